@@ -9,7 +9,8 @@ from templates import *
 
 # blacklist a few illogical combinations:
 input_blacklist: Set[str] = set([
-	'radioactivity', 'absorbed dose', 'dose equivalent',
+	'radioactivity', 'absorbed dose', 'dose equivalent', 'absorbed dose rate', 'dose equivalent rate',
+	'inverse absorbed dose rate', 'inverse dose equivalent rate',
 ])
 combo_whitelist: Set[Tuple[str, str, str]] = set([
 	('mass', 'absorbed dose', 'energy'),
@@ -30,11 +31,41 @@ combo_whitelist: Set[Tuple[str, str, str]] = set([
 	('inverse angular acceleration', 'inverse torque', 'inverse moment of inertia'),
 	('inverse torque', 'inverse angular acceleration', 'inverse moment of inertia'),
 	('inverse torque', 'inverse moment of inertia', 'inverse angular acceleration'),
+	('temperature interval', 'specific heat capacity', 'absorbed dose'),
+	('specific heat capacity', 'temperature interval', 'absorbed dose'),
+	('absorbed dose', 'specific heat capacity', 'temperature interval'),
+	('absorbed dose', 'temperature interval', 'specific heat capacity'),
+	('absorbed dose', 'time', 'absorbed dose rate'),
+	('absorbed dose', 'absorbed dose rate', 'time'),
+	('absorbed dose rate', 'time', 'absorbed dose'),
+	('time', 'absorbed dose rate', 'absorbed dose'),
+	('dose equivalent', 'time', 'dose equivalent rate'),
+	('dose equivalent', 'dose equivalent rate', 'time'),
+	('dose equivalent rate', 'time', 'dose equivalent'),
+	('time', 'dose equivalent rate', 'dose equivalent'),
 ])
 ## the following are blacklisted as outputs because they are dimensionally equivalent to other more commonly used units
 output_blacklist: Set[str] = set([
 	'torque', 'moment of inertia', 'radioactivity', 'absorbed dose', 'dose equivalent',
 	'inverse torque', 'inverse moment of inertia', 'inverse absorbed dose', 'inverse dose equivalent',
+	'absorbed dose rate', 'dose equivalent rate', 'inverse absorbed dose rate', 'inverse dose equivalent rate',
+	'temperature interval', 'entropy', 'inverse entropy',
+])
+
+# units which cannot physically be negative (get try_from_...() constructors that reject negative values)
+non_negative_units: Set[str] = set([
+	'temperature', 'mass', 'radioactivity',
+])
+
+# units whose difference is a different unit type (eg Temperature - Temperature -> TemperatureInterval), so the
+# UnitStruct derive must not implement subtraction between two values of the same type
+self_difference_units: Dict[str, str] = {
+	'temperature': 'temperature interval',
+}
+
+# unit symbols which cannot take an SI prefix in the alternate display format (in addition to compound units)
+unprefixable_symbols: Set[str] = set([
+	'', 'nan', 'kg', 'Nm',
 ])
 
 # tracker to detect conflicting implementations
@@ -83,6 +114,8 @@ def main(*args):
 	#
 	data = add_capital_names(data, columns='category,name,desc first name,desc name,unit name'.split(','))
 	data.insert(0, 'code name', data['name'].apply(to_code_name))
+	data.insert(len(data.columns), 'dimension', data['si units'].apply(to_dimension_code))
+	data.insert(len(data.columns), 'si prefixable', data['unit symbol human'].apply(is_si_prefixable))
 	conversions.insert(len(conversions.columns), 'op-function', conversions['operator'].apply(op_function_name))
 	conversions = add_capital_names(conversions, columns=['left-side', 'right-side', 'result', 'operator', 'verbing'])
 	#
@@ -96,9 +129,97 @@ def main(*args):
 		with open(module_file, 'w', newline='\n') as fout:
 			fout.write(generated_code)
 	#
+	macro_file = path.join(main_proj_dir, 'src', 'macros.rs')
+	with open(macro_file, 'w', newline='\n') as fout:
+		fout.write(generate_si_macro(data, from_to_unit_conversions))
+	#
+	quantity_file = path.join(main_proj_dir, 'src', 'quantity.rs')
+	with open(quantity_file, 'w', newline='\n') as fout:
+		fout.write(generate_quantity_module(data, conversions))
+	#
+	unit_table_file = path.join(main_proj_dir, 'src', 'unit_table.rs')
+	with open(unit_table_file, 'w', newline='\n') as fout:
+		fout.write(generate_unit_table(data, test_recs=recommended_unit_tests))
+	#
 	recommend_unit_tests(recommended_unit_tests, path.join(main_proj_dir, 'src', 'lib.rs'),
 						 path.join(main_proj_dir, 'tests', 'uom_integration_tests.rs'))
 	# done!
+def generate_si_macro(data: DataFrame, from_to_unit_conversions: DataFrame) -> str:
+	'''
+	Generate the si! macro, which maps unit symbols to unit struct constructors. SI unit symbols are 
+	written as they are displayed (eg m/s^2), and all other units by their from_... function name 
+	(eg km). Symbols shared by more than one unit type are left out, except where a preference is 
+	given in si_macro_preferred_units
+	'''
+	unit_lut = {row['name']: row for i, row in data.iterrows()}
+	si_symbols = defaultdict(lambda: [])
+	for i, row in data.iterrows():
+		tokens = str(row['unit symbol human']).replace('²', '^2').replace('³', '^3').replace('·', '.')
+		si_symbols[tokens].append(row['name'])
+	other_symbols = defaultdict(lambda: [])
+	for i, row in from_to_unit_conversions.iterrows():
+		if row['name'] in unit_lut and row['unit symbol'] not in si_symbols:
+			other_symbols[row['unit symbol']].append((row['name'], row['unit symbol']))
+	arms = []
+	for tokens, names in si_symbols.items():
+		if len(names) > 1:
+			if tokens not in si_macro_preferred_units: continue
+			names = [si_macro_preferred_units[tokens]]
+		row = unit_lut[names[0]]
+		arms.append(SI_MACRO_ARM_TEMPLATE % {'tokens': tokens, 'module': row['category'], 'code name': row['code name'], 'unit symbol': row['unit symbol']})
+	for tokens, matches in other_symbols.items():
+		if len(set(m[0] for m in matches)) > 1: continue
+		row = unit_lut[matches[0][0]]
+		arms.append(SI_MACRO_ARM_TEMPLATE % {'tokens': tokens, 'module': row['category'], 'code name': row['code name'], 'unit symbol': matches[0][1]})
+	return SI_MACRO_TEMPLATE % {'arms': '\n'.join(arms)}
+
+si_macro_preferred_units = {'K': 'temperature'}
+
+def generate_unit_table(data: DataFrame, test_recs: defaultdict) -> str:
+	'''
+	Generate the ALL_UNITS table, which lists the name, symbol, and dimension of every unit type for introspection
+	'''
+	entries = []
+	for module in sorted(data['category'].unique()):
+		for i, row in data[data['category'] == module].iterrows():
+			entries.append(UNIT_TABLE_ENTRY_TEMPLATE % row.to_dict())
+			test_recs['all_units_test'].append(UNIT_TABLE_TEST_TEMPLATE % row.to_dict())
+	return UNIT_TABLE_TEMPLATE % {'entries': '\n'.join(entries)}
+
+def generate_quantity_module(data: DataFrame, conversions: DataFrame) -> str:
+	'''
+	Generate the Quantity enum, which holds any unit struct as a variant and maps multiplication and division of
+	variant pairs to the result variant, using the same unit conversions as the unit struct operators
+	'''
+	arms = defaultdict(lambda: [])
+	conversions_buffer = []
+	conversion_keys = set((row['code left-side'], row['operator'], row['code right-side']) for i, row in conversions.iterrows())
+	for i, row in data.iterrows():
+		code_name = row['code name']
+		arms['variants'].append('\t/// A %s value\n\t%s(%s<T>),' % (row['desc name'], code_name, code_name))
+		arms['symbol arms'].append('\t\t\tQuantity::%s(_) => %s::<T>::unit_symbol(),' % (code_name, code_name))
+		arms['name arms'].append('\t\t\tQuantity::%s(_) => %s::<T>::unit_name(),' % (code_name, code_name))
+		difference_name = to_code_name(self_difference_units.get(row['name'], row['name']))
+		for key, op, result in [('add arms', '+', code_name), ('sub arms', '-', difference_name)]:
+			arms[key].append('\t\t\t(Quantity::%s(a), Quantity::%s(b)) => Ok(Quantity::%s(a.clone() %s b.clone())),' % (
+				code_name, code_name, result, op))
+		if (code_name, '/', code_name) not in conversion_keys:
+			# same unit division is dimensionless
+			arms['div arms'].append('\t\t\t(Quantity::%s(a), Quantity::%s(b)) => Ok(Quantity::Scalar(a.clone() / b.clone())),' % (
+				code_name, code_name))
+		for key, op in [('scalar mul arms', '*'), ('scalar div arms', '/')]:
+			arms[key].append('\t\t\tQuantity::%s(v) => Quantity::%s(v %s rhs),' % (code_name, code_name, op))
+		arms['display arms'].append('\t\t\tQuantity::%s(v) => fmt::Display::fmt(v, f),' % code_name)
+		conversions_buffer.append(QUANTITY_CONVERSION_TEMPLATE % row.to_dict())
+	for i, row in conversions.iterrows():
+		key = 'mul arms' if row['operator'] == '*' else 'div arms'
+		arms[key].append('\t\t\t(Quantity::%s(a), Quantity::%s(b)) => Ok(Quantity::%s(a.clone() %s b.clone())),' % (
+			row['code left-side'], row['code right-side'], row['code result'], row['operator']))
+	return QUANTITY_MODULE_TEMPLATE % {
+		**{key: '\n'.join(lines) for key, lines in arms.items()},
+		'conversions': ''.join(conversions_buffer)
+	}
+
 def post_gen_patching(code: str) -> str:
 	if '#[cfg(feature="num-bigfloat")]\nimpl' not in code:
 		code = code.replace('#[cfg(feature="num-bigfloat")]\nuse num_bigfloat;', '')
@@ -106,6 +227,8 @@ def post_gen_patching(code: str) -> str:
 		code = code.replace('#[cfg(feature="num-complex")]\nuse num_complex;', '')
 	if '#[cfg(feature="num-rational")]\nimpl' not in code:
 		code = code.replace('#[cfg(feature="num-rational")]\nuse num_rational;', '')
+	if '#[cfg(feature="nalgebra")]\nimpl' not in code:
+		code = code.replace('#[cfg(feature="nalgebra")]\nuse nalgebra;', '')
 	return code
 
 def generate_modules(module: str, data: DataFrame, conversions: DataFrame, from_to_unit_conversions: DataFrame, test_recs: defaultdict) -> str:
@@ -124,6 +247,25 @@ def generate_modules(module: str, data: DataFrame, conversions: DataFrame, from_
 
 
 def get_appendix_for_module(module: str) -> str:
+	if module == 'base':
+		return DIMENSIONLESS_APPENDIX_TEMPLATE \
+			+ DIMENSIONLESS_POWERS_TEMPLATE % {'code name': 'Dimensionless', 'desc name': 'dimensionless'} \
+			+ TEMPERATURE_INTERVAL_APPENDIX_TEMPLATE + TIME_DURATION_APPENDIX_TEMPLATE + DISTANCE_POWERS_APPENDIX_TEMPLATE \
+			+ DISTANCE_HYPOT_APPENDIX_TEMPLATE
+	if module == 'geometry':
+		return ANGLE_TRIG_APPENDIX_TEMPLATE + GEOMETRY_SHAPES_APPENDIX_TEMPLATE + AREA_ROOTS_APPENDIX_TEMPLATE
+	if module == 'mechanical':
+		return FREQUENCY_ANGULAR_VELOCITY_APPENDIX_TEMPLATE + TORQUE_ENERGY_APPENDIX_TEMPLATE \
+			+ KINETIC_ENERGY_APPENDIX_TEMPLATE + HYDROSTATIC_PRESSURE_APPENDIX_TEMPLATE \
+			+ NAMED_DIMENSIONLESS_TEMPLATE % {'code name': 'Strain', 'desc name': 'strain',
+				'ratio desc': 'change in length over original length', 'unit name': 'meters per meter', 'unit symbol': 'm/m'} \
+			+ DIMENSIONLESS_POWERS_TEMPLATE % {'code name': 'Strain', 'desc name': 'strain'} \
+			+ NAMED_DIMENSIONLESS_TEMPLATE % {'code name': 'MachNumber', 'desc name': 'Mach number',
+				'ratio desc': 'speed over the speed of sound', 'unit name': 'Mach', 'unit symbol': 'Ma'} \
+			+ DIMENSIONLESS_POWERS_TEMPLATE % {'code name': 'MachNumber', 'desc name': 'Mach number'} \
+			+ STRAIN_MACH_APPENDIX_TEMPLATE
+	if module == 'nuclear':
+		return RADIOACTIVE_DECAY_APPENDIX_TEMPLATE
 	return ''
 
 def generate_local_imports(module: str, data: DataFrame, conversions: DataFrame, test_recs: defaultdict) -> str:
@@ -151,7 +293,10 @@ def generate_unit_structs(data: DataFrame, conversions: DataFrame, from_to_unit_
 			'non-converting methods': generate_nonconverting_from_to_conversions(row, from_to_unit_conversions, test_recs),
 			'to-and-from': generate_from_to_conversions(row, from_to_unit_conversions, test_recs),
 			'extended scalar ops': generate_extended_scalar_ops(row),
-			'uom integration': generate_uom_conversions(row, test_recs)
+			'uom integration': generate_uom_conversions(row, test_recs),
+			'nalgebra integration': NALGEBRA_TEMPLATE % row.to_dict(),
+			'checked constructors': NON_NEGATIVE_CONSTRUCTOR_TEMPLATE % row.to_dict() if row['name'] in non_negative_units else '',
+			'si options': ', no_self_sub' if row['name'] in self_difference_units else ''
 		}
 		out_buf += generate_unit_conversions(row, conversions)
 		out_buf += inversions
@@ -251,11 +396,13 @@ def generate_extended_scalar_ops(data_row: Series):
 	output = ''
 	concrete_types = [
 		'num_bigfloat::BigFloat',
-		'num_complex::Complex32', 'num_complex::Complex64'
+		'num_complex::Complex32', 'num_complex::Complex64',
+		'num_rational::Rational64', 'num_rational::BigRational'
 	]
 	cfg_attrs = [
 		'#[cfg(feature="num-bigfloat")]\n',
-		'#[cfg(feature="num-complex")]\n', '#[cfg(feature="num-complex")]\n'
+		'#[cfg(feature="num-complex")]\n', '#[cfg(feature="num-complex")]\n',
+		'#[cfg(feature="num-rational")]\n', '#[cfg(feature="num-rational")]\n'
 	]
 	for n in range(0, len(concrete_types)):
 		stype = concrete_types[n]
@@ -276,18 +423,39 @@ def generate_inverse_unit_conversions(data_row: Series, data: DataFrame, test_re
 	concrete_types = [
 		'f64', 'f32', 'i64', 'i32',
 		'num_bigfloat::BigFloat',
-		'num_complex::Complex32', 'num_complex::Complex64'
+		'num_complex::Complex32', 'num_complex::Complex64',
+		'num_rational::Rational64', 'num_rational::BigRational'
 	]
 	cfg_attrs = [
 		'', '', '', '',
 		'#[cfg(feature="num-bigfloat")]\n',
-		'#[cfg(feature="num-complex")]\n', '#[cfg(feature="num-complex")]\n'
+		'#[cfg(feature="num-complex")]\n', '#[cfg(feature="num-complex")]\n',
+		'#[cfg(feature="num-rational")]\n', '#[cfg(feature="num-rational")]\n'
 	]
+	# units that are blacklisted as outputs (eg absorbed dose) would otherwise have no recip() at all, so
+	# fall back to their explicitly named inverse partner (eg inverse absorbed dose) if nothing else matches
+	if src_unit_name.startswith('inverse '):
+		partner_name = src_unit_name[len('inverse '):]
+	else:
+		partner_name = 'inverse ' + src_unit_name
+	has_other_match = any(
+		row['name'] not in output_blacklist and inverse_units == SIUnits.from_str(row['si units'])
+		for i, row in data.iterrows()
+	)
 	for i, row in data.iterrows():
 		# print('\t%s (%s)' % (row['name'], SIUnits.from_str(row['si units'])))
-		if row['name'] not in output_blacklist and inverse_units == SIUnits.from_str(row['si units']):
+		if inverse_units == SIUnits.from_str(row['si units']) and (row['name'] not in output_blacklist
+				or (not has_other_match and row['name'] == partner_name)):
 			# print('1/%s = %s' % (src_unit_name, row['name']))
 			# found a match
+			recip_args = {
+				'code right-side': to_code_name(src_unit_name),
+				'code result': to_code_name(row['name']),
+				'right-side symbol': data_row['unit symbol'],
+				'result symbol': row['unit symbol']
+			}
+			out_buf += RECIP_TEMPLATE % recip_args
+			test_recs['recip_test'].append(RECIP_TEST_TEMPLATE % recip_args)
 			for n in range(0, len(concrete_types)):
 				stype = concrete_types[n]
 				cfg_stmnt = cfg_attrs[n]
@@ -478,6 +646,8 @@ def recommend_unit_tests(test_recs: defaultdict, lib_filepath: str, uom_test_fil
 		count = 0
 		print_buffer = []
 		print_buffer.append('\t#[test]')
+		if test_fn.startswith('test_rational'):
+			print_buffer.append('\t#[cfg(feature="num-rational")]')
 		print_buffer.append('\tfn %s() {' % test_fn)
 		if test_fn.startswith('test_complex'):
 			# add import
@@ -485,6 +655,10 @@ def recommend_unit_tests(test_recs: defaultdict, lib_filepath: str, uom_test_fil
 		if test_fn.startswith('test_bigfloat'):
 			# add import
 			print_buffer.append('\t\tuse num_bigfloat::BigFloat;')
+		if test_fn.startswith('test_rational'):
+			# add imports
+			print_buffer.append('\t\tuse num_rational::{Rational64, BigRational};')
+			print_buffer.append('\t\tuse num_traits::FromPrimitive;')
 		if (
 				': x}' in test_recs[test_fn][0] and ': y}' in test_recs[test_fn][0]
 		) or (
@@ -495,6 +669,7 @@ def recommend_unit_tests(test_recs: defaultdict, lib_filepath: str, uom_test_fil
 		print_buffer.append('\t\t// ...')
 		for unit_test in test_recs[test_fn]:
 			unit_test = unit_test.replace('Complex32::from(x)', 'Complex32::from(x as f32)').replace('Complex32::from(y)', 'Complex32::from(y as f32)')
+			unit_test = re.sub(r'(Rational64|BigRational)::from\((\w)\)', r'\1::from_f64(\2).unwrap()', unit_test)
 			if reduce_spaces(unit_test) not in test_file_content:
 				print_buffer.append(unit_test)
 				count += 1
@@ -638,6 +813,23 @@ def to_code_name(n: str) -> str:
 	return ''.join([capitalize(x) for x in str(n).split(' ')])
 
 
+# SI base units, in the order of the fields of the Dimension struct (radians are dimensionless)
+base_unit_dimensions = [('m', 'length'), ('kg', 'mass'), ('s', 'time'), ('A', 'current'), ('K', 'temperature'),
+						('mol', 'amount'), ('cd', 'luminosity')]
+
+def to_dimension_code(si_units: str) -> str:
+	units = SIUnits.from_str(si_units)
+	exponents = ['%s: %s' % (name, units.numerator.count(symbol) - units.denominator.count(symbol))
+		for symbol, name in base_unit_dimensions]
+	return 'super::Dimension{%s}' % ', '.join(exponents)
+
+
+def is_si_prefixable(symbol: str) -> str:
+	symbol = str(symbol)
+	prefixable = symbol not in unprefixable_symbols and not any(c in symbol for c in '/²³·^')
+	return 'true' if prefixable else 'false'
+
+
 def op_function_name(symbol: str) -> str:
 	if symbol == '*': return 'mul'
 	if symbol == '/': return 'div'
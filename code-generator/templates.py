@@ -4,10 +4,11 @@ MODULE_TEMPLATE='''
 use core::fmt;
 use super::UnitStruct;
 use super::NumLike;
+use super::SiParseError;
 %(crate imports)s
 
 // optional supports
-#[cfg(feature="serde")]
+#[cfg(all(feature="serde", not(feature="serde-unit-tag")))]
 use serde::{Serialize, Deserialize};
 #[cfg(feature="num-bigfloat")]
 use num_bigfloat;
@@ -15,6 +16,8 @@ use num_bigfloat;
 use num_complex;
 #[cfg(feature="num-rational")]
 use num_rational;
+#[cfg(feature="nalgebra")]
+use nalgebra;
 
 %(content)s
 
@@ -23,8 +26,10 @@ use num_rational;
 
 UNIT_STRUCT_DEFINITION_TEMPLATE='''
 /// The %(desc first name)s unit type, defined as %(unit name)s in SI units
-#[derive(UnitStruct, Debug, Clone)]
-#[cfg_attr(feature="serde", derive(Serialize, Deserialize))]
+#[derive(UnitStruct, Debug, Clone, Default)]
+#[si(no_display%(si options)s)]
+#[cfg_attr(all(feature="serde", not(feature="serde-unit-tag")), derive(Serialize, Deserialize))]
+#[repr(transparent)]
 pub struct %(code name)s<T: NumLike>{
 	/// The value of this %(capital desc name)s in %(unit name)s
 	pub %(unit symbol)s: T
@@ -37,22 +42,658 @@ impl<T> %(code name)s<T> where T: NumLike {
 	
 	/// Returns the abbreviated name or symbol of %(desc name)s: "%(unit symbol human)s" for %(unit name)s
 	pub fn unit_symbol() -> &'static str { "%(unit symbol human)s" }
+
+	/// Returns the exponents of the SI base units of %(desc name)s (%(si units)s)
+	pub const fn dimension() -> super::Dimension { %(dimension)s }
+
+	/// Returns a wrapper that formats this %(desc name)s value with the full unit name instead of 
+	/// the unit symbol, eg "5 %(unit name)s" (the unit name is not singularized for values of 1)
+	pub fn display_named(&self) -> super::NamedDisplay<'_, T> {
+		super::NamedDisplay::new(&self.%(unit symbol)s, Self::unit_name())
+	}
+
+	/// Returns a new %(desc name)s value from the given number of %(unit name)s (same as 
+	/// `from_%(unit symbol)s(...)`), for any number-like type. This is useful in generic code 
+	/// that works with several unit types
+	///
+	/// # Arguments
+	/// * `value` - Any number-like type, representing a quantity of %(unit name)s
+	pub const fn new(value: T) -> Self { %(code name)s{%(unit symbol)s: value} }
+
+	/// Returns a new %(desc name)s value from the given number of %(unit name)s. Unlike
+	/// `from_%(unit symbol)s(...)`, this is a `const fn`, so it can be used to declare `const` and `static` values
+	///
+	/// # Arguments
+	/// * `%(unit symbol)s` - Any number-like type, representing a quantity of %(unit name)s
+	pub const fn from_%(unit symbol)s_const(%(unit symbol)s: T) -> Self { %(code name)s{%(unit symbol)s} }
+
+	/// Returns a %(desc name)s value with a different number type, by applying the 
+	/// given function to the value in %(unit name)s (eg `.map(|x| x as f32)` to convert 
+	/// from `f64` to `f32`)
+	///
+	/// # Arguments
+	/// * `f` - Function that converts the number of %(unit name)s to the new number type
+	pub fn map<U, F>(self, f: F) -> %(code name)s<U> where U: NumLike, F: FnOnce(T) -> U {
+		%(code name)s{%(unit symbol)s: f(self.%(unit symbol)s)}
+	}
+
+	/// Returns a %(desc name)s value with a different number type, converted 
+	/// losslessly with the `From` trait (eg from `f32` to `f64`)
+	pub fn cast<U>(self) -> %(code name)s<U> where U: NumLike+From<T> {
+		%(code name)s{%(unit symbol)s: U::from(self.%(unit symbol)s)}
+	}
+
+	/// Returns a reference to the value of this %(desc name)s in %(unit name)s
+	pub fn as_raw(&self) -> &T {
+		&self.%(unit symbol)s
+	}
+
+	/// Returns a mutable reference to the value of this %(desc name)s in %(unit name)s, 
+	/// eg for passing to numeric libraries that modify values in place. The number 
+	/// is always in %(unit name)s, so changing it directly does not change the unit
+	pub fn raw_mut(&mut self) -> &mut T {
+		&mut self.%(unit symbol)s
+	}
+
+	/// Returns a slice of numbers in %(unit name)s as a slice of %(desc name)s values, 
+	/// without copying
+	///
+	/// # Arguments
+	/// * `values` - Slice of numbers, representing quantities of %(unit name)s
+	pub fn wrap_slice(values: &[T]) -> &[Self] {
+		// SAFETY: %(code name)s<T> is #[repr(transparent)], so it has the same memory layout as T
+		unsafe { core::slice::from_raw_parts(values.as_ptr() as *const Self, values.len()) }
+	}
+
+	/// Returns a mutable slice of numbers in %(unit name)s as a mutable slice of 
+	/// %(desc name)s values, without copying
+	///
+	/// # Arguments
+	/// * `values` - Slice of numbers, representing quantities of %(unit name)s
+	pub fn wrap_slice_mut(values: &mut [T]) -> &mut [Self] {
+		// SAFETY: %(code name)s<T> is #[repr(transparent)], so it has the same memory layout as T
+		unsafe { core::slice::from_raw_parts_mut(values.as_mut_ptr() as *mut Self, values.len()) }
+	}
+
+	/// Returns the ratio of this %(desc name)s value to another %(desc name)s value 
+	/// (same as `self / other`). Dividing a unit value by another value of the same 
+	/// unit type cancels the units, so the ratio is a dimensionless number
+	///
+	/// # Arguments
+	/// * `other` - The %(desc name)s value to divide by
+	pub fn ratio(self, other: Self) -> T {
+		self.%(unit symbol)s / other.%(unit symbol)s
+	}
+
+	/// Returns the ratio of this %(desc name)s value to another %(desc name)s value 
+	/// as a `Dimensionless` value, instead of a bare number like `ratio(...)` does
+	///
+	/// # Arguments
+	/// * `other` - The %(desc name)s value to divide by
+	pub fn dimensionless_ratio(self, other: Self) -> super::base::Dimensionless<T> {
+		super::base::Dimensionless{value: self.%(unit symbol)s / other.%(unit symbol)s}
+	}
+
+	/// Returns this %(desc name)s value multiplied by a dimensionless factor 
+	/// (same as `self * factor`)
+	///
+	/// # Arguments
+	/// * `factor` - The number to multiply by
+	pub fn scale(self, factor: T) -> Self {
+		%(code name)s{%(unit symbol)s: self.%(unit symbol)s * factor}
+	}
 	%(non-converting methods)s
 }
 
+/// Formats %(desc name)s values as a number followed by its unit symbol, eg "1500 %(unit symbol human)s"
+/// (complex numbers are wrapped in parentheses, eg "(1+2i) %(unit symbol human)s").
+/// The alternate flag (`{:#}`) scales the number by the nearest SI prefix where the unit symbol allows it
 impl<T> fmt::Display for %(code name)s<T> where T: NumLike {
 	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-		write!(f, "{} {}", &self.%(unit symbol)s, Self::unit_symbol())
+		if f.alternate() {
+			return super::fmt_with_si_prefix(f, &self.%(unit symbol)s, Self::unit_symbol(), %(si prefixable)s);
+		}
+		super::fmt_unit_value(f, &self.%(unit symbol)s, Self::unit_symbol())
+	}
+}
+
+/// Formats %(desc name)s values in exponential notation followed by the unit symbol, eg "1.5e3 %(unit symbol human)s"
+impl<T> fmt::LowerExp for %(code name)s<T> where T: NumLike+fmt::LowerExp {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		fmt::LowerExp::fmt(&self.%(unit symbol)s, f)?;
+		write!(f, " {}", Self::unit_symbol())
+	}
+}
+
+/// Formats %(desc name)s values in exponential notation followed by the unit symbol, eg "1.5E3 %(unit symbol human)s"
+impl<T> fmt::UpperExp for %(code name)s<T> where T: NumLike+fmt::UpperExp {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		fmt::UpperExp::fmt(&self.%(unit symbol)s, f)?;
+		write!(f, " {}", Self::unit_symbol())
+	}
+}
+
+impl<T> super::SiUnit for %(code name)s<T> where T: NumLike {
+	type Number = T;
+	fn unit_name(&self) -> &'static str { Self::unit_name() }
+	fn unit_symbol(&self) -> &'static str { Self::unit_symbol() }
+	fn raw(&self) -> &T { &self.%(unit symbol)s }
+	fn dimension(&self) -> super::Dimension { Self::dimension() }
+	fn from_raw(value: T) -> Self { %(code name)s{%(unit symbol)s: value} }
+	fn into_raw(self) -> T { self.%(unit symbol)s }
+}
+
+#[cfg(feature="alloc")]
+impl<T> %(code name)s<T> where T: NumLike {
+	/// Returns this %(desc name)s value in the canonical text format "<value> <symbol>", eg "5 %(unit symbol human)s".
+	/// Unlike `Display`, this format never changes with precision or alternate flags, and it is the
+	/// input format expected by `from_str(...)`, so `x.canonical_string().parse()` returns the same value
+	pub fn canonical_string(&self) -> alloc::string::String {
+		alloc::format!("{} {}", self.%(unit symbol)s, Self::unit_symbol())
+	}
+
+	/// Returns a `Vec` of %(desc name)s values copied from the given slice of numbers
+	///
+	/// # Arguments
+	/// * `values` - Slice of numbers, representing quantities of %(unit name)s
+	pub fn from_%(unit symbol)s_slice(values: &[T]) -> alloc::vec::Vec<Self> {
+		values.iter().map(|x| %(code name)s{%(unit symbol)s: x.clone()}).collect()
+	}
+}
+
+/// Parses a %(desc name)s value from a number followed by its unit symbol, eg "5 %(unit symbol human)s"
+impl<T> core::str::FromStr for %(code name)s<T> where T: NumLike+core::str::FromStr {
+	type Err = SiParseError;
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		Ok(%(code name)s{%(unit symbol)s: super::parse_unit_value(s, Self::unit_symbol())?})
+	}
+}
+
+/// Parses a %(desc name)s value from a number followed by its unit symbol, eg "5 %(unit symbol human)s" 
+/// (same as `str::parse()`)
+impl<T> core::convert::TryFrom<&str> for %(code name)s<T> where T: NumLike+core::str::FromStr {
+	type Error = SiParseError;
+	fn try_from(s: &str) -> Result<Self, Self::Error> {
+		<Self as core::str::FromStr>::from_str(s)
+	}
+}
+
+#[cfg(feature="num-traits")]
+impl<T> %(code name)s<T> where T: NumLike+num_traits::Euclid {
+
+	/// Returns the Euclidean remainder of dividing this %(desc name)s value by another, 
+	/// which is never negative (requires the `num-traits` feature)
+	///
+	/// # Arguments
+	/// * `rhs` - The %(desc name)s value to divide by
+	pub fn rem_euclid(self, rhs: Self) -> Self {
+		%(code name)s{%(unit symbol)s: self.%(unit symbol)s.rem_euclid(&rhs.%(unit symbol)s)}
+	}
+
+	/// Returns the Euclidean quotient of dividing this %(desc name)s value by another, 
+	/// ie the whole number of times that `rhs` fits into this value, rounded such that 
+	/// `rem_euclid(...)` is never negative (requires the `num-traits` feature)
+	///
+	/// # Arguments
+	/// * `rhs` - The %(desc name)s value to divide by
+	pub fn div_euclid(self, rhs: Self) -> T {
+		self.%(unit symbol)s.div_euclid(&rhs.%(unit symbol)s)
+	}
+}
+
+/// Summing %(desc name)s values returns their total as a %(desc name)s value (or zero if there are no values)
+impl<T> core::iter::Sum<%(code name)s<T>> for %(code name)s<T> where T: NumLike+core::iter::Sum {
+	fn sum<I: Iterator<Item=%(code name)s<T>>>(iter: I) -> Self {
+		%(code name)s{%(unit symbol)s: iter.map(|x| x.%(unit symbol)s).sum()}
+	}
+}
+/// Summing %(desc name)s values returns their total as a %(desc name)s value (or zero if there are no values)
+impl<'a, T> core::iter::Sum<&'a %(code name)s<T>> for %(code name)s<T> where T: NumLike+core::iter::Sum+'a {
+	fn sum<I: Iterator<Item=&'a %(code name)s<T>>>(iter: I) -> Self {
+		%(code name)s{%(unit symbol)s: iter.map(|x| x.%(unit symbol)s.clone()).sum()}
+	}
+}
+
+impl<T> %(code name)s<T> where T: NumLike+From<f64> {
+
+	/// Returns the weighted average of the given %(desc name)s values, where each 
+	/// value is paired with its weight (weights do not need to add up to 1). If the 
+	/// total weight is zero (including when `values` is empty), then zero is returned
+	///
+	/// # Arguments
+	/// * `values` - Slice of (%(desc name)s value, weight) pairs
+	pub fn weighted_mean(values: &[(Self, f64)]) -> Self {
+		let total_weight: f64 = values.iter().map(|(_, weight)| *weight).sum();
+		if total_weight == 0.0 {
+			return %(code name)s{%(unit symbol)s: T::from(0.0)};
+		}
+		let total = values.iter().fold(T::from(0.0),
+			|total, (value, weight)| total + value.%(unit symbol)s.clone() * T::from(*weight));
+		%(code name)s{%(unit symbol)s: total / T::from(total_weight)}
+	}
+}
+
+impl<T> %(code name)s<T> where T: NumLike+PartialEq+From<u8> {
+
+	/// Divides this %(desc name)s value by another unit value (eg `distance.checked_div(time)` 
+	/// returns a velocity), returning `None` if the divisor is zero instead of producing 
+	/// infinity or NaN (or panicking, for integer number types). This works for every unit 
+	/// type that this %(desc name)s value can be divided by with the `/` operator
+	///
+	/// # Arguments
+	/// * `rhs` - The unit value to divide by
+	pub fn checked_div<R>(self, rhs: R) -> Option<<Self as core::ops::Div<R>>::Output> 
+		where Self: core::ops::Div<R>, R: super::SiUnit<Number=T>
+	{
+		if *rhs.raw() == T::from(0u8) {
+			return None;
+		}
+		Some(self / rhs)
+	}
+}
+
+/// Multiplying a %(desc name)s value by a dimensionless value returns a %(desc name)s value
+impl<T> core::ops::Mul<super::base::Dimensionless<T>> for %(code name)s<T> where T: NumLike {
+	type Output = %(code name)s<T>;
+	fn mul(self, rhs: super::base::Dimensionless<T>) -> Self::Output {
+		%(code name)s{%(unit symbol)s: self.%(unit symbol)s * rhs.value}
+	}
+}
+/// Multiplying a dimensionless value by a %(desc name)s value returns a %(desc name)s value
+impl<T> core::ops::Mul<%(code name)s<T>> for super::base::Dimensionless<T> where T: NumLike {
+	type Output = %(code name)s<T>;
+	fn mul(self, rhs: %(code name)s<T>) -> Self::Output {
+		%(code name)s{%(unit symbol)s: self.value * rhs.%(unit symbol)s}
+	}
+}
+/// Dividing a %(desc name)s value by a dimensionless value returns a %(desc name)s value
+impl<T> core::ops::Div<super::base::Dimensionless<T>> for %(code name)s<T> where T: NumLike {
+	type Output = %(code name)s<T>;
+	fn div(self, rhs: super::base::Dimensionless<T>) -> Self::Output {
+		%(code name)s{%(unit symbol)s: self.%(unit symbol)s / rhs.value}
+	}
+}
+
+/// The remainder of dividing a %(desc name)s value by another %(desc name)s value is a %(desc name)s value
+impl<T> core::ops::Rem<%(code name)s<T>> for %(code name)s<T> where T: NumLike+core::ops::Rem<Output=T> {
+	type Output = %(code name)s<T>;
+	fn rem(self, rhs: %(code name)s<T>) -> Self::Output {
+		%(code name)s{%(unit symbol)s: self.%(unit symbol)s %% rhs.%(unit symbol)s}
+	}
+}
+/// The remainder of dividing a %(desc name)s value by another %(desc name)s value is a %(desc name)s value
+impl<T> core::ops::Rem<&%(code name)s<T>> for %(code name)s<T> where T: NumLike+core::ops::Rem<Output=T> {
+	type Output = %(code name)s<T>;
+	fn rem(self, rhs: &%(code name)s<T>) -> Self::Output {
+		%(code name)s{%(unit symbol)s: self.%(unit symbol)s %% rhs.%(unit symbol)s.clone()}
+	}
+}
+/// The remainder of dividing a %(desc name)s value by another %(desc name)s value is a %(desc name)s value
+impl<T> core::ops::Rem<%(code name)s<T>> for &%(code name)s<T> where T: NumLike+core::ops::Rem<Output=T> {
+	type Output = %(code name)s<T>;
+	fn rem(self, rhs: %(code name)s<T>) -> Self::Output {
+		%(code name)s{%(unit symbol)s: self.%(unit symbol)s.clone() %% rhs.%(unit symbol)s}
+	}
+}
+/// The remainder of dividing a %(desc name)s value by another %(desc name)s value is a %(desc name)s value
+impl<T> core::ops::Rem<&%(code name)s<T>> for &%(code name)s<T> where T: NumLike+core::ops::Rem<Output=T> {
+	type Output = %(code name)s<T>;
+	fn rem(self, rhs: &%(code name)s<T>) -> Self::Output {
+		%(code name)s{%(unit symbol)s: self.%(unit symbol)s.clone() %% rhs.%(unit symbol)s.clone()}
+	}
+}
+
+/// Serializes a %(desc name)s value together with its unit symbol, eg 
+/// `{"value": 1.5, "unit": "%(unit symbol human)s"}` (requires the `serde-unit-tag` feature)
+#[cfg(feature="serde-unit-tag")]
+impl<T> serde::Serialize for %(code name)s<T> where T: NumLike+serde::Serialize {
+	fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+		super::serialize_with_unit_tag(&self.%(unit symbol)s, Self::unit_symbol(), serializer)
+	}
+}
+/// Deserializes a %(desc name)s value from a value and unit symbol, eg 
+/// `{"value": 1.5, "unit": "%(unit symbol human)s"}`, returning an error if the unit symbol 
+/// does not match (requires the `serde-unit-tag` feature)
+#[cfg(feature="serde-unit-tag")]
+impl<'de, T> serde::Deserialize<'de> for %(code name)s<T> where T: NumLike+serde::Deserialize<'de> {
+	fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+		Ok(%(code name)s{%(unit symbol)s: super::deserialize_with_unit_tag(Self::unit_symbol(), deserializer)?})
+	}
+}
+
+/// The additive identity of %(desc name)s values is zero %(unit name)s (requires the `num-traits` feature)
+#[cfg(feature="num-traits")]
+impl<T> num_traits::Zero for %(code name)s<T> where T: NumLike+num_traits::Zero {
+	fn zero() -> Self { %(code name)s{%(unit symbol)s: T::zero()} }
+	fn is_zero(&self) -> bool { self.%(unit symbol)s.is_zero() }
+}
+
+#[cfg(feature="num-traits")]
+impl<T> %(code name)s<T> where T: NumLike+num_traits::Signed {
+
+	/// Returns the sign of this %(desc name)s value as a dimensionless number: 1 if it is 
+	/// positive, -1 if it is negative, and 0 if it is zero for integer number types 
+	/// (for floating-point number types, +0.0 returns 1.0, -0.0 returns -1.0, and NaN returns 
+	/// NaN) (requires the `num-traits` feature)
+	pub fn signum(self) -> T {
+		self.%(unit symbol)s.signum()
+	}
+}
+
+impl<T> %(code name)s<T> where T: NumLike+PartialOrd {
+
+	/// Returns the absolute value of this %(desc name)s value, ie its magnitude without a minus sign
+	/// (for floating-point number types, -0.0 returns +0.0)
+	pub fn abs(self) -> Self {
+		let negated = -self.%(unit symbol)s.clone();
+		if self.%(unit symbol)s < negated {
+			%(code name)s{%(unit symbol)s: negated}
+		} else if self.%(unit symbol)s == negated {
+			// zero, which is its own negation (adding +0.0 turns -0.0 into +0.0)
+			%(code name)s{%(unit symbol)s: negated + self.%(unit symbol)s}
+		} else {
+			self
+		}
+	}
+
+	/// Returns the lesser of this %(desc name)s value and the given %(desc name)s value
+	///
+	/// # Arguments
+	/// * `other` - The %(desc name)s value to compare with this one
+	pub fn min(self, other: Self) -> Self {
+		if other.%(unit symbol)s < self.%(unit symbol)s { other } else { self }
+	}
+
+	/// Returns the greater of this %(desc name)s value and the given %(desc name)s value
+	///
+	/// # Arguments
+	/// * `other` - The %(desc name)s value to compare with this one
+	pub fn max(self, other: Self) -> Self {
+		if other.%(unit symbol)s > self.%(unit symbol)s { other } else { self }
+	}
+
+	/// Returns the least of the given %(desc name)s values, or `None` if there are none. 
+	/// Unlike `Iterator::min()`, this does not require `Ord`, so it works for 
+	/// floating-point numbers. NaN values are skipped (so the result is `None` if all 
+	/// values are NaN), and the first of several equal values is returned
+	///
+	/// # Arguments
+	/// * `values` - Any iterator or collection of %(desc name)s values
+	pub fn min_of<I>(values: I) -> Option<Self> where I: IntoIterator<Item=Self> {
+		values.into_iter().filter(|x| x.%(unit symbol)s.partial_cmp(&x.%(unit symbol)s).is_some()).reduce(Self::min)
+	}
+
+	/// Returns the greatest of the given %(desc name)s values, or `None` if there are none. 
+	/// Unlike `Iterator::max()`, this does not require `Ord`, so it works for 
+	/// floating-point numbers. NaN values are skipped (so the result is `None` if all 
+	/// values are NaN), and the first of several equal values is returned
+	///
+	/// # Arguments
+	/// * `values` - Any iterator or collection of %(desc name)s values
+	pub fn max_of<I>(values: I) -> Option<Self> where I: IntoIterator<Item=Self> {
+		values.into_iter().filter(|x| x.%(unit symbol)s.partial_cmp(&x.%(unit symbol)s).is_some()).reduce(Self::max)
+	}
+
+	/// Returns this %(desc name)s value restricted to the range from `lo` to `hi`
+	///
+	/// # Arguments
+	/// * `lo` - The minimum %(desc name)s value
+	/// * `hi` - The maximum %(desc name)s value
+	///
+	/// # Panics
+	/// Panics if `lo` is greater than `hi` (or if either of them is NaN)
+	pub fn clamp(self, lo: Self, hi: Self) -> Self {
+		assert!(lo.%(unit symbol)s <= hi.%(unit symbol)s, "lo must be less than or equal to hi");
+		if self.%(unit symbol)s < lo.%(unit symbol)s { lo } else if self.%(unit symbol)s > hi.%(unit symbol)s { hi } else { self }
+	}
+
+	/// Returns this %(desc name)s value with its magnitude capped at `max`, keeping its 
+	/// sign (eg for capping a speed), or this value unchanged if its magnitude does not 
+	/// exceed `max`. For complex-valued %(desc name)s, use `clamp_norm(...)` instead, 
+	/// which caps the magnitude while keeping the phase
+	///
+	/// # Arguments
+	/// * `max` - The maximum magnitude (should not be negative)
+	pub fn clamp_magnitude(self, max: Self) -> Self {
+		let negated_max = -max.%(unit symbol)s.clone();
+		if self.%(unit symbol)s > max.%(unit symbol)s { 
+			max 
+		} else if self.%(unit symbol)s < negated_max { 
+			%(code name)s{%(unit symbol)s: negated_max} 
+		} else { 
+			self 
+		}
+	}
+
+	/// Returns true if this %(desc name)s value differs from the given %(desc name)s 
+	/// value by no more than the given absolute tolerance (which is also a 
+	/// %(desc name)s value, so the comparison is dimensionally safe). NaN is never 
+	/// equal to anything. See also the `assert_unit_eq!(...)` macro.
+	///
+	/// # Arguments
+	/// * `other` - The %(desc name)s value to compare with this one
+	/// * `tolerance` - The largest allowed difference between the two values
+	pub fn eq_within(self, other: Self, tolerance: Self) -> bool {
+		let diff = self.%(unit symbol)s - other.%(unit symbol)s;
+		diff <= tolerance.%(unit symbol)s && -diff <= tolerance.%(unit symbol)s
+	}
+}
+
+#[cfg(feature="num-complex")]
+impl %(code name)s<num_complex::Complex32> {
+	/// Returns the magnitude (aka norm or absolute value) of this complex %(desc name)s 
+	/// value as a real-valued %(desc name)s (requires the `num-complex` feature)
+	pub fn magnitude(self) -> %(code name)s<f32> {
+		%(code name)s{%(unit symbol)s: self.%(unit symbol)s.norm()}
+	}
+
+	/// Returns this complex %(desc name)s value scaled down so that its magnitude is 
+	/// at most `max` (keeping its phase), or this value unchanged if its magnitude does 
+	/// not exceed `max`. This is the complex equivalent of `clamp_magnitude(...)` (requires 
+	/// the `num-complex` feature)
+	///
+	/// # Arguments
+	/// * `max` - The maximum magnitude (should not be negative)
+	pub fn clamp_norm(self, max: %(code name)s<f32>) -> Self {
+		let norm = self.%(unit symbol)s.norm();
+		if norm > max.%(unit symbol)s {
+			%(code name)s{%(unit symbol)s: self.%(unit symbol)s * (max.%(unit symbol)s / norm)}
+		} else {
+			self
+		}
+	}
+}
+
+#[cfg(feature="num-complex")]
+impl %(code name)s<num_complex::Complex64> {
+	/// Returns the magnitude (aka norm or absolute value) of this complex %(desc name)s 
+	/// value as a real-valued %(desc name)s (requires the `num-complex` feature)
+	pub fn magnitude(self) -> %(code name)s<f64> {
+		%(code name)s{%(unit symbol)s: self.%(unit symbol)s.norm()}
+	}
+
+	/// Returns this complex %(desc name)s value scaled down so that its magnitude is 
+	/// at most `max` (keeping its phase), or this value unchanged if its magnitude does 
+	/// not exceed `max`. This is the complex equivalent of `clamp_magnitude(...)` (requires 
+	/// the `num-complex` feature)
+	///
+	/// # Arguments
+	/// * `max` - The maximum magnitude (should not be negative)
+	pub fn clamp_norm(self, max: %(code name)s<f64>) -> Self {
+		let norm = self.%(unit symbol)s.norm();
+		if norm > max.%(unit symbol)s {
+			%(code name)s{%(unit symbol)s: self.%(unit symbol)s * (max.%(unit symbol)s / norm)}
+		} else {
+			self
+		}
+	}
+}
+
+/// Rounding and other floating-point functions (requires the `num-traits` feature, 
+/// and either the `std` or `libm` feature)
+#[cfg(all(feature="num-traits", any(feature="std", feature="libm")))]
+impl<T> %(code name)s<T> where T: NumLike+num_traits::Float {
+
+	/// Returns this %(desc name)s value rounded to the nearest whole number of %(unit name)s 
+	/// (half-way cases are rounded away from zero)
+	pub fn round(self) -> Self {
+		%(code name)s{%(unit symbol)s: self.%(unit symbol)s.round()}
+	}
+
+	/// Returns this %(desc name)s value rounded down to a whole number of %(unit name)s
+	pub fn floor(self) -> Self {
+		%(code name)s{%(unit symbol)s: self.%(unit symbol)s.floor()}
+	}
+
+	/// Returns this %(desc name)s value rounded up to a whole number of %(unit name)s
+	pub fn ceil(self) -> Self {
+		%(code name)s{%(unit symbol)s: self.%(unit symbol)s.ceil()}
+	}
+
+	/// Returns this %(desc name)s value rounded towards zero to a whole number of %(unit name)s
+	pub fn trunc(self) -> Self {
+		%(code name)s{%(unit symbol)s: self.%(unit symbol)s.trunc()}
+	}
+
+	/// Returns this %(desc name)s value rounded to the nearest multiple of the given step 
+	/// (half-way cases are rounded away from zero)
+	///
+	/// # Arguments
+	/// * `step` - The %(desc name)s value to round to a multiple of
+	pub fn round_to(self, step: Self) -> Self {
+		%(code name)s{%(unit symbol)s: (self.%(unit symbol)s / step.%(unit symbol)s).round() * step.%(unit symbol)s}
+	}
+
+	/// Fused multiply-add: returns `self * a + b` computed with only one rounding 
+	/// error, which is more accurate (and on some hardware faster) than a separate 
+	/// multiplication and addition
+	///
+	/// # Arguments
+	/// * `a` - The scalar value to multiply this %(desc name)s value by
+	/// * `b` - The %(desc name)s value to add to the product
+	pub fn mul_add(self, a: T, b: Self) -> Self {
+		%(code name)s{%(unit symbol)s: self.%(unit symbol)s.mul_add(a, b.%(unit symbol)s)}
+	}
+
+	/// Returns true if this %(desc name)s value is neither infinite nor NaN
+	pub fn is_finite(&self) -> bool {
+		self.%(unit symbol)s.is_finite()
+	}
+
+	/// Returns true if this %(desc name)s value is positive or negative infinity
+	pub fn is_infinite(&self) -> bool {
+		self.%(unit symbol)s.is_infinite()
+	}
+
+	/// Returns true if this %(desc name)s value is NaN (not a number)
+	pub fn is_nan(&self) -> bool {
+		self.%(unit symbol)s.is_nan()
+	}
+
+	/// Returns true if this %(desc name)s value has a positive sign, including +0.0 
+	/// and positive infinity
+	pub fn is_sign_positive(&self) -> bool {
+		self.%(unit symbol)s.is_sign_positive()
+	}
+
+	/// Returns true if this %(desc name)s value has a negative sign, including -0.0 
+	/// and negative infinity
+	pub fn is_sign_negative(&self) -> bool {
+		self.%(unit symbol)s.is_sign_negative()
+	}
+
+	/// Returns a %(desc name)s value with the magnitude of this one and the sign of 
+	/// `sign_source`, eg for reflecting a value off of a surface
+	///
+	/// # Arguments
+	/// * `sign_source` - The %(desc name)s value whose sign is copied
+	pub fn copysign(self, sign_source: Self) -> Self {
+		%(code name)s{%(unit symbol)s: self.%(unit symbol)s.copysign(sign_source.%(unit symbol)s)}
+	}
+}
+
+#[cfg(feature="num-traits")]
+impl<T> %(code name)s<T> where T: NumLike+num_traits::CheckedAdd+num_traits::CheckedSub {
+
+	/// Adds another %(desc name)s value to this one, returning `None` if the 
+	/// addition overflows the number type (requires the `num-traits` feature)
+	pub fn checked_add(self, rhs: Self) -> Option<Self> {
+		Some(%(code name)s{%(unit symbol)s: self.%(unit symbol)s.checked_add(&rhs.%(unit symbol)s)?})
+	}
+
+	/// Subtracts another %(desc name)s value from this one, returning `None` if 
+	/// the subtraction overflows the number type (requires the `num-traits` feature)
+	pub fn checked_sub(self, rhs: Self) -> Option<Self> {
+		Some(%(code name)s{%(unit symbol)s: self.%(unit symbol)s.checked_sub(&rhs.%(unit symbol)s)?})
+	}
+}
+
+#[cfg(feature="num-traits")]
+impl<T> %(code name)s<T> where T: NumLike+num_traits::SaturatingAdd+num_traits::SaturatingSub {
+
+	/// Adds another %(desc name)s value to this one, clamping the result to the 
+	/// minimum or maximum value of the number type instead of overflowing 
+	/// (requires the `num-traits` feature)
+	pub fn saturating_add(self, rhs: Self) -> Self {
+		%(code name)s{%(unit symbol)s: self.%(unit symbol)s.saturating_add(&rhs.%(unit symbol)s)}
+	}
+
+	/// Subtracts another %(desc name)s value from this one, clamping the result 
+	/// to the minimum or maximum value of the number type instead of overflowing 
+	/// (requires the `num-traits` feature)
+	pub fn saturating_sub(self, rhs: Self) -> Self {
+		%(code name)s{%(unit symbol)s: self.%(unit symbol)s.saturating_sub(&rhs.%(unit symbol)s)}
+	}
+}
+
+impl<T> %(code name)s<T> where T: NumLike+Into<f64> {
+
+	/// Returns true if this %(desc name)s value is equal to the given %(desc name)s 
+	/// value to within the given number of significant figures (eg 6 significant 
+	/// figures allows a relative difference of up to 1 part per million). NaN is 
+	/// only approximately equal to NaN, infinity is only approximately equal to 
+	/// infinity of the same sign, and zero is only approximately equal to zero.
+	///
+	/// # Arguments
+	/// * `other` - The %(desc name)s value to compare with this one
+	/// * `sig_figs` - The number of significant figures that must match
+	pub fn approx_eq(self, other: Self, sig_figs: i32) -> bool {
+		super::approx_eq_f64(self.%(unit symbol)s.into(), other.%(unit symbol)s.into(), sig_figs)
 	}
 }
 
 impl<T> %(code name)s<T> where T: NumLike+From<f64> {
+
+	/// Returns the linear interpolation between this %(desc name)s value and the given 
+	/// %(desc name)s value, ie `self*(1-t) + other*t`. The interpolation factor is not 
+	/// clamped, so values of `t` outside the range 0 to 1 extrapolate beyond the two 
+	/// values (see `lerp_clamped(...)`).
+	///
+	/// # Arguments
+	/// * `other` - The %(desc name)s value to interpolate towards (returned when `t` is 1)
+	/// * `t` - The interpolation factor, where 0 returns this value and 1 returns `other`
+	pub fn lerp(self, other: Self, t: f64) -> Self {
+		%(code name)s{%(unit symbol)s: self.%(unit symbol)s * T::from(1.0 - t) + other.%(unit symbol)s * T::from(t)}
+	}
+
+	/// Same as `lerp(...)`, except that the interpolation factor `t` is first clamped 
+	/// to the range 0 to 1, so that the result is always between the two values
+	///
+	/// # Arguments
+	/// * `other` - The %(desc name)s value to interpolate towards (returned when `t` is 1 or more)
+	/// * `t` - The interpolation factor, where 0 returns this value and 1 returns `other`
+	pub fn lerp_clamped(self, other: Self, t: f64) -> Self {
+		self.lerp(other, t.clamp(0.0, 1.0))
+	}
 	%(to-and-from)s
 }
 
 %(extended scalar ops)s
 
 %(uom integration)s
+
+%(nalgebra integration)s
+%(checked constructors)s
 '''
 
 NON_COEFFICIENT_TO_FROM_TEMPLATE = '''
@@ -70,6 +711,8 @@ TO_FROM_SLOPE_OFFSET_TEMPLATE = '''
 	/// Returns a copy of this %(desc name)s value in %(unit name)s
 	/// 
 	/// *Note: This method is not available for `f32` and other number types lacking the `From<f64>` trait*
+	///
+	/// *Note: This is an absolute conversion that includes the offset between the zero points of the two scales, so it does not apply to differences between %(desc name)s values*
 	pub fn to_%(unit symbol)s(&self) -> T {
 		return (self.%(si unit symbol)s.clone() * T::from(%(inverse slope)s_f64)) - T::from(%(offset)s_f64);
 	}
@@ -78,6 +721,8 @@ TO_FROM_SLOPE_OFFSET_TEMPLATE = '''
 	/// 
 	/// *Note: This method is not available for `f32` and other number types lacking the `From<f64>` trait*
 	///
+	/// *Note: This is an absolute conversion that includes the offset between the zero points of the two scales, so it does not apply to differences between %(desc name)s values*
+	///
 	/// # Arguments
 	/// * `%(unit symbol)s` - Any number-like type, representing a quantity of %(unit name)s
 	pub fn from_%(unit symbol)s(%(unit symbol)s: T) -> Self {
@@ -167,6 +812,16 @@ SCALAR_EXTENDED_TYPES_TEMPLATE='''
 }
 '''
 
+RECIP_TEMPLATE='''
+impl<T> %(code right-side)s<T> where T: NumLike+From<u8> {
+	/// Returns the reciprocal of this %(code right-side)s value (ie 1 divided by 
+	/// this value) as a value of type %(code result)s
+	pub fn recip(self) -> %(code result)s<T> {
+		%(code result)s{%(result symbol)s: T::from(1u8) / self.%(right-side symbol)s}
+	}
+}
+'''
+
 INVERSE_CONVERSION_TEMPLATE='''
 // 1/%(code right-side)s -> %(code result)s
 /// Dividing a scalar value by a %(code right-side)s unit value returns a value of type %(code result)s
@@ -219,11 +874,50 @@ impl<T> From<uom::si::%(uom data type)s::%(uom name)s> for %(code name)s<T> wher
 }
 '''
 
+NALGEBRA_TEMPLATE='''
+/// Multiplying a %(code name)s by an [nalgebra](https://crates.io/crates/nalgebra) 
+/// vector or matrix of unitless values returns a vector or matrix of %(code name)s 
+/// values (eg a direction vector multiplied by a %(code name)s)
+#[cfg(feature="nalgebra")]
+impl<T, R, C, S> core::ops::Mul<nalgebra::Matrix<T, R, C, S>> for %(code name)s<T> where 
+	T: NumLike+nalgebra::Scalar, R: nalgebra::Dim, C: nalgebra::Dim, S: nalgebra::RawStorage<T, R, C>, 
+	nalgebra::DefaultAllocator: nalgebra::allocator::Allocator<R, C> {
+	type Output = nalgebra::OMatrix<%(code name)s<T>, R, C>;
+	fn mul(self, rhs: nalgebra::Matrix<T, R, C, S>) -> Self::Output {
+		rhs.map(|x| %(code name)s{%(unit symbol)s: self.%(unit symbol)s.clone() * x})
+	}
+}
+/// Multiplying an [nalgebra](https://crates.io/crates/nalgebra) vector or matrix of 
+/// unitless values by a %(code name)s returns a vector or matrix of %(code name)s values
+#[cfg(feature="nalgebra")]
+impl<T, R, C, S> core::ops::Mul<%(code name)s<T>> for nalgebra::Matrix<T, R, C, S> where 
+	T: NumLike+nalgebra::Scalar, R: nalgebra::Dim, C: nalgebra::Dim, S: nalgebra::RawStorage<T, R, C>, 
+	nalgebra::DefaultAllocator: nalgebra::allocator::Allocator<R, C> {
+	type Output = nalgebra::OMatrix<%(code name)s<T>, R, C>;
+	fn mul(self, rhs: %(code name)s<T>) -> Self::Output {
+		self.map(|x| %(code name)s{%(unit symbol)s: x * rhs.%(unit symbol)s.clone()})
+	}
+}
+/// Dividing an [nalgebra](https://crates.io/crates/nalgebra) vector or matrix of 
+/// %(code name)s values by a %(code name)s returns a vector or matrix of unitless values
+#[cfg(feature="nalgebra")]
+impl<T, R, C, S> core::ops::Div<%(code name)s<T>> for nalgebra::Matrix<%(code name)s<T>, R, C, S> where 
+	T: NumLike+nalgebra::Scalar, R: nalgebra::Dim, C: nalgebra::Dim, S: nalgebra::RawStorage<%(code name)s<T>, R, C>, 
+	nalgebra::DefaultAllocator: nalgebra::allocator::Allocator<R, C> {
+	type Output = nalgebra::OMatrix<T, R, C>;
+	fn div(self, rhs: %(code name)s<T>) -> Self::Output {
+		self.map(|x| x.%(unit symbol)s / rhs.%(unit symbol)s.clone())
+	}
+}
+'''
+
 UNIT_NAME_TEST_TEMPLATE = '''		assert!(%(struct)s::<f64>::unit_name().eq("%(unit name)s"));
 		assert!(%(struct)s::<f64>::unit_symbol().eq("%(symbol)s"));'''
 
 UNIT_CONVERSION_TEST_TEMPLATE='\t\tassert_eq!(%(op)s_check(&%(left struct)s{%(left symbol)s: x}, &%(right struct)s{%(right symbol)s: y}), %(out struct)s{%(out symbol)s: x%(op symbol)sy});'
 
+RECIP_TEST_TEMPLATE='\t\tassert_eq!(%(code right-side)s{%(right-side symbol)s: 4.0_f64}.recip(), %(code result)s{%(result symbol)s: 0.25_f64});'
+
 UNIT_INVERSE_CONVERSION_TEST_TEMPLATE='\t\tassert_eq!(div_check(&(x as %(scalar type)s), &%(code right-side)s{%(right-side symbol)s: y as %(scalar type)s}), %(code result)s{%(result symbol)s: x as %(scalar type)s/y as %(scalar type)s});'
 
 SPECIAL_UNIT_INVERSE_CONVERSION_TEST_TEMPLATE='''		assert_eq!(div_check(
@@ -253,3 +947,1035 @@ UOM_INTO_TEST_TEMPLATE='''		assert!(uom::si::%(data type)s::%(uom name)s::new::<
 UOM_FROM_TEST_TEMPLATE='''		assert!(simple_si_units::%(category)s::%(code name)s::from(
 				uom::si::%(data type)s::%(uom name)s::new::<uom::si::%(uom module)s::%(uom type)s>(x)
 			) == simple_si_units::%(category)s::%(code name)s{%(unit symbol)s: x});'''
+
+AREA_ROOTS_APPENDIX_TEMPLATE='''
+/// Square root of an area (requires the `num-traits` feature, and either the `std` 
+/// or `libm` feature)
+#[cfg(all(feature="num-traits", any(feature="std", feature="libm")))]
+impl<T> Area<T> where T: NumLike+num_traits::Float {
+
+	/// Returns the side length of a square with this area (ie √A)
+	pub fn sqrt(self) -> Distance<T> {
+		Distance{m: self.m2.sqrt()}
+	}
+}
+
+/// Cube root of a volume (requires the `num-traits` feature, and either the `std` 
+/// or `libm` feature)
+#[cfg(all(feature="num-traits", any(feature="std", feature="libm")))]
+impl<T> Volume<T> where T: NumLike+num_traits::Float {
+
+	/// Returns the side length of a cube with this volume (ie ∛V)
+	pub fn cbrt(self) -> Distance<T> {
+		Distance{m: self.m3.cbrt()}
+	}
+}
+
+/// Square root of an inverse area (requires the `num-traits` feature, and either the 
+/// `std` or `libm` feature)
+#[cfg(all(feature="num-traits", any(feature="std", feature="libm")))]
+impl<T> InverseArea<T> where T: NumLike+num_traits::Float {
+
+	/// Returns the inverse side length of a square with this inverse area (ie √(1/A))
+	pub fn sqrt(self) -> InverseDistance<T> {
+		InverseDistance{per_m: self.per_m2.sqrt()}
+	}
+}
+
+/// Cube root of an inverse volume (requires the `num-traits` feature, and either the 
+/// `std` or `libm` feature)
+#[cfg(all(feature="num-traits", any(feature="std", feature="libm")))]
+impl<T> InverseVolume<T> where T: NumLike+num_traits::Float {
+
+	/// Returns the inverse side length of a cube with this inverse volume (ie ∛(1/V))
+	pub fn cbrt(self) -> InverseDistance<T> {
+		InverseDistance{per_m: self.per_m3.cbrt()}
+	}
+}
+'''
+
+TEMPERATURE_INTERVAL_APPENDIX_TEMPLATE='''
+impl<T> Temperature<T> where T: NumLike {
+
+	/// Returns the temperature interval from the given starting temperature to 
+	/// this temperature (ie this temperature minus the starting temperature), 
+	/// the same as `self - start` but without consuming either temperature
+	///
+	/// # Arguments
+	/// * `start` - The temperature at the start of the interval
+	pub fn interval_from(&self, start: &Temperature<T>) -> TemperatureInterval<T> {
+		TemperatureInterval{K: self.K.clone() - start.K.clone()}
+	}
+}
+
+// Temperature + TemperatureInterval -> Temperature
+/// Adding a TemperatureInterval to a Temperature returns a value of type Temperature
+impl<T> core::ops::Add<TemperatureInterval<T>> for Temperature<T> where T: NumLike {
+	type Output = Temperature<T>;
+	fn add(self, rhs: TemperatureInterval<T>) -> Self::Output {
+		Temperature{K: self.K + rhs.K}
+	}
+}
+/// Adding a TemperatureInterval to a Temperature returns a value of type Temperature
+impl<T> core::ops::Add<TemperatureInterval<T>> for &Temperature<T> where T: NumLike {
+	type Output = Temperature<T>;
+	fn add(self, rhs: TemperatureInterval<T>) -> Self::Output {
+		Temperature{K: self.K.clone() + rhs.K}
+	}
+}
+/// Adding a TemperatureInterval to a Temperature returns a value of type Temperature
+impl<T> core::ops::Add<&TemperatureInterval<T>> for Temperature<T> where T: NumLike {
+	type Output = Temperature<T>;
+	fn add(self, rhs: &TemperatureInterval<T>) -> Self::Output {
+		Temperature{K: self.K + rhs.K.clone()}
+	}
+}
+/// Adding a TemperatureInterval to a Temperature returns a value of type Temperature
+impl<T> core::ops::Add<&TemperatureInterval<T>> for &Temperature<T> where T: NumLike {
+	type Output = Temperature<T>;
+	fn add(self, rhs: &TemperatureInterval<T>) -> Self::Output {
+		Temperature{K: self.K.clone() + rhs.K.clone()}
+	}
+}
+
+// TemperatureInterval + Temperature -> Temperature
+/// Adding a Temperature to a TemperatureInterval returns a value of type Temperature
+impl<T> core::ops::Add<Temperature<T>> for TemperatureInterval<T> where T: NumLike {
+	type Output = Temperature<T>;
+	fn add(self, rhs: Temperature<T>) -> Self::Output {
+		Temperature{K: self.K + rhs.K}
+	}
+}
+/// Adding a Temperature to a TemperatureInterval returns a value of type Temperature
+impl<T> core::ops::Add<Temperature<T>> for &TemperatureInterval<T> where T: NumLike {
+	type Output = Temperature<T>;
+	fn add(self, rhs: Temperature<T>) -> Self::Output {
+		Temperature{K: self.K.clone() + rhs.K}
+	}
+}
+/// Adding a Temperature to a TemperatureInterval returns a value of type Temperature
+impl<T> core::ops::Add<&Temperature<T>> for TemperatureInterval<T> where T: NumLike {
+	type Output = Temperature<T>;
+	fn add(self, rhs: &Temperature<T>) -> Self::Output {
+		Temperature{K: self.K + rhs.K.clone()}
+	}
+}
+/// Adding a Temperature to a TemperatureInterval returns a value of type Temperature
+impl<T> core::ops::Add<&Temperature<T>> for &TemperatureInterval<T> where T: NumLike {
+	type Output = Temperature<T>;
+	fn add(self, rhs: &Temperature<T>) -> Self::Output {
+		Temperature{K: self.K.clone() + rhs.K.clone()}
+	}
+}
+
+// Temperature - TemperatureInterval -> Temperature
+/// Subtracting a TemperatureInterval from a Temperature returns a value of type Temperature
+impl<T> core::ops::Sub<TemperatureInterval<T>> for Temperature<T> where T: NumLike {
+	type Output = Temperature<T>;
+	fn sub(self, rhs: TemperatureInterval<T>) -> Self::Output {
+		Temperature{K: self.K - rhs.K}
+	}
+}
+/// Subtracting a TemperatureInterval from a Temperature returns a value of type Temperature
+impl<T> core::ops::Sub<TemperatureInterval<T>> for &Temperature<T> where T: NumLike {
+	type Output = Temperature<T>;
+	fn sub(self, rhs: TemperatureInterval<T>) -> Self::Output {
+		Temperature{K: self.K.clone() - rhs.K}
+	}
+}
+/// Subtracting a TemperatureInterval from a Temperature returns a value of type Temperature
+impl<T> core::ops::Sub<&TemperatureInterval<T>> for Temperature<T> where T: NumLike {
+	type Output = Temperature<T>;
+	fn sub(self, rhs: &TemperatureInterval<T>) -> Self::Output {
+		Temperature{K: self.K - rhs.K.clone()}
+	}
+}
+/// Subtracting a TemperatureInterval from a Temperature returns a value of type Temperature
+impl<T> core::ops::Sub<&TemperatureInterval<T>> for &Temperature<T> where T: NumLike {
+	type Output = Temperature<T>;
+	fn sub(self, rhs: &TemperatureInterval<T>) -> Self::Output {
+		Temperature{K: self.K.clone() - rhs.K.clone()}
+	}
+}
+
+// Temperature - Temperature -> TemperatureInterval
+/// Subtracting a Temperature from a Temperature returns a value of type TemperatureInterval
+impl<T> core::ops::Sub<Temperature<T>> for Temperature<T> where T: NumLike {
+	type Output = TemperatureInterval<T>;
+	fn sub(self, rhs: Temperature<T>) -> Self::Output {
+		TemperatureInterval{K: self.K - rhs.K}
+	}
+}
+/// Subtracting a Temperature from a Temperature returns a value of type TemperatureInterval
+impl<T> core::ops::Sub<Temperature<T>> for &Temperature<T> where T: NumLike {
+	type Output = TemperatureInterval<T>;
+	fn sub(self, rhs: Temperature<T>) -> Self::Output {
+		TemperatureInterval{K: self.K.clone() - rhs.K}
+	}
+}
+/// Subtracting a Temperature from a Temperature returns a value of type TemperatureInterval
+impl<T> core::ops::Sub<&Temperature<T>> for Temperature<T> where T: NumLike {
+	type Output = TemperatureInterval<T>;
+	fn sub(self, rhs: &Temperature<T>) -> Self::Output {
+		TemperatureInterval{K: self.K - rhs.K.clone()}
+	}
+}
+/// Subtracting a Temperature from a Temperature returns a value of type TemperatureInterval
+impl<T> core::ops::Sub<&Temperature<T>> for &Temperature<T> where T: NumLike {
+	type Output = TemperatureInterval<T>;
+	fn sub(self, rhs: &Temperature<T>) -> Self::Output {
+		TemperatureInterval{K: self.K.clone() - rhs.K.clone()}
+	}
+}
+'''
+
+DIMENSIONLESS_APPENDIX_TEMPLATE='''
+/// The dimensionless unit type, for unitless values such as the ratio of two values 
+/// of the same unit type (eg strain or Mach number). Unlike a bare number, a 
+/// `Dimensionless` value keeps the intent that the value is a ratio
+#[derive(UnitStruct, Debug, Clone, Default)]
+#[si(name="dimensionless", symbol="", no_display)]
+#[cfg_attr(feature="serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(transparent)]
+pub struct Dimensionless<T: NumLike>{
+	/// The value of this dimensionless ratio
+	pub value: T
+}
+
+impl<T> Dimensionless<T> where T: NumLike {
+
+	/// Returns the exponents of the SI base units of a dimensionless value (all zero)
+	pub const fn dimension() -> super::Dimension { super::Dimension{length: 0, mass: 0, time: 0, current: 0, temperature: 0, amount: 0, luminosity: 0} }
+
+	/// Returns a new dimensionless value from the given number
+	///
+	/// # Arguments
+	/// * `value` - Any number-like type
+	pub const fn new(value: T) -> Self { Dimensionless{value} }
+
+	/// Returns the number held by this dimensionless value
+	pub fn to_value(self) -> T { self.value }
+}
+
+/// Formats a dimensionless value as a bare number, since it has no unit symbol
+impl<T> fmt::Display for Dimensionless<T> where T: NumLike {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		fmt::Display::fmt(&self.value, f)
+	}
+}
+
+impl<T> super::SiUnit for Dimensionless<T> where T: NumLike {
+	type Number = T;
+	fn unit_name(&self) -> &'static str { Self::unit_name() }
+	fn unit_symbol(&self) -> &'static str { Self::unit_symbol() }
+	fn raw(&self) -> &T { &self.value }
+	fn dimension(&self) -> super::Dimension { Self::dimension() }
+	fn from_raw(value: T) -> Self { Dimensionless{value} }
+	fn into_raw(self) -> T { self.value }
+}
+
+/// Any number can be used as a dimensionless value
+impl<T> From<T> for Dimensionless<T> where T: NumLike {
+	fn from(value: T) -> Self { Dimensionless{value} }
+}
+
+/// Multiplying two dimensionless values returns a dimensionless value
+impl<T> core::ops::Mul<Dimensionless<T>> for Dimensionless<T> where T: NumLike {
+	type Output = Dimensionless<T>;
+	fn mul(self, rhs: Dimensionless<T>) -> Self::Output {
+		Dimensionless{value: self.value * rhs.value}
+	}
+}
+'''
+
+DIMENSIONLESS_POWERS_TEMPLATE='''
+/// Powers of a %(desc name)s value are also dimensionless. Dimensioned unit types 
+/// (eg `Distance`) do not have these functions, because raising them to an 
+/// arbitrary power would change their dimensions (eg m² or m^0.5)
+#[cfg(all(feature="num-traits", any(feature="std", feature="libm")))]
+impl<T> %(code name)s<T> where T: NumLike+num_traits::Float {
+
+	/// Returns this %(desc name)s value raised to a floating-point power 
+	/// (requires the `num-traits` feature, and either the `std` or `libm` feature)
+	///
+	/// # Arguments
+	/// * `exp` - The exponent
+	pub fn powf(self, exp: T) -> Self {
+		%(code name)s{value: self.value.powf(exp)}
+	}
+
+	/// Returns this %(desc name)s value raised to an integer power 
+	/// (requires the `num-traits` feature, and either the `std` or `libm` feature)
+	///
+	/// # Arguments
+	/// * `exp` - The exponent
+	pub fn powi(self, exp: i32) -> Self {
+		%(code name)s{value: self.value.powi(exp)}
+	}
+}
+'''
+
+TIME_DURATION_APPENDIX_TEMPLATE='''
+impl<T> Time<T> where T: NumLike+From<u8> {
+
+	/// Returns the frequency of a cycle with this time as its period (ie 1/t, same 
+	/// as `recip()`). For floating-point numbers, a period of zero returns an 
+	/// infinite frequency
+	pub fn frequency(self) -> Frequency<T> {
+		self.recip()
+	}
+}
+
+/// Converts a `core::time::Duration` into a Time value
+impl From<core::time::Duration> for Time<f64> {
+	fn from(duration: core::time::Duration) -> Self {
+		Time{s: duration.as_secs_f64()}
+	}
+}
+
+/// Converts a Time value into a `core::time::Duration`, returning a 
+/// `DurationConversionError` if the time is negative, not finite, or too large 
+/// to be stored in a `Duration`
+impl TryFrom<Time<f64>> for core::time::Duration {
+	type Error = super::DurationConversionError;
+	fn try_from(time: Time<f64>) -> Result<Self, Self::Error> {
+		core::time::Duration::try_from_secs_f64(time.s).map_err(|_| super::DurationConversionError)
+	}
+}
+
+/// Multiplying a Velocity by a `core::time::Duration` returns the Distance travelled in 
+/// that time. The duration is converted to seconds as an `f64`, which is precise to about 
+/// a nanosecond or better for durations of up to about 104 days (2^53 nanoseconds), 
+/// and proportionally less precise for longer durations
+impl core::ops::Mul<core::time::Duration> for Velocity<f64> {
+	type Output = Distance<f64>;
+	fn mul(self, rhs: core::time::Duration) -> Self::Output {
+		Distance{m: self.mps * rhs.as_secs_f64()}
+	}
+}
+
+/// Dividing a Distance by a `core::time::Duration` returns the average Velocity over 
+/// that time. The duration is converted to seconds as an `f64` (see 
+/// `Mul<Duration> for Velocity`), and a zero duration returns an infinite (or NaN) velocity
+impl core::ops::Div<core::time::Duration> for Distance<f64> {
+	type Output = Velocity<f64>;
+	fn div(self, rhs: core::time::Duration) -> Self::Output {
+		Velocity{mps: self.m / rhs.as_secs_f64()}
+	}
+}
+
+impl Time<f64> {
+
+	/// Returns the time of the given number of ticks of a fixed-rate clock, eg 
+	/// `Time::from_ticks(90, 60)` is 1.5 seconds. Tick counts greater than 2^53 
+	/// lose precision when converted to `f64`
+	///
+	/// # Arguments
+	/// * `ticks` - Number of clock ticks
+	/// * `tick_hz` - Number of clock ticks per second
+	pub fn from_ticks(ticks: i64, tick_hz: u32) -> Self {
+		Time{s: ticks as f64 / tick_hz as f64}
+	}
+
+	/// Returns the number of ticks of a fixed-rate clock in this time, rounded to 
+	/// the nearest tick (half-way cases are rounded away from zero). Times too 
+	/// large for an `i64` tick count saturate at `i64::MAX` or `i64::MIN`, and NaN 
+	/// returns 0
+	///
+	/// # Arguments
+	/// * `tick_hz` - Number of clock ticks per second
+	pub fn to_ticks(self, tick_hz: u32) -> i64 {
+		let ticks = self.s * tick_hz as f64;
+		// float to int casts truncate towards zero and saturate at the i64 limits
+		let whole = ticks as i64;
+		let fraction = ticks - whole as f64;
+		if fraction >= 0.5 {
+			whole.saturating_add(1)
+		} else if fraction <= -0.5 {
+			whole.saturating_sub(1)
+		} else {
+			whole
+		}
+	}
+}
+'''
+
+DISTANCE_HYPOT_APPENDIX_TEMPLATE='''
+#[cfg(all(feature="num-traits", any(feature="std", feature="libm")))]
+impl<T> Distance<T> where T: NumLike+num_traits::Float {
+
+	/// Returns the length of the hypotenuse of a right-angle triangle with the 
+	/// given side lengths, ie sqrt(dx² + dy²), without overflowing or 
+	/// underflowing in the intermediate calculation (requires the `num-traits` 
+	/// feature, and either the `std` or `libm` feature)
+	///
+	/// # Arguments
+	/// * `dx` - The length of one side
+	/// * `dy` - The length of the other side
+	pub fn hypot(dx: Distance<T>, dy: Distance<T>) -> Self {
+		Distance{m: dx.m.hypot(dy.m)}
+	}
+
+	/// Returns the length of a 3D vector from its x, y, and z components, ie 
+	/// sqrt(dx² + dy² + dz²), without overflowing or underflowing in the 
+	/// intermediate calculation (requires the `num-traits` feature, and either the 
+	/// `std` or `libm` feature)
+	///
+	/// # Arguments
+	/// * `dx` - The x component
+	/// * `dy` - The y component
+	/// * `dz` - The z component
+	pub fn hypot3(dx: Distance<T>, dy: Distance<T>, dz: Distance<T>) -> Self {
+		Distance{m: dx.m.hypot(dy.m).hypot(dz.m)}
+	}
+}
+'''
+
+DISTANCE_POWERS_APPENDIX_TEMPLATE='''
+impl<T> Distance<T> where T: NumLike {
+
+	/// Returns the area of a square with this side length, ie `self * self`.
+	/// (Note that there is no equivalent `squared()` for `Velocity`, because
+	/// there is no dedicated unit type for squared velocity; the unit types with
+	/// the same dimensions, `AbsorbedDose` and `DoseEquivalent`, are not
+	/// physically equivalent)
+	pub fn squared(self) -> Area<T> {
+		Area{m2: self.m.clone() * self.m}
+	}
+
+	/// Returns the volume of a cube with this side length, ie `self * self * self`
+	pub fn cubed(self) -> Volume<T> {
+		Volume{m3: self.m.clone() * self.m.clone() * self.m}
+	}
+}
+'''
+
+ANGLE_TRIG_APPENDIX_TEMPLATE='''
+/// Trigonometric functions for angles with floating-point values (requires the 
+/// `num-traits` feature, and either the `std` or `libm` feature)
+#[cfg(all(feature="num-traits", any(feature="std", feature="libm")))]
+impl<T> Angle<T> where T: NumLike+num_traits::Float {
+
+	/// Returns the sine of this angle
+	pub fn sin(self) -> T { self.rad.sin() }
+
+	/// Returns the cosine of this angle
+	pub fn cos(self) -> T { self.rad.cos() }
+
+	/// Returns the tangent of this angle
+	pub fn tan(self) -> T { self.rad.tan() }
+
+	/// Returns the angle whose sine is the given value, in the range [-pi/2, pi/2] radians
+	///
+	/// # Arguments
+	/// * `x` - The sine of the angle, between -1 and 1
+	pub fn asin(x: T) -> Self { Angle{rad: x.asin()} }
+
+	/// Returns the angle whose cosine is the given value, in the range [0, pi] radians
+	///
+	/// # Arguments
+	/// * `x` - The cosine of the angle, between -1 and 1
+	pub fn acos(x: T) -> Self { Angle{rad: x.acos()} }
+
+	/// Returns the angle whose tangent is the given value, in the range [-pi/2, pi/2] radians
+	///
+	/// # Arguments
+	/// * `x` - The tangent of the angle
+	pub fn atan(x: T) -> Self { Angle{rad: x.atan()} }
+
+	/// Returns the angle of the point (x, y) from the x-axis, in the range [-pi, pi] radians
+	///
+	/// # Arguments
+	/// * `y` - The y coordinate of the point
+	/// * `x` - The x coordinate of the point
+	pub fn atan2(y: Distance<T>, x: Distance<T>) -> Self { Angle{rad: y.m.atan2(x.m)} }
+}
+
+/// Angle wrapping for angles with floating-point values (requires the `num-traits` 
+/// feature, and either the `std` or `libm` feature)
+#[cfg(all(feature="num-traits", any(feature="std", feature="libm")))]
+impl<T> Angle<T> where T: NumLike+num_traits::Float+num_traits::FloatConst+num_traits::Euclid {
+
+	/// Returns the equivalent angle in the range [0, 2pi) radians, eg 3pi becomes pi, 
+	/// 2pi becomes 0, and -pi/2 becomes 3pi/2
+	pub fn wrapped(self) -> Self {
+		let tau = T::TAU();
+		let rad = self.rad.rem_euclid(&tau);
+		// rounding can make rem_euclid(...) of a tiny negative angle return exactly 2pi
+		Angle{rad: if rad >= tau { T::zero() } else { rad }}
+	}
+
+	/// Returns the equivalent angle in the range (-pi, pi] radians, eg 3pi becomes pi, 
+	/// 2pi becomes 0, and 3pi/2 becomes -pi/2
+	pub fn wrapped_signed(self) -> Self {
+		let rad = self.wrapped().rad;
+		Angle{rad: if rad > T::PI() { rad - T::TAU() } else { rad }}
+	}
+}
+'''
+
+FREQUENCY_ANGULAR_VELOCITY_APPENDIX_TEMPLATE='''
+/// Conversion between frequency and angular velocity. Note that the revolutions 
+/// per minute of `Frequency::from_rpm(...)` and `to_rpm()` are cycles per minute 
+/// (1 rpm = 1/60 Hz), while those of `AngularVelocity::from_rpm(...)` and 
+/// `to_rpm()` include the 2π factor (1 rpm = 2π/60 rad/s)
+impl<T> Frequency<T> where T: NumLike+From<f64> {
+
+	/// Returns the angular velocity of a rotation at this frequency, ie ω = 2πf. 
+	/// Note that 1 Hz is one full rotation per second, which is 2π rad/s (not 1 rad/s)
+	pub fn to_angular_velocity(self) -> AngularVelocity<T> {
+		AngularVelocity{radps: self.Hz * T::from(core::f64::consts::TAU)}
+	}
+}
+
+/// Conversion between angular velocity and frequency (see `Frequency::to_angular_velocity()`)
+impl<T> AngularVelocity<T> where T: NumLike+From<f64> {
+
+	/// Returns the frequency of rotation at this angular velocity, ie f = ω/2π. 
+	/// Note that 2π rad/s is one full rotation per second, which is 1 Hz
+	pub fn to_frequency(self) -> Frequency<T> {
+		Frequency{Hz: self.radps / T::from(core::f64::consts::TAU)}
+	}
+}
+
+impl<T> Frequency<T> where T: NumLike+From<u8> {
+
+	/// Returns the period of one cycle at this frequency (ie 1/f, same as `recip()`). 
+	/// For floating-point numbers, a frequency of zero returns an infinite period
+	pub fn period(self) -> Time<T> {
+		self.recip()
+	}
+}
+
+/// Rotational kinematics
+impl<T> AngularVelocity<T> where T: NumLike {
+
+	/// Returns the tangential speed of a point at the given radius from the axis of 
+	/// rotation (ie v = ωr). Radians are a ratio of arc length to radius, so an 
+	/// angular velocity of 1 rad/s at a radius of 1 m is a speed of 1 m/s. This is a 
+	/// named function instead of an `AngularVelocity * Distance` operator because 
+	/// the result only makes sense when the angle is in radians and the distance is 
+	/// a radius, and dropping the radians silently would hide mistakes
+	///
+	/// # Arguments
+	/// * `radius` - The distance from the axis of rotation
+	pub fn tangential_speed(self, radius: Distance<T>) -> Velocity<T> {
+		Velocity{mps: self.radps * radius.m}
+	}
+}
+'''
+
+GEOMETRY_SHAPES_APPENDIX_TEMPLATE='''
+/// Areas of common shapes
+impl<T> Area<T> where T: NumLike+From<f64> {
+
+	/// Returns the area of a circle with the given radius (ie πr²)
+	///
+	/// # Arguments
+	/// * `radius` - The radius of the circle
+	pub fn circle(radius: Distance<T>) -> Self {
+		Area{m2: T::from(core::f64::consts::PI) * radius.m.clone() * radius.m}
+	}
+}
+
+/// Volumes of common shapes
+impl<T> Volume<T> where T: NumLike+From<f64> {
+
+	/// Returns the volume of a sphere with the given radius (ie 4/3 πr³)
+	///
+	/// # Arguments
+	/// * `radius` - The radius of the sphere
+	pub fn sphere(radius: Distance<T>) -> Self {
+		Volume{m3: T::from(4.0 / 3.0 * core::f64::consts::PI) * radius.m.clone() * radius.m.clone() * radius.m}
+	}
+
+	/// Returns the volume of a cylinder with the given radius and height (ie πr²h)
+	///
+	/// # Arguments
+	/// * `radius` - The radius of the cylinder
+	/// * `height` - The height (or length) of the cylinder
+	pub fn cylinder(radius: Distance<T>, height: Distance<T>) -> Self {
+		Area::circle(radius) * height
+	}
+}
+'''
+
+KINETIC_ENERGY_APPENDIX_TEMPLATE='''
+/// Kinetic energy of a moving mass
+impl<T> Mass<T> where T: NumLike+From<f64> {
+
+	/// Returns the (non-relativistic) kinetic energy of this mass moving at the 
+	/// given speed (ie ½mv²)
+	///
+	/// # Arguments
+	/// * `velocity` - The speed of this mass
+	pub fn kinetic_energy(self, velocity: Velocity<T>) -> Energy<T> {
+		Energy{J: T::from(0.5) * self.kg * velocity.mps.clone() * velocity.mps}
+	}
+}
+
+/// Kinetic energy of a moving mass
+impl<T> Momentum<T> where T: NumLike+From<f64> {
+
+	/// Returns the (non-relativistic) kinetic energy of a mass with this momentum 
+	/// (ie p²/2m)
+	///
+	/// # Arguments
+	/// * `mass` - The mass of the moving object
+	pub fn kinetic_energy(self, mass: Mass<T>) -> Energy<T> {
+		Energy{J: self.kgmps.clone() * self.kgmps / (T::from(2.0) * mass.kg)}
+	}
+}
+'''
+
+RADIOACTIVE_DECAY_APPENDIX_TEMPLATE='''
+/// Exponential decay of radioactive sources
+#[cfg(all(feature="num-traits", any(feature="std", feature="libm")))]
+impl<T> Radioactivity<T> where T: NumLike+num_traits::Float {
+
+	/// Returns the activity of this radioactive source after the given time has 
+	/// elapsed, ie A × 0.5^(t/t½) (requires the `num-traits` feature, and either 
+	/// the `std` or `libm` feature)
+	///
+	/// # Arguments
+	/// * `half_life` - The half-life of the radioactive isotope
+	/// * `elapsed` - The time that has passed since this activity was measured
+	pub fn after(self, half_life: Time<T>, elapsed: Time<T>) -> Self {
+		Radioactivity{Bq: self.Bq * (-(elapsed.s / half_life.s)).exp2()}
+	}
+}
+'''
+
+HYDROSTATIC_PRESSURE_APPENDIX_TEMPLATE='''
+/// Hydrostatic pressure of a fluid column
+impl<T> Pressure<T> where T: NumLike {
+
+	/// Returns the hydrostatic pressure at the given depth in a fluid of the given 
+	/// density (ie ρgh). This is the gauge pressure due to the fluid column only, so 
+	/// add the pressure at the surface (eg atmospheric pressure) to get the absolute 
+	/// pressure
+	///
+	/// # Arguments
+	/// * `density` - The density of the fluid
+	/// * `gravity` - The gravitational acceleration (eg `constants::STANDARD_GRAVITY`)
+	/// * `depth` - The depth below the surface of the fluid
+	pub fn hydrostatic(density: Density<T>, gravity: Acceleration<T>, depth: Distance<T>) -> Self {
+		Pressure{Pa: density.kgpm3 * gravity.mps2 * depth.m}
+	}
+}
+'''
+
+TORQUE_ENERGY_APPENDIX_TEMPLATE='''
+/// Explicit conversion between torque and energy. Torque and energy have the same 
+/// SI units (kg·m²/s², ie N·m = J), but they are different physical quantities: 
+/// torque is a rotational moment (the cross product of a lever arm and a force), 
+/// while energy is work done (the dot product of a force and a displacement). 
+/// Silently treating one as the other hides mistakes, so these types do not 
+/// convert automatically, and these functions must be called explicitly where 
+/// the conversion is intended (note that the work done by a torque is torque 
+/// multiplied by the angle of rotation in radians)
+impl<T> Torque<T> where T: NumLike {
+
+	/// Returns an energy value with the same number of joules as this torque has 
+	/// newton meters. Only use this where a torque is deliberately reinterpreted 
+	/// as an energy (eg work done per radian of rotation)
+	pub fn as_energy(self) -> Energy<T> {
+		Energy{J: self.Nm}
+	}
+}
+
+/// Explicit conversion between energy and torque (see `Torque::as_energy()`)
+impl<T> Energy<T> where T: NumLike {
+
+	/// Returns a torque value with the same number of newton meters as this energy 
+	/// has joules. Only use this where an energy is deliberately reinterpreted as 
+	/// a torque (eg energy per radian of rotation)
+	pub fn as_torque(self) -> Torque<T> {
+		Torque{Nm: self.J}
+	}
+}
+'''
+
+NAMED_DIMENSIONLESS_TEMPLATE='''
+/// The %(desc name)s unit type, a dimensionless ratio (%(ratio desc)s) that is 
+/// labeled as %(desc name)s instead of being a bare number
+#[derive(UnitStruct, Debug, Clone, Default)]
+#[si(name="%(unit name)s", symbol="%(unit symbol)s")]
+#[cfg_attr(feature="serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(transparent)]
+pub struct %(code name)s<T: NumLike>{
+	/// The value of this %(desc name)s as a dimensionless ratio
+	pub value: T
+}
+
+impl<T> %(code name)s<T> where T: NumLike {
+
+	/// Returns the exponents of the SI base units of %(desc name)s (all zero)
+	pub const fn dimension() -> super::Dimension { super::Dimension{length: 0, mass: 0, time: 0, current: 0, temperature: 0, amount: 0, luminosity: 0} }
+
+	/// Returns a new %(desc name)s value from the given dimensionless number
+	///
+	/// # Arguments
+	/// * `value` - Any number-like type, representing the %(desc name)s ratio
+	pub const fn new(value: T) -> Self { %(code name)s{value} }
+
+	/// Returns this %(desc name)s value as a generic `Dimensionless` value
+	pub fn to_dimensionless(self) -> Dimensionless<T> { Dimensionless{value: self.value} }
+}
+
+impl<T> super::SiUnit for %(code name)s<T> where T: NumLike {
+	type Number = T;
+	fn unit_name(&self) -> &'static str { Self::unit_name() }
+	fn unit_symbol(&self) -> &'static str { Self::unit_symbol() }
+	fn raw(&self) -> &T { &self.value }
+	fn dimension(&self) -> super::Dimension { Self::dimension() }
+	fn from_raw(value: T) -> Self { %(code name)s{value} }
+	fn into_raw(self) -> T { self.value }
+}
+
+/// Any dimensionless value can be labeled as %(desc name)s
+impl<T> From<Dimensionless<T>> for %(code name)s<T> where T: NumLike {
+	fn from(src: Dimensionless<T>) -> Self { %(code name)s{value: src.value} }
+}
+'''
+
+STRAIN_MACH_APPENDIX_TEMPLATE='''
+impl<T> Strain<T> where T: NumLike {
+
+	/// Returns the strain of an object that changed length by `elongation` from 
+	/// its `original_length` (ie ε = ΔL/L)
+	///
+	/// # Arguments
+	/// * `elongation` - The change in length
+	/// * `original_length` - The length before the change
+	pub fn from_lengths(elongation: Distance<T>, original_length: Distance<T>) -> Self {
+		Strain{value: elongation.m / original_length.m}
+	}
+
+	/// Returns the change in length of an object with the given original length 
+	/// under this strain (ie ΔL = εL)
+	///
+	/// # Arguments
+	/// * `original_length` - The length before the change
+	pub fn elongation(self, original_length: Distance<T>) -> Distance<T> {
+		Distance{m: self.value * original_length.m}
+	}
+}
+
+impl<T> Strain<T> where T: NumLike+From<f64> {
+
+	/// Returns a new strain value from the given number of microstrain (µε, millionths of a strain)
+	///
+	/// # Arguments
+	/// * `microstrain` - Any number-like type, representing a strain in millionths
+	pub fn from_microstrain(microstrain: T) -> Self {
+		Strain{value: microstrain * T::from(1e-6)}
+	}
+
+	/// Returns this strain as a number of microstrain (µε, millionths of a strain)
+	pub fn to_microstrain(&self) -> T {
+		self.value.clone() * T::from(1e6)
+	}
+}
+
+impl<T> MachNumber<T> where T: NumLike {
+
+	/// Returns the Mach number of the given speed relative to the given speed of 
+	/// sound (ie Ma = v/c)
+	///
+	/// # Arguments
+	/// * `speed` - The speed of the object
+	/// * `speed_of_sound` - The speed of sound in the surrounding medium
+	pub fn from_velocities(speed: Velocity<T>, speed_of_sound: Velocity<T>) -> Self {
+		MachNumber{value: speed.mps / speed_of_sound.mps}
+	}
+
+	/// Returns the speed for this Mach number, given the speed of sound 
+	/// in the surrounding medium (ie v = Ma × c)
+	///
+	/// # Arguments
+	/// * `speed_of_sound` - The speed of sound in the surrounding medium
+	pub fn to_velocity(self, speed_of_sound: Velocity<T>) -> Velocity<T> {
+		Velocity{mps: self.value * speed_of_sound.mps}
+	}
+}
+
+impl<T> Velocity<T> where T: NumLike {
+
+	/// Returns the Mach number of this speed, given the speed of sound in the 
+	/// surrounding medium (same as `MachNumber::from_velocities(self, speed_of_sound)`)
+	///
+	/// # Arguments
+	/// * `speed_of_sound` - The speed of sound in the surrounding medium
+	pub fn mach_number(self, speed_of_sound: Velocity<T>) -> MachNumber<T> {
+		MachNumber::from_velocities(self, speed_of_sound)
+	}
+}
+'''
+
+NON_NEGATIVE_CONSTRUCTOR_TEMPLATE='''
+impl<T> %(code name)s<T> where T: NumLike+PartialOrd+From<u8> {
+
+	/// Returns a new %(desc name)s value from the given number of %(unit name)s, or a 
+	/// `DomainError` if the number is negative (or NaN), because %(desc name)s cannot 
+	/// physically be less than zero. Use `from_%(unit symbol)s(...)` to skip this check
+	///
+	/// # Arguments
+	/// * `%(unit symbol)s` - Any number-like type, representing a quantity of %(unit name)s
+	pub fn try_from_%(unit symbol)s(%(unit symbol)s: T) -> Result<Self, super::DomainError> {
+		// NaN is not comparable to zero, so it is rejected along with negative values
+		match %(unit symbol)s.partial_cmp(&T::from(0u8)) {
+			Some(core::cmp::Ordering::Less) | None => Err(super::DomainError{unit_type: "%(code name)s"}),
+			_ => Ok(%(code name)s{%(unit symbol)s})
+		}
+	}
+}
+'''
+
+UNIT_TABLE_TEMPLATE='''
+//! This module provides the `ALL_UNITS` table, which lists the name, symbol, 
+//! and dimension of every unit type, for introspection (eg to generate 
+//! documentation or to fill a drop-down menu in a user interface).
+use super::UnitInfo;
+
+/// Table of every unit type in this crate that is generated from the unit 
+/// definitions (the hand-written dimensionless types, such as `Dimensionless` 
+/// and `MachNumber`, are not included), in alphabetical order of module and 
+/// then in the order that they are defined. Note that dimensionally equivalent 
+/// unit types may share a symbol (eg `Entropy` and `HeatCapacity` are both J/K)
+pub const ALL_UNITS: &[UnitInfo] = &[
+%(entries)s
+];
+'''
+
+UNIT_TABLE_ENTRY_TEMPLATE='''	UnitInfo{type_name: "%(code name)s", module: "%(category)s", unit_name: "%(unit name)s", symbol: "%(unit symbol human)s", dimension: %(dimension)s},'''
+
+UNIT_TABLE_TEST_TEMPLATE='''		assert!(super::ALL_UNITS.iter().any(|u| u.type_name == "%(code name)s" && u.symbol == %(code name)s::<f64>::unit_symbol()
+			&& u.dimension == %(code name)s::<f64>::dimension()));'''
+
+SI_MACRO_TEMPLATE='''
+//! This module provides the `si!` macro for writing unit values as typed 
+//! literals, such as `si!(9.81 m/s^2)`.
+
+/// The `si!` macro creates a unit struct from a number followed by its unit 
+/// symbol, eg `si!(5.0 m)` is equivalent to `Distance::from_m(5.0)`. 
+/// 
+/// The SI units of each unit type are written as they are displayed, with `^2` 
+/// and `^3` for powers and `.` for multiplication (eg `si!(9.81 m/s^2)`, 
+/// `si!(2.0 kg.m^2)`, or `si!(3.3 V)`), and any of the other supported units 
+/// may be written using the name of its `from_...` function (eg `si!(5.0 km)` 
+/// or `si!(60.0 mph)`). The number may be a literal or an expression in 
+/// parentheses, eg `si!((x * 2.0) m)`. 
+/// 
+/// A unit symbol that is not recognized causes a compile error. Note that `K` 
+/// always creates a `Temperature` (not a `TemperatureInterval`), and that unit 
+/// symbols which are shared by more than one unit type (such as `J/K`, which 
+/// is the unit for both `Entropy` and `HeatCapacity`) are not supported, so 
+/// use the unit type's constructor functions instead.
+/// 
+/// # Examples
+/// ```rust
+/// use simple_si_units::si;
+/// use simple_si_units::base::Distance;
+/// use simple_si_units::mechanical::Acceleration;
+/// 
+/// assert_eq!(si!(5.0 m), Distance::from_m(5.0));
+/// assert_eq!(si!(9.81 m/s^2), Acceleration::from_mps2(9.81));
+/// assert_eq!(si!(1.5 km), Distance::from_m(1500.0));
+/// ```
+#[macro_export]
+macro_rules! si {
+	($value:literal $($unit:tt)+) => { $crate::si!(@unit $value; $($unit)+) };
+	(($value:expr) $($unit:tt)+) => { $crate::si!(@unit ($value); $($unit)+) };
+%(arms)s
+	(@unit $value:expr; $($unit:tt)+) => {
+		compile_error!(concat!("unknown or ambiguous unit symbol: ", stringify!($($unit)+)))
+	};
+}
+'''
+
+SI_MACRO_ARM_TEMPLATE='''	(@unit $value:expr; %(tokens)s) => { $crate::%(module)s::%(code name)s::from_%(unit symbol)s($value) };'''
+
+QUANTITY_MODULE_TEMPLATE='''
+//! This module provides the `Quantity` enum, which can hold a value of any of 
+//! the unit types, for when the unit type is only known at runtime (such as in
+//! a calculator or scripting language).
+use core::fmt;
+use super::NumLike;
+use super::base::*;
+use super::chemical::*;
+use super::electromagnetic::*;
+use super::geometry::*;
+use super::mechanical::*;
+use super::nuclear::*;
+
+/// A `Quantity` holds a value of any of the unit types, with the unit type 
+/// checked at runtime instead of compile time. Adding or subtracting two 
+/// quantities only succeeds if they are the same unit type, and multiplying or 
+/// dividing two quantities only succeeds if the unit structs themselves 
+/// support that operation (eg `Distance / Time -> Velocity`). Dimensionless 
+/// numbers, such as the ratio of two values of the same unit type, are held by
+/// the `Scalar` variant.
+/// 
+/// # Examples
+/// ```rust
+/// use simple_si_units::quantity::Quantity;
+/// use simple_si_units::base::*;
+/// use simple_si_units::mechanical::Velocity;
+/// 
+/// let d = Quantity::from(Distance::from_m(100.0));
+/// let t = Quantity::from(Time::from_s(20.0));
+/// assert_eq!(d.try_div(&t).unwrap(), Quantity::from(Velocity::from_mps(5.0)));
+/// assert!(d.try_add(&t).is_err());
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub enum Quantity<T: NumLike> {
+	/// A dimensionless number
+	Scalar(T),
+%(variants)s
+}
+
+/// The error returned when an operation on `Quantity` values is not 
+/// dimensionally valid, eg adding a distance to a time
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DimensionError {
+	/// The operation that failed, eg "add"
+	pub operation: &'static str,
+	/// The unit symbol of the left-hand quantity
+	pub left: &'static str,
+	/// The unit symbol of the right-hand quantity (or the unit type that was 
+	/// requested, when converting a `Quantity` to a unit struct)
+	pub right: &'static str
+}
+
+impl fmt::Display for DimensionError {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		write!(f, "cannot {} quantities with units '{}' and '{}'", self.operation, self.left, self.right)
+	}
+}
+
+impl<T> Quantity<T> where T: NumLike {
+
+	/// Returns the unit symbol of this quantity (or an empty string for a `Scalar`)
+	pub fn unit_symbol(&self) -> &'static str {
+		match self {
+			Quantity::Scalar(_) => "",
+%(symbol arms)s
+		}
+	}
+
+	/// Returns the standard unit name of this quantity (or an empty string for a `Scalar`)
+	pub fn unit_name(&self) -> &'static str {
+		match self {
+			Quantity::Scalar(_) => "",
+%(name arms)s
+		}
+	}
+
+	/// Adds two quantities, returning an error if they are not the same unit type
+	///
+	/// # Arguments
+	/// * `other` - The quantity to add to this one
+	pub fn try_add(&self, other: &Quantity<T>) -> Result<Quantity<T>, DimensionError> {
+		match (self, other) {
+			(Quantity::Scalar(a), Quantity::Scalar(b)) => Ok(Quantity::Scalar(a.clone() + b.clone())),
+%(add arms)s
+			_ => Err(self.dimension_error("add", other))
+		}
+	}
+
+	/// Subtracts a quantity from this one, returning an error if they are not the 
+	/// same unit type
+	///
+	/// # Arguments
+	/// * `other` - The quantity to subtract from this one
+	pub fn try_sub(&self, other: &Quantity<T>) -> Result<Quantity<T>, DimensionError> {
+		match (self, other) {
+			(Quantity::Scalar(a), Quantity::Scalar(b)) => Ok(Quantity::Scalar(a.clone() - b.clone())),
+%(sub arms)s
+			_ => Err(self.dimension_error("subtract", other))
+		}
+	}
+
+	/// Multiplies two quantities, returning an error if there is no unit type 
+	/// for the result
+	///
+	/// # Arguments
+	/// * `other` - The quantity to multiply this one by
+	pub fn try_mul(&self, other: &Quantity<T>) -> Result<Quantity<T>, DimensionError> {
+		match (self, other) {
+			(Quantity::Scalar(s), q) | (q, Quantity::Scalar(s)) => Ok(q.clone() * s.clone()),
+%(mul arms)s
+			_ => Err(self.dimension_error("multiply", other))
+		}
+	}
+
+	/// Divides this quantity by another, returning an error if there is no unit 
+	/// type for the result
+	///
+	/// # Arguments
+	/// * `other` - The quantity to divide this one by
+	pub fn try_div(&self, other: &Quantity<T>) -> Result<Quantity<T>, DimensionError> {
+		match (self, other) {
+			(q, Quantity::Scalar(s)) => Ok(q.clone() / s.clone()),
+%(div arms)s
+			_ => Err(self.dimension_error("divide", other))
+		}
+	}
+
+	fn dimension_error(&self, operation: &'static str, other: &Quantity<T>) -> DimensionError {
+		DimensionError{operation, left: self.unit_symbol(), right: other.unit_symbol()}
+	}
+}
+
+/// Multiplying a quantity by a scalar value returns a quantity of the same unit type
+impl<T> core::ops::Mul<T> for Quantity<T> where T: NumLike {
+	type Output = Quantity<T>;
+	fn mul(self, rhs: T) -> Self::Output {
+		match self {
+			Quantity::Scalar(v) => Quantity::Scalar(v * rhs),
+%(scalar mul arms)s
+		}
+	}
+}
+
+/// Dividing a quantity by a scalar value returns a quantity of the same unit type
+impl<T> core::ops::Div<T> for Quantity<T> where T: NumLike {
+	type Output = Quantity<T>;
+	fn div(self, rhs: T) -> Self::Output {
+		match self {
+			Quantity::Scalar(v) => Quantity::Scalar(v / rhs),
+%(scalar div arms)s
+		}
+	}
+}
+
+/// Formats the quantity the same way as the unit struct that it holds
+impl<T> fmt::Display for Quantity<T> where T: NumLike {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			Quantity::Scalar(v) => fmt::Display::fmt(v, f),
+%(display arms)s
+		}
+	}
+}
+%(conversions)s
+'''
+
+QUANTITY_CONVERSION_TEMPLATE='''
+/// Converts a %(desc name)s value into a `Quantity`
+impl<T> From<%(code name)s<T>> for Quantity<T> where T: NumLike {
+	fn from(value: %(code name)s<T>) -> Self { Quantity::%(code name)s(value) }
+}
+
+/// Converts a `Quantity` into a %(desc name)s value, returning an error if it holds a different unit type
+impl<T> TryFrom<Quantity<T>> for %(code name)s<T> where T: NumLike {
+	type Error = DimensionError;
+	fn try_from(value: Quantity<T>) -> Result<Self, Self::Error> {
+		match value {
+			Quantity::%(code name)s(v) => Ok(v),
+			_ => Err(DimensionError{operation: "convert", left: value.unit_symbol(), right: Self::unit_symbol()})
+		}
+	}
+}
+'''
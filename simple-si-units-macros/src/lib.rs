@@ -27,7 +27,35 @@ use syn::*;
 ///   return weight*a + (1.-weight)*b;
 /// }
 /// ```
-#[proc_macro_derive(UnitStruct)]
+/// 
+/// The derived struct also implements `Display`, which formats the value 
+/// followed by its unit symbol (eg "1.5 m²/s"), or by the name of the field 
+/// if no symbol is given (eg "1.5 square_meters_per_second"). Use 
+/// `#[si(no_display)]` to implement `Display` yourself instead. Similarly, 
+/// `#[si(no_self_sub)]` skips the `Sub` and `SubAssign` implementations for 
+/// two values of the same type, for unit structs where the difference is a 
+/// different type (eg the difference between two absolute temperatures is a 
+/// temperature interval).
+/// 
+/// The unit name and symbol can optionally be given with the `si` attribute,
+/// in which case the derived struct also gets `unit_name()` and 
+/// `unit_symbol()` functions, just like the unit structs of 
+/// **simple-si-units**:
+/// 
+/// ```rust
+/// use simple_si_units_macros::UnitStruct;
+/// use simple_si_units_core::NumLike;
+/// 
+/// #[derive(UnitStruct, Debug, Clone)]
+/// #[si(name = "square meters per second", symbol = "m²/s")]
+/// struct HyperVelocity<T: NumLike>{
+///   square_meters_per_second: T
+/// }
+/// 
+/// assert_eq!(HyperVelocity::<f64>::unit_symbol(), "m²/s");
+/// assert_eq!(format!("{}", HyperVelocity{square_meters_per_second: 1.5}), "1.5 m²/s");
+/// ```
+#[proc_macro_derive(UnitStruct, attributes(si))]
 pub fn derive_unit(tokens: TokenStream) -> TokenStream {
 	// convert the input tokens into an ast, specially from a derive
 	let input: syn::DeriveInput = syn::parse(tokens).expect("syn::parse failed on proc macro \
@@ -61,6 +89,76 @@ fn impl_derive_unit(input: &syn::DeriveInput) -> TokenStream {
 	}
 	let data_name = &fields[0].ident.as_ref().unwrap();
 	let data_type = &fields[0].ty;
+	let si_attributes = parse_si_attributes(&input.attrs);
+	let unit_name_gen = match &si_attributes.name {
+		Some(unit_name) => quote! {
+			impl<#data_type: NumLike> #name<#data_type> {
+				#[doc="Returns the standard unit name of this unit struct"]
+				pub fn unit_name() -> &'static str { #unit_name }
+			}
+		},
+		None => quote! {}
+	};
+	let unit_symbol_gen = match &si_attributes.symbol {
+		Some(unit_symbol) => quote! {
+			impl<#data_type: NumLike> #name<#data_type> {
+				#[doc="Returns the abbreviated name or symbol of this unit struct"]
+				pub fn unit_symbol() -> &'static str { #unit_symbol }
+			}
+		},
+		None => quote! {}
+	};
+	// the Display label is the unit symbol if given, otherwise the name of the field
+	let display_label = match &si_attributes.symbol {
+		Some(unit_symbol) => quote! {#unit_symbol},
+		None => quote! {stringify!(#data_name)}
+	};
+	let display_gen = match si_attributes.display {
+		true => quote! {
+			#[doc="Formats the unit value as a number followed by its unit symbol"]
+			impl<#data_type: NumLike> core::fmt::Display for #name<#data_type> {
+				fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+					write!(f, "{} {}", &self.#data_name, #display_label)
+				}
+			}
+		},
+		false => quote! {}
+	};
+	let self_sub_gen = match si_attributes.self_sub {
+		true => quote! {
+			#[doc="Subtracting two unit values of the same type returns a new unit value of the same \
+			type"]
+			impl<#data_type: NumLike> core::ops::Sub<Self> for
+			#name<#data_type> {
+				type Output = Self;
+				fn sub(self, rhs: Self) -> Self::Output {
+					return Self{#data_name: self.#data_name - rhs.#data_name}
+				}
+			}
+			#[doc="Subtracts the given unit value from this unit value"]
+			impl<#data_type: NumLike> core::ops::SubAssign for #name<#data_type> {
+				fn sub_assign(&mut self, rhs: Self){
+					self.#data_name -= rhs.#data_name;
+				}
+			}
+			#[doc="Subtracts the given borrowed unit value from this unit value"]
+			impl<#data_type: NumLike> core::ops::SubAssign<&Self> for #name<#data_type> {
+				fn sub_assign(&mut self, rhs: &Self){
+					self.#data_name -= rhs.#data_name.clone();
+				}
+			}
+			#[doc="Subtracting two unit values of the same type returns a new unit value of the same \
+			type (automatically clones the referenced data for convenient ergonomics)"]
+			impl<#data_type: NumLike> core::ops::Sub<Self> for
+			&#name<#data_type> {
+				type Output = #name<#data_type>;
+				fn sub(self, rhs: Self) -> Self::Output {
+					return Self::Output{#data_name: self.#data_name.clone() - rhs.#data_name.clone()}
+				}
+			}
+		},
+		false => quote! {}
+	};
 	let gen = quote! {
 		#[doc="This struct implements the Copy marker trait if it's member data type also has the \
 		Copy trait"]
@@ -119,19 +217,10 @@ fn impl_derive_unit(input: &syn::DeriveInput) -> TokenStream {
 				self.#data_name += rhs.#data_name;
 			}
 		}
-		#[doc="Subtracting two unit values of the same type returns a new unit value of the same \
-		type"]
-		impl<#data_type: NumLike> core::ops::Sub<Self> for
-		#name<#data_type> {
-			type Output = Self;
-			fn sub(self, rhs: Self) -> Self::Output {
-				return Self{#data_name: self.#data_name - rhs.#data_name}
-			}
-		}
-		#[doc="Subtracts the given unit value from this unit value"]
-		impl<#data_type: NumLike> core::ops::SubAssign for #name<#data_type> {
-			fn sub_assign(&mut self, rhs: Self){
-				self.#data_name -= rhs.#data_name;
+		#[doc="Adds the given borrowed unit value to this unit value"]
+		impl<#data_type: NumLike> core::ops::AddAssign<&Self> for #name<#data_type> {
+			fn add_assign(&mut self, rhs: &Self){
+				self.#data_name += rhs.#data_name.clone();
 			}
 		}
 		#[doc="Dividing a unit value by another of the same type returns a scalar value"]
@@ -156,6 +245,12 @@ fn impl_derive_unit(input: &syn::DeriveInput) -> TokenStream {
 				self.#data_name /= rhs;
 			}
 		}
+		#[doc="Divides this unit value by a borrowed scalar"]
+		impl<#data_type: NumLike> core::ops::DivAssign<&#data_type> for #name<#data_type> {
+			fn div_assign(&mut self, rhs: &#data_type){
+				self.#data_name /= rhs.clone();
+			}
+		}
 		#[doc="Multiplying a unit value by a scalar value returns a unit value"]
 		impl<#data_type: NumLike> core::ops::Mul<#data_type> for
 		#name<#data_type> {
@@ -186,6 +281,12 @@ fn impl_derive_unit(input: &syn::DeriveInput) -> TokenStream {
 				self.#data_name *= rhs;
 			}
 		}
+		#[doc="Multiplies this unit value by a borrowed scalar"]
+		impl<#data_type: NumLike> core::ops::MulAssign<&#data_type> for #name<#data_type> {
+			fn mul_assign(&mut self, rhs: &#data_type){
+				self.#data_name *= rhs.clone();
+			}
+		}
 		#[doc="Multiplying a unit value by a scalar value returns a unit value"]
 		impl<#data_type>
 		core::ops::Mul<#name<#data_type>> for
@@ -294,15 +395,6 @@ fn impl_derive_unit(input: &syn::DeriveInput) -> TokenStream {
 				return Self::Output{#data_name: self.#data_name.clone() + rhs.#data_name.clone()}
 			}
 		}
-		#[doc="Subtracting two unit values of the same type returns a new unit value of the same \
-		type (automatically clones the referenced data for convenient ergonomics)"]
-		impl<#data_type: NumLike> core::ops::Sub<Self> for
-		&#name<#data_type> {
-			type Output = #name<#data_type>;
-			fn sub(self, rhs: Self) -> Self::Output {
-				return Self::Output{#data_name: self.#data_name.clone() - rhs.#data_name.clone()}
-			}
-		}
 		#[doc="Dividing a unit value by another of the same type returns a scalar value \
 		(automatically clones the referenced data for convenient ergonomics)"]
 		impl<#data_type: NumLike> core::ops::Div<Self> for
@@ -455,6 +547,10 @@ fn impl_derive_unit(input: &syn::DeriveInput) -> TokenStream {
 				return Self::Output{#data_name: self.#data_name.clone().neg()}
 			}
 		}
+		#unit_name_gen
+		#unit_symbol_gen
+		#display_gen
+		#self_sub_gen
 		// Mul DT by Self and Self by DT -> Self
 
         // impl #name {
@@ -473,6 +569,54 @@ fn impl_derive_unit(input: &syn::DeriveInput) -> TokenStream {
 }
 
 
+/// The options of the `#[si(...)]` attribute of the derived struct
+struct SiAttributes {
+	/// unit name, from `name = "..."`
+	name: Option<LitStr>,
+	/// unit symbol, from `symbol = "..."`
+	symbol: Option<LitStr>,
+	/// false if the `no_display` option is given
+	display: bool,
+	/// false if the `no_self_sub` option is given
+	self_sub: bool
+}
+
+/// Reads the unit name and symbol from the optional
+/// `#[si(name = "...", symbol = "...")]` attribute of the derived struct
+fn parse_si_attributes(attrs: &[Attribute]) -> SiAttributes {
+	let usage_msg = "The si attribute of simple_si_units::UnitStruct must be of the form \
+	#[si(name = \"...\", symbol = \"...\")], eg #[si(name = \"meters per second\", symbol = \"m/s\")], \
+	or #[si(no_display)] to skip deriving the Display trait, or #[si(no_self_sub)] to skip \
+	deriving subtraction of two values of the same type";
+	let mut si_attributes = SiAttributes{name: None, symbol: None, display: true, self_sub: true};
+	for attr in attrs.iter().filter(|a| a.path.is_ident("si")) {
+		let list = match attr.parse_meta() {
+			Ok(Meta::List(list)) => list,
+			_ => panic!("{}", usage_msg)
+		};
+		for nested in list.nested.iter() {
+			match nested {
+				NestedMeta::Meta(Meta::NameValue(MetaNameValue{path, lit: Lit::Str(value), ..})) => {
+					if path.is_ident("name") {
+						si_attributes.name = Some(value.clone());
+					} else if path.is_ident("symbol") {
+						si_attributes.symbol = Some(value.clone());
+					} else {
+						panic!("{}", usage_msg)
+					}
+				},
+				NestedMeta::Meta(Meta::Path(path)) if path.is_ident("no_display") => {
+					si_attributes.display = false;
+				},
+				NestedMeta::Meta(Meta::Path(path)) if path.is_ident("no_self_sub") => {
+					si_attributes.self_sub = false;
+				},
+				_ => panic!("{}", usage_msg)
+			}
+		}
+	}
+	si_attributes
+}
 // #[test]
 // fn macro_test() {
 // 	println!("Testing UnitStruct procedural macro...");
@@ -0,0 +1,10 @@
+use simple_si_units_macros::UnitStruct;
+use simple_si_units_core::NumLike;
+
+
+#[derive(UnitStruct, Debug, Clone)]
+#[si(name = "square meters per second", symbol = "m²/s")]
+pub struct HyperVelocity<DT: NumLike>
+{
+	m2ps: DT
+}
@@ -0,0 +1,9 @@
+use simple_si_units_macros::UnitStruct;
+use simple_si_units_core::NumLike;
+
+
+#[derive(UnitStruct, Debug, Clone)]
+pub struct Furlongs<DT: NumLike>
+{
+	furlongs: DT
+}
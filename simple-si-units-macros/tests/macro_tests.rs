@@ -20,6 +20,56 @@ fn numlike_test(){
 	let _ = a.x * b.x;
 }
 
+#[derive(simple_si_units_macros::UnitStruct, Debug, Clone)]
+#[si(name = "square meters per second", symbol = "m²/s")]
+struct HyperVelocity<DT: NumLike>
+{
+	m2ps: DT
+}
+#[test]
+fn si_attribute_test(){
+	assert_eq!(HyperVelocity::<f64>::unit_name(), "square meters per second");
+	assert_eq!(HyperVelocity::<f64>::unit_symbol(), "m²/s");
+	let v = HyperVelocity{m2ps: 1.5} + HyperVelocity{m2ps: 1.5};
+	assert_eq!(format!("{}", v), "3 m²/s");
+}
+
+#[derive(simple_si_units_macros::UnitStruct, Debug, Clone)]
+struct Furlongs<DT: NumLike>
+{
+	furlongs: DT
+}
+#[test]
+fn derived_display_test(){
+	assert_eq!(format!("{}", Furlongs{furlongs: 2.5}), "2.5 furlongs");
+	assert_eq!(format!("{}", HyperVelocity{m2ps: -1}), "-1 m²/s");
+}
+
+#[derive(simple_si_units_macros::UnitStruct, Debug, Clone)]
+#[si(symbol = "K", no_self_sub)]
+struct AbsoluteTemp<DT: NumLike>
+{
+	k: DT
+}
+#[derive(simple_si_units_macros::UnitStruct, Debug, Clone)]
+#[si(symbol = "K")]
+struct TempChange<DT: NumLike>
+{
+	k: DT
+}
+impl<DT: NumLike> core::ops::Sub<Self> for AbsoluteTemp<DT> {
+	type Output = TempChange<DT>;
+	fn sub(self, rhs: Self) -> Self::Output {
+		TempChange{k: self.k - rhs.k}
+	}
+}
+#[test]
+fn no_self_sub_test(){
+	let dt = AbsoluteTemp{k: 300.0} - AbsoluteTemp{k: 280.0};
+	assert_eq!(dt, TempChange{k: 20.0});
+	assert_eq!(AbsoluteTemp{k: 300.0} + AbsoluteTemp{k: 280.0}, AbsoluteTemp{k: 580.0});
+}
+
 /* // comment this line for testing, uncomment for publish
 #[test]
 fn proc_macro_test() {
@@ -0,0 +1,61 @@
+//! This module provides physical constants as unit structs, such as the speed
+//! of light and the elementary charge. The values are the
+//! [CODATA 2018](https://physics.nist.gov/cuu/Constants/) recommended values
+//! (several of which are exact by definition of the SI units).
+//!
+//! Constants which do not have a matching unit struct in this crate (such as
+//! the gravitational constant) are provided as plain `f64` values in SI units.
+use super::base::*;
+use super::electromagnetic::*;
+use super::mechanical::*;
+
+/// The speed of light in vacuum, c (exact)
+pub const SPEED_OF_LIGHT: Velocity<f64> = Velocity::from_mps_const(299792458.0);
+
+/// The elementary charge, e, ie the charge of a proton (exact)
+pub const ELEMENTARY_CHARGE: Charge<f64> = Charge::from_C_const(1.602176634e-19);
+
+/// The Avogadro constant, N<sub>A</sub>, ie the number of particles per mole (exact)
+pub const AVOGADRO_NUMBER: InverseAmount<f64> = InverseAmount::from_per_mol_const(6.02214076e23);
+
+/// The electron-volt, ie the energy gained by an electron when it is
+/// accelerated through 1 volt of electric potential (exact)
+pub const ELECTRON_VOLT: Energy<f64> = Energy::from_J_const(1.602176634e-19);
+
+/// The standard acceleration due to gravity at the surface of the Earth, g<sub>0</sub> (exact)
+pub const STANDARD_GRAVITY: Acceleration<f64> = Acceleration::from_mps2_const(9.80665);
+
+/// The standard atmospheric pressure at sea level (exact)
+pub const STANDARD_ATMOSPHERE: Pressure<f64> = Pressure::from_Pa_const(101325.0);
+
+/// The rest mass of an electron, m<sub>e</sub>
+pub const ELECTRON_MASS: Mass<f64> = Mass::from_kg_const(9.1093837015e-31);
+
+/// The rest mass of a proton, m<sub>p</sub>
+pub const PROTON_MASS: Mass<f64> = Mass::from_kg_const(1.67262192369e-27);
+
+/// The rest mass of a neutron, m<sub>n</sub>
+pub const NEUTRON_MASS: Mass<f64> = Mass::from_kg_const(1.67492749804e-27);
+
+/// The atomic mass constant (aka 1 dalton), m<sub>u</sub>, ie 1/12th of the mass of a carbon-12 atom
+pub const ATOMIC_MASS_CONSTANT: Mass<f64> = Mass::from_kg_const(1.66053906660e-27);
+
+/// The Newtonian constant of gravitation, G, in cubic meters per kilogram per
+/// second squared (m³/kg·s²)
+pub const GRAVITATIONAL_CONSTANT: f64 = 6.67430e-11;
+
+/// The Planck constant, h, in joule-seconds (J·s) (exact)
+pub const PLANCK_CONSTANT: f64 = 6.62607015e-34;
+
+/// The reduced Planck constant, ħ = h/2π, in joule-seconds (J·s)
+pub const REDUCED_PLANCK_CONSTANT: f64 = 1.054571817e-34;
+
+/// The Boltzmann constant, k<sub>B</sub>, in joules per kelvin (J/K) (exact)
+pub const BOLTZMANN_CONSTANT: f64 = 1.380649e-23;
+
+/// The molar gas constant, R = N<sub>A</sub>k<sub>B</sub>, in joules per mole per kelvin (J/mol·K) (exact)
+pub const MOLAR_GAS_CONSTANT: f64 = 8.31446261815324;
+
+/// The Faraday constant, F = N<sub>A</sub>e, ie the charge of one mole of
+/// protons, in coulombs per mole (C/mol) (exact)
+pub const FARADAY_CONSTANT: f64 = 96485.33212331001;
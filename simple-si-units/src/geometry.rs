@@ -4,24 +4,30 @@
 use core::fmt;
 use super::UnitStruct;
 use super::NumLike;
+use super::SiParseError;
 use super::base::*;
 use super::chemical::*;
 use super::electromagnetic::*;
 use super::mechanical::*;
 
 // optional supports
-#[cfg(feature="serde")]
+#[cfg(all(feature="serde", not(feature="serde-unit-tag")))]
 use serde::{Serialize, Deserialize};
 #[cfg(feature="num-bigfloat")]
 use num_bigfloat;
 #[cfg(feature="num-complex")]
 use num_complex;
-
+#[cfg(feature="num-rational")]
+use num_rational;
+#[cfg(feature="nalgebra")]
+use nalgebra;
 
 
 /// The angle unit type, defined as radians in SI units
-#[derive(UnitStruct, Debug, Clone)]
-#[cfg_attr(feature="serde", derive(Serialize, Deserialize))]
+#[derive(UnitStruct, Debug, Clone, Default)]
+#[si(no_display)]
+#[cfg_attr(all(feature="serde", not(feature="serde-unit-tag")), derive(Serialize, Deserialize))]
+#[repr(transparent)]
 pub struct Angle<T: NumLike>{
 	/// The value of this Angle in radians
 	pub rad: T
@@ -34,6 +40,106 @@ impl<T> Angle<T> where T: NumLike {
 	
 	/// Returns the abbreviated name or symbol of angle: "rad" for radians
 	pub fn unit_symbol() -> &'static str { "rad" }
+
+	/// Returns the exponents of the SI base units of angle (rad)
+	pub const fn dimension() -> super::Dimension { super::Dimension{length: 0, mass: 0, time: 0, current: 0, temperature: 0, amount: 0, luminosity: 0} }
+
+	/// Returns a wrapper that formats this angle value with the full unit name instead of 
+	/// the unit symbol, eg "5 radians" (the unit name is not singularized for values of 1)
+	pub fn display_named(&self) -> super::NamedDisplay<'_, T> {
+		super::NamedDisplay::new(&self.rad, Self::unit_name())
+	}
+
+	/// Returns a new angle value from the given number of radians (same as 
+	/// `from_rad(...)`), for any number-like type. This is useful in generic code 
+	/// that works with several unit types
+	///
+	/// # Arguments
+	/// * `value` - Any number-like type, representing a quantity of radians
+	pub const fn new(value: T) -> Self { Angle{rad: value} }
+
+	/// Returns a new angle value from the given number of radians. Unlike
+	/// `from_rad(...)`, this is a `const fn`, so it can be used to declare `const` and `static` values
+	///
+	/// # Arguments
+	/// * `rad` - Any number-like type, representing a quantity of radians
+	pub const fn from_rad_const(rad: T) -> Self { Angle{rad} }
+
+	/// Returns a angle value with a different number type, by applying the 
+	/// given function to the value in radians (eg `.map(|x| x as f32)` to convert 
+	/// from `f64` to `f32`)
+	///
+	/// # Arguments
+	/// * `f` - Function that converts the number of radians to the new number type
+	pub fn map<U, F>(self, f: F) -> Angle<U> where U: NumLike, F: FnOnce(T) -> U {
+		Angle{rad: f(self.rad)}
+	}
+
+	/// Returns a angle value with a different number type, converted 
+	/// losslessly with the `From` trait (eg from `f32` to `f64`)
+	pub fn cast<U>(self) -> Angle<U> where U: NumLike+From<T> {
+		Angle{rad: U::from(self.rad)}
+	}
+
+	/// Returns a reference to the value of this angle in radians
+	pub fn as_raw(&self) -> &T {
+		&self.rad
+	}
+
+	/// Returns a mutable reference to the value of this angle in radians, 
+	/// eg for passing to numeric libraries that modify values in place. The number 
+	/// is always in radians, so changing it directly does not change the unit
+	pub fn raw_mut(&mut self) -> &mut T {
+		&mut self.rad
+	}
+
+	/// Returns a slice of numbers in radians as a slice of angle values, 
+	/// without copying
+	///
+	/// # Arguments
+	/// * `values` - Slice of numbers, representing quantities of radians
+	pub fn wrap_slice(values: &[T]) -> &[Self] {
+		// SAFETY: Angle<T> is #[repr(transparent)], so it has the same memory layout as T
+		unsafe { core::slice::from_raw_parts(values.as_ptr() as *const Self, values.len()) }
+	}
+
+	/// Returns a mutable slice of numbers in radians as a mutable slice of 
+	/// angle values, without copying
+	///
+	/// # Arguments
+	/// * `values` - Slice of numbers, representing quantities of radians
+	pub fn wrap_slice_mut(values: &mut [T]) -> &mut [Self] {
+		// SAFETY: Angle<T> is #[repr(transparent)], so it has the same memory layout as T
+		unsafe { core::slice::from_raw_parts_mut(values.as_mut_ptr() as *mut Self, values.len()) }
+	}
+
+	/// Returns the ratio of this angle value to another angle value 
+	/// (same as `self / other`). Dividing a unit value by another value of the same 
+	/// unit type cancels the units, so the ratio is a dimensionless number
+	///
+	/// # Arguments
+	/// * `other` - The angle value to divide by
+	pub fn ratio(self, other: Self) -> T {
+		self.rad / other.rad
+	}
+
+	/// Returns the ratio of this angle value to another angle value 
+	/// as a `Dimensionless` value, instead of a bare number like `ratio(...)` does
+	///
+	/// # Arguments
+	/// * `other` - The angle value to divide by
+	pub fn dimensionless_ratio(self, other: Self) -> super::base::Dimensionless<T> {
+		super::base::Dimensionless{value: self.rad / other.rad}
+	}
+
+	/// Returns this angle value multiplied by a dimensionless factor 
+	/// (same as `self * factor`)
+	///
+	/// # Arguments
+	/// * `factor` - The number to multiply by
+	pub fn scale(self, factor: T) -> Self {
+		Angle{rad: self.rad * factor}
+	}
 	
 	/// Returns a new angle value from the given number of radians
 	///
@@ -55,4345 +161,10615 @@ impl<T> Angle<T> where T: NumLike {
 
 }
 
+/// Formats angle values as a number followed by its unit symbol, eg "1500 rad"
+/// (complex numbers are wrapped in parentheses, eg "(1+2i) rad").
+/// The alternate flag (`{:#}`) scales the number by the nearest SI prefix where the unit symbol allows it
 impl<T> fmt::Display for Angle<T> where T: NumLike {
 	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-		write!(f, "{} {}", &self.rad, Self::unit_symbol())
+		if f.alternate() {
+			return super::fmt_with_si_prefix(f, &self.rad, Self::unit_symbol(), true);
+		}
+		super::fmt_unit_value(f, &self.rad, Self::unit_symbol())
 	}
 }
 
-impl<T> Angle<T> where T: NumLike+From<f64> {
-	
-	/// Returns a copy of this angle value in degrees
-	/// 
-	/// *Note: This method is not available for `f32` and other number types lacking the `From<f64>` trait*
-	pub fn to_degrees(&self) -> T {
-		return self.rad.clone() * T::from(57.2957795130823_f64);
+/// Formats angle values in exponential notation followed by the unit symbol, eg "1.5e3 rad"
+impl<T> fmt::LowerExp for Angle<T> where T: NumLike+fmt::LowerExp {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		fmt::LowerExp::fmt(&self.rad, f)?;
+		write!(f, " {}", Self::unit_symbol())
 	}
+}
 
-	/// Returns a new angle value from the given number of degrees
-	/// 
-	/// *Note: This method is not available for `f32` and other number types lacking the `From<f64>` trait*
-	///
-	/// # Arguments
-	/// * `degrees` - Any number-like type, representing a quantity of degrees
-	pub fn from_degrees(degrees: T) -> Self {
-		Angle{rad: degrees * T::from(0.0174532925199433_f64)}
+/// Formats angle values in exponential notation followed by the unit symbol, eg "1.5E3 rad"
+impl<T> fmt::UpperExp for Angle<T> where T: NumLike+fmt::UpperExp {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		fmt::UpperExp::fmt(&self.rad, f)?;
+		write!(f, " {}", Self::unit_symbol())
 	}
+}
 
-	/// Returns a copy of this angle value in degrees
-	/// 
-	/// *Note: This method is not available for `f32` and other number types lacking the `From<f64>` trait*
-	pub fn to_deg(&self) -> T {
-		return self.rad.clone() * T::from(57.2957795130823_f64);
+impl<T> super::SiUnit for Angle<T> where T: NumLike {
+	type Number = T;
+	fn unit_name(&self) -> &'static str { Self::unit_name() }
+	fn unit_symbol(&self) -> &'static str { Self::unit_symbol() }
+	fn raw(&self) -> &T { &self.rad }
+	fn dimension(&self) -> super::Dimension { Self::dimension() }
+	fn from_raw(value: T) -> Self { Angle{rad: value} }
+	fn into_raw(self) -> T { self.rad }
+}
+
+#[cfg(feature="alloc")]
+impl<T> Angle<T> where T: NumLike {
+	/// Returns this angle value in the canonical text format "<value> <symbol>", eg "5 rad".
+	/// Unlike `Display`, this format never changes with precision or alternate flags, and it is the
+	/// input format expected by `from_str(...)`, so `x.canonical_string().parse()` returns the same value
+	pub fn canonical_string(&self) -> alloc::string::String {
+		alloc::format!("{} {}", self.rad, Self::unit_symbol())
 	}
 
-	/// Returns a new angle value from the given number of degrees
-	/// 
-	/// *Note: This method is not available for `f32` and other number types lacking the `From<f64>` trait*
+	/// Returns a `Vec` of angle values copied from the given slice of numbers
 	///
 	/// # Arguments
-	/// * `deg` - Any number-like type, representing a quantity of degrees
-	pub fn from_deg(deg: T) -> Self {
-		Angle{rad: deg * T::from(0.0174532925199433_f64)}
+	/// * `values` - Slice of numbers, representing quantities of radians
+	pub fn from_rad_slice(values: &[T]) -> alloc::vec::Vec<Self> {
+		values.iter().map(|x| Angle{rad: x.clone()}).collect()
 	}
+}
 
+/// Parses a angle value from a number followed by its unit symbol, eg "5 rad"
+impl<T> core::str::FromStr for Angle<T> where T: NumLike+core::str::FromStr {
+	type Err = SiParseError;
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		Ok(Angle{rad: super::parse_unit_value(s, Self::unit_symbol())?})
+	}
 }
 
+/// Parses a angle value from a number followed by its unit symbol, eg "5 rad" 
+/// (same as `str::parse()`)
+impl<T> core::convert::TryFrom<&str> for Angle<T> where T: NumLike+core::str::FromStr {
+	type Error = SiParseError;
+	fn try_from(s: &str) -> Result<Self, Self::Error> {
+		<Self as core::str::FromStr>::from_str(s)
+	}
+}
 
-/// Multiplying a unit value by a scalar value returns a unit value
-#[cfg(feature="num-bigfloat")]
-impl core::ops::Mul<Angle<num_bigfloat::BigFloat>> for num_bigfloat::BigFloat {
-	type Output = Angle<num_bigfloat::BigFloat>;
-	fn mul(self, rhs: Angle<num_bigfloat::BigFloat>) -> Self::Output {
-		Angle{rad: self * rhs.rad}
+#[cfg(feature="num-traits")]
+impl<T> Angle<T> where T: NumLike+num_traits::Euclid {
+
+	/// Returns the Euclidean remainder of dividing this angle value by another, 
+	/// which is never negative (requires the `num-traits` feature)
+	///
+	/// # Arguments
+	/// * `rhs` - The angle value to divide by
+	pub fn rem_euclid(self, rhs: Self) -> Self {
+		Angle{rad: self.rad.rem_euclid(&rhs.rad)}
+	}
+
+	/// Returns the Euclidean quotient of dividing this angle value by another, 
+	/// ie the whole number of times that `rhs` fits into this value, rounded such that 
+	/// `rem_euclid(...)` is never negative (requires the `num-traits` feature)
+	///
+	/// # Arguments
+	/// * `rhs` - The angle value to divide by
+	pub fn div_euclid(self, rhs: Self) -> T {
+		self.rad.div_euclid(&rhs.rad)
 	}
 }
-/// Multiplying a unit value by a scalar value returns a unit value
-#[cfg(feature="num-bigfloat")]
-impl core::ops::Mul<Angle<num_bigfloat::BigFloat>> for &num_bigfloat::BigFloat {
-	type Output = Angle<num_bigfloat::BigFloat>;
-	fn mul(self, rhs: Angle<num_bigfloat::BigFloat>) -> Self::Output {
-		Angle{rad: self.clone() * rhs.rad}
+
+/// Summing angle values returns their total as a angle value (or zero if there are no values)
+impl<T> core::iter::Sum<Angle<T>> for Angle<T> where T: NumLike+core::iter::Sum {
+	fn sum<I: Iterator<Item=Angle<T>>>(iter: I) -> Self {
+		Angle{rad: iter.map(|x| x.rad).sum()}
 	}
 }
-/// Multiplying a unit value by a scalar value returns a unit value
-#[cfg(feature="num-bigfloat")]
-impl core::ops::Mul<&Angle<num_bigfloat::BigFloat>> for num_bigfloat::BigFloat {
-	type Output = Angle<num_bigfloat::BigFloat>;
-	fn mul(self, rhs: &Angle<num_bigfloat::BigFloat>) -> Self::Output {
-		Angle{rad: self * rhs.rad.clone()}
+/// Summing angle values returns their total as a angle value (or zero if there are no values)
+impl<'a, T> core::iter::Sum<&'a Angle<T>> for Angle<T> where T: NumLike+core::iter::Sum+'a {
+	fn sum<I: Iterator<Item=&'a Angle<T>>>(iter: I) -> Self {
+		Angle{rad: iter.map(|x| x.rad.clone()).sum()}
 	}
 }
-/// Multiplying a unit value by a scalar value returns a unit value
-#[cfg(feature="num-bigfloat")]
-impl core::ops::Mul<&Angle<num_bigfloat::BigFloat>> for &num_bigfloat::BigFloat {
-	type Output = Angle<num_bigfloat::BigFloat>;
-	fn mul(self, rhs: &Angle<num_bigfloat::BigFloat>) -> Self::Output {
-		Angle{rad: self.clone() * rhs.rad.clone()}
+
+impl<T> Angle<T> where T: NumLike+From<f64> {
+
+	/// Returns the weighted average of the given angle values, where each 
+	/// value is paired with its weight (weights do not need to add up to 1). If the 
+	/// total weight is zero (including when `values` is empty), then zero is returned
+	///
+	/// # Arguments
+	/// * `values` - Slice of (angle value, weight) pairs
+	pub fn weighted_mean(values: &[(Self, f64)]) -> Self {
+		let total_weight: f64 = values.iter().map(|(_, weight)| *weight).sum();
+		if total_weight == 0.0 {
+			return Angle{rad: T::from(0.0)};
+		}
+		let total = values.iter().fold(T::from(0.0),
+			|total, (value, weight)| total + value.rad.clone() * T::from(*weight));
+		Angle{rad: total / T::from(total_weight)}
 	}
 }
 
-/// Multiplying a unit value by a scalar value returns a unit value
-#[cfg(feature="num-complex")]
-impl core::ops::Mul<Angle<num_complex::Complex32>> for num_complex::Complex32 {
-	type Output = Angle<num_complex::Complex32>;
-	fn mul(self, rhs: Angle<num_complex::Complex32>) -> Self::Output {
-		Angle{rad: self * rhs.rad}
+impl<T> Angle<T> where T: NumLike+PartialEq+From<u8> {
+
+	/// Divides this angle value by another unit value (eg `distance.checked_div(time)` 
+	/// returns a velocity), returning `None` if the divisor is zero instead of producing 
+	/// infinity or NaN (or panicking, for integer number types). This works for every unit 
+	/// type that this angle value can be divided by with the `/` operator
+	///
+	/// # Arguments
+	/// * `rhs` - The unit value to divide by
+	pub fn checked_div<R>(self, rhs: R) -> Option<<Self as core::ops::Div<R>>::Output> 
+		where Self: core::ops::Div<R>, R: super::SiUnit<Number=T>
+	{
+		if *rhs.raw() == T::from(0u8) {
+			return None;
+		}
+		Some(self / rhs)
 	}
 }
-/// Multiplying a unit value by a scalar value returns a unit value
-#[cfg(feature="num-complex")]
-impl core::ops::Mul<Angle<num_complex::Complex32>> for &num_complex::Complex32 {
-	type Output = Angle<num_complex::Complex32>;
-	fn mul(self, rhs: Angle<num_complex::Complex32>) -> Self::Output {
-		Angle{rad: self.clone() * rhs.rad}
+
+/// Multiplying a angle value by a dimensionless value returns a angle value
+impl<T> core::ops::Mul<super::base::Dimensionless<T>> for Angle<T> where T: NumLike {
+	type Output = Angle<T>;
+	fn mul(self, rhs: super::base::Dimensionless<T>) -> Self::Output {
+		Angle{rad: self.rad * rhs.value}
 	}
 }
-/// Multiplying a unit value by a scalar value returns a unit value
-#[cfg(feature="num-complex")]
-impl core::ops::Mul<&Angle<num_complex::Complex32>> for num_complex::Complex32 {
-	type Output = Angle<num_complex::Complex32>;
-	fn mul(self, rhs: &Angle<num_complex::Complex32>) -> Self::Output {
-		Angle{rad: self * rhs.rad.clone()}
+/// Multiplying a dimensionless value by a angle value returns a angle value
+impl<T> core::ops::Mul<Angle<T>> for super::base::Dimensionless<T> where T: NumLike {
+	type Output = Angle<T>;
+	fn mul(self, rhs: Angle<T>) -> Self::Output {
+		Angle{rad: self.value * rhs.rad}
 	}
 }
-/// Multiplying a unit value by a scalar value returns a unit value
-#[cfg(feature="num-complex")]
-impl core::ops::Mul<&Angle<num_complex::Complex32>> for &num_complex::Complex32 {
-	type Output = Angle<num_complex::Complex32>;
-	fn mul(self, rhs: &Angle<num_complex::Complex32>) -> Self::Output {
-		Angle{rad: self.clone() * rhs.rad.clone()}
+/// Dividing a angle value by a dimensionless value returns a angle value
+impl<T> core::ops::Div<super::base::Dimensionless<T>> for Angle<T> where T: NumLike {
+	type Output = Angle<T>;
+	fn div(self, rhs: super::base::Dimensionless<T>) -> Self::Output {
+		Angle{rad: self.rad / rhs.value}
 	}
 }
 
-/// Multiplying a unit value by a scalar value returns a unit value
-#[cfg(feature="num-complex")]
-impl core::ops::Mul<Angle<num_complex::Complex64>> for num_complex::Complex64 {
-	type Output = Angle<num_complex::Complex64>;
-	fn mul(self, rhs: Angle<num_complex::Complex64>) -> Self::Output {
-		Angle{rad: self * rhs.rad}
+/// The remainder of dividing a angle value by another angle value is a angle value
+impl<T> core::ops::Rem<Angle<T>> for Angle<T> where T: NumLike+core::ops::Rem<Output=T> {
+	type Output = Angle<T>;
+	fn rem(self, rhs: Angle<T>) -> Self::Output {
+		Angle{rad: self.rad % rhs.rad}
 	}
 }
-/// Multiplying a unit value by a scalar value returns a unit value
-#[cfg(feature="num-complex")]
-impl core::ops::Mul<Angle<num_complex::Complex64>> for &num_complex::Complex64 {
-	type Output = Angle<num_complex::Complex64>;
-	fn mul(self, rhs: Angle<num_complex::Complex64>) -> Self::Output {
-		Angle{rad: self.clone() * rhs.rad}
+/// The remainder of dividing a angle value by another angle value is a angle value
+impl<T> core::ops::Rem<&Angle<T>> for Angle<T> where T: NumLike+core::ops::Rem<Output=T> {
+	type Output = Angle<T>;
+	fn rem(self, rhs: &Angle<T>) -> Self::Output {
+		Angle{rad: self.rad % rhs.rad.clone()}
 	}
 }
-/// Multiplying a unit value by a scalar value returns a unit value
-#[cfg(feature="num-complex")]
-impl core::ops::Mul<&Angle<num_complex::Complex64>> for num_complex::Complex64 {
-	type Output = Angle<num_complex::Complex64>;
-	fn mul(self, rhs: &Angle<num_complex::Complex64>) -> Self::Output {
-		Angle{rad: self * rhs.rad.clone()}
+/// The remainder of dividing a angle value by another angle value is a angle value
+impl<T> core::ops::Rem<Angle<T>> for &Angle<T> where T: NumLike+core::ops::Rem<Output=T> {
+	type Output = Angle<T>;
+	fn rem(self, rhs: Angle<T>) -> Self::Output {
+		Angle{rad: self.rad.clone() % rhs.rad}
 	}
 }
-/// Multiplying a unit value by a scalar value returns a unit value
-#[cfg(feature="num-complex")]
-impl core::ops::Mul<&Angle<num_complex::Complex64>> for &num_complex::Complex64 {
-	type Output = Angle<num_complex::Complex64>;
-	fn mul(self, rhs: &Angle<num_complex::Complex64>) -> Self::Output {
-		Angle{rad: self.clone() * rhs.rad.clone()}
+/// The remainder of dividing a angle value by another angle value is a angle value
+impl<T> core::ops::Rem<&Angle<T>> for &Angle<T> where T: NumLike+core::ops::Rem<Output=T> {
+	type Output = Angle<T>;
+	fn rem(self, rhs: &Angle<T>) -> Self::Output {
+		Angle{rad: self.rad.clone() % rhs.rad.clone()}
+	}
+}
+
+/// Serializes a angle value together with its unit symbol, eg 
+/// `{"value": 1.5, "unit": "rad"}` (requires the `serde-unit-tag` feature)
+#[cfg(feature="serde-unit-tag")]
+impl<T> serde::Serialize for Angle<T> where T: NumLike+serde::Serialize {
+	fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+		super::serialize_with_unit_tag(&self.rad, Self::unit_symbol(), serializer)
+	}
+}
+/// Deserializes a angle value from a value and unit symbol, eg 
+/// `{"value": 1.5, "unit": "rad"}`, returning an error if the unit symbol 
+/// does not match (requires the `serde-unit-tag` feature)
+#[cfg(feature="serde-unit-tag")]
+impl<'de, T> serde::Deserialize<'de> for Angle<T> where T: NumLike+serde::Deserialize<'de> {
+	fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+		Ok(Angle{rad: super::deserialize_with_unit_tag(Self::unit_symbol(), deserializer)?})
 	}
 }
 
+/// The additive identity of angle values is zero radians (requires the `num-traits` feature)
+#[cfg(feature="num-traits")]
+impl<T> num_traits::Zero for Angle<T> where T: NumLike+num_traits::Zero {
+	fn zero() -> Self { Angle{rad: T::zero()} }
+	fn is_zero(&self) -> bool { self.rad.is_zero() }
+}
 
+#[cfg(feature="num-traits")]
+impl<T> Angle<T> where T: NumLike+num_traits::Signed {
 
-/// Converts a Angle into the equivalent [uom](https://crates.io/crates/uom) type [Angle](https://docs.rs/uom/0.34.0/uom/si/f32/type.Angle.html)
-#[cfg(feature = "uom")]
-impl<T> Into<uom::si::f32::Angle> for Angle<T> where T: NumLike+Into<f32> {
-	fn into(self) -> uom::si::f32::Angle {
-		uom::si::f32::Angle::new::<uom::si::angle::radian>(self.rad.into())
+	/// Returns the sign of this angle value as a dimensionless number: 1 if it is 
+	/// positive, -1 if it is negative, and 0 if it is zero for integer number types 
+	/// (for floating-point number types, +0.0 returns 1.0, -0.0 returns -1.0, and NaN returns 
+	/// NaN) (requires the `num-traits` feature)
+	pub fn signum(self) -> T {
+		self.rad.signum()
 	}
 }
 
-/// Creates a Angle from the equivalent [uom](https://crates.io/crates/uom) type [Angle](https://docs.rs/uom/0.34.0/uom/si/f32/type.Angle.html)
-#[cfg(feature = "uom")]
-impl<T> From<uom::si::f32::Angle> for Angle<T> where T: NumLike+From<f32> {
-	fn from(src: uom::si::f32::Angle) -> Self {
-		Angle{rad: T::from(src.value)}
+impl<T> Angle<T> where T: NumLike+PartialOrd {
+
+	/// Returns the absolute value of this angle value, ie its magnitude without a minus sign
+	/// (for floating-point number types, -0.0 returns +0.0)
+	pub fn abs(self) -> Self {
+		let negated = -self.rad.clone();
+		if self.rad < negated {
+			Angle{rad: negated}
+		} else if self.rad == negated {
+			// zero, which is its own negation (adding +0.0 turns -0.0 into +0.0)
+			Angle{rad: negated + self.rad}
+		} else {
+			self
+		}
 	}
-}
 
-/// Converts a Angle into the equivalent [uom](https://crates.io/crates/uom) type [Angle](https://docs.rs/uom/0.34.0/uom/si/f64/type.Angle.html)
-#[cfg(feature = "uom")]
-impl<T> Into<uom::si::f64::Angle> for Angle<T> where T: NumLike+Into<f64> {
-	fn into(self) -> uom::si::f64::Angle {
-		uom::si::f64::Angle::new::<uom::si::angle::radian>(self.rad.into())
+	/// Returns the lesser of this angle value and the given angle value
+	///
+	/// # Arguments
+	/// * `other` - The angle value to compare with this one
+	pub fn min(self, other: Self) -> Self {
+		if other.rad < self.rad { other } else { self }
 	}
-}
 
-/// Creates a Angle from the equivalent [uom](https://crates.io/crates/uom) type [Angle](https://docs.rs/uom/0.34.0/uom/si/f64/type.Angle.html)
-#[cfg(feature = "uom")]
-impl<T> From<uom::si::f64::Angle> for Angle<T> where T: NumLike+From<f64> {
-	fn from(src: uom::si::f64::Angle) -> Self {
-		Angle{rad: T::from(src.value)}
+	/// Returns the greater of this angle value and the given angle value
+	///
+	/// # Arguments
+	/// * `other` - The angle value to compare with this one
+	pub fn max(self, other: Self) -> Self {
+		if other.rad > self.rad { other } else { self }
 	}
-}
 
+	/// Returns the least of the given angle values, or `None` if there are none. 
+	/// Unlike `Iterator::min()`, this does not require `Ord`, so it works for 
+	/// floating-point numbers. NaN values are skipped (so the result is `None` if all 
+	/// values are NaN), and the first of several equal values is returned
+	///
+	/// # Arguments
+	/// * `values` - Any iterator or collection of angle values
+	pub fn min_of<I>(values: I) -> Option<Self> where I: IntoIterator<Item=Self> {
+		values.into_iter().filter(|x| x.rad.partial_cmp(&x.rad).is_some()).reduce(Self::min)
+	}
 
-// Angle / Time -> AngularVelocity
-/// Dividing a Angle by a Time returns a value of type AngularVelocity
-impl<T> core::ops::Div<Time<T>> for Angle<T> where T: NumLike {
-	type Output = AngularVelocity<T>;
-	fn div(self, rhs: Time<T>) -> Self::Output {
-		AngularVelocity{radps: self.rad / rhs.s}
+	/// Returns the greatest of the given angle values, or `None` if there are none. 
+	/// Unlike `Iterator::max()`, this does not require `Ord`, so it works for 
+	/// floating-point numbers. NaN values are skipped (so the result is `None` if all 
+	/// values are NaN), and the first of several equal values is returned
+	///
+	/// # Arguments
+	/// * `values` - Any iterator or collection of angle values
+	pub fn max_of<I>(values: I) -> Option<Self> where I: IntoIterator<Item=Self> {
+		values.into_iter().filter(|x| x.rad.partial_cmp(&x.rad).is_some()).reduce(Self::max)
 	}
-}
-/// Dividing a Angle by a Time returns a value of type AngularVelocity
-impl<T> core::ops::Div<Time<T>> for &Angle<T> where T: NumLike {
-	type Output = AngularVelocity<T>;
-	fn div(self, rhs: Time<T>) -> Self::Output {
-		AngularVelocity{radps: self.rad.clone() / rhs.s}
+
+	/// Returns this angle value restricted to the range from `lo` to `hi`
+	///
+	/// # Arguments
+	/// * `lo` - The minimum angle value
+	/// * `hi` - The maximum angle value
+	///
+	/// # Panics
+	/// Panics if `lo` is greater than `hi` (or if either of them is NaN)
+	pub fn clamp(self, lo: Self, hi: Self) -> Self {
+		assert!(lo.rad <= hi.rad, "lo must be less than or equal to hi");
+		if self.rad < lo.rad { lo } else if self.rad > hi.rad { hi } else { self }
 	}
-}
-/// Dividing a Angle by a Time returns a value of type AngularVelocity
-impl<T> core::ops::Div<&Time<T>> for Angle<T> where T: NumLike {
-	type Output = AngularVelocity<T>;
-	fn div(self, rhs: &Time<T>) -> Self::Output {
-		AngularVelocity{radps: self.rad / rhs.s.clone()}
+
+	/// Returns this angle value with its magnitude capped at `max`, keeping its 
+	/// sign (eg for capping a speed), or this value unchanged if its magnitude does not 
+	/// exceed `max`. For complex-valued angle, use `clamp_norm(...)` instead, 
+	/// which caps the magnitude while keeping the phase
+	///
+	/// # Arguments
+	/// * `max` - The maximum magnitude (should not be negative)
+	pub fn clamp_magnitude(self, max: Self) -> Self {
+		let negated_max = -max.rad.clone();
+		if self.rad > max.rad { 
+			max 
+		} else if self.rad < negated_max { 
+			Angle{rad: negated_max} 
+		} else { 
+			self 
+		}
+	}
+
+	/// Returns true if this angle value differs from the given angle 
+	/// value by no more than the given absolute tolerance (which is also a 
+	/// angle value, so the comparison is dimensionally safe). NaN is never 
+	/// equal to anything. See also the `assert_unit_eq!(...)` macro.
+	///
+	/// # Arguments
+	/// * `other` - The angle value to compare with this one
+	/// * `tolerance` - The largest allowed difference between the two values
+	pub fn eq_within(self, other: Self, tolerance: Self) -> bool {
+		let diff = self.rad - other.rad;
+		diff <= tolerance.rad && -diff <= tolerance.rad
 	}
 }
-/// Dividing a Angle by a Time returns a value of type AngularVelocity
-impl<T> core::ops::Div<&Time<T>> for &Angle<T> where T: NumLike {
-	type Output = AngularVelocity<T>;
-	fn div(self, rhs: &Time<T>) -> Self::Output {
-		AngularVelocity{radps: self.rad.clone() / rhs.s.clone()}
+
+#[cfg(feature="num-complex")]
+impl Angle<num_complex::Complex32> {
+	/// Returns the magnitude (aka norm or absolute value) of this complex angle 
+	/// value as a real-valued angle (requires the `num-complex` feature)
+	pub fn magnitude(self) -> Angle<f32> {
+		Angle{rad: self.rad.norm()}
 	}
-}
 
-// Angle * Angle -> SolidAngle
-/// Multiplying a Angle by a Angle returns a value of type SolidAngle
-impl<T> core::ops::Mul<Angle<T>> for Angle<T> where T: NumLike {
-	type Output = SolidAngle<T>;
-	fn mul(self, rhs: Angle<T>) -> Self::Output {
-		SolidAngle{sr: self.rad * rhs.rad}
+	/// Returns this complex angle value scaled down so that its magnitude is 
+	/// at most `max` (keeping its phase), or this value unchanged if its magnitude does 
+	/// not exceed `max`. This is the complex equivalent of `clamp_magnitude(...)` (requires 
+	/// the `num-complex` feature)
+	///
+	/// # Arguments
+	/// * `max` - The maximum magnitude (should not be negative)
+	pub fn clamp_norm(self, max: Angle<f32>) -> Self {
+		let norm = self.rad.norm();
+		if norm > max.rad {
+			Angle{rad: self.rad * (max.rad / norm)}
+		} else {
+			self
+		}
 	}
 }
-/// Multiplying a Angle by a Angle returns a value of type SolidAngle
-impl<T> core::ops::Mul<Angle<T>> for &Angle<T> where T: NumLike {
-	type Output = SolidAngle<T>;
-	fn mul(self, rhs: Angle<T>) -> Self::Output {
-		SolidAngle{sr: self.rad.clone() * rhs.rad}
-	}
-}
-/// Multiplying a Angle by a Angle returns a value of type SolidAngle
-impl<T> core::ops::Mul<&Angle<T>> for Angle<T> where T: NumLike {
-	type Output = SolidAngle<T>;
-	fn mul(self, rhs: &Angle<T>) -> Self::Output {
-		SolidAngle{sr: self.rad * rhs.rad.clone()}
+
+#[cfg(feature="num-complex")]
+impl Angle<num_complex::Complex64> {
+	/// Returns the magnitude (aka norm or absolute value) of this complex angle 
+	/// value as a real-valued angle (requires the `num-complex` feature)
+	pub fn magnitude(self) -> Angle<f64> {
+		Angle{rad: self.rad.norm()}
 	}
-}
-/// Multiplying a Angle by a Angle returns a value of type SolidAngle
-impl<T> core::ops::Mul<&Angle<T>> for &Angle<T> where T: NumLike {
-	type Output = SolidAngle<T>;
-	fn mul(self, rhs: &Angle<T>) -> Self::Output {
-		SolidAngle{sr: self.rad.clone() * rhs.rad.clone()}
+
+	/// Returns this complex angle value scaled down so that its magnitude is 
+	/// at most `max` (keeping its phase), or this value unchanged if its magnitude does 
+	/// not exceed `max`. This is the complex equivalent of `clamp_magnitude(...)` (requires 
+	/// the `num-complex` feature)
+	///
+	/// # Arguments
+	/// * `max` - The maximum magnitude (should not be negative)
+	pub fn clamp_norm(self, max: Angle<f64>) -> Self {
+		let norm = self.rad.norm();
+		if norm > max.rad {
+			Angle{rad: self.rad * (max.rad / norm)}
+		} else {
+			self
+		}
 	}
 }
 
-// Angle / InverseAngle -> SolidAngle
-/// Dividing a Angle by a InverseAngle returns a value of type SolidAngle
-impl<T> core::ops::Div<InverseAngle<T>> for Angle<T> where T: NumLike {
-	type Output = SolidAngle<T>;
-	fn div(self, rhs: InverseAngle<T>) -> Self::Output {
-		SolidAngle{sr: self.rad / rhs.per_rad}
+/// Rounding and other floating-point functions (requires the `num-traits` feature, 
+/// and either the `std` or `libm` feature)
+#[cfg(all(feature="num-traits", any(feature="std", feature="libm")))]
+impl<T> Angle<T> where T: NumLike+num_traits::Float {
+
+	/// Returns this angle value rounded to the nearest whole number of radians 
+	/// (half-way cases are rounded away from zero)
+	pub fn round(self) -> Self {
+		Angle{rad: self.rad.round()}
 	}
-}
-/// Dividing a Angle by a InverseAngle returns a value of type SolidAngle
-impl<T> core::ops::Div<InverseAngle<T>> for &Angle<T> where T: NumLike {
-	type Output = SolidAngle<T>;
-	fn div(self, rhs: InverseAngle<T>) -> Self::Output {
-		SolidAngle{sr: self.rad.clone() / rhs.per_rad}
+
+	/// Returns this angle value rounded down to a whole number of radians
+	pub fn floor(self) -> Self {
+		Angle{rad: self.rad.floor()}
 	}
-}
-/// Dividing a Angle by a InverseAngle returns a value of type SolidAngle
-impl<T> core::ops::Div<&InverseAngle<T>> for Angle<T> where T: NumLike {
-	type Output = SolidAngle<T>;
-	fn div(self, rhs: &InverseAngle<T>) -> Self::Output {
-		SolidAngle{sr: self.rad / rhs.per_rad.clone()}
+
+	/// Returns this angle value rounded up to a whole number of radians
+	pub fn ceil(self) -> Self {
+		Angle{rad: self.rad.ceil()}
 	}
-}
-/// Dividing a Angle by a InverseAngle returns a value of type SolidAngle
-impl<T> core::ops::Div<&InverseAngle<T>> for &Angle<T> where T: NumLike {
-	type Output = SolidAngle<T>;
-	fn div(self, rhs: &InverseAngle<T>) -> Self::Output {
-		SolidAngle{sr: self.rad.clone() / rhs.per_rad.clone()}
+
+	/// Returns this angle value rounded towards zero to a whole number of radians
+	pub fn trunc(self) -> Self {
+		Angle{rad: self.rad.trunc()}
 	}
-}
 
-// Angle * InverseSolidAngle -> InverseAngle
-/// Multiplying a Angle by a InverseSolidAngle returns a value of type InverseAngle
-impl<T> core::ops::Mul<InverseSolidAngle<T>> for Angle<T> where T: NumLike {
-	type Output = InverseAngle<T>;
-	fn mul(self, rhs: InverseSolidAngle<T>) -> Self::Output {
-		InverseAngle{per_rad: self.rad * rhs.per_sr}
+	/// Returns this angle value rounded to the nearest multiple of the given step 
+	/// (half-way cases are rounded away from zero)
+	///
+	/// # Arguments
+	/// * `step` - The angle value to round to a multiple of
+	pub fn round_to(self, step: Self) -> Self {
+		Angle{rad: (self.rad / step.rad).round() * step.rad}
 	}
-}
-/// Multiplying a Angle by a InverseSolidAngle returns a value of type InverseAngle
-impl<T> core::ops::Mul<InverseSolidAngle<T>> for &Angle<T> where T: NumLike {
-	type Output = InverseAngle<T>;
-	fn mul(self, rhs: InverseSolidAngle<T>) -> Self::Output {
-		InverseAngle{per_rad: self.rad.clone() * rhs.per_sr}
+
+	/// Fused multiply-add: returns `self * a + b` computed with only one rounding 
+	/// error, which is more accurate (and on some hardware faster) than a separate 
+	/// multiplication and addition
+	///
+	/// # Arguments
+	/// * `a` - The scalar value to multiply this angle value by
+	/// * `b` - The angle value to add to the product
+	pub fn mul_add(self, a: T, b: Self) -> Self {
+		Angle{rad: self.rad.mul_add(a, b.rad)}
 	}
-}
-/// Multiplying a Angle by a InverseSolidAngle returns a value of type InverseAngle
-impl<T> core::ops::Mul<&InverseSolidAngle<T>> for Angle<T> where T: NumLike {
-	type Output = InverseAngle<T>;
-	fn mul(self, rhs: &InverseSolidAngle<T>) -> Self::Output {
-		InverseAngle{per_rad: self.rad * rhs.per_sr.clone()}
+
+	/// Returns true if this angle value is neither infinite nor NaN
+	pub fn is_finite(&self) -> bool {
+		self.rad.is_finite()
 	}
-}
-/// Multiplying a Angle by a InverseSolidAngle returns a value of type InverseAngle
-impl<T> core::ops::Mul<&InverseSolidAngle<T>> for &Angle<T> where T: NumLike {
-	type Output = InverseAngle<T>;
-	fn mul(self, rhs: &InverseSolidAngle<T>) -> Self::Output {
-		InverseAngle{per_rad: self.rad.clone() * rhs.per_sr.clone()}
+
+	/// Returns true if this angle value is positive or negative infinity
+	pub fn is_infinite(&self) -> bool {
+		self.rad.is_infinite()
 	}
-}
 
-// Angle / SolidAngle -> InverseAngle
-/// Dividing a Angle by a SolidAngle returns a value of type InverseAngle
-impl<T> core::ops::Div<SolidAngle<T>> for Angle<T> where T: NumLike {
-	type Output = InverseAngle<T>;
-	fn div(self, rhs: SolidAngle<T>) -> Self::Output {
-		InverseAngle{per_rad: self.rad / rhs.sr}
+	/// Returns true if this angle value is NaN (not a number)
+	pub fn is_nan(&self) -> bool {
+		self.rad.is_nan()
 	}
-}
-/// Dividing a Angle by a SolidAngle returns a value of type InverseAngle
-impl<T> core::ops::Div<SolidAngle<T>> for &Angle<T> where T: NumLike {
-	type Output = InverseAngle<T>;
-	fn div(self, rhs: SolidAngle<T>) -> Self::Output {
-		InverseAngle{per_rad: self.rad.clone() / rhs.sr}
+
+	/// Returns true if this angle value has a positive sign, including +0.0 
+	/// and positive infinity
+	pub fn is_sign_positive(&self) -> bool {
+		self.rad.is_sign_positive()
 	}
-}
-/// Dividing a Angle by a SolidAngle returns a value of type InverseAngle
-impl<T> core::ops::Div<&SolidAngle<T>> for Angle<T> where T: NumLike {
-	type Output = InverseAngle<T>;
-	fn div(self, rhs: &SolidAngle<T>) -> Self::Output {
-		InverseAngle{per_rad: self.rad / rhs.sr.clone()}
+
+	/// Returns true if this angle value has a negative sign, including -0.0 
+	/// and negative infinity
+	pub fn is_sign_negative(&self) -> bool {
+		self.rad.is_sign_negative()
 	}
-}
-/// Dividing a Angle by a SolidAngle returns a value of type InverseAngle
-impl<T> core::ops::Div<&SolidAngle<T>> for &Angle<T> where T: NumLike {
-	type Output = InverseAngle<T>;
-	fn div(self, rhs: &SolidAngle<T>) -> Self::Output {
-		InverseAngle{per_rad: self.rad.clone() / rhs.sr.clone()}
+
+	/// Returns a angle value with the magnitude of this one and the sign of 
+	/// `sign_source`, eg for reflecting a value off of a surface
+	///
+	/// # Arguments
+	/// * `sign_source` - The angle value whose sign is copied
+	pub fn copysign(self, sign_source: Self) -> Self {
+		Angle{rad: self.rad.copysign(sign_source.rad)}
 	}
 }
 
-// Angle / AngularVelocity -> Time
-/// Dividing a Angle by a AngularVelocity returns a value of type Time
-impl<T> core::ops::Div<AngularVelocity<T>> for Angle<T> where T: NumLike {
-	type Output = Time<T>;
-	fn div(self, rhs: AngularVelocity<T>) -> Self::Output {
-		Time{s: self.rad / rhs.radps}
+#[cfg(feature="num-traits")]
+impl<T> Angle<T> where T: NumLike+num_traits::CheckedAdd+num_traits::CheckedSub {
+
+	/// Adds another angle value to this one, returning `None` if the 
+	/// addition overflows the number type (requires the `num-traits` feature)
+	pub fn checked_add(self, rhs: Self) -> Option<Self> {
+		Some(Angle{rad: self.rad.checked_add(&rhs.rad)?})
 	}
-}
-/// Dividing a Angle by a AngularVelocity returns a value of type Time
-impl<T> core::ops::Div<AngularVelocity<T>> for &Angle<T> where T: NumLike {
-	type Output = Time<T>;
-	fn div(self, rhs: AngularVelocity<T>) -> Self::Output {
-		Time{s: self.rad.clone() / rhs.radps}
+
+	/// Subtracts another angle value from this one, returning `None` if 
+	/// the subtraction overflows the number type (requires the `num-traits` feature)
+	pub fn checked_sub(self, rhs: Self) -> Option<Self> {
+		Some(Angle{rad: self.rad.checked_sub(&rhs.rad)?})
 	}
 }
-/// Dividing a Angle by a AngularVelocity returns a value of type Time
-impl<T> core::ops::Div<&AngularVelocity<T>> for Angle<T> where T: NumLike {
-	type Output = Time<T>;
-	fn div(self, rhs: &AngularVelocity<T>) -> Self::Output {
-		Time{s: self.rad / rhs.radps.clone()}
+
+#[cfg(feature="num-traits")]
+impl<T> Angle<T> where T: NumLike+num_traits::SaturatingAdd+num_traits::SaturatingSub {
+
+	/// Adds another angle value to this one, clamping the result to the 
+	/// minimum or maximum value of the number type instead of overflowing 
+	/// (requires the `num-traits` feature)
+	pub fn saturating_add(self, rhs: Self) -> Self {
+		Angle{rad: self.rad.saturating_add(&rhs.rad)}
 	}
-}
-/// Dividing a Angle by a AngularVelocity returns a value of type Time
-impl<T> core::ops::Div<&AngularVelocity<T>> for &Angle<T> where T: NumLike {
-	type Output = Time<T>;
-	fn div(self, rhs: &AngularVelocity<T>) -> Self::Output {
-		Time{s: self.rad.clone() / rhs.radps.clone()}
+
+	/// Subtracts another angle value from this one, clamping the result 
+	/// to the minimum or maximum value of the number type instead of overflowing 
+	/// (requires the `num-traits` feature)
+	pub fn saturating_sub(self, rhs: Self) -> Self {
+		Angle{rad: self.rad.saturating_sub(&rhs.rad)}
 	}
 }
 
-// Angle * Frequency -> AngularVelocity
-/// Multiplying a Angle by a Frequency returns a value of type AngularVelocity
-impl<T> core::ops::Mul<Frequency<T>> for Angle<T> where T: NumLike {
-	type Output = AngularVelocity<T>;
-	fn mul(self, rhs: Frequency<T>) -> Self::Output {
-		AngularVelocity{radps: self.rad * rhs.Hz}
+impl<T> Angle<T> where T: NumLike+Into<f64> {
+
+	/// Returns true if this angle value is equal to the given angle 
+	/// value to within the given number of significant figures (eg 6 significant 
+	/// figures allows a relative difference of up to 1 part per million). NaN is 
+	/// only approximately equal to NaN, infinity is only approximately equal to 
+	/// infinity of the same sign, and zero is only approximately equal to zero.
+	///
+	/// # Arguments
+	/// * `other` - The angle value to compare with this one
+	/// * `sig_figs` - The number of significant figures that must match
+	pub fn approx_eq(self, other: Self, sig_figs: i32) -> bool {
+		super::approx_eq_f64(self.rad.into(), other.rad.into(), sig_figs)
 	}
 }
-/// Multiplying a Angle by a Frequency returns a value of type AngularVelocity
-impl<T> core::ops::Mul<Frequency<T>> for &Angle<T> where T: NumLike {
-	type Output = AngularVelocity<T>;
-	fn mul(self, rhs: Frequency<T>) -> Self::Output {
-		AngularVelocity{radps: self.rad.clone() * rhs.Hz}
+
+impl<T> Angle<T> where T: NumLike+From<f64> {
+
+	/// Returns the linear interpolation between this angle value and the given 
+	/// angle value, ie `self*(1-t) + other*t`. The interpolation factor is not 
+	/// clamped, so values of `t` outside the range 0 to 1 extrapolate beyond the two 
+	/// values (see `lerp_clamped(...)`).
+	///
+	/// # Arguments
+	/// * `other` - The angle value to interpolate towards (returned when `t` is 1)
+	/// * `t` - The interpolation factor, where 0 returns this value and 1 returns `other`
+	pub fn lerp(self, other: Self, t: f64) -> Self {
+		Angle{rad: self.rad * T::from(1.0 - t) + other.rad * T::from(t)}
 	}
-}
-/// Multiplying a Angle by a Frequency returns a value of type AngularVelocity
-impl<T> core::ops::Mul<&Frequency<T>> for Angle<T> where T: NumLike {
-	type Output = AngularVelocity<T>;
-	fn mul(self, rhs: &Frequency<T>) -> Self::Output {
-		AngularVelocity{radps: self.rad * rhs.Hz.clone()}
+
+	/// Same as `lerp(...)`, except that the interpolation factor `t` is first clamped 
+	/// to the range 0 to 1, so that the result is always between the two values
+	///
+	/// # Arguments
+	/// * `other` - The angle value to interpolate towards (returned when `t` is 1 or more)
+	/// * `t` - The interpolation factor, where 0 returns this value and 1 returns `other`
+	pub fn lerp_clamped(self, other: Self, t: f64) -> Self {
+		self.lerp(other, t.clamp(0.0, 1.0))
 	}
-}
-/// Multiplying a Angle by a Frequency returns a value of type AngularVelocity
-impl<T> core::ops::Mul<&Frequency<T>> for &Angle<T> where T: NumLike {
-	type Output = AngularVelocity<T>;
-	fn mul(self, rhs: &Frequency<T>) -> Self::Output {
-		AngularVelocity{radps: self.rad.clone() * rhs.Hz.clone()}
+	
+	/// Returns a copy of this angle value in degrees
+	/// 
+	/// *Note: This method is not available for `f32` and other number types lacking the `From<f64>` trait*
+	pub fn to_degrees(&self) -> T {
+		return self.rad.clone() * T::from(57.29577951308232_f64);
 	}
-}
 
-// Angle * InverseAngularVelocity -> Time
-/// Multiplying a Angle by a InverseAngularVelocity returns a value of type Time
-impl<T> core::ops::Mul<InverseAngularVelocity<T>> for Angle<T> where T: NumLike {
-	type Output = Time<T>;
-	fn mul(self, rhs: InverseAngularVelocity<T>) -> Self::Output {
-		Time{s: self.rad * rhs.s_per_rad}
+	/// Returns a new angle value from the given number of degrees
+	/// 
+	/// *Note: This method is not available for `f32` and other number types lacking the `From<f64>` trait*
+	///
+	/// # Arguments
+	/// * `degrees` - Any number-like type, representing a quantity of degrees
+	pub fn from_degrees(degrees: T) -> Self {
+		Angle{rad: degrees * T::from(0.017453292519943295_f64)}
 	}
-}
-/// Multiplying a Angle by a InverseAngularVelocity returns a value of type Time
-impl<T> core::ops::Mul<InverseAngularVelocity<T>> for &Angle<T> where T: NumLike {
-	type Output = Time<T>;
-	fn mul(self, rhs: InverseAngularVelocity<T>) -> Self::Output {
-		Time{s: self.rad.clone() * rhs.s_per_rad}
+
+	/// Returns a copy of this angle value in degrees
+	/// 
+	/// *Note: This method is not available for `f32` and other number types lacking the `From<f64>` trait*
+	pub fn to_deg(&self) -> T {
+		return self.rad.clone() * T::from(57.29577951308232_f64);
 	}
-}
-/// Multiplying a Angle by a InverseAngularVelocity returns a value of type Time
-impl<T> core::ops::Mul<&InverseAngularVelocity<T>> for Angle<T> where T: NumLike {
-	type Output = Time<T>;
-	fn mul(self, rhs: &InverseAngularVelocity<T>) -> Self::Output {
-		Time{s: self.rad * rhs.s_per_rad.clone()}
+
+	/// Returns a new angle value from the given number of degrees
+	/// 
+	/// *Note: This method is not available for `f32` and other number types lacking the `From<f64>` trait*
+	///
+	/// # Arguments
+	/// * `deg` - Any number-like type, representing a quantity of degrees
+	pub fn from_deg(deg: T) -> Self {
+		Angle{rad: deg * T::from(0.017453292519943295_f64)}
 	}
-}
-/// Multiplying a Angle by a InverseAngularVelocity returns a value of type Time
-impl<T> core::ops::Mul<&InverseAngularVelocity<T>> for &Angle<T> where T: NumLike {
-	type Output = Time<T>;
-	fn mul(self, rhs: &InverseAngularVelocity<T>) -> Self::Output {
-		Time{s: self.rad.clone() * rhs.s_per_rad.clone()}
+
+	/// Returns a copy of this angle value in arcminutes
+	/// 
+	/// *Note: This method is not available for `f32` and other number types lacking the `From<f64>` trait*
+	pub fn to_arcminutes(&self) -> T {
+		return self.rad.clone() * T::from(3437.7467707849396_f64);
 	}
-}
 
-// 1/Angle -> InverseAngle
-/// Dividing a scalar value by a Angle unit value returns a value of type InverseAngle
-impl<T> core::ops::Div<Angle<T>> for f64 where T: NumLike+From<f64> {
-	type Output = InverseAngle<T>;
-	fn div(self, rhs: Angle<T>) -> Self::Output {
-		InverseAngle{per_rad: T::from(self) / rhs.rad}
+	/// Returns a new angle value from the given number of arcminutes
+	/// 
+	/// *Note: This method is not available for `f32` and other number types lacking the `From<f64>` trait*
+	///
+	/// # Arguments
+	/// * `arcminutes` - Any number-like type, representing a quantity of arcminutes
+	pub fn from_arcminutes(arcminutes: T) -> Self {
+		Angle{rad: arcminutes * T::from(0.0002908882086657216_f64)}
 	}
-}
-/// Dividing a scalar value by a Angle unit value returns a value of type InverseAngle
-impl<T> core::ops::Div<Angle<T>> for &f64 where T: NumLike+From<f64> {
-	type Output = InverseAngle<T>;
-	fn div(self, rhs: Angle<T>) -> Self::Output {
-		InverseAngle{per_rad: T::from(self.clone()) / rhs.rad}
+
+	/// Returns a copy of this angle value in arcminutes
+	/// 
+	/// *Note: This method is not available for `f32` and other number types lacking the `From<f64>` trait*
+	pub fn to_arcmin(&self) -> T {
+		return self.rad.clone() * T::from(3437.7467707849396_f64);
 	}
-}
-/// Dividing a scalar value by a Angle unit value returns a value of type InverseAngle
-impl<T> core::ops::Div<&Angle<T>> for f64 where T: NumLike+From<f64> {
-	type Output = InverseAngle<T>;
-	fn div(self, rhs: &Angle<T>) -> Self::Output {
-		InverseAngle{per_rad: T::from(self) / rhs.rad.clone()}
+
+	/// Returns a new angle value from the given number of arcminutes
+	/// 
+	/// *Note: This method is not available for `f32` and other number types lacking the `From<f64>` trait*
+	///
+	/// # Arguments
+	/// * `arcmin` - Any number-like type, representing a quantity of arcminutes
+	pub fn from_arcmin(arcmin: T) -> Self {
+		Angle{rad: arcmin * T::from(0.0002908882086657216_f64)}
 	}
-}
-/// Dividing a scalar value by a Angle unit value returns a value of type InverseAngle
-impl<T> core::ops::Div<&Angle<T>> for &f64 where T: NumLike+From<f64> {
-	type Output = InverseAngle<T>;
-	fn div(self, rhs: &Angle<T>) -> Self::Output {
-		InverseAngle{per_rad: T::from(self.clone()) / rhs.rad.clone()}
-	}
-}
 
-// 1/Angle -> InverseAngle
-/// Dividing a scalar value by a Angle unit value returns a value of type InverseAngle
-impl<T> core::ops::Div<Angle<T>> for f32 where T: NumLike+From<f32> {
-	type Output = InverseAngle<T>;
-	fn div(self, rhs: Angle<T>) -> Self::Output {
-		InverseAngle{per_rad: T::from(self) / rhs.rad}
-	}
-}
-/// Dividing a scalar value by a Angle unit value returns a value of type InverseAngle
-impl<T> core::ops::Div<Angle<T>> for &f32 where T: NumLike+From<f32> {
-	type Output = InverseAngle<T>;
-	fn div(self, rhs: Angle<T>) -> Self::Output {
-		InverseAngle{per_rad: T::from(self.clone()) / rhs.rad}
-	}
-}
-/// Dividing a scalar value by a Angle unit value returns a value of type InverseAngle
-impl<T> core::ops::Div<&Angle<T>> for f32 where T: NumLike+From<f32> {
-	type Output = InverseAngle<T>;
-	fn div(self, rhs: &Angle<T>) -> Self::Output {
-		InverseAngle{per_rad: T::from(self) / rhs.rad.clone()}
-	}
-}
-/// Dividing a scalar value by a Angle unit value returns a value of type InverseAngle
-impl<T> core::ops::Div<&Angle<T>> for &f32 where T: NumLike+From<f32> {
-	type Output = InverseAngle<T>;
-	fn div(self, rhs: &Angle<T>) -> Self::Output {
-		InverseAngle{per_rad: T::from(self.clone()) / rhs.rad.clone()}
+	/// Returns a copy of this angle value in arcseconds
+	/// 
+	/// *Note: This method is not available for `f32` and other number types lacking the `From<f64>` trait*
+	pub fn to_arcseconds(&self) -> T {
+		return self.rad.clone() * T::from(206264.80624709636_f64);
 	}
-}
 
-// 1/Angle -> InverseAngle
-/// Dividing a scalar value by a Angle unit value returns a value of type InverseAngle
-impl<T> core::ops::Div<Angle<T>> for i64 where T: NumLike+From<i64> {
-	type Output = InverseAngle<T>;
-	fn div(self, rhs: Angle<T>) -> Self::Output {
-		InverseAngle{per_rad: T::from(self) / rhs.rad}
-	}
-}
-/// Dividing a scalar value by a Angle unit value returns a value of type InverseAngle
-impl<T> core::ops::Div<Angle<T>> for &i64 where T: NumLike+From<i64> {
-	type Output = InverseAngle<T>;
-	fn div(self, rhs: Angle<T>) -> Self::Output {
-		InverseAngle{per_rad: T::from(self.clone()) / rhs.rad}
+	/// Returns a new angle value from the given number of arcseconds
+	/// 
+	/// *Note: This method is not available for `f32` and other number types lacking the `From<f64>` trait*
+	///
+	/// # Arguments
+	/// * `arcseconds` - Any number-like type, representing a quantity of arcseconds
+	pub fn from_arcseconds(arcseconds: T) -> Self {
+		Angle{rad: arcseconds * T::from(4.84813681109536e-06_f64)}
 	}
-}
-/// Dividing a scalar value by a Angle unit value returns a value of type InverseAngle
-impl<T> core::ops::Div<&Angle<T>> for i64 where T: NumLike+From<i64> {
-	type Output = InverseAngle<T>;
-	fn div(self, rhs: &Angle<T>) -> Self::Output {
-		InverseAngle{per_rad: T::from(self) / rhs.rad.clone()}
+
+	/// Returns a copy of this angle value in arcseconds
+	/// 
+	/// *Note: This method is not available for `f32` and other number types lacking the `From<f64>` trait*
+	pub fn to_arcsec(&self) -> T {
+		return self.rad.clone() * T::from(206264.80624709636_f64);
 	}
-}
-/// Dividing a scalar value by a Angle unit value returns a value of type InverseAngle
-impl<T> core::ops::Div<&Angle<T>> for &i64 where T: NumLike+From<i64> {
-	type Output = InverseAngle<T>;
-	fn div(self, rhs: &Angle<T>) -> Self::Output {
-		InverseAngle{per_rad: T::from(self.clone()) / rhs.rad.clone()}
+
+	/// Returns a new angle value from the given number of arcseconds
+	/// 
+	/// *Note: This method is not available for `f32` and other number types lacking the `From<f64>` trait*
+	///
+	/// # Arguments
+	/// * `arcsec` - Any number-like type, representing a quantity of arcseconds
+	pub fn from_arcsec(arcsec: T) -> Self {
+		Angle{rad: arcsec * T::from(4.84813681109536e-06_f64)}
 	}
-}
 
-// 1/Angle -> InverseAngle
-/// Dividing a scalar value by a Angle unit value returns a value of type InverseAngle
-impl<T> core::ops::Div<Angle<T>> for i32 where T: NumLike+From<i32> {
-	type Output = InverseAngle<T>;
-	fn div(self, rhs: Angle<T>) -> Self::Output {
-		InverseAngle{per_rad: T::from(self) / rhs.rad}
+	/// Returns a copy of this angle value in gradians
+	/// 
+	/// *Note: This method is not available for `f32` and other number types lacking the `From<f64>` trait*
+	pub fn to_gradians(&self) -> T {
+		return self.rad.clone() * T::from(63.66197723675813_f64);
 	}
-}
-/// Dividing a scalar value by a Angle unit value returns a value of type InverseAngle
-impl<T> core::ops::Div<Angle<T>> for &i32 where T: NumLike+From<i32> {
-	type Output = InverseAngle<T>;
-	fn div(self, rhs: Angle<T>) -> Self::Output {
-		InverseAngle{per_rad: T::from(self.clone()) / rhs.rad}
+
+	/// Returns a new angle value from the given number of gradians
+	/// 
+	/// *Note: This method is not available for `f32` and other number types lacking the `From<f64>` trait*
+	///
+	/// # Arguments
+	/// * `gradians` - Any number-like type, representing a quantity of gradians
+	pub fn from_gradians(gradians: T) -> Self {
+		Angle{rad: gradians * T::from(0.015707963267948967_f64)}
 	}
-}
-/// Dividing a scalar value by a Angle unit value returns a value of type InverseAngle
-impl<T> core::ops::Div<&Angle<T>> for i32 where T: NumLike+From<i32> {
-	type Output = InverseAngle<T>;
-	fn div(self, rhs: &Angle<T>) -> Self::Output {
-		InverseAngle{per_rad: T::from(self) / rhs.rad.clone()}
+
+	/// Returns a copy of this angle value in gradians
+	/// 
+	/// *Note: This method is not available for `f32` and other number types lacking the `From<f64>` trait*
+	pub fn to_grad(&self) -> T {
+		return self.rad.clone() * T::from(63.66197723675813_f64);
 	}
-}
-/// Dividing a scalar value by a Angle unit value returns a value of type InverseAngle
-impl<T> core::ops::Div<&Angle<T>> for &i32 where T: NumLike+From<i32> {
-	type Output = InverseAngle<T>;
-	fn div(self, rhs: &Angle<T>) -> Self::Output {
-		InverseAngle{per_rad: T::from(self.clone()) / rhs.rad.clone()}
+
+	/// Returns a new angle value from the given number of gradians
+	/// 
+	/// *Note: This method is not available for `f32` and other number types lacking the `From<f64>` trait*
+	///
+	/// # Arguments
+	/// * `grad` - Any number-like type, representing a quantity of gradians
+	pub fn from_grad(grad: T) -> Self {
+		Angle{rad: grad * T::from(0.015707963267948967_f64)}
 	}
+
 }
 
-// 1/Angle -> InverseAngle
-/// Dividing a scalar value by a Angle unit value returns a value of type InverseAngle
+
+/// Multiplying a unit value by a scalar value returns a unit value
 #[cfg(feature="num-bigfloat")]
-impl<T> core::ops::Div<Angle<T>> for num_bigfloat::BigFloat where T: NumLike+From<num_bigfloat::BigFloat> {
-	type Output = InverseAngle<T>;
-	fn div(self, rhs: Angle<T>) -> Self::Output {
-		InverseAngle{per_rad: T::from(self) / rhs.rad}
+impl core::ops::Mul<Angle<num_bigfloat::BigFloat>> for num_bigfloat::BigFloat {
+	type Output = Angle<num_bigfloat::BigFloat>;
+	fn mul(self, rhs: Angle<num_bigfloat::BigFloat>) -> Self::Output {
+		Angle{rad: self * rhs.rad}
 	}
 }
-/// Dividing a scalar value by a Angle unit value returns a value of type InverseAngle
+/// Multiplying a unit value by a scalar value returns a unit value
 #[cfg(feature="num-bigfloat")]
-impl<T> core::ops::Div<Angle<T>> for &num_bigfloat::BigFloat where T: NumLike+From<num_bigfloat::BigFloat> {
-	type Output = InverseAngle<T>;
-	fn div(self, rhs: Angle<T>) -> Self::Output {
-		InverseAngle{per_rad: T::from(self.clone()) / rhs.rad}
+impl core::ops::Mul<Angle<num_bigfloat::BigFloat>> for &num_bigfloat::BigFloat {
+	type Output = Angle<num_bigfloat::BigFloat>;
+	fn mul(self, rhs: Angle<num_bigfloat::BigFloat>) -> Self::Output {
+		Angle{rad: self.clone() * rhs.rad}
 	}
 }
-/// Dividing a scalar value by a Angle unit value returns a value of type InverseAngle
+/// Multiplying a unit value by a scalar value returns a unit value
 #[cfg(feature="num-bigfloat")]
-impl<T> core::ops::Div<&Angle<T>> for num_bigfloat::BigFloat where T: NumLike+From<num_bigfloat::BigFloat> {
-	type Output = InverseAngle<T>;
-	fn div(self, rhs: &Angle<T>) -> Self::Output {
-		InverseAngle{per_rad: T::from(self) / rhs.rad.clone()}
+impl core::ops::Mul<&Angle<num_bigfloat::BigFloat>> for num_bigfloat::BigFloat {
+	type Output = Angle<num_bigfloat::BigFloat>;
+	fn mul(self, rhs: &Angle<num_bigfloat::BigFloat>) -> Self::Output {
+		Angle{rad: self * rhs.rad.clone()}
 	}
 }
-/// Dividing a scalar value by a Angle unit value returns a value of type InverseAngle
+/// Multiplying a unit value by a scalar value returns a unit value
 #[cfg(feature="num-bigfloat")]
-impl<T> core::ops::Div<&Angle<T>> for &num_bigfloat::BigFloat where T: NumLike+From<num_bigfloat::BigFloat> {
-	type Output = InverseAngle<T>;
-	fn div(self, rhs: &Angle<T>) -> Self::Output {
-		InverseAngle{per_rad: T::from(self.clone()) / rhs.rad.clone()}
+impl core::ops::Mul<&Angle<num_bigfloat::BigFloat>> for &num_bigfloat::BigFloat {
+	type Output = Angle<num_bigfloat::BigFloat>;
+	fn mul(self, rhs: &Angle<num_bigfloat::BigFloat>) -> Self::Output {
+		Angle{rad: self.clone() * rhs.rad.clone()}
 	}
 }
 
-// 1/Angle -> InverseAngle
-/// Dividing a scalar value by a Angle unit value returns a value of type InverseAngle
+/// Multiplying a unit value by a scalar value returns a unit value
 #[cfg(feature="num-complex")]
-impl<T> core::ops::Div<Angle<T>> for num_complex::Complex32 where T: NumLike+From<num_complex::Complex32> {
-	type Output = InverseAngle<T>;
-	fn div(self, rhs: Angle<T>) -> Self::Output {
-		InverseAngle{per_rad: T::from(self) / rhs.rad}
+impl core::ops::Mul<Angle<num_complex::Complex32>> for num_complex::Complex32 {
+	type Output = Angle<num_complex::Complex32>;
+	fn mul(self, rhs: Angle<num_complex::Complex32>) -> Self::Output {
+		Angle{rad: self * rhs.rad}
 	}
 }
-/// Dividing a scalar value by a Angle unit value returns a value of type InverseAngle
+/// Multiplying a unit value by a scalar value returns a unit value
 #[cfg(feature="num-complex")]
-impl<T> core::ops::Div<Angle<T>> for &num_complex::Complex32 where T: NumLike+From<num_complex::Complex32> {
-	type Output = InverseAngle<T>;
-	fn div(self, rhs: Angle<T>) -> Self::Output {
-		InverseAngle{per_rad: T::from(self.clone()) / rhs.rad}
+impl core::ops::Mul<Angle<num_complex::Complex32>> for &num_complex::Complex32 {
+	type Output = Angle<num_complex::Complex32>;
+	fn mul(self, rhs: Angle<num_complex::Complex32>) -> Self::Output {
+		Angle{rad: self.clone() * rhs.rad}
 	}
 }
-/// Dividing a scalar value by a Angle unit value returns a value of type InverseAngle
+/// Multiplying a unit value by a scalar value returns a unit value
 #[cfg(feature="num-complex")]
-impl<T> core::ops::Div<&Angle<T>> for num_complex::Complex32 where T: NumLike+From<num_complex::Complex32> {
-	type Output = InverseAngle<T>;
-	fn div(self, rhs: &Angle<T>) -> Self::Output {
-		InverseAngle{per_rad: T::from(self) / rhs.rad.clone()}
+impl core::ops::Mul<&Angle<num_complex::Complex32>> for num_complex::Complex32 {
+	type Output = Angle<num_complex::Complex32>;
+	fn mul(self, rhs: &Angle<num_complex::Complex32>) -> Self::Output {
+		Angle{rad: self * rhs.rad.clone()}
 	}
 }
-/// Dividing a scalar value by a Angle unit value returns a value of type InverseAngle
+/// Multiplying a unit value by a scalar value returns a unit value
 #[cfg(feature="num-complex")]
-impl<T> core::ops::Div<&Angle<T>> for &num_complex::Complex32 where T: NumLike+From<num_complex::Complex32> {
-	type Output = InverseAngle<T>;
-	fn div(self, rhs: &Angle<T>) -> Self::Output {
-		InverseAngle{per_rad: T::from(self.clone()) / rhs.rad.clone()}
+impl core::ops::Mul<&Angle<num_complex::Complex32>> for &num_complex::Complex32 {
+	type Output = Angle<num_complex::Complex32>;
+	fn mul(self, rhs: &Angle<num_complex::Complex32>) -> Self::Output {
+		Angle{rad: self.clone() * rhs.rad.clone()}
 	}
 }
 
-// 1/Angle -> InverseAngle
-/// Dividing a scalar value by a Angle unit value returns a value of type InverseAngle
+/// Multiplying a unit value by a scalar value returns a unit value
 #[cfg(feature="num-complex")]
-impl<T> core::ops::Div<Angle<T>> for num_complex::Complex64 where T: NumLike+From<num_complex::Complex64> {
-	type Output = InverseAngle<T>;
-	fn div(self, rhs: Angle<T>) -> Self::Output {
-		InverseAngle{per_rad: T::from(self) / rhs.rad}
+impl core::ops::Mul<Angle<num_complex::Complex64>> for num_complex::Complex64 {
+	type Output = Angle<num_complex::Complex64>;
+	fn mul(self, rhs: Angle<num_complex::Complex64>) -> Self::Output {
+		Angle{rad: self * rhs.rad}
 	}
 }
-/// Dividing a scalar value by a Angle unit value returns a value of type InverseAngle
+/// Multiplying a unit value by a scalar value returns a unit value
 #[cfg(feature="num-complex")]
-impl<T> core::ops::Div<Angle<T>> for &num_complex::Complex64 where T: NumLike+From<num_complex::Complex64> {
-	type Output = InverseAngle<T>;
-	fn div(self, rhs: Angle<T>) -> Self::Output {
-		InverseAngle{per_rad: T::from(self.clone()) / rhs.rad}
+impl core::ops::Mul<Angle<num_complex::Complex64>> for &num_complex::Complex64 {
+	type Output = Angle<num_complex::Complex64>;
+	fn mul(self, rhs: Angle<num_complex::Complex64>) -> Self::Output {
+		Angle{rad: self.clone() * rhs.rad}
 	}
 }
-/// Dividing a scalar value by a Angle unit value returns a value of type InverseAngle
+/// Multiplying a unit value by a scalar value returns a unit value
 #[cfg(feature="num-complex")]
-impl<T> core::ops::Div<&Angle<T>> for num_complex::Complex64 where T: NumLike+From<num_complex::Complex64> {
-	type Output = InverseAngle<T>;
-	fn div(self, rhs: &Angle<T>) -> Self::Output {
-		InverseAngle{per_rad: T::from(self) / rhs.rad.clone()}
+impl core::ops::Mul<&Angle<num_complex::Complex64>> for num_complex::Complex64 {
+	type Output = Angle<num_complex::Complex64>;
+	fn mul(self, rhs: &Angle<num_complex::Complex64>) -> Self::Output {
+		Angle{rad: self * rhs.rad.clone()}
 	}
 }
-/// Dividing a scalar value by a Angle unit value returns a value of type InverseAngle
+/// Multiplying a unit value by a scalar value returns a unit value
 #[cfg(feature="num-complex")]
-impl<T> core::ops::Div<&Angle<T>> for &num_complex::Complex64 where T: NumLike+From<num_complex::Complex64> {
-	type Output = InverseAngle<T>;
-	fn div(self, rhs: &Angle<T>) -> Self::Output {
-		InverseAngle{per_rad: T::from(self.clone()) / rhs.rad.clone()}
+impl core::ops::Mul<&Angle<num_complex::Complex64>> for &num_complex::Complex64 {
+	type Output = Angle<num_complex::Complex64>;
+	fn mul(self, rhs: &Angle<num_complex::Complex64>) -> Self::Output {
+		Angle{rad: self.clone() * rhs.rad.clone()}
 	}
 }
 
-/// The area unit type, defined as square meters in SI units
-#[derive(UnitStruct, Debug, Clone)]
-#[cfg_attr(feature="serde", derive(Serialize, Deserialize))]
-pub struct Area<T: NumLike>{
-	/// The value of this Area in square meters
-	pub m2: T
-}
-
-impl<T> Area<T> where T: NumLike {
-
-	/// Returns the standard unit name of area: "square meters"
-	pub fn unit_name() -> &'static str { "square meters" }
-	
-	/// Returns the abbreviated name or symbol of area: "m²" for square meters
-	pub fn unit_symbol() -> &'static str { "m²" }
-	
-	/// Returns a new area value from the given number of square meters
-	///
-	/// # Arguments
-	/// * `m2` - Any number-like type, representing a quantity of square meters
-	pub fn from_m2(m2: T) -> Self { Area{m2: m2} }
-	
-	/// Returns a copy of this area value in square meters
-	pub fn to_m2(&self) -> T { self.m2.clone() }
-
-	/// Returns a new area value from the given number of square meters
-	///
-	/// # Arguments
-	/// * `square_meters` - Any number-like type, representing a quantity of square meters
-	pub fn from_square_meters(square_meters: T) -> Self { Area{m2: square_meters} }
-	
-	/// Returns a copy of this area value in square meters
-	pub fn to_square_meters(&self) -> T { self.m2.clone() }
-
-}
-
-impl<T> fmt::Display for Area<T> where T: NumLike {
-	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-		write!(f, "{} {}", &self.m2, Self::unit_symbol())
-	}
-}
-
-impl<T> Area<T> where T: NumLike+From<f64> {
-	
-	/// Returns a copy of this area value in square cm
-	/// 
-	/// *Note: This method is not available for `f32` and other number types lacking the `From<f64>` trait*
-	pub fn to_cm2(&self) -> T {
-		return self.m2.clone() * T::from(10000.0_f64);
-	}
-
-	/// Returns a new area value from the given number of square cm
-	/// 
-	/// *Note: This method is not available for `f32` and other number types lacking the `From<f64>` trait*
-	///
-	/// # Arguments
-	/// * `cm2` - Any number-like type, representing a quantity of square cm
-	pub fn from_cm2(cm2: T) -> Self {
-		Area{m2: cm2 * T::from(0.0001_f64)}
-	}
-
-	/// Returns a copy of this area value in square cm
-	/// 
-	/// *Note: This method is not available for `f32` and other number types lacking the `From<f64>` trait*
-	pub fn to_square_cm(&self) -> T {
-		return self.m2.clone() * T::from(10000.0_f64);
-	}
-
-	/// Returns a new area value from the given number of square cm
-	/// 
-	/// *Note: This method is not available for `f32` and other number types lacking the `From<f64>` trait*
-	///
-	/// # Arguments
-	/// * `square_cm` - Any number-like type, representing a quantity of square cm
-	pub fn from_square_cm(square_cm: T) -> Self {
-		Area{m2: square_cm * T::from(0.0001_f64)}
-	}
-
-	/// Returns a copy of this area value in square mm
-	/// 
-	/// *Note: This method is not available for `f32` and other number types lacking the `From<f64>` trait*
-	pub fn to_mm2(&self) -> T {
-		return self.m2.clone() * T::from(1000000.0_f64);
-	}
-
-	/// Returns a new area value from the given number of square mm
-	/// 
-	/// *Note: This method is not available for `f32` and other number types lacking the `From<f64>` trait*
-	///
-	/// # Arguments
-	/// * `mm2` - Any number-like type, representing a quantity of square mm
-	pub fn from_mm2(mm2: T) -> Self {
-		Area{m2: mm2 * T::from(1e-06_f64)}
-	}
-
-	/// Returns a copy of this area value in square um
-	/// 
-	/// *Note: This method is not available for `f32` and other number types lacking the `From<f64>` trait*
-	pub fn to_um2(&self) -> T {
-		return self.m2.clone() * T::from(1000000000000.0_f64);
-	}
-
-	/// Returns a new area value from the given number of square um
-	/// 
-	/// *Note: This method is not available for `f32` and other number types lacking the `From<f64>` trait*
-	///
-	/// # Arguments
-	/// * `um2` - Any number-like type, representing a quantity of square um
-	pub fn from_um2(um2: T) -> Self {
-		Area{m2: um2 * T::from(1e-12_f64)}
-	}
-
-	/// Returns a copy of this area value in square nm
-	/// 
-	/// *Note: This method is not available for `f32` and other number types lacking the `From<f64>` trait*
-	pub fn to_nm2(&self) -> T {
-		return self.m2.clone() * T::from(1e+18_f64);
-	}
-
-	/// Returns a new area value from the given number of square nm
-	/// 
-	/// *Note: This method is not available for `f32` and other number types lacking the `From<f64>` trait*
-	///
-	/// # Arguments
-	/// * `nm2` - Any number-like type, representing a quantity of square nm
-	pub fn from_nm2(nm2: T) -> Self {
-		Area{m2: nm2 * T::from(1e-18_f64)}
-	}
-
-	/// Returns a copy of this area value in square km
-	/// 
-	/// *Note: This method is not available for `f32` and other number types lacking the `From<f64>` trait*
-	pub fn to_km2(&self) -> T {
-		return self.m2.clone() * T::from(1e-06_f64);
-	}
-
-	/// Returns a new area value from the given number of square km
-	/// 
-	/// *Note: This method is not available for `f32` and other number types lacking the `From<f64>` trait*
-	///
-	/// # Arguments
-	/// * `km2` - Any number-like type, representing a quantity of square km
-	pub fn from_km2(km2: T) -> Self {
-		Area{m2: km2 * T::from(1000000.0_f64)}
-	}
-
-}
-
-
-/// Multiplying a unit value by a scalar value returns a unit value
-#[cfg(feature="num-bigfloat")]
-impl core::ops::Mul<Area<num_bigfloat::BigFloat>> for num_bigfloat::BigFloat {
-	type Output = Area<num_bigfloat::BigFloat>;
-	fn mul(self, rhs: Area<num_bigfloat::BigFloat>) -> Self::Output {
-		Area{m2: self * rhs.m2}
-	}
-}
-/// Multiplying a unit value by a scalar value returns a unit value
-#[cfg(feature="num-bigfloat")]
-impl core::ops::Mul<Area<num_bigfloat::BigFloat>> for &num_bigfloat::BigFloat {
-	type Output = Area<num_bigfloat::BigFloat>;
-	fn mul(self, rhs: Area<num_bigfloat::BigFloat>) -> Self::Output {
-		Area{m2: self.clone() * rhs.m2}
-	}
-}
-/// Multiplying a unit value by a scalar value returns a unit value
-#[cfg(feature="num-bigfloat")]
-impl core::ops::Mul<&Area<num_bigfloat::BigFloat>> for num_bigfloat::BigFloat {
-	type Output = Area<num_bigfloat::BigFloat>;
-	fn mul(self, rhs: &Area<num_bigfloat::BigFloat>) -> Self::Output {
-		Area{m2: self * rhs.m2.clone()}
-	}
-}
-/// Multiplying a unit value by a scalar value returns a unit value
-#[cfg(feature="num-bigfloat")]
-impl core::ops::Mul<&Area<num_bigfloat::BigFloat>> for &num_bigfloat::BigFloat {
-	type Output = Area<num_bigfloat::BigFloat>;
-	fn mul(self, rhs: &Area<num_bigfloat::BigFloat>) -> Self::Output {
-		Area{m2: self.clone() * rhs.m2.clone()}
-	}
-}
-
-/// Multiplying a unit value by a scalar value returns a unit value
-#[cfg(feature="num-complex")]
-impl core::ops::Mul<Area<num_complex::Complex32>> for num_complex::Complex32 {
-	type Output = Area<num_complex::Complex32>;
-	fn mul(self, rhs: Area<num_complex::Complex32>) -> Self::Output {
-		Area{m2: self * rhs.m2}
-	}
+/// Multiplying a unit value by a scalar value returns a unit value
+#[cfg(feature="num-rational")]
+impl core::ops::Mul<Angle<num_rational::Rational64>> for num_rational::Rational64 {
+	type Output = Angle<num_rational::Rational64>;
+	fn mul(self, rhs: Angle<num_rational::Rational64>) -> Self::Output {
+		Angle{rad: self * rhs.rad}
+	}
 }
 /// Multiplying a unit value by a scalar value returns a unit value
-#[cfg(feature="num-complex")]
-impl core::ops::Mul<Area<num_complex::Complex32>> for &num_complex::Complex32 {
-	type Output = Area<num_complex::Complex32>;
-	fn mul(self, rhs: Area<num_complex::Complex32>) -> Self::Output {
-		Area{m2: self.clone() * rhs.m2}
+#[cfg(feature="num-rational")]
+impl core::ops::Mul<Angle<num_rational::Rational64>> for &num_rational::Rational64 {
+	type Output = Angle<num_rational::Rational64>;
+	fn mul(self, rhs: Angle<num_rational::Rational64>) -> Self::Output {
+		Angle{rad: self.clone() * rhs.rad}
 	}
 }
 /// Multiplying a unit value by a scalar value returns a unit value
-#[cfg(feature="num-complex")]
-impl core::ops::Mul<&Area<num_complex::Complex32>> for num_complex::Complex32 {
-	type Output = Area<num_complex::Complex32>;
-	fn mul(self, rhs: &Area<num_complex::Complex32>) -> Self::Output {
-		Area{m2: self * rhs.m2.clone()}
+#[cfg(feature="num-rational")]
+impl core::ops::Mul<&Angle<num_rational::Rational64>> for num_rational::Rational64 {
+	type Output = Angle<num_rational::Rational64>;
+	fn mul(self, rhs: &Angle<num_rational::Rational64>) -> Self::Output {
+		Angle{rad: self * rhs.rad.clone()}
 	}
 }
 /// Multiplying a unit value by a scalar value returns a unit value
-#[cfg(feature="num-complex")]
-impl core::ops::Mul<&Area<num_complex::Complex32>> for &num_complex::Complex32 {
-	type Output = Area<num_complex::Complex32>;
-	fn mul(self, rhs: &Area<num_complex::Complex32>) -> Self::Output {
-		Area{m2: self.clone() * rhs.m2.clone()}
+#[cfg(feature="num-rational")]
+impl core::ops::Mul<&Angle<num_rational::Rational64>> for &num_rational::Rational64 {
+	type Output = Angle<num_rational::Rational64>;
+	fn mul(self, rhs: &Angle<num_rational::Rational64>) -> Self::Output {
+		Angle{rad: self.clone() * rhs.rad.clone()}
 	}
 }
 
 /// Multiplying a unit value by a scalar value returns a unit value
-#[cfg(feature="num-complex")]
-impl core::ops::Mul<Area<num_complex::Complex64>> for num_complex::Complex64 {
-	type Output = Area<num_complex::Complex64>;
-	fn mul(self, rhs: Area<num_complex::Complex64>) -> Self::Output {
-		Area{m2: self * rhs.m2}
+#[cfg(feature="num-rational")]
+impl core::ops::Mul<Angle<num_rational::BigRational>> for num_rational::BigRational {
+	type Output = Angle<num_rational::BigRational>;
+	fn mul(self, rhs: Angle<num_rational::BigRational>) -> Self::Output {
+		Angle{rad: self * rhs.rad}
 	}
 }
 /// Multiplying a unit value by a scalar value returns a unit value
-#[cfg(feature="num-complex")]
-impl core::ops::Mul<Area<num_complex::Complex64>> for &num_complex::Complex64 {
-	type Output = Area<num_complex::Complex64>;
-	fn mul(self, rhs: Area<num_complex::Complex64>) -> Self::Output {
-		Area{m2: self.clone() * rhs.m2}
+#[cfg(feature="num-rational")]
+impl core::ops::Mul<Angle<num_rational::BigRational>> for &num_rational::BigRational {
+	type Output = Angle<num_rational::BigRational>;
+	fn mul(self, rhs: Angle<num_rational::BigRational>) -> Self::Output {
+		Angle{rad: self.clone() * rhs.rad}
 	}
 }
 /// Multiplying a unit value by a scalar value returns a unit value
-#[cfg(feature="num-complex")]
-impl core::ops::Mul<&Area<num_complex::Complex64>> for num_complex::Complex64 {
-	type Output = Area<num_complex::Complex64>;
-	fn mul(self, rhs: &Area<num_complex::Complex64>) -> Self::Output {
-		Area{m2: self * rhs.m2.clone()}
+#[cfg(feature="num-rational")]
+impl core::ops::Mul<&Angle<num_rational::BigRational>> for num_rational::BigRational {
+	type Output = Angle<num_rational::BigRational>;
+	fn mul(self, rhs: &Angle<num_rational::BigRational>) -> Self::Output {
+		Angle{rad: self * rhs.rad.clone()}
 	}
 }
 /// Multiplying a unit value by a scalar value returns a unit value
-#[cfg(feature="num-complex")]
-impl core::ops::Mul<&Area<num_complex::Complex64>> for &num_complex::Complex64 {
-	type Output = Area<num_complex::Complex64>;
-	fn mul(self, rhs: &Area<num_complex::Complex64>) -> Self::Output {
-		Area{m2: self.clone() * rhs.m2.clone()}
+#[cfg(feature="num-rational")]
+impl core::ops::Mul<&Angle<num_rational::BigRational>> for &num_rational::BigRational {
+	type Output = Angle<num_rational::BigRational>;
+	fn mul(self, rhs: &Angle<num_rational::BigRational>) -> Self::Output {
+		Angle{rad: self.clone() * rhs.rad.clone()}
 	}
 }
 
 
 
-/// Converts a Area into the equivalent [uom](https://crates.io/crates/uom) type [Area](https://docs.rs/uom/0.34.0/uom/si/f32/type.Area.html)
+/// Converts a Angle into the equivalent [uom](https://crates.io/crates/uom) type [Angle](https://docs.rs/uom/0.34.0/uom/si/f32/type.Angle.html)
 #[cfg(feature = "uom")]
-impl<T> Into<uom::si::f32::Area> for Area<T> where T: NumLike+Into<f32> {
-	fn into(self) -> uom::si::f32::Area {
-		uom::si::f32::Area::new::<uom::si::area::square_meter>(self.m2.into())
+impl<T> Into<uom::si::f32::Angle> for Angle<T> where T: NumLike+Into<f32> {
+	fn into(self) -> uom::si::f32::Angle {
+		uom::si::f32::Angle::new::<uom::si::angle::radian>(self.rad.into())
 	}
 }
 
-/// Creates a Area from the equivalent [uom](https://crates.io/crates/uom) type [Area](https://docs.rs/uom/0.34.0/uom/si/f32/type.Area.html)
+/// Creates a Angle from the equivalent [uom](https://crates.io/crates/uom) type [Angle](https://docs.rs/uom/0.34.0/uom/si/f32/type.Angle.html)
 #[cfg(feature = "uom")]
-impl<T> From<uom::si::f32::Area> for Area<T> where T: NumLike+From<f32> {
-	fn from(src: uom::si::f32::Area) -> Self {
-		Area{m2: T::from(src.value)}
+impl<T> From<uom::si::f32::Angle> for Angle<T> where T: NumLike+From<f32> {
+	fn from(src: uom::si::f32::Angle) -> Self {
+		Angle{rad: T::from(src.value)}
 	}
 }
 
-/// Converts a Area into the equivalent [uom](https://crates.io/crates/uom) type [Area](https://docs.rs/uom/0.34.0/uom/si/f64/type.Area.html)
+/// Converts a Angle into the equivalent [uom](https://crates.io/crates/uom) type [Angle](https://docs.rs/uom/0.34.0/uom/si/f64/type.Angle.html)
 #[cfg(feature = "uom")]
-impl<T> Into<uom::si::f64::Area> for Area<T> where T: NumLike+Into<f64> {
-	fn into(self) -> uom::si::f64::Area {
-		uom::si::f64::Area::new::<uom::si::area::square_meter>(self.m2.into())
+impl<T> Into<uom::si::f64::Angle> for Angle<T> where T: NumLike+Into<f64> {
+	fn into(self) -> uom::si::f64::Angle {
+		uom::si::f64::Angle::new::<uom::si::angle::radian>(self.rad.into())
 	}
 }
 
-/// Creates a Area from the equivalent [uom](https://crates.io/crates/uom) type [Area](https://docs.rs/uom/0.34.0/uom/si/f64/type.Area.html)
+/// Creates a Angle from the equivalent [uom](https://crates.io/crates/uom) type [Angle](https://docs.rs/uom/0.34.0/uom/si/f64/type.Angle.html)
 #[cfg(feature = "uom")]
-impl<T> From<uom::si::f64::Area> for Area<T> where T: NumLike+From<f64> {
-	fn from(src: uom::si::f64::Area) -> Self {
-		Area{m2: T::from(src.value)}
+impl<T> From<uom::si::f64::Angle> for Angle<T> where T: NumLike+From<f64> {
+	fn from(src: uom::si::f64::Angle) -> Self {
+		Angle{rad: T::from(src.value)}
 	}
 }
 
 
-// Area * Distance -> Volume
-/// Multiplying a Area by a Distance returns a value of type Volume
-impl<T> core::ops::Mul<Distance<T>> for Area<T> where T: NumLike {
-	type Output = Volume<T>;
-	fn mul(self, rhs: Distance<T>) -> Self::Output {
-		Volume{m3: self.m2 * rhs.m}
+
+/// Multiplying a Angle by an [nalgebra](https://crates.io/crates/nalgebra) 
+/// vector or matrix of unitless values returns a vector or matrix of Angle 
+/// values (eg a direction vector multiplied by a Angle)
+#[cfg(feature="nalgebra")]
+impl<T, R, C, S> core::ops::Mul<nalgebra::Matrix<T, R, C, S>> for Angle<T> where 
+	T: NumLike+nalgebra::Scalar, R: nalgebra::Dim, C: nalgebra::Dim, S: nalgebra::RawStorage<T, R, C>, 
+	nalgebra::DefaultAllocator: nalgebra::allocator::Allocator<R, C> {
+	type Output = nalgebra::OMatrix<Angle<T>, R, C>;
+	fn mul(self, rhs: nalgebra::Matrix<T, R, C, S>) -> Self::Output {
+		rhs.map(|x| Angle{rad: self.rad.clone() * x})
 	}
 }
-/// Multiplying a Area by a Distance returns a value of type Volume
-impl<T> core::ops::Mul<Distance<T>> for &Area<T> where T: NumLike {
-	type Output = Volume<T>;
-	fn mul(self, rhs: Distance<T>) -> Self::Output {
-		Volume{m3: self.m2.clone() * rhs.m}
-	}
-}
-/// Multiplying a Area by a Distance returns a value of type Volume
-impl<T> core::ops::Mul<&Distance<T>> for Area<T> where T: NumLike {
-	type Output = Volume<T>;
-	fn mul(self, rhs: &Distance<T>) -> Self::Output {
-		Volume{m3: self.m2 * rhs.m.clone()}
+/// Multiplying an [nalgebra](https://crates.io/crates/nalgebra) vector or matrix of 
+/// unitless values by a Angle returns a vector or matrix of Angle values
+#[cfg(feature="nalgebra")]
+impl<T, R, C, S> core::ops::Mul<Angle<T>> for nalgebra::Matrix<T, R, C, S> where 
+	T: NumLike+nalgebra::Scalar, R: nalgebra::Dim, C: nalgebra::Dim, S: nalgebra::RawStorage<T, R, C>, 
+	nalgebra::DefaultAllocator: nalgebra::allocator::Allocator<R, C> {
+	type Output = nalgebra::OMatrix<Angle<T>, R, C>;
+	fn mul(self, rhs: Angle<T>) -> Self::Output {
+		self.map(|x| Angle{rad: x * rhs.rad.clone()})
 	}
 }
-/// Multiplying a Area by a Distance returns a value of type Volume
-impl<T> core::ops::Mul<&Distance<T>> for &Area<T> where T: NumLike {
-	type Output = Volume<T>;
-	fn mul(self, rhs: &Distance<T>) -> Self::Output {
-		Volume{m3: self.m2.clone() * rhs.m.clone()}
+/// Dividing an [nalgebra](https://crates.io/crates/nalgebra) vector or matrix of 
+/// Angle values by a Angle returns a vector or matrix of unitless values
+#[cfg(feature="nalgebra")]
+impl<T, R, C, S> core::ops::Div<Angle<T>> for nalgebra::Matrix<Angle<T>, R, C, S> where 
+	T: NumLike+nalgebra::Scalar, R: nalgebra::Dim, C: nalgebra::Dim, S: nalgebra::RawStorage<Angle<T>, R, C>, 
+	nalgebra::DefaultAllocator: nalgebra::allocator::Allocator<R, C> {
+	type Output = nalgebra::OMatrix<T, R, C>;
+	fn div(self, rhs: Angle<T>) -> Self::Output {
+		self.map(|x| x.rad / rhs.rad.clone())
 	}
 }
 
-// Area / Distance -> Distance
-/// Dividing a Area by a Distance returns a value of type Distance
-impl<T> core::ops::Div<Distance<T>> for Area<T> where T: NumLike {
-	type Output = Distance<T>;
-	fn div(self, rhs: Distance<T>) -> Self::Output {
-		Distance{m: self.m2 / rhs.m}
+
+
+// Angle / Time -> AngularVelocity
+/// Dividing a Angle by a Time returns a value of type AngularVelocity
+impl<T> core::ops::Div<Time<T>> for Angle<T> where T: NumLike {
+	type Output = AngularVelocity<T>;
+	fn div(self, rhs: Time<T>) -> Self::Output {
+		AngularVelocity{radps: self.rad / rhs.s}
 	}
 }
-/// Dividing a Area by a Distance returns a value of type Distance
-impl<T> core::ops::Div<Distance<T>> for &Area<T> where T: NumLike {
-	type Output = Distance<T>;
-	fn div(self, rhs: Distance<T>) -> Self::Output {
-		Distance{m: self.m2.clone() / rhs.m}
+/// Dividing a Angle by a Time returns a value of type AngularVelocity
+impl<T> core::ops::Div<Time<T>> for &Angle<T> where T: NumLike {
+	type Output = AngularVelocity<T>;
+	fn div(self, rhs: Time<T>) -> Self::Output {
+		AngularVelocity{radps: self.rad.clone() / rhs.s}
 	}
 }
-/// Dividing a Area by a Distance returns a value of type Distance
-impl<T> core::ops::Div<&Distance<T>> for Area<T> where T: NumLike {
-	type Output = Distance<T>;
-	fn div(self, rhs: &Distance<T>) -> Self::Output {
-		Distance{m: self.m2 / rhs.m.clone()}
+/// Dividing a Angle by a Time returns a value of type AngularVelocity
+impl<T> core::ops::Div<&Time<T>> for Angle<T> where T: NumLike {
+	type Output = AngularVelocity<T>;
+	fn div(self, rhs: &Time<T>) -> Self::Output {
+		AngularVelocity{radps: self.rad / rhs.s.clone()}
 	}
 }
-/// Dividing a Area by a Distance returns a value of type Distance
-impl<T> core::ops::Div<&Distance<T>> for &Area<T> where T: NumLike {
-	type Output = Distance<T>;
-	fn div(self, rhs: &Distance<T>) -> Self::Output {
-		Distance{m: self.m2.clone() / rhs.m.clone()}
+/// Dividing a Angle by a Time returns a value of type AngularVelocity
+impl<T> core::ops::Div<&Time<T>> for &Angle<T> where T: NumLike {
+	type Output = AngularVelocity<T>;
+	fn div(self, rhs: &Time<T>) -> Self::Output {
+		AngularVelocity{radps: self.rad.clone() / rhs.s.clone()}
 	}
 }
 
-// Area * InverseDistance -> Distance
-/// Multiplying a Area by a InverseDistance returns a value of type Distance
-impl<T> core::ops::Mul<InverseDistance<T>> for Area<T> where T: NumLike {
-	type Output = Distance<T>;
-	fn mul(self, rhs: InverseDistance<T>) -> Self::Output {
-		Distance{m: self.m2 * rhs.per_m}
+// Angle * Angle -> SolidAngle
+/// Multiplying a Angle by a Angle returns a value of type SolidAngle
+impl<T> core::ops::Mul<Angle<T>> for Angle<T> where T: NumLike {
+	type Output = SolidAngle<T>;
+	fn mul(self, rhs: Angle<T>) -> Self::Output {
+		SolidAngle{sr: self.rad * rhs.rad}
 	}
 }
-/// Multiplying a Area by a InverseDistance returns a value of type Distance
-impl<T> core::ops::Mul<InverseDistance<T>> for &Area<T> where T: NumLike {
-	type Output = Distance<T>;
-	fn mul(self, rhs: InverseDistance<T>) -> Self::Output {
-		Distance{m: self.m2.clone() * rhs.per_m}
+/// Multiplying a Angle by a Angle returns a value of type SolidAngle
+impl<T> core::ops::Mul<Angle<T>> for &Angle<T> where T: NumLike {
+	type Output = SolidAngle<T>;
+	fn mul(self, rhs: Angle<T>) -> Self::Output {
+		SolidAngle{sr: self.rad.clone() * rhs.rad}
 	}
 }
-/// Multiplying a Area by a InverseDistance returns a value of type Distance
-impl<T> core::ops::Mul<&InverseDistance<T>> for Area<T> where T: NumLike {
-	type Output = Distance<T>;
-	fn mul(self, rhs: &InverseDistance<T>) -> Self::Output {
-		Distance{m: self.m2 * rhs.per_m.clone()}
+/// Multiplying a Angle by a Angle returns a value of type SolidAngle
+impl<T> core::ops::Mul<&Angle<T>> for Angle<T> where T: NumLike {
+	type Output = SolidAngle<T>;
+	fn mul(self, rhs: &Angle<T>) -> Self::Output {
+		SolidAngle{sr: self.rad * rhs.rad.clone()}
 	}
 }
-/// Multiplying a Area by a InverseDistance returns a value of type Distance
-impl<T> core::ops::Mul<&InverseDistance<T>> for &Area<T> where T: NumLike {
-	type Output = Distance<T>;
-	fn mul(self, rhs: &InverseDistance<T>) -> Self::Output {
-		Distance{m: self.m2.clone() * rhs.per_m.clone()}
+/// Multiplying a Angle by a Angle returns a value of type SolidAngle
+impl<T> core::ops::Mul<&Angle<T>> for &Angle<T> where T: NumLike {
+	type Output = SolidAngle<T>;
+	fn mul(self, rhs: &Angle<T>) -> Self::Output {
+		SolidAngle{sr: self.rad.clone() * rhs.rad.clone()}
 	}
 }
 
-// Area / InverseDistance -> Volume
-/// Dividing a Area by a InverseDistance returns a value of type Volume
-impl<T> core::ops::Div<InverseDistance<T>> for Area<T> where T: NumLike {
-	type Output = Volume<T>;
-	fn div(self, rhs: InverseDistance<T>) -> Self::Output {
-		Volume{m3: self.m2 / rhs.per_m}
+// Angle / InverseAngle -> SolidAngle
+/// Dividing a Angle by a InverseAngle returns a value of type SolidAngle
+impl<T> core::ops::Div<InverseAngle<T>> for Angle<T> where T: NumLike {
+	type Output = SolidAngle<T>;
+	fn div(self, rhs: InverseAngle<T>) -> Self::Output {
+		SolidAngle{sr: self.rad / rhs.per_rad}
 	}
 }
-/// Dividing a Area by a InverseDistance returns a value of type Volume
-impl<T> core::ops::Div<InverseDistance<T>> for &Area<T> where T: NumLike {
-	type Output = Volume<T>;
-	fn div(self, rhs: InverseDistance<T>) -> Self::Output {
-		Volume{m3: self.m2.clone() / rhs.per_m}
+/// Dividing a Angle by a InverseAngle returns a value of type SolidAngle
+impl<T> core::ops::Div<InverseAngle<T>> for &Angle<T> where T: NumLike {
+	type Output = SolidAngle<T>;
+	fn div(self, rhs: InverseAngle<T>) -> Self::Output {
+		SolidAngle{sr: self.rad.clone() / rhs.per_rad}
 	}
 }
-/// Dividing a Area by a InverseDistance returns a value of type Volume
-impl<T> core::ops::Div<&InverseDistance<T>> for Area<T> where T: NumLike {
-	type Output = Volume<T>;
-	fn div(self, rhs: &InverseDistance<T>) -> Self::Output {
-		Volume{m3: self.m2 / rhs.per_m.clone()}
+/// Dividing a Angle by a InverseAngle returns a value of type SolidAngle
+impl<T> core::ops::Div<&InverseAngle<T>> for Angle<T> where T: NumLike {
+	type Output = SolidAngle<T>;
+	fn div(self, rhs: &InverseAngle<T>) -> Self::Output {
+		SolidAngle{sr: self.rad / rhs.per_rad.clone()}
 	}
 }
-/// Dividing a Area by a InverseDistance returns a value of type Volume
-impl<T> core::ops::Div<&InverseDistance<T>> for &Area<T> where T: NumLike {
-	type Output = Volume<T>;
-	fn div(self, rhs: &InverseDistance<T>) -> Self::Output {
-		Volume{m3: self.m2.clone() / rhs.per_m.clone()}
+/// Dividing a Angle by a InverseAngle returns a value of type SolidAngle
+impl<T> core::ops::Div<&InverseAngle<T>> for &Angle<T> where T: NumLike {
+	type Output = SolidAngle<T>;
+	fn div(self, rhs: &InverseAngle<T>) -> Self::Output {
+		SolidAngle{sr: self.rad.clone() / rhs.per_rad.clone()}
 	}
 }
 
-// Area * InverseMass -> AreaPerMass
-/// Multiplying a Area by a InverseMass returns a value of type AreaPerMass
-impl<T> core::ops::Mul<InverseMass<T>> for Area<T> where T: NumLike {
-	type Output = AreaPerMass<T>;
-	fn mul(self, rhs: InverseMass<T>) -> Self::Output {
-		AreaPerMass{m2_per_kg: self.m2 * rhs.per_kg}
+// Angle * InverseSolidAngle -> InverseAngle
+/// Multiplying a Angle by a InverseSolidAngle returns a value of type InverseAngle
+impl<T> core::ops::Mul<InverseSolidAngle<T>> for Angle<T> where T: NumLike {
+	type Output = InverseAngle<T>;
+	fn mul(self, rhs: InverseSolidAngle<T>) -> Self::Output {
+		InverseAngle{per_rad: self.rad * rhs.per_sr}
 	}
 }
-/// Multiplying a Area by a InverseMass returns a value of type AreaPerMass
-impl<T> core::ops::Mul<InverseMass<T>> for &Area<T> where T: NumLike {
-	type Output = AreaPerMass<T>;
-	fn mul(self, rhs: InverseMass<T>) -> Self::Output {
-		AreaPerMass{m2_per_kg: self.m2.clone() * rhs.per_kg}
+/// Multiplying a Angle by a InverseSolidAngle returns a value of type InverseAngle
+impl<T> core::ops::Mul<InverseSolidAngle<T>> for &Angle<T> where T: NumLike {
+	type Output = InverseAngle<T>;
+	fn mul(self, rhs: InverseSolidAngle<T>) -> Self::Output {
+		InverseAngle{per_rad: self.rad.clone() * rhs.per_sr}
 	}
 }
-/// Multiplying a Area by a InverseMass returns a value of type AreaPerMass
-impl<T> core::ops::Mul<&InverseMass<T>> for Area<T> where T: NumLike {
-	type Output = AreaPerMass<T>;
-	fn mul(self, rhs: &InverseMass<T>) -> Self::Output {
-		AreaPerMass{m2_per_kg: self.m2 * rhs.per_kg.clone()}
+/// Multiplying a Angle by a InverseSolidAngle returns a value of type InverseAngle
+impl<T> core::ops::Mul<&InverseSolidAngle<T>> for Angle<T> where T: NumLike {
+	type Output = InverseAngle<T>;
+	fn mul(self, rhs: &InverseSolidAngle<T>) -> Self::Output {
+		InverseAngle{per_rad: self.rad * rhs.per_sr.clone()}
 	}
 }
-/// Multiplying a Area by a InverseMass returns a value of type AreaPerMass
-impl<T> core::ops::Mul<&InverseMass<T>> for &Area<T> where T: NumLike {
-	type Output = AreaPerMass<T>;
-	fn mul(self, rhs: &InverseMass<T>) -> Self::Output {
-		AreaPerMass{m2_per_kg: self.m2.clone() * rhs.per_kg.clone()}
+/// Multiplying a Angle by a InverseSolidAngle returns a value of type InverseAngle
+impl<T> core::ops::Mul<&InverseSolidAngle<T>> for &Angle<T> where T: NumLike {
+	type Output = InverseAngle<T>;
+	fn mul(self, rhs: &InverseSolidAngle<T>) -> Self::Output {
+		InverseAngle{per_rad: self.rad.clone() * rhs.per_sr.clone()}
 	}
 }
 
-// Area / Mass -> AreaPerMass
-/// Dividing a Area by a Mass returns a value of type AreaPerMass
-impl<T> core::ops::Div<Mass<T>> for Area<T> where T: NumLike {
-	type Output = AreaPerMass<T>;
-	fn div(self, rhs: Mass<T>) -> Self::Output {
-		AreaPerMass{m2_per_kg: self.m2 / rhs.kg}
+// Angle / SolidAngle -> InverseAngle
+/// Dividing a Angle by a SolidAngle returns a value of type InverseAngle
+impl<T> core::ops::Div<SolidAngle<T>> for Angle<T> where T: NumLike {
+	type Output = InverseAngle<T>;
+	fn div(self, rhs: SolidAngle<T>) -> Self::Output {
+		InverseAngle{per_rad: self.rad / rhs.sr}
 	}
 }
-/// Dividing a Area by a Mass returns a value of type AreaPerMass
-impl<T> core::ops::Div<Mass<T>> for &Area<T> where T: NumLike {
-	type Output = AreaPerMass<T>;
-	fn div(self, rhs: Mass<T>) -> Self::Output {
-		AreaPerMass{m2_per_kg: self.m2.clone() / rhs.kg}
+/// Dividing a Angle by a SolidAngle returns a value of type InverseAngle
+impl<T> core::ops::Div<SolidAngle<T>> for &Angle<T> where T: NumLike {
+	type Output = InverseAngle<T>;
+	fn div(self, rhs: SolidAngle<T>) -> Self::Output {
+		InverseAngle{per_rad: self.rad.clone() / rhs.sr}
 	}
 }
-/// Dividing a Area by a Mass returns a value of type AreaPerMass
-impl<T> core::ops::Div<&Mass<T>> for Area<T> where T: NumLike {
-	type Output = AreaPerMass<T>;
-	fn div(self, rhs: &Mass<T>) -> Self::Output {
-		AreaPerMass{m2_per_kg: self.m2 / rhs.kg.clone()}
+/// Dividing a Angle by a SolidAngle returns a value of type InverseAngle
+impl<T> core::ops::Div<&SolidAngle<T>> for Angle<T> where T: NumLike {
+	type Output = InverseAngle<T>;
+	fn div(self, rhs: &SolidAngle<T>) -> Self::Output {
+		InverseAngle{per_rad: self.rad / rhs.sr.clone()}
 	}
 }
-/// Dividing a Area by a Mass returns a value of type AreaPerMass
-impl<T> core::ops::Div<&Mass<T>> for &Area<T> where T: NumLike {
-	type Output = AreaPerMass<T>;
-	fn div(self, rhs: &Mass<T>) -> Self::Output {
-		AreaPerMass{m2_per_kg: self.m2.clone() / rhs.kg.clone()}
+/// Dividing a Angle by a SolidAngle returns a value of type InverseAngle
+impl<T> core::ops::Div<&SolidAngle<T>> for &Angle<T> where T: NumLike {
+	type Output = InverseAngle<T>;
+	fn div(self, rhs: &SolidAngle<T>) -> Self::Output {
+		InverseAngle{per_rad: self.rad.clone() / rhs.sr.clone()}
 	}
 }
 
-// Area / AreaPerLumen -> LuminousFlux
-/// Dividing a Area by a AreaPerLumen returns a value of type LuminousFlux
-impl<T> core::ops::Div<AreaPerLumen<T>> for Area<T> where T: NumLike {
-	type Output = LuminousFlux<T>;
-	fn div(self, rhs: AreaPerLumen<T>) -> Self::Output {
-		LuminousFlux{lm: self.m2 / rhs.m2_per_lm}
+// Angle / AngularVelocity -> Time
+/// Dividing a Angle by a AngularVelocity returns a value of type Time
+impl<T> core::ops::Div<AngularVelocity<T>> for Angle<T> where T: NumLike {
+	type Output = Time<T>;
+	fn div(self, rhs: AngularVelocity<T>) -> Self::Output {
+		Time{s: self.rad / rhs.radps}
 	}
 }
-/// Dividing a Area by a AreaPerLumen returns a value of type LuminousFlux
-impl<T> core::ops::Div<AreaPerLumen<T>> for &Area<T> where T: NumLike {
-	type Output = LuminousFlux<T>;
-	fn div(self, rhs: AreaPerLumen<T>) -> Self::Output {
-		LuminousFlux{lm: self.m2.clone() / rhs.m2_per_lm}
+/// Dividing a Angle by a AngularVelocity returns a value of type Time
+impl<T> core::ops::Div<AngularVelocity<T>> for &Angle<T> where T: NumLike {
+	type Output = Time<T>;
+	fn div(self, rhs: AngularVelocity<T>) -> Self::Output {
+		Time{s: self.rad.clone() / rhs.radps}
 	}
 }
-/// Dividing a Area by a AreaPerLumen returns a value of type LuminousFlux
-impl<T> core::ops::Div<&AreaPerLumen<T>> for Area<T> where T: NumLike {
-	type Output = LuminousFlux<T>;
-	fn div(self, rhs: &AreaPerLumen<T>) -> Self::Output {
-		LuminousFlux{lm: self.m2 / rhs.m2_per_lm.clone()}
+/// Dividing a Angle by a AngularVelocity returns a value of type Time
+impl<T> core::ops::Div<&AngularVelocity<T>> for Angle<T> where T: NumLike {
+	type Output = Time<T>;
+	fn div(self, rhs: &AngularVelocity<T>) -> Self::Output {
+		Time{s: self.rad / rhs.radps.clone()}
+	}
+}
+/// Dividing a Angle by a AngularVelocity returns a value of type Time
+impl<T> core::ops::Div<&AngularVelocity<T>> for &Angle<T> where T: NumLike {
+	type Output = Time<T>;
+	fn div(self, rhs: &AngularVelocity<T>) -> Self::Output {
+		Time{s: self.rad.clone() / rhs.radps.clone()}
+	}
+}
+
+// Angle * Frequency -> AngularVelocity
+/// Multiplying a Angle by a Frequency returns a value of type AngularVelocity
+impl<T> core::ops::Mul<Frequency<T>> for Angle<T> where T: NumLike {
+	type Output = AngularVelocity<T>;
+	fn mul(self, rhs: Frequency<T>) -> Self::Output {
+		AngularVelocity{radps: self.rad * rhs.Hz}
+	}
+}
+/// Multiplying a Angle by a Frequency returns a value of type AngularVelocity
+impl<T> core::ops::Mul<Frequency<T>> for &Angle<T> where T: NumLike {
+	type Output = AngularVelocity<T>;
+	fn mul(self, rhs: Frequency<T>) -> Self::Output {
+		AngularVelocity{radps: self.rad.clone() * rhs.Hz}
+	}
+}
+/// Multiplying a Angle by a Frequency returns a value of type AngularVelocity
+impl<T> core::ops::Mul<&Frequency<T>> for Angle<T> where T: NumLike {
+	type Output = AngularVelocity<T>;
+	fn mul(self, rhs: &Frequency<T>) -> Self::Output {
+		AngularVelocity{radps: self.rad * rhs.Hz.clone()}
+	}
+}
+/// Multiplying a Angle by a Frequency returns a value of type AngularVelocity
+impl<T> core::ops::Mul<&Frequency<T>> for &Angle<T> where T: NumLike {
+	type Output = AngularVelocity<T>;
+	fn mul(self, rhs: &Frequency<T>) -> Self::Output {
+		AngularVelocity{radps: self.rad.clone() * rhs.Hz.clone()}
+	}
+}
+
+// Angle * InverseAngularVelocity -> Time
+/// Multiplying a Angle by a InverseAngularVelocity returns a value of type Time
+impl<T> core::ops::Mul<InverseAngularVelocity<T>> for Angle<T> where T: NumLike {
+	type Output = Time<T>;
+	fn mul(self, rhs: InverseAngularVelocity<T>) -> Self::Output {
+		Time{s: self.rad * rhs.s_per_rad}
+	}
+}
+/// Multiplying a Angle by a InverseAngularVelocity returns a value of type Time
+impl<T> core::ops::Mul<InverseAngularVelocity<T>> for &Angle<T> where T: NumLike {
+	type Output = Time<T>;
+	fn mul(self, rhs: InverseAngularVelocity<T>) -> Self::Output {
+		Time{s: self.rad.clone() * rhs.s_per_rad}
+	}
+}
+/// Multiplying a Angle by a InverseAngularVelocity returns a value of type Time
+impl<T> core::ops::Mul<&InverseAngularVelocity<T>> for Angle<T> where T: NumLike {
+	type Output = Time<T>;
+	fn mul(self, rhs: &InverseAngularVelocity<T>) -> Self::Output {
+		Time{s: self.rad * rhs.s_per_rad.clone()}
+	}
+}
+/// Multiplying a Angle by a InverseAngularVelocity returns a value of type Time
+impl<T> core::ops::Mul<&InverseAngularVelocity<T>> for &Angle<T> where T: NumLike {
+	type Output = Time<T>;
+	fn mul(self, rhs: &InverseAngularVelocity<T>) -> Self::Output {
+		Time{s: self.rad.clone() * rhs.s_per_rad.clone()}
+	}
+}
+
+impl<T> Angle<T> where T: NumLike+From<u8> {
+	/// Returns the reciprocal of this Angle value (ie 1 divided by 
+	/// this value) as a value of type InverseAngle
+	pub fn recip(self) -> InverseAngle<T> {
+		InverseAngle{per_rad: T::from(1u8) / self.rad}
+	}
+}
+
+// 1/Angle -> InverseAngle
+/// Dividing a scalar value by a Angle unit value returns a value of type InverseAngle
+impl<T> core::ops::Div<Angle<T>> for f64 where T: NumLike+From<f64> {
+	type Output = InverseAngle<T>;
+	fn div(self, rhs: Angle<T>) -> Self::Output {
+		InverseAngle{per_rad: T::from(self) / rhs.rad}
+	}
+}
+/// Dividing a scalar value by a Angle unit value returns a value of type InverseAngle
+impl<T> core::ops::Div<Angle<T>> for &f64 where T: NumLike+From<f64> {
+	type Output = InverseAngle<T>;
+	fn div(self, rhs: Angle<T>) -> Self::Output {
+		InverseAngle{per_rad: T::from(self.clone()) / rhs.rad}
+	}
+}
+/// Dividing a scalar value by a Angle unit value returns a value of type InverseAngle
+impl<T> core::ops::Div<&Angle<T>> for f64 where T: NumLike+From<f64> {
+	type Output = InverseAngle<T>;
+	fn div(self, rhs: &Angle<T>) -> Self::Output {
+		InverseAngle{per_rad: T::from(self) / rhs.rad.clone()}
+	}
+}
+/// Dividing a scalar value by a Angle unit value returns a value of type InverseAngle
+impl<T> core::ops::Div<&Angle<T>> for &f64 where T: NumLike+From<f64> {
+	type Output = InverseAngle<T>;
+	fn div(self, rhs: &Angle<T>) -> Self::Output {
+		InverseAngle{per_rad: T::from(self.clone()) / rhs.rad.clone()}
+	}
+}
+
+// 1/Angle -> InverseAngle
+/// Dividing a scalar value by a Angle unit value returns a value of type InverseAngle
+impl<T> core::ops::Div<Angle<T>> for f32 where T: NumLike+From<f32> {
+	type Output = InverseAngle<T>;
+	fn div(self, rhs: Angle<T>) -> Self::Output {
+		InverseAngle{per_rad: T::from(self) / rhs.rad}
+	}
+}
+/// Dividing a scalar value by a Angle unit value returns a value of type InverseAngle
+impl<T> core::ops::Div<Angle<T>> for &f32 where T: NumLike+From<f32> {
+	type Output = InverseAngle<T>;
+	fn div(self, rhs: Angle<T>) -> Self::Output {
+		InverseAngle{per_rad: T::from(self.clone()) / rhs.rad}
+	}
+}
+/// Dividing a scalar value by a Angle unit value returns a value of type InverseAngle
+impl<T> core::ops::Div<&Angle<T>> for f32 where T: NumLike+From<f32> {
+	type Output = InverseAngle<T>;
+	fn div(self, rhs: &Angle<T>) -> Self::Output {
+		InverseAngle{per_rad: T::from(self) / rhs.rad.clone()}
+	}
+}
+/// Dividing a scalar value by a Angle unit value returns a value of type InverseAngle
+impl<T> core::ops::Div<&Angle<T>> for &f32 where T: NumLike+From<f32> {
+	type Output = InverseAngle<T>;
+	fn div(self, rhs: &Angle<T>) -> Self::Output {
+		InverseAngle{per_rad: T::from(self.clone()) / rhs.rad.clone()}
+	}
+}
+
+// 1/Angle -> InverseAngle
+/// Dividing a scalar value by a Angle unit value returns a value of type InverseAngle
+impl<T> core::ops::Div<Angle<T>> for i64 where T: NumLike+From<i64> {
+	type Output = InverseAngle<T>;
+	fn div(self, rhs: Angle<T>) -> Self::Output {
+		InverseAngle{per_rad: T::from(self) / rhs.rad}
+	}
+}
+/// Dividing a scalar value by a Angle unit value returns a value of type InverseAngle
+impl<T> core::ops::Div<Angle<T>> for &i64 where T: NumLike+From<i64> {
+	type Output = InverseAngle<T>;
+	fn div(self, rhs: Angle<T>) -> Self::Output {
+		InverseAngle{per_rad: T::from(self.clone()) / rhs.rad}
+	}
+}
+/// Dividing a scalar value by a Angle unit value returns a value of type InverseAngle
+impl<T> core::ops::Div<&Angle<T>> for i64 where T: NumLike+From<i64> {
+	type Output = InverseAngle<T>;
+	fn div(self, rhs: &Angle<T>) -> Self::Output {
+		InverseAngle{per_rad: T::from(self) / rhs.rad.clone()}
+	}
+}
+/// Dividing a scalar value by a Angle unit value returns a value of type InverseAngle
+impl<T> core::ops::Div<&Angle<T>> for &i64 where T: NumLike+From<i64> {
+	type Output = InverseAngle<T>;
+	fn div(self, rhs: &Angle<T>) -> Self::Output {
+		InverseAngle{per_rad: T::from(self.clone()) / rhs.rad.clone()}
+	}
+}
+
+// 1/Angle -> InverseAngle
+/// Dividing a scalar value by a Angle unit value returns a value of type InverseAngle
+impl<T> core::ops::Div<Angle<T>> for i32 where T: NumLike+From<i32> {
+	type Output = InverseAngle<T>;
+	fn div(self, rhs: Angle<T>) -> Self::Output {
+		InverseAngle{per_rad: T::from(self) / rhs.rad}
+	}
+}
+/// Dividing a scalar value by a Angle unit value returns a value of type InverseAngle
+impl<T> core::ops::Div<Angle<T>> for &i32 where T: NumLike+From<i32> {
+	type Output = InverseAngle<T>;
+	fn div(self, rhs: Angle<T>) -> Self::Output {
+		InverseAngle{per_rad: T::from(self.clone()) / rhs.rad}
+	}
+}
+/// Dividing a scalar value by a Angle unit value returns a value of type InverseAngle
+impl<T> core::ops::Div<&Angle<T>> for i32 where T: NumLike+From<i32> {
+	type Output = InverseAngle<T>;
+	fn div(self, rhs: &Angle<T>) -> Self::Output {
+		InverseAngle{per_rad: T::from(self) / rhs.rad.clone()}
+	}
+}
+/// Dividing a scalar value by a Angle unit value returns a value of type InverseAngle
+impl<T> core::ops::Div<&Angle<T>> for &i32 where T: NumLike+From<i32> {
+	type Output = InverseAngle<T>;
+	fn div(self, rhs: &Angle<T>) -> Self::Output {
+		InverseAngle{per_rad: T::from(self.clone()) / rhs.rad.clone()}
+	}
+}
+
+// 1/Angle -> InverseAngle
+/// Dividing a scalar value by a Angle unit value returns a value of type InverseAngle
+#[cfg(feature="num-bigfloat")]
+impl<T> core::ops::Div<Angle<T>> for num_bigfloat::BigFloat where T: NumLike+From<num_bigfloat::BigFloat> {
+	type Output = InverseAngle<T>;
+	fn div(self, rhs: Angle<T>) -> Self::Output {
+		InverseAngle{per_rad: T::from(self) / rhs.rad}
+	}
+}
+/// Dividing a scalar value by a Angle unit value returns a value of type InverseAngle
+#[cfg(feature="num-bigfloat")]
+impl<T> core::ops::Div<Angle<T>> for &num_bigfloat::BigFloat where T: NumLike+From<num_bigfloat::BigFloat> {
+	type Output = InverseAngle<T>;
+	fn div(self, rhs: Angle<T>) -> Self::Output {
+		InverseAngle{per_rad: T::from(self.clone()) / rhs.rad}
+	}
+}
+/// Dividing a scalar value by a Angle unit value returns a value of type InverseAngle
+#[cfg(feature="num-bigfloat")]
+impl<T> core::ops::Div<&Angle<T>> for num_bigfloat::BigFloat where T: NumLike+From<num_bigfloat::BigFloat> {
+	type Output = InverseAngle<T>;
+	fn div(self, rhs: &Angle<T>) -> Self::Output {
+		InverseAngle{per_rad: T::from(self) / rhs.rad.clone()}
+	}
+}
+/// Dividing a scalar value by a Angle unit value returns a value of type InverseAngle
+#[cfg(feature="num-bigfloat")]
+impl<T> core::ops::Div<&Angle<T>> for &num_bigfloat::BigFloat where T: NumLike+From<num_bigfloat::BigFloat> {
+	type Output = InverseAngle<T>;
+	fn div(self, rhs: &Angle<T>) -> Self::Output {
+		InverseAngle{per_rad: T::from(self.clone()) / rhs.rad.clone()}
+	}
+}
+
+// 1/Angle -> InverseAngle
+/// Dividing a scalar value by a Angle unit value returns a value of type InverseAngle
+#[cfg(feature="num-complex")]
+impl<T> core::ops::Div<Angle<T>> for num_complex::Complex32 where T: NumLike+From<num_complex::Complex32> {
+	type Output = InverseAngle<T>;
+	fn div(self, rhs: Angle<T>) -> Self::Output {
+		InverseAngle{per_rad: T::from(self) / rhs.rad}
+	}
+}
+/// Dividing a scalar value by a Angle unit value returns a value of type InverseAngle
+#[cfg(feature="num-complex")]
+impl<T> core::ops::Div<Angle<T>> for &num_complex::Complex32 where T: NumLike+From<num_complex::Complex32> {
+	type Output = InverseAngle<T>;
+	fn div(self, rhs: Angle<T>) -> Self::Output {
+		InverseAngle{per_rad: T::from(self.clone()) / rhs.rad}
+	}
+}
+/// Dividing a scalar value by a Angle unit value returns a value of type InverseAngle
+#[cfg(feature="num-complex")]
+impl<T> core::ops::Div<&Angle<T>> for num_complex::Complex32 where T: NumLike+From<num_complex::Complex32> {
+	type Output = InverseAngle<T>;
+	fn div(self, rhs: &Angle<T>) -> Self::Output {
+		InverseAngle{per_rad: T::from(self) / rhs.rad.clone()}
+	}
+}
+/// Dividing a scalar value by a Angle unit value returns a value of type InverseAngle
+#[cfg(feature="num-complex")]
+impl<T> core::ops::Div<&Angle<T>> for &num_complex::Complex32 where T: NumLike+From<num_complex::Complex32> {
+	type Output = InverseAngle<T>;
+	fn div(self, rhs: &Angle<T>) -> Self::Output {
+		InverseAngle{per_rad: T::from(self.clone()) / rhs.rad.clone()}
+	}
+}
+
+// 1/Angle -> InverseAngle
+/// Dividing a scalar value by a Angle unit value returns a value of type InverseAngle
+#[cfg(feature="num-complex")]
+impl<T> core::ops::Div<Angle<T>> for num_complex::Complex64 where T: NumLike+From<num_complex::Complex64> {
+	type Output = InverseAngle<T>;
+	fn div(self, rhs: Angle<T>) -> Self::Output {
+		InverseAngle{per_rad: T::from(self) / rhs.rad}
+	}
+}
+/// Dividing a scalar value by a Angle unit value returns a value of type InverseAngle
+#[cfg(feature="num-complex")]
+impl<T> core::ops::Div<Angle<T>> for &num_complex::Complex64 where T: NumLike+From<num_complex::Complex64> {
+	type Output = InverseAngle<T>;
+	fn div(self, rhs: Angle<T>) -> Self::Output {
+		InverseAngle{per_rad: T::from(self.clone()) / rhs.rad}
+	}
+}
+/// Dividing a scalar value by a Angle unit value returns a value of type InverseAngle
+#[cfg(feature="num-complex")]
+impl<T> core::ops::Div<&Angle<T>> for num_complex::Complex64 where T: NumLike+From<num_complex::Complex64> {
+	type Output = InverseAngle<T>;
+	fn div(self, rhs: &Angle<T>) -> Self::Output {
+		InverseAngle{per_rad: T::from(self) / rhs.rad.clone()}
+	}
+}
+/// Dividing a scalar value by a Angle unit value returns a value of type InverseAngle
+#[cfg(feature="num-complex")]
+impl<T> core::ops::Div<&Angle<T>> for &num_complex::Complex64 where T: NumLike+From<num_complex::Complex64> {
+	type Output = InverseAngle<T>;
+	fn div(self, rhs: &Angle<T>) -> Self::Output {
+		InverseAngle{per_rad: T::from(self.clone()) / rhs.rad.clone()}
+	}
+}
+
+// 1/Angle -> InverseAngle
+/// Dividing a scalar value by a Angle unit value returns a value of type InverseAngle
+#[cfg(feature="num-rational")]
+impl<T> core::ops::Div<Angle<T>> for num_rational::Rational64 where T: NumLike+From<num_rational::Rational64> {
+	type Output = InverseAngle<T>;
+	fn div(self, rhs: Angle<T>) -> Self::Output {
+		InverseAngle{per_rad: T::from(self) / rhs.rad}
+	}
+}
+/// Dividing a scalar value by a Angle unit value returns a value of type InverseAngle
+#[cfg(feature="num-rational")]
+impl<T> core::ops::Div<Angle<T>> for &num_rational::Rational64 where T: NumLike+From<num_rational::Rational64> {
+	type Output = InverseAngle<T>;
+	fn div(self, rhs: Angle<T>) -> Self::Output {
+		InverseAngle{per_rad: T::from(self.clone()) / rhs.rad}
+	}
+}
+/// Dividing a scalar value by a Angle unit value returns a value of type InverseAngle
+#[cfg(feature="num-rational")]
+impl<T> core::ops::Div<&Angle<T>> for num_rational::Rational64 where T: NumLike+From<num_rational::Rational64> {
+	type Output = InverseAngle<T>;
+	fn div(self, rhs: &Angle<T>) -> Self::Output {
+		InverseAngle{per_rad: T::from(self) / rhs.rad.clone()}
+	}
+}
+/// Dividing a scalar value by a Angle unit value returns a value of type InverseAngle
+#[cfg(feature="num-rational")]
+impl<T> core::ops::Div<&Angle<T>> for &num_rational::Rational64 where T: NumLike+From<num_rational::Rational64> {
+	type Output = InverseAngle<T>;
+	fn div(self, rhs: &Angle<T>) -> Self::Output {
+		InverseAngle{per_rad: T::from(self.clone()) / rhs.rad.clone()}
+	}
+}
+
+// 1/Angle -> InverseAngle
+/// Dividing a scalar value by a Angle unit value returns a value of type InverseAngle
+#[cfg(feature="num-rational")]
+impl<T> core::ops::Div<Angle<T>> for num_rational::BigRational where T: NumLike+From<num_rational::BigRational> {
+	type Output = InverseAngle<T>;
+	fn div(self, rhs: Angle<T>) -> Self::Output {
+		InverseAngle{per_rad: T::from(self) / rhs.rad}
+	}
+}
+/// Dividing a scalar value by a Angle unit value returns a value of type InverseAngle
+#[cfg(feature="num-rational")]
+impl<T> core::ops::Div<Angle<T>> for &num_rational::BigRational where T: NumLike+From<num_rational::BigRational> {
+	type Output = InverseAngle<T>;
+	fn div(self, rhs: Angle<T>) -> Self::Output {
+		InverseAngle{per_rad: T::from(self.clone()) / rhs.rad}
+	}
+}
+/// Dividing a scalar value by a Angle unit value returns a value of type InverseAngle
+#[cfg(feature="num-rational")]
+impl<T> core::ops::Div<&Angle<T>> for num_rational::BigRational where T: NumLike+From<num_rational::BigRational> {
+	type Output = InverseAngle<T>;
+	fn div(self, rhs: &Angle<T>) -> Self::Output {
+		InverseAngle{per_rad: T::from(self) / rhs.rad.clone()}
+	}
+}
+/// Dividing a scalar value by a Angle unit value returns a value of type InverseAngle
+#[cfg(feature="num-rational")]
+impl<T> core::ops::Div<&Angle<T>> for &num_rational::BigRational where T: NumLike+From<num_rational::BigRational> {
+	type Output = InverseAngle<T>;
+	fn div(self, rhs: &Angle<T>) -> Self::Output {
+		InverseAngle{per_rad: T::from(self.clone()) / rhs.rad.clone()}
+	}
+}
+
+/// The area unit type, defined as square meters in SI units
+#[derive(UnitStruct, Debug, Clone, Default)]
+#[si(no_display)]
+#[cfg_attr(all(feature="serde", not(feature="serde-unit-tag")), derive(Serialize, Deserialize))]
+#[repr(transparent)]
+pub struct Area<T: NumLike>{
+	/// The value of this Area in square meters
+	pub m2: T
+}
+
+impl<T> Area<T> where T: NumLike {
+
+	/// Returns the standard unit name of area: "square meters"
+	pub fn unit_name() -> &'static str { "square meters" }
+	
+	/// Returns the abbreviated name or symbol of area: "m²" for square meters
+	pub fn unit_symbol() -> &'static str { "m²" }
+
+	/// Returns the exponents of the SI base units of area (m^2)
+	pub const fn dimension() -> super::Dimension { super::Dimension{length: 2, mass: 0, time: 0, current: 0, temperature: 0, amount: 0, luminosity: 0} }
+
+	/// Returns a wrapper that formats this area value with the full unit name instead of 
+	/// the unit symbol, eg "5 square meters" (the unit name is not singularized for values of 1)
+	pub fn display_named(&self) -> super::NamedDisplay<'_, T> {
+		super::NamedDisplay::new(&self.m2, Self::unit_name())
+	}
+
+	/// Returns a new area value from the given number of square meters (same as 
+	/// `from_m2(...)`), for any number-like type. This is useful in generic code 
+	/// that works with several unit types
+	///
+	/// # Arguments
+	/// * `value` - Any number-like type, representing a quantity of square meters
+	pub const fn new(value: T) -> Self { Area{m2: value} }
+
+	/// Returns a new area value from the given number of square meters. Unlike
+	/// `from_m2(...)`, this is a `const fn`, so it can be used to declare `const` and `static` values
+	///
+	/// # Arguments
+	/// * `m2` - Any number-like type, representing a quantity of square meters
+	pub const fn from_m2_const(m2: T) -> Self { Area{m2} }
+
+	/// Returns a area value with a different number type, by applying the 
+	/// given function to the value in square meters (eg `.map(|x| x as f32)` to convert 
+	/// from `f64` to `f32`)
+	///
+	/// # Arguments
+	/// * `f` - Function that converts the number of square meters to the new number type
+	pub fn map<U, F>(self, f: F) -> Area<U> where U: NumLike, F: FnOnce(T) -> U {
+		Area{m2: f(self.m2)}
+	}
+
+	/// Returns a area value with a different number type, converted 
+	/// losslessly with the `From` trait (eg from `f32` to `f64`)
+	pub fn cast<U>(self) -> Area<U> where U: NumLike+From<T> {
+		Area{m2: U::from(self.m2)}
+	}
+
+	/// Returns a reference to the value of this area in square meters
+	pub fn as_raw(&self) -> &T {
+		&self.m2
+	}
+
+	/// Returns a mutable reference to the value of this area in square meters, 
+	/// eg for passing to numeric libraries that modify values in place. The number 
+	/// is always in square meters, so changing it directly does not change the unit
+	pub fn raw_mut(&mut self) -> &mut T {
+		&mut self.m2
+	}
+
+	/// Returns a slice of numbers in square meters as a slice of area values, 
+	/// without copying
+	///
+	/// # Arguments
+	/// * `values` - Slice of numbers, representing quantities of square meters
+	pub fn wrap_slice(values: &[T]) -> &[Self] {
+		// SAFETY: Area<T> is #[repr(transparent)], so it has the same memory layout as T
+		unsafe { core::slice::from_raw_parts(values.as_ptr() as *const Self, values.len()) }
+	}
+
+	/// Returns a mutable slice of numbers in square meters as a mutable slice of 
+	/// area values, without copying
+	///
+	/// # Arguments
+	/// * `values` - Slice of numbers, representing quantities of square meters
+	pub fn wrap_slice_mut(values: &mut [T]) -> &mut [Self] {
+		// SAFETY: Area<T> is #[repr(transparent)], so it has the same memory layout as T
+		unsafe { core::slice::from_raw_parts_mut(values.as_mut_ptr() as *mut Self, values.len()) }
+	}
+
+	/// Returns the ratio of this area value to another area value 
+	/// (same as `self / other`). Dividing a unit value by another value of the same 
+	/// unit type cancels the units, so the ratio is a dimensionless number
+	///
+	/// # Arguments
+	/// * `other` - The area value to divide by
+	pub fn ratio(self, other: Self) -> T {
+		self.m2 / other.m2
+	}
+
+	/// Returns the ratio of this area value to another area value 
+	/// as a `Dimensionless` value, instead of a bare number like `ratio(...)` does
+	///
+	/// # Arguments
+	/// * `other` - The area value to divide by
+	pub fn dimensionless_ratio(self, other: Self) -> super::base::Dimensionless<T> {
+		super::base::Dimensionless{value: self.m2 / other.m2}
+	}
+
+	/// Returns this area value multiplied by a dimensionless factor 
+	/// (same as `self * factor`)
+	///
+	/// # Arguments
+	/// * `factor` - The number to multiply by
+	pub fn scale(self, factor: T) -> Self {
+		Area{m2: self.m2 * factor}
+	}
+	
+	/// Returns a new area value from the given number of square meters
+	///
+	/// # Arguments
+	/// * `m2` - Any number-like type, representing a quantity of square meters
+	pub fn from_m2(m2: T) -> Self { Area{m2: m2} }
+	
+	/// Returns a copy of this area value in square meters
+	pub fn to_m2(&self) -> T { self.m2.clone() }
+
+	/// Returns a new area value from the given number of square meters
+	///
+	/// # Arguments
+	/// * `square_meters` - Any number-like type, representing a quantity of square meters
+	pub fn from_square_meters(square_meters: T) -> Self { Area{m2: square_meters} }
+	
+	/// Returns a copy of this area value in square meters
+	pub fn to_square_meters(&self) -> T { self.m2.clone() }
+
+}
+
+/// Formats area values as a number followed by its unit symbol, eg "1500 m²"
+/// (complex numbers are wrapped in parentheses, eg "(1+2i) m²").
+/// The alternate flag (`{:#}`) scales the number by the nearest SI prefix where the unit symbol allows it
+impl<T> fmt::Display for Area<T> where T: NumLike {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		if f.alternate() {
+			return super::fmt_with_si_prefix(f, &self.m2, Self::unit_symbol(), false);
+		}
+		super::fmt_unit_value(f, &self.m2, Self::unit_symbol())
+	}
+}
+
+/// Formats area values in exponential notation followed by the unit symbol, eg "1.5e3 m²"
+impl<T> fmt::LowerExp for Area<T> where T: NumLike+fmt::LowerExp {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		fmt::LowerExp::fmt(&self.m2, f)?;
+		write!(f, " {}", Self::unit_symbol())
+	}
+}
+
+/// Formats area values in exponential notation followed by the unit symbol, eg "1.5E3 m²"
+impl<T> fmt::UpperExp for Area<T> where T: NumLike+fmt::UpperExp {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		fmt::UpperExp::fmt(&self.m2, f)?;
+		write!(f, " {}", Self::unit_symbol())
+	}
+}
+
+impl<T> super::SiUnit for Area<T> where T: NumLike {
+	type Number = T;
+	fn unit_name(&self) -> &'static str { Self::unit_name() }
+	fn unit_symbol(&self) -> &'static str { Self::unit_symbol() }
+	fn raw(&self) -> &T { &self.m2 }
+	fn dimension(&self) -> super::Dimension { Self::dimension() }
+	fn from_raw(value: T) -> Self { Area{m2: value} }
+	fn into_raw(self) -> T { self.m2 }
+}
+
+#[cfg(feature="alloc")]
+impl<T> Area<T> where T: NumLike {
+	/// Returns this area value in the canonical text format "<value> <symbol>", eg "5 m²".
+	/// Unlike `Display`, this format never changes with precision or alternate flags, and it is the
+	/// input format expected by `from_str(...)`, so `x.canonical_string().parse()` returns the same value
+	pub fn canonical_string(&self) -> alloc::string::String {
+		alloc::format!("{} {}", self.m2, Self::unit_symbol())
+	}
+
+	/// Returns a `Vec` of area values copied from the given slice of numbers
+	///
+	/// # Arguments
+	/// * `values` - Slice of numbers, representing quantities of square meters
+	pub fn from_m2_slice(values: &[T]) -> alloc::vec::Vec<Self> {
+		values.iter().map(|x| Area{m2: x.clone()}).collect()
+	}
+}
+
+/// Parses a area value from a number followed by its unit symbol, eg "5 m²"
+impl<T> core::str::FromStr for Area<T> where T: NumLike+core::str::FromStr {
+	type Err = SiParseError;
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		Ok(Area{m2: super::parse_unit_value(s, Self::unit_symbol())?})
+	}
+}
+
+/// Parses a area value from a number followed by its unit symbol, eg "5 m²" 
+/// (same as `str::parse()`)
+impl<T> core::convert::TryFrom<&str> for Area<T> where T: NumLike+core::str::FromStr {
+	type Error = SiParseError;
+	fn try_from(s: &str) -> Result<Self, Self::Error> {
+		<Self as core::str::FromStr>::from_str(s)
+	}
+}
+
+#[cfg(feature="num-traits")]
+impl<T> Area<T> where T: NumLike+num_traits::Euclid {
+
+	/// Returns the Euclidean remainder of dividing this area value by another, 
+	/// which is never negative (requires the `num-traits` feature)
+	///
+	/// # Arguments
+	/// * `rhs` - The area value to divide by
+	pub fn rem_euclid(self, rhs: Self) -> Self {
+		Area{m2: self.m2.rem_euclid(&rhs.m2)}
+	}
+
+	/// Returns the Euclidean quotient of dividing this area value by another, 
+	/// ie the whole number of times that `rhs` fits into this value, rounded such that 
+	/// `rem_euclid(...)` is never negative (requires the `num-traits` feature)
+	///
+	/// # Arguments
+	/// * `rhs` - The area value to divide by
+	pub fn div_euclid(self, rhs: Self) -> T {
+		self.m2.div_euclid(&rhs.m2)
+	}
+}
+
+/// Summing area values returns their total as a area value (or zero if there are no values)
+impl<T> core::iter::Sum<Area<T>> for Area<T> where T: NumLike+core::iter::Sum {
+	fn sum<I: Iterator<Item=Area<T>>>(iter: I) -> Self {
+		Area{m2: iter.map(|x| x.m2).sum()}
+	}
+}
+/// Summing area values returns their total as a area value (or zero if there are no values)
+impl<'a, T> core::iter::Sum<&'a Area<T>> for Area<T> where T: NumLike+core::iter::Sum+'a {
+	fn sum<I: Iterator<Item=&'a Area<T>>>(iter: I) -> Self {
+		Area{m2: iter.map(|x| x.m2.clone()).sum()}
+	}
+}
+
+impl<T> Area<T> where T: NumLike+From<f64> {
+
+	/// Returns the weighted average of the given area values, where each 
+	/// value is paired with its weight (weights do not need to add up to 1). If the 
+	/// total weight is zero (including when `values` is empty), then zero is returned
+	///
+	/// # Arguments
+	/// * `values` - Slice of (area value, weight) pairs
+	pub fn weighted_mean(values: &[(Self, f64)]) -> Self {
+		let total_weight: f64 = values.iter().map(|(_, weight)| *weight).sum();
+		if total_weight == 0.0 {
+			return Area{m2: T::from(0.0)};
+		}
+		let total = values.iter().fold(T::from(0.0),
+			|total, (value, weight)| total + value.m2.clone() * T::from(*weight));
+		Area{m2: total / T::from(total_weight)}
+	}
+}
+
+impl<T> Area<T> where T: NumLike+PartialEq+From<u8> {
+
+	/// Divides this area value by another unit value (eg `distance.checked_div(time)` 
+	/// returns a velocity), returning `None` if the divisor is zero instead of producing 
+	/// infinity or NaN (or panicking, for integer number types). This works for every unit 
+	/// type that this area value can be divided by with the `/` operator
+	///
+	/// # Arguments
+	/// * `rhs` - The unit value to divide by
+	pub fn checked_div<R>(self, rhs: R) -> Option<<Self as core::ops::Div<R>>::Output> 
+		where Self: core::ops::Div<R>, R: super::SiUnit<Number=T>
+	{
+		if *rhs.raw() == T::from(0u8) {
+			return None;
+		}
+		Some(self / rhs)
+	}
+}
+
+/// Multiplying a area value by a dimensionless value returns a area value
+impl<T> core::ops::Mul<super::base::Dimensionless<T>> for Area<T> where T: NumLike {
+	type Output = Area<T>;
+	fn mul(self, rhs: super::base::Dimensionless<T>) -> Self::Output {
+		Area{m2: self.m2 * rhs.value}
+	}
+}
+/// Multiplying a dimensionless value by a area value returns a area value
+impl<T> core::ops::Mul<Area<T>> for super::base::Dimensionless<T> where T: NumLike {
+	type Output = Area<T>;
+	fn mul(self, rhs: Area<T>) -> Self::Output {
+		Area{m2: self.value * rhs.m2}
+	}
+}
+/// Dividing a area value by a dimensionless value returns a area value
+impl<T> core::ops::Div<super::base::Dimensionless<T>> for Area<T> where T: NumLike {
+	type Output = Area<T>;
+	fn div(self, rhs: super::base::Dimensionless<T>) -> Self::Output {
+		Area{m2: self.m2 / rhs.value}
+	}
+}
+
+/// The remainder of dividing a area value by another area value is a area value
+impl<T> core::ops::Rem<Area<T>> for Area<T> where T: NumLike+core::ops::Rem<Output=T> {
+	type Output = Area<T>;
+	fn rem(self, rhs: Area<T>) -> Self::Output {
+		Area{m2: self.m2 % rhs.m2}
+	}
+}
+/// The remainder of dividing a area value by another area value is a area value
+impl<T> core::ops::Rem<&Area<T>> for Area<T> where T: NumLike+core::ops::Rem<Output=T> {
+	type Output = Area<T>;
+	fn rem(self, rhs: &Area<T>) -> Self::Output {
+		Area{m2: self.m2 % rhs.m2.clone()}
+	}
+}
+/// The remainder of dividing a area value by another area value is a area value
+impl<T> core::ops::Rem<Area<T>> for &Area<T> where T: NumLike+core::ops::Rem<Output=T> {
+	type Output = Area<T>;
+	fn rem(self, rhs: Area<T>) -> Self::Output {
+		Area{m2: self.m2.clone() % rhs.m2}
+	}
+}
+/// The remainder of dividing a area value by another area value is a area value
+impl<T> core::ops::Rem<&Area<T>> for &Area<T> where T: NumLike+core::ops::Rem<Output=T> {
+	type Output = Area<T>;
+	fn rem(self, rhs: &Area<T>) -> Self::Output {
+		Area{m2: self.m2.clone() % rhs.m2.clone()}
+	}
+}
+
+/// Serializes a area value together with its unit symbol, eg 
+/// `{"value": 1.5, "unit": "m²"}` (requires the `serde-unit-tag` feature)
+#[cfg(feature="serde-unit-tag")]
+impl<T> serde::Serialize for Area<T> where T: NumLike+serde::Serialize {
+	fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+		super::serialize_with_unit_tag(&self.m2, Self::unit_symbol(), serializer)
+	}
+}
+/// Deserializes a area value from a value and unit symbol, eg 
+/// `{"value": 1.5, "unit": "m²"}`, returning an error if the unit symbol 
+/// does not match (requires the `serde-unit-tag` feature)
+#[cfg(feature="serde-unit-tag")]
+impl<'de, T> serde::Deserialize<'de> for Area<T> where T: NumLike+serde::Deserialize<'de> {
+	fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+		Ok(Area{m2: super::deserialize_with_unit_tag(Self::unit_symbol(), deserializer)?})
+	}
+}
+
+/// The additive identity of area values is zero square meters (requires the `num-traits` feature)
+#[cfg(feature="num-traits")]
+impl<T> num_traits::Zero for Area<T> where T: NumLike+num_traits::Zero {
+	fn zero() -> Self { Area{m2: T::zero()} }
+	fn is_zero(&self) -> bool { self.m2.is_zero() }
+}
+
+#[cfg(feature="num-traits")]
+impl<T> Area<T> where T: NumLike+num_traits::Signed {
+
+	/// Returns the sign of this area value as a dimensionless number: 1 if it is 
+	/// positive, -1 if it is negative, and 0 if it is zero for integer number types 
+	/// (for floating-point number types, +0.0 returns 1.0, -0.0 returns -1.0, and NaN returns 
+	/// NaN) (requires the `num-traits` feature)
+	pub fn signum(self) -> T {
+		self.m2.signum()
+	}
+}
+
+impl<T> Area<T> where T: NumLike+PartialOrd {
+
+	/// Returns the absolute value of this area value, ie its magnitude without a minus sign
+	/// (for floating-point number types, -0.0 returns +0.0)
+	pub fn abs(self) -> Self {
+		let negated = -self.m2.clone();
+		if self.m2 < negated {
+			Area{m2: negated}
+		} else if self.m2 == negated {
+			// zero, which is its own negation (adding +0.0 turns -0.0 into +0.0)
+			Area{m2: negated + self.m2}
+		} else {
+			self
+		}
+	}
+
+	/// Returns the lesser of this area value and the given area value
+	///
+	/// # Arguments
+	/// * `other` - The area value to compare with this one
+	pub fn min(self, other: Self) -> Self {
+		if other.m2 < self.m2 { other } else { self }
+	}
+
+	/// Returns the greater of this area value and the given area value
+	///
+	/// # Arguments
+	/// * `other` - The area value to compare with this one
+	pub fn max(self, other: Self) -> Self {
+		if other.m2 > self.m2 { other } else { self }
+	}
+
+	/// Returns the least of the given area values, or `None` if there are none. 
+	/// Unlike `Iterator::min()`, this does not require `Ord`, so it works for 
+	/// floating-point numbers. NaN values are skipped (so the result is `None` if all 
+	/// values are NaN), and the first of several equal values is returned
+	///
+	/// # Arguments
+	/// * `values` - Any iterator or collection of area values
+	pub fn min_of<I>(values: I) -> Option<Self> where I: IntoIterator<Item=Self> {
+		values.into_iter().filter(|x| x.m2.partial_cmp(&x.m2).is_some()).reduce(Self::min)
+	}
+
+	/// Returns the greatest of the given area values, or `None` if there are none. 
+	/// Unlike `Iterator::max()`, this does not require `Ord`, so it works for 
+	/// floating-point numbers. NaN values are skipped (so the result is `None` if all 
+	/// values are NaN), and the first of several equal values is returned
+	///
+	/// # Arguments
+	/// * `values` - Any iterator or collection of area values
+	pub fn max_of<I>(values: I) -> Option<Self> where I: IntoIterator<Item=Self> {
+		values.into_iter().filter(|x| x.m2.partial_cmp(&x.m2).is_some()).reduce(Self::max)
+	}
+
+	/// Returns this area value restricted to the range from `lo` to `hi`
+	///
+	/// # Arguments
+	/// * `lo` - The minimum area value
+	/// * `hi` - The maximum area value
+	///
+	/// # Panics
+	/// Panics if `lo` is greater than `hi` (or if either of them is NaN)
+	pub fn clamp(self, lo: Self, hi: Self) -> Self {
+		assert!(lo.m2 <= hi.m2, "lo must be less than or equal to hi");
+		if self.m2 < lo.m2 { lo } else if self.m2 > hi.m2 { hi } else { self }
+	}
+
+	/// Returns this area value with its magnitude capped at `max`, keeping its 
+	/// sign (eg for capping a speed), or this value unchanged if its magnitude does not 
+	/// exceed `max`. For complex-valued area, use `clamp_norm(...)` instead, 
+	/// which caps the magnitude while keeping the phase
+	///
+	/// # Arguments
+	/// * `max` - The maximum magnitude (should not be negative)
+	pub fn clamp_magnitude(self, max: Self) -> Self {
+		let negated_max = -max.m2.clone();
+		if self.m2 > max.m2 { 
+			max 
+		} else if self.m2 < negated_max { 
+			Area{m2: negated_max} 
+		} else { 
+			self 
+		}
+	}
+
+	/// Returns true if this area value differs from the given area 
+	/// value by no more than the given absolute tolerance (which is also a 
+	/// area value, so the comparison is dimensionally safe). NaN is never 
+	/// equal to anything. See also the `assert_unit_eq!(...)` macro.
+	///
+	/// # Arguments
+	/// * `other` - The area value to compare with this one
+	/// * `tolerance` - The largest allowed difference between the two values
+	pub fn eq_within(self, other: Self, tolerance: Self) -> bool {
+		let diff = self.m2 - other.m2;
+		diff <= tolerance.m2 && -diff <= tolerance.m2
+	}
+}
+
+#[cfg(feature="num-complex")]
+impl Area<num_complex::Complex32> {
+	/// Returns the magnitude (aka norm or absolute value) of this complex area 
+	/// value as a real-valued area (requires the `num-complex` feature)
+	pub fn magnitude(self) -> Area<f32> {
+		Area{m2: self.m2.norm()}
+	}
+
+	/// Returns this complex area value scaled down so that its magnitude is 
+	/// at most `max` (keeping its phase), or this value unchanged if its magnitude does 
+	/// not exceed `max`. This is the complex equivalent of `clamp_magnitude(...)` (requires 
+	/// the `num-complex` feature)
+	///
+	/// # Arguments
+	/// * `max` - The maximum magnitude (should not be negative)
+	pub fn clamp_norm(self, max: Area<f32>) -> Self {
+		let norm = self.m2.norm();
+		if norm > max.m2 {
+			Area{m2: self.m2 * (max.m2 / norm)}
+		} else {
+			self
+		}
+	}
+}
+
+#[cfg(feature="num-complex")]
+impl Area<num_complex::Complex64> {
+	/// Returns the magnitude (aka norm or absolute value) of this complex area 
+	/// value as a real-valued area (requires the `num-complex` feature)
+	pub fn magnitude(self) -> Area<f64> {
+		Area{m2: self.m2.norm()}
+	}
+
+	/// Returns this complex area value scaled down so that its magnitude is 
+	/// at most `max` (keeping its phase), or this value unchanged if its magnitude does 
+	/// not exceed `max`. This is the complex equivalent of `clamp_magnitude(...)` (requires 
+	/// the `num-complex` feature)
+	///
+	/// # Arguments
+	/// * `max` - The maximum magnitude (should not be negative)
+	pub fn clamp_norm(self, max: Area<f64>) -> Self {
+		let norm = self.m2.norm();
+		if norm > max.m2 {
+			Area{m2: self.m2 * (max.m2 / norm)}
+		} else {
+			self
+		}
+	}
+}
+
+/// Rounding and other floating-point functions (requires the `num-traits` feature, 
+/// and either the `std` or `libm` feature)
+#[cfg(all(feature="num-traits", any(feature="std", feature="libm")))]
+impl<T> Area<T> where T: NumLike+num_traits::Float {
+
+	/// Returns this area value rounded to the nearest whole number of square meters 
+	/// (half-way cases are rounded away from zero)
+	pub fn round(self) -> Self {
+		Area{m2: self.m2.round()}
+	}
+
+	/// Returns this area value rounded down to a whole number of square meters
+	pub fn floor(self) -> Self {
+		Area{m2: self.m2.floor()}
+	}
+
+	/// Returns this area value rounded up to a whole number of square meters
+	pub fn ceil(self) -> Self {
+		Area{m2: self.m2.ceil()}
+	}
+
+	/// Returns this area value rounded towards zero to a whole number of square meters
+	pub fn trunc(self) -> Self {
+		Area{m2: self.m2.trunc()}
+	}
+
+	/// Returns this area value rounded to the nearest multiple of the given step 
+	/// (half-way cases are rounded away from zero)
+	///
+	/// # Arguments
+	/// * `step` - The area value to round to a multiple of
+	pub fn round_to(self, step: Self) -> Self {
+		Area{m2: (self.m2 / step.m2).round() * step.m2}
+	}
+
+	/// Fused multiply-add: returns `self * a + b` computed with only one rounding 
+	/// error, which is more accurate (and on some hardware faster) than a separate 
+	/// multiplication and addition
+	///
+	/// # Arguments
+	/// * `a` - The scalar value to multiply this area value by
+	/// * `b` - The area value to add to the product
+	pub fn mul_add(self, a: T, b: Self) -> Self {
+		Area{m2: self.m2.mul_add(a, b.m2)}
+	}
+
+	/// Returns true if this area value is neither infinite nor NaN
+	pub fn is_finite(&self) -> bool {
+		self.m2.is_finite()
+	}
+
+	/// Returns true if this area value is positive or negative infinity
+	pub fn is_infinite(&self) -> bool {
+		self.m2.is_infinite()
+	}
+
+	/// Returns true if this area value is NaN (not a number)
+	pub fn is_nan(&self) -> bool {
+		self.m2.is_nan()
+	}
+
+	/// Returns true if this area value has a positive sign, including +0.0 
+	/// and positive infinity
+	pub fn is_sign_positive(&self) -> bool {
+		self.m2.is_sign_positive()
+	}
+
+	/// Returns true if this area value has a negative sign, including -0.0 
+	/// and negative infinity
+	pub fn is_sign_negative(&self) -> bool {
+		self.m2.is_sign_negative()
+	}
+
+	/// Returns a area value with the magnitude of this one and the sign of 
+	/// `sign_source`, eg for reflecting a value off of a surface
+	///
+	/// # Arguments
+	/// * `sign_source` - The area value whose sign is copied
+	pub fn copysign(self, sign_source: Self) -> Self {
+		Area{m2: self.m2.copysign(sign_source.m2)}
+	}
+}
+
+#[cfg(feature="num-traits")]
+impl<T> Area<T> where T: NumLike+num_traits::CheckedAdd+num_traits::CheckedSub {
+
+	/// Adds another area value to this one, returning `None` if the 
+	/// addition overflows the number type (requires the `num-traits` feature)
+	pub fn checked_add(self, rhs: Self) -> Option<Self> {
+		Some(Area{m2: self.m2.checked_add(&rhs.m2)?})
+	}
+
+	/// Subtracts another area value from this one, returning `None` if 
+	/// the subtraction overflows the number type (requires the `num-traits` feature)
+	pub fn checked_sub(self, rhs: Self) -> Option<Self> {
+		Some(Area{m2: self.m2.checked_sub(&rhs.m2)?})
+	}
+}
+
+#[cfg(feature="num-traits")]
+impl<T> Area<T> where T: NumLike+num_traits::SaturatingAdd+num_traits::SaturatingSub {
+
+	/// Adds another area value to this one, clamping the result to the 
+	/// minimum or maximum value of the number type instead of overflowing 
+	/// (requires the `num-traits` feature)
+	pub fn saturating_add(self, rhs: Self) -> Self {
+		Area{m2: self.m2.saturating_add(&rhs.m2)}
+	}
+
+	/// Subtracts another area value from this one, clamping the result 
+	/// to the minimum or maximum value of the number type instead of overflowing 
+	/// (requires the `num-traits` feature)
+	pub fn saturating_sub(self, rhs: Self) -> Self {
+		Area{m2: self.m2.saturating_sub(&rhs.m2)}
+	}
+}
+
+impl<T> Area<T> where T: NumLike+Into<f64> {
+
+	/// Returns true if this area value is equal to the given area 
+	/// value to within the given number of significant figures (eg 6 significant 
+	/// figures allows a relative difference of up to 1 part per million). NaN is 
+	/// only approximately equal to NaN, infinity is only approximately equal to 
+	/// infinity of the same sign, and zero is only approximately equal to zero.
+	///
+	/// # Arguments
+	/// * `other` - The area value to compare with this one
+	/// * `sig_figs` - The number of significant figures that must match
+	pub fn approx_eq(self, other: Self, sig_figs: i32) -> bool {
+		super::approx_eq_f64(self.m2.into(), other.m2.into(), sig_figs)
+	}
+}
+
+impl<T> Area<T> where T: NumLike+From<f64> {
+
+	/// Returns the linear interpolation between this area value and the given 
+	/// area value, ie `self*(1-t) + other*t`. The interpolation factor is not 
+	/// clamped, so values of `t` outside the range 0 to 1 extrapolate beyond the two 
+	/// values (see `lerp_clamped(...)`).
+	///
+	/// # Arguments
+	/// * `other` - The area value to interpolate towards (returned when `t` is 1)
+	/// * `t` - The interpolation factor, where 0 returns this value and 1 returns `other`
+	pub fn lerp(self, other: Self, t: f64) -> Self {
+		Area{m2: self.m2 * T::from(1.0 - t) + other.m2 * T::from(t)}
+	}
+
+	/// Same as `lerp(...)`, except that the interpolation factor `t` is first clamped 
+	/// to the range 0 to 1, so that the result is always between the two values
+	///
+	/// # Arguments
+	/// * `other` - The area value to interpolate towards (returned when `t` is 1 or more)
+	/// * `t` - The interpolation factor, where 0 returns this value and 1 returns `other`
+	pub fn lerp_clamped(self, other: Self, t: f64) -> Self {
+		self.lerp(other, t.clamp(0.0, 1.0))
+	}
+	
+	/// Returns a copy of this area value in square cm
+	/// 
+	/// *Note: This method is not available for `f32` and other number types lacking the `From<f64>` trait*
+	pub fn to_cm2(&self) -> T {
+		return self.m2.clone() * T::from(10000.0_f64);
+	}
+
+	/// Returns a new area value from the given number of square cm
+	/// 
+	/// *Note: This method is not available for `f32` and other number types lacking the `From<f64>` trait*
+	///
+	/// # Arguments
+	/// * `cm2` - Any number-like type, representing a quantity of square cm
+	pub fn from_cm2(cm2: T) -> Self {
+		Area{m2: cm2 * T::from(0.0001_f64)}
+	}
+
+	/// Returns a copy of this area value in square cm
+	/// 
+	/// *Note: This method is not available for `f32` and other number types lacking the `From<f64>` trait*
+	pub fn to_square_cm(&self) -> T {
+		return self.m2.clone() * T::from(10000.0_f64);
+	}
+
+	/// Returns a new area value from the given number of square cm
+	/// 
+	/// *Note: This method is not available for `f32` and other number types lacking the `From<f64>` trait*
+	///
+	/// # Arguments
+	/// * `square_cm` - Any number-like type, representing a quantity of square cm
+	pub fn from_square_cm(square_cm: T) -> Self {
+		Area{m2: square_cm * T::from(0.0001_f64)}
+	}
+
+	/// Returns a copy of this area value in square mm
+	/// 
+	/// *Note: This method is not available for `f32` and other number types lacking the `From<f64>` trait*
+	pub fn to_mm2(&self) -> T {
+		return self.m2.clone() * T::from(1000000.0_f64);
+	}
+
+	/// Returns a new area value from the given number of square mm
+	/// 
+	/// *Note: This method is not available for `f32` and other number types lacking the `From<f64>` trait*
+	///
+	/// # Arguments
+	/// * `mm2` - Any number-like type, representing a quantity of square mm
+	pub fn from_mm2(mm2: T) -> Self {
+		Area{m2: mm2 * T::from(1e-06_f64)}
+	}
+
+	/// Returns a copy of this area value in square um
+	/// 
+	/// *Note: This method is not available for `f32` and other number types lacking the `From<f64>` trait*
+	pub fn to_um2(&self) -> T {
+		return self.m2.clone() * T::from(1000000000000.0_f64);
+	}
+
+	/// Returns a new area value from the given number of square um
+	/// 
+	/// *Note: This method is not available for `f32` and other number types lacking the `From<f64>` trait*
+	///
+	/// # Arguments
+	/// * `um2` - Any number-like type, representing a quantity of square um
+	pub fn from_um2(um2: T) -> Self {
+		Area{m2: um2 * T::from(1e-12_f64)}
+	}
+
+	/// Returns a copy of this area value in square nm
+	/// 
+	/// *Note: This method is not available for `f32` and other number types lacking the `From<f64>` trait*
+	pub fn to_nm2(&self) -> T {
+		return self.m2.clone() * T::from(1e+18_f64);
+	}
+
+	/// Returns a new area value from the given number of square nm
+	/// 
+	/// *Note: This method is not available for `f32` and other number types lacking the `From<f64>` trait*
+	///
+	/// # Arguments
+	/// * `nm2` - Any number-like type, representing a quantity of square nm
+	pub fn from_nm2(nm2: T) -> Self {
+		Area{m2: nm2 * T::from(1e-18_f64)}
+	}
+
+	/// Returns a copy of this area value in square km
+	/// 
+	/// *Note: This method is not available for `f32` and other number types lacking the `From<f64>` trait*
+	pub fn to_km2(&self) -> T {
+		return self.m2.clone() * T::from(1e-06_f64);
+	}
+
+	/// Returns a new area value from the given number of square km
+	/// 
+	/// *Note: This method is not available for `f32` and other number types lacking the `From<f64>` trait*
+	///
+	/// # Arguments
+	/// * `km2` - Any number-like type, representing a quantity of square km
+	pub fn from_km2(km2: T) -> Self {
+		Area{m2: km2 * T::from(1000000.0_f64)}
+	}
+
+}
+
+
+/// Multiplying a unit value by a scalar value returns a unit value
+#[cfg(feature="num-bigfloat")]
+impl core::ops::Mul<Area<num_bigfloat::BigFloat>> for num_bigfloat::BigFloat {
+	type Output = Area<num_bigfloat::BigFloat>;
+	fn mul(self, rhs: Area<num_bigfloat::BigFloat>) -> Self::Output {
+		Area{m2: self * rhs.m2}
+	}
+}
+/// Multiplying a unit value by a scalar value returns a unit value
+#[cfg(feature="num-bigfloat")]
+impl core::ops::Mul<Area<num_bigfloat::BigFloat>> for &num_bigfloat::BigFloat {
+	type Output = Area<num_bigfloat::BigFloat>;
+	fn mul(self, rhs: Area<num_bigfloat::BigFloat>) -> Self::Output {
+		Area{m2: self.clone() * rhs.m2}
+	}
+}
+/// Multiplying a unit value by a scalar value returns a unit value
+#[cfg(feature="num-bigfloat")]
+impl core::ops::Mul<&Area<num_bigfloat::BigFloat>> for num_bigfloat::BigFloat {
+	type Output = Area<num_bigfloat::BigFloat>;
+	fn mul(self, rhs: &Area<num_bigfloat::BigFloat>) -> Self::Output {
+		Area{m2: self * rhs.m2.clone()}
+	}
+}
+/// Multiplying a unit value by a scalar value returns a unit value
+#[cfg(feature="num-bigfloat")]
+impl core::ops::Mul<&Area<num_bigfloat::BigFloat>> for &num_bigfloat::BigFloat {
+	type Output = Area<num_bigfloat::BigFloat>;
+	fn mul(self, rhs: &Area<num_bigfloat::BigFloat>) -> Self::Output {
+		Area{m2: self.clone() * rhs.m2.clone()}
+	}
+}
+
+/// Multiplying a unit value by a scalar value returns a unit value
+#[cfg(feature="num-complex")]
+impl core::ops::Mul<Area<num_complex::Complex32>> for num_complex::Complex32 {
+	type Output = Area<num_complex::Complex32>;
+	fn mul(self, rhs: Area<num_complex::Complex32>) -> Self::Output {
+		Area{m2: self * rhs.m2}
+	}
+}
+/// Multiplying a unit value by a scalar value returns a unit value
+#[cfg(feature="num-complex")]
+impl core::ops::Mul<Area<num_complex::Complex32>> for &num_complex::Complex32 {
+	type Output = Area<num_complex::Complex32>;
+	fn mul(self, rhs: Area<num_complex::Complex32>) -> Self::Output {
+		Area{m2: self.clone() * rhs.m2}
+	}
+}
+/// Multiplying a unit value by a scalar value returns a unit value
+#[cfg(feature="num-complex")]
+impl core::ops::Mul<&Area<num_complex::Complex32>> for num_complex::Complex32 {
+	type Output = Area<num_complex::Complex32>;
+	fn mul(self, rhs: &Area<num_complex::Complex32>) -> Self::Output {
+		Area{m2: self * rhs.m2.clone()}
+	}
+}
+/// Multiplying a unit value by a scalar value returns a unit value
+#[cfg(feature="num-complex")]
+impl core::ops::Mul<&Area<num_complex::Complex32>> for &num_complex::Complex32 {
+	type Output = Area<num_complex::Complex32>;
+	fn mul(self, rhs: &Area<num_complex::Complex32>) -> Self::Output {
+		Area{m2: self.clone() * rhs.m2.clone()}
+	}
+}
+
+/// Multiplying a unit value by a scalar value returns a unit value
+#[cfg(feature="num-complex")]
+impl core::ops::Mul<Area<num_complex::Complex64>> for num_complex::Complex64 {
+	type Output = Area<num_complex::Complex64>;
+	fn mul(self, rhs: Area<num_complex::Complex64>) -> Self::Output {
+		Area{m2: self * rhs.m2}
+	}
+}
+/// Multiplying a unit value by a scalar value returns a unit value
+#[cfg(feature="num-complex")]
+impl core::ops::Mul<Area<num_complex::Complex64>> for &num_complex::Complex64 {
+	type Output = Area<num_complex::Complex64>;
+	fn mul(self, rhs: Area<num_complex::Complex64>) -> Self::Output {
+		Area{m2: self.clone() * rhs.m2}
+	}
+}
+/// Multiplying a unit value by a scalar value returns a unit value
+#[cfg(feature="num-complex")]
+impl core::ops::Mul<&Area<num_complex::Complex64>> for num_complex::Complex64 {
+	type Output = Area<num_complex::Complex64>;
+	fn mul(self, rhs: &Area<num_complex::Complex64>) -> Self::Output {
+		Area{m2: self * rhs.m2.clone()}
+	}
+}
+/// Multiplying a unit value by a scalar value returns a unit value
+#[cfg(feature="num-complex")]
+impl core::ops::Mul<&Area<num_complex::Complex64>> for &num_complex::Complex64 {
+	type Output = Area<num_complex::Complex64>;
+	fn mul(self, rhs: &Area<num_complex::Complex64>) -> Self::Output {
+		Area{m2: self.clone() * rhs.m2.clone()}
+	}
+}
+
+/// Multiplying a unit value by a scalar value returns a unit value
+#[cfg(feature="num-rational")]
+impl core::ops::Mul<Area<num_rational::Rational64>> for num_rational::Rational64 {
+	type Output = Area<num_rational::Rational64>;
+	fn mul(self, rhs: Area<num_rational::Rational64>) -> Self::Output {
+		Area{m2: self * rhs.m2}
+	}
+}
+/// Multiplying a unit value by a scalar value returns a unit value
+#[cfg(feature="num-rational")]
+impl core::ops::Mul<Area<num_rational::Rational64>> for &num_rational::Rational64 {
+	type Output = Area<num_rational::Rational64>;
+	fn mul(self, rhs: Area<num_rational::Rational64>) -> Self::Output {
+		Area{m2: self.clone() * rhs.m2}
+	}
+}
+/// Multiplying a unit value by a scalar value returns a unit value
+#[cfg(feature="num-rational")]
+impl core::ops::Mul<&Area<num_rational::Rational64>> for num_rational::Rational64 {
+	type Output = Area<num_rational::Rational64>;
+	fn mul(self, rhs: &Area<num_rational::Rational64>) -> Self::Output {
+		Area{m2: self * rhs.m2.clone()}
+	}
+}
+/// Multiplying a unit value by a scalar value returns a unit value
+#[cfg(feature="num-rational")]
+impl core::ops::Mul<&Area<num_rational::Rational64>> for &num_rational::Rational64 {
+	type Output = Area<num_rational::Rational64>;
+	fn mul(self, rhs: &Area<num_rational::Rational64>) -> Self::Output {
+		Area{m2: self.clone() * rhs.m2.clone()}
+	}
+}
+
+/// Multiplying a unit value by a scalar value returns a unit value
+#[cfg(feature="num-rational")]
+impl core::ops::Mul<Area<num_rational::BigRational>> for num_rational::BigRational {
+	type Output = Area<num_rational::BigRational>;
+	fn mul(self, rhs: Area<num_rational::BigRational>) -> Self::Output {
+		Area{m2: self * rhs.m2}
+	}
+}
+/// Multiplying a unit value by a scalar value returns a unit value
+#[cfg(feature="num-rational")]
+impl core::ops::Mul<Area<num_rational::BigRational>> for &num_rational::BigRational {
+	type Output = Area<num_rational::BigRational>;
+	fn mul(self, rhs: Area<num_rational::BigRational>) -> Self::Output {
+		Area{m2: self.clone() * rhs.m2}
+	}
+}
+/// Multiplying a unit value by a scalar value returns a unit value
+#[cfg(feature="num-rational")]
+impl core::ops::Mul<&Area<num_rational::BigRational>> for num_rational::BigRational {
+	type Output = Area<num_rational::BigRational>;
+	fn mul(self, rhs: &Area<num_rational::BigRational>) -> Self::Output {
+		Area{m2: self * rhs.m2.clone()}
+	}
+}
+/// Multiplying a unit value by a scalar value returns a unit value
+#[cfg(feature="num-rational")]
+impl core::ops::Mul<&Area<num_rational::BigRational>> for &num_rational::BigRational {
+	type Output = Area<num_rational::BigRational>;
+	fn mul(self, rhs: &Area<num_rational::BigRational>) -> Self::Output {
+		Area{m2: self.clone() * rhs.m2.clone()}
+	}
+}
+
+
+
+/// Converts a Area into the equivalent [uom](https://crates.io/crates/uom) type [Area](https://docs.rs/uom/0.34.0/uom/si/f32/type.Area.html)
+#[cfg(feature = "uom")]
+impl<T> Into<uom::si::f32::Area> for Area<T> where T: NumLike+Into<f32> {
+	fn into(self) -> uom::si::f32::Area {
+		uom::si::f32::Area::new::<uom::si::area::square_meter>(self.m2.into())
+	}
+}
+
+/// Creates a Area from the equivalent [uom](https://crates.io/crates/uom) type [Area](https://docs.rs/uom/0.34.0/uom/si/f32/type.Area.html)
+#[cfg(feature = "uom")]
+impl<T> From<uom::si::f32::Area> for Area<T> where T: NumLike+From<f32> {
+	fn from(src: uom::si::f32::Area) -> Self {
+		Area{m2: T::from(src.value)}
+	}
+}
+
+/// Converts a Area into the equivalent [uom](https://crates.io/crates/uom) type [Area](https://docs.rs/uom/0.34.0/uom/si/f64/type.Area.html)
+#[cfg(feature = "uom")]
+impl<T> Into<uom::si::f64::Area> for Area<T> where T: NumLike+Into<f64> {
+	fn into(self) -> uom::si::f64::Area {
+		uom::si::f64::Area::new::<uom::si::area::square_meter>(self.m2.into())
+	}
+}
+
+/// Creates a Area from the equivalent [uom](https://crates.io/crates/uom) type [Area](https://docs.rs/uom/0.34.0/uom/si/f64/type.Area.html)
+#[cfg(feature = "uom")]
+impl<T> From<uom::si::f64::Area> for Area<T> where T: NumLike+From<f64> {
+	fn from(src: uom::si::f64::Area) -> Self {
+		Area{m2: T::from(src.value)}
+	}
+}
+
+
+
+/// Multiplying a Area by an [nalgebra](https://crates.io/crates/nalgebra) 
+/// vector or matrix of unitless values returns a vector or matrix of Area 
+/// values (eg a direction vector multiplied by a Area)
+#[cfg(feature="nalgebra")]
+impl<T, R, C, S> core::ops::Mul<nalgebra::Matrix<T, R, C, S>> for Area<T> where 
+	T: NumLike+nalgebra::Scalar, R: nalgebra::Dim, C: nalgebra::Dim, S: nalgebra::RawStorage<T, R, C>, 
+	nalgebra::DefaultAllocator: nalgebra::allocator::Allocator<R, C> {
+	type Output = nalgebra::OMatrix<Area<T>, R, C>;
+	fn mul(self, rhs: nalgebra::Matrix<T, R, C, S>) -> Self::Output {
+		rhs.map(|x| Area{m2: self.m2.clone() * x})
+	}
+}
+/// Multiplying an [nalgebra](https://crates.io/crates/nalgebra) vector or matrix of 
+/// unitless values by a Area returns a vector or matrix of Area values
+#[cfg(feature="nalgebra")]
+impl<T, R, C, S> core::ops::Mul<Area<T>> for nalgebra::Matrix<T, R, C, S> where 
+	T: NumLike+nalgebra::Scalar, R: nalgebra::Dim, C: nalgebra::Dim, S: nalgebra::RawStorage<T, R, C>, 
+	nalgebra::DefaultAllocator: nalgebra::allocator::Allocator<R, C> {
+	type Output = nalgebra::OMatrix<Area<T>, R, C>;
+	fn mul(self, rhs: Area<T>) -> Self::Output {
+		self.map(|x| Area{m2: x * rhs.m2.clone()})
+	}
+}
+/// Dividing an [nalgebra](https://crates.io/crates/nalgebra) vector or matrix of 
+/// Area values by a Area returns a vector or matrix of unitless values
+#[cfg(feature="nalgebra")]
+impl<T, R, C, S> core::ops::Div<Area<T>> for nalgebra::Matrix<Area<T>, R, C, S> where 
+	T: NumLike+nalgebra::Scalar, R: nalgebra::Dim, C: nalgebra::Dim, S: nalgebra::RawStorage<Area<T>, R, C>, 
+	nalgebra::DefaultAllocator: nalgebra::allocator::Allocator<R, C> {
+	type Output = nalgebra::OMatrix<T, R, C>;
+	fn div(self, rhs: Area<T>) -> Self::Output {
+		self.map(|x| x.m2 / rhs.m2.clone())
+	}
+}
+
+
+
+// Area * Distance -> Volume
+/// Multiplying a Area by a Distance returns a value of type Volume
+impl<T> core::ops::Mul<Distance<T>> for Area<T> where T: NumLike {
+	type Output = Volume<T>;
+	fn mul(self, rhs: Distance<T>) -> Self::Output {
+		Volume{m3: self.m2 * rhs.m}
+	}
+}
+/// Multiplying a Area by a Distance returns a value of type Volume
+impl<T> core::ops::Mul<Distance<T>> for &Area<T> where T: NumLike {
+	type Output = Volume<T>;
+	fn mul(self, rhs: Distance<T>) -> Self::Output {
+		Volume{m3: self.m2.clone() * rhs.m}
+	}
+}
+/// Multiplying a Area by a Distance returns a value of type Volume
+impl<T> core::ops::Mul<&Distance<T>> for Area<T> where T: NumLike {
+	type Output = Volume<T>;
+	fn mul(self, rhs: &Distance<T>) -> Self::Output {
+		Volume{m3: self.m2 * rhs.m.clone()}
+	}
+}
+/// Multiplying a Area by a Distance returns a value of type Volume
+impl<T> core::ops::Mul<&Distance<T>> for &Area<T> where T: NumLike {
+	type Output = Volume<T>;
+	fn mul(self, rhs: &Distance<T>) -> Self::Output {
+		Volume{m3: self.m2.clone() * rhs.m.clone()}
+	}
+}
+
+// Area / Distance -> Distance
+/// Dividing a Area by a Distance returns a value of type Distance
+impl<T> core::ops::Div<Distance<T>> for Area<T> where T: NumLike {
+	type Output = Distance<T>;
+	fn div(self, rhs: Distance<T>) -> Self::Output {
+		Distance{m: self.m2 / rhs.m}
+	}
+}
+/// Dividing a Area by a Distance returns a value of type Distance
+impl<T> core::ops::Div<Distance<T>> for &Area<T> where T: NumLike {
+	type Output = Distance<T>;
+	fn div(self, rhs: Distance<T>) -> Self::Output {
+		Distance{m: self.m2.clone() / rhs.m}
+	}
+}
+/// Dividing a Area by a Distance returns a value of type Distance
+impl<T> core::ops::Div<&Distance<T>> for Area<T> where T: NumLike {
+	type Output = Distance<T>;
+	fn div(self, rhs: &Distance<T>) -> Self::Output {
+		Distance{m: self.m2 / rhs.m.clone()}
+	}
+}
+/// Dividing a Area by a Distance returns a value of type Distance
+impl<T> core::ops::Div<&Distance<T>> for &Area<T> where T: NumLike {
+	type Output = Distance<T>;
+	fn div(self, rhs: &Distance<T>) -> Self::Output {
+		Distance{m: self.m2.clone() / rhs.m.clone()}
+	}
+}
+
+// Area * InverseDistance -> Distance
+/// Multiplying a Area by a InverseDistance returns a value of type Distance
+impl<T> core::ops::Mul<InverseDistance<T>> for Area<T> where T: NumLike {
+	type Output = Distance<T>;
+	fn mul(self, rhs: InverseDistance<T>) -> Self::Output {
+		Distance{m: self.m2 * rhs.per_m}
+	}
+}
+/// Multiplying a Area by a InverseDistance returns a value of type Distance
+impl<T> core::ops::Mul<InverseDistance<T>> for &Area<T> where T: NumLike {
+	type Output = Distance<T>;
+	fn mul(self, rhs: InverseDistance<T>) -> Self::Output {
+		Distance{m: self.m2.clone() * rhs.per_m}
+	}
+}
+/// Multiplying a Area by a InverseDistance returns a value of type Distance
+impl<T> core::ops::Mul<&InverseDistance<T>> for Area<T> where T: NumLike {
+	type Output = Distance<T>;
+	fn mul(self, rhs: &InverseDistance<T>) -> Self::Output {
+		Distance{m: self.m2 * rhs.per_m.clone()}
+	}
+}
+/// Multiplying a Area by a InverseDistance returns a value of type Distance
+impl<T> core::ops::Mul<&InverseDistance<T>> for &Area<T> where T: NumLike {
+	type Output = Distance<T>;
+	fn mul(self, rhs: &InverseDistance<T>) -> Self::Output {
+		Distance{m: self.m2.clone() * rhs.per_m.clone()}
+	}
+}
+
+// Area / InverseDistance -> Volume
+/// Dividing a Area by a InverseDistance returns a value of type Volume
+impl<T> core::ops::Div<InverseDistance<T>> for Area<T> where T: NumLike {
+	type Output = Volume<T>;
+	fn div(self, rhs: InverseDistance<T>) -> Self::Output {
+		Volume{m3: self.m2 / rhs.per_m}
+	}
+}
+/// Dividing a Area by a InverseDistance returns a value of type Volume
+impl<T> core::ops::Div<InverseDistance<T>> for &Area<T> where T: NumLike {
+	type Output = Volume<T>;
+	fn div(self, rhs: InverseDistance<T>) -> Self::Output {
+		Volume{m3: self.m2.clone() / rhs.per_m}
+	}
+}
+/// Dividing a Area by a InverseDistance returns a value of type Volume
+impl<T> core::ops::Div<&InverseDistance<T>> for Area<T> where T: NumLike {
+	type Output = Volume<T>;
+	fn div(self, rhs: &InverseDistance<T>) -> Self::Output {
+		Volume{m3: self.m2 / rhs.per_m.clone()}
+	}
+}
+/// Dividing a Area by a InverseDistance returns a value of type Volume
+impl<T> core::ops::Div<&InverseDistance<T>> for &Area<T> where T: NumLike {
+	type Output = Volume<T>;
+	fn div(self, rhs: &InverseDistance<T>) -> Self::Output {
+		Volume{m3: self.m2.clone() / rhs.per_m.clone()}
+	}
+}
+
+// Area * InverseMass -> AreaPerMass
+/// Multiplying a Area by a InverseMass returns a value of type AreaPerMass
+impl<T> core::ops::Mul<InverseMass<T>> for Area<T> where T: NumLike {
+	type Output = AreaPerMass<T>;
+	fn mul(self, rhs: InverseMass<T>) -> Self::Output {
+		AreaPerMass{m2_per_kg: self.m2 * rhs.per_kg}
+	}
+}
+/// Multiplying a Area by a InverseMass returns a value of type AreaPerMass
+impl<T> core::ops::Mul<InverseMass<T>> for &Area<T> where T: NumLike {
+	type Output = AreaPerMass<T>;
+	fn mul(self, rhs: InverseMass<T>) -> Self::Output {
+		AreaPerMass{m2_per_kg: self.m2.clone() * rhs.per_kg}
+	}
+}
+/// Multiplying a Area by a InverseMass returns a value of type AreaPerMass
+impl<T> core::ops::Mul<&InverseMass<T>> for Area<T> where T: NumLike {
+	type Output = AreaPerMass<T>;
+	fn mul(self, rhs: &InverseMass<T>) -> Self::Output {
+		AreaPerMass{m2_per_kg: self.m2 * rhs.per_kg.clone()}
+	}
+}
+/// Multiplying a Area by a InverseMass returns a value of type AreaPerMass
+impl<T> core::ops::Mul<&InverseMass<T>> for &Area<T> where T: NumLike {
+	type Output = AreaPerMass<T>;
+	fn mul(self, rhs: &InverseMass<T>) -> Self::Output {
+		AreaPerMass{m2_per_kg: self.m2.clone() * rhs.per_kg.clone()}
+	}
+}
+
+// Area / Mass -> AreaPerMass
+/// Dividing a Area by a Mass returns a value of type AreaPerMass
+impl<T> core::ops::Div<Mass<T>> for Area<T> where T: NumLike {
+	type Output = AreaPerMass<T>;
+	fn div(self, rhs: Mass<T>) -> Self::Output {
+		AreaPerMass{m2_per_kg: self.m2 / rhs.kg}
+	}
+}
+/// Dividing a Area by a Mass returns a value of type AreaPerMass
+impl<T> core::ops::Div<Mass<T>> for &Area<T> where T: NumLike {
+	type Output = AreaPerMass<T>;
+	fn div(self, rhs: Mass<T>) -> Self::Output {
+		AreaPerMass{m2_per_kg: self.m2.clone() / rhs.kg}
+	}
+}
+/// Dividing a Area by a Mass returns a value of type AreaPerMass
+impl<T> core::ops::Div<&Mass<T>> for Area<T> where T: NumLike {
+	type Output = AreaPerMass<T>;
+	fn div(self, rhs: &Mass<T>) -> Self::Output {
+		AreaPerMass{m2_per_kg: self.m2 / rhs.kg.clone()}
+	}
+}
+/// Dividing a Area by a Mass returns a value of type AreaPerMass
+impl<T> core::ops::Div<&Mass<T>> for &Area<T> where T: NumLike {
+	type Output = AreaPerMass<T>;
+	fn div(self, rhs: &Mass<T>) -> Self::Output {
+		AreaPerMass{m2_per_kg: self.m2.clone() / rhs.kg.clone()}
+	}
+}
+
+// Area / Time -> KinematicViscosity
+/// Dividing a Area by a Time returns a value of type KinematicViscosity
+impl<T> core::ops::Div<Time<T>> for Area<T> where T: NumLike {
+	type Output = KinematicViscosity<T>;
+	fn div(self, rhs: Time<T>) -> Self::Output {
+		KinematicViscosity{m2ps: self.m2 / rhs.s}
+	}
+}
+/// Dividing a Area by a Time returns a value of type KinematicViscosity
+impl<T> core::ops::Div<Time<T>> for &Area<T> where T: NumLike {
+	type Output = KinematicViscosity<T>;
+	fn div(self, rhs: Time<T>) -> Self::Output {
+		KinematicViscosity{m2ps: self.m2.clone() / rhs.s}
+	}
+}
+/// Dividing a Area by a Time returns a value of type KinematicViscosity
+impl<T> core::ops::Div<&Time<T>> for Area<T> where T: NumLike {
+	type Output = KinematicViscosity<T>;
+	fn div(self, rhs: &Time<T>) -> Self::Output {
+		KinematicViscosity{m2ps: self.m2 / rhs.s.clone()}
+	}
+}
+/// Dividing a Area by a Time returns a value of type KinematicViscosity
+impl<T> core::ops::Div<&Time<T>> for &Area<T> where T: NumLike {
+	type Output = KinematicViscosity<T>;
+	fn div(self, rhs: &Time<T>) -> Self::Output {
+		KinematicViscosity{m2ps: self.m2.clone() / rhs.s.clone()}
+	}
+}
+
+// Area / AreaPerLumen -> LuminousFlux
+/// Dividing a Area by a AreaPerLumen returns a value of type LuminousFlux
+impl<T> core::ops::Div<AreaPerLumen<T>> for Area<T> where T: NumLike {
+	type Output = LuminousFlux<T>;
+	fn div(self, rhs: AreaPerLumen<T>) -> Self::Output {
+		LuminousFlux{lm: self.m2 / rhs.m2_per_lm}
+	}
+}
+/// Dividing a Area by a AreaPerLumen returns a value of type LuminousFlux
+impl<T> core::ops::Div<AreaPerLumen<T>> for &Area<T> where T: NumLike {
+	type Output = LuminousFlux<T>;
+	fn div(self, rhs: AreaPerLumen<T>) -> Self::Output {
+		LuminousFlux{lm: self.m2.clone() / rhs.m2_per_lm}
+	}
+}
+/// Dividing a Area by a AreaPerLumen returns a value of type LuminousFlux
+impl<T> core::ops::Div<&AreaPerLumen<T>> for Area<T> where T: NumLike {
+	type Output = LuminousFlux<T>;
+	fn div(self, rhs: &AreaPerLumen<T>) -> Self::Output {
+		LuminousFlux{lm: self.m2 / rhs.m2_per_lm.clone()}
+	}
+}
+/// Dividing a Area by a AreaPerLumen returns a value of type LuminousFlux
+impl<T> core::ops::Div<&AreaPerLumen<T>> for &Area<T> where T: NumLike {
+	type Output = LuminousFlux<T>;
+	fn div(self, rhs: &AreaPerLumen<T>) -> Self::Output {
+		LuminousFlux{lm: self.m2.clone() / rhs.m2_per_lm.clone()}
+	}
+}
+
+// Area / Charge -> InverseSurfaceChargeDensity
+/// Dividing a Area by a Charge returns a value of type InverseSurfaceChargeDensity
+impl<T> core::ops::Div<Charge<T>> for Area<T> where T: NumLike {
+	type Output = InverseSurfaceChargeDensity<T>;
+	fn div(self, rhs: Charge<T>) -> Self::Output {
+		InverseSurfaceChargeDensity{m2_per_C: self.m2 / rhs.C}
+	}
+}
+/// Dividing a Area by a Charge returns a value of type InverseSurfaceChargeDensity
+impl<T> core::ops::Div<Charge<T>> for &Area<T> where T: NumLike {
+	type Output = InverseSurfaceChargeDensity<T>;
+	fn div(self, rhs: Charge<T>) -> Self::Output {
+		InverseSurfaceChargeDensity{m2_per_C: self.m2.clone() / rhs.C}
+	}
+}
+/// Dividing a Area by a Charge returns a value of type InverseSurfaceChargeDensity
+impl<T> core::ops::Div<&Charge<T>> for Area<T> where T: NumLike {
+	type Output = InverseSurfaceChargeDensity<T>;
+	fn div(self, rhs: &Charge<T>) -> Self::Output {
+		InverseSurfaceChargeDensity{m2_per_C: self.m2 / rhs.C.clone()}
+	}
+}
+/// Dividing a Area by a Charge returns a value of type InverseSurfaceChargeDensity
+impl<T> core::ops::Div<&Charge<T>> for &Area<T> where T: NumLike {
+	type Output = InverseSurfaceChargeDensity<T>;
+	fn div(self, rhs: &Charge<T>) -> Self::Output {
+		InverseSurfaceChargeDensity{m2_per_C: self.m2.clone() / rhs.C.clone()}
+	}
+}
+
+// Area * Illuminance -> LuminousFlux
+/// Multiplying a Area by a Illuminance returns a value of type LuminousFlux
+impl<T> core::ops::Mul<Illuminance<T>> for Area<T> where T: NumLike {
+	type Output = LuminousFlux<T>;
+	fn mul(self, rhs: Illuminance<T>) -> Self::Output {
+		LuminousFlux{lm: self.m2 * rhs.lux}
+	}
+}
+/// Multiplying a Area by a Illuminance returns a value of type LuminousFlux
+impl<T> core::ops::Mul<Illuminance<T>> for &Area<T> where T: NumLike {
+	type Output = LuminousFlux<T>;
+	fn mul(self, rhs: Illuminance<T>) -> Self::Output {
+		LuminousFlux{lm: self.m2.clone() * rhs.lux}
+	}
+}
+/// Multiplying a Area by a Illuminance returns a value of type LuminousFlux
+impl<T> core::ops::Mul<&Illuminance<T>> for Area<T> where T: NumLike {
+	type Output = LuminousFlux<T>;
+	fn mul(self, rhs: &Illuminance<T>) -> Self::Output {
+		LuminousFlux{lm: self.m2 * rhs.lux.clone()}
+	}
+}
+/// Multiplying a Area by a Illuminance returns a value of type LuminousFlux
+impl<T> core::ops::Mul<&Illuminance<T>> for &Area<T> where T: NumLike {
+	type Output = LuminousFlux<T>;
+	fn mul(self, rhs: &Illuminance<T>) -> Self::Output {
+		LuminousFlux{lm: self.m2.clone() * rhs.lux.clone()}
+	}
+}
+
+// Area * InverseCharge -> InverseSurfaceChargeDensity
+/// Multiplying a Area by a InverseCharge returns a value of type InverseSurfaceChargeDensity
+impl<T> core::ops::Mul<InverseCharge<T>> for Area<T> where T: NumLike {
+	type Output = InverseSurfaceChargeDensity<T>;
+	fn mul(self, rhs: InverseCharge<T>) -> Self::Output {
+		InverseSurfaceChargeDensity{m2_per_C: self.m2 * rhs.per_C}
+	}
+}
+/// Multiplying a Area by a InverseCharge returns a value of type InverseSurfaceChargeDensity
+impl<T> core::ops::Mul<InverseCharge<T>> for &Area<T> where T: NumLike {
+	type Output = InverseSurfaceChargeDensity<T>;
+	fn mul(self, rhs: InverseCharge<T>) -> Self::Output {
+		InverseSurfaceChargeDensity{m2_per_C: self.m2.clone() * rhs.per_C}
+	}
+}
+/// Multiplying a Area by a InverseCharge returns a value of type InverseSurfaceChargeDensity
+impl<T> core::ops::Mul<&InverseCharge<T>> for Area<T> where T: NumLike {
+	type Output = InverseSurfaceChargeDensity<T>;
+	fn mul(self, rhs: &InverseCharge<T>) -> Self::Output {
+		InverseSurfaceChargeDensity{m2_per_C: self.m2 * rhs.per_C.clone()}
+	}
+}
+/// Multiplying a Area by a InverseCharge returns a value of type InverseSurfaceChargeDensity
+impl<T> core::ops::Mul<&InverseCharge<T>> for &Area<T> where T: NumLike {
+	type Output = InverseSurfaceChargeDensity<T>;
+	fn mul(self, rhs: &InverseCharge<T>) -> Self::Output {
+		InverseSurfaceChargeDensity{m2_per_C: self.m2.clone() * rhs.per_C.clone()}
+	}
+}
+
+// Area / InverseIrradiance -> Power
+/// Dividing a Area by a InverseIrradiance returns a value of type Power
+impl<T> core::ops::Div<InverseIrradiance<T>> for Area<T> where T: NumLike {
+	type Output = Power<T>;
+	fn div(self, rhs: InverseIrradiance<T>) -> Self::Output {
+		Power{W: self.m2 / rhs.m2_per_W}
+	}
+}
+/// Dividing a Area by a InverseIrradiance returns a value of type Power
+impl<T> core::ops::Div<InverseIrradiance<T>> for &Area<T> where T: NumLike {
+	type Output = Power<T>;
+	fn div(self, rhs: InverseIrradiance<T>) -> Self::Output {
+		Power{W: self.m2.clone() / rhs.m2_per_W}
+	}
+}
+/// Dividing a Area by a InverseIrradiance returns a value of type Power
+impl<T> core::ops::Div<&InverseIrradiance<T>> for Area<T> where T: NumLike {
+	type Output = Power<T>;
+	fn div(self, rhs: &InverseIrradiance<T>) -> Self::Output {
+		Power{W: self.m2 / rhs.m2_per_W.clone()}
+	}
+}
+/// Dividing a Area by a InverseIrradiance returns a value of type Power
+impl<T> core::ops::Div<&InverseIrradiance<T>> for &Area<T> where T: NumLike {
+	type Output = Power<T>;
+	fn div(self, rhs: &InverseIrradiance<T>) -> Self::Output {
+		Power{W: self.m2.clone() / rhs.m2_per_W.clone()}
+	}
+}
+
+// Area * InverseLinearChargeDensity -> InverseVolumeChargeDensity
+/// Multiplying a Area by a InverseLinearChargeDensity returns a value of type InverseVolumeChargeDensity
+impl<T> core::ops::Mul<InverseLinearChargeDensity<T>> for Area<T> where T: NumLike {
+	type Output = InverseVolumeChargeDensity<T>;
+	fn mul(self, rhs: InverseLinearChargeDensity<T>) -> Self::Output {
+		InverseVolumeChargeDensity{m3_per_C: self.m2 * rhs.m_per_C}
+	}
+}
+/// Multiplying a Area by a InverseLinearChargeDensity returns a value of type InverseVolumeChargeDensity
+impl<T> core::ops::Mul<InverseLinearChargeDensity<T>> for &Area<T> where T: NumLike {
+	type Output = InverseVolumeChargeDensity<T>;
+	fn mul(self, rhs: InverseLinearChargeDensity<T>) -> Self::Output {
+		InverseVolumeChargeDensity{m3_per_C: self.m2.clone() * rhs.m_per_C}
+	}
+}
+/// Multiplying a Area by a InverseLinearChargeDensity returns a value of type InverseVolumeChargeDensity
+impl<T> core::ops::Mul<&InverseLinearChargeDensity<T>> for Area<T> where T: NumLike {
+	type Output = InverseVolumeChargeDensity<T>;
+	fn mul(self, rhs: &InverseLinearChargeDensity<T>) -> Self::Output {
+		InverseVolumeChargeDensity{m3_per_C: self.m2 * rhs.m_per_C.clone()}
+	}
+}
+/// Multiplying a Area by a InverseLinearChargeDensity returns a value of type InverseVolumeChargeDensity
+impl<T> core::ops::Mul<&InverseLinearChargeDensity<T>> for &Area<T> where T: NumLike {
+	type Output = InverseVolumeChargeDensity<T>;
+	fn mul(self, rhs: &InverseLinearChargeDensity<T>) -> Self::Output {
+		InverseVolumeChargeDensity{m3_per_C: self.m2.clone() * rhs.m_per_C.clone()}
+	}
+}
+
+// Area * InverseLuminousFlux -> AreaPerLumen
+/// Multiplying a Area by a InverseLuminousFlux returns a value of type AreaPerLumen
+impl<T> core::ops::Mul<InverseLuminousFlux<T>> for Area<T> where T: NumLike {
+	type Output = AreaPerLumen<T>;
+	fn mul(self, rhs: InverseLuminousFlux<T>) -> Self::Output {
+		AreaPerLumen{m2_per_lm: self.m2 * rhs.per_lm}
+	}
+}
+/// Multiplying a Area by a InverseLuminousFlux returns a value of type AreaPerLumen
+impl<T> core::ops::Mul<InverseLuminousFlux<T>> for &Area<T> where T: NumLike {
+	type Output = AreaPerLumen<T>;
+	fn mul(self, rhs: InverseLuminousFlux<T>) -> Self::Output {
+		AreaPerLumen{m2_per_lm: self.m2.clone() * rhs.per_lm}
+	}
+}
+/// Multiplying a Area by a InverseLuminousFlux returns a value of type AreaPerLumen
+impl<T> core::ops::Mul<&InverseLuminousFlux<T>> for Area<T> where T: NumLike {
+	type Output = AreaPerLumen<T>;
+	fn mul(self, rhs: &InverseLuminousFlux<T>) -> Self::Output {
+		AreaPerLumen{m2_per_lm: self.m2 * rhs.per_lm.clone()}
+	}
+}
+/// Multiplying a Area by a InverseLuminousFlux returns a value of type AreaPerLumen
+impl<T> core::ops::Mul<&InverseLuminousFlux<T>> for &Area<T> where T: NumLike {
+	type Output = AreaPerLumen<T>;
+	fn mul(self, rhs: &InverseLuminousFlux<T>) -> Self::Output {
+		AreaPerLumen{m2_per_lm: self.m2.clone() * rhs.per_lm.clone()}
+	}
+}
+
+// Area * InverseMagneticFlux -> InverseMagneticFluxDensity
+/// Multiplying a Area by a InverseMagneticFlux returns a value of type InverseMagneticFluxDensity
+impl<T> core::ops::Mul<InverseMagneticFlux<T>> for Area<T> where T: NumLike {
+	type Output = InverseMagneticFluxDensity<T>;
+	fn mul(self, rhs: InverseMagneticFlux<T>) -> Self::Output {
+		InverseMagneticFluxDensity{m2_per_Wb: self.m2 * rhs.per_Wb}
+	}
+}
+/// Multiplying a Area by a InverseMagneticFlux returns a value of type InverseMagneticFluxDensity
+impl<T> core::ops::Mul<InverseMagneticFlux<T>> for &Area<T> where T: NumLike {
+	type Output = InverseMagneticFluxDensity<T>;
+	fn mul(self, rhs: InverseMagneticFlux<T>) -> Self::Output {
+		InverseMagneticFluxDensity{m2_per_Wb: self.m2.clone() * rhs.per_Wb}
+	}
+}
+/// Multiplying a Area by a InverseMagneticFlux returns a value of type InverseMagneticFluxDensity
+impl<T> core::ops::Mul<&InverseMagneticFlux<T>> for Area<T> where T: NumLike {
+	type Output = InverseMagneticFluxDensity<T>;
+	fn mul(self, rhs: &InverseMagneticFlux<T>) -> Self::Output {
+		InverseMagneticFluxDensity{m2_per_Wb: self.m2 * rhs.per_Wb.clone()}
+	}
+}
+/// Multiplying a Area by a InverseMagneticFlux returns a value of type InverseMagneticFluxDensity
+impl<T> core::ops::Mul<&InverseMagneticFlux<T>> for &Area<T> where T: NumLike {
+	type Output = InverseMagneticFluxDensity<T>;
+	fn mul(self, rhs: &InverseMagneticFlux<T>) -> Self::Output {
+		InverseMagneticFluxDensity{m2_per_Wb: self.m2.clone() * rhs.per_Wb.clone()}
+	}
+}
+
+// Area / InverseMagneticFluxDensity -> MagneticFlux
+/// Dividing a Area by a InverseMagneticFluxDensity returns a value of type MagneticFlux
+impl<T> core::ops::Div<InverseMagneticFluxDensity<T>> for Area<T> where T: NumLike {
+	type Output = MagneticFlux<T>;
+	fn div(self, rhs: InverseMagneticFluxDensity<T>) -> Self::Output {
+		MagneticFlux{Wb: self.m2 / rhs.m2_per_Wb}
+	}
+}
+/// Dividing a Area by a InverseMagneticFluxDensity returns a value of type MagneticFlux
+impl<T> core::ops::Div<InverseMagneticFluxDensity<T>> for &Area<T> where T: NumLike {
+	type Output = MagneticFlux<T>;
+	fn div(self, rhs: InverseMagneticFluxDensity<T>) -> Self::Output {
+		MagneticFlux{Wb: self.m2.clone() / rhs.m2_per_Wb}
+	}
+}
+/// Dividing a Area by a InverseMagneticFluxDensity returns a value of type MagneticFlux
+impl<T> core::ops::Div<&InverseMagneticFluxDensity<T>> for Area<T> where T: NumLike {
+	type Output = MagneticFlux<T>;
+	fn div(self, rhs: &InverseMagneticFluxDensity<T>) -> Self::Output {
+		MagneticFlux{Wb: self.m2 / rhs.m2_per_Wb.clone()}
+	}
+}
+/// Dividing a Area by a InverseMagneticFluxDensity returns a value of type MagneticFlux
+impl<T> core::ops::Div<&InverseMagneticFluxDensity<T>> for &Area<T> where T: NumLike {
+	type Output = MagneticFlux<T>;
+	fn div(self, rhs: &InverseMagneticFluxDensity<T>) -> Self::Output {
+		MagneticFlux{Wb: self.m2.clone() / rhs.m2_per_Wb.clone()}
+	}
+}
+
+// Area / InverseSurfaceChargeDensity -> Charge
+/// Dividing a Area by a InverseSurfaceChargeDensity returns a value of type Charge
+impl<T> core::ops::Div<InverseSurfaceChargeDensity<T>> for Area<T> where T: NumLike {
+	type Output = Charge<T>;
+	fn div(self, rhs: InverseSurfaceChargeDensity<T>) -> Self::Output {
+		Charge{C: self.m2 / rhs.m2_per_C}
+	}
+}
+/// Dividing a Area by a InverseSurfaceChargeDensity returns a value of type Charge
+impl<T> core::ops::Div<InverseSurfaceChargeDensity<T>> for &Area<T> where T: NumLike {
+	type Output = Charge<T>;
+	fn div(self, rhs: InverseSurfaceChargeDensity<T>) -> Self::Output {
+		Charge{C: self.m2.clone() / rhs.m2_per_C}
+	}
+}
+/// Dividing a Area by a InverseSurfaceChargeDensity returns a value of type Charge
+impl<T> core::ops::Div<&InverseSurfaceChargeDensity<T>> for Area<T> where T: NumLike {
+	type Output = Charge<T>;
+	fn div(self, rhs: &InverseSurfaceChargeDensity<T>) -> Self::Output {
+		Charge{C: self.m2 / rhs.m2_per_C.clone()}
+	}
+}
+/// Dividing a Area by a InverseSurfaceChargeDensity returns a value of type Charge
+impl<T> core::ops::Div<&InverseSurfaceChargeDensity<T>> for &Area<T> where T: NumLike {
+	type Output = Charge<T>;
+	fn div(self, rhs: &InverseSurfaceChargeDensity<T>) -> Self::Output {
+		Charge{C: self.m2.clone() / rhs.m2_per_C.clone()}
+	}
+}
+
+// Area / InverseVolumeChargeDensity -> LinearChargeDensity
+/// Dividing a Area by a InverseVolumeChargeDensity returns a value of type LinearChargeDensity
+impl<T> core::ops::Div<InverseVolumeChargeDensity<T>> for Area<T> where T: NumLike {
+	type Output = LinearChargeDensity<T>;
+	fn div(self, rhs: InverseVolumeChargeDensity<T>) -> Self::Output {
+		LinearChargeDensity{Cpm: self.m2 / rhs.m3_per_C}
+	}
+}
+/// Dividing a Area by a InverseVolumeChargeDensity returns a value of type LinearChargeDensity
+impl<T> core::ops::Div<InverseVolumeChargeDensity<T>> for &Area<T> where T: NumLike {
+	type Output = LinearChargeDensity<T>;
+	fn div(self, rhs: InverseVolumeChargeDensity<T>) -> Self::Output {
+		LinearChargeDensity{Cpm: self.m2.clone() / rhs.m3_per_C}
+	}
+}
+/// Dividing a Area by a InverseVolumeChargeDensity returns a value of type LinearChargeDensity
+impl<T> core::ops::Div<&InverseVolumeChargeDensity<T>> for Area<T> where T: NumLike {
+	type Output = LinearChargeDensity<T>;
+	fn div(self, rhs: &InverseVolumeChargeDensity<T>) -> Self::Output {
+		LinearChargeDensity{Cpm: self.m2 / rhs.m3_per_C.clone()}
+	}
+}
+/// Dividing a Area by a InverseVolumeChargeDensity returns a value of type LinearChargeDensity
+impl<T> core::ops::Div<&InverseVolumeChargeDensity<T>> for &Area<T> where T: NumLike {
+	type Output = LinearChargeDensity<T>;
+	fn div(self, rhs: &InverseVolumeChargeDensity<T>) -> Self::Output {
+		LinearChargeDensity{Cpm: self.m2.clone() / rhs.m3_per_C.clone()}
+	}
+}
+
+// Area * Irradiance -> Power
+/// Multiplying a Area by a Irradiance returns a value of type Power
+impl<T> core::ops::Mul<Irradiance<T>> for Area<T> where T: NumLike {
+	type Output = Power<T>;
+	fn mul(self, rhs: Irradiance<T>) -> Self::Output {
+		Power{W: self.m2 * rhs.W_per_m2}
+	}
+}
+/// Multiplying a Area by a Irradiance returns a value of type Power
+impl<T> core::ops::Mul<Irradiance<T>> for &Area<T> where T: NumLike {
+	type Output = Power<T>;
+	fn mul(self, rhs: Irradiance<T>) -> Self::Output {
+		Power{W: self.m2.clone() * rhs.W_per_m2}
+	}
+}
+/// Multiplying a Area by a Irradiance returns a value of type Power
+impl<T> core::ops::Mul<&Irradiance<T>> for Area<T> where T: NumLike {
+	type Output = Power<T>;
+	fn mul(self, rhs: &Irradiance<T>) -> Self::Output {
+		Power{W: self.m2 * rhs.W_per_m2.clone()}
+	}
+}
+/// Multiplying a Area by a Irradiance returns a value of type Power
+impl<T> core::ops::Mul<&Irradiance<T>> for &Area<T> where T: NumLike {
+	type Output = Power<T>;
+	fn mul(self, rhs: &Irradiance<T>) -> Self::Output {
+		Power{W: self.m2.clone() * rhs.W_per_m2.clone()}
+	}
+}
+
+// Area / LinearChargeDensity -> InverseVolumeChargeDensity
+/// Dividing a Area by a LinearChargeDensity returns a value of type InverseVolumeChargeDensity
+impl<T> core::ops::Div<LinearChargeDensity<T>> for Area<T> where T: NumLike {
+	type Output = InverseVolumeChargeDensity<T>;
+	fn div(self, rhs: LinearChargeDensity<T>) -> Self::Output {
+		InverseVolumeChargeDensity{m3_per_C: self.m2 / rhs.Cpm}
+	}
+}
+/// Dividing a Area by a LinearChargeDensity returns a value of type InverseVolumeChargeDensity
+impl<T> core::ops::Div<LinearChargeDensity<T>> for &Area<T> where T: NumLike {
+	type Output = InverseVolumeChargeDensity<T>;
+	fn div(self, rhs: LinearChargeDensity<T>) -> Self::Output {
+		InverseVolumeChargeDensity{m3_per_C: self.m2.clone() / rhs.Cpm}
+	}
+}
+/// Dividing a Area by a LinearChargeDensity returns a value of type InverseVolumeChargeDensity
+impl<T> core::ops::Div<&LinearChargeDensity<T>> for Area<T> where T: NumLike {
+	type Output = InverseVolumeChargeDensity<T>;
+	fn div(self, rhs: &LinearChargeDensity<T>) -> Self::Output {
+		InverseVolumeChargeDensity{m3_per_C: self.m2 / rhs.Cpm.clone()}
+	}
+}
+/// Dividing a Area by a LinearChargeDensity returns a value of type InverseVolumeChargeDensity
+impl<T> core::ops::Div<&LinearChargeDensity<T>> for &Area<T> where T: NumLike {
+	type Output = InverseVolumeChargeDensity<T>;
+	fn div(self, rhs: &LinearChargeDensity<T>) -> Self::Output {
+		InverseVolumeChargeDensity{m3_per_C: self.m2.clone() / rhs.Cpm.clone()}
+	}
+}
+
+// Area / LuminousFlux -> AreaPerLumen
+/// Dividing a Area by a LuminousFlux returns a value of type AreaPerLumen
+impl<T> core::ops::Div<LuminousFlux<T>> for Area<T> where T: NumLike {
+	type Output = AreaPerLumen<T>;
+	fn div(self, rhs: LuminousFlux<T>) -> Self::Output {
+		AreaPerLumen{m2_per_lm: self.m2 / rhs.lm}
+	}
+}
+/// Dividing a Area by a LuminousFlux returns a value of type AreaPerLumen
+impl<T> core::ops::Div<LuminousFlux<T>> for &Area<T> where T: NumLike {
+	type Output = AreaPerLumen<T>;
+	fn div(self, rhs: LuminousFlux<T>) -> Self::Output {
+		AreaPerLumen{m2_per_lm: self.m2.clone() / rhs.lm}
+	}
+}
+/// Dividing a Area by a LuminousFlux returns a value of type AreaPerLumen
+impl<T> core::ops::Div<&LuminousFlux<T>> for Area<T> where T: NumLike {
+	type Output = AreaPerLumen<T>;
+	fn div(self, rhs: &LuminousFlux<T>) -> Self::Output {
+		AreaPerLumen{m2_per_lm: self.m2 / rhs.lm.clone()}
+	}
+}
+/// Dividing a Area by a LuminousFlux returns a value of type AreaPerLumen
+impl<T> core::ops::Div<&LuminousFlux<T>> for &Area<T> where T: NumLike {
+	type Output = AreaPerLumen<T>;
+	fn div(self, rhs: &LuminousFlux<T>) -> Self::Output {
+		AreaPerLumen{m2_per_lm: self.m2.clone() / rhs.lm.clone()}
+	}
+}
+
+// Area / MagneticFlux -> InverseMagneticFluxDensity
+/// Dividing a Area by a MagneticFlux returns a value of type InverseMagneticFluxDensity
+impl<T> core::ops::Div<MagneticFlux<T>> for Area<T> where T: NumLike {
+	type Output = InverseMagneticFluxDensity<T>;
+	fn div(self, rhs: MagneticFlux<T>) -> Self::Output {
+		InverseMagneticFluxDensity{m2_per_Wb: self.m2 / rhs.Wb}
+	}
+}
+/// Dividing a Area by a MagneticFlux returns a value of type InverseMagneticFluxDensity
+impl<T> core::ops::Div<MagneticFlux<T>> for &Area<T> where T: NumLike {
+	type Output = InverseMagneticFluxDensity<T>;
+	fn div(self, rhs: MagneticFlux<T>) -> Self::Output {
+		InverseMagneticFluxDensity{m2_per_Wb: self.m2.clone() / rhs.Wb}
+	}
+}
+/// Dividing a Area by a MagneticFlux returns a value of type InverseMagneticFluxDensity
+impl<T> core::ops::Div<&MagneticFlux<T>> for Area<T> where T: NumLike {
+	type Output = InverseMagneticFluxDensity<T>;
+	fn div(self, rhs: &MagneticFlux<T>) -> Self::Output {
+		InverseMagneticFluxDensity{m2_per_Wb: self.m2 / rhs.Wb.clone()}
+	}
+}
+/// Dividing a Area by a MagneticFlux returns a value of type InverseMagneticFluxDensity
+impl<T> core::ops::Div<&MagneticFlux<T>> for &Area<T> where T: NumLike {
+	type Output = InverseMagneticFluxDensity<T>;
+	fn div(self, rhs: &MagneticFlux<T>) -> Self::Output {
+		InverseMagneticFluxDensity{m2_per_Wb: self.m2.clone() / rhs.Wb.clone()}
+	}
+}
+
+// Area * MagneticFluxDensity -> MagneticFlux
+/// Multiplying a Area by a MagneticFluxDensity returns a value of type MagneticFlux
+impl<T> core::ops::Mul<MagneticFluxDensity<T>> for Area<T> where T: NumLike {
+	type Output = MagneticFlux<T>;
+	fn mul(self, rhs: MagneticFluxDensity<T>) -> Self::Output {
+		MagneticFlux{Wb: self.m2 * rhs.T}
+	}
+}
+/// Multiplying a Area by a MagneticFluxDensity returns a value of type MagneticFlux
+impl<T> core::ops::Mul<MagneticFluxDensity<T>> for &Area<T> where T: NumLike {
+	type Output = MagneticFlux<T>;
+	fn mul(self, rhs: MagneticFluxDensity<T>) -> Self::Output {
+		MagneticFlux{Wb: self.m2.clone() * rhs.T}
+	}
+}
+/// Multiplying a Area by a MagneticFluxDensity returns a value of type MagneticFlux
+impl<T> core::ops::Mul<&MagneticFluxDensity<T>> for Area<T> where T: NumLike {
+	type Output = MagneticFlux<T>;
+	fn mul(self, rhs: &MagneticFluxDensity<T>) -> Self::Output {
+		MagneticFlux{Wb: self.m2 * rhs.T.clone()}
+	}
+}
+/// Multiplying a Area by a MagneticFluxDensity returns a value of type MagneticFlux
+impl<T> core::ops::Mul<&MagneticFluxDensity<T>> for &Area<T> where T: NumLike {
+	type Output = MagneticFlux<T>;
+	fn mul(self, rhs: &MagneticFluxDensity<T>) -> Self::Output {
+		MagneticFlux{Wb: self.m2.clone() * rhs.T.clone()}
+	}
+}
+
+// Area * SurfaceChargeDensity -> Charge
+/// Multiplying a Area by a SurfaceChargeDensity returns a value of type Charge
+impl<T> core::ops::Mul<SurfaceChargeDensity<T>> for Area<T> where T: NumLike {
+	type Output = Charge<T>;
+	fn mul(self, rhs: SurfaceChargeDensity<T>) -> Self::Output {
+		Charge{C: self.m2 * rhs.Cpm2}
+	}
+}
+/// Multiplying a Area by a SurfaceChargeDensity returns a value of type Charge
+impl<T> core::ops::Mul<SurfaceChargeDensity<T>> for &Area<T> where T: NumLike {
+	type Output = Charge<T>;
+	fn mul(self, rhs: SurfaceChargeDensity<T>) -> Self::Output {
+		Charge{C: self.m2.clone() * rhs.Cpm2}
+	}
+}
+/// Multiplying a Area by a SurfaceChargeDensity returns a value of type Charge
+impl<T> core::ops::Mul<&SurfaceChargeDensity<T>> for Area<T> where T: NumLike {
+	type Output = Charge<T>;
+	fn mul(self, rhs: &SurfaceChargeDensity<T>) -> Self::Output {
+		Charge{C: self.m2 * rhs.Cpm2.clone()}
+	}
+}
+/// Multiplying a Area by a SurfaceChargeDensity returns a value of type Charge
+impl<T> core::ops::Mul<&SurfaceChargeDensity<T>> for &Area<T> where T: NumLike {
+	type Output = Charge<T>;
+	fn mul(self, rhs: &SurfaceChargeDensity<T>) -> Self::Output {
+		Charge{C: self.m2.clone() * rhs.Cpm2.clone()}
+	}
+}
+
+// Area * VolumeChargeDensity -> LinearChargeDensity
+/// Multiplying a Area by a VolumeChargeDensity returns a value of type LinearChargeDensity
+impl<T> core::ops::Mul<VolumeChargeDensity<T>> for Area<T> where T: NumLike {
+	type Output = LinearChargeDensity<T>;
+	fn mul(self, rhs: VolumeChargeDensity<T>) -> Self::Output {
+		LinearChargeDensity{Cpm: self.m2 * rhs.Cpm3}
+	}
+}
+/// Multiplying a Area by a VolumeChargeDensity returns a value of type LinearChargeDensity
+impl<T> core::ops::Mul<VolumeChargeDensity<T>> for &Area<T> where T: NumLike {
+	type Output = LinearChargeDensity<T>;
+	fn mul(self, rhs: VolumeChargeDensity<T>) -> Self::Output {
+		LinearChargeDensity{Cpm: self.m2.clone() * rhs.Cpm3}
+	}
+}
+/// Multiplying a Area by a VolumeChargeDensity returns a value of type LinearChargeDensity
+impl<T> core::ops::Mul<&VolumeChargeDensity<T>> for Area<T> where T: NumLike {
+	type Output = LinearChargeDensity<T>;
+	fn mul(self, rhs: &VolumeChargeDensity<T>) -> Self::Output {
+		LinearChargeDensity{Cpm: self.m2 * rhs.Cpm3.clone()}
+	}
+}
+/// Multiplying a Area by a VolumeChargeDensity returns a value of type LinearChargeDensity
+impl<T> core::ops::Mul<&VolumeChargeDensity<T>> for &Area<T> where T: NumLike {
+	type Output = LinearChargeDensity<T>;
+	fn mul(self, rhs: &VolumeChargeDensity<T>) -> Self::Output {
+		LinearChargeDensity{Cpm: self.m2.clone() * rhs.Cpm3.clone()}
+	}
+}
+
+// Area * InverseVolume -> InverseDistance
+/// Multiplying a Area by a InverseVolume returns a value of type InverseDistance
+impl<T> core::ops::Mul<InverseVolume<T>> for Area<T> where T: NumLike {
+	type Output = InverseDistance<T>;
+	fn mul(self, rhs: InverseVolume<T>) -> Self::Output {
+		InverseDistance{per_m: self.m2 * rhs.per_m3}
+	}
+}
+/// Multiplying a Area by a InverseVolume returns a value of type InverseDistance
+impl<T> core::ops::Mul<InverseVolume<T>> for &Area<T> where T: NumLike {
+	type Output = InverseDistance<T>;
+	fn mul(self, rhs: InverseVolume<T>) -> Self::Output {
+		InverseDistance{per_m: self.m2.clone() * rhs.per_m3}
+	}
+}
+/// Multiplying a Area by a InverseVolume returns a value of type InverseDistance
+impl<T> core::ops::Mul<&InverseVolume<T>> for Area<T> where T: NumLike {
+	type Output = InverseDistance<T>;
+	fn mul(self, rhs: &InverseVolume<T>) -> Self::Output {
+		InverseDistance{per_m: self.m2 * rhs.per_m3.clone()}
+	}
+}
+/// Multiplying a Area by a InverseVolume returns a value of type InverseDistance
+impl<T> core::ops::Mul<&InverseVolume<T>> for &Area<T> where T: NumLike {
+	type Output = InverseDistance<T>;
+	fn mul(self, rhs: &InverseVolume<T>) -> Self::Output {
+		InverseDistance{per_m: self.m2.clone() * rhs.per_m3.clone()}
+	}
+}
+
+// Area / Volume -> InverseDistance
+/// Dividing a Area by a Volume returns a value of type InverseDistance
+impl<T> core::ops::Div<Volume<T>> for Area<T> where T: NumLike {
+	type Output = InverseDistance<T>;
+	fn div(self, rhs: Volume<T>) -> Self::Output {
+		InverseDistance{per_m: self.m2 / rhs.m3}
+	}
+}
+/// Dividing a Area by a Volume returns a value of type InverseDistance
+impl<T> core::ops::Div<Volume<T>> for &Area<T> where T: NumLike {
+	type Output = InverseDistance<T>;
+	fn div(self, rhs: Volume<T>) -> Self::Output {
+		InverseDistance{per_m: self.m2.clone() / rhs.m3}
+	}
+}
+/// Dividing a Area by a Volume returns a value of type InverseDistance
+impl<T> core::ops::Div<&Volume<T>> for Area<T> where T: NumLike {
+	type Output = InverseDistance<T>;
+	fn div(self, rhs: &Volume<T>) -> Self::Output {
+		InverseDistance{per_m: self.m2 / rhs.m3.clone()}
+	}
+}
+/// Dividing a Area by a Volume returns a value of type InverseDistance
+impl<T> core::ops::Div<&Volume<T>> for &Area<T> where T: NumLike {
+	type Output = InverseDistance<T>;
+	fn div(self, rhs: &Volume<T>) -> Self::Output {
+		InverseDistance{per_m: self.m2.clone() / rhs.m3.clone()}
+	}
+}
+
+// Area * AreaDensity -> Mass
+/// Multiplying a Area by a AreaDensity returns a value of type Mass
+impl<T> core::ops::Mul<AreaDensity<T>> for Area<T> where T: NumLike {
+	type Output = Mass<T>;
+	fn mul(self, rhs: AreaDensity<T>) -> Self::Output {
+		Mass{kg: self.m2 * rhs.kgpm2}
+	}
+}
+/// Multiplying a Area by a AreaDensity returns a value of type Mass
+impl<T> core::ops::Mul<AreaDensity<T>> for &Area<T> where T: NumLike {
+	type Output = Mass<T>;
+	fn mul(self, rhs: AreaDensity<T>) -> Self::Output {
+		Mass{kg: self.m2.clone() * rhs.kgpm2}
+	}
+}
+/// Multiplying a Area by a AreaDensity returns a value of type Mass
+impl<T> core::ops::Mul<&AreaDensity<T>> for Area<T> where T: NumLike {
+	type Output = Mass<T>;
+	fn mul(self, rhs: &AreaDensity<T>) -> Self::Output {
+		Mass{kg: self.m2 * rhs.kgpm2.clone()}
+	}
+}
+/// Multiplying a Area by a AreaDensity returns a value of type Mass
+impl<T> core::ops::Mul<&AreaDensity<T>> for &Area<T> where T: NumLike {
+	type Output = Mass<T>;
+	fn mul(self, rhs: &AreaDensity<T>) -> Self::Output {
+		Mass{kg: self.m2.clone() * rhs.kgpm2.clone()}
+	}
+}
+
+// Area / AreaPerMass -> Mass
+/// Dividing a Area by a AreaPerMass returns a value of type Mass
+impl<T> core::ops::Div<AreaPerMass<T>> for Area<T> where T: NumLike {
+	type Output = Mass<T>;
+	fn div(self, rhs: AreaPerMass<T>) -> Self::Output {
+		Mass{kg: self.m2 / rhs.m2_per_kg}
+	}
+}
+/// Dividing a Area by a AreaPerMass returns a value of type Mass
+impl<T> core::ops::Div<AreaPerMass<T>> for &Area<T> where T: NumLike {
+	type Output = Mass<T>;
+	fn div(self, rhs: AreaPerMass<T>) -> Self::Output {
+		Mass{kg: self.m2.clone() / rhs.m2_per_kg}
+	}
+}
+/// Dividing a Area by a AreaPerMass returns a value of type Mass
+impl<T> core::ops::Div<&AreaPerMass<T>> for Area<T> where T: NumLike {
+	type Output = Mass<T>;
+	fn div(self, rhs: &AreaPerMass<T>) -> Self::Output {
+		Mass{kg: self.m2 / rhs.m2_per_kg.clone()}
+	}
+}
+/// Dividing a Area by a AreaPerMass returns a value of type Mass
+impl<T> core::ops::Div<&AreaPerMass<T>> for &Area<T> where T: NumLike {
+	type Output = Mass<T>;
+	fn div(self, rhs: &AreaPerMass<T>) -> Self::Output {
+		Mass{kg: self.m2.clone() / rhs.m2_per_kg.clone()}
+	}
+}
+
+// Area * DynamicViscosity -> Momentum
+/// Multiplying a Area by a DynamicViscosity returns a value of type Momentum
+impl<T> core::ops::Mul<DynamicViscosity<T>> for Area<T> where T: NumLike {
+	type Output = Momentum<T>;
+	fn mul(self, rhs: DynamicViscosity<T>) -> Self::Output {
+		Momentum{kgmps: self.m2 * rhs.Pas}
+	}
+}
+/// Multiplying a Area by a DynamicViscosity returns a value of type Momentum
+impl<T> core::ops::Mul<DynamicViscosity<T>> for &Area<T> where T: NumLike {
+	type Output = Momentum<T>;
+	fn mul(self, rhs: DynamicViscosity<T>) -> Self::Output {
+		Momentum{kgmps: self.m2.clone() * rhs.Pas}
+	}
+}
+/// Multiplying a Area by a DynamicViscosity returns a value of type Momentum
+impl<T> core::ops::Mul<&DynamicViscosity<T>> for Area<T> where T: NumLike {
+	type Output = Momentum<T>;
+	fn mul(self, rhs: &DynamicViscosity<T>) -> Self::Output {
+		Momentum{kgmps: self.m2 * rhs.Pas.clone()}
+	}
+}
+/// Multiplying a Area by a DynamicViscosity returns a value of type Momentum
+impl<T> core::ops::Mul<&DynamicViscosity<T>> for &Area<T> where T: NumLike {
+	type Output = Momentum<T>;
+	fn mul(self, rhs: &DynamicViscosity<T>) -> Self::Output {
+		Momentum{kgmps: self.m2.clone() * rhs.Pas.clone()}
+	}
+}
+
+// Area / Force -> InversePressure
+/// Dividing a Area by a Force returns a value of type InversePressure
+impl<T> core::ops::Div<Force<T>> for Area<T> where T: NumLike {
+	type Output = InversePressure<T>;
+	fn div(self, rhs: Force<T>) -> Self::Output {
+		InversePressure{per_Pa: self.m2 / rhs.N}
+	}
+}
+/// Dividing a Area by a Force returns a value of type InversePressure
+impl<T> core::ops::Div<Force<T>> for &Area<T> where T: NumLike {
+	type Output = InversePressure<T>;
+	fn div(self, rhs: Force<T>) -> Self::Output {
+		InversePressure{per_Pa: self.m2.clone() / rhs.N}
+	}
+}
+/// Dividing a Area by a Force returns a value of type InversePressure
+impl<T> core::ops::Div<&Force<T>> for Area<T> where T: NumLike {
+	type Output = InversePressure<T>;
+	fn div(self, rhs: &Force<T>) -> Self::Output {
+		InversePressure{per_Pa: self.m2 / rhs.N.clone()}
+	}
+}
+/// Dividing a Area by a Force returns a value of type InversePressure
+impl<T> core::ops::Div<&Force<T>> for &Area<T> where T: NumLike {
+	type Output = InversePressure<T>;
+	fn div(self, rhs: &Force<T>) -> Self::Output {
+		InversePressure{per_Pa: self.m2.clone() / rhs.N.clone()}
+	}
+}
+
+// Area * Frequency -> KinematicViscosity
+/// Multiplying a Area by a Frequency returns a value of type KinematicViscosity
+impl<T> core::ops::Mul<Frequency<T>> for Area<T> where T: NumLike {
+	type Output = KinematicViscosity<T>;
+	fn mul(self, rhs: Frequency<T>) -> Self::Output {
+		KinematicViscosity{m2ps: self.m2 * rhs.Hz}
+	}
+}
+/// Multiplying a Area by a Frequency returns a value of type KinematicViscosity
+impl<T> core::ops::Mul<Frequency<T>> for &Area<T> where T: NumLike {
+	type Output = KinematicViscosity<T>;
+	fn mul(self, rhs: Frequency<T>) -> Self::Output {
+		KinematicViscosity{m2ps: self.m2.clone() * rhs.Hz}
+	}
+}
+/// Multiplying a Area by a Frequency returns a value of type KinematicViscosity
+impl<T> core::ops::Mul<&Frequency<T>> for Area<T> where T: NumLike {
+	type Output = KinematicViscosity<T>;
+	fn mul(self, rhs: &Frequency<T>) -> Self::Output {
+		KinematicViscosity{m2ps: self.m2 * rhs.Hz.clone()}
+	}
+}
+/// Multiplying a Area by a Frequency returns a value of type KinematicViscosity
+impl<T> core::ops::Mul<&Frequency<T>> for &Area<T> where T: NumLike {
+	type Output = KinematicViscosity<T>;
+	fn mul(self, rhs: &Frequency<T>) -> Self::Output {
+		KinematicViscosity{m2ps: self.m2.clone() * rhs.Hz.clone()}
+	}
+}
+
+// Area / InverseDynamicViscosity -> Momentum
+/// Dividing a Area by a InverseDynamicViscosity returns a value of type Momentum
+impl<T> core::ops::Div<InverseDynamicViscosity<T>> for Area<T> where T: NumLike {
+	type Output = Momentum<T>;
+	fn div(self, rhs: InverseDynamicViscosity<T>) -> Self::Output {
+		Momentum{kgmps: self.m2 / rhs.per_Pas}
+	}
+}
+/// Dividing a Area by a InverseDynamicViscosity returns a value of type Momentum
+impl<T> core::ops::Div<InverseDynamicViscosity<T>> for &Area<T> where T: NumLike {
+	type Output = Momentum<T>;
+	fn div(self, rhs: InverseDynamicViscosity<T>) -> Self::Output {
+		Momentum{kgmps: self.m2.clone() / rhs.per_Pas}
+	}
+}
+/// Dividing a Area by a InverseDynamicViscosity returns a value of type Momentum
+impl<T> core::ops::Div<&InverseDynamicViscosity<T>> for Area<T> where T: NumLike {
+	type Output = Momentum<T>;
+	fn div(self, rhs: &InverseDynamicViscosity<T>) -> Self::Output {
+		Momentum{kgmps: self.m2 / rhs.per_Pas.clone()}
+	}
+}
+/// Dividing a Area by a InverseDynamicViscosity returns a value of type Momentum
+impl<T> core::ops::Div<&InverseDynamicViscosity<T>> for &Area<T> where T: NumLike {
+	type Output = Momentum<T>;
+	fn div(self, rhs: &InverseDynamicViscosity<T>) -> Self::Output {
+		Momentum{kgmps: self.m2.clone() / rhs.per_Pas.clone()}
+	}
+}
+
+// Area * InverseForce -> InversePressure
+/// Multiplying a Area by a InverseForce returns a value of type InversePressure
+impl<T> core::ops::Mul<InverseForce<T>> for Area<T> where T: NumLike {
+	type Output = InversePressure<T>;
+	fn mul(self, rhs: InverseForce<T>) -> Self::Output {
+		InversePressure{per_Pa: self.m2 * rhs.per_N}
+	}
+}
+/// Multiplying a Area by a InverseForce returns a value of type InversePressure
+impl<T> core::ops::Mul<InverseForce<T>> for &Area<T> where T: NumLike {
+	type Output = InversePressure<T>;
+	fn mul(self, rhs: InverseForce<T>) -> Self::Output {
+		InversePressure{per_Pa: self.m2.clone() * rhs.per_N}
+	}
+}
+/// Multiplying a Area by a InverseForce returns a value of type InversePressure
+impl<T> core::ops::Mul<&InverseForce<T>> for Area<T> where T: NumLike {
+	type Output = InversePressure<T>;
+	fn mul(self, rhs: &InverseForce<T>) -> Self::Output {
+		InversePressure{per_Pa: self.m2 * rhs.per_N.clone()}
+	}
+}
+/// Multiplying a Area by a InverseForce returns a value of type InversePressure
+impl<T> core::ops::Mul<&InverseForce<T>> for &Area<T> where T: NumLike {
+	type Output = InversePressure<T>;
+	fn mul(self, rhs: &InverseForce<T>) -> Self::Output {
+		InversePressure{per_Pa: self.m2.clone() * rhs.per_N.clone()}
+	}
+}
+
+// Area * InverseKinematicViscosity -> Time
+/// Multiplying a Area by a InverseKinematicViscosity returns a value of type Time
+impl<T> core::ops::Mul<InverseKinematicViscosity<T>> for Area<T> where T: NumLike {
+	type Output = Time<T>;
+	fn mul(self, rhs: InverseKinematicViscosity<T>) -> Self::Output {
+		Time{s: self.m2 * rhs.s_per_m2}
+	}
+}
+/// Multiplying a Area by a InverseKinematicViscosity returns a value of type Time
+impl<T> core::ops::Mul<InverseKinematicViscosity<T>> for &Area<T> where T: NumLike {
+	type Output = Time<T>;
+	fn mul(self, rhs: InverseKinematicViscosity<T>) -> Self::Output {
+		Time{s: self.m2.clone() * rhs.s_per_m2}
+	}
+}
+/// Multiplying a Area by a InverseKinematicViscosity returns a value of type Time
+impl<T> core::ops::Mul<&InverseKinematicViscosity<T>> for Area<T> where T: NumLike {
+	type Output = Time<T>;
+	fn mul(self, rhs: &InverseKinematicViscosity<T>) -> Self::Output {
+		Time{s: self.m2 * rhs.s_per_m2.clone()}
+	}
+}
+/// Multiplying a Area by a InverseKinematicViscosity returns a value of type Time
+impl<T> core::ops::Mul<&InverseKinematicViscosity<T>> for &Area<T> where T: NumLike {
+	type Output = Time<T>;
+	fn mul(self, rhs: &InverseKinematicViscosity<T>) -> Self::Output {
+		Time{s: self.m2.clone() * rhs.s_per_m2.clone()}
+	}
+}
+
+// Area * InverseMomentOfInertia -> InverseMass
+/// Multiplying a Area by a InverseMomentOfInertia returns a value of type InverseMass
+impl<T> core::ops::Mul<InverseMomentOfInertia<T>> for Area<T> where T: NumLike {
+	type Output = InverseMass<T>;
+	fn mul(self, rhs: InverseMomentOfInertia<T>) -> Self::Output {
+		InverseMass{per_kg: self.m2 * rhs.per_kgm2}
+	}
+}
+/// Multiplying a Area by a InverseMomentOfInertia returns a value of type InverseMass
+impl<T> core::ops::Mul<InverseMomentOfInertia<T>> for &Area<T> where T: NumLike {
+	type Output = InverseMass<T>;
+	fn mul(self, rhs: InverseMomentOfInertia<T>) -> Self::Output {
+		InverseMass{per_kg: self.m2.clone() * rhs.per_kgm2}
+	}
+}
+/// Multiplying a Area by a InverseMomentOfInertia returns a value of type InverseMass
+impl<T> core::ops::Mul<&InverseMomentOfInertia<T>> for Area<T> where T: NumLike {
+	type Output = InverseMass<T>;
+	fn mul(self, rhs: &InverseMomentOfInertia<T>) -> Self::Output {
+		InverseMass{per_kg: self.m2 * rhs.per_kgm2.clone()}
+	}
+}
+/// Multiplying a Area by a InverseMomentOfInertia returns a value of type InverseMass
+impl<T> core::ops::Mul<&InverseMomentOfInertia<T>> for &Area<T> where T: NumLike {
+	type Output = InverseMass<T>;
+	fn mul(self, rhs: &InverseMomentOfInertia<T>) -> Self::Output {
+		InverseMass{per_kg: self.m2.clone() * rhs.per_kgm2.clone()}
+	}
+}
+
+// Area * InverseMomentum -> InverseDynamicViscosity
+/// Multiplying a Area by a InverseMomentum returns a value of type InverseDynamicViscosity
+impl<T> core::ops::Mul<InverseMomentum<T>> for Area<T> where T: NumLike {
+	type Output = InverseDynamicViscosity<T>;
+	fn mul(self, rhs: InverseMomentum<T>) -> Self::Output {
+		InverseDynamicViscosity{per_Pas: self.m2 * rhs.s_per_kgm}
+	}
+}
+/// Multiplying a Area by a InverseMomentum returns a value of type InverseDynamicViscosity
+impl<T> core::ops::Mul<InverseMomentum<T>> for &Area<T> where T: NumLike {
+	type Output = InverseDynamicViscosity<T>;
+	fn mul(self, rhs: InverseMomentum<T>) -> Self::Output {
+		InverseDynamicViscosity{per_Pas: self.m2.clone() * rhs.s_per_kgm}
+	}
+}
+/// Multiplying a Area by a InverseMomentum returns a value of type InverseDynamicViscosity
+impl<T> core::ops::Mul<&InverseMomentum<T>> for Area<T> where T: NumLike {
+	type Output = InverseDynamicViscosity<T>;
+	fn mul(self, rhs: &InverseMomentum<T>) -> Self::Output {
+		InverseDynamicViscosity{per_Pas: self.m2 * rhs.s_per_kgm.clone()}
+	}
+}
+/// Multiplying a Area by a InverseMomentum returns a value of type InverseDynamicViscosity
+impl<T> core::ops::Mul<&InverseMomentum<T>> for &Area<T> where T: NumLike {
+	type Output = InverseDynamicViscosity<T>;
+	fn mul(self, rhs: &InverseMomentum<T>) -> Self::Output {
+		InverseDynamicViscosity{per_Pas: self.m2.clone() * rhs.s_per_kgm.clone()}
+	}
+}
+
+// Area * InversePower -> InverseIrradiance
+/// Multiplying a Area by a InversePower returns a value of type InverseIrradiance
+impl<T> core::ops::Mul<InversePower<T>> for Area<T> where T: NumLike {
+	type Output = InverseIrradiance<T>;
+	fn mul(self, rhs: InversePower<T>) -> Self::Output {
+		InverseIrradiance{m2_per_W: self.m2 * rhs.per_W}
+	}
+}
+/// Multiplying a Area by a InversePower returns a value of type InverseIrradiance
+impl<T> core::ops::Mul<InversePower<T>> for &Area<T> where T: NumLike {
+	type Output = InverseIrradiance<T>;
+	fn mul(self, rhs: InversePower<T>) -> Self::Output {
+		InverseIrradiance{m2_per_W: self.m2.clone() * rhs.per_W}
+	}
+}
+/// Multiplying a Area by a InversePower returns a value of type InverseIrradiance
+impl<T> core::ops::Mul<&InversePower<T>> for Area<T> where T: NumLike {
+	type Output = InverseIrradiance<T>;
+	fn mul(self, rhs: &InversePower<T>) -> Self::Output {
+		InverseIrradiance{m2_per_W: self.m2 * rhs.per_W.clone()}
+	}
+}
+/// Multiplying a Area by a InversePower returns a value of type InverseIrradiance
+impl<T> core::ops::Mul<&InversePower<T>> for &Area<T> where T: NumLike {
+	type Output = InverseIrradiance<T>;
+	fn mul(self, rhs: &InversePower<T>) -> Self::Output {
+		InverseIrradiance{m2_per_W: self.m2.clone() * rhs.per_W.clone()}
+	}
+}
+
+// Area / InversePressure -> Force
+/// Dividing a Area by a InversePressure returns a value of type Force
+impl<T> core::ops::Div<InversePressure<T>> for Area<T> where T: NumLike {
+	type Output = Force<T>;
+	fn div(self, rhs: InversePressure<T>) -> Self::Output {
+		Force{N: self.m2 / rhs.per_Pa}
+	}
+}
+/// Dividing a Area by a InversePressure returns a value of type Force
+impl<T> core::ops::Div<InversePressure<T>> for &Area<T> where T: NumLike {
+	type Output = Force<T>;
+	fn div(self, rhs: InversePressure<T>) -> Self::Output {
+		Force{N: self.m2.clone() / rhs.per_Pa}
+	}
+}
+/// Dividing a Area by a InversePressure returns a value of type Force
+impl<T> core::ops::Div<&InversePressure<T>> for Area<T> where T: NumLike {
+	type Output = Force<T>;
+	fn div(self, rhs: &InversePressure<T>) -> Self::Output {
+		Force{N: self.m2 / rhs.per_Pa.clone()}
+	}
+}
+/// Dividing a Area by a InversePressure returns a value of type Force
+impl<T> core::ops::Div<&InversePressure<T>> for &Area<T> where T: NumLike {
+	type Output = Force<T>;
+	fn div(self, rhs: &InversePressure<T>) -> Self::Output {
+		Force{N: self.m2.clone() / rhs.per_Pa.clone()}
+	}
+}
+
+// Area * InverseVolumetricFlowRate -> TimePerDistance
+/// Multiplying a Area by a InverseVolumetricFlowRate returns a value of type TimePerDistance
+impl<T> core::ops::Mul<InverseVolumetricFlowRate<T>> for Area<T> where T: NumLike {
+	type Output = TimePerDistance<T>;
+	fn mul(self, rhs: InverseVolumetricFlowRate<T>) -> Self::Output {
+		TimePerDistance{spm: self.m2 * rhs.s_per_m3}
+	}
+}
+/// Multiplying a Area by a InverseVolumetricFlowRate returns a value of type TimePerDistance
+impl<T> core::ops::Mul<InverseVolumetricFlowRate<T>> for &Area<T> where T: NumLike {
+	type Output = TimePerDistance<T>;
+	fn mul(self, rhs: InverseVolumetricFlowRate<T>) -> Self::Output {
+		TimePerDistance{spm: self.m2.clone() * rhs.s_per_m3}
+	}
+}
+/// Multiplying a Area by a InverseVolumetricFlowRate returns a value of type TimePerDistance
+impl<T> core::ops::Mul<&InverseVolumetricFlowRate<T>> for Area<T> where T: NumLike {
+	type Output = TimePerDistance<T>;
+	fn mul(self, rhs: &InverseVolumetricFlowRate<T>) -> Self::Output {
+		TimePerDistance{spm: self.m2 * rhs.s_per_m3.clone()}
+	}
+}
+/// Multiplying a Area by a InverseVolumetricFlowRate returns a value of type TimePerDistance
+impl<T> core::ops::Mul<&InverseVolumetricFlowRate<T>> for &Area<T> where T: NumLike {
+	type Output = TimePerDistance<T>;
+	fn mul(self, rhs: &InverseVolumetricFlowRate<T>) -> Self::Output {
+		TimePerDistance{spm: self.m2.clone() * rhs.s_per_m3.clone()}
+	}
+}
+
+// Area / KinematicViscosity -> Time
+/// Dividing a Area by a KinematicViscosity returns a value of type Time
+impl<T> core::ops::Div<KinematicViscosity<T>> for Area<T> where T: NumLike {
+	type Output = Time<T>;
+	fn div(self, rhs: KinematicViscosity<T>) -> Self::Output {
+		Time{s: self.m2 / rhs.m2ps}
+	}
+}
+/// Dividing a Area by a KinematicViscosity returns a value of type Time
+impl<T> core::ops::Div<KinematicViscosity<T>> for &Area<T> where T: NumLike {
+	type Output = Time<T>;
+	fn div(self, rhs: KinematicViscosity<T>) -> Self::Output {
+		Time{s: self.m2.clone() / rhs.m2ps}
+	}
+}
+/// Dividing a Area by a KinematicViscosity returns a value of type Time
+impl<T> core::ops::Div<&KinematicViscosity<T>> for Area<T> where T: NumLike {
+	type Output = Time<T>;
+	fn div(self, rhs: &KinematicViscosity<T>) -> Self::Output {
+		Time{s: self.m2 / rhs.m2ps.clone()}
+	}
+}
+/// Dividing a Area by a KinematicViscosity returns a value of type Time
+impl<T> core::ops::Div<&KinematicViscosity<T>> for &Area<T> where T: NumLike {
+	type Output = Time<T>;
+	fn div(self, rhs: &KinematicViscosity<T>) -> Self::Output {
+		Time{s: self.m2.clone() / rhs.m2ps.clone()}
+	}
+}
+
+// Area / MomentOfInertia -> InverseMass
+/// Dividing a Area by a MomentOfInertia returns a value of type InverseMass
+impl<T> core::ops::Div<MomentOfInertia<T>> for Area<T> where T: NumLike {
+	type Output = InverseMass<T>;
+	fn div(self, rhs: MomentOfInertia<T>) -> Self::Output {
+		InverseMass{per_kg: self.m2 / rhs.kgm2}
+	}
+}
+/// Dividing a Area by a MomentOfInertia returns a value of type InverseMass
+impl<T> core::ops::Div<MomentOfInertia<T>> for &Area<T> where T: NumLike {
+	type Output = InverseMass<T>;
+	fn div(self, rhs: MomentOfInertia<T>) -> Self::Output {
+		InverseMass{per_kg: self.m2.clone() / rhs.kgm2}
+	}
+}
+/// Dividing a Area by a MomentOfInertia returns a value of type InverseMass
+impl<T> core::ops::Div<&MomentOfInertia<T>> for Area<T> where T: NumLike {
+	type Output = InverseMass<T>;
+	fn div(self, rhs: &MomentOfInertia<T>) -> Self::Output {
+		InverseMass{per_kg: self.m2 / rhs.kgm2.clone()}
+	}
+}
+/// Dividing a Area by a MomentOfInertia returns a value of type InverseMass
+impl<T> core::ops::Div<&MomentOfInertia<T>> for &Area<T> where T: NumLike {
+	type Output = InverseMass<T>;
+	fn div(self, rhs: &MomentOfInertia<T>) -> Self::Output {
+		InverseMass{per_kg: self.m2.clone() / rhs.kgm2.clone()}
+	}
+}
+
+// Area / Momentum -> InverseDynamicViscosity
+/// Dividing a Area by a Momentum returns a value of type InverseDynamicViscosity
+impl<T> core::ops::Div<Momentum<T>> for Area<T> where T: NumLike {
+	type Output = InverseDynamicViscosity<T>;
+	fn div(self, rhs: Momentum<T>) -> Self::Output {
+		InverseDynamicViscosity{per_Pas: self.m2 / rhs.kgmps}
+	}
+}
+/// Dividing a Area by a Momentum returns a value of type InverseDynamicViscosity
+impl<T> core::ops::Div<Momentum<T>> for &Area<T> where T: NumLike {
+	type Output = InverseDynamicViscosity<T>;
+	fn div(self, rhs: Momentum<T>) -> Self::Output {
+		InverseDynamicViscosity{per_Pas: self.m2.clone() / rhs.kgmps}
+	}
+}
+/// Dividing a Area by a Momentum returns a value of type InverseDynamicViscosity
+impl<T> core::ops::Div<&Momentum<T>> for Area<T> where T: NumLike {
+	type Output = InverseDynamicViscosity<T>;
+	fn div(self, rhs: &Momentum<T>) -> Self::Output {
+		InverseDynamicViscosity{per_Pas: self.m2 / rhs.kgmps.clone()}
+	}
+}
+/// Dividing a Area by a Momentum returns a value of type InverseDynamicViscosity
+impl<T> core::ops::Div<&Momentum<T>> for &Area<T> where T: NumLike {
+	type Output = InverseDynamicViscosity<T>;
+	fn div(self, rhs: &Momentum<T>) -> Self::Output {
+		InverseDynamicViscosity{per_Pas: self.m2.clone() / rhs.kgmps.clone()}
+	}
+}
+
+// Area / Power -> InverseIrradiance
+/// Dividing a Area by a Power returns a value of type InverseIrradiance
+impl<T> core::ops::Div<Power<T>> for Area<T> where T: NumLike {
+	type Output = InverseIrradiance<T>;
+	fn div(self, rhs: Power<T>) -> Self::Output {
+		InverseIrradiance{m2_per_W: self.m2 / rhs.W}
+	}
+}
+/// Dividing a Area by a Power returns a value of type InverseIrradiance
+impl<T> core::ops::Div<Power<T>> for &Area<T> where T: NumLike {
+	type Output = InverseIrradiance<T>;
+	fn div(self, rhs: Power<T>) -> Self::Output {
+		InverseIrradiance{m2_per_W: self.m2.clone() / rhs.W}
+	}
+}
+/// Dividing a Area by a Power returns a value of type InverseIrradiance
+impl<T> core::ops::Div<&Power<T>> for Area<T> where T: NumLike {
+	type Output = InverseIrradiance<T>;
+	fn div(self, rhs: &Power<T>) -> Self::Output {
+		InverseIrradiance{m2_per_W: self.m2 / rhs.W.clone()}
+	}
+}
+/// Dividing a Area by a Power returns a value of type InverseIrradiance
+impl<T> core::ops::Div<&Power<T>> for &Area<T> where T: NumLike {
+	type Output = InverseIrradiance<T>;
+	fn div(self, rhs: &Power<T>) -> Self::Output {
+		InverseIrradiance{m2_per_W: self.m2.clone() / rhs.W.clone()}
+	}
+}
+
+// Area * Pressure -> Force
+/// Multiplying a Area by a Pressure returns a value of type Force
+impl<T> core::ops::Mul<Pressure<T>> for Area<T> where T: NumLike {
+	type Output = Force<T>;
+	fn mul(self, rhs: Pressure<T>) -> Self::Output {
+		Force{N: self.m2 * rhs.Pa}
+	}
+}
+/// Multiplying a Area by a Pressure returns a value of type Force
+impl<T> core::ops::Mul<Pressure<T>> for &Area<T> where T: NumLike {
+	type Output = Force<T>;
+	fn mul(self, rhs: Pressure<T>) -> Self::Output {
+		Force{N: self.m2.clone() * rhs.Pa}
+	}
+}
+/// Multiplying a Area by a Pressure returns a value of type Force
+impl<T> core::ops::Mul<&Pressure<T>> for Area<T> where T: NumLike {
+	type Output = Force<T>;
+	fn mul(self, rhs: &Pressure<T>) -> Self::Output {
+		Force{N: self.m2 * rhs.Pa.clone()}
+	}
+}
+/// Multiplying a Area by a Pressure returns a value of type Force
+impl<T> core::ops::Mul<&Pressure<T>> for &Area<T> where T: NumLike {
+	type Output = Force<T>;
+	fn mul(self, rhs: &Pressure<T>) -> Self::Output {
+		Force{N: self.m2.clone() * rhs.Pa.clone()}
+	}
+}
+
+// Area / TimePerDistance -> VolumetricFlowRate
+/// Dividing a Area by a TimePerDistance returns a value of type VolumetricFlowRate
+impl<T> core::ops::Div<TimePerDistance<T>> for Area<T> where T: NumLike {
+	type Output = VolumetricFlowRate<T>;
+	fn div(self, rhs: TimePerDistance<T>) -> Self::Output {
+		VolumetricFlowRate{m3ps: self.m2 / rhs.spm}
+	}
+}
+/// Dividing a Area by a TimePerDistance returns a value of type VolumetricFlowRate
+impl<T> core::ops::Div<TimePerDistance<T>> for &Area<T> where T: NumLike {
+	type Output = VolumetricFlowRate<T>;
+	fn div(self, rhs: TimePerDistance<T>) -> Self::Output {
+		VolumetricFlowRate{m3ps: self.m2.clone() / rhs.spm}
+	}
+}
+/// Dividing a Area by a TimePerDistance returns a value of type VolumetricFlowRate
+impl<T> core::ops::Div<&TimePerDistance<T>> for Area<T> where T: NumLike {
+	type Output = VolumetricFlowRate<T>;
+	fn div(self, rhs: &TimePerDistance<T>) -> Self::Output {
+		VolumetricFlowRate{m3ps: self.m2 / rhs.spm.clone()}
+	}
+}
+/// Dividing a Area by a TimePerDistance returns a value of type VolumetricFlowRate
+impl<T> core::ops::Div<&TimePerDistance<T>> for &Area<T> where T: NumLike {
+	type Output = VolumetricFlowRate<T>;
+	fn div(self, rhs: &TimePerDistance<T>) -> Self::Output {
+		VolumetricFlowRate{m3ps: self.m2.clone() / rhs.spm.clone()}
+	}
+}
+
+// Area * Velocity -> VolumetricFlowRate
+/// Multiplying a Area by a Velocity returns a value of type VolumetricFlowRate
+impl<T> core::ops::Mul<Velocity<T>> for Area<T> where T: NumLike {
+	type Output = VolumetricFlowRate<T>;
+	fn mul(self, rhs: Velocity<T>) -> Self::Output {
+		VolumetricFlowRate{m3ps: self.m2 * rhs.mps}
+	}
+}
+/// Multiplying a Area by a Velocity returns a value of type VolumetricFlowRate
+impl<T> core::ops::Mul<Velocity<T>> for &Area<T> where T: NumLike {
+	type Output = VolumetricFlowRate<T>;
+	fn mul(self, rhs: Velocity<T>) -> Self::Output {
+		VolumetricFlowRate{m3ps: self.m2.clone() * rhs.mps}
+	}
+}
+/// Multiplying a Area by a Velocity returns a value of type VolumetricFlowRate
+impl<T> core::ops::Mul<&Velocity<T>> for Area<T> where T: NumLike {
+	type Output = VolumetricFlowRate<T>;
+	fn mul(self, rhs: &Velocity<T>) -> Self::Output {
+		VolumetricFlowRate{m3ps: self.m2 * rhs.mps.clone()}
+	}
+}
+/// Multiplying a Area by a Velocity returns a value of type VolumetricFlowRate
+impl<T> core::ops::Mul<&Velocity<T>> for &Area<T> where T: NumLike {
+	type Output = VolumetricFlowRate<T>;
+	fn mul(self, rhs: &Velocity<T>) -> Self::Output {
+		VolumetricFlowRate{m3ps: self.m2.clone() * rhs.mps.clone()}
+	}
+}
+
+// Area / VolumetricFlowRate -> TimePerDistance
+/// Dividing a Area by a VolumetricFlowRate returns a value of type TimePerDistance
+impl<T> core::ops::Div<VolumetricFlowRate<T>> for Area<T> where T: NumLike {
+	type Output = TimePerDistance<T>;
+	fn div(self, rhs: VolumetricFlowRate<T>) -> Self::Output {
+		TimePerDistance{spm: self.m2 / rhs.m3ps}
+	}
+}
+/// Dividing a Area by a VolumetricFlowRate returns a value of type TimePerDistance
+impl<T> core::ops::Div<VolumetricFlowRate<T>> for &Area<T> where T: NumLike {
+	type Output = TimePerDistance<T>;
+	fn div(self, rhs: VolumetricFlowRate<T>) -> Self::Output {
+		TimePerDistance{spm: self.m2.clone() / rhs.m3ps}
+	}
+}
+/// Dividing a Area by a VolumetricFlowRate returns a value of type TimePerDistance
+impl<T> core::ops::Div<&VolumetricFlowRate<T>> for Area<T> where T: NumLike {
+	type Output = TimePerDistance<T>;
+	fn div(self, rhs: &VolumetricFlowRate<T>) -> Self::Output {
+		TimePerDistance{spm: self.m2 / rhs.m3ps.clone()}
+	}
+}
+/// Dividing a Area by a VolumetricFlowRate returns a value of type TimePerDistance
+impl<T> core::ops::Div<&VolumetricFlowRate<T>> for &Area<T> where T: NumLike {
+	type Output = TimePerDistance<T>;
+	fn div(self, rhs: &VolumetricFlowRate<T>) -> Self::Output {
+		TimePerDistance{spm: self.m2.clone() / rhs.m3ps.clone()}
+	}
+}
+
+impl<T> Area<T> where T: NumLike+From<u8> {
+	/// Returns the reciprocal of this Area value (ie 1 divided by 
+	/// this value) as a value of type InverseArea
+	pub fn recip(self) -> InverseArea<T> {
+		InverseArea{per_m2: T::from(1u8) / self.m2}
+	}
+}
+
+// 1/Area -> InverseArea
+/// Dividing a scalar value by a Area unit value returns a value of type InverseArea
+impl<T> core::ops::Div<Area<T>> for f64 where T: NumLike+From<f64> {
+	type Output = InverseArea<T>;
+	fn div(self, rhs: Area<T>) -> Self::Output {
+		InverseArea{per_m2: T::from(self) / rhs.m2}
+	}
+}
+/// Dividing a scalar value by a Area unit value returns a value of type InverseArea
+impl<T> core::ops::Div<Area<T>> for &f64 where T: NumLike+From<f64> {
+	type Output = InverseArea<T>;
+	fn div(self, rhs: Area<T>) -> Self::Output {
+		InverseArea{per_m2: T::from(self.clone()) / rhs.m2}
+	}
+}
+/// Dividing a scalar value by a Area unit value returns a value of type InverseArea
+impl<T> core::ops::Div<&Area<T>> for f64 where T: NumLike+From<f64> {
+	type Output = InverseArea<T>;
+	fn div(self, rhs: &Area<T>) -> Self::Output {
+		InverseArea{per_m2: T::from(self) / rhs.m2.clone()}
+	}
+}
+/// Dividing a scalar value by a Area unit value returns a value of type InverseArea
+impl<T> core::ops::Div<&Area<T>> for &f64 where T: NumLike+From<f64> {
+	type Output = InverseArea<T>;
+	fn div(self, rhs: &Area<T>) -> Self::Output {
+		InverseArea{per_m2: T::from(self.clone()) / rhs.m2.clone()}
+	}
+}
+
+// 1/Area -> InverseArea
+/// Dividing a scalar value by a Area unit value returns a value of type InverseArea
+impl<T> core::ops::Div<Area<T>> for f32 where T: NumLike+From<f32> {
+	type Output = InverseArea<T>;
+	fn div(self, rhs: Area<T>) -> Self::Output {
+		InverseArea{per_m2: T::from(self) / rhs.m2}
+	}
+}
+/// Dividing a scalar value by a Area unit value returns a value of type InverseArea
+impl<T> core::ops::Div<Area<T>> for &f32 where T: NumLike+From<f32> {
+	type Output = InverseArea<T>;
+	fn div(self, rhs: Area<T>) -> Self::Output {
+		InverseArea{per_m2: T::from(self.clone()) / rhs.m2}
+	}
+}
+/// Dividing a scalar value by a Area unit value returns a value of type InverseArea
+impl<T> core::ops::Div<&Area<T>> for f32 where T: NumLike+From<f32> {
+	type Output = InverseArea<T>;
+	fn div(self, rhs: &Area<T>) -> Self::Output {
+		InverseArea{per_m2: T::from(self) / rhs.m2.clone()}
+	}
+}
+/// Dividing a scalar value by a Area unit value returns a value of type InverseArea
+impl<T> core::ops::Div<&Area<T>> for &f32 where T: NumLike+From<f32> {
+	type Output = InverseArea<T>;
+	fn div(self, rhs: &Area<T>) -> Self::Output {
+		InverseArea{per_m2: T::from(self.clone()) / rhs.m2.clone()}
+	}
+}
+
+// 1/Area -> InverseArea
+/// Dividing a scalar value by a Area unit value returns a value of type InverseArea
+impl<T> core::ops::Div<Area<T>> for i64 where T: NumLike+From<i64> {
+	type Output = InverseArea<T>;
+	fn div(self, rhs: Area<T>) -> Self::Output {
+		InverseArea{per_m2: T::from(self) / rhs.m2}
+	}
+}
+/// Dividing a scalar value by a Area unit value returns a value of type InverseArea
+impl<T> core::ops::Div<Area<T>> for &i64 where T: NumLike+From<i64> {
+	type Output = InverseArea<T>;
+	fn div(self, rhs: Area<T>) -> Self::Output {
+		InverseArea{per_m2: T::from(self.clone()) / rhs.m2}
+	}
+}
+/// Dividing a scalar value by a Area unit value returns a value of type InverseArea
+impl<T> core::ops::Div<&Area<T>> for i64 where T: NumLike+From<i64> {
+	type Output = InverseArea<T>;
+	fn div(self, rhs: &Area<T>) -> Self::Output {
+		InverseArea{per_m2: T::from(self) / rhs.m2.clone()}
+	}
+}
+/// Dividing a scalar value by a Area unit value returns a value of type InverseArea
+impl<T> core::ops::Div<&Area<T>> for &i64 where T: NumLike+From<i64> {
+	type Output = InverseArea<T>;
+	fn div(self, rhs: &Area<T>) -> Self::Output {
+		InverseArea{per_m2: T::from(self.clone()) / rhs.m2.clone()}
+	}
+}
+
+// 1/Area -> InverseArea
+/// Dividing a scalar value by a Area unit value returns a value of type InverseArea
+impl<T> core::ops::Div<Area<T>> for i32 where T: NumLike+From<i32> {
+	type Output = InverseArea<T>;
+	fn div(self, rhs: Area<T>) -> Self::Output {
+		InverseArea{per_m2: T::from(self) / rhs.m2}
+	}
+}
+/// Dividing a scalar value by a Area unit value returns a value of type InverseArea
+impl<T> core::ops::Div<Area<T>> for &i32 where T: NumLike+From<i32> {
+	type Output = InverseArea<T>;
+	fn div(self, rhs: Area<T>) -> Self::Output {
+		InverseArea{per_m2: T::from(self.clone()) / rhs.m2}
+	}
+}
+/// Dividing a scalar value by a Area unit value returns a value of type InverseArea
+impl<T> core::ops::Div<&Area<T>> for i32 where T: NumLike+From<i32> {
+	type Output = InverseArea<T>;
+	fn div(self, rhs: &Area<T>) -> Self::Output {
+		InverseArea{per_m2: T::from(self) / rhs.m2.clone()}
+	}
+}
+/// Dividing a scalar value by a Area unit value returns a value of type InverseArea
+impl<T> core::ops::Div<&Area<T>> for &i32 where T: NumLike+From<i32> {
+	type Output = InverseArea<T>;
+	fn div(self, rhs: &Area<T>) -> Self::Output {
+		InverseArea{per_m2: T::from(self.clone()) / rhs.m2.clone()}
+	}
+}
+
+// 1/Area -> InverseArea
+/// Dividing a scalar value by a Area unit value returns a value of type InverseArea
+#[cfg(feature="num-bigfloat")]
+impl<T> core::ops::Div<Area<T>> for num_bigfloat::BigFloat where T: NumLike+From<num_bigfloat::BigFloat> {
+	type Output = InverseArea<T>;
+	fn div(self, rhs: Area<T>) -> Self::Output {
+		InverseArea{per_m2: T::from(self) / rhs.m2}
+	}
+}
+/// Dividing a scalar value by a Area unit value returns a value of type InverseArea
+#[cfg(feature="num-bigfloat")]
+impl<T> core::ops::Div<Area<T>> for &num_bigfloat::BigFloat where T: NumLike+From<num_bigfloat::BigFloat> {
+	type Output = InverseArea<T>;
+	fn div(self, rhs: Area<T>) -> Self::Output {
+		InverseArea{per_m2: T::from(self.clone()) / rhs.m2}
+	}
+}
+/// Dividing a scalar value by a Area unit value returns a value of type InverseArea
+#[cfg(feature="num-bigfloat")]
+impl<T> core::ops::Div<&Area<T>> for num_bigfloat::BigFloat where T: NumLike+From<num_bigfloat::BigFloat> {
+	type Output = InverseArea<T>;
+	fn div(self, rhs: &Area<T>) -> Self::Output {
+		InverseArea{per_m2: T::from(self) / rhs.m2.clone()}
+	}
+}
+/// Dividing a scalar value by a Area unit value returns a value of type InverseArea
+#[cfg(feature="num-bigfloat")]
+impl<T> core::ops::Div<&Area<T>> for &num_bigfloat::BigFloat where T: NumLike+From<num_bigfloat::BigFloat> {
+	type Output = InverseArea<T>;
+	fn div(self, rhs: &Area<T>) -> Self::Output {
+		InverseArea{per_m2: T::from(self.clone()) / rhs.m2.clone()}
+	}
+}
+
+// 1/Area -> InverseArea
+/// Dividing a scalar value by a Area unit value returns a value of type InverseArea
+#[cfg(feature="num-complex")]
+impl<T> core::ops::Div<Area<T>> for num_complex::Complex32 where T: NumLike+From<num_complex::Complex32> {
+	type Output = InverseArea<T>;
+	fn div(self, rhs: Area<T>) -> Self::Output {
+		InverseArea{per_m2: T::from(self) / rhs.m2}
+	}
+}
+/// Dividing a scalar value by a Area unit value returns a value of type InverseArea
+#[cfg(feature="num-complex")]
+impl<T> core::ops::Div<Area<T>> for &num_complex::Complex32 where T: NumLike+From<num_complex::Complex32> {
+	type Output = InverseArea<T>;
+	fn div(self, rhs: Area<T>) -> Self::Output {
+		InverseArea{per_m2: T::from(self.clone()) / rhs.m2}
+	}
+}
+/// Dividing a scalar value by a Area unit value returns a value of type InverseArea
+#[cfg(feature="num-complex")]
+impl<T> core::ops::Div<&Area<T>> for num_complex::Complex32 where T: NumLike+From<num_complex::Complex32> {
+	type Output = InverseArea<T>;
+	fn div(self, rhs: &Area<T>) -> Self::Output {
+		InverseArea{per_m2: T::from(self) / rhs.m2.clone()}
+	}
+}
+/// Dividing a scalar value by a Area unit value returns a value of type InverseArea
+#[cfg(feature="num-complex")]
+impl<T> core::ops::Div<&Area<T>> for &num_complex::Complex32 where T: NumLike+From<num_complex::Complex32> {
+	type Output = InverseArea<T>;
+	fn div(self, rhs: &Area<T>) -> Self::Output {
+		InverseArea{per_m2: T::from(self.clone()) / rhs.m2.clone()}
+	}
+}
+
+// 1/Area -> InverseArea
+/// Dividing a scalar value by a Area unit value returns a value of type InverseArea
+#[cfg(feature="num-complex")]
+impl<T> core::ops::Div<Area<T>> for num_complex::Complex64 where T: NumLike+From<num_complex::Complex64> {
+	type Output = InverseArea<T>;
+	fn div(self, rhs: Area<T>) -> Self::Output {
+		InverseArea{per_m2: T::from(self) / rhs.m2}
+	}
+}
+/// Dividing a scalar value by a Area unit value returns a value of type InverseArea
+#[cfg(feature="num-complex")]
+impl<T> core::ops::Div<Area<T>> for &num_complex::Complex64 where T: NumLike+From<num_complex::Complex64> {
+	type Output = InverseArea<T>;
+	fn div(self, rhs: Area<T>) -> Self::Output {
+		InverseArea{per_m2: T::from(self.clone()) / rhs.m2}
+	}
+}
+/// Dividing a scalar value by a Area unit value returns a value of type InverseArea
+#[cfg(feature="num-complex")]
+impl<T> core::ops::Div<&Area<T>> for num_complex::Complex64 where T: NumLike+From<num_complex::Complex64> {
+	type Output = InverseArea<T>;
+	fn div(self, rhs: &Area<T>) -> Self::Output {
+		InverseArea{per_m2: T::from(self) / rhs.m2.clone()}
+	}
+}
+/// Dividing a scalar value by a Area unit value returns a value of type InverseArea
+#[cfg(feature="num-complex")]
+impl<T> core::ops::Div<&Area<T>> for &num_complex::Complex64 where T: NumLike+From<num_complex::Complex64> {
+	type Output = InverseArea<T>;
+	fn div(self, rhs: &Area<T>) -> Self::Output {
+		InverseArea{per_m2: T::from(self.clone()) / rhs.m2.clone()}
+	}
+}
+
+// 1/Area -> InverseArea
+/// Dividing a scalar value by a Area unit value returns a value of type InverseArea
+#[cfg(feature="num-rational")]
+impl<T> core::ops::Div<Area<T>> for num_rational::Rational64 where T: NumLike+From<num_rational::Rational64> {
+	type Output = InverseArea<T>;
+	fn div(self, rhs: Area<T>) -> Self::Output {
+		InverseArea{per_m2: T::from(self) / rhs.m2}
+	}
+}
+/// Dividing a scalar value by a Area unit value returns a value of type InverseArea
+#[cfg(feature="num-rational")]
+impl<T> core::ops::Div<Area<T>> for &num_rational::Rational64 where T: NumLike+From<num_rational::Rational64> {
+	type Output = InverseArea<T>;
+	fn div(self, rhs: Area<T>) -> Self::Output {
+		InverseArea{per_m2: T::from(self.clone()) / rhs.m2}
+	}
+}
+/// Dividing a scalar value by a Area unit value returns a value of type InverseArea
+#[cfg(feature="num-rational")]
+impl<T> core::ops::Div<&Area<T>> for num_rational::Rational64 where T: NumLike+From<num_rational::Rational64> {
+	type Output = InverseArea<T>;
+	fn div(self, rhs: &Area<T>) -> Self::Output {
+		InverseArea{per_m2: T::from(self) / rhs.m2.clone()}
+	}
+}
+/// Dividing a scalar value by a Area unit value returns a value of type InverseArea
+#[cfg(feature="num-rational")]
+impl<T> core::ops::Div<&Area<T>> for &num_rational::Rational64 where T: NumLike+From<num_rational::Rational64> {
+	type Output = InverseArea<T>;
+	fn div(self, rhs: &Area<T>) -> Self::Output {
+		InverseArea{per_m2: T::from(self.clone()) / rhs.m2.clone()}
+	}
+}
+
+// 1/Area -> InverseArea
+/// Dividing a scalar value by a Area unit value returns a value of type InverseArea
+#[cfg(feature="num-rational")]
+impl<T> core::ops::Div<Area<T>> for num_rational::BigRational where T: NumLike+From<num_rational::BigRational> {
+	type Output = InverseArea<T>;
+	fn div(self, rhs: Area<T>) -> Self::Output {
+		InverseArea{per_m2: T::from(self) / rhs.m2}
+	}
+}
+/// Dividing a scalar value by a Area unit value returns a value of type InverseArea
+#[cfg(feature="num-rational")]
+impl<T> core::ops::Div<Area<T>> for &num_rational::BigRational where T: NumLike+From<num_rational::BigRational> {
+	type Output = InverseArea<T>;
+	fn div(self, rhs: Area<T>) -> Self::Output {
+		InverseArea{per_m2: T::from(self.clone()) / rhs.m2}
+	}
+}
+/// Dividing a scalar value by a Area unit value returns a value of type InverseArea
+#[cfg(feature="num-rational")]
+impl<T> core::ops::Div<&Area<T>> for num_rational::BigRational where T: NumLike+From<num_rational::BigRational> {
+	type Output = InverseArea<T>;
+	fn div(self, rhs: &Area<T>) -> Self::Output {
+		InverseArea{per_m2: T::from(self) / rhs.m2.clone()}
+	}
+}
+/// Dividing a scalar value by a Area unit value returns a value of type InverseArea
+#[cfg(feature="num-rational")]
+impl<T> core::ops::Div<&Area<T>> for &num_rational::BigRational where T: NumLike+From<num_rational::BigRational> {
+	type Output = InverseArea<T>;
+	fn div(self, rhs: &Area<T>) -> Self::Output {
+		InverseArea{per_m2: T::from(self.clone()) / rhs.m2.clone()}
+	}
+}
+
+/// The inverse of angle unit type, defined as inverse radians in SI units
+#[derive(UnitStruct, Debug, Clone, Default)]
+#[si(no_display)]
+#[cfg_attr(all(feature="serde", not(feature="serde-unit-tag")), derive(Serialize, Deserialize))]
+#[repr(transparent)]
+pub struct InverseAngle<T: NumLike>{
+	/// The value of this Inverse angle in inverse radians
+	pub per_rad: T
+}
+
+impl<T> InverseAngle<T> where T: NumLike {
+
+	/// Returns the standard unit name of inverse angle: "inverse radians"
+	pub fn unit_name() -> &'static str { "inverse radians" }
+	
+	/// Returns the abbreviated name or symbol of inverse angle: "1/rad" for inverse radians
+	pub fn unit_symbol() -> &'static str { "1/rad" }
+
+	/// Returns the exponents of the SI base units of inverse angle (1/rad)
+	pub const fn dimension() -> super::Dimension { super::Dimension{length: 0, mass: 0, time: 0, current: 0, temperature: 0, amount: 0, luminosity: 0} }
+
+	/// Returns a wrapper that formats this inverse angle value with the full unit name instead of 
+	/// the unit symbol, eg "5 inverse radians" (the unit name is not singularized for values of 1)
+	pub fn display_named(&self) -> super::NamedDisplay<'_, T> {
+		super::NamedDisplay::new(&self.per_rad, Self::unit_name())
+	}
+
+	/// Returns a new inverse angle value from the given number of inverse radians (same as 
+	/// `from_per_rad(...)`), for any number-like type. This is useful in generic code 
+	/// that works with several unit types
+	///
+	/// # Arguments
+	/// * `value` - Any number-like type, representing a quantity of inverse radians
+	pub const fn new(value: T) -> Self { InverseAngle{per_rad: value} }
+
+	/// Returns a new inverse angle value from the given number of inverse radians. Unlike
+	/// `from_per_rad(...)`, this is a `const fn`, so it can be used to declare `const` and `static` values
+	///
+	/// # Arguments
+	/// * `per_rad` - Any number-like type, representing a quantity of inverse radians
+	pub const fn from_per_rad_const(per_rad: T) -> Self { InverseAngle{per_rad} }
+
+	/// Returns a inverse angle value with a different number type, by applying the 
+	/// given function to the value in inverse radians (eg `.map(|x| x as f32)` to convert 
+	/// from `f64` to `f32`)
+	///
+	/// # Arguments
+	/// * `f` - Function that converts the number of inverse radians to the new number type
+	pub fn map<U, F>(self, f: F) -> InverseAngle<U> where U: NumLike, F: FnOnce(T) -> U {
+		InverseAngle{per_rad: f(self.per_rad)}
+	}
+
+	/// Returns a inverse angle value with a different number type, converted 
+	/// losslessly with the `From` trait (eg from `f32` to `f64`)
+	pub fn cast<U>(self) -> InverseAngle<U> where U: NumLike+From<T> {
+		InverseAngle{per_rad: U::from(self.per_rad)}
+	}
+
+	/// Returns a reference to the value of this inverse angle in inverse radians
+	pub fn as_raw(&self) -> &T {
+		&self.per_rad
+	}
+
+	/// Returns a mutable reference to the value of this inverse angle in inverse radians, 
+	/// eg for passing to numeric libraries that modify values in place. The number 
+	/// is always in inverse radians, so changing it directly does not change the unit
+	pub fn raw_mut(&mut self) -> &mut T {
+		&mut self.per_rad
+	}
+
+	/// Returns a slice of numbers in inverse radians as a slice of inverse angle values, 
+	/// without copying
+	///
+	/// # Arguments
+	/// * `values` - Slice of numbers, representing quantities of inverse radians
+	pub fn wrap_slice(values: &[T]) -> &[Self] {
+		// SAFETY: InverseAngle<T> is #[repr(transparent)], so it has the same memory layout as T
+		unsafe { core::slice::from_raw_parts(values.as_ptr() as *const Self, values.len()) }
+	}
+
+	/// Returns a mutable slice of numbers in inverse radians as a mutable slice of 
+	/// inverse angle values, without copying
+	///
+	/// # Arguments
+	/// * `values` - Slice of numbers, representing quantities of inverse radians
+	pub fn wrap_slice_mut(values: &mut [T]) -> &mut [Self] {
+		// SAFETY: InverseAngle<T> is #[repr(transparent)], so it has the same memory layout as T
+		unsafe { core::slice::from_raw_parts_mut(values.as_mut_ptr() as *mut Self, values.len()) }
+	}
+
+	/// Returns the ratio of this inverse angle value to another inverse angle value 
+	/// (same as `self / other`). Dividing a unit value by another value of the same 
+	/// unit type cancels the units, so the ratio is a dimensionless number
+	///
+	/// # Arguments
+	/// * `other` - The inverse angle value to divide by
+	pub fn ratio(self, other: Self) -> T {
+		self.per_rad / other.per_rad
+	}
+
+	/// Returns the ratio of this inverse angle value to another inverse angle value 
+	/// as a `Dimensionless` value, instead of a bare number like `ratio(...)` does
+	///
+	/// # Arguments
+	/// * `other` - The inverse angle value to divide by
+	pub fn dimensionless_ratio(self, other: Self) -> super::base::Dimensionless<T> {
+		super::base::Dimensionless{value: self.per_rad / other.per_rad}
+	}
+
+	/// Returns this inverse angle value multiplied by a dimensionless factor 
+	/// (same as `self * factor`)
+	///
+	/// # Arguments
+	/// * `factor` - The number to multiply by
+	pub fn scale(self, factor: T) -> Self {
+		InverseAngle{per_rad: self.per_rad * factor}
+	}
+	
+	/// Returns a new inverse angle value from the given number of inverse radians
+	///
+	/// # Arguments
+	/// * `per_rad` - Any number-like type, representing a quantity of inverse radians
+	pub fn from_per_rad(per_rad: T) -> Self { InverseAngle{per_rad: per_rad} }
+	
+	/// Returns a copy of this inverse angle value in inverse radians
+	pub fn to_per_rad(&self) -> T { self.per_rad.clone() }
+
+	/// Returns a new inverse angle value from the given number of inverse radians
+	///
+	/// # Arguments
+	/// * `per_radians` - Any number-like type, representing a quantity of inverse radians
+	pub fn from_per_radians(per_radians: T) -> Self { InverseAngle{per_rad: per_radians} }
+	
+	/// Returns a copy of this inverse angle value in inverse radians
+	pub fn to_per_radians(&self) -> T { self.per_rad.clone() }
+
+}
+
+/// Formats inverse angle values as a number followed by its unit symbol, eg "1500 1/rad"
+/// (complex numbers are wrapped in parentheses, eg "(1+2i) 1/rad").
+/// The alternate flag (`{:#}`) scales the number by the nearest SI prefix where the unit symbol allows it
+impl<T> fmt::Display for InverseAngle<T> where T: NumLike {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		if f.alternate() {
+			return super::fmt_with_si_prefix(f, &self.per_rad, Self::unit_symbol(), false);
+		}
+		super::fmt_unit_value(f, &self.per_rad, Self::unit_symbol())
+	}
+}
+
+/// Formats inverse angle values in exponential notation followed by the unit symbol, eg "1.5e3 1/rad"
+impl<T> fmt::LowerExp for InverseAngle<T> where T: NumLike+fmt::LowerExp {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		fmt::LowerExp::fmt(&self.per_rad, f)?;
+		write!(f, " {}", Self::unit_symbol())
+	}
+}
+
+/// Formats inverse angle values in exponential notation followed by the unit symbol, eg "1.5E3 1/rad"
+impl<T> fmt::UpperExp for InverseAngle<T> where T: NumLike+fmt::UpperExp {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		fmt::UpperExp::fmt(&self.per_rad, f)?;
+		write!(f, " {}", Self::unit_symbol())
+	}
+}
+
+impl<T> super::SiUnit for InverseAngle<T> where T: NumLike {
+	type Number = T;
+	fn unit_name(&self) -> &'static str { Self::unit_name() }
+	fn unit_symbol(&self) -> &'static str { Self::unit_symbol() }
+	fn raw(&self) -> &T { &self.per_rad }
+	fn dimension(&self) -> super::Dimension { Self::dimension() }
+	fn from_raw(value: T) -> Self { InverseAngle{per_rad: value} }
+	fn into_raw(self) -> T { self.per_rad }
+}
+
+#[cfg(feature="alloc")]
+impl<T> InverseAngle<T> where T: NumLike {
+	/// Returns this inverse angle value in the canonical text format "<value> <symbol>", eg "5 1/rad".
+	/// Unlike `Display`, this format never changes with precision or alternate flags, and it is the
+	/// input format expected by `from_str(...)`, so `x.canonical_string().parse()` returns the same value
+	pub fn canonical_string(&self) -> alloc::string::String {
+		alloc::format!("{} {}", self.per_rad, Self::unit_symbol())
+	}
+
+	/// Returns a `Vec` of inverse angle values copied from the given slice of numbers
+	///
+	/// # Arguments
+	/// * `values` - Slice of numbers, representing quantities of inverse radians
+	pub fn from_per_rad_slice(values: &[T]) -> alloc::vec::Vec<Self> {
+		values.iter().map(|x| InverseAngle{per_rad: x.clone()}).collect()
+	}
+}
+
+/// Parses a inverse angle value from a number followed by its unit symbol, eg "5 1/rad"
+impl<T> core::str::FromStr for InverseAngle<T> where T: NumLike+core::str::FromStr {
+	type Err = SiParseError;
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		Ok(InverseAngle{per_rad: super::parse_unit_value(s, Self::unit_symbol())?})
+	}
+}
+
+/// Parses a inverse angle value from a number followed by its unit symbol, eg "5 1/rad" 
+/// (same as `str::parse()`)
+impl<T> core::convert::TryFrom<&str> for InverseAngle<T> where T: NumLike+core::str::FromStr {
+	type Error = SiParseError;
+	fn try_from(s: &str) -> Result<Self, Self::Error> {
+		<Self as core::str::FromStr>::from_str(s)
+	}
+}
+
+#[cfg(feature="num-traits")]
+impl<T> InverseAngle<T> where T: NumLike+num_traits::Euclid {
+
+	/// Returns the Euclidean remainder of dividing this inverse angle value by another, 
+	/// which is never negative (requires the `num-traits` feature)
+	///
+	/// # Arguments
+	/// * `rhs` - The inverse angle value to divide by
+	pub fn rem_euclid(self, rhs: Self) -> Self {
+		InverseAngle{per_rad: self.per_rad.rem_euclid(&rhs.per_rad)}
+	}
+
+	/// Returns the Euclidean quotient of dividing this inverse angle value by another, 
+	/// ie the whole number of times that `rhs` fits into this value, rounded such that 
+	/// `rem_euclid(...)` is never negative (requires the `num-traits` feature)
+	///
+	/// # Arguments
+	/// * `rhs` - The inverse angle value to divide by
+	pub fn div_euclid(self, rhs: Self) -> T {
+		self.per_rad.div_euclid(&rhs.per_rad)
+	}
+}
+
+/// Summing inverse angle values returns their total as a inverse angle value (or zero if there are no values)
+impl<T> core::iter::Sum<InverseAngle<T>> for InverseAngle<T> where T: NumLike+core::iter::Sum {
+	fn sum<I: Iterator<Item=InverseAngle<T>>>(iter: I) -> Self {
+		InverseAngle{per_rad: iter.map(|x| x.per_rad).sum()}
+	}
+}
+/// Summing inverse angle values returns their total as a inverse angle value (or zero if there are no values)
+impl<'a, T> core::iter::Sum<&'a InverseAngle<T>> for InverseAngle<T> where T: NumLike+core::iter::Sum+'a {
+	fn sum<I: Iterator<Item=&'a InverseAngle<T>>>(iter: I) -> Self {
+		InverseAngle{per_rad: iter.map(|x| x.per_rad.clone()).sum()}
+	}
+}
+
+impl<T> InverseAngle<T> where T: NumLike+From<f64> {
+
+	/// Returns the weighted average of the given inverse angle values, where each 
+	/// value is paired with its weight (weights do not need to add up to 1). If the 
+	/// total weight is zero (including when `values` is empty), then zero is returned
+	///
+	/// # Arguments
+	/// * `values` - Slice of (inverse angle value, weight) pairs
+	pub fn weighted_mean(values: &[(Self, f64)]) -> Self {
+		let total_weight: f64 = values.iter().map(|(_, weight)| *weight).sum();
+		if total_weight == 0.0 {
+			return InverseAngle{per_rad: T::from(0.0)};
+		}
+		let total = values.iter().fold(T::from(0.0),
+			|total, (value, weight)| total + value.per_rad.clone() * T::from(*weight));
+		InverseAngle{per_rad: total / T::from(total_weight)}
+	}
+}
+
+impl<T> InverseAngle<T> where T: NumLike+PartialEq+From<u8> {
+
+	/// Divides this inverse angle value by another unit value (eg `distance.checked_div(time)` 
+	/// returns a velocity), returning `None` if the divisor is zero instead of producing 
+	/// infinity or NaN (or panicking, for integer number types). This works for every unit 
+	/// type that this inverse angle value can be divided by with the `/` operator
+	///
+	/// # Arguments
+	/// * `rhs` - The unit value to divide by
+	pub fn checked_div<R>(self, rhs: R) -> Option<<Self as core::ops::Div<R>>::Output> 
+		where Self: core::ops::Div<R>, R: super::SiUnit<Number=T>
+	{
+		if *rhs.raw() == T::from(0u8) {
+			return None;
+		}
+		Some(self / rhs)
+	}
+}
+
+/// Multiplying a inverse angle value by a dimensionless value returns a inverse angle value
+impl<T> core::ops::Mul<super::base::Dimensionless<T>> for InverseAngle<T> where T: NumLike {
+	type Output = InverseAngle<T>;
+	fn mul(self, rhs: super::base::Dimensionless<T>) -> Self::Output {
+		InverseAngle{per_rad: self.per_rad * rhs.value}
+	}
+}
+/// Multiplying a dimensionless value by a inverse angle value returns a inverse angle value
+impl<T> core::ops::Mul<InverseAngle<T>> for super::base::Dimensionless<T> where T: NumLike {
+	type Output = InverseAngle<T>;
+	fn mul(self, rhs: InverseAngle<T>) -> Self::Output {
+		InverseAngle{per_rad: self.value * rhs.per_rad}
+	}
+}
+/// Dividing a inverse angle value by a dimensionless value returns a inverse angle value
+impl<T> core::ops::Div<super::base::Dimensionless<T>> for InverseAngle<T> where T: NumLike {
+	type Output = InverseAngle<T>;
+	fn div(self, rhs: super::base::Dimensionless<T>) -> Self::Output {
+		InverseAngle{per_rad: self.per_rad / rhs.value}
+	}
+}
+
+/// The remainder of dividing a inverse angle value by another inverse angle value is a inverse angle value
+impl<T> core::ops::Rem<InverseAngle<T>> for InverseAngle<T> where T: NumLike+core::ops::Rem<Output=T> {
+	type Output = InverseAngle<T>;
+	fn rem(self, rhs: InverseAngle<T>) -> Self::Output {
+		InverseAngle{per_rad: self.per_rad % rhs.per_rad}
+	}
+}
+/// The remainder of dividing a inverse angle value by another inverse angle value is a inverse angle value
+impl<T> core::ops::Rem<&InverseAngle<T>> for InverseAngle<T> where T: NumLike+core::ops::Rem<Output=T> {
+	type Output = InverseAngle<T>;
+	fn rem(self, rhs: &InverseAngle<T>) -> Self::Output {
+		InverseAngle{per_rad: self.per_rad % rhs.per_rad.clone()}
+	}
+}
+/// The remainder of dividing a inverse angle value by another inverse angle value is a inverse angle value
+impl<T> core::ops::Rem<InverseAngle<T>> for &InverseAngle<T> where T: NumLike+core::ops::Rem<Output=T> {
+	type Output = InverseAngle<T>;
+	fn rem(self, rhs: InverseAngle<T>) -> Self::Output {
+		InverseAngle{per_rad: self.per_rad.clone() % rhs.per_rad}
+	}
+}
+/// The remainder of dividing a inverse angle value by another inverse angle value is a inverse angle value
+impl<T> core::ops::Rem<&InverseAngle<T>> for &InverseAngle<T> where T: NumLike+core::ops::Rem<Output=T> {
+	type Output = InverseAngle<T>;
+	fn rem(self, rhs: &InverseAngle<T>) -> Self::Output {
+		InverseAngle{per_rad: self.per_rad.clone() % rhs.per_rad.clone()}
+	}
+}
+
+/// Serializes a inverse angle value together with its unit symbol, eg 
+/// `{"value": 1.5, "unit": "1/rad"}` (requires the `serde-unit-tag` feature)
+#[cfg(feature="serde-unit-tag")]
+impl<T> serde::Serialize for InverseAngle<T> where T: NumLike+serde::Serialize {
+	fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+		super::serialize_with_unit_tag(&self.per_rad, Self::unit_symbol(), serializer)
+	}
+}
+/// Deserializes a inverse angle value from a value and unit symbol, eg 
+/// `{"value": 1.5, "unit": "1/rad"}`, returning an error if the unit symbol 
+/// does not match (requires the `serde-unit-tag` feature)
+#[cfg(feature="serde-unit-tag")]
+impl<'de, T> serde::Deserialize<'de> for InverseAngle<T> where T: NumLike+serde::Deserialize<'de> {
+	fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+		Ok(InverseAngle{per_rad: super::deserialize_with_unit_tag(Self::unit_symbol(), deserializer)?})
+	}
+}
+
+/// The additive identity of inverse angle values is zero inverse radians (requires the `num-traits` feature)
+#[cfg(feature="num-traits")]
+impl<T> num_traits::Zero for InverseAngle<T> where T: NumLike+num_traits::Zero {
+	fn zero() -> Self { InverseAngle{per_rad: T::zero()} }
+	fn is_zero(&self) -> bool { self.per_rad.is_zero() }
+}
+
+#[cfg(feature="num-traits")]
+impl<T> InverseAngle<T> where T: NumLike+num_traits::Signed {
+
+	/// Returns the sign of this inverse angle value as a dimensionless number: 1 if it is 
+	/// positive, -1 if it is negative, and 0 if it is zero for integer number types 
+	/// (for floating-point number types, +0.0 returns 1.0, -0.0 returns -1.0, and NaN returns 
+	/// NaN) (requires the `num-traits` feature)
+	pub fn signum(self) -> T {
+		self.per_rad.signum()
+	}
+}
+
+impl<T> InverseAngle<T> where T: NumLike+PartialOrd {
+
+	/// Returns the absolute value of this inverse angle value, ie its magnitude without a minus sign
+	/// (for floating-point number types, -0.0 returns +0.0)
+	pub fn abs(self) -> Self {
+		let negated = -self.per_rad.clone();
+		if self.per_rad < negated {
+			InverseAngle{per_rad: negated}
+		} else if self.per_rad == negated {
+			// zero, which is its own negation (adding +0.0 turns -0.0 into +0.0)
+			InverseAngle{per_rad: negated + self.per_rad}
+		} else {
+			self
+		}
+	}
+
+	/// Returns the lesser of this inverse angle value and the given inverse angle value
+	///
+	/// # Arguments
+	/// * `other` - The inverse angle value to compare with this one
+	pub fn min(self, other: Self) -> Self {
+		if other.per_rad < self.per_rad { other } else { self }
+	}
+
+	/// Returns the greater of this inverse angle value and the given inverse angle value
+	///
+	/// # Arguments
+	/// * `other` - The inverse angle value to compare with this one
+	pub fn max(self, other: Self) -> Self {
+		if other.per_rad > self.per_rad { other } else { self }
+	}
+
+	/// Returns the least of the given inverse angle values, or `None` if there are none. 
+	/// Unlike `Iterator::min()`, this does not require `Ord`, so it works for 
+	/// floating-point numbers. NaN values are skipped (so the result is `None` if all 
+	/// values are NaN), and the first of several equal values is returned
+	///
+	/// # Arguments
+	/// * `values` - Any iterator or collection of inverse angle values
+	pub fn min_of<I>(values: I) -> Option<Self> where I: IntoIterator<Item=Self> {
+		values.into_iter().filter(|x| x.per_rad.partial_cmp(&x.per_rad).is_some()).reduce(Self::min)
+	}
+
+	/// Returns the greatest of the given inverse angle values, or `None` if there are none. 
+	/// Unlike `Iterator::max()`, this does not require `Ord`, so it works for 
+	/// floating-point numbers. NaN values are skipped (so the result is `None` if all 
+	/// values are NaN), and the first of several equal values is returned
+	///
+	/// # Arguments
+	/// * `values` - Any iterator or collection of inverse angle values
+	pub fn max_of<I>(values: I) -> Option<Self> where I: IntoIterator<Item=Self> {
+		values.into_iter().filter(|x| x.per_rad.partial_cmp(&x.per_rad).is_some()).reduce(Self::max)
+	}
+
+	/// Returns this inverse angle value restricted to the range from `lo` to `hi`
+	///
+	/// # Arguments
+	/// * `lo` - The minimum inverse angle value
+	/// * `hi` - The maximum inverse angle value
+	///
+	/// # Panics
+	/// Panics if `lo` is greater than `hi` (or if either of them is NaN)
+	pub fn clamp(self, lo: Self, hi: Self) -> Self {
+		assert!(lo.per_rad <= hi.per_rad, "lo must be less than or equal to hi");
+		if self.per_rad < lo.per_rad { lo } else if self.per_rad > hi.per_rad { hi } else { self }
+	}
+
+	/// Returns this inverse angle value with its magnitude capped at `max`, keeping its 
+	/// sign (eg for capping a speed), or this value unchanged if its magnitude does not 
+	/// exceed `max`. For complex-valued inverse angle, use `clamp_norm(...)` instead, 
+	/// which caps the magnitude while keeping the phase
+	///
+	/// # Arguments
+	/// * `max` - The maximum magnitude (should not be negative)
+	pub fn clamp_magnitude(self, max: Self) -> Self {
+		let negated_max = -max.per_rad.clone();
+		if self.per_rad > max.per_rad { 
+			max 
+		} else if self.per_rad < negated_max { 
+			InverseAngle{per_rad: negated_max} 
+		} else { 
+			self 
+		}
+	}
+
+	/// Returns true if this inverse angle value differs from the given inverse angle 
+	/// value by no more than the given absolute tolerance (which is also a 
+	/// inverse angle value, so the comparison is dimensionally safe). NaN is never 
+	/// equal to anything. See also the `assert_unit_eq!(...)` macro.
+	///
+	/// # Arguments
+	/// * `other` - The inverse angle value to compare with this one
+	/// * `tolerance` - The largest allowed difference between the two values
+	pub fn eq_within(self, other: Self, tolerance: Self) -> bool {
+		let diff = self.per_rad - other.per_rad;
+		diff <= tolerance.per_rad && -diff <= tolerance.per_rad
+	}
+}
+
+#[cfg(feature="num-complex")]
+impl InverseAngle<num_complex::Complex32> {
+	/// Returns the magnitude (aka norm or absolute value) of this complex inverse angle 
+	/// value as a real-valued inverse angle (requires the `num-complex` feature)
+	pub fn magnitude(self) -> InverseAngle<f32> {
+		InverseAngle{per_rad: self.per_rad.norm()}
+	}
+
+	/// Returns this complex inverse angle value scaled down so that its magnitude is 
+	/// at most `max` (keeping its phase), or this value unchanged if its magnitude does 
+	/// not exceed `max`. This is the complex equivalent of `clamp_magnitude(...)` (requires 
+	/// the `num-complex` feature)
+	///
+	/// # Arguments
+	/// * `max` - The maximum magnitude (should not be negative)
+	pub fn clamp_norm(self, max: InverseAngle<f32>) -> Self {
+		let norm = self.per_rad.norm();
+		if norm > max.per_rad {
+			InverseAngle{per_rad: self.per_rad * (max.per_rad / norm)}
+		} else {
+			self
+		}
+	}
+}
+
+#[cfg(feature="num-complex")]
+impl InverseAngle<num_complex::Complex64> {
+	/// Returns the magnitude (aka norm or absolute value) of this complex inverse angle 
+	/// value as a real-valued inverse angle (requires the `num-complex` feature)
+	pub fn magnitude(self) -> InverseAngle<f64> {
+		InverseAngle{per_rad: self.per_rad.norm()}
+	}
+
+	/// Returns this complex inverse angle value scaled down so that its magnitude is 
+	/// at most `max` (keeping its phase), or this value unchanged if its magnitude does 
+	/// not exceed `max`. This is the complex equivalent of `clamp_magnitude(...)` (requires 
+	/// the `num-complex` feature)
+	///
+	/// # Arguments
+	/// * `max` - The maximum magnitude (should not be negative)
+	pub fn clamp_norm(self, max: InverseAngle<f64>) -> Self {
+		let norm = self.per_rad.norm();
+		if norm > max.per_rad {
+			InverseAngle{per_rad: self.per_rad * (max.per_rad / norm)}
+		} else {
+			self
+		}
+	}
+}
+
+/// Rounding and other floating-point functions (requires the `num-traits` feature, 
+/// and either the `std` or `libm` feature)
+#[cfg(all(feature="num-traits", any(feature="std", feature="libm")))]
+impl<T> InverseAngle<T> where T: NumLike+num_traits::Float {
+
+	/// Returns this inverse angle value rounded to the nearest whole number of inverse radians 
+	/// (half-way cases are rounded away from zero)
+	pub fn round(self) -> Self {
+		InverseAngle{per_rad: self.per_rad.round()}
+	}
+
+	/// Returns this inverse angle value rounded down to a whole number of inverse radians
+	pub fn floor(self) -> Self {
+		InverseAngle{per_rad: self.per_rad.floor()}
+	}
+
+	/// Returns this inverse angle value rounded up to a whole number of inverse radians
+	pub fn ceil(self) -> Self {
+		InverseAngle{per_rad: self.per_rad.ceil()}
+	}
+
+	/// Returns this inverse angle value rounded towards zero to a whole number of inverse radians
+	pub fn trunc(self) -> Self {
+		InverseAngle{per_rad: self.per_rad.trunc()}
+	}
+
+	/// Returns this inverse angle value rounded to the nearest multiple of the given step 
+	/// (half-way cases are rounded away from zero)
+	///
+	/// # Arguments
+	/// * `step` - The inverse angle value to round to a multiple of
+	pub fn round_to(self, step: Self) -> Self {
+		InverseAngle{per_rad: (self.per_rad / step.per_rad).round() * step.per_rad}
+	}
+
+	/// Fused multiply-add: returns `self * a + b` computed with only one rounding 
+	/// error, which is more accurate (and on some hardware faster) than a separate 
+	/// multiplication and addition
+	///
+	/// # Arguments
+	/// * `a` - The scalar value to multiply this inverse angle value by
+	/// * `b` - The inverse angle value to add to the product
+	pub fn mul_add(self, a: T, b: Self) -> Self {
+		InverseAngle{per_rad: self.per_rad.mul_add(a, b.per_rad)}
+	}
+
+	/// Returns true if this inverse angle value is neither infinite nor NaN
+	pub fn is_finite(&self) -> bool {
+		self.per_rad.is_finite()
+	}
+
+	/// Returns true if this inverse angle value is positive or negative infinity
+	pub fn is_infinite(&self) -> bool {
+		self.per_rad.is_infinite()
+	}
+
+	/// Returns true if this inverse angle value is NaN (not a number)
+	pub fn is_nan(&self) -> bool {
+		self.per_rad.is_nan()
+	}
+
+	/// Returns true if this inverse angle value has a positive sign, including +0.0 
+	/// and positive infinity
+	pub fn is_sign_positive(&self) -> bool {
+		self.per_rad.is_sign_positive()
+	}
+
+	/// Returns true if this inverse angle value has a negative sign, including -0.0 
+	/// and negative infinity
+	pub fn is_sign_negative(&self) -> bool {
+		self.per_rad.is_sign_negative()
+	}
+
+	/// Returns a inverse angle value with the magnitude of this one and the sign of 
+	/// `sign_source`, eg for reflecting a value off of a surface
+	///
+	/// # Arguments
+	/// * `sign_source` - The inverse angle value whose sign is copied
+	pub fn copysign(self, sign_source: Self) -> Self {
+		InverseAngle{per_rad: self.per_rad.copysign(sign_source.per_rad)}
+	}
+}
+
+#[cfg(feature="num-traits")]
+impl<T> InverseAngle<T> where T: NumLike+num_traits::CheckedAdd+num_traits::CheckedSub {
+
+	/// Adds another inverse angle value to this one, returning `None` if the 
+	/// addition overflows the number type (requires the `num-traits` feature)
+	pub fn checked_add(self, rhs: Self) -> Option<Self> {
+		Some(InverseAngle{per_rad: self.per_rad.checked_add(&rhs.per_rad)?})
+	}
+
+	/// Subtracts another inverse angle value from this one, returning `None` if 
+	/// the subtraction overflows the number type (requires the `num-traits` feature)
+	pub fn checked_sub(self, rhs: Self) -> Option<Self> {
+		Some(InverseAngle{per_rad: self.per_rad.checked_sub(&rhs.per_rad)?})
+	}
+}
+
+#[cfg(feature="num-traits")]
+impl<T> InverseAngle<T> where T: NumLike+num_traits::SaturatingAdd+num_traits::SaturatingSub {
+
+	/// Adds another inverse angle value to this one, clamping the result to the 
+	/// minimum or maximum value of the number type instead of overflowing 
+	/// (requires the `num-traits` feature)
+	pub fn saturating_add(self, rhs: Self) -> Self {
+		InverseAngle{per_rad: self.per_rad.saturating_add(&rhs.per_rad)}
+	}
+
+	/// Subtracts another inverse angle value from this one, clamping the result 
+	/// to the minimum or maximum value of the number type instead of overflowing 
+	/// (requires the `num-traits` feature)
+	pub fn saturating_sub(self, rhs: Self) -> Self {
+		InverseAngle{per_rad: self.per_rad.saturating_sub(&rhs.per_rad)}
+	}
+}
+
+impl<T> InverseAngle<T> where T: NumLike+Into<f64> {
+
+	/// Returns true if this inverse angle value is equal to the given inverse angle 
+	/// value to within the given number of significant figures (eg 6 significant 
+	/// figures allows a relative difference of up to 1 part per million). NaN is 
+	/// only approximately equal to NaN, infinity is only approximately equal to 
+	/// infinity of the same sign, and zero is only approximately equal to zero.
+	///
+	/// # Arguments
+	/// * `other` - The inverse angle value to compare with this one
+	/// * `sig_figs` - The number of significant figures that must match
+	pub fn approx_eq(self, other: Self, sig_figs: i32) -> bool {
+		super::approx_eq_f64(self.per_rad.into(), other.per_rad.into(), sig_figs)
+	}
+}
+
+impl<T> InverseAngle<T> where T: NumLike+From<f64> {
+
+	/// Returns the linear interpolation between this inverse angle value and the given 
+	/// inverse angle value, ie `self*(1-t) + other*t`. The interpolation factor is not 
+	/// clamped, so values of `t` outside the range 0 to 1 extrapolate beyond the two 
+	/// values (see `lerp_clamped(...)`).
+	///
+	/// # Arguments
+	/// * `other` - The inverse angle value to interpolate towards (returned when `t` is 1)
+	/// * `t` - The interpolation factor, where 0 returns this value and 1 returns `other`
+	pub fn lerp(self, other: Self, t: f64) -> Self {
+		InverseAngle{per_rad: self.per_rad * T::from(1.0 - t) + other.per_rad * T::from(t)}
+	}
+
+	/// Same as `lerp(...)`, except that the interpolation factor `t` is first clamped 
+	/// to the range 0 to 1, so that the result is always between the two values
+	///
+	/// # Arguments
+	/// * `other` - The inverse angle value to interpolate towards (returned when `t` is 1 or more)
+	/// * `t` - The interpolation factor, where 0 returns this value and 1 returns `other`
+	pub fn lerp_clamped(self, other: Self, t: f64) -> Self {
+		self.lerp(other, t.clamp(0.0, 1.0))
+	}
+	
+	/// Returns a copy of this inverse angle value in inverse degrees
+	/// 
+	/// *Note: This method is not available for `f32` and other number types lacking the `From<f64>` trait*
+	pub fn to_per_degrees(&self) -> T {
+		return self.per_rad.clone() * T::from(0.017453292519943295_f64);
+	}
+
+	/// Returns a new inverse angle value from the given number of inverse degrees
+	/// 
+	/// *Note: This method is not available for `f32` and other number types lacking the `From<f64>` trait*
+	///
+	/// # Arguments
+	/// * `per_degrees` - Any number-like type, representing a quantity of inverse degrees
+	pub fn from_per_degrees(per_degrees: T) -> Self {
+		InverseAngle{per_rad: per_degrees * T::from(57.29577951308232_f64)}
+	}
+
+	/// Returns a copy of this inverse angle value in inverse degrees
+	/// 
+	/// *Note: This method is not available for `f32` and other number types lacking the `From<f64>` trait*
+	pub fn to_per_deg(&self) -> T {
+		return self.per_rad.clone() * T::from(0.017453292519943295_f64);
+	}
+
+	/// Returns a new inverse angle value from the given number of inverse degrees
+	/// 
+	/// *Note: This method is not available for `f32` and other number types lacking the `From<f64>` trait*
+	///
+	/// # Arguments
+	/// * `per_deg` - Any number-like type, representing a quantity of inverse degrees
+	pub fn from_per_deg(per_deg: T) -> Self {
+		InverseAngle{per_rad: per_deg * T::from(57.29577951308232_f64)}
+	}
+
+}
+
+
+/// Multiplying a unit value by a scalar value returns a unit value
+#[cfg(feature="num-bigfloat")]
+impl core::ops::Mul<InverseAngle<num_bigfloat::BigFloat>> for num_bigfloat::BigFloat {
+	type Output = InverseAngle<num_bigfloat::BigFloat>;
+	fn mul(self, rhs: InverseAngle<num_bigfloat::BigFloat>) -> Self::Output {
+		InverseAngle{per_rad: self * rhs.per_rad}
+	}
+}
+/// Multiplying a unit value by a scalar value returns a unit value
+#[cfg(feature="num-bigfloat")]
+impl core::ops::Mul<InverseAngle<num_bigfloat::BigFloat>> for &num_bigfloat::BigFloat {
+	type Output = InverseAngle<num_bigfloat::BigFloat>;
+	fn mul(self, rhs: InverseAngle<num_bigfloat::BigFloat>) -> Self::Output {
+		InverseAngle{per_rad: self.clone() * rhs.per_rad}
+	}
+}
+/// Multiplying a unit value by a scalar value returns a unit value
+#[cfg(feature="num-bigfloat")]
+impl core::ops::Mul<&InverseAngle<num_bigfloat::BigFloat>> for num_bigfloat::BigFloat {
+	type Output = InverseAngle<num_bigfloat::BigFloat>;
+	fn mul(self, rhs: &InverseAngle<num_bigfloat::BigFloat>) -> Self::Output {
+		InverseAngle{per_rad: self * rhs.per_rad.clone()}
+	}
+}
+/// Multiplying a unit value by a scalar value returns a unit value
+#[cfg(feature="num-bigfloat")]
+impl core::ops::Mul<&InverseAngle<num_bigfloat::BigFloat>> for &num_bigfloat::BigFloat {
+	type Output = InverseAngle<num_bigfloat::BigFloat>;
+	fn mul(self, rhs: &InverseAngle<num_bigfloat::BigFloat>) -> Self::Output {
+		InverseAngle{per_rad: self.clone() * rhs.per_rad.clone()}
+	}
+}
+
+/// Multiplying a unit value by a scalar value returns a unit value
+#[cfg(feature="num-complex")]
+impl core::ops::Mul<InverseAngle<num_complex::Complex32>> for num_complex::Complex32 {
+	type Output = InverseAngle<num_complex::Complex32>;
+	fn mul(self, rhs: InverseAngle<num_complex::Complex32>) -> Self::Output {
+		InverseAngle{per_rad: self * rhs.per_rad}
+	}
+}
+/// Multiplying a unit value by a scalar value returns a unit value
+#[cfg(feature="num-complex")]
+impl core::ops::Mul<InverseAngle<num_complex::Complex32>> for &num_complex::Complex32 {
+	type Output = InverseAngle<num_complex::Complex32>;
+	fn mul(self, rhs: InverseAngle<num_complex::Complex32>) -> Self::Output {
+		InverseAngle{per_rad: self.clone() * rhs.per_rad}
+	}
+}
+/// Multiplying a unit value by a scalar value returns a unit value
+#[cfg(feature="num-complex")]
+impl core::ops::Mul<&InverseAngle<num_complex::Complex32>> for num_complex::Complex32 {
+	type Output = InverseAngle<num_complex::Complex32>;
+	fn mul(self, rhs: &InverseAngle<num_complex::Complex32>) -> Self::Output {
+		InverseAngle{per_rad: self * rhs.per_rad.clone()}
+	}
+}
+/// Multiplying a unit value by a scalar value returns a unit value
+#[cfg(feature="num-complex")]
+impl core::ops::Mul<&InverseAngle<num_complex::Complex32>> for &num_complex::Complex32 {
+	type Output = InverseAngle<num_complex::Complex32>;
+	fn mul(self, rhs: &InverseAngle<num_complex::Complex32>) -> Self::Output {
+		InverseAngle{per_rad: self.clone() * rhs.per_rad.clone()}
+	}
+}
+
+/// Multiplying a unit value by a scalar value returns a unit value
+#[cfg(feature="num-complex")]
+impl core::ops::Mul<InverseAngle<num_complex::Complex64>> for num_complex::Complex64 {
+	type Output = InverseAngle<num_complex::Complex64>;
+	fn mul(self, rhs: InverseAngle<num_complex::Complex64>) -> Self::Output {
+		InverseAngle{per_rad: self * rhs.per_rad}
+	}
+}
+/// Multiplying a unit value by a scalar value returns a unit value
+#[cfg(feature="num-complex")]
+impl core::ops::Mul<InverseAngle<num_complex::Complex64>> for &num_complex::Complex64 {
+	type Output = InverseAngle<num_complex::Complex64>;
+	fn mul(self, rhs: InverseAngle<num_complex::Complex64>) -> Self::Output {
+		InverseAngle{per_rad: self.clone() * rhs.per_rad}
+	}
+}
+/// Multiplying a unit value by a scalar value returns a unit value
+#[cfg(feature="num-complex")]
+impl core::ops::Mul<&InverseAngle<num_complex::Complex64>> for num_complex::Complex64 {
+	type Output = InverseAngle<num_complex::Complex64>;
+	fn mul(self, rhs: &InverseAngle<num_complex::Complex64>) -> Self::Output {
+		InverseAngle{per_rad: self * rhs.per_rad.clone()}
+	}
+}
+/// Multiplying a unit value by a scalar value returns a unit value
+#[cfg(feature="num-complex")]
+impl core::ops::Mul<&InverseAngle<num_complex::Complex64>> for &num_complex::Complex64 {
+	type Output = InverseAngle<num_complex::Complex64>;
+	fn mul(self, rhs: &InverseAngle<num_complex::Complex64>) -> Self::Output {
+		InverseAngle{per_rad: self.clone() * rhs.per_rad.clone()}
+	}
+}
+
+/// Multiplying a unit value by a scalar value returns a unit value
+#[cfg(feature="num-rational")]
+impl core::ops::Mul<InverseAngle<num_rational::Rational64>> for num_rational::Rational64 {
+	type Output = InverseAngle<num_rational::Rational64>;
+	fn mul(self, rhs: InverseAngle<num_rational::Rational64>) -> Self::Output {
+		InverseAngle{per_rad: self * rhs.per_rad}
+	}
+}
+/// Multiplying a unit value by a scalar value returns a unit value
+#[cfg(feature="num-rational")]
+impl core::ops::Mul<InverseAngle<num_rational::Rational64>> for &num_rational::Rational64 {
+	type Output = InverseAngle<num_rational::Rational64>;
+	fn mul(self, rhs: InverseAngle<num_rational::Rational64>) -> Self::Output {
+		InverseAngle{per_rad: self.clone() * rhs.per_rad}
+	}
+}
+/// Multiplying a unit value by a scalar value returns a unit value
+#[cfg(feature="num-rational")]
+impl core::ops::Mul<&InverseAngle<num_rational::Rational64>> for num_rational::Rational64 {
+	type Output = InverseAngle<num_rational::Rational64>;
+	fn mul(self, rhs: &InverseAngle<num_rational::Rational64>) -> Self::Output {
+		InverseAngle{per_rad: self * rhs.per_rad.clone()}
+	}
+}
+/// Multiplying a unit value by a scalar value returns a unit value
+#[cfg(feature="num-rational")]
+impl core::ops::Mul<&InverseAngle<num_rational::Rational64>> for &num_rational::Rational64 {
+	type Output = InverseAngle<num_rational::Rational64>;
+	fn mul(self, rhs: &InverseAngle<num_rational::Rational64>) -> Self::Output {
+		InverseAngle{per_rad: self.clone() * rhs.per_rad.clone()}
+	}
+}
+
+/// Multiplying a unit value by a scalar value returns a unit value
+#[cfg(feature="num-rational")]
+impl core::ops::Mul<InverseAngle<num_rational::BigRational>> for num_rational::BigRational {
+	type Output = InverseAngle<num_rational::BigRational>;
+	fn mul(self, rhs: InverseAngle<num_rational::BigRational>) -> Self::Output {
+		InverseAngle{per_rad: self * rhs.per_rad}
+	}
+}
+/// Multiplying a unit value by a scalar value returns a unit value
+#[cfg(feature="num-rational")]
+impl core::ops::Mul<InverseAngle<num_rational::BigRational>> for &num_rational::BigRational {
+	type Output = InverseAngle<num_rational::BigRational>;
+	fn mul(self, rhs: InverseAngle<num_rational::BigRational>) -> Self::Output {
+		InverseAngle{per_rad: self.clone() * rhs.per_rad}
+	}
+}
+/// Multiplying a unit value by a scalar value returns a unit value
+#[cfg(feature="num-rational")]
+impl core::ops::Mul<&InverseAngle<num_rational::BigRational>> for num_rational::BigRational {
+	type Output = InverseAngle<num_rational::BigRational>;
+	fn mul(self, rhs: &InverseAngle<num_rational::BigRational>) -> Self::Output {
+		InverseAngle{per_rad: self * rhs.per_rad.clone()}
+	}
+}
+/// Multiplying a unit value by a scalar value returns a unit value
+#[cfg(feature="num-rational")]
+impl core::ops::Mul<&InverseAngle<num_rational::BigRational>> for &num_rational::BigRational {
+	type Output = InverseAngle<num_rational::BigRational>;
+	fn mul(self, rhs: &InverseAngle<num_rational::BigRational>) -> Self::Output {
+		InverseAngle{per_rad: self.clone() * rhs.per_rad.clone()}
+	}
+}
+
+
+
+
+
+/// Multiplying a InverseAngle by an [nalgebra](https://crates.io/crates/nalgebra) 
+/// vector or matrix of unitless values returns a vector or matrix of InverseAngle 
+/// values (eg a direction vector multiplied by a InverseAngle)
+#[cfg(feature="nalgebra")]
+impl<T, R, C, S> core::ops::Mul<nalgebra::Matrix<T, R, C, S>> for InverseAngle<T> where 
+	T: NumLike+nalgebra::Scalar, R: nalgebra::Dim, C: nalgebra::Dim, S: nalgebra::RawStorage<T, R, C>, 
+	nalgebra::DefaultAllocator: nalgebra::allocator::Allocator<R, C> {
+	type Output = nalgebra::OMatrix<InverseAngle<T>, R, C>;
+	fn mul(self, rhs: nalgebra::Matrix<T, R, C, S>) -> Self::Output {
+		rhs.map(|x| InverseAngle{per_rad: self.per_rad.clone() * x})
+	}
+}
+/// Multiplying an [nalgebra](https://crates.io/crates/nalgebra) vector or matrix of 
+/// unitless values by a InverseAngle returns a vector or matrix of InverseAngle values
+#[cfg(feature="nalgebra")]
+impl<T, R, C, S> core::ops::Mul<InverseAngle<T>> for nalgebra::Matrix<T, R, C, S> where 
+	T: NumLike+nalgebra::Scalar, R: nalgebra::Dim, C: nalgebra::Dim, S: nalgebra::RawStorage<T, R, C>, 
+	nalgebra::DefaultAllocator: nalgebra::allocator::Allocator<R, C> {
+	type Output = nalgebra::OMatrix<InverseAngle<T>, R, C>;
+	fn mul(self, rhs: InverseAngle<T>) -> Self::Output {
+		self.map(|x| InverseAngle{per_rad: x * rhs.per_rad.clone()})
+	}
+}
+/// Dividing an [nalgebra](https://crates.io/crates/nalgebra) vector or matrix of 
+/// InverseAngle values by a InverseAngle returns a vector or matrix of unitless values
+#[cfg(feature="nalgebra")]
+impl<T, R, C, S> core::ops::Div<InverseAngle<T>> for nalgebra::Matrix<InverseAngle<T>, R, C, S> where 
+	T: NumLike+nalgebra::Scalar, R: nalgebra::Dim, C: nalgebra::Dim, S: nalgebra::RawStorage<InverseAngle<T>, R, C>, 
+	nalgebra::DefaultAllocator: nalgebra::allocator::Allocator<R, C> {
+	type Output = nalgebra::OMatrix<T, R, C>;
+	fn div(self, rhs: InverseAngle<T>) -> Self::Output {
+		self.map(|x| x.per_rad / rhs.per_rad.clone())
+	}
+}
+
+
+
+// InverseAngle * Time -> InverseAngularVelocity
+/// Multiplying a InverseAngle by a Time returns a value of type InverseAngularVelocity
+impl<T> core::ops::Mul<Time<T>> for InverseAngle<T> where T: NumLike {
+	type Output = InverseAngularVelocity<T>;
+	fn mul(self, rhs: Time<T>) -> Self::Output {
+		InverseAngularVelocity{s_per_rad: self.per_rad * rhs.s}
+	}
+}
+/// Multiplying a InverseAngle by a Time returns a value of type InverseAngularVelocity
+impl<T> core::ops::Mul<Time<T>> for &InverseAngle<T> where T: NumLike {
+	type Output = InverseAngularVelocity<T>;
+	fn mul(self, rhs: Time<T>) -> Self::Output {
+		InverseAngularVelocity{s_per_rad: self.per_rad.clone() * rhs.s}
+	}
+}
+/// Multiplying a InverseAngle by a Time returns a value of type InverseAngularVelocity
+impl<T> core::ops::Mul<&Time<T>> for InverseAngle<T> where T: NumLike {
+	type Output = InverseAngularVelocity<T>;
+	fn mul(self, rhs: &Time<T>) -> Self::Output {
+		InverseAngularVelocity{s_per_rad: self.per_rad * rhs.s.clone()}
+	}
+}
+/// Multiplying a InverseAngle by a Time returns a value of type InverseAngularVelocity
+impl<T> core::ops::Mul<&Time<T>> for &InverseAngle<T> where T: NumLike {
+	type Output = InverseAngularVelocity<T>;
+	fn mul(self, rhs: &Time<T>) -> Self::Output {
+		InverseAngularVelocity{s_per_rad: self.per_rad.clone() * rhs.s.clone()}
+	}
+}
+
+// InverseAngle / Angle -> InverseSolidAngle
+/// Dividing a InverseAngle by a Angle returns a value of type InverseSolidAngle
+impl<T> core::ops::Div<Angle<T>> for InverseAngle<T> where T: NumLike {
+	type Output = InverseSolidAngle<T>;
+	fn div(self, rhs: Angle<T>) -> Self::Output {
+		InverseSolidAngle{per_sr: self.per_rad / rhs.rad}
+	}
+}
+/// Dividing a InverseAngle by a Angle returns a value of type InverseSolidAngle
+impl<T> core::ops::Div<Angle<T>> for &InverseAngle<T> where T: NumLike {
+	type Output = InverseSolidAngle<T>;
+	fn div(self, rhs: Angle<T>) -> Self::Output {
+		InverseSolidAngle{per_sr: self.per_rad.clone() / rhs.rad}
+	}
+}
+/// Dividing a InverseAngle by a Angle returns a value of type InverseSolidAngle
+impl<T> core::ops::Div<&Angle<T>> for InverseAngle<T> where T: NumLike {
+	type Output = InverseSolidAngle<T>;
+	fn div(self, rhs: &Angle<T>) -> Self::Output {
+		InverseSolidAngle{per_sr: self.per_rad / rhs.rad.clone()}
+	}
+}
+/// Dividing a InverseAngle by a Angle returns a value of type InverseSolidAngle
+impl<T> core::ops::Div<&Angle<T>> for &InverseAngle<T> where T: NumLike {
+	type Output = InverseSolidAngle<T>;
+	fn div(self, rhs: &Angle<T>) -> Self::Output {
+		InverseSolidAngle{per_sr: self.per_rad.clone() / rhs.rad.clone()}
+	}
+}
+
+// InverseAngle * InverseAngle -> InverseSolidAngle
+/// Multiplying a InverseAngle by a InverseAngle returns a value of type InverseSolidAngle
+impl<T> core::ops::Mul<InverseAngle<T>> for InverseAngle<T> where T: NumLike {
+	type Output = InverseSolidAngle<T>;
+	fn mul(self, rhs: InverseAngle<T>) -> Self::Output {
+		InverseSolidAngle{per_sr: self.per_rad * rhs.per_rad}
+	}
+}
+/// Multiplying a InverseAngle by a InverseAngle returns a value of type InverseSolidAngle
+impl<T> core::ops::Mul<InverseAngle<T>> for &InverseAngle<T> where T: NumLike {
+	type Output = InverseSolidAngle<T>;
+	fn mul(self, rhs: InverseAngle<T>) -> Self::Output {
+		InverseSolidAngle{per_sr: self.per_rad.clone() * rhs.per_rad}
+	}
+}
+/// Multiplying a InverseAngle by a InverseAngle returns a value of type InverseSolidAngle
+impl<T> core::ops::Mul<&InverseAngle<T>> for InverseAngle<T> where T: NumLike {
+	type Output = InverseSolidAngle<T>;
+	fn mul(self, rhs: &InverseAngle<T>) -> Self::Output {
+		InverseSolidAngle{per_sr: self.per_rad * rhs.per_rad.clone()}
+	}
+}
+/// Multiplying a InverseAngle by a InverseAngle returns a value of type InverseSolidAngle
+impl<T> core::ops::Mul<&InverseAngle<T>> for &InverseAngle<T> where T: NumLike {
+	type Output = InverseSolidAngle<T>;
+	fn mul(self, rhs: &InverseAngle<T>) -> Self::Output {
+		InverseSolidAngle{per_sr: self.per_rad.clone() * rhs.per_rad.clone()}
+	}
+}
+
+// InverseAngle / InverseSolidAngle -> Angle
+/// Dividing a InverseAngle by a InverseSolidAngle returns a value of type Angle
+impl<T> core::ops::Div<InverseSolidAngle<T>> for InverseAngle<T> where T: NumLike {
+	type Output = Angle<T>;
+	fn div(self, rhs: InverseSolidAngle<T>) -> Self::Output {
+		Angle{rad: self.per_rad / rhs.per_sr}
+	}
+}
+/// Dividing a InverseAngle by a InverseSolidAngle returns a value of type Angle
+impl<T> core::ops::Div<InverseSolidAngle<T>> for &InverseAngle<T> where T: NumLike {
+	type Output = Angle<T>;
+	fn div(self, rhs: InverseSolidAngle<T>) -> Self::Output {
+		Angle{rad: self.per_rad.clone() / rhs.per_sr}
+	}
+}
+/// Dividing a InverseAngle by a InverseSolidAngle returns a value of type Angle
+impl<T> core::ops::Div<&InverseSolidAngle<T>> for InverseAngle<T> where T: NumLike {
+	type Output = Angle<T>;
+	fn div(self, rhs: &InverseSolidAngle<T>) -> Self::Output {
+		Angle{rad: self.per_rad / rhs.per_sr.clone()}
+	}
+}
+/// Dividing a InverseAngle by a InverseSolidAngle returns a value of type Angle
+impl<T> core::ops::Div<&InverseSolidAngle<T>> for &InverseAngle<T> where T: NumLike {
+	type Output = Angle<T>;
+	fn div(self, rhs: &InverseSolidAngle<T>) -> Self::Output {
+		Angle{rad: self.per_rad.clone() / rhs.per_sr.clone()}
+	}
+}
+
+// InverseAngle * SolidAngle -> Angle
+/// Multiplying a InverseAngle by a SolidAngle returns a value of type Angle
+impl<T> core::ops::Mul<SolidAngle<T>> for InverseAngle<T> where T: NumLike {
+	type Output = Angle<T>;
+	fn mul(self, rhs: SolidAngle<T>) -> Self::Output {
+		Angle{rad: self.per_rad * rhs.sr}
+	}
+}
+/// Multiplying a InverseAngle by a SolidAngle returns a value of type Angle
+impl<T> core::ops::Mul<SolidAngle<T>> for &InverseAngle<T> where T: NumLike {
+	type Output = Angle<T>;
+	fn mul(self, rhs: SolidAngle<T>) -> Self::Output {
+		Angle{rad: self.per_rad.clone() * rhs.sr}
+	}
+}
+/// Multiplying a InverseAngle by a SolidAngle returns a value of type Angle
+impl<T> core::ops::Mul<&SolidAngle<T>> for InverseAngle<T> where T: NumLike {
+	type Output = Angle<T>;
+	fn mul(self, rhs: &SolidAngle<T>) -> Self::Output {
+		Angle{rad: self.per_rad * rhs.sr.clone()}
+	}
+}
+/// Multiplying a InverseAngle by a SolidAngle returns a value of type Angle
+impl<T> core::ops::Mul<&SolidAngle<T>> for &InverseAngle<T> where T: NumLike {
+	type Output = Angle<T>;
+	fn mul(self, rhs: &SolidAngle<T>) -> Self::Output {
+		Angle{rad: self.per_rad.clone() * rhs.sr.clone()}
+	}
+}
+
+// InverseAngle * AngularVelocity -> Frequency
+/// Multiplying a InverseAngle by a AngularVelocity returns a value of type Frequency
+impl<T> core::ops::Mul<AngularVelocity<T>> for InverseAngle<T> where T: NumLike {
+	type Output = Frequency<T>;
+	fn mul(self, rhs: AngularVelocity<T>) -> Self::Output {
+		Frequency{Hz: self.per_rad * rhs.radps}
+	}
+}
+/// Multiplying a InverseAngle by a AngularVelocity returns a value of type Frequency
+impl<T> core::ops::Mul<AngularVelocity<T>> for &InverseAngle<T> where T: NumLike {
+	type Output = Frequency<T>;
+	fn mul(self, rhs: AngularVelocity<T>) -> Self::Output {
+		Frequency{Hz: self.per_rad.clone() * rhs.radps}
+	}
+}
+/// Multiplying a InverseAngle by a AngularVelocity returns a value of type Frequency
+impl<T> core::ops::Mul<&AngularVelocity<T>> for InverseAngle<T> where T: NumLike {
+	type Output = Frequency<T>;
+	fn mul(self, rhs: &AngularVelocity<T>) -> Self::Output {
+		Frequency{Hz: self.per_rad * rhs.radps.clone()}
+	}
+}
+/// Multiplying a InverseAngle by a AngularVelocity returns a value of type Frequency
+impl<T> core::ops::Mul<&AngularVelocity<T>> for &InverseAngle<T> where T: NumLike {
+	type Output = Frequency<T>;
+	fn mul(self, rhs: &AngularVelocity<T>) -> Self::Output {
+		Frequency{Hz: self.per_rad.clone() * rhs.radps.clone()}
+	}
+}
+
+// InverseAngle / Frequency -> InverseAngularVelocity
+/// Dividing a InverseAngle by a Frequency returns a value of type InverseAngularVelocity
+impl<T> core::ops::Div<Frequency<T>> for InverseAngle<T> where T: NumLike {
+	type Output = InverseAngularVelocity<T>;
+	fn div(self, rhs: Frequency<T>) -> Self::Output {
+		InverseAngularVelocity{s_per_rad: self.per_rad / rhs.Hz}
+	}
+}
+/// Dividing a InverseAngle by a Frequency returns a value of type InverseAngularVelocity
+impl<T> core::ops::Div<Frequency<T>> for &InverseAngle<T> where T: NumLike {
+	type Output = InverseAngularVelocity<T>;
+	fn div(self, rhs: Frequency<T>) -> Self::Output {
+		InverseAngularVelocity{s_per_rad: self.per_rad.clone() / rhs.Hz}
+	}
+}
+/// Dividing a InverseAngle by a Frequency returns a value of type InverseAngularVelocity
+impl<T> core::ops::Div<&Frequency<T>> for InverseAngle<T> where T: NumLike {
+	type Output = InverseAngularVelocity<T>;
+	fn div(self, rhs: &Frequency<T>) -> Self::Output {
+		InverseAngularVelocity{s_per_rad: self.per_rad / rhs.Hz.clone()}
+	}
+}
+/// Dividing a InverseAngle by a Frequency returns a value of type InverseAngularVelocity
+impl<T> core::ops::Div<&Frequency<T>> for &InverseAngle<T> where T: NumLike {
+	type Output = InverseAngularVelocity<T>;
+	fn div(self, rhs: &Frequency<T>) -> Self::Output {
+		InverseAngularVelocity{s_per_rad: self.per_rad.clone() / rhs.Hz.clone()}
+	}
+}
+
+// InverseAngle / InverseAngularVelocity -> Frequency
+/// Dividing a InverseAngle by a InverseAngularVelocity returns a value of type Frequency
+impl<T> core::ops::Div<InverseAngularVelocity<T>> for InverseAngle<T> where T: NumLike {
+	type Output = Frequency<T>;
+	fn div(self, rhs: InverseAngularVelocity<T>) -> Self::Output {
+		Frequency{Hz: self.per_rad / rhs.s_per_rad}
+	}
+}
+/// Dividing a InverseAngle by a InverseAngularVelocity returns a value of type Frequency
+impl<T> core::ops::Div<InverseAngularVelocity<T>> for &InverseAngle<T> where T: NumLike {
+	type Output = Frequency<T>;
+	fn div(self, rhs: InverseAngularVelocity<T>) -> Self::Output {
+		Frequency{Hz: self.per_rad.clone() / rhs.s_per_rad}
+	}
+}
+/// Dividing a InverseAngle by a InverseAngularVelocity returns a value of type Frequency
+impl<T> core::ops::Div<&InverseAngularVelocity<T>> for InverseAngle<T> where T: NumLike {
+	type Output = Frequency<T>;
+	fn div(self, rhs: &InverseAngularVelocity<T>) -> Self::Output {
+		Frequency{Hz: self.per_rad / rhs.s_per_rad.clone()}
+	}
+}
+/// Dividing a InverseAngle by a InverseAngularVelocity returns a value of type Frequency
+impl<T> core::ops::Div<&InverseAngularVelocity<T>> for &InverseAngle<T> where T: NumLike {
+	type Output = Frequency<T>;
+	fn div(self, rhs: &InverseAngularVelocity<T>) -> Self::Output {
+		Frequency{Hz: self.per_rad.clone() / rhs.s_per_rad.clone()}
+	}
+}
+
+impl<T> InverseAngle<T> where T: NumLike+From<u8> {
+	/// Returns the reciprocal of this InverseAngle value (ie 1 divided by 
+	/// this value) as a value of type Angle
+	pub fn recip(self) -> Angle<T> {
+		Angle{rad: T::from(1u8) / self.per_rad}
+	}
+}
+
+// 1/InverseAngle -> Angle
+/// Dividing a scalar value by a InverseAngle unit value returns a value of type Angle
+impl<T> core::ops::Div<InverseAngle<T>> for f64 where T: NumLike+From<f64> {
+	type Output = Angle<T>;
+	fn div(self, rhs: InverseAngle<T>) -> Self::Output {
+		Angle{rad: T::from(self) / rhs.per_rad}
+	}
+}
+/// Dividing a scalar value by a InverseAngle unit value returns a value of type Angle
+impl<T> core::ops::Div<InverseAngle<T>> for &f64 where T: NumLike+From<f64> {
+	type Output = Angle<T>;
+	fn div(self, rhs: InverseAngle<T>) -> Self::Output {
+		Angle{rad: T::from(self.clone()) / rhs.per_rad}
+	}
+}
+/// Dividing a scalar value by a InverseAngle unit value returns a value of type Angle
+impl<T> core::ops::Div<&InverseAngle<T>> for f64 where T: NumLike+From<f64> {
+	type Output = Angle<T>;
+	fn div(self, rhs: &InverseAngle<T>) -> Self::Output {
+		Angle{rad: T::from(self) / rhs.per_rad.clone()}
+	}
+}
+/// Dividing a scalar value by a InverseAngle unit value returns a value of type Angle
+impl<T> core::ops::Div<&InverseAngle<T>> for &f64 where T: NumLike+From<f64> {
+	type Output = Angle<T>;
+	fn div(self, rhs: &InverseAngle<T>) -> Self::Output {
+		Angle{rad: T::from(self.clone()) / rhs.per_rad.clone()}
+	}
+}
+
+// 1/InverseAngle -> Angle
+/// Dividing a scalar value by a InverseAngle unit value returns a value of type Angle
+impl<T> core::ops::Div<InverseAngle<T>> for f32 where T: NumLike+From<f32> {
+	type Output = Angle<T>;
+	fn div(self, rhs: InverseAngle<T>) -> Self::Output {
+		Angle{rad: T::from(self) / rhs.per_rad}
+	}
+}
+/// Dividing a scalar value by a InverseAngle unit value returns a value of type Angle
+impl<T> core::ops::Div<InverseAngle<T>> for &f32 where T: NumLike+From<f32> {
+	type Output = Angle<T>;
+	fn div(self, rhs: InverseAngle<T>) -> Self::Output {
+		Angle{rad: T::from(self.clone()) / rhs.per_rad}
+	}
+}
+/// Dividing a scalar value by a InverseAngle unit value returns a value of type Angle
+impl<T> core::ops::Div<&InverseAngle<T>> for f32 where T: NumLike+From<f32> {
+	type Output = Angle<T>;
+	fn div(self, rhs: &InverseAngle<T>) -> Self::Output {
+		Angle{rad: T::from(self) / rhs.per_rad.clone()}
+	}
+}
+/// Dividing a scalar value by a InverseAngle unit value returns a value of type Angle
+impl<T> core::ops::Div<&InverseAngle<T>> for &f32 where T: NumLike+From<f32> {
+	type Output = Angle<T>;
+	fn div(self, rhs: &InverseAngle<T>) -> Self::Output {
+		Angle{rad: T::from(self.clone()) / rhs.per_rad.clone()}
+	}
+}
+
+// 1/InverseAngle -> Angle
+/// Dividing a scalar value by a InverseAngle unit value returns a value of type Angle
+impl<T> core::ops::Div<InverseAngle<T>> for i64 where T: NumLike+From<i64> {
+	type Output = Angle<T>;
+	fn div(self, rhs: InverseAngle<T>) -> Self::Output {
+		Angle{rad: T::from(self) / rhs.per_rad}
+	}
+}
+/// Dividing a scalar value by a InverseAngle unit value returns a value of type Angle
+impl<T> core::ops::Div<InverseAngle<T>> for &i64 where T: NumLike+From<i64> {
+	type Output = Angle<T>;
+	fn div(self, rhs: InverseAngle<T>) -> Self::Output {
+		Angle{rad: T::from(self.clone()) / rhs.per_rad}
+	}
+}
+/// Dividing a scalar value by a InverseAngle unit value returns a value of type Angle
+impl<T> core::ops::Div<&InverseAngle<T>> for i64 where T: NumLike+From<i64> {
+	type Output = Angle<T>;
+	fn div(self, rhs: &InverseAngle<T>) -> Self::Output {
+		Angle{rad: T::from(self) / rhs.per_rad.clone()}
+	}
+}
+/// Dividing a scalar value by a InverseAngle unit value returns a value of type Angle
+impl<T> core::ops::Div<&InverseAngle<T>> for &i64 where T: NumLike+From<i64> {
+	type Output = Angle<T>;
+	fn div(self, rhs: &InverseAngle<T>) -> Self::Output {
+		Angle{rad: T::from(self.clone()) / rhs.per_rad.clone()}
+	}
+}
+
+// 1/InverseAngle -> Angle
+/// Dividing a scalar value by a InverseAngle unit value returns a value of type Angle
+impl<T> core::ops::Div<InverseAngle<T>> for i32 where T: NumLike+From<i32> {
+	type Output = Angle<T>;
+	fn div(self, rhs: InverseAngle<T>) -> Self::Output {
+		Angle{rad: T::from(self) / rhs.per_rad}
+	}
+}
+/// Dividing a scalar value by a InverseAngle unit value returns a value of type Angle
+impl<T> core::ops::Div<InverseAngle<T>> for &i32 where T: NumLike+From<i32> {
+	type Output = Angle<T>;
+	fn div(self, rhs: InverseAngle<T>) -> Self::Output {
+		Angle{rad: T::from(self.clone()) / rhs.per_rad}
+	}
+}
+/// Dividing a scalar value by a InverseAngle unit value returns a value of type Angle
+impl<T> core::ops::Div<&InverseAngle<T>> for i32 where T: NumLike+From<i32> {
+	type Output = Angle<T>;
+	fn div(self, rhs: &InverseAngle<T>) -> Self::Output {
+		Angle{rad: T::from(self) / rhs.per_rad.clone()}
+	}
+}
+/// Dividing a scalar value by a InverseAngle unit value returns a value of type Angle
+impl<T> core::ops::Div<&InverseAngle<T>> for &i32 where T: NumLike+From<i32> {
+	type Output = Angle<T>;
+	fn div(self, rhs: &InverseAngle<T>) -> Self::Output {
+		Angle{rad: T::from(self.clone()) / rhs.per_rad.clone()}
+	}
+}
+
+// 1/InverseAngle -> Angle
+/// Dividing a scalar value by a InverseAngle unit value returns a value of type Angle
+#[cfg(feature="num-bigfloat")]
+impl<T> core::ops::Div<InverseAngle<T>> for num_bigfloat::BigFloat where T: NumLike+From<num_bigfloat::BigFloat> {
+	type Output = Angle<T>;
+	fn div(self, rhs: InverseAngle<T>) -> Self::Output {
+		Angle{rad: T::from(self) / rhs.per_rad}
+	}
+}
+/// Dividing a scalar value by a InverseAngle unit value returns a value of type Angle
+#[cfg(feature="num-bigfloat")]
+impl<T> core::ops::Div<InverseAngle<T>> for &num_bigfloat::BigFloat where T: NumLike+From<num_bigfloat::BigFloat> {
+	type Output = Angle<T>;
+	fn div(self, rhs: InverseAngle<T>) -> Self::Output {
+		Angle{rad: T::from(self.clone()) / rhs.per_rad}
+	}
+}
+/// Dividing a scalar value by a InverseAngle unit value returns a value of type Angle
+#[cfg(feature="num-bigfloat")]
+impl<T> core::ops::Div<&InverseAngle<T>> for num_bigfloat::BigFloat where T: NumLike+From<num_bigfloat::BigFloat> {
+	type Output = Angle<T>;
+	fn div(self, rhs: &InverseAngle<T>) -> Self::Output {
+		Angle{rad: T::from(self) / rhs.per_rad.clone()}
+	}
+}
+/// Dividing a scalar value by a InverseAngle unit value returns a value of type Angle
+#[cfg(feature="num-bigfloat")]
+impl<T> core::ops::Div<&InverseAngle<T>> for &num_bigfloat::BigFloat where T: NumLike+From<num_bigfloat::BigFloat> {
+	type Output = Angle<T>;
+	fn div(self, rhs: &InverseAngle<T>) -> Self::Output {
+		Angle{rad: T::from(self.clone()) / rhs.per_rad.clone()}
+	}
+}
+
+// 1/InverseAngle -> Angle
+/// Dividing a scalar value by a InverseAngle unit value returns a value of type Angle
+#[cfg(feature="num-complex")]
+impl<T> core::ops::Div<InverseAngle<T>> for num_complex::Complex32 where T: NumLike+From<num_complex::Complex32> {
+	type Output = Angle<T>;
+	fn div(self, rhs: InverseAngle<T>) -> Self::Output {
+		Angle{rad: T::from(self) / rhs.per_rad}
+	}
+}
+/// Dividing a scalar value by a InverseAngle unit value returns a value of type Angle
+#[cfg(feature="num-complex")]
+impl<T> core::ops::Div<InverseAngle<T>> for &num_complex::Complex32 where T: NumLike+From<num_complex::Complex32> {
+	type Output = Angle<T>;
+	fn div(self, rhs: InverseAngle<T>) -> Self::Output {
+		Angle{rad: T::from(self.clone()) / rhs.per_rad}
+	}
+}
+/// Dividing a scalar value by a InverseAngle unit value returns a value of type Angle
+#[cfg(feature="num-complex")]
+impl<T> core::ops::Div<&InverseAngle<T>> for num_complex::Complex32 where T: NumLike+From<num_complex::Complex32> {
+	type Output = Angle<T>;
+	fn div(self, rhs: &InverseAngle<T>) -> Self::Output {
+		Angle{rad: T::from(self) / rhs.per_rad.clone()}
+	}
+}
+/// Dividing a scalar value by a InverseAngle unit value returns a value of type Angle
+#[cfg(feature="num-complex")]
+impl<T> core::ops::Div<&InverseAngle<T>> for &num_complex::Complex32 where T: NumLike+From<num_complex::Complex32> {
+	type Output = Angle<T>;
+	fn div(self, rhs: &InverseAngle<T>) -> Self::Output {
+		Angle{rad: T::from(self.clone()) / rhs.per_rad.clone()}
+	}
+}
+
+// 1/InverseAngle -> Angle
+/// Dividing a scalar value by a InverseAngle unit value returns a value of type Angle
+#[cfg(feature="num-complex")]
+impl<T> core::ops::Div<InverseAngle<T>> for num_complex::Complex64 where T: NumLike+From<num_complex::Complex64> {
+	type Output = Angle<T>;
+	fn div(self, rhs: InverseAngle<T>) -> Self::Output {
+		Angle{rad: T::from(self) / rhs.per_rad}
+	}
+}
+/// Dividing a scalar value by a InverseAngle unit value returns a value of type Angle
+#[cfg(feature="num-complex")]
+impl<T> core::ops::Div<InverseAngle<T>> for &num_complex::Complex64 where T: NumLike+From<num_complex::Complex64> {
+	type Output = Angle<T>;
+	fn div(self, rhs: InverseAngle<T>) -> Self::Output {
+		Angle{rad: T::from(self.clone()) / rhs.per_rad}
+	}
+}
+/// Dividing a scalar value by a InverseAngle unit value returns a value of type Angle
+#[cfg(feature="num-complex")]
+impl<T> core::ops::Div<&InverseAngle<T>> for num_complex::Complex64 where T: NumLike+From<num_complex::Complex64> {
+	type Output = Angle<T>;
+	fn div(self, rhs: &InverseAngle<T>) -> Self::Output {
+		Angle{rad: T::from(self) / rhs.per_rad.clone()}
+	}
+}
+/// Dividing a scalar value by a InverseAngle unit value returns a value of type Angle
+#[cfg(feature="num-complex")]
+impl<T> core::ops::Div<&InverseAngle<T>> for &num_complex::Complex64 where T: NumLike+From<num_complex::Complex64> {
+	type Output = Angle<T>;
+	fn div(self, rhs: &InverseAngle<T>) -> Self::Output {
+		Angle{rad: T::from(self.clone()) / rhs.per_rad.clone()}
+	}
+}
+
+// 1/InverseAngle -> Angle
+/// Dividing a scalar value by a InverseAngle unit value returns a value of type Angle
+#[cfg(feature="num-rational")]
+impl<T> core::ops::Div<InverseAngle<T>> for num_rational::Rational64 where T: NumLike+From<num_rational::Rational64> {
+	type Output = Angle<T>;
+	fn div(self, rhs: InverseAngle<T>) -> Self::Output {
+		Angle{rad: T::from(self) / rhs.per_rad}
+	}
+}
+/// Dividing a scalar value by a InverseAngle unit value returns a value of type Angle
+#[cfg(feature="num-rational")]
+impl<T> core::ops::Div<InverseAngle<T>> for &num_rational::Rational64 where T: NumLike+From<num_rational::Rational64> {
+	type Output = Angle<T>;
+	fn div(self, rhs: InverseAngle<T>) -> Self::Output {
+		Angle{rad: T::from(self.clone()) / rhs.per_rad}
+	}
+}
+/// Dividing a scalar value by a InverseAngle unit value returns a value of type Angle
+#[cfg(feature="num-rational")]
+impl<T> core::ops::Div<&InverseAngle<T>> for num_rational::Rational64 where T: NumLike+From<num_rational::Rational64> {
+	type Output = Angle<T>;
+	fn div(self, rhs: &InverseAngle<T>) -> Self::Output {
+		Angle{rad: T::from(self) / rhs.per_rad.clone()}
+	}
+}
+/// Dividing a scalar value by a InverseAngle unit value returns a value of type Angle
+#[cfg(feature="num-rational")]
+impl<T> core::ops::Div<&InverseAngle<T>> for &num_rational::Rational64 where T: NumLike+From<num_rational::Rational64> {
+	type Output = Angle<T>;
+	fn div(self, rhs: &InverseAngle<T>) -> Self::Output {
+		Angle{rad: T::from(self.clone()) / rhs.per_rad.clone()}
+	}
+}
+
+// 1/InverseAngle -> Angle
+/// Dividing a scalar value by a InverseAngle unit value returns a value of type Angle
+#[cfg(feature="num-rational")]
+impl<T> core::ops::Div<InverseAngle<T>> for num_rational::BigRational where T: NumLike+From<num_rational::BigRational> {
+	type Output = Angle<T>;
+	fn div(self, rhs: InverseAngle<T>) -> Self::Output {
+		Angle{rad: T::from(self) / rhs.per_rad}
+	}
+}
+/// Dividing a scalar value by a InverseAngle unit value returns a value of type Angle
+#[cfg(feature="num-rational")]
+impl<T> core::ops::Div<InverseAngle<T>> for &num_rational::BigRational where T: NumLike+From<num_rational::BigRational> {
+	type Output = Angle<T>;
+	fn div(self, rhs: InverseAngle<T>) -> Self::Output {
+		Angle{rad: T::from(self.clone()) / rhs.per_rad}
+	}
+}
+/// Dividing a scalar value by a InverseAngle unit value returns a value of type Angle
+#[cfg(feature="num-rational")]
+impl<T> core::ops::Div<&InverseAngle<T>> for num_rational::BigRational where T: NumLike+From<num_rational::BigRational> {
+	type Output = Angle<T>;
+	fn div(self, rhs: &InverseAngle<T>) -> Self::Output {
+		Angle{rad: T::from(self) / rhs.per_rad.clone()}
+	}
+}
+/// Dividing a scalar value by a InverseAngle unit value returns a value of type Angle
+#[cfg(feature="num-rational")]
+impl<T> core::ops::Div<&InverseAngle<T>> for &num_rational::BigRational where T: NumLike+From<num_rational::BigRational> {
+	type Output = Angle<T>;
+	fn div(self, rhs: &InverseAngle<T>) -> Self::Output {
+		Angle{rad: T::from(self.clone()) / rhs.per_rad.clone()}
+	}
+}
+
+/// The inverse of area unit type, defined as inverse square meters in SI units
+#[derive(UnitStruct, Debug, Clone, Default)]
+#[si(no_display)]
+#[cfg_attr(all(feature="serde", not(feature="serde-unit-tag")), derive(Serialize, Deserialize))]
+#[repr(transparent)]
+pub struct InverseArea<T: NumLike>{
+	/// The value of this Inverse area in inverse square meters
+	pub per_m2: T
+}
+
+impl<T> InverseArea<T> where T: NumLike {
+
+	/// Returns the standard unit name of inverse area: "inverse square meters"
+	pub fn unit_name() -> &'static str { "inverse square meters" }
+	
+	/// Returns the abbreviated name or symbol of inverse area: "1/m²" for inverse square meters
+	pub fn unit_symbol() -> &'static str { "1/m²" }
+
+	/// Returns the exponents of the SI base units of inverse area (1/m^2)
+	pub const fn dimension() -> super::Dimension { super::Dimension{length: -2, mass: 0, time: 0, current: 0, temperature: 0, amount: 0, luminosity: 0} }
+
+	/// Returns a wrapper that formats this inverse area value with the full unit name instead of 
+	/// the unit symbol, eg "5 inverse square meters" (the unit name is not singularized for values of 1)
+	pub fn display_named(&self) -> super::NamedDisplay<'_, T> {
+		super::NamedDisplay::new(&self.per_m2, Self::unit_name())
+	}
+
+	/// Returns a new inverse area value from the given number of inverse square meters (same as 
+	/// `from_per_m2(...)`), for any number-like type. This is useful in generic code 
+	/// that works with several unit types
+	///
+	/// # Arguments
+	/// * `value` - Any number-like type, representing a quantity of inverse square meters
+	pub const fn new(value: T) -> Self { InverseArea{per_m2: value} }
+
+	/// Returns a new inverse area value from the given number of inverse square meters. Unlike
+	/// `from_per_m2(...)`, this is a `const fn`, so it can be used to declare `const` and `static` values
+	///
+	/// # Arguments
+	/// * `per_m2` - Any number-like type, representing a quantity of inverse square meters
+	pub const fn from_per_m2_const(per_m2: T) -> Self { InverseArea{per_m2} }
+
+	/// Returns a inverse area value with a different number type, by applying the 
+	/// given function to the value in inverse square meters (eg `.map(|x| x as f32)` to convert 
+	/// from `f64` to `f32`)
+	///
+	/// # Arguments
+	/// * `f` - Function that converts the number of inverse square meters to the new number type
+	pub fn map<U, F>(self, f: F) -> InverseArea<U> where U: NumLike, F: FnOnce(T) -> U {
+		InverseArea{per_m2: f(self.per_m2)}
+	}
+
+	/// Returns a inverse area value with a different number type, converted 
+	/// losslessly with the `From` trait (eg from `f32` to `f64`)
+	pub fn cast<U>(self) -> InverseArea<U> where U: NumLike+From<T> {
+		InverseArea{per_m2: U::from(self.per_m2)}
+	}
+
+	/// Returns a reference to the value of this inverse area in inverse square meters
+	pub fn as_raw(&self) -> &T {
+		&self.per_m2
+	}
+
+	/// Returns a mutable reference to the value of this inverse area in inverse square meters, 
+	/// eg for passing to numeric libraries that modify values in place. The number 
+	/// is always in inverse square meters, so changing it directly does not change the unit
+	pub fn raw_mut(&mut self) -> &mut T {
+		&mut self.per_m2
+	}
+
+	/// Returns a slice of numbers in inverse square meters as a slice of inverse area values, 
+	/// without copying
+	///
+	/// # Arguments
+	/// * `values` - Slice of numbers, representing quantities of inverse square meters
+	pub fn wrap_slice(values: &[T]) -> &[Self] {
+		// SAFETY: InverseArea<T> is #[repr(transparent)], so it has the same memory layout as T
+		unsafe { core::slice::from_raw_parts(values.as_ptr() as *const Self, values.len()) }
+	}
+
+	/// Returns a mutable slice of numbers in inverse square meters as a mutable slice of 
+	/// inverse area values, without copying
+	///
+	/// # Arguments
+	/// * `values` - Slice of numbers, representing quantities of inverse square meters
+	pub fn wrap_slice_mut(values: &mut [T]) -> &mut [Self] {
+		// SAFETY: InverseArea<T> is #[repr(transparent)], so it has the same memory layout as T
+		unsafe { core::slice::from_raw_parts_mut(values.as_mut_ptr() as *mut Self, values.len()) }
+	}
+
+	/// Returns the ratio of this inverse area value to another inverse area value 
+	/// (same as `self / other`). Dividing a unit value by another value of the same 
+	/// unit type cancels the units, so the ratio is a dimensionless number
+	///
+	/// # Arguments
+	/// * `other` - The inverse area value to divide by
+	pub fn ratio(self, other: Self) -> T {
+		self.per_m2 / other.per_m2
+	}
+
+	/// Returns the ratio of this inverse area value to another inverse area value 
+	/// as a `Dimensionless` value, instead of a bare number like `ratio(...)` does
+	///
+	/// # Arguments
+	/// * `other` - The inverse area value to divide by
+	pub fn dimensionless_ratio(self, other: Self) -> super::base::Dimensionless<T> {
+		super::base::Dimensionless{value: self.per_m2 / other.per_m2}
+	}
+
+	/// Returns this inverse area value multiplied by a dimensionless factor 
+	/// (same as `self * factor`)
+	///
+	/// # Arguments
+	/// * `factor` - The number to multiply by
+	pub fn scale(self, factor: T) -> Self {
+		InverseArea{per_m2: self.per_m2 * factor}
+	}
+	
+	/// Returns a new inverse area value from the given number of inverse square meters
+	///
+	/// # Arguments
+	/// * `per_m2` - Any number-like type, representing a quantity of inverse square meters
+	pub fn from_per_m2(per_m2: T) -> Self { InverseArea{per_m2: per_m2} }
+	
+	/// Returns a copy of this inverse area value in inverse square meters
+	pub fn to_per_m2(&self) -> T { self.per_m2.clone() }
+
+	/// Returns a new inverse area value from the given number of inverse square meters
+	///
+	/// # Arguments
+	/// * `per_square_meter` - Any number-like type, representing a quantity of inverse square meters
+	pub fn from_per_square_meter(per_square_meter: T) -> Self { InverseArea{per_m2: per_square_meter} }
+	
+	/// Returns a copy of this inverse area value in inverse square meters
+	pub fn to_per_square_meter(&self) -> T { self.per_m2.clone() }
+
+}
+
+/// Formats inverse area values as a number followed by its unit symbol, eg "1500 1/m²"
+/// (complex numbers are wrapped in parentheses, eg "(1+2i) 1/m²").
+/// The alternate flag (`{:#}`) scales the number by the nearest SI prefix where the unit symbol allows it
+impl<T> fmt::Display for InverseArea<T> where T: NumLike {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		if f.alternate() {
+			return super::fmt_with_si_prefix(f, &self.per_m2, Self::unit_symbol(), false);
+		}
+		super::fmt_unit_value(f, &self.per_m2, Self::unit_symbol())
+	}
+}
+
+/// Formats inverse area values in exponential notation followed by the unit symbol, eg "1.5e3 1/m²"
+impl<T> fmt::LowerExp for InverseArea<T> where T: NumLike+fmt::LowerExp {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		fmt::LowerExp::fmt(&self.per_m2, f)?;
+		write!(f, " {}", Self::unit_symbol())
+	}
+}
+
+/// Formats inverse area values in exponential notation followed by the unit symbol, eg "1.5E3 1/m²"
+impl<T> fmt::UpperExp for InverseArea<T> where T: NumLike+fmt::UpperExp {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		fmt::UpperExp::fmt(&self.per_m2, f)?;
+		write!(f, " {}", Self::unit_symbol())
+	}
+}
+
+impl<T> super::SiUnit for InverseArea<T> where T: NumLike {
+	type Number = T;
+	fn unit_name(&self) -> &'static str { Self::unit_name() }
+	fn unit_symbol(&self) -> &'static str { Self::unit_symbol() }
+	fn raw(&self) -> &T { &self.per_m2 }
+	fn dimension(&self) -> super::Dimension { Self::dimension() }
+	fn from_raw(value: T) -> Self { InverseArea{per_m2: value} }
+	fn into_raw(self) -> T { self.per_m2 }
+}
+
+#[cfg(feature="alloc")]
+impl<T> InverseArea<T> where T: NumLike {
+	/// Returns this inverse area value in the canonical text format "<value> <symbol>", eg "5 1/m²".
+	/// Unlike `Display`, this format never changes with precision or alternate flags, and it is the
+	/// input format expected by `from_str(...)`, so `x.canonical_string().parse()` returns the same value
+	pub fn canonical_string(&self) -> alloc::string::String {
+		alloc::format!("{} {}", self.per_m2, Self::unit_symbol())
+	}
+
+	/// Returns a `Vec` of inverse area values copied from the given slice of numbers
+	///
+	/// # Arguments
+	/// * `values` - Slice of numbers, representing quantities of inverse square meters
+	pub fn from_per_m2_slice(values: &[T]) -> alloc::vec::Vec<Self> {
+		values.iter().map(|x| InverseArea{per_m2: x.clone()}).collect()
+	}
+}
+
+/// Parses a inverse area value from a number followed by its unit symbol, eg "5 1/m²"
+impl<T> core::str::FromStr for InverseArea<T> where T: NumLike+core::str::FromStr {
+	type Err = SiParseError;
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		Ok(InverseArea{per_m2: super::parse_unit_value(s, Self::unit_symbol())?})
+	}
+}
+
+/// Parses a inverse area value from a number followed by its unit symbol, eg "5 1/m²" 
+/// (same as `str::parse()`)
+impl<T> core::convert::TryFrom<&str> for InverseArea<T> where T: NumLike+core::str::FromStr {
+	type Error = SiParseError;
+	fn try_from(s: &str) -> Result<Self, Self::Error> {
+		<Self as core::str::FromStr>::from_str(s)
+	}
+}
+
+#[cfg(feature="num-traits")]
+impl<T> InverseArea<T> where T: NumLike+num_traits::Euclid {
+
+	/// Returns the Euclidean remainder of dividing this inverse area value by another, 
+	/// which is never negative (requires the `num-traits` feature)
+	///
+	/// # Arguments
+	/// * `rhs` - The inverse area value to divide by
+	pub fn rem_euclid(self, rhs: Self) -> Self {
+		InverseArea{per_m2: self.per_m2.rem_euclid(&rhs.per_m2)}
+	}
+
+	/// Returns the Euclidean quotient of dividing this inverse area value by another, 
+	/// ie the whole number of times that `rhs` fits into this value, rounded such that 
+	/// `rem_euclid(...)` is never negative (requires the `num-traits` feature)
+	///
+	/// # Arguments
+	/// * `rhs` - The inverse area value to divide by
+	pub fn div_euclid(self, rhs: Self) -> T {
+		self.per_m2.div_euclid(&rhs.per_m2)
+	}
+}
+
+/// Summing inverse area values returns their total as a inverse area value (or zero if there are no values)
+impl<T> core::iter::Sum<InverseArea<T>> for InverseArea<T> where T: NumLike+core::iter::Sum {
+	fn sum<I: Iterator<Item=InverseArea<T>>>(iter: I) -> Self {
+		InverseArea{per_m2: iter.map(|x| x.per_m2).sum()}
+	}
+}
+/// Summing inverse area values returns their total as a inverse area value (or zero if there are no values)
+impl<'a, T> core::iter::Sum<&'a InverseArea<T>> for InverseArea<T> where T: NumLike+core::iter::Sum+'a {
+	fn sum<I: Iterator<Item=&'a InverseArea<T>>>(iter: I) -> Self {
+		InverseArea{per_m2: iter.map(|x| x.per_m2.clone()).sum()}
+	}
+}
+
+impl<T> InverseArea<T> where T: NumLike+From<f64> {
+
+	/// Returns the weighted average of the given inverse area values, where each 
+	/// value is paired with its weight (weights do not need to add up to 1). If the 
+	/// total weight is zero (including when `values` is empty), then zero is returned
+	///
+	/// # Arguments
+	/// * `values` - Slice of (inverse area value, weight) pairs
+	pub fn weighted_mean(values: &[(Self, f64)]) -> Self {
+		let total_weight: f64 = values.iter().map(|(_, weight)| *weight).sum();
+		if total_weight == 0.0 {
+			return InverseArea{per_m2: T::from(0.0)};
+		}
+		let total = values.iter().fold(T::from(0.0),
+			|total, (value, weight)| total + value.per_m2.clone() * T::from(*weight));
+		InverseArea{per_m2: total / T::from(total_weight)}
+	}
+}
+
+impl<T> InverseArea<T> where T: NumLike+PartialEq+From<u8> {
+
+	/// Divides this inverse area value by another unit value (eg `distance.checked_div(time)` 
+	/// returns a velocity), returning `None` if the divisor is zero instead of producing 
+	/// infinity or NaN (or panicking, for integer number types). This works for every unit 
+	/// type that this inverse area value can be divided by with the `/` operator
+	///
+	/// # Arguments
+	/// * `rhs` - The unit value to divide by
+	pub fn checked_div<R>(self, rhs: R) -> Option<<Self as core::ops::Div<R>>::Output> 
+		where Self: core::ops::Div<R>, R: super::SiUnit<Number=T>
+	{
+		if *rhs.raw() == T::from(0u8) {
+			return None;
+		}
+		Some(self / rhs)
+	}
+}
+
+/// Multiplying a inverse area value by a dimensionless value returns a inverse area value
+impl<T> core::ops::Mul<super::base::Dimensionless<T>> for InverseArea<T> where T: NumLike {
+	type Output = InverseArea<T>;
+	fn mul(self, rhs: super::base::Dimensionless<T>) -> Self::Output {
+		InverseArea{per_m2: self.per_m2 * rhs.value}
+	}
+}
+/// Multiplying a dimensionless value by a inverse area value returns a inverse area value
+impl<T> core::ops::Mul<InverseArea<T>> for super::base::Dimensionless<T> where T: NumLike {
+	type Output = InverseArea<T>;
+	fn mul(self, rhs: InverseArea<T>) -> Self::Output {
+		InverseArea{per_m2: self.value * rhs.per_m2}
+	}
+}
+/// Dividing a inverse area value by a dimensionless value returns a inverse area value
+impl<T> core::ops::Div<super::base::Dimensionless<T>> for InverseArea<T> where T: NumLike {
+	type Output = InverseArea<T>;
+	fn div(self, rhs: super::base::Dimensionless<T>) -> Self::Output {
+		InverseArea{per_m2: self.per_m2 / rhs.value}
+	}
+}
+
+/// The remainder of dividing a inverse area value by another inverse area value is a inverse area value
+impl<T> core::ops::Rem<InverseArea<T>> for InverseArea<T> where T: NumLike+core::ops::Rem<Output=T> {
+	type Output = InverseArea<T>;
+	fn rem(self, rhs: InverseArea<T>) -> Self::Output {
+		InverseArea{per_m2: self.per_m2 % rhs.per_m2}
+	}
+}
+/// The remainder of dividing a inverse area value by another inverse area value is a inverse area value
+impl<T> core::ops::Rem<&InverseArea<T>> for InverseArea<T> where T: NumLike+core::ops::Rem<Output=T> {
+	type Output = InverseArea<T>;
+	fn rem(self, rhs: &InverseArea<T>) -> Self::Output {
+		InverseArea{per_m2: self.per_m2 % rhs.per_m2.clone()}
+	}
+}
+/// The remainder of dividing a inverse area value by another inverse area value is a inverse area value
+impl<T> core::ops::Rem<InverseArea<T>> for &InverseArea<T> where T: NumLike+core::ops::Rem<Output=T> {
+	type Output = InverseArea<T>;
+	fn rem(self, rhs: InverseArea<T>) -> Self::Output {
+		InverseArea{per_m2: self.per_m2.clone() % rhs.per_m2}
+	}
+}
+/// The remainder of dividing a inverse area value by another inverse area value is a inverse area value
+impl<T> core::ops::Rem<&InverseArea<T>> for &InverseArea<T> where T: NumLike+core::ops::Rem<Output=T> {
+	type Output = InverseArea<T>;
+	fn rem(self, rhs: &InverseArea<T>) -> Self::Output {
+		InverseArea{per_m2: self.per_m2.clone() % rhs.per_m2.clone()}
+	}
+}
+
+/// Serializes a inverse area value together with its unit symbol, eg 
+/// `{"value": 1.5, "unit": "1/m²"}` (requires the `serde-unit-tag` feature)
+#[cfg(feature="serde-unit-tag")]
+impl<T> serde::Serialize for InverseArea<T> where T: NumLike+serde::Serialize {
+	fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+		super::serialize_with_unit_tag(&self.per_m2, Self::unit_symbol(), serializer)
+	}
+}
+/// Deserializes a inverse area value from a value and unit symbol, eg 
+/// `{"value": 1.5, "unit": "1/m²"}`, returning an error if the unit symbol 
+/// does not match (requires the `serde-unit-tag` feature)
+#[cfg(feature="serde-unit-tag")]
+impl<'de, T> serde::Deserialize<'de> for InverseArea<T> where T: NumLike+serde::Deserialize<'de> {
+	fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+		Ok(InverseArea{per_m2: super::deserialize_with_unit_tag(Self::unit_symbol(), deserializer)?})
+	}
+}
+
+/// The additive identity of inverse area values is zero inverse square meters (requires the `num-traits` feature)
+#[cfg(feature="num-traits")]
+impl<T> num_traits::Zero for InverseArea<T> where T: NumLike+num_traits::Zero {
+	fn zero() -> Self { InverseArea{per_m2: T::zero()} }
+	fn is_zero(&self) -> bool { self.per_m2.is_zero() }
+}
+
+#[cfg(feature="num-traits")]
+impl<T> InverseArea<T> where T: NumLike+num_traits::Signed {
+
+	/// Returns the sign of this inverse area value as a dimensionless number: 1 if it is 
+	/// positive, -1 if it is negative, and 0 if it is zero for integer number types 
+	/// (for floating-point number types, +0.0 returns 1.0, -0.0 returns -1.0, and NaN returns 
+	/// NaN) (requires the `num-traits` feature)
+	pub fn signum(self) -> T {
+		self.per_m2.signum()
+	}
+}
+
+impl<T> InverseArea<T> where T: NumLike+PartialOrd {
+
+	/// Returns the absolute value of this inverse area value, ie its magnitude without a minus sign
+	/// (for floating-point number types, -0.0 returns +0.0)
+	pub fn abs(self) -> Self {
+		let negated = -self.per_m2.clone();
+		if self.per_m2 < negated {
+			InverseArea{per_m2: negated}
+		} else if self.per_m2 == negated {
+			// zero, which is its own negation (adding +0.0 turns -0.0 into +0.0)
+			InverseArea{per_m2: negated + self.per_m2}
+		} else {
+			self
+		}
+	}
+
+	/// Returns the lesser of this inverse area value and the given inverse area value
+	///
+	/// # Arguments
+	/// * `other` - The inverse area value to compare with this one
+	pub fn min(self, other: Self) -> Self {
+		if other.per_m2 < self.per_m2 { other } else { self }
+	}
+
+	/// Returns the greater of this inverse area value and the given inverse area value
+	///
+	/// # Arguments
+	/// * `other` - The inverse area value to compare with this one
+	pub fn max(self, other: Self) -> Self {
+		if other.per_m2 > self.per_m2 { other } else { self }
+	}
+
+	/// Returns the least of the given inverse area values, or `None` if there are none. 
+	/// Unlike `Iterator::min()`, this does not require `Ord`, so it works for 
+	/// floating-point numbers. NaN values are skipped (so the result is `None` if all 
+	/// values are NaN), and the first of several equal values is returned
+	///
+	/// # Arguments
+	/// * `values` - Any iterator or collection of inverse area values
+	pub fn min_of<I>(values: I) -> Option<Self> where I: IntoIterator<Item=Self> {
+		values.into_iter().filter(|x| x.per_m2.partial_cmp(&x.per_m2).is_some()).reduce(Self::min)
+	}
+
+	/// Returns the greatest of the given inverse area values, or `None` if there are none. 
+	/// Unlike `Iterator::max()`, this does not require `Ord`, so it works for 
+	/// floating-point numbers. NaN values are skipped (so the result is `None` if all 
+	/// values are NaN), and the first of several equal values is returned
+	///
+	/// # Arguments
+	/// * `values` - Any iterator or collection of inverse area values
+	pub fn max_of<I>(values: I) -> Option<Self> where I: IntoIterator<Item=Self> {
+		values.into_iter().filter(|x| x.per_m2.partial_cmp(&x.per_m2).is_some()).reduce(Self::max)
+	}
+
+	/// Returns this inverse area value restricted to the range from `lo` to `hi`
+	///
+	/// # Arguments
+	/// * `lo` - The minimum inverse area value
+	/// * `hi` - The maximum inverse area value
+	///
+	/// # Panics
+	/// Panics if `lo` is greater than `hi` (or if either of them is NaN)
+	pub fn clamp(self, lo: Self, hi: Self) -> Self {
+		assert!(lo.per_m2 <= hi.per_m2, "lo must be less than or equal to hi");
+		if self.per_m2 < lo.per_m2 { lo } else if self.per_m2 > hi.per_m2 { hi } else { self }
+	}
+
+	/// Returns this inverse area value with its magnitude capped at `max`, keeping its 
+	/// sign (eg for capping a speed), or this value unchanged if its magnitude does not 
+	/// exceed `max`. For complex-valued inverse area, use `clamp_norm(...)` instead, 
+	/// which caps the magnitude while keeping the phase
+	///
+	/// # Arguments
+	/// * `max` - The maximum magnitude (should not be negative)
+	pub fn clamp_magnitude(self, max: Self) -> Self {
+		let negated_max = -max.per_m2.clone();
+		if self.per_m2 > max.per_m2 { 
+			max 
+		} else if self.per_m2 < negated_max { 
+			InverseArea{per_m2: negated_max} 
+		} else { 
+			self 
+		}
+	}
+
+	/// Returns true if this inverse area value differs from the given inverse area 
+	/// value by no more than the given absolute tolerance (which is also a 
+	/// inverse area value, so the comparison is dimensionally safe). NaN is never 
+	/// equal to anything. See also the `assert_unit_eq!(...)` macro.
+	///
+	/// # Arguments
+	/// * `other` - The inverse area value to compare with this one
+	/// * `tolerance` - The largest allowed difference between the two values
+	pub fn eq_within(self, other: Self, tolerance: Self) -> bool {
+		let diff = self.per_m2 - other.per_m2;
+		diff <= tolerance.per_m2 && -diff <= tolerance.per_m2
+	}
+}
+
+#[cfg(feature="num-complex")]
+impl InverseArea<num_complex::Complex32> {
+	/// Returns the magnitude (aka norm or absolute value) of this complex inverse area 
+	/// value as a real-valued inverse area (requires the `num-complex` feature)
+	pub fn magnitude(self) -> InverseArea<f32> {
+		InverseArea{per_m2: self.per_m2.norm()}
+	}
+
+	/// Returns this complex inverse area value scaled down so that its magnitude is 
+	/// at most `max` (keeping its phase), or this value unchanged if its magnitude does 
+	/// not exceed `max`. This is the complex equivalent of `clamp_magnitude(...)` (requires 
+	/// the `num-complex` feature)
+	///
+	/// # Arguments
+	/// * `max` - The maximum magnitude (should not be negative)
+	pub fn clamp_norm(self, max: InverseArea<f32>) -> Self {
+		let norm = self.per_m2.norm();
+		if norm > max.per_m2 {
+			InverseArea{per_m2: self.per_m2 * (max.per_m2 / norm)}
+		} else {
+			self
+		}
+	}
+}
+
+#[cfg(feature="num-complex")]
+impl InverseArea<num_complex::Complex64> {
+	/// Returns the magnitude (aka norm or absolute value) of this complex inverse area 
+	/// value as a real-valued inverse area (requires the `num-complex` feature)
+	pub fn magnitude(self) -> InverseArea<f64> {
+		InverseArea{per_m2: self.per_m2.norm()}
+	}
+
+	/// Returns this complex inverse area value scaled down so that its magnitude is 
+	/// at most `max` (keeping its phase), or this value unchanged if its magnitude does 
+	/// not exceed `max`. This is the complex equivalent of `clamp_magnitude(...)` (requires 
+	/// the `num-complex` feature)
+	///
+	/// # Arguments
+	/// * `max` - The maximum magnitude (should not be negative)
+	pub fn clamp_norm(self, max: InverseArea<f64>) -> Self {
+		let norm = self.per_m2.norm();
+		if norm > max.per_m2 {
+			InverseArea{per_m2: self.per_m2 * (max.per_m2 / norm)}
+		} else {
+			self
+		}
+	}
+}
+
+/// Rounding and other floating-point functions (requires the `num-traits` feature, 
+/// and either the `std` or `libm` feature)
+#[cfg(all(feature="num-traits", any(feature="std", feature="libm")))]
+impl<T> InverseArea<T> where T: NumLike+num_traits::Float {
+
+	/// Returns this inverse area value rounded to the nearest whole number of inverse square meters 
+	/// (half-way cases are rounded away from zero)
+	pub fn round(self) -> Self {
+		InverseArea{per_m2: self.per_m2.round()}
+	}
+
+	/// Returns this inverse area value rounded down to a whole number of inverse square meters
+	pub fn floor(self) -> Self {
+		InverseArea{per_m2: self.per_m2.floor()}
+	}
+
+	/// Returns this inverse area value rounded up to a whole number of inverse square meters
+	pub fn ceil(self) -> Self {
+		InverseArea{per_m2: self.per_m2.ceil()}
+	}
+
+	/// Returns this inverse area value rounded towards zero to a whole number of inverse square meters
+	pub fn trunc(self) -> Self {
+		InverseArea{per_m2: self.per_m2.trunc()}
+	}
+
+	/// Returns this inverse area value rounded to the nearest multiple of the given step 
+	/// (half-way cases are rounded away from zero)
+	///
+	/// # Arguments
+	/// * `step` - The inverse area value to round to a multiple of
+	pub fn round_to(self, step: Self) -> Self {
+		InverseArea{per_m2: (self.per_m2 / step.per_m2).round() * step.per_m2}
+	}
+
+	/// Fused multiply-add: returns `self * a + b` computed with only one rounding 
+	/// error, which is more accurate (and on some hardware faster) than a separate 
+	/// multiplication and addition
+	///
+	/// # Arguments
+	/// * `a` - The scalar value to multiply this inverse area value by
+	/// * `b` - The inverse area value to add to the product
+	pub fn mul_add(self, a: T, b: Self) -> Self {
+		InverseArea{per_m2: self.per_m2.mul_add(a, b.per_m2)}
+	}
+
+	/// Returns true if this inverse area value is neither infinite nor NaN
+	pub fn is_finite(&self) -> bool {
+		self.per_m2.is_finite()
+	}
+
+	/// Returns true if this inverse area value is positive or negative infinity
+	pub fn is_infinite(&self) -> bool {
+		self.per_m2.is_infinite()
+	}
+
+	/// Returns true if this inverse area value is NaN (not a number)
+	pub fn is_nan(&self) -> bool {
+		self.per_m2.is_nan()
+	}
+
+	/// Returns true if this inverse area value has a positive sign, including +0.0 
+	/// and positive infinity
+	pub fn is_sign_positive(&self) -> bool {
+		self.per_m2.is_sign_positive()
+	}
+
+	/// Returns true if this inverse area value has a negative sign, including -0.0 
+	/// and negative infinity
+	pub fn is_sign_negative(&self) -> bool {
+		self.per_m2.is_sign_negative()
+	}
+
+	/// Returns a inverse area value with the magnitude of this one and the sign of 
+	/// `sign_source`, eg for reflecting a value off of a surface
+	///
+	/// # Arguments
+	/// * `sign_source` - The inverse area value whose sign is copied
+	pub fn copysign(self, sign_source: Self) -> Self {
+		InverseArea{per_m2: self.per_m2.copysign(sign_source.per_m2)}
+	}
+}
+
+#[cfg(feature="num-traits")]
+impl<T> InverseArea<T> where T: NumLike+num_traits::CheckedAdd+num_traits::CheckedSub {
+
+	/// Adds another inverse area value to this one, returning `None` if the 
+	/// addition overflows the number type (requires the `num-traits` feature)
+	pub fn checked_add(self, rhs: Self) -> Option<Self> {
+		Some(InverseArea{per_m2: self.per_m2.checked_add(&rhs.per_m2)?})
+	}
+
+	/// Subtracts another inverse area value from this one, returning `None` if 
+	/// the subtraction overflows the number type (requires the `num-traits` feature)
+	pub fn checked_sub(self, rhs: Self) -> Option<Self> {
+		Some(InverseArea{per_m2: self.per_m2.checked_sub(&rhs.per_m2)?})
 	}
 }
-/// Dividing a Area by a AreaPerLumen returns a value of type LuminousFlux
-impl<T> core::ops::Div<&AreaPerLumen<T>> for &Area<T> where T: NumLike {
-	type Output = LuminousFlux<T>;
-	fn div(self, rhs: &AreaPerLumen<T>) -> Self::Output {
-		LuminousFlux{lm: self.m2.clone() / rhs.m2_per_lm.clone()}
+
+#[cfg(feature="num-traits")]
+impl<T> InverseArea<T> where T: NumLike+num_traits::SaturatingAdd+num_traits::SaturatingSub {
+
+	/// Adds another inverse area value to this one, clamping the result to the 
+	/// minimum or maximum value of the number type instead of overflowing 
+	/// (requires the `num-traits` feature)
+	pub fn saturating_add(self, rhs: Self) -> Self {
+		InverseArea{per_m2: self.per_m2.saturating_add(&rhs.per_m2)}
+	}
+
+	/// Subtracts another inverse area value from this one, clamping the result 
+	/// to the minimum or maximum value of the number type instead of overflowing 
+	/// (requires the `num-traits` feature)
+	pub fn saturating_sub(self, rhs: Self) -> Self {
+		InverseArea{per_m2: self.per_m2.saturating_sub(&rhs.per_m2)}
 	}
 }
 
-// Area * Illuminance -> LuminousFlux
-/// Multiplying a Area by a Illuminance returns a value of type LuminousFlux
-impl<T> core::ops::Mul<Illuminance<T>> for Area<T> where T: NumLike {
-	type Output = LuminousFlux<T>;
-	fn mul(self, rhs: Illuminance<T>) -> Self::Output {
-		LuminousFlux{lm: self.m2 * rhs.lux}
+impl<T> InverseArea<T> where T: NumLike+Into<f64> {
+
+	/// Returns true if this inverse area value is equal to the given inverse area 
+	/// value to within the given number of significant figures (eg 6 significant 
+	/// figures allows a relative difference of up to 1 part per million). NaN is 
+	/// only approximately equal to NaN, infinity is only approximately equal to 
+	/// infinity of the same sign, and zero is only approximately equal to zero.
+	///
+	/// # Arguments
+	/// * `other` - The inverse area value to compare with this one
+	/// * `sig_figs` - The number of significant figures that must match
+	pub fn approx_eq(self, other: Self, sig_figs: i32) -> bool {
+		super::approx_eq_f64(self.per_m2.into(), other.per_m2.into(), sig_figs)
 	}
 }
-/// Multiplying a Area by a Illuminance returns a value of type LuminousFlux
-impl<T> core::ops::Mul<Illuminance<T>> for &Area<T> where T: NumLike {
-	type Output = LuminousFlux<T>;
-	fn mul(self, rhs: Illuminance<T>) -> Self::Output {
-		LuminousFlux{lm: self.m2.clone() * rhs.lux}
+
+impl<T> InverseArea<T> where T: NumLike+From<f64> {
+
+	/// Returns the linear interpolation between this inverse area value and the given 
+	/// inverse area value, ie `self*(1-t) + other*t`. The interpolation factor is not 
+	/// clamped, so values of `t` outside the range 0 to 1 extrapolate beyond the two 
+	/// values (see `lerp_clamped(...)`).
+	///
+	/// # Arguments
+	/// * `other` - The inverse area value to interpolate towards (returned when `t` is 1)
+	/// * `t` - The interpolation factor, where 0 returns this value and 1 returns `other`
+	pub fn lerp(self, other: Self, t: f64) -> Self {
+		InverseArea{per_m2: self.per_m2 * T::from(1.0 - t) + other.per_m2 * T::from(t)}
+	}
+
+	/// Same as `lerp(...)`, except that the interpolation factor `t` is first clamped 
+	/// to the range 0 to 1, so that the result is always between the two values
+	///
+	/// # Arguments
+	/// * `other` - The inverse area value to interpolate towards (returned when `t` is 1 or more)
+	/// * `t` - The interpolation factor, where 0 returns this value and 1 returns `other`
+	pub fn lerp_clamped(self, other: Self, t: f64) -> Self {
+		self.lerp(other, t.clamp(0.0, 1.0))
+	}
+	
+	/// Returns a copy of this inverse area value in inverse square cm
+	/// 
+	/// *Note: This method is not available for `f32` and other number types lacking the `From<f64>` trait*
+	pub fn to_per_cm2(&self) -> T {
+		return self.per_m2.clone() * T::from(0.0001_f64);
+	}
+
+	/// Returns a new inverse area value from the given number of inverse square cm
+	/// 
+	/// *Note: This method is not available for `f32` and other number types lacking the `From<f64>` trait*
+	///
+	/// # Arguments
+	/// * `per_cm2` - Any number-like type, representing a quantity of inverse square cm
+	pub fn from_per_cm2(per_cm2: T) -> Self {
+		InverseArea{per_m2: per_cm2 * T::from(10000.0_f64)}
+	}
+
+	/// Returns a copy of this inverse area value in inverse square cm
+	/// 
+	/// *Note: This method is not available for `f32` and other number types lacking the `From<f64>` trait*
+	pub fn to_per_square_cm(&self) -> T {
+		return self.per_m2.clone() * T::from(0.0001_f64);
+	}
+
+	/// Returns a new inverse area value from the given number of inverse square cm
+	/// 
+	/// *Note: This method is not available for `f32` and other number types lacking the `From<f64>` trait*
+	///
+	/// # Arguments
+	/// * `per_square_cm` - Any number-like type, representing a quantity of inverse square cm
+	pub fn from_per_square_cm(per_square_cm: T) -> Self {
+		InverseArea{per_m2: per_square_cm * T::from(10000.0_f64)}
+	}
+
+	/// Returns a copy of this inverse area value in inverse square mm
+	/// 
+	/// *Note: This method is not available for `f32` and other number types lacking the `From<f64>` trait*
+	pub fn to_per_mm2(&self) -> T {
+		return self.per_m2.clone() * T::from(1e-06_f64);
+	}
+
+	/// Returns a new inverse area value from the given number of inverse square mm
+	/// 
+	/// *Note: This method is not available for `f32` and other number types lacking the `From<f64>` trait*
+	///
+	/// # Arguments
+	/// * `per_mm2` - Any number-like type, representing a quantity of inverse square mm
+	pub fn from_per_mm2(per_mm2: T) -> Self {
+		InverseArea{per_m2: per_mm2 * T::from(1000000.0_f64)}
+	}
+
+	/// Returns a copy of this inverse area value in inverse square um
+	/// 
+	/// *Note: This method is not available for `f32` and other number types lacking the `From<f64>` trait*
+	pub fn to_per_um2(&self) -> T {
+		return self.per_m2.clone() * T::from(1e-12_f64);
+	}
+
+	/// Returns a new inverse area value from the given number of inverse square um
+	/// 
+	/// *Note: This method is not available for `f32` and other number types lacking the `From<f64>` trait*
+	///
+	/// # Arguments
+	/// * `per_um2` - Any number-like type, representing a quantity of inverse square um
+	pub fn from_per_um2(per_um2: T) -> Self {
+		InverseArea{per_m2: per_um2 * T::from(1000000000000.0_f64)}
+	}
+
+	/// Returns a copy of this inverse area value in inverse square nm
+	/// 
+	/// *Note: This method is not available for `f32` and other number types lacking the `From<f64>` trait*
+	pub fn to_per_nm2(&self) -> T {
+		return self.per_m2.clone() * T::from(1e-18_f64);
+	}
+
+	/// Returns a new inverse area value from the given number of inverse square nm
+	/// 
+	/// *Note: This method is not available for `f32` and other number types lacking the `From<f64>` trait*
+	///
+	/// # Arguments
+	/// * `per_nm2` - Any number-like type, representing a quantity of inverse square nm
+	pub fn from_per_nm2(per_nm2: T) -> Self {
+		InverseArea{per_m2: per_nm2 * T::from(1e+18_f64)}
+	}
+
+	/// Returns a copy of this inverse area value in inverse square km
+	/// 
+	/// *Note: This method is not available for `f32` and other number types lacking the `From<f64>` trait*
+	pub fn to_per_km2(&self) -> T {
+		return self.per_m2.clone() * T::from(1000000.0_f64);
+	}
+
+	/// Returns a new inverse area value from the given number of inverse square km
+	/// 
+	/// *Note: This method is not available for `f32` and other number types lacking the `From<f64>` trait*
+	///
+	/// # Arguments
+	/// * `per_km2` - Any number-like type, representing a quantity of inverse square km
+	pub fn from_per_km2(per_km2: T) -> Self {
+		InverseArea{per_m2: per_km2 * T::from(1e-06_f64)}
 	}
+
 }
-/// Multiplying a Area by a Illuminance returns a value of type LuminousFlux
-impl<T> core::ops::Mul<&Illuminance<T>> for Area<T> where T: NumLike {
-	type Output = LuminousFlux<T>;
-	fn mul(self, rhs: &Illuminance<T>) -> Self::Output {
-		LuminousFlux{lm: self.m2 * rhs.lux.clone()}
+
+
+/// Multiplying a unit value by a scalar value returns a unit value
+#[cfg(feature="num-bigfloat")]
+impl core::ops::Mul<InverseArea<num_bigfloat::BigFloat>> for num_bigfloat::BigFloat {
+	type Output = InverseArea<num_bigfloat::BigFloat>;
+	fn mul(self, rhs: InverseArea<num_bigfloat::BigFloat>) -> Self::Output {
+		InverseArea{per_m2: self * rhs.per_m2}
 	}
 }
-/// Multiplying a Area by a Illuminance returns a value of type LuminousFlux
-impl<T> core::ops::Mul<&Illuminance<T>> for &Area<T> where T: NumLike {
-	type Output = LuminousFlux<T>;
-	fn mul(self, rhs: &Illuminance<T>) -> Self::Output {
-		LuminousFlux{lm: self.m2.clone() * rhs.lux.clone()}
+/// Multiplying a unit value by a scalar value returns a unit value
+#[cfg(feature="num-bigfloat")]
+impl core::ops::Mul<InverseArea<num_bigfloat::BigFloat>> for &num_bigfloat::BigFloat {
+	type Output = InverseArea<num_bigfloat::BigFloat>;
+	fn mul(self, rhs: InverseArea<num_bigfloat::BigFloat>) -> Self::Output {
+		InverseArea{per_m2: self.clone() * rhs.per_m2}
+	}
+}
+/// Multiplying a unit value by a scalar value returns a unit value
+#[cfg(feature="num-bigfloat")]
+impl core::ops::Mul<&InverseArea<num_bigfloat::BigFloat>> for num_bigfloat::BigFloat {
+	type Output = InverseArea<num_bigfloat::BigFloat>;
+	fn mul(self, rhs: &InverseArea<num_bigfloat::BigFloat>) -> Self::Output {
+		InverseArea{per_m2: self * rhs.per_m2.clone()}
+	}
+}
+/// Multiplying a unit value by a scalar value returns a unit value
+#[cfg(feature="num-bigfloat")]
+impl core::ops::Mul<&InverseArea<num_bigfloat::BigFloat>> for &num_bigfloat::BigFloat {
+	type Output = InverseArea<num_bigfloat::BigFloat>;
+	fn mul(self, rhs: &InverseArea<num_bigfloat::BigFloat>) -> Self::Output {
+		InverseArea{per_m2: self.clone() * rhs.per_m2.clone()}
 	}
 }
 
-// Area * InverseLuminousFlux -> AreaPerLumen
-/// Multiplying a Area by a InverseLuminousFlux returns a value of type AreaPerLumen
-impl<T> core::ops::Mul<InverseLuminousFlux<T>> for Area<T> where T: NumLike {
-	type Output = AreaPerLumen<T>;
-	fn mul(self, rhs: InverseLuminousFlux<T>) -> Self::Output {
-		AreaPerLumen{m2_per_lm: self.m2 * rhs.per_lm}
+/// Multiplying a unit value by a scalar value returns a unit value
+#[cfg(feature="num-complex")]
+impl core::ops::Mul<InverseArea<num_complex::Complex32>> for num_complex::Complex32 {
+	type Output = InverseArea<num_complex::Complex32>;
+	fn mul(self, rhs: InverseArea<num_complex::Complex32>) -> Self::Output {
+		InverseArea{per_m2: self * rhs.per_m2}
 	}
 }
-/// Multiplying a Area by a InverseLuminousFlux returns a value of type AreaPerLumen
-impl<T> core::ops::Mul<InverseLuminousFlux<T>> for &Area<T> where T: NumLike {
-	type Output = AreaPerLumen<T>;
-	fn mul(self, rhs: InverseLuminousFlux<T>) -> Self::Output {
-		AreaPerLumen{m2_per_lm: self.m2.clone() * rhs.per_lm}
+/// Multiplying a unit value by a scalar value returns a unit value
+#[cfg(feature="num-complex")]
+impl core::ops::Mul<InverseArea<num_complex::Complex32>> for &num_complex::Complex32 {
+	type Output = InverseArea<num_complex::Complex32>;
+	fn mul(self, rhs: InverseArea<num_complex::Complex32>) -> Self::Output {
+		InverseArea{per_m2: self.clone() * rhs.per_m2}
+	}
+}
+/// Multiplying a unit value by a scalar value returns a unit value
+#[cfg(feature="num-complex")]
+impl core::ops::Mul<&InverseArea<num_complex::Complex32>> for num_complex::Complex32 {
+	type Output = InverseArea<num_complex::Complex32>;
+	fn mul(self, rhs: &InverseArea<num_complex::Complex32>) -> Self::Output {
+		InverseArea{per_m2: self * rhs.per_m2.clone()}
+	}
+}
+/// Multiplying a unit value by a scalar value returns a unit value
+#[cfg(feature="num-complex")]
+impl core::ops::Mul<&InverseArea<num_complex::Complex32>> for &num_complex::Complex32 {
+	type Output = InverseArea<num_complex::Complex32>;
+	fn mul(self, rhs: &InverseArea<num_complex::Complex32>) -> Self::Output {
+		InverseArea{per_m2: self.clone() * rhs.per_m2.clone()}
+	}
+}
+
+/// Multiplying a unit value by a scalar value returns a unit value
+#[cfg(feature="num-complex")]
+impl core::ops::Mul<InverseArea<num_complex::Complex64>> for num_complex::Complex64 {
+	type Output = InverseArea<num_complex::Complex64>;
+	fn mul(self, rhs: InverseArea<num_complex::Complex64>) -> Self::Output {
+		InverseArea{per_m2: self * rhs.per_m2}
+	}
+}
+/// Multiplying a unit value by a scalar value returns a unit value
+#[cfg(feature="num-complex")]
+impl core::ops::Mul<InverseArea<num_complex::Complex64>> for &num_complex::Complex64 {
+	type Output = InverseArea<num_complex::Complex64>;
+	fn mul(self, rhs: InverseArea<num_complex::Complex64>) -> Self::Output {
+		InverseArea{per_m2: self.clone() * rhs.per_m2}
+	}
+}
+/// Multiplying a unit value by a scalar value returns a unit value
+#[cfg(feature="num-complex")]
+impl core::ops::Mul<&InverseArea<num_complex::Complex64>> for num_complex::Complex64 {
+	type Output = InverseArea<num_complex::Complex64>;
+	fn mul(self, rhs: &InverseArea<num_complex::Complex64>) -> Self::Output {
+		InverseArea{per_m2: self * rhs.per_m2.clone()}
+	}
+}
+/// Multiplying a unit value by a scalar value returns a unit value
+#[cfg(feature="num-complex")]
+impl core::ops::Mul<&InverseArea<num_complex::Complex64>> for &num_complex::Complex64 {
+	type Output = InverseArea<num_complex::Complex64>;
+	fn mul(self, rhs: &InverseArea<num_complex::Complex64>) -> Self::Output {
+		InverseArea{per_m2: self.clone() * rhs.per_m2.clone()}
+	}
+}
+
+/// Multiplying a unit value by a scalar value returns a unit value
+#[cfg(feature="num-rational")]
+impl core::ops::Mul<InverseArea<num_rational::Rational64>> for num_rational::Rational64 {
+	type Output = InverseArea<num_rational::Rational64>;
+	fn mul(self, rhs: InverseArea<num_rational::Rational64>) -> Self::Output {
+		InverseArea{per_m2: self * rhs.per_m2}
+	}
+}
+/// Multiplying a unit value by a scalar value returns a unit value
+#[cfg(feature="num-rational")]
+impl core::ops::Mul<InverseArea<num_rational::Rational64>> for &num_rational::Rational64 {
+	type Output = InverseArea<num_rational::Rational64>;
+	fn mul(self, rhs: InverseArea<num_rational::Rational64>) -> Self::Output {
+		InverseArea{per_m2: self.clone() * rhs.per_m2}
 	}
 }
-/// Multiplying a Area by a InverseLuminousFlux returns a value of type AreaPerLumen
-impl<T> core::ops::Mul<&InverseLuminousFlux<T>> for Area<T> where T: NumLike {
-	type Output = AreaPerLumen<T>;
-	fn mul(self, rhs: &InverseLuminousFlux<T>) -> Self::Output {
-		AreaPerLumen{m2_per_lm: self.m2 * rhs.per_lm.clone()}
+/// Multiplying a unit value by a scalar value returns a unit value
+#[cfg(feature="num-rational")]
+impl core::ops::Mul<&InverseArea<num_rational::Rational64>> for num_rational::Rational64 {
+	type Output = InverseArea<num_rational::Rational64>;
+	fn mul(self, rhs: &InverseArea<num_rational::Rational64>) -> Self::Output {
+		InverseArea{per_m2: self * rhs.per_m2.clone()}
 	}
 }
-/// Multiplying a Area by a InverseLuminousFlux returns a value of type AreaPerLumen
-impl<T> core::ops::Mul<&InverseLuminousFlux<T>> for &Area<T> where T: NumLike {
-	type Output = AreaPerLumen<T>;
-	fn mul(self, rhs: &InverseLuminousFlux<T>) -> Self::Output {
-		AreaPerLumen{m2_per_lm: self.m2.clone() * rhs.per_lm.clone()}
+/// Multiplying a unit value by a scalar value returns a unit value
+#[cfg(feature="num-rational")]
+impl core::ops::Mul<&InverseArea<num_rational::Rational64>> for &num_rational::Rational64 {
+	type Output = InverseArea<num_rational::Rational64>;
+	fn mul(self, rhs: &InverseArea<num_rational::Rational64>) -> Self::Output {
+		InverseArea{per_m2: self.clone() * rhs.per_m2.clone()}
 	}
 }
 
-// Area * InverseMagneticFlux -> InverseMagneticFluxDensity
-/// Multiplying a Area by a InverseMagneticFlux returns a value of type InverseMagneticFluxDensity
-impl<T> core::ops::Mul<InverseMagneticFlux<T>> for Area<T> where T: NumLike {
-	type Output = InverseMagneticFluxDensity<T>;
-	fn mul(self, rhs: InverseMagneticFlux<T>) -> Self::Output {
-		InverseMagneticFluxDensity{m2_per_Wb: self.m2 * rhs.per_Wb}
+/// Multiplying a unit value by a scalar value returns a unit value
+#[cfg(feature="num-rational")]
+impl core::ops::Mul<InverseArea<num_rational::BigRational>> for num_rational::BigRational {
+	type Output = InverseArea<num_rational::BigRational>;
+	fn mul(self, rhs: InverseArea<num_rational::BigRational>) -> Self::Output {
+		InverseArea{per_m2: self * rhs.per_m2}
 	}
 }
-/// Multiplying a Area by a InverseMagneticFlux returns a value of type InverseMagneticFluxDensity
-impl<T> core::ops::Mul<InverseMagneticFlux<T>> for &Area<T> where T: NumLike {
-	type Output = InverseMagneticFluxDensity<T>;
-	fn mul(self, rhs: InverseMagneticFlux<T>) -> Self::Output {
-		InverseMagneticFluxDensity{m2_per_Wb: self.m2.clone() * rhs.per_Wb}
+/// Multiplying a unit value by a scalar value returns a unit value
+#[cfg(feature="num-rational")]
+impl core::ops::Mul<InverseArea<num_rational::BigRational>> for &num_rational::BigRational {
+	type Output = InverseArea<num_rational::BigRational>;
+	fn mul(self, rhs: InverseArea<num_rational::BigRational>) -> Self::Output {
+		InverseArea{per_m2: self.clone() * rhs.per_m2}
 	}
 }
-/// Multiplying a Area by a InverseMagneticFlux returns a value of type InverseMagneticFluxDensity
-impl<T> core::ops::Mul<&InverseMagneticFlux<T>> for Area<T> where T: NumLike {
-	type Output = InverseMagneticFluxDensity<T>;
-	fn mul(self, rhs: &InverseMagneticFlux<T>) -> Self::Output {
-		InverseMagneticFluxDensity{m2_per_Wb: self.m2 * rhs.per_Wb.clone()}
+/// Multiplying a unit value by a scalar value returns a unit value
+#[cfg(feature="num-rational")]
+impl core::ops::Mul<&InverseArea<num_rational::BigRational>> for num_rational::BigRational {
+	type Output = InverseArea<num_rational::BigRational>;
+	fn mul(self, rhs: &InverseArea<num_rational::BigRational>) -> Self::Output {
+		InverseArea{per_m2: self * rhs.per_m2.clone()}
 	}
 }
-/// Multiplying a Area by a InverseMagneticFlux returns a value of type InverseMagneticFluxDensity
-impl<T> core::ops::Mul<&InverseMagneticFlux<T>> for &Area<T> where T: NumLike {
-	type Output = InverseMagneticFluxDensity<T>;
-	fn mul(self, rhs: &InverseMagneticFlux<T>) -> Self::Output {
-		InverseMagneticFluxDensity{m2_per_Wb: self.m2.clone() * rhs.per_Wb.clone()}
+/// Multiplying a unit value by a scalar value returns a unit value
+#[cfg(feature="num-rational")]
+impl core::ops::Mul<&InverseArea<num_rational::BigRational>> for &num_rational::BigRational {
+	type Output = InverseArea<num_rational::BigRational>;
+	fn mul(self, rhs: &InverseArea<num_rational::BigRational>) -> Self::Output {
+		InverseArea{per_m2: self.clone() * rhs.per_m2.clone()}
 	}
 }
 
-// Area / InverseMagneticFluxDensity -> MagneticFlux
-/// Dividing a Area by a InverseMagneticFluxDensity returns a value of type MagneticFlux
-impl<T> core::ops::Div<InverseMagneticFluxDensity<T>> for Area<T> where T: NumLike {
-	type Output = MagneticFlux<T>;
-	fn div(self, rhs: InverseMagneticFluxDensity<T>) -> Self::Output {
-		MagneticFlux{Wb: self.m2 / rhs.m2_per_Wb}
-	}
-}
-/// Dividing a Area by a InverseMagneticFluxDensity returns a value of type MagneticFlux
-impl<T> core::ops::Div<InverseMagneticFluxDensity<T>> for &Area<T> where T: NumLike {
-	type Output = MagneticFlux<T>;
-	fn div(self, rhs: InverseMagneticFluxDensity<T>) -> Self::Output {
-		MagneticFlux{Wb: self.m2.clone() / rhs.m2_per_Wb}
+
+
+/// Converts a InverseArea into the equivalent [uom](https://crates.io/crates/uom) type [ArealNumberDensity](https://docs.rs/uom/0.34.0/uom/si/f32/type.ArealNumberDensity.html)
+#[cfg(feature = "uom")]
+impl<T> Into<uom::si::f32::ArealNumberDensity> for InverseArea<T> where T: NumLike+Into<f32> {
+	fn into(self) -> uom::si::f32::ArealNumberDensity {
+		uom::si::f32::ArealNumberDensity::new::<uom::si::areal_number_density::per_square_meter>(self.per_m2.into())
 	}
 }
-/// Dividing a Area by a InverseMagneticFluxDensity returns a value of type MagneticFlux
-impl<T> core::ops::Div<&InverseMagneticFluxDensity<T>> for Area<T> where T: NumLike {
-	type Output = MagneticFlux<T>;
-	fn div(self, rhs: &InverseMagneticFluxDensity<T>) -> Self::Output {
-		MagneticFlux{Wb: self.m2 / rhs.m2_per_Wb.clone()}
+
+/// Creates a InverseArea from the equivalent [uom](https://crates.io/crates/uom) type [ArealNumberDensity](https://docs.rs/uom/0.34.0/uom/si/f32/type.ArealNumberDensity.html)
+#[cfg(feature = "uom")]
+impl<T> From<uom::si::f32::ArealNumberDensity> for InverseArea<T> where T: NumLike+From<f32> {
+	fn from(src: uom::si::f32::ArealNumberDensity) -> Self {
+		InverseArea{per_m2: T::from(src.value)}
 	}
 }
-/// Dividing a Area by a InverseMagneticFluxDensity returns a value of type MagneticFlux
-impl<T> core::ops::Div<&InverseMagneticFluxDensity<T>> for &Area<T> where T: NumLike {
-	type Output = MagneticFlux<T>;
-	fn div(self, rhs: &InverseMagneticFluxDensity<T>) -> Self::Output {
-		MagneticFlux{Wb: self.m2.clone() / rhs.m2_per_Wb.clone()}
+
+/// Converts a InverseArea into the equivalent [uom](https://crates.io/crates/uom) type [ArealNumberDensity](https://docs.rs/uom/0.34.0/uom/si/f64/type.ArealNumberDensity.html)
+#[cfg(feature = "uom")]
+impl<T> Into<uom::si::f64::ArealNumberDensity> for InverseArea<T> where T: NumLike+Into<f64> {
+	fn into(self) -> uom::si::f64::ArealNumberDensity {
+		uom::si::f64::ArealNumberDensity::new::<uom::si::areal_number_density::per_square_meter>(self.per_m2.into())
 	}
 }
 
-// Area / LuminousFlux -> AreaPerLumen
-/// Dividing a Area by a LuminousFlux returns a value of type AreaPerLumen
-impl<T> core::ops::Div<LuminousFlux<T>> for Area<T> where T: NumLike {
-	type Output = AreaPerLumen<T>;
-	fn div(self, rhs: LuminousFlux<T>) -> Self::Output {
-		AreaPerLumen{m2_per_lm: self.m2 / rhs.lm}
+/// Creates a InverseArea from the equivalent [uom](https://crates.io/crates/uom) type [ArealNumberDensity](https://docs.rs/uom/0.34.0/uom/si/f64/type.ArealNumberDensity.html)
+#[cfg(feature = "uom")]
+impl<T> From<uom::si::f64::ArealNumberDensity> for InverseArea<T> where T: NumLike+From<f64> {
+	fn from(src: uom::si::f64::ArealNumberDensity) -> Self {
+		InverseArea{per_m2: T::from(src.value)}
 	}
 }
-/// Dividing a Area by a LuminousFlux returns a value of type AreaPerLumen
-impl<T> core::ops::Div<LuminousFlux<T>> for &Area<T> where T: NumLike {
-	type Output = AreaPerLumen<T>;
-	fn div(self, rhs: LuminousFlux<T>) -> Self::Output {
-		AreaPerLumen{m2_per_lm: self.m2.clone() / rhs.lm}
+
+
+
+/// Multiplying a InverseArea by an [nalgebra](https://crates.io/crates/nalgebra) 
+/// vector or matrix of unitless values returns a vector or matrix of InverseArea 
+/// values (eg a direction vector multiplied by a InverseArea)
+#[cfg(feature="nalgebra")]
+impl<T, R, C, S> core::ops::Mul<nalgebra::Matrix<T, R, C, S>> for InverseArea<T> where 
+	T: NumLike+nalgebra::Scalar, R: nalgebra::Dim, C: nalgebra::Dim, S: nalgebra::RawStorage<T, R, C>, 
+	nalgebra::DefaultAllocator: nalgebra::allocator::Allocator<R, C> {
+	type Output = nalgebra::OMatrix<InverseArea<T>, R, C>;
+	fn mul(self, rhs: nalgebra::Matrix<T, R, C, S>) -> Self::Output {
+		rhs.map(|x| InverseArea{per_m2: self.per_m2.clone() * x})
 	}
 }
-/// Dividing a Area by a LuminousFlux returns a value of type AreaPerLumen
-impl<T> core::ops::Div<&LuminousFlux<T>> for Area<T> where T: NumLike {
-	type Output = AreaPerLumen<T>;
-	fn div(self, rhs: &LuminousFlux<T>) -> Self::Output {
-		AreaPerLumen{m2_per_lm: self.m2 / rhs.lm.clone()}
+/// Multiplying an [nalgebra](https://crates.io/crates/nalgebra) vector or matrix of 
+/// unitless values by a InverseArea returns a vector or matrix of InverseArea values
+#[cfg(feature="nalgebra")]
+impl<T, R, C, S> core::ops::Mul<InverseArea<T>> for nalgebra::Matrix<T, R, C, S> where 
+	T: NumLike+nalgebra::Scalar, R: nalgebra::Dim, C: nalgebra::Dim, S: nalgebra::RawStorage<T, R, C>, 
+	nalgebra::DefaultAllocator: nalgebra::allocator::Allocator<R, C> {
+	type Output = nalgebra::OMatrix<InverseArea<T>, R, C>;
+	fn mul(self, rhs: InverseArea<T>) -> Self::Output {
+		self.map(|x| InverseArea{per_m2: x * rhs.per_m2.clone()})
 	}
 }
-/// Dividing a Area by a LuminousFlux returns a value of type AreaPerLumen
-impl<T> core::ops::Div<&LuminousFlux<T>> for &Area<T> where T: NumLike {
-	type Output = AreaPerLumen<T>;
-	fn div(self, rhs: &LuminousFlux<T>) -> Self::Output {
-		AreaPerLumen{m2_per_lm: self.m2.clone() / rhs.lm.clone()}
+/// Dividing an [nalgebra](https://crates.io/crates/nalgebra) vector or matrix of 
+/// InverseArea values by a InverseArea returns a vector or matrix of unitless values
+#[cfg(feature="nalgebra")]
+impl<T, R, C, S> core::ops::Div<InverseArea<T>> for nalgebra::Matrix<InverseArea<T>, R, C, S> where 
+	T: NumLike+nalgebra::Scalar, R: nalgebra::Dim, C: nalgebra::Dim, S: nalgebra::RawStorage<InverseArea<T>, R, C>, 
+	nalgebra::DefaultAllocator: nalgebra::allocator::Allocator<R, C> {
+	type Output = nalgebra::OMatrix<T, R, C>;
+	fn div(self, rhs: InverseArea<T>) -> Self::Output {
+		self.map(|x| x.per_m2 / rhs.per_m2.clone())
 	}
 }
 
-// Area / MagneticFlux -> InverseMagneticFluxDensity
-/// Dividing a Area by a MagneticFlux returns a value of type InverseMagneticFluxDensity
-impl<T> core::ops::Div<MagneticFlux<T>> for Area<T> where T: NumLike {
-	type Output = InverseMagneticFluxDensity<T>;
-	fn div(self, rhs: MagneticFlux<T>) -> Self::Output {
-		InverseMagneticFluxDensity{m2_per_Wb: self.m2 / rhs.Wb}
+
+
+// InverseArea * Distance -> InverseDistance
+/// Multiplying a InverseArea by a Distance returns a value of type InverseDistance
+impl<T> core::ops::Mul<Distance<T>> for InverseArea<T> where T: NumLike {
+	type Output = InverseDistance<T>;
+	fn mul(self, rhs: Distance<T>) -> Self::Output {
+		InverseDistance{per_m: self.per_m2 * rhs.m}
 	}
 }
-/// Dividing a Area by a MagneticFlux returns a value of type InverseMagneticFluxDensity
-impl<T> core::ops::Div<MagneticFlux<T>> for &Area<T> where T: NumLike {
-	type Output = InverseMagneticFluxDensity<T>;
-	fn div(self, rhs: MagneticFlux<T>) -> Self::Output {
-		InverseMagneticFluxDensity{m2_per_Wb: self.m2.clone() / rhs.Wb}
+/// Multiplying a InverseArea by a Distance returns a value of type InverseDistance
+impl<T> core::ops::Mul<Distance<T>> for &InverseArea<T> where T: NumLike {
+	type Output = InverseDistance<T>;
+	fn mul(self, rhs: Distance<T>) -> Self::Output {
+		InverseDistance{per_m: self.per_m2.clone() * rhs.m}
 	}
 }
-/// Dividing a Area by a MagneticFlux returns a value of type InverseMagneticFluxDensity
-impl<T> core::ops::Div<&MagneticFlux<T>> for Area<T> where T: NumLike {
-	type Output = InverseMagneticFluxDensity<T>;
-	fn div(self, rhs: &MagneticFlux<T>) -> Self::Output {
-		InverseMagneticFluxDensity{m2_per_Wb: self.m2 / rhs.Wb.clone()}
+/// Multiplying a InverseArea by a Distance returns a value of type InverseDistance
+impl<T> core::ops::Mul<&Distance<T>> for InverseArea<T> where T: NumLike {
+	type Output = InverseDistance<T>;
+	fn mul(self, rhs: &Distance<T>) -> Self::Output {
+		InverseDistance{per_m: self.per_m2 * rhs.m.clone()}
 	}
 }
-/// Dividing a Area by a MagneticFlux returns a value of type InverseMagneticFluxDensity
-impl<T> core::ops::Div<&MagneticFlux<T>> for &Area<T> where T: NumLike {
-	type Output = InverseMagneticFluxDensity<T>;
-	fn div(self, rhs: &MagneticFlux<T>) -> Self::Output {
-		InverseMagneticFluxDensity{m2_per_Wb: self.m2.clone() / rhs.Wb.clone()}
+/// Multiplying a InverseArea by a Distance returns a value of type InverseDistance
+impl<T> core::ops::Mul<&Distance<T>> for &InverseArea<T> where T: NumLike {
+	type Output = InverseDistance<T>;
+	fn mul(self, rhs: &Distance<T>) -> Self::Output {
+		InverseDistance{per_m: self.per_m2.clone() * rhs.m.clone()}
 	}
 }
 
-// Area * MagneticFluxDensity -> MagneticFlux
-/// Multiplying a Area by a MagneticFluxDensity returns a value of type MagneticFlux
-impl<T> core::ops::Mul<MagneticFluxDensity<T>> for Area<T> where T: NumLike {
-	type Output = MagneticFlux<T>;
-	fn mul(self, rhs: MagneticFluxDensity<T>) -> Self::Output {
-		MagneticFlux{Wb: self.m2 * rhs.T}
+// InverseArea / Distance -> InverseVolume
+/// Dividing a InverseArea by a Distance returns a value of type InverseVolume
+impl<T> core::ops::Div<Distance<T>> for InverseArea<T> where T: NumLike {
+	type Output = InverseVolume<T>;
+	fn div(self, rhs: Distance<T>) -> Self::Output {
+		InverseVolume{per_m3: self.per_m2 / rhs.m}
 	}
 }
-/// Multiplying a Area by a MagneticFluxDensity returns a value of type MagneticFlux
-impl<T> core::ops::Mul<MagneticFluxDensity<T>> for &Area<T> where T: NumLike {
-	type Output = MagneticFlux<T>;
-	fn mul(self, rhs: MagneticFluxDensity<T>) -> Self::Output {
-		MagneticFlux{Wb: self.m2.clone() * rhs.T}
+/// Dividing a InverseArea by a Distance returns a value of type InverseVolume
+impl<T> core::ops::Div<Distance<T>> for &InverseArea<T> where T: NumLike {
+	type Output = InverseVolume<T>;
+	fn div(self, rhs: Distance<T>) -> Self::Output {
+		InverseVolume{per_m3: self.per_m2.clone() / rhs.m}
 	}
 }
-/// Multiplying a Area by a MagneticFluxDensity returns a value of type MagneticFlux
-impl<T> core::ops::Mul<&MagneticFluxDensity<T>> for Area<T> where T: NumLike {
-	type Output = MagneticFlux<T>;
-	fn mul(self, rhs: &MagneticFluxDensity<T>) -> Self::Output {
-		MagneticFlux{Wb: self.m2 * rhs.T.clone()}
+/// Dividing a InverseArea by a Distance returns a value of type InverseVolume
+impl<T> core::ops::Div<&Distance<T>> for InverseArea<T> where T: NumLike {
+	type Output = InverseVolume<T>;
+	fn div(self, rhs: &Distance<T>) -> Self::Output {
+		InverseVolume{per_m3: self.per_m2 / rhs.m.clone()}
 	}
 }
-/// Multiplying a Area by a MagneticFluxDensity returns a value of type MagneticFlux
-impl<T> core::ops::Mul<&MagneticFluxDensity<T>> for &Area<T> where T: NumLike {
-	type Output = MagneticFlux<T>;
-	fn mul(self, rhs: &MagneticFluxDensity<T>) -> Self::Output {
-		MagneticFlux{Wb: self.m2.clone() * rhs.T.clone()}
+/// Dividing a InverseArea by a Distance returns a value of type InverseVolume
+impl<T> core::ops::Div<&Distance<T>> for &InverseArea<T> where T: NumLike {
+	type Output = InverseVolume<T>;
+	fn div(self, rhs: &Distance<T>) -> Self::Output {
+		InverseVolume{per_m3: self.per_m2.clone() / rhs.m.clone()}
 	}
 }
 
-// Area * InverseVolume -> InverseDistance
-/// Multiplying a Area by a InverseVolume returns a value of type InverseDistance
-impl<T> core::ops::Mul<InverseVolume<T>> for Area<T> where T: NumLike {
-	type Output = InverseDistance<T>;
-	fn mul(self, rhs: InverseVolume<T>) -> Self::Output {
-		InverseDistance{per_m: self.m2 * rhs.per_m3}
+// InverseArea * InverseDistance -> InverseVolume
+/// Multiplying a InverseArea by a InverseDistance returns a value of type InverseVolume
+impl<T> core::ops::Mul<InverseDistance<T>> for InverseArea<T> where T: NumLike {
+	type Output = InverseVolume<T>;
+	fn mul(self, rhs: InverseDistance<T>) -> Self::Output {
+		InverseVolume{per_m3: self.per_m2 * rhs.per_m}
 	}
 }
-/// Multiplying a Area by a InverseVolume returns a value of type InverseDistance
-impl<T> core::ops::Mul<InverseVolume<T>> for &Area<T> where T: NumLike {
-	type Output = InverseDistance<T>;
-	fn mul(self, rhs: InverseVolume<T>) -> Self::Output {
-		InverseDistance{per_m: self.m2.clone() * rhs.per_m3}
+/// Multiplying a InverseArea by a InverseDistance returns a value of type InverseVolume
+impl<T> core::ops::Mul<InverseDistance<T>> for &InverseArea<T> where T: NumLike {
+	type Output = InverseVolume<T>;
+	fn mul(self, rhs: InverseDistance<T>) -> Self::Output {
+		InverseVolume{per_m3: self.per_m2.clone() * rhs.per_m}
 	}
 }
-/// Multiplying a Area by a InverseVolume returns a value of type InverseDistance
-impl<T> core::ops::Mul<&InverseVolume<T>> for Area<T> where T: NumLike {
-	type Output = InverseDistance<T>;
-	fn mul(self, rhs: &InverseVolume<T>) -> Self::Output {
-		InverseDistance{per_m: self.m2 * rhs.per_m3.clone()}
+/// Multiplying a InverseArea by a InverseDistance returns a value of type InverseVolume
+impl<T> core::ops::Mul<&InverseDistance<T>> for InverseArea<T> where T: NumLike {
+	type Output = InverseVolume<T>;
+	fn mul(self, rhs: &InverseDistance<T>) -> Self::Output {
+		InverseVolume{per_m3: self.per_m2 * rhs.per_m.clone()}
 	}
 }
-/// Multiplying a Area by a InverseVolume returns a value of type InverseDistance
-impl<T> core::ops::Mul<&InverseVolume<T>> for &Area<T> where T: NumLike {
-	type Output = InverseDistance<T>;
-	fn mul(self, rhs: &InverseVolume<T>) -> Self::Output {
-		InverseDistance{per_m: self.m2.clone() * rhs.per_m3.clone()}
+/// Multiplying a InverseArea by a InverseDistance returns a value of type InverseVolume
+impl<T> core::ops::Mul<&InverseDistance<T>> for &InverseArea<T> where T: NumLike {
+	type Output = InverseVolume<T>;
+	fn mul(self, rhs: &InverseDistance<T>) -> Self::Output {
+		InverseVolume{per_m3: self.per_m2.clone() * rhs.per_m.clone()}
 	}
 }
 
-// Area / Volume -> InverseDistance
-/// Dividing a Area by a Volume returns a value of type InverseDistance
-impl<T> core::ops::Div<Volume<T>> for Area<T> where T: NumLike {
-	type Output = InverseDistance<T>;
-	fn div(self, rhs: Volume<T>) -> Self::Output {
-		InverseDistance{per_m: self.m2 / rhs.m3}
+// InverseArea / InverseDistance -> InverseDistance
+/// Dividing a InverseArea by a InverseDistance returns a value of type InverseDistance
+impl<T> core::ops::Div<InverseDistance<T>> for InverseArea<T> where T: NumLike {
+	type Output = InverseDistance<T>;
+	fn div(self, rhs: InverseDistance<T>) -> Self::Output {
+		InverseDistance{per_m: self.per_m2 / rhs.per_m}
 	}
 }
-/// Dividing a Area by a Volume returns a value of type InverseDistance
-impl<T> core::ops::Div<Volume<T>> for &Area<T> where T: NumLike {
+/// Dividing a InverseArea by a InverseDistance returns a value of type InverseDistance
+impl<T> core::ops::Div<InverseDistance<T>> for &InverseArea<T> where T: NumLike {
 	type Output = InverseDistance<T>;
-	fn div(self, rhs: Volume<T>) -> Self::Output {
-		InverseDistance{per_m: self.m2.clone() / rhs.m3}
+	fn div(self, rhs: InverseDistance<T>) -> Self::Output {
+		InverseDistance{per_m: self.per_m2.clone() / rhs.per_m}
 	}
 }
-/// Dividing a Area by a Volume returns a value of type InverseDistance
-impl<T> core::ops::Div<&Volume<T>> for Area<T> where T: NumLike {
+/// Dividing a InverseArea by a InverseDistance returns a value of type InverseDistance
+impl<T> core::ops::Div<&InverseDistance<T>> for InverseArea<T> where T: NumLike {
 	type Output = InverseDistance<T>;
-	fn div(self, rhs: &Volume<T>) -> Self::Output {
-		InverseDistance{per_m: self.m2 / rhs.m3.clone()}
+	fn div(self, rhs: &InverseDistance<T>) -> Self::Output {
+		InverseDistance{per_m: self.per_m2 / rhs.per_m.clone()}
 	}
 }
-/// Dividing a Area by a Volume returns a value of type InverseDistance
-impl<T> core::ops::Div<&Volume<T>> for &Area<T> where T: NumLike {
+/// Dividing a InverseArea by a InverseDistance returns a value of type InverseDistance
+impl<T> core::ops::Div<&InverseDistance<T>> for &InverseArea<T> where T: NumLike {
 	type Output = InverseDistance<T>;
-	fn div(self, rhs: &Volume<T>) -> Self::Output {
-		InverseDistance{per_m: self.m2.clone() / rhs.m3.clone()}
+	fn div(self, rhs: &InverseDistance<T>) -> Self::Output {
+		InverseDistance{per_m: self.per_m2.clone() / rhs.per_m.clone()}
 	}
 }
 
-// Area * AreaDensity -> Mass
-/// Multiplying a Area by a AreaDensity returns a value of type Mass
-impl<T> core::ops::Mul<AreaDensity<T>> for Area<T> where T: NumLike {
-	type Output = Mass<T>;
-	fn mul(self, rhs: AreaDensity<T>) -> Self::Output {
-		Mass{kg: self.m2 * rhs.kgpm2}
+// InverseArea / InverseMass -> AreaDensity
+/// Dividing a InverseArea by a InverseMass returns a value of type AreaDensity
+impl<T> core::ops::Div<InverseMass<T>> for InverseArea<T> where T: NumLike {
+	type Output = AreaDensity<T>;
+	fn div(self, rhs: InverseMass<T>) -> Self::Output {
+		AreaDensity{kgpm2: self.per_m2 / rhs.per_kg}
 	}
 }
-/// Multiplying a Area by a AreaDensity returns a value of type Mass
-impl<T> core::ops::Mul<AreaDensity<T>> for &Area<T> where T: NumLike {
-	type Output = Mass<T>;
-	fn mul(self, rhs: AreaDensity<T>) -> Self::Output {
-		Mass{kg: self.m2.clone() * rhs.kgpm2}
+/// Dividing a InverseArea by a InverseMass returns a value of type AreaDensity
+impl<T> core::ops::Div<InverseMass<T>> for &InverseArea<T> where T: NumLike {
+	type Output = AreaDensity<T>;
+	fn div(self, rhs: InverseMass<T>) -> Self::Output {
+		AreaDensity{kgpm2: self.per_m2.clone() / rhs.per_kg}
 	}
 }
-/// Multiplying a Area by a AreaDensity returns a value of type Mass
-impl<T> core::ops::Mul<&AreaDensity<T>> for Area<T> where T: NumLike {
-	type Output = Mass<T>;
-	fn mul(self, rhs: &AreaDensity<T>) -> Self::Output {
-		Mass{kg: self.m2 * rhs.kgpm2.clone()}
+/// Dividing a InverseArea by a InverseMass returns a value of type AreaDensity
+impl<T> core::ops::Div<&InverseMass<T>> for InverseArea<T> where T: NumLike {
+	type Output = AreaDensity<T>;
+	fn div(self, rhs: &InverseMass<T>) -> Self::Output {
+		AreaDensity{kgpm2: self.per_m2 / rhs.per_kg.clone()}
 	}
 }
-/// Multiplying a Area by a AreaDensity returns a value of type Mass
-impl<T> core::ops::Mul<&AreaDensity<T>> for &Area<T> where T: NumLike {
-	type Output = Mass<T>;
-	fn mul(self, rhs: &AreaDensity<T>) -> Self::Output {
-		Mass{kg: self.m2.clone() * rhs.kgpm2.clone()}
+/// Dividing a InverseArea by a InverseMass returns a value of type AreaDensity
+impl<T> core::ops::Div<&InverseMass<T>> for &InverseArea<T> where T: NumLike {
+	type Output = AreaDensity<T>;
+	fn div(self, rhs: &InverseMass<T>) -> Self::Output {
+		AreaDensity{kgpm2: self.per_m2.clone() / rhs.per_kg.clone()}
 	}
 }
 
-// Area / AreaPerMass -> Mass
-/// Dividing a Area by a AreaPerMass returns a value of type Mass
-impl<T> core::ops::Div<AreaPerMass<T>> for Area<T> where T: NumLike {
-	type Output = Mass<T>;
-	fn div(self, rhs: AreaPerMass<T>) -> Self::Output {
-		Mass{kg: self.m2 / rhs.m2_per_kg}
+// InverseArea * Mass -> AreaDensity
+/// Multiplying a InverseArea by a Mass returns a value of type AreaDensity
+impl<T> core::ops::Mul<Mass<T>> for InverseArea<T> where T: NumLike {
+	type Output = AreaDensity<T>;
+	fn mul(self, rhs: Mass<T>) -> Self::Output {
+		AreaDensity{kgpm2: self.per_m2 * rhs.kg}
 	}
 }
-/// Dividing a Area by a AreaPerMass returns a value of type Mass
-impl<T> core::ops::Div<AreaPerMass<T>> for &Area<T> where T: NumLike {
-	type Output = Mass<T>;
-	fn div(self, rhs: AreaPerMass<T>) -> Self::Output {
-		Mass{kg: self.m2.clone() / rhs.m2_per_kg}
+/// Multiplying a InverseArea by a Mass returns a value of type AreaDensity
+impl<T> core::ops::Mul<Mass<T>> for &InverseArea<T> where T: NumLike {
+	type Output = AreaDensity<T>;
+	fn mul(self, rhs: Mass<T>) -> Self::Output {
+		AreaDensity{kgpm2: self.per_m2.clone() * rhs.kg}
 	}
 }
-/// Dividing a Area by a AreaPerMass returns a value of type Mass
-impl<T> core::ops::Div<&AreaPerMass<T>> for Area<T> where T: NumLike {
-	type Output = Mass<T>;
-	fn div(self, rhs: &AreaPerMass<T>) -> Self::Output {
-		Mass{kg: self.m2 / rhs.m2_per_kg.clone()}
+/// Multiplying a InverseArea by a Mass returns a value of type AreaDensity
+impl<T> core::ops::Mul<&Mass<T>> for InverseArea<T> where T: NumLike {
+	type Output = AreaDensity<T>;
+	fn mul(self, rhs: &Mass<T>) -> Self::Output {
+		AreaDensity{kgpm2: self.per_m2 * rhs.kg.clone()}
 	}
 }
-/// Dividing a Area by a AreaPerMass returns a value of type Mass
-impl<T> core::ops::Div<&AreaPerMass<T>> for &Area<T> where T: NumLike {
-	type Output = Mass<T>;
-	fn div(self, rhs: &AreaPerMass<T>) -> Self::Output {
-		Mass{kg: self.m2.clone() / rhs.m2_per_kg.clone()}
+/// Multiplying a InverseArea by a Mass returns a value of type AreaDensity
+impl<T> core::ops::Mul<&Mass<T>> for &InverseArea<T> where T: NumLike {
+	type Output = AreaDensity<T>;
+	fn mul(self, rhs: &Mass<T>) -> Self::Output {
+		AreaDensity{kgpm2: self.per_m2.clone() * rhs.kg.clone()}
 	}
 }
 
-// Area / Force -> InversePressure
-/// Dividing a Area by a Force returns a value of type InversePressure
-impl<T> core::ops::Div<Force<T>> for Area<T> where T: NumLike {
-	type Output = InversePressure<T>;
-	fn div(self, rhs: Force<T>) -> Self::Output {
-		InversePressure{per_Pa: self.m2 / rhs.N}
+// InverseArea * Time -> InverseKinematicViscosity
+/// Multiplying a InverseArea by a Time returns a value of type InverseKinematicViscosity
+impl<T> core::ops::Mul<Time<T>> for InverseArea<T> where T: NumLike {
+	type Output = InverseKinematicViscosity<T>;
+	fn mul(self, rhs: Time<T>) -> Self::Output {
+		InverseKinematicViscosity{s_per_m2: self.per_m2 * rhs.s}
 	}
 }
-/// Dividing a Area by a Force returns a value of type InversePressure
-impl<T> core::ops::Div<Force<T>> for &Area<T> where T: NumLike {
-	type Output = InversePressure<T>;
-	fn div(self, rhs: Force<T>) -> Self::Output {
-		InversePressure{per_Pa: self.m2.clone() / rhs.N}
+/// Multiplying a InverseArea by a Time returns a value of type InverseKinematicViscosity
+impl<T> core::ops::Mul<Time<T>> for &InverseArea<T> where T: NumLike {
+	type Output = InverseKinematicViscosity<T>;
+	fn mul(self, rhs: Time<T>) -> Self::Output {
+		InverseKinematicViscosity{s_per_m2: self.per_m2.clone() * rhs.s}
 	}
 }
-/// Dividing a Area by a Force returns a value of type InversePressure
-impl<T> core::ops::Div<&Force<T>> for Area<T> where T: NumLike {
-	type Output = InversePressure<T>;
-	fn div(self, rhs: &Force<T>) -> Self::Output {
-		InversePressure{per_Pa: self.m2 / rhs.N.clone()}
+/// Multiplying a InverseArea by a Time returns a value of type InverseKinematicViscosity
+impl<T> core::ops::Mul<&Time<T>> for InverseArea<T> where T: NumLike {
+	type Output = InverseKinematicViscosity<T>;
+	fn mul(self, rhs: &Time<T>) -> Self::Output {
+		InverseKinematicViscosity{s_per_m2: self.per_m2 * rhs.s.clone()}
 	}
 }
-/// Dividing a Area by a Force returns a value of type InversePressure
-impl<T> core::ops::Div<&Force<T>> for &Area<T> where T: NumLike {
-	type Output = InversePressure<T>;
-	fn div(self, rhs: &Force<T>) -> Self::Output {
-		InversePressure{per_Pa: self.m2.clone() / rhs.N.clone()}
+/// Multiplying a InverseArea by a Time returns a value of type InverseKinematicViscosity
+impl<T> core::ops::Mul<&Time<T>> for &InverseArea<T> where T: NumLike {
+	type Output = InverseKinematicViscosity<T>;
+	fn mul(self, rhs: &Time<T>) -> Self::Output {
+		InverseKinematicViscosity{s_per_m2: self.per_m2.clone() * rhs.s.clone()}
 	}
 }
 
-// Area * InverseForce -> InversePressure
-/// Multiplying a Area by a InverseForce returns a value of type InversePressure
-impl<T> core::ops::Mul<InverseForce<T>> for Area<T> where T: NumLike {
-	type Output = InversePressure<T>;
-	fn mul(self, rhs: InverseForce<T>) -> Self::Output {
-		InversePressure{per_Pa: self.m2 * rhs.per_N}
+// InverseArea * AreaPerLumen -> InverseLuminousFlux
+/// Multiplying a InverseArea by a AreaPerLumen returns a value of type InverseLuminousFlux
+impl<T> core::ops::Mul<AreaPerLumen<T>> for InverseArea<T> where T: NumLike {
+	type Output = InverseLuminousFlux<T>;
+	fn mul(self, rhs: AreaPerLumen<T>) -> Self::Output {
+		InverseLuminousFlux{per_lm: self.per_m2 * rhs.m2_per_lm}
 	}
 }
-/// Multiplying a Area by a InverseForce returns a value of type InversePressure
-impl<T> core::ops::Mul<InverseForce<T>> for &Area<T> where T: NumLike {
-	type Output = InversePressure<T>;
-	fn mul(self, rhs: InverseForce<T>) -> Self::Output {
-		InversePressure{per_Pa: self.m2.clone() * rhs.per_N}
+/// Multiplying a InverseArea by a AreaPerLumen returns a value of type InverseLuminousFlux
+impl<T> core::ops::Mul<AreaPerLumen<T>> for &InverseArea<T> where T: NumLike {
+	type Output = InverseLuminousFlux<T>;
+	fn mul(self, rhs: AreaPerLumen<T>) -> Self::Output {
+		InverseLuminousFlux{per_lm: self.per_m2.clone() * rhs.m2_per_lm}
 	}
 }
-/// Multiplying a Area by a InverseForce returns a value of type InversePressure
-impl<T> core::ops::Mul<&InverseForce<T>> for Area<T> where T: NumLike {
-	type Output = InversePressure<T>;
-	fn mul(self, rhs: &InverseForce<T>) -> Self::Output {
-		InversePressure{per_Pa: self.m2 * rhs.per_N.clone()}
+/// Multiplying a InverseArea by a AreaPerLumen returns a value of type InverseLuminousFlux
+impl<T> core::ops::Mul<&AreaPerLumen<T>> for InverseArea<T> where T: NumLike {
+	type Output = InverseLuminousFlux<T>;
+	fn mul(self, rhs: &AreaPerLumen<T>) -> Self::Output {
+		InverseLuminousFlux{per_lm: self.per_m2 * rhs.m2_per_lm.clone()}
 	}
 }
-/// Multiplying a Area by a InverseForce returns a value of type InversePressure
-impl<T> core::ops::Mul<&InverseForce<T>> for &Area<T> where T: NumLike {
-	type Output = InversePressure<T>;
-	fn mul(self, rhs: &InverseForce<T>) -> Self::Output {
-		InversePressure{per_Pa: self.m2.clone() * rhs.per_N.clone()}
+/// Multiplying a InverseArea by a AreaPerLumen returns a value of type InverseLuminousFlux
+impl<T> core::ops::Mul<&AreaPerLumen<T>> for &InverseArea<T> where T: NumLike {
+	type Output = InverseLuminousFlux<T>;
+	fn mul(self, rhs: &AreaPerLumen<T>) -> Self::Output {
+		InverseLuminousFlux{per_lm: self.per_m2.clone() * rhs.m2_per_lm.clone()}
 	}
 }
 
-// Area * InverseMomentOfInertia -> InverseMass
-/// Multiplying a Area by a InverseMomentOfInertia returns a value of type InverseMass
-impl<T> core::ops::Mul<InverseMomentOfInertia<T>> for Area<T> where T: NumLike {
-	type Output = InverseMass<T>;
-	fn mul(self, rhs: InverseMomentOfInertia<T>) -> Self::Output {
-		InverseMass{per_kg: self.m2 * rhs.per_kgm2}
+// InverseArea * Charge -> SurfaceChargeDensity
+/// Multiplying a InverseArea by a Charge returns a value of type SurfaceChargeDensity
+impl<T> core::ops::Mul<Charge<T>> for InverseArea<T> where T: NumLike {
+	type Output = SurfaceChargeDensity<T>;
+	fn mul(self, rhs: Charge<T>) -> Self::Output {
+		SurfaceChargeDensity{Cpm2: self.per_m2 * rhs.C}
 	}
 }
-/// Multiplying a Area by a InverseMomentOfInertia returns a value of type InverseMass
-impl<T> core::ops::Mul<InverseMomentOfInertia<T>> for &Area<T> where T: NumLike {
-	type Output = InverseMass<T>;
-	fn mul(self, rhs: InverseMomentOfInertia<T>) -> Self::Output {
-		InverseMass{per_kg: self.m2.clone() * rhs.per_kgm2}
+/// Multiplying a InverseArea by a Charge returns a value of type SurfaceChargeDensity
+impl<T> core::ops::Mul<Charge<T>> for &InverseArea<T> where T: NumLike {
+	type Output = SurfaceChargeDensity<T>;
+	fn mul(self, rhs: Charge<T>) -> Self::Output {
+		SurfaceChargeDensity{Cpm2: self.per_m2.clone() * rhs.C}
 	}
 }
-/// Multiplying a Area by a InverseMomentOfInertia returns a value of type InverseMass
-impl<T> core::ops::Mul<&InverseMomentOfInertia<T>> for Area<T> where T: NumLike {
-	type Output = InverseMass<T>;
-	fn mul(self, rhs: &InverseMomentOfInertia<T>) -> Self::Output {
-		InverseMass{per_kg: self.m2 * rhs.per_kgm2.clone()}
+/// Multiplying a InverseArea by a Charge returns a value of type SurfaceChargeDensity
+impl<T> core::ops::Mul<&Charge<T>> for InverseArea<T> where T: NumLike {
+	type Output = SurfaceChargeDensity<T>;
+	fn mul(self, rhs: &Charge<T>) -> Self::Output {
+		SurfaceChargeDensity{Cpm2: self.per_m2 * rhs.C.clone()}
 	}
 }
-/// Multiplying a Area by a InverseMomentOfInertia returns a value of type InverseMass
-impl<T> core::ops::Mul<&InverseMomentOfInertia<T>> for &Area<T> where T: NumLike {
-	type Output = InverseMass<T>;
-	fn mul(self, rhs: &InverseMomentOfInertia<T>) -> Self::Output {
-		InverseMass{per_kg: self.m2.clone() * rhs.per_kgm2.clone()}
+/// Multiplying a InverseArea by a Charge returns a value of type SurfaceChargeDensity
+impl<T> core::ops::Mul<&Charge<T>> for &InverseArea<T> where T: NumLike {
+	type Output = SurfaceChargeDensity<T>;
+	fn mul(self, rhs: &Charge<T>) -> Self::Output {
+		SurfaceChargeDensity{Cpm2: self.per_m2.clone() * rhs.C.clone()}
 	}
 }
 
-// Area / InversePressure -> Force
-/// Dividing a Area by a InversePressure returns a value of type Force
-impl<T> core::ops::Div<InversePressure<T>> for Area<T> where T: NumLike {
-	type Output = Force<T>;
-	fn div(self, rhs: InversePressure<T>) -> Self::Output {
-		Force{N: self.m2 / rhs.per_Pa}
-	}
-}
-/// Dividing a Area by a InversePressure returns a value of type Force
-impl<T> core::ops::Div<InversePressure<T>> for &Area<T> where T: NumLike {
-	type Output = Force<T>;
-	fn div(self, rhs: InversePressure<T>) -> Self::Output {
-		Force{N: self.m2.clone() / rhs.per_Pa}
+// InverseArea / Illuminance -> InverseLuminousFlux
+/// Dividing a InverseArea by a Illuminance returns a value of type InverseLuminousFlux
+impl<T> core::ops::Div<Illuminance<T>> for InverseArea<T> where T: NumLike {
+	type Output = InverseLuminousFlux<T>;
+	fn div(self, rhs: Illuminance<T>) -> Self::Output {
+		InverseLuminousFlux{per_lm: self.per_m2 / rhs.lux}
 	}
 }
-/// Dividing a Area by a InversePressure returns a value of type Force
-impl<T> core::ops::Div<&InversePressure<T>> for Area<T> where T: NumLike {
-	type Output = Force<T>;
-	fn div(self, rhs: &InversePressure<T>) -> Self::Output {
-		Force{N: self.m2 / rhs.per_Pa.clone()}
+/// Dividing a InverseArea by a Illuminance returns a value of type InverseLuminousFlux
+impl<T> core::ops::Div<Illuminance<T>> for &InverseArea<T> where T: NumLike {
+	type Output = InverseLuminousFlux<T>;
+	fn div(self, rhs: Illuminance<T>) -> Self::Output {
+		InverseLuminousFlux{per_lm: self.per_m2.clone() / rhs.lux}
 	}
 }
-/// Dividing a Area by a InversePressure returns a value of type Force
-impl<T> core::ops::Div<&InversePressure<T>> for &Area<T> where T: NumLike {
-	type Output = Force<T>;
-	fn div(self, rhs: &InversePressure<T>) -> Self::Output {
-		Force{N: self.m2.clone() / rhs.per_Pa.clone()}
+/// Dividing a InverseArea by a Illuminance returns a value of type InverseLuminousFlux
+impl<T> core::ops::Div<&Illuminance<T>> for InverseArea<T> where T: NumLike {
+	type Output = InverseLuminousFlux<T>;
+	fn div(self, rhs: &Illuminance<T>) -> Self::Output {
+		InverseLuminousFlux{per_lm: self.per_m2 / rhs.lux.clone()}
 	}
 }
-
-// Area / MomentOfInertia -> InverseMass
-/// Dividing a Area by a MomentOfInertia returns a value of type InverseMass
-impl<T> core::ops::Div<MomentOfInertia<T>> for Area<T> where T: NumLike {
-	type Output = InverseMass<T>;
-	fn div(self, rhs: MomentOfInertia<T>) -> Self::Output {
-		InverseMass{per_kg: self.m2 / rhs.kgm2}
+/// Dividing a InverseArea by a Illuminance returns a value of type InverseLuminousFlux
+impl<T> core::ops::Div<&Illuminance<T>> for &InverseArea<T> where T: NumLike {
+	type Output = InverseLuminousFlux<T>;
+	fn div(self, rhs: &Illuminance<T>) -> Self::Output {
+		InverseLuminousFlux{per_lm: self.per_m2.clone() / rhs.lux.clone()}
 	}
-}
-/// Dividing a Area by a MomentOfInertia returns a value of type InverseMass
-impl<T> core::ops::Div<MomentOfInertia<T>> for &Area<T> where T: NumLike {
-	type Output = InverseMass<T>;
-	fn div(self, rhs: MomentOfInertia<T>) -> Self::Output {
-		InverseMass{per_kg: self.m2.clone() / rhs.kgm2}
+}
+
+// InverseArea / InverseCharge -> SurfaceChargeDensity
+/// Dividing a InverseArea by a InverseCharge returns a value of type SurfaceChargeDensity
+impl<T> core::ops::Div<InverseCharge<T>> for InverseArea<T> where T: NumLike {
+	type Output = SurfaceChargeDensity<T>;
+	fn div(self, rhs: InverseCharge<T>) -> Self::Output {
+		SurfaceChargeDensity{Cpm2: self.per_m2 / rhs.per_C}
 	}
 }
-/// Dividing a Area by a MomentOfInertia returns a value of type InverseMass
-impl<T> core::ops::Div<&MomentOfInertia<T>> for Area<T> where T: NumLike {
-	type Output = InverseMass<T>;
-	fn div(self, rhs: &MomentOfInertia<T>) -> Self::Output {
-		InverseMass{per_kg: self.m2 / rhs.kgm2.clone()}
+/// Dividing a InverseArea by a InverseCharge returns a value of type SurfaceChargeDensity
+impl<T> core::ops::Div<InverseCharge<T>> for &InverseArea<T> where T: NumLike {
+	type Output = SurfaceChargeDensity<T>;
+	fn div(self, rhs: InverseCharge<T>) -> Self::Output {
+		SurfaceChargeDensity{Cpm2: self.per_m2.clone() / rhs.per_C}
 	}
 }
-/// Dividing a Area by a MomentOfInertia returns a value of type InverseMass
-impl<T> core::ops::Div<&MomentOfInertia<T>> for &Area<T> where T: NumLike {
-	type Output = InverseMass<T>;
-	fn div(self, rhs: &MomentOfInertia<T>) -> Self::Output {
-		InverseMass{per_kg: self.m2.clone() / rhs.kgm2.clone()}
+/// Dividing a InverseArea by a InverseCharge returns a value of type SurfaceChargeDensity
+impl<T> core::ops::Div<&InverseCharge<T>> for InverseArea<T> where T: NumLike {
+	type Output = SurfaceChargeDensity<T>;
+	fn div(self, rhs: &InverseCharge<T>) -> Self::Output {
+		SurfaceChargeDensity{Cpm2: self.per_m2 / rhs.per_C.clone()}
+	}
+}
+/// Dividing a InverseArea by a InverseCharge returns a value of type SurfaceChargeDensity
+impl<T> core::ops::Div<&InverseCharge<T>> for &InverseArea<T> where T: NumLike {
+	type Output = SurfaceChargeDensity<T>;
+	fn div(self, rhs: &InverseCharge<T>) -> Self::Output {
+		SurfaceChargeDensity{Cpm2: self.per_m2.clone() / rhs.per_C.clone()}
 	}
 }
 
-// Area * Pressure -> Force
-/// Multiplying a Area by a Pressure returns a value of type Force
-impl<T> core::ops::Mul<Pressure<T>> for Area<T> where T: NumLike {
-	type Output = Force<T>;
-	fn mul(self, rhs: Pressure<T>) -> Self::Output {
-		Force{N: self.m2 * rhs.Pa}
+// InverseArea * InverseIrradiance -> InversePower
+/// Multiplying a InverseArea by a InverseIrradiance returns a value of type InversePower
+impl<T> core::ops::Mul<InverseIrradiance<T>> for InverseArea<T> where T: NumLike {
+	type Output = InversePower<T>;
+	fn mul(self, rhs: InverseIrradiance<T>) -> Self::Output {
+		InversePower{per_W: self.per_m2 * rhs.m2_per_W}
 	}
 }
-/// Multiplying a Area by a Pressure returns a value of type Force
-impl<T> core::ops::Mul<Pressure<T>> for &Area<T> where T: NumLike {
-	type Output = Force<T>;
-	fn mul(self, rhs: Pressure<T>) -> Self::Output {
-		Force{N: self.m2.clone() * rhs.Pa}
+/// Multiplying a InverseArea by a InverseIrradiance returns a value of type InversePower
+impl<T> core::ops::Mul<InverseIrradiance<T>> for &InverseArea<T> where T: NumLike {
+	type Output = InversePower<T>;
+	fn mul(self, rhs: InverseIrradiance<T>) -> Self::Output {
+		InversePower{per_W: self.per_m2.clone() * rhs.m2_per_W}
 	}
 }
-/// Multiplying a Area by a Pressure returns a value of type Force
-impl<T> core::ops::Mul<&Pressure<T>> for Area<T> where T: NumLike {
-	type Output = Force<T>;
-	fn mul(self, rhs: &Pressure<T>) -> Self::Output {
-		Force{N: self.m2 * rhs.Pa.clone()}
+/// Multiplying a InverseArea by a InverseIrradiance returns a value of type InversePower
+impl<T> core::ops::Mul<&InverseIrradiance<T>> for InverseArea<T> where T: NumLike {
+	type Output = InversePower<T>;
+	fn mul(self, rhs: &InverseIrradiance<T>) -> Self::Output {
+		InversePower{per_W: self.per_m2 * rhs.m2_per_W.clone()}
 	}
 }
-/// Multiplying a Area by a Pressure returns a value of type Force
-impl<T> core::ops::Mul<&Pressure<T>> for &Area<T> where T: NumLike {
-	type Output = Force<T>;
-	fn mul(self, rhs: &Pressure<T>) -> Self::Output {
-		Force{N: self.m2.clone() * rhs.Pa.clone()}
+/// Multiplying a InverseArea by a InverseIrradiance returns a value of type InversePower
+impl<T> core::ops::Mul<&InverseIrradiance<T>> for &InverseArea<T> where T: NumLike {
+	type Output = InversePower<T>;
+	fn mul(self, rhs: &InverseIrradiance<T>) -> Self::Output {
+		InversePower{per_W: self.per_m2.clone() * rhs.m2_per_W.clone()}
 	}
 }
 
-// 1/Area -> InverseArea
-/// Dividing a scalar value by a Area unit value returns a value of type InverseArea
-impl<T> core::ops::Div<Area<T>> for f64 where T: NumLike+From<f64> {
-	type Output = InverseArea<T>;
-	fn div(self, rhs: Area<T>) -> Self::Output {
-		InverseArea{per_m2: T::from(self) / rhs.m2}
+// InverseArea / InverseLinearChargeDensity -> VolumeChargeDensity
+/// Dividing a InverseArea by a InverseLinearChargeDensity returns a value of type VolumeChargeDensity
+impl<T> core::ops::Div<InverseLinearChargeDensity<T>> for InverseArea<T> where T: NumLike {
+	type Output = VolumeChargeDensity<T>;
+	fn div(self, rhs: InverseLinearChargeDensity<T>) -> Self::Output {
+		VolumeChargeDensity{Cpm3: self.per_m2 / rhs.m_per_C}
 	}
 }
-/// Dividing a scalar value by a Area unit value returns a value of type InverseArea
-impl<T> core::ops::Div<Area<T>> for &f64 where T: NumLike+From<f64> {
-	type Output = InverseArea<T>;
-	fn div(self, rhs: Area<T>) -> Self::Output {
-		InverseArea{per_m2: T::from(self.clone()) / rhs.m2}
+/// Dividing a InverseArea by a InverseLinearChargeDensity returns a value of type VolumeChargeDensity
+impl<T> core::ops::Div<InverseLinearChargeDensity<T>> for &InverseArea<T> where T: NumLike {
+	type Output = VolumeChargeDensity<T>;
+	fn div(self, rhs: InverseLinearChargeDensity<T>) -> Self::Output {
+		VolumeChargeDensity{Cpm3: self.per_m2.clone() / rhs.m_per_C}
 	}
 }
-/// Dividing a scalar value by a Area unit value returns a value of type InverseArea
-impl<T> core::ops::Div<&Area<T>> for f64 where T: NumLike+From<f64> {
-	type Output = InverseArea<T>;
-	fn div(self, rhs: &Area<T>) -> Self::Output {
-		InverseArea{per_m2: T::from(self) / rhs.m2.clone()}
+/// Dividing a InverseArea by a InverseLinearChargeDensity returns a value of type VolumeChargeDensity
+impl<T> core::ops::Div<&InverseLinearChargeDensity<T>> for InverseArea<T> where T: NumLike {
+	type Output = VolumeChargeDensity<T>;
+	fn div(self, rhs: &InverseLinearChargeDensity<T>) -> Self::Output {
+		VolumeChargeDensity{Cpm3: self.per_m2 / rhs.m_per_C.clone()}
 	}
 }
-/// Dividing a scalar value by a Area unit value returns a value of type InverseArea
-impl<T> core::ops::Div<&Area<T>> for &f64 where T: NumLike+From<f64> {
-	type Output = InverseArea<T>;
-	fn div(self, rhs: &Area<T>) -> Self::Output {
-		InverseArea{per_m2: T::from(self.clone()) / rhs.m2.clone()}
+/// Dividing a InverseArea by a InverseLinearChargeDensity returns a value of type VolumeChargeDensity
+impl<T> core::ops::Div<&InverseLinearChargeDensity<T>> for &InverseArea<T> where T: NumLike {
+	type Output = VolumeChargeDensity<T>;
+	fn div(self, rhs: &InverseLinearChargeDensity<T>) -> Self::Output {
+		VolumeChargeDensity{Cpm3: self.per_m2.clone() / rhs.m_per_C.clone()}
 	}
 }
 
-// 1/Area -> InverseArea
-/// Dividing a scalar value by a Area unit value returns a value of type InverseArea
-impl<T> core::ops::Div<Area<T>> for f32 where T: NumLike+From<f32> {
-	type Output = InverseArea<T>;
-	fn div(self, rhs: Area<T>) -> Self::Output {
-		InverseArea{per_m2: T::from(self) / rhs.m2}
+// InverseArea / InverseLuminousFlux -> Illuminance
+/// Dividing a InverseArea by a InverseLuminousFlux returns a value of type Illuminance
+impl<T> core::ops::Div<InverseLuminousFlux<T>> for InverseArea<T> where T: NumLike {
+	type Output = Illuminance<T>;
+	fn div(self, rhs: InverseLuminousFlux<T>) -> Self::Output {
+		Illuminance{lux: self.per_m2 / rhs.per_lm}
 	}
 }
-/// Dividing a scalar value by a Area unit value returns a value of type InverseArea
-impl<T> core::ops::Div<Area<T>> for &f32 where T: NumLike+From<f32> {
-	type Output = InverseArea<T>;
-	fn div(self, rhs: Area<T>) -> Self::Output {
-		InverseArea{per_m2: T::from(self.clone()) / rhs.m2}
+/// Dividing a InverseArea by a InverseLuminousFlux returns a value of type Illuminance
+impl<T> core::ops::Div<InverseLuminousFlux<T>> for &InverseArea<T> where T: NumLike {
+	type Output = Illuminance<T>;
+	fn div(self, rhs: InverseLuminousFlux<T>) -> Self::Output {
+		Illuminance{lux: self.per_m2.clone() / rhs.per_lm}
 	}
 }
-/// Dividing a scalar value by a Area unit value returns a value of type InverseArea
-impl<T> core::ops::Div<&Area<T>> for f32 where T: NumLike+From<f32> {
-	type Output = InverseArea<T>;
-	fn div(self, rhs: &Area<T>) -> Self::Output {
-		InverseArea{per_m2: T::from(self) / rhs.m2.clone()}
+/// Dividing a InverseArea by a InverseLuminousFlux returns a value of type Illuminance
+impl<T> core::ops::Div<&InverseLuminousFlux<T>> for InverseArea<T> where T: NumLike {
+	type Output = Illuminance<T>;
+	fn div(self, rhs: &InverseLuminousFlux<T>) -> Self::Output {
+		Illuminance{lux: self.per_m2 / rhs.per_lm.clone()}
 	}
 }
-/// Dividing a scalar value by a Area unit value returns a value of type InverseArea
-impl<T> core::ops::Div<&Area<T>> for &f32 where T: NumLike+From<f32> {
-	type Output = InverseArea<T>;
-	fn div(self, rhs: &Area<T>) -> Self::Output {
-		InverseArea{per_m2: T::from(self.clone()) / rhs.m2.clone()}
+/// Dividing a InverseArea by a InverseLuminousFlux returns a value of type Illuminance
+impl<T> core::ops::Div<&InverseLuminousFlux<T>> for &InverseArea<T> where T: NumLike {
+	type Output = Illuminance<T>;
+	fn div(self, rhs: &InverseLuminousFlux<T>) -> Self::Output {
+		Illuminance{lux: self.per_m2.clone() / rhs.per_lm.clone()}
 	}
 }
 
-// 1/Area -> InverseArea
-/// Dividing a scalar value by a Area unit value returns a value of type InverseArea
-impl<T> core::ops::Div<Area<T>> for i64 where T: NumLike+From<i64> {
-	type Output = InverseArea<T>;
-	fn div(self, rhs: Area<T>) -> Self::Output {
-		InverseArea{per_m2: T::from(self) / rhs.m2}
+// InverseArea / InverseMagneticFlux -> MagneticFluxDensity
+/// Dividing a InverseArea by a InverseMagneticFlux returns a value of type MagneticFluxDensity
+impl<T> core::ops::Div<InverseMagneticFlux<T>> for InverseArea<T> where T: NumLike {
+	type Output = MagneticFluxDensity<T>;
+	fn div(self, rhs: InverseMagneticFlux<T>) -> Self::Output {
+		MagneticFluxDensity{T: self.per_m2 / rhs.per_Wb}
 	}
 }
-/// Dividing a scalar value by a Area unit value returns a value of type InverseArea
-impl<T> core::ops::Div<Area<T>> for &i64 where T: NumLike+From<i64> {
-	type Output = InverseArea<T>;
-	fn div(self, rhs: Area<T>) -> Self::Output {
-		InverseArea{per_m2: T::from(self.clone()) / rhs.m2}
+/// Dividing a InverseArea by a InverseMagneticFlux returns a value of type MagneticFluxDensity
+impl<T> core::ops::Div<InverseMagneticFlux<T>> for &InverseArea<T> where T: NumLike {
+	type Output = MagneticFluxDensity<T>;
+	fn div(self, rhs: InverseMagneticFlux<T>) -> Self::Output {
+		MagneticFluxDensity{T: self.per_m2.clone() / rhs.per_Wb}
 	}
 }
-/// Dividing a scalar value by a Area unit value returns a value of type InverseArea
-impl<T> core::ops::Div<&Area<T>> for i64 where T: NumLike+From<i64> {
-	type Output = InverseArea<T>;
-	fn div(self, rhs: &Area<T>) -> Self::Output {
-		InverseArea{per_m2: T::from(self) / rhs.m2.clone()}
+/// Dividing a InverseArea by a InverseMagneticFlux returns a value of type MagneticFluxDensity
+impl<T> core::ops::Div<&InverseMagneticFlux<T>> for InverseArea<T> where T: NumLike {
+	type Output = MagneticFluxDensity<T>;
+	fn div(self, rhs: &InverseMagneticFlux<T>) -> Self::Output {
+		MagneticFluxDensity{T: self.per_m2 / rhs.per_Wb.clone()}
 	}
 }
-/// Dividing a scalar value by a Area unit value returns a value of type InverseArea
-impl<T> core::ops::Div<&Area<T>> for &i64 where T: NumLike+From<i64> {
-	type Output = InverseArea<T>;
-	fn div(self, rhs: &Area<T>) -> Self::Output {
-		InverseArea{per_m2: T::from(self.clone()) / rhs.m2.clone()}
+/// Dividing a InverseArea by a InverseMagneticFlux returns a value of type MagneticFluxDensity
+impl<T> core::ops::Div<&InverseMagneticFlux<T>> for &InverseArea<T> where T: NumLike {
+	type Output = MagneticFluxDensity<T>;
+	fn div(self, rhs: &InverseMagneticFlux<T>) -> Self::Output {
+		MagneticFluxDensity{T: self.per_m2.clone() / rhs.per_Wb.clone()}
 	}
 }
 
-// 1/Area -> InverseArea
-/// Dividing a scalar value by a Area unit value returns a value of type InverseArea
-impl<T> core::ops::Div<Area<T>> for i32 where T: NumLike+From<i32> {
-	type Output = InverseArea<T>;
-	fn div(self, rhs: Area<T>) -> Self::Output {
-		InverseArea{per_m2: T::from(self) / rhs.m2}
+// InverseArea * InverseMagneticFluxDensity -> InverseMagneticFlux
+/// Multiplying a InverseArea by a InverseMagneticFluxDensity returns a value of type InverseMagneticFlux
+impl<T> core::ops::Mul<InverseMagneticFluxDensity<T>> for InverseArea<T> where T: NumLike {
+	type Output = InverseMagneticFlux<T>;
+	fn mul(self, rhs: InverseMagneticFluxDensity<T>) -> Self::Output {
+		InverseMagneticFlux{per_Wb: self.per_m2 * rhs.m2_per_Wb}
 	}
 }
-/// Dividing a scalar value by a Area unit value returns a value of type InverseArea
-impl<T> core::ops::Div<Area<T>> for &i32 where T: NumLike+From<i32> {
-	type Output = InverseArea<T>;
-	fn div(self, rhs: Area<T>) -> Self::Output {
-		InverseArea{per_m2: T::from(self.clone()) / rhs.m2}
+/// Multiplying a InverseArea by a InverseMagneticFluxDensity returns a value of type InverseMagneticFlux
+impl<T> core::ops::Mul<InverseMagneticFluxDensity<T>> for &InverseArea<T> where T: NumLike {
+	type Output = InverseMagneticFlux<T>;
+	fn mul(self, rhs: InverseMagneticFluxDensity<T>) -> Self::Output {
+		InverseMagneticFlux{per_Wb: self.per_m2.clone() * rhs.m2_per_Wb}
 	}
 }
-/// Dividing a scalar value by a Area unit value returns a value of type InverseArea
-impl<T> core::ops::Div<&Area<T>> for i32 where T: NumLike+From<i32> {
-	type Output = InverseArea<T>;
-	fn div(self, rhs: &Area<T>) -> Self::Output {
-		InverseArea{per_m2: T::from(self) / rhs.m2.clone()}
+/// Multiplying a InverseArea by a InverseMagneticFluxDensity returns a value of type InverseMagneticFlux
+impl<T> core::ops::Mul<&InverseMagneticFluxDensity<T>> for InverseArea<T> where T: NumLike {
+	type Output = InverseMagneticFlux<T>;
+	fn mul(self, rhs: &InverseMagneticFluxDensity<T>) -> Self::Output {
+		InverseMagneticFlux{per_Wb: self.per_m2 * rhs.m2_per_Wb.clone()}
 	}
 }
-/// Dividing a scalar value by a Area unit value returns a value of type InverseArea
-impl<T> core::ops::Div<&Area<T>> for &i32 where T: NumLike+From<i32> {
-	type Output = InverseArea<T>;
-	fn div(self, rhs: &Area<T>) -> Self::Output {
-		InverseArea{per_m2: T::from(self.clone()) / rhs.m2.clone()}
+/// Multiplying a InverseArea by a InverseMagneticFluxDensity returns a value of type InverseMagneticFlux
+impl<T> core::ops::Mul<&InverseMagneticFluxDensity<T>> for &InverseArea<T> where T: NumLike {
+	type Output = InverseMagneticFlux<T>;
+	fn mul(self, rhs: &InverseMagneticFluxDensity<T>) -> Self::Output {
+		InverseMagneticFlux{per_Wb: self.per_m2.clone() * rhs.m2_per_Wb.clone()}
 	}
 }
 
-// 1/Area -> InverseArea
-/// Dividing a scalar value by a Area unit value returns a value of type InverseArea
-#[cfg(feature="num-bigfloat")]
-impl<T> core::ops::Div<Area<T>> for num_bigfloat::BigFloat where T: NumLike+From<num_bigfloat::BigFloat> {
-	type Output = InverseArea<T>;
-	fn div(self, rhs: Area<T>) -> Self::Output {
-		InverseArea{per_m2: T::from(self) / rhs.m2}
+// InverseArea * InverseSurfaceChargeDensity -> InverseCharge
+/// Multiplying a InverseArea by a InverseSurfaceChargeDensity returns a value of type InverseCharge
+impl<T> core::ops::Mul<InverseSurfaceChargeDensity<T>> for InverseArea<T> where T: NumLike {
+	type Output = InverseCharge<T>;
+	fn mul(self, rhs: InverseSurfaceChargeDensity<T>) -> Self::Output {
+		InverseCharge{per_C: self.per_m2 * rhs.m2_per_C}
 	}
 }
-/// Dividing a scalar value by a Area unit value returns a value of type InverseArea
-#[cfg(feature="num-bigfloat")]
-impl<T> core::ops::Div<Area<T>> for &num_bigfloat::BigFloat where T: NumLike+From<num_bigfloat::BigFloat> {
-	type Output = InverseArea<T>;
-	fn div(self, rhs: Area<T>) -> Self::Output {
-		InverseArea{per_m2: T::from(self.clone()) / rhs.m2}
+/// Multiplying a InverseArea by a InverseSurfaceChargeDensity returns a value of type InverseCharge
+impl<T> core::ops::Mul<InverseSurfaceChargeDensity<T>> for &InverseArea<T> where T: NumLike {
+	type Output = InverseCharge<T>;
+	fn mul(self, rhs: InverseSurfaceChargeDensity<T>) -> Self::Output {
+		InverseCharge{per_C: self.per_m2.clone() * rhs.m2_per_C}
 	}
 }
-/// Dividing a scalar value by a Area unit value returns a value of type InverseArea
-#[cfg(feature="num-bigfloat")]
-impl<T> core::ops::Div<&Area<T>> for num_bigfloat::BigFloat where T: NumLike+From<num_bigfloat::BigFloat> {
-	type Output = InverseArea<T>;
-	fn div(self, rhs: &Area<T>) -> Self::Output {
-		InverseArea{per_m2: T::from(self) / rhs.m2.clone()}
+/// Multiplying a InverseArea by a InverseSurfaceChargeDensity returns a value of type InverseCharge
+impl<T> core::ops::Mul<&InverseSurfaceChargeDensity<T>> for InverseArea<T> where T: NumLike {
+	type Output = InverseCharge<T>;
+	fn mul(self, rhs: &InverseSurfaceChargeDensity<T>) -> Self::Output {
+		InverseCharge{per_C: self.per_m2 * rhs.m2_per_C.clone()}
 	}
 }
-/// Dividing a scalar value by a Area unit value returns a value of type InverseArea
-#[cfg(feature="num-bigfloat")]
-impl<T> core::ops::Div<&Area<T>> for &num_bigfloat::BigFloat where T: NumLike+From<num_bigfloat::BigFloat> {
-	type Output = InverseArea<T>;
-	fn div(self, rhs: &Area<T>) -> Self::Output {
-		InverseArea{per_m2: T::from(self.clone()) / rhs.m2.clone()}
+/// Multiplying a InverseArea by a InverseSurfaceChargeDensity returns a value of type InverseCharge
+impl<T> core::ops::Mul<&InverseSurfaceChargeDensity<T>> for &InverseArea<T> where T: NumLike {
+	type Output = InverseCharge<T>;
+	fn mul(self, rhs: &InverseSurfaceChargeDensity<T>) -> Self::Output {
+		InverseCharge{per_C: self.per_m2.clone() * rhs.m2_per_C.clone()}
 	}
 }
 
-// 1/Area -> InverseArea
-/// Dividing a scalar value by a Area unit value returns a value of type InverseArea
-#[cfg(feature="num-complex")]
-impl<T> core::ops::Div<Area<T>> for num_complex::Complex32 where T: NumLike+From<num_complex::Complex32> {
-	type Output = InverseArea<T>;
-	fn div(self, rhs: Area<T>) -> Self::Output {
-		InverseArea{per_m2: T::from(self) / rhs.m2}
+// InverseArea * InverseVolumeChargeDensity -> InverseLinearChargeDensity
+/// Multiplying a InverseArea by a InverseVolumeChargeDensity returns a value of type InverseLinearChargeDensity
+impl<T> core::ops::Mul<InverseVolumeChargeDensity<T>> for InverseArea<T> where T: NumLike {
+	type Output = InverseLinearChargeDensity<T>;
+	fn mul(self, rhs: InverseVolumeChargeDensity<T>) -> Self::Output {
+		InverseLinearChargeDensity{m_per_C: self.per_m2 * rhs.m3_per_C}
 	}
 }
-/// Dividing a scalar value by a Area unit value returns a value of type InverseArea
-#[cfg(feature="num-complex")]
-impl<T> core::ops::Div<Area<T>> for &num_complex::Complex32 where T: NumLike+From<num_complex::Complex32> {
-	type Output = InverseArea<T>;
-	fn div(self, rhs: Area<T>) -> Self::Output {
-		InverseArea{per_m2: T::from(self.clone()) / rhs.m2}
+/// Multiplying a InverseArea by a InverseVolumeChargeDensity returns a value of type InverseLinearChargeDensity
+impl<T> core::ops::Mul<InverseVolumeChargeDensity<T>> for &InverseArea<T> where T: NumLike {
+	type Output = InverseLinearChargeDensity<T>;
+	fn mul(self, rhs: InverseVolumeChargeDensity<T>) -> Self::Output {
+		InverseLinearChargeDensity{m_per_C: self.per_m2.clone() * rhs.m3_per_C}
 	}
 }
-/// Dividing a scalar value by a Area unit value returns a value of type InverseArea
-#[cfg(feature="num-complex")]
-impl<T> core::ops::Div<&Area<T>> for num_complex::Complex32 where T: NumLike+From<num_complex::Complex32> {
-	type Output = InverseArea<T>;
-	fn div(self, rhs: &Area<T>) -> Self::Output {
-		InverseArea{per_m2: T::from(self) / rhs.m2.clone()}
+/// Multiplying a InverseArea by a InverseVolumeChargeDensity returns a value of type InverseLinearChargeDensity
+impl<T> core::ops::Mul<&InverseVolumeChargeDensity<T>> for InverseArea<T> where T: NumLike {
+	type Output = InverseLinearChargeDensity<T>;
+	fn mul(self, rhs: &InverseVolumeChargeDensity<T>) -> Self::Output {
+		InverseLinearChargeDensity{m_per_C: self.per_m2 * rhs.m3_per_C.clone()}
 	}
 }
-/// Dividing a scalar value by a Area unit value returns a value of type InverseArea
-#[cfg(feature="num-complex")]
-impl<T> core::ops::Div<&Area<T>> for &num_complex::Complex32 where T: NumLike+From<num_complex::Complex32> {
-	type Output = InverseArea<T>;
-	fn div(self, rhs: &Area<T>) -> Self::Output {
-		InverseArea{per_m2: T::from(self.clone()) / rhs.m2.clone()}
+/// Multiplying a InverseArea by a InverseVolumeChargeDensity returns a value of type InverseLinearChargeDensity
+impl<T> core::ops::Mul<&InverseVolumeChargeDensity<T>> for &InverseArea<T> where T: NumLike {
+	type Output = InverseLinearChargeDensity<T>;
+	fn mul(self, rhs: &InverseVolumeChargeDensity<T>) -> Self::Output {
+		InverseLinearChargeDensity{m_per_C: self.per_m2.clone() * rhs.m3_per_C.clone()}
 	}
 }
 
-// 1/Area -> InverseArea
-/// Dividing a scalar value by a Area unit value returns a value of type InverseArea
-#[cfg(feature="num-complex")]
-impl<T> core::ops::Div<Area<T>> for num_complex::Complex64 where T: NumLike+From<num_complex::Complex64> {
-	type Output = InverseArea<T>;
-	fn div(self, rhs: Area<T>) -> Self::Output {
-		InverseArea{per_m2: T::from(self) / rhs.m2}
+// InverseArea / Irradiance -> InversePower
+/// Dividing a InverseArea by a Irradiance returns a value of type InversePower
+impl<T> core::ops::Div<Irradiance<T>> for InverseArea<T> where T: NumLike {
+	type Output = InversePower<T>;
+	fn div(self, rhs: Irradiance<T>) -> Self::Output {
+		InversePower{per_W: self.per_m2 / rhs.W_per_m2}
 	}
 }
-/// Dividing a scalar value by a Area unit value returns a value of type InverseArea
-#[cfg(feature="num-complex")]
-impl<T> core::ops::Div<Area<T>> for &num_complex::Complex64 where T: NumLike+From<num_complex::Complex64> {
-	type Output = InverseArea<T>;
-	fn div(self, rhs: Area<T>) -> Self::Output {
-		InverseArea{per_m2: T::from(self.clone()) / rhs.m2}
+/// Dividing a InverseArea by a Irradiance returns a value of type InversePower
+impl<T> core::ops::Div<Irradiance<T>> for &InverseArea<T> where T: NumLike {
+	type Output = InversePower<T>;
+	fn div(self, rhs: Irradiance<T>) -> Self::Output {
+		InversePower{per_W: self.per_m2.clone() / rhs.W_per_m2}
 	}
 }
-/// Dividing a scalar value by a Area unit value returns a value of type InverseArea
-#[cfg(feature="num-complex")]
-impl<T> core::ops::Div<&Area<T>> for num_complex::Complex64 where T: NumLike+From<num_complex::Complex64> {
-	type Output = InverseArea<T>;
-	fn div(self, rhs: &Area<T>) -> Self::Output {
-		InverseArea{per_m2: T::from(self) / rhs.m2.clone()}
+/// Dividing a InverseArea by a Irradiance returns a value of type InversePower
+impl<T> core::ops::Div<&Irradiance<T>> for InverseArea<T> where T: NumLike {
+	type Output = InversePower<T>;
+	fn div(self, rhs: &Irradiance<T>) -> Self::Output {
+		InversePower{per_W: self.per_m2 / rhs.W_per_m2.clone()}
+	}
+}
+/// Dividing a InverseArea by a Irradiance returns a value of type InversePower
+impl<T> core::ops::Div<&Irradiance<T>> for &InverseArea<T> where T: NumLike {
+	type Output = InversePower<T>;
+	fn div(self, rhs: &Irradiance<T>) -> Self::Output {
+		InversePower{per_W: self.per_m2.clone() / rhs.W_per_m2.clone()}
+	}
+}
+
+// InverseArea * LinearChargeDensity -> VolumeChargeDensity
+/// Multiplying a InverseArea by a LinearChargeDensity returns a value of type VolumeChargeDensity
+impl<T> core::ops::Mul<LinearChargeDensity<T>> for InverseArea<T> where T: NumLike {
+	type Output = VolumeChargeDensity<T>;
+	fn mul(self, rhs: LinearChargeDensity<T>) -> Self::Output {
+		VolumeChargeDensity{Cpm3: self.per_m2 * rhs.Cpm}
 	}
 }
-/// Dividing a scalar value by a Area unit value returns a value of type InverseArea
-#[cfg(feature="num-complex")]
-impl<T> core::ops::Div<&Area<T>> for &num_complex::Complex64 where T: NumLike+From<num_complex::Complex64> {
-	type Output = InverseArea<T>;
-	fn div(self, rhs: &Area<T>) -> Self::Output {
-		InverseArea{per_m2: T::from(self.clone()) / rhs.m2.clone()}
+/// Multiplying a InverseArea by a LinearChargeDensity returns a value of type VolumeChargeDensity
+impl<T> core::ops::Mul<LinearChargeDensity<T>> for &InverseArea<T> where T: NumLike {
+	type Output = VolumeChargeDensity<T>;
+	fn mul(self, rhs: LinearChargeDensity<T>) -> Self::Output {
+		VolumeChargeDensity{Cpm3: self.per_m2.clone() * rhs.Cpm}
 	}
 }
-
-/// The inverse of angle unit type, defined as inverse radians in SI units
-#[derive(UnitStruct, Debug, Clone)]
-#[cfg_attr(feature="serde", derive(Serialize, Deserialize))]
-pub struct InverseAngle<T: NumLike>{
-	/// The value of this Inverse angle in inverse radians
-	pub per_rad: T
+/// Multiplying a InverseArea by a LinearChargeDensity returns a value of type VolumeChargeDensity
+impl<T> core::ops::Mul<&LinearChargeDensity<T>> for InverseArea<T> where T: NumLike {
+	type Output = VolumeChargeDensity<T>;
+	fn mul(self, rhs: &LinearChargeDensity<T>) -> Self::Output {
+		VolumeChargeDensity{Cpm3: self.per_m2 * rhs.Cpm.clone()}
+	}
 }
-
-impl<T> InverseAngle<T> where T: NumLike {
-
-	/// Returns the standard unit name of inverse angle: "inverse radians"
-	pub fn unit_name() -> &'static str { "inverse radians" }
-	
-	/// Returns the abbreviated name or symbol of inverse angle: "1/rad" for inverse radians
-	pub fn unit_symbol() -> &'static str { "1/rad" }
-	
-	/// Returns a new inverse angle value from the given number of inverse radians
-	///
-	/// # Arguments
-	/// * `per_rad` - Any number-like type, representing a quantity of inverse radians
-	pub fn from_per_rad(per_rad: T) -> Self { InverseAngle{per_rad: per_rad} }
-	
-	/// Returns a copy of this inverse angle value in inverse radians
-	pub fn to_per_rad(&self) -> T { self.per_rad.clone() }
-
-	/// Returns a new inverse angle value from the given number of inverse radians
-	///
-	/// # Arguments
-	/// * `per_radians` - Any number-like type, representing a quantity of inverse radians
-	pub fn from_per_radians(per_radians: T) -> Self { InverseAngle{per_rad: per_radians} }
-	
-	/// Returns a copy of this inverse angle value in inverse radians
-	pub fn to_per_radians(&self) -> T { self.per_rad.clone() }
-
+/// Multiplying a InverseArea by a LinearChargeDensity returns a value of type VolumeChargeDensity
+impl<T> core::ops::Mul<&LinearChargeDensity<T>> for &InverseArea<T> where T: NumLike {
+	type Output = VolumeChargeDensity<T>;
+	fn mul(self, rhs: &LinearChargeDensity<T>) -> Self::Output {
+		VolumeChargeDensity{Cpm3: self.per_m2.clone() * rhs.Cpm.clone()}
+	}
 }
 
-impl<T> fmt::Display for InverseAngle<T> where T: NumLike {
-	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-		write!(f, "{} {}", &self.per_rad, Self::unit_symbol())
+// InverseArea * LuminousFlux -> Illuminance
+/// Multiplying a InverseArea by a LuminousFlux returns a value of type Illuminance
+impl<T> core::ops::Mul<LuminousFlux<T>> for InverseArea<T> where T: NumLike {
+	type Output = Illuminance<T>;
+	fn mul(self, rhs: LuminousFlux<T>) -> Self::Output {
+		Illuminance{lux: self.per_m2 * rhs.lm}
 	}
 }
-
-impl<T> InverseAngle<T> where T: NumLike+From<f64> {
-	
-	/// Returns a copy of this inverse angle value in inverse degrees
-	/// 
-	/// *Note: This method is not available for `f32` and other number types lacking the `From<f64>` trait*
-	pub fn to_per_degrees(&self) -> T {
-		return self.per_rad.clone() * T::from(0.0174532925199433_f64);
+/// Multiplying a InverseArea by a LuminousFlux returns a value of type Illuminance
+impl<T> core::ops::Mul<LuminousFlux<T>> for &InverseArea<T> where T: NumLike {
+	type Output = Illuminance<T>;
+	fn mul(self, rhs: LuminousFlux<T>) -> Self::Output {
+		Illuminance{lux: self.per_m2.clone() * rhs.lm}
 	}
-
-	/// Returns a new inverse angle value from the given number of inverse degrees
-	/// 
-	/// *Note: This method is not available for `f32` and other number types lacking the `From<f64>` trait*
-	///
-	/// # Arguments
-	/// * `per_degrees` - Any number-like type, representing a quantity of inverse degrees
-	pub fn from_per_degrees(per_degrees: T) -> Self {
-		InverseAngle{per_rad: per_degrees * T::from(57.2957795130823_f64)}
+}
+/// Multiplying a InverseArea by a LuminousFlux returns a value of type Illuminance
+impl<T> core::ops::Mul<&LuminousFlux<T>> for InverseArea<T> where T: NumLike {
+	type Output = Illuminance<T>;
+	fn mul(self, rhs: &LuminousFlux<T>) -> Self::Output {
+		Illuminance{lux: self.per_m2 * rhs.lm.clone()}
 	}
-
-	/// Returns a copy of this inverse angle value in inverse degrees
-	/// 
-	/// *Note: This method is not available for `f32` and other number types lacking the `From<f64>` trait*
-	pub fn to_per_deg(&self) -> T {
-		return self.per_rad.clone() * T::from(0.0174532925199433_f64);
+}
+/// Multiplying a InverseArea by a LuminousFlux returns a value of type Illuminance
+impl<T> core::ops::Mul<&LuminousFlux<T>> for &InverseArea<T> where T: NumLike {
+	type Output = Illuminance<T>;
+	fn mul(self, rhs: &LuminousFlux<T>) -> Self::Output {
+		Illuminance{lux: self.per_m2.clone() * rhs.lm.clone()}
 	}
+}
 
-	/// Returns a new inverse angle value from the given number of inverse degrees
-	/// 
-	/// *Note: This method is not available for `f32` and other number types lacking the `From<f64>` trait*
-	///
-	/// # Arguments
-	/// * `per_deg` - Any number-like type, representing a quantity of inverse degrees
-	pub fn from_per_deg(per_deg: T) -> Self {
-		InverseAngle{per_rad: per_deg * T::from(57.2957795130823_f64)}
+// InverseArea * MagneticFlux -> MagneticFluxDensity
+/// Multiplying a InverseArea by a MagneticFlux returns a value of type MagneticFluxDensity
+impl<T> core::ops::Mul<MagneticFlux<T>> for InverseArea<T> where T: NumLike {
+	type Output = MagneticFluxDensity<T>;
+	fn mul(self, rhs: MagneticFlux<T>) -> Self::Output {
+		MagneticFluxDensity{T: self.per_m2 * rhs.Wb}
+	}
+}
+/// Multiplying a InverseArea by a MagneticFlux returns a value of type MagneticFluxDensity
+impl<T> core::ops::Mul<MagneticFlux<T>> for &InverseArea<T> where T: NumLike {
+	type Output = MagneticFluxDensity<T>;
+	fn mul(self, rhs: MagneticFlux<T>) -> Self::Output {
+		MagneticFluxDensity{T: self.per_m2.clone() * rhs.Wb}
+	}
+}
+/// Multiplying a InverseArea by a MagneticFlux returns a value of type MagneticFluxDensity
+impl<T> core::ops::Mul<&MagneticFlux<T>> for InverseArea<T> where T: NumLike {
+	type Output = MagneticFluxDensity<T>;
+	fn mul(self, rhs: &MagneticFlux<T>) -> Self::Output {
+		MagneticFluxDensity{T: self.per_m2 * rhs.Wb.clone()}
+	}
+}
+/// Multiplying a InverseArea by a MagneticFlux returns a value of type MagneticFluxDensity
+impl<T> core::ops::Mul<&MagneticFlux<T>> for &InverseArea<T> where T: NumLike {
+	type Output = MagneticFluxDensity<T>;
+	fn mul(self, rhs: &MagneticFlux<T>) -> Self::Output {
+		MagneticFluxDensity{T: self.per_m2.clone() * rhs.Wb.clone()}
 	}
-
 }
 
-
-/// Multiplying a unit value by a scalar value returns a unit value
-#[cfg(feature="num-bigfloat")]
-impl core::ops::Mul<InverseAngle<num_bigfloat::BigFloat>> for num_bigfloat::BigFloat {
-	type Output = InverseAngle<num_bigfloat::BigFloat>;
-	fn mul(self, rhs: InverseAngle<num_bigfloat::BigFloat>) -> Self::Output {
-		InverseAngle{per_rad: self * rhs.per_rad}
+// InverseArea / MagneticFluxDensity -> InverseMagneticFlux
+/// Dividing a InverseArea by a MagneticFluxDensity returns a value of type InverseMagneticFlux
+impl<T> core::ops::Div<MagneticFluxDensity<T>> for InverseArea<T> where T: NumLike {
+	type Output = InverseMagneticFlux<T>;
+	fn div(self, rhs: MagneticFluxDensity<T>) -> Self::Output {
+		InverseMagneticFlux{per_Wb: self.per_m2 / rhs.T}
 	}
 }
-/// Multiplying a unit value by a scalar value returns a unit value
-#[cfg(feature="num-bigfloat")]
-impl core::ops::Mul<InverseAngle<num_bigfloat::BigFloat>> for &num_bigfloat::BigFloat {
-	type Output = InverseAngle<num_bigfloat::BigFloat>;
-	fn mul(self, rhs: InverseAngle<num_bigfloat::BigFloat>) -> Self::Output {
-		InverseAngle{per_rad: self.clone() * rhs.per_rad}
+/// Dividing a InverseArea by a MagneticFluxDensity returns a value of type InverseMagneticFlux
+impl<T> core::ops::Div<MagneticFluxDensity<T>> for &InverseArea<T> where T: NumLike {
+	type Output = InverseMagneticFlux<T>;
+	fn div(self, rhs: MagneticFluxDensity<T>) -> Self::Output {
+		InverseMagneticFlux{per_Wb: self.per_m2.clone() / rhs.T}
 	}
 }
-/// Multiplying a unit value by a scalar value returns a unit value
-#[cfg(feature="num-bigfloat")]
-impl core::ops::Mul<&InverseAngle<num_bigfloat::BigFloat>> for num_bigfloat::BigFloat {
-	type Output = InverseAngle<num_bigfloat::BigFloat>;
-	fn mul(self, rhs: &InverseAngle<num_bigfloat::BigFloat>) -> Self::Output {
-		InverseAngle{per_rad: self * rhs.per_rad.clone()}
+/// Dividing a InverseArea by a MagneticFluxDensity returns a value of type InverseMagneticFlux
+impl<T> core::ops::Div<&MagneticFluxDensity<T>> for InverseArea<T> where T: NumLike {
+	type Output = InverseMagneticFlux<T>;
+	fn div(self, rhs: &MagneticFluxDensity<T>) -> Self::Output {
+		InverseMagneticFlux{per_Wb: self.per_m2 / rhs.T.clone()}
 	}
 }
-/// Multiplying a unit value by a scalar value returns a unit value
-#[cfg(feature="num-bigfloat")]
-impl core::ops::Mul<&InverseAngle<num_bigfloat::BigFloat>> for &num_bigfloat::BigFloat {
-	type Output = InverseAngle<num_bigfloat::BigFloat>;
-	fn mul(self, rhs: &InverseAngle<num_bigfloat::BigFloat>) -> Self::Output {
-		InverseAngle{per_rad: self.clone() * rhs.per_rad.clone()}
+/// Dividing a InverseArea by a MagneticFluxDensity returns a value of type InverseMagneticFlux
+impl<T> core::ops::Div<&MagneticFluxDensity<T>> for &InverseArea<T> where T: NumLike {
+	type Output = InverseMagneticFlux<T>;
+	fn div(self, rhs: &MagneticFluxDensity<T>) -> Self::Output {
+		InverseMagneticFlux{per_Wb: self.per_m2.clone() / rhs.T.clone()}
 	}
 }
 
-/// Multiplying a unit value by a scalar value returns a unit value
-#[cfg(feature="num-complex")]
-impl core::ops::Mul<InverseAngle<num_complex::Complex32>> for num_complex::Complex32 {
-	type Output = InverseAngle<num_complex::Complex32>;
-	fn mul(self, rhs: InverseAngle<num_complex::Complex32>) -> Self::Output {
-		InverseAngle{per_rad: self * rhs.per_rad}
+// InverseArea / SurfaceChargeDensity -> InverseCharge
+/// Dividing a InverseArea by a SurfaceChargeDensity returns a value of type InverseCharge
+impl<T> core::ops::Div<SurfaceChargeDensity<T>> for InverseArea<T> where T: NumLike {
+	type Output = InverseCharge<T>;
+	fn div(self, rhs: SurfaceChargeDensity<T>) -> Self::Output {
+		InverseCharge{per_C: self.per_m2 / rhs.Cpm2}
 	}
 }
-/// Multiplying a unit value by a scalar value returns a unit value
-#[cfg(feature="num-complex")]
-impl core::ops::Mul<InverseAngle<num_complex::Complex32>> for &num_complex::Complex32 {
-	type Output = InverseAngle<num_complex::Complex32>;
-	fn mul(self, rhs: InverseAngle<num_complex::Complex32>) -> Self::Output {
-		InverseAngle{per_rad: self.clone() * rhs.per_rad}
+/// Dividing a InverseArea by a SurfaceChargeDensity returns a value of type InverseCharge
+impl<T> core::ops::Div<SurfaceChargeDensity<T>> for &InverseArea<T> where T: NumLike {
+	type Output = InverseCharge<T>;
+	fn div(self, rhs: SurfaceChargeDensity<T>) -> Self::Output {
+		InverseCharge{per_C: self.per_m2.clone() / rhs.Cpm2}
 	}
 }
-/// Multiplying a unit value by a scalar value returns a unit value
-#[cfg(feature="num-complex")]
-impl core::ops::Mul<&InverseAngle<num_complex::Complex32>> for num_complex::Complex32 {
-	type Output = InverseAngle<num_complex::Complex32>;
-	fn mul(self, rhs: &InverseAngle<num_complex::Complex32>) -> Self::Output {
-		InverseAngle{per_rad: self * rhs.per_rad.clone()}
+/// Dividing a InverseArea by a SurfaceChargeDensity returns a value of type InverseCharge
+impl<T> core::ops::Div<&SurfaceChargeDensity<T>> for InverseArea<T> where T: NumLike {
+	type Output = InverseCharge<T>;
+	fn div(self, rhs: &SurfaceChargeDensity<T>) -> Self::Output {
+		InverseCharge{per_C: self.per_m2 / rhs.Cpm2.clone()}
 	}
 }
-/// Multiplying a unit value by a scalar value returns a unit value
-#[cfg(feature="num-complex")]
-impl core::ops::Mul<&InverseAngle<num_complex::Complex32>> for &num_complex::Complex32 {
-	type Output = InverseAngle<num_complex::Complex32>;
-	fn mul(self, rhs: &InverseAngle<num_complex::Complex32>) -> Self::Output {
-		InverseAngle{per_rad: self.clone() * rhs.per_rad.clone()}
+/// Dividing a InverseArea by a SurfaceChargeDensity returns a value of type InverseCharge
+impl<T> core::ops::Div<&SurfaceChargeDensity<T>> for &InverseArea<T> where T: NumLike {
+	type Output = InverseCharge<T>;
+	fn div(self, rhs: &SurfaceChargeDensity<T>) -> Self::Output {
+		InverseCharge{per_C: self.per_m2.clone() / rhs.Cpm2.clone()}
 	}
 }
 
-/// Multiplying a unit value by a scalar value returns a unit value
-#[cfg(feature="num-complex")]
-impl core::ops::Mul<InverseAngle<num_complex::Complex64>> for num_complex::Complex64 {
-	type Output = InverseAngle<num_complex::Complex64>;
-	fn mul(self, rhs: InverseAngle<num_complex::Complex64>) -> Self::Output {
-		InverseAngle{per_rad: self * rhs.per_rad}
+// InverseArea / VolumeChargeDensity -> InverseLinearChargeDensity
+/// Dividing a InverseArea by a VolumeChargeDensity returns a value of type InverseLinearChargeDensity
+impl<T> core::ops::Div<VolumeChargeDensity<T>> for InverseArea<T> where T: NumLike {
+	type Output = InverseLinearChargeDensity<T>;
+	fn div(self, rhs: VolumeChargeDensity<T>) -> Self::Output {
+		InverseLinearChargeDensity{m_per_C: self.per_m2 / rhs.Cpm3}
 	}
 }
-/// Multiplying a unit value by a scalar value returns a unit value
-#[cfg(feature="num-complex")]
-impl core::ops::Mul<InverseAngle<num_complex::Complex64>> for &num_complex::Complex64 {
-	type Output = InverseAngle<num_complex::Complex64>;
-	fn mul(self, rhs: InverseAngle<num_complex::Complex64>) -> Self::Output {
-		InverseAngle{per_rad: self.clone() * rhs.per_rad}
+/// Dividing a InverseArea by a VolumeChargeDensity returns a value of type InverseLinearChargeDensity
+impl<T> core::ops::Div<VolumeChargeDensity<T>> for &InverseArea<T> where T: NumLike {
+	type Output = InverseLinearChargeDensity<T>;
+	fn div(self, rhs: VolumeChargeDensity<T>) -> Self::Output {
+		InverseLinearChargeDensity{m_per_C: self.per_m2.clone() / rhs.Cpm3}
 	}
 }
-/// Multiplying a unit value by a scalar value returns a unit value
-#[cfg(feature="num-complex")]
-impl core::ops::Mul<&InverseAngle<num_complex::Complex64>> for num_complex::Complex64 {
-	type Output = InverseAngle<num_complex::Complex64>;
-	fn mul(self, rhs: &InverseAngle<num_complex::Complex64>) -> Self::Output {
-		InverseAngle{per_rad: self * rhs.per_rad.clone()}
+/// Dividing a InverseArea by a VolumeChargeDensity returns a value of type InverseLinearChargeDensity
+impl<T> core::ops::Div<&VolumeChargeDensity<T>> for InverseArea<T> where T: NumLike {
+	type Output = InverseLinearChargeDensity<T>;
+	fn div(self, rhs: &VolumeChargeDensity<T>) -> Self::Output {
+		InverseLinearChargeDensity{m_per_C: self.per_m2 / rhs.Cpm3.clone()}
 	}
 }
-/// Multiplying a unit value by a scalar value returns a unit value
-#[cfg(feature="num-complex")]
-impl core::ops::Mul<&InverseAngle<num_complex::Complex64>> for &num_complex::Complex64 {
-	type Output = InverseAngle<num_complex::Complex64>;
-	fn mul(self, rhs: &InverseAngle<num_complex::Complex64>) -> Self::Output {
-		InverseAngle{per_rad: self.clone() * rhs.per_rad.clone()}
+/// Dividing a InverseArea by a VolumeChargeDensity returns a value of type InverseLinearChargeDensity
+impl<T> core::ops::Div<&VolumeChargeDensity<T>> for &InverseArea<T> where T: NumLike {
+	type Output = InverseLinearChargeDensity<T>;
+	fn div(self, rhs: &VolumeChargeDensity<T>) -> Self::Output {
+		InverseLinearChargeDensity{m_per_C: self.per_m2.clone() / rhs.Cpm3.clone()}
 	}
 }
 
-
-
-
-// InverseAngle * Time -> InverseAngularVelocity
-/// Multiplying a InverseAngle by a Time returns a value of type InverseAngularVelocity
-impl<T> core::ops::Mul<Time<T>> for InverseAngle<T> where T: NumLike {
-	type Output = InverseAngularVelocity<T>;
-	fn mul(self, rhs: Time<T>) -> Self::Output {
-		InverseAngularVelocity{s_per_rad: self.per_rad * rhs.s}
+// InverseArea / InverseVolume -> Distance
+/// Dividing a InverseArea by a InverseVolume returns a value of type Distance
+impl<T> core::ops::Div<InverseVolume<T>> for InverseArea<T> where T: NumLike {
+	type Output = Distance<T>;
+	fn div(self, rhs: InverseVolume<T>) -> Self::Output {
+		Distance{m: self.per_m2 / rhs.per_m3}
 	}
 }
-/// Multiplying a InverseAngle by a Time returns a value of type InverseAngularVelocity
-impl<T> core::ops::Mul<Time<T>> for &InverseAngle<T> where T: NumLike {
-	type Output = InverseAngularVelocity<T>;
-	fn mul(self, rhs: Time<T>) -> Self::Output {
-		InverseAngularVelocity{s_per_rad: self.per_rad.clone() * rhs.s}
+/// Dividing a InverseArea by a InverseVolume returns a value of type Distance
+impl<T> core::ops::Div<InverseVolume<T>> for &InverseArea<T> where T: NumLike {
+	type Output = Distance<T>;
+	fn div(self, rhs: InverseVolume<T>) -> Self::Output {
+		Distance{m: self.per_m2.clone() / rhs.per_m3}
 	}
 }
-/// Multiplying a InverseAngle by a Time returns a value of type InverseAngularVelocity
-impl<T> core::ops::Mul<&Time<T>> for InverseAngle<T> where T: NumLike {
-	type Output = InverseAngularVelocity<T>;
-	fn mul(self, rhs: &Time<T>) -> Self::Output {
-		InverseAngularVelocity{s_per_rad: self.per_rad * rhs.s.clone()}
+/// Dividing a InverseArea by a InverseVolume returns a value of type Distance
+impl<T> core::ops::Div<&InverseVolume<T>> for InverseArea<T> where T: NumLike {
+	type Output = Distance<T>;
+	fn div(self, rhs: &InverseVolume<T>) -> Self::Output {
+		Distance{m: self.per_m2 / rhs.per_m3.clone()}
 	}
-}
-/// Multiplying a InverseAngle by a Time returns a value of type InverseAngularVelocity
-impl<T> core::ops::Mul<&Time<T>> for &InverseAngle<T> where T: NumLike {
-	type Output = InverseAngularVelocity<T>;
-	fn mul(self, rhs: &Time<T>) -> Self::Output {
-		InverseAngularVelocity{s_per_rad: self.per_rad.clone() * rhs.s.clone()}
+}
+/// Dividing a InverseArea by a InverseVolume returns a value of type Distance
+impl<T> core::ops::Div<&InverseVolume<T>> for &InverseArea<T> where T: NumLike {
+	type Output = Distance<T>;
+	fn div(self, rhs: &InverseVolume<T>) -> Self::Output {
+		Distance{m: self.per_m2.clone() / rhs.per_m3.clone()}
 	}
 }
 
-// InverseAngle / Angle -> InverseSolidAngle
-/// Dividing a InverseAngle by a Angle returns a value of type InverseSolidAngle
-impl<T> core::ops::Div<Angle<T>> for InverseAngle<T> where T: NumLike {
-	type Output = InverseSolidAngle<T>;
-	fn div(self, rhs: Angle<T>) -> Self::Output {
-		InverseSolidAngle{per_sr: self.per_rad / rhs.rad}
+// InverseArea * Volume -> Distance
+/// Multiplying a InverseArea by a Volume returns a value of type Distance
+impl<T> core::ops::Mul<Volume<T>> for InverseArea<T> where T: NumLike {
+	type Output = Distance<T>;
+	fn mul(self, rhs: Volume<T>) -> Self::Output {
+		Distance{m: self.per_m2 * rhs.m3}
 	}
 }
-/// Dividing a InverseAngle by a Angle returns a value of type InverseSolidAngle
-impl<T> core::ops::Div<Angle<T>> for &InverseAngle<T> where T: NumLike {
-	type Output = InverseSolidAngle<T>;
-	fn div(self, rhs: Angle<T>) -> Self::Output {
-		InverseSolidAngle{per_sr: self.per_rad.clone() / rhs.rad}
+/// Multiplying a InverseArea by a Volume returns a value of type Distance
+impl<T> core::ops::Mul<Volume<T>> for &InverseArea<T> where T: NumLike {
+	type Output = Distance<T>;
+	fn mul(self, rhs: Volume<T>) -> Self::Output {
+		Distance{m: self.per_m2.clone() * rhs.m3}
 	}
 }
-/// Dividing a InverseAngle by a Angle returns a value of type InverseSolidAngle
-impl<T> core::ops::Div<&Angle<T>> for InverseAngle<T> where T: NumLike {
-	type Output = InverseSolidAngle<T>;
-	fn div(self, rhs: &Angle<T>) -> Self::Output {
-		InverseSolidAngle{per_sr: self.per_rad / rhs.rad.clone()}
+/// Multiplying a InverseArea by a Volume returns a value of type Distance
+impl<T> core::ops::Mul<&Volume<T>> for InverseArea<T> where T: NumLike {
+	type Output = Distance<T>;
+	fn mul(self, rhs: &Volume<T>) -> Self::Output {
+		Distance{m: self.per_m2 * rhs.m3.clone()}
 	}
 }
-/// Dividing a InverseAngle by a Angle returns a value of type InverseSolidAngle
-impl<T> core::ops::Div<&Angle<T>> for &InverseAngle<T> where T: NumLike {
-	type Output = InverseSolidAngle<T>;
-	fn div(self, rhs: &Angle<T>) -> Self::Output {
-		InverseSolidAngle{per_sr: self.per_rad.clone() / rhs.rad.clone()}
+/// Multiplying a InverseArea by a Volume returns a value of type Distance
+impl<T> core::ops::Mul<&Volume<T>> for &InverseArea<T> where T: NumLike {
+	type Output = Distance<T>;
+	fn mul(self, rhs: &Volume<T>) -> Self::Output {
+		Distance{m: self.per_m2.clone() * rhs.m3.clone()}
 	}
 }
 
-// InverseAngle * InverseAngle -> InverseSolidAngle
-/// Multiplying a InverseAngle by a InverseAngle returns a value of type InverseSolidAngle
-impl<T> core::ops::Mul<InverseAngle<T>> for InverseAngle<T> where T: NumLike {
-	type Output = InverseSolidAngle<T>;
-	fn mul(self, rhs: InverseAngle<T>) -> Self::Output {
-		InverseSolidAngle{per_sr: self.per_rad * rhs.per_rad}
+// InverseArea / AreaDensity -> InverseMass
+/// Dividing a InverseArea by a AreaDensity returns a value of type InverseMass
+impl<T> core::ops::Div<AreaDensity<T>> for InverseArea<T> where T: NumLike {
+	type Output = InverseMass<T>;
+	fn div(self, rhs: AreaDensity<T>) -> Self::Output {
+		InverseMass{per_kg: self.per_m2 / rhs.kgpm2}
 	}
 }
-/// Multiplying a InverseAngle by a InverseAngle returns a value of type InverseSolidAngle
-impl<T> core::ops::Mul<InverseAngle<T>> for &InverseAngle<T> where T: NumLike {
-	type Output = InverseSolidAngle<T>;
-	fn mul(self, rhs: InverseAngle<T>) -> Self::Output {
-		InverseSolidAngle{per_sr: self.per_rad.clone() * rhs.per_rad}
+/// Dividing a InverseArea by a AreaDensity returns a value of type InverseMass
+impl<T> core::ops::Div<AreaDensity<T>> for &InverseArea<T> where T: NumLike {
+	type Output = InverseMass<T>;
+	fn div(self, rhs: AreaDensity<T>) -> Self::Output {
+		InverseMass{per_kg: self.per_m2.clone() / rhs.kgpm2}
 	}
 }
-/// Multiplying a InverseAngle by a InverseAngle returns a value of type InverseSolidAngle
-impl<T> core::ops::Mul<&InverseAngle<T>> for InverseAngle<T> where T: NumLike {
-	type Output = InverseSolidAngle<T>;
-	fn mul(self, rhs: &InverseAngle<T>) -> Self::Output {
-		InverseSolidAngle{per_sr: self.per_rad * rhs.per_rad.clone()}
+/// Dividing a InverseArea by a AreaDensity returns a value of type InverseMass
+impl<T> core::ops::Div<&AreaDensity<T>> for InverseArea<T> where T: NumLike {
+	type Output = InverseMass<T>;
+	fn div(self, rhs: &AreaDensity<T>) -> Self::Output {
+		InverseMass{per_kg: self.per_m2 / rhs.kgpm2.clone()}
 	}
 }
-/// Multiplying a InverseAngle by a InverseAngle returns a value of type InverseSolidAngle
-impl<T> core::ops::Mul<&InverseAngle<T>> for &InverseAngle<T> where T: NumLike {
-	type Output = InverseSolidAngle<T>;
-	fn mul(self, rhs: &InverseAngle<T>) -> Self::Output {
-		InverseSolidAngle{per_sr: self.per_rad.clone() * rhs.per_rad.clone()}
+/// Dividing a InverseArea by a AreaDensity returns a value of type InverseMass
+impl<T> core::ops::Div<&AreaDensity<T>> for &InverseArea<T> where T: NumLike {
+	type Output = InverseMass<T>;
+	fn div(self, rhs: &AreaDensity<T>) -> Self::Output {
+		InverseMass{per_kg: self.per_m2.clone() / rhs.kgpm2.clone()}
 	}
 }
 
-// InverseAngle / InverseSolidAngle -> Angle
-/// Dividing a InverseAngle by a InverseSolidAngle returns a value of type Angle
-impl<T> core::ops::Div<InverseSolidAngle<T>> for InverseAngle<T> where T: NumLike {
-	type Output = Angle<T>;
-	fn div(self, rhs: InverseSolidAngle<T>) -> Self::Output {
-		Angle{rad: self.per_rad / rhs.per_sr}
+// InverseArea * AreaPerMass -> InverseMass
+/// Multiplying a InverseArea by a AreaPerMass returns a value of type InverseMass
+impl<T> core::ops::Mul<AreaPerMass<T>> for InverseArea<T> where T: NumLike {
+	type Output = InverseMass<T>;
+	fn mul(self, rhs: AreaPerMass<T>) -> Self::Output {
+		InverseMass{per_kg: self.per_m2 * rhs.m2_per_kg}
 	}
 }
-/// Dividing a InverseAngle by a InverseSolidAngle returns a value of type Angle
-impl<T> core::ops::Div<InverseSolidAngle<T>> for &InverseAngle<T> where T: NumLike {
-	type Output = Angle<T>;
-	fn div(self, rhs: InverseSolidAngle<T>) -> Self::Output {
-		Angle{rad: self.per_rad.clone() / rhs.per_sr}
+/// Multiplying a InverseArea by a AreaPerMass returns a value of type InverseMass
+impl<T> core::ops::Mul<AreaPerMass<T>> for &InverseArea<T> where T: NumLike {
+	type Output = InverseMass<T>;
+	fn mul(self, rhs: AreaPerMass<T>) -> Self::Output {
+		InverseMass{per_kg: self.per_m2.clone() * rhs.m2_per_kg}
 	}
 }
-/// Dividing a InverseAngle by a InverseSolidAngle returns a value of type Angle
-impl<T> core::ops::Div<&InverseSolidAngle<T>> for InverseAngle<T> where T: NumLike {
-	type Output = Angle<T>;
-	fn div(self, rhs: &InverseSolidAngle<T>) -> Self::Output {
-		Angle{rad: self.per_rad / rhs.per_sr.clone()}
+/// Multiplying a InverseArea by a AreaPerMass returns a value of type InverseMass
+impl<T> core::ops::Mul<&AreaPerMass<T>> for InverseArea<T> where T: NumLike {
+	type Output = InverseMass<T>;
+	fn mul(self, rhs: &AreaPerMass<T>) -> Self::Output {
+		InverseMass{per_kg: self.per_m2 * rhs.m2_per_kg.clone()}
 	}
 }
-/// Dividing a InverseAngle by a InverseSolidAngle returns a value of type Angle
-impl<T> core::ops::Div<&InverseSolidAngle<T>> for &InverseAngle<T> where T: NumLike {
-	type Output = Angle<T>;
-	fn div(self, rhs: &InverseSolidAngle<T>) -> Self::Output {
-		Angle{rad: self.per_rad.clone() / rhs.per_sr.clone()}
+/// Multiplying a InverseArea by a AreaPerMass returns a value of type InverseMass
+impl<T> core::ops::Mul<&AreaPerMass<T>> for &InverseArea<T> where T: NumLike {
+	type Output = InverseMass<T>;
+	fn mul(self, rhs: &AreaPerMass<T>) -> Self::Output {
+		InverseMass{per_kg: self.per_m2.clone() * rhs.m2_per_kg.clone()}
 	}
 }
 
-// InverseAngle * SolidAngle -> Angle
-/// Multiplying a InverseAngle by a SolidAngle returns a value of type Angle
-impl<T> core::ops::Mul<SolidAngle<T>> for InverseAngle<T> where T: NumLike {
-	type Output = Angle<T>;
-	fn mul(self, rhs: SolidAngle<T>) -> Self::Output {
-		Angle{rad: self.per_rad * rhs.sr}
+// InverseArea / DynamicViscosity -> InverseMomentum
+/// Dividing a InverseArea by a DynamicViscosity returns a value of type InverseMomentum
+impl<T> core::ops::Div<DynamicViscosity<T>> for InverseArea<T> where T: NumLike {
+	type Output = InverseMomentum<T>;
+	fn div(self, rhs: DynamicViscosity<T>) -> Self::Output {
+		InverseMomentum{s_per_kgm: self.per_m2 / rhs.Pas}
 	}
 }
-/// Multiplying a InverseAngle by a SolidAngle returns a value of type Angle
-impl<T> core::ops::Mul<SolidAngle<T>> for &InverseAngle<T> where T: NumLike {
-	type Output = Angle<T>;
-	fn mul(self, rhs: SolidAngle<T>) -> Self::Output {
-		Angle{rad: self.per_rad.clone() * rhs.sr}
+/// Dividing a InverseArea by a DynamicViscosity returns a value of type InverseMomentum
+impl<T> core::ops::Div<DynamicViscosity<T>> for &InverseArea<T> where T: NumLike {
+	type Output = InverseMomentum<T>;
+	fn div(self, rhs: DynamicViscosity<T>) -> Self::Output {
+		InverseMomentum{s_per_kgm: self.per_m2.clone() / rhs.Pas}
 	}
 }
-/// Multiplying a InverseAngle by a SolidAngle returns a value of type Angle
-impl<T> core::ops::Mul<&SolidAngle<T>> for InverseAngle<T> where T: NumLike {
-	type Output = Angle<T>;
-	fn mul(self, rhs: &SolidAngle<T>) -> Self::Output {
-		Angle{rad: self.per_rad * rhs.sr.clone()}
+/// Dividing a InverseArea by a DynamicViscosity returns a value of type InverseMomentum
+impl<T> core::ops::Div<&DynamicViscosity<T>> for InverseArea<T> where T: NumLike {
+	type Output = InverseMomentum<T>;
+	fn div(self, rhs: &DynamicViscosity<T>) -> Self::Output {
+		InverseMomentum{s_per_kgm: self.per_m2 / rhs.Pas.clone()}
 	}
 }
-/// Multiplying a InverseAngle by a SolidAngle returns a value of type Angle
-impl<T> core::ops::Mul<&SolidAngle<T>> for &InverseAngle<T> where T: NumLike {
-	type Output = Angle<T>;
-	fn mul(self, rhs: &SolidAngle<T>) -> Self::Output {
-		Angle{rad: self.per_rad.clone() * rhs.sr.clone()}
+/// Dividing a InverseArea by a DynamicViscosity returns a value of type InverseMomentum
+impl<T> core::ops::Div<&DynamicViscosity<T>> for &InverseArea<T> where T: NumLike {
+	type Output = InverseMomentum<T>;
+	fn div(self, rhs: &DynamicViscosity<T>) -> Self::Output {
+		InverseMomentum{s_per_kgm: self.per_m2.clone() / rhs.Pas.clone()}
 	}
 }
 
-// InverseAngle * AngularVelocity -> Frequency
-/// Multiplying a InverseAngle by a AngularVelocity returns a value of type Frequency
-impl<T> core::ops::Mul<AngularVelocity<T>> for InverseAngle<T> where T: NumLike {
-	type Output = Frequency<T>;
-	fn mul(self, rhs: AngularVelocity<T>) -> Self::Output {
-		Frequency{Hz: self.per_rad * rhs.radps}
+// InverseArea * Force -> Pressure
+/// Multiplying a InverseArea by a Force returns a value of type Pressure
+impl<T> core::ops::Mul<Force<T>> for InverseArea<T> where T: NumLike {
+	type Output = Pressure<T>;
+	fn mul(self, rhs: Force<T>) -> Self::Output {
+		Pressure{Pa: self.per_m2 * rhs.N}
 	}
 }
-/// Multiplying a InverseAngle by a AngularVelocity returns a value of type Frequency
-impl<T> core::ops::Mul<AngularVelocity<T>> for &InverseAngle<T> where T: NumLike {
-	type Output = Frequency<T>;
-	fn mul(self, rhs: AngularVelocity<T>) -> Self::Output {
-		Frequency{Hz: self.per_rad.clone() * rhs.radps}
+/// Multiplying a InverseArea by a Force returns a value of type Pressure
+impl<T> core::ops::Mul<Force<T>> for &InverseArea<T> where T: NumLike {
+	type Output = Pressure<T>;
+	fn mul(self, rhs: Force<T>) -> Self::Output {
+		Pressure{Pa: self.per_m2.clone() * rhs.N}
 	}
 }
-/// Multiplying a InverseAngle by a AngularVelocity returns a value of type Frequency
-impl<T> core::ops::Mul<&AngularVelocity<T>> for InverseAngle<T> where T: NumLike {
-	type Output = Frequency<T>;
-	fn mul(self, rhs: &AngularVelocity<T>) -> Self::Output {
-		Frequency{Hz: self.per_rad * rhs.radps.clone()}
+/// Multiplying a InverseArea by a Force returns a value of type Pressure
+impl<T> core::ops::Mul<&Force<T>> for InverseArea<T> where T: NumLike {
+	type Output = Pressure<T>;
+	fn mul(self, rhs: &Force<T>) -> Self::Output {
+		Pressure{Pa: self.per_m2 * rhs.N.clone()}
 	}
 }
-/// Multiplying a InverseAngle by a AngularVelocity returns a value of type Frequency
-impl<T> core::ops::Mul<&AngularVelocity<T>> for &InverseAngle<T> where T: NumLike {
-	type Output = Frequency<T>;
-	fn mul(self, rhs: &AngularVelocity<T>) -> Self::Output {
-		Frequency{Hz: self.per_rad.clone() * rhs.radps.clone()}
+/// Multiplying a InverseArea by a Force returns a value of type Pressure
+impl<T> core::ops::Mul<&Force<T>> for &InverseArea<T> where T: NumLike {
+	type Output = Pressure<T>;
+	fn mul(self, rhs: &Force<T>) -> Self::Output {
+		Pressure{Pa: self.per_m2.clone() * rhs.N.clone()}
 	}
 }
 
-// InverseAngle / Frequency -> InverseAngularVelocity
-/// Dividing a InverseAngle by a Frequency returns a value of type InverseAngularVelocity
-impl<T> core::ops::Div<Frequency<T>> for InverseAngle<T> where T: NumLike {
-	type Output = InverseAngularVelocity<T>;
+// InverseArea / Frequency -> InverseKinematicViscosity
+/// Dividing a InverseArea by a Frequency returns a value of type InverseKinematicViscosity
+impl<T> core::ops::Div<Frequency<T>> for InverseArea<T> where T: NumLike {
+	type Output = InverseKinematicViscosity<T>;
 	fn div(self, rhs: Frequency<T>) -> Self::Output {
-		InverseAngularVelocity{s_per_rad: self.per_rad / rhs.Hz}
+		InverseKinematicViscosity{s_per_m2: self.per_m2 / rhs.Hz}
 	}
 }
-/// Dividing a InverseAngle by a Frequency returns a value of type InverseAngularVelocity
-impl<T> core::ops::Div<Frequency<T>> for &InverseAngle<T> where T: NumLike {
-	type Output = InverseAngularVelocity<T>;
+/// Dividing a InverseArea by a Frequency returns a value of type InverseKinematicViscosity
+impl<T> core::ops::Div<Frequency<T>> for &InverseArea<T> where T: NumLike {
+	type Output = InverseKinematicViscosity<T>;
 	fn div(self, rhs: Frequency<T>) -> Self::Output {
-		InverseAngularVelocity{s_per_rad: self.per_rad.clone() / rhs.Hz}
+		InverseKinematicViscosity{s_per_m2: self.per_m2.clone() / rhs.Hz}
 	}
 }
-/// Dividing a InverseAngle by a Frequency returns a value of type InverseAngularVelocity
-impl<T> core::ops::Div<&Frequency<T>> for InverseAngle<T> where T: NumLike {
-	type Output = InverseAngularVelocity<T>;
+/// Dividing a InverseArea by a Frequency returns a value of type InverseKinematicViscosity
+impl<T> core::ops::Div<&Frequency<T>> for InverseArea<T> where T: NumLike {
+	type Output = InverseKinematicViscosity<T>;
 	fn div(self, rhs: &Frequency<T>) -> Self::Output {
-		InverseAngularVelocity{s_per_rad: self.per_rad / rhs.Hz.clone()}
+		InverseKinematicViscosity{s_per_m2: self.per_m2 / rhs.Hz.clone()}
 	}
 }
-/// Dividing a InverseAngle by a Frequency returns a value of type InverseAngularVelocity
-impl<T> core::ops::Div<&Frequency<T>> for &InverseAngle<T> where T: NumLike {
-	type Output = InverseAngularVelocity<T>;
+/// Dividing a InverseArea by a Frequency returns a value of type InverseKinematicViscosity
+impl<T> core::ops::Div<&Frequency<T>> for &InverseArea<T> where T: NumLike {
+	type Output = InverseKinematicViscosity<T>;
 	fn div(self, rhs: &Frequency<T>) -> Self::Output {
-		InverseAngularVelocity{s_per_rad: self.per_rad.clone() / rhs.Hz.clone()}
+		InverseKinematicViscosity{s_per_m2: self.per_m2.clone() / rhs.Hz.clone()}
 	}
 }
 
-// InverseAngle / InverseAngularVelocity -> Frequency
-/// Dividing a InverseAngle by a InverseAngularVelocity returns a value of type Frequency
-impl<T> core::ops::Div<InverseAngularVelocity<T>> for InverseAngle<T> where T: NumLike {
-	type Output = Frequency<T>;
-	fn div(self, rhs: InverseAngularVelocity<T>) -> Self::Output {
-		Frequency{Hz: self.per_rad / rhs.s_per_rad}
+// InverseArea * InverseDynamicViscosity -> InverseMomentum
+/// Multiplying a InverseArea by a InverseDynamicViscosity returns a value of type InverseMomentum
+impl<T> core::ops::Mul<InverseDynamicViscosity<T>> for InverseArea<T> where T: NumLike {
+	type Output = InverseMomentum<T>;
+	fn mul(self, rhs: InverseDynamicViscosity<T>) -> Self::Output {
+		InverseMomentum{s_per_kgm: self.per_m2 * rhs.per_Pas}
 	}
 }
-/// Dividing a InverseAngle by a InverseAngularVelocity returns a value of type Frequency
-impl<T> core::ops::Div<InverseAngularVelocity<T>> for &InverseAngle<T> where T: NumLike {
-	type Output = Frequency<T>;
-	fn div(self, rhs: InverseAngularVelocity<T>) -> Self::Output {
-		Frequency{Hz: self.per_rad.clone() / rhs.s_per_rad}
+/// Multiplying a InverseArea by a InverseDynamicViscosity returns a value of type InverseMomentum
+impl<T> core::ops::Mul<InverseDynamicViscosity<T>> for &InverseArea<T> where T: NumLike {
+	type Output = InverseMomentum<T>;
+	fn mul(self, rhs: InverseDynamicViscosity<T>) -> Self::Output {
+		InverseMomentum{s_per_kgm: self.per_m2.clone() * rhs.per_Pas}
 	}
 }
-/// Dividing a InverseAngle by a InverseAngularVelocity returns a value of type Frequency
-impl<T> core::ops::Div<&InverseAngularVelocity<T>> for InverseAngle<T> where T: NumLike {
-	type Output = Frequency<T>;
-	fn div(self, rhs: &InverseAngularVelocity<T>) -> Self::Output {
-		Frequency{Hz: self.per_rad / rhs.s_per_rad.clone()}
+/// Multiplying a InverseArea by a InverseDynamicViscosity returns a value of type InverseMomentum
+impl<T> core::ops::Mul<&InverseDynamicViscosity<T>> for InverseArea<T> where T: NumLike {
+	type Output = InverseMomentum<T>;
+	fn mul(self, rhs: &InverseDynamicViscosity<T>) -> Self::Output {
+		InverseMomentum{s_per_kgm: self.per_m2 * rhs.per_Pas.clone()}
 	}
 }
-/// Dividing a InverseAngle by a InverseAngularVelocity returns a value of type Frequency
-impl<T> core::ops::Div<&InverseAngularVelocity<T>> for &InverseAngle<T> where T: NumLike {
-	type Output = Frequency<T>;
-	fn div(self, rhs: &InverseAngularVelocity<T>) -> Self::Output {
-		Frequency{Hz: self.per_rad.clone() / rhs.s_per_rad.clone()}
+/// Multiplying a InverseArea by a InverseDynamicViscosity returns a value of type InverseMomentum
+impl<T> core::ops::Mul<&InverseDynamicViscosity<T>> for &InverseArea<T> where T: NumLike {
+	type Output = InverseMomentum<T>;
+	fn mul(self, rhs: &InverseDynamicViscosity<T>) -> Self::Output {
+		InverseMomentum{s_per_kgm: self.per_m2.clone() * rhs.per_Pas.clone()}
+	}
+}
+
+// InverseArea / InverseForce -> Pressure
+/// Dividing a InverseArea by a InverseForce returns a value of type Pressure
+impl<T> core::ops::Div<InverseForce<T>> for InverseArea<T> where T: NumLike {
+	type Output = Pressure<T>;
+	fn div(self, rhs: InverseForce<T>) -> Self::Output {
+		Pressure{Pa: self.per_m2 / rhs.per_N}
+	}
+}
+/// Dividing a InverseArea by a InverseForce returns a value of type Pressure
+impl<T> core::ops::Div<InverseForce<T>> for &InverseArea<T> where T: NumLike {
+	type Output = Pressure<T>;
+	fn div(self, rhs: InverseForce<T>) -> Self::Output {
+		Pressure{Pa: self.per_m2.clone() / rhs.per_N}
+	}
+}
+/// Dividing a InverseArea by a InverseForce returns a value of type Pressure
+impl<T> core::ops::Div<&InverseForce<T>> for InverseArea<T> where T: NumLike {
+	type Output = Pressure<T>;
+	fn div(self, rhs: &InverseForce<T>) -> Self::Output {
+		Pressure{Pa: self.per_m2 / rhs.per_N.clone()}
+	}
+}
+/// Dividing a InverseArea by a InverseForce returns a value of type Pressure
+impl<T> core::ops::Div<&InverseForce<T>> for &InverseArea<T> where T: NumLike {
+	type Output = Pressure<T>;
+	fn div(self, rhs: &InverseForce<T>) -> Self::Output {
+		Pressure{Pa: self.per_m2.clone() / rhs.per_N.clone()}
 	}
 }
 
-// 1/InverseAngle -> Angle
-/// Dividing a scalar value by a InverseAngle unit value returns a value of type Angle
-impl<T> core::ops::Div<InverseAngle<T>> for f64 where T: NumLike+From<f64> {
-	type Output = Angle<T>;
-	fn div(self, rhs: InverseAngle<T>) -> Self::Output {
-		Angle{rad: T::from(self) / rhs.per_rad}
+// InverseArea / InverseKinematicViscosity -> Frequency
+/// Dividing a InverseArea by a InverseKinematicViscosity returns a value of type Frequency
+impl<T> core::ops::Div<InverseKinematicViscosity<T>> for InverseArea<T> where T: NumLike {
+	type Output = Frequency<T>;
+	fn div(self, rhs: InverseKinematicViscosity<T>) -> Self::Output {
+		Frequency{Hz: self.per_m2 / rhs.s_per_m2}
 	}
 }
-/// Dividing a scalar value by a InverseAngle unit value returns a value of type Angle
-impl<T> core::ops::Div<InverseAngle<T>> for &f64 where T: NumLike+From<f64> {
-	type Output = Angle<T>;
-	fn div(self, rhs: InverseAngle<T>) -> Self::Output {
-		Angle{rad: T::from(self.clone()) / rhs.per_rad}
+/// Dividing a InverseArea by a InverseKinematicViscosity returns a value of type Frequency
+impl<T> core::ops::Div<InverseKinematicViscosity<T>> for &InverseArea<T> where T: NumLike {
+	type Output = Frequency<T>;
+	fn div(self, rhs: InverseKinematicViscosity<T>) -> Self::Output {
+		Frequency{Hz: self.per_m2.clone() / rhs.s_per_m2}
 	}
 }
-/// Dividing a scalar value by a InverseAngle unit value returns a value of type Angle
-impl<T> core::ops::Div<&InverseAngle<T>> for f64 where T: NumLike+From<f64> {
-	type Output = Angle<T>;
-	fn div(self, rhs: &InverseAngle<T>) -> Self::Output {
-		Angle{rad: T::from(self) / rhs.per_rad.clone()}
+/// Dividing a InverseArea by a InverseKinematicViscosity returns a value of type Frequency
+impl<T> core::ops::Div<&InverseKinematicViscosity<T>> for InverseArea<T> where T: NumLike {
+	type Output = Frequency<T>;
+	fn div(self, rhs: &InverseKinematicViscosity<T>) -> Self::Output {
+		Frequency{Hz: self.per_m2 / rhs.s_per_m2.clone()}
 	}
 }
-/// Dividing a scalar value by a InverseAngle unit value returns a value of type Angle
-impl<T> core::ops::Div<&InverseAngle<T>> for &f64 where T: NumLike+From<f64> {
-	type Output = Angle<T>;
-	fn div(self, rhs: &InverseAngle<T>) -> Self::Output {
-		Angle{rad: T::from(self.clone()) / rhs.per_rad.clone()}
+/// Dividing a InverseArea by a InverseKinematicViscosity returns a value of type Frequency
+impl<T> core::ops::Div<&InverseKinematicViscosity<T>> for &InverseArea<T> where T: NumLike {
+	type Output = Frequency<T>;
+	fn div(self, rhs: &InverseKinematicViscosity<T>) -> Self::Output {
+		Frequency{Hz: self.per_m2.clone() / rhs.s_per_m2.clone()}
 	}
 }
 
-// 1/InverseAngle -> Angle
-/// Dividing a scalar value by a InverseAngle unit value returns a value of type Angle
-impl<T> core::ops::Div<InverseAngle<T>> for f32 where T: NumLike+From<f32> {
-	type Output = Angle<T>;
-	fn div(self, rhs: InverseAngle<T>) -> Self::Output {
-		Angle{rad: T::from(self) / rhs.per_rad}
+// InverseArea / InverseMomentOfInertia -> Mass
+/// Dividing a InverseArea by a InverseMomentOfInertia returns a value of type Mass
+impl<T> core::ops::Div<InverseMomentOfInertia<T>> for InverseArea<T> where T: NumLike {
+	type Output = Mass<T>;
+	fn div(self, rhs: InverseMomentOfInertia<T>) -> Self::Output {
+		Mass{kg: self.per_m2 / rhs.per_kgm2}
 	}
 }
-/// Dividing a scalar value by a InverseAngle unit value returns a value of type Angle
-impl<T> core::ops::Div<InverseAngle<T>> for &f32 where T: NumLike+From<f32> {
-	type Output = Angle<T>;
-	fn div(self, rhs: InverseAngle<T>) -> Self::Output {
-		Angle{rad: T::from(self.clone()) / rhs.per_rad}
+/// Dividing a InverseArea by a InverseMomentOfInertia returns a value of type Mass
+impl<T> core::ops::Div<InverseMomentOfInertia<T>> for &InverseArea<T> where T: NumLike {
+	type Output = Mass<T>;
+	fn div(self, rhs: InverseMomentOfInertia<T>) -> Self::Output {
+		Mass{kg: self.per_m2.clone() / rhs.per_kgm2}
 	}
 }
-/// Dividing a scalar value by a InverseAngle unit value returns a value of type Angle
-impl<T> core::ops::Div<&InverseAngle<T>> for f32 where T: NumLike+From<f32> {
-	type Output = Angle<T>;
-	fn div(self, rhs: &InverseAngle<T>) -> Self::Output {
-		Angle{rad: T::from(self) / rhs.per_rad.clone()}
+/// Dividing a InverseArea by a InverseMomentOfInertia returns a value of type Mass
+impl<T> core::ops::Div<&InverseMomentOfInertia<T>> for InverseArea<T> where T: NumLike {
+	type Output = Mass<T>;
+	fn div(self, rhs: &InverseMomentOfInertia<T>) -> Self::Output {
+		Mass{kg: self.per_m2 / rhs.per_kgm2.clone()}
 	}
 }
-/// Dividing a scalar value by a InverseAngle unit value returns a value of type Angle
-impl<T> core::ops::Div<&InverseAngle<T>> for &f32 where T: NumLike+From<f32> {
-	type Output = Angle<T>;
-	fn div(self, rhs: &InverseAngle<T>) -> Self::Output {
-		Angle{rad: T::from(self.clone()) / rhs.per_rad.clone()}
+/// Dividing a InverseArea by a InverseMomentOfInertia returns a value of type Mass
+impl<T> core::ops::Div<&InverseMomentOfInertia<T>> for &InverseArea<T> where T: NumLike {
+	type Output = Mass<T>;
+	fn div(self, rhs: &InverseMomentOfInertia<T>) -> Self::Output {
+		Mass{kg: self.per_m2.clone() / rhs.per_kgm2.clone()}
 	}
 }
 
-// 1/InverseAngle -> Angle
-/// Dividing a scalar value by a InverseAngle unit value returns a value of type Angle
-impl<T> core::ops::Div<InverseAngle<T>> for i64 where T: NumLike+From<i64> {
-	type Output = Angle<T>;
-	fn div(self, rhs: InverseAngle<T>) -> Self::Output {
-		Angle{rad: T::from(self) / rhs.per_rad}
+// InverseArea / InverseMomentum -> DynamicViscosity
+/// Dividing a InverseArea by a InverseMomentum returns a value of type DynamicViscosity
+impl<T> core::ops::Div<InverseMomentum<T>> for InverseArea<T> where T: NumLike {
+	type Output = DynamicViscosity<T>;
+	fn div(self, rhs: InverseMomentum<T>) -> Self::Output {
+		DynamicViscosity{Pas: self.per_m2 / rhs.s_per_kgm}
 	}
 }
-/// Dividing a scalar value by a InverseAngle unit value returns a value of type Angle
-impl<T> core::ops::Div<InverseAngle<T>> for &i64 where T: NumLike+From<i64> {
-	type Output = Angle<T>;
-	fn div(self, rhs: InverseAngle<T>) -> Self::Output {
-		Angle{rad: T::from(self.clone()) / rhs.per_rad}
+/// Dividing a InverseArea by a InverseMomentum returns a value of type DynamicViscosity
+impl<T> core::ops::Div<InverseMomentum<T>> for &InverseArea<T> where T: NumLike {
+	type Output = DynamicViscosity<T>;
+	fn div(self, rhs: InverseMomentum<T>) -> Self::Output {
+		DynamicViscosity{Pas: self.per_m2.clone() / rhs.s_per_kgm}
 	}
 }
-/// Dividing a scalar value by a InverseAngle unit value returns a value of type Angle
-impl<T> core::ops::Div<&InverseAngle<T>> for i64 where T: NumLike+From<i64> {
-	type Output = Angle<T>;
-	fn div(self, rhs: &InverseAngle<T>) -> Self::Output {
-		Angle{rad: T::from(self) / rhs.per_rad.clone()}
+/// Dividing a InverseArea by a InverseMomentum returns a value of type DynamicViscosity
+impl<T> core::ops::Div<&InverseMomentum<T>> for InverseArea<T> where T: NumLike {
+	type Output = DynamicViscosity<T>;
+	fn div(self, rhs: &InverseMomentum<T>) -> Self::Output {
+		DynamicViscosity{Pas: self.per_m2 / rhs.s_per_kgm.clone()}
 	}
 }
-/// Dividing a scalar value by a InverseAngle unit value returns a value of type Angle
-impl<T> core::ops::Div<&InverseAngle<T>> for &i64 where T: NumLike+From<i64> {
-	type Output = Angle<T>;
-	fn div(self, rhs: &InverseAngle<T>) -> Self::Output {
-		Angle{rad: T::from(self.clone()) / rhs.per_rad.clone()}
+/// Dividing a InverseArea by a InverseMomentum returns a value of type DynamicViscosity
+impl<T> core::ops::Div<&InverseMomentum<T>> for &InverseArea<T> where T: NumLike {
+	type Output = DynamicViscosity<T>;
+	fn div(self, rhs: &InverseMomentum<T>) -> Self::Output {
+		DynamicViscosity{Pas: self.per_m2.clone() / rhs.s_per_kgm.clone()}
 	}
 }
 
-// 1/InverseAngle -> Angle
-/// Dividing a scalar value by a InverseAngle unit value returns a value of type Angle
-impl<T> core::ops::Div<InverseAngle<T>> for i32 where T: NumLike+From<i32> {
-	type Output = Angle<T>;
-	fn div(self, rhs: InverseAngle<T>) -> Self::Output {
-		Angle{rad: T::from(self) / rhs.per_rad}
+// InverseArea / InversePower -> Irradiance
+/// Dividing a InverseArea by a InversePower returns a value of type Irradiance
+impl<T> core::ops::Div<InversePower<T>> for InverseArea<T> where T: NumLike {
+	type Output = Irradiance<T>;
+	fn div(self, rhs: InversePower<T>) -> Self::Output {
+		Irradiance{W_per_m2: self.per_m2 / rhs.per_W}
 	}
 }
-/// Dividing a scalar value by a InverseAngle unit value returns a value of type Angle
-impl<T> core::ops::Div<InverseAngle<T>> for &i32 where T: NumLike+From<i32> {
-	type Output = Angle<T>;
-	fn div(self, rhs: InverseAngle<T>) -> Self::Output {
-		Angle{rad: T::from(self.clone()) / rhs.per_rad}
+/// Dividing a InverseArea by a InversePower returns a value of type Irradiance
+impl<T> core::ops::Div<InversePower<T>> for &InverseArea<T> where T: NumLike {
+	type Output = Irradiance<T>;
+	fn div(self, rhs: InversePower<T>) -> Self::Output {
+		Irradiance{W_per_m2: self.per_m2.clone() / rhs.per_W}
 	}
 }
-/// Dividing a scalar value by a InverseAngle unit value returns a value of type Angle
-impl<T> core::ops::Div<&InverseAngle<T>> for i32 where T: NumLike+From<i32> {
-	type Output = Angle<T>;
-	fn div(self, rhs: &InverseAngle<T>) -> Self::Output {
-		Angle{rad: T::from(self) / rhs.per_rad.clone()}
+/// Dividing a InverseArea by a InversePower returns a value of type Irradiance
+impl<T> core::ops::Div<&InversePower<T>> for InverseArea<T> where T: NumLike {
+	type Output = Irradiance<T>;
+	fn div(self, rhs: &InversePower<T>) -> Self::Output {
+		Irradiance{W_per_m2: self.per_m2 / rhs.per_W.clone()}
 	}
 }
-/// Dividing a scalar value by a InverseAngle unit value returns a value of type Angle
-impl<T> core::ops::Div<&InverseAngle<T>> for &i32 where T: NumLike+From<i32> {
-	type Output = Angle<T>;
-	fn div(self, rhs: &InverseAngle<T>) -> Self::Output {
-		Angle{rad: T::from(self.clone()) / rhs.per_rad.clone()}
+/// Dividing a InverseArea by a InversePower returns a value of type Irradiance
+impl<T> core::ops::Div<&InversePower<T>> for &InverseArea<T> where T: NumLike {
+	type Output = Irradiance<T>;
+	fn div(self, rhs: &InversePower<T>) -> Self::Output {
+		Irradiance{W_per_m2: self.per_m2.clone() / rhs.per_W.clone()}
 	}
 }
 
-// 1/InverseAngle -> Angle
-/// Dividing a scalar value by a InverseAngle unit value returns a value of type Angle
-#[cfg(feature="num-bigfloat")]
-impl<T> core::ops::Div<InverseAngle<T>> for num_bigfloat::BigFloat where T: NumLike+From<num_bigfloat::BigFloat> {
-	type Output = Angle<T>;
-	fn div(self, rhs: InverseAngle<T>) -> Self::Output {
-		Angle{rad: T::from(self) / rhs.per_rad}
+// InverseArea * InversePressure -> InverseForce
+/// Multiplying a InverseArea by a InversePressure returns a value of type InverseForce
+impl<T> core::ops::Mul<InversePressure<T>> for InverseArea<T> where T: NumLike {
+	type Output = InverseForce<T>;
+	fn mul(self, rhs: InversePressure<T>) -> Self::Output {
+		InverseForce{per_N: self.per_m2 * rhs.per_Pa}
 	}
 }
-/// Dividing a scalar value by a InverseAngle unit value returns a value of type Angle
-#[cfg(feature="num-bigfloat")]
-impl<T> core::ops::Div<InverseAngle<T>> for &num_bigfloat::BigFloat where T: NumLike+From<num_bigfloat::BigFloat> {
-	type Output = Angle<T>;
-	fn div(self, rhs: InverseAngle<T>) -> Self::Output {
-		Angle{rad: T::from(self.clone()) / rhs.per_rad}
+/// Multiplying a InverseArea by a InversePressure returns a value of type InverseForce
+impl<T> core::ops::Mul<InversePressure<T>> for &InverseArea<T> where T: NumLike {
+	type Output = InverseForce<T>;
+	fn mul(self, rhs: InversePressure<T>) -> Self::Output {
+		InverseForce{per_N: self.per_m2.clone() * rhs.per_Pa}
 	}
 }
-/// Dividing a scalar value by a InverseAngle unit value returns a value of type Angle
-#[cfg(feature="num-bigfloat")]
-impl<T> core::ops::Div<&InverseAngle<T>> for num_bigfloat::BigFloat where T: NumLike+From<num_bigfloat::BigFloat> {
-	type Output = Angle<T>;
-	fn div(self, rhs: &InverseAngle<T>) -> Self::Output {
-		Angle{rad: T::from(self) / rhs.per_rad.clone()}
+/// Multiplying a InverseArea by a InversePressure returns a value of type InverseForce
+impl<T> core::ops::Mul<&InversePressure<T>> for InverseArea<T> where T: NumLike {
+	type Output = InverseForce<T>;
+	fn mul(self, rhs: &InversePressure<T>) -> Self::Output {
+		InverseForce{per_N: self.per_m2 * rhs.per_Pa.clone()}
 	}
 }
-/// Dividing a scalar value by a InverseAngle unit value returns a value of type Angle
-#[cfg(feature="num-bigfloat")]
-impl<T> core::ops::Div<&InverseAngle<T>> for &num_bigfloat::BigFloat where T: NumLike+From<num_bigfloat::BigFloat> {
-	type Output = Angle<T>;
-	fn div(self, rhs: &InverseAngle<T>) -> Self::Output {
-		Angle{rad: T::from(self.clone()) / rhs.per_rad.clone()}
+/// Multiplying a InverseArea by a InversePressure returns a value of type InverseForce
+impl<T> core::ops::Mul<&InversePressure<T>> for &InverseArea<T> where T: NumLike {
+	type Output = InverseForce<T>;
+	fn mul(self, rhs: &InversePressure<T>) -> Self::Output {
+		InverseForce{per_N: self.per_m2.clone() * rhs.per_Pa.clone()}
 	}
 }
 
-// 1/InverseAngle -> Angle
-/// Dividing a scalar value by a InverseAngle unit value returns a value of type Angle
-#[cfg(feature="num-complex")]
-impl<T> core::ops::Div<InverseAngle<T>> for num_complex::Complex32 where T: NumLike+From<num_complex::Complex32> {
-	type Output = Angle<T>;
-	fn div(self, rhs: InverseAngle<T>) -> Self::Output {
-		Angle{rad: T::from(self) / rhs.per_rad}
+// InverseArea / InverseVolumetricFlowRate -> Velocity
+/// Dividing a InverseArea by a InverseVolumetricFlowRate returns a value of type Velocity
+impl<T> core::ops::Div<InverseVolumetricFlowRate<T>> for InverseArea<T> where T: NumLike {
+	type Output = Velocity<T>;
+	fn div(self, rhs: InverseVolumetricFlowRate<T>) -> Self::Output {
+		Velocity{mps: self.per_m2 / rhs.s_per_m3}
 	}
 }
-/// Dividing a scalar value by a InverseAngle unit value returns a value of type Angle
-#[cfg(feature="num-complex")]
-impl<T> core::ops::Div<InverseAngle<T>> for &num_complex::Complex32 where T: NumLike+From<num_complex::Complex32> {
-	type Output = Angle<T>;
-	fn div(self, rhs: InverseAngle<T>) -> Self::Output {
-		Angle{rad: T::from(self.clone()) / rhs.per_rad}
+/// Dividing a InverseArea by a InverseVolumetricFlowRate returns a value of type Velocity
+impl<T> core::ops::Div<InverseVolumetricFlowRate<T>> for &InverseArea<T> where T: NumLike {
+	type Output = Velocity<T>;
+	fn div(self, rhs: InverseVolumetricFlowRate<T>) -> Self::Output {
+		Velocity{mps: self.per_m2.clone() / rhs.s_per_m3}
 	}
 }
-/// Dividing a scalar value by a InverseAngle unit value returns a value of type Angle
-#[cfg(feature="num-complex")]
-impl<T> core::ops::Div<&InverseAngle<T>> for num_complex::Complex32 where T: NumLike+From<num_complex::Complex32> {
-	type Output = Angle<T>;
-	fn div(self, rhs: &InverseAngle<T>) -> Self::Output {
-		Angle{rad: T::from(self) / rhs.per_rad.clone()}
+/// Dividing a InverseArea by a InverseVolumetricFlowRate returns a value of type Velocity
+impl<T> core::ops::Div<&InverseVolumetricFlowRate<T>> for InverseArea<T> where T: NumLike {
+	type Output = Velocity<T>;
+	fn div(self, rhs: &InverseVolumetricFlowRate<T>) -> Self::Output {
+		Velocity{mps: self.per_m2 / rhs.s_per_m3.clone()}
 	}
 }
-/// Dividing a scalar value by a InverseAngle unit value returns a value of type Angle
-#[cfg(feature="num-complex")]
-impl<T> core::ops::Div<&InverseAngle<T>> for &num_complex::Complex32 where T: NumLike+From<num_complex::Complex32> {
-	type Output = Angle<T>;
-	fn div(self, rhs: &InverseAngle<T>) -> Self::Output {
-		Angle{rad: T::from(self.clone()) / rhs.per_rad.clone()}
+/// Dividing a InverseArea by a InverseVolumetricFlowRate returns a value of type Velocity
+impl<T> core::ops::Div<&InverseVolumetricFlowRate<T>> for &InverseArea<T> where T: NumLike {
+	type Output = Velocity<T>;
+	fn div(self, rhs: &InverseVolumetricFlowRate<T>) -> Self::Output {
+		Velocity{mps: self.per_m2.clone() / rhs.s_per_m3.clone()}
 	}
 }
 
-// 1/InverseAngle -> Angle
-/// Dividing a scalar value by a InverseAngle unit value returns a value of type Angle
-#[cfg(feature="num-complex")]
-impl<T> core::ops::Div<InverseAngle<T>> for num_complex::Complex64 where T: NumLike+From<num_complex::Complex64> {
-	type Output = Angle<T>;
-	fn div(self, rhs: InverseAngle<T>) -> Self::Output {
-		Angle{rad: T::from(self) / rhs.per_rad}
+// InverseArea * KinematicViscosity -> Frequency
+/// Multiplying a InverseArea by a KinematicViscosity returns a value of type Frequency
+impl<T> core::ops::Mul<KinematicViscosity<T>> for InverseArea<T> where T: NumLike {
+	type Output = Frequency<T>;
+	fn mul(self, rhs: KinematicViscosity<T>) -> Self::Output {
+		Frequency{Hz: self.per_m2 * rhs.m2ps}
 	}
 }
-/// Dividing a scalar value by a InverseAngle unit value returns a value of type Angle
-#[cfg(feature="num-complex")]
-impl<T> core::ops::Div<InverseAngle<T>> for &num_complex::Complex64 where T: NumLike+From<num_complex::Complex64> {
-	type Output = Angle<T>;
-	fn div(self, rhs: InverseAngle<T>) -> Self::Output {
-		Angle{rad: T::from(self.clone()) / rhs.per_rad}
+/// Multiplying a InverseArea by a KinematicViscosity returns a value of type Frequency
+impl<T> core::ops::Mul<KinematicViscosity<T>> for &InverseArea<T> where T: NumLike {
+	type Output = Frequency<T>;
+	fn mul(self, rhs: KinematicViscosity<T>) -> Self::Output {
+		Frequency{Hz: self.per_m2.clone() * rhs.m2ps}
 	}
 }
-/// Dividing a scalar value by a InverseAngle unit value returns a value of type Angle
-#[cfg(feature="num-complex")]
-impl<T> core::ops::Div<&InverseAngle<T>> for num_complex::Complex64 where T: NumLike+From<num_complex::Complex64> {
-	type Output = Angle<T>;
-	fn div(self, rhs: &InverseAngle<T>) -> Self::Output {
-		Angle{rad: T::from(self) / rhs.per_rad.clone()}
+/// Multiplying a InverseArea by a KinematicViscosity returns a value of type Frequency
+impl<T> core::ops::Mul<&KinematicViscosity<T>> for InverseArea<T> where T: NumLike {
+	type Output = Frequency<T>;
+	fn mul(self, rhs: &KinematicViscosity<T>) -> Self::Output {
+		Frequency{Hz: self.per_m2 * rhs.m2ps.clone()}
 	}
 }
-/// Dividing a scalar value by a InverseAngle unit value returns a value of type Angle
-#[cfg(feature="num-complex")]
-impl<T> core::ops::Div<&InverseAngle<T>> for &num_complex::Complex64 where T: NumLike+From<num_complex::Complex64> {
-	type Output = Angle<T>;
-	fn div(self, rhs: &InverseAngle<T>) -> Self::Output {
-		Angle{rad: T::from(self.clone()) / rhs.per_rad.clone()}
+/// Multiplying a InverseArea by a KinematicViscosity returns a value of type Frequency
+impl<T> core::ops::Mul<&KinematicViscosity<T>> for &InverseArea<T> where T: NumLike {
+	type Output = Frequency<T>;
+	fn mul(self, rhs: &KinematicViscosity<T>) -> Self::Output {
+		Frequency{Hz: self.per_m2.clone() * rhs.m2ps.clone()}
 	}
 }
 
-/// The inverse of area unit type, defined as inverse square meters in SI units
-#[derive(UnitStruct, Debug, Clone)]
-#[cfg_attr(feature="serde", derive(Serialize, Deserialize))]
-pub struct InverseArea<T: NumLike>{
-	/// The value of this Inverse area in inverse square meters
-	pub per_m2: T
+// InverseArea * MomentOfInertia -> Mass
+/// Multiplying a InverseArea by a MomentOfInertia returns a value of type Mass
+impl<T> core::ops::Mul<MomentOfInertia<T>> for InverseArea<T> where T: NumLike {
+	type Output = Mass<T>;
+	fn mul(self, rhs: MomentOfInertia<T>) -> Self::Output {
+		Mass{kg: self.per_m2 * rhs.kgm2}
+	}
 }
-
-impl<T> InverseArea<T> where T: NumLike {
-
-	/// Returns the standard unit name of inverse area: "inverse square meters"
-	pub fn unit_name() -> &'static str { "inverse square meters" }
-	
-	/// Returns the abbreviated name or symbol of inverse area: "1/m²" for inverse square meters
-	pub fn unit_symbol() -> &'static str { "1/m²" }
-	
-	/// Returns a new inverse area value from the given number of inverse square meters
-	///
-	/// # Arguments
-	/// * `per_m2` - Any number-like type, representing a quantity of inverse square meters
-	pub fn from_per_m2(per_m2: T) -> Self { InverseArea{per_m2: per_m2} }
-	
-	/// Returns a copy of this inverse area value in inverse square meters
-	pub fn to_per_m2(&self) -> T { self.per_m2.clone() }
-
-	/// Returns a new inverse area value from the given number of inverse square meters
-	///
-	/// # Arguments
-	/// * `per_square_meter` - Any number-like type, representing a quantity of inverse square meters
-	pub fn from_per_square_meter(per_square_meter: T) -> Self { InverseArea{per_m2: per_square_meter} }
-	
-	/// Returns a copy of this inverse area value in inverse square meters
-	pub fn to_per_square_meter(&self) -> T { self.per_m2.clone() }
-
+/// Multiplying a InverseArea by a MomentOfInertia returns a value of type Mass
+impl<T> core::ops::Mul<MomentOfInertia<T>> for &InverseArea<T> where T: NumLike {
+	type Output = Mass<T>;
+	fn mul(self, rhs: MomentOfInertia<T>) -> Self::Output {
+		Mass{kg: self.per_m2.clone() * rhs.kgm2}
+	}
 }
-
-impl<T> fmt::Display for InverseArea<T> where T: NumLike {
-	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-		write!(f, "{} {}", &self.per_m2, Self::unit_symbol())
+/// Multiplying a InverseArea by a MomentOfInertia returns a value of type Mass
+impl<T> core::ops::Mul<&MomentOfInertia<T>> for InverseArea<T> where T: NumLike {
+	type Output = Mass<T>;
+	fn mul(self, rhs: &MomentOfInertia<T>) -> Self::Output {
+		Mass{kg: self.per_m2 * rhs.kgm2.clone()}
 	}
 }
-
-impl<T> InverseArea<T> where T: NumLike+From<f64> {
-	
-	/// Returns a copy of this inverse area value in inverse square cm
-	/// 
-	/// *Note: This method is not available for `f32` and other number types lacking the `From<f64>` trait*
-	pub fn to_per_cm2(&self) -> T {
-		return self.per_m2.clone() * T::from(0.0001_f64);
+/// Multiplying a InverseArea by a MomentOfInertia returns a value of type Mass
+impl<T> core::ops::Mul<&MomentOfInertia<T>> for &InverseArea<T> where T: NumLike {
+	type Output = Mass<T>;
+	fn mul(self, rhs: &MomentOfInertia<T>) -> Self::Output {
+		Mass{kg: self.per_m2.clone() * rhs.kgm2.clone()}
 	}
+}
 
-	/// Returns a new inverse area value from the given number of inverse square cm
-	/// 
-	/// *Note: This method is not available for `f32` and other number types lacking the `From<f64>` trait*
-	///
-	/// # Arguments
-	/// * `per_cm2` - Any number-like type, representing a quantity of inverse square cm
-	pub fn from_per_cm2(per_cm2: T) -> Self {
-		InverseArea{per_m2: per_cm2 * T::from(10000.0_f64)}
+// InverseArea * Momentum -> DynamicViscosity
+/// Multiplying a InverseArea by a Momentum returns a value of type DynamicViscosity
+impl<T> core::ops::Mul<Momentum<T>> for InverseArea<T> where T: NumLike {
+	type Output = DynamicViscosity<T>;
+	fn mul(self, rhs: Momentum<T>) -> Self::Output {
+		DynamicViscosity{Pas: self.per_m2 * rhs.kgmps}
 	}
-
-	/// Returns a copy of this inverse area value in inverse square cm
-	/// 
-	/// *Note: This method is not available for `f32` and other number types lacking the `From<f64>` trait*
-	pub fn to_per_square_cm(&self) -> T {
-		return self.per_m2.clone() * T::from(0.0001_f64);
+}
+/// Multiplying a InverseArea by a Momentum returns a value of type DynamicViscosity
+impl<T> core::ops::Mul<Momentum<T>> for &InverseArea<T> where T: NumLike {
+	type Output = DynamicViscosity<T>;
+	fn mul(self, rhs: Momentum<T>) -> Self::Output {
+		DynamicViscosity{Pas: self.per_m2.clone() * rhs.kgmps}
 	}
-
-	/// Returns a new inverse area value from the given number of inverse square cm
-	/// 
-	/// *Note: This method is not available for `f32` and other number types lacking the `From<f64>` trait*
-	///
-	/// # Arguments
-	/// * `per_square_cm` - Any number-like type, representing a quantity of inverse square cm
-	pub fn from_per_square_cm(per_square_cm: T) -> Self {
-		InverseArea{per_m2: per_square_cm * T::from(10000.0_f64)}
+}
+/// Multiplying a InverseArea by a Momentum returns a value of type DynamicViscosity
+impl<T> core::ops::Mul<&Momentum<T>> for InverseArea<T> where T: NumLike {
+	type Output = DynamicViscosity<T>;
+	fn mul(self, rhs: &Momentum<T>) -> Self::Output {
+		DynamicViscosity{Pas: self.per_m2 * rhs.kgmps.clone()}
 	}
-
-	/// Returns a copy of this inverse area value in inverse square mm
-	/// 
-	/// *Note: This method is not available for `f32` and other number types lacking the `From<f64>` trait*
-	pub fn to_per_mm2(&self) -> T {
-		return self.per_m2.clone() * T::from(1e-06_f64);
+}
+/// Multiplying a InverseArea by a Momentum returns a value of type DynamicViscosity
+impl<T> core::ops::Mul<&Momentum<T>> for &InverseArea<T> where T: NumLike {
+	type Output = DynamicViscosity<T>;
+	fn mul(self, rhs: &Momentum<T>) -> Self::Output {
+		DynamicViscosity{Pas: self.per_m2.clone() * rhs.kgmps.clone()}
 	}
+}
 
-	/// Returns a new inverse area value from the given number of inverse square mm
-	/// 
-	/// *Note: This method is not available for `f32` and other number types lacking the `From<f64>` trait*
-	///
-	/// # Arguments
-	/// * `per_mm2` - Any number-like type, representing a quantity of inverse square mm
-	pub fn from_per_mm2(per_mm2: T) -> Self {
-		InverseArea{per_m2: per_mm2 * T::from(1000000.0_f64)}
+// InverseArea * Power -> Irradiance
+/// Multiplying a InverseArea by a Power returns a value of type Irradiance
+impl<T> core::ops::Mul<Power<T>> for InverseArea<T> where T: NumLike {
+	type Output = Irradiance<T>;
+	fn mul(self, rhs: Power<T>) -> Self::Output {
+		Irradiance{W_per_m2: self.per_m2 * rhs.W}
 	}
-
-	/// Returns a copy of this inverse area value in inverse square um
-	/// 
-	/// *Note: This method is not available for `f32` and other number types lacking the `From<f64>` trait*
-	pub fn to_per_um2(&self) -> T {
-		return self.per_m2.clone() * T::from(1e-12_f64);
+}
+/// Multiplying a InverseArea by a Power returns a value of type Irradiance
+impl<T> core::ops::Mul<Power<T>> for &InverseArea<T> where T: NumLike {
+	type Output = Irradiance<T>;
+	fn mul(self, rhs: Power<T>) -> Self::Output {
+		Irradiance{W_per_m2: self.per_m2.clone() * rhs.W}
 	}
-
-	/// Returns a new inverse area value from the given number of inverse square um
-	/// 
-	/// *Note: This method is not available for `f32` and other number types lacking the `From<f64>` trait*
-	///
-	/// # Arguments
-	/// * `per_um2` - Any number-like type, representing a quantity of inverse square um
-	pub fn from_per_um2(per_um2: T) -> Self {
-		InverseArea{per_m2: per_um2 * T::from(1000000000000.0_f64)}
+}
+/// Multiplying a InverseArea by a Power returns a value of type Irradiance
+impl<T> core::ops::Mul<&Power<T>> for InverseArea<T> where T: NumLike {
+	type Output = Irradiance<T>;
+	fn mul(self, rhs: &Power<T>) -> Self::Output {
+		Irradiance{W_per_m2: self.per_m2 * rhs.W.clone()}
 	}
-
-	/// Returns a copy of this inverse area value in inverse square nm
-	/// 
-	/// *Note: This method is not available for `f32` and other number types lacking the `From<f64>` trait*
-	pub fn to_per_nm2(&self) -> T {
-		return self.per_m2.clone() * T::from(1e-18_f64);
+}
+/// Multiplying a InverseArea by a Power returns a value of type Irradiance
+impl<T> core::ops::Mul<&Power<T>> for &InverseArea<T> where T: NumLike {
+	type Output = Irradiance<T>;
+	fn mul(self, rhs: &Power<T>) -> Self::Output {
+		Irradiance{W_per_m2: self.per_m2.clone() * rhs.W.clone()}
 	}
+}
 
-	/// Returns a new inverse area value from the given number of inverse square nm
-	/// 
-	/// *Note: This method is not available for `f32` and other number types lacking the `From<f64>` trait*
-	///
-	/// # Arguments
-	/// * `per_nm2` - Any number-like type, representing a quantity of inverse square nm
-	pub fn from_per_nm2(per_nm2: T) -> Self {
-		InverseArea{per_m2: per_nm2 * T::from(1e+18_f64)}
+// InverseArea / Pressure -> InverseForce
+/// Dividing a InverseArea by a Pressure returns a value of type InverseForce
+impl<T> core::ops::Div<Pressure<T>> for InverseArea<T> where T: NumLike {
+	type Output = InverseForce<T>;
+	fn div(self, rhs: Pressure<T>) -> Self::Output {
+		InverseForce{per_N: self.per_m2 / rhs.Pa}
 	}
-
-	/// Returns a copy of this inverse area value in inverse square km
-	/// 
-	/// *Note: This method is not available for `f32` and other number types lacking the `From<f64>` trait*
-	pub fn to_per_km2(&self) -> T {
-		return self.per_m2.clone() * T::from(1000000.0_f64);
+}
+/// Dividing a InverseArea by a Pressure returns a value of type InverseForce
+impl<T> core::ops::Div<Pressure<T>> for &InverseArea<T> where T: NumLike {
+	type Output = InverseForce<T>;
+	fn div(self, rhs: Pressure<T>) -> Self::Output {
+		InverseForce{per_N: self.per_m2.clone() / rhs.Pa}
 	}
-
-	/// Returns a new inverse area value from the given number of inverse square km
-	/// 
-	/// *Note: This method is not available for `f32` and other number types lacking the `From<f64>` trait*
-	///
-	/// # Arguments
-	/// * `per_km2` - Any number-like type, representing a quantity of inverse square km
-	pub fn from_per_km2(per_km2: T) -> Self {
-		InverseArea{per_m2: per_km2 * T::from(1e-06_f64)}
+}
+/// Dividing a InverseArea by a Pressure returns a value of type InverseForce
+impl<T> core::ops::Div<&Pressure<T>> for InverseArea<T> where T: NumLike {
+	type Output = InverseForce<T>;
+	fn div(self, rhs: &Pressure<T>) -> Self::Output {
+		InverseForce{per_N: self.per_m2 / rhs.Pa.clone()}
+	}
+}
+/// Dividing a InverseArea by a Pressure returns a value of type InverseForce
+impl<T> core::ops::Div<&Pressure<T>> for &InverseArea<T> where T: NumLike {
+	type Output = InverseForce<T>;
+	fn div(self, rhs: &Pressure<T>) -> Self::Output {
+		InverseForce{per_N: self.per_m2.clone() / rhs.Pa.clone()}
 	}
-
 }
 
-
-/// Multiplying a unit value by a scalar value returns a unit value
-#[cfg(feature="num-bigfloat")]
-impl core::ops::Mul<InverseArea<num_bigfloat::BigFloat>> for num_bigfloat::BigFloat {
-	type Output = InverseArea<num_bigfloat::BigFloat>;
-	fn mul(self, rhs: InverseArea<num_bigfloat::BigFloat>) -> Self::Output {
-		InverseArea{per_m2: self * rhs.per_m2}
+// InverseArea * TimePerDistance -> InverseVolumetricFlowRate
+/// Multiplying a InverseArea by a TimePerDistance returns a value of type InverseVolumetricFlowRate
+impl<T> core::ops::Mul<TimePerDistance<T>> for InverseArea<T> where T: NumLike {
+	type Output = InverseVolumetricFlowRate<T>;
+	fn mul(self, rhs: TimePerDistance<T>) -> Self::Output {
+		InverseVolumetricFlowRate{s_per_m3: self.per_m2 * rhs.spm}
 	}
 }
-/// Multiplying a unit value by a scalar value returns a unit value
-#[cfg(feature="num-bigfloat")]
-impl core::ops::Mul<InverseArea<num_bigfloat::BigFloat>> for &num_bigfloat::BigFloat {
-	type Output = InverseArea<num_bigfloat::BigFloat>;
-	fn mul(self, rhs: InverseArea<num_bigfloat::BigFloat>) -> Self::Output {
-		InverseArea{per_m2: self.clone() * rhs.per_m2}
+/// Multiplying a InverseArea by a TimePerDistance returns a value of type InverseVolumetricFlowRate
+impl<T> core::ops::Mul<TimePerDistance<T>> for &InverseArea<T> where T: NumLike {
+	type Output = InverseVolumetricFlowRate<T>;
+	fn mul(self, rhs: TimePerDistance<T>) -> Self::Output {
+		InverseVolumetricFlowRate{s_per_m3: self.per_m2.clone() * rhs.spm}
 	}
 }
-/// Multiplying a unit value by a scalar value returns a unit value
-#[cfg(feature="num-bigfloat")]
-impl core::ops::Mul<&InverseArea<num_bigfloat::BigFloat>> for num_bigfloat::BigFloat {
-	type Output = InverseArea<num_bigfloat::BigFloat>;
-	fn mul(self, rhs: &InverseArea<num_bigfloat::BigFloat>) -> Self::Output {
-		InverseArea{per_m2: self * rhs.per_m2.clone()}
+/// Multiplying a InverseArea by a TimePerDistance returns a value of type InverseVolumetricFlowRate
+impl<T> core::ops::Mul<&TimePerDistance<T>> for InverseArea<T> where T: NumLike {
+	type Output = InverseVolumetricFlowRate<T>;
+	fn mul(self, rhs: &TimePerDistance<T>) -> Self::Output {
+		InverseVolumetricFlowRate{s_per_m3: self.per_m2 * rhs.spm.clone()}
 	}
 }
-/// Multiplying a unit value by a scalar value returns a unit value
-#[cfg(feature="num-bigfloat")]
-impl core::ops::Mul<&InverseArea<num_bigfloat::BigFloat>> for &num_bigfloat::BigFloat {
-	type Output = InverseArea<num_bigfloat::BigFloat>;
-	fn mul(self, rhs: &InverseArea<num_bigfloat::BigFloat>) -> Self::Output {
-		InverseArea{per_m2: self.clone() * rhs.per_m2.clone()}
+/// Multiplying a InverseArea by a TimePerDistance returns a value of type InverseVolumetricFlowRate
+impl<T> core::ops::Mul<&TimePerDistance<T>> for &InverseArea<T> where T: NumLike {
+	type Output = InverseVolumetricFlowRate<T>;
+	fn mul(self, rhs: &TimePerDistance<T>) -> Self::Output {
+		InverseVolumetricFlowRate{s_per_m3: self.per_m2.clone() * rhs.spm.clone()}
 	}
 }
 
-/// Multiplying a unit value by a scalar value returns a unit value
-#[cfg(feature="num-complex")]
-impl core::ops::Mul<InverseArea<num_complex::Complex32>> for num_complex::Complex32 {
-	type Output = InverseArea<num_complex::Complex32>;
-	fn mul(self, rhs: InverseArea<num_complex::Complex32>) -> Self::Output {
-		InverseArea{per_m2: self * rhs.per_m2}
+// InverseArea / Velocity -> InverseVolumetricFlowRate
+/// Dividing a InverseArea by a Velocity returns a value of type InverseVolumetricFlowRate
+impl<T> core::ops::Div<Velocity<T>> for InverseArea<T> where T: NumLike {
+	type Output = InverseVolumetricFlowRate<T>;
+	fn div(self, rhs: Velocity<T>) -> Self::Output {
+		InverseVolumetricFlowRate{s_per_m3: self.per_m2 / rhs.mps}
 	}
 }
-/// Multiplying a unit value by a scalar value returns a unit value
-#[cfg(feature="num-complex")]
-impl core::ops::Mul<InverseArea<num_complex::Complex32>> for &num_complex::Complex32 {
-	type Output = InverseArea<num_complex::Complex32>;
-	fn mul(self, rhs: InverseArea<num_complex::Complex32>) -> Self::Output {
-		InverseArea{per_m2: self.clone() * rhs.per_m2}
+/// Dividing a InverseArea by a Velocity returns a value of type InverseVolumetricFlowRate
+impl<T> core::ops::Div<Velocity<T>> for &InverseArea<T> where T: NumLike {
+	type Output = InverseVolumetricFlowRate<T>;
+	fn div(self, rhs: Velocity<T>) -> Self::Output {
+		InverseVolumetricFlowRate{s_per_m3: self.per_m2.clone() / rhs.mps}
 	}
 }
-/// Multiplying a unit value by a scalar value returns a unit value
-#[cfg(feature="num-complex")]
-impl core::ops::Mul<&InverseArea<num_complex::Complex32>> for num_complex::Complex32 {
-	type Output = InverseArea<num_complex::Complex32>;
-	fn mul(self, rhs: &InverseArea<num_complex::Complex32>) -> Self::Output {
-		InverseArea{per_m2: self * rhs.per_m2.clone()}
+/// Dividing a InverseArea by a Velocity returns a value of type InverseVolumetricFlowRate
+impl<T> core::ops::Div<&Velocity<T>> for InverseArea<T> where T: NumLike {
+	type Output = InverseVolumetricFlowRate<T>;
+	fn div(self, rhs: &Velocity<T>) -> Self::Output {
+		InverseVolumetricFlowRate{s_per_m3: self.per_m2 / rhs.mps.clone()}
 	}
 }
-/// Multiplying a unit value by a scalar value returns a unit value
-#[cfg(feature="num-complex")]
-impl core::ops::Mul<&InverseArea<num_complex::Complex32>> for &num_complex::Complex32 {
-	type Output = InverseArea<num_complex::Complex32>;
-	fn mul(self, rhs: &InverseArea<num_complex::Complex32>) -> Self::Output {
-		InverseArea{per_m2: self.clone() * rhs.per_m2.clone()}
+/// Dividing a InverseArea by a Velocity returns a value of type InverseVolumetricFlowRate
+impl<T> core::ops::Div<&Velocity<T>> for &InverseArea<T> where T: NumLike {
+	type Output = InverseVolumetricFlowRate<T>;
+	fn div(self, rhs: &Velocity<T>) -> Self::Output {
+		InverseVolumetricFlowRate{s_per_m3: self.per_m2.clone() / rhs.mps.clone()}
 	}
 }
 
-/// Multiplying a unit value by a scalar value returns a unit value
-#[cfg(feature="num-complex")]
-impl core::ops::Mul<InverseArea<num_complex::Complex64>> for num_complex::Complex64 {
-	type Output = InverseArea<num_complex::Complex64>;
-	fn mul(self, rhs: InverseArea<num_complex::Complex64>) -> Self::Output {
-		InverseArea{per_m2: self * rhs.per_m2}
+// InverseArea * VolumetricFlowRate -> Velocity
+/// Multiplying a InverseArea by a VolumetricFlowRate returns a value of type Velocity
+impl<T> core::ops::Mul<VolumetricFlowRate<T>> for InverseArea<T> where T: NumLike {
+	type Output = Velocity<T>;
+	fn mul(self, rhs: VolumetricFlowRate<T>) -> Self::Output {
+		Velocity{mps: self.per_m2 * rhs.m3ps}
 	}
 }
-/// Multiplying a unit value by a scalar value returns a unit value
-#[cfg(feature="num-complex")]
-impl core::ops::Mul<InverseArea<num_complex::Complex64>> for &num_complex::Complex64 {
-	type Output = InverseArea<num_complex::Complex64>;
-	fn mul(self, rhs: InverseArea<num_complex::Complex64>) -> Self::Output {
-		InverseArea{per_m2: self.clone() * rhs.per_m2}
+/// Multiplying a InverseArea by a VolumetricFlowRate returns a value of type Velocity
+impl<T> core::ops::Mul<VolumetricFlowRate<T>> for &InverseArea<T> where T: NumLike {
+	type Output = Velocity<T>;
+	fn mul(self, rhs: VolumetricFlowRate<T>) -> Self::Output {
+		Velocity{mps: self.per_m2.clone() * rhs.m3ps}
 	}
 }
-/// Multiplying a unit value by a scalar value returns a unit value
-#[cfg(feature="num-complex")]
-impl core::ops::Mul<&InverseArea<num_complex::Complex64>> for num_complex::Complex64 {
-	type Output = InverseArea<num_complex::Complex64>;
-	fn mul(self, rhs: &InverseArea<num_complex::Complex64>) -> Self::Output {
-		InverseArea{per_m2: self * rhs.per_m2.clone()}
+/// Multiplying a InverseArea by a VolumetricFlowRate returns a value of type Velocity
+impl<T> core::ops::Mul<&VolumetricFlowRate<T>> for InverseArea<T> where T: NumLike {
+	type Output = Velocity<T>;
+	fn mul(self, rhs: &VolumetricFlowRate<T>) -> Self::Output {
+		Velocity{mps: self.per_m2 * rhs.m3ps.clone()}
 	}
 }
-/// Multiplying a unit value by a scalar value returns a unit value
-#[cfg(feature="num-complex")]
-impl core::ops::Mul<&InverseArea<num_complex::Complex64>> for &num_complex::Complex64 {
-	type Output = InverseArea<num_complex::Complex64>;
-	fn mul(self, rhs: &InverseArea<num_complex::Complex64>) -> Self::Output {
-		InverseArea{per_m2: self.clone() * rhs.per_m2.clone()}
+/// Multiplying a InverseArea by a VolumetricFlowRate returns a value of type Velocity
+impl<T> core::ops::Mul<&VolumetricFlowRate<T>> for &InverseArea<T> where T: NumLike {
+	type Output = Velocity<T>;
+	fn mul(self, rhs: &VolumetricFlowRate<T>) -> Self::Output {
+		Velocity{mps: self.per_m2.clone() * rhs.m3ps.clone()}
 	}
 }
 
+impl<T> InverseArea<T> where T: NumLike+From<u8> {
+	/// Returns the reciprocal of this InverseArea value (ie 1 divided by 
+	/// this value) as a value of type Area
+	pub fn recip(self) -> Area<T> {
+		Area{m2: T::from(1u8) / self.per_m2}
+	}
+}
 
+// 1/InverseArea -> Area
+/// Dividing a scalar value by a InverseArea unit value returns a value of type Area
+impl<T> core::ops::Div<InverseArea<T>> for f64 where T: NumLike+From<f64> {
+	type Output = Area<T>;
+	fn div(self, rhs: InverseArea<T>) -> Self::Output {
+		Area{m2: T::from(self) / rhs.per_m2}
+	}
+}
+/// Dividing a scalar value by a InverseArea unit value returns a value of type Area
+impl<T> core::ops::Div<InverseArea<T>> for &f64 where T: NumLike+From<f64> {
+	type Output = Area<T>;
+	fn div(self, rhs: InverseArea<T>) -> Self::Output {
+		Area{m2: T::from(self.clone()) / rhs.per_m2}
+	}
+}
+/// Dividing a scalar value by a InverseArea unit value returns a value of type Area
+impl<T> core::ops::Div<&InverseArea<T>> for f64 where T: NumLike+From<f64> {
+	type Output = Area<T>;
+	fn div(self, rhs: &InverseArea<T>) -> Self::Output {
+		Area{m2: T::from(self) / rhs.per_m2.clone()}
+	}
+}
+/// Dividing a scalar value by a InverseArea unit value returns a value of type Area
+impl<T> core::ops::Div<&InverseArea<T>> for &f64 where T: NumLike+From<f64> {
+	type Output = Area<T>;
+	fn div(self, rhs: &InverseArea<T>) -> Self::Output {
+		Area{m2: T::from(self.clone()) / rhs.per_m2.clone()}
+	}
+}
 
-/// Converts a InverseArea into the equivalent [uom](https://crates.io/crates/uom) type [ArealNumberDensity](https://docs.rs/uom/0.34.0/uom/si/f32/type.ArealNumberDensity.html)
-#[cfg(feature = "uom")]
-impl<T> Into<uom::si::f32::ArealNumberDensity> for InverseArea<T> where T: NumLike+Into<f32> {
-	fn into(self) -> uom::si::f32::ArealNumberDensity {
-		uom::si::f32::ArealNumberDensity::new::<uom::si::areal_number_density::per_square_meter>(self.per_m2.into())
+// 1/InverseArea -> Area
+/// Dividing a scalar value by a InverseArea unit value returns a value of type Area
+impl<T> core::ops::Div<InverseArea<T>> for f32 where T: NumLike+From<f32> {
+	type Output = Area<T>;
+	fn div(self, rhs: InverseArea<T>) -> Self::Output {
+		Area{m2: T::from(self) / rhs.per_m2}
 	}
 }
-
-/// Creates a InverseArea from the equivalent [uom](https://crates.io/crates/uom) type [ArealNumberDensity](https://docs.rs/uom/0.34.0/uom/si/f32/type.ArealNumberDensity.html)
-#[cfg(feature = "uom")]
-impl<T> From<uom::si::f32::ArealNumberDensity> for InverseArea<T> where T: NumLike+From<f32> {
-	fn from(src: uom::si::f32::ArealNumberDensity) -> Self {
-		InverseArea{per_m2: T::from(src.value)}
+/// Dividing a scalar value by a InverseArea unit value returns a value of type Area
+impl<T> core::ops::Div<InverseArea<T>> for &f32 where T: NumLike+From<f32> {
+	type Output = Area<T>;
+	fn div(self, rhs: InverseArea<T>) -> Self::Output {
+		Area{m2: T::from(self.clone()) / rhs.per_m2}
 	}
 }
-
-/// Converts a InverseArea into the equivalent [uom](https://crates.io/crates/uom) type [ArealNumberDensity](https://docs.rs/uom/0.34.0/uom/si/f64/type.ArealNumberDensity.html)
-#[cfg(feature = "uom")]
-impl<T> Into<uom::si::f64::ArealNumberDensity> for InverseArea<T> where T: NumLike+Into<f64> {
-	fn into(self) -> uom::si::f64::ArealNumberDensity {
-		uom::si::f64::ArealNumberDensity::new::<uom::si::areal_number_density::per_square_meter>(self.per_m2.into())
+/// Dividing a scalar value by a InverseArea unit value returns a value of type Area
+impl<T> core::ops::Div<&InverseArea<T>> for f32 where T: NumLike+From<f32> {
+	type Output = Area<T>;
+	fn div(self, rhs: &InverseArea<T>) -> Self::Output {
+		Area{m2: T::from(self) / rhs.per_m2.clone()}
 	}
 }
-
-/// Creates a InverseArea from the equivalent [uom](https://crates.io/crates/uom) type [ArealNumberDensity](https://docs.rs/uom/0.34.0/uom/si/f64/type.ArealNumberDensity.html)
-#[cfg(feature = "uom")]
-impl<T> From<uom::si::f64::ArealNumberDensity> for InverseArea<T> where T: NumLike+From<f64> {
-	fn from(src: uom::si::f64::ArealNumberDensity) -> Self {
-		InverseArea{per_m2: T::from(src.value)}
+/// Dividing a scalar value by a InverseArea unit value returns a value of type Area
+impl<T> core::ops::Div<&InverseArea<T>> for &f32 where T: NumLike+From<f32> {
+	type Output = Area<T>;
+	fn div(self, rhs: &InverseArea<T>) -> Self::Output {
+		Area{m2: T::from(self.clone()) / rhs.per_m2.clone()}
 	}
 }
 
-
-// InverseArea * Distance -> InverseDistance
-/// Multiplying a InverseArea by a Distance returns a value of type InverseDistance
-impl<T> core::ops::Mul<Distance<T>> for InverseArea<T> where T: NumLike {
-	type Output = InverseDistance<T>;
-	fn mul(self, rhs: Distance<T>) -> Self::Output {
-		InverseDistance{per_m: self.per_m2 * rhs.m}
+// 1/InverseArea -> Area
+/// Dividing a scalar value by a InverseArea unit value returns a value of type Area
+impl<T> core::ops::Div<InverseArea<T>> for i64 where T: NumLike+From<i64> {
+	type Output = Area<T>;
+	fn div(self, rhs: InverseArea<T>) -> Self::Output {
+		Area{m2: T::from(self) / rhs.per_m2}
 	}
 }
-/// Multiplying a InverseArea by a Distance returns a value of type InverseDistance
-impl<T> core::ops::Mul<Distance<T>> for &InverseArea<T> where T: NumLike {
-	type Output = InverseDistance<T>;
-	fn mul(self, rhs: Distance<T>) -> Self::Output {
-		InverseDistance{per_m: self.per_m2.clone() * rhs.m}
+/// Dividing a scalar value by a InverseArea unit value returns a value of type Area
+impl<T> core::ops::Div<InverseArea<T>> for &i64 where T: NumLike+From<i64> {
+	type Output = Area<T>;
+	fn div(self, rhs: InverseArea<T>) -> Self::Output {
+		Area{m2: T::from(self.clone()) / rhs.per_m2}
 	}
 }
-/// Multiplying a InverseArea by a Distance returns a value of type InverseDistance
-impl<T> core::ops::Mul<&Distance<T>> for InverseArea<T> where T: NumLike {
-	type Output = InverseDistance<T>;
-	fn mul(self, rhs: &Distance<T>) -> Self::Output {
-		InverseDistance{per_m: self.per_m2 * rhs.m.clone()}
+/// Dividing a scalar value by a InverseArea unit value returns a value of type Area
+impl<T> core::ops::Div<&InverseArea<T>> for i64 where T: NumLike+From<i64> {
+	type Output = Area<T>;
+	fn div(self, rhs: &InverseArea<T>) -> Self::Output {
+		Area{m2: T::from(self) / rhs.per_m2.clone()}
 	}
 }
-/// Multiplying a InverseArea by a Distance returns a value of type InverseDistance
-impl<T> core::ops::Mul<&Distance<T>> for &InverseArea<T> where T: NumLike {
-	type Output = InverseDistance<T>;
-	fn mul(self, rhs: &Distance<T>) -> Self::Output {
-		InverseDistance{per_m: self.per_m2.clone() * rhs.m.clone()}
+/// Dividing a scalar value by a InverseArea unit value returns a value of type Area
+impl<T> core::ops::Div<&InverseArea<T>> for &i64 where T: NumLike+From<i64> {
+	type Output = Area<T>;
+	fn div(self, rhs: &InverseArea<T>) -> Self::Output {
+		Area{m2: T::from(self.clone()) / rhs.per_m2.clone()}
 	}
 }
 
-// InverseArea / Distance -> InverseVolume
-/// Dividing a InverseArea by a Distance returns a value of type InverseVolume
-impl<T> core::ops::Div<Distance<T>> for InverseArea<T> where T: NumLike {
-	type Output = InverseVolume<T>;
-	fn div(self, rhs: Distance<T>) -> Self::Output {
-		InverseVolume{per_m3: self.per_m2 / rhs.m}
+// 1/InverseArea -> Area
+/// Dividing a scalar value by a InverseArea unit value returns a value of type Area
+impl<T> core::ops::Div<InverseArea<T>> for i32 where T: NumLike+From<i32> {
+	type Output = Area<T>;
+	fn div(self, rhs: InverseArea<T>) -> Self::Output {
+		Area{m2: T::from(self) / rhs.per_m2}
 	}
 }
-/// Dividing a InverseArea by a Distance returns a value of type InverseVolume
-impl<T> core::ops::Div<Distance<T>> for &InverseArea<T> where T: NumLike {
-	type Output = InverseVolume<T>;
-	fn div(self, rhs: Distance<T>) -> Self::Output {
-		InverseVolume{per_m3: self.per_m2.clone() / rhs.m}
+/// Dividing a scalar value by a InverseArea unit value returns a value of type Area
+impl<T> core::ops::Div<InverseArea<T>> for &i32 where T: NumLike+From<i32> {
+	type Output = Area<T>;
+	fn div(self, rhs: InverseArea<T>) -> Self::Output {
+		Area{m2: T::from(self.clone()) / rhs.per_m2}
 	}
 }
-/// Dividing a InverseArea by a Distance returns a value of type InverseVolume
-impl<T> core::ops::Div<&Distance<T>> for InverseArea<T> where T: NumLike {
-	type Output = InverseVolume<T>;
-	fn div(self, rhs: &Distance<T>) -> Self::Output {
-		InverseVolume{per_m3: self.per_m2 / rhs.m.clone()}
+/// Dividing a scalar value by a InverseArea unit value returns a value of type Area
+impl<T> core::ops::Div<&InverseArea<T>> for i32 where T: NumLike+From<i32> {
+	type Output = Area<T>;
+	fn div(self, rhs: &InverseArea<T>) -> Self::Output {
+		Area{m2: T::from(self) / rhs.per_m2.clone()}
 	}
 }
-/// Dividing a InverseArea by a Distance returns a value of type InverseVolume
-impl<T> core::ops::Div<&Distance<T>> for &InverseArea<T> where T: NumLike {
-	type Output = InverseVolume<T>;
-	fn div(self, rhs: &Distance<T>) -> Self::Output {
-		InverseVolume{per_m3: self.per_m2.clone() / rhs.m.clone()}
+/// Dividing a scalar value by a InverseArea unit value returns a value of type Area
+impl<T> core::ops::Div<&InverseArea<T>> for &i32 where T: NumLike+From<i32> {
+	type Output = Area<T>;
+	fn div(self, rhs: &InverseArea<T>) -> Self::Output {
+		Area{m2: T::from(self.clone()) / rhs.per_m2.clone()}
 	}
 }
 
-// InverseArea * InverseDistance -> InverseVolume
-/// Multiplying a InverseArea by a InverseDistance returns a value of type InverseVolume
-impl<T> core::ops::Mul<InverseDistance<T>> for InverseArea<T> where T: NumLike {
-	type Output = InverseVolume<T>;
-	fn mul(self, rhs: InverseDistance<T>) -> Self::Output {
-		InverseVolume{per_m3: self.per_m2 * rhs.per_m}
+// 1/InverseArea -> Area
+/// Dividing a scalar value by a InverseArea unit value returns a value of type Area
+#[cfg(feature="num-bigfloat")]
+impl<T> core::ops::Div<InverseArea<T>> for num_bigfloat::BigFloat where T: NumLike+From<num_bigfloat::BigFloat> {
+	type Output = Area<T>;
+	fn div(self, rhs: InverseArea<T>) -> Self::Output {
+		Area{m2: T::from(self) / rhs.per_m2}
 	}
 }
-/// Multiplying a InverseArea by a InverseDistance returns a value of type InverseVolume
-impl<T> core::ops::Mul<InverseDistance<T>> for &InverseArea<T> where T: NumLike {
-	type Output = InverseVolume<T>;
-	fn mul(self, rhs: InverseDistance<T>) -> Self::Output {
-		InverseVolume{per_m3: self.per_m2.clone() * rhs.per_m}
+/// Dividing a scalar value by a InverseArea unit value returns a value of type Area
+#[cfg(feature="num-bigfloat")]
+impl<T> core::ops::Div<InverseArea<T>> for &num_bigfloat::BigFloat where T: NumLike+From<num_bigfloat::BigFloat> {
+	type Output = Area<T>;
+	fn div(self, rhs: InverseArea<T>) -> Self::Output {
+		Area{m2: T::from(self.clone()) / rhs.per_m2}
 	}
 }
-/// Multiplying a InverseArea by a InverseDistance returns a value of type InverseVolume
-impl<T> core::ops::Mul<&InverseDistance<T>> for InverseArea<T> where T: NumLike {
-	type Output = InverseVolume<T>;
-	fn mul(self, rhs: &InverseDistance<T>) -> Self::Output {
-		InverseVolume{per_m3: self.per_m2 * rhs.per_m.clone()}
+/// Dividing a scalar value by a InverseArea unit value returns a value of type Area
+#[cfg(feature="num-bigfloat")]
+impl<T> core::ops::Div<&InverseArea<T>> for num_bigfloat::BigFloat where T: NumLike+From<num_bigfloat::BigFloat> {
+	type Output = Area<T>;
+	fn div(self, rhs: &InverseArea<T>) -> Self::Output {
+		Area{m2: T::from(self) / rhs.per_m2.clone()}
 	}
 }
-/// Multiplying a InverseArea by a InverseDistance returns a value of type InverseVolume
-impl<T> core::ops::Mul<&InverseDistance<T>> for &InverseArea<T> where T: NumLike {
-	type Output = InverseVolume<T>;
-	fn mul(self, rhs: &InverseDistance<T>) -> Self::Output {
-		InverseVolume{per_m3: self.per_m2.clone() * rhs.per_m.clone()}
+/// Dividing a scalar value by a InverseArea unit value returns a value of type Area
+#[cfg(feature="num-bigfloat")]
+impl<T> core::ops::Div<&InverseArea<T>> for &num_bigfloat::BigFloat where T: NumLike+From<num_bigfloat::BigFloat> {
+	type Output = Area<T>;
+	fn div(self, rhs: &InverseArea<T>) -> Self::Output {
+		Area{m2: T::from(self.clone()) / rhs.per_m2.clone()}
 	}
 }
 
-// InverseArea / InverseDistance -> InverseDistance
-/// Dividing a InverseArea by a InverseDistance returns a value of type InverseDistance
-impl<T> core::ops::Div<InverseDistance<T>> for InverseArea<T> where T: NumLike {
-	type Output = InverseDistance<T>;
-	fn div(self, rhs: InverseDistance<T>) -> Self::Output {
-		InverseDistance{per_m: self.per_m2 / rhs.per_m}
+// 1/InverseArea -> Area
+/// Dividing a scalar value by a InverseArea unit value returns a value of type Area
+#[cfg(feature="num-complex")]
+impl<T> core::ops::Div<InverseArea<T>> for num_complex::Complex32 where T: NumLike+From<num_complex::Complex32> {
+	type Output = Area<T>;
+	fn div(self, rhs: InverseArea<T>) -> Self::Output {
+		Area{m2: T::from(self) / rhs.per_m2}
 	}
 }
-/// Dividing a InverseArea by a InverseDistance returns a value of type InverseDistance
-impl<T> core::ops::Div<InverseDistance<T>> for &InverseArea<T> where T: NumLike {
-	type Output = InverseDistance<T>;
-	fn div(self, rhs: InverseDistance<T>) -> Self::Output {
-		InverseDistance{per_m: self.per_m2.clone() / rhs.per_m}
+/// Dividing a scalar value by a InverseArea unit value returns a value of type Area
+#[cfg(feature="num-complex")]
+impl<T> core::ops::Div<InverseArea<T>> for &num_complex::Complex32 where T: NumLike+From<num_complex::Complex32> {
+	type Output = Area<T>;
+	fn div(self, rhs: InverseArea<T>) -> Self::Output {
+		Area{m2: T::from(self.clone()) / rhs.per_m2}
 	}
 }
-/// Dividing a InverseArea by a InverseDistance returns a value of type InverseDistance
-impl<T> core::ops::Div<&InverseDistance<T>> for InverseArea<T> where T: NumLike {
-	type Output = InverseDistance<T>;
-	fn div(self, rhs: &InverseDistance<T>) -> Self::Output {
-		InverseDistance{per_m: self.per_m2 / rhs.per_m.clone()}
+/// Dividing a scalar value by a InverseArea unit value returns a value of type Area
+#[cfg(feature="num-complex")]
+impl<T> core::ops::Div<&InverseArea<T>> for num_complex::Complex32 where T: NumLike+From<num_complex::Complex32> {
+	type Output = Area<T>;
+	fn div(self, rhs: &InverseArea<T>) -> Self::Output {
+		Area{m2: T::from(self) / rhs.per_m2.clone()}
 	}
 }
-/// Dividing a InverseArea by a InverseDistance returns a value of type InverseDistance
-impl<T> core::ops::Div<&InverseDistance<T>> for &InverseArea<T> where T: NumLike {
-	type Output = InverseDistance<T>;
-	fn div(self, rhs: &InverseDistance<T>) -> Self::Output {
-		InverseDistance{per_m: self.per_m2.clone() / rhs.per_m.clone()}
+/// Dividing a scalar value by a InverseArea unit value returns a value of type Area
+#[cfg(feature="num-complex")]
+impl<T> core::ops::Div<&InverseArea<T>> for &num_complex::Complex32 where T: NumLike+From<num_complex::Complex32> {
+	type Output = Area<T>;
+	fn div(self, rhs: &InverseArea<T>) -> Self::Output {
+		Area{m2: T::from(self.clone()) / rhs.per_m2.clone()}
 	}
 }
 
-// InverseArea / InverseMass -> AreaDensity
-/// Dividing a InverseArea by a InverseMass returns a value of type AreaDensity
-impl<T> core::ops::Div<InverseMass<T>> for InverseArea<T> where T: NumLike {
-	type Output = AreaDensity<T>;
-	fn div(self, rhs: InverseMass<T>) -> Self::Output {
-		AreaDensity{kgpm2: self.per_m2 / rhs.per_kg}
+// 1/InverseArea -> Area
+/// Dividing a scalar value by a InverseArea unit value returns a value of type Area
+#[cfg(feature="num-complex")]
+impl<T> core::ops::Div<InverseArea<T>> for num_complex::Complex64 where T: NumLike+From<num_complex::Complex64> {
+	type Output = Area<T>;
+	fn div(self, rhs: InverseArea<T>) -> Self::Output {
+		Area{m2: T::from(self) / rhs.per_m2}
 	}
 }
-/// Dividing a InverseArea by a InverseMass returns a value of type AreaDensity
-impl<T> core::ops::Div<InverseMass<T>> for &InverseArea<T> where T: NumLike {
-	type Output = AreaDensity<T>;
-	fn div(self, rhs: InverseMass<T>) -> Self::Output {
-		AreaDensity{kgpm2: self.per_m2.clone() / rhs.per_kg}
+/// Dividing a scalar value by a InverseArea unit value returns a value of type Area
+#[cfg(feature="num-complex")]
+impl<T> core::ops::Div<InverseArea<T>> for &num_complex::Complex64 where T: NumLike+From<num_complex::Complex64> {
+	type Output = Area<T>;
+	fn div(self, rhs: InverseArea<T>) -> Self::Output {
+		Area{m2: T::from(self.clone()) / rhs.per_m2}
 	}
 }
-/// Dividing a InverseArea by a InverseMass returns a value of type AreaDensity
-impl<T> core::ops::Div<&InverseMass<T>> for InverseArea<T> where T: NumLike {
-	type Output = AreaDensity<T>;
-	fn div(self, rhs: &InverseMass<T>) -> Self::Output {
-		AreaDensity{kgpm2: self.per_m2 / rhs.per_kg.clone()}
+/// Dividing a scalar value by a InverseArea unit value returns a value of type Area
+#[cfg(feature="num-complex")]
+impl<T> core::ops::Div<&InverseArea<T>> for num_complex::Complex64 where T: NumLike+From<num_complex::Complex64> {
+	type Output = Area<T>;
+	fn div(self, rhs: &InverseArea<T>) -> Self::Output {
+		Area{m2: T::from(self) / rhs.per_m2.clone()}
 	}
 }
-/// Dividing a InverseArea by a InverseMass returns a value of type AreaDensity
-impl<T> core::ops::Div<&InverseMass<T>> for &InverseArea<T> where T: NumLike {
-	type Output = AreaDensity<T>;
-	fn div(self, rhs: &InverseMass<T>) -> Self::Output {
-		AreaDensity{kgpm2: self.per_m2.clone() / rhs.per_kg.clone()}
+/// Dividing a scalar value by a InverseArea unit value returns a value of type Area
+#[cfg(feature="num-complex")]
+impl<T> core::ops::Div<&InverseArea<T>> for &num_complex::Complex64 where T: NumLike+From<num_complex::Complex64> {
+	type Output = Area<T>;
+	fn div(self, rhs: &InverseArea<T>) -> Self::Output {
+		Area{m2: T::from(self.clone()) / rhs.per_m2.clone()}
 	}
 }
 
-// InverseArea * Mass -> AreaDensity
-/// Multiplying a InverseArea by a Mass returns a value of type AreaDensity
-impl<T> core::ops::Mul<Mass<T>> for InverseArea<T> where T: NumLike {
-	type Output = AreaDensity<T>;
-	fn mul(self, rhs: Mass<T>) -> Self::Output {
-		AreaDensity{kgpm2: self.per_m2 * rhs.kg}
+// 1/InverseArea -> Area
+/// Dividing a scalar value by a InverseArea unit value returns a value of type Area
+#[cfg(feature="num-rational")]
+impl<T> core::ops::Div<InverseArea<T>> for num_rational::Rational64 where T: NumLike+From<num_rational::Rational64> {
+	type Output = Area<T>;
+	fn div(self, rhs: InverseArea<T>) -> Self::Output {
+		Area{m2: T::from(self) / rhs.per_m2}
 	}
 }
-/// Multiplying a InverseArea by a Mass returns a value of type AreaDensity
-impl<T> core::ops::Mul<Mass<T>> for &InverseArea<T> where T: NumLike {
-	type Output = AreaDensity<T>;
-	fn mul(self, rhs: Mass<T>) -> Self::Output {
-		AreaDensity{kgpm2: self.per_m2.clone() * rhs.kg}
+/// Dividing a scalar value by a InverseArea unit value returns a value of type Area
+#[cfg(feature="num-rational")]
+impl<T> core::ops::Div<InverseArea<T>> for &num_rational::Rational64 where T: NumLike+From<num_rational::Rational64> {
+	type Output = Area<T>;
+	fn div(self, rhs: InverseArea<T>) -> Self::Output {
+		Area{m2: T::from(self.clone()) / rhs.per_m2}
 	}
 }
-/// Multiplying a InverseArea by a Mass returns a value of type AreaDensity
-impl<T> core::ops::Mul<&Mass<T>> for InverseArea<T> where T: NumLike {
-	type Output = AreaDensity<T>;
-	fn mul(self, rhs: &Mass<T>) -> Self::Output {
-		AreaDensity{kgpm2: self.per_m2 * rhs.kg.clone()}
+/// Dividing a scalar value by a InverseArea unit value returns a value of type Area
+#[cfg(feature="num-rational")]
+impl<T> core::ops::Div<&InverseArea<T>> for num_rational::Rational64 where T: NumLike+From<num_rational::Rational64> {
+	type Output = Area<T>;
+	fn div(self, rhs: &InverseArea<T>) -> Self::Output {
+		Area{m2: T::from(self) / rhs.per_m2.clone()}
 	}
 }
-/// Multiplying a InverseArea by a Mass returns a value of type AreaDensity
-impl<T> core::ops::Mul<&Mass<T>> for &InverseArea<T> where T: NumLike {
-	type Output = AreaDensity<T>;
-	fn mul(self, rhs: &Mass<T>) -> Self::Output {
-		AreaDensity{kgpm2: self.per_m2.clone() * rhs.kg.clone()}
+/// Dividing a scalar value by a InverseArea unit value returns a value of type Area
+#[cfg(feature="num-rational")]
+impl<T> core::ops::Div<&InverseArea<T>> for &num_rational::Rational64 where T: NumLike+From<num_rational::Rational64> {
+	type Output = Area<T>;
+	fn div(self, rhs: &InverseArea<T>) -> Self::Output {
+		Area{m2: T::from(self.clone()) / rhs.per_m2.clone()}
 	}
 }
 
-// InverseArea * AreaPerLumen -> InverseLuminousFlux
-/// Multiplying a InverseArea by a AreaPerLumen returns a value of type InverseLuminousFlux
-impl<T> core::ops::Mul<AreaPerLumen<T>> for InverseArea<T> where T: NumLike {
-	type Output = InverseLuminousFlux<T>;
-	fn mul(self, rhs: AreaPerLumen<T>) -> Self::Output {
-		InverseLuminousFlux{per_lm: self.per_m2 * rhs.m2_per_lm}
+// 1/InverseArea -> Area
+/// Dividing a scalar value by a InverseArea unit value returns a value of type Area
+#[cfg(feature="num-rational")]
+impl<T> core::ops::Div<InverseArea<T>> for num_rational::BigRational where T: NumLike+From<num_rational::BigRational> {
+	type Output = Area<T>;
+	fn div(self, rhs: InverseArea<T>) -> Self::Output {
+		Area{m2: T::from(self) / rhs.per_m2}
 	}
 }
-/// Multiplying a InverseArea by a AreaPerLumen returns a value of type InverseLuminousFlux
-impl<T> core::ops::Mul<AreaPerLumen<T>> for &InverseArea<T> where T: NumLike {
-	type Output = InverseLuminousFlux<T>;
-	fn mul(self, rhs: AreaPerLumen<T>) -> Self::Output {
-		InverseLuminousFlux{per_lm: self.per_m2.clone() * rhs.m2_per_lm}
+/// Dividing a scalar value by a InverseArea unit value returns a value of type Area
+#[cfg(feature="num-rational")]
+impl<T> core::ops::Div<InverseArea<T>> for &num_rational::BigRational where T: NumLike+From<num_rational::BigRational> {
+	type Output = Area<T>;
+	fn div(self, rhs: InverseArea<T>) -> Self::Output {
+		Area{m2: T::from(self.clone()) / rhs.per_m2}
 	}
 }
-/// Multiplying a InverseArea by a AreaPerLumen returns a value of type InverseLuminousFlux
-impl<T> core::ops::Mul<&AreaPerLumen<T>> for InverseArea<T> where T: NumLike {
-	type Output = InverseLuminousFlux<T>;
-	fn mul(self, rhs: &AreaPerLumen<T>) -> Self::Output {
-		InverseLuminousFlux{per_lm: self.per_m2 * rhs.m2_per_lm.clone()}
+/// Dividing a scalar value by a InverseArea unit value returns a value of type Area
+#[cfg(feature="num-rational")]
+impl<T> core::ops::Div<&InverseArea<T>> for num_rational::BigRational where T: NumLike+From<num_rational::BigRational> {
+	type Output = Area<T>;
+	fn div(self, rhs: &InverseArea<T>) -> Self::Output {
+		Area{m2: T::from(self) / rhs.per_m2.clone()}
 	}
 }
-/// Multiplying a InverseArea by a AreaPerLumen returns a value of type InverseLuminousFlux
-impl<T> core::ops::Mul<&AreaPerLumen<T>> for &InverseArea<T> where T: NumLike {
-	type Output = InverseLuminousFlux<T>;
-	fn mul(self, rhs: &AreaPerLumen<T>) -> Self::Output {
-		InverseLuminousFlux{per_lm: self.per_m2.clone() * rhs.m2_per_lm.clone()}
+/// Dividing a scalar value by a InverseArea unit value returns a value of type Area
+#[cfg(feature="num-rational")]
+impl<T> core::ops::Div<&InverseArea<T>> for &num_rational::BigRational where T: NumLike+From<num_rational::BigRational> {
+	type Output = Area<T>;
+	fn div(self, rhs: &InverseArea<T>) -> Self::Output {
+		Area{m2: T::from(self.clone()) / rhs.per_m2.clone()}
 	}
 }
 
-// InverseArea / Illuminance -> InverseLuminousFlux
-/// Dividing a InverseArea by a Illuminance returns a value of type InverseLuminousFlux
-impl<T> core::ops::Div<Illuminance<T>> for InverseArea<T> where T: NumLike {
-	type Output = InverseLuminousFlux<T>;
-	fn div(self, rhs: Illuminance<T>) -> Self::Output {
-		InverseLuminousFlux{per_lm: self.per_m2 / rhs.lux}
-	}
+/// The inverse of solid angle unit type, defined as inverse steradian in SI units
+#[derive(UnitStruct, Debug, Clone, Default)]
+#[si(no_display)]
+#[cfg_attr(all(feature="serde", not(feature="serde-unit-tag")), derive(Serialize, Deserialize))]
+#[repr(transparent)]
+pub struct InverseSolidAngle<T: NumLike>{
+	/// The value of this Inverse solid angle in inverse steradian
+	pub per_sr: T
 }
-/// Dividing a InverseArea by a Illuminance returns a value of type InverseLuminousFlux
-impl<T> core::ops::Div<Illuminance<T>> for &InverseArea<T> where T: NumLike {
-	type Output = InverseLuminousFlux<T>;
-	fn div(self, rhs: Illuminance<T>) -> Self::Output {
-		InverseLuminousFlux{per_lm: self.per_m2.clone() / rhs.lux}
+
+impl<T> InverseSolidAngle<T> where T: NumLike {
+
+	/// Returns the standard unit name of inverse solid angle: "inverse steradian"
+	pub fn unit_name() -> &'static str { "inverse steradian" }
+	
+	/// Returns the abbreviated name or symbol of inverse solid angle: "1/sr" for inverse steradian
+	pub fn unit_symbol() -> &'static str { "1/sr" }
+
+	/// Returns the exponents of the SI base units of inverse solid angle (1/rad^2)
+	pub const fn dimension() -> super::Dimension { super::Dimension{length: 0, mass: 0, time: 0, current: 0, temperature: 0, amount: 0, luminosity: 0} }
+
+	/// Returns a wrapper that formats this inverse solid angle value with the full unit name instead of 
+	/// the unit symbol, eg "5 inverse steradian" (the unit name is not singularized for values of 1)
+	pub fn display_named(&self) -> super::NamedDisplay<'_, T> {
+		super::NamedDisplay::new(&self.per_sr, Self::unit_name())
 	}
-}
-/// Dividing a InverseArea by a Illuminance returns a value of type InverseLuminousFlux
-impl<T> core::ops::Div<&Illuminance<T>> for InverseArea<T> where T: NumLike {
-	type Output = InverseLuminousFlux<T>;
-	fn div(self, rhs: &Illuminance<T>) -> Self::Output {
-		InverseLuminousFlux{per_lm: self.per_m2 / rhs.lux.clone()}
+
+	/// Returns a new inverse solid angle value from the given number of inverse steradian (same as 
+	/// `from_per_sr(...)`), for any number-like type. This is useful in generic code 
+	/// that works with several unit types
+	///
+	/// # Arguments
+	/// * `value` - Any number-like type, representing a quantity of inverse steradian
+	pub const fn new(value: T) -> Self { InverseSolidAngle{per_sr: value} }
+
+	/// Returns a new inverse solid angle value from the given number of inverse steradian. Unlike
+	/// `from_per_sr(...)`, this is a `const fn`, so it can be used to declare `const` and `static` values
+	///
+	/// # Arguments
+	/// * `per_sr` - Any number-like type, representing a quantity of inverse steradian
+	pub const fn from_per_sr_const(per_sr: T) -> Self { InverseSolidAngle{per_sr} }
+
+	/// Returns a inverse solid angle value with a different number type, by applying the 
+	/// given function to the value in inverse steradian (eg `.map(|x| x as f32)` to convert 
+	/// from `f64` to `f32`)
+	///
+	/// # Arguments
+	/// * `f` - Function that converts the number of inverse steradian to the new number type
+	pub fn map<U, F>(self, f: F) -> InverseSolidAngle<U> where U: NumLike, F: FnOnce(T) -> U {
+		InverseSolidAngle{per_sr: f(self.per_sr)}
 	}
-}
-/// Dividing a InverseArea by a Illuminance returns a value of type InverseLuminousFlux
-impl<T> core::ops::Div<&Illuminance<T>> for &InverseArea<T> where T: NumLike {
-	type Output = InverseLuminousFlux<T>;
-	fn div(self, rhs: &Illuminance<T>) -> Self::Output {
-		InverseLuminousFlux{per_lm: self.per_m2.clone() / rhs.lux.clone()}
+
+	/// Returns a inverse solid angle value with a different number type, converted 
+	/// losslessly with the `From` trait (eg from `f32` to `f64`)
+	pub fn cast<U>(self) -> InverseSolidAngle<U> where U: NumLike+From<T> {
+		InverseSolidAngle{per_sr: U::from(self.per_sr)}
 	}
-}
 
-// InverseArea / InverseLuminousFlux -> Illuminance
-/// Dividing a InverseArea by a InverseLuminousFlux returns a value of type Illuminance
-impl<T> core::ops::Div<InverseLuminousFlux<T>> for InverseArea<T> where T: NumLike {
-	type Output = Illuminance<T>;
-	fn div(self, rhs: InverseLuminousFlux<T>) -> Self::Output {
-		Illuminance{lux: self.per_m2 / rhs.per_lm}
+	/// Returns a reference to the value of this inverse solid angle in inverse steradian
+	pub fn as_raw(&self) -> &T {
+		&self.per_sr
 	}
-}
-/// Dividing a InverseArea by a InverseLuminousFlux returns a value of type Illuminance
-impl<T> core::ops::Div<InverseLuminousFlux<T>> for &InverseArea<T> where T: NumLike {
-	type Output = Illuminance<T>;
-	fn div(self, rhs: InverseLuminousFlux<T>) -> Self::Output {
-		Illuminance{lux: self.per_m2.clone() / rhs.per_lm}
+
+	/// Returns a mutable reference to the value of this inverse solid angle in inverse steradian, 
+	/// eg for passing to numeric libraries that modify values in place. The number 
+	/// is always in inverse steradian, so changing it directly does not change the unit
+	pub fn raw_mut(&mut self) -> &mut T {
+		&mut self.per_sr
 	}
-}
-/// Dividing a InverseArea by a InverseLuminousFlux returns a value of type Illuminance
-impl<T> core::ops::Div<&InverseLuminousFlux<T>> for InverseArea<T> where T: NumLike {
-	type Output = Illuminance<T>;
-	fn div(self, rhs: &InverseLuminousFlux<T>) -> Self::Output {
-		Illuminance{lux: self.per_m2 / rhs.per_lm.clone()}
+
+	/// Returns a slice of numbers in inverse steradian as a slice of inverse solid angle values, 
+	/// without copying
+	///
+	/// # Arguments
+	/// * `values` - Slice of numbers, representing quantities of inverse steradian
+	pub fn wrap_slice(values: &[T]) -> &[Self] {
+		// SAFETY: InverseSolidAngle<T> is #[repr(transparent)], so it has the same memory layout as T
+		unsafe { core::slice::from_raw_parts(values.as_ptr() as *const Self, values.len()) }
 	}
-}
-/// Dividing a InverseArea by a InverseLuminousFlux returns a value of type Illuminance
-impl<T> core::ops::Div<&InverseLuminousFlux<T>> for &InverseArea<T> where T: NumLike {
-	type Output = Illuminance<T>;
-	fn div(self, rhs: &InverseLuminousFlux<T>) -> Self::Output {
-		Illuminance{lux: self.per_m2.clone() / rhs.per_lm.clone()}
+
+	/// Returns a mutable slice of numbers in inverse steradian as a mutable slice of 
+	/// inverse solid angle values, without copying
+	///
+	/// # Arguments
+	/// * `values` - Slice of numbers, representing quantities of inverse steradian
+	pub fn wrap_slice_mut(values: &mut [T]) -> &mut [Self] {
+		// SAFETY: InverseSolidAngle<T> is #[repr(transparent)], so it has the same memory layout as T
+		unsafe { core::slice::from_raw_parts_mut(values.as_mut_ptr() as *mut Self, values.len()) }
 	}
-}
 
-// InverseArea / InverseMagneticFlux -> MagneticFluxDensity
-/// Dividing a InverseArea by a InverseMagneticFlux returns a value of type MagneticFluxDensity
-impl<T> core::ops::Div<InverseMagneticFlux<T>> for InverseArea<T> where T: NumLike {
-	type Output = MagneticFluxDensity<T>;
-	fn div(self, rhs: InverseMagneticFlux<T>) -> Self::Output {
-		MagneticFluxDensity{T: self.per_m2 / rhs.per_Wb}
+	/// Returns the ratio of this inverse solid angle value to another inverse solid angle value 
+	/// (same as `self / other`). Dividing a unit value by another value of the same 
+	/// unit type cancels the units, so the ratio is a dimensionless number
+	///
+	/// # Arguments
+	/// * `other` - The inverse solid angle value to divide by
+	pub fn ratio(self, other: Self) -> T {
+		self.per_sr / other.per_sr
 	}
-}
-/// Dividing a InverseArea by a InverseMagneticFlux returns a value of type MagneticFluxDensity
-impl<T> core::ops::Div<InverseMagneticFlux<T>> for &InverseArea<T> where T: NumLike {
-	type Output = MagneticFluxDensity<T>;
-	fn div(self, rhs: InverseMagneticFlux<T>) -> Self::Output {
-		MagneticFluxDensity{T: self.per_m2.clone() / rhs.per_Wb}
+
+	/// Returns the ratio of this inverse solid angle value to another inverse solid angle value 
+	/// as a `Dimensionless` value, instead of a bare number like `ratio(...)` does
+	///
+	/// # Arguments
+	/// * `other` - The inverse solid angle value to divide by
+	pub fn dimensionless_ratio(self, other: Self) -> super::base::Dimensionless<T> {
+		super::base::Dimensionless{value: self.per_sr / other.per_sr}
+	}
+
+	/// Returns this inverse solid angle value multiplied by a dimensionless factor 
+	/// (same as `self * factor`)
+	///
+	/// # Arguments
+	/// * `factor` - The number to multiply by
+	pub fn scale(self, factor: T) -> Self {
+		InverseSolidAngle{per_sr: self.per_sr * factor}
 	}
+	
+	/// Returns a new inverse solid angle value from the given number of inverse steradians
+	///
+	/// # Arguments
+	/// * `per_sr` - Any number-like type, representing a quantity of inverse steradian
+	pub fn from_per_sr(per_sr: T) -> Self { InverseSolidAngle{per_sr: per_sr} }
+	
+	/// Returns a copy of this inverse solid angle value in inverse steradians
+	pub fn to_per_sr(&self) -> T { self.per_sr.clone() }
+
+	/// Returns a new inverse solid angle value from the given number of inverse steradians
+	///
+	/// # Arguments
+	/// * `per_steradians` - Any number-like type, representing a quantity of inverse steradian
+	pub fn from_per_steradians(per_steradians: T) -> Self { InverseSolidAngle{per_sr: per_steradians} }
+	
+	/// Returns a copy of this inverse solid angle value in inverse steradians
+	pub fn to_per_steradians(&self) -> T { self.per_sr.clone() }
+
 }
-/// Dividing a InverseArea by a InverseMagneticFlux returns a value of type MagneticFluxDensity
-impl<T> core::ops::Div<&InverseMagneticFlux<T>> for InverseArea<T> where T: NumLike {
-	type Output = MagneticFluxDensity<T>;
-	fn div(self, rhs: &InverseMagneticFlux<T>) -> Self::Output {
-		MagneticFluxDensity{T: self.per_m2 / rhs.per_Wb.clone()}
+
+/// Formats inverse solid angle values as a number followed by its unit symbol, eg "1500 1/sr"
+/// (complex numbers are wrapped in parentheses, eg "(1+2i) 1/sr").
+/// The alternate flag (`{:#}`) scales the number by the nearest SI prefix where the unit symbol allows it
+impl<T> fmt::Display for InverseSolidAngle<T> where T: NumLike {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		if f.alternate() {
+			return super::fmt_with_si_prefix(f, &self.per_sr, Self::unit_symbol(), false);
+		}
+		super::fmt_unit_value(f, &self.per_sr, Self::unit_symbol())
 	}
 }
-/// Dividing a InverseArea by a InverseMagneticFlux returns a value of type MagneticFluxDensity
-impl<T> core::ops::Div<&InverseMagneticFlux<T>> for &InverseArea<T> where T: NumLike {
-	type Output = MagneticFluxDensity<T>;
-	fn div(self, rhs: &InverseMagneticFlux<T>) -> Self::Output {
-		MagneticFluxDensity{T: self.per_m2.clone() / rhs.per_Wb.clone()}
+
+/// Formats inverse solid angle values in exponential notation followed by the unit symbol, eg "1.5e3 1/sr"
+impl<T> fmt::LowerExp for InverseSolidAngle<T> where T: NumLike+fmt::LowerExp {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		fmt::LowerExp::fmt(&self.per_sr, f)?;
+		write!(f, " {}", Self::unit_symbol())
 	}
 }
 
-// InverseArea * InverseMagneticFluxDensity -> InverseMagneticFlux
-/// Multiplying a InverseArea by a InverseMagneticFluxDensity returns a value of type InverseMagneticFlux
-impl<T> core::ops::Mul<InverseMagneticFluxDensity<T>> for InverseArea<T> where T: NumLike {
-	type Output = InverseMagneticFlux<T>;
-	fn mul(self, rhs: InverseMagneticFluxDensity<T>) -> Self::Output {
-		InverseMagneticFlux{per_Wb: self.per_m2 * rhs.m2_per_Wb}
+/// Formats inverse solid angle values in exponential notation followed by the unit symbol, eg "1.5E3 1/sr"
+impl<T> fmt::UpperExp for InverseSolidAngle<T> where T: NumLike+fmt::UpperExp {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		fmt::UpperExp::fmt(&self.per_sr, f)?;
+		write!(f, " {}", Self::unit_symbol())
 	}
 }
-/// Multiplying a InverseArea by a InverseMagneticFluxDensity returns a value of type InverseMagneticFlux
-impl<T> core::ops::Mul<InverseMagneticFluxDensity<T>> for &InverseArea<T> where T: NumLike {
-	type Output = InverseMagneticFlux<T>;
-	fn mul(self, rhs: InverseMagneticFluxDensity<T>) -> Self::Output {
-		InverseMagneticFlux{per_Wb: self.per_m2.clone() * rhs.m2_per_Wb}
+
+impl<T> super::SiUnit for InverseSolidAngle<T> where T: NumLike {
+	type Number = T;
+	fn unit_name(&self) -> &'static str { Self::unit_name() }
+	fn unit_symbol(&self) -> &'static str { Self::unit_symbol() }
+	fn raw(&self) -> &T { &self.per_sr }
+	fn dimension(&self) -> super::Dimension { Self::dimension() }
+	fn from_raw(value: T) -> Self { InverseSolidAngle{per_sr: value} }
+	fn into_raw(self) -> T { self.per_sr }
+}
+
+#[cfg(feature="alloc")]
+impl<T> InverseSolidAngle<T> where T: NumLike {
+	/// Returns this inverse solid angle value in the canonical text format "<value> <symbol>", eg "5 1/sr".
+	/// Unlike `Display`, this format never changes with precision or alternate flags, and it is the
+	/// input format expected by `from_str(...)`, so `x.canonical_string().parse()` returns the same value
+	pub fn canonical_string(&self) -> alloc::string::String {
+		alloc::format!("{} {}", self.per_sr, Self::unit_symbol())
+	}
+
+	/// Returns a `Vec` of inverse solid angle values copied from the given slice of numbers
+	///
+	/// # Arguments
+	/// * `values` - Slice of numbers, representing quantities of inverse steradian
+	pub fn from_per_sr_slice(values: &[T]) -> alloc::vec::Vec<Self> {
+		values.iter().map(|x| InverseSolidAngle{per_sr: x.clone()}).collect()
 	}
 }
-/// Multiplying a InverseArea by a InverseMagneticFluxDensity returns a value of type InverseMagneticFlux
-impl<T> core::ops::Mul<&InverseMagneticFluxDensity<T>> for InverseArea<T> where T: NumLike {
-	type Output = InverseMagneticFlux<T>;
-	fn mul(self, rhs: &InverseMagneticFluxDensity<T>) -> Self::Output {
-		InverseMagneticFlux{per_Wb: self.per_m2 * rhs.m2_per_Wb.clone()}
+
+/// Parses a inverse solid angle value from a number followed by its unit symbol, eg "5 1/sr"
+impl<T> core::str::FromStr for InverseSolidAngle<T> where T: NumLike+core::str::FromStr {
+	type Err = SiParseError;
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		Ok(InverseSolidAngle{per_sr: super::parse_unit_value(s, Self::unit_symbol())?})
 	}
 }
-/// Multiplying a InverseArea by a InverseMagneticFluxDensity returns a value of type InverseMagneticFlux
-impl<T> core::ops::Mul<&InverseMagneticFluxDensity<T>> for &InverseArea<T> where T: NumLike {
-	type Output = InverseMagneticFlux<T>;
-	fn mul(self, rhs: &InverseMagneticFluxDensity<T>) -> Self::Output {
-		InverseMagneticFlux{per_Wb: self.per_m2.clone() * rhs.m2_per_Wb.clone()}
+
+/// Parses a inverse solid angle value from a number followed by its unit symbol, eg "5 1/sr" 
+/// (same as `str::parse()`)
+impl<T> core::convert::TryFrom<&str> for InverseSolidAngle<T> where T: NumLike+core::str::FromStr {
+	type Error = SiParseError;
+	fn try_from(s: &str) -> Result<Self, Self::Error> {
+		<Self as core::str::FromStr>::from_str(s)
 	}
 }
 
-// InverseArea * LuminousFlux -> Illuminance
-/// Multiplying a InverseArea by a LuminousFlux returns a value of type Illuminance
-impl<T> core::ops::Mul<LuminousFlux<T>> for InverseArea<T> where T: NumLike {
-	type Output = Illuminance<T>;
-	fn mul(self, rhs: LuminousFlux<T>) -> Self::Output {
-		Illuminance{lux: self.per_m2 * rhs.lm}
+#[cfg(feature="num-traits")]
+impl<T> InverseSolidAngle<T> where T: NumLike+num_traits::Euclid {
+
+	/// Returns the Euclidean remainder of dividing this inverse solid angle value by another, 
+	/// which is never negative (requires the `num-traits` feature)
+	///
+	/// # Arguments
+	/// * `rhs` - The inverse solid angle value to divide by
+	pub fn rem_euclid(self, rhs: Self) -> Self {
+		InverseSolidAngle{per_sr: self.per_sr.rem_euclid(&rhs.per_sr)}
+	}
+
+	/// Returns the Euclidean quotient of dividing this inverse solid angle value by another, 
+	/// ie the whole number of times that `rhs` fits into this value, rounded such that 
+	/// `rem_euclid(...)` is never negative (requires the `num-traits` feature)
+	///
+	/// # Arguments
+	/// * `rhs` - The inverse solid angle value to divide by
+	pub fn div_euclid(self, rhs: Self) -> T {
+		self.per_sr.div_euclid(&rhs.per_sr)
 	}
 }
-/// Multiplying a InverseArea by a LuminousFlux returns a value of type Illuminance
-impl<T> core::ops::Mul<LuminousFlux<T>> for &InverseArea<T> where T: NumLike {
-	type Output = Illuminance<T>;
-	fn mul(self, rhs: LuminousFlux<T>) -> Self::Output {
-		Illuminance{lux: self.per_m2.clone() * rhs.lm}
+
+/// Summing inverse solid angle values returns their total as a inverse solid angle value (or zero if there are no values)
+impl<T> core::iter::Sum<InverseSolidAngle<T>> for InverseSolidAngle<T> where T: NumLike+core::iter::Sum {
+	fn sum<I: Iterator<Item=InverseSolidAngle<T>>>(iter: I) -> Self {
+		InverseSolidAngle{per_sr: iter.map(|x| x.per_sr).sum()}
 	}
 }
-/// Multiplying a InverseArea by a LuminousFlux returns a value of type Illuminance
-impl<T> core::ops::Mul<&LuminousFlux<T>> for InverseArea<T> where T: NumLike {
-	type Output = Illuminance<T>;
-	fn mul(self, rhs: &LuminousFlux<T>) -> Self::Output {
-		Illuminance{lux: self.per_m2 * rhs.lm.clone()}
+/// Summing inverse solid angle values returns their total as a inverse solid angle value (or zero if there are no values)
+impl<'a, T> core::iter::Sum<&'a InverseSolidAngle<T>> for InverseSolidAngle<T> where T: NumLike+core::iter::Sum+'a {
+	fn sum<I: Iterator<Item=&'a InverseSolidAngle<T>>>(iter: I) -> Self {
+		InverseSolidAngle{per_sr: iter.map(|x| x.per_sr.clone()).sum()}
 	}
 }
-/// Multiplying a InverseArea by a LuminousFlux returns a value of type Illuminance
-impl<T> core::ops::Mul<&LuminousFlux<T>> for &InverseArea<T> where T: NumLike {
-	type Output = Illuminance<T>;
-	fn mul(self, rhs: &LuminousFlux<T>) -> Self::Output {
-		Illuminance{lux: self.per_m2.clone() * rhs.lm.clone()}
+
+impl<T> InverseSolidAngle<T> where T: NumLike+From<f64> {
+
+	/// Returns the weighted average of the given inverse solid angle values, where each 
+	/// value is paired with its weight (weights do not need to add up to 1). If the 
+	/// total weight is zero (including when `values` is empty), then zero is returned
+	///
+	/// # Arguments
+	/// * `values` - Slice of (inverse solid angle value, weight) pairs
+	pub fn weighted_mean(values: &[(Self, f64)]) -> Self {
+		let total_weight: f64 = values.iter().map(|(_, weight)| *weight).sum();
+		if total_weight == 0.0 {
+			return InverseSolidAngle{per_sr: T::from(0.0)};
+		}
+		let total = values.iter().fold(T::from(0.0),
+			|total, (value, weight)| total + value.per_sr.clone() * T::from(*weight));
+		InverseSolidAngle{per_sr: total / T::from(total_weight)}
 	}
 }
 
-// InverseArea * MagneticFlux -> MagneticFluxDensity
-/// Multiplying a InverseArea by a MagneticFlux returns a value of type MagneticFluxDensity
-impl<T> core::ops::Mul<MagneticFlux<T>> for InverseArea<T> where T: NumLike {
-	type Output = MagneticFluxDensity<T>;
-	fn mul(self, rhs: MagneticFlux<T>) -> Self::Output {
-		MagneticFluxDensity{T: self.per_m2 * rhs.Wb}
+impl<T> InverseSolidAngle<T> where T: NumLike+PartialEq+From<u8> {
+
+	/// Divides this inverse solid angle value by another unit value (eg `distance.checked_div(time)` 
+	/// returns a velocity), returning `None` if the divisor is zero instead of producing 
+	/// infinity or NaN (or panicking, for integer number types). This works for every unit 
+	/// type that this inverse solid angle value can be divided by with the `/` operator
+	///
+	/// # Arguments
+	/// * `rhs` - The unit value to divide by
+	pub fn checked_div<R>(self, rhs: R) -> Option<<Self as core::ops::Div<R>>::Output> 
+		where Self: core::ops::Div<R>, R: super::SiUnit<Number=T>
+	{
+		if *rhs.raw() == T::from(0u8) {
+			return None;
+		}
+		Some(self / rhs)
 	}
 }
-/// Multiplying a InverseArea by a MagneticFlux returns a value of type MagneticFluxDensity
-impl<T> core::ops::Mul<MagneticFlux<T>> for &InverseArea<T> where T: NumLike {
-	type Output = MagneticFluxDensity<T>;
-	fn mul(self, rhs: MagneticFlux<T>) -> Self::Output {
-		MagneticFluxDensity{T: self.per_m2.clone() * rhs.Wb}
+
+/// Multiplying a inverse solid angle value by a dimensionless value returns a inverse solid angle value
+impl<T> core::ops::Mul<super::base::Dimensionless<T>> for InverseSolidAngle<T> where T: NumLike {
+	type Output = InverseSolidAngle<T>;
+	fn mul(self, rhs: super::base::Dimensionless<T>) -> Self::Output {
+		InverseSolidAngle{per_sr: self.per_sr * rhs.value}
 	}
 }
-/// Multiplying a InverseArea by a MagneticFlux returns a value of type MagneticFluxDensity
-impl<T> core::ops::Mul<&MagneticFlux<T>> for InverseArea<T> where T: NumLike {
-	type Output = MagneticFluxDensity<T>;
-	fn mul(self, rhs: &MagneticFlux<T>) -> Self::Output {
-		MagneticFluxDensity{T: self.per_m2 * rhs.Wb.clone()}
+/// Multiplying a dimensionless value by a inverse solid angle value returns a inverse solid angle value
+impl<T> core::ops::Mul<InverseSolidAngle<T>> for super::base::Dimensionless<T> where T: NumLike {
+	type Output = InverseSolidAngle<T>;
+	fn mul(self, rhs: InverseSolidAngle<T>) -> Self::Output {
+		InverseSolidAngle{per_sr: self.value * rhs.per_sr}
 	}
 }
-/// Multiplying a InverseArea by a MagneticFlux returns a value of type MagneticFluxDensity
-impl<T> core::ops::Mul<&MagneticFlux<T>> for &InverseArea<T> where T: NumLike {
-	type Output = MagneticFluxDensity<T>;
-	fn mul(self, rhs: &MagneticFlux<T>) -> Self::Output {
-		MagneticFluxDensity{T: self.per_m2.clone() * rhs.Wb.clone()}
+/// Dividing a inverse solid angle value by a dimensionless value returns a inverse solid angle value
+impl<T> core::ops::Div<super::base::Dimensionless<T>> for InverseSolidAngle<T> where T: NumLike {
+	type Output = InverseSolidAngle<T>;
+	fn div(self, rhs: super::base::Dimensionless<T>) -> Self::Output {
+		InverseSolidAngle{per_sr: self.per_sr / rhs.value}
 	}
 }
 
-// InverseArea / MagneticFluxDensity -> InverseMagneticFlux
-/// Dividing a InverseArea by a MagneticFluxDensity returns a value of type InverseMagneticFlux
-impl<T> core::ops::Div<MagneticFluxDensity<T>> for InverseArea<T> where T: NumLike {
-	type Output = InverseMagneticFlux<T>;
-	fn div(self, rhs: MagneticFluxDensity<T>) -> Self::Output {
-		InverseMagneticFlux{per_Wb: self.per_m2 / rhs.T}
+/// The remainder of dividing a inverse solid angle value by another inverse solid angle value is a inverse solid angle value
+impl<T> core::ops::Rem<InverseSolidAngle<T>> for InverseSolidAngle<T> where T: NumLike+core::ops::Rem<Output=T> {
+	type Output = InverseSolidAngle<T>;
+	fn rem(self, rhs: InverseSolidAngle<T>) -> Self::Output {
+		InverseSolidAngle{per_sr: self.per_sr % rhs.per_sr}
 	}
 }
-/// Dividing a InverseArea by a MagneticFluxDensity returns a value of type InverseMagneticFlux
-impl<T> core::ops::Div<MagneticFluxDensity<T>> for &InverseArea<T> where T: NumLike {
-	type Output = InverseMagneticFlux<T>;
-	fn div(self, rhs: MagneticFluxDensity<T>) -> Self::Output {
-		InverseMagneticFlux{per_Wb: self.per_m2.clone() / rhs.T}
+/// The remainder of dividing a inverse solid angle value by another inverse solid angle value is a inverse solid angle value
+impl<T> core::ops::Rem<&InverseSolidAngle<T>> for InverseSolidAngle<T> where T: NumLike+core::ops::Rem<Output=T> {
+	type Output = InverseSolidAngle<T>;
+	fn rem(self, rhs: &InverseSolidAngle<T>) -> Self::Output {
+		InverseSolidAngle{per_sr: self.per_sr % rhs.per_sr.clone()}
 	}
 }
-/// Dividing a InverseArea by a MagneticFluxDensity returns a value of type InverseMagneticFlux
-impl<T> core::ops::Div<&MagneticFluxDensity<T>> for InverseArea<T> where T: NumLike {
-	type Output = InverseMagneticFlux<T>;
-	fn div(self, rhs: &MagneticFluxDensity<T>) -> Self::Output {
-		InverseMagneticFlux{per_Wb: self.per_m2 / rhs.T.clone()}
+/// The remainder of dividing a inverse solid angle value by another inverse solid angle value is a inverse solid angle value
+impl<T> core::ops::Rem<InverseSolidAngle<T>> for &InverseSolidAngle<T> where T: NumLike+core::ops::Rem<Output=T> {
+	type Output = InverseSolidAngle<T>;
+	fn rem(self, rhs: InverseSolidAngle<T>) -> Self::Output {
+		InverseSolidAngle{per_sr: self.per_sr.clone() % rhs.per_sr}
 	}
 }
-/// Dividing a InverseArea by a MagneticFluxDensity returns a value of type InverseMagneticFlux
-impl<T> core::ops::Div<&MagneticFluxDensity<T>> for &InverseArea<T> where T: NumLike {
-	type Output = InverseMagneticFlux<T>;
-	fn div(self, rhs: &MagneticFluxDensity<T>) -> Self::Output {
-		InverseMagneticFlux{per_Wb: self.per_m2.clone() / rhs.T.clone()}
+/// The remainder of dividing a inverse solid angle value by another inverse solid angle value is a inverse solid angle value
+impl<T> core::ops::Rem<&InverseSolidAngle<T>> for &InverseSolidAngle<T> where T: NumLike+core::ops::Rem<Output=T> {
+	type Output = InverseSolidAngle<T>;
+	fn rem(self, rhs: &InverseSolidAngle<T>) -> Self::Output {
+		InverseSolidAngle{per_sr: self.per_sr.clone() % rhs.per_sr.clone()}
 	}
 }
 
-// InverseArea / InverseVolume -> Distance
-/// Dividing a InverseArea by a InverseVolume returns a value of type Distance
-impl<T> core::ops::Div<InverseVolume<T>> for InverseArea<T> where T: NumLike {
-	type Output = Distance<T>;
-	fn div(self, rhs: InverseVolume<T>) -> Self::Output {
-		Distance{m: self.per_m2 / rhs.per_m3}
+/// Serializes a inverse solid angle value together with its unit symbol, eg 
+/// `{"value": 1.5, "unit": "1/sr"}` (requires the `serde-unit-tag` feature)
+#[cfg(feature="serde-unit-tag")]
+impl<T> serde::Serialize for InverseSolidAngle<T> where T: NumLike+serde::Serialize {
+	fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+		super::serialize_with_unit_tag(&self.per_sr, Self::unit_symbol(), serializer)
 	}
 }
-/// Dividing a InverseArea by a InverseVolume returns a value of type Distance
-impl<T> core::ops::Div<InverseVolume<T>> for &InverseArea<T> where T: NumLike {
-	type Output = Distance<T>;
-	fn div(self, rhs: InverseVolume<T>) -> Self::Output {
-		Distance{m: self.per_m2.clone() / rhs.per_m3}
+/// Deserializes a inverse solid angle value from a value and unit symbol, eg 
+/// `{"value": 1.5, "unit": "1/sr"}`, returning an error if the unit symbol 
+/// does not match (requires the `serde-unit-tag` feature)
+#[cfg(feature="serde-unit-tag")]
+impl<'de, T> serde::Deserialize<'de> for InverseSolidAngle<T> where T: NumLike+serde::Deserialize<'de> {
+	fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+		Ok(InverseSolidAngle{per_sr: super::deserialize_with_unit_tag(Self::unit_symbol(), deserializer)?})
 	}
 }
-/// Dividing a InverseArea by a InverseVolume returns a value of type Distance
-impl<T> core::ops::Div<&InverseVolume<T>> for InverseArea<T> where T: NumLike {
-	type Output = Distance<T>;
-	fn div(self, rhs: &InverseVolume<T>) -> Self::Output {
-		Distance{m: self.per_m2 / rhs.per_m3.clone()}
-	}
+
+/// The additive identity of inverse solid angle values is zero inverse steradian (requires the `num-traits` feature)
+#[cfg(feature="num-traits")]
+impl<T> num_traits::Zero for InverseSolidAngle<T> where T: NumLike+num_traits::Zero {
+	fn zero() -> Self { InverseSolidAngle{per_sr: T::zero()} }
+	fn is_zero(&self) -> bool { self.per_sr.is_zero() }
 }
-/// Dividing a InverseArea by a InverseVolume returns a value of type Distance
-impl<T> core::ops::Div<&InverseVolume<T>> for &InverseArea<T> where T: NumLike {
-	type Output = Distance<T>;
-	fn div(self, rhs: &InverseVolume<T>) -> Self::Output {
-		Distance{m: self.per_m2.clone() / rhs.per_m3.clone()}
+
+#[cfg(feature="num-traits")]
+impl<T> InverseSolidAngle<T> where T: NumLike+num_traits::Signed {
+
+	/// Returns the sign of this inverse solid angle value as a dimensionless number: 1 if it is 
+	/// positive, -1 if it is negative, and 0 if it is zero for integer number types 
+	/// (for floating-point number types, +0.0 returns 1.0, -0.0 returns -1.0, and NaN returns 
+	/// NaN) (requires the `num-traits` feature)
+	pub fn signum(self) -> T {
+		self.per_sr.signum()
 	}
 }
 
-// InverseArea * Volume -> Distance
-/// Multiplying a InverseArea by a Volume returns a value of type Distance
-impl<T> core::ops::Mul<Volume<T>> for InverseArea<T> where T: NumLike {
-	type Output = Distance<T>;
-	fn mul(self, rhs: Volume<T>) -> Self::Output {
-		Distance{m: self.per_m2 * rhs.m3}
+impl<T> InverseSolidAngle<T> where T: NumLike+PartialOrd {
+
+	/// Returns the absolute value of this inverse solid angle value, ie its magnitude without a minus sign
+	/// (for floating-point number types, -0.0 returns +0.0)
+	pub fn abs(self) -> Self {
+		let negated = -self.per_sr.clone();
+		if self.per_sr < negated {
+			InverseSolidAngle{per_sr: negated}
+		} else if self.per_sr == negated {
+			// zero, which is its own negation (adding +0.0 turns -0.0 into +0.0)
+			InverseSolidAngle{per_sr: negated + self.per_sr}
+		} else {
+			self
+		}
+	}
+
+	/// Returns the lesser of this inverse solid angle value and the given inverse solid angle value
+	///
+	/// # Arguments
+	/// * `other` - The inverse solid angle value to compare with this one
+	pub fn min(self, other: Self) -> Self {
+		if other.per_sr < self.per_sr { other } else { self }
+	}
+
+	/// Returns the greater of this inverse solid angle value and the given inverse solid angle value
+	///
+	/// # Arguments
+	/// * `other` - The inverse solid angle value to compare with this one
+	pub fn max(self, other: Self) -> Self {
+		if other.per_sr > self.per_sr { other } else { self }
+	}
+
+	/// Returns the least of the given inverse solid angle values, or `None` if there are none. 
+	/// Unlike `Iterator::min()`, this does not require `Ord`, so it works for 
+	/// floating-point numbers. NaN values are skipped (so the result is `None` if all 
+	/// values are NaN), and the first of several equal values is returned
+	///
+	/// # Arguments
+	/// * `values` - Any iterator or collection of inverse solid angle values
+	pub fn min_of<I>(values: I) -> Option<Self> where I: IntoIterator<Item=Self> {
+		values.into_iter().filter(|x| x.per_sr.partial_cmp(&x.per_sr).is_some()).reduce(Self::min)
+	}
+
+	/// Returns the greatest of the given inverse solid angle values, or `None` if there are none. 
+	/// Unlike `Iterator::max()`, this does not require `Ord`, so it works for 
+	/// floating-point numbers. NaN values are skipped (so the result is `None` if all 
+	/// values are NaN), and the first of several equal values is returned
+	///
+	/// # Arguments
+	/// * `values` - Any iterator or collection of inverse solid angle values
+	pub fn max_of<I>(values: I) -> Option<Self> where I: IntoIterator<Item=Self> {
+		values.into_iter().filter(|x| x.per_sr.partial_cmp(&x.per_sr).is_some()).reduce(Self::max)
+	}
+
+	/// Returns this inverse solid angle value restricted to the range from `lo` to `hi`
+	///
+	/// # Arguments
+	/// * `lo` - The minimum inverse solid angle value
+	/// * `hi` - The maximum inverse solid angle value
+	///
+	/// # Panics
+	/// Panics if `lo` is greater than `hi` (or if either of them is NaN)
+	pub fn clamp(self, lo: Self, hi: Self) -> Self {
+		assert!(lo.per_sr <= hi.per_sr, "lo must be less than or equal to hi");
+		if self.per_sr < lo.per_sr { lo } else if self.per_sr > hi.per_sr { hi } else { self }
+	}
+
+	/// Returns this inverse solid angle value with its magnitude capped at `max`, keeping its 
+	/// sign (eg for capping a speed), or this value unchanged if its magnitude does not 
+	/// exceed `max`. For complex-valued inverse solid angle, use `clamp_norm(...)` instead, 
+	/// which caps the magnitude while keeping the phase
+	///
+	/// # Arguments
+	/// * `max` - The maximum magnitude (should not be negative)
+	pub fn clamp_magnitude(self, max: Self) -> Self {
+		let negated_max = -max.per_sr.clone();
+		if self.per_sr > max.per_sr { 
+			max 
+		} else if self.per_sr < negated_max { 
+			InverseSolidAngle{per_sr: negated_max} 
+		} else { 
+			self 
+		}
+	}
+
+	/// Returns true if this inverse solid angle value differs from the given inverse solid angle 
+	/// value by no more than the given absolute tolerance (which is also a 
+	/// inverse solid angle value, so the comparison is dimensionally safe). NaN is never 
+	/// equal to anything. See also the `assert_unit_eq!(...)` macro.
+	///
+	/// # Arguments
+	/// * `other` - The inverse solid angle value to compare with this one
+	/// * `tolerance` - The largest allowed difference between the two values
+	pub fn eq_within(self, other: Self, tolerance: Self) -> bool {
+		let diff = self.per_sr - other.per_sr;
+		diff <= tolerance.per_sr && -diff <= tolerance.per_sr
 	}
 }
-/// Multiplying a InverseArea by a Volume returns a value of type Distance
-impl<T> core::ops::Mul<Volume<T>> for &InverseArea<T> where T: NumLike {
-	type Output = Distance<T>;
-	fn mul(self, rhs: Volume<T>) -> Self::Output {
-		Distance{m: self.per_m2.clone() * rhs.m3}
+
+#[cfg(feature="num-complex")]
+impl InverseSolidAngle<num_complex::Complex32> {
+	/// Returns the magnitude (aka norm or absolute value) of this complex inverse solid angle 
+	/// value as a real-valued inverse solid angle (requires the `num-complex` feature)
+	pub fn magnitude(self) -> InverseSolidAngle<f32> {
+		InverseSolidAngle{per_sr: self.per_sr.norm()}
+	}
+
+	/// Returns this complex inverse solid angle value scaled down so that its magnitude is 
+	/// at most `max` (keeping its phase), or this value unchanged if its magnitude does 
+	/// not exceed `max`. This is the complex equivalent of `clamp_magnitude(...)` (requires 
+	/// the `num-complex` feature)
+	///
+	/// # Arguments
+	/// * `max` - The maximum magnitude (should not be negative)
+	pub fn clamp_norm(self, max: InverseSolidAngle<f32>) -> Self {
+		let norm = self.per_sr.norm();
+		if norm > max.per_sr {
+			InverseSolidAngle{per_sr: self.per_sr * (max.per_sr / norm)}
+		} else {
+			self
+		}
 	}
 }
-/// Multiplying a InverseArea by a Volume returns a value of type Distance
-impl<T> core::ops::Mul<&Volume<T>> for InverseArea<T> where T: NumLike {
-	type Output = Distance<T>;
-	fn mul(self, rhs: &Volume<T>) -> Self::Output {
-		Distance{m: self.per_m2 * rhs.m3.clone()}
+
+#[cfg(feature="num-complex")]
+impl InverseSolidAngle<num_complex::Complex64> {
+	/// Returns the magnitude (aka norm or absolute value) of this complex inverse solid angle 
+	/// value as a real-valued inverse solid angle (requires the `num-complex` feature)
+	pub fn magnitude(self) -> InverseSolidAngle<f64> {
+		InverseSolidAngle{per_sr: self.per_sr.norm()}
+	}
+
+	/// Returns this complex inverse solid angle value scaled down so that its magnitude is 
+	/// at most `max` (keeping its phase), or this value unchanged if its magnitude does 
+	/// not exceed `max`. This is the complex equivalent of `clamp_magnitude(...)` (requires 
+	/// the `num-complex` feature)
+	///
+	/// # Arguments
+	/// * `max` - The maximum magnitude (should not be negative)
+	pub fn clamp_norm(self, max: InverseSolidAngle<f64>) -> Self {
+		let norm = self.per_sr.norm();
+		if norm > max.per_sr {
+			InverseSolidAngle{per_sr: self.per_sr * (max.per_sr / norm)}
+		} else {
+			self
+		}
 	}
 }
-/// Multiplying a InverseArea by a Volume returns a value of type Distance
-impl<T> core::ops::Mul<&Volume<T>> for &InverseArea<T> where T: NumLike {
-	type Output = Distance<T>;
-	fn mul(self, rhs: &Volume<T>) -> Self::Output {
-		Distance{m: self.per_m2.clone() * rhs.m3.clone()}
+
+/// Rounding and other floating-point functions (requires the `num-traits` feature, 
+/// and either the `std` or `libm` feature)
+#[cfg(all(feature="num-traits", any(feature="std", feature="libm")))]
+impl<T> InverseSolidAngle<T> where T: NumLike+num_traits::Float {
+
+	/// Returns this inverse solid angle value rounded to the nearest whole number of inverse steradian 
+	/// (half-way cases are rounded away from zero)
+	pub fn round(self) -> Self {
+		InverseSolidAngle{per_sr: self.per_sr.round()}
+	}
+
+	/// Returns this inverse solid angle value rounded down to a whole number of inverse steradian
+	pub fn floor(self) -> Self {
+		InverseSolidAngle{per_sr: self.per_sr.floor()}
+	}
+
+	/// Returns this inverse solid angle value rounded up to a whole number of inverse steradian
+	pub fn ceil(self) -> Self {
+		InverseSolidAngle{per_sr: self.per_sr.ceil()}
+	}
+
+	/// Returns this inverse solid angle value rounded towards zero to a whole number of inverse steradian
+	pub fn trunc(self) -> Self {
+		InverseSolidAngle{per_sr: self.per_sr.trunc()}
+	}
+
+	/// Returns this inverse solid angle value rounded to the nearest multiple of the given step 
+	/// (half-way cases are rounded away from zero)
+	///
+	/// # Arguments
+	/// * `step` - The inverse solid angle value to round to a multiple of
+	pub fn round_to(self, step: Self) -> Self {
+		InverseSolidAngle{per_sr: (self.per_sr / step.per_sr).round() * step.per_sr}
+	}
+
+	/// Fused multiply-add: returns `self * a + b` computed with only one rounding 
+	/// error, which is more accurate (and on some hardware faster) than a separate 
+	/// multiplication and addition
+	///
+	/// # Arguments
+	/// * `a` - The scalar value to multiply this inverse solid angle value by
+	/// * `b` - The inverse solid angle value to add to the product
+	pub fn mul_add(self, a: T, b: Self) -> Self {
+		InverseSolidAngle{per_sr: self.per_sr.mul_add(a, b.per_sr)}
+	}
+
+	/// Returns true if this inverse solid angle value is neither infinite nor NaN
+	pub fn is_finite(&self) -> bool {
+		self.per_sr.is_finite()
+	}
+
+	/// Returns true if this inverse solid angle value is positive or negative infinity
+	pub fn is_infinite(&self) -> bool {
+		self.per_sr.is_infinite()
+	}
+
+	/// Returns true if this inverse solid angle value is NaN (not a number)
+	pub fn is_nan(&self) -> bool {
+		self.per_sr.is_nan()
+	}
+
+	/// Returns true if this inverse solid angle value has a positive sign, including +0.0 
+	/// and positive infinity
+	pub fn is_sign_positive(&self) -> bool {
+		self.per_sr.is_sign_positive()
+	}
+
+	/// Returns true if this inverse solid angle value has a negative sign, including -0.0 
+	/// and negative infinity
+	pub fn is_sign_negative(&self) -> bool {
+		self.per_sr.is_sign_negative()
+	}
+
+	/// Returns a inverse solid angle value with the magnitude of this one and the sign of 
+	/// `sign_source`, eg for reflecting a value off of a surface
+	///
+	/// # Arguments
+	/// * `sign_source` - The inverse solid angle value whose sign is copied
+	pub fn copysign(self, sign_source: Self) -> Self {
+		InverseSolidAngle{per_sr: self.per_sr.copysign(sign_source.per_sr)}
 	}
 }
 
-// InverseArea / AreaDensity -> InverseMass
-/// Dividing a InverseArea by a AreaDensity returns a value of type InverseMass
-impl<T> core::ops::Div<AreaDensity<T>> for InverseArea<T> where T: NumLike {
-	type Output = InverseMass<T>;
-	fn div(self, rhs: AreaDensity<T>) -> Self::Output {
-		InverseMass{per_kg: self.per_m2 / rhs.kgpm2}
+#[cfg(feature="num-traits")]
+impl<T> InverseSolidAngle<T> where T: NumLike+num_traits::CheckedAdd+num_traits::CheckedSub {
+
+	/// Adds another inverse solid angle value to this one, returning `None` if the 
+	/// addition overflows the number type (requires the `num-traits` feature)
+	pub fn checked_add(self, rhs: Self) -> Option<Self> {
+		Some(InverseSolidAngle{per_sr: self.per_sr.checked_add(&rhs.per_sr)?})
+	}
+
+	/// Subtracts another inverse solid angle value from this one, returning `None` if 
+	/// the subtraction overflows the number type (requires the `num-traits` feature)
+	pub fn checked_sub(self, rhs: Self) -> Option<Self> {
+		Some(InverseSolidAngle{per_sr: self.per_sr.checked_sub(&rhs.per_sr)?})
 	}
 }
-/// Dividing a InverseArea by a AreaDensity returns a value of type InverseMass
-impl<T> core::ops::Div<AreaDensity<T>> for &InverseArea<T> where T: NumLike {
-	type Output = InverseMass<T>;
-	fn div(self, rhs: AreaDensity<T>) -> Self::Output {
-		InverseMass{per_kg: self.per_m2.clone() / rhs.kgpm2}
+
+#[cfg(feature="num-traits")]
+impl<T> InverseSolidAngle<T> where T: NumLike+num_traits::SaturatingAdd+num_traits::SaturatingSub {
+
+	/// Adds another inverse solid angle value to this one, clamping the result to the 
+	/// minimum or maximum value of the number type instead of overflowing 
+	/// (requires the `num-traits` feature)
+	pub fn saturating_add(self, rhs: Self) -> Self {
+		InverseSolidAngle{per_sr: self.per_sr.saturating_add(&rhs.per_sr)}
+	}
+
+	/// Subtracts another inverse solid angle value from this one, clamping the result 
+	/// to the minimum or maximum value of the number type instead of overflowing 
+	/// (requires the `num-traits` feature)
+	pub fn saturating_sub(self, rhs: Self) -> Self {
+		InverseSolidAngle{per_sr: self.per_sr.saturating_sub(&rhs.per_sr)}
 	}
 }
-/// Dividing a InverseArea by a AreaDensity returns a value of type InverseMass
-impl<T> core::ops::Div<&AreaDensity<T>> for InverseArea<T> where T: NumLike {
-	type Output = InverseMass<T>;
-	fn div(self, rhs: &AreaDensity<T>) -> Self::Output {
-		InverseMass{per_kg: self.per_m2 / rhs.kgpm2.clone()}
+
+impl<T> InverseSolidAngle<T> where T: NumLike+Into<f64> {
+
+	/// Returns true if this inverse solid angle value is equal to the given inverse solid angle 
+	/// value to within the given number of significant figures (eg 6 significant 
+	/// figures allows a relative difference of up to 1 part per million). NaN is 
+	/// only approximately equal to NaN, infinity is only approximately equal to 
+	/// infinity of the same sign, and zero is only approximately equal to zero.
+	///
+	/// # Arguments
+	/// * `other` - The inverse solid angle value to compare with this one
+	/// * `sig_figs` - The number of significant figures that must match
+	pub fn approx_eq(self, other: Self, sig_figs: i32) -> bool {
+		super::approx_eq_f64(self.per_sr.into(), other.per_sr.into(), sig_figs)
 	}
 }
-/// Dividing a InverseArea by a AreaDensity returns a value of type InverseMass
-impl<T> core::ops::Div<&AreaDensity<T>> for &InverseArea<T> where T: NumLike {
-	type Output = InverseMass<T>;
-	fn div(self, rhs: &AreaDensity<T>) -> Self::Output {
-		InverseMass{per_kg: self.per_m2.clone() / rhs.kgpm2.clone()}
+
+impl<T> InverseSolidAngle<T> where T: NumLike+From<f64> {
+
+	/// Returns the linear interpolation between this inverse solid angle value and the given 
+	/// inverse solid angle value, ie `self*(1-t) + other*t`. The interpolation factor is not 
+	/// clamped, so values of `t` outside the range 0 to 1 extrapolate beyond the two 
+	/// values (see `lerp_clamped(...)`).
+	///
+	/// # Arguments
+	/// * `other` - The inverse solid angle value to interpolate towards (returned when `t` is 1)
+	/// * `t` - The interpolation factor, where 0 returns this value and 1 returns `other`
+	pub fn lerp(self, other: Self, t: f64) -> Self {
+		InverseSolidAngle{per_sr: self.per_sr * T::from(1.0 - t) + other.per_sr * T::from(t)}
+	}
+
+	/// Same as `lerp(...)`, except that the interpolation factor `t` is first clamped 
+	/// to the range 0 to 1, so that the result is always between the two values
+	///
+	/// # Arguments
+	/// * `other` - The inverse solid angle value to interpolate towards (returned when `t` is 1 or more)
+	/// * `t` - The interpolation factor, where 0 returns this value and 1 returns `other`
+	pub fn lerp_clamped(self, other: Self, t: f64) -> Self {
+		self.lerp(other, t.clamp(0.0, 1.0))
 	}
+	
 }
 
-// InverseArea * AreaPerMass -> InverseMass
-/// Multiplying a InverseArea by a AreaPerMass returns a value of type InverseMass
-impl<T> core::ops::Mul<AreaPerMass<T>> for InverseArea<T> where T: NumLike {
-	type Output = InverseMass<T>;
-	fn mul(self, rhs: AreaPerMass<T>) -> Self::Output {
-		InverseMass{per_kg: self.per_m2 * rhs.m2_per_kg}
+
+/// Multiplying a unit value by a scalar value returns a unit value
+#[cfg(feature="num-bigfloat")]
+impl core::ops::Mul<InverseSolidAngle<num_bigfloat::BigFloat>> for num_bigfloat::BigFloat {
+	type Output = InverseSolidAngle<num_bigfloat::BigFloat>;
+	fn mul(self, rhs: InverseSolidAngle<num_bigfloat::BigFloat>) -> Self::Output {
+		InverseSolidAngle{per_sr: self * rhs.per_sr}
 	}
 }
-/// Multiplying a InverseArea by a AreaPerMass returns a value of type InverseMass
-impl<T> core::ops::Mul<AreaPerMass<T>> for &InverseArea<T> where T: NumLike {
-	type Output = InverseMass<T>;
-	fn mul(self, rhs: AreaPerMass<T>) -> Self::Output {
-		InverseMass{per_kg: self.per_m2.clone() * rhs.m2_per_kg}
+/// Multiplying a unit value by a scalar value returns a unit value
+#[cfg(feature="num-bigfloat")]
+impl core::ops::Mul<InverseSolidAngle<num_bigfloat::BigFloat>> for &num_bigfloat::BigFloat {
+	type Output = InverseSolidAngle<num_bigfloat::BigFloat>;
+	fn mul(self, rhs: InverseSolidAngle<num_bigfloat::BigFloat>) -> Self::Output {
+		InverseSolidAngle{per_sr: self.clone() * rhs.per_sr}
 	}
 }
-/// Multiplying a InverseArea by a AreaPerMass returns a value of type InverseMass
-impl<T> core::ops::Mul<&AreaPerMass<T>> for InverseArea<T> where T: NumLike {
-	type Output = InverseMass<T>;
-	fn mul(self, rhs: &AreaPerMass<T>) -> Self::Output {
-		InverseMass{per_kg: self.per_m2 * rhs.m2_per_kg.clone()}
+/// Multiplying a unit value by a scalar value returns a unit value
+#[cfg(feature="num-bigfloat")]
+impl core::ops::Mul<&InverseSolidAngle<num_bigfloat::BigFloat>> for num_bigfloat::BigFloat {
+	type Output = InverseSolidAngle<num_bigfloat::BigFloat>;
+	fn mul(self, rhs: &InverseSolidAngle<num_bigfloat::BigFloat>) -> Self::Output {
+		InverseSolidAngle{per_sr: self * rhs.per_sr.clone()}
 	}
 }
-/// Multiplying a InverseArea by a AreaPerMass returns a value of type InverseMass
-impl<T> core::ops::Mul<&AreaPerMass<T>> for &InverseArea<T> where T: NumLike {
-	type Output = InverseMass<T>;
-	fn mul(self, rhs: &AreaPerMass<T>) -> Self::Output {
-		InverseMass{per_kg: self.per_m2.clone() * rhs.m2_per_kg.clone()}
+/// Multiplying a unit value by a scalar value returns a unit value
+#[cfg(feature="num-bigfloat")]
+impl core::ops::Mul<&InverseSolidAngle<num_bigfloat::BigFloat>> for &num_bigfloat::BigFloat {
+	type Output = InverseSolidAngle<num_bigfloat::BigFloat>;
+	fn mul(self, rhs: &InverseSolidAngle<num_bigfloat::BigFloat>) -> Self::Output {
+		InverseSolidAngle{per_sr: self.clone() * rhs.per_sr.clone()}
 	}
 }
 
-// InverseArea * Force -> Pressure
-/// Multiplying a InverseArea by a Force returns a value of type Pressure
-impl<T> core::ops::Mul<Force<T>> for InverseArea<T> where T: NumLike {
-	type Output = Pressure<T>;
-	fn mul(self, rhs: Force<T>) -> Self::Output {
-		Pressure{Pa: self.per_m2 * rhs.N}
+/// Multiplying a unit value by a scalar value returns a unit value
+#[cfg(feature="num-complex")]
+impl core::ops::Mul<InverseSolidAngle<num_complex::Complex32>> for num_complex::Complex32 {
+	type Output = InverseSolidAngle<num_complex::Complex32>;
+	fn mul(self, rhs: InverseSolidAngle<num_complex::Complex32>) -> Self::Output {
+		InverseSolidAngle{per_sr: self * rhs.per_sr}
 	}
 }
-/// Multiplying a InverseArea by a Force returns a value of type Pressure
-impl<T> core::ops::Mul<Force<T>> for &InverseArea<T> where T: NumLike {
-	type Output = Pressure<T>;
-	fn mul(self, rhs: Force<T>) -> Self::Output {
-		Pressure{Pa: self.per_m2.clone() * rhs.N}
+/// Multiplying a unit value by a scalar value returns a unit value
+#[cfg(feature="num-complex")]
+impl core::ops::Mul<InverseSolidAngle<num_complex::Complex32>> for &num_complex::Complex32 {
+	type Output = InverseSolidAngle<num_complex::Complex32>;
+	fn mul(self, rhs: InverseSolidAngle<num_complex::Complex32>) -> Self::Output {
+		InverseSolidAngle{per_sr: self.clone() * rhs.per_sr}
 	}
 }
-/// Multiplying a InverseArea by a Force returns a value of type Pressure
-impl<T> core::ops::Mul<&Force<T>> for InverseArea<T> where T: NumLike {
-	type Output = Pressure<T>;
-	fn mul(self, rhs: &Force<T>) -> Self::Output {
-		Pressure{Pa: self.per_m2 * rhs.N.clone()}
+/// Multiplying a unit value by a scalar value returns a unit value
+#[cfg(feature="num-complex")]
+impl core::ops::Mul<&InverseSolidAngle<num_complex::Complex32>> for num_complex::Complex32 {
+	type Output = InverseSolidAngle<num_complex::Complex32>;
+	fn mul(self, rhs: &InverseSolidAngle<num_complex::Complex32>) -> Self::Output {
+		InverseSolidAngle{per_sr: self * rhs.per_sr.clone()}
 	}
 }
-/// Multiplying a InverseArea by a Force returns a value of type Pressure
-impl<T> core::ops::Mul<&Force<T>> for &InverseArea<T> where T: NumLike {
-	type Output = Pressure<T>;
-	fn mul(self, rhs: &Force<T>) -> Self::Output {
-		Pressure{Pa: self.per_m2.clone() * rhs.N.clone()}
+/// Multiplying a unit value by a scalar value returns a unit value
+#[cfg(feature="num-complex")]
+impl core::ops::Mul<&InverseSolidAngle<num_complex::Complex32>> for &num_complex::Complex32 {
+	type Output = InverseSolidAngle<num_complex::Complex32>;
+	fn mul(self, rhs: &InverseSolidAngle<num_complex::Complex32>) -> Self::Output {
+		InverseSolidAngle{per_sr: self.clone() * rhs.per_sr.clone()}
 	}
 }
 
-// InverseArea / InverseForce -> Pressure
-/// Dividing a InverseArea by a InverseForce returns a value of type Pressure
-impl<T> core::ops::Div<InverseForce<T>> for InverseArea<T> where T: NumLike {
-	type Output = Pressure<T>;
-	fn div(self, rhs: InverseForce<T>) -> Self::Output {
-		Pressure{Pa: self.per_m2 / rhs.per_N}
+/// Multiplying a unit value by a scalar value returns a unit value
+#[cfg(feature="num-complex")]
+impl core::ops::Mul<InverseSolidAngle<num_complex::Complex64>> for num_complex::Complex64 {
+	type Output = InverseSolidAngle<num_complex::Complex64>;
+	fn mul(self, rhs: InverseSolidAngle<num_complex::Complex64>) -> Self::Output {
+		InverseSolidAngle{per_sr: self * rhs.per_sr}
 	}
 }
-/// Dividing a InverseArea by a InverseForce returns a value of type Pressure
-impl<T> core::ops::Div<InverseForce<T>> for &InverseArea<T> where T: NumLike {
-	type Output = Pressure<T>;
-	fn div(self, rhs: InverseForce<T>) -> Self::Output {
-		Pressure{Pa: self.per_m2.clone() / rhs.per_N}
+/// Multiplying a unit value by a scalar value returns a unit value
+#[cfg(feature="num-complex")]
+impl core::ops::Mul<InverseSolidAngle<num_complex::Complex64>> for &num_complex::Complex64 {
+	type Output = InverseSolidAngle<num_complex::Complex64>;
+	fn mul(self, rhs: InverseSolidAngle<num_complex::Complex64>) -> Self::Output {
+		InverseSolidAngle{per_sr: self.clone() * rhs.per_sr}
 	}
 }
-/// Dividing a InverseArea by a InverseForce returns a value of type Pressure
-impl<T> core::ops::Div<&InverseForce<T>> for InverseArea<T> where T: NumLike {
-	type Output = Pressure<T>;
-	fn div(self, rhs: &InverseForce<T>) -> Self::Output {
-		Pressure{Pa: self.per_m2 / rhs.per_N.clone()}
+/// Multiplying a unit value by a scalar value returns a unit value
+#[cfg(feature="num-complex")]
+impl core::ops::Mul<&InverseSolidAngle<num_complex::Complex64>> for num_complex::Complex64 {
+	type Output = InverseSolidAngle<num_complex::Complex64>;
+	fn mul(self, rhs: &InverseSolidAngle<num_complex::Complex64>) -> Self::Output {
+		InverseSolidAngle{per_sr: self * rhs.per_sr.clone()}
 	}
 }
-/// Dividing a InverseArea by a InverseForce returns a value of type Pressure
-impl<T> core::ops::Div<&InverseForce<T>> for &InverseArea<T> where T: NumLike {
-	type Output = Pressure<T>;
-	fn div(self, rhs: &InverseForce<T>) -> Self::Output {
-		Pressure{Pa: self.per_m2.clone() / rhs.per_N.clone()}
+/// Multiplying a unit value by a scalar value returns a unit value
+#[cfg(feature="num-complex")]
+impl core::ops::Mul<&InverseSolidAngle<num_complex::Complex64>> for &num_complex::Complex64 {
+	type Output = InverseSolidAngle<num_complex::Complex64>;
+	fn mul(self, rhs: &InverseSolidAngle<num_complex::Complex64>) -> Self::Output {
+		InverseSolidAngle{per_sr: self.clone() * rhs.per_sr.clone()}
 	}
 }
 
-// InverseArea / InverseMomentOfInertia -> Mass
-/// Dividing a InverseArea by a InverseMomentOfInertia returns a value of type Mass
-impl<T> core::ops::Div<InverseMomentOfInertia<T>> for InverseArea<T> where T: NumLike {
-	type Output = Mass<T>;
-	fn div(self, rhs: InverseMomentOfInertia<T>) -> Self::Output {
-		Mass{kg: self.per_m2 / rhs.per_kgm2}
+/// Multiplying a unit value by a scalar value returns a unit value
+#[cfg(feature="num-rational")]
+impl core::ops::Mul<InverseSolidAngle<num_rational::Rational64>> for num_rational::Rational64 {
+	type Output = InverseSolidAngle<num_rational::Rational64>;
+	fn mul(self, rhs: InverseSolidAngle<num_rational::Rational64>) -> Self::Output {
+		InverseSolidAngle{per_sr: self * rhs.per_sr}
 	}
 }
-/// Dividing a InverseArea by a InverseMomentOfInertia returns a value of type Mass
-impl<T> core::ops::Div<InverseMomentOfInertia<T>> for &InverseArea<T> where T: NumLike {
-	type Output = Mass<T>;
-	fn div(self, rhs: InverseMomentOfInertia<T>) -> Self::Output {
-		Mass{kg: self.per_m2.clone() / rhs.per_kgm2}
+/// Multiplying a unit value by a scalar value returns a unit value
+#[cfg(feature="num-rational")]
+impl core::ops::Mul<InverseSolidAngle<num_rational::Rational64>> for &num_rational::Rational64 {
+	type Output = InverseSolidAngle<num_rational::Rational64>;
+	fn mul(self, rhs: InverseSolidAngle<num_rational::Rational64>) -> Self::Output {
+		InverseSolidAngle{per_sr: self.clone() * rhs.per_sr}
 	}
 }
-/// Dividing a InverseArea by a InverseMomentOfInertia returns a value of type Mass
-impl<T> core::ops::Div<&InverseMomentOfInertia<T>> for InverseArea<T> where T: NumLike {
-	type Output = Mass<T>;
-	fn div(self, rhs: &InverseMomentOfInertia<T>) -> Self::Output {
-		Mass{kg: self.per_m2 / rhs.per_kgm2.clone()}
+/// Multiplying a unit value by a scalar value returns a unit value
+#[cfg(feature="num-rational")]
+impl core::ops::Mul<&InverseSolidAngle<num_rational::Rational64>> for num_rational::Rational64 {
+	type Output = InverseSolidAngle<num_rational::Rational64>;
+	fn mul(self, rhs: &InverseSolidAngle<num_rational::Rational64>) -> Self::Output {
+		InverseSolidAngle{per_sr: self * rhs.per_sr.clone()}
 	}
 }
-/// Dividing a InverseArea by a InverseMomentOfInertia returns a value of type Mass
-impl<T> core::ops::Div<&InverseMomentOfInertia<T>> for &InverseArea<T> where T: NumLike {
-	type Output = Mass<T>;
-	fn div(self, rhs: &InverseMomentOfInertia<T>) -> Self::Output {
-		Mass{kg: self.per_m2.clone() / rhs.per_kgm2.clone()}
+/// Multiplying a unit value by a scalar value returns a unit value
+#[cfg(feature="num-rational")]
+impl core::ops::Mul<&InverseSolidAngle<num_rational::Rational64>> for &num_rational::Rational64 {
+	type Output = InverseSolidAngle<num_rational::Rational64>;
+	fn mul(self, rhs: &InverseSolidAngle<num_rational::Rational64>) -> Self::Output {
+		InverseSolidAngle{per_sr: self.clone() * rhs.per_sr.clone()}
 	}
 }
 
-// InverseArea * InversePressure -> InverseForce
-/// Multiplying a InverseArea by a InversePressure returns a value of type InverseForce
-impl<T> core::ops::Mul<InversePressure<T>> for InverseArea<T> where T: NumLike {
-	type Output = InverseForce<T>;
-	fn mul(self, rhs: InversePressure<T>) -> Self::Output {
-		InverseForce{per_N: self.per_m2 * rhs.per_Pa}
+/// Multiplying a unit value by a scalar value returns a unit value
+#[cfg(feature="num-rational")]
+impl core::ops::Mul<InverseSolidAngle<num_rational::BigRational>> for num_rational::BigRational {
+	type Output = InverseSolidAngle<num_rational::BigRational>;
+	fn mul(self, rhs: InverseSolidAngle<num_rational::BigRational>) -> Self::Output {
+		InverseSolidAngle{per_sr: self * rhs.per_sr}
 	}
 }
-/// Multiplying a InverseArea by a InversePressure returns a value of type InverseForce
-impl<T> core::ops::Mul<InversePressure<T>> for &InverseArea<T> where T: NumLike {
-	type Output = InverseForce<T>;
-	fn mul(self, rhs: InversePressure<T>) -> Self::Output {
-		InverseForce{per_N: self.per_m2.clone() * rhs.per_Pa}
+/// Multiplying a unit value by a scalar value returns a unit value
+#[cfg(feature="num-rational")]
+impl core::ops::Mul<InverseSolidAngle<num_rational::BigRational>> for &num_rational::BigRational {
+	type Output = InverseSolidAngle<num_rational::BigRational>;
+	fn mul(self, rhs: InverseSolidAngle<num_rational::BigRational>) -> Self::Output {
+		InverseSolidAngle{per_sr: self.clone() * rhs.per_sr}
 	}
 }
-/// Multiplying a InverseArea by a InversePressure returns a value of type InverseForce
-impl<T> core::ops::Mul<&InversePressure<T>> for InverseArea<T> where T: NumLike {
-	type Output = InverseForce<T>;
-	fn mul(self, rhs: &InversePressure<T>) -> Self::Output {
-		InverseForce{per_N: self.per_m2 * rhs.per_Pa.clone()}
+/// Multiplying a unit value by a scalar value returns a unit value
+#[cfg(feature="num-rational")]
+impl core::ops::Mul<&InverseSolidAngle<num_rational::BigRational>> for num_rational::BigRational {
+	type Output = InverseSolidAngle<num_rational::BigRational>;
+	fn mul(self, rhs: &InverseSolidAngle<num_rational::BigRational>) -> Self::Output {
+		InverseSolidAngle{per_sr: self * rhs.per_sr.clone()}
 	}
 }
-/// Multiplying a InverseArea by a InversePressure returns a value of type InverseForce
-impl<T> core::ops::Mul<&InversePressure<T>> for &InverseArea<T> where T: NumLike {
-	type Output = InverseForce<T>;
-	fn mul(self, rhs: &InversePressure<T>) -> Self::Output {
-		InverseForce{per_N: self.per_m2.clone() * rhs.per_Pa.clone()}
+/// Multiplying a unit value by a scalar value returns a unit value
+#[cfg(feature="num-rational")]
+impl core::ops::Mul<&InverseSolidAngle<num_rational::BigRational>> for &num_rational::BigRational {
+	type Output = InverseSolidAngle<num_rational::BigRational>;
+	fn mul(self, rhs: &InverseSolidAngle<num_rational::BigRational>) -> Self::Output {
+		InverseSolidAngle{per_sr: self.clone() * rhs.per_sr.clone()}
 	}
 }
 
-// InverseArea * MomentOfInertia -> Mass
-/// Multiplying a InverseArea by a MomentOfInertia returns a value of type Mass
-impl<T> core::ops::Mul<MomentOfInertia<T>> for InverseArea<T> where T: NumLike {
-	type Output = Mass<T>;
-	fn mul(self, rhs: MomentOfInertia<T>) -> Self::Output {
-		Mass{kg: self.per_m2 * rhs.kgm2}
-	}
-}
-/// Multiplying a InverseArea by a MomentOfInertia returns a value of type Mass
-impl<T> core::ops::Mul<MomentOfInertia<T>> for &InverseArea<T> where T: NumLike {
-	type Output = Mass<T>;
-	fn mul(self, rhs: MomentOfInertia<T>) -> Self::Output {
-		Mass{kg: self.per_m2.clone() * rhs.kgm2}
+
+
+
+
+/// Multiplying a InverseSolidAngle by an [nalgebra](https://crates.io/crates/nalgebra) 
+/// vector or matrix of unitless values returns a vector or matrix of InverseSolidAngle 
+/// values (eg a direction vector multiplied by a InverseSolidAngle)
+#[cfg(feature="nalgebra")]
+impl<T, R, C, S> core::ops::Mul<nalgebra::Matrix<T, R, C, S>> for InverseSolidAngle<T> where 
+	T: NumLike+nalgebra::Scalar, R: nalgebra::Dim, C: nalgebra::Dim, S: nalgebra::RawStorage<T, R, C>, 
+	nalgebra::DefaultAllocator: nalgebra::allocator::Allocator<R, C> {
+	type Output = nalgebra::OMatrix<InverseSolidAngle<T>, R, C>;
+	fn mul(self, rhs: nalgebra::Matrix<T, R, C, S>) -> Self::Output {
+		rhs.map(|x| InverseSolidAngle{per_sr: self.per_sr.clone() * x})
 	}
 }
-/// Multiplying a InverseArea by a MomentOfInertia returns a value of type Mass
-impl<T> core::ops::Mul<&MomentOfInertia<T>> for InverseArea<T> where T: NumLike {
-	type Output = Mass<T>;
-	fn mul(self, rhs: &MomentOfInertia<T>) -> Self::Output {
-		Mass{kg: self.per_m2 * rhs.kgm2.clone()}
+/// Multiplying an [nalgebra](https://crates.io/crates/nalgebra) vector or matrix of 
+/// unitless values by a InverseSolidAngle returns a vector or matrix of InverseSolidAngle values
+#[cfg(feature="nalgebra")]
+impl<T, R, C, S> core::ops::Mul<InverseSolidAngle<T>> for nalgebra::Matrix<T, R, C, S> where 
+	T: NumLike+nalgebra::Scalar, R: nalgebra::Dim, C: nalgebra::Dim, S: nalgebra::RawStorage<T, R, C>, 
+	nalgebra::DefaultAllocator: nalgebra::allocator::Allocator<R, C> {
+	type Output = nalgebra::OMatrix<InverseSolidAngle<T>, R, C>;
+	fn mul(self, rhs: InverseSolidAngle<T>) -> Self::Output {
+		self.map(|x| InverseSolidAngle{per_sr: x * rhs.per_sr.clone()})
 	}
 }
-/// Multiplying a InverseArea by a MomentOfInertia returns a value of type Mass
-impl<T> core::ops::Mul<&MomentOfInertia<T>> for &InverseArea<T> where T: NumLike {
-	type Output = Mass<T>;
-	fn mul(self, rhs: &MomentOfInertia<T>) -> Self::Output {
-		Mass{kg: self.per_m2.clone() * rhs.kgm2.clone()}
+/// Dividing an [nalgebra](https://crates.io/crates/nalgebra) vector or matrix of 
+/// InverseSolidAngle values by a InverseSolidAngle returns a vector or matrix of unitless values
+#[cfg(feature="nalgebra")]
+impl<T, R, C, S> core::ops::Div<InverseSolidAngle<T>> for nalgebra::Matrix<InverseSolidAngle<T>, R, C, S> where 
+	T: NumLike+nalgebra::Scalar, R: nalgebra::Dim, C: nalgebra::Dim, S: nalgebra::RawStorage<InverseSolidAngle<T>, R, C>, 
+	nalgebra::DefaultAllocator: nalgebra::allocator::Allocator<R, C> {
+	type Output = nalgebra::OMatrix<T, R, C>;
+	fn div(self, rhs: InverseSolidAngle<T>) -> Self::Output {
+		self.map(|x| x.per_sr / rhs.per_sr.clone())
 	}
 }
 
-// InverseArea / Pressure -> InverseForce
-/// Dividing a InverseArea by a Pressure returns a value of type InverseForce
-impl<T> core::ops::Div<Pressure<T>> for InverseArea<T> where T: NumLike {
-	type Output = InverseForce<T>;
-	fn div(self, rhs: Pressure<T>) -> Self::Output {
-		InverseForce{per_N: self.per_m2 / rhs.Pa}
+
+
+// InverseSolidAngle * InverseLuminosity -> InverseLuminousFlux
+/// Multiplying a InverseSolidAngle by a InverseLuminosity returns a value of type InverseLuminousFlux
+impl<T> core::ops::Mul<InverseLuminosity<T>> for InverseSolidAngle<T> where T: NumLike {
+	type Output = InverseLuminousFlux<T>;
+	fn mul(self, rhs: InverseLuminosity<T>) -> Self::Output {
+		InverseLuminousFlux{per_lm: self.per_sr * rhs.per_cd}
 	}
 }
-/// Dividing a InverseArea by a Pressure returns a value of type InverseForce
-impl<T> core::ops::Div<Pressure<T>> for &InverseArea<T> where T: NumLike {
-	type Output = InverseForce<T>;
-	fn div(self, rhs: Pressure<T>) -> Self::Output {
-		InverseForce{per_N: self.per_m2.clone() / rhs.Pa}
+/// Multiplying a InverseSolidAngle by a InverseLuminosity returns a value of type InverseLuminousFlux
+impl<T> core::ops::Mul<InverseLuminosity<T>> for &InverseSolidAngle<T> where T: NumLike {
+	type Output = InverseLuminousFlux<T>;
+	fn mul(self, rhs: InverseLuminosity<T>) -> Self::Output {
+		InverseLuminousFlux{per_lm: self.per_sr.clone() * rhs.per_cd}
 	}
 }
-/// Dividing a InverseArea by a Pressure returns a value of type InverseForce
-impl<T> core::ops::Div<&Pressure<T>> for InverseArea<T> where T: NumLike {
-	type Output = InverseForce<T>;
-	fn div(self, rhs: &Pressure<T>) -> Self::Output {
-		InverseForce{per_N: self.per_m2 / rhs.Pa.clone()}
+/// Multiplying a InverseSolidAngle by a InverseLuminosity returns a value of type InverseLuminousFlux
+impl<T> core::ops::Mul<&InverseLuminosity<T>> for InverseSolidAngle<T> where T: NumLike {
+	type Output = InverseLuminousFlux<T>;
+	fn mul(self, rhs: &InverseLuminosity<T>) -> Self::Output {
+		InverseLuminousFlux{per_lm: self.per_sr * rhs.per_cd.clone()}
 	}
 }
-/// Dividing a InverseArea by a Pressure returns a value of type InverseForce
-impl<T> core::ops::Div<&Pressure<T>> for &InverseArea<T> where T: NumLike {
-	type Output = InverseForce<T>;
-	fn div(self, rhs: &Pressure<T>) -> Self::Output {
-		InverseForce{per_N: self.per_m2.clone() / rhs.Pa.clone()}
+/// Multiplying a InverseSolidAngle by a InverseLuminosity returns a value of type InverseLuminousFlux
+impl<T> core::ops::Mul<&InverseLuminosity<T>> for &InverseSolidAngle<T> where T: NumLike {
+	type Output = InverseLuminousFlux<T>;
+	fn mul(self, rhs: &InverseLuminosity<T>) -> Self::Output {
+		InverseLuminousFlux{per_lm: self.per_sr.clone() * rhs.per_cd.clone()}
 	}
 }
 
-// 1/InverseArea -> Area
-/// Dividing a scalar value by a InverseArea unit value returns a value of type Area
-impl<T> core::ops::Div<InverseArea<T>> for f64 where T: NumLike+From<f64> {
-	type Output = Area<T>;
-	fn div(self, rhs: InverseArea<T>) -> Self::Output {
-		Area{m2: T::from(self) / rhs.per_m2}
+// InverseSolidAngle / Luminosity -> InverseLuminousFlux
+/// Dividing a InverseSolidAngle by a Luminosity returns a value of type InverseLuminousFlux
+impl<T> core::ops::Div<Luminosity<T>> for InverseSolidAngle<T> where T: NumLike {
+	type Output = InverseLuminousFlux<T>;
+	fn div(self, rhs: Luminosity<T>) -> Self::Output {
+		InverseLuminousFlux{per_lm: self.per_sr / rhs.cd}
 	}
 }
-/// Dividing a scalar value by a InverseArea unit value returns a value of type Area
-impl<T> core::ops::Div<InverseArea<T>> for &f64 where T: NumLike+From<f64> {
-	type Output = Area<T>;
-	fn div(self, rhs: InverseArea<T>) -> Self::Output {
-		Area{m2: T::from(self.clone()) / rhs.per_m2}
+/// Dividing a InverseSolidAngle by a Luminosity returns a value of type InverseLuminousFlux
+impl<T> core::ops::Div<Luminosity<T>> for &InverseSolidAngle<T> where T: NumLike {
+	type Output = InverseLuminousFlux<T>;
+	fn div(self, rhs: Luminosity<T>) -> Self::Output {
+		InverseLuminousFlux{per_lm: self.per_sr.clone() / rhs.cd}
 	}
 }
-/// Dividing a scalar value by a InverseArea unit value returns a value of type Area
-impl<T> core::ops::Div<&InverseArea<T>> for f64 where T: NumLike+From<f64> {
-	type Output = Area<T>;
-	fn div(self, rhs: &InverseArea<T>) -> Self::Output {
-		Area{m2: T::from(self) / rhs.per_m2.clone()}
+/// Dividing a InverseSolidAngle by a Luminosity returns a value of type InverseLuminousFlux
+impl<T> core::ops::Div<&Luminosity<T>> for InverseSolidAngle<T> where T: NumLike {
+	type Output = InverseLuminousFlux<T>;
+	fn div(self, rhs: &Luminosity<T>) -> Self::Output {
+		InverseLuminousFlux{per_lm: self.per_sr / rhs.cd.clone()}
 	}
 }
-/// Dividing a scalar value by a InverseArea unit value returns a value of type Area
-impl<T> core::ops::Div<&InverseArea<T>> for &f64 where T: NumLike+From<f64> {
-	type Output = Area<T>;
-	fn div(self, rhs: &InverseArea<T>) -> Self::Output {
-		Area{m2: T::from(self.clone()) / rhs.per_m2.clone()}
+/// Dividing a InverseSolidAngle by a Luminosity returns a value of type InverseLuminousFlux
+impl<T> core::ops::Div<&Luminosity<T>> for &InverseSolidAngle<T> where T: NumLike {
+	type Output = InverseLuminousFlux<T>;
+	fn div(self, rhs: &Luminosity<T>) -> Self::Output {
+		InverseLuminousFlux{per_lm: self.per_sr.clone() / rhs.cd.clone()}
 	}
 }
 
-// 1/InverseArea -> Area
-/// Dividing a scalar value by a InverseArea unit value returns a value of type Area
-impl<T> core::ops::Div<InverseArea<T>> for f32 where T: NumLike+From<f32> {
-	type Output = Area<T>;
-	fn div(self, rhs: InverseArea<T>) -> Self::Output {
-		Area{m2: T::from(self) / rhs.per_m2}
+// InverseSolidAngle / InverseIrradiance -> Radiance
+/// Dividing a InverseSolidAngle by a InverseIrradiance returns a value of type Radiance
+impl<T> core::ops::Div<InverseIrradiance<T>> for InverseSolidAngle<T> where T: NumLike {
+	type Output = Radiance<T>;
+	fn div(self, rhs: InverseIrradiance<T>) -> Self::Output {
+		Radiance{W_per_m2sr: self.per_sr / rhs.m2_per_W}
 	}
 }
-/// Dividing a scalar value by a InverseArea unit value returns a value of type Area
-impl<T> core::ops::Div<InverseArea<T>> for &f32 where T: NumLike+From<f32> {
-	type Output = Area<T>;
-	fn div(self, rhs: InverseArea<T>) -> Self::Output {
-		Area{m2: T::from(self.clone()) / rhs.per_m2}
+/// Dividing a InverseSolidAngle by a InverseIrradiance returns a value of type Radiance
+impl<T> core::ops::Div<InverseIrradiance<T>> for &InverseSolidAngle<T> where T: NumLike {
+	type Output = Radiance<T>;
+	fn div(self, rhs: InverseIrradiance<T>) -> Self::Output {
+		Radiance{W_per_m2sr: self.per_sr.clone() / rhs.m2_per_W}
 	}
 }
-/// Dividing a scalar value by a InverseArea unit value returns a value of type Area
-impl<T> core::ops::Div<&InverseArea<T>> for f32 where T: NumLike+From<f32> {
-	type Output = Area<T>;
-	fn div(self, rhs: &InverseArea<T>) -> Self::Output {
-		Area{m2: T::from(self) / rhs.per_m2.clone()}
+/// Dividing a InverseSolidAngle by a InverseIrradiance returns a value of type Radiance
+impl<T> core::ops::Div<&InverseIrradiance<T>> for InverseSolidAngle<T> where T: NumLike {
+	type Output = Radiance<T>;
+	fn div(self, rhs: &InverseIrradiance<T>) -> Self::Output {
+		Radiance{W_per_m2sr: self.per_sr / rhs.m2_per_W.clone()}
 	}
 }
-/// Dividing a scalar value by a InverseArea unit value returns a value of type Area
-impl<T> core::ops::Div<&InverseArea<T>> for &f32 where T: NumLike+From<f32> {
-	type Output = Area<T>;
-	fn div(self, rhs: &InverseArea<T>) -> Self::Output {
-		Area{m2: T::from(self.clone()) / rhs.per_m2.clone()}
+/// Dividing a InverseSolidAngle by a InverseIrradiance returns a value of type Radiance
+impl<T> core::ops::Div<&InverseIrradiance<T>> for &InverseSolidAngle<T> where T: NumLike {
+	type Output = Radiance<T>;
+	fn div(self, rhs: &InverseIrradiance<T>) -> Self::Output {
+		Radiance{W_per_m2sr: self.per_sr.clone() / rhs.m2_per_W.clone()}
 	}
 }
 
-// 1/InverseArea -> Area
-/// Dividing a scalar value by a InverseArea unit value returns a value of type Area
-impl<T> core::ops::Div<InverseArea<T>> for i64 where T: NumLike+From<i64> {
-	type Output = Area<T>;
-	fn div(self, rhs: InverseArea<T>) -> Self::Output {
-		Area{m2: T::from(self) / rhs.per_m2}
+// InverseSolidAngle / InverseLuminousFlux -> Luminosity
+/// Dividing a InverseSolidAngle by a InverseLuminousFlux returns a value of type Luminosity
+impl<T> core::ops::Div<InverseLuminousFlux<T>> for InverseSolidAngle<T> where T: NumLike {
+	type Output = Luminosity<T>;
+	fn div(self, rhs: InverseLuminousFlux<T>) -> Self::Output {
+		Luminosity{cd: self.per_sr / rhs.per_lm}
 	}
 }
-/// Dividing a scalar value by a InverseArea unit value returns a value of type Area
-impl<T> core::ops::Div<InverseArea<T>> for &i64 where T: NumLike+From<i64> {
-	type Output = Area<T>;
-	fn div(self, rhs: InverseArea<T>) -> Self::Output {
-		Area{m2: T::from(self.clone()) / rhs.per_m2}
+/// Dividing a InverseSolidAngle by a InverseLuminousFlux returns a value of type Luminosity
+impl<T> core::ops::Div<InverseLuminousFlux<T>> for &InverseSolidAngle<T> where T: NumLike {
+	type Output = Luminosity<T>;
+	fn div(self, rhs: InverseLuminousFlux<T>) -> Self::Output {
+		Luminosity{cd: self.per_sr.clone() / rhs.per_lm}
 	}
 }
-/// Dividing a scalar value by a InverseArea unit value returns a value of type Area
-impl<T> core::ops::Div<&InverseArea<T>> for i64 where T: NumLike+From<i64> {
-	type Output = Area<T>;
-	fn div(self, rhs: &InverseArea<T>) -> Self::Output {
-		Area{m2: T::from(self) / rhs.per_m2.clone()}
+/// Dividing a InverseSolidAngle by a InverseLuminousFlux returns a value of type Luminosity
+impl<T> core::ops::Div<&InverseLuminousFlux<T>> for InverseSolidAngle<T> where T: NumLike {
+	type Output = Luminosity<T>;
+	fn div(self, rhs: &InverseLuminousFlux<T>) -> Self::Output {
+		Luminosity{cd: self.per_sr / rhs.per_lm.clone()}
 	}
 }
-/// Dividing a scalar value by a InverseArea unit value returns a value of type Area
-impl<T> core::ops::Div<&InverseArea<T>> for &i64 where T: NumLike+From<i64> {
-	type Output = Area<T>;
-	fn div(self, rhs: &InverseArea<T>) -> Self::Output {
-		Area{m2: T::from(self.clone()) / rhs.per_m2.clone()}
+/// Dividing a InverseSolidAngle by a InverseLuminousFlux returns a value of type Luminosity
+impl<T> core::ops::Div<&InverseLuminousFlux<T>> for &InverseSolidAngle<T> where T: NumLike {
+	type Output = Luminosity<T>;
+	fn div(self, rhs: &InverseLuminousFlux<T>) -> Self::Output {
+		Luminosity{cd: self.per_sr.clone() / rhs.per_lm.clone()}
 	}
 }
 
-// 1/InverseArea -> Area
-/// Dividing a scalar value by a InverseArea unit value returns a value of type Area
-impl<T> core::ops::Div<InverseArea<T>> for i32 where T: NumLike+From<i32> {
-	type Output = Area<T>;
-	fn div(self, rhs: InverseArea<T>) -> Self::Output {
-		Area{m2: T::from(self) / rhs.per_m2}
+// InverseSolidAngle * InverseRadiance -> InverseIrradiance
+/// Multiplying a InverseSolidAngle by a InverseRadiance returns a value of type InverseIrradiance
+impl<T> core::ops::Mul<InverseRadiance<T>> for InverseSolidAngle<T> where T: NumLike {
+	type Output = InverseIrradiance<T>;
+	fn mul(self, rhs: InverseRadiance<T>) -> Self::Output {
+		InverseIrradiance{m2_per_W: self.per_sr * rhs.m2sr_per_W}
 	}
 }
-/// Dividing a scalar value by a InverseArea unit value returns a value of type Area
-impl<T> core::ops::Div<InverseArea<T>> for &i32 where T: NumLike+From<i32> {
-	type Output = Area<T>;
-	fn div(self, rhs: InverseArea<T>) -> Self::Output {
-		Area{m2: T::from(self.clone()) / rhs.per_m2}
+/// Multiplying a InverseSolidAngle by a InverseRadiance returns a value of type InverseIrradiance
+impl<T> core::ops::Mul<InverseRadiance<T>> for &InverseSolidAngle<T> where T: NumLike {
+	type Output = InverseIrradiance<T>;
+	fn mul(self, rhs: InverseRadiance<T>) -> Self::Output {
+		InverseIrradiance{m2_per_W: self.per_sr.clone() * rhs.m2sr_per_W}
 	}
 }
-/// Dividing a scalar value by a InverseArea unit value returns a value of type Area
-impl<T> core::ops::Div<&InverseArea<T>> for i32 where T: NumLike+From<i32> {
-	type Output = Area<T>;
-	fn div(self, rhs: &InverseArea<T>) -> Self::Output {
-		Area{m2: T::from(self) / rhs.per_m2.clone()}
+/// Multiplying a InverseSolidAngle by a InverseRadiance returns a value of type InverseIrradiance
+impl<T> core::ops::Mul<&InverseRadiance<T>> for InverseSolidAngle<T> where T: NumLike {
+	type Output = InverseIrradiance<T>;
+	fn mul(self, rhs: &InverseRadiance<T>) -> Self::Output {
+		InverseIrradiance{m2_per_W: self.per_sr * rhs.m2sr_per_W.clone()}
 	}
 }
-/// Dividing a scalar value by a InverseArea unit value returns a value of type Area
-impl<T> core::ops::Div<&InverseArea<T>> for &i32 where T: NumLike+From<i32> {
-	type Output = Area<T>;
-	fn div(self, rhs: &InverseArea<T>) -> Self::Output {
-		Area{m2: T::from(self.clone()) / rhs.per_m2.clone()}
+/// Multiplying a InverseSolidAngle by a InverseRadiance returns a value of type InverseIrradiance
+impl<T> core::ops::Mul<&InverseRadiance<T>> for &InverseSolidAngle<T> where T: NumLike {
+	type Output = InverseIrradiance<T>;
+	fn mul(self, rhs: &InverseRadiance<T>) -> Self::Output {
+		InverseIrradiance{m2_per_W: self.per_sr.clone() * rhs.m2sr_per_W.clone()}
 	}
 }
 
-// 1/InverseArea -> Area
-/// Dividing a scalar value by a InverseArea unit value returns a value of type Area
-#[cfg(feature="num-bigfloat")]
-impl<T> core::ops::Div<InverseArea<T>> for num_bigfloat::BigFloat where T: NumLike+From<num_bigfloat::BigFloat> {
-	type Output = Area<T>;
-	fn div(self, rhs: InverseArea<T>) -> Self::Output {
-		Area{m2: T::from(self) / rhs.per_m2}
+// InverseSolidAngle * Irradiance -> Radiance
+/// Multiplying a InverseSolidAngle by a Irradiance returns a value of type Radiance
+impl<T> core::ops::Mul<Irradiance<T>> for InverseSolidAngle<T> where T: NumLike {
+	type Output = Radiance<T>;
+	fn mul(self, rhs: Irradiance<T>) -> Self::Output {
+		Radiance{W_per_m2sr: self.per_sr * rhs.W_per_m2}
 	}
 }
-/// Dividing a scalar value by a InverseArea unit value returns a value of type Area
-#[cfg(feature="num-bigfloat")]
-impl<T> core::ops::Div<InverseArea<T>> for &num_bigfloat::BigFloat where T: NumLike+From<num_bigfloat::BigFloat> {
-	type Output = Area<T>;
-	fn div(self, rhs: InverseArea<T>) -> Self::Output {
-		Area{m2: T::from(self.clone()) / rhs.per_m2}
+/// Multiplying a InverseSolidAngle by a Irradiance returns a value of type Radiance
+impl<T> core::ops::Mul<Irradiance<T>> for &InverseSolidAngle<T> where T: NumLike {
+	type Output = Radiance<T>;
+	fn mul(self, rhs: Irradiance<T>) -> Self::Output {
+		Radiance{W_per_m2sr: self.per_sr.clone() * rhs.W_per_m2}
 	}
 }
-/// Dividing a scalar value by a InverseArea unit value returns a value of type Area
-#[cfg(feature="num-bigfloat")]
-impl<T> core::ops::Div<&InverseArea<T>> for num_bigfloat::BigFloat where T: NumLike+From<num_bigfloat::BigFloat> {
-	type Output = Area<T>;
-	fn div(self, rhs: &InverseArea<T>) -> Self::Output {
-		Area{m2: T::from(self) / rhs.per_m2.clone()}
+/// Multiplying a InverseSolidAngle by a Irradiance returns a value of type Radiance
+impl<T> core::ops::Mul<&Irradiance<T>> for InverseSolidAngle<T> where T: NumLike {
+	type Output = Radiance<T>;
+	fn mul(self, rhs: &Irradiance<T>) -> Self::Output {
+		Radiance{W_per_m2sr: self.per_sr * rhs.W_per_m2.clone()}
 	}
 }
-/// Dividing a scalar value by a InverseArea unit value returns a value of type Area
-#[cfg(feature="num-bigfloat")]
-impl<T> core::ops::Div<&InverseArea<T>> for &num_bigfloat::BigFloat where T: NumLike+From<num_bigfloat::BigFloat> {
-	type Output = Area<T>;
-	fn div(self, rhs: &InverseArea<T>) -> Self::Output {
-		Area{m2: T::from(self.clone()) / rhs.per_m2.clone()}
+/// Multiplying a InverseSolidAngle by a Irradiance returns a value of type Radiance
+impl<T> core::ops::Mul<&Irradiance<T>> for &InverseSolidAngle<T> where T: NumLike {
+	type Output = Radiance<T>;
+	fn mul(self, rhs: &Irradiance<T>) -> Self::Output {
+		Radiance{W_per_m2sr: self.per_sr.clone() * rhs.W_per_m2.clone()}
 	}
 }
 
-// 1/InverseArea -> Area
-/// Dividing a scalar value by a InverseArea unit value returns a value of type Area
-#[cfg(feature="num-complex")]
-impl<T> core::ops::Div<InverseArea<T>> for num_complex::Complex32 where T: NumLike+From<num_complex::Complex32> {
-	type Output = Area<T>;
-	fn div(self, rhs: InverseArea<T>) -> Self::Output {
-		Area{m2: T::from(self) / rhs.per_m2}
+// InverseSolidAngle * LuminousFlux -> Luminosity
+/// Multiplying a InverseSolidAngle by a LuminousFlux returns a value of type Luminosity
+impl<T> core::ops::Mul<LuminousFlux<T>> for InverseSolidAngle<T> where T: NumLike {
+	type Output = Luminosity<T>;
+	fn mul(self, rhs: LuminousFlux<T>) -> Self::Output {
+		Luminosity{cd: self.per_sr * rhs.lm}
 	}
 }
-/// Dividing a scalar value by a InverseArea unit value returns a value of type Area
-#[cfg(feature="num-complex")]
-impl<T> core::ops::Div<InverseArea<T>> for &num_complex::Complex32 where T: NumLike+From<num_complex::Complex32> {
-	type Output = Area<T>;
-	fn div(self, rhs: InverseArea<T>) -> Self::Output {
-		Area{m2: T::from(self.clone()) / rhs.per_m2}
+/// Multiplying a InverseSolidAngle by a LuminousFlux returns a value of type Luminosity
+impl<T> core::ops::Mul<LuminousFlux<T>> for &InverseSolidAngle<T> where T: NumLike {
+	type Output = Luminosity<T>;
+	fn mul(self, rhs: LuminousFlux<T>) -> Self::Output {
+		Luminosity{cd: self.per_sr.clone() * rhs.lm}
 	}
 }
-/// Dividing a scalar value by a InverseArea unit value returns a value of type Area
-#[cfg(feature="num-complex")]
-impl<T> core::ops::Div<&InverseArea<T>> for num_complex::Complex32 where T: NumLike+From<num_complex::Complex32> {
-	type Output = Area<T>;
-	fn div(self, rhs: &InverseArea<T>) -> Self::Output {
-		Area{m2: T::from(self) / rhs.per_m2.clone()}
+/// Multiplying a InverseSolidAngle by a LuminousFlux returns a value of type Luminosity
+impl<T> core::ops::Mul<&LuminousFlux<T>> for InverseSolidAngle<T> where T: NumLike {
+	type Output = Luminosity<T>;
+	fn mul(self, rhs: &LuminousFlux<T>) -> Self::Output {
+		Luminosity{cd: self.per_sr * rhs.lm.clone()}
 	}
 }
-/// Dividing a scalar value by a InverseArea unit value returns a value of type Area
-#[cfg(feature="num-complex")]
-impl<T> core::ops::Div<&InverseArea<T>> for &num_complex::Complex32 where T: NumLike+From<num_complex::Complex32> {
-	type Output = Area<T>;
-	fn div(self, rhs: &InverseArea<T>) -> Self::Output {
-		Area{m2: T::from(self.clone()) / rhs.per_m2.clone()}
+/// Multiplying a InverseSolidAngle by a LuminousFlux returns a value of type Luminosity
+impl<T> core::ops::Mul<&LuminousFlux<T>> for &InverseSolidAngle<T> where T: NumLike {
+	type Output = Luminosity<T>;
+	fn mul(self, rhs: &LuminousFlux<T>) -> Self::Output {
+		Luminosity{cd: self.per_sr.clone() * rhs.lm.clone()}
 	}
 }
 
-// 1/InverseArea -> Area
-/// Dividing a scalar value by a InverseArea unit value returns a value of type Area
-#[cfg(feature="num-complex")]
-impl<T> core::ops::Div<InverseArea<T>> for num_complex::Complex64 where T: NumLike+From<num_complex::Complex64> {
-	type Output = Area<T>;
-	fn div(self, rhs: InverseArea<T>) -> Self::Output {
-		Area{m2: T::from(self) / rhs.per_m2}
+// InverseSolidAngle / Radiance -> InverseIrradiance
+/// Dividing a InverseSolidAngle by a Radiance returns a value of type InverseIrradiance
+impl<T> core::ops::Div<Radiance<T>> for InverseSolidAngle<T> where T: NumLike {
+	type Output = InverseIrradiance<T>;
+	fn div(self, rhs: Radiance<T>) -> Self::Output {
+		InverseIrradiance{m2_per_W: self.per_sr / rhs.W_per_m2sr}
 	}
 }
-/// Dividing a scalar value by a InverseArea unit value returns a value of type Area
-#[cfg(feature="num-complex")]
-impl<T> core::ops::Div<InverseArea<T>> for &num_complex::Complex64 where T: NumLike+From<num_complex::Complex64> {
-	type Output = Area<T>;
-	fn div(self, rhs: InverseArea<T>) -> Self::Output {
-		Area{m2: T::from(self.clone()) / rhs.per_m2}
+/// Dividing a InverseSolidAngle by a Radiance returns a value of type InverseIrradiance
+impl<T> core::ops::Div<Radiance<T>> for &InverseSolidAngle<T> where T: NumLike {
+	type Output = InverseIrradiance<T>;
+	fn div(self, rhs: Radiance<T>) -> Self::Output {
+		InverseIrradiance{m2_per_W: self.per_sr.clone() / rhs.W_per_m2sr}
 	}
 }
-/// Dividing a scalar value by a InverseArea unit value returns a value of type Area
-#[cfg(feature="num-complex")]
-impl<T> core::ops::Div<&InverseArea<T>> for num_complex::Complex64 where T: NumLike+From<num_complex::Complex64> {
-	type Output = Area<T>;
-	fn div(self, rhs: &InverseArea<T>) -> Self::Output {
-		Area{m2: T::from(self) / rhs.per_m2.clone()}
+/// Dividing a InverseSolidAngle by a Radiance returns a value of type InverseIrradiance
+impl<T> core::ops::Div<&Radiance<T>> for InverseSolidAngle<T> where T: NumLike {
+	type Output = InverseIrradiance<T>;
+	fn div(self, rhs: &Radiance<T>) -> Self::Output {
+		InverseIrradiance{m2_per_W: self.per_sr / rhs.W_per_m2sr.clone()}
 	}
 }
-/// Dividing a scalar value by a InverseArea unit value returns a value of type Area
-#[cfg(feature="num-complex")]
-impl<T> core::ops::Div<&InverseArea<T>> for &num_complex::Complex64 where T: NumLike+From<num_complex::Complex64> {
-	type Output = Area<T>;
-	fn div(self, rhs: &InverseArea<T>) -> Self::Output {
-		Area{m2: T::from(self.clone()) / rhs.per_m2.clone()}
+/// Dividing a InverseSolidAngle by a Radiance returns a value of type InverseIrradiance
+impl<T> core::ops::Div<&Radiance<T>> for &InverseSolidAngle<T> where T: NumLike {
+	type Output = InverseIrradiance<T>;
+	fn div(self, rhs: &Radiance<T>) -> Self::Output {
+		InverseIrradiance{m2_per_W: self.per_sr.clone() / rhs.W_per_m2sr.clone()}
 	}
 }
 
-/// The inverse of solid angle unit type, defined as inverse steradian in SI units
-#[derive(UnitStruct, Debug, Clone)]
-#[cfg_attr(feature="serde", derive(Serialize, Deserialize))]
-pub struct InverseSolidAngle<T: NumLike>{
-	/// The value of this Inverse solid angle in inverse steradian
-	pub per_sr: T
+// InverseSolidAngle * Angle -> InverseAngle
+/// Multiplying a InverseSolidAngle by a Angle returns a value of type InverseAngle
+impl<T> core::ops::Mul<Angle<T>> for InverseSolidAngle<T> where T: NumLike {
+	type Output = InverseAngle<T>;
+	fn mul(self, rhs: Angle<T>) -> Self::Output {
+		InverseAngle{per_rad: self.per_sr * rhs.rad}
+	}
 }
-
-impl<T> InverseSolidAngle<T> where T: NumLike {
-
-	/// Returns the standard unit name of inverse solid angle: "inverse steradian"
-	pub fn unit_name() -> &'static str { "inverse steradian" }
-	
-	/// Returns the abbreviated name or symbol of inverse solid angle: "1/sr" for inverse steradian
-	pub fn unit_symbol() -> &'static str { "1/sr" }
-	
-	/// Returns a new inverse solid angle value from the given number of inverse steradians
-	///
-	/// # Arguments
-	/// * `per_sr` - Any number-like type, representing a quantity of inverse steradian
-	pub fn from_per_sr(per_sr: T) -> Self { InverseSolidAngle{per_sr: per_sr} }
-	
-	/// Returns a copy of this inverse solid angle value in inverse steradians
-	pub fn to_per_sr(&self) -> T { self.per_sr.clone() }
-
-	/// Returns a new inverse solid angle value from the given number of inverse steradians
-	///
-	/// # Arguments
-	/// * `per_steradians` - Any number-like type, representing a quantity of inverse steradian
-	pub fn from_per_steradians(per_steradians: T) -> Self { InverseSolidAngle{per_sr: per_steradians} }
-	
-	/// Returns a copy of this inverse solid angle value in inverse steradians
-	pub fn to_per_steradians(&self) -> T { self.per_sr.clone() }
-
+/// Multiplying a InverseSolidAngle by a Angle returns a value of type InverseAngle
+impl<T> core::ops::Mul<Angle<T>> for &InverseSolidAngle<T> where T: NumLike {
+	type Output = InverseAngle<T>;
+	fn mul(self, rhs: Angle<T>) -> Self::Output {
+		InverseAngle{per_rad: self.per_sr.clone() * rhs.rad}
+	}
 }
-
-impl<T> fmt::Display for InverseSolidAngle<T> where T: NumLike {
-	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-		write!(f, "{} {}", &self.per_sr, Self::unit_symbol())
+/// Multiplying a InverseSolidAngle by a Angle returns a value of type InverseAngle
+impl<T> core::ops::Mul<&Angle<T>> for InverseSolidAngle<T> where T: NumLike {
+	type Output = InverseAngle<T>;
+	fn mul(self, rhs: &Angle<T>) -> Self::Output {
+		InverseAngle{per_rad: self.per_sr * rhs.rad.clone()}
 	}
 }
-
-impl<T> InverseSolidAngle<T> where T: NumLike+From<f64> {
-	
+/// Multiplying a InverseSolidAngle by a Angle returns a value of type InverseAngle
+impl<T> core::ops::Mul<&Angle<T>> for &InverseSolidAngle<T> where T: NumLike {
+	type Output = InverseAngle<T>;
+	fn mul(self, rhs: &Angle<T>) -> Self::Output {
+		InverseAngle{per_rad: self.per_sr.clone() * rhs.rad.clone()}
+	}
 }
 
-
-/// Multiplying a unit value by a scalar value returns a unit value
-#[cfg(feature="num-bigfloat")]
-impl core::ops::Mul<InverseSolidAngle<num_bigfloat::BigFloat>> for num_bigfloat::BigFloat {
-	type Output = InverseSolidAngle<num_bigfloat::BigFloat>;
-	fn mul(self, rhs: InverseSolidAngle<num_bigfloat::BigFloat>) -> Self::Output {
-		InverseSolidAngle{per_sr: self * rhs.per_sr}
+// InverseSolidAngle / InverseAngle -> InverseAngle
+/// Dividing a InverseSolidAngle by a InverseAngle returns a value of type InverseAngle
+impl<T> core::ops::Div<InverseAngle<T>> for InverseSolidAngle<T> where T: NumLike {
+	type Output = InverseAngle<T>;
+	fn div(self, rhs: InverseAngle<T>) -> Self::Output {
+		InverseAngle{per_rad: self.per_sr / rhs.per_rad}
 	}
 }
-/// Multiplying a unit value by a scalar value returns a unit value
-#[cfg(feature="num-bigfloat")]
-impl core::ops::Mul<InverseSolidAngle<num_bigfloat::BigFloat>> for &num_bigfloat::BigFloat {
-	type Output = InverseSolidAngle<num_bigfloat::BigFloat>;
-	fn mul(self, rhs: InverseSolidAngle<num_bigfloat::BigFloat>) -> Self::Output {
-		InverseSolidAngle{per_sr: self.clone() * rhs.per_sr}
+/// Dividing a InverseSolidAngle by a InverseAngle returns a value of type InverseAngle
+impl<T> core::ops::Div<InverseAngle<T>> for &InverseSolidAngle<T> where T: NumLike {
+	type Output = InverseAngle<T>;
+	fn div(self, rhs: InverseAngle<T>) -> Self::Output {
+		InverseAngle{per_rad: self.per_sr.clone() / rhs.per_rad}
 	}
 }
-/// Multiplying a unit value by a scalar value returns a unit value
-#[cfg(feature="num-bigfloat")]
-impl core::ops::Mul<&InverseSolidAngle<num_bigfloat::BigFloat>> for num_bigfloat::BigFloat {
-	type Output = InverseSolidAngle<num_bigfloat::BigFloat>;
-	fn mul(self, rhs: &InverseSolidAngle<num_bigfloat::BigFloat>) -> Self::Output {
-		InverseSolidAngle{per_sr: self * rhs.per_sr.clone()}
+/// Dividing a InverseSolidAngle by a InverseAngle returns a value of type InverseAngle
+impl<T> core::ops::Div<&InverseAngle<T>> for InverseSolidAngle<T> where T: NumLike {
+	type Output = InverseAngle<T>;
+	fn div(self, rhs: &InverseAngle<T>) -> Self::Output {
+		InverseAngle{per_rad: self.per_sr / rhs.per_rad.clone()}
 	}
 }
-/// Multiplying a unit value by a scalar value returns a unit value
-#[cfg(feature="num-bigfloat")]
-impl core::ops::Mul<&InverseSolidAngle<num_bigfloat::BigFloat>> for &num_bigfloat::BigFloat {
-	type Output = InverseSolidAngle<num_bigfloat::BigFloat>;
-	fn mul(self, rhs: &InverseSolidAngle<num_bigfloat::BigFloat>) -> Self::Output {
-		InverseSolidAngle{per_sr: self.clone() * rhs.per_sr.clone()}
+/// Dividing a InverseSolidAngle by a InverseAngle returns a value of type InverseAngle
+impl<T> core::ops::Div<&InverseAngle<T>> for &InverseSolidAngle<T> where T: NumLike {
+	type Output = InverseAngle<T>;
+	fn div(self, rhs: &InverseAngle<T>) -> Self::Output {
+		InverseAngle{per_rad: self.per_sr.clone() / rhs.per_rad.clone()}
 	}
 }
 
-/// Multiplying a unit value by a scalar value returns a unit value
-#[cfg(feature="num-complex")]
-impl core::ops::Mul<InverseSolidAngle<num_complex::Complex32>> for num_complex::Complex32 {
-	type Output = InverseSolidAngle<num_complex::Complex32>;
-	fn mul(self, rhs: InverseSolidAngle<num_complex::Complex32>) -> Self::Output {
-		InverseSolidAngle{per_sr: self * rhs.per_sr}
+impl<T> InverseSolidAngle<T> where T: NumLike+From<u8> {
+	/// Returns the reciprocal of this InverseSolidAngle value (ie 1 divided by 
+	/// this value) as a value of type SolidAngle
+	pub fn recip(self) -> SolidAngle<T> {
+		SolidAngle{sr: T::from(1u8) / self.per_sr}
 	}
 }
-/// Multiplying a unit value by a scalar value returns a unit value
-#[cfg(feature="num-complex")]
-impl core::ops::Mul<InverseSolidAngle<num_complex::Complex32>> for &num_complex::Complex32 {
-	type Output = InverseSolidAngle<num_complex::Complex32>;
-	fn mul(self, rhs: InverseSolidAngle<num_complex::Complex32>) -> Self::Output {
-		InverseSolidAngle{per_sr: self.clone() * rhs.per_sr}
+
+// 1/InverseSolidAngle -> SolidAngle
+/// Dividing a scalar value by a InverseSolidAngle unit value returns a value of type SolidAngle
+impl<T> core::ops::Div<InverseSolidAngle<T>> for f64 where T: NumLike+From<f64> {
+	type Output = SolidAngle<T>;
+	fn div(self, rhs: InverseSolidAngle<T>) -> Self::Output {
+		SolidAngle{sr: T::from(self) / rhs.per_sr}
 	}
 }
-/// Multiplying a unit value by a scalar value returns a unit value
-#[cfg(feature="num-complex")]
-impl core::ops::Mul<&InverseSolidAngle<num_complex::Complex32>> for num_complex::Complex32 {
-	type Output = InverseSolidAngle<num_complex::Complex32>;
-	fn mul(self, rhs: &InverseSolidAngle<num_complex::Complex32>) -> Self::Output {
-		InverseSolidAngle{per_sr: self * rhs.per_sr.clone()}
+/// Dividing a scalar value by a InverseSolidAngle unit value returns a value of type SolidAngle
+impl<T> core::ops::Div<InverseSolidAngle<T>> for &f64 where T: NumLike+From<f64> {
+	type Output = SolidAngle<T>;
+	fn div(self, rhs: InverseSolidAngle<T>) -> Self::Output {
+		SolidAngle{sr: T::from(self.clone()) / rhs.per_sr}
 	}
 }
-/// Multiplying a unit value by a scalar value returns a unit value
-#[cfg(feature="num-complex")]
-impl core::ops::Mul<&InverseSolidAngle<num_complex::Complex32>> for &num_complex::Complex32 {
-	type Output = InverseSolidAngle<num_complex::Complex32>;
-	fn mul(self, rhs: &InverseSolidAngle<num_complex::Complex32>) -> Self::Output {
-		InverseSolidAngle{per_sr: self.clone() * rhs.per_sr.clone()}
+/// Dividing a scalar value by a InverseSolidAngle unit value returns a value of type SolidAngle
+impl<T> core::ops::Div<&InverseSolidAngle<T>> for f64 where T: NumLike+From<f64> {
+	type Output = SolidAngle<T>;
+	fn div(self, rhs: &InverseSolidAngle<T>) -> Self::Output {
+		SolidAngle{sr: T::from(self) / rhs.per_sr.clone()}
+	}
+}
+/// Dividing a scalar value by a InverseSolidAngle unit value returns a value of type SolidAngle
+impl<T> core::ops::Div<&InverseSolidAngle<T>> for &f64 where T: NumLike+From<f64> {
+	type Output = SolidAngle<T>;
+	fn div(self, rhs: &InverseSolidAngle<T>) -> Self::Output {
+		SolidAngle{sr: T::from(self.clone()) / rhs.per_sr.clone()}
 	}
 }
 
-/// Multiplying a unit value by a scalar value returns a unit value
-#[cfg(feature="num-complex")]
-impl core::ops::Mul<InverseSolidAngle<num_complex::Complex64>> for num_complex::Complex64 {
-	type Output = InverseSolidAngle<num_complex::Complex64>;
-	fn mul(self, rhs: InverseSolidAngle<num_complex::Complex64>) -> Self::Output {
-		InverseSolidAngle{per_sr: self * rhs.per_sr}
+// 1/InverseSolidAngle -> SolidAngle
+/// Dividing a scalar value by a InverseSolidAngle unit value returns a value of type SolidAngle
+impl<T> core::ops::Div<InverseSolidAngle<T>> for f32 where T: NumLike+From<f32> {
+	type Output = SolidAngle<T>;
+	fn div(self, rhs: InverseSolidAngle<T>) -> Self::Output {
+		SolidAngle{sr: T::from(self) / rhs.per_sr}
 	}
 }
-/// Multiplying a unit value by a scalar value returns a unit value
-#[cfg(feature="num-complex")]
-impl core::ops::Mul<InverseSolidAngle<num_complex::Complex64>> for &num_complex::Complex64 {
-	type Output = InverseSolidAngle<num_complex::Complex64>;
-	fn mul(self, rhs: InverseSolidAngle<num_complex::Complex64>) -> Self::Output {
-		InverseSolidAngle{per_sr: self.clone() * rhs.per_sr}
+/// Dividing a scalar value by a InverseSolidAngle unit value returns a value of type SolidAngle
+impl<T> core::ops::Div<InverseSolidAngle<T>> for &f32 where T: NumLike+From<f32> {
+	type Output = SolidAngle<T>;
+	fn div(self, rhs: InverseSolidAngle<T>) -> Self::Output {
+		SolidAngle{sr: T::from(self.clone()) / rhs.per_sr}
 	}
 }
-/// Multiplying a unit value by a scalar value returns a unit value
-#[cfg(feature="num-complex")]
-impl core::ops::Mul<&InverseSolidAngle<num_complex::Complex64>> for num_complex::Complex64 {
-	type Output = InverseSolidAngle<num_complex::Complex64>;
-	fn mul(self, rhs: &InverseSolidAngle<num_complex::Complex64>) -> Self::Output {
-		InverseSolidAngle{per_sr: self * rhs.per_sr.clone()}
+/// Dividing a scalar value by a InverseSolidAngle unit value returns a value of type SolidAngle
+impl<T> core::ops::Div<&InverseSolidAngle<T>> for f32 where T: NumLike+From<f32> {
+	type Output = SolidAngle<T>;
+	fn div(self, rhs: &InverseSolidAngle<T>) -> Self::Output {
+		SolidAngle{sr: T::from(self) / rhs.per_sr.clone()}
 	}
 }
-/// Multiplying a unit value by a scalar value returns a unit value
-#[cfg(feature="num-complex")]
-impl core::ops::Mul<&InverseSolidAngle<num_complex::Complex64>> for &num_complex::Complex64 {
-	type Output = InverseSolidAngle<num_complex::Complex64>;
-	fn mul(self, rhs: &InverseSolidAngle<num_complex::Complex64>) -> Self::Output {
-		InverseSolidAngle{per_sr: self.clone() * rhs.per_sr.clone()}
+/// Dividing a scalar value by a InverseSolidAngle unit value returns a value of type SolidAngle
+impl<T> core::ops::Div<&InverseSolidAngle<T>> for &f32 where T: NumLike+From<f32> {
+	type Output = SolidAngle<T>;
+	fn div(self, rhs: &InverseSolidAngle<T>) -> Self::Output {
+		SolidAngle{sr: T::from(self.clone()) / rhs.per_sr.clone()}
 	}
 }
 
-
-
-
-// InverseSolidAngle * InverseLuminosity -> InverseLuminousFlux
-/// Multiplying a InverseSolidAngle by a InverseLuminosity returns a value of type InverseLuminousFlux
-impl<T> core::ops::Mul<InverseLuminosity<T>> for InverseSolidAngle<T> where T: NumLike {
-	type Output = InverseLuminousFlux<T>;
-	fn mul(self, rhs: InverseLuminosity<T>) -> Self::Output {
-		InverseLuminousFlux{per_lm: self.per_sr * rhs.per_cd}
+// 1/InverseSolidAngle -> SolidAngle
+/// Dividing a scalar value by a InverseSolidAngle unit value returns a value of type SolidAngle
+impl<T> core::ops::Div<InverseSolidAngle<T>> for i64 where T: NumLike+From<i64> {
+	type Output = SolidAngle<T>;
+	fn div(self, rhs: InverseSolidAngle<T>) -> Self::Output {
+		SolidAngle{sr: T::from(self) / rhs.per_sr}
 	}
 }
-/// Multiplying a InverseSolidAngle by a InverseLuminosity returns a value of type InverseLuminousFlux
-impl<T> core::ops::Mul<InverseLuminosity<T>> for &InverseSolidAngle<T> where T: NumLike {
-	type Output = InverseLuminousFlux<T>;
-	fn mul(self, rhs: InverseLuminosity<T>) -> Self::Output {
-		InverseLuminousFlux{per_lm: self.per_sr.clone() * rhs.per_cd}
+/// Dividing a scalar value by a InverseSolidAngle unit value returns a value of type SolidAngle
+impl<T> core::ops::Div<InverseSolidAngle<T>> for &i64 where T: NumLike+From<i64> {
+	type Output = SolidAngle<T>;
+	fn div(self, rhs: InverseSolidAngle<T>) -> Self::Output {
+		SolidAngle{sr: T::from(self.clone()) / rhs.per_sr}
 	}
 }
-/// Multiplying a InverseSolidAngle by a InverseLuminosity returns a value of type InverseLuminousFlux
-impl<T> core::ops::Mul<&InverseLuminosity<T>> for InverseSolidAngle<T> where T: NumLike {
-	type Output = InverseLuminousFlux<T>;
-	fn mul(self, rhs: &InverseLuminosity<T>) -> Self::Output {
-		InverseLuminousFlux{per_lm: self.per_sr * rhs.per_cd.clone()}
+/// Dividing a scalar value by a InverseSolidAngle unit value returns a value of type SolidAngle
+impl<T> core::ops::Div<&InverseSolidAngle<T>> for i64 where T: NumLike+From<i64> {
+	type Output = SolidAngle<T>;
+	fn div(self, rhs: &InverseSolidAngle<T>) -> Self::Output {
+		SolidAngle{sr: T::from(self) / rhs.per_sr.clone()}
 	}
 }
-/// Multiplying a InverseSolidAngle by a InverseLuminosity returns a value of type InverseLuminousFlux
-impl<T> core::ops::Mul<&InverseLuminosity<T>> for &InverseSolidAngle<T> where T: NumLike {
-	type Output = InverseLuminousFlux<T>;
-	fn mul(self, rhs: &InverseLuminosity<T>) -> Self::Output {
-		InverseLuminousFlux{per_lm: self.per_sr.clone() * rhs.per_cd.clone()}
+/// Dividing a scalar value by a InverseSolidAngle unit value returns a value of type SolidAngle
+impl<T> core::ops::Div<&InverseSolidAngle<T>> for &i64 where T: NumLike+From<i64> {
+	type Output = SolidAngle<T>;
+	fn div(self, rhs: &InverseSolidAngle<T>) -> Self::Output {
+		SolidAngle{sr: T::from(self.clone()) / rhs.per_sr.clone()}
 	}
 }
 
-// InverseSolidAngle / Luminosity -> InverseLuminousFlux
-/// Dividing a InverseSolidAngle by a Luminosity returns a value of type InverseLuminousFlux
-impl<T> core::ops::Div<Luminosity<T>> for InverseSolidAngle<T> where T: NumLike {
-	type Output = InverseLuminousFlux<T>;
-	fn div(self, rhs: Luminosity<T>) -> Self::Output {
-		InverseLuminousFlux{per_lm: self.per_sr / rhs.cd}
+// 1/InverseSolidAngle -> SolidAngle
+/// Dividing a scalar value by a InverseSolidAngle unit value returns a value of type SolidAngle
+impl<T> core::ops::Div<InverseSolidAngle<T>> for i32 where T: NumLike+From<i32> {
+	type Output = SolidAngle<T>;
+	fn div(self, rhs: InverseSolidAngle<T>) -> Self::Output {
+		SolidAngle{sr: T::from(self) / rhs.per_sr}
 	}
 }
-/// Dividing a InverseSolidAngle by a Luminosity returns a value of type InverseLuminousFlux
-impl<T> core::ops::Div<Luminosity<T>> for &InverseSolidAngle<T> where T: NumLike {
-	type Output = InverseLuminousFlux<T>;
-	fn div(self, rhs: Luminosity<T>) -> Self::Output {
-		InverseLuminousFlux{per_lm: self.per_sr.clone() / rhs.cd}
+/// Dividing a scalar value by a InverseSolidAngle unit value returns a value of type SolidAngle
+impl<T> core::ops::Div<InverseSolidAngle<T>> for &i32 where T: NumLike+From<i32> {
+	type Output = SolidAngle<T>;
+	fn div(self, rhs: InverseSolidAngle<T>) -> Self::Output {
+		SolidAngle{sr: T::from(self.clone()) / rhs.per_sr}
 	}
 }
-/// Dividing a InverseSolidAngle by a Luminosity returns a value of type InverseLuminousFlux
-impl<T> core::ops::Div<&Luminosity<T>> for InverseSolidAngle<T> where T: NumLike {
-	type Output = InverseLuminousFlux<T>;
-	fn div(self, rhs: &Luminosity<T>) -> Self::Output {
-		InverseLuminousFlux{per_lm: self.per_sr / rhs.cd.clone()}
+/// Dividing a scalar value by a InverseSolidAngle unit value returns a value of type SolidAngle
+impl<T> core::ops::Div<&InverseSolidAngle<T>> for i32 where T: NumLike+From<i32> {
+	type Output = SolidAngle<T>;
+	fn div(self, rhs: &InverseSolidAngle<T>) -> Self::Output {
+		SolidAngle{sr: T::from(self) / rhs.per_sr.clone()}
 	}
 }
-/// Dividing a InverseSolidAngle by a Luminosity returns a value of type InverseLuminousFlux
-impl<T> core::ops::Div<&Luminosity<T>> for &InverseSolidAngle<T> where T: NumLike {
-	type Output = InverseLuminousFlux<T>;
-	fn div(self, rhs: &Luminosity<T>) -> Self::Output {
-		InverseLuminousFlux{per_lm: self.per_sr.clone() / rhs.cd.clone()}
+/// Dividing a scalar value by a InverseSolidAngle unit value returns a value of type SolidAngle
+impl<T> core::ops::Div<&InverseSolidAngle<T>> for &i32 where T: NumLike+From<i32> {
+	type Output = SolidAngle<T>;
+	fn div(self, rhs: &InverseSolidAngle<T>) -> Self::Output {
+		SolidAngle{sr: T::from(self.clone()) / rhs.per_sr.clone()}
 	}
 }
 
-// InverseSolidAngle / InverseLuminousFlux -> Luminosity
-/// Dividing a InverseSolidAngle by a InverseLuminousFlux returns a value of type Luminosity
-impl<T> core::ops::Div<InverseLuminousFlux<T>> for InverseSolidAngle<T> where T: NumLike {
-	type Output = Luminosity<T>;
-	fn div(self, rhs: InverseLuminousFlux<T>) -> Self::Output {
-		Luminosity{cd: self.per_sr / rhs.per_lm}
+// 1/InverseSolidAngle -> SolidAngle
+/// Dividing a scalar value by a InverseSolidAngle unit value returns a value of type SolidAngle
+#[cfg(feature="num-bigfloat")]
+impl<T> core::ops::Div<InverseSolidAngle<T>> for num_bigfloat::BigFloat where T: NumLike+From<num_bigfloat::BigFloat> {
+	type Output = SolidAngle<T>;
+	fn div(self, rhs: InverseSolidAngle<T>) -> Self::Output {
+		SolidAngle{sr: T::from(self) / rhs.per_sr}
 	}
 }
-/// Dividing a InverseSolidAngle by a InverseLuminousFlux returns a value of type Luminosity
-impl<T> core::ops::Div<InverseLuminousFlux<T>> for &InverseSolidAngle<T> where T: NumLike {
-	type Output = Luminosity<T>;
-	fn div(self, rhs: InverseLuminousFlux<T>) -> Self::Output {
-		Luminosity{cd: self.per_sr.clone() / rhs.per_lm}
+/// Dividing a scalar value by a InverseSolidAngle unit value returns a value of type SolidAngle
+#[cfg(feature="num-bigfloat")]
+impl<T> core::ops::Div<InverseSolidAngle<T>> for &num_bigfloat::BigFloat where T: NumLike+From<num_bigfloat::BigFloat> {
+	type Output = SolidAngle<T>;
+	fn div(self, rhs: InverseSolidAngle<T>) -> Self::Output {
+		SolidAngle{sr: T::from(self.clone()) / rhs.per_sr}
 	}
 }
-/// Dividing a InverseSolidAngle by a InverseLuminousFlux returns a value of type Luminosity
-impl<T> core::ops::Div<&InverseLuminousFlux<T>> for InverseSolidAngle<T> where T: NumLike {
-	type Output = Luminosity<T>;
-	fn div(self, rhs: &InverseLuminousFlux<T>) -> Self::Output {
-		Luminosity{cd: self.per_sr / rhs.per_lm.clone()}
+/// Dividing a scalar value by a InverseSolidAngle unit value returns a value of type SolidAngle
+#[cfg(feature="num-bigfloat")]
+impl<T> core::ops::Div<&InverseSolidAngle<T>> for num_bigfloat::BigFloat where T: NumLike+From<num_bigfloat::BigFloat> {
+	type Output = SolidAngle<T>;
+	fn div(self, rhs: &InverseSolidAngle<T>) -> Self::Output {
+		SolidAngle{sr: T::from(self) / rhs.per_sr.clone()}
 	}
 }
-/// Dividing a InverseSolidAngle by a InverseLuminousFlux returns a value of type Luminosity
-impl<T> core::ops::Div<&InverseLuminousFlux<T>> for &InverseSolidAngle<T> where T: NumLike {
-	type Output = Luminosity<T>;
-	fn div(self, rhs: &InverseLuminousFlux<T>) -> Self::Output {
-		Luminosity{cd: self.per_sr.clone() / rhs.per_lm.clone()}
+/// Dividing a scalar value by a InverseSolidAngle unit value returns a value of type SolidAngle
+#[cfg(feature="num-bigfloat")]
+impl<T> core::ops::Div<&InverseSolidAngle<T>> for &num_bigfloat::BigFloat where T: NumLike+From<num_bigfloat::BigFloat> {
+	type Output = SolidAngle<T>;
+	fn div(self, rhs: &InverseSolidAngle<T>) -> Self::Output {
+		SolidAngle{sr: T::from(self.clone()) / rhs.per_sr.clone()}
 	}
 }
 
-// InverseSolidAngle * LuminousFlux -> Luminosity
-/// Multiplying a InverseSolidAngle by a LuminousFlux returns a value of type Luminosity
-impl<T> core::ops::Mul<LuminousFlux<T>> for InverseSolidAngle<T> where T: NumLike {
-	type Output = Luminosity<T>;
-	fn mul(self, rhs: LuminousFlux<T>) -> Self::Output {
-		Luminosity{cd: self.per_sr * rhs.lm}
+// 1/InverseSolidAngle -> SolidAngle
+/// Dividing a scalar value by a InverseSolidAngle unit value returns a value of type SolidAngle
+#[cfg(feature="num-complex")]
+impl<T> core::ops::Div<InverseSolidAngle<T>> for num_complex::Complex32 where T: NumLike+From<num_complex::Complex32> {
+	type Output = SolidAngle<T>;
+	fn div(self, rhs: InverseSolidAngle<T>) -> Self::Output {
+		SolidAngle{sr: T::from(self) / rhs.per_sr}
 	}
 }
-/// Multiplying a InverseSolidAngle by a LuminousFlux returns a value of type Luminosity
-impl<T> core::ops::Mul<LuminousFlux<T>> for &InverseSolidAngle<T> where T: NumLike {
-	type Output = Luminosity<T>;
-	fn mul(self, rhs: LuminousFlux<T>) -> Self::Output {
-		Luminosity{cd: self.per_sr.clone() * rhs.lm}
+/// Dividing a scalar value by a InverseSolidAngle unit value returns a value of type SolidAngle
+#[cfg(feature="num-complex")]
+impl<T> core::ops::Div<InverseSolidAngle<T>> for &num_complex::Complex32 where T: NumLike+From<num_complex::Complex32> {
+	type Output = SolidAngle<T>;
+	fn div(self, rhs: InverseSolidAngle<T>) -> Self::Output {
+		SolidAngle{sr: T::from(self.clone()) / rhs.per_sr}
 	}
 }
-/// Multiplying a InverseSolidAngle by a LuminousFlux returns a value of type Luminosity
-impl<T> core::ops::Mul<&LuminousFlux<T>> for InverseSolidAngle<T> where T: NumLike {
-	type Output = Luminosity<T>;
-	fn mul(self, rhs: &LuminousFlux<T>) -> Self::Output {
-		Luminosity{cd: self.per_sr * rhs.lm.clone()}
+/// Dividing a scalar value by a InverseSolidAngle unit value returns a value of type SolidAngle
+#[cfg(feature="num-complex")]
+impl<T> core::ops::Div<&InverseSolidAngle<T>> for num_complex::Complex32 where T: NumLike+From<num_complex::Complex32> {
+	type Output = SolidAngle<T>;
+	fn div(self, rhs: &InverseSolidAngle<T>) -> Self::Output {
+		SolidAngle{sr: T::from(self) / rhs.per_sr.clone()}
 	}
 }
-/// Multiplying a InverseSolidAngle by a LuminousFlux returns a value of type Luminosity
-impl<T> core::ops::Mul<&LuminousFlux<T>> for &InverseSolidAngle<T> where T: NumLike {
-	type Output = Luminosity<T>;
-	fn mul(self, rhs: &LuminousFlux<T>) -> Self::Output {
-		Luminosity{cd: self.per_sr.clone() * rhs.lm.clone()}
+/// Dividing a scalar value by a InverseSolidAngle unit value returns a value of type SolidAngle
+#[cfg(feature="num-complex")]
+impl<T> core::ops::Div<&InverseSolidAngle<T>> for &num_complex::Complex32 where T: NumLike+From<num_complex::Complex32> {
+	type Output = SolidAngle<T>;
+	fn div(self, rhs: &InverseSolidAngle<T>) -> Self::Output {
+		SolidAngle{sr: T::from(self.clone()) / rhs.per_sr.clone()}
 	}
 }
 
-// InverseSolidAngle * Angle -> InverseAngle
-/// Multiplying a InverseSolidAngle by a Angle returns a value of type InverseAngle
-impl<T> core::ops::Mul<Angle<T>> for InverseSolidAngle<T> where T: NumLike {
-	type Output = InverseAngle<T>;
-	fn mul(self, rhs: Angle<T>) -> Self::Output {
-		InverseAngle{per_rad: self.per_sr * rhs.rad}
+// 1/InverseSolidAngle -> SolidAngle
+/// Dividing a scalar value by a InverseSolidAngle unit value returns a value of type SolidAngle
+#[cfg(feature="num-complex")]
+impl<T> core::ops::Div<InverseSolidAngle<T>> for num_complex::Complex64 where T: NumLike+From<num_complex::Complex64> {
+	type Output = SolidAngle<T>;
+	fn div(self, rhs: InverseSolidAngle<T>) -> Self::Output {
+		SolidAngle{sr: T::from(self) / rhs.per_sr}
 	}
 }
-/// Multiplying a InverseSolidAngle by a Angle returns a value of type InverseAngle
-impl<T> core::ops::Mul<Angle<T>> for &InverseSolidAngle<T> where T: NumLike {
-	type Output = InverseAngle<T>;
-	fn mul(self, rhs: Angle<T>) -> Self::Output {
-		InverseAngle{per_rad: self.per_sr.clone() * rhs.rad}
+/// Dividing a scalar value by a InverseSolidAngle unit value returns a value of type SolidAngle
+#[cfg(feature="num-complex")]
+impl<T> core::ops::Div<InverseSolidAngle<T>> for &num_complex::Complex64 where T: NumLike+From<num_complex::Complex64> {
+	type Output = SolidAngle<T>;
+	fn div(self, rhs: InverseSolidAngle<T>) -> Self::Output {
+		SolidAngle{sr: T::from(self.clone()) / rhs.per_sr}
 	}
 }
-/// Multiplying a InverseSolidAngle by a Angle returns a value of type InverseAngle
-impl<T> core::ops::Mul<&Angle<T>> for InverseSolidAngle<T> where T: NumLike {
-	type Output = InverseAngle<T>;
-	fn mul(self, rhs: &Angle<T>) -> Self::Output {
-		InverseAngle{per_rad: self.per_sr * rhs.rad.clone()}
+/// Dividing a scalar value by a InverseSolidAngle unit value returns a value of type SolidAngle
+#[cfg(feature="num-complex")]
+impl<T> core::ops::Div<&InverseSolidAngle<T>> for num_complex::Complex64 where T: NumLike+From<num_complex::Complex64> {
+	type Output = SolidAngle<T>;
+	fn div(self, rhs: &InverseSolidAngle<T>) -> Self::Output {
+		SolidAngle{sr: T::from(self) / rhs.per_sr.clone()}
 	}
 }
-/// Multiplying a InverseSolidAngle by a Angle returns a value of type InverseAngle
-impl<T> core::ops::Mul<&Angle<T>> for &InverseSolidAngle<T> where T: NumLike {
-	type Output = InverseAngle<T>;
-	fn mul(self, rhs: &Angle<T>) -> Self::Output {
-		InverseAngle{per_rad: self.per_sr.clone() * rhs.rad.clone()}
+/// Dividing a scalar value by a InverseSolidAngle unit value returns a value of type SolidAngle
+#[cfg(feature="num-complex")]
+impl<T> core::ops::Div<&InverseSolidAngle<T>> for &num_complex::Complex64 where T: NumLike+From<num_complex::Complex64> {
+	type Output = SolidAngle<T>;
+	fn div(self, rhs: &InverseSolidAngle<T>) -> Self::Output {
+		SolidAngle{sr: T::from(self.clone()) / rhs.per_sr.clone()}
 	}
 }
 
-// InverseSolidAngle / InverseAngle -> InverseAngle
-/// Dividing a InverseSolidAngle by a InverseAngle returns a value of type InverseAngle
-impl<T> core::ops::Div<InverseAngle<T>> for InverseSolidAngle<T> where T: NumLike {
-	type Output = InverseAngle<T>;
-	fn div(self, rhs: InverseAngle<T>) -> Self::Output {
-		InverseAngle{per_rad: self.per_sr / rhs.per_rad}
+// 1/InverseSolidAngle -> SolidAngle
+/// Dividing a scalar value by a InverseSolidAngle unit value returns a value of type SolidAngle
+#[cfg(feature="num-rational")]
+impl<T> core::ops::Div<InverseSolidAngle<T>> for num_rational::Rational64 where T: NumLike+From<num_rational::Rational64> {
+	type Output = SolidAngle<T>;
+	fn div(self, rhs: InverseSolidAngle<T>) -> Self::Output {
+		SolidAngle{sr: T::from(self) / rhs.per_sr}
 	}
 }
-/// Dividing a InverseSolidAngle by a InverseAngle returns a value of type InverseAngle
-impl<T> core::ops::Div<InverseAngle<T>> for &InverseSolidAngle<T> where T: NumLike {
-	type Output = InverseAngle<T>;
-	fn div(self, rhs: InverseAngle<T>) -> Self::Output {
-		InverseAngle{per_rad: self.per_sr.clone() / rhs.per_rad}
+/// Dividing a scalar value by a InverseSolidAngle unit value returns a value of type SolidAngle
+#[cfg(feature="num-rational")]
+impl<T> core::ops::Div<InverseSolidAngle<T>> for &num_rational::Rational64 where T: NumLike+From<num_rational::Rational64> {
+	type Output = SolidAngle<T>;
+	fn div(self, rhs: InverseSolidAngle<T>) -> Self::Output {
+		SolidAngle{sr: T::from(self.clone()) / rhs.per_sr}
 	}
 }
-/// Dividing a InverseSolidAngle by a InverseAngle returns a value of type InverseAngle
-impl<T> core::ops::Div<&InverseAngle<T>> for InverseSolidAngle<T> where T: NumLike {
-	type Output = InverseAngle<T>;
-	fn div(self, rhs: &InverseAngle<T>) -> Self::Output {
-		InverseAngle{per_rad: self.per_sr / rhs.per_rad.clone()}
+/// Dividing a scalar value by a InverseSolidAngle unit value returns a value of type SolidAngle
+#[cfg(feature="num-rational")]
+impl<T> core::ops::Div<&InverseSolidAngle<T>> for num_rational::Rational64 where T: NumLike+From<num_rational::Rational64> {
+	type Output = SolidAngle<T>;
+	fn div(self, rhs: &InverseSolidAngle<T>) -> Self::Output {
+		SolidAngle{sr: T::from(self) / rhs.per_sr.clone()}
 	}
 }
-/// Dividing a InverseSolidAngle by a InverseAngle returns a value of type InverseAngle
-impl<T> core::ops::Div<&InverseAngle<T>> for &InverseSolidAngle<T> where T: NumLike {
-	type Output = InverseAngle<T>;
-	fn div(self, rhs: &InverseAngle<T>) -> Self::Output {
-		InverseAngle{per_rad: self.per_sr.clone() / rhs.per_rad.clone()}
+/// Dividing a scalar value by a InverseSolidAngle unit value returns a value of type SolidAngle
+#[cfg(feature="num-rational")]
+impl<T> core::ops::Div<&InverseSolidAngle<T>> for &num_rational::Rational64 where T: NumLike+From<num_rational::Rational64> {
+	type Output = SolidAngle<T>;
+	fn div(self, rhs: &InverseSolidAngle<T>) -> Self::Output {
+		SolidAngle{sr: T::from(self.clone()) / rhs.per_sr.clone()}
 	}
 }
 
 // 1/InverseSolidAngle -> SolidAngle
 /// Dividing a scalar value by a InverseSolidAngle unit value returns a value of type SolidAngle
-impl<T> core::ops::Div<InverseSolidAngle<T>> for f64 where T: NumLike+From<f64> {
+#[cfg(feature="num-rational")]
+impl<T> core::ops::Div<InverseSolidAngle<T>> for num_rational::BigRational where T: NumLike+From<num_rational::BigRational> {
 	type Output = SolidAngle<T>;
 	fn div(self, rhs: InverseSolidAngle<T>) -> Self::Output {
 		SolidAngle{sr: T::from(self) / rhs.per_sr}
 	}
 }
 /// Dividing a scalar value by a InverseSolidAngle unit value returns a value of type SolidAngle
-impl<T> core::ops::Div<InverseSolidAngle<T>> for &f64 where T: NumLike+From<f64> {
+#[cfg(feature="num-rational")]
+impl<T> core::ops::Div<InverseSolidAngle<T>> for &num_rational::BigRational where T: NumLike+From<num_rational::BigRational> {
 	type Output = SolidAngle<T>;
 	fn div(self, rhs: InverseSolidAngle<T>) -> Self::Output {
 		SolidAngle{sr: T::from(self.clone()) / rhs.per_sr}
 	}
 }
 /// Dividing a scalar value by a InverseSolidAngle unit value returns a value of type SolidAngle
-impl<T> core::ops::Div<&InverseSolidAngle<T>> for f64 where T: NumLike+From<f64> {
+#[cfg(feature="num-rational")]
+impl<T> core::ops::Div<&InverseSolidAngle<T>> for num_rational::BigRational where T: NumLike+From<num_rational::BigRational> {
 	type Output = SolidAngle<T>;
 	fn div(self, rhs: &InverseSolidAngle<T>) -> Self::Output {
 		SolidAngle{sr: T::from(self) / rhs.per_sr.clone()}
 	}
 }
-/// Dividing a scalar value by a InverseSolidAngle unit value returns a value of type SolidAngle
-impl<T> core::ops::Div<&InverseSolidAngle<T>> for &f64 where T: NumLike+From<f64> {
-	type Output = SolidAngle<T>;
-	fn div(self, rhs: &InverseSolidAngle<T>) -> Self::Output {
-		SolidAngle{sr: T::from(self.clone()) / rhs.per_sr.clone()}
+/// Dividing a scalar value by a InverseSolidAngle unit value returns a value of type SolidAngle
+#[cfg(feature="num-rational")]
+impl<T> core::ops::Div<&InverseSolidAngle<T>> for &num_rational::BigRational where T: NumLike+From<num_rational::BigRational> {
+	type Output = SolidAngle<T>;
+	fn div(self, rhs: &InverseSolidAngle<T>) -> Self::Output {
+		SolidAngle{sr: T::from(self.clone()) / rhs.per_sr.clone()}
+	}
+}
+
+/// The inverse of volume unit type, defined as inverse cubic meters in SI units
+#[derive(UnitStruct, Debug, Clone, Default)]
+#[si(no_display)]
+#[cfg_attr(all(feature="serde", not(feature="serde-unit-tag")), derive(Serialize, Deserialize))]
+#[repr(transparent)]
+pub struct InverseVolume<T: NumLike>{
+	/// The value of this Inverse volume in inverse cubic meters
+	pub per_m3: T
+}
+
+impl<T> InverseVolume<T> where T: NumLike {
+
+	/// Returns the standard unit name of inverse volume: "inverse cubic meters"
+	pub fn unit_name() -> &'static str { "inverse cubic meters" }
+	
+	/// Returns the abbreviated name or symbol of inverse volume: "1/m³" for inverse cubic meters
+	pub fn unit_symbol() -> &'static str { "1/m³" }
+
+	/// Returns the exponents of the SI base units of inverse volume (1/m^3)
+	pub const fn dimension() -> super::Dimension { super::Dimension{length: -3, mass: 0, time: 0, current: 0, temperature: 0, amount: 0, luminosity: 0} }
+
+	/// Returns a wrapper that formats this inverse volume value with the full unit name instead of 
+	/// the unit symbol, eg "5 inverse cubic meters" (the unit name is not singularized for values of 1)
+	pub fn display_named(&self) -> super::NamedDisplay<'_, T> {
+		super::NamedDisplay::new(&self.per_m3, Self::unit_name())
+	}
+
+	/// Returns a new inverse volume value from the given number of inverse cubic meters (same as 
+	/// `from_per_m3(...)`), for any number-like type. This is useful in generic code 
+	/// that works with several unit types
+	///
+	/// # Arguments
+	/// * `value` - Any number-like type, representing a quantity of inverse cubic meters
+	pub const fn new(value: T) -> Self { InverseVolume{per_m3: value} }
+
+	/// Returns a new inverse volume value from the given number of inverse cubic meters. Unlike
+	/// `from_per_m3(...)`, this is a `const fn`, so it can be used to declare `const` and `static` values
+	///
+	/// # Arguments
+	/// * `per_m3` - Any number-like type, representing a quantity of inverse cubic meters
+	pub const fn from_per_m3_const(per_m3: T) -> Self { InverseVolume{per_m3} }
+
+	/// Returns a inverse volume value with a different number type, by applying the 
+	/// given function to the value in inverse cubic meters (eg `.map(|x| x as f32)` to convert 
+	/// from `f64` to `f32`)
+	///
+	/// # Arguments
+	/// * `f` - Function that converts the number of inverse cubic meters to the new number type
+	pub fn map<U, F>(self, f: F) -> InverseVolume<U> where U: NumLike, F: FnOnce(T) -> U {
+		InverseVolume{per_m3: f(self.per_m3)}
+	}
+
+	/// Returns a inverse volume value with a different number type, converted 
+	/// losslessly with the `From` trait (eg from `f32` to `f64`)
+	pub fn cast<U>(self) -> InverseVolume<U> where U: NumLike+From<T> {
+		InverseVolume{per_m3: U::from(self.per_m3)}
+	}
+
+	/// Returns a reference to the value of this inverse volume in inverse cubic meters
+	pub fn as_raw(&self) -> &T {
+		&self.per_m3
+	}
+
+	/// Returns a mutable reference to the value of this inverse volume in inverse cubic meters, 
+	/// eg for passing to numeric libraries that modify values in place. The number 
+	/// is always in inverse cubic meters, so changing it directly does not change the unit
+	pub fn raw_mut(&mut self) -> &mut T {
+		&mut self.per_m3
+	}
+
+	/// Returns a slice of numbers in inverse cubic meters as a slice of inverse volume values, 
+	/// without copying
+	///
+	/// # Arguments
+	/// * `values` - Slice of numbers, representing quantities of inverse cubic meters
+	pub fn wrap_slice(values: &[T]) -> &[Self] {
+		// SAFETY: InverseVolume<T> is #[repr(transparent)], so it has the same memory layout as T
+		unsafe { core::slice::from_raw_parts(values.as_ptr() as *const Self, values.len()) }
+	}
+
+	/// Returns a mutable slice of numbers in inverse cubic meters as a mutable slice of 
+	/// inverse volume values, without copying
+	///
+	/// # Arguments
+	/// * `values` - Slice of numbers, representing quantities of inverse cubic meters
+	pub fn wrap_slice_mut(values: &mut [T]) -> &mut [Self] {
+		// SAFETY: InverseVolume<T> is #[repr(transparent)], so it has the same memory layout as T
+		unsafe { core::slice::from_raw_parts_mut(values.as_mut_ptr() as *mut Self, values.len()) }
+	}
+
+	/// Returns the ratio of this inverse volume value to another inverse volume value 
+	/// (same as `self / other`). Dividing a unit value by another value of the same 
+	/// unit type cancels the units, so the ratio is a dimensionless number
+	///
+	/// # Arguments
+	/// * `other` - The inverse volume value to divide by
+	pub fn ratio(self, other: Self) -> T {
+		self.per_m3 / other.per_m3
+	}
+
+	/// Returns the ratio of this inverse volume value to another inverse volume value 
+	/// as a `Dimensionless` value, instead of a bare number like `ratio(...)` does
+	///
+	/// # Arguments
+	/// * `other` - The inverse volume value to divide by
+	pub fn dimensionless_ratio(self, other: Self) -> super::base::Dimensionless<T> {
+		super::base::Dimensionless{value: self.per_m3 / other.per_m3}
+	}
+
+	/// Returns this inverse volume value multiplied by a dimensionless factor 
+	/// (same as `self * factor`)
+	///
+	/// # Arguments
+	/// * `factor` - The number to multiply by
+	pub fn scale(self, factor: T) -> Self {
+		InverseVolume{per_m3: self.per_m3 * factor}
+	}
+	
+	/// Returns a new inverse volume value from the given number of inverse cubic meters
+	///
+	/// # Arguments
+	/// * `per_m3` - Any number-like type, representing a quantity of inverse cubic meters
+	pub fn from_per_m3(per_m3: T) -> Self { InverseVolume{per_m3: per_m3} }
+	
+	/// Returns a copy of this inverse volume value in inverse cubic meters
+	pub fn to_per_m3(&self) -> T { self.per_m3.clone() }
+
+	/// Returns a new inverse volume value from the given number of inverse cubic meters
+	///
+	/// # Arguments
+	/// * `per_cubic_meter` - Any number-like type, representing a quantity of inverse cubic meters
+	pub fn from_per_cubic_meter(per_cubic_meter: T) -> Self { InverseVolume{per_m3: per_cubic_meter} }
+	
+	/// Returns a copy of this inverse volume value in inverse cubic meters
+	pub fn to_per_cubic_meter(&self) -> T { self.per_m3.clone() }
+
+	/// Returns a new inverse volume value from the given number of inverse kiloliters
+	///
+	/// # Arguments
+	/// * `per_kL` - Any number-like type, representing a quantity of inverse cubic meters
+	pub fn from_per_kL(per_kL: T) -> Self { InverseVolume{per_m3: per_kL} }
+	
+	/// Returns a copy of this inverse volume value in inverse kiloliters
+	pub fn to_per_kL(&self) -> T { self.per_m3.clone() }
+
+}
+
+/// Formats inverse volume values as a number followed by its unit symbol, eg "1500 1/m³"
+/// (complex numbers are wrapped in parentheses, eg "(1+2i) 1/m³").
+/// The alternate flag (`{:#}`) scales the number by the nearest SI prefix where the unit symbol allows it
+impl<T> fmt::Display for InverseVolume<T> where T: NumLike {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		if f.alternate() {
+			return super::fmt_with_si_prefix(f, &self.per_m3, Self::unit_symbol(), false);
+		}
+		super::fmt_unit_value(f, &self.per_m3, Self::unit_symbol())
+	}
+}
+
+/// Formats inverse volume values in exponential notation followed by the unit symbol, eg "1.5e3 1/m³"
+impl<T> fmt::LowerExp for InverseVolume<T> where T: NumLike+fmt::LowerExp {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		fmt::LowerExp::fmt(&self.per_m3, f)?;
+		write!(f, " {}", Self::unit_symbol())
 	}
 }
 
-// 1/InverseSolidAngle -> SolidAngle
-/// Dividing a scalar value by a InverseSolidAngle unit value returns a value of type SolidAngle
-impl<T> core::ops::Div<InverseSolidAngle<T>> for f32 where T: NumLike+From<f32> {
-	type Output = SolidAngle<T>;
-	fn div(self, rhs: InverseSolidAngle<T>) -> Self::Output {
-		SolidAngle{sr: T::from(self) / rhs.per_sr}
+/// Formats inverse volume values in exponential notation followed by the unit symbol, eg "1.5E3 1/m³"
+impl<T> fmt::UpperExp for InverseVolume<T> where T: NumLike+fmt::UpperExp {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		fmt::UpperExp::fmt(&self.per_m3, f)?;
+		write!(f, " {}", Self::unit_symbol())
 	}
 }
-/// Dividing a scalar value by a InverseSolidAngle unit value returns a value of type SolidAngle
-impl<T> core::ops::Div<InverseSolidAngle<T>> for &f32 where T: NumLike+From<f32> {
-	type Output = SolidAngle<T>;
-	fn div(self, rhs: InverseSolidAngle<T>) -> Self::Output {
-		SolidAngle{sr: T::from(self.clone()) / rhs.per_sr}
+
+impl<T> super::SiUnit for InverseVolume<T> where T: NumLike {
+	type Number = T;
+	fn unit_name(&self) -> &'static str { Self::unit_name() }
+	fn unit_symbol(&self) -> &'static str { Self::unit_symbol() }
+	fn raw(&self) -> &T { &self.per_m3 }
+	fn dimension(&self) -> super::Dimension { Self::dimension() }
+	fn from_raw(value: T) -> Self { InverseVolume{per_m3: value} }
+	fn into_raw(self) -> T { self.per_m3 }
+}
+
+#[cfg(feature="alloc")]
+impl<T> InverseVolume<T> where T: NumLike {
+	/// Returns this inverse volume value in the canonical text format "<value> <symbol>", eg "5 1/m³".
+	/// Unlike `Display`, this format never changes with precision or alternate flags, and it is the
+	/// input format expected by `from_str(...)`, so `x.canonical_string().parse()` returns the same value
+	pub fn canonical_string(&self) -> alloc::string::String {
+		alloc::format!("{} {}", self.per_m3, Self::unit_symbol())
+	}
+
+	/// Returns a `Vec` of inverse volume values copied from the given slice of numbers
+	///
+	/// # Arguments
+	/// * `values` - Slice of numbers, representing quantities of inverse cubic meters
+	pub fn from_per_m3_slice(values: &[T]) -> alloc::vec::Vec<Self> {
+		values.iter().map(|x| InverseVolume{per_m3: x.clone()}).collect()
 	}
 }
-/// Dividing a scalar value by a InverseSolidAngle unit value returns a value of type SolidAngle
-impl<T> core::ops::Div<&InverseSolidAngle<T>> for f32 where T: NumLike+From<f32> {
-	type Output = SolidAngle<T>;
-	fn div(self, rhs: &InverseSolidAngle<T>) -> Self::Output {
-		SolidAngle{sr: T::from(self) / rhs.per_sr.clone()}
+
+/// Parses a inverse volume value from a number followed by its unit symbol, eg "5 1/m³"
+impl<T> core::str::FromStr for InverseVolume<T> where T: NumLike+core::str::FromStr {
+	type Err = SiParseError;
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		Ok(InverseVolume{per_m3: super::parse_unit_value(s, Self::unit_symbol())?})
 	}
 }
-/// Dividing a scalar value by a InverseSolidAngle unit value returns a value of type SolidAngle
-impl<T> core::ops::Div<&InverseSolidAngle<T>> for &f32 where T: NumLike+From<f32> {
-	type Output = SolidAngle<T>;
-	fn div(self, rhs: &InverseSolidAngle<T>) -> Self::Output {
-		SolidAngle{sr: T::from(self.clone()) / rhs.per_sr.clone()}
+
+/// Parses a inverse volume value from a number followed by its unit symbol, eg "5 1/m³" 
+/// (same as `str::parse()`)
+impl<T> core::convert::TryFrom<&str> for InverseVolume<T> where T: NumLike+core::str::FromStr {
+	type Error = SiParseError;
+	fn try_from(s: &str) -> Result<Self, Self::Error> {
+		<Self as core::str::FromStr>::from_str(s)
 	}
 }
 
-// 1/InverseSolidAngle -> SolidAngle
-/// Dividing a scalar value by a InverseSolidAngle unit value returns a value of type SolidAngle
-impl<T> core::ops::Div<InverseSolidAngle<T>> for i64 where T: NumLike+From<i64> {
-	type Output = SolidAngle<T>;
-	fn div(self, rhs: InverseSolidAngle<T>) -> Self::Output {
-		SolidAngle{sr: T::from(self) / rhs.per_sr}
+#[cfg(feature="num-traits")]
+impl<T> InverseVolume<T> where T: NumLike+num_traits::Euclid {
+
+	/// Returns the Euclidean remainder of dividing this inverse volume value by another, 
+	/// which is never negative (requires the `num-traits` feature)
+	///
+	/// # Arguments
+	/// * `rhs` - The inverse volume value to divide by
+	pub fn rem_euclid(self, rhs: Self) -> Self {
+		InverseVolume{per_m3: self.per_m3.rem_euclid(&rhs.per_m3)}
+	}
+
+	/// Returns the Euclidean quotient of dividing this inverse volume value by another, 
+	/// ie the whole number of times that `rhs` fits into this value, rounded such that 
+	/// `rem_euclid(...)` is never negative (requires the `num-traits` feature)
+	///
+	/// # Arguments
+	/// * `rhs` - The inverse volume value to divide by
+	pub fn div_euclid(self, rhs: Self) -> T {
+		self.per_m3.div_euclid(&rhs.per_m3)
 	}
 }
-/// Dividing a scalar value by a InverseSolidAngle unit value returns a value of type SolidAngle
-impl<T> core::ops::Div<InverseSolidAngle<T>> for &i64 where T: NumLike+From<i64> {
-	type Output = SolidAngle<T>;
-	fn div(self, rhs: InverseSolidAngle<T>) -> Self::Output {
-		SolidAngle{sr: T::from(self.clone()) / rhs.per_sr}
+
+/// Summing inverse volume values returns their total as a inverse volume value (or zero if there are no values)
+impl<T> core::iter::Sum<InverseVolume<T>> for InverseVolume<T> where T: NumLike+core::iter::Sum {
+	fn sum<I: Iterator<Item=InverseVolume<T>>>(iter: I) -> Self {
+		InverseVolume{per_m3: iter.map(|x| x.per_m3).sum()}
 	}
 }
-/// Dividing a scalar value by a InverseSolidAngle unit value returns a value of type SolidAngle
-impl<T> core::ops::Div<&InverseSolidAngle<T>> for i64 where T: NumLike+From<i64> {
-	type Output = SolidAngle<T>;
-	fn div(self, rhs: &InverseSolidAngle<T>) -> Self::Output {
-		SolidAngle{sr: T::from(self) / rhs.per_sr.clone()}
+/// Summing inverse volume values returns their total as a inverse volume value (or zero if there are no values)
+impl<'a, T> core::iter::Sum<&'a InverseVolume<T>> for InverseVolume<T> where T: NumLike+core::iter::Sum+'a {
+	fn sum<I: Iterator<Item=&'a InverseVolume<T>>>(iter: I) -> Self {
+		InverseVolume{per_m3: iter.map(|x| x.per_m3.clone()).sum()}
 	}
 }
-/// Dividing a scalar value by a InverseSolidAngle unit value returns a value of type SolidAngle
-impl<T> core::ops::Div<&InverseSolidAngle<T>> for &i64 where T: NumLike+From<i64> {
-	type Output = SolidAngle<T>;
-	fn div(self, rhs: &InverseSolidAngle<T>) -> Self::Output {
-		SolidAngle{sr: T::from(self.clone()) / rhs.per_sr.clone()}
+
+impl<T> InverseVolume<T> where T: NumLike+From<f64> {
+
+	/// Returns the weighted average of the given inverse volume values, where each 
+	/// value is paired with its weight (weights do not need to add up to 1). If the 
+	/// total weight is zero (including when `values` is empty), then zero is returned
+	///
+	/// # Arguments
+	/// * `values` - Slice of (inverse volume value, weight) pairs
+	pub fn weighted_mean(values: &[(Self, f64)]) -> Self {
+		let total_weight: f64 = values.iter().map(|(_, weight)| *weight).sum();
+		if total_weight == 0.0 {
+			return InverseVolume{per_m3: T::from(0.0)};
+		}
+		let total = values.iter().fold(T::from(0.0),
+			|total, (value, weight)| total + value.per_m3.clone() * T::from(*weight));
+		InverseVolume{per_m3: total / T::from(total_weight)}
 	}
 }
 
-// 1/InverseSolidAngle -> SolidAngle
-/// Dividing a scalar value by a InverseSolidAngle unit value returns a value of type SolidAngle
-impl<T> core::ops::Div<InverseSolidAngle<T>> for i32 where T: NumLike+From<i32> {
-	type Output = SolidAngle<T>;
-	fn div(self, rhs: InverseSolidAngle<T>) -> Self::Output {
-		SolidAngle{sr: T::from(self) / rhs.per_sr}
+impl<T> InverseVolume<T> where T: NumLike+PartialEq+From<u8> {
+
+	/// Divides this inverse volume value by another unit value (eg `distance.checked_div(time)` 
+	/// returns a velocity), returning `None` if the divisor is zero instead of producing 
+	/// infinity or NaN (or panicking, for integer number types). This works for every unit 
+	/// type that this inverse volume value can be divided by with the `/` operator
+	///
+	/// # Arguments
+	/// * `rhs` - The unit value to divide by
+	pub fn checked_div<R>(self, rhs: R) -> Option<<Self as core::ops::Div<R>>::Output> 
+		where Self: core::ops::Div<R>, R: super::SiUnit<Number=T>
+	{
+		if *rhs.raw() == T::from(0u8) {
+			return None;
+		}
+		Some(self / rhs)
 	}
 }
-/// Dividing a scalar value by a InverseSolidAngle unit value returns a value of type SolidAngle
-impl<T> core::ops::Div<InverseSolidAngle<T>> for &i32 where T: NumLike+From<i32> {
-	type Output = SolidAngle<T>;
-	fn div(self, rhs: InverseSolidAngle<T>) -> Self::Output {
-		SolidAngle{sr: T::from(self.clone()) / rhs.per_sr}
+
+/// Multiplying a inverse volume value by a dimensionless value returns a inverse volume value
+impl<T> core::ops::Mul<super::base::Dimensionless<T>> for InverseVolume<T> where T: NumLike {
+	type Output = InverseVolume<T>;
+	fn mul(self, rhs: super::base::Dimensionless<T>) -> Self::Output {
+		InverseVolume{per_m3: self.per_m3 * rhs.value}
 	}
 }
-/// Dividing a scalar value by a InverseSolidAngle unit value returns a value of type SolidAngle
-impl<T> core::ops::Div<&InverseSolidAngle<T>> for i32 where T: NumLike+From<i32> {
-	type Output = SolidAngle<T>;
-	fn div(self, rhs: &InverseSolidAngle<T>) -> Self::Output {
-		SolidAngle{sr: T::from(self) / rhs.per_sr.clone()}
+/// Multiplying a dimensionless value by a inverse volume value returns a inverse volume value
+impl<T> core::ops::Mul<InverseVolume<T>> for super::base::Dimensionless<T> where T: NumLike {
+	type Output = InverseVolume<T>;
+	fn mul(self, rhs: InverseVolume<T>) -> Self::Output {
+		InverseVolume{per_m3: self.value * rhs.per_m3}
 	}
 }
-/// Dividing a scalar value by a InverseSolidAngle unit value returns a value of type SolidAngle
-impl<T> core::ops::Div<&InverseSolidAngle<T>> for &i32 where T: NumLike+From<i32> {
-	type Output = SolidAngle<T>;
-	fn div(self, rhs: &InverseSolidAngle<T>) -> Self::Output {
-		SolidAngle{sr: T::from(self.clone()) / rhs.per_sr.clone()}
+/// Dividing a inverse volume value by a dimensionless value returns a inverse volume value
+impl<T> core::ops::Div<super::base::Dimensionless<T>> for InverseVolume<T> where T: NumLike {
+	type Output = InverseVolume<T>;
+	fn div(self, rhs: super::base::Dimensionless<T>) -> Self::Output {
+		InverseVolume{per_m3: self.per_m3 / rhs.value}
 	}
 }
 
-// 1/InverseSolidAngle -> SolidAngle
-/// Dividing a scalar value by a InverseSolidAngle unit value returns a value of type SolidAngle
-#[cfg(feature="num-bigfloat")]
-impl<T> core::ops::Div<InverseSolidAngle<T>> for num_bigfloat::BigFloat where T: NumLike+From<num_bigfloat::BigFloat> {
-	type Output = SolidAngle<T>;
-	fn div(self, rhs: InverseSolidAngle<T>) -> Self::Output {
-		SolidAngle{sr: T::from(self) / rhs.per_sr}
+/// The remainder of dividing a inverse volume value by another inverse volume value is a inverse volume value
+impl<T> core::ops::Rem<InverseVolume<T>> for InverseVolume<T> where T: NumLike+core::ops::Rem<Output=T> {
+	type Output = InverseVolume<T>;
+	fn rem(self, rhs: InverseVolume<T>) -> Self::Output {
+		InverseVolume{per_m3: self.per_m3 % rhs.per_m3}
 	}
 }
-/// Dividing a scalar value by a InverseSolidAngle unit value returns a value of type SolidAngle
-#[cfg(feature="num-bigfloat")]
-impl<T> core::ops::Div<InverseSolidAngle<T>> for &num_bigfloat::BigFloat where T: NumLike+From<num_bigfloat::BigFloat> {
-	type Output = SolidAngle<T>;
-	fn div(self, rhs: InverseSolidAngle<T>) -> Self::Output {
-		SolidAngle{sr: T::from(self.clone()) / rhs.per_sr}
+/// The remainder of dividing a inverse volume value by another inverse volume value is a inverse volume value
+impl<T> core::ops::Rem<&InverseVolume<T>> for InverseVolume<T> where T: NumLike+core::ops::Rem<Output=T> {
+	type Output = InverseVolume<T>;
+	fn rem(self, rhs: &InverseVolume<T>) -> Self::Output {
+		InverseVolume{per_m3: self.per_m3 % rhs.per_m3.clone()}
 	}
 }
-/// Dividing a scalar value by a InverseSolidAngle unit value returns a value of type SolidAngle
-#[cfg(feature="num-bigfloat")]
-impl<T> core::ops::Div<&InverseSolidAngle<T>> for num_bigfloat::BigFloat where T: NumLike+From<num_bigfloat::BigFloat> {
-	type Output = SolidAngle<T>;
-	fn div(self, rhs: &InverseSolidAngle<T>) -> Self::Output {
-		SolidAngle{sr: T::from(self) / rhs.per_sr.clone()}
+/// The remainder of dividing a inverse volume value by another inverse volume value is a inverse volume value
+impl<T> core::ops::Rem<InverseVolume<T>> for &InverseVolume<T> where T: NumLike+core::ops::Rem<Output=T> {
+	type Output = InverseVolume<T>;
+	fn rem(self, rhs: InverseVolume<T>) -> Self::Output {
+		InverseVolume{per_m3: self.per_m3.clone() % rhs.per_m3}
 	}
 }
-/// Dividing a scalar value by a InverseSolidAngle unit value returns a value of type SolidAngle
-#[cfg(feature="num-bigfloat")]
-impl<T> core::ops::Div<&InverseSolidAngle<T>> for &num_bigfloat::BigFloat where T: NumLike+From<num_bigfloat::BigFloat> {
-	type Output = SolidAngle<T>;
-	fn div(self, rhs: &InverseSolidAngle<T>) -> Self::Output {
-		SolidAngle{sr: T::from(self.clone()) / rhs.per_sr.clone()}
+/// The remainder of dividing a inverse volume value by another inverse volume value is a inverse volume value
+impl<T> core::ops::Rem<&InverseVolume<T>> for &InverseVolume<T> where T: NumLike+core::ops::Rem<Output=T> {
+	type Output = InverseVolume<T>;
+	fn rem(self, rhs: &InverseVolume<T>) -> Self::Output {
+		InverseVolume{per_m3: self.per_m3.clone() % rhs.per_m3.clone()}
 	}
 }
 
-// 1/InverseSolidAngle -> SolidAngle
-/// Dividing a scalar value by a InverseSolidAngle unit value returns a value of type SolidAngle
-#[cfg(feature="num-complex")]
-impl<T> core::ops::Div<InverseSolidAngle<T>> for num_complex::Complex32 where T: NumLike+From<num_complex::Complex32> {
-	type Output = SolidAngle<T>;
-	fn div(self, rhs: InverseSolidAngle<T>) -> Self::Output {
-		SolidAngle{sr: T::from(self) / rhs.per_sr}
+/// Serializes a inverse volume value together with its unit symbol, eg 
+/// `{"value": 1.5, "unit": "1/m³"}` (requires the `serde-unit-tag` feature)
+#[cfg(feature="serde-unit-tag")]
+impl<T> serde::Serialize for InverseVolume<T> where T: NumLike+serde::Serialize {
+	fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+		super::serialize_with_unit_tag(&self.per_m3, Self::unit_symbol(), serializer)
 	}
 }
-/// Dividing a scalar value by a InverseSolidAngle unit value returns a value of type SolidAngle
-#[cfg(feature="num-complex")]
-impl<T> core::ops::Div<InverseSolidAngle<T>> for &num_complex::Complex32 where T: NumLike+From<num_complex::Complex32> {
-	type Output = SolidAngle<T>;
-	fn div(self, rhs: InverseSolidAngle<T>) -> Self::Output {
-		SolidAngle{sr: T::from(self.clone()) / rhs.per_sr}
+/// Deserializes a inverse volume value from a value and unit symbol, eg 
+/// `{"value": 1.5, "unit": "1/m³"}`, returning an error if the unit symbol 
+/// does not match (requires the `serde-unit-tag` feature)
+#[cfg(feature="serde-unit-tag")]
+impl<'de, T> serde::Deserialize<'de> for InverseVolume<T> where T: NumLike+serde::Deserialize<'de> {
+	fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+		Ok(InverseVolume{per_m3: super::deserialize_with_unit_tag(Self::unit_symbol(), deserializer)?})
 	}
 }
-/// Dividing a scalar value by a InverseSolidAngle unit value returns a value of type SolidAngle
-#[cfg(feature="num-complex")]
-impl<T> core::ops::Div<&InverseSolidAngle<T>> for num_complex::Complex32 where T: NumLike+From<num_complex::Complex32> {
-	type Output = SolidAngle<T>;
-	fn div(self, rhs: &InverseSolidAngle<T>) -> Self::Output {
-		SolidAngle{sr: T::from(self) / rhs.per_sr.clone()}
-	}
+
+/// The additive identity of inverse volume values is zero inverse cubic meters (requires the `num-traits` feature)
+#[cfg(feature="num-traits")]
+impl<T> num_traits::Zero for InverseVolume<T> where T: NumLike+num_traits::Zero {
+	fn zero() -> Self { InverseVolume{per_m3: T::zero()} }
+	fn is_zero(&self) -> bool { self.per_m3.is_zero() }
 }
-/// Dividing a scalar value by a InverseSolidAngle unit value returns a value of type SolidAngle
-#[cfg(feature="num-complex")]
-impl<T> core::ops::Div<&InverseSolidAngle<T>> for &num_complex::Complex32 where T: NumLike+From<num_complex::Complex32> {
-	type Output = SolidAngle<T>;
-	fn div(self, rhs: &InverseSolidAngle<T>) -> Self::Output {
-		SolidAngle{sr: T::from(self.clone()) / rhs.per_sr.clone()}
+
+#[cfg(feature="num-traits")]
+impl<T> InverseVolume<T> where T: NumLike+num_traits::Signed {
+
+	/// Returns the sign of this inverse volume value as a dimensionless number: 1 if it is 
+	/// positive, -1 if it is negative, and 0 if it is zero for integer number types 
+	/// (for floating-point number types, +0.0 returns 1.0, -0.0 returns -1.0, and NaN returns 
+	/// NaN) (requires the `num-traits` feature)
+	pub fn signum(self) -> T {
+		self.per_m3.signum()
 	}
 }
 
-// 1/InverseSolidAngle -> SolidAngle
-/// Dividing a scalar value by a InverseSolidAngle unit value returns a value of type SolidAngle
-#[cfg(feature="num-complex")]
-impl<T> core::ops::Div<InverseSolidAngle<T>> for num_complex::Complex64 where T: NumLike+From<num_complex::Complex64> {
-	type Output = SolidAngle<T>;
-	fn div(self, rhs: InverseSolidAngle<T>) -> Self::Output {
-		SolidAngle{sr: T::from(self) / rhs.per_sr}
+impl<T> InverseVolume<T> where T: NumLike+PartialOrd {
+
+	/// Returns the absolute value of this inverse volume value, ie its magnitude without a minus sign
+	/// (for floating-point number types, -0.0 returns +0.0)
+	pub fn abs(self) -> Self {
+		let negated = -self.per_m3.clone();
+		if self.per_m3 < negated {
+			InverseVolume{per_m3: negated}
+		} else if self.per_m3 == negated {
+			// zero, which is its own negation (adding +0.0 turns -0.0 into +0.0)
+			InverseVolume{per_m3: negated + self.per_m3}
+		} else {
+			self
+		}
+	}
+
+	/// Returns the lesser of this inverse volume value and the given inverse volume value
+	///
+	/// # Arguments
+	/// * `other` - The inverse volume value to compare with this one
+	pub fn min(self, other: Self) -> Self {
+		if other.per_m3 < self.per_m3 { other } else { self }
+	}
+
+	/// Returns the greater of this inverse volume value and the given inverse volume value
+	///
+	/// # Arguments
+	/// * `other` - The inverse volume value to compare with this one
+	pub fn max(self, other: Self) -> Self {
+		if other.per_m3 > self.per_m3 { other } else { self }
+	}
+
+	/// Returns the least of the given inverse volume values, or `None` if there are none. 
+	/// Unlike `Iterator::min()`, this does not require `Ord`, so it works for 
+	/// floating-point numbers. NaN values are skipped (so the result is `None` if all 
+	/// values are NaN), and the first of several equal values is returned
+	///
+	/// # Arguments
+	/// * `values` - Any iterator or collection of inverse volume values
+	pub fn min_of<I>(values: I) -> Option<Self> where I: IntoIterator<Item=Self> {
+		values.into_iter().filter(|x| x.per_m3.partial_cmp(&x.per_m3).is_some()).reduce(Self::min)
+	}
+
+	/// Returns the greatest of the given inverse volume values, or `None` if there are none. 
+	/// Unlike `Iterator::max()`, this does not require `Ord`, so it works for 
+	/// floating-point numbers. NaN values are skipped (so the result is `None` if all 
+	/// values are NaN), and the first of several equal values is returned
+	///
+	/// # Arguments
+	/// * `values` - Any iterator or collection of inverse volume values
+	pub fn max_of<I>(values: I) -> Option<Self> where I: IntoIterator<Item=Self> {
+		values.into_iter().filter(|x| x.per_m3.partial_cmp(&x.per_m3).is_some()).reduce(Self::max)
+	}
+
+	/// Returns this inverse volume value restricted to the range from `lo` to `hi`
+	///
+	/// # Arguments
+	/// * `lo` - The minimum inverse volume value
+	/// * `hi` - The maximum inverse volume value
+	///
+	/// # Panics
+	/// Panics if `lo` is greater than `hi` (or if either of them is NaN)
+	pub fn clamp(self, lo: Self, hi: Self) -> Self {
+		assert!(lo.per_m3 <= hi.per_m3, "lo must be less than or equal to hi");
+		if self.per_m3 < lo.per_m3 { lo } else if self.per_m3 > hi.per_m3 { hi } else { self }
+	}
+
+	/// Returns this inverse volume value with its magnitude capped at `max`, keeping its 
+	/// sign (eg for capping a speed), or this value unchanged if its magnitude does not 
+	/// exceed `max`. For complex-valued inverse volume, use `clamp_norm(...)` instead, 
+	/// which caps the magnitude while keeping the phase
+	///
+	/// # Arguments
+	/// * `max` - The maximum magnitude (should not be negative)
+	pub fn clamp_magnitude(self, max: Self) -> Self {
+		let negated_max = -max.per_m3.clone();
+		if self.per_m3 > max.per_m3 { 
+			max 
+		} else if self.per_m3 < negated_max { 
+			InverseVolume{per_m3: negated_max} 
+		} else { 
+			self 
+		}
+	}
+
+	/// Returns true if this inverse volume value differs from the given inverse volume 
+	/// value by no more than the given absolute tolerance (which is also a 
+	/// inverse volume value, so the comparison is dimensionally safe). NaN is never 
+	/// equal to anything. See also the `assert_unit_eq!(...)` macro.
+	///
+	/// # Arguments
+	/// * `other` - The inverse volume value to compare with this one
+	/// * `tolerance` - The largest allowed difference between the two values
+	pub fn eq_within(self, other: Self, tolerance: Self) -> bool {
+		let diff = self.per_m3 - other.per_m3;
+		diff <= tolerance.per_m3 && -diff <= tolerance.per_m3
 	}
 }
-/// Dividing a scalar value by a InverseSolidAngle unit value returns a value of type SolidAngle
+
 #[cfg(feature="num-complex")]
-impl<T> core::ops::Div<InverseSolidAngle<T>> for &num_complex::Complex64 where T: NumLike+From<num_complex::Complex64> {
-	type Output = SolidAngle<T>;
-	fn div(self, rhs: InverseSolidAngle<T>) -> Self::Output {
-		SolidAngle{sr: T::from(self.clone()) / rhs.per_sr}
+impl InverseVolume<num_complex::Complex32> {
+	/// Returns the magnitude (aka norm or absolute value) of this complex inverse volume 
+	/// value as a real-valued inverse volume (requires the `num-complex` feature)
+	pub fn magnitude(self) -> InverseVolume<f32> {
+		InverseVolume{per_m3: self.per_m3.norm()}
+	}
+
+	/// Returns this complex inverse volume value scaled down so that its magnitude is 
+	/// at most `max` (keeping its phase), or this value unchanged if its magnitude does 
+	/// not exceed `max`. This is the complex equivalent of `clamp_magnitude(...)` (requires 
+	/// the `num-complex` feature)
+	///
+	/// # Arguments
+	/// * `max` - The maximum magnitude (should not be negative)
+	pub fn clamp_norm(self, max: InverseVolume<f32>) -> Self {
+		let norm = self.per_m3.norm();
+		if norm > max.per_m3 {
+			InverseVolume{per_m3: self.per_m3 * (max.per_m3 / norm)}
+		} else {
+			self
+		}
 	}
 }
-/// Dividing a scalar value by a InverseSolidAngle unit value returns a value of type SolidAngle
+
 #[cfg(feature="num-complex")]
-impl<T> core::ops::Div<&InverseSolidAngle<T>> for num_complex::Complex64 where T: NumLike+From<num_complex::Complex64> {
-	type Output = SolidAngle<T>;
-	fn div(self, rhs: &InverseSolidAngle<T>) -> Self::Output {
-		SolidAngle{sr: T::from(self) / rhs.per_sr.clone()}
+impl InverseVolume<num_complex::Complex64> {
+	/// Returns the magnitude (aka norm or absolute value) of this complex inverse volume 
+	/// value as a real-valued inverse volume (requires the `num-complex` feature)
+	pub fn magnitude(self) -> InverseVolume<f64> {
+		InverseVolume{per_m3: self.per_m3.norm()}
+	}
+
+	/// Returns this complex inverse volume value scaled down so that its magnitude is 
+	/// at most `max` (keeping its phase), or this value unchanged if its magnitude does 
+	/// not exceed `max`. This is the complex equivalent of `clamp_magnitude(...)` (requires 
+	/// the `num-complex` feature)
+	///
+	/// # Arguments
+	/// * `max` - The maximum magnitude (should not be negative)
+	pub fn clamp_norm(self, max: InverseVolume<f64>) -> Self {
+		let norm = self.per_m3.norm();
+		if norm > max.per_m3 {
+			InverseVolume{per_m3: self.per_m3 * (max.per_m3 / norm)}
+		} else {
+			self
+		}
 	}
 }
-/// Dividing a scalar value by a InverseSolidAngle unit value returns a value of type SolidAngle
-#[cfg(feature="num-complex")]
-impl<T> core::ops::Div<&InverseSolidAngle<T>> for &num_complex::Complex64 where T: NumLike+From<num_complex::Complex64> {
-	type Output = SolidAngle<T>;
-	fn div(self, rhs: &InverseSolidAngle<T>) -> Self::Output {
-		SolidAngle{sr: T::from(self.clone()) / rhs.per_sr.clone()}
+
+/// Rounding and other floating-point functions (requires the `num-traits` feature, 
+/// and either the `std` or `libm` feature)
+#[cfg(all(feature="num-traits", any(feature="std", feature="libm")))]
+impl<T> InverseVolume<T> where T: NumLike+num_traits::Float {
+
+	/// Returns this inverse volume value rounded to the nearest whole number of inverse cubic meters 
+	/// (half-way cases are rounded away from zero)
+	pub fn round(self) -> Self {
+		InverseVolume{per_m3: self.per_m3.round()}
+	}
+
+	/// Returns this inverse volume value rounded down to a whole number of inverse cubic meters
+	pub fn floor(self) -> Self {
+		InverseVolume{per_m3: self.per_m3.floor()}
+	}
+
+	/// Returns this inverse volume value rounded up to a whole number of inverse cubic meters
+	pub fn ceil(self) -> Self {
+		InverseVolume{per_m3: self.per_m3.ceil()}
+	}
+
+	/// Returns this inverse volume value rounded towards zero to a whole number of inverse cubic meters
+	pub fn trunc(self) -> Self {
+		InverseVolume{per_m3: self.per_m3.trunc()}
+	}
+
+	/// Returns this inverse volume value rounded to the nearest multiple of the given step 
+	/// (half-way cases are rounded away from zero)
+	///
+	/// # Arguments
+	/// * `step` - The inverse volume value to round to a multiple of
+	pub fn round_to(self, step: Self) -> Self {
+		InverseVolume{per_m3: (self.per_m3 / step.per_m3).round() * step.per_m3}
+	}
+
+	/// Fused multiply-add: returns `self * a + b` computed with only one rounding 
+	/// error, which is more accurate (and on some hardware faster) than a separate 
+	/// multiplication and addition
+	///
+	/// # Arguments
+	/// * `a` - The scalar value to multiply this inverse volume value by
+	/// * `b` - The inverse volume value to add to the product
+	pub fn mul_add(self, a: T, b: Self) -> Self {
+		InverseVolume{per_m3: self.per_m3.mul_add(a, b.per_m3)}
+	}
+
+	/// Returns true if this inverse volume value is neither infinite nor NaN
+	pub fn is_finite(&self) -> bool {
+		self.per_m3.is_finite()
+	}
+
+	/// Returns true if this inverse volume value is positive or negative infinity
+	pub fn is_infinite(&self) -> bool {
+		self.per_m3.is_infinite()
+	}
+
+	/// Returns true if this inverse volume value is NaN (not a number)
+	pub fn is_nan(&self) -> bool {
+		self.per_m3.is_nan()
+	}
+
+	/// Returns true if this inverse volume value has a positive sign, including +0.0 
+	/// and positive infinity
+	pub fn is_sign_positive(&self) -> bool {
+		self.per_m3.is_sign_positive()
+	}
+
+	/// Returns true if this inverse volume value has a negative sign, including -0.0 
+	/// and negative infinity
+	pub fn is_sign_negative(&self) -> bool {
+		self.per_m3.is_sign_negative()
+	}
+
+	/// Returns a inverse volume value with the magnitude of this one and the sign of 
+	/// `sign_source`, eg for reflecting a value off of a surface
+	///
+	/// # Arguments
+	/// * `sign_source` - The inverse volume value whose sign is copied
+	pub fn copysign(self, sign_source: Self) -> Self {
+		InverseVolume{per_m3: self.per_m3.copysign(sign_source.per_m3)}
+	}
+}
+
+#[cfg(feature="num-traits")]
+impl<T> InverseVolume<T> where T: NumLike+num_traits::CheckedAdd+num_traits::CheckedSub {
+
+	/// Adds another inverse volume value to this one, returning `None` if the 
+	/// addition overflows the number type (requires the `num-traits` feature)
+	pub fn checked_add(self, rhs: Self) -> Option<Self> {
+		Some(InverseVolume{per_m3: self.per_m3.checked_add(&rhs.per_m3)?})
+	}
+
+	/// Subtracts another inverse volume value from this one, returning `None` if 
+	/// the subtraction overflows the number type (requires the `num-traits` feature)
+	pub fn checked_sub(self, rhs: Self) -> Option<Self> {
+		Some(InverseVolume{per_m3: self.per_m3.checked_sub(&rhs.per_m3)?})
 	}
 }
 
-/// The inverse of volume unit type, defined as inverse cubic meters in SI units
-#[derive(UnitStruct, Debug, Clone)]
-#[cfg_attr(feature="serde", derive(Serialize, Deserialize))]
-pub struct InverseVolume<T: NumLike>{
-	/// The value of this Inverse volume in inverse cubic meters
-	pub per_m3: T
+#[cfg(feature="num-traits")]
+impl<T> InverseVolume<T> where T: NumLike+num_traits::SaturatingAdd+num_traits::SaturatingSub {
+
+	/// Adds another inverse volume value to this one, clamping the result to the 
+	/// minimum or maximum value of the number type instead of overflowing 
+	/// (requires the `num-traits` feature)
+	pub fn saturating_add(self, rhs: Self) -> Self {
+		InverseVolume{per_m3: self.per_m3.saturating_add(&rhs.per_m3)}
+	}
+
+	/// Subtracts another inverse volume value from this one, clamping the result 
+	/// to the minimum or maximum value of the number type instead of overflowing 
+	/// (requires the `num-traits` feature)
+	pub fn saturating_sub(self, rhs: Self) -> Self {
+		InverseVolume{per_m3: self.per_m3.saturating_sub(&rhs.per_m3)}
+	}
 }
 
-impl<T> InverseVolume<T> where T: NumLike {
+impl<T> InverseVolume<T> where T: NumLike+Into<f64> {
 
-	/// Returns the standard unit name of inverse volume: "inverse cubic meters"
-	pub fn unit_name() -> &'static str { "inverse cubic meters" }
-	
-	/// Returns the abbreviated name or symbol of inverse volume: "1/m³" for inverse cubic meters
-	pub fn unit_symbol() -> &'static str { "1/m³" }
-	
-	/// Returns a new inverse volume value from the given number of inverse cubic meters
+	/// Returns true if this inverse volume value is equal to the given inverse volume 
+	/// value to within the given number of significant figures (eg 6 significant 
+	/// figures allows a relative difference of up to 1 part per million). NaN is 
+	/// only approximately equal to NaN, infinity is only approximately equal to 
+	/// infinity of the same sign, and zero is only approximately equal to zero.
 	///
 	/// # Arguments
-	/// * `per_m3` - Any number-like type, representing a quantity of inverse cubic meters
-	pub fn from_per_m3(per_m3: T) -> Self { InverseVolume{per_m3: per_m3} }
-	
-	/// Returns a copy of this inverse volume value in inverse cubic meters
-	pub fn to_per_m3(&self) -> T { self.per_m3.clone() }
+	/// * `other` - The inverse volume value to compare with this one
+	/// * `sig_figs` - The number of significant figures that must match
+	pub fn approx_eq(self, other: Self, sig_figs: i32) -> bool {
+		super::approx_eq_f64(self.per_m3.into(), other.per_m3.into(), sig_figs)
+	}
+}
 
-	/// Returns a new inverse volume value from the given number of inverse cubic meters
+impl<T> InverseVolume<T> where T: NumLike+From<f64> {
+
+	/// Returns the linear interpolation between this inverse volume value and the given 
+	/// inverse volume value, ie `self*(1-t) + other*t`. The interpolation factor is not 
+	/// clamped, so values of `t` outside the range 0 to 1 extrapolate beyond the two 
+	/// values (see `lerp_clamped(...)`).
 	///
 	/// # Arguments
-	/// * `per_cubic_meter` - Any number-like type, representing a quantity of inverse cubic meters
-	pub fn from_per_cubic_meter(per_cubic_meter: T) -> Self { InverseVolume{per_m3: per_cubic_meter} }
-	
-	/// Returns a copy of this inverse volume value in inverse cubic meters
-	pub fn to_per_cubic_meter(&self) -> T { self.per_m3.clone() }
+	/// * `other` - The inverse volume value to interpolate towards (returned when `t` is 1)
+	/// * `t` - The interpolation factor, where 0 returns this value and 1 returns `other`
+	pub fn lerp(self, other: Self, t: f64) -> Self {
+		InverseVolume{per_m3: self.per_m3 * T::from(1.0 - t) + other.per_m3 * T::from(t)}
+	}
 
-	/// Returns a new inverse volume value from the given number of inverse kiloliters
+	/// Same as `lerp(...)`, except that the interpolation factor `t` is first clamped 
+	/// to the range 0 to 1, so that the result is always between the two values
 	///
 	/// # Arguments
-	/// * `per_kL` - Any number-like type, representing a quantity of inverse cubic meters
-	pub fn from_per_kL(per_kL: T) -> Self { InverseVolume{per_m3: per_kL} }
-	
-	/// Returns a copy of this inverse volume value in inverse kiloliters
-	pub fn to_per_kL(&self) -> T { self.per_m3.clone() }
-
-}
-
-impl<T> fmt::Display for InverseVolume<T> where T: NumLike {
-	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-		write!(f, "{} {}", &self.per_m3, Self::unit_symbol())
+	/// * `other` - The inverse volume value to interpolate towards (returned when `t` is 1 or more)
+	/// * `t` - The interpolation factor, where 0 returns this value and 1 returns `other`
+	pub fn lerp_clamped(self, other: Self, t: f64) -> Self {
+		self.lerp(other, t.clamp(0.0, 1.0))
 	}
-}
-
-impl<T> InverseVolume<T> where T: NumLike+From<f64> {
 	
 	/// Returns a copy of this inverse volume value in inverse cubic cm
 	/// 
@@ -4650,6 +11026,72 @@ impl core::ops::Mul<&InverseVolume<num_complex::Complex64>> for &num_complex::Co
 	}
 }
 
+/// Multiplying a unit value by a scalar value returns a unit value
+#[cfg(feature="num-rational")]
+impl core::ops::Mul<InverseVolume<num_rational::Rational64>> for num_rational::Rational64 {
+	type Output = InverseVolume<num_rational::Rational64>;
+	fn mul(self, rhs: InverseVolume<num_rational::Rational64>) -> Self::Output {
+		InverseVolume{per_m3: self * rhs.per_m3}
+	}
+}
+/// Multiplying a unit value by a scalar value returns a unit value
+#[cfg(feature="num-rational")]
+impl core::ops::Mul<InverseVolume<num_rational::Rational64>> for &num_rational::Rational64 {
+	type Output = InverseVolume<num_rational::Rational64>;
+	fn mul(self, rhs: InverseVolume<num_rational::Rational64>) -> Self::Output {
+		InverseVolume{per_m3: self.clone() * rhs.per_m3}
+	}
+}
+/// Multiplying a unit value by a scalar value returns a unit value
+#[cfg(feature="num-rational")]
+impl core::ops::Mul<&InverseVolume<num_rational::Rational64>> for num_rational::Rational64 {
+	type Output = InverseVolume<num_rational::Rational64>;
+	fn mul(self, rhs: &InverseVolume<num_rational::Rational64>) -> Self::Output {
+		InverseVolume{per_m3: self * rhs.per_m3.clone()}
+	}
+}
+/// Multiplying a unit value by a scalar value returns a unit value
+#[cfg(feature="num-rational")]
+impl core::ops::Mul<&InverseVolume<num_rational::Rational64>> for &num_rational::Rational64 {
+	type Output = InverseVolume<num_rational::Rational64>;
+	fn mul(self, rhs: &InverseVolume<num_rational::Rational64>) -> Self::Output {
+		InverseVolume{per_m3: self.clone() * rhs.per_m3.clone()}
+	}
+}
+
+/// Multiplying a unit value by a scalar value returns a unit value
+#[cfg(feature="num-rational")]
+impl core::ops::Mul<InverseVolume<num_rational::BigRational>> for num_rational::BigRational {
+	type Output = InverseVolume<num_rational::BigRational>;
+	fn mul(self, rhs: InverseVolume<num_rational::BigRational>) -> Self::Output {
+		InverseVolume{per_m3: self * rhs.per_m3}
+	}
+}
+/// Multiplying a unit value by a scalar value returns a unit value
+#[cfg(feature="num-rational")]
+impl core::ops::Mul<InverseVolume<num_rational::BigRational>> for &num_rational::BigRational {
+	type Output = InverseVolume<num_rational::BigRational>;
+	fn mul(self, rhs: InverseVolume<num_rational::BigRational>) -> Self::Output {
+		InverseVolume{per_m3: self.clone() * rhs.per_m3}
+	}
+}
+/// Multiplying a unit value by a scalar value returns a unit value
+#[cfg(feature="num-rational")]
+impl core::ops::Mul<&InverseVolume<num_rational::BigRational>> for num_rational::BigRational {
+	type Output = InverseVolume<num_rational::BigRational>;
+	fn mul(self, rhs: &InverseVolume<num_rational::BigRational>) -> Self::Output {
+		InverseVolume{per_m3: self * rhs.per_m3.clone()}
+	}
+}
+/// Multiplying a unit value by a scalar value returns a unit value
+#[cfg(feature="num-rational")]
+impl core::ops::Mul<&InverseVolume<num_rational::BigRational>> for &num_rational::BigRational {
+	type Output = InverseVolume<num_rational::BigRational>;
+	fn mul(self, rhs: &InverseVolume<num_rational::BigRational>) -> Self::Output {
+		InverseVolume{per_m3: self.clone() * rhs.per_m3.clone()}
+	}
+}
+
 
 
 /// Converts a InverseVolume into the equivalent [uom](https://crates.io/crates/uom) type [VolumetricNumberDensity](https://docs.rs/uom/0.34.0/uom/si/f32/type.VolumetricNumberDensity.html)
@@ -4685,6 +11127,44 @@ impl<T> From<uom::si::f64::VolumetricNumberDensity> for InverseVolume<T> where T
 }
 
 
+
+/// Multiplying a InverseVolume by an [nalgebra](https://crates.io/crates/nalgebra) 
+/// vector or matrix of unitless values returns a vector or matrix of InverseVolume 
+/// values (eg a direction vector multiplied by a InverseVolume)
+#[cfg(feature="nalgebra")]
+impl<T, R, C, S> core::ops::Mul<nalgebra::Matrix<T, R, C, S>> for InverseVolume<T> where 
+	T: NumLike+nalgebra::Scalar, R: nalgebra::Dim, C: nalgebra::Dim, S: nalgebra::RawStorage<T, R, C>, 
+	nalgebra::DefaultAllocator: nalgebra::allocator::Allocator<R, C> {
+	type Output = nalgebra::OMatrix<InverseVolume<T>, R, C>;
+	fn mul(self, rhs: nalgebra::Matrix<T, R, C, S>) -> Self::Output {
+		rhs.map(|x| InverseVolume{per_m3: self.per_m3.clone() * x})
+	}
+}
+/// Multiplying an [nalgebra](https://crates.io/crates/nalgebra) vector or matrix of 
+/// unitless values by a InverseVolume returns a vector or matrix of InverseVolume values
+#[cfg(feature="nalgebra")]
+impl<T, R, C, S> core::ops::Mul<InverseVolume<T>> for nalgebra::Matrix<T, R, C, S> where 
+	T: NumLike+nalgebra::Scalar, R: nalgebra::Dim, C: nalgebra::Dim, S: nalgebra::RawStorage<T, R, C>, 
+	nalgebra::DefaultAllocator: nalgebra::allocator::Allocator<R, C> {
+	type Output = nalgebra::OMatrix<InverseVolume<T>, R, C>;
+	fn mul(self, rhs: InverseVolume<T>) -> Self::Output {
+		self.map(|x| InverseVolume{per_m3: x * rhs.per_m3.clone()})
+	}
+}
+/// Dividing an [nalgebra](https://crates.io/crates/nalgebra) vector or matrix of 
+/// InverseVolume values by a InverseVolume returns a vector or matrix of unitless values
+#[cfg(feature="nalgebra")]
+impl<T, R, C, S> core::ops::Div<InverseVolume<T>> for nalgebra::Matrix<InverseVolume<T>, R, C, S> where 
+	T: NumLike+nalgebra::Scalar, R: nalgebra::Dim, C: nalgebra::Dim, S: nalgebra::RawStorage<InverseVolume<T>, R, C>, 
+	nalgebra::DefaultAllocator: nalgebra::allocator::Allocator<R, C> {
+	type Output = nalgebra::OMatrix<T, R, C>;
+	fn div(self, rhs: InverseVolume<T>) -> Self::Output {
+		self.map(|x| x.per_m3 / rhs.per_m3.clone())
+	}
+}
+
+
+
 // InverseVolume * Amount -> Concentration
 /// Multiplying a InverseVolume by a Amount returns a value of type Concentration
 impl<T> core::ops::Mul<Amount<T>> for InverseVolume<T> where T: NumLike {
@@ -4865,6 +11345,36 @@ impl<T> core::ops::Mul<&Mass<T>> for &InverseVolume<T> where T: NumLike {
 	}
 }
 
+// InverseVolume * Time -> InverseVolumetricFlowRate
+/// Multiplying a InverseVolume by a Time returns a value of type InverseVolumetricFlowRate
+impl<T> core::ops::Mul<Time<T>> for InverseVolume<T> where T: NumLike {
+	type Output = InverseVolumetricFlowRate<T>;
+	fn mul(self, rhs: Time<T>) -> Self::Output {
+		InverseVolumetricFlowRate{s_per_m3: self.per_m3 * rhs.s}
+	}
+}
+/// Multiplying a InverseVolume by a Time returns a value of type InverseVolumetricFlowRate
+impl<T> core::ops::Mul<Time<T>> for &InverseVolume<T> where T: NumLike {
+	type Output = InverseVolumetricFlowRate<T>;
+	fn mul(self, rhs: Time<T>) -> Self::Output {
+		InverseVolumetricFlowRate{s_per_m3: self.per_m3.clone() * rhs.s}
+	}
+}
+/// Multiplying a InverseVolume by a Time returns a value of type InverseVolumetricFlowRate
+impl<T> core::ops::Mul<&Time<T>> for InverseVolume<T> where T: NumLike {
+	type Output = InverseVolumetricFlowRate<T>;
+	fn mul(self, rhs: &Time<T>) -> Self::Output {
+		InverseVolumetricFlowRate{s_per_m3: self.per_m3 * rhs.s.clone()}
+	}
+}
+/// Multiplying a InverseVolume by a Time returns a value of type InverseVolumetricFlowRate
+impl<T> core::ops::Mul<&Time<T>> for &InverseVolume<T> where T: NumLike {
+	type Output = InverseVolumetricFlowRate<T>;
+	fn mul(self, rhs: &Time<T>) -> Self::Output {
+		InverseVolumetricFlowRate{s_per_m3: self.per_m3.clone() * rhs.s.clone()}
+	}
+}
+
 // InverseVolume / Concentration -> InverseAmount
 /// Dividing a InverseVolume by a Concentration returns a value of type InverseAmount
 impl<T> core::ops::Div<Concentration<T>> for InverseVolume<T> where T: NumLike {
@@ -4925,6 +11435,126 @@ impl<T> core::ops::Mul<&MolarVolume<T>> for &InverseVolume<T> where T: NumLike {
 	}
 }
 
+// InverseVolume * Charge -> VolumeChargeDensity
+/// Multiplying a InverseVolume by a Charge returns a value of type VolumeChargeDensity
+impl<T> core::ops::Mul<Charge<T>> for InverseVolume<T> where T: NumLike {
+	type Output = VolumeChargeDensity<T>;
+	fn mul(self, rhs: Charge<T>) -> Self::Output {
+		VolumeChargeDensity{Cpm3: self.per_m3 * rhs.C}
+	}
+}
+/// Multiplying a InverseVolume by a Charge returns a value of type VolumeChargeDensity
+impl<T> core::ops::Mul<Charge<T>> for &InverseVolume<T> where T: NumLike {
+	type Output = VolumeChargeDensity<T>;
+	fn mul(self, rhs: Charge<T>) -> Self::Output {
+		VolumeChargeDensity{Cpm3: self.per_m3.clone() * rhs.C}
+	}
+}
+/// Multiplying a InverseVolume by a Charge returns a value of type VolumeChargeDensity
+impl<T> core::ops::Mul<&Charge<T>> for InverseVolume<T> where T: NumLike {
+	type Output = VolumeChargeDensity<T>;
+	fn mul(self, rhs: &Charge<T>) -> Self::Output {
+		VolumeChargeDensity{Cpm3: self.per_m3 * rhs.C.clone()}
+	}
+}
+/// Multiplying a InverseVolume by a Charge returns a value of type VolumeChargeDensity
+impl<T> core::ops::Mul<&Charge<T>> for &InverseVolume<T> where T: NumLike {
+	type Output = VolumeChargeDensity<T>;
+	fn mul(self, rhs: &Charge<T>) -> Self::Output {
+		VolumeChargeDensity{Cpm3: self.per_m3.clone() * rhs.C.clone()}
+	}
+}
+
+// InverseVolume / InverseCharge -> VolumeChargeDensity
+/// Dividing a InverseVolume by a InverseCharge returns a value of type VolumeChargeDensity
+impl<T> core::ops::Div<InverseCharge<T>> for InverseVolume<T> where T: NumLike {
+	type Output = VolumeChargeDensity<T>;
+	fn div(self, rhs: InverseCharge<T>) -> Self::Output {
+		VolumeChargeDensity{Cpm3: self.per_m3 / rhs.per_C}
+	}
+}
+/// Dividing a InverseVolume by a InverseCharge returns a value of type VolumeChargeDensity
+impl<T> core::ops::Div<InverseCharge<T>> for &InverseVolume<T> where T: NumLike {
+	type Output = VolumeChargeDensity<T>;
+	fn div(self, rhs: InverseCharge<T>) -> Self::Output {
+		VolumeChargeDensity{Cpm3: self.per_m3.clone() / rhs.per_C}
+	}
+}
+/// Dividing a InverseVolume by a InverseCharge returns a value of type VolumeChargeDensity
+impl<T> core::ops::Div<&InverseCharge<T>> for InverseVolume<T> where T: NumLike {
+	type Output = VolumeChargeDensity<T>;
+	fn div(self, rhs: &InverseCharge<T>) -> Self::Output {
+		VolumeChargeDensity{Cpm3: self.per_m3 / rhs.per_C.clone()}
+	}
+}
+/// Dividing a InverseVolume by a InverseCharge returns a value of type VolumeChargeDensity
+impl<T> core::ops::Div<&InverseCharge<T>> for &InverseVolume<T> where T: NumLike {
+	type Output = VolumeChargeDensity<T>;
+	fn div(self, rhs: &InverseCharge<T>) -> Self::Output {
+		VolumeChargeDensity{Cpm3: self.per_m3.clone() / rhs.per_C.clone()}
+	}
+}
+
+// InverseVolume * InverseVolumeChargeDensity -> InverseCharge
+/// Multiplying a InverseVolume by a InverseVolumeChargeDensity returns a value of type InverseCharge
+impl<T> core::ops::Mul<InverseVolumeChargeDensity<T>> for InverseVolume<T> where T: NumLike {
+	type Output = InverseCharge<T>;
+	fn mul(self, rhs: InverseVolumeChargeDensity<T>) -> Self::Output {
+		InverseCharge{per_C: self.per_m3 * rhs.m3_per_C}
+	}
+}
+/// Multiplying a InverseVolume by a InverseVolumeChargeDensity returns a value of type InverseCharge
+impl<T> core::ops::Mul<InverseVolumeChargeDensity<T>> for &InverseVolume<T> where T: NumLike {
+	type Output = InverseCharge<T>;
+	fn mul(self, rhs: InverseVolumeChargeDensity<T>) -> Self::Output {
+		InverseCharge{per_C: self.per_m3.clone() * rhs.m3_per_C}
+	}
+}
+/// Multiplying a InverseVolume by a InverseVolumeChargeDensity returns a value of type InverseCharge
+impl<T> core::ops::Mul<&InverseVolumeChargeDensity<T>> for InverseVolume<T> where T: NumLike {
+	type Output = InverseCharge<T>;
+	fn mul(self, rhs: &InverseVolumeChargeDensity<T>) -> Self::Output {
+		InverseCharge{per_C: self.per_m3 * rhs.m3_per_C.clone()}
+	}
+}
+/// Multiplying a InverseVolume by a InverseVolumeChargeDensity returns a value of type InverseCharge
+impl<T> core::ops::Mul<&InverseVolumeChargeDensity<T>> for &InverseVolume<T> where T: NumLike {
+	type Output = InverseCharge<T>;
+	fn mul(self, rhs: &InverseVolumeChargeDensity<T>) -> Self::Output {
+		InverseCharge{per_C: self.per_m3.clone() * rhs.m3_per_C.clone()}
+	}
+}
+
+// InverseVolume / VolumeChargeDensity -> InverseCharge
+/// Dividing a InverseVolume by a VolumeChargeDensity returns a value of type InverseCharge
+impl<T> core::ops::Div<VolumeChargeDensity<T>> for InverseVolume<T> where T: NumLike {
+	type Output = InverseCharge<T>;
+	fn div(self, rhs: VolumeChargeDensity<T>) -> Self::Output {
+		InverseCharge{per_C: self.per_m3 / rhs.Cpm3}
+	}
+}
+/// Dividing a InverseVolume by a VolumeChargeDensity returns a value of type InverseCharge
+impl<T> core::ops::Div<VolumeChargeDensity<T>> for &InverseVolume<T> where T: NumLike {
+	type Output = InverseCharge<T>;
+	fn div(self, rhs: VolumeChargeDensity<T>) -> Self::Output {
+		InverseCharge{per_C: self.per_m3.clone() / rhs.Cpm3}
+	}
+}
+/// Dividing a InverseVolume by a VolumeChargeDensity returns a value of type InverseCharge
+impl<T> core::ops::Div<&VolumeChargeDensity<T>> for InverseVolume<T> where T: NumLike {
+	type Output = InverseCharge<T>;
+	fn div(self, rhs: &VolumeChargeDensity<T>) -> Self::Output {
+		InverseCharge{per_C: self.per_m3 / rhs.Cpm3.clone()}
+	}
+}
+/// Dividing a InverseVolume by a VolumeChargeDensity returns a value of type InverseCharge
+impl<T> core::ops::Div<&VolumeChargeDensity<T>> for &InverseVolume<T> where T: NumLike {
+	type Output = InverseCharge<T>;
+	fn div(self, rhs: &VolumeChargeDensity<T>) -> Self::Output {
+		InverseCharge{per_C: self.per_m3.clone() / rhs.Cpm3.clone()}
+	}
+}
+
 // InverseVolume * Area -> InverseDistance
 /// Multiplying a InverseVolume by a Area returns a value of type InverseDistance
 impl<T> core::ops::Mul<Area<T>> for InverseVolume<T> where T: NumLike {
@@ -5075,6 +11705,36 @@ impl<T> core::ops::Mul<&Torque<T>> for &InverseVolume<T> where T: NumLike {
 	}
 }
 
+// InverseVolume / Frequency -> InverseVolumetricFlowRate
+/// Dividing a InverseVolume by a Frequency returns a value of type InverseVolumetricFlowRate
+impl<T> core::ops::Div<Frequency<T>> for InverseVolume<T> where T: NumLike {
+	type Output = InverseVolumetricFlowRate<T>;
+	fn div(self, rhs: Frequency<T>) -> Self::Output {
+		InverseVolumetricFlowRate{s_per_m3: self.per_m3 / rhs.Hz}
+	}
+}
+/// Dividing a InverseVolume by a Frequency returns a value of type InverseVolumetricFlowRate
+impl<T> core::ops::Div<Frequency<T>> for &InverseVolume<T> where T: NumLike {
+	type Output = InverseVolumetricFlowRate<T>;
+	fn div(self, rhs: Frequency<T>) -> Self::Output {
+		InverseVolumetricFlowRate{s_per_m3: self.per_m3.clone() / rhs.Hz}
+	}
+}
+/// Dividing a InverseVolume by a Frequency returns a value of type InverseVolumetricFlowRate
+impl<T> core::ops::Div<&Frequency<T>> for InverseVolume<T> where T: NumLike {
+	type Output = InverseVolumetricFlowRate<T>;
+	fn div(self, rhs: &Frequency<T>) -> Self::Output {
+		InverseVolumetricFlowRate{s_per_m3: self.per_m3 / rhs.Hz.clone()}
+	}
+}
+/// Dividing a InverseVolume by a Frequency returns a value of type InverseVolumetricFlowRate
+impl<T> core::ops::Div<&Frequency<T>> for &InverseVolume<T> where T: NumLike {
+	type Output = InverseVolumetricFlowRate<T>;
+	fn div(self, rhs: &Frequency<T>) -> Self::Output {
+		InverseVolumetricFlowRate{s_per_m3: self.per_m3.clone() / rhs.Hz.clone()}
+	}
+}
+
 // InverseVolume / InverseEnergy -> Pressure
 /// Dividing a InverseVolume by a InverseEnergy returns a value of type Pressure
 impl<T> core::ops::Div<InverseEnergy<T>> for InverseVolume<T> where T: NumLike {
@@ -5165,6 +11825,36 @@ impl<T> core::ops::Mul<&InversePressure<T>> for &InverseVolume<T> where T: NumLi
 	}
 }
 
+// InverseVolume / InverseVolumetricFlowRate -> Frequency
+/// Dividing a InverseVolume by a InverseVolumetricFlowRate returns a value of type Frequency
+impl<T> core::ops::Div<InverseVolumetricFlowRate<T>> for InverseVolume<T> where T: NumLike {
+	type Output = Frequency<T>;
+	fn div(self, rhs: InverseVolumetricFlowRate<T>) -> Self::Output {
+		Frequency{Hz: self.per_m3 / rhs.s_per_m3}
+	}
+}
+/// Dividing a InverseVolume by a InverseVolumetricFlowRate returns a value of type Frequency
+impl<T> core::ops::Div<InverseVolumetricFlowRate<T>> for &InverseVolume<T> where T: NumLike {
+	type Output = Frequency<T>;
+	fn div(self, rhs: InverseVolumetricFlowRate<T>) -> Self::Output {
+		Frequency{Hz: self.per_m3.clone() / rhs.s_per_m3}
+	}
+}
+/// Dividing a InverseVolume by a InverseVolumetricFlowRate returns a value of type Frequency
+impl<T> core::ops::Div<&InverseVolumetricFlowRate<T>> for InverseVolume<T> where T: NumLike {
+	type Output = Frequency<T>;
+	fn div(self, rhs: &InverseVolumetricFlowRate<T>) -> Self::Output {
+		Frequency{Hz: self.per_m3 / rhs.s_per_m3.clone()}
+	}
+}
+/// Dividing a InverseVolume by a InverseVolumetricFlowRate returns a value of type Frequency
+impl<T> core::ops::Div<&InverseVolumetricFlowRate<T>> for &InverseVolume<T> where T: NumLike {
+	type Output = Frequency<T>;
+	fn div(self, rhs: &InverseVolumetricFlowRate<T>) -> Self::Output {
+		Frequency{Hz: self.per_m3.clone() / rhs.s_per_m3.clone()}
+	}
+}
+
 // InverseVolume / Pressure -> InverseEnergy
 /// Dividing a InverseVolume by a Pressure returns a value of type InverseEnergy
 impl<T> core::ops::Div<Pressure<T>> for InverseVolume<T> where T: NumLike {
@@ -5225,6 +11915,44 @@ impl<T> core::ops::Mul<&VolumePerMass<T>> for &InverseVolume<T> where T: NumLike
 	}
 }
 
+// InverseVolume * VolumetricFlowRate -> Frequency
+/// Multiplying a InverseVolume by a VolumetricFlowRate returns a value of type Frequency
+impl<T> core::ops::Mul<VolumetricFlowRate<T>> for InverseVolume<T> where T: NumLike {
+	type Output = Frequency<T>;
+	fn mul(self, rhs: VolumetricFlowRate<T>) -> Self::Output {
+		Frequency{Hz: self.per_m3 * rhs.m3ps}
+	}
+}
+/// Multiplying a InverseVolume by a VolumetricFlowRate returns a value of type Frequency
+impl<T> core::ops::Mul<VolumetricFlowRate<T>> for &InverseVolume<T> where T: NumLike {
+	type Output = Frequency<T>;
+	fn mul(self, rhs: VolumetricFlowRate<T>) -> Self::Output {
+		Frequency{Hz: self.per_m3.clone() * rhs.m3ps}
+	}
+}
+/// Multiplying a InverseVolume by a VolumetricFlowRate returns a value of type Frequency
+impl<T> core::ops::Mul<&VolumetricFlowRate<T>> for InverseVolume<T> where T: NumLike {
+	type Output = Frequency<T>;
+	fn mul(self, rhs: &VolumetricFlowRate<T>) -> Self::Output {
+		Frequency{Hz: self.per_m3 * rhs.m3ps.clone()}
+	}
+}
+/// Multiplying a InverseVolume by a VolumetricFlowRate returns a value of type Frequency
+impl<T> core::ops::Mul<&VolumetricFlowRate<T>> for &InverseVolume<T> where T: NumLike {
+	type Output = Frequency<T>;
+	fn mul(self, rhs: &VolumetricFlowRate<T>) -> Self::Output {
+		Frequency{Hz: self.per_m3.clone() * rhs.m3ps.clone()}
+	}
+}
+
+impl<T> InverseVolume<T> where T: NumLike+From<u8> {
+	/// Returns the reciprocal of this InverseVolume value (ie 1 divided by 
+	/// this value) as a value of type Volume
+	pub fn recip(self) -> Volume<T> {
+		Volume{m3: T::from(1u8) / self.per_m3}
+	}
+}
+
 // 1/InverseVolume -> Volume
 /// Dividing a scalar value by a InverseVolume unit value returns a value of type Volume
 impl<T> core::ops::Div<InverseVolume<T>> for f64 where T: NumLike+From<f64> {
@@ -5355,24 +12083,92 @@ impl<T> core::ops::Div<InverseVolume<T>> for num_bigfloat::BigFloat where T: Num
 	}
 }
 /// Dividing a scalar value by a InverseVolume unit value returns a value of type Volume
-#[cfg(feature="num-bigfloat")]
-impl<T> core::ops::Div<InverseVolume<T>> for &num_bigfloat::BigFloat where T: NumLike+From<num_bigfloat::BigFloat> {
+#[cfg(feature="num-bigfloat")]
+impl<T> core::ops::Div<InverseVolume<T>> for &num_bigfloat::BigFloat where T: NumLike+From<num_bigfloat::BigFloat> {
+	type Output = Volume<T>;
+	fn div(self, rhs: InverseVolume<T>) -> Self::Output {
+		Volume{m3: T::from(self.clone()) / rhs.per_m3}
+	}
+}
+/// Dividing a scalar value by a InverseVolume unit value returns a value of type Volume
+#[cfg(feature="num-bigfloat")]
+impl<T> core::ops::Div<&InverseVolume<T>> for num_bigfloat::BigFloat where T: NumLike+From<num_bigfloat::BigFloat> {
+	type Output = Volume<T>;
+	fn div(self, rhs: &InverseVolume<T>) -> Self::Output {
+		Volume{m3: T::from(self) / rhs.per_m3.clone()}
+	}
+}
+/// Dividing a scalar value by a InverseVolume unit value returns a value of type Volume
+#[cfg(feature="num-bigfloat")]
+impl<T> core::ops::Div<&InverseVolume<T>> for &num_bigfloat::BigFloat where T: NumLike+From<num_bigfloat::BigFloat> {
+	type Output = Volume<T>;
+	fn div(self, rhs: &InverseVolume<T>) -> Self::Output {
+		Volume{m3: T::from(self.clone()) / rhs.per_m3.clone()}
+	}
+}
+
+// 1/InverseVolume -> Volume
+/// Dividing a scalar value by a InverseVolume unit value returns a value of type Volume
+#[cfg(feature="num-complex")]
+impl<T> core::ops::Div<InverseVolume<T>> for num_complex::Complex32 where T: NumLike+From<num_complex::Complex32> {
+	type Output = Volume<T>;
+	fn div(self, rhs: InverseVolume<T>) -> Self::Output {
+		Volume{m3: T::from(self) / rhs.per_m3}
+	}
+}
+/// Dividing a scalar value by a InverseVolume unit value returns a value of type Volume
+#[cfg(feature="num-complex")]
+impl<T> core::ops::Div<InverseVolume<T>> for &num_complex::Complex32 where T: NumLike+From<num_complex::Complex32> {
+	type Output = Volume<T>;
+	fn div(self, rhs: InverseVolume<T>) -> Self::Output {
+		Volume{m3: T::from(self.clone()) / rhs.per_m3}
+	}
+}
+/// Dividing a scalar value by a InverseVolume unit value returns a value of type Volume
+#[cfg(feature="num-complex")]
+impl<T> core::ops::Div<&InverseVolume<T>> for num_complex::Complex32 where T: NumLike+From<num_complex::Complex32> {
+	type Output = Volume<T>;
+	fn div(self, rhs: &InverseVolume<T>) -> Self::Output {
+		Volume{m3: T::from(self) / rhs.per_m3.clone()}
+	}
+}
+/// Dividing a scalar value by a InverseVolume unit value returns a value of type Volume
+#[cfg(feature="num-complex")]
+impl<T> core::ops::Div<&InverseVolume<T>> for &num_complex::Complex32 where T: NumLike+From<num_complex::Complex32> {
+	type Output = Volume<T>;
+	fn div(self, rhs: &InverseVolume<T>) -> Self::Output {
+		Volume{m3: T::from(self.clone()) / rhs.per_m3.clone()}
+	}
+}
+
+// 1/InverseVolume -> Volume
+/// Dividing a scalar value by a InverseVolume unit value returns a value of type Volume
+#[cfg(feature="num-complex")]
+impl<T> core::ops::Div<InverseVolume<T>> for num_complex::Complex64 where T: NumLike+From<num_complex::Complex64> {
+	type Output = Volume<T>;
+	fn div(self, rhs: InverseVolume<T>) -> Self::Output {
+		Volume{m3: T::from(self) / rhs.per_m3}
+	}
+}
+/// Dividing a scalar value by a InverseVolume unit value returns a value of type Volume
+#[cfg(feature="num-complex")]
+impl<T> core::ops::Div<InverseVolume<T>> for &num_complex::Complex64 where T: NumLike+From<num_complex::Complex64> {
 	type Output = Volume<T>;
 	fn div(self, rhs: InverseVolume<T>) -> Self::Output {
 		Volume{m3: T::from(self.clone()) / rhs.per_m3}
 	}
 }
 /// Dividing a scalar value by a InverseVolume unit value returns a value of type Volume
-#[cfg(feature="num-bigfloat")]
-impl<T> core::ops::Div<&InverseVolume<T>> for num_bigfloat::BigFloat where T: NumLike+From<num_bigfloat::BigFloat> {
+#[cfg(feature="num-complex")]
+impl<T> core::ops::Div<&InverseVolume<T>> for num_complex::Complex64 where T: NumLike+From<num_complex::Complex64> {
 	type Output = Volume<T>;
 	fn div(self, rhs: &InverseVolume<T>) -> Self::Output {
 		Volume{m3: T::from(self) / rhs.per_m3.clone()}
 	}
 }
 /// Dividing a scalar value by a InverseVolume unit value returns a value of type Volume
-#[cfg(feature="num-bigfloat")]
-impl<T> core::ops::Div<&InverseVolume<T>> for &num_bigfloat::BigFloat where T: NumLike+From<num_bigfloat::BigFloat> {
+#[cfg(feature="num-complex")]
+impl<T> core::ops::Div<&InverseVolume<T>> for &num_complex::Complex64 where T: NumLike+From<num_complex::Complex64> {
 	type Output = Volume<T>;
 	fn div(self, rhs: &InverseVolume<T>) -> Self::Output {
 		Volume{m3: T::from(self.clone()) / rhs.per_m3.clone()}
@@ -5381,32 +12177,32 @@ impl<T> core::ops::Div<&InverseVolume<T>> for &num_bigfloat::BigFloat where T: N
 
 // 1/InverseVolume -> Volume
 /// Dividing a scalar value by a InverseVolume unit value returns a value of type Volume
-#[cfg(feature="num-complex")]
-impl<T> core::ops::Div<InverseVolume<T>> for num_complex::Complex32 where T: NumLike+From<num_complex::Complex32> {
+#[cfg(feature="num-rational")]
+impl<T> core::ops::Div<InverseVolume<T>> for num_rational::Rational64 where T: NumLike+From<num_rational::Rational64> {
 	type Output = Volume<T>;
 	fn div(self, rhs: InverseVolume<T>) -> Self::Output {
 		Volume{m3: T::from(self) / rhs.per_m3}
 	}
 }
 /// Dividing a scalar value by a InverseVolume unit value returns a value of type Volume
-#[cfg(feature="num-complex")]
-impl<T> core::ops::Div<InverseVolume<T>> for &num_complex::Complex32 where T: NumLike+From<num_complex::Complex32> {
+#[cfg(feature="num-rational")]
+impl<T> core::ops::Div<InverseVolume<T>> for &num_rational::Rational64 where T: NumLike+From<num_rational::Rational64> {
 	type Output = Volume<T>;
 	fn div(self, rhs: InverseVolume<T>) -> Self::Output {
 		Volume{m3: T::from(self.clone()) / rhs.per_m3}
 	}
 }
 /// Dividing a scalar value by a InverseVolume unit value returns a value of type Volume
-#[cfg(feature="num-complex")]
-impl<T> core::ops::Div<&InverseVolume<T>> for num_complex::Complex32 where T: NumLike+From<num_complex::Complex32> {
+#[cfg(feature="num-rational")]
+impl<T> core::ops::Div<&InverseVolume<T>> for num_rational::Rational64 where T: NumLike+From<num_rational::Rational64> {
 	type Output = Volume<T>;
 	fn div(self, rhs: &InverseVolume<T>) -> Self::Output {
 		Volume{m3: T::from(self) / rhs.per_m3.clone()}
 	}
 }
 /// Dividing a scalar value by a InverseVolume unit value returns a value of type Volume
-#[cfg(feature="num-complex")]
-impl<T> core::ops::Div<&InverseVolume<T>> for &num_complex::Complex32 where T: NumLike+From<num_complex::Complex32> {
+#[cfg(feature="num-rational")]
+impl<T> core::ops::Div<&InverseVolume<T>> for &num_rational::Rational64 where T: NumLike+From<num_rational::Rational64> {
 	type Output = Volume<T>;
 	fn div(self, rhs: &InverseVolume<T>) -> Self::Output {
 		Volume{m3: T::from(self.clone()) / rhs.per_m3.clone()}
@@ -5415,32 +12211,32 @@ impl<T> core::ops::Div<&InverseVolume<T>> for &num_complex::Complex32 where T: N
 
 // 1/InverseVolume -> Volume
 /// Dividing a scalar value by a InverseVolume unit value returns a value of type Volume
-#[cfg(feature="num-complex")]
-impl<T> core::ops::Div<InverseVolume<T>> for num_complex::Complex64 where T: NumLike+From<num_complex::Complex64> {
+#[cfg(feature="num-rational")]
+impl<T> core::ops::Div<InverseVolume<T>> for num_rational::BigRational where T: NumLike+From<num_rational::BigRational> {
 	type Output = Volume<T>;
 	fn div(self, rhs: InverseVolume<T>) -> Self::Output {
 		Volume{m3: T::from(self) / rhs.per_m3}
 	}
 }
 /// Dividing a scalar value by a InverseVolume unit value returns a value of type Volume
-#[cfg(feature="num-complex")]
-impl<T> core::ops::Div<InverseVolume<T>> for &num_complex::Complex64 where T: NumLike+From<num_complex::Complex64> {
+#[cfg(feature="num-rational")]
+impl<T> core::ops::Div<InverseVolume<T>> for &num_rational::BigRational where T: NumLike+From<num_rational::BigRational> {
 	type Output = Volume<T>;
 	fn div(self, rhs: InverseVolume<T>) -> Self::Output {
 		Volume{m3: T::from(self.clone()) / rhs.per_m3}
 	}
 }
 /// Dividing a scalar value by a InverseVolume unit value returns a value of type Volume
-#[cfg(feature="num-complex")]
-impl<T> core::ops::Div<&InverseVolume<T>> for num_complex::Complex64 where T: NumLike+From<num_complex::Complex64> {
+#[cfg(feature="num-rational")]
+impl<T> core::ops::Div<&InverseVolume<T>> for num_rational::BigRational where T: NumLike+From<num_rational::BigRational> {
 	type Output = Volume<T>;
 	fn div(self, rhs: &InverseVolume<T>) -> Self::Output {
 		Volume{m3: T::from(self) / rhs.per_m3.clone()}
 	}
 }
 /// Dividing a scalar value by a InverseVolume unit value returns a value of type Volume
-#[cfg(feature="num-complex")]
-impl<T> core::ops::Div<&InverseVolume<T>> for &num_complex::Complex64 where T: NumLike+From<num_complex::Complex64> {
+#[cfg(feature="num-rational")]
+impl<T> core::ops::Div<&InverseVolume<T>> for &num_rational::BigRational where T: NumLike+From<num_rational::BigRational> {
 	type Output = Volume<T>;
 	fn div(self, rhs: &InverseVolume<T>) -> Self::Output {
 		Volume{m3: T::from(self.clone()) / rhs.per_m3.clone()}
@@ -5448,8 +12244,10 @@ impl<T> core::ops::Div<&InverseVolume<T>> for &num_complex::Complex64 where T: N
 }
 
 /// The solid angle unit type, defined as steradian in SI units
-#[derive(UnitStruct, Debug, Clone)]
-#[cfg_attr(feature="serde", derive(Serialize, Deserialize))]
+#[derive(UnitStruct, Debug, Clone, Default)]
+#[si(no_display)]
+#[cfg_attr(all(feature="serde", not(feature="serde-unit-tag")), derive(Serialize, Deserialize))]
+#[repr(transparent)]
 pub struct SolidAngle<T: NumLike>{
 	/// The value of this Solid angle in steradian
 	pub sr: T
@@ -5462,6 +12260,106 @@ impl<T> SolidAngle<T> where T: NumLike {
 	
 	/// Returns the abbreviated name or symbol of solid angle: "sr" for steradian
 	pub fn unit_symbol() -> &'static str { "sr" }
+
+	/// Returns the exponents of the SI base units of solid angle (rad^2)
+	pub const fn dimension() -> super::Dimension { super::Dimension{length: 0, mass: 0, time: 0, current: 0, temperature: 0, amount: 0, luminosity: 0} }
+
+	/// Returns a wrapper that formats this solid angle value with the full unit name instead of 
+	/// the unit symbol, eg "5 steradian" (the unit name is not singularized for values of 1)
+	pub fn display_named(&self) -> super::NamedDisplay<'_, T> {
+		super::NamedDisplay::new(&self.sr, Self::unit_name())
+	}
+
+	/// Returns a new solid angle value from the given number of steradian (same as 
+	/// `from_sr(...)`), for any number-like type. This is useful in generic code 
+	/// that works with several unit types
+	///
+	/// # Arguments
+	/// * `value` - Any number-like type, representing a quantity of steradian
+	pub const fn new(value: T) -> Self { SolidAngle{sr: value} }
+
+	/// Returns a new solid angle value from the given number of steradian. Unlike
+	/// `from_sr(...)`, this is a `const fn`, so it can be used to declare `const` and `static` values
+	///
+	/// # Arguments
+	/// * `sr` - Any number-like type, representing a quantity of steradian
+	pub const fn from_sr_const(sr: T) -> Self { SolidAngle{sr} }
+
+	/// Returns a solid angle value with a different number type, by applying the 
+	/// given function to the value in steradian (eg `.map(|x| x as f32)` to convert 
+	/// from `f64` to `f32`)
+	///
+	/// # Arguments
+	/// * `f` - Function that converts the number of steradian to the new number type
+	pub fn map<U, F>(self, f: F) -> SolidAngle<U> where U: NumLike, F: FnOnce(T) -> U {
+		SolidAngle{sr: f(self.sr)}
+	}
+
+	/// Returns a solid angle value with a different number type, converted 
+	/// losslessly with the `From` trait (eg from `f32` to `f64`)
+	pub fn cast<U>(self) -> SolidAngle<U> where U: NumLike+From<T> {
+		SolidAngle{sr: U::from(self.sr)}
+	}
+
+	/// Returns a reference to the value of this solid angle in steradian
+	pub fn as_raw(&self) -> &T {
+		&self.sr
+	}
+
+	/// Returns a mutable reference to the value of this solid angle in steradian, 
+	/// eg for passing to numeric libraries that modify values in place. The number 
+	/// is always in steradian, so changing it directly does not change the unit
+	pub fn raw_mut(&mut self) -> &mut T {
+		&mut self.sr
+	}
+
+	/// Returns a slice of numbers in steradian as a slice of solid angle values, 
+	/// without copying
+	///
+	/// # Arguments
+	/// * `values` - Slice of numbers, representing quantities of steradian
+	pub fn wrap_slice(values: &[T]) -> &[Self] {
+		// SAFETY: SolidAngle<T> is #[repr(transparent)], so it has the same memory layout as T
+		unsafe { core::slice::from_raw_parts(values.as_ptr() as *const Self, values.len()) }
+	}
+
+	/// Returns a mutable slice of numbers in steradian as a mutable slice of 
+	/// solid angle values, without copying
+	///
+	/// # Arguments
+	/// * `values` - Slice of numbers, representing quantities of steradian
+	pub fn wrap_slice_mut(values: &mut [T]) -> &mut [Self] {
+		// SAFETY: SolidAngle<T> is #[repr(transparent)], so it has the same memory layout as T
+		unsafe { core::slice::from_raw_parts_mut(values.as_mut_ptr() as *mut Self, values.len()) }
+	}
+
+	/// Returns the ratio of this solid angle value to another solid angle value 
+	/// (same as `self / other`). Dividing a unit value by another value of the same 
+	/// unit type cancels the units, so the ratio is a dimensionless number
+	///
+	/// # Arguments
+	/// * `other` - The solid angle value to divide by
+	pub fn ratio(self, other: Self) -> T {
+		self.sr / other.sr
+	}
+
+	/// Returns the ratio of this solid angle value to another solid angle value 
+	/// as a `Dimensionless` value, instead of a bare number like `ratio(...)` does
+	///
+	/// # Arguments
+	/// * `other` - The solid angle value to divide by
+	pub fn dimensionless_ratio(self, other: Self) -> super::base::Dimensionless<T> {
+		super::base::Dimensionless{value: self.sr / other.sr}
+	}
+
+	/// Returns this solid angle value multiplied by a dimensionless factor 
+	/// (same as `self * factor`)
+	///
+	/// # Arguments
+	/// * `factor` - The number to multiply by
+	pub fn scale(self, factor: T) -> Self {
+		SolidAngle{sr: self.sr * factor}
+	}
 	
 	/// Returns a new solid angle value from the given number of steradians
 	///
@@ -5472,24 +12370,557 @@ impl<T> SolidAngle<T> where T: NumLike {
 	/// Returns a copy of this solid angle value in steradians
 	pub fn to_sr(&self) -> T { self.sr.clone() }
 
-	/// Returns a new solid angle value from the given number of steradians
-	///
-	/// # Arguments
-	/// * `steradians` - Any number-like type, representing a quantity of steradian
-	pub fn from_steradians(steradians: T) -> Self { SolidAngle{sr: steradians} }
-	
-	/// Returns a copy of this solid angle value in steradians
-	pub fn to_steradians(&self) -> T { self.sr.clone() }
+	/// Returns a new solid angle value from the given number of steradians
+	///
+	/// # Arguments
+	/// * `steradians` - Any number-like type, representing a quantity of steradian
+	pub fn from_steradians(steradians: T) -> Self { SolidAngle{sr: steradians} }
+	
+	/// Returns a copy of this solid angle value in steradians
+	pub fn to_steradians(&self) -> T { self.sr.clone() }
+
+}
+
+/// Formats solid angle values as a number followed by its unit symbol, eg "1500 sr"
+/// (complex numbers are wrapped in parentheses, eg "(1+2i) sr").
+/// The alternate flag (`{:#}`) scales the number by the nearest SI prefix where the unit symbol allows it
+impl<T> fmt::Display for SolidAngle<T> where T: NumLike {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		if f.alternate() {
+			return super::fmt_with_si_prefix(f, &self.sr, Self::unit_symbol(), true);
+		}
+		super::fmt_unit_value(f, &self.sr, Self::unit_symbol())
+	}
+}
+
+/// Formats solid angle values in exponential notation followed by the unit symbol, eg "1.5e3 sr"
+impl<T> fmt::LowerExp for SolidAngle<T> where T: NumLike+fmt::LowerExp {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		fmt::LowerExp::fmt(&self.sr, f)?;
+		write!(f, " {}", Self::unit_symbol())
+	}
+}
+
+/// Formats solid angle values in exponential notation followed by the unit symbol, eg "1.5E3 sr"
+impl<T> fmt::UpperExp for SolidAngle<T> where T: NumLike+fmt::UpperExp {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		fmt::UpperExp::fmt(&self.sr, f)?;
+		write!(f, " {}", Self::unit_symbol())
+	}
+}
+
+impl<T> super::SiUnit for SolidAngle<T> where T: NumLike {
+	type Number = T;
+	fn unit_name(&self) -> &'static str { Self::unit_name() }
+	fn unit_symbol(&self) -> &'static str { Self::unit_symbol() }
+	fn raw(&self) -> &T { &self.sr }
+	fn dimension(&self) -> super::Dimension { Self::dimension() }
+	fn from_raw(value: T) -> Self { SolidAngle{sr: value} }
+	fn into_raw(self) -> T { self.sr }
+}
+
+#[cfg(feature="alloc")]
+impl<T> SolidAngle<T> where T: NumLike {
+	/// Returns this solid angle value in the canonical text format "<value> <symbol>", eg "5 sr".
+	/// Unlike `Display`, this format never changes with precision or alternate flags, and it is the
+	/// input format expected by `from_str(...)`, so `x.canonical_string().parse()` returns the same value
+	pub fn canonical_string(&self) -> alloc::string::String {
+		alloc::format!("{} {}", self.sr, Self::unit_symbol())
+	}
+
+	/// Returns a `Vec` of solid angle values copied from the given slice of numbers
+	///
+	/// # Arguments
+	/// * `values` - Slice of numbers, representing quantities of steradian
+	pub fn from_sr_slice(values: &[T]) -> alloc::vec::Vec<Self> {
+		values.iter().map(|x| SolidAngle{sr: x.clone()}).collect()
+	}
+}
+
+/// Parses a solid angle value from a number followed by its unit symbol, eg "5 sr"
+impl<T> core::str::FromStr for SolidAngle<T> where T: NumLike+core::str::FromStr {
+	type Err = SiParseError;
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		Ok(SolidAngle{sr: super::parse_unit_value(s, Self::unit_symbol())?})
+	}
+}
+
+/// Parses a solid angle value from a number followed by its unit symbol, eg "5 sr" 
+/// (same as `str::parse()`)
+impl<T> core::convert::TryFrom<&str> for SolidAngle<T> where T: NumLike+core::str::FromStr {
+	type Error = SiParseError;
+	fn try_from(s: &str) -> Result<Self, Self::Error> {
+		<Self as core::str::FromStr>::from_str(s)
+	}
+}
+
+#[cfg(feature="num-traits")]
+impl<T> SolidAngle<T> where T: NumLike+num_traits::Euclid {
+
+	/// Returns the Euclidean remainder of dividing this solid angle value by another, 
+	/// which is never negative (requires the `num-traits` feature)
+	///
+	/// # Arguments
+	/// * `rhs` - The solid angle value to divide by
+	pub fn rem_euclid(self, rhs: Self) -> Self {
+		SolidAngle{sr: self.sr.rem_euclid(&rhs.sr)}
+	}
+
+	/// Returns the Euclidean quotient of dividing this solid angle value by another, 
+	/// ie the whole number of times that `rhs` fits into this value, rounded such that 
+	/// `rem_euclid(...)` is never negative (requires the `num-traits` feature)
+	///
+	/// # Arguments
+	/// * `rhs` - The solid angle value to divide by
+	pub fn div_euclid(self, rhs: Self) -> T {
+		self.sr.div_euclid(&rhs.sr)
+	}
+}
+
+/// Summing solid angle values returns their total as a solid angle value (or zero if there are no values)
+impl<T> core::iter::Sum<SolidAngle<T>> for SolidAngle<T> where T: NumLike+core::iter::Sum {
+	fn sum<I: Iterator<Item=SolidAngle<T>>>(iter: I) -> Self {
+		SolidAngle{sr: iter.map(|x| x.sr).sum()}
+	}
+}
+/// Summing solid angle values returns their total as a solid angle value (or zero if there are no values)
+impl<'a, T> core::iter::Sum<&'a SolidAngle<T>> for SolidAngle<T> where T: NumLike+core::iter::Sum+'a {
+	fn sum<I: Iterator<Item=&'a SolidAngle<T>>>(iter: I) -> Self {
+		SolidAngle{sr: iter.map(|x| x.sr.clone()).sum()}
+	}
+}
+
+impl<T> SolidAngle<T> where T: NumLike+From<f64> {
+
+	/// Returns the weighted average of the given solid angle values, where each 
+	/// value is paired with its weight (weights do not need to add up to 1). If the 
+	/// total weight is zero (including when `values` is empty), then zero is returned
+	///
+	/// # Arguments
+	/// * `values` - Slice of (solid angle value, weight) pairs
+	pub fn weighted_mean(values: &[(Self, f64)]) -> Self {
+		let total_weight: f64 = values.iter().map(|(_, weight)| *weight).sum();
+		if total_weight == 0.0 {
+			return SolidAngle{sr: T::from(0.0)};
+		}
+		let total = values.iter().fold(T::from(0.0),
+			|total, (value, weight)| total + value.sr.clone() * T::from(*weight));
+		SolidAngle{sr: total / T::from(total_weight)}
+	}
+}
+
+impl<T> SolidAngle<T> where T: NumLike+PartialEq+From<u8> {
+
+	/// Divides this solid angle value by another unit value (eg `distance.checked_div(time)` 
+	/// returns a velocity), returning `None` if the divisor is zero instead of producing 
+	/// infinity or NaN (or panicking, for integer number types). This works for every unit 
+	/// type that this solid angle value can be divided by with the `/` operator
+	///
+	/// # Arguments
+	/// * `rhs` - The unit value to divide by
+	pub fn checked_div<R>(self, rhs: R) -> Option<<Self as core::ops::Div<R>>::Output> 
+		where Self: core::ops::Div<R>, R: super::SiUnit<Number=T>
+	{
+		if *rhs.raw() == T::from(0u8) {
+			return None;
+		}
+		Some(self / rhs)
+	}
+}
+
+/// Multiplying a solid angle value by a dimensionless value returns a solid angle value
+impl<T> core::ops::Mul<super::base::Dimensionless<T>> for SolidAngle<T> where T: NumLike {
+	type Output = SolidAngle<T>;
+	fn mul(self, rhs: super::base::Dimensionless<T>) -> Self::Output {
+		SolidAngle{sr: self.sr * rhs.value}
+	}
+}
+/// Multiplying a dimensionless value by a solid angle value returns a solid angle value
+impl<T> core::ops::Mul<SolidAngle<T>> for super::base::Dimensionless<T> where T: NumLike {
+	type Output = SolidAngle<T>;
+	fn mul(self, rhs: SolidAngle<T>) -> Self::Output {
+		SolidAngle{sr: self.value * rhs.sr}
+	}
+}
+/// Dividing a solid angle value by a dimensionless value returns a solid angle value
+impl<T> core::ops::Div<super::base::Dimensionless<T>> for SolidAngle<T> where T: NumLike {
+	type Output = SolidAngle<T>;
+	fn div(self, rhs: super::base::Dimensionless<T>) -> Self::Output {
+		SolidAngle{sr: self.sr / rhs.value}
+	}
+}
+
+/// The remainder of dividing a solid angle value by another solid angle value is a solid angle value
+impl<T> core::ops::Rem<SolidAngle<T>> for SolidAngle<T> where T: NumLike+core::ops::Rem<Output=T> {
+	type Output = SolidAngle<T>;
+	fn rem(self, rhs: SolidAngle<T>) -> Self::Output {
+		SolidAngle{sr: self.sr % rhs.sr}
+	}
+}
+/// The remainder of dividing a solid angle value by another solid angle value is a solid angle value
+impl<T> core::ops::Rem<&SolidAngle<T>> for SolidAngle<T> where T: NumLike+core::ops::Rem<Output=T> {
+	type Output = SolidAngle<T>;
+	fn rem(self, rhs: &SolidAngle<T>) -> Self::Output {
+		SolidAngle{sr: self.sr % rhs.sr.clone()}
+	}
+}
+/// The remainder of dividing a solid angle value by another solid angle value is a solid angle value
+impl<T> core::ops::Rem<SolidAngle<T>> for &SolidAngle<T> where T: NumLike+core::ops::Rem<Output=T> {
+	type Output = SolidAngle<T>;
+	fn rem(self, rhs: SolidAngle<T>) -> Self::Output {
+		SolidAngle{sr: self.sr.clone() % rhs.sr}
+	}
+}
+/// The remainder of dividing a solid angle value by another solid angle value is a solid angle value
+impl<T> core::ops::Rem<&SolidAngle<T>> for &SolidAngle<T> where T: NumLike+core::ops::Rem<Output=T> {
+	type Output = SolidAngle<T>;
+	fn rem(self, rhs: &SolidAngle<T>) -> Self::Output {
+		SolidAngle{sr: self.sr.clone() % rhs.sr.clone()}
+	}
+}
+
+/// Serializes a solid angle value together with its unit symbol, eg 
+/// `{"value": 1.5, "unit": "sr"}` (requires the `serde-unit-tag` feature)
+#[cfg(feature="serde-unit-tag")]
+impl<T> serde::Serialize for SolidAngle<T> where T: NumLike+serde::Serialize {
+	fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+		super::serialize_with_unit_tag(&self.sr, Self::unit_symbol(), serializer)
+	}
+}
+/// Deserializes a solid angle value from a value and unit symbol, eg 
+/// `{"value": 1.5, "unit": "sr"}`, returning an error if the unit symbol 
+/// does not match (requires the `serde-unit-tag` feature)
+#[cfg(feature="serde-unit-tag")]
+impl<'de, T> serde::Deserialize<'de> for SolidAngle<T> where T: NumLike+serde::Deserialize<'de> {
+	fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+		Ok(SolidAngle{sr: super::deserialize_with_unit_tag(Self::unit_symbol(), deserializer)?})
+	}
+}
+
+/// The additive identity of solid angle values is zero steradian (requires the `num-traits` feature)
+#[cfg(feature="num-traits")]
+impl<T> num_traits::Zero for SolidAngle<T> where T: NumLike+num_traits::Zero {
+	fn zero() -> Self { SolidAngle{sr: T::zero()} }
+	fn is_zero(&self) -> bool { self.sr.is_zero() }
+}
+
+#[cfg(feature="num-traits")]
+impl<T> SolidAngle<T> where T: NumLike+num_traits::Signed {
+
+	/// Returns the sign of this solid angle value as a dimensionless number: 1 if it is 
+	/// positive, -1 if it is negative, and 0 if it is zero for integer number types 
+	/// (for floating-point number types, +0.0 returns 1.0, -0.0 returns -1.0, and NaN returns 
+	/// NaN) (requires the `num-traits` feature)
+	pub fn signum(self) -> T {
+		self.sr.signum()
+	}
+}
+
+impl<T> SolidAngle<T> where T: NumLike+PartialOrd {
+
+	/// Returns the absolute value of this solid angle value, ie its magnitude without a minus sign
+	/// (for floating-point number types, -0.0 returns +0.0)
+	pub fn abs(self) -> Self {
+		let negated = -self.sr.clone();
+		if self.sr < negated {
+			SolidAngle{sr: negated}
+		} else if self.sr == negated {
+			// zero, which is its own negation (adding +0.0 turns -0.0 into +0.0)
+			SolidAngle{sr: negated + self.sr}
+		} else {
+			self
+		}
+	}
+
+	/// Returns the lesser of this solid angle value and the given solid angle value
+	///
+	/// # Arguments
+	/// * `other` - The solid angle value to compare with this one
+	pub fn min(self, other: Self) -> Self {
+		if other.sr < self.sr { other } else { self }
+	}
+
+	/// Returns the greater of this solid angle value and the given solid angle value
+	///
+	/// # Arguments
+	/// * `other` - The solid angle value to compare with this one
+	pub fn max(self, other: Self) -> Self {
+		if other.sr > self.sr { other } else { self }
+	}
+
+	/// Returns the least of the given solid angle values, or `None` if there are none. 
+	/// Unlike `Iterator::min()`, this does not require `Ord`, so it works for 
+	/// floating-point numbers. NaN values are skipped (so the result is `None` if all 
+	/// values are NaN), and the first of several equal values is returned
+	///
+	/// # Arguments
+	/// * `values` - Any iterator or collection of solid angle values
+	pub fn min_of<I>(values: I) -> Option<Self> where I: IntoIterator<Item=Self> {
+		values.into_iter().filter(|x| x.sr.partial_cmp(&x.sr).is_some()).reduce(Self::min)
+	}
+
+	/// Returns the greatest of the given solid angle values, or `None` if there are none. 
+	/// Unlike `Iterator::max()`, this does not require `Ord`, so it works for 
+	/// floating-point numbers. NaN values are skipped (so the result is `None` if all 
+	/// values are NaN), and the first of several equal values is returned
+	///
+	/// # Arguments
+	/// * `values` - Any iterator or collection of solid angle values
+	pub fn max_of<I>(values: I) -> Option<Self> where I: IntoIterator<Item=Self> {
+		values.into_iter().filter(|x| x.sr.partial_cmp(&x.sr).is_some()).reduce(Self::max)
+	}
+
+	/// Returns this solid angle value restricted to the range from `lo` to `hi`
+	///
+	/// # Arguments
+	/// * `lo` - The minimum solid angle value
+	/// * `hi` - The maximum solid angle value
+	///
+	/// # Panics
+	/// Panics if `lo` is greater than `hi` (or if either of them is NaN)
+	pub fn clamp(self, lo: Self, hi: Self) -> Self {
+		assert!(lo.sr <= hi.sr, "lo must be less than or equal to hi");
+		if self.sr < lo.sr { lo } else if self.sr > hi.sr { hi } else { self }
+	}
+
+	/// Returns this solid angle value with its magnitude capped at `max`, keeping its 
+	/// sign (eg for capping a speed), or this value unchanged if its magnitude does not 
+	/// exceed `max`. For complex-valued solid angle, use `clamp_norm(...)` instead, 
+	/// which caps the magnitude while keeping the phase
+	///
+	/// # Arguments
+	/// * `max` - The maximum magnitude (should not be negative)
+	pub fn clamp_magnitude(self, max: Self) -> Self {
+		let negated_max = -max.sr.clone();
+		if self.sr > max.sr { 
+			max 
+		} else if self.sr < negated_max { 
+			SolidAngle{sr: negated_max} 
+		} else { 
+			self 
+		}
+	}
+
+	/// Returns true if this solid angle value differs from the given solid angle 
+	/// value by no more than the given absolute tolerance (which is also a 
+	/// solid angle value, so the comparison is dimensionally safe). NaN is never 
+	/// equal to anything. See also the `assert_unit_eq!(...)` macro.
+	///
+	/// # Arguments
+	/// * `other` - The solid angle value to compare with this one
+	/// * `tolerance` - The largest allowed difference between the two values
+	pub fn eq_within(self, other: Self, tolerance: Self) -> bool {
+		let diff = self.sr - other.sr;
+		diff <= tolerance.sr && -diff <= tolerance.sr
+	}
+}
+
+#[cfg(feature="num-complex")]
+impl SolidAngle<num_complex::Complex32> {
+	/// Returns the magnitude (aka norm or absolute value) of this complex solid angle 
+	/// value as a real-valued solid angle (requires the `num-complex` feature)
+	pub fn magnitude(self) -> SolidAngle<f32> {
+		SolidAngle{sr: self.sr.norm()}
+	}
+
+	/// Returns this complex solid angle value scaled down so that its magnitude is 
+	/// at most `max` (keeping its phase), or this value unchanged if its magnitude does 
+	/// not exceed `max`. This is the complex equivalent of `clamp_magnitude(...)` (requires 
+	/// the `num-complex` feature)
+	///
+	/// # Arguments
+	/// * `max` - The maximum magnitude (should not be negative)
+	pub fn clamp_norm(self, max: SolidAngle<f32>) -> Self {
+		let norm = self.sr.norm();
+		if norm > max.sr {
+			SolidAngle{sr: self.sr * (max.sr / norm)}
+		} else {
+			self
+		}
+	}
+}
+
+#[cfg(feature="num-complex")]
+impl SolidAngle<num_complex::Complex64> {
+	/// Returns the magnitude (aka norm or absolute value) of this complex solid angle 
+	/// value as a real-valued solid angle (requires the `num-complex` feature)
+	pub fn magnitude(self) -> SolidAngle<f64> {
+		SolidAngle{sr: self.sr.norm()}
+	}
+
+	/// Returns this complex solid angle value scaled down so that its magnitude is 
+	/// at most `max` (keeping its phase), or this value unchanged if its magnitude does 
+	/// not exceed `max`. This is the complex equivalent of `clamp_magnitude(...)` (requires 
+	/// the `num-complex` feature)
+	///
+	/// # Arguments
+	/// * `max` - The maximum magnitude (should not be negative)
+	pub fn clamp_norm(self, max: SolidAngle<f64>) -> Self {
+		let norm = self.sr.norm();
+		if norm > max.sr {
+			SolidAngle{sr: self.sr * (max.sr / norm)}
+		} else {
+			self
+		}
+	}
+}
+
+/// Rounding and other floating-point functions (requires the `num-traits` feature, 
+/// and either the `std` or `libm` feature)
+#[cfg(all(feature="num-traits", any(feature="std", feature="libm")))]
+impl<T> SolidAngle<T> where T: NumLike+num_traits::Float {
+
+	/// Returns this solid angle value rounded to the nearest whole number of steradian 
+	/// (half-way cases are rounded away from zero)
+	pub fn round(self) -> Self {
+		SolidAngle{sr: self.sr.round()}
+	}
+
+	/// Returns this solid angle value rounded down to a whole number of steradian
+	pub fn floor(self) -> Self {
+		SolidAngle{sr: self.sr.floor()}
+	}
+
+	/// Returns this solid angle value rounded up to a whole number of steradian
+	pub fn ceil(self) -> Self {
+		SolidAngle{sr: self.sr.ceil()}
+	}
+
+	/// Returns this solid angle value rounded towards zero to a whole number of steradian
+	pub fn trunc(self) -> Self {
+		SolidAngle{sr: self.sr.trunc()}
+	}
+
+	/// Returns this solid angle value rounded to the nearest multiple of the given step 
+	/// (half-way cases are rounded away from zero)
+	///
+	/// # Arguments
+	/// * `step` - The solid angle value to round to a multiple of
+	pub fn round_to(self, step: Self) -> Self {
+		SolidAngle{sr: (self.sr / step.sr).round() * step.sr}
+	}
+
+	/// Fused multiply-add: returns `self * a + b` computed with only one rounding 
+	/// error, which is more accurate (and on some hardware faster) than a separate 
+	/// multiplication and addition
+	///
+	/// # Arguments
+	/// * `a` - The scalar value to multiply this solid angle value by
+	/// * `b` - The solid angle value to add to the product
+	pub fn mul_add(self, a: T, b: Self) -> Self {
+		SolidAngle{sr: self.sr.mul_add(a, b.sr)}
+	}
+
+	/// Returns true if this solid angle value is neither infinite nor NaN
+	pub fn is_finite(&self) -> bool {
+		self.sr.is_finite()
+	}
+
+	/// Returns true if this solid angle value is positive or negative infinity
+	pub fn is_infinite(&self) -> bool {
+		self.sr.is_infinite()
+	}
+
+	/// Returns true if this solid angle value is NaN (not a number)
+	pub fn is_nan(&self) -> bool {
+		self.sr.is_nan()
+	}
+
+	/// Returns true if this solid angle value has a positive sign, including +0.0 
+	/// and positive infinity
+	pub fn is_sign_positive(&self) -> bool {
+		self.sr.is_sign_positive()
+	}
+
+	/// Returns true if this solid angle value has a negative sign, including -0.0 
+	/// and negative infinity
+	pub fn is_sign_negative(&self) -> bool {
+		self.sr.is_sign_negative()
+	}
+
+	/// Returns a solid angle value with the magnitude of this one and the sign of 
+	/// `sign_source`, eg for reflecting a value off of a surface
+	///
+	/// # Arguments
+	/// * `sign_source` - The solid angle value whose sign is copied
+	pub fn copysign(self, sign_source: Self) -> Self {
+		SolidAngle{sr: self.sr.copysign(sign_source.sr)}
+	}
+}
+
+#[cfg(feature="num-traits")]
+impl<T> SolidAngle<T> where T: NumLike+num_traits::CheckedAdd+num_traits::CheckedSub {
+
+	/// Adds another solid angle value to this one, returning `None` if the 
+	/// addition overflows the number type (requires the `num-traits` feature)
+	pub fn checked_add(self, rhs: Self) -> Option<Self> {
+		Some(SolidAngle{sr: self.sr.checked_add(&rhs.sr)?})
+	}
+
+	/// Subtracts another solid angle value from this one, returning `None` if 
+	/// the subtraction overflows the number type (requires the `num-traits` feature)
+	pub fn checked_sub(self, rhs: Self) -> Option<Self> {
+		Some(SolidAngle{sr: self.sr.checked_sub(&rhs.sr)?})
+	}
+}
+
+#[cfg(feature="num-traits")]
+impl<T> SolidAngle<T> where T: NumLike+num_traits::SaturatingAdd+num_traits::SaturatingSub {
 
+	/// Adds another solid angle value to this one, clamping the result to the 
+	/// minimum or maximum value of the number type instead of overflowing 
+	/// (requires the `num-traits` feature)
+	pub fn saturating_add(self, rhs: Self) -> Self {
+		SolidAngle{sr: self.sr.saturating_add(&rhs.sr)}
+	}
+
+	/// Subtracts another solid angle value from this one, clamping the result 
+	/// to the minimum or maximum value of the number type instead of overflowing 
+	/// (requires the `num-traits` feature)
+	pub fn saturating_sub(self, rhs: Self) -> Self {
+		SolidAngle{sr: self.sr.saturating_sub(&rhs.sr)}
+	}
 }
 
-impl<T> fmt::Display for SolidAngle<T> where T: NumLike {
-	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-		write!(f, "{} {}", &self.sr, Self::unit_symbol())
+impl<T> SolidAngle<T> where T: NumLike+Into<f64> {
+
+	/// Returns true if this solid angle value is equal to the given solid angle 
+	/// value to within the given number of significant figures (eg 6 significant 
+	/// figures allows a relative difference of up to 1 part per million). NaN is 
+	/// only approximately equal to NaN, infinity is only approximately equal to 
+	/// infinity of the same sign, and zero is only approximately equal to zero.
+	///
+	/// # Arguments
+	/// * `other` - The solid angle value to compare with this one
+	/// * `sig_figs` - The number of significant figures that must match
+	pub fn approx_eq(self, other: Self, sig_figs: i32) -> bool {
+		super::approx_eq_f64(self.sr.into(), other.sr.into(), sig_figs)
 	}
 }
 
 impl<T> SolidAngle<T> where T: NumLike+From<f64> {
+
+	/// Returns the linear interpolation between this solid angle value and the given 
+	/// solid angle value, ie `self*(1-t) + other*t`. The interpolation factor is not 
+	/// clamped, so values of `t` outside the range 0 to 1 extrapolate beyond the two 
+	/// values (see `lerp_clamped(...)`).
+	///
+	/// # Arguments
+	/// * `other` - The solid angle value to interpolate towards (returned when `t` is 1)
+	/// * `t` - The interpolation factor, where 0 returns this value and 1 returns `other`
+	pub fn lerp(self, other: Self, t: f64) -> Self {
+		SolidAngle{sr: self.sr * T::from(1.0 - t) + other.sr * T::from(t)}
+	}
+
+	/// Same as `lerp(...)`, except that the interpolation factor `t` is first clamped 
+	/// to the range 0 to 1, so that the result is always between the two values
+	///
+	/// # Arguments
+	/// * `other` - The solid angle value to interpolate towards (returned when `t` is 1 or more)
+	/// * `t` - The interpolation factor, where 0 returns this value and 1 returns `other`
+	pub fn lerp_clamped(self, other: Self, t: f64) -> Self {
+		self.lerp(other, t.clamp(0.0, 1.0))
+	}
 	
 }
 
@@ -5593,6 +13024,72 @@ impl core::ops::Mul<&SolidAngle<num_complex::Complex64>> for &num_complex::Compl
 	}
 }
 
+/// Multiplying a unit value by a scalar value returns a unit value
+#[cfg(feature="num-rational")]
+impl core::ops::Mul<SolidAngle<num_rational::Rational64>> for num_rational::Rational64 {
+	type Output = SolidAngle<num_rational::Rational64>;
+	fn mul(self, rhs: SolidAngle<num_rational::Rational64>) -> Self::Output {
+		SolidAngle{sr: self * rhs.sr}
+	}
+}
+/// Multiplying a unit value by a scalar value returns a unit value
+#[cfg(feature="num-rational")]
+impl core::ops::Mul<SolidAngle<num_rational::Rational64>> for &num_rational::Rational64 {
+	type Output = SolidAngle<num_rational::Rational64>;
+	fn mul(self, rhs: SolidAngle<num_rational::Rational64>) -> Self::Output {
+		SolidAngle{sr: self.clone() * rhs.sr}
+	}
+}
+/// Multiplying a unit value by a scalar value returns a unit value
+#[cfg(feature="num-rational")]
+impl core::ops::Mul<&SolidAngle<num_rational::Rational64>> for num_rational::Rational64 {
+	type Output = SolidAngle<num_rational::Rational64>;
+	fn mul(self, rhs: &SolidAngle<num_rational::Rational64>) -> Self::Output {
+		SolidAngle{sr: self * rhs.sr.clone()}
+	}
+}
+/// Multiplying a unit value by a scalar value returns a unit value
+#[cfg(feature="num-rational")]
+impl core::ops::Mul<&SolidAngle<num_rational::Rational64>> for &num_rational::Rational64 {
+	type Output = SolidAngle<num_rational::Rational64>;
+	fn mul(self, rhs: &SolidAngle<num_rational::Rational64>) -> Self::Output {
+		SolidAngle{sr: self.clone() * rhs.sr.clone()}
+	}
+}
+
+/// Multiplying a unit value by a scalar value returns a unit value
+#[cfg(feature="num-rational")]
+impl core::ops::Mul<SolidAngle<num_rational::BigRational>> for num_rational::BigRational {
+	type Output = SolidAngle<num_rational::BigRational>;
+	fn mul(self, rhs: SolidAngle<num_rational::BigRational>) -> Self::Output {
+		SolidAngle{sr: self * rhs.sr}
+	}
+}
+/// Multiplying a unit value by a scalar value returns a unit value
+#[cfg(feature="num-rational")]
+impl core::ops::Mul<SolidAngle<num_rational::BigRational>> for &num_rational::BigRational {
+	type Output = SolidAngle<num_rational::BigRational>;
+	fn mul(self, rhs: SolidAngle<num_rational::BigRational>) -> Self::Output {
+		SolidAngle{sr: self.clone() * rhs.sr}
+	}
+}
+/// Multiplying a unit value by a scalar value returns a unit value
+#[cfg(feature="num-rational")]
+impl core::ops::Mul<&SolidAngle<num_rational::BigRational>> for num_rational::BigRational {
+	type Output = SolidAngle<num_rational::BigRational>;
+	fn mul(self, rhs: &SolidAngle<num_rational::BigRational>) -> Self::Output {
+		SolidAngle{sr: self * rhs.sr.clone()}
+	}
+}
+/// Multiplying a unit value by a scalar value returns a unit value
+#[cfg(feature="num-rational")]
+impl core::ops::Mul<&SolidAngle<num_rational::BigRational>> for &num_rational::BigRational {
+	type Output = SolidAngle<num_rational::BigRational>;
+	fn mul(self, rhs: &SolidAngle<num_rational::BigRational>) -> Self::Output {
+		SolidAngle{sr: self.clone() * rhs.sr.clone()}
+	}
+}
+
 
 
 /// Converts a SolidAngle into the equivalent [uom](https://crates.io/crates/uom) type [SolidAngle](https://docs.rs/uom/0.34.0/uom/si/f32/type.SolidAngle.html)
@@ -5628,6 +13125,44 @@ impl<T> From<uom::si::f64::SolidAngle> for SolidAngle<T> where T: NumLike+From<f
 }
 
 
+
+/// Multiplying a SolidAngle by an [nalgebra](https://crates.io/crates/nalgebra) 
+/// vector or matrix of unitless values returns a vector or matrix of SolidAngle 
+/// values (eg a direction vector multiplied by a SolidAngle)
+#[cfg(feature="nalgebra")]
+impl<T, R, C, S> core::ops::Mul<nalgebra::Matrix<T, R, C, S>> for SolidAngle<T> where 
+	T: NumLike+nalgebra::Scalar, R: nalgebra::Dim, C: nalgebra::Dim, S: nalgebra::RawStorage<T, R, C>, 
+	nalgebra::DefaultAllocator: nalgebra::allocator::Allocator<R, C> {
+	type Output = nalgebra::OMatrix<SolidAngle<T>, R, C>;
+	fn mul(self, rhs: nalgebra::Matrix<T, R, C, S>) -> Self::Output {
+		rhs.map(|x| SolidAngle{sr: self.sr.clone() * x})
+	}
+}
+/// Multiplying an [nalgebra](https://crates.io/crates/nalgebra) vector or matrix of 
+/// unitless values by a SolidAngle returns a vector or matrix of SolidAngle values
+#[cfg(feature="nalgebra")]
+impl<T, R, C, S> core::ops::Mul<SolidAngle<T>> for nalgebra::Matrix<T, R, C, S> where 
+	T: NumLike+nalgebra::Scalar, R: nalgebra::Dim, C: nalgebra::Dim, S: nalgebra::RawStorage<T, R, C>, 
+	nalgebra::DefaultAllocator: nalgebra::allocator::Allocator<R, C> {
+	type Output = nalgebra::OMatrix<SolidAngle<T>, R, C>;
+	fn mul(self, rhs: SolidAngle<T>) -> Self::Output {
+		self.map(|x| SolidAngle{sr: x * rhs.sr.clone()})
+	}
+}
+/// Dividing an [nalgebra](https://crates.io/crates/nalgebra) vector or matrix of 
+/// SolidAngle values by a SolidAngle returns a vector or matrix of unitless values
+#[cfg(feature="nalgebra")]
+impl<T, R, C, S> core::ops::Div<SolidAngle<T>> for nalgebra::Matrix<SolidAngle<T>, R, C, S> where 
+	T: NumLike+nalgebra::Scalar, R: nalgebra::Dim, C: nalgebra::Dim, S: nalgebra::RawStorage<SolidAngle<T>, R, C>, 
+	nalgebra::DefaultAllocator: nalgebra::allocator::Allocator<R, C> {
+	type Output = nalgebra::OMatrix<T, R, C>;
+	fn div(self, rhs: SolidAngle<T>) -> Self::Output {
+		self.map(|x| x.sr / rhs.sr.clone())
+	}
+}
+
+
+
 // SolidAngle / InverseLuminosity -> LuminousFlux
 /// Dividing a SolidAngle by a InverseLuminosity returns a value of type LuminousFlux
 impl<T> core::ops::Div<InverseLuminosity<T>> for SolidAngle<T> where T: NumLike {
@@ -5688,6 +13223,36 @@ impl<T> core::ops::Mul<&Luminosity<T>> for &SolidAngle<T> where T: NumLike {
 	}
 }
 
+// SolidAngle * InverseIrradiance -> InverseRadiance
+/// Multiplying a SolidAngle by a InverseIrradiance returns a value of type InverseRadiance
+impl<T> core::ops::Mul<InverseIrradiance<T>> for SolidAngle<T> where T: NumLike {
+	type Output = InverseRadiance<T>;
+	fn mul(self, rhs: InverseIrradiance<T>) -> Self::Output {
+		InverseRadiance{m2sr_per_W: self.sr * rhs.m2_per_W}
+	}
+}
+/// Multiplying a SolidAngle by a InverseIrradiance returns a value of type InverseRadiance
+impl<T> core::ops::Mul<InverseIrradiance<T>> for &SolidAngle<T> where T: NumLike {
+	type Output = InverseRadiance<T>;
+	fn mul(self, rhs: InverseIrradiance<T>) -> Self::Output {
+		InverseRadiance{m2sr_per_W: self.sr.clone() * rhs.m2_per_W}
+	}
+}
+/// Multiplying a SolidAngle by a InverseIrradiance returns a value of type InverseRadiance
+impl<T> core::ops::Mul<&InverseIrradiance<T>> for SolidAngle<T> where T: NumLike {
+	type Output = InverseRadiance<T>;
+	fn mul(self, rhs: &InverseIrradiance<T>) -> Self::Output {
+		InverseRadiance{m2sr_per_W: self.sr * rhs.m2_per_W.clone()}
+	}
+}
+/// Multiplying a SolidAngle by a InverseIrradiance returns a value of type InverseRadiance
+impl<T> core::ops::Mul<&InverseIrradiance<T>> for &SolidAngle<T> where T: NumLike {
+	type Output = InverseRadiance<T>;
+	fn mul(self, rhs: &InverseIrradiance<T>) -> Self::Output {
+		InverseRadiance{m2sr_per_W: self.sr.clone() * rhs.m2_per_W.clone()}
+	}
+}
+
 // SolidAngle * InverseLuminousFlux -> InverseLuminosity
 /// Multiplying a SolidAngle by a InverseLuminousFlux returns a value of type InverseLuminosity
 impl<T> core::ops::Mul<InverseLuminousFlux<T>> for SolidAngle<T> where T: NumLike {
@@ -5718,6 +13283,66 @@ impl<T> core::ops::Mul<&InverseLuminousFlux<T>> for &SolidAngle<T> where T: NumL
 	}
 }
 
+// SolidAngle / InverseRadiance -> Irradiance
+/// Dividing a SolidAngle by a InverseRadiance returns a value of type Irradiance
+impl<T> core::ops::Div<InverseRadiance<T>> for SolidAngle<T> where T: NumLike {
+	type Output = Irradiance<T>;
+	fn div(self, rhs: InverseRadiance<T>) -> Self::Output {
+		Irradiance{W_per_m2: self.sr / rhs.m2sr_per_W}
+	}
+}
+/// Dividing a SolidAngle by a InverseRadiance returns a value of type Irradiance
+impl<T> core::ops::Div<InverseRadiance<T>> for &SolidAngle<T> where T: NumLike {
+	type Output = Irradiance<T>;
+	fn div(self, rhs: InverseRadiance<T>) -> Self::Output {
+		Irradiance{W_per_m2: self.sr.clone() / rhs.m2sr_per_W}
+	}
+}
+/// Dividing a SolidAngle by a InverseRadiance returns a value of type Irradiance
+impl<T> core::ops::Div<&InverseRadiance<T>> for SolidAngle<T> where T: NumLike {
+	type Output = Irradiance<T>;
+	fn div(self, rhs: &InverseRadiance<T>) -> Self::Output {
+		Irradiance{W_per_m2: self.sr / rhs.m2sr_per_W.clone()}
+	}
+}
+/// Dividing a SolidAngle by a InverseRadiance returns a value of type Irradiance
+impl<T> core::ops::Div<&InverseRadiance<T>> for &SolidAngle<T> where T: NumLike {
+	type Output = Irradiance<T>;
+	fn div(self, rhs: &InverseRadiance<T>) -> Self::Output {
+		Irradiance{W_per_m2: self.sr.clone() / rhs.m2sr_per_W.clone()}
+	}
+}
+
+// SolidAngle / Irradiance -> InverseRadiance
+/// Dividing a SolidAngle by a Irradiance returns a value of type InverseRadiance
+impl<T> core::ops::Div<Irradiance<T>> for SolidAngle<T> where T: NumLike {
+	type Output = InverseRadiance<T>;
+	fn div(self, rhs: Irradiance<T>) -> Self::Output {
+		InverseRadiance{m2sr_per_W: self.sr / rhs.W_per_m2}
+	}
+}
+/// Dividing a SolidAngle by a Irradiance returns a value of type InverseRadiance
+impl<T> core::ops::Div<Irradiance<T>> for &SolidAngle<T> where T: NumLike {
+	type Output = InverseRadiance<T>;
+	fn div(self, rhs: Irradiance<T>) -> Self::Output {
+		InverseRadiance{m2sr_per_W: self.sr.clone() / rhs.W_per_m2}
+	}
+}
+/// Dividing a SolidAngle by a Irradiance returns a value of type InverseRadiance
+impl<T> core::ops::Div<&Irradiance<T>> for SolidAngle<T> where T: NumLike {
+	type Output = InverseRadiance<T>;
+	fn div(self, rhs: &Irradiance<T>) -> Self::Output {
+		InverseRadiance{m2sr_per_W: self.sr / rhs.W_per_m2.clone()}
+	}
+}
+/// Dividing a SolidAngle by a Irradiance returns a value of type InverseRadiance
+impl<T> core::ops::Div<&Irradiance<T>> for &SolidAngle<T> where T: NumLike {
+	type Output = InverseRadiance<T>;
+	fn div(self, rhs: &Irradiance<T>) -> Self::Output {
+		InverseRadiance{m2sr_per_W: self.sr.clone() / rhs.W_per_m2.clone()}
+	}
+}
+
 // SolidAngle / LuminousFlux -> InverseLuminosity
 /// Dividing a SolidAngle by a LuminousFlux returns a value of type InverseLuminosity
 impl<T> core::ops::Div<LuminousFlux<T>> for SolidAngle<T> where T: NumLike {
@@ -5748,6 +13373,36 @@ impl<T> core::ops::Div<&LuminousFlux<T>> for &SolidAngle<T> where T: NumLike {
 	}
 }
 
+// SolidAngle * Radiance -> Irradiance
+/// Multiplying a SolidAngle by a Radiance returns a value of type Irradiance
+impl<T> core::ops::Mul<Radiance<T>> for SolidAngle<T> where T: NumLike {
+	type Output = Irradiance<T>;
+	fn mul(self, rhs: Radiance<T>) -> Self::Output {
+		Irradiance{W_per_m2: self.sr * rhs.W_per_m2sr}
+	}
+}
+/// Multiplying a SolidAngle by a Radiance returns a value of type Irradiance
+impl<T> core::ops::Mul<Radiance<T>> for &SolidAngle<T> where T: NumLike {
+	type Output = Irradiance<T>;
+	fn mul(self, rhs: Radiance<T>) -> Self::Output {
+		Irradiance{W_per_m2: self.sr.clone() * rhs.W_per_m2sr}
+	}
+}
+/// Multiplying a SolidAngle by a Radiance returns a value of type Irradiance
+impl<T> core::ops::Mul<&Radiance<T>> for SolidAngle<T> where T: NumLike {
+	type Output = Irradiance<T>;
+	fn mul(self, rhs: &Radiance<T>) -> Self::Output {
+		Irradiance{W_per_m2: self.sr * rhs.W_per_m2sr.clone()}
+	}
+}
+/// Multiplying a SolidAngle by a Radiance returns a value of type Irradiance
+impl<T> core::ops::Mul<&Radiance<T>> for &SolidAngle<T> where T: NumLike {
+	type Output = Irradiance<T>;
+	fn mul(self, rhs: &Radiance<T>) -> Self::Output {
+		Irradiance{W_per_m2: self.sr.clone() * rhs.W_per_m2sr.clone()}
+	}
+}
+
 // SolidAngle / Angle -> Angle
 /// Dividing a SolidAngle by a Angle returns a value of type Angle
 impl<T> core::ops::Div<Angle<T>> for SolidAngle<T> where T: NumLike {
@@ -5763,75 +13418,241 @@ impl<T> core::ops::Div<Angle<T>> for &SolidAngle<T> where T: NumLike {
 		Angle{rad: self.sr.clone() / rhs.rad}
 	}
 }
-/// Dividing a SolidAngle by a Angle returns a value of type Angle
-impl<T> core::ops::Div<&Angle<T>> for SolidAngle<T> where T: NumLike {
-	type Output = Angle<T>;
-	fn div(self, rhs: &Angle<T>) -> Self::Output {
-		Angle{rad: self.sr / rhs.rad.clone()}
+/// Dividing a SolidAngle by a Angle returns a value of type Angle
+impl<T> core::ops::Div<&Angle<T>> for SolidAngle<T> where T: NumLike {
+	type Output = Angle<T>;
+	fn div(self, rhs: &Angle<T>) -> Self::Output {
+		Angle{rad: self.sr / rhs.rad.clone()}
+	}
+}
+/// Dividing a SolidAngle by a Angle returns a value of type Angle
+impl<T> core::ops::Div<&Angle<T>> for &SolidAngle<T> where T: NumLike {
+	type Output = Angle<T>;
+	fn div(self, rhs: &Angle<T>) -> Self::Output {
+		Angle{rad: self.sr.clone() / rhs.rad.clone()}
+	}
+}
+
+// SolidAngle * InverseAngle -> Angle
+/// Multiplying a SolidAngle by a InverseAngle returns a value of type Angle
+impl<T> core::ops::Mul<InverseAngle<T>> for SolidAngle<T> where T: NumLike {
+	type Output = Angle<T>;
+	fn mul(self, rhs: InverseAngle<T>) -> Self::Output {
+		Angle{rad: self.sr * rhs.per_rad}
+	}
+}
+/// Multiplying a SolidAngle by a InverseAngle returns a value of type Angle
+impl<T> core::ops::Mul<InverseAngle<T>> for &SolidAngle<T> where T: NumLike {
+	type Output = Angle<T>;
+	fn mul(self, rhs: InverseAngle<T>) -> Self::Output {
+		Angle{rad: self.sr.clone() * rhs.per_rad}
+	}
+}
+/// Multiplying a SolidAngle by a InverseAngle returns a value of type Angle
+impl<T> core::ops::Mul<&InverseAngle<T>> for SolidAngle<T> where T: NumLike {
+	type Output = Angle<T>;
+	fn mul(self, rhs: &InverseAngle<T>) -> Self::Output {
+		Angle{rad: self.sr * rhs.per_rad.clone()}
+	}
+}
+/// Multiplying a SolidAngle by a InverseAngle returns a value of type Angle
+impl<T> core::ops::Mul<&InverseAngle<T>> for &SolidAngle<T> where T: NumLike {
+	type Output = Angle<T>;
+	fn mul(self, rhs: &InverseAngle<T>) -> Self::Output {
+		Angle{rad: self.sr.clone() * rhs.per_rad.clone()}
+	}
+}
+
+impl<T> SolidAngle<T> where T: NumLike+From<u8> {
+	/// Returns the reciprocal of this SolidAngle value (ie 1 divided by 
+	/// this value) as a value of type InverseSolidAngle
+	pub fn recip(self) -> InverseSolidAngle<T> {
+		InverseSolidAngle{per_sr: T::from(1u8) / self.sr}
+	}
+}
+
+// 1/SolidAngle -> InverseSolidAngle
+/// Dividing a scalar value by a SolidAngle unit value returns a value of type InverseSolidAngle
+impl<T> core::ops::Div<SolidAngle<T>> for f64 where T: NumLike+From<f64> {
+	type Output = InverseSolidAngle<T>;
+	fn div(self, rhs: SolidAngle<T>) -> Self::Output {
+		InverseSolidAngle{per_sr: T::from(self) / rhs.sr}
+	}
+}
+/// Dividing a scalar value by a SolidAngle unit value returns a value of type InverseSolidAngle
+impl<T> core::ops::Div<SolidAngle<T>> for &f64 where T: NumLike+From<f64> {
+	type Output = InverseSolidAngle<T>;
+	fn div(self, rhs: SolidAngle<T>) -> Self::Output {
+		InverseSolidAngle{per_sr: T::from(self.clone()) / rhs.sr}
+	}
+}
+/// Dividing a scalar value by a SolidAngle unit value returns a value of type InverseSolidAngle
+impl<T> core::ops::Div<&SolidAngle<T>> for f64 where T: NumLike+From<f64> {
+	type Output = InverseSolidAngle<T>;
+	fn div(self, rhs: &SolidAngle<T>) -> Self::Output {
+		InverseSolidAngle{per_sr: T::from(self) / rhs.sr.clone()}
+	}
+}
+/// Dividing a scalar value by a SolidAngle unit value returns a value of type InverseSolidAngle
+impl<T> core::ops::Div<&SolidAngle<T>> for &f64 where T: NumLike+From<f64> {
+	type Output = InverseSolidAngle<T>;
+	fn div(self, rhs: &SolidAngle<T>) -> Self::Output {
+		InverseSolidAngle{per_sr: T::from(self.clone()) / rhs.sr.clone()}
+	}
+}
+
+// 1/SolidAngle -> InverseSolidAngle
+/// Dividing a scalar value by a SolidAngle unit value returns a value of type InverseSolidAngle
+impl<T> core::ops::Div<SolidAngle<T>> for f32 where T: NumLike+From<f32> {
+	type Output = InverseSolidAngle<T>;
+	fn div(self, rhs: SolidAngle<T>) -> Self::Output {
+		InverseSolidAngle{per_sr: T::from(self) / rhs.sr}
+	}
+}
+/// Dividing a scalar value by a SolidAngle unit value returns a value of type InverseSolidAngle
+impl<T> core::ops::Div<SolidAngle<T>> for &f32 where T: NumLike+From<f32> {
+	type Output = InverseSolidAngle<T>;
+	fn div(self, rhs: SolidAngle<T>) -> Self::Output {
+		InverseSolidAngle{per_sr: T::from(self.clone()) / rhs.sr}
+	}
+}
+/// Dividing a scalar value by a SolidAngle unit value returns a value of type InverseSolidAngle
+impl<T> core::ops::Div<&SolidAngle<T>> for f32 where T: NumLike+From<f32> {
+	type Output = InverseSolidAngle<T>;
+	fn div(self, rhs: &SolidAngle<T>) -> Self::Output {
+		InverseSolidAngle{per_sr: T::from(self) / rhs.sr.clone()}
+	}
+}
+/// Dividing a scalar value by a SolidAngle unit value returns a value of type InverseSolidAngle
+impl<T> core::ops::Div<&SolidAngle<T>> for &f32 where T: NumLike+From<f32> {
+	type Output = InverseSolidAngle<T>;
+	fn div(self, rhs: &SolidAngle<T>) -> Self::Output {
+		InverseSolidAngle{per_sr: T::from(self.clone()) / rhs.sr.clone()}
+	}
+}
+
+// 1/SolidAngle -> InverseSolidAngle
+/// Dividing a scalar value by a SolidAngle unit value returns a value of type InverseSolidAngle
+impl<T> core::ops::Div<SolidAngle<T>> for i64 where T: NumLike+From<i64> {
+	type Output = InverseSolidAngle<T>;
+	fn div(self, rhs: SolidAngle<T>) -> Self::Output {
+		InverseSolidAngle{per_sr: T::from(self) / rhs.sr}
+	}
+}
+/// Dividing a scalar value by a SolidAngle unit value returns a value of type InverseSolidAngle
+impl<T> core::ops::Div<SolidAngle<T>> for &i64 where T: NumLike+From<i64> {
+	type Output = InverseSolidAngle<T>;
+	fn div(self, rhs: SolidAngle<T>) -> Self::Output {
+		InverseSolidAngle{per_sr: T::from(self.clone()) / rhs.sr}
+	}
+}
+/// Dividing a scalar value by a SolidAngle unit value returns a value of type InverseSolidAngle
+impl<T> core::ops::Div<&SolidAngle<T>> for i64 where T: NumLike+From<i64> {
+	type Output = InverseSolidAngle<T>;
+	fn div(self, rhs: &SolidAngle<T>) -> Self::Output {
+		InverseSolidAngle{per_sr: T::from(self) / rhs.sr.clone()}
+	}
+}
+/// Dividing a scalar value by a SolidAngle unit value returns a value of type InverseSolidAngle
+impl<T> core::ops::Div<&SolidAngle<T>> for &i64 where T: NumLike+From<i64> {
+	type Output = InverseSolidAngle<T>;
+	fn div(self, rhs: &SolidAngle<T>) -> Self::Output {
+		InverseSolidAngle{per_sr: T::from(self.clone()) / rhs.sr.clone()}
+	}
+}
+
+// 1/SolidAngle -> InverseSolidAngle
+/// Dividing a scalar value by a SolidAngle unit value returns a value of type InverseSolidAngle
+impl<T> core::ops::Div<SolidAngle<T>> for i32 where T: NumLike+From<i32> {
+	type Output = InverseSolidAngle<T>;
+	fn div(self, rhs: SolidAngle<T>) -> Self::Output {
+		InverseSolidAngle{per_sr: T::from(self) / rhs.sr}
+	}
+}
+/// Dividing a scalar value by a SolidAngle unit value returns a value of type InverseSolidAngle
+impl<T> core::ops::Div<SolidAngle<T>> for &i32 where T: NumLike+From<i32> {
+	type Output = InverseSolidAngle<T>;
+	fn div(self, rhs: SolidAngle<T>) -> Self::Output {
+		InverseSolidAngle{per_sr: T::from(self.clone()) / rhs.sr}
+	}
+}
+/// Dividing a scalar value by a SolidAngle unit value returns a value of type InverseSolidAngle
+impl<T> core::ops::Div<&SolidAngle<T>> for i32 where T: NumLike+From<i32> {
+	type Output = InverseSolidAngle<T>;
+	fn div(self, rhs: &SolidAngle<T>) -> Self::Output {
+		InverseSolidAngle{per_sr: T::from(self) / rhs.sr.clone()}
 	}
 }
-/// Dividing a SolidAngle by a Angle returns a value of type Angle
-impl<T> core::ops::Div<&Angle<T>> for &SolidAngle<T> where T: NumLike {
-	type Output = Angle<T>;
-	fn div(self, rhs: &Angle<T>) -> Self::Output {
-		Angle{rad: self.sr.clone() / rhs.rad.clone()}
+/// Dividing a scalar value by a SolidAngle unit value returns a value of type InverseSolidAngle
+impl<T> core::ops::Div<&SolidAngle<T>> for &i32 where T: NumLike+From<i32> {
+	type Output = InverseSolidAngle<T>;
+	fn div(self, rhs: &SolidAngle<T>) -> Self::Output {
+		InverseSolidAngle{per_sr: T::from(self.clone()) / rhs.sr.clone()}
 	}
 }
 
-// SolidAngle * InverseAngle -> Angle
-/// Multiplying a SolidAngle by a InverseAngle returns a value of type Angle
-impl<T> core::ops::Mul<InverseAngle<T>> for SolidAngle<T> where T: NumLike {
-	type Output = Angle<T>;
-	fn mul(self, rhs: InverseAngle<T>) -> Self::Output {
-		Angle{rad: self.sr * rhs.per_rad}
+// 1/SolidAngle -> InverseSolidAngle
+/// Dividing a scalar value by a SolidAngle unit value returns a value of type InverseSolidAngle
+#[cfg(feature="num-bigfloat")]
+impl<T> core::ops::Div<SolidAngle<T>> for num_bigfloat::BigFloat where T: NumLike+From<num_bigfloat::BigFloat> {
+	type Output = InverseSolidAngle<T>;
+	fn div(self, rhs: SolidAngle<T>) -> Self::Output {
+		InverseSolidAngle{per_sr: T::from(self) / rhs.sr}
 	}
 }
-/// Multiplying a SolidAngle by a InverseAngle returns a value of type Angle
-impl<T> core::ops::Mul<InverseAngle<T>> for &SolidAngle<T> where T: NumLike {
-	type Output = Angle<T>;
-	fn mul(self, rhs: InverseAngle<T>) -> Self::Output {
-		Angle{rad: self.sr.clone() * rhs.per_rad}
+/// Dividing a scalar value by a SolidAngle unit value returns a value of type InverseSolidAngle
+#[cfg(feature="num-bigfloat")]
+impl<T> core::ops::Div<SolidAngle<T>> for &num_bigfloat::BigFloat where T: NumLike+From<num_bigfloat::BigFloat> {
+	type Output = InverseSolidAngle<T>;
+	fn div(self, rhs: SolidAngle<T>) -> Self::Output {
+		InverseSolidAngle{per_sr: T::from(self.clone()) / rhs.sr}
 	}
 }
-/// Multiplying a SolidAngle by a InverseAngle returns a value of type Angle
-impl<T> core::ops::Mul<&InverseAngle<T>> for SolidAngle<T> where T: NumLike {
-	type Output = Angle<T>;
-	fn mul(self, rhs: &InverseAngle<T>) -> Self::Output {
-		Angle{rad: self.sr * rhs.per_rad.clone()}
+/// Dividing a scalar value by a SolidAngle unit value returns a value of type InverseSolidAngle
+#[cfg(feature="num-bigfloat")]
+impl<T> core::ops::Div<&SolidAngle<T>> for num_bigfloat::BigFloat where T: NumLike+From<num_bigfloat::BigFloat> {
+	type Output = InverseSolidAngle<T>;
+	fn div(self, rhs: &SolidAngle<T>) -> Self::Output {
+		InverseSolidAngle{per_sr: T::from(self) / rhs.sr.clone()}
 	}
 }
-/// Multiplying a SolidAngle by a InverseAngle returns a value of type Angle
-impl<T> core::ops::Mul<&InverseAngle<T>> for &SolidAngle<T> where T: NumLike {
-	type Output = Angle<T>;
-	fn mul(self, rhs: &InverseAngle<T>) -> Self::Output {
-		Angle{rad: self.sr.clone() * rhs.per_rad.clone()}
+/// Dividing a scalar value by a SolidAngle unit value returns a value of type InverseSolidAngle
+#[cfg(feature="num-bigfloat")]
+impl<T> core::ops::Div<&SolidAngle<T>> for &num_bigfloat::BigFloat where T: NumLike+From<num_bigfloat::BigFloat> {
+	type Output = InverseSolidAngle<T>;
+	fn div(self, rhs: &SolidAngle<T>) -> Self::Output {
+		InverseSolidAngle{per_sr: T::from(self.clone()) / rhs.sr.clone()}
 	}
 }
 
 // 1/SolidAngle -> InverseSolidAngle
 /// Dividing a scalar value by a SolidAngle unit value returns a value of type InverseSolidAngle
-impl<T> core::ops::Div<SolidAngle<T>> for f64 where T: NumLike+From<f64> {
+#[cfg(feature="num-complex")]
+impl<T> core::ops::Div<SolidAngle<T>> for num_complex::Complex32 where T: NumLike+From<num_complex::Complex32> {
 	type Output = InverseSolidAngle<T>;
 	fn div(self, rhs: SolidAngle<T>) -> Self::Output {
 		InverseSolidAngle{per_sr: T::from(self) / rhs.sr}
 	}
 }
 /// Dividing a scalar value by a SolidAngle unit value returns a value of type InverseSolidAngle
-impl<T> core::ops::Div<SolidAngle<T>> for &f64 where T: NumLike+From<f64> {
+#[cfg(feature="num-complex")]
+impl<T> core::ops::Div<SolidAngle<T>> for &num_complex::Complex32 where T: NumLike+From<num_complex::Complex32> {
 	type Output = InverseSolidAngle<T>;
 	fn div(self, rhs: SolidAngle<T>) -> Self::Output {
 		InverseSolidAngle{per_sr: T::from(self.clone()) / rhs.sr}
 	}
 }
 /// Dividing a scalar value by a SolidAngle unit value returns a value of type InverseSolidAngle
-impl<T> core::ops::Div<&SolidAngle<T>> for f64 where T: NumLike+From<f64> {
+#[cfg(feature="num-complex")]
+impl<T> core::ops::Div<&SolidAngle<T>> for num_complex::Complex32 where T: NumLike+From<num_complex::Complex32> {
 	type Output = InverseSolidAngle<T>;
 	fn div(self, rhs: &SolidAngle<T>) -> Self::Output {
 		InverseSolidAngle{per_sr: T::from(self) / rhs.sr.clone()}
 	}
 }
 /// Dividing a scalar value by a SolidAngle unit value returns a value of type InverseSolidAngle
-impl<T> core::ops::Div<&SolidAngle<T>> for &f64 where T: NumLike+From<f64> {
+#[cfg(feature="num-complex")]
+impl<T> core::ops::Div<&SolidAngle<T>> for &num_complex::Complex32 where T: NumLike+From<num_complex::Complex32> {
 	type Output = InverseSolidAngle<T>;
 	fn div(self, rhs: &SolidAngle<T>) -> Self::Output {
 		InverseSolidAngle{per_sr: T::from(self.clone()) / rhs.sr.clone()}
@@ -5840,28 +13661,32 @@ impl<T> core::ops::Div<&SolidAngle<T>> for &f64 where T: NumLike+From<f64> {
 
 // 1/SolidAngle -> InverseSolidAngle
 /// Dividing a scalar value by a SolidAngle unit value returns a value of type InverseSolidAngle
-impl<T> core::ops::Div<SolidAngle<T>> for f32 where T: NumLike+From<f32> {
+#[cfg(feature="num-complex")]
+impl<T> core::ops::Div<SolidAngle<T>> for num_complex::Complex64 where T: NumLike+From<num_complex::Complex64> {
 	type Output = InverseSolidAngle<T>;
 	fn div(self, rhs: SolidAngle<T>) -> Self::Output {
 		InverseSolidAngle{per_sr: T::from(self) / rhs.sr}
 	}
 }
 /// Dividing a scalar value by a SolidAngle unit value returns a value of type InverseSolidAngle
-impl<T> core::ops::Div<SolidAngle<T>> for &f32 where T: NumLike+From<f32> {
+#[cfg(feature="num-complex")]
+impl<T> core::ops::Div<SolidAngle<T>> for &num_complex::Complex64 where T: NumLike+From<num_complex::Complex64> {
 	type Output = InverseSolidAngle<T>;
 	fn div(self, rhs: SolidAngle<T>) -> Self::Output {
 		InverseSolidAngle{per_sr: T::from(self.clone()) / rhs.sr}
 	}
 }
 /// Dividing a scalar value by a SolidAngle unit value returns a value of type InverseSolidAngle
-impl<T> core::ops::Div<&SolidAngle<T>> for f32 where T: NumLike+From<f32> {
+#[cfg(feature="num-complex")]
+impl<T> core::ops::Div<&SolidAngle<T>> for num_complex::Complex64 where T: NumLike+From<num_complex::Complex64> {
 	type Output = InverseSolidAngle<T>;
 	fn div(self, rhs: &SolidAngle<T>) -> Self::Output {
 		InverseSolidAngle{per_sr: T::from(self) / rhs.sr.clone()}
 	}
 }
 /// Dividing a scalar value by a SolidAngle unit value returns a value of type InverseSolidAngle
-impl<T> core::ops::Div<&SolidAngle<T>> for &f32 where T: NumLike+From<f32> {
+#[cfg(feature="num-complex")]
+impl<T> core::ops::Div<&SolidAngle<T>> for &num_complex::Complex64 where T: NumLike+From<num_complex::Complex64> {
 	type Output = InverseSolidAngle<T>;
 	fn div(self, rhs: &SolidAngle<T>) -> Self::Output {
 		InverseSolidAngle{per_sr: T::from(self.clone()) / rhs.sr.clone()}
@@ -5870,28 +13695,32 @@ impl<T> core::ops::Div<&SolidAngle<T>> for &f32 where T: NumLike+From<f32> {
 
 // 1/SolidAngle -> InverseSolidAngle
 /// Dividing a scalar value by a SolidAngle unit value returns a value of type InverseSolidAngle
-impl<T> core::ops::Div<SolidAngle<T>> for i64 where T: NumLike+From<i64> {
+#[cfg(feature="num-rational")]
+impl<T> core::ops::Div<SolidAngle<T>> for num_rational::Rational64 where T: NumLike+From<num_rational::Rational64> {
 	type Output = InverseSolidAngle<T>;
 	fn div(self, rhs: SolidAngle<T>) -> Self::Output {
 		InverseSolidAngle{per_sr: T::from(self) / rhs.sr}
 	}
 }
 /// Dividing a scalar value by a SolidAngle unit value returns a value of type InverseSolidAngle
-impl<T> core::ops::Div<SolidAngle<T>> for &i64 where T: NumLike+From<i64> {
+#[cfg(feature="num-rational")]
+impl<T> core::ops::Div<SolidAngle<T>> for &num_rational::Rational64 where T: NumLike+From<num_rational::Rational64> {
 	type Output = InverseSolidAngle<T>;
 	fn div(self, rhs: SolidAngle<T>) -> Self::Output {
 		InverseSolidAngle{per_sr: T::from(self.clone()) / rhs.sr}
 	}
 }
 /// Dividing a scalar value by a SolidAngle unit value returns a value of type InverseSolidAngle
-impl<T> core::ops::Div<&SolidAngle<T>> for i64 where T: NumLike+From<i64> {
+#[cfg(feature="num-rational")]
+impl<T> core::ops::Div<&SolidAngle<T>> for num_rational::Rational64 where T: NumLike+From<num_rational::Rational64> {
 	type Output = InverseSolidAngle<T>;
 	fn div(self, rhs: &SolidAngle<T>) -> Self::Output {
 		InverseSolidAngle{per_sr: T::from(self) / rhs.sr.clone()}
 	}
 }
 /// Dividing a scalar value by a SolidAngle unit value returns a value of type InverseSolidAngle
-impl<T> core::ops::Div<&SolidAngle<T>> for &i64 where T: NumLike+From<i64> {
+#[cfg(feature="num-rational")]
+impl<T> core::ops::Div<&SolidAngle<T>> for &num_rational::Rational64 where T: NumLike+From<num_rational::Rational64> {
 	type Output = InverseSolidAngle<T>;
 	fn div(self, rhs: &SolidAngle<T>) -> Self::Output {
 		InverseSolidAngle{per_sr: T::from(self.clone()) / rhs.sr.clone()}
@@ -5900,188 +13729,725 @@ impl<T> core::ops::Div<&SolidAngle<T>> for &i64 where T: NumLike+From<i64> {
 
 // 1/SolidAngle -> InverseSolidAngle
 /// Dividing a scalar value by a SolidAngle unit value returns a value of type InverseSolidAngle
-impl<T> core::ops::Div<SolidAngle<T>> for i32 where T: NumLike+From<i32> {
+#[cfg(feature="num-rational")]
+impl<T> core::ops::Div<SolidAngle<T>> for num_rational::BigRational where T: NumLike+From<num_rational::BigRational> {
 	type Output = InverseSolidAngle<T>;
 	fn div(self, rhs: SolidAngle<T>) -> Self::Output {
 		InverseSolidAngle{per_sr: T::from(self) / rhs.sr}
 	}
 }
-/// Dividing a scalar value by a SolidAngle unit value returns a value of type InverseSolidAngle
-impl<T> core::ops::Div<SolidAngle<T>> for &i32 where T: NumLike+From<i32> {
-	type Output = InverseSolidAngle<T>;
-	fn div(self, rhs: SolidAngle<T>) -> Self::Output {
-		InverseSolidAngle{per_sr: T::from(self.clone()) / rhs.sr}
+/// Dividing a scalar value by a SolidAngle unit value returns a value of type InverseSolidAngle
+#[cfg(feature="num-rational")]
+impl<T> core::ops::Div<SolidAngle<T>> for &num_rational::BigRational where T: NumLike+From<num_rational::BigRational> {
+	type Output = InverseSolidAngle<T>;
+	fn div(self, rhs: SolidAngle<T>) -> Self::Output {
+		InverseSolidAngle{per_sr: T::from(self.clone()) / rhs.sr}
+	}
+}
+/// Dividing a scalar value by a SolidAngle unit value returns a value of type InverseSolidAngle
+#[cfg(feature="num-rational")]
+impl<T> core::ops::Div<&SolidAngle<T>> for num_rational::BigRational where T: NumLike+From<num_rational::BigRational> {
+	type Output = InverseSolidAngle<T>;
+	fn div(self, rhs: &SolidAngle<T>) -> Self::Output {
+		InverseSolidAngle{per_sr: T::from(self) / rhs.sr.clone()}
+	}
+}
+/// Dividing a scalar value by a SolidAngle unit value returns a value of type InverseSolidAngle
+#[cfg(feature="num-rational")]
+impl<T> core::ops::Div<&SolidAngle<T>> for &num_rational::BigRational where T: NumLike+From<num_rational::BigRational> {
+	type Output = InverseSolidAngle<T>;
+	fn div(self, rhs: &SolidAngle<T>) -> Self::Output {
+		InverseSolidAngle{per_sr: T::from(self.clone()) / rhs.sr.clone()}
+	}
+}
+
+/// The volume unit type, defined as cubic meters in SI units
+#[derive(UnitStruct, Debug, Clone, Default)]
+#[si(no_display)]
+#[cfg_attr(all(feature="serde", not(feature="serde-unit-tag")), derive(Serialize, Deserialize))]
+#[repr(transparent)]
+pub struct Volume<T: NumLike>{
+	/// The value of this Volume in cubic meters
+	pub m3: T
+}
+
+impl<T> Volume<T> where T: NumLike {
+
+	/// Returns the standard unit name of volume: "cubic meters"
+	pub fn unit_name() -> &'static str { "cubic meters" }
+	
+	/// Returns the abbreviated name or symbol of volume: "m³" for cubic meters
+	pub fn unit_symbol() -> &'static str { "m³" }
+
+	/// Returns the exponents of the SI base units of volume (m^3)
+	pub const fn dimension() -> super::Dimension { super::Dimension{length: 3, mass: 0, time: 0, current: 0, temperature: 0, amount: 0, luminosity: 0} }
+
+	/// Returns a wrapper that formats this volume value with the full unit name instead of 
+	/// the unit symbol, eg "5 cubic meters" (the unit name is not singularized for values of 1)
+	pub fn display_named(&self) -> super::NamedDisplay<'_, T> {
+		super::NamedDisplay::new(&self.m3, Self::unit_name())
+	}
+
+	/// Returns a new volume value from the given number of cubic meters (same as 
+	/// `from_m3(...)`), for any number-like type. This is useful in generic code 
+	/// that works with several unit types
+	///
+	/// # Arguments
+	/// * `value` - Any number-like type, representing a quantity of cubic meters
+	pub const fn new(value: T) -> Self { Volume{m3: value} }
+
+	/// Returns a new volume value from the given number of cubic meters. Unlike
+	/// `from_m3(...)`, this is a `const fn`, so it can be used to declare `const` and `static` values
+	///
+	/// # Arguments
+	/// * `m3` - Any number-like type, representing a quantity of cubic meters
+	pub const fn from_m3_const(m3: T) -> Self { Volume{m3} }
+
+	/// Returns a volume value with a different number type, by applying the 
+	/// given function to the value in cubic meters (eg `.map(|x| x as f32)` to convert 
+	/// from `f64` to `f32`)
+	///
+	/// # Arguments
+	/// * `f` - Function that converts the number of cubic meters to the new number type
+	pub fn map<U, F>(self, f: F) -> Volume<U> where U: NumLike, F: FnOnce(T) -> U {
+		Volume{m3: f(self.m3)}
+	}
+
+	/// Returns a volume value with a different number type, converted 
+	/// losslessly with the `From` trait (eg from `f32` to `f64`)
+	pub fn cast<U>(self) -> Volume<U> where U: NumLike+From<T> {
+		Volume{m3: U::from(self.m3)}
+	}
+
+	/// Returns a reference to the value of this volume in cubic meters
+	pub fn as_raw(&self) -> &T {
+		&self.m3
+	}
+
+	/// Returns a mutable reference to the value of this volume in cubic meters, 
+	/// eg for passing to numeric libraries that modify values in place. The number 
+	/// is always in cubic meters, so changing it directly does not change the unit
+	pub fn raw_mut(&mut self) -> &mut T {
+		&mut self.m3
+	}
+
+	/// Returns a slice of numbers in cubic meters as a slice of volume values, 
+	/// without copying
+	///
+	/// # Arguments
+	/// * `values` - Slice of numbers, representing quantities of cubic meters
+	pub fn wrap_slice(values: &[T]) -> &[Self] {
+		// SAFETY: Volume<T> is #[repr(transparent)], so it has the same memory layout as T
+		unsafe { core::slice::from_raw_parts(values.as_ptr() as *const Self, values.len()) }
+	}
+
+	/// Returns a mutable slice of numbers in cubic meters as a mutable slice of 
+	/// volume values, without copying
+	///
+	/// # Arguments
+	/// * `values` - Slice of numbers, representing quantities of cubic meters
+	pub fn wrap_slice_mut(values: &mut [T]) -> &mut [Self] {
+		// SAFETY: Volume<T> is #[repr(transparent)], so it has the same memory layout as T
+		unsafe { core::slice::from_raw_parts_mut(values.as_mut_ptr() as *mut Self, values.len()) }
+	}
+
+	/// Returns the ratio of this volume value to another volume value 
+	/// (same as `self / other`). Dividing a unit value by another value of the same 
+	/// unit type cancels the units, so the ratio is a dimensionless number
+	///
+	/// # Arguments
+	/// * `other` - The volume value to divide by
+	pub fn ratio(self, other: Self) -> T {
+		self.m3 / other.m3
+	}
+
+	/// Returns the ratio of this volume value to another volume value 
+	/// as a `Dimensionless` value, instead of a bare number like `ratio(...)` does
+	///
+	/// # Arguments
+	/// * `other` - The volume value to divide by
+	pub fn dimensionless_ratio(self, other: Self) -> super::base::Dimensionless<T> {
+		super::base::Dimensionless{value: self.m3 / other.m3}
+	}
+
+	/// Returns this volume value multiplied by a dimensionless factor 
+	/// (same as `self * factor`)
+	///
+	/// # Arguments
+	/// * `factor` - The number to multiply by
+	pub fn scale(self, factor: T) -> Self {
+		Volume{m3: self.m3 * factor}
+	}
+	
+	/// Returns a new volume value from the given number of cubic meters
+	///
+	/// # Arguments
+	/// * `m3` - Any number-like type, representing a quantity of cubic meters
+	pub fn from_m3(m3: T) -> Self { Volume{m3: m3} }
+	
+	/// Returns a copy of this volume value in cubic meters
+	pub fn to_m3(&self) -> T { self.m3.clone() }
+
+	/// Returns a new volume value from the given number of cubic meters
+	///
+	/// # Arguments
+	/// * `cubic_meters` - Any number-like type, representing a quantity of cubic meters
+	pub fn from_cubic_meters(cubic_meters: T) -> Self { Volume{m3: cubic_meters} }
+	
+	/// Returns a copy of this volume value in cubic meters
+	pub fn to_cubic_meters(&self) -> T { self.m3.clone() }
+
+	/// Returns a new volume value from the given number of kiloliters
+	///
+	/// # Arguments
+	/// * `kL` - Any number-like type, representing a quantity of cubic meters
+	pub fn from_kL(kL: T) -> Self { Volume{m3: kL} }
+	
+	/// Returns a copy of this volume value in kiloliters
+	pub fn to_kL(&self) -> T { self.m3.clone() }
+
+}
+
+/// Formats volume values as a number followed by its unit symbol, eg "1500 m³"
+/// (complex numbers are wrapped in parentheses, eg "(1+2i) m³").
+/// The alternate flag (`{:#}`) scales the number by the nearest SI prefix where the unit symbol allows it
+impl<T> fmt::Display for Volume<T> where T: NumLike {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		if f.alternate() {
+			return super::fmt_with_si_prefix(f, &self.m3, Self::unit_symbol(), false);
+		}
+		super::fmt_unit_value(f, &self.m3, Self::unit_symbol())
+	}
+}
+
+/// Formats volume values in exponential notation followed by the unit symbol, eg "1.5e3 m³"
+impl<T> fmt::LowerExp for Volume<T> where T: NumLike+fmt::LowerExp {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		fmt::LowerExp::fmt(&self.m3, f)?;
+		write!(f, " {}", Self::unit_symbol())
+	}
+}
+
+/// Formats volume values in exponential notation followed by the unit symbol, eg "1.5E3 m³"
+impl<T> fmt::UpperExp for Volume<T> where T: NumLike+fmt::UpperExp {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		fmt::UpperExp::fmt(&self.m3, f)?;
+		write!(f, " {}", Self::unit_symbol())
+	}
+}
+
+impl<T> super::SiUnit for Volume<T> where T: NumLike {
+	type Number = T;
+	fn unit_name(&self) -> &'static str { Self::unit_name() }
+	fn unit_symbol(&self) -> &'static str { Self::unit_symbol() }
+	fn raw(&self) -> &T { &self.m3 }
+	fn dimension(&self) -> super::Dimension { Self::dimension() }
+	fn from_raw(value: T) -> Self { Volume{m3: value} }
+	fn into_raw(self) -> T { self.m3 }
+}
+
+#[cfg(feature="alloc")]
+impl<T> Volume<T> where T: NumLike {
+	/// Returns this volume value in the canonical text format "<value> <symbol>", eg "5 m³".
+	/// Unlike `Display`, this format never changes with precision or alternate flags, and it is the
+	/// input format expected by `from_str(...)`, so `x.canonical_string().parse()` returns the same value
+	pub fn canonical_string(&self) -> alloc::string::String {
+		alloc::format!("{} {}", self.m3, Self::unit_symbol())
+	}
+
+	/// Returns a `Vec` of volume values copied from the given slice of numbers
+	///
+	/// # Arguments
+	/// * `values` - Slice of numbers, representing quantities of cubic meters
+	pub fn from_m3_slice(values: &[T]) -> alloc::vec::Vec<Self> {
+		values.iter().map(|x| Volume{m3: x.clone()}).collect()
+	}
+}
+
+/// Parses a volume value from a number followed by its unit symbol, eg "5 m³"
+impl<T> core::str::FromStr for Volume<T> where T: NumLike+core::str::FromStr {
+	type Err = SiParseError;
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		Ok(Volume{m3: super::parse_unit_value(s, Self::unit_symbol())?})
+	}
+}
+
+/// Parses a volume value from a number followed by its unit symbol, eg "5 m³" 
+/// (same as `str::parse()`)
+impl<T> core::convert::TryFrom<&str> for Volume<T> where T: NumLike+core::str::FromStr {
+	type Error = SiParseError;
+	fn try_from(s: &str) -> Result<Self, Self::Error> {
+		<Self as core::str::FromStr>::from_str(s)
+	}
+}
+
+#[cfg(feature="num-traits")]
+impl<T> Volume<T> where T: NumLike+num_traits::Euclid {
+
+	/// Returns the Euclidean remainder of dividing this volume value by another, 
+	/// which is never negative (requires the `num-traits` feature)
+	///
+	/// # Arguments
+	/// * `rhs` - The volume value to divide by
+	pub fn rem_euclid(self, rhs: Self) -> Self {
+		Volume{m3: self.m3.rem_euclid(&rhs.m3)}
+	}
+
+	/// Returns the Euclidean quotient of dividing this volume value by another, 
+	/// ie the whole number of times that `rhs` fits into this value, rounded such that 
+	/// `rem_euclid(...)` is never negative (requires the `num-traits` feature)
+	///
+	/// # Arguments
+	/// * `rhs` - The volume value to divide by
+	pub fn div_euclid(self, rhs: Self) -> T {
+		self.m3.div_euclid(&rhs.m3)
+	}
+}
+
+/// Summing volume values returns their total as a volume value (or zero if there are no values)
+impl<T> core::iter::Sum<Volume<T>> for Volume<T> where T: NumLike+core::iter::Sum {
+	fn sum<I: Iterator<Item=Volume<T>>>(iter: I) -> Self {
+		Volume{m3: iter.map(|x| x.m3).sum()}
+	}
+}
+/// Summing volume values returns their total as a volume value (or zero if there are no values)
+impl<'a, T> core::iter::Sum<&'a Volume<T>> for Volume<T> where T: NumLike+core::iter::Sum+'a {
+	fn sum<I: Iterator<Item=&'a Volume<T>>>(iter: I) -> Self {
+		Volume{m3: iter.map(|x| x.m3.clone()).sum()}
+	}
+}
+
+impl<T> Volume<T> where T: NumLike+From<f64> {
+
+	/// Returns the weighted average of the given volume values, where each 
+	/// value is paired with its weight (weights do not need to add up to 1). If the 
+	/// total weight is zero (including when `values` is empty), then zero is returned
+	///
+	/// # Arguments
+	/// * `values` - Slice of (volume value, weight) pairs
+	pub fn weighted_mean(values: &[(Self, f64)]) -> Self {
+		let total_weight: f64 = values.iter().map(|(_, weight)| *weight).sum();
+		if total_weight == 0.0 {
+			return Volume{m3: T::from(0.0)};
+		}
+		let total = values.iter().fold(T::from(0.0),
+			|total, (value, weight)| total + value.m3.clone() * T::from(*weight));
+		Volume{m3: total / T::from(total_weight)}
+	}
+}
+
+impl<T> Volume<T> where T: NumLike+PartialEq+From<u8> {
+
+	/// Divides this volume value by another unit value (eg `distance.checked_div(time)` 
+	/// returns a velocity), returning `None` if the divisor is zero instead of producing 
+	/// infinity or NaN (or panicking, for integer number types). This works for every unit 
+	/// type that this volume value can be divided by with the `/` operator
+	///
+	/// # Arguments
+	/// * `rhs` - The unit value to divide by
+	pub fn checked_div<R>(self, rhs: R) -> Option<<Self as core::ops::Div<R>>::Output> 
+		where Self: core::ops::Div<R>, R: super::SiUnit<Number=T>
+	{
+		if *rhs.raw() == T::from(0u8) {
+			return None;
+		}
+		Some(self / rhs)
+	}
+}
+
+/// Multiplying a volume value by a dimensionless value returns a volume value
+impl<T> core::ops::Mul<super::base::Dimensionless<T>> for Volume<T> where T: NumLike {
+	type Output = Volume<T>;
+	fn mul(self, rhs: super::base::Dimensionless<T>) -> Self::Output {
+		Volume{m3: self.m3 * rhs.value}
 	}
 }
-/// Dividing a scalar value by a SolidAngle unit value returns a value of type InverseSolidAngle
-impl<T> core::ops::Div<&SolidAngle<T>> for i32 where T: NumLike+From<i32> {
-	type Output = InverseSolidAngle<T>;
-	fn div(self, rhs: &SolidAngle<T>) -> Self::Output {
-		InverseSolidAngle{per_sr: T::from(self) / rhs.sr.clone()}
+/// Multiplying a dimensionless value by a volume value returns a volume value
+impl<T> core::ops::Mul<Volume<T>> for super::base::Dimensionless<T> where T: NumLike {
+	type Output = Volume<T>;
+	fn mul(self, rhs: Volume<T>) -> Self::Output {
+		Volume{m3: self.value * rhs.m3}
 	}
 }
-/// Dividing a scalar value by a SolidAngle unit value returns a value of type InverseSolidAngle
-impl<T> core::ops::Div<&SolidAngle<T>> for &i32 where T: NumLike+From<i32> {
-	type Output = InverseSolidAngle<T>;
-	fn div(self, rhs: &SolidAngle<T>) -> Self::Output {
-		InverseSolidAngle{per_sr: T::from(self.clone()) / rhs.sr.clone()}
+/// Dividing a volume value by a dimensionless value returns a volume value
+impl<T> core::ops::Div<super::base::Dimensionless<T>> for Volume<T> where T: NumLike {
+	type Output = Volume<T>;
+	fn div(self, rhs: super::base::Dimensionless<T>) -> Self::Output {
+		Volume{m3: self.m3 / rhs.value}
 	}
 }
 
-// 1/SolidAngle -> InverseSolidAngle
-/// Dividing a scalar value by a SolidAngle unit value returns a value of type InverseSolidAngle
-#[cfg(feature="num-bigfloat")]
-impl<T> core::ops::Div<SolidAngle<T>> for num_bigfloat::BigFloat where T: NumLike+From<num_bigfloat::BigFloat> {
-	type Output = InverseSolidAngle<T>;
-	fn div(self, rhs: SolidAngle<T>) -> Self::Output {
-		InverseSolidAngle{per_sr: T::from(self) / rhs.sr}
+/// The remainder of dividing a volume value by another volume value is a volume value
+impl<T> core::ops::Rem<Volume<T>> for Volume<T> where T: NumLike+core::ops::Rem<Output=T> {
+	type Output = Volume<T>;
+	fn rem(self, rhs: Volume<T>) -> Self::Output {
+		Volume{m3: self.m3 % rhs.m3}
 	}
 }
-/// Dividing a scalar value by a SolidAngle unit value returns a value of type InverseSolidAngle
-#[cfg(feature="num-bigfloat")]
-impl<T> core::ops::Div<SolidAngle<T>> for &num_bigfloat::BigFloat where T: NumLike+From<num_bigfloat::BigFloat> {
-	type Output = InverseSolidAngle<T>;
-	fn div(self, rhs: SolidAngle<T>) -> Self::Output {
-		InverseSolidAngle{per_sr: T::from(self.clone()) / rhs.sr}
+/// The remainder of dividing a volume value by another volume value is a volume value
+impl<T> core::ops::Rem<&Volume<T>> for Volume<T> where T: NumLike+core::ops::Rem<Output=T> {
+	type Output = Volume<T>;
+	fn rem(self, rhs: &Volume<T>) -> Self::Output {
+		Volume{m3: self.m3 % rhs.m3.clone()}
 	}
 }
-/// Dividing a scalar value by a SolidAngle unit value returns a value of type InverseSolidAngle
-#[cfg(feature="num-bigfloat")]
-impl<T> core::ops::Div<&SolidAngle<T>> for num_bigfloat::BigFloat where T: NumLike+From<num_bigfloat::BigFloat> {
-	type Output = InverseSolidAngle<T>;
-	fn div(self, rhs: &SolidAngle<T>) -> Self::Output {
-		InverseSolidAngle{per_sr: T::from(self) / rhs.sr.clone()}
+/// The remainder of dividing a volume value by another volume value is a volume value
+impl<T> core::ops::Rem<Volume<T>> for &Volume<T> where T: NumLike+core::ops::Rem<Output=T> {
+	type Output = Volume<T>;
+	fn rem(self, rhs: Volume<T>) -> Self::Output {
+		Volume{m3: self.m3.clone() % rhs.m3}
 	}
 }
-/// Dividing a scalar value by a SolidAngle unit value returns a value of type InverseSolidAngle
-#[cfg(feature="num-bigfloat")]
-impl<T> core::ops::Div<&SolidAngle<T>> for &num_bigfloat::BigFloat where T: NumLike+From<num_bigfloat::BigFloat> {
-	type Output = InverseSolidAngle<T>;
-	fn div(self, rhs: &SolidAngle<T>) -> Self::Output {
-		InverseSolidAngle{per_sr: T::from(self.clone()) / rhs.sr.clone()}
+/// The remainder of dividing a volume value by another volume value is a volume value
+impl<T> core::ops::Rem<&Volume<T>> for &Volume<T> where T: NumLike+core::ops::Rem<Output=T> {
+	type Output = Volume<T>;
+	fn rem(self, rhs: &Volume<T>) -> Self::Output {
+		Volume{m3: self.m3.clone() % rhs.m3.clone()}
 	}
 }
 
-// 1/SolidAngle -> InverseSolidAngle
-/// Dividing a scalar value by a SolidAngle unit value returns a value of type InverseSolidAngle
-#[cfg(feature="num-complex")]
-impl<T> core::ops::Div<SolidAngle<T>> for num_complex::Complex32 where T: NumLike+From<num_complex::Complex32> {
-	type Output = InverseSolidAngle<T>;
-	fn div(self, rhs: SolidAngle<T>) -> Self::Output {
-		InverseSolidAngle{per_sr: T::from(self) / rhs.sr}
+/// Serializes a volume value together with its unit symbol, eg 
+/// `{"value": 1.5, "unit": "m³"}` (requires the `serde-unit-tag` feature)
+#[cfg(feature="serde-unit-tag")]
+impl<T> serde::Serialize for Volume<T> where T: NumLike+serde::Serialize {
+	fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+		super::serialize_with_unit_tag(&self.m3, Self::unit_symbol(), serializer)
 	}
 }
-/// Dividing a scalar value by a SolidAngle unit value returns a value of type InverseSolidAngle
-#[cfg(feature="num-complex")]
-impl<T> core::ops::Div<SolidAngle<T>> for &num_complex::Complex32 where T: NumLike+From<num_complex::Complex32> {
-	type Output = InverseSolidAngle<T>;
-	fn div(self, rhs: SolidAngle<T>) -> Self::Output {
-		InverseSolidAngle{per_sr: T::from(self.clone()) / rhs.sr}
+/// Deserializes a volume value from a value and unit symbol, eg 
+/// `{"value": 1.5, "unit": "m³"}`, returning an error if the unit symbol 
+/// does not match (requires the `serde-unit-tag` feature)
+#[cfg(feature="serde-unit-tag")]
+impl<'de, T> serde::Deserialize<'de> for Volume<T> where T: NumLike+serde::Deserialize<'de> {
+	fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+		Ok(Volume{m3: super::deserialize_with_unit_tag(Self::unit_symbol(), deserializer)?})
 	}
 }
-/// Dividing a scalar value by a SolidAngle unit value returns a value of type InverseSolidAngle
-#[cfg(feature="num-complex")]
-impl<T> core::ops::Div<&SolidAngle<T>> for num_complex::Complex32 where T: NumLike+From<num_complex::Complex32> {
-	type Output = InverseSolidAngle<T>;
-	fn div(self, rhs: &SolidAngle<T>) -> Self::Output {
-		InverseSolidAngle{per_sr: T::from(self) / rhs.sr.clone()}
-	}
+
+/// The additive identity of volume values is zero cubic meters (requires the `num-traits` feature)
+#[cfg(feature="num-traits")]
+impl<T> num_traits::Zero for Volume<T> where T: NumLike+num_traits::Zero {
+	fn zero() -> Self { Volume{m3: T::zero()} }
+	fn is_zero(&self) -> bool { self.m3.is_zero() }
 }
-/// Dividing a scalar value by a SolidAngle unit value returns a value of type InverseSolidAngle
-#[cfg(feature="num-complex")]
-impl<T> core::ops::Div<&SolidAngle<T>> for &num_complex::Complex32 where T: NumLike+From<num_complex::Complex32> {
-	type Output = InverseSolidAngle<T>;
-	fn div(self, rhs: &SolidAngle<T>) -> Self::Output {
-		InverseSolidAngle{per_sr: T::from(self.clone()) / rhs.sr.clone()}
+
+#[cfg(feature="num-traits")]
+impl<T> Volume<T> where T: NumLike+num_traits::Signed {
+
+	/// Returns the sign of this volume value as a dimensionless number: 1 if it is 
+	/// positive, -1 if it is negative, and 0 if it is zero for integer number types 
+	/// (for floating-point number types, +0.0 returns 1.0, -0.0 returns -1.0, and NaN returns 
+	/// NaN) (requires the `num-traits` feature)
+	pub fn signum(self) -> T {
+		self.m3.signum()
 	}
 }
 
-// 1/SolidAngle -> InverseSolidAngle
-/// Dividing a scalar value by a SolidAngle unit value returns a value of type InverseSolidAngle
-#[cfg(feature="num-complex")]
-impl<T> core::ops::Div<SolidAngle<T>> for num_complex::Complex64 where T: NumLike+From<num_complex::Complex64> {
-	type Output = InverseSolidAngle<T>;
-	fn div(self, rhs: SolidAngle<T>) -> Self::Output {
-		InverseSolidAngle{per_sr: T::from(self) / rhs.sr}
+impl<T> Volume<T> where T: NumLike+PartialOrd {
+
+	/// Returns the absolute value of this volume value, ie its magnitude without a minus sign
+	/// (for floating-point number types, -0.0 returns +0.0)
+	pub fn abs(self) -> Self {
+		let negated = -self.m3.clone();
+		if self.m3 < negated {
+			Volume{m3: negated}
+		} else if self.m3 == negated {
+			// zero, which is its own negation (adding +0.0 turns -0.0 into +0.0)
+			Volume{m3: negated + self.m3}
+		} else {
+			self
+		}
 	}
-}
-/// Dividing a scalar value by a SolidAngle unit value returns a value of type InverseSolidAngle
-#[cfg(feature="num-complex")]
-impl<T> core::ops::Div<SolidAngle<T>> for &num_complex::Complex64 where T: NumLike+From<num_complex::Complex64> {
-	type Output = InverseSolidAngle<T>;
-	fn div(self, rhs: SolidAngle<T>) -> Self::Output {
-		InverseSolidAngle{per_sr: T::from(self.clone()) / rhs.sr}
+
+	/// Returns the lesser of this volume value and the given volume value
+	///
+	/// # Arguments
+	/// * `other` - The volume value to compare with this one
+	pub fn min(self, other: Self) -> Self {
+		if other.m3 < self.m3 { other } else { self }
+	}
+
+	/// Returns the greater of this volume value and the given volume value
+	///
+	/// # Arguments
+	/// * `other` - The volume value to compare with this one
+	pub fn max(self, other: Self) -> Self {
+		if other.m3 > self.m3 { other } else { self }
+	}
+
+	/// Returns the least of the given volume values, or `None` if there are none. 
+	/// Unlike `Iterator::min()`, this does not require `Ord`, so it works for 
+	/// floating-point numbers. NaN values are skipped (so the result is `None` if all 
+	/// values are NaN), and the first of several equal values is returned
+	///
+	/// # Arguments
+	/// * `values` - Any iterator or collection of volume values
+	pub fn min_of<I>(values: I) -> Option<Self> where I: IntoIterator<Item=Self> {
+		values.into_iter().filter(|x| x.m3.partial_cmp(&x.m3).is_some()).reduce(Self::min)
+	}
+
+	/// Returns the greatest of the given volume values, or `None` if there are none. 
+	/// Unlike `Iterator::max()`, this does not require `Ord`, so it works for 
+	/// floating-point numbers. NaN values are skipped (so the result is `None` if all 
+	/// values are NaN), and the first of several equal values is returned
+	///
+	/// # Arguments
+	/// * `values` - Any iterator or collection of volume values
+	pub fn max_of<I>(values: I) -> Option<Self> where I: IntoIterator<Item=Self> {
+		values.into_iter().filter(|x| x.m3.partial_cmp(&x.m3).is_some()).reduce(Self::max)
+	}
+
+	/// Returns this volume value restricted to the range from `lo` to `hi`
+	///
+	/// # Arguments
+	/// * `lo` - The minimum volume value
+	/// * `hi` - The maximum volume value
+	///
+	/// # Panics
+	/// Panics if `lo` is greater than `hi` (or if either of them is NaN)
+	pub fn clamp(self, lo: Self, hi: Self) -> Self {
+		assert!(lo.m3 <= hi.m3, "lo must be less than or equal to hi");
+		if self.m3 < lo.m3 { lo } else if self.m3 > hi.m3 { hi } else { self }
+	}
+
+	/// Returns this volume value with its magnitude capped at `max`, keeping its 
+	/// sign (eg for capping a speed), or this value unchanged if its magnitude does not 
+	/// exceed `max`. For complex-valued volume, use `clamp_norm(...)` instead, 
+	/// which caps the magnitude while keeping the phase
+	///
+	/// # Arguments
+	/// * `max` - The maximum magnitude (should not be negative)
+	pub fn clamp_magnitude(self, max: Self) -> Self {
+		let negated_max = -max.m3.clone();
+		if self.m3 > max.m3 { 
+			max 
+		} else if self.m3 < negated_max { 
+			Volume{m3: negated_max} 
+		} else { 
+			self 
+		}
+	}
+
+	/// Returns true if this volume value differs from the given volume 
+	/// value by no more than the given absolute tolerance (which is also a 
+	/// volume value, so the comparison is dimensionally safe). NaN is never 
+	/// equal to anything. See also the `assert_unit_eq!(...)` macro.
+	///
+	/// # Arguments
+	/// * `other` - The volume value to compare with this one
+	/// * `tolerance` - The largest allowed difference between the two values
+	pub fn eq_within(self, other: Self, tolerance: Self) -> bool {
+		let diff = self.m3 - other.m3;
+		diff <= tolerance.m3 && -diff <= tolerance.m3
 	}
 }
-/// Dividing a scalar value by a SolidAngle unit value returns a value of type InverseSolidAngle
+
 #[cfg(feature="num-complex")]
-impl<T> core::ops::Div<&SolidAngle<T>> for num_complex::Complex64 where T: NumLike+From<num_complex::Complex64> {
-	type Output = InverseSolidAngle<T>;
-	fn div(self, rhs: &SolidAngle<T>) -> Self::Output {
-		InverseSolidAngle{per_sr: T::from(self) / rhs.sr.clone()}
+impl Volume<num_complex::Complex32> {
+	/// Returns the magnitude (aka norm or absolute value) of this complex volume 
+	/// value as a real-valued volume (requires the `num-complex` feature)
+	pub fn magnitude(self) -> Volume<f32> {
+		Volume{m3: self.m3.norm()}
+	}
+
+	/// Returns this complex volume value scaled down so that its magnitude is 
+	/// at most `max` (keeping its phase), or this value unchanged if its magnitude does 
+	/// not exceed `max`. This is the complex equivalent of `clamp_magnitude(...)` (requires 
+	/// the `num-complex` feature)
+	///
+	/// # Arguments
+	/// * `max` - The maximum magnitude (should not be negative)
+	pub fn clamp_norm(self, max: Volume<f32>) -> Self {
+		let norm = self.m3.norm();
+		if norm > max.m3 {
+			Volume{m3: self.m3 * (max.m3 / norm)}
+		} else {
+			self
+		}
 	}
 }
-/// Dividing a scalar value by a SolidAngle unit value returns a value of type InverseSolidAngle
+
 #[cfg(feature="num-complex")]
-impl<T> core::ops::Div<&SolidAngle<T>> for &num_complex::Complex64 where T: NumLike+From<num_complex::Complex64> {
-	type Output = InverseSolidAngle<T>;
-	fn div(self, rhs: &SolidAngle<T>) -> Self::Output {
-		InverseSolidAngle{per_sr: T::from(self.clone()) / rhs.sr.clone()}
+impl Volume<num_complex::Complex64> {
+	/// Returns the magnitude (aka norm or absolute value) of this complex volume 
+	/// value as a real-valued volume (requires the `num-complex` feature)
+	pub fn magnitude(self) -> Volume<f64> {
+		Volume{m3: self.m3.norm()}
 	}
-}
 
-/// The volume unit type, defined as cubic meters in SI units
-#[derive(UnitStruct, Debug, Clone)]
-#[cfg_attr(feature="serde", derive(Serialize, Deserialize))]
-pub struct Volume<T: NumLike>{
-	/// The value of this Volume in cubic meters
-	pub m3: T
+	/// Returns this complex volume value scaled down so that its magnitude is 
+	/// at most `max` (keeping its phase), or this value unchanged if its magnitude does 
+	/// not exceed `max`. This is the complex equivalent of `clamp_magnitude(...)` (requires 
+	/// the `num-complex` feature)
+	///
+	/// # Arguments
+	/// * `max` - The maximum magnitude (should not be negative)
+	pub fn clamp_norm(self, max: Volume<f64>) -> Self {
+		let norm = self.m3.norm();
+		if norm > max.m3 {
+			Volume{m3: self.m3 * (max.m3 / norm)}
+		} else {
+			self
+		}
+	}
 }
 
-impl<T> Volume<T> where T: NumLike {
+/// Rounding and other floating-point functions (requires the `num-traits` feature, 
+/// and either the `std` or `libm` feature)
+#[cfg(all(feature="num-traits", any(feature="std", feature="libm")))]
+impl<T> Volume<T> where T: NumLike+num_traits::Float {
 
-	/// Returns the standard unit name of volume: "cubic meters"
-	pub fn unit_name() -> &'static str { "cubic meters" }
-	
-	/// Returns the abbreviated name or symbol of volume: "m³" for cubic meters
-	pub fn unit_symbol() -> &'static str { "m³" }
-	
-	/// Returns a new volume value from the given number of cubic meters
+	/// Returns this volume value rounded to the nearest whole number of cubic meters 
+	/// (half-way cases are rounded away from zero)
+	pub fn round(self) -> Self {
+		Volume{m3: self.m3.round()}
+	}
+
+	/// Returns this volume value rounded down to a whole number of cubic meters
+	pub fn floor(self) -> Self {
+		Volume{m3: self.m3.floor()}
+	}
+
+	/// Returns this volume value rounded up to a whole number of cubic meters
+	pub fn ceil(self) -> Self {
+		Volume{m3: self.m3.ceil()}
+	}
+
+	/// Returns this volume value rounded towards zero to a whole number of cubic meters
+	pub fn trunc(self) -> Self {
+		Volume{m3: self.m3.trunc()}
+	}
+
+	/// Returns this volume value rounded to the nearest multiple of the given step 
+	/// (half-way cases are rounded away from zero)
 	///
 	/// # Arguments
-	/// * `m3` - Any number-like type, representing a quantity of cubic meters
-	pub fn from_m3(m3: T) -> Self { Volume{m3: m3} }
-	
-	/// Returns a copy of this volume value in cubic meters
-	pub fn to_m3(&self) -> T { self.m3.clone() }
+	/// * `step` - The volume value to round to a multiple of
+	pub fn round_to(self, step: Self) -> Self {
+		Volume{m3: (self.m3 / step.m3).round() * step.m3}
+	}
 
-	/// Returns a new volume value from the given number of cubic meters
+	/// Fused multiply-add: returns `self * a + b` computed with only one rounding 
+	/// error, which is more accurate (and on some hardware faster) than a separate 
+	/// multiplication and addition
 	///
 	/// # Arguments
-	/// * `cubic_meters` - Any number-like type, representing a quantity of cubic meters
-	pub fn from_cubic_meters(cubic_meters: T) -> Self { Volume{m3: cubic_meters} }
-	
-	/// Returns a copy of this volume value in cubic meters
-	pub fn to_cubic_meters(&self) -> T { self.m3.clone() }
+	/// * `a` - The scalar value to multiply this volume value by
+	/// * `b` - The volume value to add to the product
+	pub fn mul_add(self, a: T, b: Self) -> Self {
+		Volume{m3: self.m3.mul_add(a, b.m3)}
+	}
 
-	/// Returns a new volume value from the given number of kiloliters
+	/// Returns true if this volume value is neither infinite nor NaN
+	pub fn is_finite(&self) -> bool {
+		self.m3.is_finite()
+	}
+
+	/// Returns true if this volume value is positive or negative infinity
+	pub fn is_infinite(&self) -> bool {
+		self.m3.is_infinite()
+	}
+
+	/// Returns true if this volume value is NaN (not a number)
+	pub fn is_nan(&self) -> bool {
+		self.m3.is_nan()
+	}
+
+	/// Returns true if this volume value has a positive sign, including +0.0 
+	/// and positive infinity
+	pub fn is_sign_positive(&self) -> bool {
+		self.m3.is_sign_positive()
+	}
+
+	/// Returns true if this volume value has a negative sign, including -0.0 
+	/// and negative infinity
+	pub fn is_sign_negative(&self) -> bool {
+		self.m3.is_sign_negative()
+	}
+
+	/// Returns a volume value with the magnitude of this one and the sign of 
+	/// `sign_source`, eg for reflecting a value off of a surface
 	///
 	/// # Arguments
-	/// * `kL` - Any number-like type, representing a quantity of cubic meters
-	pub fn from_kL(kL: T) -> Self { Volume{m3: kL} }
-	
-	/// Returns a copy of this volume value in kiloliters
-	pub fn to_kL(&self) -> T { self.m3.clone() }
+	/// * `sign_source` - The volume value whose sign is copied
+	pub fn copysign(self, sign_source: Self) -> Self {
+		Volume{m3: self.m3.copysign(sign_source.m3)}
+	}
+}
+
+#[cfg(feature="num-traits")]
+impl<T> Volume<T> where T: NumLike+num_traits::CheckedAdd+num_traits::CheckedSub {
+
+	/// Adds another volume value to this one, returning `None` if the 
+	/// addition overflows the number type (requires the `num-traits` feature)
+	pub fn checked_add(self, rhs: Self) -> Option<Self> {
+		Some(Volume{m3: self.m3.checked_add(&rhs.m3)?})
+	}
 
+	/// Subtracts another volume value from this one, returning `None` if 
+	/// the subtraction overflows the number type (requires the `num-traits` feature)
+	pub fn checked_sub(self, rhs: Self) -> Option<Self> {
+		Some(Volume{m3: self.m3.checked_sub(&rhs.m3)?})
+	}
 }
 
-impl<T> fmt::Display for Volume<T> where T: NumLike {
-	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-		write!(f, "{} {}", &self.m3, Self::unit_symbol())
+#[cfg(feature="num-traits")]
+impl<T> Volume<T> where T: NumLike+num_traits::SaturatingAdd+num_traits::SaturatingSub {
+
+	/// Adds another volume value to this one, clamping the result to the 
+	/// minimum or maximum value of the number type instead of overflowing 
+	/// (requires the `num-traits` feature)
+	pub fn saturating_add(self, rhs: Self) -> Self {
+		Volume{m3: self.m3.saturating_add(&rhs.m3)}
+	}
+
+	/// Subtracts another volume value from this one, clamping the result 
+	/// to the minimum or maximum value of the number type instead of overflowing 
+	/// (requires the `num-traits` feature)
+	pub fn saturating_sub(self, rhs: Self) -> Self {
+		Volume{m3: self.m3.saturating_sub(&rhs.m3)}
+	}
+}
+
+impl<T> Volume<T> where T: NumLike+Into<f64> {
+
+	/// Returns true if this volume value is equal to the given volume 
+	/// value to within the given number of significant figures (eg 6 significant 
+	/// figures allows a relative difference of up to 1 part per million). NaN is 
+	/// only approximately equal to NaN, infinity is only approximately equal to 
+	/// infinity of the same sign, and zero is only approximately equal to zero.
+	///
+	/// # Arguments
+	/// * `other` - The volume value to compare with this one
+	/// * `sig_figs` - The number of significant figures that must match
+	pub fn approx_eq(self, other: Self, sig_figs: i32) -> bool {
+		super::approx_eq_f64(self.m3.into(), other.m3.into(), sig_figs)
 	}
 }
 
 impl<T> Volume<T> where T: NumLike+From<f64> {
+
+	/// Returns the linear interpolation between this volume value and the given 
+	/// volume value, ie `self*(1-t) + other*t`. The interpolation factor is not 
+	/// clamped, so values of `t` outside the range 0 to 1 extrapolate beyond the two 
+	/// values (see `lerp_clamped(...)`).
+	///
+	/// # Arguments
+	/// * `other` - The volume value to interpolate towards (returned when `t` is 1)
+	/// * `t` - The interpolation factor, where 0 returns this value and 1 returns `other`
+	pub fn lerp(self, other: Self, t: f64) -> Self {
+		Volume{m3: self.m3 * T::from(1.0 - t) + other.m3 * T::from(t)}
+	}
+
+	/// Same as `lerp(...)`, except that the interpolation factor `t` is first clamped 
+	/// to the range 0 to 1, so that the result is always between the two values
+	///
+	/// # Arguments
+	/// * `other` - The volume value to interpolate towards (returned when `t` is 1 or more)
+	/// * `t` - The interpolation factor, where 0 returns this value and 1 returns `other`
+	pub fn lerp_clamped(self, other: Self, t: f64) -> Self {
+		self.lerp(other, t.clamp(0.0, 1.0))
+	}
 	
 	/// Returns a copy of this volume value in cubic cm
 	/// 
@@ -6129,26 +14495,77 @@ impl<T> Volume<T> where T: NumLike+From<f64> {
 	/// *Note: This method is not available for `f32` and other number types lacking the `From<f64>` trait*
 	///
 	/// # Arguments
-	/// * `liters` - Any number-like type, representing a quantity of liters
-	pub fn from_liters(liters: T) -> Self {
-		Volume{m3: liters * T::from(0.001_f64)}
+	/// * `liters` - Any number-like type, representing a quantity of liters
+	pub fn from_liters(liters: T) -> Self {
+		Volume{m3: liters * T::from(0.001_f64)}
+	}
+
+	/// Returns a copy of this volume value in milliliters
+	/// 
+	/// *Note: This method is not available for `f32` and other number types lacking the `From<f64>` trait*
+	pub fn to_mL(&self) -> T {
+		return self.m3.clone() * T::from(1000000.0_f64);
+	}
+
+	/// Returns a new volume value from the given number of milliliters
+	/// 
+	/// *Note: This method is not available for `f32` and other number types lacking the `From<f64>` trait*
+	///
+	/// # Arguments
+	/// * `mL` - Any number-like type, representing a quantity of milliliters
+	pub fn from_mL(mL: T) -> Self {
+		Volume{m3: mL * T::from(1e-06_f64)}
+	}
+
+	/// Returns a copy of this volume value in milliliters
+	/// 
+	/// *Note: This method is not available for `f32` and other number types lacking the `From<f64>` trait*
+	pub fn to_milliliters(&self) -> T {
+		return self.m3.clone() * T::from(1000000.0_f64);
+	}
+
+	/// Returns a new volume value from the given number of milliliters
+	/// 
+	/// *Note: This method is not available for `f32` and other number types lacking the `From<f64>` trait*
+	///
+	/// # Arguments
+	/// * `milliliters` - Any number-like type, representing a quantity of milliliters
+	pub fn from_milliliters(milliliters: T) -> Self {
+		Volume{m3: milliliters * T::from(1e-06_f64)}
+	}
+
+	/// Returns a copy of this volume value in US gallons
+	/// 
+	/// *Note: This method is not available for `f32` and other number types lacking the `From<f64>` trait*
+	pub fn to_us_gallons(&self) -> T {
+		return self.m3.clone() * T::from(264.1720523581484_f64);
+	}
+
+	/// Returns a new volume value from the given number of US gallons
+	/// 
+	/// *Note: This method is not available for `f32` and other number types lacking the `From<f64>` trait*
+	///
+	/// # Arguments
+	/// * `us_gallons` - Any number-like type, representing a quantity of US gallons
+	pub fn from_us_gallons(us_gallons: T) -> Self {
+		Volume{m3: us_gallons * T::from(0.003785411784_f64)}
 	}
 
-	/// Returns a copy of this volume value in milliliters
+	/// Returns a copy of this volume value in imperial gallons
 	/// 
 	/// *Note: This method is not available for `f32` and other number types lacking the `From<f64>` trait*
-	pub fn to_mL(&self) -> T {
-		return self.m3.clone() * T::from(1000000.0_f64);
+	pub fn to_imperial_gallons(&self) -> T {
+		return self.m3.clone() * T::from(219.96924829908778_f64);
 	}
 
-	/// Returns a new volume value from the given number of milliliters
+	/// Returns a new volume value from the given number of imperial gallons
 	/// 
 	/// *Note: This method is not available for `f32` and other number types lacking the `From<f64>` trait*
 	///
 	/// # Arguments
-	/// * `mL` - Any number-like type, representing a quantity of milliliters
-	pub fn from_mL(mL: T) -> Self {
-		Volume{m3: mL * T::from(1e-06_f64)}
+	/// * `imperial_gallons` - Any number-like type, representing a quantity of imperial gallons
+	pub fn from_imperial_gallons(imperial_gallons: T) -> Self {
+		Volume{m3: imperial_gallons * T::from(0.00454609_f64)}
 	}
 
 	/// Returns a copy of this volume value in microliters
@@ -6338,6 +14755,72 @@ impl core::ops::Mul<&Volume<num_complex::Complex64>> for &num_complex::Complex64
 	}
 }
 
+/// Multiplying a unit value by a scalar value returns a unit value
+#[cfg(feature="num-rational")]
+impl core::ops::Mul<Volume<num_rational::Rational64>> for num_rational::Rational64 {
+	type Output = Volume<num_rational::Rational64>;
+	fn mul(self, rhs: Volume<num_rational::Rational64>) -> Self::Output {
+		Volume{m3: self * rhs.m3}
+	}
+}
+/// Multiplying a unit value by a scalar value returns a unit value
+#[cfg(feature="num-rational")]
+impl core::ops::Mul<Volume<num_rational::Rational64>> for &num_rational::Rational64 {
+	type Output = Volume<num_rational::Rational64>;
+	fn mul(self, rhs: Volume<num_rational::Rational64>) -> Self::Output {
+		Volume{m3: self.clone() * rhs.m3}
+	}
+}
+/// Multiplying a unit value by a scalar value returns a unit value
+#[cfg(feature="num-rational")]
+impl core::ops::Mul<&Volume<num_rational::Rational64>> for num_rational::Rational64 {
+	type Output = Volume<num_rational::Rational64>;
+	fn mul(self, rhs: &Volume<num_rational::Rational64>) -> Self::Output {
+		Volume{m3: self * rhs.m3.clone()}
+	}
+}
+/// Multiplying a unit value by a scalar value returns a unit value
+#[cfg(feature="num-rational")]
+impl core::ops::Mul<&Volume<num_rational::Rational64>> for &num_rational::Rational64 {
+	type Output = Volume<num_rational::Rational64>;
+	fn mul(self, rhs: &Volume<num_rational::Rational64>) -> Self::Output {
+		Volume{m3: self.clone() * rhs.m3.clone()}
+	}
+}
+
+/// Multiplying a unit value by a scalar value returns a unit value
+#[cfg(feature="num-rational")]
+impl core::ops::Mul<Volume<num_rational::BigRational>> for num_rational::BigRational {
+	type Output = Volume<num_rational::BigRational>;
+	fn mul(self, rhs: Volume<num_rational::BigRational>) -> Self::Output {
+		Volume{m3: self * rhs.m3}
+	}
+}
+/// Multiplying a unit value by a scalar value returns a unit value
+#[cfg(feature="num-rational")]
+impl core::ops::Mul<Volume<num_rational::BigRational>> for &num_rational::BigRational {
+	type Output = Volume<num_rational::BigRational>;
+	fn mul(self, rhs: Volume<num_rational::BigRational>) -> Self::Output {
+		Volume{m3: self.clone() * rhs.m3}
+	}
+}
+/// Multiplying a unit value by a scalar value returns a unit value
+#[cfg(feature="num-rational")]
+impl core::ops::Mul<&Volume<num_rational::BigRational>> for num_rational::BigRational {
+	type Output = Volume<num_rational::BigRational>;
+	fn mul(self, rhs: &Volume<num_rational::BigRational>) -> Self::Output {
+		Volume{m3: self * rhs.m3.clone()}
+	}
+}
+/// Multiplying a unit value by a scalar value returns a unit value
+#[cfg(feature="num-rational")]
+impl core::ops::Mul<&Volume<num_rational::BigRational>> for &num_rational::BigRational {
+	type Output = Volume<num_rational::BigRational>;
+	fn mul(self, rhs: &Volume<num_rational::BigRational>) -> Self::Output {
+		Volume{m3: self.clone() * rhs.m3.clone()}
+	}
+}
+
 
 
 /// Converts a Volume into the equivalent [uom](https://crates.io/crates/uom) type [Volume](https://docs.rs/uom/0.34.0/uom/si/f32/type.Volume.html)
@@ -6373,6 +14856,44 @@ impl<T> From<uom::si::f64::Volume> for Volume<T> where T: NumLike+From<f64> {
 }
 
 
+
+/// Multiplying a Volume by an [nalgebra](https://crates.io/crates/nalgebra) 
+/// vector or matrix of unitless values returns a vector or matrix of Volume 
+/// values (eg a direction vector multiplied by a Volume)
+#[cfg(feature="nalgebra")]
+impl<T, R, C, S> core::ops::Mul<nalgebra::Matrix<T, R, C, S>> for Volume<T> where 
+	T: NumLike+nalgebra::Scalar, R: nalgebra::Dim, C: nalgebra::Dim, S: nalgebra::RawStorage<T, R, C>, 
+	nalgebra::DefaultAllocator: nalgebra::allocator::Allocator<R, C> {
+	type Output = nalgebra::OMatrix<Volume<T>, R, C>;
+	fn mul(self, rhs: nalgebra::Matrix<T, R, C, S>) -> Self::Output {
+		rhs.map(|x| Volume{m3: self.m3.clone() * x})
+	}
+}
+/// Multiplying an [nalgebra](https://crates.io/crates/nalgebra) vector or matrix of 
+/// unitless values by a Volume returns a vector or matrix of Volume values
+#[cfg(feature="nalgebra")]
+impl<T, R, C, S> core::ops::Mul<Volume<T>> for nalgebra::Matrix<T, R, C, S> where 
+	T: NumLike+nalgebra::Scalar, R: nalgebra::Dim, C: nalgebra::Dim, S: nalgebra::RawStorage<T, R, C>, 
+	nalgebra::DefaultAllocator: nalgebra::allocator::Allocator<R, C> {
+	type Output = nalgebra::OMatrix<Volume<T>, R, C>;
+	fn mul(self, rhs: Volume<T>) -> Self::Output {
+		self.map(|x| Volume{m3: x * rhs.m3.clone()})
+	}
+}
+/// Dividing an [nalgebra](https://crates.io/crates/nalgebra) vector or matrix of 
+/// Volume values by a Volume returns a vector or matrix of unitless values
+#[cfg(feature="nalgebra")]
+impl<T, R, C, S> core::ops::Div<Volume<T>> for nalgebra::Matrix<Volume<T>, R, C, S> where 
+	T: NumLike+nalgebra::Scalar, R: nalgebra::Dim, C: nalgebra::Dim, S: nalgebra::RawStorage<Volume<T>, R, C>, 
+	nalgebra::DefaultAllocator: nalgebra::allocator::Allocator<R, C> {
+	type Output = nalgebra::OMatrix<T, R, C>;
+	fn div(self, rhs: Volume<T>) -> Self::Output {
+		self.map(|x| x.m3 / rhs.m3.clone())
+	}
+}
+
+
+
 // Volume / Amount -> MolarVolume
 /// Dividing a Volume by a Amount returns a value of type MolarVolume
 impl<T> core::ops::Div<Amount<T>> for Volume<T> where T: NumLike {
@@ -6553,6 +15074,36 @@ impl<T> core::ops::Div<&Mass<T>> for &Volume<T> where T: NumLike {
 	}
 }
 
+// Volume / Time -> VolumetricFlowRate
+/// Dividing a Volume by a Time returns a value of type VolumetricFlowRate
+impl<T> core::ops::Div<Time<T>> for Volume<T> where T: NumLike {
+	type Output = VolumetricFlowRate<T>;
+	fn div(self, rhs: Time<T>) -> Self::Output {
+		VolumetricFlowRate{m3ps: self.m3 / rhs.s}
+	}
+}
+/// Dividing a Volume by a Time returns a value of type VolumetricFlowRate
+impl<T> core::ops::Div<Time<T>> for &Volume<T> where T: NumLike {
+	type Output = VolumetricFlowRate<T>;
+	fn div(self, rhs: Time<T>) -> Self::Output {
+		VolumetricFlowRate{m3ps: self.m3.clone() / rhs.s}
+	}
+}
+/// Dividing a Volume by a Time returns a value of type VolumetricFlowRate
+impl<T> core::ops::Div<&Time<T>> for Volume<T> where T: NumLike {
+	type Output = VolumetricFlowRate<T>;
+	fn div(self, rhs: &Time<T>) -> Self::Output {
+		VolumetricFlowRate{m3ps: self.m3 / rhs.s.clone()}
+	}
+}
+/// Dividing a Volume by a Time returns a value of type VolumetricFlowRate
+impl<T> core::ops::Div<&Time<T>> for &Volume<T> where T: NumLike {
+	type Output = VolumetricFlowRate<T>;
+	fn div(self, rhs: &Time<T>) -> Self::Output {
+		VolumetricFlowRate{m3ps: self.m3.clone() / rhs.s.clone()}
+	}
+}
+
 // Volume * Concentration -> Amount
 /// Multiplying a Volume by a Concentration returns a value of type Amount
 impl<T> core::ops::Mul<Concentration<T>> for Volume<T> where T: NumLike {
@@ -6613,6 +15164,126 @@ impl<T> core::ops::Div<&MolarVolume<T>> for &Volume<T> where T: NumLike {
 	}
 }
 
+// Volume / Charge -> InverseVolumeChargeDensity
+/// Dividing a Volume by a Charge returns a value of type InverseVolumeChargeDensity
+impl<T> core::ops::Div<Charge<T>> for Volume<T> where T: NumLike {
+	type Output = InverseVolumeChargeDensity<T>;
+	fn div(self, rhs: Charge<T>) -> Self::Output {
+		InverseVolumeChargeDensity{m3_per_C: self.m3 / rhs.C}
+	}
+}
+/// Dividing a Volume by a Charge returns a value of type InverseVolumeChargeDensity
+impl<T> core::ops::Div<Charge<T>> for &Volume<T> where T: NumLike {
+	type Output = InverseVolumeChargeDensity<T>;
+	fn div(self, rhs: Charge<T>) -> Self::Output {
+		InverseVolumeChargeDensity{m3_per_C: self.m3.clone() / rhs.C}
+	}
+}
+/// Dividing a Volume by a Charge returns a value of type InverseVolumeChargeDensity
+impl<T> core::ops::Div<&Charge<T>> for Volume<T> where T: NumLike {
+	type Output = InverseVolumeChargeDensity<T>;
+	fn div(self, rhs: &Charge<T>) -> Self::Output {
+		InverseVolumeChargeDensity{m3_per_C: self.m3 / rhs.C.clone()}
+	}
+}
+/// Dividing a Volume by a Charge returns a value of type InverseVolumeChargeDensity
+impl<T> core::ops::Div<&Charge<T>> for &Volume<T> where T: NumLike {
+	type Output = InverseVolumeChargeDensity<T>;
+	fn div(self, rhs: &Charge<T>) -> Self::Output {
+		InverseVolumeChargeDensity{m3_per_C: self.m3.clone() / rhs.C.clone()}
+	}
+}
+
+// Volume * InverseCharge -> InverseVolumeChargeDensity
+/// Multiplying a Volume by a InverseCharge returns a value of type InverseVolumeChargeDensity
+impl<T> core::ops::Mul<InverseCharge<T>> for Volume<T> where T: NumLike {
+	type Output = InverseVolumeChargeDensity<T>;
+	fn mul(self, rhs: InverseCharge<T>) -> Self::Output {
+		InverseVolumeChargeDensity{m3_per_C: self.m3 * rhs.per_C}
+	}
+}
+/// Multiplying a Volume by a InverseCharge returns a value of type InverseVolumeChargeDensity
+impl<T> core::ops::Mul<InverseCharge<T>> for &Volume<T> where T: NumLike {
+	type Output = InverseVolumeChargeDensity<T>;
+	fn mul(self, rhs: InverseCharge<T>) -> Self::Output {
+		InverseVolumeChargeDensity{m3_per_C: self.m3.clone() * rhs.per_C}
+	}
+}
+/// Multiplying a Volume by a InverseCharge returns a value of type InverseVolumeChargeDensity
+impl<T> core::ops::Mul<&InverseCharge<T>> for Volume<T> where T: NumLike {
+	type Output = InverseVolumeChargeDensity<T>;
+	fn mul(self, rhs: &InverseCharge<T>) -> Self::Output {
+		InverseVolumeChargeDensity{m3_per_C: self.m3 * rhs.per_C.clone()}
+	}
+}
+/// Multiplying a Volume by a InverseCharge returns a value of type InverseVolumeChargeDensity
+impl<T> core::ops::Mul<&InverseCharge<T>> for &Volume<T> where T: NumLike {
+	type Output = InverseVolumeChargeDensity<T>;
+	fn mul(self, rhs: &InverseCharge<T>) -> Self::Output {
+		InverseVolumeChargeDensity{m3_per_C: self.m3.clone() * rhs.per_C.clone()}
+	}
+}
+
+// Volume / InverseVolumeChargeDensity -> Charge
+/// Dividing a Volume by a InverseVolumeChargeDensity returns a value of type Charge
+impl<T> core::ops::Div<InverseVolumeChargeDensity<T>> for Volume<T> where T: NumLike {
+	type Output = Charge<T>;
+	fn div(self, rhs: InverseVolumeChargeDensity<T>) -> Self::Output {
+		Charge{C: self.m3 / rhs.m3_per_C}
+	}
+}
+/// Dividing a Volume by a InverseVolumeChargeDensity returns a value of type Charge
+impl<T> core::ops::Div<InverseVolumeChargeDensity<T>> for &Volume<T> where T: NumLike {
+	type Output = Charge<T>;
+	fn div(self, rhs: InverseVolumeChargeDensity<T>) -> Self::Output {
+		Charge{C: self.m3.clone() / rhs.m3_per_C}
+	}
+}
+/// Dividing a Volume by a InverseVolumeChargeDensity returns a value of type Charge
+impl<T> core::ops::Div<&InverseVolumeChargeDensity<T>> for Volume<T> where T: NumLike {
+	type Output = Charge<T>;
+	fn div(self, rhs: &InverseVolumeChargeDensity<T>) -> Self::Output {
+		Charge{C: self.m3 / rhs.m3_per_C.clone()}
+	}
+}
+/// Dividing a Volume by a InverseVolumeChargeDensity returns a value of type Charge
+impl<T> core::ops::Div<&InverseVolumeChargeDensity<T>> for &Volume<T> where T: NumLike {
+	type Output = Charge<T>;
+	fn div(self, rhs: &InverseVolumeChargeDensity<T>) -> Self::Output {
+		Charge{C: self.m3.clone() / rhs.m3_per_C.clone()}
+	}
+}
+
+// Volume * VolumeChargeDensity -> Charge
+/// Multiplying a Volume by a VolumeChargeDensity returns a value of type Charge
+impl<T> core::ops::Mul<VolumeChargeDensity<T>> for Volume<T> where T: NumLike {
+	type Output = Charge<T>;
+	fn mul(self, rhs: VolumeChargeDensity<T>) -> Self::Output {
+		Charge{C: self.m3 * rhs.Cpm3}
+	}
+}
+/// Multiplying a Volume by a VolumeChargeDensity returns a value of type Charge
+impl<T> core::ops::Mul<VolumeChargeDensity<T>> for &Volume<T> where T: NumLike {
+	type Output = Charge<T>;
+	fn mul(self, rhs: VolumeChargeDensity<T>) -> Self::Output {
+		Charge{C: self.m3.clone() * rhs.Cpm3}
+	}
+}
+/// Multiplying a Volume by a VolumeChargeDensity returns a value of type Charge
+impl<T> core::ops::Mul<&VolumeChargeDensity<T>> for Volume<T> where T: NumLike {
+	type Output = Charge<T>;
+	fn mul(self, rhs: &VolumeChargeDensity<T>) -> Self::Output {
+		Charge{C: self.m3 * rhs.Cpm3.clone()}
+	}
+}
+/// Multiplying a Volume by a VolumeChargeDensity returns a value of type Charge
+impl<T> core::ops::Mul<&VolumeChargeDensity<T>> for &Volume<T> where T: NumLike {
+	type Output = Charge<T>;
+	fn mul(self, rhs: &VolumeChargeDensity<T>) -> Self::Output {
+		Charge{C: self.m3.clone() * rhs.Cpm3.clone()}
+	}
+}
+
 // Volume / Area -> Distance
 /// Dividing a Volume by a Area returns a value of type Distance
 impl<T> core::ops::Div<Area<T>> for Volume<T> where T: NumLike {
@@ -6763,6 +15434,36 @@ impl<T> core::ops::Div<&Torque<T>> for &Volume<T> where T: NumLike {
 	}
 }
 
+// Volume * Frequency -> VolumetricFlowRate
+/// Multiplying a Volume by a Frequency returns a value of type VolumetricFlowRate
+impl<T> core::ops::Mul<Frequency<T>> for Volume<T> where T: NumLike {
+	type Output = VolumetricFlowRate<T>;
+	fn mul(self, rhs: Frequency<T>) -> Self::Output {
+		VolumetricFlowRate{m3ps: self.m3 * rhs.Hz}
+	}
+}
+/// Multiplying a Volume by a Frequency returns a value of type VolumetricFlowRate
+impl<T> core::ops::Mul<Frequency<T>> for &Volume<T> where T: NumLike {
+	type Output = VolumetricFlowRate<T>;
+	fn mul(self, rhs: Frequency<T>) -> Self::Output {
+		VolumetricFlowRate{m3ps: self.m3.clone() * rhs.Hz}
+	}
+}
+/// Multiplying a Volume by a Frequency returns a value of type VolumetricFlowRate
+impl<T> core::ops::Mul<&Frequency<T>> for Volume<T> where T: NumLike {
+	type Output = VolumetricFlowRate<T>;
+	fn mul(self, rhs: &Frequency<T>) -> Self::Output {
+		VolumetricFlowRate{m3ps: self.m3 * rhs.Hz.clone()}
+	}
+}
+/// Multiplying a Volume by a Frequency returns a value of type VolumetricFlowRate
+impl<T> core::ops::Mul<&Frequency<T>> for &Volume<T> where T: NumLike {
+	type Output = VolumetricFlowRate<T>;
+	fn mul(self, rhs: &Frequency<T>) -> Self::Output {
+		VolumetricFlowRate{m3ps: self.m3.clone() * rhs.Hz.clone()}
+	}
+}
+
 // Volume * InverseEnergy -> InversePressure
 /// Multiplying a Volume by a InverseEnergy returns a value of type InversePressure
 impl<T> core::ops::Mul<InverseEnergy<T>> for Volume<T> where T: NumLike {
@@ -6853,6 +15554,36 @@ impl<T> core::ops::Div<&InversePressure<T>> for &Volume<T> where T: NumLike {
 	}
 }
 
+// Volume * InverseVolumetricFlowRate -> Time
+/// Multiplying a Volume by a InverseVolumetricFlowRate returns a value of type Time
+impl<T> core::ops::Mul<InverseVolumetricFlowRate<T>> for Volume<T> where T: NumLike {
+	type Output = Time<T>;
+	fn mul(self, rhs: InverseVolumetricFlowRate<T>) -> Self::Output {
+		Time{s: self.m3 * rhs.s_per_m3}
+	}
+}
+/// Multiplying a Volume by a InverseVolumetricFlowRate returns a value of type Time
+impl<T> core::ops::Mul<InverseVolumetricFlowRate<T>> for &Volume<T> where T: NumLike {
+	type Output = Time<T>;
+	fn mul(self, rhs: InverseVolumetricFlowRate<T>) -> Self::Output {
+		Time{s: self.m3.clone() * rhs.s_per_m3}
+	}
+}
+/// Multiplying a Volume by a InverseVolumetricFlowRate returns a value of type Time
+impl<T> core::ops::Mul<&InverseVolumetricFlowRate<T>> for Volume<T> where T: NumLike {
+	type Output = Time<T>;
+	fn mul(self, rhs: &InverseVolumetricFlowRate<T>) -> Self::Output {
+		Time{s: self.m3 * rhs.s_per_m3.clone()}
+	}
+}
+/// Multiplying a Volume by a InverseVolumetricFlowRate returns a value of type Time
+impl<T> core::ops::Mul<&InverseVolumetricFlowRate<T>> for &Volume<T> where T: NumLike {
+	type Output = Time<T>;
+	fn mul(self, rhs: &InverseVolumetricFlowRate<T>) -> Self::Output {
+		Time{s: self.m3.clone() * rhs.s_per_m3.clone()}
+	}
+}
+
 // Volume * Pressure -> Energy
 /// Multiplying a Volume by a Pressure returns a value of type Energy
 impl<T> core::ops::Mul<Pressure<T>> for Volume<T> where T: NumLike {
@@ -6913,6 +15644,44 @@ impl<T> core::ops::Div<&VolumePerMass<T>> for &Volume<T> where T: NumLike {
 	}
 }
 
+// Volume / VolumetricFlowRate -> Time
+/// Dividing a Volume by a VolumetricFlowRate returns a value of type Time
+impl<T> core::ops::Div<VolumetricFlowRate<T>> for Volume<T> where T: NumLike {
+	type Output = Time<T>;
+	fn div(self, rhs: VolumetricFlowRate<T>) -> Self::Output {
+		Time{s: self.m3 / rhs.m3ps}
+	}
+}
+/// Dividing a Volume by a VolumetricFlowRate returns a value of type Time
+impl<T> core::ops::Div<VolumetricFlowRate<T>> for &Volume<T> where T: NumLike {
+	type Output = Time<T>;
+	fn div(self, rhs: VolumetricFlowRate<T>) -> Self::Output {
+		Time{s: self.m3.clone() / rhs.m3ps}
+	}
+}
+/// Dividing a Volume by a VolumetricFlowRate returns a value of type Time
+impl<T> core::ops::Div<&VolumetricFlowRate<T>> for Volume<T> where T: NumLike {
+	type Output = Time<T>;
+	fn div(self, rhs: &VolumetricFlowRate<T>) -> Self::Output {
+		Time{s: self.m3 / rhs.m3ps.clone()}
+	}
+}
+/// Dividing a Volume by a VolumetricFlowRate returns a value of type Time
+impl<T> core::ops::Div<&VolumetricFlowRate<T>> for &Volume<T> where T: NumLike {
+	type Output = Time<T>;
+	fn div(self, rhs: &VolumetricFlowRate<T>) -> Self::Output {
+		Time{s: self.m3.clone() / rhs.m3ps.clone()}
+	}
+}
+
+impl<T> Volume<T> where T: NumLike+From<u8> {
+	/// Returns the reciprocal of this Volume value (ie 1 divided by 
+	/// this value) as a value of type InverseVolume
+	pub fn recip(self) -> InverseVolume<T> {
+		InverseVolume{per_m3: T::from(1u8) / self.m3}
+	}
+}
+
 // 1/Volume -> InverseVolume
 /// Dividing a scalar value by a Volume unit value returns a value of type InverseVolume
 impl<T> core::ops::Div<Volume<T>> for f64 where T: NumLike+From<f64> {
@@ -7135,5 +15904,212 @@ impl<T> core::ops::Div<&Volume<T>> for &num_complex::Complex64 where T: NumLike+
 	}
 }
 
+// 1/Volume -> InverseVolume
+/// Dividing a scalar value by a Volume unit value returns a value of type InverseVolume
+#[cfg(feature="num-rational")]
+impl<T> core::ops::Div<Volume<T>> for num_rational::Rational64 where T: NumLike+From<num_rational::Rational64> {
+	type Output = InverseVolume<T>;
+	fn div(self, rhs: Volume<T>) -> Self::Output {
+		InverseVolume{per_m3: T::from(self) / rhs.m3}
+	}
+}
+/// Dividing a scalar value by a Volume unit value returns a value of type InverseVolume
+#[cfg(feature="num-rational")]
+impl<T> core::ops::Div<Volume<T>> for &num_rational::Rational64 where T: NumLike+From<num_rational::Rational64> {
+	type Output = InverseVolume<T>;
+	fn div(self, rhs: Volume<T>) -> Self::Output {
+		InverseVolume{per_m3: T::from(self.clone()) / rhs.m3}
+	}
+}
+/// Dividing a scalar value by a Volume unit value returns a value of type InverseVolume
+#[cfg(feature="num-rational")]
+impl<T> core::ops::Div<&Volume<T>> for num_rational::Rational64 where T: NumLike+From<num_rational::Rational64> {
+	type Output = InverseVolume<T>;
+	fn div(self, rhs: &Volume<T>) -> Self::Output {
+		InverseVolume{per_m3: T::from(self) / rhs.m3.clone()}
+	}
+}
+/// Dividing a scalar value by a Volume unit value returns a value of type InverseVolume
+#[cfg(feature="num-rational")]
+impl<T> core::ops::Div<&Volume<T>> for &num_rational::Rational64 where T: NumLike+From<num_rational::Rational64> {
+	type Output = InverseVolume<T>;
+	fn div(self, rhs: &Volume<T>) -> Self::Output {
+		InverseVolume{per_m3: T::from(self.clone()) / rhs.m3.clone()}
+	}
+}
+
+// 1/Volume -> InverseVolume
+/// Dividing a scalar value by a Volume unit value returns a value of type InverseVolume
+#[cfg(feature="num-rational")]
+impl<T> core::ops::Div<Volume<T>> for num_rational::BigRational where T: NumLike+From<num_rational::BigRational> {
+	type Output = InverseVolume<T>;
+	fn div(self, rhs: Volume<T>) -> Self::Output {
+		InverseVolume{per_m3: T::from(self) / rhs.m3}
+	}
+}
+/// Dividing a scalar value by a Volume unit value returns a value of type InverseVolume
+#[cfg(feature="num-rational")]
+impl<T> core::ops::Div<Volume<T>> for &num_rational::BigRational where T: NumLike+From<num_rational::BigRational> {
+	type Output = InverseVolume<T>;
+	fn div(self, rhs: Volume<T>) -> Self::Output {
+		InverseVolume{per_m3: T::from(self.clone()) / rhs.m3}
+	}
+}
+/// Dividing a scalar value by a Volume unit value returns a value of type InverseVolume
+#[cfg(feature="num-rational")]
+impl<T> core::ops::Div<&Volume<T>> for num_rational::BigRational where T: NumLike+From<num_rational::BigRational> {
+	type Output = InverseVolume<T>;
+	fn div(self, rhs: &Volume<T>) -> Self::Output {
+		InverseVolume{per_m3: T::from(self) / rhs.m3.clone()}
+	}
+}
+/// Dividing a scalar value by a Volume unit value returns a value of type InverseVolume
+#[cfg(feature="num-rational")]
+impl<T> core::ops::Div<&Volume<T>> for &num_rational::BigRational where T: NumLike+From<num_rational::BigRational> {
+	type Output = InverseVolume<T>;
+	fn div(self, rhs: &Volume<T>) -> Self::Output {
+		InverseVolume{per_m3: T::from(self.clone()) / rhs.m3.clone()}
+	}
+}
+
+
+
+/// Trigonometric functions for angles with floating-point values (requires the 
+/// `num-traits` feature, and either the `std` or `libm` feature)
+#[cfg(all(feature="num-traits", any(feature="std", feature="libm")))]
+impl<T> Angle<T> where T: NumLike+num_traits::Float {
+
+	/// Returns the sine of this angle
+	pub fn sin(self) -> T { self.rad.sin() }
+
+	/// Returns the cosine of this angle
+	pub fn cos(self) -> T { self.rad.cos() }
+
+	/// Returns the tangent of this angle
+	pub fn tan(self) -> T { self.rad.tan() }
+
+	/// Returns the angle whose sine is the given value, in the range [-pi/2, pi/2] radians
+	///
+	/// # Arguments
+	/// * `x` - The sine of the angle, between -1 and 1
+	pub fn asin(x: T) -> Self { Angle{rad: x.asin()} }
+
+	/// Returns the angle whose cosine is the given value, in the range [0, pi] radians
+	///
+	/// # Arguments
+	/// * `x` - The cosine of the angle, between -1 and 1
+	pub fn acos(x: T) -> Self { Angle{rad: x.acos()} }
+
+	/// Returns the angle whose tangent is the given value, in the range [-pi/2, pi/2] radians
+	///
+	/// # Arguments
+	/// * `x` - The tangent of the angle
+	pub fn atan(x: T) -> Self { Angle{rad: x.atan()} }
+
+	/// Returns the angle of the point (x, y) from the x-axis, in the range [-pi, pi] radians
+	///
+	/// # Arguments
+	/// * `y` - The y coordinate of the point
+	/// * `x` - The x coordinate of the point
+	pub fn atan2(y: Distance<T>, x: Distance<T>) -> Self { Angle{rad: y.m.atan2(x.m)} }
+}
+
+/// Angle wrapping for angles with floating-point values (requires the `num-traits` 
+/// feature, and either the `std` or `libm` feature)
+#[cfg(all(feature="num-traits", any(feature="std", feature="libm")))]
+impl<T> Angle<T> where T: NumLike+num_traits::Float+num_traits::FloatConst+num_traits::Euclid {
+
+	/// Returns the equivalent angle in the range [0, 2pi) radians, eg 3pi becomes pi, 
+	/// 2pi becomes 0, and -pi/2 becomes 3pi/2
+	pub fn wrapped(self) -> Self {
+		let tau = T::TAU();
+		let rad = self.rad.rem_euclid(&tau);
+		// rounding can make rem_euclid(...) of a tiny negative angle return exactly 2pi
+		Angle{rad: if rad >= tau { T::zero() } else { rad }}
+	}
+
+	/// Returns the equivalent angle in the range (-pi, pi] radians, eg 3pi becomes pi, 
+	/// 2pi becomes 0, and 3pi/2 becomes -pi/2
+	pub fn wrapped_signed(self) -> Self {
+		let rad = self.wrapped().rad;
+		Angle{rad: if rad > T::PI() { rad - T::TAU() } else { rad }}
+	}
+}
+
+/// Areas of common shapes
+impl<T> Area<T> where T: NumLike+From<f64> {
+
+	/// Returns the area of a circle with the given radius (ie πr²)
+	///
+	/// # Arguments
+	/// * `radius` - The radius of the circle
+	pub fn circle(radius: Distance<T>) -> Self {
+		Area{m2: T::from(core::f64::consts::PI) * radius.m.clone() * radius.m}
+	}
+}
+
+/// Volumes of common shapes
+impl<T> Volume<T> where T: NumLike+From<f64> {
+
+	/// Returns the volume of a sphere with the given radius (ie 4/3 πr³)
+	///
+	/// # Arguments
+	/// * `radius` - The radius of the sphere
+	pub fn sphere(radius: Distance<T>) -> Self {
+		Volume{m3: T::from(4.0 / 3.0 * core::f64::consts::PI) * radius.m.clone() * radius.m.clone() * radius.m}
+	}
+
+	/// Returns the volume of a cylinder with the given radius and height (ie πr²h)
+	///
+	/// # Arguments
+	/// * `radius` - The radius of the cylinder
+	/// * `height` - The height (or length) of the cylinder
+	pub fn cylinder(radius: Distance<T>, height: Distance<T>) -> Self {
+		Area::circle(radius) * height
+	}
+}
+
+/// Square root of an area (requires the `num-traits` feature, and either the `std` 
+/// or `libm` feature)
+#[cfg(all(feature="num-traits", any(feature="std", feature="libm")))]
+impl<T> Area<T> where T: NumLike+num_traits::Float {
+
+	/// Returns the side length of a square with this area (ie √A)
+	pub fn sqrt(self) -> Distance<T> {
+		Distance{m: self.m2.sqrt()}
+	}
+}
+
+/// Cube root of a volume (requires the `num-traits` feature, and either the `std` 
+/// or `libm` feature)
+#[cfg(all(feature="num-traits", any(feature="std", feature="libm")))]
+impl<T> Volume<T> where T: NumLike+num_traits::Float {
+
+	/// Returns the side length of a cube with this volume (ie ∛V)
+	pub fn cbrt(self) -> Distance<T> {
+		Distance{m: self.m3.cbrt()}
+	}
+}
+
+/// Square root of an inverse area (requires the `num-traits` feature, and either the 
+/// `std` or `libm` feature)
+#[cfg(all(feature="num-traits", any(feature="std", feature="libm")))]
+impl<T> InverseArea<T> where T: NumLike+num_traits::Float {
+
+	/// Returns the inverse side length of a square with this inverse area (ie √(1/A))
+	pub fn sqrt(self) -> InverseDistance<T> {
+		InverseDistance{per_m: self.per_m2.sqrt()}
+	}
+}
+
+/// Cube root of an inverse volume (requires the `num-traits` feature, and either the 
+/// `std` or `libm` feature)
+#[cfg(all(feature="num-traits", any(feature="std", feature="libm")))]
+impl<T> InverseVolume<T> where T: NumLike+num_traits::Float {
 
+	/// Returns the inverse side length of a cube with this inverse volume (ie ∛(1/V))
+	pub fn cbrt(self) -> InverseDistance<T> {
+		InverseDistance{per_m: self.per_m3.cbrt()}
+	}
+}
 
@@ -46,6 +46,8 @@ pub use simple_si_units_core::NumLike;
 // NOTE: test with: RUST_BACKTRACE=full cargo clean && cargo test --all-features
 
 // optional supports
+#[cfg(feature="alloc")]
+extern crate alloc;
 #[cfg(feature="serde")]
 extern crate serde;
 #[cfg(feature="num-bigfloat")]
@@ -54,6 +56,8 @@ extern crate num_bigfloat;
 extern crate num_complex;
 #[cfg(feature="num-rational")]
 extern crate num_rational;
+#[cfg(feature="nalgebra")]
+extern crate nalgebra;
 #[cfg(feature="uom")]
 extern crate uom;
 
@@ -63,6 +67,551 @@ pub mod electromagnetic;
 pub mod geometry;
 pub mod mechanical;
 pub mod nuclear;
+pub mod constants;
+pub mod quantity;
+mod macros;
+mod unit_table;
+pub use unit_table::ALL_UNITS;
+
+/// The prelude module re-exports all of the unit structs from every unit
+/// module, plus the `UnitStruct` and `NumLike` traits, so that they can all be
+/// imported at once with `use simple_si_units::prelude::*;`
+pub mod prelude {
+	pub use super::{UnitStruct, NumLike, SiUnit, Dimension};
+	pub use super::base::*;
+	pub use super::chemical::*;
+	pub use super::electromagnetic::*;
+	pub use super::geometry::*;
+	pub use super::mechanical::*;
+	pub use super::nuclear::*;
+	pub use super::quantity::*;
+}
+
+use core::fmt;
+
+/// The dimension of a unit type, expressed as the exponents of the seven SI
+/// base units (radians and steradians are dimensionless). For example, the
+/// dimension of `Force` (kg·m/s²) has `length: 1, mass: 1, time: -2`. The
+/// dimension of each unit struct is returned by its `dimension()` function,
+/// and multiplying or dividing dimensions adds or subtracts their exponents:
+/// ```rust
+/// use simple_si_units::base::*;
+/// use simple_si_units::mechanical::*;
+///
+/// assert_eq!(Velocity::<f64>::dimension(), Distance::<f64>::dimension() / Time::<f64>::dimension());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Dimension {
+	/// Exponent of meters
+	pub length: i8,
+	/// Exponent of kilograms
+	pub mass: i8,
+	/// Exponent of seconds
+	pub time: i8,
+	/// Exponent of amperes
+	pub current: i8,
+	/// Exponent of kelvin
+	pub temperature: i8,
+	/// Exponent of moles
+	pub amount: i8,
+	/// Exponent of candela
+	pub luminosity: i8
+}
+
+impl Dimension {
+	/// Returns the exponents as an array, in the order length, mass, time,
+	/// current, temperature, amount, luminosity
+	pub const fn to_array(self) -> [i8; 7] {
+		[self.length, self.mass, self.time, self.current, self.temperature, self.amount, self.luminosity]
+	}
+
+	/// Returns true if all of the exponents are zero
+	pub const fn is_dimensionless(self) -> bool {
+		self.length == 0 && self.mass == 0 && self.time == 0 && self.current == 0
+			&& self.temperature == 0 && self.amount == 0 && self.luminosity == 0
+	}
+}
+
+/// Multiplying two dimensions adds their exponents
+impl core::ops::Mul for Dimension {
+	type Output = Dimension;
+	fn mul(self, rhs: Dimension) -> Self::Output {
+		Dimension{
+			length: self.length + rhs.length, mass: self.mass + rhs.mass, time: self.time + rhs.time,
+			current: self.current + rhs.current, temperature: self.temperature + rhs.temperature,
+			amount: self.amount + rhs.amount, luminosity: self.luminosity + rhs.luminosity
+		}
+	}
+}
+
+/// Dividing two dimensions subtracts their exponents
+impl core::ops::Div for Dimension {
+	type Output = Dimension;
+	fn div(self, rhs: Dimension) -> Self::Output {
+		Dimension{
+			length: self.length - rhs.length, mass: self.mass - rhs.mass, time: self.time - rhs.time,
+			current: self.current - rhs.current, temperature: self.temperature - rhs.temperature,
+			amount: self.amount - rhs.amount, luminosity: self.luminosity - rhs.luminosity
+		}
+	}
+}
+
+/// Asserts that two unit values of the same type are equal to within an absolute
+/// tolerance of the same type, using `eq_within(...)`, and panics with the values
+/// of both sides and the tolerance if they are not. Comparing values of different
+/// unit types (or a unit value with a bare number) is a compile error. E.g.
+/// ```rust
+/// use simple_si_units::assert_unit_eq;
+/// use simple_si_units::base::Distance;
+///
+/// assert_unit_eq!(Distance::from_km(1.0), Distance::from_m(1000.2), Distance::from_m(0.5));
+/// ```
+#[macro_export]
+macro_rules! assert_unit_eq {
+	($left:expr, $right:expr, $tolerance:expr $(,)?) => {
+		match (&$left, &$right, &$tolerance) {
+			(left, right, tolerance) => {
+				if !left.clone().eq_within(right.clone(), tolerance.clone()) {
+					::core::panic!("assertion `left == right` failed (within tolerance)\n     left: {:?}\n    right: {:?}\ntolerance: {:?}",
+						left, right, tolerance);
+				}
+			}
+		}
+	};
+}
+
+/// Describes a unit type, as listed in the `ALL_UNITS` table. E.g.
+/// ```rust
+/// use simple_si_units::ALL_UNITS;
+///
+/// let velocity = ALL_UNITS.iter().find(|u| u.type_name == "Velocity").unwrap();
+/// assert_eq!(velocity.symbol, "m/s");
+/// assert_eq!(velocity.dimension.to_array(), [1, 0, -1, 0, 0, 0, 0]);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct UnitInfo {
+	/// Name of the unit struct (eg "Velocity")
+	pub type_name: &'static str,
+	/// Name of the module that contains the unit struct (eg "mechanical")
+	pub module: &'static str,
+	/// Standard unit name, as returned by `unit_name()` (eg "meters per second")
+	pub unit_name: &'static str,
+	/// Unit symbol, as returned by `unit_symbol()` (eg "m/s")
+	pub symbol: &'static str,
+	/// Exponents of the SI base units, as returned by `dimension()`
+	pub dimension: Dimension
+}
+
+/// The `SiUnit` trait is implemented by all of the unit structs in this crate,
+/// providing access to their unit name, unit symbol, and value in a way that
+/// can be used with trait objects. E.g.
+/// ```rust
+/// use simple_si_units::SiUnit;
+/// use simple_si_units::base::*;
+/// use simple_si_units::mechanical::*;
+///
+/// let readings: [&dyn SiUnit<Number=f64>; 3] = [
+///   &Distance::from_m(1.5), &Time::from_s(0.5), &Velocity::from_mps(3.0)
+/// ];
+/// for r in readings {
+///   println!("{} {} ({})", r.raw(), r.unit_symbol(), r.unit_name());
+/// }
+/// ```
+pub trait SiUnit: fmt::Display {
+	/// The number type that holds the value of the unit struct (eg `f64`)
+	type Number;
+	/// Returns the standard unit name of this unit struct (eg "meters")
+	fn unit_name(&self) -> &'static str;
+	/// Returns the abbreviated name or symbol of this unit struct (eg "m")
+	fn unit_symbol(&self) -> &'static str;
+	/// Returns a reference to the value of this unit struct in its SI reference
+	/// unit of measure (eg meters for `Distance`)
+	fn raw(&self) -> &Self::Number;
+	/// Returns the exponents of the SI base units of this unit struct
+	fn dimension(&self) -> Dimension;
+	/// Creates a unit struct from a value in its SI reference unit of measure
+	/// (eg meters for `Distance`)
+	fn from_raw(value: Self::Number) -> Self where Self: Sized;
+	/// Returns the value of this unit struct in its SI reference unit of
+	/// measure (eg meters for `Distance`)
+	fn into_raw(self) -> Self::Number where Self: Sized;
+
+	/// Converts this unit struct to another unit type with the same dimensions
+	/// and the same SI unit of measure, returning `None` if the units are not
+	/// compatible. For example, a `Temperature` can be converted to a
+	/// `TemperatureInterval` (both are in kelvin) and a `HeatCapacity` to an
+	/// `Entropy` (both are in joules per kelvin).
+	///
+	/// Unit types which have the same dimensions but different units of
+	/// measure are physically different quantities, so they cannot be
+	/// converted with this function. This includes `Torque` (N·m) and `Energy`
+	/// (J), `Frequency` (Hz), `Radioactivity` (Bq) and `AngularVelocity`
+	/// (rad/s), and `AbsorbedDose` (Gy) and `DoseEquivalent` (Sv). E.g.
+	/// ```rust
+	/// use simple_si_units::SiUnit;
+	/// use simple_si_units::mechanical::*;
+	///
+	/// let e = Energy::from_J(5.0);
+	/// assert_eq!(e.try_convert::<Energy<f64>>(), Some(Energy::from_J(5.0)));
+	/// assert_eq!(e.try_convert::<Torque<f64>>(), None);
+	/// ```
+	fn try_convert<U>(self) -> Option<U> where Self: Sized, U: SiUnit<Number=Self::Number> {
+		let dimension = self.dimension();
+		let symbol = self.unit_symbol();
+		let converted = U::from_raw(self.into_raw());
+		if converted.dimension() == dimension && converted.unit_symbol() == symbol {
+			Some(converted)
+		} else {
+			None
+		}
+	}
+}
+
+/// Error returned when a unit struct cannot be parsed from a string (see the
+/// `core::str::FromStr` implementation of each unit struct)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SiParseError {
+	/// The number part of the string could not be parsed as the unit struct's
+	/// number type
+	BadNumber,
+	/// The unit symbol in the string does not match the unit symbol of the unit
+	/// struct (eg "5 s" cannot be parsed as a Distance)
+	WrongUnit {
+		/// The expected unit symbol, as returned by `unit_symbol()`
+		expected: &'static str,
+		/// The unit symbol that was found instead
+		found: FoundSymbol
+	},
+}
+
+impl fmt::Display for SiParseError {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			SiParseError::BadNumber => write!(f, "invalid number"),
+			SiParseError::WrongUnit {expected, found} =>
+				write!(f, "wrong unit: expected \"{}\" but found \"{}\"", expected, found),
+		}
+	}
+}
+
+/// A copy of the unit symbol found by the parser, reported in
+/// `SiParseError::WrongUnit`. Since this crate does not allocate memory, the
+/// symbol is stored inline and truncated to at most 32 bytes.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct FoundSymbol {
+	bytes: [u8; 32],
+	len: usize
+}
+
+impl FoundSymbol {
+	fn new(symbol: &str) -> Self {
+		let mut len = symbol.len().min(32);
+		while !symbol.is_char_boundary(len) { len -= 1; }
+		let mut bytes = [0u8; 32];
+		bytes[..len].copy_from_slice(&symbol.as_bytes()[..len]);
+		FoundSymbol{bytes, len}
+	}
+
+	/// Returns the found unit symbol as a string slice
+	pub fn as_str(&self) -> &str {
+		// always valid UTF-8 because it was copied from a str on a char boundary
+		core::str::from_utf8(&self.bytes[..self.len]).unwrap_or("")
+	}
+}
+
+impl fmt::Debug for FoundSymbol {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		fmt::Debug::fmt(self.as_str(), f)
+	}
+}
+
+impl fmt::Display for FoundSymbol {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.write_str(self.as_str())
+	}
+}
+
+#[cfg(feature="serde-unit-tag")]
+impl<'de> serde::Deserialize<'de> for FoundSymbol {
+	fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+		struct SymbolVisitor;
+		impl<'de> serde::de::Visitor<'de> for SymbolVisitor {
+			type Value = FoundSymbol;
+			fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+				f.write_str("a unit symbol string")
+			}
+			fn visit_str<E: serde::de::Error>(self, v: &str) -> Result<FoundSymbol, E> {
+				Ok(FoundSymbol::new(v))
+			}
+		}
+		deserializer.deserialize_str(SymbolVisitor)
+	}
+}
+
+/// Value and unit symbol pair, as read by `deserialize_with_unit_tag(...)`
+#[cfg(feature="serde-unit-tag")]
+#[derive(serde::Deserialize)]
+#[serde(rename = "UnitValue")]
+struct UnitTaggedValue<T> {
+	value: T,
+	unit: FoundSymbol
+}
+
+/// Serializes a unit struct value as `{"value": ..., "unit": "..."}`, as used by
+/// the `serde::Serialize` implementations of the unit structs when the
+/// `serde-unit-tag` feature is enabled. Compact binary formats (ie those which
+/// are not human-readable, such as postcard or bincode) get the bare value
+/// instead, without the unit symbol
+#[cfg(feature="serde-unit-tag")]
+pub(crate) fn serialize_with_unit_tag<T, S>(value: &T, symbol: &'static str, serializer: S) -> Result<S::Ok, S::Error>
+	where T: serde::Serialize, S: serde::Serializer
+{
+	use serde::ser::SerializeStruct;
+	if serializer.is_human_readable() {
+		let mut state = serializer.serialize_struct("UnitValue", 2)?;
+		state.serialize_field("value", value)?;
+		state.serialize_field("unit", symbol)?;
+		state.end()
+	} else {
+		value.serialize(serializer)
+	}
+}
+
+/// Deserializes a unit struct value from `{"value": ..., "unit": "..."}`,
+/// returning an error if the unit symbol does not match the given symbol.
+/// Formats which are not human-readable are read as the bare value, the same
+/// as written by `serialize_with_unit_tag(...)`
+#[cfg(feature="serde-unit-tag")]
+pub(crate) fn deserialize_with_unit_tag<'de, T, D>(symbol: &'static str, deserializer: D) -> Result<T, D::Error>
+	where T: serde::Deserialize<'de>, D: serde::Deserializer<'de>
+{
+	use serde::Deserialize;
+	if !deserializer.is_human_readable() {
+		T::deserialize(deserializer)
+	} else {
+		let tagged = UnitTaggedValue::<T>::deserialize(deserializer)?;
+		if tagged.unit.as_str() == symbol {
+			Ok(tagged.value)
+		} else {
+			Err(serde::de::Error::custom(SiParseError::WrongUnit {expected: symbol, found: tagged.unit}))
+		}
+	}
+}
+
+/// Error returned when a `Time` value cannot be converted into a
+/// `core::time::Duration` because it is negative, not finite (ie NaN or
+/// infinity), or too large to be stored in a `Duration`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DurationConversionError;
+
+impl fmt::Display for DurationConversionError {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		write!(f, "time cannot be represented as a Duration (must be finite and non-negative)")
+	}
+}
+
+/// Error returned by the checked constructors of unit types that cannot
+/// physically be negative, such as `Temperature::try_from_K(...)`, when given
+/// a negative (or NaN) value
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DomainError {
+	/// The name of the unit type that rejected the value (eg "Temperature")
+	pub unit_type: &'static str
+}
+
+impl fmt::Display for DomainError {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		write!(f, "{} value cannot be negative", self.unit_type)
+	}
+}
+
+/// Parses a number followed by the given unit symbol (eg "5 m" or "5m"), as
+/// used by the `FromStr` implementations of the unit structs. The number may be
+/// wrapped in parentheses, as written for complex numbers (eg "(1+2i) m")
+pub(crate) fn parse_unit_value<T>(s: &str, symbol: &'static str) -> Result<T, SiParseError>
+	where T: core::str::FromStr
+{
+	let s = s.trim();
+	let (number, found) = match s.rfind(char::is_whitespace) {
+		Some(i) => (s[..i].trim_end(), s[i..].trim_start()),
+		None => match s.strip_suffix(symbol) {
+			Some(number) => (number, symbol),
+			None => {
+				// no space between number and symbol, so split at the first non-number character
+				let i = s.find(|c: char| !(c.is_ascii_digit() || "+-.eE".contains(c)))
+					.unwrap_or(s.len());
+				(&s[..i], &s[i..])
+			}
+		}
+	};
+	if found != symbol {
+		return Err(SiParseError::WrongUnit {expected: symbol, found: FoundSymbol::new(found)});
+	}
+	let number = number.strip_prefix('(').and_then(|n| n.strip_suffix(')')).unwrap_or(number);
+	number.parse::<T>().map_err(|_| SiParseError::BadNumber)
+}
+
+/// Relative-tolerance comparison used by the `approx_eq(...)` methods of the
+/// unit structs
+pub(crate) fn approx_eq_f64(a: f64, b: f64, sig_figs: i32) -> bool {
+	if a.is_nan() {
+		return b.is_nan();
+	} else if a.is_infinite() {
+		return b.is_infinite() && a.is_sign_positive() == b.is_sign_positive();
+	} else if a == 0.0 {
+		return b == 0.0;
+	}
+	let mut ypsilon = 1.0_f64;
+	for _ in 0..sig_figs.unsigned_abs() {
+		if sig_figs > 0 { ypsilon /= 10.0; } else { ypsilon *= 10.0; }
+	}
+	let magnitude = |x: f64| if x < 0.0 { -x } else { x };
+	let max_delta = (magnitude(a) + magnitude(b)) * 0.5 * ypsilon;
+	magnitude(a - b) < max_delta
+}
+
+/// Fixed-size text buffer for formatting numbers without allocating memory
+struct TextBuffer {
+	bytes: [u8; 64],
+	len: usize
+}
+
+impl TextBuffer {
+	fn new() -> Self { TextBuffer{bytes: [0u8; 64], len: 0} }
+	fn as_str(&self) -> &str { core::str::from_utf8(&self.bytes[..self.len]).unwrap_or("") }
+}
+
+impl fmt::Write for TextBuffer {
+	fn write_str(&mut self, s: &str) -> fmt::Result {
+		let end = self.len + s.len();
+		if end > self.bytes.len() { return Err(fmt::Error); }
+		self.bytes[self.len..end].copy_from_slice(s.as_bytes());
+		self.len = end;
+		Ok(())
+	}
+}
+
+/// Writes a number followed by the unit symbol, as used by the `Display`
+/// format of the unit structs. The precision, width, alignment, and sign flags
+/// of the formatter apply to the number (eg `{:8.2}`). Complex numbers (eg
+/// "1+2i") are wrapped in parentheses so that the unit symbol clearly applies
+/// to the whole number.
+pub(crate) fn fmt_unit_value<T>(f: &mut fmt::Formatter<'_>, value: &T, symbol: &'static str) -> fmt::Result
+	where T: fmt::Display
+{
+	use fmt::Write;
+	let mut text = TextBuffer::new();
+	let written = match (f.precision(), f.sign_plus()) {
+		(Some(precision), true) => write!(text, "{:+.*}", precision, value),
+		(Some(precision), false) => write!(text, "{:.*}", precision, value),
+		(None, true) => write!(text, "{:+}", value),
+		(None, false) => write!(text, "{}", value)
+	};
+	if written.is_ok() {
+		let number = text.as_str();
+		let is_complex = number.ends_with('i') && number.trim_start_matches(['+', '-']).contains(['+', '-']);
+		if is_complex {
+			let mut parenthesized = TextBuffer::new();
+			if write!(parenthesized, "({})", number).is_err() {
+				return write!(f, "({}) {}", number, symbol);
+			}
+			// pad by hand, because Formatter::pad() would truncate the text to the precision
+			let width = parenthesized.as_str().chars().count();
+			let padding = f.width().unwrap_or(0).saturating_sub(width);
+			let (before, after) = match f.align() {
+				Some(fmt::Alignment::Left) => (0, padding),
+				Some(fmt::Alignment::Center) => (padding / 2, padding - padding / 2),
+				_ => (padding, 0)
+			};
+			let fill = f.fill();
+			for _ in 0..before { f.write_char(fill)?; }
+			f.write_str(parenthesized.as_str())?;
+			for _ in 0..after { f.write_char(fill)?; }
+			return write!(f, " {}", symbol);
+		}
+	}
+	fmt::Display::fmt(value, f)?;
+	write!(f, " {}", symbol)
+}
+
+/// Formats a unit struct value followed by its full unit name instead of its
+/// unit symbol, eg "5 meters" rather than "5 m", as returned by the
+/// `display_named()` method of the unit structs. This is intended for
+/// accessibility and voice interfaces. The precision flag is applied to the
+/// number, eg `format!("{:.1}", d.display_named())` gives "5.0 meters".
+///
+/// Unit names are always written as returned by `unit_name()`, which is
+/// plural (eg "1 meters"). No attempt is made to singularize them, because
+/// many names are compound (eg "kilograms per cubic meter") or are the same in
+/// the singular (eg "siemens" and "lux"), and whether a value such as 1.0 or
+/// -1 takes the singular form depends on the language and style guide.
+#[derive(Debug, Clone, Copy)]
+pub struct NamedDisplay<'a, T: NumLike> {
+	value: &'a T,
+	name: &'static str
+}
+
+impl<'a, T: NumLike> NamedDisplay<'a, T> {
+	/// Creates a wrapper which formats the given value and unit name
+	pub(crate) fn new(value: &'a T, name: &'static str) -> Self {
+		NamedDisplay{value, name}
+	}
+}
+
+impl<T: NumLike> fmt::Display for NamedDisplay<'_, T> {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		fmt_unit_value(f, self.value, self.name)
+	}
+}
+
+/// Writes a value and unit symbol, scaling the value to the nearest SI prefix
+/// (eg "1.5 km" instead of "1500 m") with 3 significant figures. This is used
+/// by the alternate (`{:#}`) `Display` format of the unit structs. Values that
+/// are not real numbers, as well as units that cannot take a prefix (eg "kg",
+/// "m/s" or non-SI symbols such as "Ma", as flagged by `prefixable`), are
+/// written as-is. A unit without a symbol is never prefixed. Values too small
+/// or too large for the pico to tera prefixes are written in exponent notation
+/// instead (eg "1e-20 m").
+pub(crate) fn fmt_with_si_prefix<T>(f: &mut fmt::Formatter<'_>, value: &T, symbol: &'static str, prefixable: bool) -> fmt::Result
+	where T: fmt::Display
+{
+	use fmt::Write;
+	const PREFIXES: [&str; 9] = ["p", "n", "µ", "m", "", "k", "M", "G", "T"];
+	let prefixable = prefixable && !symbol.is_empty();
+	let mut text = TextBuffer::new();
+	let number = match write!(text, "{}", value) {
+		Ok(_) => text.as_str().parse::<f64>().ok(),
+		Err(_) => None
+	};
+	let x = match number {
+		Some(x) if prefixable && x.is_finite() && x != 0.0 => x,
+		_ => return fmt_unit_value(f, value, symbol)
+	};
+	let mut magnitude = if x < 0.0 { -x } else { x };
+	let mut index = 4;
+	while magnitude >= 999.5 && index < PREFIXES.len() - 1 {
+		magnitude /= 1000.0;
+		index += 1;
+	}
+	while magnitude < 0.9995 && index > 0 {
+		magnitude *= 1000.0;
+		index -= 1;
+	}
+	let trim_zeros = |number: &str| -> usize {
+		if number.contains('.') { number.trim_end_matches('0').trim_end_matches('.').len() } else { number.len() }
+	};
+	let mut text = TextBuffer::new();
+	if !(0.9995..999.5).contains(&magnitude) {
+		// beyond the SI prefixes, so write 3 significant figures in exponent notation instead
+		write!(text, "{:.2e}", x)?;
+		let (mantissa, exponent) = text.as_str().split_once('e').unwrap_or((text.as_str(), "0"));
+		return write!(f, "{}e{} {}", &mantissa[..trim_zeros(mantissa)], exponent, symbol);
+	}
+	let decimals = if magnitude < 9.995 { 2 } else if magnitude < 99.95 { 1 } else { 0 };
+	write!(text, "{:.*}", decimals, if x < 0.0 { -magnitude } else { magnitude })?;
+	let number = text.as_str();
+	write!(f, "{} {}{}", &number[..trim_zeros(number)], PREFIXES[index], symbol)
+}
 
 #[cfg(test)]
 #[macro_use]
@@ -131,656 +680,2398 @@ mod unit_tests {
 		d2 /= -0.5;
 		assert_approx_equal(d2.to_m(), 5.0, 9);
 	}
+	/// Unit test
+	#[test]
+	fn from_str_test() {
+		use core::str::FromStr;
+		use std::string::ToString;
+		use super::SiParseError;
+		assert_eq!(Distance::<f64>::from_str("5 m"), Ok(Distance::from_m(5.0)));
+		assert_eq!(Distance::<f64>::from_str(" 5m "), Ok(Distance::from_m(5.0)));
+		assert_eq!(Distance::<i32>::from_str("-3 m"), Ok(Distance::from_m(-3)));
+		assert_eq!(Acceleration::<f64>::from_str("9.81 m/s²"), Ok(Acceleration::from_mps2(9.81)));
+		match Distance::<f64>::from_str("5 s") {
+			Err(SiParseError::WrongUnit {expected, found}) => {
+				assert_eq!(expected, "m");
+				assert_eq!(found.as_str(), "s");
+			},
+			other => panic!("expected WrongUnit error, got {:?}", other)
+		}
+		assert!(matches!(Distance::<f64>::from_str("5 mm"), Err(SiParseError::WrongUnit {..})));
+		assert!(matches!(Distance::<f64>::from_str("5"), Err(SiParseError::WrongUnit {..})));
+		assert_eq!(Distance::<f64>::from_str("five m"), Err(SiParseError::BadNumber));
+		assert_eq!(Distance::<i32>::from_str("1.5 m"), Err(SiParseError::BadNumber));
+		// round trip
+		let d = Distance::from_m(1234.5678);
+		assert_eq!(d.to_string().parse(), Ok(d));
+		let a = Area::from_m2(-0.001);
+		assert_eq!(a.to_string().parse(), Ok(a));
+		let c = SpecificHeatCapacity::from_J_per_kgK(4184.0);
+		assert_eq!(c.to_string().parse(), Ok(c));
+	}
+	/// Unit test
+	#[test]
+	fn try_from_str_test() {
+		use core::convert::TryFrom;
+		use super::SiParseError;
+		fn total_length(a: &str, b: &str) -> Result<Distance<f64>, SiParseError> {
+			Ok(Distance::try_from(a)? + Distance::try_from(b)?)
+		}
+		assert_eq!(total_length("3 m", "2.5 m"), Ok(Distance::from_m(5.5)));
+		assert!(matches!(total_length("3 m", "2.5 s"), Err(SiParseError::WrongUnit {..})));
+		assert_eq!(total_length("three m", "2.5 m"), Err(SiParseError::BadNumber));
+		// same result as FromStr
+		assert_eq!(Energy::<i64>::try_from("-7 J"), "-7 J".parse::<Energy<i64>>());
+	}
 
-	fn mul_check<
-		'y,
-		A: std::ops::Mul<B, Output = X>+Clone+'y,
-		B: std::ops::Mul<A, Output = X>+Clone+'y,
-		X: std::cmp::PartialEq+Clone
-	>(a: &'y A, b: &'y B) -> X where
-		A: std::ops::Mul<B, Output = X>+std::ops::Mul<&'y B, Output = X>,
-		B: std::ops::Mul<A, Output = X>+std::ops::Mul<&'y A, Output = X>,
-		&'y A: std::ops::Mul<B, Output = X>, &'y B: std::ops::Mul<A, Output = X>,
-		&'y A: std::ops::Mul<&'y B, Output = X>, &'y B: std::ops::Mul<&'y A, Output = X>
-	{
-		let x1: X = a * b;
-		let x2: X = b * a;
-		let x3: X = a.clone() * b.clone();
-		let x4: X = b.clone() * a.clone();
-		let x5: X = a * b.clone();
-		let x6: X = b * a.clone();
-		let x7: X = a.clone() * b;
-		let x8: X = b.clone() * a;
-		// let x6: X = b.clone() * &(a.clone());
-		assert!((x1.eq(&x2)));
-		assert!((x1.eq(&x3)));
-		assert!((x1.eq(&x4)));
-		assert!((x1.eq(&x5)));
-		assert!((x1.eq(&x6)));
-		assert!((x1.eq(&x7)));
-		assert!((x1.eq(&x8)));
-		return x1;
+	/// Unit test
+	#[test]
+	fn prefixed_display_test() {
+		assert_eq!(format!("{}", Distance::from_m(1500.0)), "1500 m");
+		assert_eq!(format!("{:#}", Distance::from_m(1500.0)), "1.5 km");
+		assert_eq!(format!("{:#}", Distance::from_m(0.0025)), "2.5 mm");
+		assert_eq!(format!("{:#}", Distance::from_m(-0.0025)), "-2.5 mm");
+		assert_eq!(format!("{:#}", Time::from_s(1.23456e-7)), "123 ns");
+		assert_eq!(format!("{:#}", Time::from_s(2.5e-6)), "2.5 µs");
+		assert_eq!(format!("{:#}", Frequency::from_Hz(999.99)), "1 kHz");
+		assert_eq!(format!("{:#}", Energy::from_J(4.2e18)), "4.2e18 J");
+		assert_eq!(format!("{:#}", Distance::from_m(1500)), "1.5 km");
+		assert_eq!(format!("{:#}", Distance::from_m(0.0)), "0 m");
+		// unit symbols that cannot take a prefix
+		assert_eq!(format!("{:#}", Mass::from_kg(1500.0)), "1500 kg");
+		assert_eq!(format!("{:#}", Velocity::from_mps(1500.0)), "1500 m/s");
+		assert_eq!(format!("{:#}", Area::from_m2(1500.0)), "1500 m²");
+		assert_eq!(format!("{:#}", Torque::from_Nm(1500.0)), "1500 Nm");
+		assert_eq!(format!("{:#}", Strain::new(1500.0)), "1500 m/m");
+		assert_eq!(format!("{:#}", MachNumber::new(1500.0)), "1500 Ma");
+		assert_eq!(format!("{:#}", Dimensionless::new(1500.0)), "1500");
 	}
 
-	fn div_check<
-		'y,
-		A: std::ops::Div<B, Output = X>+Clone+'y,
-		B: Clone+'y,
-		X: std::cmp::PartialEq+Clone
-	>(a: &'y A, b: &'y B) -> X where
-		A: std::ops::Div<B, Output = X>+std::ops::Div<&'y B, Output = X>,
-		&'y A: std::ops::Div<B, Output = X>+std::ops::Div<&'y B, Output = X>
-	{
-		let x1: X = a / b;
-		let x2: X = a.clone() / b.clone();
-		let x3: X = a / b.clone();
-		let x4: X = a.clone() / b;
-		assert!((x1.eq(&x2)));
-		assert!((x1.eq(&x3)));
-		assert!((x1.eq(&x4)));
-		return x1;
+	/// Unit test
+	#[test]
+	fn fmt_with_si_prefix_test() {
+		struct Prefixed(f64, &'static str, bool);
+		impl core::fmt::Display for Prefixed {
+			fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+				super::fmt_with_si_prefix(f, &self.0, self.1, self.2)
+			}
+		}
+		assert_eq!(format!("{}", Prefixed(1500.0, "m", true)), "1.5 km");
+		// empty and non-SI symbols are never prefixed
+		assert_eq!(format!("{}", Prefixed(1500.0, "", true)), "1500 ");
+		assert_eq!(format!("{}", Prefixed(1500.0, "Ma", false)), "1500 Ma");
+		// values beyond the pico to tera prefixes
+		assert_eq!(format!("{:#}", Distance::from_m(1e-20)), "1e-20 m");
+		assert_eq!(format!("{:#}", Distance::from_m(-1.234e-15)), "-1.23e-15 m");
+		assert_eq!(format!("{:#}", Distance::from_m(1e20)), "1e20 m");
+		assert_eq!(format!("{:#}", Distance::from_m(4.5678e15)), "4.57e15 m");
+		assert_eq!(format!("{:#}", Distance::from_m(999.4e12)), "999 Tm");
+		assert_eq!(format!("{:#}", Distance::from_m(1.5e-12)), "1.5 pm");
 	}
 
-	macro_rules! mul_div_check {
-		($a: expr, $b: expr, $c: expr, $d: expr) => {
-			assert_eq!(($a) * ($b), ($c));
-			assert_eq!(($a) * &($b), ($c));
-			assert_eq!(&($a) * ($b), ($c));
-			assert_eq!(&($a) * &($b), ($c));
-			assert_eq!(($b) * ($a), ($c));
-			assert_eq!(($b) * &($a), ($c));
-			assert_eq!(&($b) * ($a), ($c));
-			assert_eq!(&($b) * &($a), ($c));
-			assert_eq!(($a) / ($b), ($d));
-			assert_eq!(($a) / &($b), ($d));
-			assert_eq!(&($a) / ($b), ($d));
-			assert_eq!(&($a) / &($b), ($d));
+	/// Unit test
+	#[test]
+	fn abs_test() {
+		assert_eq!(Distance::from_m(-3.5).abs(), Distance::from_m(3.5));
+		assert_eq!(Distance::from_m(3.5).abs(), Distance::from_m(3.5));
+		assert_eq!(Velocity::from_mps(-2.5f32).abs(), Velocity::from_mps(2.5f32));
+		assert_eq!(Energy::from_J(-7i32).abs(), Energy::from_J(7i32));
+		assert_eq!(Energy::from_J(0i64).abs(), Energy::from_J(0i64));
+		assert!(Time::from_s(f64::NAN).abs().to_s().is_nan());
+		let zero = Distance::from_m(-0.0_f64).abs().to_m();
+		assert_eq!(zero, 0.0);
+		assert!(zero.is_sign_positive());
+		assert!(Distance::from_m(0.0_f64).abs().to_m().is_sign_positive());
+		assert!(Velocity::from_mps(-0.0f32).abs().to_mps().is_sign_positive());
+		// complex numbers have no ordering, so abs() is not available for them
+		// (use num_complex::Complex::norm() on the value instead)
+	}
+
+	/// Unit test
+	#[test]
+	#[cfg(all(feature="num-traits", any(feature="std", feature="libm")))]
+	fn area_sqrt_test() {
+		assert_eq!(Area::from_m2(16.0).sqrt(), Distance::from_m(4.0));
+		assert_approx_equal(Area::from_cm2(4.0).sqrt().to_cm(), 2.0, 12);
+		assert_approx_equal(Volume::from_L(1.0).cbrt().to_cm(), 10.0, 12);
+		for m in [0.001, 0.5, 3.0, 1234.5] {
+			let d = Distance::from_m(m);
+			assert_approx_equal((d.clone() * d.clone()).sqrt().to_m(), m, 12);
+			assert_approx_equal((d.clone() * d.clone() * d.clone()).cbrt().to_m(), m, 12);
+			let per_d = InverseDistance::from_per_m(1.0 / m);
+			assert_approx_equal((per_d.clone() * per_d.clone()).sqrt().to_per_m(), 1.0 / m, 12);
 		}
+		assert_eq!(InverseArea::from_per_m2(0.25).sqrt(), InverseDistance::from_per_m(0.5));
+		assert_eq!(InverseVolume::from_per_m3(8.0).cbrt(), InverseDistance::from_per_m(2.0));
 	}
 
-	macro_rules! check_scalar_mul_div {
-		($a: expr, $b: expr) => {
-			let x = ($a).clone();
-			let y = ($b).clone();
-			let xy = x.clone() * y.clone();
-			let xovery = x.clone() / y.clone();
-			mul_div_check!(Amount{mol: x.clone()}, y.clone(), Amount{mol: xy.clone()}, Amount{mol: xovery.clone()});
-			mul_div_check!(Current{A: x.clone()}, y.clone(), Current{A: xy.clone()}, Current{A: xovery.clone()});
-			mul_div_check!(Distance{m: x.clone()}, y.clone(), Distance{m: xy.clone()}, Distance{m: xovery.clone()});
-			mul_div_check!(Luminosity{cd: x.clone()}, y.clone(), Luminosity{cd: xy.clone()}, Luminosity{cd: xovery.clone()});
-			mul_div_check!(Mass{kg: x.clone()}, y.clone(), Mass{kg: xy.clone()}, Mass{kg: xovery.clone()});
-			mul_div_check!(Temperature{K: x.clone()}, y.clone(), Temperature{K: xy.clone()}, Temperature{K: xovery.clone()});
-			mul_div_check!(Time{s: x.clone()}, y.clone(), Time{s: xy.clone()}, Time{s: xovery.clone()});
-			mul_div_check!(CatalyticActivity{molps: x.clone()}, y.clone(), CatalyticActivity{molps: xy.clone()}, CatalyticActivity{molps: xovery.clone()});
-			mul_div_check!(Concentration{molpm3: x.clone()}, y.clone(), Concentration{molpm3: xy.clone()}, Concentration{molpm3: xovery.clone()});
-			mul_div_check!(Molality{molpkg: x.clone()}, y.clone(), Molality{molpkg: xy.clone()}, Molality{molpkg: xovery.clone()});
-			mul_div_check!(MolarMass{kgpmol: x.clone()}, y.clone(), MolarMass{kgpmol: xy.clone()}, MolarMass{kgpmol: xovery.clone()});
-			mul_div_check!(SpecificHeatCapacity{J_per_kgK: x.clone()}, y.clone(), SpecificHeatCapacity{J_per_kgK: xy.clone()}, SpecificHeatCapacity{J_per_kgK: xovery.clone()});
-			mul_div_check!(Capacitance{F: x.clone()}, y.clone(), Capacitance{F: xy.clone()}, Capacitance{F: xovery.clone()});
-			mul_div_check!(Charge{C: x.clone()}, y.clone(), Charge{C: xy.clone()}, Charge{C: xovery.clone()});
-			mul_div_check!(Conductance{S: x.clone()}, y.clone(), Conductance{S: xy.clone()}, Conductance{S: xovery.clone()});
-			mul_div_check!(Illuminance{lux: x.clone()}, y.clone(), Illuminance{lux: xy.clone()}, Illuminance{lux: xovery.clone()});
-			mul_div_check!(Inductance{H: x.clone()}, y.clone(), Inductance{H: xy.clone()}, Inductance{H: xovery.clone()});
-			mul_div_check!(LuminousFlux{lm: x.clone()}, y.clone(), LuminousFlux{lm: xy.clone()}, LuminousFlux{lm: xovery.clone()});
-			mul_div_check!(MagneticFlux{Wb: x.clone()}, y.clone(), MagneticFlux{Wb: xy.clone()}, MagneticFlux{Wb: xovery.clone()});
-			mul_div_check!(MagneticFluxDensity{T: x.clone()}, y.clone(), MagneticFluxDensity{T: xy.clone()}, MagneticFluxDensity{T: xovery.clone()});
-			mul_div_check!(Resistance{Ohm: x.clone()}, y.clone(), Resistance{Ohm: xy.clone()}, Resistance{Ohm: xovery.clone()});
-			mul_div_check!(Voltage{V: x.clone()}, y.clone(), Voltage{V: xy.clone()}, Voltage{V: xovery.clone()});
-			mul_div_check!(Angle{rad: x.clone()}, y.clone(), Angle{rad: xy.clone()}, Angle{rad: xovery.clone()});
-			mul_div_check!(Area{m2: x.clone()}, y.clone(), Area{m2: xy.clone()}, Area{m2: xovery.clone()});
-			mul_div_check!(SolidAngle{sr: x.clone()}, y.clone(), SolidAngle{sr: xy.clone()}, SolidAngle{sr: xovery.clone()});
-			mul_div_check!(Volume{m3: x.clone()}, y.clone(), Volume{m3: xy.clone()}, Volume{m3: xovery.clone()});
-			mul_div_check!(Acceleration{mps2: x.clone()}, y.clone(), Acceleration{mps2: xy.clone()}, Acceleration{mps2: xovery.clone()});
-			mul_div_check!(AngularAcceleration{radps2: x.clone()}, y.clone(), AngularAcceleration{radps2: xy.clone()}, AngularAcceleration{radps2: xovery.clone()});
-			mul_div_check!(AngularMomentum{kgm2radps: x.clone()}, y.clone(), AngularMomentum{kgm2radps: xy.clone()}, AngularMomentum{kgm2radps: xovery.clone()});
-			mul_div_check!(AngularVelocity{radps: x.clone()}, y.clone(), AngularVelocity{radps: xy.clone()}, AngularVelocity{radps: xovery.clone()});
-			mul_div_check!(AreaDensity{kgpm2: x.clone()}, y.clone(), AreaDensity{kgpm2: xy.clone()}, AreaDensity{kgpm2: xovery.clone()});
-			mul_div_check!(Density{kgpm3: x.clone()}, y.clone(), Density{kgpm3: xy.clone()}, Density{kgpm3: xovery.clone()});
-			mul_div_check!(Energy{J: x.clone()}, y.clone(), Energy{J: xy.clone()}, Energy{J: xovery.clone()});
-			mul_div_check!(Force{N: x.clone()}, y.clone(), Force{N: xy.clone()}, Force{N: xovery.clone()});
-			mul_div_check!(Frequency{Hz: x.clone()}, y.clone(), Frequency{Hz: xy.clone()}, Frequency{Hz: xovery.clone()});
-			mul_div_check!(MomentOfInertia{kgm2: x.clone()}, y.clone(), MomentOfInertia{kgm2: xy.clone()}, MomentOfInertia{kgm2: xovery.clone()});
-			mul_div_check!(Momentum{kgmps: x.clone()}, y.clone(), Momentum{kgmps: xy.clone()}, Momentum{kgmps: xovery.clone()});
-			mul_div_check!(Power{W: x.clone()}, y.clone(), Power{W: xy.clone()}, Power{W: xovery.clone()});
-			mul_div_check!(Pressure{Pa: x.clone()}, y.clone(), Pressure{Pa: xy.clone()}, Pressure{Pa: xovery.clone()});
-			mul_div_check!(Torque{Nm: x.clone()}, y.clone(), Torque{Nm: xy.clone()}, Torque{Nm: xovery.clone()});
-			mul_div_check!(Velocity{mps: x.clone()}, y.clone(), Velocity{mps: xy.clone()}, Velocity{mps: xovery.clone()});
-			mul_div_check!(AbsorbedDose{Gy: x.clone()}, y.clone(), AbsorbedDose{Gy: xy.clone()}, AbsorbedDose{Gy: xovery.clone()});
-			mul_div_check!(DoseEquivalent{Sv: x.clone()}, y.clone(), DoseEquivalent{Sv: xy.clone()}, DoseEquivalent{Sv: xovery.clone()});
-			mul_div_check!(Radioactivity{Bq: x.clone()}, y.clone(), Radioactivity{Bq: xy.clone()}, Radioactivity{Bq: xovery.clone()});
-		mul_div_check!(InverseAmount{per_mol: x.clone()}, y.clone(), InverseAmount{per_mol: xy.clone()}, InverseAmount{per_mol: xovery.clone()});
-		mul_div_check!(InverseCurrent{per_A: x.clone()}, y.clone(), InverseCurrent{per_A: xy.clone()}, InverseCurrent{per_A: xovery.clone()});
-		mul_div_check!(InverseDistance{per_m: x.clone()}, y.clone(), InverseDistance{per_m: xy.clone()}, InverseDistance{per_m: xovery.clone()});
-		mul_div_check!(InverseLuminosity{per_cd: x.clone()}, y.clone(), InverseLuminosity{per_cd: xy.clone()}, InverseLuminosity{per_cd: xovery.clone()});
-		mul_div_check!(InverseMass{per_kg: x.clone()}, y.clone(), InverseMass{per_kg: xy.clone()}, InverseMass{per_kg: xovery.clone()});
-		mul_div_check!(InverseTemperature{per_K: x.clone()}, y.clone(), InverseTemperature{per_K: xy.clone()}, InverseTemperature{per_K: xovery.clone()});
-		mul_div_check!(InverseCatalyticActivity{s_per_mol: x.clone()}, y.clone(), InverseCatalyticActivity{s_per_mol: xy.clone()}, InverseCatalyticActivity{s_per_mol: xovery.clone()});
-		mul_div_check!(InverseSpecificHeatCapacity{kgK_per_J: x.clone()}, y.clone(), InverseSpecificHeatCapacity{kgK_per_J: xy.clone()}, InverseSpecificHeatCapacity{kgK_per_J: xovery.clone()});
-		mul_div_check!(MolarVolume{m3_per_mol: x.clone()}, y.clone(), MolarVolume{m3_per_mol: xy.clone()}, MolarVolume{m3_per_mol: xovery.clone()});
-		mul_div_check!(AreaPerLumen{m2_per_lm: x.clone()}, y.clone(), AreaPerLumen{m2_per_lm: xy.clone()}, AreaPerLumen{m2_per_lm: xovery.clone()});
-		mul_div_check!(Elastance{per_F: x.clone()}, y.clone(), Elastance{per_F: xy.clone()}, Elastance{per_F: xovery.clone()});
-		mul_div_check!(InverseCharge{per_C: x.clone()}, y.clone(), InverseCharge{per_C: xy.clone()}, InverseCharge{per_C: xovery.clone()});
-		mul_div_check!(InverseInductance{per_H: x.clone()}, y.clone(), InverseInductance{per_H: xy.clone()}, InverseInductance{per_H: xovery.clone()});
-		mul_div_check!(InverseLuminousFlux{per_lm: x.clone()}, y.clone(), InverseLuminousFlux{per_lm: xy.clone()}, InverseLuminousFlux{per_lm: xovery.clone()});
-		mul_div_check!(InverseMagneticFlux{per_Wb: x.clone()}, y.clone(), InverseMagneticFlux{per_Wb: xy.clone()}, InverseMagneticFlux{per_Wb: xovery.clone()});
-		mul_div_check!(InverseMagneticFluxDensity{m2_per_Wb: x.clone()}, y.clone(), InverseMagneticFluxDensity{m2_per_Wb: xy.clone()}, InverseMagneticFluxDensity{m2_per_Wb: xovery.clone()});
-		mul_div_check!(InverseVoltage{per_V: x.clone()}, y.clone(), InverseVoltage{per_V: xy.clone()}, InverseVoltage{per_V: xovery.clone()});
-		mul_div_check!(InverseAngle{per_rad: x.clone()}, y.clone(), InverseAngle{per_rad: xy.clone()}, InverseAngle{per_rad: xovery.clone()});
-		mul_div_check!(InverseArea{per_m2: x.clone()}, y.clone(), InverseArea{per_m2: xy.clone()}, InverseArea{per_m2: xovery.clone()});
-		mul_div_check!(InverseSolidAngle{per_sr: x.clone()}, y.clone(), InverseSolidAngle{per_sr: xy.clone()}, InverseSolidAngle{per_sr: xovery.clone()});
-		mul_div_check!(InverseVolume{per_m3: x.clone()}, y.clone(), InverseVolume{per_m3: xy.clone()}, InverseVolume{per_m3: xovery.clone()});
-		mul_div_check!(AreaPerMass{m2_per_kg: x.clone()}, y.clone(), AreaPerMass{m2_per_kg: xy.clone()}, AreaPerMass{m2_per_kg: xovery.clone()});
-		mul_div_check!(InverseAcceleration{s2pm: x.clone()}, y.clone(), InverseAcceleration{s2pm: xy.clone()}, InverseAcceleration{s2pm: xovery.clone()});
-		mul_div_check!(InverseAngularAcceleration{s2prad: x.clone()}, y.clone(), InverseAngularAcceleration{s2prad: xy.clone()}, InverseAngularAcceleration{s2prad: xovery.clone()});
-		mul_div_check!(InverseAngularMomentum{s_per_kgm2rad: x.clone()}, y.clone(), InverseAngularMomentum{s_per_kgm2rad: xy.clone()}, InverseAngularMomentum{s_per_kgm2rad: xovery.clone()});
-		mul_div_check!(InverseAngularVelocity{s_per_rad: x.clone()}, y.clone(), InverseAngularVelocity{s_per_rad: xy.clone()}, InverseAngularVelocity{s_per_rad: xovery.clone()});
-		mul_div_check!(InverseEnergy{per_J: x.clone()}, y.clone(), InverseEnergy{per_J: xy.clone()}, InverseEnergy{per_J: xovery.clone()});
-		mul_div_check!(InverseForce{per_N: x.clone()}, y.clone(), InverseForce{per_N: xy.clone()}, InverseForce{per_N: xovery.clone()});
-		mul_div_check!(InverseMomentOfInertia{per_kgm2: x.clone()}, y.clone(), InverseMomentOfInertia{per_kgm2: xy.clone()}, InverseMomentOfInertia{per_kgm2: xovery.clone()});
-		mul_div_check!(InverseMomentum{s_per_kgm: x.clone()}, y.clone(), InverseMomentum{s_per_kgm: xy.clone()}, InverseMomentum{s_per_kgm: xovery.clone()});
-		mul_div_check!(InversePower{per_W: x.clone()}, y.clone(), InversePower{per_W: xy.clone()}, InversePower{per_W: xovery.clone()});
-		mul_div_check!(InversePressure{per_Pa: x.clone()}, y.clone(), InversePressure{per_Pa: xy.clone()}, InversePressure{per_Pa: xovery.clone()});
-		mul_div_check!(InverseTorque{per_Nm: x.clone()}, y.clone(), InverseTorque{per_Nm: xy.clone()}, InverseTorque{per_Nm: xovery.clone()});
-		mul_div_check!(TimePerDistance{spm: x.clone()}, y.clone(), TimePerDistance{spm: xy.clone()}, TimePerDistance{spm: xovery.clone()});
-		mul_div_check!(VolumePerMass{m3_per_kg: x.clone()}, y.clone(), VolumePerMass{m3_per_kg: xy.clone()}, VolumePerMass{m3_per_kg: xovery.clone()});
-		mul_div_check!(InverseAbsorbedDose{per_Gy: x.clone()}, y.clone(), InverseAbsorbedDose{per_Gy: xy.clone()}, InverseAbsorbedDose{per_Gy: xovery.clone()});
-		mul_div_check!(InverseDoseEquivalent{per_Sv: x.clone()}, y.clone(), InverseDoseEquivalent{per_Sv: xy.clone()}, InverseDoseEquivalent{per_Sv: xovery.clone()});
+	/// Unit test
+	#[test]
+	fn celsius_fahrenheit_test() {
+		assert_approx_equal(Temperature::from_celsius(0.0).to_K(), 273.15, 9);
+		assert_approx_equal(Temperature::from_celsius(100.0).to_K(), 373.15, 9);
+		assert_approx_equal(Temperature::from_fahrenheit(32.0).to_K(), 273.15, 9);
+		assert_approx_equal(Temperature::from_fahrenheit(212.0).to_K(), 373.15, 9);
+		assert_approx_equal(Temperature::from_K(273.15).to_celsius(), 0.0, 9);
+		assert_approx_equal(Temperature::from_K(273.15).to_fahrenheit(), 32.0, 9);
+		assert_approx_equal(Temperature::from_celsius(100.0).to_fahrenheit(), 212.0, 9);
+		assert_approx_equal(Temperature::from_fahrenheit(212.0).to_celsius(), 100.0, 9);
+		// -40 is the same temperature in both scales
+		assert_approx_equal(Temperature::from_celsius(-40.0).to_fahrenheit(), -40.0, 9);
+		assert_approx_equal(Temperature::from_fahrenheit(-40.0).to_celsius(), -40.0, 9);
+		assert_approx_equal(Temperature::from_celsius(-40.0).to_K(), 233.15, 9);
+		// the fahrenheit scale factor is exactly the inverse of 1.8
+		assert_eq!(Temperature::from_fahrenheit(-40.0).to_fahrenheit(), -40.0);
+		assert_eq!(Temperature::from_fahrenheit(0.0).to_fahrenheit(), 0.0);
+		assert_eq!(Temperature::from_fahrenheit(-459.67).to_fahrenheit(), -459.67);
+		for f in [0.1, 1.0, 9.0, 18.0, 100.0, 180.0] {
+			assert_eq!(TemperatureInterval::from_fahrenheit(f).to_fahrenheit(), f);
+		}
 	}
-}
 
+	/// Unit test
 	#[test]
-	#[cfg(feature="num-complex")]
-	fn test_complex_scalar_multiply() {
-		use num_complex::{Complex32, Complex64};
-		let x = Complex64::new(1.2, 3.4);
-		let y = Complex64::new(5.6, 7.8);
-		check_scalar_mul_div!(x, y);
-		let x = Complex32::new(1.2, 3.4);
-		let y = Complex32::new(5.6, 7.8);
-		check_scalar_mul_div!(x, y);
+	fn temperature_interval_test() {
+		let t1 = Temperature::from_celsius(20.0);
+		let t2 = Temperature::from_celsius(25.0);
+		let dt = t2.interval_from(&t1);
+		assert_approx_equal(dt.to_K(), 5.0, 9);
+		// same as subtracting the kelvin values
+		assert_eq!(dt.to_K(), t2.to_K() - t1.to_K());
+		// the difference between two temperatures is a temperature interval
+		let diff: TemperatureInterval<f64> = t2 - t1;
+		assert_eq!(diff, dt);
+		assert_eq!(&t2 - &t1, dt);
+		assert_eq!(t2 - &t1, dt);
+		assert_eq!(&t2 - t1, dt);
+		// intervals are scaled without any offset
+		assert_approx_equal(dt.to_celsius(), 5.0, 9);
+		assert_approx_equal(dt.to_fahrenheit(), 9.0, 9);
+		assert_approx_equal(TemperatureInterval::from_celsius(10.0).to_K(), 10.0, 9);
+		assert_approx_equal(TemperatureInterval::from_fahrenheit(9.0).to_K(), 5.0, 9);
+		assert_approx_equal((t1 + dt).to_celsius(), 25.0, 9);
+		assert_approx_equal((dt + t1).to_celsius(), 25.0, 9);
+		assert_approx_equal((t2 - dt).to_celsius(), 20.0, 9);
+		assert_approx_equal((&t1 + &dt).to_K(), t2.to_K(), 9);
+		// heating 2 kg of water by 5 K
+		let heat: Energy<f64> = dt * SpecificHeatCapacity::from_J_per_kgK(4184.0) * Mass::from_kg(2.0);
+		assert_approx_equal(heat.to_J(), 41840.0, 9);
 	}
 
+	/// Unit test
 	#[test]
-	#[cfg(feature="num-bigfloat")]
-	fn test_bigfloat_scalar_multiply() {
-		use num_bigfloat::BigFloat;
-		let x = BigFloat::from(4.2);
-		let y = BigFloat::from(2.1);
-		check_scalar_mul_div!(x, y);
+	#[cfg(all(feature="num-traits", any(feature="std", feature="libm")))]
+	fn angle_trig_test() {
+		use core::f64::consts::PI;
+		assert_approx_equal(Angle::from_rad(PI/6.0).sin(), 0.5, 9);
+		assert_approx_equal(Angle::from_rad(PI/3.0).cos(), 0.5, 9);
+		assert_approx_equal(Angle::from_rad(PI/4.0).tan(), 1.0, 9);
+		assert_approx_equal(Angle::from_rad(-PI/2.0).sin(), -1.0, 9);
+		assert_approx_equal(Angle::asin(0.5).to_rad(), PI/6.0, 9);
+		assert_approx_equal(Angle::acos(-1.0).to_rad(), PI, 9);
+		assert_approx_equal(Angle::atan(1.0).to_rad(), PI/4.0, 9);
+		assert_approx_equal(Angle::atan2(Distance::from_m(1.0), Distance::from_m(-1.0)).to_rad(), 3.0*PI/4.0, 9);
+		assert_approx_equal(Angle::atan2(Distance::from_m(-2.0), Distance::from_m(0.0)).to_rad(), -PI/2.0, 9);
+		assert_eq!(Angle::from_rad(0.5f32).sin(), 0.5f32.sin());
 	}
 
-	///// Place generated unit tests below this comment /////
+	/// Unit test
+	#[test]
+	fn angle_degrees_test() {
+		use core::f64::consts::PI;
+		assert_eq!(Angle::from_degrees(180.0).to_radians(), PI);
+		assert_approx_equal(Angle::from_degrees(90.0).to_rad(), PI/2.0, 12);
+		assert_approx_equal(Angle::from_degrees(360.0).to_rad(), 2.0*PI, 12);
+		assert_approx_equal(Angle::from_degrees(-45.0).to_rad(), -PI/4.0, 12);
+		assert_approx_equal(Angle::from_rad(PI).to_degrees(), 180.0, 12);
+		assert_approx_equal(Angle::from_rad(-PI/2.0).to_degrees(), -90.0, 12);
+		assert_approx_equal(Angle::from_arcminutes(60.0).to_degrees(), 1.0, 12);
+		assert_approx_equal(Angle::from_arcseconds(3600.0).to_degrees(), 1.0, 12);
+		assert_approx_equal(Angle::from_gradians(100.0).to_degrees(), 90.0, 12);
+		assert_approx_equal(Angle::from_degrees(-1.0).to_arcseconds(), -3600.0, 12);
+		assert_approx_equal(Angle::from_rad(PI).to_gradians(), 200.0, 12);
+	}
 
+	/// Unit test
 	#[test]
-	fn unit_names_and_symbols_test() {
-		assert!(Amount::<f64>::unit_name().eq("moles"));
-		assert!(Amount::<f64>::unit_symbol().eq("mol"));
-		assert!(Current::<f64>::unit_name().eq("amperes"));
-		assert!(Current::<f64>::unit_symbol().eq("A"));
-		assert!(Distance::<f64>::unit_name().eq("meters"));
-		assert!(Distance::<f64>::unit_symbol().eq("m"));
-		assert!(Luminosity::<f64>::unit_name().eq("candela"));
-		assert!(Luminosity::<f64>::unit_symbol().eq("cd"));
-		assert!(Mass::<f64>::unit_name().eq("kilograms"));
-		assert!(Mass::<f64>::unit_symbol().eq("kg"));
-		assert!(Temperature::<f64>::unit_name().eq("degrees kelvin"));
-		assert!(Temperature::<f64>::unit_symbol().eq("K"));
-		assert!(Time::<f64>::unit_name().eq("seconds"));
-		assert!(Time::<f64>::unit_symbol().eq("s"));
-		assert!(CatalyticActivity::<f64>::unit_name().eq("moles per second"));
-		assert!(CatalyticActivity::<f64>::unit_symbol().eq("mol/s"));
-		assert!(Concentration::<f64>::unit_name().eq("moles per cubic meter"));
-		assert!(Concentration::<f64>::unit_symbol().eq("mol/m³"));
-		assert!(Molality::<f64>::unit_name().eq("moles per kilogram"));
-		assert!(Molality::<f64>::unit_symbol().eq("mol/kg"));
-		assert!(MolarMass::<f64>::unit_name().eq("kilograms per mole"));
-		assert!(MolarMass::<f64>::unit_symbol().eq("kg/mol"));
-		assert!(SpecificHeatCapacity::<f64>::unit_name().eq("joules per kilogram per kelvin"));
-		assert!(SpecificHeatCapacity::<f64>::unit_symbol().eq("J/kg·K"));
-		assert!(Capacitance::<f64>::unit_name().eq("farads"));
-		assert!(Capacitance::<f64>::unit_symbol().eq("F"));
-		assert!(Charge::<f64>::unit_name().eq("coulombs"));
-		assert!(Charge::<f64>::unit_symbol().eq("C"));
-		assert!(Conductance::<f64>::unit_name().eq("siemens"));
-		assert!(Conductance::<f64>::unit_symbol().eq("S"));
-		assert!(Illuminance::<f64>::unit_name().eq("lux"));
-		assert!(Illuminance::<f64>::unit_symbol().eq("lux"));
-		assert!(Inductance::<f64>::unit_name().eq("henries"));
-		assert!(Inductance::<f64>::unit_symbol().eq("H"));
-		assert!(LuminousFlux::<f64>::unit_name().eq("lumens"));
-		assert!(LuminousFlux::<f64>::unit_symbol().eq("lm"));
-		assert!(MagneticFlux::<f64>::unit_name().eq("webers"));
-		assert!(MagneticFlux::<f64>::unit_symbol().eq("Wb"));
-		assert!(MagneticFluxDensity::<f64>::unit_name().eq("teslas"));
-		assert!(MagneticFluxDensity::<f64>::unit_symbol().eq("T"));
-		assert!(Resistance::<f64>::unit_name().eq("ohms"));
-		assert!(Resistance::<f64>::unit_symbol().eq("Ohm"));
-		assert!(Voltage::<f64>::unit_name().eq("volts"));
-		assert!(Voltage::<f64>::unit_symbol().eq("V"));
-		assert!(Angle::<f64>::unit_name().eq("radians"));
-		assert!(Angle::<f64>::unit_symbol().eq("rad"));
-		assert!(Area::<f64>::unit_name().eq("square meters"));
-		assert!(Area::<f64>::unit_symbol().eq("m²"));
-		assert!(SolidAngle::<f64>::unit_name().eq("steradian"));
-		assert!(SolidAngle::<f64>::unit_symbol().eq("sr"));
-		assert!(Volume::<f64>::unit_name().eq("cubic meters"));
-		assert!(Volume::<f64>::unit_symbol().eq("m³"));
-		assert!(Acceleration::<f64>::unit_name().eq("meters per second squared"));
-		assert!(Acceleration::<f64>::unit_symbol().eq("m/s²"));
-		assert!(AngularAcceleration::<f64>::unit_name().eq("radians per second squared"));
-		assert!(AngularAcceleration::<f64>::unit_symbol().eq("rad/s²"));
-		assert!(AngularMomentum::<f64>::unit_name().eq("kilogram meters squared radians per second"));
-		assert!(AngularMomentum::<f64>::unit_symbol().eq("kg·m²·rad/s"));
-		assert!(AngularVelocity::<f64>::unit_name().eq("radians per second"));
-		assert!(AngularVelocity::<f64>::unit_symbol().eq("rad/s"));
-		assert!(AreaDensity::<f64>::unit_name().eq("kilograms per square meter"));
-		assert!(AreaDensity::<f64>::unit_symbol().eq("kg/m²"));
-		assert!(Density::<f64>::unit_name().eq("kilograms per cubic meter"));
-		assert!(Density::<f64>::unit_symbol().eq("kg/m³"));
-		assert!(Energy::<f64>::unit_name().eq("joules"));
-		assert!(Energy::<f64>::unit_symbol().eq("J"));
-		assert!(Force::<f64>::unit_name().eq("newtons"));
-		assert!(Force::<f64>::unit_symbol().eq("N"));
-		assert!(Frequency::<f64>::unit_name().eq("hertz"));
-		assert!(Frequency::<f64>::unit_symbol().eq("Hz"));
-		assert!(MomentOfInertia::<f64>::unit_name().eq("kilogram meters squared"));
-		assert!(MomentOfInertia::<f64>::unit_symbol().eq("kg·m²"));
-		assert!(Momentum::<f64>::unit_name().eq("kilogram meters per second"));
-		assert!(Momentum::<f64>::unit_symbol().eq("kg·m/s"));
-		assert!(Power::<f64>::unit_name().eq("watts"));
-		assert!(Power::<f64>::unit_symbol().eq("W"));
-		assert!(Pressure::<f64>::unit_name().eq("pascals"));
-		assert!(Pressure::<f64>::unit_symbol().eq("Pa"));
-		assert!(Torque::<f64>::unit_name().eq("newton meters"));
-		assert!(Torque::<f64>::unit_symbol().eq("Nm"));
-		assert!(Velocity::<f64>::unit_name().eq("meters per second"));
-		assert!(Velocity::<f64>::unit_symbol().eq("m/s"));
-		assert!(AbsorbedDose::<f64>::unit_name().eq("grays"));
-		assert!(AbsorbedDose::<f64>::unit_symbol().eq("Gy"));
-		assert!(DoseEquivalent::<f64>::unit_name().eq("sieverts"));
-		assert!(DoseEquivalent::<f64>::unit_symbol().eq("Sv"));
-		assert!(Radioactivity::<f64>::unit_name().eq("becquerels"));
-		assert!(Radioactivity::<f64>::unit_symbol().eq("Bq"));
-		assert!(InverseAmount::<f64>::unit_name().eq("inverse moles"));
-		assert!(InverseAmount::<f64>::unit_symbol().eq("1/mol"));
-		assert!(InverseCurrent::<f64>::unit_name().eq("inverse amperes"));
-		assert!(InverseCurrent::<f64>::unit_symbol().eq("1/A"));
-		assert!(InverseDistance::<f64>::unit_name().eq("inverse meters"));
-		assert!(InverseDistance::<f64>::unit_symbol().eq("1/m"));
-		assert!(InverseLuminosity::<f64>::unit_name().eq("inverse candela"));
-		assert!(InverseLuminosity::<f64>::unit_symbol().eq("1/cd"));
-		assert!(InverseMass::<f64>::unit_name().eq("inverse kilograms"));
-		assert!(InverseMass::<f64>::unit_symbol().eq("1/kg"));
-		assert!(InverseTemperature::<f64>::unit_name().eq("inverse degrees kelvin"));
-		assert!(InverseTemperature::<f64>::unit_symbol().eq("1/K"));
-		assert!(InverseCatalyticActivity::<f64>::unit_name().eq("seconds per mole"));
-		assert!(InverseCatalyticActivity::<f64>::unit_symbol().eq("s/mol"));
-		assert!(InverseSpecificHeatCapacity::<f64>::unit_name().eq("kilogram per kelvin per joules"));
-		assert!(InverseSpecificHeatCapacity::<f64>::unit_symbol().eq("kg·K/J"));
-		assert!(MolarVolume::<f64>::unit_name().eq("cubic meters per mole"));
-		assert!(MolarVolume::<f64>::unit_symbol().eq("m³/mol"));
-		assert!(AreaPerLumen::<f64>::unit_name().eq("square meters per lumen"));
-		assert!(AreaPerLumen::<f64>::unit_symbol().eq("m²/lm"));
-		assert!(Elastance::<f64>::unit_name().eq("inverse farads"));
-		assert!(Elastance::<f64>::unit_symbol().eq("1/F"));
-		assert!(InverseCharge::<f64>::unit_name().eq("inverse coulombs"));
-		assert!(InverseCharge::<f64>::unit_symbol().eq("1/C"));
-		assert!(InverseInductance::<f64>::unit_name().eq("inverse henries"));
-		assert!(InverseInductance::<f64>::unit_symbol().eq("1/H"));
-		assert!(InverseLuminousFlux::<f64>::unit_name().eq("inverse lumens"));
-		assert!(InverseLuminousFlux::<f64>::unit_symbol().eq("1/lm"));
-		assert!(InverseMagneticFlux::<f64>::unit_name().eq("inverse webers"));
-		assert!(InverseMagneticFlux::<f64>::unit_symbol().eq("1/Wb"));
-		assert!(InverseMagneticFluxDensity::<f64>::unit_name().eq("square meters per weber"));
-		assert!(InverseMagneticFluxDensity::<f64>::unit_symbol().eq("m²/Wb"));
-		assert!(InverseVoltage::<f64>::unit_name().eq("inverse volts"));
-		assert!(InverseVoltage::<f64>::unit_symbol().eq("1/V"));
-		assert!(InverseAngle::<f64>::unit_name().eq("inverse radians"));
-		assert!(InverseAngle::<f64>::unit_symbol().eq("1/rad"));
-		assert!(InverseArea::<f64>::unit_name().eq("inverse square meters"));
-		assert!(InverseArea::<f64>::unit_symbol().eq("1/m²"));
-		assert!(InverseSolidAngle::<f64>::unit_name().eq("inverse steradian"));
-		assert!(InverseSolidAngle::<f64>::unit_symbol().eq("1/sr"));
-		assert!(InverseVolume::<f64>::unit_name().eq("inverse cubic meters"));
-		assert!(InverseVolume::<f64>::unit_symbol().eq("1/m³"));
-		assert!(AreaPerMass::<f64>::unit_name().eq("square meters per kilogram"));
-		assert!(AreaPerMass::<f64>::unit_symbol().eq("m²/kg"));
-		assert!(InverseAcceleration::<f64>::unit_name().eq("seconds squared per meter"));
-		assert!(InverseAcceleration::<f64>::unit_symbol().eq("s²/m"));
-		assert!(InverseAngularAcceleration::<f64>::unit_name().eq("seconds squared per radian"));
-		assert!(InverseAngularAcceleration::<f64>::unit_symbol().eq("s²/rad"));
-		assert!(InverseAngularMomentum::<f64>::unit_name().eq("seconds per kilogram meters squared radian"));
-		assert!(InverseAngularMomentum::<f64>::unit_symbol().eq("s/kg·m²·rad"));
-		assert!(InverseAngularVelocity::<f64>::unit_name().eq("seconds per radian"));
-		assert!(InverseAngularVelocity::<f64>::unit_symbol().eq("s/rad"));
-		assert!(InverseEnergy::<f64>::unit_name().eq("inverse joules"));
-		assert!(InverseEnergy::<f64>::unit_symbol().eq("1/J"));
-		assert!(InverseForce::<f64>::unit_name().eq("inverse newtons"));
-		assert!(InverseForce::<f64>::unit_symbol().eq("1/N"));
-		assert!(InverseMomentOfInertia::<f64>::unit_name().eq("inverse kilogram meters squared"));
-		assert!(InverseMomentOfInertia::<f64>::unit_symbol().eq("1/kg·m²"));
-		assert!(InverseMomentum::<f64>::unit_name().eq("seconds per kilogram meter"));
-		assert!(InverseMomentum::<f64>::unit_symbol().eq("s/kg·m"));
-		assert!(InversePower::<f64>::unit_name().eq("inverse watts"));
-		assert!(InversePower::<f64>::unit_symbol().eq("1/W"));
-		assert!(InversePressure::<f64>::unit_name().eq("inverse pascals"));
-		assert!(InversePressure::<f64>::unit_symbol().eq("1/Pa"));
-		assert!(InverseTorque::<f64>::unit_name().eq("inverse newton meters"));
-		assert!(InverseTorque::<f64>::unit_symbol().eq("1/Nm"));
-		assert!(TimePerDistance::<f64>::unit_name().eq("seconds per meter"));
-		assert!(TimePerDistance::<f64>::unit_symbol().eq("s/m"));
-		assert!(VolumePerMass::<f64>::unit_name().eq("cubic meters per kilogram"));
-		assert!(VolumePerMass::<f64>::unit_symbol().eq("m³/kg"));
-		assert!(InverseAbsorbedDose::<f64>::unit_name().eq("inverse grays"));
-		assert!(InverseAbsorbedDose::<f64>::unit_symbol().eq("1/Gy"));
-		assert!(InverseDoseEquivalent::<f64>::unit_name().eq("inverse sieverts"));
-		assert!(InverseDoseEquivalent::<f64>::unit_symbol().eq("1/Sv"));
+	fn ordering_test() {
+		use std::vec::Vec;
+		assert!(Distance::from_m(1.0) < Distance::from_m(2.0));
+		assert!(Pressure::from_Pa(-1.0) <= Pressure::from_Pa(-1.0));
+		assert!(Time::from_s(f64::NAN).partial_cmp(&Time::from_s(1.0)).is_none());
+		let mut distances = vec![Distance::from_m(3.0), Distance::from_m(-1.0), Distance::from_m(2.5)];
+		distances.sort_by(|a, b| a.partial_cmp(b).unwrap());
+		assert_eq!(distances, vec![Distance::from_m(-1.0), Distance::from_m(2.5), Distance::from_m(3.0)]);
+		// integer units are totally ordered
+		let mut ticks: Vec<Time<i64>> = vec![Time::from_s(30), Time::from_s(10), Time::from_s(20)];
+		ticks.sort();
+		assert_eq!(ticks, vec![Time::from_s(10), Time::from_s(20), Time::from_s(30)]);
+		assert_eq!(ticks.iter().max(), Some(&Time::from_s(30)));
+		assert_eq!(Mass::from_kg(5).max(Mass::from_kg(7)), Mass::from_kg(7));
 	}
 
+	/// Unit test
 	#[test]
-	fn unit_print_display_test() {
-		println!("{}", Amount{mol: 1});
-		println!("{}", Current{A: 1});
-		println!("{}", Distance{m: 1});
-		println!("{}", Luminosity{cd: 1});
-		println!("{}", Mass{kg: 1});
-		println!("{}", Temperature{K: 1});
-		println!("{}", Time{s: 1});
-		println!("{}", CatalyticActivity{molps: 1});
-		println!("{}", Concentration{molpm3: 1});
-		println!("{}", Molality{molpkg: 1});
-		println!("{}", MolarMass{kgpmol: 1});
-		println!("{}", SpecificHeatCapacity{J_per_kgK: 1});
-		println!("{}", Capacitance{F: 1});
-		println!("{}", Charge{C: 1});
-		println!("{}", Conductance{S: 1});
-		println!("{}", Illuminance{lux: 1});
-		println!("{}", Inductance{H: 1});
-		println!("{}", LuminousFlux{lm: 1});
-		println!("{}", MagneticFlux{Wb: 1});
-		println!("{}", MagneticFluxDensity{T: 1});
-		println!("{}", Resistance{Ohm: 1});
-		println!("{}", Voltage{V: 1});
-		println!("{}", Angle{rad: 1});
-		println!("{}", Area{m2: 1});
-		println!("{}", SolidAngle{sr: 1});
-		println!("{}", Volume{m3: 1});
-		println!("{}", Acceleration{mps2: 1});
-		println!("{}", AngularAcceleration{radps2: 1});
-		println!("{}", AngularMomentum{kgm2radps: 1});
-		println!("{}", AngularVelocity{radps: 1});
-		println!("{}", AreaDensity{kgpm2: 1});
-		println!("{}", Density{kgpm3: 1});
-		println!("{}", Energy{J: 1});
-		println!("{}", Force{N: 1});
-		println!("{}", Frequency{Hz: 1});
-		println!("{}", MomentOfInertia{kgm2: 1});
-		println!("{}", Momentum{kgmps: 1});
-		println!("{}", Power{W: 1});
-		println!("{}", Pressure{Pa: 1});
-		println!("{}", Torque{Nm: 1});
-		println!("{}", Velocity{mps: 1});
-		println!("{}", AbsorbedDose{Gy: 1});
-		println!("{}", DoseEquivalent{Sv: 1});
-		println!("{}", Radioactivity{Bq: 1});
-		println!("{}", InverseAmount{per_mol: 1});
-		println!("{}", InverseCurrent{per_A: 1});
-		println!("{}", InverseDistance{per_m: 1});
-		println!("{}", InverseLuminosity{per_cd: 1});
-		println!("{}", InverseMass{per_kg: 1});
-		println!("{}", InverseTemperature{per_K: 1});
-		println!("{}", InverseCatalyticActivity{s_per_mol: 1});
-		println!("{}", InverseSpecificHeatCapacity{kgK_per_J: 1});
-		println!("{}", MolarVolume{m3_per_mol: 1});
-		println!("{}", AreaPerLumen{m2_per_lm: 1});
-		println!("{}", Elastance{per_F: 1});
-		println!("{}", InverseCharge{per_C: 1});
-		println!("{}", InverseInductance{per_H: 1});
-		println!("{}", InverseLuminousFlux{per_lm: 1});
-		println!("{}", InverseMagneticFlux{per_Wb: 1});
-		println!("{}", InverseMagneticFluxDensity{m2_per_Wb: 1});
-		println!("{}", InverseVoltage{per_V: 1});
-		println!("{}", InverseAngle{per_rad: 1});
-		println!("{}", InverseArea{per_m2: 1});
-		println!("{}", InverseSolidAngle{per_sr: 1});
-		println!("{}", InverseVolume{per_m3: 1});
-		println!("{}", AreaPerMass{m2_per_kg: 1});
-		println!("{}", InverseAcceleration{s2pm: 1});
-		println!("{}", InverseAngularAcceleration{s2prad: 1});
-		println!("{}", InverseAngularMomentum{s_per_kgm2rad: 1});
-		println!("{}", InverseAngularVelocity{s_per_rad: 1});
-		println!("{}", InverseEnergy{per_J: 1});
-		println!("{}", InverseForce{per_N: 1});
-		println!("{}", InverseMomentOfInertia{per_kgm2: 1});
-		println!("{}", InverseMomentum{s_per_kgm: 1});
-		println!("{}", InversePower{per_W: 1});
-		println!("{}", InversePressure{per_Pa: 1});
-		println!("{}", InverseTorque{per_Nm: 1});
-		println!("{}", TimePerDistance{spm: 1});
-		println!("{}", VolumePerMass{m3_per_kg: 1});
-		println!("{}", InverseAbsorbedDose{per_Gy: 1});
-		println!("{}", InverseDoseEquivalent{per_Sv: 1});
+	fn hash_test() {
+		use std::collections::{HashMap, HashSet};
+		let mut set: HashSet<Distance<i32>> = HashSet::new();
+		assert!(set.insert(Distance::from_m(1)));
+		assert!(set.insert(Distance::from_m(2)));
+		assert!(!set.insert(Distance::from_m(1)));
+		assert_eq!(set.len(), 2);
+		assert!(set.contains(&Distance::from_m(2)));
+		assert!(!set.contains(&Distance::from_m(3)));
+		let mut events: HashMap<Time<i64>, &str> = HashMap::new();
+		events.insert(Time::from_s(10), "start");
+		events.insert(Time::from_s(20), "stop");
+		assert_eq!(events.get(&Time::from_s(20)), Some(&"stop"));
 	}
 
+	/// Unit test
 	#[test]
-	fn test_unit_converions() {
-		let x = 4.5f64;
-		let y = 2.5f64;
-		assert_eq!(div_check(&Amount{mol: x}, &Time{s: y}), CatalyticActivity{molps: x/y});
-		assert_eq!(div_check(&Amount{mol: x}, &CatalyticActivity{molps: y}), Time{s: x/y});
-		assert_eq!(div_check(&Amount{mol: x}, &Concentration{molpm3: y}), Volume{m3: x/y});
-		assert_eq!(div_check(&Amount{mol: x}, &Volume{m3: y}), Concentration{molpm3: x/y});
-		assert_eq!(mul_check(&Amount{mol: x}, &Frequency{Hz: y}), CatalyticActivity{molps: x*y});
-		assert_eq!(mul_check(&Current{A: x}, &Time{s: y}), Charge{C: x*y});
-		assert_eq!(div_check(&Current{A: x}, &Charge{C: y}), Frequency{Hz: x/y});
-		assert_eq!(div_check(&Current{A: x}, &Conductance{S: y}), Voltage{V: x/y});
-		assert_eq!(mul_check(&Current{A: x}, &Inductance{H: y}), MagneticFlux{Wb: x*y});
-		assert_eq!(mul_check(&Current{A: x}, &MagneticFlux{Wb: y}), Energy{J: x*y});
-		assert_eq!(mul_check(&Current{A: x}, &Resistance{Ohm: y}), Voltage{V: x*y});
-		assert_eq!(mul_check(&Current{A: x}, &Voltage{V: y}), Power{W: x*y});
-		assert_eq!(div_check(&Current{A: x}, &Voltage{V: y}), Conductance{S: x/y});
-		assert_eq!(div_check(&Current{A: x}, &Frequency{Hz: y}), Charge{C: x/y});
-		assert_eq!(mul_check(&Distance{m: x}, &Distance{m: y}), Area{m2: x*y});
-		assert_eq!(div_check(&Distance{m: x}, &Time{s: y}), Velocity{mps: x/y});
-		assert_eq!(mul_check(&Distance{m: x}, &Area{m2: y}), Volume{m3: x*y});
-		assert_eq!(mul_check(&Distance{m: x}, &Density{kgpm3: y}), AreaDensity{kgpm2: x*y});
-		assert_eq!(mul_check(&Distance{m: x}, &Force{N: y}), Energy{J: x*y});
-		assert_eq!(mul_check(&Distance{m: x}, &Frequency{Hz: y}), Velocity{mps: x*y});
-		assert_eq!(div_check(&Distance{m: x}, &Velocity{mps: y}), Time{s: x/y});
-		assert_eq!(mul_check(&Luminosity{cd: x}, &SolidAngle{sr: y}), LuminousFlux{lm: x*y});
-		assert_eq!(div_check(&Mass{kg: x}, &Area{m2: y}), AreaDensity{kgpm2: x/y});
-		assert_eq!(div_check(&Mass{kg: x}, &Volume{m3: y}), Density{kgpm3: x/y});
-		assert_eq!(mul_check(&Mass{kg: x}, &Acceleration{mps2: y}), Force{N: x*y});
-		assert_eq!(div_check(&Mass{kg: x}, &AreaDensity{kgpm2: y}), Area{m2: x/y});
-		assert_eq!(div_check(&Mass{kg: x}, &Density{kgpm3: y}), Volume{m3: x/y});
-		assert_eq!(mul_check(&Mass{kg: x}, &Velocity{mps: y}), Momentum{kgmps: x*y});
-		assert_eq!(mul_check(&Mass{kg: x}, &AbsorbedDose{Gy: y}), Energy{J: x*y});
-		assert_eq!(mul_check(&Mass{kg: x}, &DoseEquivalent{Sv: y}), Energy{J: x*y});
-		assert_eq!(mul_check(&Time{s: x}, &Current{A: y}), Charge{C: x*y});
-		assert_eq!(mul_check(&Time{s: x}, &CatalyticActivity{molps: y}), Amount{mol: x*y});
-		assert_eq!(div_check(&Time{s: x}, &Capacitance{F: y}), Resistance{Ohm: x/y});
-		assert_eq!(mul_check(&Time{s: x}, &Conductance{S: y}), Capacitance{F: x*y});
-		assert_eq!(div_check(&Time{s: x}, &Conductance{S: y}), Inductance{H: x/y});
-		assert_eq!(div_check(&Time{s: x}, &Inductance{H: y}), Conductance{S: x/y});
-		assert_eq!(mul_check(&Time{s: x}, &Resistance{Ohm: y}), Inductance{H: x*y});
-		assert_eq!(div_check(&Time{s: x}, &Resistance{Ohm: y}), Capacitance{F: x/y});
-		assert_eq!(mul_check(&Time{s: x}, &Voltage{V: y}), MagneticFlux{Wb: x*y});
-		assert_eq!(mul_check(&Time{s: x}, &Acceleration{mps2: y}), Velocity{mps: x*y});
-		assert_eq!(mul_check(&Time{s: x}, &AngularAcceleration{radps2: y}), AngularVelocity{radps: x*y});
-		assert_eq!(mul_check(&Time{s: x}, &AngularVelocity{radps: y}), Angle{rad: x*y});
-		assert_eq!(mul_check(&Time{s: x}, &Force{N: y}), Momentum{kgmps: x*y});
-		assert_eq!(mul_check(&Time{s: x}, &Power{W: y}), Energy{J: x*y});
-		assert_eq!(mul_check(&Time{s: x}, &Velocity{mps: y}), Distance{m: x*y});
-		assert_eq!(div_check(&CatalyticActivity{molps: x}, &Amount{mol: y}), Frequency{Hz: x/y});
-		assert_eq!(mul_check(&CatalyticActivity{molps: x}, &Time{s: y}), Amount{mol: x*y});
-		assert_eq!(div_check(&CatalyticActivity{molps: x}, &Frequency{Hz: y}), Amount{mol: x/y});
-		assert_eq!(mul_check(&Concentration{molpm3: x}, &Volume{m3: y}), Amount{mol: x*y});
-		assert_eq!(div_check(&Capacitance{F: x}, &Time{s: y}), Conductance{S: x/y});
-		assert_eq!(div_check(&Capacitance{F: x}, &Conductance{S: y}), Time{s: x/y});
-		assert_eq!(mul_check(&Capacitance{F: x}, &Resistance{Ohm: y}), Time{s: x*y});
-		assert_eq!(mul_check(&Capacitance{F: x}, &Voltage{V: y}), Charge{C: x*y});
-		assert_eq!(mul_check(&Capacitance{F: x}, &Frequency{Hz: y}), Conductance{S: x*y});
-		assert_eq!(div_check(&Charge{C: x}, &Current{A: y}), Time{s: x/y});
-		assert_eq!(div_check(&Charge{C: x}, &Time{s: y}), Current{A: x/y});
-		assert_eq!(div_check(&Charge{C: x}, &Capacitance{F: y}), Voltage{V: x/y});
-		assert_eq!(div_check(&Charge{C: x}, &Conductance{S: y}), MagneticFlux{Wb: x/y});
-		assert_eq!(div_check(&Charge{C: x}, &MagneticFlux{Wb: y}), Conductance{S: x/y});
-		assert_eq!(mul_check(&Charge{C: x}, &Resistance{Ohm: y}), MagneticFlux{Wb: x*y});
-		assert_eq!(mul_check(&Charge{C: x}, &Voltage{V: y}), Energy{J: x*y});
-		assert_eq!(div_check(&Charge{C: x}, &Voltage{V: y}), Capacitance{F: x/y});
-		assert_eq!(mul_check(&Charge{C: x}, &Frequency{Hz: y}), Current{A: x*y});
-		assert_eq!(mul_check(&Conductance{S: x}, &Time{s: y}), Capacitance{F: x*y});
-		assert_eq!(div_check(&Conductance{S: x}, &Capacitance{F: y}), Frequency{Hz: x/y});
-		assert_eq!(mul_check(&Conductance{S: x}, &Inductance{H: y}), Time{s: x*y});
-		assert_eq!(mul_check(&Conductance{S: x}, &MagneticFlux{Wb: y}), Charge{C: x*y});
-		assert_eq!(mul_check(&Conductance{S: x}, &Voltage{V: y}), Current{A: x*y});
-		assert_eq!(div_check(&Conductance{S: x}, &Frequency{Hz: y}), Capacitance{F: x/y});
-		assert_eq!(mul_check(&Illuminance{lux: x}, &Area{m2: y}), LuminousFlux{lm: x*y});
-		assert_eq!(mul_check(&Inductance{H: x}, &Current{A: y}), MagneticFlux{Wb: x*y});
-		assert_eq!(div_check(&Inductance{H: x}, &Time{s: y}), Resistance{Ohm: x/y});
-		assert_eq!(mul_check(&Inductance{H: x}, &Conductance{S: y}), Time{s: x*y});
-		assert_eq!(div_check(&Inductance{H: x}, &Resistance{Ohm: y}), Time{s: x/y});
-		assert_eq!(mul_check(&Inductance{H: x}, &Frequency{Hz: y}), Resistance{Ohm: x*y});
-		assert_eq!(div_check(&LuminousFlux{lm: x}, &Luminosity{cd: y}), SolidAngle{sr: x/y});
-		assert_eq!(div_check(&LuminousFlux{lm: x}, &Illuminance{lux: y}), Area{m2: x/y});
-		assert_eq!(div_check(&LuminousFlux{lm: x}, &Area{m2: y}), Illuminance{lux: x/y});
-		assert_eq!(div_check(&LuminousFlux{lm: x}, &SolidAngle{sr: y}), Luminosity{cd: x/y});
-		assert_eq!(mul_check(&MagneticFlux{Wb: x}, &Current{A: y}), Energy{J: x*y});
-		assert_eq!(div_check(&MagneticFlux{Wb: x}, &Current{A: y}), Inductance{H: x/y});
-		assert_eq!(div_check(&MagneticFlux{Wb: x}, &Time{s: y}), Voltage{V: x/y});
-		assert_eq!(div_check(&MagneticFlux{Wb: x}, &Charge{C: y}), Resistance{Ohm: x/y});
-		assert_eq!(mul_check(&MagneticFlux{Wb: x}, &Conductance{S: y}), Charge{C: x*y});
-		assert_eq!(div_check(&MagneticFlux{Wb: x}, &Inductance{H: y}), Current{A: x/y});
-		assert_eq!(div_check(&MagneticFlux{Wb: x}, &MagneticFluxDensity{T: y}), Area{m2: x/y});
-		assert_eq!(div_check(&MagneticFlux{Wb: x}, &Resistance{Ohm: y}), Charge{C: x/y});
-		assert_eq!(div_check(&MagneticFlux{Wb: x}, &Voltage{V: y}), Time{s: x/y});
-		assert_eq!(div_check(&MagneticFlux{Wb: x}, &Area{m2: y}), MagneticFluxDensity{T: x/y});
-		assert_eq!(mul_check(&MagneticFlux{Wb: x}, &Frequency{Hz: y}), Voltage{V: x*y});
-		assert_eq!(mul_check(&MagneticFluxDensity{T: x}, &Area{m2: y}), MagneticFlux{Wb: x*y});
-		assert_eq!(mul_check(&Resistance{Ohm: x}, &Current{A: y}), Voltage{V: x*y});
-		assert_eq!(mul_check(&Resistance{Ohm: x}, &Time{s: y}), Inductance{H: x*y});
-		assert_eq!(mul_check(&Resistance{Ohm: x}, &Capacitance{F: y}), Time{s: x*y});
-		assert_eq!(mul_check(&Resistance{Ohm: x}, &Charge{C: y}), MagneticFlux{Wb: x*y});
-		assert_eq!(div_check(&Resistance{Ohm: x}, &Inductance{H: y}), Frequency{Hz: x/y});
-		assert_eq!(div_check(&Resistance{Ohm: x}, &Frequency{Hz: y}), Inductance{H: x/y});
-		assert_eq!(mul_check(&Voltage{V: x}, &Current{A: y}), Power{W: x*y});
-		assert_eq!(div_check(&Voltage{V: x}, &Current{A: y}), Resistance{Ohm: x/y});
-		assert_eq!(mul_check(&Voltage{V: x}, &Time{s: y}), MagneticFlux{Wb: x*y});
-		assert_eq!(mul_check(&Voltage{V: x}, &Capacitance{F: y}), Charge{C: x*y});
-		assert_eq!(mul_check(&Voltage{V: x}, &Charge{C: y}), Energy{J: x*y});
-		assert_eq!(mul_check(&Voltage{V: x}, &Conductance{S: y}), Current{A: x*y});
-		assert_eq!(div_check(&Voltage{V: x}, &MagneticFlux{Wb: y}), Frequency{Hz: x/y});
-		assert_eq!(div_check(&Voltage{V: x}, &Resistance{Ohm: y}), Current{A: x/y});
-		assert_eq!(div_check(&Voltage{V: x}, &Frequency{Hz: y}), MagneticFlux{Wb: x/y});
-		assert_eq!(div_check(&Angle{rad: x}, &Time{s: y}), AngularVelocity{radps: x/y});
-		assert_eq!(div_check(&Angle{rad: x}, &AngularVelocity{radps: y}), Time{s: x/y});
-		assert_eq!(mul_check(&Angle{rad: x}, &Frequency{Hz: y}), AngularVelocity{radps: x*y});
-		assert_eq!(mul_check(&Area{m2: x}, &Distance{m: y}), Volume{m3: x*y});
-		assert_eq!(div_check(&Area{m2: x}, &Distance{m: y}), Distance{m: x/y});
-		assert_eq!(mul_check(&Area{m2: x}, &Illuminance{lux: y}), LuminousFlux{lm: x*y});
-		assert_eq!(mul_check(&Area{m2: x}, &MagneticFluxDensity{T: y}), MagneticFlux{Wb: x*y});
-		assert_eq!(mul_check(&Area{m2: x}, &AreaDensity{kgpm2: y}), Mass{kg: x*y});
-		assert_eq!(mul_check(&Area{m2: x}, &Pressure{Pa: y}), Force{N: x*y});
-		assert_eq!(mul_check(&SolidAngle{sr: x}, &Luminosity{cd: y}), LuminousFlux{lm: x*y});
-		assert_eq!(div_check(&Volume{m3: x}, &Distance{m: y}), Area{m2: x/y});
-		assert_eq!(mul_check(&Volume{m3: x}, &Concentration{molpm3: y}), Amount{mol: x*y});
-		assert_eq!(div_check(&Volume{m3: x}, &Area{m2: y}), Distance{m: x/y});
-		assert_eq!(mul_check(&Volume{m3: x}, &Density{kgpm3: y}), Mass{kg: x*y});
-		assert_eq!(mul_check(&Volume{m3: x}, &Pressure{Pa: y}), Energy{J: x*y});
-		assert_eq!(mul_check(&Acceleration{mps2: x}, &Mass{kg: y}), Force{N: x*y});
-		assert_eq!(mul_check(&Acceleration{mps2: x}, &Time{s: y}), Velocity{mps: x*y});
-		assert_eq!(mul_check(&Acceleration{mps2: x}, &AreaDensity{kgpm2: y}), Pressure{Pa: x*y});
-		assert_eq!(div_check(&Acceleration{mps2: x}, &Frequency{Hz: y}), Velocity{mps: x/y});
-		assert_eq!(mul_check(&Acceleration{mps2: x}, &Momentum{kgmps: y}), Power{W: x*y});
-		assert_eq!(div_check(&Acceleration{mps2: x}, &Velocity{mps: y}), Frequency{Hz: x/y});
-		assert_eq!(mul_check(&AngularAcceleration{radps2: x}, &Time{s: y}), AngularVelocity{radps: x*y});
-		assert_eq!(div_check(&AngularAcceleration{radps2: x}, &AngularVelocity{radps: y}), Frequency{Hz: x/y});
-		assert_eq!(div_check(&AngularAcceleration{radps2: x}, &Frequency{Hz: y}), AngularVelocity{radps: x/y});
-		assert_eq!(div_check(&AngularMomentum{kgm2radps: x}, &MomentOfInertia{kgm2: y}), AngularVelocity{radps: x/y});
-		assert_eq!(mul_check(&AngularVelocity{radps: x}, &Time{s: y}), Angle{rad: x*y});
-		assert_eq!(div_check(&AngularVelocity{radps: x}, &Time{s: y}), AngularAcceleration{radps2: x/y});
-		assert_eq!(div_check(&AngularVelocity{radps: x}, &Angle{rad: y}), Frequency{Hz: x/y});
-		assert_eq!(div_check(&AngularVelocity{radps: x}, &AngularAcceleration{radps2: y}), Time{s: x/y});
-		assert_eq!(mul_check(&AngularVelocity{radps: x}, &Frequency{Hz: y}), AngularAcceleration{radps2: x*y});
-		assert_eq!(div_check(&AngularVelocity{radps: x}, &Frequency{Hz: y}), Angle{rad: x/y});
-		assert_eq!(mul_check(&AngularVelocity{radps: x}, &MomentOfInertia{kgm2: y}), AngularMomentum{kgm2radps: x*y});
-		assert_eq!(div_check(&AreaDensity{kgpm2: x}, &Distance{m: y}), Density{kgpm3: x/y});
-		assert_eq!(mul_check(&AreaDensity{kgpm2: x}, &Area{m2: y}), Mass{kg: x*y});
-		assert_eq!(mul_check(&AreaDensity{kgpm2: x}, &Acceleration{mps2: y}), Pressure{Pa: x*y});
-		assert_eq!(div_check(&AreaDensity{kgpm2: x}, &Density{kgpm3: y}), Distance{m: x/y});
-		assert_eq!(mul_check(&Density{kgpm3: x}, &Distance{m: y}), AreaDensity{kgpm2: x*y});
-		assert_eq!(mul_check(&Density{kgpm3: x}, &Volume{m3: y}), Mass{kg: x*y});
-		assert_eq!(div_check(&Energy{J: x}, &Current{A: y}), MagneticFlux{Wb: x/y});
-		assert_eq!(div_check(&Energy{J: x}, &Distance{m: y}), Force{N: x/y});
-		assert_eq!(div_check(&Energy{J: x}, &Time{s: y}), Power{W: x/y});
-		assert_eq!(div_check(&Energy{J: x}, &Charge{C: y}), Voltage{V: x/y});
-		assert_eq!(div_check(&Energy{J: x}, &MagneticFlux{Wb: y}), Current{A: x/y});
-		assert_eq!(div_check(&Energy{J: x}, &Voltage{V: y}), Charge{C: x/y});
-		assert_eq!(div_check(&Energy{J: x}, &Volume{m3: y}), Pressure{Pa: x/y});
-		assert_eq!(div_check(&Energy{J: x}, &Force{N: y}), Distance{m: x/y});
-		assert_eq!(mul_check(&Energy{J: x}, &Frequency{Hz: y}), Power{W: x*y});
-		assert_eq!(div_check(&Energy{J: x}, &Momentum{kgmps: y}), Velocity{mps: x/y});
-		assert_eq!(div_check(&Energy{J: x}, &Power{W: y}), Time{s: x/y});
-		assert_eq!(div_check(&Energy{J: x}, &Pressure{Pa: y}), Volume{m3: x/y});
-		assert_eq!(div_check(&Energy{J: x}, &Velocity{mps: y}), Momentum{kgmps: x/y});
-		assert_eq!(div_check(&Energy{J: x}, &AbsorbedDose{Gy: y}), Mass{kg: x/y});
-		assert_eq!(div_check(&Energy{J: x}, &DoseEquivalent{Sv: y}), Mass{kg: x/y});
-		assert_eq!(mul_check(&Force{N: x}, &Distance{m: y}), Energy{J: x*y});
-		assert_eq!(div_check(&Force{N: x}, &Mass{kg: y}), Acceleration{mps2: x/y});
-		assert_eq!(mul_check(&Force{N: x}, &Time{s: y}), Momentum{kgmps: x*y});
-		assert_eq!(div_check(&Force{N: x}, &Area{m2: y}), Pressure{Pa: x/y});
-		assert_eq!(div_check(&Force{N: x}, &Acceleration{mps2: y}), Mass{kg: x/y});
-		assert_eq!(div_check(&Force{N: x}, &Frequency{Hz: y}), Momentum{kgmps: x/y});
-		assert_eq!(div_check(&Force{N: x}, &Momentum{kgmps: y}), Frequency{Hz: x/y});
-		assert_eq!(div_check(&Force{N: x}, &Pressure{Pa: y}), Area{m2: x/y});
-		assert_eq!(mul_check(&Force{N: x}, &Velocity{mps: y}), Power{W: x*y});
-		assert_eq!(mul_check(&Frequency{Hz: x}, &Amount{mol: y}), CatalyticActivity{molps: x*y});
-		assert_eq!(mul_check(&Frequency{Hz: x}, &Distance{m: y}), Velocity{mps: x*y});
-		assert_eq!(mul_check(&Frequency{Hz: x}, &Capacitance{F: y}), Conductance{S: x*y});
-		assert_eq!(mul_check(&Frequency{Hz: x}, &Charge{C: y}), Current{A: x*y});
-		assert_eq!(mul_check(&Frequency{Hz: x}, &Inductance{H: y}), Resistance{Ohm: x*y});
-		assert_eq!(mul_check(&Frequency{Hz: x}, &MagneticFlux{Wb: y}), Voltage{V: x*y});
-		assert_eq!(mul_check(&Frequency{Hz: x}, &Angle{rad: y}), AngularVelocity{radps: x*y});
-		assert_eq!(mul_check(&Frequency{Hz: x}, &AngularVelocity{radps: y}), AngularAcceleration{radps2: x*y});
-		assert_eq!(mul_check(&Frequency{Hz: x}, &Energy{J: y}), Power{W: x*y});
-		assert_eq!(mul_check(&Frequency{Hz: x}, &Torque{Nm: y}), Power{W: x*y});
-		assert_eq!(mul_check(&Frequency{Hz: x}, &Momentum{kgmps: y}), Force{N: x*y});
-		assert_eq!(mul_check(&Frequency{Hz: x}, &Velocity{mps: y}), Acceleration{mps2: x*y});
-		assert_eq!(div_check(&MomentOfInertia{kgm2: x}, &Mass{kg: y}), Area{m2: x/y});
-		assert_eq!(div_check(&MomentOfInertia{kgm2: x}, &Area{m2: y}), Mass{kg: x/y});
-		assert_eq!(mul_check(&MomentOfInertia{kgm2: x}, &AngularVelocity{radps: y}), AngularMomentum{kgm2radps: x*y});
-		assert_eq!(div_check(&Momentum{kgmps: x}, &Mass{kg: y}), Velocity{mps: x/y});
-		assert_eq!(div_check(&Momentum{kgmps: x}, &Time{s: y}), Force{N: x/y});
-		assert_eq!(mul_check(&Momentum{kgmps: x}, &Acceleration{mps2: y}), Power{W: x*y});
-		assert_eq!(div_check(&Momentum{kgmps: x}, &Force{N: y}), Time{s: x/y});
-		assert_eq!(mul_check(&Momentum{kgmps: x}, &Frequency{Hz: y}), Force{N: x*y});
-		assert_eq!(mul_check(&Momentum{kgmps: x}, &Velocity{mps: y}), Energy{J: x*y});
-		assert_eq!(div_check(&Momentum{kgmps: x}, &Velocity{mps: y}), Mass{kg: x/y});
-		assert_eq!(div_check(&Power{W: x}, &Current{A: y}), Voltage{V: x/y});
-		assert_eq!(mul_check(&Power{W: x}, &Time{s: y}), Energy{J: x*y});
-		assert_eq!(div_check(&Power{W: x}, &Voltage{V: y}), Current{A: x/y});
-		assert_eq!(div_check(&Power{W: x}, &Acceleration{mps2: y}), Momentum{kgmps: x/y});
-		assert_eq!(div_check(&Power{W: x}, &Energy{J: y}), Frequency{Hz: x/y});
-		assert_eq!(div_check(&Power{W: x}, &Torque{Nm: y}), Frequency{Hz: x/y});
-		assert_eq!(div_check(&Power{W: x}, &Force{N: y}), Velocity{mps: x/y});
-		assert_eq!(div_check(&Power{W: x}, &Frequency{Hz: y}), Energy{J: x/y});
-		assert_eq!(div_check(&Power{W: x}, &Momentum{kgmps: y}), Acceleration{mps2: x/y});
-		assert_eq!(div_check(&Power{W: x}, &Velocity{mps: y}), Force{N: x/y});
-		assert_eq!(mul_check(&Pressure{Pa: x}, &Area{m2: y}), Force{N: x*y});
-		assert_eq!(mul_check(&Pressure{Pa: x}, &Volume{m3: y}), Energy{J: x*y});
-		assert_eq!(div_check(&Pressure{Pa: x}, &Acceleration{mps2: y}), AreaDensity{kgpm2: x/y});
-		assert_eq!(div_check(&Pressure{Pa: x}, &AreaDensity{kgpm2: y}), Acceleration{mps2: x/y});
-		assert_eq!(div_check(&Torque{Nm: x}, &Current{A: y}), MagneticFlux{Wb: x/y});
-		assert_eq!(div_check(&Torque{Nm: x}, &Distance{m: y}), Force{N: x/y});
-		assert_eq!(div_check(&Torque{Nm: x}, &Time{s: y}), Power{W: x/y});
-		assert_eq!(div_check(&Torque{Nm: x}, &Charge{C: y}), Voltage{V: x/y});
-		assert_eq!(div_check(&Torque{Nm: x}, &MagneticFlux{Wb: y}), Current{A: x/y});
-		assert_eq!(div_check(&Torque{Nm: x}, &Voltage{V: y}), Charge{C: x/y});
-		assert_eq!(div_check(&Torque{Nm: x}, &Volume{m3: y}), Pressure{Pa: x/y});
-		assert_eq!(div_check(&Torque{Nm: x}, &Force{N: y}), Distance{m: x/y});
-		assert_eq!(mul_check(&Torque{Nm: x}, &Frequency{Hz: y}), Power{W: x*y});
-		assert_eq!(div_check(&Torque{Nm: x}, &Momentum{kgmps: y}), Velocity{mps: x/y});
-		assert_eq!(div_check(&Torque{Nm: x}, &Power{W: y}), Time{s: x/y});
+	#[cfg(feature="num-traits")]
+	fn zero_test() {
+		let mut total: Distance<f64> = Distance::zero();
+		assert!(total.is_zero());
+		assert_eq!(total, Distance::from_m(0.0));
+		for d in [1.0, 2.5, -0.5] {
+			total += Distance::from_m(d);
+		}
+		assert_eq!(total, Distance::from_m(3.0));
+		assert!(!total.is_zero());
+		assert!(!Force::from_N(1e-300).is_zero());
+		assert!(Force::<i32>::zero().is_zero());
+		assert!(!Force::from_N(-1).is_zero());
+		assert!(Energy::<num_complex::Complex64>::zero().is_zero());
+	}
+
+	/// Unit test
+	#[test]
+	fn sum_test() {
+		use std::vec::Vec;
+		let energies = vec![Energy::from_J(1.5), Energy::from_J(2.0), Energy::from_J(-0.5)];
+		assert_eq!(energies.iter().sum::<Energy<f64>>(), Energy::from_J(3.0));
+		assert_eq!(energies.iter().cloned().sum::<Energy<f64>>(), Energy::from_J(3.0));
+		let nothing: Vec<Energy<f64>> = Vec::new();
+		assert_eq!(nothing.into_iter().sum::<Energy<f64>>(), Energy::from_J(0.0));
+		let ticks = [Time::from_s(3), Time::from_s(4)];
+		assert_eq!(ticks.iter().copied().sum::<Time<i64>>(), Time::from_s(7));
+		assert_eq!(core::iter::empty::<&Time<i32>>().sum::<Time<i32>>(), Time::from_s(0));
+	}
+
+	/// Unit test
+	#[test]
+	fn min_max_clamp_test() {
+		let slow = Velocity::from_mps(1.0);
+		let fast = Velocity::from_mps(10.0);
+		assert_eq!(slow.min(fast), slow);
+		assert_eq!(fast.min(slow), slow);
+		assert_eq!(slow.max(fast), fast);
+		assert_eq!(fast.max(slow), fast);
+		assert_eq!(Velocity::from_mps(5.0).clamp(slow, fast), Velocity::from_mps(5.0));
+		assert_eq!(Velocity::from_mps(-5.0).clamp(slow, fast), slow);
+		assert_eq!(Velocity::from_mps(50.0).clamp(slow, fast), fast);
+		assert_eq!(Distance::from_m(7).clamp(Distance::from_m(0), Distance::from_m(3)), Distance::from_m(3));
+		assert_eq!(Distance::from_m(-7).max(Distance::from_m(-3)), Distance::from_m(-3));
+	}
+
+	/// Unit test
+	#[test]
+	fn const_constructor_test() {
+		const MAX_V: Voltage<f32> = Voltage::from_V_const(3.3);
+		const ARM_LENGTH: Distance<f64> = Distance::from_m_const(0.25);
+		static GRAVITY: Acceleration<f64> = Acceleration::from_mps2_const(9.81);
+		static TIMEOUT: Time<f32> = Time::from_s_const(1.5);
+		assert_eq!(MAX_V, Voltage::from_V(3.3f32));
+		assert_eq!(ARM_LENGTH, Distance::from_m(0.25));
+		assert_eq!(GRAVITY.to_mps2(), 9.81);
+		assert_eq!(TIMEOUT.to_s(), 1.5f32);
+	}
+
+	/// Unit test
+	#[test]
+	fn constants_test() {
+		use super::constants::*;
+		let eV: Energy<f64> = ELEMENTARY_CHARGE * Voltage::from_V(1.0);
+		assert_eq!(eV, ELECTRON_VOLT);
+		assert_approx_equal(eV.to_eV(), 1.0, 9);
+		assert_approx_equal(SPEED_OF_LIGHT.to_mps(), 299792458.0, 12);
+		assert_approx_equal((Time::from_s(1.0) * SPEED_OF_LIGHT).to_m(), 299792458.0, 12);
+		assert_approx_equal(Amount::from_count(AVOGADRO_NUMBER.to_per_mol()).to_mol(), 1.0, 9);
+		assert_approx_equal(AVOGADRO_NUMBER.to_per_mol() * BOLTZMANN_CONSTANT, MOLAR_GAS_CONSTANT, 12);
+		assert_approx_equal(AVOGADRO_NUMBER.to_per_mol() * ELEMENTARY_CHARGE.to_C(), FARADAY_CONSTANT, 12);
+		assert_approx_equal(PLANCK_CONSTANT / (2.0 * core::f64::consts::PI), REDUCED_PLANCK_CONSTANT, 9);
+		assert_approx_equal((STANDARD_GRAVITY * Mass::from_kg(1.0)).to_N(), 9.80665, 12);
+		assert_approx_equal(STANDARD_ATMOSPHERE.to_atm(), 1.0, 12);
+		assert_approx_equal(ATOMIC_MASS_CONSTANT.to_kg() * AVOGADRO_NUMBER.to_per_mol(), 1e-3, 9);
+	}
+
+	/// Unit test
+	#[test]
+	#[should_panic]
+	fn clamp_bad_range_test() {
+		Velocity::from_mps(5.0).clamp(Velocity::from_mps(10.0), Velocity::from_mps(1.0));
+	}
+
+	/// Unit test
+	#[test]
+	fn imperial_distance_test() {
+		assert_eq!(Distance::from_feet(1.0).to_m(), 0.3048);
+		assert_eq!(Distance::from_inches(1.0).to_m(), 0.0254);
+		assert_eq!(Distance::from_yards(1.0).to_m(), 0.9144);
+		assert_eq!(Distance::from_miles(1.0).to_m(), 1609.344);
+		assert_eq!(Distance::from_nautical_miles(1.0).to_m(), 1852.0);
+		assert_approx_equal(Distance::from_miles(1.0).to_feet(), 5280.0, 12);
+		assert_approx_equal(Distance::from_yards(1.0).to_inches(), 36.0, 12);
+		assert_approx_equal(Distance::from_feet(3.0).to_yards(), 1.0, 12);
+		assert_approx_equal(Distance::from_feet(12.5).to_feet(), 12.5, 12);
+		assert_approx_equal(Distance::from_miles(26.2).to_miles(), 26.2, 12);
+		assert_approx_equal(Distance::from_nautical_miles(1.0).to_miles(), 1.150779448, 9);
+	}
+
+	/// Unit test
+	#[test]
+	fn imperial_mass_test() {
+		assert_eq!(Mass::from_pounds(1.0).to_kg(), 0.45359237);
+		assert_eq!(Mass::from_grams(1500.0).to_kg(), 1.5);
+		assert_eq!(Mass::from_tonnes(2.0).to_kg(), 2000.0);
+		assert_approx_equal(Mass::from_kg(1.5).to_grams(), 1500.0, 12);
+		assert_approx_equal(Mass::from_pounds(1.0).to_ounces(), 16.0, 12);
+		assert_approx_equal(Mass::from_ounces(16.0).to_pounds(), 1.0, 12);
+		assert_approx_equal(Mass::from_short_tons(1.0).to_pounds(), 2000.0, 12);
+		assert_approx_equal(Mass::from_pounds(150.0).to_pounds(), 150.0, 12);
+		assert_approx_equal(Mass::from_ounces(3.5).to_ounces(), 3.5, 12);
+	}
+
+	/// Unit test
+	#[test]
+	fn pressure_units_test() {
+		let atm = Pressure::from_atmospheres(1.0);
+		assert_eq!(atm.to_Pa(), 101325.0);
+		assert_eq!(atm, Pressure::from_atm(1.0));
+		assert_approx_equal(atm.to_bar(), 1.01325, 12);
+		assert_approx_equal(atm.to_psi(), 14.6959487755, 11);
+		// 1 torr is exactly 1/760 atm, 1 mmHg is very slightly more than 1 torr
+		assert_approx_equal(atm.to_torr(), 760.0, 12);
+		assert_approx_equal(atm.to_mmHg(), 759.99989173, 10);
+		assert!(Pressure::from_mmHg(1.0) > Pressure::from_torr(1.0));
+		assert_approx_equal(Pressure::from_psi(1.0).to_Pa(), 6894.757293168361, 15);
+		assert_approx_equal(Pressure::from_bar(2.5).to_bar(), 2.5, 12);
+	}
+
+	/// Unit test
+	#[test]
+	fn energy_units_test() {
+		assert_eq!(Energy::from_kilowatt_hours(1.0).to_J(), 3.6e6);
+		assert_eq!(Energy::from_calories(1.0).to_J(), 4.184);
+		assert_eq!(Energy::from_electronvolts(1.0).to_J(), 1.602176634e-19);
+		assert_eq!(Energy::from_btu(1.0).to_J(), 1055.05585262);
+		assert_eq!(Energy::from_btu(1.0), Energy::from_BTU(1.0));
+		assert_approx_equal(Energy::from_J(3.6e6).to_kilowatt_hours(), 1.0, 12);
+		assert_approx_equal(Energy::from_kcal(1.0).to_calories(), 1000.0, 12);
+		assert_approx_equal(Energy::from_J(1.0).to_electronvolts(), 6.241509074e18, 9);
+		assert_approx_equal(Energy::from_btu(2.0).to_btu(), 2.0, 12);
+		assert_approx_equal(Energy::from_electronvolts(1.0).to_eV(), 1.0, 12);
+	}
+
+	/// Unit test
+	#[test]
+	fn pressure_factor_test() {
+		// mmHg and per_atm used 133.3223684211 Pa and 9.87e-6 per Pa in version 1.x
+		assert_eq!(Pressure::from_mmHg(1.0).to_Pa(), 133.322387415);
+		assert_eq!(Pressure::from_torr(1.0).to_Pa(), 133.32236842105263);
+		assert_approx_equal(InversePressure::from_per_mmHg(1.0).to_per_Pa(), 1.0 / 133.322387415, 12);
+		assert_eq!(InversePressure::from_per_atm(1.0).to_per_Pa(), 9.869232667160129e-6);
+		assert_approx_equal(InversePressure::from_per_Pa(1.0).to_per_atm(), 101325.0, 12);
+	}
+
+	/// Unit test
+	#[test]
+	fn btu_factor_test() {
+		// BTU uses the International Table definition (it was 1055 J in version 1.x)
+		assert_eq!(Energy::from_BTU(1.0).to_J(), 1055.05585262);
+		assert_ne!(Energy::from_BTU(1.0).to_J(), 1055.0);
+		assert_approx_equal(Energy::from_J(1055.05585262).to_BTU(), 1.0, 12);
+		assert_approx_equal(InverseEnergy::from_per_BTU(1.0).to_per_J(), 1.0 / 1055.05585262, 12);
+		assert_approx_equal(InverseEnergy::from_per_J(1.0).to_per_BTU(), 1055.05585262, 12);
+	}
+
+	/// Unit test
+	#[test]
+	fn velocity_units_test() {
+		assert_approx_equal(Velocity::from_kph(100.0).to_mps(), 27.777777777777778, 12);
+		assert_approx_equal(Velocity::from_mps(27.777777777777778).to_kph(), 100.0, 12);
+		assert_approx_equal(Velocity::from_knots(1.0).to_mps(), 1852.0/3600.0, 15);
+		assert_approx_equal(Velocity::from_knots(1.0).to_kph(), 1.852, 12);
+		assert_approx_equal(Velocity::from_mph(60.0).to_mps(), 26.8224, 12);
+		assert_approx_equal(Velocity::from_mph(1.0).to_knots(), 0.868976242, 9);
+		assert_approx_equal(Velocity::from_knots(250.0).to_knots(), 250.0, 12);
+		// distance per time
+		assert_approx_equal((Distance::from_nautical_miles(1.0) / Time::from_hr(1.0)).to_knots(), 1.0, 12);
+		assert_approx_equal((Distance::from_miles(1.0) / Time::from_hr(1.0)).to_mph(), 1.0, 12);
+	}
+
+	/// Unit test
+	#[test]
+	fn volume_units_test() {
+		assert_eq!(Volume::from_liters(1.0).to_m3(), 1e-3);
+		assert_eq!(Volume::from_milliliters(1.0).to_m3(), 1e-6);
+		assert_approx_equal(Volume::from_m3(1.0).to_liters(), 1000.0, 12);
+		assert_approx_equal(Volume::from_liters(1.0).to_milliliters(), 1000.0, 12);
+		assert_approx_equal(Volume::from_us_gallons(1.0).to_liters(), 3.785411784, 12);
+		assert_approx_equal(Volume::from_imperial_gallons(1.0).to_liters(), 4.54609, 12);
+		assert_approx_equal(Volume::from_liters(3.785411784).to_us_gallons(), 1.0, 12);
+		assert_approx_equal(Volume::from_liters(4.54609).to_imperial_gallons(), 1.0, 12);
+		assert!(Volume::from_imperial_gallons(1.0) > Volume::from_us_gallons(1.0));
+	}
+
+	/// Unit test
+	#[test]
+	fn time_units_test() {
+		assert_eq!(Time::from_days(1.0).to_s(), 86400.0);
+		assert_eq!(Time::from_hours(2.0).to_s(), 7200.0);
+		assert_eq!(Time::from_minutes(90.0).to_s(), 5400.0);
+		assert_approx_equal(Time::from_minutes(90.0).to_minutes(), 90.0, 12);
+		assert_approx_equal(Time::from_minutes(90.0).to_hours(), 1.5, 12);
+		assert_approx_equal(Time::from_days(1.0).to_hours(), 24.0, 12);
+		assert_approx_equal(Time::from_milliseconds(1.0).to_microseconds(), 1000.0, 12);
+		assert_approx_equal(Time::from_microseconds(1.0).to_nanoseconds(), 1000.0, 12);
+		assert_approx_equal(Time::from_nanoseconds(1.0).to_s(), 1e-9, 12);
+		assert_approx_equal(Time::from_s(0.25).to_milliseconds(), 250.0, 12);
+	}
+
+	/// Unit test
+	#[test]
+	fn duration_conversion_test() {
+		use core::time::Duration;
+		let t: Time<f64> = Duration::from_millis(1500).into();
+		assert_eq!(t, Time::from_s(1.5));
+		let d: Duration = Duration::try_from(t).unwrap();
+		assert_eq!(d, Duration::from_millis(1500));
+		assert_eq!(Duration::try_from(Time::from_s(0.0)), Ok(Duration::ZERO));
+		assert_eq!(Duration::try_from(Time::from_s(-1.0)), Err(super::DurationConversionError));
+		assert_eq!(Duration::try_from(Time::from_s(f64::NAN)), Err(super::DurationConversionError));
+		assert_eq!(Duration::try_from(Time::from_s(f64::INFINITY)), Err(super::DurationConversionError));
+	}
+
+	/// Unit test
+	#[test]
+	fn duration_rate_test() {
+		use core::time::Duration;
+		let v = Velocity::from_mps(12.0);
+		assert_eq!(v.clone() * Duration::from_millis(250), Distance::from_m(3.0));
+		assert_eq!(Distance::from_m(3.0) / Duration::from_millis(250), v);
+		assert_eq!(v.clone() * Duration::ZERO, Distance::from_m(0.0));
+		assert_eq!(Distance::from_m(3.0) / Duration::ZERO, Velocity::from_mps(f64::INFINITY));
+		// nanosecond precision is kept for short durations
+		assert_approx_equal((v.clone() * Duration::from_nanos(1)).to_m(), 12e-9, 12);
+		assert_eq!((Velocity::from_mps(1.0) * Duration::new(1, 1)).to_m(), 1.000000001);
+		// very long durations are rounded to the precision of f64
+		assert_approx_equal((v * Duration::MAX).to_m(), 12.0 * u64::MAX as f64, 12);
+	}
+
+	/// Unit test
+	#[test]
+	fn recip_round_trip_test() {
+		let d = Distance::from_m(8.0);
+		assert_eq!(d.recip().recip(), d);
+		assert_eq!(Time::from_s(0.5).recip().recip(), Time::from_s(0.5));
+		assert_eq!(Distance::from_m(0.0).recip(), InverseDistance::from_per_m(f64::INFINITY));
+		assert_eq!(Distance::from_m(-0.0).recip(), InverseDistance::from_per_m(f64::NEG_INFINITY));
+		assert_eq!(Distance{m: 4_i32}.recip(), InverseDistance{per_m: 0_i32});
+		assert_eq!(Mass{kg: 2.0_f32}.recip(), InverseMass{per_kg: 0.5_f32});
+	}
+
+	/// Unit test
+	#[test]
+	#[cfg(feature="num-rational")]
+	fn rational_exact_arithmetic_test() {
+		use num_rational::Rational64;
+		let third = Rational64::new(1, 3);
+		let d = Distance{m: third} + Distance{m: third} + Distance{m: third};
+		assert_eq!(d, Distance{m: Rational64::from_integer(1)});
+		assert_eq!(mul_check(&Distance{m: third}, &Distance{m: Rational64::new(3, 2)}), Area{m2: Rational64::new(1, 2)});
+		assert_eq!(div_check(&Distance{m: Rational64::new(5, 2)}, &Time{s: Rational64::new(5, 4)}), Velocity{mps: Rational64::from_integer(2)});
+		assert_eq!(Rational64::new(1, 4) * Time{s: Rational64::from_integer(2)}, Time{s: Rational64::new(1, 2)});
+		assert_eq!(Rational64::from_integer(1) / Time{s: Rational64::new(1, 4)}, Frequency{Hz: Rational64::from_integer(4)});
+	}
+
+	/// Unit test
+	#[test]
+	#[cfg(feature="nalgebra")]
+	fn nalgebra_vector_test() {
+		use nalgebra::Vector3;
+		let direction = Vector3::new(0.6, 0.0, 0.8);
+		let speed = Velocity::from_mps(10.0);
+		let v: Vector3<Velocity<f64>> = speed * direction;
+		assert_eq!(v, Vector3::new(Velocity::from_mps(6.0), Velocity::from_mps(0.0), Velocity::from_mps(8.0)));
+		assert_eq!(direction * speed, v);
+		let ratios: Vector3<f64> = v / speed;
+		assert_eq!(ratios, direction);
+		let d: Vector3<Distance<f64>> = Distance::from_m(2.0) * Vector3::new(1.0, 2.0, 3.0);
+		assert_eq!(d.y, Distance::from_m(4.0));
+	}
+
+	/// Unit test
+	#[test]
+	fn approx_eq_test() {
+		let a = Distance::from_m(1.0);
+		let b = Distance::from_m(1.0 + 1e-9);
+		assert!(a.approx_eq(b, 6));
+		assert!(!a.approx_eq(Distance::from_m(1.001), 6));
+		assert!(Distance::from_km(1.0).approx_eq(Distance::from_mm(1e6), 9));
+		assert!(Mass::from_kg(1.0_f32).approx_eq(Mass::from_kg(1.0000001_f32), 5));
+		assert!(Distance::from_m(0.0).approx_eq(Distance::from_m(-0.0), 9));
+		assert!(!Distance::from_m(0.0).approx_eq(Distance::from_m(1e-300), 9));
+		assert!(Distance::from_m(f64::NAN).approx_eq(Distance::from_m(f64::NAN), 9));
+		assert!(!Distance::from_m(f64::NAN).approx_eq(a, 9));
+		assert!(Distance::from_m(f64::INFINITY).approx_eq(Distance::from_m(f64::INFINITY), 9));
+		assert!(!Distance::from_m(f64::INFINITY).approx_eq(Distance::from_m(f64::NEG_INFINITY), 9));
+	}
+
+	/// Unit test
+	#[test]
+	#[cfg(all(feature="num-traits", any(feature="std", feature="libm")))]
+	fn rounding_test() {
+		assert_eq!(Distance::from_m(2.5).round(), Distance::from_m(3.0));
+		assert_eq!(Distance::from_m(-2.5).round(), Distance::from_m(-3.0));
+		assert_eq!(Distance::from_m(2.7).floor(), Distance::from_m(2.0));
+		assert_eq!(Distance::from_m(-2.7).floor(), Distance::from_m(-3.0));
+		assert_eq!(Distance::from_m(2.1).ceil(), Distance::from_m(3.0));
+		assert_eq!(Distance::from_m(-2.7).trunc(), Distance::from_m(-2.0));
+		assert_eq!(Time::from_s(7.3).round_to(Time::from_s(0.5)), Time::from_s(7.5));
+		assert_eq!(Time::from_s(7.2).round_to(Time::from_s(0.5)), Time::from_s(7.0));
+		assert_eq!(Mass::from_kg(1.75_f32).round_to(Mass::from_kg(0.5_f32)), Mass::from_kg(2.0_f32));
+	}
+
+	/// Unit test
+	#[test]
+	#[cfg(all(feature="num-traits", any(feature="std", feature="libm")))]
+	fn float_predicates_test() {
+		let f = Force::from_N(f64::NAN);
+		assert!(f.is_nan());
+		assert!(!f.is_finite());
+		assert!(!f.is_infinite());
+		let v = Velocity::from_mps(f64::NEG_INFINITY);
+		assert!(v.is_infinite());
+		assert!(!v.is_nan());
+		assert!(v.is_sign_negative());
+		assert!(!v.is_sign_positive());
+		let d = Distance::from_m(0.0_f32);
+		assert!(d.is_finite());
+		assert!(d.is_sign_positive());
+		assert!(Distance::from_m(-0.0).is_sign_negative());
+	}
+
+	/// Unit test
+	#[test]
+	fn lerp_test() {
+		let a = Distance::from_m(2.0);
+		let b = Distance::from_m(6.0);
+		assert_eq!(a.lerp(b, 0.0), a);
+		assert_eq!(a.lerp(b, 1.0), b);
+		assert_eq!(a.lerp(b, 0.5), Distance::from_m(4.0));
+		assert_eq!(a.lerp(b, 1.5), Distance::from_m(8.0));
+		assert_eq!(a.lerp(b, -0.5), Distance::from_m(0.0));
+		assert_eq!(a.lerp_clamped(b, 1.5), b);
+		assert_eq!(a.lerp_clamped(b, -0.5), a);
+		assert_eq!(a.lerp_clamped(b, 0.25), Distance::from_m(3.0));
+		assert_eq!(Time::from_s(10.0).lerp(Time::from_s(20.0), 0.5), Time::from_s(15.0));
+	}
+
+	/// Unit test
+	#[test]
+	#[cfg(all(feature="num-traits", any(feature="std", feature="libm")))]
+	fn mul_add_test() {
+		let x = Distance::from_m(1.5);
+		let b = Distance::from_m(0.25);
+		assert_eq!(x.mul_add(2.0, b), x * 2.0 + b);
+		assert_eq!(Time::from_s(3.0_f32).mul_add(0.5, Time::from_s(1.0)), Time::from_s(2.5_f32));
+		// fused multiply-add only rounds once, so it keeps the bits that the naive expression loses
+		let e = f64::EPSILON;
+		let v = Velocity::from_mps(1.0 + e);
+		let fused = v.mul_add(1.0 - e, Velocity::from_mps(-1.0));
+		let naive = v * (1.0 - e) + Velocity::from_mps(-1.0);
+		assert_eq!(naive, Velocity::from_mps(0.0));
+		assert_eq!(fused, Velocity::from_mps(-e * e));
+	}
+
+	/// Unit test
+	#[test]
+	fn remainder_test() {
+		assert_eq!(Time::from_s(7.0) % Time::from_s(3.0), Time::from_s(1.0));
+		assert_eq!(Time::from_s(7.0) % &Time::from_s(3.0), Time::from_s(1.0));
+		assert_eq!(&Time::from_s(-7.0) % Time::from_s(3.0), Time::from_s(-1.0));
+		assert_eq!(&Distance{m: 17_i32} % &Distance{m: 5_i32}, Distance{m: 2_i32});
+		let full_turn = Angle::from_rad(2.0 * core::f64::consts::PI);
+		assert_approx_equal((Angle::from_rad(7.0) % full_turn).to_rad(), 7.0 - 2.0 * core::f64::consts::PI, 12);
+	}
+
+	/// Unit test
+	#[test]
+	#[cfg(all(feature="num-traits", any(feature="std", feature="libm")))]
+	fn hypot_test() {
+		assert_eq!(Distance::hypot(Distance::from_m(3.0), Distance::from_m(4.0)), Distance::from_m(5.0));
+		assert_eq!(Distance::hypot(Distance::from_m(-3.0_f32), Distance::from_m(4.0)), Distance::from_m(5.0_f32));
+		assert_eq!(Distance::hypot3(Distance::from_m(2.0), Distance::from_m(3.0), Distance::from_m(6.0)), Distance::from_m(7.0));
+		// no overflow in the intermediate squares
+		assert_approx_equal(Distance::hypot(Distance::from_m(3e300), Distance::from_m(4e300)).to_m(), 5e300, 12);
+	}
+
+	/// Unit test
+	#[test]
+	#[cfg(all(feature="serde", not(feature="serde-unit-tag")))]
+	fn serde_json_test() {
+		let d = Distance::from_m(5.0);
+		let json = serde_json::to_string(&d).unwrap();
+		assert_eq!(json, r#"{"m":5.0}"#);
+		assert_eq!(serde_json::from_str::<Distance<f64>>(&json).unwrap(), d);
+	}
+
+	/// Unit test
+	#[test]
+	#[cfg(feature="serde-unit-tag")]
+	fn serde_unit_tag_test() {
+		let d = Distance::from_m(5.0);
+		let json = serde_json::to_string(&d).unwrap();
+		assert_eq!(json, r#"{"value":5.0,"unit":"m"}"#);
+		assert_eq!(serde_json::from_str::<Distance<f64>>(&json).unwrap(), d);
+		let e = Energy::from_J(1.5e3);
+		let json = serde_json::to_string(&e).unwrap();
+		assert_eq!(json, r#"{"value":1500.0,"unit":"J"}"#);
+		assert_eq!(serde_json::from_str::<Energy<f64>>(&json).unwrap(), e);
+		// field order does not matter, but the unit must match
+		assert_eq!(serde_json::from_str::<Energy<f64>>(r#"{"unit":"J","value":2.0}"#).unwrap(), Energy::from_J(2.0));
+		let err = serde_json::from_str::<Energy<f64>>(r#"{"value":5.0,"unit":"m"}"#).unwrap_err();
+		assert!(std::string::ToString::to_string(&err).contains("wrong unit"));
+		assert!(serde_json::from_str::<Energy<f64>>(r#"{"value":5.0}"#).is_err());
+		assert!(serde_json::from_str::<Energy<f64>>(r#"{"J":5.0}"#).is_err());
+	}
+
+	/// Unit test
+	#[test]
+	#[cfg(feature="serde")]
+	fn serde_postcard_test() {
+		// non-human-readable formats store just the number, with or without the serde-unit-tag feature
+		let d = Distance::from_m(5.0_f64);
+		let bytes = postcard::to_allocvec(&d).unwrap();
+		assert_eq!(bytes, postcard::to_allocvec(&5.0_f64).unwrap());
+		assert_eq!(postcard::from_bytes::<Distance<f64>>(&bytes).unwrap(), d);
+		let e = Energy::from_J(-1500_i32);
+		let bytes = postcard::to_allocvec(&e).unwrap();
+		assert_eq!(bytes, postcard::to_allocvec(&-1500_i32).unwrap());
+		assert_eq!(postcard::from_bytes::<Energy<i32>>(&bytes).unwrap(), e);
+		// while JSON still carries the unit
+		let json = serde_json::to_string(&d).unwrap();
+		assert!(json.contains("\"m\""));
+		assert_eq!(serde_json::from_str::<Distance<f64>>(&json).unwrap(), d);
+	}
+
+	/// Unit test
+	#[test]
+	#[cfg(feature="num-complex")]
+	fn complex_magnitude_and_display_test() {
+		use num_complex::{Complex32, Complex64};
+		let impedance = Resistance{Ohm: Complex64::new(3.0, 4.0)};
+		assert_eq!(impedance.magnitude(), Resistance{Ohm: 5.0_f64});
+		assert_eq!(Voltage{V: Complex32::new(0.0, -2.0)}.magnitude(), Voltage{V: 2.0_f32});
+		assert_eq!(format!("{}", Distance{m: Complex64::new(1.0, 2.0)}), "(1+2i) m");
+		assert_eq!(format!("{}", Distance{m: Complex64::new(-1.0, -2.0)}), "(-1-2i) m");
+		assert_eq!(format!("{:#}", Distance{m: Complex64::new(1.0, 2.0)}), "(1+2i) m");
+		assert_eq!(format!("{}", Distance::from_m(-1.5)), "-1.5 m");
+	}
+
+	/// Unit test
+	#[test]
+	#[cfg(feature="num-complex")]
+	fn complex_display_round_trip_test() {
+		use num_complex::Complex64;
+		for z in [Complex64::new(1.0, 2.0), Complex64::new(-1.5, -0.25), Complex64::new(0.0, 3.0)] {
+			let d = Distance{m: z};
+			assert_eq!(format!("{}", d).parse::<Distance<Complex64>>().unwrap(), d);
+		}
+		assert_eq!("(1+2i)m".parse::<Distance<Complex64>>().unwrap(), Distance{m: Complex64::new(1.0, 2.0)});
+		assert_eq!("1+2i m".parse::<Distance<Complex64>>().unwrap(), Distance{m: Complex64::new(1.0, 2.0)});
+		assert!("(1+2i) s".parse::<Distance<Complex64>>().is_err());
+	}
+
+	/// Unit test
+	#[test]
+	fn flow_rate_test() {
+		let flow = Volume::from_L(30.0) / Time::from_s(10.0);
+		assert_approx_equal(flow.to_liters_per_second(), 3.0, 12);
+		assert_approx_equal(VolumetricFlowRate::from_liters_per_second(3.0).to_m3ps(), 0.003, 12);
+		assert_approx_equal(VolumetricFlowRate::from_liters_per_minute(60.0).to_Lps(), 1.0, 12);
+		let mass_flow: MassFlowRate<f64> = VolumetricFlowRate::from_Lps(2.0) * Density::from_kgpm3(1000.0);
+		assert_approx_equal(mass_flow.to_kgps(), 2.0, 12);
+		assert_approx_equal((Mass::from_kg(7200.0) / Time::from_hr(1.0)).to_tph(), 7.2, 12);
+		assert_approx_equal((VolumetricFlowRate::from_m3ps(0.5) * Time::from_s(4.0)).to_m3(), 2.0, 12);
+	}
+
+	/// Unit test
+	#[test]
+	fn heat_capacity_test() {
+		// 2 kg of water
+		let c: HeatCapacity<f64> = SpecificHeatCapacity::from_J_per_gK(4.184) * Mass::from_kg(2.0);
+		assert_approx_equal(c.to_kJ_per_K(), 8.368, 12);
+		let heat: Energy<f64> = c * TemperatureInterval::from_K(10.0);
+		assert_approx_equal(heat.to_kJ(), 83.68, 12);
+		assert_approx_equal((heat / TemperatureInterval::from_K(10.0)).to_J_per_K(), c.to_J_per_K(), 12);
+		let s: Entropy<f64> = Entropy::from_J_per_K(2.0);
+		assert_approx_equal((s * Temperature::from_K(300.0)).to_J(), 600.0, 12);
+	}
+
+	/// Unit test
+	#[test]
+	fn thermal_conductivity_test() {
+		// heat flow through a 2 m² wall of brick (k = 0.8 W/m·K), 0.25 m thick, with a 20 K temperature difference
+		let k = ThermalConductivity::from_W_per_mK(0.8);
+		let conductance: ThermalConductance<f64> = k * (Area::from_m2(2.0) / Distance::from_m(0.25));
+		assert_approx_equal(conductance.to_W_per_K(), 6.4, 12);
+		let heat_flow: Power<f64> = conductance * TemperatureInterval::from_K(20.0);
+		assert_approx_equal(heat_flow.to_W(), 128.0, 12);
+		let resistance: ThermalResistance<f64> = conductance.recip();
+		assert_approx_equal(resistance.to_K_per_W(), 0.15625, 12);
+		assert_approx_equal(ThermalConductivity::from_BTU_per_hr_ft_F(1.0).to_W_per_mK(), 1.730734666, 9);
+	}
+
+	/// Unit test
+	#[test]
+	fn negate_reference_test() {
+		let f = Force::from_N(5.0);
+		assert_eq!(-&f, Force::from_N(-5.0));
+		assert_eq!(-(-&f), f);
+		let d = Distance{m: 3_i32};
+		assert_eq!(-&d, Distance{m: -3_i32});
+	}
+
+	/// Unit test
+	#[test]
+	fn map_and_cast_test() {
+		let d = Distance{m: 3_i32};
+		let d64: Distance<f64> = d.cast();
+		assert_eq!(d64, Distance::from_m(3.0));
+		assert_eq!(Distance{m: 3_i32}.map(|x| x as f64 * 0.5), Distance::from_m(1.5));
+		let t32: Time<f32> = Time::from_s(2.5_f64).map(|x| x as f32);
+		assert_eq!(t32, Time::from_s(2.5_f32));
+		assert_eq!(t32.cast::<f64>(), Time::from_s(2.5_f64));
+	}
+
+	/// Unit test
+	#[test]
+	fn si_unit_trait_test() {
+		let units: [&dyn super::SiUnit<Number=f64>; 4] = [
+			&Distance::from_m(1.5), &Time::from_s(0.5), &Mass::from_kg(2.0), &Velocity::from_mps(3.0)
+		];
+		let symbols: std::vec::Vec<&str> = units.iter().map(|u| u.unit_symbol()).collect();
+		assert_eq!(symbols, ["m", "s", "kg", "m/s"]);
+		assert_eq!(units[0].unit_name(), "meters");
+		let total: f64 = units.iter().map(|u| *u.raw()).sum();
+		assert_eq!(total, 7.0);
+		assert_eq!(std::format!("{}", units[3]), "3 m/s");
+	}
+
+	/// Unit test
+	#[test]
+	#[cfg(feature="alloc")]
+	fn canonical_string_test() {
+		let d = Distance::from_m(5.0);
+		assert_eq!(d.canonical_string(), "5 m");
+		assert_eq!(d.canonical_string().parse::<Distance<f64>>().unwrap(), d);
+		let v = Velocity::from_mps(1.0/3.0);
+		assert_eq!(v.canonical_string().parse::<Velocity<f64>>().unwrap(), v);
+		let e = Energy::from_J(-2.5e-19);
+		assert_eq!(e.canonical_string().parse::<Energy<f64>>().unwrap(), e);
+		let t = Time{s: 42_i32};
+		assert_eq!(t.canonical_string(), "42 s");
+		assert_eq!(t.canonical_string().parse::<Time<i32>>().unwrap(), t);
+		let c = Current::from_A(num_complex::Complex64::new(1.5, -2.0));
+		assert_eq!(c.canonical_string().parse::<Current<num_complex::Complex64>>().unwrap(), c);
+	}
+
+	/// Unit test
+	#[test]
+	fn squared_and_cubed_test() {
+		let d = Distance::from_m(3.0);
+		assert_eq!(d.squared(), d * d);
+		assert_eq!(d.cubed(), d * d * d);
+		assert_eq!(d.squared(), Area::from_m2(9.0));
+		assert_eq!(d.cubed(), Volume::from_m3(27.0));
+		let di = Distance{m: -2_i32};
+		assert_eq!(di.squared(), Area{m2: 4});
+		assert_eq!(di.cubed(), di * di * di);
+	}
+
+	/// Unit test
+	#[test]
+	#[cfg(feature="num-traits")]
+	fn checked_and_saturating_test() {
+		let big = Time{s: i32::MAX - 1};
+		let one = Time{s: 1_i32};
+		assert_eq!(big.checked_add(one), Some(Time{s: i32::MAX}));
+		assert_eq!(big.checked_add(one + one), None);
+		assert_eq!(Time{s: i32::MIN}.checked_sub(one), None);
+		assert_eq!(Time{s: 0_i32}.checked_sub(one), Some(Time{s: -1}));
+		assert_eq!(big.saturating_add(Time{s: 100}), Time{s: i32::MAX});
+		assert_eq!(Time{s: i32::MIN + 5}.saturating_sub(Time{s: 100}), Time{s: i32::MIN});
+		assert_eq!(Time{s: 7_i8}.saturating_add(Time{s: 125}), Time{s: i8::MAX});
+	}
+
+	/// Unit test
+	#[test]
+	fn default_test() {
+		assert_eq!(Distance::<f64>::default(), Distance::from_m(0.0));
+		assert_eq!(Time::<i32>::default(), Time{s: 0});
+		#[derive(Default, Debug, PartialEq)]
+		struct Reading {
+			position: Distance<f64>,
+			temperature: Temperature<f32>
+		}
+		assert_eq!(Reading::default(), Reading{position: Distance::from_m(0.0), temperature: Temperature::from_K(0.0)});
+	}
+
+	/// Unit test
+	#[test]
+	fn ratio_and_scale_test() {
+		let a = Distance::from_m(3.0);
+		let b = Distance::from_km(1.5);
+		assert_eq!(a.ratio(b), 0.002);
+		assert_eq!(a.ratio(b), a / b);
+		assert_eq!(b.ratio(a), 500.0);
+		assert_eq!(Distance{m: 7_i32}.ratio(Distance{m: 2}), 3);
+		assert_eq!(a.scale(4.0), Distance::from_m(12.0));
+		assert_eq!(a.scale(4.0), a * 4.0);
+	}
+
+	/// Unit test
+	#[test]
+	#[cfg(all(feature="num-traits", any(feature="std", feature="libm")))]
+	fn angle_wrapping_test() {
+		use core::f64::consts::PI;
+		assert_approx_equal(Angle::from_rad(3.0*PI).wrapped().to_rad(), PI, 9);
+		assert_approx_equal(Angle::from_rad(3.0*PI).wrapped_signed().to_rad(), PI, 9);
+		assert_approx_equal(Angle::from_rad(-PI/2.0).wrapped().to_rad(), 1.5*PI, 9);
+		assert_approx_equal(Angle::from_rad(-PI/2.0).wrapped_signed().to_rad(), -PI/2.0, 9);
+		assert_eq!(Angle::from_rad(2.0*PI).wrapped(), Angle::from_rad(0.0));
+		assert_eq!(Angle::from_rad(2.0*PI).wrapped_signed(), Angle::from_rad(0.0));
+		assert_eq!(Angle::from_rad(-1e-20).wrapped(), Angle::from_rad(0.0));
+		assert_approx_equal(Angle::from_degrees(-720.0 - 90.0).wrapped().to_degrees(), 270.0, 9);
+		assert_eq!(Angle::from_rad(PI).wrapped_signed(), Angle::from_rad(PI));
+		assert_eq!(Angle::from_rad(-PI).wrapped_signed(), Angle::from_rad(PI));
+		// Euclidean division
+		assert_eq!(Time{s: -7_i32}.rem_euclid(Time{s: 3}), Time{s: 2});
+		assert_eq!(Time{s: -7_i32}.div_euclid(Time{s: 3}), -3);
+		assert_eq!(Distance::from_m(7.5).rem_euclid(Distance::from_m(2.0)), Distance::from_m(1.5));
+	}
+
+	/// Unit test
+	#[test]
+	fn raw_accessors_test() {
+		let mut d = Distance::from_km(1.5);
+		assert_eq!(*d.as_raw(), 1500.0);
+		*d.raw_mut() *= 2.0;
+		assert_eq!(d, Distance::from_m(3000.0));
+		fn scale_in_place(x: &mut f64) { *x *= 0.5; }
+		scale_in_place(d.raw_mut());
+		assert_eq!(d.to_km(), 1.5);
+	}
+
+	/// Unit test
+	#[test]
+	fn slice_conversion_test() {
+		let readings = [1.5, -2.0, 0.0, 4.25];
+		let distances = Distance::wrap_slice(&readings);
+		assert_eq!(distances.len(), readings.len());
+		for (d, x) in distances.iter().zip(readings.iter()) {
+			assert_eq!(*d, Distance::from_m(*x));
+		}
+		let mut raw = [1_i32, 2, 3];
+		for t in Time::wrap_slice_mut(&mut raw) {
+			*t += Time{s: 10};
+		}
+		assert_eq!(raw, [11, 12, 13]);
+		assert_eq!(Distance::wrap_slice(&[] as &[f64]), &[]);
+	}
+
+	/// Unit test
+	#[test]
+	#[cfg(feature="alloc")]
+	fn from_slice_test() {
+		let readings = [1.5, -2.0, 0.0, 4.25];
+		let distances = Distance::from_m_slice(&readings);
+		assert_eq!(distances.as_slice(), Distance::wrap_slice(&readings));
+		assert_eq!(distances[3], Distance::from_m(4.25));
+	}
+
+	/// Unit test
+	#[test]
+	fn repr_transparent_test() {
+		use core::mem::{size_of, align_of};
+		const _: () = assert!(size_of::<Distance<f64>>() == size_of::<f64>());
+		const _: () = assert!(align_of::<Distance<f64>>() == align_of::<f64>());
+		const _: () = assert!(size_of::<Velocity<f32>>() == size_of::<f32>());
+		const _: () = assert!(size_of::<Time<i8>>() == size_of::<i8>());
+		assert_eq!(size_of::<Energy<num_complex::Complex64>>(), size_of::<num_complex::Complex64>());
+	}
+
+	/// Unit test
+	#[test]
+	fn frequency_angular_velocity_test() {
+		use core::f64::consts::PI;
+		assert_approx_equal(Frequency::from_Hz(1.0).to_angular_velocity().to_radps(), 2.0*PI, 12);
+		assert_approx_equal(Frequency::from_Hz(60.0).to_angular_velocity().to_radps(), 120.0*PI, 12);
+		assert_approx_equal(Frequency::from_Hz(60.0).to_angular_velocity().to_rpm(), 3600.0, 12);
+		assert_approx_equal(AngularVelocity::from_radps(2.0*PI).to_frequency().to_Hz(), 1.0, 12);
+		assert_approx_equal(AngularVelocity::from_radps(120.0*PI).to_frequency().to_Hz(), 60.0, 12);
+		assert_approx_equal(Frequency::from_Hz(60.0).to_angular_velocity().to_frequency().to_Hz(), 60.0, 12);
+	}
+
+	/// Unit test
+	#[test]
+	fn rpm_test() {
+		use core::f64::consts::PI;
+		assert_approx_equal(Frequency::from_rpm(3000.0).to_Hz(), 50.0, 12);
+		assert_approx_equal(Frequency::from_Hz(50.0).to_rpm(), 3000.0, 12);
+		assert_approx_equal(AngularVelocity::from_rpm(3000.0).to_radps(), 100.0*PI, 12);
+		assert_approx_equal(AngularVelocity::from_radps(100.0*PI).to_rpm(), 3000.0, 12);
+		assert_approx_equal(Frequency::from_rpm(3000.0).to_angular_velocity().to_rpm(), 3000.0, 12);
+		assert_eq!(Frequency::from_rpm(3000.0).to_rpm(), 3000.0);
+		assert_eq!(Frequency::from_rpm(60.0).to_Hz(), 1.0);
+	}
+
+	/// Unit test
+	#[test]
+	fn exp_format_test() {
+		let d = Distance::from_m(1500.0);
+		assert_eq!(std::format!("{:e}", d), "1.5e3 m");
+		assert_eq!(std::format!("{:.2e}", d), "1.50e3 m");
+		assert_eq!(std::format!("{:.2E}", d), "1.50E3 m");
+		assert_eq!(std::format!("{:e}", Energy::from_J(-2.5e-19_f32)), "-2.5e-19 J");
+		assert_eq!(std::format!("{:e}", Time{s: 1200_i32}), "1.2e3 s");
+	}
+
+	/// Unit test
+	#[test]
+	fn display_flags_test() {
+		assert_eq!(std::format!("{:.2}", Distance::from_m(1.23456)), "1.23 m");
+		assert_eq!(std::format!("{:8.2}", Distance::from_m(1.23456)), "    1.23 m");
+		assert_eq!(std::format!("{:<8.1}|", Velocity::from_mps(2.0)), "2.0      m/s|");
+		assert_eq!(std::format!("{:+}", Time::from_s(3.0)), "+3 s");
+		assert_eq!(std::format!("{:05}", Time{s: 42_i32}), "00042 s");
+		assert_eq!(std::format!("{:.1}", Current::from_A(num_complex::Complex64::new(1.25, -2.0))), "(1.2-2.0i) A");
+		let z = Resistance::from_Ohm(num_complex::Complex64::new(3.0, 4.0));
+		assert_eq!(std::format!("{:>12}", z), "      (3+4i) Ohm");
+		assert_eq!(std::format!("{:<8}|", z), "(3+4i)   Ohm|");
+		assert_eq!(std::format!("{:*^10.1}", z), "(3.0+4.0i) Ohm");
+		assert_eq!(std::format!("{:*^12.1}", z), "*(3.0+4.0i)* Ohm");
+		assert_eq!(std::format!("{:+}", z), "(+3+4i) Ohm");
+	}
+
+	/// Unit test
+	#[test]
+	#[cfg(feature="num-traits")]
+	fn signum_test() {
+		assert_eq!(Force::from_N(12.5).signum(), 1.0);
+		assert_eq!(Force::from_N(-0.25).signum(), -1.0);
+		assert_eq!(Force::from_N(0.0).signum(), 1.0);
+		assert_eq!(Force::from_N(-0.0).signum(), -1.0);
+		assert!(Force::from_N(f64::NAN).signum().is_nan());
+		assert_eq!(Force{N: 7_i32}.signum(), 1);
+		assert_eq!(Force{N: -7_i32}.signum(), -1);
+		assert_eq!(Force{N: 0_i32}.signum(), 0);
+	}
+
+	/// Unit test
+	#[test]
+	#[cfg(all(feature="num-traits", any(feature="std", feature="libm")))]
+	fn copysign_test() {
+		assert_eq!(Velocity::from_mps(3.0).copysign(Velocity::from_mps(-1.0)), Velocity::from_mps(-3.0));
+		assert_eq!(Velocity::from_mps(-3.0).copysign(Velocity::from_mps(1.0)), Velocity::from_mps(3.0));
+		assert_eq!(Velocity::from_mps(-3.0_f32).copysign(Velocity::from_mps(0.0)), Velocity::from_mps(3.0));
+	}
+
+	/// Unit test
+	#[test]
+	fn quantity_test() {
+		use super::quantity::*;
+		let d = Quantity::from(Distance::from_m(100.0));
+		let t = Quantity::from(Time::from_s(20.0));
+		let v = d.try_div(&t).unwrap();
+		assert_eq!(v, Quantity::Velocity(Velocity::from_mps(5.0)));
+		assert_eq!(v.unit_symbol(), "m/s");
+		assert_eq!(v.try_mul(&t).unwrap(), d);
+		assert_eq!(d.try_add(&d).unwrap(), Quantity::Distance(Distance::from_m(200.0)));
+		assert_eq!(d.try_sub(&d).unwrap(), Quantity::Distance(Distance::from_m(0.0)));
+		let temp = Quantity::from(Temperature::from_K(300.0));
+		assert_eq!(temp.try_sub(&temp).unwrap(), Quantity::TemperatureInterval(TemperatureInterval::from_K(0.0)));
+		assert_eq!(d.try_div(&d).unwrap(), Quantity::Scalar(1.0));
+		assert_eq!(d.try_mul(&Quantity::Scalar(3.0)).unwrap(), Quantity::from(Distance::from_m(300.0)));
+		assert_eq!(Quantity::Scalar(3.0).try_mul(&d).unwrap(), Quantity::from(Distance::from_m(300.0)));
+		assert_eq!(d.try_mul(&d).unwrap(), Quantity::from(Area::from_m2(10000.0)));
+		assert_eq!(d.clone() * 0.5, Quantity::from(Distance::from_m(50.0)));
+		// mismatched units
+		let err = d.try_add(&t).unwrap_err();
+		assert_eq!(err, DimensionError{operation: "add", left: "m", right: "s"});
+		assert_eq!(std::string::ToString::to_string(&err), "cannot add quantities with units 'm' and 's'");
+		assert!(t.try_sub(&d).is_err());
+		assert!(Quantity::from(Mass::from_kg(1.0)).try_mul(&Quantity::from(Current::from_A(1.0))).is_err());
+		// converting back to unit structs
+		let vel: Velocity<f64> = v.clone().try_into().unwrap();
+		assert_eq!(vel, Velocity::from_mps(5.0));
+		assert!(Distance::<f64>::try_from(v.clone()).is_err());
+		assert_eq!(std::format!("{:.1}", v), "5.0 m/s");
+	}
+
+	/// Unit test
+	#[test]
+	fn dimension_test() {
+		use super::Dimension;
+		let length = Distance::<f64>::dimension();
+		let mass = Mass::<f64>::dimension();
+		let time = Time::<f64>::dimension();
+		assert_eq!(length, Dimension{length: 1, ..Default::default()});
+		assert_eq!(Energy::<f64>::dimension(), length * length * mass / (time * time));
+		assert_eq!(Energy::<f64>::dimension().to_array(), [2, 1, -2, 0, 0, 0, 0]);
+		assert_eq!(Force::<f64>::dimension().to_array(), [1, 1, -2, 0, 0, 0, 0]);
+		assert_eq!(Voltage::<f64>::dimension().to_array(), [2, 1, -3, -1, 0, 0, 0]);
+		assert_eq!(InverseEnergy::<f64>::dimension(), Dimension::default() / Energy::<f64>::dimension());
+		assert_eq!(Concentration::<f64>::dimension().to_array(), [-3, 0, 0, 0, 0, 1, 0]);
+		assert!(Angle::<f64>::dimension().is_dimensionless());
+		assert!(!Frequency::<f64>::dimension().is_dimensionless());
+	}
+
+	/// Unit test
+	#[test]
+	fn try_convert_test() {
+		use super::SiUnit;
+		assert_eq!(Temperature::from_K(300.0).try_convert::<TemperatureInterval<f64>>(), Some(TemperatureInterval::from_K(300.0)));
+		assert_eq!(HeatCapacity::from_J_per_K(2.0).try_convert::<Entropy<f64>>(), Some(Entropy::from_J_per_K(2.0)));
+		assert_eq!(Distance::from_m(2.0).try_convert::<Distance<f64>>(), Some(Distance::from_m(2.0)));
+		// same dimensions, but physically different quantities
+		assert_eq!(Torque::from_Nm(2.0).try_convert::<Energy<f64>>(), None);
+		assert_eq!(Energy::from_J(2.0).try_convert::<Torque<f64>>(), None);
+		assert_eq!(Frequency::from_Hz(2.0).try_convert::<AngularVelocity<f64>>(), None);
+		assert_eq!(Frequency::from_Hz(2.0).try_convert::<Radioactivity<f64>>(), None);
+		// different dimensions
+		assert_eq!(Distance::from_m(2.0).try_convert::<Time<f64>>(), None);
+	}
+
+	/// Unit test
+	#[test]
+	fn tick_conversion_test() {
+		assert_eq!(Time::from_ticks(60, 60), Time::from_s(1.0));
+		assert_eq!(Time::from_ticks(90, 60), Time::from_s(1.5));
+		assert_eq!(Time::from_ticks(-30, 60), Time::from_s(-0.5));
+		assert_eq!(Time::from_s(1.0).to_ticks(60), 60);
+		assert_eq!(Time::from_ticks(12345, 60).to_ticks(60), 12345);
+		// rounds to the nearest tick
+		assert_eq!(Time::from_s(1.0/60.0 * 0.49).to_ticks(60), 0);
+		assert_eq!(Time::from_s(1.0/60.0 * 0.51).to_ticks(60), 1);
+		assert_eq!(Time::from_s(-1.0/60.0 * 1.6).to_ticks(60), -2);
+		assert_eq!(Time::from_s(2.5).to_ticks(1), 3);
+		assert_eq!(Time::from_s(-2.5).to_ticks(1), -3);
+		// saturation
+		assert_eq!(Time::from_s(1e30).to_ticks(60), i64::MAX);
+		assert_eq!(Time::from_s(-1e30).to_ticks(60), i64::MIN);
+		assert_eq!(Time::from_s(f64::INFINITY).to_ticks(60), i64::MAX);
+		assert_eq!(Time::from_s(f64::NAN).to_ticks(60), 0);
+	}
+
+	/// Unit test
+	#[test]
+	fn integer_unit_map_key_test() {
+		let mut events = std::collections::BTreeMap::new();
+		events.insert(Time{s: 30_i64}, "b");
+		events.insert(Time{s: 10_i64}, "a");
+		events.insert(Time{s: 30_i64}, "c");
+		assert_eq!(events.len(), 2);
+		assert_eq!(events.keys().copied().collect::<std::vec::Vec<_>>(), [Time{s: 10_i64}, Time{s: 30_i64}]);
+		assert_eq!(events[&Time{s: 30_i64}], "c");
+		let mut seen = std::collections::HashSet::new();
+		assert!(seen.insert(Distance{m: 5_i32}));
+		assert!(!seen.insert(Distance{m: 5_i32}));
+		fn assert_eq_trait<X: Eq>() {}
+		assert_eq_trait::<Time<i64>>();
+	}
+
+	/// Unit test
+	#[test]
+	fn sort_integer_units_test() {
+		let mut masses = std::vec![Mass{kg: 5_i32}, Mass{kg: -2}, Mass{kg: 12}, Mass{kg: 0}];
+		masses.sort();
+		assert_eq!(masses, [Mass{kg: -2}, Mass{kg: 0}, Mass{kg: 5}, Mass{kg: 12}]);
+		assert_eq!(masses.iter().max(), Some(&Mass{kg: 12}));
+		assert_eq!(Mass{kg: 3_i32}.cmp(&Mass{kg: 4}), core::cmp::Ordering::Less);
+	}
+
+	/// Unit test
+	#[test]
+	fn clamp_magnitude_test() {
+		let max_speed = Velocity::from_mps(10.0);
+		assert_eq!(Velocity::from_mps(25.0).clamp_magnitude(max_speed), Velocity::from_mps(10.0));
+		assert_eq!(Velocity::from_mps(-25.0).clamp_magnitude(max_speed), Velocity::from_mps(-10.0));
+		assert_eq!(Velocity::from_mps(4.0).clamp_magnitude(max_speed), Velocity::from_mps(4.0));
+		assert_eq!(Velocity::from_mps(-4.0).clamp_magnitude(max_speed), Velocity::from_mps(-4.0));
+		assert_eq!(Velocity{mps: -40_i32}.clamp_magnitude(Velocity{mps: 10}), Velocity{mps: -10});
+		let v = Velocity::from_mps(num_complex::Complex64::new(30.0, 40.0));
+		assert_eq!(v.clamp_norm(Velocity::from_mps(10.0)), Velocity::from_mps(num_complex::Complex64::new(6.0, 8.0)));
+		assert_eq!(v.clamp_norm(Velocity::from_mps(100.0)), v);
+	}
+
+	/// Unit test
+	#[test]
+	fn new_test() {
+		assert_eq!(Distance::new(2.5), Distance::from_m(2.5));
+		assert_eq!(Time::new(3_i64), Time{s: 3_i64});
+		fn total<T: super::NumLike>(values: &[T]) -> Energy<T> where T: core::iter::Sum {
+			Energy::new(values.iter().cloned().sum())
+		}
+		assert_eq!(total(&[1.0, 2.0]), Energy::from_J(3.0));
+	}
+
+	/// Unit test
+	#[test]
+	#[cfg(feature="num-bigfloat")]
+	fn new_bigfloat_test() {
+		use num_bigfloat::BigFloat;
+		let d = Distance::new(BigFloat::from(1.5));
+		assert_eq!(d.m, BigFloat::from(1.5));
+		assert_eq!(d + Distance::new(BigFloat::from(1.5)), Distance::new(BigFloat::from(3.0)));
+	}
+
+	/// Unit test
+	#[test]
+	#[cfg(feature="num-rational")]
+	fn new_rational_test() {
+		use num_rational::Rational64;
+		let d = Distance::new(Rational64::new(1, 3));
+		assert_eq!(d * Rational64::from(3), Distance::new(Rational64::from(1)));
+	}
+
+	/// Unit test
+	#[test]
+	fn non_negative_constructors_test() {
+		use super::DomainError;
+		assert_eq!(Temperature::try_from_K(-1.0), Err(DomainError{unit_type: "Temperature"}));
+		assert_eq!(Temperature::try_from_K(0.0), Ok(Temperature::from_K(0.0)));
+		assert_eq!(Temperature::try_from_K(293.15), Ok(Temperature::from_K(293.15)));
+		assert!(Temperature::try_from_K(f64::NAN).is_err());
+		assert_eq!(Mass::try_from_kg(-0.5), Err(DomainError{unit_type: "Mass"}));
+		assert_eq!(Mass::try_from_kg(3_i32), Ok(Mass{kg: 3}));
+		assert!(Radioactivity::try_from_Bq(-10.0).is_err());
+		assert_eq!(std::string::ToString::to_string(&Temperature::try_from_K(-1.0).unwrap_err()),
+			"Temperature value cannot be negative");
+		// the unchecked constructors are unchanged
+		assert_eq!(Temperature::from_K(-1.0).K, -1.0);
+	}
+
+	/// Unit test
+	#[test]
+	fn weighted_mean_test() {
+		let values = [
+			(Distance::from_m(1.0), 1.0),
+			(Distance::from_m(2.0), 2.0),
+			(Distance::from_km(0.004), 1.0),
+		];
+		assert_approx_equal(Distance::weighted_mean(&values).m, 2.25, 9);
+		// weights don't need to be normalized
+		let scaled = values.map(|(d, w)| (d, w * 10.0));
+		assert_approx_equal(Distance::weighted_mean(&scaled).m, 2.25, 9);
+		// zero total weight returns zero
+		assert_eq!(Distance::<f64>::weighted_mean(&[]).m, 0.0);
+		assert_eq!(Distance::weighted_mean(&[(Distance::from_m(5.0), 0.0)]).m, 0.0);
+	}
+
+	/// Unit test
+	#[test]
+	fn radiometric_conversion_test() {
+		// 1 kW spread over 2 m² is 500 W/m²
+		let irradiance: Irradiance<f64> = Power::from_kW(1.0) / Area::from_m2(2.0);
+		assert_approx_equal(irradiance.to_W_per_m2(), 500.0, 9);
+		assert_approx_equal(irradiance.to_mW_per_cm2(), 50.0, 9);
+		assert_approx_equal((irradiance * Area::from_m2(2.0)).to_W(), 1000.0, 9);
+		assert_approx_equal((Power::from_W(3.0) / irradiance).to_m2(), 0.006, 9);
+		// 500 W/m² over 0.5 sr is 1000 W/m²·sr
+		let radiance: Radiance<f64> = irradiance / SolidAngle::from_sr(0.5);
+		assert_approx_equal(radiance.to_W_per_m2sr(), 1000.0, 9);
+		assert_approx_equal((radiance * SolidAngle::from_sr(0.5)).to_W_per_m2(), 500.0, 9);
+		let inverse: InverseIrradiance<f64> = 1.0 / irradiance;
+		assert_approx_equal(inverse.to_m2_per_W(), 0.002, 9);
+	}
+
+	/// Unit test
+	#[test]
+	fn molar_energy_test() {
+		// enthalpy of vaporization of water: about 40.7 kJ/mol
+		let h_vap = MolarEnergy::from_kJ_per_mol(40.7);
+		assert_approx_equal(h_vap.to_J_per_mol(), 40700.0, 9);
+		assert_approx_equal(h_vap.to_kcal_per_mol(), 9.72753346, 9);
+		assert_approx_equal((h_vap * Amount::from_mol(2.0)).to_kJ(), 81.4, 9);
+		let h_vap2: MolarEnergy<f64> = Energy::from_kJ(81.4) / Amount::from_mol(2.0);
+		assert_approx_equal(h_vap2.to_kJ_per_mol(), 40.7, 9);
+		// entropy of vaporization at the boiling point (373.15 K)
+		let s_vap: MolarEntropy<f64> = h_vap / TemperatureInterval::from_K(373.15);
+		assert_approx_equal(s_vap.to_J_per_molK(), 109.0714, 6);
+		assert_approx_equal((s_vap * TemperatureInterval::from_K(373.15)).to_kJ_per_mol(), 40.7, 9);
+	}
+
+	/// Unit test
+	#[test]
+	fn dimensionless_test() {
+		use super::SiUnit;
+		// strain: change in length over original length
+		let strain: Dimensionless<f64> = Distance::from_mm(2.0).dimensionless_ratio(Distance::from_m(1.0));
+		assert_approx_equal(strain.value, 0.002, 9);
+		assert_approx_equal((strain * Distance::from_m(3.0)).to_mm(), 6.0, 9);
+		assert_approx_equal((Distance::from_m(3.0) * strain).to_mm(), 6.0, 9);
+		assert_approx_equal((Distance::from_mm(6.0) / strain).to_m(), 3.0, 9);
+		// Mach number
+		let mach = Velocity::from_mps(686.0).dimensionless_ratio(Velocity::from_mps(343.0));
+		assert_eq!(mach, Dimensionless::new(2.0));
+		assert_eq!(mach * Dimensionless::from(1.5), Dimensionless{value: 3.0});
+		assert_eq!(mach * 2.0, Dimensionless{value: 4.0});
+		assert_eq!(mach / Dimensionless::new(4.0), 0.5);
+		assert_eq!(mach.to_value(), 2.0);
+		assert!(mach.dimension().is_dimensionless());
+		assert_eq!(Dimensionless::<f64>::unit_name(), "dimensionless");
+		assert_eq!(std::format!("{:.1}", mach), "2.0");
+	}
+
+	/// Unit test
+	#[test]
+	fn strain_mach_test() {
+		// a 2 m steel bar stretched by 1 mm
+		let strain = Strain::from_lengths(Distance::from_mm(1.0), Distance::from_m(2.0));
+		assert_approx_equal(strain.value, 0.0005, 9);
+		assert_approx_equal(strain.to_microstrain(), 500.0, 9);
+		assert_approx_equal(Strain::from_microstrain(500.0).value, 0.0005, 9);
+		assert_approx_equal(strain.elongation(Distance::from_m(4.0)).to_mm(), 2.0, 9);
+		assert_eq!(std::string::ToString::to_string(&Strain::new(0.5)), "0.5 m/m");
+		// an aircraft at 686 m/s where the speed of sound is 343 m/s
+		let c = Velocity::from_mps(343.0);
+		let mach = Velocity::from_mps(686.0).mach_number(c);
+		assert_eq!(mach, MachNumber::new(2.0));
+		assert_eq!(MachNumber::from_velocities(Velocity::from_mps(686.0), c), mach);
+		assert_approx_equal(MachNumber::new(0.8).to_velocity(c).to_mps(), 274.4, 9);
+		assert_eq!(std::string::ToString::to_string(&mach), "2 Ma");
+		assert_eq!(MachNumber::<f64>::unit_name(), "Mach");
+		assert_eq!(MachNumber::from(Dimensionless::new(1.5)).to_dimensionless(), Dimensionless::new(1.5));
+	}
+
+	/// Unit test
+	#[test]
+	fn unit_table_test() {
+		use super::ALL_UNITS;
+		// every unit type is listed exactly once (all_units_test checks that each one is present)
+		for (i, a) in ALL_UNITS.iter().enumerate() {
+			for b in &ALL_UNITS[i+1..] {
+				assert_ne!(a.type_name, b.type_name);
+				// unit types may only share a symbol if they are dimensionally equivalent (eg J/K)
+				if a.symbol == b.symbol {
+					assert_eq!(a.dimension, b.dimension, "{} and {} share a symbol", a.type_name, b.type_name);
+				}
+			}
+		}
+		let velocity = ALL_UNITS.iter().find(|u| u.type_name == "Velocity").unwrap();
+		assert_eq!(velocity.module, "mechanical");
+		assert_eq!(velocity.unit_name, Velocity::<f64>::unit_name());
+	}
+
+	/// Unit test
+	#[test]
+	fn op_assign_ref_test() {
+		let lengths = [Distance::from_m(1.0), Distance::from_m(2.5), Distance::from_m(0.5)];
+		let mut total = Distance::from_m(0.0);
+		for d in &lengths {
+			total += d;
+		}
+		assert_approx_equal(total.to_m(), 4.0, 9);
+		total -= &lengths[1];
+		assert_approx_equal(total.to_m(), 1.5, 9);
+		// also works for number types that are not Copy
+		#[cfg(feature="num-rational")]
+		{
+			use num_rational::BigRational;
+			let mut a = Mass{kg: BigRational::from_integer(3.into())};
+			let b = Mass{kg: BigRational::new(1.into(), 2.into())};
+			a -= &b;
+			a -= &b;
+			assert_eq!(a, Mass{kg: BigRational::from_integer(2.into())});
+		}
+	}
+
+	/// Unit test
+	#[test]
+	fn scalar_op_assign_ref_test() {
+		let factor = 2.5;
+		let mut f = Force::from_N(4.0);
+		f *= &factor;
+		assert_approx_equal(f.to_N(), 10.0, 9);
+		f /= &factor;
+		assert_approx_equal(f.to_N(), 4.0, 9);
+		let mut g = Force{N: 6_i32};
+		g *= &2;
+		g /= &3;
+		assert_eq!(g, Force{N: 4});
+	}
+
+	/// Unit test
+	#[test]
+	fn eq_within_test() {
+		let tolerance = Distance::from_mm(1.0);
+		assert!(Distance::from_m(1.0).eq_within(Distance::from_m(1.0005), tolerance));
+		assert!(Distance::from_m(1.0005).eq_within(Distance::from_m(1.0), tolerance));
+		assert!(!Distance::from_m(1.0).eq_within(Distance::from_m(1.002), tolerance));
+		assert!(!Distance::from_m(f64::NAN).eq_within(Distance::from_m(1.0), tolerance));
+		assert!(Distance{m: 10_i32}.eq_within(Distance{m: 12}, Distance{m: 2}));
+		crate::assert_unit_eq!(Distance::from_km(1.0), Distance::from_m(1000.0004), tolerance);
+		crate::assert_unit_eq!(Distance::from_inches(1.0), Distance::from_mm(25.4), Distance::from_um(1.0),);
+	}
+
+	/// Unit test
+	#[test]
+	#[should_panic(expected = "within tolerance")]
+	fn assert_unit_eq_failure_test() {
+		crate::assert_unit_eq!(Distance::from_m(1.0), Distance::from_m(1.1), Distance::from_mm(1.0));
+	}
+
+	/// Unit test
+	#[test]
+	fn torque_energy_test() {
+		let torque = Torque::from_Nm(12.5);
+		let energy: Energy<f64> = torque.as_energy();
+		assert_eq!(energy, Energy::from_J(12.5));
+		assert_eq!(energy.as_torque(), torque);
+		assert_eq!(Energy{J: 3_i32}.as_torque(), Torque{Nm: 3_i32});
+		// work done by a torque over one full rotation
+		let work = (Torque::from_Nm(2.0) * Angle::from_rad(core::f64::consts::TAU).rad).as_energy();
+		assert_approx_equal(work.to_J(), 4.0 * core::f64::consts::PI, 9);
+	}
+
+	/// Unit test
+	#[test]
+	fn geometry_shapes_test() {
+		use core::f64::consts::PI;
+		let r = Distance::from_m(1.0);
+		assert_approx_equal(Area::circle(r).to_m2(), PI, 12);
+		assert_approx_equal(Volume::sphere(r).to_m3(), 4.0 / 3.0 * PI, 12);
+		assert_approx_equal(Volume::cylinder(r, Distance::from_m(2.0)).to_m3(), 2.0 * PI, 12);
+		// other units of measure
+		assert_approx_equal(Area::circle(Distance::from_cm(10.0)).to_cm2(), 100.0 * PI, 12);
+		assert_approx_equal(Volume::sphere(Distance::from_cm(10.0)).to_L(), 4.0 / 3.0 * PI, 12);
+	}
+
+	/// Unit test
+	#[test]
+	fn tangential_speed_test() {
+		// a wheel of radius 0.3 m turning at 600 rpm (10 revolutions per second)
+		let omega = AngularVelocity::from_rpm(600.0);
+		let v = omega.tangential_speed(Distance::from_m(0.3));
+		assert_approx_equal(v.to_mps(), 0.3 * 10.0 * core::f64::consts::TAU, 9);
+		assert_approx_equal(AngularVelocity::from_radps(2.0).tangential_speed(Distance::from_cm(50.0)).to_mps(), 1.0, 9);
+	}
+
+	/// Unit test
+	#[test]
+	fn kinetic_energy_test() {
+		// a 1500 kg car at 20 m/s
+		let m = Mass::from_kg(1500.0);
+		let v = Velocity::from_mps(20.0);
+		let e1 = m.kinetic_energy(v);
+		assert_approx_equal(e1.to_kJ(), 300.0, 9);
+		let p: Momentum<f64> = m * v;
+		let e2 = p.kinetic_energy(m);
+		assert_approx_equal(e1.to_J(), e2.to_J(), 12);
+	}
+
+	/// Unit test
+	#[test]
+	fn hydrostatic_pressure_test() {
+		use super::constants::STANDARD_GRAVITY;
+		// water at 10 m depth
+		let p = Pressure::hydrostatic(Density::from_kgpm3(1000.0), STANDARD_GRAVITY, Distance::from_m(10.0));
+		assert_approx_equal(p.to_Pa(), 98066.5, 9);
+		assert_approx_equal(p.to_kPa(), 98.0665, 9);
+	}
+
+	/// Unit test
+	#[test]
+	fn period_frequency_test() {
+		assert_eq!(Frequency::from_Hz(50.0).period(), Time::from_s(0.02));
+		assert_eq!(Time::from_s(0.02).frequency(), Frequency::from_Hz(50.0));
+		assert_approx_equal(Frequency::from_kHz(2.0).period().to_ms(), 0.5, 12);
+		let f = Frequency::from_Hz(440.0);
+		assert_approx_equal(f.period().frequency().to_Hz(), 440.0, 12);
+		// zero propagates to infinity for floats
+		assert_eq!(Frequency::from_Hz(0.0).period(), Time::from_s(f64::INFINITY));
+		assert_eq!(Time::from_s(0.0).frequency(), Frequency::from_Hz(f64::INFINITY));
+		assert_eq!(Time::from_s(f64::INFINITY).frequency(), Frequency::from_Hz(0.0));
+	}
+
+	/// Unit test
+	#[test]
+	fn negate_inverse_units_test() {
+		// Neg keeps the inverse unit type, so it composes with recip()
+		let per_m: InverseDistance<f64> = -Distance::from_m(2.0).recip();
+		assert_eq!(per_m, InverseDistance::from_per_m(-0.5));
+		assert_eq!((-Distance::from_m(2.0)).recip(), per_m);
+		let per_Pa: InversePressure<f64> = -InversePressure::from_per_Pa(4.0);
+		assert_eq!(per_Pa, InversePressure::from_per_Pa(-4.0));
+		assert_eq!(per_Pa.recip(), -Pressure::from_Pa(0.25));
+		let per_J: InverseEnergy<f64> = -&InverseEnergy::from_per_J(0.5);
+		assert_eq!(per_J, InverseEnergy::from_per_J(-0.5));
+		assert_eq!(per_J.recip(), -Energy::from_J(2.0));
+		let s_per_kgm: InverseMomentum<f64> = -Momentum::from_kgmps(8.0).recip();
+		assert_eq!(s_per_kgm, InverseMomentum::from_s_per_kgm(-0.125));
+		assert_eq!(-s_per_kgm.recip(), Momentum::from_kgmps(8.0));
+		// units which share dimensions with others are still paired with their own inverse
+		assert_eq!(-AbsorbedDose::from_Gy(4.0).recip(), InverseAbsorbedDose::from_per_Gy(-0.25));
+		assert_eq!(-MomentOfInertia::from_kgm2(4.0).recip(), InverseMomentOfInertia::from_per_kgm2(-0.25));
+	}
+
+	/// Unit test
+	#[test]
+	fn display_named_test() {
+		use std::string::ToString;
+		assert_eq!(Distance::from_m(5.0).display_named().to_string(), "5 meters");
+		assert_eq!(std::format!("{:.2}", Velocity::from_mps(2.5).display_named()), "2.50 meters per second");
+		assert_eq!(Force::from_N(-3_i32).display_named().to_string(), "-3 newtons");
+		// names are not singularized
+		assert_eq!(Distance::from_m(1.0).display_named().to_string(), "1 meters");
+		assert_eq!(Mass::from_kg(1_i32).display_named().to_string(), "1 kilograms");
+	}
+
+	/// Unit test
+	#[test]
+	fn dose_rate_test() {
+		// per hour factors round-trip
+		assert_eq!(AbsorbedDoseRate::from_Gy_per_h(3.0).to_Gy_per_h(), 3.0);
+		assert_eq!(DoseEquivalentRate::from_Sv_per_h(3.0).to_Sv_per_h(), 3.0);
+		assert_eq!(DoseEquivalentRate::from_uSv_per_h(0.5).to_uSv_per_h(), 0.5);
+		assert_eq!(DoseEquivalentRate::from_Sv_per_h(1.0).to_Sv_per_s(), 1.0 / 3600.0);
+		// background radiation of about 0.25 microsieverts per hour over a year
+		let rate = DoseEquivalentRate::from_uSv_per_h(0.25);
+		assert_approx_equal(rate.to_Sv_per_s(), 6.944444444e-11, 9);
+		let year = Time::from_days(365.0);
+		let dose: DoseEquivalent<f64> = rate * year;
+		assert_approx_equal(dose.to_mSv(), 2.19, 9);
+		assert_approx_equal((year * rate).to_mSv(), 2.19, 9);
+		let rate2: DoseEquivalentRate<f64> = dose / year;
+		assert_approx_equal(rate2.to_uSv_per_h(), 0.25, 9);
+		let t: Time<f64> = dose / rate;
+		assert_approx_equal(t.to_days(), 365.0, 9);
+		// absorbed dose rates are kept separate from dose equivalent rates
+		let absorbed: AbsorbedDoseRate<f64> = AbsorbedDose::from_Gy(3.0) / Time::from_hr(2.0);
+		assert_approx_equal(absorbed.to_Gy_per_h(), 1.5, 9);
+		assert_approx_equal(absorbed.to_mGy_per_s(), 1.5 / 3.6, 9);
+		assert_approx_equal((absorbed * Time::from_min(20.0)).to_Gy(), 0.5, 9);
+		let per: InverseAbsorbedDoseRate<f64> = absorbed.recip();
+		assert_approx_equal(per.to_h_per_Gy(), 1.0 / 1.5, 9);
+		assert_approx_equal(DoseEquivalentRate::from_mSv_per_h(2.0).recip().to_h_per_mSv(), 0.5, 9);
+	}
+
+	/// Unit test
+	#[test]
+	fn curies_test() {
+		assert_eq!(Radioactivity::from_curies(1.0).to_curies(), 1.0);
+		assert_eq!(Radioactivity::from_Ci(2.0).to_Ci(), 2.0);
+		assert_eq!(Radioactivity::from_mCi(10.0).to_mCi(), 10.0);
+		assert_eq!(Radioactivity::from_pCi(3.0).to_pCi(), 3.0);
+		assert_eq!(Radioactivity::from_curies(1.0), Radioactivity::from_Bq(3.7e10));
+	}
+
+	/// Unit test
+	#[test]
+	#[cfg(all(feature="num-traits", any(feature="std", feature="libm")))]
+	fn radioactive_decay_test() {
+		let a = Radioactivity::from_curies(2.0);
+		assert_approx_equal(a.to_Bq(), 7.4e10, 12);
+		assert_approx_equal(Radioactivity::from_GBq(37.0).to_curies(), 1.0, 12);
+		// cobalt-60 has a half-life of about 5.27 years
+		let half_life = Time::from_yr(5.27);
+		assert_approx_equal(a.clone().after(half_life.clone(), half_life.clone()).to_curies(), 1.0, 12);
+		assert_approx_equal(a.clone().after(half_life.clone(), half_life.clone() * 2.0).to_curies(), 0.5, 12);
+		assert_approx_equal(a.clone().after(half_life.clone(), Time::from_s(0.0)).to_curies(), 2.0, 12);
+		assert_approx_equal(a.after(half_life, Time::from_yr(5.27 / 2.0)).to_curies(), 2.0_f64.sqrt(), 12);
+	}
+
+	/// Unit test
+	#[test]
+	#[cfg(all(feature="num-traits", any(feature="std", feature="libm")))]
+	fn dimensionless_powers_test() {
+		let ma = MachNumber::new(2.0);
+		assert_eq!(ma.clone().powi(2), MachNumber::new(4.0));
+		assert_eq!(ma.clone().powi(-1), MachNumber::new(0.5));
+		assert_approx_equal(ma.powf(0.5).value, 2.0_f64.sqrt(), 12);
+		// isentropic stagnation pressure ratio, (1 + (γ-1)/2 Ma²)^(γ/(γ-1)) with γ = 1.4
+		let ma = MachNumber::from_velocities(Velocity::from_mps(343.0), Velocity::from_mps(343.0));
+		let ratio = (Dimensionless::new(1.0) + Dimensionless::new(0.2) * ma.to_dimensionless().powi(2)).powf(3.5);
+		assert_approx_equal(ratio.to_value(), 1.892929159, 9);
+		assert_eq!(Strain::new(0.5_f32).powi(3), Strain::new(0.125_f32));
+	}
+
+	/// Unit test
+	#[test]
+	fn min_max_of_test() {
+		let temps = std::vec![
+			Temperature::from_celsius(21.5), Temperature::from_K(f64::NAN),
+			Temperature::from_celsius(-4.0), Temperature::from_celsius(37.0), Temperature::from_celsius(-4.0)
+		];
+		assert_eq!(Temperature::min_of(temps.clone()), Some(Temperature::from_celsius(-4.0)));
+		assert_eq!(Temperature::max_of(temps.iter().cloned()), Some(Temperature::from_celsius(37.0)));
+		// NaN is skipped even when it comes first
+		let nan_first = [Temperature::from_K(f64::NAN), Temperature::from_K(300.0)];
+		assert_eq!(Temperature::min_of(nan_first.clone()), Some(Temperature::from_K(300.0)));
+		assert_eq!(Temperature::max_of(nan_first), Some(Temperature::from_K(300.0)));
+		assert_eq!(Temperature::<f64>::min_of(std::vec![]), None);
+		assert_eq!(Temperature::max_of([Temperature::from_K(f64::NAN)]), None);
+		assert_eq!(Distance::max_of([Distance{m: 3_i32}, Distance{m: 7}, Distance{m: -1}]), Some(Distance{m: 7}));
+	}
+
+	/// Unit test
+	#[test]
+	fn checked_div_test() {
+		let d = Distance::from_m(12.0);
+		assert_eq!(d.clone().checked_div(Time::from_s(4.0)), Some(Velocity::from_mps(3.0)));
+		assert_eq!(d.clone().checked_div(Time::from_s(0.0)), None);
+		assert_eq!(d.clone().checked_div(Time::from_s(-0.0)), None);
+		assert_eq!(d.clone().checked_div(Velocity::from_mps(0.0)), None);
+		// dividing by the same unit type returns a plain number
+		assert_eq!(d.clone().checked_div(Distance::from_m(3.0)), Some(4.0));
+		assert_eq!(d.checked_div(Distance::from_m(0.0)), None);
+		assert_eq!(Energy::from_J(10.0).checked_div(Dimensionless::new(0.0)), None);
+		// integer division by zero would otherwise panic
+		assert_eq!(Distance{m: 12_i32}.checked_div(Time{s: 0}), None);
+		assert_eq!(Distance{m: 12_i32}.checked_div(Time{s: 4}), Some(Velocity{mps: 3}));
+		// a NaN divisor is not zero, so it is divided as usual
+		assert!(Mass::from_kg(1.0).checked_div(Volume::from_m3(f64::NAN)).unwrap().to_kgpm3().is_nan());
+	}
+
+	fn mul_check<
+		'y,
+		A: std::ops::Mul<B, Output = X>+Clone+'y,
+		B: std::ops::Mul<A, Output = X>+Clone+'y,
+		X: std::cmp::PartialEq+Clone
+	>(a: &'y A, b: &'y B) -> X where
+		A: std::ops::Mul<B, Output = X>+std::ops::Mul<&'y B, Output = X>,
+		B: std::ops::Mul<A, Output = X>+std::ops::Mul<&'y A, Output = X>,
+		&'y A: std::ops::Mul<B, Output = X>, &'y B: std::ops::Mul<A, Output = X>,
+		&'y A: std::ops::Mul<&'y B, Output = X>, &'y B: std::ops::Mul<&'y A, Output = X>
+	{
+		let x1: X = a * b;
+		let x2: X = b * a;
+		let x3: X = a.clone() * b.clone();
+		let x4: X = b.clone() * a.clone();
+		let x5: X = a * b.clone();
+		let x6: X = b * a.clone();
+		let x7: X = a.clone() * b;
+		let x8: X = b.clone() * a;
+		// let x6: X = b.clone() * &(a.clone());
+		assert!((x1.eq(&x2)));
+		assert!((x1.eq(&x3)));
+		assert!((x1.eq(&x4)));
+		assert!((x1.eq(&x5)));
+		assert!((x1.eq(&x6)));
+		assert!((x1.eq(&x7)));
+		assert!((x1.eq(&x8)));
+		return x1;
+	}
+
+	fn div_check<
+		'y,
+		A: std::ops::Div<B, Output = X>+Clone+'y,
+		B: Clone+'y,
+		X: std::cmp::PartialEq+Clone
+	>(a: &'y A, b: &'y B) -> X where
+		A: std::ops::Div<B, Output = X>+std::ops::Div<&'y B, Output = X>,
+		&'y A: std::ops::Div<B, Output = X>+std::ops::Div<&'y B, Output = X>
+	{
+		let x1: X = a / b;
+		let x2: X = a.clone() / b.clone();
+		let x3: X = a / b.clone();
+		let x4: X = a.clone() / b;
+		assert!((x1.eq(&x2)));
+		assert!((x1.eq(&x3)));
+		assert!((x1.eq(&x4)));
+		return x1;
+	}
+
+	macro_rules! mul_div_check {
+		($a: expr, $b: expr, $c: expr, $d: expr) => {
+			assert_eq!(($a) * ($b), ($c));
+			assert_eq!(($a) * &($b), ($c));
+			assert_eq!(&($a) * ($b), ($c));
+			assert_eq!(&($a) * &($b), ($c));
+			assert_eq!(($b) * ($a), ($c));
+			assert_eq!(($b) * &($a), ($c));
+			assert_eq!(&($b) * ($a), ($c));
+			assert_eq!(&($b) * &($a), ($c));
+			assert_eq!(($a) / ($b), ($d));
+			assert_eq!(($a) / &($b), ($d));
+			assert_eq!(&($a) / ($b), ($d));
+			assert_eq!(&($a) / &($b), ($d));
+		}
+	}
+
+	macro_rules! check_scalar_mul_div {
+		($a: expr, $b: expr) => {
+			let x = ($a).clone();
+			let y = ($b).clone();
+			let xy = x.clone() * y.clone();
+			let xovery = x.clone() / y.clone();
+			mul_div_check!(Amount{mol: x.clone()}, y.clone(), Amount{mol: xy.clone()}, Amount{mol: xovery.clone()});
+			mul_div_check!(Current{A: x.clone()}, y.clone(), Current{A: xy.clone()}, Current{A: xovery.clone()});
+			mul_div_check!(Distance{m: x.clone()}, y.clone(), Distance{m: xy.clone()}, Distance{m: xovery.clone()});
+			mul_div_check!(Luminosity{cd: x.clone()}, y.clone(), Luminosity{cd: xy.clone()}, Luminosity{cd: xovery.clone()});
+			mul_div_check!(Mass{kg: x.clone()}, y.clone(), Mass{kg: xy.clone()}, Mass{kg: xovery.clone()});
+			mul_div_check!(Temperature{K: x.clone()}, y.clone(), Temperature{K: xy.clone()}, Temperature{K: xovery.clone()});
+			mul_div_check!(Time{s: x.clone()}, y.clone(), Time{s: xy.clone()}, Time{s: xovery.clone()});
+			mul_div_check!(CatalyticActivity{molps: x.clone()}, y.clone(), CatalyticActivity{molps: xy.clone()}, CatalyticActivity{molps: xovery.clone()});
+			mul_div_check!(Concentration{molpm3: x.clone()}, y.clone(), Concentration{molpm3: xy.clone()}, Concentration{molpm3: xovery.clone()});
+			mul_div_check!(Molality{molpkg: x.clone()}, y.clone(), Molality{molpkg: xy.clone()}, Molality{molpkg: xovery.clone()});
+			mul_div_check!(MolarMass{kgpmol: x.clone()}, y.clone(), MolarMass{kgpmol: xy.clone()}, MolarMass{kgpmol: xovery.clone()});
+			mul_div_check!(SpecificHeatCapacity{J_per_kgK: x.clone()}, y.clone(), SpecificHeatCapacity{J_per_kgK: xy.clone()}, SpecificHeatCapacity{J_per_kgK: xovery.clone()});
+			mul_div_check!(Capacitance{F: x.clone()}, y.clone(), Capacitance{F: xy.clone()}, Capacitance{F: xovery.clone()});
+			mul_div_check!(Charge{C: x.clone()}, y.clone(), Charge{C: xy.clone()}, Charge{C: xovery.clone()});
+			mul_div_check!(Conductance{S: x.clone()}, y.clone(), Conductance{S: xy.clone()}, Conductance{S: xovery.clone()});
+			mul_div_check!(Illuminance{lux: x.clone()}, y.clone(), Illuminance{lux: xy.clone()}, Illuminance{lux: xovery.clone()});
+			mul_div_check!(Inductance{H: x.clone()}, y.clone(), Inductance{H: xy.clone()}, Inductance{H: xovery.clone()});
+			mul_div_check!(LuminousFlux{lm: x.clone()}, y.clone(), LuminousFlux{lm: xy.clone()}, LuminousFlux{lm: xovery.clone()});
+			mul_div_check!(MagneticFlux{Wb: x.clone()}, y.clone(), MagneticFlux{Wb: xy.clone()}, MagneticFlux{Wb: xovery.clone()});
+			mul_div_check!(MagneticFluxDensity{T: x.clone()}, y.clone(), MagneticFluxDensity{T: xy.clone()}, MagneticFluxDensity{T: xovery.clone()});
+			mul_div_check!(Resistance{Ohm: x.clone()}, y.clone(), Resistance{Ohm: xy.clone()}, Resistance{Ohm: xovery.clone()});
+			mul_div_check!(Voltage{V: x.clone()}, y.clone(), Voltage{V: xy.clone()}, Voltage{V: xovery.clone()});
+			mul_div_check!(Angle{rad: x.clone()}, y.clone(), Angle{rad: xy.clone()}, Angle{rad: xovery.clone()});
+			mul_div_check!(Area{m2: x.clone()}, y.clone(), Area{m2: xy.clone()}, Area{m2: xovery.clone()});
+			mul_div_check!(SolidAngle{sr: x.clone()}, y.clone(), SolidAngle{sr: xy.clone()}, SolidAngle{sr: xovery.clone()});
+			mul_div_check!(Volume{m3: x.clone()}, y.clone(), Volume{m3: xy.clone()}, Volume{m3: xovery.clone()});
+			mul_div_check!(Acceleration{mps2: x.clone()}, y.clone(), Acceleration{mps2: xy.clone()}, Acceleration{mps2: xovery.clone()});
+			mul_div_check!(AngularAcceleration{radps2: x.clone()}, y.clone(), AngularAcceleration{radps2: xy.clone()}, AngularAcceleration{radps2: xovery.clone()});
+			mul_div_check!(AngularMomentum{kgm2radps: x.clone()}, y.clone(), AngularMomentum{kgm2radps: xy.clone()}, AngularMomentum{kgm2radps: xovery.clone()});
+			mul_div_check!(AngularVelocity{radps: x.clone()}, y.clone(), AngularVelocity{radps: xy.clone()}, AngularVelocity{radps: xovery.clone()});
+			mul_div_check!(AreaDensity{kgpm2: x.clone()}, y.clone(), AreaDensity{kgpm2: xy.clone()}, AreaDensity{kgpm2: xovery.clone()});
+			mul_div_check!(Density{kgpm3: x.clone()}, y.clone(), Density{kgpm3: xy.clone()}, Density{kgpm3: xovery.clone()});
+			mul_div_check!(Energy{J: x.clone()}, y.clone(), Energy{J: xy.clone()}, Energy{J: xovery.clone()});
+			mul_div_check!(Force{N: x.clone()}, y.clone(), Force{N: xy.clone()}, Force{N: xovery.clone()});
+			mul_div_check!(Frequency{Hz: x.clone()}, y.clone(), Frequency{Hz: xy.clone()}, Frequency{Hz: xovery.clone()});
+			mul_div_check!(MomentOfInertia{kgm2: x.clone()}, y.clone(), MomentOfInertia{kgm2: xy.clone()}, MomentOfInertia{kgm2: xovery.clone()});
+			mul_div_check!(Momentum{kgmps: x.clone()}, y.clone(), Momentum{kgmps: xy.clone()}, Momentum{kgmps: xovery.clone()});
+			mul_div_check!(Power{W: x.clone()}, y.clone(), Power{W: xy.clone()}, Power{W: xovery.clone()});
+			mul_div_check!(Pressure{Pa: x.clone()}, y.clone(), Pressure{Pa: xy.clone()}, Pressure{Pa: xovery.clone()});
+			mul_div_check!(Torque{Nm: x.clone()}, y.clone(), Torque{Nm: xy.clone()}, Torque{Nm: xovery.clone()});
+			mul_div_check!(Velocity{mps: x.clone()}, y.clone(), Velocity{mps: xy.clone()}, Velocity{mps: xovery.clone()});
+			mul_div_check!(AbsorbedDose{Gy: x.clone()}, y.clone(), AbsorbedDose{Gy: xy.clone()}, AbsorbedDose{Gy: xovery.clone()});
+			mul_div_check!(DoseEquivalent{Sv: x.clone()}, y.clone(), DoseEquivalent{Sv: xy.clone()}, DoseEquivalent{Sv: xovery.clone()});
+			mul_div_check!(Radioactivity{Bq: x.clone()}, y.clone(), Radioactivity{Bq: xy.clone()}, Radioactivity{Bq: xovery.clone()});
+		mul_div_check!(InverseAmount{per_mol: x.clone()}, y.clone(), InverseAmount{per_mol: xy.clone()}, InverseAmount{per_mol: xovery.clone()});
+		mul_div_check!(InverseCurrent{per_A: x.clone()}, y.clone(), InverseCurrent{per_A: xy.clone()}, InverseCurrent{per_A: xovery.clone()});
+		mul_div_check!(InverseDistance{per_m: x.clone()}, y.clone(), InverseDistance{per_m: xy.clone()}, InverseDistance{per_m: xovery.clone()});
+		mul_div_check!(InverseLuminosity{per_cd: x.clone()}, y.clone(), InverseLuminosity{per_cd: xy.clone()}, InverseLuminosity{per_cd: xovery.clone()});
+		mul_div_check!(InverseMass{per_kg: x.clone()}, y.clone(), InverseMass{per_kg: xy.clone()}, InverseMass{per_kg: xovery.clone()});
+		mul_div_check!(InverseTemperature{per_K: x.clone()}, y.clone(), InverseTemperature{per_K: xy.clone()}, InverseTemperature{per_K: xovery.clone()});
+		mul_div_check!(InverseCatalyticActivity{s_per_mol: x.clone()}, y.clone(), InverseCatalyticActivity{s_per_mol: xy.clone()}, InverseCatalyticActivity{s_per_mol: xovery.clone()});
+		mul_div_check!(InverseSpecificHeatCapacity{kgK_per_J: x.clone()}, y.clone(), InverseSpecificHeatCapacity{kgK_per_J: xy.clone()}, InverseSpecificHeatCapacity{kgK_per_J: xovery.clone()});
+		mul_div_check!(MolarVolume{m3_per_mol: x.clone()}, y.clone(), MolarVolume{m3_per_mol: xy.clone()}, MolarVolume{m3_per_mol: xovery.clone()});
+		mul_div_check!(AreaPerLumen{m2_per_lm: x.clone()}, y.clone(), AreaPerLumen{m2_per_lm: xy.clone()}, AreaPerLumen{m2_per_lm: xovery.clone()});
+		mul_div_check!(Elastance{per_F: x.clone()}, y.clone(), Elastance{per_F: xy.clone()}, Elastance{per_F: xovery.clone()});
+		mul_div_check!(InverseCharge{per_C: x.clone()}, y.clone(), InverseCharge{per_C: xy.clone()}, InverseCharge{per_C: xovery.clone()});
+		mul_div_check!(InverseInductance{per_H: x.clone()}, y.clone(), InverseInductance{per_H: xy.clone()}, InverseInductance{per_H: xovery.clone()});
+		mul_div_check!(InverseLuminousFlux{per_lm: x.clone()}, y.clone(), InverseLuminousFlux{per_lm: xy.clone()}, InverseLuminousFlux{per_lm: xovery.clone()});
+		mul_div_check!(InverseMagneticFlux{per_Wb: x.clone()}, y.clone(), InverseMagneticFlux{per_Wb: xy.clone()}, InverseMagneticFlux{per_Wb: xovery.clone()});
+		mul_div_check!(InverseMagneticFluxDensity{m2_per_Wb: x.clone()}, y.clone(), InverseMagneticFluxDensity{m2_per_Wb: xy.clone()}, InverseMagneticFluxDensity{m2_per_Wb: xovery.clone()});
+		mul_div_check!(InverseVoltage{per_V: x.clone()}, y.clone(), InverseVoltage{per_V: xy.clone()}, InverseVoltage{per_V: xovery.clone()});
+		mul_div_check!(InverseAngle{per_rad: x.clone()}, y.clone(), InverseAngle{per_rad: xy.clone()}, InverseAngle{per_rad: xovery.clone()});
+		mul_div_check!(InverseArea{per_m2: x.clone()}, y.clone(), InverseArea{per_m2: xy.clone()}, InverseArea{per_m2: xovery.clone()});
+		mul_div_check!(InverseSolidAngle{per_sr: x.clone()}, y.clone(), InverseSolidAngle{per_sr: xy.clone()}, InverseSolidAngle{per_sr: xovery.clone()});
+		mul_div_check!(InverseVolume{per_m3: x.clone()}, y.clone(), InverseVolume{per_m3: xy.clone()}, InverseVolume{per_m3: xovery.clone()});
+		mul_div_check!(AreaPerMass{m2_per_kg: x.clone()}, y.clone(), AreaPerMass{m2_per_kg: xy.clone()}, AreaPerMass{m2_per_kg: xovery.clone()});
+		mul_div_check!(InverseAcceleration{s2pm: x.clone()}, y.clone(), InverseAcceleration{s2pm: xy.clone()}, InverseAcceleration{s2pm: xovery.clone()});
+		mul_div_check!(InverseAngularAcceleration{s2prad: x.clone()}, y.clone(), InverseAngularAcceleration{s2prad: xy.clone()}, InverseAngularAcceleration{s2prad: xovery.clone()});
+		mul_div_check!(InverseAngularMomentum{s_per_kgm2rad: x.clone()}, y.clone(), InverseAngularMomentum{s_per_kgm2rad: xy.clone()}, InverseAngularMomentum{s_per_kgm2rad: xovery.clone()});
+		mul_div_check!(InverseAngularVelocity{s_per_rad: x.clone()}, y.clone(), InverseAngularVelocity{s_per_rad: xy.clone()}, InverseAngularVelocity{s_per_rad: xovery.clone()});
+		mul_div_check!(InverseEnergy{per_J: x.clone()}, y.clone(), InverseEnergy{per_J: xy.clone()}, InverseEnergy{per_J: xovery.clone()});
+		mul_div_check!(InverseForce{per_N: x.clone()}, y.clone(), InverseForce{per_N: xy.clone()}, InverseForce{per_N: xovery.clone()});
+		mul_div_check!(InverseMomentOfInertia{per_kgm2: x.clone()}, y.clone(), InverseMomentOfInertia{per_kgm2: xy.clone()}, InverseMomentOfInertia{per_kgm2: xovery.clone()});
+		mul_div_check!(InverseMomentum{s_per_kgm: x.clone()}, y.clone(), InverseMomentum{s_per_kgm: xy.clone()}, InverseMomentum{s_per_kgm: xovery.clone()});
+		mul_div_check!(InversePower{per_W: x.clone()}, y.clone(), InversePower{per_W: xy.clone()}, InversePower{per_W: xovery.clone()});
+		mul_div_check!(InversePressure{per_Pa: x.clone()}, y.clone(), InversePressure{per_Pa: xy.clone()}, InversePressure{per_Pa: xovery.clone()});
+		mul_div_check!(InverseTorque{per_Nm: x.clone()}, y.clone(), InverseTorque{per_Nm: xy.clone()}, InverseTorque{per_Nm: xovery.clone()});
+		mul_div_check!(TimePerDistance{spm: x.clone()}, y.clone(), TimePerDistance{spm: xy.clone()}, TimePerDistance{spm: xovery.clone()});
+		mul_div_check!(VolumePerMass{m3_per_kg: x.clone()}, y.clone(), VolumePerMass{m3_per_kg: xy.clone()}, VolumePerMass{m3_per_kg: xovery.clone()});
+		mul_div_check!(InverseAbsorbedDose{per_Gy: x.clone()}, y.clone(), InverseAbsorbedDose{per_Gy: xy.clone()}, InverseAbsorbedDose{per_Gy: xovery.clone()});
+		mul_div_check!(InverseDoseEquivalent{per_Sv: x.clone()}, y.clone(), InverseDoseEquivalent{per_Sv: xy.clone()}, InverseDoseEquivalent{per_Sv: xovery.clone()});
+		mul_div_check!(TemperatureInterval{K: x.clone()}, y.clone(), TemperatureInterval{K: xy.clone()}, TemperatureInterval{K: xovery.clone()});
+		mul_div_check!(InverseJerk{s3pm: x.clone()}, y.clone(), InverseJerk{s3pm: xy.clone()}, InverseJerk{s3pm: xovery.clone()});
+		mul_div_check!(Jerk{mps3: x.clone()}, y.clone(), Jerk{mps3: xy.clone()}, Jerk{mps3: xovery.clone()});
+		mul_div_check!(DynamicViscosity{Pas: x.clone()}, y.clone(), DynamicViscosity{Pas: xy.clone()}, DynamicViscosity{Pas: xovery.clone()});
+		mul_div_check!(InverseDynamicViscosity{per_Pas: x.clone()}, y.clone(), InverseDynamicViscosity{per_Pas: xy.clone()}, InverseDynamicViscosity{per_Pas: xovery.clone()});
+		mul_div_check!(InverseKinematicViscosity{s_per_m2: x.clone()}, y.clone(), InverseKinematicViscosity{s_per_m2: xy.clone()}, InverseKinematicViscosity{s_per_m2: xovery.clone()});
+		mul_div_check!(KinematicViscosity{m2ps: x.clone()}, y.clone(), KinematicViscosity{m2ps: xy.clone()}, KinematicViscosity{m2ps: xovery.clone()});
+		mul_div_check!(InverseMassFlowRate{s_per_kg: x.clone()}, y.clone(), InverseMassFlowRate{s_per_kg: xy.clone()}, InverseMassFlowRate{s_per_kg: xovery.clone()});
+		mul_div_check!(InverseVolumetricFlowRate{s_per_m3: x.clone()}, y.clone(), InverseVolumetricFlowRate{s_per_m3: xy.clone()}, InverseVolumetricFlowRate{s_per_m3: xovery.clone()});
+		mul_div_check!(MassFlowRate{kgps: x.clone()}, y.clone(), MassFlowRate{kgps: xy.clone()}, MassFlowRate{kgps: xovery.clone()});
+		mul_div_check!(VolumetricFlowRate{m3ps: x.clone()}, y.clone(), VolumetricFlowRate{m3ps: xy.clone()}, VolumetricFlowRate{m3ps: xovery.clone()});
+		mul_div_check!(Entropy{J_per_K: x.clone()}, y.clone(), Entropy{J_per_K: xy.clone()}, Entropy{J_per_K: xovery.clone()});
+		mul_div_check!(HeatCapacity{J_per_K: x.clone()}, y.clone(), HeatCapacity{J_per_K: xy.clone()}, HeatCapacity{J_per_K: xovery.clone()});
+		mul_div_check!(InverseEntropy{K_per_J: x.clone()}, y.clone(), InverseEntropy{K_per_J: xy.clone()}, InverseEntropy{K_per_J: xovery.clone()});
+		mul_div_check!(InverseHeatCapacity{K_per_J: x.clone()}, y.clone(), InverseHeatCapacity{K_per_J: xy.clone()}, InverseHeatCapacity{K_per_J: xovery.clone()});
+		mul_div_check!(ThermalConductance{W_per_K: x.clone()}, y.clone(), ThermalConductance{W_per_K: xy.clone()}, ThermalConductance{W_per_K: xovery.clone()});
+		mul_div_check!(ThermalConductivity{W_per_mK: x.clone()}, y.clone(), ThermalConductivity{W_per_mK: xy.clone()}, ThermalConductivity{W_per_mK: xovery.clone()});
+		mul_div_check!(ThermalResistance{K_per_W: x.clone()}, y.clone(), ThermalResistance{K_per_W: xy.clone()}, ThermalResistance{K_per_W: xovery.clone()});
+		mul_div_check!(ThermalResistivity{mK_per_W: x.clone()}, y.clone(), ThermalResistivity{mK_per_W: xy.clone()}, ThermalResistivity{mK_per_W: xovery.clone()});
+		mul_div_check!(ElectricField{Vpm: x.clone()}, y.clone(), ElectricField{Vpm: xy.clone()}, ElectricField{Vpm: xovery.clone()});
+		mul_div_check!(InverseElectricField{m_per_V: x.clone()}, y.clone(), InverseElectricField{m_per_V: xy.clone()}, InverseElectricField{m_per_V: xovery.clone()});
+		mul_div_check!(InverseMagneticFieldStrength{m_per_A: x.clone()}, y.clone(), InverseMagneticFieldStrength{m_per_A: xy.clone()}, InverseMagneticFieldStrength{m_per_A: xovery.clone()});
+		mul_div_check!(MagneticFieldStrength{Apm: x.clone()}, y.clone(), MagneticFieldStrength{Apm: xy.clone()}, MagneticFieldStrength{Apm: xovery.clone()});
+		mul_div_check!(InverseLinearChargeDensity{m_per_C: x.clone()}, y.clone(), InverseLinearChargeDensity{m_per_C: xy.clone()}, InverseLinearChargeDensity{m_per_C: xovery.clone()});
+		mul_div_check!(InverseSurfaceChargeDensity{m2_per_C: x.clone()}, y.clone(), InverseSurfaceChargeDensity{m2_per_C: xy.clone()}, InverseSurfaceChargeDensity{m2_per_C: xovery.clone()});
+		mul_div_check!(InverseVolumeChargeDensity{m3_per_C: x.clone()}, y.clone(), InverseVolumeChargeDensity{m3_per_C: xy.clone()}, InverseVolumeChargeDensity{m3_per_C: xovery.clone()});
+		mul_div_check!(LinearChargeDensity{Cpm: x.clone()}, y.clone(), LinearChargeDensity{Cpm: xy.clone()}, LinearChargeDensity{Cpm: xovery.clone()});
+		mul_div_check!(SurfaceChargeDensity{Cpm2: x.clone()}, y.clone(), SurfaceChargeDensity{Cpm2: xy.clone()}, SurfaceChargeDensity{Cpm2: xovery.clone()});
+		mul_div_check!(VolumeChargeDensity{Cpm3: x.clone()}, y.clone(), VolumeChargeDensity{Cpm3: xy.clone()}, VolumeChargeDensity{Cpm3: xovery.clone()});
+		mul_div_check!(InverseIrradiance{m2_per_W: x.clone()}, y.clone(), InverseIrradiance{m2_per_W: xy.clone()}, InverseIrradiance{m2_per_W: xovery.clone()});
+		mul_div_check!(InverseRadiance{m2sr_per_W: x.clone()}, y.clone(), InverseRadiance{m2sr_per_W: xy.clone()}, InverseRadiance{m2sr_per_W: xovery.clone()});
+		mul_div_check!(Irradiance{W_per_m2: x.clone()}, y.clone(), Irradiance{W_per_m2: xy.clone()}, Irradiance{W_per_m2: xovery.clone()});
+		mul_div_check!(Radiance{W_per_m2sr: x.clone()}, y.clone(), Radiance{W_per_m2sr: xy.clone()}, Radiance{W_per_m2sr: xovery.clone()});
+		mul_div_check!(InverseMolarEnergy{mol_per_J: x.clone()}, y.clone(), InverseMolarEnergy{mol_per_J: xy.clone()}, InverseMolarEnergy{mol_per_J: xovery.clone()});
+		mul_div_check!(InverseMolarEntropy{molK_per_J: x.clone()}, y.clone(), InverseMolarEntropy{molK_per_J: xy.clone()}, InverseMolarEntropy{molK_per_J: xovery.clone()});
+		mul_div_check!(MolarEnergy{J_per_mol: x.clone()}, y.clone(), MolarEnergy{J_per_mol: xy.clone()}, MolarEnergy{J_per_mol: xovery.clone()});
+		mul_div_check!(MolarEntropy{J_per_molK: x.clone()}, y.clone(), MolarEntropy{J_per_molK: xy.clone()}, MolarEntropy{J_per_molK: xovery.clone()});
+		mul_div_check!(AbsorbedDoseRate{Gy_per_s: x.clone()}, y.clone(), AbsorbedDoseRate{Gy_per_s: xy.clone()}, AbsorbedDoseRate{Gy_per_s: xovery.clone()});
+		mul_div_check!(DoseEquivalentRate{Sv_per_s: x.clone()}, y.clone(), DoseEquivalentRate{Sv_per_s: xy.clone()}, DoseEquivalentRate{Sv_per_s: xovery.clone()});
+		mul_div_check!(InverseAbsorbedDoseRate{s_per_Gy: x.clone()}, y.clone(), InverseAbsorbedDoseRate{s_per_Gy: xy.clone()}, InverseAbsorbedDoseRate{s_per_Gy: xovery.clone()});
+		mul_div_check!(InverseDoseEquivalentRate{s_per_Sv: x.clone()}, y.clone(), InverseDoseEquivalentRate{s_per_Sv: xy.clone()}, InverseDoseEquivalentRate{s_per_Sv: xovery.clone()});
+	}
+}
+
+	#[test]
+	#[cfg(feature="num-complex")]
+	fn test_complex_scalar_multiply() {
+		use num_complex::{Complex32, Complex64};
+		let x = Complex64::new(1.2, 3.4);
+		let y = Complex64::new(5.6, 7.8);
+		check_scalar_mul_div!(x, y);
+		let x = Complex32::new(1.2, 3.4);
+		let y = Complex32::new(5.6, 7.8);
+		check_scalar_mul_div!(x, y);
+	}
+
+	#[test]
+	#[cfg(feature="num-bigfloat")]
+	fn test_bigfloat_scalar_multiply() {
+		use num_bigfloat::BigFloat;
+		let x = BigFloat::from(4.2);
+		let y = BigFloat::from(2.1);
+		check_scalar_mul_div!(x, y);
+	}
+
+	#[test]
+	#[cfg(feature="num-rational")]
+	fn test_rational_scalar_multiply() {
+		use num_rational::{Rational64, BigRational};
+		let x = Rational64::new(21, 5);
+		let y = Rational64::new(21, 10);
+		check_scalar_mul_div!(x, y);
+		let x = BigRational::new(21.into(), 5.into());
+		let y = BigRational::new(21.into(), 10.into());
+		check_scalar_mul_div!(x, y);
+	}
+
+	///// Place generated unit tests below this comment /////
+
+	#[test]
+	fn unit_names_and_symbols_test() {
+		assert!(Amount::<f64>::unit_name().eq("moles"));
+		assert!(Amount::<f64>::unit_symbol().eq("mol"));
+		assert!(Current::<f64>::unit_name().eq("amperes"));
+		assert!(Current::<f64>::unit_symbol().eq("A"));
+		assert!(Distance::<f64>::unit_name().eq("meters"));
+		assert!(Distance::<f64>::unit_symbol().eq("m"));
+		assert!(Luminosity::<f64>::unit_name().eq("candela"));
+		assert!(Luminosity::<f64>::unit_symbol().eq("cd"));
+		assert!(Mass::<f64>::unit_name().eq("kilograms"));
+		assert!(Mass::<f64>::unit_symbol().eq("kg"));
+		assert!(Temperature::<f64>::unit_name().eq("degrees kelvin"));
+		assert!(Temperature::<f64>::unit_symbol().eq("K"));
+		assert!(Time::<f64>::unit_name().eq("seconds"));
+		assert!(Time::<f64>::unit_symbol().eq("s"));
+		assert!(CatalyticActivity::<f64>::unit_name().eq("moles per second"));
+		assert!(CatalyticActivity::<f64>::unit_symbol().eq("mol/s"));
+		assert!(Concentration::<f64>::unit_name().eq("moles per cubic meter"));
+		assert!(Concentration::<f64>::unit_symbol().eq("mol/m³"));
+		assert!(Molality::<f64>::unit_name().eq("moles per kilogram"));
+		assert!(Molality::<f64>::unit_symbol().eq("mol/kg"));
+		assert!(MolarMass::<f64>::unit_name().eq("kilograms per mole"));
+		assert!(MolarMass::<f64>::unit_symbol().eq("kg/mol"));
+		assert!(SpecificHeatCapacity::<f64>::unit_name().eq("joules per kilogram per kelvin"));
+		assert!(SpecificHeatCapacity::<f64>::unit_symbol().eq("J/kg·K"));
+		assert!(Capacitance::<f64>::unit_name().eq("farads"));
+		assert!(Capacitance::<f64>::unit_symbol().eq("F"));
+		assert!(Charge::<f64>::unit_name().eq("coulombs"));
+		assert!(Charge::<f64>::unit_symbol().eq("C"));
+		assert!(Conductance::<f64>::unit_name().eq("siemens"));
+		assert!(Conductance::<f64>::unit_symbol().eq("S"));
+		assert!(Illuminance::<f64>::unit_name().eq("lux"));
+		assert!(Illuminance::<f64>::unit_symbol().eq("lux"));
+		assert!(Inductance::<f64>::unit_name().eq("henries"));
+		assert!(Inductance::<f64>::unit_symbol().eq("H"));
+		assert!(LuminousFlux::<f64>::unit_name().eq("lumens"));
+		assert!(LuminousFlux::<f64>::unit_symbol().eq("lm"));
+		assert!(MagneticFlux::<f64>::unit_name().eq("webers"));
+		assert!(MagneticFlux::<f64>::unit_symbol().eq("Wb"));
+		assert!(MagneticFluxDensity::<f64>::unit_name().eq("teslas"));
+		assert!(MagneticFluxDensity::<f64>::unit_symbol().eq("T"));
+		assert!(Resistance::<f64>::unit_name().eq("ohms"));
+		assert!(Resistance::<f64>::unit_symbol().eq("Ohm"));
+		assert!(Voltage::<f64>::unit_name().eq("volts"));
+		assert!(Voltage::<f64>::unit_symbol().eq("V"));
+		assert!(Angle::<f64>::unit_name().eq("radians"));
+		assert!(Angle::<f64>::unit_symbol().eq("rad"));
+		assert!(Area::<f64>::unit_name().eq("square meters"));
+		assert!(Area::<f64>::unit_symbol().eq("m²"));
+		assert!(SolidAngle::<f64>::unit_name().eq("steradian"));
+		assert!(SolidAngle::<f64>::unit_symbol().eq("sr"));
+		assert!(Volume::<f64>::unit_name().eq("cubic meters"));
+		assert!(Volume::<f64>::unit_symbol().eq("m³"));
+		assert!(Acceleration::<f64>::unit_name().eq("meters per second squared"));
+		assert!(Acceleration::<f64>::unit_symbol().eq("m/s²"));
+		assert!(AngularAcceleration::<f64>::unit_name().eq("radians per second squared"));
+		assert!(AngularAcceleration::<f64>::unit_symbol().eq("rad/s²"));
+		assert!(AngularMomentum::<f64>::unit_name().eq("kilogram meters squared radians per second"));
+		assert!(AngularMomentum::<f64>::unit_symbol().eq("kg·m²·rad/s"));
+		assert!(AngularVelocity::<f64>::unit_name().eq("radians per second"));
+		assert!(AngularVelocity::<f64>::unit_symbol().eq("rad/s"));
+		assert!(AreaDensity::<f64>::unit_name().eq("kilograms per square meter"));
+		assert!(AreaDensity::<f64>::unit_symbol().eq("kg/m²"));
+		assert!(Density::<f64>::unit_name().eq("kilograms per cubic meter"));
+		assert!(Density::<f64>::unit_symbol().eq("kg/m³"));
+		assert!(Energy::<f64>::unit_name().eq("joules"));
+		assert!(Energy::<f64>::unit_symbol().eq("J"));
+		assert!(Force::<f64>::unit_name().eq("newtons"));
+		assert!(Force::<f64>::unit_symbol().eq("N"));
+		assert!(Frequency::<f64>::unit_name().eq("hertz"));
+		assert!(Frequency::<f64>::unit_symbol().eq("Hz"));
+		assert!(MomentOfInertia::<f64>::unit_name().eq("kilogram meters squared"));
+		assert!(MomentOfInertia::<f64>::unit_symbol().eq("kg·m²"));
+		assert!(Momentum::<f64>::unit_name().eq("kilogram meters per second"));
+		assert!(Momentum::<f64>::unit_symbol().eq("kg·m/s"));
+		assert!(Power::<f64>::unit_name().eq("watts"));
+		assert!(Power::<f64>::unit_symbol().eq("W"));
+		assert!(Pressure::<f64>::unit_name().eq("pascals"));
+		assert!(Pressure::<f64>::unit_symbol().eq("Pa"));
+		assert!(Torque::<f64>::unit_name().eq("newton meters"));
+		assert!(Torque::<f64>::unit_symbol().eq("Nm"));
+		assert!(Velocity::<f64>::unit_name().eq("meters per second"));
+		assert!(Velocity::<f64>::unit_symbol().eq("m/s"));
+		assert!(AbsorbedDose::<f64>::unit_name().eq("grays"));
+		assert!(AbsorbedDose::<f64>::unit_symbol().eq("Gy"));
+		assert!(DoseEquivalent::<f64>::unit_name().eq("sieverts"));
+		assert!(DoseEquivalent::<f64>::unit_symbol().eq("Sv"));
+		assert!(Radioactivity::<f64>::unit_name().eq("becquerels"));
+		assert!(Radioactivity::<f64>::unit_symbol().eq("Bq"));
+		assert!(InverseAmount::<f64>::unit_name().eq("inverse moles"));
+		assert!(InverseAmount::<f64>::unit_symbol().eq("1/mol"));
+		assert!(InverseCurrent::<f64>::unit_name().eq("inverse amperes"));
+		assert!(InverseCurrent::<f64>::unit_symbol().eq("1/A"));
+		assert!(InverseDistance::<f64>::unit_name().eq("inverse meters"));
+		assert!(InverseDistance::<f64>::unit_symbol().eq("1/m"));
+		assert!(InverseLuminosity::<f64>::unit_name().eq("inverse candela"));
+		assert!(InverseLuminosity::<f64>::unit_symbol().eq("1/cd"));
+		assert!(InverseMass::<f64>::unit_name().eq("inverse kilograms"));
+		assert!(InverseMass::<f64>::unit_symbol().eq("1/kg"));
+		assert!(InverseTemperature::<f64>::unit_name().eq("inverse degrees kelvin"));
+		assert!(InverseTemperature::<f64>::unit_symbol().eq("1/K"));
+		assert!(InverseCatalyticActivity::<f64>::unit_name().eq("seconds per mole"));
+		assert!(InverseCatalyticActivity::<f64>::unit_symbol().eq("s/mol"));
+		assert!(InverseSpecificHeatCapacity::<f64>::unit_name().eq("kilogram per kelvin per joules"));
+		assert!(InverseSpecificHeatCapacity::<f64>::unit_symbol().eq("kg·K/J"));
+		assert!(MolarVolume::<f64>::unit_name().eq("cubic meters per mole"));
+		assert!(MolarVolume::<f64>::unit_symbol().eq("m³/mol"));
+		assert!(AreaPerLumen::<f64>::unit_name().eq("square meters per lumen"));
+		assert!(AreaPerLumen::<f64>::unit_symbol().eq("m²/lm"));
+		assert!(Elastance::<f64>::unit_name().eq("inverse farads"));
+		assert!(Elastance::<f64>::unit_symbol().eq("1/F"));
+		assert!(InverseCharge::<f64>::unit_name().eq("inverse coulombs"));
+		assert!(InverseCharge::<f64>::unit_symbol().eq("1/C"));
+		assert!(InverseInductance::<f64>::unit_name().eq("inverse henries"));
+		assert!(InverseInductance::<f64>::unit_symbol().eq("1/H"));
+		assert!(InverseLuminousFlux::<f64>::unit_name().eq("inverse lumens"));
+		assert!(InverseLuminousFlux::<f64>::unit_symbol().eq("1/lm"));
+		assert!(InverseMagneticFlux::<f64>::unit_name().eq("inverse webers"));
+		assert!(InverseMagneticFlux::<f64>::unit_symbol().eq("1/Wb"));
+		assert!(InverseMagneticFluxDensity::<f64>::unit_name().eq("square meters per weber"));
+		assert!(InverseMagneticFluxDensity::<f64>::unit_symbol().eq("m²/Wb"));
+		assert!(InverseVoltage::<f64>::unit_name().eq("inverse volts"));
+		assert!(InverseVoltage::<f64>::unit_symbol().eq("1/V"));
+		assert!(InverseAngle::<f64>::unit_name().eq("inverse radians"));
+		assert!(InverseAngle::<f64>::unit_symbol().eq("1/rad"));
+		assert!(InverseArea::<f64>::unit_name().eq("inverse square meters"));
+		assert!(InverseArea::<f64>::unit_symbol().eq("1/m²"));
+		assert!(InverseSolidAngle::<f64>::unit_name().eq("inverse steradian"));
+		assert!(InverseSolidAngle::<f64>::unit_symbol().eq("1/sr"));
+		assert!(InverseVolume::<f64>::unit_name().eq("inverse cubic meters"));
+		assert!(InverseVolume::<f64>::unit_symbol().eq("1/m³"));
+		assert!(AreaPerMass::<f64>::unit_name().eq("square meters per kilogram"));
+		assert!(AreaPerMass::<f64>::unit_symbol().eq("m²/kg"));
+		assert!(InverseAcceleration::<f64>::unit_name().eq("seconds squared per meter"));
+		assert!(InverseAcceleration::<f64>::unit_symbol().eq("s²/m"));
+		assert!(InverseAngularAcceleration::<f64>::unit_name().eq("seconds squared per radian"));
+		assert!(InverseAngularAcceleration::<f64>::unit_symbol().eq("s²/rad"));
+		assert!(InverseAngularMomentum::<f64>::unit_name().eq("seconds per kilogram meters squared radian"));
+		assert!(InverseAngularMomentum::<f64>::unit_symbol().eq("s/kg·m²·rad"));
+		assert!(InverseAngularVelocity::<f64>::unit_name().eq("seconds per radian"));
+		assert!(InverseAngularVelocity::<f64>::unit_symbol().eq("s/rad"));
+		assert!(InverseEnergy::<f64>::unit_name().eq("inverse joules"));
+		assert!(InverseEnergy::<f64>::unit_symbol().eq("1/J"));
+		assert!(InverseForce::<f64>::unit_name().eq("inverse newtons"));
+		assert!(InverseForce::<f64>::unit_symbol().eq("1/N"));
+		assert!(InverseMomentOfInertia::<f64>::unit_name().eq("inverse kilogram meters squared"));
+		assert!(InverseMomentOfInertia::<f64>::unit_symbol().eq("1/kg·m²"));
+		assert!(InverseMomentum::<f64>::unit_name().eq("seconds per kilogram meter"));
+		assert!(InverseMomentum::<f64>::unit_symbol().eq("s/kg·m"));
+		assert!(InversePower::<f64>::unit_name().eq("inverse watts"));
+		assert!(InversePower::<f64>::unit_symbol().eq("1/W"));
+		assert!(InversePressure::<f64>::unit_name().eq("inverse pascals"));
+		assert!(InversePressure::<f64>::unit_symbol().eq("1/Pa"));
+		assert!(InverseTorque::<f64>::unit_name().eq("inverse newton meters"));
+		assert!(InverseTorque::<f64>::unit_symbol().eq("1/Nm"));
+		assert!(TimePerDistance::<f64>::unit_name().eq("seconds per meter"));
+		assert!(TimePerDistance::<f64>::unit_symbol().eq("s/m"));
+		assert!(VolumePerMass::<f64>::unit_name().eq("cubic meters per kilogram"));
+		assert!(VolumePerMass::<f64>::unit_symbol().eq("m³/kg"));
+		assert!(InverseAbsorbedDose::<f64>::unit_name().eq("inverse grays"));
+		assert!(InverseAbsorbedDose::<f64>::unit_symbol().eq("1/Gy"));
+		assert!(InverseDoseEquivalent::<f64>::unit_name().eq("inverse sieverts"));
+		assert!(InverseDoseEquivalent::<f64>::unit_symbol().eq("1/Sv"));
+		assert!(TemperatureInterval::<f64>::unit_name().eq("degrees kelvin"));
+		assert!(TemperatureInterval::<f64>::unit_symbol().eq("K"));
+		assert!(InverseJerk::<f64>::unit_name().eq("seconds cubed per meter"));
+		assert!(InverseJerk::<f64>::unit_symbol().eq("s³/m"));
+		assert!(Jerk::<f64>::unit_name().eq("meters per second cubed"));
+		assert!(Jerk::<f64>::unit_symbol().eq("m/s³"));
+		assert!(DynamicViscosity::<f64>::unit_name().eq("pascal seconds"));
+		assert!(DynamicViscosity::<f64>::unit_symbol().eq("Pa·s"));
+		assert!(InverseDynamicViscosity::<f64>::unit_name().eq("inverse pascal seconds"));
+		assert!(InverseDynamicViscosity::<f64>::unit_symbol().eq("1/Pa·s"));
+		assert!(InverseKinematicViscosity::<f64>::unit_name().eq("seconds per square meter"));
+		assert!(InverseKinematicViscosity::<f64>::unit_symbol().eq("s/m²"));
+		assert!(KinematicViscosity::<f64>::unit_name().eq("square meters per second"));
+		assert!(KinematicViscosity::<f64>::unit_symbol().eq("m²/s"));
+		assert!(InverseMassFlowRate::<f64>::unit_name().eq("seconds per kilogram"));
+		assert!(InverseMassFlowRate::<f64>::unit_symbol().eq("s/kg"));
+		assert!(InverseVolumetricFlowRate::<f64>::unit_name().eq("seconds per cubic meter"));
+		assert!(InverseVolumetricFlowRate::<f64>::unit_symbol().eq("s/m³"));
+		assert!(MassFlowRate::<f64>::unit_name().eq("kilograms per second"));
+		assert!(MassFlowRate::<f64>::unit_symbol().eq("kg/s"));
+		assert!(VolumetricFlowRate::<f64>::unit_name().eq("cubic meters per second"));
+		assert!(VolumetricFlowRate::<f64>::unit_symbol().eq("m³/s"));
+		assert!(Entropy::<f64>::unit_name().eq("joules per kelvin"));
+		assert!(Entropy::<f64>::unit_symbol().eq("J/K"));
+		assert!(HeatCapacity::<f64>::unit_name().eq("joules per kelvin"));
+		assert!(HeatCapacity::<f64>::unit_symbol().eq("J/K"));
+		assert!(InverseEntropy::<f64>::unit_name().eq("kelvin per joule"));
+		assert!(InverseEntropy::<f64>::unit_symbol().eq("K/J"));
+		assert!(InverseHeatCapacity::<f64>::unit_name().eq("kelvin per joule"));
+		assert!(InverseHeatCapacity::<f64>::unit_symbol().eq("K/J"));
+		assert!(ThermalConductance::<f64>::unit_name().eq("watts per kelvin"));
+		assert!(ThermalConductance::<f64>::unit_symbol().eq("W/K"));
+		assert!(ThermalConductivity::<f64>::unit_name().eq("watts per meter per kelvin"));
+		assert!(ThermalConductivity::<f64>::unit_symbol().eq("W/m·K"));
+		assert!(ThermalResistance::<f64>::unit_name().eq("kelvin per watt"));
+		assert!(ThermalResistance::<f64>::unit_symbol().eq("K/W"));
+		assert!(ThermalResistivity::<f64>::unit_name().eq("meter kelvin per watt"));
+		assert!(ThermalResistivity::<f64>::unit_symbol().eq("m·K/W"));
+		assert!(ElectricField::<f64>::unit_name().eq("volts per meter"));
+		assert!(ElectricField::<f64>::unit_symbol().eq("V/m"));
+		assert!(InverseElectricField::<f64>::unit_name().eq("meters per volt"));
+		assert!(InverseElectricField::<f64>::unit_symbol().eq("m/V"));
+		assert!(InverseMagneticFieldStrength::<f64>::unit_name().eq("meters per ampere"));
+		assert!(InverseMagneticFieldStrength::<f64>::unit_symbol().eq("m/A"));
+		assert!(MagneticFieldStrength::<f64>::unit_name().eq("amperes per meter"));
+		assert!(MagneticFieldStrength::<f64>::unit_symbol().eq("A/m"));
+		assert!(InverseLinearChargeDensity::<f64>::unit_name().eq("meters per coulomb"));
+		assert!(InverseLinearChargeDensity::<f64>::unit_symbol().eq("m/C"));
+		assert!(InverseSurfaceChargeDensity::<f64>::unit_name().eq("square meters per coulomb"));
+		assert!(InverseSurfaceChargeDensity::<f64>::unit_symbol().eq("m²/C"));
+		assert!(InverseVolumeChargeDensity::<f64>::unit_name().eq("cubic meters per coulomb"));
+		assert!(InverseVolumeChargeDensity::<f64>::unit_symbol().eq("m³/C"));
+		assert!(LinearChargeDensity::<f64>::unit_name().eq("coulombs per meter"));
+		assert!(LinearChargeDensity::<f64>::unit_symbol().eq("C/m"));
+		assert!(SurfaceChargeDensity::<f64>::unit_name().eq("coulombs per square meter"));
+		assert!(SurfaceChargeDensity::<f64>::unit_symbol().eq("C/m²"));
+		assert!(VolumeChargeDensity::<f64>::unit_name().eq("coulombs per cubic meter"));
+		assert!(VolumeChargeDensity::<f64>::unit_symbol().eq("C/m³"));
+		assert!(InverseIrradiance::<f64>::unit_name().eq("square meters per watt"));
+		assert!(InverseIrradiance::<f64>::unit_symbol().eq("m²/W"));
+		assert!(InverseRadiance::<f64>::unit_name().eq("square meter steradians per watt"));
+		assert!(InverseRadiance::<f64>::unit_symbol().eq("m²·sr/W"));
+		assert!(Irradiance::<f64>::unit_name().eq("watts per square meter"));
+		assert!(Irradiance::<f64>::unit_symbol().eq("W/m²"));
+		assert!(Radiance::<f64>::unit_name().eq("watts per square meter per steradian"));
+		assert!(Radiance::<f64>::unit_symbol().eq("W/m²·sr"));
+		assert!(InverseMolarEnergy::<f64>::unit_name().eq("moles per joule"));
+		assert!(InverseMolarEnergy::<f64>::unit_symbol().eq("mol/J"));
+		assert!(InverseMolarEntropy::<f64>::unit_name().eq("mole kelvins per joule"));
+		assert!(InverseMolarEntropy::<f64>::unit_symbol().eq("mol·K/J"));
+		assert!(MolarEnergy::<f64>::unit_name().eq("joules per mole"));
+		assert!(MolarEnergy::<f64>::unit_symbol().eq("J/mol"));
+		assert!(MolarEntropy::<f64>::unit_name().eq("joules per mole per kelvin"));
+		assert!(MolarEntropy::<f64>::unit_symbol().eq("J/mol·K"));
+		assert!(AbsorbedDoseRate::<f64>::unit_name().eq("grays per second"));
+		assert!(AbsorbedDoseRate::<f64>::unit_symbol().eq("Gy/s"));
+		assert!(DoseEquivalentRate::<f64>::unit_name().eq("sieverts per second"));
+		assert!(DoseEquivalentRate::<f64>::unit_symbol().eq("Sv/s"));
+		assert!(InverseAbsorbedDoseRate::<f64>::unit_name().eq("seconds per gray"));
+		assert!(InverseAbsorbedDoseRate::<f64>::unit_symbol().eq("s/Gy"));
+		assert!(InverseDoseEquivalentRate::<f64>::unit_name().eq("seconds per sievert"));
+		assert!(InverseDoseEquivalentRate::<f64>::unit_symbol().eq("s/Sv"));
+	}
+
+	#[test]
+	fn unit_print_display_test() {
+		println!("{}", Amount{mol: 1});
+		println!("{}", Current{A: 1});
+		println!("{}", Distance{m: 1});
+		println!("{}", Luminosity{cd: 1});
+		println!("{}", Mass{kg: 1});
+		println!("{}", Temperature{K: 1});
+		println!("{}", Time{s: 1});
+		println!("{}", CatalyticActivity{molps: 1});
+		println!("{}", Concentration{molpm3: 1});
+		println!("{}", Molality{molpkg: 1});
+		println!("{}", MolarMass{kgpmol: 1});
+		println!("{}", SpecificHeatCapacity{J_per_kgK: 1});
+		println!("{}", Capacitance{F: 1});
+		println!("{}", Charge{C: 1});
+		println!("{}", Conductance{S: 1});
+		println!("{}", Illuminance{lux: 1});
+		println!("{}", Inductance{H: 1});
+		println!("{}", LuminousFlux{lm: 1});
+		println!("{}", MagneticFlux{Wb: 1});
+		println!("{}", MagneticFluxDensity{T: 1});
+		println!("{}", Resistance{Ohm: 1});
+		println!("{}", Voltage{V: 1});
+		println!("{}", Angle{rad: 1});
+		println!("{}", Area{m2: 1});
+		println!("{}", SolidAngle{sr: 1});
+		println!("{}", Volume{m3: 1});
+		println!("{}", Acceleration{mps2: 1});
+		println!("{}", AngularAcceleration{radps2: 1});
+		println!("{}", AngularMomentum{kgm2radps: 1});
+		println!("{}", AngularVelocity{radps: 1});
+		println!("{}", AreaDensity{kgpm2: 1});
+		println!("{}", Density{kgpm3: 1});
+		println!("{}", Energy{J: 1});
+		println!("{}", Force{N: 1});
+		println!("{}", Frequency{Hz: 1});
+		println!("{}", MomentOfInertia{kgm2: 1});
+		println!("{}", Momentum{kgmps: 1});
+		println!("{}", Power{W: 1});
+		println!("{}", Pressure{Pa: 1});
+		println!("{}", Torque{Nm: 1});
+		println!("{}", Velocity{mps: 1});
+		println!("{}", AbsorbedDose{Gy: 1});
+		println!("{}", DoseEquivalent{Sv: 1});
+		println!("{}", Radioactivity{Bq: 1});
+		println!("{}", InverseAmount{per_mol: 1});
+		println!("{}", InverseCurrent{per_A: 1});
+		println!("{}", InverseDistance{per_m: 1});
+		println!("{}", InverseLuminosity{per_cd: 1});
+		println!("{}", InverseMass{per_kg: 1});
+		println!("{}", InverseTemperature{per_K: 1});
+		println!("{}", InverseCatalyticActivity{s_per_mol: 1});
+		println!("{}", InverseSpecificHeatCapacity{kgK_per_J: 1});
+		println!("{}", MolarVolume{m3_per_mol: 1});
+		println!("{}", AreaPerLumen{m2_per_lm: 1});
+		println!("{}", Elastance{per_F: 1});
+		println!("{}", InverseCharge{per_C: 1});
+		println!("{}", InverseInductance{per_H: 1});
+		println!("{}", InverseLuminousFlux{per_lm: 1});
+		println!("{}", InverseMagneticFlux{per_Wb: 1});
+		println!("{}", InverseMagneticFluxDensity{m2_per_Wb: 1});
+		println!("{}", InverseVoltage{per_V: 1});
+		println!("{}", InverseAngle{per_rad: 1});
+		println!("{}", InverseArea{per_m2: 1});
+		println!("{}", InverseSolidAngle{per_sr: 1});
+		println!("{}", InverseVolume{per_m3: 1});
+		println!("{}", AreaPerMass{m2_per_kg: 1});
+		println!("{}", InverseAcceleration{s2pm: 1});
+		println!("{}", InverseAngularAcceleration{s2prad: 1});
+		println!("{}", InverseAngularMomentum{s_per_kgm2rad: 1});
+		println!("{}", InverseAngularVelocity{s_per_rad: 1});
+		println!("{}", InverseEnergy{per_J: 1});
+		println!("{}", InverseForce{per_N: 1});
+		println!("{}", InverseMomentOfInertia{per_kgm2: 1});
+		println!("{}", InverseMomentum{s_per_kgm: 1});
+		println!("{}", InversePower{per_W: 1});
+		println!("{}", InversePressure{per_Pa: 1});
+		println!("{}", InverseTorque{per_Nm: 1});
+		println!("{}", TimePerDistance{spm: 1});
+		println!("{}", VolumePerMass{m3_per_kg: 1});
+		println!("{}", InverseAbsorbedDose{per_Gy: 1});
+		println!("{}", InverseDoseEquivalent{per_Sv: 1});
+		println!("{}", TemperatureInterval{K: 1});
+		println!("{}", InverseJerk{s3pm: 1});
+		println!("{}", Jerk{mps3: 1});
+		println!("{}", DynamicViscosity{Pas: 1});
+		println!("{}", InverseDynamicViscosity{per_Pas: 1});
+		println!("{}", InverseKinematicViscosity{s_per_m2: 1});
+		println!("{}", KinematicViscosity{m2ps: 1});
+		println!("{}", InverseMassFlowRate{s_per_kg: 1});
+		println!("{}", InverseVolumetricFlowRate{s_per_m3: 1});
+		println!("{}", MassFlowRate{kgps: 1});
+		println!("{}", VolumetricFlowRate{m3ps: 1});
+		println!("{}", Entropy{J_per_K: 1});
+		println!("{}", HeatCapacity{J_per_K: 1});
+		println!("{}", InverseEntropy{K_per_J: 1});
+		println!("{}", InverseHeatCapacity{K_per_J: 1});
+		println!("{}", ThermalConductance{W_per_K: 1});
+		println!("{}", ThermalConductivity{W_per_mK: 1});
+		println!("{}", ThermalResistance{K_per_W: 1});
+		println!("{}", ThermalResistivity{mK_per_W: 1});
+		println!("{}", ElectricField{Vpm: 1});
+		println!("{}", InverseElectricField{m_per_V: 1});
+		println!("{}", InverseMagneticFieldStrength{m_per_A: 1});
+		println!("{}", MagneticFieldStrength{Apm: 1});
+		println!("{}", InverseLinearChargeDensity{m_per_C: 1});
+		println!("{}", InverseSurfaceChargeDensity{m2_per_C: 1});
+		println!("{}", InverseVolumeChargeDensity{m3_per_C: 1});
+		println!("{}", LinearChargeDensity{Cpm: 1});
+		println!("{}", SurfaceChargeDensity{Cpm2: 1});
+		println!("{}", VolumeChargeDensity{Cpm3: 1});
+		println!("{}", InverseIrradiance{m2_per_W: 1});
+		println!("{}", InverseRadiance{m2sr_per_W: 1});
+		println!("{}", Irradiance{W_per_m2: 1});
+		println!("{}", Radiance{W_per_m2sr: 1});
+		println!("{}", InverseMolarEnergy{mol_per_J: 1});
+		println!("{}", InverseMolarEntropy{molK_per_J: 1});
+		println!("{}", MolarEnergy{J_per_mol: 1});
+		println!("{}", MolarEntropy{J_per_molK: 1});
+		println!("{}", AbsorbedDoseRate{Gy_per_s: 1});
+		println!("{}", DoseEquivalentRate{Sv_per_s: 1});
+		println!("{}", InverseAbsorbedDoseRate{s_per_Gy: 1});
+		println!("{}", InverseDoseEquivalentRate{s_per_Sv: 1});
+	}
+
+	#[test]
+	fn test_unit_converions() {
+		let x = 4.5f64;
+		let y = 2.5f64;
+		assert_eq!(div_check(&Amount{mol: x}, &Time{s: y}), CatalyticActivity{molps: x/y});
+		assert_eq!(div_check(&Amount{mol: x}, &CatalyticActivity{molps: y}), Time{s: x/y});
+		assert_eq!(div_check(&Amount{mol: x}, &Concentration{molpm3: y}), Volume{m3: x/y});
+		assert_eq!(div_check(&Amount{mol: x}, &Volume{m3: y}), Concentration{molpm3: x/y});
+		assert_eq!(mul_check(&Amount{mol: x}, &Frequency{Hz: y}), CatalyticActivity{molps: x*y});
+		assert_eq!(mul_check(&Current{A: x}, &Time{s: y}), Charge{C: x*y});
+		assert_eq!(div_check(&Current{A: x}, &Charge{C: y}), Frequency{Hz: x/y});
+		assert_eq!(div_check(&Current{A: x}, &Conductance{S: y}), Voltage{V: x/y});
+		assert_eq!(mul_check(&Current{A: x}, &Inductance{H: y}), MagneticFlux{Wb: x*y});
+		assert_eq!(mul_check(&Current{A: x}, &MagneticFlux{Wb: y}), Energy{J: x*y});
+		assert_eq!(mul_check(&Current{A: x}, &Resistance{Ohm: y}), Voltage{V: x*y});
+		assert_eq!(mul_check(&Current{A: x}, &Voltage{V: y}), Power{W: x*y});
+		assert_eq!(div_check(&Current{A: x}, &Voltage{V: y}), Conductance{S: x/y});
+		assert_eq!(div_check(&Current{A: x}, &Frequency{Hz: y}), Charge{C: x/y});
+		assert_eq!(mul_check(&Distance{m: x}, &Distance{m: y}), Area{m2: x*y});
+		assert_eq!(div_check(&Distance{m: x}, &Time{s: y}), Velocity{mps: x/y});
+		assert_eq!(mul_check(&Distance{m: x}, &Area{m2: y}), Volume{m3: x*y});
+		assert_eq!(mul_check(&Distance{m: x}, &Density{kgpm3: y}), AreaDensity{kgpm2: x*y});
+		assert_eq!(mul_check(&Distance{m: x}, &Force{N: y}), Energy{J: x*y});
+		assert_eq!(mul_check(&Distance{m: x}, &Frequency{Hz: y}), Velocity{mps: x*y});
+		assert_eq!(div_check(&Distance{m: x}, &Velocity{mps: y}), Time{s: x/y});
+		assert_eq!(mul_check(&Luminosity{cd: x}, &SolidAngle{sr: y}), LuminousFlux{lm: x*y});
+		assert_eq!(div_check(&Mass{kg: x}, &Area{m2: y}), AreaDensity{kgpm2: x/y});
+		assert_eq!(div_check(&Mass{kg: x}, &Volume{m3: y}), Density{kgpm3: x/y});
+		assert_eq!(mul_check(&Mass{kg: x}, &Acceleration{mps2: y}), Force{N: x*y});
+		assert_eq!(div_check(&Mass{kg: x}, &AreaDensity{kgpm2: y}), Area{m2: x/y});
+		assert_eq!(div_check(&Mass{kg: x}, &Density{kgpm3: y}), Volume{m3: x/y});
+		assert_eq!(mul_check(&Mass{kg: x}, &Velocity{mps: y}), Momentum{kgmps: x*y});
+		assert_eq!(mul_check(&Mass{kg: x}, &AbsorbedDose{Gy: y}), Energy{J: x*y});
+		assert_eq!(mul_check(&Mass{kg: x}, &DoseEquivalent{Sv: y}), Energy{J: x*y});
+		assert_eq!(mul_check(&Time{s: x}, &Current{A: y}), Charge{C: x*y});
+		assert_eq!(mul_check(&Time{s: x}, &CatalyticActivity{molps: y}), Amount{mol: x*y});
+		assert_eq!(div_check(&Time{s: x}, &Capacitance{F: y}), Resistance{Ohm: x/y});
+		assert_eq!(mul_check(&Time{s: x}, &Conductance{S: y}), Capacitance{F: x*y});
+		assert_eq!(div_check(&Time{s: x}, &Conductance{S: y}), Inductance{H: x/y});
+		assert_eq!(div_check(&Time{s: x}, &Inductance{H: y}), Conductance{S: x/y});
+		assert_eq!(mul_check(&Time{s: x}, &Resistance{Ohm: y}), Inductance{H: x*y});
+		assert_eq!(div_check(&Time{s: x}, &Resistance{Ohm: y}), Capacitance{F: x/y});
+		assert_eq!(mul_check(&Time{s: x}, &Voltage{V: y}), MagneticFlux{Wb: x*y});
+		assert_eq!(mul_check(&Time{s: x}, &Acceleration{mps2: y}), Velocity{mps: x*y});
+		assert_eq!(mul_check(&Time{s: x}, &AngularAcceleration{radps2: y}), AngularVelocity{radps: x*y});
+		assert_eq!(mul_check(&Time{s: x}, &AngularVelocity{radps: y}), Angle{rad: x*y});
+		assert_eq!(mul_check(&Time{s: x}, &Force{N: y}), Momentum{kgmps: x*y});
+		assert_eq!(mul_check(&Time{s: x}, &Power{W: y}), Energy{J: x*y});
+		assert_eq!(mul_check(&Time{s: x}, &Velocity{mps: y}), Distance{m: x*y});
+		assert_eq!(div_check(&CatalyticActivity{molps: x}, &Amount{mol: y}), Frequency{Hz: x/y});
+		assert_eq!(mul_check(&CatalyticActivity{molps: x}, &Time{s: y}), Amount{mol: x*y});
+		assert_eq!(div_check(&CatalyticActivity{molps: x}, &Frequency{Hz: y}), Amount{mol: x/y});
+		assert_eq!(mul_check(&Concentration{molpm3: x}, &Volume{m3: y}), Amount{mol: x*y});
+		assert_eq!(div_check(&Capacitance{F: x}, &Time{s: y}), Conductance{S: x/y});
+		assert_eq!(div_check(&Capacitance{F: x}, &Conductance{S: y}), Time{s: x/y});
+		assert_eq!(mul_check(&Capacitance{F: x}, &Resistance{Ohm: y}), Time{s: x*y});
+		assert_eq!(mul_check(&Capacitance{F: x}, &Voltage{V: y}), Charge{C: x*y});
+		assert_eq!(mul_check(&Capacitance{F: x}, &Frequency{Hz: y}), Conductance{S: x*y});
+		assert_eq!(div_check(&Charge{C: x}, &Current{A: y}), Time{s: x/y});
+		assert_eq!(div_check(&Charge{C: x}, &Time{s: y}), Current{A: x/y});
+		assert_eq!(div_check(&Charge{C: x}, &Capacitance{F: y}), Voltage{V: x/y});
+		assert_eq!(div_check(&Charge{C: x}, &Conductance{S: y}), MagneticFlux{Wb: x/y});
+		assert_eq!(div_check(&Charge{C: x}, &MagneticFlux{Wb: y}), Conductance{S: x/y});
+		assert_eq!(mul_check(&Charge{C: x}, &Resistance{Ohm: y}), MagneticFlux{Wb: x*y});
+		assert_eq!(mul_check(&Charge{C: x}, &Voltage{V: y}), Energy{J: x*y});
+		assert_eq!(div_check(&Charge{C: x}, &Voltage{V: y}), Capacitance{F: x/y});
+		assert_eq!(mul_check(&Charge{C: x}, &Frequency{Hz: y}), Current{A: x*y});
+		assert_eq!(mul_check(&Conductance{S: x}, &Time{s: y}), Capacitance{F: x*y});
+		assert_eq!(div_check(&Conductance{S: x}, &Capacitance{F: y}), Frequency{Hz: x/y});
+		assert_eq!(mul_check(&Conductance{S: x}, &Inductance{H: y}), Time{s: x*y});
+		assert_eq!(mul_check(&Conductance{S: x}, &MagneticFlux{Wb: y}), Charge{C: x*y});
+		assert_eq!(mul_check(&Conductance{S: x}, &Voltage{V: y}), Current{A: x*y});
+		assert_eq!(div_check(&Conductance{S: x}, &Frequency{Hz: y}), Capacitance{F: x/y});
+		assert_eq!(mul_check(&Illuminance{lux: x}, &Area{m2: y}), LuminousFlux{lm: x*y});
+		assert_eq!(mul_check(&Inductance{H: x}, &Current{A: y}), MagneticFlux{Wb: x*y});
+		assert_eq!(div_check(&Inductance{H: x}, &Time{s: y}), Resistance{Ohm: x/y});
+		assert_eq!(mul_check(&Inductance{H: x}, &Conductance{S: y}), Time{s: x*y});
+		assert_eq!(div_check(&Inductance{H: x}, &Resistance{Ohm: y}), Time{s: x/y});
+		assert_eq!(mul_check(&Inductance{H: x}, &Frequency{Hz: y}), Resistance{Ohm: x*y});
+		assert_eq!(div_check(&LuminousFlux{lm: x}, &Luminosity{cd: y}), SolidAngle{sr: x/y});
+		assert_eq!(div_check(&LuminousFlux{lm: x}, &Illuminance{lux: y}), Area{m2: x/y});
+		assert_eq!(div_check(&LuminousFlux{lm: x}, &Area{m2: y}), Illuminance{lux: x/y});
+		assert_eq!(div_check(&LuminousFlux{lm: x}, &SolidAngle{sr: y}), Luminosity{cd: x/y});
+		assert_eq!(mul_check(&MagneticFlux{Wb: x}, &Current{A: y}), Energy{J: x*y});
+		assert_eq!(div_check(&MagneticFlux{Wb: x}, &Current{A: y}), Inductance{H: x/y});
+		assert_eq!(div_check(&MagneticFlux{Wb: x}, &Time{s: y}), Voltage{V: x/y});
+		assert_eq!(div_check(&MagneticFlux{Wb: x}, &Charge{C: y}), Resistance{Ohm: x/y});
+		assert_eq!(mul_check(&MagneticFlux{Wb: x}, &Conductance{S: y}), Charge{C: x*y});
+		assert_eq!(div_check(&MagneticFlux{Wb: x}, &Inductance{H: y}), Current{A: x/y});
+		assert_eq!(div_check(&MagneticFlux{Wb: x}, &MagneticFluxDensity{T: y}), Area{m2: x/y});
+		assert_eq!(div_check(&MagneticFlux{Wb: x}, &Resistance{Ohm: y}), Charge{C: x/y});
+		assert_eq!(div_check(&MagneticFlux{Wb: x}, &Voltage{V: y}), Time{s: x/y});
+		assert_eq!(div_check(&MagneticFlux{Wb: x}, &Area{m2: y}), MagneticFluxDensity{T: x/y});
+		assert_eq!(mul_check(&MagneticFlux{Wb: x}, &Frequency{Hz: y}), Voltage{V: x*y});
+		assert_eq!(mul_check(&MagneticFluxDensity{T: x}, &Area{m2: y}), MagneticFlux{Wb: x*y});
+		assert_eq!(mul_check(&Resistance{Ohm: x}, &Current{A: y}), Voltage{V: x*y});
+		assert_eq!(mul_check(&Resistance{Ohm: x}, &Time{s: y}), Inductance{H: x*y});
+		assert_eq!(mul_check(&Resistance{Ohm: x}, &Capacitance{F: y}), Time{s: x*y});
+		assert_eq!(mul_check(&Resistance{Ohm: x}, &Charge{C: y}), MagneticFlux{Wb: x*y});
+		assert_eq!(div_check(&Resistance{Ohm: x}, &Inductance{H: y}), Frequency{Hz: x/y});
+		assert_eq!(div_check(&Resistance{Ohm: x}, &Frequency{Hz: y}), Inductance{H: x/y});
+		assert_eq!(mul_check(&Voltage{V: x}, &Current{A: y}), Power{W: x*y});
+		assert_eq!(div_check(&Voltage{V: x}, &Current{A: y}), Resistance{Ohm: x/y});
+		assert_eq!(mul_check(&Voltage{V: x}, &Time{s: y}), MagneticFlux{Wb: x*y});
+		assert_eq!(mul_check(&Voltage{V: x}, &Capacitance{F: y}), Charge{C: x*y});
+		assert_eq!(mul_check(&Voltage{V: x}, &Charge{C: y}), Energy{J: x*y});
+		assert_eq!(mul_check(&Voltage{V: x}, &Conductance{S: y}), Current{A: x*y});
+		assert_eq!(div_check(&Voltage{V: x}, &MagneticFlux{Wb: y}), Frequency{Hz: x/y});
+		assert_eq!(div_check(&Voltage{V: x}, &Resistance{Ohm: y}), Current{A: x/y});
+		assert_eq!(div_check(&Voltage{V: x}, &Frequency{Hz: y}), MagneticFlux{Wb: x/y});
+		assert_eq!(div_check(&Angle{rad: x}, &Time{s: y}), AngularVelocity{radps: x/y});
+		assert_eq!(div_check(&Angle{rad: x}, &AngularVelocity{radps: y}), Time{s: x/y});
+		assert_eq!(mul_check(&Angle{rad: x}, &Frequency{Hz: y}), AngularVelocity{radps: x*y});
+		assert_eq!(mul_check(&Area{m2: x}, &Distance{m: y}), Volume{m3: x*y});
+		assert_eq!(div_check(&Area{m2: x}, &Distance{m: y}), Distance{m: x/y});
+		assert_eq!(mul_check(&Area{m2: x}, &Illuminance{lux: y}), LuminousFlux{lm: x*y});
+		assert_eq!(mul_check(&Area{m2: x}, &MagneticFluxDensity{T: y}), MagneticFlux{Wb: x*y});
+		assert_eq!(mul_check(&Area{m2: x}, &AreaDensity{kgpm2: y}), Mass{kg: x*y});
+		assert_eq!(mul_check(&Area{m2: x}, &Pressure{Pa: y}), Force{N: x*y});
+		assert_eq!(mul_check(&SolidAngle{sr: x}, &Luminosity{cd: y}), LuminousFlux{lm: x*y});
+		assert_eq!(div_check(&Volume{m3: x}, &Distance{m: y}), Area{m2: x/y});
+		assert_eq!(mul_check(&Volume{m3: x}, &Concentration{molpm3: y}), Amount{mol: x*y});
+		assert_eq!(div_check(&Volume{m3: x}, &Area{m2: y}), Distance{m: x/y});
+		assert_eq!(mul_check(&Volume{m3: x}, &Density{kgpm3: y}), Mass{kg: x*y});
+		assert_eq!(mul_check(&Volume{m3: x}, &Pressure{Pa: y}), Energy{J: x*y});
+		assert_eq!(mul_check(&Acceleration{mps2: x}, &Mass{kg: y}), Force{N: x*y});
+		assert_eq!(mul_check(&Acceleration{mps2: x}, &Time{s: y}), Velocity{mps: x*y});
+		assert_eq!(mul_check(&Acceleration{mps2: x}, &AreaDensity{kgpm2: y}), Pressure{Pa: x*y});
+		assert_eq!(div_check(&Acceleration{mps2: x}, &Frequency{Hz: y}), Velocity{mps: x/y});
+		assert_eq!(mul_check(&Acceleration{mps2: x}, &Momentum{kgmps: y}), Power{W: x*y});
+		assert_eq!(div_check(&Acceleration{mps2: x}, &Velocity{mps: y}), Frequency{Hz: x/y});
+		assert_eq!(mul_check(&AngularAcceleration{radps2: x}, &Time{s: y}), AngularVelocity{radps: x*y});
+		assert_eq!(div_check(&AngularAcceleration{radps2: x}, &AngularVelocity{radps: y}), Frequency{Hz: x/y});
+		assert_eq!(div_check(&AngularAcceleration{radps2: x}, &Frequency{Hz: y}), AngularVelocity{radps: x/y});
+		assert_eq!(div_check(&AngularMomentum{kgm2radps: x}, &MomentOfInertia{kgm2: y}), AngularVelocity{radps: x/y});
+		assert_eq!(mul_check(&AngularVelocity{radps: x}, &Time{s: y}), Angle{rad: x*y});
+		assert_eq!(div_check(&AngularVelocity{radps: x}, &Time{s: y}), AngularAcceleration{radps2: x/y});
+		assert_eq!(div_check(&AngularVelocity{radps: x}, &Angle{rad: y}), Frequency{Hz: x/y});
+		assert_eq!(div_check(&AngularVelocity{radps: x}, &AngularAcceleration{radps2: y}), Time{s: x/y});
+		assert_eq!(mul_check(&AngularVelocity{radps: x}, &Frequency{Hz: y}), AngularAcceleration{radps2: x*y});
+		assert_eq!(div_check(&AngularVelocity{radps: x}, &Frequency{Hz: y}), Angle{rad: x/y});
+		assert_eq!(mul_check(&AngularVelocity{radps: x}, &MomentOfInertia{kgm2: y}), AngularMomentum{kgm2radps: x*y});
+		assert_eq!(div_check(&AreaDensity{kgpm2: x}, &Distance{m: y}), Density{kgpm3: x/y});
+		assert_eq!(mul_check(&AreaDensity{kgpm2: x}, &Area{m2: y}), Mass{kg: x*y});
+		assert_eq!(mul_check(&AreaDensity{kgpm2: x}, &Acceleration{mps2: y}), Pressure{Pa: x*y});
+		assert_eq!(div_check(&AreaDensity{kgpm2: x}, &Density{kgpm3: y}), Distance{m: x/y});
+		assert_eq!(mul_check(&Density{kgpm3: x}, &Distance{m: y}), AreaDensity{kgpm2: x*y});
+		assert_eq!(mul_check(&Density{kgpm3: x}, &Volume{m3: y}), Mass{kg: x*y});
+		assert_eq!(div_check(&Energy{J: x}, &Current{A: y}), MagneticFlux{Wb: x/y});
+		assert_eq!(div_check(&Energy{J: x}, &Distance{m: y}), Force{N: x/y});
+		assert_eq!(div_check(&Energy{J: x}, &Time{s: y}), Power{W: x/y});
+		assert_eq!(div_check(&Energy{J: x}, &Charge{C: y}), Voltage{V: x/y});
+		assert_eq!(div_check(&Energy{J: x}, &MagneticFlux{Wb: y}), Current{A: x/y});
+		assert_eq!(div_check(&Energy{J: x}, &Voltage{V: y}), Charge{C: x/y});
+		assert_eq!(div_check(&Energy{J: x}, &Volume{m3: y}), Pressure{Pa: x/y});
+		assert_eq!(div_check(&Energy{J: x}, &Force{N: y}), Distance{m: x/y});
+		assert_eq!(mul_check(&Energy{J: x}, &Frequency{Hz: y}), Power{W: x*y});
+		assert_eq!(div_check(&Energy{J: x}, &Momentum{kgmps: y}), Velocity{mps: x/y});
+		assert_eq!(div_check(&Energy{J: x}, &Power{W: y}), Time{s: x/y});
+		assert_eq!(div_check(&Energy{J: x}, &Pressure{Pa: y}), Volume{m3: x/y});
+		assert_eq!(div_check(&Energy{J: x}, &Velocity{mps: y}), Momentum{kgmps: x/y});
+		assert_eq!(div_check(&Energy{J: x}, &AbsorbedDose{Gy: y}), Mass{kg: x/y});
+		assert_eq!(div_check(&Energy{J: x}, &DoseEquivalent{Sv: y}), Mass{kg: x/y});
+		assert_eq!(mul_check(&Force{N: x}, &Distance{m: y}), Energy{J: x*y});
+		assert_eq!(div_check(&Force{N: x}, &Mass{kg: y}), Acceleration{mps2: x/y});
+		assert_eq!(mul_check(&Force{N: x}, &Time{s: y}), Momentum{kgmps: x*y});
+		assert_eq!(div_check(&Force{N: x}, &Area{m2: y}), Pressure{Pa: x/y});
+		assert_eq!(div_check(&Force{N: x}, &Acceleration{mps2: y}), Mass{kg: x/y});
+		assert_eq!(div_check(&Force{N: x}, &Frequency{Hz: y}), Momentum{kgmps: x/y});
+		assert_eq!(div_check(&Force{N: x}, &Momentum{kgmps: y}), Frequency{Hz: x/y});
+		assert_eq!(div_check(&Force{N: x}, &Pressure{Pa: y}), Area{m2: x/y});
+		assert_eq!(mul_check(&Force{N: x}, &Velocity{mps: y}), Power{W: x*y});
+		assert_eq!(mul_check(&Frequency{Hz: x}, &Amount{mol: y}), CatalyticActivity{molps: x*y});
+		assert_eq!(mul_check(&Frequency{Hz: x}, &Distance{m: y}), Velocity{mps: x*y});
+		assert_eq!(mul_check(&Frequency{Hz: x}, &Capacitance{F: y}), Conductance{S: x*y});
+		assert_eq!(mul_check(&Frequency{Hz: x}, &Charge{C: y}), Current{A: x*y});
+		assert_eq!(mul_check(&Frequency{Hz: x}, &Inductance{H: y}), Resistance{Ohm: x*y});
+		assert_eq!(mul_check(&Frequency{Hz: x}, &MagneticFlux{Wb: y}), Voltage{V: x*y});
+		assert_eq!(mul_check(&Frequency{Hz: x}, &Angle{rad: y}), AngularVelocity{radps: x*y});
+		assert_eq!(mul_check(&Frequency{Hz: x}, &AngularVelocity{radps: y}), AngularAcceleration{radps2: x*y});
+		assert_eq!(mul_check(&Frequency{Hz: x}, &Energy{J: y}), Power{W: x*y});
+		assert_eq!(mul_check(&Frequency{Hz: x}, &Torque{Nm: y}), Power{W: x*y});
+		assert_eq!(mul_check(&Frequency{Hz: x}, &Momentum{kgmps: y}), Force{N: x*y});
+		assert_eq!(mul_check(&Frequency{Hz: x}, &Velocity{mps: y}), Acceleration{mps2: x*y});
+		assert_eq!(div_check(&MomentOfInertia{kgm2: x}, &Mass{kg: y}), Area{m2: x/y});
+		assert_eq!(div_check(&MomentOfInertia{kgm2: x}, &Area{m2: y}), Mass{kg: x/y});
+		assert_eq!(mul_check(&MomentOfInertia{kgm2: x}, &AngularVelocity{radps: y}), AngularMomentum{kgm2radps: x*y});
+		assert_eq!(div_check(&Momentum{kgmps: x}, &Mass{kg: y}), Velocity{mps: x/y});
+		assert_eq!(div_check(&Momentum{kgmps: x}, &Time{s: y}), Force{N: x/y});
+		assert_eq!(mul_check(&Momentum{kgmps: x}, &Acceleration{mps2: y}), Power{W: x*y});
+		assert_eq!(div_check(&Momentum{kgmps: x}, &Force{N: y}), Time{s: x/y});
+		assert_eq!(mul_check(&Momentum{kgmps: x}, &Frequency{Hz: y}), Force{N: x*y});
+		assert_eq!(mul_check(&Momentum{kgmps: x}, &Velocity{mps: y}), Energy{J: x*y});
+		assert_eq!(div_check(&Momentum{kgmps: x}, &Velocity{mps: y}), Mass{kg: x/y});
+		assert_eq!(div_check(&Power{W: x}, &Current{A: y}), Voltage{V: x/y});
+		assert_eq!(mul_check(&Power{W: x}, &Time{s: y}), Energy{J: x*y});
+		assert_eq!(div_check(&Power{W: x}, &Voltage{V: y}), Current{A: x/y});
+		assert_eq!(div_check(&Power{W: x}, &Acceleration{mps2: y}), Momentum{kgmps: x/y});
+		assert_eq!(div_check(&Power{W: x}, &Energy{J: y}), Frequency{Hz: x/y});
+		assert_eq!(div_check(&Power{W: x}, &Torque{Nm: y}), Frequency{Hz: x/y});
+		assert_eq!(div_check(&Power{W: x}, &Force{N: y}), Velocity{mps: x/y});
+		assert_eq!(div_check(&Power{W: x}, &Frequency{Hz: y}), Energy{J: x/y});
+		assert_eq!(div_check(&Power{W: x}, &Momentum{kgmps: y}), Acceleration{mps2: x/y});
+		assert_eq!(div_check(&Power{W: x}, &Velocity{mps: y}), Force{N: x/y});
+		assert_eq!(mul_check(&Pressure{Pa: x}, &Area{m2: y}), Force{N: x*y});
+		assert_eq!(mul_check(&Pressure{Pa: x}, &Volume{m3: y}), Energy{J: x*y});
+		assert_eq!(div_check(&Pressure{Pa: x}, &Acceleration{mps2: y}), AreaDensity{kgpm2: x/y});
+		assert_eq!(div_check(&Pressure{Pa: x}, &AreaDensity{kgpm2: y}), Acceleration{mps2: x/y});
+		assert_eq!(div_check(&Torque{Nm: x}, &Current{A: y}), MagneticFlux{Wb: x/y});
+		assert_eq!(div_check(&Torque{Nm: x}, &Distance{m: y}), Force{N: x/y});
+		assert_eq!(div_check(&Torque{Nm: x}, &Time{s: y}), Power{W: x/y});
+		assert_eq!(div_check(&Torque{Nm: x}, &Charge{C: y}), Voltage{V: x/y});
+		assert_eq!(div_check(&Torque{Nm: x}, &MagneticFlux{Wb: y}), Current{A: x/y});
+		assert_eq!(div_check(&Torque{Nm: x}, &Voltage{V: y}), Charge{C: x/y});
+		assert_eq!(div_check(&Torque{Nm: x}, &Volume{m3: y}), Pressure{Pa: x/y});
+		assert_eq!(div_check(&Torque{Nm: x}, &Force{N: y}), Distance{m: x/y});
+		assert_eq!(mul_check(&Torque{Nm: x}, &Frequency{Hz: y}), Power{W: x*y});
+		assert_eq!(div_check(&Torque{Nm: x}, &Momentum{kgmps: y}), Velocity{mps: x/y});
+		assert_eq!(div_check(&Torque{Nm: x}, &Power{W: y}), Time{s: x/y});
 		assert_eq!(div_check(&Torque{Nm: x}, &Pressure{Pa: y}), Volume{m3: x/y});
 		assert_eq!(div_check(&Torque{Nm: x}, &Velocity{mps: y}), Momentum{kgmps: x/y});
 		assert_eq!(div_check(&Velocity{mps: x}, &Distance{m: y}), Frequency{Hz: x/y});
@@ -2284,5692 +4575,10379 @@ mod unit_tests {
 		assert_eq!(div_check(&(x as f32), &VolumePerMass{m3_per_kg: y as f32}), Density{kgpm3: x as f32/y as f32});
 		assert_eq!(div_check(&(x as i64), &VolumePerMass{m3_per_kg: y as i64}), Density{kgpm3: x as i64/y as i64});
 		assert_eq!(div_check(&(x as i32), &VolumePerMass{m3_per_kg: y as i32}), Density{kgpm3: x as i32/y as i32});
+		assert_eq!(mul_check(&TemperatureInterval{K: x}, &SpecificHeatCapacity{J_per_kgK: y}), AbsorbedDose{Gy: x*y});
+		assert_eq!(mul_check(&TemperatureInterval{K: x}, &InverseAbsorbedDose{per_Gy: y}), InverseSpecificHeatCapacity{kgK_per_J: x*y});
+		assert_eq!(mul_check(&TemperatureInterval{K: x}, &InverseDoseEquivalent{per_Sv: y}), InverseSpecificHeatCapacity{kgK_per_J: x*y});
+		assert_eq!(mul_check(&SpecificHeatCapacity{J_per_kgK: x}, &TemperatureInterval{K: y}), AbsorbedDose{Gy: x*y});
+		assert_eq!(div_check(&AbsorbedDose{Gy: x}, &TemperatureInterval{K: y}), SpecificHeatCapacity{J_per_kgK: x/y});
+		assert_eq!(div_check(&AbsorbedDose{Gy: x}, &SpecificHeatCapacity{J_per_kgK: y}), TemperatureInterval{K: x/y});
+		assert_eq!(mul_check(&InverseAbsorbedDose{per_Gy: x}, &TemperatureInterval{K: y}), InverseSpecificHeatCapacity{kgK_per_J: x*y});
+		assert_eq!(mul_check(&InverseDoseEquivalent{per_Sv: x}, &TemperatureInterval{K: y}), InverseSpecificHeatCapacity{kgK_per_J: x*y});
+		assert_eq!(div_check(&(x as f64), &TemperatureInterval{K: y as f64}), InverseTemperature{per_K: x as f64/y as f64});
+		assert_eq!(div_check(&(x as f32), &TemperatureInterval{K: y as f32}), InverseTemperature{per_K: x as f32/y as f32});
+		assert_eq!(div_check(&(x as i64), &TemperatureInterval{K: y as i64}), InverseTemperature{per_K: x as i64/y as i64});
+		assert_eq!(div_check(&(x as i32), &TemperatureInterval{K: y as i32}), InverseTemperature{per_K: x as i32/y as i32});
+		assert_eq!(div_check(&Time{s: x}, &Acceleration{mps2: y}), InverseJerk{s3pm: x/y});
+		assert_eq!(mul_check(&Time{s: x}, &InverseAcceleration{s2pm: y}), InverseJerk{s3pm: x*y});
+		assert_eq!(div_check(&Time{s: x}, &InverseJerk{s3pm: y}), Acceleration{mps2: x/y});
+		assert_eq!(mul_check(&Time{s: x}, &Jerk{mps3: y}), Acceleration{mps2: x*y});
+		assert_eq!(div_check(&Acceleration{mps2: x}, &Time{s: y}), Jerk{mps3: x/y});
+		assert_eq!(mul_check(&Acceleration{mps2: x}, &Frequency{Hz: y}), Jerk{mps3: x*y});
+		assert_eq!(mul_check(&Acceleration{mps2: x}, &InverseJerk{s3pm: y}), Time{s: x*y});
+		assert_eq!(div_check(&Acceleration{mps2: x}, &Jerk{mps3: y}), Time{s: x/y});
+		assert_eq!(mul_check(&Frequency{Hz: x}, &Acceleration{mps2: y}), Jerk{mps3: x*y});
+		assert_eq!(div_check(&Frequency{Hz: x}, &InverseAcceleration{s2pm: y}), Jerk{mps3: x/y});
+		assert_eq!(mul_check(&Frequency{Hz: x}, &InverseJerk{s3pm: y}), InverseAcceleration{s2pm: x*y});
+		assert_eq!(div_check(&Frequency{Hz: x}, &Jerk{mps3: y}), InverseAcceleration{s2pm: x/y});
+		assert_eq!(mul_check(&InverseAcceleration{s2pm: x}, &Time{s: y}), InverseJerk{s3pm: x*y});
+		assert_eq!(div_check(&InverseAcceleration{s2pm: x}, &Frequency{Hz: y}), InverseJerk{s3pm: x/y});
+		assert_eq!(div_check(&InverseAcceleration{s2pm: x}, &InverseJerk{s3pm: y}), Frequency{Hz: x/y});
+		assert_eq!(mul_check(&InverseAcceleration{s2pm: x}, &Jerk{mps3: y}), Frequency{Hz: x*y});
+		assert_eq!(div_check(&InverseJerk{s3pm: x}, &Time{s: y}), InverseAcceleration{s2pm: x/y});
+		assert_eq!(mul_check(&InverseJerk{s3pm: x}, &Acceleration{mps2: y}), Time{s: x*y});
+		assert_eq!(mul_check(&InverseJerk{s3pm: x}, &Frequency{Hz: y}), InverseAcceleration{s2pm: x*y});
+		assert_eq!(div_check(&InverseJerk{s3pm: x}, &InverseAcceleration{s2pm: y}), Time{s: x/y});
+		assert_eq!(mul_check(&Jerk{mps3: x}, &Time{s: y}), Acceleration{mps2: x*y});
+		assert_eq!(div_check(&Jerk{mps3: x}, &Acceleration{mps2: y}), Frequency{Hz: x/y});
+		assert_eq!(div_check(&Jerk{mps3: x}, &Frequency{Hz: y}), Acceleration{mps2: x/y});
+		assert_eq!(mul_check(&Jerk{mps3: x}, &InverseAcceleration{s2pm: y}), Frequency{Hz: x*y});
+		assert_eq!(div_check(&(x as f64), &InverseJerk{s3pm: y as f64}), Jerk{mps3: x as f64/y as f64});
+		assert_eq!(div_check(&(x as f32), &InverseJerk{s3pm: y as f32}), Jerk{mps3: x as f32/y as f32});
+		assert_eq!(div_check(&(x as i64), &InverseJerk{s3pm: y as i64}), Jerk{mps3: x as i64/y as i64});
+		assert_eq!(div_check(&(x as i32), &InverseJerk{s3pm: y as i32}), Jerk{mps3: x as i32/y as i32});
+		assert_eq!(div_check(&(x as f64), &Jerk{mps3: y as f64}), InverseJerk{s3pm: x as f64/y as f64});
+		assert_eq!(div_check(&(x as f32), &Jerk{mps3: y as f32}), InverseJerk{s3pm: x as f32/y as f32});
+		assert_eq!(div_check(&(x as i64), &Jerk{mps3: y as i64}), InverseJerk{s3pm: x as i64/y as i64});
+		assert_eq!(div_check(&(x as i32), &Jerk{mps3: y as i32}), InverseJerk{s3pm: x as i32/y as i32});
+		assert_eq!(mul_check(&Distance{m: x}, &InverseKinematicViscosity{s_per_m2: y}), TimePerDistance{spm: x*y});
+		assert_eq!(div_check(&Distance{m: x}, &KinematicViscosity{m2ps: y}), TimePerDistance{spm: x/y});
+		assert_eq!(div_check(&Distance{m: x}, &TimePerDistance{spm: y}), KinematicViscosity{m2ps: x/y});
+		assert_eq!(mul_check(&Distance{m: x}, &Velocity{mps: y}), KinematicViscosity{m2ps: x*y});
+		assert_eq!(div_check(&InverseDistance{per_m: x}, &InverseKinematicViscosity{s_per_m2: y}), Velocity{mps: x/y});
+		assert_eq!(mul_check(&InverseDistance{per_m: x}, &KinematicViscosity{m2ps: y}), Velocity{mps: x*y});
+		assert_eq!(mul_check(&InverseDistance{per_m: x}, &TimePerDistance{spm: y}), InverseKinematicViscosity{s_per_m2: x*y});
+		assert_eq!(div_check(&InverseDistance{per_m: x}, &Velocity{mps: y}), InverseKinematicViscosity{s_per_m2: x/y});
+		assert_eq!(div_check(&Time{s: x}, &Area{m2: y}), InverseKinematicViscosity{s_per_m2: x/y});
+		assert_eq!(mul_check(&Time{s: x}, &InverseArea{per_m2: y}), InverseKinematicViscosity{s_per_m2: x*y});
+		assert_eq!(div_check(&Time{s: x}, &DynamicViscosity{Pas: y}), InversePressure{per_Pa: x/y});
+		assert_eq!(mul_check(&Time{s: x}, &InverseDynamicViscosity{per_Pas: y}), InversePressure{per_Pa: x*y});
+		assert_eq!(div_check(&Time{s: x}, &InverseKinematicViscosity{s_per_m2: y}), Area{m2: x/y});
+		assert_eq!(div_check(&Time{s: x}, &InversePressure{per_Pa: y}), DynamicViscosity{Pas: x/y});
+		assert_eq!(mul_check(&Time{s: x}, &KinematicViscosity{m2ps: y}), Area{m2: x*y});
+		assert_eq!(mul_check(&Time{s: x}, &Pressure{Pa: y}), DynamicViscosity{Pas: x*y});
+		assert_eq!(div_check(&Time{s: x}, &InverseAbsorbedDose{per_Gy: y}), KinematicViscosity{m2ps: x/y});
+		assert_eq!(div_check(&Time{s: x}, &InverseDoseEquivalent{per_Sv: y}), KinematicViscosity{m2ps: x/y});
+		assert_eq!(div_check(&InverseMagneticFluxDensity{m2_per_Wb: x}, &InverseVoltage{per_V: y}), KinematicViscosity{m2ps: x/y});
+		assert_eq!(mul_check(&InverseMagneticFluxDensity{m2_per_Wb: x}, &Voltage{V: y}), KinematicViscosity{m2ps: x*y});
+		assert_eq!(mul_check(&InverseMagneticFluxDensity{m2_per_Wb: x}, &InverseKinematicViscosity{s_per_m2: y}), InverseVoltage{per_V: x*y});
+		assert_eq!(div_check(&InverseMagneticFluxDensity{m2_per_Wb: x}, &KinematicViscosity{m2ps: y}), InverseVoltage{per_V: x/y});
+		assert_eq!(div_check(&InverseVoltage{per_V: x}, &InverseMagneticFluxDensity{m2_per_Wb: y}), InverseKinematicViscosity{s_per_m2: x/y});
+		assert_eq!(mul_check(&InverseVoltage{per_V: x}, &MagneticFluxDensity{T: y}), InverseKinematicViscosity{s_per_m2: x*y});
+		assert_eq!(div_check(&InverseVoltage{per_V: x}, &InverseKinematicViscosity{s_per_m2: y}), InverseMagneticFluxDensity{m2_per_Wb: x/y});
+		assert_eq!(mul_check(&InverseVoltage{per_V: x}, &KinematicViscosity{m2ps: y}), InverseMagneticFluxDensity{m2_per_Wb: x*y});
+		assert_eq!(mul_check(&MagneticFluxDensity{T: x}, &InverseVoltage{per_V: y}), InverseKinematicViscosity{s_per_m2: x*y});
+		assert_eq!(div_check(&MagneticFluxDensity{T: x}, &Voltage{V: y}), InverseKinematicViscosity{s_per_m2: x/y});
+		assert_eq!(div_check(&MagneticFluxDensity{T: x}, &InverseKinematicViscosity{s_per_m2: y}), Voltage{V: x/y});
+		assert_eq!(mul_check(&MagneticFluxDensity{T: x}, &KinematicViscosity{m2ps: y}), Voltage{V: x*y});
+		assert_eq!(mul_check(&Voltage{V: x}, &InverseMagneticFluxDensity{m2_per_Wb: y}), KinematicViscosity{m2ps: x*y});
+		assert_eq!(div_check(&Voltage{V: x}, &MagneticFluxDensity{T: y}), KinematicViscosity{m2ps: x/y});
+		assert_eq!(mul_check(&Voltage{V: x}, &InverseKinematicViscosity{s_per_m2: y}), MagneticFluxDensity{T: x*y});
+		assert_eq!(div_check(&Voltage{V: x}, &KinematicViscosity{m2ps: y}), MagneticFluxDensity{T: x/y});
+		assert_eq!(div_check(&Area{m2: x}, &Time{s: y}), KinematicViscosity{m2ps: x/y});
+		assert_eq!(mul_check(&Area{m2: x}, &DynamicViscosity{Pas: y}), Momentum{kgmps: x*y});
+		assert_eq!(mul_check(&Area{m2: x}, &Frequency{Hz: y}), KinematicViscosity{m2ps: x*y});
+		assert_eq!(div_check(&Area{m2: x}, &InverseDynamicViscosity{per_Pas: y}), Momentum{kgmps: x/y});
+		assert_eq!(mul_check(&Area{m2: x}, &InverseKinematicViscosity{s_per_m2: y}), Time{s: x*y});
+		assert_eq!(mul_check(&Area{m2: x}, &InverseMomentum{s_per_kgm: y}), InverseDynamicViscosity{per_Pas: x*y});
+		assert_eq!(div_check(&Area{m2: x}, &KinematicViscosity{m2ps: y}), Time{s: x/y});
+		assert_eq!(div_check(&Area{m2: x}, &Momentum{kgmps: y}), InverseDynamicViscosity{per_Pas: x/y});
+		assert_eq!(mul_check(&InverseArea{per_m2: x}, &Time{s: y}), InverseKinematicViscosity{s_per_m2: x*y});
+		assert_eq!(div_check(&InverseArea{per_m2: x}, &DynamicViscosity{Pas: y}), InverseMomentum{s_per_kgm: x/y});
+		assert_eq!(div_check(&InverseArea{per_m2: x}, &Frequency{Hz: y}), InverseKinematicViscosity{s_per_m2: x/y});
+		assert_eq!(mul_check(&InverseArea{per_m2: x}, &InverseDynamicViscosity{per_Pas: y}), InverseMomentum{s_per_kgm: x*y});
+		assert_eq!(div_check(&InverseArea{per_m2: x}, &InverseKinematicViscosity{s_per_m2: y}), Frequency{Hz: x/y});
+		assert_eq!(div_check(&InverseArea{per_m2: x}, &InverseMomentum{s_per_kgm: y}), DynamicViscosity{Pas: x/y});
+		assert_eq!(mul_check(&InverseArea{per_m2: x}, &KinematicViscosity{m2ps: y}), Frequency{Hz: x*y});
+		assert_eq!(mul_check(&InverseArea{per_m2: x}, &Momentum{kgmps: y}), DynamicViscosity{Pas: x*y});
+		assert_eq!(div_check(&AreaDensity{kgpm2: x}, &DynamicViscosity{Pas: y}), TimePerDistance{spm: x/y});
+		assert_eq!(mul_check(&AreaDensity{kgpm2: x}, &InverseDynamicViscosity{per_Pas: y}), TimePerDistance{spm: x*y});
+		assert_eq!(div_check(&AreaDensity{kgpm2: x}, &TimePerDistance{spm: y}), DynamicViscosity{Pas: x/y});
+		assert_eq!(mul_check(&AreaDensity{kgpm2: x}, &Velocity{mps: y}), DynamicViscosity{Pas: x*y});
+		assert_eq!(mul_check(&AreaPerMass{m2_per_kg: x}, &DynamicViscosity{Pas: y}), Velocity{mps: x*y});
+		assert_eq!(div_check(&AreaPerMass{m2_per_kg: x}, &InverseDynamicViscosity{per_Pas: y}), Velocity{mps: x/y});
+		assert_eq!(mul_check(&AreaPerMass{m2_per_kg: x}, &TimePerDistance{spm: y}), InverseDynamicViscosity{per_Pas: x*y});
+		assert_eq!(div_check(&AreaPerMass{m2_per_kg: x}, &Velocity{mps: y}), InverseDynamicViscosity{per_Pas: x/y});
+		assert_eq!(div_check(&Density{kgpm3: x}, &DynamicViscosity{Pas: y}), InverseKinematicViscosity{s_per_m2: x/y});
+		assert_eq!(mul_check(&Density{kgpm3: x}, &InverseDynamicViscosity{per_Pas: y}), InverseKinematicViscosity{s_per_m2: x*y});
+		assert_eq!(div_check(&Density{kgpm3: x}, &InverseKinematicViscosity{s_per_m2: y}), DynamicViscosity{Pas: x/y});
+		assert_eq!(mul_check(&Density{kgpm3: x}, &KinematicViscosity{m2ps: y}), DynamicViscosity{Pas: x*y});
+		assert_eq!(div_check(&DynamicViscosity{Pas: x}, &Time{s: y}), Pressure{Pa: x/y});
+		assert_eq!(mul_check(&DynamicViscosity{Pas: x}, &Area{m2: y}), Momentum{kgmps: x*y});
+		assert_eq!(div_check(&DynamicViscosity{Pas: x}, &InverseArea{per_m2: y}), Momentum{kgmps: x/y});
+		assert_eq!(div_check(&DynamicViscosity{Pas: x}, &AreaDensity{kgpm2: y}), Velocity{mps: x/y});
+		assert_eq!(mul_check(&DynamicViscosity{Pas: x}, &AreaPerMass{m2_per_kg: y}), Velocity{mps: x*y});
+		assert_eq!(div_check(&DynamicViscosity{Pas: x}, &Density{kgpm3: y}), KinematicViscosity{m2ps: x/y});
+		assert_eq!(div_check(&DynamicViscosity{Pas: x}, &Force{N: y}), InverseKinematicViscosity{s_per_m2: x/y});
+		assert_eq!(mul_check(&DynamicViscosity{Pas: x}, &Frequency{Hz: y}), Pressure{Pa: x*y});
+		assert_eq!(mul_check(&DynamicViscosity{Pas: x}, &InverseForce{per_N: y}), InverseKinematicViscosity{s_per_m2: x*y});
+		assert_eq!(mul_check(&DynamicViscosity{Pas: x}, &InverseKinematicViscosity{s_per_m2: y}), Density{kgpm3: x*y});
+		assert_eq!(div_check(&DynamicViscosity{Pas: x}, &InverseKinematicViscosity{s_per_m2: y}), Force{N: x/y});
+		assert_eq!(mul_check(&DynamicViscosity{Pas: x}, &InverseMomentum{s_per_kgm: y}), InverseArea{per_m2: x*y});
+		assert_eq!(mul_check(&DynamicViscosity{Pas: x}, &InversePressure{per_Pa: y}), Time{s: x*y});
+		assert_eq!(mul_check(&DynamicViscosity{Pas: x}, &KinematicViscosity{m2ps: y}), Force{N: x*y});
+		assert_eq!(div_check(&DynamicViscosity{Pas: x}, &KinematicViscosity{m2ps: y}), Density{kgpm3: x/y});
+		assert_eq!(div_check(&DynamicViscosity{Pas: x}, &Momentum{kgmps: y}), InverseArea{per_m2: x/y});
+		assert_eq!(div_check(&DynamicViscosity{Pas: x}, &Pressure{Pa: y}), Time{s: x/y});
+		assert_eq!(mul_check(&DynamicViscosity{Pas: x}, &TimePerDistance{spm: y}), AreaDensity{kgpm2: x*y});
+		assert_eq!(div_check(&DynamicViscosity{Pas: x}, &Velocity{mps: y}), AreaDensity{kgpm2: x/y});
+		assert_eq!(mul_check(&DynamicViscosity{Pas: x}, &VolumePerMass{m3_per_kg: y}), KinematicViscosity{m2ps: x*y});
+		assert_eq!(div_check(&Force{N: x}, &DynamicViscosity{Pas: y}), KinematicViscosity{m2ps: x/y});
+		assert_eq!(mul_check(&Force{N: x}, &InverseDynamicViscosity{per_Pas: y}), KinematicViscosity{m2ps: x*y});
+		assert_eq!(mul_check(&Force{N: x}, &InverseKinematicViscosity{s_per_m2: y}), DynamicViscosity{Pas: x*y});
+		assert_eq!(div_check(&Force{N: x}, &KinematicViscosity{m2ps: y}), DynamicViscosity{Pas: x/y});
+		assert_eq!(mul_check(&Frequency{Hz: x}, &Area{m2: y}), KinematicViscosity{m2ps: x*y});
+		assert_eq!(div_check(&Frequency{Hz: x}, &InverseArea{per_m2: y}), KinematicViscosity{m2ps: x/y});
+		assert_eq!(mul_check(&Frequency{Hz: x}, &DynamicViscosity{Pas: y}), Pressure{Pa: x*y});
+		assert_eq!(div_check(&Frequency{Hz: x}, &InverseDynamicViscosity{per_Pas: y}), Pressure{Pa: x/y});
+		assert_eq!(mul_check(&Frequency{Hz: x}, &InverseKinematicViscosity{s_per_m2: y}), InverseArea{per_m2: x*y});
+		assert_eq!(mul_check(&Frequency{Hz: x}, &InversePressure{per_Pa: y}), InverseDynamicViscosity{per_Pas: x*y});
+		assert_eq!(div_check(&Frequency{Hz: x}, &KinematicViscosity{m2ps: y}), InverseArea{per_m2: x/y});
+		assert_eq!(div_check(&Frequency{Hz: x}, &Pressure{Pa: y}), InverseDynamicViscosity{per_Pas: x/y});
+		assert_eq!(mul_check(&Frequency{Hz: x}, &InverseAbsorbedDose{per_Gy: y}), InverseKinematicViscosity{s_per_m2: x*y});
+		assert_eq!(mul_check(&Frequency{Hz: x}, &InverseDoseEquivalent{per_Sv: y}), InverseKinematicViscosity{s_per_m2: x*y});
+		assert_eq!(mul_check(&InverseDynamicViscosity{per_Pas: x}, &Time{s: y}), InversePressure{per_Pa: x*y});
+		assert_eq!(div_check(&InverseDynamicViscosity{per_Pas: x}, &Area{m2: y}), InverseMomentum{s_per_kgm: x/y});
+		assert_eq!(mul_check(&InverseDynamicViscosity{per_Pas: x}, &InverseArea{per_m2: y}), InverseMomentum{s_per_kgm: x*y});
+		assert_eq!(mul_check(&InverseDynamicViscosity{per_Pas: x}, &AreaDensity{kgpm2: y}), TimePerDistance{spm: x*y});
+		assert_eq!(div_check(&InverseDynamicViscosity{per_Pas: x}, &AreaPerMass{m2_per_kg: y}), TimePerDistance{spm: x/y});
+		assert_eq!(mul_check(&InverseDynamicViscosity{per_Pas: x}, &Density{kgpm3: y}), InverseKinematicViscosity{s_per_m2: x*y});
+		assert_eq!(mul_check(&InverseDynamicViscosity{per_Pas: x}, &Force{N: y}), KinematicViscosity{m2ps: x*y});
+		assert_eq!(div_check(&InverseDynamicViscosity{per_Pas: x}, &Frequency{Hz: y}), InversePressure{per_Pa: x/y});
+		assert_eq!(div_check(&InverseDynamicViscosity{per_Pas: x}, &InverseForce{per_N: y}), KinematicViscosity{m2ps: x/y});
+		assert_eq!(mul_check(&InverseDynamicViscosity{per_Pas: x}, &InverseKinematicViscosity{s_per_m2: y}), InverseForce{per_N: x*y});
+		assert_eq!(div_check(&InverseDynamicViscosity{per_Pas: x}, &InverseKinematicViscosity{s_per_m2: y}), VolumePerMass{m3_per_kg: x/y});
+		assert_eq!(div_check(&InverseDynamicViscosity{per_Pas: x}, &InverseMomentum{s_per_kgm: y}), Area{m2: x/y});
+		assert_eq!(div_check(&InverseDynamicViscosity{per_Pas: x}, &InversePressure{per_Pa: y}), Frequency{Hz: x/y});
+		assert_eq!(mul_check(&InverseDynamicViscosity{per_Pas: x}, &KinematicViscosity{m2ps: y}), VolumePerMass{m3_per_kg: x*y});
+		assert_eq!(div_check(&InverseDynamicViscosity{per_Pas: x}, &KinematicViscosity{m2ps: y}), InverseForce{per_N: x/y});
+		assert_eq!(mul_check(&InverseDynamicViscosity{per_Pas: x}, &Momentum{kgmps: y}), Area{m2: x*y});
+		assert_eq!(mul_check(&InverseDynamicViscosity{per_Pas: x}, &Pressure{Pa: y}), Frequency{Hz: x*y});
+		assert_eq!(div_check(&InverseDynamicViscosity{per_Pas: x}, &TimePerDistance{spm: y}), AreaPerMass{m2_per_kg: x/y});
+		assert_eq!(mul_check(&InverseDynamicViscosity{per_Pas: x}, &Velocity{mps: y}), AreaPerMass{m2_per_kg: x*y});
+		assert_eq!(div_check(&InverseDynamicViscosity{per_Pas: x}, &VolumePerMass{m3_per_kg: y}), InverseKinematicViscosity{s_per_m2: x/y});
+		assert_eq!(mul_check(&InverseForce{per_N: x}, &DynamicViscosity{Pas: y}), InverseKinematicViscosity{s_per_m2: x*y});
+		assert_eq!(div_check(&InverseForce{per_N: x}, &InverseDynamicViscosity{per_Pas: y}), InverseKinematicViscosity{s_per_m2: x/y});
+		assert_eq!(div_check(&InverseForce{per_N: x}, &InverseKinematicViscosity{s_per_m2: y}), InverseDynamicViscosity{per_Pas: x/y});
+		assert_eq!(mul_check(&InverseForce{per_N: x}, &KinematicViscosity{m2ps: y}), InverseDynamicViscosity{per_Pas: x*y});
+		assert_eq!(mul_check(&InverseKinematicViscosity{s_per_m2: x}, &Distance{m: y}), TimePerDistance{spm: x*y});
+		assert_eq!(div_check(&InverseKinematicViscosity{s_per_m2: x}, &InverseDistance{per_m: y}), TimePerDistance{spm: x/y});
+		assert_eq!(div_check(&InverseKinematicViscosity{s_per_m2: x}, &Time{s: y}), InverseArea{per_m2: x/y});
+		assert_eq!(mul_check(&InverseKinematicViscosity{s_per_m2: x}, &InverseMagneticFluxDensity{m2_per_Wb: y}), InverseVoltage{per_V: x*y});
+		assert_eq!(div_check(&InverseKinematicViscosity{s_per_m2: x}, &InverseVoltage{per_V: y}), MagneticFluxDensity{T: x/y});
+		assert_eq!(div_check(&InverseKinematicViscosity{s_per_m2: x}, &MagneticFluxDensity{T: y}), InverseVoltage{per_V: x/y});
+		assert_eq!(mul_check(&InverseKinematicViscosity{s_per_m2: x}, &Voltage{V: y}), MagneticFluxDensity{T: x*y});
+		assert_eq!(mul_check(&InverseKinematicViscosity{s_per_m2: x}, &Area{m2: y}), Time{s: x*y});
+		assert_eq!(div_check(&InverseKinematicViscosity{s_per_m2: x}, &InverseArea{per_m2: y}), Time{s: x/y});
+		assert_eq!(div_check(&InverseKinematicViscosity{s_per_m2: x}, &Density{kgpm3: y}), InverseDynamicViscosity{per_Pas: x/y});
+		assert_eq!(mul_check(&InverseKinematicViscosity{s_per_m2: x}, &DynamicViscosity{Pas: y}), Density{kgpm3: x*y});
+		assert_eq!(div_check(&InverseKinematicViscosity{s_per_m2: x}, &DynamicViscosity{Pas: y}), InverseForce{per_N: x/y});
+		assert_eq!(mul_check(&InverseKinematicViscosity{s_per_m2: x}, &Force{N: y}), DynamicViscosity{Pas: x*y});
+		assert_eq!(mul_check(&InverseKinematicViscosity{s_per_m2: x}, &Frequency{Hz: y}), InverseArea{per_m2: x*y});
+		assert_eq!(mul_check(&InverseKinematicViscosity{s_per_m2: x}, &InverseDynamicViscosity{per_Pas: y}), InverseForce{per_N: x*y});
+		assert_eq!(div_check(&InverseKinematicViscosity{s_per_m2: x}, &InverseDynamicViscosity{per_Pas: y}), Density{kgpm3: x/y});
+		assert_eq!(div_check(&InverseKinematicViscosity{s_per_m2: x}, &InverseForce{per_N: y}), DynamicViscosity{Pas: x/y});
+		assert_eq!(div_check(&InverseKinematicViscosity{s_per_m2: x}, &TimePerDistance{spm: y}), InverseDistance{per_m: x/y});
+		assert_eq!(mul_check(&InverseKinematicViscosity{s_per_m2: x}, &Velocity{mps: y}), InverseDistance{per_m: x*y});
+		assert_eq!(mul_check(&InverseKinematicViscosity{s_per_m2: x}, &VolumePerMass{m3_per_kg: y}), InverseDynamicViscosity{per_Pas: x*y});
+		assert_eq!(div_check(&InverseKinematicViscosity{s_per_m2: x}, &InverseAbsorbedDose{per_Gy: y}), Frequency{Hz: x/y});
+		assert_eq!(div_check(&InverseKinematicViscosity{s_per_m2: x}, &InverseDoseEquivalent{per_Sv: y}), Frequency{Hz: x/y});
+		assert_eq!(mul_check(&InverseMomentum{s_per_kgm: x}, &Area{m2: y}), InverseDynamicViscosity{per_Pas: x*y});
+		assert_eq!(div_check(&InverseMomentum{s_per_kgm: x}, &InverseArea{per_m2: y}), InverseDynamicViscosity{per_Pas: x/y});
+		assert_eq!(mul_check(&InverseMomentum{s_per_kgm: x}, &DynamicViscosity{Pas: y}), InverseArea{per_m2: x*y});
+		assert_eq!(div_check(&InverseMomentum{s_per_kgm: x}, &InverseDynamicViscosity{per_Pas: y}), InverseArea{per_m2: x/y});
+		assert_eq!(div_check(&InversePressure{per_Pa: x}, &Time{s: y}), InverseDynamicViscosity{per_Pas: x/y});
+		assert_eq!(mul_check(&InversePressure{per_Pa: x}, &DynamicViscosity{Pas: y}), Time{s: x*y});
+		assert_eq!(mul_check(&InversePressure{per_Pa: x}, &Frequency{Hz: y}), InverseDynamicViscosity{per_Pas: x*y});
+		assert_eq!(div_check(&InversePressure{per_Pa: x}, &InverseDynamicViscosity{per_Pas: y}), Time{s: x/y});
+		assert_eq!(div_check(&KinematicViscosity{m2ps: x}, &Distance{m: y}), Velocity{mps: x/y});
+		assert_eq!(mul_check(&KinematicViscosity{m2ps: x}, &InverseDistance{per_m: y}), Velocity{mps: x*y});
+		assert_eq!(mul_check(&KinematicViscosity{m2ps: x}, &Time{s: y}), Area{m2: x*y});
+		assert_eq!(div_check(&KinematicViscosity{m2ps: x}, &InverseMagneticFluxDensity{m2_per_Wb: y}), Voltage{V: x/y});
+		assert_eq!(mul_check(&KinematicViscosity{m2ps: x}, &InverseVoltage{per_V: y}), InverseMagneticFluxDensity{m2_per_Wb: x*y});
+		assert_eq!(mul_check(&KinematicViscosity{m2ps: x}, &MagneticFluxDensity{T: y}), Voltage{V: x*y});
+		assert_eq!(div_check(&KinematicViscosity{m2ps: x}, &Voltage{V: y}), InverseMagneticFluxDensity{m2_per_Wb: x/y});
+		assert_eq!(div_check(&KinematicViscosity{m2ps: x}, &Area{m2: y}), Frequency{Hz: x/y});
+		assert_eq!(mul_check(&KinematicViscosity{m2ps: x}, &InverseArea{per_m2: y}), Frequency{Hz: x*y});
+		assert_eq!(mul_check(&KinematicViscosity{m2ps: x}, &Density{kgpm3: y}), DynamicViscosity{Pas: x*y});
+		assert_eq!(mul_check(&KinematicViscosity{m2ps: x}, &DynamicViscosity{Pas: y}), Force{N: x*y});
+		assert_eq!(div_check(&KinematicViscosity{m2ps: x}, &DynamicViscosity{Pas: y}), VolumePerMass{m3_per_kg: x/y});
+		assert_eq!(div_check(&KinematicViscosity{m2ps: x}, &Force{N: y}), InverseDynamicViscosity{per_Pas: x/y});
+		assert_eq!(div_check(&KinematicViscosity{m2ps: x}, &Frequency{Hz: y}), Area{m2: x/y});
+		assert_eq!(mul_check(&KinematicViscosity{m2ps: x}, &InverseDynamicViscosity{per_Pas: y}), VolumePerMass{m3_per_kg: x*y});
+		assert_eq!(div_check(&KinematicViscosity{m2ps: x}, &InverseDynamicViscosity{per_Pas: y}), Force{N: x/y});
+		assert_eq!(mul_check(&KinematicViscosity{m2ps: x}, &InverseForce{per_N: y}), InverseDynamicViscosity{per_Pas: x*y});
+		assert_eq!(mul_check(&KinematicViscosity{m2ps: x}, &TimePerDistance{spm: y}), Distance{m: x*y});
+		assert_eq!(div_check(&KinematicViscosity{m2ps: x}, &Velocity{mps: y}), Distance{m: x/y});
+		assert_eq!(div_check(&KinematicViscosity{m2ps: x}, &VolumePerMass{m3_per_kg: y}), DynamicViscosity{Pas: x/y});
+		assert_eq!(mul_check(&KinematicViscosity{m2ps: x}, &InverseAbsorbedDose{per_Gy: y}), Time{s: x*y});
+		assert_eq!(mul_check(&KinematicViscosity{m2ps: x}, &InverseDoseEquivalent{per_Sv: y}), Time{s: x*y});
+		assert_eq!(div_check(&Momentum{kgmps: x}, &Area{m2: y}), DynamicViscosity{Pas: x/y});
+		assert_eq!(mul_check(&Momentum{kgmps: x}, &InverseArea{per_m2: y}), DynamicViscosity{Pas: x*y});
+		assert_eq!(div_check(&Momentum{kgmps: x}, &DynamicViscosity{Pas: y}), Area{m2: x/y});
+		assert_eq!(mul_check(&Momentum{kgmps: x}, &InverseDynamicViscosity{per_Pas: y}), Area{m2: x*y});
+		assert_eq!(mul_check(&Pressure{Pa: x}, &Time{s: y}), DynamicViscosity{Pas: x*y});
+		assert_eq!(div_check(&Pressure{Pa: x}, &DynamicViscosity{Pas: y}), Frequency{Hz: x/y});
+		assert_eq!(div_check(&Pressure{Pa: x}, &Frequency{Hz: y}), DynamicViscosity{Pas: x/y});
+		assert_eq!(mul_check(&Pressure{Pa: x}, &InverseDynamicViscosity{per_Pas: y}), Frequency{Hz: x*y});
+		assert_eq!(div_check(&TimePerDistance{spm: x}, &Distance{m: y}), InverseKinematicViscosity{s_per_m2: x/y});
+		assert_eq!(mul_check(&TimePerDistance{spm: x}, &InverseDistance{per_m: y}), InverseKinematicViscosity{s_per_m2: x*y});
+		assert_eq!(div_check(&TimePerDistance{spm: x}, &AreaDensity{kgpm2: y}), InverseDynamicViscosity{per_Pas: x/y});
+		assert_eq!(mul_check(&TimePerDistance{spm: x}, &AreaPerMass{m2_per_kg: y}), InverseDynamicViscosity{per_Pas: x*y});
+		assert_eq!(mul_check(&TimePerDistance{spm: x}, &DynamicViscosity{Pas: y}), AreaDensity{kgpm2: x*y});
+		assert_eq!(div_check(&TimePerDistance{spm: x}, &InverseDynamicViscosity{per_Pas: y}), AreaDensity{kgpm2: x/y});
+		assert_eq!(div_check(&TimePerDistance{spm: x}, &InverseKinematicViscosity{s_per_m2: y}), Distance{m: x/y});
+		assert_eq!(mul_check(&TimePerDistance{spm: x}, &KinematicViscosity{m2ps: y}), Distance{m: x*y});
+		assert_eq!(mul_check(&Velocity{mps: x}, &Distance{m: y}), KinematicViscosity{m2ps: x*y});
+		assert_eq!(div_check(&Velocity{mps: x}, &InverseDistance{per_m: y}), KinematicViscosity{m2ps: x/y});
+		assert_eq!(mul_check(&Velocity{mps: x}, &AreaDensity{kgpm2: y}), DynamicViscosity{Pas: x*y});
+		assert_eq!(div_check(&Velocity{mps: x}, &AreaPerMass{m2_per_kg: y}), DynamicViscosity{Pas: x/y});
+		assert_eq!(div_check(&Velocity{mps: x}, &DynamicViscosity{Pas: y}), AreaPerMass{m2_per_kg: x/y});
+		assert_eq!(mul_check(&Velocity{mps: x}, &InverseDynamicViscosity{per_Pas: y}), AreaPerMass{m2_per_kg: x*y});
+		assert_eq!(mul_check(&Velocity{mps: x}, &InverseKinematicViscosity{s_per_m2: y}), InverseDistance{per_m: x*y});
+		assert_eq!(div_check(&Velocity{mps: x}, &KinematicViscosity{m2ps: y}), InverseDistance{per_m: x/y});
+		assert_eq!(mul_check(&VolumePerMass{m3_per_kg: x}, &DynamicViscosity{Pas: y}), KinematicViscosity{m2ps: x*y});
+		assert_eq!(div_check(&VolumePerMass{m3_per_kg: x}, &InverseDynamicViscosity{per_Pas: y}), KinematicViscosity{m2ps: x/y});
+		assert_eq!(mul_check(&VolumePerMass{m3_per_kg: x}, &InverseKinematicViscosity{s_per_m2: y}), InverseDynamicViscosity{per_Pas: x*y});
+		assert_eq!(div_check(&VolumePerMass{m3_per_kg: x}, &KinematicViscosity{m2ps: y}), InverseDynamicViscosity{per_Pas: x/y});
+		assert_eq!(div_check(&InverseAbsorbedDose{per_Gy: x}, &Time{s: y}), InverseKinematicViscosity{s_per_m2: x/y});
+		assert_eq!(mul_check(&InverseAbsorbedDose{per_Gy: x}, &Frequency{Hz: y}), InverseKinematicViscosity{s_per_m2: x*y});
+		assert_eq!(div_check(&InverseAbsorbedDose{per_Gy: x}, &InverseKinematicViscosity{s_per_m2: y}), Time{s: x/y});
+		assert_eq!(mul_check(&InverseAbsorbedDose{per_Gy: x}, &KinematicViscosity{m2ps: y}), Time{s: x*y});
+		assert_eq!(div_check(&InverseDoseEquivalent{per_Sv: x}, &Time{s: y}), InverseKinematicViscosity{s_per_m2: x/y});
+		assert_eq!(mul_check(&InverseDoseEquivalent{per_Sv: x}, &Frequency{Hz: y}), InverseKinematicViscosity{s_per_m2: x*y});
+		assert_eq!(div_check(&InverseDoseEquivalent{per_Sv: x}, &InverseKinematicViscosity{s_per_m2: y}), Time{s: x/y});
+		assert_eq!(mul_check(&InverseDoseEquivalent{per_Sv: x}, &KinematicViscosity{m2ps: y}), Time{s: x*y});
+		assert_eq!(div_check(&(x as f64), &DynamicViscosity{Pas: y as f64}), InverseDynamicViscosity{per_Pas: x as f64/y as f64});
+		assert_eq!(div_check(&(x as f32), &DynamicViscosity{Pas: y as f32}), InverseDynamicViscosity{per_Pas: x as f32/y as f32});
+		assert_eq!(div_check(&(x as i64), &DynamicViscosity{Pas: y as i64}), InverseDynamicViscosity{per_Pas: x as i64/y as i64});
+		assert_eq!(div_check(&(x as i32), &DynamicViscosity{Pas: y as i32}), InverseDynamicViscosity{per_Pas: x as i32/y as i32});
+		assert_eq!(div_check(&(x as f64), &InverseDynamicViscosity{per_Pas: y as f64}), DynamicViscosity{Pas: x as f64/y as f64});
+		assert_eq!(div_check(&(x as f32), &InverseDynamicViscosity{per_Pas: y as f32}), DynamicViscosity{Pas: x as f32/y as f32});
+		assert_eq!(div_check(&(x as i64), &InverseDynamicViscosity{per_Pas: y as i64}), DynamicViscosity{Pas: x as i64/y as i64});
+		assert_eq!(div_check(&(x as i32), &InverseDynamicViscosity{per_Pas: y as i32}), DynamicViscosity{Pas: x as i32/y as i32});
+		assert_eq!(div_check(&(x as f64), &InverseKinematicViscosity{s_per_m2: y as f64}), KinematicViscosity{m2ps: x as f64/y as f64});
+		assert_eq!(div_check(&(x as f32), &InverseKinematicViscosity{s_per_m2: y as f32}), KinematicViscosity{m2ps: x as f32/y as f32});
+		assert_eq!(div_check(&(x as i64), &InverseKinematicViscosity{s_per_m2: y as i64}), KinematicViscosity{m2ps: x as i64/y as i64});
+		assert_eq!(div_check(&(x as i32), &InverseKinematicViscosity{s_per_m2: y as i32}), KinematicViscosity{m2ps: x as i32/y as i32});
+		assert_eq!(div_check(&(x as f64), &KinematicViscosity{m2ps: y as f64}), InverseKinematicViscosity{s_per_m2: x as f64/y as f64});
+		assert_eq!(div_check(&(x as f32), &KinematicViscosity{m2ps: y as f32}), InverseKinematicViscosity{s_per_m2: x as f32/y as f32});
+		assert_eq!(div_check(&(x as i64), &KinematicViscosity{m2ps: y as i64}), InverseKinematicViscosity{s_per_m2: x as i64/y as i64});
+		assert_eq!(div_check(&(x as i32), &KinematicViscosity{m2ps: y as i32}), InverseKinematicViscosity{s_per_m2: x as i32/y as i32});
+		assert_eq!(mul_check(&Distance{m: x}, &DynamicViscosity{Pas: y}), MassFlowRate{kgps: x*y});
+		assert_eq!(div_check(&Distance{m: x}, &InverseDynamicViscosity{per_Pas: y}), MassFlowRate{kgps: x/y});
+		assert_eq!(div_check(&Distance{m: x}, &InverseKinematicViscosity{s_per_m2: y}), VolumetricFlowRate{m3ps: x/y});
+		assert_eq!(mul_check(&Distance{m: x}, &InverseMassFlowRate{s_per_kg: y}), InverseDynamicViscosity{per_Pas: x*y});
+		assert_eq!(div_check(&Distance{m: x}, &InverseMassFlowRate{s_per_kg: y}), Momentum{kgmps: x/y});
+		assert_eq!(mul_check(&Distance{m: x}, &InverseMomentum{s_per_kgm: y}), InverseMassFlowRate{s_per_kg: x*y});
+		assert_eq!(mul_check(&Distance{m: x}, &InverseVolumetricFlowRate{s_per_m3: y}), InverseKinematicViscosity{s_per_m2: x*y});
+		assert_eq!(mul_check(&Distance{m: x}, &KinematicViscosity{m2ps: y}), VolumetricFlowRate{m3ps: x*y});
+		assert_eq!(mul_check(&Distance{m: x}, &MassFlowRate{kgps: y}), Momentum{kgmps: x*y});
+		assert_eq!(div_check(&Distance{m: x}, &MassFlowRate{kgps: y}), InverseDynamicViscosity{per_Pas: x/y});
+		assert_eq!(div_check(&Distance{m: x}, &Momentum{kgmps: y}), InverseMassFlowRate{s_per_kg: x/y});
+		assert_eq!(div_check(&Distance{m: x}, &VolumetricFlowRate{m3ps: y}), InverseKinematicViscosity{s_per_m2: x/y});
+		assert_eq!(div_check(&InverseDistance{per_m: x}, &DynamicViscosity{Pas: y}), InverseMassFlowRate{s_per_kg: x/y});
+		assert_eq!(mul_check(&InverseDistance{per_m: x}, &InverseDynamicViscosity{per_Pas: y}), InverseMassFlowRate{s_per_kg: x*y});
+		assert_eq!(mul_check(&InverseDistance{per_m: x}, &InverseKinematicViscosity{s_per_m2: y}), InverseVolumetricFlowRate{s_per_m3: x*y});
+		assert_eq!(mul_check(&InverseDistance{per_m: x}, &InverseMassFlowRate{s_per_kg: y}), InverseMomentum{s_per_kgm: x*y});
+		assert_eq!(div_check(&InverseDistance{per_m: x}, &InverseMassFlowRate{s_per_kg: y}), DynamicViscosity{Pas: x/y});
+		assert_eq!(div_check(&InverseDistance{per_m: x}, &InverseMomentum{s_per_kgm: y}), MassFlowRate{kgps: x/y});
+		assert_eq!(div_check(&InverseDistance{per_m: x}, &InverseVolumetricFlowRate{s_per_m3: y}), KinematicViscosity{m2ps: x/y});
+		assert_eq!(div_check(&InverseDistance{per_m: x}, &KinematicViscosity{m2ps: y}), InverseVolumetricFlowRate{s_per_m3: x/y});
+		assert_eq!(mul_check(&InverseDistance{per_m: x}, &MassFlowRate{kgps: y}), DynamicViscosity{Pas: x*y});
+		assert_eq!(div_check(&InverseDistance{per_m: x}, &MassFlowRate{kgps: y}), InverseMomentum{s_per_kgm: x/y});
+		assert_eq!(mul_check(&InverseDistance{per_m: x}, &Momentum{kgmps: y}), MassFlowRate{kgps: x*y});
+		assert_eq!(mul_check(&InverseDistance{per_m: x}, &VolumetricFlowRate{m3ps: y}), KinematicViscosity{m2ps: x*y});
+		assert_eq!(mul_check(&InverseMass{per_kg: x}, &Time{s: y}), InverseMassFlowRate{s_per_kg: x*y});
+		assert_eq!(div_check(&InverseMass{per_kg: x}, &Frequency{Hz: y}), InverseMassFlowRate{s_per_kg: x/y});
+		assert_eq!(div_check(&InverseMass{per_kg: x}, &InverseMassFlowRate{s_per_kg: y}), Frequency{Hz: x/y});
+		assert_eq!(mul_check(&InverseMass{per_kg: x}, &MassFlowRate{kgps: y}), Frequency{Hz: x*y});
+		assert_eq!(div_check(&Mass{kg: x}, &Time{s: y}), MassFlowRate{kgps: x/y});
+		assert_eq!(mul_check(&Mass{kg: x}, &Frequency{Hz: y}), MassFlowRate{kgps: x*y});
+		assert_eq!(mul_check(&Mass{kg: x}, &InverseMassFlowRate{s_per_kg: y}), Time{s: x*y});
+		assert_eq!(div_check(&Mass{kg: x}, &MassFlowRate{kgps: y}), Time{s: x/y});
+		assert_eq!(mul_check(&Time{s: x}, &InverseMass{per_kg: y}), InverseMassFlowRate{s_per_kg: x*y});
+		assert_eq!(div_check(&Time{s: x}, &Mass{kg: y}), InverseMassFlowRate{s_per_kg: x/y});
+		assert_eq!(mul_check(&Time{s: x}, &InverseVolume{per_m3: y}), InverseVolumetricFlowRate{s_per_m3: x*y});
+		assert_eq!(div_check(&Time{s: x}, &Volume{m3: y}), InverseVolumetricFlowRate{s_per_m3: x/y});
+		assert_eq!(div_check(&Time{s: x}, &InverseMassFlowRate{s_per_kg: y}), Mass{kg: x/y});
+		assert_eq!(div_check(&Time{s: x}, &InverseVolumetricFlowRate{s_per_m3: y}), Volume{m3: x/y});
+		assert_eq!(mul_check(&Time{s: x}, &MassFlowRate{kgps: y}), Mass{kg: x*y});
+		assert_eq!(mul_check(&Time{s: x}, &VolumetricFlowRate{m3ps: y}), Volume{m3: x*y});
+		assert_eq!(div_check(&CatalyticActivity{molps: x}, &Concentration{molpm3: y}), VolumetricFlowRate{m3ps: x/y});
+		assert_eq!(div_check(&CatalyticActivity{molps: x}, &Molality{molpkg: y}), MassFlowRate{kgps: x/y});
+		assert_eq!(mul_check(&CatalyticActivity{molps: x}, &MolarMass{kgpmol: y}), MassFlowRate{kgps: x*y});
+		assert_eq!(mul_check(&CatalyticActivity{molps: x}, &MolarVolume{m3_per_mol: y}), VolumetricFlowRate{m3ps: x*y});
+		assert_eq!(mul_check(&CatalyticActivity{molps: x}, &InverseMassFlowRate{s_per_kg: y}), Molality{molpkg: x*y});
+		assert_eq!(mul_check(&CatalyticActivity{molps: x}, &InverseVolumetricFlowRate{s_per_m3: y}), Concentration{molpm3: x*y});
+		assert_eq!(div_check(&CatalyticActivity{molps: x}, &MassFlowRate{kgps: y}), Molality{molpkg: x/y});
+		assert_eq!(div_check(&CatalyticActivity{molps: x}, &VolumetricFlowRate{m3ps: y}), Concentration{molpm3: x/y});
+		assert_eq!(div_check(&Concentration{molpm3: x}, &CatalyticActivity{molps: y}), InverseVolumetricFlowRate{s_per_m3: x/y});
+		assert_eq!(mul_check(&Concentration{molpm3: x}, &InverseCatalyticActivity{s_per_mol: y}), InverseVolumetricFlowRate{s_per_m3: x*y});
+		assert_eq!(div_check(&Concentration{molpm3: x}, &InverseVolumetricFlowRate{s_per_m3: y}), CatalyticActivity{molps: x/y});
+		assert_eq!(mul_check(&Concentration{molpm3: x}, &VolumetricFlowRate{m3ps: y}), CatalyticActivity{molps: x*y});
+		assert_eq!(mul_check(&InverseCatalyticActivity{s_per_mol: x}, &Concentration{molpm3: y}), InverseVolumetricFlowRate{s_per_m3: x*y});
+		assert_eq!(mul_check(&InverseCatalyticActivity{s_per_mol: x}, &Molality{molpkg: y}), InverseMassFlowRate{s_per_kg: x*y});
+		assert_eq!(div_check(&InverseCatalyticActivity{s_per_mol: x}, &MolarMass{kgpmol: y}), InverseMassFlowRate{s_per_kg: x/y});
+		assert_eq!(div_check(&InverseCatalyticActivity{s_per_mol: x}, &MolarVolume{m3_per_mol: y}), InverseVolumetricFlowRate{s_per_m3: x/y});
+		assert_eq!(div_check(&InverseCatalyticActivity{s_per_mol: x}, &InverseMassFlowRate{s_per_kg: y}), MolarMass{kgpmol: x/y});
+		assert_eq!(div_check(&InverseCatalyticActivity{s_per_mol: x}, &InverseVolumetricFlowRate{s_per_m3: y}), MolarVolume{m3_per_mol: x/y});
+		assert_eq!(mul_check(&InverseCatalyticActivity{s_per_mol: x}, &MassFlowRate{kgps: y}), MolarMass{kgpmol: x*y});
+		assert_eq!(mul_check(&InverseCatalyticActivity{s_per_mol: x}, &VolumetricFlowRate{m3ps: y}), MolarVolume{m3_per_mol: x*y});
+		assert_eq!(div_check(&Molality{molpkg: x}, &CatalyticActivity{molps: y}), InverseMassFlowRate{s_per_kg: x/y});
+		assert_eq!(mul_check(&Molality{molpkg: x}, &InverseCatalyticActivity{s_per_mol: y}), InverseMassFlowRate{s_per_kg: x*y});
+		assert_eq!(div_check(&Molality{molpkg: x}, &InverseMassFlowRate{s_per_kg: y}), CatalyticActivity{molps: x/y});
+		assert_eq!(mul_check(&Molality{molpkg: x}, &MassFlowRate{kgps: y}), CatalyticActivity{molps: x*y});
+		assert_eq!(mul_check(&MolarMass{kgpmol: x}, &CatalyticActivity{molps: y}), MassFlowRate{kgps: x*y});
+		assert_eq!(div_check(&MolarMass{kgpmol: x}, &InverseCatalyticActivity{s_per_mol: y}), MassFlowRate{kgps: x/y});
+		assert_eq!(mul_check(&MolarMass{kgpmol: x}, &InverseMassFlowRate{s_per_kg: y}), InverseCatalyticActivity{s_per_mol: x*y});
+		assert_eq!(div_check(&MolarMass{kgpmol: x}, &MassFlowRate{kgps: y}), InverseCatalyticActivity{s_per_mol: x/y});
+		assert_eq!(mul_check(&MolarVolume{m3_per_mol: x}, &CatalyticActivity{molps: y}), VolumetricFlowRate{m3ps: x*y});
+		assert_eq!(div_check(&MolarVolume{m3_per_mol: x}, &InverseCatalyticActivity{s_per_mol: y}), VolumetricFlowRate{m3ps: x/y});
+		assert_eq!(mul_check(&MolarVolume{m3_per_mol: x}, &InverseVolumetricFlowRate{s_per_m3: y}), InverseCatalyticActivity{s_per_mol: x*y});
+		assert_eq!(div_check(&MolarVolume{m3_per_mol: x}, &VolumetricFlowRate{m3ps: y}), InverseCatalyticActivity{s_per_mol: x/y});
+		assert_eq!(div_check(&Charge{C: x}, &InverseMagneticFluxDensity{m2_per_Wb: y}), MassFlowRate{kgps: x/y});
+		assert_eq!(mul_check(&Charge{C: x}, &MagneticFluxDensity{T: y}), MassFlowRate{kgps: x*y});
+		assert_eq!(mul_check(&Charge{C: x}, &InverseMassFlowRate{s_per_kg: y}), InverseMagneticFluxDensity{m2_per_Wb: x*y});
+		assert_eq!(div_check(&Charge{C: x}, &MassFlowRate{kgps: y}), InverseMagneticFluxDensity{m2_per_Wb: x/y});
+		assert_eq!(mul_check(&InverseCharge{per_C: x}, &InverseMagneticFluxDensity{m2_per_Wb: y}), InverseMassFlowRate{s_per_kg: x*y});
+		assert_eq!(div_check(&InverseCharge{per_C: x}, &MagneticFluxDensity{T: y}), InverseMassFlowRate{s_per_kg: x/y});
+		assert_eq!(div_check(&InverseCharge{per_C: x}, &InverseMassFlowRate{s_per_kg: y}), MagneticFluxDensity{T: x/y});
+		assert_eq!(mul_check(&InverseCharge{per_C: x}, &MassFlowRate{kgps: y}), MagneticFluxDensity{T: x*y});
+		assert_eq!(div_check(&InverseMagneticFluxDensity{m2_per_Wb: x}, &Charge{C: y}), InverseMassFlowRate{s_per_kg: x/y});
+		assert_eq!(mul_check(&InverseMagneticFluxDensity{m2_per_Wb: x}, &InverseCharge{per_C: y}), InverseMassFlowRate{s_per_kg: x*y});
+		assert_eq!(div_check(&InverseMagneticFluxDensity{m2_per_Wb: x}, &InverseMassFlowRate{s_per_kg: y}), Charge{C: x/y});
+		assert_eq!(mul_check(&InverseMagneticFluxDensity{m2_per_Wb: x}, &MassFlowRate{kgps: y}), Charge{C: x*y});
+		assert_eq!(mul_check(&MagneticFluxDensity{T: x}, &Charge{C: y}), MassFlowRate{kgps: x*y});
+		assert_eq!(div_check(&MagneticFluxDensity{T: x}, &InverseCharge{per_C: y}), MassFlowRate{kgps: x/y});
+		assert_eq!(mul_check(&MagneticFluxDensity{T: x}, &InverseMassFlowRate{s_per_kg: y}), InverseCharge{per_C: x*y});
+		assert_eq!(div_check(&MagneticFluxDensity{T: x}, &MassFlowRate{kgps: y}), InverseCharge{per_C: x/y});
+		assert_eq!(mul_check(&Area{m2: x}, &InverseVolumetricFlowRate{s_per_m3: y}), TimePerDistance{spm: x*y});
+		assert_eq!(div_check(&Area{m2: x}, &TimePerDistance{spm: y}), VolumetricFlowRate{m3ps: x/y});
+		assert_eq!(mul_check(&Area{m2: x}, &Velocity{mps: y}), VolumetricFlowRate{m3ps: x*y});
+		assert_eq!(div_check(&Area{m2: x}, &VolumetricFlowRate{m3ps: y}), TimePerDistance{spm: x/y});
+		assert_eq!(div_check(&InverseArea{per_m2: x}, &InverseVolumetricFlowRate{s_per_m3: y}), Velocity{mps: x/y});
+		assert_eq!(mul_check(&InverseArea{per_m2: x}, &TimePerDistance{spm: y}), InverseVolumetricFlowRate{s_per_m3: x*y});
+		assert_eq!(div_check(&InverseArea{per_m2: x}, &Velocity{mps: y}), InverseVolumetricFlowRate{s_per_m3: x/y});
+		assert_eq!(mul_check(&InverseArea{per_m2: x}, &VolumetricFlowRate{m3ps: y}), Velocity{mps: x*y});
+		assert_eq!(mul_check(&InverseVolume{per_m3: x}, &Time{s: y}), InverseVolumetricFlowRate{s_per_m3: x*y});
+		assert_eq!(div_check(&InverseVolume{per_m3: x}, &Frequency{Hz: y}), InverseVolumetricFlowRate{s_per_m3: x/y});
+		assert_eq!(div_check(&InverseVolume{per_m3: x}, &InverseVolumetricFlowRate{s_per_m3: y}), Frequency{Hz: x/y});
+		assert_eq!(mul_check(&InverseVolume{per_m3: x}, &VolumetricFlowRate{m3ps: y}), Frequency{Hz: x*y});
+		assert_eq!(div_check(&Volume{m3: x}, &Time{s: y}), VolumetricFlowRate{m3ps: x/y});
+		assert_eq!(mul_check(&Volume{m3: x}, &Frequency{Hz: y}), VolumetricFlowRate{m3ps: x*y});
+		assert_eq!(mul_check(&Volume{m3: x}, &InverseVolumetricFlowRate{s_per_m3: y}), Time{s: x*y});
+		assert_eq!(div_check(&Volume{m3: x}, &VolumetricFlowRate{m3ps: y}), Time{s: x/y});
+		assert_eq!(div_check(&AreaDensity{kgpm2: x}, &InverseKinematicViscosity{s_per_m2: y}), MassFlowRate{kgps: x/y});
+		assert_eq!(mul_check(&AreaDensity{kgpm2: x}, &InverseMassFlowRate{s_per_kg: y}), InverseKinematicViscosity{s_per_m2: x*y});
+		assert_eq!(mul_check(&AreaDensity{kgpm2: x}, &InverseMomentum{s_per_kgm: y}), InverseVolumetricFlowRate{s_per_m3: x*y});
+		assert_eq!(div_check(&AreaDensity{kgpm2: x}, &InverseVolumetricFlowRate{s_per_m3: y}), Momentum{kgmps: x/y});
+		assert_eq!(mul_check(&AreaDensity{kgpm2: x}, &KinematicViscosity{m2ps: y}), MassFlowRate{kgps: x*y});
+		assert_eq!(div_check(&AreaDensity{kgpm2: x}, &MassFlowRate{kgps: y}), InverseKinematicViscosity{s_per_m2: x/y});
+		assert_eq!(div_check(&AreaDensity{kgpm2: x}, &Momentum{kgmps: y}), InverseVolumetricFlowRate{s_per_m3: x/y});
+		assert_eq!(mul_check(&AreaDensity{kgpm2: x}, &VolumetricFlowRate{m3ps: y}), Momentum{kgmps: x*y});
+		assert_eq!(mul_check(&AreaPerMass{m2_per_kg: x}, &InverseKinematicViscosity{s_per_m2: y}), InverseMassFlowRate{s_per_kg: x*y});
+		assert_eq!(div_check(&AreaPerMass{m2_per_kg: x}, &InverseMassFlowRate{s_per_kg: y}), KinematicViscosity{m2ps: x/y});
+		assert_eq!(div_check(&AreaPerMass{m2_per_kg: x}, &InverseMomentum{s_per_kgm: y}), VolumetricFlowRate{m3ps: x/y});
+		assert_eq!(mul_check(&AreaPerMass{m2_per_kg: x}, &InverseVolumetricFlowRate{s_per_m3: y}), InverseMomentum{s_per_kgm: x*y});
+		assert_eq!(div_check(&AreaPerMass{m2_per_kg: x}, &KinematicViscosity{m2ps: y}), InverseMassFlowRate{s_per_kg: x/y});
+		assert_eq!(mul_check(&AreaPerMass{m2_per_kg: x}, &MassFlowRate{kgps: y}), KinematicViscosity{m2ps: x*y});
+		assert_eq!(mul_check(&AreaPerMass{m2_per_kg: x}, &Momentum{kgmps: y}), VolumetricFlowRate{m3ps: x*y});
+		assert_eq!(div_check(&AreaPerMass{m2_per_kg: x}, &VolumetricFlowRate{m3ps: y}), InverseMomentum{s_per_kgm: x/y});
+		assert_eq!(mul_check(&Density{kgpm3: x}, &InverseMassFlowRate{s_per_kg: y}), InverseVolumetricFlowRate{s_per_m3: x*y});
+		assert_eq!(div_check(&Density{kgpm3: x}, &InverseVolumetricFlowRate{s_per_m3: y}), MassFlowRate{kgps: x/y});
+		assert_eq!(div_check(&Density{kgpm3: x}, &MassFlowRate{kgps: y}), InverseVolumetricFlowRate{s_per_m3: x/y});
+		assert_eq!(mul_check(&Density{kgpm3: x}, &VolumetricFlowRate{m3ps: y}), MassFlowRate{kgps: x*y});
+		assert_eq!(mul_check(&DynamicViscosity{Pas: x}, &Distance{m: y}), MassFlowRate{kgps: x*y});
+		assert_eq!(div_check(&DynamicViscosity{Pas: x}, &InverseDistance{per_m: y}), MassFlowRate{kgps: x/y});
+		assert_eq!(div_check(&DynamicViscosity{Pas: x}, &Energy{J: y}), InverseVolumetricFlowRate{s_per_m3: x/y});
+		assert_eq!(div_check(&DynamicViscosity{Pas: x}, &Torque{Nm: y}), InverseVolumetricFlowRate{s_per_m3: x/y});
+		assert_eq!(mul_check(&DynamicViscosity{Pas: x}, &InverseEnergy{per_J: y}), InverseVolumetricFlowRate{s_per_m3: x*y});
+		assert_eq!(mul_check(&DynamicViscosity{Pas: x}, &InverseTorque{per_Nm: y}), InverseVolumetricFlowRate{s_per_m3: x*y});
+		assert_eq!(mul_check(&DynamicViscosity{Pas: x}, &InverseMassFlowRate{s_per_kg: y}), InverseDistance{per_m: x*y});
+		assert_eq!(div_check(&DynamicViscosity{Pas: x}, &InverseVolumetricFlowRate{s_per_m3: y}), Energy{J: x/y});
+		assert_eq!(div_check(&DynamicViscosity{Pas: x}, &MassFlowRate{kgps: y}), InverseDistance{per_m: x/y});
+		assert_eq!(mul_check(&DynamicViscosity{Pas: x}, &VolumetricFlowRate{m3ps: y}), Energy{J: x*y});
+		assert_eq!(div_check(&Energy{J: x}, &DynamicViscosity{Pas: y}), VolumetricFlowRate{m3ps: x/y});
+		assert_eq!(mul_check(&Energy{J: x}, &InverseDynamicViscosity{per_Pas: y}), VolumetricFlowRate{m3ps: x*y});
+		assert_eq!(mul_check(&Energy{J: x}, &InverseKinematicViscosity{s_per_m2: y}), MassFlowRate{kgps: x*y});
+		assert_eq!(mul_check(&Energy{J: x}, &InverseMassFlowRate{s_per_kg: y}), KinematicViscosity{m2ps: x*y});
+		assert_eq!(mul_check(&Energy{J: x}, &InverseVolumetricFlowRate{s_per_m3: y}), DynamicViscosity{Pas: x*y});
+		assert_eq!(div_check(&Energy{J: x}, &KinematicViscosity{m2ps: y}), MassFlowRate{kgps: x/y});
+		assert_eq!(div_check(&Energy{J: x}, &MassFlowRate{kgps: y}), KinematicViscosity{m2ps: x/y});
+		assert_eq!(div_check(&Energy{J: x}, &VolumetricFlowRate{m3ps: y}), DynamicViscosity{Pas: x/y});
+		assert_eq!(mul_check(&Force{N: x}, &InverseMassFlowRate{s_per_kg: y}), Velocity{mps: x*y});
+		assert_eq!(div_check(&Force{N: x}, &MassFlowRate{kgps: y}), Velocity{mps: x/y});
+		assert_eq!(mul_check(&Force{N: x}, &TimePerDistance{spm: y}), MassFlowRate{kgps: x*y});
+		assert_eq!(div_check(&Force{N: x}, &Velocity{mps: y}), MassFlowRate{kgps: x/y});
+		assert_eq!(div_check(&Frequency{Hz: x}, &InverseMass{per_kg: y}), MassFlowRate{kgps: x/y});
+		assert_eq!(mul_check(&Frequency{Hz: x}, &Mass{kg: y}), MassFlowRate{kgps: x*y});
+		assert_eq!(div_check(&Frequency{Hz: x}, &InverseVolume{per_m3: y}), VolumetricFlowRate{m3ps: x/y});
+		assert_eq!(mul_check(&Frequency{Hz: x}, &Volume{m3: y}), VolumetricFlowRate{m3ps: x*y});
+		assert_eq!(mul_check(&Frequency{Hz: x}, &InverseMassFlowRate{s_per_kg: y}), InverseMass{per_kg: x*y});
+		assert_eq!(mul_check(&Frequency{Hz: x}, &InverseVolumetricFlowRate{s_per_m3: y}), InverseVolume{per_m3: x*y});
+		assert_eq!(div_check(&Frequency{Hz: x}, &MassFlowRate{kgps: y}), InverseMass{per_kg: x/y});
+		assert_eq!(div_check(&Frequency{Hz: x}, &VolumetricFlowRate{m3ps: y}), InverseVolume{per_m3: x/y});
+		assert_eq!(div_check(&InverseDynamicViscosity{per_Pas: x}, &Distance{m: y}), InverseMassFlowRate{s_per_kg: x/y});
+		assert_eq!(mul_check(&InverseDynamicViscosity{per_Pas: x}, &InverseDistance{per_m: y}), InverseMassFlowRate{s_per_kg: x*y});
+		assert_eq!(mul_check(&InverseDynamicViscosity{per_Pas: x}, &Energy{J: y}), VolumetricFlowRate{m3ps: x*y});
+		assert_eq!(mul_check(&InverseDynamicViscosity{per_Pas: x}, &Torque{Nm: y}), VolumetricFlowRate{m3ps: x*y});
+		assert_eq!(div_check(&InverseDynamicViscosity{per_Pas: x}, &InverseEnergy{per_J: y}), VolumetricFlowRate{m3ps: x/y});
+		assert_eq!(div_check(&InverseDynamicViscosity{per_Pas: x}, &InverseTorque{per_Nm: y}), VolumetricFlowRate{m3ps: x/y});
+		assert_eq!(div_check(&InverseDynamicViscosity{per_Pas: x}, &InverseMassFlowRate{s_per_kg: y}), Distance{m: x/y});
+		assert_eq!(mul_check(&InverseDynamicViscosity{per_Pas: x}, &InverseVolumetricFlowRate{s_per_m3: y}), InverseEnergy{per_J: x*y});
+		assert_eq!(mul_check(&InverseDynamicViscosity{per_Pas: x}, &MassFlowRate{kgps: y}), Distance{m: x*y});
+		assert_eq!(div_check(&InverseDynamicViscosity{per_Pas: x}, &VolumetricFlowRate{m3ps: y}), InverseEnergy{per_J: x/y});
+		assert_eq!(mul_check(&InverseEnergy{per_J: x}, &DynamicViscosity{Pas: y}), InverseVolumetricFlowRate{s_per_m3: x*y});
+		assert_eq!(div_check(&InverseEnergy{per_J: x}, &InverseDynamicViscosity{per_Pas: y}), InverseVolumetricFlowRate{s_per_m3: x/y});
+		assert_eq!(div_check(&InverseEnergy{per_J: x}, &InverseKinematicViscosity{s_per_m2: y}), InverseMassFlowRate{s_per_kg: x/y});
+		assert_eq!(div_check(&InverseEnergy{per_J: x}, &InverseMassFlowRate{s_per_kg: y}), InverseKinematicViscosity{s_per_m2: x/y});
+		assert_eq!(div_check(&InverseEnergy{per_J: x}, &InverseVolumetricFlowRate{s_per_m3: y}), InverseDynamicViscosity{per_Pas: x/y});
+		assert_eq!(mul_check(&InverseEnergy{per_J: x}, &KinematicViscosity{m2ps: y}), InverseMassFlowRate{s_per_kg: x*y});
+		assert_eq!(mul_check(&InverseEnergy{per_J: x}, &MassFlowRate{kgps: y}), InverseKinematicViscosity{s_per_m2: x*y});
+		assert_eq!(mul_check(&InverseEnergy{per_J: x}, &VolumetricFlowRate{m3ps: y}), InverseDynamicViscosity{per_Pas: x*y});
+		assert_eq!(div_check(&InverseForce{per_N: x}, &InverseMassFlowRate{s_per_kg: y}), TimePerDistance{spm: x/y});
+		assert_eq!(mul_check(&InverseForce{per_N: x}, &MassFlowRate{kgps: y}), TimePerDistance{spm: x*y});
+		assert_eq!(div_check(&InverseForce{per_N: x}, &TimePerDistance{spm: y}), InverseMassFlowRate{s_per_kg: x/y});
+		assert_eq!(mul_check(&InverseForce{per_N: x}, &Velocity{mps: y}), InverseMassFlowRate{s_per_kg: x*y});
+		assert_eq!(div_check(&InverseKinematicViscosity{s_per_m2: x}, &Distance{m: y}), InverseVolumetricFlowRate{s_per_m3: x/y});
+		assert_eq!(mul_check(&InverseKinematicViscosity{s_per_m2: x}, &InverseDistance{per_m: y}), InverseVolumetricFlowRate{s_per_m3: x*y});
+		assert_eq!(div_check(&InverseKinematicViscosity{s_per_m2: x}, &AreaDensity{kgpm2: y}), InverseMassFlowRate{s_per_kg: x/y});
+		assert_eq!(mul_check(&InverseKinematicViscosity{s_per_m2: x}, &AreaPerMass{m2_per_kg: y}), InverseMassFlowRate{s_per_kg: x*y});
+		assert_eq!(mul_check(&InverseKinematicViscosity{s_per_m2: x}, &Energy{J: y}), MassFlowRate{kgps: x*y});
+		assert_eq!(mul_check(&InverseKinematicViscosity{s_per_m2: x}, &Torque{Nm: y}), MassFlowRate{kgps: x*y});
+		assert_eq!(div_check(&InverseKinematicViscosity{s_per_m2: x}, &InverseEnergy{per_J: y}), MassFlowRate{kgps: x/y});
+		assert_eq!(div_check(&InverseKinematicViscosity{s_per_m2: x}, &InverseTorque{per_Nm: y}), MassFlowRate{kgps: x/y});
+		assert_eq!(mul_check(&InverseKinematicViscosity{s_per_m2: x}, &InverseMassFlowRate{s_per_kg: y}), InverseEnergy{per_J: x*y});
+		assert_eq!(div_check(&InverseKinematicViscosity{s_per_m2: x}, &InverseMassFlowRate{s_per_kg: y}), AreaDensity{kgpm2: x/y});
+		assert_eq!(div_check(&InverseKinematicViscosity{s_per_m2: x}, &InverseVolumetricFlowRate{s_per_m3: y}), Distance{m: x/y});
+		assert_eq!(mul_check(&InverseKinematicViscosity{s_per_m2: x}, &MassFlowRate{kgps: y}), AreaDensity{kgpm2: x*y});
+		assert_eq!(div_check(&InverseKinematicViscosity{s_per_m2: x}, &MassFlowRate{kgps: y}), InverseEnergy{per_J: x/y});
+		assert_eq!(mul_check(&InverseKinematicViscosity{s_per_m2: x}, &VolumetricFlowRate{m3ps: y}), Distance{m: x*y});
+		assert_eq!(mul_check(&InverseMassFlowRate{s_per_kg: x}, &Distance{m: y}), InverseDynamicViscosity{per_Pas: x*y});
+		assert_eq!(div_check(&InverseMassFlowRate{s_per_kg: x}, &Distance{m: y}), InverseMomentum{s_per_kgm: x/y});
+		assert_eq!(mul_check(&InverseMassFlowRate{s_per_kg: x}, &InverseDistance{per_m: y}), InverseMomentum{s_per_kgm: x*y});
+		assert_eq!(div_check(&InverseMassFlowRate{s_per_kg: x}, &InverseDistance{per_m: y}), InverseDynamicViscosity{per_Pas: x/y});
+		assert_eq!(div_check(&InverseMassFlowRate{s_per_kg: x}, &InverseMass{per_kg: y}), Time{s: x/y});
+		assert_eq!(mul_check(&InverseMassFlowRate{s_per_kg: x}, &Mass{kg: y}), Time{s: x*y});
+		assert_eq!(div_check(&InverseMassFlowRate{s_per_kg: x}, &Time{s: y}), InverseMass{per_kg: x/y});
+		assert_eq!(mul_check(&InverseMassFlowRate{s_per_kg: x}, &CatalyticActivity{molps: y}), Molality{molpkg: x*y});
+		assert_eq!(div_check(&InverseMassFlowRate{s_per_kg: x}, &InverseCatalyticActivity{s_per_mol: y}), Molality{molpkg: x/y});
+		assert_eq!(div_check(&InverseMassFlowRate{s_per_kg: x}, &Molality{molpkg: y}), InverseCatalyticActivity{s_per_mol: x/y});
+		assert_eq!(mul_check(&InverseMassFlowRate{s_per_kg: x}, &MolarMass{kgpmol: y}), InverseCatalyticActivity{s_per_mol: x*y});
+		assert_eq!(mul_check(&InverseMassFlowRate{s_per_kg: x}, &Charge{C: y}), InverseMagneticFluxDensity{m2_per_Wb: x*y});
+		assert_eq!(div_check(&InverseMassFlowRate{s_per_kg: x}, &InverseCharge{per_C: y}), InverseMagneticFluxDensity{m2_per_Wb: x/y});
+		assert_eq!(div_check(&InverseMassFlowRate{s_per_kg: x}, &InverseMagneticFluxDensity{m2_per_Wb: y}), InverseCharge{per_C: x/y});
+		assert_eq!(mul_check(&InverseMassFlowRate{s_per_kg: x}, &MagneticFluxDensity{T: y}), InverseCharge{per_C: x*y});
+		assert_eq!(mul_check(&InverseMassFlowRate{s_per_kg: x}, &AreaDensity{kgpm2: y}), InverseKinematicViscosity{s_per_m2: x*y});
+		assert_eq!(div_check(&InverseMassFlowRate{s_per_kg: x}, &AreaPerMass{m2_per_kg: y}), InverseKinematicViscosity{s_per_m2: x/y});
+		assert_eq!(mul_check(&InverseMassFlowRate{s_per_kg: x}, &Density{kgpm3: y}), InverseVolumetricFlowRate{s_per_m3: x*y});
+		assert_eq!(mul_check(&InverseMassFlowRate{s_per_kg: x}, &DynamicViscosity{Pas: y}), InverseDistance{per_m: x*y});
+		assert_eq!(mul_check(&InverseMassFlowRate{s_per_kg: x}, &Energy{J: y}), KinematicViscosity{m2ps: x*y});
+		assert_eq!(mul_check(&InverseMassFlowRate{s_per_kg: x}, &Torque{Nm: y}), KinematicViscosity{m2ps: x*y});
+		assert_eq!(mul_check(&InverseMassFlowRate{s_per_kg: x}, &Force{N: y}), Velocity{mps: x*y});
+		assert_eq!(mul_check(&InverseMassFlowRate{s_per_kg: x}, &Frequency{Hz: y}), InverseMass{per_kg: x*y});
+		assert_eq!(div_check(&InverseMassFlowRate{s_per_kg: x}, &InverseDynamicViscosity{per_Pas: y}), InverseDistance{per_m: x/y});
+		assert_eq!(div_check(&InverseMassFlowRate{s_per_kg: x}, &InverseEnergy{per_J: y}), KinematicViscosity{m2ps: x/y});
+		assert_eq!(div_check(&InverseMassFlowRate{s_per_kg: x}, &InverseTorque{per_Nm: y}), KinematicViscosity{m2ps: x/y});
+		assert_eq!(div_check(&InverseMassFlowRate{s_per_kg: x}, &InverseForce{per_N: y}), Velocity{mps: x/y});
+		assert_eq!(mul_check(&InverseMassFlowRate{s_per_kg: x}, &InverseKinematicViscosity{s_per_m2: y}), InverseEnergy{per_J: x*y});
+		assert_eq!(div_check(&InverseMassFlowRate{s_per_kg: x}, &InverseKinematicViscosity{s_per_m2: y}), AreaPerMass{m2_per_kg: x/y});
+		assert_eq!(div_check(&InverseMassFlowRate{s_per_kg: x}, &InverseMomentum{s_per_kgm: y}), Distance{m: x/y});
+		assert_eq!(div_check(&InverseMassFlowRate{s_per_kg: x}, &InverseVolumetricFlowRate{s_per_m3: y}), VolumePerMass{m3_per_kg: x/y});
+		assert_eq!(mul_check(&InverseMassFlowRate{s_per_kg: x}, &KinematicViscosity{m2ps: y}), AreaPerMass{m2_per_kg: x*y});
+		assert_eq!(div_check(&InverseMassFlowRate{s_per_kg: x}, &KinematicViscosity{m2ps: y}), InverseEnergy{per_J: x/y});
+		assert_eq!(mul_check(&InverseMassFlowRate{s_per_kg: x}, &Momentum{kgmps: y}), Distance{m: x*y});
+		assert_eq!(mul_check(&InverseMassFlowRate{s_per_kg: x}, &TimePerDistance{spm: y}), InverseForce{per_N: x*y});
+		assert_eq!(div_check(&InverseMassFlowRate{s_per_kg: x}, &Velocity{mps: y}), InverseForce{per_N: x/y});
+		assert_eq!(div_check(&InverseMassFlowRate{s_per_kg: x}, &VolumePerMass{m3_per_kg: y}), InverseVolumetricFlowRate{s_per_m3: x/y});
+		assert_eq!(mul_check(&InverseMassFlowRate{s_per_kg: x}, &VolumetricFlowRate{m3ps: y}), VolumePerMass{m3_per_kg: x*y});
+		assert_eq!(mul_check(&InverseMassFlowRate{s_per_kg: x}, &InverseAbsorbedDose{per_Gy: y}), InversePower{per_W: x*y});
+		assert_eq!(mul_check(&InverseMassFlowRate{s_per_kg: x}, &InverseDoseEquivalent{per_Sv: y}), InversePower{per_W: x*y});
+		assert_eq!(mul_check(&InverseMomentum{s_per_kgm: x}, &Distance{m: y}), InverseMassFlowRate{s_per_kg: x*y});
+		assert_eq!(div_check(&InverseMomentum{s_per_kgm: x}, &InverseDistance{per_m: y}), InverseMassFlowRate{s_per_kg: x/y});
+		assert_eq!(mul_check(&InverseMomentum{s_per_kgm: x}, &AreaDensity{kgpm2: y}), InverseVolumetricFlowRate{s_per_m3: x*y});
+		assert_eq!(div_check(&InverseMomentum{s_per_kgm: x}, &AreaPerMass{m2_per_kg: y}), InverseVolumetricFlowRate{s_per_m3: x/y});
+		assert_eq!(div_check(&InverseMomentum{s_per_kgm: x}, &InverseMassFlowRate{s_per_kg: y}), InverseDistance{per_m: x/y});
+		assert_eq!(div_check(&InverseMomentum{s_per_kgm: x}, &InverseVolumetricFlowRate{s_per_m3: y}), AreaPerMass{m2_per_kg: x/y});
+		assert_eq!(mul_check(&InverseMomentum{s_per_kgm: x}, &MassFlowRate{kgps: y}), InverseDistance{per_m: x*y});
+		assert_eq!(mul_check(&InverseMomentum{s_per_kgm: x}, &VolumetricFlowRate{m3ps: y}), AreaPerMass{m2_per_kg: x*y});
+		assert_eq!(div_check(&InversePower{per_W: x}, &InversePressure{per_Pa: y}), InverseVolumetricFlowRate{s_per_m3: x/y});
+		assert_eq!(div_check(&InversePower{per_W: x}, &InverseVolumetricFlowRate{s_per_m3: y}), InversePressure{per_Pa: x/y});
+		assert_eq!(mul_check(&InversePower{per_W: x}, &Pressure{Pa: y}), InverseVolumetricFlowRate{s_per_m3: x*y});
+		assert_eq!(mul_check(&InversePower{per_W: x}, &VolumetricFlowRate{m3ps: y}), InversePressure{per_Pa: x*y});
+		assert_eq!(div_check(&InversePower{per_W: x}, &InverseAbsorbedDose{per_Gy: y}), InverseMassFlowRate{s_per_kg: x/y});
+		assert_eq!(div_check(&InversePower{per_W: x}, &InverseDoseEquivalent{per_Sv: y}), InverseMassFlowRate{s_per_kg: x/y});
+		assert_eq!(div_check(&InversePressure{per_Pa: x}, &InversePower{per_W: y}), VolumetricFlowRate{m3ps: x/y});
+		assert_eq!(mul_check(&InversePressure{per_Pa: x}, &InverseVolumetricFlowRate{s_per_m3: y}), InversePower{per_W: x*y});
+		assert_eq!(mul_check(&InversePressure{per_Pa: x}, &Power{W: y}), VolumetricFlowRate{m3ps: x*y});
+		assert_eq!(div_check(&InversePressure{per_Pa: x}, &VolumetricFlowRate{m3ps: y}), InversePower{per_W: x/y});
+		assert_eq!(mul_check(&InverseTorque{per_Nm: x}, &DynamicViscosity{Pas: y}), InverseVolumetricFlowRate{s_per_m3: x*y});
+		assert_eq!(div_check(&InverseTorque{per_Nm: x}, &InverseDynamicViscosity{per_Pas: y}), InverseVolumetricFlowRate{s_per_m3: x/y});
+		assert_eq!(div_check(&InverseTorque{per_Nm: x}, &InverseKinematicViscosity{s_per_m2: y}), InverseMassFlowRate{s_per_kg: x/y});
+		assert_eq!(div_check(&InverseTorque{per_Nm: x}, &InverseMassFlowRate{s_per_kg: y}), InverseKinematicViscosity{s_per_m2: x/y});
+		assert_eq!(div_check(&InverseTorque{per_Nm: x}, &InverseVolumetricFlowRate{s_per_m3: y}), InverseDynamicViscosity{per_Pas: x/y});
+		assert_eq!(mul_check(&InverseTorque{per_Nm: x}, &KinematicViscosity{m2ps: y}), InverseMassFlowRate{s_per_kg: x*y});
+		assert_eq!(mul_check(&InverseTorque{per_Nm: x}, &MassFlowRate{kgps: y}), InverseKinematicViscosity{s_per_m2: x*y});
+		assert_eq!(mul_check(&InverseTorque{per_Nm: x}, &VolumetricFlowRate{m3ps: y}), InverseDynamicViscosity{per_Pas: x*y});
+		assert_eq!(mul_check(&InverseVolumetricFlowRate{s_per_m3: x}, &Distance{m: y}), InverseKinematicViscosity{s_per_m2: x*y});
+		assert_eq!(div_check(&InverseVolumetricFlowRate{s_per_m3: x}, &InverseDistance{per_m: y}), InverseKinematicViscosity{s_per_m2: x/y});
+		assert_eq!(div_check(&InverseVolumetricFlowRate{s_per_m3: x}, &Time{s: y}), InverseVolume{per_m3: x/y});
+		assert_eq!(mul_check(&InverseVolumetricFlowRate{s_per_m3: x}, &CatalyticActivity{molps: y}), Concentration{molpm3: x*y});
+		assert_eq!(div_check(&InverseVolumetricFlowRate{s_per_m3: x}, &Concentration{molpm3: y}), InverseCatalyticActivity{s_per_mol: x/y});
+		assert_eq!(div_check(&InverseVolumetricFlowRate{s_per_m3: x}, &InverseCatalyticActivity{s_per_mol: y}), Concentration{molpm3: x/y});
+		assert_eq!(mul_check(&InverseVolumetricFlowRate{s_per_m3: x}, &MolarVolume{m3_per_mol: y}), InverseCatalyticActivity{s_per_mol: x*y});
+		assert_eq!(mul_check(&InverseVolumetricFlowRate{s_per_m3: x}, &Area{m2: y}), TimePerDistance{spm: x*y});
+		assert_eq!(div_check(&InverseVolumetricFlowRate{s_per_m3: x}, &InverseArea{per_m2: y}), TimePerDistance{spm: x/y});
+		assert_eq!(div_check(&InverseVolumetricFlowRate{s_per_m3: x}, &InverseVolume{per_m3: y}), Time{s: x/y});
+		assert_eq!(mul_check(&InverseVolumetricFlowRate{s_per_m3: x}, &Volume{m3: y}), Time{s: x*y});
+		assert_eq!(div_check(&InverseVolumetricFlowRate{s_per_m3: x}, &AreaDensity{kgpm2: y}), InverseMomentum{s_per_kgm: x/y});
+		assert_eq!(mul_check(&InverseVolumetricFlowRate{s_per_m3: x}, &AreaPerMass{m2_per_kg: y}), InverseMomentum{s_per_kgm: x*y});
+		assert_eq!(div_check(&InverseVolumetricFlowRate{s_per_m3: x}, &Density{kgpm3: y}), InverseMassFlowRate{s_per_kg: x/y});
+		assert_eq!(div_check(&InverseVolumetricFlowRate{s_per_m3: x}, &DynamicViscosity{Pas: y}), InverseEnergy{per_J: x/y});
+		assert_eq!(mul_check(&InverseVolumetricFlowRate{s_per_m3: x}, &Energy{J: y}), DynamicViscosity{Pas: x*y});
+		assert_eq!(mul_check(&InverseVolumetricFlowRate{s_per_m3: x}, &Torque{Nm: y}), DynamicViscosity{Pas: x*y});
+		assert_eq!(mul_check(&InverseVolumetricFlowRate{s_per_m3: x}, &Frequency{Hz: y}), InverseVolume{per_m3: x*y});
+		assert_eq!(mul_check(&InverseVolumetricFlowRate{s_per_m3: x}, &InverseDynamicViscosity{per_Pas: y}), InverseEnergy{per_J: x*y});
+		assert_eq!(div_check(&InverseVolumetricFlowRate{s_per_m3: x}, &InverseEnergy{per_J: y}), DynamicViscosity{Pas: x/y});
+		assert_eq!(div_check(&InverseVolumetricFlowRate{s_per_m3: x}, &InverseTorque{per_Nm: y}), DynamicViscosity{Pas: x/y});
+		assert_eq!(div_check(&InverseVolumetricFlowRate{s_per_m3: x}, &InverseKinematicViscosity{s_per_m2: y}), InverseDistance{per_m: x/y});
+		assert_eq!(div_check(&InverseVolumetricFlowRate{s_per_m3: x}, &InverseMassFlowRate{s_per_kg: y}), Density{kgpm3: x/y});
+		assert_eq!(div_check(&InverseVolumetricFlowRate{s_per_m3: x}, &InverseMomentum{s_per_kgm: y}), AreaDensity{kgpm2: x/y});
+		assert_eq!(div_check(&InverseVolumetricFlowRate{s_per_m3: x}, &InversePower{per_W: y}), Pressure{Pa: x/y});
+		assert_eq!(mul_check(&InverseVolumetricFlowRate{s_per_m3: x}, &InversePressure{per_Pa: y}), InversePower{per_W: x*y});
+		assert_eq!(mul_check(&InverseVolumetricFlowRate{s_per_m3: x}, &KinematicViscosity{m2ps: y}), InverseDistance{per_m: x*y});
+		assert_eq!(mul_check(&InverseVolumetricFlowRate{s_per_m3: x}, &MassFlowRate{kgps: y}), Density{kgpm3: x*y});
+		assert_eq!(mul_check(&InverseVolumetricFlowRate{s_per_m3: x}, &Momentum{kgmps: y}), AreaDensity{kgpm2: x*y});
+		assert_eq!(mul_check(&InverseVolumetricFlowRate{s_per_m3: x}, &Power{W: y}), Pressure{Pa: x*y});
+		assert_eq!(div_check(&InverseVolumetricFlowRate{s_per_m3: x}, &Pressure{Pa: y}), InversePower{per_W: x/y});
+		assert_eq!(div_check(&InverseVolumetricFlowRate{s_per_m3: x}, &TimePerDistance{spm: y}), InverseArea{per_m2: x/y});
+		assert_eq!(mul_check(&InverseVolumetricFlowRate{s_per_m3: x}, &Velocity{mps: y}), InverseArea{per_m2: x*y});
+		assert_eq!(mul_check(&InverseVolumetricFlowRate{s_per_m3: x}, &VolumePerMass{m3_per_kg: y}), InverseMassFlowRate{s_per_kg: x*y});
+		assert_eq!(mul_check(&KinematicViscosity{m2ps: x}, &Distance{m: y}), VolumetricFlowRate{m3ps: x*y});
+		assert_eq!(div_check(&KinematicViscosity{m2ps: x}, &InverseDistance{per_m: y}), VolumetricFlowRate{m3ps: x/y});
+		assert_eq!(mul_check(&KinematicViscosity{m2ps: x}, &AreaDensity{kgpm2: y}), MassFlowRate{kgps: x*y});
+		assert_eq!(div_check(&KinematicViscosity{m2ps: x}, &AreaPerMass{m2_per_kg: y}), MassFlowRate{kgps: x/y});
+		assert_eq!(div_check(&KinematicViscosity{m2ps: x}, &Energy{J: y}), InverseMassFlowRate{s_per_kg: x/y});
+		assert_eq!(div_check(&KinematicViscosity{m2ps: x}, &Torque{Nm: y}), InverseMassFlowRate{s_per_kg: x/y});
+		assert_eq!(mul_check(&KinematicViscosity{m2ps: x}, &InverseEnergy{per_J: y}), InverseMassFlowRate{s_per_kg: x*y});
+		assert_eq!(mul_check(&KinematicViscosity{m2ps: x}, &InverseTorque{per_Nm: y}), InverseMassFlowRate{s_per_kg: x*y});
+		assert_eq!(mul_check(&KinematicViscosity{m2ps: x}, &InverseMassFlowRate{s_per_kg: y}), AreaPerMass{m2_per_kg: x*y});
+		assert_eq!(div_check(&KinematicViscosity{m2ps: x}, &InverseMassFlowRate{s_per_kg: y}), Energy{J: x/y});
+		assert_eq!(mul_check(&KinematicViscosity{m2ps: x}, &InverseVolumetricFlowRate{s_per_m3: y}), InverseDistance{per_m: x*y});
+		assert_eq!(mul_check(&KinematicViscosity{m2ps: x}, &MassFlowRate{kgps: y}), Energy{J: x*y});
+		assert_eq!(div_check(&KinematicViscosity{m2ps: x}, &MassFlowRate{kgps: y}), AreaPerMass{m2_per_kg: x/y});
+		assert_eq!(div_check(&KinematicViscosity{m2ps: x}, &VolumetricFlowRate{m3ps: y}), InverseDistance{per_m: x/y});
+		assert_eq!(mul_check(&MassFlowRate{kgps: x}, &Distance{m: y}), Momentum{kgmps: x*y});
+		assert_eq!(div_check(&MassFlowRate{kgps: x}, &Distance{m: y}), DynamicViscosity{Pas: x/y});
+		assert_eq!(mul_check(&MassFlowRate{kgps: x}, &InverseDistance{per_m: y}), DynamicViscosity{Pas: x*y});
+		assert_eq!(div_check(&MassFlowRate{kgps: x}, &InverseDistance{per_m: y}), Momentum{kgmps: x/y});
+		assert_eq!(mul_check(&MassFlowRate{kgps: x}, &InverseMass{per_kg: y}), Frequency{Hz: x*y});
+		assert_eq!(div_check(&MassFlowRate{kgps: x}, &Mass{kg: y}), Frequency{Hz: x/y});
+		assert_eq!(mul_check(&MassFlowRate{kgps: x}, &Time{s: y}), Mass{kg: x*y});
+		assert_eq!(div_check(&MassFlowRate{kgps: x}, &CatalyticActivity{molps: y}), MolarMass{kgpmol: x/y});
+		assert_eq!(mul_check(&MassFlowRate{kgps: x}, &InverseCatalyticActivity{s_per_mol: y}), MolarMass{kgpmol: x*y});
+		assert_eq!(mul_check(&MassFlowRate{kgps: x}, &Molality{molpkg: y}), CatalyticActivity{molps: x*y});
+		assert_eq!(div_check(&MassFlowRate{kgps: x}, &MolarMass{kgpmol: y}), CatalyticActivity{molps: x/y});
+		assert_eq!(div_check(&MassFlowRate{kgps: x}, &Charge{C: y}), MagneticFluxDensity{T: x/y});
+		assert_eq!(mul_check(&MassFlowRate{kgps: x}, &InverseCharge{per_C: y}), MagneticFluxDensity{T: x*y});
+		assert_eq!(mul_check(&MassFlowRate{kgps: x}, &InverseMagneticFluxDensity{m2_per_Wb: y}), Charge{C: x*y});
+		assert_eq!(div_check(&MassFlowRate{kgps: x}, &MagneticFluxDensity{T: y}), Charge{C: x/y});
+		assert_eq!(div_check(&MassFlowRate{kgps: x}, &AreaDensity{kgpm2: y}), KinematicViscosity{m2ps: x/y});
+		assert_eq!(mul_check(&MassFlowRate{kgps: x}, &AreaPerMass{m2_per_kg: y}), KinematicViscosity{m2ps: x*y});
+		assert_eq!(div_check(&MassFlowRate{kgps: x}, &Density{kgpm3: y}), VolumetricFlowRate{m3ps: x/y});
+		assert_eq!(div_check(&MassFlowRate{kgps: x}, &DynamicViscosity{Pas: y}), Distance{m: x/y});
+		assert_eq!(div_check(&MassFlowRate{kgps: x}, &Energy{J: y}), InverseKinematicViscosity{s_per_m2: x/y});
+		assert_eq!(div_check(&MassFlowRate{kgps: x}, &Torque{Nm: y}), InverseKinematicViscosity{s_per_m2: x/y});
+		assert_eq!(div_check(&MassFlowRate{kgps: x}, &Force{N: y}), TimePerDistance{spm: x/y});
+		assert_eq!(div_check(&MassFlowRate{kgps: x}, &Frequency{Hz: y}), Mass{kg: x/y});
+		assert_eq!(mul_check(&MassFlowRate{kgps: x}, &InverseDynamicViscosity{per_Pas: y}), Distance{m: x*y});
+		assert_eq!(mul_check(&MassFlowRate{kgps: x}, &InverseEnergy{per_J: y}), InverseKinematicViscosity{s_per_m2: x*y});
+		assert_eq!(mul_check(&MassFlowRate{kgps: x}, &InverseTorque{per_Nm: y}), InverseKinematicViscosity{s_per_m2: x*y});
+		assert_eq!(mul_check(&MassFlowRate{kgps: x}, &InverseForce{per_N: y}), TimePerDistance{spm: x*y});
+		assert_eq!(mul_check(&MassFlowRate{kgps: x}, &InverseKinematicViscosity{s_per_m2: y}), AreaDensity{kgpm2: x*y});
+		assert_eq!(div_check(&MassFlowRate{kgps: x}, &InverseKinematicViscosity{s_per_m2: y}), Energy{J: x/y});
+		assert_eq!(mul_check(&MassFlowRate{kgps: x}, &InverseMomentum{s_per_kgm: y}), InverseDistance{per_m: x*y});
+		assert_eq!(mul_check(&MassFlowRate{kgps: x}, &InverseVolumetricFlowRate{s_per_m3: y}), Density{kgpm3: x*y});
+		assert_eq!(mul_check(&MassFlowRate{kgps: x}, &KinematicViscosity{m2ps: y}), Energy{J: x*y});
+		assert_eq!(div_check(&MassFlowRate{kgps: x}, &KinematicViscosity{m2ps: y}), AreaDensity{kgpm2: x/y});
+		assert_eq!(div_check(&MassFlowRate{kgps: x}, &Momentum{kgmps: y}), InverseDistance{per_m: x/y});
+		assert_eq!(div_check(&MassFlowRate{kgps: x}, &TimePerDistance{spm: y}), Force{N: x/y});
+		assert_eq!(mul_check(&MassFlowRate{kgps: x}, &Velocity{mps: y}), Force{N: x*y});
+		assert_eq!(mul_check(&MassFlowRate{kgps: x}, &VolumePerMass{m3_per_kg: y}), VolumetricFlowRate{m3ps: x*y});
+		assert_eq!(div_check(&MassFlowRate{kgps: x}, &VolumetricFlowRate{m3ps: y}), Density{kgpm3: x/y});
+		assert_eq!(div_check(&MassFlowRate{kgps: x}, &InverseAbsorbedDose{per_Gy: y}), Power{W: x/y});
+		assert_eq!(div_check(&MassFlowRate{kgps: x}, &InverseDoseEquivalent{per_Sv: y}), Power{W: x/y});
+		assert_eq!(div_check(&Momentum{kgmps: x}, &Distance{m: y}), MassFlowRate{kgps: x/y});
+		assert_eq!(mul_check(&Momentum{kgmps: x}, &InverseDistance{per_m: y}), MassFlowRate{kgps: x*y});
+		assert_eq!(div_check(&Momentum{kgmps: x}, &AreaDensity{kgpm2: y}), VolumetricFlowRate{m3ps: x/y});
+		assert_eq!(mul_check(&Momentum{kgmps: x}, &AreaPerMass{m2_per_kg: y}), VolumetricFlowRate{m3ps: x*y});
+		assert_eq!(mul_check(&Momentum{kgmps: x}, &InverseMassFlowRate{s_per_kg: y}), Distance{m: x*y});
+		assert_eq!(mul_check(&Momentum{kgmps: x}, &InverseVolumetricFlowRate{s_per_m3: y}), AreaDensity{kgpm2: x*y});
+		assert_eq!(div_check(&Momentum{kgmps: x}, &MassFlowRate{kgps: y}), Distance{m: x/y});
+		assert_eq!(div_check(&Momentum{kgmps: x}, &VolumetricFlowRate{m3ps: y}), AreaDensity{kgpm2: x/y});
+		assert_eq!(mul_check(&Power{W: x}, &InversePressure{per_Pa: y}), VolumetricFlowRate{m3ps: x*y});
+		assert_eq!(mul_check(&Power{W: x}, &InverseVolumetricFlowRate{s_per_m3: y}), Pressure{Pa: x*y});
+		assert_eq!(div_check(&Power{W: x}, &Pressure{Pa: y}), VolumetricFlowRate{m3ps: x/y});
+		assert_eq!(div_check(&Power{W: x}, &VolumetricFlowRate{m3ps: y}), Pressure{Pa: x/y});
+		assert_eq!(mul_check(&Power{W: x}, &InverseAbsorbedDose{per_Gy: y}), MassFlowRate{kgps: x*y});
+		assert_eq!(mul_check(&Power{W: x}, &InverseDoseEquivalent{per_Sv: y}), MassFlowRate{kgps: x*y});
+		assert_eq!(mul_check(&Pressure{Pa: x}, &InversePower{per_W: y}), InverseVolumetricFlowRate{s_per_m3: x*y});
+		assert_eq!(div_check(&Pressure{Pa: x}, &InverseVolumetricFlowRate{s_per_m3: y}), Power{W: x/y});
+		assert_eq!(div_check(&Pressure{Pa: x}, &Power{W: y}), InverseVolumetricFlowRate{s_per_m3: x/y});
+		assert_eq!(mul_check(&Pressure{Pa: x}, &VolumetricFlowRate{m3ps: y}), Power{W: x*y});
+		assert_eq!(div_check(&TimePerDistance{spm: x}, &Area{m2: y}), InverseVolumetricFlowRate{s_per_m3: x/y});
+		assert_eq!(mul_check(&TimePerDistance{spm: x}, &InverseArea{per_m2: y}), InverseVolumetricFlowRate{s_per_m3: x*y});
+		assert_eq!(mul_check(&TimePerDistance{spm: x}, &Force{N: y}), MassFlowRate{kgps: x*y});
+		assert_eq!(div_check(&TimePerDistance{spm: x}, &InverseForce{per_N: y}), MassFlowRate{kgps: x/y});
+		assert_eq!(mul_check(&TimePerDistance{spm: x}, &InverseMassFlowRate{s_per_kg: y}), InverseForce{per_N: x*y});
+		assert_eq!(div_check(&TimePerDistance{spm: x}, &InverseVolumetricFlowRate{s_per_m3: y}), Area{m2: x/y});
+		assert_eq!(div_check(&TimePerDistance{spm: x}, &MassFlowRate{kgps: y}), InverseForce{per_N: x/y});
+		assert_eq!(mul_check(&TimePerDistance{spm: x}, &VolumetricFlowRate{m3ps: y}), Area{m2: x*y});
+		assert_eq!(div_check(&Torque{Nm: x}, &DynamicViscosity{Pas: y}), VolumetricFlowRate{m3ps: x/y});
+		assert_eq!(mul_check(&Torque{Nm: x}, &InverseDynamicViscosity{per_Pas: y}), VolumetricFlowRate{m3ps: x*y});
+		assert_eq!(mul_check(&Torque{Nm: x}, &InverseKinematicViscosity{s_per_m2: y}), MassFlowRate{kgps: x*y});
+		assert_eq!(mul_check(&Torque{Nm: x}, &InverseMassFlowRate{s_per_kg: y}), KinematicViscosity{m2ps: x*y});
+		assert_eq!(mul_check(&Torque{Nm: x}, &InverseVolumetricFlowRate{s_per_m3: y}), DynamicViscosity{Pas: x*y});
+		assert_eq!(div_check(&Torque{Nm: x}, &KinematicViscosity{m2ps: y}), MassFlowRate{kgps: x/y});
+		assert_eq!(div_check(&Torque{Nm: x}, &MassFlowRate{kgps: y}), KinematicViscosity{m2ps: x/y});
+		assert_eq!(div_check(&Torque{Nm: x}, &VolumetricFlowRate{m3ps: y}), DynamicViscosity{Pas: x/y});
+		assert_eq!(mul_check(&Velocity{mps: x}, &Area{m2: y}), VolumetricFlowRate{m3ps: x*y});
+		assert_eq!(div_check(&Velocity{mps: x}, &InverseArea{per_m2: y}), VolumetricFlowRate{m3ps: x/y});
+		assert_eq!(div_check(&Velocity{mps: x}, &Force{N: y}), InverseMassFlowRate{s_per_kg: x/y});
+		assert_eq!(mul_check(&Velocity{mps: x}, &InverseForce{per_N: y}), InverseMassFlowRate{s_per_kg: x*y});
+		assert_eq!(div_check(&Velocity{mps: x}, &InverseMassFlowRate{s_per_kg: y}), Force{N: x/y});
+		assert_eq!(mul_check(&Velocity{mps: x}, &InverseVolumetricFlowRate{s_per_m3: y}), InverseArea{per_m2: x*y});
+		assert_eq!(mul_check(&Velocity{mps: x}, &MassFlowRate{kgps: y}), Force{N: x*y});
+		assert_eq!(div_check(&Velocity{mps: x}, &VolumetricFlowRate{m3ps: y}), InverseArea{per_m2: x/y});
+		assert_eq!(div_check(&VolumePerMass{m3_per_kg: x}, &InverseMassFlowRate{s_per_kg: y}), VolumetricFlowRate{m3ps: x/y});
+		assert_eq!(mul_check(&VolumePerMass{m3_per_kg: x}, &InverseVolumetricFlowRate{s_per_m3: y}), InverseMassFlowRate{s_per_kg: x*y});
+		assert_eq!(mul_check(&VolumePerMass{m3_per_kg: x}, &MassFlowRate{kgps: y}), VolumetricFlowRate{m3ps: x*y});
+		assert_eq!(div_check(&VolumePerMass{m3_per_kg: x}, &VolumetricFlowRate{m3ps: y}), InverseMassFlowRate{s_per_kg: x/y});
+		assert_eq!(div_check(&VolumetricFlowRate{m3ps: x}, &Distance{m: y}), KinematicViscosity{m2ps: x/y});
+		assert_eq!(mul_check(&VolumetricFlowRate{m3ps: x}, &InverseDistance{per_m: y}), KinematicViscosity{m2ps: x*y});
+		assert_eq!(mul_check(&VolumetricFlowRate{m3ps: x}, &Time{s: y}), Volume{m3: x*y});
+		assert_eq!(div_check(&VolumetricFlowRate{m3ps: x}, &CatalyticActivity{molps: y}), MolarVolume{m3_per_mol: x/y});
+		assert_eq!(mul_check(&VolumetricFlowRate{m3ps: x}, &Concentration{molpm3: y}), CatalyticActivity{molps: x*y});
+		assert_eq!(mul_check(&VolumetricFlowRate{m3ps: x}, &InverseCatalyticActivity{s_per_mol: y}), MolarVolume{m3_per_mol: x*y});
+		assert_eq!(div_check(&VolumetricFlowRate{m3ps: x}, &MolarVolume{m3_per_mol: y}), CatalyticActivity{molps: x/y});
+		assert_eq!(div_check(&VolumetricFlowRate{m3ps: x}, &Area{m2: y}), Velocity{mps: x/y});
+		assert_eq!(mul_check(&VolumetricFlowRate{m3ps: x}, &InverseArea{per_m2: y}), Velocity{mps: x*y});
+		assert_eq!(mul_check(&VolumetricFlowRate{m3ps: x}, &InverseVolume{per_m3: y}), Frequency{Hz: x*y});
+		assert_eq!(div_check(&VolumetricFlowRate{m3ps: x}, &Volume{m3: y}), Frequency{Hz: x/y});
+		assert_eq!(mul_check(&VolumetricFlowRate{m3ps: x}, &AreaDensity{kgpm2: y}), Momentum{kgmps: x*y});
+		assert_eq!(div_check(&VolumetricFlowRate{m3ps: x}, &AreaPerMass{m2_per_kg: y}), Momentum{kgmps: x/y});
+		assert_eq!(mul_check(&VolumetricFlowRate{m3ps: x}, &Density{kgpm3: y}), MassFlowRate{kgps: x*y});
+		assert_eq!(mul_check(&VolumetricFlowRate{m3ps: x}, &DynamicViscosity{Pas: y}), Energy{J: x*y});
+		assert_eq!(div_check(&VolumetricFlowRate{m3ps: x}, &Energy{J: y}), InverseDynamicViscosity{per_Pas: x/y});
+		assert_eq!(div_check(&VolumetricFlowRate{m3ps: x}, &Torque{Nm: y}), InverseDynamicViscosity{per_Pas: x/y});
+		assert_eq!(div_check(&VolumetricFlowRate{m3ps: x}, &Frequency{Hz: y}), Volume{m3: x/y});
+		assert_eq!(div_check(&VolumetricFlowRate{m3ps: x}, &InverseDynamicViscosity{per_Pas: y}), Energy{J: x/y});
+		assert_eq!(mul_check(&VolumetricFlowRate{m3ps: x}, &InverseEnergy{per_J: y}), InverseDynamicViscosity{per_Pas: x*y});
+		assert_eq!(mul_check(&VolumetricFlowRate{m3ps: x}, &InverseTorque{per_Nm: y}), InverseDynamicViscosity{per_Pas: x*y});
+		assert_eq!(mul_check(&VolumetricFlowRate{m3ps: x}, &InverseKinematicViscosity{s_per_m2: y}), Distance{m: x*y});
+		assert_eq!(mul_check(&VolumetricFlowRate{m3ps: x}, &InverseMassFlowRate{s_per_kg: y}), VolumePerMass{m3_per_kg: x*y});
+		assert_eq!(mul_check(&VolumetricFlowRate{m3ps: x}, &InverseMomentum{s_per_kgm: y}), AreaPerMass{m2_per_kg: x*y});
+		assert_eq!(mul_check(&VolumetricFlowRate{m3ps: x}, &InversePower{per_W: y}), InversePressure{per_Pa: x*y});
+		assert_eq!(div_check(&VolumetricFlowRate{m3ps: x}, &InversePressure{per_Pa: y}), Power{W: x/y});
+		assert_eq!(div_check(&VolumetricFlowRate{m3ps: x}, &KinematicViscosity{m2ps: y}), Distance{m: x/y});
+		assert_eq!(div_check(&VolumetricFlowRate{m3ps: x}, &MassFlowRate{kgps: y}), VolumePerMass{m3_per_kg: x/y});
+		assert_eq!(div_check(&VolumetricFlowRate{m3ps: x}, &Momentum{kgmps: y}), AreaPerMass{m2_per_kg: x/y});
+		assert_eq!(div_check(&VolumetricFlowRate{m3ps: x}, &Power{W: y}), InversePressure{per_Pa: x/y});
+		assert_eq!(mul_check(&VolumetricFlowRate{m3ps: x}, &Pressure{Pa: y}), Power{W: x*y});
+		assert_eq!(mul_check(&VolumetricFlowRate{m3ps: x}, &TimePerDistance{spm: y}), Area{m2: x*y});
+		assert_eq!(div_check(&VolumetricFlowRate{m3ps: x}, &Velocity{mps: y}), Area{m2: x/y});
+		assert_eq!(div_check(&VolumetricFlowRate{m3ps: x}, &VolumePerMass{m3_per_kg: y}), MassFlowRate{kgps: x/y});
+		assert_eq!(mul_check(&InverseAbsorbedDose{per_Gy: x}, &InverseMassFlowRate{s_per_kg: y}), InversePower{per_W: x*y});
+		assert_eq!(div_check(&InverseAbsorbedDose{per_Gy: x}, &InversePower{per_W: y}), MassFlowRate{kgps: x/y});
+		assert_eq!(div_check(&InverseAbsorbedDose{per_Gy: x}, &MassFlowRate{kgps: y}), InversePower{per_W: x/y});
+		assert_eq!(mul_check(&InverseAbsorbedDose{per_Gy: x}, &Power{W: y}), MassFlowRate{kgps: x*y});
+		assert_eq!(mul_check(&InverseDoseEquivalent{per_Sv: x}, &InverseMassFlowRate{s_per_kg: y}), InversePower{per_W: x*y});
+		assert_eq!(div_check(&InverseDoseEquivalent{per_Sv: x}, &InversePower{per_W: y}), MassFlowRate{kgps: x/y});
+		assert_eq!(div_check(&InverseDoseEquivalent{per_Sv: x}, &MassFlowRate{kgps: y}), InversePower{per_W: x/y});
+		assert_eq!(mul_check(&InverseDoseEquivalent{per_Sv: x}, &Power{W: y}), MassFlowRate{kgps: x*y});
+		assert_eq!(div_check(&(x as f64), &InverseMassFlowRate{s_per_kg: y as f64}), MassFlowRate{kgps: x as f64/y as f64});
+		assert_eq!(div_check(&(x as f32), &InverseMassFlowRate{s_per_kg: y as f32}), MassFlowRate{kgps: x as f32/y as f32});
+		assert_eq!(div_check(&(x as i64), &InverseMassFlowRate{s_per_kg: y as i64}), MassFlowRate{kgps: x as i64/y as i64});
+		assert_eq!(div_check(&(x as i32), &InverseMassFlowRate{s_per_kg: y as i32}), MassFlowRate{kgps: x as i32/y as i32});
+		assert_eq!(div_check(&(x as f64), &InverseVolumetricFlowRate{s_per_m3: y as f64}), VolumetricFlowRate{m3ps: x as f64/y as f64});
+		assert_eq!(div_check(&(x as f32), &InverseVolumetricFlowRate{s_per_m3: y as f32}), VolumetricFlowRate{m3ps: x as f32/y as f32});
+		assert_eq!(div_check(&(x as i64), &InverseVolumetricFlowRate{s_per_m3: y as i64}), VolumetricFlowRate{m3ps: x as i64/y as i64});
+		assert_eq!(div_check(&(x as i32), &InverseVolumetricFlowRate{s_per_m3: y as i32}), VolumetricFlowRate{m3ps: x as i32/y as i32});
+		assert_eq!(div_check(&(x as f64), &MassFlowRate{kgps: y as f64}), InverseMassFlowRate{s_per_kg: x as f64/y as f64});
+		assert_eq!(div_check(&(x as f32), &MassFlowRate{kgps: y as f32}), InverseMassFlowRate{s_per_kg: x as f32/y as f32});
+		assert_eq!(div_check(&(x as i64), &MassFlowRate{kgps: y as i64}), InverseMassFlowRate{s_per_kg: x as i64/y as i64});
+		assert_eq!(div_check(&(x as i32), &MassFlowRate{kgps: y as i32}), InverseMassFlowRate{s_per_kg: x as i32/y as i32});
+		assert_eq!(div_check(&(x as f64), &VolumetricFlowRate{m3ps: y as f64}), InverseVolumetricFlowRate{s_per_m3: x as f64/y as f64});
+		assert_eq!(div_check(&(x as f32), &VolumetricFlowRate{m3ps: y as f32}), InverseVolumetricFlowRate{s_per_m3: x as f32/y as f32});
+		assert_eq!(div_check(&(x as i64), &VolumetricFlowRate{m3ps: y as i64}), InverseVolumetricFlowRate{s_per_m3: x as i64/y as i64});
+		assert_eq!(div_check(&(x as i32), &VolumetricFlowRate{m3ps: y as i32}), InverseVolumetricFlowRate{s_per_m3: x as i32/y as i32});
+		assert_eq!(mul_check(&InverseMass{per_kg: x}, &Entropy{J_per_K: y}), SpecificHeatCapacity{J_per_kgK: x*y});
+		assert_eq!(mul_check(&InverseMass{per_kg: x}, &HeatCapacity{J_per_K: y}), SpecificHeatCapacity{J_per_kgK: x*y});
+		assert_eq!(div_check(&InverseMass{per_kg: x}, &InverseEntropy{K_per_J: y}), SpecificHeatCapacity{J_per_kgK: x/y});
+		assert_eq!(div_check(&InverseMass{per_kg: x}, &InverseHeatCapacity{K_per_J: y}), SpecificHeatCapacity{J_per_kgK: x/y});
+		assert_eq!(mul_check(&InverseMass{per_kg: x}, &InverseSpecificHeatCapacity{kgK_per_J: y}), InverseHeatCapacity{K_per_J: x*y});
+		assert_eq!(div_check(&InverseMass{per_kg: x}, &SpecificHeatCapacity{J_per_kgK: y}), InverseHeatCapacity{K_per_J: x/y});
+		assert_eq!(div_check(&InverseTemperature{per_K: x}, &Entropy{J_per_K: y}), InverseEnergy{per_J: x/y});
+		assert_eq!(div_check(&InverseTemperature{per_K: x}, &HeatCapacity{J_per_K: y}), InverseEnergy{per_J: x/y});
+		assert_eq!(mul_check(&InverseTemperature{per_K: x}, &InverseEntropy{K_per_J: y}), InverseEnergy{per_J: x*y});
+		assert_eq!(mul_check(&InverseTemperature{per_K: x}, &InverseHeatCapacity{K_per_J: y}), InverseEnergy{per_J: x*y});
+		assert_eq!(mul_check(&InverseTemperature{per_K: x}, &Energy{J: y}), HeatCapacity{J_per_K: x*y});
+		assert_eq!(mul_check(&InverseTemperature{per_K: x}, &Torque{Nm: y}), HeatCapacity{J_per_K: x*y});
+		assert_eq!(div_check(&InverseTemperature{per_K: x}, &InverseEnergy{per_J: y}), HeatCapacity{J_per_K: x/y});
+		assert_eq!(div_check(&InverseTemperature{per_K: x}, &InverseTorque{per_Nm: y}), HeatCapacity{J_per_K: x/y});
+		assert_eq!(div_check(&Mass{kg: x}, &Entropy{J_per_K: y}), InverseSpecificHeatCapacity{kgK_per_J: x/y});
+		assert_eq!(div_check(&Mass{kg: x}, &HeatCapacity{J_per_K: y}), InverseSpecificHeatCapacity{kgK_per_J: x/y});
+		assert_eq!(mul_check(&Mass{kg: x}, &InverseEntropy{K_per_J: y}), InverseSpecificHeatCapacity{kgK_per_J: x*y});
+		assert_eq!(mul_check(&Mass{kg: x}, &InverseHeatCapacity{K_per_J: y}), InverseSpecificHeatCapacity{kgK_per_J: x*y});
+		assert_eq!(div_check(&Mass{kg: x}, &InverseSpecificHeatCapacity{kgK_per_J: y}), HeatCapacity{J_per_K: x/y});
+		assert_eq!(mul_check(&Mass{kg: x}, &SpecificHeatCapacity{J_per_kgK: y}), HeatCapacity{J_per_K: x*y});
+		assert_eq!(mul_check(&Temperature{K: x}, &Entropy{J_per_K: y}), Energy{J: x*y});
+		assert_eq!(mul_check(&Temperature{K: x}, &HeatCapacity{J_per_K: y}), Energy{J: x*y});
+		assert_eq!(div_check(&Temperature{K: x}, &InverseEntropy{K_per_J: y}), Energy{J: x/y});
+		assert_eq!(div_check(&Temperature{K: x}, &InverseHeatCapacity{K_per_J: y}), Energy{J: x/y});
+		assert_eq!(div_check(&Temperature{K: x}, &Energy{J: y}), InverseHeatCapacity{K_per_J: x/y});
+		assert_eq!(div_check(&Temperature{K: x}, &Torque{Nm: y}), InverseHeatCapacity{K_per_J: x/y});
+		assert_eq!(mul_check(&Temperature{K: x}, &InverseEnergy{per_J: y}), InverseHeatCapacity{K_per_J: x*y});
+		assert_eq!(mul_check(&Temperature{K: x}, &InverseTorque{per_Nm: y}), InverseHeatCapacity{K_per_J: x*y});
+		assert_eq!(mul_check(&TemperatureInterval{K: x}, &Entropy{J_per_K: y}), Energy{J: x*y});
+		assert_eq!(mul_check(&TemperatureInterval{K: x}, &HeatCapacity{J_per_K: y}), Energy{J: x*y});
+		assert_eq!(div_check(&TemperatureInterval{K: x}, &InverseEntropy{K_per_J: y}), Energy{J: x/y});
+		assert_eq!(div_check(&TemperatureInterval{K: x}, &InverseHeatCapacity{K_per_J: y}), Energy{J: x/y});
+		assert_eq!(div_check(&TemperatureInterval{K: x}, &Energy{J: y}), InverseHeatCapacity{K_per_J: x/y});
+		assert_eq!(div_check(&TemperatureInterval{K: x}, &Torque{Nm: y}), InverseHeatCapacity{K_per_J: x/y});
+		assert_eq!(mul_check(&TemperatureInterval{K: x}, &InverseEnergy{per_J: y}), InverseHeatCapacity{K_per_J: x*y});
+		assert_eq!(mul_check(&TemperatureInterval{K: x}, &InverseTorque{per_Nm: y}), InverseHeatCapacity{K_per_J: x*y});
+		assert_eq!(mul_check(&Entropy{J_per_K: x}, &InverseMass{per_kg: y}), SpecificHeatCapacity{J_per_kgK: x*y});
+		assert_eq!(div_check(&Entropy{J_per_K: x}, &InverseTemperature{per_K: y}), Energy{J: x/y});
+		assert_eq!(div_check(&Entropy{J_per_K: x}, &Mass{kg: y}), SpecificHeatCapacity{J_per_kgK: x/y});
+		assert_eq!(mul_check(&Entropy{J_per_K: x}, &Temperature{K: y}), Energy{J: x*y});
+		assert_eq!(mul_check(&Entropy{J_per_K: x}, &TemperatureInterval{K: y}), Energy{J: x*y});
+		assert_eq!(mul_check(&Entropy{J_per_K: x}, &InverseSpecificHeatCapacity{kgK_per_J: y}), Mass{kg: x*y});
+		assert_eq!(div_check(&Entropy{J_per_K: x}, &SpecificHeatCapacity{J_per_kgK: y}), Mass{kg: x/y});
+		assert_eq!(div_check(&Entropy{J_per_K: x}, &Energy{J: y}), InverseTemperature{per_K: x/y});
+		assert_eq!(div_check(&Entropy{J_per_K: x}, &Torque{Nm: y}), InverseTemperature{per_K: x/y});
+		assert_eq!(mul_check(&Entropy{J_per_K: x}, &InverseEnergy{per_J: y}), InverseTemperature{per_K: x*y});
+		assert_eq!(mul_check(&Entropy{J_per_K: x}, &InverseTorque{per_Nm: y}), InverseTemperature{per_K: x*y});
+		assert_eq!(mul_check(&HeatCapacity{J_per_K: x}, &InverseMass{per_kg: y}), SpecificHeatCapacity{J_per_kgK: x*y});
+		assert_eq!(div_check(&HeatCapacity{J_per_K: x}, &InverseTemperature{per_K: y}), Energy{J: x/y});
+		assert_eq!(div_check(&HeatCapacity{J_per_K: x}, &Mass{kg: y}), SpecificHeatCapacity{J_per_kgK: x/y});
+		assert_eq!(mul_check(&HeatCapacity{J_per_K: x}, &Temperature{K: y}), Energy{J: x*y});
+		assert_eq!(mul_check(&HeatCapacity{J_per_K: x}, &TemperatureInterval{K: y}), Energy{J: x*y});
+		assert_eq!(mul_check(&HeatCapacity{J_per_K: x}, &InverseSpecificHeatCapacity{kgK_per_J: y}), Mass{kg: x*y});
+		assert_eq!(div_check(&HeatCapacity{J_per_K: x}, &SpecificHeatCapacity{J_per_kgK: y}), Mass{kg: x/y});
+		assert_eq!(div_check(&HeatCapacity{J_per_K: x}, &Energy{J: y}), InverseTemperature{per_K: x/y});
+		assert_eq!(div_check(&HeatCapacity{J_per_K: x}, &Torque{Nm: y}), InverseTemperature{per_K: x/y});
+		assert_eq!(mul_check(&HeatCapacity{J_per_K: x}, &InverseEnergy{per_J: y}), InverseTemperature{per_K: x*y});
+		assert_eq!(mul_check(&HeatCapacity{J_per_K: x}, &InverseTorque{per_Nm: y}), InverseTemperature{per_K: x*y});
+		assert_eq!(div_check(&InverseEntropy{K_per_J: x}, &InverseMass{per_kg: y}), InverseSpecificHeatCapacity{kgK_per_J: x/y});
+		assert_eq!(mul_check(&InverseEntropy{K_per_J: x}, &InverseTemperature{per_K: y}), InverseEnergy{per_J: x*y});
+		assert_eq!(mul_check(&InverseEntropy{K_per_J: x}, &Mass{kg: y}), InverseSpecificHeatCapacity{kgK_per_J: x*y});
+		assert_eq!(div_check(&InverseEntropy{K_per_J: x}, &Temperature{K: y}), InverseEnergy{per_J: x/y});
+		assert_eq!(div_check(&InverseEntropy{K_per_J: x}, &TemperatureInterval{K: y}), InverseEnergy{per_J: x/y});
+		assert_eq!(div_check(&InverseEntropy{K_per_J: x}, &InverseSpecificHeatCapacity{kgK_per_J: y}), InverseMass{per_kg: x/y});
+		assert_eq!(mul_check(&InverseEntropy{K_per_J: x}, &SpecificHeatCapacity{J_per_kgK: y}), InverseMass{per_kg: x*y});
+		assert_eq!(mul_check(&InverseEntropy{K_per_J: x}, &Energy{J: y}), Temperature{K: x*y});
+		assert_eq!(mul_check(&InverseEntropy{K_per_J: x}, &Torque{Nm: y}), Temperature{K: x*y});
+		assert_eq!(div_check(&InverseEntropy{K_per_J: x}, &InverseEnergy{per_J: y}), Temperature{K: x/y});
+		assert_eq!(div_check(&InverseEntropy{K_per_J: x}, &InverseTorque{per_Nm: y}), Temperature{K: x/y});
+		assert_eq!(div_check(&InverseHeatCapacity{K_per_J: x}, &InverseMass{per_kg: y}), InverseSpecificHeatCapacity{kgK_per_J: x/y});
+		assert_eq!(mul_check(&InverseHeatCapacity{K_per_J: x}, &InverseTemperature{per_K: y}), InverseEnergy{per_J: x*y});
+		assert_eq!(mul_check(&InverseHeatCapacity{K_per_J: x}, &Mass{kg: y}), InverseSpecificHeatCapacity{kgK_per_J: x*y});
+		assert_eq!(div_check(&InverseHeatCapacity{K_per_J: x}, &Temperature{K: y}), InverseEnergy{per_J: x/y});
+		assert_eq!(div_check(&InverseHeatCapacity{K_per_J: x}, &TemperatureInterval{K: y}), InverseEnergy{per_J: x/y});
+		assert_eq!(div_check(&InverseHeatCapacity{K_per_J: x}, &InverseSpecificHeatCapacity{kgK_per_J: y}), InverseMass{per_kg: x/y});
+		assert_eq!(mul_check(&InverseHeatCapacity{K_per_J: x}, &SpecificHeatCapacity{J_per_kgK: y}), InverseMass{per_kg: x*y});
+		assert_eq!(mul_check(&InverseHeatCapacity{K_per_J: x}, &Energy{J: y}), Temperature{K: x*y});
+		assert_eq!(mul_check(&InverseHeatCapacity{K_per_J: x}, &Torque{Nm: y}), Temperature{K: x*y});
+		assert_eq!(div_check(&InverseHeatCapacity{K_per_J: x}, &InverseEnergy{per_J: y}), Temperature{K: x/y});
+		assert_eq!(div_check(&InverseHeatCapacity{K_per_J: x}, &InverseTorque{per_Nm: y}), Temperature{K: x/y});
+		assert_eq!(mul_check(&InverseSpecificHeatCapacity{kgK_per_J: x}, &InverseMass{per_kg: y}), InverseHeatCapacity{K_per_J: x*y});
+		assert_eq!(div_check(&InverseSpecificHeatCapacity{kgK_per_J: x}, &Mass{kg: y}), InverseHeatCapacity{K_per_J: x/y});
+		assert_eq!(mul_check(&InverseSpecificHeatCapacity{kgK_per_J: x}, &Entropy{J_per_K: y}), Mass{kg: x*y});
+		assert_eq!(mul_check(&InverseSpecificHeatCapacity{kgK_per_J: x}, &HeatCapacity{J_per_K: y}), Mass{kg: x*y});
+		assert_eq!(div_check(&InverseSpecificHeatCapacity{kgK_per_J: x}, &InverseEntropy{K_per_J: y}), Mass{kg: x/y});
+		assert_eq!(div_check(&InverseSpecificHeatCapacity{kgK_per_J: x}, &InverseHeatCapacity{K_per_J: y}), Mass{kg: x/y});
+		assert_eq!(div_check(&SpecificHeatCapacity{J_per_kgK: x}, &InverseMass{per_kg: y}), HeatCapacity{J_per_K: x/y});
+		assert_eq!(mul_check(&SpecificHeatCapacity{J_per_kgK: x}, &Mass{kg: y}), HeatCapacity{J_per_K: x*y});
+		assert_eq!(div_check(&SpecificHeatCapacity{J_per_kgK: x}, &Entropy{J_per_K: y}), InverseMass{per_kg: x/y});
+		assert_eq!(div_check(&SpecificHeatCapacity{J_per_kgK: x}, &HeatCapacity{J_per_K: y}), InverseMass{per_kg: x/y});
+		assert_eq!(mul_check(&SpecificHeatCapacity{J_per_kgK: x}, &InverseEntropy{K_per_J: y}), InverseMass{per_kg: x*y});
+		assert_eq!(mul_check(&SpecificHeatCapacity{J_per_kgK: x}, &InverseHeatCapacity{K_per_J: y}), InverseMass{per_kg: x*y});
+		assert_eq!(mul_check(&Energy{J: x}, &InverseTemperature{per_K: y}), HeatCapacity{J_per_K: x*y});
+		assert_eq!(div_check(&Energy{J: x}, &Temperature{K: y}), HeatCapacity{J_per_K: x/y});
+		assert_eq!(div_check(&Energy{J: x}, &TemperatureInterval{K: y}), HeatCapacity{J_per_K: x/y});
+		assert_eq!(div_check(&Energy{J: x}, &Entropy{J_per_K: y}), Temperature{K: x/y});
+		assert_eq!(div_check(&Energy{J: x}, &HeatCapacity{J_per_K: y}), Temperature{K: x/y});
+		assert_eq!(mul_check(&Energy{J: x}, &InverseEntropy{K_per_J: y}), Temperature{K: x*y});
+		assert_eq!(mul_check(&Energy{J: x}, &InverseHeatCapacity{K_per_J: y}), Temperature{K: x*y});
+		assert_eq!(div_check(&InverseEnergy{per_J: x}, &InverseTemperature{per_K: y}), InverseHeatCapacity{K_per_J: x/y});
+		assert_eq!(mul_check(&InverseEnergy{per_J: x}, &Temperature{K: y}), InverseHeatCapacity{K_per_J: x*y});
+		assert_eq!(mul_check(&InverseEnergy{per_J: x}, &TemperatureInterval{K: y}), InverseHeatCapacity{K_per_J: x*y});
+		assert_eq!(mul_check(&InverseEnergy{per_J: x}, &Entropy{J_per_K: y}), InverseTemperature{per_K: x*y});
+		assert_eq!(mul_check(&InverseEnergy{per_J: x}, &HeatCapacity{J_per_K: y}), InverseTemperature{per_K: x*y});
+		assert_eq!(div_check(&InverseEnergy{per_J: x}, &InverseEntropy{K_per_J: y}), InverseTemperature{per_K: x/y});
+		assert_eq!(div_check(&InverseEnergy{per_J: x}, &InverseHeatCapacity{K_per_J: y}), InverseTemperature{per_K: x/y});
+		assert_eq!(div_check(&InverseTorque{per_Nm: x}, &InverseTemperature{per_K: y}), InverseHeatCapacity{K_per_J: x/y});
+		assert_eq!(mul_check(&InverseTorque{per_Nm: x}, &Temperature{K: y}), InverseHeatCapacity{K_per_J: x*y});
+		assert_eq!(mul_check(&InverseTorque{per_Nm: x}, &TemperatureInterval{K: y}), InverseHeatCapacity{K_per_J: x*y});
+		assert_eq!(mul_check(&InverseTorque{per_Nm: x}, &Entropy{J_per_K: y}), InverseTemperature{per_K: x*y});
+		assert_eq!(mul_check(&InverseTorque{per_Nm: x}, &HeatCapacity{J_per_K: y}), InverseTemperature{per_K: x*y});
+		assert_eq!(div_check(&InverseTorque{per_Nm: x}, &InverseEntropy{K_per_J: y}), InverseTemperature{per_K: x/y});
+		assert_eq!(div_check(&InverseTorque{per_Nm: x}, &InverseHeatCapacity{K_per_J: y}), InverseTemperature{per_K: x/y});
+		assert_eq!(mul_check(&Torque{Nm: x}, &InverseTemperature{per_K: y}), HeatCapacity{J_per_K: x*y});
+		assert_eq!(div_check(&Torque{Nm: x}, &Temperature{K: y}), HeatCapacity{J_per_K: x/y});
+		assert_eq!(div_check(&Torque{Nm: x}, &TemperatureInterval{K: y}), HeatCapacity{J_per_K: x/y});
+		assert_eq!(div_check(&Torque{Nm: x}, &Entropy{J_per_K: y}), Temperature{K: x/y});
+		assert_eq!(div_check(&Torque{Nm: x}, &HeatCapacity{J_per_K: y}), Temperature{K: x/y});
+		assert_eq!(mul_check(&Torque{Nm: x}, &InverseEntropy{K_per_J: y}), Temperature{K: x*y});
+		assert_eq!(mul_check(&Torque{Nm: x}, &InverseHeatCapacity{K_per_J: y}), Temperature{K: x*y});
+		assert_eq!(div_check(&(x as f64), &Entropy{J_per_K: y as f64}), InverseHeatCapacity{K_per_J: x as f64/y as f64});
+		assert_eq!(div_check(&(x as f32), &Entropy{J_per_K: y as f32}), InverseHeatCapacity{K_per_J: x as f32/y as f32});
+		assert_eq!(div_check(&(x as i64), &Entropy{J_per_K: y as i64}), InverseHeatCapacity{K_per_J: x as i64/y as i64});
+		assert_eq!(div_check(&(x as i32), &Entropy{J_per_K: y as i32}), InverseHeatCapacity{K_per_J: x as i32/y as i32});
+		assert_eq!(div_check(&(x as f64), &HeatCapacity{J_per_K: y as f64}), InverseHeatCapacity{K_per_J: x as f64/y as f64});
+		assert_eq!(div_check(&(x as f32), &HeatCapacity{J_per_K: y as f32}), InverseHeatCapacity{K_per_J: x as f32/y as f32});
+		assert_eq!(div_check(&(x as i64), &HeatCapacity{J_per_K: y as i64}), InverseHeatCapacity{K_per_J: x as i64/y as i64});
+		assert_eq!(div_check(&(x as i32), &HeatCapacity{J_per_K: y as i32}), InverseHeatCapacity{K_per_J: x as i32/y as i32});
+		assert_eq!(div_check(&(x as f64), &InverseEntropy{K_per_J: y as f64}), HeatCapacity{J_per_K: x as f64/y as f64});
+		assert_eq!(div_check(&(x as f32), &InverseEntropy{K_per_J: y as f32}), HeatCapacity{J_per_K: x as f32/y as f32});
+		assert_eq!(div_check(&(x as i64), &InverseEntropy{K_per_J: y as i64}), HeatCapacity{J_per_K: x as i64/y as i64});
+		assert_eq!(div_check(&(x as i32), &InverseEntropy{K_per_J: y as i32}), HeatCapacity{J_per_K: x as i32/y as i32});
+		assert_eq!(div_check(&(x as f64), &InverseHeatCapacity{K_per_J: y as f64}), HeatCapacity{J_per_K: x as f64/y as f64});
+		assert_eq!(div_check(&(x as f32), &InverseHeatCapacity{K_per_J: y as f32}), HeatCapacity{J_per_K: x as f32/y as f32});
+		assert_eq!(div_check(&(x as i64), &InverseHeatCapacity{K_per_J: y as i64}), HeatCapacity{J_per_K: x as i64/y as i64});
+		assert_eq!(div_check(&(x as i32), &InverseHeatCapacity{K_per_J: y as i32}), HeatCapacity{J_per_K: x as i32/y as i32});
+		assert_eq!(div_check(&Distance{m: x}, &ThermalConductance{W_per_K: y}), ThermalResistivity{mK_per_W: x/y});
+		assert_eq!(mul_check(&Distance{m: x}, &ThermalConductivity{W_per_mK: y}), ThermalConductance{W_per_K: x*y});
+		assert_eq!(mul_check(&Distance{m: x}, &ThermalResistance{K_per_W: y}), ThermalResistivity{mK_per_W: x*y});
+		assert_eq!(div_check(&Distance{m: x}, &ThermalResistivity{mK_per_W: y}), ThermalConductance{W_per_K: x/y});
+		assert_eq!(mul_check(&InverseDistance{per_m: x}, &ThermalConductance{W_per_K: y}), ThermalConductivity{W_per_mK: x*y});
+		assert_eq!(div_check(&InverseDistance{per_m: x}, &ThermalConductivity{W_per_mK: y}), ThermalResistance{K_per_W: x/y});
+		assert_eq!(div_check(&InverseDistance{per_m: x}, &ThermalResistance{K_per_W: y}), ThermalConductivity{W_per_mK: x/y});
+		assert_eq!(mul_check(&InverseDistance{per_m: x}, &ThermalResistivity{mK_per_W: y}), ThermalResistance{K_per_W: x*y});
+		assert_eq!(div_check(&InverseTemperature{per_K: x}, &ThermalConductance{W_per_K: y}), InversePower{per_W: x/y});
+		assert_eq!(mul_check(&InverseTemperature{per_K: x}, &ThermalResistance{K_per_W: y}), InversePower{per_W: x*y});
+		assert_eq!(div_check(&InverseTemperature{per_K: x}, &InversePower{per_W: y}), ThermalConductance{W_per_K: x/y});
+		assert_eq!(mul_check(&InverseTemperature{per_K: x}, &Power{W: y}), ThermalConductance{W_per_K: x*y});
+		assert_eq!(mul_check(&Temperature{K: x}, &ThermalConductance{W_per_K: y}), Power{W: x*y});
+		assert_eq!(div_check(&Temperature{K: x}, &ThermalResistance{K_per_W: y}), Power{W: x/y});
+		assert_eq!(mul_check(&Temperature{K: x}, &InversePower{per_W: y}), ThermalResistance{K_per_W: x*y});
+		assert_eq!(div_check(&Temperature{K: x}, &Power{W: y}), ThermalResistance{K_per_W: x/y});
+		assert_eq!(mul_check(&TemperatureInterval{K: x}, &ThermalConductance{W_per_K: y}), Power{W: x*y});
+		assert_eq!(div_check(&TemperatureInterval{K: x}, &ThermalResistance{K_per_W: y}), Power{W: x/y});
+		assert_eq!(mul_check(&TemperatureInterval{K: x}, &InversePower{per_W: y}), ThermalResistance{K_per_W: x*y});
+		assert_eq!(div_check(&TemperatureInterval{K: x}, &Power{W: y}), ThermalResistance{K_per_W: x/y});
+		assert_eq!(div_check(&Time{s: x}, &Entropy{J_per_K: y}), ThermalResistance{K_per_W: x/y});
+		assert_eq!(div_check(&Time{s: x}, &HeatCapacity{J_per_K: y}), ThermalResistance{K_per_W: x/y});
+		assert_eq!(mul_check(&Time{s: x}, &InverseEntropy{K_per_J: y}), ThermalResistance{K_per_W: x*y});
+		assert_eq!(mul_check(&Time{s: x}, &InverseHeatCapacity{K_per_J: y}), ThermalResistance{K_per_W: x*y});
+		assert_eq!(mul_check(&Time{s: x}, &ThermalConductance{W_per_K: y}), HeatCapacity{J_per_K: x*y});
+		assert_eq!(div_check(&Time{s: x}, &ThermalResistance{K_per_W: y}), HeatCapacity{J_per_K: x/y});
+		assert_eq!(div_check(&Entropy{J_per_K: x}, &Time{s: y}), ThermalConductance{W_per_K: x/y});
+		assert_eq!(div_check(&Entropy{J_per_K: x}, &ThermalConductance{W_per_K: y}), Time{s: x/y});
+		assert_eq!(mul_check(&Entropy{J_per_K: x}, &ThermalResistance{K_per_W: y}), Time{s: x*y});
+		assert_eq!(mul_check(&Entropy{J_per_K: x}, &Frequency{Hz: y}), ThermalConductance{W_per_K: x*y});
+		assert_eq!(div_check(&HeatCapacity{J_per_K: x}, &Time{s: y}), ThermalConductance{W_per_K: x/y});
+		assert_eq!(div_check(&HeatCapacity{J_per_K: x}, &ThermalConductance{W_per_K: y}), Time{s: x/y});
+		assert_eq!(mul_check(&HeatCapacity{J_per_K: x}, &ThermalResistance{K_per_W: y}), Time{s: x*y});
+		assert_eq!(mul_check(&HeatCapacity{J_per_K: x}, &Frequency{Hz: y}), ThermalConductance{W_per_K: x*y});
+		assert_eq!(mul_check(&InverseEntropy{K_per_J: x}, &Time{s: y}), ThermalResistance{K_per_W: x*y});
+		assert_eq!(mul_check(&InverseEntropy{K_per_J: x}, &ThermalConductance{W_per_K: y}), Frequency{Hz: x*y});
+		assert_eq!(div_check(&InverseEntropy{K_per_J: x}, &ThermalResistance{K_per_W: y}), Frequency{Hz: x/y});
+		assert_eq!(div_check(&InverseEntropy{K_per_J: x}, &Frequency{Hz: y}), ThermalResistance{K_per_W: x/y});
+		assert_eq!(mul_check(&InverseHeatCapacity{K_per_J: x}, &Time{s: y}), ThermalResistance{K_per_W: x*y});
+		assert_eq!(mul_check(&InverseHeatCapacity{K_per_J: x}, &ThermalConductance{W_per_K: y}), Frequency{Hz: x*y});
+		assert_eq!(div_check(&InverseHeatCapacity{K_per_J: x}, &ThermalResistance{K_per_W: y}), Frequency{Hz: x/y});
+		assert_eq!(div_check(&InverseHeatCapacity{K_per_J: x}, &Frequency{Hz: y}), ThermalResistance{K_per_W: x/y});
+		assert_eq!(mul_check(&InverseSpecificHeatCapacity{kgK_per_J: x}, &ThermalConductance{W_per_K: y}), MassFlowRate{kgps: x*y});
+		assert_eq!(mul_check(&InverseSpecificHeatCapacity{kgK_per_J: x}, &ThermalConductivity{W_per_mK: y}), DynamicViscosity{Pas: x*y});
+		assert_eq!(div_check(&InverseSpecificHeatCapacity{kgK_per_J: x}, &ThermalResistance{K_per_W: y}), MassFlowRate{kgps: x/y});
+		assert_eq!(div_check(&InverseSpecificHeatCapacity{kgK_per_J: x}, &ThermalResistivity{mK_per_W: y}), DynamicViscosity{Pas: x/y});
+		assert_eq!(div_check(&InverseSpecificHeatCapacity{kgK_per_J: x}, &DynamicViscosity{Pas: y}), ThermalResistivity{mK_per_W: x/y});
+		assert_eq!(mul_check(&InverseSpecificHeatCapacity{kgK_per_J: x}, &InverseDynamicViscosity{per_Pas: y}), ThermalResistivity{mK_per_W: x*y});
+		assert_eq!(mul_check(&InverseSpecificHeatCapacity{kgK_per_J: x}, &InverseMassFlowRate{s_per_kg: y}), ThermalResistance{K_per_W: x*y});
+		assert_eq!(div_check(&InverseSpecificHeatCapacity{kgK_per_J: x}, &MassFlowRate{kgps: y}), ThermalResistance{K_per_W: x/y});
+		assert_eq!(div_check(&SpecificHeatCapacity{J_per_kgK: x}, &ThermalConductance{W_per_K: y}), InverseMassFlowRate{s_per_kg: x/y});
+		assert_eq!(div_check(&SpecificHeatCapacity{J_per_kgK: x}, &ThermalConductivity{W_per_mK: y}), InverseDynamicViscosity{per_Pas: x/y});
+		assert_eq!(mul_check(&SpecificHeatCapacity{J_per_kgK: x}, &ThermalResistance{K_per_W: y}), InverseMassFlowRate{s_per_kg: x*y});
+		assert_eq!(mul_check(&SpecificHeatCapacity{J_per_kgK: x}, &ThermalResistivity{mK_per_W: y}), InverseDynamicViscosity{per_Pas: x*y});
+		assert_eq!(mul_check(&SpecificHeatCapacity{J_per_kgK: x}, &DynamicViscosity{Pas: y}), ThermalConductivity{W_per_mK: x*y});
+		assert_eq!(div_check(&SpecificHeatCapacity{J_per_kgK: x}, &InverseDynamicViscosity{per_Pas: y}), ThermalConductivity{W_per_mK: x/y});
+		assert_eq!(div_check(&SpecificHeatCapacity{J_per_kgK: x}, &InverseMassFlowRate{s_per_kg: y}), ThermalConductance{W_per_K: x/y});
+		assert_eq!(mul_check(&SpecificHeatCapacity{J_per_kgK: x}, &MassFlowRate{kgps: y}), ThermalConductance{W_per_K: x*y});
+		assert_eq!(div_check(&ThermalConductance{W_per_K: x}, &Distance{m: y}), ThermalConductivity{W_per_mK: x/y});
+		assert_eq!(mul_check(&ThermalConductance{W_per_K: x}, &InverseDistance{per_m: y}), ThermalConductivity{W_per_mK: x*y});
+		assert_eq!(div_check(&ThermalConductance{W_per_K: x}, &InverseTemperature{per_K: y}), Power{W: x/y});
+		assert_eq!(mul_check(&ThermalConductance{W_per_K: x}, &Temperature{K: y}), Power{W: x*y});
+		assert_eq!(mul_check(&ThermalConductance{W_per_K: x}, &TemperatureInterval{K: y}), Power{W: x*y});
+		assert_eq!(mul_check(&ThermalConductance{W_per_K: x}, &Time{s: y}), HeatCapacity{J_per_K: x*y});
+		assert_eq!(div_check(&ThermalConductance{W_per_K: x}, &Entropy{J_per_K: y}), Frequency{Hz: x/y});
+		assert_eq!(div_check(&ThermalConductance{W_per_K: x}, &HeatCapacity{J_per_K: y}), Frequency{Hz: x/y});
+		assert_eq!(mul_check(&ThermalConductance{W_per_K: x}, &InverseEntropy{K_per_J: y}), Frequency{Hz: x*y});
+		assert_eq!(mul_check(&ThermalConductance{W_per_K: x}, &InverseHeatCapacity{K_per_J: y}), Frequency{Hz: x*y});
+		assert_eq!(mul_check(&ThermalConductance{W_per_K: x}, &InverseSpecificHeatCapacity{kgK_per_J: y}), MassFlowRate{kgps: x*y});
+		assert_eq!(div_check(&ThermalConductance{W_per_K: x}, &SpecificHeatCapacity{J_per_kgK: y}), MassFlowRate{kgps: x/y});
+		assert_eq!(div_check(&ThermalConductance{W_per_K: x}, &ThermalConductivity{W_per_mK: y}), Distance{m: x/y});
+		assert_eq!(mul_check(&ThermalConductance{W_per_K: x}, &ThermalResistivity{mK_per_W: y}), Distance{m: x*y});
+		assert_eq!(div_check(&ThermalConductance{W_per_K: x}, &Frequency{Hz: y}), HeatCapacity{J_per_K: x/y});
+		assert_eq!(mul_check(&ThermalConductance{W_per_K: x}, &InverseMassFlowRate{s_per_kg: y}), SpecificHeatCapacity{J_per_kgK: x*y});
+		assert_eq!(mul_check(&ThermalConductance{W_per_K: x}, &InversePower{per_W: y}), InverseTemperature{per_K: x*y});
+		assert_eq!(div_check(&ThermalConductance{W_per_K: x}, &MassFlowRate{kgps: y}), SpecificHeatCapacity{J_per_kgK: x/y});
+		assert_eq!(div_check(&ThermalConductance{W_per_K: x}, &Power{W: y}), InverseTemperature{per_K: x/y});
+		assert_eq!(mul_check(&ThermalConductivity{W_per_mK: x}, &Distance{m: y}), ThermalConductance{W_per_K: x*y});
+		assert_eq!(div_check(&ThermalConductivity{W_per_mK: x}, &InverseDistance{per_m: y}), ThermalConductance{W_per_K: x/y});
+		assert_eq!(mul_check(&ThermalConductivity{W_per_mK: x}, &InverseSpecificHeatCapacity{kgK_per_J: y}), DynamicViscosity{Pas: x*y});
+		assert_eq!(div_check(&ThermalConductivity{W_per_mK: x}, &SpecificHeatCapacity{J_per_kgK: y}), DynamicViscosity{Pas: x/y});
+		assert_eq!(div_check(&ThermalConductivity{W_per_mK: x}, &ThermalConductance{W_per_K: y}), InverseDistance{per_m: x/y});
+		assert_eq!(mul_check(&ThermalConductivity{W_per_mK: x}, &ThermalResistance{K_per_W: y}), InverseDistance{per_m: x*y});
+		assert_eq!(div_check(&ThermalConductivity{W_per_mK: x}, &DynamicViscosity{Pas: y}), SpecificHeatCapacity{J_per_kgK: x/y});
+		assert_eq!(mul_check(&ThermalConductivity{W_per_mK: x}, &InverseDynamicViscosity{per_Pas: y}), SpecificHeatCapacity{J_per_kgK: x*y});
+		assert_eq!(mul_check(&ThermalResistance{K_per_W: x}, &Distance{m: y}), ThermalResistivity{mK_per_W: x*y});
+		assert_eq!(div_check(&ThermalResistance{K_per_W: x}, &InverseDistance{per_m: y}), ThermalResistivity{mK_per_W: x/y});
+		assert_eq!(mul_check(&ThermalResistance{K_per_W: x}, &InverseTemperature{per_K: y}), InversePower{per_W: x*y});
+		assert_eq!(div_check(&ThermalResistance{K_per_W: x}, &Temperature{K: y}), InversePower{per_W: x/y});
+		assert_eq!(div_check(&ThermalResistance{K_per_W: x}, &TemperatureInterval{K: y}), InversePower{per_W: x/y});
+		assert_eq!(div_check(&ThermalResistance{K_per_W: x}, &Time{s: y}), InverseHeatCapacity{K_per_J: x/y});
+		assert_eq!(mul_check(&ThermalResistance{K_per_W: x}, &Entropy{J_per_K: y}), Time{s: x*y});
+		assert_eq!(mul_check(&ThermalResistance{K_per_W: x}, &HeatCapacity{J_per_K: y}), Time{s: x*y});
+		assert_eq!(div_check(&ThermalResistance{K_per_W: x}, &InverseEntropy{K_per_J: y}), Time{s: x/y});
+		assert_eq!(div_check(&ThermalResistance{K_per_W: x}, &InverseHeatCapacity{K_per_J: y}), Time{s: x/y});
+		assert_eq!(div_check(&ThermalResistance{K_per_W: x}, &InverseSpecificHeatCapacity{kgK_per_J: y}), InverseMassFlowRate{s_per_kg: x/y});
+		assert_eq!(mul_check(&ThermalResistance{K_per_W: x}, &SpecificHeatCapacity{J_per_kgK: y}), InverseMassFlowRate{s_per_kg: x*y});
+		assert_eq!(mul_check(&ThermalResistance{K_per_W: x}, &ThermalConductivity{W_per_mK: y}), InverseDistance{per_m: x*y});
+		assert_eq!(div_check(&ThermalResistance{K_per_W: x}, &ThermalResistivity{mK_per_W: y}), InverseDistance{per_m: x/y});
+		assert_eq!(mul_check(&ThermalResistance{K_per_W: x}, &Frequency{Hz: y}), InverseHeatCapacity{K_per_J: x*y});
+		assert_eq!(div_check(&ThermalResistance{K_per_W: x}, &InverseMassFlowRate{s_per_kg: y}), InverseSpecificHeatCapacity{kgK_per_J: x/y});
+		assert_eq!(div_check(&ThermalResistance{K_per_W: x}, &InversePower{per_W: y}), Temperature{K: x/y});
+		assert_eq!(mul_check(&ThermalResistance{K_per_W: x}, &MassFlowRate{kgps: y}), InverseSpecificHeatCapacity{kgK_per_J: x*y});
+		assert_eq!(mul_check(&ThermalResistance{K_per_W: x}, &Power{W: y}), Temperature{K: x*y});
+		assert_eq!(div_check(&ThermalResistivity{mK_per_W: x}, &Distance{m: y}), ThermalResistance{K_per_W: x/y});
+		assert_eq!(mul_check(&ThermalResistivity{mK_per_W: x}, &InverseDistance{per_m: y}), ThermalResistance{K_per_W: x*y});
+		assert_eq!(div_check(&ThermalResistivity{mK_per_W: x}, &InverseSpecificHeatCapacity{kgK_per_J: y}), InverseDynamicViscosity{per_Pas: x/y});
+		assert_eq!(mul_check(&ThermalResistivity{mK_per_W: x}, &SpecificHeatCapacity{J_per_kgK: y}), InverseDynamicViscosity{per_Pas: x*y});
+		assert_eq!(mul_check(&ThermalResistivity{mK_per_W: x}, &ThermalConductance{W_per_K: y}), Distance{m: x*y});
+		assert_eq!(div_check(&ThermalResistivity{mK_per_W: x}, &ThermalResistance{K_per_W: y}), Distance{m: x/y});
+		assert_eq!(mul_check(&ThermalResistivity{mK_per_W: x}, &DynamicViscosity{Pas: y}), InverseSpecificHeatCapacity{kgK_per_J: x*y});
+		assert_eq!(div_check(&ThermalResistivity{mK_per_W: x}, &InverseDynamicViscosity{per_Pas: y}), InverseSpecificHeatCapacity{kgK_per_J: x/y});
+		assert_eq!(div_check(&DynamicViscosity{Pas: x}, &InverseSpecificHeatCapacity{kgK_per_J: y}), ThermalConductivity{W_per_mK: x/y});
+		assert_eq!(mul_check(&DynamicViscosity{Pas: x}, &SpecificHeatCapacity{J_per_kgK: y}), ThermalConductivity{W_per_mK: x*y});
+		assert_eq!(div_check(&DynamicViscosity{Pas: x}, &ThermalConductivity{W_per_mK: y}), InverseSpecificHeatCapacity{kgK_per_J: x/y});
+		assert_eq!(mul_check(&DynamicViscosity{Pas: x}, &ThermalResistivity{mK_per_W: y}), InverseSpecificHeatCapacity{kgK_per_J: x*y});
+		assert_eq!(mul_check(&Frequency{Hz: x}, &Entropy{J_per_K: y}), ThermalConductance{W_per_K: x*y});
+		assert_eq!(mul_check(&Frequency{Hz: x}, &HeatCapacity{J_per_K: y}), ThermalConductance{W_per_K: x*y});
+		assert_eq!(div_check(&Frequency{Hz: x}, &InverseEntropy{K_per_J: y}), ThermalConductance{W_per_K: x/y});
+		assert_eq!(div_check(&Frequency{Hz: x}, &InverseHeatCapacity{K_per_J: y}), ThermalConductance{W_per_K: x/y});
+		assert_eq!(div_check(&Frequency{Hz: x}, &ThermalConductance{W_per_K: y}), InverseHeatCapacity{K_per_J: x/y});
+		assert_eq!(mul_check(&Frequency{Hz: x}, &ThermalResistance{K_per_W: y}), InverseHeatCapacity{K_per_J: x*y});
+		assert_eq!(mul_check(&InverseDynamicViscosity{per_Pas: x}, &InverseSpecificHeatCapacity{kgK_per_J: y}), ThermalResistivity{mK_per_W: x*y});
+		assert_eq!(div_check(&InverseDynamicViscosity{per_Pas: x}, &SpecificHeatCapacity{J_per_kgK: y}), ThermalResistivity{mK_per_W: x/y});
+		assert_eq!(mul_check(&InverseDynamicViscosity{per_Pas: x}, &ThermalConductivity{W_per_mK: y}), SpecificHeatCapacity{J_per_kgK: x*y});
+		assert_eq!(div_check(&InverseDynamicViscosity{per_Pas: x}, &ThermalResistivity{mK_per_W: y}), SpecificHeatCapacity{J_per_kgK: x/y});
+		assert_eq!(mul_check(&InverseMassFlowRate{s_per_kg: x}, &InverseSpecificHeatCapacity{kgK_per_J: y}), ThermalResistance{K_per_W: x*y});
+		assert_eq!(div_check(&InverseMassFlowRate{s_per_kg: x}, &SpecificHeatCapacity{J_per_kgK: y}), ThermalResistance{K_per_W: x/y});
+		assert_eq!(mul_check(&InverseMassFlowRate{s_per_kg: x}, &ThermalConductance{W_per_K: y}), SpecificHeatCapacity{J_per_kgK: x*y});
+		assert_eq!(div_check(&InverseMassFlowRate{s_per_kg: x}, &ThermalResistance{K_per_W: y}), SpecificHeatCapacity{J_per_kgK: x/y});
+		assert_eq!(div_check(&InversePower{per_W: x}, &InverseTemperature{per_K: y}), ThermalResistance{K_per_W: x/y});
+		assert_eq!(mul_check(&InversePower{per_W: x}, &Temperature{K: y}), ThermalResistance{K_per_W: x*y});
+		assert_eq!(mul_check(&InversePower{per_W: x}, &TemperatureInterval{K: y}), ThermalResistance{K_per_W: x*y});
+		assert_eq!(mul_check(&InversePower{per_W: x}, &ThermalConductance{W_per_K: y}), InverseTemperature{per_K: x*y});
+		assert_eq!(div_check(&InversePower{per_W: x}, &ThermalResistance{K_per_W: y}), InverseTemperature{per_K: x/y});
+		assert_eq!(div_check(&MassFlowRate{kgps: x}, &InverseSpecificHeatCapacity{kgK_per_J: y}), ThermalConductance{W_per_K: x/y});
+		assert_eq!(mul_check(&MassFlowRate{kgps: x}, &SpecificHeatCapacity{J_per_kgK: y}), ThermalConductance{W_per_K: x*y});
+		assert_eq!(div_check(&MassFlowRate{kgps: x}, &ThermalConductance{W_per_K: y}), InverseSpecificHeatCapacity{kgK_per_J: x/y});
+		assert_eq!(mul_check(&MassFlowRate{kgps: x}, &ThermalResistance{K_per_W: y}), InverseSpecificHeatCapacity{kgK_per_J: x*y});
+		assert_eq!(mul_check(&Power{W: x}, &InverseTemperature{per_K: y}), ThermalConductance{W_per_K: x*y});
+		assert_eq!(div_check(&Power{W: x}, &Temperature{K: y}), ThermalConductance{W_per_K: x/y});
+		assert_eq!(div_check(&Power{W: x}, &TemperatureInterval{K: y}), ThermalConductance{W_per_K: x/y});
+		assert_eq!(div_check(&Power{W: x}, &ThermalConductance{W_per_K: y}), Temperature{K: x/y});
+		assert_eq!(mul_check(&Power{W: x}, &ThermalResistance{K_per_W: y}), Temperature{K: x*y});
+		assert_eq!(div_check(&(x as f64), &ThermalConductance{W_per_K: y as f64}), ThermalResistance{K_per_W: x as f64/y as f64});
+		assert_eq!(div_check(&(x as f32), &ThermalConductance{W_per_K: y as f32}), ThermalResistance{K_per_W: x as f32/y as f32});
+		assert_eq!(div_check(&(x as i64), &ThermalConductance{W_per_K: y as i64}), ThermalResistance{K_per_W: x as i64/y as i64});
+		assert_eq!(div_check(&(x as i32), &ThermalConductance{W_per_K: y as i32}), ThermalResistance{K_per_W: x as i32/y as i32});
+		assert_eq!(div_check(&(x as f64), &ThermalConductivity{W_per_mK: y as f64}), ThermalResistivity{mK_per_W: x as f64/y as f64});
+		assert_eq!(div_check(&(x as f32), &ThermalConductivity{W_per_mK: y as f32}), ThermalResistivity{mK_per_W: x as f32/y as f32});
+		assert_eq!(div_check(&(x as i64), &ThermalConductivity{W_per_mK: y as i64}), ThermalResistivity{mK_per_W: x as i64/y as i64});
+		assert_eq!(div_check(&(x as i32), &ThermalConductivity{W_per_mK: y as i32}), ThermalResistivity{mK_per_W: x as i32/y as i32});
+		assert_eq!(div_check(&(x as f64), &ThermalResistance{K_per_W: y as f64}), ThermalConductance{W_per_K: x as f64/y as f64});
+		assert_eq!(div_check(&(x as f32), &ThermalResistance{K_per_W: y as f32}), ThermalConductance{W_per_K: x as f32/y as f32});
+		assert_eq!(div_check(&(x as i64), &ThermalResistance{K_per_W: y as i64}), ThermalConductance{W_per_K: x as i64/y as i64});
+		assert_eq!(div_check(&(x as i32), &ThermalResistance{K_per_W: y as i32}), ThermalConductance{W_per_K: x as i32/y as i32});
+		assert_eq!(div_check(&(x as f64), &ThermalResistivity{mK_per_W: y as f64}), ThermalConductivity{W_per_mK: x as f64/y as f64});
+		assert_eq!(div_check(&(x as f32), &ThermalResistivity{mK_per_W: y as f32}), ThermalConductivity{W_per_mK: x as f32/y as f32});
+		assert_eq!(div_check(&(x as i64), &ThermalResistivity{mK_per_W: y as i64}), ThermalConductivity{W_per_mK: x as i64/y as i64});
+		assert_eq!(div_check(&(x as i32), &ThermalResistivity{mK_per_W: y as i32}), ThermalConductivity{W_per_mK: x as i32/y as i32});
+		assert_eq!(div_check(&Current{A: x}, &Distance{m: y}), MagneticFieldStrength{Apm: x/y});
+		assert_eq!(mul_check(&Current{A: x}, &InverseDistance{per_m: y}), MagneticFieldStrength{Apm: x*y});
+		assert_eq!(mul_check(&Current{A: x}, &InverseMagneticFieldStrength{m_per_A: y}), Distance{m: x*y});
+		assert_eq!(div_check(&Current{A: x}, &MagneticFieldStrength{Apm: y}), Distance{m: x/y});
+		assert_eq!(div_check(&Distance{m: x}, &Current{A: y}), InverseMagneticFieldStrength{m_per_A: x/y});
+		assert_eq!(mul_check(&Distance{m: x}, &InverseCurrent{per_A: y}), InverseMagneticFieldStrength{m_per_A: x*y});
+		assert_eq!(mul_check(&Distance{m: x}, &ElectricField{Vpm: y}), Voltage{V: x*y});
+		assert_eq!(div_check(&Distance{m: x}, &InverseElectricField{m_per_V: y}), Voltage{V: x/y});
+		assert_eq!(div_check(&Distance{m: x}, &InverseMagneticFieldStrength{m_per_A: y}), Current{A: x/y});
+		assert_eq!(mul_check(&Distance{m: x}, &InverseVoltage{per_V: y}), InverseElectricField{m_per_V: x*y});
+		assert_eq!(mul_check(&Distance{m: x}, &MagneticFieldStrength{Apm: y}), Current{A: x*y});
+		assert_eq!(div_check(&Distance{m: x}, &Voltage{V: y}), InverseElectricField{m_per_V: x/y});
+		assert_eq!(mul_check(&InverseCurrent{per_A: x}, &Distance{m: y}), InverseMagneticFieldStrength{m_per_A: x*y});
+		assert_eq!(div_check(&InverseCurrent{per_A: x}, &InverseDistance{per_m: y}), InverseMagneticFieldStrength{m_per_A: x/y});
+		assert_eq!(div_check(&InverseCurrent{per_A: x}, &InverseMagneticFieldStrength{m_per_A: y}), InverseDistance{per_m: x/y});
+		assert_eq!(mul_check(&InverseCurrent{per_A: x}, &MagneticFieldStrength{Apm: y}), InverseDistance{per_m: x*y});
+		assert_eq!(mul_check(&InverseDistance{per_m: x}, &Current{A: y}), MagneticFieldStrength{Apm: x*y});
+		assert_eq!(div_check(&InverseDistance{per_m: x}, &InverseCurrent{per_A: y}), MagneticFieldStrength{Apm: x/y});
+		assert_eq!(div_check(&InverseDistance{per_m: x}, &ElectricField{Vpm: y}), InverseVoltage{per_V: x/y});
+		assert_eq!(mul_check(&InverseDistance{per_m: x}, &InverseElectricField{m_per_V: y}), InverseVoltage{per_V: x*y});
+		assert_eq!(mul_check(&InverseDistance{per_m: x}, &InverseMagneticFieldStrength{m_per_A: y}), InverseCurrent{per_A: x*y});
+		assert_eq!(div_check(&InverseDistance{per_m: x}, &InverseVoltage{per_V: y}), ElectricField{Vpm: x/y});
+		assert_eq!(div_check(&InverseDistance{per_m: x}, &MagneticFieldStrength{Apm: y}), InverseCurrent{per_A: x/y});
+		assert_eq!(mul_check(&InverseDistance{per_m: x}, &Voltage{V: y}), ElectricField{Vpm: x*y});
+		assert_eq!(mul_check(&Charge{C: x}, &ElectricField{Vpm: y}), Force{N: x*y});
+		assert_eq!(div_check(&Charge{C: x}, &InverseElectricField{m_per_V: y}), Force{N: x/y});
+		assert_eq!(div_check(&Charge{C: x}, &Force{N: y}), InverseElectricField{m_per_V: x/y});
+		assert_eq!(mul_check(&Charge{C: x}, &InverseForce{per_N: y}), InverseElectricField{m_per_V: x*y});
+		assert_eq!(mul_check(&Conductance{S: x}, &ElectricField{Vpm: y}), MagneticFieldStrength{Apm: x*y});
+		assert_eq!(div_check(&Conductance{S: x}, &InverseElectricField{m_per_V: y}), MagneticFieldStrength{Apm: x/y});
+		assert_eq!(mul_check(&Conductance{S: x}, &InverseMagneticFieldStrength{m_per_A: y}), InverseElectricField{m_per_V: x*y});
+		assert_eq!(div_check(&Conductance{S: x}, &MagneticFieldStrength{Apm: y}), InverseElectricField{m_per_V: x/y});
+		assert_eq!(mul_check(&ElectricField{Vpm: x}, &Distance{m: y}), Voltage{V: x*y});
+		assert_eq!(div_check(&ElectricField{Vpm: x}, &InverseDistance{per_m: y}), Voltage{V: x/y});
+		assert_eq!(mul_check(&ElectricField{Vpm: x}, &Charge{C: y}), Force{N: x*y});
+		assert_eq!(mul_check(&ElectricField{Vpm: x}, &Conductance{S: y}), MagneticFieldStrength{Apm: x*y});
+		assert_eq!(div_check(&ElectricField{Vpm: x}, &InverseCharge{per_C: y}), Force{N: x/y});
+		assert_eq!(mul_check(&ElectricField{Vpm: x}, &InverseMagneticFieldStrength{m_per_A: y}), Resistance{Ohm: x*y});
+		assert_eq!(mul_check(&ElectricField{Vpm: x}, &InverseMagneticFluxDensity{m2_per_Wb: y}), Velocity{mps: x*y});
+		assert_eq!(mul_check(&ElectricField{Vpm: x}, &InverseVoltage{per_V: y}), InverseDistance{per_m: x*y});
+		assert_eq!(div_check(&ElectricField{Vpm: x}, &MagneticFieldStrength{Apm: y}), Resistance{Ohm: x/y});
+		assert_eq!(div_check(&ElectricField{Vpm: x}, &MagneticFluxDensity{T: y}), Velocity{mps: x/y});
+		assert_eq!(div_check(&ElectricField{Vpm: x}, &Resistance{Ohm: y}), MagneticFieldStrength{Apm: x/y});
+		assert_eq!(div_check(&ElectricField{Vpm: x}, &Voltage{V: y}), InverseDistance{per_m: x/y});
+		assert_eq!(div_check(&ElectricField{Vpm: x}, &Force{N: y}), InverseCharge{per_C: x/y});
+		assert_eq!(mul_check(&ElectricField{Vpm: x}, &InverseForce{per_N: y}), InverseCharge{per_C: x*y});
+		assert_eq!(mul_check(&ElectricField{Vpm: x}, &TimePerDistance{spm: y}), MagneticFluxDensity{T: x*y});
+		assert_eq!(div_check(&ElectricField{Vpm: x}, &Velocity{mps: y}), MagneticFluxDensity{T: x/y});
+		assert_eq!(div_check(&InverseCharge{per_C: x}, &ElectricField{Vpm: y}), InverseForce{per_N: x/y});
+		assert_eq!(mul_check(&InverseCharge{per_C: x}, &InverseElectricField{m_per_V: y}), InverseForce{per_N: x*y});
+		assert_eq!(mul_check(&InverseCharge{per_C: x}, &Force{N: y}), ElectricField{Vpm: x*y});
+		assert_eq!(div_check(&InverseCharge{per_C: x}, &InverseForce{per_N: y}), ElectricField{Vpm: x/y});
+		assert_eq!(div_check(&InverseElectricField{m_per_V: x}, &Distance{m: y}), InverseVoltage{per_V: x/y});
+		assert_eq!(mul_check(&InverseElectricField{m_per_V: x}, &InverseDistance{per_m: y}), InverseVoltage{per_V: x*y});
+		assert_eq!(div_check(&InverseElectricField{m_per_V: x}, &Charge{C: y}), InverseForce{per_N: x/y});
+		assert_eq!(div_check(&InverseElectricField{m_per_V: x}, &Conductance{S: y}), InverseMagneticFieldStrength{m_per_A: x/y});
+		assert_eq!(mul_check(&InverseElectricField{m_per_V: x}, &InverseCharge{per_C: y}), InverseForce{per_N: x*y});
+		assert_eq!(div_check(&InverseElectricField{m_per_V: x}, &InverseMagneticFieldStrength{m_per_A: y}), Conductance{S: x/y});
+		assert_eq!(div_check(&InverseElectricField{m_per_V: x}, &InverseMagneticFluxDensity{m2_per_Wb: y}), TimePerDistance{spm: x/y});
+		assert_eq!(div_check(&InverseElectricField{m_per_V: x}, &InverseVoltage{per_V: y}), Distance{m: x/y});
+		assert_eq!(mul_check(&InverseElectricField{m_per_V: x}, &MagneticFieldStrength{Apm: y}), Conductance{S: x*y});
+		assert_eq!(mul_check(&InverseElectricField{m_per_V: x}, &MagneticFluxDensity{T: y}), TimePerDistance{spm: x*y});
+		assert_eq!(mul_check(&InverseElectricField{m_per_V: x}, &Resistance{Ohm: y}), InverseMagneticFieldStrength{m_per_A: x*y});
+		assert_eq!(mul_check(&InverseElectricField{m_per_V: x}, &Voltage{V: y}), Distance{m: x*y});
+		assert_eq!(mul_check(&InverseElectricField{m_per_V: x}, &Force{N: y}), Charge{C: x*y});
+		assert_eq!(div_check(&InverseElectricField{m_per_V: x}, &InverseForce{per_N: y}), Charge{C: x/y});
+		assert_eq!(div_check(&InverseElectricField{m_per_V: x}, &TimePerDistance{spm: y}), InverseMagneticFluxDensity{m2_per_Wb: x/y});
+		assert_eq!(mul_check(&InverseElectricField{m_per_V: x}, &Velocity{mps: y}), InverseMagneticFluxDensity{m2_per_Wb: x*y});
+		assert_eq!(mul_check(&InverseMagneticFieldStrength{m_per_A: x}, &Current{A: y}), Distance{m: x*y});
+		assert_eq!(div_check(&InverseMagneticFieldStrength{m_per_A: x}, &Distance{m: y}), InverseCurrent{per_A: x/y});
+		assert_eq!(div_check(&InverseMagneticFieldStrength{m_per_A: x}, &InverseCurrent{per_A: y}), Distance{m: x/y});
+		assert_eq!(mul_check(&InverseMagneticFieldStrength{m_per_A: x}, &InverseDistance{per_m: y}), InverseCurrent{per_A: x*y});
+		assert_eq!(mul_check(&InverseMagneticFieldStrength{m_per_A: x}, &Conductance{S: y}), InverseElectricField{m_per_V: x*y});
+		assert_eq!(mul_check(&InverseMagneticFieldStrength{m_per_A: x}, &ElectricField{Vpm: y}), Resistance{Ohm: x*y});
+		assert_eq!(div_check(&InverseMagneticFieldStrength{m_per_A: x}, &InverseElectricField{m_per_V: y}), Resistance{Ohm: x/y});
+		assert_eq!(mul_check(&InverseMagneticFieldStrength{m_per_A: x}, &InverseMagneticFlux{per_Wb: y}), InverseForce{per_N: x*y});
+		assert_eq!(mul_check(&InverseMagneticFieldStrength{m_per_A: x}, &InverseMagneticFluxDensity{m2_per_Wb: y}), InversePressure{per_Pa: x*y});
+		assert_eq!(div_check(&InverseMagneticFieldStrength{m_per_A: x}, &MagneticFlux{Wb: y}), InverseForce{per_N: x/y});
+		assert_eq!(div_check(&InverseMagneticFieldStrength{m_per_A: x}, &MagneticFluxDensity{T: y}), InversePressure{per_Pa: x/y});
+		assert_eq!(div_check(&InverseMagneticFieldStrength{m_per_A: x}, &Resistance{Ohm: y}), InverseElectricField{m_per_V: x/y});
+		assert_eq!(mul_check(&InverseMagneticFieldStrength{m_per_A: x}, &Force{N: y}), MagneticFlux{Wb: x*y});
+		assert_eq!(div_check(&InverseMagneticFieldStrength{m_per_A: x}, &InverseForce{per_N: y}), MagneticFlux{Wb: x/y});
+		assert_eq!(div_check(&InverseMagneticFieldStrength{m_per_A: x}, &InversePressure{per_Pa: y}), MagneticFluxDensity{T: x/y});
+		assert_eq!(mul_check(&InverseMagneticFieldStrength{m_per_A: x}, &Pressure{Pa: y}), MagneticFluxDensity{T: x*y});
+		assert_eq!(mul_check(&InverseMagneticFlux{per_Wb: x}, &InverseMagneticFieldStrength{m_per_A: y}), InverseForce{per_N: x*y});
+		assert_eq!(div_check(&InverseMagneticFlux{per_Wb: x}, &MagneticFieldStrength{Apm: y}), InverseForce{per_N: x/y});
+		assert_eq!(mul_check(&InverseMagneticFlux{per_Wb: x}, &Force{N: y}), MagneticFieldStrength{Apm: x*y});
+		assert_eq!(div_check(&InverseMagneticFlux{per_Wb: x}, &InverseForce{per_N: y}), MagneticFieldStrength{Apm: x/y});
+		assert_eq!(mul_check(&InverseMagneticFluxDensity{m2_per_Wb: x}, &ElectricField{Vpm: y}), Velocity{mps: x*y});
+		assert_eq!(div_check(&InverseMagneticFluxDensity{m2_per_Wb: x}, &InverseElectricField{m_per_V: y}), Velocity{mps: x/y});
+		assert_eq!(mul_check(&InverseMagneticFluxDensity{m2_per_Wb: x}, &InverseMagneticFieldStrength{m_per_A: y}), InversePressure{per_Pa: x*y});
+		assert_eq!(div_check(&InverseMagneticFluxDensity{m2_per_Wb: x}, &MagneticFieldStrength{Apm: y}), InversePressure{per_Pa: x/y});
+		assert_eq!(div_check(&InverseMagneticFluxDensity{m2_per_Wb: x}, &InversePressure{per_Pa: y}), MagneticFieldStrength{Apm: x/y});
+		assert_eq!(mul_check(&InverseMagneticFluxDensity{m2_per_Wb: x}, &Pressure{Pa: y}), MagneticFieldStrength{Apm: x*y});
+		assert_eq!(mul_check(&InverseMagneticFluxDensity{m2_per_Wb: x}, &TimePerDistance{spm: y}), InverseElectricField{m_per_V: x*y});
+		assert_eq!(div_check(&InverseMagneticFluxDensity{m2_per_Wb: x}, &Velocity{mps: y}), InverseElectricField{m_per_V: x/y});
+		assert_eq!(mul_check(&InverseVoltage{per_V: x}, &Distance{m: y}), InverseElectricField{m_per_V: x*y});
+		assert_eq!(div_check(&InverseVoltage{per_V: x}, &InverseDistance{per_m: y}), InverseElectricField{m_per_V: x/y});
+		assert_eq!(mul_check(&InverseVoltage{per_V: x}, &ElectricField{Vpm: y}), InverseDistance{per_m: x*y});
+		assert_eq!(div_check(&InverseVoltage{per_V: x}, &InverseElectricField{m_per_V: y}), InverseDistance{per_m: x/y});
+		assert_eq!(div_check(&MagneticFieldStrength{Apm: x}, &Current{A: y}), InverseDistance{per_m: x/y});
+		assert_eq!(mul_check(&MagneticFieldStrength{Apm: x}, &Distance{m: y}), Current{A: x*y});
+		assert_eq!(mul_check(&MagneticFieldStrength{Apm: x}, &InverseCurrent{per_A: y}), InverseDistance{per_m: x*y});
+		assert_eq!(div_check(&MagneticFieldStrength{Apm: x}, &InverseDistance{per_m: y}), Current{A: x/y});
+		assert_eq!(div_check(&MagneticFieldStrength{Apm: x}, &Conductance{S: y}), ElectricField{Vpm: x/y});
+		assert_eq!(div_check(&MagneticFieldStrength{Apm: x}, &ElectricField{Vpm: y}), Conductance{S: x/y});
+		assert_eq!(mul_check(&MagneticFieldStrength{Apm: x}, &InverseElectricField{m_per_V: y}), Conductance{S: x*y});
+		assert_eq!(div_check(&MagneticFieldStrength{Apm: x}, &InverseMagneticFlux{per_Wb: y}), Force{N: x/y});
+		assert_eq!(div_check(&MagneticFieldStrength{Apm: x}, &InverseMagneticFluxDensity{m2_per_Wb: y}), Pressure{Pa: x/y});
+		assert_eq!(mul_check(&MagneticFieldStrength{Apm: x}, &MagneticFlux{Wb: y}), Force{N: x*y});
+		assert_eq!(mul_check(&MagneticFieldStrength{Apm: x}, &MagneticFluxDensity{T: y}), Pressure{Pa: x*y});
+		assert_eq!(mul_check(&MagneticFieldStrength{Apm: x}, &Resistance{Ohm: y}), ElectricField{Vpm: x*y});
+		assert_eq!(div_check(&MagneticFieldStrength{Apm: x}, &Force{N: y}), InverseMagneticFlux{per_Wb: x/y});
+		assert_eq!(mul_check(&MagneticFieldStrength{Apm: x}, &InverseForce{per_N: y}), InverseMagneticFlux{per_Wb: x*y});
+		assert_eq!(mul_check(&MagneticFieldStrength{Apm: x}, &InversePressure{per_Pa: y}), InverseMagneticFluxDensity{m2_per_Wb: x*y});
+		assert_eq!(div_check(&MagneticFieldStrength{Apm: x}, &Pressure{Pa: y}), InverseMagneticFluxDensity{m2_per_Wb: x/y});
+		assert_eq!(div_check(&MagneticFlux{Wb: x}, &InverseMagneticFieldStrength{m_per_A: y}), Force{N: x/y});
+		assert_eq!(mul_check(&MagneticFlux{Wb: x}, &MagneticFieldStrength{Apm: y}), Force{N: x*y});
+		assert_eq!(div_check(&MagneticFlux{Wb: x}, &Force{N: y}), InverseMagneticFieldStrength{m_per_A: x/y});
+		assert_eq!(mul_check(&MagneticFlux{Wb: x}, &InverseForce{per_N: y}), InverseMagneticFieldStrength{m_per_A: x*y});
+		assert_eq!(div_check(&MagneticFluxDensity{T: x}, &ElectricField{Vpm: y}), TimePerDistance{spm: x/y});
+		assert_eq!(mul_check(&MagneticFluxDensity{T: x}, &InverseElectricField{m_per_V: y}), TimePerDistance{spm: x*y});
+		assert_eq!(div_check(&MagneticFluxDensity{T: x}, &InverseMagneticFieldStrength{m_per_A: y}), Pressure{Pa: x/y});
+		assert_eq!(mul_check(&MagneticFluxDensity{T: x}, &MagneticFieldStrength{Apm: y}), Pressure{Pa: x*y});
+		assert_eq!(mul_check(&MagneticFluxDensity{T: x}, &InversePressure{per_Pa: y}), InverseMagneticFieldStrength{m_per_A: x*y});
+		assert_eq!(div_check(&MagneticFluxDensity{T: x}, &Pressure{Pa: y}), InverseMagneticFieldStrength{m_per_A: x/y});
+		assert_eq!(div_check(&MagneticFluxDensity{T: x}, &TimePerDistance{spm: y}), ElectricField{Vpm: x/y});
+		assert_eq!(mul_check(&MagneticFluxDensity{T: x}, &Velocity{mps: y}), ElectricField{Vpm: x*y});
+		assert_eq!(div_check(&Resistance{Ohm: x}, &ElectricField{Vpm: y}), InverseMagneticFieldStrength{m_per_A: x/y});
+		assert_eq!(mul_check(&Resistance{Ohm: x}, &InverseElectricField{m_per_V: y}), InverseMagneticFieldStrength{m_per_A: x*y});
+		assert_eq!(div_check(&Resistance{Ohm: x}, &InverseMagneticFieldStrength{m_per_A: y}), ElectricField{Vpm: x/y});
+		assert_eq!(mul_check(&Resistance{Ohm: x}, &MagneticFieldStrength{Apm: y}), ElectricField{Vpm: x*y});
+		assert_eq!(div_check(&Voltage{V: x}, &Distance{m: y}), ElectricField{Vpm: x/y});
+		assert_eq!(mul_check(&Voltage{V: x}, &InverseDistance{per_m: y}), ElectricField{Vpm: x*y});
+		assert_eq!(div_check(&Voltage{V: x}, &ElectricField{Vpm: y}), Distance{m: x/y});
+		assert_eq!(mul_check(&Voltage{V: x}, &InverseElectricField{m_per_V: y}), Distance{m: x*y});
+		assert_eq!(div_check(&Force{N: x}, &Charge{C: y}), ElectricField{Vpm: x/y});
+		assert_eq!(div_check(&Force{N: x}, &ElectricField{Vpm: y}), Charge{C: x/y});
+		assert_eq!(mul_check(&Force{N: x}, &InverseCharge{per_C: y}), ElectricField{Vpm: x*y});
+		assert_eq!(mul_check(&Force{N: x}, &InverseElectricField{m_per_V: y}), Charge{C: x*y});
+		assert_eq!(mul_check(&Force{N: x}, &InverseMagneticFieldStrength{m_per_A: y}), MagneticFlux{Wb: x*y});
+		assert_eq!(mul_check(&Force{N: x}, &InverseMagneticFlux{per_Wb: y}), MagneticFieldStrength{Apm: x*y});
+		assert_eq!(div_check(&Force{N: x}, &MagneticFieldStrength{Apm: y}), MagneticFlux{Wb: x/y});
+		assert_eq!(div_check(&Force{N: x}, &MagneticFlux{Wb: y}), MagneticFieldStrength{Apm: x/y});
+		assert_eq!(mul_check(&InverseForce{per_N: x}, &Charge{C: y}), InverseElectricField{m_per_V: x*y});
+		assert_eq!(mul_check(&InverseForce{per_N: x}, &ElectricField{Vpm: y}), InverseCharge{per_C: x*y});
+		assert_eq!(div_check(&InverseForce{per_N: x}, &InverseCharge{per_C: y}), InverseElectricField{m_per_V: x/y});
+		assert_eq!(div_check(&InverseForce{per_N: x}, &InverseElectricField{m_per_V: y}), InverseCharge{per_C: x/y});
+		assert_eq!(div_check(&InverseForce{per_N: x}, &InverseMagneticFieldStrength{m_per_A: y}), InverseMagneticFlux{per_Wb: x/y});
+		assert_eq!(div_check(&InverseForce{per_N: x}, &InverseMagneticFlux{per_Wb: y}), InverseMagneticFieldStrength{m_per_A: x/y});
+		assert_eq!(mul_check(&InverseForce{per_N: x}, &MagneticFieldStrength{Apm: y}), InverseMagneticFlux{per_Wb: x*y});
+		assert_eq!(mul_check(&InverseForce{per_N: x}, &MagneticFlux{Wb: y}), InverseMagneticFieldStrength{m_per_A: x*y});
+		assert_eq!(div_check(&InversePressure{per_Pa: x}, &InverseMagneticFieldStrength{m_per_A: y}), InverseMagneticFluxDensity{m2_per_Wb: x/y});
+		assert_eq!(div_check(&InversePressure{per_Pa: x}, &InverseMagneticFluxDensity{m2_per_Wb: y}), InverseMagneticFieldStrength{m_per_A: x/y});
+		assert_eq!(mul_check(&InversePressure{per_Pa: x}, &MagneticFieldStrength{Apm: y}), InverseMagneticFluxDensity{m2_per_Wb: x*y});
+		assert_eq!(mul_check(&InversePressure{per_Pa: x}, &MagneticFluxDensity{T: y}), InverseMagneticFieldStrength{m_per_A: x*y});
+		assert_eq!(mul_check(&Pressure{Pa: x}, &InverseMagneticFieldStrength{m_per_A: y}), MagneticFluxDensity{T: x*y});
+		assert_eq!(mul_check(&Pressure{Pa: x}, &InverseMagneticFluxDensity{m2_per_Wb: y}), MagneticFieldStrength{Apm: x*y});
+		assert_eq!(div_check(&Pressure{Pa: x}, &MagneticFieldStrength{Apm: y}), MagneticFluxDensity{T: x/y});
+		assert_eq!(div_check(&Pressure{Pa: x}, &MagneticFluxDensity{T: y}), MagneticFieldStrength{Apm: x/y});
+		assert_eq!(mul_check(&TimePerDistance{spm: x}, &ElectricField{Vpm: y}), MagneticFluxDensity{T: x*y});
+		assert_eq!(div_check(&TimePerDistance{spm: x}, &InverseElectricField{m_per_V: y}), MagneticFluxDensity{T: x/y});
+		assert_eq!(mul_check(&TimePerDistance{spm: x}, &InverseMagneticFluxDensity{m2_per_Wb: y}), InverseElectricField{m_per_V: x*y});
+		assert_eq!(div_check(&TimePerDistance{spm: x}, &MagneticFluxDensity{T: y}), InverseElectricField{m_per_V: x/y});
+		assert_eq!(div_check(&Velocity{mps: x}, &ElectricField{Vpm: y}), InverseMagneticFluxDensity{m2_per_Wb: x/y});
+		assert_eq!(mul_check(&Velocity{mps: x}, &InverseElectricField{m_per_V: y}), InverseMagneticFluxDensity{m2_per_Wb: x*y});
+		assert_eq!(div_check(&Velocity{mps: x}, &InverseMagneticFluxDensity{m2_per_Wb: y}), ElectricField{Vpm: x/y});
+		assert_eq!(mul_check(&Velocity{mps: x}, &MagneticFluxDensity{T: y}), ElectricField{Vpm: x*y});
+		assert_eq!(div_check(&(x as f64), &ElectricField{Vpm: y as f64}), InverseElectricField{m_per_V: x as f64/y as f64});
+		assert_eq!(div_check(&(x as f32), &ElectricField{Vpm: y as f32}), InverseElectricField{m_per_V: x as f32/y as f32});
+		assert_eq!(div_check(&(x as i64), &ElectricField{Vpm: y as i64}), InverseElectricField{m_per_V: x as i64/y as i64});
+		assert_eq!(div_check(&(x as i32), &ElectricField{Vpm: y as i32}), InverseElectricField{m_per_V: x as i32/y as i32});
+		assert_eq!(div_check(&(x as f64), &InverseElectricField{m_per_V: y as f64}), ElectricField{Vpm: x as f64/y as f64});
+		assert_eq!(div_check(&(x as f32), &InverseElectricField{m_per_V: y as f32}), ElectricField{Vpm: x as f32/y as f32});
+		assert_eq!(div_check(&(x as i64), &InverseElectricField{m_per_V: y as i64}), ElectricField{Vpm: x as i64/y as i64});
+		assert_eq!(div_check(&(x as i32), &InverseElectricField{m_per_V: y as i32}), ElectricField{Vpm: x as i32/y as i32});
+		assert_eq!(div_check(&(x as f64), &InverseMagneticFieldStrength{m_per_A: y as f64}), MagneticFieldStrength{Apm: x as f64/y as f64});
+		assert_eq!(div_check(&(x as f32), &InverseMagneticFieldStrength{m_per_A: y as f32}), MagneticFieldStrength{Apm: x as f32/y as f32});
+		assert_eq!(div_check(&(x as i64), &InverseMagneticFieldStrength{m_per_A: y as i64}), MagneticFieldStrength{Apm: x as i64/y as i64});
+		assert_eq!(div_check(&(x as i32), &InverseMagneticFieldStrength{m_per_A: y as i32}), MagneticFieldStrength{Apm: x as i32/y as i32});
+		assert_eq!(div_check(&(x as f64), &MagneticFieldStrength{Apm: y as f64}), InverseMagneticFieldStrength{m_per_A: x as f64/y as f64});
+		assert_eq!(div_check(&(x as f32), &MagneticFieldStrength{Apm: y as f32}), InverseMagneticFieldStrength{m_per_A: x as f32/y as f32});
+		assert_eq!(div_check(&(x as i64), &MagneticFieldStrength{Apm: y as i64}), InverseMagneticFieldStrength{m_per_A: x as i64/y as i64});
+		assert_eq!(div_check(&(x as i32), &MagneticFieldStrength{Apm: y as i32}), InverseMagneticFieldStrength{m_per_A: x as i32/y as i32});
+		assert_eq!(mul_check(&Current{A: x}, &InverseLinearChargeDensity{m_per_C: y}), Velocity{mps: x*y});
+		assert_eq!(mul_check(&Current{A: x}, &InverseSurfaceChargeDensity{m2_per_C: y}), KinematicViscosity{m2ps: x*y});
+		assert_eq!(mul_check(&Current{A: x}, &InverseVolumeChargeDensity{m3_per_C: y}), VolumetricFlowRate{m3ps: x*y});
+		assert_eq!(div_check(&Current{A: x}, &LinearChargeDensity{Cpm: y}), Velocity{mps: x/y});
+		assert_eq!(div_check(&Current{A: x}, &SurfaceChargeDensity{Cpm2: y}), KinematicViscosity{m2ps: x/y});
+		assert_eq!(div_check(&Current{A: x}, &VolumeChargeDensity{Cpm3: y}), VolumetricFlowRate{m3ps: x/y});
+		assert_eq!(mul_check(&Current{A: x}, &InverseKinematicViscosity{s_per_m2: y}), SurfaceChargeDensity{Cpm2: x*y});
+		assert_eq!(mul_check(&Current{A: x}, &InverseVolumetricFlowRate{s_per_m3: y}), VolumeChargeDensity{Cpm3: x*y});
+		assert_eq!(div_check(&Current{A: x}, &KinematicViscosity{m2ps: y}), SurfaceChargeDensity{Cpm2: x/y});
+		assert_eq!(mul_check(&Current{A: x}, &TimePerDistance{spm: y}), LinearChargeDensity{Cpm: x*y});
+		assert_eq!(div_check(&Current{A: x}, &Velocity{mps: y}), LinearChargeDensity{Cpm: x/y});
+		assert_eq!(div_check(&Current{A: x}, &VolumetricFlowRate{m3ps: y}), VolumeChargeDensity{Cpm3: x/y});
+		assert_eq!(div_check(&Distance{m: x}, &Charge{C: y}), InverseLinearChargeDensity{m_per_C: x/y});
+		assert_eq!(mul_check(&Distance{m: x}, &InverseCharge{per_C: y}), InverseLinearChargeDensity{m_per_C: x*y});
+		assert_eq!(mul_check(&Distance{m: x}, &InverseLinearChargeDensity{m_per_C: y}), InverseSurfaceChargeDensity{m2_per_C: x*y});
+		assert_eq!(div_check(&Distance{m: x}, &InverseLinearChargeDensity{m_per_C: y}), Charge{C: x/y});
+		assert_eq!(mul_check(&Distance{m: x}, &InverseSurfaceChargeDensity{m2_per_C: y}), InverseVolumeChargeDensity{m3_per_C: x*y});
+		assert_eq!(div_check(&Distance{m: x}, &InverseSurfaceChargeDensity{m2_per_C: y}), LinearChargeDensity{Cpm: x/y});
+		assert_eq!(div_check(&Distance{m: x}, &InverseVolumeChargeDensity{m3_per_C: y}), SurfaceChargeDensity{Cpm2: x/y});
+		assert_eq!(mul_check(&Distance{m: x}, &LinearChargeDensity{Cpm: y}), Charge{C: x*y});
+		assert_eq!(div_check(&Distance{m: x}, &LinearChargeDensity{Cpm: y}), InverseSurfaceChargeDensity{m2_per_C: x/y});
+		assert_eq!(mul_check(&Distance{m: x}, &SurfaceChargeDensity{Cpm2: y}), LinearChargeDensity{Cpm: x*y});
+		assert_eq!(div_check(&Distance{m: x}, &SurfaceChargeDensity{Cpm2: y}), InverseVolumeChargeDensity{m3_per_C: x/y});
+		assert_eq!(mul_check(&Distance{m: x}, &VolumeChargeDensity{Cpm3: y}), SurfaceChargeDensity{Cpm2: x*y});
+		assert_eq!(div_check(&InverseCurrent{per_A: x}, &InverseLinearChargeDensity{m_per_C: y}), TimePerDistance{spm: x/y});
+		assert_eq!(div_check(&InverseCurrent{per_A: x}, &InverseSurfaceChargeDensity{m2_per_C: y}), InverseKinematicViscosity{s_per_m2: x/y});
+		assert_eq!(div_check(&InverseCurrent{per_A: x}, &InverseVolumeChargeDensity{m3_per_C: y}), InverseVolumetricFlowRate{s_per_m3: x/y});
+		assert_eq!(mul_check(&InverseCurrent{per_A: x}, &LinearChargeDensity{Cpm: y}), TimePerDistance{spm: x*y});
+		assert_eq!(mul_check(&InverseCurrent{per_A: x}, &SurfaceChargeDensity{Cpm2: y}), InverseKinematicViscosity{s_per_m2: x*y});
+		assert_eq!(mul_check(&InverseCurrent{per_A: x}, &VolumeChargeDensity{Cpm3: y}), InverseVolumetricFlowRate{s_per_m3: x*y});
+		assert_eq!(div_check(&InverseCurrent{per_A: x}, &InverseKinematicViscosity{s_per_m2: y}), InverseSurfaceChargeDensity{m2_per_C: x/y});
+		assert_eq!(div_check(&InverseCurrent{per_A: x}, &InverseVolumetricFlowRate{s_per_m3: y}), InverseVolumeChargeDensity{m3_per_C: x/y});
+		assert_eq!(mul_check(&InverseCurrent{per_A: x}, &KinematicViscosity{m2ps: y}), InverseSurfaceChargeDensity{m2_per_C: x*y});
+		assert_eq!(div_check(&InverseCurrent{per_A: x}, &TimePerDistance{spm: y}), InverseLinearChargeDensity{m_per_C: x/y});
+		assert_eq!(mul_check(&InverseCurrent{per_A: x}, &Velocity{mps: y}), InverseLinearChargeDensity{m_per_C: x*y});
+		assert_eq!(mul_check(&InverseCurrent{per_A: x}, &VolumetricFlowRate{m3ps: y}), InverseVolumeChargeDensity{m3_per_C: x*y});
+		assert_eq!(mul_check(&InverseDistance{per_m: x}, &Charge{C: y}), LinearChargeDensity{Cpm: x*y});
+		assert_eq!(div_check(&InverseDistance{per_m: x}, &InverseCharge{per_C: y}), LinearChargeDensity{Cpm: x/y});
+		assert_eq!(mul_check(&InverseDistance{per_m: x}, &InverseLinearChargeDensity{m_per_C: y}), InverseCharge{per_C: x*y});
+		assert_eq!(div_check(&InverseDistance{per_m: x}, &InverseLinearChargeDensity{m_per_C: y}), SurfaceChargeDensity{Cpm2: x/y});
+		assert_eq!(mul_check(&InverseDistance{per_m: x}, &InverseSurfaceChargeDensity{m2_per_C: y}), InverseLinearChargeDensity{m_per_C: x*y});
+		assert_eq!(div_check(&InverseDistance{per_m: x}, &InverseSurfaceChargeDensity{m2_per_C: y}), VolumeChargeDensity{Cpm3: x/y});
+		assert_eq!(mul_check(&InverseDistance{per_m: x}, &InverseVolumeChargeDensity{m3_per_C: y}), InverseSurfaceChargeDensity{m2_per_C: x*y});
+		assert_eq!(mul_check(&InverseDistance{per_m: x}, &LinearChargeDensity{Cpm: y}), SurfaceChargeDensity{Cpm2: x*y});
+		assert_eq!(div_check(&InverseDistance{per_m: x}, &LinearChargeDensity{Cpm: y}), InverseCharge{per_C: x/y});
+		assert_eq!(mul_check(&InverseDistance{per_m: x}, &SurfaceChargeDensity{Cpm2: y}), VolumeChargeDensity{Cpm3: x*y});
+		assert_eq!(div_check(&InverseDistance{per_m: x}, &SurfaceChargeDensity{Cpm2: y}), InverseLinearChargeDensity{m_per_C: x/y});
+		assert_eq!(div_check(&InverseDistance{per_m: x}, &VolumeChargeDensity{Cpm3: y}), InverseSurfaceChargeDensity{m2_per_C: x/y});
+		assert_eq!(mul_check(&Time{s: x}, &InverseLinearChargeDensity{m_per_C: y}), InverseMagneticFieldStrength{m_per_A: x*y});
+		assert_eq!(div_check(&Time{s: x}, &InverseMagneticFieldStrength{m_per_A: y}), LinearChargeDensity{Cpm: x/y});
+		assert_eq!(div_check(&Time{s: x}, &LinearChargeDensity{Cpm: y}), InverseMagneticFieldStrength{m_per_A: x/y});
+		assert_eq!(mul_check(&Time{s: x}, &MagneticFieldStrength{Apm: y}), LinearChargeDensity{Cpm: x*y});
+		assert_eq!(mul_check(&Capacitance{F: x}, &ElectricField{Vpm: y}), LinearChargeDensity{Cpm: x*y});
+		assert_eq!(div_check(&Capacitance{F: x}, &InverseElectricField{m_per_V: y}), LinearChargeDensity{Cpm: x/y});
+		assert_eq!(mul_check(&Capacitance{F: x}, &InverseLinearChargeDensity{m_per_C: y}), InverseElectricField{m_per_V: x*y});
+		assert_eq!(div_check(&Capacitance{F: x}, &LinearChargeDensity{Cpm: y}), InverseElectricField{m_per_V: x/y});
+		assert_eq!(div_check(&Charge{C: x}, &Distance{m: y}), LinearChargeDensity{Cpm: x/y});
+		assert_eq!(mul_check(&Charge{C: x}, &InverseDistance{per_m: y}), LinearChargeDensity{Cpm: x*y});
+		assert_eq!(mul_check(&Charge{C: x}, &InverseLinearChargeDensity{m_per_C: y}), Distance{m: x*y});
+		assert_eq!(mul_check(&Charge{C: x}, &InverseSurfaceChargeDensity{m2_per_C: y}), Area{m2: x*y});
+		assert_eq!(mul_check(&Charge{C: x}, &InverseVolumeChargeDensity{m3_per_C: y}), Volume{m3: x*y});
+		assert_eq!(div_check(&Charge{C: x}, &LinearChargeDensity{Cpm: y}), Distance{m: x/y});
+		assert_eq!(div_check(&Charge{C: x}, &SurfaceChargeDensity{Cpm2: y}), Area{m2: x/y});
+		assert_eq!(div_check(&Charge{C: x}, &VolumeChargeDensity{Cpm3: y}), Volume{m3: x/y});
+		assert_eq!(div_check(&Charge{C: x}, &Area{m2: y}), SurfaceChargeDensity{Cpm2: x/y});
+		assert_eq!(mul_check(&Charge{C: x}, &InverseArea{per_m2: y}), SurfaceChargeDensity{Cpm2: x*y});
+		assert_eq!(mul_check(&Charge{C: x}, &InverseVolume{per_m3: y}), VolumeChargeDensity{Cpm3: x*y});
+		assert_eq!(div_check(&Charge{C: x}, &Volume{m3: y}), VolumeChargeDensity{Cpm3: x/y});
+		assert_eq!(div_check(&Conductance{S: x}, &InverseMagneticFluxDensity{m2_per_Wb: y}), SurfaceChargeDensity{Cpm2: x/y});
+		assert_eq!(mul_check(&Conductance{S: x}, &InverseSurfaceChargeDensity{m2_per_C: y}), InverseMagneticFluxDensity{m2_per_Wb: x*y});
+		assert_eq!(mul_check(&Conductance{S: x}, &MagneticFluxDensity{T: y}), SurfaceChargeDensity{Cpm2: x*y});
+		assert_eq!(div_check(&Conductance{S: x}, &SurfaceChargeDensity{Cpm2: y}), InverseMagneticFluxDensity{m2_per_Wb: x/y});
+		assert_eq!(div_check(&Elastance{per_F: x}, &ElectricField{Vpm: y}), InverseLinearChargeDensity{m_per_C: x/y});
+		assert_eq!(mul_check(&Elastance{per_F: x}, &InverseElectricField{m_per_V: y}), InverseLinearChargeDensity{m_per_C: x*y});
+		assert_eq!(div_check(&Elastance{per_F: x}, &InverseLinearChargeDensity{m_per_C: y}), ElectricField{Vpm: x/y});
+		assert_eq!(mul_check(&Elastance{per_F: x}, &LinearChargeDensity{Cpm: y}), ElectricField{Vpm: x*y});
+		assert_eq!(mul_check(&ElectricField{Vpm: x}, &Capacitance{F: y}), LinearChargeDensity{Cpm: x*y});
+		assert_eq!(div_check(&ElectricField{Vpm: x}, &Elastance{per_F: y}), LinearChargeDensity{Cpm: x/y});
+		assert_eq!(mul_check(&ElectricField{Vpm: x}, &InverseLinearChargeDensity{m_per_C: y}), Elastance{per_F: x*y});
+		assert_eq!(div_check(&ElectricField{Vpm: x}, &InverseSurfaceChargeDensity{m2_per_C: y}), Pressure{Pa: x/y});
+		assert_eq!(div_check(&ElectricField{Vpm: x}, &LinearChargeDensity{Cpm: y}), Elastance{per_F: x/y});
+		assert_eq!(mul_check(&ElectricField{Vpm: x}, &SurfaceChargeDensity{Cpm2: y}), Pressure{Pa: x*y});
+		assert_eq!(mul_check(&ElectricField{Vpm: x}, &InversePressure{per_Pa: y}), InverseSurfaceChargeDensity{m2_per_C: x*y});
+		assert_eq!(div_check(&ElectricField{Vpm: x}, &Pressure{Pa: y}), InverseSurfaceChargeDensity{m2_per_C: x/y});
+		assert_eq!(mul_check(&InverseCharge{per_C: x}, &Distance{m: y}), InverseLinearChargeDensity{m_per_C: x*y});
+		assert_eq!(div_check(&InverseCharge{per_C: x}, &InverseDistance{per_m: y}), InverseLinearChargeDensity{m_per_C: x/y});
+		assert_eq!(div_check(&InverseCharge{per_C: x}, &InverseLinearChargeDensity{m_per_C: y}), InverseDistance{per_m: x/y});
+		assert_eq!(div_check(&InverseCharge{per_C: x}, &InverseSurfaceChargeDensity{m2_per_C: y}), InverseArea{per_m2: x/y});
+		assert_eq!(div_check(&InverseCharge{per_C: x}, &InverseVolumeChargeDensity{m3_per_C: y}), InverseVolume{per_m3: x/y});
+		assert_eq!(mul_check(&InverseCharge{per_C: x}, &LinearChargeDensity{Cpm: y}), InverseDistance{per_m: x*y});
+		assert_eq!(mul_check(&InverseCharge{per_C: x}, &SurfaceChargeDensity{Cpm2: y}), InverseArea{per_m2: x*y});
+		assert_eq!(mul_check(&InverseCharge{per_C: x}, &VolumeChargeDensity{Cpm3: y}), InverseVolume{per_m3: x*y});
+		assert_eq!(mul_check(&InverseCharge{per_C: x}, &Area{m2: y}), InverseSurfaceChargeDensity{m2_per_C: x*y});
+		assert_eq!(div_check(&InverseCharge{per_C: x}, &InverseArea{per_m2: y}), InverseSurfaceChargeDensity{m2_per_C: x/y});
+		assert_eq!(div_check(&InverseCharge{per_C: x}, &InverseVolume{per_m3: y}), InverseVolumeChargeDensity{m3_per_C: x/y});
+		assert_eq!(mul_check(&InverseCharge{per_C: x}, &Volume{m3: y}), InverseVolumeChargeDensity{m3_per_C: x*y});
+		assert_eq!(div_check(&InverseElectricField{m_per_V: x}, &Capacitance{F: y}), InverseLinearChargeDensity{m_per_C: x/y});
+		assert_eq!(mul_check(&InverseElectricField{m_per_V: x}, &Elastance{per_F: y}), InverseLinearChargeDensity{m_per_C: x*y});
+		assert_eq!(div_check(&InverseElectricField{m_per_V: x}, &InverseLinearChargeDensity{m_per_C: y}), Capacitance{F: x/y});
+		assert_eq!(mul_check(&InverseElectricField{m_per_V: x}, &InverseSurfaceChargeDensity{m2_per_C: y}), InversePressure{per_Pa: x*y});
+		assert_eq!(mul_check(&InverseElectricField{m_per_V: x}, &LinearChargeDensity{Cpm: y}), Capacitance{F: x*y});
+		assert_eq!(div_check(&InverseElectricField{m_per_V: x}, &SurfaceChargeDensity{Cpm2: y}), InversePressure{per_Pa: x/y});
+		assert_eq!(div_check(&InverseElectricField{m_per_V: x}, &InversePressure{per_Pa: y}), SurfaceChargeDensity{Cpm2: x/y});
+		assert_eq!(mul_check(&InverseElectricField{m_per_V: x}, &Pressure{Pa: y}), SurfaceChargeDensity{Cpm2: x*y});
+		assert_eq!(mul_check(&InverseLinearChargeDensity{m_per_C: x}, &Current{A: y}), Velocity{mps: x*y});
+		assert_eq!(mul_check(&InverseLinearChargeDensity{m_per_C: x}, &Distance{m: y}), InverseSurfaceChargeDensity{m2_per_C: x*y});
+		assert_eq!(div_check(&InverseLinearChargeDensity{m_per_C: x}, &Distance{m: y}), InverseCharge{per_C: x/y});
+		assert_eq!(div_check(&InverseLinearChargeDensity{m_per_C: x}, &InverseCurrent{per_A: y}), Velocity{mps: x/y});
+		assert_eq!(mul_check(&InverseLinearChargeDensity{m_per_C: x}, &InverseDistance{per_m: y}), InverseCharge{per_C: x*y});
+		assert_eq!(div_check(&InverseLinearChargeDensity{m_per_C: x}, &InverseDistance{per_m: y}), InverseSurfaceChargeDensity{m2_per_C: x/y});
+		assert_eq!(mul_check(&InverseLinearChargeDensity{m_per_C: x}, &Time{s: y}), InverseMagneticFieldStrength{m_per_A: x*y});
+		assert_eq!(mul_check(&InverseLinearChargeDensity{m_per_C: x}, &Capacitance{F: y}), InverseElectricField{m_per_V: x*y});
+		assert_eq!(mul_check(&InverseLinearChargeDensity{m_per_C: x}, &Charge{C: y}), Distance{m: x*y});
+		assert_eq!(div_check(&InverseLinearChargeDensity{m_per_C: x}, &Elastance{per_F: y}), InverseElectricField{m_per_V: x/y});
+		assert_eq!(mul_check(&InverseLinearChargeDensity{m_per_C: x}, &ElectricField{Vpm: y}), Elastance{per_F: x*y});
+		assert_eq!(div_check(&InverseLinearChargeDensity{m_per_C: x}, &InverseCharge{per_C: y}), Distance{m: x/y});
+		assert_eq!(div_check(&InverseLinearChargeDensity{m_per_C: x}, &InverseElectricField{m_per_V: y}), Elastance{per_F: x/y});
+		assert_eq!(div_check(&InverseLinearChargeDensity{m_per_C: x}, &InverseMagneticFieldStrength{m_per_A: y}), Frequency{Hz: x/y});
+		assert_eq!(mul_check(&InverseLinearChargeDensity{m_per_C: x}, &InverseMagneticFlux{per_Wb: y}), InverseMomentum{s_per_kgm: x*y});
+		assert_eq!(mul_check(&InverseLinearChargeDensity{m_per_C: x}, &InverseMagneticFluxDensity{m2_per_Wb: y}), InverseDynamicViscosity{per_Pas: x*y});
+		assert_eq!(div_check(&InverseLinearChargeDensity{m_per_C: x}, &InverseSurfaceChargeDensity{m2_per_C: y}), InverseDistance{per_m: x/y});
+		assert_eq!(mul_check(&InverseLinearChargeDensity{m_per_C: x}, &InverseVoltage{per_V: y}), InverseForce{per_N: x*y});
+		assert_eq!(div_check(&InverseLinearChargeDensity{m_per_C: x}, &InverseVolumeChargeDensity{m3_per_C: y}), InverseArea{per_m2: x/y});
+		assert_eq!(mul_check(&InverseLinearChargeDensity{m_per_C: x}, &MagneticFieldStrength{Apm: y}), Frequency{Hz: x*y});
+		assert_eq!(div_check(&InverseLinearChargeDensity{m_per_C: x}, &MagneticFlux{Wb: y}), InverseMomentum{s_per_kgm: x/y});
+		assert_eq!(div_check(&InverseLinearChargeDensity{m_per_C: x}, &MagneticFluxDensity{T: y}), InverseDynamicViscosity{per_Pas: x/y});
+		assert_eq!(mul_check(&InverseLinearChargeDensity{m_per_C: x}, &SurfaceChargeDensity{Cpm2: y}), InverseDistance{per_m: x*y});
+		assert_eq!(div_check(&InverseLinearChargeDensity{m_per_C: x}, &Voltage{V: y}), InverseForce{per_N: x/y});
+		assert_eq!(mul_check(&InverseLinearChargeDensity{m_per_C: x}, &VolumeChargeDensity{Cpm3: y}), InverseArea{per_m2: x*y});
+		assert_eq!(mul_check(&InverseLinearChargeDensity{m_per_C: x}, &Area{m2: y}), InverseVolumeChargeDensity{m3_per_C: x*y});
+		assert_eq!(div_check(&InverseLinearChargeDensity{m_per_C: x}, &InverseArea{per_m2: y}), InverseVolumeChargeDensity{m3_per_C: x/y});
+		assert_eq!(mul_check(&InverseLinearChargeDensity{m_per_C: x}, &DynamicViscosity{Pas: y}), MagneticFluxDensity{T: x*y});
+		assert_eq!(mul_check(&InverseLinearChargeDensity{m_per_C: x}, &Force{N: y}), Voltage{V: x*y});
+		assert_eq!(div_check(&InverseLinearChargeDensity{m_per_C: x}, &Frequency{Hz: y}), InverseMagneticFieldStrength{m_per_A: x/y});
+		assert_eq!(div_check(&InverseLinearChargeDensity{m_per_C: x}, &InverseDynamicViscosity{per_Pas: y}), MagneticFluxDensity{T: x/y});
+		assert_eq!(div_check(&InverseLinearChargeDensity{m_per_C: x}, &InverseForce{per_N: y}), Voltage{V: x/y});
+		assert_eq!(div_check(&InverseLinearChargeDensity{m_per_C: x}, &InverseMomentum{s_per_kgm: y}), MagneticFlux{Wb: x/y});
+		assert_eq!(mul_check(&InverseLinearChargeDensity{m_per_C: x}, &Momentum{kgmps: y}), MagneticFlux{Wb: x*y});
+		assert_eq!(mul_check(&InverseLinearChargeDensity{m_per_C: x}, &TimePerDistance{spm: y}), InverseCurrent{per_A: x*y});
+		assert_eq!(div_check(&InverseLinearChargeDensity{m_per_C: x}, &Velocity{mps: y}), InverseCurrent{per_A: x/y});
+		assert_eq!(div_check(&InverseMagneticFieldStrength{m_per_A: x}, &Time{s: y}), InverseLinearChargeDensity{m_per_C: x/y});
+		assert_eq!(div_check(&InverseMagneticFieldStrength{m_per_A: x}, &InverseLinearChargeDensity{m_per_C: y}), Time{s: x/y});
+		assert_eq!(div_check(&InverseMagneticFieldStrength{m_per_A: x}, &InverseSurfaceChargeDensity{m2_per_C: y}), TimePerDistance{spm: x/y});
+		assert_eq!(div_check(&InverseMagneticFieldStrength{m_per_A: x}, &InverseVolumeChargeDensity{m3_per_C: y}), InverseKinematicViscosity{s_per_m2: x/y});
+		assert_eq!(mul_check(&InverseMagneticFieldStrength{m_per_A: x}, &LinearChargeDensity{Cpm: y}), Time{s: x*y});
+		assert_eq!(mul_check(&InverseMagneticFieldStrength{m_per_A: x}, &SurfaceChargeDensity{Cpm2: y}), TimePerDistance{spm: x*y});
+		assert_eq!(mul_check(&InverseMagneticFieldStrength{m_per_A: x}, &VolumeChargeDensity{Cpm3: y}), InverseKinematicViscosity{s_per_m2: x*y});
+		assert_eq!(mul_check(&InverseMagneticFieldStrength{m_per_A: x}, &Frequency{Hz: y}), InverseLinearChargeDensity{m_per_C: x*y});
+		assert_eq!(div_check(&InverseMagneticFieldStrength{m_per_A: x}, &InverseKinematicViscosity{s_per_m2: y}), InverseVolumeChargeDensity{m3_per_C: x/y});
+		assert_eq!(mul_check(&InverseMagneticFieldStrength{m_per_A: x}, &KinematicViscosity{m2ps: y}), InverseVolumeChargeDensity{m3_per_C: x*y});
+		assert_eq!(div_check(&InverseMagneticFieldStrength{m_per_A: x}, &TimePerDistance{spm: y}), InverseSurfaceChargeDensity{m2_per_C: x/y});
+		assert_eq!(mul_check(&InverseMagneticFieldStrength{m_per_A: x}, &Velocity{mps: y}), InverseSurfaceChargeDensity{m2_per_C: x*y});
+		assert_eq!(mul_check(&InverseMagneticFlux{per_Wb: x}, &InverseLinearChargeDensity{m_per_C: y}), InverseMomentum{s_per_kgm: x*y});
+		assert_eq!(mul_check(&InverseMagneticFlux{per_Wb: x}, &InverseSurfaceChargeDensity{m2_per_C: y}), InverseMassFlowRate{s_per_kg: x*y});
+		assert_eq!(mul_check(&InverseMagneticFlux{per_Wb: x}, &InverseVolumeChargeDensity{m3_per_C: y}), InverseDynamicViscosity{per_Pas: x*y});
+		assert_eq!(div_check(&InverseMagneticFlux{per_Wb: x}, &LinearChargeDensity{Cpm: y}), InverseMomentum{s_per_kgm: x/y});
+		assert_eq!(div_check(&InverseMagneticFlux{per_Wb: x}, &SurfaceChargeDensity{Cpm2: y}), InverseMassFlowRate{s_per_kg: x/y});
+		assert_eq!(div_check(&InverseMagneticFlux{per_Wb: x}, &VolumeChargeDensity{Cpm3: y}), InverseDynamicViscosity{per_Pas: x/y});
+		assert_eq!(mul_check(&InverseMagneticFlux{per_Wb: x}, &DynamicViscosity{Pas: y}), VolumeChargeDensity{Cpm3: x*y});
+		assert_eq!(div_check(&InverseMagneticFlux{per_Wb: x}, &InverseDynamicViscosity{per_Pas: y}), VolumeChargeDensity{Cpm3: x/y});
+		assert_eq!(div_check(&InverseMagneticFlux{per_Wb: x}, &InverseMassFlowRate{s_per_kg: y}), SurfaceChargeDensity{Cpm2: x/y});
+		assert_eq!(div_check(&InverseMagneticFlux{per_Wb: x}, &InverseMomentum{s_per_kgm: y}), LinearChargeDensity{Cpm: x/y});
+		assert_eq!(mul_check(&InverseMagneticFlux{per_Wb: x}, &MassFlowRate{kgps: y}), SurfaceChargeDensity{Cpm2: x*y});
+		assert_eq!(mul_check(&InverseMagneticFlux{per_Wb: x}, &Momentum{kgmps: y}), LinearChargeDensity{Cpm: x*y});
+		assert_eq!(div_check(&InverseMagneticFluxDensity{m2_per_Wb: x}, &Conductance{S: y}), InverseSurfaceChargeDensity{m2_per_C: x/y});
+		assert_eq!(mul_check(&InverseMagneticFluxDensity{m2_per_Wb: x}, &InverseLinearChargeDensity{m_per_C: y}), InverseDynamicViscosity{per_Pas: x*y});
+		assert_eq!(div_check(&InverseMagneticFluxDensity{m2_per_Wb: x}, &InverseSurfaceChargeDensity{m2_per_C: y}), Conductance{S: x/y});
+		assert_eq!(div_check(&InverseMagneticFluxDensity{m2_per_Wb: x}, &LinearChargeDensity{Cpm: y}), InverseDynamicViscosity{per_Pas: x/y});
+		assert_eq!(mul_check(&InverseMagneticFluxDensity{m2_per_Wb: x}, &Resistance{Ohm: y}), InverseSurfaceChargeDensity{m2_per_C: x*y});
+		assert_eq!(mul_check(&InverseMagneticFluxDensity{m2_per_Wb: x}, &SurfaceChargeDensity{Cpm2: y}), Conductance{S: x*y});
+		assert_eq!(mul_check(&InverseMagneticFluxDensity{m2_per_Wb: x}, &DynamicViscosity{Pas: y}), LinearChargeDensity{Cpm: x*y});
+		assert_eq!(div_check(&InverseMagneticFluxDensity{m2_per_Wb: x}, &InverseDynamicViscosity{per_Pas: y}), LinearChargeDensity{Cpm: x/y});
+		assert_eq!(mul_check(&InverseSurfaceChargeDensity{m2_per_C: x}, &Current{A: y}), KinematicViscosity{m2ps: x*y});
+		assert_eq!(mul_check(&InverseSurfaceChargeDensity{m2_per_C: x}, &Distance{m: y}), InverseVolumeChargeDensity{m3_per_C: x*y});
+		assert_eq!(div_check(&InverseSurfaceChargeDensity{m2_per_C: x}, &Distance{m: y}), InverseLinearChargeDensity{m_per_C: x/y});
+		assert_eq!(div_check(&InverseSurfaceChargeDensity{m2_per_C: x}, &InverseCurrent{per_A: y}), KinematicViscosity{m2ps: x/y});
+		assert_eq!(mul_check(&InverseSurfaceChargeDensity{m2_per_C: x}, &InverseDistance{per_m: y}), InverseLinearChargeDensity{m_per_C: x*y});
+		assert_eq!(div_check(&InverseSurfaceChargeDensity{m2_per_C: x}, &InverseDistance{per_m: y}), InverseVolumeChargeDensity{m3_per_C: x/y});
+		assert_eq!(mul_check(&InverseSurfaceChargeDensity{m2_per_C: x}, &Charge{C: y}), Area{m2: x*y});
+		assert_eq!(mul_check(&InverseSurfaceChargeDensity{m2_per_C: x}, &Conductance{S: y}), InverseMagneticFluxDensity{m2_per_Wb: x*y});
+		assert_eq!(div_check(&InverseSurfaceChargeDensity{m2_per_C: x}, &ElectricField{Vpm: y}), InversePressure{per_Pa: x/y});
+		assert_eq!(div_check(&InverseSurfaceChargeDensity{m2_per_C: x}, &InverseCharge{per_C: y}), Area{m2: x/y});
+		assert_eq!(mul_check(&InverseSurfaceChargeDensity{m2_per_C: x}, &InverseElectricField{m_per_V: y}), InversePressure{per_Pa: x*y});
+		assert_eq!(div_check(&InverseSurfaceChargeDensity{m2_per_C: x}, &InverseLinearChargeDensity{m_per_C: y}), Distance{m: x/y});
+		assert_eq!(div_check(&InverseSurfaceChargeDensity{m2_per_C: x}, &InverseMagneticFieldStrength{m_per_A: y}), Velocity{mps: x/y});
+		assert_eq!(mul_check(&InverseSurfaceChargeDensity{m2_per_C: x}, &InverseMagneticFlux{per_Wb: y}), InverseMassFlowRate{s_per_kg: x*y});
+		assert_eq!(div_check(&InverseSurfaceChargeDensity{m2_per_C: x}, &InverseMagneticFluxDensity{m2_per_Wb: y}), Resistance{Ohm: x/y});
+		assert_eq!(div_check(&InverseSurfaceChargeDensity{m2_per_C: x}, &InverseVolumeChargeDensity{m3_per_C: y}), InverseDistance{per_m: x/y});
+		assert_eq!(mul_check(&InverseSurfaceChargeDensity{m2_per_C: x}, &LinearChargeDensity{Cpm: y}), Distance{m: x*y});
+		assert_eq!(mul_check(&InverseSurfaceChargeDensity{m2_per_C: x}, &MagneticFieldStrength{Apm: y}), Velocity{mps: x*y});
+		assert_eq!(div_check(&InverseSurfaceChargeDensity{m2_per_C: x}, &MagneticFlux{Wb: y}), InverseMassFlowRate{s_per_kg: x/y});
+		assert_eq!(mul_check(&InverseSurfaceChargeDensity{m2_per_C: x}, &MagneticFluxDensity{T: y}), Resistance{Ohm: x*y});
+		assert_eq!(div_check(&InverseSurfaceChargeDensity{m2_per_C: x}, &Resistance{Ohm: y}), InverseMagneticFluxDensity{m2_per_Wb: x/y});
+		assert_eq!(mul_check(&InverseSurfaceChargeDensity{m2_per_C: x}, &VolumeChargeDensity{Cpm3: y}), InverseDistance{per_m: x*y});
+		assert_eq!(div_check(&InverseSurfaceChargeDensity{m2_per_C: x}, &Area{m2: y}), InverseCharge{per_C: x/y});
+		assert_eq!(mul_check(&InverseSurfaceChargeDensity{m2_per_C: x}, &InverseArea{per_m2: y}), InverseCharge{per_C: x*y});
+		assert_eq!(mul_check(&InverseSurfaceChargeDensity{m2_per_C: x}, &InverseKinematicViscosity{s_per_m2: y}), InverseCurrent{per_A: x*y});
+		assert_eq!(div_check(&InverseSurfaceChargeDensity{m2_per_C: x}, &InverseMassFlowRate{s_per_kg: y}), MagneticFlux{Wb: x/y});
+		assert_eq!(div_check(&InverseSurfaceChargeDensity{m2_per_C: x}, &InversePressure{per_Pa: y}), ElectricField{Vpm: x/y});
+		assert_eq!(div_check(&InverseSurfaceChargeDensity{m2_per_C: x}, &KinematicViscosity{m2ps: y}), InverseCurrent{per_A: x/y});
+		assert_eq!(mul_check(&InverseSurfaceChargeDensity{m2_per_C: x}, &MassFlowRate{kgps: y}), MagneticFlux{Wb: x*y});
+		assert_eq!(mul_check(&InverseSurfaceChargeDensity{m2_per_C: x}, &Pressure{Pa: y}), ElectricField{Vpm: x*y});
+		assert_eq!(mul_check(&InverseSurfaceChargeDensity{m2_per_C: x}, &TimePerDistance{spm: y}), InverseMagneticFieldStrength{m_per_A: x*y});
+		assert_eq!(div_check(&InverseSurfaceChargeDensity{m2_per_C: x}, &Velocity{mps: y}), InverseMagneticFieldStrength{m_per_A: x/y});
+		assert_eq!(mul_check(&InverseVoltage{per_V: x}, &InverseLinearChargeDensity{m_per_C: y}), InverseForce{per_N: x*y});
+		assert_eq!(mul_check(&InverseVoltage{per_V: x}, &InverseVolumeChargeDensity{m3_per_C: y}), InversePressure{per_Pa: x*y});
+		assert_eq!(div_check(&InverseVoltage{per_V: x}, &LinearChargeDensity{Cpm: y}), InverseForce{per_N: x/y});
+		assert_eq!(div_check(&InverseVoltage{per_V: x}, &VolumeChargeDensity{Cpm3: y}), InversePressure{per_Pa: x/y});
+		assert_eq!(mul_check(&InverseVoltage{per_V: x}, &Force{N: y}), LinearChargeDensity{Cpm: x*y});
+		assert_eq!(div_check(&InverseVoltage{per_V: x}, &InverseForce{per_N: y}), LinearChargeDensity{Cpm: x/y});
+		assert_eq!(div_check(&InverseVoltage{per_V: x}, &InversePressure{per_Pa: y}), VolumeChargeDensity{Cpm3: x/y});
+		assert_eq!(mul_check(&InverseVoltage{per_V: x}, &Pressure{Pa: y}), VolumeChargeDensity{Cpm3: x*y});
+		assert_eq!(mul_check(&InverseVolumeChargeDensity{m3_per_C: x}, &Current{A: y}), VolumetricFlowRate{m3ps: x*y});
+		assert_eq!(div_check(&InverseVolumeChargeDensity{m3_per_C: x}, &Distance{m: y}), InverseSurfaceChargeDensity{m2_per_C: x/y});
+		assert_eq!(div_check(&InverseVolumeChargeDensity{m3_per_C: x}, &InverseCurrent{per_A: y}), VolumetricFlowRate{m3ps: x/y});
+		assert_eq!(mul_check(&InverseVolumeChargeDensity{m3_per_C: x}, &InverseDistance{per_m: y}), InverseSurfaceChargeDensity{m2_per_C: x*y});
+		assert_eq!(mul_check(&InverseVolumeChargeDensity{m3_per_C: x}, &Charge{C: y}), Volume{m3: x*y});
+		assert_eq!(div_check(&InverseVolumeChargeDensity{m3_per_C: x}, &InverseCharge{per_C: y}), Volume{m3: x/y});
+		assert_eq!(div_check(&InverseVolumeChargeDensity{m3_per_C: x}, &InverseLinearChargeDensity{m_per_C: y}), Area{m2: x/y});
+		assert_eq!(div_check(&InverseVolumeChargeDensity{m3_per_C: x}, &InverseMagneticFieldStrength{m_per_A: y}), KinematicViscosity{m2ps: x/y});
+		assert_eq!(mul_check(&InverseVolumeChargeDensity{m3_per_C: x}, &InverseMagneticFlux{per_Wb: y}), InverseDynamicViscosity{per_Pas: x*y});
+		assert_eq!(div_check(&InverseVolumeChargeDensity{m3_per_C: x}, &InverseSurfaceChargeDensity{m2_per_C: y}), Distance{m: x/y});
+		assert_eq!(mul_check(&InverseVolumeChargeDensity{m3_per_C: x}, &InverseVoltage{per_V: y}), InversePressure{per_Pa: x*y});
+		assert_eq!(mul_check(&InverseVolumeChargeDensity{m3_per_C: x}, &LinearChargeDensity{Cpm: y}), Area{m2: x*y});
+		assert_eq!(mul_check(&InverseVolumeChargeDensity{m3_per_C: x}, &MagneticFieldStrength{Apm: y}), KinematicViscosity{m2ps: x*y});
+		assert_eq!(div_check(&InverseVolumeChargeDensity{m3_per_C: x}, &MagneticFlux{Wb: y}), InverseDynamicViscosity{per_Pas: x/y});
+		assert_eq!(mul_check(&InverseVolumeChargeDensity{m3_per_C: x}, &SurfaceChargeDensity{Cpm2: y}), Distance{m: x*y});
+		assert_eq!(div_check(&InverseVolumeChargeDensity{m3_per_C: x}, &Voltage{V: y}), InversePressure{per_Pa: x/y});
+		assert_eq!(div_check(&InverseVolumeChargeDensity{m3_per_C: x}, &Area{m2: y}), InverseLinearChargeDensity{m_per_C: x/y});
+		assert_eq!(mul_check(&InverseVolumeChargeDensity{m3_per_C: x}, &InverseArea{per_m2: y}), InverseLinearChargeDensity{m_per_C: x*y});
+		assert_eq!(mul_check(&InverseVolumeChargeDensity{m3_per_C: x}, &InverseVolume{per_m3: y}), InverseCharge{per_C: x*y});
+		assert_eq!(div_check(&InverseVolumeChargeDensity{m3_per_C: x}, &Volume{m3: y}), InverseCharge{per_C: x/y});
+		assert_eq!(mul_check(&InverseVolumeChargeDensity{m3_per_C: x}, &DynamicViscosity{Pas: y}), MagneticFlux{Wb: x*y});
+		assert_eq!(div_check(&InverseVolumeChargeDensity{m3_per_C: x}, &InverseDynamicViscosity{per_Pas: y}), MagneticFlux{Wb: x/y});
+		assert_eq!(mul_check(&InverseVolumeChargeDensity{m3_per_C: x}, &InverseKinematicViscosity{s_per_m2: y}), InverseMagneticFieldStrength{m_per_A: x*y});
+		assert_eq!(div_check(&InverseVolumeChargeDensity{m3_per_C: x}, &InversePressure{per_Pa: y}), Voltage{V: x/y});
+		assert_eq!(mul_check(&InverseVolumeChargeDensity{m3_per_C: x}, &InverseVolumetricFlowRate{s_per_m3: y}), InverseCurrent{per_A: x*y});
+		assert_eq!(div_check(&InverseVolumeChargeDensity{m3_per_C: x}, &KinematicViscosity{m2ps: y}), InverseMagneticFieldStrength{m_per_A: x/y});
+		assert_eq!(mul_check(&InverseVolumeChargeDensity{m3_per_C: x}, &Pressure{Pa: y}), Voltage{V: x*y});
+		assert_eq!(div_check(&InverseVolumeChargeDensity{m3_per_C: x}, &VolumetricFlowRate{m3ps: y}), InverseCurrent{per_A: x/y});
+		assert_eq!(div_check(&LinearChargeDensity{Cpm: x}, &Current{A: y}), TimePerDistance{spm: x/y});
+		assert_eq!(mul_check(&LinearChargeDensity{Cpm: x}, &Distance{m: y}), Charge{C: x*y});
+		assert_eq!(div_check(&LinearChargeDensity{Cpm: x}, &Distance{m: y}), SurfaceChargeDensity{Cpm2: x/y});
+		assert_eq!(mul_check(&LinearChargeDensity{Cpm: x}, &InverseCurrent{per_A: y}), TimePerDistance{spm: x*y});
+		assert_eq!(mul_check(&LinearChargeDensity{Cpm: x}, &InverseDistance{per_m: y}), SurfaceChargeDensity{Cpm2: x*y});
+		assert_eq!(div_check(&LinearChargeDensity{Cpm: x}, &InverseDistance{per_m: y}), Charge{C: x/y});
+		assert_eq!(div_check(&LinearChargeDensity{Cpm: x}, &Time{s: y}), MagneticFieldStrength{Apm: x/y});
+		assert_eq!(div_check(&LinearChargeDensity{Cpm: x}, &Capacitance{F: y}), ElectricField{Vpm: x/y});
+		assert_eq!(div_check(&LinearChargeDensity{Cpm: x}, &Charge{C: y}), InverseDistance{per_m: x/y});
+		assert_eq!(mul_check(&LinearChargeDensity{Cpm: x}, &Elastance{per_F: y}), ElectricField{Vpm: x*y});
+		assert_eq!(div_check(&LinearChargeDensity{Cpm: x}, &ElectricField{Vpm: y}), Capacitance{F: x/y});
+		assert_eq!(mul_check(&LinearChargeDensity{Cpm: x}, &InverseCharge{per_C: y}), InverseDistance{per_m: x*y});
+		assert_eq!(mul_check(&LinearChargeDensity{Cpm: x}, &InverseElectricField{m_per_V: y}), Capacitance{F: x*y});
+		assert_eq!(mul_check(&LinearChargeDensity{Cpm: x}, &InverseMagneticFieldStrength{m_per_A: y}), Time{s: x*y});
+		assert_eq!(div_check(&LinearChargeDensity{Cpm: x}, &InverseMagneticFlux{per_Wb: y}), Momentum{kgmps: x/y});
+		assert_eq!(div_check(&LinearChargeDensity{Cpm: x}, &InverseMagneticFluxDensity{m2_per_Wb: y}), DynamicViscosity{Pas: x/y});
+		assert_eq!(mul_check(&LinearChargeDensity{Cpm: x}, &InverseSurfaceChargeDensity{m2_per_C: y}), Distance{m: x*y});
+		assert_eq!(div_check(&LinearChargeDensity{Cpm: x}, &InverseVoltage{per_V: y}), Force{N: x/y});
+		assert_eq!(mul_check(&LinearChargeDensity{Cpm: x}, &InverseVolumeChargeDensity{m3_per_C: y}), Area{m2: x*y});
+		assert_eq!(div_check(&LinearChargeDensity{Cpm: x}, &MagneticFieldStrength{Apm: y}), Time{s: x/y});
+		assert_eq!(mul_check(&LinearChargeDensity{Cpm: x}, &MagneticFlux{Wb: y}), Momentum{kgmps: x*y});
+		assert_eq!(mul_check(&LinearChargeDensity{Cpm: x}, &MagneticFluxDensity{T: y}), DynamicViscosity{Pas: x*y});
+		assert_eq!(div_check(&LinearChargeDensity{Cpm: x}, &SurfaceChargeDensity{Cpm2: y}), Distance{m: x/y});
+		assert_eq!(mul_check(&LinearChargeDensity{Cpm: x}, &Voltage{V: y}), Force{N: x*y});
+		assert_eq!(div_check(&LinearChargeDensity{Cpm: x}, &VolumeChargeDensity{Cpm3: y}), Area{m2: x/y});
+		assert_eq!(div_check(&LinearChargeDensity{Cpm: x}, &Area{m2: y}), VolumeChargeDensity{Cpm3: x/y});
+		assert_eq!(mul_check(&LinearChargeDensity{Cpm: x}, &InverseArea{per_m2: y}), VolumeChargeDensity{Cpm3: x*y});
+		assert_eq!(div_check(&LinearChargeDensity{Cpm: x}, &DynamicViscosity{Pas: y}), InverseMagneticFluxDensity{m2_per_Wb: x/y});
+		assert_eq!(div_check(&LinearChargeDensity{Cpm: x}, &Force{N: y}), InverseVoltage{per_V: x/y});
+		assert_eq!(mul_check(&LinearChargeDensity{Cpm: x}, &Frequency{Hz: y}), MagneticFieldStrength{Apm: x*y});
+		assert_eq!(mul_check(&LinearChargeDensity{Cpm: x}, &InverseDynamicViscosity{per_Pas: y}), InverseMagneticFluxDensity{m2_per_Wb: x*y});
+		assert_eq!(mul_check(&LinearChargeDensity{Cpm: x}, &InverseForce{per_N: y}), InverseVoltage{per_V: x*y});
+		assert_eq!(mul_check(&LinearChargeDensity{Cpm: x}, &InverseMomentum{s_per_kgm: y}), InverseMagneticFlux{per_Wb: x*y});
+		assert_eq!(div_check(&LinearChargeDensity{Cpm: x}, &Momentum{kgmps: y}), InverseMagneticFlux{per_Wb: x/y});
+		assert_eq!(div_check(&LinearChargeDensity{Cpm: x}, &TimePerDistance{spm: y}), Current{A: x/y});
+		assert_eq!(mul_check(&LinearChargeDensity{Cpm: x}, &Velocity{mps: y}), Current{A: x*y});
+		assert_eq!(mul_check(&MagneticFieldStrength{Apm: x}, &Time{s: y}), LinearChargeDensity{Cpm: x*y});
+		assert_eq!(mul_check(&MagneticFieldStrength{Apm: x}, &InverseLinearChargeDensity{m_per_C: y}), Frequency{Hz: x*y});
+		assert_eq!(mul_check(&MagneticFieldStrength{Apm: x}, &InverseSurfaceChargeDensity{m2_per_C: y}), Velocity{mps: x*y});
+		assert_eq!(mul_check(&MagneticFieldStrength{Apm: x}, &InverseVolumeChargeDensity{m3_per_C: y}), KinematicViscosity{m2ps: x*y});
+		assert_eq!(div_check(&MagneticFieldStrength{Apm: x}, &LinearChargeDensity{Cpm: y}), Frequency{Hz: x/y});
+		assert_eq!(div_check(&MagneticFieldStrength{Apm: x}, &SurfaceChargeDensity{Cpm2: y}), Velocity{mps: x/y});
+		assert_eq!(div_check(&MagneticFieldStrength{Apm: x}, &VolumeChargeDensity{Cpm3: y}), KinematicViscosity{m2ps: x/y});
+		assert_eq!(div_check(&MagneticFieldStrength{Apm: x}, &Frequency{Hz: y}), LinearChargeDensity{Cpm: x/y});
+		assert_eq!(mul_check(&MagneticFieldStrength{Apm: x}, &InverseKinematicViscosity{s_per_m2: y}), VolumeChargeDensity{Cpm3: x*y});
+		assert_eq!(div_check(&MagneticFieldStrength{Apm: x}, &KinematicViscosity{m2ps: y}), VolumeChargeDensity{Cpm3: x/y});
+		assert_eq!(mul_check(&MagneticFieldStrength{Apm: x}, &TimePerDistance{spm: y}), SurfaceChargeDensity{Cpm2: x*y});
+		assert_eq!(div_check(&MagneticFieldStrength{Apm: x}, &Velocity{mps: y}), SurfaceChargeDensity{Cpm2: x/y});
+		assert_eq!(div_check(&MagneticFlux{Wb: x}, &InverseLinearChargeDensity{m_per_C: y}), Momentum{kgmps: x/y});
+		assert_eq!(div_check(&MagneticFlux{Wb: x}, &InverseSurfaceChargeDensity{m2_per_C: y}), MassFlowRate{kgps: x/y});
+		assert_eq!(div_check(&MagneticFlux{Wb: x}, &InverseVolumeChargeDensity{m3_per_C: y}), DynamicViscosity{Pas: x/y});
+		assert_eq!(mul_check(&MagneticFlux{Wb: x}, &LinearChargeDensity{Cpm: y}), Momentum{kgmps: x*y});
+		assert_eq!(mul_check(&MagneticFlux{Wb: x}, &SurfaceChargeDensity{Cpm2: y}), MassFlowRate{kgps: x*y});
+		assert_eq!(mul_check(&MagneticFlux{Wb: x}, &VolumeChargeDensity{Cpm3: y}), DynamicViscosity{Pas: x*y});
+		assert_eq!(div_check(&MagneticFlux{Wb: x}, &DynamicViscosity{Pas: y}), InverseVolumeChargeDensity{m3_per_C: x/y});
+		assert_eq!(mul_check(&MagneticFlux{Wb: x}, &InverseDynamicViscosity{per_Pas: y}), InverseVolumeChargeDensity{m3_per_C: x*y});
+		assert_eq!(mul_check(&MagneticFlux{Wb: x}, &InverseMassFlowRate{s_per_kg: y}), InverseSurfaceChargeDensity{m2_per_C: x*y});
+		assert_eq!(mul_check(&MagneticFlux{Wb: x}, &InverseMomentum{s_per_kgm: y}), InverseLinearChargeDensity{m_per_C: x*y});
+		assert_eq!(div_check(&MagneticFlux{Wb: x}, &MassFlowRate{kgps: y}), InverseSurfaceChargeDensity{m2_per_C: x/y});
+		assert_eq!(div_check(&MagneticFlux{Wb: x}, &Momentum{kgmps: y}), InverseLinearChargeDensity{m_per_C: x/y});
+		assert_eq!(mul_check(&MagneticFluxDensity{T: x}, &Conductance{S: y}), SurfaceChargeDensity{Cpm2: x*y});
+		assert_eq!(div_check(&MagneticFluxDensity{T: x}, &InverseLinearChargeDensity{m_per_C: y}), DynamicViscosity{Pas: x/y});
+		assert_eq!(mul_check(&MagneticFluxDensity{T: x}, &InverseSurfaceChargeDensity{m2_per_C: y}), Resistance{Ohm: x*y});
+		assert_eq!(mul_check(&MagneticFluxDensity{T: x}, &LinearChargeDensity{Cpm: y}), DynamicViscosity{Pas: x*y});
+		assert_eq!(div_check(&MagneticFluxDensity{T: x}, &Resistance{Ohm: y}), SurfaceChargeDensity{Cpm2: x/y});
+		assert_eq!(div_check(&MagneticFluxDensity{T: x}, &SurfaceChargeDensity{Cpm2: y}), Resistance{Ohm: x/y});
+		assert_eq!(div_check(&MagneticFluxDensity{T: x}, &DynamicViscosity{Pas: y}), InverseLinearChargeDensity{m_per_C: x/y});
+		assert_eq!(mul_check(&MagneticFluxDensity{T: x}, &InverseDynamicViscosity{per_Pas: y}), InverseLinearChargeDensity{m_per_C: x*y});
+		assert_eq!(mul_check(&Resistance{Ohm: x}, &InverseMagneticFluxDensity{m2_per_Wb: y}), InverseSurfaceChargeDensity{m2_per_C: x*y});
+		assert_eq!(div_check(&Resistance{Ohm: x}, &InverseSurfaceChargeDensity{m2_per_C: y}), MagneticFluxDensity{T: x/y});
+		assert_eq!(div_check(&Resistance{Ohm: x}, &MagneticFluxDensity{T: y}), InverseSurfaceChargeDensity{m2_per_C: x/y});
+		assert_eq!(mul_check(&Resistance{Ohm: x}, &SurfaceChargeDensity{Cpm2: y}), MagneticFluxDensity{T: x*y});
+		assert_eq!(div_check(&SurfaceChargeDensity{Cpm2: x}, &Current{A: y}), InverseKinematicViscosity{s_per_m2: x/y});
+		assert_eq!(mul_check(&SurfaceChargeDensity{Cpm2: x}, &Distance{m: y}), LinearChargeDensity{Cpm: x*y});
+		assert_eq!(div_check(&SurfaceChargeDensity{Cpm2: x}, &Distance{m: y}), VolumeChargeDensity{Cpm3: x/y});
+		assert_eq!(mul_check(&SurfaceChargeDensity{Cpm2: x}, &InverseCurrent{per_A: y}), InverseKinematicViscosity{s_per_m2: x*y});
+		assert_eq!(mul_check(&SurfaceChargeDensity{Cpm2: x}, &InverseDistance{per_m: y}), VolumeChargeDensity{Cpm3: x*y});
+		assert_eq!(div_check(&SurfaceChargeDensity{Cpm2: x}, &InverseDistance{per_m: y}), LinearChargeDensity{Cpm: x/y});
+		assert_eq!(div_check(&SurfaceChargeDensity{Cpm2: x}, &Charge{C: y}), InverseArea{per_m2: x/y});
+		assert_eq!(div_check(&SurfaceChargeDensity{Cpm2: x}, &Conductance{S: y}), MagneticFluxDensity{T: x/y});
+		assert_eq!(mul_check(&SurfaceChargeDensity{Cpm2: x}, &ElectricField{Vpm: y}), Pressure{Pa: x*y});
+		assert_eq!(mul_check(&SurfaceChargeDensity{Cpm2: x}, &InverseCharge{per_C: y}), InverseArea{per_m2: x*y});
+		assert_eq!(div_check(&SurfaceChargeDensity{Cpm2: x}, &InverseElectricField{m_per_V: y}), Pressure{Pa: x/y});
+		assert_eq!(mul_check(&SurfaceChargeDensity{Cpm2: x}, &InverseLinearChargeDensity{m_per_C: y}), InverseDistance{per_m: x*y});
+		assert_eq!(mul_check(&SurfaceChargeDensity{Cpm2: x}, &InverseMagneticFieldStrength{m_per_A: y}), TimePerDistance{spm: x*y});
+		assert_eq!(div_check(&SurfaceChargeDensity{Cpm2: x}, &InverseMagneticFlux{per_Wb: y}), MassFlowRate{kgps: x/y});
+		assert_eq!(mul_check(&SurfaceChargeDensity{Cpm2: x}, &InverseMagneticFluxDensity{m2_per_Wb: y}), Conductance{S: x*y});
+		assert_eq!(mul_check(&SurfaceChargeDensity{Cpm2: x}, &InverseVolumeChargeDensity{m3_per_C: y}), Distance{m: x*y});
+		assert_eq!(div_check(&SurfaceChargeDensity{Cpm2: x}, &LinearChargeDensity{Cpm: y}), InverseDistance{per_m: x/y});
+		assert_eq!(div_check(&SurfaceChargeDensity{Cpm2: x}, &MagneticFieldStrength{Apm: y}), TimePerDistance{spm: x/y});
+		assert_eq!(mul_check(&SurfaceChargeDensity{Cpm2: x}, &MagneticFlux{Wb: y}), MassFlowRate{kgps: x*y});
+		assert_eq!(div_check(&SurfaceChargeDensity{Cpm2: x}, &MagneticFluxDensity{T: y}), Conductance{S: x/y});
+		assert_eq!(mul_check(&SurfaceChargeDensity{Cpm2: x}, &Resistance{Ohm: y}), MagneticFluxDensity{T: x*y});
+		assert_eq!(div_check(&SurfaceChargeDensity{Cpm2: x}, &VolumeChargeDensity{Cpm3: y}), Distance{m: x/y});
+		assert_eq!(mul_check(&SurfaceChargeDensity{Cpm2: x}, &Area{m2: y}), Charge{C: x*y});
+		assert_eq!(div_check(&SurfaceChargeDensity{Cpm2: x}, &InverseArea{per_m2: y}), Charge{C: x/y});
+		assert_eq!(div_check(&SurfaceChargeDensity{Cpm2: x}, &InverseKinematicViscosity{s_per_m2: y}), Current{A: x/y});
+		assert_eq!(mul_check(&SurfaceChargeDensity{Cpm2: x}, &InverseMassFlowRate{s_per_kg: y}), InverseMagneticFlux{per_Wb: x*y});
+		assert_eq!(mul_check(&SurfaceChargeDensity{Cpm2: x}, &InversePressure{per_Pa: y}), InverseElectricField{m_per_V: x*y});
+		assert_eq!(mul_check(&SurfaceChargeDensity{Cpm2: x}, &KinematicViscosity{m2ps: y}), Current{A: x*y});
+		assert_eq!(div_check(&SurfaceChargeDensity{Cpm2: x}, &MassFlowRate{kgps: y}), InverseMagneticFlux{per_Wb: x/y});
+		assert_eq!(div_check(&SurfaceChargeDensity{Cpm2: x}, &Pressure{Pa: y}), InverseElectricField{m_per_V: x/y});
+		assert_eq!(div_check(&SurfaceChargeDensity{Cpm2: x}, &TimePerDistance{spm: y}), MagneticFieldStrength{Apm: x/y});
+		assert_eq!(mul_check(&SurfaceChargeDensity{Cpm2: x}, &Velocity{mps: y}), MagneticFieldStrength{Apm: x*y});
+		assert_eq!(div_check(&Voltage{V: x}, &InverseLinearChargeDensity{m_per_C: y}), Force{N: x/y});
+		assert_eq!(div_check(&Voltage{V: x}, &InverseVolumeChargeDensity{m3_per_C: y}), Pressure{Pa: x/y});
+		assert_eq!(mul_check(&Voltage{V: x}, &LinearChargeDensity{Cpm: y}), Force{N: x*y});
+		assert_eq!(mul_check(&Voltage{V: x}, &VolumeChargeDensity{Cpm3: y}), Pressure{Pa: x*y});
+		assert_eq!(div_check(&Voltage{V: x}, &Force{N: y}), InverseLinearChargeDensity{m_per_C: x/y});
+		assert_eq!(mul_check(&Voltage{V: x}, &InverseForce{per_N: y}), InverseLinearChargeDensity{m_per_C: x*y});
+		assert_eq!(mul_check(&Voltage{V: x}, &InversePressure{per_Pa: y}), InverseVolumeChargeDensity{m3_per_C: x*y});
+		assert_eq!(div_check(&Voltage{V: x}, &Pressure{Pa: y}), InverseVolumeChargeDensity{m3_per_C: x/y});
+		assert_eq!(div_check(&VolumeChargeDensity{Cpm3: x}, &Current{A: y}), InverseVolumetricFlowRate{s_per_m3: x/y});
+		assert_eq!(mul_check(&VolumeChargeDensity{Cpm3: x}, &Distance{m: y}), SurfaceChargeDensity{Cpm2: x*y});
+		assert_eq!(mul_check(&VolumeChargeDensity{Cpm3: x}, &InverseCurrent{per_A: y}), InverseVolumetricFlowRate{s_per_m3: x*y});
+		assert_eq!(div_check(&VolumeChargeDensity{Cpm3: x}, &InverseDistance{per_m: y}), SurfaceChargeDensity{Cpm2: x/y});
+		assert_eq!(div_check(&VolumeChargeDensity{Cpm3: x}, &Charge{C: y}), InverseVolume{per_m3: x/y});
+		assert_eq!(mul_check(&VolumeChargeDensity{Cpm3: x}, &InverseCharge{per_C: y}), InverseVolume{per_m3: x*y});
+		assert_eq!(mul_check(&VolumeChargeDensity{Cpm3: x}, &InverseLinearChargeDensity{m_per_C: y}), InverseArea{per_m2: x*y});
+		assert_eq!(mul_check(&VolumeChargeDensity{Cpm3: x}, &InverseMagneticFieldStrength{m_per_A: y}), InverseKinematicViscosity{s_per_m2: x*y});
+		assert_eq!(div_check(&VolumeChargeDensity{Cpm3: x}, &InverseMagneticFlux{per_Wb: y}), DynamicViscosity{Pas: x/y});
+		assert_eq!(mul_check(&VolumeChargeDensity{Cpm3: x}, &InverseSurfaceChargeDensity{m2_per_C: y}), InverseDistance{per_m: x*y});
+		assert_eq!(div_check(&VolumeChargeDensity{Cpm3: x}, &InverseVoltage{per_V: y}), Pressure{Pa: x/y});
+		assert_eq!(div_check(&VolumeChargeDensity{Cpm3: x}, &LinearChargeDensity{Cpm: y}), InverseArea{per_m2: x/y});
+		assert_eq!(div_check(&VolumeChargeDensity{Cpm3: x}, &MagneticFieldStrength{Apm: y}), InverseKinematicViscosity{s_per_m2: x/y});
+		assert_eq!(mul_check(&VolumeChargeDensity{Cpm3: x}, &MagneticFlux{Wb: y}), DynamicViscosity{Pas: x*y});
+		assert_eq!(div_check(&VolumeChargeDensity{Cpm3: x}, &SurfaceChargeDensity{Cpm2: y}), InverseDistance{per_m: x/y});
+		assert_eq!(mul_check(&VolumeChargeDensity{Cpm3: x}, &Voltage{V: y}), Pressure{Pa: x*y});
+		assert_eq!(mul_check(&VolumeChargeDensity{Cpm3: x}, &Area{m2: y}), LinearChargeDensity{Cpm: x*y});
+		assert_eq!(div_check(&VolumeChargeDensity{Cpm3: x}, &InverseArea{per_m2: y}), LinearChargeDensity{Cpm: x/y});
+		assert_eq!(div_check(&VolumeChargeDensity{Cpm3: x}, &InverseVolume{per_m3: y}), Charge{C: x/y});
+		assert_eq!(mul_check(&VolumeChargeDensity{Cpm3: x}, &Volume{m3: y}), Charge{C: x*y});
+		assert_eq!(div_check(&VolumeChargeDensity{Cpm3: x}, &DynamicViscosity{Pas: y}), InverseMagneticFlux{per_Wb: x/y});
+		assert_eq!(mul_check(&VolumeChargeDensity{Cpm3: x}, &InverseDynamicViscosity{per_Pas: y}), InverseMagneticFlux{per_Wb: x*y});
+		assert_eq!(div_check(&VolumeChargeDensity{Cpm3: x}, &InverseKinematicViscosity{s_per_m2: y}), MagneticFieldStrength{Apm: x/y});
+		assert_eq!(mul_check(&VolumeChargeDensity{Cpm3: x}, &InversePressure{per_Pa: y}), InverseVoltage{per_V: x*y});
+		assert_eq!(div_check(&VolumeChargeDensity{Cpm3: x}, &InverseVolumetricFlowRate{s_per_m3: y}), Current{A: x/y});
+		assert_eq!(mul_check(&VolumeChargeDensity{Cpm3: x}, &KinematicViscosity{m2ps: y}), MagneticFieldStrength{Apm: x*y});
+		assert_eq!(div_check(&VolumeChargeDensity{Cpm3: x}, &Pressure{Pa: y}), InverseVoltage{per_V: x/y});
+		assert_eq!(mul_check(&VolumeChargeDensity{Cpm3: x}, &VolumetricFlowRate{m3ps: y}), Current{A: x*y});
+		assert_eq!(div_check(&Area{m2: x}, &Charge{C: y}), InverseSurfaceChargeDensity{m2_per_C: x/y});
+		assert_eq!(mul_check(&Area{m2: x}, &InverseCharge{per_C: y}), InverseSurfaceChargeDensity{m2_per_C: x*y});
+		assert_eq!(mul_check(&Area{m2: x}, &InverseLinearChargeDensity{m_per_C: y}), InverseVolumeChargeDensity{m3_per_C: x*y});
+		assert_eq!(div_check(&Area{m2: x}, &InverseSurfaceChargeDensity{m2_per_C: y}), Charge{C: x/y});
+		assert_eq!(div_check(&Area{m2: x}, &InverseVolumeChargeDensity{m3_per_C: y}), LinearChargeDensity{Cpm: x/y});
+		assert_eq!(div_check(&Area{m2: x}, &LinearChargeDensity{Cpm: y}), InverseVolumeChargeDensity{m3_per_C: x/y});
+		assert_eq!(mul_check(&Area{m2: x}, &SurfaceChargeDensity{Cpm2: y}), Charge{C: x*y});
+		assert_eq!(mul_check(&Area{m2: x}, &VolumeChargeDensity{Cpm3: y}), LinearChargeDensity{Cpm: x*y});
+		assert_eq!(mul_check(&InverseArea{per_m2: x}, &Charge{C: y}), SurfaceChargeDensity{Cpm2: x*y});
+		assert_eq!(div_check(&InverseArea{per_m2: x}, &InverseCharge{per_C: y}), SurfaceChargeDensity{Cpm2: x/y});
+		assert_eq!(div_check(&InverseArea{per_m2: x}, &InverseLinearChargeDensity{m_per_C: y}), VolumeChargeDensity{Cpm3: x/y});
+		assert_eq!(mul_check(&InverseArea{per_m2: x}, &InverseSurfaceChargeDensity{m2_per_C: y}), InverseCharge{per_C: x*y});
+		assert_eq!(mul_check(&InverseArea{per_m2: x}, &InverseVolumeChargeDensity{m3_per_C: y}), InverseLinearChargeDensity{m_per_C: x*y});
+		assert_eq!(mul_check(&InverseArea{per_m2: x}, &LinearChargeDensity{Cpm: y}), VolumeChargeDensity{Cpm3: x*y});
+		assert_eq!(div_check(&InverseArea{per_m2: x}, &SurfaceChargeDensity{Cpm2: y}), InverseCharge{per_C: x/y});
+		assert_eq!(div_check(&InverseArea{per_m2: x}, &VolumeChargeDensity{Cpm3: y}), InverseLinearChargeDensity{m_per_C: x/y});
+		assert_eq!(mul_check(&InverseVolume{per_m3: x}, &Charge{C: y}), VolumeChargeDensity{Cpm3: x*y});
+		assert_eq!(div_check(&InverseVolume{per_m3: x}, &InverseCharge{per_C: y}), VolumeChargeDensity{Cpm3: x/y});
+		assert_eq!(mul_check(&InverseVolume{per_m3: x}, &InverseVolumeChargeDensity{m3_per_C: y}), InverseCharge{per_C: x*y});
+		assert_eq!(div_check(&InverseVolume{per_m3: x}, &VolumeChargeDensity{Cpm3: y}), InverseCharge{per_C: x/y});
+		assert_eq!(div_check(&Volume{m3: x}, &Charge{C: y}), InverseVolumeChargeDensity{m3_per_C: x/y});
+		assert_eq!(mul_check(&Volume{m3: x}, &InverseCharge{per_C: y}), InverseVolumeChargeDensity{m3_per_C: x*y});
+		assert_eq!(div_check(&Volume{m3: x}, &InverseVolumeChargeDensity{m3_per_C: y}), Charge{C: x/y});
+		assert_eq!(mul_check(&Volume{m3: x}, &VolumeChargeDensity{Cpm3: y}), Charge{C: x*y});
+		assert_eq!(mul_check(&DynamicViscosity{Pas: x}, &InverseLinearChargeDensity{m_per_C: y}), MagneticFluxDensity{T: x*y});
+		assert_eq!(mul_check(&DynamicViscosity{Pas: x}, &InverseMagneticFlux{per_Wb: y}), VolumeChargeDensity{Cpm3: x*y});
+		assert_eq!(mul_check(&DynamicViscosity{Pas: x}, &InverseMagneticFluxDensity{m2_per_Wb: y}), LinearChargeDensity{Cpm: x*y});
+		assert_eq!(mul_check(&DynamicViscosity{Pas: x}, &InverseVolumeChargeDensity{m3_per_C: y}), MagneticFlux{Wb: x*y});
+		assert_eq!(div_check(&DynamicViscosity{Pas: x}, &LinearChargeDensity{Cpm: y}), MagneticFluxDensity{T: x/y});
+		assert_eq!(div_check(&DynamicViscosity{Pas: x}, &MagneticFlux{Wb: y}), VolumeChargeDensity{Cpm3: x/y});
+		assert_eq!(div_check(&DynamicViscosity{Pas: x}, &MagneticFluxDensity{T: y}), LinearChargeDensity{Cpm: x/y});
+		assert_eq!(div_check(&DynamicViscosity{Pas: x}, &VolumeChargeDensity{Cpm3: y}), MagneticFlux{Wb: x/y});
+		assert_eq!(mul_check(&Force{N: x}, &InverseLinearChargeDensity{m_per_C: y}), Voltage{V: x*y});
+		assert_eq!(mul_check(&Force{N: x}, &InverseVoltage{per_V: y}), LinearChargeDensity{Cpm: x*y});
+		assert_eq!(div_check(&Force{N: x}, &LinearChargeDensity{Cpm: y}), Voltage{V: x/y});
+		assert_eq!(div_check(&Force{N: x}, &Voltage{V: y}), LinearChargeDensity{Cpm: x/y});
+		assert_eq!(div_check(&Frequency{Hz: x}, &InverseLinearChargeDensity{m_per_C: y}), MagneticFieldStrength{Apm: x/y});
+		assert_eq!(mul_check(&Frequency{Hz: x}, &InverseMagneticFieldStrength{m_per_A: y}), InverseLinearChargeDensity{m_per_C: x*y});
+		assert_eq!(mul_check(&Frequency{Hz: x}, &LinearChargeDensity{Cpm: y}), MagneticFieldStrength{Apm: x*y});
+		assert_eq!(div_check(&Frequency{Hz: x}, &MagneticFieldStrength{Apm: y}), InverseLinearChargeDensity{m_per_C: x/y});
+		assert_eq!(div_check(&InverseDynamicViscosity{per_Pas: x}, &InverseLinearChargeDensity{m_per_C: y}), InverseMagneticFluxDensity{m2_per_Wb: x/y});
+		assert_eq!(div_check(&InverseDynamicViscosity{per_Pas: x}, &InverseMagneticFlux{per_Wb: y}), InverseVolumeChargeDensity{m3_per_C: x/y});
+		assert_eq!(div_check(&InverseDynamicViscosity{per_Pas: x}, &InverseMagneticFluxDensity{m2_per_Wb: y}), InverseLinearChargeDensity{m_per_C: x/y});
+		assert_eq!(div_check(&InverseDynamicViscosity{per_Pas: x}, &InverseVolumeChargeDensity{m3_per_C: y}), InverseMagneticFlux{per_Wb: x/y});
+		assert_eq!(mul_check(&InverseDynamicViscosity{per_Pas: x}, &LinearChargeDensity{Cpm: y}), InverseMagneticFluxDensity{m2_per_Wb: x*y});
+		assert_eq!(mul_check(&InverseDynamicViscosity{per_Pas: x}, &MagneticFlux{Wb: y}), InverseVolumeChargeDensity{m3_per_C: x*y});
+		assert_eq!(mul_check(&InverseDynamicViscosity{per_Pas: x}, &MagneticFluxDensity{T: y}), InverseLinearChargeDensity{m_per_C: x*y});
+		assert_eq!(mul_check(&InverseDynamicViscosity{per_Pas: x}, &VolumeChargeDensity{Cpm3: y}), InverseMagneticFlux{per_Wb: x*y});
+		assert_eq!(div_check(&InverseForce{per_N: x}, &InverseLinearChargeDensity{m_per_C: y}), InverseVoltage{per_V: x/y});
+		assert_eq!(div_check(&InverseForce{per_N: x}, &InverseVoltage{per_V: y}), InverseLinearChargeDensity{m_per_C: x/y});
+		assert_eq!(mul_check(&InverseForce{per_N: x}, &LinearChargeDensity{Cpm: y}), InverseVoltage{per_V: x*y});
+		assert_eq!(mul_check(&InverseForce{per_N: x}, &Voltage{V: y}), InverseLinearChargeDensity{m_per_C: x*y});
+		assert_eq!(mul_check(&InverseKinematicViscosity{s_per_m2: x}, &Current{A: y}), SurfaceChargeDensity{Cpm2: x*y});
+		assert_eq!(div_check(&InverseKinematicViscosity{s_per_m2: x}, &InverseCurrent{per_A: y}), SurfaceChargeDensity{Cpm2: x/y});
+		assert_eq!(div_check(&InverseKinematicViscosity{s_per_m2: x}, &InverseMagneticFieldStrength{m_per_A: y}), VolumeChargeDensity{Cpm3: x/y});
+		assert_eq!(mul_check(&InverseKinematicViscosity{s_per_m2: x}, &InverseSurfaceChargeDensity{m2_per_C: y}), InverseCurrent{per_A: x*y});
+		assert_eq!(mul_check(&InverseKinematicViscosity{s_per_m2: x}, &InverseVolumeChargeDensity{m3_per_C: y}), InverseMagneticFieldStrength{m_per_A: x*y});
+		assert_eq!(mul_check(&InverseKinematicViscosity{s_per_m2: x}, &MagneticFieldStrength{Apm: y}), VolumeChargeDensity{Cpm3: x*y});
+		assert_eq!(div_check(&InverseKinematicViscosity{s_per_m2: x}, &SurfaceChargeDensity{Cpm2: y}), InverseCurrent{per_A: x/y});
+		assert_eq!(div_check(&InverseKinematicViscosity{s_per_m2: x}, &VolumeChargeDensity{Cpm3: y}), InverseMagneticFieldStrength{m_per_A: x/y});
+		assert_eq!(div_check(&InverseMassFlowRate{s_per_kg: x}, &InverseMagneticFlux{per_Wb: y}), InverseSurfaceChargeDensity{m2_per_C: x/y});
+		assert_eq!(div_check(&InverseMassFlowRate{s_per_kg: x}, &InverseSurfaceChargeDensity{m2_per_C: y}), InverseMagneticFlux{per_Wb: x/y});
+		assert_eq!(mul_check(&InverseMassFlowRate{s_per_kg: x}, &MagneticFlux{Wb: y}), InverseSurfaceChargeDensity{m2_per_C: x*y});
+		assert_eq!(mul_check(&InverseMassFlowRate{s_per_kg: x}, &SurfaceChargeDensity{Cpm2: y}), InverseMagneticFlux{per_Wb: x*y});
+		assert_eq!(div_check(&InverseMomentum{s_per_kgm: x}, &InverseLinearChargeDensity{m_per_C: y}), InverseMagneticFlux{per_Wb: x/y});
+		assert_eq!(div_check(&InverseMomentum{s_per_kgm: x}, &InverseMagneticFlux{per_Wb: y}), InverseLinearChargeDensity{m_per_C: x/y});
+		assert_eq!(mul_check(&InverseMomentum{s_per_kgm: x}, &LinearChargeDensity{Cpm: y}), InverseMagneticFlux{per_Wb: x*y});
+		assert_eq!(mul_check(&InverseMomentum{s_per_kgm: x}, &MagneticFlux{Wb: y}), InverseLinearChargeDensity{m_per_C: x*y});
+		assert_eq!(mul_check(&InversePressure{per_Pa: x}, &ElectricField{Vpm: y}), InverseSurfaceChargeDensity{m2_per_C: x*y});
+		assert_eq!(div_check(&InversePressure{per_Pa: x}, &InverseElectricField{m_per_V: y}), InverseSurfaceChargeDensity{m2_per_C: x/y});
+		assert_eq!(div_check(&InversePressure{per_Pa: x}, &InverseSurfaceChargeDensity{m2_per_C: y}), InverseElectricField{m_per_V: x/y});
+		assert_eq!(div_check(&InversePressure{per_Pa: x}, &InverseVoltage{per_V: y}), InverseVolumeChargeDensity{m3_per_C: x/y});
+		assert_eq!(div_check(&InversePressure{per_Pa: x}, &InverseVolumeChargeDensity{m3_per_C: y}), InverseVoltage{per_V: x/y});
+		assert_eq!(mul_check(&InversePressure{per_Pa: x}, &SurfaceChargeDensity{Cpm2: y}), InverseElectricField{m_per_V: x*y});
+		assert_eq!(mul_check(&InversePressure{per_Pa: x}, &Voltage{V: y}), InverseVolumeChargeDensity{m3_per_C: x*y});
+		assert_eq!(mul_check(&InversePressure{per_Pa: x}, &VolumeChargeDensity{Cpm3: y}), InverseVoltage{per_V: x*y});
+		assert_eq!(mul_check(&InverseVolumetricFlowRate{s_per_m3: x}, &Current{A: y}), VolumeChargeDensity{Cpm3: x*y});
+		assert_eq!(div_check(&InverseVolumetricFlowRate{s_per_m3: x}, &InverseCurrent{per_A: y}), VolumeChargeDensity{Cpm3: x/y});
+		assert_eq!(mul_check(&InverseVolumetricFlowRate{s_per_m3: x}, &InverseVolumeChargeDensity{m3_per_C: y}), InverseCurrent{per_A: x*y});
+		assert_eq!(div_check(&InverseVolumetricFlowRate{s_per_m3: x}, &VolumeChargeDensity{Cpm3: y}), InverseCurrent{per_A: x/y});
+		assert_eq!(div_check(&KinematicViscosity{m2ps: x}, &Current{A: y}), InverseSurfaceChargeDensity{m2_per_C: x/y});
+		assert_eq!(mul_check(&KinematicViscosity{m2ps: x}, &InverseCurrent{per_A: y}), InverseSurfaceChargeDensity{m2_per_C: x*y});
+		assert_eq!(mul_check(&KinematicViscosity{m2ps: x}, &InverseMagneticFieldStrength{m_per_A: y}), InverseVolumeChargeDensity{m3_per_C: x*y});
+		assert_eq!(div_check(&KinematicViscosity{m2ps: x}, &InverseSurfaceChargeDensity{m2_per_C: y}), Current{A: x/y});
+		assert_eq!(div_check(&KinematicViscosity{m2ps: x}, &InverseVolumeChargeDensity{m3_per_C: y}), MagneticFieldStrength{Apm: x/y});
+		assert_eq!(div_check(&KinematicViscosity{m2ps: x}, &MagneticFieldStrength{Apm: y}), InverseVolumeChargeDensity{m3_per_C: x/y});
+		assert_eq!(mul_check(&KinematicViscosity{m2ps: x}, &SurfaceChargeDensity{Cpm2: y}), Current{A: x*y});
+		assert_eq!(mul_check(&KinematicViscosity{m2ps: x}, &VolumeChargeDensity{Cpm3: y}), MagneticFieldStrength{Apm: x*y});
+		assert_eq!(mul_check(&MassFlowRate{kgps: x}, &InverseMagneticFlux{per_Wb: y}), SurfaceChargeDensity{Cpm2: x*y});
+		assert_eq!(mul_check(&MassFlowRate{kgps: x}, &InverseSurfaceChargeDensity{m2_per_C: y}), MagneticFlux{Wb: x*y});
+		assert_eq!(div_check(&MassFlowRate{kgps: x}, &MagneticFlux{Wb: y}), SurfaceChargeDensity{Cpm2: x/y});
+		assert_eq!(div_check(&MassFlowRate{kgps: x}, &SurfaceChargeDensity{Cpm2: y}), MagneticFlux{Wb: x/y});
+		assert_eq!(mul_check(&Momentum{kgmps: x}, &InverseLinearChargeDensity{m_per_C: y}), MagneticFlux{Wb: x*y});
+		assert_eq!(mul_check(&Momentum{kgmps: x}, &InverseMagneticFlux{per_Wb: y}), LinearChargeDensity{Cpm: x*y});
+		assert_eq!(div_check(&Momentum{kgmps: x}, &LinearChargeDensity{Cpm: y}), MagneticFlux{Wb: x/y});
+		assert_eq!(div_check(&Momentum{kgmps: x}, &MagneticFlux{Wb: y}), LinearChargeDensity{Cpm: x/y});
+		assert_eq!(div_check(&Pressure{Pa: x}, &ElectricField{Vpm: y}), SurfaceChargeDensity{Cpm2: x/y});
+		assert_eq!(mul_check(&Pressure{Pa: x}, &InverseElectricField{m_per_V: y}), SurfaceChargeDensity{Cpm2: x*y});
+		assert_eq!(mul_check(&Pressure{Pa: x}, &InverseSurfaceChargeDensity{m2_per_C: y}), ElectricField{Vpm: x*y});
+		assert_eq!(mul_check(&Pressure{Pa: x}, &InverseVoltage{per_V: y}), VolumeChargeDensity{Cpm3: x*y});
+		assert_eq!(mul_check(&Pressure{Pa: x}, &InverseVolumeChargeDensity{m3_per_C: y}), Voltage{V: x*y});
+		assert_eq!(div_check(&Pressure{Pa: x}, &SurfaceChargeDensity{Cpm2: y}), ElectricField{Vpm: x/y});
+		assert_eq!(div_check(&Pressure{Pa: x}, &Voltage{V: y}), VolumeChargeDensity{Cpm3: x/y});
+		assert_eq!(div_check(&Pressure{Pa: x}, &VolumeChargeDensity{Cpm3: y}), Voltage{V: x/y});
+		assert_eq!(mul_check(&TimePerDistance{spm: x}, &Current{A: y}), LinearChargeDensity{Cpm: x*y});
+		assert_eq!(div_check(&TimePerDistance{spm: x}, &InverseCurrent{per_A: y}), LinearChargeDensity{Cpm: x/y});
+		assert_eq!(mul_check(&TimePerDistance{spm: x}, &InverseLinearChargeDensity{m_per_C: y}), InverseCurrent{per_A: x*y});
+		assert_eq!(div_check(&TimePerDistance{spm: x}, &InverseMagneticFieldStrength{m_per_A: y}), SurfaceChargeDensity{Cpm2: x/y});
+		assert_eq!(mul_check(&TimePerDistance{spm: x}, &InverseSurfaceChargeDensity{m2_per_C: y}), InverseMagneticFieldStrength{m_per_A: x*y});
+		assert_eq!(div_check(&TimePerDistance{spm: x}, &LinearChargeDensity{Cpm: y}), InverseCurrent{per_A: x/y});
+		assert_eq!(mul_check(&TimePerDistance{spm: x}, &MagneticFieldStrength{Apm: y}), SurfaceChargeDensity{Cpm2: x*y});
+		assert_eq!(div_check(&TimePerDistance{spm: x}, &SurfaceChargeDensity{Cpm2: y}), InverseMagneticFieldStrength{m_per_A: x/y});
+		assert_eq!(div_check(&Velocity{mps: x}, &Current{A: y}), InverseLinearChargeDensity{m_per_C: x/y});
+		assert_eq!(mul_check(&Velocity{mps: x}, &InverseCurrent{per_A: y}), InverseLinearChargeDensity{m_per_C: x*y});
+		assert_eq!(div_check(&Velocity{mps: x}, &InverseLinearChargeDensity{m_per_C: y}), Current{A: x/y});
+		assert_eq!(mul_check(&Velocity{mps: x}, &InverseMagneticFieldStrength{m_per_A: y}), InverseSurfaceChargeDensity{m2_per_C: x*y});
+		assert_eq!(div_check(&Velocity{mps: x}, &InverseSurfaceChargeDensity{m2_per_C: y}), MagneticFieldStrength{Apm: x/y});
+		assert_eq!(mul_check(&Velocity{mps: x}, &LinearChargeDensity{Cpm: y}), Current{A: x*y});
+		assert_eq!(div_check(&Velocity{mps: x}, &MagneticFieldStrength{Apm: y}), InverseSurfaceChargeDensity{m2_per_C: x/y});
+		assert_eq!(mul_check(&Velocity{mps: x}, &SurfaceChargeDensity{Cpm2: y}), MagneticFieldStrength{Apm: x*y});
+		assert_eq!(div_check(&VolumetricFlowRate{m3ps: x}, &Current{A: y}), InverseVolumeChargeDensity{m3_per_C: x/y});
+		assert_eq!(mul_check(&VolumetricFlowRate{m3ps: x}, &InverseCurrent{per_A: y}), InverseVolumeChargeDensity{m3_per_C: x*y});
+		assert_eq!(div_check(&VolumetricFlowRate{m3ps: x}, &InverseVolumeChargeDensity{m3_per_C: y}), Current{A: x/y});
+		assert_eq!(mul_check(&VolumetricFlowRate{m3ps: x}, &VolumeChargeDensity{Cpm3: y}), Current{A: x*y});
+		assert_eq!(div_check(&(x as f64), &InverseLinearChargeDensity{m_per_C: y as f64}), LinearChargeDensity{Cpm: x as f64/y as f64});
+		assert_eq!(div_check(&(x as f32), &InverseLinearChargeDensity{m_per_C: y as f32}), LinearChargeDensity{Cpm: x as f32/y as f32});
+		assert_eq!(div_check(&(x as i64), &InverseLinearChargeDensity{m_per_C: y as i64}), LinearChargeDensity{Cpm: x as i64/y as i64});
+		assert_eq!(div_check(&(x as i32), &InverseLinearChargeDensity{m_per_C: y as i32}), LinearChargeDensity{Cpm: x as i32/y as i32});
+		assert_eq!(div_check(&(x as f64), &InverseSurfaceChargeDensity{m2_per_C: y as f64}), SurfaceChargeDensity{Cpm2: x as f64/y as f64});
+		assert_eq!(div_check(&(x as f32), &InverseSurfaceChargeDensity{m2_per_C: y as f32}), SurfaceChargeDensity{Cpm2: x as f32/y as f32});
+		assert_eq!(div_check(&(x as i64), &InverseSurfaceChargeDensity{m2_per_C: y as i64}), SurfaceChargeDensity{Cpm2: x as i64/y as i64});
+		assert_eq!(div_check(&(x as i32), &InverseSurfaceChargeDensity{m2_per_C: y as i32}), SurfaceChargeDensity{Cpm2: x as i32/y as i32});
+		assert_eq!(div_check(&(x as f64), &InverseVolumeChargeDensity{m3_per_C: y as f64}), VolumeChargeDensity{Cpm3: x as f64/y as f64});
+		assert_eq!(div_check(&(x as f32), &InverseVolumeChargeDensity{m3_per_C: y as f32}), VolumeChargeDensity{Cpm3: x as f32/y as f32});
+		assert_eq!(div_check(&(x as i64), &InverseVolumeChargeDensity{m3_per_C: y as i64}), VolumeChargeDensity{Cpm3: x as i64/y as i64});
+		assert_eq!(div_check(&(x as i32), &InverseVolumeChargeDensity{m3_per_C: y as i32}), VolumeChargeDensity{Cpm3: x as i32/y as i32});
+		assert_eq!(div_check(&(x as f64), &LinearChargeDensity{Cpm: y as f64}), InverseLinearChargeDensity{m_per_C: x as f64/y as f64});
+		assert_eq!(div_check(&(x as f32), &LinearChargeDensity{Cpm: y as f32}), InverseLinearChargeDensity{m_per_C: x as f32/y as f32});
+		assert_eq!(div_check(&(x as i64), &LinearChargeDensity{Cpm: y as i64}), InverseLinearChargeDensity{m_per_C: x as i64/y as i64});
+		assert_eq!(div_check(&(x as i32), &LinearChargeDensity{Cpm: y as i32}), InverseLinearChargeDensity{m_per_C: x as i32/y as i32});
+		assert_eq!(div_check(&(x as f64), &SurfaceChargeDensity{Cpm2: y as f64}), InverseSurfaceChargeDensity{m2_per_C: x as f64/y as f64});
+		assert_eq!(div_check(&(x as f32), &SurfaceChargeDensity{Cpm2: y as f32}), InverseSurfaceChargeDensity{m2_per_C: x as f32/y as f32});
+		assert_eq!(div_check(&(x as i64), &SurfaceChargeDensity{Cpm2: y as i64}), InverseSurfaceChargeDensity{m2_per_C: x as i64/y as i64});
+		assert_eq!(div_check(&(x as i32), &SurfaceChargeDensity{Cpm2: y as i32}), InverseSurfaceChargeDensity{m2_per_C: x as i32/y as i32});
+		assert_eq!(div_check(&(x as f64), &VolumeChargeDensity{Cpm3: y as f64}), InverseVolumeChargeDensity{m3_per_C: x as f64/y as f64});
+		assert_eq!(div_check(&(x as f32), &VolumeChargeDensity{Cpm3: y as f32}), InverseVolumeChargeDensity{m3_per_C: x as f32/y as f32});
+		assert_eq!(div_check(&(x as i64), &VolumeChargeDensity{Cpm3: y as i64}), InverseVolumeChargeDensity{m3_per_C: x as i64/y as i64});
+		assert_eq!(div_check(&(x as i32), &VolumeChargeDensity{Cpm3: y as i32}), InverseVolumeChargeDensity{m3_per_C: x as i32/y as i32});
+		assert_eq!(mul_check(&ElectricField{Vpm: x}, &InverseIrradiance{m2_per_W: y}), InverseMagneticFieldStrength{m_per_A: x*y});
+		assert_eq!(div_check(&ElectricField{Vpm: x}, &InverseMagneticFieldStrength{m_per_A: y}), Irradiance{W_per_m2: x/y});
+		assert_eq!(div_check(&ElectricField{Vpm: x}, &Irradiance{W_per_m2: y}), InverseMagneticFieldStrength{m_per_A: x/y});
+		assert_eq!(mul_check(&ElectricField{Vpm: x}, &MagneticFieldStrength{Apm: y}), Irradiance{W_per_m2: x*y});
+		assert_eq!(div_check(&InverseElectricField{m_per_V: x}, &InverseIrradiance{m2_per_W: y}), MagneticFieldStrength{Apm: x/y});
+		assert_eq!(mul_check(&InverseElectricField{m_per_V: x}, &InverseMagneticFieldStrength{m_per_A: y}), InverseIrradiance{m2_per_W: x*y});
+		assert_eq!(mul_check(&InverseElectricField{m_per_V: x}, &Irradiance{W_per_m2: y}), MagneticFieldStrength{Apm: x*y});
+		assert_eq!(div_check(&InverseElectricField{m_per_V: x}, &MagneticFieldStrength{Apm: y}), InverseIrradiance{m2_per_W: x/y});
+		assert_eq!(mul_check(&InverseIrradiance{m2_per_W: x}, &ElectricField{Vpm: y}), InverseMagneticFieldStrength{m_per_A: x*y});
+		assert_eq!(div_check(&InverseIrradiance{m2_per_W: x}, &InverseElectricField{m_per_V: y}), InverseMagneticFieldStrength{m_per_A: x/y});
+		assert_eq!(div_check(&InverseIrradiance{m2_per_W: x}, &InverseMagneticFieldStrength{m_per_A: y}), InverseElectricField{m_per_V: x/y});
+		assert_eq!(div_check(&InverseIrradiance{m2_per_W: x}, &InverseRadiance{m2sr_per_W: y}), InverseSolidAngle{per_sr: x/y});
+		assert_eq!(mul_check(&InverseIrradiance{m2_per_W: x}, &MagneticFieldStrength{Apm: y}), InverseElectricField{m_per_V: x*y});
+		assert_eq!(mul_check(&InverseIrradiance{m2_per_W: x}, &Radiance{W_per_m2sr: y}), InverseSolidAngle{per_sr: x*y});
+		assert_eq!(div_check(&InverseIrradiance{m2_per_W: x}, &Area{m2: y}), InversePower{per_W: x/y});
+		assert_eq!(mul_check(&InverseIrradiance{m2_per_W: x}, &InverseArea{per_m2: y}), InversePower{per_W: x*y});
+		assert_eq!(div_check(&InverseIrradiance{m2_per_W: x}, &InverseSolidAngle{per_sr: y}), InverseRadiance{m2sr_per_W: x/y});
+		assert_eq!(mul_check(&InverseIrradiance{m2_per_W: x}, &SolidAngle{sr: y}), InverseRadiance{m2sr_per_W: x*y});
+		assert_eq!(mul_check(&InverseIrradiance{m2_per_W: x}, &Acceleration{mps2: y}), InverseDynamicViscosity{per_Pas: x*y});
+		assert_eq!(mul_check(&InverseIrradiance{m2_per_W: x}, &DynamicViscosity{Pas: y}), InverseAcceleration{s2pm: x*y});
+		assert_eq!(div_check(&InverseIrradiance{m2_per_W: x}, &InverseAcceleration{s2pm: y}), InverseDynamicViscosity{per_Pas: x/y});
+		assert_eq!(div_check(&InverseIrradiance{m2_per_W: x}, &InverseDynamicViscosity{per_Pas: y}), InverseAcceleration{s2pm: x/y});
+		assert_eq!(div_check(&InverseIrradiance{m2_per_W: x}, &InversePower{per_W: y}), Area{m2: x/y});
+		assert_eq!(div_check(&InverseIrradiance{m2_per_W: x}, &InversePressure{per_Pa: y}), TimePerDistance{spm: x/y});
+		assert_eq!(mul_check(&InverseIrradiance{m2_per_W: x}, &Power{W: y}), Area{m2: x*y});
+		assert_eq!(mul_check(&InverseIrradiance{m2_per_W: x}, &Pressure{Pa: y}), TimePerDistance{spm: x*y});
+		assert_eq!(div_check(&InverseIrradiance{m2_per_W: x}, &TimePerDistance{spm: y}), InversePressure{per_Pa: x/y});
+		assert_eq!(mul_check(&InverseIrradiance{m2_per_W: x}, &Velocity{mps: y}), InversePressure{per_Pa: x*y});
+		assert_eq!(div_check(&InverseMagneticFieldStrength{m_per_A: x}, &ElectricField{Vpm: y}), InverseIrradiance{m2_per_W: x/y});
+		assert_eq!(mul_check(&InverseMagneticFieldStrength{m_per_A: x}, &InverseElectricField{m_per_V: y}), InverseIrradiance{m2_per_W: x*y});
+		assert_eq!(div_check(&InverseMagneticFieldStrength{m_per_A: x}, &InverseIrradiance{m2_per_W: y}), ElectricField{Vpm: x/y});
+		assert_eq!(mul_check(&InverseMagneticFieldStrength{m_per_A: x}, &Irradiance{W_per_m2: y}), ElectricField{Vpm: x*y});
+		assert_eq!(div_check(&InverseRadiance{m2sr_per_W: x}, &InverseIrradiance{m2_per_W: y}), SolidAngle{sr: x/y});
+		assert_eq!(mul_check(&InverseRadiance{m2sr_per_W: x}, &Irradiance{W_per_m2: y}), SolidAngle{sr: x*y});
+		assert_eq!(mul_check(&InverseRadiance{m2sr_per_W: x}, &InverseSolidAngle{per_sr: y}), InverseIrradiance{m2_per_W: x*y});
+		assert_eq!(div_check(&InverseRadiance{m2sr_per_W: x}, &SolidAngle{sr: y}), InverseIrradiance{m2_per_W: x/y});
+		assert_eq!(div_check(&Irradiance{W_per_m2: x}, &ElectricField{Vpm: y}), MagneticFieldStrength{Apm: x/y});
+		assert_eq!(mul_check(&Irradiance{W_per_m2: x}, &InverseElectricField{m_per_V: y}), MagneticFieldStrength{Apm: x*y});
+		assert_eq!(mul_check(&Irradiance{W_per_m2: x}, &InverseMagneticFieldStrength{m_per_A: y}), ElectricField{Vpm: x*y});
+		assert_eq!(mul_check(&Irradiance{W_per_m2: x}, &InverseRadiance{m2sr_per_W: y}), SolidAngle{sr: x*y});
+		assert_eq!(div_check(&Irradiance{W_per_m2: x}, &MagneticFieldStrength{Apm: y}), ElectricField{Vpm: x/y});
+		assert_eq!(div_check(&Irradiance{W_per_m2: x}, &Radiance{W_per_m2sr: y}), SolidAngle{sr: x/y});
+		assert_eq!(mul_check(&Irradiance{W_per_m2: x}, &Area{m2: y}), Power{W: x*y});
+		assert_eq!(div_check(&Irradiance{W_per_m2: x}, &InverseArea{per_m2: y}), Power{W: x/y});
+		assert_eq!(mul_check(&Irradiance{W_per_m2: x}, &InverseSolidAngle{per_sr: y}), Radiance{W_per_m2sr: x*y});
+		assert_eq!(div_check(&Irradiance{W_per_m2: x}, &SolidAngle{sr: y}), Radiance{W_per_m2sr: x/y});
+		assert_eq!(div_check(&Irradiance{W_per_m2: x}, &Acceleration{mps2: y}), DynamicViscosity{Pas: x/y});
+		assert_eq!(div_check(&Irradiance{W_per_m2: x}, &DynamicViscosity{Pas: y}), Acceleration{mps2: x/y});
+		assert_eq!(mul_check(&Irradiance{W_per_m2: x}, &InverseAcceleration{s2pm: y}), DynamicViscosity{Pas: x*y});
+		assert_eq!(mul_check(&Irradiance{W_per_m2: x}, &InverseDynamicViscosity{per_Pas: y}), Acceleration{mps2: x*y});
+		assert_eq!(mul_check(&Irradiance{W_per_m2: x}, &InversePower{per_W: y}), InverseArea{per_m2: x*y});
+		assert_eq!(mul_check(&Irradiance{W_per_m2: x}, &InversePressure{per_Pa: y}), Velocity{mps: x*y});
+		assert_eq!(div_check(&Irradiance{W_per_m2: x}, &Power{W: y}), InverseArea{per_m2: x/y});
+		assert_eq!(div_check(&Irradiance{W_per_m2: x}, &Pressure{Pa: y}), Velocity{mps: x/y});
+		assert_eq!(mul_check(&Irradiance{W_per_m2: x}, &TimePerDistance{spm: y}), Pressure{Pa: x*y});
+		assert_eq!(div_check(&Irradiance{W_per_m2: x}, &Velocity{mps: y}), Pressure{Pa: x/y});
+		assert_eq!(mul_check(&MagneticFieldStrength{Apm: x}, &ElectricField{Vpm: y}), Irradiance{W_per_m2: x*y});
+		assert_eq!(div_check(&MagneticFieldStrength{Apm: x}, &InverseElectricField{m_per_V: y}), Irradiance{W_per_m2: x/y});
+		assert_eq!(mul_check(&MagneticFieldStrength{Apm: x}, &InverseIrradiance{m2_per_W: y}), InverseElectricField{m_per_V: x*y});
+		assert_eq!(div_check(&MagneticFieldStrength{Apm: x}, &Irradiance{W_per_m2: y}), InverseElectricField{m_per_V: x/y});
+		assert_eq!(mul_check(&Radiance{W_per_m2sr: x}, &InverseIrradiance{m2_per_W: y}), InverseSolidAngle{per_sr: x*y});
+		assert_eq!(div_check(&Radiance{W_per_m2sr: x}, &Irradiance{W_per_m2: y}), InverseSolidAngle{per_sr: x/y});
+		assert_eq!(div_check(&Radiance{W_per_m2sr: x}, &InverseSolidAngle{per_sr: y}), Irradiance{W_per_m2: x/y});
+		assert_eq!(mul_check(&Radiance{W_per_m2sr: x}, &SolidAngle{sr: y}), Irradiance{W_per_m2: x*y});
+		assert_eq!(div_check(&Area{m2: x}, &InverseIrradiance{m2_per_W: y}), Power{W: x/y});
+		assert_eq!(mul_check(&Area{m2: x}, &Irradiance{W_per_m2: y}), Power{W: x*y});
+		assert_eq!(mul_check(&Area{m2: x}, &InversePower{per_W: y}), InverseIrradiance{m2_per_W: x*y});
+		assert_eq!(div_check(&Area{m2: x}, &Power{W: y}), InverseIrradiance{m2_per_W: x/y});
+		assert_eq!(mul_check(&InverseArea{per_m2: x}, &InverseIrradiance{m2_per_W: y}), InversePower{per_W: x*y});
+		assert_eq!(div_check(&InverseArea{per_m2: x}, &Irradiance{W_per_m2: y}), InversePower{per_W: x/y});
+		assert_eq!(div_check(&InverseArea{per_m2: x}, &InversePower{per_W: y}), Irradiance{W_per_m2: x/y});
+		assert_eq!(mul_check(&InverseArea{per_m2: x}, &Power{W: y}), Irradiance{W_per_m2: x*y});
+		assert_eq!(div_check(&InverseSolidAngle{per_sr: x}, &InverseIrradiance{m2_per_W: y}), Radiance{W_per_m2sr: x/y});
+		assert_eq!(mul_check(&InverseSolidAngle{per_sr: x}, &InverseRadiance{m2sr_per_W: y}), InverseIrradiance{m2_per_W: x*y});
+		assert_eq!(mul_check(&InverseSolidAngle{per_sr: x}, &Irradiance{W_per_m2: y}), Radiance{W_per_m2sr: x*y});
+		assert_eq!(div_check(&InverseSolidAngle{per_sr: x}, &Radiance{W_per_m2sr: y}), InverseIrradiance{m2_per_W: x/y});
+		assert_eq!(mul_check(&SolidAngle{sr: x}, &InverseIrradiance{m2_per_W: y}), InverseRadiance{m2sr_per_W: x*y});
+		assert_eq!(div_check(&SolidAngle{sr: x}, &InverseRadiance{m2sr_per_W: y}), Irradiance{W_per_m2: x/y});
+		assert_eq!(div_check(&SolidAngle{sr: x}, &Irradiance{W_per_m2: y}), InverseRadiance{m2sr_per_W: x/y});
+		assert_eq!(mul_check(&SolidAngle{sr: x}, &Radiance{W_per_m2sr: y}), Irradiance{W_per_m2: x*y});
+		assert_eq!(mul_check(&Acceleration{mps2: x}, &InverseIrradiance{m2_per_W: y}), InverseDynamicViscosity{per_Pas: x*y});
+		assert_eq!(div_check(&Acceleration{mps2: x}, &Irradiance{W_per_m2: y}), InverseDynamicViscosity{per_Pas: x/y});
+		assert_eq!(mul_check(&Acceleration{mps2: x}, &DynamicViscosity{Pas: y}), Irradiance{W_per_m2: x*y});
+		assert_eq!(div_check(&Acceleration{mps2: x}, &InverseDynamicViscosity{per_Pas: y}), Irradiance{W_per_m2: x/y});
+		assert_eq!(mul_check(&DynamicViscosity{Pas: x}, &InverseIrradiance{m2_per_W: y}), InverseAcceleration{s2pm: x*y});
+		assert_eq!(div_check(&DynamicViscosity{Pas: x}, &Irradiance{W_per_m2: y}), InverseAcceleration{s2pm: x/y});
+		assert_eq!(mul_check(&DynamicViscosity{Pas: x}, &Acceleration{mps2: y}), Irradiance{W_per_m2: x*y});
+		assert_eq!(div_check(&DynamicViscosity{Pas: x}, &InverseAcceleration{s2pm: y}), Irradiance{W_per_m2: x/y});
+		assert_eq!(div_check(&InverseAcceleration{s2pm: x}, &InverseIrradiance{m2_per_W: y}), DynamicViscosity{Pas: x/y});
+		assert_eq!(mul_check(&InverseAcceleration{s2pm: x}, &Irradiance{W_per_m2: y}), DynamicViscosity{Pas: x*y});
+		assert_eq!(div_check(&InverseAcceleration{s2pm: x}, &DynamicViscosity{Pas: y}), InverseIrradiance{m2_per_W: x/y});
+		assert_eq!(mul_check(&InverseAcceleration{s2pm: x}, &InverseDynamicViscosity{per_Pas: y}), InverseIrradiance{m2_per_W: x*y});
+		assert_eq!(div_check(&InverseDynamicViscosity{per_Pas: x}, &InverseIrradiance{m2_per_W: y}), Acceleration{mps2: x/y});
+		assert_eq!(mul_check(&InverseDynamicViscosity{per_Pas: x}, &Irradiance{W_per_m2: y}), Acceleration{mps2: x*y});
+		assert_eq!(div_check(&InverseDynamicViscosity{per_Pas: x}, &Acceleration{mps2: y}), InverseIrradiance{m2_per_W: x/y});
+		assert_eq!(mul_check(&InverseDynamicViscosity{per_Pas: x}, &InverseAcceleration{s2pm: y}), InverseIrradiance{m2_per_W: x*y});
+		assert_eq!(div_check(&InversePower{per_W: x}, &InverseIrradiance{m2_per_W: y}), InverseArea{per_m2: x/y});
+		assert_eq!(mul_check(&InversePower{per_W: x}, &Irradiance{W_per_m2: y}), InverseArea{per_m2: x*y});
+		assert_eq!(mul_check(&InversePower{per_W: x}, &Area{m2: y}), InverseIrradiance{m2_per_W: x*y});
+		assert_eq!(div_check(&InversePower{per_W: x}, &InverseArea{per_m2: y}), InverseIrradiance{m2_per_W: x/y});
+		assert_eq!(div_check(&InversePressure{per_Pa: x}, &InverseIrradiance{m2_per_W: y}), Velocity{mps: x/y});
+		assert_eq!(mul_check(&InversePressure{per_Pa: x}, &Irradiance{W_per_m2: y}), Velocity{mps: x*y});
+		assert_eq!(mul_check(&InversePressure{per_Pa: x}, &TimePerDistance{spm: y}), InverseIrradiance{m2_per_W: x*y});
+		assert_eq!(div_check(&InversePressure{per_Pa: x}, &Velocity{mps: y}), InverseIrradiance{m2_per_W: x/y});
+		assert_eq!(mul_check(&Power{W: x}, &InverseIrradiance{m2_per_W: y}), Area{m2: x*y});
+		assert_eq!(div_check(&Power{W: x}, &Irradiance{W_per_m2: y}), Area{m2: x/y});
+		assert_eq!(div_check(&Power{W: x}, &Area{m2: y}), Irradiance{W_per_m2: x/y});
+		assert_eq!(mul_check(&Power{W: x}, &InverseArea{per_m2: y}), Irradiance{W_per_m2: x*y});
+		assert_eq!(mul_check(&Pressure{Pa: x}, &InverseIrradiance{m2_per_W: y}), TimePerDistance{spm: x*y});
+		assert_eq!(div_check(&Pressure{Pa: x}, &Irradiance{W_per_m2: y}), TimePerDistance{spm: x/y});
+		assert_eq!(div_check(&Pressure{Pa: x}, &TimePerDistance{spm: y}), Irradiance{W_per_m2: x/y});
+		assert_eq!(mul_check(&Pressure{Pa: x}, &Velocity{mps: y}), Irradiance{W_per_m2: x*y});
+		assert_eq!(div_check(&TimePerDistance{spm: x}, &InverseIrradiance{m2_per_W: y}), Pressure{Pa: x/y});
+		assert_eq!(mul_check(&TimePerDistance{spm: x}, &Irradiance{W_per_m2: y}), Pressure{Pa: x*y});
+		assert_eq!(mul_check(&TimePerDistance{spm: x}, &InversePressure{per_Pa: y}), InverseIrradiance{m2_per_W: x*y});
+		assert_eq!(div_check(&TimePerDistance{spm: x}, &Pressure{Pa: y}), InverseIrradiance{m2_per_W: x/y});
+		assert_eq!(mul_check(&Velocity{mps: x}, &InverseIrradiance{m2_per_W: y}), InversePressure{per_Pa: x*y});
+		assert_eq!(div_check(&Velocity{mps: x}, &Irradiance{W_per_m2: y}), InversePressure{per_Pa: x/y});
+		assert_eq!(div_check(&Velocity{mps: x}, &InversePressure{per_Pa: y}), Irradiance{W_per_m2: x/y});
+		assert_eq!(mul_check(&Velocity{mps: x}, &Pressure{Pa: y}), Irradiance{W_per_m2: x*y});
+		assert_eq!(div_check(&(x as f64), &InverseIrradiance{m2_per_W: y as f64}), Irradiance{W_per_m2: x as f64/y as f64});
+		assert_eq!(div_check(&(x as f32), &InverseIrradiance{m2_per_W: y as f32}), Irradiance{W_per_m2: x as f32/y as f32});
+		assert_eq!(div_check(&(x as i64), &InverseIrradiance{m2_per_W: y as i64}), Irradiance{W_per_m2: x as i64/y as i64});
+		assert_eq!(div_check(&(x as i32), &InverseIrradiance{m2_per_W: y as i32}), Irradiance{W_per_m2: x as i32/y as i32});
+		assert_eq!(div_check(&(x as f64), &InverseRadiance{m2sr_per_W: y as f64}), Radiance{W_per_m2sr: x as f64/y as f64});
+		assert_eq!(div_check(&(x as f32), &InverseRadiance{m2sr_per_W: y as f32}), Radiance{W_per_m2sr: x as f32/y as f32});
+		assert_eq!(div_check(&(x as i64), &InverseRadiance{m2sr_per_W: y as i64}), Radiance{W_per_m2sr: x as i64/y as i64});
+		assert_eq!(div_check(&(x as i32), &InverseRadiance{m2sr_per_W: y as i32}), Radiance{W_per_m2sr: x as i32/y as i32});
+		assert_eq!(div_check(&(x as f64), &Irradiance{W_per_m2: y as f64}), InverseIrradiance{m2_per_W: x as f64/y as f64});
+		assert_eq!(div_check(&(x as f32), &Irradiance{W_per_m2: y as f32}), InverseIrradiance{m2_per_W: x as f32/y as f32});
+		assert_eq!(div_check(&(x as i64), &Irradiance{W_per_m2: y as i64}), InverseIrradiance{m2_per_W: x as i64/y as i64});
+		assert_eq!(div_check(&(x as i32), &Irradiance{W_per_m2: y as i32}), InverseIrradiance{m2_per_W: x as i32/y as i32});
+		assert_eq!(div_check(&(x as f64), &Radiance{W_per_m2sr: y as f64}), InverseRadiance{m2sr_per_W: x as f64/y as f64});
+		assert_eq!(div_check(&(x as f32), &Radiance{W_per_m2sr: y as f32}), InverseRadiance{m2sr_per_W: x as f32/y as f32});
+		assert_eq!(div_check(&(x as i64), &Radiance{W_per_m2sr: y as i64}), InverseRadiance{m2sr_per_W: x as i64/y as i64});
+		assert_eq!(div_check(&(x as i32), &Radiance{W_per_m2sr: y as i32}), InverseRadiance{m2sr_per_W: x as i32/y as i32});
+		assert_eq!(div_check(&Amount{mol: x}, &Entropy{J_per_K: y}), InverseMolarEntropy{molK_per_J: x/y});
+		assert_eq!(div_check(&Amount{mol: x}, &HeatCapacity{J_per_K: y}), InverseMolarEntropy{molK_per_J: x/y});
+		assert_eq!(mul_check(&Amount{mol: x}, &InverseEntropy{K_per_J: y}), InverseMolarEntropy{molK_per_J: x*y});
+		assert_eq!(mul_check(&Amount{mol: x}, &InverseHeatCapacity{K_per_J: y}), InverseMolarEntropy{molK_per_J: x*y});
+		assert_eq!(div_check(&Amount{mol: x}, &InverseMolarEnergy{mol_per_J: y}), Energy{J: x/y});
+		assert_eq!(div_check(&Amount{mol: x}, &InverseMolarEntropy{molK_per_J: y}), HeatCapacity{J_per_K: x/y});
+		assert_eq!(mul_check(&Amount{mol: x}, &MolarEnergy{J_per_mol: y}), Energy{J: x*y});
+		assert_eq!(mul_check(&Amount{mol: x}, &MolarEntropy{J_per_molK: y}), HeatCapacity{J_per_K: x*y});
+		assert_eq!(div_check(&Amount{mol: x}, &Energy{J: y}), InverseMolarEnergy{mol_per_J: x/y});
+		assert_eq!(div_check(&Amount{mol: x}, &Torque{Nm: y}), InverseMolarEnergy{mol_per_J: x/y});
+		assert_eq!(mul_check(&Amount{mol: x}, &InverseEnergy{per_J: y}), InverseMolarEnergy{mol_per_J: x*y});
+		assert_eq!(mul_check(&Amount{mol: x}, &InverseTorque{per_Nm: y}), InverseMolarEnergy{mol_per_J: x*y});
+		assert_eq!(mul_check(&InverseAmount{per_mol: x}, &Entropy{J_per_K: y}), MolarEntropy{J_per_molK: x*y});
+		assert_eq!(mul_check(&InverseAmount{per_mol: x}, &HeatCapacity{J_per_K: y}), MolarEntropy{J_per_molK: x*y});
+		assert_eq!(div_check(&InverseAmount{per_mol: x}, &InverseEntropy{K_per_J: y}), MolarEntropy{J_per_molK: x/y});
+		assert_eq!(div_check(&InverseAmount{per_mol: x}, &InverseHeatCapacity{K_per_J: y}), MolarEntropy{J_per_molK: x/y});
+		assert_eq!(mul_check(&InverseAmount{per_mol: x}, &InverseMolarEnergy{mol_per_J: y}), InverseEnergy{per_J: x*y});
+		assert_eq!(mul_check(&InverseAmount{per_mol: x}, &InverseMolarEntropy{molK_per_J: y}), InverseHeatCapacity{K_per_J: x*y});
+		assert_eq!(div_check(&InverseAmount{per_mol: x}, &MolarEnergy{J_per_mol: y}), InverseEnergy{per_J: x/y});
+		assert_eq!(div_check(&InverseAmount{per_mol: x}, &MolarEntropy{J_per_molK: y}), InverseHeatCapacity{K_per_J: x/y});
+		assert_eq!(mul_check(&InverseAmount{per_mol: x}, &Energy{J: y}), MolarEnergy{J_per_mol: x*y});
+		assert_eq!(mul_check(&InverseAmount{per_mol: x}, &Torque{Nm: y}), MolarEnergy{J_per_mol: x*y});
+		assert_eq!(div_check(&InverseAmount{per_mol: x}, &InverseEnergy{per_J: y}), MolarEnergy{J_per_mol: x/y});
+		assert_eq!(div_check(&InverseAmount{per_mol: x}, &InverseTorque{per_Nm: y}), MolarEnergy{J_per_mol: x/y});
+		assert_eq!(div_check(&InverseTemperature{per_K: x}, &InverseMolarEnergy{mol_per_J: y}), MolarEntropy{J_per_molK: x/y});
+		assert_eq!(mul_check(&InverseTemperature{per_K: x}, &InverseMolarEntropy{molK_per_J: y}), InverseMolarEnergy{mol_per_J: x*y});
+		assert_eq!(mul_check(&InverseTemperature{per_K: x}, &MolarEnergy{J_per_mol: y}), MolarEntropy{J_per_molK: x*y});
+		assert_eq!(div_check(&InverseTemperature{per_K: x}, &MolarEntropy{J_per_molK: y}), InverseMolarEnergy{mol_per_J: x/y});
+		assert_eq!(mul_check(&Temperature{K: x}, &InverseMolarEnergy{mol_per_J: y}), InverseMolarEntropy{molK_per_J: x*y});
+		assert_eq!(div_check(&Temperature{K: x}, &InverseMolarEntropy{molK_per_J: y}), MolarEnergy{J_per_mol: x/y});
+		assert_eq!(div_check(&Temperature{K: x}, &MolarEnergy{J_per_mol: y}), InverseMolarEntropy{molK_per_J: x/y});
+		assert_eq!(mul_check(&Temperature{K: x}, &MolarEntropy{J_per_molK: y}), MolarEnergy{J_per_mol: x*y});
+		assert_eq!(mul_check(&TemperatureInterval{K: x}, &InverseMolarEnergy{mol_per_J: y}), InverseMolarEntropy{molK_per_J: x*y});
+		assert_eq!(div_check(&TemperatureInterval{K: x}, &InverseMolarEntropy{molK_per_J: y}), MolarEnergy{J_per_mol: x/y});
+		assert_eq!(div_check(&TemperatureInterval{K: x}, &MolarEnergy{J_per_mol: y}), InverseMolarEntropy{molK_per_J: x/y});
+		assert_eq!(mul_check(&TemperatureInterval{K: x}, &MolarEntropy{J_per_molK: y}), MolarEnergy{J_per_mol: x*y});
+		assert_eq!(div_check(&CatalyticActivity{molps: x}, &InverseMolarEnergy{mol_per_J: y}), Power{W: x/y});
+		assert_eq!(div_check(&CatalyticActivity{molps: x}, &InverseMolarEntropy{molK_per_J: y}), ThermalConductance{W_per_K: x/y});
+		assert_eq!(mul_check(&CatalyticActivity{molps: x}, &MolarEnergy{J_per_mol: y}), Power{W: x*y});
+		assert_eq!(mul_check(&CatalyticActivity{molps: x}, &MolarEntropy{J_per_molK: y}), ThermalConductance{W_per_K: x*y});
+		assert_eq!(div_check(&CatalyticActivity{molps: x}, &ThermalConductance{W_per_K: y}), InverseMolarEntropy{molK_per_J: x/y});
+		assert_eq!(mul_check(&CatalyticActivity{molps: x}, &ThermalResistance{K_per_W: y}), InverseMolarEntropy{molK_per_J: x*y});
+		assert_eq!(mul_check(&CatalyticActivity{molps: x}, &InversePower{per_W: y}), InverseMolarEnergy{mol_per_J: x*y});
+		assert_eq!(div_check(&CatalyticActivity{molps: x}, &Power{W: y}), InverseMolarEnergy{mol_per_J: x/y});
+		assert_eq!(div_check(&Concentration{molpm3: x}, &InverseMolarEnergy{mol_per_J: y}), Pressure{Pa: x/y});
+		assert_eq!(mul_check(&Concentration{molpm3: x}, &MolarEnergy{J_per_mol: y}), Pressure{Pa: x*y});
+		assert_eq!(mul_check(&Concentration{molpm3: x}, &InversePressure{per_Pa: y}), InverseMolarEnergy{mol_per_J: x*y});
+		assert_eq!(div_check(&Concentration{molpm3: x}, &Pressure{Pa: y}), InverseMolarEnergy{mol_per_J: x/y});
+		assert_eq!(div_check(&Entropy{J_per_K: x}, &Amount{mol: y}), MolarEntropy{J_per_molK: x/y});
+		assert_eq!(mul_check(&Entropy{J_per_K: x}, &InverseAmount{per_mol: y}), MolarEntropy{J_per_molK: x*y});
+		assert_eq!(mul_check(&Entropy{J_per_K: x}, &InverseMolarEntropy{molK_per_J: y}), Amount{mol: x*y});
+		assert_eq!(div_check(&Entropy{J_per_K: x}, &MolarEntropy{J_per_molK: y}), Amount{mol: x/y});
+		assert_eq!(div_check(&HeatCapacity{J_per_K: x}, &Amount{mol: y}), MolarEntropy{J_per_molK: x/y});
+		assert_eq!(mul_check(&HeatCapacity{J_per_K: x}, &InverseAmount{per_mol: y}), MolarEntropy{J_per_molK: x*y});
+		assert_eq!(mul_check(&HeatCapacity{J_per_K: x}, &InverseMolarEntropy{molK_per_J: y}), Amount{mol: x*y});
+		assert_eq!(div_check(&HeatCapacity{J_per_K: x}, &MolarEntropy{J_per_molK: y}), Amount{mol: x/y});
+		assert_eq!(mul_check(&InverseCatalyticActivity{s_per_mol: x}, &InverseMolarEnergy{mol_per_J: y}), InversePower{per_W: x*y});
+		assert_eq!(mul_check(&InverseCatalyticActivity{s_per_mol: x}, &InverseMolarEntropy{molK_per_J: y}), ThermalResistance{K_per_W: x*y});
+		assert_eq!(div_check(&InverseCatalyticActivity{s_per_mol: x}, &MolarEnergy{J_per_mol: y}), InversePower{per_W: x/y});
+		assert_eq!(div_check(&InverseCatalyticActivity{s_per_mol: x}, &MolarEntropy{J_per_molK: y}), ThermalResistance{K_per_W: x/y});
+		assert_eq!(mul_check(&InverseCatalyticActivity{s_per_mol: x}, &ThermalConductance{W_per_K: y}), MolarEntropy{J_per_molK: x*y});
+		assert_eq!(div_check(&InverseCatalyticActivity{s_per_mol: x}, &ThermalResistance{K_per_W: y}), MolarEntropy{J_per_molK: x/y});
+		assert_eq!(div_check(&InverseCatalyticActivity{s_per_mol: x}, &InversePower{per_W: y}), MolarEnergy{J_per_mol: x/y});
+		assert_eq!(mul_check(&InverseCatalyticActivity{s_per_mol: x}, &Power{W: y}), MolarEnergy{J_per_mol: x*y});
+		assert_eq!(mul_check(&InverseEntropy{K_per_J: x}, &Amount{mol: y}), InverseMolarEntropy{molK_per_J: x*y});
+		assert_eq!(div_check(&InverseEntropy{K_per_J: x}, &InverseAmount{per_mol: y}), InverseMolarEntropy{molK_per_J: x/y});
+		assert_eq!(div_check(&InverseEntropy{K_per_J: x}, &InverseMolarEntropy{molK_per_J: y}), InverseAmount{per_mol: x/y});
+		assert_eq!(mul_check(&InverseEntropy{K_per_J: x}, &MolarEntropy{J_per_molK: y}), InverseAmount{per_mol: x*y});
+		assert_eq!(mul_check(&InverseHeatCapacity{K_per_J: x}, &Amount{mol: y}), InverseMolarEntropy{molK_per_J: x*y});
+		assert_eq!(div_check(&InverseHeatCapacity{K_per_J: x}, &InverseAmount{per_mol: y}), InverseMolarEntropy{molK_per_J: x/y});
+		assert_eq!(div_check(&InverseHeatCapacity{K_per_J: x}, &InverseMolarEntropy{molK_per_J: y}), InverseAmount{per_mol: x/y});
+		assert_eq!(mul_check(&InverseHeatCapacity{K_per_J: x}, &MolarEntropy{J_per_molK: y}), InverseAmount{per_mol: x*y});
+		assert_eq!(div_check(&InverseMolarEnergy{mol_per_J: x}, &Amount{mol: y}), InverseEnergy{per_J: x/y});
+		assert_eq!(mul_check(&InverseMolarEnergy{mol_per_J: x}, &InverseAmount{per_mol: y}), InverseEnergy{per_J: x*y});
+		assert_eq!(div_check(&InverseMolarEnergy{mol_per_J: x}, &InverseTemperature{per_K: y}), InverseMolarEntropy{molK_per_J: x/y});
+		assert_eq!(mul_check(&InverseMolarEnergy{mol_per_J: x}, &Temperature{K: y}), InverseMolarEntropy{molK_per_J: x*y});
+		assert_eq!(mul_check(&InverseMolarEnergy{mol_per_J: x}, &TemperatureInterval{K: y}), InverseMolarEntropy{molK_per_J: x*y});
+		assert_eq!(div_check(&InverseMolarEnergy{mol_per_J: x}, &CatalyticActivity{molps: y}), InversePower{per_W: x/y});
+		assert_eq!(div_check(&InverseMolarEnergy{mol_per_J: x}, &Concentration{molpm3: y}), InversePressure{per_Pa: x/y});
+		assert_eq!(mul_check(&InverseMolarEnergy{mol_per_J: x}, &InverseCatalyticActivity{s_per_mol: y}), InversePower{per_W: x*y});
+		assert_eq!(div_check(&InverseMolarEnergy{mol_per_J: x}, &InverseMolarEntropy{molK_per_J: y}), InverseTemperature{per_K: x/y});
+		assert_eq!(mul_check(&InverseMolarEnergy{mol_per_J: x}, &MolarEntropy{J_per_molK: y}), InverseTemperature{per_K: x*y});
+		assert_eq!(mul_check(&InverseMolarEnergy{mol_per_J: x}, &MolarVolume{m3_per_mol: y}), InversePressure{per_Pa: x*y});
+		assert_eq!(mul_check(&InverseMolarEnergy{mol_per_J: x}, &Energy{J: y}), Amount{mol: x*y});
+		assert_eq!(mul_check(&InverseMolarEnergy{mol_per_J: x}, &Torque{Nm: y}), Amount{mol: x*y});
+		assert_eq!(div_check(&InverseMolarEnergy{mol_per_J: x}, &InverseEnergy{per_J: y}), Amount{mol: x/y});
+		assert_eq!(div_check(&InverseMolarEnergy{mol_per_J: x}, &InverseTorque{per_Nm: y}), Amount{mol: x/y});
+		assert_eq!(div_check(&InverseMolarEnergy{mol_per_J: x}, &InversePower{per_W: y}), CatalyticActivity{molps: x/y});
+		assert_eq!(div_check(&InverseMolarEnergy{mol_per_J: x}, &InversePressure{per_Pa: y}), Concentration{molpm3: x/y});
+		assert_eq!(mul_check(&InverseMolarEnergy{mol_per_J: x}, &Power{W: y}), CatalyticActivity{molps: x*y});
+		assert_eq!(mul_check(&InverseMolarEnergy{mol_per_J: x}, &Pressure{Pa: y}), Concentration{molpm3: x*y});
+		assert_eq!(div_check(&InverseMolarEnergy{mol_per_J: x}, &InverseAbsorbedDose{per_Gy: y}), Molality{molpkg: x/y});
+		assert_eq!(div_check(&InverseMolarEnergy{mol_per_J: x}, &InverseDoseEquivalent{per_Sv: y}), Molality{molpkg: x/y});
+		assert_eq!(div_check(&InverseMolarEntropy{molK_per_J: x}, &Amount{mol: y}), InverseHeatCapacity{K_per_J: x/y});
+		assert_eq!(mul_check(&InverseMolarEntropy{molK_per_J: x}, &InverseAmount{per_mol: y}), InverseHeatCapacity{K_per_J: x*y});
+		assert_eq!(mul_check(&InverseMolarEntropy{molK_per_J: x}, &InverseTemperature{per_K: y}), InverseMolarEnergy{mol_per_J: x*y});
+		assert_eq!(div_check(&InverseMolarEntropy{molK_per_J: x}, &Temperature{K: y}), InverseMolarEnergy{mol_per_J: x/y});
+		assert_eq!(div_check(&InverseMolarEntropy{molK_per_J: x}, &TemperatureInterval{K: y}), InverseMolarEnergy{mol_per_J: x/y});
+		assert_eq!(div_check(&InverseMolarEntropy{molK_per_J: x}, &CatalyticActivity{molps: y}), ThermalResistance{K_per_W: x/y});
+		assert_eq!(mul_check(&InverseMolarEntropy{molK_per_J: x}, &Entropy{J_per_K: y}), Amount{mol: x*y});
+		assert_eq!(mul_check(&InverseMolarEntropy{molK_per_J: x}, &HeatCapacity{J_per_K: y}), Amount{mol: x*y});
+		assert_eq!(mul_check(&InverseMolarEntropy{molK_per_J: x}, &InverseCatalyticActivity{s_per_mol: y}), ThermalResistance{K_per_W: x*y});
+		assert_eq!(div_check(&InverseMolarEntropy{molK_per_J: x}, &InverseEntropy{K_per_J: y}), Amount{mol: x/y});
+		assert_eq!(div_check(&InverseMolarEntropy{molK_per_J: x}, &InverseHeatCapacity{K_per_J: y}), Amount{mol: x/y});
+		assert_eq!(div_check(&InverseMolarEntropy{molK_per_J: x}, &InverseMolarEnergy{mol_per_J: y}), Temperature{K: x/y});
+		assert_eq!(div_check(&InverseMolarEntropy{molK_per_J: x}, &InverseSpecificHeatCapacity{kgK_per_J: y}), Molality{molpkg: x/y});
+		assert_eq!(div_check(&InverseMolarEntropy{molK_per_J: x}, &Molality{molpkg: y}), InverseSpecificHeatCapacity{kgK_per_J: x/y});
+		assert_eq!(mul_check(&InverseMolarEntropy{molK_per_J: x}, &MolarEnergy{J_per_mol: y}), Temperature{K: x*y});
+		assert_eq!(mul_check(&InverseMolarEntropy{molK_per_J: x}, &MolarMass{kgpmol: y}), InverseSpecificHeatCapacity{kgK_per_J: x*y});
+		assert_eq!(mul_check(&InverseMolarEntropy{molK_per_J: x}, &SpecificHeatCapacity{J_per_kgK: y}), Molality{molpkg: x*y});
+		assert_eq!(mul_check(&InverseMolarEntropy{molK_per_J: x}, &ThermalConductance{W_per_K: y}), CatalyticActivity{molps: x*y});
+		assert_eq!(div_check(&InverseMolarEntropy{molK_per_J: x}, &ThermalResistance{K_per_W: y}), CatalyticActivity{molps: x/y});
+		assert_eq!(div_check(&InverseSpecificHeatCapacity{kgK_per_J: x}, &InverseMolarEntropy{molK_per_J: y}), MolarMass{kgpmol: x/y});
+		assert_eq!(mul_check(&InverseSpecificHeatCapacity{kgK_per_J: x}, &Molality{molpkg: y}), InverseMolarEntropy{molK_per_J: x*y});
+		assert_eq!(mul_check(&InverseSpecificHeatCapacity{kgK_per_J: x}, &MolarEntropy{J_per_molK: y}), MolarMass{kgpmol: x*y});
+		assert_eq!(div_check(&InverseSpecificHeatCapacity{kgK_per_J: x}, &MolarMass{kgpmol: y}), InverseMolarEntropy{molK_per_J: x/y});
+		assert_eq!(div_check(&Molality{molpkg: x}, &InverseMolarEntropy{molK_per_J: y}), SpecificHeatCapacity{J_per_kgK: x/y});
+		assert_eq!(mul_check(&Molality{molpkg: x}, &InverseSpecificHeatCapacity{kgK_per_J: y}), InverseMolarEntropy{molK_per_J: x*y});
+		assert_eq!(mul_check(&Molality{molpkg: x}, &MolarEntropy{J_per_molK: y}), SpecificHeatCapacity{J_per_kgK: x*y});
+		assert_eq!(div_check(&Molality{molpkg: x}, &SpecificHeatCapacity{J_per_kgK: y}), InverseMolarEntropy{molK_per_J: x/y});
+		assert_eq!(mul_check(&Molality{molpkg: x}, &InverseAbsorbedDose{per_Gy: y}), InverseMolarEnergy{mol_per_J: x*y});
+		assert_eq!(mul_check(&Molality{molpkg: x}, &InverseDoseEquivalent{per_Sv: y}), InverseMolarEnergy{mol_per_J: x*y});
+		assert_eq!(mul_check(&MolarEnergy{J_per_mol: x}, &Amount{mol: y}), Energy{J: x*y});
+		assert_eq!(div_check(&MolarEnergy{J_per_mol: x}, &InverseAmount{per_mol: y}), Energy{J: x/y});
+		assert_eq!(mul_check(&MolarEnergy{J_per_mol: x}, &InverseTemperature{per_K: y}), MolarEntropy{J_per_molK: x*y});
+		assert_eq!(div_check(&MolarEnergy{J_per_mol: x}, &Temperature{K: y}), MolarEntropy{J_per_molK: x/y});
+		assert_eq!(div_check(&MolarEnergy{J_per_mol: x}, &TemperatureInterval{K: y}), MolarEntropy{J_per_molK: x/y});
+		assert_eq!(mul_check(&MolarEnergy{J_per_mol: x}, &CatalyticActivity{molps: y}), Power{W: x*y});
+		assert_eq!(mul_check(&MolarEnergy{J_per_mol: x}, &Concentration{molpm3: y}), Pressure{Pa: x*y});
+		assert_eq!(div_check(&MolarEnergy{J_per_mol: x}, &InverseCatalyticActivity{s_per_mol: y}), Power{W: x/y});
+		assert_eq!(mul_check(&MolarEnergy{J_per_mol: x}, &InverseMolarEntropy{molK_per_J: y}), Temperature{K: x*y});
+		assert_eq!(div_check(&MolarEnergy{J_per_mol: x}, &MolarEntropy{J_per_molK: y}), Temperature{K: x/y});
+		assert_eq!(div_check(&MolarEnergy{J_per_mol: x}, &MolarVolume{m3_per_mol: y}), Pressure{Pa: x/y});
+		assert_eq!(div_check(&MolarEnergy{J_per_mol: x}, &Energy{J: y}), InverseAmount{per_mol: x/y});
+		assert_eq!(div_check(&MolarEnergy{J_per_mol: x}, &Torque{Nm: y}), InverseAmount{per_mol: x/y});
+		assert_eq!(mul_check(&MolarEnergy{J_per_mol: x}, &InverseEnergy{per_J: y}), InverseAmount{per_mol: x*y});
+		assert_eq!(mul_check(&MolarEnergy{J_per_mol: x}, &InverseTorque{per_Nm: y}), InverseAmount{per_mol: x*y});
+		assert_eq!(mul_check(&MolarEnergy{J_per_mol: x}, &InversePower{per_W: y}), InverseCatalyticActivity{s_per_mol: x*y});
+		assert_eq!(mul_check(&MolarEnergy{J_per_mol: x}, &InversePressure{per_Pa: y}), MolarVolume{m3_per_mol: x*y});
+		assert_eq!(div_check(&MolarEnergy{J_per_mol: x}, &Power{W: y}), InverseCatalyticActivity{s_per_mol: x/y});
+		assert_eq!(div_check(&MolarEnergy{J_per_mol: x}, &Pressure{Pa: y}), MolarVolume{m3_per_mol: x/y});
+		assert_eq!(mul_check(&MolarEnergy{J_per_mol: x}, &InverseAbsorbedDose{per_Gy: y}), MolarMass{kgpmol: x*y});
+		assert_eq!(mul_check(&MolarEnergy{J_per_mol: x}, &InverseDoseEquivalent{per_Sv: y}), MolarMass{kgpmol: x*y});
+		assert_eq!(mul_check(&MolarEntropy{J_per_molK: x}, &Amount{mol: y}), HeatCapacity{J_per_K: x*y});
+		assert_eq!(div_check(&MolarEntropy{J_per_molK: x}, &InverseAmount{per_mol: y}), HeatCapacity{J_per_K: x/y});
+		assert_eq!(div_check(&MolarEntropy{J_per_molK: x}, &InverseTemperature{per_K: y}), MolarEnergy{J_per_mol: x/y});
+		assert_eq!(mul_check(&MolarEntropy{J_per_molK: x}, &Temperature{K: y}), MolarEnergy{J_per_mol: x*y});
+		assert_eq!(mul_check(&MolarEntropy{J_per_molK: x}, &TemperatureInterval{K: y}), MolarEnergy{J_per_mol: x*y});
+		assert_eq!(mul_check(&MolarEntropy{J_per_molK: x}, &CatalyticActivity{molps: y}), ThermalConductance{W_per_K: x*y});
+		assert_eq!(div_check(&MolarEntropy{J_per_molK: x}, &Entropy{J_per_K: y}), InverseAmount{per_mol: x/y});
+		assert_eq!(div_check(&MolarEntropy{J_per_molK: x}, &HeatCapacity{J_per_K: y}), InverseAmount{per_mol: x/y});
+		assert_eq!(div_check(&MolarEntropy{J_per_molK: x}, &InverseCatalyticActivity{s_per_mol: y}), ThermalConductance{W_per_K: x/y});
+		assert_eq!(mul_check(&MolarEntropy{J_per_molK: x}, &InverseEntropy{K_per_J: y}), InverseAmount{per_mol: x*y});
+		assert_eq!(mul_check(&MolarEntropy{J_per_molK: x}, &InverseHeatCapacity{K_per_J: y}), InverseAmount{per_mol: x*y});
+		assert_eq!(mul_check(&MolarEntropy{J_per_molK: x}, &InverseMolarEnergy{mol_per_J: y}), InverseTemperature{per_K: x*y});
+		assert_eq!(mul_check(&MolarEntropy{J_per_molK: x}, &InverseSpecificHeatCapacity{kgK_per_J: y}), MolarMass{kgpmol: x*y});
+		assert_eq!(mul_check(&MolarEntropy{J_per_molK: x}, &Molality{molpkg: y}), SpecificHeatCapacity{J_per_kgK: x*y});
+		assert_eq!(div_check(&MolarEntropy{J_per_molK: x}, &MolarEnergy{J_per_mol: y}), InverseTemperature{per_K: x/y});
+		assert_eq!(div_check(&MolarEntropy{J_per_molK: x}, &MolarMass{kgpmol: y}), SpecificHeatCapacity{J_per_kgK: x/y});
+		assert_eq!(div_check(&MolarEntropy{J_per_molK: x}, &SpecificHeatCapacity{J_per_kgK: y}), MolarMass{kgpmol: x/y});
+		assert_eq!(div_check(&MolarEntropy{J_per_molK: x}, &ThermalConductance{W_per_K: y}), InverseCatalyticActivity{s_per_mol: x/y});
+		assert_eq!(mul_check(&MolarEntropy{J_per_molK: x}, &ThermalResistance{K_per_W: y}), InverseCatalyticActivity{s_per_mol: x*y});
+		assert_eq!(mul_check(&MolarMass{kgpmol: x}, &InverseMolarEntropy{molK_per_J: y}), InverseSpecificHeatCapacity{kgK_per_J: x*y});
+		assert_eq!(div_check(&MolarMass{kgpmol: x}, &InverseSpecificHeatCapacity{kgK_per_J: y}), MolarEntropy{J_per_molK: x/y});
+		assert_eq!(div_check(&MolarMass{kgpmol: x}, &MolarEntropy{J_per_molK: y}), InverseSpecificHeatCapacity{kgK_per_J: x/y});
+		assert_eq!(mul_check(&MolarMass{kgpmol: x}, &SpecificHeatCapacity{J_per_kgK: y}), MolarEntropy{J_per_molK: x*y});
+		assert_eq!(div_check(&MolarMass{kgpmol: x}, &InverseAbsorbedDose{per_Gy: y}), MolarEnergy{J_per_mol: x/y});
+		assert_eq!(div_check(&MolarMass{kgpmol: x}, &InverseDoseEquivalent{per_Sv: y}), MolarEnergy{J_per_mol: x/y});
+		assert_eq!(mul_check(&MolarVolume{m3_per_mol: x}, &InverseMolarEnergy{mol_per_J: y}), InversePressure{per_Pa: x*y});
+		assert_eq!(div_check(&MolarVolume{m3_per_mol: x}, &MolarEnergy{J_per_mol: y}), InversePressure{per_Pa: x/y});
+		assert_eq!(div_check(&MolarVolume{m3_per_mol: x}, &InversePressure{per_Pa: y}), MolarEnergy{J_per_mol: x/y});
+		assert_eq!(mul_check(&MolarVolume{m3_per_mol: x}, &Pressure{Pa: y}), MolarEnergy{J_per_mol: x*y});
+		assert_eq!(mul_check(&SpecificHeatCapacity{J_per_kgK: x}, &InverseMolarEntropy{molK_per_J: y}), Molality{molpkg: x*y});
+		assert_eq!(div_check(&SpecificHeatCapacity{J_per_kgK: x}, &Molality{molpkg: y}), MolarEntropy{J_per_molK: x/y});
+		assert_eq!(div_check(&SpecificHeatCapacity{J_per_kgK: x}, &MolarEntropy{J_per_molK: y}), Molality{molpkg: x/y});
+		assert_eq!(mul_check(&SpecificHeatCapacity{J_per_kgK: x}, &MolarMass{kgpmol: y}), MolarEntropy{J_per_molK: x*y});
+		assert_eq!(div_check(&ThermalConductance{W_per_K: x}, &CatalyticActivity{molps: y}), MolarEntropy{J_per_molK: x/y});
+		assert_eq!(mul_check(&ThermalConductance{W_per_K: x}, &InverseCatalyticActivity{s_per_mol: y}), MolarEntropy{J_per_molK: x*y});
+		assert_eq!(mul_check(&ThermalConductance{W_per_K: x}, &InverseMolarEntropy{molK_per_J: y}), CatalyticActivity{molps: x*y});
+		assert_eq!(div_check(&ThermalConductance{W_per_K: x}, &MolarEntropy{J_per_molK: y}), CatalyticActivity{molps: x/y});
+		assert_eq!(mul_check(&ThermalResistance{K_per_W: x}, &CatalyticActivity{molps: y}), InverseMolarEntropy{molK_per_J: x*y});
+		assert_eq!(div_check(&ThermalResistance{K_per_W: x}, &InverseCatalyticActivity{s_per_mol: y}), InverseMolarEntropy{molK_per_J: x/y});
+		assert_eq!(div_check(&ThermalResistance{K_per_W: x}, &InverseMolarEntropy{molK_per_J: y}), InverseCatalyticActivity{s_per_mol: x/y});
+		assert_eq!(mul_check(&ThermalResistance{K_per_W: x}, &MolarEntropy{J_per_molK: y}), InverseCatalyticActivity{s_per_mol: x*y});
+		assert_eq!(div_check(&Energy{J: x}, &Amount{mol: y}), MolarEnergy{J_per_mol: x/y});
+		assert_eq!(mul_check(&Energy{J: x}, &InverseAmount{per_mol: y}), MolarEnergy{J_per_mol: x*y});
+		assert_eq!(mul_check(&Energy{J: x}, &InverseMolarEnergy{mol_per_J: y}), Amount{mol: x*y});
+		assert_eq!(div_check(&Energy{J: x}, &MolarEnergy{J_per_mol: y}), Amount{mol: x/y});
+		assert_eq!(mul_check(&InverseEnergy{per_J: x}, &Amount{mol: y}), InverseMolarEnergy{mol_per_J: x*y});
+		assert_eq!(div_check(&InverseEnergy{per_J: x}, &InverseAmount{per_mol: y}), InverseMolarEnergy{mol_per_J: x/y});
+		assert_eq!(div_check(&InverseEnergy{per_J: x}, &InverseMolarEnergy{mol_per_J: y}), InverseAmount{per_mol: x/y});
+		assert_eq!(mul_check(&InverseEnergy{per_J: x}, &MolarEnergy{J_per_mol: y}), InverseAmount{per_mol: x*y});
+		assert_eq!(mul_check(&InversePower{per_W: x}, &CatalyticActivity{molps: y}), InverseMolarEnergy{mol_per_J: x*y});
+		assert_eq!(div_check(&InversePower{per_W: x}, &InverseCatalyticActivity{s_per_mol: y}), InverseMolarEnergy{mol_per_J: x/y});
+		assert_eq!(div_check(&InversePower{per_W: x}, &InverseMolarEnergy{mol_per_J: y}), InverseCatalyticActivity{s_per_mol: x/y});
+		assert_eq!(mul_check(&InversePower{per_W: x}, &MolarEnergy{J_per_mol: y}), InverseCatalyticActivity{s_per_mol: x*y});
+		assert_eq!(mul_check(&InversePressure{per_Pa: x}, &Concentration{molpm3: y}), InverseMolarEnergy{mol_per_J: x*y});
+		assert_eq!(div_check(&InversePressure{per_Pa: x}, &InverseMolarEnergy{mol_per_J: y}), MolarVolume{m3_per_mol: x/y});
+		assert_eq!(mul_check(&InversePressure{per_Pa: x}, &MolarEnergy{J_per_mol: y}), MolarVolume{m3_per_mol: x*y});
+		assert_eq!(div_check(&InversePressure{per_Pa: x}, &MolarVolume{m3_per_mol: y}), InverseMolarEnergy{mol_per_J: x/y});
+		assert_eq!(mul_check(&InverseTorque{per_Nm: x}, &Amount{mol: y}), InverseMolarEnergy{mol_per_J: x*y});
+		assert_eq!(div_check(&InverseTorque{per_Nm: x}, &InverseAmount{per_mol: y}), InverseMolarEnergy{mol_per_J: x/y});
+		assert_eq!(div_check(&InverseTorque{per_Nm: x}, &InverseMolarEnergy{mol_per_J: y}), InverseAmount{per_mol: x/y});
+		assert_eq!(mul_check(&InverseTorque{per_Nm: x}, &MolarEnergy{J_per_mol: y}), InverseAmount{per_mol: x*y});
+		assert_eq!(div_check(&Power{W: x}, &CatalyticActivity{molps: y}), MolarEnergy{J_per_mol: x/y});
+		assert_eq!(mul_check(&Power{W: x}, &InverseCatalyticActivity{s_per_mol: y}), MolarEnergy{J_per_mol: x*y});
+		assert_eq!(mul_check(&Power{W: x}, &InverseMolarEnergy{mol_per_J: y}), CatalyticActivity{molps: x*y});
+		assert_eq!(div_check(&Power{W: x}, &MolarEnergy{J_per_mol: y}), CatalyticActivity{molps: x/y});
+		assert_eq!(div_check(&Pressure{Pa: x}, &Concentration{molpm3: y}), MolarEnergy{J_per_mol: x/y});
+		assert_eq!(mul_check(&Pressure{Pa: x}, &InverseMolarEnergy{mol_per_J: y}), Concentration{molpm3: x*y});
+		assert_eq!(div_check(&Pressure{Pa: x}, &MolarEnergy{J_per_mol: y}), Concentration{molpm3: x/y});
+		assert_eq!(mul_check(&Pressure{Pa: x}, &MolarVolume{m3_per_mol: y}), MolarEnergy{J_per_mol: x*y});
+		assert_eq!(div_check(&Torque{Nm: x}, &Amount{mol: y}), MolarEnergy{J_per_mol: x/y});
+		assert_eq!(mul_check(&Torque{Nm: x}, &InverseAmount{per_mol: y}), MolarEnergy{J_per_mol: x*y});
+		assert_eq!(mul_check(&Torque{Nm: x}, &InverseMolarEnergy{mol_per_J: y}), Amount{mol: x*y});
+		assert_eq!(div_check(&Torque{Nm: x}, &MolarEnergy{J_per_mol: y}), Amount{mol: x/y});
+		assert_eq!(div_check(&InverseAbsorbedDose{per_Gy: x}, &InverseMolarEnergy{mol_per_J: y}), MolarMass{kgpmol: x/y});
+		assert_eq!(mul_check(&InverseAbsorbedDose{per_Gy: x}, &Molality{molpkg: y}), InverseMolarEnergy{mol_per_J: x*y});
+		assert_eq!(mul_check(&InverseAbsorbedDose{per_Gy: x}, &MolarEnergy{J_per_mol: y}), MolarMass{kgpmol: x*y});
+		assert_eq!(div_check(&InverseAbsorbedDose{per_Gy: x}, &MolarMass{kgpmol: y}), InverseMolarEnergy{mol_per_J: x/y});
+		assert_eq!(div_check(&InverseDoseEquivalent{per_Sv: x}, &InverseMolarEnergy{mol_per_J: y}), MolarMass{kgpmol: x/y});
+		assert_eq!(mul_check(&InverseDoseEquivalent{per_Sv: x}, &Molality{molpkg: y}), InverseMolarEnergy{mol_per_J: x*y});
+		assert_eq!(mul_check(&InverseDoseEquivalent{per_Sv: x}, &MolarEnergy{J_per_mol: y}), MolarMass{kgpmol: x*y});
+		assert_eq!(div_check(&InverseDoseEquivalent{per_Sv: x}, &MolarMass{kgpmol: y}), InverseMolarEnergy{mol_per_J: x/y});
+		assert_eq!(div_check(&(x as f64), &InverseMolarEnergy{mol_per_J: y as f64}), MolarEnergy{J_per_mol: x as f64/y as f64});
+		assert_eq!(div_check(&(x as f32), &InverseMolarEnergy{mol_per_J: y as f32}), MolarEnergy{J_per_mol: x as f32/y as f32});
+		assert_eq!(div_check(&(x as i64), &InverseMolarEnergy{mol_per_J: y as i64}), MolarEnergy{J_per_mol: x as i64/y as i64});
+		assert_eq!(div_check(&(x as i32), &InverseMolarEnergy{mol_per_J: y as i32}), MolarEnergy{J_per_mol: x as i32/y as i32});
+		assert_eq!(div_check(&(x as f64), &InverseMolarEntropy{molK_per_J: y as f64}), MolarEntropy{J_per_molK: x as f64/y as f64});
+		assert_eq!(div_check(&(x as f32), &InverseMolarEntropy{molK_per_J: y as f32}), MolarEntropy{J_per_molK: x as f32/y as f32});
+		assert_eq!(div_check(&(x as i64), &InverseMolarEntropy{molK_per_J: y as i64}), MolarEntropy{J_per_molK: x as i64/y as i64});
+		assert_eq!(div_check(&(x as i32), &InverseMolarEntropy{molK_per_J: y as i32}), MolarEntropy{J_per_molK: x as i32/y as i32});
+		assert_eq!(div_check(&(x as f64), &MolarEnergy{J_per_mol: y as f64}), InverseMolarEnergy{mol_per_J: x as f64/y as f64});
+		assert_eq!(div_check(&(x as f32), &MolarEnergy{J_per_mol: y as f32}), InverseMolarEnergy{mol_per_J: x as f32/y as f32});
+		assert_eq!(div_check(&(x as i64), &MolarEnergy{J_per_mol: y as i64}), InverseMolarEnergy{mol_per_J: x as i64/y as i64});
+		assert_eq!(div_check(&(x as i32), &MolarEnergy{J_per_mol: y as i32}), InverseMolarEnergy{mol_per_J: x as i32/y as i32});
+		assert_eq!(div_check(&(x as f64), &MolarEntropy{J_per_molK: y as f64}), InverseMolarEntropy{molK_per_J: x as f64/y as f64});
+		assert_eq!(div_check(&(x as f32), &MolarEntropy{J_per_molK: y as f32}), InverseMolarEntropy{molK_per_J: x as f32/y as f32});
+		assert_eq!(div_check(&(x as i64), &MolarEntropy{J_per_molK: y as i64}), InverseMolarEntropy{molK_per_J: x as i64/y as i64});
+		assert_eq!(div_check(&(x as i32), &MolarEntropy{J_per_molK: y as i32}), InverseMolarEntropy{molK_per_J: x as i32/y as i32});
+		assert_eq!(div_check(&(x as f64), &InverseMomentOfInertia{per_kgm2: y as f64}), MomentOfInertia{kgm2: x as f64/y as f64});
+		assert_eq!(div_check(&(x as f32), &InverseMomentOfInertia{per_kgm2: y as f32}), MomentOfInertia{kgm2: x as f32/y as f32});
+		assert_eq!(div_check(&(x as i64), &InverseMomentOfInertia{per_kgm2: y as i64}), MomentOfInertia{kgm2: x as i64/y as i64});
+		assert_eq!(div_check(&(x as i32), &InverseMomentOfInertia{per_kgm2: y as i32}), MomentOfInertia{kgm2: x as i32/y as i32});
+		assert_eq!(div_check(&(x as f64), &MomentOfInertia{kgm2: y as f64}), InverseMomentOfInertia{per_kgm2: x as f64/y as f64});
+		assert_eq!(div_check(&(x as f32), &MomentOfInertia{kgm2: y as f32}), InverseMomentOfInertia{per_kgm2: x as f32/y as f32});
+		assert_eq!(div_check(&(x as i64), &MomentOfInertia{kgm2: y as i64}), InverseMomentOfInertia{per_kgm2: x as i64/y as i64});
+		assert_eq!(div_check(&(x as i32), &MomentOfInertia{kgm2: y as i32}), InverseMomentOfInertia{per_kgm2: x as i32/y as i32});
+		assert_eq!(div_check(&(x as f64), &AbsorbedDose{Gy: y as f64}), InverseAbsorbedDose{per_Gy: x as f64/y as f64});
+		assert_eq!(div_check(&(x as f32), &AbsorbedDose{Gy: y as f32}), InverseAbsorbedDose{per_Gy: x as f32/y as f32});
+		assert_eq!(div_check(&(x as i64), &AbsorbedDose{Gy: y as i64}), InverseAbsorbedDose{per_Gy: x as i64/y as i64});
+		assert_eq!(div_check(&(x as i32), &AbsorbedDose{Gy: y as i32}), InverseAbsorbedDose{per_Gy: x as i32/y as i32});
+		assert_eq!(div_check(&(x as f64), &DoseEquivalent{Sv: y as f64}), InverseDoseEquivalent{per_Sv: x as f64/y as f64});
+		assert_eq!(div_check(&(x as f32), &DoseEquivalent{Sv: y as f32}), InverseDoseEquivalent{per_Sv: x as f32/y as f32});
+		assert_eq!(div_check(&(x as i64), &DoseEquivalent{Sv: y as i64}), InverseDoseEquivalent{per_Sv: x as i64/y as i64});
+		assert_eq!(div_check(&(x as i32), &DoseEquivalent{Sv: y as i32}), InverseDoseEquivalent{per_Sv: x as i32/y as i32});
+		assert_eq!(div_check(&(x as f64), &InverseAbsorbedDose{per_Gy: y as f64}), AbsorbedDose{Gy: x as f64/y as f64});
+		assert_eq!(div_check(&(x as f32), &InverseAbsorbedDose{per_Gy: y as f32}), AbsorbedDose{Gy: x as f32/y as f32});
+		assert_eq!(div_check(&(x as i64), &InverseAbsorbedDose{per_Gy: y as i64}), AbsorbedDose{Gy: x as i64/y as i64});
+		assert_eq!(div_check(&(x as i32), &InverseAbsorbedDose{per_Gy: y as i32}), AbsorbedDose{Gy: x as i32/y as i32});
+		assert_eq!(div_check(&(x as f64), &InverseDoseEquivalent{per_Sv: y as f64}), DoseEquivalent{Sv: x as f64/y as f64});
+		assert_eq!(div_check(&(x as f32), &InverseDoseEquivalent{per_Sv: y as f32}), DoseEquivalent{Sv: x as f32/y as f32});
+		assert_eq!(div_check(&(x as i64), &InverseDoseEquivalent{per_Sv: y as i64}), DoseEquivalent{Sv: x as i64/y as i64});
+		assert_eq!(div_check(&(x as i32), &InverseDoseEquivalent{per_Sv: y as i32}), DoseEquivalent{Sv: x as i32/y as i32});
+		assert_eq!(mul_check(&Time{s: x}, &AbsorbedDoseRate{Gy_per_s: y}), AbsorbedDose{Gy: x*y});
+		assert_eq!(mul_check(&Time{s: x}, &DoseEquivalentRate{Sv_per_s: y}), DoseEquivalent{Sv: x*y});
+		assert_eq!(div_check(&AbsorbedDose{Gy: x}, &Time{s: y}), AbsorbedDoseRate{Gy_per_s: x/y});
+		assert_eq!(div_check(&AbsorbedDose{Gy: x}, &AbsorbedDoseRate{Gy_per_s: y}), Time{s: x/y});
+		assert_eq!(mul_check(&AbsorbedDoseRate{Gy_per_s: x}, &Time{s: y}), AbsorbedDose{Gy: x*y});
+		assert_eq!(div_check(&DoseEquivalent{Sv: x}, &Time{s: y}), DoseEquivalentRate{Sv_per_s: x/y});
+		assert_eq!(div_check(&DoseEquivalent{Sv: x}, &DoseEquivalentRate{Sv_per_s: y}), Time{s: x/y});
+		assert_eq!(mul_check(&DoseEquivalentRate{Sv_per_s: x}, &Time{s: y}), DoseEquivalent{Sv: x*y});
+		assert_eq!(div_check(&(x as f64), &AbsorbedDoseRate{Gy_per_s: y as f64}), InverseAbsorbedDoseRate{s_per_Gy: x as f64/y as f64});
+		assert_eq!(div_check(&(x as f32), &AbsorbedDoseRate{Gy_per_s: y as f32}), InverseAbsorbedDoseRate{s_per_Gy: x as f32/y as f32});
+		assert_eq!(div_check(&(x as i64), &AbsorbedDoseRate{Gy_per_s: y as i64}), InverseAbsorbedDoseRate{s_per_Gy: x as i64/y as i64});
+		assert_eq!(div_check(&(x as i32), &AbsorbedDoseRate{Gy_per_s: y as i32}), InverseAbsorbedDoseRate{s_per_Gy: x as i32/y as i32});
+		assert_eq!(div_check(&(x as f64), &DoseEquivalentRate{Sv_per_s: y as f64}), InverseDoseEquivalentRate{s_per_Sv: x as f64/y as f64});
+		assert_eq!(div_check(&(x as f32), &DoseEquivalentRate{Sv_per_s: y as f32}), InverseDoseEquivalentRate{s_per_Sv: x as f32/y as f32});
+		assert_eq!(div_check(&(x as i64), &DoseEquivalentRate{Sv_per_s: y as i64}), InverseDoseEquivalentRate{s_per_Sv: x as i64/y as i64});
+		assert_eq!(div_check(&(x as i32), &DoseEquivalentRate{Sv_per_s: y as i32}), InverseDoseEquivalentRate{s_per_Sv: x as i32/y as i32});
+		assert_eq!(div_check(&(x as f64), &InverseAbsorbedDoseRate{s_per_Gy: y as f64}), AbsorbedDoseRate{Gy_per_s: x as f64/y as f64});
+		assert_eq!(div_check(&(x as f32), &InverseAbsorbedDoseRate{s_per_Gy: y as f32}), AbsorbedDoseRate{Gy_per_s: x as f32/y as f32});
+		assert_eq!(div_check(&(x as i64), &InverseAbsorbedDoseRate{s_per_Gy: y as i64}), AbsorbedDoseRate{Gy_per_s: x as i64/y as i64});
+		assert_eq!(div_check(&(x as i32), &InverseAbsorbedDoseRate{s_per_Gy: y as i32}), AbsorbedDoseRate{Gy_per_s: x as i32/y as i32});
+		assert_eq!(div_check(&(x as f64), &InverseDoseEquivalentRate{s_per_Sv: y as f64}), DoseEquivalentRate{Sv_per_s: x as f64/y as f64});
+		assert_eq!(div_check(&(x as f32), &InverseDoseEquivalentRate{s_per_Sv: y as f32}), DoseEquivalentRate{Sv_per_s: x as f32/y as f32});
+		assert_eq!(div_check(&(x as i64), &InverseDoseEquivalentRate{s_per_Sv: y as i64}), DoseEquivalentRate{Sv_per_s: x as i64/y as i64});
+		assert_eq!(div_check(&(x as i32), &InverseDoseEquivalentRate{s_per_Sv: y as i32}), DoseEquivalentRate{Sv_per_s: x as i32/y as i32});
+	}
+
+	#[test]
+	fn test_bigfloat_unit_conversions() {
+		use num_bigfloat::BigFloat;
+		let x = 4.5f64;
+		let y = 2.5f64;
+		assert_eq!(div_check(
+			&BigFloat::from(x), &Time{s: BigFloat::from(y)}),
+				   Frequency{Hz: BigFloat::from(x)/BigFloat::from(y)}
+		);
+		assert_eq!(div_check(
+			&BigFloat::from(x), &Time{s: BigFloat::from(y)}),
+				   Frequency{Hz: BigFloat::from(x)/BigFloat::from(y)}
+		);
+		assert_eq!(div_check(
+			&BigFloat::from(x), &Conductance{S: BigFloat::from(y)}),
+				   Resistance{Ohm: BigFloat::from(x)/BigFloat::from(y)}
+		);
+		assert_eq!(div_check(
+			&BigFloat::from(x), &Resistance{Ohm: BigFloat::from(y)}),
+				   Conductance{S: BigFloat::from(x)/BigFloat::from(y)}
+		);
+		assert_eq!(div_check(
+			&BigFloat::from(x), &Conductance{S: BigFloat::from(y)}),
+				   Resistance{Ohm: BigFloat::from(x)/BigFloat::from(y)}
+		);
+		assert_eq!(div_check(
+			&BigFloat::from(x), &Resistance{Ohm: BigFloat::from(y)}),
+				   Conductance{S: BigFloat::from(x)/BigFloat::from(y)}
+		);
+		assert_eq!(div_check(
+			&BigFloat::from(x), &Frequency{Hz: BigFloat::from(y)}),
+				   Time{s: BigFloat::from(x)/BigFloat::from(y)}
+		);
+		assert_eq!(div_check(
+			&BigFloat::from(x), &Frequency{Hz: BigFloat::from(y)}),
+				   Time{s: BigFloat::from(x)/BigFloat::from(y)}
+		);
+		assert_eq!(div_check(
+			&BigFloat::from(x), &Radioactivity{Bq: BigFloat::from(y)}),
+				   Time{s: BigFloat::from(x)/BigFloat::from(y)}
+		);
+		assert_eq!(div_check(
+			&BigFloat::from(x), &Radioactivity{Bq: BigFloat::from(y)}),
+				   Time{s: BigFloat::from(x)/BigFloat::from(y)}
+		);
+		assert_eq!(div_check(
+			&BigFloat::from(x), &Molality{molpkg: BigFloat::from(y)}),
+				   MolarMass{kgpmol: BigFloat::from(x)/BigFloat::from(y)}
+		);
+		assert_eq!(div_check(
+			&BigFloat::from(x), &MolarMass{kgpmol: BigFloat::from(y)}),
+				   Molality{molpkg: BigFloat::from(x)/BigFloat::from(y)}
+		);
+		assert_eq!(div_check(
+			&BigFloat::from(x), &Molality{molpkg: BigFloat::from(y)}),
+				   MolarMass{kgpmol: BigFloat::from(x)/BigFloat::from(y)}
+		);
+		assert_eq!(div_check(
+			&BigFloat::from(x), &MolarMass{kgpmol: BigFloat::from(y)}),
+				   Molality{molpkg: BigFloat::from(x)/BigFloat::from(y)}
+		);
+		assert_eq!(div_check(
+			&BigFloat::from(x), &Amount{mol: BigFloat::from(y)}),
+				   InverseAmount{per_mol: BigFloat::from(x)/BigFloat::from(y)}
+		);
+		assert_eq!(div_check(
+			&BigFloat::from(x), &Current{A: BigFloat::from(y)}),
+				   InverseCurrent{per_A: BigFloat::from(x)/BigFloat::from(y)}
+		);
+		assert_eq!(div_check(
+			&BigFloat::from(x), &Distance{m: BigFloat::from(y)}),
+				   InverseDistance{per_m: BigFloat::from(x)/BigFloat::from(y)}
+		);
+		assert_eq!(div_check(
+			&BigFloat::from(x), &InverseAmount{per_mol: BigFloat::from(y)}),
+				   Amount{mol: BigFloat::from(x)/BigFloat::from(y)}
+		);
+		assert_eq!(div_check(
+			&BigFloat::from(x), &InverseCurrent{per_A: BigFloat::from(y)}),
+				   Current{A: BigFloat::from(x)/BigFloat::from(y)}
+		);
+		assert_eq!(div_check(
+			&BigFloat::from(x), &InverseDistance{per_m: BigFloat::from(y)}),
+				   Distance{m: BigFloat::from(x)/BigFloat::from(y)}
+		);
+		assert_eq!(div_check(
+			&BigFloat::from(x), &InverseLuminosity{per_cd: BigFloat::from(y)}),
+				   Luminosity{cd: BigFloat::from(x)/BigFloat::from(y)}
+		);
+		assert_eq!(div_check(
+			&BigFloat::from(x), &InverseMass{per_kg: BigFloat::from(y)}),
+				   Mass{kg: BigFloat::from(x)/BigFloat::from(y)}
+		);
+		assert_eq!(div_check(
+			&BigFloat::from(x), &InverseTemperature{per_K: BigFloat::from(y)}),
+				   Temperature{K: BigFloat::from(x)/BigFloat::from(y)}
+		);
+		assert_eq!(div_check(
+			&BigFloat::from(x), &Luminosity{cd: BigFloat::from(y)}),
+				   InverseLuminosity{per_cd: BigFloat::from(x)/BigFloat::from(y)}
+		);
+		assert_eq!(div_check(
+			&BigFloat::from(x), &Mass{kg: BigFloat::from(y)}),
+				   InverseMass{per_kg: BigFloat::from(x)/BigFloat::from(y)}
+		);
+		assert_eq!(div_check(
+			&BigFloat::from(x), &Temperature{K: BigFloat::from(y)}),
+				   InverseTemperature{per_K: BigFloat::from(x)/BigFloat::from(y)}
+		);
+		assert_eq!(div_check(
+			&BigFloat::from(x), &Amount{mol: BigFloat::from(y)}),
+				   InverseAmount{per_mol: BigFloat::from(x)/BigFloat::from(y)}
+		);
+		assert_eq!(div_check(
+			&BigFloat::from(x), &Current{A: BigFloat::from(y)}),
+				   InverseCurrent{per_A: BigFloat::from(x)/BigFloat::from(y)}
+		);
+		assert_eq!(div_check(
+			&BigFloat::from(x), &Distance{m: BigFloat::from(y)}),
+				   InverseDistance{per_m: BigFloat::from(x)/BigFloat::from(y)}
+		);
+		assert_eq!(div_check(
+			&BigFloat::from(x), &InverseAmount{per_mol: BigFloat::from(y)}),
+				   Amount{mol: BigFloat::from(x)/BigFloat::from(y)}
+		);
+		assert_eq!(div_check(
+			&BigFloat::from(x), &InverseCurrent{per_A: BigFloat::from(y)}),
+				   Current{A: BigFloat::from(x)/BigFloat::from(y)}
+		);
+		assert_eq!(div_check(
+			&BigFloat::from(x), &InverseDistance{per_m: BigFloat::from(y)}),
+				   Distance{m: BigFloat::from(x)/BigFloat::from(y)}
+		);
+		assert_eq!(div_check(
+			&BigFloat::from(x), &InverseLuminosity{per_cd: BigFloat::from(y)}),
+				   Luminosity{cd: BigFloat::from(x)/BigFloat::from(y)}
+		);
+		assert_eq!(div_check(
+			&BigFloat::from(x), &InverseMass{per_kg: BigFloat::from(y)}),
+				   Mass{kg: BigFloat::from(x)/BigFloat::from(y)}
+		);
+		assert_eq!(div_check(
+			&BigFloat::from(x), &InverseTemperature{per_K: BigFloat::from(y)}),
+				   Temperature{K: BigFloat::from(x)/BigFloat::from(y)}
+		);
+		assert_eq!(div_check(
+			&BigFloat::from(x), &Luminosity{cd: BigFloat::from(y)}),
+				   InverseLuminosity{per_cd: BigFloat::from(x)/BigFloat::from(y)}
+		);
+		assert_eq!(div_check(
+			&BigFloat::from(x), &Mass{kg: BigFloat::from(y)}),
+				   InverseMass{per_kg: BigFloat::from(x)/BigFloat::from(y)}
+		);
+		assert_eq!(div_check(
+			&BigFloat::from(x), &Temperature{K: BigFloat::from(y)}),
+				   InverseTemperature{per_K: BigFloat::from(x)/BigFloat::from(y)}
+		);
+		assert_eq!(div_check(
+			&BigFloat::from(x), &CatalyticActivity{molps: BigFloat::from(y)}),
+				   InverseCatalyticActivity{s_per_mol: BigFloat::from(x)/BigFloat::from(y)}
+		);
+		assert_eq!(div_check(
+			&BigFloat::from(x), &Concentration{molpm3: BigFloat::from(y)}),
+				   MolarVolume{m3_per_mol: BigFloat::from(x)/BigFloat::from(y)}
+		);
+		assert_eq!(div_check(
+			&BigFloat::from(x), &InverseCatalyticActivity{s_per_mol: BigFloat::from(y)}),
+				   CatalyticActivity{molps: BigFloat::from(x)/BigFloat::from(y)}
+		);
+		assert_eq!(div_check(
+			&BigFloat::from(x), &InverseSpecificHeatCapacity{kgK_per_J: BigFloat::from(y)}),
+				   SpecificHeatCapacity{J_per_kgK: BigFloat::from(x)/BigFloat::from(y)}
+		);
+		assert_eq!(div_check(
+			&BigFloat::from(x), &MolarVolume{m3_per_mol: BigFloat::from(y)}),
+				   Concentration{molpm3: BigFloat::from(x)/BigFloat::from(y)}
+		);
+		assert_eq!(div_check(
+			&BigFloat::from(x), &SpecificHeatCapacity{J_per_kgK: BigFloat::from(y)}),
+				   InverseSpecificHeatCapacity{kgK_per_J: BigFloat::from(x)/BigFloat::from(y)}
+		);
+		assert_eq!(div_check(
+			&BigFloat::from(x), &CatalyticActivity{molps: BigFloat::from(y)}),
+				   InverseCatalyticActivity{s_per_mol: BigFloat::from(x)/BigFloat::from(y)}
+		);
+		assert_eq!(div_check(
+			&BigFloat::from(x), &Concentration{molpm3: BigFloat::from(y)}),
+				   MolarVolume{m3_per_mol: BigFloat::from(x)/BigFloat::from(y)}
+		);
+		assert_eq!(div_check(
+			&BigFloat::from(x), &InverseCatalyticActivity{s_per_mol: BigFloat::from(y)}),
+				   CatalyticActivity{molps: BigFloat::from(x)/BigFloat::from(y)}
+		);
+		assert_eq!(div_check(
+			&BigFloat::from(x), &InverseSpecificHeatCapacity{kgK_per_J: BigFloat::from(y)}),
+				   SpecificHeatCapacity{J_per_kgK: BigFloat::from(x)/BigFloat::from(y)}
+		);
+		assert_eq!(div_check(
+			&BigFloat::from(x), &MolarVolume{m3_per_mol: BigFloat::from(y)}),
+				   Concentration{molpm3: BigFloat::from(x)/BigFloat::from(y)}
+		);
+		assert_eq!(div_check(
+			&BigFloat::from(x), &SpecificHeatCapacity{J_per_kgK: BigFloat::from(y)}),
+				   InverseSpecificHeatCapacity{kgK_per_J: BigFloat::from(x)/BigFloat::from(y)}
+		);
+		assert_eq!(div_check(
+			&BigFloat::from(x), &AreaPerLumen{m2_per_lm: BigFloat::from(y)}),
+				   Illuminance{lux: BigFloat::from(x)/BigFloat::from(y)}
+		);
+		assert_eq!(div_check(
+			&BigFloat::from(x), &Capacitance{F: BigFloat::from(y)}),
+				   Elastance{per_F: BigFloat::from(x)/BigFloat::from(y)}
+		);
+		assert_eq!(div_check(
+			&BigFloat::from(x), &Charge{C: BigFloat::from(y)}),
+				   InverseCharge{per_C: BigFloat::from(x)/BigFloat::from(y)}
+		);
+		assert_eq!(div_check(
+			&BigFloat::from(x), &Elastance{per_F: BigFloat::from(y)}),
+				   Capacitance{F: BigFloat::from(x)/BigFloat::from(y)}
+		);
+		assert_eq!(div_check(
+			&BigFloat::from(x), &Illuminance{lux: BigFloat::from(y)}),
+				   AreaPerLumen{m2_per_lm: BigFloat::from(x)/BigFloat::from(y)}
+		);
+		assert_eq!(div_check(
+			&BigFloat::from(x), &Inductance{H: BigFloat::from(y)}),
+				   InverseInductance{per_H: BigFloat::from(x)/BigFloat::from(y)}
+		);
+		assert_eq!(div_check(
+			&BigFloat::from(x), &InverseCharge{per_C: BigFloat::from(y)}),
+				   Charge{C: BigFloat::from(x)/BigFloat::from(y)}
+		);
+		assert_eq!(div_check(
+			&BigFloat::from(x), &InverseInductance{per_H: BigFloat::from(y)}),
+				   Inductance{H: BigFloat::from(x)/BigFloat::from(y)}
+		);
+		assert_eq!(div_check(
+			&BigFloat::from(x), &InverseLuminousFlux{per_lm: BigFloat::from(y)}),
+				   LuminousFlux{lm: BigFloat::from(x)/BigFloat::from(y)}
+		);
+		assert_eq!(div_check(
+			&BigFloat::from(x), &InverseMagneticFlux{per_Wb: BigFloat::from(y)}),
+				   MagneticFlux{Wb: BigFloat::from(x)/BigFloat::from(y)}
+		);
+		assert_eq!(div_check(
+			&BigFloat::from(x), &InverseMagneticFluxDensity{m2_per_Wb: BigFloat::from(y)}),
+				   MagneticFluxDensity{T: BigFloat::from(x)/BigFloat::from(y)}
+		);
+		assert_eq!(div_check(
+			&BigFloat::from(x), &InverseVoltage{per_V: BigFloat::from(y)}),
+				   Voltage{V: BigFloat::from(x)/BigFloat::from(y)}
+		);
+		assert_eq!(div_check(
+			&BigFloat::from(x), &LuminousFlux{lm: BigFloat::from(y)}),
+				   InverseLuminousFlux{per_lm: BigFloat::from(x)/BigFloat::from(y)}
+		);
+		assert_eq!(div_check(
+			&BigFloat::from(x), &MagneticFlux{Wb: BigFloat::from(y)}),
+				   InverseMagneticFlux{per_Wb: BigFloat::from(x)/BigFloat::from(y)}
+		);
+		assert_eq!(div_check(
+			&BigFloat::from(x), &MagneticFluxDensity{T: BigFloat::from(y)}),
+				   InverseMagneticFluxDensity{m2_per_Wb: BigFloat::from(x)/BigFloat::from(y)}
+		);
+		assert_eq!(div_check(
+			&BigFloat::from(x), &Voltage{V: BigFloat::from(y)}),
+				   InverseVoltage{per_V: BigFloat::from(x)/BigFloat::from(y)}
+		);
+		assert_eq!(div_check(
+			&BigFloat::from(x), &AreaPerLumen{m2_per_lm: BigFloat::from(y)}),
+				   Illuminance{lux: BigFloat::from(x)/BigFloat::from(y)}
+		);
+		assert_eq!(div_check(
+			&BigFloat::from(x), &Capacitance{F: BigFloat::from(y)}),
+				   Elastance{per_F: BigFloat::from(x)/BigFloat::from(y)}
+		);
+		assert_eq!(div_check(
+			&BigFloat::from(x), &Charge{C: BigFloat::from(y)}),
+				   InverseCharge{per_C: BigFloat::from(x)/BigFloat::from(y)}
+		);
+		assert_eq!(div_check(
+			&BigFloat::from(x), &Elastance{per_F: BigFloat::from(y)}),
+				   Capacitance{F: BigFloat::from(x)/BigFloat::from(y)}
+		);
+		assert_eq!(div_check(
+			&BigFloat::from(x), &Illuminance{lux: BigFloat::from(y)}),
+				   AreaPerLumen{m2_per_lm: BigFloat::from(x)/BigFloat::from(y)}
+		);
+		assert_eq!(div_check(
+			&BigFloat::from(x), &Inductance{H: BigFloat::from(y)}),
+				   InverseInductance{per_H: BigFloat::from(x)/BigFloat::from(y)}
+		);
+		assert_eq!(div_check(
+			&BigFloat::from(x), &InverseCharge{per_C: BigFloat::from(y)}),
+				   Charge{C: BigFloat::from(x)/BigFloat::from(y)}
+		);
+		assert_eq!(div_check(
+			&BigFloat::from(x), &InverseInductance{per_H: BigFloat::from(y)}),
+				   Inductance{H: BigFloat::from(x)/BigFloat::from(y)}
+		);
+		assert_eq!(div_check(
+			&BigFloat::from(x), &InverseLuminousFlux{per_lm: BigFloat::from(y)}),
+				   LuminousFlux{lm: BigFloat::from(x)/BigFloat::from(y)}
+		);
+		assert_eq!(div_check(
+			&BigFloat::from(x), &InverseMagneticFlux{per_Wb: BigFloat::from(y)}),
+				   MagneticFlux{Wb: BigFloat::from(x)/BigFloat::from(y)}
+		);
+		assert_eq!(div_check(
+			&BigFloat::from(x), &InverseMagneticFluxDensity{m2_per_Wb: BigFloat::from(y)}),
+				   MagneticFluxDensity{T: BigFloat::from(x)/BigFloat::from(y)}
+		);
+		assert_eq!(div_check(
+			&BigFloat::from(x), &InverseVoltage{per_V: BigFloat::from(y)}),
+				   Voltage{V: BigFloat::from(x)/BigFloat::from(y)}
+		);
+		assert_eq!(div_check(
+			&BigFloat::from(x), &LuminousFlux{lm: BigFloat::from(y)}),
+				   InverseLuminousFlux{per_lm: BigFloat::from(x)/BigFloat::from(y)}
+		);
+		assert_eq!(div_check(
+			&BigFloat::from(x), &MagneticFlux{Wb: BigFloat::from(y)}),
+				   InverseMagneticFlux{per_Wb: BigFloat::from(x)/BigFloat::from(y)}
+		);
+		assert_eq!(div_check(
+			&BigFloat::from(x), &MagneticFluxDensity{T: BigFloat::from(y)}),
+				   InverseMagneticFluxDensity{m2_per_Wb: BigFloat::from(x)/BigFloat::from(y)}
+		);
+		assert_eq!(div_check(
+			&BigFloat::from(x), &Voltage{V: BigFloat::from(y)}),
+				   InverseVoltage{per_V: BigFloat::from(x)/BigFloat::from(y)}
+		);
+		assert_eq!(div_check(
+			&BigFloat::from(x), &Angle{rad: BigFloat::from(y)}),
+				   InverseAngle{per_rad: BigFloat::from(x)/BigFloat::from(y)}
+		);
+		assert_eq!(div_check(
+			&BigFloat::from(x), &Area{m2: BigFloat::from(y)}),
+				   InverseArea{per_m2: BigFloat::from(x)/BigFloat::from(y)}
+		);
+		assert_eq!(div_check(
+			&BigFloat::from(x), &InverseAngle{per_rad: BigFloat::from(y)}),
+				   Angle{rad: BigFloat::from(x)/BigFloat::from(y)}
+		);
+		assert_eq!(div_check(
+			&BigFloat::from(x), &InverseArea{per_m2: BigFloat::from(y)}),
+				   Area{m2: BigFloat::from(x)/BigFloat::from(y)}
+		);
+		assert_eq!(div_check(
+			&BigFloat::from(x), &InverseSolidAngle{per_sr: BigFloat::from(y)}),
+				   SolidAngle{sr: BigFloat::from(x)/BigFloat::from(y)}
+		);
+		assert_eq!(div_check(
+			&BigFloat::from(x), &InverseVolume{per_m3: BigFloat::from(y)}),
+				   Volume{m3: BigFloat::from(x)/BigFloat::from(y)}
+		);
+		assert_eq!(div_check(
+			&BigFloat::from(x), &SolidAngle{sr: BigFloat::from(y)}),
+				   InverseSolidAngle{per_sr: BigFloat::from(x)/BigFloat::from(y)}
+		);
+		assert_eq!(div_check(
+			&BigFloat::from(x), &Volume{m3: BigFloat::from(y)}),
+				   InverseVolume{per_m3: BigFloat::from(x)/BigFloat::from(y)}
+		);
+		assert_eq!(div_check(
+			&BigFloat::from(x), &Angle{rad: BigFloat::from(y)}),
+				   InverseAngle{per_rad: BigFloat::from(x)/BigFloat::from(y)}
+		);
+		assert_eq!(div_check(
+			&BigFloat::from(x), &Area{m2: BigFloat::from(y)}),
+				   InverseArea{per_m2: BigFloat::from(x)/BigFloat::from(y)}
+		);
+		assert_eq!(div_check(
+			&BigFloat::from(x), &InverseAngle{per_rad: BigFloat::from(y)}),
+				   Angle{rad: BigFloat::from(x)/BigFloat::from(y)}
+		);
+		assert_eq!(div_check(
+			&BigFloat::from(x), &InverseArea{per_m2: BigFloat::from(y)}),
+				   Area{m2: BigFloat::from(x)/BigFloat::from(y)}
+		);
+		assert_eq!(div_check(
+			&BigFloat::from(x), &InverseSolidAngle{per_sr: BigFloat::from(y)}),
+				   SolidAngle{sr: BigFloat::from(x)/BigFloat::from(y)}
+		);
+		assert_eq!(div_check(
+			&BigFloat::from(x), &InverseVolume{per_m3: BigFloat::from(y)}),
+				   Volume{m3: BigFloat::from(x)/BigFloat::from(y)}
+		);
+		assert_eq!(div_check(
+			&BigFloat::from(x), &SolidAngle{sr: BigFloat::from(y)}),
+				   InverseSolidAngle{per_sr: BigFloat::from(x)/BigFloat::from(y)}
+		);
+		assert_eq!(div_check(
+			&BigFloat::from(x), &Volume{m3: BigFloat::from(y)}),
+				   InverseVolume{per_m3: BigFloat::from(x)/BigFloat::from(y)}
+		);
+		assert_eq!(div_check(
+			&BigFloat::from(x), &Acceleration{mps2: BigFloat::from(y)}),
+				   InverseAcceleration{s2pm: BigFloat::from(x)/BigFloat::from(y)}
+		);
+		assert_eq!(div_check(
+			&BigFloat::from(x), &AngularAcceleration{radps2: BigFloat::from(y)}),
+				   InverseAngularAcceleration{s2prad: BigFloat::from(x)/BigFloat::from(y)}
+		);
+		assert_eq!(div_check(
+			&BigFloat::from(x), &AngularMomentum{kgm2radps: BigFloat::from(y)}),
+				   InverseAngularMomentum{s_per_kgm2rad: BigFloat::from(x)/BigFloat::from(y)}
+		);
+		assert_eq!(div_check(
+			&BigFloat::from(x), &AngularVelocity{radps: BigFloat::from(y)}),
+				   InverseAngularVelocity{s_per_rad: BigFloat::from(x)/BigFloat::from(y)}
+		);
+		assert_eq!(div_check(
+			&BigFloat::from(x), &AreaDensity{kgpm2: BigFloat::from(y)}),
+				   AreaPerMass{m2_per_kg: BigFloat::from(x)/BigFloat::from(y)}
+		);
+		assert_eq!(div_check(
+			&BigFloat::from(x), &AreaPerMass{m2_per_kg: BigFloat::from(y)}),
+				   AreaDensity{kgpm2: BigFloat::from(x)/BigFloat::from(y)}
+		);
+		assert_eq!(div_check(
+			&BigFloat::from(x), &Density{kgpm3: BigFloat::from(y)}),
+				   VolumePerMass{m3_per_kg: BigFloat::from(x)/BigFloat::from(y)}
+		);
+		assert_eq!(div_check(
+			&BigFloat::from(x), &Energy{J: BigFloat::from(y)}),
+				   InverseEnergy{per_J: BigFloat::from(x)/BigFloat::from(y)}
+		);
+		assert_eq!(div_check(
+			&BigFloat::from(x), &Force{N: BigFloat::from(y)}),
+				   InverseForce{per_N: BigFloat::from(x)/BigFloat::from(y)}
+		);
+		assert_eq!(div_check(
+			&BigFloat::from(x), &InverseAcceleration{s2pm: BigFloat::from(y)}),
+				   Acceleration{mps2: BigFloat::from(x)/BigFloat::from(y)}
+		);
+		assert_eq!(div_check(
+			&BigFloat::from(x), &InverseAngularAcceleration{s2prad: BigFloat::from(y)}),
+				   AngularAcceleration{radps2: BigFloat::from(x)/BigFloat::from(y)}
+		);
+		assert_eq!(div_check(
+			&BigFloat::from(x), &InverseAngularMomentum{s_per_kgm2rad: BigFloat::from(y)}),
+				   AngularMomentum{kgm2radps: BigFloat::from(x)/BigFloat::from(y)}
+		);
+		assert_eq!(div_check(
+			&BigFloat::from(x), &InverseAngularVelocity{s_per_rad: BigFloat::from(y)}),
+				   AngularVelocity{radps: BigFloat::from(x)/BigFloat::from(y)}
+		);
+		assert_eq!(div_check(
+			&BigFloat::from(x), &InverseEnergy{per_J: BigFloat::from(y)}),
+				   Energy{J: BigFloat::from(x)/BigFloat::from(y)}
+		);
+		assert_eq!(div_check(
+			&BigFloat::from(x), &InverseForce{per_N: BigFloat::from(y)}),
+				   Force{N: BigFloat::from(x)/BigFloat::from(y)}
+		);
+		assert_eq!(div_check(
+			&BigFloat::from(x), &InverseMomentum{s_per_kgm: BigFloat::from(y)}),
+				   Momentum{kgmps: BigFloat::from(x)/BigFloat::from(y)}
+		);
+		assert_eq!(div_check(
+			&BigFloat::from(x), &InversePower{per_W: BigFloat::from(y)}),
+				   Power{W: BigFloat::from(x)/BigFloat::from(y)}
+		);
+		assert_eq!(div_check(
+			&BigFloat::from(x), &InversePressure{per_Pa: BigFloat::from(y)}),
+				   Pressure{Pa: BigFloat::from(x)/BigFloat::from(y)}
+		);
+		assert_eq!(div_check(
+			&BigFloat::from(x), &InverseTorque{per_Nm: BigFloat::from(y)}),
+				   Energy{J: BigFloat::from(x)/BigFloat::from(y)}
+		);
+		assert_eq!(div_check(
+			&BigFloat::from(x), &Momentum{kgmps: BigFloat::from(y)}),
+				   InverseMomentum{s_per_kgm: BigFloat::from(x)/BigFloat::from(y)}
+		);
+		assert_eq!(div_check(
+			&BigFloat::from(x), &Power{W: BigFloat::from(y)}),
+				   InversePower{per_W: BigFloat::from(x)/BigFloat::from(y)}
+		);
+		assert_eq!(div_check(
+			&BigFloat::from(x), &Pressure{Pa: BigFloat::from(y)}),
+				   InversePressure{per_Pa: BigFloat::from(x)/BigFloat::from(y)}
+		);
+		assert_eq!(div_check(
+			&BigFloat::from(x), &TimePerDistance{spm: BigFloat::from(y)}),
+				   Velocity{mps: BigFloat::from(x)/BigFloat::from(y)}
+		);
+		assert_eq!(div_check(
+			&BigFloat::from(x), &Torque{Nm: BigFloat::from(y)}),
+				   InverseEnergy{per_J: BigFloat::from(x)/BigFloat::from(y)}
+		);
+		assert_eq!(div_check(
+			&BigFloat::from(x), &Velocity{mps: BigFloat::from(y)}),
+				   TimePerDistance{spm: BigFloat::from(x)/BigFloat::from(y)}
+		);
+		assert_eq!(div_check(
+			&BigFloat::from(x), &VolumePerMass{m3_per_kg: BigFloat::from(y)}),
+				   Density{kgpm3: BigFloat::from(x)/BigFloat::from(y)}
+		);
+		assert_eq!(div_check(
+			&BigFloat::from(x), &Acceleration{mps2: BigFloat::from(y)}),
+				   InverseAcceleration{s2pm: BigFloat::from(x)/BigFloat::from(y)}
+		);
+		assert_eq!(div_check(
+			&BigFloat::from(x), &AngularAcceleration{radps2: BigFloat::from(y)}),
+				   InverseAngularAcceleration{s2prad: BigFloat::from(x)/BigFloat::from(y)}
+		);
+		assert_eq!(div_check(
+			&BigFloat::from(x), &AngularMomentum{kgm2radps: BigFloat::from(y)}),
+				   InverseAngularMomentum{s_per_kgm2rad: BigFloat::from(x)/BigFloat::from(y)}
+		);
+		assert_eq!(div_check(
+			&BigFloat::from(x), &AngularVelocity{radps: BigFloat::from(y)}),
+				   InverseAngularVelocity{s_per_rad: BigFloat::from(x)/BigFloat::from(y)}
+		);
+		assert_eq!(div_check(
+			&BigFloat::from(x), &AreaDensity{kgpm2: BigFloat::from(y)}),
+				   AreaPerMass{m2_per_kg: BigFloat::from(x)/BigFloat::from(y)}
+		);
+		assert_eq!(div_check(
+			&BigFloat::from(x), &AreaPerMass{m2_per_kg: BigFloat::from(y)}),
+				   AreaDensity{kgpm2: BigFloat::from(x)/BigFloat::from(y)}
+		);
+		assert_eq!(div_check(
+			&BigFloat::from(x), &Density{kgpm3: BigFloat::from(y)}),
+				   VolumePerMass{m3_per_kg: BigFloat::from(x)/BigFloat::from(y)}
+		);
+		assert_eq!(div_check(
+			&BigFloat::from(x), &Energy{J: BigFloat::from(y)}),
+				   InverseEnergy{per_J: BigFloat::from(x)/BigFloat::from(y)}
+		);
+		assert_eq!(div_check(
+			&BigFloat::from(x), &Force{N: BigFloat::from(y)}),
+				   InverseForce{per_N: BigFloat::from(x)/BigFloat::from(y)}
+		);
+		assert_eq!(div_check(
+			&BigFloat::from(x), &InverseAcceleration{s2pm: BigFloat::from(y)}),
+				   Acceleration{mps2: BigFloat::from(x)/BigFloat::from(y)}
+		);
+		assert_eq!(div_check(
+			&BigFloat::from(x), &InverseAngularAcceleration{s2prad: BigFloat::from(y)}),
+				   AngularAcceleration{radps2: BigFloat::from(x)/BigFloat::from(y)}
+		);
+		assert_eq!(div_check(
+			&BigFloat::from(x), &InverseAngularMomentum{s_per_kgm2rad: BigFloat::from(y)}),
+				   AngularMomentum{kgm2radps: BigFloat::from(x)/BigFloat::from(y)}
+		);
+		assert_eq!(div_check(
+			&BigFloat::from(x), &InverseAngularVelocity{s_per_rad: BigFloat::from(y)}),
+				   AngularVelocity{radps: BigFloat::from(x)/BigFloat::from(y)}
+		);
+		assert_eq!(div_check(
+			&BigFloat::from(x), &InverseEnergy{per_J: BigFloat::from(y)}),
+				   Energy{J: BigFloat::from(x)/BigFloat::from(y)}
+		);
+		assert_eq!(div_check(
+			&BigFloat::from(x), &InverseForce{per_N: BigFloat::from(y)}),
+				   Force{N: BigFloat::from(x)/BigFloat::from(y)}
+		);
+		assert_eq!(div_check(
+			&BigFloat::from(x), &InverseMomentum{s_per_kgm: BigFloat::from(y)}),
+				   Momentum{kgmps: BigFloat::from(x)/BigFloat::from(y)}
+		);
+		assert_eq!(div_check(
+			&BigFloat::from(x), &InversePower{per_W: BigFloat::from(y)}),
+				   Power{W: BigFloat::from(x)/BigFloat::from(y)}
+		);
+		assert_eq!(div_check(
+			&BigFloat::from(x), &InversePressure{per_Pa: BigFloat::from(y)}),
+				   Pressure{Pa: BigFloat::from(x)/BigFloat::from(y)}
+		);
+		assert_eq!(div_check(
+			&BigFloat::from(x), &InverseTorque{per_Nm: BigFloat::from(y)}),
+				   Energy{J: BigFloat::from(x)/BigFloat::from(y)}
+		);
+		assert_eq!(div_check(
+			&BigFloat::from(x), &Momentum{kgmps: BigFloat::from(y)}),
+				   InverseMomentum{s_per_kgm: BigFloat::from(x)/BigFloat::from(y)}
+		);
+		assert_eq!(div_check(
+			&BigFloat::from(x), &Power{W: BigFloat::from(y)}),
+				   InversePower{per_W: BigFloat::from(x)/BigFloat::from(y)}
+		);
+		assert_eq!(div_check(
+			&BigFloat::from(x), &Pressure{Pa: BigFloat::from(y)}),
+				   InversePressure{per_Pa: BigFloat::from(x)/BigFloat::from(y)}
+		);
+		assert_eq!(div_check(
+			&BigFloat::from(x), &TimePerDistance{spm: BigFloat::from(y)}),
+				   Velocity{mps: BigFloat::from(x)/BigFloat::from(y)}
+		);
+		assert_eq!(div_check(
+			&BigFloat::from(x), &Torque{Nm: BigFloat::from(y)}),
+				   InverseEnergy{per_J: BigFloat::from(x)/BigFloat::from(y)}
+		);
+		assert_eq!(div_check(
+			&BigFloat::from(x), &Velocity{mps: BigFloat::from(y)}),
+				   TimePerDistance{spm: BigFloat::from(x)/BigFloat::from(y)}
+		);
+		assert_eq!(div_check(
+			&BigFloat::from(x), &VolumePerMass{m3_per_kg: BigFloat::from(y)}),
+				   Density{kgpm3: BigFloat::from(x)/BigFloat::from(y)}
+		);
+		assert_eq!(div_check(
+			&BigFloat::from(x), &TemperatureInterval{K: BigFloat::from(y)}),
+				   InverseTemperature{per_K: BigFloat::from(x)/BigFloat::from(y)}
+		);
+		assert_eq!(div_check(
+			&BigFloat::from(x), &InverseJerk{s3pm: BigFloat::from(y)}),
+				   Jerk{mps3: BigFloat::from(x)/BigFloat::from(y)}
+		);
+		assert_eq!(div_check(
+			&BigFloat::from(x), &Jerk{mps3: BigFloat::from(y)}),
+				   InverseJerk{s3pm: BigFloat::from(x)/BigFloat::from(y)}
+		);
+		assert_eq!(div_check(
+			&BigFloat::from(x), &DynamicViscosity{Pas: BigFloat::from(y)}),
+				   InverseDynamicViscosity{per_Pas: BigFloat::from(x)/BigFloat::from(y)}
+		);
+		assert_eq!(div_check(
+			&BigFloat::from(x), &InverseDynamicViscosity{per_Pas: BigFloat::from(y)}),
+				   DynamicViscosity{Pas: BigFloat::from(x)/BigFloat::from(y)}
+		);
+		assert_eq!(div_check(
+			&BigFloat::from(x), &InverseKinematicViscosity{s_per_m2: BigFloat::from(y)}),
+				   KinematicViscosity{m2ps: BigFloat::from(x)/BigFloat::from(y)}
+		);
+		assert_eq!(div_check(
+			&BigFloat::from(x), &KinematicViscosity{m2ps: BigFloat::from(y)}),
+				   InverseKinematicViscosity{s_per_m2: BigFloat::from(x)/BigFloat::from(y)}
+		);
+		assert_eq!(div_check(
+			&BigFloat::from(x), &InverseMassFlowRate{s_per_kg: BigFloat::from(y)}),
+				   MassFlowRate{kgps: BigFloat::from(x)/BigFloat::from(y)}
+		);
+		assert_eq!(div_check(
+			&BigFloat::from(x), &InverseVolumetricFlowRate{s_per_m3: BigFloat::from(y)}),
+				   VolumetricFlowRate{m3ps: BigFloat::from(x)/BigFloat::from(y)}
+		);
+		assert_eq!(div_check(
+			&BigFloat::from(x), &MassFlowRate{kgps: BigFloat::from(y)}),
+				   InverseMassFlowRate{s_per_kg: BigFloat::from(x)/BigFloat::from(y)}
+		);
+		assert_eq!(div_check(
+			&BigFloat::from(x), &VolumetricFlowRate{m3ps: BigFloat::from(y)}),
+				   InverseVolumetricFlowRate{s_per_m3: BigFloat::from(x)/BigFloat::from(y)}
+		);
+		assert_eq!(div_check(
+			&BigFloat::from(x), &Entropy{J_per_K: BigFloat::from(y)}),
+				   InverseHeatCapacity{K_per_J: BigFloat::from(x)/BigFloat::from(y)}
+		);
+		assert_eq!(div_check(
+			&BigFloat::from(x), &HeatCapacity{J_per_K: BigFloat::from(y)}),
+				   InverseHeatCapacity{K_per_J: BigFloat::from(x)/BigFloat::from(y)}
+		);
+		assert_eq!(div_check(
+			&BigFloat::from(x), &InverseEntropy{K_per_J: BigFloat::from(y)}),
+				   HeatCapacity{J_per_K: BigFloat::from(x)/BigFloat::from(y)}
+		);
+		assert_eq!(div_check(
+			&BigFloat::from(x), &InverseHeatCapacity{K_per_J: BigFloat::from(y)}),
+				   HeatCapacity{J_per_K: BigFloat::from(x)/BigFloat::from(y)}
+		);
+		assert_eq!(div_check(
+			&BigFloat::from(x), &ThermalConductance{W_per_K: BigFloat::from(y)}),
+				   ThermalResistance{K_per_W: BigFloat::from(x)/BigFloat::from(y)}
+		);
+		assert_eq!(div_check(
+			&BigFloat::from(x), &ThermalConductivity{W_per_mK: BigFloat::from(y)}),
+				   ThermalResistivity{mK_per_W: BigFloat::from(x)/BigFloat::from(y)}
+		);
+		assert_eq!(div_check(
+			&BigFloat::from(x), &ThermalResistance{K_per_W: BigFloat::from(y)}),
+				   ThermalConductance{W_per_K: BigFloat::from(x)/BigFloat::from(y)}
+		);
+		assert_eq!(div_check(
+			&BigFloat::from(x), &ThermalResistivity{mK_per_W: BigFloat::from(y)}),
+				   ThermalConductivity{W_per_mK: BigFloat::from(x)/BigFloat::from(y)}
+		);
+		assert_eq!(div_check(
+			&BigFloat::from(x), &ElectricField{Vpm: BigFloat::from(y)}),
+				   InverseElectricField{m_per_V: BigFloat::from(x)/BigFloat::from(y)}
+		);
+		assert_eq!(div_check(
+			&BigFloat::from(x), &InverseElectricField{m_per_V: BigFloat::from(y)}),
+				   ElectricField{Vpm: BigFloat::from(x)/BigFloat::from(y)}
+		);
+		assert_eq!(div_check(
+			&BigFloat::from(x), &InverseMagneticFieldStrength{m_per_A: BigFloat::from(y)}),
+				   MagneticFieldStrength{Apm: BigFloat::from(x)/BigFloat::from(y)}
+		);
+		assert_eq!(div_check(
+			&BigFloat::from(x), &MagneticFieldStrength{Apm: BigFloat::from(y)}),
+				   InverseMagneticFieldStrength{m_per_A: BigFloat::from(x)/BigFloat::from(y)}
+		);
+		assert_eq!(div_check(
+			&BigFloat::from(x), &InverseLinearChargeDensity{m_per_C: BigFloat::from(y)}),
+				   LinearChargeDensity{Cpm: BigFloat::from(x)/BigFloat::from(y)}
+		);
+		assert_eq!(div_check(
+			&BigFloat::from(x), &InverseSurfaceChargeDensity{m2_per_C: BigFloat::from(y)}),
+				   SurfaceChargeDensity{Cpm2: BigFloat::from(x)/BigFloat::from(y)}
+		);
+		assert_eq!(div_check(
+			&BigFloat::from(x), &InverseVolumeChargeDensity{m3_per_C: BigFloat::from(y)}),
+				   VolumeChargeDensity{Cpm3: BigFloat::from(x)/BigFloat::from(y)}
+		);
+		assert_eq!(div_check(
+			&BigFloat::from(x), &LinearChargeDensity{Cpm: BigFloat::from(y)}),
+				   InverseLinearChargeDensity{m_per_C: BigFloat::from(x)/BigFloat::from(y)}
+		);
+		assert_eq!(div_check(
+			&BigFloat::from(x), &SurfaceChargeDensity{Cpm2: BigFloat::from(y)}),
+				   InverseSurfaceChargeDensity{m2_per_C: BigFloat::from(x)/BigFloat::from(y)}
+		);
+		assert_eq!(div_check(
+			&BigFloat::from(x), &VolumeChargeDensity{Cpm3: BigFloat::from(y)}),
+				   InverseVolumeChargeDensity{m3_per_C: BigFloat::from(x)/BigFloat::from(y)}
+		);
+		assert_eq!(div_check(
+			&BigFloat::from(x), &InverseIrradiance{m2_per_W: BigFloat::from(y)}),
+				   Irradiance{W_per_m2: BigFloat::from(x)/BigFloat::from(y)}
+		);
+		assert_eq!(div_check(
+			&BigFloat::from(x), &InverseRadiance{m2sr_per_W: BigFloat::from(y)}),
+				   Radiance{W_per_m2sr: BigFloat::from(x)/BigFloat::from(y)}
+		);
+		assert_eq!(div_check(
+			&BigFloat::from(x), &Irradiance{W_per_m2: BigFloat::from(y)}),
+				   InverseIrradiance{m2_per_W: BigFloat::from(x)/BigFloat::from(y)}
+		);
+		assert_eq!(div_check(
+			&BigFloat::from(x), &Radiance{W_per_m2sr: BigFloat::from(y)}),
+				   InverseRadiance{m2sr_per_W: BigFloat::from(x)/BigFloat::from(y)}
+		);
+		assert_eq!(div_check(
+			&BigFloat::from(x), &InverseMolarEnergy{mol_per_J: BigFloat::from(y)}),
+				   MolarEnergy{J_per_mol: BigFloat::from(x)/BigFloat::from(y)}
+		);
+		assert_eq!(div_check(
+			&BigFloat::from(x), &InverseMolarEntropy{molK_per_J: BigFloat::from(y)}),
+				   MolarEntropy{J_per_molK: BigFloat::from(x)/BigFloat::from(y)}
+		);
+		assert_eq!(div_check(
+			&BigFloat::from(x), &MolarEnergy{J_per_mol: BigFloat::from(y)}),
+				   InverseMolarEnergy{mol_per_J: BigFloat::from(x)/BigFloat::from(y)}
+		);
+		assert_eq!(div_check(
+			&BigFloat::from(x), &MolarEntropy{J_per_molK: BigFloat::from(y)}),
+				   InverseMolarEntropy{molK_per_J: BigFloat::from(x)/BigFloat::from(y)}
+		);
+		assert_eq!(div_check(
+			&BigFloat::from(x), &InverseMomentOfInertia{per_kgm2: BigFloat::from(y)}),
+				   MomentOfInertia{kgm2: BigFloat::from(x)/BigFloat::from(y)}
+		);
+		assert_eq!(div_check(
+			&BigFloat::from(x), &MomentOfInertia{kgm2: BigFloat::from(y)}),
+				   InverseMomentOfInertia{per_kgm2: BigFloat::from(x)/BigFloat::from(y)}
+		);
+		assert_eq!(div_check(
+			&BigFloat::from(x), &AbsorbedDose{Gy: BigFloat::from(y)}),
+				   InverseAbsorbedDose{per_Gy: BigFloat::from(x)/BigFloat::from(y)}
+		);
+		assert_eq!(div_check(
+			&BigFloat::from(x), &DoseEquivalent{Sv: BigFloat::from(y)}),
+				   InverseDoseEquivalent{per_Sv: BigFloat::from(x)/BigFloat::from(y)}
+		);
+		assert_eq!(div_check(
+			&BigFloat::from(x), &InverseAbsorbedDose{per_Gy: BigFloat::from(y)}),
+				   AbsorbedDose{Gy: BigFloat::from(x)/BigFloat::from(y)}
+		);
+		assert_eq!(div_check(
+			&BigFloat::from(x), &InverseDoseEquivalent{per_Sv: BigFloat::from(y)}),
+				   DoseEquivalent{Sv: BigFloat::from(x)/BigFloat::from(y)}
+		);
+		assert_eq!(div_check(
+			&BigFloat::from(x), &AbsorbedDoseRate{Gy_per_s: BigFloat::from(y)}),
+				   InverseAbsorbedDoseRate{s_per_Gy: BigFloat::from(x)/BigFloat::from(y)}
+		);
+		assert_eq!(div_check(
+			&BigFloat::from(x), &DoseEquivalentRate{Sv_per_s: BigFloat::from(y)}),
+				   InverseDoseEquivalentRate{s_per_Sv: BigFloat::from(x)/BigFloat::from(y)}
+		);
+		assert_eq!(div_check(
+			&BigFloat::from(x), &InverseAbsorbedDoseRate{s_per_Gy: BigFloat::from(y)}),
+				   AbsorbedDoseRate{Gy_per_s: BigFloat::from(x)/BigFloat::from(y)}
+		);
+		assert_eq!(div_check(
+			&BigFloat::from(x), &InverseDoseEquivalentRate{s_per_Sv: BigFloat::from(y)}),
+				   DoseEquivalentRate{Sv_per_s: BigFloat::from(x)/BigFloat::from(y)}
+		);
 	}
 
 	#[test]
-	fn test_bigfloat_unit_conversions() {
-		use num_bigfloat::BigFloat;
+	fn test_complex_unit_conversions() {
+		use num_complex::{Complex32, Complex64};
 		let x = 4.5f64;
 		let y = 2.5f64;
 		assert_eq!(div_check(
-			&BigFloat::from(x), &Time{s: BigFloat::from(y)}),
-				   Frequency{Hz: BigFloat::from(x)/BigFloat::from(y)}
+			&Complex32::from(x as f32), &Time{s: Complex32::from(y as f32)}),
+				   Frequency{Hz: Complex32::from(x as f32)/Complex32::from(y as f32)}
+		);
+		assert_eq!(div_check(
+			&Complex64::from(x), &Time{s: Complex64::from(y)}),
+				   Frequency{Hz: Complex64::from(x)/Complex64::from(y)}
+		);
+		assert_eq!(div_check(
+			&Complex32::from(x as f32), &Time{s: Complex32::from(y as f32)}),
+				   Frequency{Hz: Complex32::from(x as f32)/Complex32::from(y as f32)}
+		);
+		assert_eq!(div_check(
+			&Complex64::from(x), &Time{s: Complex64::from(y)}),
+				   Frequency{Hz: Complex64::from(x)/Complex64::from(y)}
+		);
+		assert_eq!(div_check(
+			&Complex32::from(x as f32), &Conductance{S: Complex32::from(y as f32)}),
+				   Resistance{Ohm: Complex32::from(x as f32)/Complex32::from(y as f32)}
+		);
+		assert_eq!(div_check(
+			&Complex64::from(x), &Conductance{S: Complex64::from(y)}),
+				   Resistance{Ohm: Complex64::from(x)/Complex64::from(y)}
+		);
+		assert_eq!(div_check(
+			&Complex32::from(x as f32), &Resistance{Ohm: Complex32::from(y as f32)}),
+				   Conductance{S: Complex32::from(x as f32)/Complex32::from(y as f32)}
+		);
+		assert_eq!(div_check(
+			&Complex64::from(x), &Resistance{Ohm: Complex64::from(y)}),
+				   Conductance{S: Complex64::from(x)/Complex64::from(y)}
+		);
+		assert_eq!(div_check(
+			&Complex32::from(x as f32), &Conductance{S: Complex32::from(y as f32)}),
+				   Resistance{Ohm: Complex32::from(x as f32)/Complex32::from(y as f32)}
+		);
+		assert_eq!(div_check(
+			&Complex64::from(x), &Conductance{S: Complex64::from(y)}),
+				   Resistance{Ohm: Complex64::from(x)/Complex64::from(y)}
+		);
+		assert_eq!(div_check(
+			&Complex32::from(x as f32), &Resistance{Ohm: Complex32::from(y as f32)}),
+				   Conductance{S: Complex32::from(x as f32)/Complex32::from(y as f32)}
+		);
+		assert_eq!(div_check(
+			&Complex64::from(x), &Resistance{Ohm: Complex64::from(y)}),
+				   Conductance{S: Complex64::from(x)/Complex64::from(y)}
+		);
+		assert_eq!(div_check(
+			&Complex32::from(x as f32), &Frequency{Hz: Complex32::from(y as f32)}),
+				   Time{s: Complex32::from(x as f32)/Complex32::from(y as f32)}
+		);
+		assert_eq!(div_check(
+			&Complex64::from(x), &Frequency{Hz: Complex64::from(y)}),
+				   Time{s: Complex64::from(x)/Complex64::from(y)}
+		);
+		assert_eq!(div_check(
+			&Complex32::from(x as f32), &Frequency{Hz: Complex32::from(y as f32)}),
+				   Time{s: Complex32::from(x as f32)/Complex32::from(y as f32)}
+		);
+		assert_eq!(div_check(
+			&Complex64::from(x), &Frequency{Hz: Complex64::from(y)}),
+				   Time{s: Complex64::from(x)/Complex64::from(y)}
+		);
+		assert_eq!(div_check(
+			&Complex32::from(x as f32), &Radioactivity{Bq: Complex32::from(y as f32)}),
+				   Time{s: Complex32::from(x as f32)/Complex32::from(y as f32)}
+		);
+		assert_eq!(div_check(
+			&Complex64::from(x), &Radioactivity{Bq: Complex64::from(y)}),
+				   Time{s: Complex64::from(x)/Complex64::from(y)}
+		);
+		assert_eq!(div_check(
+			&Complex32::from(x as f32), &Radioactivity{Bq: Complex32::from(y as f32)}),
+				   Time{s: Complex32::from(x as f32)/Complex32::from(y as f32)}
+		);
+		assert_eq!(div_check(
+			&Complex64::from(x), &Radioactivity{Bq: Complex64::from(y)}),
+				   Time{s: Complex64::from(x)/Complex64::from(y)}
+		);
+		assert_eq!(div_check(
+			&Complex32::from(x as f32), &Molality{molpkg: Complex32::from(y as f32)}),
+				   MolarMass{kgpmol: Complex32::from(x as f32)/Complex32::from(y as f32)}
+		);
+		assert_eq!(div_check(
+			&Complex64::from(x), &Molality{molpkg: Complex64::from(y)}),
+				   MolarMass{kgpmol: Complex64::from(x)/Complex64::from(y)}
+		);
+		assert_eq!(div_check(
+			&Complex32::from(x as f32), &MolarMass{kgpmol: Complex32::from(y as f32)}),
+				   Molality{molpkg: Complex32::from(x as f32)/Complex32::from(y as f32)}
+		);
+		assert_eq!(div_check(
+			&Complex64::from(x), &MolarMass{kgpmol: Complex64::from(y)}),
+				   Molality{molpkg: Complex64::from(x)/Complex64::from(y)}
+		);
+		assert_eq!(div_check(
+			&Complex32::from(x as f32), &Molality{molpkg: Complex32::from(y as f32)}),
+				   MolarMass{kgpmol: Complex32::from(x as f32)/Complex32::from(y as f32)}
+		);
+		assert_eq!(div_check(
+			&Complex64::from(x), &Molality{molpkg: Complex64::from(y)}),
+				   MolarMass{kgpmol: Complex64::from(x)/Complex64::from(y)}
+		);
+		assert_eq!(div_check(
+			&Complex32::from(x as f32), &MolarMass{kgpmol: Complex32::from(y as f32)}),
+				   Molality{molpkg: Complex32::from(x as f32)/Complex32::from(y as f32)}
+		);
+		assert_eq!(div_check(
+			&Complex64::from(x), &MolarMass{kgpmol: Complex64::from(y)}),
+				   Molality{molpkg: Complex64::from(x)/Complex64::from(y)}
+		);
+		assert_eq!(div_check(
+			&Complex32::from(x as f32), &Amount{mol: Complex32::from(y as f32)}),
+				   InverseAmount{per_mol: Complex32::from(x as f32)/Complex32::from(y as f32)}
+		);
+		assert_eq!(div_check(
+			&Complex64::from(x), &Amount{mol: Complex64::from(y)}),
+				   InverseAmount{per_mol: Complex64::from(x)/Complex64::from(y)}
+		);
+		assert_eq!(div_check(
+			&Complex32::from(x as f32), &Current{A: Complex32::from(y as f32)}),
+				   InverseCurrent{per_A: Complex32::from(x as f32)/Complex32::from(y as f32)}
+		);
+		assert_eq!(div_check(
+			&Complex64::from(x), &Current{A: Complex64::from(y)}),
+				   InverseCurrent{per_A: Complex64::from(x)/Complex64::from(y)}
+		);
+		assert_eq!(div_check(
+			&Complex32::from(x as f32), &Distance{m: Complex32::from(y as f32)}),
+				   InverseDistance{per_m: Complex32::from(x as f32)/Complex32::from(y as f32)}
+		);
+		assert_eq!(div_check(
+			&Complex64::from(x), &Distance{m: Complex64::from(y)}),
+				   InverseDistance{per_m: Complex64::from(x)/Complex64::from(y)}
+		);
+		assert_eq!(div_check(
+			&Complex32::from(x as f32), &InverseAmount{per_mol: Complex32::from(y as f32)}),
+				   Amount{mol: Complex32::from(x as f32)/Complex32::from(y as f32)}
+		);
+		assert_eq!(div_check(
+			&Complex64::from(x), &InverseAmount{per_mol: Complex64::from(y)}),
+				   Amount{mol: Complex64::from(x)/Complex64::from(y)}
+		);
+		assert_eq!(div_check(
+			&Complex32::from(x as f32), &InverseCurrent{per_A: Complex32::from(y as f32)}),
+				   Current{A: Complex32::from(x as f32)/Complex32::from(y as f32)}
+		);
+		assert_eq!(div_check(
+			&Complex64::from(x), &InverseCurrent{per_A: Complex64::from(y)}),
+				   Current{A: Complex64::from(x)/Complex64::from(y)}
+		);
+		assert_eq!(div_check(
+			&Complex32::from(x as f32), &InverseDistance{per_m: Complex32::from(y as f32)}),
+				   Distance{m: Complex32::from(x as f32)/Complex32::from(y as f32)}
+		);
+		assert_eq!(div_check(
+			&Complex64::from(x), &InverseDistance{per_m: Complex64::from(y)}),
+				   Distance{m: Complex64::from(x)/Complex64::from(y)}
+		);
+		assert_eq!(div_check(
+			&Complex32::from(x as f32), &InverseLuminosity{per_cd: Complex32::from(y as f32)}),
+				   Luminosity{cd: Complex32::from(x as f32)/Complex32::from(y as f32)}
+		);
+		assert_eq!(div_check(
+			&Complex64::from(x), &InverseLuminosity{per_cd: Complex64::from(y)}),
+				   Luminosity{cd: Complex64::from(x)/Complex64::from(y)}
+		);
+		assert_eq!(div_check(
+			&Complex32::from(x as f32), &InverseMass{per_kg: Complex32::from(y as f32)}),
+				   Mass{kg: Complex32::from(x as f32)/Complex32::from(y as f32)}
+		);
+		assert_eq!(div_check(
+			&Complex64::from(x), &InverseMass{per_kg: Complex64::from(y)}),
+				   Mass{kg: Complex64::from(x)/Complex64::from(y)}
+		);
+		assert_eq!(div_check(
+			&Complex32::from(x as f32), &InverseTemperature{per_K: Complex32::from(y as f32)}),
+				   Temperature{K: Complex32::from(x as f32)/Complex32::from(y as f32)}
+		);
+		assert_eq!(div_check(
+			&Complex64::from(x), &InverseTemperature{per_K: Complex64::from(y)}),
+				   Temperature{K: Complex64::from(x)/Complex64::from(y)}
+		);
+		assert_eq!(div_check(
+			&Complex32::from(x as f32), &Luminosity{cd: Complex32::from(y as f32)}),
+				   InverseLuminosity{per_cd: Complex32::from(x as f32)/Complex32::from(y as f32)}
+		);
+		assert_eq!(div_check(
+			&Complex64::from(x), &Luminosity{cd: Complex64::from(y)}),
+				   InverseLuminosity{per_cd: Complex64::from(x)/Complex64::from(y)}
+		);
+		assert_eq!(div_check(
+			&Complex32::from(x as f32), &Mass{kg: Complex32::from(y as f32)}),
+				   InverseMass{per_kg: Complex32::from(x as f32)/Complex32::from(y as f32)}
+		);
+		assert_eq!(div_check(
+			&Complex64::from(x), &Mass{kg: Complex64::from(y)}),
+				   InverseMass{per_kg: Complex64::from(x)/Complex64::from(y)}
+		);
+		assert_eq!(div_check(
+			&Complex32::from(x as f32), &Temperature{K: Complex32::from(y as f32)}),
+				   InverseTemperature{per_K: Complex32::from(x as f32)/Complex32::from(y as f32)}
+		);
+		assert_eq!(div_check(
+			&Complex64::from(x), &Temperature{K: Complex64::from(y)}),
+				   InverseTemperature{per_K: Complex64::from(x)/Complex64::from(y)}
+		);
+		assert_eq!(div_check(
+			&Complex32::from(x as f32), &Amount{mol: Complex32::from(y as f32)}),
+				   InverseAmount{per_mol: Complex32::from(x as f32)/Complex32::from(y as f32)}
+		);
+		assert_eq!(div_check(
+			&Complex64::from(x), &Amount{mol: Complex64::from(y)}),
+				   InverseAmount{per_mol: Complex64::from(x)/Complex64::from(y)}
+		);
+		assert_eq!(div_check(
+			&Complex32::from(x as f32), &Current{A: Complex32::from(y as f32)}),
+				   InverseCurrent{per_A: Complex32::from(x as f32)/Complex32::from(y as f32)}
+		);
+		assert_eq!(div_check(
+			&Complex64::from(x), &Current{A: Complex64::from(y)}),
+				   InverseCurrent{per_A: Complex64::from(x)/Complex64::from(y)}
+		);
+		assert_eq!(div_check(
+			&Complex32::from(x as f32), &Distance{m: Complex32::from(y as f32)}),
+				   InverseDistance{per_m: Complex32::from(x as f32)/Complex32::from(y as f32)}
+		);
+		assert_eq!(div_check(
+			&Complex64::from(x), &Distance{m: Complex64::from(y)}),
+				   InverseDistance{per_m: Complex64::from(x)/Complex64::from(y)}
+		);
+		assert_eq!(div_check(
+			&Complex32::from(x as f32), &InverseAmount{per_mol: Complex32::from(y as f32)}),
+				   Amount{mol: Complex32::from(x as f32)/Complex32::from(y as f32)}
+		);
+		assert_eq!(div_check(
+			&Complex64::from(x), &InverseAmount{per_mol: Complex64::from(y)}),
+				   Amount{mol: Complex64::from(x)/Complex64::from(y)}
+		);
+		assert_eq!(div_check(
+			&Complex32::from(x as f32), &InverseCurrent{per_A: Complex32::from(y as f32)}),
+				   Current{A: Complex32::from(x as f32)/Complex32::from(y as f32)}
+		);
+		assert_eq!(div_check(
+			&Complex64::from(x), &InverseCurrent{per_A: Complex64::from(y)}),
+				   Current{A: Complex64::from(x)/Complex64::from(y)}
+		);
+		assert_eq!(div_check(
+			&Complex32::from(x as f32), &InverseDistance{per_m: Complex32::from(y as f32)}),
+				   Distance{m: Complex32::from(x as f32)/Complex32::from(y as f32)}
+		);
+		assert_eq!(div_check(
+			&Complex64::from(x), &InverseDistance{per_m: Complex64::from(y)}),
+				   Distance{m: Complex64::from(x)/Complex64::from(y)}
+		);
+		assert_eq!(div_check(
+			&Complex32::from(x as f32), &InverseLuminosity{per_cd: Complex32::from(y as f32)}),
+				   Luminosity{cd: Complex32::from(x as f32)/Complex32::from(y as f32)}
+		);
+		assert_eq!(div_check(
+			&Complex64::from(x), &InverseLuminosity{per_cd: Complex64::from(y)}),
+				   Luminosity{cd: Complex64::from(x)/Complex64::from(y)}
+		);
+		assert_eq!(div_check(
+			&Complex32::from(x as f32), &InverseMass{per_kg: Complex32::from(y as f32)}),
+				   Mass{kg: Complex32::from(x as f32)/Complex32::from(y as f32)}
+		);
+		assert_eq!(div_check(
+			&Complex64::from(x), &InverseMass{per_kg: Complex64::from(y)}),
+				   Mass{kg: Complex64::from(x)/Complex64::from(y)}
+		);
+		assert_eq!(div_check(
+			&Complex32::from(x as f32), &InverseTemperature{per_K: Complex32::from(y as f32)}),
+				   Temperature{K: Complex32::from(x as f32)/Complex32::from(y as f32)}
+		);
+		assert_eq!(div_check(
+			&Complex64::from(x), &InverseTemperature{per_K: Complex64::from(y)}),
+				   Temperature{K: Complex64::from(x)/Complex64::from(y)}
+		);
+		assert_eq!(div_check(
+			&Complex32::from(x as f32), &Luminosity{cd: Complex32::from(y as f32)}),
+				   InverseLuminosity{per_cd: Complex32::from(x as f32)/Complex32::from(y as f32)}
+		);
+		assert_eq!(div_check(
+			&Complex64::from(x), &Luminosity{cd: Complex64::from(y)}),
+				   InverseLuminosity{per_cd: Complex64::from(x)/Complex64::from(y)}
+		);
+		assert_eq!(div_check(
+			&Complex32::from(x as f32), &Mass{kg: Complex32::from(y as f32)}),
+				   InverseMass{per_kg: Complex32::from(x as f32)/Complex32::from(y as f32)}
+		);
+		assert_eq!(div_check(
+			&Complex64::from(x), &Mass{kg: Complex64::from(y)}),
+				   InverseMass{per_kg: Complex64::from(x)/Complex64::from(y)}
+		);
+		assert_eq!(div_check(
+			&Complex32::from(x as f32), &Temperature{K: Complex32::from(y as f32)}),
+				   InverseTemperature{per_K: Complex32::from(x as f32)/Complex32::from(y as f32)}
+		);
+		assert_eq!(div_check(
+			&Complex64::from(x), &Temperature{K: Complex64::from(y)}),
+				   InverseTemperature{per_K: Complex64::from(x)/Complex64::from(y)}
+		);
+		assert_eq!(div_check(
+			&Complex32::from(x as f32), &CatalyticActivity{molps: Complex32::from(y as f32)}),
+				   InverseCatalyticActivity{s_per_mol: Complex32::from(x as f32)/Complex32::from(y as f32)}
+		);
+		assert_eq!(div_check(
+			&Complex64::from(x), &CatalyticActivity{molps: Complex64::from(y)}),
+				   InverseCatalyticActivity{s_per_mol: Complex64::from(x)/Complex64::from(y)}
+		);
+		assert_eq!(div_check(
+			&Complex32::from(x as f32), &Concentration{molpm3: Complex32::from(y as f32)}),
+				   MolarVolume{m3_per_mol: Complex32::from(x as f32)/Complex32::from(y as f32)}
+		);
+		assert_eq!(div_check(
+			&Complex64::from(x), &Concentration{molpm3: Complex64::from(y)}),
+				   MolarVolume{m3_per_mol: Complex64::from(x)/Complex64::from(y)}
+		);
+		assert_eq!(div_check(
+			&Complex32::from(x as f32), &InverseCatalyticActivity{s_per_mol: Complex32::from(y as f32)}),
+				   CatalyticActivity{molps: Complex32::from(x as f32)/Complex32::from(y as f32)}
+		);
+		assert_eq!(div_check(
+			&Complex64::from(x), &InverseCatalyticActivity{s_per_mol: Complex64::from(y)}),
+				   CatalyticActivity{molps: Complex64::from(x)/Complex64::from(y)}
+		);
+		assert_eq!(div_check(
+			&Complex32::from(x as f32), &InverseSpecificHeatCapacity{kgK_per_J: Complex32::from(y as f32)}),
+				   SpecificHeatCapacity{J_per_kgK: Complex32::from(x as f32)/Complex32::from(y as f32)}
+		);
+		assert_eq!(div_check(
+			&Complex64::from(x), &InverseSpecificHeatCapacity{kgK_per_J: Complex64::from(y)}),
+				   SpecificHeatCapacity{J_per_kgK: Complex64::from(x)/Complex64::from(y)}
+		);
+		assert_eq!(div_check(
+			&Complex32::from(x as f32), &MolarVolume{m3_per_mol: Complex32::from(y as f32)}),
+				   Concentration{molpm3: Complex32::from(x as f32)/Complex32::from(y as f32)}
+		);
+		assert_eq!(div_check(
+			&Complex64::from(x), &MolarVolume{m3_per_mol: Complex64::from(y)}),
+				   Concentration{molpm3: Complex64::from(x)/Complex64::from(y)}
+		);
+		assert_eq!(div_check(
+			&Complex32::from(x as f32), &SpecificHeatCapacity{J_per_kgK: Complex32::from(y as f32)}),
+				   InverseSpecificHeatCapacity{kgK_per_J: Complex32::from(x as f32)/Complex32::from(y as f32)}
+		);
+		assert_eq!(div_check(
+			&Complex64::from(x), &SpecificHeatCapacity{J_per_kgK: Complex64::from(y)}),
+				   InverseSpecificHeatCapacity{kgK_per_J: Complex64::from(x)/Complex64::from(y)}
+		);
+		assert_eq!(div_check(
+			&Complex32::from(x as f32), &CatalyticActivity{molps: Complex32::from(y as f32)}),
+				   InverseCatalyticActivity{s_per_mol: Complex32::from(x as f32)/Complex32::from(y as f32)}
+		);
+		assert_eq!(div_check(
+			&Complex64::from(x), &CatalyticActivity{molps: Complex64::from(y)}),
+				   InverseCatalyticActivity{s_per_mol: Complex64::from(x)/Complex64::from(y)}
+		);
+		assert_eq!(div_check(
+			&Complex32::from(x as f32), &Concentration{molpm3: Complex32::from(y as f32)}),
+				   MolarVolume{m3_per_mol: Complex32::from(x as f32)/Complex32::from(y as f32)}
+		);
+		assert_eq!(div_check(
+			&Complex64::from(x), &Concentration{molpm3: Complex64::from(y)}),
+				   MolarVolume{m3_per_mol: Complex64::from(x)/Complex64::from(y)}
+		);
+		assert_eq!(div_check(
+			&Complex32::from(x as f32), &InverseCatalyticActivity{s_per_mol: Complex32::from(y as f32)}),
+				   CatalyticActivity{molps: Complex32::from(x as f32)/Complex32::from(y as f32)}
+		);
+		assert_eq!(div_check(
+			&Complex64::from(x), &InverseCatalyticActivity{s_per_mol: Complex64::from(y)}),
+				   CatalyticActivity{molps: Complex64::from(x)/Complex64::from(y)}
+		);
+		assert_eq!(div_check(
+			&Complex32::from(x as f32), &InverseSpecificHeatCapacity{kgK_per_J: Complex32::from(y as f32)}),
+				   SpecificHeatCapacity{J_per_kgK: Complex32::from(x as f32)/Complex32::from(y as f32)}
+		);
+		assert_eq!(div_check(
+			&Complex64::from(x), &InverseSpecificHeatCapacity{kgK_per_J: Complex64::from(y)}),
+				   SpecificHeatCapacity{J_per_kgK: Complex64::from(x)/Complex64::from(y)}
+		);
+		assert_eq!(div_check(
+			&Complex32::from(x as f32), &MolarVolume{m3_per_mol: Complex32::from(y as f32)}),
+				   Concentration{molpm3: Complex32::from(x as f32)/Complex32::from(y as f32)}
+		);
+		assert_eq!(div_check(
+			&Complex64::from(x), &MolarVolume{m3_per_mol: Complex64::from(y)}),
+				   Concentration{molpm3: Complex64::from(x)/Complex64::from(y)}
+		);
+		assert_eq!(div_check(
+			&Complex32::from(x as f32), &SpecificHeatCapacity{J_per_kgK: Complex32::from(y as f32)}),
+				   InverseSpecificHeatCapacity{kgK_per_J: Complex32::from(x as f32)/Complex32::from(y as f32)}
+		);
+		assert_eq!(div_check(
+			&Complex64::from(x), &SpecificHeatCapacity{J_per_kgK: Complex64::from(y)}),
+				   InverseSpecificHeatCapacity{kgK_per_J: Complex64::from(x)/Complex64::from(y)}
+		);
+		assert_eq!(div_check(
+			&Complex32::from(x as f32), &AreaPerLumen{m2_per_lm: Complex32::from(y as f32)}),
+				   Illuminance{lux: Complex32::from(x as f32)/Complex32::from(y as f32)}
+		);
+		assert_eq!(div_check(
+			&Complex64::from(x), &AreaPerLumen{m2_per_lm: Complex64::from(y)}),
+				   Illuminance{lux: Complex64::from(x)/Complex64::from(y)}
+		);
+		assert_eq!(div_check(
+			&Complex32::from(x as f32), &Capacitance{F: Complex32::from(y as f32)}),
+				   Elastance{per_F: Complex32::from(x as f32)/Complex32::from(y as f32)}
+		);
+		assert_eq!(div_check(
+			&Complex64::from(x), &Capacitance{F: Complex64::from(y)}),
+				   Elastance{per_F: Complex64::from(x)/Complex64::from(y)}
+		);
+		assert_eq!(div_check(
+			&Complex32::from(x as f32), &Charge{C: Complex32::from(y as f32)}),
+				   InverseCharge{per_C: Complex32::from(x as f32)/Complex32::from(y as f32)}
+		);
+		assert_eq!(div_check(
+			&Complex64::from(x), &Charge{C: Complex64::from(y)}),
+				   InverseCharge{per_C: Complex64::from(x)/Complex64::from(y)}
+		);
+		assert_eq!(div_check(
+			&Complex32::from(x as f32), &Elastance{per_F: Complex32::from(y as f32)}),
+				   Capacitance{F: Complex32::from(x as f32)/Complex32::from(y as f32)}
+		);
+		assert_eq!(div_check(
+			&Complex64::from(x), &Elastance{per_F: Complex64::from(y)}),
+				   Capacitance{F: Complex64::from(x)/Complex64::from(y)}
+		);
+		assert_eq!(div_check(
+			&Complex32::from(x as f32), &Illuminance{lux: Complex32::from(y as f32)}),
+				   AreaPerLumen{m2_per_lm: Complex32::from(x as f32)/Complex32::from(y as f32)}
+		);
+		assert_eq!(div_check(
+			&Complex64::from(x), &Illuminance{lux: Complex64::from(y)}),
+				   AreaPerLumen{m2_per_lm: Complex64::from(x)/Complex64::from(y)}
+		);
+		assert_eq!(div_check(
+			&Complex32::from(x as f32), &Inductance{H: Complex32::from(y as f32)}),
+				   InverseInductance{per_H: Complex32::from(x as f32)/Complex32::from(y as f32)}
+		);
+		assert_eq!(div_check(
+			&Complex64::from(x), &Inductance{H: Complex64::from(y)}),
+				   InverseInductance{per_H: Complex64::from(x)/Complex64::from(y)}
+		);
+		assert_eq!(div_check(
+			&Complex32::from(x as f32), &InverseCharge{per_C: Complex32::from(y as f32)}),
+				   Charge{C: Complex32::from(x as f32)/Complex32::from(y as f32)}
+		);
+		assert_eq!(div_check(
+			&Complex64::from(x), &InverseCharge{per_C: Complex64::from(y)}),
+				   Charge{C: Complex64::from(x)/Complex64::from(y)}
+		);
+		assert_eq!(div_check(
+			&Complex32::from(x as f32), &InverseInductance{per_H: Complex32::from(y as f32)}),
+				   Inductance{H: Complex32::from(x as f32)/Complex32::from(y as f32)}
+		);
+		assert_eq!(div_check(
+			&Complex64::from(x), &InverseInductance{per_H: Complex64::from(y)}),
+				   Inductance{H: Complex64::from(x)/Complex64::from(y)}
+		);
+		assert_eq!(div_check(
+			&Complex32::from(x as f32), &InverseLuminousFlux{per_lm: Complex32::from(y as f32)}),
+				   LuminousFlux{lm: Complex32::from(x as f32)/Complex32::from(y as f32)}
+		);
+		assert_eq!(div_check(
+			&Complex64::from(x), &InverseLuminousFlux{per_lm: Complex64::from(y)}),
+				   LuminousFlux{lm: Complex64::from(x)/Complex64::from(y)}
+		);
+		assert_eq!(div_check(
+			&Complex32::from(x as f32), &InverseMagneticFlux{per_Wb: Complex32::from(y as f32)}),
+				   MagneticFlux{Wb: Complex32::from(x as f32)/Complex32::from(y as f32)}
+		);
+		assert_eq!(div_check(
+			&Complex64::from(x), &InverseMagneticFlux{per_Wb: Complex64::from(y)}),
+				   MagneticFlux{Wb: Complex64::from(x)/Complex64::from(y)}
+		);
+		assert_eq!(div_check(
+			&Complex32::from(x as f32), &InverseMagneticFluxDensity{m2_per_Wb: Complex32::from(y as f32)}),
+				   MagneticFluxDensity{T: Complex32::from(x as f32)/Complex32::from(y as f32)}
+		);
+		assert_eq!(div_check(
+			&Complex64::from(x), &InverseMagneticFluxDensity{m2_per_Wb: Complex64::from(y)}),
+				   MagneticFluxDensity{T: Complex64::from(x)/Complex64::from(y)}
+		);
+		assert_eq!(div_check(
+			&Complex32::from(x as f32), &InverseVoltage{per_V: Complex32::from(y as f32)}),
+				   Voltage{V: Complex32::from(x as f32)/Complex32::from(y as f32)}
+		);
+		assert_eq!(div_check(
+			&Complex64::from(x), &InverseVoltage{per_V: Complex64::from(y)}),
+				   Voltage{V: Complex64::from(x)/Complex64::from(y)}
+		);
+		assert_eq!(div_check(
+			&Complex32::from(x as f32), &LuminousFlux{lm: Complex32::from(y as f32)}),
+				   InverseLuminousFlux{per_lm: Complex32::from(x as f32)/Complex32::from(y as f32)}
+		);
+		assert_eq!(div_check(
+			&Complex64::from(x), &LuminousFlux{lm: Complex64::from(y)}),
+				   InverseLuminousFlux{per_lm: Complex64::from(x)/Complex64::from(y)}
+		);
+		assert_eq!(div_check(
+			&Complex32::from(x as f32), &MagneticFlux{Wb: Complex32::from(y as f32)}),
+				   InverseMagneticFlux{per_Wb: Complex32::from(x as f32)/Complex32::from(y as f32)}
+		);
+		assert_eq!(div_check(
+			&Complex64::from(x), &MagneticFlux{Wb: Complex64::from(y)}),
+				   InverseMagneticFlux{per_Wb: Complex64::from(x)/Complex64::from(y)}
+		);
+		assert_eq!(div_check(
+			&Complex32::from(x as f32), &MagneticFluxDensity{T: Complex32::from(y as f32)}),
+				   InverseMagneticFluxDensity{m2_per_Wb: Complex32::from(x as f32)/Complex32::from(y as f32)}
+		);
+		assert_eq!(div_check(
+			&Complex64::from(x), &MagneticFluxDensity{T: Complex64::from(y)}),
+				   InverseMagneticFluxDensity{m2_per_Wb: Complex64::from(x)/Complex64::from(y)}
+		);
+		assert_eq!(div_check(
+			&Complex32::from(x as f32), &Voltage{V: Complex32::from(y as f32)}),
+				   InverseVoltage{per_V: Complex32::from(x as f32)/Complex32::from(y as f32)}
+		);
+		assert_eq!(div_check(
+			&Complex64::from(x), &Voltage{V: Complex64::from(y)}),
+				   InverseVoltage{per_V: Complex64::from(x)/Complex64::from(y)}
+		);
+		assert_eq!(div_check(
+			&Complex32::from(x as f32), &AreaPerLumen{m2_per_lm: Complex32::from(y as f32)}),
+				   Illuminance{lux: Complex32::from(x as f32)/Complex32::from(y as f32)}
+		);
+		assert_eq!(div_check(
+			&Complex64::from(x), &AreaPerLumen{m2_per_lm: Complex64::from(y)}),
+				   Illuminance{lux: Complex64::from(x)/Complex64::from(y)}
+		);
+		assert_eq!(div_check(
+			&Complex32::from(x as f32), &Capacitance{F: Complex32::from(y as f32)}),
+				   Elastance{per_F: Complex32::from(x as f32)/Complex32::from(y as f32)}
+		);
+		assert_eq!(div_check(
+			&Complex64::from(x), &Capacitance{F: Complex64::from(y)}),
+				   Elastance{per_F: Complex64::from(x)/Complex64::from(y)}
+		);
+		assert_eq!(div_check(
+			&Complex32::from(x as f32), &Charge{C: Complex32::from(y as f32)}),
+				   InverseCharge{per_C: Complex32::from(x as f32)/Complex32::from(y as f32)}
+		);
+		assert_eq!(div_check(
+			&Complex64::from(x), &Charge{C: Complex64::from(y)}),
+				   InverseCharge{per_C: Complex64::from(x)/Complex64::from(y)}
+		);
+		assert_eq!(div_check(
+			&Complex32::from(x as f32), &Elastance{per_F: Complex32::from(y as f32)}),
+				   Capacitance{F: Complex32::from(x as f32)/Complex32::from(y as f32)}
+		);
+		assert_eq!(div_check(
+			&Complex64::from(x), &Elastance{per_F: Complex64::from(y)}),
+				   Capacitance{F: Complex64::from(x)/Complex64::from(y)}
+		);
+		assert_eq!(div_check(
+			&Complex32::from(x as f32), &Illuminance{lux: Complex32::from(y as f32)}),
+				   AreaPerLumen{m2_per_lm: Complex32::from(x as f32)/Complex32::from(y as f32)}
+		);
+		assert_eq!(div_check(
+			&Complex64::from(x), &Illuminance{lux: Complex64::from(y)}),
+				   AreaPerLumen{m2_per_lm: Complex64::from(x)/Complex64::from(y)}
+		);
+		assert_eq!(div_check(
+			&Complex32::from(x as f32), &Inductance{H: Complex32::from(y as f32)}),
+				   InverseInductance{per_H: Complex32::from(x as f32)/Complex32::from(y as f32)}
+		);
+		assert_eq!(div_check(
+			&Complex64::from(x), &Inductance{H: Complex64::from(y)}),
+				   InverseInductance{per_H: Complex64::from(x)/Complex64::from(y)}
+		);
+		assert_eq!(div_check(
+			&Complex32::from(x as f32), &InverseCharge{per_C: Complex32::from(y as f32)}),
+				   Charge{C: Complex32::from(x as f32)/Complex32::from(y as f32)}
+		);
+		assert_eq!(div_check(
+			&Complex64::from(x), &InverseCharge{per_C: Complex64::from(y)}),
+				   Charge{C: Complex64::from(x)/Complex64::from(y)}
+		);
+		assert_eq!(div_check(
+			&Complex32::from(x as f32), &InverseInductance{per_H: Complex32::from(y as f32)}),
+				   Inductance{H: Complex32::from(x as f32)/Complex32::from(y as f32)}
+		);
+		assert_eq!(div_check(
+			&Complex64::from(x), &InverseInductance{per_H: Complex64::from(y)}),
+				   Inductance{H: Complex64::from(x)/Complex64::from(y)}
+		);
+		assert_eq!(div_check(
+			&Complex32::from(x as f32), &InverseLuminousFlux{per_lm: Complex32::from(y as f32)}),
+				   LuminousFlux{lm: Complex32::from(x as f32)/Complex32::from(y as f32)}
+		);
+		assert_eq!(div_check(
+			&Complex64::from(x), &InverseLuminousFlux{per_lm: Complex64::from(y)}),
+				   LuminousFlux{lm: Complex64::from(x)/Complex64::from(y)}
+		);
+		assert_eq!(div_check(
+			&Complex32::from(x as f32), &InverseMagneticFlux{per_Wb: Complex32::from(y as f32)}),
+				   MagneticFlux{Wb: Complex32::from(x as f32)/Complex32::from(y as f32)}
+		);
+		assert_eq!(div_check(
+			&Complex64::from(x), &InverseMagneticFlux{per_Wb: Complex64::from(y)}),
+				   MagneticFlux{Wb: Complex64::from(x)/Complex64::from(y)}
+		);
+		assert_eq!(div_check(
+			&Complex32::from(x as f32), &InverseMagneticFluxDensity{m2_per_Wb: Complex32::from(y as f32)}),
+				   MagneticFluxDensity{T: Complex32::from(x as f32)/Complex32::from(y as f32)}
+		);
+		assert_eq!(div_check(
+			&Complex64::from(x), &InverseMagneticFluxDensity{m2_per_Wb: Complex64::from(y)}),
+				   MagneticFluxDensity{T: Complex64::from(x)/Complex64::from(y)}
+		);
+		assert_eq!(div_check(
+			&Complex32::from(x as f32), &InverseVoltage{per_V: Complex32::from(y as f32)}),
+				   Voltage{V: Complex32::from(x as f32)/Complex32::from(y as f32)}
+		);
+		assert_eq!(div_check(
+			&Complex64::from(x), &InverseVoltage{per_V: Complex64::from(y)}),
+				   Voltage{V: Complex64::from(x)/Complex64::from(y)}
+		);
+		assert_eq!(div_check(
+			&Complex32::from(x as f32), &LuminousFlux{lm: Complex32::from(y as f32)}),
+				   InverseLuminousFlux{per_lm: Complex32::from(x as f32)/Complex32::from(y as f32)}
+		);
+		assert_eq!(div_check(
+			&Complex64::from(x), &LuminousFlux{lm: Complex64::from(y)}),
+				   InverseLuminousFlux{per_lm: Complex64::from(x)/Complex64::from(y)}
+		);
+		assert_eq!(div_check(
+			&Complex32::from(x as f32), &MagneticFlux{Wb: Complex32::from(y as f32)}),
+				   InverseMagneticFlux{per_Wb: Complex32::from(x as f32)/Complex32::from(y as f32)}
+		);
+		assert_eq!(div_check(
+			&Complex64::from(x), &MagneticFlux{Wb: Complex64::from(y)}),
+				   InverseMagneticFlux{per_Wb: Complex64::from(x)/Complex64::from(y)}
+		);
+		assert_eq!(div_check(
+			&Complex32::from(x as f32), &MagneticFluxDensity{T: Complex32::from(y as f32)}),
+				   InverseMagneticFluxDensity{m2_per_Wb: Complex32::from(x as f32)/Complex32::from(y as f32)}
+		);
+		assert_eq!(div_check(
+			&Complex64::from(x), &MagneticFluxDensity{T: Complex64::from(y)}),
+				   InverseMagneticFluxDensity{m2_per_Wb: Complex64::from(x)/Complex64::from(y)}
+		);
+		assert_eq!(div_check(
+			&Complex32::from(x as f32), &Voltage{V: Complex32::from(y as f32)}),
+				   InverseVoltage{per_V: Complex32::from(x as f32)/Complex32::from(y as f32)}
+		);
+		assert_eq!(div_check(
+			&Complex64::from(x), &Voltage{V: Complex64::from(y)}),
+				   InverseVoltage{per_V: Complex64::from(x)/Complex64::from(y)}
+		);
+		assert_eq!(div_check(
+			&Complex32::from(x as f32), &Angle{rad: Complex32::from(y as f32)}),
+				   InverseAngle{per_rad: Complex32::from(x as f32)/Complex32::from(y as f32)}
+		);
+		assert_eq!(div_check(
+			&Complex64::from(x), &Angle{rad: Complex64::from(y)}),
+				   InverseAngle{per_rad: Complex64::from(x)/Complex64::from(y)}
+		);
+		assert_eq!(div_check(
+			&Complex32::from(x as f32), &Area{m2: Complex32::from(y as f32)}),
+				   InverseArea{per_m2: Complex32::from(x as f32)/Complex32::from(y as f32)}
+		);
+		assert_eq!(div_check(
+			&Complex64::from(x), &Area{m2: Complex64::from(y)}),
+				   InverseArea{per_m2: Complex64::from(x)/Complex64::from(y)}
+		);
+		assert_eq!(div_check(
+			&Complex32::from(x as f32), &InverseAngle{per_rad: Complex32::from(y as f32)}),
+				   Angle{rad: Complex32::from(x as f32)/Complex32::from(y as f32)}
+		);
+		assert_eq!(div_check(
+			&Complex64::from(x), &InverseAngle{per_rad: Complex64::from(y)}),
+				   Angle{rad: Complex64::from(x)/Complex64::from(y)}
+		);
+		assert_eq!(div_check(
+			&Complex32::from(x as f32), &InverseArea{per_m2: Complex32::from(y as f32)}),
+				   Area{m2: Complex32::from(x as f32)/Complex32::from(y as f32)}
+		);
+		assert_eq!(div_check(
+			&Complex64::from(x), &InverseArea{per_m2: Complex64::from(y)}),
+				   Area{m2: Complex64::from(x)/Complex64::from(y)}
+		);
+		assert_eq!(div_check(
+			&Complex32::from(x as f32), &InverseSolidAngle{per_sr: Complex32::from(y as f32)}),
+				   SolidAngle{sr: Complex32::from(x as f32)/Complex32::from(y as f32)}
+		);
+		assert_eq!(div_check(
+			&Complex64::from(x), &InverseSolidAngle{per_sr: Complex64::from(y)}),
+				   SolidAngle{sr: Complex64::from(x)/Complex64::from(y)}
+		);
+		assert_eq!(div_check(
+			&Complex32::from(x as f32), &InverseVolume{per_m3: Complex32::from(y as f32)}),
+				   Volume{m3: Complex32::from(x as f32)/Complex32::from(y as f32)}
+		);
+		assert_eq!(div_check(
+			&Complex64::from(x), &InverseVolume{per_m3: Complex64::from(y)}),
+				   Volume{m3: Complex64::from(x)/Complex64::from(y)}
+		);
+		assert_eq!(div_check(
+			&Complex32::from(x as f32), &SolidAngle{sr: Complex32::from(y as f32)}),
+				   InverseSolidAngle{per_sr: Complex32::from(x as f32)/Complex32::from(y as f32)}
+		);
+		assert_eq!(div_check(
+			&Complex64::from(x), &SolidAngle{sr: Complex64::from(y)}),
+				   InverseSolidAngle{per_sr: Complex64::from(x)/Complex64::from(y)}
+		);
+		assert_eq!(div_check(
+			&Complex32::from(x as f32), &Volume{m3: Complex32::from(y as f32)}),
+				   InverseVolume{per_m3: Complex32::from(x as f32)/Complex32::from(y as f32)}
+		);
+		assert_eq!(div_check(
+			&Complex64::from(x), &Volume{m3: Complex64::from(y)}),
+				   InverseVolume{per_m3: Complex64::from(x)/Complex64::from(y)}
+		);
+		assert_eq!(div_check(
+			&Complex32::from(x as f32), &Angle{rad: Complex32::from(y as f32)}),
+				   InverseAngle{per_rad: Complex32::from(x as f32)/Complex32::from(y as f32)}
+		);
+		assert_eq!(div_check(
+			&Complex64::from(x), &Angle{rad: Complex64::from(y)}),
+				   InverseAngle{per_rad: Complex64::from(x)/Complex64::from(y)}
+		);
+		assert_eq!(div_check(
+			&Complex32::from(x as f32), &Area{m2: Complex32::from(y as f32)}),
+				   InverseArea{per_m2: Complex32::from(x as f32)/Complex32::from(y as f32)}
+		);
+		assert_eq!(div_check(
+			&Complex64::from(x), &Area{m2: Complex64::from(y)}),
+				   InverseArea{per_m2: Complex64::from(x)/Complex64::from(y)}
+		);
+		assert_eq!(div_check(
+			&Complex32::from(x as f32), &InverseAngle{per_rad: Complex32::from(y as f32)}),
+				   Angle{rad: Complex32::from(x as f32)/Complex32::from(y as f32)}
+		);
+		assert_eq!(div_check(
+			&Complex64::from(x), &InverseAngle{per_rad: Complex64::from(y)}),
+				   Angle{rad: Complex64::from(x)/Complex64::from(y)}
+		);
+		assert_eq!(div_check(
+			&Complex32::from(x as f32), &InverseArea{per_m2: Complex32::from(y as f32)}),
+				   Area{m2: Complex32::from(x as f32)/Complex32::from(y as f32)}
+		);
+		assert_eq!(div_check(
+			&Complex64::from(x), &InverseArea{per_m2: Complex64::from(y)}),
+				   Area{m2: Complex64::from(x)/Complex64::from(y)}
+		);
+		assert_eq!(div_check(
+			&Complex32::from(x as f32), &InverseSolidAngle{per_sr: Complex32::from(y as f32)}),
+				   SolidAngle{sr: Complex32::from(x as f32)/Complex32::from(y as f32)}
+		);
+		assert_eq!(div_check(
+			&Complex64::from(x), &InverseSolidAngle{per_sr: Complex64::from(y)}),
+				   SolidAngle{sr: Complex64::from(x)/Complex64::from(y)}
+		);
+		assert_eq!(div_check(
+			&Complex32::from(x as f32), &InverseVolume{per_m3: Complex32::from(y as f32)}),
+				   Volume{m3: Complex32::from(x as f32)/Complex32::from(y as f32)}
+		);
+		assert_eq!(div_check(
+			&Complex64::from(x), &InverseVolume{per_m3: Complex64::from(y)}),
+				   Volume{m3: Complex64::from(x)/Complex64::from(y)}
+		);
+		assert_eq!(div_check(
+			&Complex32::from(x as f32), &SolidAngle{sr: Complex32::from(y as f32)}),
+				   InverseSolidAngle{per_sr: Complex32::from(x as f32)/Complex32::from(y as f32)}
+		);
+		assert_eq!(div_check(
+			&Complex64::from(x), &SolidAngle{sr: Complex64::from(y)}),
+				   InverseSolidAngle{per_sr: Complex64::from(x)/Complex64::from(y)}
+		);
+		assert_eq!(div_check(
+			&Complex32::from(x as f32), &Volume{m3: Complex32::from(y as f32)}),
+				   InverseVolume{per_m3: Complex32::from(x as f32)/Complex32::from(y as f32)}
+		);
+		assert_eq!(div_check(
+			&Complex64::from(x), &Volume{m3: Complex64::from(y)}),
+				   InverseVolume{per_m3: Complex64::from(x)/Complex64::from(y)}
+		);
+		assert_eq!(div_check(
+			&Complex32::from(x as f32), &Acceleration{mps2: Complex32::from(y as f32)}),
+				   InverseAcceleration{s2pm: Complex32::from(x as f32)/Complex32::from(y as f32)}
+		);
+		assert_eq!(div_check(
+			&Complex64::from(x), &Acceleration{mps2: Complex64::from(y)}),
+				   InverseAcceleration{s2pm: Complex64::from(x)/Complex64::from(y)}
+		);
+		assert_eq!(div_check(
+			&Complex32::from(x as f32), &AngularAcceleration{radps2: Complex32::from(y as f32)}),
+				   InverseAngularAcceleration{s2prad: Complex32::from(x as f32)/Complex32::from(y as f32)}
+		);
+		assert_eq!(div_check(
+			&Complex64::from(x), &AngularAcceleration{radps2: Complex64::from(y)}),
+				   InverseAngularAcceleration{s2prad: Complex64::from(x)/Complex64::from(y)}
+		);
+		assert_eq!(div_check(
+			&Complex32::from(x as f32), &AngularMomentum{kgm2radps: Complex32::from(y as f32)}),
+				   InverseAngularMomentum{s_per_kgm2rad: Complex32::from(x as f32)/Complex32::from(y as f32)}
+		);
+		assert_eq!(div_check(
+			&Complex64::from(x), &AngularMomentum{kgm2radps: Complex64::from(y)}),
+				   InverseAngularMomentum{s_per_kgm2rad: Complex64::from(x)/Complex64::from(y)}
+		);
+		assert_eq!(div_check(
+			&Complex32::from(x as f32), &AngularVelocity{radps: Complex32::from(y as f32)}),
+				   InverseAngularVelocity{s_per_rad: Complex32::from(x as f32)/Complex32::from(y as f32)}
+		);
+		assert_eq!(div_check(
+			&Complex64::from(x), &AngularVelocity{radps: Complex64::from(y)}),
+				   InverseAngularVelocity{s_per_rad: Complex64::from(x)/Complex64::from(y)}
+		);
+		assert_eq!(div_check(
+			&Complex32::from(x as f32), &AreaDensity{kgpm2: Complex32::from(y as f32)}),
+				   AreaPerMass{m2_per_kg: Complex32::from(x as f32)/Complex32::from(y as f32)}
+		);
+		assert_eq!(div_check(
+			&Complex64::from(x), &AreaDensity{kgpm2: Complex64::from(y)}),
+				   AreaPerMass{m2_per_kg: Complex64::from(x)/Complex64::from(y)}
+		);
+		assert_eq!(div_check(
+			&Complex32::from(x as f32), &AreaPerMass{m2_per_kg: Complex32::from(y as f32)}),
+				   AreaDensity{kgpm2: Complex32::from(x as f32)/Complex32::from(y as f32)}
+		);
+		assert_eq!(div_check(
+			&Complex64::from(x), &AreaPerMass{m2_per_kg: Complex64::from(y)}),
+				   AreaDensity{kgpm2: Complex64::from(x)/Complex64::from(y)}
+		);
+		assert_eq!(div_check(
+			&Complex32::from(x as f32), &Density{kgpm3: Complex32::from(y as f32)}),
+				   VolumePerMass{m3_per_kg: Complex32::from(x as f32)/Complex32::from(y as f32)}
+		);
+		assert_eq!(div_check(
+			&Complex64::from(x), &Density{kgpm3: Complex64::from(y)}),
+				   VolumePerMass{m3_per_kg: Complex64::from(x)/Complex64::from(y)}
+		);
+		assert_eq!(div_check(
+			&Complex32::from(x as f32), &Energy{J: Complex32::from(y as f32)}),
+				   InverseEnergy{per_J: Complex32::from(x as f32)/Complex32::from(y as f32)}
+		);
+		assert_eq!(div_check(
+			&Complex64::from(x), &Energy{J: Complex64::from(y)}),
+				   InverseEnergy{per_J: Complex64::from(x)/Complex64::from(y)}
+		);
+		assert_eq!(div_check(
+			&Complex32::from(x as f32), &Force{N: Complex32::from(y as f32)}),
+				   InverseForce{per_N: Complex32::from(x as f32)/Complex32::from(y as f32)}
+		);
+		assert_eq!(div_check(
+			&Complex64::from(x), &Force{N: Complex64::from(y)}),
+				   InverseForce{per_N: Complex64::from(x)/Complex64::from(y)}
+		);
+		assert_eq!(div_check(
+			&Complex32::from(x as f32), &InverseAcceleration{s2pm: Complex32::from(y as f32)}),
+				   Acceleration{mps2: Complex32::from(x as f32)/Complex32::from(y as f32)}
+		);
+		assert_eq!(div_check(
+			&Complex64::from(x), &InverseAcceleration{s2pm: Complex64::from(y)}),
+				   Acceleration{mps2: Complex64::from(x)/Complex64::from(y)}
+		);
+		assert_eq!(div_check(
+			&Complex32::from(x as f32), &InverseAngularAcceleration{s2prad: Complex32::from(y as f32)}),
+				   AngularAcceleration{radps2: Complex32::from(x as f32)/Complex32::from(y as f32)}
+		);
+		assert_eq!(div_check(
+			&Complex64::from(x), &InverseAngularAcceleration{s2prad: Complex64::from(y)}),
+				   AngularAcceleration{radps2: Complex64::from(x)/Complex64::from(y)}
+		);
+		assert_eq!(div_check(
+			&Complex32::from(x as f32), &InverseAngularMomentum{s_per_kgm2rad: Complex32::from(y as f32)}),
+				   AngularMomentum{kgm2radps: Complex32::from(x as f32)/Complex32::from(y as f32)}
+		);
+		assert_eq!(div_check(
+			&Complex64::from(x), &InverseAngularMomentum{s_per_kgm2rad: Complex64::from(y)}),
+				   AngularMomentum{kgm2radps: Complex64::from(x)/Complex64::from(y)}
+		);
+		assert_eq!(div_check(
+			&Complex32::from(x as f32), &InverseAngularVelocity{s_per_rad: Complex32::from(y as f32)}),
+				   AngularVelocity{radps: Complex32::from(x as f32)/Complex32::from(y as f32)}
+		);
+		assert_eq!(div_check(
+			&Complex64::from(x), &InverseAngularVelocity{s_per_rad: Complex64::from(y)}),
+				   AngularVelocity{radps: Complex64::from(x)/Complex64::from(y)}
+		);
+		assert_eq!(div_check(
+			&Complex32::from(x as f32), &InverseEnergy{per_J: Complex32::from(y as f32)}),
+				   Energy{J: Complex32::from(x as f32)/Complex32::from(y as f32)}
+		);
+		assert_eq!(div_check(
+			&Complex64::from(x), &InverseEnergy{per_J: Complex64::from(y)}),
+				   Energy{J: Complex64::from(x)/Complex64::from(y)}
+		);
+		assert_eq!(div_check(
+			&Complex32::from(x as f32), &InverseForce{per_N: Complex32::from(y as f32)}),
+				   Force{N: Complex32::from(x as f32)/Complex32::from(y as f32)}
+		);
+		assert_eq!(div_check(
+			&Complex64::from(x), &InverseForce{per_N: Complex64::from(y)}),
+				   Force{N: Complex64::from(x)/Complex64::from(y)}
+		);
+		assert_eq!(div_check(
+			&Complex32::from(x as f32), &InverseMomentum{s_per_kgm: Complex32::from(y as f32)}),
+				   Momentum{kgmps: Complex32::from(x as f32)/Complex32::from(y as f32)}
+		);
+		assert_eq!(div_check(
+			&Complex64::from(x), &InverseMomentum{s_per_kgm: Complex64::from(y)}),
+				   Momentum{kgmps: Complex64::from(x)/Complex64::from(y)}
+		);
+		assert_eq!(div_check(
+			&Complex32::from(x as f32), &InversePower{per_W: Complex32::from(y as f32)}),
+				   Power{W: Complex32::from(x as f32)/Complex32::from(y as f32)}
+		);
+		assert_eq!(div_check(
+			&Complex64::from(x), &InversePower{per_W: Complex64::from(y)}),
+				   Power{W: Complex64::from(x)/Complex64::from(y)}
+		);
+		assert_eq!(div_check(
+			&Complex32::from(x as f32), &InversePressure{per_Pa: Complex32::from(y as f32)}),
+				   Pressure{Pa: Complex32::from(x as f32)/Complex32::from(y as f32)}
+		);
+		assert_eq!(div_check(
+			&Complex64::from(x), &InversePressure{per_Pa: Complex64::from(y)}),
+				   Pressure{Pa: Complex64::from(x)/Complex64::from(y)}
+		);
+		assert_eq!(div_check(
+			&Complex32::from(x as f32), &InverseTorque{per_Nm: Complex32::from(y as f32)}),
+				   Energy{J: Complex32::from(x as f32)/Complex32::from(y as f32)}
+		);
+		assert_eq!(div_check(
+			&Complex64::from(x), &InverseTorque{per_Nm: Complex64::from(y)}),
+				   Energy{J: Complex64::from(x)/Complex64::from(y)}
+		);
+		assert_eq!(div_check(
+			&Complex32::from(x as f32), &Momentum{kgmps: Complex32::from(y as f32)}),
+				   InverseMomentum{s_per_kgm: Complex32::from(x as f32)/Complex32::from(y as f32)}
+		);
+		assert_eq!(div_check(
+			&Complex64::from(x), &Momentum{kgmps: Complex64::from(y)}),
+				   InverseMomentum{s_per_kgm: Complex64::from(x)/Complex64::from(y)}
+		);
+		assert_eq!(div_check(
+			&Complex32::from(x as f32), &Power{W: Complex32::from(y as f32)}),
+				   InversePower{per_W: Complex32::from(x as f32)/Complex32::from(y as f32)}
+		);
+		assert_eq!(div_check(
+			&Complex64::from(x), &Power{W: Complex64::from(y)}),
+				   InversePower{per_W: Complex64::from(x)/Complex64::from(y)}
+		);
+		assert_eq!(div_check(
+			&Complex32::from(x as f32), &Pressure{Pa: Complex32::from(y as f32)}),
+				   InversePressure{per_Pa: Complex32::from(x as f32)/Complex32::from(y as f32)}
+		);
+		assert_eq!(div_check(
+			&Complex64::from(x), &Pressure{Pa: Complex64::from(y)}),
+				   InversePressure{per_Pa: Complex64::from(x)/Complex64::from(y)}
+		);
+		assert_eq!(div_check(
+			&Complex32::from(x as f32), &TimePerDistance{spm: Complex32::from(y as f32)}),
+				   Velocity{mps: Complex32::from(x as f32)/Complex32::from(y as f32)}
+		);
+		assert_eq!(div_check(
+			&Complex64::from(x), &TimePerDistance{spm: Complex64::from(y)}),
+				   Velocity{mps: Complex64::from(x)/Complex64::from(y)}
+		);
+		assert_eq!(div_check(
+			&Complex32::from(x as f32), &Torque{Nm: Complex32::from(y as f32)}),
+				   InverseEnergy{per_J: Complex32::from(x as f32)/Complex32::from(y as f32)}
+		);
+		assert_eq!(div_check(
+			&Complex64::from(x), &Torque{Nm: Complex64::from(y)}),
+				   InverseEnergy{per_J: Complex64::from(x)/Complex64::from(y)}
+		);
+		assert_eq!(div_check(
+			&Complex32::from(x as f32), &Velocity{mps: Complex32::from(y as f32)}),
+				   TimePerDistance{spm: Complex32::from(x as f32)/Complex32::from(y as f32)}
+		);
+		assert_eq!(div_check(
+			&Complex64::from(x), &Velocity{mps: Complex64::from(y)}),
+				   TimePerDistance{spm: Complex64::from(x)/Complex64::from(y)}
+		);
+		assert_eq!(div_check(
+			&Complex32::from(x as f32), &VolumePerMass{m3_per_kg: Complex32::from(y as f32)}),
+				   Density{kgpm3: Complex32::from(x as f32)/Complex32::from(y as f32)}
+		);
+		assert_eq!(div_check(
+			&Complex64::from(x), &VolumePerMass{m3_per_kg: Complex64::from(y)}),
+				   Density{kgpm3: Complex64::from(x)/Complex64::from(y)}
+		);
+		assert_eq!(div_check(
+			&Complex32::from(x as f32), &Acceleration{mps2: Complex32::from(y as f32)}),
+				   InverseAcceleration{s2pm: Complex32::from(x as f32)/Complex32::from(y as f32)}
+		);
+		assert_eq!(div_check(
+			&Complex64::from(x), &Acceleration{mps2: Complex64::from(y)}),
+				   InverseAcceleration{s2pm: Complex64::from(x)/Complex64::from(y)}
+		);
+		assert_eq!(div_check(
+			&Complex32::from(x as f32), &AngularAcceleration{radps2: Complex32::from(y as f32)}),
+				   InverseAngularAcceleration{s2prad: Complex32::from(x as f32)/Complex32::from(y as f32)}
 		);
 		assert_eq!(div_check(
-			&BigFloat::from(x), &Time{s: BigFloat::from(y)}),
-				   Frequency{Hz: BigFloat::from(x)/BigFloat::from(y)}
+			&Complex64::from(x), &AngularAcceleration{radps2: Complex64::from(y)}),
+				   InverseAngularAcceleration{s2prad: Complex64::from(x)/Complex64::from(y)}
 		);
 		assert_eq!(div_check(
-			&BigFloat::from(x), &Conductance{S: BigFloat::from(y)}),
-				   Resistance{Ohm: BigFloat::from(x)/BigFloat::from(y)}
+			&Complex32::from(x as f32), &AngularMomentum{kgm2radps: Complex32::from(y as f32)}),
+				   InverseAngularMomentum{s_per_kgm2rad: Complex32::from(x as f32)/Complex32::from(y as f32)}
 		);
 		assert_eq!(div_check(
-			&BigFloat::from(x), &Resistance{Ohm: BigFloat::from(y)}),
-				   Conductance{S: BigFloat::from(x)/BigFloat::from(y)}
+			&Complex64::from(x), &AngularMomentum{kgm2radps: Complex64::from(y)}),
+				   InverseAngularMomentum{s_per_kgm2rad: Complex64::from(x)/Complex64::from(y)}
 		);
 		assert_eq!(div_check(
-			&BigFloat::from(x), &Conductance{S: BigFloat::from(y)}),
-				   Resistance{Ohm: BigFloat::from(x)/BigFloat::from(y)}
+			&Complex32::from(x as f32), &AngularVelocity{radps: Complex32::from(y as f32)}),
+				   InverseAngularVelocity{s_per_rad: Complex32::from(x as f32)/Complex32::from(y as f32)}
 		);
 		assert_eq!(div_check(
-			&BigFloat::from(x), &Resistance{Ohm: BigFloat::from(y)}),
-				   Conductance{S: BigFloat::from(x)/BigFloat::from(y)}
+			&Complex64::from(x), &AngularVelocity{radps: Complex64::from(y)}),
+				   InverseAngularVelocity{s_per_rad: Complex64::from(x)/Complex64::from(y)}
 		);
 		assert_eq!(div_check(
-			&BigFloat::from(x), &Frequency{Hz: BigFloat::from(y)}),
-				   Time{s: BigFloat::from(x)/BigFloat::from(y)}
+			&Complex32::from(x as f32), &AreaDensity{kgpm2: Complex32::from(y as f32)}),
+				   AreaPerMass{m2_per_kg: Complex32::from(x as f32)/Complex32::from(y as f32)}
 		);
 		assert_eq!(div_check(
-			&BigFloat::from(x), &Frequency{Hz: BigFloat::from(y)}),
-				   Time{s: BigFloat::from(x)/BigFloat::from(y)}
+			&Complex64::from(x), &AreaDensity{kgpm2: Complex64::from(y)}),
+				   AreaPerMass{m2_per_kg: Complex64::from(x)/Complex64::from(y)}
 		);
 		assert_eq!(div_check(
-			&BigFloat::from(x), &Radioactivity{Bq: BigFloat::from(y)}),
-				   Time{s: BigFloat::from(x)/BigFloat::from(y)}
+			&Complex32::from(x as f32), &AreaPerMass{m2_per_kg: Complex32::from(y as f32)}),
+				   AreaDensity{kgpm2: Complex32::from(x as f32)/Complex32::from(y as f32)}
 		);
 		assert_eq!(div_check(
-			&BigFloat::from(x), &Radioactivity{Bq: BigFloat::from(y)}),
-				   Time{s: BigFloat::from(x)/BigFloat::from(y)}
+			&Complex64::from(x), &AreaPerMass{m2_per_kg: Complex64::from(y)}),
+				   AreaDensity{kgpm2: Complex64::from(x)/Complex64::from(y)}
 		);
 		assert_eq!(div_check(
-			&BigFloat::from(x), &Molality{molpkg: BigFloat::from(y)}),
-				   MolarMass{kgpmol: BigFloat::from(x)/BigFloat::from(y)}
+			&Complex32::from(x as f32), &Density{kgpm3: Complex32::from(y as f32)}),
+				   VolumePerMass{m3_per_kg: Complex32::from(x as f32)/Complex32::from(y as f32)}
 		);
 		assert_eq!(div_check(
-			&BigFloat::from(x), &MolarMass{kgpmol: BigFloat::from(y)}),
-				   Molality{molpkg: BigFloat::from(x)/BigFloat::from(y)}
+			&Complex64::from(x), &Density{kgpm3: Complex64::from(y)}),
+				   VolumePerMass{m3_per_kg: Complex64::from(x)/Complex64::from(y)}
 		);
 		assert_eq!(div_check(
-			&BigFloat::from(x), &Molality{molpkg: BigFloat::from(y)}),
-				   MolarMass{kgpmol: BigFloat::from(x)/BigFloat::from(y)}
+			&Complex32::from(x as f32), &Energy{J: Complex32::from(y as f32)}),
+				   InverseEnergy{per_J: Complex32::from(x as f32)/Complex32::from(y as f32)}
 		);
 		assert_eq!(div_check(
-			&BigFloat::from(x), &MolarMass{kgpmol: BigFloat::from(y)}),
-				   Molality{molpkg: BigFloat::from(x)/BigFloat::from(y)}
+			&Complex64::from(x), &Energy{J: Complex64::from(y)}),
+				   InverseEnergy{per_J: Complex64::from(x)/Complex64::from(y)}
 		);
 		assert_eq!(div_check(
-			&BigFloat::from(x), &Amount{mol: BigFloat::from(y)}),
-				   InverseAmount{per_mol: BigFloat::from(x)/BigFloat::from(y)}
+			&Complex32::from(x as f32), &Force{N: Complex32::from(y as f32)}),
+				   InverseForce{per_N: Complex32::from(x as f32)/Complex32::from(y as f32)}
 		);
 		assert_eq!(div_check(
-			&BigFloat::from(x), &Current{A: BigFloat::from(y)}),
-				   InverseCurrent{per_A: BigFloat::from(x)/BigFloat::from(y)}
+			&Complex64::from(x), &Force{N: Complex64::from(y)}),
+				   InverseForce{per_N: Complex64::from(x)/Complex64::from(y)}
 		);
 		assert_eq!(div_check(
-			&BigFloat::from(x), &Distance{m: BigFloat::from(y)}),
-				   InverseDistance{per_m: BigFloat::from(x)/BigFloat::from(y)}
+			&Complex32::from(x as f32), &InverseAcceleration{s2pm: Complex32::from(y as f32)}),
+				   Acceleration{mps2: Complex32::from(x as f32)/Complex32::from(y as f32)}
 		);
 		assert_eq!(div_check(
-			&BigFloat::from(x), &InverseAmount{per_mol: BigFloat::from(y)}),
-				   Amount{mol: BigFloat::from(x)/BigFloat::from(y)}
+			&Complex64::from(x), &InverseAcceleration{s2pm: Complex64::from(y)}),
+				   Acceleration{mps2: Complex64::from(x)/Complex64::from(y)}
 		);
 		assert_eq!(div_check(
-			&BigFloat::from(x), &InverseCurrent{per_A: BigFloat::from(y)}),
-				   Current{A: BigFloat::from(x)/BigFloat::from(y)}
+			&Complex32::from(x as f32), &InverseAngularAcceleration{s2prad: Complex32::from(y as f32)}),
+				   AngularAcceleration{radps2: Complex32::from(x as f32)/Complex32::from(y as f32)}
 		);
 		assert_eq!(div_check(
-			&BigFloat::from(x), &InverseDistance{per_m: BigFloat::from(y)}),
-				   Distance{m: BigFloat::from(x)/BigFloat::from(y)}
+			&Complex64::from(x), &InverseAngularAcceleration{s2prad: Complex64::from(y)}),
+				   AngularAcceleration{radps2: Complex64::from(x)/Complex64::from(y)}
 		);
 		assert_eq!(div_check(
-			&BigFloat::from(x), &InverseLuminosity{per_cd: BigFloat::from(y)}),
-				   Luminosity{cd: BigFloat::from(x)/BigFloat::from(y)}
+			&Complex32::from(x as f32), &InverseAngularMomentum{s_per_kgm2rad: Complex32::from(y as f32)}),
+				   AngularMomentum{kgm2radps: Complex32::from(x as f32)/Complex32::from(y as f32)}
 		);
 		assert_eq!(div_check(
-			&BigFloat::from(x), &InverseMass{per_kg: BigFloat::from(y)}),
-				   Mass{kg: BigFloat::from(x)/BigFloat::from(y)}
+			&Complex64::from(x), &InverseAngularMomentum{s_per_kgm2rad: Complex64::from(y)}),
+				   AngularMomentum{kgm2radps: Complex64::from(x)/Complex64::from(y)}
 		);
 		assert_eq!(div_check(
-			&BigFloat::from(x), &InverseTemperature{per_K: BigFloat::from(y)}),
-				   Temperature{K: BigFloat::from(x)/BigFloat::from(y)}
+			&Complex32::from(x as f32), &InverseAngularVelocity{s_per_rad: Complex32::from(y as f32)}),
+				   AngularVelocity{radps: Complex32::from(x as f32)/Complex32::from(y as f32)}
 		);
 		assert_eq!(div_check(
-			&BigFloat::from(x), &Luminosity{cd: BigFloat::from(y)}),
-				   InverseLuminosity{per_cd: BigFloat::from(x)/BigFloat::from(y)}
+			&Complex64::from(x), &InverseAngularVelocity{s_per_rad: Complex64::from(y)}),
+				   AngularVelocity{radps: Complex64::from(x)/Complex64::from(y)}
 		);
 		assert_eq!(div_check(
-			&BigFloat::from(x), &Mass{kg: BigFloat::from(y)}),
-				   InverseMass{per_kg: BigFloat::from(x)/BigFloat::from(y)}
+			&Complex32::from(x as f32), &InverseEnergy{per_J: Complex32::from(y as f32)}),
+				   Energy{J: Complex32::from(x as f32)/Complex32::from(y as f32)}
 		);
 		assert_eq!(div_check(
-			&BigFloat::from(x), &Temperature{K: BigFloat::from(y)}),
-				   InverseTemperature{per_K: BigFloat::from(x)/BigFloat::from(y)}
+			&Complex64::from(x), &InverseEnergy{per_J: Complex64::from(y)}),
+				   Energy{J: Complex64::from(x)/Complex64::from(y)}
 		);
 		assert_eq!(div_check(
-			&BigFloat::from(x), &Amount{mol: BigFloat::from(y)}),
-				   InverseAmount{per_mol: BigFloat::from(x)/BigFloat::from(y)}
+			&Complex32::from(x as f32), &InverseForce{per_N: Complex32::from(y as f32)}),
+				   Force{N: Complex32::from(x as f32)/Complex32::from(y as f32)}
 		);
 		assert_eq!(div_check(
-			&BigFloat::from(x), &Current{A: BigFloat::from(y)}),
-				   InverseCurrent{per_A: BigFloat::from(x)/BigFloat::from(y)}
+			&Complex64::from(x), &InverseForce{per_N: Complex64::from(y)}),
+				   Force{N: Complex64::from(x)/Complex64::from(y)}
 		);
 		assert_eq!(div_check(
-			&BigFloat::from(x), &Distance{m: BigFloat::from(y)}),
-				   InverseDistance{per_m: BigFloat::from(x)/BigFloat::from(y)}
+			&Complex32::from(x as f32), &InverseMomentum{s_per_kgm: Complex32::from(y as f32)}),
+				   Momentum{kgmps: Complex32::from(x as f32)/Complex32::from(y as f32)}
 		);
 		assert_eq!(div_check(
-			&BigFloat::from(x), &InverseAmount{per_mol: BigFloat::from(y)}),
-				   Amount{mol: BigFloat::from(x)/BigFloat::from(y)}
+			&Complex64::from(x), &InverseMomentum{s_per_kgm: Complex64::from(y)}),
+				   Momentum{kgmps: Complex64::from(x)/Complex64::from(y)}
 		);
 		assert_eq!(div_check(
-			&BigFloat::from(x), &InverseCurrent{per_A: BigFloat::from(y)}),
-				   Current{A: BigFloat::from(x)/BigFloat::from(y)}
+			&Complex32::from(x as f32), &InversePower{per_W: Complex32::from(y as f32)}),
+				   Power{W: Complex32::from(x as f32)/Complex32::from(y as f32)}
 		);
 		assert_eq!(div_check(
-			&BigFloat::from(x), &InverseDistance{per_m: BigFloat::from(y)}),
-				   Distance{m: BigFloat::from(x)/BigFloat::from(y)}
+			&Complex64::from(x), &InversePower{per_W: Complex64::from(y)}),
+				   Power{W: Complex64::from(x)/Complex64::from(y)}
 		);
 		assert_eq!(div_check(
-			&BigFloat::from(x), &InverseLuminosity{per_cd: BigFloat::from(y)}),
-				   Luminosity{cd: BigFloat::from(x)/BigFloat::from(y)}
+			&Complex32::from(x as f32), &InversePressure{per_Pa: Complex32::from(y as f32)}),
+				   Pressure{Pa: Complex32::from(x as f32)/Complex32::from(y as f32)}
 		);
 		assert_eq!(div_check(
-			&BigFloat::from(x), &InverseMass{per_kg: BigFloat::from(y)}),
-				   Mass{kg: BigFloat::from(x)/BigFloat::from(y)}
+			&Complex64::from(x), &InversePressure{per_Pa: Complex64::from(y)}),
+				   Pressure{Pa: Complex64::from(x)/Complex64::from(y)}
 		);
 		assert_eq!(div_check(
-			&BigFloat::from(x), &InverseTemperature{per_K: BigFloat::from(y)}),
-				   Temperature{K: BigFloat::from(x)/BigFloat::from(y)}
+			&Complex32::from(x as f32), &InverseTorque{per_Nm: Complex32::from(y as f32)}),
+				   Energy{J: Complex32::from(x as f32)/Complex32::from(y as f32)}
 		);
 		assert_eq!(div_check(
-			&BigFloat::from(x), &Luminosity{cd: BigFloat::from(y)}),
-				   InverseLuminosity{per_cd: BigFloat::from(x)/BigFloat::from(y)}
+			&Complex64::from(x), &InverseTorque{per_Nm: Complex64::from(y)}),
+				   Energy{J: Complex64::from(x)/Complex64::from(y)}
 		);
 		assert_eq!(div_check(
-			&BigFloat::from(x), &Mass{kg: BigFloat::from(y)}),
-				   InverseMass{per_kg: BigFloat::from(x)/BigFloat::from(y)}
+			&Complex32::from(x as f32), &Momentum{kgmps: Complex32::from(y as f32)}),
+				   InverseMomentum{s_per_kgm: Complex32::from(x as f32)/Complex32::from(y as f32)}
 		);
 		assert_eq!(div_check(
-			&BigFloat::from(x), &Temperature{K: BigFloat::from(y)}),
-				   InverseTemperature{per_K: BigFloat::from(x)/BigFloat::from(y)}
+			&Complex64::from(x), &Momentum{kgmps: Complex64::from(y)}),
+				   InverseMomentum{s_per_kgm: Complex64::from(x)/Complex64::from(y)}
 		);
 		assert_eq!(div_check(
-			&BigFloat::from(x), &CatalyticActivity{molps: BigFloat::from(y)}),
-				   InverseCatalyticActivity{s_per_mol: BigFloat::from(x)/BigFloat::from(y)}
+			&Complex32::from(x as f32), &Power{W: Complex32::from(y as f32)}),
+				   InversePower{per_W: Complex32::from(x as f32)/Complex32::from(y as f32)}
 		);
 		assert_eq!(div_check(
-			&BigFloat::from(x), &Concentration{molpm3: BigFloat::from(y)}),
-				   MolarVolume{m3_per_mol: BigFloat::from(x)/BigFloat::from(y)}
+			&Complex64::from(x), &Power{W: Complex64::from(y)}),
+				   InversePower{per_W: Complex64::from(x)/Complex64::from(y)}
 		);
 		assert_eq!(div_check(
-			&BigFloat::from(x), &InverseCatalyticActivity{s_per_mol: BigFloat::from(y)}),
-				   CatalyticActivity{molps: BigFloat::from(x)/BigFloat::from(y)}
+			&Complex32::from(x as f32), &Pressure{Pa: Complex32::from(y as f32)}),
+				   InversePressure{per_Pa: Complex32::from(x as f32)/Complex32::from(y as f32)}
 		);
 		assert_eq!(div_check(
-			&BigFloat::from(x), &InverseSpecificHeatCapacity{kgK_per_J: BigFloat::from(y)}),
-				   SpecificHeatCapacity{J_per_kgK: BigFloat::from(x)/BigFloat::from(y)}
+			&Complex64::from(x), &Pressure{Pa: Complex64::from(y)}),
+				   InversePressure{per_Pa: Complex64::from(x)/Complex64::from(y)}
 		);
 		assert_eq!(div_check(
-			&BigFloat::from(x), &MolarVolume{m3_per_mol: BigFloat::from(y)}),
-				   Concentration{molpm3: BigFloat::from(x)/BigFloat::from(y)}
+			&Complex32::from(x as f32), &TimePerDistance{spm: Complex32::from(y as f32)}),
+				   Velocity{mps: Complex32::from(x as f32)/Complex32::from(y as f32)}
 		);
 		assert_eq!(div_check(
-			&BigFloat::from(x), &SpecificHeatCapacity{J_per_kgK: BigFloat::from(y)}),
-				   InverseSpecificHeatCapacity{kgK_per_J: BigFloat::from(x)/BigFloat::from(y)}
+			&Complex64::from(x), &TimePerDistance{spm: Complex64::from(y)}),
+				   Velocity{mps: Complex64::from(x)/Complex64::from(y)}
 		);
 		assert_eq!(div_check(
-			&BigFloat::from(x), &CatalyticActivity{molps: BigFloat::from(y)}),
-				   InverseCatalyticActivity{s_per_mol: BigFloat::from(x)/BigFloat::from(y)}
+			&Complex32::from(x as f32), &Torque{Nm: Complex32::from(y as f32)}),
+				   InverseEnergy{per_J: Complex32::from(x as f32)/Complex32::from(y as f32)}
 		);
 		assert_eq!(div_check(
-			&BigFloat::from(x), &Concentration{molpm3: BigFloat::from(y)}),
-				   MolarVolume{m3_per_mol: BigFloat::from(x)/BigFloat::from(y)}
+			&Complex64::from(x), &Torque{Nm: Complex64::from(y)}),
+				   InverseEnergy{per_J: Complex64::from(x)/Complex64::from(y)}
 		);
 		assert_eq!(div_check(
-			&BigFloat::from(x), &InverseCatalyticActivity{s_per_mol: BigFloat::from(y)}),
-				   CatalyticActivity{molps: BigFloat::from(x)/BigFloat::from(y)}
+			&Complex32::from(x as f32), &Velocity{mps: Complex32::from(y as f32)}),
+				   TimePerDistance{spm: Complex32::from(x as f32)/Complex32::from(y as f32)}
 		);
 		assert_eq!(div_check(
-			&BigFloat::from(x), &InverseSpecificHeatCapacity{kgK_per_J: BigFloat::from(y)}),
-				   SpecificHeatCapacity{J_per_kgK: BigFloat::from(x)/BigFloat::from(y)}
+			&Complex64::from(x), &Velocity{mps: Complex64::from(y)}),
+				   TimePerDistance{spm: Complex64::from(x)/Complex64::from(y)}
 		);
 		assert_eq!(div_check(
-			&BigFloat::from(x), &MolarVolume{m3_per_mol: BigFloat::from(y)}),
-				   Concentration{molpm3: BigFloat::from(x)/BigFloat::from(y)}
+			&Complex32::from(x as f32), &VolumePerMass{m3_per_kg: Complex32::from(y as f32)}),
+				   Density{kgpm3: Complex32::from(x as f32)/Complex32::from(y as f32)}
 		);
 		assert_eq!(div_check(
-			&BigFloat::from(x), &SpecificHeatCapacity{J_per_kgK: BigFloat::from(y)}),
-				   InverseSpecificHeatCapacity{kgK_per_J: BigFloat::from(x)/BigFloat::from(y)}
+			&Complex64::from(x), &VolumePerMass{m3_per_kg: Complex64::from(y)}),
+				   Density{kgpm3: Complex64::from(x)/Complex64::from(y)}
 		);
 		assert_eq!(div_check(
-			&BigFloat::from(x), &AreaPerLumen{m2_per_lm: BigFloat::from(y)}),
-				   Illuminance{lux: BigFloat::from(x)/BigFloat::from(y)}
+			&Complex32::from(x as f32), &TemperatureInterval{K: Complex32::from(y as f32)}),
+				   InverseTemperature{per_K: Complex32::from(x as f32)/Complex32::from(y as f32)}
 		);
 		assert_eq!(div_check(
-			&BigFloat::from(x), &Capacitance{F: BigFloat::from(y)}),
-				   Elastance{per_F: BigFloat::from(x)/BigFloat::from(y)}
+			&Complex64::from(x), &TemperatureInterval{K: Complex64::from(y)}),
+				   InverseTemperature{per_K: Complex64::from(x)/Complex64::from(y)}
 		);
 		assert_eq!(div_check(
-			&BigFloat::from(x), &Charge{C: BigFloat::from(y)}),
-				   InverseCharge{per_C: BigFloat::from(x)/BigFloat::from(y)}
+			&Complex32::from(x as f32), &InverseJerk{s3pm: Complex32::from(y as f32)}),
+				   Jerk{mps3: Complex32::from(x as f32)/Complex32::from(y as f32)}
 		);
 		assert_eq!(div_check(
-			&BigFloat::from(x), &Elastance{per_F: BigFloat::from(y)}),
-				   Capacitance{F: BigFloat::from(x)/BigFloat::from(y)}
+			&Complex64::from(x), &InverseJerk{s3pm: Complex64::from(y)}),
+				   Jerk{mps3: Complex64::from(x)/Complex64::from(y)}
 		);
 		assert_eq!(div_check(
-			&BigFloat::from(x), &Illuminance{lux: BigFloat::from(y)}),
-				   AreaPerLumen{m2_per_lm: BigFloat::from(x)/BigFloat::from(y)}
+			&Complex32::from(x as f32), &Jerk{mps3: Complex32::from(y as f32)}),
+				   InverseJerk{s3pm: Complex32::from(x as f32)/Complex32::from(y as f32)}
 		);
 		assert_eq!(div_check(
-			&BigFloat::from(x), &Inductance{H: BigFloat::from(y)}),
-				   InverseInductance{per_H: BigFloat::from(x)/BigFloat::from(y)}
+			&Complex64::from(x), &Jerk{mps3: Complex64::from(y)}),
+				   InverseJerk{s3pm: Complex64::from(x)/Complex64::from(y)}
 		);
 		assert_eq!(div_check(
-			&BigFloat::from(x), &InverseCharge{per_C: BigFloat::from(y)}),
-				   Charge{C: BigFloat::from(x)/BigFloat::from(y)}
+			&Complex32::from(x as f32), &DynamicViscosity{Pas: Complex32::from(y as f32)}),
+				   InverseDynamicViscosity{per_Pas: Complex32::from(x as f32)/Complex32::from(y as f32)}
 		);
 		assert_eq!(div_check(
-			&BigFloat::from(x), &InverseInductance{per_H: BigFloat::from(y)}),
-				   Inductance{H: BigFloat::from(x)/BigFloat::from(y)}
+			&Complex64::from(x), &DynamicViscosity{Pas: Complex64::from(y)}),
+				   InverseDynamicViscosity{per_Pas: Complex64::from(x)/Complex64::from(y)}
 		);
 		assert_eq!(div_check(
-			&BigFloat::from(x), &InverseLuminousFlux{per_lm: BigFloat::from(y)}),
-				   LuminousFlux{lm: BigFloat::from(x)/BigFloat::from(y)}
+			&Complex32::from(x as f32), &InverseDynamicViscosity{per_Pas: Complex32::from(y as f32)}),
+				   DynamicViscosity{Pas: Complex32::from(x as f32)/Complex32::from(y as f32)}
 		);
 		assert_eq!(div_check(
-			&BigFloat::from(x), &InverseMagneticFlux{per_Wb: BigFloat::from(y)}),
-				   MagneticFlux{Wb: BigFloat::from(x)/BigFloat::from(y)}
+			&Complex64::from(x), &InverseDynamicViscosity{per_Pas: Complex64::from(y)}),
+				   DynamicViscosity{Pas: Complex64::from(x)/Complex64::from(y)}
+		);
+		assert_eq!(div_check(
+			&Complex32::from(x as f32), &InverseKinematicViscosity{s_per_m2: Complex32::from(y as f32)}),
+				   KinematicViscosity{m2ps: Complex32::from(x as f32)/Complex32::from(y as f32)}
+		);
+		assert_eq!(div_check(
+			&Complex64::from(x), &InverseKinematicViscosity{s_per_m2: Complex64::from(y)}),
+				   KinematicViscosity{m2ps: Complex64::from(x)/Complex64::from(y)}
+		);
+		assert_eq!(div_check(
+			&Complex32::from(x as f32), &KinematicViscosity{m2ps: Complex32::from(y as f32)}),
+				   InverseKinematicViscosity{s_per_m2: Complex32::from(x as f32)/Complex32::from(y as f32)}
+		);
+		assert_eq!(div_check(
+			&Complex64::from(x), &KinematicViscosity{m2ps: Complex64::from(y)}),
+				   InverseKinematicViscosity{s_per_m2: Complex64::from(x)/Complex64::from(y)}
+		);
+		assert_eq!(div_check(
+			&Complex32::from(x as f32), &InverseMassFlowRate{s_per_kg: Complex32::from(y as f32)}),
+				   MassFlowRate{kgps: Complex32::from(x as f32)/Complex32::from(y as f32)}
+		);
+		assert_eq!(div_check(
+			&Complex64::from(x), &InverseMassFlowRate{s_per_kg: Complex64::from(y)}),
+				   MassFlowRate{kgps: Complex64::from(x)/Complex64::from(y)}
+		);
+		assert_eq!(div_check(
+			&Complex32::from(x as f32), &InverseVolumetricFlowRate{s_per_m3: Complex32::from(y as f32)}),
+				   VolumetricFlowRate{m3ps: Complex32::from(x as f32)/Complex32::from(y as f32)}
+		);
+		assert_eq!(div_check(
+			&Complex64::from(x), &InverseVolumetricFlowRate{s_per_m3: Complex64::from(y)}),
+				   VolumetricFlowRate{m3ps: Complex64::from(x)/Complex64::from(y)}
+		);
+		assert_eq!(div_check(
+			&Complex32::from(x as f32), &MassFlowRate{kgps: Complex32::from(y as f32)}),
+				   InverseMassFlowRate{s_per_kg: Complex32::from(x as f32)/Complex32::from(y as f32)}
+		);
+		assert_eq!(div_check(
+			&Complex64::from(x), &MassFlowRate{kgps: Complex64::from(y)}),
+				   InverseMassFlowRate{s_per_kg: Complex64::from(x)/Complex64::from(y)}
+		);
+		assert_eq!(div_check(
+			&Complex32::from(x as f32), &VolumetricFlowRate{m3ps: Complex32::from(y as f32)}),
+				   InverseVolumetricFlowRate{s_per_m3: Complex32::from(x as f32)/Complex32::from(y as f32)}
+		);
+		assert_eq!(div_check(
+			&Complex64::from(x), &VolumetricFlowRate{m3ps: Complex64::from(y)}),
+				   InverseVolumetricFlowRate{s_per_m3: Complex64::from(x)/Complex64::from(y)}
+		);
+		assert_eq!(div_check(
+			&Complex32::from(x as f32), &Entropy{J_per_K: Complex32::from(y as f32)}),
+				   InverseHeatCapacity{K_per_J: Complex32::from(x as f32)/Complex32::from(y as f32)}
+		);
+		assert_eq!(div_check(
+			&Complex64::from(x), &Entropy{J_per_K: Complex64::from(y)}),
+				   InverseHeatCapacity{K_per_J: Complex64::from(x)/Complex64::from(y)}
+		);
+		assert_eq!(div_check(
+			&Complex32::from(x as f32), &HeatCapacity{J_per_K: Complex32::from(y as f32)}),
+				   InverseHeatCapacity{K_per_J: Complex32::from(x as f32)/Complex32::from(y as f32)}
+		);
+		assert_eq!(div_check(
+			&Complex64::from(x), &HeatCapacity{J_per_K: Complex64::from(y)}),
+				   InverseHeatCapacity{K_per_J: Complex64::from(x)/Complex64::from(y)}
 		);
 		assert_eq!(div_check(
-			&BigFloat::from(x), &InverseMagneticFluxDensity{m2_per_Wb: BigFloat::from(y)}),
-				   MagneticFluxDensity{T: BigFloat::from(x)/BigFloat::from(y)}
+			&Complex32::from(x as f32), &InverseEntropy{K_per_J: Complex32::from(y as f32)}),
+				   HeatCapacity{J_per_K: Complex32::from(x as f32)/Complex32::from(y as f32)}
 		);
 		assert_eq!(div_check(
-			&BigFloat::from(x), &InverseVoltage{per_V: BigFloat::from(y)}),
-				   Voltage{V: BigFloat::from(x)/BigFloat::from(y)}
+			&Complex64::from(x), &InverseEntropy{K_per_J: Complex64::from(y)}),
+				   HeatCapacity{J_per_K: Complex64::from(x)/Complex64::from(y)}
 		);
 		assert_eq!(div_check(
-			&BigFloat::from(x), &LuminousFlux{lm: BigFloat::from(y)}),
-				   InverseLuminousFlux{per_lm: BigFloat::from(x)/BigFloat::from(y)}
+			&Complex32::from(x as f32), &InverseHeatCapacity{K_per_J: Complex32::from(y as f32)}),
+				   HeatCapacity{J_per_K: Complex32::from(x as f32)/Complex32::from(y as f32)}
 		);
 		assert_eq!(div_check(
-			&BigFloat::from(x), &MagneticFlux{Wb: BigFloat::from(y)}),
-				   InverseMagneticFlux{per_Wb: BigFloat::from(x)/BigFloat::from(y)}
+			&Complex64::from(x), &InverseHeatCapacity{K_per_J: Complex64::from(y)}),
+				   HeatCapacity{J_per_K: Complex64::from(x)/Complex64::from(y)}
 		);
 		assert_eq!(div_check(
-			&BigFloat::from(x), &MagneticFluxDensity{T: BigFloat::from(y)}),
-				   InverseMagneticFluxDensity{m2_per_Wb: BigFloat::from(x)/BigFloat::from(y)}
+			&Complex32::from(x as f32), &ThermalConductance{W_per_K: Complex32::from(y as f32)}),
+				   ThermalResistance{K_per_W: Complex32::from(x as f32)/Complex32::from(y as f32)}
 		);
 		assert_eq!(div_check(
-			&BigFloat::from(x), &Voltage{V: BigFloat::from(y)}),
-				   InverseVoltage{per_V: BigFloat::from(x)/BigFloat::from(y)}
+			&Complex64::from(x), &ThermalConductance{W_per_K: Complex64::from(y)}),
+				   ThermalResistance{K_per_W: Complex64::from(x)/Complex64::from(y)}
 		);
 		assert_eq!(div_check(
-			&BigFloat::from(x), &AreaPerLumen{m2_per_lm: BigFloat::from(y)}),
-				   Illuminance{lux: BigFloat::from(x)/BigFloat::from(y)}
+			&Complex32::from(x as f32), &ThermalConductivity{W_per_mK: Complex32::from(y as f32)}),
+				   ThermalResistivity{mK_per_W: Complex32::from(x as f32)/Complex32::from(y as f32)}
 		);
 		assert_eq!(div_check(
-			&BigFloat::from(x), &Capacitance{F: BigFloat::from(y)}),
-				   Elastance{per_F: BigFloat::from(x)/BigFloat::from(y)}
+			&Complex64::from(x), &ThermalConductivity{W_per_mK: Complex64::from(y)}),
+				   ThermalResistivity{mK_per_W: Complex64::from(x)/Complex64::from(y)}
 		);
 		assert_eq!(div_check(
-			&BigFloat::from(x), &Charge{C: BigFloat::from(y)}),
-				   InverseCharge{per_C: BigFloat::from(x)/BigFloat::from(y)}
+			&Complex32::from(x as f32), &ThermalResistance{K_per_W: Complex32::from(y as f32)}),
+				   ThermalConductance{W_per_K: Complex32::from(x as f32)/Complex32::from(y as f32)}
 		);
 		assert_eq!(div_check(
-			&BigFloat::from(x), &Elastance{per_F: BigFloat::from(y)}),
-				   Capacitance{F: BigFloat::from(x)/BigFloat::from(y)}
+			&Complex64::from(x), &ThermalResistance{K_per_W: Complex64::from(y)}),
+				   ThermalConductance{W_per_K: Complex64::from(x)/Complex64::from(y)}
 		);
 		assert_eq!(div_check(
-			&BigFloat::from(x), &Illuminance{lux: BigFloat::from(y)}),
-				   AreaPerLumen{m2_per_lm: BigFloat::from(x)/BigFloat::from(y)}
+			&Complex32::from(x as f32), &ThermalResistivity{mK_per_W: Complex32::from(y as f32)}),
+				   ThermalConductivity{W_per_mK: Complex32::from(x as f32)/Complex32::from(y as f32)}
 		);
 		assert_eq!(div_check(
-			&BigFloat::from(x), &Inductance{H: BigFloat::from(y)}),
-				   InverseInductance{per_H: BigFloat::from(x)/BigFloat::from(y)}
+			&Complex64::from(x), &ThermalResistivity{mK_per_W: Complex64::from(y)}),
+				   ThermalConductivity{W_per_mK: Complex64::from(x)/Complex64::from(y)}
 		);
 		assert_eq!(div_check(
-			&BigFloat::from(x), &InverseCharge{per_C: BigFloat::from(y)}),
-				   Charge{C: BigFloat::from(x)/BigFloat::from(y)}
+			&Complex32::from(x as f32), &ElectricField{Vpm: Complex32::from(y as f32)}),
+				   InverseElectricField{m_per_V: Complex32::from(x as f32)/Complex32::from(y as f32)}
 		);
 		assert_eq!(div_check(
-			&BigFloat::from(x), &InverseInductance{per_H: BigFloat::from(y)}),
-				   Inductance{H: BigFloat::from(x)/BigFloat::from(y)}
+			&Complex64::from(x), &ElectricField{Vpm: Complex64::from(y)}),
+				   InverseElectricField{m_per_V: Complex64::from(x)/Complex64::from(y)}
 		);
 		assert_eq!(div_check(
-			&BigFloat::from(x), &InverseLuminousFlux{per_lm: BigFloat::from(y)}),
-				   LuminousFlux{lm: BigFloat::from(x)/BigFloat::from(y)}
+			&Complex32::from(x as f32), &InverseElectricField{m_per_V: Complex32::from(y as f32)}),
+				   ElectricField{Vpm: Complex32::from(x as f32)/Complex32::from(y as f32)}
 		);
 		assert_eq!(div_check(
-			&BigFloat::from(x), &InverseMagneticFlux{per_Wb: BigFloat::from(y)}),
-				   MagneticFlux{Wb: BigFloat::from(x)/BigFloat::from(y)}
+			&Complex64::from(x), &InverseElectricField{m_per_V: Complex64::from(y)}),
+				   ElectricField{Vpm: Complex64::from(x)/Complex64::from(y)}
 		);
 		assert_eq!(div_check(
-			&BigFloat::from(x), &InverseMagneticFluxDensity{m2_per_Wb: BigFloat::from(y)}),
-				   MagneticFluxDensity{T: BigFloat::from(x)/BigFloat::from(y)}
+			&Complex32::from(x as f32), &InverseMagneticFieldStrength{m_per_A: Complex32::from(y as f32)}),
+				   MagneticFieldStrength{Apm: Complex32::from(x as f32)/Complex32::from(y as f32)}
 		);
 		assert_eq!(div_check(
-			&BigFloat::from(x), &InverseVoltage{per_V: BigFloat::from(y)}),
-				   Voltage{V: BigFloat::from(x)/BigFloat::from(y)}
+			&Complex64::from(x), &InverseMagneticFieldStrength{m_per_A: Complex64::from(y)}),
+				   MagneticFieldStrength{Apm: Complex64::from(x)/Complex64::from(y)}
 		);
 		assert_eq!(div_check(
-			&BigFloat::from(x), &LuminousFlux{lm: BigFloat::from(y)}),
-				   InverseLuminousFlux{per_lm: BigFloat::from(x)/BigFloat::from(y)}
+			&Complex32::from(x as f32), &MagneticFieldStrength{Apm: Complex32::from(y as f32)}),
+				   InverseMagneticFieldStrength{m_per_A: Complex32::from(x as f32)/Complex32::from(y as f32)}
 		);
 		assert_eq!(div_check(
-			&BigFloat::from(x), &MagneticFlux{Wb: BigFloat::from(y)}),
-				   InverseMagneticFlux{per_Wb: BigFloat::from(x)/BigFloat::from(y)}
+			&Complex64::from(x), &MagneticFieldStrength{Apm: Complex64::from(y)}),
+				   InverseMagneticFieldStrength{m_per_A: Complex64::from(x)/Complex64::from(y)}
 		);
 		assert_eq!(div_check(
-			&BigFloat::from(x), &MagneticFluxDensity{T: BigFloat::from(y)}),
-				   InverseMagneticFluxDensity{m2_per_Wb: BigFloat::from(x)/BigFloat::from(y)}
+			&Complex32::from(x as f32), &InverseLinearChargeDensity{m_per_C: Complex32::from(y as f32)}),
+				   LinearChargeDensity{Cpm: Complex32::from(x as f32)/Complex32::from(y as f32)}
 		);
 		assert_eq!(div_check(
-			&BigFloat::from(x), &Voltage{V: BigFloat::from(y)}),
-				   InverseVoltage{per_V: BigFloat::from(x)/BigFloat::from(y)}
+			&Complex64::from(x), &InverseLinearChargeDensity{m_per_C: Complex64::from(y)}),
+				   LinearChargeDensity{Cpm: Complex64::from(x)/Complex64::from(y)}
 		);
 		assert_eq!(div_check(
-			&BigFloat::from(x), &Angle{rad: BigFloat::from(y)}),
-				   InverseAngle{per_rad: BigFloat::from(x)/BigFloat::from(y)}
+			&Complex32::from(x as f32), &InverseSurfaceChargeDensity{m2_per_C: Complex32::from(y as f32)}),
+				   SurfaceChargeDensity{Cpm2: Complex32::from(x as f32)/Complex32::from(y as f32)}
 		);
 		assert_eq!(div_check(
-			&BigFloat::from(x), &Area{m2: BigFloat::from(y)}),
-				   InverseArea{per_m2: BigFloat::from(x)/BigFloat::from(y)}
+			&Complex64::from(x), &InverseSurfaceChargeDensity{m2_per_C: Complex64::from(y)}),
+				   SurfaceChargeDensity{Cpm2: Complex64::from(x)/Complex64::from(y)}
 		);
 		assert_eq!(div_check(
-			&BigFloat::from(x), &InverseAngle{per_rad: BigFloat::from(y)}),
-				   Angle{rad: BigFloat::from(x)/BigFloat::from(y)}
+			&Complex32::from(x as f32), &InverseVolumeChargeDensity{m3_per_C: Complex32::from(y as f32)}),
+				   VolumeChargeDensity{Cpm3: Complex32::from(x as f32)/Complex32::from(y as f32)}
 		);
 		assert_eq!(div_check(
-			&BigFloat::from(x), &InverseArea{per_m2: BigFloat::from(y)}),
-				   Area{m2: BigFloat::from(x)/BigFloat::from(y)}
+			&Complex64::from(x), &InverseVolumeChargeDensity{m3_per_C: Complex64::from(y)}),
+				   VolumeChargeDensity{Cpm3: Complex64::from(x)/Complex64::from(y)}
 		);
 		assert_eq!(div_check(
-			&BigFloat::from(x), &InverseSolidAngle{per_sr: BigFloat::from(y)}),
-				   SolidAngle{sr: BigFloat::from(x)/BigFloat::from(y)}
+			&Complex32::from(x as f32), &LinearChargeDensity{Cpm: Complex32::from(y as f32)}),
+				   InverseLinearChargeDensity{m_per_C: Complex32::from(x as f32)/Complex32::from(y as f32)}
 		);
 		assert_eq!(div_check(
-			&BigFloat::from(x), &InverseVolume{per_m3: BigFloat::from(y)}),
-				   Volume{m3: BigFloat::from(x)/BigFloat::from(y)}
+			&Complex64::from(x), &LinearChargeDensity{Cpm: Complex64::from(y)}),
+				   InverseLinearChargeDensity{m_per_C: Complex64::from(x)/Complex64::from(y)}
 		);
 		assert_eq!(div_check(
-			&BigFloat::from(x), &SolidAngle{sr: BigFloat::from(y)}),
-				   InverseSolidAngle{per_sr: BigFloat::from(x)/BigFloat::from(y)}
+			&Complex32::from(x as f32), &SurfaceChargeDensity{Cpm2: Complex32::from(y as f32)}),
+				   InverseSurfaceChargeDensity{m2_per_C: Complex32::from(x as f32)/Complex32::from(y as f32)}
 		);
 		assert_eq!(div_check(
-			&BigFloat::from(x), &Volume{m3: BigFloat::from(y)}),
-				   InverseVolume{per_m3: BigFloat::from(x)/BigFloat::from(y)}
+			&Complex64::from(x), &SurfaceChargeDensity{Cpm2: Complex64::from(y)}),
+				   InverseSurfaceChargeDensity{m2_per_C: Complex64::from(x)/Complex64::from(y)}
 		);
 		assert_eq!(div_check(
-			&BigFloat::from(x), &Angle{rad: BigFloat::from(y)}),
-				   InverseAngle{per_rad: BigFloat::from(x)/BigFloat::from(y)}
+			&Complex32::from(x as f32), &VolumeChargeDensity{Cpm3: Complex32::from(y as f32)}),
+				   InverseVolumeChargeDensity{m3_per_C: Complex32::from(x as f32)/Complex32::from(y as f32)}
 		);
 		assert_eq!(div_check(
-			&BigFloat::from(x), &Area{m2: BigFloat::from(y)}),
-				   InverseArea{per_m2: BigFloat::from(x)/BigFloat::from(y)}
+			&Complex64::from(x), &VolumeChargeDensity{Cpm3: Complex64::from(y)}),
+				   InverseVolumeChargeDensity{m3_per_C: Complex64::from(x)/Complex64::from(y)}
 		);
 		assert_eq!(div_check(
-			&BigFloat::from(x), &InverseAngle{per_rad: BigFloat::from(y)}),
-				   Angle{rad: BigFloat::from(x)/BigFloat::from(y)}
+			&Complex32::from(x as f32), &InverseIrradiance{m2_per_W: Complex32::from(y as f32)}),
+				   Irradiance{W_per_m2: Complex32::from(x as f32)/Complex32::from(y as f32)}
 		);
 		assert_eq!(div_check(
-			&BigFloat::from(x), &InverseArea{per_m2: BigFloat::from(y)}),
-				   Area{m2: BigFloat::from(x)/BigFloat::from(y)}
+			&Complex64::from(x), &InverseIrradiance{m2_per_W: Complex64::from(y)}),
+				   Irradiance{W_per_m2: Complex64::from(x)/Complex64::from(y)}
 		);
 		assert_eq!(div_check(
-			&BigFloat::from(x), &InverseSolidAngle{per_sr: BigFloat::from(y)}),
-				   SolidAngle{sr: BigFloat::from(x)/BigFloat::from(y)}
+			&Complex32::from(x as f32), &InverseRadiance{m2sr_per_W: Complex32::from(y as f32)}),
+				   Radiance{W_per_m2sr: Complex32::from(x as f32)/Complex32::from(y as f32)}
 		);
 		assert_eq!(div_check(
-			&BigFloat::from(x), &InverseVolume{per_m3: BigFloat::from(y)}),
-				   Volume{m3: BigFloat::from(x)/BigFloat::from(y)}
+			&Complex64::from(x), &InverseRadiance{m2sr_per_W: Complex64::from(y)}),
+				   Radiance{W_per_m2sr: Complex64::from(x)/Complex64::from(y)}
 		);
 		assert_eq!(div_check(
-			&BigFloat::from(x), &SolidAngle{sr: BigFloat::from(y)}),
-				   InverseSolidAngle{per_sr: BigFloat::from(x)/BigFloat::from(y)}
+			&Complex32::from(x as f32), &Irradiance{W_per_m2: Complex32::from(y as f32)}),
+				   InverseIrradiance{m2_per_W: Complex32::from(x as f32)/Complex32::from(y as f32)}
 		);
 		assert_eq!(div_check(
-			&BigFloat::from(x), &Volume{m3: BigFloat::from(y)}),
-				   InverseVolume{per_m3: BigFloat::from(x)/BigFloat::from(y)}
+			&Complex64::from(x), &Irradiance{W_per_m2: Complex64::from(y)}),
+				   InverseIrradiance{m2_per_W: Complex64::from(x)/Complex64::from(y)}
 		);
 		assert_eq!(div_check(
-			&BigFloat::from(x), &Acceleration{mps2: BigFloat::from(y)}),
-				   InverseAcceleration{s2pm: BigFloat::from(x)/BigFloat::from(y)}
+			&Complex32::from(x as f32), &Radiance{W_per_m2sr: Complex32::from(y as f32)}),
+				   InverseRadiance{m2sr_per_W: Complex32::from(x as f32)/Complex32::from(y as f32)}
 		);
 		assert_eq!(div_check(
-			&BigFloat::from(x), &AngularAcceleration{radps2: BigFloat::from(y)}),
-				   InverseAngularAcceleration{s2prad: BigFloat::from(x)/BigFloat::from(y)}
+			&Complex64::from(x), &Radiance{W_per_m2sr: Complex64::from(y)}),
+				   InverseRadiance{m2sr_per_W: Complex64::from(x)/Complex64::from(y)}
 		);
 		assert_eq!(div_check(
-			&BigFloat::from(x), &AngularMomentum{kgm2radps: BigFloat::from(y)}),
-				   InverseAngularMomentum{s_per_kgm2rad: BigFloat::from(x)/BigFloat::from(y)}
+			&Complex32::from(x as f32), &InverseMolarEnergy{mol_per_J: Complex32::from(y as f32)}),
+				   MolarEnergy{J_per_mol: Complex32::from(x as f32)/Complex32::from(y as f32)}
 		);
 		assert_eq!(div_check(
-			&BigFloat::from(x), &AngularVelocity{radps: BigFloat::from(y)}),
-				   InverseAngularVelocity{s_per_rad: BigFloat::from(x)/BigFloat::from(y)}
+			&Complex64::from(x), &InverseMolarEnergy{mol_per_J: Complex64::from(y)}),
+				   MolarEnergy{J_per_mol: Complex64::from(x)/Complex64::from(y)}
 		);
 		assert_eq!(div_check(
-			&BigFloat::from(x), &AreaDensity{kgpm2: BigFloat::from(y)}),
-				   AreaPerMass{m2_per_kg: BigFloat::from(x)/BigFloat::from(y)}
+			&Complex32::from(x as f32), &InverseMolarEntropy{molK_per_J: Complex32::from(y as f32)}),
+				   MolarEntropy{J_per_molK: Complex32::from(x as f32)/Complex32::from(y as f32)}
 		);
 		assert_eq!(div_check(
-			&BigFloat::from(x), &AreaPerMass{m2_per_kg: BigFloat::from(y)}),
-				   AreaDensity{kgpm2: BigFloat::from(x)/BigFloat::from(y)}
+			&Complex64::from(x), &InverseMolarEntropy{molK_per_J: Complex64::from(y)}),
+				   MolarEntropy{J_per_molK: Complex64::from(x)/Complex64::from(y)}
 		);
 		assert_eq!(div_check(
-			&BigFloat::from(x), &Density{kgpm3: BigFloat::from(y)}),
-				   VolumePerMass{m3_per_kg: BigFloat::from(x)/BigFloat::from(y)}
+			&Complex32::from(x as f32), &MolarEnergy{J_per_mol: Complex32::from(y as f32)}),
+				   InverseMolarEnergy{mol_per_J: Complex32::from(x as f32)/Complex32::from(y as f32)}
 		);
 		assert_eq!(div_check(
-			&BigFloat::from(x), &Energy{J: BigFloat::from(y)}),
-				   InverseEnergy{per_J: BigFloat::from(x)/BigFloat::from(y)}
+			&Complex64::from(x), &MolarEnergy{J_per_mol: Complex64::from(y)}),
+				   InverseMolarEnergy{mol_per_J: Complex64::from(x)/Complex64::from(y)}
 		);
 		assert_eq!(div_check(
-			&BigFloat::from(x), &Force{N: BigFloat::from(y)}),
-				   InverseForce{per_N: BigFloat::from(x)/BigFloat::from(y)}
+			&Complex32::from(x as f32), &MolarEntropy{J_per_molK: Complex32::from(y as f32)}),
+				   InverseMolarEntropy{molK_per_J: Complex32::from(x as f32)/Complex32::from(y as f32)}
 		);
 		assert_eq!(div_check(
-			&BigFloat::from(x), &InverseAcceleration{s2pm: BigFloat::from(y)}),
-				   Acceleration{mps2: BigFloat::from(x)/BigFloat::from(y)}
+			&Complex64::from(x), &MolarEntropy{J_per_molK: Complex64::from(y)}),
+				   InverseMolarEntropy{molK_per_J: Complex64::from(x)/Complex64::from(y)}
 		);
 		assert_eq!(div_check(
-			&BigFloat::from(x), &InverseAngularAcceleration{s2prad: BigFloat::from(y)}),
-				   AngularAcceleration{radps2: BigFloat::from(x)/BigFloat::from(y)}
+			&Complex32::from(x as f32), &InverseMomentOfInertia{per_kgm2: Complex32::from(y as f32)}),
+				   MomentOfInertia{kgm2: Complex32::from(x as f32)/Complex32::from(y as f32)}
 		);
 		assert_eq!(div_check(
-			&BigFloat::from(x), &InverseAngularMomentum{s_per_kgm2rad: BigFloat::from(y)}),
-				   AngularMomentum{kgm2radps: BigFloat::from(x)/BigFloat::from(y)}
+			&Complex64::from(x), &InverseMomentOfInertia{per_kgm2: Complex64::from(y)}),
+				   MomentOfInertia{kgm2: Complex64::from(x)/Complex64::from(y)}
 		);
 		assert_eq!(div_check(
-			&BigFloat::from(x), &InverseAngularVelocity{s_per_rad: BigFloat::from(y)}),
-				   AngularVelocity{radps: BigFloat::from(x)/BigFloat::from(y)}
+			&Complex32::from(x as f32), &MomentOfInertia{kgm2: Complex32::from(y as f32)}),
+				   InverseMomentOfInertia{per_kgm2: Complex32::from(x as f32)/Complex32::from(y as f32)}
 		);
 		assert_eq!(div_check(
-			&BigFloat::from(x), &InverseEnergy{per_J: BigFloat::from(y)}),
-				   Energy{J: BigFloat::from(x)/BigFloat::from(y)}
+			&Complex64::from(x), &MomentOfInertia{kgm2: Complex64::from(y)}),
+				   InverseMomentOfInertia{per_kgm2: Complex64::from(x)/Complex64::from(y)}
 		);
 		assert_eq!(div_check(
-			&BigFloat::from(x), &InverseForce{per_N: BigFloat::from(y)}),
-				   Force{N: BigFloat::from(x)/BigFloat::from(y)}
+			&Complex32::from(x as f32), &AbsorbedDose{Gy: Complex32::from(y as f32)}),
+				   InverseAbsorbedDose{per_Gy: Complex32::from(x as f32)/Complex32::from(y as f32)}
 		);
 		assert_eq!(div_check(
-			&BigFloat::from(x), &InverseMomentum{s_per_kgm: BigFloat::from(y)}),
-				   Momentum{kgmps: BigFloat::from(x)/BigFloat::from(y)}
+			&Complex64::from(x), &AbsorbedDose{Gy: Complex64::from(y)}),
+				   InverseAbsorbedDose{per_Gy: Complex64::from(x)/Complex64::from(y)}
 		);
 		assert_eq!(div_check(
-			&BigFloat::from(x), &InversePower{per_W: BigFloat::from(y)}),
-				   Power{W: BigFloat::from(x)/BigFloat::from(y)}
+			&Complex32::from(x as f32), &DoseEquivalent{Sv: Complex32::from(y as f32)}),
+				   InverseDoseEquivalent{per_Sv: Complex32::from(x as f32)/Complex32::from(y as f32)}
 		);
 		assert_eq!(div_check(
-			&BigFloat::from(x), &InversePressure{per_Pa: BigFloat::from(y)}),
-				   Pressure{Pa: BigFloat::from(x)/BigFloat::from(y)}
+			&Complex64::from(x), &DoseEquivalent{Sv: Complex64::from(y)}),
+				   InverseDoseEquivalent{per_Sv: Complex64::from(x)/Complex64::from(y)}
 		);
 		assert_eq!(div_check(
-			&BigFloat::from(x), &InverseTorque{per_Nm: BigFloat::from(y)}),
-				   Energy{J: BigFloat::from(x)/BigFloat::from(y)}
+			&Complex32::from(x as f32), &InverseAbsorbedDose{per_Gy: Complex32::from(y as f32)}),
+				   AbsorbedDose{Gy: Complex32::from(x as f32)/Complex32::from(y as f32)}
 		);
 		assert_eq!(div_check(
-			&BigFloat::from(x), &Momentum{kgmps: BigFloat::from(y)}),
-				   InverseMomentum{s_per_kgm: BigFloat::from(x)/BigFloat::from(y)}
+			&Complex64::from(x), &InverseAbsorbedDose{per_Gy: Complex64::from(y)}),
+				   AbsorbedDose{Gy: Complex64::from(x)/Complex64::from(y)}
 		);
 		assert_eq!(div_check(
-			&BigFloat::from(x), &Power{W: BigFloat::from(y)}),
-				   InversePower{per_W: BigFloat::from(x)/BigFloat::from(y)}
+			&Complex32::from(x as f32), &InverseDoseEquivalent{per_Sv: Complex32::from(y as f32)}),
+				   DoseEquivalent{Sv: Complex32::from(x as f32)/Complex32::from(y as f32)}
 		);
 		assert_eq!(div_check(
-			&BigFloat::from(x), &Pressure{Pa: BigFloat::from(y)}),
-				   InversePressure{per_Pa: BigFloat::from(x)/BigFloat::from(y)}
+			&Complex64::from(x), &InverseDoseEquivalent{per_Sv: Complex64::from(y)}),
+				   DoseEquivalent{Sv: Complex64::from(x)/Complex64::from(y)}
 		);
 		assert_eq!(div_check(
-			&BigFloat::from(x), &TimePerDistance{spm: BigFloat::from(y)}),
-				   Velocity{mps: BigFloat::from(x)/BigFloat::from(y)}
+			&Complex32::from(x as f32), &AbsorbedDoseRate{Gy_per_s: Complex32::from(y as f32)}),
+				   InverseAbsorbedDoseRate{s_per_Gy: Complex32::from(x as f32)/Complex32::from(y as f32)}
 		);
 		assert_eq!(div_check(
-			&BigFloat::from(x), &Torque{Nm: BigFloat::from(y)}),
-				   InverseEnergy{per_J: BigFloat::from(x)/BigFloat::from(y)}
+			&Complex64::from(x), &AbsorbedDoseRate{Gy_per_s: Complex64::from(y)}),
+				   InverseAbsorbedDoseRate{s_per_Gy: Complex64::from(x)/Complex64::from(y)}
 		);
 		assert_eq!(div_check(
-			&BigFloat::from(x), &Velocity{mps: BigFloat::from(y)}),
-				   TimePerDistance{spm: BigFloat::from(x)/BigFloat::from(y)}
+			&Complex32::from(x as f32), &DoseEquivalentRate{Sv_per_s: Complex32::from(y as f32)}),
+				   InverseDoseEquivalentRate{s_per_Sv: Complex32::from(x as f32)/Complex32::from(y as f32)}
 		);
 		assert_eq!(div_check(
-			&BigFloat::from(x), &VolumePerMass{m3_per_kg: BigFloat::from(y)}),
-				   Density{kgpm3: BigFloat::from(x)/BigFloat::from(y)}
+			&Complex64::from(x), &DoseEquivalentRate{Sv_per_s: Complex64::from(y)}),
+				   InverseDoseEquivalentRate{s_per_Sv: Complex64::from(x)/Complex64::from(y)}
 		);
 		assert_eq!(div_check(
-			&BigFloat::from(x), &Acceleration{mps2: BigFloat::from(y)}),
-				   InverseAcceleration{s2pm: BigFloat::from(x)/BigFloat::from(y)}
+			&Complex32::from(x as f32), &InverseAbsorbedDoseRate{s_per_Gy: Complex32::from(y as f32)}),
+				   AbsorbedDoseRate{Gy_per_s: Complex32::from(x as f32)/Complex32::from(y as f32)}
 		);
 		assert_eq!(div_check(
-			&BigFloat::from(x), &AngularAcceleration{radps2: BigFloat::from(y)}),
-				   InverseAngularAcceleration{s2prad: BigFloat::from(x)/BigFloat::from(y)}
+			&Complex64::from(x), &InverseAbsorbedDoseRate{s_per_Gy: Complex64::from(y)}),
+				   AbsorbedDoseRate{Gy_per_s: Complex64::from(x)/Complex64::from(y)}
 		);
 		assert_eq!(div_check(
-			&BigFloat::from(x), &AngularMomentum{kgm2radps: BigFloat::from(y)}),
-				   InverseAngularMomentum{s_per_kgm2rad: BigFloat::from(x)/BigFloat::from(y)}
+			&Complex32::from(x as f32), &InverseDoseEquivalentRate{s_per_Sv: Complex32::from(y as f32)}),
+				   DoseEquivalentRate{Sv_per_s: Complex32::from(x as f32)/Complex32::from(y as f32)}
 		);
 		assert_eq!(div_check(
-			&BigFloat::from(x), &AngularVelocity{radps: BigFloat::from(y)}),
-				   InverseAngularVelocity{s_per_rad: BigFloat::from(x)/BigFloat::from(y)}
+			&Complex64::from(x), &InverseDoseEquivalentRate{s_per_Sv: Complex64::from(y)}),
+				   DoseEquivalentRate{Sv_per_s: Complex64::from(x)/Complex64::from(y)}
+		);
+	}
+
+	#[test]
+	fn amount_units() {
+		assert_approx_equal(
+			Amount::from_mol(1.66053906717385e-24_f64).to_mol(),
+			Amount::from_count(1.0_f64).to_mol(), 9
+		);
+		assert_approx_equal(
+			Amount::from_mol(1.0_f64).to_mol() * 6.02214076e+23,
+			Amount::from_mol(1.0_f64).to_count(), 9
+		);
+		assert_approx_equal(
+			Amount::from_mol(0.001_f64).to_mol(),
+			Amount::from_mmol(1.0_f64).to_mol(), 9
+		);
+		assert_approx_equal(
+			Amount::from_mol(1.0_f64).to_mol() * 1000.0,
+			Amount::from_mol(1.0_f64).to_mmol(), 9
+		);
+		assert_approx_equal(
+			Amount::from_mol(1e-06_f64).to_mol(),
+			Amount::from_umol(1.0_f64).to_mol(), 9
+		);
+		assert_approx_equal(
+			Amount::from_mol(1.0_f64).to_mol() * 1000000.0,
+			Amount::from_mol(1.0_f64).to_umol(), 9
+		);
+		assert_approx_equal(
+			Amount::from_mol(1e-09_f64).to_mol(),
+			Amount::from_nmol(1.0_f64).to_mol(), 9
+		);
+		assert_approx_equal(
+			Amount::from_mol(1.0_f64).to_mol() * 1000000000.0,
+			Amount::from_mol(1.0_f64).to_nmol(), 9
+		);
+		assert_approx_equal(
+			Amount::from_mol(1e-12_f64).to_mol(),
+			Amount::from_pmol(1.0_f64).to_mol(), 9
+		);
+		assert_approx_equal(
+			Amount::from_mol(1.0_f64).to_mol() * 1000000000000.0,
+			Amount::from_mol(1.0_f64).to_pmol(), 9
+		);
+	}
+
+	#[test]
+	fn current_units() {
+		assert_approx_equal(
+			Current::from_A(0.001_f64).to_A(),
+			Current::from_mA(1.0_f64).to_A(), 9
+		);
+		assert_approx_equal(
+			Current::from_A(1.0_f64).to_A() * 1000.0,
+			Current::from_A(1.0_f64).to_mA(), 9
+		);
+		assert_approx_equal(
+			Current::from_A(1e-06_f64).to_A(),
+			Current::from_uA(1.0_f64).to_A(), 9
+		);
+		assert_approx_equal(
+			Current::from_A(1.0_f64).to_A() * 1000000.0,
+			Current::from_A(1.0_f64).to_uA(), 9
+		);
+		assert_approx_equal(
+			Current::from_A(1e-09_f64).to_A(),
+			Current::from_nA(1.0_f64).to_A(), 9
+		);
+		assert_approx_equal(
+			Current::from_A(1.0_f64).to_A() * 1000000000.0,
+			Current::from_A(1.0_f64).to_nA(), 9
+		);
+		assert_approx_equal(
+			Current::from_A(1000.0_f64).to_A(),
+			Current::from_kA(1.0_f64).to_A(), 9
+		);
+		assert_approx_equal(
+			Current::from_A(1.0_f64).to_A() * 0.001,
+			Current::from_A(1.0_f64).to_kA(), 9
+		);
+		assert_approx_equal(
+			Current::from_A(1000000.0_f64).to_A(),
+			Current::from_MA(1.0_f64).to_A(), 9
+		);
+		assert_approx_equal(
+			Current::from_A(1.0_f64).to_A() * 1e-06,
+			Current::from_A(1.0_f64).to_MA(), 9
+		);
+		assert_approx_equal(
+			Current::from_A(1000000000.0_f64).to_A(),
+			Current::from_GA(1.0_f64).to_A(), 9
+		);
+		assert_approx_equal(
+			Current::from_A(1.0_f64).to_A() * 1e-09,
+			Current::from_A(1.0_f64).to_GA(), 9
+		);
+	}
+
+	#[test]
+	fn distance_units() {
+		assert_approx_equal(
+			Distance::from_m(0.01_f64).to_m(),
+			Distance::from_cm(1.0_f64).to_m(), 9
+		);
+		assert_approx_equal(
+			Distance::from_m(1.0_f64).to_m() * 100.0,
+			Distance::from_m(1.0_f64).to_cm(), 9
+		);
+		assert_approx_equal(
+			Distance::from_m(0.001_f64).to_m(),
+			Distance::from_mm(1.0_f64).to_m(), 9
+		);
+		assert_approx_equal(
+			Distance::from_m(1.0_f64).to_m() * 1000.0,
+			Distance::from_m(1.0_f64).to_mm(), 9
+		);
+		assert_approx_equal(
+			Distance::from_m(1e-06_f64).to_m(),
+			Distance::from_um(1.0_f64).to_m(), 9
 		);
-		assert_eq!(div_check(
-			&BigFloat::from(x), &AreaDensity{kgpm2: BigFloat::from(y)}),
-				   AreaPerMass{m2_per_kg: BigFloat::from(x)/BigFloat::from(y)}
+		assert_approx_equal(
+			Distance::from_m(1.0_f64).to_m() * 1000000.0,
+			Distance::from_m(1.0_f64).to_um(), 9
 		);
-		assert_eq!(div_check(
-			&BigFloat::from(x), &AreaPerMass{m2_per_kg: BigFloat::from(y)}),
-				   AreaDensity{kgpm2: BigFloat::from(x)/BigFloat::from(y)}
+		assert_approx_equal(
+			Distance::from_m(1e-09_f64).to_m(),
+			Distance::from_nm(1.0_f64).to_m(), 9
 		);
-		assert_eq!(div_check(
-			&BigFloat::from(x), &Density{kgpm3: BigFloat::from(y)}),
-				   VolumePerMass{m3_per_kg: BigFloat::from(x)/BigFloat::from(y)}
+		assert_approx_equal(
+			Distance::from_m(1.0_f64).to_m() * 1000000000.0,
+			Distance::from_m(1.0_f64).to_nm(), 9
 		);
-		assert_eq!(div_check(
-			&BigFloat::from(x), &Energy{J: BigFloat::from(y)}),
-				   InverseEnergy{per_J: BigFloat::from(x)/BigFloat::from(y)}
+		assert_approx_equal(
+			Distance::from_m(1e-12_f64).to_m(),
+			Distance::from_pm(1.0_f64).to_m(), 9
 		);
-		assert_eq!(div_check(
-			&BigFloat::from(x), &Force{N: BigFloat::from(y)}),
-				   InverseForce{per_N: BigFloat::from(x)/BigFloat::from(y)}
+		assert_approx_equal(
+			Distance::from_m(1.0_f64).to_m() * 1000000000000.0,
+			Distance::from_m(1.0_f64).to_pm(), 9
 		);
-		assert_eq!(div_check(
-			&BigFloat::from(x), &InverseAcceleration{s2pm: BigFloat::from(y)}),
-				   Acceleration{mps2: BigFloat::from(x)/BigFloat::from(y)}
+		assert_approx_equal(
+			Distance::from_m(1000.0_f64).to_m(),
+			Distance::from_km(1.0_f64).to_m(), 9
 		);
-		assert_eq!(div_check(
-			&BigFloat::from(x), &InverseAngularAcceleration{s2prad: BigFloat::from(y)}),
-				   AngularAcceleration{radps2: BigFloat::from(x)/BigFloat::from(y)}
+		assert_approx_equal(
+			Distance::from_m(1.0_f64).to_m() * 0.001,
+			Distance::from_m(1.0_f64).to_km(), 9
 		);
-		assert_eq!(div_check(
-			&BigFloat::from(x), &InverseAngularMomentum{s_per_kgm2rad: BigFloat::from(y)}),
-				   AngularMomentum{kgm2radps: BigFloat::from(x)/BigFloat::from(y)}
+		assert_approx_equal(
+			Distance::from_m(149597870700.0_f64).to_m(),
+			Distance::from_au(1.0_f64).to_m(), 9
 		);
-		assert_eq!(div_check(
-			&BigFloat::from(x), &InverseAngularVelocity{s_per_rad: BigFloat::from(y)}),
-				   AngularVelocity{radps: BigFloat::from(x)/BigFloat::from(y)}
+		assert_approx_equal(
+			Distance::from_m(1.0_f64).to_m() * 6.68458712226845e-12,
+			Distance::from_m(1.0_f64).to_au(), 9
 		);
-		assert_eq!(div_check(
-			&BigFloat::from(x), &InverseEnergy{per_J: BigFloat::from(y)}),
-				   Energy{J: BigFloat::from(x)/BigFloat::from(y)}
+		assert_approx_equal(
+			Distance::from_m(3.08568047999355e+16_f64).to_m(),
+			Distance::from_parsec(1.0_f64).to_m(), 9
 		);
-		assert_eq!(div_check(
-			&BigFloat::from(x), &InverseForce{per_N: BigFloat::from(y)}),
-				   Force{N: BigFloat::from(x)/BigFloat::from(y)}
+		assert_approx_equal(
+			Distance::from_m(1.0_f64).to_m() * 3.24077624525171e-17,
+			Distance::from_m(1.0_f64).to_parsec(), 9
 		);
-		assert_eq!(div_check(
-			&BigFloat::from(x), &InverseMomentum{s_per_kgm: BigFloat::from(y)}),
-				   Momentum{kgmps: BigFloat::from(x)/BigFloat::from(y)}
+		assert_approx_equal(
+			Distance::from_m(9460528169656200.0_f64).to_m(),
+			Distance::from_lyr(1.0_f64).to_m(), 9
 		);
-		assert_eq!(div_check(
-			&BigFloat::from(x), &InversePower{per_W: BigFloat::from(y)}),
-				   Power{W: BigFloat::from(x)/BigFloat::from(y)}
+		assert_approx_equal(
+			Distance::from_m(1.0_f64).to_m() * 1.05702343681763e-16,
+			Distance::from_m(1.0_f64).to_lyr(), 9
 		);
-		assert_eq!(div_check(
-			&BigFloat::from(x), &InversePressure{per_Pa: BigFloat::from(y)}),
-				   Pressure{Pa: BigFloat::from(x)/BigFloat::from(y)}
+		assert_approx_equal(
+			Distance::from_m(0.3048_f64).to_m(),
+			Distance::from_feet(1.0_f64).to_m(), 9
 		);
-		assert_eq!(div_check(
-			&BigFloat::from(x), &InverseTorque{per_Nm: BigFloat::from(y)}),
-				   Energy{J: BigFloat::from(x)/BigFloat::from(y)}
+		assert_approx_equal(
+			Distance::from_m(1.0_f64).to_m() * 3.280839895013123,
+			Distance::from_m(1.0_f64).to_feet(), 9
 		);
-		assert_eq!(div_check(
-			&BigFloat::from(x), &Momentum{kgmps: BigFloat::from(y)}),
-				   InverseMomentum{s_per_kgm: BigFloat::from(x)/BigFloat::from(y)}
+		assert_approx_equal(
+			Distance::from_m(0.0254_f64).to_m(),
+			Distance::from_inches(1.0_f64).to_m(), 9
 		);
-		assert_eq!(div_check(
-			&BigFloat::from(x), &Power{W: BigFloat::from(y)}),
-				   InversePower{per_W: BigFloat::from(x)/BigFloat::from(y)}
+		assert_approx_equal(
+			Distance::from_m(1.0_f64).to_m() * 39.37007874015748,
+			Distance::from_m(1.0_f64).to_inches(), 9
 		);
-		assert_eq!(div_check(
-			&BigFloat::from(x), &Pressure{Pa: BigFloat::from(y)}),
-				   InversePressure{per_Pa: BigFloat::from(x)/BigFloat::from(y)}
+		assert_approx_equal(
+			Distance::from_m(0.9144_f64).to_m(),
+			Distance::from_yards(1.0_f64).to_m(), 9
 		);
-		assert_eq!(div_check(
-			&BigFloat::from(x), &TimePerDistance{spm: BigFloat::from(y)}),
-				   Velocity{mps: BigFloat::from(x)/BigFloat::from(y)}
+		assert_approx_equal(
+			Distance::from_m(1.0_f64).to_m() * 1.0936132983377078,
+			Distance::from_m(1.0_f64).to_yards(), 9
 		);
-		assert_eq!(div_check(
-			&BigFloat::from(x), &Torque{Nm: BigFloat::from(y)}),
-				   InverseEnergy{per_J: BigFloat::from(x)/BigFloat::from(y)}
+		assert_approx_equal(
+			Distance::from_m(1609.344_f64).to_m(),
+			Distance::from_miles(1.0_f64).to_m(), 9
 		);
-		assert_eq!(div_check(
-			&BigFloat::from(x), &Velocity{mps: BigFloat::from(y)}),
-				   TimePerDistance{spm: BigFloat::from(x)/BigFloat::from(y)}
+		assert_approx_equal(
+			Distance::from_m(1.0_f64).to_m() * 0.0006213711922373339,
+			Distance::from_m(1.0_f64).to_miles(), 9
 		);
-		assert_eq!(div_check(
-			&BigFloat::from(x), &VolumePerMass{m3_per_kg: BigFloat::from(y)}),
-				   Density{kgpm3: BigFloat::from(x)/BigFloat::from(y)}
+		assert_approx_equal(
+			Distance::from_m(1852.0_f64).to_m(),
+			Distance::from_nautical_miles(1.0_f64).to_m(), 9
+		);
+		assert_approx_equal(
+			Distance::from_m(1.0_f64).to_m() * 0.0005399568034557236,
+			Distance::from_m(1.0_f64).to_nautical_miles(), 9
 		);
 	}
 
 	#[test]
-	fn test_complex_unit_conversions() {
-		use num_complex::{Complex32, Complex64};
-		let x = 4.5f64;
-		let y = 2.5f64;
-		assert_eq!(div_check(
-			&Complex32::from(x as f32), &Time{s: Complex32::from(y as f32)}),
-				   Frequency{Hz: Complex32::from(x as f32)/Complex32::from(y as f32)}
+	fn luminosity_units() {
+		assert_approx_equal(
+			Luminosity::from_cd(0.001_f64).to_cd(),
+			Luminosity::from_mcd(1.0_f64).to_cd(), 9
 		);
-		assert_eq!(div_check(
-			&Complex64::from(x), &Time{s: Complex64::from(y)}),
-				   Frequency{Hz: Complex64::from(x)/Complex64::from(y)}
+		assert_approx_equal(
+			Luminosity::from_cd(1.0_f64).to_cd() * 1000.0,
+			Luminosity::from_cd(1.0_f64).to_mcd(), 9
 		);
-		assert_eq!(div_check(
-			&Complex32::from(x as f32), &Time{s: Complex32::from(y as f32)}),
-				   Frequency{Hz: Complex32::from(x as f32)/Complex32::from(y as f32)}
+		assert_approx_equal(
+			Luminosity::from_cd(1e-06_f64).to_cd(),
+			Luminosity::from_ucd(1.0_f64).to_cd(), 9
 		);
-		assert_eq!(div_check(
-			&Complex64::from(x), &Time{s: Complex64::from(y)}),
-				   Frequency{Hz: Complex64::from(x)/Complex64::from(y)}
+		assert_approx_equal(
+			Luminosity::from_cd(1.0_f64).to_cd() * 1000000.0,
+			Luminosity::from_cd(1.0_f64).to_ucd(), 9
 		);
-		assert_eq!(div_check(
-			&Complex32::from(x as f32), &Conductance{S: Complex32::from(y as f32)}),
-				   Resistance{Ohm: Complex32::from(x as f32)/Complex32::from(y as f32)}
+		assert_approx_equal(
+			Luminosity::from_cd(1e-09_f64).to_cd(),
+			Luminosity::from_ncd(1.0_f64).to_cd(), 9
 		);
-		assert_eq!(div_check(
-			&Complex64::from(x), &Conductance{S: Complex64::from(y)}),
-				   Resistance{Ohm: Complex64::from(x)/Complex64::from(y)}
+		assert_approx_equal(
+			Luminosity::from_cd(1.0_f64).to_cd() * 1000000000.0,
+			Luminosity::from_cd(1.0_f64).to_ncd(), 9
 		);
-		assert_eq!(div_check(
-			&Complex32::from(x as f32), &Resistance{Ohm: Complex32::from(y as f32)}),
-				   Conductance{S: Complex32::from(x as f32)/Complex32::from(y as f32)}
+		assert_approx_equal(
+			Luminosity::from_cd(1000.0_f64).to_cd(),
+			Luminosity::from_kcd(1.0_f64).to_cd(), 9
 		);
-		assert_eq!(div_check(
-			&Complex64::from(x), &Resistance{Ohm: Complex64::from(y)}),
-				   Conductance{S: Complex64::from(x)/Complex64::from(y)}
+		assert_approx_equal(
+			Luminosity::from_cd(1.0_f64).to_cd() * 0.001,
+			Luminosity::from_cd(1.0_f64).to_kcd(), 9
 		);
-		assert_eq!(div_check(
-			&Complex32::from(x as f32), &Conductance{S: Complex32::from(y as f32)}),
-				   Resistance{Ohm: Complex32::from(x as f32)/Complex32::from(y as f32)}
+		assert_approx_equal(
+			Luminosity::from_cd(1000000.0_f64).to_cd(),
+			Luminosity::from_Mcd(1.0_f64).to_cd(), 9
 		);
-		assert_eq!(div_check(
-			&Complex64::from(x), &Conductance{S: Complex64::from(y)}),
-				   Resistance{Ohm: Complex64::from(x)/Complex64::from(y)}
+		assert_approx_equal(
+			Luminosity::from_cd(1.0_f64).to_cd() * 1e-06,
+			Luminosity::from_cd(1.0_f64).to_Mcd(), 9
 		);
-		assert_eq!(div_check(
-			&Complex32::from(x as f32), &Resistance{Ohm: Complex32::from(y as f32)}),
-				   Conductance{S: Complex32::from(x as f32)/Complex32::from(y as f32)}
+		assert_approx_equal(
+			Luminosity::from_cd(1000000000.0_f64).to_cd(),
+			Luminosity::from_Gcd(1.0_f64).to_cd(), 9
 		);
-		assert_eq!(div_check(
-			&Complex64::from(x), &Resistance{Ohm: Complex64::from(y)}),
-				   Conductance{S: Complex64::from(x)/Complex64::from(y)}
+		assert_approx_equal(
+			Luminosity::from_cd(1.0_f64).to_cd() * 1e-09,
+			Luminosity::from_cd(1.0_f64).to_Gcd(), 9
 		);
-		assert_eq!(div_check(
-			&Complex32::from(x as f32), &Frequency{Hz: Complex32::from(y as f32)}),
-				   Time{s: Complex32::from(x as f32)/Complex32::from(y as f32)}
+	}
+
+	#[test]
+	fn mass_units() {
+		assert_approx_equal(
+			Mass::from_kg(0.001_f64).to_kg(),
+			Mass::from_g(1.0_f64).to_kg(), 9
 		);
-		assert_eq!(div_check(
-			&Complex64::from(x), &Frequency{Hz: Complex64::from(y)}),
-				   Time{s: Complex64::from(x)/Complex64::from(y)}
+		assert_approx_equal(
+			Mass::from_kg(1.0_f64).to_kg() * 1000.0,
+			Mass::from_kg(1.0_f64).to_g(), 9
 		);
-		assert_eq!(div_check(
-			&Complex32::from(x as f32), &Frequency{Hz: Complex32::from(y as f32)}),
-				   Time{s: Complex32::from(x as f32)/Complex32::from(y as f32)}
+		assert_approx_equal(
+			Mass::from_kg(1e-06_f64).to_kg(),
+			Mass::from_mg(1.0_f64).to_kg(), 9
 		);
-		assert_eq!(div_check(
-			&Complex64::from(x), &Frequency{Hz: Complex64::from(y)}),
-				   Time{s: Complex64::from(x)/Complex64::from(y)}
+		assert_approx_equal(
+			Mass::from_kg(1.0_f64).to_kg() * 1000000.0,
+			Mass::from_kg(1.0_f64).to_mg(), 9
 		);
-		assert_eq!(div_check(
-			&Complex32::from(x as f32), &Radioactivity{Bq: Complex32::from(y as f32)}),
-				   Time{s: Complex32::from(x as f32)/Complex32::from(y as f32)}
+		assert_approx_equal(
+			Mass::from_kg(1e-09_f64).to_kg(),
+			Mass::from_ug(1.0_f64).to_kg(), 9
 		);
-		assert_eq!(div_check(
-			&Complex64::from(x), &Radioactivity{Bq: Complex64::from(y)}),
-				   Time{s: Complex64::from(x)/Complex64::from(y)}
+		assert_approx_equal(
+			Mass::from_kg(1.0_f64).to_kg() * 1000000000.0,
+			Mass::from_kg(1.0_f64).to_ug(), 9
 		);
-		assert_eq!(div_check(
-			&Complex32::from(x as f32), &Radioactivity{Bq: Complex32::from(y as f32)}),
-				   Time{s: Complex32::from(x as f32)/Complex32::from(y as f32)}
+		assert_approx_equal(
+			Mass::from_kg(1e-12_f64).to_kg(),
+			Mass::from_ng(1.0_f64).to_kg(), 9
 		);
-		assert_eq!(div_check(
-			&Complex64::from(x), &Radioactivity{Bq: Complex64::from(y)}),
-				   Time{s: Complex64::from(x)/Complex64::from(y)}
+		assert_approx_equal(
+			Mass::from_kg(1.0_f64).to_kg() * 1000000000000.0,
+			Mass::from_kg(1.0_f64).to_ng(), 9
+		);
+		assert_approx_equal(
+			Mass::from_kg(1e-15_f64).to_kg(),
+			Mass::from_pg(1.0_f64).to_kg(), 9
+		);
+		assert_approx_equal(
+			Mass::from_kg(1.0_f64).to_kg() * 1000000000000000.0,
+			Mass::from_kg(1.0_f64).to_pg(), 9
+		);
+		assert_approx_equal(
+			Mass::from_kg(1000.0_f64).to_kg(),
+			Mass::from_tons(1.0_f64).to_kg(), 9
 		);
-		assert_eq!(div_check(
-			&Complex32::from(x as f32), &Molality{molpkg: Complex32::from(y as f32)}),
-				   MolarMass{kgpmol: Complex32::from(x as f32)/Complex32::from(y as f32)}
+		assert_approx_equal(
+			Mass::from_kg(1.0_f64).to_kg() * 0.001,
+			Mass::from_kg(1.0_f64).to_tons(), 9
 		);
-		assert_eq!(div_check(
-			&Complex64::from(x), &Molality{molpkg: Complex64::from(y)}),
-				   MolarMass{kgpmol: Complex64::from(x)/Complex64::from(y)}
+		assert_approx_equal(
+			Mass::from_kg(5.9722e+24_f64).to_kg(),
+			Mass::from_earth_mass(1.0_f64).to_kg(), 9
 		);
-		assert_eq!(div_check(
-			&Complex32::from(x as f32), &MolarMass{kgpmol: Complex32::from(y as f32)}),
-				   Molality{molpkg: Complex32::from(x as f32)/Complex32::from(y as f32)}
+		assert_approx_equal(
+			Mass::from_kg(1.0_f64).to_kg() * 1.6744248350691502e-25,
+			Mass::from_kg(1.0_f64).to_earth_mass(), 9
 		);
-		assert_eq!(div_check(
-			&Complex64::from(x), &MolarMass{kgpmol: Complex64::from(y)}),
-				   Molality{molpkg: Complex64::from(x)/Complex64::from(y)}
+		assert_approx_equal(
+			Mass::from_kg(1.8986e+27_f64).to_kg(),
+			Mass::from_jupiter_mass(1.0_f64).to_kg(), 9
 		);
-		assert_eq!(div_check(
-			&Complex32::from(x as f32), &Molality{molpkg: Complex32::from(y as f32)}),
-				   MolarMass{kgpmol: Complex32::from(x as f32)/Complex32::from(y as f32)}
+		assert_approx_equal(
+			Mass::from_kg(1.0_f64).to_kg() * 5.26703887074687e-28,
+			Mass::from_kg(1.0_f64).to_jupiter_mass(), 9
 		);
-		assert_eq!(div_check(
-			&Complex64::from(x), &Molality{molpkg: Complex64::from(y)}),
-				   MolarMass{kgpmol: Complex64::from(x)/Complex64::from(y)}
+		assert_approx_equal(
+			Mass::from_kg(1.9885500000000002e+30_f64).to_kg(),
+			Mass::from_solar_mass(1.0_f64).to_kg(), 9
 		);
-		assert_eq!(div_check(
-			&Complex32::from(x as f32), &MolarMass{kgpmol: Complex32::from(y as f32)}),
-				   Molality{molpkg: Complex32::from(x as f32)/Complex32::from(y as f32)}
+		assert_approx_equal(
+			Mass::from_kg(1.0_f64).to_kg() * 5.0287898217294e-31,
+			Mass::from_kg(1.0_f64).to_solar_mass(), 9
 		);
-		assert_eq!(div_check(
-			&Complex64::from(x), &MolarMass{kgpmol: Complex64::from(y)}),
-				   Molality{molpkg: Complex64::from(x)/Complex64::from(y)}
+		assert_approx_equal(
+			Mass::from_kg(0.001_f64).to_kg(),
+			Mass::from_grams(1.0_f64).to_kg(), 9
 		);
-		assert_eq!(div_check(
-			&Complex32::from(x as f32), &Amount{mol: Complex32::from(y as f32)}),
-				   InverseAmount{per_mol: Complex32::from(x as f32)/Complex32::from(y as f32)}
+		assert_approx_equal(
+			Mass::from_kg(1.0_f64).to_kg() * 1000.0,
+			Mass::from_kg(1.0_f64).to_grams(), 9
 		);
-		assert_eq!(div_check(
-			&Complex64::from(x), &Amount{mol: Complex64::from(y)}),
-				   InverseAmount{per_mol: Complex64::from(x)/Complex64::from(y)}
+		assert_approx_equal(
+			Mass::from_kg(1000.0_f64).to_kg(),
+			Mass::from_tonnes(1.0_f64).to_kg(), 9
 		);
-		assert_eq!(div_check(
-			&Complex32::from(x as f32), &Current{A: Complex32::from(y as f32)}),
-				   InverseCurrent{per_A: Complex32::from(x as f32)/Complex32::from(y as f32)}
+		assert_approx_equal(
+			Mass::from_kg(1.0_f64).to_kg() * 0.001,
+			Mass::from_kg(1.0_f64).to_tonnes(), 9
 		);
-		assert_eq!(div_check(
-			&Complex64::from(x), &Current{A: Complex64::from(y)}),
-				   InverseCurrent{per_A: Complex64::from(x)/Complex64::from(y)}
+		assert_approx_equal(
+			Mass::from_kg(907.18474_f64).to_kg(),
+			Mass::from_short_tons(1.0_f64).to_kg(), 9
 		);
-		assert_eq!(div_check(
-			&Complex32::from(x as f32), &Distance{m: Complex32::from(y as f32)}),
-				   InverseDistance{per_m: Complex32::from(x as f32)/Complex32::from(y as f32)}
+		assert_approx_equal(
+			Mass::from_kg(1.0_f64).to_kg() * 0.001102311310924388,
+			Mass::from_kg(1.0_f64).to_short_tons(), 9
 		);
-		assert_eq!(div_check(
-			&Complex64::from(x), &Distance{m: Complex64::from(y)}),
-				   InverseDistance{per_m: Complex64::from(x)/Complex64::from(y)}
+		assert_approx_equal(
+			Mass::from_kg(0.45359237_f64).to_kg(),
+			Mass::from_pounds(1.0_f64).to_kg(), 9
 		);
-		assert_eq!(div_check(
-			&Complex32::from(x as f32), &InverseAmount{per_mol: Complex32::from(y as f32)}),
-				   Amount{mol: Complex32::from(x as f32)/Complex32::from(y as f32)}
+		assert_approx_equal(
+			Mass::from_kg(1.0_f64).to_kg() * 2.2046226218487757,
+			Mass::from_kg(1.0_f64).to_pounds(), 9
 		);
-		assert_eq!(div_check(
-			&Complex64::from(x), &InverseAmount{per_mol: Complex64::from(y)}),
-				   Amount{mol: Complex64::from(x)/Complex64::from(y)}
+		assert_approx_equal(
+			Mass::from_kg(0.028349523125_f64).to_kg(),
+			Mass::from_ounces(1.0_f64).to_kg(), 9
 		);
-		assert_eq!(div_check(
-			&Complex32::from(x as f32), &InverseCurrent{per_A: Complex32::from(y as f32)}),
-				   Current{A: Complex32::from(x as f32)/Complex32::from(y as f32)}
+		assert_approx_equal(
+			Mass::from_kg(1.0_f64).to_kg() * 35.27396194958041,
+			Mass::from_kg(1.0_f64).to_ounces(), 9
 		);
-		assert_eq!(div_check(
-			&Complex64::from(x), &InverseCurrent{per_A: Complex64::from(y)}),
-				   Current{A: Complex64::from(x)/Complex64::from(y)}
+	}
+
+	#[test]
+	fn temperature_units() {
+		assert_approx_equal(
+			Temperature::from_K(274.1500000000000_f64).to_K(),
+			Temperature::from_C(1.0_f64).to_K(), 9
 		);
-		assert_eq!(div_check(
-			&Complex32::from(x as f32), &InverseDistance{per_m: Complex32::from(y as f32)}),
-				   Distance{m: Complex32::from(x as f32)/Complex32::from(y as f32)}
+		assert_approx_equal(
+			Temperature::from_K(1.0_f64).to_K() * -272.1500000000000,
+			Temperature::from_K(1.0_f64).to_C(), 9
 		);
-		assert_eq!(div_check(
-			&Complex64::from(x), &InverseDistance{per_m: Complex64::from(y)}),
-				   Distance{m: Complex64::from(x)/Complex64::from(y)}
+		assert_approx_equal(
+			Temperature::from_K(274.1500000000000_f64).to_K(),
+			Temperature::from_celsius(1.0_f64).to_K(), 9
 		);
-		assert_eq!(div_check(
-			&Complex32::from(x as f32), &InverseLuminosity{per_cd: Complex32::from(y as f32)}),
-				   Luminosity{cd: Complex32::from(x as f32)/Complex32::from(y as f32)}
+		assert_approx_equal(
+			Temperature::from_K(1.0_f64).to_K() * -272.1500000000000,
+			Temperature::from_K(1.0_f64).to_celsius(), 9
 		);
-		assert_eq!(div_check(
-			&Complex64::from(x), &InverseLuminosity{per_cd: Complex64::from(y)}),
-				   Luminosity{cd: Complex64::from(x)/Complex64::from(y)}
+		assert_approx_equal(
+			Temperature::from_K(255.9277777777780_f64).to_K(),
+			Temperature::from_F(1.0_f64).to_K(), 9
 		);
-		assert_eq!(div_check(
-			&Complex32::from(x as f32), &InverseMass{per_kg: Complex32::from(y as f32)}),
-				   Mass{kg: Complex32::from(x as f32)/Complex32::from(y as f32)}
+		assert_approx_equal(
+			Temperature::from_K(1.0_f64).to_K() * -457.8700000000000,
+			Temperature::from_K(1.0_f64).to_F(), 9
 		);
-		assert_eq!(div_check(
-			&Complex64::from(x), &InverseMass{per_kg: Complex64::from(y)}),
-				   Mass{kg: Complex64::from(x)/Complex64::from(y)}
+		assert_approx_equal(
+			Temperature::from_K(255.9277777777780_f64).to_K(),
+			Temperature::from_fahrenheit(1.0_f64).to_K(), 9
 		);
-		assert_eq!(div_check(
-			&Complex32::from(x as f32), &InverseTemperature{per_K: Complex32::from(y as f32)}),
-				   Temperature{K: Complex32::from(x as f32)/Complex32::from(y as f32)}
+		assert_approx_equal(
+			Temperature::from_K(1.0_f64).to_K() * -457.8700000000000,
+			Temperature::from_K(1.0_f64).to_fahrenheit(), 9
 		);
-		assert_eq!(div_check(
-			&Complex64::from(x), &InverseTemperature{per_K: Complex64::from(y)}),
-				   Temperature{K: Complex64::from(x)/Complex64::from(y)}
+	}
+
+	#[test]
+	fn temperature_interval_units() {
+		assert_approx_equal(
+			TemperatureInterval::from_K(0.555555555555556_f64).to_K(),
+			TemperatureInterval::from_F(1.0_f64).to_K(), 9
 		);
-		assert_eq!(div_check(
-			&Complex32::from(x as f32), &Luminosity{cd: Complex32::from(y as f32)}),
-				   InverseLuminosity{per_cd: Complex32::from(x as f32)/Complex32::from(y as f32)}
+		assert_approx_equal(
+			TemperatureInterval::from_K(1.0_f64).to_K() * 1.8,
+			TemperatureInterval::from_K(1.0_f64).to_F(), 9
 		);
-		assert_eq!(div_check(
-			&Complex64::from(x), &Luminosity{cd: Complex64::from(y)}),
-				   InverseLuminosity{per_cd: Complex64::from(x)/Complex64::from(y)}
+		assert_approx_equal(
+			TemperatureInterval::from_K(0.555555555555556_f64).to_K(),
+			TemperatureInterval::from_fahrenheit(1.0_f64).to_K(), 9
 		);
-		assert_eq!(div_check(
-			&Complex32::from(x as f32), &Mass{kg: Complex32::from(y as f32)}),
-				   InverseMass{per_kg: Complex32::from(x as f32)/Complex32::from(y as f32)}
+		assert_approx_equal(
+			TemperatureInterval::from_K(1.0_f64).to_K() * 1.8,
+			TemperatureInterval::from_K(1.0_f64).to_fahrenheit(), 9
 		);
-		assert_eq!(div_check(
-			&Complex64::from(x), &Mass{kg: Complex64::from(y)}),
-				   InverseMass{per_kg: Complex64::from(x)/Complex64::from(y)}
+	}
+
+	#[test]
+	fn time_units() {
+		assert_approx_equal(
+			Time::from_s(0.001_f64).to_s(),
+			Time::from_ms(1.0_f64).to_s(), 9
 		);
-		assert_eq!(div_check(
-			&Complex32::from(x as f32), &Temperature{K: Complex32::from(y as f32)}),
-				   InverseTemperature{per_K: Complex32::from(x as f32)/Complex32::from(y as f32)}
+		assert_approx_equal(
+			Time::from_s(1.0_f64).to_s() * 1000.0,
+			Time::from_s(1.0_f64).to_ms(), 9
 		);
-		assert_eq!(div_check(
-			&Complex64::from(x), &Temperature{K: Complex64::from(y)}),
-				   InverseTemperature{per_K: Complex64::from(x)/Complex64::from(y)}
+		assert_approx_equal(
+			Time::from_s(1e-06_f64).to_s(),
+			Time::from_us(1.0_f64).to_s(), 9
 		);
-		assert_eq!(div_check(
-			&Complex32::from(x as f32), &Amount{mol: Complex32::from(y as f32)}),
-				   InverseAmount{per_mol: Complex32::from(x as f32)/Complex32::from(y as f32)}
+		assert_approx_equal(
+			Time::from_s(1.0_f64).to_s() * 1000000.0,
+			Time::from_s(1.0_f64).to_us(), 9
 		);
-		assert_eq!(div_check(
-			&Complex64::from(x), &Amount{mol: Complex64::from(y)}),
-				   InverseAmount{per_mol: Complex64::from(x)/Complex64::from(y)}
+		assert_approx_equal(
+			Time::from_s(1e-09_f64).to_s(),
+			Time::from_ns(1.0_f64).to_s(), 9
 		);
-		assert_eq!(div_check(
-			&Complex32::from(x as f32), &Current{A: Complex32::from(y as f32)}),
-				   InverseCurrent{per_A: Complex32::from(x as f32)/Complex32::from(y as f32)}
+		assert_approx_equal(
+			Time::from_s(1.0_f64).to_s() * 1000000000.0,
+			Time::from_s(1.0_f64).to_ns(), 9
 		);
-		assert_eq!(div_check(
-			&Complex64::from(x), &Current{A: Complex64::from(y)}),
-				   InverseCurrent{per_A: Complex64::from(x)/Complex64::from(y)}
+		assert_approx_equal(
+			Time::from_s(1e-12_f64).to_s(),
+			Time::from_ps(1.0_f64).to_s(), 9
 		);
-		assert_eq!(div_check(
-			&Complex32::from(x as f32), &Distance{m: Complex32::from(y as f32)}),
-				   InverseDistance{per_m: Complex32::from(x as f32)/Complex32::from(y as f32)}
+		assert_approx_equal(
+			Time::from_s(1.0_f64).to_s() * 1000000000000.0,
+			Time::from_s(1.0_f64).to_ps(), 9
 		);
-		assert_eq!(div_check(
-			&Complex64::from(x), &Distance{m: Complex64::from(y)}),
-				   InverseDistance{per_m: Complex64::from(x)/Complex64::from(y)}
+		assert_approx_equal(
+			Time::from_s(60.0_f64).to_s(),
+			Time::from_min(1.0_f64).to_s(), 9
 		);
-		assert_eq!(div_check(
-			&Complex32::from(x as f32), &InverseAmount{per_mol: Complex32::from(y as f32)}),
-				   Amount{mol: Complex32::from(x as f32)/Complex32::from(y as f32)}
+		assert_approx_equal(
+			Time::from_s(1.0_f64).to_s() * 0.0166666666666667,
+			Time::from_s(1.0_f64).to_min(), 9
 		);
-		assert_eq!(div_check(
-			&Complex64::from(x), &InverseAmount{per_mol: Complex64::from(y)}),
-				   Amount{mol: Complex64::from(x)/Complex64::from(y)}
+		assert_approx_equal(
+			Time::from_s(3600.0_f64).to_s(),
+			Time::from_hr(1.0_f64).to_s(), 9
 		);
-		assert_eq!(div_check(
-			&Complex32::from(x as f32), &InverseCurrent{per_A: Complex32::from(y as f32)}),
-				   Current{A: Complex32::from(x as f32)/Complex32::from(y as f32)}
+		assert_approx_equal(
+			Time::from_s(1.0_f64).to_s() * 0.0002777777777777,
+			Time::from_s(1.0_f64).to_hr(), 9
 		);
-		assert_eq!(div_check(
-			&Complex64::from(x), &InverseCurrent{per_A: Complex64::from(y)}),
-				   Current{A: Complex64::from(x)/Complex64::from(y)}
+		assert_approx_equal(
+			Time::from_s(86400.0_f64).to_s(),
+			Time::from_days(1.0_f64).to_s(), 9
 		);
-		assert_eq!(div_check(
-			&Complex32::from(x as f32), &InverseDistance{per_m: Complex32::from(y as f32)}),
-				   Distance{m: Complex32::from(x as f32)/Complex32::from(y as f32)}
+		assert_approx_equal(
+			Time::from_s(1.0_f64).to_s() * 1.15740740740741e-05,
+			Time::from_s(1.0_f64).to_days(), 9
 		);
-		assert_eq!(div_check(
-			&Complex64::from(x), &InverseDistance{per_m: Complex64::from(y)}),
-				   Distance{m: Complex64::from(x)/Complex64::from(y)}
+		assert_approx_equal(
+			Time::from_s(604800.0_f64).to_s(),
+			Time::from_weeks(1.0_f64).to_s(), 9
 		);
-		assert_eq!(div_check(
-			&Complex32::from(x as f32), &InverseLuminosity{per_cd: Complex32::from(y as f32)}),
-				   Luminosity{cd: Complex32::from(x as f32)/Complex32::from(y as f32)}
+		assert_approx_equal(
+			Time::from_s(1.0_f64).to_s() * 1.65343915343915e-06,
+			Time::from_s(1.0_f64).to_weeks(), 9
 		);
-		assert_eq!(div_check(
-			&Complex64::from(x), &InverseLuminosity{per_cd: Complex64::from(y)}),
-				   Luminosity{cd: Complex64::from(x)/Complex64::from(y)}
+		assert_approx_equal(
+			Time::from_s(31556925.19008_f64).to_s(),
+			Time::from_yr(1.0_f64).to_s(), 9
 		);
-		assert_eq!(div_check(
-			&Complex32::from(x as f32), &InverseMass{per_kg: Complex32::from(y as f32)}),
-				   Mass{kg: Complex32::from(x as f32)/Complex32::from(y as f32)}
+		assert_approx_equal(
+			Time::from_s(1.0_f64).to_s() * 3.16887654287165e-08,
+			Time::from_s(1.0_f64).to_yr(), 9
 		);
-		assert_eq!(div_check(
-			&Complex64::from(x), &InverseMass{per_kg: Complex64::from(y)}),
-				   Mass{kg: Complex64::from(x)/Complex64::from(y)}
+		assert_approx_equal(
+			Time::from_s(31556925190.08_f64).to_s(),
+			Time::from_kyr(1.0_f64).to_s(), 9
 		);
-		assert_eq!(div_check(
-			&Complex32::from(x as f32), &InverseTemperature{per_K: Complex32::from(y as f32)}),
-				   Temperature{K: Complex32::from(x as f32)/Complex32::from(y as f32)}
+		assert_approx_equal(
+			Time::from_s(1.0_f64).to_s() * 3.16887654287165e-11,
+			Time::from_s(1.0_f64).to_kyr(), 9
 		);
-		assert_eq!(div_check(
-			&Complex64::from(x), &InverseTemperature{per_K: Complex64::from(y)}),
-				   Temperature{K: Complex64::from(x)/Complex64::from(y)}
+		assert_approx_equal(
+			Time::from_s(31556925190080.0_f64).to_s(),
+			Time::from_Myr(1.0_f64).to_s(), 9
 		);
-		assert_eq!(div_check(
-			&Complex32::from(x as f32), &Luminosity{cd: Complex32::from(y as f32)}),
-				   InverseLuminosity{per_cd: Complex32::from(x as f32)/Complex32::from(y as f32)}
+		assert_approx_equal(
+			Time::from_s(1.0_f64).to_s() * 3.16887654287165e-14,
+			Time::from_s(1.0_f64).to_Myr(), 9
 		);
-		assert_eq!(div_check(
-			&Complex64::from(x), &Luminosity{cd: Complex64::from(y)}),
-				   InverseLuminosity{per_cd: Complex64::from(x)/Complex64::from(y)}
+		assert_approx_equal(
+			Time::from_s(3.155692519008e+16_f64).to_s(),
+			Time::from_Gyr(1.0_f64).to_s(), 9
 		);
-		assert_eq!(div_check(
-			&Complex32::from(x as f32), &Mass{kg: Complex32::from(y as f32)}),
-				   InverseMass{per_kg: Complex32::from(x as f32)/Complex32::from(y as f32)}
+		assert_approx_equal(
+			Time::from_s(1.0_f64).to_s() * 3.16887654287165e-17,
+			Time::from_s(1.0_f64).to_Gyr(), 9
 		);
-		assert_eq!(div_check(
-			&Complex64::from(x), &Mass{kg: Complex64::from(y)}),
-				   InverseMass{per_kg: Complex64::from(x)/Complex64::from(y)}
+		assert_approx_equal(
+			Time::from_s(0.001_f64).to_s(),
+			Time::from_milliseconds(1.0_f64).to_s(), 9
 		);
-		assert_eq!(div_check(
-			&Complex32::from(x as f32), &Temperature{K: Complex32::from(y as f32)}),
-				   InverseTemperature{per_K: Complex32::from(x as f32)/Complex32::from(y as f32)}
+		assert_approx_equal(
+			Time::from_s(1.0_f64).to_s() * 1000.0,
+			Time::from_s(1.0_f64).to_milliseconds(), 9
 		);
-		assert_eq!(div_check(
-			&Complex64::from(x), &Temperature{K: Complex64::from(y)}),
-				   InverseTemperature{per_K: Complex64::from(x)/Complex64::from(y)}
+		assert_approx_equal(
+			Time::from_s(1e-06_f64).to_s(),
+			Time::from_microseconds(1.0_f64).to_s(), 9
 		);
-		assert_eq!(div_check(
-			&Complex32::from(x as f32), &CatalyticActivity{molps: Complex32::from(y as f32)}),
-				   InverseCatalyticActivity{s_per_mol: Complex32::from(x as f32)/Complex32::from(y as f32)}
+		assert_approx_equal(
+			Time::from_s(1.0_f64).to_s() * 1000000.0,
+			Time::from_s(1.0_f64).to_microseconds(), 9
 		);
-		assert_eq!(div_check(
-			&Complex64::from(x), &CatalyticActivity{molps: Complex64::from(y)}),
-				   InverseCatalyticActivity{s_per_mol: Complex64::from(x)/Complex64::from(y)}
+		assert_approx_equal(
+			Time::from_s(1e-09_f64).to_s(),
+			Time::from_nanoseconds(1.0_f64).to_s(), 9
 		);
-		assert_eq!(div_check(
-			&Complex32::from(x as f32), &Concentration{molpm3: Complex32::from(y as f32)}),
-				   MolarVolume{m3_per_mol: Complex32::from(x as f32)/Complex32::from(y as f32)}
+		assert_approx_equal(
+			Time::from_s(1.0_f64).to_s() * 1000000000.0,
+			Time::from_s(1.0_f64).to_nanoseconds(), 9
 		);
-		assert_eq!(div_check(
-			&Complex64::from(x), &Concentration{molpm3: Complex64::from(y)}),
-				   MolarVolume{m3_per_mol: Complex64::from(x)/Complex64::from(y)}
+		assert_approx_equal(
+			Time::from_s(60.0_f64).to_s(),
+			Time::from_minutes(1.0_f64).to_s(), 9
 		);
-		assert_eq!(div_check(
-			&Complex32::from(x as f32), &InverseCatalyticActivity{s_per_mol: Complex32::from(y as f32)}),
-				   CatalyticActivity{molps: Complex32::from(x as f32)/Complex32::from(y as f32)}
+		assert_approx_equal(
+			Time::from_s(1.0_f64).to_s() * 0.016666666666666666,
+			Time::from_s(1.0_f64).to_minutes(), 9
 		);
-		assert_eq!(div_check(
-			&Complex64::from(x), &InverseCatalyticActivity{s_per_mol: Complex64::from(y)}),
-				   CatalyticActivity{molps: Complex64::from(x)/Complex64::from(y)}
+		assert_approx_equal(
+			Time::from_s(3600.0_f64).to_s(),
+			Time::from_hours(1.0_f64).to_s(), 9
 		);
-		assert_eq!(div_check(
-			&Complex32::from(x as f32), &InverseSpecificHeatCapacity{kgK_per_J: Complex32::from(y as f32)}),
-				   SpecificHeatCapacity{J_per_kgK: Complex32::from(x as f32)/Complex32::from(y as f32)}
+		assert_approx_equal(
+			Time::from_s(1.0_f64).to_s() * 0.0002777777777777778,
+			Time::from_s(1.0_f64).to_hours(), 9
 		);
-		assert_eq!(div_check(
-			&Complex64::from(x), &InverseSpecificHeatCapacity{kgK_per_J: Complex64::from(y)}),
-				   SpecificHeatCapacity{J_per_kgK: Complex64::from(x)/Complex64::from(y)}
+	}
+
+	#[test]
+	fn catalytic_activity_units() {
+		assert_approx_equal(
+			CatalyticActivity::from_molps(1.66053906717385e-24_f64).to_molps(),
+			CatalyticActivity::from_Nps(1.0_f64).to_molps(), 9
 		);
-		assert_eq!(div_check(
-			&Complex32::from(x as f32), &MolarVolume{m3_per_mol: Complex32::from(y as f32)}),
-				   Concentration{molpm3: Complex32::from(x as f32)/Complex32::from(y as f32)}
+		assert_approx_equal(
+			CatalyticActivity::from_molps(1.0_f64).to_molps() * 6.02214076e+23,
+			CatalyticActivity::from_molps(1.0_f64).to_Nps(), 9
 		);
-		assert_eq!(div_check(
-			&Complex64::from(x), &MolarVolume{m3_per_mol: Complex64::from(y)}),
-				   Concentration{molpm3: Complex64::from(x)/Complex64::from(y)}
+		assert_approx_equal(
+			CatalyticActivity::from_molps(0.001_f64).to_molps(),
+			CatalyticActivity::from_mmolps(1.0_f64).to_molps(), 9
 		);
-		assert_eq!(div_check(
-			&Complex32::from(x as f32), &SpecificHeatCapacity{J_per_kgK: Complex32::from(y as f32)}),
-				   InverseSpecificHeatCapacity{kgK_per_J: Complex32::from(x as f32)/Complex32::from(y as f32)}
+		assert_approx_equal(
+			CatalyticActivity::from_molps(1.0_f64).to_molps() * 1000.0,
+			CatalyticActivity::from_molps(1.0_f64).to_mmolps(), 9
 		);
-		assert_eq!(div_check(
-			&Complex64::from(x), &SpecificHeatCapacity{J_per_kgK: Complex64::from(y)}),
-				   InverseSpecificHeatCapacity{kgK_per_J: Complex64::from(x)/Complex64::from(y)}
+		assert_approx_equal(
+			CatalyticActivity::from_molps(1e-06_f64).to_molps(),
+			CatalyticActivity::from_umolps(1.0_f64).to_molps(), 9
 		);
-		assert_eq!(div_check(
-			&Complex32::from(x as f32), &CatalyticActivity{molps: Complex32::from(y as f32)}),
-				   InverseCatalyticActivity{s_per_mol: Complex32::from(x as f32)/Complex32::from(y as f32)}
+		assert_approx_equal(
+			CatalyticActivity::from_molps(1.0_f64).to_molps() * 1000000.0,
+			CatalyticActivity::from_molps(1.0_f64).to_umolps(), 9
 		);
-		assert_eq!(div_check(
-			&Complex64::from(x), &CatalyticActivity{molps: Complex64::from(y)}),
-				   InverseCatalyticActivity{s_per_mol: Complex64::from(x)/Complex64::from(y)}
+		assert_approx_equal(
+			CatalyticActivity::from_molps(1e-09_f64).to_molps(),
+			CatalyticActivity::from_nmolps(1.0_f64).to_molps(), 9
 		);
-		assert_eq!(div_check(
-			&Complex32::from(x as f32), &Concentration{molpm3: Complex32::from(y as f32)}),
-				   MolarVolume{m3_per_mol: Complex32::from(x as f32)/Complex32::from(y as f32)}
+		assert_approx_equal(
+			CatalyticActivity::from_molps(1.0_f64).to_molps() * 1000000000.0,
+			CatalyticActivity::from_molps(1.0_f64).to_nmolps(), 9
 		);
-		assert_eq!(div_check(
-			&Complex64::from(x), &Concentration{molpm3: Complex64::from(y)}),
-				   MolarVolume{m3_per_mol: Complex64::from(x)/Complex64::from(y)}
+	}
+
+	#[test]
+	fn concentration_units() {
+		assert_approx_equal(
+			Concentration::from_molpm3(1.66053906717385e-24_f64).to_molpm3(),
+			Concentration::from_Npm3(1.0_f64).to_molpm3(), 9
 		);
-		assert_eq!(div_check(
-			&Complex32::from(x as f32), &InverseCatalyticActivity{s_per_mol: Complex32::from(y as f32)}),
-				   CatalyticActivity{molps: Complex32::from(x as f32)/Complex32::from(y as f32)}
+		assert_approx_equal(
+			Concentration::from_molpm3(1.0_f64).to_molpm3() * 6.02214076e+23,
+			Concentration::from_molpm3(1.0_f64).to_Npm3(), 9
 		);
-		assert_eq!(div_check(
-			&Complex64::from(x), &InverseCatalyticActivity{s_per_mol: Complex64::from(y)}),
-				   CatalyticActivity{molps: Complex64::from(x)/Complex64::from(y)}
+		assert_approx_equal(
+			Concentration::from_molpm3(1.66053906717385e-24_f64).to_molpm3(),
+			Concentration::from_count_per_cubic_meter(1.0_f64).to_molpm3(), 9
 		);
-		assert_eq!(div_check(
-			&Complex32::from(x as f32), &InverseSpecificHeatCapacity{kgK_per_J: Complex32::from(y as f32)}),
-				   SpecificHeatCapacity{J_per_kgK: Complex32::from(x as f32)/Complex32::from(y as f32)}
+		assert_approx_equal(
+			Concentration::from_molpm3(1.0_f64).to_molpm3() * 6.02214076e+23,
+			Concentration::from_molpm3(1.0_f64).to_count_per_cubic_meter(), 9
 		);
-		assert_eq!(div_check(
-			&Complex64::from(x), &InverseSpecificHeatCapacity{kgK_per_J: Complex64::from(y)}),
-				   SpecificHeatCapacity{J_per_kgK: Complex64::from(x)/Complex64::from(y)}
+		assert_approx_equal(
+			Concentration::from_molpm3(1.66053906717385e-21_f64).to_molpm3(),
+			Concentration::from_NpL(1.0_f64).to_molpm3(), 9
 		);
-		assert_eq!(div_check(
-			&Complex32::from(x as f32), &MolarVolume{m3_per_mol: Complex32::from(y as f32)}),
-				   Concentration{molpm3: Complex32::from(x as f32)/Complex32::from(y as f32)}
+		assert_approx_equal(
+			Concentration::from_molpm3(1.0_f64).to_molpm3() * 6.02214076e+26,
+			Concentration::from_molpm3(1.0_f64).to_NpL(), 9
 		);
-		assert_eq!(div_check(
-			&Complex64::from(x), &MolarVolume{m3_per_mol: Complex64::from(y)}),
-				   Concentration{molpm3: Complex64::from(x)/Complex64::from(y)}
+		assert_approx_equal(
+			Concentration::from_molpm3(1.66053906717385e-21_f64).to_molpm3(),
+			Concentration::from_count_per_L(1.0_f64).to_molpm3(), 9
 		);
-		assert_eq!(div_check(
-			&Complex32::from(x as f32), &SpecificHeatCapacity{J_per_kgK: Complex32::from(y as f32)}),
-				   InverseSpecificHeatCapacity{kgK_per_J: Complex32::from(x as f32)/Complex32::from(y as f32)}
+		assert_approx_equal(
+			Concentration::from_molpm3(1.0_f64).to_molpm3() * 6.02214076e+26,
+			Concentration::from_molpm3(1.0_f64).to_count_per_L(), 9
 		);
-		assert_eq!(div_check(
-			&Complex64::from(x), &SpecificHeatCapacity{J_per_kgK: Complex64::from(y)}),
-				   InverseSpecificHeatCapacity{kgK_per_J: Complex64::from(x)/Complex64::from(y)}
+		assert_approx_equal(
+			Concentration::from_molpm3(1.66053906717385e-18_f64).to_molpm3(),
+			Concentration::from_Npcc(1.0_f64).to_molpm3(), 9
 		);
-		assert_eq!(div_check(
-			&Complex32::from(x as f32), &AreaPerLumen{m2_per_lm: Complex32::from(y as f32)}),
-				   Illuminance{lux: Complex32::from(x as f32)/Complex32::from(y as f32)}
+		assert_approx_equal(
+			Concentration::from_molpm3(1.0_f64).to_molpm3() * 6.02214076e+29,
+			Concentration::from_molpm3(1.0_f64).to_Npcc(), 9
 		);
-		assert_eq!(div_check(
-			&Complex64::from(x), &AreaPerLumen{m2_per_lm: Complex64::from(y)}),
-				   Illuminance{lux: Complex64::from(x)/Complex64::from(y)}
+		assert_approx_equal(
+			Concentration::from_molpm3(1.66053906717385e-18_f64).to_molpm3(),
+			Concentration::from_count_per_cc(1.0_f64).to_molpm3(), 9
 		);
-		assert_eq!(div_check(
-			&Complex32::from(x as f32), &Capacitance{F: Complex32::from(y as f32)}),
-				   Elastance{per_F: Complex32::from(x as f32)/Complex32::from(y as f32)}
+		assert_approx_equal(
+			Concentration::from_molpm3(1.0_f64).to_molpm3() * 6.02214076e+29,
+			Concentration::from_molpm3(1.0_f64).to_count_per_cc(), 9
 		);
-		assert_eq!(div_check(
-			&Complex64::from(x), &Capacitance{F: Complex64::from(y)}),
-				   Elastance{per_F: Complex64::from(x)/Complex64::from(y)}
+		assert_approx_equal(
+			Concentration::from_molpm3(1000.0_f64).to_molpm3(),
+			Concentration::from_M(1.0_f64).to_molpm3(), 9
 		);
-		assert_eq!(div_check(
-			&Complex32::from(x as f32), &Charge{C: Complex32::from(y as f32)}),
-				   InverseCharge{per_C: Complex32::from(x as f32)/Complex32::from(y as f32)}
+		assert_approx_equal(
+			Concentration::from_molpm3(1.0_f64).to_molpm3() * 0.001,
+			Concentration::from_molpm3(1.0_f64).to_M(), 9
 		);
-		assert_eq!(div_check(
-			&Complex64::from(x), &Charge{C: Complex64::from(y)}),
-				   InverseCharge{per_C: Complex64::from(x)/Complex64::from(y)}
+		assert_approx_equal(
+			Concentration::from_molpm3(1000.0_f64).to_molpm3(),
+			Concentration::from_molarity(1.0_f64).to_molpm3(), 9
 		);
-		assert_eq!(div_check(
-			&Complex32::from(x as f32), &Elastance{per_F: Complex32::from(y as f32)}),
-				   Capacitance{F: Complex32::from(x as f32)/Complex32::from(y as f32)}
+		assert_approx_equal(
+			Concentration::from_molpm3(1.0_f64).to_molpm3() * 0.001,
+			Concentration::from_molpm3(1.0_f64).to_molarity(), 9
 		);
-		assert_eq!(div_check(
-			&Complex64::from(x), &Elastance{per_F: Complex64::from(y)}),
-				   Capacitance{F: Complex64::from(x)/Complex64::from(y)}
+		assert_approx_equal(
+			Concentration::from_molpm3(0.001_f64).to_molpm3(),
+			Concentration::from_uM(1.0_f64).to_molpm3(), 9
+		);
+		assert_approx_equal(
+			Concentration::from_molpm3(1.0_f64).to_molpm3() * 1000.0,
+			Concentration::from_molpm3(1.0_f64).to_uM(), 9
 		);
-		assert_eq!(div_check(
-			&Complex32::from(x as f32), &Illuminance{lux: Complex32::from(y as f32)}),
-				   AreaPerLumen{m2_per_lm: Complex32::from(x as f32)/Complex32::from(y as f32)}
+		assert_approx_equal(
+			Concentration::from_molpm3(1e-06_f64).to_molpm3(),
+			Concentration::from_nM(1.0_f64).to_molpm3(), 9
 		);
-		assert_eq!(div_check(
-			&Complex64::from(x), &Illuminance{lux: Complex64::from(y)}),
-				   AreaPerLumen{m2_per_lm: Complex64::from(x)/Complex64::from(y)}
+		assert_approx_equal(
+			Concentration::from_molpm3(1.0_f64).to_molpm3() * 1000000.0,
+			Concentration::from_molpm3(1.0_f64).to_nM(), 9
 		);
-		assert_eq!(div_check(
-			&Complex32::from(x as f32), &Inductance{H: Complex32::from(y as f32)}),
-				   InverseInductance{per_H: Complex32::from(x as f32)/Complex32::from(y as f32)}
+	}
+
+	#[test]
+	fn molality_units() {
+		assert_approx_equal(
+			Molality::from_molpkg(0.001_f64).to_molpkg(),
+			Molality::from_mmolpkg(1.0_f64).to_molpkg(), 9
 		);
-		assert_eq!(div_check(
-			&Complex64::from(x), &Inductance{H: Complex64::from(y)}),
-				   InverseInductance{per_H: Complex64::from(x)/Complex64::from(y)}
+		assert_approx_equal(
+			Molality::from_molpkg(1.0_f64).to_molpkg() * 1000.0,
+			Molality::from_molpkg(1.0_f64).to_mmolpkg(), 9
 		);
-		assert_eq!(div_check(
-			&Complex32::from(x as f32), &InverseCharge{per_C: Complex32::from(y as f32)}),
-				   Charge{C: Complex32::from(x as f32)/Complex32::from(y as f32)}
+		assert_approx_equal(
+			Molality::from_molpkg(1e-06_f64).to_molpkg(),
+			Molality::from_umolpkg(1.0_f64).to_molpkg(), 9
 		);
-		assert_eq!(div_check(
-			&Complex64::from(x), &InverseCharge{per_C: Complex64::from(y)}),
-				   Charge{C: Complex64::from(x)/Complex64::from(y)}
+		assert_approx_equal(
+			Molality::from_molpkg(1.0_f64).to_molpkg() * 1000000.0,
+			Molality::from_molpkg(1.0_f64).to_umolpkg(), 9
 		);
-		assert_eq!(div_check(
-			&Complex32::from(x as f32), &InverseInductance{per_H: Complex32::from(y as f32)}),
-				   Inductance{H: Complex32::from(x as f32)/Complex32::from(y as f32)}
+		assert_approx_equal(
+			Molality::from_molpkg(1e-09_f64).to_molpkg(),
+			Molality::from_nmolpkg(1.0_f64).to_molpkg(), 9
 		);
-		assert_eq!(div_check(
-			&Complex64::from(x), &InverseInductance{per_H: Complex64::from(y)}),
-				   Inductance{H: Complex64::from(x)/Complex64::from(y)}
+		assert_approx_equal(
+			Molality::from_molpkg(1.0_f64).to_molpkg() * 1000000000.0,
+			Molality::from_molpkg(1.0_f64).to_nmolpkg(), 9
 		);
-		assert_eq!(div_check(
-			&Complex32::from(x as f32), &InverseLuminousFlux{per_lm: Complex32::from(y as f32)}),
-				   LuminousFlux{lm: Complex32::from(x as f32)/Complex32::from(y as f32)}
+		assert_approx_equal(
+			Molality::from_molpkg(0.001_f64).to_molpkg(),
+			Molality::from_umolpg(1.0_f64).to_molpkg(), 9
 		);
-		assert_eq!(div_check(
-			&Complex64::from(x), &InverseLuminousFlux{per_lm: Complex64::from(y)}),
-				   LuminousFlux{lm: Complex64::from(x)/Complex64::from(y)}
+		assert_approx_equal(
+			Molality::from_molpkg(1.0_f64).to_molpkg() * 1000.0,
+			Molality::from_molpkg(1.0_f64).to_umolpg(), 9
 		);
-		assert_eq!(div_check(
-			&Complex32::from(x as f32), &InverseMagneticFlux{per_Wb: Complex32::from(y as f32)}),
-				   MagneticFlux{Wb: Complex32::from(x as f32)/Complex32::from(y as f32)}
+		assert_approx_equal(
+			Molality::from_molpkg(1e-06_f64).to_molpkg(),
+			Molality::from_nmolpg(1.0_f64).to_molpkg(), 9
 		);
-		assert_eq!(div_check(
-			&Complex64::from(x), &InverseMagneticFlux{per_Wb: Complex64::from(y)}),
-				   MagneticFlux{Wb: Complex64::from(x)/Complex64::from(y)}
+		assert_approx_equal(
+			Molality::from_molpkg(1.0_f64).to_molpkg() * 1000000.0,
+			Molality::from_molpkg(1.0_f64).to_nmolpg(), 9
 		);
-		assert_eq!(div_check(
-			&Complex32::from(x as f32), &InverseMagneticFluxDensity{m2_per_Wb: Complex32::from(y as f32)}),
-				   MagneticFluxDensity{T: Complex32::from(x as f32)/Complex32::from(y as f32)}
+	}
+
+	#[test]
+	fn molar_mass_units() {
+		assert_approx_equal(
+			MolarMass::from_kgpmol(0.001_f64).to_kgpmol(),
+			MolarMass::from_gpmol(1.0_f64).to_kgpmol(), 9
 		);
-		assert_eq!(div_check(
-			&Complex64::from(x), &InverseMagneticFluxDensity{m2_per_Wb: Complex64::from(y)}),
-				   MagneticFluxDensity{T: Complex64::from(x)/Complex64::from(y)}
+		assert_approx_equal(
+			MolarMass::from_kgpmol(1.0_f64).to_kgpmol() * 1000.0,
+			MolarMass::from_kgpmol(1.0_f64).to_gpmol(), 9
 		);
-		assert_eq!(div_check(
-			&Complex32::from(x as f32), &InverseVoltage{per_V: Complex32::from(y as f32)}),
-				   Voltage{V: Complex32::from(x as f32)/Complex32::from(y as f32)}
+		assert_approx_equal(
+			MolarMass::from_kgpmol(0.001_f64).to_kgpmol(),
+			MolarMass::from_grams_per_mole(1.0_f64).to_kgpmol(), 9
 		);
-		assert_eq!(div_check(
-			&Complex64::from(x), &InverseVoltage{per_V: Complex64::from(y)}),
-				   Voltage{V: Complex64::from(x)/Complex64::from(y)}
+		assert_approx_equal(
+			MolarMass::from_kgpmol(1.0_f64).to_kgpmol() * 1000.0,
+			MolarMass::from_kgpmol(1.0_f64).to_grams_per_mole(), 9
 		);
-		assert_eq!(div_check(
-			&Complex32::from(x as f32), &LuminousFlux{lm: Complex32::from(y as f32)}),
-				   InverseLuminousFlux{per_lm: Complex32::from(x as f32)/Complex32::from(y as f32)}
+	}
+
+	#[test]
+	fn inverse_specific_heat_capacity_units() {
+		assert_approx_equal(
+			InverseSpecificHeatCapacity::from_kgK_per_J(0.001_f64).to_kgK_per_J(),
+			InverseSpecificHeatCapacity::from_grams_kelvin_per_joule(1.0_f64).to_kgK_per_J(), 9
 		);
-		assert_eq!(div_check(
-			&Complex64::from(x), &LuminousFlux{lm: Complex64::from(y)}),
-				   InverseLuminousFlux{per_lm: Complex64::from(x)/Complex64::from(y)}
+		assert_approx_equal(
+			InverseSpecificHeatCapacity::from_kgK_per_J(1.0_f64).to_kgK_per_J() * 1000.0,
+			InverseSpecificHeatCapacity::from_kgK_per_J(1.0_f64).to_grams_kelvin_per_joule(), 9
 		);
-		assert_eq!(div_check(
-			&Complex32::from(x as f32), &MagneticFlux{Wb: Complex32::from(y as f32)}),
-				   InverseMagneticFlux{per_Wb: Complex32::from(x as f32)/Complex32::from(y as f32)}
+		assert_approx_equal(
+			InverseSpecificHeatCapacity::from_kgK_per_J(0.001_f64).to_kgK_per_J(),
+			InverseSpecificHeatCapacity::from_gK_per_J(1.0_f64).to_kgK_per_J(), 9
 		);
-		assert_eq!(div_check(
-			&Complex64::from(x), &MagneticFlux{Wb: Complex64::from(y)}),
-				   InverseMagneticFlux{per_Wb: Complex64::from(x)/Complex64::from(y)}
+		assert_approx_equal(
+			InverseSpecificHeatCapacity::from_kgK_per_J(1.0_f64).to_kgK_per_J() * 1000.0,
+			InverseSpecificHeatCapacity::from_kgK_per_J(1.0_f64).to_gK_per_J(), 9
 		);
-		assert_eq!(div_check(
-			&Complex32::from(x as f32), &MagneticFluxDensity{T: Complex32::from(y as f32)}),
-				   InverseMagneticFluxDensity{m2_per_Wb: Complex32::from(x as f32)/Complex32::from(y as f32)}
+	}
+
+	#[test]
+	fn capacitance_units() {
+		assert_approx_equal(
+			Capacitance::from_F(0.001_f64).to_F(),
+			Capacitance::from_mF(1.0_f64).to_F(), 9
 		);
-		assert_eq!(div_check(
-			&Complex64::from(x), &MagneticFluxDensity{T: Complex64::from(y)}),
-				   InverseMagneticFluxDensity{m2_per_Wb: Complex64::from(x)/Complex64::from(y)}
+		assert_approx_equal(
+			Capacitance::from_F(1.0_f64).to_F() * 1000.0,
+			Capacitance::from_F(1.0_f64).to_mF(), 9
 		);
-		assert_eq!(div_check(
-			&Complex32::from(x as f32), &Voltage{V: Complex32::from(y as f32)}),
-				   InverseVoltage{per_V: Complex32::from(x as f32)/Complex32::from(y as f32)}
+		assert_approx_equal(
+			Capacitance::from_F(1e-06_f64).to_F(),
+			Capacitance::from_uF(1.0_f64).to_F(), 9
 		);
-		assert_eq!(div_check(
-			&Complex64::from(x), &Voltage{V: Complex64::from(y)}),
-				   InverseVoltage{per_V: Complex64::from(x)/Complex64::from(y)}
+		assert_approx_equal(
+			Capacitance::from_F(1.0_f64).to_F() * 1000000.0,
+			Capacitance::from_F(1.0_f64).to_uF(), 9
 		);
-		assert_eq!(div_check(
-			&Complex32::from(x as f32), &AreaPerLumen{m2_per_lm: Complex32::from(y as f32)}),
-				   Illuminance{lux: Complex32::from(x as f32)/Complex32::from(y as f32)}
+		assert_approx_equal(
+			Capacitance::from_F(1e-09_f64).to_F(),
+			Capacitance::from_nF(1.0_f64).to_F(), 9
 		);
-		assert_eq!(div_check(
-			&Complex64::from(x), &AreaPerLumen{m2_per_lm: Complex64::from(y)}),
-				   Illuminance{lux: Complex64::from(x)/Complex64::from(y)}
+		assert_approx_equal(
+			Capacitance::from_F(1.0_f64).to_F() * 1000000000.0,
+			Capacitance::from_F(1.0_f64).to_nF(), 9
 		);
-		assert_eq!(div_check(
-			&Complex32::from(x as f32), &Capacitance{F: Complex32::from(y as f32)}),
-				   Elastance{per_F: Complex32::from(x as f32)/Complex32::from(y as f32)}
+		assert_approx_equal(
+			Capacitance::from_F(1e-12_f64).to_F(),
+			Capacitance::from_pF(1.0_f64).to_F(), 9
 		);
-		assert_eq!(div_check(
-			&Complex64::from(x), &Capacitance{F: Complex64::from(y)}),
-				   Elastance{per_F: Complex64::from(x)/Complex64::from(y)}
+		assert_approx_equal(
+			Capacitance::from_F(1.0_f64).to_F() * 1000000000000.0,
+			Capacitance::from_F(1.0_f64).to_pF(), 9
 		);
-		assert_eq!(div_check(
-			&Complex32::from(x as f32), &Charge{C: Complex32::from(y as f32)}),
-				   InverseCharge{per_C: Complex32::from(x as f32)/Complex32::from(y as f32)}
+		assert_approx_equal(
+			Capacitance::from_F(1000.0_f64).to_F(),
+			Capacitance::from_kF(1.0_f64).to_F(), 9
 		);
-		assert_eq!(div_check(
-			&Complex64::from(x), &Charge{C: Complex64::from(y)}),
-				   InverseCharge{per_C: Complex64::from(x)/Complex64::from(y)}
+		assert_approx_equal(
+			Capacitance::from_F(1.0_f64).to_F() * 0.001,
+			Capacitance::from_F(1.0_f64).to_kF(), 9
 		);
-		assert_eq!(div_check(
-			&Complex32::from(x as f32), &Elastance{per_F: Complex32::from(y as f32)}),
-				   Capacitance{F: Complex32::from(x as f32)/Complex32::from(y as f32)}
+		assert_approx_equal(
+			Capacitance::from_F(1000000.0_f64).to_F(),
+			Capacitance::from_MF(1.0_f64).to_F(), 9
 		);
-		assert_eq!(div_check(
-			&Complex64::from(x), &Elastance{per_F: Complex64::from(y)}),
-				   Capacitance{F: Complex64::from(x)/Complex64::from(y)}
+		assert_approx_equal(
+			Capacitance::from_F(1.0_f64).to_F() * 1e-06,
+			Capacitance::from_F(1.0_f64).to_MF(), 9
 		);
-		assert_eq!(div_check(
-			&Complex32::from(x as f32), &Illuminance{lux: Complex32::from(y as f32)}),
-				   AreaPerLumen{m2_per_lm: Complex32::from(x as f32)/Complex32::from(y as f32)}
+		assert_approx_equal(
+			Capacitance::from_F(1000000000.0_f64).to_F(),
+			Capacitance::from_GF(1.0_f64).to_F(), 9
 		);
-		assert_eq!(div_check(
-			&Complex64::from(x), &Illuminance{lux: Complex64::from(y)}),
-				   AreaPerLumen{m2_per_lm: Complex64::from(x)/Complex64::from(y)}
+		assert_approx_equal(
+			Capacitance::from_F(1.0_f64).to_F() * 1e-09,
+			Capacitance::from_F(1.0_f64).to_GF(), 9
 		);
-		assert_eq!(div_check(
-			&Complex32::from(x as f32), &Inductance{H: Complex32::from(y as f32)}),
-				   InverseInductance{per_H: Complex32::from(x as f32)/Complex32::from(y as f32)}
+	}
+
+	#[test]
+	fn charge_units() {
+		assert_approx_equal(
+			Charge::from_C(0.001_f64).to_C(),
+			Charge::from_mC(1.0_f64).to_C(), 9
 		);
-		assert_eq!(div_check(
-			&Complex64::from(x), &Inductance{H: Complex64::from(y)}),
-				   InverseInductance{per_H: Complex64::from(x)/Complex64::from(y)}
+		assert_approx_equal(
+			Charge::from_C(1.0_f64).to_C() * 1000.0,
+			Charge::from_C(1.0_f64).to_mC(), 9
 		);
-		assert_eq!(div_check(
-			&Complex32::from(x as f32), &InverseCharge{per_C: Complex32::from(y as f32)}),
-				   Charge{C: Complex32::from(x as f32)/Complex32::from(y as f32)}
+		assert_approx_equal(
+			Charge::from_C(1e-06_f64).to_C(),
+			Charge::from_uC(1.0_f64).to_C(), 9
 		);
-		assert_eq!(div_check(
-			&Complex64::from(x), &InverseCharge{per_C: Complex64::from(y)}),
-				   Charge{C: Complex64::from(x)/Complex64::from(y)}
+		assert_approx_equal(
+			Charge::from_C(1.0_f64).to_C() * 1000000.0,
+			Charge::from_C(1.0_f64).to_uC(), 9
 		);
-		assert_eq!(div_check(
-			&Complex32::from(x as f32), &InverseInductance{per_H: Complex32::from(y as f32)}),
-				   Inductance{H: Complex32::from(x as f32)/Complex32::from(y as f32)}
+		assert_approx_equal(
+			Charge::from_C(1e-09_f64).to_C(),
+			Charge::from_nC(1.0_f64).to_C(), 9
 		);
-		assert_eq!(div_check(
-			&Complex64::from(x), &InverseInductance{per_H: Complex64::from(y)}),
-				   Inductance{H: Complex64::from(x)/Complex64::from(y)}
+		assert_approx_equal(
+			Charge::from_C(1.0_f64).to_C() * 1000000000.0,
+			Charge::from_C(1.0_f64).to_nC(), 9
 		);
-		assert_eq!(div_check(
-			&Complex32::from(x as f32), &InverseLuminousFlux{per_lm: Complex32::from(y as f32)}),
-				   LuminousFlux{lm: Complex32::from(x as f32)/Complex32::from(y as f32)}
+		assert_approx_equal(
+			Charge::from_C(1000.0_f64).to_C(),
+			Charge::from_kC(1.0_f64).to_C(), 9
 		);
-		assert_eq!(div_check(
-			&Complex64::from(x), &InverseLuminousFlux{per_lm: Complex64::from(y)}),
-				   LuminousFlux{lm: Complex64::from(x)/Complex64::from(y)}
+		assert_approx_equal(
+			Charge::from_C(1.0_f64).to_C() * 0.001,
+			Charge::from_C(1.0_f64).to_kC(), 9
 		);
-		assert_eq!(div_check(
-			&Complex32::from(x as f32), &InverseMagneticFlux{per_Wb: Complex32::from(y as f32)}),
-				   MagneticFlux{Wb: Complex32::from(x as f32)/Complex32::from(y as f32)}
+		assert_approx_equal(
+			Charge::from_C(1000000.0_f64).to_C(),
+			Charge::from_MC(1.0_f64).to_C(), 9
 		);
-		assert_eq!(div_check(
-			&Complex64::from(x), &InverseMagneticFlux{per_Wb: Complex64::from(y)}),
-				   MagneticFlux{Wb: Complex64::from(x)/Complex64::from(y)}
+		assert_approx_equal(
+			Charge::from_C(1.0_f64).to_C() * 1e-06,
+			Charge::from_C(1.0_f64).to_MC(), 9
 		);
-		assert_eq!(div_check(
-			&Complex32::from(x as f32), &InverseMagneticFluxDensity{m2_per_Wb: Complex32::from(y as f32)}),
-				   MagneticFluxDensity{T: Complex32::from(x as f32)/Complex32::from(y as f32)}
+		assert_approx_equal(
+			Charge::from_C(1000000000.0_f64).to_C(),
+			Charge::from_GC(1.0_f64).to_C(), 9
 		);
-		assert_eq!(div_check(
-			&Complex64::from(x), &InverseMagneticFluxDensity{m2_per_Wb: Complex64::from(y)}),
-				   MagneticFluxDensity{T: Complex64::from(x)/Complex64::from(y)}
+		assert_approx_equal(
+			Charge::from_C(1.0_f64).to_C() * 1e-09,
+			Charge::from_C(1.0_f64).to_GC(), 9
 		);
-		assert_eq!(div_check(
-			&Complex32::from(x as f32), &InverseVoltage{per_V: Complex32::from(y as f32)}),
-				   Voltage{V: Complex32::from(x as f32)/Complex32::from(y as f32)}
+		assert_approx_equal(
+			Charge::from_C(1.6021766340000001e-19_f64).to_C(),
+			Charge::from_p(1.0_f64).to_C(), 9
 		);
-		assert_eq!(div_check(
-			&Complex64::from(x), &InverseVoltage{per_V: Complex64::from(y)}),
-				   Voltage{V: Complex64::from(x)/Complex64::from(y)}
+		assert_approx_equal(
+			Charge::from_C(1.0_f64).to_C() * 6.24150907446076e+18,
+			Charge::from_C(1.0_f64).to_p(), 9
 		);
-		assert_eq!(div_check(
-			&Complex32::from(x as f32), &LuminousFlux{lm: Complex32::from(y as f32)}),
-				   InverseLuminousFlux{per_lm: Complex32::from(x as f32)/Complex32::from(y as f32)}
+		assert_approx_equal(
+			Charge::from_C(-1.6021766340000001e-19_f64).to_C(),
+			Charge::from_e(1.0_f64).to_C(), 9
 		);
-		assert_eq!(div_check(
-			&Complex64::from(x), &LuminousFlux{lm: Complex64::from(y)}),
-				   InverseLuminousFlux{per_lm: Complex64::from(x)/Complex64::from(y)}
+		assert_approx_equal(
+			Charge::from_C(1.0_f64).to_C() * -6.24150907446076e+18,
+			Charge::from_C(1.0_f64).to_e(), 9
 		);
-		assert_eq!(div_check(
-			&Complex32::from(x as f32), &MagneticFlux{Wb: Complex32::from(y as f32)}),
-				   InverseMagneticFlux{per_Wb: Complex32::from(x as f32)/Complex32::from(y as f32)}
+	}
+
+	#[test]
+	fn conductance_units() {
+		assert_approx_equal(
+			Conductance::from_S(0.001_f64).to_S(),
+			Conductance::from_mS(1.0_f64).to_S(), 9
 		);
-		assert_eq!(div_check(
-			&Complex64::from(x), &MagneticFlux{Wb: Complex64::from(y)}),
-				   InverseMagneticFlux{per_Wb: Complex64::from(x)/Complex64::from(y)}
+		assert_approx_equal(
+			Conductance::from_S(1.0_f64).to_S() * 1000.0,
+			Conductance::from_S(1.0_f64).to_mS(), 9
 		);
-		assert_eq!(div_check(
-			&Complex32::from(x as f32), &MagneticFluxDensity{T: Complex32::from(y as f32)}),
-				   InverseMagneticFluxDensity{m2_per_Wb: Complex32::from(x as f32)/Complex32::from(y as f32)}
+		assert_approx_equal(
+			Conductance::from_S(1e-06_f64).to_S(),
+			Conductance::from_uS(1.0_f64).to_S(), 9
 		);
-		assert_eq!(div_check(
-			&Complex64::from(x), &MagneticFluxDensity{T: Complex64::from(y)}),
-				   InverseMagneticFluxDensity{m2_per_Wb: Complex64::from(x)/Complex64::from(y)}
+		assert_approx_equal(
+			Conductance::from_S(1.0_f64).to_S() * 1000000.0,
+			Conductance::from_S(1.0_f64).to_uS(), 9
 		);
-		assert_eq!(div_check(
-			&Complex32::from(x as f32), &Voltage{V: Complex32::from(y as f32)}),
-				   InverseVoltage{per_V: Complex32::from(x as f32)/Complex32::from(y as f32)}
+		assert_approx_equal(
+			Conductance::from_S(1e-09_f64).to_S(),
+			Conductance::from_nS(1.0_f64).to_S(), 9
 		);
-		assert_eq!(div_check(
-			&Complex64::from(x), &Voltage{V: Complex64::from(y)}),
-				   InverseVoltage{per_V: Complex64::from(x)/Complex64::from(y)}
+		assert_approx_equal(
+			Conductance::from_S(1.0_f64).to_S() * 1000000000.0,
+			Conductance::from_S(1.0_f64).to_nS(), 9
 		);
-		assert_eq!(div_check(
-			&Complex32::from(x as f32), &Angle{rad: Complex32::from(y as f32)}),
-				   InverseAngle{per_rad: Complex32::from(x as f32)/Complex32::from(y as f32)}
+		assert_approx_equal(
+			Conductance::from_S(1000.0_f64).to_S(),
+			Conductance::from_kS(1.0_f64).to_S(), 9
 		);
-		assert_eq!(div_check(
-			&Complex64::from(x), &Angle{rad: Complex64::from(y)}),
-				   InverseAngle{per_rad: Complex64::from(x)/Complex64::from(y)}
+		assert_approx_equal(
+			Conductance::from_S(1.0_f64).to_S() * 0.001,
+			Conductance::from_S(1.0_f64).to_kS(), 9
 		);
-		assert_eq!(div_check(
-			&Complex32::from(x as f32), &Area{m2: Complex32::from(y as f32)}),
-				   InverseArea{per_m2: Complex32::from(x as f32)/Complex32::from(y as f32)}
+		assert_approx_equal(
+			Conductance::from_S(1000000.0_f64).to_S(),
+			Conductance::from_MS(1.0_f64).to_S(), 9
 		);
-		assert_eq!(div_check(
-			&Complex64::from(x), &Area{m2: Complex64::from(y)}),
-				   InverseArea{per_m2: Complex64::from(x)/Complex64::from(y)}
+		assert_approx_equal(
+			Conductance::from_S(1.0_f64).to_S() * 1e-06,
+			Conductance::from_S(1.0_f64).to_MS(), 9
 		);
-		assert_eq!(div_check(
-			&Complex32::from(x as f32), &InverseAngle{per_rad: Complex32::from(y as f32)}),
-				   Angle{rad: Complex32::from(x as f32)/Complex32::from(y as f32)}
+		assert_approx_equal(
+			Conductance::from_S(1000000000.0_f64).to_S(),
+			Conductance::from_GS(1.0_f64).to_S(), 9
 		);
-		assert_eq!(div_check(
-			&Complex64::from(x), &InverseAngle{per_rad: Complex64::from(y)}),
-				   Angle{rad: Complex64::from(x)/Complex64::from(y)}
+		assert_approx_equal(
+			Conductance::from_S(1.0_f64).to_S() * 1e-09,
+			Conductance::from_S(1.0_f64).to_GS(), 9
 		);
-		assert_eq!(div_check(
-			&Complex32::from(x as f32), &InverseArea{per_m2: Complex32::from(y as f32)}),
-				   Area{m2: Complex32::from(x as f32)/Complex32::from(y as f32)}
+	}
+
+	#[test]
+	fn illuminance_units() {
+		assert_approx_equal(
+			Illuminance::from_lux(0.001_f64).to_lux(),
+			Illuminance::from_mlux(1.0_f64).to_lux(), 9
 		);
-		assert_eq!(div_check(
-			&Complex64::from(x), &InverseArea{per_m2: Complex64::from(y)}),
-				   Area{m2: Complex64::from(x)/Complex64::from(y)}
+		assert_approx_equal(
+			Illuminance::from_lux(1.0_f64).to_lux() * 1000.0,
+			Illuminance::from_lux(1.0_f64).to_mlux(), 9
 		);
-		assert_eq!(div_check(
-			&Complex32::from(x as f32), &InverseSolidAngle{per_sr: Complex32::from(y as f32)}),
-				   SolidAngle{sr: Complex32::from(x as f32)/Complex32::from(y as f32)}
+		assert_approx_equal(
+			Illuminance::from_lux(1e-06_f64).to_lux(),
+			Illuminance::from_ulux(1.0_f64).to_lux(), 9
 		);
-		assert_eq!(div_check(
-			&Complex64::from(x), &InverseSolidAngle{per_sr: Complex64::from(y)}),
-				   SolidAngle{sr: Complex64::from(x)/Complex64::from(y)}
+		assert_approx_equal(
+			Illuminance::from_lux(1.0_f64).to_lux() * 1000000.0,
+			Illuminance::from_lux(1.0_f64).to_ulux(), 9
 		);
-		assert_eq!(div_check(
-			&Complex32::from(x as f32), &InverseVolume{per_m3: Complex32::from(y as f32)}),
-				   Volume{m3: Complex32::from(x as f32)/Complex32::from(y as f32)}
+		assert_approx_equal(
+			Illuminance::from_lux(1e-09_f64).to_lux(),
+			Illuminance::from_nlux(1.0_f64).to_lux(), 9
 		);
-		assert_eq!(div_check(
-			&Complex64::from(x), &InverseVolume{per_m3: Complex64::from(y)}),
-				   Volume{m3: Complex64::from(x)/Complex64::from(y)}
+		assert_approx_equal(
+			Illuminance::from_lux(1.0_f64).to_lux() * 1000000000.0,
+			Illuminance::from_lux(1.0_f64).to_nlux(), 9
 		);
-		assert_eq!(div_check(
-			&Complex32::from(x as f32), &SolidAngle{sr: Complex32::from(y as f32)}),
-				   InverseSolidAngle{per_sr: Complex32::from(x as f32)/Complex32::from(y as f32)}
+		assert_approx_equal(
+			Illuminance::from_lux(1000.0_f64).to_lux(),
+			Illuminance::from_klux(1.0_f64).to_lux(), 9
 		);
-		assert_eq!(div_check(
-			&Complex64::from(x), &SolidAngle{sr: Complex64::from(y)}),
-				   InverseSolidAngle{per_sr: Complex64::from(x)/Complex64::from(y)}
+		assert_approx_equal(
+			Illuminance::from_lux(1.0_f64).to_lux() * 0.001,
+			Illuminance::from_lux(1.0_f64).to_klux(), 9
 		);
-		assert_eq!(div_check(
-			&Complex32::from(x as f32), &Volume{m3: Complex32::from(y as f32)}),
-				   InverseVolume{per_m3: Complex32::from(x as f32)/Complex32::from(y as f32)}
+		assert_approx_equal(
+			Illuminance::from_lux(1000000.0_f64).to_lux(),
+			Illuminance::from_Mlux(1.0_f64).to_lux(), 9
 		);
-		assert_eq!(div_check(
-			&Complex64::from(x), &Volume{m3: Complex64::from(y)}),
-				   InverseVolume{per_m3: Complex64::from(x)/Complex64::from(y)}
+		assert_approx_equal(
+			Illuminance::from_lux(1.0_f64).to_lux() * 1e-06,
+			Illuminance::from_lux(1.0_f64).to_Mlux(), 9
 		);
-		assert_eq!(div_check(
-			&Complex32::from(x as f32), &Angle{rad: Complex32::from(y as f32)}),
-				   InverseAngle{per_rad: Complex32::from(x as f32)/Complex32::from(y as f32)}
+		assert_approx_equal(
+			Illuminance::from_lux(1000000000.0_f64).to_lux(),
+			Illuminance::from_Glux(1.0_f64).to_lux(), 9
 		);
-		assert_eq!(div_check(
-			&Complex64::from(x), &Angle{rad: Complex64::from(y)}),
-				   InverseAngle{per_rad: Complex64::from(x)/Complex64::from(y)}
+		assert_approx_equal(
+			Illuminance::from_lux(1.0_f64).to_lux() * 1e-09,
+			Illuminance::from_lux(1.0_f64).to_Glux(), 9
 		);
-		assert_eq!(div_check(
-			&Complex32::from(x as f32), &Area{m2: Complex32::from(y as f32)}),
-				   InverseArea{per_m2: Complex32::from(x as f32)/Complex32::from(y as f32)}
+	}
+
+	#[test]
+	fn inductance_units() {
+		assert_approx_equal(
+			Inductance::from_H(0.001_f64).to_H(),
+			Inductance::from_mH(1.0_f64).to_H(), 9
 		);
-		assert_eq!(div_check(
-			&Complex64::from(x), &Area{m2: Complex64::from(y)}),
-				   InverseArea{per_m2: Complex64::from(x)/Complex64::from(y)}
+		assert_approx_equal(
+			Inductance::from_H(1.0_f64).to_H() * 1000.0,
+			Inductance::from_H(1.0_f64).to_mH(), 9
 		);
-		assert_eq!(div_check(
-			&Complex32::from(x as f32), &InverseAngle{per_rad: Complex32::from(y as f32)}),
-				   Angle{rad: Complex32::from(x as f32)/Complex32::from(y as f32)}
+		assert_approx_equal(
+			Inductance::from_H(1e-06_f64).to_H(),
+			Inductance::from_uH(1.0_f64).to_H(), 9
 		);
-		assert_eq!(div_check(
-			&Complex64::from(x), &InverseAngle{per_rad: Complex64::from(y)}),
-				   Angle{rad: Complex64::from(x)/Complex64::from(y)}
+		assert_approx_equal(
+			Inductance::from_H(1.0_f64).to_H() * 1000000.0,
+			Inductance::from_H(1.0_f64).to_uH(), 9
 		);
-		assert_eq!(div_check(
-			&Complex32::from(x as f32), &InverseArea{per_m2: Complex32::from(y as f32)}),
-				   Area{m2: Complex32::from(x as f32)/Complex32::from(y as f32)}
+		assert_approx_equal(
+			Inductance::from_H(1e-09_f64).to_H(),
+			Inductance::from_nH(1.0_f64).to_H(), 9
+		);
+		assert_approx_equal(
+			Inductance::from_H(1.0_f64).to_H() * 1000000000.0,
+			Inductance::from_H(1.0_f64).to_nH(), 9
 		);
-		assert_eq!(div_check(
-			&Complex64::from(x), &InverseArea{per_m2: Complex64::from(y)}),
-				   Area{m2: Complex64::from(x)/Complex64::from(y)}
+		assert_approx_equal(
+			Inductance::from_H(1000.0_f64).to_H(),
+			Inductance::from_kH(1.0_f64).to_H(), 9
 		);
-		assert_eq!(div_check(
-			&Complex32::from(x as f32), &InverseSolidAngle{per_sr: Complex32::from(y as f32)}),
-				   SolidAngle{sr: Complex32::from(x as f32)/Complex32::from(y as f32)}
+		assert_approx_equal(
+			Inductance::from_H(1.0_f64).to_H() * 0.001,
+			Inductance::from_H(1.0_f64).to_kH(), 9
 		);
-		assert_eq!(div_check(
-			&Complex64::from(x), &InverseSolidAngle{per_sr: Complex64::from(y)}),
-				   SolidAngle{sr: Complex64::from(x)/Complex64::from(y)}
+		assert_approx_equal(
+			Inductance::from_H(1000000.0_f64).to_H(),
+			Inductance::from_MH(1.0_f64).to_H(), 9
 		);
-		assert_eq!(div_check(
-			&Complex32::from(x as f32), &InverseVolume{per_m3: Complex32::from(y as f32)}),
-				   Volume{m3: Complex32::from(x as f32)/Complex32::from(y as f32)}
+		assert_approx_equal(
+			Inductance::from_H(1.0_f64).to_H() * 1e-06,
+			Inductance::from_H(1.0_f64).to_MH(), 9
 		);
-		assert_eq!(div_check(
-			&Complex64::from(x), &InverseVolume{per_m3: Complex64::from(y)}),
-				   Volume{m3: Complex64::from(x)/Complex64::from(y)}
+		assert_approx_equal(
+			Inductance::from_H(1000000000.0_f64).to_H(),
+			Inductance::from_GH(1.0_f64).to_H(), 9
 		);
-		assert_eq!(div_check(
-			&Complex32::from(x as f32), &SolidAngle{sr: Complex32::from(y as f32)}),
-				   InverseSolidAngle{per_sr: Complex32::from(x as f32)/Complex32::from(y as f32)}
+		assert_approx_equal(
+			Inductance::from_H(1.0_f64).to_H() * 1e-09,
+			Inductance::from_H(1.0_f64).to_GH(), 9
 		);
-		assert_eq!(div_check(
-			&Complex64::from(x), &SolidAngle{sr: Complex64::from(y)}),
-				   InverseSolidAngle{per_sr: Complex64::from(x)/Complex64::from(y)}
+	}
+
+	#[test]
+	fn luminous_flux_units() {
+		assert_approx_equal(
+			LuminousFlux::from_lm(0.001_f64).to_lm(),
+			LuminousFlux::from_mlm(1.0_f64).to_lm(), 9
 		);
-		assert_eq!(div_check(
-			&Complex32::from(x as f32), &Volume{m3: Complex32::from(y as f32)}),
-				   InverseVolume{per_m3: Complex32::from(x as f32)/Complex32::from(y as f32)}
+		assert_approx_equal(
+			LuminousFlux::from_lm(1.0_f64).to_lm() * 1000.0,
+			LuminousFlux::from_lm(1.0_f64).to_mlm(), 9
 		);
-		assert_eq!(div_check(
-			&Complex64::from(x), &Volume{m3: Complex64::from(y)}),
-				   InverseVolume{per_m3: Complex64::from(x)/Complex64::from(y)}
+		assert_approx_equal(
+			LuminousFlux::from_lm(1e-06_f64).to_lm(),
+			LuminousFlux::from_ulm(1.0_f64).to_lm(), 9
 		);
-		assert_eq!(div_check(
-			&Complex32::from(x as f32), &Acceleration{mps2: Complex32::from(y as f32)}),
-				   InverseAcceleration{s2pm: Complex32::from(x as f32)/Complex32::from(y as f32)}
+		assert_approx_equal(
+			LuminousFlux::from_lm(1.0_f64).to_lm() * 1000000.0,
+			LuminousFlux::from_lm(1.0_f64).to_ulm(), 9
 		);
-		assert_eq!(div_check(
-			&Complex64::from(x), &Acceleration{mps2: Complex64::from(y)}),
-				   InverseAcceleration{s2pm: Complex64::from(x)/Complex64::from(y)}
+		assert_approx_equal(
+			LuminousFlux::from_lm(1e-09_f64).to_lm(),
+			LuminousFlux::from_nlm(1.0_f64).to_lm(), 9
 		);
-		assert_eq!(div_check(
-			&Complex32::from(x as f32), &AngularAcceleration{radps2: Complex32::from(y as f32)}),
-				   InverseAngularAcceleration{s2prad: Complex32::from(x as f32)/Complex32::from(y as f32)}
+		assert_approx_equal(
+			LuminousFlux::from_lm(1.0_f64).to_lm() * 1000000000.0,
+			LuminousFlux::from_lm(1.0_f64).to_nlm(), 9
 		);
-		assert_eq!(div_check(
-			&Complex64::from(x), &AngularAcceleration{radps2: Complex64::from(y)}),
-				   InverseAngularAcceleration{s2prad: Complex64::from(x)/Complex64::from(y)}
+		assert_approx_equal(
+			LuminousFlux::from_lm(1000.0_f64).to_lm(),
+			LuminousFlux::from_klm(1.0_f64).to_lm(), 9
 		);
-		assert_eq!(div_check(
-			&Complex32::from(x as f32), &AngularMomentum{kgm2radps: Complex32::from(y as f32)}),
-				   InverseAngularMomentum{s_per_kgm2rad: Complex32::from(x as f32)/Complex32::from(y as f32)}
+		assert_approx_equal(
+			LuminousFlux::from_lm(1.0_f64).to_lm() * 0.001,
+			LuminousFlux::from_lm(1.0_f64).to_klm(), 9
 		);
-		assert_eq!(div_check(
-			&Complex64::from(x), &AngularMomentum{kgm2radps: Complex64::from(y)}),
-				   InverseAngularMomentum{s_per_kgm2rad: Complex64::from(x)/Complex64::from(y)}
+		assert_approx_equal(
+			LuminousFlux::from_lm(1000000.0_f64).to_lm(),
+			LuminousFlux::from_Mlm(1.0_f64).to_lm(), 9
 		);
-		assert_eq!(div_check(
-			&Complex32::from(x as f32), &AngularVelocity{radps: Complex32::from(y as f32)}),
-				   InverseAngularVelocity{s_per_rad: Complex32::from(x as f32)/Complex32::from(y as f32)}
+		assert_approx_equal(
+			LuminousFlux::from_lm(1.0_f64).to_lm() * 1e-06,
+			LuminousFlux::from_lm(1.0_f64).to_Mlm(), 9
 		);
-		assert_eq!(div_check(
-			&Complex64::from(x), &AngularVelocity{radps: Complex64::from(y)}),
-				   InverseAngularVelocity{s_per_rad: Complex64::from(x)/Complex64::from(y)}
+		assert_approx_equal(
+			LuminousFlux::from_lm(1000000000.0_f64).to_lm(),
+			LuminousFlux::from_Glm(1.0_f64).to_lm(), 9
 		);
-		assert_eq!(div_check(
-			&Complex32::from(x as f32), &AreaDensity{kgpm2: Complex32::from(y as f32)}),
-				   AreaPerMass{m2_per_kg: Complex32::from(x as f32)/Complex32::from(y as f32)}
+		assert_approx_equal(
+			LuminousFlux::from_lm(1.0_f64).to_lm() * 1e-09,
+			LuminousFlux::from_lm(1.0_f64).to_Glm(), 9
 		);
-		assert_eq!(div_check(
-			&Complex64::from(x), &AreaDensity{kgpm2: Complex64::from(y)}),
-				   AreaPerMass{m2_per_kg: Complex64::from(x)/Complex64::from(y)}
+	}
+
+	#[test]
+	fn magnetic_flux_units() {
+		assert_approx_equal(
+			MagneticFlux::from_Wb(0.001_f64).to_Wb(),
+			MagneticFlux::from_mWb(1.0_f64).to_Wb(), 9
 		);
-		assert_eq!(div_check(
-			&Complex32::from(x as f32), &AreaPerMass{m2_per_kg: Complex32::from(y as f32)}),
-				   AreaDensity{kgpm2: Complex32::from(x as f32)/Complex32::from(y as f32)}
+		assert_approx_equal(
+			MagneticFlux::from_Wb(1.0_f64).to_Wb() * 1000.0,
+			MagneticFlux::from_Wb(1.0_f64).to_mWb(), 9
 		);
-		assert_eq!(div_check(
-			&Complex64::from(x), &AreaPerMass{m2_per_kg: Complex64::from(y)}),
-				   AreaDensity{kgpm2: Complex64::from(x)/Complex64::from(y)}
+		assert_approx_equal(
+			MagneticFlux::from_Wb(1e-06_f64).to_Wb(),
+			MagneticFlux::from_uWb(1.0_f64).to_Wb(), 9
 		);
-		assert_eq!(div_check(
-			&Complex32::from(x as f32), &Density{kgpm3: Complex32::from(y as f32)}),
-				   VolumePerMass{m3_per_kg: Complex32::from(x as f32)/Complex32::from(y as f32)}
+		assert_approx_equal(
+			MagneticFlux::from_Wb(1.0_f64).to_Wb() * 1000000.0,
+			MagneticFlux::from_Wb(1.0_f64).to_uWb(), 9
 		);
-		assert_eq!(div_check(
-			&Complex64::from(x), &Density{kgpm3: Complex64::from(y)}),
-				   VolumePerMass{m3_per_kg: Complex64::from(x)/Complex64::from(y)}
+		assert_approx_equal(
+			MagneticFlux::from_Wb(1e-09_f64).to_Wb(),
+			MagneticFlux::from_nWb(1.0_f64).to_Wb(), 9
 		);
-		assert_eq!(div_check(
-			&Complex32::from(x as f32), &Energy{J: Complex32::from(y as f32)}),
-				   InverseEnergy{per_J: Complex32::from(x as f32)/Complex32::from(y as f32)}
+		assert_approx_equal(
+			MagneticFlux::from_Wb(1.0_f64).to_Wb() * 1000000000.0,
+			MagneticFlux::from_Wb(1.0_f64).to_nWb(), 9
 		);
-		assert_eq!(div_check(
-			&Complex64::from(x), &Energy{J: Complex64::from(y)}),
-				   InverseEnergy{per_J: Complex64::from(x)/Complex64::from(y)}
+		assert_approx_equal(
+			MagneticFlux::from_Wb(1000.0_f64).to_Wb(),
+			MagneticFlux::from_kWb(1.0_f64).to_Wb(), 9
 		);
-		assert_eq!(div_check(
-			&Complex32::from(x as f32), &Force{N: Complex32::from(y as f32)}),
-				   InverseForce{per_N: Complex32::from(x as f32)/Complex32::from(y as f32)}
+		assert_approx_equal(
+			MagneticFlux::from_Wb(1.0_f64).to_Wb() * 0.001,
+			MagneticFlux::from_Wb(1.0_f64).to_kWb(), 9
 		);
-		assert_eq!(div_check(
-			&Complex64::from(x), &Force{N: Complex64::from(y)}),
-				   InverseForce{per_N: Complex64::from(x)/Complex64::from(y)}
+		assert_approx_equal(
+			MagneticFlux::from_Wb(1000000.0_f64).to_Wb(),
+			MagneticFlux::from_MWb(1.0_f64).to_Wb(), 9
 		);
-		assert_eq!(div_check(
-			&Complex32::from(x as f32), &InverseAcceleration{s2pm: Complex32::from(y as f32)}),
-				   Acceleration{mps2: Complex32::from(x as f32)/Complex32::from(y as f32)}
+		assert_approx_equal(
+			MagneticFlux::from_Wb(1.0_f64).to_Wb() * 1e-06,
+			MagneticFlux::from_Wb(1.0_f64).to_MWb(), 9
 		);
-		assert_eq!(div_check(
-			&Complex64::from(x), &InverseAcceleration{s2pm: Complex64::from(y)}),
-				   Acceleration{mps2: Complex64::from(x)/Complex64::from(y)}
+		assert_approx_equal(
+			MagneticFlux::from_Wb(1000000000.0_f64).to_Wb(),
+			MagneticFlux::from_GWb(1.0_f64).to_Wb(), 9
 		);
-		assert_eq!(div_check(
-			&Complex32::from(x as f32), &InverseAngularAcceleration{s2prad: Complex32::from(y as f32)}),
-				   AngularAcceleration{radps2: Complex32::from(x as f32)/Complex32::from(y as f32)}
+		assert_approx_equal(
+			MagneticFlux::from_Wb(1.0_f64).to_Wb() * 1e-09,
+			MagneticFlux::from_Wb(1.0_f64).to_GWb(), 9
 		);
-		assert_eq!(div_check(
-			&Complex64::from(x), &InverseAngularAcceleration{s2prad: Complex64::from(y)}),
-				   AngularAcceleration{radps2: Complex64::from(x)/Complex64::from(y)}
+	}
+
+	#[test]
+	fn magnetic_flux_density_units() {
+		assert_approx_equal(
+			MagneticFluxDensity::from_T(0.001_f64).to_T(),
+			MagneticFluxDensity::from_mT(1.0_f64).to_T(), 9
 		);
-		assert_eq!(div_check(
-			&Complex32::from(x as f32), &InverseAngularMomentum{s_per_kgm2rad: Complex32::from(y as f32)}),
-				   AngularMomentum{kgm2radps: Complex32::from(x as f32)/Complex32::from(y as f32)}
+		assert_approx_equal(
+			MagneticFluxDensity::from_T(1.0_f64).to_T() * 1000.0,
+			MagneticFluxDensity::from_T(1.0_f64).to_mT(), 9
 		);
-		assert_eq!(div_check(
-			&Complex64::from(x), &InverseAngularMomentum{s_per_kgm2rad: Complex64::from(y)}),
-				   AngularMomentum{kgm2radps: Complex64::from(x)/Complex64::from(y)}
+		assert_approx_equal(
+			MagneticFluxDensity::from_T(1e-06_f64).to_T(),
+			MagneticFluxDensity::from_uT(1.0_f64).to_T(), 9
 		);
-		assert_eq!(div_check(
-			&Complex32::from(x as f32), &InverseAngularVelocity{s_per_rad: Complex32::from(y as f32)}),
-				   AngularVelocity{radps: Complex32::from(x as f32)/Complex32::from(y as f32)}
+		assert_approx_equal(
+			MagneticFluxDensity::from_T(1.0_f64).to_T() * 1000000.0,
+			MagneticFluxDensity::from_T(1.0_f64).to_uT(), 9
 		);
-		assert_eq!(div_check(
-			&Complex64::from(x), &InverseAngularVelocity{s_per_rad: Complex64::from(y)}),
-				   AngularVelocity{radps: Complex64::from(x)/Complex64::from(y)}
+		assert_approx_equal(
+			MagneticFluxDensity::from_T(1e-09_f64).to_T(),
+			MagneticFluxDensity::from_nT(1.0_f64).to_T(), 9
 		);
-		assert_eq!(div_check(
-			&Complex32::from(x as f32), &InverseEnergy{per_J: Complex32::from(y as f32)}),
-				   Energy{J: Complex32::from(x as f32)/Complex32::from(y as f32)}
+		assert_approx_equal(
+			MagneticFluxDensity::from_T(1.0_f64).to_T() * 1000000000.0,
+			MagneticFluxDensity::from_T(1.0_f64).to_nT(), 9
 		);
-		assert_eq!(div_check(
-			&Complex64::from(x), &InverseEnergy{per_J: Complex64::from(y)}),
-				   Energy{J: Complex64::from(x)/Complex64::from(y)}
+		assert_approx_equal(
+			MagneticFluxDensity::from_T(1000.0_f64).to_T(),
+			MagneticFluxDensity::from_kT(1.0_f64).to_T(), 9
 		);
-		assert_eq!(div_check(
-			&Complex32::from(x as f32), &InverseForce{per_N: Complex32::from(y as f32)}),
-				   Force{N: Complex32::from(x as f32)/Complex32::from(y as f32)}
+		assert_approx_equal(
+			MagneticFluxDensity::from_T(1.0_f64).to_T() * 0.001,
+			MagneticFluxDensity::from_T(1.0_f64).to_kT(), 9
 		);
-		assert_eq!(div_check(
-			&Complex64::from(x), &InverseForce{per_N: Complex64::from(y)}),
-				   Force{N: Complex64::from(x)/Complex64::from(y)}
+		assert_approx_equal(
+			MagneticFluxDensity::from_T(1000000.0_f64).to_T(),
+			MagneticFluxDensity::from_MT(1.0_f64).to_T(), 9
 		);
-		assert_eq!(div_check(
-			&Complex32::from(x as f32), &InverseMomentum{s_per_kgm: Complex32::from(y as f32)}),
-				   Momentum{kgmps: Complex32::from(x as f32)/Complex32::from(y as f32)}
+		assert_approx_equal(
+			MagneticFluxDensity::from_T(1.0_f64).to_T() * 1e-06,
+			MagneticFluxDensity::from_T(1.0_f64).to_MT(), 9
+		);
+		assert_approx_equal(
+			MagneticFluxDensity::from_T(1000000000.0_f64).to_T(),
+			MagneticFluxDensity::from_GT(1.0_f64).to_T(), 9
 		);
-		assert_eq!(div_check(
-			&Complex64::from(x), &InverseMomentum{s_per_kgm: Complex64::from(y)}),
-				   Momentum{kgmps: Complex64::from(x)/Complex64::from(y)}
+		assert_approx_equal(
+			MagneticFluxDensity::from_T(1.0_f64).to_T() * 1e-09,
+			MagneticFluxDensity::from_T(1.0_f64).to_GT(), 9
 		);
-		assert_eq!(div_check(
-			&Complex32::from(x as f32), &InversePower{per_W: Complex32::from(y as f32)}),
-				   Power{W: Complex32::from(x as f32)/Complex32::from(y as f32)}
+	}
+
+	#[test]
+	fn resistance_units() {
+		assert_approx_equal(
+			Resistance::from_Ohm(0.001_f64).to_Ohm(),
+			Resistance::from_mOhm(1.0_f64).to_Ohm(), 9
 		);
-		assert_eq!(div_check(
-			&Complex64::from(x), &InversePower{per_W: Complex64::from(y)}),
-				   Power{W: Complex64::from(x)/Complex64::from(y)}
+		assert_approx_equal(
+			Resistance::from_Ohm(1.0_f64).to_Ohm() * 1000.0,
+			Resistance::from_Ohm(1.0_f64).to_mOhm(), 9
 		);
-		assert_eq!(div_check(
-			&Complex32::from(x as f32), &InversePressure{per_Pa: Complex32::from(y as f32)}),
-				   Pressure{Pa: Complex32::from(x as f32)/Complex32::from(y as f32)}
+		assert_approx_equal(
+			Resistance::from_Ohm(1e-06_f64).to_Ohm(),
+			Resistance::from_uOhm(1.0_f64).to_Ohm(), 9
 		);
-		assert_eq!(div_check(
-			&Complex64::from(x), &InversePressure{per_Pa: Complex64::from(y)}),
-				   Pressure{Pa: Complex64::from(x)/Complex64::from(y)}
+		assert_approx_equal(
+			Resistance::from_Ohm(1.0_f64).to_Ohm() * 1000000.0,
+			Resistance::from_Ohm(1.0_f64).to_uOhm(), 9
 		);
-		assert_eq!(div_check(
-			&Complex32::from(x as f32), &InverseTorque{per_Nm: Complex32::from(y as f32)}),
-				   Energy{J: Complex32::from(x as f32)/Complex32::from(y as f32)}
+		assert_approx_equal(
+			Resistance::from_Ohm(1e-09_f64).to_Ohm(),
+			Resistance::from_nOhm(1.0_f64).to_Ohm(), 9
 		);
-		assert_eq!(div_check(
-			&Complex64::from(x), &InverseTorque{per_Nm: Complex64::from(y)}),
-				   Energy{J: Complex64::from(x)/Complex64::from(y)}
+		assert_approx_equal(
+			Resistance::from_Ohm(1.0_f64).to_Ohm() * 1000000000.0,
+			Resistance::from_Ohm(1.0_f64).to_nOhm(), 9
 		);
-		assert_eq!(div_check(
-			&Complex32::from(x as f32), &Momentum{kgmps: Complex32::from(y as f32)}),
-				   InverseMomentum{s_per_kgm: Complex32::from(x as f32)/Complex32::from(y as f32)}
+		assert_approx_equal(
+			Resistance::from_Ohm(1000.0_f64).to_Ohm(),
+			Resistance::from_kOhm(1.0_f64).to_Ohm(), 9
 		);
-		assert_eq!(div_check(
-			&Complex64::from(x), &Momentum{kgmps: Complex64::from(y)}),
-				   InverseMomentum{s_per_kgm: Complex64::from(x)/Complex64::from(y)}
+		assert_approx_equal(
+			Resistance::from_Ohm(1.0_f64).to_Ohm() * 0.001,
+			Resistance::from_Ohm(1.0_f64).to_kOhm(), 9
 		);
-		assert_eq!(div_check(
-			&Complex32::from(x as f32), &Power{W: Complex32::from(y as f32)}),
-				   InversePower{per_W: Complex32::from(x as f32)/Complex32::from(y as f32)}
+		assert_approx_equal(
+			Resistance::from_Ohm(1000000.0_f64).to_Ohm(),
+			Resistance::from_MOhm(1.0_f64).to_Ohm(), 9
 		);
-		assert_eq!(div_check(
-			&Complex64::from(x), &Power{W: Complex64::from(y)}),
-				   InversePower{per_W: Complex64::from(x)/Complex64::from(y)}
+		assert_approx_equal(
+			Resistance::from_Ohm(1.0_f64).to_Ohm() * 1e-06,
+			Resistance::from_Ohm(1.0_f64).to_MOhm(), 9
 		);
-		assert_eq!(div_check(
-			&Complex32::from(x as f32), &Pressure{Pa: Complex32::from(y as f32)}),
-				   InversePressure{per_Pa: Complex32::from(x as f32)/Complex32::from(y as f32)}
+		assert_approx_equal(
+			Resistance::from_Ohm(1000000000.0_f64).to_Ohm(),
+			Resistance::from_GOhm(1.0_f64).to_Ohm(), 9
 		);
-		assert_eq!(div_check(
-			&Complex64::from(x), &Pressure{Pa: Complex64::from(y)}),
-				   InversePressure{per_Pa: Complex64::from(x)/Complex64::from(y)}
+		assert_approx_equal(
+			Resistance::from_Ohm(1.0_f64).to_Ohm() * 1e-09,
+			Resistance::from_Ohm(1.0_f64).to_GOhm(), 9
 		);
-		assert_eq!(div_check(
-			&Complex32::from(x as f32), &TimePerDistance{spm: Complex32::from(y as f32)}),
-				   Velocity{mps: Complex32::from(x as f32)/Complex32::from(y as f32)}
+	}
+
+	#[test]
+	fn voltage_units() {
+		assert_approx_equal(
+			Voltage::from_V(0.001_f64).to_V(),
+			Voltage::from_mV(1.0_f64).to_V(), 9
 		);
-		assert_eq!(div_check(
-			&Complex64::from(x), &TimePerDistance{spm: Complex64::from(y)}),
-				   Velocity{mps: Complex64::from(x)/Complex64::from(y)}
+		assert_approx_equal(
+			Voltage::from_V(1.0_f64).to_V() * 1000.0,
+			Voltage::from_V(1.0_f64).to_mV(), 9
 		);
-		assert_eq!(div_check(
-			&Complex32::from(x as f32), &Torque{Nm: Complex32::from(y as f32)}),
-				   InverseEnergy{per_J: Complex32::from(x as f32)/Complex32::from(y as f32)}
+		assert_approx_equal(
+			Voltage::from_V(1e-06_f64).to_V(),
+			Voltage::from_uV(1.0_f64).to_V(), 9
 		);
-		assert_eq!(div_check(
-			&Complex64::from(x), &Torque{Nm: Complex64::from(y)}),
-				   InverseEnergy{per_J: Complex64::from(x)/Complex64::from(y)}
+		assert_approx_equal(
+			Voltage::from_V(1.0_f64).to_V() * 1000000.0,
+			Voltage::from_V(1.0_f64).to_uV(), 9
 		);
-		assert_eq!(div_check(
-			&Complex32::from(x as f32), &Velocity{mps: Complex32::from(y as f32)}),
-				   TimePerDistance{spm: Complex32::from(x as f32)/Complex32::from(y as f32)}
+		assert_approx_equal(
+			Voltage::from_V(1e-09_f64).to_V(),
+			Voltage::from_nV(1.0_f64).to_V(), 9
 		);
-		assert_eq!(div_check(
-			&Complex64::from(x), &Velocity{mps: Complex64::from(y)}),
-				   TimePerDistance{spm: Complex64::from(x)/Complex64::from(y)}
+		assert_approx_equal(
+			Voltage::from_V(1.0_f64).to_V() * 1000000000.0,
+			Voltage::from_V(1.0_f64).to_nV(), 9
 		);
-		assert_eq!(div_check(
-			&Complex32::from(x as f32), &VolumePerMass{m3_per_kg: Complex32::from(y as f32)}),
-				   Density{kgpm3: Complex32::from(x as f32)/Complex32::from(y as f32)}
+		assert_approx_equal(
+			Voltage::from_V(1000.0_f64).to_V(),
+			Voltage::from_kV(1.0_f64).to_V(), 9
 		);
-		assert_eq!(div_check(
-			&Complex64::from(x), &VolumePerMass{m3_per_kg: Complex64::from(y)}),
-				   Density{kgpm3: Complex64::from(x)/Complex64::from(y)}
+		assert_approx_equal(
+			Voltage::from_V(1.0_f64).to_V() * 0.001,
+			Voltage::from_V(1.0_f64).to_kV(), 9
 		);
-		assert_eq!(div_check(
-			&Complex32::from(x as f32), &Acceleration{mps2: Complex32::from(y as f32)}),
-				   InverseAcceleration{s2pm: Complex32::from(x as f32)/Complex32::from(y as f32)}
+		assert_approx_equal(
+			Voltage::from_V(1000000.0_f64).to_V(),
+			Voltage::from_MV(1.0_f64).to_V(), 9
 		);
-		assert_eq!(div_check(
-			&Complex64::from(x), &Acceleration{mps2: Complex64::from(y)}),
-				   InverseAcceleration{s2pm: Complex64::from(x)/Complex64::from(y)}
+		assert_approx_equal(
+			Voltage::from_V(1.0_f64).to_V() * 1e-06,
+			Voltage::from_V(1.0_f64).to_MV(), 9
 		);
-		assert_eq!(div_check(
-			&Complex32::from(x as f32), &AngularAcceleration{radps2: Complex32::from(y as f32)}),
-				   InverseAngularAcceleration{s2prad: Complex32::from(x as f32)/Complex32::from(y as f32)}
+		assert_approx_equal(
+			Voltage::from_V(1000000000.0_f64).to_V(),
+			Voltage::from_GV(1.0_f64).to_V(), 9
 		);
-		assert_eq!(div_check(
-			&Complex64::from(x), &AngularAcceleration{radps2: Complex64::from(y)}),
-				   InverseAngularAcceleration{s2prad: Complex64::from(x)/Complex64::from(y)}
+		assert_approx_equal(
+			Voltage::from_V(1.0_f64).to_V() * 1e-09,
+			Voltage::from_V(1.0_f64).to_GV(), 9
 		);
-		assert_eq!(div_check(
-			&Complex32::from(x as f32), &AngularMomentum{kgm2radps: Complex32::from(y as f32)}),
-				   InverseAngularMomentum{s_per_kgm2rad: Complex32::from(x as f32)/Complex32::from(y as f32)}
+	}
+
+	#[test]
+	fn angle_units() {
+		assert_approx_equal(
+			Angle::from_rad(0.017453292519943295_f64).to_rad(),
+			Angle::from_degrees(1.0_f64).to_rad(), 9
 		);
-		assert_eq!(div_check(
-			&Complex64::from(x), &AngularMomentum{kgm2radps: Complex64::from(y)}),
-				   InverseAngularMomentum{s_per_kgm2rad: Complex64::from(x)/Complex64::from(y)}
+		assert_approx_equal(
+			Angle::from_rad(1.0_f64).to_rad() * 57.29577951308232,
+			Angle::from_rad(1.0_f64).to_degrees(), 9
 		);
-		assert_eq!(div_check(
-			&Complex32::from(x as f32), &AngularVelocity{radps: Complex32::from(y as f32)}),
-				   InverseAngularVelocity{s_per_rad: Complex32::from(x as f32)/Complex32::from(y as f32)}
+		assert_approx_equal(
+			Angle::from_rad(0.017453292519943295_f64).to_rad(),
+			Angle::from_deg(1.0_f64).to_rad(), 9
 		);
-		assert_eq!(div_check(
-			&Complex64::from(x), &AngularVelocity{radps: Complex64::from(y)}),
-				   InverseAngularVelocity{s_per_rad: Complex64::from(x)/Complex64::from(y)}
+		assert_approx_equal(
+			Angle::from_rad(1.0_f64).to_rad() * 57.29577951308232,
+			Angle::from_rad(1.0_f64).to_deg(), 9
 		);
-		assert_eq!(div_check(
-			&Complex32::from(x as f32), &AreaDensity{kgpm2: Complex32::from(y as f32)}),
-				   AreaPerMass{m2_per_kg: Complex32::from(x as f32)/Complex32::from(y as f32)}
+		assert_approx_equal(
+			Angle::from_rad(0.0002908882086657216_f64).to_rad(),
+			Angle::from_arcminutes(1.0_f64).to_rad(), 9
 		);
-		assert_eq!(div_check(
-			&Complex64::from(x), &AreaDensity{kgpm2: Complex64::from(y)}),
-				   AreaPerMass{m2_per_kg: Complex64::from(x)/Complex64::from(y)}
+		assert_approx_equal(
+			Angle::from_rad(1.0_f64).to_rad() * 3437.7467707849396,
+			Angle::from_rad(1.0_f64).to_arcminutes(), 9
 		);
-		assert_eq!(div_check(
-			&Complex32::from(x as f32), &AreaPerMass{m2_per_kg: Complex32::from(y as f32)}),
-				   AreaDensity{kgpm2: Complex32::from(x as f32)/Complex32::from(y as f32)}
+		assert_approx_equal(
+			Angle::from_rad(0.0002908882086657216_f64).to_rad(),
+			Angle::from_arcmin(1.0_f64).to_rad(), 9
 		);
-		assert_eq!(div_check(
-			&Complex64::from(x), &AreaPerMass{m2_per_kg: Complex64::from(y)}),
-				   AreaDensity{kgpm2: Complex64::from(x)/Complex64::from(y)}
+		assert_approx_equal(
+			Angle::from_rad(1.0_f64).to_rad() * 3437.7467707849396,
+			Angle::from_rad(1.0_f64).to_arcmin(), 9
 		);
-		assert_eq!(div_check(
-			&Complex32::from(x as f32), &Density{kgpm3: Complex32::from(y as f32)}),
-				   VolumePerMass{m3_per_kg: Complex32::from(x as f32)/Complex32::from(y as f32)}
+		assert_approx_equal(
+			Angle::from_rad(4.84813681109536e-06_f64).to_rad(),
+			Angle::from_arcseconds(1.0_f64).to_rad(), 9
 		);
-		assert_eq!(div_check(
-			&Complex64::from(x), &Density{kgpm3: Complex64::from(y)}),
-				   VolumePerMass{m3_per_kg: Complex64::from(x)/Complex64::from(y)}
+		assert_approx_equal(
+			Angle::from_rad(1.0_f64).to_rad() * 206264.80624709636,
+			Angle::from_rad(1.0_f64).to_arcseconds(), 9
 		);
-		assert_eq!(div_check(
-			&Complex32::from(x as f32), &Energy{J: Complex32::from(y as f32)}),
-				   InverseEnergy{per_J: Complex32::from(x as f32)/Complex32::from(y as f32)}
+		assert_approx_equal(
+			Angle::from_rad(4.84813681109536e-06_f64).to_rad(),
+			Angle::from_arcsec(1.0_f64).to_rad(), 9
 		);
-		assert_eq!(div_check(
-			&Complex64::from(x), &Energy{J: Complex64::from(y)}),
-				   InverseEnergy{per_J: Complex64::from(x)/Complex64::from(y)}
+		assert_approx_equal(
+			Angle::from_rad(1.0_f64).to_rad() * 206264.80624709636,
+			Angle::from_rad(1.0_f64).to_arcsec(), 9
 		);
-		assert_eq!(div_check(
-			&Complex32::from(x as f32), &Force{N: Complex32::from(y as f32)}),
-				   InverseForce{per_N: Complex32::from(x as f32)/Complex32::from(y as f32)}
+		assert_approx_equal(
+			Angle::from_rad(0.015707963267948967_f64).to_rad(),
+			Angle::from_gradians(1.0_f64).to_rad(), 9
 		);
-		assert_eq!(div_check(
-			&Complex64::from(x), &Force{N: Complex64::from(y)}),
-				   InverseForce{per_N: Complex64::from(x)/Complex64::from(y)}
+		assert_approx_equal(
+			Angle::from_rad(1.0_f64).to_rad() * 63.66197723675813,
+			Angle::from_rad(1.0_f64).to_gradians(), 9
 		);
-		assert_eq!(div_check(
-			&Complex32::from(x as f32), &InverseAcceleration{s2pm: Complex32::from(y as f32)}),
-				   Acceleration{mps2: Complex32::from(x as f32)/Complex32::from(y as f32)}
+		assert_approx_equal(
+			Angle::from_rad(0.015707963267948967_f64).to_rad(),
+			Angle::from_grad(1.0_f64).to_rad(), 9
 		);
-		assert_eq!(div_check(
-			&Complex64::from(x), &InverseAcceleration{s2pm: Complex64::from(y)}),
-				   Acceleration{mps2: Complex64::from(x)/Complex64::from(y)}
+		assert_approx_equal(
+			Angle::from_rad(1.0_f64).to_rad() * 63.66197723675813,
+			Angle::from_rad(1.0_f64).to_grad(), 9
 		);
-		assert_eq!(div_check(
-			&Complex32::from(x as f32), &InverseAngularAcceleration{s2prad: Complex32::from(y as f32)}),
-				   AngularAcceleration{radps2: Complex32::from(x as f32)/Complex32::from(y as f32)}
+	}
+
+	#[test]
+	fn area_units() {
+		assert_approx_equal(
+			Area::from_m2(0.0001_f64).to_m2(),
+			Area::from_cm2(1.0_f64).to_m2(), 9
 		);
-		assert_eq!(div_check(
-			&Complex64::from(x), &InverseAngularAcceleration{s2prad: Complex64::from(y)}),
-				   AngularAcceleration{radps2: Complex64::from(x)/Complex64::from(y)}
+		assert_approx_equal(
+			Area::from_m2(1.0_f64).to_m2() * 10000.0,
+			Area::from_m2(1.0_f64).to_cm2(), 9
 		);
-		assert_eq!(div_check(
-			&Complex32::from(x as f32), &InverseAngularMomentum{s_per_kgm2rad: Complex32::from(y as f32)}),
-				   AngularMomentum{kgm2radps: Complex32::from(x as f32)/Complex32::from(y as f32)}
+		assert_approx_equal(
+			Area::from_m2(0.0001_f64).to_m2(),
+			Area::from_square_cm(1.0_f64).to_m2(), 9
 		);
-		assert_eq!(div_check(
-			&Complex64::from(x), &InverseAngularMomentum{s_per_kgm2rad: Complex64::from(y)}),
-				   AngularMomentum{kgm2radps: Complex64::from(x)/Complex64::from(y)}
+		assert_approx_equal(
+			Area::from_m2(1.0_f64).to_m2() * 10000.0,
+			Area::from_m2(1.0_f64).to_square_cm(), 9
 		);
-		assert_eq!(div_check(
-			&Complex32::from(x as f32), &InverseAngularVelocity{s_per_rad: Complex32::from(y as f32)}),
-				   AngularVelocity{radps: Complex32::from(x as f32)/Complex32::from(y as f32)}
+		assert_approx_equal(
+			Area::from_m2(1e-06_f64).to_m2(),
+			Area::from_mm2(1.0_f64).to_m2(), 9
 		);
-		assert_eq!(div_check(
-			&Complex64::from(x), &InverseAngularVelocity{s_per_rad: Complex64::from(y)}),
-				   AngularVelocity{radps: Complex64::from(x)/Complex64::from(y)}
+		assert_approx_equal(
+			Area::from_m2(1.0_f64).to_m2() * 1000000.0,
+			Area::from_m2(1.0_f64).to_mm2(), 9
 		);
-		assert_eq!(div_check(
-			&Complex32::from(x as f32), &InverseEnergy{per_J: Complex32::from(y as f32)}),
-				   Energy{J: Complex32::from(x as f32)/Complex32::from(y as f32)}
+		assert_approx_equal(
+			Area::from_m2(1e-12_f64).to_m2(),
+			Area::from_um2(1.0_f64).to_m2(), 9
 		);
-		assert_eq!(div_check(
-			&Complex64::from(x), &InverseEnergy{per_J: Complex64::from(y)}),
-				   Energy{J: Complex64::from(x)/Complex64::from(y)}
+		assert_approx_equal(
+			Area::from_m2(1.0_f64).to_m2() * 1000000000000.0,
+			Area::from_m2(1.0_f64).to_um2(), 9
 		);
-		assert_eq!(div_check(
-			&Complex32::from(x as f32), &InverseForce{per_N: Complex32::from(y as f32)}),
-				   Force{N: Complex32::from(x as f32)/Complex32::from(y as f32)}
+		assert_approx_equal(
+			Area::from_m2(1e-18_f64).to_m2(),
+			Area::from_nm2(1.0_f64).to_m2(), 9
 		);
-		assert_eq!(div_check(
-			&Complex64::from(x), &InverseForce{per_N: Complex64::from(y)}),
-				   Force{N: Complex64::from(x)/Complex64::from(y)}
+		assert_approx_equal(
+			Area::from_m2(1.0_f64).to_m2() * 1e+18,
+			Area::from_m2(1.0_f64).to_nm2(), 9
 		);
-		assert_eq!(div_check(
-			&Complex32::from(x as f32), &InverseMomentum{s_per_kgm: Complex32::from(y as f32)}),
-				   Momentum{kgmps: Complex32::from(x as f32)/Complex32::from(y as f32)}
+		assert_approx_equal(
+			Area::from_m2(1000000.0_f64).to_m2(),
+			Area::from_km2(1.0_f64).to_m2(), 9
 		);
-		assert_eq!(div_check(
-			&Complex64::from(x), &InverseMomentum{s_per_kgm: Complex64::from(y)}),
-				   Momentum{kgmps: Complex64::from(x)/Complex64::from(y)}
+		assert_approx_equal(
+			Area::from_m2(1.0_f64).to_m2() * 1e-06,
+			Area::from_m2(1.0_f64).to_km2(), 9
 		);
-		assert_eq!(div_check(
-			&Complex32::from(x as f32), &InversePower{per_W: Complex32::from(y as f32)}),
-				   Power{W: Complex32::from(x as f32)/Complex32::from(y as f32)}
+	}
+
+	#[test]
+	fn volume_units() {
+		assert_approx_equal(
+			Volume::from_m3(1e-06_f64).to_m3(),
+			Volume::from_cc(1.0_f64).to_m3(), 9
 		);
-		assert_eq!(div_check(
-			&Complex64::from(x), &InversePower{per_W: Complex64::from(y)}),
-				   Power{W: Complex64::from(x)/Complex64::from(y)}
+		assert_approx_equal(
+			Volume::from_m3(1.0_f64).to_m3() * 1000000.0,
+			Volume::from_m3(1.0_f64).to_cc(), 9
 		);
-		assert_eq!(div_check(
-			&Complex32::from(x as f32), &InversePressure{per_Pa: Complex32::from(y as f32)}),
-				   Pressure{Pa: Complex32::from(x as f32)/Complex32::from(y as f32)}
+		assert_approx_equal(
+			Volume::from_m3(0.001_f64).to_m3(),
+			Volume::from_L(1.0_f64).to_m3(), 9
 		);
-		assert_eq!(div_check(
-			&Complex64::from(x), &InversePressure{per_Pa: Complex64::from(y)}),
-				   Pressure{Pa: Complex64::from(x)/Complex64::from(y)}
+		assert_approx_equal(
+			Volume::from_m3(1.0_f64).to_m3() * 1000.0,
+			Volume::from_m3(1.0_f64).to_L(), 9
 		);
-		assert_eq!(div_check(
-			&Complex32::from(x as f32), &InverseTorque{per_Nm: Complex32::from(y as f32)}),
-				   Energy{J: Complex32::from(x as f32)/Complex32::from(y as f32)}
+		assert_approx_equal(
+			Volume::from_m3(0.001_f64).to_m3(),
+			Volume::from_liters(1.0_f64).to_m3(), 9
 		);
-		assert_eq!(div_check(
-			&Complex64::from(x), &InverseTorque{per_Nm: Complex64::from(y)}),
-				   Energy{J: Complex64::from(x)/Complex64::from(y)}
+		assert_approx_equal(
+			Volume::from_m3(1.0_f64).to_m3() * 1000.0,
+			Volume::from_m3(1.0_f64).to_liters(), 9
 		);
-		assert_eq!(div_check(
-			&Complex32::from(x as f32), &Momentum{kgmps: Complex32::from(y as f32)}),
-				   InverseMomentum{s_per_kgm: Complex32::from(x as f32)/Complex32::from(y as f32)}
+		assert_approx_equal(
+			Volume::from_m3(1e-06_f64).to_m3(),
+			Volume::from_mL(1.0_f64).to_m3(), 9
 		);
-		assert_eq!(div_check(
-			&Complex64::from(x), &Momentum{kgmps: Complex64::from(y)}),
-				   InverseMomentum{s_per_kgm: Complex64::from(x)/Complex64::from(y)}
+		assert_approx_equal(
+			Volume::from_m3(1.0_f64).to_m3() * 1000000.0,
+			Volume::from_m3(1.0_f64).to_mL(), 9
 		);
-		assert_eq!(div_check(
-			&Complex32::from(x as f32), &Power{W: Complex32::from(y as f32)}),
-				   InversePower{per_W: Complex32::from(x as f32)/Complex32::from(y as f32)}
+		assert_approx_equal(
+			Volume::from_m3(1e-09_f64).to_m3(),
+			Volume::from_uL(1.0_f64).to_m3(), 9
 		);
-		assert_eq!(div_check(
-			&Complex64::from(x), &Power{W: Complex64::from(y)}),
-				   InversePower{per_W: Complex64::from(x)/Complex64::from(y)}
+		assert_approx_equal(
+			Volume::from_m3(1.0_f64).to_m3() * 1000000000.0,
+			Volume::from_m3(1.0_f64).to_uL(), 9
 		);
-		assert_eq!(div_check(
-			&Complex32::from(x as f32), &Pressure{Pa: Complex32::from(y as f32)}),
-				   InversePressure{per_Pa: Complex32::from(x as f32)/Complex32::from(y as f32)}
+		assert_approx_equal(
+			Volume::from_m3(1e-12_f64).to_m3(),
+			Volume::from_nL(1.0_f64).to_m3(), 9
 		);
-		assert_eq!(div_check(
-			&Complex64::from(x), &Pressure{Pa: Complex64::from(y)}),
-				   InversePressure{per_Pa: Complex64::from(x)/Complex64::from(y)}
+		assert_approx_equal(
+			Volume::from_m3(1.0_f64).to_m3() * 1000000000000.0,
+			Volume::from_m3(1.0_f64).to_nL(), 9
 		);
-		assert_eq!(div_check(
-			&Complex32::from(x as f32), &TimePerDistance{spm: Complex32::from(y as f32)}),
-				   Velocity{mps: Complex32::from(x as f32)/Complex32::from(y as f32)}
+		assert_approx_equal(
+			Volume::from_m3(1e-15_f64).to_m3(),
+			Volume::from_pL(1.0_f64).to_m3(), 9
 		);
-		assert_eq!(div_check(
-			&Complex64::from(x), &TimePerDistance{spm: Complex64::from(y)}),
-				   Velocity{mps: Complex64::from(x)/Complex64::from(y)}
+		assert_approx_equal(
+			Volume::from_m3(1.0_f64).to_m3() * 1000000000000000.0,
+			Volume::from_m3(1.0_f64).to_pL(), 9
 		);
-		assert_eq!(div_check(
-			&Complex32::from(x as f32), &Torque{Nm: Complex32::from(y as f32)}),
-				   InverseEnergy{per_J: Complex32::from(x as f32)/Complex32::from(y as f32)}
+		assert_approx_equal(
+			Volume::from_m3(1000.0_f64).to_m3(),
+			Volume::from_ML(1.0_f64).to_m3(), 9
 		);
-		assert_eq!(div_check(
-			&Complex64::from(x), &Torque{Nm: Complex64::from(y)}),
-				   InverseEnergy{per_J: Complex64::from(x)/Complex64::from(y)}
+		assert_approx_equal(
+			Volume::from_m3(1.0_f64).to_m3() * 0.001,
+			Volume::from_m3(1.0_f64).to_ML(), 9
 		);
-		assert_eq!(div_check(
-			&Complex32::from(x as f32), &Velocity{mps: Complex32::from(y as f32)}),
-				   TimePerDistance{spm: Complex32::from(x as f32)/Complex32::from(y as f32)}
+		assert_approx_equal(
+			Volume::from_m3(1000000.0_f64).to_m3(),
+			Volume::from_GL(1.0_f64).to_m3(), 9
 		);
-		assert_eq!(div_check(
-			&Complex64::from(x), &Velocity{mps: Complex64::from(y)}),
-				   TimePerDistance{spm: Complex64::from(x)/Complex64::from(y)}
+		assert_approx_equal(
+			Volume::from_m3(1.0_f64).to_m3() * 1e-06,
+			Volume::from_m3(1.0_f64).to_GL(), 9
 		);
-		assert_eq!(div_check(
-			&Complex32::from(x as f32), &VolumePerMass{m3_per_kg: Complex32::from(y as f32)}),
-				   Density{kgpm3: Complex32::from(x as f32)/Complex32::from(y as f32)}
+		assert_approx_equal(
+			Volume::from_m3(1e-06_f64).to_m3(),
+			Volume::from_milliliters(1.0_f64).to_m3(), 9
 		);
-		assert_eq!(div_check(
-			&Complex64::from(x), &VolumePerMass{m3_per_kg: Complex64::from(y)}),
-				   Density{kgpm3: Complex64::from(x)/Complex64::from(y)}
+		assert_approx_equal(
+			Volume::from_m3(1.0_f64).to_m3() * 1000000.0,
+			Volume::from_m3(1.0_f64).to_milliliters(), 9
 		);
-	}
-
-	#[test]
-	fn amount_units() {
 		assert_approx_equal(
-			Amount::from_mol(1.66053906717385e-24_f64).to_mol(),
-			Amount::from_count(1.0_f64).to_mol(), 9
+			Volume::from_m3(0.003785411784_f64).to_m3(),
+			Volume::from_us_gallons(1.0_f64).to_m3(), 9
 		);
 		assert_approx_equal(
-			Amount::from_mol(1.0_f64).to_mol() * 6.02214076e+23,
-			Amount::from_mol(1.0_f64).to_count(), 9
+			Volume::from_m3(1.0_f64).to_m3() * 264.1720523581484,
+			Volume::from_m3(1.0_f64).to_us_gallons(), 9
 		);
 		assert_approx_equal(
-			Amount::from_mol(0.001_f64).to_mol(),
-			Amount::from_mmol(1.0_f64).to_mol(), 9
+			Volume::from_m3(0.00454609_f64).to_m3(),
+			Volume::from_imperial_gallons(1.0_f64).to_m3(), 9
 		);
 		assert_approx_equal(
-			Amount::from_mol(1.0_f64).to_mol() * 1000.0,
-			Amount::from_mol(1.0_f64).to_mmol(), 9
+			Volume::from_m3(1.0_f64).to_m3() * 219.96924829908778,
+			Volume::from_m3(1.0_f64).to_imperial_gallons(), 9
 		);
+	}
+
+	#[test]
+	fn acceleration_units() {
 		assert_approx_equal(
-			Amount::from_mol(1e-06_f64).to_mol(),
-			Amount::from_umol(1.0_f64).to_mol(), 9
+			Acceleration::from_mps2(0.001_f64).to_mps2(),
+			Acceleration::from_mmps2(1.0_f64).to_mps2(), 9
 		);
 		assert_approx_equal(
-			Amount::from_mol(1.0_f64).to_mol() * 1000000.0,
-			Amount::from_mol(1.0_f64).to_umol(), 9
+			Acceleration::from_mps2(1.0_f64).to_mps2() * 1000.0,
+			Acceleration::from_mps2(1.0_f64).to_mmps2(), 9
 		);
 		assert_approx_equal(
-			Amount::from_mol(1e-09_f64).to_mol(),
-			Amount::from_nmol(1.0_f64).to_mol(), 9
+			Acceleration::from_mps2(1e-06_f64).to_mps2(),
+			Acceleration::from_kilometers_per_hour_squared(1.0_f64).to_mps2(), 9
 		);
 		assert_approx_equal(
-			Amount::from_mol(1.0_f64).to_mol() * 1000000000.0,
-			Amount::from_mol(1.0_f64).to_nmol(), 9
+			Acceleration::from_mps2(1.0_f64).to_mps2() * 1000000.0,
+			Acceleration::from_mps2(1.0_f64).to_kilometers_per_hour_squared(), 9
 		);
 		assert_approx_equal(
-			Amount::from_mol(1e-12_f64).to_mol(),
-			Amount::from_pmol(1.0_f64).to_mol(), 9
+			Acceleration::from_mps2(7.71604938271605e-05_f64).to_mps2(),
+			Acceleration::from_kph2(1.0_f64).to_mps2(), 9
 		);
 		assert_approx_equal(
-			Amount::from_mol(1.0_f64).to_mol() * 1000000000000.0,
-			Amount::from_mol(1.0_f64).to_pmol(), 9
+			Acceleration::from_mps2(1.0_f64).to_mps2() * 12960.0,
+			Acceleration::from_mps2(1.0_f64).to_kph2(), 9
 		);
 	}
 
 	#[test]
-	fn current_units() {
+	fn angular_acceleration_units() {
 		assert_approx_equal(
-			Current::from_A(0.001_f64).to_A(),
-			Current::from_mA(1.0_f64).to_A(), 9
+			AngularAcceleration::from_radps2(0.0174532925199433_f64).to_radps2(),
+			AngularAcceleration::from_degrees_per_second_squared(1.0_f64).to_radps2(), 9
 		);
 		assert_approx_equal(
-			Current::from_A(1.0_f64).to_A() * 1000.0,
-			Current::from_A(1.0_f64).to_mA(), 9
+			AngularAcceleration::from_radps2(1.0_f64).to_radps2() * 57.2957795130823,
+			AngularAcceleration::from_radps2(1.0_f64).to_degrees_per_second_squared(), 9
 		);
 		assert_approx_equal(
-			Current::from_A(1e-06_f64).to_A(),
-			Current::from_uA(1.0_f64).to_A(), 9
+			AngularAcceleration::from_radps2(6.28318530717959_f64).to_radps2(),
+			AngularAcceleration::from_rps2(1.0_f64).to_radps2(), 9
 		);
 		assert_approx_equal(
-			Current::from_A(1.0_f64).to_A() * 1000000.0,
-			Current::from_A(1.0_f64).to_uA(), 9
+			AngularAcceleration::from_radps2(1.0_f64).to_radps2() * 0.159154943091895,
+			AngularAcceleration::from_radps2(1.0_f64).to_rps2(), 9
 		);
 		assert_approx_equal(
-			Current::from_A(1e-09_f64).to_A(),
-			Current::from_nA(1.0_f64).to_A(), 9
+			AngularAcceleration::from_radps2(0.0017453292519943_f64).to_radps2(),
+			AngularAcceleration::from_rpm2(1.0_f64).to_radps2(), 9
 		);
 		assert_approx_equal(
-			Current::from_A(1.0_f64).to_A() * 1000000000.0,
-			Current::from_A(1.0_f64).to_nA(), 9
+			AngularAcceleration::from_radps2(1.0_f64).to_radps2() * 572.957795130823,
+			AngularAcceleration::from_radps2(1.0_f64).to_rpm2(), 9
 		);
 		assert_approx_equal(
-			Current::from_A(1000.0_f64).to_A(),
-			Current::from_kA(1.0_f64).to_A(), 9
+			AngularAcceleration::from_radps2(0.0174532925199433_f64).to_radps2(),
+			AngularAcceleration::from_degps2(1.0_f64).to_radps2(), 9
 		);
 		assert_approx_equal(
-			Current::from_A(1.0_f64).to_A() * 0.001,
-			Current::from_A(1.0_f64).to_kA(), 9
+			AngularAcceleration::from_radps2(1.0_f64).to_radps2() * 57.2957795130823,
+			AngularAcceleration::from_radps2(1.0_f64).to_degps2(), 9
 		);
 		assert_approx_equal(
-			Current::from_A(1000000.0_f64).to_A(),
-			Current::from_MA(1.0_f64).to_A(), 9
+			AngularAcceleration::from_radps2(4.84813681109536e-07_f64).to_radps2(),
+			AngularAcceleration::from_rph2(1.0_f64).to_radps2(), 9
 		);
 		assert_approx_equal(
-			Current::from_A(1.0_f64).to_A() * 1e-06,
-			Current::from_A(1.0_f64).to_MA(), 9
+			AngularAcceleration::from_radps2(1.0_f64).to_radps2() * 2062648.06247096,
+			AngularAcceleration::from_radps2(1.0_f64).to_rph2(), 9
 		);
+	}
+
+	#[test]
+	fn angular_momentum_units() {
 		assert_approx_equal(
-			Current::from_A(1000000000.0_f64).to_A(),
-			Current::from_GA(1.0_f64).to_A(), 9
+			AngularMomentum::from_kgm2radps(1e-07_f64).to_kgm2radps(),
+			AngularMomentum::from_gcm2radps(1.0_f64).to_kgm2radps(), 9
 		);
 		assert_approx_equal(
-			Current::from_A(1.0_f64).to_A() * 1e-09,
-			Current::from_A(1.0_f64).to_GA(), 9
+			AngularMomentum::from_kgm2radps(1.0_f64).to_kgm2radps() * 10000000.0,
+			AngularMomentum::from_kgm2radps(1.0_f64).to_gcm2radps(), 9
 		);
 	}
 
 	#[test]
-	fn distance_units() {
+	fn angular_velocity_units() {
 		assert_approx_equal(
-			Distance::from_m(0.01_f64).to_m(),
-			Distance::from_cm(1.0_f64).to_m(), 9
+			AngularVelocity::from_radps(0.0174532925199433_f64).to_radps(),
+			AngularVelocity::from_degrees_per_second(1.0_f64).to_radps(), 9
 		);
 		assert_approx_equal(
-			Distance::from_m(1.0_f64).to_m() * 100.0,
-			Distance::from_m(1.0_f64).to_cm(), 9
+			AngularVelocity::from_radps(1.0_f64).to_radps() * 57.2957795130823,
+			AngularVelocity::from_radps(1.0_f64).to_degrees_per_second(), 9
 		);
 		assert_approx_equal(
-			Distance::from_m(0.001_f64).to_m(),
-			Distance::from_mm(1.0_f64).to_m(), 9
+			AngularVelocity::from_radps(0.0174532925199433_f64).to_radps(),
+			AngularVelocity::from_degps(1.0_f64).to_radps(), 9
 		);
 		assert_approx_equal(
-			Distance::from_m(1.0_f64).to_m() * 1000.0,
-			Distance::from_m(1.0_f64).to_mm(), 9
+			AngularVelocity::from_radps(1.0_f64).to_radps() * 57.2957795130823,
+			AngularVelocity::from_radps(1.0_f64).to_degps(), 9
 		);
 		assert_approx_equal(
-			Distance::from_m(1e-06_f64).to_m(),
-			Distance::from_um(1.0_f64).to_m(), 9
+			AngularVelocity::from_radps(6.28318530717959_f64).to_radps(),
+			AngularVelocity::from_rps(1.0_f64).to_radps(), 9
 		);
 		assert_approx_equal(
-			Distance::from_m(1.0_f64).to_m() * 1000000.0,
-			Distance::from_m(1.0_f64).to_um(), 9
+			AngularVelocity::from_radps(1.0_f64).to_radps() * 0.159154943091895,
+			AngularVelocity::from_radps(1.0_f64).to_rps(), 9
 		);
 		assert_approx_equal(
-			Distance::from_m(1e-09_f64).to_m(),
-			Distance::from_nm(1.0_f64).to_m(), 9
+			AngularVelocity::from_radps(0.10471975511966_f64).to_radps(),
+			AngularVelocity::from_rpm(1.0_f64).to_radps(), 9
 		);
 		assert_approx_equal(
-			Distance::from_m(1.0_f64).to_m() * 1000000000.0,
-			Distance::from_m(1.0_f64).to_nm(), 9
+			AngularVelocity::from_radps(1.0_f64).to_radps() * 9.54929658551372,
+			AngularVelocity::from_radps(1.0_f64).to_rpm(), 9
 		);
 		assert_approx_equal(
-			Distance::from_m(1e-12_f64).to_m(),
-			Distance::from_pm(1.0_f64).to_m(), 9
+			AngularVelocity::from_radps(0.0017453292519943_f64).to_radps(),
+			AngularVelocity::from_rph(1.0_f64).to_radps(), 9
 		);
 		assert_approx_equal(
-			Distance::from_m(1.0_f64).to_m() * 1000000000000.0,
-			Distance::from_m(1.0_f64).to_pm(), 9
+			AngularVelocity::from_radps(1.0_f64).to_radps() * 572.957795130823,
+			AngularVelocity::from_radps(1.0_f64).to_rph(), 9
 		);
+	}
+
+	#[test]
+	fn area_density_units() {
 		assert_approx_equal(
-			Distance::from_m(1000.0_f64).to_m(),
-			Distance::from_km(1.0_f64).to_m(), 9
+			AreaDensity::from_kgpm2(0.001_f64).to_kgpm2(),
+			AreaDensity::from_gpm2(1.0_f64).to_kgpm2(), 9
 		);
 		assert_approx_equal(
-			Distance::from_m(1.0_f64).to_m() * 0.001,
-			Distance::from_m(1.0_f64).to_km(), 9
+			AreaDensity::from_kgpm2(1.0_f64).to_kgpm2() * 1000.0,
+			AreaDensity::from_kgpm2(1.0_f64).to_gpm2(), 9
 		);
 		assert_approx_equal(
-			Distance::from_m(149597870700.0_f64).to_m(),
-			Distance::from_au(1.0_f64).to_m(), 9
+			AreaDensity::from_kgpm2(0.001_f64).to_kgpm2(),
+			AreaDensity::from_grams_per_square_meter(1.0_f64).to_kgpm2(), 9
 		);
 		assert_approx_equal(
-			Distance::from_m(1.0_f64).to_m() * 6.68458712226845e-12,
-			Distance::from_m(1.0_f64).to_au(), 9
+			AreaDensity::from_kgpm2(1.0_f64).to_kgpm2() * 1000.0,
+			AreaDensity::from_kgpm2(1.0_f64).to_grams_per_square_meter(), 9
 		);
 		assert_approx_equal(
-			Distance::from_m(3.08568047999355e+16_f64).to_m(),
-			Distance::from_parsec(1.0_f64).to_m(), 9
+			AreaDensity::from_kgpm2(10.0_f64).to_kgpm2(),
+			AreaDensity::from_gpcm2(1.0_f64).to_kgpm2(), 9
 		);
 		assert_approx_equal(
-			Distance::from_m(1.0_f64).to_m() * 3.24077624525171e-17,
-			Distance::from_m(1.0_f64).to_parsec(), 9
+			AreaDensity::from_kgpm2(1.0_f64).to_kgpm2() * 0.1,
+			AreaDensity::from_kgpm2(1.0_f64).to_gpcm2(), 9
 		);
 		assert_approx_equal(
-			Distance::from_m(9460528169656200.0_f64).to_m(),
-			Distance::from_lyr(1.0_f64).to_m(), 9
+			AreaDensity::from_kgpm2(10.0_f64).to_kgpm2(),
+			AreaDensity::from_grams_per_square_cm(1.0_f64).to_kgpm2(), 9
 		);
 		assert_approx_equal(
-			Distance::from_m(1.0_f64).to_m() * 1.05702343681763e-16,
-			Distance::from_m(1.0_f64).to_lyr(), 9
+			AreaDensity::from_kgpm2(1.0_f64).to_kgpm2() * 0.1,
+			AreaDensity::from_kgpm2(1.0_f64).to_grams_per_square_cm(), 9
 		);
 	}
 
 	#[test]
-	fn luminosity_units() {
-		assert_approx_equal(
-			Luminosity::from_cd(0.001_f64).to_cd(),
-			Luminosity::from_mcd(1.0_f64).to_cd(), 9
-		);
-		assert_approx_equal(
-			Luminosity::from_cd(1.0_f64).to_cd() * 1000.0,
-			Luminosity::from_cd(1.0_f64).to_mcd(), 9
-		);
+	fn density_units() {
 		assert_approx_equal(
-			Luminosity::from_cd(1e-06_f64).to_cd(),
-			Luminosity::from_ucd(1.0_f64).to_cd(), 9
+			Density::from_kgpm3(1000.0_f64).to_kgpm3(),
+			Density::from_kgpL(1.0_f64).to_kgpm3(), 9
 		);
 		assert_approx_equal(
-			Luminosity::from_cd(1.0_f64).to_cd() * 1000000.0,
-			Luminosity::from_cd(1.0_f64).to_ucd(), 9
+			Density::from_kgpm3(1.0_f64).to_kgpm3() * 0.001,
+			Density::from_kgpm3(1.0_f64).to_kgpL(), 9
 		);
 		assert_approx_equal(
-			Luminosity::from_cd(1e-09_f64).to_cd(),
-			Luminosity::from_ncd(1.0_f64).to_cd(), 9
+			Density::from_kgpm3(1000.0_f64).to_kgpm3(),
+			Density::from_kilograms_per_liter(1.0_f64).to_kgpm3(), 9
 		);
 		assert_approx_equal(
-			Luminosity::from_cd(1.0_f64).to_cd() * 1000000000.0,
-			Luminosity::from_cd(1.0_f64).to_ncd(), 9
+			Density::from_kgpm3(1.0_f64).to_kgpm3() * 0.001,
+			Density::from_kgpm3(1.0_f64).to_kilograms_per_liter(), 9
 		);
 		assert_approx_equal(
-			Luminosity::from_cd(1000.0_f64).to_cd(),
-			Luminosity::from_kcd(1.0_f64).to_cd(), 9
+			Density::from_kgpm3(1000.0_f64).to_kgpm3(),
+			Density::from_gpcc(1.0_f64).to_kgpm3(), 9
 		);
 		assert_approx_equal(
-			Luminosity::from_cd(1.0_f64).to_cd() * 0.001,
-			Luminosity::from_cd(1.0_f64).to_kcd(), 9
+			Density::from_kgpm3(1.0_f64).to_kgpm3() * 0.001,
+			Density::from_kgpm3(1.0_f64).to_gpcc(), 9
 		);
 		assert_approx_equal(
-			Luminosity::from_cd(1000000.0_f64).to_cd(),
-			Luminosity::from_Mcd(1.0_f64).to_cd(), 9
+			Density::from_kgpm3(1000.0_f64).to_kgpm3(),
+			Density::from_grams_per_cubic_centimeter(1.0_f64).to_kgpm3(), 9
 		);
 		assert_approx_equal(
-			Luminosity::from_cd(1.0_f64).to_cd() * 1e-06,
-			Luminosity::from_cd(1.0_f64).to_Mcd(), 9
+			Density::from_kgpm3(1.0_f64).to_kgpm3() * 0.001,
+			Density::from_kgpm3(1.0_f64).to_grams_per_cubic_centimeter(), 9
 		);
 		assert_approx_equal(
-			Luminosity::from_cd(1000000000.0_f64).to_cd(),
-			Luminosity::from_Gcd(1.0_f64).to_cd(), 9
+			Density::from_kgpm3(0.001_f64).to_kgpm3(),
+			Density::from_gpm3(1.0_f64).to_kgpm3(), 9
 		);
 		assert_approx_equal(
-			Luminosity::from_cd(1.0_f64).to_cd() * 1e-09,
-			Luminosity::from_cd(1.0_f64).to_Gcd(), 9
+			Density::from_kgpm3(1.0_f64).to_kgpm3() * 1000.0,
+			Density::from_kgpm3(1.0_f64).to_gpm3(), 9
 		);
 	}
 
 	#[test]
-	fn mass_units() {
+	fn energy_units() {
 		assert_approx_equal(
-			Mass::from_kg(0.001_f64).to_kg(),
-			Mass::from_g(1.0_f64).to_kg(), 9
+			Energy::from_J(0.001_f64).to_J(),
+			Energy::from_mJ(1.0_f64).to_J(), 9
 		);
 		assert_approx_equal(
-			Mass::from_kg(1.0_f64).to_kg() * 1000.0,
-			Mass::from_kg(1.0_f64).to_g(), 9
+			Energy::from_J(1.0_f64).to_J() * 1000.0,
+			Energy::from_J(1.0_f64).to_mJ(), 9
 		);
 		assert_approx_equal(
-			Mass::from_kg(1e-06_f64).to_kg(),
-			Mass::from_mg(1.0_f64).to_kg(), 9
+			Energy::from_J(1e-06_f64).to_J(),
+			Energy::from_uJ(1.0_f64).to_J(), 9
 		);
 		assert_approx_equal(
-			Mass::from_kg(1.0_f64).to_kg() * 1000000.0,
-			Mass::from_kg(1.0_f64).to_mg(), 9
+			Energy::from_J(1.0_f64).to_J() * 1000000.0,
+			Energy::from_J(1.0_f64).to_uJ(), 9
 		);
 		assert_approx_equal(
-			Mass::from_kg(1e-09_f64).to_kg(),
-			Mass::from_ug(1.0_f64).to_kg(), 9
+			Energy::from_J(1e-09_f64).to_J(),
+			Energy::from_nJ(1.0_f64).to_J(), 9
 		);
 		assert_approx_equal(
-			Mass::from_kg(1.0_f64).to_kg() * 1000000000.0,
-			Mass::from_kg(1.0_f64).to_ug(), 9
+			Energy::from_J(1.0_f64).to_J() * 1000000000.0,
+			Energy::from_J(1.0_f64).to_nJ(), 9
 		);
 		assert_approx_equal(
-			Mass::from_kg(1e-12_f64).to_kg(),
-			Mass::from_ng(1.0_f64).to_kg(), 9
+			Energy::from_J(1000.0_f64).to_J(),
+			Energy::from_kJ(1.0_f64).to_J(), 9
 		);
 		assert_approx_equal(
-			Mass::from_kg(1.0_f64).to_kg() * 1000000000000.0,
-			Mass::from_kg(1.0_f64).to_ng(), 9
+			Energy::from_J(1.0_f64).to_J() * 0.001,
+			Energy::from_J(1.0_f64).to_kJ(), 9
 		);
 		assert_approx_equal(
-			Mass::from_kg(1e-15_f64).to_kg(),
-			Mass::from_pg(1.0_f64).to_kg(), 9
+			Energy::from_J(1000000.0_f64).to_J(),
+			Energy::from_MJ(1.0_f64).to_J(), 9
 		);
 		assert_approx_equal(
-			Mass::from_kg(1.0_f64).to_kg() * 1000000000000000.0,
-			Mass::from_kg(1.0_f64).to_pg(), 9
+			Energy::from_J(1.0_f64).to_J() * 1e-06,
+			Energy::from_J(1.0_f64).to_MJ(), 9
 		);
 		assert_approx_equal(
-			Mass::from_kg(1000.0_f64).to_kg(),
-			Mass::from_tons(1.0_f64).to_kg(), 9
+			Energy::from_J(1000000000.0_f64).to_J(),
+			Energy::from_GJ(1.0_f64).to_J(), 9
 		);
 		assert_approx_equal(
-			Mass::from_kg(1.0_f64).to_kg() * 0.001,
-			Mass::from_kg(1.0_f64).to_tons(), 9
+			Energy::from_J(1.0_f64).to_J() * 1e-09,
+			Energy::from_J(1.0_f64).to_GJ(), 9
 		);
 		assert_approx_equal(
-			Mass::from_kg(5.9722e+24_f64).to_kg(),
-			Mass::from_earth_mass(1.0_f64).to_kg(), 9
+			Energy::from_J(4.184_f64).to_J(),
+			Energy::from_cal(1.0_f64).to_J(), 9
 		);
 		assert_approx_equal(
-			Mass::from_kg(1.0_f64).to_kg() * 1.6744248350691502e-25,
-			Mass::from_kg(1.0_f64).to_earth_mass(), 9
+			Energy::from_J(1.0_f64).to_J() * 0.239005736137667,
+			Energy::from_J(1.0_f64).to_cal(), 9
 		);
 		assert_approx_equal(
-			Mass::from_kg(1.8986e+27_f64).to_kg(),
-			Mass::from_jupiter_mass(1.0_f64).to_kg(), 9
+			Energy::from_J(4184.0_f64).to_J(),
+			Energy::from_kcal(1.0_f64).to_J(), 9
 		);
 		assert_approx_equal(
-			Mass::from_kg(1.0_f64).to_kg() * 5.26703887074687e-28,
-			Mass::from_kg(1.0_f64).to_jupiter_mass(), 9
+			Energy::from_J(1.0_f64).to_J() * 0.0002390057361376,
+			Energy::from_J(1.0_f64).to_kcal(), 9
 		);
 		assert_approx_equal(
-			Mass::from_kg(1.9885500000000002e+30_f64).to_kg(),
-			Mass::from_solar_mass(1.0_f64).to_kg(), 9
+			Energy::from_J(3600.0_f64).to_J(),
+			Energy::from_Whr(1.0_f64).to_J(), 9
 		);
 		assert_approx_equal(
-			Mass::from_kg(1.0_f64).to_kg() * 5.0287898217294e-31,
-			Mass::from_kg(1.0_f64).to_solar_mass(), 9
+			Energy::from_J(1.0_f64).to_J() * 0.0002777777777777,
+			Energy::from_J(1.0_f64).to_Whr(), 9
 		);
-	}
-
-	#[test]
-	fn temperature_units() {
 		assert_approx_equal(
-			Temperature::from_K(274.1500000000000_f64).to_K(),
-			Temperature::from_C(1.0_f64).to_K(), 9
+			Energy::from_J(3600000.0_f64).to_J(),
+			Energy::from_kWhr(1.0_f64).to_J(), 9
 		);
 		assert_approx_equal(
-			Temperature::from_K(1.0_f64).to_K() * -272.1500000000000,
-			Temperature::from_K(1.0_f64).to_C(), 9
+			Energy::from_J(1.0_f64).to_J() * 2.77777777777778e-07,
+			Energy::from_J(1.0_f64).to_kWhr(), 9
 		);
 		assert_approx_equal(
-			Temperature::from_K(274.1500000000000_f64).to_K(),
-			Temperature::from_celsius(1.0_f64).to_K(), 9
+			Energy::from_J(1.6021766340000001e-19_f64).to_J(),
+			Energy::from_eV(1.0_f64).to_J(), 9
 		);
 		assert_approx_equal(
-			Temperature::from_K(1.0_f64).to_K() * -272.1500000000000,
-			Temperature::from_K(1.0_f64).to_celsius(), 9
+			Energy::from_J(1.0_f64).to_J() * 6.24150907446076e+18,
+			Energy::from_J(1.0_f64).to_eV(), 9
 		);
 		assert_approx_equal(
-			Temperature::from_K(255.9277777777780_f64).to_K(),
-			Temperature::from_F(1.0_f64).to_K(), 9
+			Energy::from_J(4.184_f64).to_J(),
+			Energy::from_calories(1.0_f64).to_J(), 9
 		);
 		assert_approx_equal(
-			Temperature::from_K(1.0_f64).to_K() * -457.8700000000000,
-			Temperature::from_K(1.0_f64).to_F(), 9
+			Energy::from_J(1.0_f64).to_J() * 0.2390057361376673,
+			Energy::from_J(1.0_f64).to_calories(), 9
 		);
-	}
-
-	#[test]
-	fn time_units() {
 		assert_approx_equal(
-			Time::from_s(0.001_f64).to_s(),
-			Time::from_ms(1.0_f64).to_s(), 9
+			Energy::from_J(3600000.0_f64).to_J(),
+			Energy::from_kilowatt_hours(1.0_f64).to_J(), 9
 		);
 		assert_approx_equal(
-			Time::from_s(1.0_f64).to_s() * 1000.0,
-			Time::from_s(1.0_f64).to_ms(), 9
+			Energy::from_J(1.0_f64).to_J() * 2.7777777777777776e-07,
+			Energy::from_J(1.0_f64).to_kilowatt_hours(), 9
 		);
 		assert_approx_equal(
-			Time::from_s(1e-06_f64).to_s(),
-			Time::from_us(1.0_f64).to_s(), 9
+			Energy::from_J(1.602176634e-19_f64).to_J(),
+			Energy::from_electronvolts(1.0_f64).to_J(), 9
 		);
 		assert_approx_equal(
-			Time::from_s(1.0_f64).to_s() * 1000000.0,
-			Time::from_s(1.0_f64).to_us(), 9
+			Energy::from_J(1.0_f64).to_J() * 6.241509074460763e+18,
+			Energy::from_J(1.0_f64).to_electronvolts(), 9
 		);
 		assert_approx_equal(
-			Time::from_s(1e-09_f64).to_s(),
-			Time::from_ns(1.0_f64).to_s(), 9
+			Energy::from_J(1055.05585262_f64).to_J(),
+			Energy::from_BTU(1.0_f64).to_J(), 9
 		);
 		assert_approx_equal(
-			Time::from_s(1.0_f64).to_s() * 1000000000.0,
-			Time::from_s(1.0_f64).to_ns(), 9
+			Energy::from_J(1.0_f64).to_J() * 0.0009478171203133172,
+			Energy::from_J(1.0_f64).to_BTU(), 9
 		);
 		assert_approx_equal(
-			Time::from_s(1e-12_f64).to_s(),
-			Time::from_ps(1.0_f64).to_s(), 9
+			Energy::from_J(1055.05585262_f64).to_J(),
+			Energy::from_btu(1.0_f64).to_J(), 9
 		);
 		assert_approx_equal(
-			Time::from_s(1.0_f64).to_s() * 1000000000000.0,
-			Time::from_s(1.0_f64).to_ps(), 9
+			Energy::from_J(1.0_f64).to_J() * 0.0009478171203133172,
+			Energy::from_J(1.0_f64).to_btu(), 9
 		);
+	}
+
+	#[test]
+	fn force_units() {
 		assert_approx_equal(
-			Time::from_s(60.0_f64).to_s(),
-			Time::from_min(1.0_f64).to_s(), 9
+			Force::from_N(4.45756819483586_f64).to_N(),
+			Force::from_lb(1.0_f64).to_N(), 9
 		);
 		assert_approx_equal(
-			Time::from_s(1.0_f64).to_s() * 0.0166666666666667,
-			Time::from_s(1.0_f64).to_min(), 9
+			Force::from_N(1.0_f64).to_N() * 0.224337566199999,
+			Force::from_N(1.0_f64).to_lb(), 9
 		);
 		assert_approx_equal(
-			Time::from_s(3600.0_f64).to_s(),
-			Time::from_hr(1.0_f64).to_s(), 9
+			Force::from_N(9.8066500286389_f64).to_N(),
+			Force::from_kgG(1.0_f64).to_N(), 9
 		);
 		assert_approx_equal(
-			Time::from_s(1.0_f64).to_s() * 0.0002777777777777,
-			Time::from_s(1.0_f64).to_hr(), 9
+			Force::from_N(1.0_f64).to_N() * 0.101971620999999,
+			Force::from_N(1.0_f64).to_kgG(), 9
 		);
 		assert_approx_equal(
-			Time::from_s(86400.0_f64).to_s(),
-			Time::from_days(1.0_f64).to_s(), 9
+			Force::from_N(0.001_f64).to_N(),
+			Force::from_mN(1.0_f64).to_N(), 9
 		);
 		assert_approx_equal(
-			Time::from_s(1.0_f64).to_s() * 1.15740740740741e-05,
-			Time::from_s(1.0_f64).to_days(), 9
+			Force::from_N(1.0_f64).to_N() * 1000.0,
+			Force::from_N(1.0_f64).to_mN(), 9
 		);
 		assert_approx_equal(
-			Time::from_s(604800.0_f64).to_s(),
-			Time::from_weeks(1.0_f64).to_s(), 9
+			Force::from_N(1e-06_f64).to_N(),
+			Force::from_uN(1.0_f64).to_N(), 9
 		);
 		assert_approx_equal(
-			Time::from_s(1.0_f64).to_s() * 1.65343915343915e-06,
-			Time::from_s(1.0_f64).to_weeks(), 9
+			Force::from_N(1.0_f64).to_N() * 1000000.0,
+			Force::from_N(1.0_f64).to_uN(), 9
 		);
 		assert_approx_equal(
-			Time::from_s(31556925.19008_f64).to_s(),
-			Time::from_yr(1.0_f64).to_s(), 9
+			Force::from_N(1e-09_f64).to_N(),
+			Force::from_nN(1.0_f64).to_N(), 9
 		);
 		assert_approx_equal(
-			Time::from_s(1.0_f64).to_s() * 3.16887654287165e-08,
-			Time::from_s(1.0_f64).to_yr(), 9
+			Force::from_N(1.0_f64).to_N() * 1000000000.0,
+			Force::from_N(1.0_f64).to_nN(), 9
 		);
 		assert_approx_equal(
-			Time::from_s(31556925190.08_f64).to_s(),
-			Time::from_kyr(1.0_f64).to_s(), 9
+			Force::from_N(1000.0_f64).to_N(),
+			Force::from_kN(1.0_f64).to_N(), 9
 		);
 		assert_approx_equal(
-			Time::from_s(1.0_f64).to_s() * 3.16887654287165e-11,
-			Time::from_s(1.0_f64).to_kyr(), 9
+			Force::from_N(1.0_f64).to_N() * 0.001,
+			Force::from_N(1.0_f64).to_kN(), 9
 		);
 		assert_approx_equal(
-			Time::from_s(31556925190080.0_f64).to_s(),
-			Time::from_Myr(1.0_f64).to_s(), 9
+			Force::from_N(1000000.0_f64).to_N(),
+			Force::from_MN(1.0_f64).to_N(), 9
 		);
 		assert_approx_equal(
-			Time::from_s(1.0_f64).to_s() * 3.16887654287165e-14,
-			Time::from_s(1.0_f64).to_Myr(), 9
+			Force::from_N(1.0_f64).to_N() * 1e-06,
+			Force::from_N(1.0_f64).to_MN(), 9
 		);
 		assert_approx_equal(
-			Time::from_s(3.155692519008e+16_f64).to_s(),
-			Time::from_Gyr(1.0_f64).to_s(), 9
+			Force::from_N(1000000000.0_f64).to_N(),
+			Force::from_GN(1.0_f64).to_N(), 9
 		);
 		assert_approx_equal(
-			Time::from_s(1.0_f64).to_s() * 3.16887654287165e-17,
-			Time::from_s(1.0_f64).to_Gyr(), 9
+			Force::from_N(1.0_f64).to_N() * 1e-09,
+			Force::from_N(1.0_f64).to_GN(), 9
 		);
 	}
 
 	#[test]
-	fn catalytic_activity_units() {
+	fn frequency_units() {
 		assert_approx_equal(
-			CatalyticActivity::from_molps(1.66053906717385e-24_f64).to_molps(),
-			CatalyticActivity::from_Nps(1.0_f64).to_molps(), 9
+			Frequency::from_Hz(1000.0_f64).to_Hz(),
+			Frequency::from_kHz(1.0_f64).to_Hz(), 9
 		);
 		assert_approx_equal(
-			CatalyticActivity::from_molps(1.0_f64).to_molps() * 6.02214076e+23,
-			CatalyticActivity::from_molps(1.0_f64).to_Nps(), 9
+			Frequency::from_Hz(1.0_f64).to_Hz() * 0.001,
+			Frequency::from_Hz(1.0_f64).to_kHz(), 9
 		);
 		assert_approx_equal(
-			CatalyticActivity::from_molps(0.001_f64).to_molps(),
-			CatalyticActivity::from_mmolps(1.0_f64).to_molps(), 9
+			Frequency::from_Hz(1000000.0_f64).to_Hz(),
+			Frequency::from_MHz(1.0_f64).to_Hz(), 9
 		);
 		assert_approx_equal(
-			CatalyticActivity::from_molps(1.0_f64).to_molps() * 1000.0,
-			CatalyticActivity::from_molps(1.0_f64).to_mmolps(), 9
+			Frequency::from_Hz(1.0_f64).to_Hz() * 1e-06,
+			Frequency::from_Hz(1.0_f64).to_MHz(), 9
 		);
 		assert_approx_equal(
-			CatalyticActivity::from_molps(1e-06_f64).to_molps(),
-			CatalyticActivity::from_umolps(1.0_f64).to_molps(), 9
+			Frequency::from_Hz(1000000000.0_f64).to_Hz(),
+			Frequency::from_GHz(1.0_f64).to_Hz(), 9
 		);
 		assert_approx_equal(
-			CatalyticActivity::from_molps(1.0_f64).to_molps() * 1000000.0,
-			CatalyticActivity::from_molps(1.0_f64).to_umolps(), 9
+			Frequency::from_Hz(1.0_f64).to_Hz() * 1e-09,
+			Frequency::from_Hz(1.0_f64).to_GHz(), 9
 		);
 		assert_approx_equal(
-			CatalyticActivity::from_molps(1e-09_f64).to_molps(),
-			CatalyticActivity::from_nmolps(1.0_f64).to_molps(), 9
+			Frequency::from_Hz(1000000000000.0_f64).to_Hz(),
+			Frequency::from_THz(1.0_f64).to_Hz(), 9
 		);
 		assert_approx_equal(
-			CatalyticActivity::from_molps(1.0_f64).to_molps() * 1000000000.0,
-			CatalyticActivity::from_molps(1.0_f64).to_nmolps(), 9
+			Frequency::from_Hz(1.0_f64).to_Hz() * 1e-12,
+			Frequency::from_Hz(1.0_f64).to_THz(), 9
+		);
+		assert_approx_equal(
+			Frequency::from_Hz(0.016666666666666666_f64).to_Hz(),
+			Frequency::from_rpm(1.0_f64).to_Hz(), 9
+		);
+		assert_approx_equal(
+			Frequency::from_Hz(1.0_f64).to_Hz() * 60.0,
+			Frequency::from_Hz(1.0_f64).to_rpm(), 9
 		);
 	}
 
 	#[test]
-	fn concentration_units() {
+	fn moment_of_inertia_units() {
 		assert_approx_equal(
-			Concentration::from_molpm3(1.66053906717385e-24_f64).to_molpm3(),
-			Concentration::from_Npm3(1.0_f64).to_molpm3(), 9
+			MomentOfInertia::from_kgm2(10.0_f64).to_kgm2(),
+			MomentOfInertia::from_gcm2(1.0_f64).to_kgm2(), 9
 		);
 		assert_approx_equal(
-			Concentration::from_molpm3(1.0_f64).to_molpm3() * 6.02214076e+23,
-			Concentration::from_molpm3(1.0_f64).to_Npm3(), 9
+			MomentOfInertia::from_kgm2(1.0_f64).to_kgm2() * 0.1,
+			MomentOfInertia::from_kgm2(1.0_f64).to_gcm2(), 9
 		);
 		assert_approx_equal(
-			Concentration::from_molpm3(1.66053906717385e-24_f64).to_molpm3(),
-			Concentration::from_count_per_cubic_meter(1.0_f64).to_molpm3(), 9
+			MomentOfInertia::from_kgm2(0.001_f64).to_kgm2(),
+			MomentOfInertia::from_gm2(1.0_f64).to_kgm2(), 9
 		);
 		assert_approx_equal(
-			Concentration::from_molpm3(1.0_f64).to_molpm3() * 6.02214076e+23,
-			Concentration::from_molpm3(1.0_f64).to_count_per_cubic_meter(), 9
+			MomentOfInertia::from_kgm2(1.0_f64).to_kgm2() * 1000.0,
+			MomentOfInertia::from_kgm2(1.0_f64).to_gm2(), 9
 		);
+	}
+
+	#[test]
+	fn momentum_units() {
 		assert_approx_equal(
-			Concentration::from_molpm3(1.66053906717385e-21_f64).to_molpm3(),
-			Concentration::from_NpL(1.0_f64).to_molpm3(), 9
+			Momentum::from_kgmps(1e-05_f64).to_kgmps(),
+			Momentum::from_gram_centimeters_per_second(1.0_f64).to_kgmps(), 9
 		);
 		assert_approx_equal(
-			Concentration::from_molpm3(1.0_f64).to_molpm3() * 6.02214076e+26,
-			Concentration::from_molpm3(1.0_f64).to_NpL(), 9
+			Momentum::from_kgmps(1.0_f64).to_kgmps() * 100000.0,
+			Momentum::from_kgmps(1.0_f64).to_gram_centimeters_per_second(), 9
 		);
 		assert_approx_equal(
-			Concentration::from_molpm3(1.66053906717385e-21_f64).to_molpm3(),
-			Concentration::from_count_per_L(1.0_f64).to_molpm3(), 9
+			Momentum::from_kgmps(1e-05_f64).to_kgmps(),
+			Momentum::from_gcmps(1.0_f64).to_kgmps(), 9
 		);
 		assert_approx_equal(
-			Concentration::from_molpm3(1.0_f64).to_molpm3() * 6.02214076e+26,
-			Concentration::from_molpm3(1.0_f64).to_count_per_L(), 9
+			Momentum::from_kgmps(1.0_f64).to_kgmps() * 100000.0,
+			Momentum::from_kgmps(1.0_f64).to_gcmps(), 9
 		);
+	}
+
+	#[test]
+	fn power_units() {
 		assert_approx_equal(
-			Concentration::from_molpm3(1.66053906717385e-18_f64).to_molpm3(),
-			Concentration::from_Npcc(1.0_f64).to_molpm3(), 9
+			Power::from_W(0.001_f64).to_W(),
+			Power::from_mW(1.0_f64).to_W(), 9
 		);
 		assert_approx_equal(
-			Concentration::from_molpm3(1.0_f64).to_molpm3() * 6.02214076e+29,
-			Concentration::from_molpm3(1.0_f64).to_Npcc(), 9
+			Power::from_W(1.0_f64).to_W() * 1000.0,
+			Power::from_W(1.0_f64).to_mW(), 9
 		);
 		assert_approx_equal(
-			Concentration::from_molpm3(1.66053906717385e-18_f64).to_molpm3(),
-			Concentration::from_count_per_cc(1.0_f64).to_molpm3(), 9
+			Power::from_W(1e-06_f64).to_W(),
+			Power::from_uW(1.0_f64).to_W(), 9
 		);
 		assert_approx_equal(
-			Concentration::from_molpm3(1.0_f64).to_molpm3() * 6.02214076e+29,
-			Concentration::from_molpm3(1.0_f64).to_count_per_cc(), 9
+			Power::from_W(1.0_f64).to_W() * 1000000.0,
+			Power::from_W(1.0_f64).to_uW(), 9
 		);
 		assert_approx_equal(
-			Concentration::from_molpm3(1000.0_f64).to_molpm3(),
-			Concentration::from_M(1.0_f64).to_molpm3(), 9
+			Power::from_W(1e-09_f64).to_W(),
+			Power::from_nW(1.0_f64).to_W(), 9
 		);
 		assert_approx_equal(
-			Concentration::from_molpm3(1.0_f64).to_molpm3() * 0.001,
-			Concentration::from_molpm3(1.0_f64).to_M(), 9
+			Power::from_W(1.0_f64).to_W() * 1000000000.0,
+			Power::from_W(1.0_f64).to_nW(), 9
 		);
 		assert_approx_equal(
-			Concentration::from_molpm3(1000.0_f64).to_molpm3(),
-			Concentration::from_molarity(1.0_f64).to_molpm3(), 9
+			Power::from_W(1000.0_f64).to_W(),
+			Power::from_kW(1.0_f64).to_W(), 9
 		);
 		assert_approx_equal(
-			Concentration::from_molpm3(1.0_f64).to_molpm3() * 0.001,
-			Concentration::from_molpm3(1.0_f64).to_molarity(), 9
+			Power::from_W(1.0_f64).to_W() * 0.001,
+			Power::from_W(1.0_f64).to_kW(), 9
 		);
 		assert_approx_equal(
-			Concentration::from_molpm3(0.001_f64).to_molpm3(),
-			Concentration::from_uM(1.0_f64).to_molpm3(), 9
+			Power::from_W(1000000.0_f64).to_W(),
+			Power::from_MW(1.0_f64).to_W(), 9
 		);
 		assert_approx_equal(
-			Concentration::from_molpm3(1.0_f64).to_molpm3() * 1000.0,
-			Concentration::from_molpm3(1.0_f64).to_uM(), 9
+			Power::from_W(1.0_f64).to_W() * 1e-06,
+			Power::from_W(1.0_f64).to_MW(), 9
 		);
 		assert_approx_equal(
-			Concentration::from_molpm3(1e-06_f64).to_molpm3(),
-			Concentration::from_nM(1.0_f64).to_molpm3(), 9
+			Power::from_W(1000000000.0_f64).to_W(),
+			Power::from_GW(1.0_f64).to_W(), 9
 		);
 		assert_approx_equal(
-			Concentration::from_molpm3(1.0_f64).to_molpm3() * 1000000.0,
-			Concentration::from_molpm3(1.0_f64).to_nM(), 9
+			Power::from_W(1.0_f64).to_W() * 1e-09,
+			Power::from_W(1.0_f64).to_GW(), 9
 		);
-	}
-
-	#[test]
-	fn molality_units() {
 		assert_approx_equal(
-			Molality::from_molpkg(0.001_f64).to_molpkg(),
-			Molality::from_mmolpkg(1.0_f64).to_molpkg(), 9
+			Power::from_W(745.7_f64).to_W(),
+			Power::from_horsepower(1.0_f64).to_W(), 9
 		);
 		assert_approx_equal(
-			Molality::from_molpkg(1.0_f64).to_molpkg() * 1000.0,
-			Molality::from_molpkg(1.0_f64).to_mmolpkg(), 9
+			Power::from_W(1.0_f64).to_W() * 0.0013410218586563,
+			Power::from_W(1.0_f64).to_horsepower(), 9
 		);
+	}
+
+	#[test]
+	fn pressure_units() {
 		assert_approx_equal(
-			Molality::from_molpkg(1e-06_f64).to_molpkg(),
-			Molality::from_umolpkg(1.0_f64).to_molpkg(), 9
+			Pressure::from_Pa(0.001_f64).to_Pa(),
+			Pressure::from_mPa(1.0_f64).to_Pa(), 9
 		);
 		assert_approx_equal(
-			Molality::from_molpkg(1.0_f64).to_molpkg() * 1000000.0,
-			Molality::from_molpkg(1.0_f64).to_umolpkg(), 9
+			Pressure::from_Pa(1.0_f64).to_Pa() * 1000.0,
+			Pressure::from_Pa(1.0_f64).to_mPa(), 9
 		);
 		assert_approx_equal(
-			Molality::from_molpkg(1e-09_f64).to_molpkg(),
-			Molality::from_nmolpkg(1.0_f64).to_molpkg(), 9
+			Pressure::from_Pa(1e-06_f64).to_Pa(),
+			Pressure::from_uPa(1.0_f64).to_Pa(), 9
 		);
 		assert_approx_equal(
-			Molality::from_molpkg(1.0_f64).to_molpkg() * 1000000000.0,
-			Molality::from_molpkg(1.0_f64).to_nmolpkg(), 9
+			Pressure::from_Pa(1.0_f64).to_Pa() * 1000000.0,
+			Pressure::from_Pa(1.0_f64).to_uPa(), 9
 		);
 		assert_approx_equal(
-			Molality::from_molpkg(0.001_f64).to_molpkg(),
-			Molality::from_umolpg(1.0_f64).to_molpkg(), 9
+			Pressure::from_Pa(1e-09_f64).to_Pa(),
+			Pressure::from_nPa(1.0_f64).to_Pa(), 9
 		);
 		assert_approx_equal(
-			Molality::from_molpkg(1.0_f64).to_molpkg() * 1000.0,
-			Molality::from_molpkg(1.0_f64).to_umolpg(), 9
+			Pressure::from_Pa(1.0_f64).to_Pa() * 1000000000.0,
+			Pressure::from_Pa(1.0_f64).to_nPa(), 9
 		);
 		assert_approx_equal(
-			Molality::from_molpkg(1e-06_f64).to_molpkg(),
-			Molality::from_nmolpg(1.0_f64).to_molpkg(), 9
+			Pressure::from_Pa(1000.0_f64).to_Pa(),
+			Pressure::from_kPa(1.0_f64).to_Pa(), 9
 		);
 		assert_approx_equal(
-			Molality::from_molpkg(1.0_f64).to_molpkg() * 1000000.0,
-			Molality::from_molpkg(1.0_f64).to_nmolpg(), 9
+			Pressure::from_Pa(1.0_f64).to_Pa() * 0.001,
+			Pressure::from_Pa(1.0_f64).to_kPa(), 9
 		);
-	}
-
-	#[test]
-	fn molar_mass_units() {
 		assert_approx_equal(
-			MolarMass::from_kgpmol(0.001_f64).to_kgpmol(),
-			MolarMass::from_gpmol(1.0_f64).to_kgpmol(), 9
+			Pressure::from_Pa(1000000.0_f64).to_Pa(),
+			Pressure::from_MPa(1.0_f64).to_Pa(), 9
 		);
 		assert_approx_equal(
-			MolarMass::from_kgpmol(1.0_f64).to_kgpmol() * 1000.0,
-			MolarMass::from_kgpmol(1.0_f64).to_gpmol(), 9
+			Pressure::from_Pa(1.0_f64).to_Pa() * 1e-06,
+			Pressure::from_Pa(1.0_f64).to_MPa(), 9
 		);
 		assert_approx_equal(
-			MolarMass::from_kgpmol(0.001_f64).to_kgpmol(),
-			MolarMass::from_grams_per_mole(1.0_f64).to_kgpmol(), 9
+			Pressure::from_Pa(1000000000.0_f64).to_Pa(),
+			Pressure::from_GPa(1.0_f64).to_Pa(), 9
 		);
 		assert_approx_equal(
-			MolarMass::from_kgpmol(1.0_f64).to_kgpmol() * 1000.0,
-			MolarMass::from_kgpmol(1.0_f64).to_grams_per_mole(), 9
+			Pressure::from_Pa(1.0_f64).to_Pa() * 1e-09,
+			Pressure::from_Pa(1.0_f64).to_GPa(), 9
 		);
-	}
-
-	#[test]
-	fn inverse_specific_heat_capacity_units() {
 		assert_approx_equal(
-			InverseSpecificHeatCapacity::from_kgK_per_J(0.001_f64).to_kgK_per_J(),
-			InverseSpecificHeatCapacity::from_grams_kelvin_per_joule(1.0_f64).to_kgK_per_J(), 9
+			Pressure::from_Pa(100.0_f64).to_Pa(),
+			Pressure::from_hPa(1.0_f64).to_Pa(), 9
 		);
 		assert_approx_equal(
-			InverseSpecificHeatCapacity::from_kgK_per_J(1.0_f64).to_kgK_per_J() * 1000.0,
-			InverseSpecificHeatCapacity::from_kgK_per_J(1.0_f64).to_grams_kelvin_per_joule(), 9
+			Pressure::from_Pa(1.0_f64).to_Pa() * 0.01,
+			Pressure::from_Pa(1.0_f64).to_hPa(), 9
 		);
 		assert_approx_equal(
-			InverseSpecificHeatCapacity::from_kgK_per_J(0.001_f64).to_kgK_per_J(),
-			InverseSpecificHeatCapacity::from_gK_per_J(1.0_f64).to_kgK_per_J(), 9
+			Pressure::from_Pa(100000.0_f64).to_Pa(),
+			Pressure::from_bar(1.0_f64).to_Pa(), 9
 		);
 		assert_approx_equal(
-			InverseSpecificHeatCapacity::from_kgK_per_J(1.0_f64).to_kgK_per_J() * 1000.0,
-			InverseSpecificHeatCapacity::from_kgK_per_J(1.0_f64).to_gK_per_J(), 9
+			Pressure::from_Pa(1.0_f64).to_Pa() * 1e-05,
+			Pressure::from_Pa(1.0_f64).to_bar(), 9
 		);
-	}
-
-	#[test]
-	fn capacitance_units() {
 		assert_approx_equal(
-			Capacitance::from_F(0.001_f64).to_F(),
-			Capacitance::from_mF(1.0_f64).to_F(), 9
+			Pressure::from_Pa(100.0_f64).to_Pa(),
+			Pressure::from_mbar(1.0_f64).to_Pa(), 9
 		);
 		assert_approx_equal(
-			Capacitance::from_F(1.0_f64).to_F() * 1000.0,
-			Capacitance::from_F(1.0_f64).to_mF(), 9
+			Pressure::from_Pa(1.0_f64).to_Pa() * 0.01,
+			Pressure::from_Pa(1.0_f64).to_mbar(), 9
 		);
 		assert_approx_equal(
-			Capacitance::from_F(1e-06_f64).to_F(),
-			Capacitance::from_uF(1.0_f64).to_F(), 9
+			Pressure::from_Pa(101325.0_f64).to_Pa(),
+			Pressure::from_atm(1.0_f64).to_Pa(), 9
 		);
 		assert_approx_equal(
-			Capacitance::from_F(1.0_f64).to_F() * 1000000.0,
-			Capacitance::from_F(1.0_f64).to_uF(), 9
+			Pressure::from_Pa(6894.757293168361_f64).to_Pa(),
+			Pressure::from_psi(1.0_f64).to_Pa(), 9
 		);
 		assert_approx_equal(
-			Capacitance::from_F(1e-09_f64).to_F(),
-			Capacitance::from_nF(1.0_f64).to_F(), 9
+			Pressure::from_Pa(1.0_f64).to_Pa() * 0.00014503773773020924,
+			Pressure::from_Pa(1.0_f64).to_psi(), 9
 		);
 		assert_approx_equal(
-			Capacitance::from_F(1.0_f64).to_F() * 1000000000.0,
-			Capacitance::from_F(1.0_f64).to_nF(), 9
+			Pressure::from_Pa(1.0_f64).to_Pa() * 9.869232667160129e-06,
+			Pressure::from_Pa(1.0_f64).to_atm(), 9
 		);
 		assert_approx_equal(
-			Capacitance::from_F(1e-12_f64).to_F(),
-			Capacitance::from_pF(1.0_f64).to_F(), 9
+			Pressure::from_Pa(101325.0_f64).to_Pa(),
+			Pressure::from_atmospheres(1.0_f64).to_Pa(), 9
 		);
 		assert_approx_equal(
-			Capacitance::from_F(1.0_f64).to_F() * 1000000000000.0,
-			Capacitance::from_F(1.0_f64).to_pF(), 9
+			Pressure::from_Pa(1.0_f64).to_Pa() * 9.869232667160129e-06,
+			Pressure::from_Pa(1.0_f64).to_atmospheres(), 9
 		);
 		assert_approx_equal(
-			Capacitance::from_F(1000.0_f64).to_F(),
-			Capacitance::from_kF(1.0_f64).to_F(), 9
+			Pressure::from_Pa(133.32236842105263_f64).to_Pa(),
+			Pressure::from_torr(1.0_f64).to_Pa(), 9
 		);
 		assert_approx_equal(
-			Capacitance::from_F(1.0_f64).to_F() * 0.001,
-			Capacitance::from_F(1.0_f64).to_kF(), 9
+			Pressure::from_Pa(1.0_f64).to_Pa() * 0.007500616827041697,
+			Pressure::from_Pa(1.0_f64).to_torr(), 9
 		);
 		assert_approx_equal(
-			Capacitance::from_F(1000000.0_f64).to_F(),
-			Capacitance::from_MF(1.0_f64).to_F(), 9
+			Pressure::from_Pa(133.322387415_f64).to_Pa(),
+			Pressure::from_mmHg(1.0_f64).to_Pa(), 9
 		);
 		assert_approx_equal(
-			Capacitance::from_F(1.0_f64).to_F() * 1e-06,
-			Capacitance::from_F(1.0_f64).to_MF(), 9
+			Pressure::from_Pa(1.0_f64).to_Pa() * 0.007500615758456563,
+			Pressure::from_Pa(1.0_f64).to_mmHg(), 9
 		);
+	}
+
+	#[test]
+	fn torque_units() {
 		assert_approx_equal(
-			Capacitance::from_F(1000000000.0_f64).to_F(),
-			Capacitance::from_GF(1.0_f64).to_F(), 9
+			Torque::from_Nm(1.35581794833139_f64).to_Nm(),
+			Torque::from_ftlb(1.0_f64).to_Nm(), 9
 		);
 		assert_approx_equal(
-			Capacitance::from_F(1.0_f64).to_F() * 1e-09,
-			Capacitance::from_F(1.0_f64).to_GF(), 9
+			Torque::from_Nm(1.0_f64).to_Nm() * 0.73756214927727,
+			Torque::from_Nm(1.0_f64).to_ftlb(), 9
 		);
 	}
 
 	#[test]
-	fn charge_units() {
+	fn velocity_units() {
 		assert_approx_equal(
-			Charge::from_C(0.001_f64).to_C(),
-			Charge::from_mC(1.0_f64).to_C(), 9
+			Velocity::from_mps(0.01_f64).to_mps(),
+			Velocity::from_cmps(1.0_f64).to_mps(), 9
 		);
 		assert_approx_equal(
-			Charge::from_C(1.0_f64).to_C() * 1000.0,
-			Charge::from_C(1.0_f64).to_mC(), 9
+			Velocity::from_mps(1.0_f64).to_mps() * 100.0,
+			Velocity::from_mps(1.0_f64).to_cmps(), 9
 		);
 		assert_approx_equal(
-			Charge::from_C(1e-06_f64).to_C(),
-			Charge::from_uC(1.0_f64).to_C(), 9
+			Velocity::from_mps(0.001_f64).to_mps(),
+			Velocity::from_mmps(1.0_f64).to_mps(), 9
 		);
 		assert_approx_equal(
-			Charge::from_C(1.0_f64).to_C() * 1000000.0,
-			Charge::from_C(1.0_f64).to_uC(), 9
+			Velocity::from_mps(1.0_f64).to_mps() * 1000.0,
+			Velocity::from_mps(1.0_f64).to_mmps(), 9
 		);
 		assert_approx_equal(
-			Charge::from_C(1e-09_f64).to_C(),
-			Charge::from_nC(1.0_f64).to_C(), 9
+			Velocity::from_mps(2.77777777777778e-07_f64).to_mps(),
+			Velocity::from_mmph(1.0_f64).to_mps(), 9
 		);
 		assert_approx_equal(
-			Charge::from_C(1.0_f64).to_C() * 1000000000.0,
-			Charge::from_C(1.0_f64).to_nC(), 9
+			Velocity::from_mps(1.0_f64).to_mps() * 3600000.0,
+			Velocity::from_mps(1.0_f64).to_mmph(), 9
 		);
 		assert_approx_equal(
-			Charge::from_C(1000.0_f64).to_C(),
-			Charge::from_kC(1.0_f64).to_C(), 9
+			Velocity::from_mps(0.277777777777778_f64).to_mps(),
+			Velocity::from_kph(1.0_f64).to_mps(), 9
 		);
 		assert_approx_equal(
-			Charge::from_C(1.0_f64).to_C() * 0.001,
-			Charge::from_C(1.0_f64).to_kC(), 9
+			Velocity::from_mps(1.0_f64).to_mps() * 3.6,
+			Velocity::from_mps(1.0_f64).to_kph(), 9
 		);
 		assert_approx_equal(
-			Charge::from_C(1000000.0_f64).to_C(),
-			Charge::from_MC(1.0_f64).to_C(), 9
+			Velocity::from_mps(0.44704_f64).to_mps(),
+			Velocity::from_mph(1.0_f64).to_mps(), 9
 		);
 		assert_approx_equal(
-			Charge::from_C(1.0_f64).to_C() * 1e-06,
-			Charge::from_C(1.0_f64).to_MC(), 9
+			Velocity::from_mps(1.0_f64).to_mps() * 2.2369362920544,
+			Velocity::from_mps(1.0_f64).to_mph(), 9
 		);
 		assert_approx_equal(
-			Charge::from_C(1000000000.0_f64).to_C(),
-			Charge::from_GC(1.0_f64).to_C(), 9
+			Velocity::from_mps(1000.0_f64).to_mps(),
+			Velocity::from_kmps(1.0_f64).to_mps(), 9
 		);
 		assert_approx_equal(
-			Charge::from_C(1.0_f64).to_C() * 1e-09,
-			Charge::from_C(1.0_f64).to_GC(), 9
+			Velocity::from_mps(1.0_f64).to_mps() * 0.001,
+			Velocity::from_mps(1.0_f64).to_kmps(), 9
 		);
 		assert_approx_equal(
-			Charge::from_C(1.6021766340000001e-19_f64).to_C(),
-			Charge::from_p(1.0_f64).to_C(), 9
+			Velocity::from_mps(299792458.0_f64).to_mps(),
+			Velocity::from_c(1.0_f64).to_mps(), 9
 		);
 		assert_approx_equal(
-			Charge::from_C(1.0_f64).to_C() * 6.24150907446076e+18,
-			Charge::from_C(1.0_f64).to_p(), 9
+			Velocity::from_mps(1.0_f64).to_mps() * 3.3356409519815204e-09,
+			Velocity::from_mps(1.0_f64).to_c(), 9
 		);
 		assert_approx_equal(
-			Charge::from_C(-1.6021766340000001e-19_f64).to_C(),
-			Charge::from_e(1.0_f64).to_C(), 9
+			Velocity::from_mps(0.5144444444444445_f64).to_mps(),
+			Velocity::from_knots(1.0_f64).to_mps(), 9
 		);
 		assert_approx_equal(
-			Charge::from_C(1.0_f64).to_C() * -6.24150907446076e+18,
-			Charge::from_C(1.0_f64).to_e(), 9
+			Velocity::from_mps(1.0_f64).to_mps() * 1.9438444924406046,
+			Velocity::from_mps(1.0_f64).to_knots(), 9
 		);
 	}
 
 	#[test]
-	fn conductance_units() {
+	fn absorbed_dose_units() {
 		assert_approx_equal(
-			Conductance::from_S(0.001_f64).to_S(),
-			Conductance::from_mS(1.0_f64).to_S(), 9
+			AbsorbedDose::from_Gy(0.001_f64).to_Gy(),
+			AbsorbedDose::from_mGy(1.0_f64).to_Gy(), 9
 		);
 		assert_approx_equal(
-			Conductance::from_S(1.0_f64).to_S() * 1000.0,
-			Conductance::from_S(1.0_f64).to_mS(), 9
+			AbsorbedDose::from_Gy(1.0_f64).to_Gy() * 1000.0,
+			AbsorbedDose::from_Gy(1.0_f64).to_mGy(), 9
 		);
 		assert_approx_equal(
-			Conductance::from_S(1e-06_f64).to_S(),
-			Conductance::from_uS(1.0_f64).to_S(), 9
+			AbsorbedDose::from_Gy(1e-06_f64).to_Gy(),
+			AbsorbedDose::from_uGy(1.0_f64).to_Gy(), 9
 		);
 		assert_approx_equal(
-			Conductance::from_S(1.0_f64).to_S() * 1000000.0,
-			Conductance::from_S(1.0_f64).to_uS(), 9
+			AbsorbedDose::from_Gy(1.0_f64).to_Gy() * 1000000.0,
+			AbsorbedDose::from_Gy(1.0_f64).to_uGy(), 9
 		);
 		assert_approx_equal(
-			Conductance::from_S(1e-09_f64).to_S(),
-			Conductance::from_nS(1.0_f64).to_S(), 9
+			AbsorbedDose::from_Gy(1e-09_f64).to_Gy(),
+			AbsorbedDose::from_nGy(1.0_f64).to_Gy(), 9
 		);
 		assert_approx_equal(
-			Conductance::from_S(1.0_f64).to_S() * 1000000000.0,
-			Conductance::from_S(1.0_f64).to_nS(), 9
+			AbsorbedDose::from_Gy(1.0_f64).to_Gy() * 1000000000.0,
+			AbsorbedDose::from_Gy(1.0_f64).to_nGy(), 9
 		);
 		assert_approx_equal(
-			Conductance::from_S(1000.0_f64).to_S(),
-			Conductance::from_kS(1.0_f64).to_S(), 9
+			AbsorbedDose::from_Gy(1000.0_f64).to_Gy(),
+			AbsorbedDose::from_kGy(1.0_f64).to_Gy(), 9
 		);
 		assert_approx_equal(
-			Conductance::from_S(1.0_f64).to_S() * 0.001,
-			Conductance::from_S(1.0_f64).to_kS(), 9
+			AbsorbedDose::from_Gy(1.0_f64).to_Gy() * 0.001,
+			AbsorbedDose::from_Gy(1.0_f64).to_kGy(), 9
 		);
 		assert_approx_equal(
-			Conductance::from_S(1000000.0_f64).to_S(),
-			Conductance::from_MS(1.0_f64).to_S(), 9
+			AbsorbedDose::from_Gy(1000000.0_f64).to_Gy(),
+			AbsorbedDose::from_MGy(1.0_f64).to_Gy(), 9
 		);
 		assert_approx_equal(
-			Conductance::from_S(1.0_f64).to_S() * 1e-06,
-			Conductance::from_S(1.0_f64).to_MS(), 9
+			AbsorbedDose::from_Gy(1.0_f64).to_Gy() * 1e-06,
+			AbsorbedDose::from_Gy(1.0_f64).to_MGy(), 9
 		);
 		assert_approx_equal(
-			Conductance::from_S(1000000000.0_f64).to_S(),
-			Conductance::from_GS(1.0_f64).to_S(), 9
+			AbsorbedDose::from_Gy(1000000000.0_f64).to_Gy(),
+			AbsorbedDose::from_GGy(1.0_f64).to_Gy(), 9
 		);
 		assert_approx_equal(
-			Conductance::from_S(1.0_f64).to_S() * 1e-09,
-			Conductance::from_S(1.0_f64).to_GS(), 9
+			AbsorbedDose::from_Gy(1.0_f64).to_Gy() * 1e-09,
+			AbsorbedDose::from_Gy(1.0_f64).to_GGy(), 9
 		);
-	}
-
-	#[test]
-	fn illuminance_units() {
 		assert_approx_equal(
-			Illuminance::from_lux(0.001_f64).to_lux(),
-			Illuminance::from_mlux(1.0_f64).to_lux(), 9
+			AbsorbedDose::from_Gy(0.01_f64).to_Gy(),
+			AbsorbedDose::from_rad(1.0_f64).to_Gy(), 9
 		);
 		assert_approx_equal(
-			Illuminance::from_lux(1.0_f64).to_lux() * 1000.0,
-			Illuminance::from_lux(1.0_f64).to_mlux(), 9
+			AbsorbedDose::from_Gy(1.0_f64).to_Gy() * 100.0,
+			AbsorbedDose::from_Gy(1.0_f64).to_rad(), 9
 		);
 		assert_approx_equal(
-			Illuminance::from_lux(1e-06_f64).to_lux(),
-			Illuminance::from_ulux(1.0_f64).to_lux(), 9
+			AbsorbedDose::from_Gy(10.0_f64).to_Gy(),
+			AbsorbedDose::from_krad(1.0_f64).to_Gy(), 9
 		);
 		assert_approx_equal(
-			Illuminance::from_lux(1.0_f64).to_lux() * 1000000.0,
-			Illuminance::from_lux(1.0_f64).to_ulux(), 9
+			AbsorbedDose::from_Gy(1.0_f64).to_Gy() * 0.1,
+			AbsorbedDose::from_Gy(1.0_f64).to_krad(), 9
 		);
 		assert_approx_equal(
-			Illuminance::from_lux(1e-09_f64).to_lux(),
-			Illuminance::from_nlux(1.0_f64).to_lux(), 9
+			AbsorbedDose::from_Gy(1e-05_f64).to_Gy(),
+			AbsorbedDose::from_mrad(1.0_f64).to_Gy(), 9
 		);
 		assert_approx_equal(
-			Illuminance::from_lux(1.0_f64).to_lux() * 1000000000.0,
-			Illuminance::from_lux(1.0_f64).to_nlux(), 9
+			AbsorbedDose::from_Gy(1.0_f64).to_Gy() * 100000.0,
+			AbsorbedDose::from_Gy(1.0_f64).to_mrad(), 9
 		);
 		assert_approx_equal(
-			Illuminance::from_lux(1000.0_f64).to_lux(),
-			Illuminance::from_klux(1.0_f64).to_lux(), 9
+			AbsorbedDose::from_Gy(1e-08_f64).to_Gy(),
+			AbsorbedDose::from_urad(1.0_f64).to_Gy(), 9
 		);
 		assert_approx_equal(
-			Illuminance::from_lux(1.0_f64).to_lux() * 0.001,
-			Illuminance::from_lux(1.0_f64).to_klux(), 9
+			AbsorbedDose::from_Gy(1.0_f64).to_Gy() * 100000000.0,
+			AbsorbedDose::from_Gy(1.0_f64).to_urad(), 9
 		);
 		assert_approx_equal(
-			Illuminance::from_lux(1000000.0_f64).to_lux(),
-			Illuminance::from_Mlux(1.0_f64).to_lux(), 9
+			AbsorbedDose::from_Gy(0.0001_f64).to_Gy(),
+			AbsorbedDose::from_erg(1.0_f64).to_Gy(), 9
+		);
+		assert_approx_equal(
+			AbsorbedDose::from_Gy(1.0_f64).to_Gy() * 10000.0,
+			AbsorbedDose::from_Gy(1.0_f64).to_erg(), 9
+		);
+	}
+
+	#[test]
+	fn dose_equivalent_units() {
+		assert_approx_equal(
+			DoseEquivalent::from_Sv(0.001_f64).to_Sv(),
+			DoseEquivalent::from_mSv(1.0_f64).to_Sv(), 9
+		);
+		assert_approx_equal(
+			DoseEquivalent::from_Sv(1.0_f64).to_Sv() * 1000.0,
+			DoseEquivalent::from_Sv(1.0_f64).to_mSv(), 9
+		);
+		assert_approx_equal(
+			DoseEquivalent::from_Sv(1e-06_f64).to_Sv(),
+			DoseEquivalent::from_uSv(1.0_f64).to_Sv(), 9
 		);
 		assert_approx_equal(
-			Illuminance::from_lux(1.0_f64).to_lux() * 1e-06,
-			Illuminance::from_lux(1.0_f64).to_Mlux(), 9
+			DoseEquivalent::from_Sv(1.0_f64).to_Sv() * 1000000.0,
+			DoseEquivalent::from_Sv(1.0_f64).to_uSv(), 9
 		);
 		assert_approx_equal(
-			Illuminance::from_lux(1000000000.0_f64).to_lux(),
-			Illuminance::from_Glux(1.0_f64).to_lux(), 9
+			DoseEquivalent::from_Sv(1e-09_f64).to_Sv(),
+			DoseEquivalent::from_nSv(1.0_f64).to_Sv(), 9
 		);
 		assert_approx_equal(
-			Illuminance::from_lux(1.0_f64).to_lux() * 1e-09,
-			Illuminance::from_lux(1.0_f64).to_Glux(), 9
+			DoseEquivalent::from_Sv(1.0_f64).to_Sv() * 1000000000.0,
+			DoseEquivalent::from_Sv(1.0_f64).to_nSv(), 9
 		);
-	}
-
-	#[test]
-	fn inductance_units() {
 		assert_approx_equal(
-			Inductance::from_H(0.001_f64).to_H(),
-			Inductance::from_mH(1.0_f64).to_H(), 9
+			DoseEquivalent::from_Sv(1000.0_f64).to_Sv(),
+			DoseEquivalent::from_kSv(1.0_f64).to_Sv(), 9
 		);
 		assert_approx_equal(
-			Inductance::from_H(1.0_f64).to_H() * 1000.0,
-			Inductance::from_H(1.0_f64).to_mH(), 9
+			DoseEquivalent::from_Sv(1.0_f64).to_Sv() * 0.001,
+			DoseEquivalent::from_Sv(1.0_f64).to_kSv(), 9
 		);
 		assert_approx_equal(
-			Inductance::from_H(1e-06_f64).to_H(),
-			Inductance::from_uH(1.0_f64).to_H(), 9
+			DoseEquivalent::from_Sv(1000000.0_f64).to_Sv(),
+			DoseEquivalent::from_MSv(1.0_f64).to_Sv(), 9
 		);
 		assert_approx_equal(
-			Inductance::from_H(1.0_f64).to_H() * 1000000.0,
-			Inductance::from_H(1.0_f64).to_uH(), 9
+			DoseEquivalent::from_Sv(1.0_f64).to_Sv() * 1e-06,
+			DoseEquivalent::from_Sv(1.0_f64).to_MSv(), 9
 		);
 		assert_approx_equal(
-			Inductance::from_H(1e-09_f64).to_H(),
-			Inductance::from_nH(1.0_f64).to_H(), 9
+			DoseEquivalent::from_Sv(1000000000.0_f64).to_Sv(),
+			DoseEquivalent::from_GSv(1.0_f64).to_Sv(), 9
 		);
 		assert_approx_equal(
-			Inductance::from_H(1.0_f64).to_H() * 1000000000.0,
-			Inductance::from_H(1.0_f64).to_nH(), 9
+			DoseEquivalent::from_Sv(1.0_f64).to_Sv() * 1e-09,
+			DoseEquivalent::from_Sv(1.0_f64).to_GSv(), 9
 		);
 		assert_approx_equal(
-			Inductance::from_H(1000.0_f64).to_H(),
-			Inductance::from_kH(1.0_f64).to_H(), 9
+			DoseEquivalent::from_Sv(0.01_f64).to_Sv(),
+			DoseEquivalent::from_rem(1.0_f64).to_Sv(), 9
 		);
 		assert_approx_equal(
-			Inductance::from_H(1.0_f64).to_H() * 0.001,
-			Inductance::from_H(1.0_f64).to_kH(), 9
+			DoseEquivalent::from_Sv(1.0_f64).to_Sv() * 100.0,
+			DoseEquivalent::from_Sv(1.0_f64).to_rem(), 9
 		);
 		assert_approx_equal(
-			Inductance::from_H(1000000.0_f64).to_H(),
-			Inductance::from_MH(1.0_f64).to_H(), 9
+			DoseEquivalent::from_Sv(1e-05_f64).to_Sv(),
+			DoseEquivalent::from_mrem(1.0_f64).to_Sv(), 9
 		);
 		assert_approx_equal(
-			Inductance::from_H(1.0_f64).to_H() * 1e-06,
-			Inductance::from_H(1.0_f64).to_MH(), 9
+			DoseEquivalent::from_Sv(1.0_f64).to_Sv() * 100000.0,
+			DoseEquivalent::from_Sv(1.0_f64).to_mrem(), 9
 		);
 		assert_approx_equal(
-			Inductance::from_H(1000000000.0_f64).to_H(),
-			Inductance::from_GH(1.0_f64).to_H(), 9
+			DoseEquivalent::from_Sv(10.0_f64).to_Sv(),
+			DoseEquivalent::from_krem(1.0_f64).to_Sv(), 9
 		);
 		assert_approx_equal(
-			Inductance::from_H(1.0_f64).to_H() * 1e-09,
-			Inductance::from_H(1.0_f64).to_GH(), 9
+			DoseEquivalent::from_Sv(1.0_f64).to_Sv() * 0.1,
+			DoseEquivalent::from_Sv(1.0_f64).to_krem(), 9
 		);
 	}
 
 	#[test]
-	fn luminous_flux_units() {
+	fn radioactivity_units() {
 		assert_approx_equal(
-			LuminousFlux::from_lm(0.001_f64).to_lm(),
-			LuminousFlux::from_mlm(1.0_f64).to_lm(), 9
+			Radioactivity::from_Bq(0.001_f64).to_Bq(),
+			Radioactivity::from_mBq(1.0_f64).to_Bq(), 9
 		);
 		assert_approx_equal(
-			LuminousFlux::from_lm(1.0_f64).to_lm() * 1000.0,
-			LuminousFlux::from_lm(1.0_f64).to_mlm(), 9
+			Radioactivity::from_Bq(1.0_f64).to_Bq() * 1000.0,
+			Radioactivity::from_Bq(1.0_f64).to_mBq(), 9
 		);
 		assert_approx_equal(
-			LuminousFlux::from_lm(1e-06_f64).to_lm(),
-			LuminousFlux::from_ulm(1.0_f64).to_lm(), 9
+			Radioactivity::from_Bq(1e-06_f64).to_Bq(),
+			Radioactivity::from_uBq(1.0_f64).to_Bq(), 9
 		);
 		assert_approx_equal(
-			LuminousFlux::from_lm(1.0_f64).to_lm() * 1000000.0,
-			LuminousFlux::from_lm(1.0_f64).to_ulm(), 9
+			Radioactivity::from_Bq(1.0_f64).to_Bq() * 1000000.0,
+			Radioactivity::from_Bq(1.0_f64).to_uBq(), 9
 		);
 		assert_approx_equal(
-			LuminousFlux::from_lm(1e-09_f64).to_lm(),
-			LuminousFlux::from_nlm(1.0_f64).to_lm(), 9
+			Radioactivity::from_Bq(1e-09_f64).to_Bq(),
+			Radioactivity::from_nBq(1.0_f64).to_Bq(), 9
 		);
 		assert_approx_equal(
-			LuminousFlux::from_lm(1.0_f64).to_lm() * 1000000000.0,
-			LuminousFlux::from_lm(1.0_f64).to_nlm(), 9
+			Radioactivity::from_Bq(1.0_f64).to_Bq() * 1000000000.0,
+			Radioactivity::from_Bq(1.0_f64).to_nBq(), 9
 		);
 		assert_approx_equal(
-			LuminousFlux::from_lm(1000.0_f64).to_lm(),
-			LuminousFlux::from_klm(1.0_f64).to_lm(), 9
+			Radioactivity::from_Bq(1000.0_f64).to_Bq(),
+			Radioactivity::from_kBq(1.0_f64).to_Bq(), 9
 		);
 		assert_approx_equal(
-			LuminousFlux::from_lm(1.0_f64).to_lm() * 0.001,
-			LuminousFlux::from_lm(1.0_f64).to_klm(), 9
+			Radioactivity::from_Bq(1.0_f64).to_Bq() * 0.001,
+			Radioactivity::from_Bq(1.0_f64).to_kBq(), 9
 		);
 		assert_approx_equal(
-			LuminousFlux::from_lm(1000000.0_f64).to_lm(),
-			LuminousFlux::from_Mlm(1.0_f64).to_lm(), 9
+			Radioactivity::from_Bq(1000000.0_f64).to_Bq(),
+			Radioactivity::from_MBq(1.0_f64).to_Bq(), 9
 		);
 		assert_approx_equal(
-			LuminousFlux::from_lm(1.0_f64).to_lm() * 1e-06,
-			LuminousFlux::from_lm(1.0_f64).to_Mlm(), 9
+			Radioactivity::from_Bq(1.0_f64).to_Bq() * 1e-06,
+			Radioactivity::from_Bq(1.0_f64).to_MBq(), 9
 		);
 		assert_approx_equal(
-			LuminousFlux::from_lm(1000000000.0_f64).to_lm(),
-			LuminousFlux::from_Glm(1.0_f64).to_lm(), 9
+			Radioactivity::from_Bq(1000000000.0_f64).to_Bq(),
+			Radioactivity::from_GBq(1.0_f64).to_Bq(), 9
 		);
 		assert_approx_equal(
-			LuminousFlux::from_lm(1.0_f64).to_lm() * 1e-09,
-			LuminousFlux::from_lm(1.0_f64).to_Glm(), 9
+			Radioactivity::from_Bq(1.0_f64).to_Bq() * 1e-09,
+			Radioactivity::from_Bq(1.0_f64).to_GBq(), 9
 		);
-	}
-
-	#[test]
-	fn magnetic_flux_units() {
 		assert_approx_equal(
-			MagneticFlux::from_Wb(0.001_f64).to_Wb(),
-			MagneticFlux::from_mWb(1.0_f64).to_Wb(), 9
+			Radioactivity::from_Bq(37000000000.0_f64).to_Bq(),
+			Radioactivity::from_Ci(1.0_f64).to_Bq(), 9
 		);
 		assert_approx_equal(
-			MagneticFlux::from_Wb(1.0_f64).to_Wb() * 1000.0,
-			MagneticFlux::from_Wb(1.0_f64).to_mWb(), 9
+			Radioactivity::from_Bq(1.0_f64).to_Bq() * 2.7027027027027027e-11,
+			Radioactivity::from_Bq(1.0_f64).to_Ci(), 9
 		);
 		assert_approx_equal(
-			MagneticFlux::from_Wb(1e-06_f64).to_Wb(),
-			MagneticFlux::from_uWb(1.0_f64).to_Wb(), 9
+			Radioactivity::from_Bq(37000000.0_f64).to_Bq(),
+			Radioactivity::from_mCi(1.0_f64).to_Bq(), 9
 		);
 		assert_approx_equal(
-			MagneticFlux::from_Wb(1.0_f64).to_Wb() * 1000000.0,
-			MagneticFlux::from_Wb(1.0_f64).to_uWb(), 9
+			Radioactivity::from_Bq(1.0_f64).to_Bq() * 2.7027027027027028e-08,
+			Radioactivity::from_Bq(1.0_f64).to_mCi(), 9
 		);
 		assert_approx_equal(
-			MagneticFlux::from_Wb(1e-09_f64).to_Wb(),
-			MagneticFlux::from_nWb(1.0_f64).to_Wb(), 9
+			Radioactivity::from_Bq(37000.0_f64).to_Bq(),
+			Radioactivity::from_uCi(1.0_f64).to_Bq(), 9
 		);
 		assert_approx_equal(
-			MagneticFlux::from_Wb(1.0_f64).to_Wb() * 1000000000.0,
-			MagneticFlux::from_Wb(1.0_f64).to_nWb(), 9
+			Radioactivity::from_Bq(1.0_f64).to_Bq() * 2.7027027027027027e-05,
+			Radioactivity::from_Bq(1.0_f64).to_uCi(), 9
 		);
 		assert_approx_equal(
-			MagneticFlux::from_Wb(1000.0_f64).to_Wb(),
-			MagneticFlux::from_kWb(1.0_f64).to_Wb(), 9
+			Radioactivity::from_Bq(37.0_f64).to_Bq(),
+			Radioactivity::from_nCi(1.0_f64).to_Bq(), 9
 		);
 		assert_approx_equal(
-			MagneticFlux::from_Wb(1.0_f64).to_Wb() * 0.001,
-			MagneticFlux::from_Wb(1.0_f64).to_kWb(), 9
+			Radioactivity::from_Bq(1.0_f64).to_Bq() * 0.027027027027027025,
+			Radioactivity::from_Bq(1.0_f64).to_nCi(), 9
 		);
 		assert_approx_equal(
-			MagneticFlux::from_Wb(1000000.0_f64).to_Wb(),
-			MagneticFlux::from_MWb(1.0_f64).to_Wb(), 9
+			Radioactivity::from_Bq(0.037_f64).to_Bq(),
+			Radioactivity::from_pCi(1.0_f64).to_Bq(), 9
 		);
 		assert_approx_equal(
-			MagneticFlux::from_Wb(1.0_f64).to_Wb() * 1e-06,
-			MagneticFlux::from_Wb(1.0_f64).to_MWb(), 9
+			Radioactivity::from_Bq(1.0_f64).to_Bq() * 27.027027027027028,
+			Radioactivity::from_Bq(1.0_f64).to_pCi(), 9
 		);
 		assert_approx_equal(
-			MagneticFlux::from_Wb(1000000000.0_f64).to_Wb(),
-			MagneticFlux::from_GWb(1.0_f64).to_Wb(), 9
+			Radioactivity::from_Bq(1000000.0_f64).to_Bq(),
+			Radioactivity::from_Rd(1.0_f64).to_Bq(), 9
 		);
 		assert_approx_equal(
-			MagneticFlux::from_Wb(1.0_f64).to_Wb() * 1e-09,
-			MagneticFlux::from_Wb(1.0_f64).to_GWb(), 9
+			Radioactivity::from_Bq(1.0_f64).to_Bq() * 1e-06,
+			Radioactivity::from_Bq(1.0_f64).to_Rd(), 9
 		);
-	}
-
-	#[test]
-	fn magnetic_flux_density_units() {
 		assert_approx_equal(
-			MagneticFluxDensity::from_T(0.001_f64).to_T(),
-			MagneticFluxDensity::from_mT(1.0_f64).to_T(), 9
+			Radioactivity::from_Bq(37000000000.0_f64).to_Bq(),
+			Radioactivity::from_curies(1.0_f64).to_Bq(), 9
 		);
 		assert_approx_equal(
-			MagneticFluxDensity::from_T(1.0_f64).to_T() * 1000.0,
-			MagneticFluxDensity::from_T(1.0_f64).to_mT(), 9
+			Radioactivity::from_Bq(1.0_f64).to_Bq() * 2.7027027027027027e-11,
+			Radioactivity::from_Bq(1.0_f64).to_curies(), 9
 		);
+	}
+
+
+	#[test]
+	fn inverse_amount_units() {
 		assert_approx_equal(
-			MagneticFluxDensity::from_T(1e-06_f64).to_T(),
-			MagneticFluxDensity::from_uT(1.0_f64).to_T(), 9
+			InverseAmount::from_per_mol(6.02e+23_f64).to_per_mol(),
+			InverseAmount::from_per_count(1.0_f64).to_per_mol(), 9
 		);
 		assert_approx_equal(
-			MagneticFluxDensity::from_T(1.0_f64).to_T() * 1000000.0,
-			MagneticFluxDensity::from_T(1.0_f64).to_uT(), 9
+			InverseAmount::from_per_mol(1.0_f64).to_per_mol() * 1.66e-24,
+			InverseAmount::from_per_mol(1.0_f64).to_per_count(), 9
 		);
 		assert_approx_equal(
-			MagneticFluxDensity::from_T(1e-09_f64).to_T(),
-			MagneticFluxDensity::from_nT(1.0_f64).to_T(), 9
+			InverseAmount::from_per_mol(1000.0_f64).to_per_mol(),
+			InverseAmount::from_per_mmol(1.0_f64).to_per_mol(), 9
 		);
 		assert_approx_equal(
-			MagneticFluxDensity::from_T(1.0_f64).to_T() * 1000000000.0,
-			MagneticFluxDensity::from_T(1.0_f64).to_nT(), 9
+			InverseAmount::from_per_mol(1.0_f64).to_per_mol() * 0.001,
+			InverseAmount::from_per_mol(1.0_f64).to_per_mmol(), 9
 		);
 		assert_approx_equal(
-			MagneticFluxDensity::from_T(1000.0_f64).to_T(),
-			MagneticFluxDensity::from_kT(1.0_f64).to_T(), 9
+			InverseAmount::from_per_mol(1000000.0_f64).to_per_mol(),
+			InverseAmount::from_per_umol(1.0_f64).to_per_mol(), 9
 		);
 		assert_approx_equal(
-			MagneticFluxDensity::from_T(1.0_f64).to_T() * 0.001,
-			MagneticFluxDensity::from_T(1.0_f64).to_kT(), 9
+			InverseAmount::from_per_mol(1.0_f64).to_per_mol() * 1e-06,
+			InverseAmount::from_per_mol(1.0_f64).to_per_umol(), 9
 		);
 		assert_approx_equal(
-			MagneticFluxDensity::from_T(1000000.0_f64).to_T(),
-			MagneticFluxDensity::from_MT(1.0_f64).to_T(), 9
+			InverseAmount::from_per_mol(1000000000.0_f64).to_per_mol(),
+			InverseAmount::from_per_nmol(1.0_f64).to_per_mol(), 9
 		);
 		assert_approx_equal(
-			MagneticFluxDensity::from_T(1.0_f64).to_T() * 1e-06,
-			MagneticFluxDensity::from_T(1.0_f64).to_MT(), 9
+			InverseAmount::from_per_mol(1.0_f64).to_per_mol() * 1e-09,
+			InverseAmount::from_per_mol(1.0_f64).to_per_nmol(), 9
 		);
 		assert_approx_equal(
-			MagneticFluxDensity::from_T(1000000000.0_f64).to_T(),
-			MagneticFluxDensity::from_GT(1.0_f64).to_T(), 9
+			InverseAmount::from_per_mol(1000000000000.0_f64).to_per_mol(),
+			InverseAmount::from_per_pmol(1.0_f64).to_per_mol(), 9
 		);
 		assert_approx_equal(
-			MagneticFluxDensity::from_T(1.0_f64).to_T() * 1e-09,
-			MagneticFluxDensity::from_T(1.0_f64).to_GT(), 9
+			InverseAmount::from_per_mol(1.0_f64).to_per_mol() * 1e-12,
+			InverseAmount::from_per_mol(1.0_f64).to_per_pmol(), 9
 		);
 	}
 
 	#[test]
-	fn resistance_units() {
+	fn inverse_current_units() {
 		assert_approx_equal(
-			Resistance::from_Ohm(0.001_f64).to_Ohm(),
-			Resistance::from_mOhm(1.0_f64).to_Ohm(), 9
+			InverseCurrent::from_per_A(1000.0_f64).to_per_A(),
+			InverseCurrent::from_per_mA(1.0_f64).to_per_A(), 9
 		);
 		assert_approx_equal(
-			Resistance::from_Ohm(1.0_f64).to_Ohm() * 1000.0,
-			Resistance::from_Ohm(1.0_f64).to_mOhm(), 9
+			InverseCurrent::from_per_A(1.0_f64).to_per_A() * 0.001,
+			InverseCurrent::from_per_A(1.0_f64).to_per_mA(), 9
 		);
 		assert_approx_equal(
-			Resistance::from_Ohm(1e-06_f64).to_Ohm(),
-			Resistance::from_uOhm(1.0_f64).to_Ohm(), 9
+			InverseCurrent::from_per_A(1000000.0_f64).to_per_A(),
+			InverseCurrent::from_per_uA(1.0_f64).to_per_A(), 9
 		);
 		assert_approx_equal(
-			Resistance::from_Ohm(1.0_f64).to_Ohm() * 1000000.0,
-			Resistance::from_Ohm(1.0_f64).to_uOhm(), 9
+			InverseCurrent::from_per_A(1.0_f64).to_per_A() * 1e-06,
+			InverseCurrent::from_per_A(1.0_f64).to_per_uA(), 9
 		);
 		assert_approx_equal(
-			Resistance::from_Ohm(1e-09_f64).to_Ohm(),
-			Resistance::from_nOhm(1.0_f64).to_Ohm(), 9
+			InverseCurrent::from_per_A(1000000000.0_f64).to_per_A(),
+			InverseCurrent::from_per_nA(1.0_f64).to_per_A(), 9
 		);
 		assert_approx_equal(
-			Resistance::from_Ohm(1.0_f64).to_Ohm() * 1000000000.0,
-			Resistance::from_Ohm(1.0_f64).to_nOhm(), 9
+			InverseCurrent::from_per_A(1.0_f64).to_per_A() * 1e-09,
+			InverseCurrent::from_per_A(1.0_f64).to_per_nA(), 9
 		);
 		assert_approx_equal(
-			Resistance::from_Ohm(1000.0_f64).to_Ohm(),
-			Resistance::from_kOhm(1.0_f64).to_Ohm(), 9
+			InverseCurrent::from_per_A(0.001_f64).to_per_A(),
+			InverseCurrent::from_per_kA(1.0_f64).to_per_A(), 9
 		);
 		assert_approx_equal(
-			Resistance::from_Ohm(1.0_f64).to_Ohm() * 0.001,
-			Resistance::from_Ohm(1.0_f64).to_kOhm(), 9
+			InverseCurrent::from_per_A(1.0_f64).to_per_A() * 1000.0,
+			InverseCurrent::from_per_A(1.0_f64).to_per_kA(), 9
 		);
 		assert_approx_equal(
-			Resistance::from_Ohm(1000000.0_f64).to_Ohm(),
-			Resistance::from_MOhm(1.0_f64).to_Ohm(), 9
+			InverseCurrent::from_per_A(1e-06_f64).to_per_A(),
+			InverseCurrent::from_per_MA(1.0_f64).to_per_A(), 9
 		);
 		assert_approx_equal(
-			Resistance::from_Ohm(1.0_f64).to_Ohm() * 1e-06,
-			Resistance::from_Ohm(1.0_f64).to_MOhm(), 9
+			InverseCurrent::from_per_A(1.0_f64).to_per_A() * 1000000.0,
+			InverseCurrent::from_per_A(1.0_f64).to_per_MA(), 9
 		);
 		assert_approx_equal(
-			Resistance::from_Ohm(1000000000.0_f64).to_Ohm(),
-			Resistance::from_GOhm(1.0_f64).to_Ohm(), 9
+			InverseCurrent::from_per_A(1e-09_f64).to_per_A(),
+			InverseCurrent::from_per_GA(1.0_f64).to_per_A(), 9
 		);
 		assert_approx_equal(
-			Resistance::from_Ohm(1.0_f64).to_Ohm() * 1e-09,
-			Resistance::from_Ohm(1.0_f64).to_GOhm(), 9
+			InverseCurrent::from_per_A(1.0_f64).to_per_A() * 1000000000.0,
+			InverseCurrent::from_per_A(1.0_f64).to_per_GA(), 9
 		);
 	}
 
 	#[test]
-	fn voltage_units() {
+	fn inverse_distance_units() {
 		assert_approx_equal(
-			Voltage::from_V(0.001_f64).to_V(),
-			Voltage::from_mV(1.0_f64).to_V(), 9
+			InverseDistance::from_per_m(100.0_f64).to_per_m(),
+			InverseDistance::from_per_cm(1.0_f64).to_per_m(), 9
 		);
 		assert_approx_equal(
-			Voltage::from_V(1.0_f64).to_V() * 1000.0,
-			Voltage::from_V(1.0_f64).to_mV(), 9
+			InverseDistance::from_per_m(1.0_f64).to_per_m() * 0.01,
+			InverseDistance::from_per_m(1.0_f64).to_per_cm(), 9
 		);
 		assert_approx_equal(
-			Voltage::from_V(1e-06_f64).to_V(),
-			Voltage::from_uV(1.0_f64).to_V(), 9
+			InverseDistance::from_per_m(1000.0_f64).to_per_m(),
+			InverseDistance::from_per_mm(1.0_f64).to_per_m(), 9
 		);
 		assert_approx_equal(
-			Voltage::from_V(1.0_f64).to_V() * 1000000.0,
-			Voltage::from_V(1.0_f64).to_uV(), 9
+			InverseDistance::from_per_m(1.0_f64).to_per_m() * 0.001,
+			InverseDistance::from_per_m(1.0_f64).to_per_mm(), 9
 		);
 		assert_approx_equal(
-			Voltage::from_V(1e-09_f64).to_V(),
-			Voltage::from_nV(1.0_f64).to_V(), 9
+			InverseDistance::from_per_m(1000000.0_f64).to_per_m(),
+			InverseDistance::from_per_um(1.0_f64).to_per_m(), 9
 		);
 		assert_approx_equal(
-			Voltage::from_V(1.0_f64).to_V() * 1000000000.0,
-			Voltage::from_V(1.0_f64).to_nV(), 9
+			InverseDistance::from_per_m(1.0_f64).to_per_m() * 1e-06,
+			InverseDistance::from_per_m(1.0_f64).to_per_um(), 9
 		);
 		assert_approx_equal(
-			Voltage::from_V(1000.0_f64).to_V(),
-			Voltage::from_kV(1.0_f64).to_V(), 9
+			InverseDistance::from_per_m(1000000000.0_f64).to_per_m(),
+			InverseDistance::from_per_nm(1.0_f64).to_per_m(), 9
 		);
 		assert_approx_equal(
-			Voltage::from_V(1.0_f64).to_V() * 0.001,
-			Voltage::from_V(1.0_f64).to_kV(), 9
+			InverseDistance::from_per_m(1.0_f64).to_per_m() * 1e-09,
+			InverseDistance::from_per_m(1.0_f64).to_per_nm(), 9
 		);
 		assert_approx_equal(
-			Voltage::from_V(1000000.0_f64).to_V(),
-			Voltage::from_MV(1.0_f64).to_V(), 9
+			InverseDistance::from_per_m(1000000000000.0_f64).to_per_m(),
+			InverseDistance::from_per_pm(1.0_f64).to_per_m(), 9
 		);
 		assert_approx_equal(
-			Voltage::from_V(1.0_f64).to_V() * 1e-06,
-			Voltage::from_V(1.0_f64).to_MV(), 9
+			InverseDistance::from_per_m(1.0_f64).to_per_m() * 1e-12,
+			InverseDistance::from_per_m(1.0_f64).to_per_pm(), 9
 		);
 		assert_approx_equal(
-			Voltage::from_V(1000000000.0_f64).to_V(),
-			Voltage::from_GV(1.0_f64).to_V(), 9
+			InverseDistance::from_per_m(0.001_f64).to_per_m(),
+			InverseDistance::from_per_km(1.0_f64).to_per_m(), 9
 		);
 		assert_approx_equal(
-			Voltage::from_V(1.0_f64).to_V() * 1e-09,
-			Voltage::from_V(1.0_f64).to_GV(), 9
+			InverseDistance::from_per_m(1.0_f64).to_per_m() * 1000.0,
+			InverseDistance::from_per_m(1.0_f64).to_per_km(), 9
 		);
-	}
-
-	#[test]
-	fn angle_units() {
 		assert_approx_equal(
-			Angle::from_rad(0.0174532925199433_f64).to_rad(),
-			Angle::from_degrees(1.0_f64).to_rad(), 9
+			InverseDistance::from_per_m(6.68e-12_f64).to_per_m(),
+			InverseDistance::from_per_au(1.0_f64).to_per_m(), 9
 		);
 		assert_approx_equal(
-			Angle::from_rad(1.0_f64).to_rad() * 57.2957795130823,
-			Angle::from_rad(1.0_f64).to_degrees(), 9
+			InverseDistance::from_per_m(1.0_f64).to_per_m() * 149597870700.0,
+			InverseDistance::from_per_m(1.0_f64).to_per_au(), 9
 		);
 		assert_approx_equal(
-			Angle::from_rad(0.0174532925199433_f64).to_rad(),
-			Angle::from_deg(1.0_f64).to_rad(), 9
+			InverseDistance::from_per_m(3.24e-17_f64).to_per_m(),
+			InverseDistance::from_per_parsec(1.0_f64).to_per_m(), 9
 		);
 		assert_approx_equal(
-			Angle::from_rad(1.0_f64).to_rad() * 57.2957795130823,
-			Angle::from_rad(1.0_f64).to_deg(), 9
+			InverseDistance::from_per_m(1.0_f64).to_per_m() * 3.09e+16,
+			InverseDistance::from_per_m(1.0_f64).to_per_parsec(), 9
+		);
+		assert_approx_equal(
+			InverseDistance::from_per_m(1.06e-16_f64).to_per_m(),
+			InverseDistance::from_per_lyr(1.0_f64).to_per_m(), 9
+		);
+		assert_approx_equal(
+			InverseDistance::from_per_m(1.0_f64).to_per_m() * 9460528169656200.0,
+			InverseDistance::from_per_m(1.0_f64).to_per_lyr(), 9
 		);
 	}
 
 	#[test]
-	fn area_units() {
+	fn inverse_luminosity_units() {
 		assert_approx_equal(
-			Area::from_m2(0.0001_f64).to_m2(),
-			Area::from_cm2(1.0_f64).to_m2(), 9
+			InverseLuminosity::from_per_cd(1000.0_f64).to_per_cd(),
+			InverseLuminosity::from_per_mcd(1.0_f64).to_per_cd(), 9
 		);
 		assert_approx_equal(
-			Area::from_m2(1.0_f64).to_m2() * 10000.0,
-			Area::from_m2(1.0_f64).to_cm2(), 9
+			InverseLuminosity::from_per_cd(1.0_f64).to_per_cd() * 0.001,
+			InverseLuminosity::from_per_cd(1.0_f64).to_per_mcd(), 9
 		);
 		assert_approx_equal(
-			Area::from_m2(0.0001_f64).to_m2(),
-			Area::from_square_cm(1.0_f64).to_m2(), 9
+			InverseLuminosity::from_per_cd(1000000.0_f64).to_per_cd(),
+			InverseLuminosity::from_per_ucd(1.0_f64).to_per_cd(), 9
 		);
 		assert_approx_equal(
-			Area::from_m2(1.0_f64).to_m2() * 10000.0,
-			Area::from_m2(1.0_f64).to_square_cm(), 9
+			InverseLuminosity::from_per_cd(1.0_f64).to_per_cd() * 1e-06,
+			InverseLuminosity::from_per_cd(1.0_f64).to_per_ucd(), 9
 		);
 		assert_approx_equal(
-			Area::from_m2(1e-06_f64).to_m2(),
-			Area::from_mm2(1.0_f64).to_m2(), 9
+			InverseLuminosity::from_per_cd(1000000000.0_f64).to_per_cd(),
+			InverseLuminosity::from_per_ncd(1.0_f64).to_per_cd(), 9
 		);
 		assert_approx_equal(
-			Area::from_m2(1.0_f64).to_m2() * 1000000.0,
-			Area::from_m2(1.0_f64).to_mm2(), 9
+			InverseLuminosity::from_per_cd(1.0_f64).to_per_cd() * 1e-09,
+			InverseLuminosity::from_per_cd(1.0_f64).to_per_ncd(), 9
 		);
 		assert_approx_equal(
-			Area::from_m2(1e-12_f64).to_m2(),
-			Area::from_um2(1.0_f64).to_m2(), 9
+			InverseLuminosity::from_per_cd(0.001_f64).to_per_cd(),
+			InverseLuminosity::from_per_kcd(1.0_f64).to_per_cd(), 9
 		);
 		assert_approx_equal(
-			Area::from_m2(1.0_f64).to_m2() * 1000000000000.0,
-			Area::from_m2(1.0_f64).to_um2(), 9
+			InverseLuminosity::from_per_cd(1.0_f64).to_per_cd() * 1000.0,
+			InverseLuminosity::from_per_cd(1.0_f64).to_per_kcd(), 9
 		);
 		assert_approx_equal(
-			Area::from_m2(1e-18_f64).to_m2(),
-			Area::from_nm2(1.0_f64).to_m2(), 9
+			InverseLuminosity::from_per_cd(1e-06_f64).to_per_cd(),
+			InverseLuminosity::from_per_Mcd(1.0_f64).to_per_cd(), 9
 		);
 		assert_approx_equal(
-			Area::from_m2(1.0_f64).to_m2() * 1e+18,
-			Area::from_m2(1.0_f64).to_nm2(), 9
+			InverseLuminosity::from_per_cd(1.0_f64).to_per_cd() * 1000000.0,
+			InverseLuminosity::from_per_cd(1.0_f64).to_per_Mcd(), 9
 		);
 		assert_approx_equal(
-			Area::from_m2(1000000.0_f64).to_m2(),
-			Area::from_km2(1.0_f64).to_m2(), 9
+			InverseLuminosity::from_per_cd(1e-09_f64).to_per_cd(),
+			InverseLuminosity::from_per_Gcd(1.0_f64).to_per_cd(), 9
 		);
 		assert_approx_equal(
-			Area::from_m2(1.0_f64).to_m2() * 1e-06,
-			Area::from_m2(1.0_f64).to_km2(), 9
+			InverseLuminosity::from_per_cd(1.0_f64).to_per_cd() * 1000000000.0,
+			InverseLuminosity::from_per_cd(1.0_f64).to_per_Gcd(), 9
 		);
 	}
 
 	#[test]
-	fn volume_units() {
+	fn inverse_mass_units() {
 		assert_approx_equal(
-			Volume::from_m3(1e-06_f64).to_m3(),
-			Volume::from_cc(1.0_f64).to_m3(), 9
+			InverseMass::from_per_kg(1000.0_f64).to_per_kg(),
+			InverseMass::from_per_g(1.0_f64).to_per_kg(), 9
 		);
 		assert_approx_equal(
-			Volume::from_m3(1.0_f64).to_m3() * 1000000.0,
-			Volume::from_m3(1.0_f64).to_cc(), 9
+			InverseMass::from_per_kg(1.0_f64).to_per_kg() * 0.001,
+			InverseMass::from_per_kg(1.0_f64).to_per_g(), 9
 		);
 		assert_approx_equal(
-			Volume::from_m3(0.001_f64).to_m3(),
-			Volume::from_L(1.0_f64).to_m3(), 9
+			InverseMass::from_per_kg(1000000.0_f64).to_per_kg(),
+			InverseMass::from_per_mg(1.0_f64).to_per_kg(), 9
 		);
 		assert_approx_equal(
-			Volume::from_m3(1.0_f64).to_m3() * 1000.0,
-			Volume::from_m3(1.0_f64).to_L(), 9
+			InverseMass::from_per_kg(1.0_f64).to_per_kg() * 1e-06,
+			InverseMass::from_per_kg(1.0_f64).to_per_mg(), 9
 		);
 		assert_approx_equal(
-			Volume::from_m3(0.001_f64).to_m3(),
-			Volume::from_liters(1.0_f64).to_m3(), 9
+			InverseMass::from_per_kg(1000000000.0_f64).to_per_kg(),
+			InverseMass::from_per_ug(1.0_f64).to_per_kg(), 9
 		);
 		assert_approx_equal(
-			Volume::from_m3(1.0_f64).to_m3() * 1000.0,
-			Volume::from_m3(1.0_f64).to_liters(), 9
+			InverseMass::from_per_kg(1.0_f64).to_per_kg() * 1e-09,
+			InverseMass::from_per_kg(1.0_f64).to_per_ug(), 9
 		);
 		assert_approx_equal(
-			Volume::from_m3(1e-06_f64).to_m3(),
-			Volume::from_mL(1.0_f64).to_m3(), 9
+			InverseMass::from_per_kg(1000000000000.0_f64).to_per_kg(),
+			InverseMass::from_per_ng(1.0_f64).to_per_kg(), 9
 		);
 		assert_approx_equal(
-			Volume::from_m3(1.0_f64).to_m3() * 1000000.0,
-			Volume::from_m3(1.0_f64).to_mL(), 9
+			InverseMass::from_per_kg(1.0_f64).to_per_kg() * 1e-12,
+			InverseMass::from_per_kg(1.0_f64).to_per_ng(), 9
 		);
 		assert_approx_equal(
-			Volume::from_m3(1e-09_f64).to_m3(),
-			Volume::from_uL(1.0_f64).to_m3(), 9
+			InverseMass::from_per_kg(1000000000000000.0_f64).to_per_kg(),
+			InverseMass::from_per_pg(1.0_f64).to_per_kg(), 9
 		);
 		assert_approx_equal(
-			Volume::from_m3(1.0_f64).to_m3() * 1000000000.0,
-			Volume::from_m3(1.0_f64).to_uL(), 9
+			InverseMass::from_per_kg(1.0_f64).to_per_kg() * 1e-15,
+			InverseMass::from_per_kg(1.0_f64).to_per_pg(), 9
 		);
 		assert_approx_equal(
-			Volume::from_m3(1e-12_f64).to_m3(),
-			Volume::from_nL(1.0_f64).to_m3(), 9
+			InverseMass::from_per_kg(0.001_f64).to_per_kg(),
+			InverseMass::from_per_tons(1.0_f64).to_per_kg(), 9
 		);
 		assert_approx_equal(
-			Volume::from_m3(1.0_f64).to_m3() * 1000000000000.0,
-			Volume::from_m3(1.0_f64).to_nL(), 9
+			InverseMass::from_per_kg(1.0_f64).to_per_kg() * 1000.0,
+			InverseMass::from_per_kg(1.0_f64).to_per_tons(), 9
 		);
 		assert_approx_equal(
-			Volume::from_m3(1e-15_f64).to_m3(),
-			Volume::from_pL(1.0_f64).to_m3(), 9
+			InverseMass::from_per_kg(1.6699999999999999e-25_f64).to_per_kg(),
+			InverseMass::from_per_earth_mass(1.0_f64).to_per_kg(), 9
 		);
 		assert_approx_equal(
-			Volume::from_m3(1.0_f64).to_m3() * 1000000000000000.0,
-			Volume::from_m3(1.0_f64).to_pL(), 9
+			InverseMass::from_per_kg(1.0_f64).to_per_kg() * 5.97e+24,
+			InverseMass::from_per_kg(1.0_f64).to_per_earth_mass(), 9
 		);
 		assert_approx_equal(
-			Volume::from_m3(1000.0_f64).to_m3(),
-			Volume::from_ML(1.0_f64).to_m3(), 9
+			InverseMass::from_per_kg(5.27e-28_f64).to_per_kg(),
+			InverseMass::from_per_jupiter_mass(1.0_f64).to_per_kg(), 9
 		);
 		assert_approx_equal(
-			Volume::from_m3(1.0_f64).to_m3() * 0.001,
-			Volume::from_m3(1.0_f64).to_ML(), 9
+			InverseMass::from_per_kg(1.0_f64).to_per_kg() * 1.9e+27,
+			InverseMass::from_per_kg(1.0_f64).to_per_jupiter_mass(), 9
 		);
 		assert_approx_equal(
-			Volume::from_m3(1000000.0_f64).to_m3(),
-			Volume::from_GL(1.0_f64).to_m3(), 9
+			InverseMass::from_per_kg(5.03e-31_f64).to_per_kg(),
+			InverseMass::from_per_solar_mass(1.0_f64).to_per_kg(), 9
 		);
 		assert_approx_equal(
-			Volume::from_m3(1.0_f64).to_m3() * 1e-06,
-			Volume::from_m3(1.0_f64).to_GL(), 9
+			InverseMass::from_per_kg(1.0_f64).to_per_kg() * 1.99e+30,
+			InverseMass::from_per_kg(1.0_f64).to_per_solar_mass(), 9
 		);
 	}
 
 	#[test]
-	fn acceleration_units() {
+	fn inverse_catalytic_activity_units() {
 		assert_approx_equal(
-			Acceleration::from_mps2(0.001_f64).to_mps2(),
-			Acceleration::from_mmps2(1.0_f64).to_mps2(), 9
+			InverseCatalyticActivity::from_s_per_mol(60.0_f64).to_s_per_mol(),
+			InverseCatalyticActivity::from_minutes_per_mole(1.0_f64).to_s_per_mol(), 9
 		);
 		assert_approx_equal(
-			Acceleration::from_mps2(1.0_f64).to_mps2() * 1000.0,
-			Acceleration::from_mps2(1.0_f64).to_mmps2(), 9
+			InverseCatalyticActivity::from_s_per_mol(1.0_f64).to_s_per_mol() * 0.0166666666666667,
+			InverseCatalyticActivity::from_s_per_mol(1.0_f64).to_minutes_per_mole(), 9
 		);
 		assert_approx_equal(
-			Acceleration::from_mps2(1e-06_f64).to_mps2(),
-			Acceleration::from_kilometers_per_hour_squared(1.0_f64).to_mps2(), 9
+			InverseCatalyticActivity::from_s_per_mol(3600.0_f64).to_s_per_mol(),
+			InverseCatalyticActivity::from_hours_per_mole(1.0_f64).to_s_per_mol(), 9
 		);
 		assert_approx_equal(
-			Acceleration::from_mps2(1.0_f64).to_mps2() * 1000000.0,
-			Acceleration::from_mps2(1.0_f64).to_kilometers_per_hour_squared(), 9
+			InverseCatalyticActivity::from_s_per_mol(1.0_f64).to_s_per_mol() * 0.000277777777777778,
+			InverseCatalyticActivity::from_s_per_mol(1.0_f64).to_hours_per_mole(), 9
 		);
+	}
+
+	#[test]
+	fn molar_volume_units() {
 		assert_approx_equal(
-			Acceleration::from_mps2(7.71604938271605e-05_f64).to_mps2(),
-			Acceleration::from_kph2(1.0_f64).to_mps2(), 9
+			MolarVolume::from_m3_per_mol(0.001_f64).to_m3_per_mol(),
+			MolarVolume::from_L_per_mol(1.0_f64).to_m3_per_mol(), 9
 		);
 		assert_approx_equal(
-			Acceleration::from_mps2(1.0_f64).to_mps2() * 12960.0,
-			Acceleration::from_mps2(1.0_f64).to_kph2(), 9
+			MolarVolume::from_m3_per_mol(1.0_f64).to_m3_per_mol() * 1000.0,
+			MolarVolume::from_m3_per_mol(1.0_f64).to_L_per_mol(), 9
+		);
+		assert_approx_equal(
+			MolarVolume::from_m3_per_mol(0.001_f64).to_m3_per_mol(),
+			MolarVolume::from_liters_per_mole(1.0_f64).to_m3_per_mol(), 9
+		);
+		assert_approx_equal(
+			MolarVolume::from_m3_per_mol(1.0_f64).to_m3_per_mol() * 1000.0,
+			MolarVolume::from_m3_per_mol(1.0_f64).to_liters_per_mole(), 9
 		);
 	}
 
 	#[test]
-	fn angular_acceleration_units() {
+	fn specific_heat_capacity_units() {
 		assert_approx_equal(
-			AngularAcceleration::from_radps2(0.0174532925199433_f64).to_radps2(),
-			AngularAcceleration::from_degrees_per_second_squared(1.0_f64).to_radps2(), 9
+			SpecificHeatCapacity::from_J_per_kgK(1000.0_f64).to_J_per_kgK(),
+			SpecificHeatCapacity::from_joules_per_gram_kelvin(1.0_f64).to_J_per_kgK(), 9
 		);
 		assert_approx_equal(
-			AngularAcceleration::from_radps2(1.0_f64).to_radps2() * 57.2957795130823,
-			AngularAcceleration::from_radps2(1.0_f64).to_degrees_per_second_squared(), 9
+			SpecificHeatCapacity::from_J_per_kgK(1.0_f64).to_J_per_kgK() * 0.001,
+			SpecificHeatCapacity::from_J_per_kgK(1.0_f64).to_joules_per_gram_kelvin(), 9
 		);
 		assert_approx_equal(
-			AngularAcceleration::from_radps2(6.28318530717959_f64).to_radps2(),
-			AngularAcceleration::from_rps2(1.0_f64).to_radps2(), 9
+			SpecificHeatCapacity::from_J_per_kgK(1000.0_f64).to_J_per_kgK(),
+			SpecificHeatCapacity::from_J_per_gK(1.0_f64).to_J_per_kgK(), 9
 		);
 		assert_approx_equal(
-			AngularAcceleration::from_radps2(1.0_f64).to_radps2() * 0.159154943091895,
-			AngularAcceleration::from_radps2(1.0_f64).to_rps2(), 9
+			SpecificHeatCapacity::from_J_per_kgK(1.0_f64).to_J_per_kgK() * 0.001,
+			SpecificHeatCapacity::from_J_per_kgK(1.0_f64).to_J_per_gK(), 9
 		);
+	}
+
+	#[test]
+	fn elastance_units() {
 		assert_approx_equal(
-			AngularAcceleration::from_radps2(0.0017453292519943_f64).to_radps2(),
-			AngularAcceleration::from_rpm2(1.0_f64).to_radps2(), 9
+			Elastance::from_per_F(1000.0_f64).to_per_F(),
+			Elastance::from_per_mF(1.0_f64).to_per_F(), 9
 		);
 		assert_approx_equal(
-			AngularAcceleration::from_radps2(1.0_f64).to_radps2() * 572.957795130823,
-			AngularAcceleration::from_radps2(1.0_f64).to_rpm2(), 9
+			Elastance::from_per_F(1.0_f64).to_per_F() * 0.001,
+			Elastance::from_per_F(1.0_f64).to_per_mF(), 9
 		);
 		assert_approx_equal(
-			AngularAcceleration::from_radps2(0.0174532925199433_f64).to_radps2(),
-			AngularAcceleration::from_degps2(1.0_f64).to_radps2(), 9
+			Elastance::from_per_F(1000000.0_f64).to_per_F(),
+			Elastance::from_per_uF(1.0_f64).to_per_F(), 9
 		);
 		assert_approx_equal(
-			AngularAcceleration::from_radps2(1.0_f64).to_radps2() * 57.2957795130823,
-			AngularAcceleration::from_radps2(1.0_f64).to_degps2(), 9
+			Elastance::from_per_F(1.0_f64).to_per_F() * 1e-06,
+			Elastance::from_per_F(1.0_f64).to_per_uF(), 9
 		);
 		assert_approx_equal(
-			AngularAcceleration::from_radps2(4.84813681109536e-07_f64).to_radps2(),
-			AngularAcceleration::from_rph2(1.0_f64).to_radps2(), 9
+			Elastance::from_per_F(1000000000.0_f64).to_per_F(),
+			Elastance::from_per_nF(1.0_f64).to_per_F(), 9
 		);
 		assert_approx_equal(
-			AngularAcceleration::from_radps2(1.0_f64).to_radps2() * 2062648.06247096,
-			AngularAcceleration::from_radps2(1.0_f64).to_rph2(), 9
+			Elastance::from_per_F(1.0_f64).to_per_F() * 1e-09,
+			Elastance::from_per_F(1.0_f64).to_per_nF(), 9
 		);
-	}
-
-	#[test]
-	fn angular_momentum_units() {
 		assert_approx_equal(
-			AngularMomentum::from_kgm2radps(1e-07_f64).to_kgm2radps(),
-			AngularMomentum::from_gcm2radps(1.0_f64).to_kgm2radps(), 9
+			Elastance::from_per_F(1000000000000.0_f64).to_per_F(),
+			Elastance::from_per_pF(1.0_f64).to_per_F(), 9
 		);
 		assert_approx_equal(
-			AngularMomentum::from_kgm2radps(1.0_f64).to_kgm2radps() * 10000000.0,
-			AngularMomentum::from_kgm2radps(1.0_f64).to_gcm2radps(), 9
+			Elastance::from_per_F(1.0_f64).to_per_F() * 1e-12,
+			Elastance::from_per_F(1.0_f64).to_per_pF(), 9
 		);
-	}
-
-	#[test]
-	fn angular_velocity_units() {
 		assert_approx_equal(
-			AngularVelocity::from_radps(0.0174532925199433_f64).to_radps(),
-			AngularVelocity::from_degrees_per_second(1.0_f64).to_radps(), 9
+			Elastance::from_per_F(0.001_f64).to_per_F(),
+			Elastance::from_per_kF(1.0_f64).to_per_F(), 9
 		);
 		assert_approx_equal(
-			AngularVelocity::from_radps(1.0_f64).to_radps() * 57.2957795130823,
-			AngularVelocity::from_radps(1.0_f64).to_degrees_per_second(), 9
+			Elastance::from_per_F(1.0_f64).to_per_F() * 1000.0,
+			Elastance::from_per_F(1.0_f64).to_per_kF(), 9
 		);
 		assert_approx_equal(
-			AngularVelocity::from_radps(0.0174532925199433_f64).to_radps(),
-			AngularVelocity::from_degps(1.0_f64).to_radps(), 9
+			Elastance::from_per_F(1e-06_f64).to_per_F(),
+			Elastance::from_per_MF(1.0_f64).to_per_F(), 9
 		);
 		assert_approx_equal(
-			AngularVelocity::from_radps(1.0_f64).to_radps() * 57.2957795130823,
-			AngularVelocity::from_radps(1.0_f64).to_degps(), 9
+			Elastance::from_per_F(1.0_f64).to_per_F() * 1000000.0,
+			Elastance::from_per_F(1.0_f64).to_per_MF(), 9
 		);
 		assert_approx_equal(
-			AngularVelocity::from_radps(6.28318530717959_f64).to_radps(),
-			AngularVelocity::from_rps(1.0_f64).to_radps(), 9
+			Elastance::from_per_F(1e-09_f64).to_per_F(),
+			Elastance::from_per_GF(1.0_f64).to_per_F(), 9
 		);
 		assert_approx_equal(
-			AngularVelocity::from_radps(1.0_f64).to_radps() * 0.159154943091895,
-			AngularVelocity::from_radps(1.0_f64).to_rps(), 9
+			Elastance::from_per_F(1.0_f64).to_per_F() * 1000000000.0,
+			Elastance::from_per_F(1.0_f64).to_per_GF(), 9
 		);
+	}
+
+	#[test]
+	fn inverse_charge_units() {
 		assert_approx_equal(
-			AngularVelocity::from_radps(0.10471975511966_f64).to_radps(),
-			AngularVelocity::from_rpm(1.0_f64).to_radps(), 9
+			InverseCharge::from_per_C(1000.0_f64).to_per_C(),
+			InverseCharge::from_per_mC(1.0_f64).to_per_C(), 9
 		);
 		assert_approx_equal(
-			AngularVelocity::from_radps(1.0_f64).to_radps() * 9.54929658551372,
-			AngularVelocity::from_radps(1.0_f64).to_rpm(), 9
+			InverseCharge::from_per_C(1.0_f64).to_per_C() * 0.001,
+			InverseCharge::from_per_C(1.0_f64).to_per_mC(), 9
 		);
 		assert_approx_equal(
-			AngularVelocity::from_radps(0.0017453292519943_f64).to_radps(),
-			AngularVelocity::from_rph(1.0_f64).to_radps(), 9
+			InverseCharge::from_per_C(1000000.0_f64).to_per_C(),
+			InverseCharge::from_per_uC(1.0_f64).to_per_C(), 9
 		);
 		assert_approx_equal(
-			AngularVelocity::from_radps(1.0_f64).to_radps() * 572.957795130823,
-			AngularVelocity::from_radps(1.0_f64).to_rph(), 9
+			InverseCharge::from_per_C(1.0_f64).to_per_C() * 1e-06,
+			InverseCharge::from_per_C(1.0_f64).to_per_uC(), 9
 		);
-	}
-
-	#[test]
-	fn area_density_units() {
 		assert_approx_equal(
-			AreaDensity::from_kgpm2(0.001_f64).to_kgpm2(),
-			AreaDensity::from_gpm2(1.0_f64).to_kgpm2(), 9
+			InverseCharge::from_per_C(1000000000.0_f64).to_per_C(),
+			InverseCharge::from_per_nC(1.0_f64).to_per_C(), 9
 		);
 		assert_approx_equal(
-			AreaDensity::from_kgpm2(1.0_f64).to_kgpm2() * 1000.0,
-			AreaDensity::from_kgpm2(1.0_f64).to_gpm2(), 9
+			InverseCharge::from_per_C(1.0_f64).to_per_C() * 1e-09,
+			InverseCharge::from_per_C(1.0_f64).to_per_nC(), 9
 		);
 		assert_approx_equal(
-			AreaDensity::from_kgpm2(0.001_f64).to_kgpm2(),
-			AreaDensity::from_grams_per_square_meter(1.0_f64).to_kgpm2(), 9
+			InverseCharge::from_per_C(0.001_f64).to_per_C(),
+			InverseCharge::from_per_kC(1.0_f64).to_per_C(), 9
 		);
 		assert_approx_equal(
-			AreaDensity::from_kgpm2(1.0_f64).to_kgpm2() * 1000.0,
-			AreaDensity::from_kgpm2(1.0_f64).to_grams_per_square_meter(), 9
+			InverseCharge::from_per_C(1.0_f64).to_per_C() * 1000.0,
+			InverseCharge::from_per_C(1.0_f64).to_per_kC(), 9
 		);
 		assert_approx_equal(
-			AreaDensity::from_kgpm2(10.0_f64).to_kgpm2(),
-			AreaDensity::from_gpcm2(1.0_f64).to_kgpm2(), 9
+			InverseCharge::from_per_C(1e-06_f64).to_per_C(),
+			InverseCharge::from_per_MC(1.0_f64).to_per_C(), 9
 		);
 		assert_approx_equal(
-			AreaDensity::from_kgpm2(1.0_f64).to_kgpm2() * 0.1,
-			AreaDensity::from_kgpm2(1.0_f64).to_gpcm2(), 9
+			InverseCharge::from_per_C(1.0_f64).to_per_C() * 1000000.0,
+			InverseCharge::from_per_C(1.0_f64).to_per_MC(), 9
 		);
 		assert_approx_equal(
-			AreaDensity::from_kgpm2(10.0_f64).to_kgpm2(),
-			AreaDensity::from_grams_per_square_cm(1.0_f64).to_kgpm2(), 9
+			InverseCharge::from_per_C(1e-09_f64).to_per_C(),
+			InverseCharge::from_per_GC(1.0_f64).to_per_C(), 9
 		);
 		assert_approx_equal(
-			AreaDensity::from_kgpm2(1.0_f64).to_kgpm2() * 0.1,
-			AreaDensity::from_kgpm2(1.0_f64).to_grams_per_square_cm(), 9
+			InverseCharge::from_per_C(1.0_f64).to_per_C() * 1000000000.0,
+			InverseCharge::from_per_C(1.0_f64).to_per_GC(), 9
 		);
 	}
 
 	#[test]
-	fn density_units() {
+	fn inverse_inductance_units() {
 		assert_approx_equal(
-			Density::from_kgpm3(1000.0_f64).to_kgpm3(),
-			Density::from_kgpL(1.0_f64).to_kgpm3(), 9
+			InverseInductance::from_per_H(1000.0_f64).to_per_H(),
+			InverseInductance::from_per_mH(1.0_f64).to_per_H(), 9
 		);
 		assert_approx_equal(
-			Density::from_kgpm3(1.0_f64).to_kgpm3() * 0.001,
-			Density::from_kgpm3(1.0_f64).to_kgpL(), 9
+			InverseInductance::from_per_H(1.0_f64).to_per_H() * 0.001,
+			InverseInductance::from_per_H(1.0_f64).to_per_mH(), 9
 		);
 		assert_approx_equal(
-			Density::from_kgpm3(1000.0_f64).to_kgpm3(),
-			Density::from_kilograms_per_liter(1.0_f64).to_kgpm3(), 9
+			InverseInductance::from_per_H(1000000.0_f64).to_per_H(),
+			InverseInductance::from_per_uH(1.0_f64).to_per_H(), 9
 		);
 		assert_approx_equal(
-			Density::from_kgpm3(1.0_f64).to_kgpm3() * 0.001,
-			Density::from_kgpm3(1.0_f64).to_kilograms_per_liter(), 9
+			InverseInductance::from_per_H(1.0_f64).to_per_H() * 1e-06,
+			InverseInductance::from_per_H(1.0_f64).to_per_uH(), 9
 		);
 		assert_approx_equal(
-			Density::from_kgpm3(1000.0_f64).to_kgpm3(),
-			Density::from_gpcc(1.0_f64).to_kgpm3(), 9
+			InverseInductance::from_per_H(1000000000.0_f64).to_per_H(),
+			InverseInductance::from_per_nH(1.0_f64).to_per_H(), 9
 		);
 		assert_approx_equal(
-			Density::from_kgpm3(1.0_f64).to_kgpm3() * 0.001,
-			Density::from_kgpm3(1.0_f64).to_gpcc(), 9
+			InverseInductance::from_per_H(1.0_f64).to_per_H() * 1e-09,
+			InverseInductance::from_per_H(1.0_f64).to_per_nH(), 9
 		);
 		assert_approx_equal(
-			Density::from_kgpm3(1000.0_f64).to_kgpm3(),
-			Density::from_grams_per_cubic_centimeter(1.0_f64).to_kgpm3(), 9
+			InverseInductance::from_per_H(0.001_f64).to_per_H(),
+			InverseInductance::from_per_kH(1.0_f64).to_per_H(), 9
 		);
 		assert_approx_equal(
-			Density::from_kgpm3(1.0_f64).to_kgpm3() * 0.001,
-			Density::from_kgpm3(1.0_f64).to_grams_per_cubic_centimeter(), 9
+			InverseInductance::from_per_H(1.0_f64).to_per_H() * 1000.0,
+			InverseInductance::from_per_H(1.0_f64).to_per_kH(), 9
 		);
 		assert_approx_equal(
-			Density::from_kgpm3(0.001_f64).to_kgpm3(),
-			Density::from_gpm3(1.0_f64).to_kgpm3(), 9
+			InverseInductance::from_per_H(1e-06_f64).to_per_H(),
+			InverseInductance::from_per_MH(1.0_f64).to_per_H(), 9
 		);
 		assert_approx_equal(
-			Density::from_kgpm3(1.0_f64).to_kgpm3() * 1000.0,
-			Density::from_kgpm3(1.0_f64).to_gpm3(), 9
+			InverseInductance::from_per_H(1.0_f64).to_per_H() * 1000000.0,
+			InverseInductance::from_per_H(1.0_f64).to_per_MH(), 9
+		);
+		assert_approx_equal(
+			InverseInductance::from_per_H(1e-09_f64).to_per_H(),
+			InverseInductance::from_per_GH(1.0_f64).to_per_H(), 9
+		);
+		assert_approx_equal(
+			InverseInductance::from_per_H(1.0_f64).to_per_H() * 1000000000.0,
+			InverseInductance::from_per_H(1.0_f64).to_per_GH(), 9
 		);
 	}
 
 	#[test]
-	fn energy_units() {
+	fn inverse_luminous_flux_units() {
 		assert_approx_equal(
-			Energy::from_J(0.001_f64).to_J(),
-			Energy::from_mJ(1.0_f64).to_J(), 9
+			InverseLuminousFlux::from_per_lm(1000.0_f64).to_per_lm(),
+			InverseLuminousFlux::from_per_mlm(1.0_f64).to_per_lm(), 9
 		);
 		assert_approx_equal(
-			Energy::from_J(1.0_f64).to_J() * 1000.0,
-			Energy::from_J(1.0_f64).to_mJ(), 9
+			InverseLuminousFlux::from_per_lm(1.0_f64).to_per_lm() * 0.001,
+			InverseLuminousFlux::from_per_lm(1.0_f64).to_per_mlm(), 9
 		);
 		assert_approx_equal(
-			Energy::from_J(1e-06_f64).to_J(),
-			Energy::from_uJ(1.0_f64).to_J(), 9
+			InverseLuminousFlux::from_per_lm(1000000.0_f64).to_per_lm(),
+			InverseLuminousFlux::from_per_ulm(1.0_f64).to_per_lm(), 9
 		);
 		assert_approx_equal(
-			Energy::from_J(1.0_f64).to_J() * 1000000.0,
-			Energy::from_J(1.0_f64).to_uJ(), 9
+			InverseLuminousFlux::from_per_lm(1.0_f64).to_per_lm() * 1e-06,
+			InverseLuminousFlux::from_per_lm(1.0_f64).to_per_ulm(), 9
 		);
 		assert_approx_equal(
-			Energy::from_J(1e-09_f64).to_J(),
-			Energy::from_nJ(1.0_f64).to_J(), 9
+			InverseLuminousFlux::from_per_lm(1000000000.0_f64).to_per_lm(),
+			InverseLuminousFlux::from_per_nlm(1.0_f64).to_per_lm(), 9
 		);
 		assert_approx_equal(
-			Energy::from_J(1.0_f64).to_J() * 1000000000.0,
-			Energy::from_J(1.0_f64).to_nJ(), 9
+			InverseLuminousFlux::from_per_lm(1.0_f64).to_per_lm() * 1e-09,
+			InverseLuminousFlux::from_per_lm(1.0_f64).to_per_nlm(), 9
 		);
 		assert_approx_equal(
-			Energy::from_J(1000.0_f64).to_J(),
-			Energy::from_kJ(1.0_f64).to_J(), 9
+			InverseLuminousFlux::from_per_lm(0.001_f64).to_per_lm(),
+			InverseLuminousFlux::from_per_klm(1.0_f64).to_per_lm(), 9
 		);
 		assert_approx_equal(
-			Energy::from_J(1.0_f64).to_J() * 0.001,
-			Energy::from_J(1.0_f64).to_kJ(), 9
+			InverseLuminousFlux::from_per_lm(1.0_f64).to_per_lm() * 1000.0,
+			InverseLuminousFlux::from_per_lm(1.0_f64).to_per_klm(), 9
 		);
 		assert_approx_equal(
-			Energy::from_J(1000000.0_f64).to_J(),
-			Energy::from_MJ(1.0_f64).to_J(), 9
+			InverseLuminousFlux::from_per_lm(1e-06_f64).to_per_lm(),
+			InverseLuminousFlux::from_per_Mlm(1.0_f64).to_per_lm(), 9
 		);
 		assert_approx_equal(
-			Energy::from_J(1.0_f64).to_J() * 1e-06,
-			Energy::from_J(1.0_f64).to_MJ(), 9
+			InverseLuminousFlux::from_per_lm(1.0_f64).to_per_lm() * 1000000.0,
+			InverseLuminousFlux::from_per_lm(1.0_f64).to_per_Mlm(), 9
 		);
 		assert_approx_equal(
-			Energy::from_J(1000000000.0_f64).to_J(),
-			Energy::from_GJ(1.0_f64).to_J(), 9
+			InverseLuminousFlux::from_per_lm(1e-09_f64).to_per_lm(),
+			InverseLuminousFlux::from_per_Glm(1.0_f64).to_per_lm(), 9
 		);
 		assert_approx_equal(
-			Energy::from_J(1.0_f64).to_J() * 1e-09,
-			Energy::from_J(1.0_f64).to_GJ(), 9
+			InverseLuminousFlux::from_per_lm(1.0_f64).to_per_lm() * 1000000000.0,
+			InverseLuminousFlux::from_per_lm(1.0_f64).to_per_Glm(), 9
 		);
+	}
+
+	#[test]
+	fn inverse_magnetic_flux_units() {
 		assert_approx_equal(
-			Energy::from_J(4.184_f64).to_J(),
-			Energy::from_cal(1.0_f64).to_J(), 9
+			InverseMagneticFlux::from_per_Wb(1000.0_f64).to_per_Wb(),
+			InverseMagneticFlux::from_per_mWb(1.0_f64).to_per_Wb(), 9
 		);
 		assert_approx_equal(
-			Energy::from_J(1.0_f64).to_J() * 0.239005736137667,
-			Energy::from_J(1.0_f64).to_cal(), 9
+			InverseMagneticFlux::from_per_Wb(1.0_f64).to_per_Wb() * 0.001,
+			InverseMagneticFlux::from_per_Wb(1.0_f64).to_per_mWb(), 9
 		);
 		assert_approx_equal(
-			Energy::from_J(4184.0_f64).to_J(),
-			Energy::from_kcal(1.0_f64).to_J(), 9
+			InverseMagneticFlux::from_per_Wb(1000000.0_f64).to_per_Wb(),
+			InverseMagneticFlux::from_per_uWb(1.0_f64).to_per_Wb(), 9
 		);
 		assert_approx_equal(
-			Energy::from_J(1.0_f64).to_J() * 0.0002390057361376,
-			Energy::from_J(1.0_f64).to_kcal(), 9
+			InverseMagneticFlux::from_per_Wb(1.0_f64).to_per_Wb() * 1e-06,
+			InverseMagneticFlux::from_per_Wb(1.0_f64).to_per_uWb(), 9
 		);
 		assert_approx_equal(
-			Energy::from_J(3600.0_f64).to_J(),
-			Energy::from_Whr(1.0_f64).to_J(), 9
+			InverseMagneticFlux::from_per_Wb(1000000000.0_f64).to_per_Wb(),
+			InverseMagneticFlux::from_per_nWb(1.0_f64).to_per_Wb(), 9
 		);
 		assert_approx_equal(
-			Energy::from_J(1.0_f64).to_J() * 0.0002777777777777,
-			Energy::from_J(1.0_f64).to_Whr(), 9
+			InverseMagneticFlux::from_per_Wb(1.0_f64).to_per_Wb() * 1e-09,
+			InverseMagneticFlux::from_per_Wb(1.0_f64).to_per_nWb(), 9
 		);
 		assert_approx_equal(
-			Energy::from_J(3600000.0_f64).to_J(),
-			Energy::from_kWhr(1.0_f64).to_J(), 9
+			InverseMagneticFlux::from_per_Wb(0.001_f64).to_per_Wb(),
+			InverseMagneticFlux::from_per_kWb(1.0_f64).to_per_Wb(), 9
 		);
 		assert_approx_equal(
-			Energy::from_J(1.0_f64).to_J() * 2.77777777777778e-07,
-			Energy::from_J(1.0_f64).to_kWhr(), 9
+			InverseMagneticFlux::from_per_Wb(1.0_f64).to_per_Wb() * 1000.0,
+			InverseMagneticFlux::from_per_Wb(1.0_f64).to_per_kWb(), 9
 		);
 		assert_approx_equal(
-			Energy::from_J(1.6021766340000001e-19_f64).to_J(),
-			Energy::from_eV(1.0_f64).to_J(), 9
+			InverseMagneticFlux::from_per_Wb(1e-06_f64).to_per_Wb(),
+			InverseMagneticFlux::from_per_MWb(1.0_f64).to_per_Wb(), 9
 		);
 		assert_approx_equal(
-			Energy::from_J(1.0_f64).to_J() * 6.24150907446076e+18,
-			Energy::from_J(1.0_f64).to_eV(), 9
+			InverseMagneticFlux::from_per_Wb(1.0_f64).to_per_Wb() * 1000000.0,
+			InverseMagneticFlux::from_per_Wb(1.0_f64).to_per_MWb(), 9
 		);
 		assert_approx_equal(
-			Energy::from_J(1055.0_f64).to_J(),
-			Energy::from_BTU(1.0_f64).to_J(), 9
+			InverseMagneticFlux::from_per_Wb(1e-09_f64).to_per_Wb(),
+			InverseMagneticFlux::from_per_GWb(1.0_f64).to_per_Wb(), 9
 		);
 		assert_approx_equal(
-			Energy::from_J(1.0_f64).to_J() * 0.0009478672985781,
-			Energy::from_J(1.0_f64).to_BTU(), 9
+			InverseMagneticFlux::from_per_Wb(1.0_f64).to_per_Wb() * 1000000000.0,
+			InverseMagneticFlux::from_per_Wb(1.0_f64).to_per_GWb(), 9
 		);
 	}
 
 	#[test]
-	fn force_units() {
+	fn inverse_voltage_units() {
 		assert_approx_equal(
-			Force::from_N(4.45756819483586_f64).to_N(),
-			Force::from_lb(1.0_f64).to_N(), 9
+			InverseVoltage::from_per_V(1000.0_f64).to_per_V(),
+			InverseVoltage::from_per_mV(1.0_f64).to_per_V(), 9
 		);
 		assert_approx_equal(
-			Force::from_N(1.0_f64).to_N() * 0.224337566199999,
-			Force::from_N(1.0_f64).to_lb(), 9
+			InverseVoltage::from_per_V(1.0_f64).to_per_V() * 0.001,
+			InverseVoltage::from_per_V(1.0_f64).to_per_mV(), 9
 		);
 		assert_approx_equal(
-			Force::from_N(9.8066500286389_f64).to_N(),
-			Force::from_kgG(1.0_f64).to_N(), 9
+			InverseVoltage::from_per_V(1000000.0_f64).to_per_V(),
+			InverseVoltage::from_per_uV(1.0_f64).to_per_V(), 9
 		);
 		assert_approx_equal(
-			Force::from_N(1.0_f64).to_N() * 0.101971620999999,
-			Force::from_N(1.0_f64).to_kgG(), 9
+			InverseVoltage::from_per_V(1.0_f64).to_per_V() * 1e-06,
+			InverseVoltage::from_per_V(1.0_f64).to_per_uV(), 9
 		);
 		assert_approx_equal(
-			Force::from_N(0.001_f64).to_N(),
-			Force::from_mN(1.0_f64).to_N(), 9
+			InverseVoltage::from_per_V(1000000000.0_f64).to_per_V(),
+			InverseVoltage::from_per_nV(1.0_f64).to_per_V(), 9
 		);
 		assert_approx_equal(
-			Force::from_N(1.0_f64).to_N() * 1000.0,
-			Force::from_N(1.0_f64).to_mN(), 9
+			InverseVoltage::from_per_V(1.0_f64).to_per_V() * 1e-09,
+			InverseVoltage::from_per_V(1.0_f64).to_per_nV(), 9
 		);
 		assert_approx_equal(
-			Force::from_N(1e-06_f64).to_N(),
-			Force::from_uN(1.0_f64).to_N(), 9
+			InverseVoltage::from_per_V(0.001_f64).to_per_V(),
+			InverseVoltage::from_per_kV(1.0_f64).to_per_V(), 9
 		);
 		assert_approx_equal(
-			Force::from_N(1.0_f64).to_N() * 1000000.0,
-			Force::from_N(1.0_f64).to_uN(), 9
+			InverseVoltage::from_per_V(1.0_f64).to_per_V() * 1000.0,
+			InverseVoltage::from_per_V(1.0_f64).to_per_kV(), 9
 		);
 		assert_approx_equal(
-			Force::from_N(1e-09_f64).to_N(),
-			Force::from_nN(1.0_f64).to_N(), 9
+			InverseVoltage::from_per_V(1e-06_f64).to_per_V(),
+			InverseVoltage::from_per_MV(1.0_f64).to_per_V(), 9
 		);
 		assert_approx_equal(
-			Force::from_N(1.0_f64).to_N() * 1000000000.0,
-			Force::from_N(1.0_f64).to_nN(), 9
+			InverseVoltage::from_per_V(1.0_f64).to_per_V() * 1000000.0,
+			InverseVoltage::from_per_V(1.0_f64).to_per_MV(), 9
 		);
 		assert_approx_equal(
-			Force::from_N(1000.0_f64).to_N(),
-			Force::from_kN(1.0_f64).to_N(), 9
+			InverseVoltage::from_per_V(1e-09_f64).to_per_V(),
+			InverseVoltage::from_per_GV(1.0_f64).to_per_V(), 9
 		);
 		assert_approx_equal(
-			Force::from_N(1.0_f64).to_N() * 0.001,
-			Force::from_N(1.0_f64).to_kN(), 9
+			InverseVoltage::from_per_V(1.0_f64).to_per_V() * 1000000000.0,
+			InverseVoltage::from_per_V(1.0_f64).to_per_GV(), 9
 		);
+	}
+
+	#[test]
+	fn inverse_angle_units() {
 		assert_approx_equal(
-			Force::from_N(1000000.0_f64).to_N(),
-			Force::from_MN(1.0_f64).to_N(), 9
+			InverseAngle::from_per_rad(57.29577951308232_f64).to_per_rad(),
+			InverseAngle::from_per_degrees(1.0_f64).to_per_rad(), 9
 		);
 		assert_approx_equal(
-			Force::from_N(1.0_f64).to_N() * 1e-06,
-			Force::from_N(1.0_f64).to_MN(), 9
+			InverseAngle::from_per_rad(1.0_f64).to_per_rad() * 0.017453292519943295,
+			InverseAngle::from_per_rad(1.0_f64).to_per_degrees(), 9
 		);
 		assert_approx_equal(
-			Force::from_N(1000000000.0_f64).to_N(),
-			Force::from_GN(1.0_f64).to_N(), 9
+			InverseAngle::from_per_rad(57.29577951308232_f64).to_per_rad(),
+			InverseAngle::from_per_deg(1.0_f64).to_per_rad(), 9
 		);
 		assert_approx_equal(
-			Force::from_N(1.0_f64).to_N() * 1e-09,
-			Force::from_N(1.0_f64).to_GN(), 9
+			InverseAngle::from_per_rad(1.0_f64).to_per_rad() * 0.017453292519943295,
+			InverseAngle::from_per_rad(1.0_f64).to_per_deg(), 9
 		);
 	}
 
 	#[test]
-	fn frequency_units() {
+	fn inverse_area_units() {
 		assert_approx_equal(
-			Frequency::from_Hz(1000.0_f64).to_Hz(),
-			Frequency::from_kHz(1.0_f64).to_Hz(), 9
+			InverseArea::from_per_m2(10000.0_f64).to_per_m2(),
+			InverseArea::from_per_cm2(1.0_f64).to_per_m2(), 9
 		);
 		assert_approx_equal(
-			Frequency::from_Hz(1.0_f64).to_Hz() * 0.001,
-			Frequency::from_Hz(1.0_f64).to_kHz(), 9
+			InverseArea::from_per_m2(1.0_f64).to_per_m2() * 0.0001,
+			InverseArea::from_per_m2(1.0_f64).to_per_cm2(), 9
 		);
 		assert_approx_equal(
-			Frequency::from_Hz(1000000.0_f64).to_Hz(),
-			Frequency::from_MHz(1.0_f64).to_Hz(), 9
+			InverseArea::from_per_m2(10000.0_f64).to_per_m2(),
+			InverseArea::from_per_square_cm(1.0_f64).to_per_m2(), 9
 		);
 		assert_approx_equal(
-			Frequency::from_Hz(1.0_f64).to_Hz() * 1e-06,
-			Frequency::from_Hz(1.0_f64).to_MHz(), 9
+			InverseArea::from_per_m2(1.0_f64).to_per_m2() * 0.0001,
+			InverseArea::from_per_m2(1.0_f64).to_per_square_cm(), 9
 		);
 		assert_approx_equal(
-			Frequency::from_Hz(1000000000.0_f64).to_Hz(),
-			Frequency::from_GHz(1.0_f64).to_Hz(), 9
+			InverseArea::from_per_m2(1000000.0_f64).to_per_m2(),
+			InverseArea::from_per_mm2(1.0_f64).to_per_m2(), 9
 		);
 		assert_approx_equal(
-			Frequency::from_Hz(1.0_f64).to_Hz() * 1e-09,
-			Frequency::from_Hz(1.0_f64).to_GHz(), 9
+			InverseArea::from_per_m2(1.0_f64).to_per_m2() * 1e-06,
+			InverseArea::from_per_m2(1.0_f64).to_per_mm2(), 9
 		);
 		assert_approx_equal(
-			Frequency::from_Hz(1000000000000.0_f64).to_Hz(),
-			Frequency::from_THz(1.0_f64).to_Hz(), 9
+			InverseArea::from_per_m2(1000000000000.0_f64).to_per_m2(),
+			InverseArea::from_per_um2(1.0_f64).to_per_m2(), 9
 		);
 		assert_approx_equal(
-			Frequency::from_Hz(1.0_f64).to_Hz() * 1e-12,
-			Frequency::from_Hz(1.0_f64).to_THz(), 9
+			InverseArea::from_per_m2(1.0_f64).to_per_m2() * 1e-12,
+			InverseArea::from_per_m2(1.0_f64).to_per_um2(), 9
 		);
-	}
-
-	#[test]
-	fn moment_of_inertia_units() {
 		assert_approx_equal(
-			MomentOfInertia::from_kgm2(10.0_f64).to_kgm2(),
-			MomentOfInertia::from_gcm2(1.0_f64).to_kgm2(), 9
+			InverseArea::from_per_m2(1e+18_f64).to_per_m2(),
+			InverseArea::from_per_nm2(1.0_f64).to_per_m2(), 9
 		);
 		assert_approx_equal(
-			MomentOfInertia::from_kgm2(1.0_f64).to_kgm2() * 0.1,
-			MomentOfInertia::from_kgm2(1.0_f64).to_gcm2(), 9
+			InverseArea::from_per_m2(1.0_f64).to_per_m2() * 1e-18,
+			InverseArea::from_per_m2(1.0_f64).to_per_nm2(), 9
 		);
 		assert_approx_equal(
-			MomentOfInertia::from_kgm2(0.001_f64).to_kgm2(),
-			MomentOfInertia::from_gm2(1.0_f64).to_kgm2(), 9
+			InverseArea::from_per_m2(1e-06_f64).to_per_m2(),
+			InverseArea::from_per_km2(1.0_f64).to_per_m2(), 9
 		);
 		assert_approx_equal(
-			MomentOfInertia::from_kgm2(1.0_f64).to_kgm2() * 1000.0,
-			MomentOfInertia::from_kgm2(1.0_f64).to_gm2(), 9
+			InverseArea::from_per_m2(1.0_f64).to_per_m2() * 1000000.0,
+			InverseArea::from_per_m2(1.0_f64).to_per_km2(), 9
 		);
 	}
 
 	#[test]
-	fn momentum_units() {
+	fn inverse_volume_units() {
 		assert_approx_equal(
-			Momentum::from_kgmps(1e-05_f64).to_kgmps(),
-			Momentum::from_gram_centimeters_per_second(1.0_f64).to_kgmps(), 9
+			InverseVolume::from_per_m3(1000000.0_f64).to_per_m3(),
+			InverseVolume::from_per_cc(1.0_f64).to_per_m3(), 9
 		);
 		assert_approx_equal(
-			Momentum::from_kgmps(1.0_f64).to_kgmps() * 100000.0,
-			Momentum::from_kgmps(1.0_f64).to_gram_centimeters_per_second(), 9
+			InverseVolume::from_per_m3(1.0_f64).to_per_m3() * 1e-06,
+			InverseVolume::from_per_m3(1.0_f64).to_per_cc(), 9
 		);
 		assert_approx_equal(
-			Momentum::from_kgmps(1e-05_f64).to_kgmps(),
-			Momentum::from_gcmps(1.0_f64).to_kgmps(), 9
+			InverseVolume::from_per_m3(1000.0_f64).to_per_m3(),
+			InverseVolume::from_per_L(1.0_f64).to_per_m3(), 9
 		);
 		assert_approx_equal(
-			Momentum::from_kgmps(1.0_f64).to_kgmps() * 100000.0,
-			Momentum::from_kgmps(1.0_f64).to_gcmps(), 9
+			InverseVolume::from_per_m3(1.0_f64).to_per_m3() * 0.001,
+			InverseVolume::from_per_m3(1.0_f64).to_per_L(), 9
 		);
-	}
-
-	#[test]
-	fn power_units() {
 		assert_approx_equal(
-			Power::from_W(0.001_f64).to_W(),
-			Power::from_mW(1.0_f64).to_W(), 9
+			InverseVolume::from_per_m3(1000.0_f64).to_per_m3(),
+			InverseVolume::from_per_liters(1.0_f64).to_per_m3(), 9
 		);
 		assert_approx_equal(
-			Power::from_W(1.0_f64).to_W() * 1000.0,
-			Power::from_W(1.0_f64).to_mW(), 9
+			InverseVolume::from_per_m3(1.0_f64).to_per_m3() * 0.001,
+			InverseVolume::from_per_m3(1.0_f64).to_per_liters(), 9
 		);
 		assert_approx_equal(
-			Power::from_W(1e-06_f64).to_W(),
-			Power::from_uW(1.0_f64).to_W(), 9
+			InverseVolume::from_per_m3(1000000.0_f64).to_per_m3(),
+			InverseVolume::from_per_mL(1.0_f64).to_per_m3(), 9
 		);
 		assert_approx_equal(
-			Power::from_W(1.0_f64).to_W() * 1000000.0,
-			Power::from_W(1.0_f64).to_uW(), 9
+			InverseVolume::from_per_m3(1.0_f64).to_per_m3() * 1e-06,
+			InverseVolume::from_per_m3(1.0_f64).to_per_mL(), 9
 		);
 		assert_approx_equal(
-			Power::from_W(1e-09_f64).to_W(),
-			Power::from_nW(1.0_f64).to_W(), 9
+			InverseVolume::from_per_m3(1000000000.0_f64).to_per_m3(),
+			InverseVolume::from_per_uL(1.0_f64).to_per_m3(), 9
 		);
 		assert_approx_equal(
-			Power::from_W(1.0_f64).to_W() * 1000000000.0,
-			Power::from_W(1.0_f64).to_nW(), 9
+			InverseVolume::from_per_m3(1.0_f64).to_per_m3() * 1e-09,
+			InverseVolume::from_per_m3(1.0_f64).to_per_uL(), 9
 		);
 		assert_approx_equal(
-			Power::from_W(1000.0_f64).to_W(),
-			Power::from_kW(1.0_f64).to_W(), 9
+			InverseVolume::from_per_m3(1000000000000.0_f64).to_per_m3(),
+			InverseVolume::from_per_nL(1.0_f64).to_per_m3(), 9
 		);
 		assert_approx_equal(
-			Power::from_W(1.0_f64).to_W() * 0.001,
-			Power::from_W(1.0_f64).to_kW(), 9
+			InverseVolume::from_per_m3(1.0_f64).to_per_m3() * 1e-12,
+			InverseVolume::from_per_m3(1.0_f64).to_per_nL(), 9
 		);
 		assert_approx_equal(
-			Power::from_W(1000000.0_f64).to_W(),
-			Power::from_MW(1.0_f64).to_W(), 9
+			InverseVolume::from_per_m3(1000000000000000.0_f64).to_per_m3(),
+			InverseVolume::from_per_pL(1.0_f64).to_per_m3(), 9
 		);
 		assert_approx_equal(
-			Power::from_W(1.0_f64).to_W() * 1e-06,
-			Power::from_W(1.0_f64).to_MW(), 9
+			InverseVolume::from_per_m3(1.0_f64).to_per_m3() * 1e-15,
+			InverseVolume::from_per_m3(1.0_f64).to_per_pL(), 9
 		);
 		assert_approx_equal(
-			Power::from_W(1000000000.0_f64).to_W(),
-			Power::from_GW(1.0_f64).to_W(), 9
+			InverseVolume::from_per_m3(0.001_f64).to_per_m3(),
+			InverseVolume::from_per_ML(1.0_f64).to_per_m3(), 9
 		);
 		assert_approx_equal(
-			Power::from_W(1.0_f64).to_W() * 1e-09,
-			Power::from_W(1.0_f64).to_GW(), 9
+			InverseVolume::from_per_m3(1.0_f64).to_per_m3() * 1000.0,
+			InverseVolume::from_per_m3(1.0_f64).to_per_ML(), 9
 		);
 		assert_approx_equal(
-			Power::from_W(745.7_f64).to_W(),
-			Power::from_horsepower(1.0_f64).to_W(), 9
+			InverseVolume::from_per_m3(1e-06_f64).to_per_m3(),
+			InverseVolume::from_per_GL(1.0_f64).to_per_m3(), 9
 		);
 		assert_approx_equal(
-			Power::from_W(1.0_f64).to_W() * 0.0013410218586563,
-			Power::from_W(1.0_f64).to_horsepower(), 9
+			InverseVolume::from_per_m3(1.0_f64).to_per_m3() * 1000000.0,
+			InverseVolume::from_per_m3(1.0_f64).to_per_GL(), 9
 		);
 	}
 
 	#[test]
-	fn pressure_units() {
+	fn area_per_mass_units() {
 		assert_approx_equal(
-			Pressure::from_Pa(6894.7572931783_f64).to_Pa(),
-			Pressure::from_psi(1.0_f64).to_Pa(), 9
+			AreaPerMass::from_m2_per_kg(1000.0_f64).to_m2_per_kg(),
+			AreaPerMass::from_m2_per_g(1.0_f64).to_m2_per_kg(), 9
 		);
 		assert_approx_equal(
-			Pressure::from_Pa(1.0_f64).to_Pa() * 0.00014503773773,
-			Pressure::from_Pa(1.0_f64).to_psi(), 9
+			AreaPerMass::from_m2_per_kg(1.0_f64).to_m2_per_kg() * 0.001,
+			AreaPerMass::from_m2_per_kg(1.0_f64).to_m2_per_g(), 9
 		);
 		assert_approx_equal(
-			Pressure::from_Pa(0.001_f64).to_Pa(),
-			Pressure::from_mPa(1.0_f64).to_Pa(), 9
+			AreaPerMass::from_m2_per_kg(1000.0_f64).to_m2_per_kg(),
+			AreaPerMass::from_square_meters_per_gram(1.0_f64).to_m2_per_kg(), 9
 		);
 		assert_approx_equal(
-			Pressure::from_Pa(1.0_f64).to_Pa() * 1000.0,
-			Pressure::from_Pa(1.0_f64).to_mPa(), 9
+			AreaPerMass::from_m2_per_kg(1.0_f64).to_m2_per_kg() * 0.001,
+			AreaPerMass::from_m2_per_kg(1.0_f64).to_square_meters_per_gram(), 9
 		);
 		assert_approx_equal(
-			Pressure::from_Pa(1e-06_f64).to_Pa(),
-			Pressure::from_uPa(1.0_f64).to_Pa(), 9
+			AreaPerMass::from_m2_per_kg(0.1_f64).to_m2_per_kg(),
+			AreaPerMass::from_cm2_per_g(1.0_f64).to_m2_per_kg(), 9
 		);
 		assert_approx_equal(
-			Pressure::from_Pa(1.0_f64).to_Pa() * 1000000.0,
-			Pressure::from_Pa(1.0_f64).to_uPa(), 9
+			AreaPerMass::from_m2_per_kg(1.0_f64).to_m2_per_kg() * 10.0,
+			AreaPerMass::from_m2_per_kg(1.0_f64).to_cm2_per_g(), 9
 		);
 		assert_approx_equal(
-			Pressure::from_Pa(1e-09_f64).to_Pa(),
-			Pressure::from_nPa(1.0_f64).to_Pa(), 9
+			AreaPerMass::from_m2_per_kg(0.1_f64).to_m2_per_kg(),
+			AreaPerMass::from_square_centimeters_per_gram(1.0_f64).to_m2_per_kg(), 9
 		);
 		assert_approx_equal(
-			Pressure::from_Pa(1.0_f64).to_Pa() * 1000000000.0,
-			Pressure::from_Pa(1.0_f64).to_nPa(), 9
+			AreaPerMass::from_m2_per_kg(1.0_f64).to_m2_per_kg() * 10.0,
+			AreaPerMass::from_m2_per_kg(1.0_f64).to_square_centimeters_per_gram(), 9
 		);
+	}
+
+	#[test]
+	fn inverse_acceleration_units() {
 		assert_approx_equal(
-			Pressure::from_Pa(1000.0_f64).to_Pa(),
-			Pressure::from_kPa(1.0_f64).to_Pa(), 9
+			InverseAcceleration::from_s2pm(1000.0_f64).to_s2pm(),
+			InverseAcceleration::from_s2pmm(1.0_f64).to_s2pm(), 9
 		);
 		assert_approx_equal(
-			Pressure::from_Pa(1.0_f64).to_Pa() * 0.001,
-			Pressure::from_Pa(1.0_f64).to_kPa(), 9
+			InverseAcceleration::from_s2pm(1.0_f64).to_s2pm() * 0.001,
+			InverseAcceleration::from_s2pm(1.0_f64).to_s2pmm(), 9
 		);
 		assert_approx_equal(
-			Pressure::from_Pa(1000000.0_f64).to_Pa(),
-			Pressure::from_MPa(1.0_f64).to_Pa(), 9
+			InverseAcceleration::from_s2pm(1000000.0_f64).to_s2pm(),
+			InverseAcceleration::from_hours_squared_per_kilometers(1.0_f64).to_s2pm(), 9
 		);
 		assert_approx_equal(
-			Pressure::from_Pa(1.0_f64).to_Pa() * 1e-06,
-			Pressure::from_Pa(1.0_f64).to_MPa(), 9
+			InverseAcceleration::from_s2pm(1.0_f64).to_s2pm() * 1e-06,
+			InverseAcceleration::from_s2pm(1.0_f64).to_hours_squared_per_kilometers(), 9
 		);
 		assert_approx_equal(
-			Pressure::from_Pa(1000000000.0_f64).to_Pa(),
-			Pressure::from_GPa(1.0_f64).to_Pa(), 9
+			InverseAcceleration::from_s2pm(12960.0_f64).to_s2pm(),
+			InverseAcceleration::from_hr2_per_km(1.0_f64).to_s2pm(), 9
 		);
 		assert_approx_equal(
-			Pressure::from_Pa(1.0_f64).to_Pa() * 1e-09,
-			Pressure::from_Pa(1.0_f64).to_GPa(), 9
+			InverseAcceleration::from_s2pm(1.0_f64).to_s2pm() * 7.72e-05,
+			InverseAcceleration::from_s2pm(1.0_f64).to_hr2_per_km(), 9
 		);
+	}
+
+	#[test]
+	fn inverse_angular_acceleration_units() {
 		assert_approx_equal(
-			Pressure::from_Pa(100.0_f64).to_Pa(),
-			Pressure::from_hPa(1.0_f64).to_Pa(), 9
+			InverseAngularAcceleration::from_s2prad(57.2957795130823_f64).to_s2prad(),
+			InverseAngularAcceleration::from_seconds_squared_per_degree(1.0_f64).to_s2prad(), 9
 		);
 		assert_approx_equal(
-			Pressure::from_Pa(1.0_f64).to_Pa() * 0.01,
-			Pressure::from_Pa(1.0_f64).to_hPa(), 9
+			InverseAngularAcceleration::from_s2prad(1.0_f64).to_s2prad() * 0.0174532925199433,
+			InverseAngularAcceleration::from_s2prad(1.0_f64).to_seconds_squared_per_degree(), 9
 		);
+	}
+
+	#[test]
+	fn inverse_angular_momentum_units() {
 		assert_approx_equal(
-			Pressure::from_Pa(100000.0_f64).to_Pa(),
-			Pressure::from_bar(1.0_f64).to_Pa(), 9
+			InverseAngularMomentum::from_s_per_kgm2rad(10000000.0_f64).to_s_per_kgm2rad(),
+			InverseAngularMomentum::from_s_per_gcm2rad(1.0_f64).to_s_per_kgm2rad(), 9
 		);
 		assert_approx_equal(
-			Pressure::from_Pa(1.0_f64).to_Pa() * 1e-05,
-			Pressure::from_Pa(1.0_f64).to_bar(), 9
+			InverseAngularMomentum::from_s_per_kgm2rad(1.0_f64).to_s_per_kgm2rad() * 1e-07,
+			InverseAngularMomentum::from_s_per_kgm2rad(1.0_f64).to_s_per_gcm2rad(), 9
 		);
+	}
+
+	#[test]
+	fn inverse_angular_velocity_units() {
 		assert_approx_equal(
-			Pressure::from_Pa(100.0_f64).to_Pa(),
-			Pressure::from_mbar(1.0_f64).to_Pa(), 9
+			InverseAngularVelocity::from_s_per_rad(57.2957795130823_f64).to_s_per_rad(),
+			InverseAngularVelocity::from_seconds_per_degree(1.0_f64).to_s_per_rad(), 9
 		);
 		assert_approx_equal(
-			Pressure::from_Pa(1.0_f64).to_Pa() * 0.01,
-			Pressure::from_Pa(1.0_f64).to_mbar(), 9
+			InverseAngularVelocity::from_s_per_rad(1.0_f64).to_s_per_rad() * 0.0174532925199433,
+			InverseAngularVelocity::from_s_per_rad(1.0_f64).to_seconds_per_degree(), 9
 		);
 		assert_approx_equal(
-			Pressure::from_Pa(101325.0_f64).to_Pa(),
-			Pressure::from_atm(1.0_f64).to_Pa(), 9
+			InverseAngularVelocity::from_s_per_rad(57.2957795130823_f64).to_s_per_rad(),
+			InverseAngularVelocity::from_s_per_deg(1.0_f64).to_s_per_rad(), 9
 		);
 		assert_approx_equal(
-			Pressure::from_Pa(1.0_f64).to_Pa() * 9.86923266716013e-06,
-			Pressure::from_Pa(1.0_f64).to_atm(), 9
+			InverseAngularVelocity::from_s_per_rad(1.0_f64).to_s_per_rad() * 0.0174532925199433,
+			InverseAngularVelocity::from_s_per_rad(1.0_f64).to_s_per_deg(), 9
 		);
 		assert_approx_equal(
-			Pressure::from_Pa(133.3223684211_f64).to_Pa(),
-			Pressure::from_torr(1.0_f64).to_Pa(), 9
+			InverseAngularVelocity::from_s_per_rad(0.159154943091895_f64).to_s_per_rad(),
+			InverseAngularVelocity::from_spr(1.0_f64).to_s_per_rad(), 9
 		);
 		assert_approx_equal(
-			Pressure::from_Pa(1.0_f64).to_Pa() * 0.007500616827039,
-			Pressure::from_Pa(1.0_f64).to_torr(), 9
+			InverseAngularVelocity::from_s_per_rad(1.0_f64).to_s_per_rad() * 6.28318530717959,
+			InverseAngularVelocity::from_s_per_rad(1.0_f64).to_spr(), 9
 		);
 		assert_approx_equal(
-			Pressure::from_Pa(133.3223684211_f64).to_Pa(),
-			Pressure::from_mmHg(1.0_f64).to_Pa(), 9
+			InverseAngularVelocity::from_s_per_rad(9.54929658551372_f64).to_s_per_rad(),
+			InverseAngularVelocity::from_mpr(1.0_f64).to_s_per_rad(), 9
 		);
 		assert_approx_equal(
-			Pressure::from_Pa(1.0_f64).to_Pa() * 0.007500616827039,
-			Pressure::from_Pa(1.0_f64).to_mmHg(), 9
+			InverseAngularVelocity::from_s_per_rad(1.0_f64).to_s_per_rad() * 0.10471975511966,
+			InverseAngularVelocity::from_s_per_rad(1.0_f64).to_mpr(), 9
 		);
-	}
-
-	#[test]
-	fn torque_units() {
 		assert_approx_equal(
-			Torque::from_Nm(1.35581794833139_f64).to_Nm(),
-			Torque::from_ftlb(1.0_f64).to_Nm(), 9
+			InverseAngularVelocity::from_s_per_rad(572.957795130823_f64).to_s_per_rad(),
+			InverseAngularVelocity::from_hpr(1.0_f64).to_s_per_rad(), 9
 		);
 		assert_approx_equal(
-			Torque::from_Nm(1.0_f64).to_Nm() * 0.73756214927727,
-			Torque::from_Nm(1.0_f64).to_ftlb(), 9
+			InverseAngularVelocity::from_s_per_rad(1.0_f64).to_s_per_rad() * 0.0017453292519943,
+			InverseAngularVelocity::from_s_per_rad(1.0_f64).to_hpr(), 9
 		);
 	}
 
 	#[test]
-	fn velocity_units() {
+	fn inverse_energy_units() {
 		assert_approx_equal(
-			Velocity::from_mps(0.01_f64).to_mps(),
-			Velocity::from_cmps(1.0_f64).to_mps(), 9
+			InverseEnergy::from_per_J(1000.0_f64).to_per_J(),
+			InverseEnergy::from_per_mJ(1.0_f64).to_per_J(), 9
 		);
 		assert_approx_equal(
-			Velocity::from_mps(1.0_f64).to_mps() * 100.0,
-			Velocity::from_mps(1.0_f64).to_cmps(), 9
+			InverseEnergy::from_per_J(1.0_f64).to_per_J() * 0.001,
+			InverseEnergy::from_per_J(1.0_f64).to_per_mJ(), 9
 		);
 		assert_approx_equal(
-			Velocity::from_mps(0.001_f64).to_mps(),
-			Velocity::from_mmps(1.0_f64).to_mps(), 9
+			InverseEnergy::from_per_J(1000000.0_f64).to_per_J(),
+			InverseEnergy::from_per_uJ(1.0_f64).to_per_J(), 9
 		);
 		assert_approx_equal(
-			Velocity::from_mps(1.0_f64).to_mps() * 1000.0,
-			Velocity::from_mps(1.0_f64).to_mmps(), 9
+			InverseEnergy::from_per_J(1.0_f64).to_per_J() * 1e-06,
+			InverseEnergy::from_per_J(1.0_f64).to_per_uJ(), 9
 		);
 		assert_approx_equal(
-			Velocity::from_mps(2.77777777777778e-07_f64).to_mps(),
-			Velocity::from_mmph(1.0_f64).to_mps(), 9
+			InverseEnergy::from_per_J(1000000000.0_f64).to_per_J(),
+			InverseEnergy::from_per_nJ(1.0_f64).to_per_J(), 9
 		);
 		assert_approx_equal(
-			Velocity::from_mps(1.0_f64).to_mps() * 3600000.0,
-			Velocity::from_mps(1.0_f64).to_mmph(), 9
+			InverseEnergy::from_per_J(1.0_f64).to_per_J() * 1e-09,
+			InverseEnergy::from_per_J(1.0_f64).to_per_nJ(), 9
 		);
 		assert_approx_equal(
-			Velocity::from_mps(0.277777777777778_f64).to_mps(),
-			Velocity::from_kph(1.0_f64).to_mps(), 9
+			InverseEnergy::from_per_J(0.001_f64).to_per_J(),
+			InverseEnergy::from_per_kJ(1.0_f64).to_per_J(), 9
 		);
 		assert_approx_equal(
-			Velocity::from_mps(1.0_f64).to_mps() * 3.6,
-			Velocity::from_mps(1.0_f64).to_kph(), 9
+			InverseEnergy::from_per_J(1.0_f64).to_per_J() * 1000.0,
+			InverseEnergy::from_per_J(1.0_f64).to_per_kJ(), 9
 		);
 		assert_approx_equal(
-			Velocity::from_mps(0.44704_f64).to_mps(),
-			Velocity::from_mph(1.0_f64).to_mps(), 9
+			InverseEnergy::from_per_J(1e-06_f64).to_per_J(),
+			InverseEnergy::from_per_MJ(1.0_f64).to_per_J(), 9
 		);
 		assert_approx_equal(
-			Velocity::from_mps(1.0_f64).to_mps() * 2.2369362920544,
-			Velocity::from_mps(1.0_f64).to_mph(), 9
+			InverseEnergy::from_per_J(1.0_f64).to_per_J() * 1000000.0,
+			InverseEnergy::from_per_J(1.0_f64).to_per_MJ(), 9
 		);
 		assert_approx_equal(
-			Velocity::from_mps(1000.0_f64).to_mps(),
-			Velocity::from_kmps(1.0_f64).to_mps(), 9
+			InverseEnergy::from_per_J(1e-09_f64).to_per_J(),
+			InverseEnergy::from_per_GJ(1.0_f64).to_per_J(), 9
 		);
 		assert_approx_equal(
-			Velocity::from_mps(1.0_f64).to_mps() * 0.001,
-			Velocity::from_mps(1.0_f64).to_kmps(), 9
+			InverseEnergy::from_per_J(1.0_f64).to_per_J() * 1000000000.0,
+			InverseEnergy::from_per_J(1.0_f64).to_per_GJ(), 9
 		);
 		assert_approx_equal(
-			Velocity::from_mps(299792458.0_f64).to_mps(),
-			Velocity::from_c(1.0_f64).to_mps(), 9
+			InverseEnergy::from_per_J(0.239005736137667_f64).to_per_J(),
+			InverseEnergy::from_per_cal(1.0_f64).to_per_J(), 9
 		);
 		assert_approx_equal(
-			Velocity::from_mps(1.0_f64).to_mps() * 3.3356409519815204e-09,
-			Velocity::from_mps(1.0_f64).to_c(), 9
+			InverseEnergy::from_per_J(1.0_f64).to_per_J() * 4.184,
+			InverseEnergy::from_per_J(1.0_f64).to_per_cal(), 9
 		);
-	}
-
-	#[test]
-	fn absorbed_dose_units() {
 		assert_approx_equal(
-			AbsorbedDose::from_Gy(0.001_f64).to_Gy(),
-			AbsorbedDose::from_mGy(1.0_f64).to_Gy(), 9
+			InverseEnergy::from_per_J(0.0002390057361376_f64).to_per_J(),
+			InverseEnergy::from_per_kcal(1.0_f64).to_per_J(), 9
 		);
 		assert_approx_equal(
-			AbsorbedDose::from_Gy(1.0_f64).to_Gy() * 1000.0,
-			AbsorbedDose::from_Gy(1.0_f64).to_mGy(), 9
+			InverseEnergy::from_per_J(1.0_f64).to_per_J() * 4184.0,
+			InverseEnergy::from_per_J(1.0_f64).to_per_kcal(), 9
 		);
 		assert_approx_equal(
-			AbsorbedDose::from_Gy(1e-06_f64).to_Gy(),
-			AbsorbedDose::from_uGy(1.0_f64).to_Gy(), 9
+			InverseEnergy::from_per_J(0.0002777777777777_f64).to_per_J(),
+			InverseEnergy::from_per_Whr(1.0_f64).to_per_J(), 9
 		);
 		assert_approx_equal(
-			AbsorbedDose::from_Gy(1.0_f64).to_Gy() * 1000000.0,
-			AbsorbedDose::from_Gy(1.0_f64).to_uGy(), 9
+			InverseEnergy::from_per_J(1.0_f64).to_per_J() * 3600.0,
+			InverseEnergy::from_per_J(1.0_f64).to_per_Whr(), 9
 		);
 		assert_approx_equal(
-			AbsorbedDose::from_Gy(1e-09_f64).to_Gy(),
-			AbsorbedDose::from_nGy(1.0_f64).to_Gy(), 9
+			InverseEnergy::from_per_J(2.78e-07_f64).to_per_J(),
+			InverseEnergy::from_per_kWhr(1.0_f64).to_per_J(), 9
 		);
 		assert_approx_equal(
-			AbsorbedDose::from_Gy(1.0_f64).to_Gy() * 1000000000.0,
-			AbsorbedDose::from_Gy(1.0_f64).to_nGy(), 9
+			InverseEnergy::from_per_J(1.0_f64).to_per_J() * 3600000.0,
+			InverseEnergy::from_per_J(1.0_f64).to_per_kWhr(), 9
 		);
 		assert_approx_equal(
-			AbsorbedDose::from_Gy(1000.0_f64).to_Gy(),
-			AbsorbedDose::from_kGy(1.0_f64).to_Gy(), 9
+			InverseEnergy::from_per_J(6.24e+18_f64).to_per_J(),
+			InverseEnergy::from_per_eV(1.0_f64).to_per_J(), 9
 		);
 		assert_approx_equal(
-			AbsorbedDose::from_Gy(1.0_f64).to_Gy() * 0.001,
-			AbsorbedDose::from_Gy(1.0_f64).to_kGy(), 9
+			InverseEnergy::from_per_J(1.0_f64).to_per_J() * 1.6e-19,
+			InverseEnergy::from_per_J(1.0_f64).to_per_eV(), 9
 		);
 		assert_approx_equal(
-			AbsorbedDose::from_Gy(1000000.0_f64).to_Gy(),
-			AbsorbedDose::from_MGy(1.0_f64).to_Gy(), 9
+			InverseEnergy::from_per_J(0.0009478171203133172_f64).to_per_J(),
+			InverseEnergy::from_per_BTU(1.0_f64).to_per_J(), 9
 		);
 		assert_approx_equal(
-			AbsorbedDose::from_Gy(1.0_f64).to_Gy() * 1e-06,
-			AbsorbedDose::from_Gy(1.0_f64).to_MGy(), 9
+			InverseEnergy::from_per_J(1.0_f64).to_per_J() * 1055.05585262,
+			InverseEnergy::from_per_J(1.0_f64).to_per_BTU(), 9
 		);
+	}
+
+	#[test]
+	fn inverse_force_units() {
 		assert_approx_equal(
-			AbsorbedDose::from_Gy(1000000000.0_f64).to_Gy(),
-			AbsorbedDose::from_GGy(1.0_f64).to_Gy(), 9
+			InverseForce::from_per_N(0.224337566199999_f64).to_per_N(),
+			InverseForce::from_per_lb(1.0_f64).to_per_N(), 9
 		);
 		assert_approx_equal(
-			AbsorbedDose::from_Gy(1.0_f64).to_Gy() * 1e-09,
-			AbsorbedDose::from_Gy(1.0_f64).to_GGy(), 9
+			InverseForce::from_per_N(1.0_f64).to_per_N() * 4.45756819483586,
+			InverseForce::from_per_N(1.0_f64).to_per_lb(), 9
 		);
 		assert_approx_equal(
-			AbsorbedDose::from_Gy(0.01_f64).to_Gy(),
-			AbsorbedDose::from_rad(1.0_f64).to_Gy(), 9
+			InverseForce::from_per_N(0.101971620999999_f64).to_per_N(),
+			InverseForce::from_per_kgG(1.0_f64).to_per_N(), 9
 		);
 		assert_approx_equal(
-			AbsorbedDose::from_Gy(1.0_f64).to_Gy() * 100.0,
-			AbsorbedDose::from_Gy(1.0_f64).to_rad(), 9
+			InverseForce::from_per_N(1.0_f64).to_per_N() * 9.8066500286389,
+			InverseForce::from_per_N(1.0_f64).to_per_kgG(), 9
 		);
 		assert_approx_equal(
-			AbsorbedDose::from_Gy(10.0_f64).to_Gy(),
-			AbsorbedDose::from_krad(1.0_f64).to_Gy(), 9
+			InverseForce::from_per_N(1000.0_f64).to_per_N(),
+			InverseForce::from_per_mN(1.0_f64).to_per_N(), 9
 		);
 		assert_approx_equal(
-			AbsorbedDose::from_Gy(1.0_f64).to_Gy() * 0.1,
-			AbsorbedDose::from_Gy(1.0_f64).to_krad(), 9
+			InverseForce::from_per_N(1.0_f64).to_per_N() * 0.001,
+			InverseForce::from_per_N(1.0_f64).to_per_mN(), 9
 		);
 		assert_approx_equal(
-			AbsorbedDose::from_Gy(1e-05_f64).to_Gy(),
-			AbsorbedDose::from_mrad(1.0_f64).to_Gy(), 9
+			InverseForce::from_per_N(1000000.0_f64).to_per_N(),
+			InverseForce::from_per_uN(1.0_f64).to_per_N(), 9
 		);
 		assert_approx_equal(
-			AbsorbedDose::from_Gy(1.0_f64).to_Gy() * 100000.0,
-			AbsorbedDose::from_Gy(1.0_f64).to_mrad(), 9
+			InverseForce::from_per_N(1.0_f64).to_per_N() * 1e-06,
+			InverseForce::from_per_N(1.0_f64).to_per_uN(), 9
 		);
 		assert_approx_equal(
-			AbsorbedDose::from_Gy(1e-08_f64).to_Gy(),
-			AbsorbedDose::from_urad(1.0_f64).to_Gy(), 9
+			InverseForce::from_per_N(1000000000.0_f64).to_per_N(),
+			InverseForce::from_per_nN(1.0_f64).to_per_N(), 9
 		);
 		assert_approx_equal(
-			AbsorbedDose::from_Gy(1.0_f64).to_Gy() * 100000000.0,
-			AbsorbedDose::from_Gy(1.0_f64).to_urad(), 9
+			InverseForce::from_per_N(1.0_f64).to_per_N() * 1e-09,
+			InverseForce::from_per_N(1.0_f64).to_per_nN(), 9
 		);
 		assert_approx_equal(
-			AbsorbedDose::from_Gy(0.0001_f64).to_Gy(),
-			AbsorbedDose::from_erg(1.0_f64).to_Gy(), 9
+			InverseForce::from_per_N(0.001_f64).to_per_N(),
+			InverseForce::from_per_kN(1.0_f64).to_per_N(), 9
 		);
 		assert_approx_equal(
-			AbsorbedDose::from_Gy(1.0_f64).to_Gy() * 10000.0,
-			AbsorbedDose::from_Gy(1.0_f64).to_erg(), 9
+			InverseForce::from_per_N(1.0_f64).to_per_N() * 1000.0,
+			InverseForce::from_per_N(1.0_f64).to_per_kN(), 9
 		);
-	}
-
-	#[test]
-	fn dose_equivalent_units() {
 		assert_approx_equal(
-			DoseEquivalent::from_Sv(0.001_f64).to_Sv(),
-			DoseEquivalent::from_mSv(1.0_f64).to_Sv(), 9
+			InverseForce::from_per_N(1e-06_f64).to_per_N(),
+			InverseForce::from_per_MN(1.0_f64).to_per_N(), 9
 		);
 		assert_approx_equal(
-			DoseEquivalent::from_Sv(1.0_f64).to_Sv() * 1000.0,
-			DoseEquivalent::from_Sv(1.0_f64).to_mSv(), 9
+			InverseForce::from_per_N(1.0_f64).to_per_N() * 1000000.0,
+			InverseForce::from_per_N(1.0_f64).to_per_MN(), 9
 		);
 		assert_approx_equal(
-			DoseEquivalent::from_Sv(1e-06_f64).to_Sv(),
-			DoseEquivalent::from_uSv(1.0_f64).to_Sv(), 9
+			InverseForce::from_per_N(1e-09_f64).to_per_N(),
+			InverseForce::from_per_GN(1.0_f64).to_per_N(), 9
 		);
 		assert_approx_equal(
-			DoseEquivalent::from_Sv(1.0_f64).to_Sv() * 1000000.0,
-			DoseEquivalent::from_Sv(1.0_f64).to_uSv(), 9
+			InverseForce::from_per_N(1.0_f64).to_per_N() * 1000000000.0,
+			InverseForce::from_per_N(1.0_f64).to_per_GN(), 9
 		);
+	}
+
+	#[test]
+	fn inverse_moment_of_inertia_units() {
 		assert_approx_equal(
-			DoseEquivalent::from_Sv(1e-09_f64).to_Sv(),
-			DoseEquivalent::from_nSv(1.0_f64).to_Sv(), 9
+			InverseMomentOfInertia::from_per_kgm2(0.1_f64).to_per_kgm2(),
+			InverseMomentOfInertia::from_per_gcm2(1.0_f64).to_per_kgm2(), 9
 		);
 		assert_approx_equal(
-			DoseEquivalent::from_Sv(1.0_f64).to_Sv() * 1000000000.0,
-			DoseEquivalent::from_Sv(1.0_f64).to_nSv(), 9
+			InverseMomentOfInertia::from_per_kgm2(1.0_f64).to_per_kgm2() * 10.0,
+			InverseMomentOfInertia::from_per_kgm2(1.0_f64).to_per_gcm2(), 9
 		);
 		assert_approx_equal(
-			DoseEquivalent::from_Sv(1000.0_f64).to_Sv(),
-			DoseEquivalent::from_kSv(1.0_f64).to_Sv(), 9
+			InverseMomentOfInertia::from_per_kgm2(1000.0_f64).to_per_kgm2(),
+			InverseMomentOfInertia::from_per_gm2(1.0_f64).to_per_kgm2(), 9
 		);
 		assert_approx_equal(
-			DoseEquivalent::from_Sv(1.0_f64).to_Sv() * 0.001,
-			DoseEquivalent::from_Sv(1.0_f64).to_kSv(), 9
+			InverseMomentOfInertia::from_per_kgm2(1.0_f64).to_per_kgm2() * 0.001,
+			InverseMomentOfInertia::from_per_kgm2(1.0_f64).to_per_gm2(), 9
 		);
+	}
+
+	#[test]
+	fn inverse_momentum_units() {
 		assert_approx_equal(
-			DoseEquivalent::from_Sv(1000000.0_f64).to_Sv(),
-			DoseEquivalent::from_MSv(1.0_f64).to_Sv(), 9
+			InverseMomentum::from_s_per_kgm(100000.0_f64).to_s_per_kgm(),
+			InverseMomentum::from_s_per_gcm(1.0_f64).to_s_per_kgm(), 9
 		);
 		assert_approx_equal(
-			DoseEquivalent::from_Sv(1.0_f64).to_Sv() * 1e-06,
-			DoseEquivalent::from_Sv(1.0_f64).to_MSv(), 9
+			InverseMomentum::from_s_per_kgm(1.0_f64).to_s_per_kgm() * 1e-05,
+			InverseMomentum::from_s_per_kgm(1.0_f64).to_s_per_gcm(), 9
 		);
 		assert_approx_equal(
-			DoseEquivalent::from_Sv(1000000000.0_f64).to_Sv(),
-			DoseEquivalent::from_GSv(1.0_f64).to_Sv(), 9
+			InverseMomentum::from_s_per_kgm(100000.0_f64).to_s_per_kgm(),
+			InverseMomentum::from_seconds_per_gram_centimeter(1.0_f64).to_s_per_kgm(), 9
 		);
 		assert_approx_equal(
-			DoseEquivalent::from_Sv(1.0_f64).to_Sv() * 1e-09,
-			DoseEquivalent::from_Sv(1.0_f64).to_GSv(), 9
+			InverseMomentum::from_s_per_kgm(1.0_f64).to_s_per_kgm() * 1e-05,
+			InverseMomentum::from_s_per_kgm(1.0_f64).to_seconds_per_gram_centimeter(), 9
 		);
+	}
+
+	#[test]
+	fn inverse_power_units() {
 		assert_approx_equal(
-			DoseEquivalent::from_Sv(0.01_f64).to_Sv(),
-			DoseEquivalent::from_rem(1.0_f64).to_Sv(), 9
+			InversePower::from_per_W(1000.0_f64).to_per_W(),
+			InversePower::from_per_mW(1.0_f64).to_per_W(), 9
 		);
 		assert_approx_equal(
-			DoseEquivalent::from_Sv(1.0_f64).to_Sv() * 100.0,
-			DoseEquivalent::from_Sv(1.0_f64).to_rem(), 9
+			InversePower::from_per_W(1.0_f64).to_per_W() * 0.001,
+			InversePower::from_per_W(1.0_f64).to_per_mW(), 9
 		);
 		assert_approx_equal(
-			DoseEquivalent::from_Sv(1e-05_f64).to_Sv(),
-			DoseEquivalent::from_mrem(1.0_f64).to_Sv(), 9
+			InversePower::from_per_W(1000000.0_f64).to_per_W(),
+			InversePower::from_per_uW(1.0_f64).to_per_W(), 9
 		);
 		assert_approx_equal(
-			DoseEquivalent::from_Sv(1.0_f64).to_Sv() * 100000.0,
-			DoseEquivalent::from_Sv(1.0_f64).to_mrem(), 9
+			InversePower::from_per_W(1.0_f64).to_per_W() * 1e-06,
+			InversePower::from_per_W(1.0_f64).to_per_uW(), 9
 		);
 		assert_approx_equal(
-			DoseEquivalent::from_Sv(10.0_f64).to_Sv(),
-			DoseEquivalent::from_krem(1.0_f64).to_Sv(), 9
+			InversePower::from_per_W(1000000000.0_f64).to_per_W(),
+			InversePower::from_per_nW(1.0_f64).to_per_W(), 9
 		);
 		assert_approx_equal(
-			DoseEquivalent::from_Sv(1.0_f64).to_Sv() * 0.1,
-			DoseEquivalent::from_Sv(1.0_f64).to_krem(), 9
+			InversePower::from_per_W(1.0_f64).to_per_W() * 1e-09,
+			InversePower::from_per_W(1.0_f64).to_per_nW(), 9
 		);
-	}
-
-	#[test]
-	fn radioactivity_units() {
 		assert_approx_equal(
-			Radioactivity::from_Bq(0.001_f64).to_Bq(),
-			Radioactivity::from_mBq(1.0_f64).to_Bq(), 9
+			InversePower::from_per_W(0.001_f64).to_per_W(),
+			InversePower::from_per_kW(1.0_f64).to_per_W(), 9
 		);
 		assert_approx_equal(
-			Radioactivity::from_Bq(1.0_f64).to_Bq() * 1000.0,
-			Radioactivity::from_Bq(1.0_f64).to_mBq(), 9
+			InversePower::from_per_W(1.0_f64).to_per_W() * 1000.0,
+			InversePower::from_per_W(1.0_f64).to_per_kW(), 9
 		);
 		assert_approx_equal(
-			Radioactivity::from_Bq(1e-06_f64).to_Bq(),
-			Radioactivity::from_uBq(1.0_f64).to_Bq(), 9
+			InversePower::from_per_W(1e-06_f64).to_per_W(),
+			InversePower::from_per_MW(1.0_f64).to_per_W(), 9
 		);
 		assert_approx_equal(
-			Radioactivity::from_Bq(1.0_f64).to_Bq() * 1000000.0,
-			Radioactivity::from_Bq(1.0_f64).to_uBq(), 9
+			InversePower::from_per_W(1.0_f64).to_per_W() * 1000000.0,
+			InversePower::from_per_W(1.0_f64).to_per_MW(), 9
 		);
 		assert_approx_equal(
-			Radioactivity::from_Bq(1e-09_f64).to_Bq(),
-			Radioactivity::from_nBq(1.0_f64).to_Bq(), 9
+			InversePower::from_per_W(1e-09_f64).to_per_W(),
+			InversePower::from_per_GW(1.0_f64).to_per_W(), 9
 		);
 		assert_approx_equal(
-			Radioactivity::from_Bq(1.0_f64).to_Bq() * 1000000000.0,
-			Radioactivity::from_Bq(1.0_f64).to_nBq(), 9
+			InversePower::from_per_W(1.0_f64).to_per_W() * 1000000000.0,
+			InversePower::from_per_W(1.0_f64).to_per_GW(), 9
 		);
 		assert_approx_equal(
-			Radioactivity::from_Bq(1000.0_f64).to_Bq(),
-			Radioactivity::from_kBq(1.0_f64).to_Bq(), 9
+			InversePower::from_per_W(0.0013410218586563_f64).to_per_W(),
+			InversePower::from_per_horsepower(1.0_f64).to_per_W(), 9
 		);
 		assert_approx_equal(
-			Radioactivity::from_Bq(1.0_f64).to_Bq() * 0.001,
-			Radioactivity::from_Bq(1.0_f64).to_kBq(), 9
+			InversePower::from_per_W(1.0_f64).to_per_W() * 745.7,
+			InversePower::from_per_W(1.0_f64).to_per_horsepower(), 9
 		);
+	}
+
+	#[test]
+	fn inverse_pressure_units() {
 		assert_approx_equal(
-			Radioactivity::from_Bq(1000000.0_f64).to_Bq(),
-			Radioactivity::from_MBq(1.0_f64).to_Bq(), 9
+			InversePressure::from_per_Pa(1000.0_f64).to_per_Pa(),
+			InversePressure::from_per_mPa(1.0_f64).to_per_Pa(), 9
 		);
 		assert_approx_equal(
-			Radioactivity::from_Bq(1.0_f64).to_Bq() * 1e-06,
-			Radioactivity::from_Bq(1.0_f64).to_MBq(), 9
+			InversePressure::from_per_Pa(1.0_f64).to_per_Pa() * 0.001,
+			InversePressure::from_per_Pa(1.0_f64).to_per_mPa(), 9
 		);
 		assert_approx_equal(
-			Radioactivity::from_Bq(1000000000.0_f64).to_Bq(),
-			Radioactivity::from_GBq(1.0_f64).to_Bq(), 9
+			InversePressure::from_per_Pa(1000000.0_f64).to_per_Pa(),
+			InversePressure::from_per_uPa(1.0_f64).to_per_Pa(), 9
 		);
 		assert_approx_equal(
-			Radioactivity::from_Bq(1.0_f64).to_Bq() * 1e-09,
-			Radioactivity::from_Bq(1.0_f64).to_GBq(), 9
+			InversePressure::from_per_Pa(1.0_f64).to_per_Pa() * 1e-06,
+			InversePressure::from_per_Pa(1.0_f64).to_per_uPa(), 9
 		);
 		assert_approx_equal(
-			Radioactivity::from_Bq(37000000000.0_f64).to_Bq(),
-			Radioactivity::from_Ci(1.0_f64).to_Bq(), 9
+			InversePressure::from_per_Pa(1000000000.0_f64).to_per_Pa(),
+			InversePressure::from_per_nPa(1.0_f64).to_per_Pa(), 9
 		);
 		assert_approx_equal(
-			Radioactivity::from_Bq(1.0_f64).to_Bq() * 2.7027027027027e-11,
-			Radioactivity::from_Bq(1.0_f64).to_Ci(), 9
+			InversePressure::from_per_Pa(1.0_f64).to_per_Pa() * 1e-09,
+			InversePressure::from_per_Pa(1.0_f64).to_per_nPa(), 9
 		);
 		assert_approx_equal(
-			Radioactivity::from_Bq(37000000.0_f64).to_Bq(),
-			Radioactivity::from_mCi(1.0_f64).to_Bq(), 9
+			InversePressure::from_per_Pa(0.001_f64).to_per_Pa(),
+			InversePressure::from_per_kPa(1.0_f64).to_per_Pa(), 9
 		);
 		assert_approx_equal(
-			Radioactivity::from_Bq(1.0_f64).to_Bq() * 2.7027027027027e-08,
-			Radioactivity::from_Bq(1.0_f64).to_mCi(), 9
+			InversePressure::from_per_Pa(1.0_f64).to_per_Pa() * 1000.0,
+			InversePressure::from_per_Pa(1.0_f64).to_per_kPa(), 9
 		);
 		assert_approx_equal(
-			Radioactivity::from_Bq(37000.0_f64).to_Bq(),
-			Radioactivity::from_uCi(1.0_f64).to_Bq(), 9
+			InversePressure::from_per_Pa(1e-06_f64).to_per_Pa(),
+			InversePressure::from_per_MPa(1.0_f64).to_per_Pa(), 9
 		);
 		assert_approx_equal(
-			Radioactivity::from_Bq(1.0_f64).to_Bq() * 2.7027027027027e-05,
-			Radioactivity::from_Bq(1.0_f64).to_uCi(), 9
+			InversePressure::from_per_Pa(1.0_f64).to_per_Pa() * 1000000.0,
+			InversePressure::from_per_Pa(1.0_f64).to_per_MPa(), 9
 		);
 		assert_approx_equal(
-			Radioactivity::from_Bq(37.0_f64).to_Bq(),
-			Radioactivity::from_nCi(1.0_f64).to_Bq(), 9
+			InversePressure::from_per_Pa(1e-09_f64).to_per_Pa(),
+			InversePressure::from_per_GPa(1.0_f64).to_per_Pa(), 9
 		);
 		assert_approx_equal(
-			Radioactivity::from_Bq(1.0_f64).to_Bq() * 0.027027027027027,
-			Radioactivity::from_Bq(1.0_f64).to_nCi(), 9
+			InversePressure::from_per_Pa(1.0_f64).to_per_Pa() * 1000000000.0,
+			InversePressure::from_per_Pa(1.0_f64).to_per_GPa(), 9
 		);
 		assert_approx_equal(
-			Radioactivity::from_Bq(0.037_f64).to_Bq(),
-			Radioactivity::from_pCi(1.0_f64).to_Bq(), 9
+			InversePressure::from_per_Pa(0.01_f64).to_per_Pa(),
+			InversePressure::from_per_hPa(1.0_f64).to_per_Pa(), 9
 		);
 		assert_approx_equal(
-			Radioactivity::from_Bq(1.0_f64).to_Bq() * 27.027027027027,
-			Radioactivity::from_Bq(1.0_f64).to_pCi(), 9
+			InversePressure::from_per_Pa(1.0_f64).to_per_Pa() * 100.0,
+			InversePressure::from_per_Pa(1.0_f64).to_per_hPa(), 9
 		);
 		assert_approx_equal(
-			Radioactivity::from_Bq(1000000.0_f64).to_Bq(),
-			Radioactivity::from_Rd(1.0_f64).to_Bq(), 9
+			InversePressure::from_per_Pa(1e-05_f64).to_per_Pa(),
+			InversePressure::from_per_bar(1.0_f64).to_per_Pa(), 9
 		);
 		assert_approx_equal(
-			Radioactivity::from_Bq(1.0_f64).to_Bq() * 1e-06,
-			Radioactivity::from_Bq(1.0_f64).to_Rd(), 9
+			InversePressure::from_per_Pa(1.0_f64).to_per_Pa() * 100000.0,
+			InversePressure::from_per_Pa(1.0_f64).to_per_bar(), 9
 		);
-	}
-
-
-	#[test]
-	fn inverse_amount_units() {
 		assert_approx_equal(
-			InverseAmount::from_per_mol(6.02e+23_f64).to_per_mol(),
-			InverseAmount::from_per_count(1.0_f64).to_per_mol(), 9
+			InversePressure::from_per_Pa(0.01_f64).to_per_Pa(),
+			InversePressure::from_per_mbar(1.0_f64).to_per_Pa(), 9
 		);
 		assert_approx_equal(
-			InverseAmount::from_per_mol(1.0_f64).to_per_mol() * 1.66e-24,
-			InverseAmount::from_per_mol(1.0_f64).to_per_count(), 9
+			InversePressure::from_per_Pa(1.0_f64).to_per_Pa() * 100.0,
+			InversePressure::from_per_Pa(1.0_f64).to_per_mbar(), 9
 		);
 		assert_approx_equal(
-			InverseAmount::from_per_mol(1000.0_f64).to_per_mol(),
-			InverseAmount::from_per_mmol(1.0_f64).to_per_mol(), 9
+			InversePressure::from_per_Pa(1.0_f64).to_per_Pa() * 101325.0,
+			InversePressure::from_per_Pa(1.0_f64).to_per_atm(), 9
 		);
 		assert_approx_equal(
-			InverseAmount::from_per_mol(1.0_f64).to_per_mol() * 0.001,
-			InverseAmount::from_per_mol(1.0_f64).to_per_mmol(), 9
+			InversePressure::from_per_Pa(0.00014503773773020924_f64).to_per_Pa(),
+			InversePressure::from_per_psi(1.0_f64).to_per_Pa(), 9
 		);
 		assert_approx_equal(
-			InverseAmount::from_per_mol(1000000.0_f64).to_per_mol(),
-			InverseAmount::from_per_umol(1.0_f64).to_per_mol(), 9
+			InversePressure::from_per_Pa(1.0_f64).to_per_Pa() * 6894.757293168361,
+			InversePressure::from_per_Pa(1.0_f64).to_per_psi(), 9
 		);
 		assert_approx_equal(
-			InverseAmount::from_per_mol(1.0_f64).to_per_mol() * 1e-06,
-			InverseAmount::from_per_mol(1.0_f64).to_per_umol(), 9
+			InversePressure::from_per_Pa(9.869232667160129e-06_f64).to_per_Pa(),
+			InversePressure::from_per_atm(1.0_f64).to_per_Pa(), 9
 		);
 		assert_approx_equal(
-			InverseAmount::from_per_mol(1000000000.0_f64).to_per_mol(),
-			InverseAmount::from_per_nmol(1.0_f64).to_per_mol(), 9
+			InversePressure::from_per_Pa(0.007500616827041697_f64).to_per_Pa(),
+			InversePressure::from_per_torr(1.0_f64).to_per_Pa(), 9
 		);
 		assert_approx_equal(
-			InverseAmount::from_per_mol(1.0_f64).to_per_mol() * 1e-09,
-			InverseAmount::from_per_mol(1.0_f64).to_per_nmol(), 9
+			InversePressure::from_per_Pa(1.0_f64).to_per_Pa() * 133.32236842105263,
+			InversePressure::from_per_Pa(1.0_f64).to_per_torr(), 9
 		);
 		assert_approx_equal(
-			InverseAmount::from_per_mol(1000000000000.0_f64).to_per_mol(),
-			InverseAmount::from_per_pmol(1.0_f64).to_per_mol(), 9
+			InversePressure::from_per_Pa(0.007500615758456563_f64).to_per_Pa(),
+			InversePressure::from_per_mmHg(1.0_f64).to_per_Pa(), 9
 		);
 		assert_approx_equal(
-			InverseAmount::from_per_mol(1.0_f64).to_per_mol() * 1e-12,
-			InverseAmount::from_per_mol(1.0_f64).to_per_pmol(), 9
+			InversePressure::from_per_Pa(1.0_f64).to_per_Pa() * 133.322387415,
+			InversePressure::from_per_Pa(1.0_f64).to_per_mmHg(), 9
 		);
 	}
 
 	#[test]
-	fn inverse_current_units() {
-		assert_approx_equal(
-			InverseCurrent::from_per_A(1000.0_f64).to_per_A(),
-			InverseCurrent::from_per_mA(1.0_f64).to_per_A(), 9
-		);
-		assert_approx_equal(
-			InverseCurrent::from_per_A(1.0_f64).to_per_A() * 0.001,
-			InverseCurrent::from_per_A(1.0_f64).to_per_mA(), 9
-		);
+	fn inverse_torque_units() {
 		assert_approx_equal(
-			InverseCurrent::from_per_A(1000000.0_f64).to_per_A(),
-			InverseCurrent::from_per_uA(1.0_f64).to_per_A(), 9
+			InverseTorque::from_per_Nm(0.73756214927727_f64).to_per_Nm(),
+			InverseTorque::from_per_ftlb(1.0_f64).to_per_Nm(), 9
 		);
 		assert_approx_equal(
-			InverseCurrent::from_per_A(1.0_f64).to_per_A() * 1e-06,
-			InverseCurrent::from_per_A(1.0_f64).to_per_uA(), 9
+			InverseTorque::from_per_Nm(1.0_f64).to_per_Nm() * 1.35581794833139,
+			InverseTorque::from_per_Nm(1.0_f64).to_per_ftlb(), 9
 		);
+	}
+
+	#[test]
+	fn time_per_distance_units() {
 		assert_approx_equal(
-			InverseCurrent::from_per_A(1000000000.0_f64).to_per_A(),
-			InverseCurrent::from_per_nA(1.0_f64).to_per_A(), 9
+			TimePerDistance::from_spm(100.0_f64).to_spm(),
+			TimePerDistance::from_s_per_cm(1.0_f64).to_spm(), 9
 		);
 		assert_approx_equal(
-			InverseCurrent::from_per_A(1.0_f64).to_per_A() * 1e-09,
-			InverseCurrent::from_per_A(1.0_f64).to_per_nA(), 9
+			TimePerDistance::from_spm(1.0_f64).to_spm() * 0.01,
+			TimePerDistance::from_spm(1.0_f64).to_s_per_cm(), 9
 		);
 		assert_approx_equal(
-			InverseCurrent::from_per_A(0.001_f64).to_per_A(),
-			InverseCurrent::from_per_kA(1.0_f64).to_per_A(), 9
+			TimePerDistance::from_spm(1000.0_f64).to_spm(),
+			TimePerDistance::from_s_per_mm(1.0_f64).to_spm(), 9
 		);
 		assert_approx_equal(
-			InverseCurrent::from_per_A(1.0_f64).to_per_A() * 1000.0,
-			InverseCurrent::from_per_A(1.0_f64).to_per_kA(), 9
+			TimePerDistance::from_spm(1.0_f64).to_spm() * 0.001,
+			TimePerDistance::from_spm(1.0_f64).to_s_per_mm(), 9
 		);
 		assert_approx_equal(
-			InverseCurrent::from_per_A(1e-06_f64).to_per_A(),
-			InverseCurrent::from_per_MA(1.0_f64).to_per_A(), 9
+			TimePerDistance::from_spm(3.6_f64).to_spm(),
+			TimePerDistance::from_hr_per_km(1.0_f64).to_spm(), 9
 		);
 		assert_approx_equal(
-			InverseCurrent::from_per_A(1.0_f64).to_per_A() * 1000000.0,
-			InverseCurrent::from_per_A(1.0_f64).to_per_MA(), 9
+			TimePerDistance::from_spm(1.0_f64).to_spm() * 0.277777777777778,
+			TimePerDistance::from_spm(1.0_f64).to_hr_per_km(), 9
 		);
 		assert_approx_equal(
-			InverseCurrent::from_per_A(1e-09_f64).to_per_A(),
-			InverseCurrent::from_per_GA(1.0_f64).to_per_A(), 9
+			TimePerDistance::from_spm(2.2369362920544_f64).to_spm(),
+			TimePerDistance::from_hr_per_mi(1.0_f64).to_spm(), 9
 		);
 		assert_approx_equal(
-			InverseCurrent::from_per_A(1.0_f64).to_per_A() * 1000000000.0,
-			InverseCurrent::from_per_A(1.0_f64).to_per_GA(), 9
+			TimePerDistance::from_spm(1.0_f64).to_spm() * 0.44704,
+			TimePerDistance::from_spm(1.0_f64).to_hr_per_mi(), 9
 		);
 	}
 
 	#[test]
-	fn inverse_distance_units() {
+	fn volume_per_mass_units() {
 		assert_approx_equal(
-			InverseDistance::from_per_m(100.0_f64).to_per_m(),
-			InverseDistance::from_per_cm(1.0_f64).to_per_m(), 9
+			VolumePerMass::from_m3_per_kg(0.001_f64).to_m3_per_kg(),
+			VolumePerMass::from_L_per_kg(1.0_f64).to_m3_per_kg(), 9
 		);
 		assert_approx_equal(
-			InverseDistance::from_per_m(1.0_f64).to_per_m() * 0.01,
-			InverseDistance::from_per_m(1.0_f64).to_per_cm(), 9
+			VolumePerMass::from_m3_per_kg(1.0_f64).to_m3_per_kg() * 1000.0,
+			VolumePerMass::from_m3_per_kg(1.0_f64).to_L_per_kg(), 9
 		);
 		assert_approx_equal(
-			InverseDistance::from_per_m(1000.0_f64).to_per_m(),
-			InverseDistance::from_per_mm(1.0_f64).to_per_m(), 9
+			VolumePerMass::from_m3_per_kg(0.001_f64).to_m3_per_kg(),
+			VolumePerMass::from_liters_per_kilogram(1.0_f64).to_m3_per_kg(), 9
 		);
 		assert_approx_equal(
-			InverseDistance::from_per_m(1.0_f64).to_per_m() * 0.001,
-			InverseDistance::from_per_m(1.0_f64).to_per_mm(), 9
+			VolumePerMass::from_m3_per_kg(1.0_f64).to_m3_per_kg() * 1000.0,
+			VolumePerMass::from_m3_per_kg(1.0_f64).to_liters_per_kilogram(), 9
 		);
 		assert_approx_equal(
-			InverseDistance::from_per_m(1000000.0_f64).to_per_m(),
-			InverseDistance::from_per_um(1.0_f64).to_per_m(), 9
+			VolumePerMass::from_m3_per_kg(0.001_f64).to_m3_per_kg(),
+			VolumePerMass::from_cc_per_g(1.0_f64).to_m3_per_kg(), 9
 		);
 		assert_approx_equal(
-			InverseDistance::from_per_m(1.0_f64).to_per_m() * 1e-06,
-			InverseDistance::from_per_m(1.0_f64).to_per_um(), 9
+			VolumePerMass::from_m3_per_kg(1.0_f64).to_m3_per_kg() * 1000.0,
+			VolumePerMass::from_m3_per_kg(1.0_f64).to_cc_per_g(), 9
 		);
 		assert_approx_equal(
-			InverseDistance::from_per_m(1000000000.0_f64).to_per_m(),
-			InverseDistance::from_per_nm(1.0_f64).to_per_m(), 9
+			VolumePerMass::from_m3_per_kg(0.001_f64).to_m3_per_kg(),
+			VolumePerMass::from_cubic_centimeters_per_gram(1.0_f64).to_m3_per_kg(), 9
 		);
 		assert_approx_equal(
-			InverseDistance::from_per_m(1.0_f64).to_per_m() * 1e-09,
-			InverseDistance::from_per_m(1.0_f64).to_per_nm(), 9
+			VolumePerMass::from_m3_per_kg(1.0_f64).to_m3_per_kg() * 1000.0,
+			VolumePerMass::from_m3_per_kg(1.0_f64).to_cubic_centimeters_per_gram(), 9
 		);
+	}
+
+	#[test]
+	fn inverse_absorbed_dose_units() {
 		assert_approx_equal(
-			InverseDistance::from_per_m(1000000000000.0_f64).to_per_m(),
-			InverseDistance::from_per_pm(1.0_f64).to_per_m(), 9
+			InverseAbsorbedDose::from_per_Gy(1000.0_f64).to_per_Gy(),
+			InverseAbsorbedDose::from_per_mGy(1.0_f64).to_per_Gy(), 9
 		);
 		assert_approx_equal(
-			InverseDistance::from_per_m(1.0_f64).to_per_m() * 1e-12,
-			InverseDistance::from_per_m(1.0_f64).to_per_pm(), 9
+			InverseAbsorbedDose::from_per_Gy(1.0_f64).to_per_Gy() * 0.001,
+			InverseAbsorbedDose::from_per_Gy(1.0_f64).to_per_mGy(), 9
 		);
 		assert_approx_equal(
-			InverseDistance::from_per_m(0.001_f64).to_per_m(),
-			InverseDistance::from_per_km(1.0_f64).to_per_m(), 9
+			InverseAbsorbedDose::from_per_Gy(1000000.0_f64).to_per_Gy(),
+			InverseAbsorbedDose::from_per_uGy(1.0_f64).to_per_Gy(), 9
 		);
 		assert_approx_equal(
-			InverseDistance::from_per_m(1.0_f64).to_per_m() * 1000.0,
-			InverseDistance::from_per_m(1.0_f64).to_per_km(), 9
+			InverseAbsorbedDose::from_per_Gy(1.0_f64).to_per_Gy() * 1e-06,
+			InverseAbsorbedDose::from_per_Gy(1.0_f64).to_per_uGy(), 9
 		);
 		assert_approx_equal(
-			InverseDistance::from_per_m(6.68e-12_f64).to_per_m(),
-			InverseDistance::from_per_au(1.0_f64).to_per_m(), 9
+			InverseAbsorbedDose::from_per_Gy(1000000000.0_f64).to_per_Gy(),
+			InverseAbsorbedDose::from_per_nGy(1.0_f64).to_per_Gy(), 9
 		);
 		assert_approx_equal(
-			InverseDistance::from_per_m(1.0_f64).to_per_m() * 149597870700.0,
-			InverseDistance::from_per_m(1.0_f64).to_per_au(), 9
+			InverseAbsorbedDose::from_per_Gy(1.0_f64).to_per_Gy() * 1e-09,
+			InverseAbsorbedDose::from_per_Gy(1.0_f64).to_per_nGy(), 9
 		);
 		assert_approx_equal(
-			InverseDistance::from_per_m(3.24e-17_f64).to_per_m(),
-			InverseDistance::from_per_parsec(1.0_f64).to_per_m(), 9
+			InverseAbsorbedDose::from_per_Gy(0.001_f64).to_per_Gy(),
+			InverseAbsorbedDose::from_per_kGy(1.0_f64).to_per_Gy(), 9
 		);
 		assert_approx_equal(
-			InverseDistance::from_per_m(1.0_f64).to_per_m() * 3.09e+16,
-			InverseDistance::from_per_m(1.0_f64).to_per_parsec(), 9
+			InverseAbsorbedDose::from_per_Gy(1.0_f64).to_per_Gy() * 1000.0,
+			InverseAbsorbedDose::from_per_Gy(1.0_f64).to_per_kGy(), 9
 		);
 		assert_approx_equal(
-			InverseDistance::from_per_m(1.06e-16_f64).to_per_m(),
-			InverseDistance::from_per_lyr(1.0_f64).to_per_m(), 9
+			InverseAbsorbedDose::from_per_Gy(1e-06_f64).to_per_Gy(),
+			InverseAbsorbedDose::from_per_MGy(1.0_f64).to_per_Gy(), 9
 		);
 		assert_approx_equal(
-			InverseDistance::from_per_m(1.0_f64).to_per_m() * 9460528169656200.0,
-			InverseDistance::from_per_m(1.0_f64).to_per_lyr(), 9
+			InverseAbsorbedDose::from_per_Gy(1.0_f64).to_per_Gy() * 1000000.0,
+			InverseAbsorbedDose::from_per_Gy(1.0_f64).to_per_MGy(), 9
 		);
-	}
-
-	#[test]
-	fn inverse_luminosity_units() {
 		assert_approx_equal(
-			InverseLuminosity::from_per_cd(1000.0_f64).to_per_cd(),
-			InverseLuminosity::from_per_mcd(1.0_f64).to_per_cd(), 9
+			InverseAbsorbedDose::from_per_Gy(1e-09_f64).to_per_Gy(),
+			InverseAbsorbedDose::from_per_GGy(1.0_f64).to_per_Gy(), 9
 		);
 		assert_approx_equal(
-			InverseLuminosity::from_per_cd(1.0_f64).to_per_cd() * 0.001,
-			InverseLuminosity::from_per_cd(1.0_f64).to_per_mcd(), 9
+			InverseAbsorbedDose::from_per_Gy(1.0_f64).to_per_Gy() * 1000000000.0,
+			InverseAbsorbedDose::from_per_Gy(1.0_f64).to_per_GGy(), 9
 		);
 		assert_approx_equal(
-			InverseLuminosity::from_per_cd(1000000.0_f64).to_per_cd(),
-			InverseLuminosity::from_per_ucd(1.0_f64).to_per_cd(), 9
+			InverseAbsorbedDose::from_per_Gy(100.0_f64).to_per_Gy(),
+			InverseAbsorbedDose::from_per_rad(1.0_f64).to_per_Gy(), 9
 		);
 		assert_approx_equal(
-			InverseLuminosity::from_per_cd(1.0_f64).to_per_cd() * 1e-06,
-			InverseLuminosity::from_per_cd(1.0_f64).to_per_ucd(), 9
+			InverseAbsorbedDose::from_per_Gy(1.0_f64).to_per_Gy() * 0.01,
+			InverseAbsorbedDose::from_per_Gy(1.0_f64).to_per_rad(), 9
 		);
 		assert_approx_equal(
-			InverseLuminosity::from_per_cd(1000000000.0_f64).to_per_cd(),
-			InverseLuminosity::from_per_ncd(1.0_f64).to_per_cd(), 9
+			InverseAbsorbedDose::from_per_Gy(0.1_f64).to_per_Gy(),
+			InverseAbsorbedDose::from_per_krad(1.0_f64).to_per_Gy(), 9
 		);
 		assert_approx_equal(
-			InverseLuminosity::from_per_cd(1.0_f64).to_per_cd() * 1e-09,
-			InverseLuminosity::from_per_cd(1.0_f64).to_per_ncd(), 9
+			InverseAbsorbedDose::from_per_Gy(1.0_f64).to_per_Gy() * 10.0,
+			InverseAbsorbedDose::from_per_Gy(1.0_f64).to_per_krad(), 9
 		);
 		assert_approx_equal(
-			InverseLuminosity::from_per_cd(0.001_f64).to_per_cd(),
-			InverseLuminosity::from_per_kcd(1.0_f64).to_per_cd(), 9
+			InverseAbsorbedDose::from_per_Gy(100000.0_f64).to_per_Gy(),
+			InverseAbsorbedDose::from_per_mrad(1.0_f64).to_per_Gy(), 9
 		);
 		assert_approx_equal(
-			InverseLuminosity::from_per_cd(1.0_f64).to_per_cd() * 1000.0,
-			InverseLuminosity::from_per_cd(1.0_f64).to_per_kcd(), 9
+			InverseAbsorbedDose::from_per_Gy(1.0_f64).to_per_Gy() * 1e-05,
+			InverseAbsorbedDose::from_per_Gy(1.0_f64).to_per_mrad(), 9
 		);
 		assert_approx_equal(
-			InverseLuminosity::from_per_cd(1e-06_f64).to_per_cd(),
-			InverseLuminosity::from_per_Mcd(1.0_f64).to_per_cd(), 9
+			InverseAbsorbedDose::from_per_Gy(100000000.0_f64).to_per_Gy(),
+			InverseAbsorbedDose::from_per_urad(1.0_f64).to_per_Gy(), 9
 		);
 		assert_approx_equal(
-			InverseLuminosity::from_per_cd(1.0_f64).to_per_cd() * 1000000.0,
-			InverseLuminosity::from_per_cd(1.0_f64).to_per_Mcd(), 9
+			InverseAbsorbedDose::from_per_Gy(1.0_f64).to_per_Gy() * 1e-08,
+			InverseAbsorbedDose::from_per_Gy(1.0_f64).to_per_urad(), 9
 		);
 		assert_approx_equal(
-			InverseLuminosity::from_per_cd(1e-09_f64).to_per_cd(),
-			InverseLuminosity::from_per_Gcd(1.0_f64).to_per_cd(), 9
+			InverseAbsorbedDose::from_per_Gy(10000.0_f64).to_per_Gy(),
+			InverseAbsorbedDose::from_per_erg(1.0_f64).to_per_Gy(), 9
 		);
 		assert_approx_equal(
-			InverseLuminosity::from_per_cd(1.0_f64).to_per_cd() * 1000000000.0,
-			InverseLuminosity::from_per_cd(1.0_f64).to_per_Gcd(), 9
+			InverseAbsorbedDose::from_per_Gy(1.0_f64).to_per_Gy() * 0.0001,
+			InverseAbsorbedDose::from_per_Gy(1.0_f64).to_per_erg(), 9
 		);
 	}
 
 	#[test]
-	fn inverse_mass_units() {
+	fn inverse_dose_equivalent_units() {
 		assert_approx_equal(
-			InverseMass::from_per_kg(1000.0_f64).to_per_kg(),
-			InverseMass::from_per_g(1.0_f64).to_per_kg(), 9
+			InverseDoseEquivalent::from_per_Sv(1000.0_f64).to_per_Sv(),
+			InverseDoseEquivalent::from_per_mSv(1.0_f64).to_per_Sv(), 9
 		);
 		assert_approx_equal(
-			InverseMass::from_per_kg(1.0_f64).to_per_kg() * 0.001,
-			InverseMass::from_per_kg(1.0_f64).to_per_g(), 9
+			InverseDoseEquivalent::from_per_Sv(1.0_f64).to_per_Sv() * 0.001,
+			InverseDoseEquivalent::from_per_Sv(1.0_f64).to_per_mSv(), 9
 		);
 		assert_approx_equal(
-			InverseMass::from_per_kg(1000000.0_f64).to_per_kg(),
-			InverseMass::from_per_mg(1.0_f64).to_per_kg(), 9
+			InverseDoseEquivalent::from_per_Sv(1000000.0_f64).to_per_Sv(),
+			InverseDoseEquivalent::from_per_uSv(1.0_f64).to_per_Sv(), 9
 		);
 		assert_approx_equal(
-			InverseMass::from_per_kg(1.0_f64).to_per_kg() * 1e-06,
-			InverseMass::from_per_kg(1.0_f64).to_per_mg(), 9
+			InverseDoseEquivalent::from_per_Sv(1.0_f64).to_per_Sv() * 1e-06,
+			InverseDoseEquivalent::from_per_Sv(1.0_f64).to_per_uSv(), 9
 		);
 		assert_approx_equal(
-			InverseMass::from_per_kg(1000000000.0_f64).to_per_kg(),
-			InverseMass::from_per_ug(1.0_f64).to_per_kg(), 9
+			InverseDoseEquivalent::from_per_Sv(1000000000.0_f64).to_per_Sv(),
+			InverseDoseEquivalent::from_per_nSv(1.0_f64).to_per_Sv(), 9
 		);
 		assert_approx_equal(
-			InverseMass::from_per_kg(1.0_f64).to_per_kg() * 1e-09,
-			InverseMass::from_per_kg(1.0_f64).to_per_ug(), 9
+			InverseDoseEquivalent::from_per_Sv(1.0_f64).to_per_Sv() * 1e-09,
+			InverseDoseEquivalent::from_per_Sv(1.0_f64).to_per_nSv(), 9
 		);
 		assert_approx_equal(
-			InverseMass::from_per_kg(1000000000000.0_f64).to_per_kg(),
-			InverseMass::from_per_ng(1.0_f64).to_per_kg(), 9
+			InverseDoseEquivalent::from_per_Sv(0.001_f64).to_per_Sv(),
+			InverseDoseEquivalent::from_per_kSv(1.0_f64).to_per_Sv(), 9
 		);
 		assert_approx_equal(
-			InverseMass::from_per_kg(1.0_f64).to_per_kg() * 1e-12,
-			InverseMass::from_per_kg(1.0_f64).to_per_ng(), 9
+			InverseDoseEquivalent::from_per_Sv(1.0_f64).to_per_Sv() * 1000.0,
+			InverseDoseEquivalent::from_per_Sv(1.0_f64).to_per_kSv(), 9
 		);
 		assert_approx_equal(
-			InverseMass::from_per_kg(1000000000000000.0_f64).to_per_kg(),
-			InverseMass::from_per_pg(1.0_f64).to_per_kg(), 9
+			InverseDoseEquivalent::from_per_Sv(1e-06_f64).to_per_Sv(),
+			InverseDoseEquivalent::from_per_MSv(1.0_f64).to_per_Sv(), 9
 		);
 		assert_approx_equal(
-			InverseMass::from_per_kg(1.0_f64).to_per_kg() * 1e-15,
-			InverseMass::from_per_kg(1.0_f64).to_per_pg(), 9
+			InverseDoseEquivalent::from_per_Sv(1.0_f64).to_per_Sv() * 1000000.0,
+			InverseDoseEquivalent::from_per_Sv(1.0_f64).to_per_MSv(), 9
 		);
 		assert_approx_equal(
-			InverseMass::from_per_kg(0.001_f64).to_per_kg(),
-			InverseMass::from_per_tons(1.0_f64).to_per_kg(), 9
+			InverseDoseEquivalent::from_per_Sv(1e-09_f64).to_per_Sv(),
+			InverseDoseEquivalent::from_per_GSv(1.0_f64).to_per_Sv(), 9
 		);
 		assert_approx_equal(
-			InverseMass::from_per_kg(1.0_f64).to_per_kg() * 1000.0,
-			InverseMass::from_per_kg(1.0_f64).to_per_tons(), 9
+			InverseDoseEquivalent::from_per_Sv(1.0_f64).to_per_Sv() * 1000000000.0,
+			InverseDoseEquivalent::from_per_Sv(1.0_f64).to_per_GSv(), 9
 		);
 		assert_approx_equal(
-			InverseMass::from_per_kg(1.6699999999999999e-25_f64).to_per_kg(),
-			InverseMass::from_per_earth_mass(1.0_f64).to_per_kg(), 9
+			InverseDoseEquivalent::from_per_Sv(100.0_f64).to_per_Sv(),
+			InverseDoseEquivalent::from_per_rem(1.0_f64).to_per_Sv(), 9
 		);
 		assert_approx_equal(
-			InverseMass::from_per_kg(1.0_f64).to_per_kg() * 5.97e+24,
-			InverseMass::from_per_kg(1.0_f64).to_per_earth_mass(), 9
+			InverseDoseEquivalent::from_per_Sv(1.0_f64).to_per_Sv() * 0.01,
+			InverseDoseEquivalent::from_per_Sv(1.0_f64).to_per_rem(), 9
 		);
 		assert_approx_equal(
-			InverseMass::from_per_kg(5.27e-28_f64).to_per_kg(),
-			InverseMass::from_per_jupiter_mass(1.0_f64).to_per_kg(), 9
+			InverseDoseEquivalent::from_per_Sv(100000.0_f64).to_per_Sv(),
+			InverseDoseEquivalent::from_per_mrem(1.0_f64).to_per_Sv(), 9
 		);
 		assert_approx_equal(
-			InverseMass::from_per_kg(1.0_f64).to_per_kg() * 1.9e+27,
-			InverseMass::from_per_kg(1.0_f64).to_per_jupiter_mass(), 9
+			InverseDoseEquivalent::from_per_Sv(1.0_f64).to_per_Sv() * 1e-05,
+			InverseDoseEquivalent::from_per_Sv(1.0_f64).to_per_mrem(), 9
 		);
 		assert_approx_equal(
-			InverseMass::from_per_kg(5.03e-31_f64).to_per_kg(),
-			InverseMass::from_per_solar_mass(1.0_f64).to_per_kg(), 9
+			InverseDoseEquivalent::from_per_Sv(0.1_f64).to_per_Sv(),
+			InverseDoseEquivalent::from_per_krem(1.0_f64).to_per_Sv(), 9
 		);
 		assert_approx_equal(
-			InverseMass::from_per_kg(1.0_f64).to_per_kg() * 1.99e+30,
-			InverseMass::from_per_kg(1.0_f64).to_per_solar_mass(), 9
+			InverseDoseEquivalent::from_per_Sv(1.0_f64).to_per_Sv() * 10.0,
+			InverseDoseEquivalent::from_per_Sv(1.0_f64).to_per_krem(), 9
+		);
+	}
+
+	#[test]
+	fn recip_test() {
+		assert_eq!(Amount{mol: 4.0_f64}.recip(), InverseAmount{per_mol: 0.25_f64});
+		assert_eq!(Current{A: 4.0_f64}.recip(), InverseCurrent{per_A: 0.25_f64});
+		assert_eq!(Distance{m: 4.0_f64}.recip(), InverseDistance{per_m: 0.25_f64});
+		assert_eq!(InverseAmount{per_mol: 4.0_f64}.recip(), Amount{mol: 0.25_f64});
+		assert_eq!(InverseCurrent{per_A: 4.0_f64}.recip(), Current{A: 0.25_f64});
+		assert_eq!(InverseDistance{per_m: 4.0_f64}.recip(), Distance{m: 0.25_f64});
+		assert_eq!(InverseLuminosity{per_cd: 4.0_f64}.recip(), Luminosity{cd: 0.25_f64});
+		assert_eq!(InverseMass{per_kg: 4.0_f64}.recip(), Mass{kg: 0.25_f64});
+		assert_eq!(InverseTemperature{per_K: 4.0_f64}.recip(), Temperature{K: 0.25_f64});
+		assert_eq!(Luminosity{cd: 4.0_f64}.recip(), InverseLuminosity{per_cd: 0.25_f64});
+		assert_eq!(Mass{kg: 4.0_f64}.recip(), InverseMass{per_kg: 0.25_f64});
+		assert_eq!(Temperature{K: 4.0_f64}.recip(), InverseTemperature{per_K: 0.25_f64});
+		assert_eq!(TemperatureInterval{K: 4.0_f64}.recip(), InverseTemperature{per_K: 0.25_f64});
+		assert_eq!(Time{s: 4.0_f64}.recip(), Frequency{Hz: 0.25_f64});
+		assert_eq!(CatalyticActivity{molps: 4.0_f64}.recip(), InverseCatalyticActivity{s_per_mol: 0.25_f64});
+		assert_eq!(Concentration{molpm3: 4.0_f64}.recip(), MolarVolume{m3_per_mol: 0.25_f64});
+		assert_eq!(InverseCatalyticActivity{s_per_mol: 4.0_f64}.recip(), CatalyticActivity{molps: 0.25_f64});
+		assert_eq!(InverseSpecificHeatCapacity{kgK_per_J: 4.0_f64}.recip(), SpecificHeatCapacity{J_per_kgK: 0.25_f64});
+		assert_eq!(Molality{molpkg: 4.0_f64}.recip(), MolarMass{kgpmol: 0.25_f64});
+		assert_eq!(MolarMass{kgpmol: 4.0_f64}.recip(), Molality{molpkg: 0.25_f64});
+		assert_eq!(MolarVolume{m3_per_mol: 4.0_f64}.recip(), Concentration{molpm3: 0.25_f64});
+		assert_eq!(SpecificHeatCapacity{J_per_kgK: 4.0_f64}.recip(), InverseSpecificHeatCapacity{kgK_per_J: 0.25_f64});
+		assert_eq!(AreaPerLumen{m2_per_lm: 4.0_f64}.recip(), Illuminance{lux: 0.25_f64});
+		assert_eq!(Capacitance{F: 4.0_f64}.recip(), Elastance{per_F: 0.25_f64});
+		assert_eq!(Charge{C: 4.0_f64}.recip(), InverseCharge{per_C: 0.25_f64});
+		assert_eq!(Conductance{S: 4.0_f64}.recip(), Resistance{Ohm: 0.25_f64});
+		assert_eq!(Elastance{per_F: 4.0_f64}.recip(), Capacitance{F: 0.25_f64});
+		assert_eq!(Illuminance{lux: 4.0_f64}.recip(), AreaPerLumen{m2_per_lm: 0.25_f64});
+		assert_eq!(Inductance{H: 4.0_f64}.recip(), InverseInductance{per_H: 0.25_f64});
+		assert_eq!(InverseCharge{per_C: 4.0_f64}.recip(), Charge{C: 0.25_f64});
+		assert_eq!(InverseInductance{per_H: 4.0_f64}.recip(), Inductance{H: 0.25_f64});
+		assert_eq!(InverseLuminousFlux{per_lm: 4.0_f64}.recip(), LuminousFlux{lm: 0.25_f64});
+		assert_eq!(InverseMagneticFlux{per_Wb: 4.0_f64}.recip(), MagneticFlux{Wb: 0.25_f64});
+		assert_eq!(InverseMagneticFluxDensity{m2_per_Wb: 4.0_f64}.recip(), MagneticFluxDensity{T: 0.25_f64});
+		assert_eq!(InverseVoltage{per_V: 4.0_f64}.recip(), Voltage{V: 0.25_f64});
+		assert_eq!(LuminousFlux{lm: 4.0_f64}.recip(), InverseLuminousFlux{per_lm: 0.25_f64});
+		assert_eq!(MagneticFlux{Wb: 4.0_f64}.recip(), InverseMagneticFlux{per_Wb: 0.25_f64});
+		assert_eq!(MagneticFluxDensity{T: 4.0_f64}.recip(), InverseMagneticFluxDensity{m2_per_Wb: 0.25_f64});
+		assert_eq!(Resistance{Ohm: 4.0_f64}.recip(), Conductance{S: 0.25_f64});
+		assert_eq!(Voltage{V: 4.0_f64}.recip(), InverseVoltage{per_V: 0.25_f64});
+		assert_eq!(Angle{rad: 4.0_f64}.recip(), InverseAngle{per_rad: 0.25_f64});
+		assert_eq!(Area{m2: 4.0_f64}.recip(), InverseArea{per_m2: 0.25_f64});
+		assert_eq!(InverseAngle{per_rad: 4.0_f64}.recip(), Angle{rad: 0.25_f64});
+		assert_eq!(InverseArea{per_m2: 4.0_f64}.recip(), Area{m2: 0.25_f64});
+		assert_eq!(InverseSolidAngle{per_sr: 4.0_f64}.recip(), SolidAngle{sr: 0.25_f64});
+		assert_eq!(InverseVolume{per_m3: 4.0_f64}.recip(), Volume{m3: 0.25_f64});
+		assert_eq!(SolidAngle{sr: 4.0_f64}.recip(), InverseSolidAngle{per_sr: 0.25_f64});
+		assert_eq!(Volume{m3: 4.0_f64}.recip(), InverseVolume{per_m3: 0.25_f64});
+		assert_eq!(Acceleration{mps2: 4.0_f64}.recip(), InverseAcceleration{s2pm: 0.25_f64});
+		assert_eq!(AngularAcceleration{radps2: 4.0_f64}.recip(), InverseAngularAcceleration{s2prad: 0.25_f64});
+		assert_eq!(AngularMomentum{kgm2radps: 4.0_f64}.recip(), InverseAngularMomentum{s_per_kgm2rad: 0.25_f64});
+		assert_eq!(AngularVelocity{radps: 4.0_f64}.recip(), InverseAngularVelocity{s_per_rad: 0.25_f64});
+		assert_eq!(AreaDensity{kgpm2: 4.0_f64}.recip(), AreaPerMass{m2_per_kg: 0.25_f64});
+		assert_eq!(AreaPerMass{m2_per_kg: 4.0_f64}.recip(), AreaDensity{kgpm2: 0.25_f64});
+		assert_eq!(Density{kgpm3: 4.0_f64}.recip(), VolumePerMass{m3_per_kg: 0.25_f64});
+		assert_eq!(Energy{J: 4.0_f64}.recip(), InverseEnergy{per_J: 0.25_f64});
+		assert_eq!(Force{N: 4.0_f64}.recip(), InverseForce{per_N: 0.25_f64});
+		assert_eq!(Frequency{Hz: 4.0_f64}.recip(), Time{s: 0.25_f64});
+		assert_eq!(InverseAcceleration{s2pm: 4.0_f64}.recip(), Acceleration{mps2: 0.25_f64});
+		assert_eq!(InverseAngularAcceleration{s2prad: 4.0_f64}.recip(), AngularAcceleration{radps2: 0.25_f64});
+		assert_eq!(InverseAngularMomentum{s_per_kgm2rad: 4.0_f64}.recip(), AngularMomentum{kgm2radps: 0.25_f64});
+		assert_eq!(InverseAngularVelocity{s_per_rad: 4.0_f64}.recip(), AngularVelocity{radps: 0.25_f64});
+		assert_eq!(InverseEnergy{per_J: 4.0_f64}.recip(), Energy{J: 0.25_f64});
+		assert_eq!(InverseForce{per_N: 4.0_f64}.recip(), Force{N: 0.25_f64});
+		assert_eq!(InverseMomentum{s_per_kgm: 4.0_f64}.recip(), Momentum{kgmps: 0.25_f64});
+		assert_eq!(InversePower{per_W: 4.0_f64}.recip(), Power{W: 0.25_f64});
+		assert_eq!(InversePressure{per_Pa: 4.0_f64}.recip(), Pressure{Pa: 0.25_f64});
+		assert_eq!(InverseTorque{per_Nm: 4.0_f64}.recip(), Energy{J: 0.25_f64});
+		assert_eq!(Momentum{kgmps: 4.0_f64}.recip(), InverseMomentum{s_per_kgm: 0.25_f64});
+		assert_eq!(Power{W: 4.0_f64}.recip(), InversePower{per_W: 0.25_f64});
+		assert_eq!(Pressure{Pa: 4.0_f64}.recip(), InversePressure{per_Pa: 0.25_f64});
+		assert_eq!(TimePerDistance{spm: 4.0_f64}.recip(), Velocity{mps: 0.25_f64});
+		assert_eq!(Torque{Nm: 4.0_f64}.recip(), InverseEnergy{per_J: 0.25_f64});
+		assert_eq!(Velocity{mps: 4.0_f64}.recip(), TimePerDistance{spm: 0.25_f64});
+		assert_eq!(VolumePerMass{m3_per_kg: 4.0_f64}.recip(), Density{kgpm3: 0.25_f64});
+		assert_eq!(Radioactivity{Bq: 4.0_f64}.recip(), Time{s: 0.25_f64});
+		assert_eq!(InverseJerk{s3pm: 4.0_f64}.recip(), Jerk{mps3: 0.25_f64});
+		assert_eq!(Jerk{mps3: 4.0_f64}.recip(), InverseJerk{s3pm: 0.25_f64});
+		assert_eq!(DynamicViscosity{Pas: 4.0_f64}.recip(), InverseDynamicViscosity{per_Pas: 0.25_f64});
+		assert_eq!(InverseDynamicViscosity{per_Pas: 4.0_f64}.recip(), DynamicViscosity{Pas: 0.25_f64});
+		assert_eq!(InverseKinematicViscosity{s_per_m2: 4.0_f64}.recip(), KinematicViscosity{m2ps: 0.25_f64});
+		assert_eq!(KinematicViscosity{m2ps: 4.0_f64}.recip(), InverseKinematicViscosity{s_per_m2: 0.25_f64});
+		assert_eq!(InverseMassFlowRate{s_per_kg: 4.0_f64}.recip(), MassFlowRate{kgps: 0.25_f64});
+		assert_eq!(InverseVolumetricFlowRate{s_per_m3: 4.0_f64}.recip(), VolumetricFlowRate{m3ps: 0.25_f64});
+		assert_eq!(MassFlowRate{kgps: 4.0_f64}.recip(), InverseMassFlowRate{s_per_kg: 0.25_f64});
+		assert_eq!(VolumetricFlowRate{m3ps: 4.0_f64}.recip(), InverseVolumetricFlowRate{s_per_m3: 0.25_f64});
+		assert_eq!(Entropy{J_per_K: 4.0_f64}.recip(), InverseHeatCapacity{K_per_J: 0.25_f64});
+		assert_eq!(HeatCapacity{J_per_K: 4.0_f64}.recip(), InverseHeatCapacity{K_per_J: 0.25_f64});
+		assert_eq!(InverseEntropy{K_per_J: 4.0_f64}.recip(), HeatCapacity{J_per_K: 0.25_f64});
+		assert_eq!(InverseHeatCapacity{K_per_J: 4.0_f64}.recip(), HeatCapacity{J_per_K: 0.25_f64});
+		assert_eq!(ThermalConductance{W_per_K: 4.0_f64}.recip(), ThermalResistance{K_per_W: 0.25_f64});
+		assert_eq!(ThermalConductivity{W_per_mK: 4.0_f64}.recip(), ThermalResistivity{mK_per_W: 0.25_f64});
+		assert_eq!(ThermalResistance{K_per_W: 4.0_f64}.recip(), ThermalConductance{W_per_K: 0.25_f64});
+		assert_eq!(ThermalResistivity{mK_per_W: 4.0_f64}.recip(), ThermalConductivity{W_per_mK: 0.25_f64});
+		assert_eq!(ElectricField{Vpm: 4.0_f64}.recip(), InverseElectricField{m_per_V: 0.25_f64});
+		assert_eq!(InverseElectricField{m_per_V: 4.0_f64}.recip(), ElectricField{Vpm: 0.25_f64});
+		assert_eq!(InverseMagneticFieldStrength{m_per_A: 4.0_f64}.recip(), MagneticFieldStrength{Apm: 0.25_f64});
+		assert_eq!(MagneticFieldStrength{Apm: 4.0_f64}.recip(), InverseMagneticFieldStrength{m_per_A: 0.25_f64});
+		assert_eq!(InverseLinearChargeDensity{m_per_C: 4.0_f64}.recip(), LinearChargeDensity{Cpm: 0.25_f64});
+		assert_eq!(InverseSurfaceChargeDensity{m2_per_C: 4.0_f64}.recip(), SurfaceChargeDensity{Cpm2: 0.25_f64});
+		assert_eq!(InverseVolumeChargeDensity{m3_per_C: 4.0_f64}.recip(), VolumeChargeDensity{Cpm3: 0.25_f64});
+		assert_eq!(LinearChargeDensity{Cpm: 4.0_f64}.recip(), InverseLinearChargeDensity{m_per_C: 0.25_f64});
+		assert_eq!(SurfaceChargeDensity{Cpm2: 4.0_f64}.recip(), InverseSurfaceChargeDensity{m2_per_C: 0.25_f64});
+		assert_eq!(VolumeChargeDensity{Cpm3: 4.0_f64}.recip(), InverseVolumeChargeDensity{m3_per_C: 0.25_f64});
+		assert_eq!(InverseIrradiance{m2_per_W: 4.0_f64}.recip(), Irradiance{W_per_m2: 0.25_f64});
+		assert_eq!(InverseRadiance{m2sr_per_W: 4.0_f64}.recip(), Radiance{W_per_m2sr: 0.25_f64});
+		assert_eq!(Irradiance{W_per_m2: 4.0_f64}.recip(), InverseIrradiance{m2_per_W: 0.25_f64});
+		assert_eq!(Radiance{W_per_m2sr: 4.0_f64}.recip(), InverseRadiance{m2sr_per_W: 0.25_f64});
+		assert_eq!(InverseMolarEnergy{mol_per_J: 4.0_f64}.recip(), MolarEnergy{J_per_mol: 0.25_f64});
+		assert_eq!(InverseMolarEntropy{molK_per_J: 4.0_f64}.recip(), MolarEntropy{J_per_molK: 0.25_f64});
+		assert_eq!(MolarEnergy{J_per_mol: 4.0_f64}.recip(), InverseMolarEnergy{mol_per_J: 0.25_f64});
+		assert_eq!(MolarEntropy{J_per_molK: 4.0_f64}.recip(), InverseMolarEntropy{molK_per_J: 0.25_f64});
+		assert_eq!(InverseMomentOfInertia{per_kgm2: 4.0_f64}.recip(), MomentOfInertia{kgm2: 0.25_f64});
+		assert_eq!(MomentOfInertia{kgm2: 4.0_f64}.recip(), InverseMomentOfInertia{per_kgm2: 0.25_f64});
+		assert_eq!(AbsorbedDose{Gy: 4.0_f64}.recip(), InverseAbsorbedDose{per_Gy: 0.25_f64});
+		assert_eq!(DoseEquivalent{Sv: 4.0_f64}.recip(), InverseDoseEquivalent{per_Sv: 0.25_f64});
+		assert_eq!(InverseAbsorbedDose{per_Gy: 4.0_f64}.recip(), AbsorbedDose{Gy: 0.25_f64});
+		assert_eq!(InverseDoseEquivalent{per_Sv: 4.0_f64}.recip(), DoseEquivalent{Sv: 0.25_f64});
+		assert_eq!(AbsorbedDoseRate{Gy_per_s: 4.0_f64}.recip(), InverseAbsorbedDoseRate{s_per_Gy: 0.25_f64});
+		assert_eq!(DoseEquivalentRate{Sv_per_s: 4.0_f64}.recip(), InverseDoseEquivalentRate{s_per_Sv: 0.25_f64});
+		assert_eq!(InverseAbsorbedDoseRate{s_per_Gy: 4.0_f64}.recip(), AbsorbedDoseRate{Gy_per_s: 0.25_f64});
+		assert_eq!(InverseDoseEquivalentRate{s_per_Sv: 4.0_f64}.recip(), DoseEquivalentRate{Sv_per_s: 0.25_f64});
+	}
+
+	#[test]
+	#[cfg(feature="num-rational")]
+	fn test_rational_unit_conversions() {
+		use num_rational::{Rational64, BigRational};
+		use num_traits::FromPrimitive;
+		let x = 4.5f64;
+		let y = 2.5f64;
+		assert_eq!(div_check(
+			&Rational64::from_f64(x).unwrap(), &Amount{mol: Rational64::from_f64(y).unwrap()}),
+				   InverseAmount{per_mol: Rational64::from_f64(x).unwrap()/Rational64::from_f64(y).unwrap()}
+		);
+		assert_eq!(div_check(
+			&BigRational::from_f64(x).unwrap(), &Amount{mol: BigRational::from_f64(y).unwrap()}),
+				   InverseAmount{per_mol: BigRational::from_f64(x).unwrap()/BigRational::from_f64(y).unwrap()}
+		);
+		assert_eq!(div_check(
+			&Rational64::from_f64(x).unwrap(), &Current{A: Rational64::from_f64(y).unwrap()}),
+				   InverseCurrent{per_A: Rational64::from_f64(x).unwrap()/Rational64::from_f64(y).unwrap()}
+		);
+		assert_eq!(div_check(
+			&BigRational::from_f64(x).unwrap(), &Current{A: BigRational::from_f64(y).unwrap()}),
+				   InverseCurrent{per_A: BigRational::from_f64(x).unwrap()/BigRational::from_f64(y).unwrap()}
+		);
+		assert_eq!(div_check(
+			&Rational64::from_f64(x).unwrap(), &Distance{m: Rational64::from_f64(y).unwrap()}),
+				   InverseDistance{per_m: Rational64::from_f64(x).unwrap()/Rational64::from_f64(y).unwrap()}
+		);
+		assert_eq!(div_check(
+			&BigRational::from_f64(x).unwrap(), &Distance{m: BigRational::from_f64(y).unwrap()}),
+				   InverseDistance{per_m: BigRational::from_f64(x).unwrap()/BigRational::from_f64(y).unwrap()}
+		);
+		assert_eq!(div_check(
+			&Rational64::from_f64(x).unwrap(), &InverseAmount{per_mol: Rational64::from_f64(y).unwrap()}),
+				   Amount{mol: Rational64::from_f64(x).unwrap()/Rational64::from_f64(y).unwrap()}
+		);
+		assert_eq!(div_check(
+			&BigRational::from_f64(x).unwrap(), &InverseAmount{per_mol: BigRational::from_f64(y).unwrap()}),
+				   Amount{mol: BigRational::from_f64(x).unwrap()/BigRational::from_f64(y).unwrap()}
+		);
+		assert_eq!(div_check(
+			&Rational64::from_f64(x).unwrap(), &InverseCurrent{per_A: Rational64::from_f64(y).unwrap()}),
+				   Current{A: Rational64::from_f64(x).unwrap()/Rational64::from_f64(y).unwrap()}
+		);
+		assert_eq!(div_check(
+			&BigRational::from_f64(x).unwrap(), &InverseCurrent{per_A: BigRational::from_f64(y).unwrap()}),
+				   Current{A: BigRational::from_f64(x).unwrap()/BigRational::from_f64(y).unwrap()}
+		);
+		assert_eq!(div_check(
+			&Rational64::from_f64(x).unwrap(), &InverseDistance{per_m: Rational64::from_f64(y).unwrap()}),
+				   Distance{m: Rational64::from_f64(x).unwrap()/Rational64::from_f64(y).unwrap()}
+		);
+		assert_eq!(div_check(
+			&BigRational::from_f64(x).unwrap(), &InverseDistance{per_m: BigRational::from_f64(y).unwrap()}),
+				   Distance{m: BigRational::from_f64(x).unwrap()/BigRational::from_f64(y).unwrap()}
+		);
+		assert_eq!(div_check(
+			&Rational64::from_f64(x).unwrap(), &InverseLuminosity{per_cd: Rational64::from_f64(y).unwrap()}),
+				   Luminosity{cd: Rational64::from_f64(x).unwrap()/Rational64::from_f64(y).unwrap()}
+		);
+		assert_eq!(div_check(
+			&BigRational::from_f64(x).unwrap(), &InverseLuminosity{per_cd: BigRational::from_f64(y).unwrap()}),
+				   Luminosity{cd: BigRational::from_f64(x).unwrap()/BigRational::from_f64(y).unwrap()}
 		);
-	}
-
-	#[test]
-	fn inverse_catalytic_activity_units() {
-		assert_approx_equal(
-			InverseCatalyticActivity::from_s_per_mol(60.0_f64).to_s_per_mol(),
-			InverseCatalyticActivity::from_minutes_per_mole(1.0_f64).to_s_per_mol(), 9
+		assert_eq!(div_check(
+			&Rational64::from_f64(x).unwrap(), &InverseMass{per_kg: Rational64::from_f64(y).unwrap()}),
+				   Mass{kg: Rational64::from_f64(x).unwrap()/Rational64::from_f64(y).unwrap()}
 		);
-		assert_approx_equal(
-			InverseCatalyticActivity::from_s_per_mol(1.0_f64).to_s_per_mol() * 0.0166666666666667,
-			InverseCatalyticActivity::from_s_per_mol(1.0_f64).to_minutes_per_mole(), 9
+		assert_eq!(div_check(
+			&BigRational::from_f64(x).unwrap(), &InverseMass{per_kg: BigRational::from_f64(y).unwrap()}),
+				   Mass{kg: BigRational::from_f64(x).unwrap()/BigRational::from_f64(y).unwrap()}
 		);
-		assert_approx_equal(
-			InverseCatalyticActivity::from_s_per_mol(3600.0_f64).to_s_per_mol(),
-			InverseCatalyticActivity::from_hours_per_mole(1.0_f64).to_s_per_mol(), 9
+		assert_eq!(div_check(
+			&Rational64::from_f64(x).unwrap(), &InverseTemperature{per_K: Rational64::from_f64(y).unwrap()}),
+				   Temperature{K: Rational64::from_f64(x).unwrap()/Rational64::from_f64(y).unwrap()}
 		);
-		assert_approx_equal(
-			InverseCatalyticActivity::from_s_per_mol(1.0_f64).to_s_per_mol() * 0.000277777777777778,
-			InverseCatalyticActivity::from_s_per_mol(1.0_f64).to_hours_per_mole(), 9
+		assert_eq!(div_check(
+			&BigRational::from_f64(x).unwrap(), &InverseTemperature{per_K: BigRational::from_f64(y).unwrap()}),
+				   Temperature{K: BigRational::from_f64(x).unwrap()/BigRational::from_f64(y).unwrap()}
 		);
-	}
-
-	#[test]
-	fn molar_volume_units() {
-		assert_approx_equal(
-			MolarVolume::from_m3_per_mol(0.001_f64).to_m3_per_mol(),
-			MolarVolume::from_L_per_mol(1.0_f64).to_m3_per_mol(), 9
+		assert_eq!(div_check(
+			&Rational64::from_f64(x).unwrap(), &Luminosity{cd: Rational64::from_f64(y).unwrap()}),
+				   InverseLuminosity{per_cd: Rational64::from_f64(x).unwrap()/Rational64::from_f64(y).unwrap()}
 		);
-		assert_approx_equal(
-			MolarVolume::from_m3_per_mol(1.0_f64).to_m3_per_mol() * 1000.0,
-			MolarVolume::from_m3_per_mol(1.0_f64).to_L_per_mol(), 9
+		assert_eq!(div_check(
+			&BigRational::from_f64(x).unwrap(), &Luminosity{cd: BigRational::from_f64(y).unwrap()}),
+				   InverseLuminosity{per_cd: BigRational::from_f64(x).unwrap()/BigRational::from_f64(y).unwrap()}
 		);
-		assert_approx_equal(
-			MolarVolume::from_m3_per_mol(0.001_f64).to_m3_per_mol(),
-			MolarVolume::from_liters_per_mole(1.0_f64).to_m3_per_mol(), 9
+		assert_eq!(div_check(
+			&Rational64::from_f64(x).unwrap(), &Mass{kg: Rational64::from_f64(y).unwrap()}),
+				   InverseMass{per_kg: Rational64::from_f64(x).unwrap()/Rational64::from_f64(y).unwrap()}
 		);
-		assert_approx_equal(
-			MolarVolume::from_m3_per_mol(1.0_f64).to_m3_per_mol() * 1000.0,
-			MolarVolume::from_m3_per_mol(1.0_f64).to_liters_per_mole(), 9
+		assert_eq!(div_check(
+			&BigRational::from_f64(x).unwrap(), &Mass{kg: BigRational::from_f64(y).unwrap()}),
+				   InverseMass{per_kg: BigRational::from_f64(x).unwrap()/BigRational::from_f64(y).unwrap()}
 		);
-	}
-
-	#[test]
-	fn specific_heat_capacity_units() {
-		assert_approx_equal(
-			SpecificHeatCapacity::from_J_per_kgK(1000.0_f64).to_J_per_kgK(),
-			SpecificHeatCapacity::from_joules_per_gram_kelvin(1.0_f64).to_J_per_kgK(), 9
+		assert_eq!(div_check(
+			&Rational64::from_f64(x).unwrap(), &Temperature{K: Rational64::from_f64(y).unwrap()}),
+				   InverseTemperature{per_K: Rational64::from_f64(x).unwrap()/Rational64::from_f64(y).unwrap()}
 		);
-		assert_approx_equal(
-			SpecificHeatCapacity::from_J_per_kgK(1.0_f64).to_J_per_kgK() * 0.001,
-			SpecificHeatCapacity::from_J_per_kgK(1.0_f64).to_joules_per_gram_kelvin(), 9
+		assert_eq!(div_check(
+			&BigRational::from_f64(x).unwrap(), &Temperature{K: BigRational::from_f64(y).unwrap()}),
+				   InverseTemperature{per_K: BigRational::from_f64(x).unwrap()/BigRational::from_f64(y).unwrap()}
 		);
-		assert_approx_equal(
-			SpecificHeatCapacity::from_J_per_kgK(1000.0_f64).to_J_per_kgK(),
-			SpecificHeatCapacity::from_J_per_gK(1.0_f64).to_J_per_kgK(), 9
+		assert_eq!(div_check(
+			&Rational64::from_f64(x).unwrap(), &TemperatureInterval{K: Rational64::from_f64(y).unwrap()}),
+				   InverseTemperature{per_K: Rational64::from_f64(x).unwrap()/Rational64::from_f64(y).unwrap()}
 		);
-		assert_approx_equal(
-			SpecificHeatCapacity::from_J_per_kgK(1.0_f64).to_J_per_kgK() * 0.001,
-			SpecificHeatCapacity::from_J_per_kgK(1.0_f64).to_J_per_gK(), 9
+		assert_eq!(div_check(
+			&BigRational::from_f64(x).unwrap(), &TemperatureInterval{K: BigRational::from_f64(y).unwrap()}),
+				   InverseTemperature{per_K: BigRational::from_f64(x).unwrap()/BigRational::from_f64(y).unwrap()}
 		);
-	}
-
-	#[test]
-	fn elastance_units() {
-		assert_approx_equal(
-			Elastance::from_per_F(1000.0_f64).to_per_F(),
-			Elastance::from_per_mF(1.0_f64).to_per_F(), 9
+		assert_eq!(div_check(
+			&Rational64::from_f64(x).unwrap(), &Time{s: Rational64::from_f64(y).unwrap()}),
+				   Frequency{Hz: Rational64::from_f64(x).unwrap()/Rational64::from_f64(y).unwrap()}
 		);
-		assert_approx_equal(
-			Elastance::from_per_F(1.0_f64).to_per_F() * 0.001,
-			Elastance::from_per_F(1.0_f64).to_per_mF(), 9
+		assert_eq!(div_check(
+			&BigRational::from_f64(x).unwrap(), &Time{s: BigRational::from_f64(y).unwrap()}),
+				   Frequency{Hz: BigRational::from_f64(x).unwrap()/BigRational::from_f64(y).unwrap()}
 		);
-		assert_approx_equal(
-			Elastance::from_per_F(1000000.0_f64).to_per_F(),
-			Elastance::from_per_uF(1.0_f64).to_per_F(), 9
+		assert_eq!(div_check(
+			&Rational64::from_f64(x).unwrap(), &CatalyticActivity{molps: Rational64::from_f64(y).unwrap()}),
+				   InverseCatalyticActivity{s_per_mol: Rational64::from_f64(x).unwrap()/Rational64::from_f64(y).unwrap()}
 		);
-		assert_approx_equal(
-			Elastance::from_per_F(1.0_f64).to_per_F() * 1e-06,
-			Elastance::from_per_F(1.0_f64).to_per_uF(), 9
+		assert_eq!(div_check(
+			&BigRational::from_f64(x).unwrap(), &CatalyticActivity{molps: BigRational::from_f64(y).unwrap()}),
+				   InverseCatalyticActivity{s_per_mol: BigRational::from_f64(x).unwrap()/BigRational::from_f64(y).unwrap()}
 		);
-		assert_approx_equal(
-			Elastance::from_per_F(1000000000.0_f64).to_per_F(),
-			Elastance::from_per_nF(1.0_f64).to_per_F(), 9
+		assert_eq!(div_check(
+			&Rational64::from_f64(x).unwrap(), &Concentration{molpm3: Rational64::from_f64(y).unwrap()}),
+				   MolarVolume{m3_per_mol: Rational64::from_f64(x).unwrap()/Rational64::from_f64(y).unwrap()}
 		);
-		assert_approx_equal(
-			Elastance::from_per_F(1.0_f64).to_per_F() * 1e-09,
-			Elastance::from_per_F(1.0_f64).to_per_nF(), 9
+		assert_eq!(div_check(
+			&BigRational::from_f64(x).unwrap(), &Concentration{molpm3: BigRational::from_f64(y).unwrap()}),
+				   MolarVolume{m3_per_mol: BigRational::from_f64(x).unwrap()/BigRational::from_f64(y).unwrap()}
 		);
-		assert_approx_equal(
-			Elastance::from_per_F(1000000000000.0_f64).to_per_F(),
-			Elastance::from_per_pF(1.0_f64).to_per_F(), 9
+		assert_eq!(div_check(
+			&Rational64::from_f64(x).unwrap(), &InverseCatalyticActivity{s_per_mol: Rational64::from_f64(y).unwrap()}),
+				   CatalyticActivity{molps: Rational64::from_f64(x).unwrap()/Rational64::from_f64(y).unwrap()}
 		);
-		assert_approx_equal(
-			Elastance::from_per_F(1.0_f64).to_per_F() * 1e-12,
-			Elastance::from_per_F(1.0_f64).to_per_pF(), 9
+		assert_eq!(div_check(
+			&BigRational::from_f64(x).unwrap(), &InverseCatalyticActivity{s_per_mol: BigRational::from_f64(y).unwrap()}),
+				   CatalyticActivity{molps: BigRational::from_f64(x).unwrap()/BigRational::from_f64(y).unwrap()}
 		);
-		assert_approx_equal(
-			Elastance::from_per_F(0.001_f64).to_per_F(),
-			Elastance::from_per_kF(1.0_f64).to_per_F(), 9
+		assert_eq!(div_check(
+			&Rational64::from_f64(x).unwrap(), &InverseSpecificHeatCapacity{kgK_per_J: Rational64::from_f64(y).unwrap()}),
+				   SpecificHeatCapacity{J_per_kgK: Rational64::from_f64(x).unwrap()/Rational64::from_f64(y).unwrap()}
 		);
-		assert_approx_equal(
-			Elastance::from_per_F(1.0_f64).to_per_F() * 1000.0,
-			Elastance::from_per_F(1.0_f64).to_per_kF(), 9
+		assert_eq!(div_check(
+			&BigRational::from_f64(x).unwrap(), &InverseSpecificHeatCapacity{kgK_per_J: BigRational::from_f64(y).unwrap()}),
+				   SpecificHeatCapacity{J_per_kgK: BigRational::from_f64(x).unwrap()/BigRational::from_f64(y).unwrap()}
 		);
-		assert_approx_equal(
-			Elastance::from_per_F(1e-06_f64).to_per_F(),
-			Elastance::from_per_MF(1.0_f64).to_per_F(), 9
+		assert_eq!(div_check(
+			&Rational64::from_f64(x).unwrap(), &Molality{molpkg: Rational64::from_f64(y).unwrap()}),
+				   MolarMass{kgpmol: Rational64::from_f64(x).unwrap()/Rational64::from_f64(y).unwrap()}
 		);
-		assert_approx_equal(
-			Elastance::from_per_F(1.0_f64).to_per_F() * 1000000.0,
-			Elastance::from_per_F(1.0_f64).to_per_MF(), 9
+		assert_eq!(div_check(
+			&BigRational::from_f64(x).unwrap(), &Molality{molpkg: BigRational::from_f64(y).unwrap()}),
+				   MolarMass{kgpmol: BigRational::from_f64(x).unwrap()/BigRational::from_f64(y).unwrap()}
 		);
-		assert_approx_equal(
-			Elastance::from_per_F(1e-09_f64).to_per_F(),
-			Elastance::from_per_GF(1.0_f64).to_per_F(), 9
+		assert_eq!(div_check(
+			&Rational64::from_f64(x).unwrap(), &MolarMass{kgpmol: Rational64::from_f64(y).unwrap()}),
+				   Molality{molpkg: Rational64::from_f64(x).unwrap()/Rational64::from_f64(y).unwrap()}
 		);
-		assert_approx_equal(
-			Elastance::from_per_F(1.0_f64).to_per_F() * 1000000000.0,
-			Elastance::from_per_F(1.0_f64).to_per_GF(), 9
+		assert_eq!(div_check(
+			&BigRational::from_f64(x).unwrap(), &MolarMass{kgpmol: BigRational::from_f64(y).unwrap()}),
+				   Molality{molpkg: BigRational::from_f64(x).unwrap()/BigRational::from_f64(y).unwrap()}
 		);
-	}
-
-	#[test]
-	fn inverse_charge_units() {
-		assert_approx_equal(
-			InverseCharge::from_per_C(1000.0_f64).to_per_C(),
-			InverseCharge::from_per_mC(1.0_f64).to_per_C(), 9
+		assert_eq!(div_check(
+			&Rational64::from_f64(x).unwrap(), &MolarVolume{m3_per_mol: Rational64::from_f64(y).unwrap()}),
+				   Concentration{molpm3: Rational64::from_f64(x).unwrap()/Rational64::from_f64(y).unwrap()}
 		);
-		assert_approx_equal(
-			InverseCharge::from_per_C(1.0_f64).to_per_C() * 0.001,
-			InverseCharge::from_per_C(1.0_f64).to_per_mC(), 9
+		assert_eq!(div_check(
+			&BigRational::from_f64(x).unwrap(), &MolarVolume{m3_per_mol: BigRational::from_f64(y).unwrap()}),
+				   Concentration{molpm3: BigRational::from_f64(x).unwrap()/BigRational::from_f64(y).unwrap()}
 		);
-		assert_approx_equal(
-			InverseCharge::from_per_C(1000000.0_f64).to_per_C(),
-			InverseCharge::from_per_uC(1.0_f64).to_per_C(), 9
+		assert_eq!(div_check(
+			&Rational64::from_f64(x).unwrap(), &SpecificHeatCapacity{J_per_kgK: Rational64::from_f64(y).unwrap()}),
+				   InverseSpecificHeatCapacity{kgK_per_J: Rational64::from_f64(x).unwrap()/Rational64::from_f64(y).unwrap()}
 		);
-		assert_approx_equal(
-			InverseCharge::from_per_C(1.0_f64).to_per_C() * 1e-06,
-			InverseCharge::from_per_C(1.0_f64).to_per_uC(), 9
+		assert_eq!(div_check(
+			&BigRational::from_f64(x).unwrap(), &SpecificHeatCapacity{J_per_kgK: BigRational::from_f64(y).unwrap()}),
+				   InverseSpecificHeatCapacity{kgK_per_J: BigRational::from_f64(x).unwrap()/BigRational::from_f64(y).unwrap()}
 		);
-		assert_approx_equal(
-			InverseCharge::from_per_C(1000000000.0_f64).to_per_C(),
-			InverseCharge::from_per_nC(1.0_f64).to_per_C(), 9
+		assert_eq!(div_check(
+			&Rational64::from_f64(x).unwrap(), &AreaPerLumen{m2_per_lm: Rational64::from_f64(y).unwrap()}),
+				   Illuminance{lux: Rational64::from_f64(x).unwrap()/Rational64::from_f64(y).unwrap()}
 		);
-		assert_approx_equal(
-			InverseCharge::from_per_C(1.0_f64).to_per_C() * 1e-09,
-			InverseCharge::from_per_C(1.0_f64).to_per_nC(), 9
+		assert_eq!(div_check(
+			&BigRational::from_f64(x).unwrap(), &AreaPerLumen{m2_per_lm: BigRational::from_f64(y).unwrap()}),
+				   Illuminance{lux: BigRational::from_f64(x).unwrap()/BigRational::from_f64(y).unwrap()}
 		);
-		assert_approx_equal(
-			InverseCharge::from_per_C(0.001_f64).to_per_C(),
-			InverseCharge::from_per_kC(1.0_f64).to_per_C(), 9
+		assert_eq!(div_check(
+			&Rational64::from_f64(x).unwrap(), &Capacitance{F: Rational64::from_f64(y).unwrap()}),
+				   Elastance{per_F: Rational64::from_f64(x).unwrap()/Rational64::from_f64(y).unwrap()}
 		);
-		assert_approx_equal(
-			InverseCharge::from_per_C(1.0_f64).to_per_C() * 1000.0,
-			InverseCharge::from_per_C(1.0_f64).to_per_kC(), 9
+		assert_eq!(div_check(
+			&BigRational::from_f64(x).unwrap(), &Capacitance{F: BigRational::from_f64(y).unwrap()}),
+				   Elastance{per_F: BigRational::from_f64(x).unwrap()/BigRational::from_f64(y).unwrap()}
 		);
-		assert_approx_equal(
-			InverseCharge::from_per_C(1e-06_f64).to_per_C(),
-			InverseCharge::from_per_MC(1.0_f64).to_per_C(), 9
+		assert_eq!(div_check(
+			&Rational64::from_f64(x).unwrap(), &Charge{C: Rational64::from_f64(y).unwrap()}),
+				   InverseCharge{per_C: Rational64::from_f64(x).unwrap()/Rational64::from_f64(y).unwrap()}
 		);
-		assert_approx_equal(
-			InverseCharge::from_per_C(1.0_f64).to_per_C() * 1000000.0,
-			InverseCharge::from_per_C(1.0_f64).to_per_MC(), 9
+		assert_eq!(div_check(
+			&BigRational::from_f64(x).unwrap(), &Charge{C: BigRational::from_f64(y).unwrap()}),
+				   InverseCharge{per_C: BigRational::from_f64(x).unwrap()/BigRational::from_f64(y).unwrap()}
 		);
-		assert_approx_equal(
-			InverseCharge::from_per_C(1e-09_f64).to_per_C(),
-			InverseCharge::from_per_GC(1.0_f64).to_per_C(), 9
+		assert_eq!(div_check(
+			&Rational64::from_f64(x).unwrap(), &Conductance{S: Rational64::from_f64(y).unwrap()}),
+				   Resistance{Ohm: Rational64::from_f64(x).unwrap()/Rational64::from_f64(y).unwrap()}
 		);
-		assert_approx_equal(
-			InverseCharge::from_per_C(1.0_f64).to_per_C() * 1000000000.0,
-			InverseCharge::from_per_C(1.0_f64).to_per_GC(), 9
+		assert_eq!(div_check(
+			&BigRational::from_f64(x).unwrap(), &Conductance{S: BigRational::from_f64(y).unwrap()}),
+				   Resistance{Ohm: BigRational::from_f64(x).unwrap()/BigRational::from_f64(y).unwrap()}
 		);
-	}
-
-	#[test]
-	fn inverse_inductance_units() {
-		assert_approx_equal(
-			InverseInductance::from_per_H(1000.0_f64).to_per_H(),
-			InverseInductance::from_per_mH(1.0_f64).to_per_H(), 9
+		assert_eq!(div_check(
+			&Rational64::from_f64(x).unwrap(), &Elastance{per_F: Rational64::from_f64(y).unwrap()}),
+				   Capacitance{F: Rational64::from_f64(x).unwrap()/Rational64::from_f64(y).unwrap()}
 		);
-		assert_approx_equal(
-			InverseInductance::from_per_H(1.0_f64).to_per_H() * 0.001,
-			InverseInductance::from_per_H(1.0_f64).to_per_mH(), 9
+		assert_eq!(div_check(
+			&BigRational::from_f64(x).unwrap(), &Elastance{per_F: BigRational::from_f64(y).unwrap()}),
+				   Capacitance{F: BigRational::from_f64(x).unwrap()/BigRational::from_f64(y).unwrap()}
 		);
-		assert_approx_equal(
-			InverseInductance::from_per_H(1000000.0_f64).to_per_H(),
-			InverseInductance::from_per_uH(1.0_f64).to_per_H(), 9
+		assert_eq!(div_check(
+			&Rational64::from_f64(x).unwrap(), &Illuminance{lux: Rational64::from_f64(y).unwrap()}),
+				   AreaPerLumen{m2_per_lm: Rational64::from_f64(x).unwrap()/Rational64::from_f64(y).unwrap()}
 		);
-		assert_approx_equal(
-			InverseInductance::from_per_H(1.0_f64).to_per_H() * 1e-06,
-			InverseInductance::from_per_H(1.0_f64).to_per_uH(), 9
+		assert_eq!(div_check(
+			&BigRational::from_f64(x).unwrap(), &Illuminance{lux: BigRational::from_f64(y).unwrap()}),
+				   AreaPerLumen{m2_per_lm: BigRational::from_f64(x).unwrap()/BigRational::from_f64(y).unwrap()}
 		);
-		assert_approx_equal(
-			InverseInductance::from_per_H(1000000000.0_f64).to_per_H(),
-			InverseInductance::from_per_nH(1.0_f64).to_per_H(), 9
+		assert_eq!(div_check(
+			&Rational64::from_f64(x).unwrap(), &Inductance{H: Rational64::from_f64(y).unwrap()}),
+				   InverseInductance{per_H: Rational64::from_f64(x).unwrap()/Rational64::from_f64(y).unwrap()}
 		);
-		assert_approx_equal(
-			InverseInductance::from_per_H(1.0_f64).to_per_H() * 1e-09,
-			InverseInductance::from_per_H(1.0_f64).to_per_nH(), 9
+		assert_eq!(div_check(
+			&BigRational::from_f64(x).unwrap(), &Inductance{H: BigRational::from_f64(y).unwrap()}),
+				   InverseInductance{per_H: BigRational::from_f64(x).unwrap()/BigRational::from_f64(y).unwrap()}
 		);
-		assert_approx_equal(
-			InverseInductance::from_per_H(0.001_f64).to_per_H(),
-			InverseInductance::from_per_kH(1.0_f64).to_per_H(), 9
+		assert_eq!(div_check(
+			&Rational64::from_f64(x).unwrap(), &InverseCharge{per_C: Rational64::from_f64(y).unwrap()}),
+				   Charge{C: Rational64::from_f64(x).unwrap()/Rational64::from_f64(y).unwrap()}
 		);
-		assert_approx_equal(
-			InverseInductance::from_per_H(1.0_f64).to_per_H() * 1000.0,
-			InverseInductance::from_per_H(1.0_f64).to_per_kH(), 9
+		assert_eq!(div_check(
+			&BigRational::from_f64(x).unwrap(), &InverseCharge{per_C: BigRational::from_f64(y).unwrap()}),
+				   Charge{C: BigRational::from_f64(x).unwrap()/BigRational::from_f64(y).unwrap()}
 		);
-		assert_approx_equal(
-			InverseInductance::from_per_H(1e-06_f64).to_per_H(),
-			InverseInductance::from_per_MH(1.0_f64).to_per_H(), 9
+		assert_eq!(div_check(
+			&Rational64::from_f64(x).unwrap(), &InverseInductance{per_H: Rational64::from_f64(y).unwrap()}),
+				   Inductance{H: Rational64::from_f64(x).unwrap()/Rational64::from_f64(y).unwrap()}
 		);
-		assert_approx_equal(
-			InverseInductance::from_per_H(1.0_f64).to_per_H() * 1000000.0,
-			InverseInductance::from_per_H(1.0_f64).to_per_MH(), 9
+		assert_eq!(div_check(
+			&BigRational::from_f64(x).unwrap(), &InverseInductance{per_H: BigRational::from_f64(y).unwrap()}),
+				   Inductance{H: BigRational::from_f64(x).unwrap()/BigRational::from_f64(y).unwrap()}
 		);
-		assert_approx_equal(
-			InverseInductance::from_per_H(1e-09_f64).to_per_H(),
-			InverseInductance::from_per_GH(1.0_f64).to_per_H(), 9
+		assert_eq!(div_check(
+			&Rational64::from_f64(x).unwrap(), &InverseLuminousFlux{per_lm: Rational64::from_f64(y).unwrap()}),
+				   LuminousFlux{lm: Rational64::from_f64(x).unwrap()/Rational64::from_f64(y).unwrap()}
 		);
-		assert_approx_equal(
-			InverseInductance::from_per_H(1.0_f64).to_per_H() * 1000000000.0,
-			InverseInductance::from_per_H(1.0_f64).to_per_GH(), 9
+		assert_eq!(div_check(
+			&BigRational::from_f64(x).unwrap(), &InverseLuminousFlux{per_lm: BigRational::from_f64(y).unwrap()}),
+				   LuminousFlux{lm: BigRational::from_f64(x).unwrap()/BigRational::from_f64(y).unwrap()}
 		);
-	}
-
-	#[test]
-	fn inverse_luminous_flux_units() {
-		assert_approx_equal(
-			InverseLuminousFlux::from_per_lm(1000.0_f64).to_per_lm(),
-			InverseLuminousFlux::from_per_mlm(1.0_f64).to_per_lm(), 9
+		assert_eq!(div_check(
+			&Rational64::from_f64(x).unwrap(), &InverseMagneticFlux{per_Wb: Rational64::from_f64(y).unwrap()}),
+				   MagneticFlux{Wb: Rational64::from_f64(x).unwrap()/Rational64::from_f64(y).unwrap()}
 		);
-		assert_approx_equal(
-			InverseLuminousFlux::from_per_lm(1.0_f64).to_per_lm() * 0.001,
-			InverseLuminousFlux::from_per_lm(1.0_f64).to_per_mlm(), 9
+		assert_eq!(div_check(
+			&BigRational::from_f64(x).unwrap(), &InverseMagneticFlux{per_Wb: BigRational::from_f64(y).unwrap()}),
+				   MagneticFlux{Wb: BigRational::from_f64(x).unwrap()/BigRational::from_f64(y).unwrap()}
 		);
-		assert_approx_equal(
-			InverseLuminousFlux::from_per_lm(1000000.0_f64).to_per_lm(),
-			InverseLuminousFlux::from_per_ulm(1.0_f64).to_per_lm(), 9
+		assert_eq!(div_check(
+			&Rational64::from_f64(x).unwrap(), &InverseMagneticFluxDensity{m2_per_Wb: Rational64::from_f64(y).unwrap()}),
+				   MagneticFluxDensity{T: Rational64::from_f64(x).unwrap()/Rational64::from_f64(y).unwrap()}
 		);
-		assert_approx_equal(
-			InverseLuminousFlux::from_per_lm(1.0_f64).to_per_lm() * 1e-06,
-			InverseLuminousFlux::from_per_lm(1.0_f64).to_per_ulm(), 9
+		assert_eq!(div_check(
+			&BigRational::from_f64(x).unwrap(), &InverseMagneticFluxDensity{m2_per_Wb: BigRational::from_f64(y).unwrap()}),
+				   MagneticFluxDensity{T: BigRational::from_f64(x).unwrap()/BigRational::from_f64(y).unwrap()}
 		);
-		assert_approx_equal(
-			InverseLuminousFlux::from_per_lm(1000000000.0_f64).to_per_lm(),
-			InverseLuminousFlux::from_per_nlm(1.0_f64).to_per_lm(), 9
+		assert_eq!(div_check(
+			&Rational64::from_f64(x).unwrap(), &InverseVoltage{per_V: Rational64::from_f64(y).unwrap()}),
+				   Voltage{V: Rational64::from_f64(x).unwrap()/Rational64::from_f64(y).unwrap()}
 		);
-		assert_approx_equal(
-			InverseLuminousFlux::from_per_lm(1.0_f64).to_per_lm() * 1e-09,
-			InverseLuminousFlux::from_per_lm(1.0_f64).to_per_nlm(), 9
+		assert_eq!(div_check(
+			&BigRational::from_f64(x).unwrap(), &InverseVoltage{per_V: BigRational::from_f64(y).unwrap()}),
+				   Voltage{V: BigRational::from_f64(x).unwrap()/BigRational::from_f64(y).unwrap()}
 		);
-		assert_approx_equal(
-			InverseLuminousFlux::from_per_lm(0.001_f64).to_per_lm(),
-			InverseLuminousFlux::from_per_klm(1.0_f64).to_per_lm(), 9
+		assert_eq!(div_check(
+			&Rational64::from_f64(x).unwrap(), &LuminousFlux{lm: Rational64::from_f64(y).unwrap()}),
+				   InverseLuminousFlux{per_lm: Rational64::from_f64(x).unwrap()/Rational64::from_f64(y).unwrap()}
 		);
-		assert_approx_equal(
-			InverseLuminousFlux::from_per_lm(1.0_f64).to_per_lm() * 1000.0,
-			InverseLuminousFlux::from_per_lm(1.0_f64).to_per_klm(), 9
+		assert_eq!(div_check(
+			&BigRational::from_f64(x).unwrap(), &LuminousFlux{lm: BigRational::from_f64(y).unwrap()}),
+				   InverseLuminousFlux{per_lm: BigRational::from_f64(x).unwrap()/BigRational::from_f64(y).unwrap()}
 		);
-		assert_approx_equal(
-			InverseLuminousFlux::from_per_lm(1e-06_f64).to_per_lm(),
-			InverseLuminousFlux::from_per_Mlm(1.0_f64).to_per_lm(), 9
+		assert_eq!(div_check(
+			&Rational64::from_f64(x).unwrap(), &MagneticFlux{Wb: Rational64::from_f64(y).unwrap()}),
+				   InverseMagneticFlux{per_Wb: Rational64::from_f64(x).unwrap()/Rational64::from_f64(y).unwrap()}
 		);
-		assert_approx_equal(
-			InverseLuminousFlux::from_per_lm(1.0_f64).to_per_lm() * 1000000.0,
-			InverseLuminousFlux::from_per_lm(1.0_f64).to_per_Mlm(), 9
+		assert_eq!(div_check(
+			&BigRational::from_f64(x).unwrap(), &MagneticFlux{Wb: BigRational::from_f64(y).unwrap()}),
+				   InverseMagneticFlux{per_Wb: BigRational::from_f64(x).unwrap()/BigRational::from_f64(y).unwrap()}
 		);
-		assert_approx_equal(
-			InverseLuminousFlux::from_per_lm(1e-09_f64).to_per_lm(),
-			InverseLuminousFlux::from_per_Glm(1.0_f64).to_per_lm(), 9
+		assert_eq!(div_check(
+			&Rational64::from_f64(x).unwrap(), &MagneticFluxDensity{T: Rational64::from_f64(y).unwrap()}),
+				   InverseMagneticFluxDensity{m2_per_Wb: Rational64::from_f64(x).unwrap()/Rational64::from_f64(y).unwrap()}
 		);
-		assert_approx_equal(
-			InverseLuminousFlux::from_per_lm(1.0_f64).to_per_lm() * 1000000000.0,
-			InverseLuminousFlux::from_per_lm(1.0_f64).to_per_Glm(), 9
+		assert_eq!(div_check(
+			&BigRational::from_f64(x).unwrap(), &MagneticFluxDensity{T: BigRational::from_f64(y).unwrap()}),
+				   InverseMagneticFluxDensity{m2_per_Wb: BigRational::from_f64(x).unwrap()/BigRational::from_f64(y).unwrap()}
 		);
-	}
-
-	#[test]
-	fn inverse_magnetic_flux_units() {
-		assert_approx_equal(
-			InverseMagneticFlux::from_per_Wb(1000.0_f64).to_per_Wb(),
-			InverseMagneticFlux::from_per_mWb(1.0_f64).to_per_Wb(), 9
+		assert_eq!(div_check(
+			&Rational64::from_f64(x).unwrap(), &Resistance{Ohm: Rational64::from_f64(y).unwrap()}),
+				   Conductance{S: Rational64::from_f64(x).unwrap()/Rational64::from_f64(y).unwrap()}
 		);
-		assert_approx_equal(
-			InverseMagneticFlux::from_per_Wb(1.0_f64).to_per_Wb() * 0.001,
-			InverseMagneticFlux::from_per_Wb(1.0_f64).to_per_mWb(), 9
+		assert_eq!(div_check(
+			&BigRational::from_f64(x).unwrap(), &Resistance{Ohm: BigRational::from_f64(y).unwrap()}),
+				   Conductance{S: BigRational::from_f64(x).unwrap()/BigRational::from_f64(y).unwrap()}
 		);
-		assert_approx_equal(
-			InverseMagneticFlux::from_per_Wb(1000000.0_f64).to_per_Wb(),
-			InverseMagneticFlux::from_per_uWb(1.0_f64).to_per_Wb(), 9
+		assert_eq!(div_check(
+			&Rational64::from_f64(x).unwrap(), &Voltage{V: Rational64::from_f64(y).unwrap()}),
+				   InverseVoltage{per_V: Rational64::from_f64(x).unwrap()/Rational64::from_f64(y).unwrap()}
 		);
-		assert_approx_equal(
-			InverseMagneticFlux::from_per_Wb(1.0_f64).to_per_Wb() * 1e-06,
-			InverseMagneticFlux::from_per_Wb(1.0_f64).to_per_uWb(), 9
+		assert_eq!(div_check(
+			&BigRational::from_f64(x).unwrap(), &Voltage{V: BigRational::from_f64(y).unwrap()}),
+				   InverseVoltage{per_V: BigRational::from_f64(x).unwrap()/BigRational::from_f64(y).unwrap()}
 		);
-		assert_approx_equal(
-			InverseMagneticFlux::from_per_Wb(1000000000.0_f64).to_per_Wb(),
-			InverseMagneticFlux::from_per_nWb(1.0_f64).to_per_Wb(), 9
+		assert_eq!(div_check(
+			&Rational64::from_f64(x).unwrap(), &Angle{rad: Rational64::from_f64(y).unwrap()}),
+				   InverseAngle{per_rad: Rational64::from_f64(x).unwrap()/Rational64::from_f64(y).unwrap()}
 		);
-		assert_approx_equal(
-			InverseMagneticFlux::from_per_Wb(1.0_f64).to_per_Wb() * 1e-09,
-			InverseMagneticFlux::from_per_Wb(1.0_f64).to_per_nWb(), 9
+		assert_eq!(div_check(
+			&BigRational::from_f64(x).unwrap(), &Angle{rad: BigRational::from_f64(y).unwrap()}),
+				   InverseAngle{per_rad: BigRational::from_f64(x).unwrap()/BigRational::from_f64(y).unwrap()}
 		);
-		assert_approx_equal(
-			InverseMagneticFlux::from_per_Wb(0.001_f64).to_per_Wb(),
-			InverseMagneticFlux::from_per_kWb(1.0_f64).to_per_Wb(), 9
+		assert_eq!(div_check(
+			&Rational64::from_f64(x).unwrap(), &Area{m2: Rational64::from_f64(y).unwrap()}),
+				   InverseArea{per_m2: Rational64::from_f64(x).unwrap()/Rational64::from_f64(y).unwrap()}
 		);
-		assert_approx_equal(
-			InverseMagneticFlux::from_per_Wb(1.0_f64).to_per_Wb() * 1000.0,
-			InverseMagneticFlux::from_per_Wb(1.0_f64).to_per_kWb(), 9
+		assert_eq!(div_check(
+			&BigRational::from_f64(x).unwrap(), &Area{m2: BigRational::from_f64(y).unwrap()}),
+				   InverseArea{per_m2: BigRational::from_f64(x).unwrap()/BigRational::from_f64(y).unwrap()}
 		);
-		assert_approx_equal(
-			InverseMagneticFlux::from_per_Wb(1e-06_f64).to_per_Wb(),
-			InverseMagneticFlux::from_per_MWb(1.0_f64).to_per_Wb(), 9
+		assert_eq!(div_check(
+			&Rational64::from_f64(x).unwrap(), &InverseAngle{per_rad: Rational64::from_f64(y).unwrap()}),
+				   Angle{rad: Rational64::from_f64(x).unwrap()/Rational64::from_f64(y).unwrap()}
 		);
-		assert_approx_equal(
-			InverseMagneticFlux::from_per_Wb(1.0_f64).to_per_Wb() * 1000000.0,
-			InverseMagneticFlux::from_per_Wb(1.0_f64).to_per_MWb(), 9
+		assert_eq!(div_check(
+			&BigRational::from_f64(x).unwrap(), &InverseAngle{per_rad: BigRational::from_f64(y).unwrap()}),
+				   Angle{rad: BigRational::from_f64(x).unwrap()/BigRational::from_f64(y).unwrap()}
 		);
-		assert_approx_equal(
-			InverseMagneticFlux::from_per_Wb(1e-09_f64).to_per_Wb(),
-			InverseMagneticFlux::from_per_GWb(1.0_f64).to_per_Wb(), 9
+		assert_eq!(div_check(
+			&Rational64::from_f64(x).unwrap(), &InverseArea{per_m2: Rational64::from_f64(y).unwrap()}),
+				   Area{m2: Rational64::from_f64(x).unwrap()/Rational64::from_f64(y).unwrap()}
 		);
-		assert_approx_equal(
-			InverseMagneticFlux::from_per_Wb(1.0_f64).to_per_Wb() * 1000000000.0,
-			InverseMagneticFlux::from_per_Wb(1.0_f64).to_per_GWb(), 9
+		assert_eq!(div_check(
+			&BigRational::from_f64(x).unwrap(), &InverseArea{per_m2: BigRational::from_f64(y).unwrap()}),
+				   Area{m2: BigRational::from_f64(x).unwrap()/BigRational::from_f64(y).unwrap()}
 		);
-	}
-
-	#[test]
-	fn inverse_voltage_units() {
-		assert_approx_equal(
-			InverseVoltage::from_per_V(1000.0_f64).to_per_V(),
-			InverseVoltage::from_per_mV(1.0_f64).to_per_V(), 9
+		assert_eq!(div_check(
+			&Rational64::from_f64(x).unwrap(), &InverseSolidAngle{per_sr: Rational64::from_f64(y).unwrap()}),
+				   SolidAngle{sr: Rational64::from_f64(x).unwrap()/Rational64::from_f64(y).unwrap()}
 		);
-		assert_approx_equal(
-			InverseVoltage::from_per_V(1.0_f64).to_per_V() * 0.001,
-			InverseVoltage::from_per_V(1.0_f64).to_per_mV(), 9
+		assert_eq!(div_check(
+			&BigRational::from_f64(x).unwrap(), &InverseSolidAngle{per_sr: BigRational::from_f64(y).unwrap()}),
+				   SolidAngle{sr: BigRational::from_f64(x).unwrap()/BigRational::from_f64(y).unwrap()}
 		);
-		assert_approx_equal(
-			InverseVoltage::from_per_V(1000000.0_f64).to_per_V(),
-			InverseVoltage::from_per_uV(1.0_f64).to_per_V(), 9
+		assert_eq!(div_check(
+			&Rational64::from_f64(x).unwrap(), &InverseVolume{per_m3: Rational64::from_f64(y).unwrap()}),
+				   Volume{m3: Rational64::from_f64(x).unwrap()/Rational64::from_f64(y).unwrap()}
 		);
-		assert_approx_equal(
-			InverseVoltage::from_per_V(1.0_f64).to_per_V() * 1e-06,
-			InverseVoltage::from_per_V(1.0_f64).to_per_uV(), 9
+		assert_eq!(div_check(
+			&BigRational::from_f64(x).unwrap(), &InverseVolume{per_m3: BigRational::from_f64(y).unwrap()}),
+				   Volume{m3: BigRational::from_f64(x).unwrap()/BigRational::from_f64(y).unwrap()}
 		);
-		assert_approx_equal(
-			InverseVoltage::from_per_V(1000000000.0_f64).to_per_V(),
-			InverseVoltage::from_per_nV(1.0_f64).to_per_V(), 9
+		assert_eq!(div_check(
+			&Rational64::from_f64(x).unwrap(), &SolidAngle{sr: Rational64::from_f64(y).unwrap()}),
+				   InverseSolidAngle{per_sr: Rational64::from_f64(x).unwrap()/Rational64::from_f64(y).unwrap()}
 		);
-		assert_approx_equal(
-			InverseVoltage::from_per_V(1.0_f64).to_per_V() * 1e-09,
-			InverseVoltage::from_per_V(1.0_f64).to_per_nV(), 9
+		assert_eq!(div_check(
+			&BigRational::from_f64(x).unwrap(), &SolidAngle{sr: BigRational::from_f64(y).unwrap()}),
+				   InverseSolidAngle{per_sr: BigRational::from_f64(x).unwrap()/BigRational::from_f64(y).unwrap()}
 		);
-		assert_approx_equal(
-			InverseVoltage::from_per_V(0.001_f64).to_per_V(),
-			InverseVoltage::from_per_kV(1.0_f64).to_per_V(), 9
+		assert_eq!(div_check(
+			&Rational64::from_f64(x).unwrap(), &Volume{m3: Rational64::from_f64(y).unwrap()}),
+				   InverseVolume{per_m3: Rational64::from_f64(x).unwrap()/Rational64::from_f64(y).unwrap()}
 		);
-		assert_approx_equal(
-			InverseVoltage::from_per_V(1.0_f64).to_per_V() * 1000.0,
-			InverseVoltage::from_per_V(1.0_f64).to_per_kV(), 9
+		assert_eq!(div_check(
+			&BigRational::from_f64(x).unwrap(), &Volume{m3: BigRational::from_f64(y).unwrap()}),
+				   InverseVolume{per_m3: BigRational::from_f64(x).unwrap()/BigRational::from_f64(y).unwrap()}
 		);
-		assert_approx_equal(
-			InverseVoltage::from_per_V(1e-06_f64).to_per_V(),
-			InverseVoltage::from_per_MV(1.0_f64).to_per_V(), 9
+		assert_eq!(div_check(
+			&Rational64::from_f64(x).unwrap(), &Acceleration{mps2: Rational64::from_f64(y).unwrap()}),
+				   InverseAcceleration{s2pm: Rational64::from_f64(x).unwrap()/Rational64::from_f64(y).unwrap()}
 		);
-		assert_approx_equal(
-			InverseVoltage::from_per_V(1.0_f64).to_per_V() * 1000000.0,
-			InverseVoltage::from_per_V(1.0_f64).to_per_MV(), 9
+		assert_eq!(div_check(
+			&BigRational::from_f64(x).unwrap(), &Acceleration{mps2: BigRational::from_f64(y).unwrap()}),
+				   InverseAcceleration{s2pm: BigRational::from_f64(x).unwrap()/BigRational::from_f64(y).unwrap()}
 		);
-		assert_approx_equal(
-			InverseVoltage::from_per_V(1e-09_f64).to_per_V(),
-			InverseVoltage::from_per_GV(1.0_f64).to_per_V(), 9
+		assert_eq!(div_check(
+			&Rational64::from_f64(x).unwrap(), &AngularAcceleration{radps2: Rational64::from_f64(y).unwrap()}),
+				   InverseAngularAcceleration{s2prad: Rational64::from_f64(x).unwrap()/Rational64::from_f64(y).unwrap()}
 		);
-		assert_approx_equal(
-			InverseVoltage::from_per_V(1.0_f64).to_per_V() * 1000000000.0,
-			InverseVoltage::from_per_V(1.0_f64).to_per_GV(), 9
+		assert_eq!(div_check(
+			&BigRational::from_f64(x).unwrap(), &AngularAcceleration{radps2: BigRational::from_f64(y).unwrap()}),
+				   InverseAngularAcceleration{s2prad: BigRational::from_f64(x).unwrap()/BigRational::from_f64(y).unwrap()}
 		);
-	}
-
-	#[test]
-	fn inverse_angle_units() {
-		assert_approx_equal(
-			InverseAngle::from_per_rad(57.2957795130823_f64).to_per_rad(),
-			InverseAngle::from_per_degrees(1.0_f64).to_per_rad(), 9
+		assert_eq!(div_check(
+			&Rational64::from_f64(x).unwrap(), &AngularMomentum{kgm2radps: Rational64::from_f64(y).unwrap()}),
+				   InverseAngularMomentum{s_per_kgm2rad: Rational64::from_f64(x).unwrap()/Rational64::from_f64(y).unwrap()}
 		);
-		assert_approx_equal(
-			InverseAngle::from_per_rad(1.0_f64).to_per_rad() * 0.0174532925199433,
-			InverseAngle::from_per_rad(1.0_f64).to_per_degrees(), 9
+		assert_eq!(div_check(
+			&BigRational::from_f64(x).unwrap(), &AngularMomentum{kgm2radps: BigRational::from_f64(y).unwrap()}),
+				   InverseAngularMomentum{s_per_kgm2rad: BigRational::from_f64(x).unwrap()/BigRational::from_f64(y).unwrap()}
 		);
-		assert_approx_equal(
-			InverseAngle::from_per_rad(57.2957795130823_f64).to_per_rad(),
-			InverseAngle::from_per_deg(1.0_f64).to_per_rad(), 9
+		assert_eq!(div_check(
+			&Rational64::from_f64(x).unwrap(), &AngularVelocity{radps: Rational64::from_f64(y).unwrap()}),
+				   InverseAngularVelocity{s_per_rad: Rational64::from_f64(x).unwrap()/Rational64::from_f64(y).unwrap()}
 		);
-		assert_approx_equal(
-			InverseAngle::from_per_rad(1.0_f64).to_per_rad() * 0.0174532925199433,
-			InverseAngle::from_per_rad(1.0_f64).to_per_deg(), 9
+		assert_eq!(div_check(
+			&BigRational::from_f64(x).unwrap(), &AngularVelocity{radps: BigRational::from_f64(y).unwrap()}),
+				   InverseAngularVelocity{s_per_rad: BigRational::from_f64(x).unwrap()/BigRational::from_f64(y).unwrap()}
 		);
-	}
-
-	#[test]
-	fn inverse_area_units() {
-		assert_approx_equal(
-			InverseArea::from_per_m2(10000.0_f64).to_per_m2(),
-			InverseArea::from_per_cm2(1.0_f64).to_per_m2(), 9
+		assert_eq!(div_check(
+			&Rational64::from_f64(x).unwrap(), &AreaDensity{kgpm2: Rational64::from_f64(y).unwrap()}),
+				   AreaPerMass{m2_per_kg: Rational64::from_f64(x).unwrap()/Rational64::from_f64(y).unwrap()}
 		);
-		assert_approx_equal(
-			InverseArea::from_per_m2(1.0_f64).to_per_m2() * 0.0001,
-			InverseArea::from_per_m2(1.0_f64).to_per_cm2(), 9
+		assert_eq!(div_check(
+			&BigRational::from_f64(x).unwrap(), &AreaDensity{kgpm2: BigRational::from_f64(y).unwrap()}),
+				   AreaPerMass{m2_per_kg: BigRational::from_f64(x).unwrap()/BigRational::from_f64(y).unwrap()}
 		);
-		assert_approx_equal(
-			InverseArea::from_per_m2(10000.0_f64).to_per_m2(),
-			InverseArea::from_per_square_cm(1.0_f64).to_per_m2(), 9
+		assert_eq!(div_check(
+			&Rational64::from_f64(x).unwrap(), &AreaPerMass{m2_per_kg: Rational64::from_f64(y).unwrap()}),
+				   AreaDensity{kgpm2: Rational64::from_f64(x).unwrap()/Rational64::from_f64(y).unwrap()}
 		);
-		assert_approx_equal(
-			InverseArea::from_per_m2(1.0_f64).to_per_m2() * 0.0001,
-			InverseArea::from_per_m2(1.0_f64).to_per_square_cm(), 9
+		assert_eq!(div_check(
+			&BigRational::from_f64(x).unwrap(), &AreaPerMass{m2_per_kg: BigRational::from_f64(y).unwrap()}),
+				   AreaDensity{kgpm2: BigRational::from_f64(x).unwrap()/BigRational::from_f64(y).unwrap()}
 		);
-		assert_approx_equal(
-			InverseArea::from_per_m2(1000000.0_f64).to_per_m2(),
-			InverseArea::from_per_mm2(1.0_f64).to_per_m2(), 9
+		assert_eq!(div_check(
+			&Rational64::from_f64(x).unwrap(), &Density{kgpm3: Rational64::from_f64(y).unwrap()}),
+				   VolumePerMass{m3_per_kg: Rational64::from_f64(x).unwrap()/Rational64::from_f64(y).unwrap()}
 		);
-		assert_approx_equal(
-			InverseArea::from_per_m2(1.0_f64).to_per_m2() * 1e-06,
-			InverseArea::from_per_m2(1.0_f64).to_per_mm2(), 9
+		assert_eq!(div_check(
+			&BigRational::from_f64(x).unwrap(), &Density{kgpm3: BigRational::from_f64(y).unwrap()}),
+				   VolumePerMass{m3_per_kg: BigRational::from_f64(x).unwrap()/BigRational::from_f64(y).unwrap()}
 		);
-		assert_approx_equal(
-			InverseArea::from_per_m2(1000000000000.0_f64).to_per_m2(),
-			InverseArea::from_per_um2(1.0_f64).to_per_m2(), 9
+		assert_eq!(div_check(
+			&Rational64::from_f64(x).unwrap(), &Energy{J: Rational64::from_f64(y).unwrap()}),
+				   InverseEnergy{per_J: Rational64::from_f64(x).unwrap()/Rational64::from_f64(y).unwrap()}
 		);
-		assert_approx_equal(
-			InverseArea::from_per_m2(1.0_f64).to_per_m2() * 1e-12,
-			InverseArea::from_per_m2(1.0_f64).to_per_um2(), 9
+		assert_eq!(div_check(
+			&BigRational::from_f64(x).unwrap(), &Energy{J: BigRational::from_f64(y).unwrap()}),
+				   InverseEnergy{per_J: BigRational::from_f64(x).unwrap()/BigRational::from_f64(y).unwrap()}
 		);
-		assert_approx_equal(
-			InverseArea::from_per_m2(1e+18_f64).to_per_m2(),
-			InverseArea::from_per_nm2(1.0_f64).to_per_m2(), 9
+		assert_eq!(div_check(
+			&Rational64::from_f64(x).unwrap(), &Force{N: Rational64::from_f64(y).unwrap()}),
+				   InverseForce{per_N: Rational64::from_f64(x).unwrap()/Rational64::from_f64(y).unwrap()}
 		);
-		assert_approx_equal(
-			InverseArea::from_per_m2(1.0_f64).to_per_m2() * 1e-18,
-			InverseArea::from_per_m2(1.0_f64).to_per_nm2(), 9
+		assert_eq!(div_check(
+			&BigRational::from_f64(x).unwrap(), &Force{N: BigRational::from_f64(y).unwrap()}),
+				   InverseForce{per_N: BigRational::from_f64(x).unwrap()/BigRational::from_f64(y).unwrap()}
 		);
-		assert_approx_equal(
-			InverseArea::from_per_m2(1e-06_f64).to_per_m2(),
-			InverseArea::from_per_km2(1.0_f64).to_per_m2(), 9
+		assert_eq!(div_check(
+			&Rational64::from_f64(x).unwrap(), &Frequency{Hz: Rational64::from_f64(y).unwrap()}),
+				   Time{s: Rational64::from_f64(x).unwrap()/Rational64::from_f64(y).unwrap()}
 		);
-		assert_approx_equal(
-			InverseArea::from_per_m2(1.0_f64).to_per_m2() * 1000000.0,
-			InverseArea::from_per_m2(1.0_f64).to_per_km2(), 9
+		assert_eq!(div_check(
+			&BigRational::from_f64(x).unwrap(), &Frequency{Hz: BigRational::from_f64(y).unwrap()}),
+				   Time{s: BigRational::from_f64(x).unwrap()/BigRational::from_f64(y).unwrap()}
 		);
-	}
-
-	#[test]
-	fn inverse_volume_units() {
-		assert_approx_equal(
-			InverseVolume::from_per_m3(1000000.0_f64).to_per_m3(),
-			InverseVolume::from_per_cc(1.0_f64).to_per_m3(), 9
+		assert_eq!(div_check(
+			&Rational64::from_f64(x).unwrap(), &InverseAcceleration{s2pm: Rational64::from_f64(y).unwrap()}),
+				   Acceleration{mps2: Rational64::from_f64(x).unwrap()/Rational64::from_f64(y).unwrap()}
 		);
-		assert_approx_equal(
-			InverseVolume::from_per_m3(1.0_f64).to_per_m3() * 1e-06,
-			InverseVolume::from_per_m3(1.0_f64).to_per_cc(), 9
+		assert_eq!(div_check(
+			&BigRational::from_f64(x).unwrap(), &InverseAcceleration{s2pm: BigRational::from_f64(y).unwrap()}),
+				   Acceleration{mps2: BigRational::from_f64(x).unwrap()/BigRational::from_f64(y).unwrap()}
 		);
-		assert_approx_equal(
-			InverseVolume::from_per_m3(1000.0_f64).to_per_m3(),
-			InverseVolume::from_per_L(1.0_f64).to_per_m3(), 9
+		assert_eq!(div_check(
+			&Rational64::from_f64(x).unwrap(), &InverseAngularAcceleration{s2prad: Rational64::from_f64(y).unwrap()}),
+				   AngularAcceleration{radps2: Rational64::from_f64(x).unwrap()/Rational64::from_f64(y).unwrap()}
 		);
-		assert_approx_equal(
-			InverseVolume::from_per_m3(1.0_f64).to_per_m3() * 0.001,
-			InverseVolume::from_per_m3(1.0_f64).to_per_L(), 9
+		assert_eq!(div_check(
+			&BigRational::from_f64(x).unwrap(), &InverseAngularAcceleration{s2prad: BigRational::from_f64(y).unwrap()}),
+				   AngularAcceleration{radps2: BigRational::from_f64(x).unwrap()/BigRational::from_f64(y).unwrap()}
 		);
-		assert_approx_equal(
-			InverseVolume::from_per_m3(1000.0_f64).to_per_m3(),
-			InverseVolume::from_per_liters(1.0_f64).to_per_m3(), 9
+		assert_eq!(div_check(
+			&Rational64::from_f64(x).unwrap(), &InverseAngularMomentum{s_per_kgm2rad: Rational64::from_f64(y).unwrap()}),
+				   AngularMomentum{kgm2radps: Rational64::from_f64(x).unwrap()/Rational64::from_f64(y).unwrap()}
 		);
-		assert_approx_equal(
-			InverseVolume::from_per_m3(1.0_f64).to_per_m3() * 0.001,
-			InverseVolume::from_per_m3(1.0_f64).to_per_liters(), 9
+		assert_eq!(div_check(
+			&BigRational::from_f64(x).unwrap(), &InverseAngularMomentum{s_per_kgm2rad: BigRational::from_f64(y).unwrap()}),
+				   AngularMomentum{kgm2radps: BigRational::from_f64(x).unwrap()/BigRational::from_f64(y).unwrap()}
 		);
-		assert_approx_equal(
-			InverseVolume::from_per_m3(1000000.0_f64).to_per_m3(),
-			InverseVolume::from_per_mL(1.0_f64).to_per_m3(), 9
+		assert_eq!(div_check(
+			&Rational64::from_f64(x).unwrap(), &InverseAngularVelocity{s_per_rad: Rational64::from_f64(y).unwrap()}),
+				   AngularVelocity{radps: Rational64::from_f64(x).unwrap()/Rational64::from_f64(y).unwrap()}
 		);
-		assert_approx_equal(
-			InverseVolume::from_per_m3(1.0_f64).to_per_m3() * 1e-06,
-			InverseVolume::from_per_m3(1.0_f64).to_per_mL(), 9
+		assert_eq!(div_check(
+			&BigRational::from_f64(x).unwrap(), &InverseAngularVelocity{s_per_rad: BigRational::from_f64(y).unwrap()}),
+				   AngularVelocity{radps: BigRational::from_f64(x).unwrap()/BigRational::from_f64(y).unwrap()}
 		);
-		assert_approx_equal(
-			InverseVolume::from_per_m3(1000000000.0_f64).to_per_m3(),
-			InverseVolume::from_per_uL(1.0_f64).to_per_m3(), 9
+		assert_eq!(div_check(
+			&Rational64::from_f64(x).unwrap(), &InverseEnergy{per_J: Rational64::from_f64(y).unwrap()}),
+				   Energy{J: Rational64::from_f64(x).unwrap()/Rational64::from_f64(y).unwrap()}
 		);
-		assert_approx_equal(
-			InverseVolume::from_per_m3(1.0_f64).to_per_m3() * 1e-09,
-			InverseVolume::from_per_m3(1.0_f64).to_per_uL(), 9
+		assert_eq!(div_check(
+			&BigRational::from_f64(x).unwrap(), &InverseEnergy{per_J: BigRational::from_f64(y).unwrap()}),
+				   Energy{J: BigRational::from_f64(x).unwrap()/BigRational::from_f64(y).unwrap()}
 		);
-		assert_approx_equal(
-			InverseVolume::from_per_m3(1000000000000.0_f64).to_per_m3(),
-			InverseVolume::from_per_nL(1.0_f64).to_per_m3(), 9
+		assert_eq!(div_check(
+			&Rational64::from_f64(x).unwrap(), &InverseForce{per_N: Rational64::from_f64(y).unwrap()}),
+				   Force{N: Rational64::from_f64(x).unwrap()/Rational64::from_f64(y).unwrap()}
 		);
-		assert_approx_equal(
-			InverseVolume::from_per_m3(1.0_f64).to_per_m3() * 1e-12,
-			InverseVolume::from_per_m3(1.0_f64).to_per_nL(), 9
+		assert_eq!(div_check(
+			&BigRational::from_f64(x).unwrap(), &InverseForce{per_N: BigRational::from_f64(y).unwrap()}),
+				   Force{N: BigRational::from_f64(x).unwrap()/BigRational::from_f64(y).unwrap()}
 		);
-		assert_approx_equal(
-			InverseVolume::from_per_m3(1000000000000000.0_f64).to_per_m3(),
-			InverseVolume::from_per_pL(1.0_f64).to_per_m3(), 9
+		assert_eq!(div_check(
+			&Rational64::from_f64(x).unwrap(), &InverseMomentum{s_per_kgm: Rational64::from_f64(y).unwrap()}),
+				   Momentum{kgmps: Rational64::from_f64(x).unwrap()/Rational64::from_f64(y).unwrap()}
 		);
-		assert_approx_equal(
-			InverseVolume::from_per_m3(1.0_f64).to_per_m3() * 1e-15,
-			InverseVolume::from_per_m3(1.0_f64).to_per_pL(), 9
+		assert_eq!(div_check(
+			&BigRational::from_f64(x).unwrap(), &InverseMomentum{s_per_kgm: BigRational::from_f64(y).unwrap()}),
+				   Momentum{kgmps: BigRational::from_f64(x).unwrap()/BigRational::from_f64(y).unwrap()}
 		);
-		assert_approx_equal(
-			InverseVolume::from_per_m3(0.001_f64).to_per_m3(),
-			InverseVolume::from_per_ML(1.0_f64).to_per_m3(), 9
+		assert_eq!(div_check(
+			&Rational64::from_f64(x).unwrap(), &InversePower{per_W: Rational64::from_f64(y).unwrap()}),
+				   Power{W: Rational64::from_f64(x).unwrap()/Rational64::from_f64(y).unwrap()}
 		);
-		assert_approx_equal(
-			InverseVolume::from_per_m3(1.0_f64).to_per_m3() * 1000.0,
-			InverseVolume::from_per_m3(1.0_f64).to_per_ML(), 9
+		assert_eq!(div_check(
+			&BigRational::from_f64(x).unwrap(), &InversePower{per_W: BigRational::from_f64(y).unwrap()}),
+				   Power{W: BigRational::from_f64(x).unwrap()/BigRational::from_f64(y).unwrap()}
 		);
-		assert_approx_equal(
-			InverseVolume::from_per_m3(1e-06_f64).to_per_m3(),
-			InverseVolume::from_per_GL(1.0_f64).to_per_m3(), 9
+		assert_eq!(div_check(
+			&Rational64::from_f64(x).unwrap(), &InversePressure{per_Pa: Rational64::from_f64(y).unwrap()}),
+				   Pressure{Pa: Rational64::from_f64(x).unwrap()/Rational64::from_f64(y).unwrap()}
 		);
-		assert_approx_equal(
-			InverseVolume::from_per_m3(1.0_f64).to_per_m3() * 1000000.0,
-			InverseVolume::from_per_m3(1.0_f64).to_per_GL(), 9
+		assert_eq!(div_check(
+			&BigRational::from_f64(x).unwrap(), &InversePressure{per_Pa: BigRational::from_f64(y).unwrap()}),
+				   Pressure{Pa: BigRational::from_f64(x).unwrap()/BigRational::from_f64(y).unwrap()}
 		);
-	}
-
-	#[test]
-	fn area_per_mass_units() {
-		assert_approx_equal(
-			AreaPerMass::from_m2_per_kg(1000.0_f64).to_m2_per_kg(),
-			AreaPerMass::from_m2_per_g(1.0_f64).to_m2_per_kg(), 9
+		assert_eq!(div_check(
+			&Rational64::from_f64(x).unwrap(), &InverseTorque{per_Nm: Rational64::from_f64(y).unwrap()}),
+				   Energy{J: Rational64::from_f64(x).unwrap()/Rational64::from_f64(y).unwrap()}
 		);
-		assert_approx_equal(
-			AreaPerMass::from_m2_per_kg(1.0_f64).to_m2_per_kg() * 0.001,
-			AreaPerMass::from_m2_per_kg(1.0_f64).to_m2_per_g(), 9
+		assert_eq!(div_check(
+			&BigRational::from_f64(x).unwrap(), &InverseTorque{per_Nm: BigRational::from_f64(y).unwrap()}),
+				   Energy{J: BigRational::from_f64(x).unwrap()/BigRational::from_f64(y).unwrap()}
 		);
-		assert_approx_equal(
-			AreaPerMass::from_m2_per_kg(1000.0_f64).to_m2_per_kg(),
-			AreaPerMass::from_square_meters_per_gram(1.0_f64).to_m2_per_kg(), 9
+		assert_eq!(div_check(
+			&Rational64::from_f64(x).unwrap(), &Momentum{kgmps: Rational64::from_f64(y).unwrap()}),
+				   InverseMomentum{s_per_kgm: Rational64::from_f64(x).unwrap()/Rational64::from_f64(y).unwrap()}
 		);
-		assert_approx_equal(
-			AreaPerMass::from_m2_per_kg(1.0_f64).to_m2_per_kg() * 0.001,
-			AreaPerMass::from_m2_per_kg(1.0_f64).to_square_meters_per_gram(), 9
+		assert_eq!(div_check(
+			&BigRational::from_f64(x).unwrap(), &Momentum{kgmps: BigRational::from_f64(y).unwrap()}),
+				   InverseMomentum{s_per_kgm: BigRational::from_f64(x).unwrap()/BigRational::from_f64(y).unwrap()}
 		);
-		assert_approx_equal(
-			AreaPerMass::from_m2_per_kg(0.1_f64).to_m2_per_kg(),
-			AreaPerMass::from_cm2_per_g(1.0_f64).to_m2_per_kg(), 9
+		assert_eq!(div_check(
+			&Rational64::from_f64(x).unwrap(), &Power{W: Rational64::from_f64(y).unwrap()}),
+				   InversePower{per_W: Rational64::from_f64(x).unwrap()/Rational64::from_f64(y).unwrap()}
 		);
-		assert_approx_equal(
-			AreaPerMass::from_m2_per_kg(1.0_f64).to_m2_per_kg() * 10.0,
-			AreaPerMass::from_m2_per_kg(1.0_f64).to_cm2_per_g(), 9
+		assert_eq!(div_check(
+			&BigRational::from_f64(x).unwrap(), &Power{W: BigRational::from_f64(y).unwrap()}),
+				   InversePower{per_W: BigRational::from_f64(x).unwrap()/BigRational::from_f64(y).unwrap()}
 		);
-		assert_approx_equal(
-			AreaPerMass::from_m2_per_kg(0.1_f64).to_m2_per_kg(),
-			AreaPerMass::from_square_centimeters_per_gram(1.0_f64).to_m2_per_kg(), 9
+		assert_eq!(div_check(
+			&Rational64::from_f64(x).unwrap(), &Pressure{Pa: Rational64::from_f64(y).unwrap()}),
+				   InversePressure{per_Pa: Rational64::from_f64(x).unwrap()/Rational64::from_f64(y).unwrap()}
 		);
-		assert_approx_equal(
-			AreaPerMass::from_m2_per_kg(1.0_f64).to_m2_per_kg() * 10.0,
-			AreaPerMass::from_m2_per_kg(1.0_f64).to_square_centimeters_per_gram(), 9
+		assert_eq!(div_check(
+			&BigRational::from_f64(x).unwrap(), &Pressure{Pa: BigRational::from_f64(y).unwrap()}),
+				   InversePressure{per_Pa: BigRational::from_f64(x).unwrap()/BigRational::from_f64(y).unwrap()}
 		);
-	}
-
-	#[test]
-	fn inverse_acceleration_units() {
-		assert_approx_equal(
-			InverseAcceleration::from_s2pm(1000.0_f64).to_s2pm(),
-			InverseAcceleration::from_s2pmm(1.0_f64).to_s2pm(), 9
+		assert_eq!(div_check(
+			&Rational64::from_f64(x).unwrap(), &TimePerDistance{spm: Rational64::from_f64(y).unwrap()}),
+				   Velocity{mps: Rational64::from_f64(x).unwrap()/Rational64::from_f64(y).unwrap()}
 		);
-		assert_approx_equal(
-			InverseAcceleration::from_s2pm(1.0_f64).to_s2pm() * 0.001,
-			InverseAcceleration::from_s2pm(1.0_f64).to_s2pmm(), 9
+		assert_eq!(div_check(
+			&BigRational::from_f64(x).unwrap(), &TimePerDistance{spm: BigRational::from_f64(y).unwrap()}),
+				   Velocity{mps: BigRational::from_f64(x).unwrap()/BigRational::from_f64(y).unwrap()}
 		);
-		assert_approx_equal(
-			InverseAcceleration::from_s2pm(1000000.0_f64).to_s2pm(),
-			InverseAcceleration::from_hours_squared_per_kilometers(1.0_f64).to_s2pm(), 9
+		assert_eq!(div_check(
+			&Rational64::from_f64(x).unwrap(), &Torque{Nm: Rational64::from_f64(y).unwrap()}),
+				   InverseEnergy{per_J: Rational64::from_f64(x).unwrap()/Rational64::from_f64(y).unwrap()}
 		);
-		assert_approx_equal(
-			InverseAcceleration::from_s2pm(1.0_f64).to_s2pm() * 1e-06,
-			InverseAcceleration::from_s2pm(1.0_f64).to_hours_squared_per_kilometers(), 9
+		assert_eq!(div_check(
+			&BigRational::from_f64(x).unwrap(), &Torque{Nm: BigRational::from_f64(y).unwrap()}),
+				   InverseEnergy{per_J: BigRational::from_f64(x).unwrap()/BigRational::from_f64(y).unwrap()}
 		);
-		assert_approx_equal(
-			InverseAcceleration::from_s2pm(12960.0_f64).to_s2pm(),
-			InverseAcceleration::from_hr2_per_km(1.0_f64).to_s2pm(), 9
+		assert_eq!(div_check(
+			&Rational64::from_f64(x).unwrap(), &Velocity{mps: Rational64::from_f64(y).unwrap()}),
+				   TimePerDistance{spm: Rational64::from_f64(x).unwrap()/Rational64::from_f64(y).unwrap()}
 		);
-		assert_approx_equal(
-			InverseAcceleration::from_s2pm(1.0_f64).to_s2pm() * 7.72e-05,
-			InverseAcceleration::from_s2pm(1.0_f64).to_hr2_per_km(), 9
+		assert_eq!(div_check(
+			&BigRational::from_f64(x).unwrap(), &Velocity{mps: BigRational::from_f64(y).unwrap()}),
+				   TimePerDistance{spm: BigRational::from_f64(x).unwrap()/BigRational::from_f64(y).unwrap()}
 		);
-	}
-
-	#[test]
-	fn inverse_angular_acceleration_units() {
-		assert_approx_equal(
-			InverseAngularAcceleration::from_s2prad(57.2957795130823_f64).to_s2prad(),
-			InverseAngularAcceleration::from_seconds_squared_per_degree(1.0_f64).to_s2prad(), 9
+		assert_eq!(div_check(
+			&Rational64::from_f64(x).unwrap(), &VolumePerMass{m3_per_kg: Rational64::from_f64(y).unwrap()}),
+				   Density{kgpm3: Rational64::from_f64(x).unwrap()/Rational64::from_f64(y).unwrap()}
 		);
-		assert_approx_equal(
-			InverseAngularAcceleration::from_s2prad(1.0_f64).to_s2prad() * 0.0174532925199433,
-			InverseAngularAcceleration::from_s2prad(1.0_f64).to_seconds_squared_per_degree(), 9
+		assert_eq!(div_check(
+			&BigRational::from_f64(x).unwrap(), &VolumePerMass{m3_per_kg: BigRational::from_f64(y).unwrap()}),
+				   Density{kgpm3: BigRational::from_f64(x).unwrap()/BigRational::from_f64(y).unwrap()}
 		);
-	}
-
-	#[test]
-	fn inverse_angular_momentum_units() {
-		assert_approx_equal(
-			InverseAngularMomentum::from_s_per_kgm2rad(10000000.0_f64).to_s_per_kgm2rad(),
-			InverseAngularMomentum::from_s_per_gcm2rad(1.0_f64).to_s_per_kgm2rad(), 9
+		assert_eq!(div_check(
+			&Rational64::from_f64(x).unwrap(), &Radioactivity{Bq: Rational64::from_f64(y).unwrap()}),
+				   Time{s: Rational64::from_f64(x).unwrap()/Rational64::from_f64(y).unwrap()}
 		);
-		assert_approx_equal(
-			InverseAngularMomentum::from_s_per_kgm2rad(1.0_f64).to_s_per_kgm2rad() * 1e-07,
-			InverseAngularMomentum::from_s_per_kgm2rad(1.0_f64).to_s_per_gcm2rad(), 9
+		assert_eq!(div_check(
+			&BigRational::from_f64(x).unwrap(), &Radioactivity{Bq: BigRational::from_f64(y).unwrap()}),
+				   Time{s: BigRational::from_f64(x).unwrap()/BigRational::from_f64(y).unwrap()}
 		);
 	}
 
 	#[test]
-	fn inverse_angular_velocity_units() {
+	fn dynamic_viscosity_units() {
 		assert_approx_equal(
-			InverseAngularVelocity::from_s_per_rad(57.2957795130823_f64).to_s_per_rad(),
-			InverseAngularVelocity::from_seconds_per_degree(1.0_f64).to_s_per_rad(), 9
+			DynamicViscosity::from_Pas(0.001_f64).to_Pas(),
+			DynamicViscosity::from_mPas(1.0_f64).to_Pas(), 9
 		);
 		assert_approx_equal(
-			InverseAngularVelocity::from_s_per_rad(1.0_f64).to_s_per_rad() * 0.0174532925199433,
-			InverseAngularVelocity::from_s_per_rad(1.0_f64).to_seconds_per_degree(), 9
+			DynamicViscosity::from_Pas(1.0_f64).to_Pas() * 1000.0,
+			DynamicViscosity::from_Pas(1.0_f64).to_mPas(), 9
 		);
 		assert_approx_equal(
-			InverseAngularVelocity::from_s_per_rad(57.2957795130823_f64).to_s_per_rad(),
-			InverseAngularVelocity::from_s_per_deg(1.0_f64).to_s_per_rad(), 9
+			DynamicViscosity::from_Pas(0.1_f64).to_Pas(),
+			DynamicViscosity::from_P(1.0_f64).to_Pas(), 9
 		);
 		assert_approx_equal(
-			InverseAngularVelocity::from_s_per_rad(1.0_f64).to_s_per_rad() * 0.0174532925199433,
-			InverseAngularVelocity::from_s_per_rad(1.0_f64).to_s_per_deg(), 9
+			DynamicViscosity::from_Pas(1.0_f64).to_Pas() * 10.0,
+			DynamicViscosity::from_Pas(1.0_f64).to_P(), 9
 		);
 		assert_approx_equal(
-			InverseAngularVelocity::from_s_per_rad(0.159154943091895_f64).to_s_per_rad(),
-			InverseAngularVelocity::from_spr(1.0_f64).to_s_per_rad(), 9
+			DynamicViscosity::from_Pas(0.1_f64).to_Pas(),
+			DynamicViscosity::from_poise(1.0_f64).to_Pas(), 9
 		);
 		assert_approx_equal(
-			InverseAngularVelocity::from_s_per_rad(1.0_f64).to_s_per_rad() * 6.28318530717959,
-			InverseAngularVelocity::from_s_per_rad(1.0_f64).to_spr(), 9
+			DynamicViscosity::from_Pas(1.0_f64).to_Pas() * 10.0,
+			DynamicViscosity::from_Pas(1.0_f64).to_poise(), 9
 		);
 		assert_approx_equal(
-			InverseAngularVelocity::from_s_per_rad(9.54929658551372_f64).to_s_per_rad(),
-			InverseAngularVelocity::from_mpr(1.0_f64).to_s_per_rad(), 9
+			DynamicViscosity::from_Pas(0.001_f64).to_Pas(),
+			DynamicViscosity::from_cP(1.0_f64).to_Pas(), 9
 		);
 		assert_approx_equal(
-			InverseAngularVelocity::from_s_per_rad(1.0_f64).to_s_per_rad() * 0.10471975511966,
-			InverseAngularVelocity::from_s_per_rad(1.0_f64).to_mpr(), 9
+			DynamicViscosity::from_Pas(1.0_f64).to_Pas() * 1000.0,
+			DynamicViscosity::from_Pas(1.0_f64).to_cP(), 9
 		);
 		assert_approx_equal(
-			InverseAngularVelocity::from_s_per_rad(572.957795130823_f64).to_s_per_rad(),
-			InverseAngularVelocity::from_hpr(1.0_f64).to_s_per_rad(), 9
+			DynamicViscosity::from_Pas(0.001_f64).to_Pas(),
+			DynamicViscosity::from_centipoise(1.0_f64).to_Pas(), 9
 		);
 		assert_approx_equal(
-			InverseAngularVelocity::from_s_per_rad(1.0_f64).to_s_per_rad() * 0.0017453292519943,
-			InverseAngularVelocity::from_s_per_rad(1.0_f64).to_hpr(), 9
+			DynamicViscosity::from_Pas(1.0_f64).to_Pas() * 1000.0,
+			DynamicViscosity::from_Pas(1.0_f64).to_centipoise(), 9
 		);
 	}
 
 	#[test]
-	fn inverse_energy_units() {
-		assert_approx_equal(
-			InverseEnergy::from_per_J(1000.0_f64).to_per_J(),
-			InverseEnergy::from_per_mJ(1.0_f64).to_per_J(), 9
-		);
-		assert_approx_equal(
-			InverseEnergy::from_per_J(1.0_f64).to_per_J() * 0.001,
-			InverseEnergy::from_per_J(1.0_f64).to_per_mJ(), 9
-		);
-		assert_approx_equal(
-			InverseEnergy::from_per_J(1000000.0_f64).to_per_J(),
-			InverseEnergy::from_per_uJ(1.0_f64).to_per_J(), 9
-		);
-		assert_approx_equal(
-			InverseEnergy::from_per_J(1.0_f64).to_per_J() * 1e-06,
-			InverseEnergy::from_per_J(1.0_f64).to_per_uJ(), 9
-		);
-		assert_approx_equal(
-			InverseEnergy::from_per_J(1000000000.0_f64).to_per_J(),
-			InverseEnergy::from_per_nJ(1.0_f64).to_per_J(), 9
-		);
-		assert_approx_equal(
-			InverseEnergy::from_per_J(1.0_f64).to_per_J() * 1e-09,
-			InverseEnergy::from_per_J(1.0_f64).to_per_nJ(), 9
-		);
-		assert_approx_equal(
-			InverseEnergy::from_per_J(0.001_f64).to_per_J(),
-			InverseEnergy::from_per_kJ(1.0_f64).to_per_J(), 9
-		);
-		assert_approx_equal(
-			InverseEnergy::from_per_J(1.0_f64).to_per_J() * 1000.0,
-			InverseEnergy::from_per_J(1.0_f64).to_per_kJ(), 9
-		);
+	fn inverse_dynamic_viscosity_units() {
 		assert_approx_equal(
-			InverseEnergy::from_per_J(1e-06_f64).to_per_J(),
-			InverseEnergy::from_per_MJ(1.0_f64).to_per_J(), 9
+			InverseDynamicViscosity::from_per_Pas(10.0_f64).to_per_Pas(),
+			InverseDynamicViscosity::from_per_P(1.0_f64).to_per_Pas(), 9
 		);
 		assert_approx_equal(
-			InverseEnergy::from_per_J(1.0_f64).to_per_J() * 1000000.0,
-			InverseEnergy::from_per_J(1.0_f64).to_per_MJ(), 9
+			InverseDynamicViscosity::from_per_Pas(1.0_f64).to_per_Pas() * 0.1,
+			InverseDynamicViscosity::from_per_Pas(1.0_f64).to_per_P(), 9
 		);
 		assert_approx_equal(
-			InverseEnergy::from_per_J(1e-09_f64).to_per_J(),
-			InverseEnergy::from_per_GJ(1.0_f64).to_per_J(), 9
+			InverseDynamicViscosity::from_per_Pas(1000.0_f64).to_per_Pas(),
+			InverseDynamicViscosity::from_per_cP(1.0_f64).to_per_Pas(), 9
 		);
 		assert_approx_equal(
-			InverseEnergy::from_per_J(1.0_f64).to_per_J() * 1000000000.0,
-			InverseEnergy::from_per_J(1.0_f64).to_per_GJ(), 9
+			InverseDynamicViscosity::from_per_Pas(1.0_f64).to_per_Pas() * 0.001,
+			InverseDynamicViscosity::from_per_Pas(1.0_f64).to_per_cP(), 9
 		);
+	}
+
+	#[test]
+	fn inverse_kinematic_viscosity_units() {
 		assert_approx_equal(
-			InverseEnergy::from_per_J(0.239005736137667_f64).to_per_J(),
-			InverseEnergy::from_per_cal(1.0_f64).to_per_J(), 9
+			InverseKinematicViscosity::from_s_per_m2(10000.0_f64).to_s_per_m2(),
+			InverseKinematicViscosity::from_per_St(1.0_f64).to_s_per_m2(), 9
 		);
 		assert_approx_equal(
-			InverseEnergy::from_per_J(1.0_f64).to_per_J() * 4.184,
-			InverseEnergy::from_per_J(1.0_f64).to_per_cal(), 9
+			InverseKinematicViscosity::from_s_per_m2(1.0_f64).to_s_per_m2() * 0.0001,
+			InverseKinematicViscosity::from_s_per_m2(1.0_f64).to_per_St(), 9
 		);
 		assert_approx_equal(
-			InverseEnergy::from_per_J(0.0002390057361376_f64).to_per_J(),
-			InverseEnergy::from_per_kcal(1.0_f64).to_per_J(), 9
+			InverseKinematicViscosity::from_s_per_m2(1000000.0_f64).to_s_per_m2(),
+			InverseKinematicViscosity::from_per_cSt(1.0_f64).to_s_per_m2(), 9
 		);
 		assert_approx_equal(
-			InverseEnergy::from_per_J(1.0_f64).to_per_J() * 4184.0,
-			InverseEnergy::from_per_J(1.0_f64).to_per_kcal(), 9
+			InverseKinematicViscosity::from_s_per_m2(1.0_f64).to_s_per_m2() * 1e-06,
+			InverseKinematicViscosity::from_s_per_m2(1.0_f64).to_per_cSt(), 9
 		);
+	}
+
+	#[test]
+	fn kinematic_viscosity_units() {
 		assert_approx_equal(
-			InverseEnergy::from_per_J(0.0002777777777777_f64).to_per_J(),
-			InverseEnergy::from_per_Whr(1.0_f64).to_per_J(), 9
+			KinematicViscosity::from_m2ps(0.0001_f64).to_m2ps(),
+			KinematicViscosity::from_St(1.0_f64).to_m2ps(), 9
 		);
 		assert_approx_equal(
-			InverseEnergy::from_per_J(1.0_f64).to_per_J() * 3600.0,
-			InverseEnergy::from_per_J(1.0_f64).to_per_Whr(), 9
+			KinematicViscosity::from_m2ps(1.0_f64).to_m2ps() * 10000.0,
+			KinematicViscosity::from_m2ps(1.0_f64).to_St(), 9
 		);
 		assert_approx_equal(
-			InverseEnergy::from_per_J(2.78e-07_f64).to_per_J(),
-			InverseEnergy::from_per_kWhr(1.0_f64).to_per_J(), 9
+			KinematicViscosity::from_m2ps(0.0001_f64).to_m2ps(),
+			KinematicViscosity::from_stokes(1.0_f64).to_m2ps(), 9
 		);
 		assert_approx_equal(
-			InverseEnergy::from_per_J(1.0_f64).to_per_J() * 3600000.0,
-			InverseEnergy::from_per_J(1.0_f64).to_per_kWhr(), 9
+			KinematicViscosity::from_m2ps(1.0_f64).to_m2ps() * 10000.0,
+			KinematicViscosity::from_m2ps(1.0_f64).to_stokes(), 9
 		);
 		assert_approx_equal(
-			InverseEnergy::from_per_J(6.24e+18_f64).to_per_J(),
-			InverseEnergy::from_per_eV(1.0_f64).to_per_J(), 9
+			KinematicViscosity::from_m2ps(1e-06_f64).to_m2ps(),
+			KinematicViscosity::from_cSt(1.0_f64).to_m2ps(), 9
 		);
 		assert_approx_equal(
-			InverseEnergy::from_per_J(1.0_f64).to_per_J() * 1.6e-19,
-			InverseEnergy::from_per_J(1.0_f64).to_per_eV(), 9
+			KinematicViscosity::from_m2ps(1.0_f64).to_m2ps() * 1000000.0,
+			KinematicViscosity::from_m2ps(1.0_f64).to_cSt(), 9
 		);
 		assert_approx_equal(
-			InverseEnergy::from_per_J(0.0009478672985781_f64).to_per_J(),
-			InverseEnergy::from_per_BTU(1.0_f64).to_per_J(), 9
+			KinematicViscosity::from_m2ps(1e-06_f64).to_m2ps(),
+			KinematicViscosity::from_centistokes(1.0_f64).to_m2ps(), 9
 		);
 		assert_approx_equal(
-			InverseEnergy::from_per_J(1.0_f64).to_per_J() * 1055.0,
-			InverseEnergy::from_per_J(1.0_f64).to_per_BTU(), 9
+			KinematicViscosity::from_m2ps(1.0_f64).to_m2ps() * 1000000.0,
+			KinematicViscosity::from_m2ps(1.0_f64).to_centistokes(), 9
 		);
 	}
 
 	#[test]
-	fn inverse_force_units() {
-		assert_approx_equal(
-			InverseForce::from_per_N(0.224337566199999_f64).to_per_N(),
-			InverseForce::from_per_lb(1.0_f64).to_per_N(), 9
-		);
+	fn inverse_mass_flow_rate_units() {
 		assert_approx_equal(
-			InverseForce::from_per_N(1.0_f64).to_per_N() * 4.45756819483586,
-			InverseForce::from_per_N(1.0_f64).to_per_lb(), 9
+			InverseMassFlowRate::from_s_per_kg(1000.0_f64).to_s_per_kg(),
+			InverseMassFlowRate::from_s_per_g(1.0_f64).to_s_per_kg(), 9
 		);
 		assert_approx_equal(
-			InverseForce::from_per_N(0.101971620999999_f64).to_per_N(),
-			InverseForce::from_per_kgG(1.0_f64).to_per_N(), 9
+			InverseMassFlowRate::from_s_per_kg(1.0_f64).to_s_per_kg() * 0.001,
+			InverseMassFlowRate::from_s_per_kg(1.0_f64).to_s_per_g(), 9
 		);
+	}
+
+	#[test]
+	fn inverse_volumetric_flow_rate_units() {
 		assert_approx_equal(
-			InverseForce::from_per_N(1.0_f64).to_per_N() * 9.8066500286389,
-			InverseForce::from_per_N(1.0_f64).to_per_kgG(), 9
+			InverseVolumetricFlowRate::from_s_per_m3(1000.0_f64).to_s_per_m3(),
+			InverseVolumetricFlowRate::from_s_per_L(1.0_f64).to_s_per_m3(), 9
 		);
 		assert_approx_equal(
-			InverseForce::from_per_N(1000.0_f64).to_per_N(),
-			InverseForce::from_per_mN(1.0_f64).to_per_N(), 9
+			InverseVolumetricFlowRate::from_s_per_m3(1.0_f64).to_s_per_m3() * 0.001,
+			InverseVolumetricFlowRate::from_s_per_m3(1.0_f64).to_s_per_L(), 9
 		);
+	}
+
+	#[test]
+	fn mass_flow_rate_units() {
 		assert_approx_equal(
-			InverseForce::from_per_N(1.0_f64).to_per_N() * 0.001,
-			InverseForce::from_per_N(1.0_f64).to_per_mN(), 9
+			MassFlowRate::from_kgps(0.001_f64).to_kgps(),
+			MassFlowRate::from_gps(1.0_f64).to_kgps(), 9
 		);
 		assert_approx_equal(
-			InverseForce::from_per_N(1000000.0_f64).to_per_N(),
-			InverseForce::from_per_uN(1.0_f64).to_per_N(), 9
+			MassFlowRate::from_kgps(1.0_f64).to_kgps() * 1000.0,
+			MassFlowRate::from_kgps(1.0_f64).to_gps(), 9
 		);
 		assert_approx_equal(
-			InverseForce::from_per_N(1.0_f64).to_per_N() * 1e-06,
-			InverseForce::from_per_N(1.0_f64).to_per_uN(), 9
+			MassFlowRate::from_kgps(0.001_f64).to_kgps(),
+			MassFlowRate::from_grams_per_second(1.0_f64).to_kgps(), 9
 		);
 		assert_approx_equal(
-			InverseForce::from_per_N(1000000000.0_f64).to_per_N(),
-			InverseForce::from_per_nN(1.0_f64).to_per_N(), 9
+			MassFlowRate::from_kgps(1.0_f64).to_kgps() * 1000.0,
+			MassFlowRate::from_kgps(1.0_f64).to_grams_per_second(), 9
 		);
 		assert_approx_equal(
-			InverseForce::from_per_N(1.0_f64).to_per_N() * 1e-09,
-			InverseForce::from_per_N(1.0_f64).to_per_nN(), 9
+			MassFlowRate::from_kgps(0.0002777777777777778_f64).to_kgps(),
+			MassFlowRate::from_kgph(1.0_f64).to_kgps(), 9
 		);
 		assert_approx_equal(
-			InverseForce::from_per_N(0.001_f64).to_per_N(),
-			InverseForce::from_per_kN(1.0_f64).to_per_N(), 9
+			MassFlowRate::from_kgps(1.0_f64).to_kgps() * 3600.0,
+			MassFlowRate::from_kgps(1.0_f64).to_kgph(), 9
 		);
 		assert_approx_equal(
-			InverseForce::from_per_N(1.0_f64).to_per_N() * 1000.0,
-			InverseForce::from_per_N(1.0_f64).to_per_kN(), 9
+			MassFlowRate::from_kgps(0.2777777777777778_f64).to_kgps(),
+			MassFlowRate::from_tph(1.0_f64).to_kgps(), 9
 		);
 		assert_approx_equal(
-			InverseForce::from_per_N(1e-06_f64).to_per_N(),
-			InverseForce::from_per_MN(1.0_f64).to_per_N(), 9
+			MassFlowRate::from_kgps(1.0_f64).to_kgps() * 3.6,
+			MassFlowRate::from_kgps(1.0_f64).to_tph(), 9
 		);
+	}
+
+	#[test]
+	fn volumetric_flow_rate_units() {
 		assert_approx_equal(
-			InverseForce::from_per_N(1.0_f64).to_per_N() * 1000000.0,
-			InverseForce::from_per_N(1.0_f64).to_per_MN(), 9
+			VolumetricFlowRate::from_m3ps(0.001_f64).to_m3ps(),
+			VolumetricFlowRate::from_Lps(1.0_f64).to_m3ps(), 9
 		);
 		assert_approx_equal(
-			InverseForce::from_per_N(1e-09_f64).to_per_N(),
-			InverseForce::from_per_GN(1.0_f64).to_per_N(), 9
+			VolumetricFlowRate::from_m3ps(1.0_f64).to_m3ps() * 1000.0,
+			VolumetricFlowRate::from_m3ps(1.0_f64).to_Lps(), 9
 		);
 		assert_approx_equal(
-			InverseForce::from_per_N(1.0_f64).to_per_N() * 1000000000.0,
-			InverseForce::from_per_N(1.0_f64).to_per_GN(), 9
+			VolumetricFlowRate::from_m3ps(0.001_f64).to_m3ps(),
+			VolumetricFlowRate::from_liters_per_second(1.0_f64).to_m3ps(), 9
 		);
-	}
-
-	#[test]
-	fn inverse_moment_of_inertia_units() {
 		assert_approx_equal(
-			InverseMomentOfInertia::from_per_kgm2(0.1_f64).to_per_kgm2(),
-			InverseMomentOfInertia::from_per_gcm2(1.0_f64).to_per_kgm2(), 9
+			VolumetricFlowRate::from_m3ps(1.0_f64).to_m3ps() * 1000.0,
+			VolumetricFlowRate::from_m3ps(1.0_f64).to_liters_per_second(), 9
 		);
 		assert_approx_equal(
-			InverseMomentOfInertia::from_per_kgm2(1.0_f64).to_per_kgm2() * 10.0,
-			InverseMomentOfInertia::from_per_kgm2(1.0_f64).to_per_gcm2(), 9
+			VolumetricFlowRate::from_m3ps(1.6666666666666667e-05_f64).to_m3ps(),
+			VolumetricFlowRate::from_Lpm(1.0_f64).to_m3ps(), 9
 		);
 		assert_approx_equal(
-			InverseMomentOfInertia::from_per_kgm2(1000.0_f64).to_per_kgm2(),
-			InverseMomentOfInertia::from_per_gm2(1.0_f64).to_per_kgm2(), 9
+			VolumetricFlowRate::from_m3ps(1.0_f64).to_m3ps() * 60000.0,
+			VolumetricFlowRate::from_m3ps(1.0_f64).to_Lpm(), 9
 		);
 		assert_approx_equal(
-			InverseMomentOfInertia::from_per_kgm2(1.0_f64).to_per_kgm2() * 0.001,
-			InverseMomentOfInertia::from_per_kgm2(1.0_f64).to_per_gm2(), 9
+			VolumetricFlowRate::from_m3ps(1.6666666666666667e-05_f64).to_m3ps(),
+			VolumetricFlowRate::from_liters_per_minute(1.0_f64).to_m3ps(), 9
 		);
-	}
-
-	#[test]
-	fn inverse_momentum_units() {
 		assert_approx_equal(
-			InverseMomentum::from_s_per_kgm(100000.0_f64).to_s_per_kgm(),
-			InverseMomentum::from_s_per_gcm(1.0_f64).to_s_per_kgm(), 9
+			VolumetricFlowRate::from_m3ps(1.0_f64).to_m3ps() * 60000.0,
+			VolumetricFlowRate::from_m3ps(1.0_f64).to_liters_per_minute(), 9
 		);
 		assert_approx_equal(
-			InverseMomentum::from_s_per_kgm(1.0_f64).to_s_per_kgm() * 1e-05,
-			InverseMomentum::from_s_per_kgm(1.0_f64).to_s_per_gcm(), 9
+			VolumetricFlowRate::from_m3ps(1.6666666666666667e-08_f64).to_m3ps(),
+			VolumetricFlowRate::from_mLpm(1.0_f64).to_m3ps(), 9
 		);
 		assert_approx_equal(
-			InverseMomentum::from_s_per_kgm(100000.0_f64).to_s_per_kgm(),
-			InverseMomentum::from_seconds_per_gram_centimeter(1.0_f64).to_s_per_kgm(), 9
+			VolumetricFlowRate::from_m3ps(1.0_f64).to_m3ps() * 60000000.0,
+			VolumetricFlowRate::from_m3ps(1.0_f64).to_mLpm(), 9
 		);
 		assert_approx_equal(
-			InverseMomentum::from_s_per_kgm(1.0_f64).to_s_per_kgm() * 1e-05,
-			InverseMomentum::from_s_per_kgm(1.0_f64).to_seconds_per_gram_centimeter(), 9
+			VolumetricFlowRate::from_m3ps(0.0002777777777777778_f64).to_m3ps(),
+			VolumetricFlowRate::from_m3ph(1.0_f64).to_m3ps(), 9
 		);
-	}
-
-	#[test]
-	fn inverse_power_units() {
 		assert_approx_equal(
-			InversePower::from_per_W(1000.0_f64).to_per_W(),
-			InversePower::from_per_mW(1.0_f64).to_per_W(), 9
+			VolumetricFlowRate::from_m3ps(1.0_f64).to_m3ps() * 3600.0,
+			VolumetricFlowRate::from_m3ps(1.0_f64).to_m3ph(), 9
 		);
 		assert_approx_equal(
-			InversePower::from_per_W(1.0_f64).to_per_W() * 0.001,
-			InversePower::from_per_W(1.0_f64).to_per_mW(), 9
+			VolumetricFlowRate::from_m3ps(6.30901964e-05_f64).to_m3ps(),
+			VolumetricFlowRate::from_gpm(1.0_f64).to_m3ps(), 9
 		);
 		assert_approx_equal(
-			InversePower::from_per_W(1000000.0_f64).to_per_W(),
-			InversePower::from_per_uW(1.0_f64).to_per_W(), 9
+			VolumetricFlowRate::from_m3ps(1.0_f64).to_m3ps() * 15850.323141488903,
+			VolumetricFlowRate::from_m3ps(1.0_f64).to_gpm(), 9
 		);
 		assert_approx_equal(
-			InversePower::from_per_W(1.0_f64).to_per_W() * 1e-06,
-			InversePower::from_per_W(1.0_f64).to_per_uW(), 9
+			VolumetricFlowRate::from_m3ps(1.0_f64).to_m3ps() * 15850.323141488905,
+			VolumetricFlowRate::from_m3ps(1.0_f64).to_gpm(), 9
 		);
+	}
+
+	#[test]
+	fn entropy_units() {
 		assert_approx_equal(
-			InversePower::from_per_W(1000000000.0_f64).to_per_W(),
-			InversePower::from_per_nW(1.0_f64).to_per_W(), 9
+			Entropy::from_J_per_K(1000.0_f64).to_J_per_K(),
+			Entropy::from_kJ_per_K(1.0_f64).to_J_per_K(), 9
 		);
 		assert_approx_equal(
-			InversePower::from_per_W(1.0_f64).to_per_W() * 1e-09,
-			InversePower::from_per_W(1.0_f64).to_per_nW(), 9
+			Entropy::from_J_per_K(1.0_f64).to_J_per_K() * 0.001,
+			Entropy::from_J_per_K(1.0_f64).to_kJ_per_K(), 9
 		);
+	}
+
+	#[test]
+	fn heat_capacity_units() {
 		assert_approx_equal(
-			InversePower::from_per_W(0.001_f64).to_per_W(),
-			InversePower::from_per_kW(1.0_f64).to_per_W(), 9
+			HeatCapacity::from_J_per_K(1000.0_f64).to_J_per_K(),
+			HeatCapacity::from_kJ_per_K(1.0_f64).to_J_per_K(), 9
 		);
 		assert_approx_equal(
-			InversePower::from_per_W(1.0_f64).to_per_W() * 1000.0,
-			InversePower::from_per_W(1.0_f64).to_per_kW(), 9
+			HeatCapacity::from_J_per_K(1.0_f64).to_J_per_K() * 0.001,
+			HeatCapacity::from_J_per_K(1.0_f64).to_kJ_per_K(), 9
 		);
+	}
+
+	#[test]
+	fn inverse_entropy_units() {
 		assert_approx_equal(
-			InversePower::from_per_W(1e-06_f64).to_per_W(),
-			InversePower::from_per_MW(1.0_f64).to_per_W(), 9
+			InverseEntropy::from_K_per_J(0.001_f64).to_K_per_J(),
+			InverseEntropy::from_K_per_kJ(1.0_f64).to_K_per_J(), 9
 		);
 		assert_approx_equal(
-			InversePower::from_per_W(1.0_f64).to_per_W() * 1000000.0,
-			InversePower::from_per_W(1.0_f64).to_per_MW(), 9
+			InverseEntropy::from_K_per_J(1.0_f64).to_K_per_J() * 1000.0,
+			InverseEntropy::from_K_per_J(1.0_f64).to_K_per_kJ(), 9
 		);
+	}
+
+	#[test]
+	fn inverse_heat_capacity_units() {
 		assert_approx_equal(
-			InversePower::from_per_W(1e-09_f64).to_per_W(),
-			InversePower::from_per_GW(1.0_f64).to_per_W(), 9
+			InverseHeatCapacity::from_K_per_J(0.001_f64).to_K_per_J(),
+			InverseHeatCapacity::from_K_per_kJ(1.0_f64).to_K_per_J(), 9
 		);
 		assert_approx_equal(
-			InversePower::from_per_W(1.0_f64).to_per_W() * 1000000000.0,
-			InversePower::from_per_W(1.0_f64).to_per_GW(), 9
+			InverseHeatCapacity::from_K_per_J(1.0_f64).to_K_per_J() * 1000.0,
+			InverseHeatCapacity::from_K_per_J(1.0_f64).to_K_per_kJ(), 9
 		);
+	}
+
+	#[test]
+	fn thermal_conductivity_units() {
 		assert_approx_equal(
-			InversePower::from_per_W(0.0013410218586563_f64).to_per_W(),
-			InversePower::from_per_horsepower(1.0_f64).to_per_W(), 9
+			ThermalConductivity::from_W_per_mK(1.730734666371391_f64).to_W_per_mK(),
+			ThermalConductivity::from_BTU_per_hr_ft_F(1.0_f64).to_W_per_mK(), 9
 		);
 		assert_approx_equal(
-			InversePower::from_per_W(1.0_f64).to_per_W() * 745.7,
-			InversePower::from_per_W(1.0_f64).to_per_horsepower(), 9
+			ThermalConductivity::from_W_per_mK(1.0_f64).to_W_per_mK() * 0.5777893165429983,
+			ThermalConductivity::from_W_per_mK(1.0_f64).to_BTU_per_hr_ft_F(), 9
 		);
 	}
 
 	#[test]
-	fn inverse_pressure_units() {
+	fn electric_field_units() {
 		assert_approx_equal(
-			InversePressure::from_per_Pa(0.00014503773773_f64).to_per_Pa(),
-			InversePressure::from_per_psi(1.0_f64).to_per_Pa(), 9
+			ElectricField::from_Vpm(1000.0_f64).to_Vpm(),
+			ElectricField::from_kVpm(1.0_f64).to_Vpm(), 9
 		);
 		assert_approx_equal(
-			InversePressure::from_per_Pa(1.0_f64).to_per_Pa() * 6894.7572931783,
-			InversePressure::from_per_Pa(1.0_f64).to_per_psi(), 9
+			ElectricField::from_Vpm(1.0_f64).to_Vpm() * 0.001,
+			ElectricField::from_Vpm(1.0_f64).to_kVpm(), 9
 		);
 		assert_approx_equal(
-			InversePressure::from_per_Pa(1000.0_f64).to_per_Pa(),
-			InversePressure::from_per_mPa(1.0_f64).to_per_Pa(), 9
+			ElectricField::from_Vpm(100.0_f64).to_Vpm(),
+			ElectricField::from_Vpcm(1.0_f64).to_Vpm(), 9
 		);
 		assert_approx_equal(
-			InversePressure::from_per_Pa(1.0_f64).to_per_Pa() * 0.001,
-			InversePressure::from_per_Pa(1.0_f64).to_per_mPa(), 9
+			ElectricField::from_Vpm(1.0_f64).to_Vpm() * 0.01,
+			ElectricField::from_Vpm(1.0_f64).to_Vpcm(), 9
 		);
+	}
+
+	#[test]
+	fn magnetic_field_strength_units() {
 		assert_approx_equal(
-			InversePressure::from_per_Pa(1000000.0_f64).to_per_Pa(),
-			InversePressure::from_per_uPa(1.0_f64).to_per_Pa(), 9
+			MagneticFieldStrength::from_Apm(1000.0_f64).to_Apm(),
+			MagneticFieldStrength::from_kApm(1.0_f64).to_Apm(), 9
 		);
 		assert_approx_equal(
-			InversePressure::from_per_Pa(1.0_f64).to_per_Pa() * 1e-06,
-			InversePressure::from_per_Pa(1.0_f64).to_per_uPa(), 9
+			MagneticFieldStrength::from_Apm(1.0_f64).to_Apm() * 0.001,
+			MagneticFieldStrength::from_Apm(1.0_f64).to_kApm(), 9
 		);
 		assert_approx_equal(
-			InversePressure::from_per_Pa(1000000000.0_f64).to_per_Pa(),
-			InversePressure::from_per_nPa(1.0_f64).to_per_Pa(), 9
+			MagneticFieldStrength::from_Apm(79.57747154594767_f64).to_Apm(),
+			MagneticFieldStrength::from_Oe(1.0_f64).to_Apm(), 9
 		);
 		assert_approx_equal(
-			InversePressure::from_per_Pa(1.0_f64).to_per_Pa() * 1e-09,
-			InversePressure::from_per_Pa(1.0_f64).to_per_nPa(), 9
+			MagneticFieldStrength::from_Apm(1.0_f64).to_Apm() * 0.012566370614359173,
+			MagneticFieldStrength::from_Apm(1.0_f64).to_Oe(), 9
 		);
 		assert_approx_equal(
-			InversePressure::from_per_Pa(0.001_f64).to_per_Pa(),
-			InversePressure::from_per_kPa(1.0_f64).to_per_Pa(), 9
+			MagneticFieldStrength::from_Apm(79.57747154594767_f64).to_Apm(),
+			MagneticFieldStrength::from_oersteds(1.0_f64).to_Apm(), 9
 		);
 		assert_approx_equal(
-			InversePressure::from_per_Pa(1.0_f64).to_per_Pa() * 1000.0,
-			InversePressure::from_per_Pa(1.0_f64).to_per_kPa(), 9
+			MagneticFieldStrength::from_Apm(1.0_f64).to_Apm() * 0.012566370614359173,
+			MagneticFieldStrength::from_Apm(1.0_f64).to_oersteds(), 9
 		);
+	}
+
+	#[test]
+	fn surface_charge_density_units() {
 		assert_approx_equal(
-			InversePressure::from_per_Pa(1e-06_f64).to_per_Pa(),
-			InversePressure::from_per_MPa(1.0_f64).to_per_Pa(), 9
+			SurfaceChargeDensity::from_Cpm2(0.01_f64).to_Cpm2(),
+			SurfaceChargeDensity::from_uCpcm2(1.0_f64).to_Cpm2(), 9
 		);
 		assert_approx_equal(
-			InversePressure::from_per_Pa(1.0_f64).to_per_Pa() * 1000000.0,
-			InversePressure::from_per_Pa(1.0_f64).to_per_MPa(), 9
+			SurfaceChargeDensity::from_Cpm2(1.0_f64).to_Cpm2() * 100.0,
+			SurfaceChargeDensity::from_Cpm2(1.0_f64).to_uCpcm2(), 9
 		);
+	}
+
+	#[test]
+	fn inverse_irradiance_units() {
 		assert_approx_equal(
-			InversePressure::from_per_Pa(1e-09_f64).to_per_Pa(),
-			InversePressure::from_per_GPa(1.0_f64).to_per_Pa(), 9
+			InverseIrradiance::from_m2_per_W(1000.0_f64).to_m2_per_W(),
+			InverseIrradiance::from_m2_per_mW(1.0_f64).to_m2_per_W(), 9
 		);
 		assert_approx_equal(
-			InversePressure::from_per_Pa(1.0_f64).to_per_Pa() * 1000000000.0,
-			InversePressure::from_per_Pa(1.0_f64).to_per_GPa(), 9
+			InverseIrradiance::from_m2_per_W(1.0_f64).to_m2_per_W() * 0.001,
+			InverseIrradiance::from_m2_per_W(1.0_f64).to_m2_per_mW(), 9
 		);
 		assert_approx_equal(
-			InversePressure::from_per_Pa(0.01_f64).to_per_Pa(),
-			InversePressure::from_per_hPa(1.0_f64).to_per_Pa(), 9
+			InverseIrradiance::from_m2_per_W(0.001_f64).to_m2_per_W(),
+			InverseIrradiance::from_m2_per_kW(1.0_f64).to_m2_per_W(), 9
 		);
 		assert_approx_equal(
-			InversePressure::from_per_Pa(1.0_f64).to_per_Pa() * 100.0,
-			InversePressure::from_per_Pa(1.0_f64).to_per_hPa(), 9
+			InverseIrradiance::from_m2_per_W(1.0_f64).to_m2_per_W() * 1000.0,
+			InverseIrradiance::from_m2_per_W(1.0_f64).to_m2_per_kW(), 9
 		);
+	}
+
+	#[test]
+	fn irradiance_units() {
 		assert_approx_equal(
-			InversePressure::from_per_Pa(1e-05_f64).to_per_Pa(),
-			InversePressure::from_per_bar(1.0_f64).to_per_Pa(), 9
+			Irradiance::from_W_per_m2(0.001_f64).to_W_per_m2(),
+			Irradiance::from_mW_per_m2(1.0_f64).to_W_per_m2(), 9
 		);
 		assert_approx_equal(
-			InversePressure::from_per_Pa(1.0_f64).to_per_Pa() * 100000.0,
-			InversePressure::from_per_Pa(1.0_f64).to_per_bar(), 9
+			Irradiance::from_W_per_m2(1.0_f64).to_W_per_m2() * 1000.0,
+			Irradiance::from_W_per_m2(1.0_f64).to_mW_per_m2(), 9
 		);
 		assert_approx_equal(
-			InversePressure::from_per_Pa(0.01_f64).to_per_Pa(),
-			InversePressure::from_per_mbar(1.0_f64).to_per_Pa(), 9
+			Irradiance::from_W_per_m2(1000.0_f64).to_W_per_m2(),
+			Irradiance::from_kW_per_m2(1.0_f64).to_W_per_m2(), 9
 		);
 		assert_approx_equal(
-			InversePressure::from_per_Pa(1.0_f64).to_per_Pa() * 100.0,
-			InversePressure::from_per_Pa(1.0_f64).to_per_mbar(), 9
+			Irradiance::from_W_per_m2(1.0_f64).to_W_per_m2() * 0.001,
+			Irradiance::from_W_per_m2(1.0_f64).to_kW_per_m2(), 9
 		);
 		assert_approx_equal(
-			InversePressure::from_per_Pa(9.87e-06_f64).to_per_Pa(),
-			InversePressure::from_per_atm(1.0_f64).to_per_Pa(), 9
+			Irradiance::from_W_per_m2(10.0_f64).to_W_per_m2(),
+			Irradiance::from_mW_per_cm2(1.0_f64).to_W_per_m2(), 9
 		);
 		assert_approx_equal(
-			InversePressure::from_per_Pa(1.0_f64).to_per_Pa() * 101325.0,
-			InversePressure::from_per_Pa(1.0_f64).to_per_atm(), 9
+			Irradiance::from_W_per_m2(1.0_f64).to_W_per_m2() * 0.1,
+			Irradiance::from_W_per_m2(1.0_f64).to_mW_per_cm2(), 9
 		);
+	}
+
+	#[test]
+	fn radiance_units() {
 		assert_approx_equal(
-			InversePressure::from_per_Pa(0.007500616827039_f64).to_per_Pa(),
-			InversePressure::from_per_torr(1.0_f64).to_per_Pa(), 9
+			Radiance::from_W_per_m2sr(0.001_f64).to_W_per_m2sr(),
+			Radiance::from_mW_per_m2sr(1.0_f64).to_W_per_m2sr(), 9
 		);
 		assert_approx_equal(
-			InversePressure::from_per_Pa(1.0_f64).to_per_Pa() * 133.3223684211,
-			InversePressure::from_per_Pa(1.0_f64).to_per_torr(), 9
+			Radiance::from_W_per_m2sr(1.0_f64).to_W_per_m2sr() * 1000.0,
+			Radiance::from_W_per_m2sr(1.0_f64).to_mW_per_m2sr(), 9
 		);
 		assert_approx_equal(
-			InversePressure::from_per_Pa(0.007500616827039_f64).to_per_Pa(),
-			InversePressure::from_per_mmHg(1.0_f64).to_per_Pa(), 9
+			Radiance::from_W_per_m2sr(1000.0_f64).to_W_per_m2sr(),
+			Radiance::from_kW_per_m2sr(1.0_f64).to_W_per_m2sr(), 9
 		);
 		assert_approx_equal(
-			InversePressure::from_per_Pa(1.0_f64).to_per_Pa() * 133.3223684211,
-			InversePressure::from_per_Pa(1.0_f64).to_per_mmHg(), 9
+			Radiance::from_W_per_m2sr(1.0_f64).to_W_per_m2sr() * 0.001,
+			Radiance::from_W_per_m2sr(1.0_f64).to_kW_per_m2sr(), 9
 		);
 	}
 
 	#[test]
-	fn inverse_torque_units() {
+	fn inverse_molar_energy_units() {
 		assert_approx_equal(
-			InverseTorque::from_per_Nm(0.73756214927727_f64).to_per_Nm(),
-			InverseTorque::from_per_ftlb(1.0_f64).to_per_Nm(), 9
+			InverseMolarEnergy::from_mol_per_J(0.001_f64).to_mol_per_J(),
+			InverseMolarEnergy::from_mol_per_kJ(1.0_f64).to_mol_per_J(), 9
 		);
 		assert_approx_equal(
-			InverseTorque::from_per_Nm(1.0_f64).to_per_Nm() * 1.35581794833139,
-			InverseTorque::from_per_Nm(1.0_f64).to_per_ftlb(), 9
+			InverseMolarEnergy::from_mol_per_J(1.0_f64).to_mol_per_J() * 1000.0,
+			InverseMolarEnergy::from_mol_per_J(1.0_f64).to_mol_per_kJ(), 9
 		);
 	}
 
 	#[test]
-	fn time_per_distance_units() {
+	fn molar_energy_units() {
 		assert_approx_equal(
-			TimePerDistance::from_spm(100.0_f64).to_spm(),
-			TimePerDistance::from_s_per_cm(1.0_f64).to_spm(), 9
+			MolarEnergy::from_J_per_mol(1000.0_f64).to_J_per_mol(),
+			MolarEnergy::from_kJ_per_mol(1.0_f64).to_J_per_mol(), 9
 		);
 		assert_approx_equal(
-			TimePerDistance::from_spm(1.0_f64).to_spm() * 0.01,
-			TimePerDistance::from_spm(1.0_f64).to_s_per_cm(), 9
+			MolarEnergy::from_J_per_mol(1.0_f64).to_J_per_mol() * 0.001,
+			MolarEnergy::from_J_per_mol(1.0_f64).to_kJ_per_mol(), 9
 		);
 		assert_approx_equal(
-			TimePerDistance::from_spm(1000.0_f64).to_spm(),
-			TimePerDistance::from_s_per_mm(1.0_f64).to_spm(), 9
+			MolarEnergy::from_J_per_mol(1000.0_f64).to_J_per_mol(),
+			MolarEnergy::from_kilojoules_per_mole(1.0_f64).to_J_per_mol(), 9
 		);
 		assert_approx_equal(
-			TimePerDistance::from_spm(1.0_f64).to_spm() * 0.001,
-			TimePerDistance::from_spm(1.0_f64).to_s_per_mm(), 9
+			MolarEnergy::from_J_per_mol(1.0_f64).to_J_per_mol() * 0.001,
+			MolarEnergy::from_J_per_mol(1.0_f64).to_kilojoules_per_mole(), 9
 		);
 		assert_approx_equal(
-			TimePerDistance::from_spm(3.6_f64).to_spm(),
-			TimePerDistance::from_hr_per_km(1.0_f64).to_spm(), 9
+			MolarEnergy::from_J_per_mol(4184.0_f64).to_J_per_mol(),
+			MolarEnergy::from_kcal_per_mol(1.0_f64).to_J_per_mol(), 9
 		);
 		assert_approx_equal(
-			TimePerDistance::from_spm(1.0_f64).to_spm() * 0.277777777777778,
-			TimePerDistance::from_spm(1.0_f64).to_hr_per_km(), 9
+			MolarEnergy::from_J_per_mol(1.0_f64).to_J_per_mol() * 0.000239005736137667,
+			MolarEnergy::from_J_per_mol(1.0_f64).to_kcal_per_mol(), 9
 		);
+	}
+
+	#[test]
+	fn molar_entropy_units() {
 		assert_approx_equal(
-			TimePerDistance::from_spm(2.2369362920544_f64).to_spm(),
-			TimePerDistance::from_hr_per_mi(1.0_f64).to_spm(), 9
+			MolarEntropy::from_J_per_molK(1000.0_f64).to_J_per_molK(),
+			MolarEntropy::from_kJ_per_molK(1.0_f64).to_J_per_molK(), 9
 		);
 		assert_approx_equal(
-			TimePerDistance::from_spm(1.0_f64).to_spm() * 0.44704,
-			TimePerDistance::from_spm(1.0_f64).to_hr_per_mi(), 9
+			MolarEntropy::from_J_per_molK(1.0_f64).to_J_per_molK() * 0.001,
+			MolarEntropy::from_J_per_molK(1.0_f64).to_kJ_per_molK(), 9
+		);
+		assert_approx_equal(
+			MolarEntropy::from_J_per_molK(4.184_f64).to_J_per_molK(),
+			MolarEntropy::from_cal_per_molK(1.0_f64).to_J_per_molK(), 9
+		);
+		assert_approx_equal(
+			MolarEntropy::from_J_per_molK(1.0_f64).to_J_per_molK() * 0.239005736137667,
+			MolarEntropy::from_J_per_molK(1.0_f64).to_cal_per_molK(), 9
 		);
 	}
 
 	#[test]
-	fn volume_per_mass_units() {
+	fn all_units_test() {
+		assert!(super::ALL_UNITS.iter().any(|u| u.type_name == "Amount" && u.symbol == Amount::<f64>::unit_symbol()
+			&& u.dimension == Amount::<f64>::dimension()));
+		assert!(super::ALL_UNITS.iter().any(|u| u.type_name == "Current" && u.symbol == Current::<f64>::unit_symbol()
+			&& u.dimension == Current::<f64>::dimension()));
+		assert!(super::ALL_UNITS.iter().any(|u| u.type_name == "Distance" && u.symbol == Distance::<f64>::unit_symbol()
+			&& u.dimension == Distance::<f64>::dimension()));
+		assert!(super::ALL_UNITS.iter().any(|u| u.type_name == "InverseAmount" && u.symbol == InverseAmount::<f64>::unit_symbol()
+			&& u.dimension == InverseAmount::<f64>::dimension()));
+		assert!(super::ALL_UNITS.iter().any(|u| u.type_name == "InverseCurrent" && u.symbol == InverseCurrent::<f64>::unit_symbol()
+			&& u.dimension == InverseCurrent::<f64>::dimension()));
+		assert!(super::ALL_UNITS.iter().any(|u| u.type_name == "InverseDistance" && u.symbol == InverseDistance::<f64>::unit_symbol()
+			&& u.dimension == InverseDistance::<f64>::dimension()));
+		assert!(super::ALL_UNITS.iter().any(|u| u.type_name == "InverseLuminosity" && u.symbol == InverseLuminosity::<f64>::unit_symbol()
+			&& u.dimension == InverseLuminosity::<f64>::dimension()));
+		assert!(super::ALL_UNITS.iter().any(|u| u.type_name == "InverseMass" && u.symbol == InverseMass::<f64>::unit_symbol()
+			&& u.dimension == InverseMass::<f64>::dimension()));
+		assert!(super::ALL_UNITS.iter().any(|u| u.type_name == "InverseTemperature" && u.symbol == InverseTemperature::<f64>::unit_symbol()
+			&& u.dimension == InverseTemperature::<f64>::dimension()));
+		assert!(super::ALL_UNITS.iter().any(|u| u.type_name == "Luminosity" && u.symbol == Luminosity::<f64>::unit_symbol()
+			&& u.dimension == Luminosity::<f64>::dimension()));
+		assert!(super::ALL_UNITS.iter().any(|u| u.type_name == "Mass" && u.symbol == Mass::<f64>::unit_symbol()
+			&& u.dimension == Mass::<f64>::dimension()));
+		assert!(super::ALL_UNITS.iter().any(|u| u.type_name == "Temperature" && u.symbol == Temperature::<f64>::unit_symbol()
+			&& u.dimension == Temperature::<f64>::dimension()));
+		assert!(super::ALL_UNITS.iter().any(|u| u.type_name == "TemperatureInterval" && u.symbol == TemperatureInterval::<f64>::unit_symbol()
+			&& u.dimension == TemperatureInterval::<f64>::dimension()));
+		assert!(super::ALL_UNITS.iter().any(|u| u.type_name == "Time" && u.symbol == Time::<f64>::unit_symbol()
+			&& u.dimension == Time::<f64>::dimension()));
+		assert!(super::ALL_UNITS.iter().any(|u| u.type_name == "CatalyticActivity" && u.symbol == CatalyticActivity::<f64>::unit_symbol()
+			&& u.dimension == CatalyticActivity::<f64>::dimension()));
+		assert!(super::ALL_UNITS.iter().any(|u| u.type_name == "Concentration" && u.symbol == Concentration::<f64>::unit_symbol()
+			&& u.dimension == Concentration::<f64>::dimension()));
+		assert!(super::ALL_UNITS.iter().any(|u| u.type_name == "Entropy" && u.symbol == Entropy::<f64>::unit_symbol()
+			&& u.dimension == Entropy::<f64>::dimension()));
+		assert!(super::ALL_UNITS.iter().any(|u| u.type_name == "HeatCapacity" && u.symbol == HeatCapacity::<f64>::unit_symbol()
+			&& u.dimension == HeatCapacity::<f64>::dimension()));
+		assert!(super::ALL_UNITS.iter().any(|u| u.type_name == "InverseCatalyticActivity" && u.symbol == InverseCatalyticActivity::<f64>::unit_symbol()
+			&& u.dimension == InverseCatalyticActivity::<f64>::dimension()));
+		assert!(super::ALL_UNITS.iter().any(|u| u.type_name == "InverseEntropy" && u.symbol == InverseEntropy::<f64>::unit_symbol()
+			&& u.dimension == InverseEntropy::<f64>::dimension()));
+		assert!(super::ALL_UNITS.iter().any(|u| u.type_name == "InverseHeatCapacity" && u.symbol == InverseHeatCapacity::<f64>::unit_symbol()
+			&& u.dimension == InverseHeatCapacity::<f64>::dimension()));
+		assert!(super::ALL_UNITS.iter().any(|u| u.type_name == "InverseMolarEnergy" && u.symbol == InverseMolarEnergy::<f64>::unit_symbol()
+			&& u.dimension == InverseMolarEnergy::<f64>::dimension()));
+		assert!(super::ALL_UNITS.iter().any(|u| u.type_name == "InverseMolarEntropy" && u.symbol == InverseMolarEntropy::<f64>::unit_symbol()
+			&& u.dimension == InverseMolarEntropy::<f64>::dimension()));
+		assert!(super::ALL_UNITS.iter().any(|u| u.type_name == "InverseSpecificHeatCapacity" && u.symbol == InverseSpecificHeatCapacity::<f64>::unit_symbol()
+			&& u.dimension == InverseSpecificHeatCapacity::<f64>::dimension()));
+		assert!(super::ALL_UNITS.iter().any(|u| u.type_name == "Molality" && u.symbol == Molality::<f64>::unit_symbol()
+			&& u.dimension == Molality::<f64>::dimension()));
+		assert!(super::ALL_UNITS.iter().any(|u| u.type_name == "MolarEnergy" && u.symbol == MolarEnergy::<f64>::unit_symbol()
+			&& u.dimension == MolarEnergy::<f64>::dimension()));
+		assert!(super::ALL_UNITS.iter().any(|u| u.type_name == "MolarEntropy" && u.symbol == MolarEntropy::<f64>::unit_symbol()
+			&& u.dimension == MolarEntropy::<f64>::dimension()));
+		assert!(super::ALL_UNITS.iter().any(|u| u.type_name == "MolarMass" && u.symbol == MolarMass::<f64>::unit_symbol()
+			&& u.dimension == MolarMass::<f64>::dimension()));
+		assert!(super::ALL_UNITS.iter().any(|u| u.type_name == "MolarVolume" && u.symbol == MolarVolume::<f64>::unit_symbol()
+			&& u.dimension == MolarVolume::<f64>::dimension()));
+		assert!(super::ALL_UNITS.iter().any(|u| u.type_name == "SpecificHeatCapacity" && u.symbol == SpecificHeatCapacity::<f64>::unit_symbol()
+			&& u.dimension == SpecificHeatCapacity::<f64>::dimension()));
+		assert!(super::ALL_UNITS.iter().any(|u| u.type_name == "ThermalConductance" && u.symbol == ThermalConductance::<f64>::unit_symbol()
+			&& u.dimension == ThermalConductance::<f64>::dimension()));
+		assert!(super::ALL_UNITS.iter().any(|u| u.type_name == "ThermalConductivity" && u.symbol == ThermalConductivity::<f64>::unit_symbol()
+			&& u.dimension == ThermalConductivity::<f64>::dimension()));
+		assert!(super::ALL_UNITS.iter().any(|u| u.type_name == "ThermalResistance" && u.symbol == ThermalResistance::<f64>::unit_symbol()
+			&& u.dimension == ThermalResistance::<f64>::dimension()));
+		assert!(super::ALL_UNITS.iter().any(|u| u.type_name == "ThermalResistivity" && u.symbol == ThermalResistivity::<f64>::unit_symbol()
+			&& u.dimension == ThermalResistivity::<f64>::dimension()));
+		assert!(super::ALL_UNITS.iter().any(|u| u.type_name == "AreaPerLumen" && u.symbol == AreaPerLumen::<f64>::unit_symbol()
+			&& u.dimension == AreaPerLumen::<f64>::dimension()));
+		assert!(super::ALL_UNITS.iter().any(|u| u.type_name == "Capacitance" && u.symbol == Capacitance::<f64>::unit_symbol()
+			&& u.dimension == Capacitance::<f64>::dimension()));
+		assert!(super::ALL_UNITS.iter().any(|u| u.type_name == "Charge" && u.symbol == Charge::<f64>::unit_symbol()
+			&& u.dimension == Charge::<f64>::dimension()));
+		assert!(super::ALL_UNITS.iter().any(|u| u.type_name == "Conductance" && u.symbol == Conductance::<f64>::unit_symbol()
+			&& u.dimension == Conductance::<f64>::dimension()));
+		assert!(super::ALL_UNITS.iter().any(|u| u.type_name == "Elastance" && u.symbol == Elastance::<f64>::unit_symbol()
+			&& u.dimension == Elastance::<f64>::dimension()));
+		assert!(super::ALL_UNITS.iter().any(|u| u.type_name == "ElectricField" && u.symbol == ElectricField::<f64>::unit_symbol()
+			&& u.dimension == ElectricField::<f64>::dimension()));
+		assert!(super::ALL_UNITS.iter().any(|u| u.type_name == "Illuminance" && u.symbol == Illuminance::<f64>::unit_symbol()
+			&& u.dimension == Illuminance::<f64>::dimension()));
+		assert!(super::ALL_UNITS.iter().any(|u| u.type_name == "Inductance" && u.symbol == Inductance::<f64>::unit_symbol()
+			&& u.dimension == Inductance::<f64>::dimension()));
+		assert!(super::ALL_UNITS.iter().any(|u| u.type_name == "InverseCharge" && u.symbol == InverseCharge::<f64>::unit_symbol()
+			&& u.dimension == InverseCharge::<f64>::dimension()));
+		assert!(super::ALL_UNITS.iter().any(|u| u.type_name == "InverseElectricField" && u.symbol == InverseElectricField::<f64>::unit_symbol()
+			&& u.dimension == InverseElectricField::<f64>::dimension()));
+		assert!(super::ALL_UNITS.iter().any(|u| u.type_name == "InverseInductance" && u.symbol == InverseInductance::<f64>::unit_symbol()
+			&& u.dimension == InverseInductance::<f64>::dimension()));
+		assert!(super::ALL_UNITS.iter().any(|u| u.type_name == "InverseIrradiance" && u.symbol == InverseIrradiance::<f64>::unit_symbol()
+			&& u.dimension == InverseIrradiance::<f64>::dimension()));
+		assert!(super::ALL_UNITS.iter().any(|u| u.type_name == "InverseLinearChargeDensity" && u.symbol == InverseLinearChargeDensity::<f64>::unit_symbol()
+			&& u.dimension == InverseLinearChargeDensity::<f64>::dimension()));
+		assert!(super::ALL_UNITS.iter().any(|u| u.type_name == "InverseLuminousFlux" && u.symbol == InverseLuminousFlux::<f64>::unit_symbol()
+			&& u.dimension == InverseLuminousFlux::<f64>::dimension()));
+		assert!(super::ALL_UNITS.iter().any(|u| u.type_name == "InverseMagneticFieldStrength" && u.symbol == InverseMagneticFieldStrength::<f64>::unit_symbol()
+			&& u.dimension == InverseMagneticFieldStrength::<f64>::dimension()));
+		assert!(super::ALL_UNITS.iter().any(|u| u.type_name == "InverseMagneticFlux" && u.symbol == InverseMagneticFlux::<f64>::unit_symbol()
+			&& u.dimension == InverseMagneticFlux::<f64>::dimension()));
+		assert!(super::ALL_UNITS.iter().any(|u| u.type_name == "InverseMagneticFluxDensity" && u.symbol == InverseMagneticFluxDensity::<f64>::unit_symbol()
+			&& u.dimension == InverseMagneticFluxDensity::<f64>::dimension()));
+		assert!(super::ALL_UNITS.iter().any(|u| u.type_name == "InverseRadiance" && u.symbol == InverseRadiance::<f64>::unit_symbol()
+			&& u.dimension == InverseRadiance::<f64>::dimension()));
+		assert!(super::ALL_UNITS.iter().any(|u| u.type_name == "InverseSurfaceChargeDensity" && u.symbol == InverseSurfaceChargeDensity::<f64>::unit_symbol()
+			&& u.dimension == InverseSurfaceChargeDensity::<f64>::dimension()));
+		assert!(super::ALL_UNITS.iter().any(|u| u.type_name == "InverseVoltage" && u.symbol == InverseVoltage::<f64>::unit_symbol()
+			&& u.dimension == InverseVoltage::<f64>::dimension()));
+		assert!(super::ALL_UNITS.iter().any(|u| u.type_name == "InverseVolumeChargeDensity" && u.symbol == InverseVolumeChargeDensity::<f64>::unit_symbol()
+			&& u.dimension == InverseVolumeChargeDensity::<f64>::dimension()));
+		assert!(super::ALL_UNITS.iter().any(|u| u.type_name == "Irradiance" && u.symbol == Irradiance::<f64>::unit_symbol()
+			&& u.dimension == Irradiance::<f64>::dimension()));
+		assert!(super::ALL_UNITS.iter().any(|u| u.type_name == "LinearChargeDensity" && u.symbol == LinearChargeDensity::<f64>::unit_symbol()
+			&& u.dimension == LinearChargeDensity::<f64>::dimension()));
+		assert!(super::ALL_UNITS.iter().any(|u| u.type_name == "LuminousFlux" && u.symbol == LuminousFlux::<f64>::unit_symbol()
+			&& u.dimension == LuminousFlux::<f64>::dimension()));
+		assert!(super::ALL_UNITS.iter().any(|u| u.type_name == "MagneticFieldStrength" && u.symbol == MagneticFieldStrength::<f64>::unit_symbol()
+			&& u.dimension == MagneticFieldStrength::<f64>::dimension()));
+		assert!(super::ALL_UNITS.iter().any(|u| u.type_name == "MagneticFlux" && u.symbol == MagneticFlux::<f64>::unit_symbol()
+			&& u.dimension == MagneticFlux::<f64>::dimension()));
+		assert!(super::ALL_UNITS.iter().any(|u| u.type_name == "MagneticFluxDensity" && u.symbol == MagneticFluxDensity::<f64>::unit_symbol()
+			&& u.dimension == MagneticFluxDensity::<f64>::dimension()));
+		assert!(super::ALL_UNITS.iter().any(|u| u.type_name == "Radiance" && u.symbol == Radiance::<f64>::unit_symbol()
+			&& u.dimension == Radiance::<f64>::dimension()));
+		assert!(super::ALL_UNITS.iter().any(|u| u.type_name == "Resistance" && u.symbol == Resistance::<f64>::unit_symbol()
+			&& u.dimension == Resistance::<f64>::dimension()));
+		assert!(super::ALL_UNITS.iter().any(|u| u.type_name == "SurfaceChargeDensity" && u.symbol == SurfaceChargeDensity::<f64>::unit_symbol()
+			&& u.dimension == SurfaceChargeDensity::<f64>::dimension()));
+		assert!(super::ALL_UNITS.iter().any(|u| u.type_name == "Voltage" && u.symbol == Voltage::<f64>::unit_symbol()
+			&& u.dimension == Voltage::<f64>::dimension()));
+		assert!(super::ALL_UNITS.iter().any(|u| u.type_name == "VolumeChargeDensity" && u.symbol == VolumeChargeDensity::<f64>::unit_symbol()
+			&& u.dimension == VolumeChargeDensity::<f64>::dimension()));
+		assert!(super::ALL_UNITS.iter().any(|u| u.type_name == "Angle" && u.symbol == Angle::<f64>::unit_symbol()
+			&& u.dimension == Angle::<f64>::dimension()));
+		assert!(super::ALL_UNITS.iter().any(|u| u.type_name == "Area" && u.symbol == Area::<f64>::unit_symbol()
+			&& u.dimension == Area::<f64>::dimension()));
+		assert!(super::ALL_UNITS.iter().any(|u| u.type_name == "InverseAngle" && u.symbol == InverseAngle::<f64>::unit_symbol()
+			&& u.dimension == InverseAngle::<f64>::dimension()));
+		assert!(super::ALL_UNITS.iter().any(|u| u.type_name == "InverseArea" && u.symbol == InverseArea::<f64>::unit_symbol()
+			&& u.dimension == InverseArea::<f64>::dimension()));
+		assert!(super::ALL_UNITS.iter().any(|u| u.type_name == "InverseSolidAngle" && u.symbol == InverseSolidAngle::<f64>::unit_symbol()
+			&& u.dimension == InverseSolidAngle::<f64>::dimension()));
+		assert!(super::ALL_UNITS.iter().any(|u| u.type_name == "InverseVolume" && u.symbol == InverseVolume::<f64>::unit_symbol()
+			&& u.dimension == InverseVolume::<f64>::dimension()));
+		assert!(super::ALL_UNITS.iter().any(|u| u.type_name == "SolidAngle" && u.symbol == SolidAngle::<f64>::unit_symbol()
+			&& u.dimension == SolidAngle::<f64>::dimension()));
+		assert!(super::ALL_UNITS.iter().any(|u| u.type_name == "Volume" && u.symbol == Volume::<f64>::unit_symbol()
+			&& u.dimension == Volume::<f64>::dimension()));
+		assert!(super::ALL_UNITS.iter().any(|u| u.type_name == "Acceleration" && u.symbol == Acceleration::<f64>::unit_symbol()
+			&& u.dimension == Acceleration::<f64>::dimension()));
+		assert!(super::ALL_UNITS.iter().any(|u| u.type_name == "AngularAcceleration" && u.symbol == AngularAcceleration::<f64>::unit_symbol()
+			&& u.dimension == AngularAcceleration::<f64>::dimension()));
+		assert!(super::ALL_UNITS.iter().any(|u| u.type_name == "AngularMomentum" && u.symbol == AngularMomentum::<f64>::unit_symbol()
+			&& u.dimension == AngularMomentum::<f64>::dimension()));
+		assert!(super::ALL_UNITS.iter().any(|u| u.type_name == "AngularVelocity" && u.symbol == AngularVelocity::<f64>::unit_symbol()
+			&& u.dimension == AngularVelocity::<f64>::dimension()));
+		assert!(super::ALL_UNITS.iter().any(|u| u.type_name == "AreaDensity" && u.symbol == AreaDensity::<f64>::unit_symbol()
+			&& u.dimension == AreaDensity::<f64>::dimension()));
+		assert!(super::ALL_UNITS.iter().any(|u| u.type_name == "AreaPerMass" && u.symbol == AreaPerMass::<f64>::unit_symbol()
+			&& u.dimension == AreaPerMass::<f64>::dimension()));
+		assert!(super::ALL_UNITS.iter().any(|u| u.type_name == "Density" && u.symbol == Density::<f64>::unit_symbol()
+			&& u.dimension == Density::<f64>::dimension()));
+		assert!(super::ALL_UNITS.iter().any(|u| u.type_name == "DynamicViscosity" && u.symbol == DynamicViscosity::<f64>::unit_symbol()
+			&& u.dimension == DynamicViscosity::<f64>::dimension()));
+		assert!(super::ALL_UNITS.iter().any(|u| u.type_name == "Energy" && u.symbol == Energy::<f64>::unit_symbol()
+			&& u.dimension == Energy::<f64>::dimension()));
+		assert!(super::ALL_UNITS.iter().any(|u| u.type_name == "Force" && u.symbol == Force::<f64>::unit_symbol()
+			&& u.dimension == Force::<f64>::dimension()));
+		assert!(super::ALL_UNITS.iter().any(|u| u.type_name == "Frequency" && u.symbol == Frequency::<f64>::unit_symbol()
+			&& u.dimension == Frequency::<f64>::dimension()));
+		assert!(super::ALL_UNITS.iter().any(|u| u.type_name == "InverseAcceleration" && u.symbol == InverseAcceleration::<f64>::unit_symbol()
+			&& u.dimension == InverseAcceleration::<f64>::dimension()));
+		assert!(super::ALL_UNITS.iter().any(|u| u.type_name == "InverseAngularAcceleration" && u.symbol == InverseAngularAcceleration::<f64>::unit_symbol()
+			&& u.dimension == InverseAngularAcceleration::<f64>::dimension()));
+		assert!(super::ALL_UNITS.iter().any(|u| u.type_name == "InverseAngularMomentum" && u.symbol == InverseAngularMomentum::<f64>::unit_symbol()
+			&& u.dimension == InverseAngularMomentum::<f64>::dimension()));
+		assert!(super::ALL_UNITS.iter().any(|u| u.type_name == "InverseAngularVelocity" && u.symbol == InverseAngularVelocity::<f64>::unit_symbol()
+			&& u.dimension == InverseAngularVelocity::<f64>::dimension()));
+		assert!(super::ALL_UNITS.iter().any(|u| u.type_name == "InverseDynamicViscosity" && u.symbol == InverseDynamicViscosity::<f64>::unit_symbol()
+			&& u.dimension == InverseDynamicViscosity::<f64>::dimension()));
+		assert!(super::ALL_UNITS.iter().any(|u| u.type_name == "InverseEnergy" && u.symbol == InverseEnergy::<f64>::unit_symbol()
+			&& u.dimension == InverseEnergy::<f64>::dimension()));
+		assert!(super::ALL_UNITS.iter().any(|u| u.type_name == "InverseForce" && u.symbol == InverseForce::<f64>::unit_symbol()
+			&& u.dimension == InverseForce::<f64>::dimension()));
+		assert!(super::ALL_UNITS.iter().any(|u| u.type_name == "InverseJerk" && u.symbol == InverseJerk::<f64>::unit_symbol()
+			&& u.dimension == InverseJerk::<f64>::dimension()));
+		assert!(super::ALL_UNITS.iter().any(|u| u.type_name == "InverseKinematicViscosity" && u.symbol == InverseKinematicViscosity::<f64>::unit_symbol()
+			&& u.dimension == InverseKinematicViscosity::<f64>::dimension()));
+		assert!(super::ALL_UNITS.iter().any(|u| u.type_name == "InverseMassFlowRate" && u.symbol == InverseMassFlowRate::<f64>::unit_symbol()
+			&& u.dimension == InverseMassFlowRate::<f64>::dimension()));
+		assert!(super::ALL_UNITS.iter().any(|u| u.type_name == "InverseMomentOfInertia" && u.symbol == InverseMomentOfInertia::<f64>::unit_symbol()
+			&& u.dimension == InverseMomentOfInertia::<f64>::dimension()));
+		assert!(super::ALL_UNITS.iter().any(|u| u.type_name == "InverseMomentum" && u.symbol == InverseMomentum::<f64>::unit_symbol()
+			&& u.dimension == InverseMomentum::<f64>::dimension()));
+		assert!(super::ALL_UNITS.iter().any(|u| u.type_name == "InversePower" && u.symbol == InversePower::<f64>::unit_symbol()
+			&& u.dimension == InversePower::<f64>::dimension()));
+		assert!(super::ALL_UNITS.iter().any(|u| u.type_name == "InversePressure" && u.symbol == InversePressure::<f64>::unit_symbol()
+			&& u.dimension == InversePressure::<f64>::dimension()));
+		assert!(super::ALL_UNITS.iter().any(|u| u.type_name == "InverseTorque" && u.symbol == InverseTorque::<f64>::unit_symbol()
+			&& u.dimension == InverseTorque::<f64>::dimension()));
+		assert!(super::ALL_UNITS.iter().any(|u| u.type_name == "InverseVolumetricFlowRate" && u.symbol == InverseVolumetricFlowRate::<f64>::unit_symbol()
+			&& u.dimension == InverseVolumetricFlowRate::<f64>::dimension()));
+		assert!(super::ALL_UNITS.iter().any(|u| u.type_name == "Jerk" && u.symbol == Jerk::<f64>::unit_symbol()
+			&& u.dimension == Jerk::<f64>::dimension()));
+		assert!(super::ALL_UNITS.iter().any(|u| u.type_name == "KinematicViscosity" && u.symbol == KinematicViscosity::<f64>::unit_symbol()
+			&& u.dimension == KinematicViscosity::<f64>::dimension()));
+		assert!(super::ALL_UNITS.iter().any(|u| u.type_name == "MassFlowRate" && u.symbol == MassFlowRate::<f64>::unit_symbol()
+			&& u.dimension == MassFlowRate::<f64>::dimension()));
+		assert!(super::ALL_UNITS.iter().any(|u| u.type_name == "MomentOfInertia" && u.symbol == MomentOfInertia::<f64>::unit_symbol()
+			&& u.dimension == MomentOfInertia::<f64>::dimension()));
+		assert!(super::ALL_UNITS.iter().any(|u| u.type_name == "Momentum" && u.symbol == Momentum::<f64>::unit_symbol()
+			&& u.dimension == Momentum::<f64>::dimension()));
+		assert!(super::ALL_UNITS.iter().any(|u| u.type_name == "Power" && u.symbol == Power::<f64>::unit_symbol()
+			&& u.dimension == Power::<f64>::dimension()));
+		assert!(super::ALL_UNITS.iter().any(|u| u.type_name == "Pressure" && u.symbol == Pressure::<f64>::unit_symbol()
+			&& u.dimension == Pressure::<f64>::dimension()));
+		assert!(super::ALL_UNITS.iter().any(|u| u.type_name == "TimePerDistance" && u.symbol == TimePerDistance::<f64>::unit_symbol()
+			&& u.dimension == TimePerDistance::<f64>::dimension()));
+		assert!(super::ALL_UNITS.iter().any(|u| u.type_name == "Torque" && u.symbol == Torque::<f64>::unit_symbol()
+			&& u.dimension == Torque::<f64>::dimension()));
+		assert!(super::ALL_UNITS.iter().any(|u| u.type_name == "Velocity" && u.symbol == Velocity::<f64>::unit_symbol()
+			&& u.dimension == Velocity::<f64>::dimension()));
+		assert!(super::ALL_UNITS.iter().any(|u| u.type_name == "VolumePerMass" && u.symbol == VolumePerMass::<f64>::unit_symbol()
+			&& u.dimension == VolumePerMass::<f64>::dimension()));
+		assert!(super::ALL_UNITS.iter().any(|u| u.type_name == "VolumetricFlowRate" && u.symbol == VolumetricFlowRate::<f64>::unit_symbol()
+			&& u.dimension == VolumetricFlowRate::<f64>::dimension()));
+		assert!(super::ALL_UNITS.iter().any(|u| u.type_name == "AbsorbedDose" && u.symbol == AbsorbedDose::<f64>::unit_symbol()
+			&& u.dimension == AbsorbedDose::<f64>::dimension()));
+		assert!(super::ALL_UNITS.iter().any(|u| u.type_name == "DoseEquivalent" && u.symbol == DoseEquivalent::<f64>::unit_symbol()
+			&& u.dimension == DoseEquivalent::<f64>::dimension()));
+		assert!(super::ALL_UNITS.iter().any(|u| u.type_name == "InverseAbsorbedDose" && u.symbol == InverseAbsorbedDose::<f64>::unit_symbol()
+			&& u.dimension == InverseAbsorbedDose::<f64>::dimension()));
+		assert!(super::ALL_UNITS.iter().any(|u| u.type_name == "InverseDoseEquivalent" && u.symbol == InverseDoseEquivalent::<f64>::unit_symbol()
+			&& u.dimension == InverseDoseEquivalent::<f64>::dimension()));
+		assert!(super::ALL_UNITS.iter().any(|u| u.type_name == "Radioactivity" && u.symbol == Radioactivity::<f64>::unit_symbol()
+			&& u.dimension == Radioactivity::<f64>::dimension()));
+		assert!(super::ALL_UNITS.iter().any(|u| u.type_name == "AbsorbedDoseRate" && u.symbol == AbsorbedDoseRate::<f64>::unit_symbol()
+			&& u.dimension == AbsorbedDoseRate::<f64>::dimension()));
+		assert!(super::ALL_UNITS.iter().any(|u| u.type_name == "DoseEquivalentRate" && u.symbol == DoseEquivalentRate::<f64>::unit_symbol()
+			&& u.dimension == DoseEquivalentRate::<f64>::dimension()));
+		assert!(super::ALL_UNITS.iter().any(|u| u.type_name == "InverseAbsorbedDoseRate" && u.symbol == InverseAbsorbedDoseRate::<f64>::unit_symbol()
+			&& u.dimension == InverseAbsorbedDoseRate::<f64>::dimension()));
+		assert!(super::ALL_UNITS.iter().any(|u| u.type_name == "InverseDoseEquivalentRate" && u.symbol == InverseDoseEquivalentRate::<f64>::unit_symbol()
+			&& u.dimension == InverseDoseEquivalentRate::<f64>::dimension()));
+	}
+
+	#[test]
+	fn absorbed_dose_rate_units() {
 		assert_approx_equal(
-			VolumePerMass::from_m3_per_kg(0.001_f64).to_m3_per_kg(),
-			VolumePerMass::from_L_per_kg(1.0_f64).to_m3_per_kg(), 9
+			AbsorbedDoseRate::from_Gy_per_s(0.001_f64).to_Gy_per_s(),
+			AbsorbedDoseRate::from_mGy_per_s(1.0_f64).to_Gy_per_s(), 9
 		);
 		assert_approx_equal(
-			VolumePerMass::from_m3_per_kg(1.0_f64).to_m3_per_kg() * 1000.0,
-			VolumePerMass::from_m3_per_kg(1.0_f64).to_L_per_kg(), 9
+			AbsorbedDoseRate::from_Gy_per_s(1.0_f64).to_Gy_per_s() * 1000.0,
+			AbsorbedDoseRate::from_Gy_per_s(1.0_f64).to_mGy_per_s(), 9
 		);
 		assert_approx_equal(
-			VolumePerMass::from_m3_per_kg(0.001_f64).to_m3_per_kg(),
-			VolumePerMass::from_liters_per_kilogram(1.0_f64).to_m3_per_kg(), 9
+			AbsorbedDoseRate::from_Gy_per_s(1e-06_f64).to_Gy_per_s(),
+			AbsorbedDoseRate::from_uGy_per_s(1.0_f64).to_Gy_per_s(), 9
 		);
 		assert_approx_equal(
-			VolumePerMass::from_m3_per_kg(1.0_f64).to_m3_per_kg() * 1000.0,
-			VolumePerMass::from_m3_per_kg(1.0_f64).to_liters_per_kilogram(), 9
+			AbsorbedDoseRate::from_Gy_per_s(1.0_f64).to_Gy_per_s() * 1000000.0,
+			AbsorbedDoseRate::from_Gy_per_s(1.0_f64).to_uGy_per_s(), 9
 		);
 		assert_approx_equal(
-			VolumePerMass::from_m3_per_kg(0.001_f64).to_m3_per_kg(),
-			VolumePerMass::from_cc_per_g(1.0_f64).to_m3_per_kg(), 9
+			AbsorbedDoseRate::from_Gy_per_s(0.0002777777777777778_f64).to_Gy_per_s(),
+			AbsorbedDoseRate::from_Gy_per_h(1.0_f64).to_Gy_per_s(), 9
 		);
 		assert_approx_equal(
-			VolumePerMass::from_m3_per_kg(1.0_f64).to_m3_per_kg() * 1000.0,
-			VolumePerMass::from_m3_per_kg(1.0_f64).to_cc_per_g(), 9
+			AbsorbedDoseRate::from_Gy_per_s(1.0_f64).to_Gy_per_s() * 3600.0,
+			AbsorbedDoseRate::from_Gy_per_s(1.0_f64).to_Gy_per_h(), 9
 		);
 		assert_approx_equal(
-			VolumePerMass::from_m3_per_kg(0.001_f64).to_m3_per_kg(),
-			VolumePerMass::from_cubic_centimeters_per_gram(1.0_f64).to_m3_per_kg(), 9
+			AbsorbedDoseRate::from_Gy_per_s(2.7777777777777776e-07_f64).to_Gy_per_s(),
+			AbsorbedDoseRate::from_mGy_per_h(1.0_f64).to_Gy_per_s(), 9
 		);
 		assert_approx_equal(
-			VolumePerMass::from_m3_per_kg(1.0_f64).to_m3_per_kg() * 1000.0,
-			VolumePerMass::from_m3_per_kg(1.0_f64).to_cubic_centimeters_per_gram(), 9
+			AbsorbedDoseRate::from_Gy_per_s(1.0_f64).to_Gy_per_s() * 3600000.0,
+			AbsorbedDoseRate::from_Gy_per_s(1.0_f64).to_mGy_per_h(), 9
 		);
-	}
-
-	#[test]
-	fn inverse_absorbed_dose_units() {
 		assert_approx_equal(
-			InverseAbsorbedDose::from_per_Gy(1000.0_f64).to_per_Gy(),
-			InverseAbsorbedDose::from_per_mGy(1.0_f64).to_per_Gy(), 9
+			AbsorbedDoseRate::from_Gy_per_s(2.7777777777777777e-10_f64).to_Gy_per_s(),
+			AbsorbedDoseRate::from_uGy_per_h(1.0_f64).to_Gy_per_s(), 9
 		);
 		assert_approx_equal(
-			InverseAbsorbedDose::from_per_Gy(1.0_f64).to_per_Gy() * 0.001,
-			InverseAbsorbedDose::from_per_Gy(1.0_f64).to_per_mGy(), 9
+			AbsorbedDoseRate::from_Gy_per_s(1.0_f64).to_Gy_per_s() * 3600000000.0,
+			AbsorbedDoseRate::from_Gy_per_s(1.0_f64).to_uGy_per_h(), 9
 		);
 		assert_approx_equal(
-			InverseAbsorbedDose::from_per_Gy(1000000.0_f64).to_per_Gy(),
-			InverseAbsorbedDose::from_per_uGy(1.0_f64).to_per_Gy(), 9
+			AbsorbedDoseRate::from_Gy_per_s(2.777777777777778e-13_f64).to_Gy_per_s(),
+			AbsorbedDoseRate::from_nGy_per_h(1.0_f64).to_Gy_per_s(), 9
 		);
 		assert_approx_equal(
-			InverseAbsorbedDose::from_per_Gy(1.0_f64).to_per_Gy() * 1e-06,
-			InverseAbsorbedDose::from_per_Gy(1.0_f64).to_per_uGy(), 9
+			AbsorbedDoseRate::from_Gy_per_s(1.0_f64).to_Gy_per_s() * 3600000000000.0,
+			AbsorbedDoseRate::from_Gy_per_s(1.0_f64).to_nGy_per_h(), 9
 		);
+	}
+
+	#[test]
+	fn dose_equivalent_rate_units() {
 		assert_approx_equal(
-			InverseAbsorbedDose::from_per_Gy(1000000000.0_f64).to_per_Gy(),
-			InverseAbsorbedDose::from_per_nGy(1.0_f64).to_per_Gy(), 9
+			DoseEquivalentRate::from_Sv_per_s(0.001_f64).to_Sv_per_s(),
+			DoseEquivalentRate::from_mSv_per_s(1.0_f64).to_Sv_per_s(), 9
 		);
 		assert_approx_equal(
-			InverseAbsorbedDose::from_per_Gy(1.0_f64).to_per_Gy() * 1e-09,
-			InverseAbsorbedDose::from_per_Gy(1.0_f64).to_per_nGy(), 9
+			DoseEquivalentRate::from_Sv_per_s(1.0_f64).to_Sv_per_s() * 1000.0,
+			DoseEquivalentRate::from_Sv_per_s(1.0_f64).to_mSv_per_s(), 9
 		);
 		assert_approx_equal(
-			InverseAbsorbedDose::from_per_Gy(0.001_f64).to_per_Gy(),
-			InverseAbsorbedDose::from_per_kGy(1.0_f64).to_per_Gy(), 9
+			DoseEquivalentRate::from_Sv_per_s(1e-06_f64).to_Sv_per_s(),
+			DoseEquivalentRate::from_uSv_per_s(1.0_f64).to_Sv_per_s(), 9
 		);
 		assert_approx_equal(
-			InverseAbsorbedDose::from_per_Gy(1.0_f64).to_per_Gy() * 1000.0,
-			InverseAbsorbedDose::from_per_Gy(1.0_f64).to_per_kGy(), 9
+			DoseEquivalentRate::from_Sv_per_s(1.0_f64).to_Sv_per_s() * 1000000.0,
+			DoseEquivalentRate::from_Sv_per_s(1.0_f64).to_uSv_per_s(), 9
 		);
 		assert_approx_equal(
-			InverseAbsorbedDose::from_per_Gy(1e-06_f64).to_per_Gy(),
-			InverseAbsorbedDose::from_per_MGy(1.0_f64).to_per_Gy(), 9
+			DoseEquivalentRate::from_Sv_per_s(0.0002777777777777778_f64).to_Sv_per_s(),
+			DoseEquivalentRate::from_Sv_per_h(1.0_f64).to_Sv_per_s(), 9
 		);
 		assert_approx_equal(
-			InverseAbsorbedDose::from_per_Gy(1.0_f64).to_per_Gy() * 1000000.0,
-			InverseAbsorbedDose::from_per_Gy(1.0_f64).to_per_MGy(), 9
+			DoseEquivalentRate::from_Sv_per_s(1.0_f64).to_Sv_per_s() * 3600.0,
+			DoseEquivalentRate::from_Sv_per_s(1.0_f64).to_Sv_per_h(), 9
 		);
 		assert_approx_equal(
-			InverseAbsorbedDose::from_per_Gy(1e-09_f64).to_per_Gy(),
-			InverseAbsorbedDose::from_per_GGy(1.0_f64).to_per_Gy(), 9
+			DoseEquivalentRate::from_Sv_per_s(2.7777777777777776e-07_f64).to_Sv_per_s(),
+			DoseEquivalentRate::from_mSv_per_h(1.0_f64).to_Sv_per_s(), 9
 		);
 		assert_approx_equal(
-			InverseAbsorbedDose::from_per_Gy(1.0_f64).to_per_Gy() * 1000000000.0,
-			InverseAbsorbedDose::from_per_Gy(1.0_f64).to_per_GGy(), 9
+			DoseEquivalentRate::from_Sv_per_s(1.0_f64).to_Sv_per_s() * 3600000.0,
+			DoseEquivalentRate::from_Sv_per_s(1.0_f64).to_mSv_per_h(), 9
 		);
 		assert_approx_equal(
-			InverseAbsorbedDose::from_per_Gy(100.0_f64).to_per_Gy(),
-			InverseAbsorbedDose::from_per_rad(1.0_f64).to_per_Gy(), 9
+			DoseEquivalentRate::from_Sv_per_s(2.7777777777777777e-10_f64).to_Sv_per_s(),
+			DoseEquivalentRate::from_uSv_per_h(1.0_f64).to_Sv_per_s(), 9
 		);
 		assert_approx_equal(
-			InverseAbsorbedDose::from_per_Gy(1.0_f64).to_per_Gy() * 0.01,
-			InverseAbsorbedDose::from_per_Gy(1.0_f64).to_per_rad(), 9
+			DoseEquivalentRate::from_Sv_per_s(1.0_f64).to_Sv_per_s() * 3600000000.0,
+			DoseEquivalentRate::from_Sv_per_s(1.0_f64).to_uSv_per_h(), 9
 		);
 		assert_approx_equal(
-			InverseAbsorbedDose::from_per_Gy(0.1_f64).to_per_Gy(),
-			InverseAbsorbedDose::from_per_krad(1.0_f64).to_per_Gy(), 9
+			DoseEquivalentRate::from_Sv_per_s(2.777777777777778e-13_f64).to_Sv_per_s(),
+			DoseEquivalentRate::from_nSv_per_h(1.0_f64).to_Sv_per_s(), 9
 		);
 		assert_approx_equal(
-			InverseAbsorbedDose::from_per_Gy(1.0_f64).to_per_Gy() * 10.0,
-			InverseAbsorbedDose::from_per_Gy(1.0_f64).to_per_krad(), 9
+			DoseEquivalentRate::from_Sv_per_s(1.0_f64).to_Sv_per_s() * 3600000000000.0,
+			DoseEquivalentRate::from_Sv_per_s(1.0_f64).to_nSv_per_h(), 9
 		);
+	}
+
+	#[test]
+	fn inverse_absorbed_dose_rate_units() {
 		assert_approx_equal(
-			InverseAbsorbedDose::from_per_Gy(100000.0_f64).to_per_Gy(),
-			InverseAbsorbedDose::from_per_mrad(1.0_f64).to_per_Gy(), 9
+			InverseAbsorbedDoseRate::from_s_per_Gy(1000.0_f64).to_s_per_Gy(),
+			InverseAbsorbedDoseRate::from_s_per_mGy(1.0_f64).to_s_per_Gy(), 9
 		);
 		assert_approx_equal(
-			InverseAbsorbedDose::from_per_Gy(1.0_f64).to_per_Gy() * 1e-05,
-			InverseAbsorbedDose::from_per_Gy(1.0_f64).to_per_mrad(), 9
+			InverseAbsorbedDoseRate::from_s_per_Gy(1.0_f64).to_s_per_Gy() * 0.001,
+			InverseAbsorbedDoseRate::from_s_per_Gy(1.0_f64).to_s_per_mGy(), 9
 		);
 		assert_approx_equal(
-			InverseAbsorbedDose::from_per_Gy(100000000.0_f64).to_per_Gy(),
-			InverseAbsorbedDose::from_per_urad(1.0_f64).to_per_Gy(), 9
+			InverseAbsorbedDoseRate::from_s_per_Gy(1000000.0_f64).to_s_per_Gy(),
+			InverseAbsorbedDoseRate::from_s_per_uGy(1.0_f64).to_s_per_Gy(), 9
 		);
 		assert_approx_equal(
-			InverseAbsorbedDose::from_per_Gy(1.0_f64).to_per_Gy() * 1e-08,
-			InverseAbsorbedDose::from_per_Gy(1.0_f64).to_per_urad(), 9
+			InverseAbsorbedDoseRate::from_s_per_Gy(1.0_f64).to_s_per_Gy() * 1e-06,
+			InverseAbsorbedDoseRate::from_s_per_Gy(1.0_f64).to_s_per_uGy(), 9
 		);
 		assert_approx_equal(
-			InverseAbsorbedDose::from_per_Gy(10000.0_f64).to_per_Gy(),
-			InverseAbsorbedDose::from_per_erg(1.0_f64).to_per_Gy(), 9
+			InverseAbsorbedDoseRate::from_s_per_Gy(3600.0_f64).to_s_per_Gy(),
+			InverseAbsorbedDoseRate::from_h_per_Gy(1.0_f64).to_s_per_Gy(), 9
 		);
 		assert_approx_equal(
-			InverseAbsorbedDose::from_per_Gy(1.0_f64).to_per_Gy() * 0.0001,
-			InverseAbsorbedDose::from_per_Gy(1.0_f64).to_per_erg(), 9
+			InverseAbsorbedDoseRate::from_s_per_Gy(1.0_f64).to_s_per_Gy() * 0.0002777777777777778,
+			InverseAbsorbedDoseRate::from_s_per_Gy(1.0_f64).to_h_per_Gy(), 9
 		);
-	}
-
-	#[test]
-	fn inverse_dose_equivalent_units() {
 		assert_approx_equal(
-			InverseDoseEquivalent::from_per_Sv(1000.0_f64).to_per_Sv(),
-			InverseDoseEquivalent::from_per_mSv(1.0_f64).to_per_Sv(), 9
+			InverseAbsorbedDoseRate::from_s_per_Gy(3600000.0_f64).to_s_per_Gy(),
+			InverseAbsorbedDoseRate::from_h_per_mGy(1.0_f64).to_s_per_Gy(), 9
 		);
 		assert_approx_equal(
-			InverseDoseEquivalent::from_per_Sv(1.0_f64).to_per_Sv() * 0.001,
-			InverseDoseEquivalent::from_per_Sv(1.0_f64).to_per_mSv(), 9
+			InverseAbsorbedDoseRate::from_s_per_Gy(1.0_f64).to_s_per_Gy() * 2.7777777777777776e-07,
+			InverseAbsorbedDoseRate::from_s_per_Gy(1.0_f64).to_h_per_mGy(), 9
 		);
 		assert_approx_equal(
-			InverseDoseEquivalent::from_per_Sv(1000000.0_f64).to_per_Sv(),
-			InverseDoseEquivalent::from_per_uSv(1.0_f64).to_per_Sv(), 9
+			InverseAbsorbedDoseRate::from_s_per_Gy(3600000000.0_f64).to_s_per_Gy(),
+			InverseAbsorbedDoseRate::from_h_per_uGy(1.0_f64).to_s_per_Gy(), 9
 		);
 		assert_approx_equal(
-			InverseDoseEquivalent::from_per_Sv(1.0_f64).to_per_Sv() * 1e-06,
-			InverseDoseEquivalent::from_per_Sv(1.0_f64).to_per_uSv(), 9
+			InverseAbsorbedDoseRate::from_s_per_Gy(1.0_f64).to_s_per_Gy() * 2.7777777777777777e-10,
+			InverseAbsorbedDoseRate::from_s_per_Gy(1.0_f64).to_h_per_uGy(), 9
 		);
 		assert_approx_equal(
-			InverseDoseEquivalent::from_per_Sv(1000000000.0_f64).to_per_Sv(),
-			InverseDoseEquivalent::from_per_nSv(1.0_f64).to_per_Sv(), 9
+			InverseAbsorbedDoseRate::from_s_per_Gy(3600000000000.0_f64).to_s_per_Gy(),
+			InverseAbsorbedDoseRate::from_h_per_nGy(1.0_f64).to_s_per_Gy(), 9
 		);
 		assert_approx_equal(
-			InverseDoseEquivalent::from_per_Sv(1.0_f64).to_per_Sv() * 1e-09,
-			InverseDoseEquivalent::from_per_Sv(1.0_f64).to_per_nSv(), 9
+			InverseAbsorbedDoseRate::from_s_per_Gy(1.0_f64).to_s_per_Gy() * 2.777777777777778e-13,
+			InverseAbsorbedDoseRate::from_s_per_Gy(1.0_f64).to_h_per_nGy(), 9
 		);
+	}
+
+	#[test]
+	fn inverse_dose_equivalent_rate_units() {
 		assert_approx_equal(
-			InverseDoseEquivalent::from_per_Sv(0.001_f64).to_per_Sv(),
-			InverseDoseEquivalent::from_per_kSv(1.0_f64).to_per_Sv(), 9
+			InverseDoseEquivalentRate::from_s_per_Sv(1000.0_f64).to_s_per_Sv(),
+			InverseDoseEquivalentRate::from_s_per_mSv(1.0_f64).to_s_per_Sv(), 9
 		);
 		assert_approx_equal(
-			InverseDoseEquivalent::from_per_Sv(1.0_f64).to_per_Sv() * 1000.0,
-			InverseDoseEquivalent::from_per_Sv(1.0_f64).to_per_kSv(), 9
+			InverseDoseEquivalentRate::from_s_per_Sv(1.0_f64).to_s_per_Sv() * 0.001,
+			InverseDoseEquivalentRate::from_s_per_Sv(1.0_f64).to_s_per_mSv(), 9
 		);
 		assert_approx_equal(
-			InverseDoseEquivalent::from_per_Sv(1e-06_f64).to_per_Sv(),
-			InverseDoseEquivalent::from_per_MSv(1.0_f64).to_per_Sv(), 9
+			InverseDoseEquivalentRate::from_s_per_Sv(1000000.0_f64).to_s_per_Sv(),
+			InverseDoseEquivalentRate::from_s_per_uSv(1.0_f64).to_s_per_Sv(), 9
 		);
 		assert_approx_equal(
-			InverseDoseEquivalent::from_per_Sv(1.0_f64).to_per_Sv() * 1000000.0,
-			InverseDoseEquivalent::from_per_Sv(1.0_f64).to_per_MSv(), 9
+			InverseDoseEquivalentRate::from_s_per_Sv(1.0_f64).to_s_per_Sv() * 1e-06,
+			InverseDoseEquivalentRate::from_s_per_Sv(1.0_f64).to_s_per_uSv(), 9
 		);
 		assert_approx_equal(
-			InverseDoseEquivalent::from_per_Sv(1e-09_f64).to_per_Sv(),
-			InverseDoseEquivalent::from_per_GSv(1.0_f64).to_per_Sv(), 9
+			InverseDoseEquivalentRate::from_s_per_Sv(3600.0_f64).to_s_per_Sv(),
+			InverseDoseEquivalentRate::from_h_per_Sv(1.0_f64).to_s_per_Sv(), 9
 		);
 		assert_approx_equal(
-			InverseDoseEquivalent::from_per_Sv(1.0_f64).to_per_Sv() * 1000000000.0,
-			InverseDoseEquivalent::from_per_Sv(1.0_f64).to_per_GSv(), 9
+			InverseDoseEquivalentRate::from_s_per_Sv(1.0_f64).to_s_per_Sv() * 0.0002777777777777778,
+			InverseDoseEquivalentRate::from_s_per_Sv(1.0_f64).to_h_per_Sv(), 9
 		);
 		assert_approx_equal(
-			InverseDoseEquivalent::from_per_Sv(100.0_f64).to_per_Sv(),
-			InverseDoseEquivalent::from_per_rem(1.0_f64).to_per_Sv(), 9
+			InverseDoseEquivalentRate::from_s_per_Sv(3600000.0_f64).to_s_per_Sv(),
+			InverseDoseEquivalentRate::from_h_per_mSv(1.0_f64).to_s_per_Sv(), 9
 		);
 		assert_approx_equal(
-			InverseDoseEquivalent::from_per_Sv(1.0_f64).to_per_Sv() * 0.01,
-			InverseDoseEquivalent::from_per_Sv(1.0_f64).to_per_rem(), 9
+			InverseDoseEquivalentRate::from_s_per_Sv(1.0_f64).to_s_per_Sv() * 2.7777777777777776e-07,
+			InverseDoseEquivalentRate::from_s_per_Sv(1.0_f64).to_h_per_mSv(), 9
 		);
 		assert_approx_equal(
-			InverseDoseEquivalent::from_per_Sv(100000.0_f64).to_per_Sv(),
-			InverseDoseEquivalent::from_per_mrem(1.0_f64).to_per_Sv(), 9
+			InverseDoseEquivalentRate::from_s_per_Sv(3600000000.0_f64).to_s_per_Sv(),
+			InverseDoseEquivalentRate::from_h_per_uSv(1.0_f64).to_s_per_Sv(), 9
 		);
 		assert_approx_equal(
-			InverseDoseEquivalent::from_per_Sv(1.0_f64).to_per_Sv() * 1e-05,
-			InverseDoseEquivalent::from_per_Sv(1.0_f64).to_per_mrem(), 9
+			InverseDoseEquivalentRate::from_s_per_Sv(1.0_f64).to_s_per_Sv() * 2.7777777777777777e-10,
+			InverseDoseEquivalentRate::from_s_per_Sv(1.0_f64).to_h_per_uSv(), 9
 		);
 		assert_approx_equal(
-			InverseDoseEquivalent::from_per_Sv(0.1_f64).to_per_Sv(),
-			InverseDoseEquivalent::from_per_krem(1.0_f64).to_per_Sv(), 9
+			InverseDoseEquivalentRate::from_s_per_Sv(3600000000000.0_f64).to_s_per_Sv(),
+			InverseDoseEquivalentRate::from_h_per_nSv(1.0_f64).to_s_per_Sv(), 9
 		);
 		assert_approx_equal(
-			InverseDoseEquivalent::from_per_Sv(1.0_f64).to_per_Sv() * 10.0,
-			InverseDoseEquivalent::from_per_Sv(1.0_f64).to_per_krem(), 9
+			InverseDoseEquivalentRate::from_s_per_Sv(1.0_f64).to_s_per_Sv() * 2.777777777777778e-13,
+			InverseDoseEquivalentRate::from_s_per_Sv(1.0_f64).to_h_per_nSv(), 9
 		);
 	}
 }
@@ -0,0 +1,905 @@
+
+//! This module provides the `si!` macro for writing unit values as typed 
+//! literals, such as `si!(9.81 m/s^2)`.
+
+/// The `si!` macro creates a unit struct from a number followed by its unit 
+/// symbol, eg `si!(5.0 m)` is equivalent to `Distance::from_m(5.0)`. 
+/// 
+/// The SI units of each unit type are written as they are displayed, with `^2` 
+/// and `^3` for powers and `.` for multiplication (eg `si!(9.81 m/s^2)`, 
+/// `si!(2.0 kg.m^2)`, or `si!(3.3 V)`), and any of the other supported units 
+/// may be written using the name of its `from_...` function (eg `si!(5.0 km)` 
+/// or `si!(60.0 mph)`). The number may be a literal or an expression in 
+/// parentheses, eg `si!((x * 2.0) m)`. 
+/// 
+/// A unit symbol that is not recognized causes a compile error. Note that `K` 
+/// always creates a `Temperature` (not a `TemperatureInterval`), and that unit 
+/// symbols which are shared by more than one unit type (such as `J/K`, which 
+/// is the unit for both `Entropy` and `HeatCapacity`) are not supported, so 
+/// use the unit type's constructor functions instead.
+/// 
+/// # Examples
+/// ```rust
+/// use simple_si_units::si;
+/// use simple_si_units::base::Distance;
+/// use simple_si_units::mechanical::Acceleration;
+/// 
+/// assert_eq!(si!(5.0 m), Distance::from_m(5.0));
+/// assert_eq!(si!(9.81 m/s^2), Acceleration::from_mps2(9.81));
+/// assert_eq!(si!(1.5 km), Distance::from_m(1500.0));
+/// ```
+#[macro_export]
+macro_rules! si {
+	($value:literal $($unit:tt)+) => { $crate::si!(@unit $value; $($unit)+) };
+	(($value:expr) $($unit:tt)+) => { $crate::si!(@unit ($value); $($unit)+) };
+	(@unit $value:expr; mol) => { $crate::base::Amount::from_mol($value) };
+	(@unit $value:expr; A) => { $crate::base::Current::from_A($value) };
+	(@unit $value:expr; m) => { $crate::base::Distance::from_m($value) };
+	(@unit $value:expr; 1/mol) => { $crate::base::InverseAmount::from_per_mol($value) };
+	(@unit $value:expr; 1/A) => { $crate::base::InverseCurrent::from_per_A($value) };
+	(@unit $value:expr; 1/m) => { $crate::base::InverseDistance::from_per_m($value) };
+	(@unit $value:expr; 1/cd) => { $crate::base::InverseLuminosity::from_per_cd($value) };
+	(@unit $value:expr; 1/kg) => { $crate::base::InverseMass::from_per_kg($value) };
+	(@unit $value:expr; 1/K) => { $crate::base::InverseTemperature::from_per_K($value) };
+	(@unit $value:expr; cd) => { $crate::base::Luminosity::from_cd($value) };
+	(@unit $value:expr; kg) => { $crate::base::Mass::from_kg($value) };
+	(@unit $value:expr; K) => { $crate::base::Temperature::from_K($value) };
+	(@unit $value:expr; s) => { $crate::base::Time::from_s($value) };
+	(@unit $value:expr; mol/s) => { $crate::chemical::CatalyticActivity::from_molps($value) };
+	(@unit $value:expr; mol/m^3) => { $crate::chemical::Concentration::from_molpm3($value) };
+	(@unit $value:expr; s/mol) => { $crate::chemical::InverseCatalyticActivity::from_s_per_mol($value) };
+	(@unit $value:expr; mol/J) => { $crate::chemical::InverseMolarEnergy::from_mol_per_J($value) };
+	(@unit $value:expr; mol.K/J) => { $crate::chemical::InverseMolarEntropy::from_molK_per_J($value) };
+	(@unit $value:expr; kg.K/J) => { $crate::chemical::InverseSpecificHeatCapacity::from_kgK_per_J($value) };
+	(@unit $value:expr; mol/kg) => { $crate::chemical::Molality::from_molpkg($value) };
+	(@unit $value:expr; J/mol) => { $crate::chemical::MolarEnergy::from_J_per_mol($value) };
+	(@unit $value:expr; J/mol.K) => { $crate::chemical::MolarEntropy::from_J_per_molK($value) };
+	(@unit $value:expr; kg/mol) => { $crate::chemical::MolarMass::from_kgpmol($value) };
+	(@unit $value:expr; m^3/mol) => { $crate::chemical::MolarVolume::from_m3_per_mol($value) };
+	(@unit $value:expr; J/kg.K) => { $crate::chemical::SpecificHeatCapacity::from_J_per_kgK($value) };
+	(@unit $value:expr; W/K) => { $crate::chemical::ThermalConductance::from_W_per_K($value) };
+	(@unit $value:expr; W/m.K) => { $crate::chemical::ThermalConductivity::from_W_per_mK($value) };
+	(@unit $value:expr; K/W) => { $crate::chemical::ThermalResistance::from_K_per_W($value) };
+	(@unit $value:expr; m.K/W) => { $crate::chemical::ThermalResistivity::from_mK_per_W($value) };
+	(@unit $value:expr; m^2/lm) => { $crate::electromagnetic::AreaPerLumen::from_m2_per_lm($value) };
+	(@unit $value:expr; F) => { $crate::electromagnetic::Capacitance::from_F($value) };
+	(@unit $value:expr; C) => { $crate::electromagnetic::Charge::from_C($value) };
+	(@unit $value:expr; S) => { $crate::electromagnetic::Conductance::from_S($value) };
+	(@unit $value:expr; 1/F) => { $crate::electromagnetic::Elastance::from_per_F($value) };
+	(@unit $value:expr; V/m) => { $crate::electromagnetic::ElectricField::from_Vpm($value) };
+	(@unit $value:expr; lux) => { $crate::electromagnetic::Illuminance::from_lux($value) };
+	(@unit $value:expr; H) => { $crate::electromagnetic::Inductance::from_H($value) };
+	(@unit $value:expr; 1/C) => { $crate::electromagnetic::InverseCharge::from_per_C($value) };
+	(@unit $value:expr; m/V) => { $crate::electromagnetic::InverseElectricField::from_m_per_V($value) };
+	(@unit $value:expr; 1/H) => { $crate::electromagnetic::InverseInductance::from_per_H($value) };
+	(@unit $value:expr; m^2/W) => { $crate::electromagnetic::InverseIrradiance::from_m2_per_W($value) };
+	(@unit $value:expr; m/C) => { $crate::electromagnetic::InverseLinearChargeDensity::from_m_per_C($value) };
+	(@unit $value:expr; 1/lm) => { $crate::electromagnetic::InverseLuminousFlux::from_per_lm($value) };
+	(@unit $value:expr; m/A) => { $crate::electromagnetic::InverseMagneticFieldStrength::from_m_per_A($value) };
+	(@unit $value:expr; 1/Wb) => { $crate::electromagnetic::InverseMagneticFlux::from_per_Wb($value) };
+	(@unit $value:expr; m^2/Wb) => { $crate::electromagnetic::InverseMagneticFluxDensity::from_m2_per_Wb($value) };
+	(@unit $value:expr; m^2.sr/W) => { $crate::electromagnetic::InverseRadiance::from_m2sr_per_W($value) };
+	(@unit $value:expr; m^2/C) => { $crate::electromagnetic::InverseSurfaceChargeDensity::from_m2_per_C($value) };
+	(@unit $value:expr; 1/V) => { $crate::electromagnetic::InverseVoltage::from_per_V($value) };
+	(@unit $value:expr; m^3/C) => { $crate::electromagnetic::InverseVolumeChargeDensity::from_m3_per_C($value) };
+	(@unit $value:expr; W/m^2) => { $crate::electromagnetic::Irradiance::from_W_per_m2($value) };
+	(@unit $value:expr; C/m) => { $crate::electromagnetic::LinearChargeDensity::from_Cpm($value) };
+	(@unit $value:expr; lm) => { $crate::electromagnetic::LuminousFlux::from_lm($value) };
+	(@unit $value:expr; A/m) => { $crate::electromagnetic::MagneticFieldStrength::from_Apm($value) };
+	(@unit $value:expr; Wb) => { $crate::electromagnetic::MagneticFlux::from_Wb($value) };
+	(@unit $value:expr; T) => { $crate::electromagnetic::MagneticFluxDensity::from_T($value) };
+	(@unit $value:expr; W/m^2.sr) => { $crate::electromagnetic::Radiance::from_W_per_m2sr($value) };
+	(@unit $value:expr; Ohm) => { $crate::electromagnetic::Resistance::from_Ohm($value) };
+	(@unit $value:expr; C/m^2) => { $crate::electromagnetic::SurfaceChargeDensity::from_Cpm2($value) };
+	(@unit $value:expr; V) => { $crate::electromagnetic::Voltage::from_V($value) };
+	(@unit $value:expr; C/m^3) => { $crate::electromagnetic::VolumeChargeDensity::from_Cpm3($value) };
+	(@unit $value:expr; rad) => { $crate::geometry::Angle::from_rad($value) };
+	(@unit $value:expr; m^2) => { $crate::geometry::Area::from_m2($value) };
+	(@unit $value:expr; 1/rad) => { $crate::geometry::InverseAngle::from_per_rad($value) };
+	(@unit $value:expr; 1/m^2) => { $crate::geometry::InverseArea::from_per_m2($value) };
+	(@unit $value:expr; 1/sr) => { $crate::geometry::InverseSolidAngle::from_per_sr($value) };
+	(@unit $value:expr; 1/m^3) => { $crate::geometry::InverseVolume::from_per_m3($value) };
+	(@unit $value:expr; sr) => { $crate::geometry::SolidAngle::from_sr($value) };
+	(@unit $value:expr; m^3) => { $crate::geometry::Volume::from_m3($value) };
+	(@unit $value:expr; m/s^2) => { $crate::mechanical::Acceleration::from_mps2($value) };
+	(@unit $value:expr; rad/s^2) => { $crate::mechanical::AngularAcceleration::from_radps2($value) };
+	(@unit $value:expr; kg.m^2.rad/s) => { $crate::mechanical::AngularMomentum::from_kgm2radps($value) };
+	(@unit $value:expr; rad/s) => { $crate::mechanical::AngularVelocity::from_radps($value) };
+	(@unit $value:expr; kg/m^2) => { $crate::mechanical::AreaDensity::from_kgpm2($value) };
+	(@unit $value:expr; m^2/kg) => { $crate::mechanical::AreaPerMass::from_m2_per_kg($value) };
+	(@unit $value:expr; kg/m^3) => { $crate::mechanical::Density::from_kgpm3($value) };
+	(@unit $value:expr; Pa.s) => { $crate::mechanical::DynamicViscosity::from_Pas($value) };
+	(@unit $value:expr; J) => { $crate::mechanical::Energy::from_J($value) };
+	(@unit $value:expr; N) => { $crate::mechanical::Force::from_N($value) };
+	(@unit $value:expr; Hz) => { $crate::mechanical::Frequency::from_Hz($value) };
+	(@unit $value:expr; s^2/m) => { $crate::mechanical::InverseAcceleration::from_s2pm($value) };
+	(@unit $value:expr; s^2/rad) => { $crate::mechanical::InverseAngularAcceleration::from_s2prad($value) };
+	(@unit $value:expr; s/kg.m^2.rad) => { $crate::mechanical::InverseAngularMomentum::from_s_per_kgm2rad($value) };
+	(@unit $value:expr; s/rad) => { $crate::mechanical::InverseAngularVelocity::from_s_per_rad($value) };
+	(@unit $value:expr; 1/Pa.s) => { $crate::mechanical::InverseDynamicViscosity::from_per_Pas($value) };
+	(@unit $value:expr; 1/J) => { $crate::mechanical::InverseEnergy::from_per_J($value) };
+	(@unit $value:expr; 1/N) => { $crate::mechanical::InverseForce::from_per_N($value) };
+	(@unit $value:expr; s^3/m) => { $crate::mechanical::InverseJerk::from_s3pm($value) };
+	(@unit $value:expr; s/m^2) => { $crate::mechanical::InverseKinematicViscosity::from_s_per_m2($value) };
+	(@unit $value:expr; s/kg) => { $crate::mechanical::InverseMassFlowRate::from_s_per_kg($value) };
+	(@unit $value:expr; 1/kg.m^2) => { $crate::mechanical::InverseMomentOfInertia::from_per_kgm2($value) };
+	(@unit $value:expr; s/kg.m) => { $crate::mechanical::InverseMomentum::from_s_per_kgm($value) };
+	(@unit $value:expr; 1/W) => { $crate::mechanical::InversePower::from_per_W($value) };
+	(@unit $value:expr; 1/Pa) => { $crate::mechanical::InversePressure::from_per_Pa($value) };
+	(@unit $value:expr; 1/Nm) => { $crate::mechanical::InverseTorque::from_per_Nm($value) };
+	(@unit $value:expr; s/m^3) => { $crate::mechanical::InverseVolumetricFlowRate::from_s_per_m3($value) };
+	(@unit $value:expr; m/s^3) => { $crate::mechanical::Jerk::from_mps3($value) };
+	(@unit $value:expr; m^2/s) => { $crate::mechanical::KinematicViscosity::from_m2ps($value) };
+	(@unit $value:expr; kg/s) => { $crate::mechanical::MassFlowRate::from_kgps($value) };
+	(@unit $value:expr; kg.m^2) => { $crate::mechanical::MomentOfInertia::from_kgm2($value) };
+	(@unit $value:expr; kg.m/s) => { $crate::mechanical::Momentum::from_kgmps($value) };
+	(@unit $value:expr; W) => { $crate::mechanical::Power::from_W($value) };
+	(@unit $value:expr; Pa) => { $crate::mechanical::Pressure::from_Pa($value) };
+	(@unit $value:expr; s/m) => { $crate::mechanical::TimePerDistance::from_spm($value) };
+	(@unit $value:expr; Nm) => { $crate::mechanical::Torque::from_Nm($value) };
+	(@unit $value:expr; m/s) => { $crate::mechanical::Velocity::from_mps($value) };
+	(@unit $value:expr; m^3/kg) => { $crate::mechanical::VolumePerMass::from_m3_per_kg($value) };
+	(@unit $value:expr; m^3/s) => { $crate::mechanical::VolumetricFlowRate::from_m3ps($value) };
+	(@unit $value:expr; Gy) => { $crate::nuclear::AbsorbedDose::from_Gy($value) };
+	(@unit $value:expr; Gy/s) => { $crate::nuclear::AbsorbedDoseRate::from_Gy_per_s($value) };
+	(@unit $value:expr; Sv) => { $crate::nuclear::DoseEquivalent::from_Sv($value) };
+	(@unit $value:expr; Sv/s) => { $crate::nuclear::DoseEquivalentRate::from_Sv_per_s($value) };
+	(@unit $value:expr; 1/Gy) => { $crate::nuclear::InverseAbsorbedDose::from_per_Gy($value) };
+	(@unit $value:expr; s/Gy) => { $crate::nuclear::InverseAbsorbedDoseRate::from_s_per_Gy($value) };
+	(@unit $value:expr; 1/Sv) => { $crate::nuclear::InverseDoseEquivalent::from_per_Sv($value) };
+	(@unit $value:expr; s/Sv) => { $crate::nuclear::InverseDoseEquivalentRate::from_s_per_Sv($value) };
+	(@unit $value:expr; Bq) => { $crate::nuclear::Radioactivity::from_Bq($value) };
+	(@unit $value:expr; meters) => { $crate::base::Distance::from_meters($value) };
+	(@unit $value:expr; cm) => { $crate::base::Distance::from_cm($value) };
+	(@unit $value:expr; mm) => { $crate::base::Distance::from_mm($value) };
+	(@unit $value:expr; um) => { $crate::base::Distance::from_um($value) };
+	(@unit $value:expr; nm) => { $crate::base::Distance::from_nm($value) };
+	(@unit $value:expr; pm) => { $crate::base::Distance::from_pm($value) };
+	(@unit $value:expr; km) => { $crate::base::Distance::from_km($value) };
+	(@unit $value:expr; au) => { $crate::base::Distance::from_au($value) };
+	(@unit $value:expr; parsec) => { $crate::base::Distance::from_parsec($value) };
+	(@unit $value:expr; lyr) => { $crate::base::Distance::from_lyr($value) };
+	(@unit $value:expr; feet) => { $crate::base::Distance::from_feet($value) };
+	(@unit $value:expr; inches) => { $crate::base::Distance::from_inches($value) };
+	(@unit $value:expr; yards) => { $crate::base::Distance::from_yards($value) };
+	(@unit $value:expr; miles) => { $crate::base::Distance::from_miles($value) };
+	(@unit $value:expr; nautical_miles) => { $crate::base::Distance::from_nautical_miles($value) };
+	(@unit $value:expr; kilograms) => { $crate::base::Mass::from_kilograms($value) };
+	(@unit $value:expr; g) => { $crate::base::Mass::from_g($value) };
+	(@unit $value:expr; grams) => { $crate::base::Mass::from_grams($value) };
+	(@unit $value:expr; mg) => { $crate::base::Mass::from_mg($value) };
+	(@unit $value:expr; ug) => { $crate::base::Mass::from_ug($value) };
+	(@unit $value:expr; ng) => { $crate::base::Mass::from_ng($value) };
+	(@unit $value:expr; pg) => { $crate::base::Mass::from_pg($value) };
+	(@unit $value:expr; tons) => { $crate::base::Mass::from_tons($value) };
+	(@unit $value:expr; tonnes) => { $crate::base::Mass::from_tonnes($value) };
+	(@unit $value:expr; short_tons) => { $crate::base::Mass::from_short_tons($value) };
+	(@unit $value:expr; pounds) => { $crate::base::Mass::from_pounds($value) };
+	(@unit $value:expr; ounces) => { $crate::base::Mass::from_ounces($value) };
+	(@unit $value:expr; earth_mass) => { $crate::base::Mass::from_earth_mass($value) };
+	(@unit $value:expr; jupiter_mass) => { $crate::base::Mass::from_jupiter_mass($value) };
+	(@unit $value:expr; solar_mass) => { $crate::base::Mass::from_solar_mass($value) };
+	(@unit $value:expr; kgps) => { $crate::mechanical::MassFlowRate::from_kgps($value) };
+	(@unit $value:expr; kilograms_per_second) => { $crate::mechanical::MassFlowRate::from_kilograms_per_second($value) };
+	(@unit $value:expr; gps) => { $crate::mechanical::MassFlowRate::from_gps($value) };
+	(@unit $value:expr; grams_per_second) => { $crate::mechanical::MassFlowRate::from_grams_per_second($value) };
+	(@unit $value:expr; kgph) => { $crate::mechanical::MassFlowRate::from_kgph($value) };
+	(@unit $value:expr; tph) => { $crate::mechanical::MassFlowRate::from_tph($value) };
+	(@unit $value:expr; seconds) => { $crate::base::Time::from_seconds($value) };
+	(@unit $value:expr; ms) => { $crate::base::Time::from_ms($value) };
+	(@unit $value:expr; milliseconds) => { $crate::base::Time::from_milliseconds($value) };
+	(@unit $value:expr; us) => { $crate::base::Time::from_us($value) };
+	(@unit $value:expr; microseconds) => { $crate::base::Time::from_microseconds($value) };
+	(@unit $value:expr; ns) => { $crate::base::Time::from_ns($value) };
+	(@unit $value:expr; nanoseconds) => { $crate::base::Time::from_nanoseconds($value) };
+	(@unit $value:expr; ps) => { $crate::base::Time::from_ps($value) };
+	(@unit $value:expr; min) => { $crate::base::Time::from_min($value) };
+	(@unit $value:expr; minutes) => { $crate::base::Time::from_minutes($value) };
+	(@unit $value:expr; hr) => { $crate::base::Time::from_hr($value) };
+	(@unit $value:expr; hours) => { $crate::base::Time::from_hours($value) };
+	(@unit $value:expr; days) => { $crate::base::Time::from_days($value) };
+	(@unit $value:expr; weeks) => { $crate::base::Time::from_weeks($value) };
+	(@unit $value:expr; yr) => { $crate::base::Time::from_yr($value) };
+	(@unit $value:expr; kyr) => { $crate::base::Time::from_kyr($value) };
+	(@unit $value:expr; Myr) => { $crate::base::Time::from_Myr($value) };
+	(@unit $value:expr; Gyr) => { $crate::base::Time::from_Gyr($value) };
+	(@unit $value:expr; count) => { $crate::base::Amount::from_count($value) };
+	(@unit $value:expr; moles) => { $crate::base::Amount::from_moles($value) };
+	(@unit $value:expr; mmol) => { $crate::base::Amount::from_mmol($value) };
+	(@unit $value:expr; umol) => { $crate::base::Amount::from_umol($value) };
+	(@unit $value:expr; nmol) => { $crate::base::Amount::from_nmol($value) };
+	(@unit $value:expr; pmol) => { $crate::base::Amount::from_pmol($value) };
+	(@unit $value:expr; amps) => { $crate::base::Current::from_amps($value) };
+	(@unit $value:expr; mA) => { $crate::base::Current::from_mA($value) };
+	(@unit $value:expr; uA) => { $crate::base::Current::from_uA($value) };
+	(@unit $value:expr; nA) => { $crate::base::Current::from_nA($value) };
+	(@unit $value:expr; kA) => { $crate::base::Current::from_kA($value) };
+	(@unit $value:expr; MA) => { $crate::base::Current::from_MA($value) };
+	(@unit $value:expr; GA) => { $crate::base::Current::from_GA($value) };
+	(@unit $value:expr; candela) => { $crate::base::Luminosity::from_candela($value) };
+	(@unit $value:expr; mcd) => { $crate::base::Luminosity::from_mcd($value) };
+	(@unit $value:expr; ucd) => { $crate::base::Luminosity::from_ucd($value) };
+	(@unit $value:expr; ncd) => { $crate::base::Luminosity::from_ncd($value) };
+	(@unit $value:expr; kcd) => { $crate::base::Luminosity::from_kcd($value) };
+	(@unit $value:expr; Mcd) => { $crate::base::Luminosity::from_Mcd($value) };
+	(@unit $value:expr; Gcd) => { $crate::base::Luminosity::from_Gcd($value) };
+	(@unit $value:expr; radians) => { $crate::geometry::Angle::from_radians($value) };
+	(@unit $value:expr; degrees) => { $crate::geometry::Angle::from_degrees($value) };
+	(@unit $value:expr; deg) => { $crate::geometry::Angle::from_deg($value) };
+	(@unit $value:expr; arcminutes) => { $crate::geometry::Angle::from_arcminutes($value) };
+	(@unit $value:expr; arcmin) => { $crate::geometry::Angle::from_arcmin($value) };
+	(@unit $value:expr; arcseconds) => { $crate::geometry::Angle::from_arcseconds($value) };
+	(@unit $value:expr; arcsec) => { $crate::geometry::Angle::from_arcsec($value) };
+	(@unit $value:expr; gradians) => { $crate::geometry::Angle::from_gradians($value) };
+	(@unit $value:expr; grad) => { $crate::geometry::Angle::from_grad($value) };
+	(@unit $value:expr; steradians) => { $crate::geometry::SolidAngle::from_steradians($value) };
+	(@unit $value:expr; radps) => { $crate::mechanical::AngularVelocity::from_radps($value) };
+	(@unit $value:expr; radians_per_second) => { $crate::mechanical::AngularVelocity::from_radians_per_second($value) };
+	(@unit $value:expr; degrees_per_second) => { $crate::mechanical::AngularVelocity::from_degrees_per_second($value) };
+	(@unit $value:expr; degps) => { $crate::mechanical::AngularVelocity::from_degps($value) };
+	(@unit $value:expr; rps) => { $crate::mechanical::AngularVelocity::from_rps($value) };
+	(@unit $value:expr; rph) => { $crate::mechanical::AngularVelocity::from_rph($value) };
+	(@unit $value:expr; radps2) => { $crate::mechanical::AngularAcceleration::from_radps2($value) };
+	(@unit $value:expr; radians_per_second_squared) => { $crate::mechanical::AngularAcceleration::from_radians_per_second_squared($value) };
+	(@unit $value:expr; degrees_per_second_squared) => { $crate::mechanical::AngularAcceleration::from_degrees_per_second_squared($value) };
+	(@unit $value:expr; rps2) => { $crate::mechanical::AngularAcceleration::from_rps2($value) };
+	(@unit $value:expr; rpm2) => { $crate::mechanical::AngularAcceleration::from_rpm2($value) };
+	(@unit $value:expr; degps2) => { $crate::mechanical::AngularAcceleration::from_degps2($value) };
+	(@unit $value:expr; rph2) => { $crate::mechanical::AngularAcceleration::from_rph2($value) };
+	(@unit $value:expr; kgm2) => { $crate::mechanical::MomentOfInertia::from_kgm2($value) };
+	(@unit $value:expr; kilogram_meters_squared) => { $crate::mechanical::MomentOfInertia::from_kilogram_meters_squared($value) };
+	(@unit $value:expr; gcm2) => { $crate::mechanical::MomentOfInertia::from_gcm2($value) };
+	(@unit $value:expr; gm2) => { $crate::mechanical::MomentOfInertia::from_gm2($value) };
+	(@unit $value:expr; kgm2radps) => { $crate::mechanical::AngularMomentum::from_kgm2radps($value) };
+	(@unit $value:expr; kilogram_meters_squared_radians_per_second) => { $crate::mechanical::AngularMomentum::from_kilogram_meters_squared_radians_per_second($value) };
+	(@unit $value:expr; gcm2radps) => { $crate::mechanical::AngularMomentum::from_gcm2radps($value) };
+	(@unit $value:expr; newton_meters) => { $crate::mechanical::Torque::from_newton_meters($value) };
+	(@unit $value:expr; ftlb) => { $crate::mechanical::Torque::from_ftlb($value) };
+	(@unit $value:expr; hertz) => { $crate::mechanical::Frequency::from_hertz($value) };
+	(@unit $value:expr; kHz) => { $crate::mechanical::Frequency::from_kHz($value) };
+	(@unit $value:expr; MHz) => { $crate::mechanical::Frequency::from_MHz($value) };
+	(@unit $value:expr; GHz) => { $crate::mechanical::Frequency::from_GHz($value) };
+	(@unit $value:expr; THz) => { $crate::mechanical::Frequency::from_THz($value) };
+	(@unit $value:expr; m2) => { $crate::geometry::Area::from_m2($value) };
+	(@unit $value:expr; square_meters) => { $crate::geometry::Area::from_square_meters($value) };
+	(@unit $value:expr; cm2) => { $crate::geometry::Area::from_cm2($value) };
+	(@unit $value:expr; square_cm) => { $crate::geometry::Area::from_square_cm($value) };
+	(@unit $value:expr; mm2) => { $crate::geometry::Area::from_mm2($value) };
+	(@unit $value:expr; um2) => { $crate::geometry::Area::from_um2($value) };
+	(@unit $value:expr; nm2) => { $crate::geometry::Area::from_nm2($value) };
+	(@unit $value:expr; km2) => { $crate::geometry::Area::from_km2($value) };
+	(@unit $value:expr; kgpm2) => { $crate::mechanical::AreaDensity::from_kgpm2($value) };
+	(@unit $value:expr; kilograms_per_square_meter) => { $crate::mechanical::AreaDensity::from_kilograms_per_square_meter($value) };
+	(@unit $value:expr; gpm2) => { $crate::mechanical::AreaDensity::from_gpm2($value) };
+	(@unit $value:expr; grams_per_square_meter) => { $crate::mechanical::AreaDensity::from_grams_per_square_meter($value) };
+	(@unit $value:expr; gpcm2) => { $crate::mechanical::AreaDensity::from_gpcm2($value) };
+	(@unit $value:expr; grams_per_square_cm) => { $crate::mechanical::AreaDensity::from_grams_per_square_cm($value) };
+	(@unit $value:expr; m3) => { $crate::geometry::Volume::from_m3($value) };
+	(@unit $value:expr; cubic_meters) => { $crate::geometry::Volume::from_cubic_meters($value) };
+	(@unit $value:expr; cc) => { $crate::geometry::Volume::from_cc($value) };
+	(@unit $value:expr; L) => { $crate::geometry::Volume::from_L($value) };
+	(@unit $value:expr; liters) => { $crate::geometry::Volume::from_liters($value) };
+	(@unit $value:expr; mL) => { $crate::geometry::Volume::from_mL($value) };
+	(@unit $value:expr; milliliters) => { $crate::geometry::Volume::from_milliliters($value) };
+	(@unit $value:expr; us_gallons) => { $crate::geometry::Volume::from_us_gallons($value) };
+	(@unit $value:expr; imperial_gallons) => { $crate::geometry::Volume::from_imperial_gallons($value) };
+	(@unit $value:expr; uL) => { $crate::geometry::Volume::from_uL($value) };
+	(@unit $value:expr; nL) => { $crate::geometry::Volume::from_nL($value) };
+	(@unit $value:expr; pL) => { $crate::geometry::Volume::from_pL($value) };
+	(@unit $value:expr; kL) => { $crate::geometry::Volume::from_kL($value) };
+	(@unit $value:expr; ML) => { $crate::geometry::Volume::from_ML($value) };
+	(@unit $value:expr; GL) => { $crate::geometry::Volume::from_GL($value) };
+	(@unit $value:expr; m3ps) => { $crate::mechanical::VolumetricFlowRate::from_m3ps($value) };
+	(@unit $value:expr; cubic_meters_per_second) => { $crate::mechanical::VolumetricFlowRate::from_cubic_meters_per_second($value) };
+	(@unit $value:expr; Lps) => { $crate::mechanical::VolumetricFlowRate::from_Lps($value) };
+	(@unit $value:expr; liters_per_second) => { $crate::mechanical::VolumetricFlowRate::from_liters_per_second($value) };
+	(@unit $value:expr; Lpm) => { $crate::mechanical::VolumetricFlowRate::from_Lpm($value) };
+	(@unit $value:expr; liters_per_minute) => { $crate::mechanical::VolumetricFlowRate::from_liters_per_minute($value) };
+	(@unit $value:expr; mLpm) => { $crate::mechanical::VolumetricFlowRate::from_mLpm($value) };
+	(@unit $value:expr; m3ph) => { $crate::mechanical::VolumetricFlowRate::from_m3ph($value) };
+	(@unit $value:expr; gpm) => { $crate::mechanical::VolumetricFlowRate::from_gpm($value) };
+	(@unit $value:expr; kgpL) => { $crate::mechanical::Density::from_kgpL($value) };
+	(@unit $value:expr; kilograms_per_liter) => { $crate::mechanical::Density::from_kilograms_per_liter($value) };
+	(@unit $value:expr; gpcc) => { $crate::mechanical::Density::from_gpcc($value) };
+	(@unit $value:expr; grams_per_cubic_centimeter) => { $crate::mechanical::Density::from_grams_per_cubic_centimeter($value) };
+	(@unit $value:expr; kgpm3) => { $crate::mechanical::Density::from_kgpm3($value) };
+	(@unit $value:expr; kilograms_per_cubic_meter) => { $crate::mechanical::Density::from_kilograms_per_cubic_meter($value) };
+	(@unit $value:expr; gpm3) => { $crate::mechanical::Density::from_gpm3($value) };
+	(@unit $value:expr; mps) => { $crate::mechanical::Velocity::from_mps($value) };
+	(@unit $value:expr; meters_per_second) => { $crate::mechanical::Velocity::from_meters_per_second($value) };
+	(@unit $value:expr; cmps) => { $crate::mechanical::Velocity::from_cmps($value) };
+	(@unit $value:expr; mmps) => { $crate::mechanical::Velocity::from_mmps($value) };
+	(@unit $value:expr; mmph) => { $crate::mechanical::Velocity::from_mmph($value) };
+	(@unit $value:expr; kph) => { $crate::mechanical::Velocity::from_kph($value) };
+	(@unit $value:expr; mph) => { $crate::mechanical::Velocity::from_mph($value) };
+	(@unit $value:expr; knots) => { $crate::mechanical::Velocity::from_knots($value) };
+	(@unit $value:expr; kmps) => { $crate::mechanical::Velocity::from_kmps($value) };
+	(@unit $value:expr; c) => { $crate::mechanical::Velocity::from_c($value) };
+	(@unit $value:expr; mps2) => { $crate::mechanical::Acceleration::from_mps2($value) };
+	(@unit $value:expr; meters_per_second_squared) => { $crate::mechanical::Acceleration::from_meters_per_second_squared($value) };
+	(@unit $value:expr; mmps2) => { $crate::mechanical::Acceleration::from_mmps2($value) };
+	(@unit $value:expr; kilometers_per_hour_squared) => { $crate::mechanical::Acceleration::from_kilometers_per_hour_squared($value) };
+	(@unit $value:expr; kph2) => { $crate::mechanical::Acceleration::from_kph2($value) };
+	(@unit $value:expr; mps3) => { $crate::mechanical::Jerk::from_mps3($value) };
+	(@unit $value:expr; meters_per_second_cubed) => { $crate::mechanical::Jerk::from_meters_per_second_cubed($value) };
+	(@unit $value:expr; kgmps) => { $crate::mechanical::Momentum::from_kgmps($value) };
+	(@unit $value:expr; kilogram_meters_per_second) => { $crate::mechanical::Momentum::from_kilogram_meters_per_second($value) };
+	(@unit $value:expr; gram_centimeters_per_second) => { $crate::mechanical::Momentum::from_gram_centimeters_per_second($value) };
+	(@unit $value:expr; gcmps) => { $crate::mechanical::Momentum::from_gcmps($value) };
+	(@unit $value:expr; newtons) => { $crate::mechanical::Force::from_newtons($value) };
+	(@unit $value:expr; lb) => { $crate::mechanical::Force::from_lb($value) };
+	(@unit $value:expr; kgG) => { $crate::mechanical::Force::from_kgG($value) };
+	(@unit $value:expr; mN) => { $crate::mechanical::Force::from_mN($value) };
+	(@unit $value:expr; uN) => { $crate::mechanical::Force::from_uN($value) };
+	(@unit $value:expr; nN) => { $crate::mechanical::Force::from_nN($value) };
+	(@unit $value:expr; kN) => { $crate::mechanical::Force::from_kN($value) };
+	(@unit $value:expr; MN) => { $crate::mechanical::Force::from_MN($value) };
+	(@unit $value:expr; GN) => { $crate::mechanical::Force::from_GN($value) };
+	(@unit $value:expr; pascals) => { $crate::mechanical::Pressure::from_pascals($value) };
+	(@unit $value:expr; psi) => { $crate::mechanical::Pressure::from_psi($value) };
+	(@unit $value:expr; mPa) => { $crate::mechanical::Pressure::from_mPa($value) };
+	(@unit $value:expr; uPa) => { $crate::mechanical::Pressure::from_uPa($value) };
+	(@unit $value:expr; nPa) => { $crate::mechanical::Pressure::from_nPa($value) };
+	(@unit $value:expr; kPa) => { $crate::mechanical::Pressure::from_kPa($value) };
+	(@unit $value:expr; MPa) => { $crate::mechanical::Pressure::from_MPa($value) };
+	(@unit $value:expr; GPa) => { $crate::mechanical::Pressure::from_GPa($value) };
+	(@unit $value:expr; hPa) => { $crate::mechanical::Pressure::from_hPa($value) };
+	(@unit $value:expr; bar) => { $crate::mechanical::Pressure::from_bar($value) };
+	(@unit $value:expr; mbar) => { $crate::mechanical::Pressure::from_mbar($value) };
+	(@unit $value:expr; atm) => { $crate::mechanical::Pressure::from_atm($value) };
+	(@unit $value:expr; atmospheres) => { $crate::mechanical::Pressure::from_atmospheres($value) };
+	(@unit $value:expr; torr) => { $crate::mechanical::Pressure::from_torr($value) };
+	(@unit $value:expr; mmHg) => { $crate::mechanical::Pressure::from_mmHg($value) };
+	(@unit $value:expr; Pas) => { $crate::mechanical::DynamicViscosity::from_Pas($value) };
+	(@unit $value:expr; pascal_seconds) => { $crate::mechanical::DynamicViscosity::from_pascal_seconds($value) };
+	(@unit $value:expr; mPas) => { $crate::mechanical::DynamicViscosity::from_mPas($value) };
+	(@unit $value:expr; P) => { $crate::mechanical::DynamicViscosity::from_P($value) };
+	(@unit $value:expr; poise) => { $crate::mechanical::DynamicViscosity::from_poise($value) };
+	(@unit $value:expr; cP) => { $crate::mechanical::DynamicViscosity::from_cP($value) };
+	(@unit $value:expr; centipoise) => { $crate::mechanical::DynamicViscosity::from_centipoise($value) };
+	(@unit $value:expr; m2ps) => { $crate::mechanical::KinematicViscosity::from_m2ps($value) };
+	(@unit $value:expr; square_meters_per_second) => { $crate::mechanical::KinematicViscosity::from_square_meters_per_second($value) };
+	(@unit $value:expr; St) => { $crate::mechanical::KinematicViscosity::from_St($value) };
+	(@unit $value:expr; stokes) => { $crate::mechanical::KinematicViscosity::from_stokes($value) };
+	(@unit $value:expr; cSt) => { $crate::mechanical::KinematicViscosity::from_cSt($value) };
+	(@unit $value:expr; centistokes) => { $crate::mechanical::KinematicViscosity::from_centistokes($value) };
+	(@unit $value:expr; joules) => { $crate::mechanical::Energy::from_joules($value) };
+	(@unit $value:expr; mJ) => { $crate::mechanical::Energy::from_mJ($value) };
+	(@unit $value:expr; uJ) => { $crate::mechanical::Energy::from_uJ($value) };
+	(@unit $value:expr; nJ) => { $crate::mechanical::Energy::from_nJ($value) };
+	(@unit $value:expr; kJ) => { $crate::mechanical::Energy::from_kJ($value) };
+	(@unit $value:expr; MJ) => { $crate::mechanical::Energy::from_MJ($value) };
+	(@unit $value:expr; GJ) => { $crate::mechanical::Energy::from_GJ($value) };
+	(@unit $value:expr; cal) => { $crate::mechanical::Energy::from_cal($value) };
+	(@unit $value:expr; calories) => { $crate::mechanical::Energy::from_calories($value) };
+	(@unit $value:expr; kcal) => { $crate::mechanical::Energy::from_kcal($value) };
+	(@unit $value:expr; Whr) => { $crate::mechanical::Energy::from_Whr($value) };
+	(@unit $value:expr; kWhr) => { $crate::mechanical::Energy::from_kWhr($value) };
+	(@unit $value:expr; kilowatt_hours) => { $crate::mechanical::Energy::from_kilowatt_hours($value) };
+	(@unit $value:expr; eV) => { $crate::mechanical::Energy::from_eV($value) };
+	(@unit $value:expr; electronvolts) => { $crate::mechanical::Energy::from_electronvolts($value) };
+	(@unit $value:expr; BTU) => { $crate::mechanical::Energy::from_BTU($value) };
+	(@unit $value:expr; btu) => { $crate::mechanical::Energy::from_btu($value) };
+	(@unit $value:expr; coulombs) => { $crate::electromagnetic::Charge::from_coulombs($value) };
+	(@unit $value:expr; mC) => { $crate::electromagnetic::Charge::from_mC($value) };
+	(@unit $value:expr; uC) => { $crate::electromagnetic::Charge::from_uC($value) };
+	(@unit $value:expr; nC) => { $crate::electromagnetic::Charge::from_nC($value) };
+	(@unit $value:expr; kC) => { $crate::electromagnetic::Charge::from_kC($value) };
+	(@unit $value:expr; MC) => { $crate::electromagnetic::Charge::from_MC($value) };
+	(@unit $value:expr; GC) => { $crate::electromagnetic::Charge::from_GC($value) };
+	(@unit $value:expr; p) => { $crate::electromagnetic::Charge::from_p($value) };
+	(@unit $value:expr; e) => { $crate::electromagnetic::Charge::from_e($value) };
+	(@unit $value:expr; watts) => { $crate::mechanical::Power::from_watts($value) };
+	(@unit $value:expr; mW) => { $crate::mechanical::Power::from_mW($value) };
+	(@unit $value:expr; uW) => { $crate::mechanical::Power::from_uW($value) };
+	(@unit $value:expr; nW) => { $crate::mechanical::Power::from_nW($value) };
+	(@unit $value:expr; kW) => { $crate::mechanical::Power::from_kW($value) };
+	(@unit $value:expr; MW) => { $crate::mechanical::Power::from_MW($value) };
+	(@unit $value:expr; GW) => { $crate::mechanical::Power::from_GW($value) };
+	(@unit $value:expr; horsepower) => { $crate::mechanical::Power::from_horsepower($value) };
+	(@unit $value:expr; volts) => { $crate::electromagnetic::Voltage::from_volts($value) };
+	(@unit $value:expr; mV) => { $crate::electromagnetic::Voltage::from_mV($value) };
+	(@unit $value:expr; uV) => { $crate::electromagnetic::Voltage::from_uV($value) };
+	(@unit $value:expr; nV) => { $crate::electromagnetic::Voltage::from_nV($value) };
+	(@unit $value:expr; kV) => { $crate::electromagnetic::Voltage::from_kV($value) };
+	(@unit $value:expr; MV) => { $crate::electromagnetic::Voltage::from_MV($value) };
+	(@unit $value:expr; GV) => { $crate::electromagnetic::Voltage::from_GV($value) };
+	(@unit $value:expr; Vpm) => { $crate::electromagnetic::ElectricField::from_Vpm($value) };
+	(@unit $value:expr; volts_per_meter) => { $crate::electromagnetic::ElectricField::from_volts_per_meter($value) };
+	(@unit $value:expr; kVpm) => { $crate::electromagnetic::ElectricField::from_kVpm($value) };
+	(@unit $value:expr; Vpcm) => { $crate::electromagnetic::ElectricField::from_Vpcm($value) };
+	(@unit $value:expr; Apm) => { $crate::electromagnetic::MagneticFieldStrength::from_Apm($value) };
+	(@unit $value:expr; amperes_per_meter) => { $crate::electromagnetic::MagneticFieldStrength::from_amperes_per_meter($value) };
+	(@unit $value:expr; kApm) => { $crate::electromagnetic::MagneticFieldStrength::from_kApm($value) };
+	(@unit $value:expr; Oe) => { $crate::electromagnetic::MagneticFieldStrength::from_Oe($value) };
+	(@unit $value:expr; oersteds) => { $crate::electromagnetic::MagneticFieldStrength::from_oersteds($value) };
+	(@unit $value:expr; Cpm) => { $crate::electromagnetic::LinearChargeDensity::from_Cpm($value) };
+	(@unit $value:expr; coulombs_per_meter) => { $crate::electromagnetic::LinearChargeDensity::from_coulombs_per_meter($value) };
+	(@unit $value:expr; Cpm2) => { $crate::electromagnetic::SurfaceChargeDensity::from_Cpm2($value) };
+	(@unit $value:expr; coulombs_per_square_meter) => { $crate::electromagnetic::SurfaceChargeDensity::from_coulombs_per_square_meter($value) };
+	(@unit $value:expr; uCpcm2) => { $crate::electromagnetic::SurfaceChargeDensity::from_uCpcm2($value) };
+	(@unit $value:expr; Cpm3) => { $crate::electromagnetic::VolumeChargeDensity::from_Cpm3($value) };
+	(@unit $value:expr; coulombs_per_cubic_meter) => { $crate::electromagnetic::VolumeChargeDensity::from_coulombs_per_cubic_meter($value) };
+	(@unit $value:expr; ohms) => { $crate::electromagnetic::Resistance::from_ohms($value) };
+	(@unit $value:expr; mOhm) => { $crate::electromagnetic::Resistance::from_mOhm($value) };
+	(@unit $value:expr; uOhm) => { $crate::electromagnetic::Resistance::from_uOhm($value) };
+	(@unit $value:expr; nOhm) => { $crate::electromagnetic::Resistance::from_nOhm($value) };
+	(@unit $value:expr; kOhm) => { $crate::electromagnetic::Resistance::from_kOhm($value) };
+	(@unit $value:expr; MOhm) => { $crate::electromagnetic::Resistance::from_MOhm($value) };
+	(@unit $value:expr; GOhm) => { $crate::electromagnetic::Resistance::from_GOhm($value) };
+	(@unit $value:expr; siemens) => { $crate::electromagnetic::Conductance::from_siemens($value) };
+	(@unit $value:expr; mS) => { $crate::electromagnetic::Conductance::from_mS($value) };
+	(@unit $value:expr; uS) => { $crate::electromagnetic::Conductance::from_uS($value) };
+	(@unit $value:expr; nS) => { $crate::electromagnetic::Conductance::from_nS($value) };
+	(@unit $value:expr; kS) => { $crate::electromagnetic::Conductance::from_kS($value) };
+	(@unit $value:expr; MS) => { $crate::electromagnetic::Conductance::from_MS($value) };
+	(@unit $value:expr; GS) => { $crate::electromagnetic::Conductance::from_GS($value) };
+	(@unit $value:expr; farads) => { $crate::electromagnetic::Capacitance::from_farads($value) };
+	(@unit $value:expr; mF) => { $crate::electromagnetic::Capacitance::from_mF($value) };
+	(@unit $value:expr; uF) => { $crate::electromagnetic::Capacitance::from_uF($value) };
+	(@unit $value:expr; nF) => { $crate::electromagnetic::Capacitance::from_nF($value) };
+	(@unit $value:expr; pF) => { $crate::electromagnetic::Capacitance::from_pF($value) };
+	(@unit $value:expr; kF) => { $crate::electromagnetic::Capacitance::from_kF($value) };
+	(@unit $value:expr; MF) => { $crate::electromagnetic::Capacitance::from_MF($value) };
+	(@unit $value:expr; GF) => { $crate::electromagnetic::Capacitance::from_GF($value) };
+	(@unit $value:expr; henries) => { $crate::electromagnetic::Inductance::from_henries($value) };
+	(@unit $value:expr; mH) => { $crate::electromagnetic::Inductance::from_mH($value) };
+	(@unit $value:expr; uH) => { $crate::electromagnetic::Inductance::from_uH($value) };
+	(@unit $value:expr; nH) => { $crate::electromagnetic::Inductance::from_nH($value) };
+	(@unit $value:expr; kH) => { $crate::electromagnetic::Inductance::from_kH($value) };
+	(@unit $value:expr; MH) => { $crate::electromagnetic::Inductance::from_MH($value) };
+	(@unit $value:expr; GH) => { $crate::electromagnetic::Inductance::from_GH($value) };
+	(@unit $value:expr; webers) => { $crate::electromagnetic::MagneticFlux::from_webers($value) };
+	(@unit $value:expr; mWb) => { $crate::electromagnetic::MagneticFlux::from_mWb($value) };
+	(@unit $value:expr; uWb) => { $crate::electromagnetic::MagneticFlux::from_uWb($value) };
+	(@unit $value:expr; nWb) => { $crate::electromagnetic::MagneticFlux::from_nWb($value) };
+	(@unit $value:expr; kWb) => { $crate::electromagnetic::MagneticFlux::from_kWb($value) };
+	(@unit $value:expr; MWb) => { $crate::electromagnetic::MagneticFlux::from_MWb($value) };
+	(@unit $value:expr; GWb) => { $crate::electromagnetic::MagneticFlux::from_GWb($value) };
+	(@unit $value:expr; teslas) => { $crate::electromagnetic::MagneticFluxDensity::from_teslas($value) };
+	(@unit $value:expr; mT) => { $crate::electromagnetic::MagneticFluxDensity::from_mT($value) };
+	(@unit $value:expr; uT) => { $crate::electromagnetic::MagneticFluxDensity::from_uT($value) };
+	(@unit $value:expr; nT) => { $crate::electromagnetic::MagneticFluxDensity::from_nT($value) };
+	(@unit $value:expr; kT) => { $crate::electromagnetic::MagneticFluxDensity::from_kT($value) };
+	(@unit $value:expr; MT) => { $crate::electromagnetic::MagneticFluxDensity::from_MT($value) };
+	(@unit $value:expr; GT) => { $crate::electromagnetic::MagneticFluxDensity::from_GT($value) };
+	(@unit $value:expr; molps) => { $crate::chemical::CatalyticActivity::from_molps($value) };
+	(@unit $value:expr; moles_per_second) => { $crate::chemical::CatalyticActivity::from_moles_per_second($value) };
+	(@unit $value:expr; Nps) => { $crate::chemical::CatalyticActivity::from_Nps($value) };
+	(@unit $value:expr; mmolps) => { $crate::chemical::CatalyticActivity::from_mmolps($value) };
+	(@unit $value:expr; umolps) => { $crate::chemical::CatalyticActivity::from_umolps($value) };
+	(@unit $value:expr; nmolps) => { $crate::chemical::CatalyticActivity::from_nmolps($value) };
+	(@unit $value:expr; molpm3) => { $crate::chemical::Concentration::from_molpm3($value) };
+	(@unit $value:expr; moles_per_cubic_meter) => { $crate::chemical::Concentration::from_moles_per_cubic_meter($value) };
+	(@unit $value:expr; Npm3) => { $crate::chemical::Concentration::from_Npm3($value) };
+	(@unit $value:expr; count_per_cubic_meter) => { $crate::chemical::Concentration::from_count_per_cubic_meter($value) };
+	(@unit $value:expr; NpL) => { $crate::chemical::Concentration::from_NpL($value) };
+	(@unit $value:expr; count_per_L) => { $crate::chemical::Concentration::from_count_per_L($value) };
+	(@unit $value:expr; Npcc) => { $crate::chemical::Concentration::from_Npcc($value) };
+	(@unit $value:expr; count_per_cc) => { $crate::chemical::Concentration::from_count_per_cc($value) };
+	(@unit $value:expr; M) => { $crate::chemical::Concentration::from_M($value) };
+	(@unit $value:expr; molarity) => { $crate::chemical::Concentration::from_molarity($value) };
+	(@unit $value:expr; mM) => { $crate::chemical::Concentration::from_mM($value) };
+	(@unit $value:expr; uM) => { $crate::chemical::Concentration::from_uM($value) };
+	(@unit $value:expr; nM) => { $crate::chemical::Concentration::from_nM($value) };
+	(@unit $value:expr; molpkg) => { $crate::chemical::Molality::from_molpkg($value) };
+	(@unit $value:expr; moles_per_kilogram) => { $crate::chemical::Molality::from_moles_per_kilogram($value) };
+	(@unit $value:expr; mmolpkg) => { $crate::chemical::Molality::from_mmolpkg($value) };
+	(@unit $value:expr; umolpkg) => { $crate::chemical::Molality::from_umolpkg($value) };
+	(@unit $value:expr; nmolpkg) => { $crate::chemical::Molality::from_nmolpkg($value) };
+	(@unit $value:expr; mmolpg) => { $crate::chemical::Molality::from_mmolpg($value) };
+	(@unit $value:expr; umolpg) => { $crate::chemical::Molality::from_umolpg($value) };
+	(@unit $value:expr; nmolpg) => { $crate::chemical::Molality::from_nmolpg($value) };
+	(@unit $value:expr; J_per_mol) => { $crate::chemical::MolarEnergy::from_J_per_mol($value) };
+	(@unit $value:expr; joules_per_mole) => { $crate::chemical::MolarEnergy::from_joules_per_mole($value) };
+	(@unit $value:expr; kJ_per_mol) => { $crate::chemical::MolarEnergy::from_kJ_per_mol($value) };
+	(@unit $value:expr; kilojoules_per_mole) => { $crate::chemical::MolarEnergy::from_kilojoules_per_mole($value) };
+	(@unit $value:expr; kcal_per_mol) => { $crate::chemical::MolarEnergy::from_kcal_per_mol($value) };
+	(@unit $value:expr; J_per_molK) => { $crate::chemical::MolarEntropy::from_J_per_molK($value) };
+	(@unit $value:expr; joules_per_mole_kelvin) => { $crate::chemical::MolarEntropy::from_joules_per_mole_kelvin($value) };
+	(@unit $value:expr; kJ_per_molK) => { $crate::chemical::MolarEntropy::from_kJ_per_molK($value) };
+	(@unit $value:expr; cal_per_molK) => { $crate::chemical::MolarEntropy::from_cal_per_molK($value) };
+	(@unit $value:expr; kgpmol) => { $crate::chemical::MolarMass::from_kgpmol($value) };
+	(@unit $value:expr; kilograms_per_mole) => { $crate::chemical::MolarMass::from_kilograms_per_mole($value) };
+	(@unit $value:expr; gpmol) => { $crate::chemical::MolarMass::from_gpmol($value) };
+	(@unit $value:expr; grams_per_mole) => { $crate::chemical::MolarMass::from_grams_per_mole($value) };
+	(@unit $value:expr; joules_per_kilogram_kelvin) => { $crate::chemical::SpecificHeatCapacity::from_joules_per_kilogram_kelvin($value) };
+	(@unit $value:expr; J_per_kgK) => { $crate::chemical::SpecificHeatCapacity::from_J_per_kgK($value) };
+	(@unit $value:expr; joules_per_gram_kelvin) => { $crate::chemical::SpecificHeatCapacity::from_joules_per_gram_kelvin($value) };
+	(@unit $value:expr; J_per_gK) => { $crate::chemical::SpecificHeatCapacity::from_J_per_gK($value) };
+	(@unit $value:expr; W_per_K) => { $crate::chemical::ThermalConductance::from_W_per_K($value) };
+	(@unit $value:expr; watts_per_kelvin) => { $crate::chemical::ThermalConductance::from_watts_per_kelvin($value) };
+	(@unit $value:expr; W_per_mK) => { $crate::chemical::ThermalConductivity::from_W_per_mK($value) };
+	(@unit $value:expr; watts_per_meter_kelvin) => { $crate::chemical::ThermalConductivity::from_watts_per_meter_kelvin($value) };
+	(@unit $value:expr; BTU_per_hr_ft_F) => { $crate::chemical::ThermalConductivity::from_BTU_per_hr_ft_F($value) };
+	(@unit $value:expr; lumens) => { $crate::electromagnetic::LuminousFlux::from_lumens($value) };
+	(@unit $value:expr; mlm) => { $crate::electromagnetic::LuminousFlux::from_mlm($value) };
+	(@unit $value:expr; ulm) => { $crate::electromagnetic::LuminousFlux::from_ulm($value) };
+	(@unit $value:expr; nlm) => { $crate::electromagnetic::LuminousFlux::from_nlm($value) };
+	(@unit $value:expr; klm) => { $crate::electromagnetic::LuminousFlux::from_klm($value) };
+	(@unit $value:expr; Mlm) => { $crate::electromagnetic::LuminousFlux::from_Mlm($value) };
+	(@unit $value:expr; Glm) => { $crate::electromagnetic::LuminousFlux::from_Glm($value) };
+	(@unit $value:expr; mlux) => { $crate::electromagnetic::Illuminance::from_mlux($value) };
+	(@unit $value:expr; ulux) => { $crate::electromagnetic::Illuminance::from_ulux($value) };
+	(@unit $value:expr; nlux) => { $crate::electromagnetic::Illuminance::from_nlux($value) };
+	(@unit $value:expr; klux) => { $crate::electromagnetic::Illuminance::from_klux($value) };
+	(@unit $value:expr; Mlux) => { $crate::electromagnetic::Illuminance::from_Mlux($value) };
+	(@unit $value:expr; Glux) => { $crate::electromagnetic::Illuminance::from_Glux($value) };
+	(@unit $value:expr; W_per_m2) => { $crate::electromagnetic::Irradiance::from_W_per_m2($value) };
+	(@unit $value:expr; watts_per_square_meter) => { $crate::electromagnetic::Irradiance::from_watts_per_square_meter($value) };
+	(@unit $value:expr; mW_per_m2) => { $crate::electromagnetic::Irradiance::from_mW_per_m2($value) };
+	(@unit $value:expr; kW_per_m2) => { $crate::electromagnetic::Irradiance::from_kW_per_m2($value) };
+	(@unit $value:expr; mW_per_cm2) => { $crate::electromagnetic::Irradiance::from_mW_per_cm2($value) };
+	(@unit $value:expr; W_per_m2sr) => { $crate::electromagnetic::Radiance::from_W_per_m2sr($value) };
+	(@unit $value:expr; watts_per_square_meter_steradian) => { $crate::electromagnetic::Radiance::from_watts_per_square_meter_steradian($value) };
+	(@unit $value:expr; mW_per_m2sr) => { $crate::electromagnetic::Radiance::from_mW_per_m2sr($value) };
+	(@unit $value:expr; kW_per_m2sr) => { $crate::electromagnetic::Radiance::from_kW_per_m2sr($value) };
+	(@unit $value:expr; becquerels) => { $crate::nuclear::Radioactivity::from_becquerels($value) };
+	(@unit $value:expr; mBq) => { $crate::nuclear::Radioactivity::from_mBq($value) };
+	(@unit $value:expr; uBq) => { $crate::nuclear::Radioactivity::from_uBq($value) };
+	(@unit $value:expr; nBq) => { $crate::nuclear::Radioactivity::from_nBq($value) };
+	(@unit $value:expr; kBq) => { $crate::nuclear::Radioactivity::from_kBq($value) };
+	(@unit $value:expr; MBq) => { $crate::nuclear::Radioactivity::from_MBq($value) };
+	(@unit $value:expr; GBq) => { $crate::nuclear::Radioactivity::from_GBq($value) };
+	(@unit $value:expr; Ci) => { $crate::nuclear::Radioactivity::from_Ci($value) };
+	(@unit $value:expr; curies) => { $crate::nuclear::Radioactivity::from_curies($value) };
+	(@unit $value:expr; mCi) => { $crate::nuclear::Radioactivity::from_mCi($value) };
+	(@unit $value:expr; uCi) => { $crate::nuclear::Radioactivity::from_uCi($value) };
+	(@unit $value:expr; nCi) => { $crate::nuclear::Radioactivity::from_nCi($value) };
+	(@unit $value:expr; pCi) => { $crate::nuclear::Radioactivity::from_pCi($value) };
+	(@unit $value:expr; Rd) => { $crate::nuclear::Radioactivity::from_Rd($value) };
+	(@unit $value:expr; grays) => { $crate::nuclear::AbsorbedDose::from_grays($value) };
+	(@unit $value:expr; mGy) => { $crate::nuclear::AbsorbedDose::from_mGy($value) };
+	(@unit $value:expr; uGy) => { $crate::nuclear::AbsorbedDose::from_uGy($value) };
+	(@unit $value:expr; nGy) => { $crate::nuclear::AbsorbedDose::from_nGy($value) };
+	(@unit $value:expr; kGy) => { $crate::nuclear::AbsorbedDose::from_kGy($value) };
+	(@unit $value:expr; MGy) => { $crate::nuclear::AbsorbedDose::from_MGy($value) };
+	(@unit $value:expr; GGy) => { $crate::nuclear::AbsorbedDose::from_GGy($value) };
+	(@unit $value:expr; krad) => { $crate::nuclear::AbsorbedDose::from_krad($value) };
+	(@unit $value:expr; mrad) => { $crate::nuclear::AbsorbedDose::from_mrad($value) };
+	(@unit $value:expr; urad) => { $crate::nuclear::AbsorbedDose::from_urad($value) };
+	(@unit $value:expr; erg) => { $crate::nuclear::AbsorbedDose::from_erg($value) };
+	(@unit $value:expr; sieverts) => { $crate::nuclear::DoseEquivalent::from_sieverts($value) };
+	(@unit $value:expr; mSv) => { $crate::nuclear::DoseEquivalent::from_mSv($value) };
+	(@unit $value:expr; uSv) => { $crate::nuclear::DoseEquivalent::from_uSv($value) };
+	(@unit $value:expr; nSv) => { $crate::nuclear::DoseEquivalent::from_nSv($value) };
+	(@unit $value:expr; kSv) => { $crate::nuclear::DoseEquivalent::from_kSv($value) };
+	(@unit $value:expr; MSv) => { $crate::nuclear::DoseEquivalent::from_MSv($value) };
+	(@unit $value:expr; GSv) => { $crate::nuclear::DoseEquivalent::from_GSv($value) };
+	(@unit $value:expr; rem) => { $crate::nuclear::DoseEquivalent::from_rem($value) };
+	(@unit $value:expr; mrem) => { $crate::nuclear::DoseEquivalent::from_mrem($value) };
+	(@unit $value:expr; krem) => { $crate::nuclear::DoseEquivalent::from_krem($value) };
+	(@unit $value:expr; Gy_per_s) => { $crate::nuclear::AbsorbedDoseRate::from_Gy_per_s($value) };
+	(@unit $value:expr; grays_per_second) => { $crate::nuclear::AbsorbedDoseRate::from_grays_per_second($value) };
+	(@unit $value:expr; mGy_per_s) => { $crate::nuclear::AbsorbedDoseRate::from_mGy_per_s($value) };
+	(@unit $value:expr; uGy_per_s) => { $crate::nuclear::AbsorbedDoseRate::from_uGy_per_s($value) };
+	(@unit $value:expr; Gy_per_h) => { $crate::nuclear::AbsorbedDoseRate::from_Gy_per_h($value) };
+	(@unit $value:expr; mGy_per_h) => { $crate::nuclear::AbsorbedDoseRate::from_mGy_per_h($value) };
+	(@unit $value:expr; uGy_per_h) => { $crate::nuclear::AbsorbedDoseRate::from_uGy_per_h($value) };
+	(@unit $value:expr; nGy_per_h) => { $crate::nuclear::AbsorbedDoseRate::from_nGy_per_h($value) };
+	(@unit $value:expr; Sv_per_s) => { $crate::nuclear::DoseEquivalentRate::from_Sv_per_s($value) };
+	(@unit $value:expr; sieverts_per_second) => { $crate::nuclear::DoseEquivalentRate::from_sieverts_per_second($value) };
+	(@unit $value:expr; mSv_per_s) => { $crate::nuclear::DoseEquivalentRate::from_mSv_per_s($value) };
+	(@unit $value:expr; uSv_per_s) => { $crate::nuclear::DoseEquivalentRate::from_uSv_per_s($value) };
+	(@unit $value:expr; Sv_per_h) => { $crate::nuclear::DoseEquivalentRate::from_Sv_per_h($value) };
+	(@unit $value:expr; mSv_per_h) => { $crate::nuclear::DoseEquivalentRate::from_mSv_per_h($value) };
+	(@unit $value:expr; uSv_per_h) => { $crate::nuclear::DoseEquivalentRate::from_uSv_per_h($value) };
+	(@unit $value:expr; nSv_per_h) => { $crate::nuclear::DoseEquivalentRate::from_nSv_per_h($value) };
+	(@unit $value:expr; per_count) => { $crate::base::InverseAmount::from_per_count($value) };
+	(@unit $value:expr; per_mole) => { $crate::base::InverseAmount::from_per_mole($value) };
+	(@unit $value:expr; per_mol) => { $crate::base::InverseAmount::from_per_mol($value) };
+	(@unit $value:expr; per_mmol) => { $crate::base::InverseAmount::from_per_mmol($value) };
+	(@unit $value:expr; per_umol) => { $crate::base::InverseAmount::from_per_umol($value) };
+	(@unit $value:expr; per_nmol) => { $crate::base::InverseAmount::from_per_nmol($value) };
+	(@unit $value:expr; per_pmol) => { $crate::base::InverseAmount::from_per_pmol($value) };
+	(@unit $value:expr; per_A) => { $crate::base::InverseCurrent::from_per_A($value) };
+	(@unit $value:expr; per_ampere) => { $crate::base::InverseCurrent::from_per_ampere($value) };
+	(@unit $value:expr; per_mA) => { $crate::base::InverseCurrent::from_per_mA($value) };
+	(@unit $value:expr; per_uA) => { $crate::base::InverseCurrent::from_per_uA($value) };
+	(@unit $value:expr; per_nA) => { $crate::base::InverseCurrent::from_per_nA($value) };
+	(@unit $value:expr; per_kA) => { $crate::base::InverseCurrent::from_per_kA($value) };
+	(@unit $value:expr; per_MA) => { $crate::base::InverseCurrent::from_per_MA($value) };
+	(@unit $value:expr; per_GA) => { $crate::base::InverseCurrent::from_per_GA($value) };
+	(@unit $value:expr; per_m) => { $crate::base::InverseDistance::from_per_m($value) };
+	(@unit $value:expr; per_meter) => { $crate::base::InverseDistance::from_per_meter($value) };
+	(@unit $value:expr; per_cm) => { $crate::base::InverseDistance::from_per_cm($value) };
+	(@unit $value:expr; per_mm) => { $crate::base::InverseDistance::from_per_mm($value) };
+	(@unit $value:expr; per_um) => { $crate::base::InverseDistance::from_per_um($value) };
+	(@unit $value:expr; per_nm) => { $crate::base::InverseDistance::from_per_nm($value) };
+	(@unit $value:expr; per_pm) => { $crate::base::InverseDistance::from_per_pm($value) };
+	(@unit $value:expr; per_km) => { $crate::base::InverseDistance::from_per_km($value) };
+	(@unit $value:expr; per_au) => { $crate::base::InverseDistance::from_per_au($value) };
+	(@unit $value:expr; per_parsec) => { $crate::base::InverseDistance::from_per_parsec($value) };
+	(@unit $value:expr; per_lyr) => { $crate::base::InverseDistance::from_per_lyr($value) };
+	(@unit $value:expr; per_cd) => { $crate::base::InverseLuminosity::from_per_cd($value) };
+	(@unit $value:expr; per_candela) => { $crate::base::InverseLuminosity::from_per_candela($value) };
+	(@unit $value:expr; per_mcd) => { $crate::base::InverseLuminosity::from_per_mcd($value) };
+	(@unit $value:expr; per_ucd) => { $crate::base::InverseLuminosity::from_per_ucd($value) };
+	(@unit $value:expr; per_ncd) => { $crate::base::InverseLuminosity::from_per_ncd($value) };
+	(@unit $value:expr; per_kcd) => { $crate::base::InverseLuminosity::from_per_kcd($value) };
+	(@unit $value:expr; per_Mcd) => { $crate::base::InverseLuminosity::from_per_Mcd($value) };
+	(@unit $value:expr; per_Gcd) => { $crate::base::InverseLuminosity::from_per_Gcd($value) };
+	(@unit $value:expr; per_kg) => { $crate::base::InverseMass::from_per_kg($value) };
+	(@unit $value:expr; per_kilograms) => { $crate::base::InverseMass::from_per_kilograms($value) };
+	(@unit $value:expr; per_g) => { $crate::base::InverseMass::from_per_g($value) };
+	(@unit $value:expr; per_mg) => { $crate::base::InverseMass::from_per_mg($value) };
+	(@unit $value:expr; per_ug) => { $crate::base::InverseMass::from_per_ug($value) };
+	(@unit $value:expr; per_ng) => { $crate::base::InverseMass::from_per_ng($value) };
+	(@unit $value:expr; per_pg) => { $crate::base::InverseMass::from_per_pg($value) };
+	(@unit $value:expr; per_tons) => { $crate::base::InverseMass::from_per_tons($value) };
+	(@unit $value:expr; per_earth_mass) => { $crate::base::InverseMass::from_per_earth_mass($value) };
+	(@unit $value:expr; per_jupiter_mass) => { $crate::base::InverseMass::from_per_jupiter_mass($value) };
+	(@unit $value:expr; per_solar_mass) => { $crate::base::InverseMass::from_per_solar_mass($value) };
+	(@unit $value:expr; s_per_kg) => { $crate::mechanical::InverseMassFlowRate::from_s_per_kg($value) };
+	(@unit $value:expr; seconds_per_kilogram) => { $crate::mechanical::InverseMassFlowRate::from_seconds_per_kilogram($value) };
+	(@unit $value:expr; s_per_g) => { $crate::mechanical::InverseMassFlowRate::from_s_per_g($value) };
+	(@unit $value:expr; per_K) => { $crate::base::InverseTemperature::from_per_K($value) };
+	(@unit $value:expr; s_per_mol) => { $crate::chemical::InverseCatalyticActivity::from_s_per_mol($value) };
+	(@unit $value:expr; seconds_per_mole) => { $crate::chemical::InverseCatalyticActivity::from_seconds_per_mole($value) };
+	(@unit $value:expr; minutes_per_mole) => { $crate::chemical::InverseCatalyticActivity::from_minutes_per_mole($value) };
+	(@unit $value:expr; hours_per_mole) => { $crate::chemical::InverseCatalyticActivity::from_hours_per_mole($value) };
+	(@unit $value:expr; m3_per_mol) => { $crate::chemical::MolarVolume::from_m3_per_mol($value) };
+	(@unit $value:expr; cubic_meters_per_mole) => { $crate::chemical::MolarVolume::from_cubic_meters_per_mole($value) };
+	(@unit $value:expr; L_per_mol) => { $crate::chemical::MolarVolume::from_L_per_mol($value) };
+	(@unit $value:expr; liters_per_mole) => { $crate::chemical::MolarVolume::from_liters_per_mole($value) };
+	(@unit $value:expr; kilograms_kelvin_per_joule) => { $crate::chemical::InverseSpecificHeatCapacity::from_kilograms_kelvin_per_joule($value) };
+	(@unit $value:expr; kgK_per_J) => { $crate::chemical::InverseSpecificHeatCapacity::from_kgK_per_J($value) };
+	(@unit $value:expr; grams_kelvin_per_joule) => { $crate::chemical::InverseSpecificHeatCapacity::from_grams_kelvin_per_joule($value) };
+	(@unit $value:expr; gK_per_J) => { $crate::chemical::InverseSpecificHeatCapacity::from_gK_per_J($value) };
+	(@unit $value:expr; K_per_W) => { $crate::chemical::ThermalResistance::from_K_per_W($value) };
+	(@unit $value:expr; kelvin_per_watt) => { $crate::chemical::ThermalResistance::from_kelvin_per_watt($value) };
+	(@unit $value:expr; mK_per_W) => { $crate::chemical::ThermalResistivity::from_mK_per_W($value) };
+	(@unit $value:expr; meter_kelvin_per_watt) => { $crate::chemical::ThermalResistivity::from_meter_kelvin_per_watt($value) };
+	(@unit $value:expr; per_F) => { $crate::electromagnetic::Elastance::from_per_F($value) };
+	(@unit $value:expr; per_farads) => { $crate::electromagnetic::Elastance::from_per_farads($value) };
+	(@unit $value:expr; per_mF) => { $crate::electromagnetic::Elastance::from_per_mF($value) };
+	(@unit $value:expr; per_uF) => { $crate::electromagnetic::Elastance::from_per_uF($value) };
+	(@unit $value:expr; per_nF) => { $crate::electromagnetic::Elastance::from_per_nF($value) };
+	(@unit $value:expr; per_pF) => { $crate::electromagnetic::Elastance::from_per_pF($value) };
+	(@unit $value:expr; per_kF) => { $crate::electromagnetic::Elastance::from_per_kF($value) };
+	(@unit $value:expr; per_MF) => { $crate::electromagnetic::Elastance::from_per_MF($value) };
+	(@unit $value:expr; per_GF) => { $crate::electromagnetic::Elastance::from_per_GF($value) };
+	(@unit $value:expr; per_C) => { $crate::electromagnetic::InverseCharge::from_per_C($value) };
+	(@unit $value:expr; per_coulombs) => { $crate::electromagnetic::InverseCharge::from_per_coulombs($value) };
+	(@unit $value:expr; per_mC) => { $crate::electromagnetic::InverseCharge::from_per_mC($value) };
+	(@unit $value:expr; per_uC) => { $crate::electromagnetic::InverseCharge::from_per_uC($value) };
+	(@unit $value:expr; per_nC) => { $crate::electromagnetic::InverseCharge::from_per_nC($value) };
+	(@unit $value:expr; per_kC) => { $crate::electromagnetic::InverseCharge::from_per_kC($value) };
+	(@unit $value:expr; per_MC) => { $crate::electromagnetic::InverseCharge::from_per_MC($value) };
+	(@unit $value:expr; per_GC) => { $crate::electromagnetic::InverseCharge::from_per_GC($value) };
+	(@unit $value:expr; m2_per_lm) => { $crate::electromagnetic::AreaPerLumen::from_m2_per_lm($value) };
+	(@unit $value:expr; square_meters_per_lumen) => { $crate::electromagnetic::AreaPerLumen::from_square_meters_per_lumen($value) };
+	(@unit $value:expr; per_lux) => { $crate::electromagnetic::AreaPerLumen::from_per_lux($value) };
+	(@unit $value:expr; mol_per_J) => { $crate::chemical::InverseMolarEnergy::from_mol_per_J($value) };
+	(@unit $value:expr; moles_per_joule) => { $crate::chemical::InverseMolarEnergy::from_moles_per_joule($value) };
+	(@unit $value:expr; mol_per_kJ) => { $crate::chemical::InverseMolarEnergy::from_mol_per_kJ($value) };
+	(@unit $value:expr; molK_per_J) => { $crate::chemical::InverseMolarEntropy::from_molK_per_J($value) };
+	(@unit $value:expr; mole_kelvins_per_joule) => { $crate::chemical::InverseMolarEntropy::from_mole_kelvins_per_joule($value) };
+	(@unit $value:expr; m2_per_W) => { $crate::electromagnetic::InverseIrradiance::from_m2_per_W($value) };
+	(@unit $value:expr; square_meters_per_watt) => { $crate::electromagnetic::InverseIrradiance::from_square_meters_per_watt($value) };
+	(@unit $value:expr; m2_per_mW) => { $crate::electromagnetic::InverseIrradiance::from_m2_per_mW($value) };
+	(@unit $value:expr; m2_per_kW) => { $crate::electromagnetic::InverseIrradiance::from_m2_per_kW($value) };
+	(@unit $value:expr; m2sr_per_W) => { $crate::electromagnetic::InverseRadiance::from_m2sr_per_W($value) };
+	(@unit $value:expr; square_meter_steradians_per_watt) => { $crate::electromagnetic::InverseRadiance::from_square_meter_steradians_per_watt($value) };
+	(@unit $value:expr; per_H) => { $crate::electromagnetic::InverseInductance::from_per_H($value) };
+	(@unit $value:expr; per_henry) => { $crate::electromagnetic::InverseInductance::from_per_henry($value) };
+	(@unit $value:expr; per_mH) => { $crate::electromagnetic::InverseInductance::from_per_mH($value) };
+	(@unit $value:expr; per_uH) => { $crate::electromagnetic::InverseInductance::from_per_uH($value) };
+	(@unit $value:expr; per_nH) => { $crate::electromagnetic::InverseInductance::from_per_nH($value) };
+	(@unit $value:expr; per_kH) => { $crate::electromagnetic::InverseInductance::from_per_kH($value) };
+	(@unit $value:expr; per_MH) => { $crate::electromagnetic::InverseInductance::from_per_MH($value) };
+	(@unit $value:expr; per_GH) => { $crate::electromagnetic::InverseInductance::from_per_GH($value) };
+	(@unit $value:expr; per_lm) => { $crate::electromagnetic::InverseLuminousFlux::from_per_lm($value) };
+	(@unit $value:expr; per_lumens) => { $crate::electromagnetic::InverseLuminousFlux::from_per_lumens($value) };
+	(@unit $value:expr; per_mlm) => { $crate::electromagnetic::InverseLuminousFlux::from_per_mlm($value) };
+	(@unit $value:expr; per_ulm) => { $crate::electromagnetic::InverseLuminousFlux::from_per_ulm($value) };
+	(@unit $value:expr; per_nlm) => { $crate::electromagnetic::InverseLuminousFlux::from_per_nlm($value) };
+	(@unit $value:expr; per_klm) => { $crate::electromagnetic::InverseLuminousFlux::from_per_klm($value) };
+	(@unit $value:expr; per_Mlm) => { $crate::electromagnetic::InverseLuminousFlux::from_per_Mlm($value) };
+	(@unit $value:expr; per_Glm) => { $crate::electromagnetic::InverseLuminousFlux::from_per_Glm($value) };
+	(@unit $value:expr; per_Wb) => { $crate::electromagnetic::InverseMagneticFlux::from_per_Wb($value) };
+	(@unit $value:expr; per_weber) => { $crate::electromagnetic::InverseMagneticFlux::from_per_weber($value) };
+	(@unit $value:expr; per_mWb) => { $crate::electromagnetic::InverseMagneticFlux::from_per_mWb($value) };
+	(@unit $value:expr; per_uWb) => { $crate::electromagnetic::InverseMagneticFlux::from_per_uWb($value) };
+	(@unit $value:expr; per_nWb) => { $crate::electromagnetic::InverseMagneticFlux::from_per_nWb($value) };
+	(@unit $value:expr; per_kWb) => { $crate::electromagnetic::InverseMagneticFlux::from_per_kWb($value) };
+	(@unit $value:expr; per_MWb) => { $crate::electromagnetic::InverseMagneticFlux::from_per_MWb($value) };
+	(@unit $value:expr; per_GWb) => { $crate::electromagnetic::InverseMagneticFlux::from_per_GWb($value) };
+	(@unit $value:expr; m2_per_Wb) => { $crate::electromagnetic::InverseMagneticFluxDensity::from_m2_per_Wb($value) };
+	(@unit $value:expr; square_meters_per_weber) => { $crate::electromagnetic::InverseMagneticFluxDensity::from_square_meters_per_weber($value) };
+	(@unit $value:expr; per_T) => { $crate::electromagnetic::InverseMagneticFluxDensity::from_per_T($value) };
+	(@unit $value:expr; per_tesla) => { $crate::electromagnetic::InverseMagneticFluxDensity::from_per_tesla($value) };
+	(@unit $value:expr; per_V) => { $crate::electromagnetic::InverseVoltage::from_per_V($value) };
+	(@unit $value:expr; per_volt) => { $crate::electromagnetic::InverseVoltage::from_per_volt($value) };
+	(@unit $value:expr; per_mV) => { $crate::electromagnetic::InverseVoltage::from_per_mV($value) };
+	(@unit $value:expr; per_uV) => { $crate::electromagnetic::InverseVoltage::from_per_uV($value) };
+	(@unit $value:expr; per_nV) => { $crate::electromagnetic::InverseVoltage::from_per_nV($value) };
+	(@unit $value:expr; per_kV) => { $crate::electromagnetic::InverseVoltage::from_per_kV($value) };
+	(@unit $value:expr; per_MV) => { $crate::electromagnetic::InverseVoltage::from_per_MV($value) };
+	(@unit $value:expr; per_GV) => { $crate::electromagnetic::InverseVoltage::from_per_GV($value) };
+	(@unit $value:expr; m_per_V) => { $crate::electromagnetic::InverseElectricField::from_m_per_V($value) };
+	(@unit $value:expr; meters_per_volt) => { $crate::electromagnetic::InverseElectricField::from_meters_per_volt($value) };
+	(@unit $value:expr; m_per_A) => { $crate::electromagnetic::InverseMagneticFieldStrength::from_m_per_A($value) };
+	(@unit $value:expr; meters_per_ampere) => { $crate::electromagnetic::InverseMagneticFieldStrength::from_meters_per_ampere($value) };
+	(@unit $value:expr; m_per_C) => { $crate::electromagnetic::InverseLinearChargeDensity::from_m_per_C($value) };
+	(@unit $value:expr; meters_per_coulomb) => { $crate::electromagnetic::InverseLinearChargeDensity::from_meters_per_coulomb($value) };
+	(@unit $value:expr; m2_per_C) => { $crate::electromagnetic::InverseSurfaceChargeDensity::from_m2_per_C($value) };
+	(@unit $value:expr; square_meters_per_coulomb) => { $crate::electromagnetic::InverseSurfaceChargeDensity::from_square_meters_per_coulomb($value) };
+	(@unit $value:expr; m3_per_C) => { $crate::electromagnetic::InverseVolumeChargeDensity::from_m3_per_C($value) };
+	(@unit $value:expr; cubic_meters_per_coulomb) => { $crate::electromagnetic::InverseVolumeChargeDensity::from_cubic_meters_per_coulomb($value) };
+	(@unit $value:expr; per_radians) => { $crate::geometry::InverseAngle::from_per_radians($value) };
+	(@unit $value:expr; per_degrees) => { $crate::geometry::InverseAngle::from_per_degrees($value) };
+	(@unit $value:expr; per_deg) => { $crate::geometry::InverseAngle::from_per_deg($value) };
+	(@unit $value:expr; per_m2) => { $crate::geometry::InverseArea::from_per_m2($value) };
+	(@unit $value:expr; per_square_meter) => { $crate::geometry::InverseArea::from_per_square_meter($value) };
+	(@unit $value:expr; per_cm2) => { $crate::geometry::InverseArea::from_per_cm2($value) };
+	(@unit $value:expr; per_square_cm) => { $crate::geometry::InverseArea::from_per_square_cm($value) };
+	(@unit $value:expr; per_mm2) => { $crate::geometry::InverseArea::from_per_mm2($value) };
+	(@unit $value:expr; per_um2) => { $crate::geometry::InverseArea::from_per_um2($value) };
+	(@unit $value:expr; per_nm2) => { $crate::geometry::InverseArea::from_per_nm2($value) };
+	(@unit $value:expr; per_km2) => { $crate::geometry::InverseArea::from_per_km2($value) };
+	(@unit $value:expr; per_sr) => { $crate::geometry::InverseSolidAngle::from_per_sr($value) };
+	(@unit $value:expr; per_steradians) => { $crate::geometry::InverseSolidAngle::from_per_steradians($value) };
+	(@unit $value:expr; per_m3) => { $crate::geometry::InverseVolume::from_per_m3($value) };
+	(@unit $value:expr; per_cubic_meter) => { $crate::geometry::InverseVolume::from_per_cubic_meter($value) };
+	(@unit $value:expr; per_cc) => { $crate::geometry::InverseVolume::from_per_cc($value) };
+	(@unit $value:expr; per_L) => { $crate::geometry::InverseVolume::from_per_L($value) };
+	(@unit $value:expr; per_liters) => { $crate::geometry::InverseVolume::from_per_liters($value) };
+	(@unit $value:expr; per_mL) => { $crate::geometry::InverseVolume::from_per_mL($value) };
+	(@unit $value:expr; per_uL) => { $crate::geometry::InverseVolume::from_per_uL($value) };
+	(@unit $value:expr; per_nL) => { $crate::geometry::InverseVolume::from_per_nL($value) };
+	(@unit $value:expr; per_pL) => { $crate::geometry::InverseVolume::from_per_pL($value) };
+	(@unit $value:expr; per_kL) => { $crate::geometry::InverseVolume::from_per_kL($value) };
+	(@unit $value:expr; per_ML) => { $crate::geometry::InverseVolume::from_per_ML($value) };
+	(@unit $value:expr; per_GL) => { $crate::geometry::InverseVolume::from_per_GL($value) };
+	(@unit $value:expr; s_per_m3) => { $crate::mechanical::InverseVolumetricFlowRate::from_s_per_m3($value) };
+	(@unit $value:expr; seconds_per_cubic_meter) => { $crate::mechanical::InverseVolumetricFlowRate::from_seconds_per_cubic_meter($value) };
+	(@unit $value:expr; s_per_L) => { $crate::mechanical::InverseVolumetricFlowRate::from_s_per_L($value) };
+	(@unit $value:expr; s2pm) => { $crate::mechanical::InverseAcceleration::from_s2pm($value) };
+	(@unit $value:expr; seconds_squared_per_meter) => { $crate::mechanical::InverseAcceleration::from_seconds_squared_per_meter($value) };
+	(@unit $value:expr; s2pmm) => { $crate::mechanical::InverseAcceleration::from_s2pmm($value) };
+	(@unit $value:expr; hours_squared_per_kilometers) => { $crate::mechanical::InverseAcceleration::from_hours_squared_per_kilometers($value) };
+	(@unit $value:expr; hr2_per_km) => { $crate::mechanical::InverseAcceleration::from_hr2_per_km($value) };
+	(@unit $value:expr; s3pm) => { $crate::mechanical::InverseJerk::from_s3pm($value) };
+	(@unit $value:expr; seconds_cubed_per_meter) => { $crate::mechanical::InverseJerk::from_seconds_cubed_per_meter($value) };
+	(@unit $value:expr; s2prad) => { $crate::mechanical::InverseAngularAcceleration::from_s2prad($value) };
+	(@unit $value:expr; seconds_squared_per_radian) => { $crate::mechanical::InverseAngularAcceleration::from_seconds_squared_per_radian($value) };
+	(@unit $value:expr; seconds_squared_per_degree) => { $crate::mechanical::InverseAngularAcceleration::from_seconds_squared_per_degree($value) };
+	(@unit $value:expr; s_per_kgm2rad) => { $crate::mechanical::InverseAngularMomentum::from_s_per_kgm2rad($value) };
+	(@unit $value:expr; seconds_per_kilogram_meters_squared_radian) => { $crate::mechanical::InverseAngularMomentum::from_seconds_per_kilogram_meters_squared_radian($value) };
+	(@unit $value:expr; s_per_gcm2rad) => { $crate::mechanical::InverseAngularMomentum::from_s_per_gcm2rad($value) };
+	(@unit $value:expr; s_per_rad) => { $crate::mechanical::InverseAngularVelocity::from_s_per_rad($value) };
+	(@unit $value:expr; seconds_per_radian) => { $crate::mechanical::InverseAngularVelocity::from_seconds_per_radian($value) };
+	(@unit $value:expr; seconds_per_degree) => { $crate::mechanical::InverseAngularVelocity::from_seconds_per_degree($value) };
+	(@unit $value:expr; s_per_deg) => { $crate::mechanical::InverseAngularVelocity::from_s_per_deg($value) };
+	(@unit $value:expr; spr) => { $crate::mechanical::InverseAngularVelocity::from_spr($value) };
+	(@unit $value:expr; mpr) => { $crate::mechanical::InverseAngularVelocity::from_mpr($value) };
+	(@unit $value:expr; hpr) => { $crate::mechanical::InverseAngularVelocity::from_hpr($value) };
+	(@unit $value:expr; m2_per_kg) => { $crate::mechanical::AreaPerMass::from_m2_per_kg($value) };
+	(@unit $value:expr; square_meters_per_kilogram) => { $crate::mechanical::AreaPerMass::from_square_meters_per_kilogram($value) };
+	(@unit $value:expr; m2_per_g) => { $crate::mechanical::AreaPerMass::from_m2_per_g($value) };
+	(@unit $value:expr; square_meters_per_gram) => { $crate::mechanical::AreaPerMass::from_square_meters_per_gram($value) };
+	(@unit $value:expr; cm2_per_g) => { $crate::mechanical::AreaPerMass::from_cm2_per_g($value) };
+	(@unit $value:expr; square_centimeters_per_gram) => { $crate::mechanical::AreaPerMass::from_square_centimeters_per_gram($value) };
+	(@unit $value:expr; L_per_kg) => { $crate::mechanical::VolumePerMass::from_L_per_kg($value) };
+	(@unit $value:expr; liters_per_kilogram) => { $crate::mechanical::VolumePerMass::from_liters_per_kilogram($value) };
+	(@unit $value:expr; cc_per_g) => { $crate::mechanical::VolumePerMass::from_cc_per_g($value) };
+	(@unit $value:expr; cubic_centimeters_per_gram) => { $crate::mechanical::VolumePerMass::from_cubic_centimeters_per_gram($value) };
+	(@unit $value:expr; m3_per_kg) => { $crate::mechanical::VolumePerMass::from_m3_per_kg($value) };
+	(@unit $value:expr; cubic_meters_per_kilogram) => { $crate::mechanical::VolumePerMass::from_cubic_meters_per_kilogram($value) };
+	(@unit $value:expr; per_J) => { $crate::mechanical::InverseEnergy::from_per_J($value) };
+	(@unit $value:expr; per_joule) => { $crate::mechanical::InverseEnergy::from_per_joule($value) };
+	(@unit $value:expr; per_mJ) => { $crate::mechanical::InverseEnergy::from_per_mJ($value) };
+	(@unit $value:expr; per_uJ) => { $crate::mechanical::InverseEnergy::from_per_uJ($value) };
+	(@unit $value:expr; per_nJ) => { $crate::mechanical::InverseEnergy::from_per_nJ($value) };
+	(@unit $value:expr; per_kJ) => { $crate::mechanical::InverseEnergy::from_per_kJ($value) };
+	(@unit $value:expr; per_MJ) => { $crate::mechanical::InverseEnergy::from_per_MJ($value) };
+	(@unit $value:expr; per_GJ) => { $crate::mechanical::InverseEnergy::from_per_GJ($value) };
+	(@unit $value:expr; per_cal) => { $crate::mechanical::InverseEnergy::from_per_cal($value) };
+	(@unit $value:expr; per_kcal) => { $crate::mechanical::InverseEnergy::from_per_kcal($value) };
+	(@unit $value:expr; per_Whr) => { $crate::mechanical::InverseEnergy::from_per_Whr($value) };
+	(@unit $value:expr; per_kWhr) => { $crate::mechanical::InverseEnergy::from_per_kWhr($value) };
+	(@unit $value:expr; per_eV) => { $crate::mechanical::InverseEnergy::from_per_eV($value) };
+	(@unit $value:expr; per_BTU) => { $crate::mechanical::InverseEnergy::from_per_BTU($value) };
+	(@unit $value:expr; per_N) => { $crate::mechanical::InverseForce::from_per_N($value) };
+	(@unit $value:expr; per_newton) => { $crate::mechanical::InverseForce::from_per_newton($value) };
+	(@unit $value:expr; per_lb) => { $crate::mechanical::InverseForce::from_per_lb($value) };
+	(@unit $value:expr; per_kgG) => { $crate::mechanical::InverseForce::from_per_kgG($value) };
+	(@unit $value:expr; per_mN) => { $crate::mechanical::InverseForce::from_per_mN($value) };
+	(@unit $value:expr; per_uN) => { $crate::mechanical::InverseForce::from_per_uN($value) };
+	(@unit $value:expr; per_nN) => { $crate::mechanical::InverseForce::from_per_nN($value) };
+	(@unit $value:expr; per_kN) => { $crate::mechanical::InverseForce::from_per_kN($value) };
+	(@unit $value:expr; per_MN) => { $crate::mechanical::InverseForce::from_per_MN($value) };
+	(@unit $value:expr; per_GN) => { $crate::mechanical::InverseForce::from_per_GN($value) };
+	(@unit $value:expr; per_kgm2) => { $crate::mechanical::InverseMomentOfInertia::from_per_kgm2($value) };
+	(@unit $value:expr; per_kilogram_meters_squared) => { $crate::mechanical::InverseMomentOfInertia::from_per_kilogram_meters_squared($value) };
+	(@unit $value:expr; per_gcm2) => { $crate::mechanical::InverseMomentOfInertia::from_per_gcm2($value) };
+	(@unit $value:expr; per_gm2) => { $crate::mechanical::InverseMomentOfInertia::from_per_gm2($value) };
+	(@unit $value:expr; s_per_kgm) => { $crate::mechanical::InverseMomentum::from_s_per_kgm($value) };
+	(@unit $value:expr; seconds_per_kilogram_meter) => { $crate::mechanical::InverseMomentum::from_seconds_per_kilogram_meter($value) };
+	(@unit $value:expr; s_per_gcm) => { $crate::mechanical::InverseMomentum::from_s_per_gcm($value) };
+	(@unit $value:expr; seconds_per_gram_centimeter) => { $crate::mechanical::InverseMomentum::from_seconds_per_gram_centimeter($value) };
+	(@unit $value:expr; per_W) => { $crate::mechanical::InversePower::from_per_W($value) };
+	(@unit $value:expr; per_watt) => { $crate::mechanical::InversePower::from_per_watt($value) };
+	(@unit $value:expr; per_mW) => { $crate::mechanical::InversePower::from_per_mW($value) };
+	(@unit $value:expr; per_uW) => { $crate::mechanical::InversePower::from_per_uW($value) };
+	(@unit $value:expr; per_nW) => { $crate::mechanical::InversePower::from_per_nW($value) };
+	(@unit $value:expr; per_kW) => { $crate::mechanical::InversePower::from_per_kW($value) };
+	(@unit $value:expr; per_MW) => { $crate::mechanical::InversePower::from_per_MW($value) };
+	(@unit $value:expr; per_GW) => { $crate::mechanical::InversePower::from_per_GW($value) };
+	(@unit $value:expr; per_horsepower) => { $crate::mechanical::InversePower::from_per_horsepower($value) };
+	(@unit $value:expr; per_Pa) => { $crate::mechanical::InversePressure::from_per_Pa($value) };
+	(@unit $value:expr; per_pascal) => { $crate::mechanical::InversePressure::from_per_pascal($value) };
+	(@unit $value:expr; per_psi) => { $crate::mechanical::InversePressure::from_per_psi($value) };
+	(@unit $value:expr; per_mPa) => { $crate::mechanical::InversePressure::from_per_mPa($value) };
+	(@unit $value:expr; per_uPa) => { $crate::mechanical::InversePressure::from_per_uPa($value) };
+	(@unit $value:expr; per_nPa) => { $crate::mechanical::InversePressure::from_per_nPa($value) };
+	(@unit $value:expr; per_kPa) => { $crate::mechanical::InversePressure::from_per_kPa($value) };
+	(@unit $value:expr; per_MPa) => { $crate::mechanical::InversePressure::from_per_MPa($value) };
+	(@unit $value:expr; per_GPa) => { $crate::mechanical::InversePressure::from_per_GPa($value) };
+	(@unit $value:expr; per_hPa) => { $crate::mechanical::InversePressure::from_per_hPa($value) };
+	(@unit $value:expr; per_bar) => { $crate::mechanical::InversePressure::from_per_bar($value) };
+	(@unit $value:expr; per_mbar) => { $crate::mechanical::InversePressure::from_per_mbar($value) };
+	(@unit $value:expr; per_atm) => { $crate::mechanical::InversePressure::from_per_atm($value) };
+	(@unit $value:expr; per_torr) => { $crate::mechanical::InversePressure::from_per_torr($value) };
+	(@unit $value:expr; per_mmHg) => { $crate::mechanical::InversePressure::from_per_mmHg($value) };
+	(@unit $value:expr; per_Pas) => { $crate::mechanical::InverseDynamicViscosity::from_per_Pas($value) };
+	(@unit $value:expr; per_pascal_seconds) => { $crate::mechanical::InverseDynamicViscosity::from_per_pascal_seconds($value) };
+	(@unit $value:expr; per_P) => { $crate::mechanical::InverseDynamicViscosity::from_per_P($value) };
+	(@unit $value:expr; per_cP) => { $crate::mechanical::InverseDynamicViscosity::from_per_cP($value) };
+	(@unit $value:expr; s_per_m2) => { $crate::mechanical::InverseKinematicViscosity::from_s_per_m2($value) };
+	(@unit $value:expr; seconds_per_square_meter) => { $crate::mechanical::InverseKinematicViscosity::from_seconds_per_square_meter($value) };
+	(@unit $value:expr; per_St) => { $crate::mechanical::InverseKinematicViscosity::from_per_St($value) };
+	(@unit $value:expr; per_cSt) => { $crate::mechanical::InverseKinematicViscosity::from_per_cSt($value) };
+	(@unit $value:expr; per_Nm) => { $crate::mechanical::InverseTorque::from_per_Nm($value) };
+	(@unit $value:expr; per_newton_meter) => { $crate::mechanical::InverseTorque::from_per_newton_meter($value) };
+	(@unit $value:expr; per_ftlb) => { $crate::mechanical::InverseTorque::from_per_ftlb($value) };
+	(@unit $value:expr; spm) => { $crate::mechanical::TimePerDistance::from_spm($value) };
+	(@unit $value:expr; seconds_per_meter) => { $crate::mechanical::TimePerDistance::from_seconds_per_meter($value) };
+	(@unit $value:expr; s_per_cm) => { $crate::mechanical::TimePerDistance::from_s_per_cm($value) };
+	(@unit $value:expr; s_per_mm) => { $crate::mechanical::TimePerDistance::from_s_per_mm($value) };
+	(@unit $value:expr; hr_per_km) => { $crate::mechanical::TimePerDistance::from_hr_per_km($value) };
+	(@unit $value:expr; hr_per_mi) => { $crate::mechanical::TimePerDistance::from_hr_per_mi($value) };
+	(@unit $value:expr; per_Gy) => { $crate::nuclear::InverseAbsorbedDose::from_per_Gy($value) };
+	(@unit $value:expr; per_grays) => { $crate::nuclear::InverseAbsorbedDose::from_per_grays($value) };
+	(@unit $value:expr; per_mGy) => { $crate::nuclear::InverseAbsorbedDose::from_per_mGy($value) };
+	(@unit $value:expr; per_uGy) => { $crate::nuclear::InverseAbsorbedDose::from_per_uGy($value) };
+	(@unit $value:expr; per_nGy) => { $crate::nuclear::InverseAbsorbedDose::from_per_nGy($value) };
+	(@unit $value:expr; per_kGy) => { $crate::nuclear::InverseAbsorbedDose::from_per_kGy($value) };
+	(@unit $value:expr; per_MGy) => { $crate::nuclear::InverseAbsorbedDose::from_per_MGy($value) };
+	(@unit $value:expr; per_GGy) => { $crate::nuclear::InverseAbsorbedDose::from_per_GGy($value) };
+	(@unit $value:expr; per_krad) => { $crate::nuclear::InverseAbsorbedDose::from_per_krad($value) };
+	(@unit $value:expr; per_mrad) => { $crate::nuclear::InverseAbsorbedDose::from_per_mrad($value) };
+	(@unit $value:expr; per_urad) => { $crate::nuclear::InverseAbsorbedDose::from_per_urad($value) };
+	(@unit $value:expr; per_erg) => { $crate::nuclear::InverseAbsorbedDose::from_per_erg($value) };
+	(@unit $value:expr; per_Sv) => { $crate::nuclear::InverseDoseEquivalent::from_per_Sv($value) };
+	(@unit $value:expr; per_sieverts) => { $crate::nuclear::InverseDoseEquivalent::from_per_sieverts($value) };
+	(@unit $value:expr; per_mSv) => { $crate::nuclear::InverseDoseEquivalent::from_per_mSv($value) };
+	(@unit $value:expr; per_uSv) => { $crate::nuclear::InverseDoseEquivalent::from_per_uSv($value) };
+	(@unit $value:expr; per_nSv) => { $crate::nuclear::InverseDoseEquivalent::from_per_nSv($value) };
+	(@unit $value:expr; per_kSv) => { $crate::nuclear::InverseDoseEquivalent::from_per_kSv($value) };
+	(@unit $value:expr; per_MSv) => { $crate::nuclear::InverseDoseEquivalent::from_per_MSv($value) };
+	(@unit $value:expr; per_GSv) => { $crate::nuclear::InverseDoseEquivalent::from_per_GSv($value) };
+	(@unit $value:expr; per_rem) => { $crate::nuclear::InverseDoseEquivalent::from_per_rem($value) };
+	(@unit $value:expr; per_mrem) => { $crate::nuclear::InverseDoseEquivalent::from_per_mrem($value) };
+	(@unit $value:expr; per_krem) => { $crate::nuclear::InverseDoseEquivalent::from_per_krem($value) };
+	(@unit $value:expr; s_per_Gy) => { $crate::nuclear::InverseAbsorbedDoseRate::from_s_per_Gy($value) };
+	(@unit $value:expr; seconds_per_gray) => { $crate::nuclear::InverseAbsorbedDoseRate::from_seconds_per_gray($value) };
+	(@unit $value:expr; s_per_mGy) => { $crate::nuclear::InverseAbsorbedDoseRate::from_s_per_mGy($value) };
+	(@unit $value:expr; s_per_uGy) => { $crate::nuclear::InverseAbsorbedDoseRate::from_s_per_uGy($value) };
+	(@unit $value:expr; h_per_Gy) => { $crate::nuclear::InverseAbsorbedDoseRate::from_h_per_Gy($value) };
+	(@unit $value:expr; h_per_mGy) => { $crate::nuclear::InverseAbsorbedDoseRate::from_h_per_mGy($value) };
+	(@unit $value:expr; h_per_uGy) => { $crate::nuclear::InverseAbsorbedDoseRate::from_h_per_uGy($value) };
+	(@unit $value:expr; h_per_nGy) => { $crate::nuclear::InverseAbsorbedDoseRate::from_h_per_nGy($value) };
+	(@unit $value:expr; s_per_Sv) => { $crate::nuclear::InverseDoseEquivalentRate::from_s_per_Sv($value) };
+	(@unit $value:expr; seconds_per_sievert) => { $crate::nuclear::InverseDoseEquivalentRate::from_seconds_per_sievert($value) };
+	(@unit $value:expr; s_per_mSv) => { $crate::nuclear::InverseDoseEquivalentRate::from_s_per_mSv($value) };
+	(@unit $value:expr; s_per_uSv) => { $crate::nuclear::InverseDoseEquivalentRate::from_s_per_uSv($value) };
+	(@unit $value:expr; h_per_Sv) => { $crate::nuclear::InverseDoseEquivalentRate::from_h_per_Sv($value) };
+	(@unit $value:expr; h_per_mSv) => { $crate::nuclear::InverseDoseEquivalentRate::from_h_per_mSv($value) };
+	(@unit $value:expr; h_per_uSv) => { $crate::nuclear::InverseDoseEquivalentRate::from_h_per_uSv($value) };
+	(@unit $value:expr; h_per_nSv) => { $crate::nuclear::InverseDoseEquivalentRate::from_h_per_nSv($value) };
+	(@unit $value:expr; $($unit:tt)+) => {
+		compile_error!(concat!("unknown or ambiguous unit symbol: ", stringify!($($unit)+)))
+	};
+}
@@ -1,26 +1,32 @@
 
 //! This module provides nuclear SI units, such as absorbed radiation dose 
-//! and inverse of radiation dose equivalent.
+//! and inverse of absorbed radiation dose rate.
 use core::fmt;
 use super::UnitStruct;
 use super::NumLike;
+use super::SiParseError;
 use super::base::*;
 use super::chemical::*;
 use super::mechanical::*;
 
 // optional supports
-#[cfg(feature="serde")]
+#[cfg(all(feature="serde", not(feature="serde-unit-tag")))]
 use serde::{Serialize, Deserialize};
 #[cfg(feature="num-bigfloat")]
 use num_bigfloat;
 #[cfg(feature="num-complex")]
 use num_complex;
-
+#[cfg(feature="num-rational")]
+use num_rational;
+#[cfg(feature="nalgebra")]
+use nalgebra;
 
 
 /// The absorbed radiation dose unit type, defined as grays in SI units
-#[derive(UnitStruct, Debug, Clone)]
-#[cfg_attr(feature="serde", derive(Serialize, Deserialize))]
+#[derive(UnitStruct, Debug, Clone, Default)]
+#[si(no_display)]
+#[cfg_attr(all(feature="serde", not(feature="serde-unit-tag")), derive(Serialize, Deserialize))]
+#[repr(transparent)]
 pub struct AbsorbedDose<T: NumLike>{
 	/// The value of this Absorbed dose in grays
 	pub Gy: T
@@ -33,6 +39,106 @@ impl<T> AbsorbedDose<T> where T: NumLike {
 	
 	/// Returns the abbreviated name or symbol of absorbed dose: "Gy" for grays
 	pub fn unit_symbol() -> &'static str { "Gy" }
+
+	/// Returns the exponents of the SI base units of absorbed dose (m^2/s^2)
+	pub const fn dimension() -> super::Dimension { super::Dimension{length: 2, mass: 0, time: -2, current: 0, temperature: 0, amount: 0, luminosity: 0} }
+
+	/// Returns a wrapper that formats this absorbed dose value with the full unit name instead of 
+	/// the unit symbol, eg "5 grays" (the unit name is not singularized for values of 1)
+	pub fn display_named(&self) -> super::NamedDisplay<'_, T> {
+		super::NamedDisplay::new(&self.Gy, Self::unit_name())
+	}
+
+	/// Returns a new absorbed dose value from the given number of grays (same as 
+	/// `from_Gy(...)`), for any number-like type. This is useful in generic code 
+	/// that works with several unit types
+	///
+	/// # Arguments
+	/// * `value` - Any number-like type, representing a quantity of grays
+	pub const fn new(value: T) -> Self { AbsorbedDose{Gy: value} }
+
+	/// Returns a new absorbed dose value from the given number of grays. Unlike
+	/// `from_Gy(...)`, this is a `const fn`, so it can be used to declare `const` and `static` values
+	///
+	/// # Arguments
+	/// * `Gy` - Any number-like type, representing a quantity of grays
+	pub const fn from_Gy_const(Gy: T) -> Self { AbsorbedDose{Gy} }
+
+	/// Returns a absorbed dose value with a different number type, by applying the 
+	/// given function to the value in grays (eg `.map(|x| x as f32)` to convert 
+	/// from `f64` to `f32`)
+	///
+	/// # Arguments
+	/// * `f` - Function that converts the number of grays to the new number type
+	pub fn map<U, F>(self, f: F) -> AbsorbedDose<U> where U: NumLike, F: FnOnce(T) -> U {
+		AbsorbedDose{Gy: f(self.Gy)}
+	}
+
+	/// Returns a absorbed dose value with a different number type, converted 
+	/// losslessly with the `From` trait (eg from `f32` to `f64`)
+	pub fn cast<U>(self) -> AbsorbedDose<U> where U: NumLike+From<T> {
+		AbsorbedDose{Gy: U::from(self.Gy)}
+	}
+
+	/// Returns a reference to the value of this absorbed dose in grays
+	pub fn as_raw(&self) -> &T {
+		&self.Gy
+	}
+
+	/// Returns a mutable reference to the value of this absorbed dose in grays, 
+	/// eg for passing to numeric libraries that modify values in place. The number 
+	/// is always in grays, so changing it directly does not change the unit
+	pub fn raw_mut(&mut self) -> &mut T {
+		&mut self.Gy
+	}
+
+	/// Returns a slice of numbers in grays as a slice of absorbed dose values, 
+	/// without copying
+	///
+	/// # Arguments
+	/// * `values` - Slice of numbers, representing quantities of grays
+	pub fn wrap_slice(values: &[T]) -> &[Self] {
+		// SAFETY: AbsorbedDose<T> is #[repr(transparent)], so it has the same memory layout as T
+		unsafe { core::slice::from_raw_parts(values.as_ptr() as *const Self, values.len()) }
+	}
+
+	/// Returns a mutable slice of numbers in grays as a mutable slice of 
+	/// absorbed dose values, without copying
+	///
+	/// # Arguments
+	/// * `values` - Slice of numbers, representing quantities of grays
+	pub fn wrap_slice_mut(values: &mut [T]) -> &mut [Self] {
+		// SAFETY: AbsorbedDose<T> is #[repr(transparent)], so it has the same memory layout as T
+		unsafe { core::slice::from_raw_parts_mut(values.as_mut_ptr() as *mut Self, values.len()) }
+	}
+
+	/// Returns the ratio of this absorbed dose value to another absorbed dose value 
+	/// (same as `self / other`). Dividing a unit value by another value of the same 
+	/// unit type cancels the units, so the ratio is a dimensionless number
+	///
+	/// # Arguments
+	/// * `other` - The absorbed dose value to divide by
+	pub fn ratio(self, other: Self) -> T {
+		self.Gy / other.Gy
+	}
+
+	/// Returns the ratio of this absorbed dose value to another absorbed dose value 
+	/// as a `Dimensionless` value, instead of a bare number like `ratio(...)` does
+	///
+	/// # Arguments
+	/// * `other` - The absorbed dose value to divide by
+	pub fn dimensionless_ratio(self, other: Self) -> super::base::Dimensionless<T> {
+		super::base::Dimensionless{value: self.Gy / other.Gy}
+	}
+
+	/// Returns this absorbed dose value multiplied by a dimensionless factor 
+	/// (same as `self * factor`)
+	///
+	/// # Arguments
+	/// * `factor` - The number to multiply by
+	pub fn scale(self, factor: T) -> Self {
+		AbsorbedDose{Gy: self.Gy * factor}
+	}
 	
 	/// Returns a new absorbed dose value from the given number of grays
 	///
@@ -54,13 +160,546 @@ impl<T> AbsorbedDose<T> where T: NumLike {
 
 }
 
+/// Formats absorbed dose values as a number followed by its unit symbol, eg "1500 Gy"
+/// (complex numbers are wrapped in parentheses, eg "(1+2i) Gy").
+/// The alternate flag (`{:#}`) scales the number by the nearest SI prefix where the unit symbol allows it
 impl<T> fmt::Display for AbsorbedDose<T> where T: NumLike {
 	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-		write!(f, "{} {}", &self.Gy, Self::unit_symbol())
+		if f.alternate() {
+			return super::fmt_with_si_prefix(f, &self.Gy, Self::unit_symbol(), true);
+		}
+		super::fmt_unit_value(f, &self.Gy, Self::unit_symbol())
+	}
+}
+
+/// Formats absorbed dose values in exponential notation followed by the unit symbol, eg "1.5e3 Gy"
+impl<T> fmt::LowerExp for AbsorbedDose<T> where T: NumLike+fmt::LowerExp {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		fmt::LowerExp::fmt(&self.Gy, f)?;
+		write!(f, " {}", Self::unit_symbol())
+	}
+}
+
+/// Formats absorbed dose values in exponential notation followed by the unit symbol, eg "1.5E3 Gy"
+impl<T> fmt::UpperExp for AbsorbedDose<T> where T: NumLike+fmt::UpperExp {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		fmt::UpperExp::fmt(&self.Gy, f)?;
+		write!(f, " {}", Self::unit_symbol())
+	}
+}
+
+impl<T> super::SiUnit for AbsorbedDose<T> where T: NumLike {
+	type Number = T;
+	fn unit_name(&self) -> &'static str { Self::unit_name() }
+	fn unit_symbol(&self) -> &'static str { Self::unit_symbol() }
+	fn raw(&self) -> &T { &self.Gy }
+	fn dimension(&self) -> super::Dimension { Self::dimension() }
+	fn from_raw(value: T) -> Self { AbsorbedDose{Gy: value} }
+	fn into_raw(self) -> T { self.Gy }
+}
+
+#[cfg(feature="alloc")]
+impl<T> AbsorbedDose<T> where T: NumLike {
+	/// Returns this absorbed dose value in the canonical text format "<value> <symbol>", eg "5 Gy".
+	/// Unlike `Display`, this format never changes with precision or alternate flags, and it is the
+	/// input format expected by `from_str(...)`, so `x.canonical_string().parse()` returns the same value
+	pub fn canonical_string(&self) -> alloc::string::String {
+		alloc::format!("{} {}", self.Gy, Self::unit_symbol())
+	}
+
+	/// Returns a `Vec` of absorbed dose values copied from the given slice of numbers
+	///
+	/// # Arguments
+	/// * `values` - Slice of numbers, representing quantities of grays
+	pub fn from_Gy_slice(values: &[T]) -> alloc::vec::Vec<Self> {
+		values.iter().map(|x| AbsorbedDose{Gy: x.clone()}).collect()
+	}
+}
+
+/// Parses a absorbed dose value from a number followed by its unit symbol, eg "5 Gy"
+impl<T> core::str::FromStr for AbsorbedDose<T> where T: NumLike+core::str::FromStr {
+	type Err = SiParseError;
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		Ok(AbsorbedDose{Gy: super::parse_unit_value(s, Self::unit_symbol())?})
+	}
+}
+
+/// Parses a absorbed dose value from a number followed by its unit symbol, eg "5 Gy" 
+/// (same as `str::parse()`)
+impl<T> core::convert::TryFrom<&str> for AbsorbedDose<T> where T: NumLike+core::str::FromStr {
+	type Error = SiParseError;
+	fn try_from(s: &str) -> Result<Self, Self::Error> {
+		<Self as core::str::FromStr>::from_str(s)
+	}
+}
+
+#[cfg(feature="num-traits")]
+impl<T> AbsorbedDose<T> where T: NumLike+num_traits::Euclid {
+
+	/// Returns the Euclidean remainder of dividing this absorbed dose value by another, 
+	/// which is never negative (requires the `num-traits` feature)
+	///
+	/// # Arguments
+	/// * `rhs` - The absorbed dose value to divide by
+	pub fn rem_euclid(self, rhs: Self) -> Self {
+		AbsorbedDose{Gy: self.Gy.rem_euclid(&rhs.Gy)}
+	}
+
+	/// Returns the Euclidean quotient of dividing this absorbed dose value by another, 
+	/// ie the whole number of times that `rhs` fits into this value, rounded such that 
+	/// `rem_euclid(...)` is never negative (requires the `num-traits` feature)
+	///
+	/// # Arguments
+	/// * `rhs` - The absorbed dose value to divide by
+	pub fn div_euclid(self, rhs: Self) -> T {
+		self.Gy.div_euclid(&rhs.Gy)
+	}
+}
+
+/// Summing absorbed dose values returns their total as a absorbed dose value (or zero if there are no values)
+impl<T> core::iter::Sum<AbsorbedDose<T>> for AbsorbedDose<T> where T: NumLike+core::iter::Sum {
+	fn sum<I: Iterator<Item=AbsorbedDose<T>>>(iter: I) -> Self {
+		AbsorbedDose{Gy: iter.map(|x| x.Gy).sum()}
+	}
+}
+/// Summing absorbed dose values returns their total as a absorbed dose value (or zero if there are no values)
+impl<'a, T> core::iter::Sum<&'a AbsorbedDose<T>> for AbsorbedDose<T> where T: NumLike+core::iter::Sum+'a {
+	fn sum<I: Iterator<Item=&'a AbsorbedDose<T>>>(iter: I) -> Self {
+		AbsorbedDose{Gy: iter.map(|x| x.Gy.clone()).sum()}
+	}
+}
+
+impl<T> AbsorbedDose<T> where T: NumLike+From<f64> {
+
+	/// Returns the weighted average of the given absorbed dose values, where each 
+	/// value is paired with its weight (weights do not need to add up to 1). If the 
+	/// total weight is zero (including when `values` is empty), then zero is returned
+	///
+	/// # Arguments
+	/// * `values` - Slice of (absorbed dose value, weight) pairs
+	pub fn weighted_mean(values: &[(Self, f64)]) -> Self {
+		let total_weight: f64 = values.iter().map(|(_, weight)| *weight).sum();
+		if total_weight == 0.0 {
+			return AbsorbedDose{Gy: T::from(0.0)};
+		}
+		let total = values.iter().fold(T::from(0.0),
+			|total, (value, weight)| total + value.Gy.clone() * T::from(*weight));
+		AbsorbedDose{Gy: total / T::from(total_weight)}
+	}
+}
+
+impl<T> AbsorbedDose<T> where T: NumLike+PartialEq+From<u8> {
+
+	/// Divides this absorbed dose value by another unit value (eg `distance.checked_div(time)` 
+	/// returns a velocity), returning `None` if the divisor is zero instead of producing 
+	/// infinity or NaN (or panicking, for integer number types). This works for every unit 
+	/// type that this absorbed dose value can be divided by with the `/` operator
+	///
+	/// # Arguments
+	/// * `rhs` - The unit value to divide by
+	pub fn checked_div<R>(self, rhs: R) -> Option<<Self as core::ops::Div<R>>::Output> 
+		where Self: core::ops::Div<R>, R: super::SiUnit<Number=T>
+	{
+		if *rhs.raw() == T::from(0u8) {
+			return None;
+		}
+		Some(self / rhs)
+	}
+}
+
+/// Multiplying a absorbed dose value by a dimensionless value returns a absorbed dose value
+impl<T> core::ops::Mul<super::base::Dimensionless<T>> for AbsorbedDose<T> where T: NumLike {
+	type Output = AbsorbedDose<T>;
+	fn mul(self, rhs: super::base::Dimensionless<T>) -> Self::Output {
+		AbsorbedDose{Gy: self.Gy * rhs.value}
+	}
+}
+/// Multiplying a dimensionless value by a absorbed dose value returns a absorbed dose value
+impl<T> core::ops::Mul<AbsorbedDose<T>> for super::base::Dimensionless<T> where T: NumLike {
+	type Output = AbsorbedDose<T>;
+	fn mul(self, rhs: AbsorbedDose<T>) -> Self::Output {
+		AbsorbedDose{Gy: self.value * rhs.Gy}
+	}
+}
+/// Dividing a absorbed dose value by a dimensionless value returns a absorbed dose value
+impl<T> core::ops::Div<super::base::Dimensionless<T>> for AbsorbedDose<T> where T: NumLike {
+	type Output = AbsorbedDose<T>;
+	fn div(self, rhs: super::base::Dimensionless<T>) -> Self::Output {
+		AbsorbedDose{Gy: self.Gy / rhs.value}
+	}
+}
+
+/// The remainder of dividing a absorbed dose value by another absorbed dose value is a absorbed dose value
+impl<T> core::ops::Rem<AbsorbedDose<T>> for AbsorbedDose<T> where T: NumLike+core::ops::Rem<Output=T> {
+	type Output = AbsorbedDose<T>;
+	fn rem(self, rhs: AbsorbedDose<T>) -> Self::Output {
+		AbsorbedDose{Gy: self.Gy % rhs.Gy}
+	}
+}
+/// The remainder of dividing a absorbed dose value by another absorbed dose value is a absorbed dose value
+impl<T> core::ops::Rem<&AbsorbedDose<T>> for AbsorbedDose<T> where T: NumLike+core::ops::Rem<Output=T> {
+	type Output = AbsorbedDose<T>;
+	fn rem(self, rhs: &AbsorbedDose<T>) -> Self::Output {
+		AbsorbedDose{Gy: self.Gy % rhs.Gy.clone()}
+	}
+}
+/// The remainder of dividing a absorbed dose value by another absorbed dose value is a absorbed dose value
+impl<T> core::ops::Rem<AbsorbedDose<T>> for &AbsorbedDose<T> where T: NumLike+core::ops::Rem<Output=T> {
+	type Output = AbsorbedDose<T>;
+	fn rem(self, rhs: AbsorbedDose<T>) -> Self::Output {
+		AbsorbedDose{Gy: self.Gy.clone() % rhs.Gy}
+	}
+}
+/// The remainder of dividing a absorbed dose value by another absorbed dose value is a absorbed dose value
+impl<T> core::ops::Rem<&AbsorbedDose<T>> for &AbsorbedDose<T> where T: NumLike+core::ops::Rem<Output=T> {
+	type Output = AbsorbedDose<T>;
+	fn rem(self, rhs: &AbsorbedDose<T>) -> Self::Output {
+		AbsorbedDose{Gy: self.Gy.clone() % rhs.Gy.clone()}
+	}
+}
+
+/// Serializes a absorbed dose value together with its unit symbol, eg 
+/// `{"value": 1.5, "unit": "Gy"}` (requires the `serde-unit-tag` feature)
+#[cfg(feature="serde-unit-tag")]
+impl<T> serde::Serialize for AbsorbedDose<T> where T: NumLike+serde::Serialize {
+	fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+		super::serialize_with_unit_tag(&self.Gy, Self::unit_symbol(), serializer)
+	}
+}
+/// Deserializes a absorbed dose value from a value and unit symbol, eg 
+/// `{"value": 1.5, "unit": "Gy"}`, returning an error if the unit symbol 
+/// does not match (requires the `serde-unit-tag` feature)
+#[cfg(feature="serde-unit-tag")]
+impl<'de, T> serde::Deserialize<'de> for AbsorbedDose<T> where T: NumLike+serde::Deserialize<'de> {
+	fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+		Ok(AbsorbedDose{Gy: super::deserialize_with_unit_tag(Self::unit_symbol(), deserializer)?})
+	}
+}
+
+/// The additive identity of absorbed dose values is zero grays (requires the `num-traits` feature)
+#[cfg(feature="num-traits")]
+impl<T> num_traits::Zero for AbsorbedDose<T> where T: NumLike+num_traits::Zero {
+	fn zero() -> Self { AbsorbedDose{Gy: T::zero()} }
+	fn is_zero(&self) -> bool { self.Gy.is_zero() }
+}
+
+#[cfg(feature="num-traits")]
+impl<T> AbsorbedDose<T> where T: NumLike+num_traits::Signed {
+
+	/// Returns the sign of this absorbed dose value as a dimensionless number: 1 if it is 
+	/// positive, -1 if it is negative, and 0 if it is zero for integer number types 
+	/// (for floating-point number types, +0.0 returns 1.0, -0.0 returns -1.0, and NaN returns 
+	/// NaN) (requires the `num-traits` feature)
+	pub fn signum(self) -> T {
+		self.Gy.signum()
+	}
+}
+
+impl<T> AbsorbedDose<T> where T: NumLike+PartialOrd {
+
+	/// Returns the absolute value of this absorbed dose value, ie its magnitude without a minus sign
+	/// (for floating-point number types, -0.0 returns +0.0)
+	pub fn abs(self) -> Self {
+		let negated = -self.Gy.clone();
+		if self.Gy < negated {
+			AbsorbedDose{Gy: negated}
+		} else if self.Gy == negated {
+			// zero, which is its own negation (adding +0.0 turns -0.0 into +0.0)
+			AbsorbedDose{Gy: negated + self.Gy}
+		} else {
+			self
+		}
+	}
+
+	/// Returns the lesser of this absorbed dose value and the given absorbed dose value
+	///
+	/// # Arguments
+	/// * `other` - The absorbed dose value to compare with this one
+	pub fn min(self, other: Self) -> Self {
+		if other.Gy < self.Gy { other } else { self }
+	}
+
+	/// Returns the greater of this absorbed dose value and the given absorbed dose value
+	///
+	/// # Arguments
+	/// * `other` - The absorbed dose value to compare with this one
+	pub fn max(self, other: Self) -> Self {
+		if other.Gy > self.Gy { other } else { self }
+	}
+
+	/// Returns the least of the given absorbed dose values, or `None` if there are none. 
+	/// Unlike `Iterator::min()`, this does not require `Ord`, so it works for 
+	/// floating-point numbers. NaN values are skipped (so the result is `None` if all 
+	/// values are NaN), and the first of several equal values is returned
+	///
+	/// # Arguments
+	/// * `values` - Any iterator or collection of absorbed dose values
+	pub fn min_of<I>(values: I) -> Option<Self> where I: IntoIterator<Item=Self> {
+		values.into_iter().filter(|x| x.Gy.partial_cmp(&x.Gy).is_some()).reduce(Self::min)
+	}
+
+	/// Returns the greatest of the given absorbed dose values, or `None` if there are none. 
+	/// Unlike `Iterator::max()`, this does not require `Ord`, so it works for 
+	/// floating-point numbers. NaN values are skipped (so the result is `None` if all 
+	/// values are NaN), and the first of several equal values is returned
+	///
+	/// # Arguments
+	/// * `values` - Any iterator or collection of absorbed dose values
+	pub fn max_of<I>(values: I) -> Option<Self> where I: IntoIterator<Item=Self> {
+		values.into_iter().filter(|x| x.Gy.partial_cmp(&x.Gy).is_some()).reduce(Self::max)
+	}
+
+	/// Returns this absorbed dose value restricted to the range from `lo` to `hi`
+	///
+	/// # Arguments
+	/// * `lo` - The minimum absorbed dose value
+	/// * `hi` - The maximum absorbed dose value
+	///
+	/// # Panics
+	/// Panics if `lo` is greater than `hi` (or if either of them is NaN)
+	pub fn clamp(self, lo: Self, hi: Self) -> Self {
+		assert!(lo.Gy <= hi.Gy, "lo must be less than or equal to hi");
+		if self.Gy < lo.Gy { lo } else if self.Gy > hi.Gy { hi } else { self }
+	}
+
+	/// Returns this absorbed dose value with its magnitude capped at `max`, keeping its 
+	/// sign (eg for capping a speed), or this value unchanged if its magnitude does not 
+	/// exceed `max`. For complex-valued absorbed dose, use `clamp_norm(...)` instead, 
+	/// which caps the magnitude while keeping the phase
+	///
+	/// # Arguments
+	/// * `max` - The maximum magnitude (should not be negative)
+	pub fn clamp_magnitude(self, max: Self) -> Self {
+		let negated_max = -max.Gy.clone();
+		if self.Gy > max.Gy { 
+			max 
+		} else if self.Gy < negated_max { 
+			AbsorbedDose{Gy: negated_max} 
+		} else { 
+			self 
+		}
+	}
+
+	/// Returns true if this absorbed dose value differs from the given absorbed dose 
+	/// value by no more than the given absolute tolerance (which is also a 
+	/// absorbed dose value, so the comparison is dimensionally safe). NaN is never 
+	/// equal to anything. See also the `assert_unit_eq!(...)` macro.
+	///
+	/// # Arguments
+	/// * `other` - The absorbed dose value to compare with this one
+	/// * `tolerance` - The largest allowed difference between the two values
+	pub fn eq_within(self, other: Self, tolerance: Self) -> bool {
+		let diff = self.Gy - other.Gy;
+		diff <= tolerance.Gy && -diff <= tolerance.Gy
+	}
+}
+
+#[cfg(feature="num-complex")]
+impl AbsorbedDose<num_complex::Complex32> {
+	/// Returns the magnitude (aka norm or absolute value) of this complex absorbed dose 
+	/// value as a real-valued absorbed dose (requires the `num-complex` feature)
+	pub fn magnitude(self) -> AbsorbedDose<f32> {
+		AbsorbedDose{Gy: self.Gy.norm()}
+	}
+
+	/// Returns this complex absorbed dose value scaled down so that its magnitude is 
+	/// at most `max` (keeping its phase), or this value unchanged if its magnitude does 
+	/// not exceed `max`. This is the complex equivalent of `clamp_magnitude(...)` (requires 
+	/// the `num-complex` feature)
+	///
+	/// # Arguments
+	/// * `max` - The maximum magnitude (should not be negative)
+	pub fn clamp_norm(self, max: AbsorbedDose<f32>) -> Self {
+		let norm = self.Gy.norm();
+		if norm > max.Gy {
+			AbsorbedDose{Gy: self.Gy * (max.Gy / norm)}
+		} else {
+			self
+		}
+	}
+}
+
+#[cfg(feature="num-complex")]
+impl AbsorbedDose<num_complex::Complex64> {
+	/// Returns the magnitude (aka norm or absolute value) of this complex absorbed dose 
+	/// value as a real-valued absorbed dose (requires the `num-complex` feature)
+	pub fn magnitude(self) -> AbsorbedDose<f64> {
+		AbsorbedDose{Gy: self.Gy.norm()}
+	}
+
+	/// Returns this complex absorbed dose value scaled down so that its magnitude is 
+	/// at most `max` (keeping its phase), or this value unchanged if its magnitude does 
+	/// not exceed `max`. This is the complex equivalent of `clamp_magnitude(...)` (requires 
+	/// the `num-complex` feature)
+	///
+	/// # Arguments
+	/// * `max` - The maximum magnitude (should not be negative)
+	pub fn clamp_norm(self, max: AbsorbedDose<f64>) -> Self {
+		let norm = self.Gy.norm();
+		if norm > max.Gy {
+			AbsorbedDose{Gy: self.Gy * (max.Gy / norm)}
+		} else {
+			self
+		}
+	}
+}
+
+/// Rounding and other floating-point functions (requires the `num-traits` feature, 
+/// and either the `std` or `libm` feature)
+#[cfg(all(feature="num-traits", any(feature="std", feature="libm")))]
+impl<T> AbsorbedDose<T> where T: NumLike+num_traits::Float {
+
+	/// Returns this absorbed dose value rounded to the nearest whole number of grays 
+	/// (half-way cases are rounded away from zero)
+	pub fn round(self) -> Self {
+		AbsorbedDose{Gy: self.Gy.round()}
+	}
+
+	/// Returns this absorbed dose value rounded down to a whole number of grays
+	pub fn floor(self) -> Self {
+		AbsorbedDose{Gy: self.Gy.floor()}
+	}
+
+	/// Returns this absorbed dose value rounded up to a whole number of grays
+	pub fn ceil(self) -> Self {
+		AbsorbedDose{Gy: self.Gy.ceil()}
+	}
+
+	/// Returns this absorbed dose value rounded towards zero to a whole number of grays
+	pub fn trunc(self) -> Self {
+		AbsorbedDose{Gy: self.Gy.trunc()}
+	}
+
+	/// Returns this absorbed dose value rounded to the nearest multiple of the given step 
+	/// (half-way cases are rounded away from zero)
+	///
+	/// # Arguments
+	/// * `step` - The absorbed dose value to round to a multiple of
+	pub fn round_to(self, step: Self) -> Self {
+		AbsorbedDose{Gy: (self.Gy / step.Gy).round() * step.Gy}
+	}
+
+	/// Fused multiply-add: returns `self * a + b` computed with only one rounding 
+	/// error, which is more accurate (and on some hardware faster) than a separate 
+	/// multiplication and addition
+	///
+	/// # Arguments
+	/// * `a` - The scalar value to multiply this absorbed dose value by
+	/// * `b` - The absorbed dose value to add to the product
+	pub fn mul_add(self, a: T, b: Self) -> Self {
+		AbsorbedDose{Gy: self.Gy.mul_add(a, b.Gy)}
+	}
+
+	/// Returns true if this absorbed dose value is neither infinite nor NaN
+	pub fn is_finite(&self) -> bool {
+		self.Gy.is_finite()
+	}
+
+	/// Returns true if this absorbed dose value is positive or negative infinity
+	pub fn is_infinite(&self) -> bool {
+		self.Gy.is_infinite()
+	}
+
+	/// Returns true if this absorbed dose value is NaN (not a number)
+	pub fn is_nan(&self) -> bool {
+		self.Gy.is_nan()
+	}
+
+	/// Returns true if this absorbed dose value has a positive sign, including +0.0 
+	/// and positive infinity
+	pub fn is_sign_positive(&self) -> bool {
+		self.Gy.is_sign_positive()
+	}
+
+	/// Returns true if this absorbed dose value has a negative sign, including -0.0 
+	/// and negative infinity
+	pub fn is_sign_negative(&self) -> bool {
+		self.Gy.is_sign_negative()
+	}
+
+	/// Returns a absorbed dose value with the magnitude of this one and the sign of 
+	/// `sign_source`, eg for reflecting a value off of a surface
+	///
+	/// # Arguments
+	/// * `sign_source` - The absorbed dose value whose sign is copied
+	pub fn copysign(self, sign_source: Self) -> Self {
+		AbsorbedDose{Gy: self.Gy.copysign(sign_source.Gy)}
+	}
+}
+
+#[cfg(feature="num-traits")]
+impl<T> AbsorbedDose<T> where T: NumLike+num_traits::CheckedAdd+num_traits::CheckedSub {
+
+	/// Adds another absorbed dose value to this one, returning `None` if the 
+	/// addition overflows the number type (requires the `num-traits` feature)
+	pub fn checked_add(self, rhs: Self) -> Option<Self> {
+		Some(AbsorbedDose{Gy: self.Gy.checked_add(&rhs.Gy)?})
+	}
+
+	/// Subtracts another absorbed dose value from this one, returning `None` if 
+	/// the subtraction overflows the number type (requires the `num-traits` feature)
+	pub fn checked_sub(self, rhs: Self) -> Option<Self> {
+		Some(AbsorbedDose{Gy: self.Gy.checked_sub(&rhs.Gy)?})
+	}
+}
+
+#[cfg(feature="num-traits")]
+impl<T> AbsorbedDose<T> where T: NumLike+num_traits::SaturatingAdd+num_traits::SaturatingSub {
+
+	/// Adds another absorbed dose value to this one, clamping the result to the 
+	/// minimum or maximum value of the number type instead of overflowing 
+	/// (requires the `num-traits` feature)
+	pub fn saturating_add(self, rhs: Self) -> Self {
+		AbsorbedDose{Gy: self.Gy.saturating_add(&rhs.Gy)}
+	}
+
+	/// Subtracts another absorbed dose value from this one, clamping the result 
+	/// to the minimum or maximum value of the number type instead of overflowing 
+	/// (requires the `num-traits` feature)
+	pub fn saturating_sub(self, rhs: Self) -> Self {
+		AbsorbedDose{Gy: self.Gy.saturating_sub(&rhs.Gy)}
+	}
+}
+
+impl<T> AbsorbedDose<T> where T: NumLike+Into<f64> {
+
+	/// Returns true if this absorbed dose value is equal to the given absorbed dose 
+	/// value to within the given number of significant figures (eg 6 significant 
+	/// figures allows a relative difference of up to 1 part per million). NaN is 
+	/// only approximately equal to NaN, infinity is only approximately equal to 
+	/// infinity of the same sign, and zero is only approximately equal to zero.
+	///
+	/// # Arguments
+	/// * `other` - The absorbed dose value to compare with this one
+	/// * `sig_figs` - The number of significant figures that must match
+	pub fn approx_eq(self, other: Self, sig_figs: i32) -> bool {
+		super::approx_eq_f64(self.Gy.into(), other.Gy.into(), sig_figs)
 	}
 }
 
 impl<T> AbsorbedDose<T> where T: NumLike+From<f64> {
+
+	/// Returns the linear interpolation between this absorbed dose value and the given 
+	/// absorbed dose value, ie `self*(1-t) + other*t`. The interpolation factor is not 
+	/// clamped, so values of `t` outside the range 0 to 1 extrapolate beyond the two 
+	/// values (see `lerp_clamped(...)`).
+	///
+	/// # Arguments
+	/// * `other` - The absorbed dose value to interpolate towards (returned when `t` is 1)
+	/// * `t` - The interpolation factor, where 0 returns this value and 1 returns `other`
+	pub fn lerp(self, other: Self, t: f64) -> Self {
+		AbsorbedDose{Gy: self.Gy * T::from(1.0 - t) + other.Gy * T::from(t)}
+	}
+
+	/// Same as `lerp(...)`, except that the interpolation factor `t` is first clamped 
+	/// to the range 0 to 1, so that the result is always between the two values
+	///
+	/// # Arguments
+	/// * `other` - The absorbed dose value to interpolate towards (returned when `t` is 1 or more)
+	/// * `t` - The interpolation factor, where 0 returns this value and 1 returns `other`
+	pub fn lerp_clamped(self, other: Self, t: f64) -> Self {
+		self.lerp(other, t.clamp(0.0, 1.0))
+	}
 	
 	/// Returns a copy of this absorbed dose value in milligrays
 	/// 
@@ -351,1502 +990,11385 @@ impl core::ops::Mul<&AbsorbedDose<num_complex::Complex64>> for &num_complex::Com
 	}
 }
 
-
-
-
-// AbsorbedDose * Mass -> Energy
-/// Multiplying a AbsorbedDose by a Mass returns a value of type Energy
-impl<T> core::ops::Mul<Mass<T>> for AbsorbedDose<T> where T: NumLike {
-	type Output = Energy<T>;
-	fn mul(self, rhs: Mass<T>) -> Self::Output {
-		Energy{J: self.Gy * rhs.kg}
+/// Multiplying a unit value by a scalar value returns a unit value
+#[cfg(feature="num-rational")]
+impl core::ops::Mul<AbsorbedDose<num_rational::Rational64>> for num_rational::Rational64 {
+	type Output = AbsorbedDose<num_rational::Rational64>;
+	fn mul(self, rhs: AbsorbedDose<num_rational::Rational64>) -> Self::Output {
+		AbsorbedDose{Gy: self * rhs.Gy}
 	}
 }
-/// Multiplying a AbsorbedDose by a Mass returns a value of type Energy
-impl<T> core::ops::Mul<Mass<T>> for &AbsorbedDose<T> where T: NumLike {
-	type Output = Energy<T>;
-	fn mul(self, rhs: Mass<T>) -> Self::Output {
-		Energy{J: self.Gy.clone() * rhs.kg}
+/// Multiplying a unit value by a scalar value returns a unit value
+#[cfg(feature="num-rational")]
+impl core::ops::Mul<AbsorbedDose<num_rational::Rational64>> for &num_rational::Rational64 {
+	type Output = AbsorbedDose<num_rational::Rational64>;
+	fn mul(self, rhs: AbsorbedDose<num_rational::Rational64>) -> Self::Output {
+		AbsorbedDose{Gy: self.clone() * rhs.Gy}
 	}
 }
-/// Multiplying a AbsorbedDose by a Mass returns a value of type Energy
-impl<T> core::ops::Mul<&Mass<T>> for AbsorbedDose<T> where T: NumLike {
-	type Output = Energy<T>;
-	fn mul(self, rhs: &Mass<T>) -> Self::Output {
-		Energy{J: self.Gy * rhs.kg.clone()}
+/// Multiplying a unit value by a scalar value returns a unit value
+#[cfg(feature="num-rational")]
+impl core::ops::Mul<&AbsorbedDose<num_rational::Rational64>> for num_rational::Rational64 {
+	type Output = AbsorbedDose<num_rational::Rational64>;
+	fn mul(self, rhs: &AbsorbedDose<num_rational::Rational64>) -> Self::Output {
+		AbsorbedDose{Gy: self * rhs.Gy.clone()}
 	}
 }
-/// Multiplying a AbsorbedDose by a Mass returns a value of type Energy
-impl<T> core::ops::Mul<&Mass<T>> for &AbsorbedDose<T> where T: NumLike {
-	type Output = Energy<T>;
-	fn mul(self, rhs: &Mass<T>) -> Self::Output {
-		Energy{J: self.Gy.clone() * rhs.kg.clone()}
-	}
+/// Multiplying a unit value by a scalar value returns a unit value
+#[cfg(feature="num-rational")]
+impl core::ops::Mul<&AbsorbedDose<num_rational::Rational64>> for &num_rational::Rational64 {
+	type Output = AbsorbedDose<num_rational::Rational64>;
+	fn mul(self, rhs: &AbsorbedDose<num_rational::Rational64>) -> Self::Output {
+		AbsorbedDose{Gy: self.clone() * rhs.Gy.clone()}
+	}
 }
 
-/// The radiation dose equivalent unit type, defined as sieverts in SI units
-#[derive(UnitStruct, Debug, Clone)]
-#[cfg_attr(feature="serde", derive(Serialize, Deserialize))]
-pub struct DoseEquivalent<T: NumLike>{
-	/// The value of this Dose equivalent in sieverts
-	pub Sv: T
+/// Multiplying a unit value by a scalar value returns a unit value
+#[cfg(feature="num-rational")]
+impl core::ops::Mul<AbsorbedDose<num_rational::BigRational>> for num_rational::BigRational {
+	type Output = AbsorbedDose<num_rational::BigRational>;
+	fn mul(self, rhs: AbsorbedDose<num_rational::BigRational>) -> Self::Output {
+		AbsorbedDose{Gy: self * rhs.Gy}
+	}
+}
+/// Multiplying a unit value by a scalar value returns a unit value
+#[cfg(feature="num-rational")]
+impl core::ops::Mul<AbsorbedDose<num_rational::BigRational>> for &num_rational::BigRational {
+	type Output = AbsorbedDose<num_rational::BigRational>;
+	fn mul(self, rhs: AbsorbedDose<num_rational::BigRational>) -> Self::Output {
+		AbsorbedDose{Gy: self.clone() * rhs.Gy}
+	}
+}
+/// Multiplying a unit value by a scalar value returns a unit value
+#[cfg(feature="num-rational")]
+impl core::ops::Mul<&AbsorbedDose<num_rational::BigRational>> for num_rational::BigRational {
+	type Output = AbsorbedDose<num_rational::BigRational>;
+	fn mul(self, rhs: &AbsorbedDose<num_rational::BigRational>) -> Self::Output {
+		AbsorbedDose{Gy: self * rhs.Gy.clone()}
+	}
+}
+/// Multiplying a unit value by a scalar value returns a unit value
+#[cfg(feature="num-rational")]
+impl core::ops::Mul<&AbsorbedDose<num_rational::BigRational>> for &num_rational::BigRational {
+	type Output = AbsorbedDose<num_rational::BigRational>;
+	fn mul(self, rhs: &AbsorbedDose<num_rational::BigRational>) -> Self::Output {
+		AbsorbedDose{Gy: self.clone() * rhs.Gy.clone()}
+	}
 }
 
-impl<T> DoseEquivalent<T> where T: NumLike {
 
-	/// Returns the standard unit name of dose equivalent: "sieverts"
-	pub fn unit_name() -> &'static str { "sieverts" }
-	
-	/// Returns the abbreviated name or symbol of dose equivalent: "Sv" for sieverts
-	pub fn unit_symbol() -> &'static str { "Sv" }
-	
-	/// Returns a new dose equivalent value from the given number of sieverts
-	///
-	/// # Arguments
-	/// * `Sv` - Any number-like type, representing a quantity of sieverts
-	pub fn from_Sv(Sv: T) -> Self { DoseEquivalent{Sv: Sv} }
-	
-	/// Returns a copy of this dose equivalent value in sieverts
-	pub fn to_Sv(&self) -> T { self.Sv.clone() }
 
-	/// Returns a new dose equivalent value from the given number of sieverts
-	///
-	/// # Arguments
-	/// * `sieverts` - Any number-like type, representing a quantity of sieverts
-	pub fn from_sieverts(sieverts: T) -> Self { DoseEquivalent{Sv: sieverts} }
-	
-	/// Returns a copy of this dose equivalent value in sieverts
-	pub fn to_sieverts(&self) -> T { self.Sv.clone() }
 
-}
 
-impl<T> fmt::Display for DoseEquivalent<T> where T: NumLike {
-	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-		write!(f, "{} {}", &self.Sv, Self::unit_symbol())
+/// Multiplying a AbsorbedDose by an [nalgebra](https://crates.io/crates/nalgebra) 
+/// vector or matrix of unitless values returns a vector or matrix of AbsorbedDose 
+/// values (eg a direction vector multiplied by a AbsorbedDose)
+#[cfg(feature="nalgebra")]
+impl<T, R, C, S> core::ops::Mul<nalgebra::Matrix<T, R, C, S>> for AbsorbedDose<T> where 
+	T: NumLike+nalgebra::Scalar, R: nalgebra::Dim, C: nalgebra::Dim, S: nalgebra::RawStorage<T, R, C>, 
+	nalgebra::DefaultAllocator: nalgebra::allocator::Allocator<R, C> {
+	type Output = nalgebra::OMatrix<AbsorbedDose<T>, R, C>;
+	fn mul(self, rhs: nalgebra::Matrix<T, R, C, S>) -> Self::Output {
+		rhs.map(|x| AbsorbedDose{Gy: self.Gy.clone() * x})
 	}
 }
-
-impl<T> DoseEquivalent<T> where T: NumLike+From<f64> {
-	
-	/// Returns a copy of this dose equivalent value in millisieverts
-	/// 
-	/// *Note: This method is not available for `f32` and other number types lacking the `From<f64>` trait*
-	pub fn to_mSv(&self) -> T {
-		return self.Sv.clone() * T::from(1000.0_f64);
+/// Multiplying an [nalgebra](https://crates.io/crates/nalgebra) vector or matrix of 
+/// unitless values by a AbsorbedDose returns a vector or matrix of AbsorbedDose values
+#[cfg(feature="nalgebra")]
+impl<T, R, C, S> core::ops::Mul<AbsorbedDose<T>> for nalgebra::Matrix<T, R, C, S> where 
+	T: NumLike+nalgebra::Scalar, R: nalgebra::Dim, C: nalgebra::Dim, S: nalgebra::RawStorage<T, R, C>, 
+	nalgebra::DefaultAllocator: nalgebra::allocator::Allocator<R, C> {
+	type Output = nalgebra::OMatrix<AbsorbedDose<T>, R, C>;
+	fn mul(self, rhs: AbsorbedDose<T>) -> Self::Output {
+		self.map(|x| AbsorbedDose{Gy: x * rhs.Gy.clone()})
 	}
-
-	/// Returns a new dose equivalent value from the given number of millisieverts
-	/// 
-	/// *Note: This method is not available for `f32` and other number types lacking the `From<f64>` trait*
-	///
-	/// # Arguments
-	/// * `mSv` - Any number-like type, representing a quantity of millisieverts
-	pub fn from_mSv(mSv: T) -> Self {
-		DoseEquivalent{Sv: mSv * T::from(0.001_f64)}
+}
+/// Dividing an [nalgebra](https://crates.io/crates/nalgebra) vector or matrix of 
+/// AbsorbedDose values by a AbsorbedDose returns a vector or matrix of unitless values
+#[cfg(feature="nalgebra")]
+impl<T, R, C, S> core::ops::Div<AbsorbedDose<T>> for nalgebra::Matrix<AbsorbedDose<T>, R, C, S> where 
+	T: NumLike+nalgebra::Scalar, R: nalgebra::Dim, C: nalgebra::Dim, S: nalgebra::RawStorage<AbsorbedDose<T>, R, C>, 
+	nalgebra::DefaultAllocator: nalgebra::allocator::Allocator<R, C> {
+	type Output = nalgebra::OMatrix<T, R, C>;
+	fn div(self, rhs: AbsorbedDose<T>) -> Self::Output {
+		self.map(|x| x.Gy / rhs.Gy.clone())
 	}
+}
 
-	/// Returns a copy of this dose equivalent value in microsieverts
-	/// 
-	/// *Note: This method is not available for `f32` and other number types lacking the `From<f64>` trait*
-	pub fn to_uSv(&self) -> T {
-		return self.Sv.clone() * T::from(1000000.0_f64);
-	}
 
-	/// Returns a new dose equivalent value from the given number of microsieverts
-	/// 
-	/// *Note: This method is not available for `f32` and other number types lacking the `From<f64>` trait*
-	///
-	/// # Arguments
-	/// * `uSv` - Any number-like type, representing a quantity of microsieverts
-	pub fn from_uSv(uSv: T) -> Self {
-		DoseEquivalent{Sv: uSv * T::from(1e-06_f64)}
-	}
 
-	/// Returns a copy of this dose equivalent value in nanosieverts
-	/// 
-	/// *Note: This method is not available for `f32` and other number types lacking the `From<f64>` trait*
-	pub fn to_nSv(&self) -> T {
-		return self.Sv.clone() * T::from(1000000000.0_f64);
+// AbsorbedDose * Mass -> Energy
+/// Multiplying a AbsorbedDose by a Mass returns a value of type Energy
+impl<T> core::ops::Mul<Mass<T>> for AbsorbedDose<T> where T: NumLike {
+	type Output = Energy<T>;
+	fn mul(self, rhs: Mass<T>) -> Self::Output {
+		Energy{J: self.Gy * rhs.kg}
 	}
-
-	/// Returns a new dose equivalent value from the given number of nanosieverts
-	/// 
-	/// *Note: This method is not available for `f32` and other number types lacking the `From<f64>` trait*
-	///
-	/// # Arguments
-	/// * `nSv` - Any number-like type, representing a quantity of nanosieverts
-	pub fn from_nSv(nSv: T) -> Self {
-		DoseEquivalent{Sv: nSv * T::from(1e-09_f64)}
+}
+/// Multiplying a AbsorbedDose by a Mass returns a value of type Energy
+impl<T> core::ops::Mul<Mass<T>> for &AbsorbedDose<T> where T: NumLike {
+	type Output = Energy<T>;
+	fn mul(self, rhs: Mass<T>) -> Self::Output {
+		Energy{J: self.Gy.clone() * rhs.kg}
 	}
-
-	/// Returns a copy of this dose equivalent value in kilosieverts
-	/// 
-	/// *Note: This method is not available for `f32` and other number types lacking the `From<f64>` trait*
-	pub fn to_kSv(&self) -> T {
-		return self.Sv.clone() * T::from(0.001_f64);
+}
+/// Multiplying a AbsorbedDose by a Mass returns a value of type Energy
+impl<T> core::ops::Mul<&Mass<T>> for AbsorbedDose<T> where T: NumLike {
+	type Output = Energy<T>;
+	fn mul(self, rhs: &Mass<T>) -> Self::Output {
+		Energy{J: self.Gy * rhs.kg.clone()}
 	}
-
-	/// Returns a new dose equivalent value from the given number of kilosieverts
-	/// 
-	/// *Note: This method is not available for `f32` and other number types lacking the `From<f64>` trait*
-	///
-	/// # Arguments
-	/// * `kSv` - Any number-like type, representing a quantity of kilosieverts
-	pub fn from_kSv(kSv: T) -> Self {
-		DoseEquivalent{Sv: kSv * T::from(1000.0_f64)}
+}
+/// Multiplying a AbsorbedDose by a Mass returns a value of type Energy
+impl<T> core::ops::Mul<&Mass<T>> for &AbsorbedDose<T> where T: NumLike {
+	type Output = Energy<T>;
+	fn mul(self, rhs: &Mass<T>) -> Self::Output {
+		Energy{J: self.Gy.clone() * rhs.kg.clone()}
 	}
+}
 
-	/// Returns a copy of this dose equivalent value in megasieverts
-	/// 
-	/// *Note: This method is not available for `f32` and other number types lacking the `From<f64>` trait*
-	pub fn to_MSv(&self) -> T {
-		return self.Sv.clone() * T::from(1e-06_f64);
+// AbsorbedDose / TemperatureInterval -> SpecificHeatCapacity
+/// Dividing a AbsorbedDose by a TemperatureInterval returns a value of type SpecificHeatCapacity
+impl<T> core::ops::Div<TemperatureInterval<T>> for AbsorbedDose<T> where T: NumLike {
+	type Output = SpecificHeatCapacity<T>;
+	fn div(self, rhs: TemperatureInterval<T>) -> Self::Output {
+		SpecificHeatCapacity{J_per_kgK: self.Gy / rhs.K}
 	}
-
-	/// Returns a new dose equivalent value from the given number of megasieverts
-	/// 
-	/// *Note: This method is not available for `f32` and other number types lacking the `From<f64>` trait*
-	///
-	/// # Arguments
-	/// * `MSv` - Any number-like type, representing a quantity of megasieverts
-	pub fn from_MSv(MSv: T) -> Self {
-		DoseEquivalent{Sv: MSv * T::from(1000000.0_f64)}
+}
+/// Dividing a AbsorbedDose by a TemperatureInterval returns a value of type SpecificHeatCapacity
+impl<T> core::ops::Div<TemperatureInterval<T>> for &AbsorbedDose<T> where T: NumLike {
+	type Output = SpecificHeatCapacity<T>;
+	fn div(self, rhs: TemperatureInterval<T>) -> Self::Output {
+		SpecificHeatCapacity{J_per_kgK: self.Gy.clone() / rhs.K}
 	}
-
-	/// Returns a copy of this dose equivalent value in gigasieverts
-	/// 
-	/// *Note: This method is not available for `f32` and other number types lacking the `From<f64>` trait*
-	pub fn to_GSv(&self) -> T {
-		return self.Sv.clone() * T::from(1e-09_f64);
+}
+/// Dividing a AbsorbedDose by a TemperatureInterval returns a value of type SpecificHeatCapacity
+impl<T> core::ops::Div<&TemperatureInterval<T>> for AbsorbedDose<T> where T: NumLike {
+	type Output = SpecificHeatCapacity<T>;
+	fn div(self, rhs: &TemperatureInterval<T>) -> Self::Output {
+		SpecificHeatCapacity{J_per_kgK: self.Gy / rhs.K.clone()}
 	}
-
-	/// Returns a new dose equivalent value from the given number of gigasieverts
-	/// 
-	/// *Note: This method is not available for `f32` and other number types lacking the `From<f64>` trait*
-	///
-	/// # Arguments
-	/// * `GSv` - Any number-like type, representing a quantity of gigasieverts
-	pub fn from_GSv(GSv: T) -> Self {
-		DoseEquivalent{Sv: GSv * T::from(1000000000.0_f64)}
+}
+/// Dividing a AbsorbedDose by a TemperatureInterval returns a value of type SpecificHeatCapacity
+impl<T> core::ops::Div<&TemperatureInterval<T>> for &AbsorbedDose<T> where T: NumLike {
+	type Output = SpecificHeatCapacity<T>;
+	fn div(self, rhs: &TemperatureInterval<T>) -> Self::Output {
+		SpecificHeatCapacity{J_per_kgK: self.Gy.clone() / rhs.K.clone()}
 	}
+}
 
-	/// Returns a copy of this dose equivalent value in roentgen equivalent man
-	/// 
-	/// *Note: This method is not available for `f32` and other number types lacking the `From<f64>` trait*
-	pub fn to_rem(&self) -> T {
-		return self.Sv.clone() * T::from(100.0_f64);
+// AbsorbedDose / Time -> AbsorbedDoseRate
+/// Dividing a AbsorbedDose by a Time returns a value of type AbsorbedDoseRate
+impl<T> core::ops::Div<Time<T>> for AbsorbedDose<T> where T: NumLike {
+	type Output = AbsorbedDoseRate<T>;
+	fn div(self, rhs: Time<T>) -> Self::Output {
+		AbsorbedDoseRate{Gy_per_s: self.Gy / rhs.s}
 	}
-
-	/// Returns a new dose equivalent value from the given number of roentgen equivalent man
-	/// 
-	/// *Note: This method is not available for `f32` and other number types lacking the `From<f64>` trait*
-	///
-	/// # Arguments
-	/// * `rem` - Any number-like type, representing a quantity of roentgen equivalent man
-	pub fn from_rem(rem: T) -> Self {
-		DoseEquivalent{Sv: rem * T::from(0.01_f64)}
+}
+/// Dividing a AbsorbedDose by a Time returns a value of type AbsorbedDoseRate
+impl<T> core::ops::Div<Time<T>> for &AbsorbedDose<T> where T: NumLike {
+	type Output = AbsorbedDoseRate<T>;
+	fn div(self, rhs: Time<T>) -> Self::Output {
+		AbsorbedDoseRate{Gy_per_s: self.Gy.clone() / rhs.s}
 	}
-
-	/// Returns a copy of this dose equivalent value in milli-roentgen equivalents
-	/// 
-	/// *Note: This method is not available for `f32` and other number types lacking the `From<f64>` trait*
-	pub fn to_mrem(&self) -> T {
-		return self.Sv.clone() * T::from(100000.0_f64);
+}
+/// Dividing a AbsorbedDose by a Time returns a value of type AbsorbedDoseRate
+impl<T> core::ops::Div<&Time<T>> for AbsorbedDose<T> where T: NumLike {
+	type Output = AbsorbedDoseRate<T>;
+	fn div(self, rhs: &Time<T>) -> Self::Output {
+		AbsorbedDoseRate{Gy_per_s: self.Gy / rhs.s.clone()}
 	}
-
-	/// Returns a new dose equivalent value from the given number of milli-roentgen equivalents
-	/// 
-	/// *Note: This method is not available for `f32` and other number types lacking the `From<f64>` trait*
-	///
-	/// # Arguments
-	/// * `mrem` - Any number-like type, representing a quantity of milli-roentgen equivalents
-	pub fn from_mrem(mrem: T) -> Self {
-		DoseEquivalent{Sv: mrem * T::from(1e-05_f64)}
+}
+/// Dividing a AbsorbedDose by a Time returns a value of type AbsorbedDoseRate
+impl<T> core::ops::Div<&Time<T>> for &AbsorbedDose<T> where T: NumLike {
+	type Output = AbsorbedDoseRate<T>;
+	fn div(self, rhs: &Time<T>) -> Self::Output {
+		AbsorbedDoseRate{Gy_per_s: self.Gy.clone() / rhs.s.clone()}
 	}
+}
 
-	/// Returns a copy of this dose equivalent value in kilo-roentgen equivalents
-	/// 
-	/// *Note: This method is not available for `f32` and other number types lacking the `From<f64>` trait*
-	pub fn to_krem(&self) -> T {
-		return self.Sv.clone() * T::from(0.1_f64);
+// AbsorbedDose / SpecificHeatCapacity -> TemperatureInterval
+/// Dividing a AbsorbedDose by a SpecificHeatCapacity returns a value of type TemperatureInterval
+impl<T> core::ops::Div<SpecificHeatCapacity<T>> for AbsorbedDose<T> where T: NumLike {
+	type Output = TemperatureInterval<T>;
+	fn div(self, rhs: SpecificHeatCapacity<T>) -> Self::Output {
+		TemperatureInterval{K: self.Gy / rhs.J_per_kgK}
 	}
-
-	/// Returns a new dose equivalent value from the given number of kilo-roentgen equivalents
-	/// 
-	/// *Note: This method is not available for `f32` and other number types lacking the `From<f64>` trait*
-	///
-	/// # Arguments
-	/// * `krem` - Any number-like type, representing a quantity of kilo-roentgen equivalents
-	pub fn from_krem(krem: T) -> Self {
-		DoseEquivalent{Sv: krem * T::from(10.0_f64)}
+}
+/// Dividing a AbsorbedDose by a SpecificHeatCapacity returns a value of type TemperatureInterval
+impl<T> core::ops::Div<SpecificHeatCapacity<T>> for &AbsorbedDose<T> where T: NumLike {
+	type Output = TemperatureInterval<T>;
+	fn div(self, rhs: SpecificHeatCapacity<T>) -> Self::Output {
+		TemperatureInterval{K: self.Gy.clone() / rhs.J_per_kgK}
 	}
-
 }
-
-
-/// Multiplying a unit value by a scalar value returns a unit value
-#[cfg(feature="num-bigfloat")]
-impl core::ops::Mul<DoseEquivalent<num_bigfloat::BigFloat>> for num_bigfloat::BigFloat {
-	type Output = DoseEquivalent<num_bigfloat::BigFloat>;
-	fn mul(self, rhs: DoseEquivalent<num_bigfloat::BigFloat>) -> Self::Output {
-		DoseEquivalent{Sv: self * rhs.Sv}
+/// Dividing a AbsorbedDose by a SpecificHeatCapacity returns a value of type TemperatureInterval
+impl<T> core::ops::Div<&SpecificHeatCapacity<T>> for AbsorbedDose<T> where T: NumLike {
+	type Output = TemperatureInterval<T>;
+	fn div(self, rhs: &SpecificHeatCapacity<T>) -> Self::Output {
+		TemperatureInterval{K: self.Gy / rhs.J_per_kgK.clone()}
 	}
 }
-/// Multiplying a unit value by a scalar value returns a unit value
-#[cfg(feature="num-bigfloat")]
-impl core::ops::Mul<DoseEquivalent<num_bigfloat::BigFloat>> for &num_bigfloat::BigFloat {
-	type Output = DoseEquivalent<num_bigfloat::BigFloat>;
-	fn mul(self, rhs: DoseEquivalent<num_bigfloat::BigFloat>) -> Self::Output {
-		DoseEquivalent{Sv: self.clone() * rhs.Sv}
+/// Dividing a AbsorbedDose by a SpecificHeatCapacity returns a value of type TemperatureInterval
+impl<T> core::ops::Div<&SpecificHeatCapacity<T>> for &AbsorbedDose<T> where T: NumLike {
+	type Output = TemperatureInterval<T>;
+	fn div(self, rhs: &SpecificHeatCapacity<T>) -> Self::Output {
+		TemperatureInterval{K: self.Gy.clone() / rhs.J_per_kgK.clone()}
 	}
 }
-/// Multiplying a unit value by a scalar value returns a unit value
-#[cfg(feature="num-bigfloat")]
-impl core::ops::Mul<&DoseEquivalent<num_bigfloat::BigFloat>> for num_bigfloat::BigFloat {
-	type Output = DoseEquivalent<num_bigfloat::BigFloat>;
-	fn mul(self, rhs: &DoseEquivalent<num_bigfloat::BigFloat>) -> Self::Output {
-		DoseEquivalent{Sv: self * rhs.Sv.clone()}
+
+// AbsorbedDose / AbsorbedDoseRate -> Time
+/// Dividing a AbsorbedDose by a AbsorbedDoseRate returns a value of type Time
+impl<T> core::ops::Div<AbsorbedDoseRate<T>> for AbsorbedDose<T> where T: NumLike {
+	type Output = Time<T>;
+	fn div(self, rhs: AbsorbedDoseRate<T>) -> Self::Output {
+		Time{s: self.Gy / rhs.Gy_per_s}
 	}
 }
-/// Multiplying a unit value by a scalar value returns a unit value
-#[cfg(feature="num-bigfloat")]
-impl core::ops::Mul<&DoseEquivalent<num_bigfloat::BigFloat>> for &num_bigfloat::BigFloat {
-	type Output = DoseEquivalent<num_bigfloat::BigFloat>;
-	fn mul(self, rhs: &DoseEquivalent<num_bigfloat::BigFloat>) -> Self::Output {
-		DoseEquivalent{Sv: self.clone() * rhs.Sv.clone()}
+/// Dividing a AbsorbedDose by a AbsorbedDoseRate returns a value of type Time
+impl<T> core::ops::Div<AbsorbedDoseRate<T>> for &AbsorbedDose<T> where T: NumLike {
+	type Output = Time<T>;
+	fn div(self, rhs: AbsorbedDoseRate<T>) -> Self::Output {
+		Time{s: self.Gy.clone() / rhs.Gy_per_s}
 	}
 }
-
-/// Multiplying a unit value by a scalar value returns a unit value
-#[cfg(feature="num-complex")]
-impl core::ops::Mul<DoseEquivalent<num_complex::Complex32>> for num_complex::Complex32 {
-	type Output = DoseEquivalent<num_complex::Complex32>;
-	fn mul(self, rhs: DoseEquivalent<num_complex::Complex32>) -> Self::Output {
-		DoseEquivalent{Sv: self * rhs.Sv}
+/// Dividing a AbsorbedDose by a AbsorbedDoseRate returns a value of type Time
+impl<T> core::ops::Div<&AbsorbedDoseRate<T>> for AbsorbedDose<T> where T: NumLike {
+	type Output = Time<T>;
+	fn div(self, rhs: &AbsorbedDoseRate<T>) -> Self::Output {
+		Time{s: self.Gy / rhs.Gy_per_s.clone()}
 	}
 }
-/// Multiplying a unit value by a scalar value returns a unit value
+/// Dividing a AbsorbedDose by a AbsorbedDoseRate returns a value of type Time
+impl<T> core::ops::Div<&AbsorbedDoseRate<T>> for &AbsorbedDose<T> where T: NumLike {
+	type Output = Time<T>;
+	fn div(self, rhs: &AbsorbedDoseRate<T>) -> Self::Output {
+		Time{s: self.Gy.clone() / rhs.Gy_per_s.clone()}
+	}
+}
+
+impl<T> AbsorbedDose<T> where T: NumLike+From<u8> {
+	/// Returns the reciprocal of this AbsorbedDose value (ie 1 divided by 
+	/// this value) as a value of type InverseAbsorbedDose
+	pub fn recip(self) -> InverseAbsorbedDose<T> {
+		InverseAbsorbedDose{per_Gy: T::from(1u8) / self.Gy}
+	}
+}
+
+// 1/AbsorbedDose -> InverseAbsorbedDose
+/// Dividing a scalar value by a AbsorbedDose unit value returns a value of type InverseAbsorbedDose
+impl<T> core::ops::Div<AbsorbedDose<T>> for f64 where T: NumLike+From<f64> {
+	type Output = InverseAbsorbedDose<T>;
+	fn div(self, rhs: AbsorbedDose<T>) -> Self::Output {
+		InverseAbsorbedDose{per_Gy: T::from(self) / rhs.Gy}
+	}
+}
+/// Dividing a scalar value by a AbsorbedDose unit value returns a value of type InverseAbsorbedDose
+impl<T> core::ops::Div<AbsorbedDose<T>> for &f64 where T: NumLike+From<f64> {
+	type Output = InverseAbsorbedDose<T>;
+	fn div(self, rhs: AbsorbedDose<T>) -> Self::Output {
+		InverseAbsorbedDose{per_Gy: T::from(self.clone()) / rhs.Gy}
+	}
+}
+/// Dividing a scalar value by a AbsorbedDose unit value returns a value of type InverseAbsorbedDose
+impl<T> core::ops::Div<&AbsorbedDose<T>> for f64 where T: NumLike+From<f64> {
+	type Output = InverseAbsorbedDose<T>;
+	fn div(self, rhs: &AbsorbedDose<T>) -> Self::Output {
+		InverseAbsorbedDose{per_Gy: T::from(self) / rhs.Gy.clone()}
+	}
+}
+/// Dividing a scalar value by a AbsorbedDose unit value returns a value of type InverseAbsorbedDose
+impl<T> core::ops::Div<&AbsorbedDose<T>> for &f64 where T: NumLike+From<f64> {
+	type Output = InverseAbsorbedDose<T>;
+	fn div(self, rhs: &AbsorbedDose<T>) -> Self::Output {
+		InverseAbsorbedDose{per_Gy: T::from(self.clone()) / rhs.Gy.clone()}
+	}
+}
+
+// 1/AbsorbedDose -> InverseAbsorbedDose
+/// Dividing a scalar value by a AbsorbedDose unit value returns a value of type InverseAbsorbedDose
+impl<T> core::ops::Div<AbsorbedDose<T>> for f32 where T: NumLike+From<f32> {
+	type Output = InverseAbsorbedDose<T>;
+	fn div(self, rhs: AbsorbedDose<T>) -> Self::Output {
+		InverseAbsorbedDose{per_Gy: T::from(self) / rhs.Gy}
+	}
+}
+/// Dividing a scalar value by a AbsorbedDose unit value returns a value of type InverseAbsorbedDose
+impl<T> core::ops::Div<AbsorbedDose<T>> for &f32 where T: NumLike+From<f32> {
+	type Output = InverseAbsorbedDose<T>;
+	fn div(self, rhs: AbsorbedDose<T>) -> Self::Output {
+		InverseAbsorbedDose{per_Gy: T::from(self.clone()) / rhs.Gy}
+	}
+}
+/// Dividing a scalar value by a AbsorbedDose unit value returns a value of type InverseAbsorbedDose
+impl<T> core::ops::Div<&AbsorbedDose<T>> for f32 where T: NumLike+From<f32> {
+	type Output = InverseAbsorbedDose<T>;
+	fn div(self, rhs: &AbsorbedDose<T>) -> Self::Output {
+		InverseAbsorbedDose{per_Gy: T::from(self) / rhs.Gy.clone()}
+	}
+}
+/// Dividing a scalar value by a AbsorbedDose unit value returns a value of type InverseAbsorbedDose
+impl<T> core::ops::Div<&AbsorbedDose<T>> for &f32 where T: NumLike+From<f32> {
+	type Output = InverseAbsorbedDose<T>;
+	fn div(self, rhs: &AbsorbedDose<T>) -> Self::Output {
+		InverseAbsorbedDose{per_Gy: T::from(self.clone()) / rhs.Gy.clone()}
+	}
+}
+
+// 1/AbsorbedDose -> InverseAbsorbedDose
+/// Dividing a scalar value by a AbsorbedDose unit value returns a value of type InverseAbsorbedDose
+impl<T> core::ops::Div<AbsorbedDose<T>> for i64 where T: NumLike+From<i64> {
+	type Output = InverseAbsorbedDose<T>;
+	fn div(self, rhs: AbsorbedDose<T>) -> Self::Output {
+		InverseAbsorbedDose{per_Gy: T::from(self) / rhs.Gy}
+	}
+}
+/// Dividing a scalar value by a AbsorbedDose unit value returns a value of type InverseAbsorbedDose
+impl<T> core::ops::Div<AbsorbedDose<T>> for &i64 where T: NumLike+From<i64> {
+	type Output = InverseAbsorbedDose<T>;
+	fn div(self, rhs: AbsorbedDose<T>) -> Self::Output {
+		InverseAbsorbedDose{per_Gy: T::from(self.clone()) / rhs.Gy}
+	}
+}
+/// Dividing a scalar value by a AbsorbedDose unit value returns a value of type InverseAbsorbedDose
+impl<T> core::ops::Div<&AbsorbedDose<T>> for i64 where T: NumLike+From<i64> {
+	type Output = InverseAbsorbedDose<T>;
+	fn div(self, rhs: &AbsorbedDose<T>) -> Self::Output {
+		InverseAbsorbedDose{per_Gy: T::from(self) / rhs.Gy.clone()}
+	}
+}
+/// Dividing a scalar value by a AbsorbedDose unit value returns a value of type InverseAbsorbedDose
+impl<T> core::ops::Div<&AbsorbedDose<T>> for &i64 where T: NumLike+From<i64> {
+	type Output = InverseAbsorbedDose<T>;
+	fn div(self, rhs: &AbsorbedDose<T>) -> Self::Output {
+		InverseAbsorbedDose{per_Gy: T::from(self.clone()) / rhs.Gy.clone()}
+	}
+}
+
+// 1/AbsorbedDose -> InverseAbsorbedDose
+/// Dividing a scalar value by a AbsorbedDose unit value returns a value of type InverseAbsorbedDose
+impl<T> core::ops::Div<AbsorbedDose<T>> for i32 where T: NumLike+From<i32> {
+	type Output = InverseAbsorbedDose<T>;
+	fn div(self, rhs: AbsorbedDose<T>) -> Self::Output {
+		InverseAbsorbedDose{per_Gy: T::from(self) / rhs.Gy}
+	}
+}
+/// Dividing a scalar value by a AbsorbedDose unit value returns a value of type InverseAbsorbedDose
+impl<T> core::ops::Div<AbsorbedDose<T>> for &i32 where T: NumLike+From<i32> {
+	type Output = InverseAbsorbedDose<T>;
+	fn div(self, rhs: AbsorbedDose<T>) -> Self::Output {
+		InverseAbsorbedDose{per_Gy: T::from(self.clone()) / rhs.Gy}
+	}
+}
+/// Dividing a scalar value by a AbsorbedDose unit value returns a value of type InverseAbsorbedDose
+impl<T> core::ops::Div<&AbsorbedDose<T>> for i32 where T: NumLike+From<i32> {
+	type Output = InverseAbsorbedDose<T>;
+	fn div(self, rhs: &AbsorbedDose<T>) -> Self::Output {
+		InverseAbsorbedDose{per_Gy: T::from(self) / rhs.Gy.clone()}
+	}
+}
+/// Dividing a scalar value by a AbsorbedDose unit value returns a value of type InverseAbsorbedDose
+impl<T> core::ops::Div<&AbsorbedDose<T>> for &i32 where T: NumLike+From<i32> {
+	type Output = InverseAbsorbedDose<T>;
+	fn div(self, rhs: &AbsorbedDose<T>) -> Self::Output {
+		InverseAbsorbedDose{per_Gy: T::from(self.clone()) / rhs.Gy.clone()}
+	}
+}
+
+// 1/AbsorbedDose -> InverseAbsorbedDose
+/// Dividing a scalar value by a AbsorbedDose unit value returns a value of type InverseAbsorbedDose
+#[cfg(feature="num-bigfloat")]
+impl<T> core::ops::Div<AbsorbedDose<T>> for num_bigfloat::BigFloat where T: NumLike+From<num_bigfloat::BigFloat> {
+	type Output = InverseAbsorbedDose<T>;
+	fn div(self, rhs: AbsorbedDose<T>) -> Self::Output {
+		InverseAbsorbedDose{per_Gy: T::from(self) / rhs.Gy}
+	}
+}
+/// Dividing a scalar value by a AbsorbedDose unit value returns a value of type InverseAbsorbedDose
+#[cfg(feature="num-bigfloat")]
+impl<T> core::ops::Div<AbsorbedDose<T>> for &num_bigfloat::BigFloat where T: NumLike+From<num_bigfloat::BigFloat> {
+	type Output = InverseAbsorbedDose<T>;
+	fn div(self, rhs: AbsorbedDose<T>) -> Self::Output {
+		InverseAbsorbedDose{per_Gy: T::from(self.clone()) / rhs.Gy}
+	}
+}
+/// Dividing a scalar value by a AbsorbedDose unit value returns a value of type InverseAbsorbedDose
+#[cfg(feature="num-bigfloat")]
+impl<T> core::ops::Div<&AbsorbedDose<T>> for num_bigfloat::BigFloat where T: NumLike+From<num_bigfloat::BigFloat> {
+	type Output = InverseAbsorbedDose<T>;
+	fn div(self, rhs: &AbsorbedDose<T>) -> Self::Output {
+		InverseAbsorbedDose{per_Gy: T::from(self) / rhs.Gy.clone()}
+	}
+}
+/// Dividing a scalar value by a AbsorbedDose unit value returns a value of type InverseAbsorbedDose
+#[cfg(feature="num-bigfloat")]
+impl<T> core::ops::Div<&AbsorbedDose<T>> for &num_bigfloat::BigFloat where T: NumLike+From<num_bigfloat::BigFloat> {
+	type Output = InverseAbsorbedDose<T>;
+	fn div(self, rhs: &AbsorbedDose<T>) -> Self::Output {
+		InverseAbsorbedDose{per_Gy: T::from(self.clone()) / rhs.Gy.clone()}
+	}
+}
+
+// 1/AbsorbedDose -> InverseAbsorbedDose
+/// Dividing a scalar value by a AbsorbedDose unit value returns a value of type InverseAbsorbedDose
 #[cfg(feature="num-complex")]
-impl core::ops::Mul<DoseEquivalent<num_complex::Complex32>> for &num_complex::Complex32 {
-	type Output = DoseEquivalent<num_complex::Complex32>;
-	fn mul(self, rhs: DoseEquivalent<num_complex::Complex32>) -> Self::Output {
-		DoseEquivalent{Sv: self.clone() * rhs.Sv}
+impl<T> core::ops::Div<AbsorbedDose<T>> for num_complex::Complex32 where T: NumLike+From<num_complex::Complex32> {
+	type Output = InverseAbsorbedDose<T>;
+	fn div(self, rhs: AbsorbedDose<T>) -> Self::Output {
+		InverseAbsorbedDose{per_Gy: T::from(self) / rhs.Gy}
 	}
 }
-/// Multiplying a unit value by a scalar value returns a unit value
+/// Dividing a scalar value by a AbsorbedDose unit value returns a value of type InverseAbsorbedDose
 #[cfg(feature="num-complex")]
-impl core::ops::Mul<&DoseEquivalent<num_complex::Complex32>> for num_complex::Complex32 {
-	type Output = DoseEquivalent<num_complex::Complex32>;
-	fn mul(self, rhs: &DoseEquivalent<num_complex::Complex32>) -> Self::Output {
-		DoseEquivalent{Sv: self * rhs.Sv.clone()}
+impl<T> core::ops::Div<AbsorbedDose<T>> for &num_complex::Complex32 where T: NumLike+From<num_complex::Complex32> {
+	type Output = InverseAbsorbedDose<T>;
+	fn div(self, rhs: AbsorbedDose<T>) -> Self::Output {
+		InverseAbsorbedDose{per_Gy: T::from(self.clone()) / rhs.Gy}
 	}
 }
-/// Multiplying a unit value by a scalar value returns a unit value
+/// Dividing a scalar value by a AbsorbedDose unit value returns a value of type InverseAbsorbedDose
 #[cfg(feature="num-complex")]
-impl core::ops::Mul<&DoseEquivalent<num_complex::Complex32>> for &num_complex::Complex32 {
-	type Output = DoseEquivalent<num_complex::Complex32>;
-	fn mul(self, rhs: &DoseEquivalent<num_complex::Complex32>) -> Self::Output {
-		DoseEquivalent{Sv: self.clone() * rhs.Sv.clone()}
+impl<T> core::ops::Div<&AbsorbedDose<T>> for num_complex::Complex32 where T: NumLike+From<num_complex::Complex32> {
+	type Output = InverseAbsorbedDose<T>;
+	fn div(self, rhs: &AbsorbedDose<T>) -> Self::Output {
+		InverseAbsorbedDose{per_Gy: T::from(self) / rhs.Gy.clone()}
+	}
+}
+/// Dividing a scalar value by a AbsorbedDose unit value returns a value of type InverseAbsorbedDose
+#[cfg(feature="num-complex")]
+impl<T> core::ops::Div<&AbsorbedDose<T>> for &num_complex::Complex32 where T: NumLike+From<num_complex::Complex32> {
+	type Output = InverseAbsorbedDose<T>;
+	fn div(self, rhs: &AbsorbedDose<T>) -> Self::Output {
+		InverseAbsorbedDose{per_Gy: T::from(self.clone()) / rhs.Gy.clone()}
 	}
 }
 
-/// Multiplying a unit value by a scalar value returns a unit value
+// 1/AbsorbedDose -> InverseAbsorbedDose
+/// Dividing a scalar value by a AbsorbedDose unit value returns a value of type InverseAbsorbedDose
 #[cfg(feature="num-complex")]
-impl core::ops::Mul<DoseEquivalent<num_complex::Complex64>> for num_complex::Complex64 {
-	type Output = DoseEquivalent<num_complex::Complex64>;
-	fn mul(self, rhs: DoseEquivalent<num_complex::Complex64>) -> Self::Output {
-		DoseEquivalent{Sv: self * rhs.Sv}
+impl<T> core::ops::Div<AbsorbedDose<T>> for num_complex::Complex64 where T: NumLike+From<num_complex::Complex64> {
+	type Output = InverseAbsorbedDose<T>;
+	fn div(self, rhs: AbsorbedDose<T>) -> Self::Output {
+		InverseAbsorbedDose{per_Gy: T::from(self) / rhs.Gy}
 	}
 }
-/// Multiplying a unit value by a scalar value returns a unit value
+/// Dividing a scalar value by a AbsorbedDose unit value returns a value of type InverseAbsorbedDose
 #[cfg(feature="num-complex")]
-impl core::ops::Mul<DoseEquivalent<num_complex::Complex64>> for &num_complex::Complex64 {
-	type Output = DoseEquivalent<num_complex::Complex64>;
-	fn mul(self, rhs: DoseEquivalent<num_complex::Complex64>) -> Self::Output {
-		DoseEquivalent{Sv: self.clone() * rhs.Sv}
+impl<T> core::ops::Div<AbsorbedDose<T>> for &num_complex::Complex64 where T: NumLike+From<num_complex::Complex64> {
+	type Output = InverseAbsorbedDose<T>;
+	fn div(self, rhs: AbsorbedDose<T>) -> Self::Output {
+		InverseAbsorbedDose{per_Gy: T::from(self.clone()) / rhs.Gy}
 	}
 }
-/// Multiplying a unit value by a scalar value returns a unit value
+/// Dividing a scalar value by a AbsorbedDose unit value returns a value of type InverseAbsorbedDose
 #[cfg(feature="num-complex")]
-impl core::ops::Mul<&DoseEquivalent<num_complex::Complex64>> for num_complex::Complex64 {
-	type Output = DoseEquivalent<num_complex::Complex64>;
-	fn mul(self, rhs: &DoseEquivalent<num_complex::Complex64>) -> Self::Output {
-		DoseEquivalent{Sv: self * rhs.Sv.clone()}
+impl<T> core::ops::Div<&AbsorbedDose<T>> for num_complex::Complex64 where T: NumLike+From<num_complex::Complex64> {
+	type Output = InverseAbsorbedDose<T>;
+	fn div(self, rhs: &AbsorbedDose<T>) -> Self::Output {
+		InverseAbsorbedDose{per_Gy: T::from(self) / rhs.Gy.clone()}
 	}
 }
-/// Multiplying a unit value by a scalar value returns a unit value
+/// Dividing a scalar value by a AbsorbedDose unit value returns a value of type InverseAbsorbedDose
 #[cfg(feature="num-complex")]
-impl core::ops::Mul<&DoseEquivalent<num_complex::Complex64>> for &num_complex::Complex64 {
-	type Output = DoseEquivalent<num_complex::Complex64>;
-	fn mul(self, rhs: &DoseEquivalent<num_complex::Complex64>) -> Self::Output {
-		DoseEquivalent{Sv: self.clone() * rhs.Sv.clone()}
+impl<T> core::ops::Div<&AbsorbedDose<T>> for &num_complex::Complex64 where T: NumLike+From<num_complex::Complex64> {
+	type Output = InverseAbsorbedDose<T>;
+	fn div(self, rhs: &AbsorbedDose<T>) -> Self::Output {
+		InverseAbsorbedDose{per_Gy: T::from(self.clone()) / rhs.Gy.clone()}
 	}
 }
 
+// 1/AbsorbedDose -> InverseAbsorbedDose
+/// Dividing a scalar value by a AbsorbedDose unit value returns a value of type InverseAbsorbedDose
+#[cfg(feature="num-rational")]
+impl<T> core::ops::Div<AbsorbedDose<T>> for num_rational::Rational64 where T: NumLike+From<num_rational::Rational64> {
+	type Output = InverseAbsorbedDose<T>;
+	fn div(self, rhs: AbsorbedDose<T>) -> Self::Output {
+		InverseAbsorbedDose{per_Gy: T::from(self) / rhs.Gy}
+	}
+}
+/// Dividing a scalar value by a AbsorbedDose unit value returns a value of type InverseAbsorbedDose
+#[cfg(feature="num-rational")]
+impl<T> core::ops::Div<AbsorbedDose<T>> for &num_rational::Rational64 where T: NumLike+From<num_rational::Rational64> {
+	type Output = InverseAbsorbedDose<T>;
+	fn div(self, rhs: AbsorbedDose<T>) -> Self::Output {
+		InverseAbsorbedDose{per_Gy: T::from(self.clone()) / rhs.Gy}
+	}
+}
+/// Dividing a scalar value by a AbsorbedDose unit value returns a value of type InverseAbsorbedDose
+#[cfg(feature="num-rational")]
+impl<T> core::ops::Div<&AbsorbedDose<T>> for num_rational::Rational64 where T: NumLike+From<num_rational::Rational64> {
+	type Output = InverseAbsorbedDose<T>;
+	fn div(self, rhs: &AbsorbedDose<T>) -> Self::Output {
+		InverseAbsorbedDose{per_Gy: T::from(self) / rhs.Gy.clone()}
+	}
+}
+/// Dividing a scalar value by a AbsorbedDose unit value returns a value of type InverseAbsorbedDose
+#[cfg(feature="num-rational")]
+impl<T> core::ops::Div<&AbsorbedDose<T>> for &num_rational::Rational64 where T: NumLike+From<num_rational::Rational64> {
+	type Output = InverseAbsorbedDose<T>;
+	fn div(self, rhs: &AbsorbedDose<T>) -> Self::Output {
+		InverseAbsorbedDose{per_Gy: T::from(self.clone()) / rhs.Gy.clone()}
+	}
+}
 
-
-
-// DoseEquivalent * Mass -> Energy
-/// Multiplying a DoseEquivalent by a Mass returns a value of type Energy
-impl<T> core::ops::Mul<Mass<T>> for DoseEquivalent<T> where T: NumLike {
-	type Output = Energy<T>;
-	fn mul(self, rhs: Mass<T>) -> Self::Output {
-		Energy{J: self.Sv * rhs.kg}
+// 1/AbsorbedDose -> InverseAbsorbedDose
+/// Dividing a scalar value by a AbsorbedDose unit value returns a value of type InverseAbsorbedDose
+#[cfg(feature="num-rational")]
+impl<T> core::ops::Div<AbsorbedDose<T>> for num_rational::BigRational where T: NumLike+From<num_rational::BigRational> {
+	type Output = InverseAbsorbedDose<T>;
+	fn div(self, rhs: AbsorbedDose<T>) -> Self::Output {
+		InverseAbsorbedDose{per_Gy: T::from(self) / rhs.Gy}
 	}
 }
-/// Multiplying a DoseEquivalent by a Mass returns a value of type Energy
-impl<T> core::ops::Mul<Mass<T>> for &DoseEquivalent<T> where T: NumLike {
-	type Output = Energy<T>;
-	fn mul(self, rhs: Mass<T>) -> Self::Output {
-		Energy{J: self.Sv.clone() * rhs.kg}
+/// Dividing a scalar value by a AbsorbedDose unit value returns a value of type InverseAbsorbedDose
+#[cfg(feature="num-rational")]
+impl<T> core::ops::Div<AbsorbedDose<T>> for &num_rational::BigRational where T: NumLike+From<num_rational::BigRational> {
+	type Output = InverseAbsorbedDose<T>;
+	fn div(self, rhs: AbsorbedDose<T>) -> Self::Output {
+		InverseAbsorbedDose{per_Gy: T::from(self.clone()) / rhs.Gy}
 	}
 }
-/// Multiplying a DoseEquivalent by a Mass returns a value of type Energy
-impl<T> core::ops::Mul<&Mass<T>> for DoseEquivalent<T> where T: NumLike {
-	type Output = Energy<T>;
-	fn mul(self, rhs: &Mass<T>) -> Self::Output {
-		Energy{J: self.Sv * rhs.kg.clone()}
+/// Dividing a scalar value by a AbsorbedDose unit value returns a value of type InverseAbsorbedDose
+#[cfg(feature="num-rational")]
+impl<T> core::ops::Div<&AbsorbedDose<T>> for num_rational::BigRational where T: NumLike+From<num_rational::BigRational> {
+	type Output = InverseAbsorbedDose<T>;
+	fn div(self, rhs: &AbsorbedDose<T>) -> Self::Output {
+		InverseAbsorbedDose{per_Gy: T::from(self) / rhs.Gy.clone()}
 	}
 }
-/// Multiplying a DoseEquivalent by a Mass returns a value of type Energy
-impl<T> core::ops::Mul<&Mass<T>> for &DoseEquivalent<T> where T: NumLike {
-	type Output = Energy<T>;
-	fn mul(self, rhs: &Mass<T>) -> Self::Output {
-		Energy{J: self.Sv.clone() * rhs.kg.clone()}
+/// Dividing a scalar value by a AbsorbedDose unit value returns a value of type InverseAbsorbedDose
+#[cfg(feature="num-rational")]
+impl<T> core::ops::Div<&AbsorbedDose<T>> for &num_rational::BigRational where T: NumLike+From<num_rational::BigRational> {
+	type Output = InverseAbsorbedDose<T>;
+	fn div(self, rhs: &AbsorbedDose<T>) -> Self::Output {
+		InverseAbsorbedDose{per_Gy: T::from(self.clone()) / rhs.Gy.clone()}
 	}
 }
 
-/// The inverse of absorbed radiation dose unit type, defined as inverse grays in SI units
-#[derive(UnitStruct, Debug, Clone)]
-#[cfg_attr(feature="serde", derive(Serialize, Deserialize))]
-pub struct InverseAbsorbedDose<T: NumLike>{
-	/// The value of this Inverse absorbed dose in inverse grays
-	pub per_Gy: T
+/// The absorbed radiation dose rate unit type, defined as grays per second in SI units
+#[derive(UnitStruct, Debug, Clone, Default)]
+#[si(no_display)]
+#[cfg_attr(all(feature="serde", not(feature="serde-unit-tag")), derive(Serialize, Deserialize))]
+#[repr(transparent)]
+pub struct AbsorbedDoseRate<T: NumLike>{
+	/// The value of this Absorbed dose rate in grays per second
+	pub Gy_per_s: T
 }
 
-impl<T> InverseAbsorbedDose<T> where T: NumLike {
+impl<T> AbsorbedDoseRate<T> where T: NumLike {
 
-	/// Returns the standard unit name of inverse absorbed dose: "inverse grays"
-	pub fn unit_name() -> &'static str { "inverse grays" }
-	
-	/// Returns the abbreviated name or symbol of inverse absorbed dose: "1/Gy" for inverse grays
-	pub fn unit_symbol() -> &'static str { "1/Gy" }
+	/// Returns the standard unit name of absorbed dose rate: "grays per second"
+	pub fn unit_name() -> &'static str { "grays per second" }
 	
-	/// Returns a new inverse absorbed dose value from the given number of inverse grays
+	/// Returns the abbreviated name or symbol of absorbed dose rate: "Gy/s" for grays per second
+	pub fn unit_symbol() -> &'static str { "Gy/s" }
+
+	/// Returns the exponents of the SI base units of absorbed dose rate (m^2/s^3)
+	pub const fn dimension() -> super::Dimension { super::Dimension{length: 2, mass: 0, time: -3, current: 0, temperature: 0, amount: 0, luminosity: 0} }
+
+	/// Returns a wrapper that formats this absorbed dose rate value with the full unit name instead of 
+	/// the unit symbol, eg "5 grays per second" (the unit name is not singularized for values of 1)
+	pub fn display_named(&self) -> super::NamedDisplay<'_, T> {
+		super::NamedDisplay::new(&self.Gy_per_s, Self::unit_name())
+	}
+
+	/// Returns a new absorbed dose rate value from the given number of grays per second (same as 
+	/// `from_Gy_per_s(...)`), for any number-like type. This is useful in generic code 
+	/// that works with several unit types
 	///
 	/// # Arguments
-	/// * `per_Gy` - Any number-like type, representing a quantity of inverse grays
-	pub fn from_per_Gy(per_Gy: T) -> Self { InverseAbsorbedDose{per_Gy: per_Gy} }
-	
-	/// Returns a copy of this inverse absorbed dose value in inverse grays
-	pub fn to_per_Gy(&self) -> T { self.per_Gy.clone() }
+	/// * `value` - Any number-like type, representing a quantity of grays per second
+	pub const fn new(value: T) -> Self { AbsorbedDoseRate{Gy_per_s: value} }
 
-	/// Returns a new inverse absorbed dose value from the given number of inverse grays
+	/// Returns a new absorbed dose rate value from the given number of grays per second. Unlike
+	/// `from_Gy_per_s(...)`, this is a `const fn`, so it can be used to declare `const` and `static` values
 	///
 	/// # Arguments
-	/// * `per_grays` - Any number-like type, representing a quantity of inverse grays
-	pub fn from_per_grays(per_grays: T) -> Self { InverseAbsorbedDose{per_Gy: per_grays} }
-	
-	/// Returns a copy of this inverse absorbed dose value in inverse grays
-	pub fn to_per_grays(&self) -> T { self.per_Gy.clone() }
+	/// * `Gy_per_s` - Any number-like type, representing a quantity of grays per second
+	pub const fn from_Gy_per_s_const(Gy_per_s: T) -> Self { AbsorbedDoseRate{Gy_per_s} }
 
-}
+	/// Returns a absorbed dose rate value with a different number type, by applying the 
+	/// given function to the value in grays per second (eg `.map(|x| x as f32)` to convert 
+	/// from `f64` to `f32`)
+	///
+	/// # Arguments
+	/// * `f` - Function that converts the number of grays per second to the new number type
+	pub fn map<U, F>(self, f: F) -> AbsorbedDoseRate<U> where U: NumLike, F: FnOnce(T) -> U {
+		AbsorbedDoseRate{Gy_per_s: f(self.Gy_per_s)}
+	}
 
-impl<T> fmt::Display for InverseAbsorbedDose<T> where T: NumLike {
-	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-		write!(f, "{} {}", &self.per_Gy, Self::unit_symbol())
+	/// Returns a absorbed dose rate value with a different number type, converted 
+	/// losslessly with the `From` trait (eg from `f32` to `f64`)
+	pub fn cast<U>(self) -> AbsorbedDoseRate<U> where U: NumLike+From<T> {
+		AbsorbedDoseRate{Gy_per_s: U::from(self.Gy_per_s)}
 	}
-}
 
-impl<T> InverseAbsorbedDose<T> where T: NumLike+From<f64> {
-	
-	/// Returns a copy of this inverse absorbed dose value in inverse milligrays
-	/// 
-	/// *Note: This method is not available for `f32` and other number types lacking the `From<f64>` trait*
-	pub fn to_per_mGy(&self) -> T {
-		return self.per_Gy.clone() * T::from(0.001_f64);
+	/// Returns a reference to the value of this absorbed dose rate in grays per second
+	pub fn as_raw(&self) -> &T {
+		&self.Gy_per_s
 	}
 
-	/// Returns a new inverse absorbed dose value from the given number of inverse milligrays
-	/// 
-	/// *Note: This method is not available for `f32` and other number types lacking the `From<f64>` trait*
-	///
-	/// # Arguments
-	/// * `per_mGy` - Any number-like type, representing a quantity of inverse milligrays
-	pub fn from_per_mGy(per_mGy: T) -> Self {
-		InverseAbsorbedDose{per_Gy: per_mGy * T::from(1000.0_f64)}
+	/// Returns a mutable reference to the value of this absorbed dose rate in grays per second, 
+	/// eg for passing to numeric libraries that modify values in place. The number 
+	/// is always in grays per second, so changing it directly does not change the unit
+	pub fn raw_mut(&mut self) -> &mut T {
+		&mut self.Gy_per_s
 	}
 
-	/// Returns a copy of this inverse absorbed dose value in inverse micrograys
-	/// 
-	/// *Note: This method is not available for `f32` and other number types lacking the `From<f64>` trait*
-	pub fn to_per_uGy(&self) -> T {
-		return self.per_Gy.clone() * T::from(1e-06_f64);
+	/// Returns a slice of numbers in grays per second as a slice of absorbed dose rate values, 
+	/// without copying
+	///
+	/// # Arguments
+	/// * `values` - Slice of numbers, representing quantities of grays per second
+	pub fn wrap_slice(values: &[T]) -> &[Self] {
+		// SAFETY: AbsorbedDoseRate<T> is #[repr(transparent)], so it has the same memory layout as T
+		unsafe { core::slice::from_raw_parts(values.as_ptr() as *const Self, values.len()) }
 	}
 
-	/// Returns a new inverse absorbed dose value from the given number of inverse micrograys
-	/// 
-	/// *Note: This method is not available for `f32` and other number types lacking the `From<f64>` trait*
+	/// Returns a mutable slice of numbers in grays per second as a mutable slice of 
+	/// absorbed dose rate values, without copying
 	///
 	/// # Arguments
-	/// * `per_uGy` - Any number-like type, representing a quantity of inverse micrograys
-	pub fn from_per_uGy(per_uGy: T) -> Self {
-		InverseAbsorbedDose{per_Gy: per_uGy * T::from(1000000.0_f64)}
+	/// * `values` - Slice of numbers, representing quantities of grays per second
+	pub fn wrap_slice_mut(values: &mut [T]) -> &mut [Self] {
+		// SAFETY: AbsorbedDoseRate<T> is #[repr(transparent)], so it has the same memory layout as T
+		unsafe { core::slice::from_raw_parts_mut(values.as_mut_ptr() as *mut Self, values.len()) }
 	}
 
-	/// Returns a copy of this inverse absorbed dose value in inverse nanograys
-	/// 
-	/// *Note: This method is not available for `f32` and other number types lacking the `From<f64>` trait*
-	pub fn to_per_nGy(&self) -> T {
-		return self.per_Gy.clone() * T::from(1e-09_f64);
+	/// Returns the ratio of this absorbed dose rate value to another absorbed dose rate value 
+	/// (same as `self / other`). Dividing a unit value by another value of the same 
+	/// unit type cancels the units, so the ratio is a dimensionless number
+	///
+	/// # Arguments
+	/// * `other` - The absorbed dose rate value to divide by
+	pub fn ratio(self, other: Self) -> T {
+		self.Gy_per_s / other.Gy_per_s
 	}
 
-	/// Returns a new inverse absorbed dose value from the given number of inverse nanograys
-	/// 
-	/// *Note: This method is not available for `f32` and other number types lacking the `From<f64>` trait*
+	/// Returns the ratio of this absorbed dose rate value to another absorbed dose rate value 
+	/// as a `Dimensionless` value, instead of a bare number like `ratio(...)` does
 	///
 	/// # Arguments
-	/// * `per_nGy` - Any number-like type, representing a quantity of inverse nanograys
-	pub fn from_per_nGy(per_nGy: T) -> Self {
-		InverseAbsorbedDose{per_Gy: per_nGy * T::from(1000000000.0_f64)}
+	/// * `other` - The absorbed dose rate value to divide by
+	pub fn dimensionless_ratio(self, other: Self) -> super::base::Dimensionless<T> {
+		super::base::Dimensionless{value: self.Gy_per_s / other.Gy_per_s}
 	}
 
-	/// Returns a copy of this inverse absorbed dose value in inverse kilograys
-	/// 
-	/// *Note: This method is not available for `f32` and other number types lacking the `From<f64>` trait*
-	pub fn to_per_kGy(&self) -> T {
-		return self.per_Gy.clone() * T::from(1000.0_f64);
+	/// Returns this absorbed dose rate value multiplied by a dimensionless factor 
+	/// (same as `self * factor`)
+	///
+	/// # Arguments
+	/// * `factor` - The number to multiply by
+	pub fn scale(self, factor: T) -> Self {
+		AbsorbedDoseRate{Gy_per_s: self.Gy_per_s * factor}
 	}
+	
+	/// Returns a new absorbed dose rate value from the given number of grays per second
+	///
+	/// # Arguments
+	/// * `Gy_per_s` - Any number-like type, representing a quantity of grays per second
+	pub fn from_Gy_per_s(Gy_per_s: T) -> Self { AbsorbedDoseRate{Gy_per_s: Gy_per_s} }
+	
+	/// Returns a copy of this absorbed dose rate value in grays per second
+	pub fn to_Gy_per_s(&self) -> T { self.Gy_per_s.clone() }
 
-	/// Returns a new inverse absorbed dose value from the given number of inverse kilograys
-	/// 
-	/// *Note: This method is not available for `f32` and other number types lacking the `From<f64>` trait*
+	/// Returns a new absorbed dose rate value from the given number of grays per second
 	///
 	/// # Arguments
-	/// * `per_kGy` - Any number-like type, representing a quantity of inverse kilograys
-	pub fn from_per_kGy(per_kGy: T) -> Self {
-		InverseAbsorbedDose{per_Gy: per_kGy * T::from(0.001_f64)}
+	/// * `grays_per_second` - Any number-like type, representing a quantity of grays per second
+	pub fn from_grays_per_second(grays_per_second: T) -> Self { AbsorbedDoseRate{Gy_per_s: grays_per_second} }
+	
+	/// Returns a copy of this absorbed dose rate value in grays per second
+	pub fn to_grays_per_second(&self) -> T { self.Gy_per_s.clone() }
+
+}
+
+/// Formats absorbed dose rate values as a number followed by its unit symbol, eg "1500 Gy/s"
+/// (complex numbers are wrapped in parentheses, eg "(1+2i) Gy/s").
+/// The alternate flag (`{:#}`) scales the number by the nearest SI prefix where the unit symbol allows it
+impl<T> fmt::Display for AbsorbedDoseRate<T> where T: NumLike {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		if f.alternate() {
+			return super::fmt_with_si_prefix(f, &self.Gy_per_s, Self::unit_symbol(), false);
+		}
+		super::fmt_unit_value(f, &self.Gy_per_s, Self::unit_symbol())
 	}
+}
 
-	/// Returns a copy of this inverse absorbed dose value in inverse megagrays
-	/// 
-	/// *Note: This method is not available for `f32` and other number types lacking the `From<f64>` trait*
-	pub fn to_per_MGy(&self) -> T {
-		return self.per_Gy.clone() * T::from(1000000.0_f64);
+/// Formats absorbed dose rate values in exponential notation followed by the unit symbol, eg "1.5e3 Gy/s"
+impl<T> fmt::LowerExp for AbsorbedDoseRate<T> where T: NumLike+fmt::LowerExp {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		fmt::LowerExp::fmt(&self.Gy_per_s, f)?;
+		write!(f, " {}", Self::unit_symbol())
 	}
+}
 
-	/// Returns a new inverse absorbed dose value from the given number of inverse megagrays
-	/// 
-	/// *Note: This method is not available for `f32` and other number types lacking the `From<f64>` trait*
-	///
-	/// # Arguments
-	/// * `per_MGy` - Any number-like type, representing a quantity of inverse megagrays
-	pub fn from_per_MGy(per_MGy: T) -> Self {
-		InverseAbsorbedDose{per_Gy: per_MGy * T::from(1e-06_f64)}
+/// Formats absorbed dose rate values in exponential notation followed by the unit symbol, eg "1.5E3 Gy/s"
+impl<T> fmt::UpperExp for AbsorbedDoseRate<T> where T: NumLike+fmt::UpperExp {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		fmt::UpperExp::fmt(&self.Gy_per_s, f)?;
+		write!(f, " {}", Self::unit_symbol())
 	}
+}
 
-	/// Returns a copy of this inverse absorbed dose value in inverse gigagrays
-	/// 
-	/// *Note: This method is not available for `f32` and other number types lacking the `From<f64>` trait*
-	pub fn to_per_GGy(&self) -> T {
-		return self.per_Gy.clone() * T::from(1000000000.0_f64);
+impl<T> super::SiUnit for AbsorbedDoseRate<T> where T: NumLike {
+	type Number = T;
+	fn unit_name(&self) -> &'static str { Self::unit_name() }
+	fn unit_symbol(&self) -> &'static str { Self::unit_symbol() }
+	fn raw(&self) -> &T { &self.Gy_per_s }
+	fn dimension(&self) -> super::Dimension { Self::dimension() }
+	fn from_raw(value: T) -> Self { AbsorbedDoseRate{Gy_per_s: value} }
+	fn into_raw(self) -> T { self.Gy_per_s }
+}
+
+#[cfg(feature="alloc")]
+impl<T> AbsorbedDoseRate<T> where T: NumLike {
+	/// Returns this absorbed dose rate value in the canonical text format "<value> <symbol>", eg "5 Gy/s".
+	/// Unlike `Display`, this format never changes with precision or alternate flags, and it is the
+	/// input format expected by `from_str(...)`, so `x.canonical_string().parse()` returns the same value
+	pub fn canonical_string(&self) -> alloc::string::String {
+		alloc::format!("{} {}", self.Gy_per_s, Self::unit_symbol())
 	}
 
-	/// Returns a new inverse absorbed dose value from the given number of inverse gigagrays
-	/// 
-	/// *Note: This method is not available for `f32` and other number types lacking the `From<f64>` trait*
+	/// Returns a `Vec` of absorbed dose rate values copied from the given slice of numbers
 	///
 	/// # Arguments
-	/// * `per_GGy` - Any number-like type, representing a quantity of inverse gigagrays
-	pub fn from_per_GGy(per_GGy: T) -> Self {
-		InverseAbsorbedDose{per_Gy: per_GGy * T::from(1e-09_f64)}
+	/// * `values` - Slice of numbers, representing quantities of grays per second
+	pub fn from_Gy_per_s_slice(values: &[T]) -> alloc::vec::Vec<Self> {
+		values.iter().map(|x| AbsorbedDoseRate{Gy_per_s: x.clone()}).collect()
 	}
+}
 
-	/// Returns a copy of this inverse absorbed dose value in inverse rads
-	/// 
-	/// *Note: This method is not available for `f32` and other number types lacking the `From<f64>` trait*
-	pub fn to_per_rad(&self) -> T {
-		return self.per_Gy.clone() * T::from(0.01_f64);
+/// Parses a absorbed dose rate value from a number followed by its unit symbol, eg "5 Gy/s"
+impl<T> core::str::FromStr for AbsorbedDoseRate<T> where T: NumLike+core::str::FromStr {
+	type Err = SiParseError;
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		Ok(AbsorbedDoseRate{Gy_per_s: super::parse_unit_value(s, Self::unit_symbol())?})
 	}
+}
 
-	/// Returns a new inverse absorbed dose value from the given number of inverse rads
+/// Parses a absorbed dose rate value from a number followed by its unit symbol, eg "5 Gy/s" 
+/// (same as `str::parse()`)
+impl<T> core::convert::TryFrom<&str> for AbsorbedDoseRate<T> where T: NumLike+core::str::FromStr {
+	type Error = SiParseError;
+	fn try_from(s: &str) -> Result<Self, Self::Error> {
+		<Self as core::str::FromStr>::from_str(s)
+	}
+}
+
+#[cfg(feature="num-traits")]
+impl<T> AbsorbedDoseRate<T> where T: NumLike+num_traits::Euclid {
+
+	/// Returns the Euclidean remainder of dividing this absorbed dose rate value by another, 
+	/// which is never negative (requires the `num-traits` feature)
+	///
+	/// # Arguments
+	/// * `rhs` - The absorbed dose rate value to divide by
+	pub fn rem_euclid(self, rhs: Self) -> Self {
+		AbsorbedDoseRate{Gy_per_s: self.Gy_per_s.rem_euclid(&rhs.Gy_per_s)}
+	}
+
+	/// Returns the Euclidean quotient of dividing this absorbed dose rate value by another, 
+	/// ie the whole number of times that `rhs` fits into this value, rounded such that 
+	/// `rem_euclid(...)` is never negative (requires the `num-traits` feature)
+	///
+	/// # Arguments
+	/// * `rhs` - The absorbed dose rate value to divide by
+	pub fn div_euclid(self, rhs: Self) -> T {
+		self.Gy_per_s.div_euclid(&rhs.Gy_per_s)
+	}
+}
+
+/// Summing absorbed dose rate values returns their total as a absorbed dose rate value (or zero if there are no values)
+impl<T> core::iter::Sum<AbsorbedDoseRate<T>> for AbsorbedDoseRate<T> where T: NumLike+core::iter::Sum {
+	fn sum<I: Iterator<Item=AbsorbedDoseRate<T>>>(iter: I) -> Self {
+		AbsorbedDoseRate{Gy_per_s: iter.map(|x| x.Gy_per_s).sum()}
+	}
+}
+/// Summing absorbed dose rate values returns their total as a absorbed dose rate value (or zero if there are no values)
+impl<'a, T> core::iter::Sum<&'a AbsorbedDoseRate<T>> for AbsorbedDoseRate<T> where T: NumLike+core::iter::Sum+'a {
+	fn sum<I: Iterator<Item=&'a AbsorbedDoseRate<T>>>(iter: I) -> Self {
+		AbsorbedDoseRate{Gy_per_s: iter.map(|x| x.Gy_per_s.clone()).sum()}
+	}
+}
+
+impl<T> AbsorbedDoseRate<T> where T: NumLike+From<f64> {
+
+	/// Returns the weighted average of the given absorbed dose rate values, where each 
+	/// value is paired with its weight (weights do not need to add up to 1). If the 
+	/// total weight is zero (including when `values` is empty), then zero is returned
+	///
+	/// # Arguments
+	/// * `values` - Slice of (absorbed dose rate value, weight) pairs
+	pub fn weighted_mean(values: &[(Self, f64)]) -> Self {
+		let total_weight: f64 = values.iter().map(|(_, weight)| *weight).sum();
+		if total_weight == 0.0 {
+			return AbsorbedDoseRate{Gy_per_s: T::from(0.0)};
+		}
+		let total = values.iter().fold(T::from(0.0),
+			|total, (value, weight)| total + value.Gy_per_s.clone() * T::from(*weight));
+		AbsorbedDoseRate{Gy_per_s: total / T::from(total_weight)}
+	}
+}
+
+impl<T> AbsorbedDoseRate<T> where T: NumLike+PartialEq+From<u8> {
+
+	/// Divides this absorbed dose rate value by another unit value (eg `distance.checked_div(time)` 
+	/// returns a velocity), returning `None` if the divisor is zero instead of producing 
+	/// infinity or NaN (or panicking, for integer number types). This works for every unit 
+	/// type that this absorbed dose rate value can be divided by with the `/` operator
+	///
+	/// # Arguments
+	/// * `rhs` - The unit value to divide by
+	pub fn checked_div<R>(self, rhs: R) -> Option<<Self as core::ops::Div<R>>::Output> 
+		where Self: core::ops::Div<R>, R: super::SiUnit<Number=T>
+	{
+		if *rhs.raw() == T::from(0u8) {
+			return None;
+		}
+		Some(self / rhs)
+	}
+}
+
+/// Multiplying a absorbed dose rate value by a dimensionless value returns a absorbed dose rate value
+impl<T> core::ops::Mul<super::base::Dimensionless<T>> for AbsorbedDoseRate<T> where T: NumLike {
+	type Output = AbsorbedDoseRate<T>;
+	fn mul(self, rhs: super::base::Dimensionless<T>) -> Self::Output {
+		AbsorbedDoseRate{Gy_per_s: self.Gy_per_s * rhs.value}
+	}
+}
+/// Multiplying a dimensionless value by a absorbed dose rate value returns a absorbed dose rate value
+impl<T> core::ops::Mul<AbsorbedDoseRate<T>> for super::base::Dimensionless<T> where T: NumLike {
+	type Output = AbsorbedDoseRate<T>;
+	fn mul(self, rhs: AbsorbedDoseRate<T>) -> Self::Output {
+		AbsorbedDoseRate{Gy_per_s: self.value * rhs.Gy_per_s}
+	}
+}
+/// Dividing a absorbed dose rate value by a dimensionless value returns a absorbed dose rate value
+impl<T> core::ops::Div<super::base::Dimensionless<T>> for AbsorbedDoseRate<T> where T: NumLike {
+	type Output = AbsorbedDoseRate<T>;
+	fn div(self, rhs: super::base::Dimensionless<T>) -> Self::Output {
+		AbsorbedDoseRate{Gy_per_s: self.Gy_per_s / rhs.value}
+	}
+}
+
+/// The remainder of dividing a absorbed dose rate value by another absorbed dose rate value is a absorbed dose rate value
+impl<T> core::ops::Rem<AbsorbedDoseRate<T>> for AbsorbedDoseRate<T> where T: NumLike+core::ops::Rem<Output=T> {
+	type Output = AbsorbedDoseRate<T>;
+	fn rem(self, rhs: AbsorbedDoseRate<T>) -> Self::Output {
+		AbsorbedDoseRate{Gy_per_s: self.Gy_per_s % rhs.Gy_per_s}
+	}
+}
+/// The remainder of dividing a absorbed dose rate value by another absorbed dose rate value is a absorbed dose rate value
+impl<T> core::ops::Rem<&AbsorbedDoseRate<T>> for AbsorbedDoseRate<T> where T: NumLike+core::ops::Rem<Output=T> {
+	type Output = AbsorbedDoseRate<T>;
+	fn rem(self, rhs: &AbsorbedDoseRate<T>) -> Self::Output {
+		AbsorbedDoseRate{Gy_per_s: self.Gy_per_s % rhs.Gy_per_s.clone()}
+	}
+}
+/// The remainder of dividing a absorbed dose rate value by another absorbed dose rate value is a absorbed dose rate value
+impl<T> core::ops::Rem<AbsorbedDoseRate<T>> for &AbsorbedDoseRate<T> where T: NumLike+core::ops::Rem<Output=T> {
+	type Output = AbsorbedDoseRate<T>;
+	fn rem(self, rhs: AbsorbedDoseRate<T>) -> Self::Output {
+		AbsorbedDoseRate{Gy_per_s: self.Gy_per_s.clone() % rhs.Gy_per_s}
+	}
+}
+/// The remainder of dividing a absorbed dose rate value by another absorbed dose rate value is a absorbed dose rate value
+impl<T> core::ops::Rem<&AbsorbedDoseRate<T>> for &AbsorbedDoseRate<T> where T: NumLike+core::ops::Rem<Output=T> {
+	type Output = AbsorbedDoseRate<T>;
+	fn rem(self, rhs: &AbsorbedDoseRate<T>) -> Self::Output {
+		AbsorbedDoseRate{Gy_per_s: self.Gy_per_s.clone() % rhs.Gy_per_s.clone()}
+	}
+}
+
+/// Serializes a absorbed dose rate value together with its unit symbol, eg 
+/// `{"value": 1.5, "unit": "Gy/s"}` (requires the `serde-unit-tag` feature)
+#[cfg(feature="serde-unit-tag")]
+impl<T> serde::Serialize for AbsorbedDoseRate<T> where T: NumLike+serde::Serialize {
+	fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+		super::serialize_with_unit_tag(&self.Gy_per_s, Self::unit_symbol(), serializer)
+	}
+}
+/// Deserializes a absorbed dose rate value from a value and unit symbol, eg 
+/// `{"value": 1.5, "unit": "Gy/s"}`, returning an error if the unit symbol 
+/// does not match (requires the `serde-unit-tag` feature)
+#[cfg(feature="serde-unit-tag")]
+impl<'de, T> serde::Deserialize<'de> for AbsorbedDoseRate<T> where T: NumLike+serde::Deserialize<'de> {
+	fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+		Ok(AbsorbedDoseRate{Gy_per_s: super::deserialize_with_unit_tag(Self::unit_symbol(), deserializer)?})
+	}
+}
+
+/// The additive identity of absorbed dose rate values is zero grays per second (requires the `num-traits` feature)
+#[cfg(feature="num-traits")]
+impl<T> num_traits::Zero for AbsorbedDoseRate<T> where T: NumLike+num_traits::Zero {
+	fn zero() -> Self { AbsorbedDoseRate{Gy_per_s: T::zero()} }
+	fn is_zero(&self) -> bool { self.Gy_per_s.is_zero() }
+}
+
+#[cfg(feature="num-traits")]
+impl<T> AbsorbedDoseRate<T> where T: NumLike+num_traits::Signed {
+
+	/// Returns the sign of this absorbed dose rate value as a dimensionless number: 1 if it is 
+	/// positive, -1 if it is negative, and 0 if it is zero for integer number types 
+	/// (for floating-point number types, +0.0 returns 1.0, -0.0 returns -1.0, and NaN returns 
+	/// NaN) (requires the `num-traits` feature)
+	pub fn signum(self) -> T {
+		self.Gy_per_s.signum()
+	}
+}
+
+impl<T> AbsorbedDoseRate<T> where T: NumLike+PartialOrd {
+
+	/// Returns the absolute value of this absorbed dose rate value, ie its magnitude without a minus sign
+	/// (for floating-point number types, -0.0 returns +0.0)
+	pub fn abs(self) -> Self {
+		let negated = -self.Gy_per_s.clone();
+		if self.Gy_per_s < negated {
+			AbsorbedDoseRate{Gy_per_s: negated}
+		} else if self.Gy_per_s == negated {
+			// zero, which is its own negation (adding +0.0 turns -0.0 into +0.0)
+			AbsorbedDoseRate{Gy_per_s: negated + self.Gy_per_s}
+		} else {
+			self
+		}
+	}
+
+	/// Returns the lesser of this absorbed dose rate value and the given absorbed dose rate value
+	///
+	/// # Arguments
+	/// * `other` - The absorbed dose rate value to compare with this one
+	pub fn min(self, other: Self) -> Self {
+		if other.Gy_per_s < self.Gy_per_s { other } else { self }
+	}
+
+	/// Returns the greater of this absorbed dose rate value and the given absorbed dose rate value
+	///
+	/// # Arguments
+	/// * `other` - The absorbed dose rate value to compare with this one
+	pub fn max(self, other: Self) -> Self {
+		if other.Gy_per_s > self.Gy_per_s { other } else { self }
+	}
+
+	/// Returns the least of the given absorbed dose rate values, or `None` if there are none. 
+	/// Unlike `Iterator::min()`, this does not require `Ord`, so it works for 
+	/// floating-point numbers. NaN values are skipped (so the result is `None` if all 
+	/// values are NaN), and the first of several equal values is returned
+	///
+	/// # Arguments
+	/// * `values` - Any iterator or collection of absorbed dose rate values
+	pub fn min_of<I>(values: I) -> Option<Self> where I: IntoIterator<Item=Self> {
+		values.into_iter().filter(|x| x.Gy_per_s.partial_cmp(&x.Gy_per_s).is_some()).reduce(Self::min)
+	}
+
+	/// Returns the greatest of the given absorbed dose rate values, or `None` if there are none. 
+	/// Unlike `Iterator::max()`, this does not require `Ord`, so it works for 
+	/// floating-point numbers. NaN values are skipped (so the result is `None` if all 
+	/// values are NaN), and the first of several equal values is returned
+	///
+	/// # Arguments
+	/// * `values` - Any iterator or collection of absorbed dose rate values
+	pub fn max_of<I>(values: I) -> Option<Self> where I: IntoIterator<Item=Self> {
+		values.into_iter().filter(|x| x.Gy_per_s.partial_cmp(&x.Gy_per_s).is_some()).reduce(Self::max)
+	}
+
+	/// Returns this absorbed dose rate value restricted to the range from `lo` to `hi`
+	///
+	/// # Arguments
+	/// * `lo` - The minimum absorbed dose rate value
+	/// * `hi` - The maximum absorbed dose rate value
+	///
+	/// # Panics
+	/// Panics if `lo` is greater than `hi` (or if either of them is NaN)
+	pub fn clamp(self, lo: Self, hi: Self) -> Self {
+		assert!(lo.Gy_per_s <= hi.Gy_per_s, "lo must be less than or equal to hi");
+		if self.Gy_per_s < lo.Gy_per_s { lo } else if self.Gy_per_s > hi.Gy_per_s { hi } else { self }
+	}
+
+	/// Returns this absorbed dose rate value with its magnitude capped at `max`, keeping its 
+	/// sign (eg for capping a speed), or this value unchanged if its magnitude does not 
+	/// exceed `max`. For complex-valued absorbed dose rate, use `clamp_norm(...)` instead, 
+	/// which caps the magnitude while keeping the phase
+	///
+	/// # Arguments
+	/// * `max` - The maximum magnitude (should not be negative)
+	pub fn clamp_magnitude(self, max: Self) -> Self {
+		let negated_max = -max.Gy_per_s.clone();
+		if self.Gy_per_s > max.Gy_per_s { 
+			max 
+		} else if self.Gy_per_s < negated_max { 
+			AbsorbedDoseRate{Gy_per_s: negated_max} 
+		} else { 
+			self 
+		}
+	}
+
+	/// Returns true if this absorbed dose rate value differs from the given absorbed dose rate 
+	/// value by no more than the given absolute tolerance (which is also a 
+	/// absorbed dose rate value, so the comparison is dimensionally safe). NaN is never 
+	/// equal to anything. See also the `assert_unit_eq!(...)` macro.
+	///
+	/// # Arguments
+	/// * `other` - The absorbed dose rate value to compare with this one
+	/// * `tolerance` - The largest allowed difference between the two values
+	pub fn eq_within(self, other: Self, tolerance: Self) -> bool {
+		let diff = self.Gy_per_s - other.Gy_per_s;
+		diff <= tolerance.Gy_per_s && -diff <= tolerance.Gy_per_s
+	}
+}
+
+#[cfg(feature="num-complex")]
+impl AbsorbedDoseRate<num_complex::Complex32> {
+	/// Returns the magnitude (aka norm or absolute value) of this complex absorbed dose rate 
+	/// value as a real-valued absorbed dose rate (requires the `num-complex` feature)
+	pub fn magnitude(self) -> AbsorbedDoseRate<f32> {
+		AbsorbedDoseRate{Gy_per_s: self.Gy_per_s.norm()}
+	}
+
+	/// Returns this complex absorbed dose rate value scaled down so that its magnitude is 
+	/// at most `max` (keeping its phase), or this value unchanged if its magnitude does 
+	/// not exceed `max`. This is the complex equivalent of `clamp_magnitude(...)` (requires 
+	/// the `num-complex` feature)
+	///
+	/// # Arguments
+	/// * `max` - The maximum magnitude (should not be negative)
+	pub fn clamp_norm(self, max: AbsorbedDoseRate<f32>) -> Self {
+		let norm = self.Gy_per_s.norm();
+		if norm > max.Gy_per_s {
+			AbsorbedDoseRate{Gy_per_s: self.Gy_per_s * (max.Gy_per_s / norm)}
+		} else {
+			self
+		}
+	}
+}
+
+#[cfg(feature="num-complex")]
+impl AbsorbedDoseRate<num_complex::Complex64> {
+	/// Returns the magnitude (aka norm or absolute value) of this complex absorbed dose rate 
+	/// value as a real-valued absorbed dose rate (requires the `num-complex` feature)
+	pub fn magnitude(self) -> AbsorbedDoseRate<f64> {
+		AbsorbedDoseRate{Gy_per_s: self.Gy_per_s.norm()}
+	}
+
+	/// Returns this complex absorbed dose rate value scaled down so that its magnitude is 
+	/// at most `max` (keeping its phase), or this value unchanged if its magnitude does 
+	/// not exceed `max`. This is the complex equivalent of `clamp_magnitude(...)` (requires 
+	/// the `num-complex` feature)
+	///
+	/// # Arguments
+	/// * `max` - The maximum magnitude (should not be negative)
+	pub fn clamp_norm(self, max: AbsorbedDoseRate<f64>) -> Self {
+		let norm = self.Gy_per_s.norm();
+		if norm > max.Gy_per_s {
+			AbsorbedDoseRate{Gy_per_s: self.Gy_per_s * (max.Gy_per_s / norm)}
+		} else {
+			self
+		}
+	}
+}
+
+/// Rounding and other floating-point functions (requires the `num-traits` feature, 
+/// and either the `std` or `libm` feature)
+#[cfg(all(feature="num-traits", any(feature="std", feature="libm")))]
+impl<T> AbsorbedDoseRate<T> where T: NumLike+num_traits::Float {
+
+	/// Returns this absorbed dose rate value rounded to the nearest whole number of grays per second 
+	/// (half-way cases are rounded away from zero)
+	pub fn round(self) -> Self {
+		AbsorbedDoseRate{Gy_per_s: self.Gy_per_s.round()}
+	}
+
+	/// Returns this absorbed dose rate value rounded down to a whole number of grays per second
+	pub fn floor(self) -> Self {
+		AbsorbedDoseRate{Gy_per_s: self.Gy_per_s.floor()}
+	}
+
+	/// Returns this absorbed dose rate value rounded up to a whole number of grays per second
+	pub fn ceil(self) -> Self {
+		AbsorbedDoseRate{Gy_per_s: self.Gy_per_s.ceil()}
+	}
+
+	/// Returns this absorbed dose rate value rounded towards zero to a whole number of grays per second
+	pub fn trunc(self) -> Self {
+		AbsorbedDoseRate{Gy_per_s: self.Gy_per_s.trunc()}
+	}
+
+	/// Returns this absorbed dose rate value rounded to the nearest multiple of the given step 
+	/// (half-way cases are rounded away from zero)
+	///
+	/// # Arguments
+	/// * `step` - The absorbed dose rate value to round to a multiple of
+	pub fn round_to(self, step: Self) -> Self {
+		AbsorbedDoseRate{Gy_per_s: (self.Gy_per_s / step.Gy_per_s).round() * step.Gy_per_s}
+	}
+
+	/// Fused multiply-add: returns `self * a + b` computed with only one rounding 
+	/// error, which is more accurate (and on some hardware faster) than a separate 
+	/// multiplication and addition
+	///
+	/// # Arguments
+	/// * `a` - The scalar value to multiply this absorbed dose rate value by
+	/// * `b` - The absorbed dose rate value to add to the product
+	pub fn mul_add(self, a: T, b: Self) -> Self {
+		AbsorbedDoseRate{Gy_per_s: self.Gy_per_s.mul_add(a, b.Gy_per_s)}
+	}
+
+	/// Returns true if this absorbed dose rate value is neither infinite nor NaN
+	pub fn is_finite(&self) -> bool {
+		self.Gy_per_s.is_finite()
+	}
+
+	/// Returns true if this absorbed dose rate value is positive or negative infinity
+	pub fn is_infinite(&self) -> bool {
+		self.Gy_per_s.is_infinite()
+	}
+
+	/// Returns true if this absorbed dose rate value is NaN (not a number)
+	pub fn is_nan(&self) -> bool {
+		self.Gy_per_s.is_nan()
+	}
+
+	/// Returns true if this absorbed dose rate value has a positive sign, including +0.0 
+	/// and positive infinity
+	pub fn is_sign_positive(&self) -> bool {
+		self.Gy_per_s.is_sign_positive()
+	}
+
+	/// Returns true if this absorbed dose rate value has a negative sign, including -0.0 
+	/// and negative infinity
+	pub fn is_sign_negative(&self) -> bool {
+		self.Gy_per_s.is_sign_negative()
+	}
+
+	/// Returns a absorbed dose rate value with the magnitude of this one and the sign of 
+	/// `sign_source`, eg for reflecting a value off of a surface
+	///
+	/// # Arguments
+	/// * `sign_source` - The absorbed dose rate value whose sign is copied
+	pub fn copysign(self, sign_source: Self) -> Self {
+		AbsorbedDoseRate{Gy_per_s: self.Gy_per_s.copysign(sign_source.Gy_per_s)}
+	}
+}
+
+#[cfg(feature="num-traits")]
+impl<T> AbsorbedDoseRate<T> where T: NumLike+num_traits::CheckedAdd+num_traits::CheckedSub {
+
+	/// Adds another absorbed dose rate value to this one, returning `None` if the 
+	/// addition overflows the number type (requires the `num-traits` feature)
+	pub fn checked_add(self, rhs: Self) -> Option<Self> {
+		Some(AbsorbedDoseRate{Gy_per_s: self.Gy_per_s.checked_add(&rhs.Gy_per_s)?})
+	}
+
+	/// Subtracts another absorbed dose rate value from this one, returning `None` if 
+	/// the subtraction overflows the number type (requires the `num-traits` feature)
+	pub fn checked_sub(self, rhs: Self) -> Option<Self> {
+		Some(AbsorbedDoseRate{Gy_per_s: self.Gy_per_s.checked_sub(&rhs.Gy_per_s)?})
+	}
+}
+
+#[cfg(feature="num-traits")]
+impl<T> AbsorbedDoseRate<T> where T: NumLike+num_traits::SaturatingAdd+num_traits::SaturatingSub {
+
+	/// Adds another absorbed dose rate value to this one, clamping the result to the 
+	/// minimum or maximum value of the number type instead of overflowing 
+	/// (requires the `num-traits` feature)
+	pub fn saturating_add(self, rhs: Self) -> Self {
+		AbsorbedDoseRate{Gy_per_s: self.Gy_per_s.saturating_add(&rhs.Gy_per_s)}
+	}
+
+	/// Subtracts another absorbed dose rate value from this one, clamping the result 
+	/// to the minimum or maximum value of the number type instead of overflowing 
+	/// (requires the `num-traits` feature)
+	pub fn saturating_sub(self, rhs: Self) -> Self {
+		AbsorbedDoseRate{Gy_per_s: self.Gy_per_s.saturating_sub(&rhs.Gy_per_s)}
+	}
+}
+
+impl<T> AbsorbedDoseRate<T> where T: NumLike+Into<f64> {
+
+	/// Returns true if this absorbed dose rate value is equal to the given absorbed dose rate 
+	/// value to within the given number of significant figures (eg 6 significant 
+	/// figures allows a relative difference of up to 1 part per million). NaN is 
+	/// only approximately equal to NaN, infinity is only approximately equal to 
+	/// infinity of the same sign, and zero is only approximately equal to zero.
+	///
+	/// # Arguments
+	/// * `other` - The absorbed dose rate value to compare with this one
+	/// * `sig_figs` - The number of significant figures that must match
+	pub fn approx_eq(self, other: Self, sig_figs: i32) -> bool {
+		super::approx_eq_f64(self.Gy_per_s.into(), other.Gy_per_s.into(), sig_figs)
+	}
+}
+
+impl<T> AbsorbedDoseRate<T> where T: NumLike+From<f64> {
+
+	/// Returns the linear interpolation between this absorbed dose rate value and the given 
+	/// absorbed dose rate value, ie `self*(1-t) + other*t`. The interpolation factor is not 
+	/// clamped, so values of `t` outside the range 0 to 1 extrapolate beyond the two 
+	/// values (see `lerp_clamped(...)`).
+	///
+	/// # Arguments
+	/// * `other` - The absorbed dose rate value to interpolate towards (returned when `t` is 1)
+	/// * `t` - The interpolation factor, where 0 returns this value and 1 returns `other`
+	pub fn lerp(self, other: Self, t: f64) -> Self {
+		AbsorbedDoseRate{Gy_per_s: self.Gy_per_s * T::from(1.0 - t) + other.Gy_per_s * T::from(t)}
+	}
+
+	/// Same as `lerp(...)`, except that the interpolation factor `t` is first clamped 
+	/// to the range 0 to 1, so that the result is always between the two values
+	///
+	/// # Arguments
+	/// * `other` - The absorbed dose rate value to interpolate towards (returned when `t` is 1 or more)
+	/// * `t` - The interpolation factor, where 0 returns this value and 1 returns `other`
+	pub fn lerp_clamped(self, other: Self, t: f64) -> Self {
+		self.lerp(other, t.clamp(0.0, 1.0))
+	}
+	
+	/// Returns a copy of this absorbed dose rate value in milligrays per second
+	/// 
+	/// *Note: This method is not available for `f32` and other number types lacking the `From<f64>` trait*
+	pub fn to_mGy_per_s(&self) -> T {
+		return self.Gy_per_s.clone() * T::from(1000.0_f64);
+	}
+
+	/// Returns a new absorbed dose rate value from the given number of milligrays per second
 	/// 
 	/// *Note: This method is not available for `f32` and other number types lacking the `From<f64>` trait*
 	///
 	/// # Arguments
-	/// * `per_rad` - Any number-like type, representing a quantity of inverse rads
-	pub fn from_per_rad(per_rad: T) -> Self {
-		InverseAbsorbedDose{per_Gy: per_rad * T::from(100.0_f64)}
+	/// * `mGy_per_s` - Any number-like type, representing a quantity of milligrays per second
+	pub fn from_mGy_per_s(mGy_per_s: T) -> Self {
+		AbsorbedDoseRate{Gy_per_s: mGy_per_s * T::from(0.001_f64)}
+	}
+
+	/// Returns a copy of this absorbed dose rate value in micrograys per second
+	/// 
+	/// *Note: This method is not available for `f32` and other number types lacking the `From<f64>` trait*
+	pub fn to_uGy_per_s(&self) -> T {
+		return self.Gy_per_s.clone() * T::from(1000000.0_f64);
+	}
+
+	/// Returns a new absorbed dose rate value from the given number of micrograys per second
+	/// 
+	/// *Note: This method is not available for `f32` and other number types lacking the `From<f64>` trait*
+	///
+	/// # Arguments
+	/// * `uGy_per_s` - Any number-like type, representing a quantity of micrograys per second
+	pub fn from_uGy_per_s(uGy_per_s: T) -> Self {
+		AbsorbedDoseRate{Gy_per_s: uGy_per_s * T::from(1e-06_f64)}
+	}
+
+	/// Returns a copy of this absorbed dose rate value in grays per hour
+	/// 
+	/// *Note: This method is not available for `f32` and other number types lacking the `From<f64>` trait*
+	pub fn to_Gy_per_h(&self) -> T {
+		return self.Gy_per_s.clone() * T::from(3600.0_f64);
+	}
+
+	/// Returns a new absorbed dose rate value from the given number of grays per hour
+	/// 
+	/// *Note: This method is not available for `f32` and other number types lacking the `From<f64>` trait*
+	///
+	/// # Arguments
+	/// * `Gy_per_h` - Any number-like type, representing a quantity of grays per hour
+	pub fn from_Gy_per_h(Gy_per_h: T) -> Self {
+		AbsorbedDoseRate{Gy_per_s: Gy_per_h * T::from(0.0002777777777777778_f64)}
+	}
+
+	/// Returns a copy of this absorbed dose rate value in milligrays per hour
+	/// 
+	/// *Note: This method is not available for `f32` and other number types lacking the `From<f64>` trait*
+	pub fn to_mGy_per_h(&self) -> T {
+		return self.Gy_per_s.clone() * T::from(3600000.0_f64);
+	}
+
+	/// Returns a new absorbed dose rate value from the given number of milligrays per hour
+	/// 
+	/// *Note: This method is not available for `f32` and other number types lacking the `From<f64>` trait*
+	///
+	/// # Arguments
+	/// * `mGy_per_h` - Any number-like type, representing a quantity of milligrays per hour
+	pub fn from_mGy_per_h(mGy_per_h: T) -> Self {
+		AbsorbedDoseRate{Gy_per_s: mGy_per_h * T::from(2.7777777777777776e-07_f64)}
+	}
+
+	/// Returns a copy of this absorbed dose rate value in micrograys per hour
+	/// 
+	/// *Note: This method is not available for `f32` and other number types lacking the `From<f64>` trait*
+	pub fn to_uGy_per_h(&self) -> T {
+		return self.Gy_per_s.clone() * T::from(3600000000.0_f64);
+	}
+
+	/// Returns a new absorbed dose rate value from the given number of micrograys per hour
+	/// 
+	/// *Note: This method is not available for `f32` and other number types lacking the `From<f64>` trait*
+	///
+	/// # Arguments
+	/// * `uGy_per_h` - Any number-like type, representing a quantity of micrograys per hour
+	pub fn from_uGy_per_h(uGy_per_h: T) -> Self {
+		AbsorbedDoseRate{Gy_per_s: uGy_per_h * T::from(2.7777777777777777e-10_f64)}
+	}
+
+	/// Returns a copy of this absorbed dose rate value in nanograys per hour
+	/// 
+	/// *Note: This method is not available for `f32` and other number types lacking the `From<f64>` trait*
+	pub fn to_nGy_per_h(&self) -> T {
+		return self.Gy_per_s.clone() * T::from(3600000000000.0_f64);
+	}
+
+	/// Returns a new absorbed dose rate value from the given number of nanograys per hour
+	/// 
+	/// *Note: This method is not available for `f32` and other number types lacking the `From<f64>` trait*
+	///
+	/// # Arguments
+	/// * `nGy_per_h` - Any number-like type, representing a quantity of nanograys per hour
+	pub fn from_nGy_per_h(nGy_per_h: T) -> Self {
+		AbsorbedDoseRate{Gy_per_s: nGy_per_h * T::from(2.777777777777778e-13_f64)}
+	}
+
+}
+
+
+/// Multiplying a unit value by a scalar value returns a unit value
+#[cfg(feature="num-bigfloat")]
+impl core::ops::Mul<AbsorbedDoseRate<num_bigfloat::BigFloat>> for num_bigfloat::BigFloat {
+	type Output = AbsorbedDoseRate<num_bigfloat::BigFloat>;
+	fn mul(self, rhs: AbsorbedDoseRate<num_bigfloat::BigFloat>) -> Self::Output {
+		AbsorbedDoseRate{Gy_per_s: self * rhs.Gy_per_s}
+	}
+}
+/// Multiplying a unit value by a scalar value returns a unit value
+#[cfg(feature="num-bigfloat")]
+impl core::ops::Mul<AbsorbedDoseRate<num_bigfloat::BigFloat>> for &num_bigfloat::BigFloat {
+	type Output = AbsorbedDoseRate<num_bigfloat::BigFloat>;
+	fn mul(self, rhs: AbsorbedDoseRate<num_bigfloat::BigFloat>) -> Self::Output {
+		AbsorbedDoseRate{Gy_per_s: self.clone() * rhs.Gy_per_s}
+	}
+}
+/// Multiplying a unit value by a scalar value returns a unit value
+#[cfg(feature="num-bigfloat")]
+impl core::ops::Mul<&AbsorbedDoseRate<num_bigfloat::BigFloat>> for num_bigfloat::BigFloat {
+	type Output = AbsorbedDoseRate<num_bigfloat::BigFloat>;
+	fn mul(self, rhs: &AbsorbedDoseRate<num_bigfloat::BigFloat>) -> Self::Output {
+		AbsorbedDoseRate{Gy_per_s: self * rhs.Gy_per_s.clone()}
+	}
+}
+/// Multiplying a unit value by a scalar value returns a unit value
+#[cfg(feature="num-bigfloat")]
+impl core::ops::Mul<&AbsorbedDoseRate<num_bigfloat::BigFloat>> for &num_bigfloat::BigFloat {
+	type Output = AbsorbedDoseRate<num_bigfloat::BigFloat>;
+	fn mul(self, rhs: &AbsorbedDoseRate<num_bigfloat::BigFloat>) -> Self::Output {
+		AbsorbedDoseRate{Gy_per_s: self.clone() * rhs.Gy_per_s.clone()}
+	}
+}
+
+/// Multiplying a unit value by a scalar value returns a unit value
+#[cfg(feature="num-complex")]
+impl core::ops::Mul<AbsorbedDoseRate<num_complex::Complex32>> for num_complex::Complex32 {
+	type Output = AbsorbedDoseRate<num_complex::Complex32>;
+	fn mul(self, rhs: AbsorbedDoseRate<num_complex::Complex32>) -> Self::Output {
+		AbsorbedDoseRate{Gy_per_s: self * rhs.Gy_per_s}
+	}
+}
+/// Multiplying a unit value by a scalar value returns a unit value
+#[cfg(feature="num-complex")]
+impl core::ops::Mul<AbsorbedDoseRate<num_complex::Complex32>> for &num_complex::Complex32 {
+	type Output = AbsorbedDoseRate<num_complex::Complex32>;
+	fn mul(self, rhs: AbsorbedDoseRate<num_complex::Complex32>) -> Self::Output {
+		AbsorbedDoseRate{Gy_per_s: self.clone() * rhs.Gy_per_s}
+	}
+}
+/// Multiplying a unit value by a scalar value returns a unit value
+#[cfg(feature="num-complex")]
+impl core::ops::Mul<&AbsorbedDoseRate<num_complex::Complex32>> for num_complex::Complex32 {
+	type Output = AbsorbedDoseRate<num_complex::Complex32>;
+	fn mul(self, rhs: &AbsorbedDoseRate<num_complex::Complex32>) -> Self::Output {
+		AbsorbedDoseRate{Gy_per_s: self * rhs.Gy_per_s.clone()}
+	}
+}
+/// Multiplying a unit value by a scalar value returns a unit value
+#[cfg(feature="num-complex")]
+impl core::ops::Mul<&AbsorbedDoseRate<num_complex::Complex32>> for &num_complex::Complex32 {
+	type Output = AbsorbedDoseRate<num_complex::Complex32>;
+	fn mul(self, rhs: &AbsorbedDoseRate<num_complex::Complex32>) -> Self::Output {
+		AbsorbedDoseRate{Gy_per_s: self.clone() * rhs.Gy_per_s.clone()}
+	}
+}
+
+/// Multiplying a unit value by a scalar value returns a unit value
+#[cfg(feature="num-complex")]
+impl core::ops::Mul<AbsorbedDoseRate<num_complex::Complex64>> for num_complex::Complex64 {
+	type Output = AbsorbedDoseRate<num_complex::Complex64>;
+	fn mul(self, rhs: AbsorbedDoseRate<num_complex::Complex64>) -> Self::Output {
+		AbsorbedDoseRate{Gy_per_s: self * rhs.Gy_per_s}
+	}
+}
+/// Multiplying a unit value by a scalar value returns a unit value
+#[cfg(feature="num-complex")]
+impl core::ops::Mul<AbsorbedDoseRate<num_complex::Complex64>> for &num_complex::Complex64 {
+	type Output = AbsorbedDoseRate<num_complex::Complex64>;
+	fn mul(self, rhs: AbsorbedDoseRate<num_complex::Complex64>) -> Self::Output {
+		AbsorbedDoseRate{Gy_per_s: self.clone() * rhs.Gy_per_s}
+	}
+}
+/// Multiplying a unit value by a scalar value returns a unit value
+#[cfg(feature="num-complex")]
+impl core::ops::Mul<&AbsorbedDoseRate<num_complex::Complex64>> for num_complex::Complex64 {
+	type Output = AbsorbedDoseRate<num_complex::Complex64>;
+	fn mul(self, rhs: &AbsorbedDoseRate<num_complex::Complex64>) -> Self::Output {
+		AbsorbedDoseRate{Gy_per_s: self * rhs.Gy_per_s.clone()}
+	}
+}
+/// Multiplying a unit value by a scalar value returns a unit value
+#[cfg(feature="num-complex")]
+impl core::ops::Mul<&AbsorbedDoseRate<num_complex::Complex64>> for &num_complex::Complex64 {
+	type Output = AbsorbedDoseRate<num_complex::Complex64>;
+	fn mul(self, rhs: &AbsorbedDoseRate<num_complex::Complex64>) -> Self::Output {
+		AbsorbedDoseRate{Gy_per_s: self.clone() * rhs.Gy_per_s.clone()}
+	}
+}
+
+/// Multiplying a unit value by a scalar value returns a unit value
+#[cfg(feature="num-rational")]
+impl core::ops::Mul<AbsorbedDoseRate<num_rational::Rational64>> for num_rational::Rational64 {
+	type Output = AbsorbedDoseRate<num_rational::Rational64>;
+	fn mul(self, rhs: AbsorbedDoseRate<num_rational::Rational64>) -> Self::Output {
+		AbsorbedDoseRate{Gy_per_s: self * rhs.Gy_per_s}
+	}
+}
+/// Multiplying a unit value by a scalar value returns a unit value
+#[cfg(feature="num-rational")]
+impl core::ops::Mul<AbsorbedDoseRate<num_rational::Rational64>> for &num_rational::Rational64 {
+	type Output = AbsorbedDoseRate<num_rational::Rational64>;
+	fn mul(self, rhs: AbsorbedDoseRate<num_rational::Rational64>) -> Self::Output {
+		AbsorbedDoseRate{Gy_per_s: self.clone() * rhs.Gy_per_s}
+	}
+}
+/// Multiplying a unit value by a scalar value returns a unit value
+#[cfg(feature="num-rational")]
+impl core::ops::Mul<&AbsorbedDoseRate<num_rational::Rational64>> for num_rational::Rational64 {
+	type Output = AbsorbedDoseRate<num_rational::Rational64>;
+	fn mul(self, rhs: &AbsorbedDoseRate<num_rational::Rational64>) -> Self::Output {
+		AbsorbedDoseRate{Gy_per_s: self * rhs.Gy_per_s.clone()}
+	}
+}
+/// Multiplying a unit value by a scalar value returns a unit value
+#[cfg(feature="num-rational")]
+impl core::ops::Mul<&AbsorbedDoseRate<num_rational::Rational64>> for &num_rational::Rational64 {
+	type Output = AbsorbedDoseRate<num_rational::Rational64>;
+	fn mul(self, rhs: &AbsorbedDoseRate<num_rational::Rational64>) -> Self::Output {
+		AbsorbedDoseRate{Gy_per_s: self.clone() * rhs.Gy_per_s.clone()}
+	}
+}
+
+/// Multiplying a unit value by a scalar value returns a unit value
+#[cfg(feature="num-rational")]
+impl core::ops::Mul<AbsorbedDoseRate<num_rational::BigRational>> for num_rational::BigRational {
+	type Output = AbsorbedDoseRate<num_rational::BigRational>;
+	fn mul(self, rhs: AbsorbedDoseRate<num_rational::BigRational>) -> Self::Output {
+		AbsorbedDoseRate{Gy_per_s: self * rhs.Gy_per_s}
+	}
+}
+/// Multiplying a unit value by a scalar value returns a unit value
+#[cfg(feature="num-rational")]
+impl core::ops::Mul<AbsorbedDoseRate<num_rational::BigRational>> for &num_rational::BigRational {
+	type Output = AbsorbedDoseRate<num_rational::BigRational>;
+	fn mul(self, rhs: AbsorbedDoseRate<num_rational::BigRational>) -> Self::Output {
+		AbsorbedDoseRate{Gy_per_s: self.clone() * rhs.Gy_per_s}
+	}
+}
+/// Multiplying a unit value by a scalar value returns a unit value
+#[cfg(feature="num-rational")]
+impl core::ops::Mul<&AbsorbedDoseRate<num_rational::BigRational>> for num_rational::BigRational {
+	type Output = AbsorbedDoseRate<num_rational::BigRational>;
+	fn mul(self, rhs: &AbsorbedDoseRate<num_rational::BigRational>) -> Self::Output {
+		AbsorbedDoseRate{Gy_per_s: self * rhs.Gy_per_s.clone()}
+	}
+}
+/// Multiplying a unit value by a scalar value returns a unit value
+#[cfg(feature="num-rational")]
+impl core::ops::Mul<&AbsorbedDoseRate<num_rational::BigRational>> for &num_rational::BigRational {
+	type Output = AbsorbedDoseRate<num_rational::BigRational>;
+	fn mul(self, rhs: &AbsorbedDoseRate<num_rational::BigRational>) -> Self::Output {
+		AbsorbedDoseRate{Gy_per_s: self.clone() * rhs.Gy_per_s.clone()}
+	}
+}
+
+
+
+
+
+/// Multiplying a AbsorbedDoseRate by an [nalgebra](https://crates.io/crates/nalgebra) 
+/// vector or matrix of unitless values returns a vector or matrix of AbsorbedDoseRate 
+/// values (eg a direction vector multiplied by a AbsorbedDoseRate)
+#[cfg(feature="nalgebra")]
+impl<T, R, C, S> core::ops::Mul<nalgebra::Matrix<T, R, C, S>> for AbsorbedDoseRate<T> where 
+	T: NumLike+nalgebra::Scalar, R: nalgebra::Dim, C: nalgebra::Dim, S: nalgebra::RawStorage<T, R, C>, 
+	nalgebra::DefaultAllocator: nalgebra::allocator::Allocator<R, C> {
+	type Output = nalgebra::OMatrix<AbsorbedDoseRate<T>, R, C>;
+	fn mul(self, rhs: nalgebra::Matrix<T, R, C, S>) -> Self::Output {
+		rhs.map(|x| AbsorbedDoseRate{Gy_per_s: self.Gy_per_s.clone() * x})
+	}
+}
+/// Multiplying an [nalgebra](https://crates.io/crates/nalgebra) vector or matrix of 
+/// unitless values by a AbsorbedDoseRate returns a vector or matrix of AbsorbedDoseRate values
+#[cfg(feature="nalgebra")]
+impl<T, R, C, S> core::ops::Mul<AbsorbedDoseRate<T>> for nalgebra::Matrix<T, R, C, S> where 
+	T: NumLike+nalgebra::Scalar, R: nalgebra::Dim, C: nalgebra::Dim, S: nalgebra::RawStorage<T, R, C>, 
+	nalgebra::DefaultAllocator: nalgebra::allocator::Allocator<R, C> {
+	type Output = nalgebra::OMatrix<AbsorbedDoseRate<T>, R, C>;
+	fn mul(self, rhs: AbsorbedDoseRate<T>) -> Self::Output {
+		self.map(|x| AbsorbedDoseRate{Gy_per_s: x * rhs.Gy_per_s.clone()})
+	}
+}
+/// Dividing an [nalgebra](https://crates.io/crates/nalgebra) vector or matrix of 
+/// AbsorbedDoseRate values by a AbsorbedDoseRate returns a vector or matrix of unitless values
+#[cfg(feature="nalgebra")]
+impl<T, R, C, S> core::ops::Div<AbsorbedDoseRate<T>> for nalgebra::Matrix<AbsorbedDoseRate<T>, R, C, S> where 
+	T: NumLike+nalgebra::Scalar, R: nalgebra::Dim, C: nalgebra::Dim, S: nalgebra::RawStorage<AbsorbedDoseRate<T>, R, C>, 
+	nalgebra::DefaultAllocator: nalgebra::allocator::Allocator<R, C> {
+	type Output = nalgebra::OMatrix<T, R, C>;
+	fn div(self, rhs: AbsorbedDoseRate<T>) -> Self::Output {
+		self.map(|x| x.Gy_per_s / rhs.Gy_per_s.clone())
+	}
+}
+
+
+
+// AbsorbedDoseRate * Time -> AbsorbedDose
+/// Multiplying a AbsorbedDoseRate by a Time returns a value of type AbsorbedDose
+impl<T> core::ops::Mul<Time<T>> for AbsorbedDoseRate<T> where T: NumLike {
+	type Output = AbsorbedDose<T>;
+	fn mul(self, rhs: Time<T>) -> Self::Output {
+		AbsorbedDose{Gy: self.Gy_per_s * rhs.s}
+	}
+}
+/// Multiplying a AbsorbedDoseRate by a Time returns a value of type AbsorbedDose
+impl<T> core::ops::Mul<Time<T>> for &AbsorbedDoseRate<T> where T: NumLike {
+	type Output = AbsorbedDose<T>;
+	fn mul(self, rhs: Time<T>) -> Self::Output {
+		AbsorbedDose{Gy: self.Gy_per_s.clone() * rhs.s}
+	}
+}
+/// Multiplying a AbsorbedDoseRate by a Time returns a value of type AbsorbedDose
+impl<T> core::ops::Mul<&Time<T>> for AbsorbedDoseRate<T> where T: NumLike {
+	type Output = AbsorbedDose<T>;
+	fn mul(self, rhs: &Time<T>) -> Self::Output {
+		AbsorbedDose{Gy: self.Gy_per_s * rhs.s.clone()}
+	}
+}
+/// Multiplying a AbsorbedDoseRate by a Time returns a value of type AbsorbedDose
+impl<T> core::ops::Mul<&Time<T>> for &AbsorbedDoseRate<T> where T: NumLike {
+	type Output = AbsorbedDose<T>;
+	fn mul(self, rhs: &Time<T>) -> Self::Output {
+		AbsorbedDose{Gy: self.Gy_per_s.clone() * rhs.s.clone()}
+	}
+}
+
+impl<T> AbsorbedDoseRate<T> where T: NumLike+From<u8> {
+	/// Returns the reciprocal of this AbsorbedDoseRate value (ie 1 divided by 
+	/// this value) as a value of type InverseAbsorbedDoseRate
+	pub fn recip(self) -> InverseAbsorbedDoseRate<T> {
+		InverseAbsorbedDoseRate{s_per_Gy: T::from(1u8) / self.Gy_per_s}
+	}
+}
+
+// 1/AbsorbedDoseRate -> InverseAbsorbedDoseRate
+/// Dividing a scalar value by a AbsorbedDoseRate unit value returns a value of type InverseAbsorbedDoseRate
+impl<T> core::ops::Div<AbsorbedDoseRate<T>> for f64 where T: NumLike+From<f64> {
+	type Output = InverseAbsorbedDoseRate<T>;
+	fn div(self, rhs: AbsorbedDoseRate<T>) -> Self::Output {
+		InverseAbsorbedDoseRate{s_per_Gy: T::from(self) / rhs.Gy_per_s}
+	}
+}
+/// Dividing a scalar value by a AbsorbedDoseRate unit value returns a value of type InverseAbsorbedDoseRate
+impl<T> core::ops::Div<AbsorbedDoseRate<T>> for &f64 where T: NumLike+From<f64> {
+	type Output = InverseAbsorbedDoseRate<T>;
+	fn div(self, rhs: AbsorbedDoseRate<T>) -> Self::Output {
+		InverseAbsorbedDoseRate{s_per_Gy: T::from(self.clone()) / rhs.Gy_per_s}
+	}
+}
+/// Dividing a scalar value by a AbsorbedDoseRate unit value returns a value of type InverseAbsorbedDoseRate
+impl<T> core::ops::Div<&AbsorbedDoseRate<T>> for f64 where T: NumLike+From<f64> {
+	type Output = InverseAbsorbedDoseRate<T>;
+	fn div(self, rhs: &AbsorbedDoseRate<T>) -> Self::Output {
+		InverseAbsorbedDoseRate{s_per_Gy: T::from(self) / rhs.Gy_per_s.clone()}
+	}
+}
+/// Dividing a scalar value by a AbsorbedDoseRate unit value returns a value of type InverseAbsorbedDoseRate
+impl<T> core::ops::Div<&AbsorbedDoseRate<T>> for &f64 where T: NumLike+From<f64> {
+	type Output = InverseAbsorbedDoseRate<T>;
+	fn div(self, rhs: &AbsorbedDoseRate<T>) -> Self::Output {
+		InverseAbsorbedDoseRate{s_per_Gy: T::from(self.clone()) / rhs.Gy_per_s.clone()}
+	}
+}
+
+// 1/AbsorbedDoseRate -> InverseAbsorbedDoseRate
+/// Dividing a scalar value by a AbsorbedDoseRate unit value returns a value of type InverseAbsorbedDoseRate
+impl<T> core::ops::Div<AbsorbedDoseRate<T>> for f32 where T: NumLike+From<f32> {
+	type Output = InverseAbsorbedDoseRate<T>;
+	fn div(self, rhs: AbsorbedDoseRate<T>) -> Self::Output {
+		InverseAbsorbedDoseRate{s_per_Gy: T::from(self) / rhs.Gy_per_s}
+	}
+}
+/// Dividing a scalar value by a AbsorbedDoseRate unit value returns a value of type InverseAbsorbedDoseRate
+impl<T> core::ops::Div<AbsorbedDoseRate<T>> for &f32 where T: NumLike+From<f32> {
+	type Output = InverseAbsorbedDoseRate<T>;
+	fn div(self, rhs: AbsorbedDoseRate<T>) -> Self::Output {
+		InverseAbsorbedDoseRate{s_per_Gy: T::from(self.clone()) / rhs.Gy_per_s}
+	}
+}
+/// Dividing a scalar value by a AbsorbedDoseRate unit value returns a value of type InverseAbsorbedDoseRate
+impl<T> core::ops::Div<&AbsorbedDoseRate<T>> for f32 where T: NumLike+From<f32> {
+	type Output = InverseAbsorbedDoseRate<T>;
+	fn div(self, rhs: &AbsorbedDoseRate<T>) -> Self::Output {
+		InverseAbsorbedDoseRate{s_per_Gy: T::from(self) / rhs.Gy_per_s.clone()}
+	}
+}
+/// Dividing a scalar value by a AbsorbedDoseRate unit value returns a value of type InverseAbsorbedDoseRate
+impl<T> core::ops::Div<&AbsorbedDoseRate<T>> for &f32 where T: NumLike+From<f32> {
+	type Output = InverseAbsorbedDoseRate<T>;
+	fn div(self, rhs: &AbsorbedDoseRate<T>) -> Self::Output {
+		InverseAbsorbedDoseRate{s_per_Gy: T::from(self.clone()) / rhs.Gy_per_s.clone()}
+	}
+}
+
+// 1/AbsorbedDoseRate -> InverseAbsorbedDoseRate
+/// Dividing a scalar value by a AbsorbedDoseRate unit value returns a value of type InverseAbsorbedDoseRate
+impl<T> core::ops::Div<AbsorbedDoseRate<T>> for i64 where T: NumLike+From<i64> {
+	type Output = InverseAbsorbedDoseRate<T>;
+	fn div(self, rhs: AbsorbedDoseRate<T>) -> Self::Output {
+		InverseAbsorbedDoseRate{s_per_Gy: T::from(self) / rhs.Gy_per_s}
+	}
+}
+/// Dividing a scalar value by a AbsorbedDoseRate unit value returns a value of type InverseAbsorbedDoseRate
+impl<T> core::ops::Div<AbsorbedDoseRate<T>> for &i64 where T: NumLike+From<i64> {
+	type Output = InverseAbsorbedDoseRate<T>;
+	fn div(self, rhs: AbsorbedDoseRate<T>) -> Self::Output {
+		InverseAbsorbedDoseRate{s_per_Gy: T::from(self.clone()) / rhs.Gy_per_s}
+	}
+}
+/// Dividing a scalar value by a AbsorbedDoseRate unit value returns a value of type InverseAbsorbedDoseRate
+impl<T> core::ops::Div<&AbsorbedDoseRate<T>> for i64 where T: NumLike+From<i64> {
+	type Output = InverseAbsorbedDoseRate<T>;
+	fn div(self, rhs: &AbsorbedDoseRate<T>) -> Self::Output {
+		InverseAbsorbedDoseRate{s_per_Gy: T::from(self) / rhs.Gy_per_s.clone()}
+	}
+}
+/// Dividing a scalar value by a AbsorbedDoseRate unit value returns a value of type InverseAbsorbedDoseRate
+impl<T> core::ops::Div<&AbsorbedDoseRate<T>> for &i64 where T: NumLike+From<i64> {
+	type Output = InverseAbsorbedDoseRate<T>;
+	fn div(self, rhs: &AbsorbedDoseRate<T>) -> Self::Output {
+		InverseAbsorbedDoseRate{s_per_Gy: T::from(self.clone()) / rhs.Gy_per_s.clone()}
+	}
+}
+
+// 1/AbsorbedDoseRate -> InverseAbsorbedDoseRate
+/// Dividing a scalar value by a AbsorbedDoseRate unit value returns a value of type InverseAbsorbedDoseRate
+impl<T> core::ops::Div<AbsorbedDoseRate<T>> for i32 where T: NumLike+From<i32> {
+	type Output = InverseAbsorbedDoseRate<T>;
+	fn div(self, rhs: AbsorbedDoseRate<T>) -> Self::Output {
+		InverseAbsorbedDoseRate{s_per_Gy: T::from(self) / rhs.Gy_per_s}
+	}
+}
+/// Dividing a scalar value by a AbsorbedDoseRate unit value returns a value of type InverseAbsorbedDoseRate
+impl<T> core::ops::Div<AbsorbedDoseRate<T>> for &i32 where T: NumLike+From<i32> {
+	type Output = InverseAbsorbedDoseRate<T>;
+	fn div(self, rhs: AbsorbedDoseRate<T>) -> Self::Output {
+		InverseAbsorbedDoseRate{s_per_Gy: T::from(self.clone()) / rhs.Gy_per_s}
+	}
+}
+/// Dividing a scalar value by a AbsorbedDoseRate unit value returns a value of type InverseAbsorbedDoseRate
+impl<T> core::ops::Div<&AbsorbedDoseRate<T>> for i32 where T: NumLike+From<i32> {
+	type Output = InverseAbsorbedDoseRate<T>;
+	fn div(self, rhs: &AbsorbedDoseRate<T>) -> Self::Output {
+		InverseAbsorbedDoseRate{s_per_Gy: T::from(self) / rhs.Gy_per_s.clone()}
+	}
+}
+/// Dividing a scalar value by a AbsorbedDoseRate unit value returns a value of type InverseAbsorbedDoseRate
+impl<T> core::ops::Div<&AbsorbedDoseRate<T>> for &i32 where T: NumLike+From<i32> {
+	type Output = InverseAbsorbedDoseRate<T>;
+	fn div(self, rhs: &AbsorbedDoseRate<T>) -> Self::Output {
+		InverseAbsorbedDoseRate{s_per_Gy: T::from(self.clone()) / rhs.Gy_per_s.clone()}
+	}
+}
+
+// 1/AbsorbedDoseRate -> InverseAbsorbedDoseRate
+/// Dividing a scalar value by a AbsorbedDoseRate unit value returns a value of type InverseAbsorbedDoseRate
+#[cfg(feature="num-bigfloat")]
+impl<T> core::ops::Div<AbsorbedDoseRate<T>> for num_bigfloat::BigFloat where T: NumLike+From<num_bigfloat::BigFloat> {
+	type Output = InverseAbsorbedDoseRate<T>;
+	fn div(self, rhs: AbsorbedDoseRate<T>) -> Self::Output {
+		InverseAbsorbedDoseRate{s_per_Gy: T::from(self) / rhs.Gy_per_s}
+	}
+}
+/// Dividing a scalar value by a AbsorbedDoseRate unit value returns a value of type InverseAbsorbedDoseRate
+#[cfg(feature="num-bigfloat")]
+impl<T> core::ops::Div<AbsorbedDoseRate<T>> for &num_bigfloat::BigFloat where T: NumLike+From<num_bigfloat::BigFloat> {
+	type Output = InverseAbsorbedDoseRate<T>;
+	fn div(self, rhs: AbsorbedDoseRate<T>) -> Self::Output {
+		InverseAbsorbedDoseRate{s_per_Gy: T::from(self.clone()) / rhs.Gy_per_s}
+	}
+}
+/// Dividing a scalar value by a AbsorbedDoseRate unit value returns a value of type InverseAbsorbedDoseRate
+#[cfg(feature="num-bigfloat")]
+impl<T> core::ops::Div<&AbsorbedDoseRate<T>> for num_bigfloat::BigFloat where T: NumLike+From<num_bigfloat::BigFloat> {
+	type Output = InverseAbsorbedDoseRate<T>;
+	fn div(self, rhs: &AbsorbedDoseRate<T>) -> Self::Output {
+		InverseAbsorbedDoseRate{s_per_Gy: T::from(self) / rhs.Gy_per_s.clone()}
+	}
+}
+/// Dividing a scalar value by a AbsorbedDoseRate unit value returns a value of type InverseAbsorbedDoseRate
+#[cfg(feature="num-bigfloat")]
+impl<T> core::ops::Div<&AbsorbedDoseRate<T>> for &num_bigfloat::BigFloat where T: NumLike+From<num_bigfloat::BigFloat> {
+	type Output = InverseAbsorbedDoseRate<T>;
+	fn div(self, rhs: &AbsorbedDoseRate<T>) -> Self::Output {
+		InverseAbsorbedDoseRate{s_per_Gy: T::from(self.clone()) / rhs.Gy_per_s.clone()}
+	}
+}
+
+// 1/AbsorbedDoseRate -> InverseAbsorbedDoseRate
+/// Dividing a scalar value by a AbsorbedDoseRate unit value returns a value of type InverseAbsorbedDoseRate
+#[cfg(feature="num-complex")]
+impl<T> core::ops::Div<AbsorbedDoseRate<T>> for num_complex::Complex32 where T: NumLike+From<num_complex::Complex32> {
+	type Output = InverseAbsorbedDoseRate<T>;
+	fn div(self, rhs: AbsorbedDoseRate<T>) -> Self::Output {
+		InverseAbsorbedDoseRate{s_per_Gy: T::from(self) / rhs.Gy_per_s}
+	}
+}
+/// Dividing a scalar value by a AbsorbedDoseRate unit value returns a value of type InverseAbsorbedDoseRate
+#[cfg(feature="num-complex")]
+impl<T> core::ops::Div<AbsorbedDoseRate<T>> for &num_complex::Complex32 where T: NumLike+From<num_complex::Complex32> {
+	type Output = InverseAbsorbedDoseRate<T>;
+	fn div(self, rhs: AbsorbedDoseRate<T>) -> Self::Output {
+		InverseAbsorbedDoseRate{s_per_Gy: T::from(self.clone()) / rhs.Gy_per_s}
+	}
+}
+/// Dividing a scalar value by a AbsorbedDoseRate unit value returns a value of type InverseAbsorbedDoseRate
+#[cfg(feature="num-complex")]
+impl<T> core::ops::Div<&AbsorbedDoseRate<T>> for num_complex::Complex32 where T: NumLike+From<num_complex::Complex32> {
+	type Output = InverseAbsorbedDoseRate<T>;
+	fn div(self, rhs: &AbsorbedDoseRate<T>) -> Self::Output {
+		InverseAbsorbedDoseRate{s_per_Gy: T::from(self) / rhs.Gy_per_s.clone()}
+	}
+}
+/// Dividing a scalar value by a AbsorbedDoseRate unit value returns a value of type InverseAbsorbedDoseRate
+#[cfg(feature="num-complex")]
+impl<T> core::ops::Div<&AbsorbedDoseRate<T>> for &num_complex::Complex32 where T: NumLike+From<num_complex::Complex32> {
+	type Output = InverseAbsorbedDoseRate<T>;
+	fn div(self, rhs: &AbsorbedDoseRate<T>) -> Self::Output {
+		InverseAbsorbedDoseRate{s_per_Gy: T::from(self.clone()) / rhs.Gy_per_s.clone()}
+	}
+}
+
+// 1/AbsorbedDoseRate -> InverseAbsorbedDoseRate
+/// Dividing a scalar value by a AbsorbedDoseRate unit value returns a value of type InverseAbsorbedDoseRate
+#[cfg(feature="num-complex")]
+impl<T> core::ops::Div<AbsorbedDoseRate<T>> for num_complex::Complex64 where T: NumLike+From<num_complex::Complex64> {
+	type Output = InverseAbsorbedDoseRate<T>;
+	fn div(self, rhs: AbsorbedDoseRate<T>) -> Self::Output {
+		InverseAbsorbedDoseRate{s_per_Gy: T::from(self) / rhs.Gy_per_s}
+	}
+}
+/// Dividing a scalar value by a AbsorbedDoseRate unit value returns a value of type InverseAbsorbedDoseRate
+#[cfg(feature="num-complex")]
+impl<T> core::ops::Div<AbsorbedDoseRate<T>> for &num_complex::Complex64 where T: NumLike+From<num_complex::Complex64> {
+	type Output = InverseAbsorbedDoseRate<T>;
+	fn div(self, rhs: AbsorbedDoseRate<T>) -> Self::Output {
+		InverseAbsorbedDoseRate{s_per_Gy: T::from(self.clone()) / rhs.Gy_per_s}
+	}
+}
+/// Dividing a scalar value by a AbsorbedDoseRate unit value returns a value of type InverseAbsorbedDoseRate
+#[cfg(feature="num-complex")]
+impl<T> core::ops::Div<&AbsorbedDoseRate<T>> for num_complex::Complex64 where T: NumLike+From<num_complex::Complex64> {
+	type Output = InverseAbsorbedDoseRate<T>;
+	fn div(self, rhs: &AbsorbedDoseRate<T>) -> Self::Output {
+		InverseAbsorbedDoseRate{s_per_Gy: T::from(self) / rhs.Gy_per_s.clone()}
+	}
+}
+/// Dividing a scalar value by a AbsorbedDoseRate unit value returns a value of type InverseAbsorbedDoseRate
+#[cfg(feature="num-complex")]
+impl<T> core::ops::Div<&AbsorbedDoseRate<T>> for &num_complex::Complex64 where T: NumLike+From<num_complex::Complex64> {
+	type Output = InverseAbsorbedDoseRate<T>;
+	fn div(self, rhs: &AbsorbedDoseRate<T>) -> Self::Output {
+		InverseAbsorbedDoseRate{s_per_Gy: T::from(self.clone()) / rhs.Gy_per_s.clone()}
+	}
+}
+
+// 1/AbsorbedDoseRate -> InverseAbsorbedDoseRate
+/// Dividing a scalar value by a AbsorbedDoseRate unit value returns a value of type InverseAbsorbedDoseRate
+#[cfg(feature="num-rational")]
+impl<T> core::ops::Div<AbsorbedDoseRate<T>> for num_rational::Rational64 where T: NumLike+From<num_rational::Rational64> {
+	type Output = InverseAbsorbedDoseRate<T>;
+	fn div(self, rhs: AbsorbedDoseRate<T>) -> Self::Output {
+		InverseAbsorbedDoseRate{s_per_Gy: T::from(self) / rhs.Gy_per_s}
+	}
+}
+/// Dividing a scalar value by a AbsorbedDoseRate unit value returns a value of type InverseAbsorbedDoseRate
+#[cfg(feature="num-rational")]
+impl<T> core::ops::Div<AbsorbedDoseRate<T>> for &num_rational::Rational64 where T: NumLike+From<num_rational::Rational64> {
+	type Output = InverseAbsorbedDoseRate<T>;
+	fn div(self, rhs: AbsorbedDoseRate<T>) -> Self::Output {
+		InverseAbsorbedDoseRate{s_per_Gy: T::from(self.clone()) / rhs.Gy_per_s}
+	}
+}
+/// Dividing a scalar value by a AbsorbedDoseRate unit value returns a value of type InverseAbsorbedDoseRate
+#[cfg(feature="num-rational")]
+impl<T> core::ops::Div<&AbsorbedDoseRate<T>> for num_rational::Rational64 where T: NumLike+From<num_rational::Rational64> {
+	type Output = InverseAbsorbedDoseRate<T>;
+	fn div(self, rhs: &AbsorbedDoseRate<T>) -> Self::Output {
+		InverseAbsorbedDoseRate{s_per_Gy: T::from(self) / rhs.Gy_per_s.clone()}
+	}
+}
+/// Dividing a scalar value by a AbsorbedDoseRate unit value returns a value of type InverseAbsorbedDoseRate
+#[cfg(feature="num-rational")]
+impl<T> core::ops::Div<&AbsorbedDoseRate<T>> for &num_rational::Rational64 where T: NumLike+From<num_rational::Rational64> {
+	type Output = InverseAbsorbedDoseRate<T>;
+	fn div(self, rhs: &AbsorbedDoseRate<T>) -> Self::Output {
+		InverseAbsorbedDoseRate{s_per_Gy: T::from(self.clone()) / rhs.Gy_per_s.clone()}
+	}
+}
+
+// 1/AbsorbedDoseRate -> InverseAbsorbedDoseRate
+/// Dividing a scalar value by a AbsorbedDoseRate unit value returns a value of type InverseAbsorbedDoseRate
+#[cfg(feature="num-rational")]
+impl<T> core::ops::Div<AbsorbedDoseRate<T>> for num_rational::BigRational where T: NumLike+From<num_rational::BigRational> {
+	type Output = InverseAbsorbedDoseRate<T>;
+	fn div(self, rhs: AbsorbedDoseRate<T>) -> Self::Output {
+		InverseAbsorbedDoseRate{s_per_Gy: T::from(self) / rhs.Gy_per_s}
+	}
+}
+/// Dividing a scalar value by a AbsorbedDoseRate unit value returns a value of type InverseAbsorbedDoseRate
+#[cfg(feature="num-rational")]
+impl<T> core::ops::Div<AbsorbedDoseRate<T>> for &num_rational::BigRational where T: NumLike+From<num_rational::BigRational> {
+	type Output = InverseAbsorbedDoseRate<T>;
+	fn div(self, rhs: AbsorbedDoseRate<T>) -> Self::Output {
+		InverseAbsorbedDoseRate{s_per_Gy: T::from(self.clone()) / rhs.Gy_per_s}
+	}
+}
+/// Dividing a scalar value by a AbsorbedDoseRate unit value returns a value of type InverseAbsorbedDoseRate
+#[cfg(feature="num-rational")]
+impl<T> core::ops::Div<&AbsorbedDoseRate<T>> for num_rational::BigRational where T: NumLike+From<num_rational::BigRational> {
+	type Output = InverseAbsorbedDoseRate<T>;
+	fn div(self, rhs: &AbsorbedDoseRate<T>) -> Self::Output {
+		InverseAbsorbedDoseRate{s_per_Gy: T::from(self) / rhs.Gy_per_s.clone()}
+	}
+}
+/// Dividing a scalar value by a AbsorbedDoseRate unit value returns a value of type InverseAbsorbedDoseRate
+#[cfg(feature="num-rational")]
+impl<T> core::ops::Div<&AbsorbedDoseRate<T>> for &num_rational::BigRational where T: NumLike+From<num_rational::BigRational> {
+	type Output = InverseAbsorbedDoseRate<T>;
+	fn div(self, rhs: &AbsorbedDoseRate<T>) -> Self::Output {
+		InverseAbsorbedDoseRate{s_per_Gy: T::from(self.clone()) / rhs.Gy_per_s.clone()}
+	}
+}
+
+/// The radiation dose equivalent unit type, defined as sieverts in SI units
+#[derive(UnitStruct, Debug, Clone, Default)]
+#[si(no_display)]
+#[cfg_attr(all(feature="serde", not(feature="serde-unit-tag")), derive(Serialize, Deserialize))]
+#[repr(transparent)]
+pub struct DoseEquivalent<T: NumLike>{
+	/// The value of this Dose equivalent in sieverts
+	pub Sv: T
+}
+
+impl<T> DoseEquivalent<T> where T: NumLike {
+
+	/// Returns the standard unit name of dose equivalent: "sieverts"
+	pub fn unit_name() -> &'static str { "sieverts" }
+	
+	/// Returns the abbreviated name or symbol of dose equivalent: "Sv" for sieverts
+	pub fn unit_symbol() -> &'static str { "Sv" }
+
+	/// Returns the exponents of the SI base units of dose equivalent (m^2/s^2)
+	pub const fn dimension() -> super::Dimension { super::Dimension{length: 2, mass: 0, time: -2, current: 0, temperature: 0, amount: 0, luminosity: 0} }
+
+	/// Returns a wrapper that formats this dose equivalent value with the full unit name instead of 
+	/// the unit symbol, eg "5 sieverts" (the unit name is not singularized for values of 1)
+	pub fn display_named(&self) -> super::NamedDisplay<'_, T> {
+		super::NamedDisplay::new(&self.Sv, Self::unit_name())
+	}
+
+	/// Returns a new dose equivalent value from the given number of sieverts (same as 
+	/// `from_Sv(...)`), for any number-like type. This is useful in generic code 
+	/// that works with several unit types
+	///
+	/// # Arguments
+	/// * `value` - Any number-like type, representing a quantity of sieverts
+	pub const fn new(value: T) -> Self { DoseEquivalent{Sv: value} }
+
+	/// Returns a new dose equivalent value from the given number of sieverts. Unlike
+	/// `from_Sv(...)`, this is a `const fn`, so it can be used to declare `const` and `static` values
+	///
+	/// # Arguments
+	/// * `Sv` - Any number-like type, representing a quantity of sieverts
+	pub const fn from_Sv_const(Sv: T) -> Self { DoseEquivalent{Sv} }
+
+	/// Returns a dose equivalent value with a different number type, by applying the 
+	/// given function to the value in sieverts (eg `.map(|x| x as f32)` to convert 
+	/// from `f64` to `f32`)
+	///
+	/// # Arguments
+	/// * `f` - Function that converts the number of sieverts to the new number type
+	pub fn map<U, F>(self, f: F) -> DoseEquivalent<U> where U: NumLike, F: FnOnce(T) -> U {
+		DoseEquivalent{Sv: f(self.Sv)}
+	}
+
+	/// Returns a dose equivalent value with a different number type, converted 
+	/// losslessly with the `From` trait (eg from `f32` to `f64`)
+	pub fn cast<U>(self) -> DoseEquivalent<U> where U: NumLike+From<T> {
+		DoseEquivalent{Sv: U::from(self.Sv)}
+	}
+
+	/// Returns a reference to the value of this dose equivalent in sieverts
+	pub fn as_raw(&self) -> &T {
+		&self.Sv
+	}
+
+	/// Returns a mutable reference to the value of this dose equivalent in sieverts, 
+	/// eg for passing to numeric libraries that modify values in place. The number 
+	/// is always in sieverts, so changing it directly does not change the unit
+	pub fn raw_mut(&mut self) -> &mut T {
+		&mut self.Sv
+	}
+
+	/// Returns a slice of numbers in sieverts as a slice of dose equivalent values, 
+	/// without copying
+	///
+	/// # Arguments
+	/// * `values` - Slice of numbers, representing quantities of sieverts
+	pub fn wrap_slice(values: &[T]) -> &[Self] {
+		// SAFETY: DoseEquivalent<T> is #[repr(transparent)], so it has the same memory layout as T
+		unsafe { core::slice::from_raw_parts(values.as_ptr() as *const Self, values.len()) }
+	}
+
+	/// Returns a mutable slice of numbers in sieverts as a mutable slice of 
+	/// dose equivalent values, without copying
+	///
+	/// # Arguments
+	/// * `values` - Slice of numbers, representing quantities of sieverts
+	pub fn wrap_slice_mut(values: &mut [T]) -> &mut [Self] {
+		// SAFETY: DoseEquivalent<T> is #[repr(transparent)], so it has the same memory layout as T
+		unsafe { core::slice::from_raw_parts_mut(values.as_mut_ptr() as *mut Self, values.len()) }
+	}
+
+	/// Returns the ratio of this dose equivalent value to another dose equivalent value 
+	/// (same as `self / other`). Dividing a unit value by another value of the same 
+	/// unit type cancels the units, so the ratio is a dimensionless number
+	///
+	/// # Arguments
+	/// * `other` - The dose equivalent value to divide by
+	pub fn ratio(self, other: Self) -> T {
+		self.Sv / other.Sv
+	}
+
+	/// Returns the ratio of this dose equivalent value to another dose equivalent value 
+	/// as a `Dimensionless` value, instead of a bare number like `ratio(...)` does
+	///
+	/// # Arguments
+	/// * `other` - The dose equivalent value to divide by
+	pub fn dimensionless_ratio(self, other: Self) -> super::base::Dimensionless<T> {
+		super::base::Dimensionless{value: self.Sv / other.Sv}
+	}
+
+	/// Returns this dose equivalent value multiplied by a dimensionless factor 
+	/// (same as `self * factor`)
+	///
+	/// # Arguments
+	/// * `factor` - The number to multiply by
+	pub fn scale(self, factor: T) -> Self {
+		DoseEquivalent{Sv: self.Sv * factor}
+	}
+	
+	/// Returns a new dose equivalent value from the given number of sieverts
+	///
+	/// # Arguments
+	/// * `Sv` - Any number-like type, representing a quantity of sieverts
+	pub fn from_Sv(Sv: T) -> Self { DoseEquivalent{Sv: Sv} }
+	
+	/// Returns a copy of this dose equivalent value in sieverts
+	pub fn to_Sv(&self) -> T { self.Sv.clone() }
+
+	/// Returns a new dose equivalent value from the given number of sieverts
+	///
+	/// # Arguments
+	/// * `sieverts` - Any number-like type, representing a quantity of sieverts
+	pub fn from_sieverts(sieverts: T) -> Self { DoseEquivalent{Sv: sieverts} }
+	
+	/// Returns a copy of this dose equivalent value in sieverts
+	pub fn to_sieverts(&self) -> T { self.Sv.clone() }
+
+}
+
+/// Formats dose equivalent values as a number followed by its unit symbol, eg "1500 Sv"
+/// (complex numbers are wrapped in parentheses, eg "(1+2i) Sv").
+/// The alternate flag (`{:#}`) scales the number by the nearest SI prefix where the unit symbol allows it
+impl<T> fmt::Display for DoseEquivalent<T> where T: NumLike {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		if f.alternate() {
+			return super::fmt_with_si_prefix(f, &self.Sv, Self::unit_symbol(), true);
+		}
+		super::fmt_unit_value(f, &self.Sv, Self::unit_symbol())
+	}
+}
+
+/// Formats dose equivalent values in exponential notation followed by the unit symbol, eg "1.5e3 Sv"
+impl<T> fmt::LowerExp for DoseEquivalent<T> where T: NumLike+fmt::LowerExp {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		fmt::LowerExp::fmt(&self.Sv, f)?;
+		write!(f, " {}", Self::unit_symbol())
+	}
+}
+
+/// Formats dose equivalent values in exponential notation followed by the unit symbol, eg "1.5E3 Sv"
+impl<T> fmt::UpperExp for DoseEquivalent<T> where T: NumLike+fmt::UpperExp {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		fmt::UpperExp::fmt(&self.Sv, f)?;
+		write!(f, " {}", Self::unit_symbol())
+	}
+}
+
+impl<T> super::SiUnit for DoseEquivalent<T> where T: NumLike {
+	type Number = T;
+	fn unit_name(&self) -> &'static str { Self::unit_name() }
+	fn unit_symbol(&self) -> &'static str { Self::unit_symbol() }
+	fn raw(&self) -> &T { &self.Sv }
+	fn dimension(&self) -> super::Dimension { Self::dimension() }
+	fn from_raw(value: T) -> Self { DoseEquivalent{Sv: value} }
+	fn into_raw(self) -> T { self.Sv }
+}
+
+#[cfg(feature="alloc")]
+impl<T> DoseEquivalent<T> where T: NumLike {
+	/// Returns this dose equivalent value in the canonical text format "<value> <symbol>", eg "5 Sv".
+	/// Unlike `Display`, this format never changes with precision or alternate flags, and it is the
+	/// input format expected by `from_str(...)`, so `x.canonical_string().parse()` returns the same value
+	pub fn canonical_string(&self) -> alloc::string::String {
+		alloc::format!("{} {}", self.Sv, Self::unit_symbol())
+	}
+
+	/// Returns a `Vec` of dose equivalent values copied from the given slice of numbers
+	///
+	/// # Arguments
+	/// * `values` - Slice of numbers, representing quantities of sieverts
+	pub fn from_Sv_slice(values: &[T]) -> alloc::vec::Vec<Self> {
+		values.iter().map(|x| DoseEquivalent{Sv: x.clone()}).collect()
+	}
+}
+
+/// Parses a dose equivalent value from a number followed by its unit symbol, eg "5 Sv"
+impl<T> core::str::FromStr for DoseEquivalent<T> where T: NumLike+core::str::FromStr {
+	type Err = SiParseError;
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		Ok(DoseEquivalent{Sv: super::parse_unit_value(s, Self::unit_symbol())?})
+	}
+}
+
+/// Parses a dose equivalent value from a number followed by its unit symbol, eg "5 Sv" 
+/// (same as `str::parse()`)
+impl<T> core::convert::TryFrom<&str> for DoseEquivalent<T> where T: NumLike+core::str::FromStr {
+	type Error = SiParseError;
+	fn try_from(s: &str) -> Result<Self, Self::Error> {
+		<Self as core::str::FromStr>::from_str(s)
+	}
+}
+
+#[cfg(feature="num-traits")]
+impl<T> DoseEquivalent<T> where T: NumLike+num_traits::Euclid {
+
+	/// Returns the Euclidean remainder of dividing this dose equivalent value by another, 
+	/// which is never negative (requires the `num-traits` feature)
+	///
+	/// # Arguments
+	/// * `rhs` - The dose equivalent value to divide by
+	pub fn rem_euclid(self, rhs: Self) -> Self {
+		DoseEquivalent{Sv: self.Sv.rem_euclid(&rhs.Sv)}
+	}
+
+	/// Returns the Euclidean quotient of dividing this dose equivalent value by another, 
+	/// ie the whole number of times that `rhs` fits into this value, rounded such that 
+	/// `rem_euclid(...)` is never negative (requires the `num-traits` feature)
+	///
+	/// # Arguments
+	/// * `rhs` - The dose equivalent value to divide by
+	pub fn div_euclid(self, rhs: Self) -> T {
+		self.Sv.div_euclid(&rhs.Sv)
+	}
+}
+
+/// Summing dose equivalent values returns their total as a dose equivalent value (or zero if there are no values)
+impl<T> core::iter::Sum<DoseEquivalent<T>> for DoseEquivalent<T> where T: NumLike+core::iter::Sum {
+	fn sum<I: Iterator<Item=DoseEquivalent<T>>>(iter: I) -> Self {
+		DoseEquivalent{Sv: iter.map(|x| x.Sv).sum()}
+	}
+}
+/// Summing dose equivalent values returns their total as a dose equivalent value (or zero if there are no values)
+impl<'a, T> core::iter::Sum<&'a DoseEquivalent<T>> for DoseEquivalent<T> where T: NumLike+core::iter::Sum+'a {
+	fn sum<I: Iterator<Item=&'a DoseEquivalent<T>>>(iter: I) -> Self {
+		DoseEquivalent{Sv: iter.map(|x| x.Sv.clone()).sum()}
+	}
+}
+
+impl<T> DoseEquivalent<T> where T: NumLike+From<f64> {
+
+	/// Returns the weighted average of the given dose equivalent values, where each 
+	/// value is paired with its weight (weights do not need to add up to 1). If the 
+	/// total weight is zero (including when `values` is empty), then zero is returned
+	///
+	/// # Arguments
+	/// * `values` - Slice of (dose equivalent value, weight) pairs
+	pub fn weighted_mean(values: &[(Self, f64)]) -> Self {
+		let total_weight: f64 = values.iter().map(|(_, weight)| *weight).sum();
+		if total_weight == 0.0 {
+			return DoseEquivalent{Sv: T::from(0.0)};
+		}
+		let total = values.iter().fold(T::from(0.0),
+			|total, (value, weight)| total + value.Sv.clone() * T::from(*weight));
+		DoseEquivalent{Sv: total / T::from(total_weight)}
+	}
+}
+
+impl<T> DoseEquivalent<T> where T: NumLike+PartialEq+From<u8> {
+
+	/// Divides this dose equivalent value by another unit value (eg `distance.checked_div(time)` 
+	/// returns a velocity), returning `None` if the divisor is zero instead of producing 
+	/// infinity or NaN (or panicking, for integer number types). This works for every unit 
+	/// type that this dose equivalent value can be divided by with the `/` operator
+	///
+	/// # Arguments
+	/// * `rhs` - The unit value to divide by
+	pub fn checked_div<R>(self, rhs: R) -> Option<<Self as core::ops::Div<R>>::Output> 
+		where Self: core::ops::Div<R>, R: super::SiUnit<Number=T>
+	{
+		if *rhs.raw() == T::from(0u8) {
+			return None;
+		}
+		Some(self / rhs)
+	}
+}
+
+/// Multiplying a dose equivalent value by a dimensionless value returns a dose equivalent value
+impl<T> core::ops::Mul<super::base::Dimensionless<T>> for DoseEquivalent<T> where T: NumLike {
+	type Output = DoseEquivalent<T>;
+	fn mul(self, rhs: super::base::Dimensionless<T>) -> Self::Output {
+		DoseEquivalent{Sv: self.Sv * rhs.value}
+	}
+}
+/// Multiplying a dimensionless value by a dose equivalent value returns a dose equivalent value
+impl<T> core::ops::Mul<DoseEquivalent<T>> for super::base::Dimensionless<T> where T: NumLike {
+	type Output = DoseEquivalent<T>;
+	fn mul(self, rhs: DoseEquivalent<T>) -> Self::Output {
+		DoseEquivalent{Sv: self.value * rhs.Sv}
+	}
+}
+/// Dividing a dose equivalent value by a dimensionless value returns a dose equivalent value
+impl<T> core::ops::Div<super::base::Dimensionless<T>> for DoseEquivalent<T> where T: NumLike {
+	type Output = DoseEquivalent<T>;
+	fn div(self, rhs: super::base::Dimensionless<T>) -> Self::Output {
+		DoseEquivalent{Sv: self.Sv / rhs.value}
+	}
+}
+
+/// The remainder of dividing a dose equivalent value by another dose equivalent value is a dose equivalent value
+impl<T> core::ops::Rem<DoseEquivalent<T>> for DoseEquivalent<T> where T: NumLike+core::ops::Rem<Output=T> {
+	type Output = DoseEquivalent<T>;
+	fn rem(self, rhs: DoseEquivalent<T>) -> Self::Output {
+		DoseEquivalent{Sv: self.Sv % rhs.Sv}
+	}
+}
+/// The remainder of dividing a dose equivalent value by another dose equivalent value is a dose equivalent value
+impl<T> core::ops::Rem<&DoseEquivalent<T>> for DoseEquivalent<T> where T: NumLike+core::ops::Rem<Output=T> {
+	type Output = DoseEquivalent<T>;
+	fn rem(self, rhs: &DoseEquivalent<T>) -> Self::Output {
+		DoseEquivalent{Sv: self.Sv % rhs.Sv.clone()}
+	}
+}
+/// The remainder of dividing a dose equivalent value by another dose equivalent value is a dose equivalent value
+impl<T> core::ops::Rem<DoseEquivalent<T>> for &DoseEquivalent<T> where T: NumLike+core::ops::Rem<Output=T> {
+	type Output = DoseEquivalent<T>;
+	fn rem(self, rhs: DoseEquivalent<T>) -> Self::Output {
+		DoseEquivalent{Sv: self.Sv.clone() % rhs.Sv}
+	}
+}
+/// The remainder of dividing a dose equivalent value by another dose equivalent value is a dose equivalent value
+impl<T> core::ops::Rem<&DoseEquivalent<T>> for &DoseEquivalent<T> where T: NumLike+core::ops::Rem<Output=T> {
+	type Output = DoseEquivalent<T>;
+	fn rem(self, rhs: &DoseEquivalent<T>) -> Self::Output {
+		DoseEquivalent{Sv: self.Sv.clone() % rhs.Sv.clone()}
+	}
+}
+
+/// Serializes a dose equivalent value together with its unit symbol, eg 
+/// `{"value": 1.5, "unit": "Sv"}` (requires the `serde-unit-tag` feature)
+#[cfg(feature="serde-unit-tag")]
+impl<T> serde::Serialize for DoseEquivalent<T> where T: NumLike+serde::Serialize {
+	fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+		super::serialize_with_unit_tag(&self.Sv, Self::unit_symbol(), serializer)
+	}
+}
+/// Deserializes a dose equivalent value from a value and unit symbol, eg 
+/// `{"value": 1.5, "unit": "Sv"}`, returning an error if the unit symbol 
+/// does not match (requires the `serde-unit-tag` feature)
+#[cfg(feature="serde-unit-tag")]
+impl<'de, T> serde::Deserialize<'de> for DoseEquivalent<T> where T: NumLike+serde::Deserialize<'de> {
+	fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+		Ok(DoseEquivalent{Sv: super::deserialize_with_unit_tag(Self::unit_symbol(), deserializer)?})
+	}
+}
+
+/// The additive identity of dose equivalent values is zero sieverts (requires the `num-traits` feature)
+#[cfg(feature="num-traits")]
+impl<T> num_traits::Zero for DoseEquivalent<T> where T: NumLike+num_traits::Zero {
+	fn zero() -> Self { DoseEquivalent{Sv: T::zero()} }
+	fn is_zero(&self) -> bool { self.Sv.is_zero() }
+}
+
+#[cfg(feature="num-traits")]
+impl<T> DoseEquivalent<T> where T: NumLike+num_traits::Signed {
+
+	/// Returns the sign of this dose equivalent value as a dimensionless number: 1 if it is 
+	/// positive, -1 if it is negative, and 0 if it is zero for integer number types 
+	/// (for floating-point number types, +0.0 returns 1.0, -0.0 returns -1.0, and NaN returns 
+	/// NaN) (requires the `num-traits` feature)
+	pub fn signum(self) -> T {
+		self.Sv.signum()
+	}
+}
+
+impl<T> DoseEquivalent<T> where T: NumLike+PartialOrd {
+
+	/// Returns the absolute value of this dose equivalent value, ie its magnitude without a minus sign
+	/// (for floating-point number types, -0.0 returns +0.0)
+	pub fn abs(self) -> Self {
+		let negated = -self.Sv.clone();
+		if self.Sv < negated {
+			DoseEquivalent{Sv: negated}
+		} else if self.Sv == negated {
+			// zero, which is its own negation (adding +0.0 turns -0.0 into +0.0)
+			DoseEquivalent{Sv: negated + self.Sv}
+		} else {
+			self
+		}
+	}
+
+	/// Returns the lesser of this dose equivalent value and the given dose equivalent value
+	///
+	/// # Arguments
+	/// * `other` - The dose equivalent value to compare with this one
+	pub fn min(self, other: Self) -> Self {
+		if other.Sv < self.Sv { other } else { self }
+	}
+
+	/// Returns the greater of this dose equivalent value and the given dose equivalent value
+	///
+	/// # Arguments
+	/// * `other` - The dose equivalent value to compare with this one
+	pub fn max(self, other: Self) -> Self {
+		if other.Sv > self.Sv { other } else { self }
+	}
+
+	/// Returns the least of the given dose equivalent values, or `None` if there are none. 
+	/// Unlike `Iterator::min()`, this does not require `Ord`, so it works for 
+	/// floating-point numbers. NaN values are skipped (so the result is `None` if all 
+	/// values are NaN), and the first of several equal values is returned
+	///
+	/// # Arguments
+	/// * `values` - Any iterator or collection of dose equivalent values
+	pub fn min_of<I>(values: I) -> Option<Self> where I: IntoIterator<Item=Self> {
+		values.into_iter().filter(|x| x.Sv.partial_cmp(&x.Sv).is_some()).reduce(Self::min)
+	}
+
+	/// Returns the greatest of the given dose equivalent values, or `None` if there are none. 
+	/// Unlike `Iterator::max()`, this does not require `Ord`, so it works for 
+	/// floating-point numbers. NaN values are skipped (so the result is `None` if all 
+	/// values are NaN), and the first of several equal values is returned
+	///
+	/// # Arguments
+	/// * `values` - Any iterator or collection of dose equivalent values
+	pub fn max_of<I>(values: I) -> Option<Self> where I: IntoIterator<Item=Self> {
+		values.into_iter().filter(|x| x.Sv.partial_cmp(&x.Sv).is_some()).reduce(Self::max)
+	}
+
+	/// Returns this dose equivalent value restricted to the range from `lo` to `hi`
+	///
+	/// # Arguments
+	/// * `lo` - The minimum dose equivalent value
+	/// * `hi` - The maximum dose equivalent value
+	///
+	/// # Panics
+	/// Panics if `lo` is greater than `hi` (or if either of them is NaN)
+	pub fn clamp(self, lo: Self, hi: Self) -> Self {
+		assert!(lo.Sv <= hi.Sv, "lo must be less than or equal to hi");
+		if self.Sv < lo.Sv { lo } else if self.Sv > hi.Sv { hi } else { self }
+	}
+
+	/// Returns this dose equivalent value with its magnitude capped at `max`, keeping its 
+	/// sign (eg for capping a speed), or this value unchanged if its magnitude does not 
+	/// exceed `max`. For complex-valued dose equivalent, use `clamp_norm(...)` instead, 
+	/// which caps the magnitude while keeping the phase
+	///
+	/// # Arguments
+	/// * `max` - The maximum magnitude (should not be negative)
+	pub fn clamp_magnitude(self, max: Self) -> Self {
+		let negated_max = -max.Sv.clone();
+		if self.Sv > max.Sv { 
+			max 
+		} else if self.Sv < negated_max { 
+			DoseEquivalent{Sv: negated_max} 
+		} else { 
+			self 
+		}
+	}
+
+	/// Returns true if this dose equivalent value differs from the given dose equivalent 
+	/// value by no more than the given absolute tolerance (which is also a 
+	/// dose equivalent value, so the comparison is dimensionally safe). NaN is never 
+	/// equal to anything. See also the `assert_unit_eq!(...)` macro.
+	///
+	/// # Arguments
+	/// * `other` - The dose equivalent value to compare with this one
+	/// * `tolerance` - The largest allowed difference between the two values
+	pub fn eq_within(self, other: Self, tolerance: Self) -> bool {
+		let diff = self.Sv - other.Sv;
+		diff <= tolerance.Sv && -diff <= tolerance.Sv
+	}
+}
+
+#[cfg(feature="num-complex")]
+impl DoseEquivalent<num_complex::Complex32> {
+	/// Returns the magnitude (aka norm or absolute value) of this complex dose equivalent 
+	/// value as a real-valued dose equivalent (requires the `num-complex` feature)
+	pub fn magnitude(self) -> DoseEquivalent<f32> {
+		DoseEquivalent{Sv: self.Sv.norm()}
+	}
+
+	/// Returns this complex dose equivalent value scaled down so that its magnitude is 
+	/// at most `max` (keeping its phase), or this value unchanged if its magnitude does 
+	/// not exceed `max`. This is the complex equivalent of `clamp_magnitude(...)` (requires 
+	/// the `num-complex` feature)
+	///
+	/// # Arguments
+	/// * `max` - The maximum magnitude (should not be negative)
+	pub fn clamp_norm(self, max: DoseEquivalent<f32>) -> Self {
+		let norm = self.Sv.norm();
+		if norm > max.Sv {
+			DoseEquivalent{Sv: self.Sv * (max.Sv / norm)}
+		} else {
+			self
+		}
+	}
+}
+
+#[cfg(feature="num-complex")]
+impl DoseEquivalent<num_complex::Complex64> {
+	/// Returns the magnitude (aka norm or absolute value) of this complex dose equivalent 
+	/// value as a real-valued dose equivalent (requires the `num-complex` feature)
+	pub fn magnitude(self) -> DoseEquivalent<f64> {
+		DoseEquivalent{Sv: self.Sv.norm()}
+	}
+
+	/// Returns this complex dose equivalent value scaled down so that its magnitude is 
+	/// at most `max` (keeping its phase), or this value unchanged if its magnitude does 
+	/// not exceed `max`. This is the complex equivalent of `clamp_magnitude(...)` (requires 
+	/// the `num-complex` feature)
+	///
+	/// # Arguments
+	/// * `max` - The maximum magnitude (should not be negative)
+	pub fn clamp_norm(self, max: DoseEquivalent<f64>) -> Self {
+		let norm = self.Sv.norm();
+		if norm > max.Sv {
+			DoseEquivalent{Sv: self.Sv * (max.Sv / norm)}
+		} else {
+			self
+		}
+	}
+}
+
+/// Rounding and other floating-point functions (requires the `num-traits` feature, 
+/// and either the `std` or `libm` feature)
+#[cfg(all(feature="num-traits", any(feature="std", feature="libm")))]
+impl<T> DoseEquivalent<T> where T: NumLike+num_traits::Float {
+
+	/// Returns this dose equivalent value rounded to the nearest whole number of sieverts 
+	/// (half-way cases are rounded away from zero)
+	pub fn round(self) -> Self {
+		DoseEquivalent{Sv: self.Sv.round()}
+	}
+
+	/// Returns this dose equivalent value rounded down to a whole number of sieverts
+	pub fn floor(self) -> Self {
+		DoseEquivalent{Sv: self.Sv.floor()}
+	}
+
+	/// Returns this dose equivalent value rounded up to a whole number of sieverts
+	pub fn ceil(self) -> Self {
+		DoseEquivalent{Sv: self.Sv.ceil()}
+	}
+
+	/// Returns this dose equivalent value rounded towards zero to a whole number of sieverts
+	pub fn trunc(self) -> Self {
+		DoseEquivalent{Sv: self.Sv.trunc()}
+	}
+
+	/// Returns this dose equivalent value rounded to the nearest multiple of the given step 
+	/// (half-way cases are rounded away from zero)
+	///
+	/// # Arguments
+	/// * `step` - The dose equivalent value to round to a multiple of
+	pub fn round_to(self, step: Self) -> Self {
+		DoseEquivalent{Sv: (self.Sv / step.Sv).round() * step.Sv}
+	}
+
+	/// Fused multiply-add: returns `self * a + b` computed with only one rounding 
+	/// error, which is more accurate (and on some hardware faster) than a separate 
+	/// multiplication and addition
+	///
+	/// # Arguments
+	/// * `a` - The scalar value to multiply this dose equivalent value by
+	/// * `b` - The dose equivalent value to add to the product
+	pub fn mul_add(self, a: T, b: Self) -> Self {
+		DoseEquivalent{Sv: self.Sv.mul_add(a, b.Sv)}
+	}
+
+	/// Returns true if this dose equivalent value is neither infinite nor NaN
+	pub fn is_finite(&self) -> bool {
+		self.Sv.is_finite()
+	}
+
+	/// Returns true if this dose equivalent value is positive or negative infinity
+	pub fn is_infinite(&self) -> bool {
+		self.Sv.is_infinite()
+	}
+
+	/// Returns true if this dose equivalent value is NaN (not a number)
+	pub fn is_nan(&self) -> bool {
+		self.Sv.is_nan()
+	}
+
+	/// Returns true if this dose equivalent value has a positive sign, including +0.0 
+	/// and positive infinity
+	pub fn is_sign_positive(&self) -> bool {
+		self.Sv.is_sign_positive()
+	}
+
+	/// Returns true if this dose equivalent value has a negative sign, including -0.0 
+	/// and negative infinity
+	pub fn is_sign_negative(&self) -> bool {
+		self.Sv.is_sign_negative()
+	}
+
+	/// Returns a dose equivalent value with the magnitude of this one and the sign of 
+	/// `sign_source`, eg for reflecting a value off of a surface
+	///
+	/// # Arguments
+	/// * `sign_source` - The dose equivalent value whose sign is copied
+	pub fn copysign(self, sign_source: Self) -> Self {
+		DoseEquivalent{Sv: self.Sv.copysign(sign_source.Sv)}
+	}
+}
+
+#[cfg(feature="num-traits")]
+impl<T> DoseEquivalent<T> where T: NumLike+num_traits::CheckedAdd+num_traits::CheckedSub {
+
+	/// Adds another dose equivalent value to this one, returning `None` if the 
+	/// addition overflows the number type (requires the `num-traits` feature)
+	pub fn checked_add(self, rhs: Self) -> Option<Self> {
+		Some(DoseEquivalent{Sv: self.Sv.checked_add(&rhs.Sv)?})
+	}
+
+	/// Subtracts another dose equivalent value from this one, returning `None` if 
+	/// the subtraction overflows the number type (requires the `num-traits` feature)
+	pub fn checked_sub(self, rhs: Self) -> Option<Self> {
+		Some(DoseEquivalent{Sv: self.Sv.checked_sub(&rhs.Sv)?})
+	}
+}
+
+#[cfg(feature="num-traits")]
+impl<T> DoseEquivalent<T> where T: NumLike+num_traits::SaturatingAdd+num_traits::SaturatingSub {
+
+	/// Adds another dose equivalent value to this one, clamping the result to the 
+	/// minimum or maximum value of the number type instead of overflowing 
+	/// (requires the `num-traits` feature)
+	pub fn saturating_add(self, rhs: Self) -> Self {
+		DoseEquivalent{Sv: self.Sv.saturating_add(&rhs.Sv)}
+	}
+
+	/// Subtracts another dose equivalent value from this one, clamping the result 
+	/// to the minimum or maximum value of the number type instead of overflowing 
+	/// (requires the `num-traits` feature)
+	pub fn saturating_sub(self, rhs: Self) -> Self {
+		DoseEquivalent{Sv: self.Sv.saturating_sub(&rhs.Sv)}
+	}
+}
+
+impl<T> DoseEquivalent<T> where T: NumLike+Into<f64> {
+
+	/// Returns true if this dose equivalent value is equal to the given dose equivalent 
+	/// value to within the given number of significant figures (eg 6 significant 
+	/// figures allows a relative difference of up to 1 part per million). NaN is 
+	/// only approximately equal to NaN, infinity is only approximately equal to 
+	/// infinity of the same sign, and zero is only approximately equal to zero.
+	///
+	/// # Arguments
+	/// * `other` - The dose equivalent value to compare with this one
+	/// * `sig_figs` - The number of significant figures that must match
+	pub fn approx_eq(self, other: Self, sig_figs: i32) -> bool {
+		super::approx_eq_f64(self.Sv.into(), other.Sv.into(), sig_figs)
+	}
+}
+
+impl<T> DoseEquivalent<T> where T: NumLike+From<f64> {
+
+	/// Returns the linear interpolation between this dose equivalent value and the given 
+	/// dose equivalent value, ie `self*(1-t) + other*t`. The interpolation factor is not 
+	/// clamped, so values of `t` outside the range 0 to 1 extrapolate beyond the two 
+	/// values (see `lerp_clamped(...)`).
+	///
+	/// # Arguments
+	/// * `other` - The dose equivalent value to interpolate towards (returned when `t` is 1)
+	/// * `t` - The interpolation factor, where 0 returns this value and 1 returns `other`
+	pub fn lerp(self, other: Self, t: f64) -> Self {
+		DoseEquivalent{Sv: self.Sv * T::from(1.0 - t) + other.Sv * T::from(t)}
+	}
+
+	/// Same as `lerp(...)`, except that the interpolation factor `t` is first clamped 
+	/// to the range 0 to 1, so that the result is always between the two values
+	///
+	/// # Arguments
+	/// * `other` - The dose equivalent value to interpolate towards (returned when `t` is 1 or more)
+	/// * `t` - The interpolation factor, where 0 returns this value and 1 returns `other`
+	pub fn lerp_clamped(self, other: Self, t: f64) -> Self {
+		self.lerp(other, t.clamp(0.0, 1.0))
+	}
+	
+	/// Returns a copy of this dose equivalent value in millisieverts
+	/// 
+	/// *Note: This method is not available for `f32` and other number types lacking the `From<f64>` trait*
+	pub fn to_mSv(&self) -> T {
+		return self.Sv.clone() * T::from(1000.0_f64);
+	}
+
+	/// Returns a new dose equivalent value from the given number of millisieverts
+	/// 
+	/// *Note: This method is not available for `f32` and other number types lacking the `From<f64>` trait*
+	///
+	/// # Arguments
+	/// * `mSv` - Any number-like type, representing a quantity of millisieverts
+	pub fn from_mSv(mSv: T) -> Self {
+		DoseEquivalent{Sv: mSv * T::from(0.001_f64)}
+	}
+
+	/// Returns a copy of this dose equivalent value in microsieverts
+	/// 
+	/// *Note: This method is not available for `f32` and other number types lacking the `From<f64>` trait*
+	pub fn to_uSv(&self) -> T {
+		return self.Sv.clone() * T::from(1000000.0_f64);
+	}
+
+	/// Returns a new dose equivalent value from the given number of microsieverts
+	/// 
+	/// *Note: This method is not available for `f32` and other number types lacking the `From<f64>` trait*
+	///
+	/// # Arguments
+	/// * `uSv` - Any number-like type, representing a quantity of microsieverts
+	pub fn from_uSv(uSv: T) -> Self {
+		DoseEquivalent{Sv: uSv * T::from(1e-06_f64)}
+	}
+
+	/// Returns a copy of this dose equivalent value in nanosieverts
+	/// 
+	/// *Note: This method is not available for `f32` and other number types lacking the `From<f64>` trait*
+	pub fn to_nSv(&self) -> T {
+		return self.Sv.clone() * T::from(1000000000.0_f64);
+	}
+
+	/// Returns a new dose equivalent value from the given number of nanosieverts
+	/// 
+	/// *Note: This method is not available for `f32` and other number types lacking the `From<f64>` trait*
+	///
+	/// # Arguments
+	/// * `nSv` - Any number-like type, representing a quantity of nanosieverts
+	pub fn from_nSv(nSv: T) -> Self {
+		DoseEquivalent{Sv: nSv * T::from(1e-09_f64)}
+	}
+
+	/// Returns a copy of this dose equivalent value in kilosieverts
+	/// 
+	/// *Note: This method is not available for `f32` and other number types lacking the `From<f64>` trait*
+	pub fn to_kSv(&self) -> T {
+		return self.Sv.clone() * T::from(0.001_f64);
+	}
+
+	/// Returns a new dose equivalent value from the given number of kilosieverts
+	/// 
+	/// *Note: This method is not available for `f32` and other number types lacking the `From<f64>` trait*
+	///
+	/// # Arguments
+	/// * `kSv` - Any number-like type, representing a quantity of kilosieverts
+	pub fn from_kSv(kSv: T) -> Self {
+		DoseEquivalent{Sv: kSv * T::from(1000.0_f64)}
+	}
+
+	/// Returns a copy of this dose equivalent value in megasieverts
+	/// 
+	/// *Note: This method is not available for `f32` and other number types lacking the `From<f64>` trait*
+	pub fn to_MSv(&self) -> T {
+		return self.Sv.clone() * T::from(1e-06_f64);
+	}
+
+	/// Returns a new dose equivalent value from the given number of megasieverts
+	/// 
+	/// *Note: This method is not available for `f32` and other number types lacking the `From<f64>` trait*
+	///
+	/// # Arguments
+	/// * `MSv` - Any number-like type, representing a quantity of megasieverts
+	pub fn from_MSv(MSv: T) -> Self {
+		DoseEquivalent{Sv: MSv * T::from(1000000.0_f64)}
+	}
+
+	/// Returns a copy of this dose equivalent value in gigasieverts
+	/// 
+	/// *Note: This method is not available for `f32` and other number types lacking the `From<f64>` trait*
+	pub fn to_GSv(&self) -> T {
+		return self.Sv.clone() * T::from(1e-09_f64);
+	}
+
+	/// Returns a new dose equivalent value from the given number of gigasieverts
+	/// 
+	/// *Note: This method is not available for `f32` and other number types lacking the `From<f64>` trait*
+	///
+	/// # Arguments
+	/// * `GSv` - Any number-like type, representing a quantity of gigasieverts
+	pub fn from_GSv(GSv: T) -> Self {
+		DoseEquivalent{Sv: GSv * T::from(1000000000.0_f64)}
+	}
+
+	/// Returns a copy of this dose equivalent value in roentgen equivalent man
+	/// 
+	/// *Note: This method is not available for `f32` and other number types lacking the `From<f64>` trait*
+	pub fn to_rem(&self) -> T {
+		return self.Sv.clone() * T::from(100.0_f64);
+	}
+
+	/// Returns a new dose equivalent value from the given number of roentgen equivalent man
+	/// 
+	/// *Note: This method is not available for `f32` and other number types lacking the `From<f64>` trait*
+	///
+	/// # Arguments
+	/// * `rem` - Any number-like type, representing a quantity of roentgen equivalent man
+	pub fn from_rem(rem: T) -> Self {
+		DoseEquivalent{Sv: rem * T::from(0.01_f64)}
+	}
+
+	/// Returns a copy of this dose equivalent value in milli-roentgen equivalents
+	/// 
+	/// *Note: This method is not available for `f32` and other number types lacking the `From<f64>` trait*
+	pub fn to_mrem(&self) -> T {
+		return self.Sv.clone() * T::from(100000.0_f64);
+	}
+
+	/// Returns a new dose equivalent value from the given number of milli-roentgen equivalents
+	/// 
+	/// *Note: This method is not available for `f32` and other number types lacking the `From<f64>` trait*
+	///
+	/// # Arguments
+	/// * `mrem` - Any number-like type, representing a quantity of milli-roentgen equivalents
+	pub fn from_mrem(mrem: T) -> Self {
+		DoseEquivalent{Sv: mrem * T::from(1e-05_f64)}
+	}
+
+	/// Returns a copy of this dose equivalent value in kilo-roentgen equivalents
+	/// 
+	/// *Note: This method is not available for `f32` and other number types lacking the `From<f64>` trait*
+	pub fn to_krem(&self) -> T {
+		return self.Sv.clone() * T::from(0.1_f64);
+	}
+
+	/// Returns a new dose equivalent value from the given number of kilo-roentgen equivalents
+	/// 
+	/// *Note: This method is not available for `f32` and other number types lacking the `From<f64>` trait*
+	///
+	/// # Arguments
+	/// * `krem` - Any number-like type, representing a quantity of kilo-roentgen equivalents
+	pub fn from_krem(krem: T) -> Self {
+		DoseEquivalent{Sv: krem * T::from(10.0_f64)}
+	}
+
+}
+
+
+/// Multiplying a unit value by a scalar value returns a unit value
+#[cfg(feature="num-bigfloat")]
+impl core::ops::Mul<DoseEquivalent<num_bigfloat::BigFloat>> for num_bigfloat::BigFloat {
+	type Output = DoseEquivalent<num_bigfloat::BigFloat>;
+	fn mul(self, rhs: DoseEquivalent<num_bigfloat::BigFloat>) -> Self::Output {
+		DoseEquivalent{Sv: self * rhs.Sv}
+	}
+}
+/// Multiplying a unit value by a scalar value returns a unit value
+#[cfg(feature="num-bigfloat")]
+impl core::ops::Mul<DoseEquivalent<num_bigfloat::BigFloat>> for &num_bigfloat::BigFloat {
+	type Output = DoseEquivalent<num_bigfloat::BigFloat>;
+	fn mul(self, rhs: DoseEquivalent<num_bigfloat::BigFloat>) -> Self::Output {
+		DoseEquivalent{Sv: self.clone() * rhs.Sv}
+	}
+}
+/// Multiplying a unit value by a scalar value returns a unit value
+#[cfg(feature="num-bigfloat")]
+impl core::ops::Mul<&DoseEquivalent<num_bigfloat::BigFloat>> for num_bigfloat::BigFloat {
+	type Output = DoseEquivalent<num_bigfloat::BigFloat>;
+	fn mul(self, rhs: &DoseEquivalent<num_bigfloat::BigFloat>) -> Self::Output {
+		DoseEquivalent{Sv: self * rhs.Sv.clone()}
+	}
+}
+/// Multiplying a unit value by a scalar value returns a unit value
+#[cfg(feature="num-bigfloat")]
+impl core::ops::Mul<&DoseEquivalent<num_bigfloat::BigFloat>> for &num_bigfloat::BigFloat {
+	type Output = DoseEquivalent<num_bigfloat::BigFloat>;
+	fn mul(self, rhs: &DoseEquivalent<num_bigfloat::BigFloat>) -> Self::Output {
+		DoseEquivalent{Sv: self.clone() * rhs.Sv.clone()}
+	}
+}
+
+/// Multiplying a unit value by a scalar value returns a unit value
+#[cfg(feature="num-complex")]
+impl core::ops::Mul<DoseEquivalent<num_complex::Complex32>> for num_complex::Complex32 {
+	type Output = DoseEquivalent<num_complex::Complex32>;
+	fn mul(self, rhs: DoseEquivalent<num_complex::Complex32>) -> Self::Output {
+		DoseEquivalent{Sv: self * rhs.Sv}
+	}
+}
+/// Multiplying a unit value by a scalar value returns a unit value
+#[cfg(feature="num-complex")]
+impl core::ops::Mul<DoseEquivalent<num_complex::Complex32>> for &num_complex::Complex32 {
+	type Output = DoseEquivalent<num_complex::Complex32>;
+	fn mul(self, rhs: DoseEquivalent<num_complex::Complex32>) -> Self::Output {
+		DoseEquivalent{Sv: self.clone() * rhs.Sv}
+	}
+}
+/// Multiplying a unit value by a scalar value returns a unit value
+#[cfg(feature="num-complex")]
+impl core::ops::Mul<&DoseEquivalent<num_complex::Complex32>> for num_complex::Complex32 {
+	type Output = DoseEquivalent<num_complex::Complex32>;
+	fn mul(self, rhs: &DoseEquivalent<num_complex::Complex32>) -> Self::Output {
+		DoseEquivalent{Sv: self * rhs.Sv.clone()}
+	}
+}
+/// Multiplying a unit value by a scalar value returns a unit value
+#[cfg(feature="num-complex")]
+impl core::ops::Mul<&DoseEquivalent<num_complex::Complex32>> for &num_complex::Complex32 {
+	type Output = DoseEquivalent<num_complex::Complex32>;
+	fn mul(self, rhs: &DoseEquivalent<num_complex::Complex32>) -> Self::Output {
+		DoseEquivalent{Sv: self.clone() * rhs.Sv.clone()}
+	}
+}
+
+/// Multiplying a unit value by a scalar value returns a unit value
+#[cfg(feature="num-complex")]
+impl core::ops::Mul<DoseEquivalent<num_complex::Complex64>> for num_complex::Complex64 {
+	type Output = DoseEquivalent<num_complex::Complex64>;
+	fn mul(self, rhs: DoseEquivalent<num_complex::Complex64>) -> Self::Output {
+		DoseEquivalent{Sv: self * rhs.Sv}
+	}
+}
+/// Multiplying a unit value by a scalar value returns a unit value
+#[cfg(feature="num-complex")]
+impl core::ops::Mul<DoseEquivalent<num_complex::Complex64>> for &num_complex::Complex64 {
+	type Output = DoseEquivalent<num_complex::Complex64>;
+	fn mul(self, rhs: DoseEquivalent<num_complex::Complex64>) -> Self::Output {
+		DoseEquivalent{Sv: self.clone() * rhs.Sv}
+	}
+}
+/// Multiplying a unit value by a scalar value returns a unit value
+#[cfg(feature="num-complex")]
+impl core::ops::Mul<&DoseEquivalent<num_complex::Complex64>> for num_complex::Complex64 {
+	type Output = DoseEquivalent<num_complex::Complex64>;
+	fn mul(self, rhs: &DoseEquivalent<num_complex::Complex64>) -> Self::Output {
+		DoseEquivalent{Sv: self * rhs.Sv.clone()}
+	}
+}
+/// Multiplying a unit value by a scalar value returns a unit value
+#[cfg(feature="num-complex")]
+impl core::ops::Mul<&DoseEquivalent<num_complex::Complex64>> for &num_complex::Complex64 {
+	type Output = DoseEquivalent<num_complex::Complex64>;
+	fn mul(self, rhs: &DoseEquivalent<num_complex::Complex64>) -> Self::Output {
+		DoseEquivalent{Sv: self.clone() * rhs.Sv.clone()}
+	}
+}
+
+/// Multiplying a unit value by a scalar value returns a unit value
+#[cfg(feature="num-rational")]
+impl core::ops::Mul<DoseEquivalent<num_rational::Rational64>> for num_rational::Rational64 {
+	type Output = DoseEquivalent<num_rational::Rational64>;
+	fn mul(self, rhs: DoseEquivalent<num_rational::Rational64>) -> Self::Output {
+		DoseEquivalent{Sv: self * rhs.Sv}
+	}
+}
+/// Multiplying a unit value by a scalar value returns a unit value
+#[cfg(feature="num-rational")]
+impl core::ops::Mul<DoseEquivalent<num_rational::Rational64>> for &num_rational::Rational64 {
+	type Output = DoseEquivalent<num_rational::Rational64>;
+	fn mul(self, rhs: DoseEquivalent<num_rational::Rational64>) -> Self::Output {
+		DoseEquivalent{Sv: self.clone() * rhs.Sv}
+	}
+}
+/// Multiplying a unit value by a scalar value returns a unit value
+#[cfg(feature="num-rational")]
+impl core::ops::Mul<&DoseEquivalent<num_rational::Rational64>> for num_rational::Rational64 {
+	type Output = DoseEquivalent<num_rational::Rational64>;
+	fn mul(self, rhs: &DoseEquivalent<num_rational::Rational64>) -> Self::Output {
+		DoseEquivalent{Sv: self * rhs.Sv.clone()}
+	}
+}
+/// Multiplying a unit value by a scalar value returns a unit value
+#[cfg(feature="num-rational")]
+impl core::ops::Mul<&DoseEquivalent<num_rational::Rational64>> for &num_rational::Rational64 {
+	type Output = DoseEquivalent<num_rational::Rational64>;
+	fn mul(self, rhs: &DoseEquivalent<num_rational::Rational64>) -> Self::Output {
+		DoseEquivalent{Sv: self.clone() * rhs.Sv.clone()}
+	}
+}
+
+/// Multiplying a unit value by a scalar value returns a unit value
+#[cfg(feature="num-rational")]
+impl core::ops::Mul<DoseEquivalent<num_rational::BigRational>> for num_rational::BigRational {
+	type Output = DoseEquivalent<num_rational::BigRational>;
+	fn mul(self, rhs: DoseEquivalent<num_rational::BigRational>) -> Self::Output {
+		DoseEquivalent{Sv: self * rhs.Sv}
+	}
+}
+/// Multiplying a unit value by a scalar value returns a unit value
+#[cfg(feature="num-rational")]
+impl core::ops::Mul<DoseEquivalent<num_rational::BigRational>> for &num_rational::BigRational {
+	type Output = DoseEquivalent<num_rational::BigRational>;
+	fn mul(self, rhs: DoseEquivalent<num_rational::BigRational>) -> Self::Output {
+		DoseEquivalent{Sv: self.clone() * rhs.Sv}
+	}
+}
+/// Multiplying a unit value by a scalar value returns a unit value
+#[cfg(feature="num-rational")]
+impl core::ops::Mul<&DoseEquivalent<num_rational::BigRational>> for num_rational::BigRational {
+	type Output = DoseEquivalent<num_rational::BigRational>;
+	fn mul(self, rhs: &DoseEquivalent<num_rational::BigRational>) -> Self::Output {
+		DoseEquivalent{Sv: self * rhs.Sv.clone()}
+	}
+}
+/// Multiplying a unit value by a scalar value returns a unit value
+#[cfg(feature="num-rational")]
+impl core::ops::Mul<&DoseEquivalent<num_rational::BigRational>> for &num_rational::BigRational {
+	type Output = DoseEquivalent<num_rational::BigRational>;
+	fn mul(self, rhs: &DoseEquivalent<num_rational::BigRational>) -> Self::Output {
+		DoseEquivalent{Sv: self.clone() * rhs.Sv.clone()}
+	}
+}
+
+
+
+
+
+/// Multiplying a DoseEquivalent by an [nalgebra](https://crates.io/crates/nalgebra) 
+/// vector or matrix of unitless values returns a vector or matrix of DoseEquivalent 
+/// values (eg a direction vector multiplied by a DoseEquivalent)
+#[cfg(feature="nalgebra")]
+impl<T, R, C, S> core::ops::Mul<nalgebra::Matrix<T, R, C, S>> for DoseEquivalent<T> where 
+	T: NumLike+nalgebra::Scalar, R: nalgebra::Dim, C: nalgebra::Dim, S: nalgebra::RawStorage<T, R, C>, 
+	nalgebra::DefaultAllocator: nalgebra::allocator::Allocator<R, C> {
+	type Output = nalgebra::OMatrix<DoseEquivalent<T>, R, C>;
+	fn mul(self, rhs: nalgebra::Matrix<T, R, C, S>) -> Self::Output {
+		rhs.map(|x| DoseEquivalent{Sv: self.Sv.clone() * x})
+	}
+}
+/// Multiplying an [nalgebra](https://crates.io/crates/nalgebra) vector or matrix of 
+/// unitless values by a DoseEquivalent returns a vector or matrix of DoseEquivalent values
+#[cfg(feature="nalgebra")]
+impl<T, R, C, S> core::ops::Mul<DoseEquivalent<T>> for nalgebra::Matrix<T, R, C, S> where 
+	T: NumLike+nalgebra::Scalar, R: nalgebra::Dim, C: nalgebra::Dim, S: nalgebra::RawStorage<T, R, C>, 
+	nalgebra::DefaultAllocator: nalgebra::allocator::Allocator<R, C> {
+	type Output = nalgebra::OMatrix<DoseEquivalent<T>, R, C>;
+	fn mul(self, rhs: DoseEquivalent<T>) -> Self::Output {
+		self.map(|x| DoseEquivalent{Sv: x * rhs.Sv.clone()})
+	}
+}
+/// Dividing an [nalgebra](https://crates.io/crates/nalgebra) vector or matrix of 
+/// DoseEquivalent values by a DoseEquivalent returns a vector or matrix of unitless values
+#[cfg(feature="nalgebra")]
+impl<T, R, C, S> core::ops::Div<DoseEquivalent<T>> for nalgebra::Matrix<DoseEquivalent<T>, R, C, S> where 
+	T: NumLike+nalgebra::Scalar, R: nalgebra::Dim, C: nalgebra::Dim, S: nalgebra::RawStorage<DoseEquivalent<T>, R, C>, 
+	nalgebra::DefaultAllocator: nalgebra::allocator::Allocator<R, C> {
+	type Output = nalgebra::OMatrix<T, R, C>;
+	fn div(self, rhs: DoseEquivalent<T>) -> Self::Output {
+		self.map(|x| x.Sv / rhs.Sv.clone())
+	}
+}
+
+
+
+// DoseEquivalent * Mass -> Energy
+/// Multiplying a DoseEquivalent by a Mass returns a value of type Energy
+impl<T> core::ops::Mul<Mass<T>> for DoseEquivalent<T> where T: NumLike {
+	type Output = Energy<T>;
+	fn mul(self, rhs: Mass<T>) -> Self::Output {
+		Energy{J: self.Sv * rhs.kg}
+	}
+}
+/// Multiplying a DoseEquivalent by a Mass returns a value of type Energy
+impl<T> core::ops::Mul<Mass<T>> for &DoseEquivalent<T> where T: NumLike {
+	type Output = Energy<T>;
+	fn mul(self, rhs: Mass<T>) -> Self::Output {
+		Energy{J: self.Sv.clone() * rhs.kg}
+	}
+}
+/// Multiplying a DoseEquivalent by a Mass returns a value of type Energy
+impl<T> core::ops::Mul<&Mass<T>> for DoseEquivalent<T> where T: NumLike {
+	type Output = Energy<T>;
+	fn mul(self, rhs: &Mass<T>) -> Self::Output {
+		Energy{J: self.Sv * rhs.kg.clone()}
+	}
+}
+/// Multiplying a DoseEquivalent by a Mass returns a value of type Energy
+impl<T> core::ops::Mul<&Mass<T>> for &DoseEquivalent<T> where T: NumLike {
+	type Output = Energy<T>;
+	fn mul(self, rhs: &Mass<T>) -> Self::Output {
+		Energy{J: self.Sv.clone() * rhs.kg.clone()}
+	}
+}
+
+// DoseEquivalent / Time -> DoseEquivalentRate
+/// Dividing a DoseEquivalent by a Time returns a value of type DoseEquivalentRate
+impl<T> core::ops::Div<Time<T>> for DoseEquivalent<T> where T: NumLike {
+	type Output = DoseEquivalentRate<T>;
+	fn div(self, rhs: Time<T>) -> Self::Output {
+		DoseEquivalentRate{Sv_per_s: self.Sv / rhs.s}
+	}
+}
+/// Dividing a DoseEquivalent by a Time returns a value of type DoseEquivalentRate
+impl<T> core::ops::Div<Time<T>> for &DoseEquivalent<T> where T: NumLike {
+	type Output = DoseEquivalentRate<T>;
+	fn div(self, rhs: Time<T>) -> Self::Output {
+		DoseEquivalentRate{Sv_per_s: self.Sv.clone() / rhs.s}
+	}
+}
+/// Dividing a DoseEquivalent by a Time returns a value of type DoseEquivalentRate
+impl<T> core::ops::Div<&Time<T>> for DoseEquivalent<T> where T: NumLike {
+	type Output = DoseEquivalentRate<T>;
+	fn div(self, rhs: &Time<T>) -> Self::Output {
+		DoseEquivalentRate{Sv_per_s: self.Sv / rhs.s.clone()}
+	}
+}
+/// Dividing a DoseEquivalent by a Time returns a value of type DoseEquivalentRate
+impl<T> core::ops::Div<&Time<T>> for &DoseEquivalent<T> where T: NumLike {
+	type Output = DoseEquivalentRate<T>;
+	fn div(self, rhs: &Time<T>) -> Self::Output {
+		DoseEquivalentRate{Sv_per_s: self.Sv.clone() / rhs.s.clone()}
+	}
+}
+
+// DoseEquivalent / DoseEquivalentRate -> Time
+/// Dividing a DoseEquivalent by a DoseEquivalentRate returns a value of type Time
+impl<T> core::ops::Div<DoseEquivalentRate<T>> for DoseEquivalent<T> where T: NumLike {
+	type Output = Time<T>;
+	fn div(self, rhs: DoseEquivalentRate<T>) -> Self::Output {
+		Time{s: self.Sv / rhs.Sv_per_s}
+	}
+}
+/// Dividing a DoseEquivalent by a DoseEquivalentRate returns a value of type Time
+impl<T> core::ops::Div<DoseEquivalentRate<T>> for &DoseEquivalent<T> where T: NumLike {
+	type Output = Time<T>;
+	fn div(self, rhs: DoseEquivalentRate<T>) -> Self::Output {
+		Time{s: self.Sv.clone() / rhs.Sv_per_s}
+	}
+}
+/// Dividing a DoseEquivalent by a DoseEquivalentRate returns a value of type Time
+impl<T> core::ops::Div<&DoseEquivalentRate<T>> for DoseEquivalent<T> where T: NumLike {
+	type Output = Time<T>;
+	fn div(self, rhs: &DoseEquivalentRate<T>) -> Self::Output {
+		Time{s: self.Sv / rhs.Sv_per_s.clone()}
+	}
+}
+/// Dividing a DoseEquivalent by a DoseEquivalentRate returns a value of type Time
+impl<T> core::ops::Div<&DoseEquivalentRate<T>> for &DoseEquivalent<T> where T: NumLike {
+	type Output = Time<T>;
+	fn div(self, rhs: &DoseEquivalentRate<T>) -> Self::Output {
+		Time{s: self.Sv.clone() / rhs.Sv_per_s.clone()}
+	}
+}
+
+impl<T> DoseEquivalent<T> where T: NumLike+From<u8> {
+	/// Returns the reciprocal of this DoseEquivalent value (ie 1 divided by 
+	/// this value) as a value of type InverseDoseEquivalent
+	pub fn recip(self) -> InverseDoseEquivalent<T> {
+		InverseDoseEquivalent{per_Sv: T::from(1u8) / self.Sv}
+	}
+}
+
+// 1/DoseEquivalent -> InverseDoseEquivalent
+/// Dividing a scalar value by a DoseEquivalent unit value returns a value of type InverseDoseEquivalent
+impl<T> core::ops::Div<DoseEquivalent<T>> for f64 where T: NumLike+From<f64> {
+	type Output = InverseDoseEquivalent<T>;
+	fn div(self, rhs: DoseEquivalent<T>) -> Self::Output {
+		InverseDoseEquivalent{per_Sv: T::from(self) / rhs.Sv}
+	}
+}
+/// Dividing a scalar value by a DoseEquivalent unit value returns a value of type InverseDoseEquivalent
+impl<T> core::ops::Div<DoseEquivalent<T>> for &f64 where T: NumLike+From<f64> {
+	type Output = InverseDoseEquivalent<T>;
+	fn div(self, rhs: DoseEquivalent<T>) -> Self::Output {
+		InverseDoseEquivalent{per_Sv: T::from(self.clone()) / rhs.Sv}
+	}
+}
+/// Dividing a scalar value by a DoseEquivalent unit value returns a value of type InverseDoseEquivalent
+impl<T> core::ops::Div<&DoseEquivalent<T>> for f64 where T: NumLike+From<f64> {
+	type Output = InverseDoseEquivalent<T>;
+	fn div(self, rhs: &DoseEquivalent<T>) -> Self::Output {
+		InverseDoseEquivalent{per_Sv: T::from(self) / rhs.Sv.clone()}
+	}
+}
+/// Dividing a scalar value by a DoseEquivalent unit value returns a value of type InverseDoseEquivalent
+impl<T> core::ops::Div<&DoseEquivalent<T>> for &f64 where T: NumLike+From<f64> {
+	type Output = InverseDoseEquivalent<T>;
+	fn div(self, rhs: &DoseEquivalent<T>) -> Self::Output {
+		InverseDoseEquivalent{per_Sv: T::from(self.clone()) / rhs.Sv.clone()}
+	}
+}
+
+// 1/DoseEquivalent -> InverseDoseEquivalent
+/// Dividing a scalar value by a DoseEquivalent unit value returns a value of type InverseDoseEquivalent
+impl<T> core::ops::Div<DoseEquivalent<T>> for f32 where T: NumLike+From<f32> {
+	type Output = InverseDoseEquivalent<T>;
+	fn div(self, rhs: DoseEquivalent<T>) -> Self::Output {
+		InverseDoseEquivalent{per_Sv: T::from(self) / rhs.Sv}
+	}
+}
+/// Dividing a scalar value by a DoseEquivalent unit value returns a value of type InverseDoseEquivalent
+impl<T> core::ops::Div<DoseEquivalent<T>> for &f32 where T: NumLike+From<f32> {
+	type Output = InverseDoseEquivalent<T>;
+	fn div(self, rhs: DoseEquivalent<T>) -> Self::Output {
+		InverseDoseEquivalent{per_Sv: T::from(self.clone()) / rhs.Sv}
+	}
+}
+/// Dividing a scalar value by a DoseEquivalent unit value returns a value of type InverseDoseEquivalent
+impl<T> core::ops::Div<&DoseEquivalent<T>> for f32 where T: NumLike+From<f32> {
+	type Output = InverseDoseEquivalent<T>;
+	fn div(self, rhs: &DoseEquivalent<T>) -> Self::Output {
+		InverseDoseEquivalent{per_Sv: T::from(self) / rhs.Sv.clone()}
+	}
+}
+/// Dividing a scalar value by a DoseEquivalent unit value returns a value of type InverseDoseEquivalent
+impl<T> core::ops::Div<&DoseEquivalent<T>> for &f32 where T: NumLike+From<f32> {
+	type Output = InverseDoseEquivalent<T>;
+	fn div(self, rhs: &DoseEquivalent<T>) -> Self::Output {
+		InverseDoseEquivalent{per_Sv: T::from(self.clone()) / rhs.Sv.clone()}
+	}
+}
+
+// 1/DoseEquivalent -> InverseDoseEquivalent
+/// Dividing a scalar value by a DoseEquivalent unit value returns a value of type InverseDoseEquivalent
+impl<T> core::ops::Div<DoseEquivalent<T>> for i64 where T: NumLike+From<i64> {
+	type Output = InverseDoseEquivalent<T>;
+	fn div(self, rhs: DoseEquivalent<T>) -> Self::Output {
+		InverseDoseEquivalent{per_Sv: T::from(self) / rhs.Sv}
+	}
+}
+/// Dividing a scalar value by a DoseEquivalent unit value returns a value of type InverseDoseEquivalent
+impl<T> core::ops::Div<DoseEquivalent<T>> for &i64 where T: NumLike+From<i64> {
+	type Output = InverseDoseEquivalent<T>;
+	fn div(self, rhs: DoseEquivalent<T>) -> Self::Output {
+		InverseDoseEquivalent{per_Sv: T::from(self.clone()) / rhs.Sv}
+	}
+}
+/// Dividing a scalar value by a DoseEquivalent unit value returns a value of type InverseDoseEquivalent
+impl<T> core::ops::Div<&DoseEquivalent<T>> for i64 where T: NumLike+From<i64> {
+	type Output = InverseDoseEquivalent<T>;
+	fn div(self, rhs: &DoseEquivalent<T>) -> Self::Output {
+		InverseDoseEquivalent{per_Sv: T::from(self) / rhs.Sv.clone()}
+	}
+}
+/// Dividing a scalar value by a DoseEquivalent unit value returns a value of type InverseDoseEquivalent
+impl<T> core::ops::Div<&DoseEquivalent<T>> for &i64 where T: NumLike+From<i64> {
+	type Output = InverseDoseEquivalent<T>;
+	fn div(self, rhs: &DoseEquivalent<T>) -> Self::Output {
+		InverseDoseEquivalent{per_Sv: T::from(self.clone()) / rhs.Sv.clone()}
+	}
+}
+
+// 1/DoseEquivalent -> InverseDoseEquivalent
+/// Dividing a scalar value by a DoseEquivalent unit value returns a value of type InverseDoseEquivalent
+impl<T> core::ops::Div<DoseEquivalent<T>> for i32 where T: NumLike+From<i32> {
+	type Output = InverseDoseEquivalent<T>;
+	fn div(self, rhs: DoseEquivalent<T>) -> Self::Output {
+		InverseDoseEquivalent{per_Sv: T::from(self) / rhs.Sv}
+	}
+}
+/// Dividing a scalar value by a DoseEquivalent unit value returns a value of type InverseDoseEquivalent
+impl<T> core::ops::Div<DoseEquivalent<T>> for &i32 where T: NumLike+From<i32> {
+	type Output = InverseDoseEquivalent<T>;
+	fn div(self, rhs: DoseEquivalent<T>) -> Self::Output {
+		InverseDoseEquivalent{per_Sv: T::from(self.clone()) / rhs.Sv}
+	}
+}
+/// Dividing a scalar value by a DoseEquivalent unit value returns a value of type InverseDoseEquivalent
+impl<T> core::ops::Div<&DoseEquivalent<T>> for i32 where T: NumLike+From<i32> {
+	type Output = InverseDoseEquivalent<T>;
+	fn div(self, rhs: &DoseEquivalent<T>) -> Self::Output {
+		InverseDoseEquivalent{per_Sv: T::from(self) / rhs.Sv.clone()}
+	}
+}
+/// Dividing a scalar value by a DoseEquivalent unit value returns a value of type InverseDoseEquivalent
+impl<T> core::ops::Div<&DoseEquivalent<T>> for &i32 where T: NumLike+From<i32> {
+	type Output = InverseDoseEquivalent<T>;
+	fn div(self, rhs: &DoseEquivalent<T>) -> Self::Output {
+		InverseDoseEquivalent{per_Sv: T::from(self.clone()) / rhs.Sv.clone()}
+	}
+}
+
+// 1/DoseEquivalent -> InverseDoseEquivalent
+/// Dividing a scalar value by a DoseEquivalent unit value returns a value of type InverseDoseEquivalent
+#[cfg(feature="num-bigfloat")]
+impl<T> core::ops::Div<DoseEquivalent<T>> for num_bigfloat::BigFloat where T: NumLike+From<num_bigfloat::BigFloat> {
+	type Output = InverseDoseEquivalent<T>;
+	fn div(self, rhs: DoseEquivalent<T>) -> Self::Output {
+		InverseDoseEquivalent{per_Sv: T::from(self) / rhs.Sv}
+	}
+}
+/// Dividing a scalar value by a DoseEquivalent unit value returns a value of type InverseDoseEquivalent
+#[cfg(feature="num-bigfloat")]
+impl<T> core::ops::Div<DoseEquivalent<T>> for &num_bigfloat::BigFloat where T: NumLike+From<num_bigfloat::BigFloat> {
+	type Output = InverseDoseEquivalent<T>;
+	fn div(self, rhs: DoseEquivalent<T>) -> Self::Output {
+		InverseDoseEquivalent{per_Sv: T::from(self.clone()) / rhs.Sv}
+	}
+}
+/// Dividing a scalar value by a DoseEquivalent unit value returns a value of type InverseDoseEquivalent
+#[cfg(feature="num-bigfloat")]
+impl<T> core::ops::Div<&DoseEquivalent<T>> for num_bigfloat::BigFloat where T: NumLike+From<num_bigfloat::BigFloat> {
+	type Output = InverseDoseEquivalent<T>;
+	fn div(self, rhs: &DoseEquivalent<T>) -> Self::Output {
+		InverseDoseEquivalent{per_Sv: T::from(self) / rhs.Sv.clone()}
+	}
+}
+/// Dividing a scalar value by a DoseEquivalent unit value returns a value of type InverseDoseEquivalent
+#[cfg(feature="num-bigfloat")]
+impl<T> core::ops::Div<&DoseEquivalent<T>> for &num_bigfloat::BigFloat where T: NumLike+From<num_bigfloat::BigFloat> {
+	type Output = InverseDoseEquivalent<T>;
+	fn div(self, rhs: &DoseEquivalent<T>) -> Self::Output {
+		InverseDoseEquivalent{per_Sv: T::from(self.clone()) / rhs.Sv.clone()}
+	}
+}
+
+// 1/DoseEquivalent -> InverseDoseEquivalent
+/// Dividing a scalar value by a DoseEquivalent unit value returns a value of type InverseDoseEquivalent
+#[cfg(feature="num-complex")]
+impl<T> core::ops::Div<DoseEquivalent<T>> for num_complex::Complex32 where T: NumLike+From<num_complex::Complex32> {
+	type Output = InverseDoseEquivalent<T>;
+	fn div(self, rhs: DoseEquivalent<T>) -> Self::Output {
+		InverseDoseEquivalent{per_Sv: T::from(self) / rhs.Sv}
+	}
+}
+/// Dividing a scalar value by a DoseEquivalent unit value returns a value of type InverseDoseEquivalent
+#[cfg(feature="num-complex")]
+impl<T> core::ops::Div<DoseEquivalent<T>> for &num_complex::Complex32 where T: NumLike+From<num_complex::Complex32> {
+	type Output = InverseDoseEquivalent<T>;
+	fn div(self, rhs: DoseEquivalent<T>) -> Self::Output {
+		InverseDoseEquivalent{per_Sv: T::from(self.clone()) / rhs.Sv}
+	}
+}
+/// Dividing a scalar value by a DoseEquivalent unit value returns a value of type InverseDoseEquivalent
+#[cfg(feature="num-complex")]
+impl<T> core::ops::Div<&DoseEquivalent<T>> for num_complex::Complex32 where T: NumLike+From<num_complex::Complex32> {
+	type Output = InverseDoseEquivalent<T>;
+	fn div(self, rhs: &DoseEquivalent<T>) -> Self::Output {
+		InverseDoseEquivalent{per_Sv: T::from(self) / rhs.Sv.clone()}
+	}
+}
+/// Dividing a scalar value by a DoseEquivalent unit value returns a value of type InverseDoseEquivalent
+#[cfg(feature="num-complex")]
+impl<T> core::ops::Div<&DoseEquivalent<T>> for &num_complex::Complex32 where T: NumLike+From<num_complex::Complex32> {
+	type Output = InverseDoseEquivalent<T>;
+	fn div(self, rhs: &DoseEquivalent<T>) -> Self::Output {
+		InverseDoseEquivalent{per_Sv: T::from(self.clone()) / rhs.Sv.clone()}
+	}
+}
+
+// 1/DoseEquivalent -> InverseDoseEquivalent
+/// Dividing a scalar value by a DoseEquivalent unit value returns a value of type InverseDoseEquivalent
+#[cfg(feature="num-complex")]
+impl<T> core::ops::Div<DoseEquivalent<T>> for num_complex::Complex64 where T: NumLike+From<num_complex::Complex64> {
+	type Output = InverseDoseEquivalent<T>;
+	fn div(self, rhs: DoseEquivalent<T>) -> Self::Output {
+		InverseDoseEquivalent{per_Sv: T::from(self) / rhs.Sv}
+	}
+}
+/// Dividing a scalar value by a DoseEquivalent unit value returns a value of type InverseDoseEquivalent
+#[cfg(feature="num-complex")]
+impl<T> core::ops::Div<DoseEquivalent<T>> for &num_complex::Complex64 where T: NumLike+From<num_complex::Complex64> {
+	type Output = InverseDoseEquivalent<T>;
+	fn div(self, rhs: DoseEquivalent<T>) -> Self::Output {
+		InverseDoseEquivalent{per_Sv: T::from(self.clone()) / rhs.Sv}
+	}
+}
+/// Dividing a scalar value by a DoseEquivalent unit value returns a value of type InverseDoseEquivalent
+#[cfg(feature="num-complex")]
+impl<T> core::ops::Div<&DoseEquivalent<T>> for num_complex::Complex64 where T: NumLike+From<num_complex::Complex64> {
+	type Output = InverseDoseEquivalent<T>;
+	fn div(self, rhs: &DoseEquivalent<T>) -> Self::Output {
+		InverseDoseEquivalent{per_Sv: T::from(self) / rhs.Sv.clone()}
+	}
+}
+/// Dividing a scalar value by a DoseEquivalent unit value returns a value of type InverseDoseEquivalent
+#[cfg(feature="num-complex")]
+impl<T> core::ops::Div<&DoseEquivalent<T>> for &num_complex::Complex64 where T: NumLike+From<num_complex::Complex64> {
+	type Output = InverseDoseEquivalent<T>;
+	fn div(self, rhs: &DoseEquivalent<T>) -> Self::Output {
+		InverseDoseEquivalent{per_Sv: T::from(self.clone()) / rhs.Sv.clone()}
+	}
+}
+
+// 1/DoseEquivalent -> InverseDoseEquivalent
+/// Dividing a scalar value by a DoseEquivalent unit value returns a value of type InverseDoseEquivalent
+#[cfg(feature="num-rational")]
+impl<T> core::ops::Div<DoseEquivalent<T>> for num_rational::Rational64 where T: NumLike+From<num_rational::Rational64> {
+	type Output = InverseDoseEquivalent<T>;
+	fn div(self, rhs: DoseEquivalent<T>) -> Self::Output {
+		InverseDoseEquivalent{per_Sv: T::from(self) / rhs.Sv}
+	}
+}
+/// Dividing a scalar value by a DoseEquivalent unit value returns a value of type InverseDoseEquivalent
+#[cfg(feature="num-rational")]
+impl<T> core::ops::Div<DoseEquivalent<T>> for &num_rational::Rational64 where T: NumLike+From<num_rational::Rational64> {
+	type Output = InverseDoseEquivalent<T>;
+	fn div(self, rhs: DoseEquivalent<T>) -> Self::Output {
+		InverseDoseEquivalent{per_Sv: T::from(self.clone()) / rhs.Sv}
+	}
+}
+/// Dividing a scalar value by a DoseEquivalent unit value returns a value of type InverseDoseEquivalent
+#[cfg(feature="num-rational")]
+impl<T> core::ops::Div<&DoseEquivalent<T>> for num_rational::Rational64 where T: NumLike+From<num_rational::Rational64> {
+	type Output = InverseDoseEquivalent<T>;
+	fn div(self, rhs: &DoseEquivalent<T>) -> Self::Output {
+		InverseDoseEquivalent{per_Sv: T::from(self) / rhs.Sv.clone()}
+	}
+}
+/// Dividing a scalar value by a DoseEquivalent unit value returns a value of type InverseDoseEquivalent
+#[cfg(feature="num-rational")]
+impl<T> core::ops::Div<&DoseEquivalent<T>> for &num_rational::Rational64 where T: NumLike+From<num_rational::Rational64> {
+	type Output = InverseDoseEquivalent<T>;
+	fn div(self, rhs: &DoseEquivalent<T>) -> Self::Output {
+		InverseDoseEquivalent{per_Sv: T::from(self.clone()) / rhs.Sv.clone()}
+	}
+}
+
+// 1/DoseEquivalent -> InverseDoseEquivalent
+/// Dividing a scalar value by a DoseEquivalent unit value returns a value of type InverseDoseEquivalent
+#[cfg(feature="num-rational")]
+impl<T> core::ops::Div<DoseEquivalent<T>> for num_rational::BigRational where T: NumLike+From<num_rational::BigRational> {
+	type Output = InverseDoseEquivalent<T>;
+	fn div(self, rhs: DoseEquivalent<T>) -> Self::Output {
+		InverseDoseEquivalent{per_Sv: T::from(self) / rhs.Sv}
+	}
+}
+/// Dividing a scalar value by a DoseEquivalent unit value returns a value of type InverseDoseEquivalent
+#[cfg(feature="num-rational")]
+impl<T> core::ops::Div<DoseEquivalent<T>> for &num_rational::BigRational where T: NumLike+From<num_rational::BigRational> {
+	type Output = InverseDoseEquivalent<T>;
+	fn div(self, rhs: DoseEquivalent<T>) -> Self::Output {
+		InverseDoseEquivalent{per_Sv: T::from(self.clone()) / rhs.Sv}
+	}
+}
+/// Dividing a scalar value by a DoseEquivalent unit value returns a value of type InverseDoseEquivalent
+#[cfg(feature="num-rational")]
+impl<T> core::ops::Div<&DoseEquivalent<T>> for num_rational::BigRational where T: NumLike+From<num_rational::BigRational> {
+	type Output = InverseDoseEquivalent<T>;
+	fn div(self, rhs: &DoseEquivalent<T>) -> Self::Output {
+		InverseDoseEquivalent{per_Sv: T::from(self) / rhs.Sv.clone()}
+	}
+}
+/// Dividing a scalar value by a DoseEquivalent unit value returns a value of type InverseDoseEquivalent
+#[cfg(feature="num-rational")]
+impl<T> core::ops::Div<&DoseEquivalent<T>> for &num_rational::BigRational where T: NumLike+From<num_rational::BigRational> {
+	type Output = InverseDoseEquivalent<T>;
+	fn div(self, rhs: &DoseEquivalent<T>) -> Self::Output {
+		InverseDoseEquivalent{per_Sv: T::from(self.clone()) / rhs.Sv.clone()}
+	}
+}
+
+/// The radiation dose equivalent rate unit type, defined as sieverts per second in SI units
+#[derive(UnitStruct, Debug, Clone, Default)]
+#[si(no_display)]
+#[cfg_attr(all(feature="serde", not(feature="serde-unit-tag")), derive(Serialize, Deserialize))]
+#[repr(transparent)]
+pub struct DoseEquivalentRate<T: NumLike>{
+	/// The value of this Dose equivalent rate in sieverts per second
+	pub Sv_per_s: T
+}
+
+impl<T> DoseEquivalentRate<T> where T: NumLike {
+
+	/// Returns the standard unit name of dose equivalent rate: "sieverts per second"
+	pub fn unit_name() -> &'static str { "sieverts per second" }
+	
+	/// Returns the abbreviated name or symbol of dose equivalent rate: "Sv/s" for sieverts per second
+	pub fn unit_symbol() -> &'static str { "Sv/s" }
+
+	/// Returns the exponents of the SI base units of dose equivalent rate (m^2/s^3)
+	pub const fn dimension() -> super::Dimension { super::Dimension{length: 2, mass: 0, time: -3, current: 0, temperature: 0, amount: 0, luminosity: 0} }
+
+	/// Returns a wrapper that formats this dose equivalent rate value with the full unit name instead of 
+	/// the unit symbol, eg "5 sieverts per second" (the unit name is not singularized for values of 1)
+	pub fn display_named(&self) -> super::NamedDisplay<'_, T> {
+		super::NamedDisplay::new(&self.Sv_per_s, Self::unit_name())
+	}
+
+	/// Returns a new dose equivalent rate value from the given number of sieverts per second (same as 
+	/// `from_Sv_per_s(...)`), for any number-like type. This is useful in generic code 
+	/// that works with several unit types
+	///
+	/// # Arguments
+	/// * `value` - Any number-like type, representing a quantity of sieverts per second
+	pub const fn new(value: T) -> Self { DoseEquivalentRate{Sv_per_s: value} }
+
+	/// Returns a new dose equivalent rate value from the given number of sieverts per second. Unlike
+	/// `from_Sv_per_s(...)`, this is a `const fn`, so it can be used to declare `const` and `static` values
+	///
+	/// # Arguments
+	/// * `Sv_per_s` - Any number-like type, representing a quantity of sieverts per second
+	pub const fn from_Sv_per_s_const(Sv_per_s: T) -> Self { DoseEquivalentRate{Sv_per_s} }
+
+	/// Returns a dose equivalent rate value with a different number type, by applying the 
+	/// given function to the value in sieverts per second (eg `.map(|x| x as f32)` to convert 
+	/// from `f64` to `f32`)
+	///
+	/// # Arguments
+	/// * `f` - Function that converts the number of sieverts per second to the new number type
+	pub fn map<U, F>(self, f: F) -> DoseEquivalentRate<U> where U: NumLike, F: FnOnce(T) -> U {
+		DoseEquivalentRate{Sv_per_s: f(self.Sv_per_s)}
+	}
+
+	/// Returns a dose equivalent rate value with a different number type, converted 
+	/// losslessly with the `From` trait (eg from `f32` to `f64`)
+	pub fn cast<U>(self) -> DoseEquivalentRate<U> where U: NumLike+From<T> {
+		DoseEquivalentRate{Sv_per_s: U::from(self.Sv_per_s)}
+	}
+
+	/// Returns a reference to the value of this dose equivalent rate in sieverts per second
+	pub fn as_raw(&self) -> &T {
+		&self.Sv_per_s
+	}
+
+	/// Returns a mutable reference to the value of this dose equivalent rate in sieverts per second, 
+	/// eg for passing to numeric libraries that modify values in place. The number 
+	/// is always in sieverts per second, so changing it directly does not change the unit
+	pub fn raw_mut(&mut self) -> &mut T {
+		&mut self.Sv_per_s
+	}
+
+	/// Returns a slice of numbers in sieverts per second as a slice of dose equivalent rate values, 
+	/// without copying
+	///
+	/// # Arguments
+	/// * `values` - Slice of numbers, representing quantities of sieverts per second
+	pub fn wrap_slice(values: &[T]) -> &[Self] {
+		// SAFETY: DoseEquivalentRate<T> is #[repr(transparent)], so it has the same memory layout as T
+		unsafe { core::slice::from_raw_parts(values.as_ptr() as *const Self, values.len()) }
+	}
+
+	/// Returns a mutable slice of numbers in sieverts per second as a mutable slice of 
+	/// dose equivalent rate values, without copying
+	///
+	/// # Arguments
+	/// * `values` - Slice of numbers, representing quantities of sieverts per second
+	pub fn wrap_slice_mut(values: &mut [T]) -> &mut [Self] {
+		// SAFETY: DoseEquivalentRate<T> is #[repr(transparent)], so it has the same memory layout as T
+		unsafe { core::slice::from_raw_parts_mut(values.as_mut_ptr() as *mut Self, values.len()) }
+	}
+
+	/// Returns the ratio of this dose equivalent rate value to another dose equivalent rate value 
+	/// (same as `self / other`). Dividing a unit value by another value of the same 
+	/// unit type cancels the units, so the ratio is a dimensionless number
+	///
+	/// # Arguments
+	/// * `other` - The dose equivalent rate value to divide by
+	pub fn ratio(self, other: Self) -> T {
+		self.Sv_per_s / other.Sv_per_s
+	}
+
+	/// Returns the ratio of this dose equivalent rate value to another dose equivalent rate value 
+	/// as a `Dimensionless` value, instead of a bare number like `ratio(...)` does
+	///
+	/// # Arguments
+	/// * `other` - The dose equivalent rate value to divide by
+	pub fn dimensionless_ratio(self, other: Self) -> super::base::Dimensionless<T> {
+		super::base::Dimensionless{value: self.Sv_per_s / other.Sv_per_s}
+	}
+
+	/// Returns this dose equivalent rate value multiplied by a dimensionless factor 
+	/// (same as `self * factor`)
+	///
+	/// # Arguments
+	/// * `factor` - The number to multiply by
+	pub fn scale(self, factor: T) -> Self {
+		DoseEquivalentRate{Sv_per_s: self.Sv_per_s * factor}
+	}
+	
+	/// Returns a new dose equivalent rate value from the given number of sieverts per second
+	///
+	/// # Arguments
+	/// * `Sv_per_s` - Any number-like type, representing a quantity of sieverts per second
+	pub fn from_Sv_per_s(Sv_per_s: T) -> Self { DoseEquivalentRate{Sv_per_s: Sv_per_s} }
+	
+	/// Returns a copy of this dose equivalent rate value in sieverts per second
+	pub fn to_Sv_per_s(&self) -> T { self.Sv_per_s.clone() }
+
+	/// Returns a new dose equivalent rate value from the given number of sieverts per second
+	///
+	/// # Arguments
+	/// * `sieverts_per_second` - Any number-like type, representing a quantity of sieverts per second
+	pub fn from_sieverts_per_second(sieverts_per_second: T) -> Self { DoseEquivalentRate{Sv_per_s: sieverts_per_second} }
+	
+	/// Returns a copy of this dose equivalent rate value in sieverts per second
+	pub fn to_sieverts_per_second(&self) -> T { self.Sv_per_s.clone() }
+
+}
+
+/// Formats dose equivalent rate values as a number followed by its unit symbol, eg "1500 Sv/s"
+/// (complex numbers are wrapped in parentheses, eg "(1+2i) Sv/s").
+/// The alternate flag (`{:#}`) scales the number by the nearest SI prefix where the unit symbol allows it
+impl<T> fmt::Display for DoseEquivalentRate<T> where T: NumLike {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		if f.alternate() {
+			return super::fmt_with_si_prefix(f, &self.Sv_per_s, Self::unit_symbol(), false);
+		}
+		super::fmt_unit_value(f, &self.Sv_per_s, Self::unit_symbol())
+	}
+}
+
+/// Formats dose equivalent rate values in exponential notation followed by the unit symbol, eg "1.5e3 Sv/s"
+impl<T> fmt::LowerExp for DoseEquivalentRate<T> where T: NumLike+fmt::LowerExp {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		fmt::LowerExp::fmt(&self.Sv_per_s, f)?;
+		write!(f, " {}", Self::unit_symbol())
+	}
+}
+
+/// Formats dose equivalent rate values in exponential notation followed by the unit symbol, eg "1.5E3 Sv/s"
+impl<T> fmt::UpperExp for DoseEquivalentRate<T> where T: NumLike+fmt::UpperExp {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		fmt::UpperExp::fmt(&self.Sv_per_s, f)?;
+		write!(f, " {}", Self::unit_symbol())
+	}
+}
+
+impl<T> super::SiUnit for DoseEquivalentRate<T> where T: NumLike {
+	type Number = T;
+	fn unit_name(&self) -> &'static str { Self::unit_name() }
+	fn unit_symbol(&self) -> &'static str { Self::unit_symbol() }
+	fn raw(&self) -> &T { &self.Sv_per_s }
+	fn dimension(&self) -> super::Dimension { Self::dimension() }
+	fn from_raw(value: T) -> Self { DoseEquivalentRate{Sv_per_s: value} }
+	fn into_raw(self) -> T { self.Sv_per_s }
+}
+
+#[cfg(feature="alloc")]
+impl<T> DoseEquivalentRate<T> where T: NumLike {
+	/// Returns this dose equivalent rate value in the canonical text format "<value> <symbol>", eg "5 Sv/s".
+	/// Unlike `Display`, this format never changes with precision or alternate flags, and it is the
+	/// input format expected by `from_str(...)`, so `x.canonical_string().parse()` returns the same value
+	pub fn canonical_string(&self) -> alloc::string::String {
+		alloc::format!("{} {}", self.Sv_per_s, Self::unit_symbol())
+	}
+
+	/// Returns a `Vec` of dose equivalent rate values copied from the given slice of numbers
+	///
+	/// # Arguments
+	/// * `values` - Slice of numbers, representing quantities of sieverts per second
+	pub fn from_Sv_per_s_slice(values: &[T]) -> alloc::vec::Vec<Self> {
+		values.iter().map(|x| DoseEquivalentRate{Sv_per_s: x.clone()}).collect()
+	}
+}
+
+/// Parses a dose equivalent rate value from a number followed by its unit symbol, eg "5 Sv/s"
+impl<T> core::str::FromStr for DoseEquivalentRate<T> where T: NumLike+core::str::FromStr {
+	type Err = SiParseError;
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		Ok(DoseEquivalentRate{Sv_per_s: super::parse_unit_value(s, Self::unit_symbol())?})
+	}
+}
+
+/// Parses a dose equivalent rate value from a number followed by its unit symbol, eg "5 Sv/s" 
+/// (same as `str::parse()`)
+impl<T> core::convert::TryFrom<&str> for DoseEquivalentRate<T> where T: NumLike+core::str::FromStr {
+	type Error = SiParseError;
+	fn try_from(s: &str) -> Result<Self, Self::Error> {
+		<Self as core::str::FromStr>::from_str(s)
+	}
+}
+
+#[cfg(feature="num-traits")]
+impl<T> DoseEquivalentRate<T> where T: NumLike+num_traits::Euclid {
+
+	/// Returns the Euclidean remainder of dividing this dose equivalent rate value by another, 
+	/// which is never negative (requires the `num-traits` feature)
+	///
+	/// # Arguments
+	/// * `rhs` - The dose equivalent rate value to divide by
+	pub fn rem_euclid(self, rhs: Self) -> Self {
+		DoseEquivalentRate{Sv_per_s: self.Sv_per_s.rem_euclid(&rhs.Sv_per_s)}
+	}
+
+	/// Returns the Euclidean quotient of dividing this dose equivalent rate value by another, 
+	/// ie the whole number of times that `rhs` fits into this value, rounded such that 
+	/// `rem_euclid(...)` is never negative (requires the `num-traits` feature)
+	///
+	/// # Arguments
+	/// * `rhs` - The dose equivalent rate value to divide by
+	pub fn div_euclid(self, rhs: Self) -> T {
+		self.Sv_per_s.div_euclid(&rhs.Sv_per_s)
+	}
+}
+
+/// Summing dose equivalent rate values returns their total as a dose equivalent rate value (or zero if there are no values)
+impl<T> core::iter::Sum<DoseEquivalentRate<T>> for DoseEquivalentRate<T> where T: NumLike+core::iter::Sum {
+	fn sum<I: Iterator<Item=DoseEquivalentRate<T>>>(iter: I) -> Self {
+		DoseEquivalentRate{Sv_per_s: iter.map(|x| x.Sv_per_s).sum()}
+	}
+}
+/// Summing dose equivalent rate values returns their total as a dose equivalent rate value (or zero if there are no values)
+impl<'a, T> core::iter::Sum<&'a DoseEquivalentRate<T>> for DoseEquivalentRate<T> where T: NumLike+core::iter::Sum+'a {
+	fn sum<I: Iterator<Item=&'a DoseEquivalentRate<T>>>(iter: I) -> Self {
+		DoseEquivalentRate{Sv_per_s: iter.map(|x| x.Sv_per_s.clone()).sum()}
+	}
+}
+
+impl<T> DoseEquivalentRate<T> where T: NumLike+From<f64> {
+
+	/// Returns the weighted average of the given dose equivalent rate values, where each 
+	/// value is paired with its weight (weights do not need to add up to 1). If the 
+	/// total weight is zero (including when `values` is empty), then zero is returned
+	///
+	/// # Arguments
+	/// * `values` - Slice of (dose equivalent rate value, weight) pairs
+	pub fn weighted_mean(values: &[(Self, f64)]) -> Self {
+		let total_weight: f64 = values.iter().map(|(_, weight)| *weight).sum();
+		if total_weight == 0.0 {
+			return DoseEquivalentRate{Sv_per_s: T::from(0.0)};
+		}
+		let total = values.iter().fold(T::from(0.0),
+			|total, (value, weight)| total + value.Sv_per_s.clone() * T::from(*weight));
+		DoseEquivalentRate{Sv_per_s: total / T::from(total_weight)}
+	}
+}
+
+impl<T> DoseEquivalentRate<T> where T: NumLike+PartialEq+From<u8> {
+
+	/// Divides this dose equivalent rate value by another unit value (eg `distance.checked_div(time)` 
+	/// returns a velocity), returning `None` if the divisor is zero instead of producing 
+	/// infinity or NaN (or panicking, for integer number types). This works for every unit 
+	/// type that this dose equivalent rate value can be divided by with the `/` operator
+	///
+	/// # Arguments
+	/// * `rhs` - The unit value to divide by
+	pub fn checked_div<R>(self, rhs: R) -> Option<<Self as core::ops::Div<R>>::Output> 
+		where Self: core::ops::Div<R>, R: super::SiUnit<Number=T>
+	{
+		if *rhs.raw() == T::from(0u8) {
+			return None;
+		}
+		Some(self / rhs)
+	}
+}
+
+/// Multiplying a dose equivalent rate value by a dimensionless value returns a dose equivalent rate value
+impl<T> core::ops::Mul<super::base::Dimensionless<T>> for DoseEquivalentRate<T> where T: NumLike {
+	type Output = DoseEquivalentRate<T>;
+	fn mul(self, rhs: super::base::Dimensionless<T>) -> Self::Output {
+		DoseEquivalentRate{Sv_per_s: self.Sv_per_s * rhs.value}
+	}
+}
+/// Multiplying a dimensionless value by a dose equivalent rate value returns a dose equivalent rate value
+impl<T> core::ops::Mul<DoseEquivalentRate<T>> for super::base::Dimensionless<T> where T: NumLike {
+	type Output = DoseEquivalentRate<T>;
+	fn mul(self, rhs: DoseEquivalentRate<T>) -> Self::Output {
+		DoseEquivalentRate{Sv_per_s: self.value * rhs.Sv_per_s}
+	}
+}
+/// Dividing a dose equivalent rate value by a dimensionless value returns a dose equivalent rate value
+impl<T> core::ops::Div<super::base::Dimensionless<T>> for DoseEquivalentRate<T> where T: NumLike {
+	type Output = DoseEquivalentRate<T>;
+	fn div(self, rhs: super::base::Dimensionless<T>) -> Self::Output {
+		DoseEquivalentRate{Sv_per_s: self.Sv_per_s / rhs.value}
+	}
+}
+
+/// The remainder of dividing a dose equivalent rate value by another dose equivalent rate value is a dose equivalent rate value
+impl<T> core::ops::Rem<DoseEquivalentRate<T>> for DoseEquivalentRate<T> where T: NumLike+core::ops::Rem<Output=T> {
+	type Output = DoseEquivalentRate<T>;
+	fn rem(self, rhs: DoseEquivalentRate<T>) -> Self::Output {
+		DoseEquivalentRate{Sv_per_s: self.Sv_per_s % rhs.Sv_per_s}
+	}
+}
+/// The remainder of dividing a dose equivalent rate value by another dose equivalent rate value is a dose equivalent rate value
+impl<T> core::ops::Rem<&DoseEquivalentRate<T>> for DoseEquivalentRate<T> where T: NumLike+core::ops::Rem<Output=T> {
+	type Output = DoseEquivalentRate<T>;
+	fn rem(self, rhs: &DoseEquivalentRate<T>) -> Self::Output {
+		DoseEquivalentRate{Sv_per_s: self.Sv_per_s % rhs.Sv_per_s.clone()}
+	}
+}
+/// The remainder of dividing a dose equivalent rate value by another dose equivalent rate value is a dose equivalent rate value
+impl<T> core::ops::Rem<DoseEquivalentRate<T>> for &DoseEquivalentRate<T> where T: NumLike+core::ops::Rem<Output=T> {
+	type Output = DoseEquivalentRate<T>;
+	fn rem(self, rhs: DoseEquivalentRate<T>) -> Self::Output {
+		DoseEquivalentRate{Sv_per_s: self.Sv_per_s.clone() % rhs.Sv_per_s}
+	}
+}
+/// The remainder of dividing a dose equivalent rate value by another dose equivalent rate value is a dose equivalent rate value
+impl<T> core::ops::Rem<&DoseEquivalentRate<T>> for &DoseEquivalentRate<T> where T: NumLike+core::ops::Rem<Output=T> {
+	type Output = DoseEquivalentRate<T>;
+	fn rem(self, rhs: &DoseEquivalentRate<T>) -> Self::Output {
+		DoseEquivalentRate{Sv_per_s: self.Sv_per_s.clone() % rhs.Sv_per_s.clone()}
+	}
+}
+
+/// Serializes a dose equivalent rate value together with its unit symbol, eg 
+/// `{"value": 1.5, "unit": "Sv/s"}` (requires the `serde-unit-tag` feature)
+#[cfg(feature="serde-unit-tag")]
+impl<T> serde::Serialize for DoseEquivalentRate<T> where T: NumLike+serde::Serialize {
+	fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+		super::serialize_with_unit_tag(&self.Sv_per_s, Self::unit_symbol(), serializer)
+	}
+}
+/// Deserializes a dose equivalent rate value from a value and unit symbol, eg 
+/// `{"value": 1.5, "unit": "Sv/s"}`, returning an error if the unit symbol 
+/// does not match (requires the `serde-unit-tag` feature)
+#[cfg(feature="serde-unit-tag")]
+impl<'de, T> serde::Deserialize<'de> for DoseEquivalentRate<T> where T: NumLike+serde::Deserialize<'de> {
+	fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+		Ok(DoseEquivalentRate{Sv_per_s: super::deserialize_with_unit_tag(Self::unit_symbol(), deserializer)?})
+	}
+}
+
+/// The additive identity of dose equivalent rate values is zero sieverts per second (requires the `num-traits` feature)
+#[cfg(feature="num-traits")]
+impl<T> num_traits::Zero for DoseEquivalentRate<T> where T: NumLike+num_traits::Zero {
+	fn zero() -> Self { DoseEquivalentRate{Sv_per_s: T::zero()} }
+	fn is_zero(&self) -> bool { self.Sv_per_s.is_zero() }
+}
+
+#[cfg(feature="num-traits")]
+impl<T> DoseEquivalentRate<T> where T: NumLike+num_traits::Signed {
+
+	/// Returns the sign of this dose equivalent rate value as a dimensionless number: 1 if it is 
+	/// positive, -1 if it is negative, and 0 if it is zero for integer number types 
+	/// (for floating-point number types, +0.0 returns 1.0, -0.0 returns -1.0, and NaN returns 
+	/// NaN) (requires the `num-traits` feature)
+	pub fn signum(self) -> T {
+		self.Sv_per_s.signum()
+	}
+}
+
+impl<T> DoseEquivalentRate<T> where T: NumLike+PartialOrd {
+
+	/// Returns the absolute value of this dose equivalent rate value, ie its magnitude without a minus sign
+	/// (for floating-point number types, -0.0 returns +0.0)
+	pub fn abs(self) -> Self {
+		let negated = -self.Sv_per_s.clone();
+		if self.Sv_per_s < negated {
+			DoseEquivalentRate{Sv_per_s: negated}
+		} else if self.Sv_per_s == negated {
+			// zero, which is its own negation (adding +0.0 turns -0.0 into +0.0)
+			DoseEquivalentRate{Sv_per_s: negated + self.Sv_per_s}
+		} else {
+			self
+		}
+	}
+
+	/// Returns the lesser of this dose equivalent rate value and the given dose equivalent rate value
+	///
+	/// # Arguments
+	/// * `other` - The dose equivalent rate value to compare with this one
+	pub fn min(self, other: Self) -> Self {
+		if other.Sv_per_s < self.Sv_per_s { other } else { self }
+	}
+
+	/// Returns the greater of this dose equivalent rate value and the given dose equivalent rate value
+	///
+	/// # Arguments
+	/// * `other` - The dose equivalent rate value to compare with this one
+	pub fn max(self, other: Self) -> Self {
+		if other.Sv_per_s > self.Sv_per_s { other } else { self }
+	}
+
+	/// Returns the least of the given dose equivalent rate values, or `None` if there are none. 
+	/// Unlike `Iterator::min()`, this does not require `Ord`, so it works for 
+	/// floating-point numbers. NaN values are skipped (so the result is `None` if all 
+	/// values are NaN), and the first of several equal values is returned
+	///
+	/// # Arguments
+	/// * `values` - Any iterator or collection of dose equivalent rate values
+	pub fn min_of<I>(values: I) -> Option<Self> where I: IntoIterator<Item=Self> {
+		values.into_iter().filter(|x| x.Sv_per_s.partial_cmp(&x.Sv_per_s).is_some()).reduce(Self::min)
+	}
+
+	/// Returns the greatest of the given dose equivalent rate values, or `None` if there are none. 
+	/// Unlike `Iterator::max()`, this does not require `Ord`, so it works for 
+	/// floating-point numbers. NaN values are skipped (so the result is `None` if all 
+	/// values are NaN), and the first of several equal values is returned
+	///
+	/// # Arguments
+	/// * `values` - Any iterator or collection of dose equivalent rate values
+	pub fn max_of<I>(values: I) -> Option<Self> where I: IntoIterator<Item=Self> {
+		values.into_iter().filter(|x| x.Sv_per_s.partial_cmp(&x.Sv_per_s).is_some()).reduce(Self::max)
+	}
+
+	/// Returns this dose equivalent rate value restricted to the range from `lo` to `hi`
+	///
+	/// # Arguments
+	/// * `lo` - The minimum dose equivalent rate value
+	/// * `hi` - The maximum dose equivalent rate value
+	///
+	/// # Panics
+	/// Panics if `lo` is greater than `hi` (or if either of them is NaN)
+	pub fn clamp(self, lo: Self, hi: Self) -> Self {
+		assert!(lo.Sv_per_s <= hi.Sv_per_s, "lo must be less than or equal to hi");
+		if self.Sv_per_s < lo.Sv_per_s { lo } else if self.Sv_per_s > hi.Sv_per_s { hi } else { self }
+	}
+
+	/// Returns this dose equivalent rate value with its magnitude capped at `max`, keeping its 
+	/// sign (eg for capping a speed), or this value unchanged if its magnitude does not 
+	/// exceed `max`. For complex-valued dose equivalent rate, use `clamp_norm(...)` instead, 
+	/// which caps the magnitude while keeping the phase
+	///
+	/// # Arguments
+	/// * `max` - The maximum magnitude (should not be negative)
+	pub fn clamp_magnitude(self, max: Self) -> Self {
+		let negated_max = -max.Sv_per_s.clone();
+		if self.Sv_per_s > max.Sv_per_s { 
+			max 
+		} else if self.Sv_per_s < negated_max { 
+			DoseEquivalentRate{Sv_per_s: negated_max} 
+		} else { 
+			self 
+		}
+	}
+
+	/// Returns true if this dose equivalent rate value differs from the given dose equivalent rate 
+	/// value by no more than the given absolute tolerance (which is also a 
+	/// dose equivalent rate value, so the comparison is dimensionally safe). NaN is never 
+	/// equal to anything. See also the `assert_unit_eq!(...)` macro.
+	///
+	/// # Arguments
+	/// * `other` - The dose equivalent rate value to compare with this one
+	/// * `tolerance` - The largest allowed difference between the two values
+	pub fn eq_within(self, other: Self, tolerance: Self) -> bool {
+		let diff = self.Sv_per_s - other.Sv_per_s;
+		diff <= tolerance.Sv_per_s && -diff <= tolerance.Sv_per_s
+	}
+}
+
+#[cfg(feature="num-complex")]
+impl DoseEquivalentRate<num_complex::Complex32> {
+	/// Returns the magnitude (aka norm or absolute value) of this complex dose equivalent rate 
+	/// value as a real-valued dose equivalent rate (requires the `num-complex` feature)
+	pub fn magnitude(self) -> DoseEquivalentRate<f32> {
+		DoseEquivalentRate{Sv_per_s: self.Sv_per_s.norm()}
+	}
+
+	/// Returns this complex dose equivalent rate value scaled down so that its magnitude is 
+	/// at most `max` (keeping its phase), or this value unchanged if its magnitude does 
+	/// not exceed `max`. This is the complex equivalent of `clamp_magnitude(...)` (requires 
+	/// the `num-complex` feature)
+	///
+	/// # Arguments
+	/// * `max` - The maximum magnitude (should not be negative)
+	pub fn clamp_norm(self, max: DoseEquivalentRate<f32>) -> Self {
+		let norm = self.Sv_per_s.norm();
+		if norm > max.Sv_per_s {
+			DoseEquivalentRate{Sv_per_s: self.Sv_per_s * (max.Sv_per_s / norm)}
+		} else {
+			self
+		}
+	}
+}
+
+#[cfg(feature="num-complex")]
+impl DoseEquivalentRate<num_complex::Complex64> {
+	/// Returns the magnitude (aka norm or absolute value) of this complex dose equivalent rate 
+	/// value as a real-valued dose equivalent rate (requires the `num-complex` feature)
+	pub fn magnitude(self) -> DoseEquivalentRate<f64> {
+		DoseEquivalentRate{Sv_per_s: self.Sv_per_s.norm()}
+	}
+
+	/// Returns this complex dose equivalent rate value scaled down so that its magnitude is 
+	/// at most `max` (keeping its phase), or this value unchanged if its magnitude does 
+	/// not exceed `max`. This is the complex equivalent of `clamp_magnitude(...)` (requires 
+	/// the `num-complex` feature)
+	///
+	/// # Arguments
+	/// * `max` - The maximum magnitude (should not be negative)
+	pub fn clamp_norm(self, max: DoseEquivalentRate<f64>) -> Self {
+		let norm = self.Sv_per_s.norm();
+		if norm > max.Sv_per_s {
+			DoseEquivalentRate{Sv_per_s: self.Sv_per_s * (max.Sv_per_s / norm)}
+		} else {
+			self
+		}
+	}
+}
+
+/// Rounding and other floating-point functions (requires the `num-traits` feature, 
+/// and either the `std` or `libm` feature)
+#[cfg(all(feature="num-traits", any(feature="std", feature="libm")))]
+impl<T> DoseEquivalentRate<T> where T: NumLike+num_traits::Float {
+
+	/// Returns this dose equivalent rate value rounded to the nearest whole number of sieverts per second 
+	/// (half-way cases are rounded away from zero)
+	pub fn round(self) -> Self {
+		DoseEquivalentRate{Sv_per_s: self.Sv_per_s.round()}
+	}
+
+	/// Returns this dose equivalent rate value rounded down to a whole number of sieverts per second
+	pub fn floor(self) -> Self {
+		DoseEquivalentRate{Sv_per_s: self.Sv_per_s.floor()}
+	}
+
+	/// Returns this dose equivalent rate value rounded up to a whole number of sieverts per second
+	pub fn ceil(self) -> Self {
+		DoseEquivalentRate{Sv_per_s: self.Sv_per_s.ceil()}
+	}
+
+	/// Returns this dose equivalent rate value rounded towards zero to a whole number of sieverts per second
+	pub fn trunc(self) -> Self {
+		DoseEquivalentRate{Sv_per_s: self.Sv_per_s.trunc()}
+	}
+
+	/// Returns this dose equivalent rate value rounded to the nearest multiple of the given step 
+	/// (half-way cases are rounded away from zero)
+	///
+	/// # Arguments
+	/// * `step` - The dose equivalent rate value to round to a multiple of
+	pub fn round_to(self, step: Self) -> Self {
+		DoseEquivalentRate{Sv_per_s: (self.Sv_per_s / step.Sv_per_s).round() * step.Sv_per_s}
+	}
+
+	/// Fused multiply-add: returns `self * a + b` computed with only one rounding 
+	/// error, which is more accurate (and on some hardware faster) than a separate 
+	/// multiplication and addition
+	///
+	/// # Arguments
+	/// * `a` - The scalar value to multiply this dose equivalent rate value by
+	/// * `b` - The dose equivalent rate value to add to the product
+	pub fn mul_add(self, a: T, b: Self) -> Self {
+		DoseEquivalentRate{Sv_per_s: self.Sv_per_s.mul_add(a, b.Sv_per_s)}
+	}
+
+	/// Returns true if this dose equivalent rate value is neither infinite nor NaN
+	pub fn is_finite(&self) -> bool {
+		self.Sv_per_s.is_finite()
+	}
+
+	/// Returns true if this dose equivalent rate value is positive or negative infinity
+	pub fn is_infinite(&self) -> bool {
+		self.Sv_per_s.is_infinite()
+	}
+
+	/// Returns true if this dose equivalent rate value is NaN (not a number)
+	pub fn is_nan(&self) -> bool {
+		self.Sv_per_s.is_nan()
+	}
+
+	/// Returns true if this dose equivalent rate value has a positive sign, including +0.0 
+	/// and positive infinity
+	pub fn is_sign_positive(&self) -> bool {
+		self.Sv_per_s.is_sign_positive()
+	}
+
+	/// Returns true if this dose equivalent rate value has a negative sign, including -0.0 
+	/// and negative infinity
+	pub fn is_sign_negative(&self) -> bool {
+		self.Sv_per_s.is_sign_negative()
+	}
+
+	/// Returns a dose equivalent rate value with the magnitude of this one and the sign of 
+	/// `sign_source`, eg for reflecting a value off of a surface
+	///
+	/// # Arguments
+	/// * `sign_source` - The dose equivalent rate value whose sign is copied
+	pub fn copysign(self, sign_source: Self) -> Self {
+		DoseEquivalentRate{Sv_per_s: self.Sv_per_s.copysign(sign_source.Sv_per_s)}
+	}
+}
+
+#[cfg(feature="num-traits")]
+impl<T> DoseEquivalentRate<T> where T: NumLike+num_traits::CheckedAdd+num_traits::CheckedSub {
+
+	/// Adds another dose equivalent rate value to this one, returning `None` if the 
+	/// addition overflows the number type (requires the `num-traits` feature)
+	pub fn checked_add(self, rhs: Self) -> Option<Self> {
+		Some(DoseEquivalentRate{Sv_per_s: self.Sv_per_s.checked_add(&rhs.Sv_per_s)?})
+	}
+
+	/// Subtracts another dose equivalent rate value from this one, returning `None` if 
+	/// the subtraction overflows the number type (requires the `num-traits` feature)
+	pub fn checked_sub(self, rhs: Self) -> Option<Self> {
+		Some(DoseEquivalentRate{Sv_per_s: self.Sv_per_s.checked_sub(&rhs.Sv_per_s)?})
+	}
+}
+
+#[cfg(feature="num-traits")]
+impl<T> DoseEquivalentRate<T> where T: NumLike+num_traits::SaturatingAdd+num_traits::SaturatingSub {
+
+	/// Adds another dose equivalent rate value to this one, clamping the result to the 
+	/// minimum or maximum value of the number type instead of overflowing 
+	/// (requires the `num-traits` feature)
+	pub fn saturating_add(self, rhs: Self) -> Self {
+		DoseEquivalentRate{Sv_per_s: self.Sv_per_s.saturating_add(&rhs.Sv_per_s)}
+	}
+
+	/// Subtracts another dose equivalent rate value from this one, clamping the result 
+	/// to the minimum or maximum value of the number type instead of overflowing 
+	/// (requires the `num-traits` feature)
+	pub fn saturating_sub(self, rhs: Self) -> Self {
+		DoseEquivalentRate{Sv_per_s: self.Sv_per_s.saturating_sub(&rhs.Sv_per_s)}
+	}
+}
+
+impl<T> DoseEquivalentRate<T> where T: NumLike+Into<f64> {
+
+	/// Returns true if this dose equivalent rate value is equal to the given dose equivalent rate 
+	/// value to within the given number of significant figures (eg 6 significant 
+	/// figures allows a relative difference of up to 1 part per million). NaN is 
+	/// only approximately equal to NaN, infinity is only approximately equal to 
+	/// infinity of the same sign, and zero is only approximately equal to zero.
+	///
+	/// # Arguments
+	/// * `other` - The dose equivalent rate value to compare with this one
+	/// * `sig_figs` - The number of significant figures that must match
+	pub fn approx_eq(self, other: Self, sig_figs: i32) -> bool {
+		super::approx_eq_f64(self.Sv_per_s.into(), other.Sv_per_s.into(), sig_figs)
+	}
+}
+
+impl<T> DoseEquivalentRate<T> where T: NumLike+From<f64> {
+
+	/// Returns the linear interpolation between this dose equivalent rate value and the given 
+	/// dose equivalent rate value, ie `self*(1-t) + other*t`. The interpolation factor is not 
+	/// clamped, so values of `t` outside the range 0 to 1 extrapolate beyond the two 
+	/// values (see `lerp_clamped(...)`).
+	///
+	/// # Arguments
+	/// * `other` - The dose equivalent rate value to interpolate towards (returned when `t` is 1)
+	/// * `t` - The interpolation factor, where 0 returns this value and 1 returns `other`
+	pub fn lerp(self, other: Self, t: f64) -> Self {
+		DoseEquivalentRate{Sv_per_s: self.Sv_per_s * T::from(1.0 - t) + other.Sv_per_s * T::from(t)}
+	}
+
+	/// Same as `lerp(...)`, except that the interpolation factor `t` is first clamped 
+	/// to the range 0 to 1, so that the result is always between the two values
+	///
+	/// # Arguments
+	/// * `other` - The dose equivalent rate value to interpolate towards (returned when `t` is 1 or more)
+	/// * `t` - The interpolation factor, where 0 returns this value and 1 returns `other`
+	pub fn lerp_clamped(self, other: Self, t: f64) -> Self {
+		self.lerp(other, t.clamp(0.0, 1.0))
+	}
+	
+	/// Returns a copy of this dose equivalent rate value in millisieverts per second
+	/// 
+	/// *Note: This method is not available for `f32` and other number types lacking the `From<f64>` trait*
+	pub fn to_mSv_per_s(&self) -> T {
+		return self.Sv_per_s.clone() * T::from(1000.0_f64);
+	}
+
+	/// Returns a new dose equivalent rate value from the given number of millisieverts per second
+	/// 
+	/// *Note: This method is not available for `f32` and other number types lacking the `From<f64>` trait*
+	///
+	/// # Arguments
+	/// * `mSv_per_s` - Any number-like type, representing a quantity of millisieverts per second
+	pub fn from_mSv_per_s(mSv_per_s: T) -> Self {
+		DoseEquivalentRate{Sv_per_s: mSv_per_s * T::from(0.001_f64)}
+	}
+
+	/// Returns a copy of this dose equivalent rate value in microsieverts per second
+	/// 
+	/// *Note: This method is not available for `f32` and other number types lacking the `From<f64>` trait*
+	pub fn to_uSv_per_s(&self) -> T {
+		return self.Sv_per_s.clone() * T::from(1000000.0_f64);
+	}
+
+	/// Returns a new dose equivalent rate value from the given number of microsieverts per second
+	/// 
+	/// *Note: This method is not available for `f32` and other number types lacking the `From<f64>` trait*
+	///
+	/// # Arguments
+	/// * `uSv_per_s` - Any number-like type, representing a quantity of microsieverts per second
+	pub fn from_uSv_per_s(uSv_per_s: T) -> Self {
+		DoseEquivalentRate{Sv_per_s: uSv_per_s * T::from(1e-06_f64)}
+	}
+
+	/// Returns a copy of this dose equivalent rate value in sieverts per hour
+	/// 
+	/// *Note: This method is not available for `f32` and other number types lacking the `From<f64>` trait*
+	pub fn to_Sv_per_h(&self) -> T {
+		return self.Sv_per_s.clone() * T::from(3600.0_f64);
+	}
+
+	/// Returns a new dose equivalent rate value from the given number of sieverts per hour
+	/// 
+	/// *Note: This method is not available for `f32` and other number types lacking the `From<f64>` trait*
+	///
+	/// # Arguments
+	/// * `Sv_per_h` - Any number-like type, representing a quantity of sieverts per hour
+	pub fn from_Sv_per_h(Sv_per_h: T) -> Self {
+		DoseEquivalentRate{Sv_per_s: Sv_per_h * T::from(0.0002777777777777778_f64)}
+	}
+
+	/// Returns a copy of this dose equivalent rate value in millisieverts per hour
+	/// 
+	/// *Note: This method is not available for `f32` and other number types lacking the `From<f64>` trait*
+	pub fn to_mSv_per_h(&self) -> T {
+		return self.Sv_per_s.clone() * T::from(3600000.0_f64);
+	}
+
+	/// Returns a new dose equivalent rate value from the given number of millisieverts per hour
+	/// 
+	/// *Note: This method is not available for `f32` and other number types lacking the `From<f64>` trait*
+	///
+	/// # Arguments
+	/// * `mSv_per_h` - Any number-like type, representing a quantity of millisieverts per hour
+	pub fn from_mSv_per_h(mSv_per_h: T) -> Self {
+		DoseEquivalentRate{Sv_per_s: mSv_per_h * T::from(2.7777777777777776e-07_f64)}
+	}
+
+	/// Returns a copy of this dose equivalent rate value in microsieverts per hour
+	/// 
+	/// *Note: This method is not available for `f32` and other number types lacking the `From<f64>` trait*
+	pub fn to_uSv_per_h(&self) -> T {
+		return self.Sv_per_s.clone() * T::from(3600000000.0_f64);
+	}
+
+	/// Returns a new dose equivalent rate value from the given number of microsieverts per hour
+	/// 
+	/// *Note: This method is not available for `f32` and other number types lacking the `From<f64>` trait*
+	///
+	/// # Arguments
+	/// * `uSv_per_h` - Any number-like type, representing a quantity of microsieverts per hour
+	pub fn from_uSv_per_h(uSv_per_h: T) -> Self {
+		DoseEquivalentRate{Sv_per_s: uSv_per_h * T::from(2.7777777777777777e-10_f64)}
+	}
+
+	/// Returns a copy of this dose equivalent rate value in nanosieverts per hour
+	/// 
+	/// *Note: This method is not available for `f32` and other number types lacking the `From<f64>` trait*
+	pub fn to_nSv_per_h(&self) -> T {
+		return self.Sv_per_s.clone() * T::from(3600000000000.0_f64);
+	}
+
+	/// Returns a new dose equivalent rate value from the given number of nanosieverts per hour
+	/// 
+	/// *Note: This method is not available for `f32` and other number types lacking the `From<f64>` trait*
+	///
+	/// # Arguments
+	/// * `nSv_per_h` - Any number-like type, representing a quantity of nanosieverts per hour
+	pub fn from_nSv_per_h(nSv_per_h: T) -> Self {
+		DoseEquivalentRate{Sv_per_s: nSv_per_h * T::from(2.777777777777778e-13_f64)}
+	}
+
+}
+
+
+/// Multiplying a unit value by a scalar value returns a unit value
+#[cfg(feature="num-bigfloat")]
+impl core::ops::Mul<DoseEquivalentRate<num_bigfloat::BigFloat>> for num_bigfloat::BigFloat {
+	type Output = DoseEquivalentRate<num_bigfloat::BigFloat>;
+	fn mul(self, rhs: DoseEquivalentRate<num_bigfloat::BigFloat>) -> Self::Output {
+		DoseEquivalentRate{Sv_per_s: self * rhs.Sv_per_s}
+	}
+}
+/// Multiplying a unit value by a scalar value returns a unit value
+#[cfg(feature="num-bigfloat")]
+impl core::ops::Mul<DoseEquivalentRate<num_bigfloat::BigFloat>> for &num_bigfloat::BigFloat {
+	type Output = DoseEquivalentRate<num_bigfloat::BigFloat>;
+	fn mul(self, rhs: DoseEquivalentRate<num_bigfloat::BigFloat>) -> Self::Output {
+		DoseEquivalentRate{Sv_per_s: self.clone() * rhs.Sv_per_s}
+	}
+}
+/// Multiplying a unit value by a scalar value returns a unit value
+#[cfg(feature="num-bigfloat")]
+impl core::ops::Mul<&DoseEquivalentRate<num_bigfloat::BigFloat>> for num_bigfloat::BigFloat {
+	type Output = DoseEquivalentRate<num_bigfloat::BigFloat>;
+	fn mul(self, rhs: &DoseEquivalentRate<num_bigfloat::BigFloat>) -> Self::Output {
+		DoseEquivalentRate{Sv_per_s: self * rhs.Sv_per_s.clone()}
+	}
+}
+/// Multiplying a unit value by a scalar value returns a unit value
+#[cfg(feature="num-bigfloat")]
+impl core::ops::Mul<&DoseEquivalentRate<num_bigfloat::BigFloat>> for &num_bigfloat::BigFloat {
+	type Output = DoseEquivalentRate<num_bigfloat::BigFloat>;
+	fn mul(self, rhs: &DoseEquivalentRate<num_bigfloat::BigFloat>) -> Self::Output {
+		DoseEquivalentRate{Sv_per_s: self.clone() * rhs.Sv_per_s.clone()}
+	}
+}
+
+/// Multiplying a unit value by a scalar value returns a unit value
+#[cfg(feature="num-complex")]
+impl core::ops::Mul<DoseEquivalentRate<num_complex::Complex32>> for num_complex::Complex32 {
+	type Output = DoseEquivalentRate<num_complex::Complex32>;
+	fn mul(self, rhs: DoseEquivalentRate<num_complex::Complex32>) -> Self::Output {
+		DoseEquivalentRate{Sv_per_s: self * rhs.Sv_per_s}
+	}
+}
+/// Multiplying a unit value by a scalar value returns a unit value
+#[cfg(feature="num-complex")]
+impl core::ops::Mul<DoseEquivalentRate<num_complex::Complex32>> for &num_complex::Complex32 {
+	type Output = DoseEquivalentRate<num_complex::Complex32>;
+	fn mul(self, rhs: DoseEquivalentRate<num_complex::Complex32>) -> Self::Output {
+		DoseEquivalentRate{Sv_per_s: self.clone() * rhs.Sv_per_s}
+	}
+}
+/// Multiplying a unit value by a scalar value returns a unit value
+#[cfg(feature="num-complex")]
+impl core::ops::Mul<&DoseEquivalentRate<num_complex::Complex32>> for num_complex::Complex32 {
+	type Output = DoseEquivalentRate<num_complex::Complex32>;
+	fn mul(self, rhs: &DoseEquivalentRate<num_complex::Complex32>) -> Self::Output {
+		DoseEquivalentRate{Sv_per_s: self * rhs.Sv_per_s.clone()}
+	}
+}
+/// Multiplying a unit value by a scalar value returns a unit value
+#[cfg(feature="num-complex")]
+impl core::ops::Mul<&DoseEquivalentRate<num_complex::Complex32>> for &num_complex::Complex32 {
+	type Output = DoseEquivalentRate<num_complex::Complex32>;
+	fn mul(self, rhs: &DoseEquivalentRate<num_complex::Complex32>) -> Self::Output {
+		DoseEquivalentRate{Sv_per_s: self.clone() * rhs.Sv_per_s.clone()}
+	}
+}
+
+/// Multiplying a unit value by a scalar value returns a unit value
+#[cfg(feature="num-complex")]
+impl core::ops::Mul<DoseEquivalentRate<num_complex::Complex64>> for num_complex::Complex64 {
+	type Output = DoseEquivalentRate<num_complex::Complex64>;
+	fn mul(self, rhs: DoseEquivalentRate<num_complex::Complex64>) -> Self::Output {
+		DoseEquivalentRate{Sv_per_s: self * rhs.Sv_per_s}
+	}
+}
+/// Multiplying a unit value by a scalar value returns a unit value
+#[cfg(feature="num-complex")]
+impl core::ops::Mul<DoseEquivalentRate<num_complex::Complex64>> for &num_complex::Complex64 {
+	type Output = DoseEquivalentRate<num_complex::Complex64>;
+	fn mul(self, rhs: DoseEquivalentRate<num_complex::Complex64>) -> Self::Output {
+		DoseEquivalentRate{Sv_per_s: self.clone() * rhs.Sv_per_s}
+	}
+}
+/// Multiplying a unit value by a scalar value returns a unit value
+#[cfg(feature="num-complex")]
+impl core::ops::Mul<&DoseEquivalentRate<num_complex::Complex64>> for num_complex::Complex64 {
+	type Output = DoseEquivalentRate<num_complex::Complex64>;
+	fn mul(self, rhs: &DoseEquivalentRate<num_complex::Complex64>) -> Self::Output {
+		DoseEquivalentRate{Sv_per_s: self * rhs.Sv_per_s.clone()}
+	}
+}
+/// Multiplying a unit value by a scalar value returns a unit value
+#[cfg(feature="num-complex")]
+impl core::ops::Mul<&DoseEquivalentRate<num_complex::Complex64>> for &num_complex::Complex64 {
+	type Output = DoseEquivalentRate<num_complex::Complex64>;
+	fn mul(self, rhs: &DoseEquivalentRate<num_complex::Complex64>) -> Self::Output {
+		DoseEquivalentRate{Sv_per_s: self.clone() * rhs.Sv_per_s.clone()}
+	}
+}
+
+/// Multiplying a unit value by a scalar value returns a unit value
+#[cfg(feature="num-rational")]
+impl core::ops::Mul<DoseEquivalentRate<num_rational::Rational64>> for num_rational::Rational64 {
+	type Output = DoseEquivalentRate<num_rational::Rational64>;
+	fn mul(self, rhs: DoseEquivalentRate<num_rational::Rational64>) -> Self::Output {
+		DoseEquivalentRate{Sv_per_s: self * rhs.Sv_per_s}
+	}
+}
+/// Multiplying a unit value by a scalar value returns a unit value
+#[cfg(feature="num-rational")]
+impl core::ops::Mul<DoseEquivalentRate<num_rational::Rational64>> for &num_rational::Rational64 {
+	type Output = DoseEquivalentRate<num_rational::Rational64>;
+	fn mul(self, rhs: DoseEquivalentRate<num_rational::Rational64>) -> Self::Output {
+		DoseEquivalentRate{Sv_per_s: self.clone() * rhs.Sv_per_s}
+	}
+}
+/// Multiplying a unit value by a scalar value returns a unit value
+#[cfg(feature="num-rational")]
+impl core::ops::Mul<&DoseEquivalentRate<num_rational::Rational64>> for num_rational::Rational64 {
+	type Output = DoseEquivalentRate<num_rational::Rational64>;
+	fn mul(self, rhs: &DoseEquivalentRate<num_rational::Rational64>) -> Self::Output {
+		DoseEquivalentRate{Sv_per_s: self * rhs.Sv_per_s.clone()}
+	}
+}
+/// Multiplying a unit value by a scalar value returns a unit value
+#[cfg(feature="num-rational")]
+impl core::ops::Mul<&DoseEquivalentRate<num_rational::Rational64>> for &num_rational::Rational64 {
+	type Output = DoseEquivalentRate<num_rational::Rational64>;
+	fn mul(self, rhs: &DoseEquivalentRate<num_rational::Rational64>) -> Self::Output {
+		DoseEquivalentRate{Sv_per_s: self.clone() * rhs.Sv_per_s.clone()}
+	}
+}
+
+/// Multiplying a unit value by a scalar value returns a unit value
+#[cfg(feature="num-rational")]
+impl core::ops::Mul<DoseEquivalentRate<num_rational::BigRational>> for num_rational::BigRational {
+	type Output = DoseEquivalentRate<num_rational::BigRational>;
+	fn mul(self, rhs: DoseEquivalentRate<num_rational::BigRational>) -> Self::Output {
+		DoseEquivalentRate{Sv_per_s: self * rhs.Sv_per_s}
+	}
+}
+/// Multiplying a unit value by a scalar value returns a unit value
+#[cfg(feature="num-rational")]
+impl core::ops::Mul<DoseEquivalentRate<num_rational::BigRational>> for &num_rational::BigRational {
+	type Output = DoseEquivalentRate<num_rational::BigRational>;
+	fn mul(self, rhs: DoseEquivalentRate<num_rational::BigRational>) -> Self::Output {
+		DoseEquivalentRate{Sv_per_s: self.clone() * rhs.Sv_per_s}
+	}
+}
+/// Multiplying a unit value by a scalar value returns a unit value
+#[cfg(feature="num-rational")]
+impl core::ops::Mul<&DoseEquivalentRate<num_rational::BigRational>> for num_rational::BigRational {
+	type Output = DoseEquivalentRate<num_rational::BigRational>;
+	fn mul(self, rhs: &DoseEquivalentRate<num_rational::BigRational>) -> Self::Output {
+		DoseEquivalentRate{Sv_per_s: self * rhs.Sv_per_s.clone()}
+	}
+}
+/// Multiplying a unit value by a scalar value returns a unit value
+#[cfg(feature="num-rational")]
+impl core::ops::Mul<&DoseEquivalentRate<num_rational::BigRational>> for &num_rational::BigRational {
+	type Output = DoseEquivalentRate<num_rational::BigRational>;
+	fn mul(self, rhs: &DoseEquivalentRate<num_rational::BigRational>) -> Self::Output {
+		DoseEquivalentRate{Sv_per_s: self.clone() * rhs.Sv_per_s.clone()}
+	}
+}
+
+
+
+
+
+/// Multiplying a DoseEquivalentRate by an [nalgebra](https://crates.io/crates/nalgebra) 
+/// vector or matrix of unitless values returns a vector or matrix of DoseEquivalentRate 
+/// values (eg a direction vector multiplied by a DoseEquivalentRate)
+#[cfg(feature="nalgebra")]
+impl<T, R, C, S> core::ops::Mul<nalgebra::Matrix<T, R, C, S>> for DoseEquivalentRate<T> where 
+	T: NumLike+nalgebra::Scalar, R: nalgebra::Dim, C: nalgebra::Dim, S: nalgebra::RawStorage<T, R, C>, 
+	nalgebra::DefaultAllocator: nalgebra::allocator::Allocator<R, C> {
+	type Output = nalgebra::OMatrix<DoseEquivalentRate<T>, R, C>;
+	fn mul(self, rhs: nalgebra::Matrix<T, R, C, S>) -> Self::Output {
+		rhs.map(|x| DoseEquivalentRate{Sv_per_s: self.Sv_per_s.clone() * x})
+	}
+}
+/// Multiplying an [nalgebra](https://crates.io/crates/nalgebra) vector or matrix of 
+/// unitless values by a DoseEquivalentRate returns a vector or matrix of DoseEquivalentRate values
+#[cfg(feature="nalgebra")]
+impl<T, R, C, S> core::ops::Mul<DoseEquivalentRate<T>> for nalgebra::Matrix<T, R, C, S> where 
+	T: NumLike+nalgebra::Scalar, R: nalgebra::Dim, C: nalgebra::Dim, S: nalgebra::RawStorage<T, R, C>, 
+	nalgebra::DefaultAllocator: nalgebra::allocator::Allocator<R, C> {
+	type Output = nalgebra::OMatrix<DoseEquivalentRate<T>, R, C>;
+	fn mul(self, rhs: DoseEquivalentRate<T>) -> Self::Output {
+		self.map(|x| DoseEquivalentRate{Sv_per_s: x * rhs.Sv_per_s.clone()})
+	}
+}
+/// Dividing an [nalgebra](https://crates.io/crates/nalgebra) vector or matrix of 
+/// DoseEquivalentRate values by a DoseEquivalentRate returns a vector or matrix of unitless values
+#[cfg(feature="nalgebra")]
+impl<T, R, C, S> core::ops::Div<DoseEquivalentRate<T>> for nalgebra::Matrix<DoseEquivalentRate<T>, R, C, S> where 
+	T: NumLike+nalgebra::Scalar, R: nalgebra::Dim, C: nalgebra::Dim, S: nalgebra::RawStorage<DoseEquivalentRate<T>, R, C>, 
+	nalgebra::DefaultAllocator: nalgebra::allocator::Allocator<R, C> {
+	type Output = nalgebra::OMatrix<T, R, C>;
+	fn div(self, rhs: DoseEquivalentRate<T>) -> Self::Output {
+		self.map(|x| x.Sv_per_s / rhs.Sv_per_s.clone())
+	}
+}
+
+
+
+// DoseEquivalentRate * Time -> DoseEquivalent
+/// Multiplying a DoseEquivalentRate by a Time returns a value of type DoseEquivalent
+impl<T> core::ops::Mul<Time<T>> for DoseEquivalentRate<T> where T: NumLike {
+	type Output = DoseEquivalent<T>;
+	fn mul(self, rhs: Time<T>) -> Self::Output {
+		DoseEquivalent{Sv: self.Sv_per_s * rhs.s}
+	}
+}
+/// Multiplying a DoseEquivalentRate by a Time returns a value of type DoseEquivalent
+impl<T> core::ops::Mul<Time<T>> for &DoseEquivalentRate<T> where T: NumLike {
+	type Output = DoseEquivalent<T>;
+	fn mul(self, rhs: Time<T>) -> Self::Output {
+		DoseEquivalent{Sv: self.Sv_per_s.clone() * rhs.s}
+	}
+}
+/// Multiplying a DoseEquivalentRate by a Time returns a value of type DoseEquivalent
+impl<T> core::ops::Mul<&Time<T>> for DoseEquivalentRate<T> where T: NumLike {
+	type Output = DoseEquivalent<T>;
+	fn mul(self, rhs: &Time<T>) -> Self::Output {
+		DoseEquivalent{Sv: self.Sv_per_s * rhs.s.clone()}
+	}
+}
+/// Multiplying a DoseEquivalentRate by a Time returns a value of type DoseEquivalent
+impl<T> core::ops::Mul<&Time<T>> for &DoseEquivalentRate<T> where T: NumLike {
+	type Output = DoseEquivalent<T>;
+	fn mul(self, rhs: &Time<T>) -> Self::Output {
+		DoseEquivalent{Sv: self.Sv_per_s.clone() * rhs.s.clone()}
+	}
+}
+
+impl<T> DoseEquivalentRate<T> where T: NumLike+From<u8> {
+	/// Returns the reciprocal of this DoseEquivalentRate value (ie 1 divided by 
+	/// this value) as a value of type InverseDoseEquivalentRate
+	pub fn recip(self) -> InverseDoseEquivalentRate<T> {
+		InverseDoseEquivalentRate{s_per_Sv: T::from(1u8) / self.Sv_per_s}
+	}
+}
+
+// 1/DoseEquivalentRate -> InverseDoseEquivalentRate
+/// Dividing a scalar value by a DoseEquivalentRate unit value returns a value of type InverseDoseEquivalentRate
+impl<T> core::ops::Div<DoseEquivalentRate<T>> for f64 where T: NumLike+From<f64> {
+	type Output = InverseDoseEquivalentRate<T>;
+	fn div(self, rhs: DoseEquivalentRate<T>) -> Self::Output {
+		InverseDoseEquivalentRate{s_per_Sv: T::from(self) / rhs.Sv_per_s}
+	}
+}
+/// Dividing a scalar value by a DoseEquivalentRate unit value returns a value of type InverseDoseEquivalentRate
+impl<T> core::ops::Div<DoseEquivalentRate<T>> for &f64 where T: NumLike+From<f64> {
+	type Output = InverseDoseEquivalentRate<T>;
+	fn div(self, rhs: DoseEquivalentRate<T>) -> Self::Output {
+		InverseDoseEquivalentRate{s_per_Sv: T::from(self.clone()) / rhs.Sv_per_s}
+	}
+}
+/// Dividing a scalar value by a DoseEquivalentRate unit value returns a value of type InverseDoseEquivalentRate
+impl<T> core::ops::Div<&DoseEquivalentRate<T>> for f64 where T: NumLike+From<f64> {
+	type Output = InverseDoseEquivalentRate<T>;
+	fn div(self, rhs: &DoseEquivalentRate<T>) -> Self::Output {
+		InverseDoseEquivalentRate{s_per_Sv: T::from(self) / rhs.Sv_per_s.clone()}
+	}
+}
+/// Dividing a scalar value by a DoseEquivalentRate unit value returns a value of type InverseDoseEquivalentRate
+impl<T> core::ops::Div<&DoseEquivalentRate<T>> for &f64 where T: NumLike+From<f64> {
+	type Output = InverseDoseEquivalentRate<T>;
+	fn div(self, rhs: &DoseEquivalentRate<T>) -> Self::Output {
+		InverseDoseEquivalentRate{s_per_Sv: T::from(self.clone()) / rhs.Sv_per_s.clone()}
+	}
+}
+
+// 1/DoseEquivalentRate -> InverseDoseEquivalentRate
+/// Dividing a scalar value by a DoseEquivalentRate unit value returns a value of type InverseDoseEquivalentRate
+impl<T> core::ops::Div<DoseEquivalentRate<T>> for f32 where T: NumLike+From<f32> {
+	type Output = InverseDoseEquivalentRate<T>;
+	fn div(self, rhs: DoseEquivalentRate<T>) -> Self::Output {
+		InverseDoseEquivalentRate{s_per_Sv: T::from(self) / rhs.Sv_per_s}
+	}
+}
+/// Dividing a scalar value by a DoseEquivalentRate unit value returns a value of type InverseDoseEquivalentRate
+impl<T> core::ops::Div<DoseEquivalentRate<T>> for &f32 where T: NumLike+From<f32> {
+	type Output = InverseDoseEquivalentRate<T>;
+	fn div(self, rhs: DoseEquivalentRate<T>) -> Self::Output {
+		InverseDoseEquivalentRate{s_per_Sv: T::from(self.clone()) / rhs.Sv_per_s}
+	}
+}
+/// Dividing a scalar value by a DoseEquivalentRate unit value returns a value of type InverseDoseEquivalentRate
+impl<T> core::ops::Div<&DoseEquivalentRate<T>> for f32 where T: NumLike+From<f32> {
+	type Output = InverseDoseEquivalentRate<T>;
+	fn div(self, rhs: &DoseEquivalentRate<T>) -> Self::Output {
+		InverseDoseEquivalentRate{s_per_Sv: T::from(self) / rhs.Sv_per_s.clone()}
+	}
+}
+/// Dividing a scalar value by a DoseEquivalentRate unit value returns a value of type InverseDoseEquivalentRate
+impl<T> core::ops::Div<&DoseEquivalentRate<T>> for &f32 where T: NumLike+From<f32> {
+	type Output = InverseDoseEquivalentRate<T>;
+	fn div(self, rhs: &DoseEquivalentRate<T>) -> Self::Output {
+		InverseDoseEquivalentRate{s_per_Sv: T::from(self.clone()) / rhs.Sv_per_s.clone()}
+	}
+}
+
+// 1/DoseEquivalentRate -> InverseDoseEquivalentRate
+/// Dividing a scalar value by a DoseEquivalentRate unit value returns a value of type InverseDoseEquivalentRate
+impl<T> core::ops::Div<DoseEquivalentRate<T>> for i64 where T: NumLike+From<i64> {
+	type Output = InverseDoseEquivalentRate<T>;
+	fn div(self, rhs: DoseEquivalentRate<T>) -> Self::Output {
+		InverseDoseEquivalentRate{s_per_Sv: T::from(self) / rhs.Sv_per_s}
+	}
+}
+/// Dividing a scalar value by a DoseEquivalentRate unit value returns a value of type InverseDoseEquivalentRate
+impl<T> core::ops::Div<DoseEquivalentRate<T>> for &i64 where T: NumLike+From<i64> {
+	type Output = InverseDoseEquivalentRate<T>;
+	fn div(self, rhs: DoseEquivalentRate<T>) -> Self::Output {
+		InverseDoseEquivalentRate{s_per_Sv: T::from(self.clone()) / rhs.Sv_per_s}
+	}
+}
+/// Dividing a scalar value by a DoseEquivalentRate unit value returns a value of type InverseDoseEquivalentRate
+impl<T> core::ops::Div<&DoseEquivalentRate<T>> for i64 where T: NumLike+From<i64> {
+	type Output = InverseDoseEquivalentRate<T>;
+	fn div(self, rhs: &DoseEquivalentRate<T>) -> Self::Output {
+		InverseDoseEquivalentRate{s_per_Sv: T::from(self) / rhs.Sv_per_s.clone()}
+	}
+}
+/// Dividing a scalar value by a DoseEquivalentRate unit value returns a value of type InverseDoseEquivalentRate
+impl<T> core::ops::Div<&DoseEquivalentRate<T>> for &i64 where T: NumLike+From<i64> {
+	type Output = InverseDoseEquivalentRate<T>;
+	fn div(self, rhs: &DoseEquivalentRate<T>) -> Self::Output {
+		InverseDoseEquivalentRate{s_per_Sv: T::from(self.clone()) / rhs.Sv_per_s.clone()}
+	}
+}
+
+// 1/DoseEquivalentRate -> InverseDoseEquivalentRate
+/// Dividing a scalar value by a DoseEquivalentRate unit value returns a value of type InverseDoseEquivalentRate
+impl<T> core::ops::Div<DoseEquivalentRate<T>> for i32 where T: NumLike+From<i32> {
+	type Output = InverseDoseEquivalentRate<T>;
+	fn div(self, rhs: DoseEquivalentRate<T>) -> Self::Output {
+		InverseDoseEquivalentRate{s_per_Sv: T::from(self) / rhs.Sv_per_s}
+	}
+}
+/// Dividing a scalar value by a DoseEquivalentRate unit value returns a value of type InverseDoseEquivalentRate
+impl<T> core::ops::Div<DoseEquivalentRate<T>> for &i32 where T: NumLike+From<i32> {
+	type Output = InverseDoseEquivalentRate<T>;
+	fn div(self, rhs: DoseEquivalentRate<T>) -> Self::Output {
+		InverseDoseEquivalentRate{s_per_Sv: T::from(self.clone()) / rhs.Sv_per_s}
+	}
+}
+/// Dividing a scalar value by a DoseEquivalentRate unit value returns a value of type InverseDoseEquivalentRate
+impl<T> core::ops::Div<&DoseEquivalentRate<T>> for i32 where T: NumLike+From<i32> {
+	type Output = InverseDoseEquivalentRate<T>;
+	fn div(self, rhs: &DoseEquivalentRate<T>) -> Self::Output {
+		InverseDoseEquivalentRate{s_per_Sv: T::from(self) / rhs.Sv_per_s.clone()}
+	}
+}
+/// Dividing a scalar value by a DoseEquivalentRate unit value returns a value of type InverseDoseEquivalentRate
+impl<T> core::ops::Div<&DoseEquivalentRate<T>> for &i32 where T: NumLike+From<i32> {
+	type Output = InverseDoseEquivalentRate<T>;
+	fn div(self, rhs: &DoseEquivalentRate<T>) -> Self::Output {
+		InverseDoseEquivalentRate{s_per_Sv: T::from(self.clone()) / rhs.Sv_per_s.clone()}
+	}
+}
+
+// 1/DoseEquivalentRate -> InverseDoseEquivalentRate
+/// Dividing a scalar value by a DoseEquivalentRate unit value returns a value of type InverseDoseEquivalentRate
+#[cfg(feature="num-bigfloat")]
+impl<T> core::ops::Div<DoseEquivalentRate<T>> for num_bigfloat::BigFloat where T: NumLike+From<num_bigfloat::BigFloat> {
+	type Output = InverseDoseEquivalentRate<T>;
+	fn div(self, rhs: DoseEquivalentRate<T>) -> Self::Output {
+		InverseDoseEquivalentRate{s_per_Sv: T::from(self) / rhs.Sv_per_s}
+	}
+}
+/// Dividing a scalar value by a DoseEquivalentRate unit value returns a value of type InverseDoseEquivalentRate
+#[cfg(feature="num-bigfloat")]
+impl<T> core::ops::Div<DoseEquivalentRate<T>> for &num_bigfloat::BigFloat where T: NumLike+From<num_bigfloat::BigFloat> {
+	type Output = InverseDoseEquivalentRate<T>;
+	fn div(self, rhs: DoseEquivalentRate<T>) -> Self::Output {
+		InverseDoseEquivalentRate{s_per_Sv: T::from(self.clone()) / rhs.Sv_per_s}
+	}
+}
+/// Dividing a scalar value by a DoseEquivalentRate unit value returns a value of type InverseDoseEquivalentRate
+#[cfg(feature="num-bigfloat")]
+impl<T> core::ops::Div<&DoseEquivalentRate<T>> for num_bigfloat::BigFloat where T: NumLike+From<num_bigfloat::BigFloat> {
+	type Output = InverseDoseEquivalentRate<T>;
+	fn div(self, rhs: &DoseEquivalentRate<T>) -> Self::Output {
+		InverseDoseEquivalentRate{s_per_Sv: T::from(self) / rhs.Sv_per_s.clone()}
+	}
+}
+/// Dividing a scalar value by a DoseEquivalentRate unit value returns a value of type InverseDoseEquivalentRate
+#[cfg(feature="num-bigfloat")]
+impl<T> core::ops::Div<&DoseEquivalentRate<T>> for &num_bigfloat::BigFloat where T: NumLike+From<num_bigfloat::BigFloat> {
+	type Output = InverseDoseEquivalentRate<T>;
+	fn div(self, rhs: &DoseEquivalentRate<T>) -> Self::Output {
+		InverseDoseEquivalentRate{s_per_Sv: T::from(self.clone()) / rhs.Sv_per_s.clone()}
+	}
+}
+
+// 1/DoseEquivalentRate -> InverseDoseEquivalentRate
+/// Dividing a scalar value by a DoseEquivalentRate unit value returns a value of type InverseDoseEquivalentRate
+#[cfg(feature="num-complex")]
+impl<T> core::ops::Div<DoseEquivalentRate<T>> for num_complex::Complex32 where T: NumLike+From<num_complex::Complex32> {
+	type Output = InverseDoseEquivalentRate<T>;
+	fn div(self, rhs: DoseEquivalentRate<T>) -> Self::Output {
+		InverseDoseEquivalentRate{s_per_Sv: T::from(self) / rhs.Sv_per_s}
+	}
+}
+/// Dividing a scalar value by a DoseEquivalentRate unit value returns a value of type InverseDoseEquivalentRate
+#[cfg(feature="num-complex")]
+impl<T> core::ops::Div<DoseEquivalentRate<T>> for &num_complex::Complex32 where T: NumLike+From<num_complex::Complex32> {
+	type Output = InverseDoseEquivalentRate<T>;
+	fn div(self, rhs: DoseEquivalentRate<T>) -> Self::Output {
+		InverseDoseEquivalentRate{s_per_Sv: T::from(self.clone()) / rhs.Sv_per_s}
+	}
+}
+/// Dividing a scalar value by a DoseEquivalentRate unit value returns a value of type InverseDoseEquivalentRate
+#[cfg(feature="num-complex")]
+impl<T> core::ops::Div<&DoseEquivalentRate<T>> for num_complex::Complex32 where T: NumLike+From<num_complex::Complex32> {
+	type Output = InverseDoseEquivalentRate<T>;
+	fn div(self, rhs: &DoseEquivalentRate<T>) -> Self::Output {
+		InverseDoseEquivalentRate{s_per_Sv: T::from(self) / rhs.Sv_per_s.clone()}
+	}
+}
+/// Dividing a scalar value by a DoseEquivalentRate unit value returns a value of type InverseDoseEquivalentRate
+#[cfg(feature="num-complex")]
+impl<T> core::ops::Div<&DoseEquivalentRate<T>> for &num_complex::Complex32 where T: NumLike+From<num_complex::Complex32> {
+	type Output = InverseDoseEquivalentRate<T>;
+	fn div(self, rhs: &DoseEquivalentRate<T>) -> Self::Output {
+		InverseDoseEquivalentRate{s_per_Sv: T::from(self.clone()) / rhs.Sv_per_s.clone()}
+	}
+}
+
+// 1/DoseEquivalentRate -> InverseDoseEquivalentRate
+/// Dividing a scalar value by a DoseEquivalentRate unit value returns a value of type InverseDoseEquivalentRate
+#[cfg(feature="num-complex")]
+impl<T> core::ops::Div<DoseEquivalentRate<T>> for num_complex::Complex64 where T: NumLike+From<num_complex::Complex64> {
+	type Output = InverseDoseEquivalentRate<T>;
+	fn div(self, rhs: DoseEquivalentRate<T>) -> Self::Output {
+		InverseDoseEquivalentRate{s_per_Sv: T::from(self) / rhs.Sv_per_s}
+	}
+}
+/// Dividing a scalar value by a DoseEquivalentRate unit value returns a value of type InverseDoseEquivalentRate
+#[cfg(feature="num-complex")]
+impl<T> core::ops::Div<DoseEquivalentRate<T>> for &num_complex::Complex64 where T: NumLike+From<num_complex::Complex64> {
+	type Output = InverseDoseEquivalentRate<T>;
+	fn div(self, rhs: DoseEquivalentRate<T>) -> Self::Output {
+		InverseDoseEquivalentRate{s_per_Sv: T::from(self.clone()) / rhs.Sv_per_s}
+	}
+}
+/// Dividing a scalar value by a DoseEquivalentRate unit value returns a value of type InverseDoseEquivalentRate
+#[cfg(feature="num-complex")]
+impl<T> core::ops::Div<&DoseEquivalentRate<T>> for num_complex::Complex64 where T: NumLike+From<num_complex::Complex64> {
+	type Output = InverseDoseEquivalentRate<T>;
+	fn div(self, rhs: &DoseEquivalentRate<T>) -> Self::Output {
+		InverseDoseEquivalentRate{s_per_Sv: T::from(self) / rhs.Sv_per_s.clone()}
+	}
+}
+/// Dividing a scalar value by a DoseEquivalentRate unit value returns a value of type InverseDoseEquivalentRate
+#[cfg(feature="num-complex")]
+impl<T> core::ops::Div<&DoseEquivalentRate<T>> for &num_complex::Complex64 where T: NumLike+From<num_complex::Complex64> {
+	type Output = InverseDoseEquivalentRate<T>;
+	fn div(self, rhs: &DoseEquivalentRate<T>) -> Self::Output {
+		InverseDoseEquivalentRate{s_per_Sv: T::from(self.clone()) / rhs.Sv_per_s.clone()}
+	}
+}
+
+// 1/DoseEquivalentRate -> InverseDoseEquivalentRate
+/// Dividing a scalar value by a DoseEquivalentRate unit value returns a value of type InverseDoseEquivalentRate
+#[cfg(feature="num-rational")]
+impl<T> core::ops::Div<DoseEquivalentRate<T>> for num_rational::Rational64 where T: NumLike+From<num_rational::Rational64> {
+	type Output = InverseDoseEquivalentRate<T>;
+	fn div(self, rhs: DoseEquivalentRate<T>) -> Self::Output {
+		InverseDoseEquivalentRate{s_per_Sv: T::from(self) / rhs.Sv_per_s}
+	}
+}
+/// Dividing a scalar value by a DoseEquivalentRate unit value returns a value of type InverseDoseEquivalentRate
+#[cfg(feature="num-rational")]
+impl<T> core::ops::Div<DoseEquivalentRate<T>> for &num_rational::Rational64 where T: NumLike+From<num_rational::Rational64> {
+	type Output = InverseDoseEquivalentRate<T>;
+	fn div(self, rhs: DoseEquivalentRate<T>) -> Self::Output {
+		InverseDoseEquivalentRate{s_per_Sv: T::from(self.clone()) / rhs.Sv_per_s}
+	}
+}
+/// Dividing a scalar value by a DoseEquivalentRate unit value returns a value of type InverseDoseEquivalentRate
+#[cfg(feature="num-rational")]
+impl<T> core::ops::Div<&DoseEquivalentRate<T>> for num_rational::Rational64 where T: NumLike+From<num_rational::Rational64> {
+	type Output = InverseDoseEquivalentRate<T>;
+	fn div(self, rhs: &DoseEquivalentRate<T>) -> Self::Output {
+		InverseDoseEquivalentRate{s_per_Sv: T::from(self) / rhs.Sv_per_s.clone()}
+	}
+}
+/// Dividing a scalar value by a DoseEquivalentRate unit value returns a value of type InverseDoseEquivalentRate
+#[cfg(feature="num-rational")]
+impl<T> core::ops::Div<&DoseEquivalentRate<T>> for &num_rational::Rational64 where T: NumLike+From<num_rational::Rational64> {
+	type Output = InverseDoseEquivalentRate<T>;
+	fn div(self, rhs: &DoseEquivalentRate<T>) -> Self::Output {
+		InverseDoseEquivalentRate{s_per_Sv: T::from(self.clone()) / rhs.Sv_per_s.clone()}
+	}
+}
+
+// 1/DoseEquivalentRate -> InverseDoseEquivalentRate
+/// Dividing a scalar value by a DoseEquivalentRate unit value returns a value of type InverseDoseEquivalentRate
+#[cfg(feature="num-rational")]
+impl<T> core::ops::Div<DoseEquivalentRate<T>> for num_rational::BigRational where T: NumLike+From<num_rational::BigRational> {
+	type Output = InverseDoseEquivalentRate<T>;
+	fn div(self, rhs: DoseEquivalentRate<T>) -> Self::Output {
+		InverseDoseEquivalentRate{s_per_Sv: T::from(self) / rhs.Sv_per_s}
+	}
+}
+/// Dividing a scalar value by a DoseEquivalentRate unit value returns a value of type InverseDoseEquivalentRate
+#[cfg(feature="num-rational")]
+impl<T> core::ops::Div<DoseEquivalentRate<T>> for &num_rational::BigRational where T: NumLike+From<num_rational::BigRational> {
+	type Output = InverseDoseEquivalentRate<T>;
+	fn div(self, rhs: DoseEquivalentRate<T>) -> Self::Output {
+		InverseDoseEquivalentRate{s_per_Sv: T::from(self.clone()) / rhs.Sv_per_s}
+	}
+}
+/// Dividing a scalar value by a DoseEquivalentRate unit value returns a value of type InverseDoseEquivalentRate
+#[cfg(feature="num-rational")]
+impl<T> core::ops::Div<&DoseEquivalentRate<T>> for num_rational::BigRational where T: NumLike+From<num_rational::BigRational> {
+	type Output = InverseDoseEquivalentRate<T>;
+	fn div(self, rhs: &DoseEquivalentRate<T>) -> Self::Output {
+		InverseDoseEquivalentRate{s_per_Sv: T::from(self) / rhs.Sv_per_s.clone()}
+	}
+}
+/// Dividing a scalar value by a DoseEquivalentRate unit value returns a value of type InverseDoseEquivalentRate
+#[cfg(feature="num-rational")]
+impl<T> core::ops::Div<&DoseEquivalentRate<T>> for &num_rational::BigRational where T: NumLike+From<num_rational::BigRational> {
+	type Output = InverseDoseEquivalentRate<T>;
+	fn div(self, rhs: &DoseEquivalentRate<T>) -> Self::Output {
+		InverseDoseEquivalentRate{s_per_Sv: T::from(self.clone()) / rhs.Sv_per_s.clone()}
+	}
+}
+
+/// The inverse of absorbed radiation dose unit type, defined as inverse grays in SI units
+#[derive(UnitStruct, Debug, Clone, Default)]
+#[si(no_display)]
+#[cfg_attr(all(feature="serde", not(feature="serde-unit-tag")), derive(Serialize, Deserialize))]
+#[repr(transparent)]
+pub struct InverseAbsorbedDose<T: NumLike>{
+	/// The value of this Inverse absorbed dose in inverse grays
+	pub per_Gy: T
+}
+
+impl<T> InverseAbsorbedDose<T> where T: NumLike {
+
+	/// Returns the standard unit name of inverse absorbed dose: "inverse grays"
+	pub fn unit_name() -> &'static str { "inverse grays" }
+	
+	/// Returns the abbreviated name or symbol of inverse absorbed dose: "1/Gy" for inverse grays
+	pub fn unit_symbol() -> &'static str { "1/Gy" }
+
+	/// Returns the exponents of the SI base units of inverse absorbed dose (s^2/m^2)
+	pub const fn dimension() -> super::Dimension { super::Dimension{length: -2, mass: 0, time: 2, current: 0, temperature: 0, amount: 0, luminosity: 0} }
+
+	/// Returns a wrapper that formats this inverse absorbed dose value with the full unit name instead of 
+	/// the unit symbol, eg "5 inverse grays" (the unit name is not singularized for values of 1)
+	pub fn display_named(&self) -> super::NamedDisplay<'_, T> {
+		super::NamedDisplay::new(&self.per_Gy, Self::unit_name())
+	}
+
+	/// Returns a new inverse absorbed dose value from the given number of inverse grays (same as 
+	/// `from_per_Gy(...)`), for any number-like type. This is useful in generic code 
+	/// that works with several unit types
+	///
+	/// # Arguments
+	/// * `value` - Any number-like type, representing a quantity of inverse grays
+	pub const fn new(value: T) -> Self { InverseAbsorbedDose{per_Gy: value} }
+
+	/// Returns a new inverse absorbed dose value from the given number of inverse grays. Unlike
+	/// `from_per_Gy(...)`, this is a `const fn`, so it can be used to declare `const` and `static` values
+	///
+	/// # Arguments
+	/// * `per_Gy` - Any number-like type, representing a quantity of inverse grays
+	pub const fn from_per_Gy_const(per_Gy: T) -> Self { InverseAbsorbedDose{per_Gy} }
+
+	/// Returns a inverse absorbed dose value with a different number type, by applying the 
+	/// given function to the value in inverse grays (eg `.map(|x| x as f32)` to convert 
+	/// from `f64` to `f32`)
+	///
+	/// # Arguments
+	/// * `f` - Function that converts the number of inverse grays to the new number type
+	pub fn map<U, F>(self, f: F) -> InverseAbsorbedDose<U> where U: NumLike, F: FnOnce(T) -> U {
+		InverseAbsorbedDose{per_Gy: f(self.per_Gy)}
+	}
+
+	/// Returns a inverse absorbed dose value with a different number type, converted 
+	/// losslessly with the `From` trait (eg from `f32` to `f64`)
+	pub fn cast<U>(self) -> InverseAbsorbedDose<U> where U: NumLike+From<T> {
+		InverseAbsorbedDose{per_Gy: U::from(self.per_Gy)}
+	}
+
+	/// Returns a reference to the value of this inverse absorbed dose in inverse grays
+	pub fn as_raw(&self) -> &T {
+		&self.per_Gy
+	}
+
+	/// Returns a mutable reference to the value of this inverse absorbed dose in inverse grays, 
+	/// eg for passing to numeric libraries that modify values in place. The number 
+	/// is always in inverse grays, so changing it directly does not change the unit
+	pub fn raw_mut(&mut self) -> &mut T {
+		&mut self.per_Gy
+	}
+
+	/// Returns a slice of numbers in inverse grays as a slice of inverse absorbed dose values, 
+	/// without copying
+	///
+	/// # Arguments
+	/// * `values` - Slice of numbers, representing quantities of inverse grays
+	pub fn wrap_slice(values: &[T]) -> &[Self] {
+		// SAFETY: InverseAbsorbedDose<T> is #[repr(transparent)], so it has the same memory layout as T
+		unsafe { core::slice::from_raw_parts(values.as_ptr() as *const Self, values.len()) }
+	}
+
+	/// Returns a mutable slice of numbers in inverse grays as a mutable slice of 
+	/// inverse absorbed dose values, without copying
+	///
+	/// # Arguments
+	/// * `values` - Slice of numbers, representing quantities of inverse grays
+	pub fn wrap_slice_mut(values: &mut [T]) -> &mut [Self] {
+		// SAFETY: InverseAbsorbedDose<T> is #[repr(transparent)], so it has the same memory layout as T
+		unsafe { core::slice::from_raw_parts_mut(values.as_mut_ptr() as *mut Self, values.len()) }
+	}
+
+	/// Returns the ratio of this inverse absorbed dose value to another inverse absorbed dose value 
+	/// (same as `self / other`). Dividing a unit value by another value of the same 
+	/// unit type cancels the units, so the ratio is a dimensionless number
+	///
+	/// # Arguments
+	/// * `other` - The inverse absorbed dose value to divide by
+	pub fn ratio(self, other: Self) -> T {
+		self.per_Gy / other.per_Gy
+	}
+
+	/// Returns the ratio of this inverse absorbed dose value to another inverse absorbed dose value 
+	/// as a `Dimensionless` value, instead of a bare number like `ratio(...)` does
+	///
+	/// # Arguments
+	/// * `other` - The inverse absorbed dose value to divide by
+	pub fn dimensionless_ratio(self, other: Self) -> super::base::Dimensionless<T> {
+		super::base::Dimensionless{value: self.per_Gy / other.per_Gy}
+	}
+
+	/// Returns this inverse absorbed dose value multiplied by a dimensionless factor 
+	/// (same as `self * factor`)
+	///
+	/// # Arguments
+	/// * `factor` - The number to multiply by
+	pub fn scale(self, factor: T) -> Self {
+		InverseAbsorbedDose{per_Gy: self.per_Gy * factor}
+	}
+	
+	/// Returns a new inverse absorbed dose value from the given number of inverse grays
+	///
+	/// # Arguments
+	/// * `per_Gy` - Any number-like type, representing a quantity of inverse grays
+	pub fn from_per_Gy(per_Gy: T) -> Self { InverseAbsorbedDose{per_Gy: per_Gy} }
+	
+	/// Returns a copy of this inverse absorbed dose value in inverse grays
+	pub fn to_per_Gy(&self) -> T { self.per_Gy.clone() }
+
+	/// Returns a new inverse absorbed dose value from the given number of inverse grays
+	///
+	/// # Arguments
+	/// * `per_grays` - Any number-like type, representing a quantity of inverse grays
+	pub fn from_per_grays(per_grays: T) -> Self { InverseAbsorbedDose{per_Gy: per_grays} }
+	
+	/// Returns a copy of this inverse absorbed dose value in inverse grays
+	pub fn to_per_grays(&self) -> T { self.per_Gy.clone() }
+
+}
+
+/// Formats inverse absorbed dose values as a number followed by its unit symbol, eg "1500 1/Gy"
+/// (complex numbers are wrapped in parentheses, eg "(1+2i) 1/Gy").
+/// The alternate flag (`{:#}`) scales the number by the nearest SI prefix where the unit symbol allows it
+impl<T> fmt::Display for InverseAbsorbedDose<T> where T: NumLike {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		if f.alternate() {
+			return super::fmt_with_si_prefix(f, &self.per_Gy, Self::unit_symbol(), false);
+		}
+		super::fmt_unit_value(f, &self.per_Gy, Self::unit_symbol())
+	}
+}
+
+/// Formats inverse absorbed dose values in exponential notation followed by the unit symbol, eg "1.5e3 1/Gy"
+impl<T> fmt::LowerExp for InverseAbsorbedDose<T> where T: NumLike+fmt::LowerExp {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		fmt::LowerExp::fmt(&self.per_Gy, f)?;
+		write!(f, " {}", Self::unit_symbol())
+	}
+}
+
+/// Formats inverse absorbed dose values in exponential notation followed by the unit symbol, eg "1.5E3 1/Gy"
+impl<T> fmt::UpperExp for InverseAbsorbedDose<T> where T: NumLike+fmt::UpperExp {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		fmt::UpperExp::fmt(&self.per_Gy, f)?;
+		write!(f, " {}", Self::unit_symbol())
+	}
+}
+
+impl<T> super::SiUnit for InverseAbsorbedDose<T> where T: NumLike {
+	type Number = T;
+	fn unit_name(&self) -> &'static str { Self::unit_name() }
+	fn unit_symbol(&self) -> &'static str { Self::unit_symbol() }
+	fn raw(&self) -> &T { &self.per_Gy }
+	fn dimension(&self) -> super::Dimension { Self::dimension() }
+	fn from_raw(value: T) -> Self { InverseAbsorbedDose{per_Gy: value} }
+	fn into_raw(self) -> T { self.per_Gy }
+}
+
+#[cfg(feature="alloc")]
+impl<T> InverseAbsorbedDose<T> where T: NumLike {
+	/// Returns this inverse absorbed dose value in the canonical text format "<value> <symbol>", eg "5 1/Gy".
+	/// Unlike `Display`, this format never changes with precision or alternate flags, and it is the
+	/// input format expected by `from_str(...)`, so `x.canonical_string().parse()` returns the same value
+	pub fn canonical_string(&self) -> alloc::string::String {
+		alloc::format!("{} {}", self.per_Gy, Self::unit_symbol())
+	}
+
+	/// Returns a `Vec` of inverse absorbed dose values copied from the given slice of numbers
+	///
+	/// # Arguments
+	/// * `values` - Slice of numbers, representing quantities of inverse grays
+	pub fn from_per_Gy_slice(values: &[T]) -> alloc::vec::Vec<Self> {
+		values.iter().map(|x| InverseAbsorbedDose{per_Gy: x.clone()}).collect()
+	}
+}
+
+/// Parses a inverse absorbed dose value from a number followed by its unit symbol, eg "5 1/Gy"
+impl<T> core::str::FromStr for InverseAbsorbedDose<T> where T: NumLike+core::str::FromStr {
+	type Err = SiParseError;
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		Ok(InverseAbsorbedDose{per_Gy: super::parse_unit_value(s, Self::unit_symbol())?})
+	}
+}
+
+/// Parses a inverse absorbed dose value from a number followed by its unit symbol, eg "5 1/Gy" 
+/// (same as `str::parse()`)
+impl<T> core::convert::TryFrom<&str> for InverseAbsorbedDose<T> where T: NumLike+core::str::FromStr {
+	type Error = SiParseError;
+	fn try_from(s: &str) -> Result<Self, Self::Error> {
+		<Self as core::str::FromStr>::from_str(s)
+	}
+}
+
+#[cfg(feature="num-traits")]
+impl<T> InverseAbsorbedDose<T> where T: NumLike+num_traits::Euclid {
+
+	/// Returns the Euclidean remainder of dividing this inverse absorbed dose value by another, 
+	/// which is never negative (requires the `num-traits` feature)
+	///
+	/// # Arguments
+	/// * `rhs` - The inverse absorbed dose value to divide by
+	pub fn rem_euclid(self, rhs: Self) -> Self {
+		InverseAbsorbedDose{per_Gy: self.per_Gy.rem_euclid(&rhs.per_Gy)}
+	}
+
+	/// Returns the Euclidean quotient of dividing this inverse absorbed dose value by another, 
+	/// ie the whole number of times that `rhs` fits into this value, rounded such that 
+	/// `rem_euclid(...)` is never negative (requires the `num-traits` feature)
+	///
+	/// # Arguments
+	/// * `rhs` - The inverse absorbed dose value to divide by
+	pub fn div_euclid(self, rhs: Self) -> T {
+		self.per_Gy.div_euclid(&rhs.per_Gy)
+	}
+}
+
+/// Summing inverse absorbed dose values returns their total as a inverse absorbed dose value (or zero if there are no values)
+impl<T> core::iter::Sum<InverseAbsorbedDose<T>> for InverseAbsorbedDose<T> where T: NumLike+core::iter::Sum {
+	fn sum<I: Iterator<Item=InverseAbsorbedDose<T>>>(iter: I) -> Self {
+		InverseAbsorbedDose{per_Gy: iter.map(|x| x.per_Gy).sum()}
+	}
+}
+/// Summing inverse absorbed dose values returns their total as a inverse absorbed dose value (or zero if there are no values)
+impl<'a, T> core::iter::Sum<&'a InverseAbsorbedDose<T>> for InverseAbsorbedDose<T> where T: NumLike+core::iter::Sum+'a {
+	fn sum<I: Iterator<Item=&'a InverseAbsorbedDose<T>>>(iter: I) -> Self {
+		InverseAbsorbedDose{per_Gy: iter.map(|x| x.per_Gy.clone()).sum()}
+	}
+}
+
+impl<T> InverseAbsorbedDose<T> where T: NumLike+From<f64> {
+
+	/// Returns the weighted average of the given inverse absorbed dose values, where each 
+	/// value is paired with its weight (weights do not need to add up to 1). If the 
+	/// total weight is zero (including when `values` is empty), then zero is returned
+	///
+	/// # Arguments
+	/// * `values` - Slice of (inverse absorbed dose value, weight) pairs
+	pub fn weighted_mean(values: &[(Self, f64)]) -> Self {
+		let total_weight: f64 = values.iter().map(|(_, weight)| *weight).sum();
+		if total_weight == 0.0 {
+			return InverseAbsorbedDose{per_Gy: T::from(0.0)};
+		}
+		let total = values.iter().fold(T::from(0.0),
+			|total, (value, weight)| total + value.per_Gy.clone() * T::from(*weight));
+		InverseAbsorbedDose{per_Gy: total / T::from(total_weight)}
+	}
+}
+
+impl<T> InverseAbsorbedDose<T> where T: NumLike+PartialEq+From<u8> {
+
+	/// Divides this inverse absorbed dose value by another unit value (eg `distance.checked_div(time)` 
+	/// returns a velocity), returning `None` if the divisor is zero instead of producing 
+	/// infinity or NaN (or panicking, for integer number types). This works for every unit 
+	/// type that this inverse absorbed dose value can be divided by with the `/` operator
+	///
+	/// # Arguments
+	/// * `rhs` - The unit value to divide by
+	pub fn checked_div<R>(self, rhs: R) -> Option<<Self as core::ops::Div<R>>::Output> 
+		where Self: core::ops::Div<R>, R: super::SiUnit<Number=T>
+	{
+		if *rhs.raw() == T::from(0u8) {
+			return None;
+		}
+		Some(self / rhs)
+	}
+}
+
+/// Multiplying a inverse absorbed dose value by a dimensionless value returns a inverse absorbed dose value
+impl<T> core::ops::Mul<super::base::Dimensionless<T>> for InverseAbsorbedDose<T> where T: NumLike {
+	type Output = InverseAbsorbedDose<T>;
+	fn mul(self, rhs: super::base::Dimensionless<T>) -> Self::Output {
+		InverseAbsorbedDose{per_Gy: self.per_Gy * rhs.value}
+	}
+}
+/// Multiplying a dimensionless value by a inverse absorbed dose value returns a inverse absorbed dose value
+impl<T> core::ops::Mul<InverseAbsorbedDose<T>> for super::base::Dimensionless<T> where T: NumLike {
+	type Output = InverseAbsorbedDose<T>;
+	fn mul(self, rhs: InverseAbsorbedDose<T>) -> Self::Output {
+		InverseAbsorbedDose{per_Gy: self.value * rhs.per_Gy}
+	}
+}
+/// Dividing a inverse absorbed dose value by a dimensionless value returns a inverse absorbed dose value
+impl<T> core::ops::Div<super::base::Dimensionless<T>> for InverseAbsorbedDose<T> where T: NumLike {
+	type Output = InverseAbsorbedDose<T>;
+	fn div(self, rhs: super::base::Dimensionless<T>) -> Self::Output {
+		InverseAbsorbedDose{per_Gy: self.per_Gy / rhs.value}
+	}
+}
+
+/// The remainder of dividing a inverse absorbed dose value by another inverse absorbed dose value is a inverse absorbed dose value
+impl<T> core::ops::Rem<InverseAbsorbedDose<T>> for InverseAbsorbedDose<T> where T: NumLike+core::ops::Rem<Output=T> {
+	type Output = InverseAbsorbedDose<T>;
+	fn rem(self, rhs: InverseAbsorbedDose<T>) -> Self::Output {
+		InverseAbsorbedDose{per_Gy: self.per_Gy % rhs.per_Gy}
+	}
+}
+/// The remainder of dividing a inverse absorbed dose value by another inverse absorbed dose value is a inverse absorbed dose value
+impl<T> core::ops::Rem<&InverseAbsorbedDose<T>> for InverseAbsorbedDose<T> where T: NumLike+core::ops::Rem<Output=T> {
+	type Output = InverseAbsorbedDose<T>;
+	fn rem(self, rhs: &InverseAbsorbedDose<T>) -> Self::Output {
+		InverseAbsorbedDose{per_Gy: self.per_Gy % rhs.per_Gy.clone()}
+	}
+}
+/// The remainder of dividing a inverse absorbed dose value by another inverse absorbed dose value is a inverse absorbed dose value
+impl<T> core::ops::Rem<InverseAbsorbedDose<T>> for &InverseAbsorbedDose<T> where T: NumLike+core::ops::Rem<Output=T> {
+	type Output = InverseAbsorbedDose<T>;
+	fn rem(self, rhs: InverseAbsorbedDose<T>) -> Self::Output {
+		InverseAbsorbedDose{per_Gy: self.per_Gy.clone() % rhs.per_Gy}
+	}
+}
+/// The remainder of dividing a inverse absorbed dose value by another inverse absorbed dose value is a inverse absorbed dose value
+impl<T> core::ops::Rem<&InverseAbsorbedDose<T>> for &InverseAbsorbedDose<T> where T: NumLike+core::ops::Rem<Output=T> {
+	type Output = InverseAbsorbedDose<T>;
+	fn rem(self, rhs: &InverseAbsorbedDose<T>) -> Self::Output {
+		InverseAbsorbedDose{per_Gy: self.per_Gy.clone() % rhs.per_Gy.clone()}
+	}
+}
+
+/// Serializes a inverse absorbed dose value together with its unit symbol, eg 
+/// `{"value": 1.5, "unit": "1/Gy"}` (requires the `serde-unit-tag` feature)
+#[cfg(feature="serde-unit-tag")]
+impl<T> serde::Serialize for InverseAbsorbedDose<T> where T: NumLike+serde::Serialize {
+	fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+		super::serialize_with_unit_tag(&self.per_Gy, Self::unit_symbol(), serializer)
+	}
+}
+/// Deserializes a inverse absorbed dose value from a value and unit symbol, eg 
+/// `{"value": 1.5, "unit": "1/Gy"}`, returning an error if the unit symbol 
+/// does not match (requires the `serde-unit-tag` feature)
+#[cfg(feature="serde-unit-tag")]
+impl<'de, T> serde::Deserialize<'de> for InverseAbsorbedDose<T> where T: NumLike+serde::Deserialize<'de> {
+	fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+		Ok(InverseAbsorbedDose{per_Gy: super::deserialize_with_unit_tag(Self::unit_symbol(), deserializer)?})
+	}
+}
+
+/// The additive identity of inverse absorbed dose values is zero inverse grays (requires the `num-traits` feature)
+#[cfg(feature="num-traits")]
+impl<T> num_traits::Zero for InverseAbsorbedDose<T> where T: NumLike+num_traits::Zero {
+	fn zero() -> Self { InverseAbsorbedDose{per_Gy: T::zero()} }
+	fn is_zero(&self) -> bool { self.per_Gy.is_zero() }
+}
+
+#[cfg(feature="num-traits")]
+impl<T> InverseAbsorbedDose<T> where T: NumLike+num_traits::Signed {
+
+	/// Returns the sign of this inverse absorbed dose value as a dimensionless number: 1 if it is 
+	/// positive, -1 if it is negative, and 0 if it is zero for integer number types 
+	/// (for floating-point number types, +0.0 returns 1.0, -0.0 returns -1.0, and NaN returns 
+	/// NaN) (requires the `num-traits` feature)
+	pub fn signum(self) -> T {
+		self.per_Gy.signum()
+	}
+}
+
+impl<T> InverseAbsorbedDose<T> where T: NumLike+PartialOrd {
+
+	/// Returns the absolute value of this inverse absorbed dose value, ie its magnitude without a minus sign
+	/// (for floating-point number types, -0.0 returns +0.0)
+	pub fn abs(self) -> Self {
+		let negated = -self.per_Gy.clone();
+		if self.per_Gy < negated {
+			InverseAbsorbedDose{per_Gy: negated}
+		} else if self.per_Gy == negated {
+			// zero, which is its own negation (adding +0.0 turns -0.0 into +0.0)
+			InverseAbsorbedDose{per_Gy: negated + self.per_Gy}
+		} else {
+			self
+		}
+	}
+
+	/// Returns the lesser of this inverse absorbed dose value and the given inverse absorbed dose value
+	///
+	/// # Arguments
+	/// * `other` - The inverse absorbed dose value to compare with this one
+	pub fn min(self, other: Self) -> Self {
+		if other.per_Gy < self.per_Gy { other } else { self }
+	}
+
+	/// Returns the greater of this inverse absorbed dose value and the given inverse absorbed dose value
+	///
+	/// # Arguments
+	/// * `other` - The inverse absorbed dose value to compare with this one
+	pub fn max(self, other: Self) -> Self {
+		if other.per_Gy > self.per_Gy { other } else { self }
+	}
+
+	/// Returns the least of the given inverse absorbed dose values, or `None` if there are none. 
+	/// Unlike `Iterator::min()`, this does not require `Ord`, so it works for 
+	/// floating-point numbers. NaN values are skipped (so the result is `None` if all 
+	/// values are NaN), and the first of several equal values is returned
+	///
+	/// # Arguments
+	/// * `values` - Any iterator or collection of inverse absorbed dose values
+	pub fn min_of<I>(values: I) -> Option<Self> where I: IntoIterator<Item=Self> {
+		values.into_iter().filter(|x| x.per_Gy.partial_cmp(&x.per_Gy).is_some()).reduce(Self::min)
+	}
+
+	/// Returns the greatest of the given inverse absorbed dose values, or `None` if there are none. 
+	/// Unlike `Iterator::max()`, this does not require `Ord`, so it works for 
+	/// floating-point numbers. NaN values are skipped (so the result is `None` if all 
+	/// values are NaN), and the first of several equal values is returned
+	///
+	/// # Arguments
+	/// * `values` - Any iterator or collection of inverse absorbed dose values
+	pub fn max_of<I>(values: I) -> Option<Self> where I: IntoIterator<Item=Self> {
+		values.into_iter().filter(|x| x.per_Gy.partial_cmp(&x.per_Gy).is_some()).reduce(Self::max)
+	}
+
+	/// Returns this inverse absorbed dose value restricted to the range from `lo` to `hi`
+	///
+	/// # Arguments
+	/// * `lo` - The minimum inverse absorbed dose value
+	/// * `hi` - The maximum inverse absorbed dose value
+	///
+	/// # Panics
+	/// Panics if `lo` is greater than `hi` (or if either of them is NaN)
+	pub fn clamp(self, lo: Self, hi: Self) -> Self {
+		assert!(lo.per_Gy <= hi.per_Gy, "lo must be less than or equal to hi");
+		if self.per_Gy < lo.per_Gy { lo } else if self.per_Gy > hi.per_Gy { hi } else { self }
+	}
+
+	/// Returns this inverse absorbed dose value with its magnitude capped at `max`, keeping its 
+	/// sign (eg for capping a speed), or this value unchanged if its magnitude does not 
+	/// exceed `max`. For complex-valued inverse absorbed dose, use `clamp_norm(...)` instead, 
+	/// which caps the magnitude while keeping the phase
+	///
+	/// # Arguments
+	/// * `max` - The maximum magnitude (should not be negative)
+	pub fn clamp_magnitude(self, max: Self) -> Self {
+		let negated_max = -max.per_Gy.clone();
+		if self.per_Gy > max.per_Gy { 
+			max 
+		} else if self.per_Gy < negated_max { 
+			InverseAbsorbedDose{per_Gy: negated_max} 
+		} else { 
+			self 
+		}
+	}
+
+	/// Returns true if this inverse absorbed dose value differs from the given inverse absorbed dose 
+	/// value by no more than the given absolute tolerance (which is also a 
+	/// inverse absorbed dose value, so the comparison is dimensionally safe). NaN is never 
+	/// equal to anything. See also the `assert_unit_eq!(...)` macro.
+	///
+	/// # Arguments
+	/// * `other` - The inverse absorbed dose value to compare with this one
+	/// * `tolerance` - The largest allowed difference between the two values
+	pub fn eq_within(self, other: Self, tolerance: Self) -> bool {
+		let diff = self.per_Gy - other.per_Gy;
+		diff <= tolerance.per_Gy && -diff <= tolerance.per_Gy
+	}
+}
+
+#[cfg(feature="num-complex")]
+impl InverseAbsorbedDose<num_complex::Complex32> {
+	/// Returns the magnitude (aka norm or absolute value) of this complex inverse absorbed dose 
+	/// value as a real-valued inverse absorbed dose (requires the `num-complex` feature)
+	pub fn magnitude(self) -> InverseAbsorbedDose<f32> {
+		InverseAbsorbedDose{per_Gy: self.per_Gy.norm()}
+	}
+
+	/// Returns this complex inverse absorbed dose value scaled down so that its magnitude is 
+	/// at most `max` (keeping its phase), or this value unchanged if its magnitude does 
+	/// not exceed `max`. This is the complex equivalent of `clamp_magnitude(...)` (requires 
+	/// the `num-complex` feature)
+	///
+	/// # Arguments
+	/// * `max` - The maximum magnitude (should not be negative)
+	pub fn clamp_norm(self, max: InverseAbsorbedDose<f32>) -> Self {
+		let norm = self.per_Gy.norm();
+		if norm > max.per_Gy {
+			InverseAbsorbedDose{per_Gy: self.per_Gy * (max.per_Gy / norm)}
+		} else {
+			self
+		}
+	}
+}
+
+#[cfg(feature="num-complex")]
+impl InverseAbsorbedDose<num_complex::Complex64> {
+	/// Returns the magnitude (aka norm or absolute value) of this complex inverse absorbed dose 
+	/// value as a real-valued inverse absorbed dose (requires the `num-complex` feature)
+	pub fn magnitude(self) -> InverseAbsorbedDose<f64> {
+		InverseAbsorbedDose{per_Gy: self.per_Gy.norm()}
+	}
+
+	/// Returns this complex inverse absorbed dose value scaled down so that its magnitude is 
+	/// at most `max` (keeping its phase), or this value unchanged if its magnitude does 
+	/// not exceed `max`. This is the complex equivalent of `clamp_magnitude(...)` (requires 
+	/// the `num-complex` feature)
+	///
+	/// # Arguments
+	/// * `max` - The maximum magnitude (should not be negative)
+	pub fn clamp_norm(self, max: InverseAbsorbedDose<f64>) -> Self {
+		let norm = self.per_Gy.norm();
+		if norm > max.per_Gy {
+			InverseAbsorbedDose{per_Gy: self.per_Gy * (max.per_Gy / norm)}
+		} else {
+			self
+		}
+	}
+}
+
+/// Rounding and other floating-point functions (requires the `num-traits` feature, 
+/// and either the `std` or `libm` feature)
+#[cfg(all(feature="num-traits", any(feature="std", feature="libm")))]
+impl<T> InverseAbsorbedDose<T> where T: NumLike+num_traits::Float {
+
+	/// Returns this inverse absorbed dose value rounded to the nearest whole number of inverse grays 
+	/// (half-way cases are rounded away from zero)
+	pub fn round(self) -> Self {
+		InverseAbsorbedDose{per_Gy: self.per_Gy.round()}
+	}
+
+	/// Returns this inverse absorbed dose value rounded down to a whole number of inverse grays
+	pub fn floor(self) -> Self {
+		InverseAbsorbedDose{per_Gy: self.per_Gy.floor()}
+	}
+
+	/// Returns this inverse absorbed dose value rounded up to a whole number of inverse grays
+	pub fn ceil(self) -> Self {
+		InverseAbsorbedDose{per_Gy: self.per_Gy.ceil()}
+	}
+
+	/// Returns this inverse absorbed dose value rounded towards zero to a whole number of inverse grays
+	pub fn trunc(self) -> Self {
+		InverseAbsorbedDose{per_Gy: self.per_Gy.trunc()}
+	}
+
+	/// Returns this inverse absorbed dose value rounded to the nearest multiple of the given step 
+	/// (half-way cases are rounded away from zero)
+	///
+	/// # Arguments
+	/// * `step` - The inverse absorbed dose value to round to a multiple of
+	pub fn round_to(self, step: Self) -> Self {
+		InverseAbsorbedDose{per_Gy: (self.per_Gy / step.per_Gy).round() * step.per_Gy}
+	}
+
+	/// Fused multiply-add: returns `self * a + b` computed with only one rounding 
+	/// error, which is more accurate (and on some hardware faster) than a separate 
+	/// multiplication and addition
+	///
+	/// # Arguments
+	/// * `a` - The scalar value to multiply this inverse absorbed dose value by
+	/// * `b` - The inverse absorbed dose value to add to the product
+	pub fn mul_add(self, a: T, b: Self) -> Self {
+		InverseAbsorbedDose{per_Gy: self.per_Gy.mul_add(a, b.per_Gy)}
+	}
+
+	/// Returns true if this inverse absorbed dose value is neither infinite nor NaN
+	pub fn is_finite(&self) -> bool {
+		self.per_Gy.is_finite()
+	}
+
+	/// Returns true if this inverse absorbed dose value is positive or negative infinity
+	pub fn is_infinite(&self) -> bool {
+		self.per_Gy.is_infinite()
+	}
+
+	/// Returns true if this inverse absorbed dose value is NaN (not a number)
+	pub fn is_nan(&self) -> bool {
+		self.per_Gy.is_nan()
+	}
+
+	/// Returns true if this inverse absorbed dose value has a positive sign, including +0.0 
+	/// and positive infinity
+	pub fn is_sign_positive(&self) -> bool {
+		self.per_Gy.is_sign_positive()
+	}
+
+	/// Returns true if this inverse absorbed dose value has a negative sign, including -0.0 
+	/// and negative infinity
+	pub fn is_sign_negative(&self) -> bool {
+		self.per_Gy.is_sign_negative()
+	}
+
+	/// Returns a inverse absorbed dose value with the magnitude of this one and the sign of 
+	/// `sign_source`, eg for reflecting a value off of a surface
+	///
+	/// # Arguments
+	/// * `sign_source` - The inverse absorbed dose value whose sign is copied
+	pub fn copysign(self, sign_source: Self) -> Self {
+		InverseAbsorbedDose{per_Gy: self.per_Gy.copysign(sign_source.per_Gy)}
+	}
+}
+
+#[cfg(feature="num-traits")]
+impl<T> InverseAbsorbedDose<T> where T: NumLike+num_traits::CheckedAdd+num_traits::CheckedSub {
+
+	/// Adds another inverse absorbed dose value to this one, returning `None` if the 
+	/// addition overflows the number type (requires the `num-traits` feature)
+	pub fn checked_add(self, rhs: Self) -> Option<Self> {
+		Some(InverseAbsorbedDose{per_Gy: self.per_Gy.checked_add(&rhs.per_Gy)?})
+	}
+
+	/// Subtracts another inverse absorbed dose value from this one, returning `None` if 
+	/// the subtraction overflows the number type (requires the `num-traits` feature)
+	pub fn checked_sub(self, rhs: Self) -> Option<Self> {
+		Some(InverseAbsorbedDose{per_Gy: self.per_Gy.checked_sub(&rhs.per_Gy)?})
+	}
+}
+
+#[cfg(feature="num-traits")]
+impl<T> InverseAbsorbedDose<T> where T: NumLike+num_traits::SaturatingAdd+num_traits::SaturatingSub {
+
+	/// Adds another inverse absorbed dose value to this one, clamping the result to the 
+	/// minimum or maximum value of the number type instead of overflowing 
+	/// (requires the `num-traits` feature)
+	pub fn saturating_add(self, rhs: Self) -> Self {
+		InverseAbsorbedDose{per_Gy: self.per_Gy.saturating_add(&rhs.per_Gy)}
+	}
+
+	/// Subtracts another inverse absorbed dose value from this one, clamping the result 
+	/// to the minimum or maximum value of the number type instead of overflowing 
+	/// (requires the `num-traits` feature)
+	pub fn saturating_sub(self, rhs: Self) -> Self {
+		InverseAbsorbedDose{per_Gy: self.per_Gy.saturating_sub(&rhs.per_Gy)}
+	}
+}
+
+impl<T> InverseAbsorbedDose<T> where T: NumLike+Into<f64> {
+
+	/// Returns true if this inverse absorbed dose value is equal to the given inverse absorbed dose 
+	/// value to within the given number of significant figures (eg 6 significant 
+	/// figures allows a relative difference of up to 1 part per million). NaN is 
+	/// only approximately equal to NaN, infinity is only approximately equal to 
+	/// infinity of the same sign, and zero is only approximately equal to zero.
+	///
+	/// # Arguments
+	/// * `other` - The inverse absorbed dose value to compare with this one
+	/// * `sig_figs` - The number of significant figures that must match
+	pub fn approx_eq(self, other: Self, sig_figs: i32) -> bool {
+		super::approx_eq_f64(self.per_Gy.into(), other.per_Gy.into(), sig_figs)
+	}
+}
+
+impl<T> InverseAbsorbedDose<T> where T: NumLike+From<f64> {
+
+	/// Returns the linear interpolation between this inverse absorbed dose value and the given 
+	/// inverse absorbed dose value, ie `self*(1-t) + other*t`. The interpolation factor is not 
+	/// clamped, so values of `t` outside the range 0 to 1 extrapolate beyond the two 
+	/// values (see `lerp_clamped(...)`).
+	///
+	/// # Arguments
+	/// * `other` - The inverse absorbed dose value to interpolate towards (returned when `t` is 1)
+	/// * `t` - The interpolation factor, where 0 returns this value and 1 returns `other`
+	pub fn lerp(self, other: Self, t: f64) -> Self {
+		InverseAbsorbedDose{per_Gy: self.per_Gy * T::from(1.0 - t) + other.per_Gy * T::from(t)}
+	}
+
+	/// Same as `lerp(...)`, except that the interpolation factor `t` is first clamped 
+	/// to the range 0 to 1, so that the result is always between the two values
+	///
+	/// # Arguments
+	/// * `other` - The inverse absorbed dose value to interpolate towards (returned when `t` is 1 or more)
+	/// * `t` - The interpolation factor, where 0 returns this value and 1 returns `other`
+	pub fn lerp_clamped(self, other: Self, t: f64) -> Self {
+		self.lerp(other, t.clamp(0.0, 1.0))
+	}
+	
+	/// Returns a copy of this inverse absorbed dose value in inverse milligrays
+	/// 
+	/// *Note: This method is not available for `f32` and other number types lacking the `From<f64>` trait*
+	pub fn to_per_mGy(&self) -> T {
+		return self.per_Gy.clone() * T::from(0.001_f64);
+	}
+
+	/// Returns a new inverse absorbed dose value from the given number of inverse milligrays
+	/// 
+	/// *Note: This method is not available for `f32` and other number types lacking the `From<f64>` trait*
+	///
+	/// # Arguments
+	/// * `per_mGy` - Any number-like type, representing a quantity of inverse milligrays
+	pub fn from_per_mGy(per_mGy: T) -> Self {
+		InverseAbsorbedDose{per_Gy: per_mGy * T::from(1000.0_f64)}
+	}
+
+	/// Returns a copy of this inverse absorbed dose value in inverse micrograys
+	/// 
+	/// *Note: This method is not available for `f32` and other number types lacking the `From<f64>` trait*
+	pub fn to_per_uGy(&self) -> T {
+		return self.per_Gy.clone() * T::from(1e-06_f64);
+	}
+
+	/// Returns a new inverse absorbed dose value from the given number of inverse micrograys
+	/// 
+	/// *Note: This method is not available for `f32` and other number types lacking the `From<f64>` trait*
+	///
+	/// # Arguments
+	/// * `per_uGy` - Any number-like type, representing a quantity of inverse micrograys
+	pub fn from_per_uGy(per_uGy: T) -> Self {
+		InverseAbsorbedDose{per_Gy: per_uGy * T::from(1000000.0_f64)}
+	}
+
+	/// Returns a copy of this inverse absorbed dose value in inverse nanograys
+	/// 
+	/// *Note: This method is not available for `f32` and other number types lacking the `From<f64>` trait*
+	pub fn to_per_nGy(&self) -> T {
+		return self.per_Gy.clone() * T::from(1e-09_f64);
+	}
+
+	/// Returns a new inverse absorbed dose value from the given number of inverse nanograys
+	/// 
+	/// *Note: This method is not available for `f32` and other number types lacking the `From<f64>` trait*
+	///
+	/// # Arguments
+	/// * `per_nGy` - Any number-like type, representing a quantity of inverse nanograys
+	pub fn from_per_nGy(per_nGy: T) -> Self {
+		InverseAbsorbedDose{per_Gy: per_nGy * T::from(1000000000.0_f64)}
+	}
+
+	/// Returns a copy of this inverse absorbed dose value in inverse kilograys
+	/// 
+	/// *Note: This method is not available for `f32` and other number types lacking the `From<f64>` trait*
+	pub fn to_per_kGy(&self) -> T {
+		return self.per_Gy.clone() * T::from(1000.0_f64);
+	}
+
+	/// Returns a new inverse absorbed dose value from the given number of inverse kilograys
+	/// 
+	/// *Note: This method is not available for `f32` and other number types lacking the `From<f64>` trait*
+	///
+	/// # Arguments
+	/// * `per_kGy` - Any number-like type, representing a quantity of inverse kilograys
+	pub fn from_per_kGy(per_kGy: T) -> Self {
+		InverseAbsorbedDose{per_Gy: per_kGy * T::from(0.001_f64)}
+	}
+
+	/// Returns a copy of this inverse absorbed dose value in inverse megagrays
+	/// 
+	/// *Note: This method is not available for `f32` and other number types lacking the `From<f64>` trait*
+	pub fn to_per_MGy(&self) -> T {
+		return self.per_Gy.clone() * T::from(1000000.0_f64);
+	}
+
+	/// Returns a new inverse absorbed dose value from the given number of inverse megagrays
+	/// 
+	/// *Note: This method is not available for `f32` and other number types lacking the `From<f64>` trait*
+	///
+	/// # Arguments
+	/// * `per_MGy` - Any number-like type, representing a quantity of inverse megagrays
+	pub fn from_per_MGy(per_MGy: T) -> Self {
+		InverseAbsorbedDose{per_Gy: per_MGy * T::from(1e-06_f64)}
+	}
+
+	/// Returns a copy of this inverse absorbed dose value in inverse gigagrays
+	/// 
+	/// *Note: This method is not available for `f32` and other number types lacking the `From<f64>` trait*
+	pub fn to_per_GGy(&self) -> T {
+		return self.per_Gy.clone() * T::from(1000000000.0_f64);
+	}
+
+	/// Returns a new inverse absorbed dose value from the given number of inverse gigagrays
+	/// 
+	/// *Note: This method is not available for `f32` and other number types lacking the `From<f64>` trait*
+	///
+	/// # Arguments
+	/// * `per_GGy` - Any number-like type, representing a quantity of inverse gigagrays
+	pub fn from_per_GGy(per_GGy: T) -> Self {
+		InverseAbsorbedDose{per_Gy: per_GGy * T::from(1e-09_f64)}
+	}
+
+	/// Returns a copy of this inverse absorbed dose value in inverse rads
+	/// 
+	/// *Note: This method is not available for `f32` and other number types lacking the `From<f64>` trait*
+	pub fn to_per_rad(&self) -> T {
+		return self.per_Gy.clone() * T::from(0.01_f64);
+	}
+
+	/// Returns a new inverse absorbed dose value from the given number of inverse rads
+	/// 
+	/// *Note: This method is not available for `f32` and other number types lacking the `From<f64>` trait*
+	///
+	/// # Arguments
+	/// * `per_rad` - Any number-like type, representing a quantity of inverse rads
+	pub fn from_per_rad(per_rad: T) -> Self {
+		InverseAbsorbedDose{per_Gy: per_rad * T::from(100.0_f64)}
+	}
+
+	/// Returns a copy of this inverse absorbed dose value in inverse kilorads
+	/// 
+	/// *Note: This method is not available for `f32` and other number types lacking the `From<f64>` trait*
+	pub fn to_per_krad(&self) -> T {
+		return self.per_Gy.clone() * T::from(10.0_f64);
+	}
+
+	/// Returns a new inverse absorbed dose value from the given number of inverse kilorads
+	/// 
+	/// *Note: This method is not available for `f32` and other number types lacking the `From<f64>` trait*
+	///
+	/// # Arguments
+	/// * `per_krad` - Any number-like type, representing a quantity of inverse kilorads
+	pub fn from_per_krad(per_krad: T) -> Self {
+		InverseAbsorbedDose{per_Gy: per_krad * T::from(0.1_f64)}
+	}
+
+	/// Returns a copy of this inverse absorbed dose value in inverse millirads
+	/// 
+	/// *Note: This method is not available for `f32` and other number types lacking the `From<f64>` trait*
+	pub fn to_per_mrad(&self) -> T {
+		return self.per_Gy.clone() * T::from(1e-05_f64);
+	}
+
+	/// Returns a new inverse absorbed dose value from the given number of inverse millirads
+	/// 
+	/// *Note: This method is not available for `f32` and other number types lacking the `From<f64>` trait*
+	///
+	/// # Arguments
+	/// * `per_mrad` - Any number-like type, representing a quantity of inverse millirads
+	pub fn from_per_mrad(per_mrad: T) -> Self {
+		InverseAbsorbedDose{per_Gy: per_mrad * T::from(100000.0_f64)}
+	}
+
+	/// Returns a copy of this inverse absorbed dose value in inverse microrads
+	/// 
+	/// *Note: This method is not available for `f32` and other number types lacking the `From<f64>` trait*
+	pub fn to_per_urad(&self) -> T {
+		return self.per_Gy.clone() * T::from(1e-08_f64);
+	}
+
+	/// Returns a new inverse absorbed dose value from the given number of inverse microrads
+	/// 
+	/// *Note: This method is not available for `f32` and other number types lacking the `From<f64>` trait*
+	///
+	/// # Arguments
+	/// * `per_urad` - Any number-like type, representing a quantity of inverse microrads
+	pub fn from_per_urad(per_urad: T) -> Self {
+		InverseAbsorbedDose{per_Gy: per_urad * T::from(100000000.0_f64)}
+	}
+
+	/// Returns a copy of this inverse absorbed dose value in gram per ergs
+	/// 
+	/// *Note: This method is not available for `f32` and other number types lacking the `From<f64>` trait*
+	pub fn to_per_erg(&self) -> T {
+		return self.per_Gy.clone() * T::from(0.0001_f64);
+	}
+
+	/// Returns a new inverse absorbed dose value from the given number of gram per ergs
+	/// 
+	/// *Note: This method is not available for `f32` and other number types lacking the `From<f64>` trait*
+	///
+	/// # Arguments
+	/// * `per_erg` - Any number-like type, representing a quantity of gram per ergs
+	pub fn from_per_erg(per_erg: T) -> Self {
+		InverseAbsorbedDose{per_Gy: per_erg * T::from(10000.0_f64)}
+	}
+
+}
+
+
+/// Multiplying a unit value by a scalar value returns a unit value
+#[cfg(feature="num-bigfloat")]
+impl core::ops::Mul<InverseAbsorbedDose<num_bigfloat::BigFloat>> for num_bigfloat::BigFloat {
+	type Output = InverseAbsorbedDose<num_bigfloat::BigFloat>;
+	fn mul(self, rhs: InverseAbsorbedDose<num_bigfloat::BigFloat>) -> Self::Output {
+		InverseAbsorbedDose{per_Gy: self * rhs.per_Gy}
+	}
+}
+/// Multiplying a unit value by a scalar value returns a unit value
+#[cfg(feature="num-bigfloat")]
+impl core::ops::Mul<InverseAbsorbedDose<num_bigfloat::BigFloat>> for &num_bigfloat::BigFloat {
+	type Output = InverseAbsorbedDose<num_bigfloat::BigFloat>;
+	fn mul(self, rhs: InverseAbsorbedDose<num_bigfloat::BigFloat>) -> Self::Output {
+		InverseAbsorbedDose{per_Gy: self.clone() * rhs.per_Gy}
+	}
+}
+/// Multiplying a unit value by a scalar value returns a unit value
+#[cfg(feature="num-bigfloat")]
+impl core::ops::Mul<&InverseAbsorbedDose<num_bigfloat::BigFloat>> for num_bigfloat::BigFloat {
+	type Output = InverseAbsorbedDose<num_bigfloat::BigFloat>;
+	fn mul(self, rhs: &InverseAbsorbedDose<num_bigfloat::BigFloat>) -> Self::Output {
+		InverseAbsorbedDose{per_Gy: self * rhs.per_Gy.clone()}
+	}
+}
+/// Multiplying a unit value by a scalar value returns a unit value
+#[cfg(feature="num-bigfloat")]
+impl core::ops::Mul<&InverseAbsorbedDose<num_bigfloat::BigFloat>> for &num_bigfloat::BigFloat {
+	type Output = InverseAbsorbedDose<num_bigfloat::BigFloat>;
+	fn mul(self, rhs: &InverseAbsorbedDose<num_bigfloat::BigFloat>) -> Self::Output {
+		InverseAbsorbedDose{per_Gy: self.clone() * rhs.per_Gy.clone()}
+	}
+}
+
+/// Multiplying a unit value by a scalar value returns a unit value
+#[cfg(feature="num-complex")]
+impl core::ops::Mul<InverseAbsorbedDose<num_complex::Complex32>> for num_complex::Complex32 {
+	type Output = InverseAbsorbedDose<num_complex::Complex32>;
+	fn mul(self, rhs: InverseAbsorbedDose<num_complex::Complex32>) -> Self::Output {
+		InverseAbsorbedDose{per_Gy: self * rhs.per_Gy}
+	}
+}
+/// Multiplying a unit value by a scalar value returns a unit value
+#[cfg(feature="num-complex")]
+impl core::ops::Mul<InverseAbsorbedDose<num_complex::Complex32>> for &num_complex::Complex32 {
+	type Output = InverseAbsorbedDose<num_complex::Complex32>;
+	fn mul(self, rhs: InverseAbsorbedDose<num_complex::Complex32>) -> Self::Output {
+		InverseAbsorbedDose{per_Gy: self.clone() * rhs.per_Gy}
+	}
+}
+/// Multiplying a unit value by a scalar value returns a unit value
+#[cfg(feature="num-complex")]
+impl core::ops::Mul<&InverseAbsorbedDose<num_complex::Complex32>> for num_complex::Complex32 {
+	type Output = InverseAbsorbedDose<num_complex::Complex32>;
+	fn mul(self, rhs: &InverseAbsorbedDose<num_complex::Complex32>) -> Self::Output {
+		InverseAbsorbedDose{per_Gy: self * rhs.per_Gy.clone()}
+	}
+}
+/// Multiplying a unit value by a scalar value returns a unit value
+#[cfg(feature="num-complex")]
+impl core::ops::Mul<&InverseAbsorbedDose<num_complex::Complex32>> for &num_complex::Complex32 {
+	type Output = InverseAbsorbedDose<num_complex::Complex32>;
+	fn mul(self, rhs: &InverseAbsorbedDose<num_complex::Complex32>) -> Self::Output {
+		InverseAbsorbedDose{per_Gy: self.clone() * rhs.per_Gy.clone()}
+	}
+}
+
+/// Multiplying a unit value by a scalar value returns a unit value
+#[cfg(feature="num-complex")]
+impl core::ops::Mul<InverseAbsorbedDose<num_complex::Complex64>> for num_complex::Complex64 {
+	type Output = InverseAbsorbedDose<num_complex::Complex64>;
+	fn mul(self, rhs: InverseAbsorbedDose<num_complex::Complex64>) -> Self::Output {
+		InverseAbsorbedDose{per_Gy: self * rhs.per_Gy}
+	}
+}
+/// Multiplying a unit value by a scalar value returns a unit value
+#[cfg(feature="num-complex")]
+impl core::ops::Mul<InverseAbsorbedDose<num_complex::Complex64>> for &num_complex::Complex64 {
+	type Output = InverseAbsorbedDose<num_complex::Complex64>;
+	fn mul(self, rhs: InverseAbsorbedDose<num_complex::Complex64>) -> Self::Output {
+		InverseAbsorbedDose{per_Gy: self.clone() * rhs.per_Gy}
+	}
+}
+/// Multiplying a unit value by a scalar value returns a unit value
+#[cfg(feature="num-complex")]
+impl core::ops::Mul<&InverseAbsorbedDose<num_complex::Complex64>> for num_complex::Complex64 {
+	type Output = InverseAbsorbedDose<num_complex::Complex64>;
+	fn mul(self, rhs: &InverseAbsorbedDose<num_complex::Complex64>) -> Self::Output {
+		InverseAbsorbedDose{per_Gy: self * rhs.per_Gy.clone()}
+	}
+}
+/// Multiplying a unit value by a scalar value returns a unit value
+#[cfg(feature="num-complex")]
+impl core::ops::Mul<&InverseAbsorbedDose<num_complex::Complex64>> for &num_complex::Complex64 {
+	type Output = InverseAbsorbedDose<num_complex::Complex64>;
+	fn mul(self, rhs: &InverseAbsorbedDose<num_complex::Complex64>) -> Self::Output {
+		InverseAbsorbedDose{per_Gy: self.clone() * rhs.per_Gy.clone()}
+	}
+}
+
+/// Multiplying a unit value by a scalar value returns a unit value
+#[cfg(feature="num-rational")]
+impl core::ops::Mul<InverseAbsorbedDose<num_rational::Rational64>> for num_rational::Rational64 {
+	type Output = InverseAbsorbedDose<num_rational::Rational64>;
+	fn mul(self, rhs: InverseAbsorbedDose<num_rational::Rational64>) -> Self::Output {
+		InverseAbsorbedDose{per_Gy: self * rhs.per_Gy}
+	}
+}
+/// Multiplying a unit value by a scalar value returns a unit value
+#[cfg(feature="num-rational")]
+impl core::ops::Mul<InverseAbsorbedDose<num_rational::Rational64>> for &num_rational::Rational64 {
+	type Output = InverseAbsorbedDose<num_rational::Rational64>;
+	fn mul(self, rhs: InverseAbsorbedDose<num_rational::Rational64>) -> Self::Output {
+		InverseAbsorbedDose{per_Gy: self.clone() * rhs.per_Gy}
+	}
+}
+/// Multiplying a unit value by a scalar value returns a unit value
+#[cfg(feature="num-rational")]
+impl core::ops::Mul<&InverseAbsorbedDose<num_rational::Rational64>> for num_rational::Rational64 {
+	type Output = InverseAbsorbedDose<num_rational::Rational64>;
+	fn mul(self, rhs: &InverseAbsorbedDose<num_rational::Rational64>) -> Self::Output {
+		InverseAbsorbedDose{per_Gy: self * rhs.per_Gy.clone()}
+	}
+}
+/// Multiplying a unit value by a scalar value returns a unit value
+#[cfg(feature="num-rational")]
+impl core::ops::Mul<&InverseAbsorbedDose<num_rational::Rational64>> for &num_rational::Rational64 {
+	type Output = InverseAbsorbedDose<num_rational::Rational64>;
+	fn mul(self, rhs: &InverseAbsorbedDose<num_rational::Rational64>) -> Self::Output {
+		InverseAbsorbedDose{per_Gy: self.clone() * rhs.per_Gy.clone()}
+	}
+}
+
+/// Multiplying a unit value by a scalar value returns a unit value
+#[cfg(feature="num-rational")]
+impl core::ops::Mul<InverseAbsorbedDose<num_rational::BigRational>> for num_rational::BigRational {
+	type Output = InverseAbsorbedDose<num_rational::BigRational>;
+	fn mul(self, rhs: InverseAbsorbedDose<num_rational::BigRational>) -> Self::Output {
+		InverseAbsorbedDose{per_Gy: self * rhs.per_Gy}
+	}
+}
+/// Multiplying a unit value by a scalar value returns a unit value
+#[cfg(feature="num-rational")]
+impl core::ops::Mul<InverseAbsorbedDose<num_rational::BigRational>> for &num_rational::BigRational {
+	type Output = InverseAbsorbedDose<num_rational::BigRational>;
+	fn mul(self, rhs: InverseAbsorbedDose<num_rational::BigRational>) -> Self::Output {
+		InverseAbsorbedDose{per_Gy: self.clone() * rhs.per_Gy}
+	}
+}
+/// Multiplying a unit value by a scalar value returns a unit value
+#[cfg(feature="num-rational")]
+impl core::ops::Mul<&InverseAbsorbedDose<num_rational::BigRational>> for num_rational::BigRational {
+	type Output = InverseAbsorbedDose<num_rational::BigRational>;
+	fn mul(self, rhs: &InverseAbsorbedDose<num_rational::BigRational>) -> Self::Output {
+		InverseAbsorbedDose{per_Gy: self * rhs.per_Gy.clone()}
+	}
+}
+/// Multiplying a unit value by a scalar value returns a unit value
+#[cfg(feature="num-rational")]
+impl core::ops::Mul<&InverseAbsorbedDose<num_rational::BigRational>> for &num_rational::BigRational {
+	type Output = InverseAbsorbedDose<num_rational::BigRational>;
+	fn mul(self, rhs: &InverseAbsorbedDose<num_rational::BigRational>) -> Self::Output {
+		InverseAbsorbedDose{per_Gy: self.clone() * rhs.per_Gy.clone()}
+	}
+}
+
+
+
+
+
+/// Multiplying a InverseAbsorbedDose by an [nalgebra](https://crates.io/crates/nalgebra) 
+/// vector or matrix of unitless values returns a vector or matrix of InverseAbsorbedDose 
+/// values (eg a direction vector multiplied by a InverseAbsorbedDose)
+#[cfg(feature="nalgebra")]
+impl<T, R, C, S> core::ops::Mul<nalgebra::Matrix<T, R, C, S>> for InverseAbsorbedDose<T> where 
+	T: NumLike+nalgebra::Scalar, R: nalgebra::Dim, C: nalgebra::Dim, S: nalgebra::RawStorage<T, R, C>, 
+	nalgebra::DefaultAllocator: nalgebra::allocator::Allocator<R, C> {
+	type Output = nalgebra::OMatrix<InverseAbsorbedDose<T>, R, C>;
+	fn mul(self, rhs: nalgebra::Matrix<T, R, C, S>) -> Self::Output {
+		rhs.map(|x| InverseAbsorbedDose{per_Gy: self.per_Gy.clone() * x})
+	}
+}
+/// Multiplying an [nalgebra](https://crates.io/crates/nalgebra) vector or matrix of 
+/// unitless values by a InverseAbsorbedDose returns a vector or matrix of InverseAbsorbedDose values
+#[cfg(feature="nalgebra")]
+impl<T, R, C, S> core::ops::Mul<InverseAbsorbedDose<T>> for nalgebra::Matrix<T, R, C, S> where 
+	T: NumLike+nalgebra::Scalar, R: nalgebra::Dim, C: nalgebra::Dim, S: nalgebra::RawStorage<T, R, C>, 
+	nalgebra::DefaultAllocator: nalgebra::allocator::Allocator<R, C> {
+	type Output = nalgebra::OMatrix<InverseAbsorbedDose<T>, R, C>;
+	fn mul(self, rhs: InverseAbsorbedDose<T>) -> Self::Output {
+		self.map(|x| InverseAbsorbedDose{per_Gy: x * rhs.per_Gy.clone()})
+	}
+}
+/// Dividing an [nalgebra](https://crates.io/crates/nalgebra) vector or matrix of 
+/// InverseAbsorbedDose values by a InverseAbsorbedDose returns a vector or matrix of unitless values
+#[cfg(feature="nalgebra")]
+impl<T, R, C, S> core::ops::Div<InverseAbsorbedDose<T>> for nalgebra::Matrix<InverseAbsorbedDose<T>, R, C, S> where 
+	T: NumLike+nalgebra::Scalar, R: nalgebra::Dim, C: nalgebra::Dim, S: nalgebra::RawStorage<InverseAbsorbedDose<T>, R, C>, 
+	nalgebra::DefaultAllocator: nalgebra::allocator::Allocator<R, C> {
+	type Output = nalgebra::OMatrix<T, R, C>;
+	fn div(self, rhs: InverseAbsorbedDose<T>) -> Self::Output {
+		self.map(|x| x.per_Gy / rhs.per_Gy.clone())
+	}
+}
+
+
+
+// InverseAbsorbedDose * Distance -> InverseAcceleration
+/// Multiplying a InverseAbsorbedDose by a Distance returns a value of type InverseAcceleration
+impl<T> core::ops::Mul<Distance<T>> for InverseAbsorbedDose<T> where T: NumLike {
+	type Output = InverseAcceleration<T>;
+	fn mul(self, rhs: Distance<T>) -> Self::Output {
+		InverseAcceleration{s2pm: self.per_Gy * rhs.m}
+	}
+}
+/// Multiplying a InverseAbsorbedDose by a Distance returns a value of type InverseAcceleration
+impl<T> core::ops::Mul<Distance<T>> for &InverseAbsorbedDose<T> where T: NumLike {
+	type Output = InverseAcceleration<T>;
+	fn mul(self, rhs: Distance<T>) -> Self::Output {
+		InverseAcceleration{s2pm: self.per_Gy.clone() * rhs.m}
+	}
+}
+/// Multiplying a InverseAbsorbedDose by a Distance returns a value of type InverseAcceleration
+impl<T> core::ops::Mul<&Distance<T>> for InverseAbsorbedDose<T> where T: NumLike {
+	type Output = InverseAcceleration<T>;
+	fn mul(self, rhs: &Distance<T>) -> Self::Output {
+		InverseAcceleration{s2pm: self.per_Gy * rhs.m.clone()}
+	}
+}
+/// Multiplying a InverseAbsorbedDose by a Distance returns a value of type InverseAcceleration
+impl<T> core::ops::Mul<&Distance<T>> for &InverseAbsorbedDose<T> where T: NumLike {
+	type Output = InverseAcceleration<T>;
+	fn mul(self, rhs: &Distance<T>) -> Self::Output {
+		InverseAcceleration{s2pm: self.per_Gy.clone() * rhs.m.clone()}
+	}
+}
+
+// InverseAbsorbedDose / InverseDistance -> InverseAcceleration
+/// Dividing a InverseAbsorbedDose by a InverseDistance returns a value of type InverseAcceleration
+impl<T> core::ops::Div<InverseDistance<T>> for InverseAbsorbedDose<T> where T: NumLike {
+	type Output = InverseAcceleration<T>;
+	fn div(self, rhs: InverseDistance<T>) -> Self::Output {
+		InverseAcceleration{s2pm: self.per_Gy / rhs.per_m}
+	}
+}
+/// Dividing a InverseAbsorbedDose by a InverseDistance returns a value of type InverseAcceleration
+impl<T> core::ops::Div<InverseDistance<T>> for &InverseAbsorbedDose<T> where T: NumLike {
+	type Output = InverseAcceleration<T>;
+	fn div(self, rhs: InverseDistance<T>) -> Self::Output {
+		InverseAcceleration{s2pm: self.per_Gy.clone() / rhs.per_m}
+	}
+}
+/// Dividing a InverseAbsorbedDose by a InverseDistance returns a value of type InverseAcceleration
+impl<T> core::ops::Div<&InverseDistance<T>> for InverseAbsorbedDose<T> where T: NumLike {
+	type Output = InverseAcceleration<T>;
+	fn div(self, rhs: &InverseDistance<T>) -> Self::Output {
+		InverseAcceleration{s2pm: self.per_Gy / rhs.per_m.clone()}
+	}
+}
+/// Dividing a InverseAbsorbedDose by a InverseDistance returns a value of type InverseAcceleration
+impl<T> core::ops::Div<&InverseDistance<T>> for &InverseAbsorbedDose<T> where T: NumLike {
+	type Output = InverseAcceleration<T>;
+	fn div(self, rhs: &InverseDistance<T>) -> Self::Output {
+		InverseAcceleration{s2pm: self.per_Gy.clone() / rhs.per_m.clone()}
+	}
+}
+
+// InverseAbsorbedDose * InverseMass -> InverseEnergy
+/// Multiplying a InverseAbsorbedDose by a InverseMass returns a value of type InverseEnergy
+impl<T> core::ops::Mul<InverseMass<T>> for InverseAbsorbedDose<T> where T: NumLike {
+	type Output = InverseEnergy<T>;
+	fn mul(self, rhs: InverseMass<T>) -> Self::Output {
+		InverseEnergy{per_J: self.per_Gy * rhs.per_kg}
+	}
+}
+/// Multiplying a InverseAbsorbedDose by a InverseMass returns a value of type InverseEnergy
+impl<T> core::ops::Mul<InverseMass<T>> for &InverseAbsorbedDose<T> where T: NumLike {
+	type Output = InverseEnergy<T>;
+	fn mul(self, rhs: InverseMass<T>) -> Self::Output {
+		InverseEnergy{per_J: self.per_Gy.clone() * rhs.per_kg}
+	}
+}
+/// Multiplying a InverseAbsorbedDose by a InverseMass returns a value of type InverseEnergy
+impl<T> core::ops::Mul<&InverseMass<T>> for InverseAbsorbedDose<T> where T: NumLike {
+	type Output = InverseEnergy<T>;
+	fn mul(self, rhs: &InverseMass<T>) -> Self::Output {
+		InverseEnergy{per_J: self.per_Gy * rhs.per_kg.clone()}
+	}
+}
+/// Multiplying a InverseAbsorbedDose by a InverseMass returns a value of type InverseEnergy
+impl<T> core::ops::Mul<&InverseMass<T>> for &InverseAbsorbedDose<T> where T: NumLike {
+	type Output = InverseEnergy<T>;
+	fn mul(self, rhs: &InverseMass<T>) -> Self::Output {
+		InverseEnergy{per_J: self.per_Gy.clone() * rhs.per_kg.clone()}
+	}
+}
+
+// InverseAbsorbedDose / InverseTemperature -> InverseSpecificHeatCapacity
+/// Dividing a InverseAbsorbedDose by a InverseTemperature returns a value of type InverseSpecificHeatCapacity
+impl<T> core::ops::Div<InverseTemperature<T>> for InverseAbsorbedDose<T> where T: NumLike {
+	type Output = InverseSpecificHeatCapacity<T>;
+	fn div(self, rhs: InverseTemperature<T>) -> Self::Output {
+		InverseSpecificHeatCapacity{kgK_per_J: self.per_Gy / rhs.per_K}
+	}
+}
+/// Dividing a InverseAbsorbedDose by a InverseTemperature returns a value of type InverseSpecificHeatCapacity
+impl<T> core::ops::Div<InverseTemperature<T>> for &InverseAbsorbedDose<T> where T: NumLike {
+	type Output = InverseSpecificHeatCapacity<T>;
+	fn div(self, rhs: InverseTemperature<T>) -> Self::Output {
+		InverseSpecificHeatCapacity{kgK_per_J: self.per_Gy.clone() / rhs.per_K}
+	}
+}
+/// Dividing a InverseAbsorbedDose by a InverseTemperature returns a value of type InverseSpecificHeatCapacity
+impl<T> core::ops::Div<&InverseTemperature<T>> for InverseAbsorbedDose<T> where T: NumLike {
+	type Output = InverseSpecificHeatCapacity<T>;
+	fn div(self, rhs: &InverseTemperature<T>) -> Self::Output {
+		InverseSpecificHeatCapacity{kgK_per_J: self.per_Gy / rhs.per_K.clone()}
+	}
+}
+/// Dividing a InverseAbsorbedDose by a InverseTemperature returns a value of type InverseSpecificHeatCapacity
+impl<T> core::ops::Div<&InverseTemperature<T>> for &InverseAbsorbedDose<T> where T: NumLike {
+	type Output = InverseSpecificHeatCapacity<T>;
+	fn div(self, rhs: &InverseTemperature<T>) -> Self::Output {
+		InverseSpecificHeatCapacity{kgK_per_J: self.per_Gy.clone() / rhs.per_K.clone()}
+	}
+}
+
+// InverseAbsorbedDose / Mass -> InverseEnergy
+/// Dividing a InverseAbsorbedDose by a Mass returns a value of type InverseEnergy
+impl<T> core::ops::Div<Mass<T>> for InverseAbsorbedDose<T> where T: NumLike {
+	type Output = InverseEnergy<T>;
+	fn div(self, rhs: Mass<T>) -> Self::Output {
+		InverseEnergy{per_J: self.per_Gy / rhs.kg}
+	}
+}
+/// Dividing a InverseAbsorbedDose by a Mass returns a value of type InverseEnergy
+impl<T> core::ops::Div<Mass<T>> for &InverseAbsorbedDose<T> where T: NumLike {
+	type Output = InverseEnergy<T>;
+	fn div(self, rhs: Mass<T>) -> Self::Output {
+		InverseEnergy{per_J: self.per_Gy.clone() / rhs.kg}
+	}
+}
+/// Dividing a InverseAbsorbedDose by a Mass returns a value of type InverseEnergy
+impl<T> core::ops::Div<&Mass<T>> for InverseAbsorbedDose<T> where T: NumLike {
+	type Output = InverseEnergy<T>;
+	fn div(self, rhs: &Mass<T>) -> Self::Output {
+		InverseEnergy{per_J: self.per_Gy / rhs.kg.clone()}
+	}
+}
+/// Dividing a InverseAbsorbedDose by a Mass returns a value of type InverseEnergy
+impl<T> core::ops::Div<&Mass<T>> for &InverseAbsorbedDose<T> where T: NumLike {
+	type Output = InverseEnergy<T>;
+	fn div(self, rhs: &Mass<T>) -> Self::Output {
+		InverseEnergy{per_J: self.per_Gy.clone() / rhs.kg.clone()}
+	}
+}
+
+// InverseAbsorbedDose * Temperature -> InverseSpecificHeatCapacity
+/// Multiplying a InverseAbsorbedDose by a Temperature returns a value of type InverseSpecificHeatCapacity
+impl<T> core::ops::Mul<Temperature<T>> for InverseAbsorbedDose<T> where T: NumLike {
+	type Output = InverseSpecificHeatCapacity<T>;
+	fn mul(self, rhs: Temperature<T>) -> Self::Output {
+		InverseSpecificHeatCapacity{kgK_per_J: self.per_Gy * rhs.K}
+	}
+}
+/// Multiplying a InverseAbsorbedDose by a Temperature returns a value of type InverseSpecificHeatCapacity
+impl<T> core::ops::Mul<Temperature<T>> for &InverseAbsorbedDose<T> where T: NumLike {
+	type Output = InverseSpecificHeatCapacity<T>;
+	fn mul(self, rhs: Temperature<T>) -> Self::Output {
+		InverseSpecificHeatCapacity{kgK_per_J: self.per_Gy.clone() * rhs.K}
+	}
+}
+/// Multiplying a InverseAbsorbedDose by a Temperature returns a value of type InverseSpecificHeatCapacity
+impl<T> core::ops::Mul<&Temperature<T>> for InverseAbsorbedDose<T> where T: NumLike {
+	type Output = InverseSpecificHeatCapacity<T>;
+	fn mul(self, rhs: &Temperature<T>) -> Self::Output {
+		InverseSpecificHeatCapacity{kgK_per_J: self.per_Gy * rhs.K.clone()}
+	}
+}
+/// Multiplying a InverseAbsorbedDose by a Temperature returns a value of type InverseSpecificHeatCapacity
+impl<T> core::ops::Mul<&Temperature<T>> for &InverseAbsorbedDose<T> where T: NumLike {
+	type Output = InverseSpecificHeatCapacity<T>;
+	fn mul(self, rhs: &Temperature<T>) -> Self::Output {
+		InverseSpecificHeatCapacity{kgK_per_J: self.per_Gy.clone() * rhs.K.clone()}
+	}
+}
+
+// InverseAbsorbedDose * TemperatureInterval -> InverseSpecificHeatCapacity
+/// Multiplying a InverseAbsorbedDose by a TemperatureInterval returns a value of type InverseSpecificHeatCapacity
+impl<T> core::ops::Mul<TemperatureInterval<T>> for InverseAbsorbedDose<T> where T: NumLike {
+	type Output = InverseSpecificHeatCapacity<T>;
+	fn mul(self, rhs: TemperatureInterval<T>) -> Self::Output {
+		InverseSpecificHeatCapacity{kgK_per_J: self.per_Gy * rhs.K}
+	}
+}
+/// Multiplying a InverseAbsorbedDose by a TemperatureInterval returns a value of type InverseSpecificHeatCapacity
+impl<T> core::ops::Mul<TemperatureInterval<T>> for &InverseAbsorbedDose<T> where T: NumLike {
+	type Output = InverseSpecificHeatCapacity<T>;
+	fn mul(self, rhs: TemperatureInterval<T>) -> Self::Output {
+		InverseSpecificHeatCapacity{kgK_per_J: self.per_Gy.clone() * rhs.K}
+	}
+}
+/// Multiplying a InverseAbsorbedDose by a TemperatureInterval returns a value of type InverseSpecificHeatCapacity
+impl<T> core::ops::Mul<&TemperatureInterval<T>> for InverseAbsorbedDose<T> where T: NumLike {
+	type Output = InverseSpecificHeatCapacity<T>;
+	fn mul(self, rhs: &TemperatureInterval<T>) -> Self::Output {
+		InverseSpecificHeatCapacity{kgK_per_J: self.per_Gy * rhs.K.clone()}
+	}
+}
+/// Multiplying a InverseAbsorbedDose by a TemperatureInterval returns a value of type InverseSpecificHeatCapacity
+impl<T> core::ops::Mul<&TemperatureInterval<T>> for &InverseAbsorbedDose<T> where T: NumLike {
+	type Output = InverseSpecificHeatCapacity<T>;
+	fn mul(self, rhs: &TemperatureInterval<T>) -> Self::Output {
+		InverseSpecificHeatCapacity{kgK_per_J: self.per_Gy.clone() * rhs.K.clone()}
+	}
+}
+
+// InverseAbsorbedDose / Time -> InverseKinematicViscosity
+/// Dividing a InverseAbsorbedDose by a Time returns a value of type InverseKinematicViscosity
+impl<T> core::ops::Div<Time<T>> for InverseAbsorbedDose<T> where T: NumLike {
+	type Output = InverseKinematicViscosity<T>;
+	fn div(self, rhs: Time<T>) -> Self::Output {
+		InverseKinematicViscosity{s_per_m2: self.per_Gy / rhs.s}
+	}
+}
+/// Dividing a InverseAbsorbedDose by a Time returns a value of type InverseKinematicViscosity
+impl<T> core::ops::Div<Time<T>> for &InverseAbsorbedDose<T> where T: NumLike {
+	type Output = InverseKinematicViscosity<T>;
+	fn div(self, rhs: Time<T>) -> Self::Output {
+		InverseKinematicViscosity{s_per_m2: self.per_Gy.clone() / rhs.s}
+	}
+}
+/// Dividing a InverseAbsorbedDose by a Time returns a value of type InverseKinematicViscosity
+impl<T> core::ops::Div<&Time<T>> for InverseAbsorbedDose<T> where T: NumLike {
+	type Output = InverseKinematicViscosity<T>;
+	fn div(self, rhs: &Time<T>) -> Self::Output {
+		InverseKinematicViscosity{s_per_m2: self.per_Gy / rhs.s.clone()}
+	}
+}
+/// Dividing a InverseAbsorbedDose by a Time returns a value of type InverseKinematicViscosity
+impl<T> core::ops::Div<&Time<T>> for &InverseAbsorbedDose<T> where T: NumLike {
+	type Output = InverseKinematicViscosity<T>;
+	fn div(self, rhs: &Time<T>) -> Self::Output {
+		InverseKinematicViscosity{s_per_m2: self.per_Gy.clone() / rhs.s.clone()}
+	}
+}
+
+// InverseAbsorbedDose / InverseMolarEnergy -> MolarMass
+/// Dividing a InverseAbsorbedDose by a InverseMolarEnergy returns a value of type MolarMass
+impl<T> core::ops::Div<InverseMolarEnergy<T>> for InverseAbsorbedDose<T> where T: NumLike {
+	type Output = MolarMass<T>;
+	fn div(self, rhs: InverseMolarEnergy<T>) -> Self::Output {
+		MolarMass{kgpmol: self.per_Gy / rhs.mol_per_J}
+	}
+}
+/// Dividing a InverseAbsorbedDose by a InverseMolarEnergy returns a value of type MolarMass
+impl<T> core::ops::Div<InverseMolarEnergy<T>> for &InverseAbsorbedDose<T> where T: NumLike {
+	type Output = MolarMass<T>;
+	fn div(self, rhs: InverseMolarEnergy<T>) -> Self::Output {
+		MolarMass{kgpmol: self.per_Gy.clone() / rhs.mol_per_J}
+	}
+}
+/// Dividing a InverseAbsorbedDose by a InverseMolarEnergy returns a value of type MolarMass
+impl<T> core::ops::Div<&InverseMolarEnergy<T>> for InverseAbsorbedDose<T> where T: NumLike {
+	type Output = MolarMass<T>;
+	fn div(self, rhs: &InverseMolarEnergy<T>) -> Self::Output {
+		MolarMass{kgpmol: self.per_Gy / rhs.mol_per_J.clone()}
+	}
+}
+/// Dividing a InverseAbsorbedDose by a InverseMolarEnergy returns a value of type MolarMass
+impl<T> core::ops::Div<&InverseMolarEnergy<T>> for &InverseAbsorbedDose<T> where T: NumLike {
+	type Output = MolarMass<T>;
+	fn div(self, rhs: &InverseMolarEnergy<T>) -> Self::Output {
+		MolarMass{kgpmol: self.per_Gy.clone() / rhs.mol_per_J.clone()}
+	}
+}
+
+// InverseAbsorbedDose / InverseSpecificHeatCapacity -> InverseTemperature
+/// Dividing a InverseAbsorbedDose by a InverseSpecificHeatCapacity returns a value of type InverseTemperature
+impl<T> core::ops::Div<InverseSpecificHeatCapacity<T>> for InverseAbsorbedDose<T> where T: NumLike {
+	type Output = InverseTemperature<T>;
+	fn div(self, rhs: InverseSpecificHeatCapacity<T>) -> Self::Output {
+		InverseTemperature{per_K: self.per_Gy / rhs.kgK_per_J}
+	}
+}
+/// Dividing a InverseAbsorbedDose by a InverseSpecificHeatCapacity returns a value of type InverseTemperature
+impl<T> core::ops::Div<InverseSpecificHeatCapacity<T>> for &InverseAbsorbedDose<T> where T: NumLike {
+	type Output = InverseTemperature<T>;
+	fn div(self, rhs: InverseSpecificHeatCapacity<T>) -> Self::Output {
+		InverseTemperature{per_K: self.per_Gy.clone() / rhs.kgK_per_J}
+	}
+}
+/// Dividing a InverseAbsorbedDose by a InverseSpecificHeatCapacity returns a value of type InverseTemperature
+impl<T> core::ops::Div<&InverseSpecificHeatCapacity<T>> for InverseAbsorbedDose<T> where T: NumLike {
+	type Output = InverseTemperature<T>;
+	fn div(self, rhs: &InverseSpecificHeatCapacity<T>) -> Self::Output {
+		InverseTemperature{per_K: self.per_Gy / rhs.kgK_per_J.clone()}
+	}
+}
+/// Dividing a InverseAbsorbedDose by a InverseSpecificHeatCapacity returns a value of type InverseTemperature
+impl<T> core::ops::Div<&InverseSpecificHeatCapacity<T>> for &InverseAbsorbedDose<T> where T: NumLike {
+	type Output = InverseTemperature<T>;
+	fn div(self, rhs: &InverseSpecificHeatCapacity<T>) -> Self::Output {
+		InverseTemperature{per_K: self.per_Gy.clone() / rhs.kgK_per_J.clone()}
+	}
+}
+
+// InverseAbsorbedDose * Molality -> InverseMolarEnergy
+/// Multiplying a InverseAbsorbedDose by a Molality returns a value of type InverseMolarEnergy
+impl<T> core::ops::Mul<Molality<T>> for InverseAbsorbedDose<T> where T: NumLike {
+	type Output = InverseMolarEnergy<T>;
+	fn mul(self, rhs: Molality<T>) -> Self::Output {
+		InverseMolarEnergy{mol_per_J: self.per_Gy * rhs.molpkg}
+	}
+}
+/// Multiplying a InverseAbsorbedDose by a Molality returns a value of type InverseMolarEnergy
+impl<T> core::ops::Mul<Molality<T>> for &InverseAbsorbedDose<T> where T: NumLike {
+	type Output = InverseMolarEnergy<T>;
+	fn mul(self, rhs: Molality<T>) -> Self::Output {
+		InverseMolarEnergy{mol_per_J: self.per_Gy.clone() * rhs.molpkg}
+	}
+}
+/// Multiplying a InverseAbsorbedDose by a Molality returns a value of type InverseMolarEnergy
+impl<T> core::ops::Mul<&Molality<T>> for InverseAbsorbedDose<T> where T: NumLike {
+	type Output = InverseMolarEnergy<T>;
+	fn mul(self, rhs: &Molality<T>) -> Self::Output {
+		InverseMolarEnergy{mol_per_J: self.per_Gy * rhs.molpkg.clone()}
+	}
+}
+/// Multiplying a InverseAbsorbedDose by a Molality returns a value of type InverseMolarEnergy
+impl<T> core::ops::Mul<&Molality<T>> for &InverseAbsorbedDose<T> where T: NumLike {
+	type Output = InverseMolarEnergy<T>;
+	fn mul(self, rhs: &Molality<T>) -> Self::Output {
+		InverseMolarEnergy{mol_per_J: self.per_Gy.clone() * rhs.molpkg.clone()}
+	}
+}
+
+// InverseAbsorbedDose * MolarEnergy -> MolarMass
+/// Multiplying a InverseAbsorbedDose by a MolarEnergy returns a value of type MolarMass
+impl<T> core::ops::Mul<MolarEnergy<T>> for InverseAbsorbedDose<T> where T: NumLike {
+	type Output = MolarMass<T>;
+	fn mul(self, rhs: MolarEnergy<T>) -> Self::Output {
+		MolarMass{kgpmol: self.per_Gy * rhs.J_per_mol}
+	}
+}
+/// Multiplying a InverseAbsorbedDose by a MolarEnergy returns a value of type MolarMass
+impl<T> core::ops::Mul<MolarEnergy<T>> for &InverseAbsorbedDose<T> where T: NumLike {
+	type Output = MolarMass<T>;
+	fn mul(self, rhs: MolarEnergy<T>) -> Self::Output {
+		MolarMass{kgpmol: self.per_Gy.clone() * rhs.J_per_mol}
+	}
+}
+/// Multiplying a InverseAbsorbedDose by a MolarEnergy returns a value of type MolarMass
+impl<T> core::ops::Mul<&MolarEnergy<T>> for InverseAbsorbedDose<T> where T: NumLike {
+	type Output = MolarMass<T>;
+	fn mul(self, rhs: &MolarEnergy<T>) -> Self::Output {
+		MolarMass{kgpmol: self.per_Gy * rhs.J_per_mol.clone()}
+	}
+}
+/// Multiplying a InverseAbsorbedDose by a MolarEnergy returns a value of type MolarMass
+impl<T> core::ops::Mul<&MolarEnergy<T>> for &InverseAbsorbedDose<T> where T: NumLike {
+	type Output = MolarMass<T>;
+	fn mul(self, rhs: &MolarEnergy<T>) -> Self::Output {
+		MolarMass{kgpmol: self.per_Gy.clone() * rhs.J_per_mol.clone()}
+	}
+}
+
+// InverseAbsorbedDose / MolarMass -> InverseMolarEnergy
+/// Dividing a InverseAbsorbedDose by a MolarMass returns a value of type InverseMolarEnergy
+impl<T> core::ops::Div<MolarMass<T>> for InverseAbsorbedDose<T> where T: NumLike {
+	type Output = InverseMolarEnergy<T>;
+	fn div(self, rhs: MolarMass<T>) -> Self::Output {
+		InverseMolarEnergy{mol_per_J: self.per_Gy / rhs.kgpmol}
+	}
+}
+/// Dividing a InverseAbsorbedDose by a MolarMass returns a value of type InverseMolarEnergy
+impl<T> core::ops::Div<MolarMass<T>> for &InverseAbsorbedDose<T> where T: NumLike {
+	type Output = InverseMolarEnergy<T>;
+	fn div(self, rhs: MolarMass<T>) -> Self::Output {
+		InverseMolarEnergy{mol_per_J: self.per_Gy.clone() / rhs.kgpmol}
+	}
+}
+/// Dividing a InverseAbsorbedDose by a MolarMass returns a value of type InverseMolarEnergy
+impl<T> core::ops::Div<&MolarMass<T>> for InverseAbsorbedDose<T> where T: NumLike {
+	type Output = InverseMolarEnergy<T>;
+	fn div(self, rhs: &MolarMass<T>) -> Self::Output {
+		InverseMolarEnergy{mol_per_J: self.per_Gy / rhs.kgpmol.clone()}
+	}
+}
+/// Dividing a InverseAbsorbedDose by a MolarMass returns a value of type InverseMolarEnergy
+impl<T> core::ops::Div<&MolarMass<T>> for &InverseAbsorbedDose<T> where T: NumLike {
+	type Output = InverseMolarEnergy<T>;
+	fn div(self, rhs: &MolarMass<T>) -> Self::Output {
+		InverseMolarEnergy{mol_per_J: self.per_Gy.clone() / rhs.kgpmol.clone()}
+	}
+}
+
+// InverseAbsorbedDose * SpecificHeatCapacity -> InverseTemperature
+/// Multiplying a InverseAbsorbedDose by a SpecificHeatCapacity returns a value of type InverseTemperature
+impl<T> core::ops::Mul<SpecificHeatCapacity<T>> for InverseAbsorbedDose<T> where T: NumLike {
+	type Output = InverseTemperature<T>;
+	fn mul(self, rhs: SpecificHeatCapacity<T>) -> Self::Output {
+		InverseTemperature{per_K: self.per_Gy * rhs.J_per_kgK}
+	}
+}
+/// Multiplying a InverseAbsorbedDose by a SpecificHeatCapacity returns a value of type InverseTemperature
+impl<T> core::ops::Mul<SpecificHeatCapacity<T>> for &InverseAbsorbedDose<T> where T: NumLike {
+	type Output = InverseTemperature<T>;
+	fn mul(self, rhs: SpecificHeatCapacity<T>) -> Self::Output {
+		InverseTemperature{per_K: self.per_Gy.clone() * rhs.J_per_kgK}
+	}
+}
+/// Multiplying a InverseAbsorbedDose by a SpecificHeatCapacity returns a value of type InverseTemperature
+impl<T> core::ops::Mul<&SpecificHeatCapacity<T>> for InverseAbsorbedDose<T> where T: NumLike {
+	type Output = InverseTemperature<T>;
+	fn mul(self, rhs: &SpecificHeatCapacity<T>) -> Self::Output {
+		InverseTemperature{per_K: self.per_Gy * rhs.J_per_kgK.clone()}
+	}
+}
+/// Multiplying a InverseAbsorbedDose by a SpecificHeatCapacity returns a value of type InverseTemperature
+impl<T> core::ops::Mul<&SpecificHeatCapacity<T>> for &InverseAbsorbedDose<T> where T: NumLike {
+	type Output = InverseTemperature<T>;
+	fn mul(self, rhs: &SpecificHeatCapacity<T>) -> Self::Output {
+		InverseTemperature{per_K: self.per_Gy.clone() * rhs.J_per_kgK.clone()}
+	}
+}
+
+// InverseAbsorbedDose * Acceleration -> InverseDistance
+/// Multiplying a InverseAbsorbedDose by a Acceleration returns a value of type InverseDistance
+impl<T> core::ops::Mul<Acceleration<T>> for InverseAbsorbedDose<T> where T: NumLike {
+	type Output = InverseDistance<T>;
+	fn mul(self, rhs: Acceleration<T>) -> Self::Output {
+		InverseDistance{per_m: self.per_Gy * rhs.mps2}
+	}
+}
+/// Multiplying a InverseAbsorbedDose by a Acceleration returns a value of type InverseDistance
+impl<T> core::ops::Mul<Acceleration<T>> for &InverseAbsorbedDose<T> where T: NumLike {
+	type Output = InverseDistance<T>;
+	fn mul(self, rhs: Acceleration<T>) -> Self::Output {
+		InverseDistance{per_m: self.per_Gy.clone() * rhs.mps2}
+	}
+}
+/// Multiplying a InverseAbsorbedDose by a Acceleration returns a value of type InverseDistance
+impl<T> core::ops::Mul<&Acceleration<T>> for InverseAbsorbedDose<T> where T: NumLike {
+	type Output = InverseDistance<T>;
+	fn mul(self, rhs: &Acceleration<T>) -> Self::Output {
+		InverseDistance{per_m: self.per_Gy * rhs.mps2.clone()}
+	}
+}
+/// Multiplying a InverseAbsorbedDose by a Acceleration returns a value of type InverseDistance
+impl<T> core::ops::Mul<&Acceleration<T>> for &InverseAbsorbedDose<T> where T: NumLike {
+	type Output = InverseDistance<T>;
+	fn mul(self, rhs: &Acceleration<T>) -> Self::Output {
+		InverseDistance{per_m: self.per_Gy.clone() * rhs.mps2.clone()}
+	}
+}
+
+// InverseAbsorbedDose / Density -> InversePressure
+/// Dividing a InverseAbsorbedDose by a Density returns a value of type InversePressure
+impl<T> core::ops::Div<Density<T>> for InverseAbsorbedDose<T> where T: NumLike {
+	type Output = InversePressure<T>;
+	fn div(self, rhs: Density<T>) -> Self::Output {
+		InversePressure{per_Pa: self.per_Gy / rhs.kgpm3}
+	}
+}
+/// Dividing a InverseAbsorbedDose by a Density returns a value of type InversePressure
+impl<T> core::ops::Div<Density<T>> for &InverseAbsorbedDose<T> where T: NumLike {
+	type Output = InversePressure<T>;
+	fn div(self, rhs: Density<T>) -> Self::Output {
+		InversePressure{per_Pa: self.per_Gy.clone() / rhs.kgpm3}
+	}
+}
+/// Dividing a InverseAbsorbedDose by a Density returns a value of type InversePressure
+impl<T> core::ops::Div<&Density<T>> for InverseAbsorbedDose<T> where T: NumLike {
+	type Output = InversePressure<T>;
+	fn div(self, rhs: &Density<T>) -> Self::Output {
+		InversePressure{per_Pa: self.per_Gy / rhs.kgpm3.clone()}
+	}
+}
+/// Dividing a InverseAbsorbedDose by a Density returns a value of type InversePressure
+impl<T> core::ops::Div<&Density<T>> for &InverseAbsorbedDose<T> where T: NumLike {
+	type Output = InversePressure<T>;
+	fn div(self, rhs: &Density<T>) -> Self::Output {
+		InversePressure{per_Pa: self.per_Gy.clone() / rhs.kgpm3.clone()}
+	}
+}
+
+// InverseAbsorbedDose * Energy -> Mass
+/// Multiplying a InverseAbsorbedDose by a Energy returns a value of type Mass
+impl<T> core::ops::Mul<Energy<T>> for InverseAbsorbedDose<T> where T: NumLike {
+	type Output = Mass<T>;
+	fn mul(self, rhs: Energy<T>) -> Self::Output {
+		Mass{kg: self.per_Gy * rhs.J}
+	}
+}
+/// Multiplying a InverseAbsorbedDose by a Energy returns a value of type Mass
+impl<T> core::ops::Mul<Energy<T>> for &InverseAbsorbedDose<T> where T: NumLike {
+	type Output = Mass<T>;
+	fn mul(self, rhs: Energy<T>) -> Self::Output {
+		Mass{kg: self.per_Gy.clone() * rhs.J}
+	}
+}
+/// Multiplying a InverseAbsorbedDose by a Energy returns a value of type Mass
+impl<T> core::ops::Mul<&Energy<T>> for InverseAbsorbedDose<T> where T: NumLike {
+	type Output = Mass<T>;
+	fn mul(self, rhs: &Energy<T>) -> Self::Output {
+		Mass{kg: self.per_Gy * rhs.J.clone()}
+	}
+}
+/// Multiplying a InverseAbsorbedDose by a Energy returns a value of type Mass
+impl<T> core::ops::Mul<&Energy<T>> for &InverseAbsorbedDose<T> where T: NumLike {
+	type Output = Mass<T>;
+	fn mul(self, rhs: &Energy<T>) -> Self::Output {
+		Mass{kg: self.per_Gy.clone() * rhs.J.clone()}
+	}
+}
+
+// InverseAbsorbedDose * Torque -> Mass
+/// Multiplying a InverseAbsorbedDose by a Torque returns a value of type Mass
+impl<T> core::ops::Mul<Torque<T>> for InverseAbsorbedDose<T> where T: NumLike {
+	type Output = Mass<T>;
+	fn mul(self, rhs: Torque<T>) -> Self::Output {
+		Mass{kg: self.per_Gy * rhs.Nm}
+	}
+}
+/// Multiplying a InverseAbsorbedDose by a Torque returns a value of type Mass
+impl<T> core::ops::Mul<Torque<T>> for &InverseAbsorbedDose<T> where T: NumLike {
+	type Output = Mass<T>;
+	fn mul(self, rhs: Torque<T>) -> Self::Output {
+		Mass{kg: self.per_Gy.clone() * rhs.Nm}
+	}
+}
+/// Multiplying a InverseAbsorbedDose by a Torque returns a value of type Mass
+impl<T> core::ops::Mul<&Torque<T>> for InverseAbsorbedDose<T> where T: NumLike {
+	type Output = Mass<T>;
+	fn mul(self, rhs: &Torque<T>) -> Self::Output {
+		Mass{kg: self.per_Gy * rhs.Nm.clone()}
+	}
+}
+/// Multiplying a InverseAbsorbedDose by a Torque returns a value of type Mass
+impl<T> core::ops::Mul<&Torque<T>> for &InverseAbsorbedDose<T> where T: NumLike {
+	type Output = Mass<T>;
+	fn mul(self, rhs: &Torque<T>) -> Self::Output {
+		Mass{kg: self.per_Gy.clone() * rhs.Nm.clone()}
+	}
+}
+
+// InverseAbsorbedDose * Frequency -> InverseKinematicViscosity
+/// Multiplying a InverseAbsorbedDose by a Frequency returns a value of type InverseKinematicViscosity
+impl<T> core::ops::Mul<Frequency<T>> for InverseAbsorbedDose<T> where T: NumLike {
+	type Output = InverseKinematicViscosity<T>;
+	fn mul(self, rhs: Frequency<T>) -> Self::Output {
+		InverseKinematicViscosity{s_per_m2: self.per_Gy * rhs.Hz}
+	}
+}
+/// Multiplying a InverseAbsorbedDose by a Frequency returns a value of type InverseKinematicViscosity
+impl<T> core::ops::Mul<Frequency<T>> for &InverseAbsorbedDose<T> where T: NumLike {
+	type Output = InverseKinematicViscosity<T>;
+	fn mul(self, rhs: Frequency<T>) -> Self::Output {
+		InverseKinematicViscosity{s_per_m2: self.per_Gy.clone() * rhs.Hz}
+	}
+}
+/// Multiplying a InverseAbsorbedDose by a Frequency returns a value of type InverseKinematicViscosity
+impl<T> core::ops::Mul<&Frequency<T>> for InverseAbsorbedDose<T> where T: NumLike {
+	type Output = InverseKinematicViscosity<T>;
+	fn mul(self, rhs: &Frequency<T>) -> Self::Output {
+		InverseKinematicViscosity{s_per_m2: self.per_Gy * rhs.Hz.clone()}
+	}
+}
+/// Multiplying a InverseAbsorbedDose by a Frequency returns a value of type InverseKinematicViscosity
+impl<T> core::ops::Mul<&Frequency<T>> for &InverseAbsorbedDose<T> where T: NumLike {
+	type Output = InverseKinematicViscosity<T>;
+	fn mul(self, rhs: &Frequency<T>) -> Self::Output {
+		InverseKinematicViscosity{s_per_m2: self.per_Gy.clone() * rhs.Hz.clone()}
+	}
+}
+
+// InverseAbsorbedDose / InverseAcceleration -> InverseDistance
+/// Dividing a InverseAbsorbedDose by a InverseAcceleration returns a value of type InverseDistance
+impl<T> core::ops::Div<InverseAcceleration<T>> for InverseAbsorbedDose<T> where T: NumLike {
+	type Output = InverseDistance<T>;
+	fn div(self, rhs: InverseAcceleration<T>) -> Self::Output {
+		InverseDistance{per_m: self.per_Gy / rhs.s2pm}
+	}
+}
+/// Dividing a InverseAbsorbedDose by a InverseAcceleration returns a value of type InverseDistance
+impl<T> core::ops::Div<InverseAcceleration<T>> for &InverseAbsorbedDose<T> where T: NumLike {
+	type Output = InverseDistance<T>;
+	fn div(self, rhs: InverseAcceleration<T>) -> Self::Output {
+		InverseDistance{per_m: self.per_Gy.clone() / rhs.s2pm}
+	}
+}
+/// Dividing a InverseAbsorbedDose by a InverseAcceleration returns a value of type InverseDistance
+impl<T> core::ops::Div<&InverseAcceleration<T>> for InverseAbsorbedDose<T> where T: NumLike {
+	type Output = InverseDistance<T>;
+	fn div(self, rhs: &InverseAcceleration<T>) -> Self::Output {
+		InverseDistance{per_m: self.per_Gy / rhs.s2pm.clone()}
+	}
+}
+/// Dividing a InverseAbsorbedDose by a InverseAcceleration returns a value of type InverseDistance
+impl<T> core::ops::Div<&InverseAcceleration<T>> for &InverseAbsorbedDose<T> where T: NumLike {
+	type Output = InverseDistance<T>;
+	fn div(self, rhs: &InverseAcceleration<T>) -> Self::Output {
+		InverseDistance{per_m: self.per_Gy.clone() / rhs.s2pm.clone()}
+	}
+}
+
+// InverseAbsorbedDose / InverseEnergy -> Mass
+/// Dividing a InverseAbsorbedDose by a InverseEnergy returns a value of type Mass
+impl<T> core::ops::Div<InverseEnergy<T>> for InverseAbsorbedDose<T> where T: NumLike {
+	type Output = Mass<T>;
+	fn div(self, rhs: InverseEnergy<T>) -> Self::Output {
+		Mass{kg: self.per_Gy / rhs.per_J}
+	}
+}
+/// Dividing a InverseAbsorbedDose by a InverseEnergy returns a value of type Mass
+impl<T> core::ops::Div<InverseEnergy<T>> for &InverseAbsorbedDose<T> where T: NumLike {
+	type Output = Mass<T>;
+	fn div(self, rhs: InverseEnergy<T>) -> Self::Output {
+		Mass{kg: self.per_Gy.clone() / rhs.per_J}
+	}
+}
+/// Dividing a InverseAbsorbedDose by a InverseEnergy returns a value of type Mass
+impl<T> core::ops::Div<&InverseEnergy<T>> for InverseAbsorbedDose<T> where T: NumLike {
+	type Output = Mass<T>;
+	fn div(self, rhs: &InverseEnergy<T>) -> Self::Output {
+		Mass{kg: self.per_Gy / rhs.per_J.clone()}
+	}
+}
+/// Dividing a InverseAbsorbedDose by a InverseEnergy returns a value of type Mass
+impl<T> core::ops::Div<&InverseEnergy<T>> for &InverseAbsorbedDose<T> where T: NumLike {
+	type Output = Mass<T>;
+	fn div(self, rhs: &InverseEnergy<T>) -> Self::Output {
+		Mass{kg: self.per_Gy.clone() / rhs.per_J.clone()}
+	}
+}
+
+// InverseAbsorbedDose / InverseTorque -> Mass
+/// Dividing a InverseAbsorbedDose by a InverseTorque returns a value of type Mass
+impl<T> core::ops::Div<InverseTorque<T>> for InverseAbsorbedDose<T> where T: NumLike {
+	type Output = Mass<T>;
+	fn div(self, rhs: InverseTorque<T>) -> Self::Output {
+		Mass{kg: self.per_Gy / rhs.per_Nm}
+	}
+}
+/// Dividing a InverseAbsorbedDose by a InverseTorque returns a value of type Mass
+impl<T> core::ops::Div<InverseTorque<T>> for &InverseAbsorbedDose<T> where T: NumLike {
+	type Output = Mass<T>;
+	fn div(self, rhs: InverseTorque<T>) -> Self::Output {
+		Mass{kg: self.per_Gy.clone() / rhs.per_Nm}
+	}
+}
+/// Dividing a InverseAbsorbedDose by a InverseTorque returns a value of type Mass
+impl<T> core::ops::Div<&InverseTorque<T>> for InverseAbsorbedDose<T> where T: NumLike {
+	type Output = Mass<T>;
+	fn div(self, rhs: &InverseTorque<T>) -> Self::Output {
+		Mass{kg: self.per_Gy / rhs.per_Nm.clone()}
+	}
+}
+/// Dividing a InverseAbsorbedDose by a InverseTorque returns a value of type Mass
+impl<T> core::ops::Div<&InverseTorque<T>> for &InverseAbsorbedDose<T> where T: NumLike {
+	type Output = Mass<T>;
+	fn div(self, rhs: &InverseTorque<T>) -> Self::Output {
+		Mass{kg: self.per_Gy.clone() / rhs.per_Nm.clone()}
+	}
+}
+
+// InverseAbsorbedDose / InverseKinematicViscosity -> Time
+/// Dividing a InverseAbsorbedDose by a InverseKinematicViscosity returns a value of type Time
+impl<T> core::ops::Div<InverseKinematicViscosity<T>> for InverseAbsorbedDose<T> where T: NumLike {
+	type Output = Time<T>;
+	fn div(self, rhs: InverseKinematicViscosity<T>) -> Self::Output {
+		Time{s: self.per_Gy / rhs.s_per_m2}
+	}
+}
+/// Dividing a InverseAbsorbedDose by a InverseKinematicViscosity returns a value of type Time
+impl<T> core::ops::Div<InverseKinematicViscosity<T>> for &InverseAbsorbedDose<T> where T: NumLike {
+	type Output = Time<T>;
+	fn div(self, rhs: InverseKinematicViscosity<T>) -> Self::Output {
+		Time{s: self.per_Gy.clone() / rhs.s_per_m2}
+	}
+}
+/// Dividing a InverseAbsorbedDose by a InverseKinematicViscosity returns a value of type Time
+impl<T> core::ops::Div<&InverseKinematicViscosity<T>> for InverseAbsorbedDose<T> where T: NumLike {
+	type Output = Time<T>;
+	fn div(self, rhs: &InverseKinematicViscosity<T>) -> Self::Output {
+		Time{s: self.per_Gy / rhs.s_per_m2.clone()}
+	}
+}
+/// Dividing a InverseAbsorbedDose by a InverseKinematicViscosity returns a value of type Time
+impl<T> core::ops::Div<&InverseKinematicViscosity<T>> for &InverseAbsorbedDose<T> where T: NumLike {
+	type Output = Time<T>;
+	fn div(self, rhs: &InverseKinematicViscosity<T>) -> Self::Output {
+		Time{s: self.per_Gy.clone() / rhs.s_per_m2.clone()}
+	}
+}
+
+// InverseAbsorbedDose * InverseMassFlowRate -> InversePower
+/// Multiplying a InverseAbsorbedDose by a InverseMassFlowRate returns a value of type InversePower
+impl<T> core::ops::Mul<InverseMassFlowRate<T>> for InverseAbsorbedDose<T> where T: NumLike {
+	type Output = InversePower<T>;
+	fn mul(self, rhs: InverseMassFlowRate<T>) -> Self::Output {
+		InversePower{per_W: self.per_Gy * rhs.s_per_kg}
+	}
+}
+/// Multiplying a InverseAbsorbedDose by a InverseMassFlowRate returns a value of type InversePower
+impl<T> core::ops::Mul<InverseMassFlowRate<T>> for &InverseAbsorbedDose<T> where T: NumLike {
+	type Output = InversePower<T>;
+	fn mul(self, rhs: InverseMassFlowRate<T>) -> Self::Output {
+		InversePower{per_W: self.per_Gy.clone() * rhs.s_per_kg}
+	}
+}
+/// Multiplying a InverseAbsorbedDose by a InverseMassFlowRate returns a value of type InversePower
+impl<T> core::ops::Mul<&InverseMassFlowRate<T>> for InverseAbsorbedDose<T> where T: NumLike {
+	type Output = InversePower<T>;
+	fn mul(self, rhs: &InverseMassFlowRate<T>) -> Self::Output {
+		InversePower{per_W: self.per_Gy * rhs.s_per_kg.clone()}
+	}
+}
+/// Multiplying a InverseAbsorbedDose by a InverseMassFlowRate returns a value of type InversePower
+impl<T> core::ops::Mul<&InverseMassFlowRate<T>> for &InverseAbsorbedDose<T> where T: NumLike {
+	type Output = InversePower<T>;
+	fn mul(self, rhs: &InverseMassFlowRate<T>) -> Self::Output {
+		InversePower{per_W: self.per_Gy.clone() * rhs.s_per_kg.clone()}
+	}
+}
+
+// InverseAbsorbedDose / InversePower -> MassFlowRate
+/// Dividing a InverseAbsorbedDose by a InversePower returns a value of type MassFlowRate
+impl<T> core::ops::Div<InversePower<T>> for InverseAbsorbedDose<T> where T: NumLike {
+	type Output = MassFlowRate<T>;
+	fn div(self, rhs: InversePower<T>) -> Self::Output {
+		MassFlowRate{kgps: self.per_Gy / rhs.per_W}
+	}
+}
+/// Dividing a InverseAbsorbedDose by a InversePower returns a value of type MassFlowRate
+impl<T> core::ops::Div<InversePower<T>> for &InverseAbsorbedDose<T> where T: NumLike {
+	type Output = MassFlowRate<T>;
+	fn div(self, rhs: InversePower<T>) -> Self::Output {
+		MassFlowRate{kgps: self.per_Gy.clone() / rhs.per_W}
+	}
+}
+/// Dividing a InverseAbsorbedDose by a InversePower returns a value of type MassFlowRate
+impl<T> core::ops::Div<&InversePower<T>> for InverseAbsorbedDose<T> where T: NumLike {
+	type Output = MassFlowRate<T>;
+	fn div(self, rhs: &InversePower<T>) -> Self::Output {
+		MassFlowRate{kgps: self.per_Gy / rhs.per_W.clone()}
+	}
+}
+/// Dividing a InverseAbsorbedDose by a InversePower returns a value of type MassFlowRate
+impl<T> core::ops::Div<&InversePower<T>> for &InverseAbsorbedDose<T> where T: NumLike {
+	type Output = MassFlowRate<T>;
+	fn div(self, rhs: &InversePower<T>) -> Self::Output {
+		MassFlowRate{kgps: self.per_Gy.clone() / rhs.per_W.clone()}
+	}
+}
+
+// InverseAbsorbedDose / InversePressure -> Density
+/// Dividing a InverseAbsorbedDose by a InversePressure returns a value of type Density
+impl<T> core::ops::Div<InversePressure<T>> for InverseAbsorbedDose<T> where T: NumLike {
+	type Output = Density<T>;
+	fn div(self, rhs: InversePressure<T>) -> Self::Output {
+		Density{kgpm3: self.per_Gy / rhs.per_Pa}
+	}
+}
+/// Dividing a InverseAbsorbedDose by a InversePressure returns a value of type Density
+impl<T> core::ops::Div<InversePressure<T>> for &InverseAbsorbedDose<T> where T: NumLike {
+	type Output = Density<T>;
+	fn div(self, rhs: InversePressure<T>) -> Self::Output {
+		Density{kgpm3: self.per_Gy.clone() / rhs.per_Pa}
+	}
+}
+/// Dividing a InverseAbsorbedDose by a InversePressure returns a value of type Density
+impl<T> core::ops::Div<&InversePressure<T>> for InverseAbsorbedDose<T> where T: NumLike {
+	type Output = Density<T>;
+	fn div(self, rhs: &InversePressure<T>) -> Self::Output {
+		Density{kgpm3: self.per_Gy / rhs.per_Pa.clone()}
+	}
+}
+/// Dividing a InverseAbsorbedDose by a InversePressure returns a value of type Density
+impl<T> core::ops::Div<&InversePressure<T>> for &InverseAbsorbedDose<T> where T: NumLike {
+	type Output = Density<T>;
+	fn div(self, rhs: &InversePressure<T>) -> Self::Output {
+		Density{kgpm3: self.per_Gy.clone() / rhs.per_Pa.clone()}
+	}
+}
+
+// InverseAbsorbedDose * KinematicViscosity -> Time
+/// Multiplying a InverseAbsorbedDose by a KinematicViscosity returns a value of type Time
+impl<T> core::ops::Mul<KinematicViscosity<T>> for InverseAbsorbedDose<T> where T: NumLike {
+	type Output = Time<T>;
+	fn mul(self, rhs: KinematicViscosity<T>) -> Self::Output {
+		Time{s: self.per_Gy * rhs.m2ps}
+	}
+}
+/// Multiplying a InverseAbsorbedDose by a KinematicViscosity returns a value of type Time
+impl<T> core::ops::Mul<KinematicViscosity<T>> for &InverseAbsorbedDose<T> where T: NumLike {
+	type Output = Time<T>;
+	fn mul(self, rhs: KinematicViscosity<T>) -> Self::Output {
+		Time{s: self.per_Gy.clone() * rhs.m2ps}
+	}
+}
+/// Multiplying a InverseAbsorbedDose by a KinematicViscosity returns a value of type Time
+impl<T> core::ops::Mul<&KinematicViscosity<T>> for InverseAbsorbedDose<T> where T: NumLike {
+	type Output = Time<T>;
+	fn mul(self, rhs: &KinematicViscosity<T>) -> Self::Output {
+		Time{s: self.per_Gy * rhs.m2ps.clone()}
+	}
+}
+/// Multiplying a InverseAbsorbedDose by a KinematicViscosity returns a value of type Time
+impl<T> core::ops::Mul<&KinematicViscosity<T>> for &InverseAbsorbedDose<T> where T: NumLike {
+	type Output = Time<T>;
+	fn mul(self, rhs: &KinematicViscosity<T>) -> Self::Output {
+		Time{s: self.per_Gy.clone() * rhs.m2ps.clone()}
+	}
+}
+
+// InverseAbsorbedDose / MassFlowRate -> InversePower
+/// Dividing a InverseAbsorbedDose by a MassFlowRate returns a value of type InversePower
+impl<T> core::ops::Div<MassFlowRate<T>> for InverseAbsorbedDose<T> where T: NumLike {
+	type Output = InversePower<T>;
+	fn div(self, rhs: MassFlowRate<T>) -> Self::Output {
+		InversePower{per_W: self.per_Gy / rhs.kgps}
+	}
+}
+/// Dividing a InverseAbsorbedDose by a MassFlowRate returns a value of type InversePower
+impl<T> core::ops::Div<MassFlowRate<T>> for &InverseAbsorbedDose<T> where T: NumLike {
+	type Output = InversePower<T>;
+	fn div(self, rhs: MassFlowRate<T>) -> Self::Output {
+		InversePower{per_W: self.per_Gy.clone() / rhs.kgps}
+	}
+}
+/// Dividing a InverseAbsorbedDose by a MassFlowRate returns a value of type InversePower
+impl<T> core::ops::Div<&MassFlowRate<T>> for InverseAbsorbedDose<T> where T: NumLike {
+	type Output = InversePower<T>;
+	fn div(self, rhs: &MassFlowRate<T>) -> Self::Output {
+		InversePower{per_W: self.per_Gy / rhs.kgps.clone()}
+	}
+}
+/// Dividing a InverseAbsorbedDose by a MassFlowRate returns a value of type InversePower
+impl<T> core::ops::Div<&MassFlowRate<T>> for &InverseAbsorbedDose<T> where T: NumLike {
+	type Output = InversePower<T>;
+	fn div(self, rhs: &MassFlowRate<T>) -> Self::Output {
+		InversePower{per_W: self.per_Gy.clone() / rhs.kgps.clone()}
+	}
+}
+
+// InverseAbsorbedDose * Power -> MassFlowRate
+/// Multiplying a InverseAbsorbedDose by a Power returns a value of type MassFlowRate
+impl<T> core::ops::Mul<Power<T>> for InverseAbsorbedDose<T> where T: NumLike {
+	type Output = MassFlowRate<T>;
+	fn mul(self, rhs: Power<T>) -> Self::Output {
+		MassFlowRate{kgps: self.per_Gy * rhs.W}
+	}
+}
+/// Multiplying a InverseAbsorbedDose by a Power returns a value of type MassFlowRate
+impl<T> core::ops::Mul<Power<T>> for &InverseAbsorbedDose<T> where T: NumLike {
+	type Output = MassFlowRate<T>;
+	fn mul(self, rhs: Power<T>) -> Self::Output {
+		MassFlowRate{kgps: self.per_Gy.clone() * rhs.W}
+	}
+}
+/// Multiplying a InverseAbsorbedDose by a Power returns a value of type MassFlowRate
+impl<T> core::ops::Mul<&Power<T>> for InverseAbsorbedDose<T> where T: NumLike {
+	type Output = MassFlowRate<T>;
+	fn mul(self, rhs: &Power<T>) -> Self::Output {
+		MassFlowRate{kgps: self.per_Gy * rhs.W.clone()}
+	}
+}
+/// Multiplying a InverseAbsorbedDose by a Power returns a value of type MassFlowRate
+impl<T> core::ops::Mul<&Power<T>> for &InverseAbsorbedDose<T> where T: NumLike {
+	type Output = MassFlowRate<T>;
+	fn mul(self, rhs: &Power<T>) -> Self::Output {
+		MassFlowRate{kgps: self.per_Gy.clone() * rhs.W.clone()}
+	}
+}
+
+// InverseAbsorbedDose * Pressure -> Density
+/// Multiplying a InverseAbsorbedDose by a Pressure returns a value of type Density
+impl<T> core::ops::Mul<Pressure<T>> for InverseAbsorbedDose<T> where T: NumLike {
+	type Output = Density<T>;
+	fn mul(self, rhs: Pressure<T>) -> Self::Output {
+		Density{kgpm3: self.per_Gy * rhs.Pa}
+	}
+}
+/// Multiplying a InverseAbsorbedDose by a Pressure returns a value of type Density
+impl<T> core::ops::Mul<Pressure<T>> for &InverseAbsorbedDose<T> where T: NumLike {
+	type Output = Density<T>;
+	fn mul(self, rhs: Pressure<T>) -> Self::Output {
+		Density{kgpm3: self.per_Gy.clone() * rhs.Pa}
+	}
+}
+/// Multiplying a InverseAbsorbedDose by a Pressure returns a value of type Density
+impl<T> core::ops::Mul<&Pressure<T>> for InverseAbsorbedDose<T> where T: NumLike {
+	type Output = Density<T>;
+	fn mul(self, rhs: &Pressure<T>) -> Self::Output {
+		Density{kgpm3: self.per_Gy * rhs.Pa.clone()}
+	}
+}
+/// Multiplying a InverseAbsorbedDose by a Pressure returns a value of type Density
+impl<T> core::ops::Mul<&Pressure<T>> for &InverseAbsorbedDose<T> where T: NumLike {
+	type Output = Density<T>;
+	fn mul(self, rhs: &Pressure<T>) -> Self::Output {
+		Density{kgpm3: self.per_Gy.clone() * rhs.Pa.clone()}
+	}
+}
+
+// InverseAbsorbedDose / TimePerDistance -> TimePerDistance
+/// Dividing a InverseAbsorbedDose by a TimePerDistance returns a value of type TimePerDistance
+impl<T> core::ops::Div<TimePerDistance<T>> for InverseAbsorbedDose<T> where T: NumLike {
+	type Output = TimePerDistance<T>;
+	fn div(self, rhs: TimePerDistance<T>) -> Self::Output {
+		TimePerDistance{spm: self.per_Gy / rhs.spm}
+	}
+}
+/// Dividing a InverseAbsorbedDose by a TimePerDistance returns a value of type TimePerDistance
+impl<T> core::ops::Div<TimePerDistance<T>> for &InverseAbsorbedDose<T> where T: NumLike {
+	type Output = TimePerDistance<T>;
+	fn div(self, rhs: TimePerDistance<T>) -> Self::Output {
+		TimePerDistance{spm: self.per_Gy.clone() / rhs.spm}
+	}
+}
+/// Dividing a InverseAbsorbedDose by a TimePerDistance returns a value of type TimePerDistance
+impl<T> core::ops::Div<&TimePerDistance<T>> for InverseAbsorbedDose<T> where T: NumLike {
+	type Output = TimePerDistance<T>;
+	fn div(self, rhs: &TimePerDistance<T>) -> Self::Output {
+		TimePerDistance{spm: self.per_Gy / rhs.spm.clone()}
+	}
+}
+/// Dividing a InverseAbsorbedDose by a TimePerDistance returns a value of type TimePerDistance
+impl<T> core::ops::Div<&TimePerDistance<T>> for &InverseAbsorbedDose<T> where T: NumLike {
+	type Output = TimePerDistance<T>;
+	fn div(self, rhs: &TimePerDistance<T>) -> Self::Output {
+		TimePerDistance{spm: self.per_Gy.clone() / rhs.spm.clone()}
+	}
+}
+
+// InverseAbsorbedDose * Velocity -> TimePerDistance
+/// Multiplying a InverseAbsorbedDose by a Velocity returns a value of type TimePerDistance
+impl<T> core::ops::Mul<Velocity<T>> for InverseAbsorbedDose<T> where T: NumLike {
+	type Output = TimePerDistance<T>;
+	fn mul(self, rhs: Velocity<T>) -> Self::Output {
+		TimePerDistance{spm: self.per_Gy * rhs.mps}
+	}
+}
+/// Multiplying a InverseAbsorbedDose by a Velocity returns a value of type TimePerDistance
+impl<T> core::ops::Mul<Velocity<T>> for &InverseAbsorbedDose<T> where T: NumLike {
+	type Output = TimePerDistance<T>;
+	fn mul(self, rhs: Velocity<T>) -> Self::Output {
+		TimePerDistance{spm: self.per_Gy.clone() * rhs.mps}
+	}
+}
+/// Multiplying a InverseAbsorbedDose by a Velocity returns a value of type TimePerDistance
+impl<T> core::ops::Mul<&Velocity<T>> for InverseAbsorbedDose<T> where T: NumLike {
+	type Output = TimePerDistance<T>;
+	fn mul(self, rhs: &Velocity<T>) -> Self::Output {
+		TimePerDistance{spm: self.per_Gy * rhs.mps.clone()}
+	}
+}
+/// Multiplying a InverseAbsorbedDose by a Velocity returns a value of type TimePerDistance
+impl<T> core::ops::Mul<&Velocity<T>> for &InverseAbsorbedDose<T> where T: NumLike {
+	type Output = TimePerDistance<T>;
+	fn mul(self, rhs: &Velocity<T>) -> Self::Output {
+		TimePerDistance{spm: self.per_Gy.clone() * rhs.mps.clone()}
+	}
+}
+
+// InverseAbsorbedDose * VolumePerMass -> InversePressure
+/// Multiplying a InverseAbsorbedDose by a VolumePerMass returns a value of type InversePressure
+impl<T> core::ops::Mul<VolumePerMass<T>> for InverseAbsorbedDose<T> where T: NumLike {
+	type Output = InversePressure<T>;
+	fn mul(self, rhs: VolumePerMass<T>) -> Self::Output {
+		InversePressure{per_Pa: self.per_Gy * rhs.m3_per_kg}
+	}
+}
+/// Multiplying a InverseAbsorbedDose by a VolumePerMass returns a value of type InversePressure
+impl<T> core::ops::Mul<VolumePerMass<T>> for &InverseAbsorbedDose<T> where T: NumLike {
+	type Output = InversePressure<T>;
+	fn mul(self, rhs: VolumePerMass<T>) -> Self::Output {
+		InversePressure{per_Pa: self.per_Gy.clone() * rhs.m3_per_kg}
+	}
+}
+/// Multiplying a InverseAbsorbedDose by a VolumePerMass returns a value of type InversePressure
+impl<T> core::ops::Mul<&VolumePerMass<T>> for InverseAbsorbedDose<T> where T: NumLike {
+	type Output = InversePressure<T>;
+	fn mul(self, rhs: &VolumePerMass<T>) -> Self::Output {
+		InversePressure{per_Pa: self.per_Gy * rhs.m3_per_kg.clone()}
+	}
+}
+/// Multiplying a InverseAbsorbedDose by a VolumePerMass returns a value of type InversePressure
+impl<T> core::ops::Mul<&VolumePerMass<T>> for &InverseAbsorbedDose<T> where T: NumLike {
+	type Output = InversePressure<T>;
+	fn mul(self, rhs: &VolumePerMass<T>) -> Self::Output {
+		InversePressure{per_Pa: self.per_Gy.clone() * rhs.m3_per_kg.clone()}
+	}
+}
+
+impl<T> InverseAbsorbedDose<T> where T: NumLike+From<u8> {
+	/// Returns the reciprocal of this InverseAbsorbedDose value (ie 1 divided by 
+	/// this value) as a value of type AbsorbedDose
+	pub fn recip(self) -> AbsorbedDose<T> {
+		AbsorbedDose{Gy: T::from(1u8) / self.per_Gy}
+	}
+}
+
+// 1/InverseAbsorbedDose -> AbsorbedDose
+/// Dividing a scalar value by a InverseAbsorbedDose unit value returns a value of type AbsorbedDose
+impl<T> core::ops::Div<InverseAbsorbedDose<T>> for f64 where T: NumLike+From<f64> {
+	type Output = AbsorbedDose<T>;
+	fn div(self, rhs: InverseAbsorbedDose<T>) -> Self::Output {
+		AbsorbedDose{Gy: T::from(self) / rhs.per_Gy}
+	}
+}
+/// Dividing a scalar value by a InverseAbsorbedDose unit value returns a value of type AbsorbedDose
+impl<T> core::ops::Div<InverseAbsorbedDose<T>> for &f64 where T: NumLike+From<f64> {
+	type Output = AbsorbedDose<T>;
+	fn div(self, rhs: InverseAbsorbedDose<T>) -> Self::Output {
+		AbsorbedDose{Gy: T::from(self.clone()) / rhs.per_Gy}
+	}
+}
+/// Dividing a scalar value by a InverseAbsorbedDose unit value returns a value of type AbsorbedDose
+impl<T> core::ops::Div<&InverseAbsorbedDose<T>> for f64 where T: NumLike+From<f64> {
+	type Output = AbsorbedDose<T>;
+	fn div(self, rhs: &InverseAbsorbedDose<T>) -> Self::Output {
+		AbsorbedDose{Gy: T::from(self) / rhs.per_Gy.clone()}
+	}
+}
+/// Dividing a scalar value by a InverseAbsorbedDose unit value returns a value of type AbsorbedDose
+impl<T> core::ops::Div<&InverseAbsorbedDose<T>> for &f64 where T: NumLike+From<f64> {
+	type Output = AbsorbedDose<T>;
+	fn div(self, rhs: &InverseAbsorbedDose<T>) -> Self::Output {
+		AbsorbedDose{Gy: T::from(self.clone()) / rhs.per_Gy.clone()}
+	}
+}
+
+// 1/InverseAbsorbedDose -> AbsorbedDose
+/// Dividing a scalar value by a InverseAbsorbedDose unit value returns a value of type AbsorbedDose
+impl<T> core::ops::Div<InverseAbsorbedDose<T>> for f32 where T: NumLike+From<f32> {
+	type Output = AbsorbedDose<T>;
+	fn div(self, rhs: InverseAbsorbedDose<T>) -> Self::Output {
+		AbsorbedDose{Gy: T::from(self) / rhs.per_Gy}
+	}
+}
+/// Dividing a scalar value by a InverseAbsorbedDose unit value returns a value of type AbsorbedDose
+impl<T> core::ops::Div<InverseAbsorbedDose<T>> for &f32 where T: NumLike+From<f32> {
+	type Output = AbsorbedDose<T>;
+	fn div(self, rhs: InverseAbsorbedDose<T>) -> Self::Output {
+		AbsorbedDose{Gy: T::from(self.clone()) / rhs.per_Gy}
+	}
+}
+/// Dividing a scalar value by a InverseAbsorbedDose unit value returns a value of type AbsorbedDose
+impl<T> core::ops::Div<&InverseAbsorbedDose<T>> for f32 where T: NumLike+From<f32> {
+	type Output = AbsorbedDose<T>;
+	fn div(self, rhs: &InverseAbsorbedDose<T>) -> Self::Output {
+		AbsorbedDose{Gy: T::from(self) / rhs.per_Gy.clone()}
+	}
+}
+/// Dividing a scalar value by a InverseAbsorbedDose unit value returns a value of type AbsorbedDose
+impl<T> core::ops::Div<&InverseAbsorbedDose<T>> for &f32 where T: NumLike+From<f32> {
+	type Output = AbsorbedDose<T>;
+	fn div(self, rhs: &InverseAbsorbedDose<T>) -> Self::Output {
+		AbsorbedDose{Gy: T::from(self.clone()) / rhs.per_Gy.clone()}
+	}
+}
+
+// 1/InverseAbsorbedDose -> AbsorbedDose
+/// Dividing a scalar value by a InverseAbsorbedDose unit value returns a value of type AbsorbedDose
+impl<T> core::ops::Div<InverseAbsorbedDose<T>> for i64 where T: NumLike+From<i64> {
+	type Output = AbsorbedDose<T>;
+	fn div(self, rhs: InverseAbsorbedDose<T>) -> Self::Output {
+		AbsorbedDose{Gy: T::from(self) / rhs.per_Gy}
+	}
+}
+/// Dividing a scalar value by a InverseAbsorbedDose unit value returns a value of type AbsorbedDose
+impl<T> core::ops::Div<InverseAbsorbedDose<T>> for &i64 where T: NumLike+From<i64> {
+	type Output = AbsorbedDose<T>;
+	fn div(self, rhs: InverseAbsorbedDose<T>) -> Self::Output {
+		AbsorbedDose{Gy: T::from(self.clone()) / rhs.per_Gy}
+	}
+}
+/// Dividing a scalar value by a InverseAbsorbedDose unit value returns a value of type AbsorbedDose
+impl<T> core::ops::Div<&InverseAbsorbedDose<T>> for i64 where T: NumLike+From<i64> {
+	type Output = AbsorbedDose<T>;
+	fn div(self, rhs: &InverseAbsorbedDose<T>) -> Self::Output {
+		AbsorbedDose{Gy: T::from(self) / rhs.per_Gy.clone()}
+	}
+}
+/// Dividing a scalar value by a InverseAbsorbedDose unit value returns a value of type AbsorbedDose
+impl<T> core::ops::Div<&InverseAbsorbedDose<T>> for &i64 where T: NumLike+From<i64> {
+	type Output = AbsorbedDose<T>;
+	fn div(self, rhs: &InverseAbsorbedDose<T>) -> Self::Output {
+		AbsorbedDose{Gy: T::from(self.clone()) / rhs.per_Gy.clone()}
+	}
+}
+
+// 1/InverseAbsorbedDose -> AbsorbedDose
+/// Dividing a scalar value by a InverseAbsorbedDose unit value returns a value of type AbsorbedDose
+impl<T> core::ops::Div<InverseAbsorbedDose<T>> for i32 where T: NumLike+From<i32> {
+	type Output = AbsorbedDose<T>;
+	fn div(self, rhs: InverseAbsorbedDose<T>) -> Self::Output {
+		AbsorbedDose{Gy: T::from(self) / rhs.per_Gy}
+	}
+}
+/// Dividing a scalar value by a InverseAbsorbedDose unit value returns a value of type AbsorbedDose
+impl<T> core::ops::Div<InverseAbsorbedDose<T>> for &i32 where T: NumLike+From<i32> {
+	type Output = AbsorbedDose<T>;
+	fn div(self, rhs: InverseAbsorbedDose<T>) -> Self::Output {
+		AbsorbedDose{Gy: T::from(self.clone()) / rhs.per_Gy}
+	}
+}
+/// Dividing a scalar value by a InverseAbsorbedDose unit value returns a value of type AbsorbedDose
+impl<T> core::ops::Div<&InverseAbsorbedDose<T>> for i32 where T: NumLike+From<i32> {
+	type Output = AbsorbedDose<T>;
+	fn div(self, rhs: &InverseAbsorbedDose<T>) -> Self::Output {
+		AbsorbedDose{Gy: T::from(self) / rhs.per_Gy.clone()}
+	}
+}
+/// Dividing a scalar value by a InverseAbsorbedDose unit value returns a value of type AbsorbedDose
+impl<T> core::ops::Div<&InverseAbsorbedDose<T>> for &i32 where T: NumLike+From<i32> {
+	type Output = AbsorbedDose<T>;
+	fn div(self, rhs: &InverseAbsorbedDose<T>) -> Self::Output {
+		AbsorbedDose{Gy: T::from(self.clone()) / rhs.per_Gy.clone()}
+	}
+}
+
+// 1/InverseAbsorbedDose -> AbsorbedDose
+/// Dividing a scalar value by a InverseAbsorbedDose unit value returns a value of type AbsorbedDose
+#[cfg(feature="num-bigfloat")]
+impl<T> core::ops::Div<InverseAbsorbedDose<T>> for num_bigfloat::BigFloat where T: NumLike+From<num_bigfloat::BigFloat> {
+	type Output = AbsorbedDose<T>;
+	fn div(self, rhs: InverseAbsorbedDose<T>) -> Self::Output {
+		AbsorbedDose{Gy: T::from(self) / rhs.per_Gy}
+	}
+}
+/// Dividing a scalar value by a InverseAbsorbedDose unit value returns a value of type AbsorbedDose
+#[cfg(feature="num-bigfloat")]
+impl<T> core::ops::Div<InverseAbsorbedDose<T>> for &num_bigfloat::BigFloat where T: NumLike+From<num_bigfloat::BigFloat> {
+	type Output = AbsorbedDose<T>;
+	fn div(self, rhs: InverseAbsorbedDose<T>) -> Self::Output {
+		AbsorbedDose{Gy: T::from(self.clone()) / rhs.per_Gy}
+	}
+}
+/// Dividing a scalar value by a InverseAbsorbedDose unit value returns a value of type AbsorbedDose
+#[cfg(feature="num-bigfloat")]
+impl<T> core::ops::Div<&InverseAbsorbedDose<T>> for num_bigfloat::BigFloat where T: NumLike+From<num_bigfloat::BigFloat> {
+	type Output = AbsorbedDose<T>;
+	fn div(self, rhs: &InverseAbsorbedDose<T>) -> Self::Output {
+		AbsorbedDose{Gy: T::from(self) / rhs.per_Gy.clone()}
+	}
+}
+/// Dividing a scalar value by a InverseAbsorbedDose unit value returns a value of type AbsorbedDose
+#[cfg(feature="num-bigfloat")]
+impl<T> core::ops::Div<&InverseAbsorbedDose<T>> for &num_bigfloat::BigFloat where T: NumLike+From<num_bigfloat::BigFloat> {
+	type Output = AbsorbedDose<T>;
+	fn div(self, rhs: &InverseAbsorbedDose<T>) -> Self::Output {
+		AbsorbedDose{Gy: T::from(self.clone()) / rhs.per_Gy.clone()}
+	}
+}
+
+// 1/InverseAbsorbedDose -> AbsorbedDose
+/// Dividing a scalar value by a InverseAbsorbedDose unit value returns a value of type AbsorbedDose
+#[cfg(feature="num-complex")]
+impl<T> core::ops::Div<InverseAbsorbedDose<T>> for num_complex::Complex32 where T: NumLike+From<num_complex::Complex32> {
+	type Output = AbsorbedDose<T>;
+	fn div(self, rhs: InverseAbsorbedDose<T>) -> Self::Output {
+		AbsorbedDose{Gy: T::from(self) / rhs.per_Gy}
+	}
+}
+/// Dividing a scalar value by a InverseAbsorbedDose unit value returns a value of type AbsorbedDose
+#[cfg(feature="num-complex")]
+impl<T> core::ops::Div<InverseAbsorbedDose<T>> for &num_complex::Complex32 where T: NumLike+From<num_complex::Complex32> {
+	type Output = AbsorbedDose<T>;
+	fn div(self, rhs: InverseAbsorbedDose<T>) -> Self::Output {
+		AbsorbedDose{Gy: T::from(self.clone()) / rhs.per_Gy}
+	}
+}
+/// Dividing a scalar value by a InverseAbsorbedDose unit value returns a value of type AbsorbedDose
+#[cfg(feature="num-complex")]
+impl<T> core::ops::Div<&InverseAbsorbedDose<T>> for num_complex::Complex32 where T: NumLike+From<num_complex::Complex32> {
+	type Output = AbsorbedDose<T>;
+	fn div(self, rhs: &InverseAbsorbedDose<T>) -> Self::Output {
+		AbsorbedDose{Gy: T::from(self) / rhs.per_Gy.clone()}
+	}
+}
+/// Dividing a scalar value by a InverseAbsorbedDose unit value returns a value of type AbsorbedDose
+#[cfg(feature="num-complex")]
+impl<T> core::ops::Div<&InverseAbsorbedDose<T>> for &num_complex::Complex32 where T: NumLike+From<num_complex::Complex32> {
+	type Output = AbsorbedDose<T>;
+	fn div(self, rhs: &InverseAbsorbedDose<T>) -> Self::Output {
+		AbsorbedDose{Gy: T::from(self.clone()) / rhs.per_Gy.clone()}
+	}
+}
+
+// 1/InverseAbsorbedDose -> AbsorbedDose
+/// Dividing a scalar value by a InverseAbsorbedDose unit value returns a value of type AbsorbedDose
+#[cfg(feature="num-complex")]
+impl<T> core::ops::Div<InverseAbsorbedDose<T>> for num_complex::Complex64 where T: NumLike+From<num_complex::Complex64> {
+	type Output = AbsorbedDose<T>;
+	fn div(self, rhs: InverseAbsorbedDose<T>) -> Self::Output {
+		AbsorbedDose{Gy: T::from(self) / rhs.per_Gy}
+	}
+}
+/// Dividing a scalar value by a InverseAbsorbedDose unit value returns a value of type AbsorbedDose
+#[cfg(feature="num-complex")]
+impl<T> core::ops::Div<InverseAbsorbedDose<T>> for &num_complex::Complex64 where T: NumLike+From<num_complex::Complex64> {
+	type Output = AbsorbedDose<T>;
+	fn div(self, rhs: InverseAbsorbedDose<T>) -> Self::Output {
+		AbsorbedDose{Gy: T::from(self.clone()) / rhs.per_Gy}
+	}
+}
+/// Dividing a scalar value by a InverseAbsorbedDose unit value returns a value of type AbsorbedDose
+#[cfg(feature="num-complex")]
+impl<T> core::ops::Div<&InverseAbsorbedDose<T>> for num_complex::Complex64 where T: NumLike+From<num_complex::Complex64> {
+	type Output = AbsorbedDose<T>;
+	fn div(self, rhs: &InverseAbsorbedDose<T>) -> Self::Output {
+		AbsorbedDose{Gy: T::from(self) / rhs.per_Gy.clone()}
+	}
+}
+/// Dividing a scalar value by a InverseAbsorbedDose unit value returns a value of type AbsorbedDose
+#[cfg(feature="num-complex")]
+impl<T> core::ops::Div<&InverseAbsorbedDose<T>> for &num_complex::Complex64 where T: NumLike+From<num_complex::Complex64> {
+	type Output = AbsorbedDose<T>;
+	fn div(self, rhs: &InverseAbsorbedDose<T>) -> Self::Output {
+		AbsorbedDose{Gy: T::from(self.clone()) / rhs.per_Gy.clone()}
+	}
+}
+
+// 1/InverseAbsorbedDose -> AbsorbedDose
+/// Dividing a scalar value by a InverseAbsorbedDose unit value returns a value of type AbsorbedDose
+#[cfg(feature="num-rational")]
+impl<T> core::ops::Div<InverseAbsorbedDose<T>> for num_rational::Rational64 where T: NumLike+From<num_rational::Rational64> {
+	type Output = AbsorbedDose<T>;
+	fn div(self, rhs: InverseAbsorbedDose<T>) -> Self::Output {
+		AbsorbedDose{Gy: T::from(self) / rhs.per_Gy}
+	}
+}
+/// Dividing a scalar value by a InverseAbsorbedDose unit value returns a value of type AbsorbedDose
+#[cfg(feature="num-rational")]
+impl<T> core::ops::Div<InverseAbsorbedDose<T>> for &num_rational::Rational64 where T: NumLike+From<num_rational::Rational64> {
+	type Output = AbsorbedDose<T>;
+	fn div(self, rhs: InverseAbsorbedDose<T>) -> Self::Output {
+		AbsorbedDose{Gy: T::from(self.clone()) / rhs.per_Gy}
+	}
+}
+/// Dividing a scalar value by a InverseAbsorbedDose unit value returns a value of type AbsorbedDose
+#[cfg(feature="num-rational")]
+impl<T> core::ops::Div<&InverseAbsorbedDose<T>> for num_rational::Rational64 where T: NumLike+From<num_rational::Rational64> {
+	type Output = AbsorbedDose<T>;
+	fn div(self, rhs: &InverseAbsorbedDose<T>) -> Self::Output {
+		AbsorbedDose{Gy: T::from(self) / rhs.per_Gy.clone()}
+	}
+}
+/// Dividing a scalar value by a InverseAbsorbedDose unit value returns a value of type AbsorbedDose
+#[cfg(feature="num-rational")]
+impl<T> core::ops::Div<&InverseAbsorbedDose<T>> for &num_rational::Rational64 where T: NumLike+From<num_rational::Rational64> {
+	type Output = AbsorbedDose<T>;
+	fn div(self, rhs: &InverseAbsorbedDose<T>) -> Self::Output {
+		AbsorbedDose{Gy: T::from(self.clone()) / rhs.per_Gy.clone()}
+	}
+}
+
+// 1/InverseAbsorbedDose -> AbsorbedDose
+/// Dividing a scalar value by a InverseAbsorbedDose unit value returns a value of type AbsorbedDose
+#[cfg(feature="num-rational")]
+impl<T> core::ops::Div<InverseAbsorbedDose<T>> for num_rational::BigRational where T: NumLike+From<num_rational::BigRational> {
+	type Output = AbsorbedDose<T>;
+	fn div(self, rhs: InverseAbsorbedDose<T>) -> Self::Output {
+		AbsorbedDose{Gy: T::from(self) / rhs.per_Gy}
+	}
+}
+/// Dividing a scalar value by a InverseAbsorbedDose unit value returns a value of type AbsorbedDose
+#[cfg(feature="num-rational")]
+impl<T> core::ops::Div<InverseAbsorbedDose<T>> for &num_rational::BigRational where T: NumLike+From<num_rational::BigRational> {
+	type Output = AbsorbedDose<T>;
+	fn div(self, rhs: InverseAbsorbedDose<T>) -> Self::Output {
+		AbsorbedDose{Gy: T::from(self.clone()) / rhs.per_Gy}
+	}
+}
+/// Dividing a scalar value by a InverseAbsorbedDose unit value returns a value of type AbsorbedDose
+#[cfg(feature="num-rational")]
+impl<T> core::ops::Div<&InverseAbsorbedDose<T>> for num_rational::BigRational where T: NumLike+From<num_rational::BigRational> {
+	type Output = AbsorbedDose<T>;
+	fn div(self, rhs: &InverseAbsorbedDose<T>) -> Self::Output {
+		AbsorbedDose{Gy: T::from(self) / rhs.per_Gy.clone()}
+	}
+}
+/// Dividing a scalar value by a InverseAbsorbedDose unit value returns a value of type AbsorbedDose
+#[cfg(feature="num-rational")]
+impl<T> core::ops::Div<&InverseAbsorbedDose<T>> for &num_rational::BigRational where T: NumLike+From<num_rational::BigRational> {
+	type Output = AbsorbedDose<T>;
+	fn div(self, rhs: &InverseAbsorbedDose<T>) -> Self::Output {
+		AbsorbedDose{Gy: T::from(self.clone()) / rhs.per_Gy.clone()}
+	}
+}
+
+/// The inverse of absorbed radiation dose rate unit type, defined as seconds per gray in SI units
+#[derive(UnitStruct, Debug, Clone, Default)]
+#[si(no_display)]
+#[cfg_attr(all(feature="serde", not(feature="serde-unit-tag")), derive(Serialize, Deserialize))]
+#[repr(transparent)]
+pub struct InverseAbsorbedDoseRate<T: NumLike>{
+	/// The value of this Inverse absorbed dose rate in seconds per gray
+	pub s_per_Gy: T
+}
+
+impl<T> InverseAbsorbedDoseRate<T> where T: NumLike {
+
+	/// Returns the standard unit name of inverse absorbed dose rate: "seconds per gray"
+	pub fn unit_name() -> &'static str { "seconds per gray" }
+	
+	/// Returns the abbreviated name or symbol of inverse absorbed dose rate: "s/Gy" for seconds per gray
+	pub fn unit_symbol() -> &'static str { "s/Gy" }
+
+	/// Returns the exponents of the SI base units of inverse absorbed dose rate (s^3/m^2)
+	pub const fn dimension() -> super::Dimension { super::Dimension{length: -2, mass: 0, time: 3, current: 0, temperature: 0, amount: 0, luminosity: 0} }
+
+	/// Returns a wrapper that formats this inverse absorbed dose rate value with the full unit name instead of 
+	/// the unit symbol, eg "5 seconds per gray" (the unit name is not singularized for values of 1)
+	pub fn display_named(&self) -> super::NamedDisplay<'_, T> {
+		super::NamedDisplay::new(&self.s_per_Gy, Self::unit_name())
+	}
+
+	/// Returns a new inverse absorbed dose rate value from the given number of seconds per gray (same as 
+	/// `from_s_per_Gy(...)`), for any number-like type. This is useful in generic code 
+	/// that works with several unit types
+	///
+	/// # Arguments
+	/// * `value` - Any number-like type, representing a quantity of seconds per gray
+	pub const fn new(value: T) -> Self { InverseAbsorbedDoseRate{s_per_Gy: value} }
+
+	/// Returns a new inverse absorbed dose rate value from the given number of seconds per gray. Unlike
+	/// `from_s_per_Gy(...)`, this is a `const fn`, so it can be used to declare `const` and `static` values
+	///
+	/// # Arguments
+	/// * `s_per_Gy` - Any number-like type, representing a quantity of seconds per gray
+	pub const fn from_s_per_Gy_const(s_per_Gy: T) -> Self { InverseAbsorbedDoseRate{s_per_Gy} }
+
+	/// Returns a inverse absorbed dose rate value with a different number type, by applying the 
+	/// given function to the value in seconds per gray (eg `.map(|x| x as f32)` to convert 
+	/// from `f64` to `f32`)
+	///
+	/// # Arguments
+	/// * `f` - Function that converts the number of seconds per gray to the new number type
+	pub fn map<U, F>(self, f: F) -> InverseAbsorbedDoseRate<U> where U: NumLike, F: FnOnce(T) -> U {
+		InverseAbsorbedDoseRate{s_per_Gy: f(self.s_per_Gy)}
+	}
+
+	/// Returns a inverse absorbed dose rate value with a different number type, converted 
+	/// losslessly with the `From` trait (eg from `f32` to `f64`)
+	pub fn cast<U>(self) -> InverseAbsorbedDoseRate<U> where U: NumLike+From<T> {
+		InverseAbsorbedDoseRate{s_per_Gy: U::from(self.s_per_Gy)}
+	}
+
+	/// Returns a reference to the value of this inverse absorbed dose rate in seconds per gray
+	pub fn as_raw(&self) -> &T {
+		&self.s_per_Gy
+	}
+
+	/// Returns a mutable reference to the value of this inverse absorbed dose rate in seconds per gray, 
+	/// eg for passing to numeric libraries that modify values in place. The number 
+	/// is always in seconds per gray, so changing it directly does not change the unit
+	pub fn raw_mut(&mut self) -> &mut T {
+		&mut self.s_per_Gy
+	}
+
+	/// Returns a slice of numbers in seconds per gray as a slice of inverse absorbed dose rate values, 
+	/// without copying
+	///
+	/// # Arguments
+	/// * `values` - Slice of numbers, representing quantities of seconds per gray
+	pub fn wrap_slice(values: &[T]) -> &[Self] {
+		// SAFETY: InverseAbsorbedDoseRate<T> is #[repr(transparent)], so it has the same memory layout as T
+		unsafe { core::slice::from_raw_parts(values.as_ptr() as *const Self, values.len()) }
+	}
+
+	/// Returns a mutable slice of numbers in seconds per gray as a mutable slice of 
+	/// inverse absorbed dose rate values, without copying
+	///
+	/// # Arguments
+	/// * `values` - Slice of numbers, representing quantities of seconds per gray
+	pub fn wrap_slice_mut(values: &mut [T]) -> &mut [Self] {
+		// SAFETY: InverseAbsorbedDoseRate<T> is #[repr(transparent)], so it has the same memory layout as T
+		unsafe { core::slice::from_raw_parts_mut(values.as_mut_ptr() as *mut Self, values.len()) }
+	}
+
+	/// Returns the ratio of this inverse absorbed dose rate value to another inverse absorbed dose rate value 
+	/// (same as `self / other`). Dividing a unit value by another value of the same 
+	/// unit type cancels the units, so the ratio is a dimensionless number
+	///
+	/// # Arguments
+	/// * `other` - The inverse absorbed dose rate value to divide by
+	pub fn ratio(self, other: Self) -> T {
+		self.s_per_Gy / other.s_per_Gy
+	}
+
+	/// Returns the ratio of this inverse absorbed dose rate value to another inverse absorbed dose rate value 
+	/// as a `Dimensionless` value, instead of a bare number like `ratio(...)` does
+	///
+	/// # Arguments
+	/// * `other` - The inverse absorbed dose rate value to divide by
+	pub fn dimensionless_ratio(self, other: Self) -> super::base::Dimensionless<T> {
+		super::base::Dimensionless{value: self.s_per_Gy / other.s_per_Gy}
+	}
+
+	/// Returns this inverse absorbed dose rate value multiplied by a dimensionless factor 
+	/// (same as `self * factor`)
+	///
+	/// # Arguments
+	/// * `factor` - The number to multiply by
+	pub fn scale(self, factor: T) -> Self {
+		InverseAbsorbedDoseRate{s_per_Gy: self.s_per_Gy * factor}
+	}
+	
+	/// Returns a new inverse absorbed dose rate value from the given number of seconds per gray
+	///
+	/// # Arguments
+	/// * `s_per_Gy` - Any number-like type, representing a quantity of seconds per gray
+	pub fn from_s_per_Gy(s_per_Gy: T) -> Self { InverseAbsorbedDoseRate{s_per_Gy: s_per_Gy} }
+	
+	/// Returns a copy of this inverse absorbed dose rate value in seconds per gray
+	pub fn to_s_per_Gy(&self) -> T { self.s_per_Gy.clone() }
+
+	/// Returns a new inverse absorbed dose rate value from the given number of seconds per gray
+	///
+	/// # Arguments
+	/// * `seconds_per_gray` - Any number-like type, representing a quantity of seconds per gray
+	pub fn from_seconds_per_gray(seconds_per_gray: T) -> Self { InverseAbsorbedDoseRate{s_per_Gy: seconds_per_gray} }
+	
+	/// Returns a copy of this inverse absorbed dose rate value in seconds per gray
+	pub fn to_seconds_per_gray(&self) -> T { self.s_per_Gy.clone() }
+
+}
+
+/// Formats inverse absorbed dose rate values as a number followed by its unit symbol, eg "1500 s/Gy"
+/// (complex numbers are wrapped in parentheses, eg "(1+2i) s/Gy").
+/// The alternate flag (`{:#}`) scales the number by the nearest SI prefix where the unit symbol allows it
+impl<T> fmt::Display for InverseAbsorbedDoseRate<T> where T: NumLike {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		if f.alternate() {
+			return super::fmt_with_si_prefix(f, &self.s_per_Gy, Self::unit_symbol(), false);
+		}
+		super::fmt_unit_value(f, &self.s_per_Gy, Self::unit_symbol())
+	}
+}
+
+/// Formats inverse absorbed dose rate values in exponential notation followed by the unit symbol, eg "1.5e3 s/Gy"
+impl<T> fmt::LowerExp for InverseAbsorbedDoseRate<T> where T: NumLike+fmt::LowerExp {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		fmt::LowerExp::fmt(&self.s_per_Gy, f)?;
+		write!(f, " {}", Self::unit_symbol())
+	}
+}
+
+/// Formats inverse absorbed dose rate values in exponential notation followed by the unit symbol, eg "1.5E3 s/Gy"
+impl<T> fmt::UpperExp for InverseAbsorbedDoseRate<T> where T: NumLike+fmt::UpperExp {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		fmt::UpperExp::fmt(&self.s_per_Gy, f)?;
+		write!(f, " {}", Self::unit_symbol())
+	}
+}
+
+impl<T> super::SiUnit for InverseAbsorbedDoseRate<T> where T: NumLike {
+	type Number = T;
+	fn unit_name(&self) -> &'static str { Self::unit_name() }
+	fn unit_symbol(&self) -> &'static str { Self::unit_symbol() }
+	fn raw(&self) -> &T { &self.s_per_Gy }
+	fn dimension(&self) -> super::Dimension { Self::dimension() }
+	fn from_raw(value: T) -> Self { InverseAbsorbedDoseRate{s_per_Gy: value} }
+	fn into_raw(self) -> T { self.s_per_Gy }
+}
+
+#[cfg(feature="alloc")]
+impl<T> InverseAbsorbedDoseRate<T> where T: NumLike {
+	/// Returns this inverse absorbed dose rate value in the canonical text format "<value> <symbol>", eg "5 s/Gy".
+	/// Unlike `Display`, this format never changes with precision or alternate flags, and it is the
+	/// input format expected by `from_str(...)`, so `x.canonical_string().parse()` returns the same value
+	pub fn canonical_string(&self) -> alloc::string::String {
+		alloc::format!("{} {}", self.s_per_Gy, Self::unit_symbol())
+	}
+
+	/// Returns a `Vec` of inverse absorbed dose rate values copied from the given slice of numbers
+	///
+	/// # Arguments
+	/// * `values` - Slice of numbers, representing quantities of seconds per gray
+	pub fn from_s_per_Gy_slice(values: &[T]) -> alloc::vec::Vec<Self> {
+		values.iter().map(|x| InverseAbsorbedDoseRate{s_per_Gy: x.clone()}).collect()
+	}
+}
+
+/// Parses a inverse absorbed dose rate value from a number followed by its unit symbol, eg "5 s/Gy"
+impl<T> core::str::FromStr for InverseAbsorbedDoseRate<T> where T: NumLike+core::str::FromStr {
+	type Err = SiParseError;
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		Ok(InverseAbsorbedDoseRate{s_per_Gy: super::parse_unit_value(s, Self::unit_symbol())?})
+	}
+}
+
+/// Parses a inverse absorbed dose rate value from a number followed by its unit symbol, eg "5 s/Gy" 
+/// (same as `str::parse()`)
+impl<T> core::convert::TryFrom<&str> for InverseAbsorbedDoseRate<T> where T: NumLike+core::str::FromStr {
+	type Error = SiParseError;
+	fn try_from(s: &str) -> Result<Self, Self::Error> {
+		<Self as core::str::FromStr>::from_str(s)
+	}
+}
+
+#[cfg(feature="num-traits")]
+impl<T> InverseAbsorbedDoseRate<T> where T: NumLike+num_traits::Euclid {
+
+	/// Returns the Euclidean remainder of dividing this inverse absorbed dose rate value by another, 
+	/// which is never negative (requires the `num-traits` feature)
+	///
+	/// # Arguments
+	/// * `rhs` - The inverse absorbed dose rate value to divide by
+	pub fn rem_euclid(self, rhs: Self) -> Self {
+		InverseAbsorbedDoseRate{s_per_Gy: self.s_per_Gy.rem_euclid(&rhs.s_per_Gy)}
+	}
+
+	/// Returns the Euclidean quotient of dividing this inverse absorbed dose rate value by another, 
+	/// ie the whole number of times that `rhs` fits into this value, rounded such that 
+	/// `rem_euclid(...)` is never negative (requires the `num-traits` feature)
+	///
+	/// # Arguments
+	/// * `rhs` - The inverse absorbed dose rate value to divide by
+	pub fn div_euclid(self, rhs: Self) -> T {
+		self.s_per_Gy.div_euclid(&rhs.s_per_Gy)
+	}
+}
+
+/// Summing inverse absorbed dose rate values returns their total as a inverse absorbed dose rate value (or zero if there are no values)
+impl<T> core::iter::Sum<InverseAbsorbedDoseRate<T>> for InverseAbsorbedDoseRate<T> where T: NumLike+core::iter::Sum {
+	fn sum<I: Iterator<Item=InverseAbsorbedDoseRate<T>>>(iter: I) -> Self {
+		InverseAbsorbedDoseRate{s_per_Gy: iter.map(|x| x.s_per_Gy).sum()}
+	}
+}
+/// Summing inverse absorbed dose rate values returns their total as a inverse absorbed dose rate value (or zero if there are no values)
+impl<'a, T> core::iter::Sum<&'a InverseAbsorbedDoseRate<T>> for InverseAbsorbedDoseRate<T> where T: NumLike+core::iter::Sum+'a {
+	fn sum<I: Iterator<Item=&'a InverseAbsorbedDoseRate<T>>>(iter: I) -> Self {
+		InverseAbsorbedDoseRate{s_per_Gy: iter.map(|x| x.s_per_Gy.clone()).sum()}
+	}
+}
+
+impl<T> InverseAbsorbedDoseRate<T> where T: NumLike+From<f64> {
+
+	/// Returns the weighted average of the given inverse absorbed dose rate values, where each 
+	/// value is paired with its weight (weights do not need to add up to 1). If the 
+	/// total weight is zero (including when `values` is empty), then zero is returned
+	///
+	/// # Arguments
+	/// * `values` - Slice of (inverse absorbed dose rate value, weight) pairs
+	pub fn weighted_mean(values: &[(Self, f64)]) -> Self {
+		let total_weight: f64 = values.iter().map(|(_, weight)| *weight).sum();
+		if total_weight == 0.0 {
+			return InverseAbsorbedDoseRate{s_per_Gy: T::from(0.0)};
+		}
+		let total = values.iter().fold(T::from(0.0),
+			|total, (value, weight)| total + value.s_per_Gy.clone() * T::from(*weight));
+		InverseAbsorbedDoseRate{s_per_Gy: total / T::from(total_weight)}
+	}
+}
+
+impl<T> InverseAbsorbedDoseRate<T> where T: NumLike+PartialEq+From<u8> {
+
+	/// Divides this inverse absorbed dose rate value by another unit value (eg `distance.checked_div(time)` 
+	/// returns a velocity), returning `None` if the divisor is zero instead of producing 
+	/// infinity or NaN (or panicking, for integer number types). This works for every unit 
+	/// type that this inverse absorbed dose rate value can be divided by with the `/` operator
+	///
+	/// # Arguments
+	/// * `rhs` - The unit value to divide by
+	pub fn checked_div<R>(self, rhs: R) -> Option<<Self as core::ops::Div<R>>::Output> 
+		where Self: core::ops::Div<R>, R: super::SiUnit<Number=T>
+	{
+		if *rhs.raw() == T::from(0u8) {
+			return None;
+		}
+		Some(self / rhs)
+	}
+}
+
+/// Multiplying a inverse absorbed dose rate value by a dimensionless value returns a inverse absorbed dose rate value
+impl<T> core::ops::Mul<super::base::Dimensionless<T>> for InverseAbsorbedDoseRate<T> where T: NumLike {
+	type Output = InverseAbsorbedDoseRate<T>;
+	fn mul(self, rhs: super::base::Dimensionless<T>) -> Self::Output {
+		InverseAbsorbedDoseRate{s_per_Gy: self.s_per_Gy * rhs.value}
+	}
+}
+/// Multiplying a dimensionless value by a inverse absorbed dose rate value returns a inverse absorbed dose rate value
+impl<T> core::ops::Mul<InverseAbsorbedDoseRate<T>> for super::base::Dimensionless<T> where T: NumLike {
+	type Output = InverseAbsorbedDoseRate<T>;
+	fn mul(self, rhs: InverseAbsorbedDoseRate<T>) -> Self::Output {
+		InverseAbsorbedDoseRate{s_per_Gy: self.value * rhs.s_per_Gy}
+	}
+}
+/// Dividing a inverse absorbed dose rate value by a dimensionless value returns a inverse absorbed dose rate value
+impl<T> core::ops::Div<super::base::Dimensionless<T>> for InverseAbsorbedDoseRate<T> where T: NumLike {
+	type Output = InverseAbsorbedDoseRate<T>;
+	fn div(self, rhs: super::base::Dimensionless<T>) -> Self::Output {
+		InverseAbsorbedDoseRate{s_per_Gy: self.s_per_Gy / rhs.value}
+	}
+}
+
+/// The remainder of dividing a inverse absorbed dose rate value by another inverse absorbed dose rate value is a inverse absorbed dose rate value
+impl<T> core::ops::Rem<InverseAbsorbedDoseRate<T>> for InverseAbsorbedDoseRate<T> where T: NumLike+core::ops::Rem<Output=T> {
+	type Output = InverseAbsorbedDoseRate<T>;
+	fn rem(self, rhs: InverseAbsorbedDoseRate<T>) -> Self::Output {
+		InverseAbsorbedDoseRate{s_per_Gy: self.s_per_Gy % rhs.s_per_Gy}
+	}
+}
+/// The remainder of dividing a inverse absorbed dose rate value by another inverse absorbed dose rate value is a inverse absorbed dose rate value
+impl<T> core::ops::Rem<&InverseAbsorbedDoseRate<T>> for InverseAbsorbedDoseRate<T> where T: NumLike+core::ops::Rem<Output=T> {
+	type Output = InverseAbsorbedDoseRate<T>;
+	fn rem(self, rhs: &InverseAbsorbedDoseRate<T>) -> Self::Output {
+		InverseAbsorbedDoseRate{s_per_Gy: self.s_per_Gy % rhs.s_per_Gy.clone()}
+	}
+}
+/// The remainder of dividing a inverse absorbed dose rate value by another inverse absorbed dose rate value is a inverse absorbed dose rate value
+impl<T> core::ops::Rem<InverseAbsorbedDoseRate<T>> for &InverseAbsorbedDoseRate<T> where T: NumLike+core::ops::Rem<Output=T> {
+	type Output = InverseAbsorbedDoseRate<T>;
+	fn rem(self, rhs: InverseAbsorbedDoseRate<T>) -> Self::Output {
+		InverseAbsorbedDoseRate{s_per_Gy: self.s_per_Gy.clone() % rhs.s_per_Gy}
+	}
+}
+/// The remainder of dividing a inverse absorbed dose rate value by another inverse absorbed dose rate value is a inverse absorbed dose rate value
+impl<T> core::ops::Rem<&InverseAbsorbedDoseRate<T>> for &InverseAbsorbedDoseRate<T> where T: NumLike+core::ops::Rem<Output=T> {
+	type Output = InverseAbsorbedDoseRate<T>;
+	fn rem(self, rhs: &InverseAbsorbedDoseRate<T>) -> Self::Output {
+		InverseAbsorbedDoseRate{s_per_Gy: self.s_per_Gy.clone() % rhs.s_per_Gy.clone()}
+	}
+}
+
+/// Serializes a inverse absorbed dose rate value together with its unit symbol, eg 
+/// `{"value": 1.5, "unit": "s/Gy"}` (requires the `serde-unit-tag` feature)
+#[cfg(feature="serde-unit-tag")]
+impl<T> serde::Serialize for InverseAbsorbedDoseRate<T> where T: NumLike+serde::Serialize {
+	fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+		super::serialize_with_unit_tag(&self.s_per_Gy, Self::unit_symbol(), serializer)
+	}
+}
+/// Deserializes a inverse absorbed dose rate value from a value and unit symbol, eg 
+/// `{"value": 1.5, "unit": "s/Gy"}`, returning an error if the unit symbol 
+/// does not match (requires the `serde-unit-tag` feature)
+#[cfg(feature="serde-unit-tag")]
+impl<'de, T> serde::Deserialize<'de> for InverseAbsorbedDoseRate<T> where T: NumLike+serde::Deserialize<'de> {
+	fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+		Ok(InverseAbsorbedDoseRate{s_per_Gy: super::deserialize_with_unit_tag(Self::unit_symbol(), deserializer)?})
+	}
+}
+
+/// The additive identity of inverse absorbed dose rate values is zero seconds per gray (requires the `num-traits` feature)
+#[cfg(feature="num-traits")]
+impl<T> num_traits::Zero for InverseAbsorbedDoseRate<T> where T: NumLike+num_traits::Zero {
+	fn zero() -> Self { InverseAbsorbedDoseRate{s_per_Gy: T::zero()} }
+	fn is_zero(&self) -> bool { self.s_per_Gy.is_zero() }
+}
+
+#[cfg(feature="num-traits")]
+impl<T> InverseAbsorbedDoseRate<T> where T: NumLike+num_traits::Signed {
+
+	/// Returns the sign of this inverse absorbed dose rate value as a dimensionless number: 1 if it is 
+	/// positive, -1 if it is negative, and 0 if it is zero for integer number types 
+	/// (for floating-point number types, +0.0 returns 1.0, -0.0 returns -1.0, and NaN returns 
+	/// NaN) (requires the `num-traits` feature)
+	pub fn signum(self) -> T {
+		self.s_per_Gy.signum()
+	}
+}
+
+impl<T> InverseAbsorbedDoseRate<T> where T: NumLike+PartialOrd {
+
+	/// Returns the absolute value of this inverse absorbed dose rate value, ie its magnitude without a minus sign
+	/// (for floating-point number types, -0.0 returns +0.0)
+	pub fn abs(self) -> Self {
+		let negated = -self.s_per_Gy.clone();
+		if self.s_per_Gy < negated {
+			InverseAbsorbedDoseRate{s_per_Gy: negated}
+		} else if self.s_per_Gy == negated {
+			// zero, which is its own negation (adding +0.0 turns -0.0 into +0.0)
+			InverseAbsorbedDoseRate{s_per_Gy: negated + self.s_per_Gy}
+		} else {
+			self
+		}
+	}
+
+	/// Returns the lesser of this inverse absorbed dose rate value and the given inverse absorbed dose rate value
+	///
+	/// # Arguments
+	/// * `other` - The inverse absorbed dose rate value to compare with this one
+	pub fn min(self, other: Self) -> Self {
+		if other.s_per_Gy < self.s_per_Gy { other } else { self }
+	}
+
+	/// Returns the greater of this inverse absorbed dose rate value and the given inverse absorbed dose rate value
+	///
+	/// # Arguments
+	/// * `other` - The inverse absorbed dose rate value to compare with this one
+	pub fn max(self, other: Self) -> Self {
+		if other.s_per_Gy > self.s_per_Gy { other } else { self }
+	}
+
+	/// Returns the least of the given inverse absorbed dose rate values, or `None` if there are none. 
+	/// Unlike `Iterator::min()`, this does not require `Ord`, so it works for 
+	/// floating-point numbers. NaN values are skipped (so the result is `None` if all 
+	/// values are NaN), and the first of several equal values is returned
+	///
+	/// # Arguments
+	/// * `values` - Any iterator or collection of inverse absorbed dose rate values
+	pub fn min_of<I>(values: I) -> Option<Self> where I: IntoIterator<Item=Self> {
+		values.into_iter().filter(|x| x.s_per_Gy.partial_cmp(&x.s_per_Gy).is_some()).reduce(Self::min)
+	}
+
+	/// Returns the greatest of the given inverse absorbed dose rate values, or `None` if there are none. 
+	/// Unlike `Iterator::max()`, this does not require `Ord`, so it works for 
+	/// floating-point numbers. NaN values are skipped (so the result is `None` if all 
+	/// values are NaN), and the first of several equal values is returned
+	///
+	/// # Arguments
+	/// * `values` - Any iterator or collection of inverse absorbed dose rate values
+	pub fn max_of<I>(values: I) -> Option<Self> where I: IntoIterator<Item=Self> {
+		values.into_iter().filter(|x| x.s_per_Gy.partial_cmp(&x.s_per_Gy).is_some()).reduce(Self::max)
+	}
+
+	/// Returns this inverse absorbed dose rate value restricted to the range from `lo` to `hi`
+	///
+	/// # Arguments
+	/// * `lo` - The minimum inverse absorbed dose rate value
+	/// * `hi` - The maximum inverse absorbed dose rate value
+	///
+	/// # Panics
+	/// Panics if `lo` is greater than `hi` (or if either of them is NaN)
+	pub fn clamp(self, lo: Self, hi: Self) -> Self {
+		assert!(lo.s_per_Gy <= hi.s_per_Gy, "lo must be less than or equal to hi");
+		if self.s_per_Gy < lo.s_per_Gy { lo } else if self.s_per_Gy > hi.s_per_Gy { hi } else { self }
+	}
+
+	/// Returns this inverse absorbed dose rate value with its magnitude capped at `max`, keeping its 
+	/// sign (eg for capping a speed), or this value unchanged if its magnitude does not 
+	/// exceed `max`. For complex-valued inverse absorbed dose rate, use `clamp_norm(...)` instead, 
+	/// which caps the magnitude while keeping the phase
+	///
+	/// # Arguments
+	/// * `max` - The maximum magnitude (should not be negative)
+	pub fn clamp_magnitude(self, max: Self) -> Self {
+		let negated_max = -max.s_per_Gy.clone();
+		if self.s_per_Gy > max.s_per_Gy { 
+			max 
+		} else if self.s_per_Gy < negated_max { 
+			InverseAbsorbedDoseRate{s_per_Gy: negated_max} 
+		} else { 
+			self 
+		}
+	}
+
+	/// Returns true if this inverse absorbed dose rate value differs from the given inverse absorbed dose rate 
+	/// value by no more than the given absolute tolerance (which is also a 
+	/// inverse absorbed dose rate value, so the comparison is dimensionally safe). NaN is never 
+	/// equal to anything. See also the `assert_unit_eq!(...)` macro.
+	///
+	/// # Arguments
+	/// * `other` - The inverse absorbed dose rate value to compare with this one
+	/// * `tolerance` - The largest allowed difference between the two values
+	pub fn eq_within(self, other: Self, tolerance: Self) -> bool {
+		let diff = self.s_per_Gy - other.s_per_Gy;
+		diff <= tolerance.s_per_Gy && -diff <= tolerance.s_per_Gy
+	}
+}
+
+#[cfg(feature="num-complex")]
+impl InverseAbsorbedDoseRate<num_complex::Complex32> {
+	/// Returns the magnitude (aka norm or absolute value) of this complex inverse absorbed dose rate 
+	/// value as a real-valued inverse absorbed dose rate (requires the `num-complex` feature)
+	pub fn magnitude(self) -> InverseAbsorbedDoseRate<f32> {
+		InverseAbsorbedDoseRate{s_per_Gy: self.s_per_Gy.norm()}
+	}
+
+	/// Returns this complex inverse absorbed dose rate value scaled down so that its magnitude is 
+	/// at most `max` (keeping its phase), or this value unchanged if its magnitude does 
+	/// not exceed `max`. This is the complex equivalent of `clamp_magnitude(...)` (requires 
+	/// the `num-complex` feature)
+	///
+	/// # Arguments
+	/// * `max` - The maximum magnitude (should not be negative)
+	pub fn clamp_norm(self, max: InverseAbsorbedDoseRate<f32>) -> Self {
+		let norm = self.s_per_Gy.norm();
+		if norm > max.s_per_Gy {
+			InverseAbsorbedDoseRate{s_per_Gy: self.s_per_Gy * (max.s_per_Gy / norm)}
+		} else {
+			self
+		}
+	}
+}
+
+#[cfg(feature="num-complex")]
+impl InverseAbsorbedDoseRate<num_complex::Complex64> {
+	/// Returns the magnitude (aka norm or absolute value) of this complex inverse absorbed dose rate 
+	/// value as a real-valued inverse absorbed dose rate (requires the `num-complex` feature)
+	pub fn magnitude(self) -> InverseAbsorbedDoseRate<f64> {
+		InverseAbsorbedDoseRate{s_per_Gy: self.s_per_Gy.norm()}
+	}
+
+	/// Returns this complex inverse absorbed dose rate value scaled down so that its magnitude is 
+	/// at most `max` (keeping its phase), or this value unchanged if its magnitude does 
+	/// not exceed `max`. This is the complex equivalent of `clamp_magnitude(...)` (requires 
+	/// the `num-complex` feature)
+	///
+	/// # Arguments
+	/// * `max` - The maximum magnitude (should not be negative)
+	pub fn clamp_norm(self, max: InverseAbsorbedDoseRate<f64>) -> Self {
+		let norm = self.s_per_Gy.norm();
+		if norm > max.s_per_Gy {
+			InverseAbsorbedDoseRate{s_per_Gy: self.s_per_Gy * (max.s_per_Gy / norm)}
+		} else {
+			self
+		}
+	}
+}
+
+/// Rounding and other floating-point functions (requires the `num-traits` feature, 
+/// and either the `std` or `libm` feature)
+#[cfg(all(feature="num-traits", any(feature="std", feature="libm")))]
+impl<T> InverseAbsorbedDoseRate<T> where T: NumLike+num_traits::Float {
+
+	/// Returns this inverse absorbed dose rate value rounded to the nearest whole number of seconds per gray 
+	/// (half-way cases are rounded away from zero)
+	pub fn round(self) -> Self {
+		InverseAbsorbedDoseRate{s_per_Gy: self.s_per_Gy.round()}
+	}
+
+	/// Returns this inverse absorbed dose rate value rounded down to a whole number of seconds per gray
+	pub fn floor(self) -> Self {
+		InverseAbsorbedDoseRate{s_per_Gy: self.s_per_Gy.floor()}
+	}
+
+	/// Returns this inverse absorbed dose rate value rounded up to a whole number of seconds per gray
+	pub fn ceil(self) -> Self {
+		InverseAbsorbedDoseRate{s_per_Gy: self.s_per_Gy.ceil()}
+	}
+
+	/// Returns this inverse absorbed dose rate value rounded towards zero to a whole number of seconds per gray
+	pub fn trunc(self) -> Self {
+		InverseAbsorbedDoseRate{s_per_Gy: self.s_per_Gy.trunc()}
+	}
+
+	/// Returns this inverse absorbed dose rate value rounded to the nearest multiple of the given step 
+	/// (half-way cases are rounded away from zero)
+	///
+	/// # Arguments
+	/// * `step` - The inverse absorbed dose rate value to round to a multiple of
+	pub fn round_to(self, step: Self) -> Self {
+		InverseAbsorbedDoseRate{s_per_Gy: (self.s_per_Gy / step.s_per_Gy).round() * step.s_per_Gy}
+	}
+
+	/// Fused multiply-add: returns `self * a + b` computed with only one rounding 
+	/// error, which is more accurate (and on some hardware faster) than a separate 
+	/// multiplication and addition
+	///
+	/// # Arguments
+	/// * `a` - The scalar value to multiply this inverse absorbed dose rate value by
+	/// * `b` - The inverse absorbed dose rate value to add to the product
+	pub fn mul_add(self, a: T, b: Self) -> Self {
+		InverseAbsorbedDoseRate{s_per_Gy: self.s_per_Gy.mul_add(a, b.s_per_Gy)}
+	}
+
+	/// Returns true if this inverse absorbed dose rate value is neither infinite nor NaN
+	pub fn is_finite(&self) -> bool {
+		self.s_per_Gy.is_finite()
+	}
+
+	/// Returns true if this inverse absorbed dose rate value is positive or negative infinity
+	pub fn is_infinite(&self) -> bool {
+		self.s_per_Gy.is_infinite()
+	}
+
+	/// Returns true if this inverse absorbed dose rate value is NaN (not a number)
+	pub fn is_nan(&self) -> bool {
+		self.s_per_Gy.is_nan()
+	}
+
+	/// Returns true if this inverse absorbed dose rate value has a positive sign, including +0.0 
+	/// and positive infinity
+	pub fn is_sign_positive(&self) -> bool {
+		self.s_per_Gy.is_sign_positive()
+	}
+
+	/// Returns true if this inverse absorbed dose rate value has a negative sign, including -0.0 
+	/// and negative infinity
+	pub fn is_sign_negative(&self) -> bool {
+		self.s_per_Gy.is_sign_negative()
+	}
+
+	/// Returns a inverse absorbed dose rate value with the magnitude of this one and the sign of 
+	/// `sign_source`, eg for reflecting a value off of a surface
+	///
+	/// # Arguments
+	/// * `sign_source` - The inverse absorbed dose rate value whose sign is copied
+	pub fn copysign(self, sign_source: Self) -> Self {
+		InverseAbsorbedDoseRate{s_per_Gy: self.s_per_Gy.copysign(sign_source.s_per_Gy)}
+	}
+}
+
+#[cfg(feature="num-traits")]
+impl<T> InverseAbsorbedDoseRate<T> where T: NumLike+num_traits::CheckedAdd+num_traits::CheckedSub {
+
+	/// Adds another inverse absorbed dose rate value to this one, returning `None` if the 
+	/// addition overflows the number type (requires the `num-traits` feature)
+	pub fn checked_add(self, rhs: Self) -> Option<Self> {
+		Some(InverseAbsorbedDoseRate{s_per_Gy: self.s_per_Gy.checked_add(&rhs.s_per_Gy)?})
+	}
+
+	/// Subtracts another inverse absorbed dose rate value from this one, returning `None` if 
+	/// the subtraction overflows the number type (requires the `num-traits` feature)
+	pub fn checked_sub(self, rhs: Self) -> Option<Self> {
+		Some(InverseAbsorbedDoseRate{s_per_Gy: self.s_per_Gy.checked_sub(&rhs.s_per_Gy)?})
+	}
+}
+
+#[cfg(feature="num-traits")]
+impl<T> InverseAbsorbedDoseRate<T> where T: NumLike+num_traits::SaturatingAdd+num_traits::SaturatingSub {
+
+	/// Adds another inverse absorbed dose rate value to this one, clamping the result to the 
+	/// minimum or maximum value of the number type instead of overflowing 
+	/// (requires the `num-traits` feature)
+	pub fn saturating_add(self, rhs: Self) -> Self {
+		InverseAbsorbedDoseRate{s_per_Gy: self.s_per_Gy.saturating_add(&rhs.s_per_Gy)}
+	}
+
+	/// Subtracts another inverse absorbed dose rate value from this one, clamping the result 
+	/// to the minimum or maximum value of the number type instead of overflowing 
+	/// (requires the `num-traits` feature)
+	pub fn saturating_sub(self, rhs: Self) -> Self {
+		InverseAbsorbedDoseRate{s_per_Gy: self.s_per_Gy.saturating_sub(&rhs.s_per_Gy)}
+	}
+}
+
+impl<T> InverseAbsorbedDoseRate<T> where T: NumLike+Into<f64> {
+
+	/// Returns true if this inverse absorbed dose rate value is equal to the given inverse absorbed dose rate 
+	/// value to within the given number of significant figures (eg 6 significant 
+	/// figures allows a relative difference of up to 1 part per million). NaN is 
+	/// only approximately equal to NaN, infinity is only approximately equal to 
+	/// infinity of the same sign, and zero is only approximately equal to zero.
+	///
+	/// # Arguments
+	/// * `other` - The inverse absorbed dose rate value to compare with this one
+	/// * `sig_figs` - The number of significant figures that must match
+	pub fn approx_eq(self, other: Self, sig_figs: i32) -> bool {
+		super::approx_eq_f64(self.s_per_Gy.into(), other.s_per_Gy.into(), sig_figs)
+	}
+}
+
+impl<T> InverseAbsorbedDoseRate<T> where T: NumLike+From<f64> {
+
+	/// Returns the linear interpolation between this inverse absorbed dose rate value and the given 
+	/// inverse absorbed dose rate value, ie `self*(1-t) + other*t`. The interpolation factor is not 
+	/// clamped, so values of `t` outside the range 0 to 1 extrapolate beyond the two 
+	/// values (see `lerp_clamped(...)`).
+	///
+	/// # Arguments
+	/// * `other` - The inverse absorbed dose rate value to interpolate towards (returned when `t` is 1)
+	/// * `t` - The interpolation factor, where 0 returns this value and 1 returns `other`
+	pub fn lerp(self, other: Self, t: f64) -> Self {
+		InverseAbsorbedDoseRate{s_per_Gy: self.s_per_Gy * T::from(1.0 - t) + other.s_per_Gy * T::from(t)}
+	}
+
+	/// Same as `lerp(...)`, except that the interpolation factor `t` is first clamped 
+	/// to the range 0 to 1, so that the result is always between the two values
+	///
+	/// # Arguments
+	/// * `other` - The inverse absorbed dose rate value to interpolate towards (returned when `t` is 1 or more)
+	/// * `t` - The interpolation factor, where 0 returns this value and 1 returns `other`
+	pub fn lerp_clamped(self, other: Self, t: f64) -> Self {
+		self.lerp(other, t.clamp(0.0, 1.0))
+	}
+	
+	/// Returns a copy of this inverse absorbed dose rate value in seconds per milligray
+	/// 
+	/// *Note: This method is not available for `f32` and other number types lacking the `From<f64>` trait*
+	pub fn to_s_per_mGy(&self) -> T {
+		return self.s_per_Gy.clone() * T::from(0.001_f64);
+	}
+
+	/// Returns a new inverse absorbed dose rate value from the given number of seconds per milligray
+	/// 
+	/// *Note: This method is not available for `f32` and other number types lacking the `From<f64>` trait*
+	///
+	/// # Arguments
+	/// * `s_per_mGy` - Any number-like type, representing a quantity of seconds per milligray
+	pub fn from_s_per_mGy(s_per_mGy: T) -> Self {
+		InverseAbsorbedDoseRate{s_per_Gy: s_per_mGy * T::from(1000.0_f64)}
+	}
+
+	/// Returns a copy of this inverse absorbed dose rate value in seconds per microgray
+	/// 
+	/// *Note: This method is not available for `f32` and other number types lacking the `From<f64>` trait*
+	pub fn to_s_per_uGy(&self) -> T {
+		return self.s_per_Gy.clone() * T::from(1e-06_f64);
+	}
+
+	/// Returns a new inverse absorbed dose rate value from the given number of seconds per microgray
+	/// 
+	/// *Note: This method is not available for `f32` and other number types lacking the `From<f64>` trait*
+	///
+	/// # Arguments
+	/// * `s_per_uGy` - Any number-like type, representing a quantity of seconds per microgray
+	pub fn from_s_per_uGy(s_per_uGy: T) -> Self {
+		InverseAbsorbedDoseRate{s_per_Gy: s_per_uGy * T::from(1000000.0_f64)}
+	}
+
+	/// Returns a copy of this inverse absorbed dose rate value in hours per gray
+	/// 
+	/// *Note: This method is not available for `f32` and other number types lacking the `From<f64>` trait*
+	pub fn to_h_per_Gy(&self) -> T {
+		return self.s_per_Gy.clone() * T::from(0.0002777777777777778_f64);
+	}
+
+	/// Returns a new inverse absorbed dose rate value from the given number of hours per gray
+	/// 
+	/// *Note: This method is not available for `f32` and other number types lacking the `From<f64>` trait*
+	///
+	/// # Arguments
+	/// * `h_per_Gy` - Any number-like type, representing a quantity of hours per gray
+	pub fn from_h_per_Gy(h_per_Gy: T) -> Self {
+		InverseAbsorbedDoseRate{s_per_Gy: h_per_Gy * T::from(3600.0_f64)}
+	}
+
+	/// Returns a copy of this inverse absorbed dose rate value in hours per milligray
+	/// 
+	/// *Note: This method is not available for `f32` and other number types lacking the `From<f64>` trait*
+	pub fn to_h_per_mGy(&self) -> T {
+		return self.s_per_Gy.clone() * T::from(2.7777777777777776e-07_f64);
+	}
+
+	/// Returns a new inverse absorbed dose rate value from the given number of hours per milligray
+	/// 
+	/// *Note: This method is not available for `f32` and other number types lacking the `From<f64>` trait*
+	///
+	/// # Arguments
+	/// * `h_per_mGy` - Any number-like type, representing a quantity of hours per milligray
+	pub fn from_h_per_mGy(h_per_mGy: T) -> Self {
+		InverseAbsorbedDoseRate{s_per_Gy: h_per_mGy * T::from(3600000.0_f64)}
+	}
+
+	/// Returns a copy of this inverse absorbed dose rate value in hours per microgray
+	/// 
+	/// *Note: This method is not available for `f32` and other number types lacking the `From<f64>` trait*
+	pub fn to_h_per_uGy(&self) -> T {
+		return self.s_per_Gy.clone() * T::from(2.7777777777777777e-10_f64);
+	}
+
+	/// Returns a new inverse absorbed dose rate value from the given number of hours per microgray
+	/// 
+	/// *Note: This method is not available for `f32` and other number types lacking the `From<f64>` trait*
+	///
+	/// # Arguments
+	/// * `h_per_uGy` - Any number-like type, representing a quantity of hours per microgray
+	pub fn from_h_per_uGy(h_per_uGy: T) -> Self {
+		InverseAbsorbedDoseRate{s_per_Gy: h_per_uGy * T::from(3600000000.0_f64)}
+	}
+
+	/// Returns a copy of this inverse absorbed dose rate value in hours per nanogray
+	/// 
+	/// *Note: This method is not available for `f32` and other number types lacking the `From<f64>` trait*
+	pub fn to_h_per_nGy(&self) -> T {
+		return self.s_per_Gy.clone() * T::from(2.777777777777778e-13_f64);
+	}
+
+	/// Returns a new inverse absorbed dose rate value from the given number of hours per nanogray
+	/// 
+	/// *Note: This method is not available for `f32` and other number types lacking the `From<f64>` trait*
+	///
+	/// # Arguments
+	/// * `h_per_nGy` - Any number-like type, representing a quantity of hours per nanogray
+	pub fn from_h_per_nGy(h_per_nGy: T) -> Self {
+		InverseAbsorbedDoseRate{s_per_Gy: h_per_nGy * T::from(3600000000000.0_f64)}
+	}
+
+}
+
+
+/// Multiplying a unit value by a scalar value returns a unit value
+#[cfg(feature="num-bigfloat")]
+impl core::ops::Mul<InverseAbsorbedDoseRate<num_bigfloat::BigFloat>> for num_bigfloat::BigFloat {
+	type Output = InverseAbsorbedDoseRate<num_bigfloat::BigFloat>;
+	fn mul(self, rhs: InverseAbsorbedDoseRate<num_bigfloat::BigFloat>) -> Self::Output {
+		InverseAbsorbedDoseRate{s_per_Gy: self * rhs.s_per_Gy}
+	}
+}
+/// Multiplying a unit value by a scalar value returns a unit value
+#[cfg(feature="num-bigfloat")]
+impl core::ops::Mul<InverseAbsorbedDoseRate<num_bigfloat::BigFloat>> for &num_bigfloat::BigFloat {
+	type Output = InverseAbsorbedDoseRate<num_bigfloat::BigFloat>;
+	fn mul(self, rhs: InverseAbsorbedDoseRate<num_bigfloat::BigFloat>) -> Self::Output {
+		InverseAbsorbedDoseRate{s_per_Gy: self.clone() * rhs.s_per_Gy}
+	}
+}
+/// Multiplying a unit value by a scalar value returns a unit value
+#[cfg(feature="num-bigfloat")]
+impl core::ops::Mul<&InverseAbsorbedDoseRate<num_bigfloat::BigFloat>> for num_bigfloat::BigFloat {
+	type Output = InverseAbsorbedDoseRate<num_bigfloat::BigFloat>;
+	fn mul(self, rhs: &InverseAbsorbedDoseRate<num_bigfloat::BigFloat>) -> Self::Output {
+		InverseAbsorbedDoseRate{s_per_Gy: self * rhs.s_per_Gy.clone()}
+	}
+}
+/// Multiplying a unit value by a scalar value returns a unit value
+#[cfg(feature="num-bigfloat")]
+impl core::ops::Mul<&InverseAbsorbedDoseRate<num_bigfloat::BigFloat>> for &num_bigfloat::BigFloat {
+	type Output = InverseAbsorbedDoseRate<num_bigfloat::BigFloat>;
+	fn mul(self, rhs: &InverseAbsorbedDoseRate<num_bigfloat::BigFloat>) -> Self::Output {
+		InverseAbsorbedDoseRate{s_per_Gy: self.clone() * rhs.s_per_Gy.clone()}
+	}
+}
+
+/// Multiplying a unit value by a scalar value returns a unit value
+#[cfg(feature="num-complex")]
+impl core::ops::Mul<InverseAbsorbedDoseRate<num_complex::Complex32>> for num_complex::Complex32 {
+	type Output = InverseAbsorbedDoseRate<num_complex::Complex32>;
+	fn mul(self, rhs: InverseAbsorbedDoseRate<num_complex::Complex32>) -> Self::Output {
+		InverseAbsorbedDoseRate{s_per_Gy: self * rhs.s_per_Gy}
+	}
+}
+/// Multiplying a unit value by a scalar value returns a unit value
+#[cfg(feature="num-complex")]
+impl core::ops::Mul<InverseAbsorbedDoseRate<num_complex::Complex32>> for &num_complex::Complex32 {
+	type Output = InverseAbsorbedDoseRate<num_complex::Complex32>;
+	fn mul(self, rhs: InverseAbsorbedDoseRate<num_complex::Complex32>) -> Self::Output {
+		InverseAbsorbedDoseRate{s_per_Gy: self.clone() * rhs.s_per_Gy}
+	}
+}
+/// Multiplying a unit value by a scalar value returns a unit value
+#[cfg(feature="num-complex")]
+impl core::ops::Mul<&InverseAbsorbedDoseRate<num_complex::Complex32>> for num_complex::Complex32 {
+	type Output = InverseAbsorbedDoseRate<num_complex::Complex32>;
+	fn mul(self, rhs: &InverseAbsorbedDoseRate<num_complex::Complex32>) -> Self::Output {
+		InverseAbsorbedDoseRate{s_per_Gy: self * rhs.s_per_Gy.clone()}
+	}
+}
+/// Multiplying a unit value by a scalar value returns a unit value
+#[cfg(feature="num-complex")]
+impl core::ops::Mul<&InverseAbsorbedDoseRate<num_complex::Complex32>> for &num_complex::Complex32 {
+	type Output = InverseAbsorbedDoseRate<num_complex::Complex32>;
+	fn mul(self, rhs: &InverseAbsorbedDoseRate<num_complex::Complex32>) -> Self::Output {
+		InverseAbsorbedDoseRate{s_per_Gy: self.clone() * rhs.s_per_Gy.clone()}
+	}
+}
+
+/// Multiplying a unit value by a scalar value returns a unit value
+#[cfg(feature="num-complex")]
+impl core::ops::Mul<InverseAbsorbedDoseRate<num_complex::Complex64>> for num_complex::Complex64 {
+	type Output = InverseAbsorbedDoseRate<num_complex::Complex64>;
+	fn mul(self, rhs: InverseAbsorbedDoseRate<num_complex::Complex64>) -> Self::Output {
+		InverseAbsorbedDoseRate{s_per_Gy: self * rhs.s_per_Gy}
+	}
+}
+/// Multiplying a unit value by a scalar value returns a unit value
+#[cfg(feature="num-complex")]
+impl core::ops::Mul<InverseAbsorbedDoseRate<num_complex::Complex64>> for &num_complex::Complex64 {
+	type Output = InverseAbsorbedDoseRate<num_complex::Complex64>;
+	fn mul(self, rhs: InverseAbsorbedDoseRate<num_complex::Complex64>) -> Self::Output {
+		InverseAbsorbedDoseRate{s_per_Gy: self.clone() * rhs.s_per_Gy}
+	}
+}
+/// Multiplying a unit value by a scalar value returns a unit value
+#[cfg(feature="num-complex")]
+impl core::ops::Mul<&InverseAbsorbedDoseRate<num_complex::Complex64>> for num_complex::Complex64 {
+	type Output = InverseAbsorbedDoseRate<num_complex::Complex64>;
+	fn mul(self, rhs: &InverseAbsorbedDoseRate<num_complex::Complex64>) -> Self::Output {
+		InverseAbsorbedDoseRate{s_per_Gy: self * rhs.s_per_Gy.clone()}
+	}
+}
+/// Multiplying a unit value by a scalar value returns a unit value
+#[cfg(feature="num-complex")]
+impl core::ops::Mul<&InverseAbsorbedDoseRate<num_complex::Complex64>> for &num_complex::Complex64 {
+	type Output = InverseAbsorbedDoseRate<num_complex::Complex64>;
+	fn mul(self, rhs: &InverseAbsorbedDoseRate<num_complex::Complex64>) -> Self::Output {
+		InverseAbsorbedDoseRate{s_per_Gy: self.clone() * rhs.s_per_Gy.clone()}
+	}
+}
+
+/// Multiplying a unit value by a scalar value returns a unit value
+#[cfg(feature="num-rational")]
+impl core::ops::Mul<InverseAbsorbedDoseRate<num_rational::Rational64>> for num_rational::Rational64 {
+	type Output = InverseAbsorbedDoseRate<num_rational::Rational64>;
+	fn mul(self, rhs: InverseAbsorbedDoseRate<num_rational::Rational64>) -> Self::Output {
+		InverseAbsorbedDoseRate{s_per_Gy: self * rhs.s_per_Gy}
+	}
+}
+/// Multiplying a unit value by a scalar value returns a unit value
+#[cfg(feature="num-rational")]
+impl core::ops::Mul<InverseAbsorbedDoseRate<num_rational::Rational64>> for &num_rational::Rational64 {
+	type Output = InverseAbsorbedDoseRate<num_rational::Rational64>;
+	fn mul(self, rhs: InverseAbsorbedDoseRate<num_rational::Rational64>) -> Self::Output {
+		InverseAbsorbedDoseRate{s_per_Gy: self.clone() * rhs.s_per_Gy}
+	}
+}
+/// Multiplying a unit value by a scalar value returns a unit value
+#[cfg(feature="num-rational")]
+impl core::ops::Mul<&InverseAbsorbedDoseRate<num_rational::Rational64>> for num_rational::Rational64 {
+	type Output = InverseAbsorbedDoseRate<num_rational::Rational64>;
+	fn mul(self, rhs: &InverseAbsorbedDoseRate<num_rational::Rational64>) -> Self::Output {
+		InverseAbsorbedDoseRate{s_per_Gy: self * rhs.s_per_Gy.clone()}
+	}
+}
+/// Multiplying a unit value by a scalar value returns a unit value
+#[cfg(feature="num-rational")]
+impl core::ops::Mul<&InverseAbsorbedDoseRate<num_rational::Rational64>> for &num_rational::Rational64 {
+	type Output = InverseAbsorbedDoseRate<num_rational::Rational64>;
+	fn mul(self, rhs: &InverseAbsorbedDoseRate<num_rational::Rational64>) -> Self::Output {
+		InverseAbsorbedDoseRate{s_per_Gy: self.clone() * rhs.s_per_Gy.clone()}
+	}
+}
+
+/// Multiplying a unit value by a scalar value returns a unit value
+#[cfg(feature="num-rational")]
+impl core::ops::Mul<InverseAbsorbedDoseRate<num_rational::BigRational>> for num_rational::BigRational {
+	type Output = InverseAbsorbedDoseRate<num_rational::BigRational>;
+	fn mul(self, rhs: InverseAbsorbedDoseRate<num_rational::BigRational>) -> Self::Output {
+		InverseAbsorbedDoseRate{s_per_Gy: self * rhs.s_per_Gy}
+	}
+}
+/// Multiplying a unit value by a scalar value returns a unit value
+#[cfg(feature="num-rational")]
+impl core::ops::Mul<InverseAbsorbedDoseRate<num_rational::BigRational>> for &num_rational::BigRational {
+	type Output = InverseAbsorbedDoseRate<num_rational::BigRational>;
+	fn mul(self, rhs: InverseAbsorbedDoseRate<num_rational::BigRational>) -> Self::Output {
+		InverseAbsorbedDoseRate{s_per_Gy: self.clone() * rhs.s_per_Gy}
+	}
+}
+/// Multiplying a unit value by a scalar value returns a unit value
+#[cfg(feature="num-rational")]
+impl core::ops::Mul<&InverseAbsorbedDoseRate<num_rational::BigRational>> for num_rational::BigRational {
+	type Output = InverseAbsorbedDoseRate<num_rational::BigRational>;
+	fn mul(self, rhs: &InverseAbsorbedDoseRate<num_rational::BigRational>) -> Self::Output {
+		InverseAbsorbedDoseRate{s_per_Gy: self * rhs.s_per_Gy.clone()}
+	}
+}
+/// Multiplying a unit value by a scalar value returns a unit value
+#[cfg(feature="num-rational")]
+impl core::ops::Mul<&InverseAbsorbedDoseRate<num_rational::BigRational>> for &num_rational::BigRational {
+	type Output = InverseAbsorbedDoseRate<num_rational::BigRational>;
+	fn mul(self, rhs: &InverseAbsorbedDoseRate<num_rational::BigRational>) -> Self::Output {
+		InverseAbsorbedDoseRate{s_per_Gy: self.clone() * rhs.s_per_Gy.clone()}
+	}
+}
+
+
+
+
+
+/// Multiplying a InverseAbsorbedDoseRate by an [nalgebra](https://crates.io/crates/nalgebra) 
+/// vector or matrix of unitless values returns a vector or matrix of InverseAbsorbedDoseRate 
+/// values (eg a direction vector multiplied by a InverseAbsorbedDoseRate)
+#[cfg(feature="nalgebra")]
+impl<T, R, C, S> core::ops::Mul<nalgebra::Matrix<T, R, C, S>> for InverseAbsorbedDoseRate<T> where 
+	T: NumLike+nalgebra::Scalar, R: nalgebra::Dim, C: nalgebra::Dim, S: nalgebra::RawStorage<T, R, C>, 
+	nalgebra::DefaultAllocator: nalgebra::allocator::Allocator<R, C> {
+	type Output = nalgebra::OMatrix<InverseAbsorbedDoseRate<T>, R, C>;
+	fn mul(self, rhs: nalgebra::Matrix<T, R, C, S>) -> Self::Output {
+		rhs.map(|x| InverseAbsorbedDoseRate{s_per_Gy: self.s_per_Gy.clone() * x})
+	}
+}
+/// Multiplying an [nalgebra](https://crates.io/crates/nalgebra) vector or matrix of 
+/// unitless values by a InverseAbsorbedDoseRate returns a vector or matrix of InverseAbsorbedDoseRate values
+#[cfg(feature="nalgebra")]
+impl<T, R, C, S> core::ops::Mul<InverseAbsorbedDoseRate<T>> for nalgebra::Matrix<T, R, C, S> where 
+	T: NumLike+nalgebra::Scalar, R: nalgebra::Dim, C: nalgebra::Dim, S: nalgebra::RawStorage<T, R, C>, 
+	nalgebra::DefaultAllocator: nalgebra::allocator::Allocator<R, C> {
+	type Output = nalgebra::OMatrix<InverseAbsorbedDoseRate<T>, R, C>;
+	fn mul(self, rhs: InverseAbsorbedDoseRate<T>) -> Self::Output {
+		self.map(|x| InverseAbsorbedDoseRate{s_per_Gy: x * rhs.s_per_Gy.clone()})
+	}
+}
+/// Dividing an [nalgebra](https://crates.io/crates/nalgebra) vector or matrix of 
+/// InverseAbsorbedDoseRate values by a InverseAbsorbedDoseRate returns a vector or matrix of unitless values
+#[cfg(feature="nalgebra")]
+impl<T, R, C, S> core::ops::Div<InverseAbsorbedDoseRate<T>> for nalgebra::Matrix<InverseAbsorbedDoseRate<T>, R, C, S> where 
+	T: NumLike+nalgebra::Scalar, R: nalgebra::Dim, C: nalgebra::Dim, S: nalgebra::RawStorage<InverseAbsorbedDoseRate<T>, R, C>, 
+	nalgebra::DefaultAllocator: nalgebra::allocator::Allocator<R, C> {
+	type Output = nalgebra::OMatrix<T, R, C>;
+	fn div(self, rhs: InverseAbsorbedDoseRate<T>) -> Self::Output {
+		self.map(|x| x.s_per_Gy / rhs.s_per_Gy.clone())
+	}
+}
+
+
+
+impl<T> InverseAbsorbedDoseRate<T> where T: NumLike+From<u8> {
+	/// Returns the reciprocal of this InverseAbsorbedDoseRate value (ie 1 divided by 
+	/// this value) as a value of type AbsorbedDoseRate
+	pub fn recip(self) -> AbsorbedDoseRate<T> {
+		AbsorbedDoseRate{Gy_per_s: T::from(1u8) / self.s_per_Gy}
+	}
+}
+
+// 1/InverseAbsorbedDoseRate -> AbsorbedDoseRate
+/// Dividing a scalar value by a InverseAbsorbedDoseRate unit value returns a value of type AbsorbedDoseRate
+impl<T> core::ops::Div<InverseAbsorbedDoseRate<T>> for f64 where T: NumLike+From<f64> {
+	type Output = AbsorbedDoseRate<T>;
+	fn div(self, rhs: InverseAbsorbedDoseRate<T>) -> Self::Output {
+		AbsorbedDoseRate{Gy_per_s: T::from(self) / rhs.s_per_Gy}
+	}
+}
+/// Dividing a scalar value by a InverseAbsorbedDoseRate unit value returns a value of type AbsorbedDoseRate
+impl<T> core::ops::Div<InverseAbsorbedDoseRate<T>> for &f64 where T: NumLike+From<f64> {
+	type Output = AbsorbedDoseRate<T>;
+	fn div(self, rhs: InverseAbsorbedDoseRate<T>) -> Self::Output {
+		AbsorbedDoseRate{Gy_per_s: T::from(self.clone()) / rhs.s_per_Gy}
+	}
+}
+/// Dividing a scalar value by a InverseAbsorbedDoseRate unit value returns a value of type AbsorbedDoseRate
+impl<T> core::ops::Div<&InverseAbsorbedDoseRate<T>> for f64 where T: NumLike+From<f64> {
+	type Output = AbsorbedDoseRate<T>;
+	fn div(self, rhs: &InverseAbsorbedDoseRate<T>) -> Self::Output {
+		AbsorbedDoseRate{Gy_per_s: T::from(self) / rhs.s_per_Gy.clone()}
+	}
+}
+/// Dividing a scalar value by a InverseAbsorbedDoseRate unit value returns a value of type AbsorbedDoseRate
+impl<T> core::ops::Div<&InverseAbsorbedDoseRate<T>> for &f64 where T: NumLike+From<f64> {
+	type Output = AbsorbedDoseRate<T>;
+	fn div(self, rhs: &InverseAbsorbedDoseRate<T>) -> Self::Output {
+		AbsorbedDoseRate{Gy_per_s: T::from(self.clone()) / rhs.s_per_Gy.clone()}
+	}
+}
+
+// 1/InverseAbsorbedDoseRate -> AbsorbedDoseRate
+/// Dividing a scalar value by a InverseAbsorbedDoseRate unit value returns a value of type AbsorbedDoseRate
+impl<T> core::ops::Div<InverseAbsorbedDoseRate<T>> for f32 where T: NumLike+From<f32> {
+	type Output = AbsorbedDoseRate<T>;
+	fn div(self, rhs: InverseAbsorbedDoseRate<T>) -> Self::Output {
+		AbsorbedDoseRate{Gy_per_s: T::from(self) / rhs.s_per_Gy}
+	}
+}
+/// Dividing a scalar value by a InverseAbsorbedDoseRate unit value returns a value of type AbsorbedDoseRate
+impl<T> core::ops::Div<InverseAbsorbedDoseRate<T>> for &f32 where T: NumLike+From<f32> {
+	type Output = AbsorbedDoseRate<T>;
+	fn div(self, rhs: InverseAbsorbedDoseRate<T>) -> Self::Output {
+		AbsorbedDoseRate{Gy_per_s: T::from(self.clone()) / rhs.s_per_Gy}
+	}
+}
+/// Dividing a scalar value by a InverseAbsorbedDoseRate unit value returns a value of type AbsorbedDoseRate
+impl<T> core::ops::Div<&InverseAbsorbedDoseRate<T>> for f32 where T: NumLike+From<f32> {
+	type Output = AbsorbedDoseRate<T>;
+	fn div(self, rhs: &InverseAbsorbedDoseRate<T>) -> Self::Output {
+		AbsorbedDoseRate{Gy_per_s: T::from(self) / rhs.s_per_Gy.clone()}
+	}
+}
+/// Dividing a scalar value by a InverseAbsorbedDoseRate unit value returns a value of type AbsorbedDoseRate
+impl<T> core::ops::Div<&InverseAbsorbedDoseRate<T>> for &f32 where T: NumLike+From<f32> {
+	type Output = AbsorbedDoseRate<T>;
+	fn div(self, rhs: &InverseAbsorbedDoseRate<T>) -> Self::Output {
+		AbsorbedDoseRate{Gy_per_s: T::from(self.clone()) / rhs.s_per_Gy.clone()}
+	}
+}
+
+// 1/InverseAbsorbedDoseRate -> AbsorbedDoseRate
+/// Dividing a scalar value by a InverseAbsorbedDoseRate unit value returns a value of type AbsorbedDoseRate
+impl<T> core::ops::Div<InverseAbsorbedDoseRate<T>> for i64 where T: NumLike+From<i64> {
+	type Output = AbsorbedDoseRate<T>;
+	fn div(self, rhs: InverseAbsorbedDoseRate<T>) -> Self::Output {
+		AbsorbedDoseRate{Gy_per_s: T::from(self) / rhs.s_per_Gy}
+	}
+}
+/// Dividing a scalar value by a InverseAbsorbedDoseRate unit value returns a value of type AbsorbedDoseRate
+impl<T> core::ops::Div<InverseAbsorbedDoseRate<T>> for &i64 where T: NumLike+From<i64> {
+	type Output = AbsorbedDoseRate<T>;
+	fn div(self, rhs: InverseAbsorbedDoseRate<T>) -> Self::Output {
+		AbsorbedDoseRate{Gy_per_s: T::from(self.clone()) / rhs.s_per_Gy}
+	}
+}
+/// Dividing a scalar value by a InverseAbsorbedDoseRate unit value returns a value of type AbsorbedDoseRate
+impl<T> core::ops::Div<&InverseAbsorbedDoseRate<T>> for i64 where T: NumLike+From<i64> {
+	type Output = AbsorbedDoseRate<T>;
+	fn div(self, rhs: &InverseAbsorbedDoseRate<T>) -> Self::Output {
+		AbsorbedDoseRate{Gy_per_s: T::from(self) / rhs.s_per_Gy.clone()}
+	}
+}
+/// Dividing a scalar value by a InverseAbsorbedDoseRate unit value returns a value of type AbsorbedDoseRate
+impl<T> core::ops::Div<&InverseAbsorbedDoseRate<T>> for &i64 where T: NumLike+From<i64> {
+	type Output = AbsorbedDoseRate<T>;
+	fn div(self, rhs: &InverseAbsorbedDoseRate<T>) -> Self::Output {
+		AbsorbedDoseRate{Gy_per_s: T::from(self.clone()) / rhs.s_per_Gy.clone()}
+	}
+}
+
+// 1/InverseAbsorbedDoseRate -> AbsorbedDoseRate
+/// Dividing a scalar value by a InverseAbsorbedDoseRate unit value returns a value of type AbsorbedDoseRate
+impl<T> core::ops::Div<InverseAbsorbedDoseRate<T>> for i32 where T: NumLike+From<i32> {
+	type Output = AbsorbedDoseRate<T>;
+	fn div(self, rhs: InverseAbsorbedDoseRate<T>) -> Self::Output {
+		AbsorbedDoseRate{Gy_per_s: T::from(self) / rhs.s_per_Gy}
+	}
+}
+/// Dividing a scalar value by a InverseAbsorbedDoseRate unit value returns a value of type AbsorbedDoseRate
+impl<T> core::ops::Div<InverseAbsorbedDoseRate<T>> for &i32 where T: NumLike+From<i32> {
+	type Output = AbsorbedDoseRate<T>;
+	fn div(self, rhs: InverseAbsorbedDoseRate<T>) -> Self::Output {
+		AbsorbedDoseRate{Gy_per_s: T::from(self.clone()) / rhs.s_per_Gy}
+	}
+}
+/// Dividing a scalar value by a InverseAbsorbedDoseRate unit value returns a value of type AbsorbedDoseRate
+impl<T> core::ops::Div<&InverseAbsorbedDoseRate<T>> for i32 where T: NumLike+From<i32> {
+	type Output = AbsorbedDoseRate<T>;
+	fn div(self, rhs: &InverseAbsorbedDoseRate<T>) -> Self::Output {
+		AbsorbedDoseRate{Gy_per_s: T::from(self) / rhs.s_per_Gy.clone()}
+	}
+}
+/// Dividing a scalar value by a InverseAbsorbedDoseRate unit value returns a value of type AbsorbedDoseRate
+impl<T> core::ops::Div<&InverseAbsorbedDoseRate<T>> for &i32 where T: NumLike+From<i32> {
+	type Output = AbsorbedDoseRate<T>;
+	fn div(self, rhs: &InverseAbsorbedDoseRate<T>) -> Self::Output {
+		AbsorbedDoseRate{Gy_per_s: T::from(self.clone()) / rhs.s_per_Gy.clone()}
+	}
+}
+
+// 1/InverseAbsorbedDoseRate -> AbsorbedDoseRate
+/// Dividing a scalar value by a InverseAbsorbedDoseRate unit value returns a value of type AbsorbedDoseRate
+#[cfg(feature="num-bigfloat")]
+impl<T> core::ops::Div<InverseAbsorbedDoseRate<T>> for num_bigfloat::BigFloat where T: NumLike+From<num_bigfloat::BigFloat> {
+	type Output = AbsorbedDoseRate<T>;
+	fn div(self, rhs: InverseAbsorbedDoseRate<T>) -> Self::Output {
+		AbsorbedDoseRate{Gy_per_s: T::from(self) / rhs.s_per_Gy}
+	}
+}
+/// Dividing a scalar value by a InverseAbsorbedDoseRate unit value returns a value of type AbsorbedDoseRate
+#[cfg(feature="num-bigfloat")]
+impl<T> core::ops::Div<InverseAbsorbedDoseRate<T>> for &num_bigfloat::BigFloat where T: NumLike+From<num_bigfloat::BigFloat> {
+	type Output = AbsorbedDoseRate<T>;
+	fn div(self, rhs: InverseAbsorbedDoseRate<T>) -> Self::Output {
+		AbsorbedDoseRate{Gy_per_s: T::from(self.clone()) / rhs.s_per_Gy}
+	}
+}
+/// Dividing a scalar value by a InverseAbsorbedDoseRate unit value returns a value of type AbsorbedDoseRate
+#[cfg(feature="num-bigfloat")]
+impl<T> core::ops::Div<&InverseAbsorbedDoseRate<T>> for num_bigfloat::BigFloat where T: NumLike+From<num_bigfloat::BigFloat> {
+	type Output = AbsorbedDoseRate<T>;
+	fn div(self, rhs: &InverseAbsorbedDoseRate<T>) -> Self::Output {
+		AbsorbedDoseRate{Gy_per_s: T::from(self) / rhs.s_per_Gy.clone()}
+	}
+}
+/// Dividing a scalar value by a InverseAbsorbedDoseRate unit value returns a value of type AbsorbedDoseRate
+#[cfg(feature="num-bigfloat")]
+impl<T> core::ops::Div<&InverseAbsorbedDoseRate<T>> for &num_bigfloat::BigFloat where T: NumLike+From<num_bigfloat::BigFloat> {
+	type Output = AbsorbedDoseRate<T>;
+	fn div(self, rhs: &InverseAbsorbedDoseRate<T>) -> Self::Output {
+		AbsorbedDoseRate{Gy_per_s: T::from(self.clone()) / rhs.s_per_Gy.clone()}
+	}
+}
+
+// 1/InverseAbsorbedDoseRate -> AbsorbedDoseRate
+/// Dividing a scalar value by a InverseAbsorbedDoseRate unit value returns a value of type AbsorbedDoseRate
+#[cfg(feature="num-complex")]
+impl<T> core::ops::Div<InverseAbsorbedDoseRate<T>> for num_complex::Complex32 where T: NumLike+From<num_complex::Complex32> {
+	type Output = AbsorbedDoseRate<T>;
+	fn div(self, rhs: InverseAbsorbedDoseRate<T>) -> Self::Output {
+		AbsorbedDoseRate{Gy_per_s: T::from(self) / rhs.s_per_Gy}
+	}
+}
+/// Dividing a scalar value by a InverseAbsorbedDoseRate unit value returns a value of type AbsorbedDoseRate
+#[cfg(feature="num-complex")]
+impl<T> core::ops::Div<InverseAbsorbedDoseRate<T>> for &num_complex::Complex32 where T: NumLike+From<num_complex::Complex32> {
+	type Output = AbsorbedDoseRate<T>;
+	fn div(self, rhs: InverseAbsorbedDoseRate<T>) -> Self::Output {
+		AbsorbedDoseRate{Gy_per_s: T::from(self.clone()) / rhs.s_per_Gy}
+	}
+}
+/// Dividing a scalar value by a InverseAbsorbedDoseRate unit value returns a value of type AbsorbedDoseRate
+#[cfg(feature="num-complex")]
+impl<T> core::ops::Div<&InverseAbsorbedDoseRate<T>> for num_complex::Complex32 where T: NumLike+From<num_complex::Complex32> {
+	type Output = AbsorbedDoseRate<T>;
+	fn div(self, rhs: &InverseAbsorbedDoseRate<T>) -> Self::Output {
+		AbsorbedDoseRate{Gy_per_s: T::from(self) / rhs.s_per_Gy.clone()}
+	}
+}
+/// Dividing a scalar value by a InverseAbsorbedDoseRate unit value returns a value of type AbsorbedDoseRate
+#[cfg(feature="num-complex")]
+impl<T> core::ops::Div<&InverseAbsorbedDoseRate<T>> for &num_complex::Complex32 where T: NumLike+From<num_complex::Complex32> {
+	type Output = AbsorbedDoseRate<T>;
+	fn div(self, rhs: &InverseAbsorbedDoseRate<T>) -> Self::Output {
+		AbsorbedDoseRate{Gy_per_s: T::from(self.clone()) / rhs.s_per_Gy.clone()}
+	}
+}
+
+// 1/InverseAbsorbedDoseRate -> AbsorbedDoseRate
+/// Dividing a scalar value by a InverseAbsorbedDoseRate unit value returns a value of type AbsorbedDoseRate
+#[cfg(feature="num-complex")]
+impl<T> core::ops::Div<InverseAbsorbedDoseRate<T>> for num_complex::Complex64 where T: NumLike+From<num_complex::Complex64> {
+	type Output = AbsorbedDoseRate<T>;
+	fn div(self, rhs: InverseAbsorbedDoseRate<T>) -> Self::Output {
+		AbsorbedDoseRate{Gy_per_s: T::from(self) / rhs.s_per_Gy}
+	}
+}
+/// Dividing a scalar value by a InverseAbsorbedDoseRate unit value returns a value of type AbsorbedDoseRate
+#[cfg(feature="num-complex")]
+impl<T> core::ops::Div<InverseAbsorbedDoseRate<T>> for &num_complex::Complex64 where T: NumLike+From<num_complex::Complex64> {
+	type Output = AbsorbedDoseRate<T>;
+	fn div(self, rhs: InverseAbsorbedDoseRate<T>) -> Self::Output {
+		AbsorbedDoseRate{Gy_per_s: T::from(self.clone()) / rhs.s_per_Gy}
+	}
+}
+/// Dividing a scalar value by a InverseAbsorbedDoseRate unit value returns a value of type AbsorbedDoseRate
+#[cfg(feature="num-complex")]
+impl<T> core::ops::Div<&InverseAbsorbedDoseRate<T>> for num_complex::Complex64 where T: NumLike+From<num_complex::Complex64> {
+	type Output = AbsorbedDoseRate<T>;
+	fn div(self, rhs: &InverseAbsorbedDoseRate<T>) -> Self::Output {
+		AbsorbedDoseRate{Gy_per_s: T::from(self) / rhs.s_per_Gy.clone()}
+	}
+}
+/// Dividing a scalar value by a InverseAbsorbedDoseRate unit value returns a value of type AbsorbedDoseRate
+#[cfg(feature="num-complex")]
+impl<T> core::ops::Div<&InverseAbsorbedDoseRate<T>> for &num_complex::Complex64 where T: NumLike+From<num_complex::Complex64> {
+	type Output = AbsorbedDoseRate<T>;
+	fn div(self, rhs: &InverseAbsorbedDoseRate<T>) -> Self::Output {
+		AbsorbedDoseRate{Gy_per_s: T::from(self.clone()) / rhs.s_per_Gy.clone()}
+	}
+}
+
+// 1/InverseAbsorbedDoseRate -> AbsorbedDoseRate
+/// Dividing a scalar value by a InverseAbsorbedDoseRate unit value returns a value of type AbsorbedDoseRate
+#[cfg(feature="num-rational")]
+impl<T> core::ops::Div<InverseAbsorbedDoseRate<T>> for num_rational::Rational64 where T: NumLike+From<num_rational::Rational64> {
+	type Output = AbsorbedDoseRate<T>;
+	fn div(self, rhs: InverseAbsorbedDoseRate<T>) -> Self::Output {
+		AbsorbedDoseRate{Gy_per_s: T::from(self) / rhs.s_per_Gy}
+	}
+}
+/// Dividing a scalar value by a InverseAbsorbedDoseRate unit value returns a value of type AbsorbedDoseRate
+#[cfg(feature="num-rational")]
+impl<T> core::ops::Div<InverseAbsorbedDoseRate<T>> for &num_rational::Rational64 where T: NumLike+From<num_rational::Rational64> {
+	type Output = AbsorbedDoseRate<T>;
+	fn div(self, rhs: InverseAbsorbedDoseRate<T>) -> Self::Output {
+		AbsorbedDoseRate{Gy_per_s: T::from(self.clone()) / rhs.s_per_Gy}
+	}
+}
+/// Dividing a scalar value by a InverseAbsorbedDoseRate unit value returns a value of type AbsorbedDoseRate
+#[cfg(feature="num-rational")]
+impl<T> core::ops::Div<&InverseAbsorbedDoseRate<T>> for num_rational::Rational64 where T: NumLike+From<num_rational::Rational64> {
+	type Output = AbsorbedDoseRate<T>;
+	fn div(self, rhs: &InverseAbsorbedDoseRate<T>) -> Self::Output {
+		AbsorbedDoseRate{Gy_per_s: T::from(self) / rhs.s_per_Gy.clone()}
+	}
+}
+/// Dividing a scalar value by a InverseAbsorbedDoseRate unit value returns a value of type AbsorbedDoseRate
+#[cfg(feature="num-rational")]
+impl<T> core::ops::Div<&InverseAbsorbedDoseRate<T>> for &num_rational::Rational64 where T: NumLike+From<num_rational::Rational64> {
+	type Output = AbsorbedDoseRate<T>;
+	fn div(self, rhs: &InverseAbsorbedDoseRate<T>) -> Self::Output {
+		AbsorbedDoseRate{Gy_per_s: T::from(self.clone()) / rhs.s_per_Gy.clone()}
+	}
+}
+
+// 1/InverseAbsorbedDoseRate -> AbsorbedDoseRate
+/// Dividing a scalar value by a InverseAbsorbedDoseRate unit value returns a value of type AbsorbedDoseRate
+#[cfg(feature="num-rational")]
+impl<T> core::ops::Div<InverseAbsorbedDoseRate<T>> for num_rational::BigRational where T: NumLike+From<num_rational::BigRational> {
+	type Output = AbsorbedDoseRate<T>;
+	fn div(self, rhs: InverseAbsorbedDoseRate<T>) -> Self::Output {
+		AbsorbedDoseRate{Gy_per_s: T::from(self) / rhs.s_per_Gy}
+	}
+}
+/// Dividing a scalar value by a InverseAbsorbedDoseRate unit value returns a value of type AbsorbedDoseRate
+#[cfg(feature="num-rational")]
+impl<T> core::ops::Div<InverseAbsorbedDoseRate<T>> for &num_rational::BigRational where T: NumLike+From<num_rational::BigRational> {
+	type Output = AbsorbedDoseRate<T>;
+	fn div(self, rhs: InverseAbsorbedDoseRate<T>) -> Self::Output {
+		AbsorbedDoseRate{Gy_per_s: T::from(self.clone()) / rhs.s_per_Gy}
+	}
+}
+/// Dividing a scalar value by a InverseAbsorbedDoseRate unit value returns a value of type AbsorbedDoseRate
+#[cfg(feature="num-rational")]
+impl<T> core::ops::Div<&InverseAbsorbedDoseRate<T>> for num_rational::BigRational where T: NumLike+From<num_rational::BigRational> {
+	type Output = AbsorbedDoseRate<T>;
+	fn div(self, rhs: &InverseAbsorbedDoseRate<T>) -> Self::Output {
+		AbsorbedDoseRate{Gy_per_s: T::from(self) / rhs.s_per_Gy.clone()}
+	}
+}
+/// Dividing a scalar value by a InverseAbsorbedDoseRate unit value returns a value of type AbsorbedDoseRate
+#[cfg(feature="num-rational")]
+impl<T> core::ops::Div<&InverseAbsorbedDoseRate<T>> for &num_rational::BigRational where T: NumLike+From<num_rational::BigRational> {
+	type Output = AbsorbedDoseRate<T>;
+	fn div(self, rhs: &InverseAbsorbedDoseRate<T>) -> Self::Output {
+		AbsorbedDoseRate{Gy_per_s: T::from(self.clone()) / rhs.s_per_Gy.clone()}
+	}
+}
+
+/// The inverse of radiation dose equivalent unit type, defined as inverse sieverts in SI units
+#[derive(UnitStruct, Debug, Clone, Default)]
+#[si(no_display)]
+#[cfg_attr(all(feature="serde", not(feature="serde-unit-tag")), derive(Serialize, Deserialize))]
+#[repr(transparent)]
+pub struct InverseDoseEquivalent<T: NumLike>{
+	/// The value of this Inverse dose equivalent in inverse sieverts
+	pub per_Sv: T
+}
+
+impl<T> InverseDoseEquivalent<T> where T: NumLike {
+
+	/// Returns the standard unit name of inverse dose equivalent: "inverse sieverts"
+	pub fn unit_name() -> &'static str { "inverse sieverts" }
+	
+	/// Returns the abbreviated name or symbol of inverse dose equivalent: "1/Sv" for inverse sieverts
+	pub fn unit_symbol() -> &'static str { "1/Sv" }
+
+	/// Returns the exponents of the SI base units of inverse dose equivalent (s^2/m^2)
+	pub const fn dimension() -> super::Dimension { super::Dimension{length: -2, mass: 0, time: 2, current: 0, temperature: 0, amount: 0, luminosity: 0} }
+
+	/// Returns a wrapper that formats this inverse dose equivalent value with the full unit name instead of 
+	/// the unit symbol, eg "5 inverse sieverts" (the unit name is not singularized for values of 1)
+	pub fn display_named(&self) -> super::NamedDisplay<'_, T> {
+		super::NamedDisplay::new(&self.per_Sv, Self::unit_name())
+	}
+
+	/// Returns a new inverse dose equivalent value from the given number of inverse sieverts (same as 
+	/// `from_per_Sv(...)`), for any number-like type. This is useful in generic code 
+	/// that works with several unit types
+	///
+	/// # Arguments
+	/// * `value` - Any number-like type, representing a quantity of inverse sieverts
+	pub const fn new(value: T) -> Self { InverseDoseEquivalent{per_Sv: value} }
+
+	/// Returns a new inverse dose equivalent value from the given number of inverse sieverts. Unlike
+	/// `from_per_Sv(...)`, this is a `const fn`, so it can be used to declare `const` and `static` values
+	///
+	/// # Arguments
+	/// * `per_Sv` - Any number-like type, representing a quantity of inverse sieverts
+	pub const fn from_per_Sv_const(per_Sv: T) -> Self { InverseDoseEquivalent{per_Sv} }
+
+	/// Returns a inverse dose equivalent value with a different number type, by applying the 
+	/// given function to the value in inverse sieverts (eg `.map(|x| x as f32)` to convert 
+	/// from `f64` to `f32`)
+	///
+	/// # Arguments
+	/// * `f` - Function that converts the number of inverse sieverts to the new number type
+	pub fn map<U, F>(self, f: F) -> InverseDoseEquivalent<U> where U: NumLike, F: FnOnce(T) -> U {
+		InverseDoseEquivalent{per_Sv: f(self.per_Sv)}
+	}
+
+	/// Returns a inverse dose equivalent value with a different number type, converted 
+	/// losslessly with the `From` trait (eg from `f32` to `f64`)
+	pub fn cast<U>(self) -> InverseDoseEquivalent<U> where U: NumLike+From<T> {
+		InverseDoseEquivalent{per_Sv: U::from(self.per_Sv)}
+	}
+
+	/// Returns a reference to the value of this inverse dose equivalent in inverse sieverts
+	pub fn as_raw(&self) -> &T {
+		&self.per_Sv
+	}
+
+	/// Returns a mutable reference to the value of this inverse dose equivalent in inverse sieverts, 
+	/// eg for passing to numeric libraries that modify values in place. The number 
+	/// is always in inverse sieverts, so changing it directly does not change the unit
+	pub fn raw_mut(&mut self) -> &mut T {
+		&mut self.per_Sv
+	}
+
+	/// Returns a slice of numbers in inverse sieverts as a slice of inverse dose equivalent values, 
+	/// without copying
+	///
+	/// # Arguments
+	/// * `values` - Slice of numbers, representing quantities of inverse sieverts
+	pub fn wrap_slice(values: &[T]) -> &[Self] {
+		// SAFETY: InverseDoseEquivalent<T> is #[repr(transparent)], so it has the same memory layout as T
+		unsafe { core::slice::from_raw_parts(values.as_ptr() as *const Self, values.len()) }
+	}
+
+	/// Returns a mutable slice of numbers in inverse sieverts as a mutable slice of 
+	/// inverse dose equivalent values, without copying
+	///
+	/// # Arguments
+	/// * `values` - Slice of numbers, representing quantities of inverse sieverts
+	pub fn wrap_slice_mut(values: &mut [T]) -> &mut [Self] {
+		// SAFETY: InverseDoseEquivalent<T> is #[repr(transparent)], so it has the same memory layout as T
+		unsafe { core::slice::from_raw_parts_mut(values.as_mut_ptr() as *mut Self, values.len()) }
+	}
+
+	/// Returns the ratio of this inverse dose equivalent value to another inverse dose equivalent value 
+	/// (same as `self / other`). Dividing a unit value by another value of the same 
+	/// unit type cancels the units, so the ratio is a dimensionless number
+	///
+	/// # Arguments
+	/// * `other` - The inverse dose equivalent value to divide by
+	pub fn ratio(self, other: Self) -> T {
+		self.per_Sv / other.per_Sv
+	}
+
+	/// Returns the ratio of this inverse dose equivalent value to another inverse dose equivalent value 
+	/// as a `Dimensionless` value, instead of a bare number like `ratio(...)` does
+	///
+	/// # Arguments
+	/// * `other` - The inverse dose equivalent value to divide by
+	pub fn dimensionless_ratio(self, other: Self) -> super::base::Dimensionless<T> {
+		super::base::Dimensionless{value: self.per_Sv / other.per_Sv}
+	}
+
+	/// Returns this inverse dose equivalent value multiplied by a dimensionless factor 
+	/// (same as `self * factor`)
+	///
+	/// # Arguments
+	/// * `factor` - The number to multiply by
+	pub fn scale(self, factor: T) -> Self {
+		InverseDoseEquivalent{per_Sv: self.per_Sv * factor}
+	}
+	
+	/// Returns a new inverse dose equivalent value from the given number of inverse sieverts
+	///
+	/// # Arguments
+	/// * `per_Sv` - Any number-like type, representing a quantity of inverse sieverts
+	pub fn from_per_Sv(per_Sv: T) -> Self { InverseDoseEquivalent{per_Sv: per_Sv} }
+	
+	/// Returns a copy of this inverse dose equivalent value in inverse sieverts
+	pub fn to_per_Sv(&self) -> T { self.per_Sv.clone() }
+
+	/// Returns a new inverse dose equivalent value from the given number of inverse sieverts
+	///
+	/// # Arguments
+	/// * `per_sieverts` - Any number-like type, representing a quantity of inverse sieverts
+	pub fn from_per_sieverts(per_sieverts: T) -> Self { InverseDoseEquivalent{per_Sv: per_sieverts} }
+	
+	/// Returns a copy of this inverse dose equivalent value in inverse sieverts
+	pub fn to_per_sieverts(&self) -> T { self.per_Sv.clone() }
+
+}
+
+/// Formats inverse dose equivalent values as a number followed by its unit symbol, eg "1500 1/Sv"
+/// (complex numbers are wrapped in parentheses, eg "(1+2i) 1/Sv").
+/// The alternate flag (`{:#}`) scales the number by the nearest SI prefix where the unit symbol allows it
+impl<T> fmt::Display for InverseDoseEquivalent<T> where T: NumLike {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		if f.alternate() {
+			return super::fmt_with_si_prefix(f, &self.per_Sv, Self::unit_symbol(), false);
+		}
+		super::fmt_unit_value(f, &self.per_Sv, Self::unit_symbol())
+	}
+}
+
+/// Formats inverse dose equivalent values in exponential notation followed by the unit symbol, eg "1.5e3 1/Sv"
+impl<T> fmt::LowerExp for InverseDoseEquivalent<T> where T: NumLike+fmt::LowerExp {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		fmt::LowerExp::fmt(&self.per_Sv, f)?;
+		write!(f, " {}", Self::unit_symbol())
+	}
+}
+
+/// Formats inverse dose equivalent values in exponential notation followed by the unit symbol, eg "1.5E3 1/Sv"
+impl<T> fmt::UpperExp for InverseDoseEquivalent<T> where T: NumLike+fmt::UpperExp {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		fmt::UpperExp::fmt(&self.per_Sv, f)?;
+		write!(f, " {}", Self::unit_symbol())
+	}
+}
+
+impl<T> super::SiUnit for InverseDoseEquivalent<T> where T: NumLike {
+	type Number = T;
+	fn unit_name(&self) -> &'static str { Self::unit_name() }
+	fn unit_symbol(&self) -> &'static str { Self::unit_symbol() }
+	fn raw(&self) -> &T { &self.per_Sv }
+	fn dimension(&self) -> super::Dimension { Self::dimension() }
+	fn from_raw(value: T) -> Self { InverseDoseEquivalent{per_Sv: value} }
+	fn into_raw(self) -> T { self.per_Sv }
+}
+
+#[cfg(feature="alloc")]
+impl<T> InverseDoseEquivalent<T> where T: NumLike {
+	/// Returns this inverse dose equivalent value in the canonical text format "<value> <symbol>", eg "5 1/Sv".
+	/// Unlike `Display`, this format never changes with precision or alternate flags, and it is the
+	/// input format expected by `from_str(...)`, so `x.canonical_string().parse()` returns the same value
+	pub fn canonical_string(&self) -> alloc::string::String {
+		alloc::format!("{} {}", self.per_Sv, Self::unit_symbol())
+	}
+
+	/// Returns a `Vec` of inverse dose equivalent values copied from the given slice of numbers
+	///
+	/// # Arguments
+	/// * `values` - Slice of numbers, representing quantities of inverse sieverts
+	pub fn from_per_Sv_slice(values: &[T]) -> alloc::vec::Vec<Self> {
+		values.iter().map(|x| InverseDoseEquivalent{per_Sv: x.clone()}).collect()
+	}
+}
+
+/// Parses a inverse dose equivalent value from a number followed by its unit symbol, eg "5 1/Sv"
+impl<T> core::str::FromStr for InverseDoseEquivalent<T> where T: NumLike+core::str::FromStr {
+	type Err = SiParseError;
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		Ok(InverseDoseEquivalent{per_Sv: super::parse_unit_value(s, Self::unit_symbol())?})
+	}
+}
+
+/// Parses a inverse dose equivalent value from a number followed by its unit symbol, eg "5 1/Sv" 
+/// (same as `str::parse()`)
+impl<T> core::convert::TryFrom<&str> for InverseDoseEquivalent<T> where T: NumLike+core::str::FromStr {
+	type Error = SiParseError;
+	fn try_from(s: &str) -> Result<Self, Self::Error> {
+		<Self as core::str::FromStr>::from_str(s)
+	}
+}
+
+#[cfg(feature="num-traits")]
+impl<T> InverseDoseEquivalent<T> where T: NumLike+num_traits::Euclid {
+
+	/// Returns the Euclidean remainder of dividing this inverse dose equivalent value by another, 
+	/// which is never negative (requires the `num-traits` feature)
+	///
+	/// # Arguments
+	/// * `rhs` - The inverse dose equivalent value to divide by
+	pub fn rem_euclid(self, rhs: Self) -> Self {
+		InverseDoseEquivalent{per_Sv: self.per_Sv.rem_euclid(&rhs.per_Sv)}
+	}
+
+	/// Returns the Euclidean quotient of dividing this inverse dose equivalent value by another, 
+	/// ie the whole number of times that `rhs` fits into this value, rounded such that 
+	/// `rem_euclid(...)` is never negative (requires the `num-traits` feature)
+	///
+	/// # Arguments
+	/// * `rhs` - The inverse dose equivalent value to divide by
+	pub fn div_euclid(self, rhs: Self) -> T {
+		self.per_Sv.div_euclid(&rhs.per_Sv)
+	}
+}
+
+/// Summing inverse dose equivalent values returns their total as a inverse dose equivalent value (or zero if there are no values)
+impl<T> core::iter::Sum<InverseDoseEquivalent<T>> for InverseDoseEquivalent<T> where T: NumLike+core::iter::Sum {
+	fn sum<I: Iterator<Item=InverseDoseEquivalent<T>>>(iter: I) -> Self {
+		InverseDoseEquivalent{per_Sv: iter.map(|x| x.per_Sv).sum()}
+	}
+}
+/// Summing inverse dose equivalent values returns their total as a inverse dose equivalent value (or zero if there are no values)
+impl<'a, T> core::iter::Sum<&'a InverseDoseEquivalent<T>> for InverseDoseEquivalent<T> where T: NumLike+core::iter::Sum+'a {
+	fn sum<I: Iterator<Item=&'a InverseDoseEquivalent<T>>>(iter: I) -> Self {
+		InverseDoseEquivalent{per_Sv: iter.map(|x| x.per_Sv.clone()).sum()}
+	}
+}
+
+impl<T> InverseDoseEquivalent<T> where T: NumLike+From<f64> {
+
+	/// Returns the weighted average of the given inverse dose equivalent values, where each 
+	/// value is paired with its weight (weights do not need to add up to 1). If the 
+	/// total weight is zero (including when `values` is empty), then zero is returned
+	///
+	/// # Arguments
+	/// * `values` - Slice of (inverse dose equivalent value, weight) pairs
+	pub fn weighted_mean(values: &[(Self, f64)]) -> Self {
+		let total_weight: f64 = values.iter().map(|(_, weight)| *weight).sum();
+		if total_weight == 0.0 {
+			return InverseDoseEquivalent{per_Sv: T::from(0.0)};
+		}
+		let total = values.iter().fold(T::from(0.0),
+			|total, (value, weight)| total + value.per_Sv.clone() * T::from(*weight));
+		InverseDoseEquivalent{per_Sv: total / T::from(total_weight)}
+	}
+}
+
+impl<T> InverseDoseEquivalent<T> where T: NumLike+PartialEq+From<u8> {
+
+	/// Divides this inverse dose equivalent value by another unit value (eg `distance.checked_div(time)` 
+	/// returns a velocity), returning `None` if the divisor is zero instead of producing 
+	/// infinity or NaN (or panicking, for integer number types). This works for every unit 
+	/// type that this inverse dose equivalent value can be divided by with the `/` operator
+	///
+	/// # Arguments
+	/// * `rhs` - The unit value to divide by
+	pub fn checked_div<R>(self, rhs: R) -> Option<<Self as core::ops::Div<R>>::Output> 
+		where Self: core::ops::Div<R>, R: super::SiUnit<Number=T>
+	{
+		if *rhs.raw() == T::from(0u8) {
+			return None;
+		}
+		Some(self / rhs)
+	}
+}
+
+/// Multiplying a inverse dose equivalent value by a dimensionless value returns a inverse dose equivalent value
+impl<T> core::ops::Mul<super::base::Dimensionless<T>> for InverseDoseEquivalent<T> where T: NumLike {
+	type Output = InverseDoseEquivalent<T>;
+	fn mul(self, rhs: super::base::Dimensionless<T>) -> Self::Output {
+		InverseDoseEquivalent{per_Sv: self.per_Sv * rhs.value}
+	}
+}
+/// Multiplying a dimensionless value by a inverse dose equivalent value returns a inverse dose equivalent value
+impl<T> core::ops::Mul<InverseDoseEquivalent<T>> for super::base::Dimensionless<T> where T: NumLike {
+	type Output = InverseDoseEquivalent<T>;
+	fn mul(self, rhs: InverseDoseEquivalent<T>) -> Self::Output {
+		InverseDoseEquivalent{per_Sv: self.value * rhs.per_Sv}
+	}
+}
+/// Dividing a inverse dose equivalent value by a dimensionless value returns a inverse dose equivalent value
+impl<T> core::ops::Div<super::base::Dimensionless<T>> for InverseDoseEquivalent<T> where T: NumLike {
+	type Output = InverseDoseEquivalent<T>;
+	fn div(self, rhs: super::base::Dimensionless<T>) -> Self::Output {
+		InverseDoseEquivalent{per_Sv: self.per_Sv / rhs.value}
+	}
+}
+
+/// The remainder of dividing a inverse dose equivalent value by another inverse dose equivalent value is a inverse dose equivalent value
+impl<T> core::ops::Rem<InverseDoseEquivalent<T>> for InverseDoseEquivalent<T> where T: NumLike+core::ops::Rem<Output=T> {
+	type Output = InverseDoseEquivalent<T>;
+	fn rem(self, rhs: InverseDoseEquivalent<T>) -> Self::Output {
+		InverseDoseEquivalent{per_Sv: self.per_Sv % rhs.per_Sv}
+	}
+}
+/// The remainder of dividing a inverse dose equivalent value by another inverse dose equivalent value is a inverse dose equivalent value
+impl<T> core::ops::Rem<&InverseDoseEquivalent<T>> for InverseDoseEquivalent<T> where T: NumLike+core::ops::Rem<Output=T> {
+	type Output = InverseDoseEquivalent<T>;
+	fn rem(self, rhs: &InverseDoseEquivalent<T>) -> Self::Output {
+		InverseDoseEquivalent{per_Sv: self.per_Sv % rhs.per_Sv.clone()}
+	}
+}
+/// The remainder of dividing a inverse dose equivalent value by another inverse dose equivalent value is a inverse dose equivalent value
+impl<T> core::ops::Rem<InverseDoseEquivalent<T>> for &InverseDoseEquivalent<T> where T: NumLike+core::ops::Rem<Output=T> {
+	type Output = InverseDoseEquivalent<T>;
+	fn rem(self, rhs: InverseDoseEquivalent<T>) -> Self::Output {
+		InverseDoseEquivalent{per_Sv: self.per_Sv.clone() % rhs.per_Sv}
+	}
+}
+/// The remainder of dividing a inverse dose equivalent value by another inverse dose equivalent value is a inverse dose equivalent value
+impl<T> core::ops::Rem<&InverseDoseEquivalent<T>> for &InverseDoseEquivalent<T> where T: NumLike+core::ops::Rem<Output=T> {
+	type Output = InverseDoseEquivalent<T>;
+	fn rem(self, rhs: &InverseDoseEquivalent<T>) -> Self::Output {
+		InverseDoseEquivalent{per_Sv: self.per_Sv.clone() % rhs.per_Sv.clone()}
+	}
+}
+
+/// Serializes a inverse dose equivalent value together with its unit symbol, eg 
+/// `{"value": 1.5, "unit": "1/Sv"}` (requires the `serde-unit-tag` feature)
+#[cfg(feature="serde-unit-tag")]
+impl<T> serde::Serialize for InverseDoseEquivalent<T> where T: NumLike+serde::Serialize {
+	fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+		super::serialize_with_unit_tag(&self.per_Sv, Self::unit_symbol(), serializer)
+	}
+}
+/// Deserializes a inverse dose equivalent value from a value and unit symbol, eg 
+/// `{"value": 1.5, "unit": "1/Sv"}`, returning an error if the unit symbol 
+/// does not match (requires the `serde-unit-tag` feature)
+#[cfg(feature="serde-unit-tag")]
+impl<'de, T> serde::Deserialize<'de> for InverseDoseEquivalent<T> where T: NumLike+serde::Deserialize<'de> {
+	fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+		Ok(InverseDoseEquivalent{per_Sv: super::deserialize_with_unit_tag(Self::unit_symbol(), deserializer)?})
+	}
+}
+
+/// The additive identity of inverse dose equivalent values is zero inverse sieverts (requires the `num-traits` feature)
+#[cfg(feature="num-traits")]
+impl<T> num_traits::Zero for InverseDoseEquivalent<T> where T: NumLike+num_traits::Zero {
+	fn zero() -> Self { InverseDoseEquivalent{per_Sv: T::zero()} }
+	fn is_zero(&self) -> bool { self.per_Sv.is_zero() }
+}
+
+#[cfg(feature="num-traits")]
+impl<T> InverseDoseEquivalent<T> where T: NumLike+num_traits::Signed {
+
+	/// Returns the sign of this inverse dose equivalent value as a dimensionless number: 1 if it is 
+	/// positive, -1 if it is negative, and 0 if it is zero for integer number types 
+	/// (for floating-point number types, +0.0 returns 1.0, -0.0 returns -1.0, and NaN returns 
+	/// NaN) (requires the `num-traits` feature)
+	pub fn signum(self) -> T {
+		self.per_Sv.signum()
+	}
+}
+
+impl<T> InverseDoseEquivalent<T> where T: NumLike+PartialOrd {
+
+	/// Returns the absolute value of this inverse dose equivalent value, ie its magnitude without a minus sign
+	/// (for floating-point number types, -0.0 returns +0.0)
+	pub fn abs(self) -> Self {
+		let negated = -self.per_Sv.clone();
+		if self.per_Sv < negated {
+			InverseDoseEquivalent{per_Sv: negated}
+		} else if self.per_Sv == negated {
+			// zero, which is its own negation (adding +0.0 turns -0.0 into +0.0)
+			InverseDoseEquivalent{per_Sv: negated + self.per_Sv}
+		} else {
+			self
+		}
+	}
+
+	/// Returns the lesser of this inverse dose equivalent value and the given inverse dose equivalent value
+	///
+	/// # Arguments
+	/// * `other` - The inverse dose equivalent value to compare with this one
+	pub fn min(self, other: Self) -> Self {
+		if other.per_Sv < self.per_Sv { other } else { self }
+	}
+
+	/// Returns the greater of this inverse dose equivalent value and the given inverse dose equivalent value
+	///
+	/// # Arguments
+	/// * `other` - The inverse dose equivalent value to compare with this one
+	pub fn max(self, other: Self) -> Self {
+		if other.per_Sv > self.per_Sv { other } else { self }
+	}
+
+	/// Returns the least of the given inverse dose equivalent values, or `None` if there are none. 
+	/// Unlike `Iterator::min()`, this does not require `Ord`, so it works for 
+	/// floating-point numbers. NaN values are skipped (so the result is `None` if all 
+	/// values are NaN), and the first of several equal values is returned
+	///
+	/// # Arguments
+	/// * `values` - Any iterator or collection of inverse dose equivalent values
+	pub fn min_of<I>(values: I) -> Option<Self> where I: IntoIterator<Item=Self> {
+		values.into_iter().filter(|x| x.per_Sv.partial_cmp(&x.per_Sv).is_some()).reduce(Self::min)
+	}
+
+	/// Returns the greatest of the given inverse dose equivalent values, or `None` if there are none. 
+	/// Unlike `Iterator::max()`, this does not require `Ord`, so it works for 
+	/// floating-point numbers. NaN values are skipped (so the result is `None` if all 
+	/// values are NaN), and the first of several equal values is returned
+	///
+	/// # Arguments
+	/// * `values` - Any iterator or collection of inverse dose equivalent values
+	pub fn max_of<I>(values: I) -> Option<Self> where I: IntoIterator<Item=Self> {
+		values.into_iter().filter(|x| x.per_Sv.partial_cmp(&x.per_Sv).is_some()).reduce(Self::max)
+	}
+
+	/// Returns this inverse dose equivalent value restricted to the range from `lo` to `hi`
+	///
+	/// # Arguments
+	/// * `lo` - The minimum inverse dose equivalent value
+	/// * `hi` - The maximum inverse dose equivalent value
+	///
+	/// # Panics
+	/// Panics if `lo` is greater than `hi` (or if either of them is NaN)
+	pub fn clamp(self, lo: Self, hi: Self) -> Self {
+		assert!(lo.per_Sv <= hi.per_Sv, "lo must be less than or equal to hi");
+		if self.per_Sv < lo.per_Sv { lo } else if self.per_Sv > hi.per_Sv { hi } else { self }
+	}
+
+	/// Returns this inverse dose equivalent value with its magnitude capped at `max`, keeping its 
+	/// sign (eg for capping a speed), or this value unchanged if its magnitude does not 
+	/// exceed `max`. For complex-valued inverse dose equivalent, use `clamp_norm(...)` instead, 
+	/// which caps the magnitude while keeping the phase
+	///
+	/// # Arguments
+	/// * `max` - The maximum magnitude (should not be negative)
+	pub fn clamp_magnitude(self, max: Self) -> Self {
+		let negated_max = -max.per_Sv.clone();
+		if self.per_Sv > max.per_Sv { 
+			max 
+		} else if self.per_Sv < negated_max { 
+			InverseDoseEquivalent{per_Sv: negated_max} 
+		} else { 
+			self 
+		}
+	}
+
+	/// Returns true if this inverse dose equivalent value differs from the given inverse dose equivalent 
+	/// value by no more than the given absolute tolerance (which is also a 
+	/// inverse dose equivalent value, so the comparison is dimensionally safe). NaN is never 
+	/// equal to anything. See also the `assert_unit_eq!(...)` macro.
+	///
+	/// # Arguments
+	/// * `other` - The inverse dose equivalent value to compare with this one
+	/// * `tolerance` - The largest allowed difference between the two values
+	pub fn eq_within(self, other: Self, tolerance: Self) -> bool {
+		let diff = self.per_Sv - other.per_Sv;
+		diff <= tolerance.per_Sv && -diff <= tolerance.per_Sv
+	}
+}
+
+#[cfg(feature="num-complex")]
+impl InverseDoseEquivalent<num_complex::Complex32> {
+	/// Returns the magnitude (aka norm or absolute value) of this complex inverse dose equivalent 
+	/// value as a real-valued inverse dose equivalent (requires the `num-complex` feature)
+	pub fn magnitude(self) -> InverseDoseEquivalent<f32> {
+		InverseDoseEquivalent{per_Sv: self.per_Sv.norm()}
+	}
+
+	/// Returns this complex inverse dose equivalent value scaled down so that its magnitude is 
+	/// at most `max` (keeping its phase), or this value unchanged if its magnitude does 
+	/// not exceed `max`. This is the complex equivalent of `clamp_magnitude(...)` (requires 
+	/// the `num-complex` feature)
+	///
+	/// # Arguments
+	/// * `max` - The maximum magnitude (should not be negative)
+	pub fn clamp_norm(self, max: InverseDoseEquivalent<f32>) -> Self {
+		let norm = self.per_Sv.norm();
+		if norm > max.per_Sv {
+			InverseDoseEquivalent{per_Sv: self.per_Sv * (max.per_Sv / norm)}
+		} else {
+			self
+		}
+	}
+}
+
+#[cfg(feature="num-complex")]
+impl InverseDoseEquivalent<num_complex::Complex64> {
+	/// Returns the magnitude (aka norm or absolute value) of this complex inverse dose equivalent 
+	/// value as a real-valued inverse dose equivalent (requires the `num-complex` feature)
+	pub fn magnitude(self) -> InverseDoseEquivalent<f64> {
+		InverseDoseEquivalent{per_Sv: self.per_Sv.norm()}
+	}
+
+	/// Returns this complex inverse dose equivalent value scaled down so that its magnitude is 
+	/// at most `max` (keeping its phase), or this value unchanged if its magnitude does 
+	/// not exceed `max`. This is the complex equivalent of `clamp_magnitude(...)` (requires 
+	/// the `num-complex` feature)
+	///
+	/// # Arguments
+	/// * `max` - The maximum magnitude (should not be negative)
+	pub fn clamp_norm(self, max: InverseDoseEquivalent<f64>) -> Self {
+		let norm = self.per_Sv.norm();
+		if norm > max.per_Sv {
+			InverseDoseEquivalent{per_Sv: self.per_Sv * (max.per_Sv / norm)}
+		} else {
+			self
+		}
+	}
+}
+
+/// Rounding and other floating-point functions (requires the `num-traits` feature, 
+/// and either the `std` or `libm` feature)
+#[cfg(all(feature="num-traits", any(feature="std", feature="libm")))]
+impl<T> InverseDoseEquivalent<T> where T: NumLike+num_traits::Float {
+
+	/// Returns this inverse dose equivalent value rounded to the nearest whole number of inverse sieverts 
+	/// (half-way cases are rounded away from zero)
+	pub fn round(self) -> Self {
+		InverseDoseEquivalent{per_Sv: self.per_Sv.round()}
+	}
+
+	/// Returns this inverse dose equivalent value rounded down to a whole number of inverse sieverts
+	pub fn floor(self) -> Self {
+		InverseDoseEquivalent{per_Sv: self.per_Sv.floor()}
+	}
+
+	/// Returns this inverse dose equivalent value rounded up to a whole number of inverse sieverts
+	pub fn ceil(self) -> Self {
+		InverseDoseEquivalent{per_Sv: self.per_Sv.ceil()}
+	}
+
+	/// Returns this inverse dose equivalent value rounded towards zero to a whole number of inverse sieverts
+	pub fn trunc(self) -> Self {
+		InverseDoseEquivalent{per_Sv: self.per_Sv.trunc()}
+	}
+
+	/// Returns this inverse dose equivalent value rounded to the nearest multiple of the given step 
+	/// (half-way cases are rounded away from zero)
+	///
+	/// # Arguments
+	/// * `step` - The inverse dose equivalent value to round to a multiple of
+	pub fn round_to(self, step: Self) -> Self {
+		InverseDoseEquivalent{per_Sv: (self.per_Sv / step.per_Sv).round() * step.per_Sv}
+	}
+
+	/// Fused multiply-add: returns `self * a + b` computed with only one rounding 
+	/// error, which is more accurate (and on some hardware faster) than a separate 
+	/// multiplication and addition
+	///
+	/// # Arguments
+	/// * `a` - The scalar value to multiply this inverse dose equivalent value by
+	/// * `b` - The inverse dose equivalent value to add to the product
+	pub fn mul_add(self, a: T, b: Self) -> Self {
+		InverseDoseEquivalent{per_Sv: self.per_Sv.mul_add(a, b.per_Sv)}
+	}
+
+	/// Returns true if this inverse dose equivalent value is neither infinite nor NaN
+	pub fn is_finite(&self) -> bool {
+		self.per_Sv.is_finite()
+	}
+
+	/// Returns true if this inverse dose equivalent value is positive or negative infinity
+	pub fn is_infinite(&self) -> bool {
+		self.per_Sv.is_infinite()
+	}
+
+	/// Returns true if this inverse dose equivalent value is NaN (not a number)
+	pub fn is_nan(&self) -> bool {
+		self.per_Sv.is_nan()
+	}
+
+	/// Returns true if this inverse dose equivalent value has a positive sign, including +0.0 
+	/// and positive infinity
+	pub fn is_sign_positive(&self) -> bool {
+		self.per_Sv.is_sign_positive()
+	}
+
+	/// Returns true if this inverse dose equivalent value has a negative sign, including -0.0 
+	/// and negative infinity
+	pub fn is_sign_negative(&self) -> bool {
+		self.per_Sv.is_sign_negative()
+	}
+
+	/// Returns a inverse dose equivalent value with the magnitude of this one and the sign of 
+	/// `sign_source`, eg for reflecting a value off of a surface
+	///
+	/// # Arguments
+	/// * `sign_source` - The inverse dose equivalent value whose sign is copied
+	pub fn copysign(self, sign_source: Self) -> Self {
+		InverseDoseEquivalent{per_Sv: self.per_Sv.copysign(sign_source.per_Sv)}
+	}
+}
+
+#[cfg(feature="num-traits")]
+impl<T> InverseDoseEquivalent<T> where T: NumLike+num_traits::CheckedAdd+num_traits::CheckedSub {
+
+	/// Adds another inverse dose equivalent value to this one, returning `None` if the 
+	/// addition overflows the number type (requires the `num-traits` feature)
+	pub fn checked_add(self, rhs: Self) -> Option<Self> {
+		Some(InverseDoseEquivalent{per_Sv: self.per_Sv.checked_add(&rhs.per_Sv)?})
+	}
+
+	/// Subtracts another inverse dose equivalent value from this one, returning `None` if 
+	/// the subtraction overflows the number type (requires the `num-traits` feature)
+	pub fn checked_sub(self, rhs: Self) -> Option<Self> {
+		Some(InverseDoseEquivalent{per_Sv: self.per_Sv.checked_sub(&rhs.per_Sv)?})
+	}
+}
+
+#[cfg(feature="num-traits")]
+impl<T> InverseDoseEquivalent<T> where T: NumLike+num_traits::SaturatingAdd+num_traits::SaturatingSub {
+
+	/// Adds another inverse dose equivalent value to this one, clamping the result to the 
+	/// minimum or maximum value of the number type instead of overflowing 
+	/// (requires the `num-traits` feature)
+	pub fn saturating_add(self, rhs: Self) -> Self {
+		InverseDoseEquivalent{per_Sv: self.per_Sv.saturating_add(&rhs.per_Sv)}
+	}
+
+	/// Subtracts another inverse dose equivalent value from this one, clamping the result 
+	/// to the minimum or maximum value of the number type instead of overflowing 
+	/// (requires the `num-traits` feature)
+	pub fn saturating_sub(self, rhs: Self) -> Self {
+		InverseDoseEquivalent{per_Sv: self.per_Sv.saturating_sub(&rhs.per_Sv)}
+	}
+}
+
+impl<T> InverseDoseEquivalent<T> where T: NumLike+Into<f64> {
+
+	/// Returns true if this inverse dose equivalent value is equal to the given inverse dose equivalent 
+	/// value to within the given number of significant figures (eg 6 significant 
+	/// figures allows a relative difference of up to 1 part per million). NaN is 
+	/// only approximately equal to NaN, infinity is only approximately equal to 
+	/// infinity of the same sign, and zero is only approximately equal to zero.
+	///
+	/// # Arguments
+	/// * `other` - The inverse dose equivalent value to compare with this one
+	/// * `sig_figs` - The number of significant figures that must match
+	pub fn approx_eq(self, other: Self, sig_figs: i32) -> bool {
+		super::approx_eq_f64(self.per_Sv.into(), other.per_Sv.into(), sig_figs)
+	}
+}
+
+impl<T> InverseDoseEquivalent<T> where T: NumLike+From<f64> {
+
+	/// Returns the linear interpolation between this inverse dose equivalent value and the given 
+	/// inverse dose equivalent value, ie `self*(1-t) + other*t`. The interpolation factor is not 
+	/// clamped, so values of `t` outside the range 0 to 1 extrapolate beyond the two 
+	/// values (see `lerp_clamped(...)`).
+	///
+	/// # Arguments
+	/// * `other` - The inverse dose equivalent value to interpolate towards (returned when `t` is 1)
+	/// * `t` - The interpolation factor, where 0 returns this value and 1 returns `other`
+	pub fn lerp(self, other: Self, t: f64) -> Self {
+		InverseDoseEquivalent{per_Sv: self.per_Sv * T::from(1.0 - t) + other.per_Sv * T::from(t)}
+	}
+
+	/// Same as `lerp(...)`, except that the interpolation factor `t` is first clamped 
+	/// to the range 0 to 1, so that the result is always between the two values
+	///
+	/// # Arguments
+	/// * `other` - The inverse dose equivalent value to interpolate towards (returned when `t` is 1 or more)
+	/// * `t` - The interpolation factor, where 0 returns this value and 1 returns `other`
+	pub fn lerp_clamped(self, other: Self, t: f64) -> Self {
+		self.lerp(other, t.clamp(0.0, 1.0))
+	}
+	
+	/// Returns a copy of this inverse dose equivalent value in inverse millisieverts
+	/// 
+	/// *Note: This method is not available for `f32` and other number types lacking the `From<f64>` trait*
+	pub fn to_per_mSv(&self) -> T {
+		return self.per_Sv.clone() * T::from(0.001_f64);
+	}
+
+	/// Returns a new inverse dose equivalent value from the given number of inverse millisieverts
+	/// 
+	/// *Note: This method is not available for `f32` and other number types lacking the `From<f64>` trait*
+	///
+	/// # Arguments
+	/// * `per_mSv` - Any number-like type, representing a quantity of inverse millisieverts
+	pub fn from_per_mSv(per_mSv: T) -> Self {
+		InverseDoseEquivalent{per_Sv: per_mSv * T::from(1000.0_f64)}
+	}
+
+	/// Returns a copy of this inverse dose equivalent value in inverse microsieverts
+	/// 
+	/// *Note: This method is not available for `f32` and other number types lacking the `From<f64>` trait*
+	pub fn to_per_uSv(&self) -> T {
+		return self.per_Sv.clone() * T::from(1e-06_f64);
+	}
+
+	/// Returns a new inverse dose equivalent value from the given number of inverse microsieverts
+	/// 
+	/// *Note: This method is not available for `f32` and other number types lacking the `From<f64>` trait*
+	///
+	/// # Arguments
+	/// * `per_uSv` - Any number-like type, representing a quantity of inverse microsieverts
+	pub fn from_per_uSv(per_uSv: T) -> Self {
+		InverseDoseEquivalent{per_Sv: per_uSv * T::from(1000000.0_f64)}
+	}
+
+	/// Returns a copy of this inverse dose equivalent value in inverse nanosieverts
+	/// 
+	/// *Note: This method is not available for `f32` and other number types lacking the `From<f64>` trait*
+	pub fn to_per_nSv(&self) -> T {
+		return self.per_Sv.clone() * T::from(1e-09_f64);
+	}
+
+	/// Returns a new inverse dose equivalent value from the given number of inverse nanosieverts
+	/// 
+	/// *Note: This method is not available for `f32` and other number types lacking the `From<f64>` trait*
+	///
+	/// # Arguments
+	/// * `per_nSv` - Any number-like type, representing a quantity of inverse nanosieverts
+	pub fn from_per_nSv(per_nSv: T) -> Self {
+		InverseDoseEquivalent{per_Sv: per_nSv * T::from(1000000000.0_f64)}
+	}
+
+	/// Returns a copy of this inverse dose equivalent value in inverse kilosieverts
+	/// 
+	/// *Note: This method is not available for `f32` and other number types lacking the `From<f64>` trait*
+	pub fn to_per_kSv(&self) -> T {
+		return self.per_Sv.clone() * T::from(1000.0_f64);
+	}
+
+	/// Returns a new inverse dose equivalent value from the given number of inverse kilosieverts
+	/// 
+	/// *Note: This method is not available for `f32` and other number types lacking the `From<f64>` trait*
+	///
+	/// # Arguments
+	/// * `per_kSv` - Any number-like type, representing a quantity of inverse kilosieverts
+	pub fn from_per_kSv(per_kSv: T) -> Self {
+		InverseDoseEquivalent{per_Sv: per_kSv * T::from(0.001_f64)}
+	}
+
+	/// Returns a copy of this inverse dose equivalent value in inverse megasieverts
+	/// 
+	/// *Note: This method is not available for `f32` and other number types lacking the `From<f64>` trait*
+	pub fn to_per_MSv(&self) -> T {
+		return self.per_Sv.clone() * T::from(1000000.0_f64);
+	}
+
+	/// Returns a new inverse dose equivalent value from the given number of inverse megasieverts
+	/// 
+	/// *Note: This method is not available for `f32` and other number types lacking the `From<f64>` trait*
+	///
+	/// # Arguments
+	/// * `per_MSv` - Any number-like type, representing a quantity of inverse megasieverts
+	pub fn from_per_MSv(per_MSv: T) -> Self {
+		InverseDoseEquivalent{per_Sv: per_MSv * T::from(1e-06_f64)}
+	}
+
+	/// Returns a copy of this inverse dose equivalent value in inverse gigasieverts
+	/// 
+	/// *Note: This method is not available for `f32` and other number types lacking the `From<f64>` trait*
+	pub fn to_per_GSv(&self) -> T {
+		return self.per_Sv.clone() * T::from(1000000000.0_f64);
+	}
+
+	/// Returns a new inverse dose equivalent value from the given number of inverse gigasieverts
+	/// 
+	/// *Note: This method is not available for `f32` and other number types lacking the `From<f64>` trait*
+	///
+	/// # Arguments
+	/// * `per_GSv` - Any number-like type, representing a quantity of inverse gigasieverts
+	pub fn from_per_GSv(per_GSv: T) -> Self {
+		InverseDoseEquivalent{per_Sv: per_GSv * T::from(1e-09_f64)}
+	}
+
+	/// Returns a copy of this inverse dose equivalent value in inverse roentgen equivalent man
+	/// 
+	/// *Note: This method is not available for `f32` and other number types lacking the `From<f64>` trait*
+	pub fn to_per_rem(&self) -> T {
+		return self.per_Sv.clone() * T::from(0.01_f64);
+	}
+
+	/// Returns a new inverse dose equivalent value from the given number of inverse roentgen equivalent man
+	/// 
+	/// *Note: This method is not available for `f32` and other number types lacking the `From<f64>` trait*
+	///
+	/// # Arguments
+	/// * `per_rem` - Any number-like type, representing a quantity of inverse roentgen equivalent man
+	pub fn from_per_rem(per_rem: T) -> Self {
+		InverseDoseEquivalent{per_Sv: per_rem * T::from(100.0_f64)}
+	}
+
+	/// Returns a copy of this inverse dose equivalent value in inverse milli-roentgen equivalents
+	/// 
+	/// *Note: This method is not available for `f32` and other number types lacking the `From<f64>` trait*
+	pub fn to_per_mrem(&self) -> T {
+		return self.per_Sv.clone() * T::from(1e-05_f64);
+	}
+
+	/// Returns a new inverse dose equivalent value from the given number of inverse milli-roentgen equivalents
+	/// 
+	/// *Note: This method is not available for `f32` and other number types lacking the `From<f64>` trait*
+	///
+	/// # Arguments
+	/// * `per_mrem` - Any number-like type, representing a quantity of inverse milli-roentgen equivalents
+	pub fn from_per_mrem(per_mrem: T) -> Self {
+		InverseDoseEquivalent{per_Sv: per_mrem * T::from(100000.0_f64)}
+	}
+
+	/// Returns a copy of this inverse dose equivalent value in inverse kilo-roentgen equivalents
+	/// 
+	/// *Note: This method is not available for `f32` and other number types lacking the `From<f64>` trait*
+	pub fn to_per_krem(&self) -> T {
+		return self.per_Sv.clone() * T::from(10.0_f64);
+	}
+
+	/// Returns a new inverse dose equivalent value from the given number of inverse kilo-roentgen equivalents
+	/// 
+	/// *Note: This method is not available for `f32` and other number types lacking the `From<f64>` trait*
+	///
+	/// # Arguments
+	/// * `per_krem` - Any number-like type, representing a quantity of inverse kilo-roentgen equivalents
+	pub fn from_per_krem(per_krem: T) -> Self {
+		InverseDoseEquivalent{per_Sv: per_krem * T::from(0.1_f64)}
+	}
+
+}
+
+
+/// Multiplying a unit value by a scalar value returns a unit value
+#[cfg(feature="num-bigfloat")]
+impl core::ops::Mul<InverseDoseEquivalent<num_bigfloat::BigFloat>> for num_bigfloat::BigFloat {
+	type Output = InverseDoseEquivalent<num_bigfloat::BigFloat>;
+	fn mul(self, rhs: InverseDoseEquivalent<num_bigfloat::BigFloat>) -> Self::Output {
+		InverseDoseEquivalent{per_Sv: self * rhs.per_Sv}
+	}
+}
+/// Multiplying a unit value by a scalar value returns a unit value
+#[cfg(feature="num-bigfloat")]
+impl core::ops::Mul<InverseDoseEquivalent<num_bigfloat::BigFloat>> for &num_bigfloat::BigFloat {
+	type Output = InverseDoseEquivalent<num_bigfloat::BigFloat>;
+	fn mul(self, rhs: InverseDoseEquivalent<num_bigfloat::BigFloat>) -> Self::Output {
+		InverseDoseEquivalent{per_Sv: self.clone() * rhs.per_Sv}
+	}
+}
+/// Multiplying a unit value by a scalar value returns a unit value
+#[cfg(feature="num-bigfloat")]
+impl core::ops::Mul<&InverseDoseEquivalent<num_bigfloat::BigFloat>> for num_bigfloat::BigFloat {
+	type Output = InverseDoseEquivalent<num_bigfloat::BigFloat>;
+	fn mul(self, rhs: &InverseDoseEquivalent<num_bigfloat::BigFloat>) -> Self::Output {
+		InverseDoseEquivalent{per_Sv: self * rhs.per_Sv.clone()}
+	}
+}
+/// Multiplying a unit value by a scalar value returns a unit value
+#[cfg(feature="num-bigfloat")]
+impl core::ops::Mul<&InverseDoseEquivalent<num_bigfloat::BigFloat>> for &num_bigfloat::BigFloat {
+	type Output = InverseDoseEquivalent<num_bigfloat::BigFloat>;
+	fn mul(self, rhs: &InverseDoseEquivalent<num_bigfloat::BigFloat>) -> Self::Output {
+		InverseDoseEquivalent{per_Sv: self.clone() * rhs.per_Sv.clone()}
+	}
+}
+
+/// Multiplying a unit value by a scalar value returns a unit value
+#[cfg(feature="num-complex")]
+impl core::ops::Mul<InverseDoseEquivalent<num_complex::Complex32>> for num_complex::Complex32 {
+	type Output = InverseDoseEquivalent<num_complex::Complex32>;
+	fn mul(self, rhs: InverseDoseEquivalent<num_complex::Complex32>) -> Self::Output {
+		InverseDoseEquivalent{per_Sv: self * rhs.per_Sv}
+	}
+}
+/// Multiplying a unit value by a scalar value returns a unit value
+#[cfg(feature="num-complex")]
+impl core::ops::Mul<InverseDoseEquivalent<num_complex::Complex32>> for &num_complex::Complex32 {
+	type Output = InverseDoseEquivalent<num_complex::Complex32>;
+	fn mul(self, rhs: InverseDoseEquivalent<num_complex::Complex32>) -> Self::Output {
+		InverseDoseEquivalent{per_Sv: self.clone() * rhs.per_Sv}
+	}
+}
+/// Multiplying a unit value by a scalar value returns a unit value
+#[cfg(feature="num-complex")]
+impl core::ops::Mul<&InverseDoseEquivalent<num_complex::Complex32>> for num_complex::Complex32 {
+	type Output = InverseDoseEquivalent<num_complex::Complex32>;
+	fn mul(self, rhs: &InverseDoseEquivalent<num_complex::Complex32>) -> Self::Output {
+		InverseDoseEquivalent{per_Sv: self * rhs.per_Sv.clone()}
+	}
+}
+/// Multiplying a unit value by a scalar value returns a unit value
+#[cfg(feature="num-complex")]
+impl core::ops::Mul<&InverseDoseEquivalent<num_complex::Complex32>> for &num_complex::Complex32 {
+	type Output = InverseDoseEquivalent<num_complex::Complex32>;
+	fn mul(self, rhs: &InverseDoseEquivalent<num_complex::Complex32>) -> Self::Output {
+		InverseDoseEquivalent{per_Sv: self.clone() * rhs.per_Sv.clone()}
+	}
+}
+
+/// Multiplying a unit value by a scalar value returns a unit value
+#[cfg(feature="num-complex")]
+impl core::ops::Mul<InverseDoseEquivalent<num_complex::Complex64>> for num_complex::Complex64 {
+	type Output = InverseDoseEquivalent<num_complex::Complex64>;
+	fn mul(self, rhs: InverseDoseEquivalent<num_complex::Complex64>) -> Self::Output {
+		InverseDoseEquivalent{per_Sv: self * rhs.per_Sv}
+	}
+}
+/// Multiplying a unit value by a scalar value returns a unit value
+#[cfg(feature="num-complex")]
+impl core::ops::Mul<InverseDoseEquivalent<num_complex::Complex64>> for &num_complex::Complex64 {
+	type Output = InverseDoseEquivalent<num_complex::Complex64>;
+	fn mul(self, rhs: InverseDoseEquivalent<num_complex::Complex64>) -> Self::Output {
+		InverseDoseEquivalent{per_Sv: self.clone() * rhs.per_Sv}
+	}
+}
+/// Multiplying a unit value by a scalar value returns a unit value
+#[cfg(feature="num-complex")]
+impl core::ops::Mul<&InverseDoseEquivalent<num_complex::Complex64>> for num_complex::Complex64 {
+	type Output = InverseDoseEquivalent<num_complex::Complex64>;
+	fn mul(self, rhs: &InverseDoseEquivalent<num_complex::Complex64>) -> Self::Output {
+		InverseDoseEquivalent{per_Sv: self * rhs.per_Sv.clone()}
+	}
+}
+/// Multiplying a unit value by a scalar value returns a unit value
+#[cfg(feature="num-complex")]
+impl core::ops::Mul<&InverseDoseEquivalent<num_complex::Complex64>> for &num_complex::Complex64 {
+	type Output = InverseDoseEquivalent<num_complex::Complex64>;
+	fn mul(self, rhs: &InverseDoseEquivalent<num_complex::Complex64>) -> Self::Output {
+		InverseDoseEquivalent{per_Sv: self.clone() * rhs.per_Sv.clone()}
+	}
+}
+
+/// Multiplying a unit value by a scalar value returns a unit value
+#[cfg(feature="num-rational")]
+impl core::ops::Mul<InverseDoseEquivalent<num_rational::Rational64>> for num_rational::Rational64 {
+	type Output = InverseDoseEquivalent<num_rational::Rational64>;
+	fn mul(self, rhs: InverseDoseEquivalent<num_rational::Rational64>) -> Self::Output {
+		InverseDoseEquivalent{per_Sv: self * rhs.per_Sv}
+	}
+}
+/// Multiplying a unit value by a scalar value returns a unit value
+#[cfg(feature="num-rational")]
+impl core::ops::Mul<InverseDoseEquivalent<num_rational::Rational64>> for &num_rational::Rational64 {
+	type Output = InverseDoseEquivalent<num_rational::Rational64>;
+	fn mul(self, rhs: InverseDoseEquivalent<num_rational::Rational64>) -> Self::Output {
+		InverseDoseEquivalent{per_Sv: self.clone() * rhs.per_Sv}
+	}
+}
+/// Multiplying a unit value by a scalar value returns a unit value
+#[cfg(feature="num-rational")]
+impl core::ops::Mul<&InverseDoseEquivalent<num_rational::Rational64>> for num_rational::Rational64 {
+	type Output = InverseDoseEquivalent<num_rational::Rational64>;
+	fn mul(self, rhs: &InverseDoseEquivalent<num_rational::Rational64>) -> Self::Output {
+		InverseDoseEquivalent{per_Sv: self * rhs.per_Sv.clone()}
+	}
+}
+/// Multiplying a unit value by a scalar value returns a unit value
+#[cfg(feature="num-rational")]
+impl core::ops::Mul<&InverseDoseEquivalent<num_rational::Rational64>> for &num_rational::Rational64 {
+	type Output = InverseDoseEquivalent<num_rational::Rational64>;
+	fn mul(self, rhs: &InverseDoseEquivalent<num_rational::Rational64>) -> Self::Output {
+		InverseDoseEquivalent{per_Sv: self.clone() * rhs.per_Sv.clone()}
+	}
+}
+
+/// Multiplying a unit value by a scalar value returns a unit value
+#[cfg(feature="num-rational")]
+impl core::ops::Mul<InverseDoseEquivalent<num_rational::BigRational>> for num_rational::BigRational {
+	type Output = InverseDoseEquivalent<num_rational::BigRational>;
+	fn mul(self, rhs: InverseDoseEquivalent<num_rational::BigRational>) -> Self::Output {
+		InverseDoseEquivalent{per_Sv: self * rhs.per_Sv}
+	}
+}
+/// Multiplying a unit value by a scalar value returns a unit value
+#[cfg(feature="num-rational")]
+impl core::ops::Mul<InverseDoseEquivalent<num_rational::BigRational>> for &num_rational::BigRational {
+	type Output = InverseDoseEquivalent<num_rational::BigRational>;
+	fn mul(self, rhs: InverseDoseEquivalent<num_rational::BigRational>) -> Self::Output {
+		InverseDoseEquivalent{per_Sv: self.clone() * rhs.per_Sv}
+	}
+}
+/// Multiplying a unit value by a scalar value returns a unit value
+#[cfg(feature="num-rational")]
+impl core::ops::Mul<&InverseDoseEquivalent<num_rational::BigRational>> for num_rational::BigRational {
+	type Output = InverseDoseEquivalent<num_rational::BigRational>;
+	fn mul(self, rhs: &InverseDoseEquivalent<num_rational::BigRational>) -> Self::Output {
+		InverseDoseEquivalent{per_Sv: self * rhs.per_Sv.clone()}
+	}
+}
+/// Multiplying a unit value by a scalar value returns a unit value
+#[cfg(feature="num-rational")]
+impl core::ops::Mul<&InverseDoseEquivalent<num_rational::BigRational>> for &num_rational::BigRational {
+	type Output = InverseDoseEquivalent<num_rational::BigRational>;
+	fn mul(self, rhs: &InverseDoseEquivalent<num_rational::BigRational>) -> Self::Output {
+		InverseDoseEquivalent{per_Sv: self.clone() * rhs.per_Sv.clone()}
+	}
+}
+
+
+
+
+
+/// Multiplying a InverseDoseEquivalent by an [nalgebra](https://crates.io/crates/nalgebra) 
+/// vector or matrix of unitless values returns a vector or matrix of InverseDoseEquivalent 
+/// values (eg a direction vector multiplied by a InverseDoseEquivalent)
+#[cfg(feature="nalgebra")]
+impl<T, R, C, S> core::ops::Mul<nalgebra::Matrix<T, R, C, S>> for InverseDoseEquivalent<T> where 
+	T: NumLike+nalgebra::Scalar, R: nalgebra::Dim, C: nalgebra::Dim, S: nalgebra::RawStorage<T, R, C>, 
+	nalgebra::DefaultAllocator: nalgebra::allocator::Allocator<R, C> {
+	type Output = nalgebra::OMatrix<InverseDoseEquivalent<T>, R, C>;
+	fn mul(self, rhs: nalgebra::Matrix<T, R, C, S>) -> Self::Output {
+		rhs.map(|x| InverseDoseEquivalent{per_Sv: self.per_Sv.clone() * x})
+	}
+}
+/// Multiplying an [nalgebra](https://crates.io/crates/nalgebra) vector or matrix of 
+/// unitless values by a InverseDoseEquivalent returns a vector or matrix of InverseDoseEquivalent values
+#[cfg(feature="nalgebra")]
+impl<T, R, C, S> core::ops::Mul<InverseDoseEquivalent<T>> for nalgebra::Matrix<T, R, C, S> where 
+	T: NumLike+nalgebra::Scalar, R: nalgebra::Dim, C: nalgebra::Dim, S: nalgebra::RawStorage<T, R, C>, 
+	nalgebra::DefaultAllocator: nalgebra::allocator::Allocator<R, C> {
+	type Output = nalgebra::OMatrix<InverseDoseEquivalent<T>, R, C>;
+	fn mul(self, rhs: InverseDoseEquivalent<T>) -> Self::Output {
+		self.map(|x| InverseDoseEquivalent{per_Sv: x * rhs.per_Sv.clone()})
+	}
+}
+/// Dividing an [nalgebra](https://crates.io/crates/nalgebra) vector or matrix of 
+/// InverseDoseEquivalent values by a InverseDoseEquivalent returns a vector or matrix of unitless values
+#[cfg(feature="nalgebra")]
+impl<T, R, C, S> core::ops::Div<InverseDoseEquivalent<T>> for nalgebra::Matrix<InverseDoseEquivalent<T>, R, C, S> where 
+	T: NumLike+nalgebra::Scalar, R: nalgebra::Dim, C: nalgebra::Dim, S: nalgebra::RawStorage<InverseDoseEquivalent<T>, R, C>, 
+	nalgebra::DefaultAllocator: nalgebra::allocator::Allocator<R, C> {
+	type Output = nalgebra::OMatrix<T, R, C>;
+	fn div(self, rhs: InverseDoseEquivalent<T>) -> Self::Output {
+		self.map(|x| x.per_Sv / rhs.per_Sv.clone())
+	}
+}
+
+
+
+// InverseDoseEquivalent * Distance -> InverseAcceleration
+/// Multiplying a InverseDoseEquivalent by a Distance returns a value of type InverseAcceleration
+impl<T> core::ops::Mul<Distance<T>> for InverseDoseEquivalent<T> where T: NumLike {
+	type Output = InverseAcceleration<T>;
+	fn mul(self, rhs: Distance<T>) -> Self::Output {
+		InverseAcceleration{s2pm: self.per_Sv * rhs.m}
+	}
+}
+/// Multiplying a InverseDoseEquivalent by a Distance returns a value of type InverseAcceleration
+impl<T> core::ops::Mul<Distance<T>> for &InverseDoseEquivalent<T> where T: NumLike {
+	type Output = InverseAcceleration<T>;
+	fn mul(self, rhs: Distance<T>) -> Self::Output {
+		InverseAcceleration{s2pm: self.per_Sv.clone() * rhs.m}
+	}
+}
+/// Multiplying a InverseDoseEquivalent by a Distance returns a value of type InverseAcceleration
+impl<T> core::ops::Mul<&Distance<T>> for InverseDoseEquivalent<T> where T: NumLike {
+	type Output = InverseAcceleration<T>;
+	fn mul(self, rhs: &Distance<T>) -> Self::Output {
+		InverseAcceleration{s2pm: self.per_Sv * rhs.m.clone()}
+	}
+}
+/// Multiplying a InverseDoseEquivalent by a Distance returns a value of type InverseAcceleration
+impl<T> core::ops::Mul<&Distance<T>> for &InverseDoseEquivalent<T> where T: NumLike {
+	type Output = InverseAcceleration<T>;
+	fn mul(self, rhs: &Distance<T>) -> Self::Output {
+		InverseAcceleration{s2pm: self.per_Sv.clone() * rhs.m.clone()}
+	}
+}
+
+// InverseDoseEquivalent / InverseDistance -> InverseAcceleration
+/// Dividing a InverseDoseEquivalent by a InverseDistance returns a value of type InverseAcceleration
+impl<T> core::ops::Div<InverseDistance<T>> for InverseDoseEquivalent<T> where T: NumLike {
+	type Output = InverseAcceleration<T>;
+	fn div(self, rhs: InverseDistance<T>) -> Self::Output {
+		InverseAcceleration{s2pm: self.per_Sv / rhs.per_m}
+	}
+}
+/// Dividing a InverseDoseEquivalent by a InverseDistance returns a value of type InverseAcceleration
+impl<T> core::ops::Div<InverseDistance<T>> for &InverseDoseEquivalent<T> where T: NumLike {
+	type Output = InverseAcceleration<T>;
+	fn div(self, rhs: InverseDistance<T>) -> Self::Output {
+		InverseAcceleration{s2pm: self.per_Sv.clone() / rhs.per_m}
+	}
+}
+/// Dividing a InverseDoseEquivalent by a InverseDistance returns a value of type InverseAcceleration
+impl<T> core::ops::Div<&InverseDistance<T>> for InverseDoseEquivalent<T> where T: NumLike {
+	type Output = InverseAcceleration<T>;
+	fn div(self, rhs: &InverseDistance<T>) -> Self::Output {
+		InverseAcceleration{s2pm: self.per_Sv / rhs.per_m.clone()}
+	}
+}
+/// Dividing a InverseDoseEquivalent by a InverseDistance returns a value of type InverseAcceleration
+impl<T> core::ops::Div<&InverseDistance<T>> for &InverseDoseEquivalent<T> where T: NumLike {
+	type Output = InverseAcceleration<T>;
+	fn div(self, rhs: &InverseDistance<T>) -> Self::Output {
+		InverseAcceleration{s2pm: self.per_Sv.clone() / rhs.per_m.clone()}
+	}
+}
+
+// InverseDoseEquivalent * InverseMass -> InverseEnergy
+/// Multiplying a InverseDoseEquivalent by a InverseMass returns a value of type InverseEnergy
+impl<T> core::ops::Mul<InverseMass<T>> for InverseDoseEquivalent<T> where T: NumLike {
+	type Output = InverseEnergy<T>;
+	fn mul(self, rhs: InverseMass<T>) -> Self::Output {
+		InverseEnergy{per_J: self.per_Sv * rhs.per_kg}
+	}
+}
+/// Multiplying a InverseDoseEquivalent by a InverseMass returns a value of type InverseEnergy
+impl<T> core::ops::Mul<InverseMass<T>> for &InverseDoseEquivalent<T> where T: NumLike {
+	type Output = InverseEnergy<T>;
+	fn mul(self, rhs: InverseMass<T>) -> Self::Output {
+		InverseEnergy{per_J: self.per_Sv.clone() * rhs.per_kg}
+	}
+}
+/// Multiplying a InverseDoseEquivalent by a InverseMass returns a value of type InverseEnergy
+impl<T> core::ops::Mul<&InverseMass<T>> for InverseDoseEquivalent<T> where T: NumLike {
+	type Output = InverseEnergy<T>;
+	fn mul(self, rhs: &InverseMass<T>) -> Self::Output {
+		InverseEnergy{per_J: self.per_Sv * rhs.per_kg.clone()}
+	}
+}
+/// Multiplying a InverseDoseEquivalent by a InverseMass returns a value of type InverseEnergy
+impl<T> core::ops::Mul<&InverseMass<T>> for &InverseDoseEquivalent<T> where T: NumLike {
+	type Output = InverseEnergy<T>;
+	fn mul(self, rhs: &InverseMass<T>) -> Self::Output {
+		InverseEnergy{per_J: self.per_Sv.clone() * rhs.per_kg.clone()}
+	}
+}
+
+// InverseDoseEquivalent / InverseTemperature -> InverseSpecificHeatCapacity
+/// Dividing a InverseDoseEquivalent by a InverseTemperature returns a value of type InverseSpecificHeatCapacity
+impl<T> core::ops::Div<InverseTemperature<T>> for InverseDoseEquivalent<T> where T: NumLike {
+	type Output = InverseSpecificHeatCapacity<T>;
+	fn div(self, rhs: InverseTemperature<T>) -> Self::Output {
+		InverseSpecificHeatCapacity{kgK_per_J: self.per_Sv / rhs.per_K}
+	}
+}
+/// Dividing a InverseDoseEquivalent by a InverseTemperature returns a value of type InverseSpecificHeatCapacity
+impl<T> core::ops::Div<InverseTemperature<T>> for &InverseDoseEquivalent<T> where T: NumLike {
+	type Output = InverseSpecificHeatCapacity<T>;
+	fn div(self, rhs: InverseTemperature<T>) -> Self::Output {
+		InverseSpecificHeatCapacity{kgK_per_J: self.per_Sv.clone() / rhs.per_K}
+	}
+}
+/// Dividing a InverseDoseEquivalent by a InverseTemperature returns a value of type InverseSpecificHeatCapacity
+impl<T> core::ops::Div<&InverseTemperature<T>> for InverseDoseEquivalent<T> where T: NumLike {
+	type Output = InverseSpecificHeatCapacity<T>;
+	fn div(self, rhs: &InverseTemperature<T>) -> Self::Output {
+		InverseSpecificHeatCapacity{kgK_per_J: self.per_Sv / rhs.per_K.clone()}
+	}
+}
+/// Dividing a InverseDoseEquivalent by a InverseTemperature returns a value of type InverseSpecificHeatCapacity
+impl<T> core::ops::Div<&InverseTemperature<T>> for &InverseDoseEquivalent<T> where T: NumLike {
+	type Output = InverseSpecificHeatCapacity<T>;
+	fn div(self, rhs: &InverseTemperature<T>) -> Self::Output {
+		InverseSpecificHeatCapacity{kgK_per_J: self.per_Sv.clone() / rhs.per_K.clone()}
+	}
+}
+
+// InverseDoseEquivalent / Mass -> InverseEnergy
+/// Dividing a InverseDoseEquivalent by a Mass returns a value of type InverseEnergy
+impl<T> core::ops::Div<Mass<T>> for InverseDoseEquivalent<T> where T: NumLike {
+	type Output = InverseEnergy<T>;
+	fn div(self, rhs: Mass<T>) -> Self::Output {
+		InverseEnergy{per_J: self.per_Sv / rhs.kg}
+	}
+}
+/// Dividing a InverseDoseEquivalent by a Mass returns a value of type InverseEnergy
+impl<T> core::ops::Div<Mass<T>> for &InverseDoseEquivalent<T> where T: NumLike {
+	type Output = InverseEnergy<T>;
+	fn div(self, rhs: Mass<T>) -> Self::Output {
+		InverseEnergy{per_J: self.per_Sv.clone() / rhs.kg}
+	}
+}
+/// Dividing a InverseDoseEquivalent by a Mass returns a value of type InverseEnergy
+impl<T> core::ops::Div<&Mass<T>> for InverseDoseEquivalent<T> where T: NumLike {
+	type Output = InverseEnergy<T>;
+	fn div(self, rhs: &Mass<T>) -> Self::Output {
+		InverseEnergy{per_J: self.per_Sv / rhs.kg.clone()}
+	}
+}
+/// Dividing a InverseDoseEquivalent by a Mass returns a value of type InverseEnergy
+impl<T> core::ops::Div<&Mass<T>> for &InverseDoseEquivalent<T> where T: NumLike {
+	type Output = InverseEnergy<T>;
+	fn div(self, rhs: &Mass<T>) -> Self::Output {
+		InverseEnergy{per_J: self.per_Sv.clone() / rhs.kg.clone()}
+	}
+}
+
+// InverseDoseEquivalent * Temperature -> InverseSpecificHeatCapacity
+/// Multiplying a InverseDoseEquivalent by a Temperature returns a value of type InverseSpecificHeatCapacity
+impl<T> core::ops::Mul<Temperature<T>> for InverseDoseEquivalent<T> where T: NumLike {
+	type Output = InverseSpecificHeatCapacity<T>;
+	fn mul(self, rhs: Temperature<T>) -> Self::Output {
+		InverseSpecificHeatCapacity{kgK_per_J: self.per_Sv * rhs.K}
+	}
+}
+/// Multiplying a InverseDoseEquivalent by a Temperature returns a value of type InverseSpecificHeatCapacity
+impl<T> core::ops::Mul<Temperature<T>> for &InverseDoseEquivalent<T> where T: NumLike {
+	type Output = InverseSpecificHeatCapacity<T>;
+	fn mul(self, rhs: Temperature<T>) -> Self::Output {
+		InverseSpecificHeatCapacity{kgK_per_J: self.per_Sv.clone() * rhs.K}
+	}
+}
+/// Multiplying a InverseDoseEquivalent by a Temperature returns a value of type InverseSpecificHeatCapacity
+impl<T> core::ops::Mul<&Temperature<T>> for InverseDoseEquivalent<T> where T: NumLike {
+	type Output = InverseSpecificHeatCapacity<T>;
+	fn mul(self, rhs: &Temperature<T>) -> Self::Output {
+		InverseSpecificHeatCapacity{kgK_per_J: self.per_Sv * rhs.K.clone()}
+	}
+}
+/// Multiplying a InverseDoseEquivalent by a Temperature returns a value of type InverseSpecificHeatCapacity
+impl<T> core::ops::Mul<&Temperature<T>> for &InverseDoseEquivalent<T> where T: NumLike {
+	type Output = InverseSpecificHeatCapacity<T>;
+	fn mul(self, rhs: &Temperature<T>) -> Self::Output {
+		InverseSpecificHeatCapacity{kgK_per_J: self.per_Sv.clone() * rhs.K.clone()}
+	}
+}
+
+// InverseDoseEquivalent * TemperatureInterval -> InverseSpecificHeatCapacity
+/// Multiplying a InverseDoseEquivalent by a TemperatureInterval returns a value of type InverseSpecificHeatCapacity
+impl<T> core::ops::Mul<TemperatureInterval<T>> for InverseDoseEquivalent<T> where T: NumLike {
+	type Output = InverseSpecificHeatCapacity<T>;
+	fn mul(self, rhs: TemperatureInterval<T>) -> Self::Output {
+		InverseSpecificHeatCapacity{kgK_per_J: self.per_Sv * rhs.K}
+	}
+}
+/// Multiplying a InverseDoseEquivalent by a TemperatureInterval returns a value of type InverseSpecificHeatCapacity
+impl<T> core::ops::Mul<TemperatureInterval<T>> for &InverseDoseEquivalent<T> where T: NumLike {
+	type Output = InverseSpecificHeatCapacity<T>;
+	fn mul(self, rhs: TemperatureInterval<T>) -> Self::Output {
+		InverseSpecificHeatCapacity{kgK_per_J: self.per_Sv.clone() * rhs.K}
+	}
+}
+/// Multiplying a InverseDoseEquivalent by a TemperatureInterval returns a value of type InverseSpecificHeatCapacity
+impl<T> core::ops::Mul<&TemperatureInterval<T>> for InverseDoseEquivalent<T> where T: NumLike {
+	type Output = InverseSpecificHeatCapacity<T>;
+	fn mul(self, rhs: &TemperatureInterval<T>) -> Self::Output {
+		InverseSpecificHeatCapacity{kgK_per_J: self.per_Sv * rhs.K.clone()}
+	}
+}
+/// Multiplying a InverseDoseEquivalent by a TemperatureInterval returns a value of type InverseSpecificHeatCapacity
+impl<T> core::ops::Mul<&TemperatureInterval<T>> for &InverseDoseEquivalent<T> where T: NumLike {
+	type Output = InverseSpecificHeatCapacity<T>;
+	fn mul(self, rhs: &TemperatureInterval<T>) -> Self::Output {
+		InverseSpecificHeatCapacity{kgK_per_J: self.per_Sv.clone() * rhs.K.clone()}
+	}
+}
+
+// InverseDoseEquivalent / Time -> InverseKinematicViscosity
+/// Dividing a InverseDoseEquivalent by a Time returns a value of type InverseKinematicViscosity
+impl<T> core::ops::Div<Time<T>> for InverseDoseEquivalent<T> where T: NumLike {
+	type Output = InverseKinematicViscosity<T>;
+	fn div(self, rhs: Time<T>) -> Self::Output {
+		InverseKinematicViscosity{s_per_m2: self.per_Sv / rhs.s}
+	}
+}
+/// Dividing a InverseDoseEquivalent by a Time returns a value of type InverseKinematicViscosity
+impl<T> core::ops::Div<Time<T>> for &InverseDoseEquivalent<T> where T: NumLike {
+	type Output = InverseKinematicViscosity<T>;
+	fn div(self, rhs: Time<T>) -> Self::Output {
+		InverseKinematicViscosity{s_per_m2: self.per_Sv.clone() / rhs.s}
+	}
+}
+/// Dividing a InverseDoseEquivalent by a Time returns a value of type InverseKinematicViscosity
+impl<T> core::ops::Div<&Time<T>> for InverseDoseEquivalent<T> where T: NumLike {
+	type Output = InverseKinematicViscosity<T>;
+	fn div(self, rhs: &Time<T>) -> Self::Output {
+		InverseKinematicViscosity{s_per_m2: self.per_Sv / rhs.s.clone()}
+	}
+}
+/// Dividing a InverseDoseEquivalent by a Time returns a value of type InverseKinematicViscosity
+impl<T> core::ops::Div<&Time<T>> for &InverseDoseEquivalent<T> where T: NumLike {
+	type Output = InverseKinematicViscosity<T>;
+	fn div(self, rhs: &Time<T>) -> Self::Output {
+		InverseKinematicViscosity{s_per_m2: self.per_Sv.clone() / rhs.s.clone()}
+	}
+}
+
+// InverseDoseEquivalent / InverseMolarEnergy -> MolarMass
+/// Dividing a InverseDoseEquivalent by a InverseMolarEnergy returns a value of type MolarMass
+impl<T> core::ops::Div<InverseMolarEnergy<T>> for InverseDoseEquivalent<T> where T: NumLike {
+	type Output = MolarMass<T>;
+	fn div(self, rhs: InverseMolarEnergy<T>) -> Self::Output {
+		MolarMass{kgpmol: self.per_Sv / rhs.mol_per_J}
+	}
+}
+/// Dividing a InverseDoseEquivalent by a InverseMolarEnergy returns a value of type MolarMass
+impl<T> core::ops::Div<InverseMolarEnergy<T>> for &InverseDoseEquivalent<T> where T: NumLike {
+	type Output = MolarMass<T>;
+	fn div(self, rhs: InverseMolarEnergy<T>) -> Self::Output {
+		MolarMass{kgpmol: self.per_Sv.clone() / rhs.mol_per_J}
+	}
+}
+/// Dividing a InverseDoseEquivalent by a InverseMolarEnergy returns a value of type MolarMass
+impl<T> core::ops::Div<&InverseMolarEnergy<T>> for InverseDoseEquivalent<T> where T: NumLike {
+	type Output = MolarMass<T>;
+	fn div(self, rhs: &InverseMolarEnergy<T>) -> Self::Output {
+		MolarMass{kgpmol: self.per_Sv / rhs.mol_per_J.clone()}
+	}
+}
+/// Dividing a InverseDoseEquivalent by a InverseMolarEnergy returns a value of type MolarMass
+impl<T> core::ops::Div<&InverseMolarEnergy<T>> for &InverseDoseEquivalent<T> where T: NumLike {
+	type Output = MolarMass<T>;
+	fn div(self, rhs: &InverseMolarEnergy<T>) -> Self::Output {
+		MolarMass{kgpmol: self.per_Sv.clone() / rhs.mol_per_J.clone()}
+	}
+}
+
+// InverseDoseEquivalent / InverseSpecificHeatCapacity -> InverseTemperature
+/// Dividing a InverseDoseEquivalent by a InverseSpecificHeatCapacity returns a value of type InverseTemperature
+impl<T> core::ops::Div<InverseSpecificHeatCapacity<T>> for InverseDoseEquivalent<T> where T: NumLike {
+	type Output = InverseTemperature<T>;
+	fn div(self, rhs: InverseSpecificHeatCapacity<T>) -> Self::Output {
+		InverseTemperature{per_K: self.per_Sv / rhs.kgK_per_J}
+	}
+}
+/// Dividing a InverseDoseEquivalent by a InverseSpecificHeatCapacity returns a value of type InverseTemperature
+impl<T> core::ops::Div<InverseSpecificHeatCapacity<T>> for &InverseDoseEquivalent<T> where T: NumLike {
+	type Output = InverseTemperature<T>;
+	fn div(self, rhs: InverseSpecificHeatCapacity<T>) -> Self::Output {
+		InverseTemperature{per_K: self.per_Sv.clone() / rhs.kgK_per_J}
+	}
+}
+/// Dividing a InverseDoseEquivalent by a InverseSpecificHeatCapacity returns a value of type InverseTemperature
+impl<T> core::ops::Div<&InverseSpecificHeatCapacity<T>> for InverseDoseEquivalent<T> where T: NumLike {
+	type Output = InverseTemperature<T>;
+	fn div(self, rhs: &InverseSpecificHeatCapacity<T>) -> Self::Output {
+		InverseTemperature{per_K: self.per_Sv / rhs.kgK_per_J.clone()}
+	}
+}
+/// Dividing a InverseDoseEquivalent by a InverseSpecificHeatCapacity returns a value of type InverseTemperature
+impl<T> core::ops::Div<&InverseSpecificHeatCapacity<T>> for &InverseDoseEquivalent<T> where T: NumLike {
+	type Output = InverseTemperature<T>;
+	fn div(self, rhs: &InverseSpecificHeatCapacity<T>) -> Self::Output {
+		InverseTemperature{per_K: self.per_Sv.clone() / rhs.kgK_per_J.clone()}
+	}
+}
+
+// InverseDoseEquivalent * Molality -> InverseMolarEnergy
+/// Multiplying a InverseDoseEquivalent by a Molality returns a value of type InverseMolarEnergy
+impl<T> core::ops::Mul<Molality<T>> for InverseDoseEquivalent<T> where T: NumLike {
+	type Output = InverseMolarEnergy<T>;
+	fn mul(self, rhs: Molality<T>) -> Self::Output {
+		InverseMolarEnergy{mol_per_J: self.per_Sv * rhs.molpkg}
+	}
+}
+/// Multiplying a InverseDoseEquivalent by a Molality returns a value of type InverseMolarEnergy
+impl<T> core::ops::Mul<Molality<T>> for &InverseDoseEquivalent<T> where T: NumLike {
+	type Output = InverseMolarEnergy<T>;
+	fn mul(self, rhs: Molality<T>) -> Self::Output {
+		InverseMolarEnergy{mol_per_J: self.per_Sv.clone() * rhs.molpkg}
+	}
+}
+/// Multiplying a InverseDoseEquivalent by a Molality returns a value of type InverseMolarEnergy
+impl<T> core::ops::Mul<&Molality<T>> for InverseDoseEquivalent<T> where T: NumLike {
+	type Output = InverseMolarEnergy<T>;
+	fn mul(self, rhs: &Molality<T>) -> Self::Output {
+		InverseMolarEnergy{mol_per_J: self.per_Sv * rhs.molpkg.clone()}
+	}
+}
+/// Multiplying a InverseDoseEquivalent by a Molality returns a value of type InverseMolarEnergy
+impl<T> core::ops::Mul<&Molality<T>> for &InverseDoseEquivalent<T> where T: NumLike {
+	type Output = InverseMolarEnergy<T>;
+	fn mul(self, rhs: &Molality<T>) -> Self::Output {
+		InverseMolarEnergy{mol_per_J: self.per_Sv.clone() * rhs.molpkg.clone()}
+	}
+}
+
+// InverseDoseEquivalent * MolarEnergy -> MolarMass
+/// Multiplying a InverseDoseEquivalent by a MolarEnergy returns a value of type MolarMass
+impl<T> core::ops::Mul<MolarEnergy<T>> for InverseDoseEquivalent<T> where T: NumLike {
+	type Output = MolarMass<T>;
+	fn mul(self, rhs: MolarEnergy<T>) -> Self::Output {
+		MolarMass{kgpmol: self.per_Sv * rhs.J_per_mol}
+	}
+}
+/// Multiplying a InverseDoseEquivalent by a MolarEnergy returns a value of type MolarMass
+impl<T> core::ops::Mul<MolarEnergy<T>> for &InverseDoseEquivalent<T> where T: NumLike {
+	type Output = MolarMass<T>;
+	fn mul(self, rhs: MolarEnergy<T>) -> Self::Output {
+		MolarMass{kgpmol: self.per_Sv.clone() * rhs.J_per_mol}
+	}
+}
+/// Multiplying a InverseDoseEquivalent by a MolarEnergy returns a value of type MolarMass
+impl<T> core::ops::Mul<&MolarEnergy<T>> for InverseDoseEquivalent<T> where T: NumLike {
+	type Output = MolarMass<T>;
+	fn mul(self, rhs: &MolarEnergy<T>) -> Self::Output {
+		MolarMass{kgpmol: self.per_Sv * rhs.J_per_mol.clone()}
+	}
+}
+/// Multiplying a InverseDoseEquivalent by a MolarEnergy returns a value of type MolarMass
+impl<T> core::ops::Mul<&MolarEnergy<T>> for &InverseDoseEquivalent<T> where T: NumLike {
+	type Output = MolarMass<T>;
+	fn mul(self, rhs: &MolarEnergy<T>) -> Self::Output {
+		MolarMass{kgpmol: self.per_Sv.clone() * rhs.J_per_mol.clone()}
+	}
+}
+
+// InverseDoseEquivalent / MolarMass -> InverseMolarEnergy
+/// Dividing a InverseDoseEquivalent by a MolarMass returns a value of type InverseMolarEnergy
+impl<T> core::ops::Div<MolarMass<T>> for InverseDoseEquivalent<T> where T: NumLike {
+	type Output = InverseMolarEnergy<T>;
+	fn div(self, rhs: MolarMass<T>) -> Self::Output {
+		InverseMolarEnergy{mol_per_J: self.per_Sv / rhs.kgpmol}
+	}
+}
+/// Dividing a InverseDoseEquivalent by a MolarMass returns a value of type InverseMolarEnergy
+impl<T> core::ops::Div<MolarMass<T>> for &InverseDoseEquivalent<T> where T: NumLike {
+	type Output = InverseMolarEnergy<T>;
+	fn div(self, rhs: MolarMass<T>) -> Self::Output {
+		InverseMolarEnergy{mol_per_J: self.per_Sv.clone() / rhs.kgpmol}
+	}
+}
+/// Dividing a InverseDoseEquivalent by a MolarMass returns a value of type InverseMolarEnergy
+impl<T> core::ops::Div<&MolarMass<T>> for InverseDoseEquivalent<T> where T: NumLike {
+	type Output = InverseMolarEnergy<T>;
+	fn div(self, rhs: &MolarMass<T>) -> Self::Output {
+		InverseMolarEnergy{mol_per_J: self.per_Sv / rhs.kgpmol.clone()}
+	}
+}
+/// Dividing a InverseDoseEquivalent by a MolarMass returns a value of type InverseMolarEnergy
+impl<T> core::ops::Div<&MolarMass<T>> for &InverseDoseEquivalent<T> where T: NumLike {
+	type Output = InverseMolarEnergy<T>;
+	fn div(self, rhs: &MolarMass<T>) -> Self::Output {
+		InverseMolarEnergy{mol_per_J: self.per_Sv.clone() / rhs.kgpmol.clone()}
+	}
+}
+
+// InverseDoseEquivalent * SpecificHeatCapacity -> InverseTemperature
+/// Multiplying a InverseDoseEquivalent by a SpecificHeatCapacity returns a value of type InverseTemperature
+impl<T> core::ops::Mul<SpecificHeatCapacity<T>> for InverseDoseEquivalent<T> where T: NumLike {
+	type Output = InverseTemperature<T>;
+	fn mul(self, rhs: SpecificHeatCapacity<T>) -> Self::Output {
+		InverseTemperature{per_K: self.per_Sv * rhs.J_per_kgK}
+	}
+}
+/// Multiplying a InverseDoseEquivalent by a SpecificHeatCapacity returns a value of type InverseTemperature
+impl<T> core::ops::Mul<SpecificHeatCapacity<T>> for &InverseDoseEquivalent<T> where T: NumLike {
+	type Output = InverseTemperature<T>;
+	fn mul(self, rhs: SpecificHeatCapacity<T>) -> Self::Output {
+		InverseTemperature{per_K: self.per_Sv.clone() * rhs.J_per_kgK}
+	}
+}
+/// Multiplying a InverseDoseEquivalent by a SpecificHeatCapacity returns a value of type InverseTemperature
+impl<T> core::ops::Mul<&SpecificHeatCapacity<T>> for InverseDoseEquivalent<T> where T: NumLike {
+	type Output = InverseTemperature<T>;
+	fn mul(self, rhs: &SpecificHeatCapacity<T>) -> Self::Output {
+		InverseTemperature{per_K: self.per_Sv * rhs.J_per_kgK.clone()}
+	}
+}
+/// Multiplying a InverseDoseEquivalent by a SpecificHeatCapacity returns a value of type InverseTemperature
+impl<T> core::ops::Mul<&SpecificHeatCapacity<T>> for &InverseDoseEquivalent<T> where T: NumLike {
+	type Output = InverseTemperature<T>;
+	fn mul(self, rhs: &SpecificHeatCapacity<T>) -> Self::Output {
+		InverseTemperature{per_K: self.per_Sv.clone() * rhs.J_per_kgK.clone()}
+	}
+}
+
+// InverseDoseEquivalent * Acceleration -> InverseDistance
+/// Multiplying a InverseDoseEquivalent by a Acceleration returns a value of type InverseDistance
+impl<T> core::ops::Mul<Acceleration<T>> for InverseDoseEquivalent<T> where T: NumLike {
+	type Output = InverseDistance<T>;
+	fn mul(self, rhs: Acceleration<T>) -> Self::Output {
+		InverseDistance{per_m: self.per_Sv * rhs.mps2}
+	}
+}
+/// Multiplying a InverseDoseEquivalent by a Acceleration returns a value of type InverseDistance
+impl<T> core::ops::Mul<Acceleration<T>> for &InverseDoseEquivalent<T> where T: NumLike {
+	type Output = InverseDistance<T>;
+	fn mul(self, rhs: Acceleration<T>) -> Self::Output {
+		InverseDistance{per_m: self.per_Sv.clone() * rhs.mps2}
+	}
+}
+/// Multiplying a InverseDoseEquivalent by a Acceleration returns a value of type InverseDistance
+impl<T> core::ops::Mul<&Acceleration<T>> for InverseDoseEquivalent<T> where T: NumLike {
+	type Output = InverseDistance<T>;
+	fn mul(self, rhs: &Acceleration<T>) -> Self::Output {
+		InverseDistance{per_m: self.per_Sv * rhs.mps2.clone()}
+	}
+}
+/// Multiplying a InverseDoseEquivalent by a Acceleration returns a value of type InverseDistance
+impl<T> core::ops::Mul<&Acceleration<T>> for &InverseDoseEquivalent<T> where T: NumLike {
+	type Output = InverseDistance<T>;
+	fn mul(self, rhs: &Acceleration<T>) -> Self::Output {
+		InverseDistance{per_m: self.per_Sv.clone() * rhs.mps2.clone()}
+	}
+}
+
+// InverseDoseEquivalent / Density -> InversePressure
+/// Dividing a InverseDoseEquivalent by a Density returns a value of type InversePressure
+impl<T> core::ops::Div<Density<T>> for InverseDoseEquivalent<T> where T: NumLike {
+	type Output = InversePressure<T>;
+	fn div(self, rhs: Density<T>) -> Self::Output {
+		InversePressure{per_Pa: self.per_Sv / rhs.kgpm3}
+	}
+}
+/// Dividing a InverseDoseEquivalent by a Density returns a value of type InversePressure
+impl<T> core::ops::Div<Density<T>> for &InverseDoseEquivalent<T> where T: NumLike {
+	type Output = InversePressure<T>;
+	fn div(self, rhs: Density<T>) -> Self::Output {
+		InversePressure{per_Pa: self.per_Sv.clone() / rhs.kgpm3}
+	}
+}
+/// Dividing a InverseDoseEquivalent by a Density returns a value of type InversePressure
+impl<T> core::ops::Div<&Density<T>> for InverseDoseEquivalent<T> where T: NumLike {
+	type Output = InversePressure<T>;
+	fn div(self, rhs: &Density<T>) -> Self::Output {
+		InversePressure{per_Pa: self.per_Sv / rhs.kgpm3.clone()}
+	}
+}
+/// Dividing a InverseDoseEquivalent by a Density returns a value of type InversePressure
+impl<T> core::ops::Div<&Density<T>> for &InverseDoseEquivalent<T> where T: NumLike {
+	type Output = InversePressure<T>;
+	fn div(self, rhs: &Density<T>) -> Self::Output {
+		InversePressure{per_Pa: self.per_Sv.clone() / rhs.kgpm3.clone()}
+	}
+}
+
+// InverseDoseEquivalent * Energy -> Mass
+/// Multiplying a InverseDoseEquivalent by a Energy returns a value of type Mass
+impl<T> core::ops::Mul<Energy<T>> for InverseDoseEquivalent<T> where T: NumLike {
+	type Output = Mass<T>;
+	fn mul(self, rhs: Energy<T>) -> Self::Output {
+		Mass{kg: self.per_Sv * rhs.J}
+	}
+}
+/// Multiplying a InverseDoseEquivalent by a Energy returns a value of type Mass
+impl<T> core::ops::Mul<Energy<T>> for &InverseDoseEquivalent<T> where T: NumLike {
+	type Output = Mass<T>;
+	fn mul(self, rhs: Energy<T>) -> Self::Output {
+		Mass{kg: self.per_Sv.clone() * rhs.J}
+	}
+}
+/// Multiplying a InverseDoseEquivalent by a Energy returns a value of type Mass
+impl<T> core::ops::Mul<&Energy<T>> for InverseDoseEquivalent<T> where T: NumLike {
+	type Output = Mass<T>;
+	fn mul(self, rhs: &Energy<T>) -> Self::Output {
+		Mass{kg: self.per_Sv * rhs.J.clone()}
+	}
+}
+/// Multiplying a InverseDoseEquivalent by a Energy returns a value of type Mass
+impl<T> core::ops::Mul<&Energy<T>> for &InverseDoseEquivalent<T> where T: NumLike {
+	type Output = Mass<T>;
+	fn mul(self, rhs: &Energy<T>) -> Self::Output {
+		Mass{kg: self.per_Sv.clone() * rhs.J.clone()}
+	}
+}
+
+// InverseDoseEquivalent * Torque -> Mass
+/// Multiplying a InverseDoseEquivalent by a Torque returns a value of type Mass
+impl<T> core::ops::Mul<Torque<T>> for InverseDoseEquivalent<T> where T: NumLike {
+	type Output = Mass<T>;
+	fn mul(self, rhs: Torque<T>) -> Self::Output {
+		Mass{kg: self.per_Sv * rhs.Nm}
+	}
+}
+/// Multiplying a InverseDoseEquivalent by a Torque returns a value of type Mass
+impl<T> core::ops::Mul<Torque<T>> for &InverseDoseEquivalent<T> where T: NumLike {
+	type Output = Mass<T>;
+	fn mul(self, rhs: Torque<T>) -> Self::Output {
+		Mass{kg: self.per_Sv.clone() * rhs.Nm}
+	}
+}
+/// Multiplying a InverseDoseEquivalent by a Torque returns a value of type Mass
+impl<T> core::ops::Mul<&Torque<T>> for InverseDoseEquivalent<T> where T: NumLike {
+	type Output = Mass<T>;
+	fn mul(self, rhs: &Torque<T>) -> Self::Output {
+		Mass{kg: self.per_Sv * rhs.Nm.clone()}
+	}
+}
+/// Multiplying a InverseDoseEquivalent by a Torque returns a value of type Mass
+impl<T> core::ops::Mul<&Torque<T>> for &InverseDoseEquivalent<T> where T: NumLike {
+	type Output = Mass<T>;
+	fn mul(self, rhs: &Torque<T>) -> Self::Output {
+		Mass{kg: self.per_Sv.clone() * rhs.Nm.clone()}
+	}
+}
+
+// InverseDoseEquivalent * Frequency -> InverseKinematicViscosity
+/// Multiplying a InverseDoseEquivalent by a Frequency returns a value of type InverseKinematicViscosity
+impl<T> core::ops::Mul<Frequency<T>> for InverseDoseEquivalent<T> where T: NumLike {
+	type Output = InverseKinematicViscosity<T>;
+	fn mul(self, rhs: Frequency<T>) -> Self::Output {
+		InverseKinematicViscosity{s_per_m2: self.per_Sv * rhs.Hz}
+	}
+}
+/// Multiplying a InverseDoseEquivalent by a Frequency returns a value of type InverseKinematicViscosity
+impl<T> core::ops::Mul<Frequency<T>> for &InverseDoseEquivalent<T> where T: NumLike {
+	type Output = InverseKinematicViscosity<T>;
+	fn mul(self, rhs: Frequency<T>) -> Self::Output {
+		InverseKinematicViscosity{s_per_m2: self.per_Sv.clone() * rhs.Hz}
+	}
+}
+/// Multiplying a InverseDoseEquivalent by a Frequency returns a value of type InverseKinematicViscosity
+impl<T> core::ops::Mul<&Frequency<T>> for InverseDoseEquivalent<T> where T: NumLike {
+	type Output = InverseKinematicViscosity<T>;
+	fn mul(self, rhs: &Frequency<T>) -> Self::Output {
+		InverseKinematicViscosity{s_per_m2: self.per_Sv * rhs.Hz.clone()}
+	}
+}
+/// Multiplying a InverseDoseEquivalent by a Frequency returns a value of type InverseKinematicViscosity
+impl<T> core::ops::Mul<&Frequency<T>> for &InverseDoseEquivalent<T> where T: NumLike {
+	type Output = InverseKinematicViscosity<T>;
+	fn mul(self, rhs: &Frequency<T>) -> Self::Output {
+		InverseKinematicViscosity{s_per_m2: self.per_Sv.clone() * rhs.Hz.clone()}
+	}
+}
+
+// InverseDoseEquivalent / InverseAcceleration -> InverseDistance
+/// Dividing a InverseDoseEquivalent by a InverseAcceleration returns a value of type InverseDistance
+impl<T> core::ops::Div<InverseAcceleration<T>> for InverseDoseEquivalent<T> where T: NumLike {
+	type Output = InverseDistance<T>;
+	fn div(self, rhs: InverseAcceleration<T>) -> Self::Output {
+		InverseDistance{per_m: self.per_Sv / rhs.s2pm}
+	}
+}
+/// Dividing a InverseDoseEquivalent by a InverseAcceleration returns a value of type InverseDistance
+impl<T> core::ops::Div<InverseAcceleration<T>> for &InverseDoseEquivalent<T> where T: NumLike {
+	type Output = InverseDistance<T>;
+	fn div(self, rhs: InverseAcceleration<T>) -> Self::Output {
+		InverseDistance{per_m: self.per_Sv.clone() / rhs.s2pm}
+	}
+}
+/// Dividing a InverseDoseEquivalent by a InverseAcceleration returns a value of type InverseDistance
+impl<T> core::ops::Div<&InverseAcceleration<T>> for InverseDoseEquivalent<T> where T: NumLike {
+	type Output = InverseDistance<T>;
+	fn div(self, rhs: &InverseAcceleration<T>) -> Self::Output {
+		InverseDistance{per_m: self.per_Sv / rhs.s2pm.clone()}
+	}
+}
+/// Dividing a InverseDoseEquivalent by a InverseAcceleration returns a value of type InverseDistance
+impl<T> core::ops::Div<&InverseAcceleration<T>> for &InverseDoseEquivalent<T> where T: NumLike {
+	type Output = InverseDistance<T>;
+	fn div(self, rhs: &InverseAcceleration<T>) -> Self::Output {
+		InverseDistance{per_m: self.per_Sv.clone() / rhs.s2pm.clone()}
+	}
+}
+
+// InverseDoseEquivalent / InverseEnergy -> Mass
+/// Dividing a InverseDoseEquivalent by a InverseEnergy returns a value of type Mass
+impl<T> core::ops::Div<InverseEnergy<T>> for InverseDoseEquivalent<T> where T: NumLike {
+	type Output = Mass<T>;
+	fn div(self, rhs: InverseEnergy<T>) -> Self::Output {
+		Mass{kg: self.per_Sv / rhs.per_J}
+	}
+}
+/// Dividing a InverseDoseEquivalent by a InverseEnergy returns a value of type Mass
+impl<T> core::ops::Div<InverseEnergy<T>> for &InverseDoseEquivalent<T> where T: NumLike {
+	type Output = Mass<T>;
+	fn div(self, rhs: InverseEnergy<T>) -> Self::Output {
+		Mass{kg: self.per_Sv.clone() / rhs.per_J}
+	}
+}
+/// Dividing a InverseDoseEquivalent by a InverseEnergy returns a value of type Mass
+impl<T> core::ops::Div<&InverseEnergy<T>> for InverseDoseEquivalent<T> where T: NumLike {
+	type Output = Mass<T>;
+	fn div(self, rhs: &InverseEnergy<T>) -> Self::Output {
+		Mass{kg: self.per_Sv / rhs.per_J.clone()}
+	}
+}
+/// Dividing a InverseDoseEquivalent by a InverseEnergy returns a value of type Mass
+impl<T> core::ops::Div<&InverseEnergy<T>> for &InverseDoseEquivalent<T> where T: NumLike {
+	type Output = Mass<T>;
+	fn div(self, rhs: &InverseEnergy<T>) -> Self::Output {
+		Mass{kg: self.per_Sv.clone() / rhs.per_J.clone()}
+	}
+}
+
+// InverseDoseEquivalent / InverseTorque -> Mass
+/// Dividing a InverseDoseEquivalent by a InverseTorque returns a value of type Mass
+impl<T> core::ops::Div<InverseTorque<T>> for InverseDoseEquivalent<T> where T: NumLike {
+	type Output = Mass<T>;
+	fn div(self, rhs: InverseTorque<T>) -> Self::Output {
+		Mass{kg: self.per_Sv / rhs.per_Nm}
+	}
+}
+/// Dividing a InverseDoseEquivalent by a InverseTorque returns a value of type Mass
+impl<T> core::ops::Div<InverseTorque<T>> for &InverseDoseEquivalent<T> where T: NumLike {
+	type Output = Mass<T>;
+	fn div(self, rhs: InverseTorque<T>) -> Self::Output {
+		Mass{kg: self.per_Sv.clone() / rhs.per_Nm}
+	}
+}
+/// Dividing a InverseDoseEquivalent by a InverseTorque returns a value of type Mass
+impl<T> core::ops::Div<&InverseTorque<T>> for InverseDoseEquivalent<T> where T: NumLike {
+	type Output = Mass<T>;
+	fn div(self, rhs: &InverseTorque<T>) -> Self::Output {
+		Mass{kg: self.per_Sv / rhs.per_Nm.clone()}
+	}
+}
+/// Dividing a InverseDoseEquivalent by a InverseTorque returns a value of type Mass
+impl<T> core::ops::Div<&InverseTorque<T>> for &InverseDoseEquivalent<T> where T: NumLike {
+	type Output = Mass<T>;
+	fn div(self, rhs: &InverseTorque<T>) -> Self::Output {
+		Mass{kg: self.per_Sv.clone() / rhs.per_Nm.clone()}
+	}
+}
+
+// InverseDoseEquivalent / InverseKinematicViscosity -> Time
+/// Dividing a InverseDoseEquivalent by a InverseKinematicViscosity returns a value of type Time
+impl<T> core::ops::Div<InverseKinematicViscosity<T>> for InverseDoseEquivalent<T> where T: NumLike {
+	type Output = Time<T>;
+	fn div(self, rhs: InverseKinematicViscosity<T>) -> Self::Output {
+		Time{s: self.per_Sv / rhs.s_per_m2}
+	}
+}
+/// Dividing a InverseDoseEquivalent by a InverseKinematicViscosity returns a value of type Time
+impl<T> core::ops::Div<InverseKinematicViscosity<T>> for &InverseDoseEquivalent<T> where T: NumLike {
+	type Output = Time<T>;
+	fn div(self, rhs: InverseKinematicViscosity<T>) -> Self::Output {
+		Time{s: self.per_Sv.clone() / rhs.s_per_m2}
+	}
+}
+/// Dividing a InverseDoseEquivalent by a InverseKinematicViscosity returns a value of type Time
+impl<T> core::ops::Div<&InverseKinematicViscosity<T>> for InverseDoseEquivalent<T> where T: NumLike {
+	type Output = Time<T>;
+	fn div(self, rhs: &InverseKinematicViscosity<T>) -> Self::Output {
+		Time{s: self.per_Sv / rhs.s_per_m2.clone()}
+	}
+}
+/// Dividing a InverseDoseEquivalent by a InverseKinematicViscosity returns a value of type Time
+impl<T> core::ops::Div<&InverseKinematicViscosity<T>> for &InverseDoseEquivalent<T> where T: NumLike {
+	type Output = Time<T>;
+	fn div(self, rhs: &InverseKinematicViscosity<T>) -> Self::Output {
+		Time{s: self.per_Sv.clone() / rhs.s_per_m2.clone()}
+	}
+}
+
+// InverseDoseEquivalent * InverseMassFlowRate -> InversePower
+/// Multiplying a InverseDoseEquivalent by a InverseMassFlowRate returns a value of type InversePower
+impl<T> core::ops::Mul<InverseMassFlowRate<T>> for InverseDoseEquivalent<T> where T: NumLike {
+	type Output = InversePower<T>;
+	fn mul(self, rhs: InverseMassFlowRate<T>) -> Self::Output {
+		InversePower{per_W: self.per_Sv * rhs.s_per_kg}
+	}
+}
+/// Multiplying a InverseDoseEquivalent by a InverseMassFlowRate returns a value of type InversePower
+impl<T> core::ops::Mul<InverseMassFlowRate<T>> for &InverseDoseEquivalent<T> where T: NumLike {
+	type Output = InversePower<T>;
+	fn mul(self, rhs: InverseMassFlowRate<T>) -> Self::Output {
+		InversePower{per_W: self.per_Sv.clone() * rhs.s_per_kg}
+	}
+}
+/// Multiplying a InverseDoseEquivalent by a InverseMassFlowRate returns a value of type InversePower
+impl<T> core::ops::Mul<&InverseMassFlowRate<T>> for InverseDoseEquivalent<T> where T: NumLike {
+	type Output = InversePower<T>;
+	fn mul(self, rhs: &InverseMassFlowRate<T>) -> Self::Output {
+		InversePower{per_W: self.per_Sv * rhs.s_per_kg.clone()}
+	}
+}
+/// Multiplying a InverseDoseEquivalent by a InverseMassFlowRate returns a value of type InversePower
+impl<T> core::ops::Mul<&InverseMassFlowRate<T>> for &InverseDoseEquivalent<T> where T: NumLike {
+	type Output = InversePower<T>;
+	fn mul(self, rhs: &InverseMassFlowRate<T>) -> Self::Output {
+		InversePower{per_W: self.per_Sv.clone() * rhs.s_per_kg.clone()}
+	}
+}
+
+// InverseDoseEquivalent / InversePower -> MassFlowRate
+/// Dividing a InverseDoseEquivalent by a InversePower returns a value of type MassFlowRate
+impl<T> core::ops::Div<InversePower<T>> for InverseDoseEquivalent<T> where T: NumLike {
+	type Output = MassFlowRate<T>;
+	fn div(self, rhs: InversePower<T>) -> Self::Output {
+		MassFlowRate{kgps: self.per_Sv / rhs.per_W}
+	}
+}
+/// Dividing a InverseDoseEquivalent by a InversePower returns a value of type MassFlowRate
+impl<T> core::ops::Div<InversePower<T>> for &InverseDoseEquivalent<T> where T: NumLike {
+	type Output = MassFlowRate<T>;
+	fn div(self, rhs: InversePower<T>) -> Self::Output {
+		MassFlowRate{kgps: self.per_Sv.clone() / rhs.per_W}
+	}
+}
+/// Dividing a InverseDoseEquivalent by a InversePower returns a value of type MassFlowRate
+impl<T> core::ops::Div<&InversePower<T>> for InverseDoseEquivalent<T> where T: NumLike {
+	type Output = MassFlowRate<T>;
+	fn div(self, rhs: &InversePower<T>) -> Self::Output {
+		MassFlowRate{kgps: self.per_Sv / rhs.per_W.clone()}
+	}
+}
+/// Dividing a InverseDoseEquivalent by a InversePower returns a value of type MassFlowRate
+impl<T> core::ops::Div<&InversePower<T>> for &InverseDoseEquivalent<T> where T: NumLike {
+	type Output = MassFlowRate<T>;
+	fn div(self, rhs: &InversePower<T>) -> Self::Output {
+		MassFlowRate{kgps: self.per_Sv.clone() / rhs.per_W.clone()}
+	}
+}
+
+// InverseDoseEquivalent / InversePressure -> Density
+/// Dividing a InverseDoseEquivalent by a InversePressure returns a value of type Density
+impl<T> core::ops::Div<InversePressure<T>> for InverseDoseEquivalent<T> where T: NumLike {
+	type Output = Density<T>;
+	fn div(self, rhs: InversePressure<T>) -> Self::Output {
+		Density{kgpm3: self.per_Sv / rhs.per_Pa}
+	}
+}
+/// Dividing a InverseDoseEquivalent by a InversePressure returns a value of type Density
+impl<T> core::ops::Div<InversePressure<T>> for &InverseDoseEquivalent<T> where T: NumLike {
+	type Output = Density<T>;
+	fn div(self, rhs: InversePressure<T>) -> Self::Output {
+		Density{kgpm3: self.per_Sv.clone() / rhs.per_Pa}
+	}
+}
+/// Dividing a InverseDoseEquivalent by a InversePressure returns a value of type Density
+impl<T> core::ops::Div<&InversePressure<T>> for InverseDoseEquivalent<T> where T: NumLike {
+	type Output = Density<T>;
+	fn div(self, rhs: &InversePressure<T>) -> Self::Output {
+		Density{kgpm3: self.per_Sv / rhs.per_Pa.clone()}
+	}
+}
+/// Dividing a InverseDoseEquivalent by a InversePressure returns a value of type Density
+impl<T> core::ops::Div<&InversePressure<T>> for &InverseDoseEquivalent<T> where T: NumLike {
+	type Output = Density<T>;
+	fn div(self, rhs: &InversePressure<T>) -> Self::Output {
+		Density{kgpm3: self.per_Sv.clone() / rhs.per_Pa.clone()}
 	}
+}
 
-	/// Returns a copy of this inverse absorbed dose value in inverse kilorads
-	/// 
-	/// *Note: This method is not available for `f32` and other number types lacking the `From<f64>` trait*
-	pub fn to_per_krad(&self) -> T {
-		return self.per_Gy.clone() * T::from(10.0_f64);
+// InverseDoseEquivalent * KinematicViscosity -> Time
+/// Multiplying a InverseDoseEquivalent by a KinematicViscosity returns a value of type Time
+impl<T> core::ops::Mul<KinematicViscosity<T>> for InverseDoseEquivalent<T> where T: NumLike {
+	type Output = Time<T>;
+	fn mul(self, rhs: KinematicViscosity<T>) -> Self::Output {
+		Time{s: self.per_Sv * rhs.m2ps}
 	}
-
-	/// Returns a new inverse absorbed dose value from the given number of inverse kilorads
-	/// 
-	/// *Note: This method is not available for `f32` and other number types lacking the `From<f64>` trait*
-	///
-	/// # Arguments
-	/// * `per_krad` - Any number-like type, representing a quantity of inverse kilorads
-	pub fn from_per_krad(per_krad: T) -> Self {
-		InverseAbsorbedDose{per_Gy: per_krad * T::from(0.1_f64)}
+}
+/// Multiplying a InverseDoseEquivalent by a KinematicViscosity returns a value of type Time
+impl<T> core::ops::Mul<KinematicViscosity<T>> for &InverseDoseEquivalent<T> where T: NumLike {
+	type Output = Time<T>;
+	fn mul(self, rhs: KinematicViscosity<T>) -> Self::Output {
+		Time{s: self.per_Sv.clone() * rhs.m2ps}
 	}
-
-	/// Returns a copy of this inverse absorbed dose value in inverse millirads
-	/// 
-	/// *Note: This method is not available for `f32` and other number types lacking the `From<f64>` trait*
-	pub fn to_per_mrad(&self) -> T {
-		return self.per_Gy.clone() * T::from(1e-05_f64);
+}
+/// Multiplying a InverseDoseEquivalent by a KinematicViscosity returns a value of type Time
+impl<T> core::ops::Mul<&KinematicViscosity<T>> for InverseDoseEquivalent<T> where T: NumLike {
+	type Output = Time<T>;
+	fn mul(self, rhs: &KinematicViscosity<T>) -> Self::Output {
+		Time{s: self.per_Sv * rhs.m2ps.clone()}
 	}
-
-	/// Returns a new inverse absorbed dose value from the given number of inverse millirads
-	/// 
-	/// *Note: This method is not available for `f32` and other number types lacking the `From<f64>` trait*
-	///
-	/// # Arguments
-	/// * `per_mrad` - Any number-like type, representing a quantity of inverse millirads
-	pub fn from_per_mrad(per_mrad: T) -> Self {
-		InverseAbsorbedDose{per_Gy: per_mrad * T::from(100000.0_f64)}
+}
+/// Multiplying a InverseDoseEquivalent by a KinematicViscosity returns a value of type Time
+impl<T> core::ops::Mul<&KinematicViscosity<T>> for &InverseDoseEquivalent<T> where T: NumLike {
+	type Output = Time<T>;
+	fn mul(self, rhs: &KinematicViscosity<T>) -> Self::Output {
+		Time{s: self.per_Sv.clone() * rhs.m2ps.clone()}
 	}
+}
 
-	/// Returns a copy of this inverse absorbed dose value in inverse microrads
-	/// 
-	/// *Note: This method is not available for `f32` and other number types lacking the `From<f64>` trait*
-	pub fn to_per_urad(&self) -> T {
-		return self.per_Gy.clone() * T::from(1e-08_f64);
+// InverseDoseEquivalent / MassFlowRate -> InversePower
+/// Dividing a InverseDoseEquivalent by a MassFlowRate returns a value of type InversePower
+impl<T> core::ops::Div<MassFlowRate<T>> for InverseDoseEquivalent<T> where T: NumLike {
+	type Output = InversePower<T>;
+	fn div(self, rhs: MassFlowRate<T>) -> Self::Output {
+		InversePower{per_W: self.per_Sv / rhs.kgps}
 	}
-
-	/// Returns a new inverse absorbed dose value from the given number of inverse microrads
-	/// 
-	/// *Note: This method is not available for `f32` and other number types lacking the `From<f64>` trait*
-	///
-	/// # Arguments
-	/// * `per_urad` - Any number-like type, representing a quantity of inverse microrads
-	pub fn from_per_urad(per_urad: T) -> Self {
-		InverseAbsorbedDose{per_Gy: per_urad * T::from(100000000.0_f64)}
+}
+/// Dividing a InverseDoseEquivalent by a MassFlowRate returns a value of type InversePower
+impl<T> core::ops::Div<MassFlowRate<T>> for &InverseDoseEquivalent<T> where T: NumLike {
+	type Output = InversePower<T>;
+	fn div(self, rhs: MassFlowRate<T>) -> Self::Output {
+		InversePower{per_W: self.per_Sv.clone() / rhs.kgps}
 	}
-
-	/// Returns a copy of this inverse absorbed dose value in gram per ergs
-	/// 
-	/// *Note: This method is not available for `f32` and other number types lacking the `From<f64>` trait*
-	pub fn to_per_erg(&self) -> T {
-		return self.per_Gy.clone() * T::from(0.0001_f64);
+}
+/// Dividing a InverseDoseEquivalent by a MassFlowRate returns a value of type InversePower
+impl<T> core::ops::Div<&MassFlowRate<T>> for InverseDoseEquivalent<T> where T: NumLike {
+	type Output = InversePower<T>;
+	fn div(self, rhs: &MassFlowRate<T>) -> Self::Output {
+		InversePower{per_W: self.per_Sv / rhs.kgps.clone()}
 	}
-
-	/// Returns a new inverse absorbed dose value from the given number of gram per ergs
-	/// 
-	/// *Note: This method is not available for `f32` and other number types lacking the `From<f64>` trait*
-	///
-	/// # Arguments
-	/// * `per_erg` - Any number-like type, representing a quantity of gram per ergs
-	pub fn from_per_erg(per_erg: T) -> Self {
-		InverseAbsorbedDose{per_Gy: per_erg * T::from(10000.0_f64)}
+}
+/// Dividing a InverseDoseEquivalent by a MassFlowRate returns a value of type InversePower
+impl<T> core::ops::Div<&MassFlowRate<T>> for &InverseDoseEquivalent<T> where T: NumLike {
+	type Output = InversePower<T>;
+	fn div(self, rhs: &MassFlowRate<T>) -> Self::Output {
+		InversePower{per_W: self.per_Sv.clone() / rhs.kgps.clone()}
 	}
-
 }
 
-
-/// Multiplying a unit value by a scalar value returns a unit value
-#[cfg(feature="num-bigfloat")]
-impl core::ops::Mul<InverseAbsorbedDose<num_bigfloat::BigFloat>> for num_bigfloat::BigFloat {
-	type Output = InverseAbsorbedDose<num_bigfloat::BigFloat>;
-	fn mul(self, rhs: InverseAbsorbedDose<num_bigfloat::BigFloat>) -> Self::Output {
-		InverseAbsorbedDose{per_Gy: self * rhs.per_Gy}
+// InverseDoseEquivalent * Power -> MassFlowRate
+/// Multiplying a InverseDoseEquivalent by a Power returns a value of type MassFlowRate
+impl<T> core::ops::Mul<Power<T>> for InverseDoseEquivalent<T> where T: NumLike {
+	type Output = MassFlowRate<T>;
+	fn mul(self, rhs: Power<T>) -> Self::Output {
+		MassFlowRate{kgps: self.per_Sv * rhs.W}
 	}
 }
-/// Multiplying a unit value by a scalar value returns a unit value
-#[cfg(feature="num-bigfloat")]
-impl core::ops::Mul<InverseAbsorbedDose<num_bigfloat::BigFloat>> for &num_bigfloat::BigFloat {
-	type Output = InverseAbsorbedDose<num_bigfloat::BigFloat>;
-	fn mul(self, rhs: InverseAbsorbedDose<num_bigfloat::BigFloat>) -> Self::Output {
-		InverseAbsorbedDose{per_Gy: self.clone() * rhs.per_Gy}
+/// Multiplying a InverseDoseEquivalent by a Power returns a value of type MassFlowRate
+impl<T> core::ops::Mul<Power<T>> for &InverseDoseEquivalent<T> where T: NumLike {
+	type Output = MassFlowRate<T>;
+	fn mul(self, rhs: Power<T>) -> Self::Output {
+		MassFlowRate{kgps: self.per_Sv.clone() * rhs.W}
 	}
 }
-/// Multiplying a unit value by a scalar value returns a unit value
-#[cfg(feature="num-bigfloat")]
-impl core::ops::Mul<&InverseAbsorbedDose<num_bigfloat::BigFloat>> for num_bigfloat::BigFloat {
-	type Output = InverseAbsorbedDose<num_bigfloat::BigFloat>;
-	fn mul(self, rhs: &InverseAbsorbedDose<num_bigfloat::BigFloat>) -> Self::Output {
-		InverseAbsorbedDose{per_Gy: self * rhs.per_Gy.clone()}
+/// Multiplying a InverseDoseEquivalent by a Power returns a value of type MassFlowRate
+impl<T> core::ops::Mul<&Power<T>> for InverseDoseEquivalent<T> where T: NumLike {
+	type Output = MassFlowRate<T>;
+	fn mul(self, rhs: &Power<T>) -> Self::Output {
+		MassFlowRate{kgps: self.per_Sv * rhs.W.clone()}
 	}
 }
-/// Multiplying a unit value by a scalar value returns a unit value
-#[cfg(feature="num-bigfloat")]
-impl core::ops::Mul<&InverseAbsorbedDose<num_bigfloat::BigFloat>> for &num_bigfloat::BigFloat {
-	type Output = InverseAbsorbedDose<num_bigfloat::BigFloat>;
-	fn mul(self, rhs: &InverseAbsorbedDose<num_bigfloat::BigFloat>) -> Self::Output {
-		InverseAbsorbedDose{per_Gy: self.clone() * rhs.per_Gy.clone()}
+/// Multiplying a InverseDoseEquivalent by a Power returns a value of type MassFlowRate
+impl<T> core::ops::Mul<&Power<T>> for &InverseDoseEquivalent<T> where T: NumLike {
+	type Output = MassFlowRate<T>;
+	fn mul(self, rhs: &Power<T>) -> Self::Output {
+		MassFlowRate{kgps: self.per_Sv.clone() * rhs.W.clone()}
 	}
 }
 
-/// Multiplying a unit value by a scalar value returns a unit value
-#[cfg(feature="num-complex")]
-impl core::ops::Mul<InverseAbsorbedDose<num_complex::Complex32>> for num_complex::Complex32 {
-	type Output = InverseAbsorbedDose<num_complex::Complex32>;
-	fn mul(self, rhs: InverseAbsorbedDose<num_complex::Complex32>) -> Self::Output {
-		InverseAbsorbedDose{per_Gy: self * rhs.per_Gy}
+// InverseDoseEquivalent * Pressure -> Density
+/// Multiplying a InverseDoseEquivalent by a Pressure returns a value of type Density
+impl<T> core::ops::Mul<Pressure<T>> for InverseDoseEquivalent<T> where T: NumLike {
+	type Output = Density<T>;
+	fn mul(self, rhs: Pressure<T>) -> Self::Output {
+		Density{kgpm3: self.per_Sv * rhs.Pa}
 	}
 }
-/// Multiplying a unit value by a scalar value returns a unit value
-#[cfg(feature="num-complex")]
-impl core::ops::Mul<InverseAbsorbedDose<num_complex::Complex32>> for &num_complex::Complex32 {
-	type Output = InverseAbsorbedDose<num_complex::Complex32>;
-	fn mul(self, rhs: InverseAbsorbedDose<num_complex::Complex32>) -> Self::Output {
-		InverseAbsorbedDose{per_Gy: self.clone() * rhs.per_Gy}
+/// Multiplying a InverseDoseEquivalent by a Pressure returns a value of type Density
+impl<T> core::ops::Mul<Pressure<T>> for &InverseDoseEquivalent<T> where T: NumLike {
+	type Output = Density<T>;
+	fn mul(self, rhs: Pressure<T>) -> Self::Output {
+		Density{kgpm3: self.per_Sv.clone() * rhs.Pa}
 	}
 }
-/// Multiplying a unit value by a scalar value returns a unit value
-#[cfg(feature="num-complex")]
-impl core::ops::Mul<&InverseAbsorbedDose<num_complex::Complex32>> for num_complex::Complex32 {
-	type Output = InverseAbsorbedDose<num_complex::Complex32>;
-	fn mul(self, rhs: &InverseAbsorbedDose<num_complex::Complex32>) -> Self::Output {
-		InverseAbsorbedDose{per_Gy: self * rhs.per_Gy.clone()}
+/// Multiplying a InverseDoseEquivalent by a Pressure returns a value of type Density
+impl<T> core::ops::Mul<&Pressure<T>> for InverseDoseEquivalent<T> where T: NumLike {
+	type Output = Density<T>;
+	fn mul(self, rhs: &Pressure<T>) -> Self::Output {
+		Density{kgpm3: self.per_Sv * rhs.Pa.clone()}
 	}
 }
-/// Multiplying a unit value by a scalar value returns a unit value
-#[cfg(feature="num-complex")]
-impl core::ops::Mul<&InverseAbsorbedDose<num_complex::Complex32>> for &num_complex::Complex32 {
-	type Output = InverseAbsorbedDose<num_complex::Complex32>;
-	fn mul(self, rhs: &InverseAbsorbedDose<num_complex::Complex32>) -> Self::Output {
-		InverseAbsorbedDose{per_Gy: self.clone() * rhs.per_Gy.clone()}
+/// Multiplying a InverseDoseEquivalent by a Pressure returns a value of type Density
+impl<T> core::ops::Mul<&Pressure<T>> for &InverseDoseEquivalent<T> where T: NumLike {
+	type Output = Density<T>;
+	fn mul(self, rhs: &Pressure<T>) -> Self::Output {
+		Density{kgpm3: self.per_Sv.clone() * rhs.Pa.clone()}
 	}
 }
 
-/// Multiplying a unit value by a scalar value returns a unit value
-#[cfg(feature="num-complex")]
-impl core::ops::Mul<InverseAbsorbedDose<num_complex::Complex64>> for num_complex::Complex64 {
-	type Output = InverseAbsorbedDose<num_complex::Complex64>;
-	fn mul(self, rhs: InverseAbsorbedDose<num_complex::Complex64>) -> Self::Output {
-		InverseAbsorbedDose{per_Gy: self * rhs.per_Gy}
+// InverseDoseEquivalent / TimePerDistance -> TimePerDistance
+/// Dividing a InverseDoseEquivalent by a TimePerDistance returns a value of type TimePerDistance
+impl<T> core::ops::Div<TimePerDistance<T>> for InverseDoseEquivalent<T> where T: NumLike {
+	type Output = TimePerDistance<T>;
+	fn div(self, rhs: TimePerDistance<T>) -> Self::Output {
+		TimePerDistance{spm: self.per_Sv / rhs.spm}
 	}
 }
-/// Multiplying a unit value by a scalar value returns a unit value
-#[cfg(feature="num-complex")]
-impl core::ops::Mul<InverseAbsorbedDose<num_complex::Complex64>> for &num_complex::Complex64 {
-	type Output = InverseAbsorbedDose<num_complex::Complex64>;
-	fn mul(self, rhs: InverseAbsorbedDose<num_complex::Complex64>) -> Self::Output {
-		InverseAbsorbedDose{per_Gy: self.clone() * rhs.per_Gy}
+/// Dividing a InverseDoseEquivalent by a TimePerDistance returns a value of type TimePerDistance
+impl<T> core::ops::Div<TimePerDistance<T>> for &InverseDoseEquivalent<T> where T: NumLike {
+	type Output = TimePerDistance<T>;
+	fn div(self, rhs: TimePerDistance<T>) -> Self::Output {
+		TimePerDistance{spm: self.per_Sv.clone() / rhs.spm}
 	}
 }
-/// Multiplying a unit value by a scalar value returns a unit value
-#[cfg(feature="num-complex")]
-impl core::ops::Mul<&InverseAbsorbedDose<num_complex::Complex64>> for num_complex::Complex64 {
-	type Output = InverseAbsorbedDose<num_complex::Complex64>;
-	fn mul(self, rhs: &InverseAbsorbedDose<num_complex::Complex64>) -> Self::Output {
-		InverseAbsorbedDose{per_Gy: self * rhs.per_Gy.clone()}
+/// Dividing a InverseDoseEquivalent by a TimePerDistance returns a value of type TimePerDistance
+impl<T> core::ops::Div<&TimePerDistance<T>> for InverseDoseEquivalent<T> where T: NumLike {
+	type Output = TimePerDistance<T>;
+	fn div(self, rhs: &TimePerDistance<T>) -> Self::Output {
+		TimePerDistance{spm: self.per_Sv / rhs.spm.clone()}
 	}
 }
-/// Multiplying a unit value by a scalar value returns a unit value
-#[cfg(feature="num-complex")]
-impl core::ops::Mul<&InverseAbsorbedDose<num_complex::Complex64>> for &num_complex::Complex64 {
-	type Output = InverseAbsorbedDose<num_complex::Complex64>;
-	fn mul(self, rhs: &InverseAbsorbedDose<num_complex::Complex64>) -> Self::Output {
-		InverseAbsorbedDose{per_Gy: self.clone() * rhs.per_Gy.clone()}
+/// Dividing a InverseDoseEquivalent by a TimePerDistance returns a value of type TimePerDistance
+impl<T> core::ops::Div<&TimePerDistance<T>> for &InverseDoseEquivalent<T> where T: NumLike {
+	type Output = TimePerDistance<T>;
+	fn div(self, rhs: &TimePerDistance<T>) -> Self::Output {
+		TimePerDistance{spm: self.per_Sv.clone() / rhs.spm.clone()}
 	}
 }
 
+// InverseDoseEquivalent * Velocity -> TimePerDistance
+/// Multiplying a InverseDoseEquivalent by a Velocity returns a value of type TimePerDistance
+impl<T> core::ops::Mul<Velocity<T>> for InverseDoseEquivalent<T> where T: NumLike {
+	type Output = TimePerDistance<T>;
+	fn mul(self, rhs: Velocity<T>) -> Self::Output {
+		TimePerDistance{spm: self.per_Sv * rhs.mps}
+	}
+}
+/// Multiplying a InverseDoseEquivalent by a Velocity returns a value of type TimePerDistance
+impl<T> core::ops::Mul<Velocity<T>> for &InverseDoseEquivalent<T> where T: NumLike {
+	type Output = TimePerDistance<T>;
+	fn mul(self, rhs: Velocity<T>) -> Self::Output {
+		TimePerDistance{spm: self.per_Sv.clone() * rhs.mps}
+	}
+}
+/// Multiplying a InverseDoseEquivalent by a Velocity returns a value of type TimePerDistance
+impl<T> core::ops::Mul<&Velocity<T>> for InverseDoseEquivalent<T> where T: NumLike {
+	type Output = TimePerDistance<T>;
+	fn mul(self, rhs: &Velocity<T>) -> Self::Output {
+		TimePerDistance{spm: self.per_Sv * rhs.mps.clone()}
+	}
+}
+/// Multiplying a InverseDoseEquivalent by a Velocity returns a value of type TimePerDistance
+impl<T> core::ops::Mul<&Velocity<T>> for &InverseDoseEquivalent<T> where T: NumLike {
+	type Output = TimePerDistance<T>;
+	fn mul(self, rhs: &Velocity<T>) -> Self::Output {
+		TimePerDistance{spm: self.per_Sv.clone() * rhs.mps.clone()}
+	}
+}
 
-
-
-// InverseAbsorbedDose * Distance -> InverseAcceleration
-/// Multiplying a InverseAbsorbedDose by a Distance returns a value of type InverseAcceleration
-impl<T> core::ops::Mul<Distance<T>> for InverseAbsorbedDose<T> where T: NumLike {
-	type Output = InverseAcceleration<T>;
-	fn mul(self, rhs: Distance<T>) -> Self::Output {
-		InverseAcceleration{s2pm: self.per_Gy * rhs.m}
+// InverseDoseEquivalent * VolumePerMass -> InversePressure
+/// Multiplying a InverseDoseEquivalent by a VolumePerMass returns a value of type InversePressure
+impl<T> core::ops::Mul<VolumePerMass<T>> for InverseDoseEquivalent<T> where T: NumLike {
+	type Output = InversePressure<T>;
+	fn mul(self, rhs: VolumePerMass<T>) -> Self::Output {
+		InversePressure{per_Pa: self.per_Sv * rhs.m3_per_kg}
 	}
 }
-/// Multiplying a InverseAbsorbedDose by a Distance returns a value of type InverseAcceleration
-impl<T> core::ops::Mul<Distance<T>> for &InverseAbsorbedDose<T> where T: NumLike {
-	type Output = InverseAcceleration<T>;
-	fn mul(self, rhs: Distance<T>) -> Self::Output {
-		InverseAcceleration{s2pm: self.per_Gy.clone() * rhs.m}
+/// Multiplying a InverseDoseEquivalent by a VolumePerMass returns a value of type InversePressure
+impl<T> core::ops::Mul<VolumePerMass<T>> for &InverseDoseEquivalent<T> where T: NumLike {
+	type Output = InversePressure<T>;
+	fn mul(self, rhs: VolumePerMass<T>) -> Self::Output {
+		InversePressure{per_Pa: self.per_Sv.clone() * rhs.m3_per_kg}
 	}
 }
-/// Multiplying a InverseAbsorbedDose by a Distance returns a value of type InverseAcceleration
-impl<T> core::ops::Mul<&Distance<T>> for InverseAbsorbedDose<T> where T: NumLike {
-	type Output = InverseAcceleration<T>;
-	fn mul(self, rhs: &Distance<T>) -> Self::Output {
-		InverseAcceleration{s2pm: self.per_Gy * rhs.m.clone()}
+/// Multiplying a InverseDoseEquivalent by a VolumePerMass returns a value of type InversePressure
+impl<T> core::ops::Mul<&VolumePerMass<T>> for InverseDoseEquivalent<T> where T: NumLike {
+	type Output = InversePressure<T>;
+	fn mul(self, rhs: &VolumePerMass<T>) -> Self::Output {
+		InversePressure{per_Pa: self.per_Sv * rhs.m3_per_kg.clone()}
+	}
+}
+/// Multiplying a InverseDoseEquivalent by a VolumePerMass returns a value of type InversePressure
+impl<T> core::ops::Mul<&VolumePerMass<T>> for &InverseDoseEquivalent<T> where T: NumLike {
+	type Output = InversePressure<T>;
+	fn mul(self, rhs: &VolumePerMass<T>) -> Self::Output {
+		InversePressure{per_Pa: self.per_Sv.clone() * rhs.m3_per_kg.clone()}
 	}
 }
-/// Multiplying a InverseAbsorbedDose by a Distance returns a value of type InverseAcceleration
-impl<T> core::ops::Mul<&Distance<T>> for &InverseAbsorbedDose<T> where T: NumLike {
-	type Output = InverseAcceleration<T>;
-	fn mul(self, rhs: &Distance<T>) -> Self::Output {
-		InverseAcceleration{s2pm: self.per_Gy.clone() * rhs.m.clone()}
+
+impl<T> InverseDoseEquivalent<T> where T: NumLike+From<u8> {
+	/// Returns the reciprocal of this InverseDoseEquivalent value (ie 1 divided by 
+	/// this value) as a value of type DoseEquivalent
+	pub fn recip(self) -> DoseEquivalent<T> {
+		DoseEquivalent{Sv: T::from(1u8) / self.per_Sv}
 	}
 }
 
-// InverseAbsorbedDose / InverseDistance -> InverseAcceleration
-/// Dividing a InverseAbsorbedDose by a InverseDistance returns a value of type InverseAcceleration
-impl<T> core::ops::Div<InverseDistance<T>> for InverseAbsorbedDose<T> where T: NumLike {
-	type Output = InverseAcceleration<T>;
-	fn div(self, rhs: InverseDistance<T>) -> Self::Output {
-		InverseAcceleration{s2pm: self.per_Gy / rhs.per_m}
+// 1/InverseDoseEquivalent -> DoseEquivalent
+/// Dividing a scalar value by a InverseDoseEquivalent unit value returns a value of type DoseEquivalent
+impl<T> core::ops::Div<InverseDoseEquivalent<T>> for f64 where T: NumLike+From<f64> {
+	type Output = DoseEquivalent<T>;
+	fn div(self, rhs: InverseDoseEquivalent<T>) -> Self::Output {
+		DoseEquivalent{Sv: T::from(self) / rhs.per_Sv}
 	}
 }
-/// Dividing a InverseAbsorbedDose by a InverseDistance returns a value of type InverseAcceleration
-impl<T> core::ops::Div<InverseDistance<T>> for &InverseAbsorbedDose<T> where T: NumLike {
-	type Output = InverseAcceleration<T>;
-	fn div(self, rhs: InverseDistance<T>) -> Self::Output {
-		InverseAcceleration{s2pm: self.per_Gy.clone() / rhs.per_m}
+/// Dividing a scalar value by a InverseDoseEquivalent unit value returns a value of type DoseEquivalent
+impl<T> core::ops::Div<InverseDoseEquivalent<T>> for &f64 where T: NumLike+From<f64> {
+	type Output = DoseEquivalent<T>;
+	fn div(self, rhs: InverseDoseEquivalent<T>) -> Self::Output {
+		DoseEquivalent{Sv: T::from(self.clone()) / rhs.per_Sv}
 	}
 }
-/// Dividing a InverseAbsorbedDose by a InverseDistance returns a value of type InverseAcceleration
-impl<T> core::ops::Div<&InverseDistance<T>> for InverseAbsorbedDose<T> where T: NumLike {
-	type Output = InverseAcceleration<T>;
-	fn div(self, rhs: &InverseDistance<T>) -> Self::Output {
-		InverseAcceleration{s2pm: self.per_Gy / rhs.per_m.clone()}
+/// Dividing a scalar value by a InverseDoseEquivalent unit value returns a value of type DoseEquivalent
+impl<T> core::ops::Div<&InverseDoseEquivalent<T>> for f64 where T: NumLike+From<f64> {
+	type Output = DoseEquivalent<T>;
+	fn div(self, rhs: &InverseDoseEquivalent<T>) -> Self::Output {
+		DoseEquivalent{Sv: T::from(self) / rhs.per_Sv.clone()}
 	}
 }
-/// Dividing a InverseAbsorbedDose by a InverseDistance returns a value of type InverseAcceleration
-impl<T> core::ops::Div<&InverseDistance<T>> for &InverseAbsorbedDose<T> where T: NumLike {
-	type Output = InverseAcceleration<T>;
-	fn div(self, rhs: &InverseDistance<T>) -> Self::Output {
-		InverseAcceleration{s2pm: self.per_Gy.clone() / rhs.per_m.clone()}
+/// Dividing a scalar value by a InverseDoseEquivalent unit value returns a value of type DoseEquivalent
+impl<T> core::ops::Div<&InverseDoseEquivalent<T>> for &f64 where T: NumLike+From<f64> {
+	type Output = DoseEquivalent<T>;
+	fn div(self, rhs: &InverseDoseEquivalent<T>) -> Self::Output {
+		DoseEquivalent{Sv: T::from(self.clone()) / rhs.per_Sv.clone()}
 	}
 }
 
-// InverseAbsorbedDose * InverseMass -> InverseEnergy
-/// Multiplying a InverseAbsorbedDose by a InverseMass returns a value of type InverseEnergy
-impl<T> core::ops::Mul<InverseMass<T>> for InverseAbsorbedDose<T> where T: NumLike {
-	type Output = InverseEnergy<T>;
-	fn mul(self, rhs: InverseMass<T>) -> Self::Output {
-		InverseEnergy{per_J: self.per_Gy * rhs.per_kg}
+// 1/InverseDoseEquivalent -> DoseEquivalent
+/// Dividing a scalar value by a InverseDoseEquivalent unit value returns a value of type DoseEquivalent
+impl<T> core::ops::Div<InverseDoseEquivalent<T>> for f32 where T: NumLike+From<f32> {
+	type Output = DoseEquivalent<T>;
+	fn div(self, rhs: InverseDoseEquivalent<T>) -> Self::Output {
+		DoseEquivalent{Sv: T::from(self) / rhs.per_Sv}
 	}
 }
-/// Multiplying a InverseAbsorbedDose by a InverseMass returns a value of type InverseEnergy
-impl<T> core::ops::Mul<InverseMass<T>> for &InverseAbsorbedDose<T> where T: NumLike {
-	type Output = InverseEnergy<T>;
-	fn mul(self, rhs: InverseMass<T>) -> Self::Output {
-		InverseEnergy{per_J: self.per_Gy.clone() * rhs.per_kg}
+/// Dividing a scalar value by a InverseDoseEquivalent unit value returns a value of type DoseEquivalent
+impl<T> core::ops::Div<InverseDoseEquivalent<T>> for &f32 where T: NumLike+From<f32> {
+	type Output = DoseEquivalent<T>;
+	fn div(self, rhs: InverseDoseEquivalent<T>) -> Self::Output {
+		DoseEquivalent{Sv: T::from(self.clone()) / rhs.per_Sv}
 	}
 }
-/// Multiplying a InverseAbsorbedDose by a InverseMass returns a value of type InverseEnergy
-impl<T> core::ops::Mul<&InverseMass<T>> for InverseAbsorbedDose<T> where T: NumLike {
-	type Output = InverseEnergy<T>;
-	fn mul(self, rhs: &InverseMass<T>) -> Self::Output {
-		InverseEnergy{per_J: self.per_Gy * rhs.per_kg.clone()}
+/// Dividing a scalar value by a InverseDoseEquivalent unit value returns a value of type DoseEquivalent
+impl<T> core::ops::Div<&InverseDoseEquivalent<T>> for f32 where T: NumLike+From<f32> {
+	type Output = DoseEquivalent<T>;
+	fn div(self, rhs: &InverseDoseEquivalent<T>) -> Self::Output {
+		DoseEquivalent{Sv: T::from(self) / rhs.per_Sv.clone()}
 	}
 }
-/// Multiplying a InverseAbsorbedDose by a InverseMass returns a value of type InverseEnergy
-impl<T> core::ops::Mul<&InverseMass<T>> for &InverseAbsorbedDose<T> where T: NumLike {
-	type Output = InverseEnergy<T>;
-	fn mul(self, rhs: &InverseMass<T>) -> Self::Output {
-		InverseEnergy{per_J: self.per_Gy.clone() * rhs.per_kg.clone()}
+/// Dividing a scalar value by a InverseDoseEquivalent unit value returns a value of type DoseEquivalent
+impl<T> core::ops::Div<&InverseDoseEquivalent<T>> for &f32 where T: NumLike+From<f32> {
+	type Output = DoseEquivalent<T>;
+	fn div(self, rhs: &InverseDoseEquivalent<T>) -> Self::Output {
+		DoseEquivalent{Sv: T::from(self.clone()) / rhs.per_Sv.clone()}
 	}
 }
 
-// InverseAbsorbedDose / InverseTemperature -> InverseSpecificHeatCapacity
-/// Dividing a InverseAbsorbedDose by a InverseTemperature returns a value of type InverseSpecificHeatCapacity
-impl<T> core::ops::Div<InverseTemperature<T>> for InverseAbsorbedDose<T> where T: NumLike {
-	type Output = InverseSpecificHeatCapacity<T>;
-	fn div(self, rhs: InverseTemperature<T>) -> Self::Output {
-		InverseSpecificHeatCapacity{kgK_per_J: self.per_Gy / rhs.per_K}
+// 1/InverseDoseEquivalent -> DoseEquivalent
+/// Dividing a scalar value by a InverseDoseEquivalent unit value returns a value of type DoseEquivalent
+impl<T> core::ops::Div<InverseDoseEquivalent<T>> for i64 where T: NumLike+From<i64> {
+	type Output = DoseEquivalent<T>;
+	fn div(self, rhs: InverseDoseEquivalent<T>) -> Self::Output {
+		DoseEquivalent{Sv: T::from(self) / rhs.per_Sv}
 	}
 }
-/// Dividing a InverseAbsorbedDose by a InverseTemperature returns a value of type InverseSpecificHeatCapacity
-impl<T> core::ops::Div<InverseTemperature<T>> for &InverseAbsorbedDose<T> where T: NumLike {
-	type Output = InverseSpecificHeatCapacity<T>;
-	fn div(self, rhs: InverseTemperature<T>) -> Self::Output {
-		InverseSpecificHeatCapacity{kgK_per_J: self.per_Gy.clone() / rhs.per_K}
+/// Dividing a scalar value by a InverseDoseEquivalent unit value returns a value of type DoseEquivalent
+impl<T> core::ops::Div<InverseDoseEquivalent<T>> for &i64 where T: NumLike+From<i64> {
+	type Output = DoseEquivalent<T>;
+	fn div(self, rhs: InverseDoseEquivalent<T>) -> Self::Output {
+		DoseEquivalent{Sv: T::from(self.clone()) / rhs.per_Sv}
 	}
 }
-/// Dividing a InverseAbsorbedDose by a InverseTemperature returns a value of type InverseSpecificHeatCapacity
-impl<T> core::ops::Div<&InverseTemperature<T>> for InverseAbsorbedDose<T> where T: NumLike {
-	type Output = InverseSpecificHeatCapacity<T>;
-	fn div(self, rhs: &InverseTemperature<T>) -> Self::Output {
-		InverseSpecificHeatCapacity{kgK_per_J: self.per_Gy / rhs.per_K.clone()}
+/// Dividing a scalar value by a InverseDoseEquivalent unit value returns a value of type DoseEquivalent
+impl<T> core::ops::Div<&InverseDoseEquivalent<T>> for i64 where T: NumLike+From<i64> {
+	type Output = DoseEquivalent<T>;
+	fn div(self, rhs: &InverseDoseEquivalent<T>) -> Self::Output {
+		DoseEquivalent{Sv: T::from(self) / rhs.per_Sv.clone()}
 	}
 }
-/// Dividing a InverseAbsorbedDose by a InverseTemperature returns a value of type InverseSpecificHeatCapacity
-impl<T> core::ops::Div<&InverseTemperature<T>> for &InverseAbsorbedDose<T> where T: NumLike {
-	type Output = InverseSpecificHeatCapacity<T>;
-	fn div(self, rhs: &InverseTemperature<T>) -> Self::Output {
-		InverseSpecificHeatCapacity{kgK_per_J: self.per_Gy.clone() / rhs.per_K.clone()}
+/// Dividing a scalar value by a InverseDoseEquivalent unit value returns a value of type DoseEquivalent
+impl<T> core::ops::Div<&InverseDoseEquivalent<T>> for &i64 where T: NumLike+From<i64> {
+	type Output = DoseEquivalent<T>;
+	fn div(self, rhs: &InverseDoseEquivalent<T>) -> Self::Output {
+		DoseEquivalent{Sv: T::from(self.clone()) / rhs.per_Sv.clone()}
 	}
 }
 
-// InverseAbsorbedDose / Mass -> InverseEnergy
-/// Dividing a InverseAbsorbedDose by a Mass returns a value of type InverseEnergy
-impl<T> core::ops::Div<Mass<T>> for InverseAbsorbedDose<T> where T: NumLike {
-	type Output = InverseEnergy<T>;
-	fn div(self, rhs: Mass<T>) -> Self::Output {
-		InverseEnergy{per_J: self.per_Gy / rhs.kg}
+// 1/InverseDoseEquivalent -> DoseEquivalent
+/// Dividing a scalar value by a InverseDoseEquivalent unit value returns a value of type DoseEquivalent
+impl<T> core::ops::Div<InverseDoseEquivalent<T>> for i32 where T: NumLike+From<i32> {
+	type Output = DoseEquivalent<T>;
+	fn div(self, rhs: InverseDoseEquivalent<T>) -> Self::Output {
+		DoseEquivalent{Sv: T::from(self) / rhs.per_Sv}
 	}
 }
-/// Dividing a InverseAbsorbedDose by a Mass returns a value of type InverseEnergy
-impl<T> core::ops::Div<Mass<T>> for &InverseAbsorbedDose<T> where T: NumLike {
-	type Output = InverseEnergy<T>;
-	fn div(self, rhs: Mass<T>) -> Self::Output {
-		InverseEnergy{per_J: self.per_Gy.clone() / rhs.kg}
+/// Dividing a scalar value by a InverseDoseEquivalent unit value returns a value of type DoseEquivalent
+impl<T> core::ops::Div<InverseDoseEquivalent<T>> for &i32 where T: NumLike+From<i32> {
+	type Output = DoseEquivalent<T>;
+	fn div(self, rhs: InverseDoseEquivalent<T>) -> Self::Output {
+		DoseEquivalent{Sv: T::from(self.clone()) / rhs.per_Sv}
 	}
 }
-/// Dividing a InverseAbsorbedDose by a Mass returns a value of type InverseEnergy
-impl<T> core::ops::Div<&Mass<T>> for InverseAbsorbedDose<T> where T: NumLike {
-	type Output = InverseEnergy<T>;
-	fn div(self, rhs: &Mass<T>) -> Self::Output {
-		InverseEnergy{per_J: self.per_Gy / rhs.kg.clone()}
+/// Dividing a scalar value by a InverseDoseEquivalent unit value returns a value of type DoseEquivalent
+impl<T> core::ops::Div<&InverseDoseEquivalent<T>> for i32 where T: NumLike+From<i32> {
+	type Output = DoseEquivalent<T>;
+	fn div(self, rhs: &InverseDoseEquivalent<T>) -> Self::Output {
+		DoseEquivalent{Sv: T::from(self) / rhs.per_Sv.clone()}
 	}
 }
-/// Dividing a InverseAbsorbedDose by a Mass returns a value of type InverseEnergy
-impl<T> core::ops::Div<&Mass<T>> for &InverseAbsorbedDose<T> where T: NumLike {
-	type Output = InverseEnergy<T>;
-	fn div(self, rhs: &Mass<T>) -> Self::Output {
-		InverseEnergy{per_J: self.per_Gy.clone() / rhs.kg.clone()}
+/// Dividing a scalar value by a InverseDoseEquivalent unit value returns a value of type DoseEquivalent
+impl<T> core::ops::Div<&InverseDoseEquivalent<T>> for &i32 where T: NumLike+From<i32> {
+	type Output = DoseEquivalent<T>;
+	fn div(self, rhs: &InverseDoseEquivalent<T>) -> Self::Output {
+		DoseEquivalent{Sv: T::from(self.clone()) / rhs.per_Sv.clone()}
 	}
 }
 
-// InverseAbsorbedDose * Temperature -> InverseSpecificHeatCapacity
-/// Multiplying a InverseAbsorbedDose by a Temperature returns a value of type InverseSpecificHeatCapacity
-impl<T> core::ops::Mul<Temperature<T>> for InverseAbsorbedDose<T> where T: NumLike {
-	type Output = InverseSpecificHeatCapacity<T>;
-	fn mul(self, rhs: Temperature<T>) -> Self::Output {
-		InverseSpecificHeatCapacity{kgK_per_J: self.per_Gy * rhs.K}
+// 1/InverseDoseEquivalent -> DoseEquivalent
+/// Dividing a scalar value by a InverseDoseEquivalent unit value returns a value of type DoseEquivalent
+#[cfg(feature="num-bigfloat")]
+impl<T> core::ops::Div<InverseDoseEquivalent<T>> for num_bigfloat::BigFloat where T: NumLike+From<num_bigfloat::BigFloat> {
+	type Output = DoseEquivalent<T>;
+	fn div(self, rhs: InverseDoseEquivalent<T>) -> Self::Output {
+		DoseEquivalent{Sv: T::from(self) / rhs.per_Sv}
 	}
 }
-/// Multiplying a InverseAbsorbedDose by a Temperature returns a value of type InverseSpecificHeatCapacity
-impl<T> core::ops::Mul<Temperature<T>> for &InverseAbsorbedDose<T> where T: NumLike {
-	type Output = InverseSpecificHeatCapacity<T>;
-	fn mul(self, rhs: Temperature<T>) -> Self::Output {
-		InverseSpecificHeatCapacity{kgK_per_J: self.per_Gy.clone() * rhs.K}
+/// Dividing a scalar value by a InverseDoseEquivalent unit value returns a value of type DoseEquivalent
+#[cfg(feature="num-bigfloat")]
+impl<T> core::ops::Div<InverseDoseEquivalent<T>> for &num_bigfloat::BigFloat where T: NumLike+From<num_bigfloat::BigFloat> {
+	type Output = DoseEquivalent<T>;
+	fn div(self, rhs: InverseDoseEquivalent<T>) -> Self::Output {
+		DoseEquivalent{Sv: T::from(self.clone()) / rhs.per_Sv}
 	}
 }
-/// Multiplying a InverseAbsorbedDose by a Temperature returns a value of type InverseSpecificHeatCapacity
-impl<T> core::ops::Mul<&Temperature<T>> for InverseAbsorbedDose<T> where T: NumLike {
-	type Output = InverseSpecificHeatCapacity<T>;
-	fn mul(self, rhs: &Temperature<T>) -> Self::Output {
-		InverseSpecificHeatCapacity{kgK_per_J: self.per_Gy * rhs.K.clone()}
+/// Dividing a scalar value by a InverseDoseEquivalent unit value returns a value of type DoseEquivalent
+#[cfg(feature="num-bigfloat")]
+impl<T> core::ops::Div<&InverseDoseEquivalent<T>> for num_bigfloat::BigFloat where T: NumLike+From<num_bigfloat::BigFloat> {
+	type Output = DoseEquivalent<T>;
+	fn div(self, rhs: &InverseDoseEquivalent<T>) -> Self::Output {
+		DoseEquivalent{Sv: T::from(self) / rhs.per_Sv.clone()}
 	}
 }
-/// Multiplying a InverseAbsorbedDose by a Temperature returns a value of type InverseSpecificHeatCapacity
-impl<T> core::ops::Mul<&Temperature<T>> for &InverseAbsorbedDose<T> where T: NumLike {
-	type Output = InverseSpecificHeatCapacity<T>;
-	fn mul(self, rhs: &Temperature<T>) -> Self::Output {
-		InverseSpecificHeatCapacity{kgK_per_J: self.per_Gy.clone() * rhs.K.clone()}
+/// Dividing a scalar value by a InverseDoseEquivalent unit value returns a value of type DoseEquivalent
+#[cfg(feature="num-bigfloat")]
+impl<T> core::ops::Div<&InverseDoseEquivalent<T>> for &num_bigfloat::BigFloat where T: NumLike+From<num_bigfloat::BigFloat> {
+	type Output = DoseEquivalent<T>;
+	fn div(self, rhs: &InverseDoseEquivalent<T>) -> Self::Output {
+		DoseEquivalent{Sv: T::from(self.clone()) / rhs.per_Sv.clone()}
 	}
 }
 
-// InverseAbsorbedDose / InverseSpecificHeatCapacity -> InverseTemperature
-/// Dividing a InverseAbsorbedDose by a InverseSpecificHeatCapacity returns a value of type InverseTemperature
-impl<T> core::ops::Div<InverseSpecificHeatCapacity<T>> for InverseAbsorbedDose<T> where T: NumLike {
-	type Output = InverseTemperature<T>;
-	fn div(self, rhs: InverseSpecificHeatCapacity<T>) -> Self::Output {
-		InverseTemperature{per_K: self.per_Gy / rhs.kgK_per_J}
+// 1/InverseDoseEquivalent -> DoseEquivalent
+/// Dividing a scalar value by a InverseDoseEquivalent unit value returns a value of type DoseEquivalent
+#[cfg(feature="num-complex")]
+impl<T> core::ops::Div<InverseDoseEquivalent<T>> for num_complex::Complex32 where T: NumLike+From<num_complex::Complex32> {
+	type Output = DoseEquivalent<T>;
+	fn div(self, rhs: InverseDoseEquivalent<T>) -> Self::Output {
+		DoseEquivalent{Sv: T::from(self) / rhs.per_Sv}
 	}
 }
-/// Dividing a InverseAbsorbedDose by a InverseSpecificHeatCapacity returns a value of type InverseTemperature
-impl<T> core::ops::Div<InverseSpecificHeatCapacity<T>> for &InverseAbsorbedDose<T> where T: NumLike {
-	type Output = InverseTemperature<T>;
-	fn div(self, rhs: InverseSpecificHeatCapacity<T>) -> Self::Output {
-		InverseTemperature{per_K: self.per_Gy.clone() / rhs.kgK_per_J}
+/// Dividing a scalar value by a InverseDoseEquivalent unit value returns a value of type DoseEquivalent
+#[cfg(feature="num-complex")]
+impl<T> core::ops::Div<InverseDoseEquivalent<T>> for &num_complex::Complex32 where T: NumLike+From<num_complex::Complex32> {
+	type Output = DoseEquivalent<T>;
+	fn div(self, rhs: InverseDoseEquivalent<T>) -> Self::Output {
+		DoseEquivalent{Sv: T::from(self.clone()) / rhs.per_Sv}
 	}
 }
-/// Dividing a InverseAbsorbedDose by a InverseSpecificHeatCapacity returns a value of type InverseTemperature
-impl<T> core::ops::Div<&InverseSpecificHeatCapacity<T>> for InverseAbsorbedDose<T> where T: NumLike {
-	type Output = InverseTemperature<T>;
-	fn div(self, rhs: &InverseSpecificHeatCapacity<T>) -> Self::Output {
-		InverseTemperature{per_K: self.per_Gy / rhs.kgK_per_J.clone()}
+/// Dividing a scalar value by a InverseDoseEquivalent unit value returns a value of type DoseEquivalent
+#[cfg(feature="num-complex")]
+impl<T> core::ops::Div<&InverseDoseEquivalent<T>> for num_complex::Complex32 where T: NumLike+From<num_complex::Complex32> {
+	type Output = DoseEquivalent<T>;
+	fn div(self, rhs: &InverseDoseEquivalent<T>) -> Self::Output {
+		DoseEquivalent{Sv: T::from(self) / rhs.per_Sv.clone()}
 	}
 }
-/// Dividing a InverseAbsorbedDose by a InverseSpecificHeatCapacity returns a value of type InverseTemperature
-impl<T> core::ops::Div<&InverseSpecificHeatCapacity<T>> for &InverseAbsorbedDose<T> where T: NumLike {
-	type Output = InverseTemperature<T>;
-	fn div(self, rhs: &InverseSpecificHeatCapacity<T>) -> Self::Output {
-		InverseTemperature{per_K: self.per_Gy.clone() / rhs.kgK_per_J.clone()}
+/// Dividing a scalar value by a InverseDoseEquivalent unit value returns a value of type DoseEquivalent
+#[cfg(feature="num-complex")]
+impl<T> core::ops::Div<&InverseDoseEquivalent<T>> for &num_complex::Complex32 where T: NumLike+From<num_complex::Complex32> {
+	type Output = DoseEquivalent<T>;
+	fn div(self, rhs: &InverseDoseEquivalent<T>) -> Self::Output {
+		DoseEquivalent{Sv: T::from(self.clone()) / rhs.per_Sv.clone()}
 	}
 }
 
-// InverseAbsorbedDose * SpecificHeatCapacity -> InverseTemperature
-/// Multiplying a InverseAbsorbedDose by a SpecificHeatCapacity returns a value of type InverseTemperature
-impl<T> core::ops::Mul<SpecificHeatCapacity<T>> for InverseAbsorbedDose<T> where T: NumLike {
-	type Output = InverseTemperature<T>;
-	fn mul(self, rhs: SpecificHeatCapacity<T>) -> Self::Output {
-		InverseTemperature{per_K: self.per_Gy * rhs.J_per_kgK}
+// 1/InverseDoseEquivalent -> DoseEquivalent
+/// Dividing a scalar value by a InverseDoseEquivalent unit value returns a value of type DoseEquivalent
+#[cfg(feature="num-complex")]
+impl<T> core::ops::Div<InverseDoseEquivalent<T>> for num_complex::Complex64 where T: NumLike+From<num_complex::Complex64> {
+	type Output = DoseEquivalent<T>;
+	fn div(self, rhs: InverseDoseEquivalent<T>) -> Self::Output {
+		DoseEquivalent{Sv: T::from(self) / rhs.per_Sv}
 	}
 }
-/// Multiplying a InverseAbsorbedDose by a SpecificHeatCapacity returns a value of type InverseTemperature
-impl<T> core::ops::Mul<SpecificHeatCapacity<T>> for &InverseAbsorbedDose<T> where T: NumLike {
-	type Output = InverseTemperature<T>;
-	fn mul(self, rhs: SpecificHeatCapacity<T>) -> Self::Output {
-		InverseTemperature{per_K: self.per_Gy.clone() * rhs.J_per_kgK}
+/// Dividing a scalar value by a InverseDoseEquivalent unit value returns a value of type DoseEquivalent
+#[cfg(feature="num-complex")]
+impl<T> core::ops::Div<InverseDoseEquivalent<T>> for &num_complex::Complex64 where T: NumLike+From<num_complex::Complex64> {
+	type Output = DoseEquivalent<T>;
+	fn div(self, rhs: InverseDoseEquivalent<T>) -> Self::Output {
+		DoseEquivalent{Sv: T::from(self.clone()) / rhs.per_Sv}
 	}
 }
-/// Multiplying a InverseAbsorbedDose by a SpecificHeatCapacity returns a value of type InverseTemperature
-impl<T> core::ops::Mul<&SpecificHeatCapacity<T>> for InverseAbsorbedDose<T> where T: NumLike {
-	type Output = InverseTemperature<T>;
-	fn mul(self, rhs: &SpecificHeatCapacity<T>) -> Self::Output {
-		InverseTemperature{per_K: self.per_Gy * rhs.J_per_kgK.clone()}
+/// Dividing a scalar value by a InverseDoseEquivalent unit value returns a value of type DoseEquivalent
+#[cfg(feature="num-complex")]
+impl<T> core::ops::Div<&InverseDoseEquivalent<T>> for num_complex::Complex64 where T: NumLike+From<num_complex::Complex64> {
+	type Output = DoseEquivalent<T>;
+	fn div(self, rhs: &InverseDoseEquivalent<T>) -> Self::Output {
+		DoseEquivalent{Sv: T::from(self) / rhs.per_Sv.clone()}
 	}
 }
-/// Multiplying a InverseAbsorbedDose by a SpecificHeatCapacity returns a value of type InverseTemperature
-impl<T> core::ops::Mul<&SpecificHeatCapacity<T>> for &InverseAbsorbedDose<T> where T: NumLike {
-	type Output = InverseTemperature<T>;
-	fn mul(self, rhs: &SpecificHeatCapacity<T>) -> Self::Output {
-		InverseTemperature{per_K: self.per_Gy.clone() * rhs.J_per_kgK.clone()}
+/// Dividing a scalar value by a InverseDoseEquivalent unit value returns a value of type DoseEquivalent
+#[cfg(feature="num-complex")]
+impl<T> core::ops::Div<&InverseDoseEquivalent<T>> for &num_complex::Complex64 where T: NumLike+From<num_complex::Complex64> {
+	type Output = DoseEquivalent<T>;
+	fn div(self, rhs: &InverseDoseEquivalent<T>) -> Self::Output {
+		DoseEquivalent{Sv: T::from(self.clone()) / rhs.per_Sv.clone()}
 	}
 }
 
-// InverseAbsorbedDose * Acceleration -> InverseDistance
-/// Multiplying a InverseAbsorbedDose by a Acceleration returns a value of type InverseDistance
-impl<T> core::ops::Mul<Acceleration<T>> for InverseAbsorbedDose<T> where T: NumLike {
-	type Output = InverseDistance<T>;
-	fn mul(self, rhs: Acceleration<T>) -> Self::Output {
-		InverseDistance{per_m: self.per_Gy * rhs.mps2}
+// 1/InverseDoseEquivalent -> DoseEquivalent
+/// Dividing a scalar value by a InverseDoseEquivalent unit value returns a value of type DoseEquivalent
+#[cfg(feature="num-rational")]
+impl<T> core::ops::Div<InverseDoseEquivalent<T>> for num_rational::Rational64 where T: NumLike+From<num_rational::Rational64> {
+	type Output = DoseEquivalent<T>;
+	fn div(self, rhs: InverseDoseEquivalent<T>) -> Self::Output {
+		DoseEquivalent{Sv: T::from(self) / rhs.per_Sv}
 	}
 }
-/// Multiplying a InverseAbsorbedDose by a Acceleration returns a value of type InverseDistance
-impl<T> core::ops::Mul<Acceleration<T>> for &InverseAbsorbedDose<T> where T: NumLike {
-	type Output = InverseDistance<T>;
-	fn mul(self, rhs: Acceleration<T>) -> Self::Output {
-		InverseDistance{per_m: self.per_Gy.clone() * rhs.mps2}
+/// Dividing a scalar value by a InverseDoseEquivalent unit value returns a value of type DoseEquivalent
+#[cfg(feature="num-rational")]
+impl<T> core::ops::Div<InverseDoseEquivalent<T>> for &num_rational::Rational64 where T: NumLike+From<num_rational::Rational64> {
+	type Output = DoseEquivalent<T>;
+	fn div(self, rhs: InverseDoseEquivalent<T>) -> Self::Output {
+		DoseEquivalent{Sv: T::from(self.clone()) / rhs.per_Sv}
 	}
 }
-/// Multiplying a InverseAbsorbedDose by a Acceleration returns a value of type InverseDistance
-impl<T> core::ops::Mul<&Acceleration<T>> for InverseAbsorbedDose<T> where T: NumLike {
-	type Output = InverseDistance<T>;
-	fn mul(self, rhs: &Acceleration<T>) -> Self::Output {
-		InverseDistance{per_m: self.per_Gy * rhs.mps2.clone()}
+/// Dividing a scalar value by a InverseDoseEquivalent unit value returns a value of type DoseEquivalent
+#[cfg(feature="num-rational")]
+impl<T> core::ops::Div<&InverseDoseEquivalent<T>> for num_rational::Rational64 where T: NumLike+From<num_rational::Rational64> {
+	type Output = DoseEquivalent<T>;
+	fn div(self, rhs: &InverseDoseEquivalent<T>) -> Self::Output {
+		DoseEquivalent{Sv: T::from(self) / rhs.per_Sv.clone()}
 	}
 }
-/// Multiplying a InverseAbsorbedDose by a Acceleration returns a value of type InverseDistance
-impl<T> core::ops::Mul<&Acceleration<T>> for &InverseAbsorbedDose<T> where T: NumLike {
-	type Output = InverseDistance<T>;
-	fn mul(self, rhs: &Acceleration<T>) -> Self::Output {
-		InverseDistance{per_m: self.per_Gy.clone() * rhs.mps2.clone()}
+/// Dividing a scalar value by a InverseDoseEquivalent unit value returns a value of type DoseEquivalent
+#[cfg(feature="num-rational")]
+impl<T> core::ops::Div<&InverseDoseEquivalent<T>> for &num_rational::Rational64 where T: NumLike+From<num_rational::Rational64> {
+	type Output = DoseEquivalent<T>;
+	fn div(self, rhs: &InverseDoseEquivalent<T>) -> Self::Output {
+		DoseEquivalent{Sv: T::from(self.clone()) / rhs.per_Sv.clone()}
 	}
 }
 
-// InverseAbsorbedDose / Density -> InversePressure
-/// Dividing a InverseAbsorbedDose by a Density returns a value of type InversePressure
-impl<T> core::ops::Div<Density<T>> for InverseAbsorbedDose<T> where T: NumLike {
-	type Output = InversePressure<T>;
-	fn div(self, rhs: Density<T>) -> Self::Output {
-		InversePressure{per_Pa: self.per_Gy / rhs.kgpm3}
+// 1/InverseDoseEquivalent -> DoseEquivalent
+/// Dividing a scalar value by a InverseDoseEquivalent unit value returns a value of type DoseEquivalent
+#[cfg(feature="num-rational")]
+impl<T> core::ops::Div<InverseDoseEquivalent<T>> for num_rational::BigRational where T: NumLike+From<num_rational::BigRational> {
+	type Output = DoseEquivalent<T>;
+	fn div(self, rhs: InverseDoseEquivalent<T>) -> Self::Output {
+		DoseEquivalent{Sv: T::from(self) / rhs.per_Sv}
 	}
 }
-/// Dividing a InverseAbsorbedDose by a Density returns a value of type InversePressure
-impl<T> core::ops::Div<Density<T>> for &InverseAbsorbedDose<T> where T: NumLike {
-	type Output = InversePressure<T>;
-	fn div(self, rhs: Density<T>) -> Self::Output {
-		InversePressure{per_Pa: self.per_Gy.clone() / rhs.kgpm3}
+/// Dividing a scalar value by a InverseDoseEquivalent unit value returns a value of type DoseEquivalent
+#[cfg(feature="num-rational")]
+impl<T> core::ops::Div<InverseDoseEquivalent<T>> for &num_rational::BigRational where T: NumLike+From<num_rational::BigRational> {
+	type Output = DoseEquivalent<T>;
+	fn div(self, rhs: InverseDoseEquivalent<T>) -> Self::Output {
+		DoseEquivalent{Sv: T::from(self.clone()) / rhs.per_Sv}
 	}
 }
-/// Dividing a InverseAbsorbedDose by a Density returns a value of type InversePressure
-impl<T> core::ops::Div<&Density<T>> for InverseAbsorbedDose<T> where T: NumLike {
-	type Output = InversePressure<T>;
-	fn div(self, rhs: &Density<T>) -> Self::Output {
-		InversePressure{per_Pa: self.per_Gy / rhs.kgpm3.clone()}
+/// Dividing a scalar value by a InverseDoseEquivalent unit value returns a value of type DoseEquivalent
+#[cfg(feature="num-rational")]
+impl<T> core::ops::Div<&InverseDoseEquivalent<T>> for num_rational::BigRational where T: NumLike+From<num_rational::BigRational> {
+	type Output = DoseEquivalent<T>;
+	fn div(self, rhs: &InverseDoseEquivalent<T>) -> Self::Output {
+		DoseEquivalent{Sv: T::from(self) / rhs.per_Sv.clone()}
 	}
 }
-/// Dividing a InverseAbsorbedDose by a Density returns a value of type InversePressure
-impl<T> core::ops::Div<&Density<T>> for &InverseAbsorbedDose<T> where T: NumLike {
-	type Output = InversePressure<T>;
-	fn div(self, rhs: &Density<T>) -> Self::Output {
-		InversePressure{per_Pa: self.per_Gy.clone() / rhs.kgpm3.clone()}
+/// Dividing a scalar value by a InverseDoseEquivalent unit value returns a value of type DoseEquivalent
+#[cfg(feature="num-rational")]
+impl<T> core::ops::Div<&InverseDoseEquivalent<T>> for &num_rational::BigRational where T: NumLike+From<num_rational::BigRational> {
+	type Output = DoseEquivalent<T>;
+	fn div(self, rhs: &InverseDoseEquivalent<T>) -> Self::Output {
+		DoseEquivalent{Sv: T::from(self.clone()) / rhs.per_Sv.clone()}
 	}
 }
 
-// InverseAbsorbedDose * Energy -> Mass
-/// Multiplying a InverseAbsorbedDose by a Energy returns a value of type Mass
-impl<T> core::ops::Mul<Energy<T>> for InverseAbsorbedDose<T> where T: NumLike {
-	type Output = Mass<T>;
-	fn mul(self, rhs: Energy<T>) -> Self::Output {
-		Mass{kg: self.per_Gy * rhs.J}
-	}
+/// The inverse of radiation dose equivalent rate unit type, defined as seconds per sievert in SI units
+#[derive(UnitStruct, Debug, Clone, Default)]
+#[si(no_display)]
+#[cfg_attr(all(feature="serde", not(feature="serde-unit-tag")), derive(Serialize, Deserialize))]
+#[repr(transparent)]
+pub struct InverseDoseEquivalentRate<T: NumLike>{
+	/// The value of this Inverse dose equivalent rate in seconds per sievert
+	pub s_per_Sv: T
 }
-/// Multiplying a InverseAbsorbedDose by a Energy returns a value of type Mass
-impl<T> core::ops::Mul<Energy<T>> for &InverseAbsorbedDose<T> where T: NumLike {
-	type Output = Mass<T>;
-	fn mul(self, rhs: Energy<T>) -> Self::Output {
-		Mass{kg: self.per_Gy.clone() * rhs.J}
+
+impl<T> InverseDoseEquivalentRate<T> where T: NumLike {
+
+	/// Returns the standard unit name of inverse dose equivalent rate: "seconds per sievert"
+	pub fn unit_name() -> &'static str { "seconds per sievert" }
+	
+	/// Returns the abbreviated name or symbol of inverse dose equivalent rate: "s/Sv" for seconds per sievert
+	pub fn unit_symbol() -> &'static str { "s/Sv" }
+
+	/// Returns the exponents of the SI base units of inverse dose equivalent rate (s^3/m^2)
+	pub const fn dimension() -> super::Dimension { super::Dimension{length: -2, mass: 0, time: 3, current: 0, temperature: 0, amount: 0, luminosity: 0} }
+
+	/// Returns a wrapper that formats this inverse dose equivalent rate value with the full unit name instead of 
+	/// the unit symbol, eg "5 seconds per sievert" (the unit name is not singularized for values of 1)
+	pub fn display_named(&self) -> super::NamedDisplay<'_, T> {
+		super::NamedDisplay::new(&self.s_per_Sv, Self::unit_name())
+	}
+
+	/// Returns a new inverse dose equivalent rate value from the given number of seconds per sievert (same as 
+	/// `from_s_per_Sv(...)`), for any number-like type. This is useful in generic code 
+	/// that works with several unit types
+	///
+	/// # Arguments
+	/// * `value` - Any number-like type, representing a quantity of seconds per sievert
+	pub const fn new(value: T) -> Self { InverseDoseEquivalentRate{s_per_Sv: value} }
+
+	/// Returns a new inverse dose equivalent rate value from the given number of seconds per sievert. Unlike
+	/// `from_s_per_Sv(...)`, this is a `const fn`, so it can be used to declare `const` and `static` values
+	///
+	/// # Arguments
+	/// * `s_per_Sv` - Any number-like type, representing a quantity of seconds per sievert
+	pub const fn from_s_per_Sv_const(s_per_Sv: T) -> Self { InverseDoseEquivalentRate{s_per_Sv} }
+
+	/// Returns a inverse dose equivalent rate value with a different number type, by applying the 
+	/// given function to the value in seconds per sievert (eg `.map(|x| x as f32)` to convert 
+	/// from `f64` to `f32`)
+	///
+	/// # Arguments
+	/// * `f` - Function that converts the number of seconds per sievert to the new number type
+	pub fn map<U, F>(self, f: F) -> InverseDoseEquivalentRate<U> where U: NumLike, F: FnOnce(T) -> U {
+		InverseDoseEquivalentRate{s_per_Sv: f(self.s_per_Sv)}
+	}
+
+	/// Returns a inverse dose equivalent rate value with a different number type, converted 
+	/// losslessly with the `From` trait (eg from `f32` to `f64`)
+	pub fn cast<U>(self) -> InverseDoseEquivalentRate<U> where U: NumLike+From<T> {
+		InverseDoseEquivalentRate{s_per_Sv: U::from(self.s_per_Sv)}
+	}
+
+	/// Returns a reference to the value of this inverse dose equivalent rate in seconds per sievert
+	pub fn as_raw(&self) -> &T {
+		&self.s_per_Sv
+	}
+
+	/// Returns a mutable reference to the value of this inverse dose equivalent rate in seconds per sievert, 
+	/// eg for passing to numeric libraries that modify values in place. The number 
+	/// is always in seconds per sievert, so changing it directly does not change the unit
+	pub fn raw_mut(&mut self) -> &mut T {
+		&mut self.s_per_Sv
+	}
+
+	/// Returns a slice of numbers in seconds per sievert as a slice of inverse dose equivalent rate values, 
+	/// without copying
+	///
+	/// # Arguments
+	/// * `values` - Slice of numbers, representing quantities of seconds per sievert
+	pub fn wrap_slice(values: &[T]) -> &[Self] {
+		// SAFETY: InverseDoseEquivalentRate<T> is #[repr(transparent)], so it has the same memory layout as T
+		unsafe { core::slice::from_raw_parts(values.as_ptr() as *const Self, values.len()) }
+	}
+
+	/// Returns a mutable slice of numbers in seconds per sievert as a mutable slice of 
+	/// inverse dose equivalent rate values, without copying
+	///
+	/// # Arguments
+	/// * `values` - Slice of numbers, representing quantities of seconds per sievert
+	pub fn wrap_slice_mut(values: &mut [T]) -> &mut [Self] {
+		// SAFETY: InverseDoseEquivalentRate<T> is #[repr(transparent)], so it has the same memory layout as T
+		unsafe { core::slice::from_raw_parts_mut(values.as_mut_ptr() as *mut Self, values.len()) }
+	}
+
+	/// Returns the ratio of this inverse dose equivalent rate value to another inverse dose equivalent rate value 
+	/// (same as `self / other`). Dividing a unit value by another value of the same 
+	/// unit type cancels the units, so the ratio is a dimensionless number
+	///
+	/// # Arguments
+	/// * `other` - The inverse dose equivalent rate value to divide by
+	pub fn ratio(self, other: Self) -> T {
+		self.s_per_Sv / other.s_per_Sv
+	}
+
+	/// Returns the ratio of this inverse dose equivalent rate value to another inverse dose equivalent rate value 
+	/// as a `Dimensionless` value, instead of a bare number like `ratio(...)` does
+	///
+	/// # Arguments
+	/// * `other` - The inverse dose equivalent rate value to divide by
+	pub fn dimensionless_ratio(self, other: Self) -> super::base::Dimensionless<T> {
+		super::base::Dimensionless{value: self.s_per_Sv / other.s_per_Sv}
 	}
-}
-/// Multiplying a InverseAbsorbedDose by a Energy returns a value of type Mass
-impl<T> core::ops::Mul<&Energy<T>> for InverseAbsorbedDose<T> where T: NumLike {
-	type Output = Mass<T>;
-	fn mul(self, rhs: &Energy<T>) -> Self::Output {
-		Mass{kg: self.per_Gy * rhs.J.clone()}
+
+	/// Returns this inverse dose equivalent rate value multiplied by a dimensionless factor 
+	/// (same as `self * factor`)
+	///
+	/// # Arguments
+	/// * `factor` - The number to multiply by
+	pub fn scale(self, factor: T) -> Self {
+		InverseDoseEquivalentRate{s_per_Sv: self.s_per_Sv * factor}
 	}
+	
+	/// Returns a new inverse dose equivalent rate value from the given number of seconds per sievert
+	///
+	/// # Arguments
+	/// * `s_per_Sv` - Any number-like type, representing a quantity of seconds per sievert
+	pub fn from_s_per_Sv(s_per_Sv: T) -> Self { InverseDoseEquivalentRate{s_per_Sv: s_per_Sv} }
+	
+	/// Returns a copy of this inverse dose equivalent rate value in seconds per sievert
+	pub fn to_s_per_Sv(&self) -> T { self.s_per_Sv.clone() }
+
+	/// Returns a new inverse dose equivalent rate value from the given number of seconds per sievert
+	///
+	/// # Arguments
+	/// * `seconds_per_sievert` - Any number-like type, representing a quantity of seconds per sievert
+	pub fn from_seconds_per_sievert(seconds_per_sievert: T) -> Self { InverseDoseEquivalentRate{s_per_Sv: seconds_per_sievert} }
+	
+	/// Returns a copy of this inverse dose equivalent rate value in seconds per sievert
+	pub fn to_seconds_per_sievert(&self) -> T { self.s_per_Sv.clone() }
+
 }
-/// Multiplying a InverseAbsorbedDose by a Energy returns a value of type Mass
-impl<T> core::ops::Mul<&Energy<T>> for &InverseAbsorbedDose<T> where T: NumLike {
-	type Output = Mass<T>;
-	fn mul(self, rhs: &Energy<T>) -> Self::Output {
-		Mass{kg: self.per_Gy.clone() * rhs.J.clone()}
+
+/// Formats inverse dose equivalent rate values as a number followed by its unit symbol, eg "1500 s/Sv"
+/// (complex numbers are wrapped in parentheses, eg "(1+2i) s/Sv").
+/// The alternate flag (`{:#}`) scales the number by the nearest SI prefix where the unit symbol allows it
+impl<T> fmt::Display for InverseDoseEquivalentRate<T> where T: NumLike {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		if f.alternate() {
+			return super::fmt_with_si_prefix(f, &self.s_per_Sv, Self::unit_symbol(), false);
+		}
+		super::fmt_unit_value(f, &self.s_per_Sv, Self::unit_symbol())
 	}
 }
 
-// InverseAbsorbedDose * Torque -> Mass
-/// Multiplying a InverseAbsorbedDose by a Torque returns a value of type Mass
-impl<T> core::ops::Mul<Torque<T>> for InverseAbsorbedDose<T> where T: NumLike {
-	type Output = Mass<T>;
-	fn mul(self, rhs: Torque<T>) -> Self::Output {
-		Mass{kg: self.per_Gy * rhs.Nm}
+/// Formats inverse dose equivalent rate values in exponential notation followed by the unit symbol, eg "1.5e3 s/Sv"
+impl<T> fmt::LowerExp for InverseDoseEquivalentRate<T> where T: NumLike+fmt::LowerExp {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		fmt::LowerExp::fmt(&self.s_per_Sv, f)?;
+		write!(f, " {}", Self::unit_symbol())
 	}
 }
-/// Multiplying a InverseAbsorbedDose by a Torque returns a value of type Mass
-impl<T> core::ops::Mul<Torque<T>> for &InverseAbsorbedDose<T> where T: NumLike {
-	type Output = Mass<T>;
-	fn mul(self, rhs: Torque<T>) -> Self::Output {
-		Mass{kg: self.per_Gy.clone() * rhs.Nm}
+
+/// Formats inverse dose equivalent rate values in exponential notation followed by the unit symbol, eg "1.5E3 s/Sv"
+impl<T> fmt::UpperExp for InverseDoseEquivalentRate<T> where T: NumLike+fmt::UpperExp {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		fmt::UpperExp::fmt(&self.s_per_Sv, f)?;
+		write!(f, " {}", Self::unit_symbol())
 	}
 }
-/// Multiplying a InverseAbsorbedDose by a Torque returns a value of type Mass
-impl<T> core::ops::Mul<&Torque<T>> for InverseAbsorbedDose<T> where T: NumLike {
-	type Output = Mass<T>;
-	fn mul(self, rhs: &Torque<T>) -> Self::Output {
-		Mass{kg: self.per_Gy * rhs.Nm.clone()}
-	}
+
+impl<T> super::SiUnit for InverseDoseEquivalentRate<T> where T: NumLike {
+	type Number = T;
+	fn unit_name(&self) -> &'static str { Self::unit_name() }
+	fn unit_symbol(&self) -> &'static str { Self::unit_symbol() }
+	fn raw(&self) -> &T { &self.s_per_Sv }
+	fn dimension(&self) -> super::Dimension { Self::dimension() }
+	fn from_raw(value: T) -> Self { InverseDoseEquivalentRate{s_per_Sv: value} }
+	fn into_raw(self) -> T { self.s_per_Sv }
 }
-/// Multiplying a InverseAbsorbedDose by a Torque returns a value of type Mass
-impl<T> core::ops::Mul<&Torque<T>> for &InverseAbsorbedDose<T> where T: NumLike {
-	type Output = Mass<T>;
-	fn mul(self, rhs: &Torque<T>) -> Self::Output {
-		Mass{kg: self.per_Gy.clone() * rhs.Nm.clone()}
+
+#[cfg(feature="alloc")]
+impl<T> InverseDoseEquivalentRate<T> where T: NumLike {
+	/// Returns this inverse dose equivalent rate value in the canonical text format "<value> <symbol>", eg "5 s/Sv".
+	/// Unlike `Display`, this format never changes with precision or alternate flags, and it is the
+	/// input format expected by `from_str(...)`, so `x.canonical_string().parse()` returns the same value
+	pub fn canonical_string(&self) -> alloc::string::String {
+		alloc::format!("{} {}", self.s_per_Sv, Self::unit_symbol())
 	}
-}
 
-// InverseAbsorbedDose / InverseAcceleration -> InverseDistance
-/// Dividing a InverseAbsorbedDose by a InverseAcceleration returns a value of type InverseDistance
-impl<T> core::ops::Div<InverseAcceleration<T>> for InverseAbsorbedDose<T> where T: NumLike {
-	type Output = InverseDistance<T>;
-	fn div(self, rhs: InverseAcceleration<T>) -> Self::Output {
-		InverseDistance{per_m: self.per_Gy / rhs.s2pm}
+	/// Returns a `Vec` of inverse dose equivalent rate values copied from the given slice of numbers
+	///
+	/// # Arguments
+	/// * `values` - Slice of numbers, representing quantities of seconds per sievert
+	pub fn from_s_per_Sv_slice(values: &[T]) -> alloc::vec::Vec<Self> {
+		values.iter().map(|x| InverseDoseEquivalentRate{s_per_Sv: x.clone()}).collect()
 	}
 }
-/// Dividing a InverseAbsorbedDose by a InverseAcceleration returns a value of type InverseDistance
-impl<T> core::ops::Div<InverseAcceleration<T>> for &InverseAbsorbedDose<T> where T: NumLike {
-	type Output = InverseDistance<T>;
-	fn div(self, rhs: InverseAcceleration<T>) -> Self::Output {
-		InverseDistance{per_m: self.per_Gy.clone() / rhs.s2pm}
+
+/// Parses a inverse dose equivalent rate value from a number followed by its unit symbol, eg "5 s/Sv"
+impl<T> core::str::FromStr for InverseDoseEquivalentRate<T> where T: NumLike+core::str::FromStr {
+	type Err = SiParseError;
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		Ok(InverseDoseEquivalentRate{s_per_Sv: super::parse_unit_value(s, Self::unit_symbol())?})
 	}
 }
-/// Dividing a InverseAbsorbedDose by a InverseAcceleration returns a value of type InverseDistance
-impl<T> core::ops::Div<&InverseAcceleration<T>> for InverseAbsorbedDose<T> where T: NumLike {
-	type Output = InverseDistance<T>;
-	fn div(self, rhs: &InverseAcceleration<T>) -> Self::Output {
-		InverseDistance{per_m: self.per_Gy / rhs.s2pm.clone()}
+
+/// Parses a inverse dose equivalent rate value from a number followed by its unit symbol, eg "5 s/Sv" 
+/// (same as `str::parse()`)
+impl<T> core::convert::TryFrom<&str> for InverseDoseEquivalentRate<T> where T: NumLike+core::str::FromStr {
+	type Error = SiParseError;
+	fn try_from(s: &str) -> Result<Self, Self::Error> {
+		<Self as core::str::FromStr>::from_str(s)
 	}
 }
-/// Dividing a InverseAbsorbedDose by a InverseAcceleration returns a value of type InverseDistance
-impl<T> core::ops::Div<&InverseAcceleration<T>> for &InverseAbsorbedDose<T> where T: NumLike {
-	type Output = InverseDistance<T>;
-	fn div(self, rhs: &InverseAcceleration<T>) -> Self::Output {
-		InverseDistance{per_m: self.per_Gy.clone() / rhs.s2pm.clone()}
+
+#[cfg(feature="num-traits")]
+impl<T> InverseDoseEquivalentRate<T> where T: NumLike+num_traits::Euclid {
+
+	/// Returns the Euclidean remainder of dividing this inverse dose equivalent rate value by another, 
+	/// which is never negative (requires the `num-traits` feature)
+	///
+	/// # Arguments
+	/// * `rhs` - The inverse dose equivalent rate value to divide by
+	pub fn rem_euclid(self, rhs: Self) -> Self {
+		InverseDoseEquivalentRate{s_per_Sv: self.s_per_Sv.rem_euclid(&rhs.s_per_Sv)}
 	}
-}
 
-// InverseAbsorbedDose / InverseEnergy -> Mass
-/// Dividing a InverseAbsorbedDose by a InverseEnergy returns a value of type Mass
-impl<T> core::ops::Div<InverseEnergy<T>> for InverseAbsorbedDose<T> where T: NumLike {
-	type Output = Mass<T>;
-	fn div(self, rhs: InverseEnergy<T>) -> Self::Output {
-		Mass{kg: self.per_Gy / rhs.per_J}
+	/// Returns the Euclidean quotient of dividing this inverse dose equivalent rate value by another, 
+	/// ie the whole number of times that `rhs` fits into this value, rounded such that 
+	/// `rem_euclid(...)` is never negative (requires the `num-traits` feature)
+	///
+	/// # Arguments
+	/// * `rhs` - The inverse dose equivalent rate value to divide by
+	pub fn div_euclid(self, rhs: Self) -> T {
+		self.s_per_Sv.div_euclid(&rhs.s_per_Sv)
 	}
 }
-/// Dividing a InverseAbsorbedDose by a InverseEnergy returns a value of type Mass
-impl<T> core::ops::Div<InverseEnergy<T>> for &InverseAbsorbedDose<T> where T: NumLike {
-	type Output = Mass<T>;
-	fn div(self, rhs: InverseEnergy<T>) -> Self::Output {
-		Mass{kg: self.per_Gy.clone() / rhs.per_J}
+
+/// Summing inverse dose equivalent rate values returns their total as a inverse dose equivalent rate value (or zero if there are no values)
+impl<T> core::iter::Sum<InverseDoseEquivalentRate<T>> for InverseDoseEquivalentRate<T> where T: NumLike+core::iter::Sum {
+	fn sum<I: Iterator<Item=InverseDoseEquivalentRate<T>>>(iter: I) -> Self {
+		InverseDoseEquivalentRate{s_per_Sv: iter.map(|x| x.s_per_Sv).sum()}
 	}
 }
-/// Dividing a InverseAbsorbedDose by a InverseEnergy returns a value of type Mass
-impl<T> core::ops::Div<&InverseEnergy<T>> for InverseAbsorbedDose<T> where T: NumLike {
-	type Output = Mass<T>;
-	fn div(self, rhs: &InverseEnergy<T>) -> Self::Output {
-		Mass{kg: self.per_Gy / rhs.per_J.clone()}
+/// Summing inverse dose equivalent rate values returns their total as a inverse dose equivalent rate value (or zero if there are no values)
+impl<'a, T> core::iter::Sum<&'a InverseDoseEquivalentRate<T>> for InverseDoseEquivalentRate<T> where T: NumLike+core::iter::Sum+'a {
+	fn sum<I: Iterator<Item=&'a InverseDoseEquivalentRate<T>>>(iter: I) -> Self {
+		InverseDoseEquivalentRate{s_per_Sv: iter.map(|x| x.s_per_Sv.clone()).sum()}
 	}
 }
-/// Dividing a InverseAbsorbedDose by a InverseEnergy returns a value of type Mass
-impl<T> core::ops::Div<&InverseEnergy<T>> for &InverseAbsorbedDose<T> where T: NumLike {
-	type Output = Mass<T>;
-	fn div(self, rhs: &InverseEnergy<T>) -> Self::Output {
-		Mass{kg: self.per_Gy.clone() / rhs.per_J.clone()}
+
+impl<T> InverseDoseEquivalentRate<T> where T: NumLike+From<f64> {
+
+	/// Returns the weighted average of the given inverse dose equivalent rate values, where each 
+	/// value is paired with its weight (weights do not need to add up to 1). If the 
+	/// total weight is zero (including when `values` is empty), then zero is returned
+	///
+	/// # Arguments
+	/// * `values` - Slice of (inverse dose equivalent rate value, weight) pairs
+	pub fn weighted_mean(values: &[(Self, f64)]) -> Self {
+		let total_weight: f64 = values.iter().map(|(_, weight)| *weight).sum();
+		if total_weight == 0.0 {
+			return InverseDoseEquivalentRate{s_per_Sv: T::from(0.0)};
+		}
+		let total = values.iter().fold(T::from(0.0),
+			|total, (value, weight)| total + value.s_per_Sv.clone() * T::from(*weight));
+		InverseDoseEquivalentRate{s_per_Sv: total / T::from(total_weight)}
 	}
 }
 
-// InverseAbsorbedDose / InverseTorque -> Mass
-/// Dividing a InverseAbsorbedDose by a InverseTorque returns a value of type Mass
-impl<T> core::ops::Div<InverseTorque<T>> for InverseAbsorbedDose<T> where T: NumLike {
-	type Output = Mass<T>;
-	fn div(self, rhs: InverseTorque<T>) -> Self::Output {
-		Mass{kg: self.per_Gy / rhs.per_Nm}
+impl<T> InverseDoseEquivalentRate<T> where T: NumLike+PartialEq+From<u8> {
+
+	/// Divides this inverse dose equivalent rate value by another unit value (eg `distance.checked_div(time)` 
+	/// returns a velocity), returning `None` if the divisor is zero instead of producing 
+	/// infinity or NaN (or panicking, for integer number types). This works for every unit 
+	/// type that this inverse dose equivalent rate value can be divided by with the `/` operator
+	///
+	/// # Arguments
+	/// * `rhs` - The unit value to divide by
+	pub fn checked_div<R>(self, rhs: R) -> Option<<Self as core::ops::Div<R>>::Output> 
+		where Self: core::ops::Div<R>, R: super::SiUnit<Number=T>
+	{
+		if *rhs.raw() == T::from(0u8) {
+			return None;
+		}
+		Some(self / rhs)
 	}
 }
-/// Dividing a InverseAbsorbedDose by a InverseTorque returns a value of type Mass
-impl<T> core::ops::Div<InverseTorque<T>> for &InverseAbsorbedDose<T> where T: NumLike {
-	type Output = Mass<T>;
-	fn div(self, rhs: InverseTorque<T>) -> Self::Output {
-		Mass{kg: self.per_Gy.clone() / rhs.per_Nm}
+
+/// Multiplying a inverse dose equivalent rate value by a dimensionless value returns a inverse dose equivalent rate value
+impl<T> core::ops::Mul<super::base::Dimensionless<T>> for InverseDoseEquivalentRate<T> where T: NumLike {
+	type Output = InverseDoseEquivalentRate<T>;
+	fn mul(self, rhs: super::base::Dimensionless<T>) -> Self::Output {
+		InverseDoseEquivalentRate{s_per_Sv: self.s_per_Sv * rhs.value}
 	}
 }
-/// Dividing a InverseAbsorbedDose by a InverseTorque returns a value of type Mass
-impl<T> core::ops::Div<&InverseTorque<T>> for InverseAbsorbedDose<T> where T: NumLike {
-	type Output = Mass<T>;
-	fn div(self, rhs: &InverseTorque<T>) -> Self::Output {
-		Mass{kg: self.per_Gy / rhs.per_Nm.clone()}
+/// Multiplying a dimensionless value by a inverse dose equivalent rate value returns a inverse dose equivalent rate value
+impl<T> core::ops::Mul<InverseDoseEquivalentRate<T>> for super::base::Dimensionless<T> where T: NumLike {
+	type Output = InverseDoseEquivalentRate<T>;
+	fn mul(self, rhs: InverseDoseEquivalentRate<T>) -> Self::Output {
+		InverseDoseEquivalentRate{s_per_Sv: self.value * rhs.s_per_Sv}
 	}
 }
-/// Dividing a InverseAbsorbedDose by a InverseTorque returns a value of type Mass
-impl<T> core::ops::Div<&InverseTorque<T>> for &InverseAbsorbedDose<T> where T: NumLike {
-	type Output = Mass<T>;
-	fn div(self, rhs: &InverseTorque<T>) -> Self::Output {
-		Mass{kg: self.per_Gy.clone() / rhs.per_Nm.clone()}
+/// Dividing a inverse dose equivalent rate value by a dimensionless value returns a inverse dose equivalent rate value
+impl<T> core::ops::Div<super::base::Dimensionless<T>> for InverseDoseEquivalentRate<T> where T: NumLike {
+	type Output = InverseDoseEquivalentRate<T>;
+	fn div(self, rhs: super::base::Dimensionless<T>) -> Self::Output {
+		InverseDoseEquivalentRate{s_per_Sv: self.s_per_Sv / rhs.value}
 	}
 }
 
-// InverseAbsorbedDose / InversePressure -> Density
-/// Dividing a InverseAbsorbedDose by a InversePressure returns a value of type Density
-impl<T> core::ops::Div<InversePressure<T>> for InverseAbsorbedDose<T> where T: NumLike {
-	type Output = Density<T>;
-	fn div(self, rhs: InversePressure<T>) -> Self::Output {
-		Density{kgpm3: self.per_Gy / rhs.per_Pa}
+/// The remainder of dividing a inverse dose equivalent rate value by another inverse dose equivalent rate value is a inverse dose equivalent rate value
+impl<T> core::ops::Rem<InverseDoseEquivalentRate<T>> for InverseDoseEquivalentRate<T> where T: NumLike+core::ops::Rem<Output=T> {
+	type Output = InverseDoseEquivalentRate<T>;
+	fn rem(self, rhs: InverseDoseEquivalentRate<T>) -> Self::Output {
+		InverseDoseEquivalentRate{s_per_Sv: self.s_per_Sv % rhs.s_per_Sv}
 	}
 }
-/// Dividing a InverseAbsorbedDose by a InversePressure returns a value of type Density
-impl<T> core::ops::Div<InversePressure<T>> for &InverseAbsorbedDose<T> where T: NumLike {
-	type Output = Density<T>;
-	fn div(self, rhs: InversePressure<T>) -> Self::Output {
-		Density{kgpm3: self.per_Gy.clone() / rhs.per_Pa}
+/// The remainder of dividing a inverse dose equivalent rate value by another inverse dose equivalent rate value is a inverse dose equivalent rate value
+impl<T> core::ops::Rem<&InverseDoseEquivalentRate<T>> for InverseDoseEquivalentRate<T> where T: NumLike+core::ops::Rem<Output=T> {
+	type Output = InverseDoseEquivalentRate<T>;
+	fn rem(self, rhs: &InverseDoseEquivalentRate<T>) -> Self::Output {
+		InverseDoseEquivalentRate{s_per_Sv: self.s_per_Sv % rhs.s_per_Sv.clone()}
 	}
 }
-/// Dividing a InverseAbsorbedDose by a InversePressure returns a value of type Density
-impl<T> core::ops::Div<&InversePressure<T>> for InverseAbsorbedDose<T> where T: NumLike {
-	type Output = Density<T>;
-	fn div(self, rhs: &InversePressure<T>) -> Self::Output {
-		Density{kgpm3: self.per_Gy / rhs.per_Pa.clone()}
+/// The remainder of dividing a inverse dose equivalent rate value by another inverse dose equivalent rate value is a inverse dose equivalent rate value
+impl<T> core::ops::Rem<InverseDoseEquivalentRate<T>> for &InverseDoseEquivalentRate<T> where T: NumLike+core::ops::Rem<Output=T> {
+	type Output = InverseDoseEquivalentRate<T>;
+	fn rem(self, rhs: InverseDoseEquivalentRate<T>) -> Self::Output {
+		InverseDoseEquivalentRate{s_per_Sv: self.s_per_Sv.clone() % rhs.s_per_Sv}
 	}
 }
-/// Dividing a InverseAbsorbedDose by a InversePressure returns a value of type Density
-impl<T> core::ops::Div<&InversePressure<T>> for &InverseAbsorbedDose<T> where T: NumLike {
-	type Output = Density<T>;
-	fn div(self, rhs: &InversePressure<T>) -> Self::Output {
-		Density{kgpm3: self.per_Gy.clone() / rhs.per_Pa.clone()}
+/// The remainder of dividing a inverse dose equivalent rate value by another inverse dose equivalent rate value is a inverse dose equivalent rate value
+impl<T> core::ops::Rem<&InverseDoseEquivalentRate<T>> for &InverseDoseEquivalentRate<T> where T: NumLike+core::ops::Rem<Output=T> {
+	type Output = InverseDoseEquivalentRate<T>;
+	fn rem(self, rhs: &InverseDoseEquivalentRate<T>) -> Self::Output {
+		InverseDoseEquivalentRate{s_per_Sv: self.s_per_Sv.clone() % rhs.s_per_Sv.clone()}
 	}
 }
 
-// InverseAbsorbedDose * Pressure -> Density
-/// Multiplying a InverseAbsorbedDose by a Pressure returns a value of type Density
-impl<T> core::ops::Mul<Pressure<T>> for InverseAbsorbedDose<T> where T: NumLike {
-	type Output = Density<T>;
-	fn mul(self, rhs: Pressure<T>) -> Self::Output {
-		Density{kgpm3: self.per_Gy * rhs.Pa}
-	}
-}
-/// Multiplying a InverseAbsorbedDose by a Pressure returns a value of type Density
-impl<T> core::ops::Mul<Pressure<T>> for &InverseAbsorbedDose<T> where T: NumLike {
-	type Output = Density<T>;
-	fn mul(self, rhs: Pressure<T>) -> Self::Output {
-		Density{kgpm3: self.per_Gy.clone() * rhs.Pa}
+/// Serializes a inverse dose equivalent rate value together with its unit symbol, eg 
+/// `{"value": 1.5, "unit": "s/Sv"}` (requires the `serde-unit-tag` feature)
+#[cfg(feature="serde-unit-tag")]
+impl<T> serde::Serialize for InverseDoseEquivalentRate<T> where T: NumLike+serde::Serialize {
+	fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+		super::serialize_with_unit_tag(&self.s_per_Sv, Self::unit_symbol(), serializer)
 	}
 }
-/// Multiplying a InverseAbsorbedDose by a Pressure returns a value of type Density
-impl<T> core::ops::Mul<&Pressure<T>> for InverseAbsorbedDose<T> where T: NumLike {
-	type Output = Density<T>;
-	fn mul(self, rhs: &Pressure<T>) -> Self::Output {
-		Density{kgpm3: self.per_Gy * rhs.Pa.clone()}
+/// Deserializes a inverse dose equivalent rate value from a value and unit symbol, eg 
+/// `{"value": 1.5, "unit": "s/Sv"}`, returning an error if the unit symbol 
+/// does not match (requires the `serde-unit-tag` feature)
+#[cfg(feature="serde-unit-tag")]
+impl<'de, T> serde::Deserialize<'de> for InverseDoseEquivalentRate<T> where T: NumLike+serde::Deserialize<'de> {
+	fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+		Ok(InverseDoseEquivalentRate{s_per_Sv: super::deserialize_with_unit_tag(Self::unit_symbol(), deserializer)?})
 	}
 }
-/// Multiplying a InverseAbsorbedDose by a Pressure returns a value of type Density
-impl<T> core::ops::Mul<&Pressure<T>> for &InverseAbsorbedDose<T> where T: NumLike {
-	type Output = Density<T>;
-	fn mul(self, rhs: &Pressure<T>) -> Self::Output {
-		Density{kgpm3: self.per_Gy.clone() * rhs.Pa.clone()}
-	}
+
+/// The additive identity of inverse dose equivalent rate values is zero seconds per sievert (requires the `num-traits` feature)
+#[cfg(feature="num-traits")]
+impl<T> num_traits::Zero for InverseDoseEquivalentRate<T> where T: NumLike+num_traits::Zero {
+	fn zero() -> Self { InverseDoseEquivalentRate{s_per_Sv: T::zero()} }
+	fn is_zero(&self) -> bool { self.s_per_Sv.is_zero() }
 }
 
-// InverseAbsorbedDose / TimePerDistance -> TimePerDistance
-/// Dividing a InverseAbsorbedDose by a TimePerDistance returns a value of type TimePerDistance
-impl<T> core::ops::Div<TimePerDistance<T>> for InverseAbsorbedDose<T> where T: NumLike {
-	type Output = TimePerDistance<T>;
-	fn div(self, rhs: TimePerDistance<T>) -> Self::Output {
-		TimePerDistance{spm: self.per_Gy / rhs.spm}
+#[cfg(feature="num-traits")]
+impl<T> InverseDoseEquivalentRate<T> where T: NumLike+num_traits::Signed {
+
+	/// Returns the sign of this inverse dose equivalent rate value as a dimensionless number: 1 if it is 
+	/// positive, -1 if it is negative, and 0 if it is zero for integer number types 
+	/// (for floating-point number types, +0.0 returns 1.0, -0.0 returns -1.0, and NaN returns 
+	/// NaN) (requires the `num-traits` feature)
+	pub fn signum(self) -> T {
+		self.s_per_Sv.signum()
 	}
 }
-/// Dividing a InverseAbsorbedDose by a TimePerDistance returns a value of type TimePerDistance
-impl<T> core::ops::Div<TimePerDistance<T>> for &InverseAbsorbedDose<T> where T: NumLike {
-	type Output = TimePerDistance<T>;
-	fn div(self, rhs: TimePerDistance<T>) -> Self::Output {
-		TimePerDistance{spm: self.per_Gy.clone() / rhs.spm}
+
+impl<T> InverseDoseEquivalentRate<T> where T: NumLike+PartialOrd {
+
+	/// Returns the absolute value of this inverse dose equivalent rate value, ie its magnitude without a minus sign
+	/// (for floating-point number types, -0.0 returns +0.0)
+	pub fn abs(self) -> Self {
+		let negated = -self.s_per_Sv.clone();
+		if self.s_per_Sv < negated {
+			InverseDoseEquivalentRate{s_per_Sv: negated}
+		} else if self.s_per_Sv == negated {
+			// zero, which is its own negation (adding +0.0 turns -0.0 into +0.0)
+			InverseDoseEquivalentRate{s_per_Sv: negated + self.s_per_Sv}
+		} else {
+			self
+		}
 	}
-}
-/// Dividing a InverseAbsorbedDose by a TimePerDistance returns a value of type TimePerDistance
-impl<T> core::ops::Div<&TimePerDistance<T>> for InverseAbsorbedDose<T> where T: NumLike {
-	type Output = TimePerDistance<T>;
-	fn div(self, rhs: &TimePerDistance<T>) -> Self::Output {
-		TimePerDistance{spm: self.per_Gy / rhs.spm.clone()}
+
+	/// Returns the lesser of this inverse dose equivalent rate value and the given inverse dose equivalent rate value
+	///
+	/// # Arguments
+	/// * `other` - The inverse dose equivalent rate value to compare with this one
+	pub fn min(self, other: Self) -> Self {
+		if other.s_per_Sv < self.s_per_Sv { other } else { self }
 	}
-}
-/// Dividing a InverseAbsorbedDose by a TimePerDistance returns a value of type TimePerDistance
-impl<T> core::ops::Div<&TimePerDistance<T>> for &InverseAbsorbedDose<T> where T: NumLike {
-	type Output = TimePerDistance<T>;
-	fn div(self, rhs: &TimePerDistance<T>) -> Self::Output {
-		TimePerDistance{spm: self.per_Gy.clone() / rhs.spm.clone()}
+
+	/// Returns the greater of this inverse dose equivalent rate value and the given inverse dose equivalent rate value
+	///
+	/// # Arguments
+	/// * `other` - The inverse dose equivalent rate value to compare with this one
+	pub fn max(self, other: Self) -> Self {
+		if other.s_per_Sv > self.s_per_Sv { other } else { self }
 	}
-}
 
-// InverseAbsorbedDose * Velocity -> TimePerDistance
-/// Multiplying a InverseAbsorbedDose by a Velocity returns a value of type TimePerDistance
-impl<T> core::ops::Mul<Velocity<T>> for InverseAbsorbedDose<T> where T: NumLike {
-	type Output = TimePerDistance<T>;
-	fn mul(self, rhs: Velocity<T>) -> Self::Output {
-		TimePerDistance{spm: self.per_Gy * rhs.mps}
+	/// Returns the least of the given inverse dose equivalent rate values, or `None` if there are none. 
+	/// Unlike `Iterator::min()`, this does not require `Ord`, so it works for 
+	/// floating-point numbers. NaN values are skipped (so the result is `None` if all 
+	/// values are NaN), and the first of several equal values is returned
+	///
+	/// # Arguments
+	/// * `values` - Any iterator or collection of inverse dose equivalent rate values
+	pub fn min_of<I>(values: I) -> Option<Self> where I: IntoIterator<Item=Self> {
+		values.into_iter().filter(|x| x.s_per_Sv.partial_cmp(&x.s_per_Sv).is_some()).reduce(Self::min)
 	}
-}
-/// Multiplying a InverseAbsorbedDose by a Velocity returns a value of type TimePerDistance
-impl<T> core::ops::Mul<Velocity<T>> for &InverseAbsorbedDose<T> where T: NumLike {
-	type Output = TimePerDistance<T>;
-	fn mul(self, rhs: Velocity<T>) -> Self::Output {
-		TimePerDistance{spm: self.per_Gy.clone() * rhs.mps}
+
+	/// Returns the greatest of the given inverse dose equivalent rate values, or `None` if there are none. 
+	/// Unlike `Iterator::max()`, this does not require `Ord`, so it works for 
+	/// floating-point numbers. NaN values are skipped (so the result is `None` if all 
+	/// values are NaN), and the first of several equal values is returned
+	///
+	/// # Arguments
+	/// * `values` - Any iterator or collection of inverse dose equivalent rate values
+	pub fn max_of<I>(values: I) -> Option<Self> where I: IntoIterator<Item=Self> {
+		values.into_iter().filter(|x| x.s_per_Sv.partial_cmp(&x.s_per_Sv).is_some()).reduce(Self::max)
 	}
-}
-/// Multiplying a InverseAbsorbedDose by a Velocity returns a value of type TimePerDistance
-impl<T> core::ops::Mul<&Velocity<T>> for InverseAbsorbedDose<T> where T: NumLike {
-	type Output = TimePerDistance<T>;
-	fn mul(self, rhs: &Velocity<T>) -> Self::Output {
-		TimePerDistance{spm: self.per_Gy * rhs.mps.clone()}
+
+	/// Returns this inverse dose equivalent rate value restricted to the range from `lo` to `hi`
+	///
+	/// # Arguments
+	/// * `lo` - The minimum inverse dose equivalent rate value
+	/// * `hi` - The maximum inverse dose equivalent rate value
+	///
+	/// # Panics
+	/// Panics if `lo` is greater than `hi` (or if either of them is NaN)
+	pub fn clamp(self, lo: Self, hi: Self) -> Self {
+		assert!(lo.s_per_Sv <= hi.s_per_Sv, "lo must be less than or equal to hi");
+		if self.s_per_Sv < lo.s_per_Sv { lo } else if self.s_per_Sv > hi.s_per_Sv { hi } else { self }
 	}
-}
-/// Multiplying a InverseAbsorbedDose by a Velocity returns a value of type TimePerDistance
-impl<T> core::ops::Mul<&Velocity<T>> for &InverseAbsorbedDose<T> where T: NumLike {
-	type Output = TimePerDistance<T>;
-	fn mul(self, rhs: &Velocity<T>) -> Self::Output {
-		TimePerDistance{spm: self.per_Gy.clone() * rhs.mps.clone()}
+
+	/// Returns this inverse dose equivalent rate value with its magnitude capped at `max`, keeping its 
+	/// sign (eg for capping a speed), or this value unchanged if its magnitude does not 
+	/// exceed `max`. For complex-valued inverse dose equivalent rate, use `clamp_norm(...)` instead, 
+	/// which caps the magnitude while keeping the phase
+	///
+	/// # Arguments
+	/// * `max` - The maximum magnitude (should not be negative)
+	pub fn clamp_magnitude(self, max: Self) -> Self {
+		let negated_max = -max.s_per_Sv.clone();
+		if self.s_per_Sv > max.s_per_Sv { 
+			max 
+		} else if self.s_per_Sv < negated_max { 
+			InverseDoseEquivalentRate{s_per_Sv: negated_max} 
+		} else { 
+			self 
+		}
+	}
+
+	/// Returns true if this inverse dose equivalent rate value differs from the given inverse dose equivalent rate 
+	/// value by no more than the given absolute tolerance (which is also a 
+	/// inverse dose equivalent rate value, so the comparison is dimensionally safe). NaN is never 
+	/// equal to anything. See also the `assert_unit_eq!(...)` macro.
+	///
+	/// # Arguments
+	/// * `other` - The inverse dose equivalent rate value to compare with this one
+	/// * `tolerance` - The largest allowed difference between the two values
+	pub fn eq_within(self, other: Self, tolerance: Self) -> bool {
+		let diff = self.s_per_Sv - other.s_per_Sv;
+		diff <= tolerance.s_per_Sv && -diff <= tolerance.s_per_Sv
 	}
 }
 
-// InverseAbsorbedDose * VolumePerMass -> InversePressure
-/// Multiplying a InverseAbsorbedDose by a VolumePerMass returns a value of type InversePressure
-impl<T> core::ops::Mul<VolumePerMass<T>> for InverseAbsorbedDose<T> where T: NumLike {
-	type Output = InversePressure<T>;
-	fn mul(self, rhs: VolumePerMass<T>) -> Self::Output {
-		InversePressure{per_Pa: self.per_Gy * rhs.m3_per_kg}
+#[cfg(feature="num-complex")]
+impl InverseDoseEquivalentRate<num_complex::Complex32> {
+	/// Returns the magnitude (aka norm or absolute value) of this complex inverse dose equivalent rate 
+	/// value as a real-valued inverse dose equivalent rate (requires the `num-complex` feature)
+	pub fn magnitude(self) -> InverseDoseEquivalentRate<f32> {
+		InverseDoseEquivalentRate{s_per_Sv: self.s_per_Sv.norm()}
 	}
-}
-/// Multiplying a InverseAbsorbedDose by a VolumePerMass returns a value of type InversePressure
-impl<T> core::ops::Mul<VolumePerMass<T>> for &InverseAbsorbedDose<T> where T: NumLike {
-	type Output = InversePressure<T>;
-	fn mul(self, rhs: VolumePerMass<T>) -> Self::Output {
-		InversePressure{per_Pa: self.per_Gy.clone() * rhs.m3_per_kg}
+
+	/// Returns this complex inverse dose equivalent rate value scaled down so that its magnitude is 
+	/// at most `max` (keeping its phase), or this value unchanged if its magnitude does 
+	/// not exceed `max`. This is the complex equivalent of `clamp_magnitude(...)` (requires 
+	/// the `num-complex` feature)
+	///
+	/// # Arguments
+	/// * `max` - The maximum magnitude (should not be negative)
+	pub fn clamp_norm(self, max: InverseDoseEquivalentRate<f32>) -> Self {
+		let norm = self.s_per_Sv.norm();
+		if norm > max.s_per_Sv {
+			InverseDoseEquivalentRate{s_per_Sv: self.s_per_Sv * (max.s_per_Sv / norm)}
+		} else {
+			self
+		}
 	}
 }
-/// Multiplying a InverseAbsorbedDose by a VolumePerMass returns a value of type InversePressure
-impl<T> core::ops::Mul<&VolumePerMass<T>> for InverseAbsorbedDose<T> where T: NumLike {
-	type Output = InversePressure<T>;
-	fn mul(self, rhs: &VolumePerMass<T>) -> Self::Output {
-		InversePressure{per_Pa: self.per_Gy * rhs.m3_per_kg.clone()}
+
+#[cfg(feature="num-complex")]
+impl InverseDoseEquivalentRate<num_complex::Complex64> {
+	/// Returns the magnitude (aka norm or absolute value) of this complex inverse dose equivalent rate 
+	/// value as a real-valued inverse dose equivalent rate (requires the `num-complex` feature)
+	pub fn magnitude(self) -> InverseDoseEquivalentRate<f64> {
+		InverseDoseEquivalentRate{s_per_Sv: self.s_per_Sv.norm()}
 	}
-}
-/// Multiplying a InverseAbsorbedDose by a VolumePerMass returns a value of type InversePressure
-impl<T> core::ops::Mul<&VolumePerMass<T>> for &InverseAbsorbedDose<T> where T: NumLike {
-	type Output = InversePressure<T>;
-	fn mul(self, rhs: &VolumePerMass<T>) -> Self::Output {
-		InversePressure{per_Pa: self.per_Gy.clone() * rhs.m3_per_kg.clone()}
+
+	/// Returns this complex inverse dose equivalent rate value scaled down so that its magnitude is 
+	/// at most `max` (keeping its phase), or this value unchanged if its magnitude does 
+	/// not exceed `max`. This is the complex equivalent of `clamp_magnitude(...)` (requires 
+	/// the `num-complex` feature)
+	///
+	/// # Arguments
+	/// * `max` - The maximum magnitude (should not be negative)
+	pub fn clamp_norm(self, max: InverseDoseEquivalentRate<f64>) -> Self {
+		let norm = self.s_per_Sv.norm();
+		if norm > max.s_per_Sv {
+			InverseDoseEquivalentRate{s_per_Sv: self.s_per_Sv * (max.s_per_Sv / norm)}
+		} else {
+			self
+		}
 	}
 }
 
-/// The inverse of radiation dose equivalent unit type, defined as inverse sieverts in SI units
-#[derive(UnitStruct, Debug, Clone)]
-#[cfg_attr(feature="serde", derive(Serialize, Deserialize))]
-pub struct InverseDoseEquivalent<T: NumLike>{
-	/// The value of this Inverse dose equivalent in inverse sieverts
-	pub per_Sv: T
-}
+/// Rounding and other floating-point functions (requires the `num-traits` feature, 
+/// and either the `std` or `libm` feature)
+#[cfg(all(feature="num-traits", any(feature="std", feature="libm")))]
+impl<T> InverseDoseEquivalentRate<T> where T: NumLike+num_traits::Float {
 
-impl<T> InverseDoseEquivalent<T> where T: NumLike {
+	/// Returns this inverse dose equivalent rate value rounded to the nearest whole number of seconds per sievert 
+	/// (half-way cases are rounded away from zero)
+	pub fn round(self) -> Self {
+		InverseDoseEquivalentRate{s_per_Sv: self.s_per_Sv.round()}
+	}
 
-	/// Returns the standard unit name of inverse dose equivalent: "inverse sieverts"
-	pub fn unit_name() -> &'static str { "inverse sieverts" }
-	
-	/// Returns the abbreviated name or symbol of inverse dose equivalent: "1/Sv" for inverse sieverts
-	pub fn unit_symbol() -> &'static str { "1/Sv" }
-	
-	/// Returns a new inverse dose equivalent value from the given number of inverse sieverts
+	/// Returns this inverse dose equivalent rate value rounded down to a whole number of seconds per sievert
+	pub fn floor(self) -> Self {
+		InverseDoseEquivalentRate{s_per_Sv: self.s_per_Sv.floor()}
+	}
+
+	/// Returns this inverse dose equivalent rate value rounded up to a whole number of seconds per sievert
+	pub fn ceil(self) -> Self {
+		InverseDoseEquivalentRate{s_per_Sv: self.s_per_Sv.ceil()}
+	}
+
+	/// Returns this inverse dose equivalent rate value rounded towards zero to a whole number of seconds per sievert
+	pub fn trunc(self) -> Self {
+		InverseDoseEquivalentRate{s_per_Sv: self.s_per_Sv.trunc()}
+	}
+
+	/// Returns this inverse dose equivalent rate value rounded to the nearest multiple of the given step 
+	/// (half-way cases are rounded away from zero)
 	///
 	/// # Arguments
-	/// * `per_Sv` - Any number-like type, representing a quantity of inverse sieverts
-	pub fn from_per_Sv(per_Sv: T) -> Self { InverseDoseEquivalent{per_Sv: per_Sv} }
-	
-	/// Returns a copy of this inverse dose equivalent value in inverse sieverts
-	pub fn to_per_Sv(&self) -> T { self.per_Sv.clone() }
+	/// * `step` - The inverse dose equivalent rate value to round to a multiple of
+	pub fn round_to(self, step: Self) -> Self {
+		InverseDoseEquivalentRate{s_per_Sv: (self.s_per_Sv / step.s_per_Sv).round() * step.s_per_Sv}
+	}
 
-	/// Returns a new inverse dose equivalent value from the given number of inverse sieverts
+	/// Fused multiply-add: returns `self * a + b` computed with only one rounding 
+	/// error, which is more accurate (and on some hardware faster) than a separate 
+	/// multiplication and addition
 	///
 	/// # Arguments
-	/// * `per_sieverts` - Any number-like type, representing a quantity of inverse sieverts
-	pub fn from_per_sieverts(per_sieverts: T) -> Self { InverseDoseEquivalent{per_Sv: per_sieverts} }
-	
-	/// Returns a copy of this inverse dose equivalent value in inverse sieverts
-	pub fn to_per_sieverts(&self) -> T { self.per_Sv.clone() }
+	/// * `a` - The scalar value to multiply this inverse dose equivalent rate value by
+	/// * `b` - The inverse dose equivalent rate value to add to the product
+	pub fn mul_add(self, a: T, b: Self) -> Self {
+		InverseDoseEquivalentRate{s_per_Sv: self.s_per_Sv.mul_add(a, b.s_per_Sv)}
+	}
 
-}
+	/// Returns true if this inverse dose equivalent rate value is neither infinite nor NaN
+	pub fn is_finite(&self) -> bool {
+		self.s_per_Sv.is_finite()
+	}
 
-impl<T> fmt::Display for InverseDoseEquivalent<T> where T: NumLike {
-	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-		write!(f, "{} {}", &self.per_Sv, Self::unit_symbol())
+	/// Returns true if this inverse dose equivalent rate value is positive or negative infinity
+	pub fn is_infinite(&self) -> bool {
+		self.s_per_Sv.is_infinite()
 	}
-}
 
-impl<T> InverseDoseEquivalent<T> where T: NumLike+From<f64> {
-	
-	/// Returns a copy of this inverse dose equivalent value in inverse millisieverts
-	/// 
-	/// *Note: This method is not available for `f32` and other number types lacking the `From<f64>` trait*
-	pub fn to_per_mSv(&self) -> T {
-		return self.per_Sv.clone() * T::from(0.001_f64);
+	/// Returns true if this inverse dose equivalent rate value is NaN (not a number)
+	pub fn is_nan(&self) -> bool {
+		self.s_per_Sv.is_nan()
 	}
 
-	/// Returns a new inverse dose equivalent value from the given number of inverse millisieverts
-	/// 
-	/// *Note: This method is not available for `f32` and other number types lacking the `From<f64>` trait*
+	/// Returns true if this inverse dose equivalent rate value has a positive sign, including +0.0 
+	/// and positive infinity
+	pub fn is_sign_positive(&self) -> bool {
+		self.s_per_Sv.is_sign_positive()
+	}
+
+	/// Returns true if this inverse dose equivalent rate value has a negative sign, including -0.0 
+	/// and negative infinity
+	pub fn is_sign_negative(&self) -> bool {
+		self.s_per_Sv.is_sign_negative()
+	}
+
+	/// Returns a inverse dose equivalent rate value with the magnitude of this one and the sign of 
+	/// `sign_source`, eg for reflecting a value off of a surface
 	///
 	/// # Arguments
-	/// * `per_mSv` - Any number-like type, representing a quantity of inverse millisieverts
-	pub fn from_per_mSv(per_mSv: T) -> Self {
-		InverseDoseEquivalent{per_Sv: per_mSv * T::from(1000.0_f64)}
+	/// * `sign_source` - The inverse dose equivalent rate value whose sign is copied
+	pub fn copysign(self, sign_source: Self) -> Self {
+		InverseDoseEquivalentRate{s_per_Sv: self.s_per_Sv.copysign(sign_source.s_per_Sv)}
 	}
+}
 
-	/// Returns a copy of this inverse dose equivalent value in inverse microsieverts
-	/// 
-	/// *Note: This method is not available for `f32` and other number types lacking the `From<f64>` trait*
-	pub fn to_per_uSv(&self) -> T {
-		return self.per_Sv.clone() * T::from(1e-06_f64);
+#[cfg(feature="num-traits")]
+impl<T> InverseDoseEquivalentRate<T> where T: NumLike+num_traits::CheckedAdd+num_traits::CheckedSub {
+
+	/// Adds another inverse dose equivalent rate value to this one, returning `None` if the 
+	/// addition overflows the number type (requires the `num-traits` feature)
+	pub fn checked_add(self, rhs: Self) -> Option<Self> {
+		Some(InverseDoseEquivalentRate{s_per_Sv: self.s_per_Sv.checked_add(&rhs.s_per_Sv)?})
 	}
 
-	/// Returns a new inverse dose equivalent value from the given number of inverse microsieverts
-	/// 
-	/// *Note: This method is not available for `f32` and other number types lacking the `From<f64>` trait*
+	/// Subtracts another inverse dose equivalent rate value from this one, returning `None` if 
+	/// the subtraction overflows the number type (requires the `num-traits` feature)
+	pub fn checked_sub(self, rhs: Self) -> Option<Self> {
+		Some(InverseDoseEquivalentRate{s_per_Sv: self.s_per_Sv.checked_sub(&rhs.s_per_Sv)?})
+	}
+}
+
+#[cfg(feature="num-traits")]
+impl<T> InverseDoseEquivalentRate<T> where T: NumLike+num_traits::SaturatingAdd+num_traits::SaturatingSub {
+
+	/// Adds another inverse dose equivalent rate value to this one, clamping the result to the 
+	/// minimum or maximum value of the number type instead of overflowing 
+	/// (requires the `num-traits` feature)
+	pub fn saturating_add(self, rhs: Self) -> Self {
+		InverseDoseEquivalentRate{s_per_Sv: self.s_per_Sv.saturating_add(&rhs.s_per_Sv)}
+	}
+
+	/// Subtracts another inverse dose equivalent rate value from this one, clamping the result 
+	/// to the minimum or maximum value of the number type instead of overflowing 
+	/// (requires the `num-traits` feature)
+	pub fn saturating_sub(self, rhs: Self) -> Self {
+		InverseDoseEquivalentRate{s_per_Sv: self.s_per_Sv.saturating_sub(&rhs.s_per_Sv)}
+	}
+}
+
+impl<T> InverseDoseEquivalentRate<T> where T: NumLike+Into<f64> {
+
+	/// Returns true if this inverse dose equivalent rate value is equal to the given inverse dose equivalent rate 
+	/// value to within the given number of significant figures (eg 6 significant 
+	/// figures allows a relative difference of up to 1 part per million). NaN is 
+	/// only approximately equal to NaN, infinity is only approximately equal to 
+	/// infinity of the same sign, and zero is only approximately equal to zero.
 	///
 	/// # Arguments
-	/// * `per_uSv` - Any number-like type, representing a quantity of inverse microsieverts
-	pub fn from_per_uSv(per_uSv: T) -> Self {
-		InverseDoseEquivalent{per_Sv: per_uSv * T::from(1000000.0_f64)}
+	/// * `other` - The inverse dose equivalent rate value to compare with this one
+	/// * `sig_figs` - The number of significant figures that must match
+	pub fn approx_eq(self, other: Self, sig_figs: i32) -> bool {
+		super::approx_eq_f64(self.s_per_Sv.into(), other.s_per_Sv.into(), sig_figs)
 	}
+}
 
-	/// Returns a copy of this inverse dose equivalent value in inverse nanosieverts
-	/// 
-	/// *Note: This method is not available for `f32` and other number types lacking the `From<f64>` trait*
-	pub fn to_per_nSv(&self) -> T {
-		return self.per_Sv.clone() * T::from(1e-09_f64);
-	}
+impl<T> InverseDoseEquivalentRate<T> where T: NumLike+From<f64> {
 
-	/// Returns a new inverse dose equivalent value from the given number of inverse nanosieverts
-	/// 
-	/// *Note: This method is not available for `f32` and other number types lacking the `From<f64>` trait*
+	/// Returns the linear interpolation between this inverse dose equivalent rate value and the given 
+	/// inverse dose equivalent rate value, ie `self*(1-t) + other*t`. The interpolation factor is not 
+	/// clamped, so values of `t` outside the range 0 to 1 extrapolate beyond the two 
+	/// values (see `lerp_clamped(...)`).
 	///
 	/// # Arguments
-	/// * `per_nSv` - Any number-like type, representing a quantity of inverse nanosieverts
-	pub fn from_per_nSv(per_nSv: T) -> Self {
-		InverseDoseEquivalent{per_Sv: per_nSv * T::from(1000000000.0_f64)}
+	/// * `other` - The inverse dose equivalent rate value to interpolate towards (returned when `t` is 1)
+	/// * `t` - The interpolation factor, where 0 returns this value and 1 returns `other`
+	pub fn lerp(self, other: Self, t: f64) -> Self {
+		InverseDoseEquivalentRate{s_per_Sv: self.s_per_Sv * T::from(1.0 - t) + other.s_per_Sv * T::from(t)}
 	}
 
-	/// Returns a copy of this inverse dose equivalent value in inverse kilosieverts
+	/// Same as `lerp(...)`, except that the interpolation factor `t` is first clamped 
+	/// to the range 0 to 1, so that the result is always between the two values
+	///
+	/// # Arguments
+	/// * `other` - The inverse dose equivalent rate value to interpolate towards (returned when `t` is 1 or more)
+	/// * `t` - The interpolation factor, where 0 returns this value and 1 returns `other`
+	pub fn lerp_clamped(self, other: Self, t: f64) -> Self {
+		self.lerp(other, t.clamp(0.0, 1.0))
+	}
+	
+	/// Returns a copy of this inverse dose equivalent rate value in seconds per millisievert
 	/// 
 	/// *Note: This method is not available for `f32` and other number types lacking the `From<f64>` trait*
-	pub fn to_per_kSv(&self) -> T {
-		return self.per_Sv.clone() * T::from(1000.0_f64);
+	pub fn to_s_per_mSv(&self) -> T {
+		return self.s_per_Sv.clone() * T::from(0.001_f64);
 	}
 
-	/// Returns a new inverse dose equivalent value from the given number of inverse kilosieverts
+	/// Returns a new inverse dose equivalent rate value from the given number of seconds per millisievert
 	/// 
 	/// *Note: This method is not available for `f32` and other number types lacking the `From<f64>` trait*
 	///
 	/// # Arguments
-	/// * `per_kSv` - Any number-like type, representing a quantity of inverse kilosieverts
-	pub fn from_per_kSv(per_kSv: T) -> Self {
-		InverseDoseEquivalent{per_Sv: per_kSv * T::from(0.001_f64)}
+	/// * `s_per_mSv` - Any number-like type, representing a quantity of seconds per millisievert
+	pub fn from_s_per_mSv(s_per_mSv: T) -> Self {
+		InverseDoseEquivalentRate{s_per_Sv: s_per_mSv * T::from(1000.0_f64)}
 	}
 
-	/// Returns a copy of this inverse dose equivalent value in inverse megasieverts
+	/// Returns a copy of this inverse dose equivalent rate value in seconds per microsievert
 	/// 
 	/// *Note: This method is not available for `f32` and other number types lacking the `From<f64>` trait*
-	pub fn to_per_MSv(&self) -> T {
-		return self.per_Sv.clone() * T::from(1000000.0_f64);
+	pub fn to_s_per_uSv(&self) -> T {
+		return self.s_per_Sv.clone() * T::from(1e-06_f64);
 	}
 
-	/// Returns a new inverse dose equivalent value from the given number of inverse megasieverts
+	/// Returns a new inverse dose equivalent rate value from the given number of seconds per microsievert
 	/// 
 	/// *Note: This method is not available for `f32` and other number types lacking the `From<f64>` trait*
 	///
 	/// # Arguments
-	/// * `per_MSv` - Any number-like type, representing a quantity of inverse megasieverts
-	pub fn from_per_MSv(per_MSv: T) -> Self {
-		InverseDoseEquivalent{per_Sv: per_MSv * T::from(1e-06_f64)}
+	/// * `s_per_uSv` - Any number-like type, representing a quantity of seconds per microsievert
+	pub fn from_s_per_uSv(s_per_uSv: T) -> Self {
+		InverseDoseEquivalentRate{s_per_Sv: s_per_uSv * T::from(1000000.0_f64)}
 	}
 
-	/// Returns a copy of this inverse dose equivalent value in inverse gigasieverts
+	/// Returns a copy of this inverse dose equivalent rate value in hours per sievert
 	/// 
 	/// *Note: This method is not available for `f32` and other number types lacking the `From<f64>` trait*
-	pub fn to_per_GSv(&self) -> T {
-		return self.per_Sv.clone() * T::from(1000000000.0_f64);
+	pub fn to_h_per_Sv(&self) -> T {
+		return self.s_per_Sv.clone() * T::from(0.0002777777777777778_f64);
 	}
 
-	/// Returns a new inverse dose equivalent value from the given number of inverse gigasieverts
+	/// Returns a new inverse dose equivalent rate value from the given number of hours per sievert
 	/// 
 	/// *Note: This method is not available for `f32` and other number types lacking the `From<f64>` trait*
 	///
 	/// # Arguments
-	/// * `per_GSv` - Any number-like type, representing a quantity of inverse gigasieverts
-	pub fn from_per_GSv(per_GSv: T) -> Self {
-		InverseDoseEquivalent{per_Sv: per_GSv * T::from(1e-09_f64)}
+	/// * `h_per_Sv` - Any number-like type, representing a quantity of hours per sievert
+	pub fn from_h_per_Sv(h_per_Sv: T) -> Self {
+		InverseDoseEquivalentRate{s_per_Sv: h_per_Sv * T::from(3600.0_f64)}
 	}
 
-	/// Returns a copy of this inverse dose equivalent value in inverse roentgen equivalent man
+	/// Returns a copy of this inverse dose equivalent rate value in hours per millisievert
 	/// 
 	/// *Note: This method is not available for `f32` and other number types lacking the `From<f64>` trait*
-	pub fn to_per_rem(&self) -> T {
-		return self.per_Sv.clone() * T::from(0.01_f64);
+	pub fn to_h_per_mSv(&self) -> T {
+		return self.s_per_Sv.clone() * T::from(2.7777777777777776e-07_f64);
 	}
 
-	/// Returns a new inverse dose equivalent value from the given number of inverse roentgen equivalent man
+	/// Returns a new inverse dose equivalent rate value from the given number of hours per millisievert
 	/// 
 	/// *Note: This method is not available for `f32` and other number types lacking the `From<f64>` trait*
 	///
 	/// # Arguments
-	/// * `per_rem` - Any number-like type, representing a quantity of inverse roentgen equivalent man
-	pub fn from_per_rem(per_rem: T) -> Self {
-		InverseDoseEquivalent{per_Sv: per_rem * T::from(100.0_f64)}
+	/// * `h_per_mSv` - Any number-like type, representing a quantity of hours per millisievert
+	pub fn from_h_per_mSv(h_per_mSv: T) -> Self {
+		InverseDoseEquivalentRate{s_per_Sv: h_per_mSv * T::from(3600000.0_f64)}
 	}
 
-	/// Returns a copy of this inverse dose equivalent value in inverse milli-roentgen equivalents
+	/// Returns a copy of this inverse dose equivalent rate value in hours per microsievert
 	/// 
 	/// *Note: This method is not available for `f32` and other number types lacking the `From<f64>` trait*
-	pub fn to_per_mrem(&self) -> T {
-		return self.per_Sv.clone() * T::from(1e-05_f64);
+	pub fn to_h_per_uSv(&self) -> T {
+		return self.s_per_Sv.clone() * T::from(2.7777777777777777e-10_f64);
 	}
 
-	/// Returns a new inverse dose equivalent value from the given number of inverse milli-roentgen equivalents
+	/// Returns a new inverse dose equivalent rate value from the given number of hours per microsievert
 	/// 
 	/// *Note: This method is not available for `f32` and other number types lacking the `From<f64>` trait*
 	///
 	/// # Arguments
-	/// * `per_mrem` - Any number-like type, representing a quantity of inverse milli-roentgen equivalents
-	pub fn from_per_mrem(per_mrem: T) -> Self {
-		InverseDoseEquivalent{per_Sv: per_mrem * T::from(100000.0_f64)}
+	/// * `h_per_uSv` - Any number-like type, representing a quantity of hours per microsievert
+	pub fn from_h_per_uSv(h_per_uSv: T) -> Self {
+		InverseDoseEquivalentRate{s_per_Sv: h_per_uSv * T::from(3600000000.0_f64)}
 	}
 
-	/// Returns a copy of this inverse dose equivalent value in inverse kilo-roentgen equivalents
+	/// Returns a copy of this inverse dose equivalent rate value in hours per nanosievert
 	/// 
 	/// *Note: This method is not available for `f32` and other number types lacking the `From<f64>` trait*
-	pub fn to_per_krem(&self) -> T {
-		return self.per_Sv.clone() * T::from(10.0_f64);
+	pub fn to_h_per_nSv(&self) -> T {
+		return self.s_per_Sv.clone() * T::from(2.777777777777778e-13_f64);
 	}
 
-	/// Returns a new inverse dose equivalent value from the given number of inverse kilo-roentgen equivalents
+	/// Returns a new inverse dose equivalent rate value from the given number of hours per nanosievert
 	/// 
 	/// *Note: This method is not available for `f32` and other number types lacking the `From<f64>` trait*
 	///
 	/// # Arguments
-	/// * `per_krem` - Any number-like type, representing a quantity of inverse kilo-roentgen equivalents
-	pub fn from_per_krem(per_krem: T) -> Self {
-		InverseDoseEquivalent{per_Sv: per_krem * T::from(0.1_f64)}
+	/// * `h_per_nSv` - Any number-like type, representing a quantity of hours per nanosievert
+	pub fn from_h_per_nSv(h_per_nSv: T) -> Self {
+		InverseDoseEquivalentRate{s_per_Sv: h_per_nSv * T::from(3600000000000.0_f64)}
 	}
 
 }
@@ -1854,749 +12376,1186 @@ impl<T> InverseDoseEquivalent<T> where T: NumLike+From<f64> {
 
 /// Multiplying a unit value by a scalar value returns a unit value
 #[cfg(feature="num-bigfloat")]
-impl core::ops::Mul<InverseDoseEquivalent<num_bigfloat::BigFloat>> for num_bigfloat::BigFloat {
-	type Output = InverseDoseEquivalent<num_bigfloat::BigFloat>;
-	fn mul(self, rhs: InverseDoseEquivalent<num_bigfloat::BigFloat>) -> Self::Output {
-		InverseDoseEquivalent{per_Sv: self * rhs.per_Sv}
+impl core::ops::Mul<InverseDoseEquivalentRate<num_bigfloat::BigFloat>> for num_bigfloat::BigFloat {
+	type Output = InverseDoseEquivalentRate<num_bigfloat::BigFloat>;
+	fn mul(self, rhs: InverseDoseEquivalentRate<num_bigfloat::BigFloat>) -> Self::Output {
+		InverseDoseEquivalentRate{s_per_Sv: self * rhs.s_per_Sv}
 	}
 }
 /// Multiplying a unit value by a scalar value returns a unit value
 #[cfg(feature="num-bigfloat")]
-impl core::ops::Mul<InverseDoseEquivalent<num_bigfloat::BigFloat>> for &num_bigfloat::BigFloat {
-	type Output = InverseDoseEquivalent<num_bigfloat::BigFloat>;
-	fn mul(self, rhs: InverseDoseEquivalent<num_bigfloat::BigFloat>) -> Self::Output {
-		InverseDoseEquivalent{per_Sv: self.clone() * rhs.per_Sv}
+impl core::ops::Mul<InverseDoseEquivalentRate<num_bigfloat::BigFloat>> for &num_bigfloat::BigFloat {
+	type Output = InverseDoseEquivalentRate<num_bigfloat::BigFloat>;
+	fn mul(self, rhs: InverseDoseEquivalentRate<num_bigfloat::BigFloat>) -> Self::Output {
+		InverseDoseEquivalentRate{s_per_Sv: self.clone() * rhs.s_per_Sv}
 	}
 }
 /// Multiplying a unit value by a scalar value returns a unit value
 #[cfg(feature="num-bigfloat")]
-impl core::ops::Mul<&InverseDoseEquivalent<num_bigfloat::BigFloat>> for num_bigfloat::BigFloat {
-	type Output = InverseDoseEquivalent<num_bigfloat::BigFloat>;
-	fn mul(self, rhs: &InverseDoseEquivalent<num_bigfloat::BigFloat>) -> Self::Output {
-		InverseDoseEquivalent{per_Sv: self * rhs.per_Sv.clone()}
+impl core::ops::Mul<&InverseDoseEquivalentRate<num_bigfloat::BigFloat>> for num_bigfloat::BigFloat {
+	type Output = InverseDoseEquivalentRate<num_bigfloat::BigFloat>;
+	fn mul(self, rhs: &InverseDoseEquivalentRate<num_bigfloat::BigFloat>) -> Self::Output {
+		InverseDoseEquivalentRate{s_per_Sv: self * rhs.s_per_Sv.clone()}
 	}
 }
 /// Multiplying a unit value by a scalar value returns a unit value
 #[cfg(feature="num-bigfloat")]
-impl core::ops::Mul<&InverseDoseEquivalent<num_bigfloat::BigFloat>> for &num_bigfloat::BigFloat {
-	type Output = InverseDoseEquivalent<num_bigfloat::BigFloat>;
-	fn mul(self, rhs: &InverseDoseEquivalent<num_bigfloat::BigFloat>) -> Self::Output {
-		InverseDoseEquivalent{per_Sv: self.clone() * rhs.per_Sv.clone()}
+impl core::ops::Mul<&InverseDoseEquivalentRate<num_bigfloat::BigFloat>> for &num_bigfloat::BigFloat {
+	type Output = InverseDoseEquivalentRate<num_bigfloat::BigFloat>;
+	fn mul(self, rhs: &InverseDoseEquivalentRate<num_bigfloat::BigFloat>) -> Self::Output {
+		InverseDoseEquivalentRate{s_per_Sv: self.clone() * rhs.s_per_Sv.clone()}
 	}
 }
 
 /// Multiplying a unit value by a scalar value returns a unit value
 #[cfg(feature="num-complex")]
-impl core::ops::Mul<InverseDoseEquivalent<num_complex::Complex32>> for num_complex::Complex32 {
-	type Output = InverseDoseEquivalent<num_complex::Complex32>;
-	fn mul(self, rhs: InverseDoseEquivalent<num_complex::Complex32>) -> Self::Output {
-		InverseDoseEquivalent{per_Sv: self * rhs.per_Sv}
+impl core::ops::Mul<InverseDoseEquivalentRate<num_complex::Complex32>> for num_complex::Complex32 {
+	type Output = InverseDoseEquivalentRate<num_complex::Complex32>;
+	fn mul(self, rhs: InverseDoseEquivalentRate<num_complex::Complex32>) -> Self::Output {
+		InverseDoseEquivalentRate{s_per_Sv: self * rhs.s_per_Sv}
 	}
 }
 /// Multiplying a unit value by a scalar value returns a unit value
 #[cfg(feature="num-complex")]
-impl core::ops::Mul<InverseDoseEquivalent<num_complex::Complex32>> for &num_complex::Complex32 {
-	type Output = InverseDoseEquivalent<num_complex::Complex32>;
-	fn mul(self, rhs: InverseDoseEquivalent<num_complex::Complex32>) -> Self::Output {
-		InverseDoseEquivalent{per_Sv: self.clone() * rhs.per_Sv}
+impl core::ops::Mul<InverseDoseEquivalentRate<num_complex::Complex32>> for &num_complex::Complex32 {
+	type Output = InverseDoseEquivalentRate<num_complex::Complex32>;
+	fn mul(self, rhs: InverseDoseEquivalentRate<num_complex::Complex32>) -> Self::Output {
+		InverseDoseEquivalentRate{s_per_Sv: self.clone() * rhs.s_per_Sv}
 	}
 }
 /// Multiplying a unit value by a scalar value returns a unit value
 #[cfg(feature="num-complex")]
-impl core::ops::Mul<&InverseDoseEquivalent<num_complex::Complex32>> for num_complex::Complex32 {
-	type Output = InverseDoseEquivalent<num_complex::Complex32>;
-	fn mul(self, rhs: &InverseDoseEquivalent<num_complex::Complex32>) -> Self::Output {
-		InverseDoseEquivalent{per_Sv: self * rhs.per_Sv.clone()}
+impl core::ops::Mul<&InverseDoseEquivalentRate<num_complex::Complex32>> for num_complex::Complex32 {
+	type Output = InverseDoseEquivalentRate<num_complex::Complex32>;
+	fn mul(self, rhs: &InverseDoseEquivalentRate<num_complex::Complex32>) -> Self::Output {
+		InverseDoseEquivalentRate{s_per_Sv: self * rhs.s_per_Sv.clone()}
 	}
 }
 /// Multiplying a unit value by a scalar value returns a unit value
 #[cfg(feature="num-complex")]
-impl core::ops::Mul<&InverseDoseEquivalent<num_complex::Complex32>> for &num_complex::Complex32 {
-	type Output = InverseDoseEquivalent<num_complex::Complex32>;
-	fn mul(self, rhs: &InverseDoseEquivalent<num_complex::Complex32>) -> Self::Output {
-		InverseDoseEquivalent{per_Sv: self.clone() * rhs.per_Sv.clone()}
+impl core::ops::Mul<&InverseDoseEquivalentRate<num_complex::Complex32>> for &num_complex::Complex32 {
+	type Output = InverseDoseEquivalentRate<num_complex::Complex32>;
+	fn mul(self, rhs: &InverseDoseEquivalentRate<num_complex::Complex32>) -> Self::Output {
+		InverseDoseEquivalentRate{s_per_Sv: self.clone() * rhs.s_per_Sv.clone()}
 	}
 }
 
 /// Multiplying a unit value by a scalar value returns a unit value
 #[cfg(feature="num-complex")]
-impl core::ops::Mul<InverseDoseEquivalent<num_complex::Complex64>> for num_complex::Complex64 {
-	type Output = InverseDoseEquivalent<num_complex::Complex64>;
-	fn mul(self, rhs: InverseDoseEquivalent<num_complex::Complex64>) -> Self::Output {
-		InverseDoseEquivalent{per_Sv: self * rhs.per_Sv}
+impl core::ops::Mul<InverseDoseEquivalentRate<num_complex::Complex64>> for num_complex::Complex64 {
+	type Output = InverseDoseEquivalentRate<num_complex::Complex64>;
+	fn mul(self, rhs: InverseDoseEquivalentRate<num_complex::Complex64>) -> Self::Output {
+		InverseDoseEquivalentRate{s_per_Sv: self * rhs.s_per_Sv}
 	}
 }
 /// Multiplying a unit value by a scalar value returns a unit value
 #[cfg(feature="num-complex")]
-impl core::ops::Mul<InverseDoseEquivalent<num_complex::Complex64>> for &num_complex::Complex64 {
-	type Output = InverseDoseEquivalent<num_complex::Complex64>;
-	fn mul(self, rhs: InverseDoseEquivalent<num_complex::Complex64>) -> Self::Output {
-		InverseDoseEquivalent{per_Sv: self.clone() * rhs.per_Sv}
+impl core::ops::Mul<InverseDoseEquivalentRate<num_complex::Complex64>> for &num_complex::Complex64 {
+	type Output = InverseDoseEquivalentRate<num_complex::Complex64>;
+	fn mul(self, rhs: InverseDoseEquivalentRate<num_complex::Complex64>) -> Self::Output {
+		InverseDoseEquivalentRate{s_per_Sv: self.clone() * rhs.s_per_Sv}
 	}
 }
 /// Multiplying a unit value by a scalar value returns a unit value
 #[cfg(feature="num-complex")]
-impl core::ops::Mul<&InverseDoseEquivalent<num_complex::Complex64>> for num_complex::Complex64 {
-	type Output = InverseDoseEquivalent<num_complex::Complex64>;
-	fn mul(self, rhs: &InverseDoseEquivalent<num_complex::Complex64>) -> Self::Output {
-		InverseDoseEquivalent{per_Sv: self * rhs.per_Sv.clone()}
+impl core::ops::Mul<&InverseDoseEquivalentRate<num_complex::Complex64>> for num_complex::Complex64 {
+	type Output = InverseDoseEquivalentRate<num_complex::Complex64>;
+	fn mul(self, rhs: &InverseDoseEquivalentRate<num_complex::Complex64>) -> Self::Output {
+		InverseDoseEquivalentRate{s_per_Sv: self * rhs.s_per_Sv.clone()}
 	}
 }
 /// Multiplying a unit value by a scalar value returns a unit value
 #[cfg(feature="num-complex")]
-impl core::ops::Mul<&InverseDoseEquivalent<num_complex::Complex64>> for &num_complex::Complex64 {
-	type Output = InverseDoseEquivalent<num_complex::Complex64>;
-	fn mul(self, rhs: &InverseDoseEquivalent<num_complex::Complex64>) -> Self::Output {
-		InverseDoseEquivalent{per_Sv: self.clone() * rhs.per_Sv.clone()}
+impl core::ops::Mul<&InverseDoseEquivalentRate<num_complex::Complex64>> for &num_complex::Complex64 {
+	type Output = InverseDoseEquivalentRate<num_complex::Complex64>;
+	fn mul(self, rhs: &InverseDoseEquivalentRate<num_complex::Complex64>) -> Self::Output {
+		InverseDoseEquivalentRate{s_per_Sv: self.clone() * rhs.s_per_Sv.clone()}
+	}
+}
+
+/// Multiplying a unit value by a scalar value returns a unit value
+#[cfg(feature="num-rational")]
+impl core::ops::Mul<InverseDoseEquivalentRate<num_rational::Rational64>> for num_rational::Rational64 {
+	type Output = InverseDoseEquivalentRate<num_rational::Rational64>;
+	fn mul(self, rhs: InverseDoseEquivalentRate<num_rational::Rational64>) -> Self::Output {
+		InverseDoseEquivalentRate{s_per_Sv: self * rhs.s_per_Sv}
+	}
+}
+/// Multiplying a unit value by a scalar value returns a unit value
+#[cfg(feature="num-rational")]
+impl core::ops::Mul<InverseDoseEquivalentRate<num_rational::Rational64>> for &num_rational::Rational64 {
+	type Output = InverseDoseEquivalentRate<num_rational::Rational64>;
+	fn mul(self, rhs: InverseDoseEquivalentRate<num_rational::Rational64>) -> Self::Output {
+		InverseDoseEquivalentRate{s_per_Sv: self.clone() * rhs.s_per_Sv}
+	}
+}
+/// Multiplying a unit value by a scalar value returns a unit value
+#[cfg(feature="num-rational")]
+impl core::ops::Mul<&InverseDoseEquivalentRate<num_rational::Rational64>> for num_rational::Rational64 {
+	type Output = InverseDoseEquivalentRate<num_rational::Rational64>;
+	fn mul(self, rhs: &InverseDoseEquivalentRate<num_rational::Rational64>) -> Self::Output {
+		InverseDoseEquivalentRate{s_per_Sv: self * rhs.s_per_Sv.clone()}
+	}
+}
+/// Multiplying a unit value by a scalar value returns a unit value
+#[cfg(feature="num-rational")]
+impl core::ops::Mul<&InverseDoseEquivalentRate<num_rational::Rational64>> for &num_rational::Rational64 {
+	type Output = InverseDoseEquivalentRate<num_rational::Rational64>;
+	fn mul(self, rhs: &InverseDoseEquivalentRate<num_rational::Rational64>) -> Self::Output {
+		InverseDoseEquivalentRate{s_per_Sv: self.clone() * rhs.s_per_Sv.clone()}
+	}
+}
+
+/// Multiplying a unit value by a scalar value returns a unit value
+#[cfg(feature="num-rational")]
+impl core::ops::Mul<InverseDoseEquivalentRate<num_rational::BigRational>> for num_rational::BigRational {
+	type Output = InverseDoseEquivalentRate<num_rational::BigRational>;
+	fn mul(self, rhs: InverseDoseEquivalentRate<num_rational::BigRational>) -> Self::Output {
+		InverseDoseEquivalentRate{s_per_Sv: self * rhs.s_per_Sv}
+	}
+}
+/// Multiplying a unit value by a scalar value returns a unit value
+#[cfg(feature="num-rational")]
+impl core::ops::Mul<InverseDoseEquivalentRate<num_rational::BigRational>> for &num_rational::BigRational {
+	type Output = InverseDoseEquivalentRate<num_rational::BigRational>;
+	fn mul(self, rhs: InverseDoseEquivalentRate<num_rational::BigRational>) -> Self::Output {
+		InverseDoseEquivalentRate{s_per_Sv: self.clone() * rhs.s_per_Sv}
+	}
+}
+/// Multiplying a unit value by a scalar value returns a unit value
+#[cfg(feature="num-rational")]
+impl core::ops::Mul<&InverseDoseEquivalentRate<num_rational::BigRational>> for num_rational::BigRational {
+	type Output = InverseDoseEquivalentRate<num_rational::BigRational>;
+	fn mul(self, rhs: &InverseDoseEquivalentRate<num_rational::BigRational>) -> Self::Output {
+		InverseDoseEquivalentRate{s_per_Sv: self * rhs.s_per_Sv.clone()}
+	}
+}
+/// Multiplying a unit value by a scalar value returns a unit value
+#[cfg(feature="num-rational")]
+impl core::ops::Mul<&InverseDoseEquivalentRate<num_rational::BigRational>> for &num_rational::BigRational {
+	type Output = InverseDoseEquivalentRate<num_rational::BigRational>;
+	fn mul(self, rhs: &InverseDoseEquivalentRate<num_rational::BigRational>) -> Self::Output {
+		InverseDoseEquivalentRate{s_per_Sv: self.clone() * rhs.s_per_Sv.clone()}
 	}
 }
 
 
 
 
-// InverseDoseEquivalent * Distance -> InverseAcceleration
-/// Multiplying a InverseDoseEquivalent by a Distance returns a value of type InverseAcceleration
-impl<T> core::ops::Mul<Distance<T>> for InverseDoseEquivalent<T> where T: NumLike {
-	type Output = InverseAcceleration<T>;
-	fn mul(self, rhs: Distance<T>) -> Self::Output {
-		InverseAcceleration{s2pm: self.per_Sv * rhs.m}
+
+/// Multiplying a InverseDoseEquivalentRate by an [nalgebra](https://crates.io/crates/nalgebra) 
+/// vector or matrix of unitless values returns a vector or matrix of InverseDoseEquivalentRate 
+/// values (eg a direction vector multiplied by a InverseDoseEquivalentRate)
+#[cfg(feature="nalgebra")]
+impl<T, R, C, S> core::ops::Mul<nalgebra::Matrix<T, R, C, S>> for InverseDoseEquivalentRate<T> where 
+	T: NumLike+nalgebra::Scalar, R: nalgebra::Dim, C: nalgebra::Dim, S: nalgebra::RawStorage<T, R, C>, 
+	nalgebra::DefaultAllocator: nalgebra::allocator::Allocator<R, C> {
+	type Output = nalgebra::OMatrix<InverseDoseEquivalentRate<T>, R, C>;
+	fn mul(self, rhs: nalgebra::Matrix<T, R, C, S>) -> Self::Output {
+		rhs.map(|x| InverseDoseEquivalentRate{s_per_Sv: self.s_per_Sv.clone() * x})
 	}
-}
-/// Multiplying a InverseDoseEquivalent by a Distance returns a value of type InverseAcceleration
-impl<T> core::ops::Mul<Distance<T>> for &InverseDoseEquivalent<T> where T: NumLike {
-	type Output = InverseAcceleration<T>;
-	fn mul(self, rhs: Distance<T>) -> Self::Output {
-		InverseAcceleration{s2pm: self.per_Sv.clone() * rhs.m}
+}
+/// Multiplying an [nalgebra](https://crates.io/crates/nalgebra) vector or matrix of 
+/// unitless values by a InverseDoseEquivalentRate returns a vector or matrix of InverseDoseEquivalentRate values
+#[cfg(feature="nalgebra")]
+impl<T, R, C, S> core::ops::Mul<InverseDoseEquivalentRate<T>> for nalgebra::Matrix<T, R, C, S> where 
+	T: NumLike+nalgebra::Scalar, R: nalgebra::Dim, C: nalgebra::Dim, S: nalgebra::RawStorage<T, R, C>, 
+	nalgebra::DefaultAllocator: nalgebra::allocator::Allocator<R, C> {
+	type Output = nalgebra::OMatrix<InverseDoseEquivalentRate<T>, R, C>;
+	fn mul(self, rhs: InverseDoseEquivalentRate<T>) -> Self::Output {
+		self.map(|x| InverseDoseEquivalentRate{s_per_Sv: x * rhs.s_per_Sv.clone()})
 	}
 }
-/// Multiplying a InverseDoseEquivalent by a Distance returns a value of type InverseAcceleration
-impl<T> core::ops::Mul<&Distance<T>> for InverseDoseEquivalent<T> where T: NumLike {
-	type Output = InverseAcceleration<T>;
-	fn mul(self, rhs: &Distance<T>) -> Self::Output {
-		InverseAcceleration{s2pm: self.per_Sv * rhs.m.clone()}
+/// Dividing an [nalgebra](https://crates.io/crates/nalgebra) vector or matrix of 
+/// InverseDoseEquivalentRate values by a InverseDoseEquivalentRate returns a vector or matrix of unitless values
+#[cfg(feature="nalgebra")]
+impl<T, R, C, S> core::ops::Div<InverseDoseEquivalentRate<T>> for nalgebra::Matrix<InverseDoseEquivalentRate<T>, R, C, S> where 
+	T: NumLike+nalgebra::Scalar, R: nalgebra::Dim, C: nalgebra::Dim, S: nalgebra::RawStorage<InverseDoseEquivalentRate<T>, R, C>, 
+	nalgebra::DefaultAllocator: nalgebra::allocator::Allocator<R, C> {
+	type Output = nalgebra::OMatrix<T, R, C>;
+	fn div(self, rhs: InverseDoseEquivalentRate<T>) -> Self::Output {
+		self.map(|x| x.s_per_Sv / rhs.s_per_Sv.clone())
 	}
 }
-/// Multiplying a InverseDoseEquivalent by a Distance returns a value of type InverseAcceleration
-impl<T> core::ops::Mul<&Distance<T>> for &InverseDoseEquivalent<T> where T: NumLike {
-	type Output = InverseAcceleration<T>;
-	fn mul(self, rhs: &Distance<T>) -> Self::Output {
-		InverseAcceleration{s2pm: self.per_Sv.clone() * rhs.m.clone()}
+
+
+
+impl<T> InverseDoseEquivalentRate<T> where T: NumLike+From<u8> {
+	/// Returns the reciprocal of this InverseDoseEquivalentRate value (ie 1 divided by 
+	/// this value) as a value of type DoseEquivalentRate
+	pub fn recip(self) -> DoseEquivalentRate<T> {
+		DoseEquivalentRate{Sv_per_s: T::from(1u8) / self.s_per_Sv}
 	}
 }
 
-// InverseDoseEquivalent / InverseDistance -> InverseAcceleration
-/// Dividing a InverseDoseEquivalent by a InverseDistance returns a value of type InverseAcceleration
-impl<T> core::ops::Div<InverseDistance<T>> for InverseDoseEquivalent<T> where T: NumLike {
-	type Output = InverseAcceleration<T>;
-	fn div(self, rhs: InverseDistance<T>) -> Self::Output {
-		InverseAcceleration{s2pm: self.per_Sv / rhs.per_m}
+// 1/InverseDoseEquivalentRate -> DoseEquivalentRate
+/// Dividing a scalar value by a InverseDoseEquivalentRate unit value returns a value of type DoseEquivalentRate
+impl<T> core::ops::Div<InverseDoseEquivalentRate<T>> for f64 where T: NumLike+From<f64> {
+	type Output = DoseEquivalentRate<T>;
+	fn div(self, rhs: InverseDoseEquivalentRate<T>) -> Self::Output {
+		DoseEquivalentRate{Sv_per_s: T::from(self) / rhs.s_per_Sv}
 	}
 }
-/// Dividing a InverseDoseEquivalent by a InverseDistance returns a value of type InverseAcceleration
-impl<T> core::ops::Div<InverseDistance<T>> for &InverseDoseEquivalent<T> where T: NumLike {
-	type Output = InverseAcceleration<T>;
-	fn div(self, rhs: InverseDistance<T>) -> Self::Output {
-		InverseAcceleration{s2pm: self.per_Sv.clone() / rhs.per_m}
+/// Dividing a scalar value by a InverseDoseEquivalentRate unit value returns a value of type DoseEquivalentRate
+impl<T> core::ops::Div<InverseDoseEquivalentRate<T>> for &f64 where T: NumLike+From<f64> {
+	type Output = DoseEquivalentRate<T>;
+	fn div(self, rhs: InverseDoseEquivalentRate<T>) -> Self::Output {
+		DoseEquivalentRate{Sv_per_s: T::from(self.clone()) / rhs.s_per_Sv}
 	}
 }
-/// Dividing a InverseDoseEquivalent by a InverseDistance returns a value of type InverseAcceleration
-impl<T> core::ops::Div<&InverseDistance<T>> for InverseDoseEquivalent<T> where T: NumLike {
-	type Output = InverseAcceleration<T>;
-	fn div(self, rhs: &InverseDistance<T>) -> Self::Output {
-		InverseAcceleration{s2pm: self.per_Sv / rhs.per_m.clone()}
+/// Dividing a scalar value by a InverseDoseEquivalentRate unit value returns a value of type DoseEquivalentRate
+impl<T> core::ops::Div<&InverseDoseEquivalentRate<T>> for f64 where T: NumLike+From<f64> {
+	type Output = DoseEquivalentRate<T>;
+	fn div(self, rhs: &InverseDoseEquivalentRate<T>) -> Self::Output {
+		DoseEquivalentRate{Sv_per_s: T::from(self) / rhs.s_per_Sv.clone()}
 	}
 }
-/// Dividing a InverseDoseEquivalent by a InverseDistance returns a value of type InverseAcceleration
-impl<T> core::ops::Div<&InverseDistance<T>> for &InverseDoseEquivalent<T> where T: NumLike {
-	type Output = InverseAcceleration<T>;
-	fn div(self, rhs: &InverseDistance<T>) -> Self::Output {
-		InverseAcceleration{s2pm: self.per_Sv.clone() / rhs.per_m.clone()}
+/// Dividing a scalar value by a InverseDoseEquivalentRate unit value returns a value of type DoseEquivalentRate
+impl<T> core::ops::Div<&InverseDoseEquivalentRate<T>> for &f64 where T: NumLike+From<f64> {
+	type Output = DoseEquivalentRate<T>;
+	fn div(self, rhs: &InverseDoseEquivalentRate<T>) -> Self::Output {
+		DoseEquivalentRate{Sv_per_s: T::from(self.clone()) / rhs.s_per_Sv.clone()}
 	}
 }
 
-// InverseDoseEquivalent * InverseMass -> InverseEnergy
-/// Multiplying a InverseDoseEquivalent by a InverseMass returns a value of type InverseEnergy
-impl<T> core::ops::Mul<InverseMass<T>> for InverseDoseEquivalent<T> where T: NumLike {
-	type Output = InverseEnergy<T>;
-	fn mul(self, rhs: InverseMass<T>) -> Self::Output {
-		InverseEnergy{per_J: self.per_Sv * rhs.per_kg}
+// 1/InverseDoseEquivalentRate -> DoseEquivalentRate
+/// Dividing a scalar value by a InverseDoseEquivalentRate unit value returns a value of type DoseEquivalentRate
+impl<T> core::ops::Div<InverseDoseEquivalentRate<T>> for f32 where T: NumLike+From<f32> {
+	type Output = DoseEquivalentRate<T>;
+	fn div(self, rhs: InverseDoseEquivalentRate<T>) -> Self::Output {
+		DoseEquivalentRate{Sv_per_s: T::from(self) / rhs.s_per_Sv}
 	}
 }
-/// Multiplying a InverseDoseEquivalent by a InverseMass returns a value of type InverseEnergy
-impl<T> core::ops::Mul<InverseMass<T>> for &InverseDoseEquivalent<T> where T: NumLike {
-	type Output = InverseEnergy<T>;
-	fn mul(self, rhs: InverseMass<T>) -> Self::Output {
-		InverseEnergy{per_J: self.per_Sv.clone() * rhs.per_kg}
+/// Dividing a scalar value by a InverseDoseEquivalentRate unit value returns a value of type DoseEquivalentRate
+impl<T> core::ops::Div<InverseDoseEquivalentRate<T>> for &f32 where T: NumLike+From<f32> {
+	type Output = DoseEquivalentRate<T>;
+	fn div(self, rhs: InverseDoseEquivalentRate<T>) -> Self::Output {
+		DoseEquivalentRate{Sv_per_s: T::from(self.clone()) / rhs.s_per_Sv}
 	}
 }
-/// Multiplying a InverseDoseEquivalent by a InverseMass returns a value of type InverseEnergy
-impl<T> core::ops::Mul<&InverseMass<T>> for InverseDoseEquivalent<T> where T: NumLike {
-	type Output = InverseEnergy<T>;
-	fn mul(self, rhs: &InverseMass<T>) -> Self::Output {
-		InverseEnergy{per_J: self.per_Sv * rhs.per_kg.clone()}
+/// Dividing a scalar value by a InverseDoseEquivalentRate unit value returns a value of type DoseEquivalentRate
+impl<T> core::ops::Div<&InverseDoseEquivalentRate<T>> for f32 where T: NumLike+From<f32> {
+	type Output = DoseEquivalentRate<T>;
+	fn div(self, rhs: &InverseDoseEquivalentRate<T>) -> Self::Output {
+		DoseEquivalentRate{Sv_per_s: T::from(self) / rhs.s_per_Sv.clone()}
 	}
 }
-/// Multiplying a InverseDoseEquivalent by a InverseMass returns a value of type InverseEnergy
-impl<T> core::ops::Mul<&InverseMass<T>> for &InverseDoseEquivalent<T> where T: NumLike {
-	type Output = InverseEnergy<T>;
-	fn mul(self, rhs: &InverseMass<T>) -> Self::Output {
-		InverseEnergy{per_J: self.per_Sv.clone() * rhs.per_kg.clone()}
+/// Dividing a scalar value by a InverseDoseEquivalentRate unit value returns a value of type DoseEquivalentRate
+impl<T> core::ops::Div<&InverseDoseEquivalentRate<T>> for &f32 where T: NumLike+From<f32> {
+	type Output = DoseEquivalentRate<T>;
+	fn div(self, rhs: &InverseDoseEquivalentRate<T>) -> Self::Output {
+		DoseEquivalentRate{Sv_per_s: T::from(self.clone()) / rhs.s_per_Sv.clone()}
 	}
 }
 
-// InverseDoseEquivalent / InverseTemperature -> InverseSpecificHeatCapacity
-/// Dividing a InverseDoseEquivalent by a InverseTemperature returns a value of type InverseSpecificHeatCapacity
-impl<T> core::ops::Div<InverseTemperature<T>> for InverseDoseEquivalent<T> where T: NumLike {
-	type Output = InverseSpecificHeatCapacity<T>;
-	fn div(self, rhs: InverseTemperature<T>) -> Self::Output {
-		InverseSpecificHeatCapacity{kgK_per_J: self.per_Sv / rhs.per_K}
+// 1/InverseDoseEquivalentRate -> DoseEquivalentRate
+/// Dividing a scalar value by a InverseDoseEquivalentRate unit value returns a value of type DoseEquivalentRate
+impl<T> core::ops::Div<InverseDoseEquivalentRate<T>> for i64 where T: NumLike+From<i64> {
+	type Output = DoseEquivalentRate<T>;
+	fn div(self, rhs: InverseDoseEquivalentRate<T>) -> Self::Output {
+		DoseEquivalentRate{Sv_per_s: T::from(self) / rhs.s_per_Sv}
 	}
 }
-/// Dividing a InverseDoseEquivalent by a InverseTemperature returns a value of type InverseSpecificHeatCapacity
-impl<T> core::ops::Div<InverseTemperature<T>> for &InverseDoseEquivalent<T> where T: NumLike {
-	type Output = InverseSpecificHeatCapacity<T>;
-	fn div(self, rhs: InverseTemperature<T>) -> Self::Output {
-		InverseSpecificHeatCapacity{kgK_per_J: self.per_Sv.clone() / rhs.per_K}
+/// Dividing a scalar value by a InverseDoseEquivalentRate unit value returns a value of type DoseEquivalentRate
+impl<T> core::ops::Div<InverseDoseEquivalentRate<T>> for &i64 where T: NumLike+From<i64> {
+	type Output = DoseEquivalentRate<T>;
+	fn div(self, rhs: InverseDoseEquivalentRate<T>) -> Self::Output {
+		DoseEquivalentRate{Sv_per_s: T::from(self.clone()) / rhs.s_per_Sv}
 	}
 }
-/// Dividing a InverseDoseEquivalent by a InverseTemperature returns a value of type InverseSpecificHeatCapacity
-impl<T> core::ops::Div<&InverseTemperature<T>> for InverseDoseEquivalent<T> where T: NumLike {
-	type Output = InverseSpecificHeatCapacity<T>;
-	fn div(self, rhs: &InverseTemperature<T>) -> Self::Output {
-		InverseSpecificHeatCapacity{kgK_per_J: self.per_Sv / rhs.per_K.clone()}
+/// Dividing a scalar value by a InverseDoseEquivalentRate unit value returns a value of type DoseEquivalentRate
+impl<T> core::ops::Div<&InverseDoseEquivalentRate<T>> for i64 where T: NumLike+From<i64> {
+	type Output = DoseEquivalentRate<T>;
+	fn div(self, rhs: &InverseDoseEquivalentRate<T>) -> Self::Output {
+		DoseEquivalentRate{Sv_per_s: T::from(self) / rhs.s_per_Sv.clone()}
 	}
 }
-/// Dividing a InverseDoseEquivalent by a InverseTemperature returns a value of type InverseSpecificHeatCapacity
-impl<T> core::ops::Div<&InverseTemperature<T>> for &InverseDoseEquivalent<T> where T: NumLike {
-	type Output = InverseSpecificHeatCapacity<T>;
-	fn div(self, rhs: &InverseTemperature<T>) -> Self::Output {
-		InverseSpecificHeatCapacity{kgK_per_J: self.per_Sv.clone() / rhs.per_K.clone()}
+/// Dividing a scalar value by a InverseDoseEquivalentRate unit value returns a value of type DoseEquivalentRate
+impl<T> core::ops::Div<&InverseDoseEquivalentRate<T>> for &i64 where T: NumLike+From<i64> {
+	type Output = DoseEquivalentRate<T>;
+	fn div(self, rhs: &InverseDoseEquivalentRate<T>) -> Self::Output {
+		DoseEquivalentRate{Sv_per_s: T::from(self.clone()) / rhs.s_per_Sv.clone()}
 	}
 }
 
-// InverseDoseEquivalent / Mass -> InverseEnergy
-/// Dividing a InverseDoseEquivalent by a Mass returns a value of type InverseEnergy
-impl<T> core::ops::Div<Mass<T>> for InverseDoseEquivalent<T> where T: NumLike {
-	type Output = InverseEnergy<T>;
-	fn div(self, rhs: Mass<T>) -> Self::Output {
-		InverseEnergy{per_J: self.per_Sv / rhs.kg}
+// 1/InverseDoseEquivalentRate -> DoseEquivalentRate
+/// Dividing a scalar value by a InverseDoseEquivalentRate unit value returns a value of type DoseEquivalentRate
+impl<T> core::ops::Div<InverseDoseEquivalentRate<T>> for i32 where T: NumLike+From<i32> {
+	type Output = DoseEquivalentRate<T>;
+	fn div(self, rhs: InverseDoseEquivalentRate<T>) -> Self::Output {
+		DoseEquivalentRate{Sv_per_s: T::from(self) / rhs.s_per_Sv}
 	}
 }
-/// Dividing a InverseDoseEquivalent by a Mass returns a value of type InverseEnergy
-impl<T> core::ops::Div<Mass<T>> for &InverseDoseEquivalent<T> where T: NumLike {
-	type Output = InverseEnergy<T>;
-	fn div(self, rhs: Mass<T>) -> Self::Output {
-		InverseEnergy{per_J: self.per_Sv.clone() / rhs.kg}
+/// Dividing a scalar value by a InverseDoseEquivalentRate unit value returns a value of type DoseEquivalentRate
+impl<T> core::ops::Div<InverseDoseEquivalentRate<T>> for &i32 where T: NumLike+From<i32> {
+	type Output = DoseEquivalentRate<T>;
+	fn div(self, rhs: InverseDoseEquivalentRate<T>) -> Self::Output {
+		DoseEquivalentRate{Sv_per_s: T::from(self.clone()) / rhs.s_per_Sv}
 	}
 }
-/// Dividing a InverseDoseEquivalent by a Mass returns a value of type InverseEnergy
-impl<T> core::ops::Div<&Mass<T>> for InverseDoseEquivalent<T> where T: NumLike {
-	type Output = InverseEnergy<T>;
-	fn div(self, rhs: &Mass<T>) -> Self::Output {
-		InverseEnergy{per_J: self.per_Sv / rhs.kg.clone()}
+/// Dividing a scalar value by a InverseDoseEquivalentRate unit value returns a value of type DoseEquivalentRate
+impl<T> core::ops::Div<&InverseDoseEquivalentRate<T>> for i32 where T: NumLike+From<i32> {
+	type Output = DoseEquivalentRate<T>;
+	fn div(self, rhs: &InverseDoseEquivalentRate<T>) -> Self::Output {
+		DoseEquivalentRate{Sv_per_s: T::from(self) / rhs.s_per_Sv.clone()}
 	}
 }
-/// Dividing a InverseDoseEquivalent by a Mass returns a value of type InverseEnergy
-impl<T> core::ops::Div<&Mass<T>> for &InverseDoseEquivalent<T> where T: NumLike {
-	type Output = InverseEnergy<T>;
-	fn div(self, rhs: &Mass<T>) -> Self::Output {
-		InverseEnergy{per_J: self.per_Sv.clone() / rhs.kg.clone()}
+/// Dividing a scalar value by a InverseDoseEquivalentRate unit value returns a value of type DoseEquivalentRate
+impl<T> core::ops::Div<&InverseDoseEquivalentRate<T>> for &i32 where T: NumLike+From<i32> {
+	type Output = DoseEquivalentRate<T>;
+	fn div(self, rhs: &InverseDoseEquivalentRate<T>) -> Self::Output {
+		DoseEquivalentRate{Sv_per_s: T::from(self.clone()) / rhs.s_per_Sv.clone()}
 	}
 }
 
-// InverseDoseEquivalent * Temperature -> InverseSpecificHeatCapacity
-/// Multiplying a InverseDoseEquivalent by a Temperature returns a value of type InverseSpecificHeatCapacity
-impl<T> core::ops::Mul<Temperature<T>> for InverseDoseEquivalent<T> where T: NumLike {
-	type Output = InverseSpecificHeatCapacity<T>;
-	fn mul(self, rhs: Temperature<T>) -> Self::Output {
-		InverseSpecificHeatCapacity{kgK_per_J: self.per_Sv * rhs.K}
+// 1/InverseDoseEquivalentRate -> DoseEquivalentRate
+/// Dividing a scalar value by a InverseDoseEquivalentRate unit value returns a value of type DoseEquivalentRate
+#[cfg(feature="num-bigfloat")]
+impl<T> core::ops::Div<InverseDoseEquivalentRate<T>> for num_bigfloat::BigFloat where T: NumLike+From<num_bigfloat::BigFloat> {
+	type Output = DoseEquivalentRate<T>;
+	fn div(self, rhs: InverseDoseEquivalentRate<T>) -> Self::Output {
+		DoseEquivalentRate{Sv_per_s: T::from(self) / rhs.s_per_Sv}
 	}
 }
-/// Multiplying a InverseDoseEquivalent by a Temperature returns a value of type InverseSpecificHeatCapacity
-impl<T> core::ops::Mul<Temperature<T>> for &InverseDoseEquivalent<T> where T: NumLike {
-	type Output = InverseSpecificHeatCapacity<T>;
-	fn mul(self, rhs: Temperature<T>) -> Self::Output {
-		InverseSpecificHeatCapacity{kgK_per_J: self.per_Sv.clone() * rhs.K}
+/// Dividing a scalar value by a InverseDoseEquivalentRate unit value returns a value of type DoseEquivalentRate
+#[cfg(feature="num-bigfloat")]
+impl<T> core::ops::Div<InverseDoseEquivalentRate<T>> for &num_bigfloat::BigFloat where T: NumLike+From<num_bigfloat::BigFloat> {
+	type Output = DoseEquivalentRate<T>;
+	fn div(self, rhs: InverseDoseEquivalentRate<T>) -> Self::Output {
+		DoseEquivalentRate{Sv_per_s: T::from(self.clone()) / rhs.s_per_Sv}
 	}
 }
-/// Multiplying a InverseDoseEquivalent by a Temperature returns a value of type InverseSpecificHeatCapacity
-impl<T> core::ops::Mul<&Temperature<T>> for InverseDoseEquivalent<T> where T: NumLike {
-	type Output = InverseSpecificHeatCapacity<T>;
-	fn mul(self, rhs: &Temperature<T>) -> Self::Output {
-		InverseSpecificHeatCapacity{kgK_per_J: self.per_Sv * rhs.K.clone()}
+/// Dividing a scalar value by a InverseDoseEquivalentRate unit value returns a value of type DoseEquivalentRate
+#[cfg(feature="num-bigfloat")]
+impl<T> core::ops::Div<&InverseDoseEquivalentRate<T>> for num_bigfloat::BigFloat where T: NumLike+From<num_bigfloat::BigFloat> {
+	type Output = DoseEquivalentRate<T>;
+	fn div(self, rhs: &InverseDoseEquivalentRate<T>) -> Self::Output {
+		DoseEquivalentRate{Sv_per_s: T::from(self) / rhs.s_per_Sv.clone()}
 	}
 }
-/// Multiplying a InverseDoseEquivalent by a Temperature returns a value of type InverseSpecificHeatCapacity
-impl<T> core::ops::Mul<&Temperature<T>> for &InverseDoseEquivalent<T> where T: NumLike {
-	type Output = InverseSpecificHeatCapacity<T>;
-	fn mul(self, rhs: &Temperature<T>) -> Self::Output {
-		InverseSpecificHeatCapacity{kgK_per_J: self.per_Sv.clone() * rhs.K.clone()}
+/// Dividing a scalar value by a InverseDoseEquivalentRate unit value returns a value of type DoseEquivalentRate
+#[cfg(feature="num-bigfloat")]
+impl<T> core::ops::Div<&InverseDoseEquivalentRate<T>> for &num_bigfloat::BigFloat where T: NumLike+From<num_bigfloat::BigFloat> {
+	type Output = DoseEquivalentRate<T>;
+	fn div(self, rhs: &InverseDoseEquivalentRate<T>) -> Self::Output {
+		DoseEquivalentRate{Sv_per_s: T::from(self.clone()) / rhs.s_per_Sv.clone()}
 	}
 }
 
-// InverseDoseEquivalent / InverseSpecificHeatCapacity -> InverseTemperature
-/// Dividing a InverseDoseEquivalent by a InverseSpecificHeatCapacity returns a value of type InverseTemperature
-impl<T> core::ops::Div<InverseSpecificHeatCapacity<T>> for InverseDoseEquivalent<T> where T: NumLike {
-	type Output = InverseTemperature<T>;
-	fn div(self, rhs: InverseSpecificHeatCapacity<T>) -> Self::Output {
-		InverseTemperature{per_K: self.per_Sv / rhs.kgK_per_J}
+// 1/InverseDoseEquivalentRate -> DoseEquivalentRate
+/// Dividing a scalar value by a InverseDoseEquivalentRate unit value returns a value of type DoseEquivalentRate
+#[cfg(feature="num-complex")]
+impl<T> core::ops::Div<InverseDoseEquivalentRate<T>> for num_complex::Complex32 where T: NumLike+From<num_complex::Complex32> {
+	type Output = DoseEquivalentRate<T>;
+	fn div(self, rhs: InverseDoseEquivalentRate<T>) -> Self::Output {
+		DoseEquivalentRate{Sv_per_s: T::from(self) / rhs.s_per_Sv}
 	}
 }
-/// Dividing a InverseDoseEquivalent by a InverseSpecificHeatCapacity returns a value of type InverseTemperature
-impl<T> core::ops::Div<InverseSpecificHeatCapacity<T>> for &InverseDoseEquivalent<T> where T: NumLike {
-	type Output = InverseTemperature<T>;
-	fn div(self, rhs: InverseSpecificHeatCapacity<T>) -> Self::Output {
-		InverseTemperature{per_K: self.per_Sv.clone() / rhs.kgK_per_J}
+/// Dividing a scalar value by a InverseDoseEquivalentRate unit value returns a value of type DoseEquivalentRate
+#[cfg(feature="num-complex")]
+impl<T> core::ops::Div<InverseDoseEquivalentRate<T>> for &num_complex::Complex32 where T: NumLike+From<num_complex::Complex32> {
+	type Output = DoseEquivalentRate<T>;
+	fn div(self, rhs: InverseDoseEquivalentRate<T>) -> Self::Output {
+		DoseEquivalentRate{Sv_per_s: T::from(self.clone()) / rhs.s_per_Sv}
 	}
 }
-/// Dividing a InverseDoseEquivalent by a InverseSpecificHeatCapacity returns a value of type InverseTemperature
-impl<T> core::ops::Div<&InverseSpecificHeatCapacity<T>> for InverseDoseEquivalent<T> where T: NumLike {
-	type Output = InverseTemperature<T>;
-	fn div(self, rhs: &InverseSpecificHeatCapacity<T>) -> Self::Output {
-		InverseTemperature{per_K: self.per_Sv / rhs.kgK_per_J.clone()}
+/// Dividing a scalar value by a InverseDoseEquivalentRate unit value returns a value of type DoseEquivalentRate
+#[cfg(feature="num-complex")]
+impl<T> core::ops::Div<&InverseDoseEquivalentRate<T>> for num_complex::Complex32 where T: NumLike+From<num_complex::Complex32> {
+	type Output = DoseEquivalentRate<T>;
+	fn div(self, rhs: &InverseDoseEquivalentRate<T>) -> Self::Output {
+		DoseEquivalentRate{Sv_per_s: T::from(self) / rhs.s_per_Sv.clone()}
 	}
 }
-/// Dividing a InverseDoseEquivalent by a InverseSpecificHeatCapacity returns a value of type InverseTemperature
-impl<T> core::ops::Div<&InverseSpecificHeatCapacity<T>> for &InverseDoseEquivalent<T> where T: NumLike {
-	type Output = InverseTemperature<T>;
-	fn div(self, rhs: &InverseSpecificHeatCapacity<T>) -> Self::Output {
-		InverseTemperature{per_K: self.per_Sv.clone() / rhs.kgK_per_J.clone()}
+/// Dividing a scalar value by a InverseDoseEquivalentRate unit value returns a value of type DoseEquivalentRate
+#[cfg(feature="num-complex")]
+impl<T> core::ops::Div<&InverseDoseEquivalentRate<T>> for &num_complex::Complex32 where T: NumLike+From<num_complex::Complex32> {
+	type Output = DoseEquivalentRate<T>;
+	fn div(self, rhs: &InverseDoseEquivalentRate<T>) -> Self::Output {
+		DoseEquivalentRate{Sv_per_s: T::from(self.clone()) / rhs.s_per_Sv.clone()}
 	}
 }
 
-// InverseDoseEquivalent * SpecificHeatCapacity -> InverseTemperature
-/// Multiplying a InverseDoseEquivalent by a SpecificHeatCapacity returns a value of type InverseTemperature
-impl<T> core::ops::Mul<SpecificHeatCapacity<T>> for InverseDoseEquivalent<T> where T: NumLike {
-	type Output = InverseTemperature<T>;
-	fn mul(self, rhs: SpecificHeatCapacity<T>) -> Self::Output {
-		InverseTemperature{per_K: self.per_Sv * rhs.J_per_kgK}
+// 1/InverseDoseEquivalentRate -> DoseEquivalentRate
+/// Dividing a scalar value by a InverseDoseEquivalentRate unit value returns a value of type DoseEquivalentRate
+#[cfg(feature="num-complex")]
+impl<T> core::ops::Div<InverseDoseEquivalentRate<T>> for num_complex::Complex64 where T: NumLike+From<num_complex::Complex64> {
+	type Output = DoseEquivalentRate<T>;
+	fn div(self, rhs: InverseDoseEquivalentRate<T>) -> Self::Output {
+		DoseEquivalentRate{Sv_per_s: T::from(self) / rhs.s_per_Sv}
 	}
 }
-/// Multiplying a InverseDoseEquivalent by a SpecificHeatCapacity returns a value of type InverseTemperature
-impl<T> core::ops::Mul<SpecificHeatCapacity<T>> for &InverseDoseEquivalent<T> where T: NumLike {
-	type Output = InverseTemperature<T>;
-	fn mul(self, rhs: SpecificHeatCapacity<T>) -> Self::Output {
-		InverseTemperature{per_K: self.per_Sv.clone() * rhs.J_per_kgK}
+/// Dividing a scalar value by a InverseDoseEquivalentRate unit value returns a value of type DoseEquivalentRate
+#[cfg(feature="num-complex")]
+impl<T> core::ops::Div<InverseDoseEquivalentRate<T>> for &num_complex::Complex64 where T: NumLike+From<num_complex::Complex64> {
+	type Output = DoseEquivalentRate<T>;
+	fn div(self, rhs: InverseDoseEquivalentRate<T>) -> Self::Output {
+		DoseEquivalentRate{Sv_per_s: T::from(self.clone()) / rhs.s_per_Sv}
 	}
 }
-/// Multiplying a InverseDoseEquivalent by a SpecificHeatCapacity returns a value of type InverseTemperature
-impl<T> core::ops::Mul<&SpecificHeatCapacity<T>> for InverseDoseEquivalent<T> where T: NumLike {
-	type Output = InverseTemperature<T>;
-	fn mul(self, rhs: &SpecificHeatCapacity<T>) -> Self::Output {
-		InverseTemperature{per_K: self.per_Sv * rhs.J_per_kgK.clone()}
+/// Dividing a scalar value by a InverseDoseEquivalentRate unit value returns a value of type DoseEquivalentRate
+#[cfg(feature="num-complex")]
+impl<T> core::ops::Div<&InverseDoseEquivalentRate<T>> for num_complex::Complex64 where T: NumLike+From<num_complex::Complex64> {
+	type Output = DoseEquivalentRate<T>;
+	fn div(self, rhs: &InverseDoseEquivalentRate<T>) -> Self::Output {
+		DoseEquivalentRate{Sv_per_s: T::from(self) / rhs.s_per_Sv.clone()}
 	}
 }
-/// Multiplying a InverseDoseEquivalent by a SpecificHeatCapacity returns a value of type InverseTemperature
-impl<T> core::ops::Mul<&SpecificHeatCapacity<T>> for &InverseDoseEquivalent<T> where T: NumLike {
-	type Output = InverseTemperature<T>;
-	fn mul(self, rhs: &SpecificHeatCapacity<T>) -> Self::Output {
-		InverseTemperature{per_K: self.per_Sv.clone() * rhs.J_per_kgK.clone()}
+/// Dividing a scalar value by a InverseDoseEquivalentRate unit value returns a value of type DoseEquivalentRate
+#[cfg(feature="num-complex")]
+impl<T> core::ops::Div<&InverseDoseEquivalentRate<T>> for &num_complex::Complex64 where T: NumLike+From<num_complex::Complex64> {
+	type Output = DoseEquivalentRate<T>;
+	fn div(self, rhs: &InverseDoseEquivalentRate<T>) -> Self::Output {
+		DoseEquivalentRate{Sv_per_s: T::from(self.clone()) / rhs.s_per_Sv.clone()}
 	}
 }
 
-// InverseDoseEquivalent * Acceleration -> InverseDistance
-/// Multiplying a InverseDoseEquivalent by a Acceleration returns a value of type InverseDistance
-impl<T> core::ops::Mul<Acceleration<T>> for InverseDoseEquivalent<T> where T: NumLike {
-	type Output = InverseDistance<T>;
-	fn mul(self, rhs: Acceleration<T>) -> Self::Output {
-		InverseDistance{per_m: self.per_Sv * rhs.mps2}
+// 1/InverseDoseEquivalentRate -> DoseEquivalentRate
+/// Dividing a scalar value by a InverseDoseEquivalentRate unit value returns a value of type DoseEquivalentRate
+#[cfg(feature="num-rational")]
+impl<T> core::ops::Div<InverseDoseEquivalentRate<T>> for num_rational::Rational64 where T: NumLike+From<num_rational::Rational64> {
+	type Output = DoseEquivalentRate<T>;
+	fn div(self, rhs: InverseDoseEquivalentRate<T>) -> Self::Output {
+		DoseEquivalentRate{Sv_per_s: T::from(self) / rhs.s_per_Sv}
 	}
 }
-/// Multiplying a InverseDoseEquivalent by a Acceleration returns a value of type InverseDistance
-impl<T> core::ops::Mul<Acceleration<T>> for &InverseDoseEquivalent<T> where T: NumLike {
-	type Output = InverseDistance<T>;
-	fn mul(self, rhs: Acceleration<T>) -> Self::Output {
-		InverseDistance{per_m: self.per_Sv.clone() * rhs.mps2}
+/// Dividing a scalar value by a InverseDoseEquivalentRate unit value returns a value of type DoseEquivalentRate
+#[cfg(feature="num-rational")]
+impl<T> core::ops::Div<InverseDoseEquivalentRate<T>> for &num_rational::Rational64 where T: NumLike+From<num_rational::Rational64> {
+	type Output = DoseEquivalentRate<T>;
+	fn div(self, rhs: InverseDoseEquivalentRate<T>) -> Self::Output {
+		DoseEquivalentRate{Sv_per_s: T::from(self.clone()) / rhs.s_per_Sv}
 	}
 }
-/// Multiplying a InverseDoseEquivalent by a Acceleration returns a value of type InverseDistance
-impl<T> core::ops::Mul<&Acceleration<T>> for InverseDoseEquivalent<T> where T: NumLike {
-	type Output = InverseDistance<T>;
-	fn mul(self, rhs: &Acceleration<T>) -> Self::Output {
-		InverseDistance{per_m: self.per_Sv * rhs.mps2.clone()}
+/// Dividing a scalar value by a InverseDoseEquivalentRate unit value returns a value of type DoseEquivalentRate
+#[cfg(feature="num-rational")]
+impl<T> core::ops::Div<&InverseDoseEquivalentRate<T>> for num_rational::Rational64 where T: NumLike+From<num_rational::Rational64> {
+	type Output = DoseEquivalentRate<T>;
+	fn div(self, rhs: &InverseDoseEquivalentRate<T>) -> Self::Output {
+		DoseEquivalentRate{Sv_per_s: T::from(self) / rhs.s_per_Sv.clone()}
 	}
 }
-/// Multiplying a InverseDoseEquivalent by a Acceleration returns a value of type InverseDistance
-impl<T> core::ops::Mul<&Acceleration<T>> for &InverseDoseEquivalent<T> where T: NumLike {
-	type Output = InverseDistance<T>;
-	fn mul(self, rhs: &Acceleration<T>) -> Self::Output {
-		InverseDistance{per_m: self.per_Sv.clone() * rhs.mps2.clone()}
+/// Dividing a scalar value by a InverseDoseEquivalentRate unit value returns a value of type DoseEquivalentRate
+#[cfg(feature="num-rational")]
+impl<T> core::ops::Div<&InverseDoseEquivalentRate<T>> for &num_rational::Rational64 where T: NumLike+From<num_rational::Rational64> {
+	type Output = DoseEquivalentRate<T>;
+	fn div(self, rhs: &InverseDoseEquivalentRate<T>) -> Self::Output {
+		DoseEquivalentRate{Sv_per_s: T::from(self.clone()) / rhs.s_per_Sv.clone()}
 	}
 }
 
-// InverseDoseEquivalent / Density -> InversePressure
-/// Dividing a InverseDoseEquivalent by a Density returns a value of type InversePressure
-impl<T> core::ops::Div<Density<T>> for InverseDoseEquivalent<T> where T: NumLike {
-	type Output = InversePressure<T>;
-	fn div(self, rhs: Density<T>) -> Self::Output {
-		InversePressure{per_Pa: self.per_Sv / rhs.kgpm3}
+// 1/InverseDoseEquivalentRate -> DoseEquivalentRate
+/// Dividing a scalar value by a InverseDoseEquivalentRate unit value returns a value of type DoseEquivalentRate
+#[cfg(feature="num-rational")]
+impl<T> core::ops::Div<InverseDoseEquivalentRate<T>> for num_rational::BigRational where T: NumLike+From<num_rational::BigRational> {
+	type Output = DoseEquivalentRate<T>;
+	fn div(self, rhs: InverseDoseEquivalentRate<T>) -> Self::Output {
+		DoseEquivalentRate{Sv_per_s: T::from(self) / rhs.s_per_Sv}
 	}
 }
-/// Dividing a InverseDoseEquivalent by a Density returns a value of type InversePressure
-impl<T> core::ops::Div<Density<T>> for &InverseDoseEquivalent<T> where T: NumLike {
-	type Output = InversePressure<T>;
-	fn div(self, rhs: Density<T>) -> Self::Output {
-		InversePressure{per_Pa: self.per_Sv.clone() / rhs.kgpm3}
+/// Dividing a scalar value by a InverseDoseEquivalentRate unit value returns a value of type DoseEquivalentRate
+#[cfg(feature="num-rational")]
+impl<T> core::ops::Div<InverseDoseEquivalentRate<T>> for &num_rational::BigRational where T: NumLike+From<num_rational::BigRational> {
+	type Output = DoseEquivalentRate<T>;
+	fn div(self, rhs: InverseDoseEquivalentRate<T>) -> Self::Output {
+		DoseEquivalentRate{Sv_per_s: T::from(self.clone()) / rhs.s_per_Sv}
 	}
 }
-/// Dividing a InverseDoseEquivalent by a Density returns a value of type InversePressure
-impl<T> core::ops::Div<&Density<T>> for InverseDoseEquivalent<T> where T: NumLike {
-	type Output = InversePressure<T>;
-	fn div(self, rhs: &Density<T>) -> Self::Output {
-		InversePressure{per_Pa: self.per_Sv / rhs.kgpm3.clone()}
+/// Dividing a scalar value by a InverseDoseEquivalentRate unit value returns a value of type DoseEquivalentRate
+#[cfg(feature="num-rational")]
+impl<T> core::ops::Div<&InverseDoseEquivalentRate<T>> for num_rational::BigRational where T: NumLike+From<num_rational::BigRational> {
+	type Output = DoseEquivalentRate<T>;
+	fn div(self, rhs: &InverseDoseEquivalentRate<T>) -> Self::Output {
+		DoseEquivalentRate{Sv_per_s: T::from(self) / rhs.s_per_Sv.clone()}
 	}
 }
-/// Dividing a InverseDoseEquivalent by a Density returns a value of type InversePressure
-impl<T> core::ops::Div<&Density<T>> for &InverseDoseEquivalent<T> where T: NumLike {
-	type Output = InversePressure<T>;
-	fn div(self, rhs: &Density<T>) -> Self::Output {
-		InversePressure{per_Pa: self.per_Sv.clone() / rhs.kgpm3.clone()}
+/// Dividing a scalar value by a InverseDoseEquivalentRate unit value returns a value of type DoseEquivalentRate
+#[cfg(feature="num-rational")]
+impl<T> core::ops::Div<&InverseDoseEquivalentRate<T>> for &num_rational::BigRational where T: NumLike+From<num_rational::BigRational> {
+	type Output = DoseEquivalentRate<T>;
+	fn div(self, rhs: &InverseDoseEquivalentRate<T>) -> Self::Output {
+		DoseEquivalentRate{Sv_per_s: T::from(self.clone()) / rhs.s_per_Sv.clone()}
+	}
+}
+
+/// The radioactivity unit type, defined as becquerels in SI units
+#[derive(UnitStruct, Debug, Clone, Default)]
+#[si(no_display)]
+#[cfg_attr(all(feature="serde", not(feature="serde-unit-tag")), derive(Serialize, Deserialize))]
+#[repr(transparent)]
+pub struct Radioactivity<T: NumLike>{
+	/// The value of this Radioactivity in becquerels
+	pub Bq: T
+}
+
+impl<T> Radioactivity<T> where T: NumLike {
+
+	/// Returns the standard unit name of radioactivity: "becquerels"
+	pub fn unit_name() -> &'static str { "becquerels" }
+	
+	/// Returns the abbreviated name or symbol of radioactivity: "Bq" for becquerels
+	pub fn unit_symbol() -> &'static str { "Bq" }
+
+	/// Returns the exponents of the SI base units of radioactivity (1/s)
+	pub const fn dimension() -> super::Dimension { super::Dimension{length: 0, mass: 0, time: -1, current: 0, temperature: 0, amount: 0, luminosity: 0} }
+
+	/// Returns a wrapper that formats this radioactivity value with the full unit name instead of 
+	/// the unit symbol, eg "5 becquerels" (the unit name is not singularized for values of 1)
+	pub fn display_named(&self) -> super::NamedDisplay<'_, T> {
+		super::NamedDisplay::new(&self.Bq, Self::unit_name())
+	}
+
+	/// Returns a new radioactivity value from the given number of becquerels (same as 
+	/// `from_Bq(...)`), for any number-like type. This is useful in generic code 
+	/// that works with several unit types
+	///
+	/// # Arguments
+	/// * `value` - Any number-like type, representing a quantity of becquerels
+	pub const fn new(value: T) -> Self { Radioactivity{Bq: value} }
+
+	/// Returns a new radioactivity value from the given number of becquerels. Unlike
+	/// `from_Bq(...)`, this is a `const fn`, so it can be used to declare `const` and `static` values
+	///
+	/// # Arguments
+	/// * `Bq` - Any number-like type, representing a quantity of becquerels
+	pub const fn from_Bq_const(Bq: T) -> Self { Radioactivity{Bq} }
+
+	/// Returns a radioactivity value with a different number type, by applying the 
+	/// given function to the value in becquerels (eg `.map(|x| x as f32)` to convert 
+	/// from `f64` to `f32`)
+	///
+	/// # Arguments
+	/// * `f` - Function that converts the number of becquerels to the new number type
+	pub fn map<U, F>(self, f: F) -> Radioactivity<U> where U: NumLike, F: FnOnce(T) -> U {
+		Radioactivity{Bq: f(self.Bq)}
+	}
+
+	/// Returns a radioactivity value with a different number type, converted 
+	/// losslessly with the `From` trait (eg from `f32` to `f64`)
+	pub fn cast<U>(self) -> Radioactivity<U> where U: NumLike+From<T> {
+		Radioactivity{Bq: U::from(self.Bq)}
+	}
+
+	/// Returns a reference to the value of this radioactivity in becquerels
+	pub fn as_raw(&self) -> &T {
+		&self.Bq
+	}
+
+	/// Returns a mutable reference to the value of this radioactivity in becquerels, 
+	/// eg for passing to numeric libraries that modify values in place. The number 
+	/// is always in becquerels, so changing it directly does not change the unit
+	pub fn raw_mut(&mut self) -> &mut T {
+		&mut self.Bq
+	}
+
+	/// Returns a slice of numbers in becquerels as a slice of radioactivity values, 
+	/// without copying
+	///
+	/// # Arguments
+	/// * `values` - Slice of numbers, representing quantities of becquerels
+	pub fn wrap_slice(values: &[T]) -> &[Self] {
+		// SAFETY: Radioactivity<T> is #[repr(transparent)], so it has the same memory layout as T
+		unsafe { core::slice::from_raw_parts(values.as_ptr() as *const Self, values.len()) }
+	}
+
+	/// Returns a mutable slice of numbers in becquerels as a mutable slice of 
+	/// radioactivity values, without copying
+	///
+	/// # Arguments
+	/// * `values` - Slice of numbers, representing quantities of becquerels
+	pub fn wrap_slice_mut(values: &mut [T]) -> &mut [Self] {
+		// SAFETY: Radioactivity<T> is #[repr(transparent)], so it has the same memory layout as T
+		unsafe { core::slice::from_raw_parts_mut(values.as_mut_ptr() as *mut Self, values.len()) }
 	}
-}
 
-// InverseDoseEquivalent * Energy -> Mass
-/// Multiplying a InverseDoseEquivalent by a Energy returns a value of type Mass
-impl<T> core::ops::Mul<Energy<T>> for InverseDoseEquivalent<T> where T: NumLike {
-	type Output = Mass<T>;
-	fn mul(self, rhs: Energy<T>) -> Self::Output {
-		Mass{kg: self.per_Sv * rhs.J}
+	/// Returns the ratio of this radioactivity value to another radioactivity value 
+	/// (same as `self / other`). Dividing a unit value by another value of the same 
+	/// unit type cancels the units, so the ratio is a dimensionless number
+	///
+	/// # Arguments
+	/// * `other` - The radioactivity value to divide by
+	pub fn ratio(self, other: Self) -> T {
+		self.Bq / other.Bq
 	}
-}
-/// Multiplying a InverseDoseEquivalent by a Energy returns a value of type Mass
-impl<T> core::ops::Mul<Energy<T>> for &InverseDoseEquivalent<T> where T: NumLike {
-	type Output = Mass<T>;
-	fn mul(self, rhs: Energy<T>) -> Self::Output {
-		Mass{kg: self.per_Sv.clone() * rhs.J}
+
+	/// Returns the ratio of this radioactivity value to another radioactivity value 
+	/// as a `Dimensionless` value, instead of a bare number like `ratio(...)` does
+	///
+	/// # Arguments
+	/// * `other` - The radioactivity value to divide by
+	pub fn dimensionless_ratio(self, other: Self) -> super::base::Dimensionless<T> {
+		super::base::Dimensionless{value: self.Bq / other.Bq}
 	}
-}
-/// Multiplying a InverseDoseEquivalent by a Energy returns a value of type Mass
-impl<T> core::ops::Mul<&Energy<T>> for InverseDoseEquivalent<T> where T: NumLike {
-	type Output = Mass<T>;
-	fn mul(self, rhs: &Energy<T>) -> Self::Output {
-		Mass{kg: self.per_Sv * rhs.J.clone()}
+
+	/// Returns this radioactivity value multiplied by a dimensionless factor 
+	/// (same as `self * factor`)
+	///
+	/// # Arguments
+	/// * `factor` - The number to multiply by
+	pub fn scale(self, factor: T) -> Self {
+		Radioactivity{Bq: self.Bq * factor}
 	}
+	
+	/// Returns a new radioactivity value from the given number of becquerels
+	///
+	/// # Arguments
+	/// * `Bq` - Any number-like type, representing a quantity of becquerels
+	pub fn from_Bq(Bq: T) -> Self { Radioactivity{Bq: Bq} }
+	
+	/// Returns a copy of this radioactivity value in becquerels
+	pub fn to_Bq(&self) -> T { self.Bq.clone() }
+
+	/// Returns a new radioactivity value from the given number of becquerels
+	///
+	/// # Arguments
+	/// * `becquerels` - Any number-like type, representing a quantity of becquerels
+	pub fn from_becquerels(becquerels: T) -> Self { Radioactivity{Bq: becquerels} }
+	
+	/// Returns a copy of this radioactivity value in becquerels
+	pub fn to_becquerels(&self) -> T { self.Bq.clone() }
+
 }
-/// Multiplying a InverseDoseEquivalent by a Energy returns a value of type Mass
-impl<T> core::ops::Mul<&Energy<T>> for &InverseDoseEquivalent<T> where T: NumLike {
-	type Output = Mass<T>;
-	fn mul(self, rhs: &Energy<T>) -> Self::Output {
-		Mass{kg: self.per_Sv.clone() * rhs.J.clone()}
+
+/// Formats radioactivity values as a number followed by its unit symbol, eg "1500 Bq"
+/// (complex numbers are wrapped in parentheses, eg "(1+2i) Bq").
+/// The alternate flag (`{:#}`) scales the number by the nearest SI prefix where the unit symbol allows it
+impl<T> fmt::Display for Radioactivity<T> where T: NumLike {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		if f.alternate() {
+			return super::fmt_with_si_prefix(f, &self.Bq, Self::unit_symbol(), true);
+		}
+		super::fmt_unit_value(f, &self.Bq, Self::unit_symbol())
 	}
 }
 
-// InverseDoseEquivalent * Torque -> Mass
-/// Multiplying a InverseDoseEquivalent by a Torque returns a value of type Mass
-impl<T> core::ops::Mul<Torque<T>> for InverseDoseEquivalent<T> where T: NumLike {
-	type Output = Mass<T>;
-	fn mul(self, rhs: Torque<T>) -> Self::Output {
-		Mass{kg: self.per_Sv * rhs.Nm}
+/// Formats radioactivity values in exponential notation followed by the unit symbol, eg "1.5e3 Bq"
+impl<T> fmt::LowerExp for Radioactivity<T> where T: NumLike+fmt::LowerExp {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		fmt::LowerExp::fmt(&self.Bq, f)?;
+		write!(f, " {}", Self::unit_symbol())
 	}
 }
-/// Multiplying a InverseDoseEquivalent by a Torque returns a value of type Mass
-impl<T> core::ops::Mul<Torque<T>> for &InverseDoseEquivalent<T> where T: NumLike {
-	type Output = Mass<T>;
-	fn mul(self, rhs: Torque<T>) -> Self::Output {
-		Mass{kg: self.per_Sv.clone() * rhs.Nm}
+
+/// Formats radioactivity values in exponential notation followed by the unit symbol, eg "1.5E3 Bq"
+impl<T> fmt::UpperExp for Radioactivity<T> where T: NumLike+fmt::UpperExp {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		fmt::UpperExp::fmt(&self.Bq, f)?;
+		write!(f, " {}", Self::unit_symbol())
 	}
 }
-/// Multiplying a InverseDoseEquivalent by a Torque returns a value of type Mass
-impl<T> core::ops::Mul<&Torque<T>> for InverseDoseEquivalent<T> where T: NumLike {
-	type Output = Mass<T>;
-	fn mul(self, rhs: &Torque<T>) -> Self::Output {
-		Mass{kg: self.per_Sv * rhs.Nm.clone()}
-	}
+
+impl<T> super::SiUnit for Radioactivity<T> where T: NumLike {
+	type Number = T;
+	fn unit_name(&self) -> &'static str { Self::unit_name() }
+	fn unit_symbol(&self) -> &'static str { Self::unit_symbol() }
+	fn raw(&self) -> &T { &self.Bq }
+	fn dimension(&self) -> super::Dimension { Self::dimension() }
+	fn from_raw(value: T) -> Self { Radioactivity{Bq: value} }
+	fn into_raw(self) -> T { self.Bq }
 }
-/// Multiplying a InverseDoseEquivalent by a Torque returns a value of type Mass
-impl<T> core::ops::Mul<&Torque<T>> for &InverseDoseEquivalent<T> where T: NumLike {
-	type Output = Mass<T>;
-	fn mul(self, rhs: &Torque<T>) -> Self::Output {
-		Mass{kg: self.per_Sv.clone() * rhs.Nm.clone()}
+
+#[cfg(feature="alloc")]
+impl<T> Radioactivity<T> where T: NumLike {
+	/// Returns this radioactivity value in the canonical text format "<value> <symbol>", eg "5 Bq".
+	/// Unlike `Display`, this format never changes with precision or alternate flags, and it is the
+	/// input format expected by `from_str(...)`, so `x.canonical_string().parse()` returns the same value
+	pub fn canonical_string(&self) -> alloc::string::String {
+		alloc::format!("{} {}", self.Bq, Self::unit_symbol())
 	}
-}
 
-// InverseDoseEquivalent / InverseAcceleration -> InverseDistance
-/// Dividing a InverseDoseEquivalent by a InverseAcceleration returns a value of type InverseDistance
-impl<T> core::ops::Div<InverseAcceleration<T>> for InverseDoseEquivalent<T> where T: NumLike {
-	type Output = InverseDistance<T>;
-	fn div(self, rhs: InverseAcceleration<T>) -> Self::Output {
-		InverseDistance{per_m: self.per_Sv / rhs.s2pm}
+	/// Returns a `Vec` of radioactivity values copied from the given slice of numbers
+	///
+	/// # Arguments
+	/// * `values` - Slice of numbers, representing quantities of becquerels
+	pub fn from_Bq_slice(values: &[T]) -> alloc::vec::Vec<Self> {
+		values.iter().map(|x| Radioactivity{Bq: x.clone()}).collect()
 	}
 }
-/// Dividing a InverseDoseEquivalent by a InverseAcceleration returns a value of type InverseDistance
-impl<T> core::ops::Div<InverseAcceleration<T>> for &InverseDoseEquivalent<T> where T: NumLike {
-	type Output = InverseDistance<T>;
-	fn div(self, rhs: InverseAcceleration<T>) -> Self::Output {
-		InverseDistance{per_m: self.per_Sv.clone() / rhs.s2pm}
+
+/// Parses a radioactivity value from a number followed by its unit symbol, eg "5 Bq"
+impl<T> core::str::FromStr for Radioactivity<T> where T: NumLike+core::str::FromStr {
+	type Err = SiParseError;
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		Ok(Radioactivity{Bq: super::parse_unit_value(s, Self::unit_symbol())?})
 	}
 }
-/// Dividing a InverseDoseEquivalent by a InverseAcceleration returns a value of type InverseDistance
-impl<T> core::ops::Div<&InverseAcceleration<T>> for InverseDoseEquivalent<T> where T: NumLike {
-	type Output = InverseDistance<T>;
-	fn div(self, rhs: &InverseAcceleration<T>) -> Self::Output {
-		InverseDistance{per_m: self.per_Sv / rhs.s2pm.clone()}
+
+/// Parses a radioactivity value from a number followed by its unit symbol, eg "5 Bq" 
+/// (same as `str::parse()`)
+impl<T> core::convert::TryFrom<&str> for Radioactivity<T> where T: NumLike+core::str::FromStr {
+	type Error = SiParseError;
+	fn try_from(s: &str) -> Result<Self, Self::Error> {
+		<Self as core::str::FromStr>::from_str(s)
 	}
 }
-/// Dividing a InverseDoseEquivalent by a InverseAcceleration returns a value of type InverseDistance
-impl<T> core::ops::Div<&InverseAcceleration<T>> for &InverseDoseEquivalent<T> where T: NumLike {
-	type Output = InverseDistance<T>;
-	fn div(self, rhs: &InverseAcceleration<T>) -> Self::Output {
-		InverseDistance{per_m: self.per_Sv.clone() / rhs.s2pm.clone()}
+
+#[cfg(feature="num-traits")]
+impl<T> Radioactivity<T> where T: NumLike+num_traits::Euclid {
+
+	/// Returns the Euclidean remainder of dividing this radioactivity value by another, 
+	/// which is never negative (requires the `num-traits` feature)
+	///
+	/// # Arguments
+	/// * `rhs` - The radioactivity value to divide by
+	pub fn rem_euclid(self, rhs: Self) -> Self {
+		Radioactivity{Bq: self.Bq.rem_euclid(&rhs.Bq)}
 	}
-}
 
-// InverseDoseEquivalent / InverseEnergy -> Mass
-/// Dividing a InverseDoseEquivalent by a InverseEnergy returns a value of type Mass
-impl<T> core::ops::Div<InverseEnergy<T>> for InverseDoseEquivalent<T> where T: NumLike {
-	type Output = Mass<T>;
-	fn div(self, rhs: InverseEnergy<T>) -> Self::Output {
-		Mass{kg: self.per_Sv / rhs.per_J}
+	/// Returns the Euclidean quotient of dividing this radioactivity value by another, 
+	/// ie the whole number of times that `rhs` fits into this value, rounded such that 
+	/// `rem_euclid(...)` is never negative (requires the `num-traits` feature)
+	///
+	/// # Arguments
+	/// * `rhs` - The radioactivity value to divide by
+	pub fn div_euclid(self, rhs: Self) -> T {
+		self.Bq.div_euclid(&rhs.Bq)
 	}
 }
-/// Dividing a InverseDoseEquivalent by a InverseEnergy returns a value of type Mass
-impl<T> core::ops::Div<InverseEnergy<T>> for &InverseDoseEquivalent<T> where T: NumLike {
-	type Output = Mass<T>;
-	fn div(self, rhs: InverseEnergy<T>) -> Self::Output {
-		Mass{kg: self.per_Sv.clone() / rhs.per_J}
+
+/// Summing radioactivity values returns their total as a radioactivity value (or zero if there are no values)
+impl<T> core::iter::Sum<Radioactivity<T>> for Radioactivity<T> where T: NumLike+core::iter::Sum {
+	fn sum<I: Iterator<Item=Radioactivity<T>>>(iter: I) -> Self {
+		Radioactivity{Bq: iter.map(|x| x.Bq).sum()}
 	}
 }
-/// Dividing a InverseDoseEquivalent by a InverseEnergy returns a value of type Mass
-impl<T> core::ops::Div<&InverseEnergy<T>> for InverseDoseEquivalent<T> where T: NumLike {
-	type Output = Mass<T>;
-	fn div(self, rhs: &InverseEnergy<T>) -> Self::Output {
-		Mass{kg: self.per_Sv / rhs.per_J.clone()}
+/// Summing radioactivity values returns their total as a radioactivity value (or zero if there are no values)
+impl<'a, T> core::iter::Sum<&'a Radioactivity<T>> for Radioactivity<T> where T: NumLike+core::iter::Sum+'a {
+	fn sum<I: Iterator<Item=&'a Radioactivity<T>>>(iter: I) -> Self {
+		Radioactivity{Bq: iter.map(|x| x.Bq.clone()).sum()}
 	}
 }
-/// Dividing a InverseDoseEquivalent by a InverseEnergy returns a value of type Mass
-impl<T> core::ops::Div<&InverseEnergy<T>> for &InverseDoseEquivalent<T> where T: NumLike {
-	type Output = Mass<T>;
-	fn div(self, rhs: &InverseEnergy<T>) -> Self::Output {
-		Mass{kg: self.per_Sv.clone() / rhs.per_J.clone()}
+
+impl<T> Radioactivity<T> where T: NumLike+From<f64> {
+
+	/// Returns the weighted average of the given radioactivity values, where each 
+	/// value is paired with its weight (weights do not need to add up to 1). If the 
+	/// total weight is zero (including when `values` is empty), then zero is returned
+	///
+	/// # Arguments
+	/// * `values` - Slice of (radioactivity value, weight) pairs
+	pub fn weighted_mean(values: &[(Self, f64)]) -> Self {
+		let total_weight: f64 = values.iter().map(|(_, weight)| *weight).sum();
+		if total_weight == 0.0 {
+			return Radioactivity{Bq: T::from(0.0)};
+		}
+		let total = values.iter().fold(T::from(0.0),
+			|total, (value, weight)| total + value.Bq.clone() * T::from(*weight));
+		Radioactivity{Bq: total / T::from(total_weight)}
 	}
 }
 
-// InverseDoseEquivalent / InverseTorque -> Mass
-/// Dividing a InverseDoseEquivalent by a InverseTorque returns a value of type Mass
-impl<T> core::ops::Div<InverseTorque<T>> for InverseDoseEquivalent<T> where T: NumLike {
-	type Output = Mass<T>;
-	fn div(self, rhs: InverseTorque<T>) -> Self::Output {
-		Mass{kg: self.per_Sv / rhs.per_Nm}
+impl<T> Radioactivity<T> where T: NumLike+PartialEq+From<u8> {
+
+	/// Divides this radioactivity value by another unit value (eg `distance.checked_div(time)` 
+	/// returns a velocity), returning `None` if the divisor is zero instead of producing 
+	/// infinity or NaN (or panicking, for integer number types). This works for every unit 
+	/// type that this radioactivity value can be divided by with the `/` operator
+	///
+	/// # Arguments
+	/// * `rhs` - The unit value to divide by
+	pub fn checked_div<R>(self, rhs: R) -> Option<<Self as core::ops::Div<R>>::Output> 
+		where Self: core::ops::Div<R>, R: super::SiUnit<Number=T>
+	{
+		if *rhs.raw() == T::from(0u8) {
+			return None;
+		}
+		Some(self / rhs)
 	}
 }
-/// Dividing a InverseDoseEquivalent by a InverseTorque returns a value of type Mass
-impl<T> core::ops::Div<InverseTorque<T>> for &InverseDoseEquivalent<T> where T: NumLike {
-	type Output = Mass<T>;
-	fn div(self, rhs: InverseTorque<T>) -> Self::Output {
-		Mass{kg: self.per_Sv.clone() / rhs.per_Nm}
+
+/// Multiplying a radioactivity value by a dimensionless value returns a radioactivity value
+impl<T> core::ops::Mul<super::base::Dimensionless<T>> for Radioactivity<T> where T: NumLike {
+	type Output = Radioactivity<T>;
+	fn mul(self, rhs: super::base::Dimensionless<T>) -> Self::Output {
+		Radioactivity{Bq: self.Bq * rhs.value}
 	}
 }
-/// Dividing a InverseDoseEquivalent by a InverseTorque returns a value of type Mass
-impl<T> core::ops::Div<&InverseTorque<T>> for InverseDoseEquivalent<T> where T: NumLike {
-	type Output = Mass<T>;
-	fn div(self, rhs: &InverseTorque<T>) -> Self::Output {
-		Mass{kg: self.per_Sv / rhs.per_Nm.clone()}
+/// Multiplying a dimensionless value by a radioactivity value returns a radioactivity value
+impl<T> core::ops::Mul<Radioactivity<T>> for super::base::Dimensionless<T> where T: NumLike {
+	type Output = Radioactivity<T>;
+	fn mul(self, rhs: Radioactivity<T>) -> Self::Output {
+		Radioactivity{Bq: self.value * rhs.Bq}
 	}
 }
-/// Dividing a InverseDoseEquivalent by a InverseTorque returns a value of type Mass
-impl<T> core::ops::Div<&InverseTorque<T>> for &InverseDoseEquivalent<T> where T: NumLike {
-	type Output = Mass<T>;
-	fn div(self, rhs: &InverseTorque<T>) -> Self::Output {
-		Mass{kg: self.per_Sv.clone() / rhs.per_Nm.clone()}
+/// Dividing a radioactivity value by a dimensionless value returns a radioactivity value
+impl<T> core::ops::Div<super::base::Dimensionless<T>> for Radioactivity<T> where T: NumLike {
+	type Output = Radioactivity<T>;
+	fn div(self, rhs: super::base::Dimensionless<T>) -> Self::Output {
+		Radioactivity{Bq: self.Bq / rhs.value}
 	}
 }
 
-// InverseDoseEquivalent / InversePressure -> Density
-/// Dividing a InverseDoseEquivalent by a InversePressure returns a value of type Density
-impl<T> core::ops::Div<InversePressure<T>> for InverseDoseEquivalent<T> where T: NumLike {
-	type Output = Density<T>;
-	fn div(self, rhs: InversePressure<T>) -> Self::Output {
-		Density{kgpm3: self.per_Sv / rhs.per_Pa}
+/// The remainder of dividing a radioactivity value by another radioactivity value is a radioactivity value
+impl<T> core::ops::Rem<Radioactivity<T>> for Radioactivity<T> where T: NumLike+core::ops::Rem<Output=T> {
+	type Output = Radioactivity<T>;
+	fn rem(self, rhs: Radioactivity<T>) -> Self::Output {
+		Radioactivity{Bq: self.Bq % rhs.Bq}
 	}
 }
-/// Dividing a InverseDoseEquivalent by a InversePressure returns a value of type Density
-impl<T> core::ops::Div<InversePressure<T>> for &InverseDoseEquivalent<T> where T: NumLike {
-	type Output = Density<T>;
-	fn div(self, rhs: InversePressure<T>) -> Self::Output {
-		Density{kgpm3: self.per_Sv.clone() / rhs.per_Pa}
+/// The remainder of dividing a radioactivity value by another radioactivity value is a radioactivity value
+impl<T> core::ops::Rem<&Radioactivity<T>> for Radioactivity<T> where T: NumLike+core::ops::Rem<Output=T> {
+	type Output = Radioactivity<T>;
+	fn rem(self, rhs: &Radioactivity<T>) -> Self::Output {
+		Radioactivity{Bq: self.Bq % rhs.Bq.clone()}
 	}
 }
-/// Dividing a InverseDoseEquivalent by a InversePressure returns a value of type Density
-impl<T> core::ops::Div<&InversePressure<T>> for InverseDoseEquivalent<T> where T: NumLike {
-	type Output = Density<T>;
-	fn div(self, rhs: &InversePressure<T>) -> Self::Output {
-		Density{kgpm3: self.per_Sv / rhs.per_Pa.clone()}
+/// The remainder of dividing a radioactivity value by another radioactivity value is a radioactivity value
+impl<T> core::ops::Rem<Radioactivity<T>> for &Radioactivity<T> where T: NumLike+core::ops::Rem<Output=T> {
+	type Output = Radioactivity<T>;
+	fn rem(self, rhs: Radioactivity<T>) -> Self::Output {
+		Radioactivity{Bq: self.Bq.clone() % rhs.Bq}
 	}
 }
-/// Dividing a InverseDoseEquivalent by a InversePressure returns a value of type Density
-impl<T> core::ops::Div<&InversePressure<T>> for &InverseDoseEquivalent<T> where T: NumLike {
-	type Output = Density<T>;
-	fn div(self, rhs: &InversePressure<T>) -> Self::Output {
-		Density{kgpm3: self.per_Sv.clone() / rhs.per_Pa.clone()}
+/// The remainder of dividing a radioactivity value by another radioactivity value is a radioactivity value
+impl<T> core::ops::Rem<&Radioactivity<T>> for &Radioactivity<T> where T: NumLike+core::ops::Rem<Output=T> {
+	type Output = Radioactivity<T>;
+	fn rem(self, rhs: &Radioactivity<T>) -> Self::Output {
+		Radioactivity{Bq: self.Bq.clone() % rhs.Bq.clone()}
 	}
 }
 
-// InverseDoseEquivalent * Pressure -> Density
-/// Multiplying a InverseDoseEquivalent by a Pressure returns a value of type Density
-impl<T> core::ops::Mul<Pressure<T>> for InverseDoseEquivalent<T> where T: NumLike {
-	type Output = Density<T>;
-	fn mul(self, rhs: Pressure<T>) -> Self::Output {
-		Density{kgpm3: self.per_Sv * rhs.Pa}
+/// Serializes a radioactivity value together with its unit symbol, eg 
+/// `{"value": 1.5, "unit": "Bq"}` (requires the `serde-unit-tag` feature)
+#[cfg(feature="serde-unit-tag")]
+impl<T> serde::Serialize for Radioactivity<T> where T: NumLike+serde::Serialize {
+	fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+		super::serialize_with_unit_tag(&self.Bq, Self::unit_symbol(), serializer)
 	}
 }
-/// Multiplying a InverseDoseEquivalent by a Pressure returns a value of type Density
-impl<T> core::ops::Mul<Pressure<T>> for &InverseDoseEquivalent<T> where T: NumLike {
-	type Output = Density<T>;
-	fn mul(self, rhs: Pressure<T>) -> Self::Output {
-		Density{kgpm3: self.per_Sv.clone() * rhs.Pa}
+/// Deserializes a radioactivity value from a value and unit symbol, eg 
+/// `{"value": 1.5, "unit": "Bq"}`, returning an error if the unit symbol 
+/// does not match (requires the `serde-unit-tag` feature)
+#[cfg(feature="serde-unit-tag")]
+impl<'de, T> serde::Deserialize<'de> for Radioactivity<T> where T: NumLike+serde::Deserialize<'de> {
+	fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+		Ok(Radioactivity{Bq: super::deserialize_with_unit_tag(Self::unit_symbol(), deserializer)?})
 	}
 }
-/// Multiplying a InverseDoseEquivalent by a Pressure returns a value of type Density
-impl<T> core::ops::Mul<&Pressure<T>> for InverseDoseEquivalent<T> where T: NumLike {
-	type Output = Density<T>;
-	fn mul(self, rhs: &Pressure<T>) -> Self::Output {
-		Density{kgpm3: self.per_Sv * rhs.Pa.clone()}
-	}
+
+/// The additive identity of radioactivity values is zero becquerels (requires the `num-traits` feature)
+#[cfg(feature="num-traits")]
+impl<T> num_traits::Zero for Radioactivity<T> where T: NumLike+num_traits::Zero {
+	fn zero() -> Self { Radioactivity{Bq: T::zero()} }
+	fn is_zero(&self) -> bool { self.Bq.is_zero() }
 }
-/// Multiplying a InverseDoseEquivalent by a Pressure returns a value of type Density
-impl<T> core::ops::Mul<&Pressure<T>> for &InverseDoseEquivalent<T> where T: NumLike {
-	type Output = Density<T>;
-	fn mul(self, rhs: &Pressure<T>) -> Self::Output {
-		Density{kgpm3: self.per_Sv.clone() * rhs.Pa.clone()}
+
+#[cfg(feature="num-traits")]
+impl<T> Radioactivity<T> where T: NumLike+num_traits::Signed {
+
+	/// Returns the sign of this radioactivity value as a dimensionless number: 1 if it is 
+	/// positive, -1 if it is negative, and 0 if it is zero for integer number types 
+	/// (for floating-point number types, +0.0 returns 1.0, -0.0 returns -1.0, and NaN returns 
+	/// NaN) (requires the `num-traits` feature)
+	pub fn signum(self) -> T {
+		self.Bq.signum()
 	}
 }
 
-// InverseDoseEquivalent / TimePerDistance -> TimePerDistance
-/// Dividing a InverseDoseEquivalent by a TimePerDistance returns a value of type TimePerDistance
-impl<T> core::ops::Div<TimePerDistance<T>> for InverseDoseEquivalent<T> where T: NumLike {
-	type Output = TimePerDistance<T>;
-	fn div(self, rhs: TimePerDistance<T>) -> Self::Output {
-		TimePerDistance{spm: self.per_Sv / rhs.spm}
+impl<T> Radioactivity<T> where T: NumLike+PartialOrd {
+
+	/// Returns the absolute value of this radioactivity value, ie its magnitude without a minus sign
+	/// (for floating-point number types, -0.0 returns +0.0)
+	pub fn abs(self) -> Self {
+		let negated = -self.Bq.clone();
+		if self.Bq < negated {
+			Radioactivity{Bq: negated}
+		} else if self.Bq == negated {
+			// zero, which is its own negation (adding +0.0 turns -0.0 into +0.0)
+			Radioactivity{Bq: negated + self.Bq}
+		} else {
+			self
+		}
 	}
-}
-/// Dividing a InverseDoseEquivalent by a TimePerDistance returns a value of type TimePerDistance
-impl<T> core::ops::Div<TimePerDistance<T>> for &InverseDoseEquivalent<T> where T: NumLike {
-	type Output = TimePerDistance<T>;
-	fn div(self, rhs: TimePerDistance<T>) -> Self::Output {
-		TimePerDistance{spm: self.per_Sv.clone() / rhs.spm}
+
+	/// Returns the lesser of this radioactivity value and the given radioactivity value
+	///
+	/// # Arguments
+	/// * `other` - The radioactivity value to compare with this one
+	pub fn min(self, other: Self) -> Self {
+		if other.Bq < self.Bq { other } else { self }
 	}
-}
-/// Dividing a InverseDoseEquivalent by a TimePerDistance returns a value of type TimePerDistance
-impl<T> core::ops::Div<&TimePerDistance<T>> for InverseDoseEquivalent<T> where T: NumLike {
-	type Output = TimePerDistance<T>;
-	fn div(self, rhs: &TimePerDistance<T>) -> Self::Output {
-		TimePerDistance{spm: self.per_Sv / rhs.spm.clone()}
+
+	/// Returns the greater of this radioactivity value and the given radioactivity value
+	///
+	/// # Arguments
+	/// * `other` - The radioactivity value to compare with this one
+	pub fn max(self, other: Self) -> Self {
+		if other.Bq > self.Bq { other } else { self }
 	}
-}
-/// Dividing a InverseDoseEquivalent by a TimePerDistance returns a value of type TimePerDistance
-impl<T> core::ops::Div<&TimePerDistance<T>> for &InverseDoseEquivalent<T> where T: NumLike {
-	type Output = TimePerDistance<T>;
-	fn div(self, rhs: &TimePerDistance<T>) -> Self::Output {
-		TimePerDistance{spm: self.per_Sv.clone() / rhs.spm.clone()}
+
+	/// Returns the least of the given radioactivity values, or `None` if there are none. 
+	/// Unlike `Iterator::min()`, this does not require `Ord`, so it works for 
+	/// floating-point numbers. NaN values are skipped (so the result is `None` if all 
+	/// values are NaN), and the first of several equal values is returned
+	///
+	/// # Arguments
+	/// * `values` - Any iterator or collection of radioactivity values
+	pub fn min_of<I>(values: I) -> Option<Self> where I: IntoIterator<Item=Self> {
+		values.into_iter().filter(|x| x.Bq.partial_cmp(&x.Bq).is_some()).reduce(Self::min)
+	}
+
+	/// Returns the greatest of the given radioactivity values, or `None` if there are none. 
+	/// Unlike `Iterator::max()`, this does not require `Ord`, so it works for 
+	/// floating-point numbers. NaN values are skipped (so the result is `None` if all 
+	/// values are NaN), and the first of several equal values is returned
+	///
+	/// # Arguments
+	/// * `values` - Any iterator or collection of radioactivity values
+	pub fn max_of<I>(values: I) -> Option<Self> where I: IntoIterator<Item=Self> {
+		values.into_iter().filter(|x| x.Bq.partial_cmp(&x.Bq).is_some()).reduce(Self::max)
+	}
+
+	/// Returns this radioactivity value restricted to the range from `lo` to `hi`
+	///
+	/// # Arguments
+	/// * `lo` - The minimum radioactivity value
+	/// * `hi` - The maximum radioactivity value
+	///
+	/// # Panics
+	/// Panics if `lo` is greater than `hi` (or if either of them is NaN)
+	pub fn clamp(self, lo: Self, hi: Self) -> Self {
+		assert!(lo.Bq <= hi.Bq, "lo must be less than or equal to hi");
+		if self.Bq < lo.Bq { lo } else if self.Bq > hi.Bq { hi } else { self }
 	}
-}
 
-// InverseDoseEquivalent * Velocity -> TimePerDistance
-/// Multiplying a InverseDoseEquivalent by a Velocity returns a value of type TimePerDistance
-impl<T> core::ops::Mul<Velocity<T>> for InverseDoseEquivalent<T> where T: NumLike {
-	type Output = TimePerDistance<T>;
-	fn mul(self, rhs: Velocity<T>) -> Self::Output {
-		TimePerDistance{spm: self.per_Sv * rhs.mps}
+	/// Returns this radioactivity value with its magnitude capped at `max`, keeping its 
+	/// sign (eg for capping a speed), or this value unchanged if its magnitude does not 
+	/// exceed `max`. For complex-valued radioactivity, use `clamp_norm(...)` instead, 
+	/// which caps the magnitude while keeping the phase
+	///
+	/// # Arguments
+	/// * `max` - The maximum magnitude (should not be negative)
+	pub fn clamp_magnitude(self, max: Self) -> Self {
+		let negated_max = -max.Bq.clone();
+		if self.Bq > max.Bq { 
+			max 
+		} else if self.Bq < negated_max { 
+			Radioactivity{Bq: negated_max} 
+		} else { 
+			self 
+		}
+	}
+
+	/// Returns true if this radioactivity value differs from the given radioactivity 
+	/// value by no more than the given absolute tolerance (which is also a 
+	/// radioactivity value, so the comparison is dimensionally safe). NaN is never 
+	/// equal to anything. See also the `assert_unit_eq!(...)` macro.
+	///
+	/// # Arguments
+	/// * `other` - The radioactivity value to compare with this one
+	/// * `tolerance` - The largest allowed difference between the two values
+	pub fn eq_within(self, other: Self, tolerance: Self) -> bool {
+		let diff = self.Bq - other.Bq;
+		diff <= tolerance.Bq && -diff <= tolerance.Bq
 	}
 }
-/// Multiplying a InverseDoseEquivalent by a Velocity returns a value of type TimePerDistance
-impl<T> core::ops::Mul<Velocity<T>> for &InverseDoseEquivalent<T> where T: NumLike {
-	type Output = TimePerDistance<T>;
-	fn mul(self, rhs: Velocity<T>) -> Self::Output {
-		TimePerDistance{spm: self.per_Sv.clone() * rhs.mps}
+
+#[cfg(feature="num-complex")]
+impl Radioactivity<num_complex::Complex32> {
+	/// Returns the magnitude (aka norm or absolute value) of this complex radioactivity 
+	/// value as a real-valued radioactivity (requires the `num-complex` feature)
+	pub fn magnitude(self) -> Radioactivity<f32> {
+		Radioactivity{Bq: self.Bq.norm()}
 	}
-}
-/// Multiplying a InverseDoseEquivalent by a Velocity returns a value of type TimePerDistance
-impl<T> core::ops::Mul<&Velocity<T>> for InverseDoseEquivalent<T> where T: NumLike {
-	type Output = TimePerDistance<T>;
-	fn mul(self, rhs: &Velocity<T>) -> Self::Output {
-		TimePerDistance{spm: self.per_Sv * rhs.mps.clone()}
+
+	/// Returns this complex radioactivity value scaled down so that its magnitude is 
+	/// at most `max` (keeping its phase), or this value unchanged if its magnitude does 
+	/// not exceed `max`. This is the complex equivalent of `clamp_magnitude(...)` (requires 
+	/// the `num-complex` feature)
+	///
+	/// # Arguments
+	/// * `max` - The maximum magnitude (should not be negative)
+	pub fn clamp_norm(self, max: Radioactivity<f32>) -> Self {
+		let norm = self.Bq.norm();
+		if norm > max.Bq {
+			Radioactivity{Bq: self.Bq * (max.Bq / norm)}
+		} else {
+			self
+		}
 	}
 }
-/// Multiplying a InverseDoseEquivalent by a Velocity returns a value of type TimePerDistance
-impl<T> core::ops::Mul<&Velocity<T>> for &InverseDoseEquivalent<T> where T: NumLike {
-	type Output = TimePerDistance<T>;
-	fn mul(self, rhs: &Velocity<T>) -> Self::Output {
-		TimePerDistance{spm: self.per_Sv.clone() * rhs.mps.clone()}
+
+#[cfg(feature="num-complex")]
+impl Radioactivity<num_complex::Complex64> {
+	/// Returns the magnitude (aka norm or absolute value) of this complex radioactivity 
+	/// value as a real-valued radioactivity (requires the `num-complex` feature)
+	pub fn magnitude(self) -> Radioactivity<f64> {
+		Radioactivity{Bq: self.Bq.norm()}
 	}
-}
 
-// InverseDoseEquivalent * VolumePerMass -> InversePressure
-/// Multiplying a InverseDoseEquivalent by a VolumePerMass returns a value of type InversePressure
-impl<T> core::ops::Mul<VolumePerMass<T>> for InverseDoseEquivalent<T> where T: NumLike {
-	type Output = InversePressure<T>;
-	fn mul(self, rhs: VolumePerMass<T>) -> Self::Output {
-		InversePressure{per_Pa: self.per_Sv * rhs.m3_per_kg}
+	/// Returns this complex radioactivity value scaled down so that its magnitude is 
+	/// at most `max` (keeping its phase), or this value unchanged if its magnitude does 
+	/// not exceed `max`. This is the complex equivalent of `clamp_magnitude(...)` (requires 
+	/// the `num-complex` feature)
+	///
+	/// # Arguments
+	/// * `max` - The maximum magnitude (should not be negative)
+	pub fn clamp_norm(self, max: Radioactivity<f64>) -> Self {
+		let norm = self.Bq.norm();
+		if norm > max.Bq {
+			Radioactivity{Bq: self.Bq * (max.Bq / norm)}
+		} else {
+			self
+		}
 	}
 }
-/// Multiplying a InverseDoseEquivalent by a VolumePerMass returns a value of type InversePressure
-impl<T> core::ops::Mul<VolumePerMass<T>> for &InverseDoseEquivalent<T> where T: NumLike {
-	type Output = InversePressure<T>;
-	fn mul(self, rhs: VolumePerMass<T>) -> Self::Output {
-		InversePressure{per_Pa: self.per_Sv.clone() * rhs.m3_per_kg}
+
+/// Rounding and other floating-point functions (requires the `num-traits` feature, 
+/// and either the `std` or `libm` feature)
+#[cfg(all(feature="num-traits", any(feature="std", feature="libm")))]
+impl<T> Radioactivity<T> where T: NumLike+num_traits::Float {
+
+	/// Returns this radioactivity value rounded to the nearest whole number of becquerels 
+	/// (half-way cases are rounded away from zero)
+	pub fn round(self) -> Self {
+		Radioactivity{Bq: self.Bq.round()}
 	}
-}
-/// Multiplying a InverseDoseEquivalent by a VolumePerMass returns a value of type InversePressure
-impl<T> core::ops::Mul<&VolumePerMass<T>> for InverseDoseEquivalent<T> where T: NumLike {
-	type Output = InversePressure<T>;
-	fn mul(self, rhs: &VolumePerMass<T>) -> Self::Output {
-		InversePressure{per_Pa: self.per_Sv * rhs.m3_per_kg.clone()}
+
+	/// Returns this radioactivity value rounded down to a whole number of becquerels
+	pub fn floor(self) -> Self {
+		Radioactivity{Bq: self.Bq.floor()}
 	}
-}
-/// Multiplying a InverseDoseEquivalent by a VolumePerMass returns a value of type InversePressure
-impl<T> core::ops::Mul<&VolumePerMass<T>> for &InverseDoseEquivalent<T> where T: NumLike {
-	type Output = InversePressure<T>;
-	fn mul(self, rhs: &VolumePerMass<T>) -> Self::Output {
-		InversePressure{per_Pa: self.per_Sv.clone() * rhs.m3_per_kg.clone()}
+
+	/// Returns this radioactivity value rounded up to a whole number of becquerels
+	pub fn ceil(self) -> Self {
+		Radioactivity{Bq: self.Bq.ceil()}
 	}
-}
 
-/// The radioactivity unit type, defined as becquerels in SI units
-#[derive(UnitStruct, Debug, Clone)]
-#[cfg_attr(feature="serde", derive(Serialize, Deserialize))]
-pub struct Radioactivity<T: NumLike>{
-	/// The value of this Radioactivity in becquerels
-	pub Bq: T
-}
+	/// Returns this radioactivity value rounded towards zero to a whole number of becquerels
+	pub fn trunc(self) -> Self {
+		Radioactivity{Bq: self.Bq.trunc()}
+	}
 
-impl<T> Radioactivity<T> where T: NumLike {
+	/// Returns this radioactivity value rounded to the nearest multiple of the given step 
+	/// (half-way cases are rounded away from zero)
+	///
+	/// # Arguments
+	/// * `step` - The radioactivity value to round to a multiple of
+	pub fn round_to(self, step: Self) -> Self {
+		Radioactivity{Bq: (self.Bq / step.Bq).round() * step.Bq}
+	}
 
-	/// Returns the standard unit name of radioactivity: "becquerels"
-	pub fn unit_name() -> &'static str { "becquerels" }
-	
-	/// Returns the abbreviated name or symbol of radioactivity: "Bq" for becquerels
-	pub fn unit_symbol() -> &'static str { "Bq" }
-	
-	/// Returns a new radioactivity value from the given number of becquerels
+	/// Fused multiply-add: returns `self * a + b` computed with only one rounding 
+	/// error, which is more accurate (and on some hardware faster) than a separate 
+	/// multiplication and addition
 	///
 	/// # Arguments
-	/// * `Bq` - Any number-like type, representing a quantity of becquerels
-	pub fn from_Bq(Bq: T) -> Self { Radioactivity{Bq: Bq} }
-	
-	/// Returns a copy of this radioactivity value in becquerels
-	pub fn to_Bq(&self) -> T { self.Bq.clone() }
+	/// * `a` - The scalar value to multiply this radioactivity value by
+	/// * `b` - The radioactivity value to add to the product
+	pub fn mul_add(self, a: T, b: Self) -> Self {
+		Radioactivity{Bq: self.Bq.mul_add(a, b.Bq)}
+	}
 
-	/// Returns a new radioactivity value from the given number of becquerels
+	/// Returns true if this radioactivity value is neither infinite nor NaN
+	pub fn is_finite(&self) -> bool {
+		self.Bq.is_finite()
+	}
+
+	/// Returns true if this radioactivity value is positive or negative infinity
+	pub fn is_infinite(&self) -> bool {
+		self.Bq.is_infinite()
+	}
+
+	/// Returns true if this radioactivity value is NaN (not a number)
+	pub fn is_nan(&self) -> bool {
+		self.Bq.is_nan()
+	}
+
+	/// Returns true if this radioactivity value has a positive sign, including +0.0 
+	/// and positive infinity
+	pub fn is_sign_positive(&self) -> bool {
+		self.Bq.is_sign_positive()
+	}
+
+	/// Returns true if this radioactivity value has a negative sign, including -0.0 
+	/// and negative infinity
+	pub fn is_sign_negative(&self) -> bool {
+		self.Bq.is_sign_negative()
+	}
+
+	/// Returns a radioactivity value with the magnitude of this one and the sign of 
+	/// `sign_source`, eg for reflecting a value off of a surface
 	///
 	/// # Arguments
-	/// * `becquerels` - Any number-like type, representing a quantity of becquerels
-	pub fn from_becquerels(becquerels: T) -> Self { Radioactivity{Bq: becquerels} }
-	
-	/// Returns a copy of this radioactivity value in becquerels
-	pub fn to_becquerels(&self) -> T { self.Bq.clone() }
+	/// * `sign_source` - The radioactivity value whose sign is copied
+	pub fn copysign(self, sign_source: Self) -> Self {
+		Radioactivity{Bq: self.Bq.copysign(sign_source.Bq)}
+	}
+}
+
+#[cfg(feature="num-traits")]
+impl<T> Radioactivity<T> where T: NumLike+num_traits::CheckedAdd+num_traits::CheckedSub {
+
+	/// Adds another radioactivity value to this one, returning `None` if the 
+	/// addition overflows the number type (requires the `num-traits` feature)
+	pub fn checked_add(self, rhs: Self) -> Option<Self> {
+		Some(Radioactivity{Bq: self.Bq.checked_add(&rhs.Bq)?})
+	}
+
+	/// Subtracts another radioactivity value from this one, returning `None` if 
+	/// the subtraction overflows the number type (requires the `num-traits` feature)
+	pub fn checked_sub(self, rhs: Self) -> Option<Self> {
+		Some(Radioactivity{Bq: self.Bq.checked_sub(&rhs.Bq)?})
+	}
+}
+
+#[cfg(feature="num-traits")]
+impl<T> Radioactivity<T> where T: NumLike+num_traits::SaturatingAdd+num_traits::SaturatingSub {
+
+	/// Adds another radioactivity value to this one, clamping the result to the 
+	/// minimum or maximum value of the number type instead of overflowing 
+	/// (requires the `num-traits` feature)
+	pub fn saturating_add(self, rhs: Self) -> Self {
+		Radioactivity{Bq: self.Bq.saturating_add(&rhs.Bq)}
+	}
 
+	/// Subtracts another radioactivity value from this one, clamping the result 
+	/// to the minimum or maximum value of the number type instead of overflowing 
+	/// (requires the `num-traits` feature)
+	pub fn saturating_sub(self, rhs: Self) -> Self {
+		Radioactivity{Bq: self.Bq.saturating_sub(&rhs.Bq)}
+	}
 }
 
-impl<T> fmt::Display for Radioactivity<T> where T: NumLike {
-	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-		write!(f, "{} {}", &self.Bq, Self::unit_symbol())
+impl<T> Radioactivity<T> where T: NumLike+Into<f64> {
+
+	/// Returns true if this radioactivity value is equal to the given radioactivity 
+	/// value to within the given number of significant figures (eg 6 significant 
+	/// figures allows a relative difference of up to 1 part per million). NaN is 
+	/// only approximately equal to NaN, infinity is only approximately equal to 
+	/// infinity of the same sign, and zero is only approximately equal to zero.
+	///
+	/// # Arguments
+	/// * `other` - The radioactivity value to compare with this one
+	/// * `sig_figs` - The number of significant figures that must match
+	pub fn approx_eq(self, other: Self, sig_figs: i32) -> bool {
+		super::approx_eq_f64(self.Bq.into(), other.Bq.into(), sig_figs)
 	}
 }
 
 impl<T> Radioactivity<T> where T: NumLike+From<f64> {
+
+	/// Returns the linear interpolation between this radioactivity value and the given 
+	/// radioactivity value, ie `self*(1-t) + other*t`. The interpolation factor is not 
+	/// clamped, so values of `t` outside the range 0 to 1 extrapolate beyond the two 
+	/// values (see `lerp_clamped(...)`).
+	///
+	/// # Arguments
+	/// * `other` - The radioactivity value to interpolate towards (returned when `t` is 1)
+	/// * `t` - The interpolation factor, where 0 returns this value and 1 returns `other`
+	pub fn lerp(self, other: Self, t: f64) -> Self {
+		Radioactivity{Bq: self.Bq * T::from(1.0 - t) + other.Bq * T::from(t)}
+	}
+
+	/// Same as `lerp(...)`, except that the interpolation factor `t` is first clamped 
+	/// to the range 0 to 1, so that the result is always between the two values
+	///
+	/// # Arguments
+	/// * `other` - The radioactivity value to interpolate towards (returned when `t` is 1 or more)
+	/// * `t` - The interpolation factor, where 0 returns this value and 1 returns `other`
+	pub fn lerp_clamped(self, other: Self, t: f64) -> Self {
+		self.lerp(other, t.clamp(0.0, 1.0))
+	}
 	
 	/// Returns a copy of this radioactivity value in millibecquerels
 	/// 
@@ -2704,7 +13663,7 @@ impl<T> Radioactivity<T> where T: NumLike+From<f64> {
 	/// 
 	/// *Note: This method is not available for `f32` and other number types lacking the `From<f64>` trait*
 	pub fn to_Ci(&self) -> T {
-		return self.Bq.clone() * T::from(2.7027027027027e-11_f64);
+		return self.Bq.clone() * T::from(2.7027027027027027e-11_f64);
 	}
 
 	/// Returns a new radioactivity value from the given number of curies
@@ -2717,11 +13676,28 @@ impl<T> Radioactivity<T> where T: NumLike+From<f64> {
 		Radioactivity{Bq: Ci * T::from(37000000000.0_f64)}
 	}
 
+	/// Returns a copy of this radioactivity value in curies
+	/// 
+	/// *Note: This method is not available for `f32` and other number types lacking the `From<f64>` trait*
+	pub fn to_curies(&self) -> T {
+		return self.Bq.clone() * T::from(2.7027027027027027e-11_f64);
+	}
+
+	/// Returns a new radioactivity value from the given number of curies
+	/// 
+	/// *Note: This method is not available for `f32` and other number types lacking the `From<f64>` trait*
+	///
+	/// # Arguments
+	/// * `curies` - Any number-like type, representing a quantity of curies
+	pub fn from_curies(curies: T) -> Self {
+		Radioactivity{Bq: curies * T::from(37000000000.0_f64)}
+	}
+
 	/// Returns a copy of this radioactivity value in millicuries
 	/// 
 	/// *Note: This method is not available for `f32` and other number types lacking the `From<f64>` trait*
 	pub fn to_mCi(&self) -> T {
-		return self.Bq.clone() * T::from(2.7027027027027e-08_f64);
+		return self.Bq.clone() * T::from(2.7027027027027028e-08_f64);
 	}
 
 	/// Returns a new radioactivity value from the given number of millicuries
@@ -2738,7 +13714,7 @@ impl<T> Radioactivity<T> where T: NumLike+From<f64> {
 	/// 
 	/// *Note: This method is not available for `f32` and other number types lacking the `From<f64>` trait*
 	pub fn to_uCi(&self) -> T {
-		return self.Bq.clone() * T::from(2.7027027027027e-05_f64);
+		return self.Bq.clone() * T::from(2.7027027027027027e-05_f64);
 	}
 
 	/// Returns a new radioactivity value from the given number of microcuries
@@ -2755,7 +13731,7 @@ impl<T> Radioactivity<T> where T: NumLike+From<f64> {
 	/// 
 	/// *Note: This method is not available for `f32` and other number types lacking the `From<f64>` trait*
 	pub fn to_nCi(&self) -> T {
-		return self.Bq.clone() * T::from(0.027027027027027_f64);
+		return self.Bq.clone() * T::from(0.027027027027027025_f64);
 	}
 
 	/// Returns a new radioactivity value from the given number of nanocuries
@@ -2772,7 +13748,7 @@ impl<T> Radioactivity<T> where T: NumLike+From<f64> {
 	/// 
 	/// *Note: This method is not available for `f32` and other number types lacking the `From<f64>` trait*
 	pub fn to_pCi(&self) -> T {
-		return self.Bq.clone() * T::from(27.027027027027_f64);
+		return self.Bq.clone() * T::from(27.027027027027028_f64);
 	}
 
 	/// Returns a new radioactivity value from the given number of picocuries
@@ -2904,6 +13880,72 @@ impl core::ops::Mul<&Radioactivity<num_complex::Complex64>> for &num_complex::Co
 	}
 }
 
+/// Multiplying a unit value by a scalar value returns a unit value
+#[cfg(feature="num-rational")]
+impl core::ops::Mul<Radioactivity<num_rational::Rational64>> for num_rational::Rational64 {
+	type Output = Radioactivity<num_rational::Rational64>;
+	fn mul(self, rhs: Radioactivity<num_rational::Rational64>) -> Self::Output {
+		Radioactivity{Bq: self * rhs.Bq}
+	}
+}
+/// Multiplying a unit value by a scalar value returns a unit value
+#[cfg(feature="num-rational")]
+impl core::ops::Mul<Radioactivity<num_rational::Rational64>> for &num_rational::Rational64 {
+	type Output = Radioactivity<num_rational::Rational64>;
+	fn mul(self, rhs: Radioactivity<num_rational::Rational64>) -> Self::Output {
+		Radioactivity{Bq: self.clone() * rhs.Bq}
+	}
+}
+/// Multiplying a unit value by a scalar value returns a unit value
+#[cfg(feature="num-rational")]
+impl core::ops::Mul<&Radioactivity<num_rational::Rational64>> for num_rational::Rational64 {
+	type Output = Radioactivity<num_rational::Rational64>;
+	fn mul(self, rhs: &Radioactivity<num_rational::Rational64>) -> Self::Output {
+		Radioactivity{Bq: self * rhs.Bq.clone()}
+	}
+}
+/// Multiplying a unit value by a scalar value returns a unit value
+#[cfg(feature="num-rational")]
+impl core::ops::Mul<&Radioactivity<num_rational::Rational64>> for &num_rational::Rational64 {
+	type Output = Radioactivity<num_rational::Rational64>;
+	fn mul(self, rhs: &Radioactivity<num_rational::Rational64>) -> Self::Output {
+		Radioactivity{Bq: self.clone() * rhs.Bq.clone()}
+	}
+}
+
+/// Multiplying a unit value by a scalar value returns a unit value
+#[cfg(feature="num-rational")]
+impl core::ops::Mul<Radioactivity<num_rational::BigRational>> for num_rational::BigRational {
+	type Output = Radioactivity<num_rational::BigRational>;
+	fn mul(self, rhs: Radioactivity<num_rational::BigRational>) -> Self::Output {
+		Radioactivity{Bq: self * rhs.Bq}
+	}
+}
+/// Multiplying a unit value by a scalar value returns a unit value
+#[cfg(feature="num-rational")]
+impl core::ops::Mul<Radioactivity<num_rational::BigRational>> for &num_rational::BigRational {
+	type Output = Radioactivity<num_rational::BigRational>;
+	fn mul(self, rhs: Radioactivity<num_rational::BigRational>) -> Self::Output {
+		Radioactivity{Bq: self.clone() * rhs.Bq}
+	}
+}
+/// Multiplying a unit value by a scalar value returns a unit value
+#[cfg(feature="num-rational")]
+impl core::ops::Mul<&Radioactivity<num_rational::BigRational>> for num_rational::BigRational {
+	type Output = Radioactivity<num_rational::BigRational>;
+	fn mul(self, rhs: &Radioactivity<num_rational::BigRational>) -> Self::Output {
+		Radioactivity{Bq: self * rhs.Bq.clone()}
+	}
+}
+/// Multiplying a unit value by a scalar value returns a unit value
+#[cfg(feature="num-rational")]
+impl core::ops::Mul<&Radioactivity<num_rational::BigRational>> for &num_rational::BigRational {
+	type Output = Radioactivity<num_rational::BigRational>;
+	fn mul(self, rhs: &Radioactivity<num_rational::BigRational>) -> Self::Output {
+		Radioactivity{Bq: self.clone() * rhs.Bq.clone()}
+	}
+}
+
 
 
 /// Converts a Radioactivity into the equivalent [uom](https://crates.io/crates/uom) type [Radioactivity](https://docs.rs/uom/0.34.0/uom/si/f32/type.Radioactivity.html)
@@ -2939,6 +13981,69 @@ impl<T> From<uom::si::f64::Radioactivity> for Radioactivity<T> where T: NumLike+
 }
 
 
+
+/// Multiplying a Radioactivity by an [nalgebra](https://crates.io/crates/nalgebra) 
+/// vector or matrix of unitless values returns a vector or matrix of Radioactivity 
+/// values (eg a direction vector multiplied by a Radioactivity)
+#[cfg(feature="nalgebra")]
+impl<T, R, C, S> core::ops::Mul<nalgebra::Matrix<T, R, C, S>> for Radioactivity<T> where 
+	T: NumLike+nalgebra::Scalar, R: nalgebra::Dim, C: nalgebra::Dim, S: nalgebra::RawStorage<T, R, C>, 
+	nalgebra::DefaultAllocator: nalgebra::allocator::Allocator<R, C> {
+	type Output = nalgebra::OMatrix<Radioactivity<T>, R, C>;
+	fn mul(self, rhs: nalgebra::Matrix<T, R, C, S>) -> Self::Output {
+		rhs.map(|x| Radioactivity{Bq: self.Bq.clone() * x})
+	}
+}
+/// Multiplying an [nalgebra](https://crates.io/crates/nalgebra) vector or matrix of 
+/// unitless values by a Radioactivity returns a vector or matrix of Radioactivity values
+#[cfg(feature="nalgebra")]
+impl<T, R, C, S> core::ops::Mul<Radioactivity<T>> for nalgebra::Matrix<T, R, C, S> where 
+	T: NumLike+nalgebra::Scalar, R: nalgebra::Dim, C: nalgebra::Dim, S: nalgebra::RawStorage<T, R, C>, 
+	nalgebra::DefaultAllocator: nalgebra::allocator::Allocator<R, C> {
+	type Output = nalgebra::OMatrix<Radioactivity<T>, R, C>;
+	fn mul(self, rhs: Radioactivity<T>) -> Self::Output {
+		self.map(|x| Radioactivity{Bq: x * rhs.Bq.clone()})
+	}
+}
+/// Dividing an [nalgebra](https://crates.io/crates/nalgebra) vector or matrix of 
+/// Radioactivity values by a Radioactivity returns a vector or matrix of unitless values
+#[cfg(feature="nalgebra")]
+impl<T, R, C, S> core::ops::Div<Radioactivity<T>> for nalgebra::Matrix<Radioactivity<T>, R, C, S> where 
+	T: NumLike+nalgebra::Scalar, R: nalgebra::Dim, C: nalgebra::Dim, S: nalgebra::RawStorage<Radioactivity<T>, R, C>, 
+	nalgebra::DefaultAllocator: nalgebra::allocator::Allocator<R, C> {
+	type Output = nalgebra::OMatrix<T, R, C>;
+	fn div(self, rhs: Radioactivity<T>) -> Self::Output {
+		self.map(|x| x.Bq / rhs.Bq.clone())
+	}
+}
+
+
+impl<T> Radioactivity<T> where T: NumLike+PartialOrd+From<u8> {
+
+	/// Returns a new radioactivity value from the given number of becquerels, or a 
+	/// `DomainError` if the number is negative (or NaN), because radioactivity cannot 
+	/// physically be less than zero. Use `from_Bq(...)` to skip this check
+	///
+	/// # Arguments
+	/// * `Bq` - Any number-like type, representing a quantity of becquerels
+	pub fn try_from_Bq(Bq: T) -> Result<Self, super::DomainError> {
+		// NaN is not comparable to zero, so it is rejected along with negative values
+		match Bq.partial_cmp(&T::from(0u8)) {
+			Some(core::cmp::Ordering::Less) | None => Err(super::DomainError{unit_type: "Radioactivity"}),
+			_ => Ok(Radioactivity{Bq})
+		}
+	}
+}
+
+
+impl<T> Radioactivity<T> where T: NumLike+From<u8> {
+	/// Returns the reciprocal of this Radioactivity value (ie 1 divided by 
+	/// this value) as a value of type Time
+	pub fn recip(self) -> Time<T> {
+		Time{s: T::from(1u8) / self.Bq}
+	}
+}
+
 // 1/Radioactivity -> Time
 /// Dividing a scalar value by a Radioactivity unit value returns a value of type Time
 impl<T> core::ops::Div<Radioactivity<T>> for f64 where T: NumLike+From<f64> {
@@ -3161,5 +14266,89 @@ impl<T> core::ops::Div<&Radioactivity<T>> for &num_complex::Complex64 where T: N
 	}
 }
 
+// 1/Radioactivity -> Time
+/// Dividing a scalar value by a Radioactivity unit value returns a value of type Time
+#[cfg(feature="num-rational")]
+impl<T> core::ops::Div<Radioactivity<T>> for num_rational::Rational64 where T: NumLike+From<num_rational::Rational64> {
+	type Output = Time<T>;
+	fn div(self, rhs: Radioactivity<T>) -> Self::Output {
+		Time{s: T::from(self) / rhs.Bq}
+	}
+}
+/// Dividing a scalar value by a Radioactivity unit value returns a value of type Time
+#[cfg(feature="num-rational")]
+impl<T> core::ops::Div<Radioactivity<T>> for &num_rational::Rational64 where T: NumLike+From<num_rational::Rational64> {
+	type Output = Time<T>;
+	fn div(self, rhs: Radioactivity<T>) -> Self::Output {
+		Time{s: T::from(self.clone()) / rhs.Bq}
+	}
+}
+/// Dividing a scalar value by a Radioactivity unit value returns a value of type Time
+#[cfg(feature="num-rational")]
+impl<T> core::ops::Div<&Radioactivity<T>> for num_rational::Rational64 where T: NumLike+From<num_rational::Rational64> {
+	type Output = Time<T>;
+	fn div(self, rhs: &Radioactivity<T>) -> Self::Output {
+		Time{s: T::from(self) / rhs.Bq.clone()}
+	}
+}
+/// Dividing a scalar value by a Radioactivity unit value returns a value of type Time
+#[cfg(feature="num-rational")]
+impl<T> core::ops::Div<&Radioactivity<T>> for &num_rational::Rational64 where T: NumLike+From<num_rational::Rational64> {
+	type Output = Time<T>;
+	fn div(self, rhs: &Radioactivity<T>) -> Self::Output {
+		Time{s: T::from(self.clone()) / rhs.Bq.clone()}
+	}
+}
+
+// 1/Radioactivity -> Time
+/// Dividing a scalar value by a Radioactivity unit value returns a value of type Time
+#[cfg(feature="num-rational")]
+impl<T> core::ops::Div<Radioactivity<T>> for num_rational::BigRational where T: NumLike+From<num_rational::BigRational> {
+	type Output = Time<T>;
+	fn div(self, rhs: Radioactivity<T>) -> Self::Output {
+		Time{s: T::from(self) / rhs.Bq}
+	}
+}
+/// Dividing a scalar value by a Radioactivity unit value returns a value of type Time
+#[cfg(feature="num-rational")]
+impl<T> core::ops::Div<Radioactivity<T>> for &num_rational::BigRational where T: NumLike+From<num_rational::BigRational> {
+	type Output = Time<T>;
+	fn div(self, rhs: Radioactivity<T>) -> Self::Output {
+		Time{s: T::from(self.clone()) / rhs.Bq}
+	}
+}
+/// Dividing a scalar value by a Radioactivity unit value returns a value of type Time
+#[cfg(feature="num-rational")]
+impl<T> core::ops::Div<&Radioactivity<T>> for num_rational::BigRational where T: NumLike+From<num_rational::BigRational> {
+	type Output = Time<T>;
+	fn div(self, rhs: &Radioactivity<T>) -> Self::Output {
+		Time{s: T::from(self) / rhs.Bq.clone()}
+	}
+}
+/// Dividing a scalar value by a Radioactivity unit value returns a value of type Time
+#[cfg(feature="num-rational")]
+impl<T> core::ops::Div<&Radioactivity<T>> for &num_rational::BigRational where T: NumLike+From<num_rational::BigRational> {
+	type Output = Time<T>;
+	fn div(self, rhs: &Radioactivity<T>) -> Self::Output {
+		Time{s: T::from(self.clone()) / rhs.Bq.clone()}
+	}
+}
+
+
+
+/// Exponential decay of radioactive sources
+#[cfg(all(feature="num-traits", any(feature="std", feature="libm")))]
+impl<T> Radioactivity<T> where T: NumLike+num_traits::Float {
 
+	/// Returns the activity of this radioactive source after the given time has 
+	/// elapsed, ie A × 0.5^(t/t½) (requires the `num-traits` feature, and either 
+	/// the `std` or `libm` feature)
+	///
+	/// # Arguments
+	/// * `half_life` - The half-life of the radioactive isotope
+	/// * `elapsed` - The time that has passed since this activity was measured
+	pub fn after(self, half_life: Time<T>, elapsed: Time<T>) -> Self {
+		Radioactivity{Bq: self.Bq * (-(elapsed.s / half_life.s)).exp2()}
+	}
+}
 
@@ -0,0 +1,6412 @@
+
+//! This module provides the `Quantity` enum, which can hold a value of any of 
+//! the unit types, for when the unit type is only known at runtime (such as in
+//! a calculator or scripting language).
+use core::fmt;
+use super::NumLike;
+use super::base::*;
+use super::chemical::*;
+use super::electromagnetic::*;
+use super::geometry::*;
+use super::mechanical::*;
+use super::nuclear::*;
+
+/// A `Quantity` holds a value of any of the unit types, with the unit type 
+/// checked at runtime instead of compile time. Adding or subtracting two 
+/// quantities only succeeds if they are the same unit type, and multiplying or 
+/// dividing two quantities only succeeds if the unit structs themselves 
+/// support that operation (eg `Distance / Time -> Velocity`). Dimensionless 
+/// numbers, such as the ratio of two values of the same unit type, are held by
+/// the `Scalar` variant.
+/// 
+/// # Examples
+/// ```rust
+/// use simple_si_units::quantity::Quantity;
+/// use simple_si_units::base::*;
+/// use simple_si_units::mechanical::Velocity;
+/// 
+/// let d = Quantity::from(Distance::from_m(100.0));
+/// let t = Quantity::from(Time::from_s(20.0));
+/// assert_eq!(d.try_div(&t).unwrap(), Quantity::from(Velocity::from_mps(5.0)));
+/// assert!(d.try_add(&t).is_err());
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub enum Quantity<T: NumLike> {
+	/// A dimensionless number
+	Scalar(T),
+	/// A amount value
+	Amount(Amount<T>),
+	/// A electrical current value
+	Current(Current<T>),
+	/// A distance value
+	Distance(Distance<T>),
+	/// A inverse amount value
+	InverseAmount(InverseAmount<T>),
+	/// A inverse electrical current value
+	InverseCurrent(InverseCurrent<T>),
+	/// A inverse distance value
+	InverseDistance(InverseDistance<T>),
+	/// A inverse luminosity value
+	InverseLuminosity(InverseLuminosity<T>),
+	/// A inverse mass value
+	InverseMass(InverseMass<T>),
+	/// A inverse temperature value
+	InverseTemperature(InverseTemperature<T>),
+	/// A luminosity value
+	Luminosity(Luminosity<T>),
+	/// A mass value
+	Mass(Mass<T>),
+	/// A temperature value
+	Temperature(Temperature<T>),
+	/// A temperature interval value
+	TemperatureInterval(TemperatureInterval<T>),
+	/// A time value
+	Time(Time<T>),
+	/// A catalytic activity value
+	CatalyticActivity(CatalyticActivity<T>),
+	/// A chemical concentration value
+	Concentration(Concentration<T>),
+	/// A entropy value
+	Entropy(Entropy<T>),
+	/// A heat capacity value
+	HeatCapacity(HeatCapacity<T>),
+	/// A inverse catalytic activity value
+	InverseCatalyticActivity(InverseCatalyticActivity<T>),
+	/// A inverse entropy value
+	InverseEntropy(InverseEntropy<T>),
+	/// A inverse heat capacity value
+	InverseHeatCapacity(InverseHeatCapacity<T>),
+	/// A inverse molar energy value
+	InverseMolarEnergy(InverseMolarEnergy<T>),
+	/// A inverse molar entropy value
+	InverseMolarEntropy(InverseMolarEntropy<T>),
+	/// A inverse specific heat capacity value
+	InverseSpecificHeatCapacity(InverseSpecificHeatCapacity<T>),
+	/// A molality value
+	Molality(Molality<T>),
+	/// A molar energy value
+	MolarEnergy(MolarEnergy<T>),
+	/// A molar entropy value
+	MolarEntropy(MolarEntropy<T>),
+	/// A molar mass value
+	MolarMass(MolarMass<T>),
+	/// A molar volume value
+	MolarVolume(MolarVolume<T>),
+	/// A specific heat capacity value
+	SpecificHeatCapacity(SpecificHeatCapacity<T>),
+	/// A thermal conductance value
+	ThermalConductance(ThermalConductance<T>),
+	/// A thermal conductivity value
+	ThermalConductivity(ThermalConductivity<T>),
+	/// A thermal resistance value
+	ThermalResistance(ThermalResistance<T>),
+	/// A thermal resistivity value
+	ThermalResistivity(ThermalResistivity<T>),
+	/// A area per lumen value
+	AreaPerLumen(AreaPerLumen<T>),
+	/// A electrical capacitance value
+	Capacitance(Capacitance<T>),
+	/// A electric charge value
+	Charge(Charge<T>),
+	/// A electrical conductance value
+	Conductance(Conductance<T>),
+	/// A electrical elastance value
+	Elastance(Elastance<T>),
+	/// A electric field strength value
+	ElectricField(ElectricField<T>),
+	/// A illuminance value
+	Illuminance(Illuminance<T>),
+	/// A inductance value
+	Inductance(Inductance<T>),
+	/// A inverse electric charge value
+	InverseCharge(InverseCharge<T>),
+	/// A inverse electric field strength value
+	InverseElectricField(InverseElectricField<T>),
+	/// A inverse inductance value
+	InverseInductance(InverseInductance<T>),
+	/// A inverse irradiance value
+	InverseIrradiance(InverseIrradiance<T>),
+	/// A inverse linear charge density value
+	InverseLinearChargeDensity(InverseLinearChargeDensity<T>),
+	/// A inverse luminous flux value
+	InverseLuminousFlux(InverseLuminousFlux<T>),
+	/// A inverse magnetic field strength value
+	InverseMagneticFieldStrength(InverseMagneticFieldStrength<T>),
+	/// A inverse magnetic flux value
+	InverseMagneticFlux(InverseMagneticFlux<T>),
+	/// A inverse magnetic flux density value
+	InverseMagneticFluxDensity(InverseMagneticFluxDensity<T>),
+	/// A inverse radiance value
+	InverseRadiance(InverseRadiance<T>),
+	/// A inverse surface charge density value
+	InverseSurfaceChargeDensity(InverseSurfaceChargeDensity<T>),
+	/// A inverse voltage value
+	InverseVoltage(InverseVoltage<T>),
+	/// A inverse volume charge density value
+	InverseVolumeChargeDensity(InverseVolumeChargeDensity<T>),
+	/// A irradiance value
+	Irradiance(Irradiance<T>),
+	/// A linear charge density value
+	LinearChargeDensity(LinearChargeDensity<T>),
+	/// A luminous flux value
+	LuminousFlux(LuminousFlux<T>),
+	/// A magnetic field strength value
+	MagneticFieldStrength(MagneticFieldStrength<T>),
+	/// A magnetic flux value
+	MagneticFlux(MagneticFlux<T>),
+	/// A magnetic flux density value
+	MagneticFluxDensity(MagneticFluxDensity<T>),
+	/// A radiance value
+	Radiance(Radiance<T>),
+	/// A electrical resistance value
+	Resistance(Resistance<T>),
+	/// A surface charge density value
+	SurfaceChargeDensity(SurfaceChargeDensity<T>),
+	/// A voltage value
+	Voltage(Voltage<T>),
+	/// A volume charge density value
+	VolumeChargeDensity(VolumeChargeDensity<T>),
+	/// A angle value
+	Angle(Angle<T>),
+	/// A area value
+	Area(Area<T>),
+	/// A inverse angle value
+	InverseAngle(InverseAngle<T>),
+	/// A inverse area value
+	InverseArea(InverseArea<T>),
+	/// A inverse solid angle value
+	InverseSolidAngle(InverseSolidAngle<T>),
+	/// A inverse volume value
+	InverseVolume(InverseVolume<T>),
+	/// A solid angle value
+	SolidAngle(SolidAngle<T>),
+	/// A volume value
+	Volume(Volume<T>),
+	/// A acceleration value
+	Acceleration(Acceleration<T>),
+	/// A angular acceleration value
+	AngularAcceleration(AngularAcceleration<T>),
+	/// A angular momentum value
+	AngularMomentum(AngularMomentum<T>),
+	/// A angular velocity value
+	AngularVelocity(AngularVelocity<T>),
+	/// A area density value
+	AreaDensity(AreaDensity<T>),
+	/// A area per mass value
+	AreaPerMass(AreaPerMass<T>),
+	/// A density value
+	Density(Density<T>),
+	/// A dynamic viscosity value
+	DynamicViscosity(DynamicViscosity<T>),
+	/// A energy value
+	Energy(Energy<T>),
+	/// A force value
+	Force(Force<T>),
+	/// A frequency value
+	Frequency(Frequency<T>),
+	/// A inverse acceleration value
+	InverseAcceleration(InverseAcceleration<T>),
+	/// A inverse angular acceleration value
+	InverseAngularAcceleration(InverseAngularAcceleration<T>),
+	/// A inverse angular momentum value
+	InverseAngularMomentum(InverseAngularMomentum<T>),
+	/// A inverse angular velocity value
+	InverseAngularVelocity(InverseAngularVelocity<T>),
+	/// A inverse dynamic viscosity value
+	InverseDynamicViscosity(InverseDynamicViscosity<T>),
+	/// A inverse energy value
+	InverseEnergy(InverseEnergy<T>),
+	/// A inverse force value
+	InverseForce(InverseForce<T>),
+	/// A inverse jerk value
+	InverseJerk(InverseJerk<T>),
+	/// A inverse kinematic viscosity value
+	InverseKinematicViscosity(InverseKinematicViscosity<T>),
+	/// A inverse mass flow rate value
+	InverseMassFlowRate(InverseMassFlowRate<T>),
+	/// A inverse moment of inertia value
+	InverseMomentOfInertia(InverseMomentOfInertia<T>),
+	/// A inverse momentum value
+	InverseMomentum(InverseMomentum<T>),
+	/// A inverse power value
+	InversePower(InversePower<T>),
+	/// A inverse pressure value
+	InversePressure(InversePressure<T>),
+	/// A inverse torque value
+	InverseTorque(InverseTorque<T>),
+	/// A inverse volumetric flow rate value
+	InverseVolumetricFlowRate(InverseVolumetricFlowRate<T>),
+	/// A jerk value
+	Jerk(Jerk<T>),
+	/// A kinematic viscosity value
+	KinematicViscosity(KinematicViscosity<T>),
+	/// A mass flow rate value
+	MassFlowRate(MassFlowRate<T>),
+	/// A moment of inertia value
+	MomentOfInertia(MomentOfInertia<T>),
+	/// A momentum value
+	Momentum(Momentum<T>),
+	/// A power value
+	Power(Power<T>),
+	/// A pressure value
+	Pressure(Pressure<T>),
+	/// A time per distance value
+	TimePerDistance(TimePerDistance<T>),
+	/// A torque value
+	Torque(Torque<T>),
+	/// A velocity value
+	Velocity(Velocity<T>),
+	/// A volume per mass value
+	VolumePerMass(VolumePerMass<T>),
+	/// A volumetric flow rate value
+	VolumetricFlowRate(VolumetricFlowRate<T>),
+	/// A absorbed dose value
+	AbsorbedDose(AbsorbedDose<T>),
+	/// A absorbed dose rate value
+	AbsorbedDoseRate(AbsorbedDoseRate<T>),
+	/// A dose equivalent value
+	DoseEquivalent(DoseEquivalent<T>),
+	/// A dose equivalent rate value
+	DoseEquivalentRate(DoseEquivalentRate<T>),
+	/// A inverse absorbed dose value
+	InverseAbsorbedDose(InverseAbsorbedDose<T>),
+	/// A inverse absorbed dose rate value
+	InverseAbsorbedDoseRate(InverseAbsorbedDoseRate<T>),
+	/// A inverse dose equivalent value
+	InverseDoseEquivalent(InverseDoseEquivalent<T>),
+	/// A inverse dose equivalent rate value
+	InverseDoseEquivalentRate(InverseDoseEquivalentRate<T>),
+	/// A radioactivity value
+	Radioactivity(Radioactivity<T>),
+}
+
+/// The error returned when an operation on `Quantity` values is not 
+/// dimensionally valid, eg adding a distance to a time
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DimensionError {
+	/// The operation that failed, eg "add"
+	pub operation: &'static str,
+	/// The unit symbol of the left-hand quantity
+	pub left: &'static str,
+	/// The unit symbol of the right-hand quantity (or the unit type that was 
+	/// requested, when converting a `Quantity` to a unit struct)
+	pub right: &'static str
+}
+
+impl fmt::Display for DimensionError {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		write!(f, "cannot {} quantities with units '{}' and '{}'", self.operation, self.left, self.right)
+	}
+}
+
+impl<T> Quantity<T> where T: NumLike {
+
+	/// Returns the unit symbol of this quantity (or an empty string for a `Scalar`)
+	pub fn unit_symbol(&self) -> &'static str {
+		match self {
+			Quantity::Scalar(_) => "",
+			Quantity::Amount(_) => Amount::<T>::unit_symbol(),
+			Quantity::Current(_) => Current::<T>::unit_symbol(),
+			Quantity::Distance(_) => Distance::<T>::unit_symbol(),
+			Quantity::InverseAmount(_) => InverseAmount::<T>::unit_symbol(),
+			Quantity::InverseCurrent(_) => InverseCurrent::<T>::unit_symbol(),
+			Quantity::InverseDistance(_) => InverseDistance::<T>::unit_symbol(),
+			Quantity::InverseLuminosity(_) => InverseLuminosity::<T>::unit_symbol(),
+			Quantity::InverseMass(_) => InverseMass::<T>::unit_symbol(),
+			Quantity::InverseTemperature(_) => InverseTemperature::<T>::unit_symbol(),
+			Quantity::Luminosity(_) => Luminosity::<T>::unit_symbol(),
+			Quantity::Mass(_) => Mass::<T>::unit_symbol(),
+			Quantity::Temperature(_) => Temperature::<T>::unit_symbol(),
+			Quantity::TemperatureInterval(_) => TemperatureInterval::<T>::unit_symbol(),
+			Quantity::Time(_) => Time::<T>::unit_symbol(),
+			Quantity::CatalyticActivity(_) => CatalyticActivity::<T>::unit_symbol(),
+			Quantity::Concentration(_) => Concentration::<T>::unit_symbol(),
+			Quantity::Entropy(_) => Entropy::<T>::unit_symbol(),
+			Quantity::HeatCapacity(_) => HeatCapacity::<T>::unit_symbol(),
+			Quantity::InverseCatalyticActivity(_) => InverseCatalyticActivity::<T>::unit_symbol(),
+			Quantity::InverseEntropy(_) => InverseEntropy::<T>::unit_symbol(),
+			Quantity::InverseHeatCapacity(_) => InverseHeatCapacity::<T>::unit_symbol(),
+			Quantity::InverseMolarEnergy(_) => InverseMolarEnergy::<T>::unit_symbol(),
+			Quantity::InverseMolarEntropy(_) => InverseMolarEntropy::<T>::unit_symbol(),
+			Quantity::InverseSpecificHeatCapacity(_) => InverseSpecificHeatCapacity::<T>::unit_symbol(),
+			Quantity::Molality(_) => Molality::<T>::unit_symbol(),
+			Quantity::MolarEnergy(_) => MolarEnergy::<T>::unit_symbol(),
+			Quantity::MolarEntropy(_) => MolarEntropy::<T>::unit_symbol(),
+			Quantity::MolarMass(_) => MolarMass::<T>::unit_symbol(),
+			Quantity::MolarVolume(_) => MolarVolume::<T>::unit_symbol(),
+			Quantity::SpecificHeatCapacity(_) => SpecificHeatCapacity::<T>::unit_symbol(),
+			Quantity::ThermalConductance(_) => ThermalConductance::<T>::unit_symbol(),
+			Quantity::ThermalConductivity(_) => ThermalConductivity::<T>::unit_symbol(),
+			Quantity::ThermalResistance(_) => ThermalResistance::<T>::unit_symbol(),
+			Quantity::ThermalResistivity(_) => ThermalResistivity::<T>::unit_symbol(),
+			Quantity::AreaPerLumen(_) => AreaPerLumen::<T>::unit_symbol(),
+			Quantity::Capacitance(_) => Capacitance::<T>::unit_symbol(),
+			Quantity::Charge(_) => Charge::<T>::unit_symbol(),
+			Quantity::Conductance(_) => Conductance::<T>::unit_symbol(),
+			Quantity::Elastance(_) => Elastance::<T>::unit_symbol(),
+			Quantity::ElectricField(_) => ElectricField::<T>::unit_symbol(),
+			Quantity::Illuminance(_) => Illuminance::<T>::unit_symbol(),
+			Quantity::Inductance(_) => Inductance::<T>::unit_symbol(),
+			Quantity::InverseCharge(_) => InverseCharge::<T>::unit_symbol(),
+			Quantity::InverseElectricField(_) => InverseElectricField::<T>::unit_symbol(),
+			Quantity::InverseInductance(_) => InverseInductance::<T>::unit_symbol(),
+			Quantity::InverseIrradiance(_) => InverseIrradiance::<T>::unit_symbol(),
+			Quantity::InverseLinearChargeDensity(_) => InverseLinearChargeDensity::<T>::unit_symbol(),
+			Quantity::InverseLuminousFlux(_) => InverseLuminousFlux::<T>::unit_symbol(),
+			Quantity::InverseMagneticFieldStrength(_) => InverseMagneticFieldStrength::<T>::unit_symbol(),
+			Quantity::InverseMagneticFlux(_) => InverseMagneticFlux::<T>::unit_symbol(),
+			Quantity::InverseMagneticFluxDensity(_) => InverseMagneticFluxDensity::<T>::unit_symbol(),
+			Quantity::InverseRadiance(_) => InverseRadiance::<T>::unit_symbol(),
+			Quantity::InverseSurfaceChargeDensity(_) => InverseSurfaceChargeDensity::<T>::unit_symbol(),
+			Quantity::InverseVoltage(_) => InverseVoltage::<T>::unit_symbol(),
+			Quantity::InverseVolumeChargeDensity(_) => InverseVolumeChargeDensity::<T>::unit_symbol(),
+			Quantity::Irradiance(_) => Irradiance::<T>::unit_symbol(),
+			Quantity::LinearChargeDensity(_) => LinearChargeDensity::<T>::unit_symbol(),
+			Quantity::LuminousFlux(_) => LuminousFlux::<T>::unit_symbol(),
+			Quantity::MagneticFieldStrength(_) => MagneticFieldStrength::<T>::unit_symbol(),
+			Quantity::MagneticFlux(_) => MagneticFlux::<T>::unit_symbol(),
+			Quantity::MagneticFluxDensity(_) => MagneticFluxDensity::<T>::unit_symbol(),
+			Quantity::Radiance(_) => Radiance::<T>::unit_symbol(),
+			Quantity::Resistance(_) => Resistance::<T>::unit_symbol(),
+			Quantity::SurfaceChargeDensity(_) => SurfaceChargeDensity::<T>::unit_symbol(),
+			Quantity::Voltage(_) => Voltage::<T>::unit_symbol(),
+			Quantity::VolumeChargeDensity(_) => VolumeChargeDensity::<T>::unit_symbol(),
+			Quantity::Angle(_) => Angle::<T>::unit_symbol(),
+			Quantity::Area(_) => Area::<T>::unit_symbol(),
+			Quantity::InverseAngle(_) => InverseAngle::<T>::unit_symbol(),
+			Quantity::InverseArea(_) => InverseArea::<T>::unit_symbol(),
+			Quantity::InverseSolidAngle(_) => InverseSolidAngle::<T>::unit_symbol(),
+			Quantity::InverseVolume(_) => InverseVolume::<T>::unit_symbol(),
+			Quantity::SolidAngle(_) => SolidAngle::<T>::unit_symbol(),
+			Quantity::Volume(_) => Volume::<T>::unit_symbol(),
+			Quantity::Acceleration(_) => Acceleration::<T>::unit_symbol(),
+			Quantity::AngularAcceleration(_) => AngularAcceleration::<T>::unit_symbol(),
+			Quantity::AngularMomentum(_) => AngularMomentum::<T>::unit_symbol(),
+			Quantity::AngularVelocity(_) => AngularVelocity::<T>::unit_symbol(),
+			Quantity::AreaDensity(_) => AreaDensity::<T>::unit_symbol(),
+			Quantity::AreaPerMass(_) => AreaPerMass::<T>::unit_symbol(),
+			Quantity::Density(_) => Density::<T>::unit_symbol(),
+			Quantity::DynamicViscosity(_) => DynamicViscosity::<T>::unit_symbol(),
+			Quantity::Energy(_) => Energy::<T>::unit_symbol(),
+			Quantity::Force(_) => Force::<T>::unit_symbol(),
+			Quantity::Frequency(_) => Frequency::<T>::unit_symbol(),
+			Quantity::InverseAcceleration(_) => InverseAcceleration::<T>::unit_symbol(),
+			Quantity::InverseAngularAcceleration(_) => InverseAngularAcceleration::<T>::unit_symbol(),
+			Quantity::InverseAngularMomentum(_) => InverseAngularMomentum::<T>::unit_symbol(),
+			Quantity::InverseAngularVelocity(_) => InverseAngularVelocity::<T>::unit_symbol(),
+			Quantity::InverseDynamicViscosity(_) => InverseDynamicViscosity::<T>::unit_symbol(),
+			Quantity::InverseEnergy(_) => InverseEnergy::<T>::unit_symbol(),
+			Quantity::InverseForce(_) => InverseForce::<T>::unit_symbol(),
+			Quantity::InverseJerk(_) => InverseJerk::<T>::unit_symbol(),
+			Quantity::InverseKinematicViscosity(_) => InverseKinematicViscosity::<T>::unit_symbol(),
+			Quantity::InverseMassFlowRate(_) => InverseMassFlowRate::<T>::unit_symbol(),
+			Quantity::InverseMomentOfInertia(_) => InverseMomentOfInertia::<T>::unit_symbol(),
+			Quantity::InverseMomentum(_) => InverseMomentum::<T>::unit_symbol(),
+			Quantity::InversePower(_) => InversePower::<T>::unit_symbol(),
+			Quantity::InversePressure(_) => InversePressure::<T>::unit_symbol(),
+			Quantity::InverseTorque(_) => InverseTorque::<T>::unit_symbol(),
+			Quantity::InverseVolumetricFlowRate(_) => InverseVolumetricFlowRate::<T>::unit_symbol(),
+			Quantity::Jerk(_) => Jerk::<T>::unit_symbol(),
+			Quantity::KinematicViscosity(_) => KinematicViscosity::<T>::unit_symbol(),
+			Quantity::MassFlowRate(_) => MassFlowRate::<T>::unit_symbol(),
+			Quantity::MomentOfInertia(_) => MomentOfInertia::<T>::unit_symbol(),
+			Quantity::Momentum(_) => Momentum::<T>::unit_symbol(),
+			Quantity::Power(_) => Power::<T>::unit_symbol(),
+			Quantity::Pressure(_) => Pressure::<T>::unit_symbol(),
+			Quantity::TimePerDistance(_) => TimePerDistance::<T>::unit_symbol(),
+			Quantity::Torque(_) => Torque::<T>::unit_symbol(),
+			Quantity::Velocity(_) => Velocity::<T>::unit_symbol(),
+			Quantity::VolumePerMass(_) => VolumePerMass::<T>::unit_symbol(),
+			Quantity::VolumetricFlowRate(_) => VolumetricFlowRate::<T>::unit_symbol(),
+			Quantity::AbsorbedDose(_) => AbsorbedDose::<T>::unit_symbol(),
+			Quantity::AbsorbedDoseRate(_) => AbsorbedDoseRate::<T>::unit_symbol(),
+			Quantity::DoseEquivalent(_) => DoseEquivalent::<T>::unit_symbol(),
+			Quantity::DoseEquivalentRate(_) => DoseEquivalentRate::<T>::unit_symbol(),
+			Quantity::InverseAbsorbedDose(_) => InverseAbsorbedDose::<T>::unit_symbol(),
+			Quantity::InverseAbsorbedDoseRate(_) => InverseAbsorbedDoseRate::<T>::unit_symbol(),
+			Quantity::InverseDoseEquivalent(_) => InverseDoseEquivalent::<T>::unit_symbol(),
+			Quantity::InverseDoseEquivalentRate(_) => InverseDoseEquivalentRate::<T>::unit_symbol(),
+			Quantity::Radioactivity(_) => Radioactivity::<T>::unit_symbol(),
+		}
+	}
+
+	/// Returns the standard unit name of this quantity (or an empty string for a `Scalar`)
+	pub fn unit_name(&self) -> &'static str {
+		match self {
+			Quantity::Scalar(_) => "",
+			Quantity::Amount(_) => Amount::<T>::unit_name(),
+			Quantity::Current(_) => Current::<T>::unit_name(),
+			Quantity::Distance(_) => Distance::<T>::unit_name(),
+			Quantity::InverseAmount(_) => InverseAmount::<T>::unit_name(),
+			Quantity::InverseCurrent(_) => InverseCurrent::<T>::unit_name(),
+			Quantity::InverseDistance(_) => InverseDistance::<T>::unit_name(),
+			Quantity::InverseLuminosity(_) => InverseLuminosity::<T>::unit_name(),
+			Quantity::InverseMass(_) => InverseMass::<T>::unit_name(),
+			Quantity::InverseTemperature(_) => InverseTemperature::<T>::unit_name(),
+			Quantity::Luminosity(_) => Luminosity::<T>::unit_name(),
+			Quantity::Mass(_) => Mass::<T>::unit_name(),
+			Quantity::Temperature(_) => Temperature::<T>::unit_name(),
+			Quantity::TemperatureInterval(_) => TemperatureInterval::<T>::unit_name(),
+			Quantity::Time(_) => Time::<T>::unit_name(),
+			Quantity::CatalyticActivity(_) => CatalyticActivity::<T>::unit_name(),
+			Quantity::Concentration(_) => Concentration::<T>::unit_name(),
+			Quantity::Entropy(_) => Entropy::<T>::unit_name(),
+			Quantity::HeatCapacity(_) => HeatCapacity::<T>::unit_name(),
+			Quantity::InverseCatalyticActivity(_) => InverseCatalyticActivity::<T>::unit_name(),
+			Quantity::InverseEntropy(_) => InverseEntropy::<T>::unit_name(),
+			Quantity::InverseHeatCapacity(_) => InverseHeatCapacity::<T>::unit_name(),
+			Quantity::InverseMolarEnergy(_) => InverseMolarEnergy::<T>::unit_name(),
+			Quantity::InverseMolarEntropy(_) => InverseMolarEntropy::<T>::unit_name(),
+			Quantity::InverseSpecificHeatCapacity(_) => InverseSpecificHeatCapacity::<T>::unit_name(),
+			Quantity::Molality(_) => Molality::<T>::unit_name(),
+			Quantity::MolarEnergy(_) => MolarEnergy::<T>::unit_name(),
+			Quantity::MolarEntropy(_) => MolarEntropy::<T>::unit_name(),
+			Quantity::MolarMass(_) => MolarMass::<T>::unit_name(),
+			Quantity::MolarVolume(_) => MolarVolume::<T>::unit_name(),
+			Quantity::SpecificHeatCapacity(_) => SpecificHeatCapacity::<T>::unit_name(),
+			Quantity::ThermalConductance(_) => ThermalConductance::<T>::unit_name(),
+			Quantity::ThermalConductivity(_) => ThermalConductivity::<T>::unit_name(),
+			Quantity::ThermalResistance(_) => ThermalResistance::<T>::unit_name(),
+			Quantity::ThermalResistivity(_) => ThermalResistivity::<T>::unit_name(),
+			Quantity::AreaPerLumen(_) => AreaPerLumen::<T>::unit_name(),
+			Quantity::Capacitance(_) => Capacitance::<T>::unit_name(),
+			Quantity::Charge(_) => Charge::<T>::unit_name(),
+			Quantity::Conductance(_) => Conductance::<T>::unit_name(),
+			Quantity::Elastance(_) => Elastance::<T>::unit_name(),
+			Quantity::ElectricField(_) => ElectricField::<T>::unit_name(),
+			Quantity::Illuminance(_) => Illuminance::<T>::unit_name(),
+			Quantity::Inductance(_) => Inductance::<T>::unit_name(),
+			Quantity::InverseCharge(_) => InverseCharge::<T>::unit_name(),
+			Quantity::InverseElectricField(_) => InverseElectricField::<T>::unit_name(),
+			Quantity::InverseInductance(_) => InverseInductance::<T>::unit_name(),
+			Quantity::InverseIrradiance(_) => InverseIrradiance::<T>::unit_name(),
+			Quantity::InverseLinearChargeDensity(_) => InverseLinearChargeDensity::<T>::unit_name(),
+			Quantity::InverseLuminousFlux(_) => InverseLuminousFlux::<T>::unit_name(),
+			Quantity::InverseMagneticFieldStrength(_) => InverseMagneticFieldStrength::<T>::unit_name(),
+			Quantity::InverseMagneticFlux(_) => InverseMagneticFlux::<T>::unit_name(),
+			Quantity::InverseMagneticFluxDensity(_) => InverseMagneticFluxDensity::<T>::unit_name(),
+			Quantity::InverseRadiance(_) => InverseRadiance::<T>::unit_name(),
+			Quantity::InverseSurfaceChargeDensity(_) => InverseSurfaceChargeDensity::<T>::unit_name(),
+			Quantity::InverseVoltage(_) => InverseVoltage::<T>::unit_name(),
+			Quantity::InverseVolumeChargeDensity(_) => InverseVolumeChargeDensity::<T>::unit_name(),
+			Quantity::Irradiance(_) => Irradiance::<T>::unit_name(),
+			Quantity::LinearChargeDensity(_) => LinearChargeDensity::<T>::unit_name(),
+			Quantity::LuminousFlux(_) => LuminousFlux::<T>::unit_name(),
+			Quantity::MagneticFieldStrength(_) => MagneticFieldStrength::<T>::unit_name(),
+			Quantity::MagneticFlux(_) => MagneticFlux::<T>::unit_name(),
+			Quantity::MagneticFluxDensity(_) => MagneticFluxDensity::<T>::unit_name(),
+			Quantity::Radiance(_) => Radiance::<T>::unit_name(),
+			Quantity::Resistance(_) => Resistance::<T>::unit_name(),
+			Quantity::SurfaceChargeDensity(_) => SurfaceChargeDensity::<T>::unit_name(),
+			Quantity::Voltage(_) => Voltage::<T>::unit_name(),
+			Quantity::VolumeChargeDensity(_) => VolumeChargeDensity::<T>::unit_name(),
+			Quantity::Angle(_) => Angle::<T>::unit_name(),
+			Quantity::Area(_) => Area::<T>::unit_name(),
+			Quantity::InverseAngle(_) => InverseAngle::<T>::unit_name(),
+			Quantity::InverseArea(_) => InverseArea::<T>::unit_name(),
+			Quantity::InverseSolidAngle(_) => InverseSolidAngle::<T>::unit_name(),
+			Quantity::InverseVolume(_) => InverseVolume::<T>::unit_name(),
+			Quantity::SolidAngle(_) => SolidAngle::<T>::unit_name(),
+			Quantity::Volume(_) => Volume::<T>::unit_name(),
+			Quantity::Acceleration(_) => Acceleration::<T>::unit_name(),
+			Quantity::AngularAcceleration(_) => AngularAcceleration::<T>::unit_name(),
+			Quantity::AngularMomentum(_) => AngularMomentum::<T>::unit_name(),
+			Quantity::AngularVelocity(_) => AngularVelocity::<T>::unit_name(),
+			Quantity::AreaDensity(_) => AreaDensity::<T>::unit_name(),
+			Quantity::AreaPerMass(_) => AreaPerMass::<T>::unit_name(),
+			Quantity::Density(_) => Density::<T>::unit_name(),
+			Quantity::DynamicViscosity(_) => DynamicViscosity::<T>::unit_name(),
+			Quantity::Energy(_) => Energy::<T>::unit_name(),
+			Quantity::Force(_) => Force::<T>::unit_name(),
+			Quantity::Frequency(_) => Frequency::<T>::unit_name(),
+			Quantity::InverseAcceleration(_) => InverseAcceleration::<T>::unit_name(),
+			Quantity::InverseAngularAcceleration(_) => InverseAngularAcceleration::<T>::unit_name(),
+			Quantity::InverseAngularMomentum(_) => InverseAngularMomentum::<T>::unit_name(),
+			Quantity::InverseAngularVelocity(_) => InverseAngularVelocity::<T>::unit_name(),
+			Quantity::InverseDynamicViscosity(_) => InverseDynamicViscosity::<T>::unit_name(),
+			Quantity::InverseEnergy(_) => InverseEnergy::<T>::unit_name(),
+			Quantity::InverseForce(_) => InverseForce::<T>::unit_name(),
+			Quantity::InverseJerk(_) => InverseJerk::<T>::unit_name(),
+			Quantity::InverseKinematicViscosity(_) => InverseKinematicViscosity::<T>::unit_name(),
+			Quantity::InverseMassFlowRate(_) => InverseMassFlowRate::<T>::unit_name(),
+			Quantity::InverseMomentOfInertia(_) => InverseMomentOfInertia::<T>::unit_name(),
+			Quantity::InverseMomentum(_) => InverseMomentum::<T>::unit_name(),
+			Quantity::InversePower(_) => InversePower::<T>::unit_name(),
+			Quantity::InversePressure(_) => InversePressure::<T>::unit_name(),
+			Quantity::InverseTorque(_) => InverseTorque::<T>::unit_name(),
+			Quantity::InverseVolumetricFlowRate(_) => InverseVolumetricFlowRate::<T>::unit_name(),
+			Quantity::Jerk(_) => Jerk::<T>::unit_name(),
+			Quantity::KinematicViscosity(_) => KinematicViscosity::<T>::unit_name(),
+			Quantity::MassFlowRate(_) => MassFlowRate::<T>::unit_name(),
+			Quantity::MomentOfInertia(_) => MomentOfInertia::<T>::unit_name(),
+			Quantity::Momentum(_) => Momentum::<T>::unit_name(),
+			Quantity::Power(_) => Power::<T>::unit_name(),
+			Quantity::Pressure(_) => Pressure::<T>::unit_name(),
+			Quantity::TimePerDistance(_) => TimePerDistance::<T>::unit_name(),
+			Quantity::Torque(_) => Torque::<T>::unit_name(),
+			Quantity::Velocity(_) => Velocity::<T>::unit_name(),
+			Quantity::VolumePerMass(_) => VolumePerMass::<T>::unit_name(),
+			Quantity::VolumetricFlowRate(_) => VolumetricFlowRate::<T>::unit_name(),
+			Quantity::AbsorbedDose(_) => AbsorbedDose::<T>::unit_name(),
+			Quantity::AbsorbedDoseRate(_) => AbsorbedDoseRate::<T>::unit_name(),
+			Quantity::DoseEquivalent(_) => DoseEquivalent::<T>::unit_name(),
+			Quantity::DoseEquivalentRate(_) => DoseEquivalentRate::<T>::unit_name(),
+			Quantity::InverseAbsorbedDose(_) => InverseAbsorbedDose::<T>::unit_name(),
+			Quantity::InverseAbsorbedDoseRate(_) => InverseAbsorbedDoseRate::<T>::unit_name(),
+			Quantity::InverseDoseEquivalent(_) => InverseDoseEquivalent::<T>::unit_name(),
+			Quantity::InverseDoseEquivalentRate(_) => InverseDoseEquivalentRate::<T>::unit_name(),
+			Quantity::Radioactivity(_) => Radioactivity::<T>::unit_name(),
+		}
+	}
+
+	/// Adds two quantities, returning an error if they are not the same unit type
+	///
+	/// # Arguments
+	/// * `other` - The quantity to add to this one
+	pub fn try_add(&self, other: &Quantity<T>) -> Result<Quantity<T>, DimensionError> {
+		match (self, other) {
+			(Quantity::Scalar(a), Quantity::Scalar(b)) => Ok(Quantity::Scalar(a.clone() + b.clone())),
+			(Quantity::Amount(a), Quantity::Amount(b)) => Ok(Quantity::Amount(a.clone() + b.clone())),
+			(Quantity::Current(a), Quantity::Current(b)) => Ok(Quantity::Current(a.clone() + b.clone())),
+			(Quantity::Distance(a), Quantity::Distance(b)) => Ok(Quantity::Distance(a.clone() + b.clone())),
+			(Quantity::InverseAmount(a), Quantity::InverseAmount(b)) => Ok(Quantity::InverseAmount(a.clone() + b.clone())),
+			(Quantity::InverseCurrent(a), Quantity::InverseCurrent(b)) => Ok(Quantity::InverseCurrent(a.clone() + b.clone())),
+			(Quantity::InverseDistance(a), Quantity::InverseDistance(b)) => Ok(Quantity::InverseDistance(a.clone() + b.clone())),
+			(Quantity::InverseLuminosity(a), Quantity::InverseLuminosity(b)) => Ok(Quantity::InverseLuminosity(a.clone() + b.clone())),
+			(Quantity::InverseMass(a), Quantity::InverseMass(b)) => Ok(Quantity::InverseMass(a.clone() + b.clone())),
+			(Quantity::InverseTemperature(a), Quantity::InverseTemperature(b)) => Ok(Quantity::InverseTemperature(a.clone() + b.clone())),
+			(Quantity::Luminosity(a), Quantity::Luminosity(b)) => Ok(Quantity::Luminosity(a.clone() + b.clone())),
+			(Quantity::Mass(a), Quantity::Mass(b)) => Ok(Quantity::Mass(a.clone() + b.clone())),
+			(Quantity::Temperature(a), Quantity::Temperature(b)) => Ok(Quantity::Temperature(a.clone() + b.clone())),
+			(Quantity::TemperatureInterval(a), Quantity::TemperatureInterval(b)) => Ok(Quantity::TemperatureInterval(a.clone() + b.clone())),
+			(Quantity::Time(a), Quantity::Time(b)) => Ok(Quantity::Time(a.clone() + b.clone())),
+			(Quantity::CatalyticActivity(a), Quantity::CatalyticActivity(b)) => Ok(Quantity::CatalyticActivity(a.clone() + b.clone())),
+			(Quantity::Concentration(a), Quantity::Concentration(b)) => Ok(Quantity::Concentration(a.clone() + b.clone())),
+			(Quantity::Entropy(a), Quantity::Entropy(b)) => Ok(Quantity::Entropy(a.clone() + b.clone())),
+			(Quantity::HeatCapacity(a), Quantity::HeatCapacity(b)) => Ok(Quantity::HeatCapacity(a.clone() + b.clone())),
+			(Quantity::InverseCatalyticActivity(a), Quantity::InverseCatalyticActivity(b)) => Ok(Quantity::InverseCatalyticActivity(a.clone() + b.clone())),
+			(Quantity::InverseEntropy(a), Quantity::InverseEntropy(b)) => Ok(Quantity::InverseEntropy(a.clone() + b.clone())),
+			(Quantity::InverseHeatCapacity(a), Quantity::InverseHeatCapacity(b)) => Ok(Quantity::InverseHeatCapacity(a.clone() + b.clone())),
+			(Quantity::InverseMolarEnergy(a), Quantity::InverseMolarEnergy(b)) => Ok(Quantity::InverseMolarEnergy(a.clone() + b.clone())),
+			(Quantity::InverseMolarEntropy(a), Quantity::InverseMolarEntropy(b)) => Ok(Quantity::InverseMolarEntropy(a.clone() + b.clone())),
+			(Quantity::InverseSpecificHeatCapacity(a), Quantity::InverseSpecificHeatCapacity(b)) => Ok(Quantity::InverseSpecificHeatCapacity(a.clone() + b.clone())),
+			(Quantity::Molality(a), Quantity::Molality(b)) => Ok(Quantity::Molality(a.clone() + b.clone())),
+			(Quantity::MolarEnergy(a), Quantity::MolarEnergy(b)) => Ok(Quantity::MolarEnergy(a.clone() + b.clone())),
+			(Quantity::MolarEntropy(a), Quantity::MolarEntropy(b)) => Ok(Quantity::MolarEntropy(a.clone() + b.clone())),
+			(Quantity::MolarMass(a), Quantity::MolarMass(b)) => Ok(Quantity::MolarMass(a.clone() + b.clone())),
+			(Quantity::MolarVolume(a), Quantity::MolarVolume(b)) => Ok(Quantity::MolarVolume(a.clone() + b.clone())),
+			(Quantity::SpecificHeatCapacity(a), Quantity::SpecificHeatCapacity(b)) => Ok(Quantity::SpecificHeatCapacity(a.clone() + b.clone())),
+			(Quantity::ThermalConductance(a), Quantity::ThermalConductance(b)) => Ok(Quantity::ThermalConductance(a.clone() + b.clone())),
+			(Quantity::ThermalConductivity(a), Quantity::ThermalConductivity(b)) => Ok(Quantity::ThermalConductivity(a.clone() + b.clone())),
+			(Quantity::ThermalResistance(a), Quantity::ThermalResistance(b)) => Ok(Quantity::ThermalResistance(a.clone() + b.clone())),
+			(Quantity::ThermalResistivity(a), Quantity::ThermalResistivity(b)) => Ok(Quantity::ThermalResistivity(a.clone() + b.clone())),
+			(Quantity::AreaPerLumen(a), Quantity::AreaPerLumen(b)) => Ok(Quantity::AreaPerLumen(a.clone() + b.clone())),
+			(Quantity::Capacitance(a), Quantity::Capacitance(b)) => Ok(Quantity::Capacitance(a.clone() + b.clone())),
+			(Quantity::Charge(a), Quantity::Charge(b)) => Ok(Quantity::Charge(a.clone() + b.clone())),
+			(Quantity::Conductance(a), Quantity::Conductance(b)) => Ok(Quantity::Conductance(a.clone() + b.clone())),
+			(Quantity::Elastance(a), Quantity::Elastance(b)) => Ok(Quantity::Elastance(a.clone() + b.clone())),
+			(Quantity::ElectricField(a), Quantity::ElectricField(b)) => Ok(Quantity::ElectricField(a.clone() + b.clone())),
+			(Quantity::Illuminance(a), Quantity::Illuminance(b)) => Ok(Quantity::Illuminance(a.clone() + b.clone())),
+			(Quantity::Inductance(a), Quantity::Inductance(b)) => Ok(Quantity::Inductance(a.clone() + b.clone())),
+			(Quantity::InverseCharge(a), Quantity::InverseCharge(b)) => Ok(Quantity::InverseCharge(a.clone() + b.clone())),
+			(Quantity::InverseElectricField(a), Quantity::InverseElectricField(b)) => Ok(Quantity::InverseElectricField(a.clone() + b.clone())),
+			(Quantity::InverseInductance(a), Quantity::InverseInductance(b)) => Ok(Quantity::InverseInductance(a.clone() + b.clone())),
+			(Quantity::InverseIrradiance(a), Quantity::InverseIrradiance(b)) => Ok(Quantity::InverseIrradiance(a.clone() + b.clone())),
+			(Quantity::InverseLinearChargeDensity(a), Quantity::InverseLinearChargeDensity(b)) => Ok(Quantity::InverseLinearChargeDensity(a.clone() + b.clone())),
+			(Quantity::InverseLuminousFlux(a), Quantity::InverseLuminousFlux(b)) => Ok(Quantity::InverseLuminousFlux(a.clone() + b.clone())),
+			(Quantity::InverseMagneticFieldStrength(a), Quantity::InverseMagneticFieldStrength(b)) => Ok(Quantity::InverseMagneticFieldStrength(a.clone() + b.clone())),
+			(Quantity::InverseMagneticFlux(a), Quantity::InverseMagneticFlux(b)) => Ok(Quantity::InverseMagneticFlux(a.clone() + b.clone())),
+			(Quantity::InverseMagneticFluxDensity(a), Quantity::InverseMagneticFluxDensity(b)) => Ok(Quantity::InverseMagneticFluxDensity(a.clone() + b.clone())),
+			(Quantity::InverseRadiance(a), Quantity::InverseRadiance(b)) => Ok(Quantity::InverseRadiance(a.clone() + b.clone())),
+			(Quantity::InverseSurfaceChargeDensity(a), Quantity::InverseSurfaceChargeDensity(b)) => Ok(Quantity::InverseSurfaceChargeDensity(a.clone() + b.clone())),
+			(Quantity::InverseVoltage(a), Quantity::InverseVoltage(b)) => Ok(Quantity::InverseVoltage(a.clone() + b.clone())),
+			(Quantity::InverseVolumeChargeDensity(a), Quantity::InverseVolumeChargeDensity(b)) => Ok(Quantity::InverseVolumeChargeDensity(a.clone() + b.clone())),
+			(Quantity::Irradiance(a), Quantity::Irradiance(b)) => Ok(Quantity::Irradiance(a.clone() + b.clone())),
+			(Quantity::LinearChargeDensity(a), Quantity::LinearChargeDensity(b)) => Ok(Quantity::LinearChargeDensity(a.clone() + b.clone())),
+			(Quantity::LuminousFlux(a), Quantity::LuminousFlux(b)) => Ok(Quantity::LuminousFlux(a.clone() + b.clone())),
+			(Quantity::MagneticFieldStrength(a), Quantity::MagneticFieldStrength(b)) => Ok(Quantity::MagneticFieldStrength(a.clone() + b.clone())),
+			(Quantity::MagneticFlux(a), Quantity::MagneticFlux(b)) => Ok(Quantity::MagneticFlux(a.clone() + b.clone())),
+			(Quantity::MagneticFluxDensity(a), Quantity::MagneticFluxDensity(b)) => Ok(Quantity::MagneticFluxDensity(a.clone() + b.clone())),
+			(Quantity::Radiance(a), Quantity::Radiance(b)) => Ok(Quantity::Radiance(a.clone() + b.clone())),
+			(Quantity::Resistance(a), Quantity::Resistance(b)) => Ok(Quantity::Resistance(a.clone() + b.clone())),
+			(Quantity::SurfaceChargeDensity(a), Quantity::SurfaceChargeDensity(b)) => Ok(Quantity::SurfaceChargeDensity(a.clone() + b.clone())),
+			(Quantity::Voltage(a), Quantity::Voltage(b)) => Ok(Quantity::Voltage(a.clone() + b.clone())),
+			(Quantity::VolumeChargeDensity(a), Quantity::VolumeChargeDensity(b)) => Ok(Quantity::VolumeChargeDensity(a.clone() + b.clone())),
+			(Quantity::Angle(a), Quantity::Angle(b)) => Ok(Quantity::Angle(a.clone() + b.clone())),
+			(Quantity::Area(a), Quantity::Area(b)) => Ok(Quantity::Area(a.clone() + b.clone())),
+			(Quantity::InverseAngle(a), Quantity::InverseAngle(b)) => Ok(Quantity::InverseAngle(a.clone() + b.clone())),
+			(Quantity::InverseArea(a), Quantity::InverseArea(b)) => Ok(Quantity::InverseArea(a.clone() + b.clone())),
+			(Quantity::InverseSolidAngle(a), Quantity::InverseSolidAngle(b)) => Ok(Quantity::InverseSolidAngle(a.clone() + b.clone())),
+			(Quantity::InverseVolume(a), Quantity::InverseVolume(b)) => Ok(Quantity::InverseVolume(a.clone() + b.clone())),
+			(Quantity::SolidAngle(a), Quantity::SolidAngle(b)) => Ok(Quantity::SolidAngle(a.clone() + b.clone())),
+			(Quantity::Volume(a), Quantity::Volume(b)) => Ok(Quantity::Volume(a.clone() + b.clone())),
+			(Quantity::Acceleration(a), Quantity::Acceleration(b)) => Ok(Quantity::Acceleration(a.clone() + b.clone())),
+			(Quantity::AngularAcceleration(a), Quantity::AngularAcceleration(b)) => Ok(Quantity::AngularAcceleration(a.clone() + b.clone())),
+			(Quantity::AngularMomentum(a), Quantity::AngularMomentum(b)) => Ok(Quantity::AngularMomentum(a.clone() + b.clone())),
+			(Quantity::AngularVelocity(a), Quantity::AngularVelocity(b)) => Ok(Quantity::AngularVelocity(a.clone() + b.clone())),
+			(Quantity::AreaDensity(a), Quantity::AreaDensity(b)) => Ok(Quantity::AreaDensity(a.clone() + b.clone())),
+			(Quantity::AreaPerMass(a), Quantity::AreaPerMass(b)) => Ok(Quantity::AreaPerMass(a.clone() + b.clone())),
+			(Quantity::Density(a), Quantity::Density(b)) => Ok(Quantity::Density(a.clone() + b.clone())),
+			(Quantity::DynamicViscosity(a), Quantity::DynamicViscosity(b)) => Ok(Quantity::DynamicViscosity(a.clone() + b.clone())),
+			(Quantity::Energy(a), Quantity::Energy(b)) => Ok(Quantity::Energy(a.clone() + b.clone())),
+			(Quantity::Force(a), Quantity::Force(b)) => Ok(Quantity::Force(a.clone() + b.clone())),
+			(Quantity::Frequency(a), Quantity::Frequency(b)) => Ok(Quantity::Frequency(a.clone() + b.clone())),
+			(Quantity::InverseAcceleration(a), Quantity::InverseAcceleration(b)) => Ok(Quantity::InverseAcceleration(a.clone() + b.clone())),
+			(Quantity::InverseAngularAcceleration(a), Quantity::InverseAngularAcceleration(b)) => Ok(Quantity::InverseAngularAcceleration(a.clone() + b.clone())),
+			(Quantity::InverseAngularMomentum(a), Quantity::InverseAngularMomentum(b)) => Ok(Quantity::InverseAngularMomentum(a.clone() + b.clone())),
+			(Quantity::InverseAngularVelocity(a), Quantity::InverseAngularVelocity(b)) => Ok(Quantity::InverseAngularVelocity(a.clone() + b.clone())),
+			(Quantity::InverseDynamicViscosity(a), Quantity::InverseDynamicViscosity(b)) => Ok(Quantity::InverseDynamicViscosity(a.clone() + b.clone())),
+			(Quantity::InverseEnergy(a), Quantity::InverseEnergy(b)) => Ok(Quantity::InverseEnergy(a.clone() + b.clone())),
+			(Quantity::InverseForce(a), Quantity::InverseForce(b)) => Ok(Quantity::InverseForce(a.clone() + b.clone())),
+			(Quantity::InverseJerk(a), Quantity::InverseJerk(b)) => Ok(Quantity::InverseJerk(a.clone() + b.clone())),
+			(Quantity::InverseKinematicViscosity(a), Quantity::InverseKinematicViscosity(b)) => Ok(Quantity::InverseKinematicViscosity(a.clone() + b.clone())),
+			(Quantity::InverseMassFlowRate(a), Quantity::InverseMassFlowRate(b)) => Ok(Quantity::InverseMassFlowRate(a.clone() + b.clone())),
+			(Quantity::InverseMomentOfInertia(a), Quantity::InverseMomentOfInertia(b)) => Ok(Quantity::InverseMomentOfInertia(a.clone() + b.clone())),
+			(Quantity::InverseMomentum(a), Quantity::InverseMomentum(b)) => Ok(Quantity::InverseMomentum(a.clone() + b.clone())),
+			(Quantity::InversePower(a), Quantity::InversePower(b)) => Ok(Quantity::InversePower(a.clone() + b.clone())),
+			(Quantity::InversePressure(a), Quantity::InversePressure(b)) => Ok(Quantity::InversePressure(a.clone() + b.clone())),
+			(Quantity::InverseTorque(a), Quantity::InverseTorque(b)) => Ok(Quantity::InverseTorque(a.clone() + b.clone())),
+			(Quantity::InverseVolumetricFlowRate(a), Quantity::InverseVolumetricFlowRate(b)) => Ok(Quantity::InverseVolumetricFlowRate(a.clone() + b.clone())),
+			(Quantity::Jerk(a), Quantity::Jerk(b)) => Ok(Quantity::Jerk(a.clone() + b.clone())),
+			(Quantity::KinematicViscosity(a), Quantity::KinematicViscosity(b)) => Ok(Quantity::KinematicViscosity(a.clone() + b.clone())),
+			(Quantity::MassFlowRate(a), Quantity::MassFlowRate(b)) => Ok(Quantity::MassFlowRate(a.clone() + b.clone())),
+			(Quantity::MomentOfInertia(a), Quantity::MomentOfInertia(b)) => Ok(Quantity::MomentOfInertia(a.clone() + b.clone())),
+			(Quantity::Momentum(a), Quantity::Momentum(b)) => Ok(Quantity::Momentum(a.clone() + b.clone())),
+			(Quantity::Power(a), Quantity::Power(b)) => Ok(Quantity::Power(a.clone() + b.clone())),
+			(Quantity::Pressure(a), Quantity::Pressure(b)) => Ok(Quantity::Pressure(a.clone() + b.clone())),
+			(Quantity::TimePerDistance(a), Quantity::TimePerDistance(b)) => Ok(Quantity::TimePerDistance(a.clone() + b.clone())),
+			(Quantity::Torque(a), Quantity::Torque(b)) => Ok(Quantity::Torque(a.clone() + b.clone())),
+			(Quantity::Velocity(a), Quantity::Velocity(b)) => Ok(Quantity::Velocity(a.clone() + b.clone())),
+			(Quantity::VolumePerMass(a), Quantity::VolumePerMass(b)) => Ok(Quantity::VolumePerMass(a.clone() + b.clone())),
+			(Quantity::VolumetricFlowRate(a), Quantity::VolumetricFlowRate(b)) => Ok(Quantity::VolumetricFlowRate(a.clone() + b.clone())),
+			(Quantity::AbsorbedDose(a), Quantity::AbsorbedDose(b)) => Ok(Quantity::AbsorbedDose(a.clone() + b.clone())),
+			(Quantity::AbsorbedDoseRate(a), Quantity::AbsorbedDoseRate(b)) => Ok(Quantity::AbsorbedDoseRate(a.clone() + b.clone())),
+			(Quantity::DoseEquivalent(a), Quantity::DoseEquivalent(b)) => Ok(Quantity::DoseEquivalent(a.clone() + b.clone())),
+			(Quantity::DoseEquivalentRate(a), Quantity::DoseEquivalentRate(b)) => Ok(Quantity::DoseEquivalentRate(a.clone() + b.clone())),
+			(Quantity::InverseAbsorbedDose(a), Quantity::InverseAbsorbedDose(b)) => Ok(Quantity::InverseAbsorbedDose(a.clone() + b.clone())),
+			(Quantity::InverseAbsorbedDoseRate(a), Quantity::InverseAbsorbedDoseRate(b)) => Ok(Quantity::InverseAbsorbedDoseRate(a.clone() + b.clone())),
+			(Quantity::InverseDoseEquivalent(a), Quantity::InverseDoseEquivalent(b)) => Ok(Quantity::InverseDoseEquivalent(a.clone() + b.clone())),
+			(Quantity::InverseDoseEquivalentRate(a), Quantity::InverseDoseEquivalentRate(b)) => Ok(Quantity::InverseDoseEquivalentRate(a.clone() + b.clone())),
+			(Quantity::Radioactivity(a), Quantity::Radioactivity(b)) => Ok(Quantity::Radioactivity(a.clone() + b.clone())),
+			_ => Err(self.dimension_error("add", other))
+		}
+	}
+
+	/// Subtracts a quantity from this one, returning an error if they are not the 
+	/// same unit type
+	///
+	/// # Arguments
+	/// * `other` - The quantity to subtract from this one
+	pub fn try_sub(&self, other: &Quantity<T>) -> Result<Quantity<T>, DimensionError> {
+		match (self, other) {
+			(Quantity::Scalar(a), Quantity::Scalar(b)) => Ok(Quantity::Scalar(a.clone() - b.clone())),
+			(Quantity::Amount(a), Quantity::Amount(b)) => Ok(Quantity::Amount(a.clone() - b.clone())),
+			(Quantity::Current(a), Quantity::Current(b)) => Ok(Quantity::Current(a.clone() - b.clone())),
+			(Quantity::Distance(a), Quantity::Distance(b)) => Ok(Quantity::Distance(a.clone() - b.clone())),
+			(Quantity::InverseAmount(a), Quantity::InverseAmount(b)) => Ok(Quantity::InverseAmount(a.clone() - b.clone())),
+			(Quantity::InverseCurrent(a), Quantity::InverseCurrent(b)) => Ok(Quantity::InverseCurrent(a.clone() - b.clone())),
+			(Quantity::InverseDistance(a), Quantity::InverseDistance(b)) => Ok(Quantity::InverseDistance(a.clone() - b.clone())),
+			(Quantity::InverseLuminosity(a), Quantity::InverseLuminosity(b)) => Ok(Quantity::InverseLuminosity(a.clone() - b.clone())),
+			(Quantity::InverseMass(a), Quantity::InverseMass(b)) => Ok(Quantity::InverseMass(a.clone() - b.clone())),
+			(Quantity::InverseTemperature(a), Quantity::InverseTemperature(b)) => Ok(Quantity::InverseTemperature(a.clone() - b.clone())),
+			(Quantity::Luminosity(a), Quantity::Luminosity(b)) => Ok(Quantity::Luminosity(a.clone() - b.clone())),
+			(Quantity::Mass(a), Quantity::Mass(b)) => Ok(Quantity::Mass(a.clone() - b.clone())),
+			(Quantity::Temperature(a), Quantity::Temperature(b)) => Ok(Quantity::TemperatureInterval(a.clone() - b.clone())),
+			(Quantity::TemperatureInterval(a), Quantity::TemperatureInterval(b)) => Ok(Quantity::TemperatureInterval(a.clone() - b.clone())),
+			(Quantity::Time(a), Quantity::Time(b)) => Ok(Quantity::Time(a.clone() - b.clone())),
+			(Quantity::CatalyticActivity(a), Quantity::CatalyticActivity(b)) => Ok(Quantity::CatalyticActivity(a.clone() - b.clone())),
+			(Quantity::Concentration(a), Quantity::Concentration(b)) => Ok(Quantity::Concentration(a.clone() - b.clone())),
+			(Quantity::Entropy(a), Quantity::Entropy(b)) => Ok(Quantity::Entropy(a.clone() - b.clone())),
+			(Quantity::HeatCapacity(a), Quantity::HeatCapacity(b)) => Ok(Quantity::HeatCapacity(a.clone() - b.clone())),
+			(Quantity::InverseCatalyticActivity(a), Quantity::InverseCatalyticActivity(b)) => Ok(Quantity::InverseCatalyticActivity(a.clone() - b.clone())),
+			(Quantity::InverseEntropy(a), Quantity::InverseEntropy(b)) => Ok(Quantity::InverseEntropy(a.clone() - b.clone())),
+			(Quantity::InverseHeatCapacity(a), Quantity::InverseHeatCapacity(b)) => Ok(Quantity::InverseHeatCapacity(a.clone() - b.clone())),
+			(Quantity::InverseMolarEnergy(a), Quantity::InverseMolarEnergy(b)) => Ok(Quantity::InverseMolarEnergy(a.clone() - b.clone())),
+			(Quantity::InverseMolarEntropy(a), Quantity::InverseMolarEntropy(b)) => Ok(Quantity::InverseMolarEntropy(a.clone() - b.clone())),
+			(Quantity::InverseSpecificHeatCapacity(a), Quantity::InverseSpecificHeatCapacity(b)) => Ok(Quantity::InverseSpecificHeatCapacity(a.clone() - b.clone())),
+			(Quantity::Molality(a), Quantity::Molality(b)) => Ok(Quantity::Molality(a.clone() - b.clone())),
+			(Quantity::MolarEnergy(a), Quantity::MolarEnergy(b)) => Ok(Quantity::MolarEnergy(a.clone() - b.clone())),
+			(Quantity::MolarEntropy(a), Quantity::MolarEntropy(b)) => Ok(Quantity::MolarEntropy(a.clone() - b.clone())),
+			(Quantity::MolarMass(a), Quantity::MolarMass(b)) => Ok(Quantity::MolarMass(a.clone() - b.clone())),
+			(Quantity::MolarVolume(a), Quantity::MolarVolume(b)) => Ok(Quantity::MolarVolume(a.clone() - b.clone())),
+			(Quantity::SpecificHeatCapacity(a), Quantity::SpecificHeatCapacity(b)) => Ok(Quantity::SpecificHeatCapacity(a.clone() - b.clone())),
+			(Quantity::ThermalConductance(a), Quantity::ThermalConductance(b)) => Ok(Quantity::ThermalConductance(a.clone() - b.clone())),
+			(Quantity::ThermalConductivity(a), Quantity::ThermalConductivity(b)) => Ok(Quantity::ThermalConductivity(a.clone() - b.clone())),
+			(Quantity::ThermalResistance(a), Quantity::ThermalResistance(b)) => Ok(Quantity::ThermalResistance(a.clone() - b.clone())),
+			(Quantity::ThermalResistivity(a), Quantity::ThermalResistivity(b)) => Ok(Quantity::ThermalResistivity(a.clone() - b.clone())),
+			(Quantity::AreaPerLumen(a), Quantity::AreaPerLumen(b)) => Ok(Quantity::AreaPerLumen(a.clone() - b.clone())),
+			(Quantity::Capacitance(a), Quantity::Capacitance(b)) => Ok(Quantity::Capacitance(a.clone() - b.clone())),
+			(Quantity::Charge(a), Quantity::Charge(b)) => Ok(Quantity::Charge(a.clone() - b.clone())),
+			(Quantity::Conductance(a), Quantity::Conductance(b)) => Ok(Quantity::Conductance(a.clone() - b.clone())),
+			(Quantity::Elastance(a), Quantity::Elastance(b)) => Ok(Quantity::Elastance(a.clone() - b.clone())),
+			(Quantity::ElectricField(a), Quantity::ElectricField(b)) => Ok(Quantity::ElectricField(a.clone() - b.clone())),
+			(Quantity::Illuminance(a), Quantity::Illuminance(b)) => Ok(Quantity::Illuminance(a.clone() - b.clone())),
+			(Quantity::Inductance(a), Quantity::Inductance(b)) => Ok(Quantity::Inductance(a.clone() - b.clone())),
+			(Quantity::InverseCharge(a), Quantity::InverseCharge(b)) => Ok(Quantity::InverseCharge(a.clone() - b.clone())),
+			(Quantity::InverseElectricField(a), Quantity::InverseElectricField(b)) => Ok(Quantity::InverseElectricField(a.clone() - b.clone())),
+			(Quantity::InverseInductance(a), Quantity::InverseInductance(b)) => Ok(Quantity::InverseInductance(a.clone() - b.clone())),
+			(Quantity::InverseIrradiance(a), Quantity::InverseIrradiance(b)) => Ok(Quantity::InverseIrradiance(a.clone() - b.clone())),
+			(Quantity::InverseLinearChargeDensity(a), Quantity::InverseLinearChargeDensity(b)) => Ok(Quantity::InverseLinearChargeDensity(a.clone() - b.clone())),
+			(Quantity::InverseLuminousFlux(a), Quantity::InverseLuminousFlux(b)) => Ok(Quantity::InverseLuminousFlux(a.clone() - b.clone())),
+			(Quantity::InverseMagneticFieldStrength(a), Quantity::InverseMagneticFieldStrength(b)) => Ok(Quantity::InverseMagneticFieldStrength(a.clone() - b.clone())),
+			(Quantity::InverseMagneticFlux(a), Quantity::InverseMagneticFlux(b)) => Ok(Quantity::InverseMagneticFlux(a.clone() - b.clone())),
+			(Quantity::InverseMagneticFluxDensity(a), Quantity::InverseMagneticFluxDensity(b)) => Ok(Quantity::InverseMagneticFluxDensity(a.clone() - b.clone())),
+			(Quantity::InverseRadiance(a), Quantity::InverseRadiance(b)) => Ok(Quantity::InverseRadiance(a.clone() - b.clone())),
+			(Quantity::InverseSurfaceChargeDensity(a), Quantity::InverseSurfaceChargeDensity(b)) => Ok(Quantity::InverseSurfaceChargeDensity(a.clone() - b.clone())),
+			(Quantity::InverseVoltage(a), Quantity::InverseVoltage(b)) => Ok(Quantity::InverseVoltage(a.clone() - b.clone())),
+			(Quantity::InverseVolumeChargeDensity(a), Quantity::InverseVolumeChargeDensity(b)) => Ok(Quantity::InverseVolumeChargeDensity(a.clone() - b.clone())),
+			(Quantity::Irradiance(a), Quantity::Irradiance(b)) => Ok(Quantity::Irradiance(a.clone() - b.clone())),
+			(Quantity::LinearChargeDensity(a), Quantity::LinearChargeDensity(b)) => Ok(Quantity::LinearChargeDensity(a.clone() - b.clone())),
+			(Quantity::LuminousFlux(a), Quantity::LuminousFlux(b)) => Ok(Quantity::LuminousFlux(a.clone() - b.clone())),
+			(Quantity::MagneticFieldStrength(a), Quantity::MagneticFieldStrength(b)) => Ok(Quantity::MagneticFieldStrength(a.clone() - b.clone())),
+			(Quantity::MagneticFlux(a), Quantity::MagneticFlux(b)) => Ok(Quantity::MagneticFlux(a.clone() - b.clone())),
+			(Quantity::MagneticFluxDensity(a), Quantity::MagneticFluxDensity(b)) => Ok(Quantity::MagneticFluxDensity(a.clone() - b.clone())),
+			(Quantity::Radiance(a), Quantity::Radiance(b)) => Ok(Quantity::Radiance(a.clone() - b.clone())),
+			(Quantity::Resistance(a), Quantity::Resistance(b)) => Ok(Quantity::Resistance(a.clone() - b.clone())),
+			(Quantity::SurfaceChargeDensity(a), Quantity::SurfaceChargeDensity(b)) => Ok(Quantity::SurfaceChargeDensity(a.clone() - b.clone())),
+			(Quantity::Voltage(a), Quantity::Voltage(b)) => Ok(Quantity::Voltage(a.clone() - b.clone())),
+			(Quantity::VolumeChargeDensity(a), Quantity::VolumeChargeDensity(b)) => Ok(Quantity::VolumeChargeDensity(a.clone() - b.clone())),
+			(Quantity::Angle(a), Quantity::Angle(b)) => Ok(Quantity::Angle(a.clone() - b.clone())),
+			(Quantity::Area(a), Quantity::Area(b)) => Ok(Quantity::Area(a.clone() - b.clone())),
+			(Quantity::InverseAngle(a), Quantity::InverseAngle(b)) => Ok(Quantity::InverseAngle(a.clone() - b.clone())),
+			(Quantity::InverseArea(a), Quantity::InverseArea(b)) => Ok(Quantity::InverseArea(a.clone() - b.clone())),
+			(Quantity::InverseSolidAngle(a), Quantity::InverseSolidAngle(b)) => Ok(Quantity::InverseSolidAngle(a.clone() - b.clone())),
+			(Quantity::InverseVolume(a), Quantity::InverseVolume(b)) => Ok(Quantity::InverseVolume(a.clone() - b.clone())),
+			(Quantity::SolidAngle(a), Quantity::SolidAngle(b)) => Ok(Quantity::SolidAngle(a.clone() - b.clone())),
+			(Quantity::Volume(a), Quantity::Volume(b)) => Ok(Quantity::Volume(a.clone() - b.clone())),
+			(Quantity::Acceleration(a), Quantity::Acceleration(b)) => Ok(Quantity::Acceleration(a.clone() - b.clone())),
+			(Quantity::AngularAcceleration(a), Quantity::AngularAcceleration(b)) => Ok(Quantity::AngularAcceleration(a.clone() - b.clone())),
+			(Quantity::AngularMomentum(a), Quantity::AngularMomentum(b)) => Ok(Quantity::AngularMomentum(a.clone() - b.clone())),
+			(Quantity::AngularVelocity(a), Quantity::AngularVelocity(b)) => Ok(Quantity::AngularVelocity(a.clone() - b.clone())),
+			(Quantity::AreaDensity(a), Quantity::AreaDensity(b)) => Ok(Quantity::AreaDensity(a.clone() - b.clone())),
+			(Quantity::AreaPerMass(a), Quantity::AreaPerMass(b)) => Ok(Quantity::AreaPerMass(a.clone() - b.clone())),
+			(Quantity::Density(a), Quantity::Density(b)) => Ok(Quantity::Density(a.clone() - b.clone())),
+			(Quantity::DynamicViscosity(a), Quantity::DynamicViscosity(b)) => Ok(Quantity::DynamicViscosity(a.clone() - b.clone())),
+			(Quantity::Energy(a), Quantity::Energy(b)) => Ok(Quantity::Energy(a.clone() - b.clone())),
+			(Quantity::Force(a), Quantity::Force(b)) => Ok(Quantity::Force(a.clone() - b.clone())),
+			(Quantity::Frequency(a), Quantity::Frequency(b)) => Ok(Quantity::Frequency(a.clone() - b.clone())),
+			(Quantity::InverseAcceleration(a), Quantity::InverseAcceleration(b)) => Ok(Quantity::InverseAcceleration(a.clone() - b.clone())),
+			(Quantity::InverseAngularAcceleration(a), Quantity::InverseAngularAcceleration(b)) => Ok(Quantity::InverseAngularAcceleration(a.clone() - b.clone())),
+			(Quantity::InverseAngularMomentum(a), Quantity::InverseAngularMomentum(b)) => Ok(Quantity::InverseAngularMomentum(a.clone() - b.clone())),
+			(Quantity::InverseAngularVelocity(a), Quantity::InverseAngularVelocity(b)) => Ok(Quantity::InverseAngularVelocity(a.clone() - b.clone())),
+			(Quantity::InverseDynamicViscosity(a), Quantity::InverseDynamicViscosity(b)) => Ok(Quantity::InverseDynamicViscosity(a.clone() - b.clone())),
+			(Quantity::InverseEnergy(a), Quantity::InverseEnergy(b)) => Ok(Quantity::InverseEnergy(a.clone() - b.clone())),
+			(Quantity::InverseForce(a), Quantity::InverseForce(b)) => Ok(Quantity::InverseForce(a.clone() - b.clone())),
+			(Quantity::InverseJerk(a), Quantity::InverseJerk(b)) => Ok(Quantity::InverseJerk(a.clone() - b.clone())),
+			(Quantity::InverseKinematicViscosity(a), Quantity::InverseKinematicViscosity(b)) => Ok(Quantity::InverseKinematicViscosity(a.clone() - b.clone())),
+			(Quantity::InverseMassFlowRate(a), Quantity::InverseMassFlowRate(b)) => Ok(Quantity::InverseMassFlowRate(a.clone() - b.clone())),
+			(Quantity::InverseMomentOfInertia(a), Quantity::InverseMomentOfInertia(b)) => Ok(Quantity::InverseMomentOfInertia(a.clone() - b.clone())),
+			(Quantity::InverseMomentum(a), Quantity::InverseMomentum(b)) => Ok(Quantity::InverseMomentum(a.clone() - b.clone())),
+			(Quantity::InversePower(a), Quantity::InversePower(b)) => Ok(Quantity::InversePower(a.clone() - b.clone())),
+			(Quantity::InversePressure(a), Quantity::InversePressure(b)) => Ok(Quantity::InversePressure(a.clone() - b.clone())),
+			(Quantity::InverseTorque(a), Quantity::InverseTorque(b)) => Ok(Quantity::InverseTorque(a.clone() - b.clone())),
+			(Quantity::InverseVolumetricFlowRate(a), Quantity::InverseVolumetricFlowRate(b)) => Ok(Quantity::InverseVolumetricFlowRate(a.clone() - b.clone())),
+			(Quantity::Jerk(a), Quantity::Jerk(b)) => Ok(Quantity::Jerk(a.clone() - b.clone())),
+			(Quantity::KinematicViscosity(a), Quantity::KinematicViscosity(b)) => Ok(Quantity::KinematicViscosity(a.clone() - b.clone())),
+			(Quantity::MassFlowRate(a), Quantity::MassFlowRate(b)) => Ok(Quantity::MassFlowRate(a.clone() - b.clone())),
+			(Quantity::MomentOfInertia(a), Quantity::MomentOfInertia(b)) => Ok(Quantity::MomentOfInertia(a.clone() - b.clone())),
+			(Quantity::Momentum(a), Quantity::Momentum(b)) => Ok(Quantity::Momentum(a.clone() - b.clone())),
+			(Quantity::Power(a), Quantity::Power(b)) => Ok(Quantity::Power(a.clone() - b.clone())),
+			(Quantity::Pressure(a), Quantity::Pressure(b)) => Ok(Quantity::Pressure(a.clone() - b.clone())),
+			(Quantity::TimePerDistance(a), Quantity::TimePerDistance(b)) => Ok(Quantity::TimePerDistance(a.clone() - b.clone())),
+			(Quantity::Torque(a), Quantity::Torque(b)) => Ok(Quantity::Torque(a.clone() - b.clone())),
+			(Quantity::Velocity(a), Quantity::Velocity(b)) => Ok(Quantity::Velocity(a.clone() - b.clone())),
+			(Quantity::VolumePerMass(a), Quantity::VolumePerMass(b)) => Ok(Quantity::VolumePerMass(a.clone() - b.clone())),
+			(Quantity::VolumetricFlowRate(a), Quantity::VolumetricFlowRate(b)) => Ok(Quantity::VolumetricFlowRate(a.clone() - b.clone())),
+			(Quantity::AbsorbedDose(a), Quantity::AbsorbedDose(b)) => Ok(Quantity::AbsorbedDose(a.clone() - b.clone())),
+			(Quantity::AbsorbedDoseRate(a), Quantity::AbsorbedDoseRate(b)) => Ok(Quantity::AbsorbedDoseRate(a.clone() - b.clone())),
+			(Quantity::DoseEquivalent(a), Quantity::DoseEquivalent(b)) => Ok(Quantity::DoseEquivalent(a.clone() - b.clone())),
+			(Quantity::DoseEquivalentRate(a), Quantity::DoseEquivalentRate(b)) => Ok(Quantity::DoseEquivalentRate(a.clone() - b.clone())),
+			(Quantity::InverseAbsorbedDose(a), Quantity::InverseAbsorbedDose(b)) => Ok(Quantity::InverseAbsorbedDose(a.clone() - b.clone())),
+			(Quantity::InverseAbsorbedDoseRate(a), Quantity::InverseAbsorbedDoseRate(b)) => Ok(Quantity::InverseAbsorbedDoseRate(a.clone() - b.clone())),
+			(Quantity::InverseDoseEquivalent(a), Quantity::InverseDoseEquivalent(b)) => Ok(Quantity::InverseDoseEquivalent(a.clone() - b.clone())),
+			(Quantity::InverseDoseEquivalentRate(a), Quantity::InverseDoseEquivalentRate(b)) => Ok(Quantity::InverseDoseEquivalentRate(a.clone() - b.clone())),
+			(Quantity::Radioactivity(a), Quantity::Radioactivity(b)) => Ok(Quantity::Radioactivity(a.clone() - b.clone())),
+			_ => Err(self.dimension_error("subtract", other))
+		}
+	}
+
+	/// Multiplies two quantities, returning an error if there is no unit type 
+	/// for the result
+	///
+	/// # Arguments
+	/// * `other` - The quantity to multiply this one by
+	pub fn try_mul(&self, other: &Quantity<T>) -> Result<Quantity<T>, DimensionError> {
+		match (self, other) {
+			(Quantity::Scalar(s), q) | (q, Quantity::Scalar(s)) => Ok(q.clone() * s.clone()),
+			(Quantity::Amount(a), Quantity::InverseMass(b)) => Ok(Quantity::Molality(a.clone() * b.clone())),
+			(Quantity::Amount(a), Quantity::InverseCatalyticActivity(b)) => Ok(Quantity::Time(a.clone() * b.clone())),
+			(Quantity::Amount(a), Quantity::InverseEntropy(b)) => Ok(Quantity::InverseMolarEntropy(a.clone() * b.clone())),
+			(Quantity::Amount(a), Quantity::InverseHeatCapacity(b)) => Ok(Quantity::InverseMolarEntropy(a.clone() * b.clone())),
+			(Quantity::Amount(a), Quantity::MolarEnergy(b)) => Ok(Quantity::Energy(a.clone() * b.clone())),
+			(Quantity::Amount(a), Quantity::MolarEntropy(b)) => Ok(Quantity::HeatCapacity(a.clone() * b.clone())),
+			(Quantity::Amount(a), Quantity::MolarMass(b)) => Ok(Quantity::Mass(a.clone() * b.clone())),
+			(Quantity::Amount(a), Quantity::MolarVolume(b)) => Ok(Quantity::Volume(a.clone() * b.clone())),
+			(Quantity::Amount(a), Quantity::InverseVolume(b)) => Ok(Quantity::Concentration(a.clone() * b.clone())),
+			(Quantity::Amount(a), Quantity::Frequency(b)) => Ok(Quantity::CatalyticActivity(a.clone() * b.clone())),
+			(Quantity::Amount(a), Quantity::InverseEnergy(b)) => Ok(Quantity::InverseMolarEnergy(a.clone() * b.clone())),
+			(Quantity::Amount(a), Quantity::InverseTorque(b)) => Ok(Quantity::InverseMolarEnergy(a.clone() * b.clone())),
+			(Quantity::Current(a), Quantity::InverseDistance(b)) => Ok(Quantity::MagneticFieldStrength(a.clone() * b.clone())),
+			(Quantity::Current(a), Quantity::Time(b)) => Ok(Quantity::Charge(a.clone() * b.clone())),
+			(Quantity::Current(a), Quantity::Inductance(b)) => Ok(Quantity::MagneticFlux(a.clone() * b.clone())),
+			(Quantity::Current(a), Quantity::InverseCharge(b)) => Ok(Quantity::Frequency(a.clone() * b.clone())),
+			(Quantity::Current(a), Quantity::InverseLinearChargeDensity(b)) => Ok(Quantity::Velocity(a.clone() * b.clone())),
+			(Quantity::Current(a), Quantity::InverseMagneticFieldStrength(b)) => Ok(Quantity::Distance(a.clone() * b.clone())),
+			(Quantity::Current(a), Quantity::InverseMagneticFlux(b)) => Ok(Quantity::InverseInductance(a.clone() * b.clone())),
+			(Quantity::Current(a), Quantity::InverseSurfaceChargeDensity(b)) => Ok(Quantity::KinematicViscosity(a.clone() * b.clone())),
+			(Quantity::Current(a), Quantity::InverseVoltage(b)) => Ok(Quantity::Conductance(a.clone() * b.clone())),
+			(Quantity::Current(a), Quantity::InverseVolumeChargeDensity(b)) => Ok(Quantity::VolumetricFlowRate(a.clone() * b.clone())),
+			(Quantity::Current(a), Quantity::MagneticFlux(b)) => Ok(Quantity::Energy(a.clone() * b.clone())),
+			(Quantity::Current(a), Quantity::Resistance(b)) => Ok(Quantity::Voltage(a.clone() * b.clone())),
+			(Quantity::Current(a), Quantity::Voltage(b)) => Ok(Quantity::Power(a.clone() * b.clone())),
+			(Quantity::Current(a), Quantity::InverseEnergy(b)) => Ok(Quantity::InverseMagneticFlux(a.clone() * b.clone())),
+			(Quantity::Current(a), Quantity::InverseTorque(b)) => Ok(Quantity::InverseMagneticFlux(a.clone() * b.clone())),
+			(Quantity::Current(a), Quantity::InverseKinematicViscosity(b)) => Ok(Quantity::SurfaceChargeDensity(a.clone() * b.clone())),
+			(Quantity::Current(a), Quantity::InversePower(b)) => Ok(Quantity::InverseVoltage(a.clone() * b.clone())),
+			(Quantity::Current(a), Quantity::InverseVolumetricFlowRate(b)) => Ok(Quantity::VolumeChargeDensity(a.clone() * b.clone())),
+			(Quantity::Current(a), Quantity::TimePerDistance(b)) => Ok(Quantity::LinearChargeDensity(a.clone() * b.clone())),
+			(Quantity::Distance(a), Quantity::Distance(b)) => Ok(Quantity::Area(a.clone() * b.clone())),
+			(Quantity::Distance(a), Quantity::InverseCurrent(b)) => Ok(Quantity::InverseMagneticFieldStrength(a.clone() * b.clone())),
+			(Quantity::Distance(a), Quantity::ThermalConductivity(b)) => Ok(Quantity::ThermalConductance(a.clone() * b.clone())),
+			(Quantity::Distance(a), Quantity::ThermalResistance(b)) => Ok(Quantity::ThermalResistivity(a.clone() * b.clone())),
+			(Quantity::Distance(a), Quantity::ElectricField(b)) => Ok(Quantity::Voltage(a.clone() * b.clone())),
+			(Quantity::Distance(a), Quantity::InverseCharge(b)) => Ok(Quantity::InverseLinearChargeDensity(a.clone() * b.clone())),
+			(Quantity::Distance(a), Quantity::InverseLinearChargeDensity(b)) => Ok(Quantity::InverseSurfaceChargeDensity(a.clone() * b.clone())),
+			(Quantity::Distance(a), Quantity::InverseSurfaceChargeDensity(b)) => Ok(Quantity::InverseVolumeChargeDensity(a.clone() * b.clone())),
+			(Quantity::Distance(a), Quantity::InverseVoltage(b)) => Ok(Quantity::InverseElectricField(a.clone() * b.clone())),
+			(Quantity::Distance(a), Quantity::LinearChargeDensity(b)) => Ok(Quantity::Charge(a.clone() * b.clone())),
+			(Quantity::Distance(a), Quantity::MagneticFieldStrength(b)) => Ok(Quantity::Current(a.clone() * b.clone())),
+			(Quantity::Distance(a), Quantity::SurfaceChargeDensity(b)) => Ok(Quantity::LinearChargeDensity(a.clone() * b.clone())),
+			(Quantity::Distance(a), Quantity::VolumeChargeDensity(b)) => Ok(Quantity::SurfaceChargeDensity(a.clone() * b.clone())),
+			(Quantity::Distance(a), Quantity::Area(b)) => Ok(Quantity::Volume(a.clone() * b.clone())),
+			(Quantity::Distance(a), Quantity::InverseArea(b)) => Ok(Quantity::InverseDistance(a.clone() * b.clone())),
+			(Quantity::Distance(a), Quantity::InverseVolume(b)) => Ok(Quantity::InverseArea(a.clone() * b.clone())),
+			(Quantity::Distance(a), Quantity::AreaPerMass(b)) => Ok(Quantity::VolumePerMass(a.clone() * b.clone())),
+			(Quantity::Distance(a), Quantity::Density(b)) => Ok(Quantity::AreaDensity(a.clone() * b.clone())),
+			(Quantity::Distance(a), Quantity::DynamicViscosity(b)) => Ok(Quantity::MassFlowRate(a.clone() * b.clone())),
+			(Quantity::Distance(a), Quantity::Force(b)) => Ok(Quantity::Energy(a.clone() * b.clone())),
+			(Quantity::Distance(a), Quantity::Frequency(b)) => Ok(Quantity::Velocity(a.clone() * b.clone())),
+			(Quantity::Distance(a), Quantity::InverseEnergy(b)) => Ok(Quantity::InverseForce(a.clone() * b.clone())),
+			(Quantity::Distance(a), Quantity::InverseTorque(b)) => Ok(Quantity::InverseForce(a.clone() * b.clone())),
+			(Quantity::Distance(a), Quantity::InverseKinematicViscosity(b)) => Ok(Quantity::TimePerDistance(a.clone() * b.clone())),
+			(Quantity::Distance(a), Quantity::InverseMassFlowRate(b)) => Ok(Quantity::InverseDynamicViscosity(a.clone() * b.clone())),
+			(Quantity::Distance(a), Quantity::InverseMomentum(b)) => Ok(Quantity::InverseMassFlowRate(a.clone() * b.clone())),
+			(Quantity::Distance(a), Quantity::InverseVolumetricFlowRate(b)) => Ok(Quantity::InverseKinematicViscosity(a.clone() * b.clone())),
+			(Quantity::Distance(a), Quantity::KinematicViscosity(b)) => Ok(Quantity::VolumetricFlowRate(a.clone() * b.clone())),
+			(Quantity::Distance(a), Quantity::MassFlowRate(b)) => Ok(Quantity::Momentum(a.clone() * b.clone())),
+			(Quantity::Distance(a), Quantity::TimePerDistance(b)) => Ok(Quantity::Time(a.clone() * b.clone())),
+			(Quantity::Distance(a), Quantity::Velocity(b)) => Ok(Quantity::KinematicViscosity(a.clone() * b.clone())),
+			(Quantity::Distance(a), Quantity::InverseAbsorbedDose(b)) => Ok(Quantity::InverseAcceleration(a.clone() * b.clone())),
+			(Quantity::Distance(a), Quantity::InverseDoseEquivalent(b)) => Ok(Quantity::InverseAcceleration(a.clone() * b.clone())),
+			(Quantity::InverseAmount(a), Quantity::Mass(b)) => Ok(Quantity::MolarMass(a.clone() * b.clone())),
+			(Quantity::InverseAmount(a), Quantity::Time(b)) => Ok(Quantity::InverseCatalyticActivity(a.clone() * b.clone())),
+			(Quantity::InverseAmount(a), Quantity::CatalyticActivity(b)) => Ok(Quantity::Frequency(a.clone() * b.clone())),
+			(Quantity::InverseAmount(a), Quantity::Concentration(b)) => Ok(Quantity::InverseVolume(a.clone() * b.clone())),
+			(Quantity::InverseAmount(a), Quantity::Entropy(b)) => Ok(Quantity::MolarEntropy(a.clone() * b.clone())),
+			(Quantity::InverseAmount(a), Quantity::HeatCapacity(b)) => Ok(Quantity::MolarEntropy(a.clone() * b.clone())),
+			(Quantity::InverseAmount(a), Quantity::InverseMolarEnergy(b)) => Ok(Quantity::InverseEnergy(a.clone() * b.clone())),
+			(Quantity::InverseAmount(a), Quantity::InverseMolarEntropy(b)) => Ok(Quantity::InverseHeatCapacity(a.clone() * b.clone())),
+			(Quantity::InverseAmount(a), Quantity::Molality(b)) => Ok(Quantity::InverseMass(a.clone() * b.clone())),
+			(Quantity::InverseAmount(a), Quantity::Volume(b)) => Ok(Quantity::MolarVolume(a.clone() * b.clone())),
+			(Quantity::InverseAmount(a), Quantity::Energy(b)) => Ok(Quantity::MolarEnergy(a.clone() * b.clone())),
+			(Quantity::InverseAmount(a), Quantity::Torque(b)) => Ok(Quantity::MolarEnergy(a.clone() * b.clone())),
+			(Quantity::InverseCurrent(a), Quantity::Distance(b)) => Ok(Quantity::InverseMagneticFieldStrength(a.clone() * b.clone())),
+			(Quantity::InverseCurrent(a), Quantity::Charge(b)) => Ok(Quantity::Time(a.clone() * b.clone())),
+			(Quantity::InverseCurrent(a), Quantity::Conductance(b)) => Ok(Quantity::InverseVoltage(a.clone() * b.clone())),
+			(Quantity::InverseCurrent(a), Quantity::InverseInductance(b)) => Ok(Quantity::InverseMagneticFlux(a.clone() * b.clone())),
+			(Quantity::InverseCurrent(a), Quantity::InverseMagneticFlux(b)) => Ok(Quantity::InverseEnergy(a.clone() * b.clone())),
+			(Quantity::InverseCurrent(a), Quantity::InverseVoltage(b)) => Ok(Quantity::InversePower(a.clone() * b.clone())),
+			(Quantity::InverseCurrent(a), Quantity::LinearChargeDensity(b)) => Ok(Quantity::TimePerDistance(a.clone() * b.clone())),
+			(Quantity::InverseCurrent(a), Quantity::MagneticFieldStrength(b)) => Ok(Quantity::InverseDistance(a.clone() * b.clone())),
+			(Quantity::InverseCurrent(a), Quantity::MagneticFlux(b)) => Ok(Quantity::Inductance(a.clone() * b.clone())),
+			(Quantity::InverseCurrent(a), Quantity::SurfaceChargeDensity(b)) => Ok(Quantity::InverseKinematicViscosity(a.clone() * b.clone())),
+			(Quantity::InverseCurrent(a), Quantity::Voltage(b)) => Ok(Quantity::Resistance(a.clone() * b.clone())),
+			(Quantity::InverseCurrent(a), Quantity::VolumeChargeDensity(b)) => Ok(Quantity::InverseVolumetricFlowRate(a.clone() * b.clone())),
+			(Quantity::InverseCurrent(a), Quantity::Energy(b)) => Ok(Quantity::MagneticFlux(a.clone() * b.clone())),
+			(Quantity::InverseCurrent(a), Quantity::Torque(b)) => Ok(Quantity::MagneticFlux(a.clone() * b.clone())),
+			(Quantity::InverseCurrent(a), Quantity::Frequency(b)) => Ok(Quantity::InverseCharge(a.clone() * b.clone())),
+			(Quantity::InverseCurrent(a), Quantity::KinematicViscosity(b)) => Ok(Quantity::InverseSurfaceChargeDensity(a.clone() * b.clone())),
+			(Quantity::InverseCurrent(a), Quantity::Power(b)) => Ok(Quantity::Voltage(a.clone() * b.clone())),
+			(Quantity::InverseCurrent(a), Quantity::Velocity(b)) => Ok(Quantity::InverseLinearChargeDensity(a.clone() * b.clone())),
+			(Quantity::InverseCurrent(a), Quantity::VolumetricFlowRate(b)) => Ok(Quantity::InverseVolumeChargeDensity(a.clone() * b.clone())),
+			(Quantity::InverseDistance(a), Quantity::Current(b)) => Ok(Quantity::MagneticFieldStrength(a.clone() * b.clone())),
+			(Quantity::InverseDistance(a), Quantity::InverseDistance(b)) => Ok(Quantity::InverseArea(a.clone() * b.clone())),
+			(Quantity::InverseDistance(a), Quantity::Time(b)) => Ok(Quantity::TimePerDistance(a.clone() * b.clone())),
+			(Quantity::InverseDistance(a), Quantity::ThermalConductance(b)) => Ok(Quantity::ThermalConductivity(a.clone() * b.clone())),
+			(Quantity::InverseDistance(a), Quantity::ThermalResistivity(b)) => Ok(Quantity::ThermalResistance(a.clone() * b.clone())),
+			(Quantity::InverseDistance(a), Quantity::Charge(b)) => Ok(Quantity::LinearChargeDensity(a.clone() * b.clone())),
+			(Quantity::InverseDistance(a), Quantity::InverseElectricField(b)) => Ok(Quantity::InverseVoltage(a.clone() * b.clone())),
+			(Quantity::InverseDistance(a), Quantity::InverseLinearChargeDensity(b)) => Ok(Quantity::InverseCharge(a.clone() * b.clone())),
+			(Quantity::InverseDistance(a), Quantity::InverseMagneticFieldStrength(b)) => Ok(Quantity::InverseCurrent(a.clone() * b.clone())),
+			(Quantity::InverseDistance(a), Quantity::InverseSurfaceChargeDensity(b)) => Ok(Quantity::InverseLinearChargeDensity(a.clone() * b.clone())),
+			(Quantity::InverseDistance(a), Quantity::InverseVolumeChargeDensity(b)) => Ok(Quantity::InverseSurfaceChargeDensity(a.clone() * b.clone())),
+			(Quantity::InverseDistance(a), Quantity::LinearChargeDensity(b)) => Ok(Quantity::SurfaceChargeDensity(a.clone() * b.clone())),
+			(Quantity::InverseDistance(a), Quantity::SurfaceChargeDensity(b)) => Ok(Quantity::VolumeChargeDensity(a.clone() * b.clone())),
+			(Quantity::InverseDistance(a), Quantity::Voltage(b)) => Ok(Quantity::ElectricField(a.clone() * b.clone())),
+			(Quantity::InverseDistance(a), Quantity::Area(b)) => Ok(Quantity::Distance(a.clone() * b.clone())),
+			(Quantity::InverseDistance(a), Quantity::InverseArea(b)) => Ok(Quantity::InverseVolume(a.clone() * b.clone())),
+			(Quantity::InverseDistance(a), Quantity::Volume(b)) => Ok(Quantity::Area(a.clone() * b.clone())),
+			(Quantity::InverseDistance(a), Quantity::AreaDensity(b)) => Ok(Quantity::Density(a.clone() * b.clone())),
+			(Quantity::InverseDistance(a), Quantity::Energy(b)) => Ok(Quantity::Force(a.clone() * b.clone())),
+			(Quantity::InverseDistance(a), Quantity::Torque(b)) => Ok(Quantity::Force(a.clone() * b.clone())),
+			(Quantity::InverseDistance(a), Quantity::InverseDynamicViscosity(b)) => Ok(Quantity::InverseMassFlowRate(a.clone() * b.clone())),
+			(Quantity::InverseDistance(a), Quantity::InverseForce(b)) => Ok(Quantity::InverseEnergy(a.clone() * b.clone())),
+			(Quantity::InverseDistance(a), Quantity::InverseKinematicViscosity(b)) => Ok(Quantity::InverseVolumetricFlowRate(a.clone() * b.clone())),
+			(Quantity::InverseDistance(a), Quantity::InverseMassFlowRate(b)) => Ok(Quantity::InverseMomentum(a.clone() * b.clone())),
+			(Quantity::InverseDistance(a), Quantity::KinematicViscosity(b)) => Ok(Quantity::Velocity(a.clone() * b.clone())),
+			(Quantity::InverseDistance(a), Quantity::MassFlowRate(b)) => Ok(Quantity::DynamicViscosity(a.clone() * b.clone())),
+			(Quantity::InverseDistance(a), Quantity::Momentum(b)) => Ok(Quantity::MassFlowRate(a.clone() * b.clone())),
+			(Quantity::InverseDistance(a), Quantity::TimePerDistance(b)) => Ok(Quantity::InverseKinematicViscosity(a.clone() * b.clone())),
+			(Quantity::InverseDistance(a), Quantity::Velocity(b)) => Ok(Quantity::Frequency(a.clone() * b.clone())),
+			(Quantity::InverseDistance(a), Quantity::VolumePerMass(b)) => Ok(Quantity::AreaPerMass(a.clone() * b.clone())),
+			(Quantity::InverseDistance(a), Quantity::VolumetricFlowRate(b)) => Ok(Quantity::KinematicViscosity(a.clone() * b.clone())),
+			(Quantity::InverseLuminosity(a), Quantity::LuminousFlux(b)) => Ok(Quantity::SolidAngle(a.clone() * b.clone())),
+			(Quantity::InverseLuminosity(a), Quantity::InverseSolidAngle(b)) => Ok(Quantity::InverseLuminousFlux(a.clone() * b.clone())),
+			(Quantity::InverseMass(a), Quantity::Amount(b)) => Ok(Quantity::Molality(a.clone() * b.clone())),
+			(Quantity::InverseMass(a), Quantity::Time(b)) => Ok(Quantity::InverseMassFlowRate(a.clone() * b.clone())),
+			(Quantity::InverseMass(a), Quantity::Entropy(b)) => Ok(Quantity::SpecificHeatCapacity(a.clone() * b.clone())),
+			(Quantity::InverseMass(a), Quantity::HeatCapacity(b)) => Ok(Quantity::SpecificHeatCapacity(a.clone() * b.clone())),
+			(Quantity::InverseMass(a), Quantity::InverseSpecificHeatCapacity(b)) => Ok(Quantity::InverseHeatCapacity(a.clone() * b.clone())),
+			(Quantity::InverseMass(a), Quantity::MolarMass(b)) => Ok(Quantity::InverseAmount(a.clone() * b.clone())),
+			(Quantity::InverseMass(a), Quantity::Area(b)) => Ok(Quantity::AreaPerMass(a.clone() * b.clone())),
+			(Quantity::InverseMass(a), Quantity::Volume(b)) => Ok(Quantity::VolumePerMass(a.clone() * b.clone())),
+			(Quantity::InverseMass(a), Quantity::AreaDensity(b)) => Ok(Quantity::InverseArea(a.clone() * b.clone())),
+			(Quantity::InverseMass(a), Quantity::Density(b)) => Ok(Quantity::InverseVolume(a.clone() * b.clone())),
+			(Quantity::InverseMass(a), Quantity::Force(b)) => Ok(Quantity::Acceleration(a.clone() * b.clone())),
+			(Quantity::InverseMass(a), Quantity::InverseAcceleration(b)) => Ok(Quantity::InverseForce(a.clone() * b.clone())),
+			(Quantity::InverseMass(a), Quantity::MassFlowRate(b)) => Ok(Quantity::Frequency(a.clone() * b.clone())),
+			(Quantity::InverseMass(a), Quantity::MomentOfInertia(b)) => Ok(Quantity::Area(a.clone() * b.clone())),
+			(Quantity::InverseMass(a), Quantity::Momentum(b)) => Ok(Quantity::Velocity(a.clone() * b.clone())),
+			(Quantity::InverseMass(a), Quantity::TimePerDistance(b)) => Ok(Quantity::InverseMomentum(a.clone() * b.clone())),
+			(Quantity::InverseMass(a), Quantity::InverseAbsorbedDose(b)) => Ok(Quantity::InverseEnergy(a.clone() * b.clone())),
+			(Quantity::InverseMass(a), Quantity::InverseDoseEquivalent(b)) => Ok(Quantity::InverseEnergy(a.clone() * b.clone())),
+			(Quantity::InverseTemperature(a), Quantity::InverseEntropy(b)) => Ok(Quantity::InverseEnergy(a.clone() * b.clone())),
+			(Quantity::InverseTemperature(a), Quantity::InverseHeatCapacity(b)) => Ok(Quantity::InverseEnergy(a.clone() * b.clone())),
+			(Quantity::InverseTemperature(a), Quantity::InverseMolarEntropy(b)) => Ok(Quantity::InverseMolarEnergy(a.clone() * b.clone())),
+			(Quantity::InverseTemperature(a), Quantity::MolarEnergy(b)) => Ok(Quantity::MolarEntropy(a.clone() * b.clone())),
+			(Quantity::InverseTemperature(a), Quantity::ThermalResistance(b)) => Ok(Quantity::InversePower(a.clone() * b.clone())),
+			(Quantity::InverseTemperature(a), Quantity::Energy(b)) => Ok(Quantity::HeatCapacity(a.clone() * b.clone())),
+			(Quantity::InverseTemperature(a), Quantity::Torque(b)) => Ok(Quantity::HeatCapacity(a.clone() * b.clone())),
+			(Quantity::InverseTemperature(a), Quantity::Power(b)) => Ok(Quantity::ThermalConductance(a.clone() * b.clone())),
+			(Quantity::Luminosity(a), Quantity::InverseLuminousFlux(b)) => Ok(Quantity::InverseSolidAngle(a.clone() * b.clone())),
+			(Quantity::Luminosity(a), Quantity::SolidAngle(b)) => Ok(Quantity::LuminousFlux(a.clone() * b.clone())),
+			(Quantity::Mass(a), Quantity::InverseAmount(b)) => Ok(Quantity::MolarMass(a.clone() * b.clone())),
+			(Quantity::Mass(a), Quantity::InverseEntropy(b)) => Ok(Quantity::InverseSpecificHeatCapacity(a.clone() * b.clone())),
+			(Quantity::Mass(a), Quantity::InverseHeatCapacity(b)) => Ok(Quantity::InverseSpecificHeatCapacity(a.clone() * b.clone())),
+			(Quantity::Mass(a), Quantity::Molality(b)) => Ok(Quantity::Amount(a.clone() * b.clone())),
+			(Quantity::Mass(a), Quantity::SpecificHeatCapacity(b)) => Ok(Quantity::HeatCapacity(a.clone() * b.clone())),
+			(Quantity::Mass(a), Quantity::InverseArea(b)) => Ok(Quantity::AreaDensity(a.clone() * b.clone())),
+			(Quantity::Mass(a), Quantity::InverseVolume(b)) => Ok(Quantity::Density(a.clone() * b.clone())),
+			(Quantity::Mass(a), Quantity::Acceleration(b)) => Ok(Quantity::Force(a.clone() * b.clone())),
+			(Quantity::Mass(a), Quantity::AreaPerMass(b)) => Ok(Quantity::Area(a.clone() * b.clone())),
+			(Quantity::Mass(a), Quantity::Frequency(b)) => Ok(Quantity::MassFlowRate(a.clone() * b.clone())),
+			(Quantity::Mass(a), Quantity::InverseForce(b)) => Ok(Quantity::InverseAcceleration(a.clone() * b.clone())),
+			(Quantity::Mass(a), Quantity::InverseMassFlowRate(b)) => Ok(Quantity::Time(a.clone() * b.clone())),
+			(Quantity::Mass(a), Quantity::InverseMomentOfInertia(b)) => Ok(Quantity::InverseArea(a.clone() * b.clone())),
+			(Quantity::Mass(a), Quantity::InverseMomentum(b)) => Ok(Quantity::TimePerDistance(a.clone() * b.clone())),
+			(Quantity::Mass(a), Quantity::Velocity(b)) => Ok(Quantity::Momentum(a.clone() * b.clone())),
+			(Quantity::Mass(a), Quantity::VolumePerMass(b)) => Ok(Quantity::Volume(a.clone() * b.clone())),
+			(Quantity::Mass(a), Quantity::AbsorbedDose(b)) => Ok(Quantity::Energy(a.clone() * b.clone())),
+			(Quantity::Mass(a), Quantity::DoseEquivalent(b)) => Ok(Quantity::Energy(a.clone() * b.clone())),
+			(Quantity::Temperature(a), Quantity::Entropy(b)) => Ok(Quantity::Energy(a.clone() * b.clone())),
+			(Quantity::Temperature(a), Quantity::HeatCapacity(b)) => Ok(Quantity::Energy(a.clone() * b.clone())),
+			(Quantity::Temperature(a), Quantity::InverseMolarEnergy(b)) => Ok(Quantity::InverseMolarEntropy(a.clone() * b.clone())),
+			(Quantity::Temperature(a), Quantity::MolarEntropy(b)) => Ok(Quantity::MolarEnergy(a.clone() * b.clone())),
+			(Quantity::Temperature(a), Quantity::ThermalConductance(b)) => Ok(Quantity::Power(a.clone() * b.clone())),
+			(Quantity::Temperature(a), Quantity::InverseEnergy(b)) => Ok(Quantity::InverseHeatCapacity(a.clone() * b.clone())),
+			(Quantity::Temperature(a), Quantity::InverseTorque(b)) => Ok(Quantity::InverseHeatCapacity(a.clone() * b.clone())),
+			(Quantity::Temperature(a), Quantity::InversePower(b)) => Ok(Quantity::ThermalResistance(a.clone() * b.clone())),
+			(Quantity::Temperature(a), Quantity::InverseAbsorbedDose(b)) => Ok(Quantity::InverseSpecificHeatCapacity(a.clone() * b.clone())),
+			(Quantity::Temperature(a), Quantity::InverseDoseEquivalent(b)) => Ok(Quantity::InverseSpecificHeatCapacity(a.clone() * b.clone())),
+			(Quantity::TemperatureInterval(a), Quantity::Entropy(b)) => Ok(Quantity::Energy(a.clone() * b.clone())),
+			(Quantity::TemperatureInterval(a), Quantity::HeatCapacity(b)) => Ok(Quantity::Energy(a.clone() * b.clone())),
+			(Quantity::TemperatureInterval(a), Quantity::InverseMolarEnergy(b)) => Ok(Quantity::InverseMolarEntropy(a.clone() * b.clone())),
+			(Quantity::TemperatureInterval(a), Quantity::MolarEntropy(b)) => Ok(Quantity::MolarEnergy(a.clone() * b.clone())),
+			(Quantity::TemperatureInterval(a), Quantity::SpecificHeatCapacity(b)) => Ok(Quantity::AbsorbedDose(a.clone() * b.clone())),
+			(Quantity::TemperatureInterval(a), Quantity::ThermalConductance(b)) => Ok(Quantity::Power(a.clone() * b.clone())),
+			(Quantity::TemperatureInterval(a), Quantity::InverseEnergy(b)) => Ok(Quantity::InverseHeatCapacity(a.clone() * b.clone())),
+			(Quantity::TemperatureInterval(a), Quantity::InverseTorque(b)) => Ok(Quantity::InverseHeatCapacity(a.clone() * b.clone())),
+			(Quantity::TemperatureInterval(a), Quantity::InversePower(b)) => Ok(Quantity::ThermalResistance(a.clone() * b.clone())),
+			(Quantity::TemperatureInterval(a), Quantity::InverseAbsorbedDose(b)) => Ok(Quantity::InverseSpecificHeatCapacity(a.clone() * b.clone())),
+			(Quantity::TemperatureInterval(a), Quantity::InverseDoseEquivalent(b)) => Ok(Quantity::InverseSpecificHeatCapacity(a.clone() * b.clone())),
+			(Quantity::Time(a), Quantity::Current(b)) => Ok(Quantity::Charge(a.clone() * b.clone())),
+			(Quantity::Time(a), Quantity::InverseAmount(b)) => Ok(Quantity::InverseCatalyticActivity(a.clone() * b.clone())),
+			(Quantity::Time(a), Quantity::InverseDistance(b)) => Ok(Quantity::TimePerDistance(a.clone() * b.clone())),
+			(Quantity::Time(a), Quantity::InverseMass(b)) => Ok(Quantity::InverseMassFlowRate(a.clone() * b.clone())),
+			(Quantity::Time(a), Quantity::CatalyticActivity(b)) => Ok(Quantity::Amount(a.clone() * b.clone())),
+			(Quantity::Time(a), Quantity::InverseEntropy(b)) => Ok(Quantity::ThermalResistance(a.clone() * b.clone())),
+			(Quantity::Time(a), Quantity::InverseHeatCapacity(b)) => Ok(Quantity::ThermalResistance(a.clone() * b.clone())),
+			(Quantity::Time(a), Quantity::ThermalConductance(b)) => Ok(Quantity::HeatCapacity(a.clone() * b.clone())),
+			(Quantity::Time(a), Quantity::Conductance(b)) => Ok(Quantity::Capacitance(a.clone() * b.clone())),
+			(Quantity::Time(a), Quantity::Elastance(b)) => Ok(Quantity::Resistance(a.clone() * b.clone())),
+			(Quantity::Time(a), Quantity::InverseCharge(b)) => Ok(Quantity::InverseCurrent(a.clone() * b.clone())),
+			(Quantity::Time(a), Quantity::InverseInductance(b)) => Ok(Quantity::Conductance(a.clone() * b.clone())),
+			(Quantity::Time(a), Quantity::InverseLinearChargeDensity(b)) => Ok(Quantity::InverseMagneticFieldStrength(a.clone() * b.clone())),
+			(Quantity::Time(a), Quantity::InverseMagneticFlux(b)) => Ok(Quantity::InverseVoltage(a.clone() * b.clone())),
+			(Quantity::Time(a), Quantity::MagneticFieldStrength(b)) => Ok(Quantity::LinearChargeDensity(a.clone() * b.clone())),
+			(Quantity::Time(a), Quantity::Resistance(b)) => Ok(Quantity::Inductance(a.clone() * b.clone())),
+			(Quantity::Time(a), Quantity::Voltage(b)) => Ok(Quantity::MagneticFlux(a.clone() * b.clone())),
+			(Quantity::Time(a), Quantity::InverseAngle(b)) => Ok(Quantity::InverseAngularVelocity(a.clone() * b.clone())),
+			(Quantity::Time(a), Quantity::InverseArea(b)) => Ok(Quantity::InverseKinematicViscosity(a.clone() * b.clone())),
+			(Quantity::Time(a), Quantity::InverseVolume(b)) => Ok(Quantity::InverseVolumetricFlowRate(a.clone() * b.clone())),
+			(Quantity::Time(a), Quantity::Acceleration(b)) => Ok(Quantity::Velocity(a.clone() * b.clone())),
+			(Quantity::Time(a), Quantity::AngularAcceleration(b)) => Ok(Quantity::AngularVelocity(a.clone() * b.clone())),
+			(Quantity::Time(a), Quantity::AngularVelocity(b)) => Ok(Quantity::Angle(a.clone() * b.clone())),
+			(Quantity::Time(a), Quantity::Force(b)) => Ok(Quantity::Momentum(a.clone() * b.clone())),
+			(Quantity::Time(a), Quantity::InverseAcceleration(b)) => Ok(Quantity::InverseJerk(a.clone() * b.clone())),
+			(Quantity::Time(a), Quantity::InverseAngularVelocity(b)) => Ok(Quantity::InverseAngularAcceleration(a.clone() * b.clone())),
+			(Quantity::Time(a), Quantity::InverseDynamicViscosity(b)) => Ok(Quantity::InversePressure(a.clone() * b.clone())),
+			(Quantity::Time(a), Quantity::InverseEnergy(b)) => Ok(Quantity::InversePower(a.clone() * b.clone())),
+			(Quantity::Time(a), Quantity::InverseTorque(b)) => Ok(Quantity::InversePower(a.clone() * b.clone())),
+			(Quantity::Time(a), Quantity::InverseMomentum(b)) => Ok(Quantity::InverseForce(a.clone() * b.clone())),
+			(Quantity::Time(a), Quantity::Jerk(b)) => Ok(Quantity::Acceleration(a.clone() * b.clone())),
+			(Quantity::Time(a), Quantity::KinematicViscosity(b)) => Ok(Quantity::Area(a.clone() * b.clone())),
+			(Quantity::Time(a), Quantity::MassFlowRate(b)) => Ok(Quantity::Mass(a.clone() * b.clone())),
+			(Quantity::Time(a), Quantity::Power(b)) => Ok(Quantity::Energy(a.clone() * b.clone())),
+			(Quantity::Time(a), Quantity::Pressure(b)) => Ok(Quantity::DynamicViscosity(a.clone() * b.clone())),
+			(Quantity::Time(a), Quantity::TimePerDistance(b)) => Ok(Quantity::InverseAcceleration(a.clone() * b.clone())),
+			(Quantity::Time(a), Quantity::Velocity(b)) => Ok(Quantity::Distance(a.clone() * b.clone())),
+			(Quantity::Time(a), Quantity::VolumetricFlowRate(b)) => Ok(Quantity::Volume(a.clone() * b.clone())),
+			(Quantity::Time(a), Quantity::AbsorbedDoseRate(b)) => Ok(Quantity::AbsorbedDose(a.clone() * b.clone())),
+			(Quantity::Time(a), Quantity::DoseEquivalentRate(b)) => Ok(Quantity::DoseEquivalent(a.clone() * b.clone())),
+			(Quantity::CatalyticActivity(a), Quantity::InverseAmount(b)) => Ok(Quantity::Frequency(a.clone() * b.clone())),
+			(Quantity::CatalyticActivity(a), Quantity::Time(b)) => Ok(Quantity::Amount(a.clone() * b.clone())),
+			(Quantity::CatalyticActivity(a), Quantity::MolarEnergy(b)) => Ok(Quantity::Power(a.clone() * b.clone())),
+			(Quantity::CatalyticActivity(a), Quantity::MolarEntropy(b)) => Ok(Quantity::ThermalConductance(a.clone() * b.clone())),
+			(Quantity::CatalyticActivity(a), Quantity::MolarMass(b)) => Ok(Quantity::MassFlowRate(a.clone() * b.clone())),
+			(Quantity::CatalyticActivity(a), Quantity::MolarVolume(b)) => Ok(Quantity::VolumetricFlowRate(a.clone() * b.clone())),
+			(Quantity::CatalyticActivity(a), Quantity::ThermalResistance(b)) => Ok(Quantity::InverseMolarEntropy(a.clone() * b.clone())),
+			(Quantity::CatalyticActivity(a), Quantity::InverseMassFlowRate(b)) => Ok(Quantity::Molality(a.clone() * b.clone())),
+			(Quantity::CatalyticActivity(a), Quantity::InversePower(b)) => Ok(Quantity::InverseMolarEnergy(a.clone() * b.clone())),
+			(Quantity::CatalyticActivity(a), Quantity::InverseVolumetricFlowRate(b)) => Ok(Quantity::Concentration(a.clone() * b.clone())),
+			(Quantity::Concentration(a), Quantity::InverseAmount(b)) => Ok(Quantity::InverseVolume(a.clone() * b.clone())),
+			(Quantity::Concentration(a), Quantity::InverseCatalyticActivity(b)) => Ok(Quantity::InverseVolumetricFlowRate(a.clone() * b.clone())),
+			(Quantity::Concentration(a), Quantity::MolarEnergy(b)) => Ok(Quantity::Pressure(a.clone() * b.clone())),
+			(Quantity::Concentration(a), Quantity::MolarMass(b)) => Ok(Quantity::Density(a.clone() * b.clone())),
+			(Quantity::Concentration(a), Quantity::Volume(b)) => Ok(Quantity::Amount(a.clone() * b.clone())),
+			(Quantity::Concentration(a), Quantity::InversePressure(b)) => Ok(Quantity::InverseMolarEnergy(a.clone() * b.clone())),
+			(Quantity::Concentration(a), Quantity::VolumePerMass(b)) => Ok(Quantity::Molality(a.clone() * b.clone())),
+			(Quantity::Concentration(a), Quantity::VolumetricFlowRate(b)) => Ok(Quantity::CatalyticActivity(a.clone() * b.clone())),
+			(Quantity::Entropy(a), Quantity::InverseAmount(b)) => Ok(Quantity::MolarEntropy(a.clone() * b.clone())),
+			(Quantity::Entropy(a), Quantity::InverseMass(b)) => Ok(Quantity::SpecificHeatCapacity(a.clone() * b.clone())),
+			(Quantity::Entropy(a), Quantity::Temperature(b)) => Ok(Quantity::Energy(a.clone() * b.clone())),
+			(Quantity::Entropy(a), Quantity::TemperatureInterval(b)) => Ok(Quantity::Energy(a.clone() * b.clone())),
+			(Quantity::Entropy(a), Quantity::InverseMolarEntropy(b)) => Ok(Quantity::Amount(a.clone() * b.clone())),
+			(Quantity::Entropy(a), Quantity::InverseSpecificHeatCapacity(b)) => Ok(Quantity::Mass(a.clone() * b.clone())),
+			(Quantity::Entropy(a), Quantity::ThermalResistance(b)) => Ok(Quantity::Time(a.clone() * b.clone())),
+			(Quantity::Entropy(a), Quantity::Frequency(b)) => Ok(Quantity::ThermalConductance(a.clone() * b.clone())),
+			(Quantity::Entropy(a), Quantity::InverseEnergy(b)) => Ok(Quantity::InverseTemperature(a.clone() * b.clone())),
+			(Quantity::Entropy(a), Quantity::InverseTorque(b)) => Ok(Quantity::InverseTemperature(a.clone() * b.clone())),
+			(Quantity::HeatCapacity(a), Quantity::InverseAmount(b)) => Ok(Quantity::MolarEntropy(a.clone() * b.clone())),
+			(Quantity::HeatCapacity(a), Quantity::InverseMass(b)) => Ok(Quantity::SpecificHeatCapacity(a.clone() * b.clone())),
+			(Quantity::HeatCapacity(a), Quantity::Temperature(b)) => Ok(Quantity::Energy(a.clone() * b.clone())),
+			(Quantity::HeatCapacity(a), Quantity::TemperatureInterval(b)) => Ok(Quantity::Energy(a.clone() * b.clone())),
+			(Quantity::HeatCapacity(a), Quantity::InverseMolarEntropy(b)) => Ok(Quantity::Amount(a.clone() * b.clone())),
+			(Quantity::HeatCapacity(a), Quantity::InverseSpecificHeatCapacity(b)) => Ok(Quantity::Mass(a.clone() * b.clone())),
+			(Quantity::HeatCapacity(a), Quantity::ThermalResistance(b)) => Ok(Quantity::Time(a.clone() * b.clone())),
+			(Quantity::HeatCapacity(a), Quantity::Frequency(b)) => Ok(Quantity::ThermalConductance(a.clone() * b.clone())),
+			(Quantity::HeatCapacity(a), Quantity::InverseEnergy(b)) => Ok(Quantity::InverseTemperature(a.clone() * b.clone())),
+			(Quantity::HeatCapacity(a), Quantity::InverseTorque(b)) => Ok(Quantity::InverseTemperature(a.clone() * b.clone())),
+			(Quantity::InverseCatalyticActivity(a), Quantity::Amount(b)) => Ok(Quantity::Time(a.clone() * b.clone())),
+			(Quantity::InverseCatalyticActivity(a), Quantity::Concentration(b)) => Ok(Quantity::InverseVolumetricFlowRate(a.clone() * b.clone())),
+			(Quantity::InverseCatalyticActivity(a), Quantity::InverseMolarEnergy(b)) => Ok(Quantity::InversePower(a.clone() * b.clone())),
+			(Quantity::InverseCatalyticActivity(a), Quantity::InverseMolarEntropy(b)) => Ok(Quantity::ThermalResistance(a.clone() * b.clone())),
+			(Quantity::InverseCatalyticActivity(a), Quantity::Molality(b)) => Ok(Quantity::InverseMassFlowRate(a.clone() * b.clone())),
+			(Quantity::InverseCatalyticActivity(a), Quantity::ThermalConductance(b)) => Ok(Quantity::MolarEntropy(a.clone() * b.clone())),
+			(Quantity::InverseCatalyticActivity(a), Quantity::Frequency(b)) => Ok(Quantity::InverseAmount(a.clone() * b.clone())),
+			(Quantity::InverseCatalyticActivity(a), Quantity::MassFlowRate(b)) => Ok(Quantity::MolarMass(a.clone() * b.clone())),
+			(Quantity::InverseCatalyticActivity(a), Quantity::Power(b)) => Ok(Quantity::MolarEnergy(a.clone() * b.clone())),
+			(Quantity::InverseCatalyticActivity(a), Quantity::VolumetricFlowRate(b)) => Ok(Quantity::MolarVolume(a.clone() * b.clone())),
+			(Quantity::InverseEntropy(a), Quantity::Amount(b)) => Ok(Quantity::InverseMolarEntropy(a.clone() * b.clone())),
+			(Quantity::InverseEntropy(a), Quantity::InverseTemperature(b)) => Ok(Quantity::InverseEnergy(a.clone() * b.clone())),
+			(Quantity::InverseEntropy(a), Quantity::Mass(b)) => Ok(Quantity::InverseSpecificHeatCapacity(a.clone() * b.clone())),
+			(Quantity::InverseEntropy(a), Quantity::Time(b)) => Ok(Quantity::ThermalResistance(a.clone() * b.clone())),
+			(Quantity::InverseEntropy(a), Quantity::MolarEntropy(b)) => Ok(Quantity::InverseAmount(a.clone() * b.clone())),
+			(Quantity::InverseEntropy(a), Quantity::SpecificHeatCapacity(b)) => Ok(Quantity::InverseMass(a.clone() * b.clone())),
+			(Quantity::InverseEntropy(a), Quantity::ThermalConductance(b)) => Ok(Quantity::Frequency(a.clone() * b.clone())),
+			(Quantity::InverseEntropy(a), Quantity::Energy(b)) => Ok(Quantity::Temperature(a.clone() * b.clone())),
+			(Quantity::InverseEntropy(a), Quantity::Torque(b)) => Ok(Quantity::Temperature(a.clone() * b.clone())),
+			(Quantity::InverseHeatCapacity(a), Quantity::Amount(b)) => Ok(Quantity::InverseMolarEntropy(a.clone() * b.clone())),
+			(Quantity::InverseHeatCapacity(a), Quantity::InverseTemperature(b)) => Ok(Quantity::InverseEnergy(a.clone() * b.clone())),
+			(Quantity::InverseHeatCapacity(a), Quantity::Mass(b)) => Ok(Quantity::InverseSpecificHeatCapacity(a.clone() * b.clone())),
+			(Quantity::InverseHeatCapacity(a), Quantity::Time(b)) => Ok(Quantity::ThermalResistance(a.clone() * b.clone())),
+			(Quantity::InverseHeatCapacity(a), Quantity::MolarEntropy(b)) => Ok(Quantity::InverseAmount(a.clone() * b.clone())),
+			(Quantity::InverseHeatCapacity(a), Quantity::SpecificHeatCapacity(b)) => Ok(Quantity::InverseMass(a.clone() * b.clone())),
+			(Quantity::InverseHeatCapacity(a), Quantity::ThermalConductance(b)) => Ok(Quantity::Frequency(a.clone() * b.clone())),
+			(Quantity::InverseHeatCapacity(a), Quantity::Energy(b)) => Ok(Quantity::Temperature(a.clone() * b.clone())),
+			(Quantity::InverseHeatCapacity(a), Quantity::Torque(b)) => Ok(Quantity::Temperature(a.clone() * b.clone())),
+			(Quantity::InverseMolarEnergy(a), Quantity::InverseAmount(b)) => Ok(Quantity::InverseEnergy(a.clone() * b.clone())),
+			(Quantity::InverseMolarEnergy(a), Quantity::Temperature(b)) => Ok(Quantity::InverseMolarEntropy(a.clone() * b.clone())),
+			(Quantity::InverseMolarEnergy(a), Quantity::TemperatureInterval(b)) => Ok(Quantity::InverseMolarEntropy(a.clone() * b.clone())),
+			(Quantity::InverseMolarEnergy(a), Quantity::InverseCatalyticActivity(b)) => Ok(Quantity::InversePower(a.clone() * b.clone())),
+			(Quantity::InverseMolarEnergy(a), Quantity::MolarEntropy(b)) => Ok(Quantity::InverseTemperature(a.clone() * b.clone())),
+			(Quantity::InverseMolarEnergy(a), Quantity::MolarVolume(b)) => Ok(Quantity::InversePressure(a.clone() * b.clone())),
+			(Quantity::InverseMolarEnergy(a), Quantity::Energy(b)) => Ok(Quantity::Amount(a.clone() * b.clone())),
+			(Quantity::InverseMolarEnergy(a), Quantity::Torque(b)) => Ok(Quantity::Amount(a.clone() * b.clone())),
+			(Quantity::InverseMolarEnergy(a), Quantity::Power(b)) => Ok(Quantity::CatalyticActivity(a.clone() * b.clone())),
+			(Quantity::InverseMolarEnergy(a), Quantity::Pressure(b)) => Ok(Quantity::Concentration(a.clone() * b.clone())),
+			(Quantity::InverseMolarEntropy(a), Quantity::InverseAmount(b)) => Ok(Quantity::InverseHeatCapacity(a.clone() * b.clone())),
+			(Quantity::InverseMolarEntropy(a), Quantity::InverseTemperature(b)) => Ok(Quantity::InverseMolarEnergy(a.clone() * b.clone())),
+			(Quantity::InverseMolarEntropy(a), Quantity::Entropy(b)) => Ok(Quantity::Amount(a.clone() * b.clone())),
+			(Quantity::InverseMolarEntropy(a), Quantity::HeatCapacity(b)) => Ok(Quantity::Amount(a.clone() * b.clone())),
+			(Quantity::InverseMolarEntropy(a), Quantity::InverseCatalyticActivity(b)) => Ok(Quantity::ThermalResistance(a.clone() * b.clone())),
+			(Quantity::InverseMolarEntropy(a), Quantity::MolarEnergy(b)) => Ok(Quantity::Temperature(a.clone() * b.clone())),
+			(Quantity::InverseMolarEntropy(a), Quantity::MolarMass(b)) => Ok(Quantity::InverseSpecificHeatCapacity(a.clone() * b.clone())),
+			(Quantity::InverseMolarEntropy(a), Quantity::SpecificHeatCapacity(b)) => Ok(Quantity::Molality(a.clone() * b.clone())),
+			(Quantity::InverseMolarEntropy(a), Quantity::ThermalConductance(b)) => Ok(Quantity::CatalyticActivity(a.clone() * b.clone())),
+			(Quantity::InverseSpecificHeatCapacity(a), Quantity::InverseMass(b)) => Ok(Quantity::InverseHeatCapacity(a.clone() * b.clone())),
+			(Quantity::InverseSpecificHeatCapacity(a), Quantity::Entropy(b)) => Ok(Quantity::Mass(a.clone() * b.clone())),
+			(Quantity::InverseSpecificHeatCapacity(a), Quantity::HeatCapacity(b)) => Ok(Quantity::Mass(a.clone() * b.clone())),
+			(Quantity::InverseSpecificHeatCapacity(a), Quantity::Molality(b)) => Ok(Quantity::InverseMolarEntropy(a.clone() * b.clone())),
+			(Quantity::InverseSpecificHeatCapacity(a), Quantity::MolarEntropy(b)) => Ok(Quantity::MolarMass(a.clone() * b.clone())),
+			(Quantity::InverseSpecificHeatCapacity(a), Quantity::ThermalConductance(b)) => Ok(Quantity::MassFlowRate(a.clone() * b.clone())),
+			(Quantity::InverseSpecificHeatCapacity(a), Quantity::ThermalConductivity(b)) => Ok(Quantity::DynamicViscosity(a.clone() * b.clone())),
+			(Quantity::InverseSpecificHeatCapacity(a), Quantity::InverseDynamicViscosity(b)) => Ok(Quantity::ThermalResistivity(a.clone() * b.clone())),
+			(Quantity::InverseSpecificHeatCapacity(a), Quantity::InverseMassFlowRate(b)) => Ok(Quantity::ThermalResistance(a.clone() * b.clone())),
+			(Quantity::Molality(a), Quantity::InverseAmount(b)) => Ok(Quantity::InverseMass(a.clone() * b.clone())),
+			(Quantity::Molality(a), Quantity::Mass(b)) => Ok(Quantity::Amount(a.clone() * b.clone())),
+			(Quantity::Molality(a), Quantity::InverseCatalyticActivity(b)) => Ok(Quantity::InverseMassFlowRate(a.clone() * b.clone())),
+			(Quantity::Molality(a), Quantity::InverseSpecificHeatCapacity(b)) => Ok(Quantity::InverseMolarEntropy(a.clone() * b.clone())),
+			(Quantity::Molality(a), Quantity::MolarEntropy(b)) => Ok(Quantity::SpecificHeatCapacity(a.clone() * b.clone())),
+			(Quantity::Molality(a), Quantity::MolarVolume(b)) => Ok(Quantity::VolumePerMass(a.clone() * b.clone())),
+			(Quantity::Molality(a), Quantity::Density(b)) => Ok(Quantity::Concentration(a.clone() * b.clone())),
+			(Quantity::Molality(a), Quantity::MassFlowRate(b)) => Ok(Quantity::CatalyticActivity(a.clone() * b.clone())),
+			(Quantity::Molality(a), Quantity::InverseAbsorbedDose(b)) => Ok(Quantity::InverseMolarEnergy(a.clone() * b.clone())),
+			(Quantity::Molality(a), Quantity::InverseDoseEquivalent(b)) => Ok(Quantity::InverseMolarEnergy(a.clone() * b.clone())),
+			(Quantity::MolarEnergy(a), Quantity::Amount(b)) => Ok(Quantity::Energy(a.clone() * b.clone())),
+			(Quantity::MolarEnergy(a), Quantity::InverseTemperature(b)) => Ok(Quantity::MolarEntropy(a.clone() * b.clone())),
+			(Quantity::MolarEnergy(a), Quantity::CatalyticActivity(b)) => Ok(Quantity::Power(a.clone() * b.clone())),
+			(Quantity::MolarEnergy(a), Quantity::Concentration(b)) => Ok(Quantity::Pressure(a.clone() * b.clone())),
+			(Quantity::MolarEnergy(a), Quantity::InverseMolarEntropy(b)) => Ok(Quantity::Temperature(a.clone() * b.clone())),
+			(Quantity::MolarEnergy(a), Quantity::InverseEnergy(b)) => Ok(Quantity::InverseAmount(a.clone() * b.clone())),
+			(Quantity::MolarEnergy(a), Quantity::InverseTorque(b)) => Ok(Quantity::InverseAmount(a.clone() * b.clone())),
+			(Quantity::MolarEnergy(a), Quantity::InversePower(b)) => Ok(Quantity::InverseCatalyticActivity(a.clone() * b.clone())),
+			(Quantity::MolarEnergy(a), Quantity::InversePressure(b)) => Ok(Quantity::MolarVolume(a.clone() * b.clone())),
+			(Quantity::MolarEnergy(a), Quantity::InverseAbsorbedDose(b)) => Ok(Quantity::MolarMass(a.clone() * b.clone())),
+			(Quantity::MolarEnergy(a), Quantity::InverseDoseEquivalent(b)) => Ok(Quantity::MolarMass(a.clone() * b.clone())),
+			(Quantity::MolarEntropy(a), Quantity::Amount(b)) => Ok(Quantity::HeatCapacity(a.clone() * b.clone())),
+			(Quantity::MolarEntropy(a), Quantity::Temperature(b)) => Ok(Quantity::MolarEnergy(a.clone() * b.clone())),
+			(Quantity::MolarEntropy(a), Quantity::TemperatureInterval(b)) => Ok(Quantity::MolarEnergy(a.clone() * b.clone())),
+			(Quantity::MolarEntropy(a), Quantity::CatalyticActivity(b)) => Ok(Quantity::ThermalConductance(a.clone() * b.clone())),
+			(Quantity::MolarEntropy(a), Quantity::InverseEntropy(b)) => Ok(Quantity::InverseAmount(a.clone() * b.clone())),
+			(Quantity::MolarEntropy(a), Quantity::InverseHeatCapacity(b)) => Ok(Quantity::InverseAmount(a.clone() * b.clone())),
+			(Quantity::MolarEntropy(a), Quantity::InverseMolarEnergy(b)) => Ok(Quantity::InverseTemperature(a.clone() * b.clone())),
+			(Quantity::MolarEntropy(a), Quantity::InverseSpecificHeatCapacity(b)) => Ok(Quantity::MolarMass(a.clone() * b.clone())),
+			(Quantity::MolarEntropy(a), Quantity::Molality(b)) => Ok(Quantity::SpecificHeatCapacity(a.clone() * b.clone())),
+			(Quantity::MolarEntropy(a), Quantity::ThermalResistance(b)) => Ok(Quantity::InverseCatalyticActivity(a.clone() * b.clone())),
+			(Quantity::MolarMass(a), Quantity::Amount(b)) => Ok(Quantity::Mass(a.clone() * b.clone())),
+			(Quantity::MolarMass(a), Quantity::InverseMass(b)) => Ok(Quantity::InverseAmount(a.clone() * b.clone())),
+			(Quantity::MolarMass(a), Quantity::CatalyticActivity(b)) => Ok(Quantity::MassFlowRate(a.clone() * b.clone())),
+			(Quantity::MolarMass(a), Quantity::Concentration(b)) => Ok(Quantity::Density(a.clone() * b.clone())),
+			(Quantity::MolarMass(a), Quantity::InverseMolarEntropy(b)) => Ok(Quantity::InverseSpecificHeatCapacity(a.clone() * b.clone())),
+			(Quantity::MolarMass(a), Quantity::SpecificHeatCapacity(b)) => Ok(Quantity::MolarEntropy(a.clone() * b.clone())),
+			(Quantity::MolarMass(a), Quantity::InverseMassFlowRate(b)) => Ok(Quantity::InverseCatalyticActivity(a.clone() * b.clone())),
+			(Quantity::MolarMass(a), Quantity::VolumePerMass(b)) => Ok(Quantity::MolarVolume(a.clone() * b.clone())),
+			(Quantity::MolarVolume(a), Quantity::Amount(b)) => Ok(Quantity::Volume(a.clone() * b.clone())),
+			(Quantity::MolarVolume(a), Quantity::CatalyticActivity(b)) => Ok(Quantity::VolumetricFlowRate(a.clone() * b.clone())),
+			(Quantity::MolarVolume(a), Quantity::InverseMolarEnergy(b)) => Ok(Quantity::InversePressure(a.clone() * b.clone())),
+			(Quantity::MolarVolume(a), Quantity::Molality(b)) => Ok(Quantity::VolumePerMass(a.clone() * b.clone())),
+			(Quantity::MolarVolume(a), Quantity::InverseVolume(b)) => Ok(Quantity::InverseAmount(a.clone() * b.clone())),
+			(Quantity::MolarVolume(a), Quantity::Density(b)) => Ok(Quantity::MolarMass(a.clone() * b.clone())),
+			(Quantity::MolarVolume(a), Quantity::InverseVolumetricFlowRate(b)) => Ok(Quantity::InverseCatalyticActivity(a.clone() * b.clone())),
+			(Quantity::MolarVolume(a), Quantity::Pressure(b)) => Ok(Quantity::MolarEnergy(a.clone() * b.clone())),
+			(Quantity::SpecificHeatCapacity(a), Quantity::Mass(b)) => Ok(Quantity::HeatCapacity(a.clone() * b.clone())),
+			(Quantity::SpecificHeatCapacity(a), Quantity::TemperatureInterval(b)) => Ok(Quantity::AbsorbedDose(a.clone() * b.clone())),
+			(Quantity::SpecificHeatCapacity(a), Quantity::InverseEntropy(b)) => Ok(Quantity::InverseMass(a.clone() * b.clone())),
+			(Quantity::SpecificHeatCapacity(a), Quantity::InverseHeatCapacity(b)) => Ok(Quantity::InverseMass(a.clone() * b.clone())),
+			(Quantity::SpecificHeatCapacity(a), Quantity::InverseMolarEntropy(b)) => Ok(Quantity::Molality(a.clone() * b.clone())),
+			(Quantity::SpecificHeatCapacity(a), Quantity::MolarMass(b)) => Ok(Quantity::MolarEntropy(a.clone() * b.clone())),
+			(Quantity::SpecificHeatCapacity(a), Quantity::ThermalResistance(b)) => Ok(Quantity::InverseMassFlowRate(a.clone() * b.clone())),
+			(Quantity::SpecificHeatCapacity(a), Quantity::ThermalResistivity(b)) => Ok(Quantity::InverseDynamicViscosity(a.clone() * b.clone())),
+			(Quantity::SpecificHeatCapacity(a), Quantity::DynamicViscosity(b)) => Ok(Quantity::ThermalConductivity(a.clone() * b.clone())),
+			(Quantity::SpecificHeatCapacity(a), Quantity::MassFlowRate(b)) => Ok(Quantity::ThermalConductance(a.clone() * b.clone())),
+			(Quantity::SpecificHeatCapacity(a), Quantity::InverseAbsorbedDose(b)) => Ok(Quantity::InverseTemperature(a.clone() * b.clone())),
+			(Quantity::SpecificHeatCapacity(a), Quantity::InverseDoseEquivalent(b)) => Ok(Quantity::InverseTemperature(a.clone() * b.clone())),
+			(Quantity::ThermalConductance(a), Quantity::InverseDistance(b)) => Ok(Quantity::ThermalConductivity(a.clone() * b.clone())),
+			(Quantity::ThermalConductance(a), Quantity::Temperature(b)) => Ok(Quantity::Power(a.clone() * b.clone())),
+			(Quantity::ThermalConductance(a), Quantity::TemperatureInterval(b)) => Ok(Quantity::Power(a.clone() * b.clone())),
+			(Quantity::ThermalConductance(a), Quantity::Time(b)) => Ok(Quantity::HeatCapacity(a.clone() * b.clone())),
+			(Quantity::ThermalConductance(a), Quantity::InverseCatalyticActivity(b)) => Ok(Quantity::MolarEntropy(a.clone() * b.clone())),
+			(Quantity::ThermalConductance(a), Quantity::InverseEntropy(b)) => Ok(Quantity::Frequency(a.clone() * b.clone())),
+			(Quantity::ThermalConductance(a), Quantity::InverseHeatCapacity(b)) => Ok(Quantity::Frequency(a.clone() * b.clone())),
+			(Quantity::ThermalConductance(a), Quantity::InverseMolarEntropy(b)) => Ok(Quantity::CatalyticActivity(a.clone() * b.clone())),
+			(Quantity::ThermalConductance(a), Quantity::InverseSpecificHeatCapacity(b)) => Ok(Quantity::MassFlowRate(a.clone() * b.clone())),
+			(Quantity::ThermalConductance(a), Quantity::ThermalResistivity(b)) => Ok(Quantity::Distance(a.clone() * b.clone())),
+			(Quantity::ThermalConductance(a), Quantity::InverseMassFlowRate(b)) => Ok(Quantity::SpecificHeatCapacity(a.clone() * b.clone())),
+			(Quantity::ThermalConductance(a), Quantity::InversePower(b)) => Ok(Quantity::InverseTemperature(a.clone() * b.clone())),
+			(Quantity::ThermalConductivity(a), Quantity::Distance(b)) => Ok(Quantity::ThermalConductance(a.clone() * b.clone())),
+			(Quantity::ThermalConductivity(a), Quantity::InverseSpecificHeatCapacity(b)) => Ok(Quantity::DynamicViscosity(a.clone() * b.clone())),
+			(Quantity::ThermalConductivity(a), Quantity::ThermalResistance(b)) => Ok(Quantity::InverseDistance(a.clone() * b.clone())),
+			(Quantity::ThermalConductivity(a), Quantity::InverseDynamicViscosity(b)) => Ok(Quantity::SpecificHeatCapacity(a.clone() * b.clone())),
+			(Quantity::ThermalResistance(a), Quantity::Distance(b)) => Ok(Quantity::ThermalResistivity(a.clone() * b.clone())),
+			(Quantity::ThermalResistance(a), Quantity::InverseTemperature(b)) => Ok(Quantity::InversePower(a.clone() * b.clone())),
+			(Quantity::ThermalResistance(a), Quantity::CatalyticActivity(b)) => Ok(Quantity::InverseMolarEntropy(a.clone() * b.clone())),
+			(Quantity::ThermalResistance(a), Quantity::Entropy(b)) => Ok(Quantity::Time(a.clone() * b.clone())),
+			(Quantity::ThermalResistance(a), Quantity::HeatCapacity(b)) => Ok(Quantity::Time(a.clone() * b.clone())),
+			(Quantity::ThermalResistance(a), Quantity::MolarEntropy(b)) => Ok(Quantity::InverseCatalyticActivity(a.clone() * b.clone())),
+			(Quantity::ThermalResistance(a), Quantity::SpecificHeatCapacity(b)) => Ok(Quantity::InverseMassFlowRate(a.clone() * b.clone())),
+			(Quantity::ThermalResistance(a), Quantity::ThermalConductivity(b)) => Ok(Quantity::InverseDistance(a.clone() * b.clone())),
+			(Quantity::ThermalResistance(a), Quantity::Frequency(b)) => Ok(Quantity::InverseHeatCapacity(a.clone() * b.clone())),
+			(Quantity::ThermalResistance(a), Quantity::MassFlowRate(b)) => Ok(Quantity::InverseSpecificHeatCapacity(a.clone() * b.clone())),
+			(Quantity::ThermalResistance(a), Quantity::Power(b)) => Ok(Quantity::Temperature(a.clone() * b.clone())),
+			(Quantity::ThermalResistivity(a), Quantity::InverseDistance(b)) => Ok(Quantity::ThermalResistance(a.clone() * b.clone())),
+			(Quantity::ThermalResistivity(a), Quantity::SpecificHeatCapacity(b)) => Ok(Quantity::InverseDynamicViscosity(a.clone() * b.clone())),
+			(Quantity::ThermalResistivity(a), Quantity::ThermalConductance(b)) => Ok(Quantity::Distance(a.clone() * b.clone())),
+			(Quantity::ThermalResistivity(a), Quantity::DynamicViscosity(b)) => Ok(Quantity::InverseSpecificHeatCapacity(a.clone() * b.clone())),
+			(Quantity::AreaPerLumen(a), Quantity::LuminousFlux(b)) => Ok(Quantity::Area(a.clone() * b.clone())),
+			(Quantity::AreaPerLumen(a), Quantity::InverseArea(b)) => Ok(Quantity::InverseLuminousFlux(a.clone() * b.clone())),
+			(Quantity::Capacitance(a), Quantity::ElectricField(b)) => Ok(Quantity::LinearChargeDensity(a.clone() * b.clone())),
+			(Quantity::Capacitance(a), Quantity::InverseCharge(b)) => Ok(Quantity::InverseVoltage(a.clone() * b.clone())),
+			(Quantity::Capacitance(a), Quantity::InverseLinearChargeDensity(b)) => Ok(Quantity::InverseElectricField(a.clone() * b.clone())),
+			(Quantity::Capacitance(a), Quantity::Resistance(b)) => Ok(Quantity::Time(a.clone() * b.clone())),
+			(Quantity::Capacitance(a), Quantity::Voltage(b)) => Ok(Quantity::Charge(a.clone() * b.clone())),
+			(Quantity::Capacitance(a), Quantity::Frequency(b)) => Ok(Quantity::Conductance(a.clone() * b.clone())),
+			(Quantity::Charge(a), Quantity::InverseCurrent(b)) => Ok(Quantity::Time(a.clone() * b.clone())),
+			(Quantity::Charge(a), Quantity::InverseDistance(b)) => Ok(Quantity::LinearChargeDensity(a.clone() * b.clone())),
+			(Quantity::Charge(a), Quantity::Elastance(b)) => Ok(Quantity::Voltage(a.clone() * b.clone())),
+			(Quantity::Charge(a), Quantity::ElectricField(b)) => Ok(Quantity::Force(a.clone() * b.clone())),
+			(Quantity::Charge(a), Quantity::InverseLinearChargeDensity(b)) => Ok(Quantity::Distance(a.clone() * b.clone())),
+			(Quantity::Charge(a), Quantity::InverseMagneticFlux(b)) => Ok(Quantity::Conductance(a.clone() * b.clone())),
+			(Quantity::Charge(a), Quantity::InverseSurfaceChargeDensity(b)) => Ok(Quantity::Area(a.clone() * b.clone())),
+			(Quantity::Charge(a), Quantity::InverseVoltage(b)) => Ok(Quantity::Capacitance(a.clone() * b.clone())),
+			(Quantity::Charge(a), Quantity::InverseVolumeChargeDensity(b)) => Ok(Quantity::Volume(a.clone() * b.clone())),
+			(Quantity::Charge(a), Quantity::MagneticFluxDensity(b)) => Ok(Quantity::MassFlowRate(a.clone() * b.clone())),
+			(Quantity::Charge(a), Quantity::Resistance(b)) => Ok(Quantity::MagneticFlux(a.clone() * b.clone())),
+			(Quantity::Charge(a), Quantity::Voltage(b)) => Ok(Quantity::Energy(a.clone() * b.clone())),
+			(Quantity::Charge(a), Quantity::InverseArea(b)) => Ok(Quantity::SurfaceChargeDensity(a.clone() * b.clone())),
+			(Quantity::Charge(a), Quantity::InverseVolume(b)) => Ok(Quantity::VolumeChargeDensity(a.clone() * b.clone())),
+			(Quantity::Charge(a), Quantity::Frequency(b)) => Ok(Quantity::Current(a.clone() * b.clone())),
+			(Quantity::Charge(a), Quantity::InverseEnergy(b)) => Ok(Quantity::InverseVoltage(a.clone() * b.clone())),
+			(Quantity::Charge(a), Quantity::InverseTorque(b)) => Ok(Quantity::InverseVoltage(a.clone() * b.clone())),
+			(Quantity::Charge(a), Quantity::InverseForce(b)) => Ok(Quantity::InverseElectricField(a.clone() * b.clone())),
+			(Quantity::Charge(a), Quantity::InverseMassFlowRate(b)) => Ok(Quantity::InverseMagneticFluxDensity(a.clone() * b.clone())),
+			(Quantity::Conductance(a), Quantity::InverseCurrent(b)) => Ok(Quantity::InverseVoltage(a.clone() * b.clone())),
+			(Quantity::Conductance(a), Quantity::Time(b)) => Ok(Quantity::Capacitance(a.clone() * b.clone())),
+			(Quantity::Conductance(a), Quantity::Elastance(b)) => Ok(Quantity::Frequency(a.clone() * b.clone())),
+			(Quantity::Conductance(a), Quantity::ElectricField(b)) => Ok(Quantity::MagneticFieldStrength(a.clone() * b.clone())),
+			(Quantity::Conductance(a), Quantity::Inductance(b)) => Ok(Quantity::Time(a.clone() * b.clone())),
+			(Quantity::Conductance(a), Quantity::InverseCharge(b)) => Ok(Quantity::InverseMagneticFlux(a.clone() * b.clone())),
+			(Quantity::Conductance(a), Quantity::InverseMagneticFieldStrength(b)) => Ok(Quantity::InverseElectricField(a.clone() * b.clone())),
+			(Quantity::Conductance(a), Quantity::InverseSurfaceChargeDensity(b)) => Ok(Quantity::InverseMagneticFluxDensity(a.clone() * b.clone())),
+			(Quantity::Conductance(a), Quantity::MagneticFlux(b)) => Ok(Quantity::Charge(a.clone() * b.clone())),
+			(Quantity::Conductance(a), Quantity::MagneticFluxDensity(b)) => Ok(Quantity::SurfaceChargeDensity(a.clone() * b.clone())),
+			(Quantity::Conductance(a), Quantity::Voltage(b)) => Ok(Quantity::Current(a.clone() * b.clone())),
+			(Quantity::Conductance(a), Quantity::Frequency(b)) => Ok(Quantity::InverseInductance(a.clone() * b.clone())),
+			(Quantity::Elastance(a), Quantity::Time(b)) => Ok(Quantity::Resistance(a.clone() * b.clone())),
+			(Quantity::Elastance(a), Quantity::Charge(b)) => Ok(Quantity::Voltage(a.clone() * b.clone())),
+			(Quantity::Elastance(a), Quantity::Conductance(b)) => Ok(Quantity::Frequency(a.clone() * b.clone())),
+			(Quantity::Elastance(a), Quantity::InverseElectricField(b)) => Ok(Quantity::InverseLinearChargeDensity(a.clone() * b.clone())),
+			(Quantity::Elastance(a), Quantity::InverseVoltage(b)) => Ok(Quantity::InverseCharge(a.clone() * b.clone())),
+			(Quantity::Elastance(a), Quantity::LinearChargeDensity(b)) => Ok(Quantity::ElectricField(a.clone() * b.clone())),
+			(Quantity::ElectricField(a), Quantity::Distance(b)) => Ok(Quantity::Voltage(a.clone() * b.clone())),
+			(Quantity::ElectricField(a), Quantity::Capacitance(b)) => Ok(Quantity::LinearChargeDensity(a.clone() * b.clone())),
+			(Quantity::ElectricField(a), Quantity::Charge(b)) => Ok(Quantity::Force(a.clone() * b.clone())),
+			(Quantity::ElectricField(a), Quantity::Conductance(b)) => Ok(Quantity::MagneticFieldStrength(a.clone() * b.clone())),
+			(Quantity::ElectricField(a), Quantity::InverseIrradiance(b)) => Ok(Quantity::InverseMagneticFieldStrength(a.clone() * b.clone())),
+			(Quantity::ElectricField(a), Quantity::InverseLinearChargeDensity(b)) => Ok(Quantity::Elastance(a.clone() * b.clone())),
+			(Quantity::ElectricField(a), Quantity::InverseMagneticFieldStrength(b)) => Ok(Quantity::Resistance(a.clone() * b.clone())),
+			(Quantity::ElectricField(a), Quantity::InverseMagneticFluxDensity(b)) => Ok(Quantity::Velocity(a.clone() * b.clone())),
+			(Quantity::ElectricField(a), Quantity::InverseVoltage(b)) => Ok(Quantity::InverseDistance(a.clone() * b.clone())),
+			(Quantity::ElectricField(a), Quantity::MagneticFieldStrength(b)) => Ok(Quantity::Irradiance(a.clone() * b.clone())),
+			(Quantity::ElectricField(a), Quantity::SurfaceChargeDensity(b)) => Ok(Quantity::Pressure(a.clone() * b.clone())),
+			(Quantity::ElectricField(a), Quantity::InverseForce(b)) => Ok(Quantity::InverseCharge(a.clone() * b.clone())),
+			(Quantity::ElectricField(a), Quantity::InversePressure(b)) => Ok(Quantity::InverseSurfaceChargeDensity(a.clone() * b.clone())),
+			(Quantity::ElectricField(a), Quantity::TimePerDistance(b)) => Ok(Quantity::MagneticFluxDensity(a.clone() * b.clone())),
+			(Quantity::Illuminance(a), Quantity::InverseLuminousFlux(b)) => Ok(Quantity::InverseArea(a.clone() * b.clone())),
+			(Quantity::Illuminance(a), Quantity::Area(b)) => Ok(Quantity::LuminousFlux(a.clone() * b.clone())),
+			(Quantity::Inductance(a), Quantity::Current(b)) => Ok(Quantity::MagneticFlux(a.clone() * b.clone())),
+			(Quantity::Inductance(a), Quantity::Conductance(b)) => Ok(Quantity::Time(a.clone() * b.clone())),
+			(Quantity::Inductance(a), Quantity::InverseMagneticFlux(b)) => Ok(Quantity::InverseCurrent(a.clone() * b.clone())),
+			(Quantity::Inductance(a), Quantity::Frequency(b)) => Ok(Quantity::Resistance(a.clone() * b.clone())),
+			(Quantity::InverseCharge(a), Quantity::Current(b)) => Ok(Quantity::Frequency(a.clone() * b.clone())),
+			(Quantity::InverseCharge(a), Quantity::Distance(b)) => Ok(Quantity::InverseLinearChargeDensity(a.clone() * b.clone())),
+			(Quantity::InverseCharge(a), Quantity::Time(b)) => Ok(Quantity::InverseCurrent(a.clone() * b.clone())),
+			(Quantity::InverseCharge(a), Quantity::Capacitance(b)) => Ok(Quantity::InverseVoltage(a.clone() * b.clone())),
+			(Quantity::InverseCharge(a), Quantity::Conductance(b)) => Ok(Quantity::InverseMagneticFlux(a.clone() * b.clone())),
+			(Quantity::InverseCharge(a), Quantity::InverseElectricField(b)) => Ok(Quantity::InverseForce(a.clone() * b.clone())),
+			(Quantity::InverseCharge(a), Quantity::InverseMagneticFluxDensity(b)) => Ok(Quantity::InverseMassFlowRate(a.clone() * b.clone())),
+			(Quantity::InverseCharge(a), Quantity::InverseVoltage(b)) => Ok(Quantity::InverseEnergy(a.clone() * b.clone())),
+			(Quantity::InverseCharge(a), Quantity::LinearChargeDensity(b)) => Ok(Quantity::InverseDistance(a.clone() * b.clone())),
+			(Quantity::InverseCharge(a), Quantity::MagneticFlux(b)) => Ok(Quantity::Resistance(a.clone() * b.clone())),
+			(Quantity::InverseCharge(a), Quantity::SurfaceChargeDensity(b)) => Ok(Quantity::InverseArea(a.clone() * b.clone())),
+			(Quantity::InverseCharge(a), Quantity::Voltage(b)) => Ok(Quantity::Elastance(a.clone() * b.clone())),
+			(Quantity::InverseCharge(a), Quantity::VolumeChargeDensity(b)) => Ok(Quantity::InverseVolume(a.clone() * b.clone())),
+			(Quantity::InverseCharge(a), Quantity::Area(b)) => Ok(Quantity::InverseSurfaceChargeDensity(a.clone() * b.clone())),
+			(Quantity::InverseCharge(a), Quantity::Volume(b)) => Ok(Quantity::InverseVolumeChargeDensity(a.clone() * b.clone())),
+			(Quantity::InverseCharge(a), Quantity::Energy(b)) => Ok(Quantity::Voltage(a.clone() * b.clone())),
+			(Quantity::InverseCharge(a), Quantity::Torque(b)) => Ok(Quantity::Voltage(a.clone() * b.clone())),
+			(Quantity::InverseCharge(a), Quantity::Force(b)) => Ok(Quantity::ElectricField(a.clone() * b.clone())),
+			(Quantity::InverseCharge(a), Quantity::MassFlowRate(b)) => Ok(Quantity::MagneticFluxDensity(a.clone() * b.clone())),
+			(Quantity::InverseElectricField(a), Quantity::InverseDistance(b)) => Ok(Quantity::InverseVoltage(a.clone() * b.clone())),
+			(Quantity::InverseElectricField(a), Quantity::Elastance(b)) => Ok(Quantity::InverseLinearChargeDensity(a.clone() * b.clone())),
+			(Quantity::InverseElectricField(a), Quantity::InverseCharge(b)) => Ok(Quantity::InverseForce(a.clone() * b.clone())),
+			(Quantity::InverseElectricField(a), Quantity::InverseMagneticFieldStrength(b)) => Ok(Quantity::InverseIrradiance(a.clone() * b.clone())),
+			(Quantity::InverseElectricField(a), Quantity::InverseSurfaceChargeDensity(b)) => Ok(Quantity::InversePressure(a.clone() * b.clone())),
+			(Quantity::InverseElectricField(a), Quantity::Irradiance(b)) => Ok(Quantity::MagneticFieldStrength(a.clone() * b.clone())),
+			(Quantity::InverseElectricField(a), Quantity::LinearChargeDensity(b)) => Ok(Quantity::Capacitance(a.clone() * b.clone())),
+			(Quantity::InverseElectricField(a), Quantity::MagneticFieldStrength(b)) => Ok(Quantity::Conductance(a.clone() * b.clone())),
+			(Quantity::InverseElectricField(a), Quantity::MagneticFluxDensity(b)) => Ok(Quantity::TimePerDistance(a.clone() * b.clone())),
+			(Quantity::InverseElectricField(a), Quantity::Resistance(b)) => Ok(Quantity::InverseMagneticFieldStrength(a.clone() * b.clone())),
+			(Quantity::InverseElectricField(a), Quantity::Voltage(b)) => Ok(Quantity::Distance(a.clone() * b.clone())),
+			(Quantity::InverseElectricField(a), Quantity::Force(b)) => Ok(Quantity::Charge(a.clone() * b.clone())),
+			(Quantity::InverseElectricField(a), Quantity::Pressure(b)) => Ok(Quantity::SurfaceChargeDensity(a.clone() * b.clone())),
+			(Quantity::InverseElectricField(a), Quantity::Velocity(b)) => Ok(Quantity::InverseMagneticFluxDensity(a.clone() * b.clone())),
+			(Quantity::InverseInductance(a), Quantity::InverseCurrent(b)) => Ok(Quantity::InverseMagneticFlux(a.clone() * b.clone())),
+			(Quantity::InverseInductance(a), Quantity::Time(b)) => Ok(Quantity::Conductance(a.clone() * b.clone())),
+			(Quantity::InverseInductance(a), Quantity::MagneticFlux(b)) => Ok(Quantity::Current(a.clone() * b.clone())),
+			(Quantity::InverseInductance(a), Quantity::Resistance(b)) => Ok(Quantity::Frequency(a.clone() * b.clone())),
+			(Quantity::InverseIrradiance(a), Quantity::ElectricField(b)) => Ok(Quantity::InverseMagneticFieldStrength(a.clone() * b.clone())),
+			(Quantity::InverseIrradiance(a), Quantity::MagneticFieldStrength(b)) => Ok(Quantity::InverseElectricField(a.clone() * b.clone())),
+			(Quantity::InverseIrradiance(a), Quantity::Radiance(b)) => Ok(Quantity::InverseSolidAngle(a.clone() * b.clone())),
+			(Quantity::InverseIrradiance(a), Quantity::InverseArea(b)) => Ok(Quantity::InversePower(a.clone() * b.clone())),
+			(Quantity::InverseIrradiance(a), Quantity::SolidAngle(b)) => Ok(Quantity::InverseRadiance(a.clone() * b.clone())),
+			(Quantity::InverseIrradiance(a), Quantity::Acceleration(b)) => Ok(Quantity::InverseDynamicViscosity(a.clone() * b.clone())),
+			(Quantity::InverseIrradiance(a), Quantity::DynamicViscosity(b)) => Ok(Quantity::InverseAcceleration(a.clone() * b.clone())),
+			(Quantity::InverseIrradiance(a), Quantity::Power(b)) => Ok(Quantity::Area(a.clone() * b.clone())),
+			(Quantity::InverseIrradiance(a), Quantity::Pressure(b)) => Ok(Quantity::TimePerDistance(a.clone() * b.clone())),
+			(Quantity::InverseIrradiance(a), Quantity::Velocity(b)) => Ok(Quantity::InversePressure(a.clone() * b.clone())),
+			(Quantity::InverseLinearChargeDensity(a), Quantity::Current(b)) => Ok(Quantity::Velocity(a.clone() * b.clone())),
+			(Quantity::InverseLinearChargeDensity(a), Quantity::Distance(b)) => Ok(Quantity::InverseSurfaceChargeDensity(a.clone() * b.clone())),
+			(Quantity::InverseLinearChargeDensity(a), Quantity::InverseDistance(b)) => Ok(Quantity::InverseCharge(a.clone() * b.clone())),
+			(Quantity::InverseLinearChargeDensity(a), Quantity::Time(b)) => Ok(Quantity::InverseMagneticFieldStrength(a.clone() * b.clone())),
+			(Quantity::InverseLinearChargeDensity(a), Quantity::Capacitance(b)) => Ok(Quantity::InverseElectricField(a.clone() * b.clone())),
+			(Quantity::InverseLinearChargeDensity(a), Quantity::Charge(b)) => Ok(Quantity::Distance(a.clone() * b.clone())),
+			(Quantity::InverseLinearChargeDensity(a), Quantity::ElectricField(b)) => Ok(Quantity::Elastance(a.clone() * b.clone())),
+			(Quantity::InverseLinearChargeDensity(a), Quantity::InverseMagneticFlux(b)) => Ok(Quantity::InverseMomentum(a.clone() * b.clone())),
+			(Quantity::InverseLinearChargeDensity(a), Quantity::InverseMagneticFluxDensity(b)) => Ok(Quantity::InverseDynamicViscosity(a.clone() * b.clone())),
+			(Quantity::InverseLinearChargeDensity(a), Quantity::InverseVoltage(b)) => Ok(Quantity::InverseForce(a.clone() * b.clone())),
+			(Quantity::InverseLinearChargeDensity(a), Quantity::MagneticFieldStrength(b)) => Ok(Quantity::Frequency(a.clone() * b.clone())),
+			(Quantity::InverseLinearChargeDensity(a), Quantity::SurfaceChargeDensity(b)) => Ok(Quantity::InverseDistance(a.clone() * b.clone())),
+			(Quantity::InverseLinearChargeDensity(a), Quantity::VolumeChargeDensity(b)) => Ok(Quantity::InverseArea(a.clone() * b.clone())),
+			(Quantity::InverseLinearChargeDensity(a), Quantity::Area(b)) => Ok(Quantity::InverseVolumeChargeDensity(a.clone() * b.clone())),
+			(Quantity::InverseLinearChargeDensity(a), Quantity::DynamicViscosity(b)) => Ok(Quantity::MagneticFluxDensity(a.clone() * b.clone())),
+			(Quantity::InverseLinearChargeDensity(a), Quantity::Force(b)) => Ok(Quantity::Voltage(a.clone() * b.clone())),
+			(Quantity::InverseLinearChargeDensity(a), Quantity::Momentum(b)) => Ok(Quantity::MagneticFlux(a.clone() * b.clone())),
+			(Quantity::InverseLinearChargeDensity(a), Quantity::TimePerDistance(b)) => Ok(Quantity::InverseCurrent(a.clone() * b.clone())),
+			(Quantity::InverseLuminousFlux(a), Quantity::Luminosity(b)) => Ok(Quantity::InverseSolidAngle(a.clone() * b.clone())),
+			(Quantity::InverseLuminousFlux(a), Quantity::Illuminance(b)) => Ok(Quantity::InverseArea(a.clone() * b.clone())),
+			(Quantity::InverseLuminousFlux(a), Quantity::Area(b)) => Ok(Quantity::AreaPerLumen(a.clone() * b.clone())),
+			(Quantity::InverseLuminousFlux(a), Quantity::SolidAngle(b)) => Ok(Quantity::InverseLuminosity(a.clone() * b.clone())),
+			(Quantity::InverseMagneticFieldStrength(a), Quantity::Current(b)) => Ok(Quantity::Distance(a.clone() * b.clone())),
+			(Quantity::InverseMagneticFieldStrength(a), Quantity::InverseDistance(b)) => Ok(Quantity::InverseCurrent(a.clone() * b.clone())),
+			(Quantity::InverseMagneticFieldStrength(a), Quantity::Conductance(b)) => Ok(Quantity::InverseElectricField(a.clone() * b.clone())),
+			(Quantity::InverseMagneticFieldStrength(a), Quantity::ElectricField(b)) => Ok(Quantity::Resistance(a.clone() * b.clone())),
+			(Quantity::InverseMagneticFieldStrength(a), Quantity::InverseElectricField(b)) => Ok(Quantity::InverseIrradiance(a.clone() * b.clone())),
+			(Quantity::InverseMagneticFieldStrength(a), Quantity::InverseMagneticFlux(b)) => Ok(Quantity::InverseForce(a.clone() * b.clone())),
+			(Quantity::InverseMagneticFieldStrength(a), Quantity::InverseMagneticFluxDensity(b)) => Ok(Quantity::InversePressure(a.clone() * b.clone())),
+			(Quantity::InverseMagneticFieldStrength(a), Quantity::Irradiance(b)) => Ok(Quantity::ElectricField(a.clone() * b.clone())),
+			(Quantity::InverseMagneticFieldStrength(a), Quantity::LinearChargeDensity(b)) => Ok(Quantity::Time(a.clone() * b.clone())),
+			(Quantity::InverseMagneticFieldStrength(a), Quantity::SurfaceChargeDensity(b)) => Ok(Quantity::TimePerDistance(a.clone() * b.clone())),
+			(Quantity::InverseMagneticFieldStrength(a), Quantity::VolumeChargeDensity(b)) => Ok(Quantity::InverseKinematicViscosity(a.clone() * b.clone())),
+			(Quantity::InverseMagneticFieldStrength(a), Quantity::Force(b)) => Ok(Quantity::MagneticFlux(a.clone() * b.clone())),
+			(Quantity::InverseMagneticFieldStrength(a), Quantity::Frequency(b)) => Ok(Quantity::InverseLinearChargeDensity(a.clone() * b.clone())),
+			(Quantity::InverseMagneticFieldStrength(a), Quantity::KinematicViscosity(b)) => Ok(Quantity::InverseVolumeChargeDensity(a.clone() * b.clone())),
+			(Quantity::InverseMagneticFieldStrength(a), Quantity::Pressure(b)) => Ok(Quantity::MagneticFluxDensity(a.clone() * b.clone())),
+			(Quantity::InverseMagneticFieldStrength(a), Quantity::Velocity(b)) => Ok(Quantity::InverseSurfaceChargeDensity(a.clone() * b.clone())),
+			(Quantity::InverseMagneticFlux(a), Quantity::Current(b)) => Ok(Quantity::InverseInductance(a.clone() * b.clone())),
+			(Quantity::InverseMagneticFlux(a), Quantity::InverseCurrent(b)) => Ok(Quantity::InverseEnergy(a.clone() * b.clone())),
+			(Quantity::InverseMagneticFlux(a), Quantity::Time(b)) => Ok(Quantity::InverseVoltage(a.clone() * b.clone())),
+			(Quantity::InverseMagneticFlux(a), Quantity::Charge(b)) => Ok(Quantity::Conductance(a.clone() * b.clone())),
+			(Quantity::InverseMagneticFlux(a), Quantity::Inductance(b)) => Ok(Quantity::InverseCurrent(a.clone() * b.clone())),
+			(Quantity::InverseMagneticFlux(a), Quantity::InverseLinearChargeDensity(b)) => Ok(Quantity::InverseMomentum(a.clone() * b.clone())),
+			(Quantity::InverseMagneticFlux(a), Quantity::InverseMagneticFieldStrength(b)) => Ok(Quantity::InverseForce(a.clone() * b.clone())),
+			(Quantity::InverseMagneticFlux(a), Quantity::InverseSurfaceChargeDensity(b)) => Ok(Quantity::InverseMassFlowRate(a.clone() * b.clone())),
+			(Quantity::InverseMagneticFlux(a), Quantity::InverseVolumeChargeDensity(b)) => Ok(Quantity::InverseDynamicViscosity(a.clone() * b.clone())),
+			(Quantity::InverseMagneticFlux(a), Quantity::MagneticFluxDensity(b)) => Ok(Quantity::InverseArea(a.clone() * b.clone())),
+			(Quantity::InverseMagneticFlux(a), Quantity::Resistance(b)) => Ok(Quantity::InverseCharge(a.clone() * b.clone())),
+			(Quantity::InverseMagneticFlux(a), Quantity::Voltage(b)) => Ok(Quantity::Frequency(a.clone() * b.clone())),
+			(Quantity::InverseMagneticFlux(a), Quantity::Area(b)) => Ok(Quantity::InverseMagneticFluxDensity(a.clone() * b.clone())),
+			(Quantity::InverseMagneticFlux(a), Quantity::DynamicViscosity(b)) => Ok(Quantity::VolumeChargeDensity(a.clone() * b.clone())),
+			(Quantity::InverseMagneticFlux(a), Quantity::Energy(b)) => Ok(Quantity::Current(a.clone() * b.clone())),
+			(Quantity::InverseMagneticFlux(a), Quantity::Torque(b)) => Ok(Quantity::Current(a.clone() * b.clone())),
+			(Quantity::InverseMagneticFlux(a), Quantity::Force(b)) => Ok(Quantity::MagneticFieldStrength(a.clone() * b.clone())),
+			(Quantity::InverseMagneticFlux(a), Quantity::MassFlowRate(b)) => Ok(Quantity::SurfaceChargeDensity(a.clone() * b.clone())),
+			(Quantity::InverseMagneticFlux(a), Quantity::Momentum(b)) => Ok(Quantity::LinearChargeDensity(a.clone() * b.clone())),
+			(Quantity::InverseMagneticFluxDensity(a), Quantity::ElectricField(b)) => Ok(Quantity::Velocity(a.clone() * b.clone())),
+			(Quantity::InverseMagneticFluxDensity(a), Quantity::InverseCharge(b)) => Ok(Quantity::InverseMassFlowRate(a.clone() * b.clone())),
+			(Quantity::InverseMagneticFluxDensity(a), Quantity::InverseLinearChargeDensity(b)) => Ok(Quantity::InverseDynamicViscosity(a.clone() * b.clone())),
+			(Quantity::InverseMagneticFluxDensity(a), Quantity::InverseMagneticFieldStrength(b)) => Ok(Quantity::InversePressure(a.clone() * b.clone())),
+			(Quantity::InverseMagneticFluxDensity(a), Quantity::MagneticFlux(b)) => Ok(Quantity::Area(a.clone() * b.clone())),
+			(Quantity::InverseMagneticFluxDensity(a), Quantity::Resistance(b)) => Ok(Quantity::InverseSurfaceChargeDensity(a.clone() * b.clone())),
+			(Quantity::InverseMagneticFluxDensity(a), Quantity::SurfaceChargeDensity(b)) => Ok(Quantity::Conductance(a.clone() * b.clone())),
+			(Quantity::InverseMagneticFluxDensity(a), Quantity::Voltage(b)) => Ok(Quantity::KinematicViscosity(a.clone() * b.clone())),
+			(Quantity::InverseMagneticFluxDensity(a), Quantity::InverseArea(b)) => Ok(Quantity::InverseMagneticFlux(a.clone() * b.clone())),
+			(Quantity::InverseMagneticFluxDensity(a), Quantity::DynamicViscosity(b)) => Ok(Quantity::LinearChargeDensity(a.clone() * b.clone())),
+			(Quantity::InverseMagneticFluxDensity(a), Quantity::InverseKinematicViscosity(b)) => Ok(Quantity::InverseVoltage(a.clone() * b.clone())),
+			(Quantity::InverseMagneticFluxDensity(a), Quantity::MassFlowRate(b)) => Ok(Quantity::Charge(a.clone() * b.clone())),
+			(Quantity::InverseMagneticFluxDensity(a), Quantity::Pressure(b)) => Ok(Quantity::MagneticFieldStrength(a.clone() * b.clone())),
+			(Quantity::InverseMagneticFluxDensity(a), Quantity::TimePerDistance(b)) => Ok(Quantity::InverseElectricField(a.clone() * b.clone())),
+			(Quantity::InverseRadiance(a), Quantity::Irradiance(b)) => Ok(Quantity::SolidAngle(a.clone() * b.clone())),
+			(Quantity::InverseRadiance(a), Quantity::InverseSolidAngle(b)) => Ok(Quantity::InverseIrradiance(a.clone() * b.clone())),
+			(Quantity::InverseSurfaceChargeDensity(a), Quantity::Current(b)) => Ok(Quantity::KinematicViscosity(a.clone() * b.clone())),
+			(Quantity::InverseSurfaceChargeDensity(a), Quantity::Distance(b)) => Ok(Quantity::InverseVolumeChargeDensity(a.clone() * b.clone())),
+			(Quantity::InverseSurfaceChargeDensity(a), Quantity::InverseDistance(b)) => Ok(Quantity::InverseLinearChargeDensity(a.clone() * b.clone())),
+			(Quantity::InverseSurfaceChargeDensity(a), Quantity::Charge(b)) => Ok(Quantity::Area(a.clone() * b.clone())),
+			(Quantity::InverseSurfaceChargeDensity(a), Quantity::Conductance(b)) => Ok(Quantity::InverseMagneticFluxDensity(a.clone() * b.clone())),
+			(Quantity::InverseSurfaceChargeDensity(a), Quantity::InverseElectricField(b)) => Ok(Quantity::InversePressure(a.clone() * b.clone())),
+			(Quantity::InverseSurfaceChargeDensity(a), Quantity::InverseMagneticFlux(b)) => Ok(Quantity::InverseMassFlowRate(a.clone() * b.clone())),
+			(Quantity::InverseSurfaceChargeDensity(a), Quantity::LinearChargeDensity(b)) => Ok(Quantity::Distance(a.clone() * b.clone())),
+			(Quantity::InverseSurfaceChargeDensity(a), Quantity::MagneticFieldStrength(b)) => Ok(Quantity::Velocity(a.clone() * b.clone())),
+			(Quantity::InverseSurfaceChargeDensity(a), Quantity::MagneticFluxDensity(b)) => Ok(Quantity::Resistance(a.clone() * b.clone())),
+			(Quantity::InverseSurfaceChargeDensity(a), Quantity::VolumeChargeDensity(b)) => Ok(Quantity::InverseDistance(a.clone() * b.clone())),
+			(Quantity::InverseSurfaceChargeDensity(a), Quantity::InverseArea(b)) => Ok(Quantity::InverseCharge(a.clone() * b.clone())),
+			(Quantity::InverseSurfaceChargeDensity(a), Quantity::InverseKinematicViscosity(b)) => Ok(Quantity::InverseCurrent(a.clone() * b.clone())),
+			(Quantity::InverseSurfaceChargeDensity(a), Quantity::MassFlowRate(b)) => Ok(Quantity::MagneticFlux(a.clone() * b.clone())),
+			(Quantity::InverseSurfaceChargeDensity(a), Quantity::Pressure(b)) => Ok(Quantity::ElectricField(a.clone() * b.clone())),
+			(Quantity::InverseSurfaceChargeDensity(a), Quantity::TimePerDistance(b)) => Ok(Quantity::InverseMagneticFieldStrength(a.clone() * b.clone())),
+			(Quantity::InverseVoltage(a), Quantity::Current(b)) => Ok(Quantity::Conductance(a.clone() * b.clone())),
+			(Quantity::InverseVoltage(a), Quantity::Distance(b)) => Ok(Quantity::InverseElectricField(a.clone() * b.clone())),
+			(Quantity::InverseVoltage(a), Quantity::InverseCurrent(b)) => Ok(Quantity::InversePower(a.clone() * b.clone())),
+			(Quantity::InverseVoltage(a), Quantity::Charge(b)) => Ok(Quantity::Capacitance(a.clone() * b.clone())),
+			(Quantity::InverseVoltage(a), Quantity::Elastance(b)) => Ok(Quantity::InverseCharge(a.clone() * b.clone())),
+			(Quantity::InverseVoltage(a), Quantity::ElectricField(b)) => Ok(Quantity::InverseDistance(a.clone() * b.clone())),
+			(Quantity::InverseVoltage(a), Quantity::InverseCharge(b)) => Ok(Quantity::InverseEnergy(a.clone() * b.clone())),
+			(Quantity::InverseVoltage(a), Quantity::InverseLinearChargeDensity(b)) => Ok(Quantity::InverseForce(a.clone() * b.clone())),
+			(Quantity::InverseVoltage(a), Quantity::InverseVolumeChargeDensity(b)) => Ok(Quantity::InversePressure(a.clone() * b.clone())),
+			(Quantity::InverseVoltage(a), Quantity::MagneticFlux(b)) => Ok(Quantity::Time(a.clone() * b.clone())),
+			(Quantity::InverseVoltage(a), Quantity::MagneticFluxDensity(b)) => Ok(Quantity::InverseKinematicViscosity(a.clone() * b.clone())),
+			(Quantity::InverseVoltage(a), Quantity::Resistance(b)) => Ok(Quantity::InverseCurrent(a.clone() * b.clone())),
+			(Quantity::InverseVoltage(a), Quantity::Energy(b)) => Ok(Quantity::Charge(a.clone() * b.clone())),
+			(Quantity::InverseVoltage(a), Quantity::Torque(b)) => Ok(Quantity::Charge(a.clone() * b.clone())),
+			(Quantity::InverseVoltage(a), Quantity::Force(b)) => Ok(Quantity::LinearChargeDensity(a.clone() * b.clone())),
+			(Quantity::InverseVoltage(a), Quantity::Frequency(b)) => Ok(Quantity::InverseMagneticFlux(a.clone() * b.clone())),
+			(Quantity::InverseVoltage(a), Quantity::KinematicViscosity(b)) => Ok(Quantity::InverseMagneticFluxDensity(a.clone() * b.clone())),
+			(Quantity::InverseVoltage(a), Quantity::Power(b)) => Ok(Quantity::Current(a.clone() * b.clone())),
+			(Quantity::InverseVoltage(a), Quantity::Pressure(b)) => Ok(Quantity::VolumeChargeDensity(a.clone() * b.clone())),
+			(Quantity::InverseVolumeChargeDensity(a), Quantity::Current(b)) => Ok(Quantity::VolumetricFlowRate(a.clone() * b.clone())),
+			(Quantity::InverseVolumeChargeDensity(a), Quantity::InverseDistance(b)) => Ok(Quantity::InverseSurfaceChargeDensity(a.clone() * b.clone())),
+			(Quantity::InverseVolumeChargeDensity(a), Quantity::Charge(b)) => Ok(Quantity::Volume(a.clone() * b.clone())),
+			(Quantity::InverseVolumeChargeDensity(a), Quantity::InverseMagneticFlux(b)) => Ok(Quantity::InverseDynamicViscosity(a.clone() * b.clone())),
+			(Quantity::InverseVolumeChargeDensity(a), Quantity::InverseVoltage(b)) => Ok(Quantity::InversePressure(a.clone() * b.clone())),
+			(Quantity::InverseVolumeChargeDensity(a), Quantity::LinearChargeDensity(b)) => Ok(Quantity::Area(a.clone() * b.clone())),
+			(Quantity::InverseVolumeChargeDensity(a), Quantity::MagneticFieldStrength(b)) => Ok(Quantity::KinematicViscosity(a.clone() * b.clone())),
+			(Quantity::InverseVolumeChargeDensity(a), Quantity::SurfaceChargeDensity(b)) => Ok(Quantity::Distance(a.clone() * b.clone())),
+			(Quantity::InverseVolumeChargeDensity(a), Quantity::InverseArea(b)) => Ok(Quantity::InverseLinearChargeDensity(a.clone() * b.clone())),
+			(Quantity::InverseVolumeChargeDensity(a), Quantity::InverseVolume(b)) => Ok(Quantity::InverseCharge(a.clone() * b.clone())),
+			(Quantity::InverseVolumeChargeDensity(a), Quantity::DynamicViscosity(b)) => Ok(Quantity::MagneticFlux(a.clone() * b.clone())),
+			(Quantity::InverseVolumeChargeDensity(a), Quantity::InverseKinematicViscosity(b)) => Ok(Quantity::InverseMagneticFieldStrength(a.clone() * b.clone())),
+			(Quantity::InverseVolumeChargeDensity(a), Quantity::InverseVolumetricFlowRate(b)) => Ok(Quantity::InverseCurrent(a.clone() * b.clone())),
+			(Quantity::InverseVolumeChargeDensity(a), Quantity::Pressure(b)) => Ok(Quantity::Voltage(a.clone() * b.clone())),
+			(Quantity::Irradiance(a), Quantity::InverseElectricField(b)) => Ok(Quantity::MagneticFieldStrength(a.clone() * b.clone())),
+			(Quantity::Irradiance(a), Quantity::InverseMagneticFieldStrength(b)) => Ok(Quantity::ElectricField(a.clone() * b.clone())),
+			(Quantity::Irradiance(a), Quantity::InverseRadiance(b)) => Ok(Quantity::SolidAngle(a.clone() * b.clone())),
+			(Quantity::Irradiance(a), Quantity::Area(b)) => Ok(Quantity::Power(a.clone() * b.clone())),
+			(Quantity::Irradiance(a), Quantity::InverseSolidAngle(b)) => Ok(Quantity::Radiance(a.clone() * b.clone())),
+			(Quantity::Irradiance(a), Quantity::InverseAcceleration(b)) => Ok(Quantity::DynamicViscosity(a.clone() * b.clone())),
+			(Quantity::Irradiance(a), Quantity::InverseDynamicViscosity(b)) => Ok(Quantity::Acceleration(a.clone() * b.clone())),
+			(Quantity::Irradiance(a), Quantity::InversePower(b)) => Ok(Quantity::InverseArea(a.clone() * b.clone())),
+			(Quantity::Irradiance(a), Quantity::InversePressure(b)) => Ok(Quantity::Velocity(a.clone() * b.clone())),
+			(Quantity::Irradiance(a), Quantity::TimePerDistance(b)) => Ok(Quantity::Pressure(a.clone() * b.clone())),
+			(Quantity::LinearChargeDensity(a), Quantity::Distance(b)) => Ok(Quantity::Charge(a.clone() * b.clone())),
+			(Quantity::LinearChargeDensity(a), Quantity::InverseCurrent(b)) => Ok(Quantity::TimePerDistance(a.clone() * b.clone())),
+			(Quantity::LinearChargeDensity(a), Quantity::InverseDistance(b)) => Ok(Quantity::SurfaceChargeDensity(a.clone() * b.clone())),
+			(Quantity::LinearChargeDensity(a), Quantity::Elastance(b)) => Ok(Quantity::ElectricField(a.clone() * b.clone())),
+			(Quantity::LinearChargeDensity(a), Quantity::InverseCharge(b)) => Ok(Quantity::InverseDistance(a.clone() * b.clone())),
+			(Quantity::LinearChargeDensity(a), Quantity::InverseElectricField(b)) => Ok(Quantity::Capacitance(a.clone() * b.clone())),
+			(Quantity::LinearChargeDensity(a), Quantity::InverseMagneticFieldStrength(b)) => Ok(Quantity::Time(a.clone() * b.clone())),
+			(Quantity::LinearChargeDensity(a), Quantity::InverseSurfaceChargeDensity(b)) => Ok(Quantity::Distance(a.clone() * b.clone())),
+			(Quantity::LinearChargeDensity(a), Quantity::InverseVolumeChargeDensity(b)) => Ok(Quantity::Area(a.clone() * b.clone())),
+			(Quantity::LinearChargeDensity(a), Quantity::MagneticFlux(b)) => Ok(Quantity::Momentum(a.clone() * b.clone())),
+			(Quantity::LinearChargeDensity(a), Quantity::MagneticFluxDensity(b)) => Ok(Quantity::DynamicViscosity(a.clone() * b.clone())),
+			(Quantity::LinearChargeDensity(a), Quantity::Voltage(b)) => Ok(Quantity::Force(a.clone() * b.clone())),
+			(Quantity::LinearChargeDensity(a), Quantity::InverseArea(b)) => Ok(Quantity::VolumeChargeDensity(a.clone() * b.clone())),
+			(Quantity::LinearChargeDensity(a), Quantity::Frequency(b)) => Ok(Quantity::MagneticFieldStrength(a.clone() * b.clone())),
+			(Quantity::LinearChargeDensity(a), Quantity::InverseDynamicViscosity(b)) => Ok(Quantity::InverseMagneticFluxDensity(a.clone() * b.clone())),
+			(Quantity::LinearChargeDensity(a), Quantity::InverseForce(b)) => Ok(Quantity::InverseVoltage(a.clone() * b.clone())),
+			(Quantity::LinearChargeDensity(a), Quantity::InverseMomentum(b)) => Ok(Quantity::InverseMagneticFlux(a.clone() * b.clone())),
+			(Quantity::LinearChargeDensity(a), Quantity::Velocity(b)) => Ok(Quantity::Current(a.clone() * b.clone())),
+			(Quantity::LuminousFlux(a), Quantity::InverseLuminosity(b)) => Ok(Quantity::SolidAngle(a.clone() * b.clone())),
+			(Quantity::LuminousFlux(a), Quantity::AreaPerLumen(b)) => Ok(Quantity::Area(a.clone() * b.clone())),
+			(Quantity::LuminousFlux(a), Quantity::InverseArea(b)) => Ok(Quantity::Illuminance(a.clone() * b.clone())),
+			(Quantity::LuminousFlux(a), Quantity::InverseSolidAngle(b)) => Ok(Quantity::Luminosity(a.clone() * b.clone())),
+			(Quantity::MagneticFieldStrength(a), Quantity::Distance(b)) => Ok(Quantity::Current(a.clone() * b.clone())),
+			(Quantity::MagneticFieldStrength(a), Quantity::InverseCurrent(b)) => Ok(Quantity::InverseDistance(a.clone() * b.clone())),
+			(Quantity::MagneticFieldStrength(a), Quantity::Time(b)) => Ok(Quantity::LinearChargeDensity(a.clone() * b.clone())),
+			(Quantity::MagneticFieldStrength(a), Quantity::ElectricField(b)) => Ok(Quantity::Irradiance(a.clone() * b.clone())),
+			(Quantity::MagneticFieldStrength(a), Quantity::InverseElectricField(b)) => Ok(Quantity::Conductance(a.clone() * b.clone())),
+			(Quantity::MagneticFieldStrength(a), Quantity::InverseIrradiance(b)) => Ok(Quantity::InverseElectricField(a.clone() * b.clone())),
+			(Quantity::MagneticFieldStrength(a), Quantity::InverseLinearChargeDensity(b)) => Ok(Quantity::Frequency(a.clone() * b.clone())),
+			(Quantity::MagneticFieldStrength(a), Quantity::InverseSurfaceChargeDensity(b)) => Ok(Quantity::Velocity(a.clone() * b.clone())),
+			(Quantity::MagneticFieldStrength(a), Quantity::InverseVolumeChargeDensity(b)) => Ok(Quantity::KinematicViscosity(a.clone() * b.clone())),
+			(Quantity::MagneticFieldStrength(a), Quantity::MagneticFlux(b)) => Ok(Quantity::Force(a.clone() * b.clone())),
+			(Quantity::MagneticFieldStrength(a), Quantity::MagneticFluxDensity(b)) => Ok(Quantity::Pressure(a.clone() * b.clone())),
+			(Quantity::MagneticFieldStrength(a), Quantity::Resistance(b)) => Ok(Quantity::ElectricField(a.clone() * b.clone())),
+			(Quantity::MagneticFieldStrength(a), Quantity::InverseForce(b)) => Ok(Quantity::InverseMagneticFlux(a.clone() * b.clone())),
+			(Quantity::MagneticFieldStrength(a), Quantity::InverseKinematicViscosity(b)) => Ok(Quantity::VolumeChargeDensity(a.clone() * b.clone())),
+			(Quantity::MagneticFieldStrength(a), Quantity::InversePressure(b)) => Ok(Quantity::InverseMagneticFluxDensity(a.clone() * b.clone())),
+			(Quantity::MagneticFieldStrength(a), Quantity::TimePerDistance(b)) => Ok(Quantity::SurfaceChargeDensity(a.clone() * b.clone())),
+			(Quantity::MagneticFlux(a), Quantity::Current(b)) => Ok(Quantity::Energy(a.clone() * b.clone())),
+			(Quantity::MagneticFlux(a), Quantity::InverseCurrent(b)) => Ok(Quantity::Inductance(a.clone() * b.clone())),
+			(Quantity::MagneticFlux(a), Quantity::Conductance(b)) => Ok(Quantity::Charge(a.clone() * b.clone())),
+			(Quantity::MagneticFlux(a), Quantity::InverseCharge(b)) => Ok(Quantity::Resistance(a.clone() * b.clone())),
+			(Quantity::MagneticFlux(a), Quantity::InverseInductance(b)) => Ok(Quantity::Current(a.clone() * b.clone())),
+			(Quantity::MagneticFlux(a), Quantity::InverseMagneticFluxDensity(b)) => Ok(Quantity::Area(a.clone() * b.clone())),
+			(Quantity::MagneticFlux(a), Quantity::InverseVoltage(b)) => Ok(Quantity::Time(a.clone() * b.clone())),
+			(Quantity::MagneticFlux(a), Quantity::LinearChargeDensity(b)) => Ok(Quantity::Momentum(a.clone() * b.clone())),
+			(Quantity::MagneticFlux(a), Quantity::MagneticFieldStrength(b)) => Ok(Quantity::Force(a.clone() * b.clone())),
+			(Quantity::MagneticFlux(a), Quantity::SurfaceChargeDensity(b)) => Ok(Quantity::MassFlowRate(a.clone() * b.clone())),
+			(Quantity::MagneticFlux(a), Quantity::VolumeChargeDensity(b)) => Ok(Quantity::DynamicViscosity(a.clone() * b.clone())),
+			(Quantity::MagneticFlux(a), Quantity::InverseArea(b)) => Ok(Quantity::MagneticFluxDensity(a.clone() * b.clone())),
+			(Quantity::MagneticFlux(a), Quantity::Frequency(b)) => Ok(Quantity::Voltage(a.clone() * b.clone())),
+			(Quantity::MagneticFlux(a), Quantity::InverseDynamicViscosity(b)) => Ok(Quantity::InverseVolumeChargeDensity(a.clone() * b.clone())),
+			(Quantity::MagneticFlux(a), Quantity::InverseEnergy(b)) => Ok(Quantity::InverseCurrent(a.clone() * b.clone())),
+			(Quantity::MagneticFlux(a), Quantity::InverseTorque(b)) => Ok(Quantity::InverseCurrent(a.clone() * b.clone())),
+			(Quantity::MagneticFlux(a), Quantity::InverseForce(b)) => Ok(Quantity::InverseMagneticFieldStrength(a.clone() * b.clone())),
+			(Quantity::MagneticFlux(a), Quantity::InverseMassFlowRate(b)) => Ok(Quantity::InverseSurfaceChargeDensity(a.clone() * b.clone())),
+			(Quantity::MagneticFlux(a), Quantity::InverseMomentum(b)) => Ok(Quantity::InverseLinearChargeDensity(a.clone() * b.clone())),
+			(Quantity::MagneticFluxDensity(a), Quantity::Charge(b)) => Ok(Quantity::MassFlowRate(a.clone() * b.clone())),
+			(Quantity::MagneticFluxDensity(a), Quantity::Conductance(b)) => Ok(Quantity::SurfaceChargeDensity(a.clone() * b.clone())),
+			(Quantity::MagneticFluxDensity(a), Quantity::InverseElectricField(b)) => Ok(Quantity::TimePerDistance(a.clone() * b.clone())),
+			(Quantity::MagneticFluxDensity(a), Quantity::InverseMagneticFlux(b)) => Ok(Quantity::InverseArea(a.clone() * b.clone())),
+			(Quantity::MagneticFluxDensity(a), Quantity::InverseSurfaceChargeDensity(b)) => Ok(Quantity::Resistance(a.clone() * b.clone())),
+			(Quantity::MagneticFluxDensity(a), Quantity::InverseVoltage(b)) => Ok(Quantity::InverseKinematicViscosity(a.clone() * b.clone())),
+			(Quantity::MagneticFluxDensity(a), Quantity::LinearChargeDensity(b)) => Ok(Quantity::DynamicViscosity(a.clone() * b.clone())),
+			(Quantity::MagneticFluxDensity(a), Quantity::MagneticFieldStrength(b)) => Ok(Quantity::Pressure(a.clone() * b.clone())),
+			(Quantity::MagneticFluxDensity(a), Quantity::Area(b)) => Ok(Quantity::MagneticFlux(a.clone() * b.clone())),
+			(Quantity::MagneticFluxDensity(a), Quantity::InverseDynamicViscosity(b)) => Ok(Quantity::InverseLinearChargeDensity(a.clone() * b.clone())),
+			(Quantity::MagneticFluxDensity(a), Quantity::InverseMassFlowRate(b)) => Ok(Quantity::InverseCharge(a.clone() * b.clone())),
+			(Quantity::MagneticFluxDensity(a), Quantity::InversePressure(b)) => Ok(Quantity::InverseMagneticFieldStrength(a.clone() * b.clone())),
+			(Quantity::MagneticFluxDensity(a), Quantity::KinematicViscosity(b)) => Ok(Quantity::Voltage(a.clone() * b.clone())),
+			(Quantity::MagneticFluxDensity(a), Quantity::Velocity(b)) => Ok(Quantity::ElectricField(a.clone() * b.clone())),
+			(Quantity::Radiance(a), Quantity::InverseIrradiance(b)) => Ok(Quantity::InverseSolidAngle(a.clone() * b.clone())),
+			(Quantity::Radiance(a), Quantity::SolidAngle(b)) => Ok(Quantity::Irradiance(a.clone() * b.clone())),
+			(Quantity::Resistance(a), Quantity::Current(b)) => Ok(Quantity::Voltage(a.clone() * b.clone())),
+			(Quantity::Resistance(a), Quantity::Time(b)) => Ok(Quantity::Inductance(a.clone() * b.clone())),
+			(Quantity::Resistance(a), Quantity::Capacitance(b)) => Ok(Quantity::Time(a.clone() * b.clone())),
+			(Quantity::Resistance(a), Quantity::Charge(b)) => Ok(Quantity::MagneticFlux(a.clone() * b.clone())),
+			(Quantity::Resistance(a), Quantity::InverseElectricField(b)) => Ok(Quantity::InverseMagneticFieldStrength(a.clone() * b.clone())),
+			(Quantity::Resistance(a), Quantity::InverseInductance(b)) => Ok(Quantity::Frequency(a.clone() * b.clone())),
+			(Quantity::Resistance(a), Quantity::InverseMagneticFlux(b)) => Ok(Quantity::InverseCharge(a.clone() * b.clone())),
+			(Quantity::Resistance(a), Quantity::InverseMagneticFluxDensity(b)) => Ok(Quantity::InverseSurfaceChargeDensity(a.clone() * b.clone())),
+			(Quantity::Resistance(a), Quantity::InverseVoltage(b)) => Ok(Quantity::InverseCurrent(a.clone() * b.clone())),
+			(Quantity::Resistance(a), Quantity::MagneticFieldStrength(b)) => Ok(Quantity::ElectricField(a.clone() * b.clone())),
+			(Quantity::Resistance(a), Quantity::SurfaceChargeDensity(b)) => Ok(Quantity::MagneticFluxDensity(a.clone() * b.clone())),
+			(Quantity::Resistance(a), Quantity::Frequency(b)) => Ok(Quantity::Elastance(a.clone() * b.clone())),
+			(Quantity::SurfaceChargeDensity(a), Quantity::Distance(b)) => Ok(Quantity::LinearChargeDensity(a.clone() * b.clone())),
+			(Quantity::SurfaceChargeDensity(a), Quantity::InverseCurrent(b)) => Ok(Quantity::InverseKinematicViscosity(a.clone() * b.clone())),
+			(Quantity::SurfaceChargeDensity(a), Quantity::InverseDistance(b)) => Ok(Quantity::VolumeChargeDensity(a.clone() * b.clone())),
+			(Quantity::SurfaceChargeDensity(a), Quantity::ElectricField(b)) => Ok(Quantity::Pressure(a.clone() * b.clone())),
+			(Quantity::SurfaceChargeDensity(a), Quantity::InverseCharge(b)) => Ok(Quantity::InverseArea(a.clone() * b.clone())),
+			(Quantity::SurfaceChargeDensity(a), Quantity::InverseLinearChargeDensity(b)) => Ok(Quantity::InverseDistance(a.clone() * b.clone())),
+			(Quantity::SurfaceChargeDensity(a), Quantity::InverseMagneticFieldStrength(b)) => Ok(Quantity::TimePerDistance(a.clone() * b.clone())),
+			(Quantity::SurfaceChargeDensity(a), Quantity::InverseMagneticFluxDensity(b)) => Ok(Quantity::Conductance(a.clone() * b.clone())),
+			(Quantity::SurfaceChargeDensity(a), Quantity::InverseVolumeChargeDensity(b)) => Ok(Quantity::Distance(a.clone() * b.clone())),
+			(Quantity::SurfaceChargeDensity(a), Quantity::MagneticFlux(b)) => Ok(Quantity::MassFlowRate(a.clone() * b.clone())),
+			(Quantity::SurfaceChargeDensity(a), Quantity::Resistance(b)) => Ok(Quantity::MagneticFluxDensity(a.clone() * b.clone())),
+			(Quantity::SurfaceChargeDensity(a), Quantity::Area(b)) => Ok(Quantity::Charge(a.clone() * b.clone())),
+			(Quantity::SurfaceChargeDensity(a), Quantity::InverseMassFlowRate(b)) => Ok(Quantity::InverseMagneticFlux(a.clone() * b.clone())),
+			(Quantity::SurfaceChargeDensity(a), Quantity::InversePressure(b)) => Ok(Quantity::InverseElectricField(a.clone() * b.clone())),
+			(Quantity::SurfaceChargeDensity(a), Quantity::KinematicViscosity(b)) => Ok(Quantity::Current(a.clone() * b.clone())),
+			(Quantity::SurfaceChargeDensity(a), Quantity::Velocity(b)) => Ok(Quantity::MagneticFieldStrength(a.clone() * b.clone())),
+			(Quantity::Voltage(a), Quantity::Current(b)) => Ok(Quantity::Power(a.clone() * b.clone())),
+			(Quantity::Voltage(a), Quantity::InverseCurrent(b)) => Ok(Quantity::Resistance(a.clone() * b.clone())),
+			(Quantity::Voltage(a), Quantity::InverseDistance(b)) => Ok(Quantity::ElectricField(a.clone() * b.clone())),
+			(Quantity::Voltage(a), Quantity::Time(b)) => Ok(Quantity::MagneticFlux(a.clone() * b.clone())),
+			(Quantity::Voltage(a), Quantity::Capacitance(b)) => Ok(Quantity::Charge(a.clone() * b.clone())),
+			(Quantity::Voltage(a), Quantity::Charge(b)) => Ok(Quantity::Energy(a.clone() * b.clone())),
+			(Quantity::Voltage(a), Quantity::Conductance(b)) => Ok(Quantity::Current(a.clone() * b.clone())),
+			(Quantity::Voltage(a), Quantity::InverseCharge(b)) => Ok(Quantity::Elastance(a.clone() * b.clone())),
+			(Quantity::Voltage(a), Quantity::InverseElectricField(b)) => Ok(Quantity::Distance(a.clone() * b.clone())),
+			(Quantity::Voltage(a), Quantity::InverseMagneticFlux(b)) => Ok(Quantity::Frequency(a.clone() * b.clone())),
+			(Quantity::Voltage(a), Quantity::InverseMagneticFluxDensity(b)) => Ok(Quantity::KinematicViscosity(a.clone() * b.clone())),
+			(Quantity::Voltage(a), Quantity::LinearChargeDensity(b)) => Ok(Quantity::Force(a.clone() * b.clone())),
+			(Quantity::Voltage(a), Quantity::VolumeChargeDensity(b)) => Ok(Quantity::Pressure(a.clone() * b.clone())),
+			(Quantity::Voltage(a), Quantity::InverseEnergy(b)) => Ok(Quantity::InverseCharge(a.clone() * b.clone())),
+			(Quantity::Voltage(a), Quantity::InverseTorque(b)) => Ok(Quantity::InverseCharge(a.clone() * b.clone())),
+			(Quantity::Voltage(a), Quantity::InverseForce(b)) => Ok(Quantity::InverseLinearChargeDensity(a.clone() * b.clone())),
+			(Quantity::Voltage(a), Quantity::InverseKinematicViscosity(b)) => Ok(Quantity::MagneticFluxDensity(a.clone() * b.clone())),
+			(Quantity::Voltage(a), Quantity::InversePower(b)) => Ok(Quantity::InverseCurrent(a.clone() * b.clone())),
+			(Quantity::Voltage(a), Quantity::InversePressure(b)) => Ok(Quantity::InverseVolumeChargeDensity(a.clone() * b.clone())),
+			(Quantity::VolumeChargeDensity(a), Quantity::Distance(b)) => Ok(Quantity::SurfaceChargeDensity(a.clone() * b.clone())),
+			(Quantity::VolumeChargeDensity(a), Quantity::InverseCurrent(b)) => Ok(Quantity::InverseVolumetricFlowRate(a.clone() * b.clone())),
+			(Quantity::VolumeChargeDensity(a), Quantity::InverseCharge(b)) => Ok(Quantity::InverseVolume(a.clone() * b.clone())),
+			(Quantity::VolumeChargeDensity(a), Quantity::InverseLinearChargeDensity(b)) => Ok(Quantity::InverseArea(a.clone() * b.clone())),
+			(Quantity::VolumeChargeDensity(a), Quantity::InverseMagneticFieldStrength(b)) => Ok(Quantity::InverseKinematicViscosity(a.clone() * b.clone())),
+			(Quantity::VolumeChargeDensity(a), Quantity::InverseSurfaceChargeDensity(b)) => Ok(Quantity::InverseDistance(a.clone() * b.clone())),
+			(Quantity::VolumeChargeDensity(a), Quantity::MagneticFlux(b)) => Ok(Quantity::DynamicViscosity(a.clone() * b.clone())),
+			(Quantity::VolumeChargeDensity(a), Quantity::Voltage(b)) => Ok(Quantity::Pressure(a.clone() * b.clone())),
+			(Quantity::VolumeChargeDensity(a), Quantity::Area(b)) => Ok(Quantity::LinearChargeDensity(a.clone() * b.clone())),
+			(Quantity::VolumeChargeDensity(a), Quantity::Volume(b)) => Ok(Quantity::Charge(a.clone() * b.clone())),
+			(Quantity::VolumeChargeDensity(a), Quantity::InverseDynamicViscosity(b)) => Ok(Quantity::InverseMagneticFlux(a.clone() * b.clone())),
+			(Quantity::VolumeChargeDensity(a), Quantity::InversePressure(b)) => Ok(Quantity::InverseVoltage(a.clone() * b.clone())),
+			(Quantity::VolumeChargeDensity(a), Quantity::KinematicViscosity(b)) => Ok(Quantity::MagneticFieldStrength(a.clone() * b.clone())),
+			(Quantity::VolumeChargeDensity(a), Quantity::VolumetricFlowRate(b)) => Ok(Quantity::Current(a.clone() * b.clone())),
+			(Quantity::Angle(a), Quantity::Angle(b)) => Ok(Quantity::SolidAngle(a.clone() * b.clone())),
+			(Quantity::Angle(a), Quantity::InverseSolidAngle(b)) => Ok(Quantity::InverseAngle(a.clone() * b.clone())),
+			(Quantity::Angle(a), Quantity::Frequency(b)) => Ok(Quantity::AngularVelocity(a.clone() * b.clone())),
+			(Quantity::Angle(a), Quantity::InverseAngularVelocity(b)) => Ok(Quantity::Time(a.clone() * b.clone())),
+			(Quantity::Area(a), Quantity::Distance(b)) => Ok(Quantity::Volume(a.clone() * b.clone())),
+			(Quantity::Area(a), Quantity::InverseDistance(b)) => Ok(Quantity::Distance(a.clone() * b.clone())),
+			(Quantity::Area(a), Quantity::InverseMass(b)) => Ok(Quantity::AreaPerMass(a.clone() * b.clone())),
+			(Quantity::Area(a), Quantity::Illuminance(b)) => Ok(Quantity::LuminousFlux(a.clone() * b.clone())),
+			(Quantity::Area(a), Quantity::InverseCharge(b)) => Ok(Quantity::InverseSurfaceChargeDensity(a.clone() * b.clone())),
+			(Quantity::Area(a), Quantity::InverseLinearChargeDensity(b)) => Ok(Quantity::InverseVolumeChargeDensity(a.clone() * b.clone())),
+			(Quantity::Area(a), Quantity::InverseLuminousFlux(b)) => Ok(Quantity::AreaPerLumen(a.clone() * b.clone())),
+			(Quantity::Area(a), Quantity::InverseMagneticFlux(b)) => Ok(Quantity::InverseMagneticFluxDensity(a.clone() * b.clone())),
+			(Quantity::Area(a), Quantity::Irradiance(b)) => Ok(Quantity::Power(a.clone() * b.clone())),
+			(Quantity::Area(a), Quantity::MagneticFluxDensity(b)) => Ok(Quantity::MagneticFlux(a.clone() * b.clone())),
+			(Quantity::Area(a), Quantity::SurfaceChargeDensity(b)) => Ok(Quantity::Charge(a.clone() * b.clone())),
+			(Quantity::Area(a), Quantity::VolumeChargeDensity(b)) => Ok(Quantity::LinearChargeDensity(a.clone() * b.clone())),
+			(Quantity::Area(a), Quantity::InverseVolume(b)) => Ok(Quantity::InverseDistance(a.clone() * b.clone())),
+			(Quantity::Area(a), Quantity::AreaDensity(b)) => Ok(Quantity::Mass(a.clone() * b.clone())),
+			(Quantity::Area(a), Quantity::DynamicViscosity(b)) => Ok(Quantity::Momentum(a.clone() * b.clone())),
+			(Quantity::Area(a), Quantity::Frequency(b)) => Ok(Quantity::KinematicViscosity(a.clone() * b.clone())),
+			(Quantity::Area(a), Quantity::InverseForce(b)) => Ok(Quantity::InversePressure(a.clone() * b.clone())),
+			(Quantity::Area(a), Quantity::InverseKinematicViscosity(b)) => Ok(Quantity::Time(a.clone() * b.clone())),
+			(Quantity::Area(a), Quantity::InverseMomentOfInertia(b)) => Ok(Quantity::InverseMass(a.clone() * b.clone())),
+			(Quantity::Area(a), Quantity::InverseMomentum(b)) => Ok(Quantity::InverseDynamicViscosity(a.clone() * b.clone())),
+			(Quantity::Area(a), Quantity::InversePower(b)) => Ok(Quantity::InverseIrradiance(a.clone() * b.clone())),
+			(Quantity::Area(a), Quantity::InverseVolumetricFlowRate(b)) => Ok(Quantity::TimePerDistance(a.clone() * b.clone())),
+			(Quantity::Area(a), Quantity::Pressure(b)) => Ok(Quantity::Force(a.clone() * b.clone())),
+			(Quantity::Area(a), Quantity::Velocity(b)) => Ok(Quantity::VolumetricFlowRate(a.clone() * b.clone())),
+			(Quantity::InverseAngle(a), Quantity::Time(b)) => Ok(Quantity::InverseAngularVelocity(a.clone() * b.clone())),
+			(Quantity::InverseAngle(a), Quantity::InverseAngle(b)) => Ok(Quantity::InverseSolidAngle(a.clone() * b.clone())),
+			(Quantity::InverseAngle(a), Quantity::SolidAngle(b)) => Ok(Quantity::Angle(a.clone() * b.clone())),
+			(Quantity::InverseAngle(a), Quantity::AngularVelocity(b)) => Ok(Quantity::Frequency(a.clone() * b.clone())),
+			(Quantity::InverseArea(a), Quantity::Distance(b)) => Ok(Quantity::InverseDistance(a.clone() * b.clone())),
+			(Quantity::InverseArea(a), Quantity::InverseDistance(b)) => Ok(Quantity::InverseVolume(a.clone() * b.clone())),
+			(Quantity::InverseArea(a), Quantity::Mass(b)) => Ok(Quantity::AreaDensity(a.clone() * b.clone())),
+			(Quantity::InverseArea(a), Quantity::Time(b)) => Ok(Quantity::InverseKinematicViscosity(a.clone() * b.clone())),
+			(Quantity::InverseArea(a), Quantity::AreaPerLumen(b)) => Ok(Quantity::InverseLuminousFlux(a.clone() * b.clone())),
+			(Quantity::InverseArea(a), Quantity::Charge(b)) => Ok(Quantity::SurfaceChargeDensity(a.clone() * b.clone())),
+			(Quantity::InverseArea(a), Quantity::InverseIrradiance(b)) => Ok(Quantity::InversePower(a.clone() * b.clone())),
+			(Quantity::InverseArea(a), Quantity::InverseMagneticFluxDensity(b)) => Ok(Quantity::InverseMagneticFlux(a.clone() * b.clone())),
+			(Quantity::InverseArea(a), Quantity::InverseSurfaceChargeDensity(b)) => Ok(Quantity::InverseCharge(a.clone() * b.clone())),
+			(Quantity::InverseArea(a), Quantity::InverseVolumeChargeDensity(b)) => Ok(Quantity::InverseLinearChargeDensity(a.clone() * b.clone())),
+			(Quantity::InverseArea(a), Quantity::LinearChargeDensity(b)) => Ok(Quantity::VolumeChargeDensity(a.clone() * b.clone())),
+			(Quantity::InverseArea(a), Quantity::LuminousFlux(b)) => Ok(Quantity::Illuminance(a.clone() * b.clone())),
+			(Quantity::InverseArea(a), Quantity::MagneticFlux(b)) => Ok(Quantity::MagneticFluxDensity(a.clone() * b.clone())),
+			(Quantity::InverseArea(a), Quantity::Volume(b)) => Ok(Quantity::Distance(a.clone() * b.clone())),
+			(Quantity::InverseArea(a), Quantity::AreaPerMass(b)) => Ok(Quantity::InverseMass(a.clone() * b.clone())),
+			(Quantity::InverseArea(a), Quantity::Force(b)) => Ok(Quantity::Pressure(a.clone() * b.clone())),
+			(Quantity::InverseArea(a), Quantity::InverseDynamicViscosity(b)) => Ok(Quantity::InverseMomentum(a.clone() * b.clone())),
+			(Quantity::InverseArea(a), Quantity::InversePressure(b)) => Ok(Quantity::InverseForce(a.clone() * b.clone())),
+			(Quantity::InverseArea(a), Quantity::KinematicViscosity(b)) => Ok(Quantity::Frequency(a.clone() * b.clone())),
+			(Quantity::InverseArea(a), Quantity::MomentOfInertia(b)) => Ok(Quantity::Mass(a.clone() * b.clone())),
+			(Quantity::InverseArea(a), Quantity::Momentum(b)) => Ok(Quantity::DynamicViscosity(a.clone() * b.clone())),
+			(Quantity::InverseArea(a), Quantity::Power(b)) => Ok(Quantity::Irradiance(a.clone() * b.clone())),
+			(Quantity::InverseArea(a), Quantity::TimePerDistance(b)) => Ok(Quantity::InverseVolumetricFlowRate(a.clone() * b.clone())),
+			(Quantity::InverseArea(a), Quantity::VolumetricFlowRate(b)) => Ok(Quantity::Velocity(a.clone() * b.clone())),
+			(Quantity::InverseSolidAngle(a), Quantity::InverseLuminosity(b)) => Ok(Quantity::InverseLuminousFlux(a.clone() * b.clone())),
+			(Quantity::InverseSolidAngle(a), Quantity::InverseRadiance(b)) => Ok(Quantity::InverseIrradiance(a.clone() * b.clone())),
+			(Quantity::InverseSolidAngle(a), Quantity::Irradiance(b)) => Ok(Quantity::Radiance(a.clone() * b.clone())),
+			(Quantity::InverseSolidAngle(a), Quantity::LuminousFlux(b)) => Ok(Quantity::Luminosity(a.clone() * b.clone())),
+			(Quantity::InverseSolidAngle(a), Quantity::Angle(b)) => Ok(Quantity::InverseAngle(a.clone() * b.clone())),
+			(Quantity::InverseVolume(a), Quantity::Amount(b)) => Ok(Quantity::Concentration(a.clone() * b.clone())),
+			(Quantity::InverseVolume(a), Quantity::Distance(b)) => Ok(Quantity::InverseArea(a.clone() * b.clone())),
+			(Quantity::InverseVolume(a), Quantity::Mass(b)) => Ok(Quantity::Density(a.clone() * b.clone())),
+			(Quantity::InverseVolume(a), Quantity::Time(b)) => Ok(Quantity::InverseVolumetricFlowRate(a.clone() * b.clone())),
+			(Quantity::InverseVolume(a), Quantity::MolarVolume(b)) => Ok(Quantity::InverseAmount(a.clone() * b.clone())),
+			(Quantity::InverseVolume(a), Quantity::Charge(b)) => Ok(Quantity::VolumeChargeDensity(a.clone() * b.clone())),
+			(Quantity::InverseVolume(a), Quantity::InverseVolumeChargeDensity(b)) => Ok(Quantity::InverseCharge(a.clone() * b.clone())),
+			(Quantity::InverseVolume(a), Quantity::Area(b)) => Ok(Quantity::InverseDistance(a.clone() * b.clone())),
+			(Quantity::InverseVolume(a), Quantity::Energy(b)) => Ok(Quantity::Pressure(a.clone() * b.clone())),
+			(Quantity::InverseVolume(a), Quantity::Torque(b)) => Ok(Quantity::Pressure(a.clone() * b.clone())),
+			(Quantity::InverseVolume(a), Quantity::InversePressure(b)) => Ok(Quantity::InverseEnergy(a.clone() * b.clone())),
+			(Quantity::InverseVolume(a), Quantity::VolumePerMass(b)) => Ok(Quantity::InverseMass(a.clone() * b.clone())),
+			(Quantity::InverseVolume(a), Quantity::VolumetricFlowRate(b)) => Ok(Quantity::Frequency(a.clone() * b.clone())),
+			(Quantity::SolidAngle(a), Quantity::Luminosity(b)) => Ok(Quantity::LuminousFlux(a.clone() * b.clone())),
+			(Quantity::SolidAngle(a), Quantity::InverseIrradiance(b)) => Ok(Quantity::InverseRadiance(a.clone() * b.clone())),
+			(Quantity::SolidAngle(a), Quantity::InverseLuminousFlux(b)) => Ok(Quantity::InverseLuminosity(a.clone() * b.clone())),
+			(Quantity::SolidAngle(a), Quantity::Radiance(b)) => Ok(Quantity::Irradiance(a.clone() * b.clone())),
+			(Quantity::SolidAngle(a), Quantity::InverseAngle(b)) => Ok(Quantity::Angle(a.clone() * b.clone())),
+			(Quantity::Volume(a), Quantity::InverseAmount(b)) => Ok(Quantity::MolarVolume(a.clone() * b.clone())),
+			(Quantity::Volume(a), Quantity::InverseDistance(b)) => Ok(Quantity::Area(a.clone() * b.clone())),
+			(Quantity::Volume(a), Quantity::InverseMass(b)) => Ok(Quantity::VolumePerMass(a.clone() * b.clone())),
+			(Quantity::Volume(a), Quantity::Concentration(b)) => Ok(Quantity::Amount(a.clone() * b.clone())),
+			(Quantity::Volume(a), Quantity::InverseCharge(b)) => Ok(Quantity::InverseVolumeChargeDensity(a.clone() * b.clone())),
+			(Quantity::Volume(a), Quantity::VolumeChargeDensity(b)) => Ok(Quantity::Charge(a.clone() * b.clone())),
+			(Quantity::Volume(a), Quantity::InverseArea(b)) => Ok(Quantity::Distance(a.clone() * b.clone())),
+			(Quantity::Volume(a), Quantity::Density(b)) => Ok(Quantity::Mass(a.clone() * b.clone())),
+			(Quantity::Volume(a), Quantity::Frequency(b)) => Ok(Quantity::VolumetricFlowRate(a.clone() * b.clone())),
+			(Quantity::Volume(a), Quantity::InverseEnergy(b)) => Ok(Quantity::InversePressure(a.clone() * b.clone())),
+			(Quantity::Volume(a), Quantity::InverseTorque(b)) => Ok(Quantity::InversePressure(a.clone() * b.clone())),
+			(Quantity::Volume(a), Quantity::InverseVolumetricFlowRate(b)) => Ok(Quantity::Time(a.clone() * b.clone())),
+			(Quantity::Volume(a), Quantity::Pressure(b)) => Ok(Quantity::Energy(a.clone() * b.clone())),
+			(Quantity::Acceleration(a), Quantity::Mass(b)) => Ok(Quantity::Force(a.clone() * b.clone())),
+			(Quantity::Acceleration(a), Quantity::Time(b)) => Ok(Quantity::Velocity(a.clone() * b.clone())),
+			(Quantity::Acceleration(a), Quantity::InverseIrradiance(b)) => Ok(Quantity::InverseDynamicViscosity(a.clone() * b.clone())),
+			(Quantity::Acceleration(a), Quantity::AreaDensity(b)) => Ok(Quantity::Pressure(a.clone() * b.clone())),
+			(Quantity::Acceleration(a), Quantity::DynamicViscosity(b)) => Ok(Quantity::Irradiance(a.clone() * b.clone())),
+			(Quantity::Acceleration(a), Quantity::Frequency(b)) => Ok(Quantity::Jerk(a.clone() * b.clone())),
+			(Quantity::Acceleration(a), Quantity::InverseForce(b)) => Ok(Quantity::InverseMass(a.clone() * b.clone())),
+			(Quantity::Acceleration(a), Quantity::InverseJerk(b)) => Ok(Quantity::Time(a.clone() * b.clone())),
+			(Quantity::Acceleration(a), Quantity::InversePower(b)) => Ok(Quantity::InverseMomentum(a.clone() * b.clone())),
+			(Quantity::Acceleration(a), Quantity::InversePressure(b)) => Ok(Quantity::AreaPerMass(a.clone() * b.clone())),
+			(Quantity::Acceleration(a), Quantity::Momentum(b)) => Ok(Quantity::Power(a.clone() * b.clone())),
+			(Quantity::Acceleration(a), Quantity::TimePerDistance(b)) => Ok(Quantity::Frequency(a.clone() * b.clone())),
+			(Quantity::Acceleration(a), Quantity::InverseAbsorbedDose(b)) => Ok(Quantity::InverseDistance(a.clone() * b.clone())),
+			(Quantity::Acceleration(a), Quantity::InverseDoseEquivalent(b)) => Ok(Quantity::InverseDistance(a.clone() * b.clone())),
+			(Quantity::AngularAcceleration(a), Quantity::Time(b)) => Ok(Quantity::AngularVelocity(a.clone() * b.clone())),
+			(Quantity::AngularAcceleration(a), Quantity::InverseAngularVelocity(b)) => Ok(Quantity::Frequency(a.clone() * b.clone())),
+			(Quantity::AngularMomentum(a), Quantity::InverseMomentOfInertia(b)) => Ok(Quantity::AngularVelocity(a.clone() * b.clone())),
+			(Quantity::AngularVelocity(a), Quantity::Time(b)) => Ok(Quantity::Angle(a.clone() * b.clone())),
+			(Quantity::AngularVelocity(a), Quantity::InverseAngle(b)) => Ok(Quantity::Frequency(a.clone() * b.clone())),
+			(Quantity::AngularVelocity(a), Quantity::Frequency(b)) => Ok(Quantity::AngularAcceleration(a.clone() * b.clone())),
+			(Quantity::AngularVelocity(a), Quantity::InverseAngularAcceleration(b)) => Ok(Quantity::Time(a.clone() * b.clone())),
+			(Quantity::AngularVelocity(a), Quantity::MomentOfInertia(b)) => Ok(Quantity::AngularMomentum(a.clone() * b.clone())),
+			(Quantity::AreaDensity(a), Quantity::InverseDistance(b)) => Ok(Quantity::Density(a.clone() * b.clone())),
+			(Quantity::AreaDensity(a), Quantity::InverseMass(b)) => Ok(Quantity::InverseArea(a.clone() * b.clone())),
+			(Quantity::AreaDensity(a), Quantity::Area(b)) => Ok(Quantity::Mass(a.clone() * b.clone())),
+			(Quantity::AreaDensity(a), Quantity::Acceleration(b)) => Ok(Quantity::Pressure(a.clone() * b.clone())),
+			(Quantity::AreaDensity(a), Quantity::InverseDynamicViscosity(b)) => Ok(Quantity::TimePerDistance(a.clone() * b.clone())),
+			(Quantity::AreaDensity(a), Quantity::InverseMassFlowRate(b)) => Ok(Quantity::InverseKinematicViscosity(a.clone() * b.clone())),
+			(Quantity::AreaDensity(a), Quantity::InverseMomentum(b)) => Ok(Quantity::InverseVolumetricFlowRate(a.clone() * b.clone())),
+			(Quantity::AreaDensity(a), Quantity::InversePressure(b)) => Ok(Quantity::InverseAcceleration(a.clone() * b.clone())),
+			(Quantity::AreaDensity(a), Quantity::KinematicViscosity(b)) => Ok(Quantity::MassFlowRate(a.clone() * b.clone())),
+			(Quantity::AreaDensity(a), Quantity::Velocity(b)) => Ok(Quantity::DynamicViscosity(a.clone() * b.clone())),
+			(Quantity::AreaDensity(a), Quantity::VolumePerMass(b)) => Ok(Quantity::Distance(a.clone() * b.clone())),
+			(Quantity::AreaDensity(a), Quantity::VolumetricFlowRate(b)) => Ok(Quantity::Momentum(a.clone() * b.clone())),
+			(Quantity::AreaPerMass(a), Quantity::Distance(b)) => Ok(Quantity::VolumePerMass(a.clone() * b.clone())),
+			(Quantity::AreaPerMass(a), Quantity::Mass(b)) => Ok(Quantity::Area(a.clone() * b.clone())),
+			(Quantity::AreaPerMass(a), Quantity::InverseArea(b)) => Ok(Quantity::InverseMass(a.clone() * b.clone())),
+			(Quantity::AreaPerMass(a), Quantity::Density(b)) => Ok(Quantity::InverseDistance(a.clone() * b.clone())),
+			(Quantity::AreaPerMass(a), Quantity::DynamicViscosity(b)) => Ok(Quantity::Velocity(a.clone() * b.clone())),
+			(Quantity::AreaPerMass(a), Quantity::InverseAcceleration(b)) => Ok(Quantity::InversePressure(a.clone() * b.clone())),
+			(Quantity::AreaPerMass(a), Quantity::InverseKinematicViscosity(b)) => Ok(Quantity::InverseMassFlowRate(a.clone() * b.clone())),
+			(Quantity::AreaPerMass(a), Quantity::InverseVolumetricFlowRate(b)) => Ok(Quantity::InverseMomentum(a.clone() * b.clone())),
+			(Quantity::AreaPerMass(a), Quantity::MassFlowRate(b)) => Ok(Quantity::KinematicViscosity(a.clone() * b.clone())),
+			(Quantity::AreaPerMass(a), Quantity::Momentum(b)) => Ok(Quantity::VolumetricFlowRate(a.clone() * b.clone())),
+			(Quantity::AreaPerMass(a), Quantity::Pressure(b)) => Ok(Quantity::Acceleration(a.clone() * b.clone())),
+			(Quantity::AreaPerMass(a), Quantity::TimePerDistance(b)) => Ok(Quantity::InverseDynamicViscosity(a.clone() * b.clone())),
+			(Quantity::Density(a), Quantity::Distance(b)) => Ok(Quantity::AreaDensity(a.clone() * b.clone())),
+			(Quantity::Density(a), Quantity::InverseMass(b)) => Ok(Quantity::InverseVolume(a.clone() * b.clone())),
+			(Quantity::Density(a), Quantity::Molality(b)) => Ok(Quantity::Concentration(a.clone() * b.clone())),
+			(Quantity::Density(a), Quantity::MolarVolume(b)) => Ok(Quantity::MolarMass(a.clone() * b.clone())),
+			(Quantity::Density(a), Quantity::Volume(b)) => Ok(Quantity::Mass(a.clone() * b.clone())),
+			(Quantity::Density(a), Quantity::AreaPerMass(b)) => Ok(Quantity::InverseDistance(a.clone() * b.clone())),
+			(Quantity::Density(a), Quantity::InverseDynamicViscosity(b)) => Ok(Quantity::InverseKinematicViscosity(a.clone() * b.clone())),
+			(Quantity::Density(a), Quantity::InverseMassFlowRate(b)) => Ok(Quantity::InverseVolumetricFlowRate(a.clone() * b.clone())),
+			(Quantity::Density(a), Quantity::KinematicViscosity(b)) => Ok(Quantity::DynamicViscosity(a.clone() * b.clone())),
+			(Quantity::Density(a), Quantity::VolumetricFlowRate(b)) => Ok(Quantity::MassFlowRate(a.clone() * b.clone())),
+			(Quantity::DynamicViscosity(a), Quantity::Distance(b)) => Ok(Quantity::MassFlowRate(a.clone() * b.clone())),
+			(Quantity::DynamicViscosity(a), Quantity::SpecificHeatCapacity(b)) => Ok(Quantity::ThermalConductivity(a.clone() * b.clone())),
+			(Quantity::DynamicViscosity(a), Quantity::ThermalResistivity(b)) => Ok(Quantity::InverseSpecificHeatCapacity(a.clone() * b.clone())),
+			(Quantity::DynamicViscosity(a), Quantity::InverseIrradiance(b)) => Ok(Quantity::InverseAcceleration(a.clone() * b.clone())),
+			(Quantity::DynamicViscosity(a), Quantity::InverseLinearChargeDensity(b)) => Ok(Quantity::MagneticFluxDensity(a.clone() * b.clone())),
+			(Quantity::DynamicViscosity(a), Quantity::InverseMagneticFlux(b)) => Ok(Quantity::VolumeChargeDensity(a.clone() * b.clone())),
+			(Quantity::DynamicViscosity(a), Quantity::InverseMagneticFluxDensity(b)) => Ok(Quantity::LinearChargeDensity(a.clone() * b.clone())),
+			(Quantity::DynamicViscosity(a), Quantity::InverseVolumeChargeDensity(b)) => Ok(Quantity::MagneticFlux(a.clone() * b.clone())),
+			(Quantity::DynamicViscosity(a), Quantity::Area(b)) => Ok(Quantity::Momentum(a.clone() * b.clone())),
+			(Quantity::DynamicViscosity(a), Quantity::Acceleration(b)) => Ok(Quantity::Irradiance(a.clone() * b.clone())),
+			(Quantity::DynamicViscosity(a), Quantity::AreaPerMass(b)) => Ok(Quantity::Velocity(a.clone() * b.clone())),
+			(Quantity::DynamicViscosity(a), Quantity::Frequency(b)) => Ok(Quantity::Pressure(a.clone() * b.clone())),
+			(Quantity::DynamicViscosity(a), Quantity::InverseEnergy(b)) => Ok(Quantity::InverseVolumetricFlowRate(a.clone() * b.clone())),
+			(Quantity::DynamicViscosity(a), Quantity::InverseTorque(b)) => Ok(Quantity::InverseVolumetricFlowRate(a.clone() * b.clone())),
+			(Quantity::DynamicViscosity(a), Quantity::InverseForce(b)) => Ok(Quantity::InverseKinematicViscosity(a.clone() * b.clone())),
+			(Quantity::DynamicViscosity(a), Quantity::InverseKinematicViscosity(b)) => Ok(Quantity::Density(a.clone() * b.clone())),
+			(Quantity::DynamicViscosity(a), Quantity::InverseMassFlowRate(b)) => Ok(Quantity::InverseDistance(a.clone() * b.clone())),
+			(Quantity::DynamicViscosity(a), Quantity::InverseMomentum(b)) => Ok(Quantity::InverseArea(a.clone() * b.clone())),
+			(Quantity::DynamicViscosity(a), Quantity::InversePressure(b)) => Ok(Quantity::Time(a.clone() * b.clone())),
+			(Quantity::DynamicViscosity(a), Quantity::KinematicViscosity(b)) => Ok(Quantity::Force(a.clone() * b.clone())),
+			(Quantity::DynamicViscosity(a), Quantity::TimePerDistance(b)) => Ok(Quantity::AreaDensity(a.clone() * b.clone())),
+			(Quantity::DynamicViscosity(a), Quantity::VolumePerMass(b)) => Ok(Quantity::KinematicViscosity(a.clone() * b.clone())),
+			(Quantity::DynamicViscosity(a), Quantity::VolumetricFlowRate(b)) => Ok(Quantity::Energy(a.clone() * b.clone())),
+			(Quantity::Energy(a), Quantity::InverseAmount(b)) => Ok(Quantity::MolarEnergy(a.clone() * b.clone())),
+			(Quantity::Energy(a), Quantity::InverseCurrent(b)) => Ok(Quantity::MagneticFlux(a.clone() * b.clone())),
+			(Quantity::Energy(a), Quantity::InverseDistance(b)) => Ok(Quantity::Force(a.clone() * b.clone())),
+			(Quantity::Energy(a), Quantity::InverseTemperature(b)) => Ok(Quantity::HeatCapacity(a.clone() * b.clone())),
+			(Quantity::Energy(a), Quantity::InverseEntropy(b)) => Ok(Quantity::Temperature(a.clone() * b.clone())),
+			(Quantity::Energy(a), Quantity::InverseHeatCapacity(b)) => Ok(Quantity::Temperature(a.clone() * b.clone())),
+			(Quantity::Energy(a), Quantity::InverseMolarEnergy(b)) => Ok(Quantity::Amount(a.clone() * b.clone())),
+			(Quantity::Energy(a), Quantity::InverseCharge(b)) => Ok(Quantity::Voltage(a.clone() * b.clone())),
+			(Quantity::Energy(a), Quantity::InverseMagneticFlux(b)) => Ok(Quantity::Current(a.clone() * b.clone())),
+			(Quantity::Energy(a), Quantity::InverseVoltage(b)) => Ok(Quantity::Charge(a.clone() * b.clone())),
+			(Quantity::Energy(a), Quantity::InverseVolume(b)) => Ok(Quantity::Pressure(a.clone() * b.clone())),
+			(Quantity::Energy(a), Quantity::Frequency(b)) => Ok(Quantity::Power(a.clone() * b.clone())),
+			(Quantity::Energy(a), Quantity::InverseDynamicViscosity(b)) => Ok(Quantity::VolumetricFlowRate(a.clone() * b.clone())),
+			(Quantity::Energy(a), Quantity::InverseForce(b)) => Ok(Quantity::Distance(a.clone() * b.clone())),
+			(Quantity::Energy(a), Quantity::InverseKinematicViscosity(b)) => Ok(Quantity::MassFlowRate(a.clone() * b.clone())),
+			(Quantity::Energy(a), Quantity::InverseMassFlowRate(b)) => Ok(Quantity::KinematicViscosity(a.clone() * b.clone())),
+			(Quantity::Energy(a), Quantity::InverseMomentum(b)) => Ok(Quantity::Velocity(a.clone() * b.clone())),
+			(Quantity::Energy(a), Quantity::InversePower(b)) => Ok(Quantity::Time(a.clone() * b.clone())),
+			(Quantity::Energy(a), Quantity::InversePressure(b)) => Ok(Quantity::Volume(a.clone() * b.clone())),
+			(Quantity::Energy(a), Quantity::InverseVolumetricFlowRate(b)) => Ok(Quantity::DynamicViscosity(a.clone() * b.clone())),
+			(Quantity::Energy(a), Quantity::TimePerDistance(b)) => Ok(Quantity::Momentum(a.clone() * b.clone())),
+			(Quantity::Energy(a), Quantity::InverseAbsorbedDose(b)) => Ok(Quantity::Mass(a.clone() * b.clone())),
+			(Quantity::Energy(a), Quantity::InverseDoseEquivalent(b)) => Ok(Quantity::Mass(a.clone() * b.clone())),
+			(Quantity::Force(a), Quantity::Distance(b)) => Ok(Quantity::Energy(a.clone() * b.clone())),
+			(Quantity::Force(a), Quantity::InverseMass(b)) => Ok(Quantity::Acceleration(a.clone() * b.clone())),
+			(Quantity::Force(a), Quantity::Time(b)) => Ok(Quantity::Momentum(a.clone() * b.clone())),
+			(Quantity::Force(a), Quantity::InverseCharge(b)) => Ok(Quantity::ElectricField(a.clone() * b.clone())),
+			(Quantity::Force(a), Quantity::InverseElectricField(b)) => Ok(Quantity::Charge(a.clone() * b.clone())),
+			(Quantity::Force(a), Quantity::InverseLinearChargeDensity(b)) => Ok(Quantity::Voltage(a.clone() * b.clone())),
+			(Quantity::Force(a), Quantity::InverseMagneticFieldStrength(b)) => Ok(Quantity::MagneticFlux(a.clone() * b.clone())),
+			(Quantity::Force(a), Quantity::InverseMagneticFlux(b)) => Ok(Quantity::MagneticFieldStrength(a.clone() * b.clone())),
+			(Quantity::Force(a), Quantity::InverseVoltage(b)) => Ok(Quantity::LinearChargeDensity(a.clone() * b.clone())),
+			(Quantity::Force(a), Quantity::InverseArea(b)) => Ok(Quantity::Pressure(a.clone() * b.clone())),
+			(Quantity::Force(a), Quantity::InverseAcceleration(b)) => Ok(Quantity::Mass(a.clone() * b.clone())),
+			(Quantity::Force(a), Quantity::InverseDynamicViscosity(b)) => Ok(Quantity::KinematicViscosity(a.clone() * b.clone())),
+			(Quantity::Force(a), Quantity::InverseEnergy(b)) => Ok(Quantity::InverseDistance(a.clone() * b.clone())),
+			(Quantity::Force(a), Quantity::InverseTorque(b)) => Ok(Quantity::InverseDistance(a.clone() * b.clone())),
+			(Quantity::Force(a), Quantity::InverseKinematicViscosity(b)) => Ok(Quantity::DynamicViscosity(a.clone() * b.clone())),
+			(Quantity::Force(a), Quantity::InverseMassFlowRate(b)) => Ok(Quantity::Velocity(a.clone() * b.clone())),
+			(Quantity::Force(a), Quantity::InverseMomentum(b)) => Ok(Quantity::Frequency(a.clone() * b.clone())),
+			(Quantity::Force(a), Quantity::InversePower(b)) => Ok(Quantity::TimePerDistance(a.clone() * b.clone())),
+			(Quantity::Force(a), Quantity::InversePressure(b)) => Ok(Quantity::Area(a.clone() * b.clone())),
+			(Quantity::Force(a), Quantity::TimePerDistance(b)) => Ok(Quantity::MassFlowRate(a.clone() * b.clone())),
+			(Quantity::Force(a), Quantity::Velocity(b)) => Ok(Quantity::Power(a.clone() * b.clone())),
+			(Quantity::Frequency(a), Quantity::Amount(b)) => Ok(Quantity::CatalyticActivity(a.clone() * b.clone())),
+			(Quantity::Frequency(a), Quantity::Distance(b)) => Ok(Quantity::Velocity(a.clone() * b.clone())),
+			(Quantity::Frequency(a), Quantity::InverseCurrent(b)) => Ok(Quantity::InverseCharge(a.clone() * b.clone())),
+			(Quantity::Frequency(a), Quantity::Mass(b)) => Ok(Quantity::MassFlowRate(a.clone() * b.clone())),
+			(Quantity::Frequency(a), Quantity::Entropy(b)) => Ok(Quantity::ThermalConductance(a.clone() * b.clone())),
+			(Quantity::Frequency(a), Quantity::HeatCapacity(b)) => Ok(Quantity::ThermalConductance(a.clone() * b.clone())),
+			(Quantity::Frequency(a), Quantity::InverseCatalyticActivity(b)) => Ok(Quantity::InverseAmount(a.clone() * b.clone())),
+			(Quantity::Frequency(a), Quantity::ThermalResistance(b)) => Ok(Quantity::InverseHeatCapacity(a.clone() * b.clone())),
+			(Quantity::Frequency(a), Quantity::Capacitance(b)) => Ok(Quantity::Conductance(a.clone() * b.clone())),
+			(Quantity::Frequency(a), Quantity::Charge(b)) => Ok(Quantity::Current(a.clone() * b.clone())),
+			(Quantity::Frequency(a), Quantity::Conductance(b)) => Ok(Quantity::InverseInductance(a.clone() * b.clone())),
+			(Quantity::Frequency(a), Quantity::Inductance(b)) => Ok(Quantity::Resistance(a.clone() * b.clone())),
+			(Quantity::Frequency(a), Quantity::InverseMagneticFieldStrength(b)) => Ok(Quantity::InverseLinearChargeDensity(a.clone() * b.clone())),
+			(Quantity::Frequency(a), Quantity::InverseVoltage(b)) => Ok(Quantity::InverseMagneticFlux(a.clone() * b.clone())),
+			(Quantity::Frequency(a), Quantity::LinearChargeDensity(b)) => Ok(Quantity::MagneticFieldStrength(a.clone() * b.clone())),
+			(Quantity::Frequency(a), Quantity::MagneticFlux(b)) => Ok(Quantity::Voltage(a.clone() * b.clone())),
+			(Quantity::Frequency(a), Quantity::Resistance(b)) => Ok(Quantity::Elastance(a.clone() * b.clone())),
+			(Quantity::Frequency(a), Quantity::Angle(b)) => Ok(Quantity::AngularVelocity(a.clone() * b.clone())),
+			(Quantity::Frequency(a), Quantity::Area(b)) => Ok(Quantity::KinematicViscosity(a.clone() * b.clone())),
+			(Quantity::Frequency(a), Quantity::Volume(b)) => Ok(Quantity::VolumetricFlowRate(a.clone() * b.clone())),
+			(Quantity::Frequency(a), Quantity::Acceleration(b)) => Ok(Quantity::Jerk(a.clone() * b.clone())),
+			(Quantity::Frequency(a), Quantity::AngularVelocity(b)) => Ok(Quantity::AngularAcceleration(a.clone() * b.clone())),
+			(Quantity::Frequency(a), Quantity::DynamicViscosity(b)) => Ok(Quantity::Pressure(a.clone() * b.clone())),
+			(Quantity::Frequency(a), Quantity::Energy(b)) => Ok(Quantity::Power(a.clone() * b.clone())),
+			(Quantity::Frequency(a), Quantity::Torque(b)) => Ok(Quantity::Power(a.clone() * b.clone())),
+			(Quantity::Frequency(a), Quantity::InverseAcceleration(b)) => Ok(Quantity::TimePerDistance(a.clone() * b.clone())),
+			(Quantity::Frequency(a), Quantity::InverseAngularAcceleration(b)) => Ok(Quantity::InverseAngularVelocity(a.clone() * b.clone())),
+			(Quantity::Frequency(a), Quantity::InverseAngularVelocity(b)) => Ok(Quantity::InverseAngle(a.clone() * b.clone())),
+			(Quantity::Frequency(a), Quantity::InverseForce(b)) => Ok(Quantity::InverseMomentum(a.clone() * b.clone())),
+			(Quantity::Frequency(a), Quantity::InverseJerk(b)) => Ok(Quantity::InverseAcceleration(a.clone() * b.clone())),
+			(Quantity::Frequency(a), Quantity::InverseKinematicViscosity(b)) => Ok(Quantity::InverseArea(a.clone() * b.clone())),
+			(Quantity::Frequency(a), Quantity::InverseMassFlowRate(b)) => Ok(Quantity::InverseMass(a.clone() * b.clone())),
+			(Quantity::Frequency(a), Quantity::InversePower(b)) => Ok(Quantity::InverseEnergy(a.clone() * b.clone())),
+			(Quantity::Frequency(a), Quantity::InversePressure(b)) => Ok(Quantity::InverseDynamicViscosity(a.clone() * b.clone())),
+			(Quantity::Frequency(a), Quantity::InverseVolumetricFlowRate(b)) => Ok(Quantity::InverseVolume(a.clone() * b.clone())),
+			(Quantity::Frequency(a), Quantity::Momentum(b)) => Ok(Quantity::Force(a.clone() * b.clone())),
+			(Quantity::Frequency(a), Quantity::TimePerDistance(b)) => Ok(Quantity::InverseDistance(a.clone() * b.clone())),
+			(Quantity::Frequency(a), Quantity::Velocity(b)) => Ok(Quantity::Acceleration(a.clone() * b.clone())),
+			(Quantity::Frequency(a), Quantity::InverseAbsorbedDose(b)) => Ok(Quantity::InverseKinematicViscosity(a.clone() * b.clone())),
+			(Quantity::Frequency(a), Quantity::InverseDoseEquivalent(b)) => Ok(Quantity::InverseKinematicViscosity(a.clone() * b.clone())),
+			(Quantity::InverseAcceleration(a), Quantity::InverseMass(b)) => Ok(Quantity::InverseForce(a.clone() * b.clone())),
+			(Quantity::InverseAcceleration(a), Quantity::Time(b)) => Ok(Quantity::InverseJerk(a.clone() * b.clone())),
+			(Quantity::InverseAcceleration(a), Quantity::Irradiance(b)) => Ok(Quantity::DynamicViscosity(a.clone() * b.clone())),
+			(Quantity::InverseAcceleration(a), Quantity::AreaPerMass(b)) => Ok(Quantity::InversePressure(a.clone() * b.clone())),
+			(Quantity::InverseAcceleration(a), Quantity::Force(b)) => Ok(Quantity::Mass(a.clone() * b.clone())),
+			(Quantity::InverseAcceleration(a), Quantity::Frequency(b)) => Ok(Quantity::TimePerDistance(a.clone() * b.clone())),
+			(Quantity::InverseAcceleration(a), Quantity::InverseDynamicViscosity(b)) => Ok(Quantity::InverseIrradiance(a.clone() * b.clone())),
+			(Quantity::InverseAcceleration(a), Quantity::InverseMomentum(b)) => Ok(Quantity::InversePower(a.clone() * b.clone())),
+			(Quantity::InverseAcceleration(a), Quantity::Jerk(b)) => Ok(Quantity::Frequency(a.clone() * b.clone())),
+			(Quantity::InverseAcceleration(a), Quantity::Power(b)) => Ok(Quantity::Momentum(a.clone() * b.clone())),
+			(Quantity::InverseAcceleration(a), Quantity::Pressure(b)) => Ok(Quantity::AreaDensity(a.clone() * b.clone())),
+			(Quantity::InverseAcceleration(a), Quantity::Velocity(b)) => Ok(Quantity::Time(a.clone() * b.clone())),
+			(Quantity::InverseAngularAcceleration(a), Quantity::AngularVelocity(b)) => Ok(Quantity::Time(a.clone() * b.clone())),
+			(Quantity::InverseAngularAcceleration(a), Quantity::Frequency(b)) => Ok(Quantity::InverseAngularVelocity(a.clone() * b.clone())),
+			(Quantity::InverseAngularMomentum(a), Quantity::MomentOfInertia(b)) => Ok(Quantity::InverseAngularVelocity(a.clone() * b.clone())),
+			(Quantity::InverseAngularVelocity(a), Quantity::Time(b)) => Ok(Quantity::InverseAngularAcceleration(a.clone() * b.clone())),
+			(Quantity::InverseAngularVelocity(a), Quantity::Angle(b)) => Ok(Quantity::Time(a.clone() * b.clone())),
+			(Quantity::InverseAngularVelocity(a), Quantity::AngularAcceleration(b)) => Ok(Quantity::Frequency(a.clone() * b.clone())),
+			(Quantity::InverseAngularVelocity(a), Quantity::Frequency(b)) => Ok(Quantity::InverseAngle(a.clone() * b.clone())),
+			(Quantity::InverseAngularVelocity(a), Quantity::InverseMomentOfInertia(b)) => Ok(Quantity::InverseAngularMomentum(a.clone() * b.clone())),
+			(Quantity::InverseDynamicViscosity(a), Quantity::InverseDistance(b)) => Ok(Quantity::InverseMassFlowRate(a.clone() * b.clone())),
+			(Quantity::InverseDynamicViscosity(a), Quantity::Time(b)) => Ok(Quantity::InversePressure(a.clone() * b.clone())),
+			(Quantity::InverseDynamicViscosity(a), Quantity::InverseSpecificHeatCapacity(b)) => Ok(Quantity::ThermalResistivity(a.clone() * b.clone())),
+			(Quantity::InverseDynamicViscosity(a), Quantity::ThermalConductivity(b)) => Ok(Quantity::SpecificHeatCapacity(a.clone() * b.clone())),
+			(Quantity::InverseDynamicViscosity(a), Quantity::Irradiance(b)) => Ok(Quantity::Acceleration(a.clone() * b.clone())),
+			(Quantity::InverseDynamicViscosity(a), Quantity::LinearChargeDensity(b)) => Ok(Quantity::InverseMagneticFluxDensity(a.clone() * b.clone())),
+			(Quantity::InverseDynamicViscosity(a), Quantity::MagneticFlux(b)) => Ok(Quantity::InverseVolumeChargeDensity(a.clone() * b.clone())),
+			(Quantity::InverseDynamicViscosity(a), Quantity::MagneticFluxDensity(b)) => Ok(Quantity::InverseLinearChargeDensity(a.clone() * b.clone())),
+			(Quantity::InverseDynamicViscosity(a), Quantity::VolumeChargeDensity(b)) => Ok(Quantity::InverseMagneticFlux(a.clone() * b.clone())),
+			(Quantity::InverseDynamicViscosity(a), Quantity::InverseArea(b)) => Ok(Quantity::InverseMomentum(a.clone() * b.clone())),
+			(Quantity::InverseDynamicViscosity(a), Quantity::AreaDensity(b)) => Ok(Quantity::TimePerDistance(a.clone() * b.clone())),
+			(Quantity::InverseDynamicViscosity(a), Quantity::Density(b)) => Ok(Quantity::InverseKinematicViscosity(a.clone() * b.clone())),
+			(Quantity::InverseDynamicViscosity(a), Quantity::Energy(b)) => Ok(Quantity::VolumetricFlowRate(a.clone() * b.clone())),
+			(Quantity::InverseDynamicViscosity(a), Quantity::Torque(b)) => Ok(Quantity::VolumetricFlowRate(a.clone() * b.clone())),
+			(Quantity::InverseDynamicViscosity(a), Quantity::Force(b)) => Ok(Quantity::KinematicViscosity(a.clone() * b.clone())),
+			(Quantity::InverseDynamicViscosity(a), Quantity::InverseAcceleration(b)) => Ok(Quantity::InverseIrradiance(a.clone() * b.clone())),
+			(Quantity::InverseDynamicViscosity(a), Quantity::InverseKinematicViscosity(b)) => Ok(Quantity::InverseForce(a.clone() * b.clone())),
+			(Quantity::InverseDynamicViscosity(a), Quantity::InverseVolumetricFlowRate(b)) => Ok(Quantity::InverseEnergy(a.clone() * b.clone())),
+			(Quantity::InverseDynamicViscosity(a), Quantity::KinematicViscosity(b)) => Ok(Quantity::VolumePerMass(a.clone() * b.clone())),
+			(Quantity::InverseDynamicViscosity(a), Quantity::MassFlowRate(b)) => Ok(Quantity::Distance(a.clone() * b.clone())),
+			(Quantity::InverseDynamicViscosity(a), Quantity::Momentum(b)) => Ok(Quantity::Area(a.clone() * b.clone())),
+			(Quantity::InverseDynamicViscosity(a), Quantity::Pressure(b)) => Ok(Quantity::Frequency(a.clone() * b.clone())),
+			(Quantity::InverseDynamicViscosity(a), Quantity::Velocity(b)) => Ok(Quantity::AreaPerMass(a.clone() * b.clone())),
+			(Quantity::InverseEnergy(a), Quantity::Amount(b)) => Ok(Quantity::InverseMolarEnergy(a.clone() * b.clone())),
+			(Quantity::InverseEnergy(a), Quantity::Current(b)) => Ok(Quantity::InverseMagneticFlux(a.clone() * b.clone())),
+			(Quantity::InverseEnergy(a), Quantity::Distance(b)) => Ok(Quantity::InverseForce(a.clone() * b.clone())),
+			(Quantity::InverseEnergy(a), Quantity::Temperature(b)) => Ok(Quantity::InverseHeatCapacity(a.clone() * b.clone())),
+			(Quantity::InverseEnergy(a), Quantity::TemperatureInterval(b)) => Ok(Quantity::InverseHeatCapacity(a.clone() * b.clone())),
+			(Quantity::InverseEnergy(a), Quantity::Time(b)) => Ok(Quantity::InversePower(a.clone() * b.clone())),
+			(Quantity::InverseEnergy(a), Quantity::Entropy(b)) => Ok(Quantity::InverseTemperature(a.clone() * b.clone())),
+			(Quantity::InverseEnergy(a), Quantity::HeatCapacity(b)) => Ok(Quantity::InverseTemperature(a.clone() * b.clone())),
+			(Quantity::InverseEnergy(a), Quantity::MolarEnergy(b)) => Ok(Quantity::InverseAmount(a.clone() * b.clone())),
+			(Quantity::InverseEnergy(a), Quantity::Charge(b)) => Ok(Quantity::InverseVoltage(a.clone() * b.clone())),
+			(Quantity::InverseEnergy(a), Quantity::MagneticFlux(b)) => Ok(Quantity::InverseCurrent(a.clone() * b.clone())),
+			(Quantity::InverseEnergy(a), Quantity::Voltage(b)) => Ok(Quantity::InverseCharge(a.clone() * b.clone())),
+			(Quantity::InverseEnergy(a), Quantity::Volume(b)) => Ok(Quantity::InversePressure(a.clone() * b.clone())),
+			(Quantity::InverseEnergy(a), Quantity::DynamicViscosity(b)) => Ok(Quantity::InverseVolumetricFlowRate(a.clone() * b.clone())),
+			(Quantity::InverseEnergy(a), Quantity::Force(b)) => Ok(Quantity::InverseDistance(a.clone() * b.clone())),
+			(Quantity::InverseEnergy(a), Quantity::KinematicViscosity(b)) => Ok(Quantity::InverseMassFlowRate(a.clone() * b.clone())),
+			(Quantity::InverseEnergy(a), Quantity::MassFlowRate(b)) => Ok(Quantity::InverseKinematicViscosity(a.clone() * b.clone())),
+			(Quantity::InverseEnergy(a), Quantity::Momentum(b)) => Ok(Quantity::TimePerDistance(a.clone() * b.clone())),
+			(Quantity::InverseEnergy(a), Quantity::Power(b)) => Ok(Quantity::Frequency(a.clone() * b.clone())),
+			(Quantity::InverseEnergy(a), Quantity::Pressure(b)) => Ok(Quantity::InverseVolume(a.clone() * b.clone())),
+			(Quantity::InverseEnergy(a), Quantity::Velocity(b)) => Ok(Quantity::InverseMomentum(a.clone() * b.clone())),
+			(Quantity::InverseEnergy(a), Quantity::VolumetricFlowRate(b)) => Ok(Quantity::InverseDynamicViscosity(a.clone() * b.clone())),
+			(Quantity::InverseForce(a), Quantity::InverseDistance(b)) => Ok(Quantity::InverseEnergy(a.clone() * b.clone())),
+			(Quantity::InverseForce(a), Quantity::Mass(b)) => Ok(Quantity::InverseAcceleration(a.clone() * b.clone())),
+			(Quantity::InverseForce(a), Quantity::Charge(b)) => Ok(Quantity::InverseElectricField(a.clone() * b.clone())),
+			(Quantity::InverseForce(a), Quantity::ElectricField(b)) => Ok(Quantity::InverseCharge(a.clone() * b.clone())),
+			(Quantity::InverseForce(a), Quantity::LinearChargeDensity(b)) => Ok(Quantity::InverseVoltage(a.clone() * b.clone())),
+			(Quantity::InverseForce(a), Quantity::MagneticFieldStrength(b)) => Ok(Quantity::InverseMagneticFlux(a.clone() * b.clone())),
+			(Quantity::InverseForce(a), Quantity::MagneticFlux(b)) => Ok(Quantity::InverseMagneticFieldStrength(a.clone() * b.clone())),
+			(Quantity::InverseForce(a), Quantity::Voltage(b)) => Ok(Quantity::InverseLinearChargeDensity(a.clone() * b.clone())),
+			(Quantity::InverseForce(a), Quantity::Area(b)) => Ok(Quantity::InversePressure(a.clone() * b.clone())),
+			(Quantity::InverseForce(a), Quantity::Acceleration(b)) => Ok(Quantity::InverseMass(a.clone() * b.clone())),
+			(Quantity::InverseForce(a), Quantity::DynamicViscosity(b)) => Ok(Quantity::InverseKinematicViscosity(a.clone() * b.clone())),
+			(Quantity::InverseForce(a), Quantity::Energy(b)) => Ok(Quantity::Distance(a.clone() * b.clone())),
+			(Quantity::InverseForce(a), Quantity::Torque(b)) => Ok(Quantity::Distance(a.clone() * b.clone())),
+			(Quantity::InverseForce(a), Quantity::Frequency(b)) => Ok(Quantity::InverseMomentum(a.clone() * b.clone())),
+			(Quantity::InverseForce(a), Quantity::KinematicViscosity(b)) => Ok(Quantity::InverseDynamicViscosity(a.clone() * b.clone())),
+			(Quantity::InverseForce(a), Quantity::MassFlowRate(b)) => Ok(Quantity::TimePerDistance(a.clone() * b.clone())),
+			(Quantity::InverseForce(a), Quantity::Momentum(b)) => Ok(Quantity::Time(a.clone() * b.clone())),
+			(Quantity::InverseForce(a), Quantity::Power(b)) => Ok(Quantity::Velocity(a.clone() * b.clone())),
+			(Quantity::InverseForce(a), Quantity::Pressure(b)) => Ok(Quantity::InverseArea(a.clone() * b.clone())),
+			(Quantity::InverseForce(a), Quantity::TimePerDistance(b)) => Ok(Quantity::InversePower(a.clone() * b.clone())),
+			(Quantity::InverseForce(a), Quantity::Velocity(b)) => Ok(Quantity::InverseMassFlowRate(a.clone() * b.clone())),
+			(Quantity::InverseJerk(a), Quantity::Acceleration(b)) => Ok(Quantity::Time(a.clone() * b.clone())),
+			(Quantity::InverseJerk(a), Quantity::Frequency(b)) => Ok(Quantity::InverseAcceleration(a.clone() * b.clone())),
+			(Quantity::InverseKinematicViscosity(a), Quantity::Current(b)) => Ok(Quantity::SurfaceChargeDensity(a.clone() * b.clone())),
+			(Quantity::InverseKinematicViscosity(a), Quantity::Distance(b)) => Ok(Quantity::TimePerDistance(a.clone() * b.clone())),
+			(Quantity::InverseKinematicViscosity(a), Quantity::InverseDistance(b)) => Ok(Quantity::InverseVolumetricFlowRate(a.clone() * b.clone())),
+			(Quantity::InverseKinematicViscosity(a), Quantity::InverseMagneticFluxDensity(b)) => Ok(Quantity::InverseVoltage(a.clone() * b.clone())),
+			(Quantity::InverseKinematicViscosity(a), Quantity::InverseSurfaceChargeDensity(b)) => Ok(Quantity::InverseCurrent(a.clone() * b.clone())),
+			(Quantity::InverseKinematicViscosity(a), Quantity::InverseVolumeChargeDensity(b)) => Ok(Quantity::InverseMagneticFieldStrength(a.clone() * b.clone())),
+			(Quantity::InverseKinematicViscosity(a), Quantity::MagneticFieldStrength(b)) => Ok(Quantity::VolumeChargeDensity(a.clone() * b.clone())),
+			(Quantity::InverseKinematicViscosity(a), Quantity::Voltage(b)) => Ok(Quantity::MagneticFluxDensity(a.clone() * b.clone())),
+			(Quantity::InverseKinematicViscosity(a), Quantity::Area(b)) => Ok(Quantity::Time(a.clone() * b.clone())),
+			(Quantity::InverseKinematicViscosity(a), Quantity::AreaPerMass(b)) => Ok(Quantity::InverseMassFlowRate(a.clone() * b.clone())),
+			(Quantity::InverseKinematicViscosity(a), Quantity::DynamicViscosity(b)) => Ok(Quantity::Density(a.clone() * b.clone())),
+			(Quantity::InverseKinematicViscosity(a), Quantity::Energy(b)) => Ok(Quantity::MassFlowRate(a.clone() * b.clone())),
+			(Quantity::InverseKinematicViscosity(a), Quantity::Torque(b)) => Ok(Quantity::MassFlowRate(a.clone() * b.clone())),
+			(Quantity::InverseKinematicViscosity(a), Quantity::Force(b)) => Ok(Quantity::DynamicViscosity(a.clone() * b.clone())),
+			(Quantity::InverseKinematicViscosity(a), Quantity::Frequency(b)) => Ok(Quantity::InverseArea(a.clone() * b.clone())),
+			(Quantity::InverseKinematicViscosity(a), Quantity::InverseDynamicViscosity(b)) => Ok(Quantity::InverseForce(a.clone() * b.clone())),
+			(Quantity::InverseKinematicViscosity(a), Quantity::InverseMassFlowRate(b)) => Ok(Quantity::InverseEnergy(a.clone() * b.clone())),
+			(Quantity::InverseKinematicViscosity(a), Quantity::MassFlowRate(b)) => Ok(Quantity::AreaDensity(a.clone() * b.clone())),
+			(Quantity::InverseKinematicViscosity(a), Quantity::Velocity(b)) => Ok(Quantity::InverseDistance(a.clone() * b.clone())),
+			(Quantity::InverseKinematicViscosity(a), Quantity::VolumePerMass(b)) => Ok(Quantity::InverseDynamicViscosity(a.clone() * b.clone())),
+			(Quantity::InverseKinematicViscosity(a), Quantity::VolumetricFlowRate(b)) => Ok(Quantity::Distance(a.clone() * b.clone())),
+			(Quantity::InverseMassFlowRate(a), Quantity::Distance(b)) => Ok(Quantity::InverseDynamicViscosity(a.clone() * b.clone())),
+			(Quantity::InverseMassFlowRate(a), Quantity::InverseDistance(b)) => Ok(Quantity::InverseMomentum(a.clone() * b.clone())),
+			(Quantity::InverseMassFlowRate(a), Quantity::Mass(b)) => Ok(Quantity::Time(a.clone() * b.clone())),
+			(Quantity::InverseMassFlowRate(a), Quantity::CatalyticActivity(b)) => Ok(Quantity::Molality(a.clone() * b.clone())),
+			(Quantity::InverseMassFlowRate(a), Quantity::InverseSpecificHeatCapacity(b)) => Ok(Quantity::ThermalResistance(a.clone() * b.clone())),
+			(Quantity::InverseMassFlowRate(a), Quantity::MolarMass(b)) => Ok(Quantity::InverseCatalyticActivity(a.clone() * b.clone())),
+			(Quantity::InverseMassFlowRate(a), Quantity::ThermalConductance(b)) => Ok(Quantity::SpecificHeatCapacity(a.clone() * b.clone())),
+			(Quantity::InverseMassFlowRate(a), Quantity::Charge(b)) => Ok(Quantity::InverseMagneticFluxDensity(a.clone() * b.clone())),
+			(Quantity::InverseMassFlowRate(a), Quantity::MagneticFlux(b)) => Ok(Quantity::InverseSurfaceChargeDensity(a.clone() * b.clone())),
+			(Quantity::InverseMassFlowRate(a), Quantity::MagneticFluxDensity(b)) => Ok(Quantity::InverseCharge(a.clone() * b.clone())),
+			(Quantity::InverseMassFlowRate(a), Quantity::SurfaceChargeDensity(b)) => Ok(Quantity::InverseMagneticFlux(a.clone() * b.clone())),
+			(Quantity::InverseMassFlowRate(a), Quantity::AreaDensity(b)) => Ok(Quantity::InverseKinematicViscosity(a.clone() * b.clone())),
+			(Quantity::InverseMassFlowRate(a), Quantity::Density(b)) => Ok(Quantity::InverseVolumetricFlowRate(a.clone() * b.clone())),
+			(Quantity::InverseMassFlowRate(a), Quantity::DynamicViscosity(b)) => Ok(Quantity::InverseDistance(a.clone() * b.clone())),
+			(Quantity::InverseMassFlowRate(a), Quantity::Energy(b)) => Ok(Quantity::KinematicViscosity(a.clone() * b.clone())),
+			(Quantity::InverseMassFlowRate(a), Quantity::Torque(b)) => Ok(Quantity::KinematicViscosity(a.clone() * b.clone())),
+			(Quantity::InverseMassFlowRate(a), Quantity::Force(b)) => Ok(Quantity::Velocity(a.clone() * b.clone())),
+			(Quantity::InverseMassFlowRate(a), Quantity::Frequency(b)) => Ok(Quantity::InverseMass(a.clone() * b.clone())),
+			(Quantity::InverseMassFlowRate(a), Quantity::InverseKinematicViscosity(b)) => Ok(Quantity::InverseEnergy(a.clone() * b.clone())),
+			(Quantity::InverseMassFlowRate(a), Quantity::KinematicViscosity(b)) => Ok(Quantity::AreaPerMass(a.clone() * b.clone())),
+			(Quantity::InverseMassFlowRate(a), Quantity::Momentum(b)) => Ok(Quantity::Distance(a.clone() * b.clone())),
+			(Quantity::InverseMassFlowRate(a), Quantity::TimePerDistance(b)) => Ok(Quantity::InverseForce(a.clone() * b.clone())),
+			(Quantity::InverseMassFlowRate(a), Quantity::VolumetricFlowRate(b)) => Ok(Quantity::VolumePerMass(a.clone() * b.clone())),
+			(Quantity::InverseMassFlowRate(a), Quantity::InverseAbsorbedDose(b)) => Ok(Quantity::InversePower(a.clone() * b.clone())),
+			(Quantity::InverseMassFlowRate(a), Quantity::InverseDoseEquivalent(b)) => Ok(Quantity::InversePower(a.clone() * b.clone())),
+			(Quantity::InverseMomentOfInertia(a), Quantity::Mass(b)) => Ok(Quantity::InverseArea(a.clone() * b.clone())),
+			(Quantity::InverseMomentOfInertia(a), Quantity::Area(b)) => Ok(Quantity::InverseMass(a.clone() * b.clone())),
+			(Quantity::InverseMomentOfInertia(a), Quantity::AngularMomentum(b)) => Ok(Quantity::AngularVelocity(a.clone() * b.clone())),
+			(Quantity::InverseMomentOfInertia(a), Quantity::InverseAngularVelocity(b)) => Ok(Quantity::InverseAngularMomentum(a.clone() * b.clone())),
+			(Quantity::InverseMomentum(a), Quantity::Distance(b)) => Ok(Quantity::InverseMassFlowRate(a.clone() * b.clone())),
+			(Quantity::InverseMomentum(a), Quantity::Mass(b)) => Ok(Quantity::TimePerDistance(a.clone() * b.clone())),
+			(Quantity::InverseMomentum(a), Quantity::Time(b)) => Ok(Quantity::InverseForce(a.clone() * b.clone())),
+			(Quantity::InverseMomentum(a), Quantity::LinearChargeDensity(b)) => Ok(Quantity::InverseMagneticFlux(a.clone() * b.clone())),
+			(Quantity::InverseMomentum(a), Quantity::MagneticFlux(b)) => Ok(Quantity::InverseLinearChargeDensity(a.clone() * b.clone())),
+			(Quantity::InverseMomentum(a), Quantity::Area(b)) => Ok(Quantity::InverseDynamicViscosity(a.clone() * b.clone())),
+			(Quantity::InverseMomentum(a), Quantity::AreaDensity(b)) => Ok(Quantity::InverseVolumetricFlowRate(a.clone() * b.clone())),
+			(Quantity::InverseMomentum(a), Quantity::DynamicViscosity(b)) => Ok(Quantity::InverseArea(a.clone() * b.clone())),
+			(Quantity::InverseMomentum(a), Quantity::Energy(b)) => Ok(Quantity::Velocity(a.clone() * b.clone())),
+			(Quantity::InverseMomentum(a), Quantity::Torque(b)) => Ok(Quantity::Velocity(a.clone() * b.clone())),
+			(Quantity::InverseMomentum(a), Quantity::Force(b)) => Ok(Quantity::Frequency(a.clone() * b.clone())),
+			(Quantity::InverseMomentum(a), Quantity::InverseAcceleration(b)) => Ok(Quantity::InversePower(a.clone() * b.clone())),
+			(Quantity::InverseMomentum(a), Quantity::MassFlowRate(b)) => Ok(Quantity::InverseDistance(a.clone() * b.clone())),
+			(Quantity::InverseMomentum(a), Quantity::Power(b)) => Ok(Quantity::Acceleration(a.clone() * b.clone())),
+			(Quantity::InverseMomentum(a), Quantity::TimePerDistance(b)) => Ok(Quantity::InverseEnergy(a.clone() * b.clone())),
+			(Quantity::InverseMomentum(a), Quantity::Velocity(b)) => Ok(Quantity::InverseMass(a.clone() * b.clone())),
+			(Quantity::InverseMomentum(a), Quantity::VolumetricFlowRate(b)) => Ok(Quantity::AreaPerMass(a.clone() * b.clone())),
+			(Quantity::InversePower(a), Quantity::Current(b)) => Ok(Quantity::InverseVoltage(a.clone() * b.clone())),
+			(Quantity::InversePower(a), Quantity::Temperature(b)) => Ok(Quantity::ThermalResistance(a.clone() * b.clone())),
+			(Quantity::InversePower(a), Quantity::TemperatureInterval(b)) => Ok(Quantity::ThermalResistance(a.clone() * b.clone())),
+			(Quantity::InversePower(a), Quantity::CatalyticActivity(b)) => Ok(Quantity::InverseMolarEnergy(a.clone() * b.clone())),
+			(Quantity::InversePower(a), Quantity::MolarEnergy(b)) => Ok(Quantity::InverseCatalyticActivity(a.clone() * b.clone())),
+			(Quantity::InversePower(a), Quantity::ThermalConductance(b)) => Ok(Quantity::InverseTemperature(a.clone() * b.clone())),
+			(Quantity::InversePower(a), Quantity::Irradiance(b)) => Ok(Quantity::InverseArea(a.clone() * b.clone())),
+			(Quantity::InversePower(a), Quantity::Voltage(b)) => Ok(Quantity::InverseCurrent(a.clone() * b.clone())),
+			(Quantity::InversePower(a), Quantity::Area(b)) => Ok(Quantity::InverseIrradiance(a.clone() * b.clone())),
+			(Quantity::InversePower(a), Quantity::Acceleration(b)) => Ok(Quantity::InverseMomentum(a.clone() * b.clone())),
+			(Quantity::InversePower(a), Quantity::Energy(b)) => Ok(Quantity::Time(a.clone() * b.clone())),
+			(Quantity::InversePower(a), Quantity::Torque(b)) => Ok(Quantity::Time(a.clone() * b.clone())),
+			(Quantity::InversePower(a), Quantity::Force(b)) => Ok(Quantity::TimePerDistance(a.clone() * b.clone())),
+			(Quantity::InversePower(a), Quantity::Frequency(b)) => Ok(Quantity::InverseEnergy(a.clone() * b.clone())),
+			(Quantity::InversePower(a), Quantity::Momentum(b)) => Ok(Quantity::InverseAcceleration(a.clone() * b.clone())),
+			(Quantity::InversePower(a), Quantity::Pressure(b)) => Ok(Quantity::InverseVolumetricFlowRate(a.clone() * b.clone())),
+			(Quantity::InversePower(a), Quantity::Velocity(b)) => Ok(Quantity::InverseForce(a.clone() * b.clone())),
+			(Quantity::InversePower(a), Quantity::VolumetricFlowRate(b)) => Ok(Quantity::InversePressure(a.clone() * b.clone())),
+			(Quantity::InversePressure(a), Quantity::Concentration(b)) => Ok(Quantity::InverseMolarEnergy(a.clone() * b.clone())),
+			(Quantity::InversePressure(a), Quantity::MolarEnergy(b)) => Ok(Quantity::MolarVolume(a.clone() * b.clone())),
+			(Quantity::InversePressure(a), Quantity::ElectricField(b)) => Ok(Quantity::InverseSurfaceChargeDensity(a.clone() * b.clone())),
+			(Quantity::InversePressure(a), Quantity::Irradiance(b)) => Ok(Quantity::Velocity(a.clone() * b.clone())),
+			(Quantity::InversePressure(a), Quantity::MagneticFieldStrength(b)) => Ok(Quantity::InverseMagneticFluxDensity(a.clone() * b.clone())),
+			(Quantity::InversePressure(a), Quantity::MagneticFluxDensity(b)) => Ok(Quantity::InverseMagneticFieldStrength(a.clone() * b.clone())),
+			(Quantity::InversePressure(a), Quantity::SurfaceChargeDensity(b)) => Ok(Quantity::InverseElectricField(a.clone() * b.clone())),
+			(Quantity::InversePressure(a), Quantity::Voltage(b)) => Ok(Quantity::InverseVolumeChargeDensity(a.clone() * b.clone())),
+			(Quantity::InversePressure(a), Quantity::VolumeChargeDensity(b)) => Ok(Quantity::InverseVoltage(a.clone() * b.clone())),
+			(Quantity::InversePressure(a), Quantity::InverseArea(b)) => Ok(Quantity::InverseForce(a.clone() * b.clone())),
+			(Quantity::InversePressure(a), Quantity::InverseVolume(b)) => Ok(Quantity::InverseEnergy(a.clone() * b.clone())),
+			(Quantity::InversePressure(a), Quantity::Acceleration(b)) => Ok(Quantity::AreaPerMass(a.clone() * b.clone())),
+			(Quantity::InversePressure(a), Quantity::AreaDensity(b)) => Ok(Quantity::InverseAcceleration(a.clone() * b.clone())),
+			(Quantity::InversePressure(a), Quantity::DynamicViscosity(b)) => Ok(Quantity::Time(a.clone() * b.clone())),
+			(Quantity::InversePressure(a), Quantity::Energy(b)) => Ok(Quantity::Volume(a.clone() * b.clone())),
+			(Quantity::InversePressure(a), Quantity::Torque(b)) => Ok(Quantity::Volume(a.clone() * b.clone())),
+			(Quantity::InversePressure(a), Quantity::Force(b)) => Ok(Quantity::Area(a.clone() * b.clone())),
+			(Quantity::InversePressure(a), Quantity::Frequency(b)) => Ok(Quantity::InverseDynamicViscosity(a.clone() * b.clone())),
+			(Quantity::InversePressure(a), Quantity::InverseVolumetricFlowRate(b)) => Ok(Quantity::InversePower(a.clone() * b.clone())),
+			(Quantity::InversePressure(a), Quantity::Power(b)) => Ok(Quantity::VolumetricFlowRate(a.clone() * b.clone())),
+			(Quantity::InversePressure(a), Quantity::TimePerDistance(b)) => Ok(Quantity::InverseIrradiance(a.clone() * b.clone())),
+			(Quantity::InverseTorque(a), Quantity::Amount(b)) => Ok(Quantity::InverseMolarEnergy(a.clone() * b.clone())),
+			(Quantity::InverseTorque(a), Quantity::Current(b)) => Ok(Quantity::InverseMagneticFlux(a.clone() * b.clone())),
+			(Quantity::InverseTorque(a), Quantity::Distance(b)) => Ok(Quantity::InverseForce(a.clone() * b.clone())),
+			(Quantity::InverseTorque(a), Quantity::Temperature(b)) => Ok(Quantity::InverseHeatCapacity(a.clone() * b.clone())),
+			(Quantity::InverseTorque(a), Quantity::TemperatureInterval(b)) => Ok(Quantity::InverseHeatCapacity(a.clone() * b.clone())),
+			(Quantity::InverseTorque(a), Quantity::Time(b)) => Ok(Quantity::InversePower(a.clone() * b.clone())),
+			(Quantity::InverseTorque(a), Quantity::Entropy(b)) => Ok(Quantity::InverseTemperature(a.clone() * b.clone())),
+			(Quantity::InverseTorque(a), Quantity::HeatCapacity(b)) => Ok(Quantity::InverseTemperature(a.clone() * b.clone())),
+			(Quantity::InverseTorque(a), Quantity::MolarEnergy(b)) => Ok(Quantity::InverseAmount(a.clone() * b.clone())),
+			(Quantity::InverseTorque(a), Quantity::Charge(b)) => Ok(Quantity::InverseVoltage(a.clone() * b.clone())),
+			(Quantity::InverseTorque(a), Quantity::MagneticFlux(b)) => Ok(Quantity::InverseCurrent(a.clone() * b.clone())),
+			(Quantity::InverseTorque(a), Quantity::Voltage(b)) => Ok(Quantity::InverseCharge(a.clone() * b.clone())),
+			(Quantity::InverseTorque(a), Quantity::Volume(b)) => Ok(Quantity::InversePressure(a.clone() * b.clone())),
+			(Quantity::InverseTorque(a), Quantity::DynamicViscosity(b)) => Ok(Quantity::InverseVolumetricFlowRate(a.clone() * b.clone())),
+			(Quantity::InverseTorque(a), Quantity::Force(b)) => Ok(Quantity::InverseDistance(a.clone() * b.clone())),
+			(Quantity::InverseTorque(a), Quantity::KinematicViscosity(b)) => Ok(Quantity::InverseMassFlowRate(a.clone() * b.clone())),
+			(Quantity::InverseTorque(a), Quantity::MassFlowRate(b)) => Ok(Quantity::InverseKinematicViscosity(a.clone() * b.clone())),
+			(Quantity::InverseTorque(a), Quantity::Momentum(b)) => Ok(Quantity::TimePerDistance(a.clone() * b.clone())),
+			(Quantity::InverseTorque(a), Quantity::Power(b)) => Ok(Quantity::Frequency(a.clone() * b.clone())),
+			(Quantity::InverseTorque(a), Quantity::Pressure(b)) => Ok(Quantity::InverseVolume(a.clone() * b.clone())),
+			(Quantity::InverseTorque(a), Quantity::Velocity(b)) => Ok(Quantity::InverseMomentum(a.clone() * b.clone())),
+			(Quantity::InverseTorque(a), Quantity::VolumetricFlowRate(b)) => Ok(Quantity::InverseDynamicViscosity(a.clone() * b.clone())),
+			(Quantity::InverseVolumetricFlowRate(a), Quantity::Current(b)) => Ok(Quantity::VolumeChargeDensity(a.clone() * b.clone())),
+			(Quantity::InverseVolumetricFlowRate(a), Quantity::Distance(b)) => Ok(Quantity::InverseKinematicViscosity(a.clone() * b.clone())),
+			(Quantity::InverseVolumetricFlowRate(a), Quantity::CatalyticActivity(b)) => Ok(Quantity::Concentration(a.clone() * b.clone())),
+			(Quantity::InverseVolumetricFlowRate(a), Quantity::MolarVolume(b)) => Ok(Quantity::InverseCatalyticActivity(a.clone() * b.clone())),
+			(Quantity::InverseVolumetricFlowRate(a), Quantity::InverseVolumeChargeDensity(b)) => Ok(Quantity::InverseCurrent(a.clone() * b.clone())),
+			(Quantity::InverseVolumetricFlowRate(a), Quantity::Area(b)) => Ok(Quantity::TimePerDistance(a.clone() * b.clone())),
+			(Quantity::InverseVolumetricFlowRate(a), Quantity::Volume(b)) => Ok(Quantity::Time(a.clone() * b.clone())),
+			(Quantity::InverseVolumetricFlowRate(a), Quantity::AreaPerMass(b)) => Ok(Quantity::InverseMomentum(a.clone() * b.clone())),
+			(Quantity::InverseVolumetricFlowRate(a), Quantity::Energy(b)) => Ok(Quantity::DynamicViscosity(a.clone() * b.clone())),
+			(Quantity::InverseVolumetricFlowRate(a), Quantity::Torque(b)) => Ok(Quantity::DynamicViscosity(a.clone() * b.clone())),
+			(Quantity::InverseVolumetricFlowRate(a), Quantity::Frequency(b)) => Ok(Quantity::InverseVolume(a.clone() * b.clone())),
+			(Quantity::InverseVolumetricFlowRate(a), Quantity::InverseDynamicViscosity(b)) => Ok(Quantity::InverseEnergy(a.clone() * b.clone())),
+			(Quantity::InverseVolumetricFlowRate(a), Quantity::InversePressure(b)) => Ok(Quantity::InversePower(a.clone() * b.clone())),
+			(Quantity::InverseVolumetricFlowRate(a), Quantity::KinematicViscosity(b)) => Ok(Quantity::InverseDistance(a.clone() * b.clone())),
+			(Quantity::InverseVolumetricFlowRate(a), Quantity::MassFlowRate(b)) => Ok(Quantity::Density(a.clone() * b.clone())),
+			(Quantity::InverseVolumetricFlowRate(a), Quantity::Momentum(b)) => Ok(Quantity::AreaDensity(a.clone() * b.clone())),
+			(Quantity::InverseVolumetricFlowRate(a), Quantity::Power(b)) => Ok(Quantity::Pressure(a.clone() * b.clone())),
+			(Quantity::InverseVolumetricFlowRate(a), Quantity::Velocity(b)) => Ok(Quantity::InverseArea(a.clone() * b.clone())),
+			(Quantity::InverseVolumetricFlowRate(a), Quantity::VolumePerMass(b)) => Ok(Quantity::InverseMassFlowRate(a.clone() * b.clone())),
+			(Quantity::Jerk(a), Quantity::Time(b)) => Ok(Quantity::Acceleration(a.clone() * b.clone())),
+			(Quantity::Jerk(a), Quantity::InverseAcceleration(b)) => Ok(Quantity::Frequency(a.clone() * b.clone())),
+			(Quantity::KinematicViscosity(a), Quantity::Distance(b)) => Ok(Quantity::VolumetricFlowRate(a.clone() * b.clone())),
+			(Quantity::KinematicViscosity(a), Quantity::InverseCurrent(b)) => Ok(Quantity::InverseSurfaceChargeDensity(a.clone() * b.clone())),
+			(Quantity::KinematicViscosity(a), Quantity::InverseDistance(b)) => Ok(Quantity::Velocity(a.clone() * b.clone())),
+			(Quantity::KinematicViscosity(a), Quantity::Time(b)) => Ok(Quantity::Area(a.clone() * b.clone())),
+			(Quantity::KinematicViscosity(a), Quantity::InverseMagneticFieldStrength(b)) => Ok(Quantity::InverseVolumeChargeDensity(a.clone() * b.clone())),
+			(Quantity::KinematicViscosity(a), Quantity::InverseVoltage(b)) => Ok(Quantity::InverseMagneticFluxDensity(a.clone() * b.clone())),
+			(Quantity::KinematicViscosity(a), Quantity::MagneticFluxDensity(b)) => Ok(Quantity::Voltage(a.clone() * b.clone())),
+			(Quantity::KinematicViscosity(a), Quantity::SurfaceChargeDensity(b)) => Ok(Quantity::Current(a.clone() * b.clone())),
+			(Quantity::KinematicViscosity(a), Quantity::VolumeChargeDensity(b)) => Ok(Quantity::MagneticFieldStrength(a.clone() * b.clone())),
+			(Quantity::KinematicViscosity(a), Quantity::InverseArea(b)) => Ok(Quantity::Frequency(a.clone() * b.clone())),
+			(Quantity::KinematicViscosity(a), Quantity::AreaDensity(b)) => Ok(Quantity::MassFlowRate(a.clone() * b.clone())),
+			(Quantity::KinematicViscosity(a), Quantity::Density(b)) => Ok(Quantity::DynamicViscosity(a.clone() * b.clone())),
+			(Quantity::KinematicViscosity(a), Quantity::DynamicViscosity(b)) => Ok(Quantity::Force(a.clone() * b.clone())),
+			(Quantity::KinematicViscosity(a), Quantity::InverseDynamicViscosity(b)) => Ok(Quantity::VolumePerMass(a.clone() * b.clone())),
+			(Quantity::KinematicViscosity(a), Quantity::InverseEnergy(b)) => Ok(Quantity::InverseMassFlowRate(a.clone() * b.clone())),
+			(Quantity::KinematicViscosity(a), Quantity::InverseTorque(b)) => Ok(Quantity::InverseMassFlowRate(a.clone() * b.clone())),
+			(Quantity::KinematicViscosity(a), Quantity::InverseForce(b)) => Ok(Quantity::InverseDynamicViscosity(a.clone() * b.clone())),
+			(Quantity::KinematicViscosity(a), Quantity::InverseMassFlowRate(b)) => Ok(Quantity::AreaPerMass(a.clone() * b.clone())),
+			(Quantity::KinematicViscosity(a), Quantity::InverseVolumetricFlowRate(b)) => Ok(Quantity::InverseDistance(a.clone() * b.clone())),
+			(Quantity::KinematicViscosity(a), Quantity::MassFlowRate(b)) => Ok(Quantity::Energy(a.clone() * b.clone())),
+			(Quantity::KinematicViscosity(a), Quantity::TimePerDistance(b)) => Ok(Quantity::Distance(a.clone() * b.clone())),
+			(Quantity::KinematicViscosity(a), Quantity::InverseAbsorbedDose(b)) => Ok(Quantity::Time(a.clone() * b.clone())),
+			(Quantity::KinematicViscosity(a), Quantity::InverseDoseEquivalent(b)) => Ok(Quantity::Time(a.clone() * b.clone())),
+			(Quantity::MassFlowRate(a), Quantity::Distance(b)) => Ok(Quantity::Momentum(a.clone() * b.clone())),
+			(Quantity::MassFlowRate(a), Quantity::InverseDistance(b)) => Ok(Quantity::DynamicViscosity(a.clone() * b.clone())),
+			(Quantity::MassFlowRate(a), Quantity::InverseMass(b)) => Ok(Quantity::Frequency(a.clone() * b.clone())),
+			(Quantity::MassFlowRate(a), Quantity::Time(b)) => Ok(Quantity::Mass(a.clone() * b.clone())),
+			(Quantity::MassFlowRate(a), Quantity::InverseCatalyticActivity(b)) => Ok(Quantity::MolarMass(a.clone() * b.clone())),
+			(Quantity::MassFlowRate(a), Quantity::Molality(b)) => Ok(Quantity::CatalyticActivity(a.clone() * b.clone())),
+			(Quantity::MassFlowRate(a), Quantity::SpecificHeatCapacity(b)) => Ok(Quantity::ThermalConductance(a.clone() * b.clone())),
+			(Quantity::MassFlowRate(a), Quantity::ThermalResistance(b)) => Ok(Quantity::InverseSpecificHeatCapacity(a.clone() * b.clone())),
+			(Quantity::MassFlowRate(a), Quantity::InverseCharge(b)) => Ok(Quantity::MagneticFluxDensity(a.clone() * b.clone())),
+			(Quantity::MassFlowRate(a), Quantity::InverseMagneticFlux(b)) => Ok(Quantity::SurfaceChargeDensity(a.clone() * b.clone())),
+			(Quantity::MassFlowRate(a), Quantity::InverseMagneticFluxDensity(b)) => Ok(Quantity::Charge(a.clone() * b.clone())),
+			(Quantity::MassFlowRate(a), Quantity::InverseSurfaceChargeDensity(b)) => Ok(Quantity::MagneticFlux(a.clone() * b.clone())),
+			(Quantity::MassFlowRate(a), Quantity::AreaPerMass(b)) => Ok(Quantity::KinematicViscosity(a.clone() * b.clone())),
+			(Quantity::MassFlowRate(a), Quantity::InverseDynamicViscosity(b)) => Ok(Quantity::Distance(a.clone() * b.clone())),
+			(Quantity::MassFlowRate(a), Quantity::InverseEnergy(b)) => Ok(Quantity::InverseKinematicViscosity(a.clone() * b.clone())),
+			(Quantity::MassFlowRate(a), Quantity::InverseTorque(b)) => Ok(Quantity::InverseKinematicViscosity(a.clone() * b.clone())),
+			(Quantity::MassFlowRate(a), Quantity::InverseForce(b)) => Ok(Quantity::TimePerDistance(a.clone() * b.clone())),
+			(Quantity::MassFlowRate(a), Quantity::InverseKinematicViscosity(b)) => Ok(Quantity::AreaDensity(a.clone() * b.clone())),
+			(Quantity::MassFlowRate(a), Quantity::InverseMomentum(b)) => Ok(Quantity::InverseDistance(a.clone() * b.clone())),
+			(Quantity::MassFlowRate(a), Quantity::InverseVolumetricFlowRate(b)) => Ok(Quantity::Density(a.clone() * b.clone())),
+			(Quantity::MassFlowRate(a), Quantity::KinematicViscosity(b)) => Ok(Quantity::Energy(a.clone() * b.clone())),
+			(Quantity::MassFlowRate(a), Quantity::Velocity(b)) => Ok(Quantity::Force(a.clone() * b.clone())),
+			(Quantity::MassFlowRate(a), Quantity::VolumePerMass(b)) => Ok(Quantity::VolumetricFlowRate(a.clone() * b.clone())),
+			(Quantity::MomentOfInertia(a), Quantity::InverseMass(b)) => Ok(Quantity::Area(a.clone() * b.clone())),
+			(Quantity::MomentOfInertia(a), Quantity::InverseArea(b)) => Ok(Quantity::Mass(a.clone() * b.clone())),
+			(Quantity::MomentOfInertia(a), Quantity::AngularVelocity(b)) => Ok(Quantity::AngularMomentum(a.clone() * b.clone())),
+			(Quantity::MomentOfInertia(a), Quantity::InverseAngularMomentum(b)) => Ok(Quantity::InverseAngularVelocity(a.clone() * b.clone())),
+			(Quantity::Momentum(a), Quantity::InverseDistance(b)) => Ok(Quantity::MassFlowRate(a.clone() * b.clone())),
+			(Quantity::Momentum(a), Quantity::InverseMass(b)) => Ok(Quantity::Velocity(a.clone() * b.clone())),
+			(Quantity::Momentum(a), Quantity::InverseLinearChargeDensity(b)) => Ok(Quantity::MagneticFlux(a.clone() * b.clone())),
+			(Quantity::Momentum(a), Quantity::InverseMagneticFlux(b)) => Ok(Quantity::LinearChargeDensity(a.clone() * b.clone())),
+			(Quantity::Momentum(a), Quantity::InverseArea(b)) => Ok(Quantity::DynamicViscosity(a.clone() * b.clone())),
+			(Quantity::Momentum(a), Quantity::Acceleration(b)) => Ok(Quantity::Power(a.clone() * b.clone())),
+			(Quantity::Momentum(a), Quantity::AreaPerMass(b)) => Ok(Quantity::VolumetricFlowRate(a.clone() * b.clone())),
+			(Quantity::Momentum(a), Quantity::Frequency(b)) => Ok(Quantity::Force(a.clone() * b.clone())),
+			(Quantity::Momentum(a), Quantity::InverseDynamicViscosity(b)) => Ok(Quantity::Area(a.clone() * b.clone())),
+			(Quantity::Momentum(a), Quantity::InverseEnergy(b)) => Ok(Quantity::TimePerDistance(a.clone() * b.clone())),
+			(Quantity::Momentum(a), Quantity::InverseTorque(b)) => Ok(Quantity::TimePerDistance(a.clone() * b.clone())),
+			(Quantity::Momentum(a), Quantity::InverseForce(b)) => Ok(Quantity::Time(a.clone() * b.clone())),
+			(Quantity::Momentum(a), Quantity::InverseMassFlowRate(b)) => Ok(Quantity::Distance(a.clone() * b.clone())),
+			(Quantity::Momentum(a), Quantity::InversePower(b)) => Ok(Quantity::InverseAcceleration(a.clone() * b.clone())),
+			(Quantity::Momentum(a), Quantity::InverseVolumetricFlowRate(b)) => Ok(Quantity::AreaDensity(a.clone() * b.clone())),
+			(Quantity::Momentum(a), Quantity::TimePerDistance(b)) => Ok(Quantity::Mass(a.clone() * b.clone())),
+			(Quantity::Momentum(a), Quantity::Velocity(b)) => Ok(Quantity::Energy(a.clone() * b.clone())),
+			(Quantity::Power(a), Quantity::InverseCurrent(b)) => Ok(Quantity::Voltage(a.clone() * b.clone())),
+			(Quantity::Power(a), Quantity::InverseTemperature(b)) => Ok(Quantity::ThermalConductance(a.clone() * b.clone())),
+			(Quantity::Power(a), Quantity::Time(b)) => Ok(Quantity::Energy(a.clone() * b.clone())),
+			(Quantity::Power(a), Quantity::InverseCatalyticActivity(b)) => Ok(Quantity::MolarEnergy(a.clone() * b.clone())),
+			(Quantity::Power(a), Quantity::InverseMolarEnergy(b)) => Ok(Quantity::CatalyticActivity(a.clone() * b.clone())),
+			(Quantity::Power(a), Quantity::ThermalResistance(b)) => Ok(Quantity::Temperature(a.clone() * b.clone())),
+			(Quantity::Power(a), Quantity::InverseIrradiance(b)) => Ok(Quantity::Area(a.clone() * b.clone())),
+			(Quantity::Power(a), Quantity::InverseVoltage(b)) => Ok(Quantity::Current(a.clone() * b.clone())),
+			(Quantity::Power(a), Quantity::InverseArea(b)) => Ok(Quantity::Irradiance(a.clone() * b.clone())),
+			(Quantity::Power(a), Quantity::InverseAcceleration(b)) => Ok(Quantity::Momentum(a.clone() * b.clone())),
+			(Quantity::Power(a), Quantity::InverseEnergy(b)) => Ok(Quantity::Frequency(a.clone() * b.clone())),
+			(Quantity::Power(a), Quantity::InverseTorque(b)) => Ok(Quantity::Frequency(a.clone() * b.clone())),
+			(Quantity::Power(a), Quantity::InverseForce(b)) => Ok(Quantity::Velocity(a.clone() * b.clone())),
+			(Quantity::Power(a), Quantity::InverseMomentum(b)) => Ok(Quantity::Acceleration(a.clone() * b.clone())),
+			(Quantity::Power(a), Quantity::InversePressure(b)) => Ok(Quantity::VolumetricFlowRate(a.clone() * b.clone())),
+			(Quantity::Power(a), Quantity::InverseVolumetricFlowRate(b)) => Ok(Quantity::Pressure(a.clone() * b.clone())),
+			(Quantity::Power(a), Quantity::TimePerDistance(b)) => Ok(Quantity::Force(a.clone() * b.clone())),
+			(Quantity::Power(a), Quantity::InverseAbsorbedDose(b)) => Ok(Quantity::MassFlowRate(a.clone() * b.clone())),
+			(Quantity::Power(a), Quantity::InverseDoseEquivalent(b)) => Ok(Quantity::MassFlowRate(a.clone() * b.clone())),
+			(Quantity::Pressure(a), Quantity::Time(b)) => Ok(Quantity::DynamicViscosity(a.clone() * b.clone())),
+			(Quantity::Pressure(a), Quantity::InverseMolarEnergy(b)) => Ok(Quantity::Concentration(a.clone() * b.clone())),
+			(Quantity::Pressure(a), Quantity::MolarVolume(b)) => Ok(Quantity::MolarEnergy(a.clone() * b.clone())),
+			(Quantity::Pressure(a), Quantity::InverseElectricField(b)) => Ok(Quantity::SurfaceChargeDensity(a.clone() * b.clone())),
+			(Quantity::Pressure(a), Quantity::InverseIrradiance(b)) => Ok(Quantity::TimePerDistance(a.clone() * b.clone())),
+			(Quantity::Pressure(a), Quantity::InverseMagneticFieldStrength(b)) => Ok(Quantity::MagneticFluxDensity(a.clone() * b.clone())),
+			(Quantity::Pressure(a), Quantity::InverseMagneticFluxDensity(b)) => Ok(Quantity::MagneticFieldStrength(a.clone() * b.clone())),
+			(Quantity::Pressure(a), Quantity::InverseSurfaceChargeDensity(b)) => Ok(Quantity::ElectricField(a.clone() * b.clone())),
+			(Quantity::Pressure(a), Quantity::InverseVoltage(b)) => Ok(Quantity::VolumeChargeDensity(a.clone() * b.clone())),
+			(Quantity::Pressure(a), Quantity::InverseVolumeChargeDensity(b)) => Ok(Quantity::Voltage(a.clone() * b.clone())),
+			(Quantity::Pressure(a), Quantity::Area(b)) => Ok(Quantity::Force(a.clone() * b.clone())),
+			(Quantity::Pressure(a), Quantity::Volume(b)) => Ok(Quantity::Energy(a.clone() * b.clone())),
+			(Quantity::Pressure(a), Quantity::AreaPerMass(b)) => Ok(Quantity::Acceleration(a.clone() * b.clone())),
+			(Quantity::Pressure(a), Quantity::InverseAcceleration(b)) => Ok(Quantity::AreaDensity(a.clone() * b.clone())),
+			(Quantity::Pressure(a), Quantity::InverseDynamicViscosity(b)) => Ok(Quantity::Frequency(a.clone() * b.clone())),
+			(Quantity::Pressure(a), Quantity::InverseEnergy(b)) => Ok(Quantity::InverseVolume(a.clone() * b.clone())),
+			(Quantity::Pressure(a), Quantity::InverseTorque(b)) => Ok(Quantity::InverseVolume(a.clone() * b.clone())),
+			(Quantity::Pressure(a), Quantity::InverseForce(b)) => Ok(Quantity::InverseArea(a.clone() * b.clone())),
+			(Quantity::Pressure(a), Quantity::InversePower(b)) => Ok(Quantity::InverseVolumetricFlowRate(a.clone() * b.clone())),
+			(Quantity::Pressure(a), Quantity::Velocity(b)) => Ok(Quantity::Irradiance(a.clone() * b.clone())),
+			(Quantity::Pressure(a), Quantity::VolumetricFlowRate(b)) => Ok(Quantity::Power(a.clone() * b.clone())),
+			(Quantity::Pressure(a), Quantity::InverseAbsorbedDose(b)) => Ok(Quantity::Density(a.clone() * b.clone())),
+			(Quantity::Pressure(a), Quantity::InverseDoseEquivalent(b)) => Ok(Quantity::Density(a.clone() * b.clone())),
+			(Quantity::TimePerDistance(a), Quantity::Current(b)) => Ok(Quantity::LinearChargeDensity(a.clone() * b.clone())),
+			(Quantity::TimePerDistance(a), Quantity::Distance(b)) => Ok(Quantity::Time(a.clone() * b.clone())),
+			(Quantity::TimePerDistance(a), Quantity::InverseDistance(b)) => Ok(Quantity::InverseKinematicViscosity(a.clone() * b.clone())),
+			(Quantity::TimePerDistance(a), Quantity::InverseMass(b)) => Ok(Quantity::InverseMomentum(a.clone() * b.clone())),
+			(Quantity::TimePerDistance(a), Quantity::Time(b)) => Ok(Quantity::InverseAcceleration(a.clone() * b.clone())),
+			(Quantity::TimePerDistance(a), Quantity::ElectricField(b)) => Ok(Quantity::MagneticFluxDensity(a.clone() * b.clone())),
+			(Quantity::TimePerDistance(a), Quantity::InverseLinearChargeDensity(b)) => Ok(Quantity::InverseCurrent(a.clone() * b.clone())),
+			(Quantity::TimePerDistance(a), Quantity::InverseMagneticFluxDensity(b)) => Ok(Quantity::InverseElectricField(a.clone() * b.clone())),
+			(Quantity::TimePerDistance(a), Quantity::InverseSurfaceChargeDensity(b)) => Ok(Quantity::InverseMagneticFieldStrength(a.clone() * b.clone())),
+			(Quantity::TimePerDistance(a), Quantity::Irradiance(b)) => Ok(Quantity::Pressure(a.clone() * b.clone())),
+			(Quantity::TimePerDistance(a), Quantity::MagneticFieldStrength(b)) => Ok(Quantity::SurfaceChargeDensity(a.clone() * b.clone())),
+			(Quantity::TimePerDistance(a), Quantity::InverseArea(b)) => Ok(Quantity::InverseVolumetricFlowRate(a.clone() * b.clone())),
+			(Quantity::TimePerDistance(a), Quantity::Acceleration(b)) => Ok(Quantity::Frequency(a.clone() * b.clone())),
+			(Quantity::TimePerDistance(a), Quantity::AreaPerMass(b)) => Ok(Quantity::InverseDynamicViscosity(a.clone() * b.clone())),
+			(Quantity::TimePerDistance(a), Quantity::DynamicViscosity(b)) => Ok(Quantity::AreaDensity(a.clone() * b.clone())),
+			(Quantity::TimePerDistance(a), Quantity::Energy(b)) => Ok(Quantity::Momentum(a.clone() * b.clone())),
+			(Quantity::TimePerDistance(a), Quantity::Torque(b)) => Ok(Quantity::Momentum(a.clone() * b.clone())),
+			(Quantity::TimePerDistance(a), Quantity::Force(b)) => Ok(Quantity::MassFlowRate(a.clone() * b.clone())),
+			(Quantity::TimePerDistance(a), Quantity::Frequency(b)) => Ok(Quantity::InverseDistance(a.clone() * b.clone())),
+			(Quantity::TimePerDistance(a), Quantity::InverseForce(b)) => Ok(Quantity::InversePower(a.clone() * b.clone())),
+			(Quantity::TimePerDistance(a), Quantity::InverseMassFlowRate(b)) => Ok(Quantity::InverseForce(a.clone() * b.clone())),
+			(Quantity::TimePerDistance(a), Quantity::InverseMomentum(b)) => Ok(Quantity::InverseEnergy(a.clone() * b.clone())),
+			(Quantity::TimePerDistance(a), Quantity::InversePressure(b)) => Ok(Quantity::InverseIrradiance(a.clone() * b.clone())),
+			(Quantity::TimePerDistance(a), Quantity::KinematicViscosity(b)) => Ok(Quantity::Distance(a.clone() * b.clone())),
+			(Quantity::TimePerDistance(a), Quantity::Momentum(b)) => Ok(Quantity::Mass(a.clone() * b.clone())),
+			(Quantity::TimePerDistance(a), Quantity::Power(b)) => Ok(Quantity::Force(a.clone() * b.clone())),
+			(Quantity::TimePerDistance(a), Quantity::VolumetricFlowRate(b)) => Ok(Quantity::Area(a.clone() * b.clone())),
+			(Quantity::Torque(a), Quantity::InverseAmount(b)) => Ok(Quantity::MolarEnergy(a.clone() * b.clone())),
+			(Quantity::Torque(a), Quantity::InverseCurrent(b)) => Ok(Quantity::MagneticFlux(a.clone() * b.clone())),
+			(Quantity::Torque(a), Quantity::InverseDistance(b)) => Ok(Quantity::Force(a.clone() * b.clone())),
+			(Quantity::Torque(a), Quantity::InverseTemperature(b)) => Ok(Quantity::HeatCapacity(a.clone() * b.clone())),
+			(Quantity::Torque(a), Quantity::InverseEntropy(b)) => Ok(Quantity::Temperature(a.clone() * b.clone())),
+			(Quantity::Torque(a), Quantity::InverseHeatCapacity(b)) => Ok(Quantity::Temperature(a.clone() * b.clone())),
+			(Quantity::Torque(a), Quantity::InverseMolarEnergy(b)) => Ok(Quantity::Amount(a.clone() * b.clone())),
+			(Quantity::Torque(a), Quantity::InverseCharge(b)) => Ok(Quantity::Voltage(a.clone() * b.clone())),
+			(Quantity::Torque(a), Quantity::InverseMagneticFlux(b)) => Ok(Quantity::Current(a.clone() * b.clone())),
+			(Quantity::Torque(a), Quantity::InverseVoltage(b)) => Ok(Quantity::Charge(a.clone() * b.clone())),
+			(Quantity::Torque(a), Quantity::InverseVolume(b)) => Ok(Quantity::Pressure(a.clone() * b.clone())),
+			(Quantity::Torque(a), Quantity::Frequency(b)) => Ok(Quantity::Power(a.clone() * b.clone())),
+			(Quantity::Torque(a), Quantity::InverseDynamicViscosity(b)) => Ok(Quantity::VolumetricFlowRate(a.clone() * b.clone())),
+			(Quantity::Torque(a), Quantity::InverseForce(b)) => Ok(Quantity::Distance(a.clone() * b.clone())),
+			(Quantity::Torque(a), Quantity::InverseKinematicViscosity(b)) => Ok(Quantity::MassFlowRate(a.clone() * b.clone())),
+			(Quantity::Torque(a), Quantity::InverseMassFlowRate(b)) => Ok(Quantity::KinematicViscosity(a.clone() * b.clone())),
+			(Quantity::Torque(a), Quantity::InverseMomentum(b)) => Ok(Quantity::Velocity(a.clone() * b.clone())),
+			(Quantity::Torque(a), Quantity::InversePower(b)) => Ok(Quantity::Time(a.clone() * b.clone())),
+			(Quantity::Torque(a), Quantity::InversePressure(b)) => Ok(Quantity::Volume(a.clone() * b.clone())),
+			(Quantity::Torque(a), Quantity::InverseVolumetricFlowRate(b)) => Ok(Quantity::DynamicViscosity(a.clone() * b.clone())),
+			(Quantity::Torque(a), Quantity::TimePerDistance(b)) => Ok(Quantity::Momentum(a.clone() * b.clone())),
+			(Quantity::Torque(a), Quantity::InverseAbsorbedDose(b)) => Ok(Quantity::Mass(a.clone() * b.clone())),
+			(Quantity::Torque(a), Quantity::InverseDoseEquivalent(b)) => Ok(Quantity::Mass(a.clone() * b.clone())),
+			(Quantity::Velocity(a), Quantity::Distance(b)) => Ok(Quantity::KinematicViscosity(a.clone() * b.clone())),
+			(Quantity::Velocity(a), Quantity::InverseCurrent(b)) => Ok(Quantity::InverseLinearChargeDensity(a.clone() * b.clone())),
+			(Quantity::Velocity(a), Quantity::InverseDistance(b)) => Ok(Quantity::Frequency(a.clone() * b.clone())),
+			(Quantity::Velocity(a), Quantity::Mass(b)) => Ok(Quantity::Momentum(a.clone() * b.clone())),
+			(Quantity::Velocity(a), Quantity::Time(b)) => Ok(Quantity::Distance(a.clone() * b.clone())),
+			(Quantity::Velocity(a), Quantity::InverseElectricField(b)) => Ok(Quantity::InverseMagneticFluxDensity(a.clone() * b.clone())),
+			(Quantity::Velocity(a), Quantity::InverseIrradiance(b)) => Ok(Quantity::InversePressure(a.clone() * b.clone())),
+			(Quantity::Velocity(a), Quantity::InverseMagneticFieldStrength(b)) => Ok(Quantity::InverseSurfaceChargeDensity(a.clone() * b.clone())),
+			(Quantity::Velocity(a), Quantity::LinearChargeDensity(b)) => Ok(Quantity::Current(a.clone() * b.clone())),
+			(Quantity::Velocity(a), Quantity::MagneticFluxDensity(b)) => Ok(Quantity::ElectricField(a.clone() * b.clone())),
+			(Quantity::Velocity(a), Quantity::SurfaceChargeDensity(b)) => Ok(Quantity::MagneticFieldStrength(a.clone() * b.clone())),
+			(Quantity::Velocity(a), Quantity::Area(b)) => Ok(Quantity::VolumetricFlowRate(a.clone() * b.clone())),
+			(Quantity::Velocity(a), Quantity::AreaDensity(b)) => Ok(Quantity::DynamicViscosity(a.clone() * b.clone())),
+			(Quantity::Velocity(a), Quantity::Force(b)) => Ok(Quantity::Power(a.clone() * b.clone())),
+			(Quantity::Velocity(a), Quantity::Frequency(b)) => Ok(Quantity::Acceleration(a.clone() * b.clone())),
+			(Quantity::Velocity(a), Quantity::InverseAcceleration(b)) => Ok(Quantity::Time(a.clone() * b.clone())),
+			(Quantity::Velocity(a), Quantity::InverseDynamicViscosity(b)) => Ok(Quantity::AreaPerMass(a.clone() * b.clone())),
+			(Quantity::Velocity(a), Quantity::InverseEnergy(b)) => Ok(Quantity::InverseMomentum(a.clone() * b.clone())),
+			(Quantity::Velocity(a), Quantity::InverseTorque(b)) => Ok(Quantity::InverseMomentum(a.clone() * b.clone())),
+			(Quantity::Velocity(a), Quantity::InverseForce(b)) => Ok(Quantity::InverseMassFlowRate(a.clone() * b.clone())),
+			(Quantity::Velocity(a), Quantity::InverseKinematicViscosity(b)) => Ok(Quantity::InverseDistance(a.clone() * b.clone())),
+			(Quantity::Velocity(a), Quantity::InverseMomentum(b)) => Ok(Quantity::InverseMass(a.clone() * b.clone())),
+			(Quantity::Velocity(a), Quantity::InversePower(b)) => Ok(Quantity::InverseForce(a.clone() * b.clone())),
+			(Quantity::Velocity(a), Quantity::InverseVolumetricFlowRate(b)) => Ok(Quantity::InverseArea(a.clone() * b.clone())),
+			(Quantity::Velocity(a), Quantity::MassFlowRate(b)) => Ok(Quantity::Force(a.clone() * b.clone())),
+			(Quantity::Velocity(a), Quantity::Momentum(b)) => Ok(Quantity::Energy(a.clone() * b.clone())),
+			(Quantity::Velocity(a), Quantity::Pressure(b)) => Ok(Quantity::Irradiance(a.clone() * b.clone())),
+			(Quantity::Velocity(a), Quantity::InverseAbsorbedDose(b)) => Ok(Quantity::TimePerDistance(a.clone() * b.clone())),
+			(Quantity::Velocity(a), Quantity::InverseDoseEquivalent(b)) => Ok(Quantity::TimePerDistance(a.clone() * b.clone())),
+			(Quantity::VolumePerMass(a), Quantity::InverseDistance(b)) => Ok(Quantity::AreaPerMass(a.clone() * b.clone())),
+			(Quantity::VolumePerMass(a), Quantity::Mass(b)) => Ok(Quantity::Volume(a.clone() * b.clone())),
+			(Quantity::VolumePerMass(a), Quantity::Concentration(b)) => Ok(Quantity::Molality(a.clone() * b.clone())),
+			(Quantity::VolumePerMass(a), Quantity::MolarMass(b)) => Ok(Quantity::MolarVolume(a.clone() * b.clone())),
+			(Quantity::VolumePerMass(a), Quantity::InverseVolume(b)) => Ok(Quantity::InverseMass(a.clone() * b.clone())),
+			(Quantity::VolumePerMass(a), Quantity::AreaDensity(b)) => Ok(Quantity::Distance(a.clone() * b.clone())),
+			(Quantity::VolumePerMass(a), Quantity::DynamicViscosity(b)) => Ok(Quantity::KinematicViscosity(a.clone() * b.clone())),
+			(Quantity::VolumePerMass(a), Quantity::InverseKinematicViscosity(b)) => Ok(Quantity::InverseDynamicViscosity(a.clone() * b.clone())),
+			(Quantity::VolumePerMass(a), Quantity::InverseVolumetricFlowRate(b)) => Ok(Quantity::InverseMassFlowRate(a.clone() * b.clone())),
+			(Quantity::VolumePerMass(a), Quantity::MassFlowRate(b)) => Ok(Quantity::VolumetricFlowRate(a.clone() * b.clone())),
+			(Quantity::VolumePerMass(a), Quantity::InverseAbsorbedDose(b)) => Ok(Quantity::InversePressure(a.clone() * b.clone())),
+			(Quantity::VolumePerMass(a), Quantity::InverseDoseEquivalent(b)) => Ok(Quantity::InversePressure(a.clone() * b.clone())),
+			(Quantity::VolumetricFlowRate(a), Quantity::InverseCurrent(b)) => Ok(Quantity::InverseVolumeChargeDensity(a.clone() * b.clone())),
+			(Quantity::VolumetricFlowRate(a), Quantity::InverseDistance(b)) => Ok(Quantity::KinematicViscosity(a.clone() * b.clone())),
+			(Quantity::VolumetricFlowRate(a), Quantity::Time(b)) => Ok(Quantity::Volume(a.clone() * b.clone())),
+			(Quantity::VolumetricFlowRate(a), Quantity::Concentration(b)) => Ok(Quantity::CatalyticActivity(a.clone() * b.clone())),
+			(Quantity::VolumetricFlowRate(a), Quantity::InverseCatalyticActivity(b)) => Ok(Quantity::MolarVolume(a.clone() * b.clone())),
+			(Quantity::VolumetricFlowRate(a), Quantity::VolumeChargeDensity(b)) => Ok(Quantity::Current(a.clone() * b.clone())),
+			(Quantity::VolumetricFlowRate(a), Quantity::InverseArea(b)) => Ok(Quantity::Velocity(a.clone() * b.clone())),
+			(Quantity::VolumetricFlowRate(a), Quantity::InverseVolume(b)) => Ok(Quantity::Frequency(a.clone() * b.clone())),
+			(Quantity::VolumetricFlowRate(a), Quantity::AreaDensity(b)) => Ok(Quantity::Momentum(a.clone() * b.clone())),
+			(Quantity::VolumetricFlowRate(a), Quantity::Density(b)) => Ok(Quantity::MassFlowRate(a.clone() * b.clone())),
+			(Quantity::VolumetricFlowRate(a), Quantity::DynamicViscosity(b)) => Ok(Quantity::Energy(a.clone() * b.clone())),
+			(Quantity::VolumetricFlowRate(a), Quantity::InverseEnergy(b)) => Ok(Quantity::InverseDynamicViscosity(a.clone() * b.clone())),
+			(Quantity::VolumetricFlowRate(a), Quantity::InverseTorque(b)) => Ok(Quantity::InverseDynamicViscosity(a.clone() * b.clone())),
+			(Quantity::VolumetricFlowRate(a), Quantity::InverseKinematicViscosity(b)) => Ok(Quantity::Distance(a.clone() * b.clone())),
+			(Quantity::VolumetricFlowRate(a), Quantity::InverseMassFlowRate(b)) => Ok(Quantity::VolumePerMass(a.clone() * b.clone())),
+			(Quantity::VolumetricFlowRate(a), Quantity::InverseMomentum(b)) => Ok(Quantity::AreaPerMass(a.clone() * b.clone())),
+			(Quantity::VolumetricFlowRate(a), Quantity::InversePower(b)) => Ok(Quantity::InversePressure(a.clone() * b.clone())),
+			(Quantity::VolumetricFlowRate(a), Quantity::Pressure(b)) => Ok(Quantity::Power(a.clone() * b.clone())),
+			(Quantity::VolumetricFlowRate(a), Quantity::TimePerDistance(b)) => Ok(Quantity::Area(a.clone() * b.clone())),
+			(Quantity::AbsorbedDose(a), Quantity::Mass(b)) => Ok(Quantity::Energy(a.clone() * b.clone())),
+			(Quantity::AbsorbedDoseRate(a), Quantity::Time(b)) => Ok(Quantity::AbsorbedDose(a.clone() * b.clone())),
+			(Quantity::DoseEquivalent(a), Quantity::Mass(b)) => Ok(Quantity::Energy(a.clone() * b.clone())),
+			(Quantity::DoseEquivalentRate(a), Quantity::Time(b)) => Ok(Quantity::DoseEquivalent(a.clone() * b.clone())),
+			(Quantity::InverseAbsorbedDose(a), Quantity::Distance(b)) => Ok(Quantity::InverseAcceleration(a.clone() * b.clone())),
+			(Quantity::InverseAbsorbedDose(a), Quantity::InverseMass(b)) => Ok(Quantity::InverseEnergy(a.clone() * b.clone())),
+			(Quantity::InverseAbsorbedDose(a), Quantity::Temperature(b)) => Ok(Quantity::InverseSpecificHeatCapacity(a.clone() * b.clone())),
+			(Quantity::InverseAbsorbedDose(a), Quantity::TemperatureInterval(b)) => Ok(Quantity::InverseSpecificHeatCapacity(a.clone() * b.clone())),
+			(Quantity::InverseAbsorbedDose(a), Quantity::Molality(b)) => Ok(Quantity::InverseMolarEnergy(a.clone() * b.clone())),
+			(Quantity::InverseAbsorbedDose(a), Quantity::MolarEnergy(b)) => Ok(Quantity::MolarMass(a.clone() * b.clone())),
+			(Quantity::InverseAbsorbedDose(a), Quantity::SpecificHeatCapacity(b)) => Ok(Quantity::InverseTemperature(a.clone() * b.clone())),
+			(Quantity::InverseAbsorbedDose(a), Quantity::Acceleration(b)) => Ok(Quantity::InverseDistance(a.clone() * b.clone())),
+			(Quantity::InverseAbsorbedDose(a), Quantity::Energy(b)) => Ok(Quantity::Mass(a.clone() * b.clone())),
+			(Quantity::InverseAbsorbedDose(a), Quantity::Torque(b)) => Ok(Quantity::Mass(a.clone() * b.clone())),
+			(Quantity::InverseAbsorbedDose(a), Quantity::Frequency(b)) => Ok(Quantity::InverseKinematicViscosity(a.clone() * b.clone())),
+			(Quantity::InverseAbsorbedDose(a), Quantity::InverseMassFlowRate(b)) => Ok(Quantity::InversePower(a.clone() * b.clone())),
+			(Quantity::InverseAbsorbedDose(a), Quantity::KinematicViscosity(b)) => Ok(Quantity::Time(a.clone() * b.clone())),
+			(Quantity::InverseAbsorbedDose(a), Quantity::Power(b)) => Ok(Quantity::MassFlowRate(a.clone() * b.clone())),
+			(Quantity::InverseAbsorbedDose(a), Quantity::Pressure(b)) => Ok(Quantity::Density(a.clone() * b.clone())),
+			(Quantity::InverseAbsorbedDose(a), Quantity::Velocity(b)) => Ok(Quantity::TimePerDistance(a.clone() * b.clone())),
+			(Quantity::InverseAbsorbedDose(a), Quantity::VolumePerMass(b)) => Ok(Quantity::InversePressure(a.clone() * b.clone())),
+			(Quantity::InverseDoseEquivalent(a), Quantity::Distance(b)) => Ok(Quantity::InverseAcceleration(a.clone() * b.clone())),
+			(Quantity::InverseDoseEquivalent(a), Quantity::InverseMass(b)) => Ok(Quantity::InverseEnergy(a.clone() * b.clone())),
+			(Quantity::InverseDoseEquivalent(a), Quantity::Temperature(b)) => Ok(Quantity::InverseSpecificHeatCapacity(a.clone() * b.clone())),
+			(Quantity::InverseDoseEquivalent(a), Quantity::TemperatureInterval(b)) => Ok(Quantity::InverseSpecificHeatCapacity(a.clone() * b.clone())),
+			(Quantity::InverseDoseEquivalent(a), Quantity::Molality(b)) => Ok(Quantity::InverseMolarEnergy(a.clone() * b.clone())),
+			(Quantity::InverseDoseEquivalent(a), Quantity::MolarEnergy(b)) => Ok(Quantity::MolarMass(a.clone() * b.clone())),
+			(Quantity::InverseDoseEquivalent(a), Quantity::SpecificHeatCapacity(b)) => Ok(Quantity::InverseTemperature(a.clone() * b.clone())),
+			(Quantity::InverseDoseEquivalent(a), Quantity::Acceleration(b)) => Ok(Quantity::InverseDistance(a.clone() * b.clone())),
+			(Quantity::InverseDoseEquivalent(a), Quantity::Energy(b)) => Ok(Quantity::Mass(a.clone() * b.clone())),
+			(Quantity::InverseDoseEquivalent(a), Quantity::Torque(b)) => Ok(Quantity::Mass(a.clone() * b.clone())),
+			(Quantity::InverseDoseEquivalent(a), Quantity::Frequency(b)) => Ok(Quantity::InverseKinematicViscosity(a.clone() * b.clone())),
+			(Quantity::InverseDoseEquivalent(a), Quantity::InverseMassFlowRate(b)) => Ok(Quantity::InversePower(a.clone() * b.clone())),
+			(Quantity::InverseDoseEquivalent(a), Quantity::KinematicViscosity(b)) => Ok(Quantity::Time(a.clone() * b.clone())),
+			(Quantity::InverseDoseEquivalent(a), Quantity::Power(b)) => Ok(Quantity::MassFlowRate(a.clone() * b.clone())),
+			(Quantity::InverseDoseEquivalent(a), Quantity::Pressure(b)) => Ok(Quantity::Density(a.clone() * b.clone())),
+			(Quantity::InverseDoseEquivalent(a), Quantity::Velocity(b)) => Ok(Quantity::TimePerDistance(a.clone() * b.clone())),
+			(Quantity::InverseDoseEquivalent(a), Quantity::VolumePerMass(b)) => Ok(Quantity::InversePressure(a.clone() * b.clone())),
+			_ => Err(self.dimension_error("multiply", other))
+		}
+	}
+
+	/// Divides this quantity by another, returning an error if there is no unit 
+	/// type for the result
+	///
+	/// # Arguments
+	/// * `other` - The quantity to divide this one by
+	pub fn try_div(&self, other: &Quantity<T>) -> Result<Quantity<T>, DimensionError> {
+		match (self, other) {
+			(q, Quantity::Scalar(s)) => Ok(q.clone() / s.clone()),
+			(Quantity::Amount(a), Quantity::Amount(b)) => Ok(Quantity::Scalar(a.clone() / b.clone())),
+			(Quantity::Current(a), Quantity::Current(b)) => Ok(Quantity::Scalar(a.clone() / b.clone())),
+			(Quantity::Distance(a), Quantity::Distance(b)) => Ok(Quantity::Scalar(a.clone() / b.clone())),
+			(Quantity::InverseAmount(a), Quantity::InverseAmount(b)) => Ok(Quantity::Scalar(a.clone() / b.clone())),
+			(Quantity::InverseCurrent(a), Quantity::InverseCurrent(b)) => Ok(Quantity::Scalar(a.clone() / b.clone())),
+			(Quantity::InverseDistance(a), Quantity::InverseDistance(b)) => Ok(Quantity::Scalar(a.clone() / b.clone())),
+			(Quantity::InverseLuminosity(a), Quantity::InverseLuminosity(b)) => Ok(Quantity::Scalar(a.clone() / b.clone())),
+			(Quantity::InverseMass(a), Quantity::InverseMass(b)) => Ok(Quantity::Scalar(a.clone() / b.clone())),
+			(Quantity::InverseTemperature(a), Quantity::InverseTemperature(b)) => Ok(Quantity::Scalar(a.clone() / b.clone())),
+			(Quantity::Luminosity(a), Quantity::Luminosity(b)) => Ok(Quantity::Scalar(a.clone() / b.clone())),
+			(Quantity::Mass(a), Quantity::Mass(b)) => Ok(Quantity::Scalar(a.clone() / b.clone())),
+			(Quantity::Temperature(a), Quantity::Temperature(b)) => Ok(Quantity::Scalar(a.clone() / b.clone())),
+			(Quantity::TemperatureInterval(a), Quantity::TemperatureInterval(b)) => Ok(Quantity::Scalar(a.clone() / b.clone())),
+			(Quantity::Time(a), Quantity::Time(b)) => Ok(Quantity::Scalar(a.clone() / b.clone())),
+			(Quantity::CatalyticActivity(a), Quantity::CatalyticActivity(b)) => Ok(Quantity::Scalar(a.clone() / b.clone())),
+			(Quantity::Concentration(a), Quantity::Concentration(b)) => Ok(Quantity::Scalar(a.clone() / b.clone())),
+			(Quantity::Entropy(a), Quantity::Entropy(b)) => Ok(Quantity::Scalar(a.clone() / b.clone())),
+			(Quantity::HeatCapacity(a), Quantity::HeatCapacity(b)) => Ok(Quantity::Scalar(a.clone() / b.clone())),
+			(Quantity::InverseCatalyticActivity(a), Quantity::InverseCatalyticActivity(b)) => Ok(Quantity::Scalar(a.clone() / b.clone())),
+			(Quantity::InverseEntropy(a), Quantity::InverseEntropy(b)) => Ok(Quantity::Scalar(a.clone() / b.clone())),
+			(Quantity::InverseHeatCapacity(a), Quantity::InverseHeatCapacity(b)) => Ok(Quantity::Scalar(a.clone() / b.clone())),
+			(Quantity::InverseMolarEnergy(a), Quantity::InverseMolarEnergy(b)) => Ok(Quantity::Scalar(a.clone() / b.clone())),
+			(Quantity::InverseMolarEntropy(a), Quantity::InverseMolarEntropy(b)) => Ok(Quantity::Scalar(a.clone() / b.clone())),
+			(Quantity::InverseSpecificHeatCapacity(a), Quantity::InverseSpecificHeatCapacity(b)) => Ok(Quantity::Scalar(a.clone() / b.clone())),
+			(Quantity::Molality(a), Quantity::Molality(b)) => Ok(Quantity::Scalar(a.clone() / b.clone())),
+			(Quantity::MolarEnergy(a), Quantity::MolarEnergy(b)) => Ok(Quantity::Scalar(a.clone() / b.clone())),
+			(Quantity::MolarEntropy(a), Quantity::MolarEntropy(b)) => Ok(Quantity::Scalar(a.clone() / b.clone())),
+			(Quantity::MolarMass(a), Quantity::MolarMass(b)) => Ok(Quantity::Scalar(a.clone() / b.clone())),
+			(Quantity::MolarVolume(a), Quantity::MolarVolume(b)) => Ok(Quantity::Scalar(a.clone() / b.clone())),
+			(Quantity::SpecificHeatCapacity(a), Quantity::SpecificHeatCapacity(b)) => Ok(Quantity::Scalar(a.clone() / b.clone())),
+			(Quantity::ThermalConductance(a), Quantity::ThermalConductance(b)) => Ok(Quantity::Scalar(a.clone() / b.clone())),
+			(Quantity::ThermalConductivity(a), Quantity::ThermalConductivity(b)) => Ok(Quantity::Scalar(a.clone() / b.clone())),
+			(Quantity::ThermalResistance(a), Quantity::ThermalResistance(b)) => Ok(Quantity::Scalar(a.clone() / b.clone())),
+			(Quantity::ThermalResistivity(a), Quantity::ThermalResistivity(b)) => Ok(Quantity::Scalar(a.clone() / b.clone())),
+			(Quantity::AreaPerLumen(a), Quantity::AreaPerLumen(b)) => Ok(Quantity::Scalar(a.clone() / b.clone())),
+			(Quantity::Capacitance(a), Quantity::Capacitance(b)) => Ok(Quantity::Scalar(a.clone() / b.clone())),
+			(Quantity::Charge(a), Quantity::Charge(b)) => Ok(Quantity::Scalar(a.clone() / b.clone())),
+			(Quantity::Conductance(a), Quantity::Conductance(b)) => Ok(Quantity::Scalar(a.clone() / b.clone())),
+			(Quantity::Elastance(a), Quantity::Elastance(b)) => Ok(Quantity::Scalar(a.clone() / b.clone())),
+			(Quantity::ElectricField(a), Quantity::ElectricField(b)) => Ok(Quantity::Scalar(a.clone() / b.clone())),
+			(Quantity::Illuminance(a), Quantity::Illuminance(b)) => Ok(Quantity::Scalar(a.clone() / b.clone())),
+			(Quantity::Inductance(a), Quantity::Inductance(b)) => Ok(Quantity::Scalar(a.clone() / b.clone())),
+			(Quantity::InverseCharge(a), Quantity::InverseCharge(b)) => Ok(Quantity::Scalar(a.clone() / b.clone())),
+			(Quantity::InverseElectricField(a), Quantity::InverseElectricField(b)) => Ok(Quantity::Scalar(a.clone() / b.clone())),
+			(Quantity::InverseInductance(a), Quantity::InverseInductance(b)) => Ok(Quantity::Scalar(a.clone() / b.clone())),
+			(Quantity::InverseIrradiance(a), Quantity::InverseIrradiance(b)) => Ok(Quantity::Scalar(a.clone() / b.clone())),
+			(Quantity::InverseLinearChargeDensity(a), Quantity::InverseLinearChargeDensity(b)) => Ok(Quantity::Scalar(a.clone() / b.clone())),
+			(Quantity::InverseLuminousFlux(a), Quantity::InverseLuminousFlux(b)) => Ok(Quantity::Scalar(a.clone() / b.clone())),
+			(Quantity::InverseMagneticFieldStrength(a), Quantity::InverseMagneticFieldStrength(b)) => Ok(Quantity::Scalar(a.clone() / b.clone())),
+			(Quantity::InverseMagneticFlux(a), Quantity::InverseMagneticFlux(b)) => Ok(Quantity::Scalar(a.clone() / b.clone())),
+			(Quantity::InverseMagneticFluxDensity(a), Quantity::InverseMagneticFluxDensity(b)) => Ok(Quantity::Scalar(a.clone() / b.clone())),
+			(Quantity::InverseRadiance(a), Quantity::InverseRadiance(b)) => Ok(Quantity::Scalar(a.clone() / b.clone())),
+			(Quantity::InverseSurfaceChargeDensity(a), Quantity::InverseSurfaceChargeDensity(b)) => Ok(Quantity::Scalar(a.clone() / b.clone())),
+			(Quantity::InverseVoltage(a), Quantity::InverseVoltage(b)) => Ok(Quantity::Scalar(a.clone() / b.clone())),
+			(Quantity::InverseVolumeChargeDensity(a), Quantity::InverseVolumeChargeDensity(b)) => Ok(Quantity::Scalar(a.clone() / b.clone())),
+			(Quantity::Irradiance(a), Quantity::Irradiance(b)) => Ok(Quantity::Scalar(a.clone() / b.clone())),
+			(Quantity::LinearChargeDensity(a), Quantity::LinearChargeDensity(b)) => Ok(Quantity::Scalar(a.clone() / b.clone())),
+			(Quantity::LuminousFlux(a), Quantity::LuminousFlux(b)) => Ok(Quantity::Scalar(a.clone() / b.clone())),
+			(Quantity::MagneticFieldStrength(a), Quantity::MagneticFieldStrength(b)) => Ok(Quantity::Scalar(a.clone() / b.clone())),
+			(Quantity::MagneticFlux(a), Quantity::MagneticFlux(b)) => Ok(Quantity::Scalar(a.clone() / b.clone())),
+			(Quantity::MagneticFluxDensity(a), Quantity::MagneticFluxDensity(b)) => Ok(Quantity::Scalar(a.clone() / b.clone())),
+			(Quantity::Radiance(a), Quantity::Radiance(b)) => Ok(Quantity::Scalar(a.clone() / b.clone())),
+			(Quantity::Resistance(a), Quantity::Resistance(b)) => Ok(Quantity::Scalar(a.clone() / b.clone())),
+			(Quantity::SurfaceChargeDensity(a), Quantity::SurfaceChargeDensity(b)) => Ok(Quantity::Scalar(a.clone() / b.clone())),
+			(Quantity::Voltage(a), Quantity::Voltage(b)) => Ok(Quantity::Scalar(a.clone() / b.clone())),
+			(Quantity::VolumeChargeDensity(a), Quantity::VolumeChargeDensity(b)) => Ok(Quantity::Scalar(a.clone() / b.clone())),
+			(Quantity::Angle(a), Quantity::Angle(b)) => Ok(Quantity::Scalar(a.clone() / b.clone())),
+			(Quantity::Area(a), Quantity::Area(b)) => Ok(Quantity::Scalar(a.clone() / b.clone())),
+			(Quantity::InverseAngle(a), Quantity::InverseAngle(b)) => Ok(Quantity::Scalar(a.clone() / b.clone())),
+			(Quantity::InverseArea(a), Quantity::InverseArea(b)) => Ok(Quantity::Scalar(a.clone() / b.clone())),
+			(Quantity::InverseSolidAngle(a), Quantity::InverseSolidAngle(b)) => Ok(Quantity::Scalar(a.clone() / b.clone())),
+			(Quantity::InverseVolume(a), Quantity::InverseVolume(b)) => Ok(Quantity::Scalar(a.clone() / b.clone())),
+			(Quantity::SolidAngle(a), Quantity::SolidAngle(b)) => Ok(Quantity::Scalar(a.clone() / b.clone())),
+			(Quantity::Volume(a), Quantity::Volume(b)) => Ok(Quantity::Scalar(a.clone() / b.clone())),
+			(Quantity::Acceleration(a), Quantity::Acceleration(b)) => Ok(Quantity::Scalar(a.clone() / b.clone())),
+			(Quantity::AngularAcceleration(a), Quantity::AngularAcceleration(b)) => Ok(Quantity::Scalar(a.clone() / b.clone())),
+			(Quantity::AngularMomentum(a), Quantity::AngularMomentum(b)) => Ok(Quantity::Scalar(a.clone() / b.clone())),
+			(Quantity::AngularVelocity(a), Quantity::AngularVelocity(b)) => Ok(Quantity::Scalar(a.clone() / b.clone())),
+			(Quantity::AreaDensity(a), Quantity::AreaDensity(b)) => Ok(Quantity::Scalar(a.clone() / b.clone())),
+			(Quantity::AreaPerMass(a), Quantity::AreaPerMass(b)) => Ok(Quantity::Scalar(a.clone() / b.clone())),
+			(Quantity::Density(a), Quantity::Density(b)) => Ok(Quantity::Scalar(a.clone() / b.clone())),
+			(Quantity::DynamicViscosity(a), Quantity::DynamicViscosity(b)) => Ok(Quantity::Scalar(a.clone() / b.clone())),
+			(Quantity::Energy(a), Quantity::Energy(b)) => Ok(Quantity::Scalar(a.clone() / b.clone())),
+			(Quantity::Force(a), Quantity::Force(b)) => Ok(Quantity::Scalar(a.clone() / b.clone())),
+			(Quantity::Frequency(a), Quantity::Frequency(b)) => Ok(Quantity::Scalar(a.clone() / b.clone())),
+			(Quantity::InverseAcceleration(a), Quantity::InverseAcceleration(b)) => Ok(Quantity::Scalar(a.clone() / b.clone())),
+			(Quantity::InverseAngularAcceleration(a), Quantity::InverseAngularAcceleration(b)) => Ok(Quantity::Scalar(a.clone() / b.clone())),
+			(Quantity::InverseAngularMomentum(a), Quantity::InverseAngularMomentum(b)) => Ok(Quantity::Scalar(a.clone() / b.clone())),
+			(Quantity::InverseAngularVelocity(a), Quantity::InverseAngularVelocity(b)) => Ok(Quantity::Scalar(a.clone() / b.clone())),
+			(Quantity::InverseDynamicViscosity(a), Quantity::InverseDynamicViscosity(b)) => Ok(Quantity::Scalar(a.clone() / b.clone())),
+			(Quantity::InverseEnergy(a), Quantity::InverseEnergy(b)) => Ok(Quantity::Scalar(a.clone() / b.clone())),
+			(Quantity::InverseForce(a), Quantity::InverseForce(b)) => Ok(Quantity::Scalar(a.clone() / b.clone())),
+			(Quantity::InverseJerk(a), Quantity::InverseJerk(b)) => Ok(Quantity::Scalar(a.clone() / b.clone())),
+			(Quantity::InverseKinematicViscosity(a), Quantity::InverseKinematicViscosity(b)) => Ok(Quantity::Scalar(a.clone() / b.clone())),
+			(Quantity::InverseMassFlowRate(a), Quantity::InverseMassFlowRate(b)) => Ok(Quantity::Scalar(a.clone() / b.clone())),
+			(Quantity::InverseMomentOfInertia(a), Quantity::InverseMomentOfInertia(b)) => Ok(Quantity::Scalar(a.clone() / b.clone())),
+			(Quantity::InverseMomentum(a), Quantity::InverseMomentum(b)) => Ok(Quantity::Scalar(a.clone() / b.clone())),
+			(Quantity::InversePower(a), Quantity::InversePower(b)) => Ok(Quantity::Scalar(a.clone() / b.clone())),
+			(Quantity::InversePressure(a), Quantity::InversePressure(b)) => Ok(Quantity::Scalar(a.clone() / b.clone())),
+			(Quantity::InverseTorque(a), Quantity::InverseTorque(b)) => Ok(Quantity::Scalar(a.clone() / b.clone())),
+			(Quantity::InverseVolumetricFlowRate(a), Quantity::InverseVolumetricFlowRate(b)) => Ok(Quantity::Scalar(a.clone() / b.clone())),
+			(Quantity::Jerk(a), Quantity::Jerk(b)) => Ok(Quantity::Scalar(a.clone() / b.clone())),
+			(Quantity::KinematicViscosity(a), Quantity::KinematicViscosity(b)) => Ok(Quantity::Scalar(a.clone() / b.clone())),
+			(Quantity::MassFlowRate(a), Quantity::MassFlowRate(b)) => Ok(Quantity::Scalar(a.clone() / b.clone())),
+			(Quantity::MomentOfInertia(a), Quantity::MomentOfInertia(b)) => Ok(Quantity::Scalar(a.clone() / b.clone())),
+			(Quantity::Momentum(a), Quantity::Momentum(b)) => Ok(Quantity::Scalar(a.clone() / b.clone())),
+			(Quantity::Power(a), Quantity::Power(b)) => Ok(Quantity::Scalar(a.clone() / b.clone())),
+			(Quantity::Pressure(a), Quantity::Pressure(b)) => Ok(Quantity::Scalar(a.clone() / b.clone())),
+			(Quantity::TimePerDistance(a), Quantity::TimePerDistance(b)) => Ok(Quantity::Scalar(a.clone() / b.clone())),
+			(Quantity::Torque(a), Quantity::Torque(b)) => Ok(Quantity::Scalar(a.clone() / b.clone())),
+			(Quantity::Velocity(a), Quantity::Velocity(b)) => Ok(Quantity::Scalar(a.clone() / b.clone())),
+			(Quantity::VolumePerMass(a), Quantity::VolumePerMass(b)) => Ok(Quantity::Scalar(a.clone() / b.clone())),
+			(Quantity::VolumetricFlowRate(a), Quantity::VolumetricFlowRate(b)) => Ok(Quantity::Scalar(a.clone() / b.clone())),
+			(Quantity::AbsorbedDose(a), Quantity::AbsorbedDose(b)) => Ok(Quantity::Scalar(a.clone() / b.clone())),
+			(Quantity::AbsorbedDoseRate(a), Quantity::AbsorbedDoseRate(b)) => Ok(Quantity::Scalar(a.clone() / b.clone())),
+			(Quantity::DoseEquivalent(a), Quantity::DoseEquivalent(b)) => Ok(Quantity::Scalar(a.clone() / b.clone())),
+			(Quantity::DoseEquivalentRate(a), Quantity::DoseEquivalentRate(b)) => Ok(Quantity::Scalar(a.clone() / b.clone())),
+			(Quantity::InverseAbsorbedDose(a), Quantity::InverseAbsorbedDose(b)) => Ok(Quantity::Scalar(a.clone() / b.clone())),
+			(Quantity::InverseAbsorbedDoseRate(a), Quantity::InverseAbsorbedDoseRate(b)) => Ok(Quantity::Scalar(a.clone() / b.clone())),
+			(Quantity::InverseDoseEquivalent(a), Quantity::InverseDoseEquivalent(b)) => Ok(Quantity::Scalar(a.clone() / b.clone())),
+			(Quantity::InverseDoseEquivalentRate(a), Quantity::InverseDoseEquivalentRate(b)) => Ok(Quantity::Scalar(a.clone() / b.clone())),
+			(Quantity::Radioactivity(a), Quantity::Radioactivity(b)) => Ok(Quantity::Scalar(a.clone() / b.clone())),
+			(Quantity::Amount(a), Quantity::Mass(b)) => Ok(Quantity::Molality(a.clone() / b.clone())),
+			(Quantity::Amount(a), Quantity::Time(b)) => Ok(Quantity::CatalyticActivity(a.clone() / b.clone())),
+			(Quantity::Amount(a), Quantity::CatalyticActivity(b)) => Ok(Quantity::Time(a.clone() / b.clone())),
+			(Quantity::Amount(a), Quantity::Concentration(b)) => Ok(Quantity::Volume(a.clone() / b.clone())),
+			(Quantity::Amount(a), Quantity::Entropy(b)) => Ok(Quantity::InverseMolarEntropy(a.clone() / b.clone())),
+			(Quantity::Amount(a), Quantity::HeatCapacity(b)) => Ok(Quantity::InverseMolarEntropy(a.clone() / b.clone())),
+			(Quantity::Amount(a), Quantity::InverseMolarEnergy(b)) => Ok(Quantity::Energy(a.clone() / b.clone())),
+			(Quantity::Amount(a), Quantity::InverseMolarEntropy(b)) => Ok(Quantity::HeatCapacity(a.clone() / b.clone())),
+			(Quantity::Amount(a), Quantity::Molality(b)) => Ok(Quantity::Mass(a.clone() / b.clone())),
+			(Quantity::Amount(a), Quantity::Volume(b)) => Ok(Quantity::Concentration(a.clone() / b.clone())),
+			(Quantity::Amount(a), Quantity::Energy(b)) => Ok(Quantity::InverseMolarEnergy(a.clone() / b.clone())),
+			(Quantity::Amount(a), Quantity::Torque(b)) => Ok(Quantity::InverseMolarEnergy(a.clone() / b.clone())),
+			(Quantity::Current(a), Quantity::Distance(b)) => Ok(Quantity::MagneticFieldStrength(a.clone() / b.clone())),
+			(Quantity::Current(a), Quantity::Charge(b)) => Ok(Quantity::Frequency(a.clone() / b.clone())),
+			(Quantity::Current(a), Quantity::Conductance(b)) => Ok(Quantity::Voltage(a.clone() / b.clone())),
+			(Quantity::Current(a), Quantity::InverseInductance(b)) => Ok(Quantity::MagneticFlux(a.clone() / b.clone())),
+			(Quantity::Current(a), Quantity::InverseMagneticFlux(b)) => Ok(Quantity::Energy(a.clone() / b.clone())),
+			(Quantity::Current(a), Quantity::InverseVoltage(b)) => Ok(Quantity::Power(a.clone() / b.clone())),
+			(Quantity::Current(a), Quantity::LinearChargeDensity(b)) => Ok(Quantity::Velocity(a.clone() / b.clone())),
+			(Quantity::Current(a), Quantity::MagneticFieldStrength(b)) => Ok(Quantity::Distance(a.clone() / b.clone())),
+			(Quantity::Current(a), Quantity::MagneticFlux(b)) => Ok(Quantity::InverseInductance(a.clone() / b.clone())),
+			(Quantity::Current(a), Quantity::SurfaceChargeDensity(b)) => Ok(Quantity::KinematicViscosity(a.clone() / b.clone())),
+			(Quantity::Current(a), Quantity::Voltage(b)) => Ok(Quantity::Conductance(a.clone() / b.clone())),
+			(Quantity::Current(a), Quantity::VolumeChargeDensity(b)) => Ok(Quantity::VolumetricFlowRate(a.clone() / b.clone())),
+			(Quantity::Current(a), Quantity::Energy(b)) => Ok(Quantity::InverseMagneticFlux(a.clone() / b.clone())),
+			(Quantity::Current(a), Quantity::Torque(b)) => Ok(Quantity::InverseMagneticFlux(a.clone() / b.clone())),
+			(Quantity::Current(a), Quantity::Frequency(b)) => Ok(Quantity::Charge(a.clone() / b.clone())),
+			(Quantity::Current(a), Quantity::KinematicViscosity(b)) => Ok(Quantity::SurfaceChargeDensity(a.clone() / b.clone())),
+			(Quantity::Current(a), Quantity::Power(b)) => Ok(Quantity::InverseVoltage(a.clone() / b.clone())),
+			(Quantity::Current(a), Quantity::Velocity(b)) => Ok(Quantity::LinearChargeDensity(a.clone() / b.clone())),
+			(Quantity::Current(a), Quantity::VolumetricFlowRate(b)) => Ok(Quantity::VolumeChargeDensity(a.clone() / b.clone())),
+			(Quantity::Distance(a), Quantity::Current(b)) => Ok(Quantity::InverseMagneticFieldStrength(a.clone() / b.clone())),
+			(Quantity::Distance(a), Quantity::InverseDistance(b)) => Ok(Quantity::Area(a.clone() / b.clone())),
+			(Quantity::Distance(a), Quantity::Time(b)) => Ok(Quantity::Velocity(a.clone() / b.clone())),
+			(Quantity::Distance(a), Quantity::ThermalConductance(b)) => Ok(Quantity::ThermalResistivity(a.clone() / b.clone())),
+			(Quantity::Distance(a), Quantity::ThermalResistivity(b)) => Ok(Quantity::ThermalConductance(a.clone() / b.clone())),
+			(Quantity::Distance(a), Quantity::Charge(b)) => Ok(Quantity::InverseLinearChargeDensity(a.clone() / b.clone())),
+			(Quantity::Distance(a), Quantity::InverseElectricField(b)) => Ok(Quantity::Voltage(a.clone() / b.clone())),
+			(Quantity::Distance(a), Quantity::InverseLinearChargeDensity(b)) => Ok(Quantity::Charge(a.clone() / b.clone())),
+			(Quantity::Distance(a), Quantity::InverseMagneticFieldStrength(b)) => Ok(Quantity::Current(a.clone() / b.clone())),
+			(Quantity::Distance(a), Quantity::InverseSurfaceChargeDensity(b)) => Ok(Quantity::LinearChargeDensity(a.clone() / b.clone())),
+			(Quantity::Distance(a), Quantity::InverseVolumeChargeDensity(b)) => Ok(Quantity::SurfaceChargeDensity(a.clone() / b.clone())),
+			(Quantity::Distance(a), Quantity::LinearChargeDensity(b)) => Ok(Quantity::InverseSurfaceChargeDensity(a.clone() / b.clone())),
+			(Quantity::Distance(a), Quantity::SurfaceChargeDensity(b)) => Ok(Quantity::InverseVolumeChargeDensity(a.clone() / b.clone())),
+			(Quantity::Distance(a), Quantity::Voltage(b)) => Ok(Quantity::InverseElectricField(a.clone() / b.clone())),
+			(Quantity::Distance(a), Quantity::Area(b)) => Ok(Quantity::InverseDistance(a.clone() / b.clone())),
+			(Quantity::Distance(a), Quantity::InverseArea(b)) => Ok(Quantity::Volume(a.clone() / b.clone())),
+			(Quantity::Distance(a), Quantity::Volume(b)) => Ok(Quantity::InverseArea(a.clone() / b.clone())),
+			(Quantity::Distance(a), Quantity::AreaDensity(b)) => Ok(Quantity::VolumePerMass(a.clone() / b.clone())),
+			(Quantity::Distance(a), Quantity::Energy(b)) => Ok(Quantity::InverseForce(a.clone() / b.clone())),
+			(Quantity::Distance(a), Quantity::Torque(b)) => Ok(Quantity::InverseForce(a.clone() / b.clone())),
+			(Quantity::Distance(a), Quantity::InverseDynamicViscosity(b)) => Ok(Quantity::MassFlowRate(a.clone() / b.clone())),
+			(Quantity::Distance(a), Quantity::InverseForce(b)) => Ok(Quantity::Energy(a.clone() / b.clone())),
+			(Quantity::Distance(a), Quantity::InverseKinematicViscosity(b)) => Ok(Quantity::VolumetricFlowRate(a.clone() / b.clone())),
+			(Quantity::Distance(a), Quantity::InverseMassFlowRate(b)) => Ok(Quantity::Momentum(a.clone() / b.clone())),
+			(Quantity::Distance(a), Quantity::KinematicViscosity(b)) => Ok(Quantity::TimePerDistance(a.clone() / b.clone())),
+			(Quantity::Distance(a), Quantity::MassFlowRate(b)) => Ok(Quantity::InverseDynamicViscosity(a.clone() / b.clone())),
+			(Quantity::Distance(a), Quantity::Momentum(b)) => Ok(Quantity::InverseMassFlowRate(a.clone() / b.clone())),
+			(Quantity::Distance(a), Quantity::TimePerDistance(b)) => Ok(Quantity::KinematicViscosity(a.clone() / b.clone())),
+			(Quantity::Distance(a), Quantity::Velocity(b)) => Ok(Quantity::Time(a.clone() / b.clone())),
+			(Quantity::Distance(a), Quantity::VolumePerMass(b)) => Ok(Quantity::AreaDensity(a.clone() / b.clone())),
+			(Quantity::Distance(a), Quantity::VolumetricFlowRate(b)) => Ok(Quantity::InverseKinematicViscosity(a.clone() / b.clone())),
+			(Quantity::InverseAmount(a), Quantity::InverseMass(b)) => Ok(Quantity::MolarMass(a.clone() / b.clone())),
+			(Quantity::InverseAmount(a), Quantity::InverseCatalyticActivity(b)) => Ok(Quantity::Frequency(a.clone() / b.clone())),
+			(Quantity::InverseAmount(a), Quantity::InverseEntropy(b)) => Ok(Quantity::MolarEntropy(a.clone() / b.clone())),
+			(Quantity::InverseAmount(a), Quantity::InverseHeatCapacity(b)) => Ok(Quantity::MolarEntropy(a.clone() / b.clone())),
+			(Quantity::InverseAmount(a), Quantity::MolarEnergy(b)) => Ok(Quantity::InverseEnergy(a.clone() / b.clone())),
+			(Quantity::InverseAmount(a), Quantity::MolarEntropy(b)) => Ok(Quantity::InverseHeatCapacity(a.clone() / b.clone())),
+			(Quantity::InverseAmount(a), Quantity::MolarMass(b)) => Ok(Quantity::InverseMass(a.clone() / b.clone())),
+			(Quantity::InverseAmount(a), Quantity::MolarVolume(b)) => Ok(Quantity::InverseVolume(a.clone() / b.clone())),
+			(Quantity::InverseAmount(a), Quantity::InverseVolume(b)) => Ok(Quantity::MolarVolume(a.clone() / b.clone())),
+			(Quantity::InverseAmount(a), Quantity::Frequency(b)) => Ok(Quantity::InverseCatalyticActivity(a.clone() / b.clone())),
+			(Quantity::InverseAmount(a), Quantity::InverseEnergy(b)) => Ok(Quantity::MolarEnergy(a.clone() / b.clone())),
+			(Quantity::InverseAmount(a), Quantity::InverseTorque(b)) => Ok(Quantity::MolarEnergy(a.clone() / b.clone())),
+			(Quantity::InverseCurrent(a), Quantity::InverseDistance(b)) => Ok(Quantity::InverseMagneticFieldStrength(a.clone() / b.clone())),
+			(Quantity::InverseCurrent(a), Quantity::Time(b)) => Ok(Quantity::InverseCharge(a.clone() / b.clone())),
+			(Quantity::InverseCurrent(a), Quantity::Inductance(b)) => Ok(Quantity::InverseMagneticFlux(a.clone() / b.clone())),
+			(Quantity::InverseCurrent(a), Quantity::InverseCharge(b)) => Ok(Quantity::Time(a.clone() / b.clone())),
+			(Quantity::InverseCurrent(a), Quantity::InverseLinearChargeDensity(b)) => Ok(Quantity::TimePerDistance(a.clone() / b.clone())),
+			(Quantity::InverseCurrent(a), Quantity::InverseMagneticFieldStrength(b)) => Ok(Quantity::InverseDistance(a.clone() / b.clone())),
+			(Quantity::InverseCurrent(a), Quantity::InverseMagneticFlux(b)) => Ok(Quantity::Inductance(a.clone() / b.clone())),
+			(Quantity::InverseCurrent(a), Quantity::InverseSurfaceChargeDensity(b)) => Ok(Quantity::InverseKinematicViscosity(a.clone() / b.clone())),
+			(Quantity::InverseCurrent(a), Quantity::InverseVoltage(b)) => Ok(Quantity::Resistance(a.clone() / b.clone())),
+			(Quantity::InverseCurrent(a), Quantity::InverseVolumeChargeDensity(b)) => Ok(Quantity::InverseVolumetricFlowRate(a.clone() / b.clone())),
+			(Quantity::InverseCurrent(a), Quantity::MagneticFlux(b)) => Ok(Quantity::InverseEnergy(a.clone() / b.clone())),
+			(Quantity::InverseCurrent(a), Quantity::Resistance(b)) => Ok(Quantity::InverseVoltage(a.clone() / b.clone())),
+			(Quantity::InverseCurrent(a), Quantity::Voltage(b)) => Ok(Quantity::InversePower(a.clone() / b.clone())),
+			(Quantity::InverseCurrent(a), Quantity::InverseEnergy(b)) => Ok(Quantity::MagneticFlux(a.clone() / b.clone())),
+			(Quantity::InverseCurrent(a), Quantity::InverseTorque(b)) => Ok(Quantity::MagneticFlux(a.clone() / b.clone())),
+			(Quantity::InverseCurrent(a), Quantity::InverseKinematicViscosity(b)) => Ok(Quantity::InverseSurfaceChargeDensity(a.clone() / b.clone())),
+			(Quantity::InverseCurrent(a), Quantity::InversePower(b)) => Ok(Quantity::Voltage(a.clone() / b.clone())),
+			(Quantity::InverseCurrent(a), Quantity::InverseVolumetricFlowRate(b)) => Ok(Quantity::InverseVolumeChargeDensity(a.clone() / b.clone())),
+			(Quantity::InverseCurrent(a), Quantity::TimePerDistance(b)) => Ok(Quantity::InverseLinearChargeDensity(a.clone() / b.clone())),
+			(Quantity::InverseDistance(a), Quantity::Distance(b)) => Ok(Quantity::InverseArea(a.clone() / b.clone())),
+			(Quantity::InverseDistance(a), Quantity::InverseCurrent(b)) => Ok(Quantity::MagneticFieldStrength(a.clone() / b.clone())),
+			(Quantity::InverseDistance(a), Quantity::ThermalConductivity(b)) => Ok(Quantity::ThermalResistance(a.clone() / b.clone())),
+			(Quantity::InverseDistance(a), Quantity::ThermalResistance(b)) => Ok(Quantity::ThermalConductivity(a.clone() / b.clone())),
+			(Quantity::InverseDistance(a), Quantity::ElectricField(b)) => Ok(Quantity::InverseVoltage(a.clone() / b.clone())),
+			(Quantity::InverseDistance(a), Quantity::InverseCharge(b)) => Ok(Quantity::LinearChargeDensity(a.clone() / b.clone())),
+			(Quantity::InverseDistance(a), Quantity::InverseLinearChargeDensity(b)) => Ok(Quantity::SurfaceChargeDensity(a.clone() / b.clone())),
+			(Quantity::InverseDistance(a), Quantity::InverseSurfaceChargeDensity(b)) => Ok(Quantity::VolumeChargeDensity(a.clone() / b.clone())),
+			(Quantity::InverseDistance(a), Quantity::InverseVoltage(b)) => Ok(Quantity::ElectricField(a.clone() / b.clone())),
+			(Quantity::InverseDistance(a), Quantity::LinearChargeDensity(b)) => Ok(Quantity::InverseCharge(a.clone() / b.clone())),
+			(Quantity::InverseDistance(a), Quantity::MagneticFieldStrength(b)) => Ok(Quantity::InverseCurrent(a.clone() / b.clone())),
+			(Quantity::InverseDistance(a), Quantity::SurfaceChargeDensity(b)) => Ok(Quantity::InverseLinearChargeDensity(a.clone() / b.clone())),
+			(Quantity::InverseDistance(a), Quantity::VolumeChargeDensity(b)) => Ok(Quantity::InverseSurfaceChargeDensity(a.clone() / b.clone())),
+			(Quantity::InverseDistance(a), Quantity::Area(b)) => Ok(Quantity::InverseVolume(a.clone() / b.clone())),
+			(Quantity::InverseDistance(a), Quantity::InverseArea(b)) => Ok(Quantity::Distance(a.clone() / b.clone())),
+			(Quantity::InverseDistance(a), Quantity::InverseVolume(b)) => Ok(Quantity::Area(a.clone() / b.clone())),
+			(Quantity::InverseDistance(a), Quantity::AreaPerMass(b)) => Ok(Quantity::Density(a.clone() / b.clone())),
+			(Quantity::InverseDistance(a), Quantity::Density(b)) => Ok(Quantity::AreaPerMass(a.clone() / b.clone())),
+			(Quantity::InverseDistance(a), Quantity::DynamicViscosity(b)) => Ok(Quantity::InverseMassFlowRate(a.clone() / b.clone())),
+			(Quantity::InverseDistance(a), Quantity::Force(b)) => Ok(Quantity::InverseEnergy(a.clone() / b.clone())),
+			(Quantity::InverseDistance(a), Quantity::Frequency(b)) => Ok(Quantity::TimePerDistance(a.clone() / b.clone())),
+			(Quantity::InverseDistance(a), Quantity::InverseEnergy(b)) => Ok(Quantity::Force(a.clone() / b.clone())),
+			(Quantity::InverseDistance(a), Quantity::InverseTorque(b)) => Ok(Quantity::Force(a.clone() / b.clone())),
+			(Quantity::InverseDistance(a), Quantity::InverseKinematicViscosity(b)) => Ok(Quantity::Velocity(a.clone() / b.clone())),
+			(Quantity::InverseDistance(a), Quantity::InverseMassFlowRate(b)) => Ok(Quantity::DynamicViscosity(a.clone() / b.clone())),
+			(Quantity::InverseDistance(a), Quantity::InverseMomentum(b)) => Ok(Quantity::MassFlowRate(a.clone() / b.clone())),
+			(Quantity::InverseDistance(a), Quantity::InverseVolumetricFlowRate(b)) => Ok(Quantity::KinematicViscosity(a.clone() / b.clone())),
+			(Quantity::InverseDistance(a), Quantity::KinematicViscosity(b)) => Ok(Quantity::InverseVolumetricFlowRate(a.clone() / b.clone())),
+			(Quantity::InverseDistance(a), Quantity::MassFlowRate(b)) => Ok(Quantity::InverseMomentum(a.clone() / b.clone())),
+			(Quantity::InverseDistance(a), Quantity::TimePerDistance(b)) => Ok(Quantity::Frequency(a.clone() / b.clone())),
+			(Quantity::InverseDistance(a), Quantity::Velocity(b)) => Ok(Quantity::InverseKinematicViscosity(a.clone() / b.clone())),
+			(Quantity::InverseDistance(a), Quantity::InverseAbsorbedDose(b)) => Ok(Quantity::Acceleration(a.clone() / b.clone())),
+			(Quantity::InverseDistance(a), Quantity::InverseDoseEquivalent(b)) => Ok(Quantity::Acceleration(a.clone() / b.clone())),
+			(Quantity::InverseLuminosity(a), Quantity::InverseLuminousFlux(b)) => Ok(Quantity::SolidAngle(a.clone() / b.clone())),
+			(Quantity::InverseLuminosity(a), Quantity::SolidAngle(b)) => Ok(Quantity::InverseLuminousFlux(a.clone() / b.clone())),
+			(Quantity::InverseMass(a), Quantity::InverseAmount(b)) => Ok(Quantity::Molality(a.clone() / b.clone())),
+			(Quantity::InverseMass(a), Quantity::InverseEntropy(b)) => Ok(Quantity::SpecificHeatCapacity(a.clone() / b.clone())),
+			(Quantity::InverseMass(a), Quantity::InverseHeatCapacity(b)) => Ok(Quantity::SpecificHeatCapacity(a.clone() / b.clone())),
+			(Quantity::InverseMass(a), Quantity::Molality(b)) => Ok(Quantity::InverseAmount(a.clone() / b.clone())),
+			(Quantity::InverseMass(a), Quantity::SpecificHeatCapacity(b)) => Ok(Quantity::InverseHeatCapacity(a.clone() / b.clone())),
+			(Quantity::InverseMass(a), Quantity::InverseArea(b)) => Ok(Quantity::AreaPerMass(a.clone() / b.clone())),
+			(Quantity::InverseMass(a), Quantity::InverseVolume(b)) => Ok(Quantity::VolumePerMass(a.clone() / b.clone())),
+			(Quantity::InverseMass(a), Quantity::Acceleration(b)) => Ok(Quantity::InverseForce(a.clone() / b.clone())),
+			(Quantity::InverseMass(a), Quantity::AreaPerMass(b)) => Ok(Quantity::InverseArea(a.clone() / b.clone())),
+			(Quantity::InverseMass(a), Quantity::Frequency(b)) => Ok(Quantity::InverseMassFlowRate(a.clone() / b.clone())),
+			(Quantity::InverseMass(a), Quantity::InverseForce(b)) => Ok(Quantity::Acceleration(a.clone() / b.clone())),
+			(Quantity::InverseMass(a), Quantity::InverseMassFlowRate(b)) => Ok(Quantity::Frequency(a.clone() / b.clone())),
+			(Quantity::InverseMass(a), Quantity::InverseMomentOfInertia(b)) => Ok(Quantity::Area(a.clone() / b.clone())),
+			(Quantity::InverseMass(a), Quantity::InverseMomentum(b)) => Ok(Quantity::Velocity(a.clone() / b.clone())),
+			(Quantity::InverseMass(a), Quantity::Velocity(b)) => Ok(Quantity::InverseMomentum(a.clone() / b.clone())),
+			(Quantity::InverseMass(a), Quantity::VolumePerMass(b)) => Ok(Quantity::InverseVolume(a.clone() / b.clone())),
+			(Quantity::InverseTemperature(a), Quantity::Entropy(b)) => Ok(Quantity::InverseEnergy(a.clone() / b.clone())),
+			(Quantity::InverseTemperature(a), Quantity::HeatCapacity(b)) => Ok(Quantity::InverseEnergy(a.clone() / b.clone())),
+			(Quantity::InverseTemperature(a), Quantity::InverseMolarEnergy(b)) => Ok(Quantity::MolarEntropy(a.clone() / b.clone())),
+			(Quantity::InverseTemperature(a), Quantity::MolarEntropy(b)) => Ok(Quantity::InverseMolarEnergy(a.clone() / b.clone())),
+			(Quantity::InverseTemperature(a), Quantity::ThermalConductance(b)) => Ok(Quantity::InversePower(a.clone() / b.clone())),
+			(Quantity::InverseTemperature(a), Quantity::InverseEnergy(b)) => Ok(Quantity::HeatCapacity(a.clone() / b.clone())),
+			(Quantity::InverseTemperature(a), Quantity::InverseTorque(b)) => Ok(Quantity::HeatCapacity(a.clone() / b.clone())),
+			(Quantity::InverseTemperature(a), Quantity::InversePower(b)) => Ok(Quantity::ThermalConductance(a.clone() / b.clone())),
+			(Quantity::InverseTemperature(a), Quantity::InverseAbsorbedDose(b)) => Ok(Quantity::SpecificHeatCapacity(a.clone() / b.clone())),
+			(Quantity::InverseTemperature(a), Quantity::InverseDoseEquivalent(b)) => Ok(Quantity::SpecificHeatCapacity(a.clone() / b.clone())),
+			(Quantity::Luminosity(a), Quantity::LuminousFlux(b)) => Ok(Quantity::InverseSolidAngle(a.clone() / b.clone())),
+			(Quantity::Luminosity(a), Quantity::InverseSolidAngle(b)) => Ok(Quantity::LuminousFlux(a.clone() / b.clone())),
+			(Quantity::Mass(a), Quantity::Amount(b)) => Ok(Quantity::MolarMass(a.clone() / b.clone())),
+			(Quantity::Mass(a), Quantity::Time(b)) => Ok(Quantity::MassFlowRate(a.clone() / b.clone())),
+			(Quantity::Mass(a), Quantity::Entropy(b)) => Ok(Quantity::InverseSpecificHeatCapacity(a.clone() / b.clone())),
+			(Quantity::Mass(a), Quantity::HeatCapacity(b)) => Ok(Quantity::InverseSpecificHeatCapacity(a.clone() / b.clone())),
+			(Quantity::Mass(a), Quantity::InverseSpecificHeatCapacity(b)) => Ok(Quantity::HeatCapacity(a.clone() / b.clone())),
+			(Quantity::Mass(a), Quantity::MolarMass(b)) => Ok(Quantity::Amount(a.clone() / b.clone())),
+			(Quantity::Mass(a), Quantity::Area(b)) => Ok(Quantity::AreaDensity(a.clone() / b.clone())),
+			(Quantity::Mass(a), Quantity::Volume(b)) => Ok(Quantity::Density(a.clone() / b.clone())),
+			(Quantity::Mass(a), Quantity::AreaDensity(b)) => Ok(Quantity::Area(a.clone() / b.clone())),
+			(Quantity::Mass(a), Quantity::Density(b)) => Ok(Quantity::Volume(a.clone() / b.clone())),
+			(Quantity::Mass(a), Quantity::Force(b)) => Ok(Quantity::InverseAcceleration(a.clone() / b.clone())),
+			(Quantity::Mass(a), Quantity::InverseAcceleration(b)) => Ok(Quantity::Force(a.clone() / b.clone())),
+			(Quantity::Mass(a), Quantity::MassFlowRate(b)) => Ok(Quantity::Time(a.clone() / b.clone())),
+			(Quantity::Mass(a), Quantity::MomentOfInertia(b)) => Ok(Quantity::InverseArea(a.clone() / b.clone())),
+			(Quantity::Mass(a), Quantity::Momentum(b)) => Ok(Quantity::TimePerDistance(a.clone() / b.clone())),
+			(Quantity::Mass(a), Quantity::TimePerDistance(b)) => Ok(Quantity::Momentum(a.clone() / b.clone())),
+			(Quantity::Mass(a), Quantity::InverseAbsorbedDose(b)) => Ok(Quantity::Energy(a.clone() / b.clone())),
+			(Quantity::Mass(a), Quantity::InverseDoseEquivalent(b)) => Ok(Quantity::Energy(a.clone() / b.clone())),
+			(Quantity::Temperature(a), Quantity::InverseEntropy(b)) => Ok(Quantity::Energy(a.clone() / b.clone())),
+			(Quantity::Temperature(a), Quantity::InverseHeatCapacity(b)) => Ok(Quantity::Energy(a.clone() / b.clone())),
+			(Quantity::Temperature(a), Quantity::InverseMolarEntropy(b)) => Ok(Quantity::MolarEnergy(a.clone() / b.clone())),
+			(Quantity::Temperature(a), Quantity::MolarEnergy(b)) => Ok(Quantity::InverseMolarEntropy(a.clone() / b.clone())),
+			(Quantity::Temperature(a), Quantity::ThermalResistance(b)) => Ok(Quantity::Power(a.clone() / b.clone())),
+			(Quantity::Temperature(a), Quantity::Energy(b)) => Ok(Quantity::InverseHeatCapacity(a.clone() / b.clone())),
+			(Quantity::Temperature(a), Quantity::Torque(b)) => Ok(Quantity::InverseHeatCapacity(a.clone() / b.clone())),
+			(Quantity::Temperature(a), Quantity::Power(b)) => Ok(Quantity::ThermalResistance(a.clone() / b.clone())),
+			(Quantity::TemperatureInterval(a), Quantity::InverseEntropy(b)) => Ok(Quantity::Energy(a.clone() / b.clone())),
+			(Quantity::TemperatureInterval(a), Quantity::InverseHeatCapacity(b)) => Ok(Quantity::Energy(a.clone() / b.clone())),
+			(Quantity::TemperatureInterval(a), Quantity::InverseMolarEntropy(b)) => Ok(Quantity::MolarEnergy(a.clone() / b.clone())),
+			(Quantity::TemperatureInterval(a), Quantity::MolarEnergy(b)) => Ok(Quantity::InverseMolarEntropy(a.clone() / b.clone())),
+			(Quantity::TemperatureInterval(a), Quantity::ThermalResistance(b)) => Ok(Quantity::Power(a.clone() / b.clone())),
+			(Quantity::TemperatureInterval(a), Quantity::Energy(b)) => Ok(Quantity::InverseHeatCapacity(a.clone() / b.clone())),
+			(Quantity::TemperatureInterval(a), Quantity::Torque(b)) => Ok(Quantity::InverseHeatCapacity(a.clone() / b.clone())),
+			(Quantity::TemperatureInterval(a), Quantity::Power(b)) => Ok(Quantity::ThermalResistance(a.clone() / b.clone())),
+			(Quantity::Time(a), Quantity::Amount(b)) => Ok(Quantity::InverseCatalyticActivity(a.clone() / b.clone())),
+			(Quantity::Time(a), Quantity::Distance(b)) => Ok(Quantity::TimePerDistance(a.clone() / b.clone())),
+			(Quantity::Time(a), Quantity::InverseCurrent(b)) => Ok(Quantity::Charge(a.clone() / b.clone())),
+			(Quantity::Time(a), Quantity::Mass(b)) => Ok(Quantity::InverseMassFlowRate(a.clone() / b.clone())),
+			(Quantity::Time(a), Quantity::Entropy(b)) => Ok(Quantity::ThermalResistance(a.clone() / b.clone())),
+			(Quantity::Time(a), Quantity::HeatCapacity(b)) => Ok(Quantity::ThermalResistance(a.clone() / b.clone())),
+			(Quantity::Time(a), Quantity::InverseCatalyticActivity(b)) => Ok(Quantity::Amount(a.clone() / b.clone())),
+			(Quantity::Time(a), Quantity::ThermalResistance(b)) => Ok(Quantity::HeatCapacity(a.clone() / b.clone())),
+			(Quantity::Time(a), Quantity::Capacitance(b)) => Ok(Quantity::Resistance(a.clone() / b.clone())),
+			(Quantity::Time(a), Quantity::Charge(b)) => Ok(Quantity::InverseCurrent(a.clone() / b.clone())),
+			(Quantity::Time(a), Quantity::Conductance(b)) => Ok(Quantity::Inductance(a.clone() / b.clone())),
+			(Quantity::Time(a), Quantity::Inductance(b)) => Ok(Quantity::Conductance(a.clone() / b.clone())),
+			(Quantity::Time(a), Quantity::InverseMagneticFieldStrength(b)) => Ok(Quantity::LinearChargeDensity(a.clone() / b.clone())),
+			(Quantity::Time(a), Quantity::InverseVoltage(b)) => Ok(Quantity::MagneticFlux(a.clone() / b.clone())),
+			(Quantity::Time(a), Quantity::LinearChargeDensity(b)) => Ok(Quantity::InverseMagneticFieldStrength(a.clone() / b.clone())),
+			(Quantity::Time(a), Quantity::MagneticFlux(b)) => Ok(Quantity::InverseVoltage(a.clone() / b.clone())),
+			(Quantity::Time(a), Quantity::Resistance(b)) => Ok(Quantity::Capacitance(a.clone() / b.clone())),
+			(Quantity::Time(a), Quantity::Angle(b)) => Ok(Quantity::InverseAngularVelocity(a.clone() / b.clone())),
+			(Quantity::Time(a), Quantity::Area(b)) => Ok(Quantity::InverseKinematicViscosity(a.clone() / b.clone())),
+			(Quantity::Time(a), Quantity::Volume(b)) => Ok(Quantity::InverseVolumetricFlowRate(a.clone() / b.clone())),
+			(Quantity::Time(a), Quantity::Acceleration(b)) => Ok(Quantity::InverseJerk(a.clone() / b.clone())),
+			(Quantity::Time(a), Quantity::AngularVelocity(b)) => Ok(Quantity::InverseAngularAcceleration(a.clone() / b.clone())),
+			(Quantity::Time(a), Quantity::DynamicViscosity(b)) => Ok(Quantity::InversePressure(a.clone() / b.clone())),
+			(Quantity::Time(a), Quantity::Energy(b)) => Ok(Quantity::InversePower(a.clone() / b.clone())),
+			(Quantity::Time(a), Quantity::Torque(b)) => Ok(Quantity::InversePower(a.clone() / b.clone())),
+			(Quantity::Time(a), Quantity::InverseAcceleration(b)) => Ok(Quantity::Velocity(a.clone() / b.clone())),
+			(Quantity::Time(a), Quantity::InverseAngularAcceleration(b)) => Ok(Quantity::AngularVelocity(a.clone() / b.clone())),
+			(Quantity::Time(a), Quantity::InverseAngularVelocity(b)) => Ok(Quantity::Angle(a.clone() / b.clone())),
+			(Quantity::Time(a), Quantity::InverseForce(b)) => Ok(Quantity::Momentum(a.clone() / b.clone())),
+			(Quantity::Time(a), Quantity::InverseJerk(b)) => Ok(Quantity::Acceleration(a.clone() / b.clone())),
+			(Quantity::Time(a), Quantity::InverseKinematicViscosity(b)) => Ok(Quantity::Area(a.clone() / b.clone())),
+			(Quantity::Time(a), Quantity::InverseMassFlowRate(b)) => Ok(Quantity::Mass(a.clone() / b.clone())),
+			(Quantity::Time(a), Quantity::InversePower(b)) => Ok(Quantity::Energy(a.clone() / b.clone())),
+			(Quantity::Time(a), Quantity::InversePressure(b)) => Ok(Quantity::DynamicViscosity(a.clone() / b.clone())),
+			(Quantity::Time(a), Quantity::InverseVolumetricFlowRate(b)) => Ok(Quantity::Volume(a.clone() / b.clone())),
+			(Quantity::Time(a), Quantity::Momentum(b)) => Ok(Quantity::InverseForce(a.clone() / b.clone())),
+			(Quantity::Time(a), Quantity::TimePerDistance(b)) => Ok(Quantity::Distance(a.clone() / b.clone())),
+			(Quantity::Time(a), Quantity::Velocity(b)) => Ok(Quantity::InverseAcceleration(a.clone() / b.clone())),
+			(Quantity::Time(a), Quantity::InverseAbsorbedDose(b)) => Ok(Quantity::KinematicViscosity(a.clone() / b.clone())),
+			(Quantity::Time(a), Quantity::InverseDoseEquivalent(b)) => Ok(Quantity::KinematicViscosity(a.clone() / b.clone())),
+			(Quantity::CatalyticActivity(a), Quantity::Amount(b)) => Ok(Quantity::Frequency(a.clone() / b.clone())),
+			(Quantity::CatalyticActivity(a), Quantity::Concentration(b)) => Ok(Quantity::VolumetricFlowRate(a.clone() / b.clone())),
+			(Quantity::CatalyticActivity(a), Quantity::InverseMolarEnergy(b)) => Ok(Quantity::Power(a.clone() / b.clone())),
+			(Quantity::CatalyticActivity(a), Quantity::InverseMolarEntropy(b)) => Ok(Quantity::ThermalConductance(a.clone() / b.clone())),
+			(Quantity::CatalyticActivity(a), Quantity::Molality(b)) => Ok(Quantity::MassFlowRate(a.clone() / b.clone())),
+			(Quantity::CatalyticActivity(a), Quantity::ThermalConductance(b)) => Ok(Quantity::InverseMolarEntropy(a.clone() / b.clone())),
+			(Quantity::CatalyticActivity(a), Quantity::Frequency(b)) => Ok(Quantity::Amount(a.clone() / b.clone())),
+			(Quantity::CatalyticActivity(a), Quantity::MassFlowRate(b)) => Ok(Quantity::Molality(a.clone() / b.clone())),
+			(Quantity::CatalyticActivity(a), Quantity::Power(b)) => Ok(Quantity::InverseMolarEnergy(a.clone() / b.clone())),
+			(Quantity::CatalyticActivity(a), Quantity::VolumetricFlowRate(b)) => Ok(Quantity::Concentration(a.clone() / b.clone())),
+			(Quantity::Concentration(a), Quantity::Amount(b)) => Ok(Quantity::InverseVolume(a.clone() / b.clone())),
+			(Quantity::Concentration(a), Quantity::CatalyticActivity(b)) => Ok(Quantity::InverseVolumetricFlowRate(a.clone() / b.clone())),
+			(Quantity::Concentration(a), Quantity::InverseMolarEnergy(b)) => Ok(Quantity::Pressure(a.clone() / b.clone())),
+			(Quantity::Concentration(a), Quantity::Molality(b)) => Ok(Quantity::Density(a.clone() / b.clone())),
+			(Quantity::Concentration(a), Quantity::InverseVolume(b)) => Ok(Quantity::Amount(a.clone() / b.clone())),
+			(Quantity::Concentration(a), Quantity::Density(b)) => Ok(Quantity::Molality(a.clone() / b.clone())),
+			(Quantity::Concentration(a), Quantity::InverseVolumetricFlowRate(b)) => Ok(Quantity::CatalyticActivity(a.clone() / b.clone())),
+			(Quantity::Concentration(a), Quantity::Pressure(b)) => Ok(Quantity::InverseMolarEnergy(a.clone() / b.clone())),
+			(Quantity::Entropy(a), Quantity::Amount(b)) => Ok(Quantity::MolarEntropy(a.clone() / b.clone())),
+			(Quantity::Entropy(a), Quantity::InverseTemperature(b)) => Ok(Quantity::Energy(a.clone() / b.clone())),
+			(Quantity::Entropy(a), Quantity::Mass(b)) => Ok(Quantity::SpecificHeatCapacity(a.clone() / b.clone())),
+			(Quantity::Entropy(a), Quantity::Time(b)) => Ok(Quantity::ThermalConductance(a.clone() / b.clone())),
+			(Quantity::Entropy(a), Quantity::MolarEntropy(b)) => Ok(Quantity::Amount(a.clone() / b.clone())),
+			(Quantity::Entropy(a), Quantity::SpecificHeatCapacity(b)) => Ok(Quantity::Mass(a.clone() / b.clone())),
+			(Quantity::Entropy(a), Quantity::ThermalConductance(b)) => Ok(Quantity::Time(a.clone() / b.clone())),
+			(Quantity::Entropy(a), Quantity::Energy(b)) => Ok(Quantity::InverseTemperature(a.clone() / b.clone())),
+			(Quantity::Entropy(a), Quantity::Torque(b)) => Ok(Quantity::InverseTemperature(a.clone() / b.clone())),
+			(Quantity::HeatCapacity(a), Quantity::Amount(b)) => Ok(Quantity::MolarEntropy(a.clone() / b.clone())),
+			(Quantity::HeatCapacity(a), Quantity::InverseTemperature(b)) => Ok(Quantity::Energy(a.clone() / b.clone())),
+			(Quantity::HeatCapacity(a), Quantity::Mass(b)) => Ok(Quantity::SpecificHeatCapacity(a.clone() / b.clone())),
+			(Quantity::HeatCapacity(a), Quantity::Time(b)) => Ok(Quantity::ThermalConductance(a.clone() / b.clone())),
+			(Quantity::HeatCapacity(a), Quantity::MolarEntropy(b)) => Ok(Quantity::Amount(a.clone() / b.clone())),
+			(Quantity::HeatCapacity(a), Quantity::SpecificHeatCapacity(b)) => Ok(Quantity::Mass(a.clone() / b.clone())),
+			(Quantity::HeatCapacity(a), Quantity::ThermalConductance(b)) => Ok(Quantity::Time(a.clone() / b.clone())),
+			(Quantity::HeatCapacity(a), Quantity::Energy(b)) => Ok(Quantity::InverseTemperature(a.clone() / b.clone())),
+			(Quantity::HeatCapacity(a), Quantity::Torque(b)) => Ok(Quantity::InverseTemperature(a.clone() / b.clone())),
+			(Quantity::InverseCatalyticActivity(a), Quantity::InverseAmount(b)) => Ok(Quantity::Time(a.clone() / b.clone())),
+			(Quantity::InverseCatalyticActivity(a), Quantity::Time(b)) => Ok(Quantity::InverseAmount(a.clone() / b.clone())),
+			(Quantity::InverseCatalyticActivity(a), Quantity::MolarEnergy(b)) => Ok(Quantity::InversePower(a.clone() / b.clone())),
+			(Quantity::InverseCatalyticActivity(a), Quantity::MolarEntropy(b)) => Ok(Quantity::ThermalResistance(a.clone() / b.clone())),
+			(Quantity::InverseCatalyticActivity(a), Quantity::MolarMass(b)) => Ok(Quantity::InverseMassFlowRate(a.clone() / b.clone())),
+			(Quantity::InverseCatalyticActivity(a), Quantity::MolarVolume(b)) => Ok(Quantity::InverseVolumetricFlowRate(a.clone() / b.clone())),
+			(Quantity::InverseCatalyticActivity(a), Quantity::ThermalResistance(b)) => Ok(Quantity::MolarEntropy(a.clone() / b.clone())),
+			(Quantity::InverseCatalyticActivity(a), Quantity::InverseMassFlowRate(b)) => Ok(Quantity::MolarMass(a.clone() / b.clone())),
+			(Quantity::InverseCatalyticActivity(a), Quantity::InversePower(b)) => Ok(Quantity::MolarEnergy(a.clone() / b.clone())),
+			(Quantity::InverseCatalyticActivity(a), Quantity::InverseVolumetricFlowRate(b)) => Ok(Quantity::MolarVolume(a.clone() / b.clone())),
+			(Quantity::InverseEntropy(a), Quantity::InverseAmount(b)) => Ok(Quantity::InverseMolarEntropy(a.clone() / b.clone())),
+			(Quantity::InverseEntropy(a), Quantity::InverseMass(b)) => Ok(Quantity::InverseSpecificHeatCapacity(a.clone() / b.clone())),
+			(Quantity::InverseEntropy(a), Quantity::Temperature(b)) => Ok(Quantity::InverseEnergy(a.clone() / b.clone())),
+			(Quantity::InverseEntropy(a), Quantity::TemperatureInterval(b)) => Ok(Quantity::InverseEnergy(a.clone() / b.clone())),
+			(Quantity::InverseEntropy(a), Quantity::InverseMolarEntropy(b)) => Ok(Quantity::InverseAmount(a.clone() / b.clone())),
+			(Quantity::InverseEntropy(a), Quantity::InverseSpecificHeatCapacity(b)) => Ok(Quantity::InverseMass(a.clone() / b.clone())),
+			(Quantity::InverseEntropy(a), Quantity::ThermalResistance(b)) => Ok(Quantity::Frequency(a.clone() / b.clone())),
+			(Quantity::InverseEntropy(a), Quantity::Frequency(b)) => Ok(Quantity::ThermalResistance(a.clone() / b.clone())),
+			(Quantity::InverseEntropy(a), Quantity::InverseEnergy(b)) => Ok(Quantity::Temperature(a.clone() / b.clone())),
+			(Quantity::InverseEntropy(a), Quantity::InverseTorque(b)) => Ok(Quantity::Temperature(a.clone() / b.clone())),
+			(Quantity::InverseHeatCapacity(a), Quantity::InverseAmount(b)) => Ok(Quantity::InverseMolarEntropy(a.clone() / b.clone())),
+			(Quantity::InverseHeatCapacity(a), Quantity::InverseMass(b)) => Ok(Quantity::InverseSpecificHeatCapacity(a.clone() / b.clone())),
+			(Quantity::InverseHeatCapacity(a), Quantity::Temperature(b)) => Ok(Quantity::InverseEnergy(a.clone() / b.clone())),
+			(Quantity::InverseHeatCapacity(a), Quantity::TemperatureInterval(b)) => Ok(Quantity::InverseEnergy(a.clone() / b.clone())),
+			(Quantity::InverseHeatCapacity(a), Quantity::InverseMolarEntropy(b)) => Ok(Quantity::InverseAmount(a.clone() / b.clone())),
+			(Quantity::InverseHeatCapacity(a), Quantity::InverseSpecificHeatCapacity(b)) => Ok(Quantity::InverseMass(a.clone() / b.clone())),
+			(Quantity::InverseHeatCapacity(a), Quantity::ThermalResistance(b)) => Ok(Quantity::Frequency(a.clone() / b.clone())),
+			(Quantity::InverseHeatCapacity(a), Quantity::Frequency(b)) => Ok(Quantity::ThermalResistance(a.clone() / b.clone())),
+			(Quantity::InverseHeatCapacity(a), Quantity::InverseEnergy(b)) => Ok(Quantity::Temperature(a.clone() / b.clone())),
+			(Quantity::InverseHeatCapacity(a), Quantity::InverseTorque(b)) => Ok(Quantity::Temperature(a.clone() / b.clone())),
+			(Quantity::InverseMolarEnergy(a), Quantity::Amount(b)) => Ok(Quantity::InverseEnergy(a.clone() / b.clone())),
+			(Quantity::InverseMolarEnergy(a), Quantity::InverseTemperature(b)) => Ok(Quantity::InverseMolarEntropy(a.clone() / b.clone())),
+			(Quantity::InverseMolarEnergy(a), Quantity::CatalyticActivity(b)) => Ok(Quantity::InversePower(a.clone() / b.clone())),
+			(Quantity::InverseMolarEnergy(a), Quantity::Concentration(b)) => Ok(Quantity::InversePressure(a.clone() / b.clone())),
+			(Quantity::InverseMolarEnergy(a), Quantity::InverseMolarEntropy(b)) => Ok(Quantity::InverseTemperature(a.clone() / b.clone())),
+			(Quantity::InverseMolarEnergy(a), Quantity::InverseEnergy(b)) => Ok(Quantity::Amount(a.clone() / b.clone())),
+			(Quantity::InverseMolarEnergy(a), Quantity::InverseTorque(b)) => Ok(Quantity::Amount(a.clone() / b.clone())),
+			(Quantity::InverseMolarEnergy(a), Quantity::InversePower(b)) => Ok(Quantity::CatalyticActivity(a.clone() / b.clone())),
+			(Quantity::InverseMolarEnergy(a), Quantity::InversePressure(b)) => Ok(Quantity::Concentration(a.clone() / b.clone())),
+			(Quantity::InverseMolarEnergy(a), Quantity::InverseAbsorbedDose(b)) => Ok(Quantity::Molality(a.clone() / b.clone())),
+			(Quantity::InverseMolarEnergy(a), Quantity::InverseDoseEquivalent(b)) => Ok(Quantity::Molality(a.clone() / b.clone())),
+			(Quantity::InverseMolarEntropy(a), Quantity::Amount(b)) => Ok(Quantity::InverseHeatCapacity(a.clone() / b.clone())),
+			(Quantity::InverseMolarEntropy(a), Quantity::Temperature(b)) => Ok(Quantity::InverseMolarEnergy(a.clone() / b.clone())),
+			(Quantity::InverseMolarEntropy(a), Quantity::TemperatureInterval(b)) => Ok(Quantity::InverseMolarEnergy(a.clone() / b.clone())),
+			(Quantity::InverseMolarEntropy(a), Quantity::CatalyticActivity(b)) => Ok(Quantity::ThermalResistance(a.clone() / b.clone())),
+			(Quantity::InverseMolarEntropy(a), Quantity::InverseEntropy(b)) => Ok(Quantity::Amount(a.clone() / b.clone())),
+			(Quantity::InverseMolarEntropy(a), Quantity::InverseHeatCapacity(b)) => Ok(Quantity::Amount(a.clone() / b.clone())),
+			(Quantity::InverseMolarEntropy(a), Quantity::InverseMolarEnergy(b)) => Ok(Quantity::Temperature(a.clone() / b.clone())),
+			(Quantity::InverseMolarEntropy(a), Quantity::InverseSpecificHeatCapacity(b)) => Ok(Quantity::Molality(a.clone() / b.clone())),
+			(Quantity::InverseMolarEntropy(a), Quantity::Molality(b)) => Ok(Quantity::InverseSpecificHeatCapacity(a.clone() / b.clone())),
+			(Quantity::InverseMolarEntropy(a), Quantity::ThermalResistance(b)) => Ok(Quantity::CatalyticActivity(a.clone() / b.clone())),
+			(Quantity::InverseSpecificHeatCapacity(a), Quantity::Mass(b)) => Ok(Quantity::InverseHeatCapacity(a.clone() / b.clone())),
+			(Quantity::InverseSpecificHeatCapacity(a), Quantity::InverseEntropy(b)) => Ok(Quantity::Mass(a.clone() / b.clone())),
+			(Quantity::InverseSpecificHeatCapacity(a), Quantity::InverseHeatCapacity(b)) => Ok(Quantity::Mass(a.clone() / b.clone())),
+			(Quantity::InverseSpecificHeatCapacity(a), Quantity::InverseMolarEntropy(b)) => Ok(Quantity::MolarMass(a.clone() / b.clone())),
+			(Quantity::InverseSpecificHeatCapacity(a), Quantity::MolarMass(b)) => Ok(Quantity::InverseMolarEntropy(a.clone() / b.clone())),
+			(Quantity::InverseSpecificHeatCapacity(a), Quantity::ThermalResistance(b)) => Ok(Quantity::MassFlowRate(a.clone() / b.clone())),
+			(Quantity::InverseSpecificHeatCapacity(a), Quantity::ThermalResistivity(b)) => Ok(Quantity::DynamicViscosity(a.clone() / b.clone())),
+			(Quantity::InverseSpecificHeatCapacity(a), Quantity::DynamicViscosity(b)) => Ok(Quantity::ThermalResistivity(a.clone() / b.clone())),
+			(Quantity::InverseSpecificHeatCapacity(a), Quantity::MassFlowRate(b)) => Ok(Quantity::ThermalResistance(a.clone() / b.clone())),
+			(Quantity::InverseSpecificHeatCapacity(a), Quantity::InverseAbsorbedDose(b)) => Ok(Quantity::Temperature(a.clone() / b.clone())),
+			(Quantity::InverseSpecificHeatCapacity(a), Quantity::InverseDoseEquivalent(b)) => Ok(Quantity::Temperature(a.clone() / b.clone())),
+			(Quantity::Molality(a), Quantity::Amount(b)) => Ok(Quantity::InverseMass(a.clone() / b.clone())),
+			(Quantity::Molality(a), Quantity::InverseMass(b)) => Ok(Quantity::Amount(a.clone() / b.clone())),
+			(Quantity::Molality(a), Quantity::CatalyticActivity(b)) => Ok(Quantity::InverseMassFlowRate(a.clone() / b.clone())),
+			(Quantity::Molality(a), Quantity::Concentration(b)) => Ok(Quantity::VolumePerMass(a.clone() / b.clone())),
+			(Quantity::Molality(a), Quantity::InverseMolarEntropy(b)) => Ok(Quantity::SpecificHeatCapacity(a.clone() / b.clone())),
+			(Quantity::Molality(a), Quantity::SpecificHeatCapacity(b)) => Ok(Quantity::InverseMolarEntropy(a.clone() / b.clone())),
+			(Quantity::Molality(a), Quantity::InverseMassFlowRate(b)) => Ok(Quantity::CatalyticActivity(a.clone() / b.clone())),
+			(Quantity::Molality(a), Quantity::VolumePerMass(b)) => Ok(Quantity::Concentration(a.clone() / b.clone())),
+			(Quantity::MolarEnergy(a), Quantity::InverseAmount(b)) => Ok(Quantity::Energy(a.clone() / b.clone())),
+			(Quantity::MolarEnergy(a), Quantity::Temperature(b)) => Ok(Quantity::MolarEntropy(a.clone() / b.clone())),
+			(Quantity::MolarEnergy(a), Quantity::TemperatureInterval(b)) => Ok(Quantity::MolarEntropy(a.clone() / b.clone())),
+			(Quantity::MolarEnergy(a), Quantity::InverseCatalyticActivity(b)) => Ok(Quantity::Power(a.clone() / b.clone())),
+			(Quantity::MolarEnergy(a), Quantity::MolarEntropy(b)) => Ok(Quantity::Temperature(a.clone() / b.clone())),
+			(Quantity::MolarEnergy(a), Quantity::MolarVolume(b)) => Ok(Quantity::Pressure(a.clone() / b.clone())),
+			(Quantity::MolarEnergy(a), Quantity::Energy(b)) => Ok(Quantity::InverseAmount(a.clone() / b.clone())),
+			(Quantity::MolarEnergy(a), Quantity::Torque(b)) => Ok(Quantity::InverseAmount(a.clone() / b.clone())),
+			(Quantity::MolarEnergy(a), Quantity::Power(b)) => Ok(Quantity::InverseCatalyticActivity(a.clone() / b.clone())),
+			(Quantity::MolarEnergy(a), Quantity::Pressure(b)) => Ok(Quantity::MolarVolume(a.clone() / b.clone())),
+			(Quantity::MolarEntropy(a), Quantity::InverseAmount(b)) => Ok(Quantity::HeatCapacity(a.clone() / b.clone())),
+			(Quantity::MolarEntropy(a), Quantity::InverseTemperature(b)) => Ok(Quantity::MolarEnergy(a.clone() / b.clone())),
+			(Quantity::MolarEntropy(a), Quantity::Entropy(b)) => Ok(Quantity::InverseAmount(a.clone() / b.clone())),
+			(Quantity::MolarEntropy(a), Quantity::HeatCapacity(b)) => Ok(Quantity::InverseAmount(a.clone() / b.clone())),
+			(Quantity::MolarEntropy(a), Quantity::InverseCatalyticActivity(b)) => Ok(Quantity::ThermalConductance(a.clone() / b.clone())),
+			(Quantity::MolarEntropy(a), Quantity::MolarEnergy(b)) => Ok(Quantity::InverseTemperature(a.clone() / b.clone())),
+			(Quantity::MolarEntropy(a), Quantity::MolarMass(b)) => Ok(Quantity::SpecificHeatCapacity(a.clone() / b.clone())),
+			(Quantity::MolarEntropy(a), Quantity::SpecificHeatCapacity(b)) => Ok(Quantity::MolarMass(a.clone() / b.clone())),
+			(Quantity::MolarEntropy(a), Quantity::ThermalConductance(b)) => Ok(Quantity::InverseCatalyticActivity(a.clone() / b.clone())),
+			(Quantity::MolarMass(a), Quantity::InverseAmount(b)) => Ok(Quantity::Mass(a.clone() / b.clone())),
+			(Quantity::MolarMass(a), Quantity::Mass(b)) => Ok(Quantity::InverseAmount(a.clone() / b.clone())),
+			(Quantity::MolarMass(a), Quantity::InverseCatalyticActivity(b)) => Ok(Quantity::MassFlowRate(a.clone() / b.clone())),
+			(Quantity::MolarMass(a), Quantity::InverseSpecificHeatCapacity(b)) => Ok(Quantity::MolarEntropy(a.clone() / b.clone())),
+			(Quantity::MolarMass(a), Quantity::MolarEntropy(b)) => Ok(Quantity::InverseSpecificHeatCapacity(a.clone() / b.clone())),
+			(Quantity::MolarMass(a), Quantity::MolarVolume(b)) => Ok(Quantity::Density(a.clone() / b.clone())),
+			(Quantity::MolarMass(a), Quantity::Density(b)) => Ok(Quantity::MolarVolume(a.clone() / b.clone())),
+			(Quantity::MolarMass(a), Quantity::MassFlowRate(b)) => Ok(Quantity::InverseCatalyticActivity(a.clone() / b.clone())),
+			(Quantity::MolarMass(a), Quantity::InverseAbsorbedDose(b)) => Ok(Quantity::MolarEnergy(a.clone() / b.clone())),
+			(Quantity::MolarMass(a), Quantity::InverseDoseEquivalent(b)) => Ok(Quantity::MolarEnergy(a.clone() / b.clone())),
+			(Quantity::MolarVolume(a), Quantity::InverseAmount(b)) => Ok(Quantity::Volume(a.clone() / b.clone())),
+			(Quantity::MolarVolume(a), Quantity::InverseCatalyticActivity(b)) => Ok(Quantity::VolumetricFlowRate(a.clone() / b.clone())),
+			(Quantity::MolarVolume(a), Quantity::MolarEnergy(b)) => Ok(Quantity::InversePressure(a.clone() / b.clone())),
+			(Quantity::MolarVolume(a), Quantity::MolarMass(b)) => Ok(Quantity::VolumePerMass(a.clone() / b.clone())),
+			(Quantity::MolarVolume(a), Quantity::Volume(b)) => Ok(Quantity::InverseAmount(a.clone() / b.clone())),
+			(Quantity::MolarVolume(a), Quantity::InversePressure(b)) => Ok(Quantity::MolarEnergy(a.clone() / b.clone())),
+			(Quantity::MolarVolume(a), Quantity::VolumePerMass(b)) => Ok(Quantity::MolarMass(a.clone() / b.clone())),
+			(Quantity::MolarVolume(a), Quantity::VolumetricFlowRate(b)) => Ok(Quantity::InverseCatalyticActivity(a.clone() / b.clone())),
+			(Quantity::SpecificHeatCapacity(a), Quantity::InverseMass(b)) => Ok(Quantity::HeatCapacity(a.clone() / b.clone())),
+			(Quantity::SpecificHeatCapacity(a), Quantity::Entropy(b)) => Ok(Quantity::InverseMass(a.clone() / b.clone())),
+			(Quantity::SpecificHeatCapacity(a), Quantity::HeatCapacity(b)) => Ok(Quantity::InverseMass(a.clone() / b.clone())),
+			(Quantity::SpecificHeatCapacity(a), Quantity::Molality(b)) => Ok(Quantity::MolarEntropy(a.clone() / b.clone())),
+			(Quantity::SpecificHeatCapacity(a), Quantity::MolarEntropy(b)) => Ok(Quantity::Molality(a.clone() / b.clone())),
+			(Quantity::SpecificHeatCapacity(a), Quantity::ThermalConductance(b)) => Ok(Quantity::InverseMassFlowRate(a.clone() / b.clone())),
+			(Quantity::SpecificHeatCapacity(a), Quantity::ThermalConductivity(b)) => Ok(Quantity::InverseDynamicViscosity(a.clone() / b.clone())),
+			(Quantity::SpecificHeatCapacity(a), Quantity::InverseDynamicViscosity(b)) => Ok(Quantity::ThermalConductivity(a.clone() / b.clone())),
+			(Quantity::SpecificHeatCapacity(a), Quantity::InverseMassFlowRate(b)) => Ok(Quantity::ThermalConductance(a.clone() / b.clone())),
+			(Quantity::ThermalConductance(a), Quantity::Distance(b)) => Ok(Quantity::ThermalConductivity(a.clone() / b.clone())),
+			(Quantity::ThermalConductance(a), Quantity::InverseTemperature(b)) => Ok(Quantity::Power(a.clone() / b.clone())),
+			(Quantity::ThermalConductance(a), Quantity::CatalyticActivity(b)) => Ok(Quantity::MolarEntropy(a.clone() / b.clone())),
+			(Quantity::ThermalConductance(a), Quantity::Entropy(b)) => Ok(Quantity::Frequency(a.clone() / b.clone())),
+			(Quantity::ThermalConductance(a), Quantity::HeatCapacity(b)) => Ok(Quantity::Frequency(a.clone() / b.clone())),
+			(Quantity::ThermalConductance(a), Quantity::MolarEntropy(b)) => Ok(Quantity::CatalyticActivity(a.clone() / b.clone())),
+			(Quantity::ThermalConductance(a), Quantity::SpecificHeatCapacity(b)) => Ok(Quantity::MassFlowRate(a.clone() / b.clone())),
+			(Quantity::ThermalConductance(a), Quantity::ThermalConductivity(b)) => Ok(Quantity::Distance(a.clone() / b.clone())),
+			(Quantity::ThermalConductance(a), Quantity::Frequency(b)) => Ok(Quantity::HeatCapacity(a.clone() / b.clone())),
+			(Quantity::ThermalConductance(a), Quantity::MassFlowRate(b)) => Ok(Quantity::SpecificHeatCapacity(a.clone() / b.clone())),
+			(Quantity::ThermalConductance(a), Quantity::Power(b)) => Ok(Quantity::InverseTemperature(a.clone() / b.clone())),
+			(Quantity::ThermalConductivity(a), Quantity::InverseDistance(b)) => Ok(Quantity::ThermalConductance(a.clone() / b.clone())),
+			(Quantity::ThermalConductivity(a), Quantity::SpecificHeatCapacity(b)) => Ok(Quantity::DynamicViscosity(a.clone() / b.clone())),
+			(Quantity::ThermalConductivity(a), Quantity::ThermalConductance(b)) => Ok(Quantity::InverseDistance(a.clone() / b.clone())),
+			(Quantity::ThermalConductivity(a), Quantity::DynamicViscosity(b)) => Ok(Quantity::SpecificHeatCapacity(a.clone() / b.clone())),
+			(Quantity::ThermalResistance(a), Quantity::InverseDistance(b)) => Ok(Quantity::ThermalResistivity(a.clone() / b.clone())),
+			(Quantity::ThermalResistance(a), Quantity::Temperature(b)) => Ok(Quantity::InversePower(a.clone() / b.clone())),
+			(Quantity::ThermalResistance(a), Quantity::TemperatureInterval(b)) => Ok(Quantity::InversePower(a.clone() / b.clone())),
+			(Quantity::ThermalResistance(a), Quantity::Time(b)) => Ok(Quantity::InverseHeatCapacity(a.clone() / b.clone())),
+			(Quantity::ThermalResistance(a), Quantity::InverseCatalyticActivity(b)) => Ok(Quantity::InverseMolarEntropy(a.clone() / b.clone())),
+			(Quantity::ThermalResistance(a), Quantity::InverseEntropy(b)) => Ok(Quantity::Time(a.clone() / b.clone())),
+			(Quantity::ThermalResistance(a), Quantity::InverseHeatCapacity(b)) => Ok(Quantity::Time(a.clone() / b.clone())),
+			(Quantity::ThermalResistance(a), Quantity::InverseMolarEntropy(b)) => Ok(Quantity::InverseCatalyticActivity(a.clone() / b.clone())),
+			(Quantity::ThermalResistance(a), Quantity::InverseSpecificHeatCapacity(b)) => Ok(Quantity::InverseMassFlowRate(a.clone() / b.clone())),
+			(Quantity::ThermalResistance(a), Quantity::ThermalResistivity(b)) => Ok(Quantity::InverseDistance(a.clone() / b.clone())),
+			(Quantity::ThermalResistance(a), Quantity::InverseMassFlowRate(b)) => Ok(Quantity::InverseSpecificHeatCapacity(a.clone() / b.clone())),
+			(Quantity::ThermalResistance(a), Quantity::InversePower(b)) => Ok(Quantity::Temperature(a.clone() / b.clone())),
+			(Quantity::ThermalResistivity(a), Quantity::Distance(b)) => Ok(Quantity::ThermalResistance(a.clone() / b.clone())),
+			(Quantity::ThermalResistivity(a), Quantity::InverseSpecificHeatCapacity(b)) => Ok(Quantity::InverseDynamicViscosity(a.clone() / b.clone())),
+			(Quantity::ThermalResistivity(a), Quantity::ThermalResistance(b)) => Ok(Quantity::Distance(a.clone() / b.clone())),
+			(Quantity::ThermalResistivity(a), Quantity::InverseDynamicViscosity(b)) => Ok(Quantity::InverseSpecificHeatCapacity(a.clone() / b.clone())),
+			(Quantity::AreaPerLumen(a), Quantity::InverseLuminousFlux(b)) => Ok(Quantity::Area(a.clone() / b.clone())),
+			(Quantity::AreaPerLumen(a), Quantity::Area(b)) => Ok(Quantity::InverseLuminousFlux(a.clone() / b.clone())),
+			(Quantity::Capacitance(a), Quantity::Time(b)) => Ok(Quantity::Conductance(a.clone() / b.clone())),
+			(Quantity::Capacitance(a), Quantity::Charge(b)) => Ok(Quantity::InverseVoltage(a.clone() / b.clone())),
+			(Quantity::Capacitance(a), Quantity::Conductance(b)) => Ok(Quantity::Time(a.clone() / b.clone())),
+			(Quantity::Capacitance(a), Quantity::InverseElectricField(b)) => Ok(Quantity::LinearChargeDensity(a.clone() / b.clone())),
+			(Quantity::Capacitance(a), Quantity::InverseVoltage(b)) => Ok(Quantity::Charge(a.clone() / b.clone())),
+			(Quantity::Capacitance(a), Quantity::LinearChargeDensity(b)) => Ok(Quantity::InverseElectricField(a.clone() / b.clone())),
+			(Quantity::Charge(a), Quantity::Current(b)) => Ok(Quantity::Time(a.clone() / b.clone())),
+			(Quantity::Charge(a), Quantity::Distance(b)) => Ok(Quantity::LinearChargeDensity(a.clone() / b.clone())),
+			(Quantity::Charge(a), Quantity::Time(b)) => Ok(Quantity::Current(a.clone() / b.clone())),
+			(Quantity::Charge(a), Quantity::Capacitance(b)) => Ok(Quantity::Voltage(a.clone() / b.clone())),
+			(Quantity::Charge(a), Quantity::Conductance(b)) => Ok(Quantity::MagneticFlux(a.clone() / b.clone())),
+			(Quantity::Charge(a), Quantity::InverseElectricField(b)) => Ok(Quantity::Force(a.clone() / b.clone())),
+			(Quantity::Charge(a), Quantity::InverseMagneticFluxDensity(b)) => Ok(Quantity::MassFlowRate(a.clone() / b.clone())),
+			(Quantity::Charge(a), Quantity::InverseVoltage(b)) => Ok(Quantity::Energy(a.clone() / b.clone())),
+			(Quantity::Charge(a), Quantity::LinearChargeDensity(b)) => Ok(Quantity::Distance(a.clone() / b.clone())),
+			(Quantity::Charge(a), Quantity::MagneticFlux(b)) => Ok(Quantity::Conductance(a.clone() / b.clone())),
+			(Quantity::Charge(a), Quantity::SurfaceChargeDensity(b)) => Ok(Quantity::Area(a.clone() / b.clone())),
+			(Quantity::Charge(a), Quantity::Voltage(b)) => Ok(Quantity::Capacitance(a.clone() / b.clone())),
+			(Quantity::Charge(a), Quantity::VolumeChargeDensity(b)) => Ok(Quantity::Volume(a.clone() / b.clone())),
+			(Quantity::Charge(a), Quantity::Area(b)) => Ok(Quantity::SurfaceChargeDensity(a.clone() / b.clone())),
+			(Quantity::Charge(a), Quantity::Volume(b)) => Ok(Quantity::VolumeChargeDensity(a.clone() / b.clone())),
+			(Quantity::Charge(a), Quantity::Energy(b)) => Ok(Quantity::InverseVoltage(a.clone() / b.clone())),
+			(Quantity::Charge(a), Quantity::Torque(b)) => Ok(Quantity::InverseVoltage(a.clone() / b.clone())),
+			(Quantity::Charge(a), Quantity::Force(b)) => Ok(Quantity::InverseElectricField(a.clone() / b.clone())),
+			(Quantity::Charge(a), Quantity::MassFlowRate(b)) => Ok(Quantity::InverseMagneticFluxDensity(a.clone() / b.clone())),
+			(Quantity::Conductance(a), Quantity::Current(b)) => Ok(Quantity::InverseVoltage(a.clone() / b.clone())),
+			(Quantity::Conductance(a), Quantity::Time(b)) => Ok(Quantity::InverseInductance(a.clone() / b.clone())),
+			(Quantity::Conductance(a), Quantity::Capacitance(b)) => Ok(Quantity::Frequency(a.clone() / b.clone())),
+			(Quantity::Conductance(a), Quantity::Charge(b)) => Ok(Quantity::InverseMagneticFlux(a.clone() / b.clone())),
+			(Quantity::Conductance(a), Quantity::InverseElectricField(b)) => Ok(Quantity::MagneticFieldStrength(a.clone() / b.clone())),
+			(Quantity::Conductance(a), Quantity::InverseInductance(b)) => Ok(Quantity::Time(a.clone() / b.clone())),
+			(Quantity::Conductance(a), Quantity::InverseMagneticFlux(b)) => Ok(Quantity::Charge(a.clone() / b.clone())),
+			(Quantity::Conductance(a), Quantity::InverseMagneticFluxDensity(b)) => Ok(Quantity::SurfaceChargeDensity(a.clone() / b.clone())),
+			(Quantity::Conductance(a), Quantity::InverseVoltage(b)) => Ok(Quantity::Current(a.clone() / b.clone())),
+			(Quantity::Conductance(a), Quantity::MagneticFieldStrength(b)) => Ok(Quantity::InverseElectricField(a.clone() / b.clone())),
+			(Quantity::Conductance(a), Quantity::SurfaceChargeDensity(b)) => Ok(Quantity::InverseMagneticFluxDensity(a.clone() / b.clone())),
+			(Quantity::Conductance(a), Quantity::Frequency(b)) => Ok(Quantity::Capacitance(a.clone() / b.clone())),
+			(Quantity::Elastance(a), Quantity::ElectricField(b)) => Ok(Quantity::InverseLinearChargeDensity(a.clone() / b.clone())),
+			(Quantity::Elastance(a), Quantity::InverseCharge(b)) => Ok(Quantity::Voltage(a.clone() / b.clone())),
+			(Quantity::Elastance(a), Quantity::InverseLinearChargeDensity(b)) => Ok(Quantity::ElectricField(a.clone() / b.clone())),
+			(Quantity::Elastance(a), Quantity::Resistance(b)) => Ok(Quantity::Frequency(a.clone() / b.clone())),
+			(Quantity::Elastance(a), Quantity::Voltage(b)) => Ok(Quantity::InverseCharge(a.clone() / b.clone())),
+			(Quantity::Elastance(a), Quantity::Frequency(b)) => Ok(Quantity::Resistance(a.clone() / b.clone())),
+			(Quantity::ElectricField(a), Quantity::InverseDistance(b)) => Ok(Quantity::Voltage(a.clone() / b.clone())),
+			(Quantity::ElectricField(a), Quantity::Elastance(b)) => Ok(Quantity::LinearChargeDensity(a.clone() / b.clone())),
+			(Quantity::ElectricField(a), Quantity::InverseCharge(b)) => Ok(Quantity::Force(a.clone() / b.clone())),
+			(Quantity::ElectricField(a), Quantity::InverseMagneticFieldStrength(b)) => Ok(Quantity::Irradiance(a.clone() / b.clone())),
+			(Quantity::ElectricField(a), Quantity::InverseSurfaceChargeDensity(b)) => Ok(Quantity::Pressure(a.clone() / b.clone())),
+			(Quantity::ElectricField(a), Quantity::Irradiance(b)) => Ok(Quantity::InverseMagneticFieldStrength(a.clone() / b.clone())),
+			(Quantity::ElectricField(a), Quantity::LinearChargeDensity(b)) => Ok(Quantity::Elastance(a.clone() / b.clone())),
+			(Quantity::ElectricField(a), Quantity::MagneticFieldStrength(b)) => Ok(Quantity::Resistance(a.clone() / b.clone())),
+			(Quantity::ElectricField(a), Quantity::MagneticFluxDensity(b)) => Ok(Quantity::Velocity(a.clone() / b.clone())),
+			(Quantity::ElectricField(a), Quantity::Resistance(b)) => Ok(Quantity::MagneticFieldStrength(a.clone() / b.clone())),
+			(Quantity::ElectricField(a), Quantity::Voltage(b)) => Ok(Quantity::InverseDistance(a.clone() / b.clone())),
+			(Quantity::ElectricField(a), Quantity::Force(b)) => Ok(Quantity::InverseCharge(a.clone() / b.clone())),
+			(Quantity::ElectricField(a), Quantity::Pressure(b)) => Ok(Quantity::InverseSurfaceChargeDensity(a.clone() / b.clone())),
+			(Quantity::ElectricField(a), Quantity::Velocity(b)) => Ok(Quantity::MagneticFluxDensity(a.clone() / b.clone())),
+			(Quantity::Illuminance(a), Quantity::LuminousFlux(b)) => Ok(Quantity::InverseArea(a.clone() / b.clone())),
+			(Quantity::Illuminance(a), Quantity::InverseArea(b)) => Ok(Quantity::LuminousFlux(a.clone() / b.clone())),
+			(Quantity::Inductance(a), Quantity::InverseCurrent(b)) => Ok(Quantity::MagneticFlux(a.clone() / b.clone())),
+			(Quantity::Inductance(a), Quantity::Time(b)) => Ok(Quantity::Resistance(a.clone() / b.clone())),
+			(Quantity::Inductance(a), Quantity::MagneticFlux(b)) => Ok(Quantity::InverseCurrent(a.clone() / b.clone())),
+			(Quantity::Inductance(a), Quantity::Resistance(b)) => Ok(Quantity::Time(a.clone() / b.clone())),
+			(Quantity::InverseCharge(a), Quantity::InverseCurrent(b)) => Ok(Quantity::Frequency(a.clone() / b.clone())),
+			(Quantity::InverseCharge(a), Quantity::InverseDistance(b)) => Ok(Quantity::InverseLinearChargeDensity(a.clone() / b.clone())),
+			(Quantity::InverseCharge(a), Quantity::Elastance(b)) => Ok(Quantity::InverseVoltage(a.clone() / b.clone())),
+			(Quantity::InverseCharge(a), Quantity::ElectricField(b)) => Ok(Quantity::InverseForce(a.clone() / b.clone())),
+			(Quantity::InverseCharge(a), Quantity::InverseLinearChargeDensity(b)) => Ok(Quantity::InverseDistance(a.clone() / b.clone())),
+			(Quantity::InverseCharge(a), Quantity::InverseMagneticFlux(b)) => Ok(Quantity::Resistance(a.clone() / b.clone())),
+			(Quantity::InverseCharge(a), Quantity::InverseSurfaceChargeDensity(b)) => Ok(Quantity::InverseArea(a.clone() / b.clone())),
+			(Quantity::InverseCharge(a), Quantity::InverseVoltage(b)) => Ok(Quantity::Elastance(a.clone() / b.clone())),
+			(Quantity::InverseCharge(a), Quantity::InverseVolumeChargeDensity(b)) => Ok(Quantity::InverseVolume(a.clone() / b.clone())),
+			(Quantity::InverseCharge(a), Quantity::MagneticFluxDensity(b)) => Ok(Quantity::InverseMassFlowRate(a.clone() / b.clone())),
+			(Quantity::InverseCharge(a), Quantity::Resistance(b)) => Ok(Quantity::InverseMagneticFlux(a.clone() / b.clone())),
+			(Quantity::InverseCharge(a), Quantity::Voltage(b)) => Ok(Quantity::InverseEnergy(a.clone() / b.clone())),
+			(Quantity::InverseCharge(a), Quantity::InverseArea(b)) => Ok(Quantity::InverseSurfaceChargeDensity(a.clone() / b.clone())),
+			(Quantity::InverseCharge(a), Quantity::InverseVolume(b)) => Ok(Quantity::InverseVolumeChargeDensity(a.clone() / b.clone())),
+			(Quantity::InverseCharge(a), Quantity::Frequency(b)) => Ok(Quantity::InverseCurrent(a.clone() / b.clone())),
+			(Quantity::InverseCharge(a), Quantity::InverseEnergy(b)) => Ok(Quantity::Voltage(a.clone() / b.clone())),
+			(Quantity::InverseCharge(a), Quantity::InverseTorque(b)) => Ok(Quantity::Voltage(a.clone() / b.clone())),
+			(Quantity::InverseCharge(a), Quantity::InverseForce(b)) => Ok(Quantity::ElectricField(a.clone() / b.clone())),
+			(Quantity::InverseCharge(a), Quantity::InverseMassFlowRate(b)) => Ok(Quantity::MagneticFluxDensity(a.clone() / b.clone())),
+			(Quantity::InverseElectricField(a), Quantity::Distance(b)) => Ok(Quantity::InverseVoltage(a.clone() / b.clone())),
+			(Quantity::InverseElectricField(a), Quantity::Capacitance(b)) => Ok(Quantity::InverseLinearChargeDensity(a.clone() / b.clone())),
+			(Quantity::InverseElectricField(a), Quantity::Charge(b)) => Ok(Quantity::InverseForce(a.clone() / b.clone())),
+			(Quantity::InverseElectricField(a), Quantity::Conductance(b)) => Ok(Quantity::InverseMagneticFieldStrength(a.clone() / b.clone())),
+			(Quantity::InverseElectricField(a), Quantity::InverseIrradiance(b)) => Ok(Quantity::MagneticFieldStrength(a.clone() / b.clone())),
+			(Quantity::InverseElectricField(a), Quantity::InverseLinearChargeDensity(b)) => Ok(Quantity::Capacitance(a.clone() / b.clone())),
+			(Quantity::InverseElectricField(a), Quantity::InverseMagneticFieldStrength(b)) => Ok(Quantity::Conductance(a.clone() / b.clone())),
+			(Quantity::InverseElectricField(a), Quantity::InverseMagneticFluxDensity(b)) => Ok(Quantity::TimePerDistance(a.clone() / b.clone())),
+			(Quantity::InverseElectricField(a), Quantity::InverseVoltage(b)) => Ok(Quantity::Distance(a.clone() / b.clone())),
+			(Quantity::InverseElectricField(a), Quantity::MagneticFieldStrength(b)) => Ok(Quantity::InverseIrradiance(a.clone() / b.clone())),
+			(Quantity::InverseElectricField(a), Quantity::SurfaceChargeDensity(b)) => Ok(Quantity::InversePressure(a.clone() / b.clone())),
+			(Quantity::InverseElectricField(a), Quantity::InverseForce(b)) => Ok(Quantity::Charge(a.clone() / b.clone())),
+			(Quantity::InverseElectricField(a), Quantity::InversePressure(b)) => Ok(Quantity::SurfaceChargeDensity(a.clone() / b.clone())),
+			(Quantity::InverseElectricField(a), Quantity::TimePerDistance(b)) => Ok(Quantity::InverseMagneticFluxDensity(a.clone() / b.clone())),
+			(Quantity::InverseInductance(a), Quantity::Current(b)) => Ok(Quantity::InverseMagneticFlux(a.clone() / b.clone())),
+			(Quantity::InverseInductance(a), Quantity::Conductance(b)) => Ok(Quantity::Frequency(a.clone() / b.clone())),
+			(Quantity::InverseInductance(a), Quantity::InverseMagneticFlux(b)) => Ok(Quantity::Current(a.clone() / b.clone())),
+			(Quantity::InverseInductance(a), Quantity::Frequency(b)) => Ok(Quantity::Conductance(a.clone() / b.clone())),
+			(Quantity::InverseIrradiance(a), Quantity::InverseElectricField(b)) => Ok(Quantity::InverseMagneticFieldStrength(a.clone() / b.clone())),
+			(Quantity::InverseIrradiance(a), Quantity::InverseMagneticFieldStrength(b)) => Ok(Quantity::InverseElectricField(a.clone() / b.clone())),
+			(Quantity::InverseIrradiance(a), Quantity::InverseRadiance(b)) => Ok(Quantity::InverseSolidAngle(a.clone() / b.clone())),
+			(Quantity::InverseIrradiance(a), Quantity::Area(b)) => Ok(Quantity::InversePower(a.clone() / b.clone())),
+			(Quantity::InverseIrradiance(a), Quantity::InverseSolidAngle(b)) => Ok(Quantity::InverseRadiance(a.clone() / b.clone())),
+			(Quantity::InverseIrradiance(a), Quantity::InverseAcceleration(b)) => Ok(Quantity::InverseDynamicViscosity(a.clone() / b.clone())),
+			(Quantity::InverseIrradiance(a), Quantity::InverseDynamicViscosity(b)) => Ok(Quantity::InverseAcceleration(a.clone() / b.clone())),
+			(Quantity::InverseIrradiance(a), Quantity::InversePower(b)) => Ok(Quantity::Area(a.clone() / b.clone())),
+			(Quantity::InverseIrradiance(a), Quantity::InversePressure(b)) => Ok(Quantity::TimePerDistance(a.clone() / b.clone())),
+			(Quantity::InverseIrradiance(a), Quantity::TimePerDistance(b)) => Ok(Quantity::InversePressure(a.clone() / b.clone())),
+			(Quantity::InverseLinearChargeDensity(a), Quantity::Distance(b)) => Ok(Quantity::InverseCharge(a.clone() / b.clone())),
+			(Quantity::InverseLinearChargeDensity(a), Quantity::InverseCurrent(b)) => Ok(Quantity::Velocity(a.clone() / b.clone())),
+			(Quantity::InverseLinearChargeDensity(a), Quantity::InverseDistance(b)) => Ok(Quantity::InverseSurfaceChargeDensity(a.clone() / b.clone())),
+			(Quantity::InverseLinearChargeDensity(a), Quantity::Elastance(b)) => Ok(Quantity::InverseElectricField(a.clone() / b.clone())),
+			(Quantity::InverseLinearChargeDensity(a), Quantity::InverseCharge(b)) => Ok(Quantity::Distance(a.clone() / b.clone())),
+			(Quantity::InverseLinearChargeDensity(a), Quantity::InverseElectricField(b)) => Ok(Quantity::Elastance(a.clone() / b.clone())),
+			(Quantity::InverseLinearChargeDensity(a), Quantity::InverseMagneticFieldStrength(b)) => Ok(Quantity::Frequency(a.clone() / b.clone())),
+			(Quantity::InverseLinearChargeDensity(a), Quantity::InverseSurfaceChargeDensity(b)) => Ok(Quantity::InverseDistance(a.clone() / b.clone())),
+			(Quantity::InverseLinearChargeDensity(a), Quantity::InverseVolumeChargeDensity(b)) => Ok(Quantity::InverseArea(a.clone() / b.clone())),
+			(Quantity::InverseLinearChargeDensity(a), Quantity::MagneticFlux(b)) => Ok(Quantity::InverseMomentum(a.clone() / b.clone())),
+			(Quantity::InverseLinearChargeDensity(a), Quantity::MagneticFluxDensity(b)) => Ok(Quantity::InverseDynamicViscosity(a.clone() / b.clone())),
+			(Quantity::InverseLinearChargeDensity(a), Quantity::Voltage(b)) => Ok(Quantity::InverseForce(a.clone() / b.clone())),
+			(Quantity::InverseLinearChargeDensity(a), Quantity::InverseArea(b)) => Ok(Quantity::InverseVolumeChargeDensity(a.clone() / b.clone())),
+			(Quantity::InverseLinearChargeDensity(a), Quantity::Frequency(b)) => Ok(Quantity::InverseMagneticFieldStrength(a.clone() / b.clone())),
+			(Quantity::InverseLinearChargeDensity(a), Quantity::InverseDynamicViscosity(b)) => Ok(Quantity::MagneticFluxDensity(a.clone() / b.clone())),
+			(Quantity::InverseLinearChargeDensity(a), Quantity::InverseForce(b)) => Ok(Quantity::Voltage(a.clone() / b.clone())),
+			(Quantity::InverseLinearChargeDensity(a), Quantity::InverseMomentum(b)) => Ok(Quantity::MagneticFlux(a.clone() / b.clone())),
+			(Quantity::InverseLinearChargeDensity(a), Quantity::Velocity(b)) => Ok(Quantity::InverseCurrent(a.clone() / b.clone())),
+			(Quantity::InverseLuminousFlux(a), Quantity::InverseLuminosity(b)) => Ok(Quantity::InverseSolidAngle(a.clone() / b.clone())),
+			(Quantity::InverseLuminousFlux(a), Quantity::AreaPerLumen(b)) => Ok(Quantity::InverseArea(a.clone() / b.clone())),
+			(Quantity::InverseLuminousFlux(a), Quantity::InverseArea(b)) => Ok(Quantity::AreaPerLumen(a.clone() / b.clone())),
+			(Quantity::InverseLuminousFlux(a), Quantity::InverseSolidAngle(b)) => Ok(Quantity::InverseLuminosity(a.clone() / b.clone())),
+			(Quantity::InverseMagneticFieldStrength(a), Quantity::Distance(b)) => Ok(Quantity::InverseCurrent(a.clone() / b.clone())),
+			(Quantity::InverseMagneticFieldStrength(a), Quantity::InverseCurrent(b)) => Ok(Quantity::Distance(a.clone() / b.clone())),
+			(Quantity::InverseMagneticFieldStrength(a), Quantity::Time(b)) => Ok(Quantity::InverseLinearChargeDensity(a.clone() / b.clone())),
+			(Quantity::InverseMagneticFieldStrength(a), Quantity::ElectricField(b)) => Ok(Quantity::InverseIrradiance(a.clone() / b.clone())),
+			(Quantity::InverseMagneticFieldStrength(a), Quantity::InverseElectricField(b)) => Ok(Quantity::Resistance(a.clone() / b.clone())),
+			(Quantity::InverseMagneticFieldStrength(a), Quantity::InverseIrradiance(b)) => Ok(Quantity::ElectricField(a.clone() / b.clone())),
+			(Quantity::InverseMagneticFieldStrength(a), Quantity::InverseLinearChargeDensity(b)) => Ok(Quantity::Time(a.clone() / b.clone())),
+			(Quantity::InverseMagneticFieldStrength(a), Quantity::InverseSurfaceChargeDensity(b)) => Ok(Quantity::TimePerDistance(a.clone() / b.clone())),
+			(Quantity::InverseMagneticFieldStrength(a), Quantity::InverseVolumeChargeDensity(b)) => Ok(Quantity::InverseKinematicViscosity(a.clone() / b.clone())),
+			(Quantity::InverseMagneticFieldStrength(a), Quantity::MagneticFlux(b)) => Ok(Quantity::InverseForce(a.clone() / b.clone())),
+			(Quantity::InverseMagneticFieldStrength(a), Quantity::MagneticFluxDensity(b)) => Ok(Quantity::InversePressure(a.clone() / b.clone())),
+			(Quantity::InverseMagneticFieldStrength(a), Quantity::Resistance(b)) => Ok(Quantity::InverseElectricField(a.clone() / b.clone())),
+			(Quantity::InverseMagneticFieldStrength(a), Quantity::InverseForce(b)) => Ok(Quantity::MagneticFlux(a.clone() / b.clone())),
+			(Quantity::InverseMagneticFieldStrength(a), Quantity::InverseKinematicViscosity(b)) => Ok(Quantity::InverseVolumeChargeDensity(a.clone() / b.clone())),
+			(Quantity::InverseMagneticFieldStrength(a), Quantity::InversePressure(b)) => Ok(Quantity::MagneticFluxDensity(a.clone() / b.clone())),
+			(Quantity::InverseMagneticFieldStrength(a), Quantity::TimePerDistance(b)) => Ok(Quantity::InverseSurfaceChargeDensity(a.clone() / b.clone())),
+			(Quantity::InverseMagneticFlux(a), Quantity::Current(b)) => Ok(Quantity::InverseEnergy(a.clone() / b.clone())),
+			(Quantity::InverseMagneticFlux(a), Quantity::InverseCurrent(b)) => Ok(Quantity::InverseInductance(a.clone() / b.clone())),
+			(Quantity::InverseMagneticFlux(a), Quantity::Conductance(b)) => Ok(Quantity::InverseCharge(a.clone() / b.clone())),
+			(Quantity::InverseMagneticFlux(a), Quantity::InverseCharge(b)) => Ok(Quantity::Conductance(a.clone() / b.clone())),
+			(Quantity::InverseMagneticFlux(a), Quantity::InverseInductance(b)) => Ok(Quantity::InverseCurrent(a.clone() / b.clone())),
+			(Quantity::InverseMagneticFlux(a), Quantity::InverseMagneticFluxDensity(b)) => Ok(Quantity::InverseArea(a.clone() / b.clone())),
+			(Quantity::InverseMagneticFlux(a), Quantity::InverseVoltage(b)) => Ok(Quantity::Frequency(a.clone() / b.clone())),
+			(Quantity::InverseMagneticFlux(a), Quantity::LinearChargeDensity(b)) => Ok(Quantity::InverseMomentum(a.clone() / b.clone())),
+			(Quantity::InverseMagneticFlux(a), Quantity::MagneticFieldStrength(b)) => Ok(Quantity::InverseForce(a.clone() / b.clone())),
+			(Quantity::InverseMagneticFlux(a), Quantity::SurfaceChargeDensity(b)) => Ok(Quantity::InverseMassFlowRate(a.clone() / b.clone())),
+			(Quantity::InverseMagneticFlux(a), Quantity::VolumeChargeDensity(b)) => Ok(Quantity::InverseDynamicViscosity(a.clone() / b.clone())),
+			(Quantity::InverseMagneticFlux(a), Quantity::InverseArea(b)) => Ok(Quantity::InverseMagneticFluxDensity(a.clone() / b.clone())),
+			(Quantity::InverseMagneticFlux(a), Quantity::Frequency(b)) => Ok(Quantity::InverseVoltage(a.clone() / b.clone())),
+			(Quantity::InverseMagneticFlux(a), Quantity::InverseDynamicViscosity(b)) => Ok(Quantity::VolumeChargeDensity(a.clone() / b.clone())),
+			(Quantity::InverseMagneticFlux(a), Quantity::InverseEnergy(b)) => Ok(Quantity::Current(a.clone() / b.clone())),
+			(Quantity::InverseMagneticFlux(a), Quantity::InverseTorque(b)) => Ok(Quantity::Current(a.clone() / b.clone())),
+			(Quantity::InverseMagneticFlux(a), Quantity::InverseForce(b)) => Ok(Quantity::MagneticFieldStrength(a.clone() / b.clone())),
+			(Quantity::InverseMagneticFlux(a), Quantity::InverseMassFlowRate(b)) => Ok(Quantity::SurfaceChargeDensity(a.clone() / b.clone())),
+			(Quantity::InverseMagneticFlux(a), Quantity::InverseMomentum(b)) => Ok(Quantity::LinearChargeDensity(a.clone() / b.clone())),
+			(Quantity::InverseMagneticFluxDensity(a), Quantity::Charge(b)) => Ok(Quantity::InverseMassFlowRate(a.clone() / b.clone())),
+			(Quantity::InverseMagneticFluxDensity(a), Quantity::Conductance(b)) => Ok(Quantity::InverseSurfaceChargeDensity(a.clone() / b.clone())),
+			(Quantity::InverseMagneticFluxDensity(a), Quantity::InverseElectricField(b)) => Ok(Quantity::Velocity(a.clone() / b.clone())),
+			(Quantity::InverseMagneticFluxDensity(a), Quantity::InverseMagneticFlux(b)) => Ok(Quantity::Area(a.clone() / b.clone())),
+			(Quantity::InverseMagneticFluxDensity(a), Quantity::InverseSurfaceChargeDensity(b)) => Ok(Quantity::Conductance(a.clone() / b.clone())),
+			(Quantity::InverseMagneticFluxDensity(a), Quantity::InverseVoltage(b)) => Ok(Quantity::KinematicViscosity(a.clone() / b.clone())),
+			(Quantity::InverseMagneticFluxDensity(a), Quantity::LinearChargeDensity(b)) => Ok(Quantity::InverseDynamicViscosity(a.clone() / b.clone())),
+			(Quantity::InverseMagneticFluxDensity(a), Quantity::MagneticFieldStrength(b)) => Ok(Quantity::InversePressure(a.clone() / b.clone())),
+			(Quantity::InverseMagneticFluxDensity(a), Quantity::Area(b)) => Ok(Quantity::InverseMagneticFlux(a.clone() / b.clone())),
+			(Quantity::InverseMagneticFluxDensity(a), Quantity::InverseDynamicViscosity(b)) => Ok(Quantity::LinearChargeDensity(a.clone() / b.clone())),
+			(Quantity::InverseMagneticFluxDensity(a), Quantity::InverseMassFlowRate(b)) => Ok(Quantity::Charge(a.clone() / b.clone())),
+			(Quantity::InverseMagneticFluxDensity(a), Quantity::InversePressure(b)) => Ok(Quantity::MagneticFieldStrength(a.clone() / b.clone())),
+			(Quantity::InverseMagneticFluxDensity(a), Quantity::KinematicViscosity(b)) => Ok(Quantity::InverseVoltage(a.clone() / b.clone())),
+			(Quantity::InverseMagneticFluxDensity(a), Quantity::Velocity(b)) => Ok(Quantity::InverseElectricField(a.clone() / b.clone())),
+			(Quantity::InverseRadiance(a), Quantity::InverseIrradiance(b)) => Ok(Quantity::SolidAngle(a.clone() / b.clone())),
+			(Quantity::InverseRadiance(a), Quantity::SolidAngle(b)) => Ok(Quantity::InverseIrradiance(a.clone() / b.clone())),
+			(Quantity::InverseSurfaceChargeDensity(a), Quantity::Distance(b)) => Ok(Quantity::InverseLinearChargeDensity(a.clone() / b.clone())),
+			(Quantity::InverseSurfaceChargeDensity(a), Quantity::InverseCurrent(b)) => Ok(Quantity::KinematicViscosity(a.clone() / b.clone())),
+			(Quantity::InverseSurfaceChargeDensity(a), Quantity::InverseDistance(b)) => Ok(Quantity::InverseVolumeChargeDensity(a.clone() / b.clone())),
+			(Quantity::InverseSurfaceChargeDensity(a), Quantity::ElectricField(b)) => Ok(Quantity::InversePressure(a.clone() / b.clone())),
+			(Quantity::InverseSurfaceChargeDensity(a), Quantity::InverseCharge(b)) => Ok(Quantity::Area(a.clone() / b.clone())),
+			(Quantity::InverseSurfaceChargeDensity(a), Quantity::InverseLinearChargeDensity(b)) => Ok(Quantity::Distance(a.clone() / b.clone())),
+			(Quantity::InverseSurfaceChargeDensity(a), Quantity::InverseMagneticFieldStrength(b)) => Ok(Quantity::Velocity(a.clone() / b.clone())),
+			(Quantity::InverseSurfaceChargeDensity(a), Quantity::InverseMagneticFluxDensity(b)) => Ok(Quantity::Resistance(a.clone() / b.clone())),
+			(Quantity::InverseSurfaceChargeDensity(a), Quantity::InverseVolumeChargeDensity(b)) => Ok(Quantity::InverseDistance(a.clone() / b.clone())),
+			(Quantity::InverseSurfaceChargeDensity(a), Quantity::MagneticFlux(b)) => Ok(Quantity::InverseMassFlowRate(a.clone() / b.clone())),
+			(Quantity::InverseSurfaceChargeDensity(a), Quantity::Resistance(b)) => Ok(Quantity::InverseMagneticFluxDensity(a.clone() / b.clone())),
+			(Quantity::InverseSurfaceChargeDensity(a), Quantity::Area(b)) => Ok(Quantity::InverseCharge(a.clone() / b.clone())),
+			(Quantity::InverseSurfaceChargeDensity(a), Quantity::InverseMassFlowRate(b)) => Ok(Quantity::MagneticFlux(a.clone() / b.clone())),
+			(Quantity::InverseSurfaceChargeDensity(a), Quantity::InversePressure(b)) => Ok(Quantity::ElectricField(a.clone() / b.clone())),
+			(Quantity::InverseSurfaceChargeDensity(a), Quantity::KinematicViscosity(b)) => Ok(Quantity::InverseCurrent(a.clone() / b.clone())),
+			(Quantity::InverseSurfaceChargeDensity(a), Quantity::Velocity(b)) => Ok(Quantity::InverseMagneticFieldStrength(a.clone() / b.clone())),
+			(Quantity::InverseVoltage(a), Quantity::Current(b)) => Ok(Quantity::InversePower(a.clone() / b.clone())),
+			(Quantity::InverseVoltage(a), Quantity::InverseCurrent(b)) => Ok(Quantity::Conductance(a.clone() / b.clone())),
+			(Quantity::InverseVoltage(a), Quantity::InverseDistance(b)) => Ok(Quantity::InverseElectricField(a.clone() / b.clone())),
+			(Quantity::InverseVoltage(a), Quantity::Time(b)) => Ok(Quantity::InverseMagneticFlux(a.clone() / b.clone())),
+			(Quantity::InverseVoltage(a), Quantity::Capacitance(b)) => Ok(Quantity::InverseCharge(a.clone() / b.clone())),
+			(Quantity::InverseVoltage(a), Quantity::Charge(b)) => Ok(Quantity::InverseEnergy(a.clone() / b.clone())),
+			(Quantity::InverseVoltage(a), Quantity::Conductance(b)) => Ok(Quantity::InverseCurrent(a.clone() / b.clone())),
+			(Quantity::InverseVoltage(a), Quantity::InverseCharge(b)) => Ok(Quantity::Capacitance(a.clone() / b.clone())),
+			(Quantity::InverseVoltage(a), Quantity::InverseElectricField(b)) => Ok(Quantity::InverseDistance(a.clone() / b.clone())),
+			(Quantity::InverseVoltage(a), Quantity::InverseMagneticFlux(b)) => Ok(Quantity::Time(a.clone() / b.clone())),
+			(Quantity::InverseVoltage(a), Quantity::InverseMagneticFluxDensity(b)) => Ok(Quantity::InverseKinematicViscosity(a.clone() / b.clone())),
+			(Quantity::InverseVoltage(a), Quantity::LinearChargeDensity(b)) => Ok(Quantity::InverseForce(a.clone() / b.clone())),
+			(Quantity::InverseVoltage(a), Quantity::VolumeChargeDensity(b)) => Ok(Quantity::InversePressure(a.clone() / b.clone())),
+			(Quantity::InverseVoltage(a), Quantity::InverseEnergy(b)) => Ok(Quantity::Charge(a.clone() / b.clone())),
+			(Quantity::InverseVoltage(a), Quantity::InverseTorque(b)) => Ok(Quantity::Charge(a.clone() / b.clone())),
+			(Quantity::InverseVoltage(a), Quantity::InverseForce(b)) => Ok(Quantity::LinearChargeDensity(a.clone() / b.clone())),
+			(Quantity::InverseVoltage(a), Quantity::InverseKinematicViscosity(b)) => Ok(Quantity::InverseMagneticFluxDensity(a.clone() / b.clone())),
+			(Quantity::InverseVoltage(a), Quantity::InversePower(b)) => Ok(Quantity::Current(a.clone() / b.clone())),
+			(Quantity::InverseVoltage(a), Quantity::InversePressure(b)) => Ok(Quantity::VolumeChargeDensity(a.clone() / b.clone())),
+			(Quantity::InverseVolumeChargeDensity(a), Quantity::Distance(b)) => Ok(Quantity::InverseSurfaceChargeDensity(a.clone() / b.clone())),
+			(Quantity::InverseVolumeChargeDensity(a), Quantity::InverseCurrent(b)) => Ok(Quantity::VolumetricFlowRate(a.clone() / b.clone())),
+			(Quantity::InverseVolumeChargeDensity(a), Quantity::InverseCharge(b)) => Ok(Quantity::Volume(a.clone() / b.clone())),
+			(Quantity::InverseVolumeChargeDensity(a), Quantity::InverseLinearChargeDensity(b)) => Ok(Quantity::Area(a.clone() / b.clone())),
+			(Quantity::InverseVolumeChargeDensity(a), Quantity::InverseMagneticFieldStrength(b)) => Ok(Quantity::KinematicViscosity(a.clone() / b.clone())),
+			(Quantity::InverseVolumeChargeDensity(a), Quantity::InverseSurfaceChargeDensity(b)) => Ok(Quantity::Distance(a.clone() / b.clone())),
+			(Quantity::InverseVolumeChargeDensity(a), Quantity::MagneticFlux(b)) => Ok(Quantity::InverseDynamicViscosity(a.clone() / b.clone())),
+			(Quantity::InverseVolumeChargeDensity(a), Quantity::Voltage(b)) => Ok(Quantity::InversePressure(a.clone() / b.clone())),
+			(Quantity::InverseVolumeChargeDensity(a), Quantity::Area(b)) => Ok(Quantity::InverseLinearChargeDensity(a.clone() / b.clone())),
+			(Quantity::InverseVolumeChargeDensity(a), Quantity::Volume(b)) => Ok(Quantity::InverseCharge(a.clone() / b.clone())),
+			(Quantity::InverseVolumeChargeDensity(a), Quantity::InverseDynamicViscosity(b)) => Ok(Quantity::MagneticFlux(a.clone() / b.clone())),
+			(Quantity::InverseVolumeChargeDensity(a), Quantity::InversePressure(b)) => Ok(Quantity::Voltage(a.clone() / b.clone())),
+			(Quantity::InverseVolumeChargeDensity(a), Quantity::KinematicViscosity(b)) => Ok(Quantity::InverseMagneticFieldStrength(a.clone() / b.clone())),
+			(Quantity::InverseVolumeChargeDensity(a), Quantity::VolumetricFlowRate(b)) => Ok(Quantity::InverseCurrent(a.clone() / b.clone())),
+			(Quantity::Irradiance(a), Quantity::ElectricField(b)) => Ok(Quantity::MagneticFieldStrength(a.clone() / b.clone())),
+			(Quantity::Irradiance(a), Quantity::MagneticFieldStrength(b)) => Ok(Quantity::ElectricField(a.clone() / b.clone())),
+			(Quantity::Irradiance(a), Quantity::Radiance(b)) => Ok(Quantity::SolidAngle(a.clone() / b.clone())),
+			(Quantity::Irradiance(a), Quantity::InverseArea(b)) => Ok(Quantity::Power(a.clone() / b.clone())),
+			(Quantity::Irradiance(a), Quantity::SolidAngle(b)) => Ok(Quantity::Radiance(a.clone() / b.clone())),
+			(Quantity::Irradiance(a), Quantity::Acceleration(b)) => Ok(Quantity::DynamicViscosity(a.clone() / b.clone())),
+			(Quantity::Irradiance(a), Quantity::DynamicViscosity(b)) => Ok(Quantity::Acceleration(a.clone() / b.clone())),
+			(Quantity::Irradiance(a), Quantity::Power(b)) => Ok(Quantity::InverseArea(a.clone() / b.clone())),
+			(Quantity::Irradiance(a), Quantity::Pressure(b)) => Ok(Quantity::Velocity(a.clone() / b.clone())),
+			(Quantity::Irradiance(a), Quantity::Velocity(b)) => Ok(Quantity::Pressure(a.clone() / b.clone())),
+			(Quantity::LinearChargeDensity(a), Quantity::Current(b)) => Ok(Quantity::TimePerDistance(a.clone() / b.clone())),
+			(Quantity::LinearChargeDensity(a), Quantity::Distance(b)) => Ok(Quantity::SurfaceChargeDensity(a.clone() / b.clone())),
+			(Quantity::LinearChargeDensity(a), Quantity::InverseDistance(b)) => Ok(Quantity::Charge(a.clone() / b.clone())),
+			(Quantity::LinearChargeDensity(a), Quantity::Time(b)) => Ok(Quantity::MagneticFieldStrength(a.clone() / b.clone())),
+			(Quantity::LinearChargeDensity(a), Quantity::Capacitance(b)) => Ok(Quantity::ElectricField(a.clone() / b.clone())),
+			(Quantity::LinearChargeDensity(a), Quantity::Charge(b)) => Ok(Quantity::InverseDistance(a.clone() / b.clone())),
+			(Quantity::LinearChargeDensity(a), Quantity::ElectricField(b)) => Ok(Quantity::Capacitance(a.clone() / b.clone())),
+			(Quantity::LinearChargeDensity(a), Quantity::InverseMagneticFlux(b)) => Ok(Quantity::Momentum(a.clone() / b.clone())),
+			(Quantity::LinearChargeDensity(a), Quantity::InverseMagneticFluxDensity(b)) => Ok(Quantity::DynamicViscosity(a.clone() / b.clone())),
+			(Quantity::LinearChargeDensity(a), Quantity::InverseVoltage(b)) => Ok(Quantity::Force(a.clone() / b.clone())),
+			(Quantity::LinearChargeDensity(a), Quantity::MagneticFieldStrength(b)) => Ok(Quantity::Time(a.clone() / b.clone())),
+			(Quantity::LinearChargeDensity(a), Quantity::SurfaceChargeDensity(b)) => Ok(Quantity::Distance(a.clone() / b.clone())),
+			(Quantity::LinearChargeDensity(a), Quantity::VolumeChargeDensity(b)) => Ok(Quantity::Area(a.clone() / b.clone())),
+			(Quantity::LinearChargeDensity(a), Quantity::Area(b)) => Ok(Quantity::VolumeChargeDensity(a.clone() / b.clone())),
+			(Quantity::LinearChargeDensity(a), Quantity::DynamicViscosity(b)) => Ok(Quantity::InverseMagneticFluxDensity(a.clone() / b.clone())),
+			(Quantity::LinearChargeDensity(a), Quantity::Force(b)) => Ok(Quantity::InverseVoltage(a.clone() / b.clone())),
+			(Quantity::LinearChargeDensity(a), Quantity::Momentum(b)) => Ok(Quantity::InverseMagneticFlux(a.clone() / b.clone())),
+			(Quantity::LinearChargeDensity(a), Quantity::TimePerDistance(b)) => Ok(Quantity::Current(a.clone() / b.clone())),
+			(Quantity::LuminousFlux(a), Quantity::Luminosity(b)) => Ok(Quantity::SolidAngle(a.clone() / b.clone())),
+			(Quantity::LuminousFlux(a), Quantity::Illuminance(b)) => Ok(Quantity::Area(a.clone() / b.clone())),
+			(Quantity::LuminousFlux(a), Quantity::Area(b)) => Ok(Quantity::Illuminance(a.clone() / b.clone())),
+			(Quantity::LuminousFlux(a), Quantity::SolidAngle(b)) => Ok(Quantity::Luminosity(a.clone() / b.clone())),
+			(Quantity::MagneticFieldStrength(a), Quantity::Current(b)) => Ok(Quantity::InverseDistance(a.clone() / b.clone())),
+			(Quantity::MagneticFieldStrength(a), Quantity::InverseDistance(b)) => Ok(Quantity::Current(a.clone() / b.clone())),
+			(Quantity::MagneticFieldStrength(a), Quantity::Conductance(b)) => Ok(Quantity::ElectricField(a.clone() / b.clone())),
+			(Quantity::MagneticFieldStrength(a), Quantity::ElectricField(b)) => Ok(Quantity::Conductance(a.clone() / b.clone())),
+			(Quantity::MagneticFieldStrength(a), Quantity::InverseElectricField(b)) => Ok(Quantity::Irradiance(a.clone() / b.clone())),
+			(Quantity::MagneticFieldStrength(a), Quantity::InverseMagneticFlux(b)) => Ok(Quantity::Force(a.clone() / b.clone())),
+			(Quantity::MagneticFieldStrength(a), Quantity::InverseMagneticFluxDensity(b)) => Ok(Quantity::Pressure(a.clone() / b.clone())),
+			(Quantity::MagneticFieldStrength(a), Quantity::Irradiance(b)) => Ok(Quantity::InverseElectricField(a.clone() / b.clone())),
+			(Quantity::MagneticFieldStrength(a), Quantity::LinearChargeDensity(b)) => Ok(Quantity::Frequency(a.clone() / b.clone())),
+			(Quantity::MagneticFieldStrength(a), Quantity::SurfaceChargeDensity(b)) => Ok(Quantity::Velocity(a.clone() / b.clone())),
+			(Quantity::MagneticFieldStrength(a), Quantity::VolumeChargeDensity(b)) => Ok(Quantity::KinematicViscosity(a.clone() / b.clone())),
+			(Quantity::MagneticFieldStrength(a), Quantity::Force(b)) => Ok(Quantity::InverseMagneticFlux(a.clone() / b.clone())),
+			(Quantity::MagneticFieldStrength(a), Quantity::Frequency(b)) => Ok(Quantity::LinearChargeDensity(a.clone() / b.clone())),
+			(Quantity::MagneticFieldStrength(a), Quantity::KinematicViscosity(b)) => Ok(Quantity::VolumeChargeDensity(a.clone() / b.clone())),
+			(Quantity::MagneticFieldStrength(a), Quantity::Pressure(b)) => Ok(Quantity::InverseMagneticFluxDensity(a.clone() / b.clone())),
+			(Quantity::MagneticFieldStrength(a), Quantity::Velocity(b)) => Ok(Quantity::SurfaceChargeDensity(a.clone() / b.clone())),
+			(Quantity::MagneticFlux(a), Quantity::Current(b)) => Ok(Quantity::Inductance(a.clone() / b.clone())),
+			(Quantity::MagneticFlux(a), Quantity::InverseCurrent(b)) => Ok(Quantity::Energy(a.clone() / b.clone())),
+			(Quantity::MagneticFlux(a), Quantity::Time(b)) => Ok(Quantity::Voltage(a.clone() / b.clone())),
+			(Quantity::MagneticFlux(a), Quantity::Charge(b)) => Ok(Quantity::Resistance(a.clone() / b.clone())),
+			(Quantity::MagneticFlux(a), Quantity::Inductance(b)) => Ok(Quantity::Current(a.clone() / b.clone())),
+			(Quantity::MagneticFlux(a), Quantity::InverseLinearChargeDensity(b)) => Ok(Quantity::Momentum(a.clone() / b.clone())),
+			(Quantity::MagneticFlux(a), Quantity::InverseMagneticFieldStrength(b)) => Ok(Quantity::Force(a.clone() / b.clone())),
+			(Quantity::MagneticFlux(a), Quantity::InverseSurfaceChargeDensity(b)) => Ok(Quantity::MassFlowRate(a.clone() / b.clone())),
+			(Quantity::MagneticFlux(a), Quantity::InverseVolumeChargeDensity(b)) => Ok(Quantity::DynamicViscosity(a.clone() / b.clone())),
+			(Quantity::MagneticFlux(a), Quantity::MagneticFluxDensity(b)) => Ok(Quantity::Area(a.clone() / b.clone())),
+			(Quantity::MagneticFlux(a), Quantity::Resistance(b)) => Ok(Quantity::Charge(a.clone() / b.clone())),
+			(Quantity::MagneticFlux(a), Quantity::Voltage(b)) => Ok(Quantity::Time(a.clone() / b.clone())),
+			(Quantity::MagneticFlux(a), Quantity::Area(b)) => Ok(Quantity::MagneticFluxDensity(a.clone() / b.clone())),
+			(Quantity::MagneticFlux(a), Quantity::DynamicViscosity(b)) => Ok(Quantity::InverseVolumeChargeDensity(a.clone() / b.clone())),
+			(Quantity::MagneticFlux(a), Quantity::Energy(b)) => Ok(Quantity::InverseCurrent(a.clone() / b.clone())),
+			(Quantity::MagneticFlux(a), Quantity::Torque(b)) => Ok(Quantity::InverseCurrent(a.clone() / b.clone())),
+			(Quantity::MagneticFlux(a), Quantity::Force(b)) => Ok(Quantity::InverseMagneticFieldStrength(a.clone() / b.clone())),
+			(Quantity::MagneticFlux(a), Quantity::MassFlowRate(b)) => Ok(Quantity::InverseSurfaceChargeDensity(a.clone() / b.clone())),
+			(Quantity::MagneticFlux(a), Quantity::Momentum(b)) => Ok(Quantity::InverseLinearChargeDensity(a.clone() / b.clone())),
+			(Quantity::MagneticFluxDensity(a), Quantity::ElectricField(b)) => Ok(Quantity::TimePerDistance(a.clone() / b.clone())),
+			(Quantity::MagneticFluxDensity(a), Quantity::InverseCharge(b)) => Ok(Quantity::MassFlowRate(a.clone() / b.clone())),
+			(Quantity::MagneticFluxDensity(a), Quantity::InverseLinearChargeDensity(b)) => Ok(Quantity::DynamicViscosity(a.clone() / b.clone())),
+			(Quantity::MagneticFluxDensity(a), Quantity::InverseMagneticFieldStrength(b)) => Ok(Quantity::Pressure(a.clone() / b.clone())),
+			(Quantity::MagneticFluxDensity(a), Quantity::MagneticFlux(b)) => Ok(Quantity::InverseArea(a.clone() / b.clone())),
+			(Quantity::MagneticFluxDensity(a), Quantity::Resistance(b)) => Ok(Quantity::SurfaceChargeDensity(a.clone() / b.clone())),
+			(Quantity::MagneticFluxDensity(a), Quantity::SurfaceChargeDensity(b)) => Ok(Quantity::Resistance(a.clone() / b.clone())),
+			(Quantity::MagneticFluxDensity(a), Quantity::Voltage(b)) => Ok(Quantity::InverseKinematicViscosity(a.clone() / b.clone())),
+			(Quantity::MagneticFluxDensity(a), Quantity::InverseArea(b)) => Ok(Quantity::MagneticFlux(a.clone() / b.clone())),
+			(Quantity::MagneticFluxDensity(a), Quantity::DynamicViscosity(b)) => Ok(Quantity::InverseLinearChargeDensity(a.clone() / b.clone())),
+			(Quantity::MagneticFluxDensity(a), Quantity::InverseKinematicViscosity(b)) => Ok(Quantity::Voltage(a.clone() / b.clone())),
+			(Quantity::MagneticFluxDensity(a), Quantity::MassFlowRate(b)) => Ok(Quantity::InverseCharge(a.clone() / b.clone())),
+			(Quantity::MagneticFluxDensity(a), Quantity::Pressure(b)) => Ok(Quantity::InverseMagneticFieldStrength(a.clone() / b.clone())),
+			(Quantity::MagneticFluxDensity(a), Quantity::TimePerDistance(b)) => Ok(Quantity::ElectricField(a.clone() / b.clone())),
+			(Quantity::Radiance(a), Quantity::Irradiance(b)) => Ok(Quantity::InverseSolidAngle(a.clone() / b.clone())),
+			(Quantity::Radiance(a), Quantity::InverseSolidAngle(b)) => Ok(Quantity::Irradiance(a.clone() / b.clone())),
+			(Quantity::Resistance(a), Quantity::InverseCurrent(b)) => Ok(Quantity::Voltage(a.clone() / b.clone())),
+			(Quantity::Resistance(a), Quantity::Time(b)) => Ok(Quantity::Elastance(a.clone() / b.clone())),
+			(Quantity::Resistance(a), Quantity::Elastance(b)) => Ok(Quantity::Time(a.clone() / b.clone())),
+			(Quantity::Resistance(a), Quantity::ElectricField(b)) => Ok(Quantity::InverseMagneticFieldStrength(a.clone() / b.clone())),
+			(Quantity::Resistance(a), Quantity::Inductance(b)) => Ok(Quantity::Frequency(a.clone() / b.clone())),
+			(Quantity::Resistance(a), Quantity::InverseCharge(b)) => Ok(Quantity::MagneticFlux(a.clone() / b.clone())),
+			(Quantity::Resistance(a), Quantity::InverseMagneticFieldStrength(b)) => Ok(Quantity::ElectricField(a.clone() / b.clone())),
+			(Quantity::Resistance(a), Quantity::InverseSurfaceChargeDensity(b)) => Ok(Quantity::MagneticFluxDensity(a.clone() / b.clone())),
+			(Quantity::Resistance(a), Quantity::MagneticFlux(b)) => Ok(Quantity::InverseCharge(a.clone() / b.clone())),
+			(Quantity::Resistance(a), Quantity::MagneticFluxDensity(b)) => Ok(Quantity::InverseSurfaceChargeDensity(a.clone() / b.clone())),
+			(Quantity::Resistance(a), Quantity::Voltage(b)) => Ok(Quantity::InverseCurrent(a.clone() / b.clone())),
+			(Quantity::Resistance(a), Quantity::Frequency(b)) => Ok(Quantity::Inductance(a.clone() / b.clone())),
+			(Quantity::SurfaceChargeDensity(a), Quantity::Current(b)) => Ok(Quantity::InverseKinematicViscosity(a.clone() / b.clone())),
+			(Quantity::SurfaceChargeDensity(a), Quantity::Distance(b)) => Ok(Quantity::VolumeChargeDensity(a.clone() / b.clone())),
+			(Quantity::SurfaceChargeDensity(a), Quantity::InverseDistance(b)) => Ok(Quantity::LinearChargeDensity(a.clone() / b.clone())),
+			(Quantity::SurfaceChargeDensity(a), Quantity::Charge(b)) => Ok(Quantity::InverseArea(a.clone() / b.clone())),
+			(Quantity::SurfaceChargeDensity(a), Quantity::Conductance(b)) => Ok(Quantity::MagneticFluxDensity(a.clone() / b.clone())),
+			(Quantity::SurfaceChargeDensity(a), Quantity::InverseElectricField(b)) => Ok(Quantity::Pressure(a.clone() / b.clone())),
+			(Quantity::SurfaceChargeDensity(a), Quantity::InverseMagneticFlux(b)) => Ok(Quantity::MassFlowRate(a.clone() / b.clone())),
+			(Quantity::SurfaceChargeDensity(a), Quantity::LinearChargeDensity(b)) => Ok(Quantity::InverseDistance(a.clone() / b.clone())),
+			(Quantity::SurfaceChargeDensity(a), Quantity::MagneticFieldStrength(b)) => Ok(Quantity::TimePerDistance(a.clone() / b.clone())),
+			(Quantity::SurfaceChargeDensity(a), Quantity::MagneticFluxDensity(b)) => Ok(Quantity::Conductance(a.clone() / b.clone())),
+			(Quantity::SurfaceChargeDensity(a), Quantity::VolumeChargeDensity(b)) => Ok(Quantity::Distance(a.clone() / b.clone())),
+			(Quantity::SurfaceChargeDensity(a), Quantity::InverseArea(b)) => Ok(Quantity::Charge(a.clone() / b.clone())),
+			(Quantity::SurfaceChargeDensity(a), Quantity::InverseKinematicViscosity(b)) => Ok(Quantity::Current(a.clone() / b.clone())),
+			(Quantity::SurfaceChargeDensity(a), Quantity::MassFlowRate(b)) => Ok(Quantity::InverseMagneticFlux(a.clone() / b.clone())),
+			(Quantity::SurfaceChargeDensity(a), Quantity::Pressure(b)) => Ok(Quantity::InverseElectricField(a.clone() / b.clone())),
+			(Quantity::SurfaceChargeDensity(a), Quantity::TimePerDistance(b)) => Ok(Quantity::MagneticFieldStrength(a.clone() / b.clone())),
+			(Quantity::Voltage(a), Quantity::Current(b)) => Ok(Quantity::Resistance(a.clone() / b.clone())),
+			(Quantity::Voltage(a), Quantity::Distance(b)) => Ok(Quantity::ElectricField(a.clone() / b.clone())),
+			(Quantity::Voltage(a), Quantity::InverseCurrent(b)) => Ok(Quantity::Power(a.clone() / b.clone())),
+			(Quantity::Voltage(a), Quantity::Charge(b)) => Ok(Quantity::Elastance(a.clone() / b.clone())),
+			(Quantity::Voltage(a), Quantity::Elastance(b)) => Ok(Quantity::Charge(a.clone() / b.clone())),
+			(Quantity::Voltage(a), Quantity::ElectricField(b)) => Ok(Quantity::Distance(a.clone() / b.clone())),
+			(Quantity::Voltage(a), Quantity::InverseCharge(b)) => Ok(Quantity::Energy(a.clone() / b.clone())),
+			(Quantity::Voltage(a), Quantity::InverseLinearChargeDensity(b)) => Ok(Quantity::Force(a.clone() / b.clone())),
+			(Quantity::Voltage(a), Quantity::InverseVolumeChargeDensity(b)) => Ok(Quantity::Pressure(a.clone() / b.clone())),
+			(Quantity::Voltage(a), Quantity::MagneticFlux(b)) => Ok(Quantity::Frequency(a.clone() / b.clone())),
+			(Quantity::Voltage(a), Quantity::MagneticFluxDensity(b)) => Ok(Quantity::KinematicViscosity(a.clone() / b.clone())),
+			(Quantity::Voltage(a), Quantity::Resistance(b)) => Ok(Quantity::Current(a.clone() / b.clone())),
+			(Quantity::Voltage(a), Quantity::Energy(b)) => Ok(Quantity::InverseCharge(a.clone() / b.clone())),
+			(Quantity::Voltage(a), Quantity::Torque(b)) => Ok(Quantity::InverseCharge(a.clone() / b.clone())),
+			(Quantity::Voltage(a), Quantity::Force(b)) => Ok(Quantity::InverseLinearChargeDensity(a.clone() / b.clone())),
+			(Quantity::Voltage(a), Quantity::Frequency(b)) => Ok(Quantity::MagneticFlux(a.clone() / b.clone())),
+			(Quantity::Voltage(a), Quantity::KinematicViscosity(b)) => Ok(Quantity::MagneticFluxDensity(a.clone() / b.clone())),
+			(Quantity::Voltage(a), Quantity::Power(b)) => Ok(Quantity::InverseCurrent(a.clone() / b.clone())),
+			(Quantity::Voltage(a), Quantity::Pressure(b)) => Ok(Quantity::InverseVolumeChargeDensity(a.clone() / b.clone())),
+			(Quantity::VolumeChargeDensity(a), Quantity::Current(b)) => Ok(Quantity::InverseVolumetricFlowRate(a.clone() / b.clone())),
+			(Quantity::VolumeChargeDensity(a), Quantity::InverseDistance(b)) => Ok(Quantity::SurfaceChargeDensity(a.clone() / b.clone())),
+			(Quantity::VolumeChargeDensity(a), Quantity::Charge(b)) => Ok(Quantity::InverseVolume(a.clone() / b.clone())),
+			(Quantity::VolumeChargeDensity(a), Quantity::InverseMagneticFlux(b)) => Ok(Quantity::DynamicViscosity(a.clone() / b.clone())),
+			(Quantity::VolumeChargeDensity(a), Quantity::InverseVoltage(b)) => Ok(Quantity::Pressure(a.clone() / b.clone())),
+			(Quantity::VolumeChargeDensity(a), Quantity::LinearChargeDensity(b)) => Ok(Quantity::InverseArea(a.clone() / b.clone())),
+			(Quantity::VolumeChargeDensity(a), Quantity::MagneticFieldStrength(b)) => Ok(Quantity::InverseKinematicViscosity(a.clone() / b.clone())),
+			(Quantity::VolumeChargeDensity(a), Quantity::SurfaceChargeDensity(b)) => Ok(Quantity::InverseDistance(a.clone() / b.clone())),
+			(Quantity::VolumeChargeDensity(a), Quantity::InverseArea(b)) => Ok(Quantity::LinearChargeDensity(a.clone() / b.clone())),
+			(Quantity::VolumeChargeDensity(a), Quantity::InverseVolume(b)) => Ok(Quantity::Charge(a.clone() / b.clone())),
+			(Quantity::VolumeChargeDensity(a), Quantity::DynamicViscosity(b)) => Ok(Quantity::InverseMagneticFlux(a.clone() / b.clone())),
+			(Quantity::VolumeChargeDensity(a), Quantity::InverseKinematicViscosity(b)) => Ok(Quantity::MagneticFieldStrength(a.clone() / b.clone())),
+			(Quantity::VolumeChargeDensity(a), Quantity::InverseVolumetricFlowRate(b)) => Ok(Quantity::Current(a.clone() / b.clone())),
+			(Quantity::VolumeChargeDensity(a), Quantity::Pressure(b)) => Ok(Quantity::InverseVoltage(a.clone() / b.clone())),
+			(Quantity::Angle(a), Quantity::Time(b)) => Ok(Quantity::AngularVelocity(a.clone() / b.clone())),
+			(Quantity::Angle(a), Quantity::InverseAngle(b)) => Ok(Quantity::SolidAngle(a.clone() / b.clone())),
+			(Quantity::Angle(a), Quantity::SolidAngle(b)) => Ok(Quantity::InverseAngle(a.clone() / b.clone())),
+			(Quantity::Angle(a), Quantity::AngularVelocity(b)) => Ok(Quantity::Time(a.clone() / b.clone())),
+			(Quantity::Area(a), Quantity::Distance(b)) => Ok(Quantity::Distance(a.clone() / b.clone())),
+			(Quantity::Area(a), Quantity::InverseDistance(b)) => Ok(Quantity::Volume(a.clone() / b.clone())),
+			(Quantity::Area(a), Quantity::Mass(b)) => Ok(Quantity::AreaPerMass(a.clone() / b.clone())),
+			(Quantity::Area(a), Quantity::Time(b)) => Ok(Quantity::KinematicViscosity(a.clone() / b.clone())),
+			(Quantity::Area(a), Quantity::AreaPerLumen(b)) => Ok(Quantity::LuminousFlux(a.clone() / b.clone())),
+			(Quantity::Area(a), Quantity::Charge(b)) => Ok(Quantity::InverseSurfaceChargeDensity(a.clone() / b.clone())),
+			(Quantity::Area(a), Quantity::InverseIrradiance(b)) => Ok(Quantity::Power(a.clone() / b.clone())),
+			(Quantity::Area(a), Quantity::InverseMagneticFluxDensity(b)) => Ok(Quantity::MagneticFlux(a.clone() / b.clone())),
+			(Quantity::Area(a), Quantity::InverseSurfaceChargeDensity(b)) => Ok(Quantity::Charge(a.clone() / b.clone())),
+			(Quantity::Area(a), Quantity::InverseVolumeChargeDensity(b)) => Ok(Quantity::LinearChargeDensity(a.clone() / b.clone())),
+			(Quantity::Area(a), Quantity::LinearChargeDensity(b)) => Ok(Quantity::InverseVolumeChargeDensity(a.clone() / b.clone())),
+			(Quantity::Area(a), Quantity::LuminousFlux(b)) => Ok(Quantity::AreaPerLumen(a.clone() / b.clone())),
+			(Quantity::Area(a), Quantity::MagneticFlux(b)) => Ok(Quantity::InverseMagneticFluxDensity(a.clone() / b.clone())),
+			(Quantity::Area(a), Quantity::Volume(b)) => Ok(Quantity::InverseDistance(a.clone() / b.clone())),
+			(Quantity::Area(a), Quantity::AreaPerMass(b)) => Ok(Quantity::Mass(a.clone() / b.clone())),
+			(Quantity::Area(a), Quantity::Force(b)) => Ok(Quantity::InversePressure(a.clone() / b.clone())),
+			(Quantity::Area(a), Quantity::InverseDynamicViscosity(b)) => Ok(Quantity::Momentum(a.clone() / b.clone())),
+			(Quantity::Area(a), Quantity::InversePressure(b)) => Ok(Quantity::Force(a.clone() / b.clone())),
+			(Quantity::Area(a), Quantity::KinematicViscosity(b)) => Ok(Quantity::Time(a.clone() / b.clone())),
+			(Quantity::Area(a), Quantity::MomentOfInertia(b)) => Ok(Quantity::InverseMass(a.clone() / b.clone())),
+			(Quantity::Area(a), Quantity::Momentum(b)) => Ok(Quantity::InverseDynamicViscosity(a.clone() / b.clone())),
+			(Quantity::Area(a), Quantity::Power(b)) => Ok(Quantity::InverseIrradiance(a.clone() / b.clone())),
+			(Quantity::Area(a), Quantity::TimePerDistance(b)) => Ok(Quantity::VolumetricFlowRate(a.clone() / b.clone())),
+			(Quantity::Area(a), Quantity::VolumetricFlowRate(b)) => Ok(Quantity::TimePerDistance(a.clone() / b.clone())),
+			(Quantity::InverseAngle(a), Quantity::Angle(b)) => Ok(Quantity::InverseSolidAngle(a.clone() / b.clone())),
+			(Quantity::InverseAngle(a), Quantity::InverseSolidAngle(b)) => Ok(Quantity::Angle(a.clone() / b.clone())),
+			(Quantity::InverseAngle(a), Quantity::Frequency(b)) => Ok(Quantity::InverseAngularVelocity(a.clone() / b.clone())),
+			(Quantity::InverseAngle(a), Quantity::InverseAngularVelocity(b)) => Ok(Quantity::Frequency(a.clone() / b.clone())),
+			(Quantity::InverseArea(a), Quantity::Distance(b)) => Ok(Quantity::InverseVolume(a.clone() / b.clone())),
+			(Quantity::InverseArea(a), Quantity::InverseDistance(b)) => Ok(Quantity::InverseDistance(a.clone() / b.clone())),
+			(Quantity::InverseArea(a), Quantity::InverseMass(b)) => Ok(Quantity::AreaDensity(a.clone() / b.clone())),
+			(Quantity::InverseArea(a), Quantity::Illuminance(b)) => Ok(Quantity::InverseLuminousFlux(a.clone() / b.clone())),
+			(Quantity::InverseArea(a), Quantity::InverseCharge(b)) => Ok(Quantity::SurfaceChargeDensity(a.clone() / b.clone())),
+			(Quantity::InverseArea(a), Quantity::InverseLinearChargeDensity(b)) => Ok(Quantity::VolumeChargeDensity(a.clone() / b.clone())),
+			(Quantity::InverseArea(a), Quantity::InverseLuminousFlux(b)) => Ok(Quantity::Illuminance(a.clone() / b.clone())),
+			(Quantity::InverseArea(a), Quantity::InverseMagneticFlux(b)) => Ok(Quantity::MagneticFluxDensity(a.clone() / b.clone())),
+			(Quantity::InverseArea(a), Quantity::Irradiance(b)) => Ok(Quantity::InversePower(a.clone() / b.clone())),
+			(Quantity::InverseArea(a), Quantity::MagneticFluxDensity(b)) => Ok(Quantity::InverseMagneticFlux(a.clone() / b.clone())),
+			(Quantity::InverseArea(a), Quantity::SurfaceChargeDensity(b)) => Ok(Quantity::InverseCharge(a.clone() / b.clone())),
+			(Quantity::InverseArea(a), Quantity::VolumeChargeDensity(b)) => Ok(Quantity::InverseLinearChargeDensity(a.clone() / b.clone())),
+			(Quantity::InverseArea(a), Quantity::InverseVolume(b)) => Ok(Quantity::Distance(a.clone() / b.clone())),
+			(Quantity::InverseArea(a), Quantity::AreaDensity(b)) => Ok(Quantity::InverseMass(a.clone() / b.clone())),
+			(Quantity::InverseArea(a), Quantity::DynamicViscosity(b)) => Ok(Quantity::InverseMomentum(a.clone() / b.clone())),
+			(Quantity::InverseArea(a), Quantity::Frequency(b)) => Ok(Quantity::InverseKinematicViscosity(a.clone() / b.clone())),
+			(Quantity::InverseArea(a), Quantity::InverseForce(b)) => Ok(Quantity::Pressure(a.clone() / b.clone())),
+			(Quantity::InverseArea(a), Quantity::InverseKinematicViscosity(b)) => Ok(Quantity::Frequency(a.clone() / b.clone())),
+			(Quantity::InverseArea(a), Quantity::InverseMomentOfInertia(b)) => Ok(Quantity::Mass(a.clone() / b.clone())),
+			(Quantity::InverseArea(a), Quantity::InverseMomentum(b)) => Ok(Quantity::DynamicViscosity(a.clone() / b.clone())),
+			(Quantity::InverseArea(a), Quantity::InversePower(b)) => Ok(Quantity::Irradiance(a.clone() / b.clone())),
+			(Quantity::InverseArea(a), Quantity::InverseVolumetricFlowRate(b)) => Ok(Quantity::Velocity(a.clone() / b.clone())),
+			(Quantity::InverseArea(a), Quantity::Pressure(b)) => Ok(Quantity::InverseForce(a.clone() / b.clone())),
+			(Quantity::InverseArea(a), Quantity::Velocity(b)) => Ok(Quantity::InverseVolumetricFlowRate(a.clone() / b.clone())),
+			(Quantity::InverseSolidAngle(a), Quantity::Luminosity(b)) => Ok(Quantity::InverseLuminousFlux(a.clone() / b.clone())),
+			(Quantity::InverseSolidAngle(a), Quantity::InverseIrradiance(b)) => Ok(Quantity::Radiance(a.clone() / b.clone())),
+			(Quantity::InverseSolidAngle(a), Quantity::InverseLuminousFlux(b)) => Ok(Quantity::Luminosity(a.clone() / b.clone())),
+			(Quantity::InverseSolidAngle(a), Quantity::Radiance(b)) => Ok(Quantity::InverseIrradiance(a.clone() / b.clone())),
+			(Quantity::InverseSolidAngle(a), Quantity::InverseAngle(b)) => Ok(Quantity::InverseAngle(a.clone() / b.clone())),
+			(Quantity::InverseVolume(a), Quantity::InverseAmount(b)) => Ok(Quantity::Concentration(a.clone() / b.clone())),
+			(Quantity::InverseVolume(a), Quantity::InverseDistance(b)) => Ok(Quantity::InverseArea(a.clone() / b.clone())),
+			(Quantity::InverseVolume(a), Quantity::InverseMass(b)) => Ok(Quantity::Density(a.clone() / b.clone())),
+			(Quantity::InverseVolume(a), Quantity::Concentration(b)) => Ok(Quantity::InverseAmount(a.clone() / b.clone())),
+			(Quantity::InverseVolume(a), Quantity::InverseCharge(b)) => Ok(Quantity::VolumeChargeDensity(a.clone() / b.clone())),
+			(Quantity::InverseVolume(a), Quantity::VolumeChargeDensity(b)) => Ok(Quantity::InverseCharge(a.clone() / b.clone())),
+			(Quantity::InverseVolume(a), Quantity::InverseArea(b)) => Ok(Quantity::InverseDistance(a.clone() / b.clone())),
+			(Quantity::InverseVolume(a), Quantity::Density(b)) => Ok(Quantity::InverseMass(a.clone() / b.clone())),
+			(Quantity::InverseVolume(a), Quantity::Frequency(b)) => Ok(Quantity::InverseVolumetricFlowRate(a.clone() / b.clone())),
+			(Quantity::InverseVolume(a), Quantity::InverseEnergy(b)) => Ok(Quantity::Pressure(a.clone() / b.clone())),
+			(Quantity::InverseVolume(a), Quantity::InverseTorque(b)) => Ok(Quantity::Pressure(a.clone() / b.clone())),
+			(Quantity::InverseVolume(a), Quantity::InverseVolumetricFlowRate(b)) => Ok(Quantity::Frequency(a.clone() / b.clone())),
+			(Quantity::InverseVolume(a), Quantity::Pressure(b)) => Ok(Quantity::InverseEnergy(a.clone() / b.clone())),
+			(Quantity::SolidAngle(a), Quantity::InverseLuminosity(b)) => Ok(Quantity::LuminousFlux(a.clone() / b.clone())),
+			(Quantity::SolidAngle(a), Quantity::InverseRadiance(b)) => Ok(Quantity::Irradiance(a.clone() / b.clone())),
+			(Quantity::SolidAngle(a), Quantity::Irradiance(b)) => Ok(Quantity::InverseRadiance(a.clone() / b.clone())),
+			(Quantity::SolidAngle(a), Quantity::LuminousFlux(b)) => Ok(Quantity::InverseLuminosity(a.clone() / b.clone())),
+			(Quantity::SolidAngle(a), Quantity::Angle(b)) => Ok(Quantity::Angle(a.clone() / b.clone())),
+			(Quantity::Volume(a), Quantity::Amount(b)) => Ok(Quantity::MolarVolume(a.clone() / b.clone())),
+			(Quantity::Volume(a), Quantity::Distance(b)) => Ok(Quantity::Area(a.clone() / b.clone())),
+			(Quantity::Volume(a), Quantity::Mass(b)) => Ok(Quantity::VolumePerMass(a.clone() / b.clone())),
+			(Quantity::Volume(a), Quantity::Time(b)) => Ok(Quantity::VolumetricFlowRate(a.clone() / b.clone())),
+			(Quantity::Volume(a), Quantity::MolarVolume(b)) => Ok(Quantity::Amount(a.clone() / b.clone())),
+			(Quantity::Volume(a), Quantity::Charge(b)) => Ok(Quantity::InverseVolumeChargeDensity(a.clone() / b.clone())),
+			(Quantity::Volume(a), Quantity::InverseVolumeChargeDensity(b)) => Ok(Quantity::Charge(a.clone() / b.clone())),
+			(Quantity::Volume(a), Quantity::Area(b)) => Ok(Quantity::Distance(a.clone() / b.clone())),
+			(Quantity::Volume(a), Quantity::Energy(b)) => Ok(Quantity::InversePressure(a.clone() / b.clone())),
+			(Quantity::Volume(a), Quantity::Torque(b)) => Ok(Quantity::InversePressure(a.clone() / b.clone())),
+			(Quantity::Volume(a), Quantity::InversePressure(b)) => Ok(Quantity::Energy(a.clone() / b.clone())),
+			(Quantity::Volume(a), Quantity::VolumePerMass(b)) => Ok(Quantity::Mass(a.clone() / b.clone())),
+			(Quantity::Volume(a), Quantity::VolumetricFlowRate(b)) => Ok(Quantity::Time(a.clone() / b.clone())),
+			(Quantity::Acceleration(a), Quantity::InverseMass(b)) => Ok(Quantity::Force(a.clone() / b.clone())),
+			(Quantity::Acceleration(a), Quantity::Time(b)) => Ok(Quantity::Jerk(a.clone() / b.clone())),
+			(Quantity::Acceleration(a), Quantity::Irradiance(b)) => Ok(Quantity::InverseDynamicViscosity(a.clone() / b.clone())),
+			(Quantity::Acceleration(a), Quantity::AreaPerMass(b)) => Ok(Quantity::Pressure(a.clone() / b.clone())),
+			(Quantity::Acceleration(a), Quantity::Force(b)) => Ok(Quantity::InverseMass(a.clone() / b.clone())),
+			(Quantity::Acceleration(a), Quantity::Frequency(b)) => Ok(Quantity::Velocity(a.clone() / b.clone())),
+			(Quantity::Acceleration(a), Quantity::InverseDynamicViscosity(b)) => Ok(Quantity::Irradiance(a.clone() / b.clone())),
+			(Quantity::Acceleration(a), Quantity::InverseMomentum(b)) => Ok(Quantity::Power(a.clone() / b.clone())),
+			(Quantity::Acceleration(a), Quantity::Jerk(b)) => Ok(Quantity::Time(a.clone() / b.clone())),
+			(Quantity::Acceleration(a), Quantity::Power(b)) => Ok(Quantity::InverseMomentum(a.clone() / b.clone())),
+			(Quantity::Acceleration(a), Quantity::Pressure(b)) => Ok(Quantity::AreaPerMass(a.clone() / b.clone())),
+			(Quantity::Acceleration(a), Quantity::Velocity(b)) => Ok(Quantity::Frequency(a.clone() / b.clone())),
+			(Quantity::AngularAcceleration(a), Quantity::AngularVelocity(b)) => Ok(Quantity::Frequency(a.clone() / b.clone())),
+			(Quantity::AngularAcceleration(a), Quantity::Frequency(b)) => Ok(Quantity::AngularVelocity(a.clone() / b.clone())),
+			(Quantity::AngularMomentum(a), Quantity::MomentOfInertia(b)) => Ok(Quantity::AngularVelocity(a.clone() / b.clone())),
+			(Quantity::AngularVelocity(a), Quantity::Time(b)) => Ok(Quantity::AngularAcceleration(a.clone() / b.clone())),
+			(Quantity::AngularVelocity(a), Quantity::Angle(b)) => Ok(Quantity::Frequency(a.clone() / b.clone())),
+			(Quantity::AngularVelocity(a), Quantity::AngularAcceleration(b)) => Ok(Quantity::Time(a.clone() / b.clone())),
+			(Quantity::AngularVelocity(a), Quantity::Frequency(b)) => Ok(Quantity::Angle(a.clone() / b.clone())),
+			(Quantity::AngularVelocity(a), Quantity::InverseMomentOfInertia(b)) => Ok(Quantity::AngularMomentum(a.clone() / b.clone())),
+			(Quantity::AreaDensity(a), Quantity::Distance(b)) => Ok(Quantity::Density(a.clone() / b.clone())),
+			(Quantity::AreaDensity(a), Quantity::Mass(b)) => Ok(Quantity::InverseArea(a.clone() / b.clone())),
+			(Quantity::AreaDensity(a), Quantity::InverseArea(b)) => Ok(Quantity::Mass(a.clone() / b.clone())),
+			(Quantity::AreaDensity(a), Quantity::Density(b)) => Ok(Quantity::Distance(a.clone() / b.clone())),
+			(Quantity::AreaDensity(a), Quantity::DynamicViscosity(b)) => Ok(Quantity::TimePerDistance(a.clone() / b.clone())),
+			(Quantity::AreaDensity(a), Quantity::InverseAcceleration(b)) => Ok(Quantity::Pressure(a.clone() / b.clone())),
+			(Quantity::AreaDensity(a), Quantity::InverseKinematicViscosity(b)) => Ok(Quantity::MassFlowRate(a.clone() / b.clone())),
+			(Quantity::AreaDensity(a), Quantity::InverseVolumetricFlowRate(b)) => Ok(Quantity::Momentum(a.clone() / b.clone())),
+			(Quantity::AreaDensity(a), Quantity::MassFlowRate(b)) => Ok(Quantity::InverseKinematicViscosity(a.clone() / b.clone())),
+			(Quantity::AreaDensity(a), Quantity::Momentum(b)) => Ok(Quantity::InverseVolumetricFlowRate(a.clone() / b.clone())),
+			(Quantity::AreaDensity(a), Quantity::Pressure(b)) => Ok(Quantity::InverseAcceleration(a.clone() / b.clone())),
+			(Quantity::AreaDensity(a), Quantity::TimePerDistance(b)) => Ok(Quantity::DynamicViscosity(a.clone() / b.clone())),
+			(Quantity::AreaPerMass(a), Quantity::InverseDistance(b)) => Ok(Quantity::VolumePerMass(a.clone() / b.clone())),
+			(Quantity::AreaPerMass(a), Quantity::InverseMass(b)) => Ok(Quantity::Area(a.clone() / b.clone())),
+			(Quantity::AreaPerMass(a), Quantity::Area(b)) => Ok(Quantity::InverseMass(a.clone() / b.clone())),
+			(Quantity::AreaPerMass(a), Quantity::Acceleration(b)) => Ok(Quantity::InversePressure(a.clone() / b.clone())),
+			(Quantity::AreaPerMass(a), Quantity::InverseDynamicViscosity(b)) => Ok(Quantity::Velocity(a.clone() / b.clone())),
+			(Quantity::AreaPerMass(a), Quantity::InverseMassFlowRate(b)) => Ok(Quantity::KinematicViscosity(a.clone() / b.clone())),
+			(Quantity::AreaPerMass(a), Quantity::InverseMomentum(b)) => Ok(Quantity::VolumetricFlowRate(a.clone() / b.clone())),
+			(Quantity::AreaPerMass(a), Quantity::InversePressure(b)) => Ok(Quantity::Acceleration(a.clone() / b.clone())),
+			(Quantity::AreaPerMass(a), Quantity::KinematicViscosity(b)) => Ok(Quantity::InverseMassFlowRate(a.clone() / b.clone())),
+			(Quantity::AreaPerMass(a), Quantity::Velocity(b)) => Ok(Quantity::InverseDynamicViscosity(a.clone() / b.clone())),
+			(Quantity::AreaPerMass(a), Quantity::VolumePerMass(b)) => Ok(Quantity::InverseDistance(a.clone() / b.clone())),
+			(Quantity::AreaPerMass(a), Quantity::VolumetricFlowRate(b)) => Ok(Quantity::InverseMomentum(a.clone() / b.clone())),
+			(Quantity::Density(a), Quantity::InverseDistance(b)) => Ok(Quantity::AreaDensity(a.clone() / b.clone())),
+			(Quantity::Density(a), Quantity::Mass(b)) => Ok(Quantity::InverseVolume(a.clone() / b.clone())),
+			(Quantity::Density(a), Quantity::Concentration(b)) => Ok(Quantity::MolarMass(a.clone() / b.clone())),
+			(Quantity::Density(a), Quantity::MolarMass(b)) => Ok(Quantity::Concentration(a.clone() / b.clone())),
+			(Quantity::Density(a), Quantity::InverseVolume(b)) => Ok(Quantity::Mass(a.clone() / b.clone())),
+			(Quantity::Density(a), Quantity::AreaDensity(b)) => Ok(Quantity::InverseDistance(a.clone() / b.clone())),
+			(Quantity::Density(a), Quantity::DynamicViscosity(b)) => Ok(Quantity::InverseKinematicViscosity(a.clone() / b.clone())),
+			(Quantity::Density(a), Quantity::InverseKinematicViscosity(b)) => Ok(Quantity::DynamicViscosity(a.clone() / b.clone())),
+			(Quantity::Density(a), Quantity::InverseVolumetricFlowRate(b)) => Ok(Quantity::MassFlowRate(a.clone() / b.clone())),
+			(Quantity::Density(a), Quantity::MassFlowRate(b)) => Ok(Quantity::InverseVolumetricFlowRate(a.clone() / b.clone())),
+			(Quantity::Density(a), Quantity::InverseAbsorbedDose(b)) => Ok(Quantity::Pressure(a.clone() / b.clone())),
+			(Quantity::Density(a), Quantity::InverseDoseEquivalent(b)) => Ok(Quantity::Pressure(a.clone() / b.clone())),
+			(Quantity::DynamicViscosity(a), Quantity::InverseDistance(b)) => Ok(Quantity::MassFlowRate(a.clone() / b.clone())),
+			(Quantity::DynamicViscosity(a), Quantity::Time(b)) => Ok(Quantity::Pressure(a.clone() / b.clone())),
+			(Quantity::DynamicViscosity(a), Quantity::InverseSpecificHeatCapacity(b)) => Ok(Quantity::ThermalConductivity(a.clone() / b.clone())),
+			(Quantity::DynamicViscosity(a), Quantity::ThermalConductivity(b)) => Ok(Quantity::InverseSpecificHeatCapacity(a.clone() / b.clone())),
+			(Quantity::DynamicViscosity(a), Quantity::Irradiance(b)) => Ok(Quantity::InverseAcceleration(a.clone() / b.clone())),
+			(Quantity::DynamicViscosity(a), Quantity::LinearChargeDensity(b)) => Ok(Quantity::MagneticFluxDensity(a.clone() / b.clone())),
+			(Quantity::DynamicViscosity(a), Quantity::MagneticFlux(b)) => Ok(Quantity::VolumeChargeDensity(a.clone() / b.clone())),
+			(Quantity::DynamicViscosity(a), Quantity::MagneticFluxDensity(b)) => Ok(Quantity::LinearChargeDensity(a.clone() / b.clone())),
+			(Quantity::DynamicViscosity(a), Quantity::VolumeChargeDensity(b)) => Ok(Quantity::MagneticFlux(a.clone() / b.clone())),
+			(Quantity::DynamicViscosity(a), Quantity::InverseArea(b)) => Ok(Quantity::Momentum(a.clone() / b.clone())),
+			(Quantity::DynamicViscosity(a), Quantity::AreaDensity(b)) => Ok(Quantity::Velocity(a.clone() / b.clone())),
+			(Quantity::DynamicViscosity(a), Quantity::Density(b)) => Ok(Quantity::KinematicViscosity(a.clone() / b.clone())),
+			(Quantity::DynamicViscosity(a), Quantity::Energy(b)) => Ok(Quantity::InverseVolumetricFlowRate(a.clone() / b.clone())),
+			(Quantity::DynamicViscosity(a), Quantity::Torque(b)) => Ok(Quantity::InverseVolumetricFlowRate(a.clone() / b.clone())),
+			(Quantity::DynamicViscosity(a), Quantity::Force(b)) => Ok(Quantity::InverseKinematicViscosity(a.clone() / b.clone())),
+			(Quantity::DynamicViscosity(a), Quantity::InverseAcceleration(b)) => Ok(Quantity::Irradiance(a.clone() / b.clone())),
+			(Quantity::DynamicViscosity(a), Quantity::InverseKinematicViscosity(b)) => Ok(Quantity::Force(a.clone() / b.clone())),
+			(Quantity::DynamicViscosity(a), Quantity::InverseVolumetricFlowRate(b)) => Ok(Quantity::Energy(a.clone() / b.clone())),
+			(Quantity::DynamicViscosity(a), Quantity::KinematicViscosity(b)) => Ok(Quantity::Density(a.clone() / b.clone())),
+			(Quantity::DynamicViscosity(a), Quantity::MassFlowRate(b)) => Ok(Quantity::InverseDistance(a.clone() / b.clone())),
+			(Quantity::DynamicViscosity(a), Quantity::Momentum(b)) => Ok(Quantity::InverseArea(a.clone() / b.clone())),
+			(Quantity::DynamicViscosity(a), Quantity::Pressure(b)) => Ok(Quantity::Time(a.clone() / b.clone())),
+			(Quantity::DynamicViscosity(a), Quantity::Velocity(b)) => Ok(Quantity::AreaDensity(a.clone() / b.clone())),
+			(Quantity::Energy(a), Quantity::Amount(b)) => Ok(Quantity::MolarEnergy(a.clone() / b.clone())),
+			(Quantity::Energy(a), Quantity::Current(b)) => Ok(Quantity::MagneticFlux(a.clone() / b.clone())),
+			(Quantity::Energy(a), Quantity::Distance(b)) => Ok(Quantity::Force(a.clone() / b.clone())),
+			(Quantity::Energy(a), Quantity::Temperature(b)) => Ok(Quantity::HeatCapacity(a.clone() / b.clone())),
+			(Quantity::Energy(a), Quantity::TemperatureInterval(b)) => Ok(Quantity::HeatCapacity(a.clone() / b.clone())),
+			(Quantity::Energy(a), Quantity::Time(b)) => Ok(Quantity::Power(a.clone() / b.clone())),
+			(Quantity::Energy(a), Quantity::Entropy(b)) => Ok(Quantity::Temperature(a.clone() / b.clone())),
+			(Quantity::Energy(a), Quantity::HeatCapacity(b)) => Ok(Quantity::Temperature(a.clone() / b.clone())),
+			(Quantity::Energy(a), Quantity::MolarEnergy(b)) => Ok(Quantity::Amount(a.clone() / b.clone())),
+			(Quantity::Energy(a), Quantity::Charge(b)) => Ok(Quantity::Voltage(a.clone() / b.clone())),
+			(Quantity::Energy(a), Quantity::MagneticFlux(b)) => Ok(Quantity::Current(a.clone() / b.clone())),
+			(Quantity::Energy(a), Quantity::Voltage(b)) => Ok(Quantity::Charge(a.clone() / b.clone())),
+			(Quantity::Energy(a), Quantity::Volume(b)) => Ok(Quantity::Pressure(a.clone() / b.clone())),
+			(Quantity::Energy(a), Quantity::DynamicViscosity(b)) => Ok(Quantity::VolumetricFlowRate(a.clone() / b.clone())),
+			(Quantity::Energy(a), Quantity::Force(b)) => Ok(Quantity::Distance(a.clone() / b.clone())),
+			(Quantity::Energy(a), Quantity::KinematicViscosity(b)) => Ok(Quantity::MassFlowRate(a.clone() / b.clone())),
+			(Quantity::Energy(a), Quantity::MassFlowRate(b)) => Ok(Quantity::KinematicViscosity(a.clone() / b.clone())),
+			(Quantity::Energy(a), Quantity::Momentum(b)) => Ok(Quantity::Velocity(a.clone() / b.clone())),
+			(Quantity::Energy(a), Quantity::Power(b)) => Ok(Quantity::Time(a.clone() / b.clone())),
+			(Quantity::Energy(a), Quantity::Pressure(b)) => Ok(Quantity::Volume(a.clone() / b.clone())),
+			(Quantity::Energy(a), Quantity::Velocity(b)) => Ok(Quantity::Momentum(a.clone() / b.clone())),
+			(Quantity::Energy(a), Quantity::VolumetricFlowRate(b)) => Ok(Quantity::DynamicViscosity(a.clone() / b.clone())),
+			(Quantity::Energy(a), Quantity::AbsorbedDose(b)) => Ok(Quantity::Mass(a.clone() / b.clone())),
+			(Quantity::Energy(a), Quantity::DoseEquivalent(b)) => Ok(Quantity::Mass(a.clone() / b.clone())),
+			(Quantity::Force(a), Quantity::InverseDistance(b)) => Ok(Quantity::Energy(a.clone() / b.clone())),
+			(Quantity::Force(a), Quantity::Mass(b)) => Ok(Quantity::Acceleration(a.clone() / b.clone())),
+			(Quantity::Force(a), Quantity::Charge(b)) => Ok(Quantity::ElectricField(a.clone() / b.clone())),
+			(Quantity::Force(a), Quantity::ElectricField(b)) => Ok(Quantity::Charge(a.clone() / b.clone())),
+			(Quantity::Force(a), Quantity::LinearChargeDensity(b)) => Ok(Quantity::Voltage(a.clone() / b.clone())),
+			(Quantity::Force(a), Quantity::MagneticFieldStrength(b)) => Ok(Quantity::MagneticFlux(a.clone() / b.clone())),
+			(Quantity::Force(a), Quantity::MagneticFlux(b)) => Ok(Quantity::MagneticFieldStrength(a.clone() / b.clone())),
+			(Quantity::Force(a), Quantity::Voltage(b)) => Ok(Quantity::LinearChargeDensity(a.clone() / b.clone())),
+			(Quantity::Force(a), Quantity::Area(b)) => Ok(Quantity::Pressure(a.clone() / b.clone())),
+			(Quantity::Force(a), Quantity::Acceleration(b)) => Ok(Quantity::Mass(a.clone() / b.clone())),
+			(Quantity::Force(a), Quantity::DynamicViscosity(b)) => Ok(Quantity::KinematicViscosity(a.clone() / b.clone())),
+			(Quantity::Force(a), Quantity::Energy(b)) => Ok(Quantity::InverseDistance(a.clone() / b.clone())),
+			(Quantity::Force(a), Quantity::Torque(b)) => Ok(Quantity::InverseDistance(a.clone() / b.clone())),
+			(Quantity::Force(a), Quantity::Frequency(b)) => Ok(Quantity::Momentum(a.clone() / b.clone())),
+			(Quantity::Force(a), Quantity::KinematicViscosity(b)) => Ok(Quantity::DynamicViscosity(a.clone() / b.clone())),
+			(Quantity::Force(a), Quantity::MassFlowRate(b)) => Ok(Quantity::Velocity(a.clone() / b.clone())),
+			(Quantity::Force(a), Quantity::Momentum(b)) => Ok(Quantity::Frequency(a.clone() / b.clone())),
+			(Quantity::Force(a), Quantity::Power(b)) => Ok(Quantity::TimePerDistance(a.clone() / b.clone())),
+			(Quantity::Force(a), Quantity::Pressure(b)) => Ok(Quantity::Area(a.clone() / b.clone())),
+			(Quantity::Force(a), Quantity::TimePerDistance(b)) => Ok(Quantity::Power(a.clone() / b.clone())),
+			(Quantity::Force(a), Quantity::Velocity(b)) => Ok(Quantity::MassFlowRate(a.clone() / b.clone())),
+			(Quantity::Frequency(a), Quantity::Current(b)) => Ok(Quantity::InverseCharge(a.clone() / b.clone())),
+			(Quantity::Frequency(a), Quantity::InverseAmount(b)) => Ok(Quantity::CatalyticActivity(a.clone() / b.clone())),
+			(Quantity::Frequency(a), Quantity::InverseDistance(b)) => Ok(Quantity::Velocity(a.clone() / b.clone())),
+			(Quantity::Frequency(a), Quantity::InverseMass(b)) => Ok(Quantity::MassFlowRate(a.clone() / b.clone())),
+			(Quantity::Frequency(a), Quantity::CatalyticActivity(b)) => Ok(Quantity::InverseAmount(a.clone() / b.clone())),
+			(Quantity::Frequency(a), Quantity::InverseEntropy(b)) => Ok(Quantity::ThermalConductance(a.clone() / b.clone())),
+			(Quantity::Frequency(a), Quantity::InverseHeatCapacity(b)) => Ok(Quantity::ThermalConductance(a.clone() / b.clone())),
+			(Quantity::Frequency(a), Quantity::ThermalConductance(b)) => Ok(Quantity::InverseHeatCapacity(a.clone() / b.clone())),
+			(Quantity::Frequency(a), Quantity::Conductance(b)) => Ok(Quantity::Elastance(a.clone() / b.clone())),
+			(Quantity::Frequency(a), Quantity::Elastance(b)) => Ok(Quantity::Conductance(a.clone() / b.clone())),
+			(Quantity::Frequency(a), Quantity::InverseCharge(b)) => Ok(Quantity::Current(a.clone() / b.clone())),
+			(Quantity::Frequency(a), Quantity::InverseInductance(b)) => Ok(Quantity::Resistance(a.clone() / b.clone())),
+			(Quantity::Frequency(a), Quantity::InverseLinearChargeDensity(b)) => Ok(Quantity::MagneticFieldStrength(a.clone() / b.clone())),
+			(Quantity::Frequency(a), Quantity::InverseMagneticFlux(b)) => Ok(Quantity::Voltage(a.clone() / b.clone())),
+			(Quantity::Frequency(a), Quantity::MagneticFieldStrength(b)) => Ok(Quantity::InverseLinearChargeDensity(a.clone() / b.clone())),
+			(Quantity::Frequency(a), Quantity::Resistance(b)) => Ok(Quantity::InverseInductance(a.clone() / b.clone())),
+			(Quantity::Frequency(a), Quantity::Voltage(b)) => Ok(Quantity::InverseMagneticFlux(a.clone() / b.clone())),
+			(Quantity::Frequency(a), Quantity::InverseAngle(b)) => Ok(Quantity::AngularVelocity(a.clone() / b.clone())),
+			(Quantity::Frequency(a), Quantity::InverseArea(b)) => Ok(Quantity::KinematicViscosity(a.clone() / b.clone())),
+			(Quantity::Frequency(a), Quantity::InverseVolume(b)) => Ok(Quantity::VolumetricFlowRate(a.clone() / b.clone())),
+			(Quantity::Frequency(a), Quantity::Acceleration(b)) => Ok(Quantity::TimePerDistance(a.clone() / b.clone())),
+			(Quantity::Frequency(a), Quantity::AngularAcceleration(b)) => Ok(Quantity::InverseAngularVelocity(a.clone() / b.clone())),
+			(Quantity::Frequency(a), Quantity::AngularVelocity(b)) => Ok(Quantity::InverseAngle(a.clone() / b.clone())),
+			(Quantity::Frequency(a), Quantity::Force(b)) => Ok(Quantity::InverseMomentum(a.clone() / b.clone())),
+			(Quantity::Frequency(a), Quantity::InverseAcceleration(b)) => Ok(Quantity::Jerk(a.clone() / b.clone())),
+			(Quantity::Frequency(a), Quantity::InverseAngularVelocity(b)) => Ok(Quantity::AngularAcceleration(a.clone() / b.clone())),
+			(Quantity::Frequency(a), Quantity::InverseDynamicViscosity(b)) => Ok(Quantity::Pressure(a.clone() / b.clone())),
+			(Quantity::Frequency(a), Quantity::InverseEnergy(b)) => Ok(Quantity::Power(a.clone() / b.clone())),
+			(Quantity::Frequency(a), Quantity::InverseTorque(b)) => Ok(Quantity::Power(a.clone() / b.clone())),
+			(Quantity::Frequency(a), Quantity::InverseMomentum(b)) => Ok(Quantity::Force(a.clone() / b.clone())),
+			(Quantity::Frequency(a), Quantity::Jerk(b)) => Ok(Quantity::InverseAcceleration(a.clone() / b.clone())),
+			(Quantity::Frequency(a), Quantity::KinematicViscosity(b)) => Ok(Quantity::InverseArea(a.clone() / b.clone())),
+			(Quantity::Frequency(a), Quantity::MassFlowRate(b)) => Ok(Quantity::InverseMass(a.clone() / b.clone())),
+			(Quantity::Frequency(a), Quantity::Power(b)) => Ok(Quantity::InverseEnergy(a.clone() / b.clone())),
+			(Quantity::Frequency(a), Quantity::Pressure(b)) => Ok(Quantity::InverseDynamicViscosity(a.clone() / b.clone())),
+			(Quantity::Frequency(a), Quantity::TimePerDistance(b)) => Ok(Quantity::Acceleration(a.clone() / b.clone())),
+			(Quantity::Frequency(a), Quantity::Velocity(b)) => Ok(Quantity::InverseDistance(a.clone() / b.clone())),
+			(Quantity::Frequency(a), Quantity::VolumetricFlowRate(b)) => Ok(Quantity::InverseVolume(a.clone() / b.clone())),
+			(Quantity::InverseAcceleration(a), Quantity::Mass(b)) => Ok(Quantity::InverseForce(a.clone() / b.clone())),
+			(Quantity::InverseAcceleration(a), Quantity::Time(b)) => Ok(Quantity::TimePerDistance(a.clone() / b.clone())),
+			(Quantity::InverseAcceleration(a), Quantity::InverseIrradiance(b)) => Ok(Quantity::DynamicViscosity(a.clone() / b.clone())),
+			(Quantity::InverseAcceleration(a), Quantity::AreaDensity(b)) => Ok(Quantity::InversePressure(a.clone() / b.clone())),
+			(Quantity::InverseAcceleration(a), Quantity::DynamicViscosity(b)) => Ok(Quantity::InverseIrradiance(a.clone() / b.clone())),
+			(Quantity::InverseAcceleration(a), Quantity::Frequency(b)) => Ok(Quantity::InverseJerk(a.clone() / b.clone())),
+			(Quantity::InverseAcceleration(a), Quantity::InverseForce(b)) => Ok(Quantity::Mass(a.clone() / b.clone())),
+			(Quantity::InverseAcceleration(a), Quantity::InverseJerk(b)) => Ok(Quantity::Frequency(a.clone() / b.clone())),
+			(Quantity::InverseAcceleration(a), Quantity::InversePower(b)) => Ok(Quantity::Momentum(a.clone() / b.clone())),
+			(Quantity::InverseAcceleration(a), Quantity::InversePressure(b)) => Ok(Quantity::AreaDensity(a.clone() / b.clone())),
+			(Quantity::InverseAcceleration(a), Quantity::Momentum(b)) => Ok(Quantity::InversePower(a.clone() / b.clone())),
+			(Quantity::InverseAcceleration(a), Quantity::TimePerDistance(b)) => Ok(Quantity::Time(a.clone() / b.clone())),
+			(Quantity::InverseAcceleration(a), Quantity::InverseAbsorbedDose(b)) => Ok(Quantity::Distance(a.clone() / b.clone())),
+			(Quantity::InverseAcceleration(a), Quantity::InverseDoseEquivalent(b)) => Ok(Quantity::Distance(a.clone() / b.clone())),
+			(Quantity::InverseAngularAcceleration(a), Quantity::Time(b)) => Ok(Quantity::InverseAngularVelocity(a.clone() / b.clone())),
+			(Quantity::InverseAngularAcceleration(a), Quantity::InverseAngularVelocity(b)) => Ok(Quantity::Time(a.clone() / b.clone())),
+			(Quantity::InverseAngularMomentum(a), Quantity::InverseMomentOfInertia(b)) => Ok(Quantity::InverseAngularVelocity(a.clone() / b.clone())),
+			(Quantity::InverseAngularVelocity(a), Quantity::Time(b)) => Ok(Quantity::InverseAngle(a.clone() / b.clone())),
+			(Quantity::InverseAngularVelocity(a), Quantity::InverseAngle(b)) => Ok(Quantity::Time(a.clone() / b.clone())),
+			(Quantity::InverseAngularVelocity(a), Quantity::Frequency(b)) => Ok(Quantity::InverseAngularAcceleration(a.clone() / b.clone())),
+			(Quantity::InverseAngularVelocity(a), Quantity::InverseAngularAcceleration(b)) => Ok(Quantity::Frequency(a.clone() / b.clone())),
+			(Quantity::InverseAngularVelocity(a), Quantity::MomentOfInertia(b)) => Ok(Quantity::InverseAngularMomentum(a.clone() / b.clone())),
+			(Quantity::InverseDynamicViscosity(a), Quantity::Distance(b)) => Ok(Quantity::InverseMassFlowRate(a.clone() / b.clone())),
+			(Quantity::InverseDynamicViscosity(a), Quantity::SpecificHeatCapacity(b)) => Ok(Quantity::ThermalResistivity(a.clone() / b.clone())),
+			(Quantity::InverseDynamicViscosity(a), Quantity::ThermalResistivity(b)) => Ok(Quantity::SpecificHeatCapacity(a.clone() / b.clone())),
+			(Quantity::InverseDynamicViscosity(a), Quantity::InverseIrradiance(b)) => Ok(Quantity::Acceleration(a.clone() / b.clone())),
+			(Quantity::InverseDynamicViscosity(a), Quantity::InverseLinearChargeDensity(b)) => Ok(Quantity::InverseMagneticFluxDensity(a.clone() / b.clone())),
+			(Quantity::InverseDynamicViscosity(a), Quantity::InverseMagneticFlux(b)) => Ok(Quantity::InverseVolumeChargeDensity(a.clone() / b.clone())),
+			(Quantity::InverseDynamicViscosity(a), Quantity::InverseMagneticFluxDensity(b)) => Ok(Quantity::InverseLinearChargeDensity(a.clone() / b.clone())),
+			(Quantity::InverseDynamicViscosity(a), Quantity::InverseVolumeChargeDensity(b)) => Ok(Quantity::InverseMagneticFlux(a.clone() / b.clone())),
+			(Quantity::InverseDynamicViscosity(a), Quantity::Area(b)) => Ok(Quantity::InverseMomentum(a.clone() / b.clone())),
+			(Quantity::InverseDynamicViscosity(a), Quantity::Acceleration(b)) => Ok(Quantity::InverseIrradiance(a.clone() / b.clone())),
+			(Quantity::InverseDynamicViscosity(a), Quantity::AreaPerMass(b)) => Ok(Quantity::TimePerDistance(a.clone() / b.clone())),
+			(Quantity::InverseDynamicViscosity(a), Quantity::Frequency(b)) => Ok(Quantity::InversePressure(a.clone() / b.clone())),
+			(Quantity::InverseDynamicViscosity(a), Quantity::InverseEnergy(b)) => Ok(Quantity::VolumetricFlowRate(a.clone() / b.clone())),
+			(Quantity::InverseDynamicViscosity(a), Quantity::InverseTorque(b)) => Ok(Quantity::VolumetricFlowRate(a.clone() / b.clone())),
+			(Quantity::InverseDynamicViscosity(a), Quantity::InverseForce(b)) => Ok(Quantity::KinematicViscosity(a.clone() / b.clone())),
+			(Quantity::InverseDynamicViscosity(a), Quantity::InverseKinematicViscosity(b)) => Ok(Quantity::VolumePerMass(a.clone() / b.clone())),
+			(Quantity::InverseDynamicViscosity(a), Quantity::InverseMassFlowRate(b)) => Ok(Quantity::Distance(a.clone() / b.clone())),
+			(Quantity::InverseDynamicViscosity(a), Quantity::InverseMomentum(b)) => Ok(Quantity::Area(a.clone() / b.clone())),
+			(Quantity::InverseDynamicViscosity(a), Quantity::InversePressure(b)) => Ok(Quantity::Frequency(a.clone() / b.clone())),
+			(Quantity::InverseDynamicViscosity(a), Quantity::KinematicViscosity(b)) => Ok(Quantity::InverseForce(a.clone() / b.clone())),
+			(Quantity::InverseDynamicViscosity(a), Quantity::TimePerDistance(b)) => Ok(Quantity::AreaPerMass(a.clone() / b.clone())),
+			(Quantity::InverseDynamicViscosity(a), Quantity::VolumePerMass(b)) => Ok(Quantity::InverseKinematicViscosity(a.clone() / b.clone())),
+			(Quantity::InverseDynamicViscosity(a), Quantity::VolumetricFlowRate(b)) => Ok(Quantity::InverseEnergy(a.clone() / b.clone())),
+			(Quantity::InverseEnergy(a), Quantity::InverseAmount(b)) => Ok(Quantity::InverseMolarEnergy(a.clone() / b.clone())),
+			(Quantity::InverseEnergy(a), Quantity::InverseCurrent(b)) => Ok(Quantity::InverseMagneticFlux(a.clone() / b.clone())),
+			(Quantity::InverseEnergy(a), Quantity::InverseDistance(b)) => Ok(Quantity::InverseForce(a.clone() / b.clone())),
+			(Quantity::InverseEnergy(a), Quantity::InverseTemperature(b)) => Ok(Quantity::InverseHeatCapacity(a.clone() / b.clone())),
+			(Quantity::InverseEnergy(a), Quantity::InverseEntropy(b)) => Ok(Quantity::InverseTemperature(a.clone() / b.clone())),
+			(Quantity::InverseEnergy(a), Quantity::InverseHeatCapacity(b)) => Ok(Quantity::InverseTemperature(a.clone() / b.clone())),
+			(Quantity::InverseEnergy(a), Quantity::InverseMolarEnergy(b)) => Ok(Quantity::InverseAmount(a.clone() / b.clone())),
+			(Quantity::InverseEnergy(a), Quantity::InverseCharge(b)) => Ok(Quantity::InverseVoltage(a.clone() / b.clone())),
+			(Quantity::InverseEnergy(a), Quantity::InverseMagneticFlux(b)) => Ok(Quantity::InverseCurrent(a.clone() / b.clone())),
+			(Quantity::InverseEnergy(a), Quantity::InverseVoltage(b)) => Ok(Quantity::InverseCharge(a.clone() / b.clone())),
+			(Quantity::InverseEnergy(a), Quantity::InverseVolume(b)) => Ok(Quantity::InversePressure(a.clone() / b.clone())),
+			(Quantity::InverseEnergy(a), Quantity::Frequency(b)) => Ok(Quantity::InversePower(a.clone() / b.clone())),
+			(Quantity::InverseEnergy(a), Quantity::InverseDynamicViscosity(b)) => Ok(Quantity::InverseVolumetricFlowRate(a.clone() / b.clone())),
+			(Quantity::InverseEnergy(a), Quantity::InverseForce(b)) => Ok(Quantity::InverseDistance(a.clone() / b.clone())),
+			(Quantity::InverseEnergy(a), Quantity::InverseKinematicViscosity(b)) => Ok(Quantity::InverseMassFlowRate(a.clone() / b.clone())),
+			(Quantity::InverseEnergy(a), Quantity::InverseMassFlowRate(b)) => Ok(Quantity::InverseKinematicViscosity(a.clone() / b.clone())),
+			(Quantity::InverseEnergy(a), Quantity::InverseMomentum(b)) => Ok(Quantity::TimePerDistance(a.clone() / b.clone())),
+			(Quantity::InverseEnergy(a), Quantity::InversePower(b)) => Ok(Quantity::Frequency(a.clone() / b.clone())),
+			(Quantity::InverseEnergy(a), Quantity::InversePressure(b)) => Ok(Quantity::InverseVolume(a.clone() / b.clone())),
+			(Quantity::InverseEnergy(a), Quantity::InverseVolumetricFlowRate(b)) => Ok(Quantity::InverseDynamicViscosity(a.clone() / b.clone())),
+			(Quantity::InverseEnergy(a), Quantity::TimePerDistance(b)) => Ok(Quantity::InverseMomentum(a.clone() / b.clone())),
+			(Quantity::InverseEnergy(a), Quantity::InverseAbsorbedDose(b)) => Ok(Quantity::InverseMass(a.clone() / b.clone())),
+			(Quantity::InverseEnergy(a), Quantity::InverseDoseEquivalent(b)) => Ok(Quantity::InverseMass(a.clone() / b.clone())),
+			(Quantity::InverseForce(a), Quantity::Distance(b)) => Ok(Quantity::InverseEnergy(a.clone() / b.clone())),
+			(Quantity::InverseForce(a), Quantity::InverseMass(b)) => Ok(Quantity::InverseAcceleration(a.clone() / b.clone())),
+			(Quantity::InverseForce(a), Quantity::Time(b)) => Ok(Quantity::InverseMomentum(a.clone() / b.clone())),
+			(Quantity::InverseForce(a), Quantity::InverseCharge(b)) => Ok(Quantity::InverseElectricField(a.clone() / b.clone())),
+			(Quantity::InverseForce(a), Quantity::InverseElectricField(b)) => Ok(Quantity::InverseCharge(a.clone() / b.clone())),
+			(Quantity::InverseForce(a), Quantity::InverseLinearChargeDensity(b)) => Ok(Quantity::InverseVoltage(a.clone() / b.clone())),
+			(Quantity::InverseForce(a), Quantity::InverseMagneticFieldStrength(b)) => Ok(Quantity::InverseMagneticFlux(a.clone() / b.clone())),
+			(Quantity::InverseForce(a), Quantity::InverseMagneticFlux(b)) => Ok(Quantity::InverseMagneticFieldStrength(a.clone() / b.clone())),
+			(Quantity::InverseForce(a), Quantity::InverseVoltage(b)) => Ok(Quantity::InverseLinearChargeDensity(a.clone() / b.clone())),
+			(Quantity::InverseForce(a), Quantity::InverseArea(b)) => Ok(Quantity::InversePressure(a.clone() / b.clone())),
+			(Quantity::InverseForce(a), Quantity::InverseAcceleration(b)) => Ok(Quantity::InverseMass(a.clone() / b.clone())),
+			(Quantity::InverseForce(a), Quantity::InverseDynamicViscosity(b)) => Ok(Quantity::InverseKinematicViscosity(a.clone() / b.clone())),
+			(Quantity::InverseForce(a), Quantity::InverseEnergy(b)) => Ok(Quantity::Distance(a.clone() / b.clone())),
+			(Quantity::InverseForce(a), Quantity::InverseTorque(b)) => Ok(Quantity::Distance(a.clone() / b.clone())),
+			(Quantity::InverseForce(a), Quantity::InverseKinematicViscosity(b)) => Ok(Quantity::InverseDynamicViscosity(a.clone() / b.clone())),
+			(Quantity::InverseForce(a), Quantity::InverseMassFlowRate(b)) => Ok(Quantity::TimePerDistance(a.clone() / b.clone())),
+			(Quantity::InverseForce(a), Quantity::InverseMomentum(b)) => Ok(Quantity::Time(a.clone() / b.clone())),
+			(Quantity::InverseForce(a), Quantity::InversePower(b)) => Ok(Quantity::Velocity(a.clone() / b.clone())),
+			(Quantity::InverseForce(a), Quantity::InversePressure(b)) => Ok(Quantity::InverseArea(a.clone() / b.clone())),
+			(Quantity::InverseForce(a), Quantity::TimePerDistance(b)) => Ok(Quantity::InverseMassFlowRate(a.clone() / b.clone())),
+			(Quantity::InverseForce(a), Quantity::Velocity(b)) => Ok(Quantity::InversePower(a.clone() / b.clone())),
+			(Quantity::InverseJerk(a), Quantity::Time(b)) => Ok(Quantity::InverseAcceleration(a.clone() / b.clone())),
+			(Quantity::InverseJerk(a), Quantity::InverseAcceleration(b)) => Ok(Quantity::Time(a.clone() / b.clone())),
+			(Quantity::InverseKinematicViscosity(a), Quantity::Distance(b)) => Ok(Quantity::InverseVolumetricFlowRate(a.clone() / b.clone())),
+			(Quantity::InverseKinematicViscosity(a), Quantity::InverseCurrent(b)) => Ok(Quantity::SurfaceChargeDensity(a.clone() / b.clone())),
+			(Quantity::InverseKinematicViscosity(a), Quantity::InverseDistance(b)) => Ok(Quantity::TimePerDistance(a.clone() / b.clone())),
+			(Quantity::InverseKinematicViscosity(a), Quantity::Time(b)) => Ok(Quantity::InverseArea(a.clone() / b.clone())),
+			(Quantity::InverseKinematicViscosity(a), Quantity::InverseMagneticFieldStrength(b)) => Ok(Quantity::VolumeChargeDensity(a.clone() / b.clone())),
+			(Quantity::InverseKinematicViscosity(a), Quantity::InverseVoltage(b)) => Ok(Quantity::MagneticFluxDensity(a.clone() / b.clone())),
+			(Quantity::InverseKinematicViscosity(a), Quantity::MagneticFluxDensity(b)) => Ok(Quantity::InverseVoltage(a.clone() / b.clone())),
+			(Quantity::InverseKinematicViscosity(a), Quantity::SurfaceChargeDensity(b)) => Ok(Quantity::InverseCurrent(a.clone() / b.clone())),
+			(Quantity::InverseKinematicViscosity(a), Quantity::VolumeChargeDensity(b)) => Ok(Quantity::InverseMagneticFieldStrength(a.clone() / b.clone())),
+			(Quantity::InverseKinematicViscosity(a), Quantity::InverseArea(b)) => Ok(Quantity::Time(a.clone() / b.clone())),
+			(Quantity::InverseKinematicViscosity(a), Quantity::AreaDensity(b)) => Ok(Quantity::InverseMassFlowRate(a.clone() / b.clone())),
+			(Quantity::InverseKinematicViscosity(a), Quantity::Density(b)) => Ok(Quantity::InverseDynamicViscosity(a.clone() / b.clone())),
+			(Quantity::InverseKinematicViscosity(a), Quantity::DynamicViscosity(b)) => Ok(Quantity::InverseForce(a.clone() / b.clone())),
+			(Quantity::InverseKinematicViscosity(a), Quantity::InverseDynamicViscosity(b)) => Ok(Quantity::Density(a.clone() / b.clone())),
+			(Quantity::InverseKinematicViscosity(a), Quantity::InverseEnergy(b)) => Ok(Quantity::MassFlowRate(a.clone() / b.clone())),
+			(Quantity::InverseKinematicViscosity(a), Quantity::InverseTorque(b)) => Ok(Quantity::MassFlowRate(a.clone() / b.clone())),
+			(Quantity::InverseKinematicViscosity(a), Quantity::InverseForce(b)) => Ok(Quantity::DynamicViscosity(a.clone() / b.clone())),
+			(Quantity::InverseKinematicViscosity(a), Quantity::InverseMassFlowRate(b)) => Ok(Quantity::AreaDensity(a.clone() / b.clone())),
+			(Quantity::InverseKinematicViscosity(a), Quantity::InverseVolumetricFlowRate(b)) => Ok(Quantity::Distance(a.clone() / b.clone())),
+			(Quantity::InverseKinematicViscosity(a), Quantity::MassFlowRate(b)) => Ok(Quantity::InverseEnergy(a.clone() / b.clone())),
+			(Quantity::InverseKinematicViscosity(a), Quantity::TimePerDistance(b)) => Ok(Quantity::InverseDistance(a.clone() / b.clone())),
+			(Quantity::InverseKinematicViscosity(a), Quantity::InverseAbsorbedDose(b)) => Ok(Quantity::Frequency(a.clone() / b.clone())),
+			(Quantity::InverseKinematicViscosity(a), Quantity::InverseDoseEquivalent(b)) => Ok(Quantity::Frequency(a.clone() / b.clone())),
+			(Quantity::InverseMassFlowRate(a), Quantity::Distance(b)) => Ok(Quantity::InverseMomentum(a.clone() / b.clone())),
+			(Quantity::InverseMassFlowRate(a), Quantity::InverseDistance(b)) => Ok(Quantity::InverseDynamicViscosity(a.clone() / b.clone())),
+			(Quantity::InverseMassFlowRate(a), Quantity::InverseMass(b)) => Ok(Quantity::Time(a.clone() / b.clone())),
+			(Quantity::InverseMassFlowRate(a), Quantity::Time(b)) => Ok(Quantity::InverseMass(a.clone() / b.clone())),
+			(Quantity::InverseMassFlowRate(a), Quantity::InverseCatalyticActivity(b)) => Ok(Quantity::Molality(a.clone() / b.clone())),
+			(Quantity::InverseMassFlowRate(a), Quantity::Molality(b)) => Ok(Quantity::InverseCatalyticActivity(a.clone() / b.clone())),
+			(Quantity::InverseMassFlowRate(a), Quantity::SpecificHeatCapacity(b)) => Ok(Quantity::ThermalResistance(a.clone() / b.clone())),
+			(Quantity::InverseMassFlowRate(a), Quantity::ThermalResistance(b)) => Ok(Quantity::SpecificHeatCapacity(a.clone() / b.clone())),
+			(Quantity::InverseMassFlowRate(a), Quantity::InverseCharge(b)) => Ok(Quantity::InverseMagneticFluxDensity(a.clone() / b.clone())),
+			(Quantity::InverseMassFlowRate(a), Quantity::InverseMagneticFlux(b)) => Ok(Quantity::InverseSurfaceChargeDensity(a.clone() / b.clone())),
+			(Quantity::InverseMassFlowRate(a), Quantity::InverseMagneticFluxDensity(b)) => Ok(Quantity::InverseCharge(a.clone() / b.clone())),
+			(Quantity::InverseMassFlowRate(a), Quantity::InverseSurfaceChargeDensity(b)) => Ok(Quantity::InverseMagneticFlux(a.clone() / b.clone())),
+			(Quantity::InverseMassFlowRate(a), Quantity::AreaPerMass(b)) => Ok(Quantity::InverseKinematicViscosity(a.clone() / b.clone())),
+			(Quantity::InverseMassFlowRate(a), Quantity::InverseDynamicViscosity(b)) => Ok(Quantity::InverseDistance(a.clone() / b.clone())),
+			(Quantity::InverseMassFlowRate(a), Quantity::InverseEnergy(b)) => Ok(Quantity::KinematicViscosity(a.clone() / b.clone())),
+			(Quantity::InverseMassFlowRate(a), Quantity::InverseTorque(b)) => Ok(Quantity::KinematicViscosity(a.clone() / b.clone())),
+			(Quantity::InverseMassFlowRate(a), Quantity::InverseForce(b)) => Ok(Quantity::Velocity(a.clone() / b.clone())),
+			(Quantity::InverseMassFlowRate(a), Quantity::InverseKinematicViscosity(b)) => Ok(Quantity::AreaPerMass(a.clone() / b.clone())),
+			(Quantity::InverseMassFlowRate(a), Quantity::InverseMomentum(b)) => Ok(Quantity::Distance(a.clone() / b.clone())),
+			(Quantity::InverseMassFlowRate(a), Quantity::InverseVolumetricFlowRate(b)) => Ok(Quantity::VolumePerMass(a.clone() / b.clone())),
+			(Quantity::InverseMassFlowRate(a), Quantity::KinematicViscosity(b)) => Ok(Quantity::InverseEnergy(a.clone() / b.clone())),
+			(Quantity::InverseMassFlowRate(a), Quantity::Velocity(b)) => Ok(Quantity::InverseForce(a.clone() / b.clone())),
+			(Quantity::InverseMassFlowRate(a), Quantity::VolumePerMass(b)) => Ok(Quantity::InverseVolumetricFlowRate(a.clone() / b.clone())),
+			(Quantity::InverseMomentOfInertia(a), Quantity::InverseMass(b)) => Ok(Quantity::InverseArea(a.clone() / b.clone())),
+			(Quantity::InverseMomentOfInertia(a), Quantity::InverseArea(b)) => Ok(Quantity::InverseMass(a.clone() / b.clone())),
+			(Quantity::InverseMomentOfInertia(a), Quantity::AngularVelocity(b)) => Ok(Quantity::InverseAngularMomentum(a.clone() / b.clone())),
+			(Quantity::InverseMomentOfInertia(a), Quantity::InverseAngularMomentum(b)) => Ok(Quantity::AngularVelocity(a.clone() / b.clone())),
+			(Quantity::InverseMomentum(a), Quantity::InverseDistance(b)) => Ok(Quantity::InverseMassFlowRate(a.clone() / b.clone())),
+			(Quantity::InverseMomentum(a), Quantity::InverseMass(b)) => Ok(Quantity::TimePerDistance(a.clone() / b.clone())),
+			(Quantity::InverseMomentum(a), Quantity::InverseLinearChargeDensity(b)) => Ok(Quantity::InverseMagneticFlux(a.clone() / b.clone())),
+			(Quantity::InverseMomentum(a), Quantity::InverseMagneticFlux(b)) => Ok(Quantity::InverseLinearChargeDensity(a.clone() / b.clone())),
+			(Quantity::InverseMomentum(a), Quantity::InverseArea(b)) => Ok(Quantity::InverseDynamicViscosity(a.clone() / b.clone())),
+			(Quantity::InverseMomentum(a), Quantity::Acceleration(b)) => Ok(Quantity::InversePower(a.clone() / b.clone())),
+			(Quantity::InverseMomentum(a), Quantity::AreaPerMass(b)) => Ok(Quantity::InverseVolumetricFlowRate(a.clone() / b.clone())),
+			(Quantity::InverseMomentum(a), Quantity::Frequency(b)) => Ok(Quantity::InverseForce(a.clone() / b.clone())),
+			(Quantity::InverseMomentum(a), Quantity::InverseDynamicViscosity(b)) => Ok(Quantity::InverseArea(a.clone() / b.clone())),
+			(Quantity::InverseMomentum(a), Quantity::InverseEnergy(b)) => Ok(Quantity::Velocity(a.clone() / b.clone())),
+			(Quantity::InverseMomentum(a), Quantity::InverseTorque(b)) => Ok(Quantity::Velocity(a.clone() / b.clone())),
+			(Quantity::InverseMomentum(a), Quantity::InverseForce(b)) => Ok(Quantity::Frequency(a.clone() / b.clone())),
+			(Quantity::InverseMomentum(a), Quantity::InverseMassFlowRate(b)) => Ok(Quantity::InverseDistance(a.clone() / b.clone())),
+			(Quantity::InverseMomentum(a), Quantity::InversePower(b)) => Ok(Quantity::Acceleration(a.clone() / b.clone())),
+			(Quantity::InverseMomentum(a), Quantity::InverseVolumetricFlowRate(b)) => Ok(Quantity::AreaPerMass(a.clone() / b.clone())),
+			(Quantity::InverseMomentum(a), Quantity::TimePerDistance(b)) => Ok(Quantity::InverseMass(a.clone() / b.clone())),
+			(Quantity::InverseMomentum(a), Quantity::Velocity(b)) => Ok(Quantity::InverseEnergy(a.clone() / b.clone())),
+			(Quantity::InversePower(a), Quantity::InverseCurrent(b)) => Ok(Quantity::InverseVoltage(a.clone() / b.clone())),
+			(Quantity::InversePower(a), Quantity::InverseTemperature(b)) => Ok(Quantity::ThermalResistance(a.clone() / b.clone())),
+			(Quantity::InversePower(a), Quantity::Time(b)) => Ok(Quantity::InverseEnergy(a.clone() / b.clone())),
+			(Quantity::InversePower(a), Quantity::InverseCatalyticActivity(b)) => Ok(Quantity::InverseMolarEnergy(a.clone() / b.clone())),
+			(Quantity::InversePower(a), Quantity::InverseMolarEnergy(b)) => Ok(Quantity::InverseCatalyticActivity(a.clone() / b.clone())),
+			(Quantity::InversePower(a), Quantity::ThermalResistance(b)) => Ok(Quantity::InverseTemperature(a.clone() / b.clone())),
+			(Quantity::InversePower(a), Quantity::InverseIrradiance(b)) => Ok(Quantity::InverseArea(a.clone() / b.clone())),
+			(Quantity::InversePower(a), Quantity::InverseVoltage(b)) => Ok(Quantity::InverseCurrent(a.clone() / b.clone())),
+			(Quantity::InversePower(a), Quantity::InverseArea(b)) => Ok(Quantity::InverseIrradiance(a.clone() / b.clone())),
+			(Quantity::InversePower(a), Quantity::InverseAcceleration(b)) => Ok(Quantity::InverseMomentum(a.clone() / b.clone())),
+			(Quantity::InversePower(a), Quantity::InverseEnergy(b)) => Ok(Quantity::Time(a.clone() / b.clone())),
+			(Quantity::InversePower(a), Quantity::InverseTorque(b)) => Ok(Quantity::Time(a.clone() / b.clone())),
+			(Quantity::InversePower(a), Quantity::InverseForce(b)) => Ok(Quantity::TimePerDistance(a.clone() / b.clone())),
+			(Quantity::InversePower(a), Quantity::InverseMomentum(b)) => Ok(Quantity::InverseAcceleration(a.clone() / b.clone())),
+			(Quantity::InversePower(a), Quantity::InversePressure(b)) => Ok(Quantity::InverseVolumetricFlowRate(a.clone() / b.clone())),
+			(Quantity::InversePower(a), Quantity::InverseVolumetricFlowRate(b)) => Ok(Quantity::InversePressure(a.clone() / b.clone())),
+			(Quantity::InversePower(a), Quantity::TimePerDistance(b)) => Ok(Quantity::InverseForce(a.clone() / b.clone())),
+			(Quantity::InversePower(a), Quantity::InverseAbsorbedDose(b)) => Ok(Quantity::InverseMassFlowRate(a.clone() / b.clone())),
+			(Quantity::InversePower(a), Quantity::InverseDoseEquivalent(b)) => Ok(Quantity::InverseMassFlowRate(a.clone() / b.clone())),
+			(Quantity::InversePressure(a), Quantity::Time(b)) => Ok(Quantity::InverseDynamicViscosity(a.clone() / b.clone())),
+			(Quantity::InversePressure(a), Quantity::InverseMolarEnergy(b)) => Ok(Quantity::MolarVolume(a.clone() / b.clone())),
+			(Quantity::InversePressure(a), Quantity::MolarVolume(b)) => Ok(Quantity::InverseMolarEnergy(a.clone() / b.clone())),
+			(Quantity::InversePressure(a), Quantity::InverseElectricField(b)) => Ok(Quantity::InverseSurfaceChargeDensity(a.clone() / b.clone())),
+			(Quantity::InversePressure(a), Quantity::InverseIrradiance(b)) => Ok(Quantity::Velocity(a.clone() / b.clone())),
+			(Quantity::InversePressure(a), Quantity::InverseMagneticFieldStrength(b)) => Ok(Quantity::InverseMagneticFluxDensity(a.clone() / b.clone())),
+			(Quantity::InversePressure(a), Quantity::InverseMagneticFluxDensity(b)) => Ok(Quantity::InverseMagneticFieldStrength(a.clone() / b.clone())),
+			(Quantity::InversePressure(a), Quantity::InverseSurfaceChargeDensity(b)) => Ok(Quantity::InverseElectricField(a.clone() / b.clone())),
+			(Quantity::InversePressure(a), Quantity::InverseVoltage(b)) => Ok(Quantity::InverseVolumeChargeDensity(a.clone() / b.clone())),
+			(Quantity::InversePressure(a), Quantity::InverseVolumeChargeDensity(b)) => Ok(Quantity::InverseVoltage(a.clone() / b.clone())),
+			(Quantity::InversePressure(a), Quantity::Area(b)) => Ok(Quantity::InverseForce(a.clone() / b.clone())),
+			(Quantity::InversePressure(a), Quantity::Volume(b)) => Ok(Quantity::InverseEnergy(a.clone() / b.clone())),
+			(Quantity::InversePressure(a), Quantity::AreaPerMass(b)) => Ok(Quantity::InverseAcceleration(a.clone() / b.clone())),
+			(Quantity::InversePressure(a), Quantity::InverseAcceleration(b)) => Ok(Quantity::AreaPerMass(a.clone() / b.clone())),
+			(Quantity::InversePressure(a), Quantity::InverseDynamicViscosity(b)) => Ok(Quantity::Time(a.clone() / b.clone())),
+			(Quantity::InversePressure(a), Quantity::InverseEnergy(b)) => Ok(Quantity::Volume(a.clone() / b.clone())),
+			(Quantity::InversePressure(a), Quantity::InverseTorque(b)) => Ok(Quantity::Volume(a.clone() / b.clone())),
+			(Quantity::InversePressure(a), Quantity::InverseForce(b)) => Ok(Quantity::Area(a.clone() / b.clone())),
+			(Quantity::InversePressure(a), Quantity::InversePower(b)) => Ok(Quantity::VolumetricFlowRate(a.clone() / b.clone())),
+			(Quantity::InversePressure(a), Quantity::Velocity(b)) => Ok(Quantity::InverseIrradiance(a.clone() / b.clone())),
+			(Quantity::InversePressure(a), Quantity::VolumetricFlowRate(b)) => Ok(Quantity::InversePower(a.clone() / b.clone())),
+			(Quantity::InversePressure(a), Quantity::InverseAbsorbedDose(b)) => Ok(Quantity::VolumePerMass(a.clone() / b.clone())),
+			(Quantity::InversePressure(a), Quantity::InverseDoseEquivalent(b)) => Ok(Quantity::VolumePerMass(a.clone() / b.clone())),
+			(Quantity::InverseTorque(a), Quantity::InverseAmount(b)) => Ok(Quantity::InverseMolarEnergy(a.clone() / b.clone())),
+			(Quantity::InverseTorque(a), Quantity::InverseCurrent(b)) => Ok(Quantity::InverseMagneticFlux(a.clone() / b.clone())),
+			(Quantity::InverseTorque(a), Quantity::InverseDistance(b)) => Ok(Quantity::InverseForce(a.clone() / b.clone())),
+			(Quantity::InverseTorque(a), Quantity::InverseTemperature(b)) => Ok(Quantity::InverseHeatCapacity(a.clone() / b.clone())),
+			(Quantity::InverseTorque(a), Quantity::InverseEntropy(b)) => Ok(Quantity::InverseTemperature(a.clone() / b.clone())),
+			(Quantity::InverseTorque(a), Quantity::InverseHeatCapacity(b)) => Ok(Quantity::InverseTemperature(a.clone() / b.clone())),
+			(Quantity::InverseTorque(a), Quantity::InverseMolarEnergy(b)) => Ok(Quantity::InverseAmount(a.clone() / b.clone())),
+			(Quantity::InverseTorque(a), Quantity::InverseCharge(b)) => Ok(Quantity::InverseVoltage(a.clone() / b.clone())),
+			(Quantity::InverseTorque(a), Quantity::InverseMagneticFlux(b)) => Ok(Quantity::InverseCurrent(a.clone() / b.clone())),
+			(Quantity::InverseTorque(a), Quantity::InverseVoltage(b)) => Ok(Quantity::InverseCharge(a.clone() / b.clone())),
+			(Quantity::InverseTorque(a), Quantity::InverseVolume(b)) => Ok(Quantity::InversePressure(a.clone() / b.clone())),
+			(Quantity::InverseTorque(a), Quantity::Frequency(b)) => Ok(Quantity::InversePower(a.clone() / b.clone())),
+			(Quantity::InverseTorque(a), Quantity::InverseDynamicViscosity(b)) => Ok(Quantity::InverseVolumetricFlowRate(a.clone() / b.clone())),
+			(Quantity::InverseTorque(a), Quantity::InverseForce(b)) => Ok(Quantity::InverseDistance(a.clone() / b.clone())),
+			(Quantity::InverseTorque(a), Quantity::InverseKinematicViscosity(b)) => Ok(Quantity::InverseMassFlowRate(a.clone() / b.clone())),
+			(Quantity::InverseTorque(a), Quantity::InverseMassFlowRate(b)) => Ok(Quantity::InverseKinematicViscosity(a.clone() / b.clone())),
+			(Quantity::InverseTorque(a), Quantity::InverseMomentum(b)) => Ok(Quantity::TimePerDistance(a.clone() / b.clone())),
+			(Quantity::InverseTorque(a), Quantity::InversePower(b)) => Ok(Quantity::Frequency(a.clone() / b.clone())),
+			(Quantity::InverseTorque(a), Quantity::InversePressure(b)) => Ok(Quantity::InverseVolume(a.clone() / b.clone())),
+			(Quantity::InverseTorque(a), Quantity::InverseVolumetricFlowRate(b)) => Ok(Quantity::InverseDynamicViscosity(a.clone() / b.clone())),
+			(Quantity::InverseTorque(a), Quantity::TimePerDistance(b)) => Ok(Quantity::InverseMomentum(a.clone() / b.clone())),
+			(Quantity::InverseTorque(a), Quantity::InverseAbsorbedDose(b)) => Ok(Quantity::InverseMass(a.clone() / b.clone())),
+			(Quantity::InverseTorque(a), Quantity::InverseDoseEquivalent(b)) => Ok(Quantity::InverseMass(a.clone() / b.clone())),
+			(Quantity::InverseVolumetricFlowRate(a), Quantity::InverseCurrent(b)) => Ok(Quantity::VolumeChargeDensity(a.clone() / b.clone())),
+			(Quantity::InverseVolumetricFlowRate(a), Quantity::InverseDistance(b)) => Ok(Quantity::InverseKinematicViscosity(a.clone() / b.clone())),
+			(Quantity::InverseVolumetricFlowRate(a), Quantity::Time(b)) => Ok(Quantity::InverseVolume(a.clone() / b.clone())),
+			(Quantity::InverseVolumetricFlowRate(a), Quantity::Concentration(b)) => Ok(Quantity::InverseCatalyticActivity(a.clone() / b.clone())),
+			(Quantity::InverseVolumetricFlowRate(a), Quantity::InverseCatalyticActivity(b)) => Ok(Quantity::Concentration(a.clone() / b.clone())),
+			(Quantity::InverseVolumetricFlowRate(a), Quantity::VolumeChargeDensity(b)) => Ok(Quantity::InverseCurrent(a.clone() / b.clone())),
+			(Quantity::InverseVolumetricFlowRate(a), Quantity::InverseArea(b)) => Ok(Quantity::TimePerDistance(a.clone() / b.clone())),
+			(Quantity::InverseVolumetricFlowRate(a), Quantity::InverseVolume(b)) => Ok(Quantity::Time(a.clone() / b.clone())),
+			(Quantity::InverseVolumetricFlowRate(a), Quantity::AreaDensity(b)) => Ok(Quantity::InverseMomentum(a.clone() / b.clone())),
+			(Quantity::InverseVolumetricFlowRate(a), Quantity::Density(b)) => Ok(Quantity::InverseMassFlowRate(a.clone() / b.clone())),
+			(Quantity::InverseVolumetricFlowRate(a), Quantity::DynamicViscosity(b)) => Ok(Quantity::InverseEnergy(a.clone() / b.clone())),
+			(Quantity::InverseVolumetricFlowRate(a), Quantity::InverseEnergy(b)) => Ok(Quantity::DynamicViscosity(a.clone() / b.clone())),
+			(Quantity::InverseVolumetricFlowRate(a), Quantity::InverseTorque(b)) => Ok(Quantity::DynamicViscosity(a.clone() / b.clone())),
+			(Quantity::InverseVolumetricFlowRate(a), Quantity::InverseKinematicViscosity(b)) => Ok(Quantity::InverseDistance(a.clone() / b.clone())),
+			(Quantity::InverseVolumetricFlowRate(a), Quantity::InverseMassFlowRate(b)) => Ok(Quantity::Density(a.clone() / b.clone())),
+			(Quantity::InverseVolumetricFlowRate(a), Quantity::InverseMomentum(b)) => Ok(Quantity::AreaDensity(a.clone() / b.clone())),
+			(Quantity::InverseVolumetricFlowRate(a), Quantity::InversePower(b)) => Ok(Quantity::Pressure(a.clone() / b.clone())),
+			(Quantity::InverseVolumetricFlowRate(a), Quantity::Pressure(b)) => Ok(Quantity::InversePower(a.clone() / b.clone())),
+			(Quantity::InverseVolumetricFlowRate(a), Quantity::TimePerDistance(b)) => Ok(Quantity::InverseArea(a.clone() / b.clone())),
+			(Quantity::Jerk(a), Quantity::Acceleration(b)) => Ok(Quantity::Frequency(a.clone() / b.clone())),
+			(Quantity::Jerk(a), Quantity::Frequency(b)) => Ok(Quantity::Acceleration(a.clone() / b.clone())),
+			(Quantity::KinematicViscosity(a), Quantity::Current(b)) => Ok(Quantity::InverseSurfaceChargeDensity(a.clone() / b.clone())),
+			(Quantity::KinematicViscosity(a), Quantity::Distance(b)) => Ok(Quantity::Velocity(a.clone() / b.clone())),
+			(Quantity::KinematicViscosity(a), Quantity::InverseDistance(b)) => Ok(Quantity::VolumetricFlowRate(a.clone() / b.clone())),
+			(Quantity::KinematicViscosity(a), Quantity::InverseMagneticFluxDensity(b)) => Ok(Quantity::Voltage(a.clone() / b.clone())),
+			(Quantity::KinematicViscosity(a), Quantity::InverseSurfaceChargeDensity(b)) => Ok(Quantity::Current(a.clone() / b.clone())),
+			(Quantity::KinematicViscosity(a), Quantity::InverseVolumeChargeDensity(b)) => Ok(Quantity::MagneticFieldStrength(a.clone() / b.clone())),
+			(Quantity::KinematicViscosity(a), Quantity::MagneticFieldStrength(b)) => Ok(Quantity::InverseVolumeChargeDensity(a.clone() / b.clone())),
+			(Quantity::KinematicViscosity(a), Quantity::Voltage(b)) => Ok(Quantity::InverseMagneticFluxDensity(a.clone() / b.clone())),
+			(Quantity::KinematicViscosity(a), Quantity::Area(b)) => Ok(Quantity::Frequency(a.clone() / b.clone())),
+			(Quantity::KinematicViscosity(a), Quantity::AreaPerMass(b)) => Ok(Quantity::MassFlowRate(a.clone() / b.clone())),
+			(Quantity::KinematicViscosity(a), Quantity::DynamicViscosity(b)) => Ok(Quantity::VolumePerMass(a.clone() / b.clone())),
+			(Quantity::KinematicViscosity(a), Quantity::Energy(b)) => Ok(Quantity::InverseMassFlowRate(a.clone() / b.clone())),
+			(Quantity::KinematicViscosity(a), Quantity::Torque(b)) => Ok(Quantity::InverseMassFlowRate(a.clone() / b.clone())),
+			(Quantity::KinematicViscosity(a), Quantity::Force(b)) => Ok(Quantity::InverseDynamicViscosity(a.clone() / b.clone())),
+			(Quantity::KinematicViscosity(a), Quantity::Frequency(b)) => Ok(Quantity::Area(a.clone() / b.clone())),
+			(Quantity::KinematicViscosity(a), Quantity::InverseDynamicViscosity(b)) => Ok(Quantity::Force(a.clone() / b.clone())),
+			(Quantity::KinematicViscosity(a), Quantity::InverseMassFlowRate(b)) => Ok(Quantity::Energy(a.clone() / b.clone())),
+			(Quantity::KinematicViscosity(a), Quantity::MassFlowRate(b)) => Ok(Quantity::AreaPerMass(a.clone() / b.clone())),
+			(Quantity::KinematicViscosity(a), Quantity::Velocity(b)) => Ok(Quantity::Distance(a.clone() / b.clone())),
+			(Quantity::KinematicViscosity(a), Quantity::VolumePerMass(b)) => Ok(Quantity::DynamicViscosity(a.clone() / b.clone())),
+			(Quantity::KinematicViscosity(a), Quantity::VolumetricFlowRate(b)) => Ok(Quantity::InverseDistance(a.clone() / b.clone())),
+			(Quantity::MassFlowRate(a), Quantity::Distance(b)) => Ok(Quantity::DynamicViscosity(a.clone() / b.clone())),
+			(Quantity::MassFlowRate(a), Quantity::InverseDistance(b)) => Ok(Quantity::Momentum(a.clone() / b.clone())),
+			(Quantity::MassFlowRate(a), Quantity::Mass(b)) => Ok(Quantity::Frequency(a.clone() / b.clone())),
+			(Quantity::MassFlowRate(a), Quantity::CatalyticActivity(b)) => Ok(Quantity::MolarMass(a.clone() / b.clone())),
+			(Quantity::MassFlowRate(a), Quantity::InverseSpecificHeatCapacity(b)) => Ok(Quantity::ThermalConductance(a.clone() / b.clone())),
+			(Quantity::MassFlowRate(a), Quantity::MolarMass(b)) => Ok(Quantity::CatalyticActivity(a.clone() / b.clone())),
+			(Quantity::MassFlowRate(a), Quantity::ThermalConductance(b)) => Ok(Quantity::InverseSpecificHeatCapacity(a.clone() / b.clone())),
+			(Quantity::MassFlowRate(a), Quantity::Charge(b)) => Ok(Quantity::MagneticFluxDensity(a.clone() / b.clone())),
+			(Quantity::MassFlowRate(a), Quantity::MagneticFlux(b)) => Ok(Quantity::SurfaceChargeDensity(a.clone() / b.clone())),
+			(Quantity::MassFlowRate(a), Quantity::MagneticFluxDensity(b)) => Ok(Quantity::Charge(a.clone() / b.clone())),
+			(Quantity::MassFlowRate(a), Quantity::SurfaceChargeDensity(b)) => Ok(Quantity::MagneticFlux(a.clone() / b.clone())),
+			(Quantity::MassFlowRate(a), Quantity::AreaDensity(b)) => Ok(Quantity::KinematicViscosity(a.clone() / b.clone())),
+			(Quantity::MassFlowRate(a), Quantity::Density(b)) => Ok(Quantity::VolumetricFlowRate(a.clone() / b.clone())),
+			(Quantity::MassFlowRate(a), Quantity::DynamicViscosity(b)) => Ok(Quantity::Distance(a.clone() / b.clone())),
+			(Quantity::MassFlowRate(a), Quantity::Energy(b)) => Ok(Quantity::InverseKinematicViscosity(a.clone() / b.clone())),
+			(Quantity::MassFlowRate(a), Quantity::Torque(b)) => Ok(Quantity::InverseKinematicViscosity(a.clone() / b.clone())),
+			(Quantity::MassFlowRate(a), Quantity::Force(b)) => Ok(Quantity::TimePerDistance(a.clone() / b.clone())),
+			(Quantity::MassFlowRate(a), Quantity::Frequency(b)) => Ok(Quantity::Mass(a.clone() / b.clone())),
+			(Quantity::MassFlowRate(a), Quantity::InverseKinematicViscosity(b)) => Ok(Quantity::Energy(a.clone() / b.clone())),
+			(Quantity::MassFlowRate(a), Quantity::KinematicViscosity(b)) => Ok(Quantity::AreaDensity(a.clone() / b.clone())),
+			(Quantity::MassFlowRate(a), Quantity::Momentum(b)) => Ok(Quantity::InverseDistance(a.clone() / b.clone())),
+			(Quantity::MassFlowRate(a), Quantity::TimePerDistance(b)) => Ok(Quantity::Force(a.clone() / b.clone())),
+			(Quantity::MassFlowRate(a), Quantity::VolumetricFlowRate(b)) => Ok(Quantity::Density(a.clone() / b.clone())),
+			(Quantity::MassFlowRate(a), Quantity::InverseAbsorbedDose(b)) => Ok(Quantity::Power(a.clone() / b.clone())),
+			(Quantity::MassFlowRate(a), Quantity::InverseDoseEquivalent(b)) => Ok(Quantity::Power(a.clone() / b.clone())),
+			(Quantity::MomentOfInertia(a), Quantity::Mass(b)) => Ok(Quantity::Area(a.clone() / b.clone())),
+			(Quantity::MomentOfInertia(a), Quantity::Area(b)) => Ok(Quantity::Mass(a.clone() / b.clone())),
+			(Quantity::MomentOfInertia(a), Quantity::AngularMomentum(b)) => Ok(Quantity::InverseAngularVelocity(a.clone() / b.clone())),
+			(Quantity::MomentOfInertia(a), Quantity::InverseAngularVelocity(b)) => Ok(Quantity::AngularMomentum(a.clone() / b.clone())),
+			(Quantity::Momentum(a), Quantity::Distance(b)) => Ok(Quantity::MassFlowRate(a.clone() / b.clone())),
+			(Quantity::Momentum(a), Quantity::Mass(b)) => Ok(Quantity::Velocity(a.clone() / b.clone())),
+			(Quantity::Momentum(a), Quantity::Time(b)) => Ok(Quantity::Force(a.clone() / b.clone())),
+			(Quantity::Momentum(a), Quantity::LinearChargeDensity(b)) => Ok(Quantity::MagneticFlux(a.clone() / b.clone())),
+			(Quantity::Momentum(a), Quantity::MagneticFlux(b)) => Ok(Quantity::LinearChargeDensity(a.clone() / b.clone())),
+			(Quantity::Momentum(a), Quantity::Area(b)) => Ok(Quantity::DynamicViscosity(a.clone() / b.clone())),
+			(Quantity::Momentum(a), Quantity::AreaDensity(b)) => Ok(Quantity::VolumetricFlowRate(a.clone() / b.clone())),
+			(Quantity::Momentum(a), Quantity::DynamicViscosity(b)) => Ok(Quantity::Area(a.clone() / b.clone())),
+			(Quantity::Momentum(a), Quantity::Energy(b)) => Ok(Quantity::TimePerDistance(a.clone() / b.clone())),
+			(Quantity::Momentum(a), Quantity::Torque(b)) => Ok(Quantity::TimePerDistance(a.clone() / b.clone())),
+			(Quantity::Momentum(a), Quantity::Force(b)) => Ok(Quantity::Time(a.clone() / b.clone())),
+			(Quantity::Momentum(a), Quantity::InverseAcceleration(b)) => Ok(Quantity::Power(a.clone() / b.clone())),
+			(Quantity::Momentum(a), Quantity::MassFlowRate(b)) => Ok(Quantity::Distance(a.clone() / b.clone())),
+			(Quantity::Momentum(a), Quantity::Power(b)) => Ok(Quantity::InverseAcceleration(a.clone() / b.clone())),
+			(Quantity::Momentum(a), Quantity::TimePerDistance(b)) => Ok(Quantity::Energy(a.clone() / b.clone())),
+			(Quantity::Momentum(a), Quantity::Velocity(b)) => Ok(Quantity::Mass(a.clone() / b.clone())),
+			(Quantity::Momentum(a), Quantity::VolumetricFlowRate(b)) => Ok(Quantity::AreaDensity(a.clone() / b.clone())),
+			(Quantity::Power(a), Quantity::Current(b)) => Ok(Quantity::Voltage(a.clone() / b.clone())),
+			(Quantity::Power(a), Quantity::Temperature(b)) => Ok(Quantity::ThermalConductance(a.clone() / b.clone())),
+			(Quantity::Power(a), Quantity::TemperatureInterval(b)) => Ok(Quantity::ThermalConductance(a.clone() / b.clone())),
+			(Quantity::Power(a), Quantity::CatalyticActivity(b)) => Ok(Quantity::MolarEnergy(a.clone() / b.clone())),
+			(Quantity::Power(a), Quantity::MolarEnergy(b)) => Ok(Quantity::CatalyticActivity(a.clone() / b.clone())),
+			(Quantity::Power(a), Quantity::ThermalConductance(b)) => Ok(Quantity::Temperature(a.clone() / b.clone())),
+			(Quantity::Power(a), Quantity::Irradiance(b)) => Ok(Quantity::Area(a.clone() / b.clone())),
+			(Quantity::Power(a), Quantity::Voltage(b)) => Ok(Quantity::Current(a.clone() / b.clone())),
+			(Quantity::Power(a), Quantity::Area(b)) => Ok(Quantity::Irradiance(a.clone() / b.clone())),
+			(Quantity::Power(a), Quantity::Acceleration(b)) => Ok(Quantity::Momentum(a.clone() / b.clone())),
+			(Quantity::Power(a), Quantity::Energy(b)) => Ok(Quantity::Frequency(a.clone() / b.clone())),
+			(Quantity::Power(a), Quantity::Torque(b)) => Ok(Quantity::Frequency(a.clone() / b.clone())),
+			(Quantity::Power(a), Quantity::Force(b)) => Ok(Quantity::Velocity(a.clone() / b.clone())),
+			(Quantity::Power(a), Quantity::Frequency(b)) => Ok(Quantity::Energy(a.clone() / b.clone())),
+			(Quantity::Power(a), Quantity::Momentum(b)) => Ok(Quantity::Acceleration(a.clone() / b.clone())),
+			(Quantity::Power(a), Quantity::Pressure(b)) => Ok(Quantity::VolumetricFlowRate(a.clone() / b.clone())),
+			(Quantity::Power(a), Quantity::Velocity(b)) => Ok(Quantity::Force(a.clone() / b.clone())),
+			(Quantity::Power(a), Quantity::VolumetricFlowRate(b)) => Ok(Quantity::Pressure(a.clone() / b.clone())),
+			(Quantity::Pressure(a), Quantity::Concentration(b)) => Ok(Quantity::MolarEnergy(a.clone() / b.clone())),
+			(Quantity::Pressure(a), Quantity::MolarEnergy(b)) => Ok(Quantity::Concentration(a.clone() / b.clone())),
+			(Quantity::Pressure(a), Quantity::ElectricField(b)) => Ok(Quantity::SurfaceChargeDensity(a.clone() / b.clone())),
+			(Quantity::Pressure(a), Quantity::Irradiance(b)) => Ok(Quantity::TimePerDistance(a.clone() / b.clone())),
+			(Quantity::Pressure(a), Quantity::MagneticFieldStrength(b)) => Ok(Quantity::MagneticFluxDensity(a.clone() / b.clone())),
+			(Quantity::Pressure(a), Quantity::MagneticFluxDensity(b)) => Ok(Quantity::MagneticFieldStrength(a.clone() / b.clone())),
+			(Quantity::Pressure(a), Quantity::SurfaceChargeDensity(b)) => Ok(Quantity::ElectricField(a.clone() / b.clone())),
+			(Quantity::Pressure(a), Quantity::Voltage(b)) => Ok(Quantity::VolumeChargeDensity(a.clone() / b.clone())),
+			(Quantity::Pressure(a), Quantity::VolumeChargeDensity(b)) => Ok(Quantity::Voltage(a.clone() / b.clone())),
+			(Quantity::Pressure(a), Quantity::InverseArea(b)) => Ok(Quantity::Force(a.clone() / b.clone())),
+			(Quantity::Pressure(a), Quantity::InverseVolume(b)) => Ok(Quantity::Energy(a.clone() / b.clone())),
+			(Quantity::Pressure(a), Quantity::Acceleration(b)) => Ok(Quantity::AreaDensity(a.clone() / b.clone())),
+			(Quantity::Pressure(a), Quantity::AreaDensity(b)) => Ok(Quantity::Acceleration(a.clone() / b.clone())),
+			(Quantity::Pressure(a), Quantity::DynamicViscosity(b)) => Ok(Quantity::Frequency(a.clone() / b.clone())),
+			(Quantity::Pressure(a), Quantity::Energy(b)) => Ok(Quantity::InverseVolume(a.clone() / b.clone())),
+			(Quantity::Pressure(a), Quantity::Torque(b)) => Ok(Quantity::InverseVolume(a.clone() / b.clone())),
+			(Quantity::Pressure(a), Quantity::Force(b)) => Ok(Quantity::InverseArea(a.clone() / b.clone())),
+			(Quantity::Pressure(a), Quantity::Frequency(b)) => Ok(Quantity::DynamicViscosity(a.clone() / b.clone())),
+			(Quantity::Pressure(a), Quantity::InverseVolumetricFlowRate(b)) => Ok(Quantity::Power(a.clone() / b.clone())),
+			(Quantity::Pressure(a), Quantity::Power(b)) => Ok(Quantity::InverseVolumetricFlowRate(a.clone() / b.clone())),
+			(Quantity::Pressure(a), Quantity::TimePerDistance(b)) => Ok(Quantity::Irradiance(a.clone() / b.clone())),
+			(Quantity::TimePerDistance(a), Quantity::Distance(b)) => Ok(Quantity::InverseKinematicViscosity(a.clone() / b.clone())),
+			(Quantity::TimePerDistance(a), Quantity::InverseCurrent(b)) => Ok(Quantity::LinearChargeDensity(a.clone() / b.clone())),
+			(Quantity::TimePerDistance(a), Quantity::InverseDistance(b)) => Ok(Quantity::Time(a.clone() / b.clone())),
+			(Quantity::TimePerDistance(a), Quantity::Mass(b)) => Ok(Quantity::InverseMomentum(a.clone() / b.clone())),
+			(Quantity::TimePerDistance(a), Quantity::Time(b)) => Ok(Quantity::InverseDistance(a.clone() / b.clone())),
+			(Quantity::TimePerDistance(a), Quantity::InverseElectricField(b)) => Ok(Quantity::MagneticFluxDensity(a.clone() / b.clone())),
+			(Quantity::TimePerDistance(a), Quantity::InverseIrradiance(b)) => Ok(Quantity::Pressure(a.clone() / b.clone())),
+			(Quantity::TimePerDistance(a), Quantity::InverseMagneticFieldStrength(b)) => Ok(Quantity::SurfaceChargeDensity(a.clone() / b.clone())),
+			(Quantity::TimePerDistance(a), Quantity::LinearChargeDensity(b)) => Ok(Quantity::InverseCurrent(a.clone() / b.clone())),
+			(Quantity::TimePerDistance(a), Quantity::MagneticFluxDensity(b)) => Ok(Quantity::InverseElectricField(a.clone() / b.clone())),
+			(Quantity::TimePerDistance(a), Quantity::SurfaceChargeDensity(b)) => Ok(Quantity::InverseMagneticFieldStrength(a.clone() / b.clone())),
+			(Quantity::TimePerDistance(a), Quantity::Area(b)) => Ok(Quantity::InverseVolumetricFlowRate(a.clone() / b.clone())),
+			(Quantity::TimePerDistance(a), Quantity::AreaDensity(b)) => Ok(Quantity::InverseDynamicViscosity(a.clone() / b.clone())),
+			(Quantity::TimePerDistance(a), Quantity::Force(b)) => Ok(Quantity::InversePower(a.clone() / b.clone())),
+			(Quantity::TimePerDistance(a), Quantity::Frequency(b)) => Ok(Quantity::InverseAcceleration(a.clone() / b.clone())),
+			(Quantity::TimePerDistance(a), Quantity::InverseAcceleration(b)) => Ok(Quantity::Frequency(a.clone() / b.clone())),
+			(Quantity::TimePerDistance(a), Quantity::InverseDynamicViscosity(b)) => Ok(Quantity::AreaDensity(a.clone() / b.clone())),
+			(Quantity::TimePerDistance(a), Quantity::InverseEnergy(b)) => Ok(Quantity::Momentum(a.clone() / b.clone())),
+			(Quantity::TimePerDistance(a), Quantity::InverseTorque(b)) => Ok(Quantity::Momentum(a.clone() / b.clone())),
+			(Quantity::TimePerDistance(a), Quantity::InverseForce(b)) => Ok(Quantity::MassFlowRate(a.clone() / b.clone())),
+			(Quantity::TimePerDistance(a), Quantity::InverseKinematicViscosity(b)) => Ok(Quantity::Distance(a.clone() / b.clone())),
+			(Quantity::TimePerDistance(a), Quantity::InverseMomentum(b)) => Ok(Quantity::Mass(a.clone() / b.clone())),
+			(Quantity::TimePerDistance(a), Quantity::InversePower(b)) => Ok(Quantity::Force(a.clone() / b.clone())),
+			(Quantity::TimePerDistance(a), Quantity::InverseVolumetricFlowRate(b)) => Ok(Quantity::Area(a.clone() / b.clone())),
+			(Quantity::TimePerDistance(a), Quantity::MassFlowRate(b)) => Ok(Quantity::InverseForce(a.clone() / b.clone())),
+			(Quantity::TimePerDistance(a), Quantity::Momentum(b)) => Ok(Quantity::InverseEnergy(a.clone() / b.clone())),
+			(Quantity::TimePerDistance(a), Quantity::Pressure(b)) => Ok(Quantity::InverseIrradiance(a.clone() / b.clone())),
+			(Quantity::TimePerDistance(a), Quantity::InverseAbsorbedDose(b)) => Ok(Quantity::Velocity(a.clone() / b.clone())),
+			(Quantity::TimePerDistance(a), Quantity::InverseDoseEquivalent(b)) => Ok(Quantity::Velocity(a.clone() / b.clone())),
+			(Quantity::Torque(a), Quantity::Amount(b)) => Ok(Quantity::MolarEnergy(a.clone() / b.clone())),
+			(Quantity::Torque(a), Quantity::Current(b)) => Ok(Quantity::MagneticFlux(a.clone() / b.clone())),
+			(Quantity::Torque(a), Quantity::Distance(b)) => Ok(Quantity::Force(a.clone() / b.clone())),
+			(Quantity::Torque(a), Quantity::Temperature(b)) => Ok(Quantity::HeatCapacity(a.clone() / b.clone())),
+			(Quantity::Torque(a), Quantity::TemperatureInterval(b)) => Ok(Quantity::HeatCapacity(a.clone() / b.clone())),
+			(Quantity::Torque(a), Quantity::Time(b)) => Ok(Quantity::Power(a.clone() / b.clone())),
+			(Quantity::Torque(a), Quantity::Entropy(b)) => Ok(Quantity::Temperature(a.clone() / b.clone())),
+			(Quantity::Torque(a), Quantity::HeatCapacity(b)) => Ok(Quantity::Temperature(a.clone() / b.clone())),
+			(Quantity::Torque(a), Quantity::MolarEnergy(b)) => Ok(Quantity::Amount(a.clone() / b.clone())),
+			(Quantity::Torque(a), Quantity::Charge(b)) => Ok(Quantity::Voltage(a.clone() / b.clone())),
+			(Quantity::Torque(a), Quantity::MagneticFlux(b)) => Ok(Quantity::Current(a.clone() / b.clone())),
+			(Quantity::Torque(a), Quantity::Voltage(b)) => Ok(Quantity::Charge(a.clone() / b.clone())),
+			(Quantity::Torque(a), Quantity::Volume(b)) => Ok(Quantity::Pressure(a.clone() / b.clone())),
+			(Quantity::Torque(a), Quantity::DynamicViscosity(b)) => Ok(Quantity::VolumetricFlowRate(a.clone() / b.clone())),
+			(Quantity::Torque(a), Quantity::Force(b)) => Ok(Quantity::Distance(a.clone() / b.clone())),
+			(Quantity::Torque(a), Quantity::KinematicViscosity(b)) => Ok(Quantity::MassFlowRate(a.clone() / b.clone())),
+			(Quantity::Torque(a), Quantity::MassFlowRate(b)) => Ok(Quantity::KinematicViscosity(a.clone() / b.clone())),
+			(Quantity::Torque(a), Quantity::Momentum(b)) => Ok(Quantity::Velocity(a.clone() / b.clone())),
+			(Quantity::Torque(a), Quantity::Power(b)) => Ok(Quantity::Time(a.clone() / b.clone())),
+			(Quantity::Torque(a), Quantity::Pressure(b)) => Ok(Quantity::Volume(a.clone() / b.clone())),
+			(Quantity::Torque(a), Quantity::Velocity(b)) => Ok(Quantity::Momentum(a.clone() / b.clone())),
+			(Quantity::Torque(a), Quantity::VolumetricFlowRate(b)) => Ok(Quantity::DynamicViscosity(a.clone() / b.clone())),
+			(Quantity::Velocity(a), Quantity::Current(b)) => Ok(Quantity::InverseLinearChargeDensity(a.clone() / b.clone())),
+			(Quantity::Velocity(a), Quantity::Distance(b)) => Ok(Quantity::Frequency(a.clone() / b.clone())),
+			(Quantity::Velocity(a), Quantity::InverseDistance(b)) => Ok(Quantity::KinematicViscosity(a.clone() / b.clone())),
+			(Quantity::Velocity(a), Quantity::InverseMass(b)) => Ok(Quantity::Momentum(a.clone() / b.clone())),
+			(Quantity::Velocity(a), Quantity::Time(b)) => Ok(Quantity::Acceleration(a.clone() / b.clone())),
+			(Quantity::Velocity(a), Quantity::ElectricField(b)) => Ok(Quantity::InverseMagneticFluxDensity(a.clone() / b.clone())),
+			(Quantity::Velocity(a), Quantity::InverseLinearChargeDensity(b)) => Ok(Quantity::Current(a.clone() / b.clone())),
+			(Quantity::Velocity(a), Quantity::InverseMagneticFluxDensity(b)) => Ok(Quantity::ElectricField(a.clone() / b.clone())),
+			(Quantity::Velocity(a), Quantity::InverseSurfaceChargeDensity(b)) => Ok(Quantity::MagneticFieldStrength(a.clone() / b.clone())),
+			(Quantity::Velocity(a), Quantity::Irradiance(b)) => Ok(Quantity::InversePressure(a.clone() / b.clone())),
+			(Quantity::Velocity(a), Quantity::MagneticFieldStrength(b)) => Ok(Quantity::InverseSurfaceChargeDensity(a.clone() / b.clone())),
+			(Quantity::Velocity(a), Quantity::InverseArea(b)) => Ok(Quantity::VolumetricFlowRate(a.clone() / b.clone())),
+			(Quantity::Velocity(a), Quantity::Acceleration(b)) => Ok(Quantity::Time(a.clone() / b.clone())),
+			(Quantity::Velocity(a), Quantity::AreaPerMass(b)) => Ok(Quantity::DynamicViscosity(a.clone() / b.clone())),
+			(Quantity::Velocity(a), Quantity::DynamicViscosity(b)) => Ok(Quantity::AreaPerMass(a.clone() / b.clone())),
+			(Quantity::Velocity(a), Quantity::Energy(b)) => Ok(Quantity::InverseMomentum(a.clone() / b.clone())),
+			(Quantity::Velocity(a), Quantity::Torque(b)) => Ok(Quantity::InverseMomentum(a.clone() / b.clone())),
+			(Quantity::Velocity(a), Quantity::Force(b)) => Ok(Quantity::InverseMassFlowRate(a.clone() / b.clone())),
+			(Quantity::Velocity(a), Quantity::Frequency(b)) => Ok(Quantity::Distance(a.clone() / b.clone())),
+			(Quantity::Velocity(a), Quantity::InverseForce(b)) => Ok(Quantity::Power(a.clone() / b.clone())),
+			(Quantity::Velocity(a), Quantity::InverseMassFlowRate(b)) => Ok(Quantity::Force(a.clone() / b.clone())),
+			(Quantity::Velocity(a), Quantity::InverseMomentum(b)) => Ok(Quantity::Energy(a.clone() / b.clone())),
+			(Quantity::Velocity(a), Quantity::InversePressure(b)) => Ok(Quantity::Irradiance(a.clone() / b.clone())),
+			(Quantity::Velocity(a), Quantity::KinematicViscosity(b)) => Ok(Quantity::InverseDistance(a.clone() / b.clone())),
+			(Quantity::Velocity(a), Quantity::Momentum(b)) => Ok(Quantity::InverseMass(a.clone() / b.clone())),
+			(Quantity::Velocity(a), Quantity::Power(b)) => Ok(Quantity::InverseForce(a.clone() / b.clone())),
+			(Quantity::Velocity(a), Quantity::VolumetricFlowRate(b)) => Ok(Quantity::InverseArea(a.clone() / b.clone())),
+			(Quantity::VolumePerMass(a), Quantity::Distance(b)) => Ok(Quantity::AreaPerMass(a.clone() / b.clone())),
+			(Quantity::VolumePerMass(a), Quantity::InverseMass(b)) => Ok(Quantity::Volume(a.clone() / b.clone())),
+			(Quantity::VolumePerMass(a), Quantity::Molality(b)) => Ok(Quantity::MolarVolume(a.clone() / b.clone())),
+			(Quantity::VolumePerMass(a), Quantity::MolarVolume(b)) => Ok(Quantity::Molality(a.clone() / b.clone())),
+			(Quantity::VolumePerMass(a), Quantity::Volume(b)) => Ok(Quantity::InverseMass(a.clone() / b.clone())),
+			(Quantity::VolumePerMass(a), Quantity::AreaPerMass(b)) => Ok(Quantity::Distance(a.clone() / b.clone())),
+			(Quantity::VolumePerMass(a), Quantity::InverseDynamicViscosity(b)) => Ok(Quantity::KinematicViscosity(a.clone() / b.clone())),
+			(Quantity::VolumePerMass(a), Quantity::InverseMassFlowRate(b)) => Ok(Quantity::VolumetricFlowRate(a.clone() / b.clone())),
+			(Quantity::VolumePerMass(a), Quantity::KinematicViscosity(b)) => Ok(Quantity::InverseDynamicViscosity(a.clone() / b.clone())),
+			(Quantity::VolumePerMass(a), Quantity::VolumetricFlowRate(b)) => Ok(Quantity::InverseMassFlowRate(a.clone() / b.clone())),
+			(Quantity::VolumetricFlowRate(a), Quantity::Current(b)) => Ok(Quantity::InverseVolumeChargeDensity(a.clone() / b.clone())),
+			(Quantity::VolumetricFlowRate(a), Quantity::Distance(b)) => Ok(Quantity::KinematicViscosity(a.clone() / b.clone())),
+			(Quantity::VolumetricFlowRate(a), Quantity::CatalyticActivity(b)) => Ok(Quantity::MolarVolume(a.clone() / b.clone())),
+			(Quantity::VolumetricFlowRate(a), Quantity::MolarVolume(b)) => Ok(Quantity::CatalyticActivity(a.clone() / b.clone())),
+			(Quantity::VolumetricFlowRate(a), Quantity::InverseVolumeChargeDensity(b)) => Ok(Quantity::Current(a.clone() / b.clone())),
+			(Quantity::VolumetricFlowRate(a), Quantity::Area(b)) => Ok(Quantity::Velocity(a.clone() / b.clone())),
+			(Quantity::VolumetricFlowRate(a), Quantity::Volume(b)) => Ok(Quantity::Frequency(a.clone() / b.clone())),
+			(Quantity::VolumetricFlowRate(a), Quantity::AreaPerMass(b)) => Ok(Quantity::Momentum(a.clone() / b.clone())),
+			(Quantity::VolumetricFlowRate(a), Quantity::Energy(b)) => Ok(Quantity::InverseDynamicViscosity(a.clone() / b.clone())),
+			(Quantity::VolumetricFlowRate(a), Quantity::Torque(b)) => Ok(Quantity::InverseDynamicViscosity(a.clone() / b.clone())),
+			(Quantity::VolumetricFlowRate(a), Quantity::Frequency(b)) => Ok(Quantity::Volume(a.clone() / b.clone())),
+			(Quantity::VolumetricFlowRate(a), Quantity::InverseDynamicViscosity(b)) => Ok(Quantity::Energy(a.clone() / b.clone())),
+			(Quantity::VolumetricFlowRate(a), Quantity::InversePressure(b)) => Ok(Quantity::Power(a.clone() / b.clone())),
+			(Quantity::VolumetricFlowRate(a), Quantity::KinematicViscosity(b)) => Ok(Quantity::Distance(a.clone() / b.clone())),
+			(Quantity::VolumetricFlowRate(a), Quantity::MassFlowRate(b)) => Ok(Quantity::VolumePerMass(a.clone() / b.clone())),
+			(Quantity::VolumetricFlowRate(a), Quantity::Momentum(b)) => Ok(Quantity::AreaPerMass(a.clone() / b.clone())),
+			(Quantity::VolumetricFlowRate(a), Quantity::Power(b)) => Ok(Quantity::InversePressure(a.clone() / b.clone())),
+			(Quantity::VolumetricFlowRate(a), Quantity::Velocity(b)) => Ok(Quantity::Area(a.clone() / b.clone())),
+			(Quantity::VolumetricFlowRate(a), Quantity::VolumePerMass(b)) => Ok(Quantity::MassFlowRate(a.clone() / b.clone())),
+			(Quantity::AbsorbedDose(a), Quantity::TemperatureInterval(b)) => Ok(Quantity::SpecificHeatCapacity(a.clone() / b.clone())),
+			(Quantity::AbsorbedDose(a), Quantity::Time(b)) => Ok(Quantity::AbsorbedDoseRate(a.clone() / b.clone())),
+			(Quantity::AbsorbedDose(a), Quantity::SpecificHeatCapacity(b)) => Ok(Quantity::TemperatureInterval(a.clone() / b.clone())),
+			(Quantity::AbsorbedDose(a), Quantity::AbsorbedDoseRate(b)) => Ok(Quantity::Time(a.clone() / b.clone())),
+			(Quantity::DoseEquivalent(a), Quantity::Time(b)) => Ok(Quantity::DoseEquivalentRate(a.clone() / b.clone())),
+			(Quantity::DoseEquivalent(a), Quantity::DoseEquivalentRate(b)) => Ok(Quantity::Time(a.clone() / b.clone())),
+			(Quantity::InverseAbsorbedDose(a), Quantity::InverseDistance(b)) => Ok(Quantity::InverseAcceleration(a.clone() / b.clone())),
+			(Quantity::InverseAbsorbedDose(a), Quantity::InverseTemperature(b)) => Ok(Quantity::InverseSpecificHeatCapacity(a.clone() / b.clone())),
+			(Quantity::InverseAbsorbedDose(a), Quantity::Mass(b)) => Ok(Quantity::InverseEnergy(a.clone() / b.clone())),
+			(Quantity::InverseAbsorbedDose(a), Quantity::Time(b)) => Ok(Quantity::InverseKinematicViscosity(a.clone() / b.clone())),
+			(Quantity::InverseAbsorbedDose(a), Quantity::InverseMolarEnergy(b)) => Ok(Quantity::MolarMass(a.clone() / b.clone())),
+			(Quantity::InverseAbsorbedDose(a), Quantity::InverseSpecificHeatCapacity(b)) => Ok(Quantity::InverseTemperature(a.clone() / b.clone())),
+			(Quantity::InverseAbsorbedDose(a), Quantity::MolarMass(b)) => Ok(Quantity::InverseMolarEnergy(a.clone() / b.clone())),
+			(Quantity::InverseAbsorbedDose(a), Quantity::Density(b)) => Ok(Quantity::InversePressure(a.clone() / b.clone())),
+			(Quantity::InverseAbsorbedDose(a), Quantity::InverseAcceleration(b)) => Ok(Quantity::InverseDistance(a.clone() / b.clone())),
+			(Quantity::InverseAbsorbedDose(a), Quantity::InverseEnergy(b)) => Ok(Quantity::Mass(a.clone() / b.clone())),
+			(Quantity::InverseAbsorbedDose(a), Quantity::InverseTorque(b)) => Ok(Quantity::Mass(a.clone() / b.clone())),
+			(Quantity::InverseAbsorbedDose(a), Quantity::InverseKinematicViscosity(b)) => Ok(Quantity::Time(a.clone() / b.clone())),
+			(Quantity::InverseAbsorbedDose(a), Quantity::InversePower(b)) => Ok(Quantity::MassFlowRate(a.clone() / b.clone())),
+			(Quantity::InverseAbsorbedDose(a), Quantity::InversePressure(b)) => Ok(Quantity::Density(a.clone() / b.clone())),
+			(Quantity::InverseAbsorbedDose(a), Quantity::MassFlowRate(b)) => Ok(Quantity::InversePower(a.clone() / b.clone())),
+			(Quantity::InverseAbsorbedDose(a), Quantity::TimePerDistance(b)) => Ok(Quantity::TimePerDistance(a.clone() / b.clone())),
+			(Quantity::InverseDoseEquivalent(a), Quantity::InverseDistance(b)) => Ok(Quantity::InverseAcceleration(a.clone() / b.clone())),
+			(Quantity::InverseDoseEquivalent(a), Quantity::InverseTemperature(b)) => Ok(Quantity::InverseSpecificHeatCapacity(a.clone() / b.clone())),
+			(Quantity::InverseDoseEquivalent(a), Quantity::Mass(b)) => Ok(Quantity::InverseEnergy(a.clone() / b.clone())),
+			(Quantity::InverseDoseEquivalent(a), Quantity::Time(b)) => Ok(Quantity::InverseKinematicViscosity(a.clone() / b.clone())),
+			(Quantity::InverseDoseEquivalent(a), Quantity::InverseMolarEnergy(b)) => Ok(Quantity::MolarMass(a.clone() / b.clone())),
+			(Quantity::InverseDoseEquivalent(a), Quantity::InverseSpecificHeatCapacity(b)) => Ok(Quantity::InverseTemperature(a.clone() / b.clone())),
+			(Quantity::InverseDoseEquivalent(a), Quantity::MolarMass(b)) => Ok(Quantity::InverseMolarEnergy(a.clone() / b.clone())),
+			(Quantity::InverseDoseEquivalent(a), Quantity::Density(b)) => Ok(Quantity::InversePressure(a.clone() / b.clone())),
+			(Quantity::InverseDoseEquivalent(a), Quantity::InverseAcceleration(b)) => Ok(Quantity::InverseDistance(a.clone() / b.clone())),
+			(Quantity::InverseDoseEquivalent(a), Quantity::InverseEnergy(b)) => Ok(Quantity::Mass(a.clone() / b.clone())),
+			(Quantity::InverseDoseEquivalent(a), Quantity::InverseTorque(b)) => Ok(Quantity::Mass(a.clone() / b.clone())),
+			(Quantity::InverseDoseEquivalent(a), Quantity::InverseKinematicViscosity(b)) => Ok(Quantity::Time(a.clone() / b.clone())),
+			(Quantity::InverseDoseEquivalent(a), Quantity::InversePower(b)) => Ok(Quantity::MassFlowRate(a.clone() / b.clone())),
+			(Quantity::InverseDoseEquivalent(a), Quantity::InversePressure(b)) => Ok(Quantity::Density(a.clone() / b.clone())),
+			(Quantity::InverseDoseEquivalent(a), Quantity::MassFlowRate(b)) => Ok(Quantity::InversePower(a.clone() / b.clone())),
+			(Quantity::InverseDoseEquivalent(a), Quantity::TimePerDistance(b)) => Ok(Quantity::TimePerDistance(a.clone() / b.clone())),
+			_ => Err(self.dimension_error("divide", other))
+		}
+	}
+
+	fn dimension_error(&self, operation: &'static str, other: &Quantity<T>) -> DimensionError {
+		DimensionError{operation, left: self.unit_symbol(), right: other.unit_symbol()}
+	}
+}
+
+/// Multiplying a quantity by a scalar value returns a quantity of the same unit type
+impl<T> core::ops::Mul<T> for Quantity<T> where T: NumLike {
+	type Output = Quantity<T>;
+	fn mul(self, rhs: T) -> Self::Output {
+		match self {
+			Quantity::Scalar(v) => Quantity::Scalar(v * rhs),
+			Quantity::Amount(v) => Quantity::Amount(v * rhs),
+			Quantity::Current(v) => Quantity::Current(v * rhs),
+			Quantity::Distance(v) => Quantity::Distance(v * rhs),
+			Quantity::InverseAmount(v) => Quantity::InverseAmount(v * rhs),
+			Quantity::InverseCurrent(v) => Quantity::InverseCurrent(v * rhs),
+			Quantity::InverseDistance(v) => Quantity::InverseDistance(v * rhs),
+			Quantity::InverseLuminosity(v) => Quantity::InverseLuminosity(v * rhs),
+			Quantity::InverseMass(v) => Quantity::InverseMass(v * rhs),
+			Quantity::InverseTemperature(v) => Quantity::InverseTemperature(v * rhs),
+			Quantity::Luminosity(v) => Quantity::Luminosity(v * rhs),
+			Quantity::Mass(v) => Quantity::Mass(v * rhs),
+			Quantity::Temperature(v) => Quantity::Temperature(v * rhs),
+			Quantity::TemperatureInterval(v) => Quantity::TemperatureInterval(v * rhs),
+			Quantity::Time(v) => Quantity::Time(v * rhs),
+			Quantity::CatalyticActivity(v) => Quantity::CatalyticActivity(v * rhs),
+			Quantity::Concentration(v) => Quantity::Concentration(v * rhs),
+			Quantity::Entropy(v) => Quantity::Entropy(v * rhs),
+			Quantity::HeatCapacity(v) => Quantity::HeatCapacity(v * rhs),
+			Quantity::InverseCatalyticActivity(v) => Quantity::InverseCatalyticActivity(v * rhs),
+			Quantity::InverseEntropy(v) => Quantity::InverseEntropy(v * rhs),
+			Quantity::InverseHeatCapacity(v) => Quantity::InverseHeatCapacity(v * rhs),
+			Quantity::InverseMolarEnergy(v) => Quantity::InverseMolarEnergy(v * rhs),
+			Quantity::InverseMolarEntropy(v) => Quantity::InverseMolarEntropy(v * rhs),
+			Quantity::InverseSpecificHeatCapacity(v) => Quantity::InverseSpecificHeatCapacity(v * rhs),
+			Quantity::Molality(v) => Quantity::Molality(v * rhs),
+			Quantity::MolarEnergy(v) => Quantity::MolarEnergy(v * rhs),
+			Quantity::MolarEntropy(v) => Quantity::MolarEntropy(v * rhs),
+			Quantity::MolarMass(v) => Quantity::MolarMass(v * rhs),
+			Quantity::MolarVolume(v) => Quantity::MolarVolume(v * rhs),
+			Quantity::SpecificHeatCapacity(v) => Quantity::SpecificHeatCapacity(v * rhs),
+			Quantity::ThermalConductance(v) => Quantity::ThermalConductance(v * rhs),
+			Quantity::ThermalConductivity(v) => Quantity::ThermalConductivity(v * rhs),
+			Quantity::ThermalResistance(v) => Quantity::ThermalResistance(v * rhs),
+			Quantity::ThermalResistivity(v) => Quantity::ThermalResistivity(v * rhs),
+			Quantity::AreaPerLumen(v) => Quantity::AreaPerLumen(v * rhs),
+			Quantity::Capacitance(v) => Quantity::Capacitance(v * rhs),
+			Quantity::Charge(v) => Quantity::Charge(v * rhs),
+			Quantity::Conductance(v) => Quantity::Conductance(v * rhs),
+			Quantity::Elastance(v) => Quantity::Elastance(v * rhs),
+			Quantity::ElectricField(v) => Quantity::ElectricField(v * rhs),
+			Quantity::Illuminance(v) => Quantity::Illuminance(v * rhs),
+			Quantity::Inductance(v) => Quantity::Inductance(v * rhs),
+			Quantity::InverseCharge(v) => Quantity::InverseCharge(v * rhs),
+			Quantity::InverseElectricField(v) => Quantity::InverseElectricField(v * rhs),
+			Quantity::InverseInductance(v) => Quantity::InverseInductance(v * rhs),
+			Quantity::InverseIrradiance(v) => Quantity::InverseIrradiance(v * rhs),
+			Quantity::InverseLinearChargeDensity(v) => Quantity::InverseLinearChargeDensity(v * rhs),
+			Quantity::InverseLuminousFlux(v) => Quantity::InverseLuminousFlux(v * rhs),
+			Quantity::InverseMagneticFieldStrength(v) => Quantity::InverseMagneticFieldStrength(v * rhs),
+			Quantity::InverseMagneticFlux(v) => Quantity::InverseMagneticFlux(v * rhs),
+			Quantity::InverseMagneticFluxDensity(v) => Quantity::InverseMagneticFluxDensity(v * rhs),
+			Quantity::InverseRadiance(v) => Quantity::InverseRadiance(v * rhs),
+			Quantity::InverseSurfaceChargeDensity(v) => Quantity::InverseSurfaceChargeDensity(v * rhs),
+			Quantity::InverseVoltage(v) => Quantity::InverseVoltage(v * rhs),
+			Quantity::InverseVolumeChargeDensity(v) => Quantity::InverseVolumeChargeDensity(v * rhs),
+			Quantity::Irradiance(v) => Quantity::Irradiance(v * rhs),
+			Quantity::LinearChargeDensity(v) => Quantity::LinearChargeDensity(v * rhs),
+			Quantity::LuminousFlux(v) => Quantity::LuminousFlux(v * rhs),
+			Quantity::MagneticFieldStrength(v) => Quantity::MagneticFieldStrength(v * rhs),
+			Quantity::MagneticFlux(v) => Quantity::MagneticFlux(v * rhs),
+			Quantity::MagneticFluxDensity(v) => Quantity::MagneticFluxDensity(v * rhs),
+			Quantity::Radiance(v) => Quantity::Radiance(v * rhs),
+			Quantity::Resistance(v) => Quantity::Resistance(v * rhs),
+			Quantity::SurfaceChargeDensity(v) => Quantity::SurfaceChargeDensity(v * rhs),
+			Quantity::Voltage(v) => Quantity::Voltage(v * rhs),
+			Quantity::VolumeChargeDensity(v) => Quantity::VolumeChargeDensity(v * rhs),
+			Quantity::Angle(v) => Quantity::Angle(v * rhs),
+			Quantity::Area(v) => Quantity::Area(v * rhs),
+			Quantity::InverseAngle(v) => Quantity::InverseAngle(v * rhs),
+			Quantity::InverseArea(v) => Quantity::InverseArea(v * rhs),
+			Quantity::InverseSolidAngle(v) => Quantity::InverseSolidAngle(v * rhs),
+			Quantity::InverseVolume(v) => Quantity::InverseVolume(v * rhs),
+			Quantity::SolidAngle(v) => Quantity::SolidAngle(v * rhs),
+			Quantity::Volume(v) => Quantity::Volume(v * rhs),
+			Quantity::Acceleration(v) => Quantity::Acceleration(v * rhs),
+			Quantity::AngularAcceleration(v) => Quantity::AngularAcceleration(v * rhs),
+			Quantity::AngularMomentum(v) => Quantity::AngularMomentum(v * rhs),
+			Quantity::AngularVelocity(v) => Quantity::AngularVelocity(v * rhs),
+			Quantity::AreaDensity(v) => Quantity::AreaDensity(v * rhs),
+			Quantity::AreaPerMass(v) => Quantity::AreaPerMass(v * rhs),
+			Quantity::Density(v) => Quantity::Density(v * rhs),
+			Quantity::DynamicViscosity(v) => Quantity::DynamicViscosity(v * rhs),
+			Quantity::Energy(v) => Quantity::Energy(v * rhs),
+			Quantity::Force(v) => Quantity::Force(v * rhs),
+			Quantity::Frequency(v) => Quantity::Frequency(v * rhs),
+			Quantity::InverseAcceleration(v) => Quantity::InverseAcceleration(v * rhs),
+			Quantity::InverseAngularAcceleration(v) => Quantity::InverseAngularAcceleration(v * rhs),
+			Quantity::InverseAngularMomentum(v) => Quantity::InverseAngularMomentum(v * rhs),
+			Quantity::InverseAngularVelocity(v) => Quantity::InverseAngularVelocity(v * rhs),
+			Quantity::InverseDynamicViscosity(v) => Quantity::InverseDynamicViscosity(v * rhs),
+			Quantity::InverseEnergy(v) => Quantity::InverseEnergy(v * rhs),
+			Quantity::InverseForce(v) => Quantity::InverseForce(v * rhs),
+			Quantity::InverseJerk(v) => Quantity::InverseJerk(v * rhs),
+			Quantity::InverseKinematicViscosity(v) => Quantity::InverseKinematicViscosity(v * rhs),
+			Quantity::InverseMassFlowRate(v) => Quantity::InverseMassFlowRate(v * rhs),
+			Quantity::InverseMomentOfInertia(v) => Quantity::InverseMomentOfInertia(v * rhs),
+			Quantity::InverseMomentum(v) => Quantity::InverseMomentum(v * rhs),
+			Quantity::InversePower(v) => Quantity::InversePower(v * rhs),
+			Quantity::InversePressure(v) => Quantity::InversePressure(v * rhs),
+			Quantity::InverseTorque(v) => Quantity::InverseTorque(v * rhs),
+			Quantity::InverseVolumetricFlowRate(v) => Quantity::InverseVolumetricFlowRate(v * rhs),
+			Quantity::Jerk(v) => Quantity::Jerk(v * rhs),
+			Quantity::KinematicViscosity(v) => Quantity::KinematicViscosity(v * rhs),
+			Quantity::MassFlowRate(v) => Quantity::MassFlowRate(v * rhs),
+			Quantity::MomentOfInertia(v) => Quantity::MomentOfInertia(v * rhs),
+			Quantity::Momentum(v) => Quantity::Momentum(v * rhs),
+			Quantity::Power(v) => Quantity::Power(v * rhs),
+			Quantity::Pressure(v) => Quantity::Pressure(v * rhs),
+			Quantity::TimePerDistance(v) => Quantity::TimePerDistance(v * rhs),
+			Quantity::Torque(v) => Quantity::Torque(v * rhs),
+			Quantity::Velocity(v) => Quantity::Velocity(v * rhs),
+			Quantity::VolumePerMass(v) => Quantity::VolumePerMass(v * rhs),
+			Quantity::VolumetricFlowRate(v) => Quantity::VolumetricFlowRate(v * rhs),
+			Quantity::AbsorbedDose(v) => Quantity::AbsorbedDose(v * rhs),
+			Quantity::AbsorbedDoseRate(v) => Quantity::AbsorbedDoseRate(v * rhs),
+			Quantity::DoseEquivalent(v) => Quantity::DoseEquivalent(v * rhs),
+			Quantity::DoseEquivalentRate(v) => Quantity::DoseEquivalentRate(v * rhs),
+			Quantity::InverseAbsorbedDose(v) => Quantity::InverseAbsorbedDose(v * rhs),
+			Quantity::InverseAbsorbedDoseRate(v) => Quantity::InverseAbsorbedDoseRate(v * rhs),
+			Quantity::InverseDoseEquivalent(v) => Quantity::InverseDoseEquivalent(v * rhs),
+			Quantity::InverseDoseEquivalentRate(v) => Quantity::InverseDoseEquivalentRate(v * rhs),
+			Quantity::Radioactivity(v) => Quantity::Radioactivity(v * rhs),
+		}
+	}
+}
+
+/// Dividing a quantity by a scalar value returns a quantity of the same unit type
+impl<T> core::ops::Div<T> for Quantity<T> where T: NumLike {
+	type Output = Quantity<T>;
+	fn div(self, rhs: T) -> Self::Output {
+		match self {
+			Quantity::Scalar(v) => Quantity::Scalar(v / rhs),
+			Quantity::Amount(v) => Quantity::Amount(v / rhs),
+			Quantity::Current(v) => Quantity::Current(v / rhs),
+			Quantity::Distance(v) => Quantity::Distance(v / rhs),
+			Quantity::InverseAmount(v) => Quantity::InverseAmount(v / rhs),
+			Quantity::InverseCurrent(v) => Quantity::InverseCurrent(v / rhs),
+			Quantity::InverseDistance(v) => Quantity::InverseDistance(v / rhs),
+			Quantity::InverseLuminosity(v) => Quantity::InverseLuminosity(v / rhs),
+			Quantity::InverseMass(v) => Quantity::InverseMass(v / rhs),
+			Quantity::InverseTemperature(v) => Quantity::InverseTemperature(v / rhs),
+			Quantity::Luminosity(v) => Quantity::Luminosity(v / rhs),
+			Quantity::Mass(v) => Quantity::Mass(v / rhs),
+			Quantity::Temperature(v) => Quantity::Temperature(v / rhs),
+			Quantity::TemperatureInterval(v) => Quantity::TemperatureInterval(v / rhs),
+			Quantity::Time(v) => Quantity::Time(v / rhs),
+			Quantity::CatalyticActivity(v) => Quantity::CatalyticActivity(v / rhs),
+			Quantity::Concentration(v) => Quantity::Concentration(v / rhs),
+			Quantity::Entropy(v) => Quantity::Entropy(v / rhs),
+			Quantity::HeatCapacity(v) => Quantity::HeatCapacity(v / rhs),
+			Quantity::InverseCatalyticActivity(v) => Quantity::InverseCatalyticActivity(v / rhs),
+			Quantity::InverseEntropy(v) => Quantity::InverseEntropy(v / rhs),
+			Quantity::InverseHeatCapacity(v) => Quantity::InverseHeatCapacity(v / rhs),
+			Quantity::InverseMolarEnergy(v) => Quantity::InverseMolarEnergy(v / rhs),
+			Quantity::InverseMolarEntropy(v) => Quantity::InverseMolarEntropy(v / rhs),
+			Quantity::InverseSpecificHeatCapacity(v) => Quantity::InverseSpecificHeatCapacity(v / rhs),
+			Quantity::Molality(v) => Quantity::Molality(v / rhs),
+			Quantity::MolarEnergy(v) => Quantity::MolarEnergy(v / rhs),
+			Quantity::MolarEntropy(v) => Quantity::MolarEntropy(v / rhs),
+			Quantity::MolarMass(v) => Quantity::MolarMass(v / rhs),
+			Quantity::MolarVolume(v) => Quantity::MolarVolume(v / rhs),
+			Quantity::SpecificHeatCapacity(v) => Quantity::SpecificHeatCapacity(v / rhs),
+			Quantity::ThermalConductance(v) => Quantity::ThermalConductance(v / rhs),
+			Quantity::ThermalConductivity(v) => Quantity::ThermalConductivity(v / rhs),
+			Quantity::ThermalResistance(v) => Quantity::ThermalResistance(v / rhs),
+			Quantity::ThermalResistivity(v) => Quantity::ThermalResistivity(v / rhs),
+			Quantity::AreaPerLumen(v) => Quantity::AreaPerLumen(v / rhs),
+			Quantity::Capacitance(v) => Quantity::Capacitance(v / rhs),
+			Quantity::Charge(v) => Quantity::Charge(v / rhs),
+			Quantity::Conductance(v) => Quantity::Conductance(v / rhs),
+			Quantity::Elastance(v) => Quantity::Elastance(v / rhs),
+			Quantity::ElectricField(v) => Quantity::ElectricField(v / rhs),
+			Quantity::Illuminance(v) => Quantity::Illuminance(v / rhs),
+			Quantity::Inductance(v) => Quantity::Inductance(v / rhs),
+			Quantity::InverseCharge(v) => Quantity::InverseCharge(v / rhs),
+			Quantity::InverseElectricField(v) => Quantity::InverseElectricField(v / rhs),
+			Quantity::InverseInductance(v) => Quantity::InverseInductance(v / rhs),
+			Quantity::InverseIrradiance(v) => Quantity::InverseIrradiance(v / rhs),
+			Quantity::InverseLinearChargeDensity(v) => Quantity::InverseLinearChargeDensity(v / rhs),
+			Quantity::InverseLuminousFlux(v) => Quantity::InverseLuminousFlux(v / rhs),
+			Quantity::InverseMagneticFieldStrength(v) => Quantity::InverseMagneticFieldStrength(v / rhs),
+			Quantity::InverseMagneticFlux(v) => Quantity::InverseMagneticFlux(v / rhs),
+			Quantity::InverseMagneticFluxDensity(v) => Quantity::InverseMagneticFluxDensity(v / rhs),
+			Quantity::InverseRadiance(v) => Quantity::InverseRadiance(v / rhs),
+			Quantity::InverseSurfaceChargeDensity(v) => Quantity::InverseSurfaceChargeDensity(v / rhs),
+			Quantity::InverseVoltage(v) => Quantity::InverseVoltage(v / rhs),
+			Quantity::InverseVolumeChargeDensity(v) => Quantity::InverseVolumeChargeDensity(v / rhs),
+			Quantity::Irradiance(v) => Quantity::Irradiance(v / rhs),
+			Quantity::LinearChargeDensity(v) => Quantity::LinearChargeDensity(v / rhs),
+			Quantity::LuminousFlux(v) => Quantity::LuminousFlux(v / rhs),
+			Quantity::MagneticFieldStrength(v) => Quantity::MagneticFieldStrength(v / rhs),
+			Quantity::MagneticFlux(v) => Quantity::MagneticFlux(v / rhs),
+			Quantity::MagneticFluxDensity(v) => Quantity::MagneticFluxDensity(v / rhs),
+			Quantity::Radiance(v) => Quantity::Radiance(v / rhs),
+			Quantity::Resistance(v) => Quantity::Resistance(v / rhs),
+			Quantity::SurfaceChargeDensity(v) => Quantity::SurfaceChargeDensity(v / rhs),
+			Quantity::Voltage(v) => Quantity::Voltage(v / rhs),
+			Quantity::VolumeChargeDensity(v) => Quantity::VolumeChargeDensity(v / rhs),
+			Quantity::Angle(v) => Quantity::Angle(v / rhs),
+			Quantity::Area(v) => Quantity::Area(v / rhs),
+			Quantity::InverseAngle(v) => Quantity::InverseAngle(v / rhs),
+			Quantity::InverseArea(v) => Quantity::InverseArea(v / rhs),
+			Quantity::InverseSolidAngle(v) => Quantity::InverseSolidAngle(v / rhs),
+			Quantity::InverseVolume(v) => Quantity::InverseVolume(v / rhs),
+			Quantity::SolidAngle(v) => Quantity::SolidAngle(v / rhs),
+			Quantity::Volume(v) => Quantity::Volume(v / rhs),
+			Quantity::Acceleration(v) => Quantity::Acceleration(v / rhs),
+			Quantity::AngularAcceleration(v) => Quantity::AngularAcceleration(v / rhs),
+			Quantity::AngularMomentum(v) => Quantity::AngularMomentum(v / rhs),
+			Quantity::AngularVelocity(v) => Quantity::AngularVelocity(v / rhs),
+			Quantity::AreaDensity(v) => Quantity::AreaDensity(v / rhs),
+			Quantity::AreaPerMass(v) => Quantity::AreaPerMass(v / rhs),
+			Quantity::Density(v) => Quantity::Density(v / rhs),
+			Quantity::DynamicViscosity(v) => Quantity::DynamicViscosity(v / rhs),
+			Quantity::Energy(v) => Quantity::Energy(v / rhs),
+			Quantity::Force(v) => Quantity::Force(v / rhs),
+			Quantity::Frequency(v) => Quantity::Frequency(v / rhs),
+			Quantity::InverseAcceleration(v) => Quantity::InverseAcceleration(v / rhs),
+			Quantity::InverseAngularAcceleration(v) => Quantity::InverseAngularAcceleration(v / rhs),
+			Quantity::InverseAngularMomentum(v) => Quantity::InverseAngularMomentum(v / rhs),
+			Quantity::InverseAngularVelocity(v) => Quantity::InverseAngularVelocity(v / rhs),
+			Quantity::InverseDynamicViscosity(v) => Quantity::InverseDynamicViscosity(v / rhs),
+			Quantity::InverseEnergy(v) => Quantity::InverseEnergy(v / rhs),
+			Quantity::InverseForce(v) => Quantity::InverseForce(v / rhs),
+			Quantity::InverseJerk(v) => Quantity::InverseJerk(v / rhs),
+			Quantity::InverseKinematicViscosity(v) => Quantity::InverseKinematicViscosity(v / rhs),
+			Quantity::InverseMassFlowRate(v) => Quantity::InverseMassFlowRate(v / rhs),
+			Quantity::InverseMomentOfInertia(v) => Quantity::InverseMomentOfInertia(v / rhs),
+			Quantity::InverseMomentum(v) => Quantity::InverseMomentum(v / rhs),
+			Quantity::InversePower(v) => Quantity::InversePower(v / rhs),
+			Quantity::InversePressure(v) => Quantity::InversePressure(v / rhs),
+			Quantity::InverseTorque(v) => Quantity::InverseTorque(v / rhs),
+			Quantity::InverseVolumetricFlowRate(v) => Quantity::InverseVolumetricFlowRate(v / rhs),
+			Quantity::Jerk(v) => Quantity::Jerk(v / rhs),
+			Quantity::KinematicViscosity(v) => Quantity::KinematicViscosity(v / rhs),
+			Quantity::MassFlowRate(v) => Quantity::MassFlowRate(v / rhs),
+			Quantity::MomentOfInertia(v) => Quantity::MomentOfInertia(v / rhs),
+			Quantity::Momentum(v) => Quantity::Momentum(v / rhs),
+			Quantity::Power(v) => Quantity::Power(v / rhs),
+			Quantity::Pressure(v) => Quantity::Pressure(v / rhs),
+			Quantity::TimePerDistance(v) => Quantity::TimePerDistance(v / rhs),
+			Quantity::Torque(v) => Quantity::Torque(v / rhs),
+			Quantity::Velocity(v) => Quantity::Velocity(v / rhs),
+			Quantity::VolumePerMass(v) => Quantity::VolumePerMass(v / rhs),
+			Quantity::VolumetricFlowRate(v) => Quantity::VolumetricFlowRate(v / rhs),
+			Quantity::AbsorbedDose(v) => Quantity::AbsorbedDose(v / rhs),
+			Quantity::AbsorbedDoseRate(v) => Quantity::AbsorbedDoseRate(v / rhs),
+			Quantity::DoseEquivalent(v) => Quantity::DoseEquivalent(v / rhs),
+			Quantity::DoseEquivalentRate(v) => Quantity::DoseEquivalentRate(v / rhs),
+			Quantity::InverseAbsorbedDose(v) => Quantity::InverseAbsorbedDose(v / rhs),
+			Quantity::InverseAbsorbedDoseRate(v) => Quantity::InverseAbsorbedDoseRate(v / rhs),
+			Quantity::InverseDoseEquivalent(v) => Quantity::InverseDoseEquivalent(v / rhs),
+			Quantity::InverseDoseEquivalentRate(v) => Quantity::InverseDoseEquivalentRate(v / rhs),
+			Quantity::Radioactivity(v) => Quantity::Radioactivity(v / rhs),
+		}
+	}
+}
+
+/// Formats the quantity the same way as the unit struct that it holds
+impl<T> fmt::Display for Quantity<T> where T: NumLike {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			Quantity::Scalar(v) => fmt::Display::fmt(v, f),
+			Quantity::Amount(v) => fmt::Display::fmt(v, f),
+			Quantity::Current(v) => fmt::Display::fmt(v, f),
+			Quantity::Distance(v) => fmt::Display::fmt(v, f),
+			Quantity::InverseAmount(v) => fmt::Display::fmt(v, f),
+			Quantity::InverseCurrent(v) => fmt::Display::fmt(v, f),
+			Quantity::InverseDistance(v) => fmt::Display::fmt(v, f),
+			Quantity::InverseLuminosity(v) => fmt::Display::fmt(v, f),
+			Quantity::InverseMass(v) => fmt::Display::fmt(v, f),
+			Quantity::InverseTemperature(v) => fmt::Display::fmt(v, f),
+			Quantity::Luminosity(v) => fmt::Display::fmt(v, f),
+			Quantity::Mass(v) => fmt::Display::fmt(v, f),
+			Quantity::Temperature(v) => fmt::Display::fmt(v, f),
+			Quantity::TemperatureInterval(v) => fmt::Display::fmt(v, f),
+			Quantity::Time(v) => fmt::Display::fmt(v, f),
+			Quantity::CatalyticActivity(v) => fmt::Display::fmt(v, f),
+			Quantity::Concentration(v) => fmt::Display::fmt(v, f),
+			Quantity::Entropy(v) => fmt::Display::fmt(v, f),
+			Quantity::HeatCapacity(v) => fmt::Display::fmt(v, f),
+			Quantity::InverseCatalyticActivity(v) => fmt::Display::fmt(v, f),
+			Quantity::InverseEntropy(v) => fmt::Display::fmt(v, f),
+			Quantity::InverseHeatCapacity(v) => fmt::Display::fmt(v, f),
+			Quantity::InverseMolarEnergy(v) => fmt::Display::fmt(v, f),
+			Quantity::InverseMolarEntropy(v) => fmt::Display::fmt(v, f),
+			Quantity::InverseSpecificHeatCapacity(v) => fmt::Display::fmt(v, f),
+			Quantity::Molality(v) => fmt::Display::fmt(v, f),
+			Quantity::MolarEnergy(v) => fmt::Display::fmt(v, f),
+			Quantity::MolarEntropy(v) => fmt::Display::fmt(v, f),
+			Quantity::MolarMass(v) => fmt::Display::fmt(v, f),
+			Quantity::MolarVolume(v) => fmt::Display::fmt(v, f),
+			Quantity::SpecificHeatCapacity(v) => fmt::Display::fmt(v, f),
+			Quantity::ThermalConductance(v) => fmt::Display::fmt(v, f),
+			Quantity::ThermalConductivity(v) => fmt::Display::fmt(v, f),
+			Quantity::ThermalResistance(v) => fmt::Display::fmt(v, f),
+			Quantity::ThermalResistivity(v) => fmt::Display::fmt(v, f),
+			Quantity::AreaPerLumen(v) => fmt::Display::fmt(v, f),
+			Quantity::Capacitance(v) => fmt::Display::fmt(v, f),
+			Quantity::Charge(v) => fmt::Display::fmt(v, f),
+			Quantity::Conductance(v) => fmt::Display::fmt(v, f),
+			Quantity::Elastance(v) => fmt::Display::fmt(v, f),
+			Quantity::ElectricField(v) => fmt::Display::fmt(v, f),
+			Quantity::Illuminance(v) => fmt::Display::fmt(v, f),
+			Quantity::Inductance(v) => fmt::Display::fmt(v, f),
+			Quantity::InverseCharge(v) => fmt::Display::fmt(v, f),
+			Quantity::InverseElectricField(v) => fmt::Display::fmt(v, f),
+			Quantity::InverseInductance(v) => fmt::Display::fmt(v, f),
+			Quantity::InverseIrradiance(v) => fmt::Display::fmt(v, f),
+			Quantity::InverseLinearChargeDensity(v) => fmt::Display::fmt(v, f),
+			Quantity::InverseLuminousFlux(v) => fmt::Display::fmt(v, f),
+			Quantity::InverseMagneticFieldStrength(v) => fmt::Display::fmt(v, f),
+			Quantity::InverseMagneticFlux(v) => fmt::Display::fmt(v, f),
+			Quantity::InverseMagneticFluxDensity(v) => fmt::Display::fmt(v, f),
+			Quantity::InverseRadiance(v) => fmt::Display::fmt(v, f),
+			Quantity::InverseSurfaceChargeDensity(v) => fmt::Display::fmt(v, f),
+			Quantity::InverseVoltage(v) => fmt::Display::fmt(v, f),
+			Quantity::InverseVolumeChargeDensity(v) => fmt::Display::fmt(v, f),
+			Quantity::Irradiance(v) => fmt::Display::fmt(v, f),
+			Quantity::LinearChargeDensity(v) => fmt::Display::fmt(v, f),
+			Quantity::LuminousFlux(v) => fmt::Display::fmt(v, f),
+			Quantity::MagneticFieldStrength(v) => fmt::Display::fmt(v, f),
+			Quantity::MagneticFlux(v) => fmt::Display::fmt(v, f),
+			Quantity::MagneticFluxDensity(v) => fmt::Display::fmt(v, f),
+			Quantity::Radiance(v) => fmt::Display::fmt(v, f),
+			Quantity::Resistance(v) => fmt::Display::fmt(v, f),
+			Quantity::SurfaceChargeDensity(v) => fmt::Display::fmt(v, f),
+			Quantity::Voltage(v) => fmt::Display::fmt(v, f),
+			Quantity::VolumeChargeDensity(v) => fmt::Display::fmt(v, f),
+			Quantity::Angle(v) => fmt::Display::fmt(v, f),
+			Quantity::Area(v) => fmt::Display::fmt(v, f),
+			Quantity::InverseAngle(v) => fmt::Display::fmt(v, f),
+			Quantity::InverseArea(v) => fmt::Display::fmt(v, f),
+			Quantity::InverseSolidAngle(v) => fmt::Display::fmt(v, f),
+			Quantity::InverseVolume(v) => fmt::Display::fmt(v, f),
+			Quantity::SolidAngle(v) => fmt::Display::fmt(v, f),
+			Quantity::Volume(v) => fmt::Display::fmt(v, f),
+			Quantity::Acceleration(v) => fmt::Display::fmt(v, f),
+			Quantity::AngularAcceleration(v) => fmt::Display::fmt(v, f),
+			Quantity::AngularMomentum(v) => fmt::Display::fmt(v, f),
+			Quantity::AngularVelocity(v) => fmt::Display::fmt(v, f),
+			Quantity::AreaDensity(v) => fmt::Display::fmt(v, f),
+			Quantity::AreaPerMass(v) => fmt::Display::fmt(v, f),
+			Quantity::Density(v) => fmt::Display::fmt(v, f),
+			Quantity::DynamicViscosity(v) => fmt::Display::fmt(v, f),
+			Quantity::Energy(v) => fmt::Display::fmt(v, f),
+			Quantity::Force(v) => fmt::Display::fmt(v, f),
+			Quantity::Frequency(v) => fmt::Display::fmt(v, f),
+			Quantity::InverseAcceleration(v) => fmt::Display::fmt(v, f),
+			Quantity::InverseAngularAcceleration(v) => fmt::Display::fmt(v, f),
+			Quantity::InverseAngularMomentum(v) => fmt::Display::fmt(v, f),
+			Quantity::InverseAngularVelocity(v) => fmt::Display::fmt(v, f),
+			Quantity::InverseDynamicViscosity(v) => fmt::Display::fmt(v, f),
+			Quantity::InverseEnergy(v) => fmt::Display::fmt(v, f),
+			Quantity::InverseForce(v) => fmt::Display::fmt(v, f),
+			Quantity::InverseJerk(v) => fmt::Display::fmt(v, f),
+			Quantity::InverseKinematicViscosity(v) => fmt::Display::fmt(v, f),
+			Quantity::InverseMassFlowRate(v) => fmt::Display::fmt(v, f),
+			Quantity::InverseMomentOfInertia(v) => fmt::Display::fmt(v, f),
+			Quantity::InverseMomentum(v) => fmt::Display::fmt(v, f),
+			Quantity::InversePower(v) => fmt::Display::fmt(v, f),
+			Quantity::InversePressure(v) => fmt::Display::fmt(v, f),
+			Quantity::InverseTorque(v) => fmt::Display::fmt(v, f),
+			Quantity::InverseVolumetricFlowRate(v) => fmt::Display::fmt(v, f),
+			Quantity::Jerk(v) => fmt::Display::fmt(v, f),
+			Quantity::KinematicViscosity(v) => fmt::Display::fmt(v, f),
+			Quantity::MassFlowRate(v) => fmt::Display::fmt(v, f),
+			Quantity::MomentOfInertia(v) => fmt::Display::fmt(v, f),
+			Quantity::Momentum(v) => fmt::Display::fmt(v, f),
+			Quantity::Power(v) => fmt::Display::fmt(v, f),
+			Quantity::Pressure(v) => fmt::Display::fmt(v, f),
+			Quantity::TimePerDistance(v) => fmt::Display::fmt(v, f),
+			Quantity::Torque(v) => fmt::Display::fmt(v, f),
+			Quantity::Velocity(v) => fmt::Display::fmt(v, f),
+			Quantity::VolumePerMass(v) => fmt::Display::fmt(v, f),
+			Quantity::VolumetricFlowRate(v) => fmt::Display::fmt(v, f),
+			Quantity::AbsorbedDose(v) => fmt::Display::fmt(v, f),
+			Quantity::AbsorbedDoseRate(v) => fmt::Display::fmt(v, f),
+			Quantity::DoseEquivalent(v) => fmt::Display::fmt(v, f),
+			Quantity::DoseEquivalentRate(v) => fmt::Display::fmt(v, f),
+			Quantity::InverseAbsorbedDose(v) => fmt::Display::fmt(v, f),
+			Quantity::InverseAbsorbedDoseRate(v) => fmt::Display::fmt(v, f),
+			Quantity::InverseDoseEquivalent(v) => fmt::Display::fmt(v, f),
+			Quantity::InverseDoseEquivalentRate(v) => fmt::Display::fmt(v, f),
+			Quantity::Radioactivity(v) => fmt::Display::fmt(v, f),
+		}
+	}
+}
+
+/// Converts a amount value into a `Quantity`
+impl<T> From<Amount<T>> for Quantity<T> where T: NumLike {
+	fn from(value: Amount<T>) -> Self { Quantity::Amount(value) }
+}
+
+/// Converts a `Quantity` into a amount value, returning an error if it holds a different unit type
+impl<T> TryFrom<Quantity<T>> for Amount<T> where T: NumLike {
+	type Error = DimensionError;
+	fn try_from(value: Quantity<T>) -> Result<Self, Self::Error> {
+		match value {
+			Quantity::Amount(v) => Ok(v),
+			_ => Err(DimensionError{operation: "convert", left: value.unit_symbol(), right: Self::unit_symbol()})
+		}
+	}
+}
+
+/// Converts a electrical current value into a `Quantity`
+impl<T> From<Current<T>> for Quantity<T> where T: NumLike {
+	fn from(value: Current<T>) -> Self { Quantity::Current(value) }
+}
+
+/// Converts a `Quantity` into a electrical current value, returning an error if it holds a different unit type
+impl<T> TryFrom<Quantity<T>> for Current<T> where T: NumLike {
+	type Error = DimensionError;
+	fn try_from(value: Quantity<T>) -> Result<Self, Self::Error> {
+		match value {
+			Quantity::Current(v) => Ok(v),
+			_ => Err(DimensionError{operation: "convert", left: value.unit_symbol(), right: Self::unit_symbol()})
+		}
+	}
+}
+
+/// Converts a distance value into a `Quantity`
+impl<T> From<Distance<T>> for Quantity<T> where T: NumLike {
+	fn from(value: Distance<T>) -> Self { Quantity::Distance(value) }
+}
+
+/// Converts a `Quantity` into a distance value, returning an error if it holds a different unit type
+impl<T> TryFrom<Quantity<T>> for Distance<T> where T: NumLike {
+	type Error = DimensionError;
+	fn try_from(value: Quantity<T>) -> Result<Self, Self::Error> {
+		match value {
+			Quantity::Distance(v) => Ok(v),
+			_ => Err(DimensionError{operation: "convert", left: value.unit_symbol(), right: Self::unit_symbol()})
+		}
+	}
+}
+
+/// Converts a inverse amount value into a `Quantity`
+impl<T> From<InverseAmount<T>> for Quantity<T> where T: NumLike {
+	fn from(value: InverseAmount<T>) -> Self { Quantity::InverseAmount(value) }
+}
+
+/// Converts a `Quantity` into a inverse amount value, returning an error if it holds a different unit type
+impl<T> TryFrom<Quantity<T>> for InverseAmount<T> where T: NumLike {
+	type Error = DimensionError;
+	fn try_from(value: Quantity<T>) -> Result<Self, Self::Error> {
+		match value {
+			Quantity::InverseAmount(v) => Ok(v),
+			_ => Err(DimensionError{operation: "convert", left: value.unit_symbol(), right: Self::unit_symbol()})
+		}
+	}
+}
+
+/// Converts a inverse electrical current value into a `Quantity`
+impl<T> From<InverseCurrent<T>> for Quantity<T> where T: NumLike {
+	fn from(value: InverseCurrent<T>) -> Self { Quantity::InverseCurrent(value) }
+}
+
+/// Converts a `Quantity` into a inverse electrical current value, returning an error if it holds a different unit type
+impl<T> TryFrom<Quantity<T>> for InverseCurrent<T> where T: NumLike {
+	type Error = DimensionError;
+	fn try_from(value: Quantity<T>) -> Result<Self, Self::Error> {
+		match value {
+			Quantity::InverseCurrent(v) => Ok(v),
+			_ => Err(DimensionError{operation: "convert", left: value.unit_symbol(), right: Self::unit_symbol()})
+		}
+	}
+}
+
+/// Converts a inverse distance value into a `Quantity`
+impl<T> From<InverseDistance<T>> for Quantity<T> where T: NumLike {
+	fn from(value: InverseDistance<T>) -> Self { Quantity::InverseDistance(value) }
+}
+
+/// Converts a `Quantity` into a inverse distance value, returning an error if it holds a different unit type
+impl<T> TryFrom<Quantity<T>> for InverseDistance<T> where T: NumLike {
+	type Error = DimensionError;
+	fn try_from(value: Quantity<T>) -> Result<Self, Self::Error> {
+		match value {
+			Quantity::InverseDistance(v) => Ok(v),
+			_ => Err(DimensionError{operation: "convert", left: value.unit_symbol(), right: Self::unit_symbol()})
+		}
+	}
+}
+
+/// Converts a inverse luminosity value into a `Quantity`
+impl<T> From<InverseLuminosity<T>> for Quantity<T> where T: NumLike {
+	fn from(value: InverseLuminosity<T>) -> Self { Quantity::InverseLuminosity(value) }
+}
+
+/// Converts a `Quantity` into a inverse luminosity value, returning an error if it holds a different unit type
+impl<T> TryFrom<Quantity<T>> for InverseLuminosity<T> where T: NumLike {
+	type Error = DimensionError;
+	fn try_from(value: Quantity<T>) -> Result<Self, Self::Error> {
+		match value {
+			Quantity::InverseLuminosity(v) => Ok(v),
+			_ => Err(DimensionError{operation: "convert", left: value.unit_symbol(), right: Self::unit_symbol()})
+		}
+	}
+}
+
+/// Converts a inverse mass value into a `Quantity`
+impl<T> From<InverseMass<T>> for Quantity<T> where T: NumLike {
+	fn from(value: InverseMass<T>) -> Self { Quantity::InverseMass(value) }
+}
+
+/// Converts a `Quantity` into a inverse mass value, returning an error if it holds a different unit type
+impl<T> TryFrom<Quantity<T>> for InverseMass<T> where T: NumLike {
+	type Error = DimensionError;
+	fn try_from(value: Quantity<T>) -> Result<Self, Self::Error> {
+		match value {
+			Quantity::InverseMass(v) => Ok(v),
+			_ => Err(DimensionError{operation: "convert", left: value.unit_symbol(), right: Self::unit_symbol()})
+		}
+	}
+}
+
+/// Converts a inverse temperature value into a `Quantity`
+impl<T> From<InverseTemperature<T>> for Quantity<T> where T: NumLike {
+	fn from(value: InverseTemperature<T>) -> Self { Quantity::InverseTemperature(value) }
+}
+
+/// Converts a `Quantity` into a inverse temperature value, returning an error if it holds a different unit type
+impl<T> TryFrom<Quantity<T>> for InverseTemperature<T> where T: NumLike {
+	type Error = DimensionError;
+	fn try_from(value: Quantity<T>) -> Result<Self, Self::Error> {
+		match value {
+			Quantity::InverseTemperature(v) => Ok(v),
+			_ => Err(DimensionError{operation: "convert", left: value.unit_symbol(), right: Self::unit_symbol()})
+		}
+	}
+}
+
+/// Converts a luminosity value into a `Quantity`
+impl<T> From<Luminosity<T>> for Quantity<T> where T: NumLike {
+	fn from(value: Luminosity<T>) -> Self { Quantity::Luminosity(value) }
+}
+
+/// Converts a `Quantity` into a luminosity value, returning an error if it holds a different unit type
+impl<T> TryFrom<Quantity<T>> for Luminosity<T> where T: NumLike {
+	type Error = DimensionError;
+	fn try_from(value: Quantity<T>) -> Result<Self, Self::Error> {
+		match value {
+			Quantity::Luminosity(v) => Ok(v),
+			_ => Err(DimensionError{operation: "convert", left: value.unit_symbol(), right: Self::unit_symbol()})
+		}
+	}
+}
+
+/// Converts a mass value into a `Quantity`
+impl<T> From<Mass<T>> for Quantity<T> where T: NumLike {
+	fn from(value: Mass<T>) -> Self { Quantity::Mass(value) }
+}
+
+/// Converts a `Quantity` into a mass value, returning an error if it holds a different unit type
+impl<T> TryFrom<Quantity<T>> for Mass<T> where T: NumLike {
+	type Error = DimensionError;
+	fn try_from(value: Quantity<T>) -> Result<Self, Self::Error> {
+		match value {
+			Quantity::Mass(v) => Ok(v),
+			_ => Err(DimensionError{operation: "convert", left: value.unit_symbol(), right: Self::unit_symbol()})
+		}
+	}
+}
+
+/// Converts a temperature value into a `Quantity`
+impl<T> From<Temperature<T>> for Quantity<T> where T: NumLike {
+	fn from(value: Temperature<T>) -> Self { Quantity::Temperature(value) }
+}
+
+/// Converts a `Quantity` into a temperature value, returning an error if it holds a different unit type
+impl<T> TryFrom<Quantity<T>> for Temperature<T> where T: NumLike {
+	type Error = DimensionError;
+	fn try_from(value: Quantity<T>) -> Result<Self, Self::Error> {
+		match value {
+			Quantity::Temperature(v) => Ok(v),
+			_ => Err(DimensionError{operation: "convert", left: value.unit_symbol(), right: Self::unit_symbol()})
+		}
+	}
+}
+
+/// Converts a temperature interval value into a `Quantity`
+impl<T> From<TemperatureInterval<T>> for Quantity<T> where T: NumLike {
+	fn from(value: TemperatureInterval<T>) -> Self { Quantity::TemperatureInterval(value) }
+}
+
+/// Converts a `Quantity` into a temperature interval value, returning an error if it holds a different unit type
+impl<T> TryFrom<Quantity<T>> for TemperatureInterval<T> where T: NumLike {
+	type Error = DimensionError;
+	fn try_from(value: Quantity<T>) -> Result<Self, Self::Error> {
+		match value {
+			Quantity::TemperatureInterval(v) => Ok(v),
+			_ => Err(DimensionError{operation: "convert", left: value.unit_symbol(), right: Self::unit_symbol()})
+		}
+	}
+}
+
+/// Converts a time value into a `Quantity`
+impl<T> From<Time<T>> for Quantity<T> where T: NumLike {
+	fn from(value: Time<T>) -> Self { Quantity::Time(value) }
+}
+
+/// Converts a `Quantity` into a time value, returning an error if it holds a different unit type
+impl<T> TryFrom<Quantity<T>> for Time<T> where T: NumLike {
+	type Error = DimensionError;
+	fn try_from(value: Quantity<T>) -> Result<Self, Self::Error> {
+		match value {
+			Quantity::Time(v) => Ok(v),
+			_ => Err(DimensionError{operation: "convert", left: value.unit_symbol(), right: Self::unit_symbol()})
+		}
+	}
+}
+
+/// Converts a catalytic activity value into a `Quantity`
+impl<T> From<CatalyticActivity<T>> for Quantity<T> where T: NumLike {
+	fn from(value: CatalyticActivity<T>) -> Self { Quantity::CatalyticActivity(value) }
+}
+
+/// Converts a `Quantity` into a catalytic activity value, returning an error if it holds a different unit type
+impl<T> TryFrom<Quantity<T>> for CatalyticActivity<T> where T: NumLike {
+	type Error = DimensionError;
+	fn try_from(value: Quantity<T>) -> Result<Self, Self::Error> {
+		match value {
+			Quantity::CatalyticActivity(v) => Ok(v),
+			_ => Err(DimensionError{operation: "convert", left: value.unit_symbol(), right: Self::unit_symbol()})
+		}
+	}
+}
+
+/// Converts a chemical concentration value into a `Quantity`
+impl<T> From<Concentration<T>> for Quantity<T> where T: NumLike {
+	fn from(value: Concentration<T>) -> Self { Quantity::Concentration(value) }
+}
+
+/// Converts a `Quantity` into a chemical concentration value, returning an error if it holds a different unit type
+impl<T> TryFrom<Quantity<T>> for Concentration<T> where T: NumLike {
+	type Error = DimensionError;
+	fn try_from(value: Quantity<T>) -> Result<Self, Self::Error> {
+		match value {
+			Quantity::Concentration(v) => Ok(v),
+			_ => Err(DimensionError{operation: "convert", left: value.unit_symbol(), right: Self::unit_symbol()})
+		}
+	}
+}
+
+/// Converts a entropy value into a `Quantity`
+impl<T> From<Entropy<T>> for Quantity<T> where T: NumLike {
+	fn from(value: Entropy<T>) -> Self { Quantity::Entropy(value) }
+}
+
+/// Converts a `Quantity` into a entropy value, returning an error if it holds a different unit type
+impl<T> TryFrom<Quantity<T>> for Entropy<T> where T: NumLike {
+	type Error = DimensionError;
+	fn try_from(value: Quantity<T>) -> Result<Self, Self::Error> {
+		match value {
+			Quantity::Entropy(v) => Ok(v),
+			_ => Err(DimensionError{operation: "convert", left: value.unit_symbol(), right: Self::unit_symbol()})
+		}
+	}
+}
+
+/// Converts a heat capacity value into a `Quantity`
+impl<T> From<HeatCapacity<T>> for Quantity<T> where T: NumLike {
+	fn from(value: HeatCapacity<T>) -> Self { Quantity::HeatCapacity(value) }
+}
+
+/// Converts a `Quantity` into a heat capacity value, returning an error if it holds a different unit type
+impl<T> TryFrom<Quantity<T>> for HeatCapacity<T> where T: NumLike {
+	type Error = DimensionError;
+	fn try_from(value: Quantity<T>) -> Result<Self, Self::Error> {
+		match value {
+			Quantity::HeatCapacity(v) => Ok(v),
+			_ => Err(DimensionError{operation: "convert", left: value.unit_symbol(), right: Self::unit_symbol()})
+		}
+	}
+}
+
+/// Converts a inverse catalytic activity value into a `Quantity`
+impl<T> From<InverseCatalyticActivity<T>> for Quantity<T> where T: NumLike {
+	fn from(value: InverseCatalyticActivity<T>) -> Self { Quantity::InverseCatalyticActivity(value) }
+}
+
+/// Converts a `Quantity` into a inverse catalytic activity value, returning an error if it holds a different unit type
+impl<T> TryFrom<Quantity<T>> for InverseCatalyticActivity<T> where T: NumLike {
+	type Error = DimensionError;
+	fn try_from(value: Quantity<T>) -> Result<Self, Self::Error> {
+		match value {
+			Quantity::InverseCatalyticActivity(v) => Ok(v),
+			_ => Err(DimensionError{operation: "convert", left: value.unit_symbol(), right: Self::unit_symbol()})
+		}
+	}
+}
+
+/// Converts a inverse entropy value into a `Quantity`
+impl<T> From<InverseEntropy<T>> for Quantity<T> where T: NumLike {
+	fn from(value: InverseEntropy<T>) -> Self { Quantity::InverseEntropy(value) }
+}
+
+/// Converts a `Quantity` into a inverse entropy value, returning an error if it holds a different unit type
+impl<T> TryFrom<Quantity<T>> for InverseEntropy<T> where T: NumLike {
+	type Error = DimensionError;
+	fn try_from(value: Quantity<T>) -> Result<Self, Self::Error> {
+		match value {
+			Quantity::InverseEntropy(v) => Ok(v),
+			_ => Err(DimensionError{operation: "convert", left: value.unit_symbol(), right: Self::unit_symbol()})
+		}
+	}
+}
+
+/// Converts a inverse heat capacity value into a `Quantity`
+impl<T> From<InverseHeatCapacity<T>> for Quantity<T> where T: NumLike {
+	fn from(value: InverseHeatCapacity<T>) -> Self { Quantity::InverseHeatCapacity(value) }
+}
+
+/// Converts a `Quantity` into a inverse heat capacity value, returning an error if it holds a different unit type
+impl<T> TryFrom<Quantity<T>> for InverseHeatCapacity<T> where T: NumLike {
+	type Error = DimensionError;
+	fn try_from(value: Quantity<T>) -> Result<Self, Self::Error> {
+		match value {
+			Quantity::InverseHeatCapacity(v) => Ok(v),
+			_ => Err(DimensionError{operation: "convert", left: value.unit_symbol(), right: Self::unit_symbol()})
+		}
+	}
+}
+
+/// Converts a inverse molar energy value into a `Quantity`
+impl<T> From<InverseMolarEnergy<T>> for Quantity<T> where T: NumLike {
+	fn from(value: InverseMolarEnergy<T>) -> Self { Quantity::InverseMolarEnergy(value) }
+}
+
+/// Converts a `Quantity` into a inverse molar energy value, returning an error if it holds a different unit type
+impl<T> TryFrom<Quantity<T>> for InverseMolarEnergy<T> where T: NumLike {
+	type Error = DimensionError;
+	fn try_from(value: Quantity<T>) -> Result<Self, Self::Error> {
+		match value {
+			Quantity::InverseMolarEnergy(v) => Ok(v),
+			_ => Err(DimensionError{operation: "convert", left: value.unit_symbol(), right: Self::unit_symbol()})
+		}
+	}
+}
+
+/// Converts a inverse molar entropy value into a `Quantity`
+impl<T> From<InverseMolarEntropy<T>> for Quantity<T> where T: NumLike {
+	fn from(value: InverseMolarEntropy<T>) -> Self { Quantity::InverseMolarEntropy(value) }
+}
+
+/// Converts a `Quantity` into a inverse molar entropy value, returning an error if it holds a different unit type
+impl<T> TryFrom<Quantity<T>> for InverseMolarEntropy<T> where T: NumLike {
+	type Error = DimensionError;
+	fn try_from(value: Quantity<T>) -> Result<Self, Self::Error> {
+		match value {
+			Quantity::InverseMolarEntropy(v) => Ok(v),
+			_ => Err(DimensionError{operation: "convert", left: value.unit_symbol(), right: Self::unit_symbol()})
+		}
+	}
+}
+
+/// Converts a inverse specific heat capacity value into a `Quantity`
+impl<T> From<InverseSpecificHeatCapacity<T>> for Quantity<T> where T: NumLike {
+	fn from(value: InverseSpecificHeatCapacity<T>) -> Self { Quantity::InverseSpecificHeatCapacity(value) }
+}
+
+/// Converts a `Quantity` into a inverse specific heat capacity value, returning an error if it holds a different unit type
+impl<T> TryFrom<Quantity<T>> for InverseSpecificHeatCapacity<T> where T: NumLike {
+	type Error = DimensionError;
+	fn try_from(value: Quantity<T>) -> Result<Self, Self::Error> {
+		match value {
+			Quantity::InverseSpecificHeatCapacity(v) => Ok(v),
+			_ => Err(DimensionError{operation: "convert", left: value.unit_symbol(), right: Self::unit_symbol()})
+		}
+	}
+}
+
+/// Converts a molality value into a `Quantity`
+impl<T> From<Molality<T>> for Quantity<T> where T: NumLike {
+	fn from(value: Molality<T>) -> Self { Quantity::Molality(value) }
+}
+
+/// Converts a `Quantity` into a molality value, returning an error if it holds a different unit type
+impl<T> TryFrom<Quantity<T>> for Molality<T> where T: NumLike {
+	type Error = DimensionError;
+	fn try_from(value: Quantity<T>) -> Result<Self, Self::Error> {
+		match value {
+			Quantity::Molality(v) => Ok(v),
+			_ => Err(DimensionError{operation: "convert", left: value.unit_symbol(), right: Self::unit_symbol()})
+		}
+	}
+}
+
+/// Converts a molar energy value into a `Quantity`
+impl<T> From<MolarEnergy<T>> for Quantity<T> where T: NumLike {
+	fn from(value: MolarEnergy<T>) -> Self { Quantity::MolarEnergy(value) }
+}
+
+/// Converts a `Quantity` into a molar energy value, returning an error if it holds a different unit type
+impl<T> TryFrom<Quantity<T>> for MolarEnergy<T> where T: NumLike {
+	type Error = DimensionError;
+	fn try_from(value: Quantity<T>) -> Result<Self, Self::Error> {
+		match value {
+			Quantity::MolarEnergy(v) => Ok(v),
+			_ => Err(DimensionError{operation: "convert", left: value.unit_symbol(), right: Self::unit_symbol()})
+		}
+	}
+}
+
+/// Converts a molar entropy value into a `Quantity`
+impl<T> From<MolarEntropy<T>> for Quantity<T> where T: NumLike {
+	fn from(value: MolarEntropy<T>) -> Self { Quantity::MolarEntropy(value) }
+}
+
+/// Converts a `Quantity` into a molar entropy value, returning an error if it holds a different unit type
+impl<T> TryFrom<Quantity<T>> for MolarEntropy<T> where T: NumLike {
+	type Error = DimensionError;
+	fn try_from(value: Quantity<T>) -> Result<Self, Self::Error> {
+		match value {
+			Quantity::MolarEntropy(v) => Ok(v),
+			_ => Err(DimensionError{operation: "convert", left: value.unit_symbol(), right: Self::unit_symbol()})
+		}
+	}
+}
+
+/// Converts a molar mass value into a `Quantity`
+impl<T> From<MolarMass<T>> for Quantity<T> where T: NumLike {
+	fn from(value: MolarMass<T>) -> Self { Quantity::MolarMass(value) }
+}
+
+/// Converts a `Quantity` into a molar mass value, returning an error if it holds a different unit type
+impl<T> TryFrom<Quantity<T>> for MolarMass<T> where T: NumLike {
+	type Error = DimensionError;
+	fn try_from(value: Quantity<T>) -> Result<Self, Self::Error> {
+		match value {
+			Quantity::MolarMass(v) => Ok(v),
+			_ => Err(DimensionError{operation: "convert", left: value.unit_symbol(), right: Self::unit_symbol()})
+		}
+	}
+}
+
+/// Converts a molar volume value into a `Quantity`
+impl<T> From<MolarVolume<T>> for Quantity<T> where T: NumLike {
+	fn from(value: MolarVolume<T>) -> Self { Quantity::MolarVolume(value) }
+}
+
+/// Converts a `Quantity` into a molar volume value, returning an error if it holds a different unit type
+impl<T> TryFrom<Quantity<T>> for MolarVolume<T> where T: NumLike {
+	type Error = DimensionError;
+	fn try_from(value: Quantity<T>) -> Result<Self, Self::Error> {
+		match value {
+			Quantity::MolarVolume(v) => Ok(v),
+			_ => Err(DimensionError{operation: "convert", left: value.unit_symbol(), right: Self::unit_symbol()})
+		}
+	}
+}
+
+/// Converts a specific heat capacity value into a `Quantity`
+impl<T> From<SpecificHeatCapacity<T>> for Quantity<T> where T: NumLike {
+	fn from(value: SpecificHeatCapacity<T>) -> Self { Quantity::SpecificHeatCapacity(value) }
+}
+
+/// Converts a `Quantity` into a specific heat capacity value, returning an error if it holds a different unit type
+impl<T> TryFrom<Quantity<T>> for SpecificHeatCapacity<T> where T: NumLike {
+	type Error = DimensionError;
+	fn try_from(value: Quantity<T>) -> Result<Self, Self::Error> {
+		match value {
+			Quantity::SpecificHeatCapacity(v) => Ok(v),
+			_ => Err(DimensionError{operation: "convert", left: value.unit_symbol(), right: Self::unit_symbol()})
+		}
+	}
+}
+
+/// Converts a thermal conductance value into a `Quantity`
+impl<T> From<ThermalConductance<T>> for Quantity<T> where T: NumLike {
+	fn from(value: ThermalConductance<T>) -> Self { Quantity::ThermalConductance(value) }
+}
+
+/// Converts a `Quantity` into a thermal conductance value, returning an error if it holds a different unit type
+impl<T> TryFrom<Quantity<T>> for ThermalConductance<T> where T: NumLike {
+	type Error = DimensionError;
+	fn try_from(value: Quantity<T>) -> Result<Self, Self::Error> {
+		match value {
+			Quantity::ThermalConductance(v) => Ok(v),
+			_ => Err(DimensionError{operation: "convert", left: value.unit_symbol(), right: Self::unit_symbol()})
+		}
+	}
+}
+
+/// Converts a thermal conductivity value into a `Quantity`
+impl<T> From<ThermalConductivity<T>> for Quantity<T> where T: NumLike {
+	fn from(value: ThermalConductivity<T>) -> Self { Quantity::ThermalConductivity(value) }
+}
+
+/// Converts a `Quantity` into a thermal conductivity value, returning an error if it holds a different unit type
+impl<T> TryFrom<Quantity<T>> for ThermalConductivity<T> where T: NumLike {
+	type Error = DimensionError;
+	fn try_from(value: Quantity<T>) -> Result<Self, Self::Error> {
+		match value {
+			Quantity::ThermalConductivity(v) => Ok(v),
+			_ => Err(DimensionError{operation: "convert", left: value.unit_symbol(), right: Self::unit_symbol()})
+		}
+	}
+}
+
+/// Converts a thermal resistance value into a `Quantity`
+impl<T> From<ThermalResistance<T>> for Quantity<T> where T: NumLike {
+	fn from(value: ThermalResistance<T>) -> Self { Quantity::ThermalResistance(value) }
+}
+
+/// Converts a `Quantity` into a thermal resistance value, returning an error if it holds a different unit type
+impl<T> TryFrom<Quantity<T>> for ThermalResistance<T> where T: NumLike {
+	type Error = DimensionError;
+	fn try_from(value: Quantity<T>) -> Result<Self, Self::Error> {
+		match value {
+			Quantity::ThermalResistance(v) => Ok(v),
+			_ => Err(DimensionError{operation: "convert", left: value.unit_symbol(), right: Self::unit_symbol()})
+		}
+	}
+}
+
+/// Converts a thermal resistivity value into a `Quantity`
+impl<T> From<ThermalResistivity<T>> for Quantity<T> where T: NumLike {
+	fn from(value: ThermalResistivity<T>) -> Self { Quantity::ThermalResistivity(value) }
+}
+
+/// Converts a `Quantity` into a thermal resistivity value, returning an error if it holds a different unit type
+impl<T> TryFrom<Quantity<T>> for ThermalResistivity<T> where T: NumLike {
+	type Error = DimensionError;
+	fn try_from(value: Quantity<T>) -> Result<Self, Self::Error> {
+		match value {
+			Quantity::ThermalResistivity(v) => Ok(v),
+			_ => Err(DimensionError{operation: "convert", left: value.unit_symbol(), right: Self::unit_symbol()})
+		}
+	}
+}
+
+/// Converts a area per lumen value into a `Quantity`
+impl<T> From<AreaPerLumen<T>> for Quantity<T> where T: NumLike {
+	fn from(value: AreaPerLumen<T>) -> Self { Quantity::AreaPerLumen(value) }
+}
+
+/// Converts a `Quantity` into a area per lumen value, returning an error if it holds a different unit type
+impl<T> TryFrom<Quantity<T>> for AreaPerLumen<T> where T: NumLike {
+	type Error = DimensionError;
+	fn try_from(value: Quantity<T>) -> Result<Self, Self::Error> {
+		match value {
+			Quantity::AreaPerLumen(v) => Ok(v),
+			_ => Err(DimensionError{operation: "convert", left: value.unit_symbol(), right: Self::unit_symbol()})
+		}
+	}
+}
+
+/// Converts a electrical capacitance value into a `Quantity`
+impl<T> From<Capacitance<T>> for Quantity<T> where T: NumLike {
+	fn from(value: Capacitance<T>) -> Self { Quantity::Capacitance(value) }
+}
+
+/// Converts a `Quantity` into a electrical capacitance value, returning an error if it holds a different unit type
+impl<T> TryFrom<Quantity<T>> for Capacitance<T> where T: NumLike {
+	type Error = DimensionError;
+	fn try_from(value: Quantity<T>) -> Result<Self, Self::Error> {
+		match value {
+			Quantity::Capacitance(v) => Ok(v),
+			_ => Err(DimensionError{operation: "convert", left: value.unit_symbol(), right: Self::unit_symbol()})
+		}
+	}
+}
+
+/// Converts a electric charge value into a `Quantity`
+impl<T> From<Charge<T>> for Quantity<T> where T: NumLike {
+	fn from(value: Charge<T>) -> Self { Quantity::Charge(value) }
+}
+
+/// Converts a `Quantity` into a electric charge value, returning an error if it holds a different unit type
+impl<T> TryFrom<Quantity<T>> for Charge<T> where T: NumLike {
+	type Error = DimensionError;
+	fn try_from(value: Quantity<T>) -> Result<Self, Self::Error> {
+		match value {
+			Quantity::Charge(v) => Ok(v),
+			_ => Err(DimensionError{operation: "convert", left: value.unit_symbol(), right: Self::unit_symbol()})
+		}
+	}
+}
+
+/// Converts a electrical conductance value into a `Quantity`
+impl<T> From<Conductance<T>> for Quantity<T> where T: NumLike {
+	fn from(value: Conductance<T>) -> Self { Quantity::Conductance(value) }
+}
+
+/// Converts a `Quantity` into a electrical conductance value, returning an error if it holds a different unit type
+impl<T> TryFrom<Quantity<T>> for Conductance<T> where T: NumLike {
+	type Error = DimensionError;
+	fn try_from(value: Quantity<T>) -> Result<Self, Self::Error> {
+		match value {
+			Quantity::Conductance(v) => Ok(v),
+			_ => Err(DimensionError{operation: "convert", left: value.unit_symbol(), right: Self::unit_symbol()})
+		}
+	}
+}
+
+/// Converts a electrical elastance value into a `Quantity`
+impl<T> From<Elastance<T>> for Quantity<T> where T: NumLike {
+	fn from(value: Elastance<T>) -> Self { Quantity::Elastance(value) }
+}
+
+/// Converts a `Quantity` into a electrical elastance value, returning an error if it holds a different unit type
+impl<T> TryFrom<Quantity<T>> for Elastance<T> where T: NumLike {
+	type Error = DimensionError;
+	fn try_from(value: Quantity<T>) -> Result<Self, Self::Error> {
+		match value {
+			Quantity::Elastance(v) => Ok(v),
+			_ => Err(DimensionError{operation: "convert", left: value.unit_symbol(), right: Self::unit_symbol()})
+		}
+	}
+}
+
+/// Converts a electric field strength value into a `Quantity`
+impl<T> From<ElectricField<T>> for Quantity<T> where T: NumLike {
+	fn from(value: ElectricField<T>) -> Self { Quantity::ElectricField(value) }
+}
+
+/// Converts a `Quantity` into a electric field strength value, returning an error if it holds a different unit type
+impl<T> TryFrom<Quantity<T>> for ElectricField<T> where T: NumLike {
+	type Error = DimensionError;
+	fn try_from(value: Quantity<T>) -> Result<Self, Self::Error> {
+		match value {
+			Quantity::ElectricField(v) => Ok(v),
+			_ => Err(DimensionError{operation: "convert", left: value.unit_symbol(), right: Self::unit_symbol()})
+		}
+	}
+}
+
+/// Converts a illuminance value into a `Quantity`
+impl<T> From<Illuminance<T>> for Quantity<T> where T: NumLike {
+	fn from(value: Illuminance<T>) -> Self { Quantity::Illuminance(value) }
+}
+
+/// Converts a `Quantity` into a illuminance value, returning an error if it holds a different unit type
+impl<T> TryFrom<Quantity<T>> for Illuminance<T> where T: NumLike {
+	type Error = DimensionError;
+	fn try_from(value: Quantity<T>) -> Result<Self, Self::Error> {
+		match value {
+			Quantity::Illuminance(v) => Ok(v),
+			_ => Err(DimensionError{operation: "convert", left: value.unit_symbol(), right: Self::unit_symbol()})
+		}
+	}
+}
+
+/// Converts a inductance value into a `Quantity`
+impl<T> From<Inductance<T>> for Quantity<T> where T: NumLike {
+	fn from(value: Inductance<T>) -> Self { Quantity::Inductance(value) }
+}
+
+/// Converts a `Quantity` into a inductance value, returning an error if it holds a different unit type
+impl<T> TryFrom<Quantity<T>> for Inductance<T> where T: NumLike {
+	type Error = DimensionError;
+	fn try_from(value: Quantity<T>) -> Result<Self, Self::Error> {
+		match value {
+			Quantity::Inductance(v) => Ok(v),
+			_ => Err(DimensionError{operation: "convert", left: value.unit_symbol(), right: Self::unit_symbol()})
+		}
+	}
+}
+
+/// Converts a inverse electric charge value into a `Quantity`
+impl<T> From<InverseCharge<T>> for Quantity<T> where T: NumLike {
+	fn from(value: InverseCharge<T>) -> Self { Quantity::InverseCharge(value) }
+}
+
+/// Converts a `Quantity` into a inverse electric charge value, returning an error if it holds a different unit type
+impl<T> TryFrom<Quantity<T>> for InverseCharge<T> where T: NumLike {
+	type Error = DimensionError;
+	fn try_from(value: Quantity<T>) -> Result<Self, Self::Error> {
+		match value {
+			Quantity::InverseCharge(v) => Ok(v),
+			_ => Err(DimensionError{operation: "convert", left: value.unit_symbol(), right: Self::unit_symbol()})
+		}
+	}
+}
+
+/// Converts a inverse electric field strength value into a `Quantity`
+impl<T> From<InverseElectricField<T>> for Quantity<T> where T: NumLike {
+	fn from(value: InverseElectricField<T>) -> Self { Quantity::InverseElectricField(value) }
+}
+
+/// Converts a `Quantity` into a inverse electric field strength value, returning an error if it holds a different unit type
+impl<T> TryFrom<Quantity<T>> for InverseElectricField<T> where T: NumLike {
+	type Error = DimensionError;
+	fn try_from(value: Quantity<T>) -> Result<Self, Self::Error> {
+		match value {
+			Quantity::InverseElectricField(v) => Ok(v),
+			_ => Err(DimensionError{operation: "convert", left: value.unit_symbol(), right: Self::unit_symbol()})
+		}
+	}
+}
+
+/// Converts a inverse inductance value into a `Quantity`
+impl<T> From<InverseInductance<T>> for Quantity<T> where T: NumLike {
+	fn from(value: InverseInductance<T>) -> Self { Quantity::InverseInductance(value) }
+}
+
+/// Converts a `Quantity` into a inverse inductance value, returning an error if it holds a different unit type
+impl<T> TryFrom<Quantity<T>> for InverseInductance<T> where T: NumLike {
+	type Error = DimensionError;
+	fn try_from(value: Quantity<T>) -> Result<Self, Self::Error> {
+		match value {
+			Quantity::InverseInductance(v) => Ok(v),
+			_ => Err(DimensionError{operation: "convert", left: value.unit_symbol(), right: Self::unit_symbol()})
+		}
+	}
+}
+
+/// Converts a inverse irradiance value into a `Quantity`
+impl<T> From<InverseIrradiance<T>> for Quantity<T> where T: NumLike {
+	fn from(value: InverseIrradiance<T>) -> Self { Quantity::InverseIrradiance(value) }
+}
+
+/// Converts a `Quantity` into a inverse irradiance value, returning an error if it holds a different unit type
+impl<T> TryFrom<Quantity<T>> for InverseIrradiance<T> where T: NumLike {
+	type Error = DimensionError;
+	fn try_from(value: Quantity<T>) -> Result<Self, Self::Error> {
+		match value {
+			Quantity::InverseIrradiance(v) => Ok(v),
+			_ => Err(DimensionError{operation: "convert", left: value.unit_symbol(), right: Self::unit_symbol()})
+		}
+	}
+}
+
+/// Converts a inverse linear charge density value into a `Quantity`
+impl<T> From<InverseLinearChargeDensity<T>> for Quantity<T> where T: NumLike {
+	fn from(value: InverseLinearChargeDensity<T>) -> Self { Quantity::InverseLinearChargeDensity(value) }
+}
+
+/// Converts a `Quantity` into a inverse linear charge density value, returning an error if it holds a different unit type
+impl<T> TryFrom<Quantity<T>> for InverseLinearChargeDensity<T> where T: NumLike {
+	type Error = DimensionError;
+	fn try_from(value: Quantity<T>) -> Result<Self, Self::Error> {
+		match value {
+			Quantity::InverseLinearChargeDensity(v) => Ok(v),
+			_ => Err(DimensionError{operation: "convert", left: value.unit_symbol(), right: Self::unit_symbol()})
+		}
+	}
+}
+
+/// Converts a inverse luminous flux value into a `Quantity`
+impl<T> From<InverseLuminousFlux<T>> for Quantity<T> where T: NumLike {
+	fn from(value: InverseLuminousFlux<T>) -> Self { Quantity::InverseLuminousFlux(value) }
+}
+
+/// Converts a `Quantity` into a inverse luminous flux value, returning an error if it holds a different unit type
+impl<T> TryFrom<Quantity<T>> for InverseLuminousFlux<T> where T: NumLike {
+	type Error = DimensionError;
+	fn try_from(value: Quantity<T>) -> Result<Self, Self::Error> {
+		match value {
+			Quantity::InverseLuminousFlux(v) => Ok(v),
+			_ => Err(DimensionError{operation: "convert", left: value.unit_symbol(), right: Self::unit_symbol()})
+		}
+	}
+}
+
+/// Converts a inverse magnetic field strength value into a `Quantity`
+impl<T> From<InverseMagneticFieldStrength<T>> for Quantity<T> where T: NumLike {
+	fn from(value: InverseMagneticFieldStrength<T>) -> Self { Quantity::InverseMagneticFieldStrength(value) }
+}
+
+/// Converts a `Quantity` into a inverse magnetic field strength value, returning an error if it holds a different unit type
+impl<T> TryFrom<Quantity<T>> for InverseMagneticFieldStrength<T> where T: NumLike {
+	type Error = DimensionError;
+	fn try_from(value: Quantity<T>) -> Result<Self, Self::Error> {
+		match value {
+			Quantity::InverseMagneticFieldStrength(v) => Ok(v),
+			_ => Err(DimensionError{operation: "convert", left: value.unit_symbol(), right: Self::unit_symbol()})
+		}
+	}
+}
+
+/// Converts a inverse magnetic flux value into a `Quantity`
+impl<T> From<InverseMagneticFlux<T>> for Quantity<T> where T: NumLike {
+	fn from(value: InverseMagneticFlux<T>) -> Self { Quantity::InverseMagneticFlux(value) }
+}
+
+/// Converts a `Quantity` into a inverse magnetic flux value, returning an error if it holds a different unit type
+impl<T> TryFrom<Quantity<T>> for InverseMagneticFlux<T> where T: NumLike {
+	type Error = DimensionError;
+	fn try_from(value: Quantity<T>) -> Result<Self, Self::Error> {
+		match value {
+			Quantity::InverseMagneticFlux(v) => Ok(v),
+			_ => Err(DimensionError{operation: "convert", left: value.unit_symbol(), right: Self::unit_symbol()})
+		}
+	}
+}
+
+/// Converts a inverse magnetic flux density value into a `Quantity`
+impl<T> From<InverseMagneticFluxDensity<T>> for Quantity<T> where T: NumLike {
+	fn from(value: InverseMagneticFluxDensity<T>) -> Self { Quantity::InverseMagneticFluxDensity(value) }
+}
+
+/// Converts a `Quantity` into a inverse magnetic flux density value, returning an error if it holds a different unit type
+impl<T> TryFrom<Quantity<T>> for InverseMagneticFluxDensity<T> where T: NumLike {
+	type Error = DimensionError;
+	fn try_from(value: Quantity<T>) -> Result<Self, Self::Error> {
+		match value {
+			Quantity::InverseMagneticFluxDensity(v) => Ok(v),
+			_ => Err(DimensionError{operation: "convert", left: value.unit_symbol(), right: Self::unit_symbol()})
+		}
+	}
+}
+
+/// Converts a inverse radiance value into a `Quantity`
+impl<T> From<InverseRadiance<T>> for Quantity<T> where T: NumLike {
+	fn from(value: InverseRadiance<T>) -> Self { Quantity::InverseRadiance(value) }
+}
+
+/// Converts a `Quantity` into a inverse radiance value, returning an error if it holds a different unit type
+impl<T> TryFrom<Quantity<T>> for InverseRadiance<T> where T: NumLike {
+	type Error = DimensionError;
+	fn try_from(value: Quantity<T>) -> Result<Self, Self::Error> {
+		match value {
+			Quantity::InverseRadiance(v) => Ok(v),
+			_ => Err(DimensionError{operation: "convert", left: value.unit_symbol(), right: Self::unit_symbol()})
+		}
+	}
+}
+
+/// Converts a inverse surface charge density value into a `Quantity`
+impl<T> From<InverseSurfaceChargeDensity<T>> for Quantity<T> where T: NumLike {
+	fn from(value: InverseSurfaceChargeDensity<T>) -> Self { Quantity::InverseSurfaceChargeDensity(value) }
+}
+
+/// Converts a `Quantity` into a inverse surface charge density value, returning an error if it holds a different unit type
+impl<T> TryFrom<Quantity<T>> for InverseSurfaceChargeDensity<T> where T: NumLike {
+	type Error = DimensionError;
+	fn try_from(value: Quantity<T>) -> Result<Self, Self::Error> {
+		match value {
+			Quantity::InverseSurfaceChargeDensity(v) => Ok(v),
+			_ => Err(DimensionError{operation: "convert", left: value.unit_symbol(), right: Self::unit_symbol()})
+		}
+	}
+}
+
+/// Converts a inverse voltage value into a `Quantity`
+impl<T> From<InverseVoltage<T>> for Quantity<T> where T: NumLike {
+	fn from(value: InverseVoltage<T>) -> Self { Quantity::InverseVoltage(value) }
+}
+
+/// Converts a `Quantity` into a inverse voltage value, returning an error if it holds a different unit type
+impl<T> TryFrom<Quantity<T>> for InverseVoltage<T> where T: NumLike {
+	type Error = DimensionError;
+	fn try_from(value: Quantity<T>) -> Result<Self, Self::Error> {
+		match value {
+			Quantity::InverseVoltage(v) => Ok(v),
+			_ => Err(DimensionError{operation: "convert", left: value.unit_symbol(), right: Self::unit_symbol()})
+		}
+	}
+}
+
+/// Converts a inverse volume charge density value into a `Quantity`
+impl<T> From<InverseVolumeChargeDensity<T>> for Quantity<T> where T: NumLike {
+	fn from(value: InverseVolumeChargeDensity<T>) -> Self { Quantity::InverseVolumeChargeDensity(value) }
+}
+
+/// Converts a `Quantity` into a inverse volume charge density value, returning an error if it holds a different unit type
+impl<T> TryFrom<Quantity<T>> for InverseVolumeChargeDensity<T> where T: NumLike {
+	type Error = DimensionError;
+	fn try_from(value: Quantity<T>) -> Result<Self, Self::Error> {
+		match value {
+			Quantity::InverseVolumeChargeDensity(v) => Ok(v),
+			_ => Err(DimensionError{operation: "convert", left: value.unit_symbol(), right: Self::unit_symbol()})
+		}
+	}
+}
+
+/// Converts a irradiance value into a `Quantity`
+impl<T> From<Irradiance<T>> for Quantity<T> where T: NumLike {
+	fn from(value: Irradiance<T>) -> Self { Quantity::Irradiance(value) }
+}
+
+/// Converts a `Quantity` into a irradiance value, returning an error if it holds a different unit type
+impl<T> TryFrom<Quantity<T>> for Irradiance<T> where T: NumLike {
+	type Error = DimensionError;
+	fn try_from(value: Quantity<T>) -> Result<Self, Self::Error> {
+		match value {
+			Quantity::Irradiance(v) => Ok(v),
+			_ => Err(DimensionError{operation: "convert", left: value.unit_symbol(), right: Self::unit_symbol()})
+		}
+	}
+}
+
+/// Converts a linear charge density value into a `Quantity`
+impl<T> From<LinearChargeDensity<T>> for Quantity<T> where T: NumLike {
+	fn from(value: LinearChargeDensity<T>) -> Self { Quantity::LinearChargeDensity(value) }
+}
+
+/// Converts a `Quantity` into a linear charge density value, returning an error if it holds a different unit type
+impl<T> TryFrom<Quantity<T>> for LinearChargeDensity<T> where T: NumLike {
+	type Error = DimensionError;
+	fn try_from(value: Quantity<T>) -> Result<Self, Self::Error> {
+		match value {
+			Quantity::LinearChargeDensity(v) => Ok(v),
+			_ => Err(DimensionError{operation: "convert", left: value.unit_symbol(), right: Self::unit_symbol()})
+		}
+	}
+}
+
+/// Converts a luminous flux value into a `Quantity`
+impl<T> From<LuminousFlux<T>> for Quantity<T> where T: NumLike {
+	fn from(value: LuminousFlux<T>) -> Self { Quantity::LuminousFlux(value) }
+}
+
+/// Converts a `Quantity` into a luminous flux value, returning an error if it holds a different unit type
+impl<T> TryFrom<Quantity<T>> for LuminousFlux<T> where T: NumLike {
+	type Error = DimensionError;
+	fn try_from(value: Quantity<T>) -> Result<Self, Self::Error> {
+		match value {
+			Quantity::LuminousFlux(v) => Ok(v),
+			_ => Err(DimensionError{operation: "convert", left: value.unit_symbol(), right: Self::unit_symbol()})
+		}
+	}
+}
+
+/// Converts a magnetic field strength value into a `Quantity`
+impl<T> From<MagneticFieldStrength<T>> for Quantity<T> where T: NumLike {
+	fn from(value: MagneticFieldStrength<T>) -> Self { Quantity::MagneticFieldStrength(value) }
+}
+
+/// Converts a `Quantity` into a magnetic field strength value, returning an error if it holds a different unit type
+impl<T> TryFrom<Quantity<T>> for MagneticFieldStrength<T> where T: NumLike {
+	type Error = DimensionError;
+	fn try_from(value: Quantity<T>) -> Result<Self, Self::Error> {
+		match value {
+			Quantity::MagneticFieldStrength(v) => Ok(v),
+			_ => Err(DimensionError{operation: "convert", left: value.unit_symbol(), right: Self::unit_symbol()})
+		}
+	}
+}
+
+/// Converts a magnetic flux value into a `Quantity`
+impl<T> From<MagneticFlux<T>> for Quantity<T> where T: NumLike {
+	fn from(value: MagneticFlux<T>) -> Self { Quantity::MagneticFlux(value) }
+}
+
+/// Converts a `Quantity` into a magnetic flux value, returning an error if it holds a different unit type
+impl<T> TryFrom<Quantity<T>> for MagneticFlux<T> where T: NumLike {
+	type Error = DimensionError;
+	fn try_from(value: Quantity<T>) -> Result<Self, Self::Error> {
+		match value {
+			Quantity::MagneticFlux(v) => Ok(v),
+			_ => Err(DimensionError{operation: "convert", left: value.unit_symbol(), right: Self::unit_symbol()})
+		}
+	}
+}
+
+/// Converts a magnetic flux density value into a `Quantity`
+impl<T> From<MagneticFluxDensity<T>> for Quantity<T> where T: NumLike {
+	fn from(value: MagneticFluxDensity<T>) -> Self { Quantity::MagneticFluxDensity(value) }
+}
+
+/// Converts a `Quantity` into a magnetic flux density value, returning an error if it holds a different unit type
+impl<T> TryFrom<Quantity<T>> for MagneticFluxDensity<T> where T: NumLike {
+	type Error = DimensionError;
+	fn try_from(value: Quantity<T>) -> Result<Self, Self::Error> {
+		match value {
+			Quantity::MagneticFluxDensity(v) => Ok(v),
+			_ => Err(DimensionError{operation: "convert", left: value.unit_symbol(), right: Self::unit_symbol()})
+		}
+	}
+}
+
+/// Converts a radiance value into a `Quantity`
+impl<T> From<Radiance<T>> for Quantity<T> where T: NumLike {
+	fn from(value: Radiance<T>) -> Self { Quantity::Radiance(value) }
+}
+
+/// Converts a `Quantity` into a radiance value, returning an error if it holds a different unit type
+impl<T> TryFrom<Quantity<T>> for Radiance<T> where T: NumLike {
+	type Error = DimensionError;
+	fn try_from(value: Quantity<T>) -> Result<Self, Self::Error> {
+		match value {
+			Quantity::Radiance(v) => Ok(v),
+			_ => Err(DimensionError{operation: "convert", left: value.unit_symbol(), right: Self::unit_symbol()})
+		}
+	}
+}
+
+/// Converts a electrical resistance value into a `Quantity`
+impl<T> From<Resistance<T>> for Quantity<T> where T: NumLike {
+	fn from(value: Resistance<T>) -> Self { Quantity::Resistance(value) }
+}
+
+/// Converts a `Quantity` into a electrical resistance value, returning an error if it holds a different unit type
+impl<T> TryFrom<Quantity<T>> for Resistance<T> where T: NumLike {
+	type Error = DimensionError;
+	fn try_from(value: Quantity<T>) -> Result<Self, Self::Error> {
+		match value {
+			Quantity::Resistance(v) => Ok(v),
+			_ => Err(DimensionError{operation: "convert", left: value.unit_symbol(), right: Self::unit_symbol()})
+		}
+	}
+}
+
+/// Converts a surface charge density value into a `Quantity`
+impl<T> From<SurfaceChargeDensity<T>> for Quantity<T> where T: NumLike {
+	fn from(value: SurfaceChargeDensity<T>) -> Self { Quantity::SurfaceChargeDensity(value) }
+}
+
+/// Converts a `Quantity` into a surface charge density value, returning an error if it holds a different unit type
+impl<T> TryFrom<Quantity<T>> for SurfaceChargeDensity<T> where T: NumLike {
+	type Error = DimensionError;
+	fn try_from(value: Quantity<T>) -> Result<Self, Self::Error> {
+		match value {
+			Quantity::SurfaceChargeDensity(v) => Ok(v),
+			_ => Err(DimensionError{operation: "convert", left: value.unit_symbol(), right: Self::unit_symbol()})
+		}
+	}
+}
+
+/// Converts a voltage value into a `Quantity`
+impl<T> From<Voltage<T>> for Quantity<T> where T: NumLike {
+	fn from(value: Voltage<T>) -> Self { Quantity::Voltage(value) }
+}
+
+/// Converts a `Quantity` into a voltage value, returning an error if it holds a different unit type
+impl<T> TryFrom<Quantity<T>> for Voltage<T> where T: NumLike {
+	type Error = DimensionError;
+	fn try_from(value: Quantity<T>) -> Result<Self, Self::Error> {
+		match value {
+			Quantity::Voltage(v) => Ok(v),
+			_ => Err(DimensionError{operation: "convert", left: value.unit_symbol(), right: Self::unit_symbol()})
+		}
+	}
+}
+
+/// Converts a volume charge density value into a `Quantity`
+impl<T> From<VolumeChargeDensity<T>> for Quantity<T> where T: NumLike {
+	fn from(value: VolumeChargeDensity<T>) -> Self { Quantity::VolumeChargeDensity(value) }
+}
+
+/// Converts a `Quantity` into a volume charge density value, returning an error if it holds a different unit type
+impl<T> TryFrom<Quantity<T>> for VolumeChargeDensity<T> where T: NumLike {
+	type Error = DimensionError;
+	fn try_from(value: Quantity<T>) -> Result<Self, Self::Error> {
+		match value {
+			Quantity::VolumeChargeDensity(v) => Ok(v),
+			_ => Err(DimensionError{operation: "convert", left: value.unit_symbol(), right: Self::unit_symbol()})
+		}
+	}
+}
+
+/// Converts a angle value into a `Quantity`
+impl<T> From<Angle<T>> for Quantity<T> where T: NumLike {
+	fn from(value: Angle<T>) -> Self { Quantity::Angle(value) }
+}
+
+/// Converts a `Quantity` into a angle value, returning an error if it holds a different unit type
+impl<T> TryFrom<Quantity<T>> for Angle<T> where T: NumLike {
+	type Error = DimensionError;
+	fn try_from(value: Quantity<T>) -> Result<Self, Self::Error> {
+		match value {
+			Quantity::Angle(v) => Ok(v),
+			_ => Err(DimensionError{operation: "convert", left: value.unit_symbol(), right: Self::unit_symbol()})
+		}
+	}
+}
+
+/// Converts a area value into a `Quantity`
+impl<T> From<Area<T>> for Quantity<T> where T: NumLike {
+	fn from(value: Area<T>) -> Self { Quantity::Area(value) }
+}
+
+/// Converts a `Quantity` into a area value, returning an error if it holds a different unit type
+impl<T> TryFrom<Quantity<T>> for Area<T> where T: NumLike {
+	type Error = DimensionError;
+	fn try_from(value: Quantity<T>) -> Result<Self, Self::Error> {
+		match value {
+			Quantity::Area(v) => Ok(v),
+			_ => Err(DimensionError{operation: "convert", left: value.unit_symbol(), right: Self::unit_symbol()})
+		}
+	}
+}
+
+/// Converts a inverse angle value into a `Quantity`
+impl<T> From<InverseAngle<T>> for Quantity<T> where T: NumLike {
+	fn from(value: InverseAngle<T>) -> Self { Quantity::InverseAngle(value) }
+}
+
+/// Converts a `Quantity` into a inverse angle value, returning an error if it holds a different unit type
+impl<T> TryFrom<Quantity<T>> for InverseAngle<T> where T: NumLike {
+	type Error = DimensionError;
+	fn try_from(value: Quantity<T>) -> Result<Self, Self::Error> {
+		match value {
+			Quantity::InverseAngle(v) => Ok(v),
+			_ => Err(DimensionError{operation: "convert", left: value.unit_symbol(), right: Self::unit_symbol()})
+		}
+	}
+}
+
+/// Converts a inverse area value into a `Quantity`
+impl<T> From<InverseArea<T>> for Quantity<T> where T: NumLike {
+	fn from(value: InverseArea<T>) -> Self { Quantity::InverseArea(value) }
+}
+
+/// Converts a `Quantity` into a inverse area value, returning an error if it holds a different unit type
+impl<T> TryFrom<Quantity<T>> for InverseArea<T> where T: NumLike {
+	type Error = DimensionError;
+	fn try_from(value: Quantity<T>) -> Result<Self, Self::Error> {
+		match value {
+			Quantity::InverseArea(v) => Ok(v),
+			_ => Err(DimensionError{operation: "convert", left: value.unit_symbol(), right: Self::unit_symbol()})
+		}
+	}
+}
+
+/// Converts a inverse solid angle value into a `Quantity`
+impl<T> From<InverseSolidAngle<T>> for Quantity<T> where T: NumLike {
+	fn from(value: InverseSolidAngle<T>) -> Self { Quantity::InverseSolidAngle(value) }
+}
+
+/// Converts a `Quantity` into a inverse solid angle value, returning an error if it holds a different unit type
+impl<T> TryFrom<Quantity<T>> for InverseSolidAngle<T> where T: NumLike {
+	type Error = DimensionError;
+	fn try_from(value: Quantity<T>) -> Result<Self, Self::Error> {
+		match value {
+			Quantity::InverseSolidAngle(v) => Ok(v),
+			_ => Err(DimensionError{operation: "convert", left: value.unit_symbol(), right: Self::unit_symbol()})
+		}
+	}
+}
+
+/// Converts a inverse volume value into a `Quantity`
+impl<T> From<InverseVolume<T>> for Quantity<T> where T: NumLike {
+	fn from(value: InverseVolume<T>) -> Self { Quantity::InverseVolume(value) }
+}
+
+/// Converts a `Quantity` into a inverse volume value, returning an error if it holds a different unit type
+impl<T> TryFrom<Quantity<T>> for InverseVolume<T> where T: NumLike {
+	type Error = DimensionError;
+	fn try_from(value: Quantity<T>) -> Result<Self, Self::Error> {
+		match value {
+			Quantity::InverseVolume(v) => Ok(v),
+			_ => Err(DimensionError{operation: "convert", left: value.unit_symbol(), right: Self::unit_symbol()})
+		}
+	}
+}
+
+/// Converts a solid angle value into a `Quantity`
+impl<T> From<SolidAngle<T>> for Quantity<T> where T: NumLike {
+	fn from(value: SolidAngle<T>) -> Self { Quantity::SolidAngle(value) }
+}
+
+/// Converts a `Quantity` into a solid angle value, returning an error if it holds a different unit type
+impl<T> TryFrom<Quantity<T>> for SolidAngle<T> where T: NumLike {
+	type Error = DimensionError;
+	fn try_from(value: Quantity<T>) -> Result<Self, Self::Error> {
+		match value {
+			Quantity::SolidAngle(v) => Ok(v),
+			_ => Err(DimensionError{operation: "convert", left: value.unit_symbol(), right: Self::unit_symbol()})
+		}
+	}
+}
+
+/// Converts a volume value into a `Quantity`
+impl<T> From<Volume<T>> for Quantity<T> where T: NumLike {
+	fn from(value: Volume<T>) -> Self { Quantity::Volume(value) }
+}
+
+/// Converts a `Quantity` into a volume value, returning an error if it holds a different unit type
+impl<T> TryFrom<Quantity<T>> for Volume<T> where T: NumLike {
+	type Error = DimensionError;
+	fn try_from(value: Quantity<T>) -> Result<Self, Self::Error> {
+		match value {
+			Quantity::Volume(v) => Ok(v),
+			_ => Err(DimensionError{operation: "convert", left: value.unit_symbol(), right: Self::unit_symbol()})
+		}
+	}
+}
+
+/// Converts a acceleration value into a `Quantity`
+impl<T> From<Acceleration<T>> for Quantity<T> where T: NumLike {
+	fn from(value: Acceleration<T>) -> Self { Quantity::Acceleration(value) }
+}
+
+/// Converts a `Quantity` into a acceleration value, returning an error if it holds a different unit type
+impl<T> TryFrom<Quantity<T>> for Acceleration<T> where T: NumLike {
+	type Error = DimensionError;
+	fn try_from(value: Quantity<T>) -> Result<Self, Self::Error> {
+		match value {
+			Quantity::Acceleration(v) => Ok(v),
+			_ => Err(DimensionError{operation: "convert", left: value.unit_symbol(), right: Self::unit_symbol()})
+		}
+	}
+}
+
+/// Converts a angular acceleration value into a `Quantity`
+impl<T> From<AngularAcceleration<T>> for Quantity<T> where T: NumLike {
+	fn from(value: AngularAcceleration<T>) -> Self { Quantity::AngularAcceleration(value) }
+}
+
+/// Converts a `Quantity` into a angular acceleration value, returning an error if it holds a different unit type
+impl<T> TryFrom<Quantity<T>> for AngularAcceleration<T> where T: NumLike {
+	type Error = DimensionError;
+	fn try_from(value: Quantity<T>) -> Result<Self, Self::Error> {
+		match value {
+			Quantity::AngularAcceleration(v) => Ok(v),
+			_ => Err(DimensionError{operation: "convert", left: value.unit_symbol(), right: Self::unit_symbol()})
+		}
+	}
+}
+
+/// Converts a angular momentum value into a `Quantity`
+impl<T> From<AngularMomentum<T>> for Quantity<T> where T: NumLike {
+	fn from(value: AngularMomentum<T>) -> Self { Quantity::AngularMomentum(value) }
+}
+
+/// Converts a `Quantity` into a angular momentum value, returning an error if it holds a different unit type
+impl<T> TryFrom<Quantity<T>> for AngularMomentum<T> where T: NumLike {
+	type Error = DimensionError;
+	fn try_from(value: Quantity<T>) -> Result<Self, Self::Error> {
+		match value {
+			Quantity::AngularMomentum(v) => Ok(v),
+			_ => Err(DimensionError{operation: "convert", left: value.unit_symbol(), right: Self::unit_symbol()})
+		}
+	}
+}
+
+/// Converts a angular velocity value into a `Quantity`
+impl<T> From<AngularVelocity<T>> for Quantity<T> where T: NumLike {
+	fn from(value: AngularVelocity<T>) -> Self { Quantity::AngularVelocity(value) }
+}
+
+/// Converts a `Quantity` into a angular velocity value, returning an error if it holds a different unit type
+impl<T> TryFrom<Quantity<T>> for AngularVelocity<T> where T: NumLike {
+	type Error = DimensionError;
+	fn try_from(value: Quantity<T>) -> Result<Self, Self::Error> {
+		match value {
+			Quantity::AngularVelocity(v) => Ok(v),
+			_ => Err(DimensionError{operation: "convert", left: value.unit_symbol(), right: Self::unit_symbol()})
+		}
+	}
+}
+
+/// Converts a area density value into a `Quantity`
+impl<T> From<AreaDensity<T>> for Quantity<T> where T: NumLike {
+	fn from(value: AreaDensity<T>) -> Self { Quantity::AreaDensity(value) }
+}
+
+/// Converts a `Quantity` into a area density value, returning an error if it holds a different unit type
+impl<T> TryFrom<Quantity<T>> for AreaDensity<T> where T: NumLike {
+	type Error = DimensionError;
+	fn try_from(value: Quantity<T>) -> Result<Self, Self::Error> {
+		match value {
+			Quantity::AreaDensity(v) => Ok(v),
+			_ => Err(DimensionError{operation: "convert", left: value.unit_symbol(), right: Self::unit_symbol()})
+		}
+	}
+}
+
+/// Converts a area per mass value into a `Quantity`
+impl<T> From<AreaPerMass<T>> for Quantity<T> where T: NumLike {
+	fn from(value: AreaPerMass<T>) -> Self { Quantity::AreaPerMass(value) }
+}
+
+/// Converts a `Quantity` into a area per mass value, returning an error if it holds a different unit type
+impl<T> TryFrom<Quantity<T>> for AreaPerMass<T> where T: NumLike {
+	type Error = DimensionError;
+	fn try_from(value: Quantity<T>) -> Result<Self, Self::Error> {
+		match value {
+			Quantity::AreaPerMass(v) => Ok(v),
+			_ => Err(DimensionError{operation: "convert", left: value.unit_symbol(), right: Self::unit_symbol()})
+		}
+	}
+}
+
+/// Converts a density value into a `Quantity`
+impl<T> From<Density<T>> for Quantity<T> where T: NumLike {
+	fn from(value: Density<T>) -> Self { Quantity::Density(value) }
+}
+
+/// Converts a `Quantity` into a density value, returning an error if it holds a different unit type
+impl<T> TryFrom<Quantity<T>> for Density<T> where T: NumLike {
+	type Error = DimensionError;
+	fn try_from(value: Quantity<T>) -> Result<Self, Self::Error> {
+		match value {
+			Quantity::Density(v) => Ok(v),
+			_ => Err(DimensionError{operation: "convert", left: value.unit_symbol(), right: Self::unit_symbol()})
+		}
+	}
+}
+
+/// Converts a dynamic viscosity value into a `Quantity`
+impl<T> From<DynamicViscosity<T>> for Quantity<T> where T: NumLike {
+	fn from(value: DynamicViscosity<T>) -> Self { Quantity::DynamicViscosity(value) }
+}
+
+/// Converts a `Quantity` into a dynamic viscosity value, returning an error if it holds a different unit type
+impl<T> TryFrom<Quantity<T>> for DynamicViscosity<T> where T: NumLike {
+	type Error = DimensionError;
+	fn try_from(value: Quantity<T>) -> Result<Self, Self::Error> {
+		match value {
+			Quantity::DynamicViscosity(v) => Ok(v),
+			_ => Err(DimensionError{operation: "convert", left: value.unit_symbol(), right: Self::unit_symbol()})
+		}
+	}
+}
+
+/// Converts a energy value into a `Quantity`
+impl<T> From<Energy<T>> for Quantity<T> where T: NumLike {
+	fn from(value: Energy<T>) -> Self { Quantity::Energy(value) }
+}
+
+/// Converts a `Quantity` into a energy value, returning an error if it holds a different unit type
+impl<T> TryFrom<Quantity<T>> for Energy<T> where T: NumLike {
+	type Error = DimensionError;
+	fn try_from(value: Quantity<T>) -> Result<Self, Self::Error> {
+		match value {
+			Quantity::Energy(v) => Ok(v),
+			_ => Err(DimensionError{operation: "convert", left: value.unit_symbol(), right: Self::unit_symbol()})
+		}
+	}
+}
+
+/// Converts a force value into a `Quantity`
+impl<T> From<Force<T>> for Quantity<T> where T: NumLike {
+	fn from(value: Force<T>) -> Self { Quantity::Force(value) }
+}
+
+/// Converts a `Quantity` into a force value, returning an error if it holds a different unit type
+impl<T> TryFrom<Quantity<T>> for Force<T> where T: NumLike {
+	type Error = DimensionError;
+	fn try_from(value: Quantity<T>) -> Result<Self, Self::Error> {
+		match value {
+			Quantity::Force(v) => Ok(v),
+			_ => Err(DimensionError{operation: "convert", left: value.unit_symbol(), right: Self::unit_symbol()})
+		}
+	}
+}
+
+/// Converts a frequency value into a `Quantity`
+impl<T> From<Frequency<T>> for Quantity<T> where T: NumLike {
+	fn from(value: Frequency<T>) -> Self { Quantity::Frequency(value) }
+}
+
+/// Converts a `Quantity` into a frequency value, returning an error if it holds a different unit type
+impl<T> TryFrom<Quantity<T>> for Frequency<T> where T: NumLike {
+	type Error = DimensionError;
+	fn try_from(value: Quantity<T>) -> Result<Self, Self::Error> {
+		match value {
+			Quantity::Frequency(v) => Ok(v),
+			_ => Err(DimensionError{operation: "convert", left: value.unit_symbol(), right: Self::unit_symbol()})
+		}
+	}
+}
+
+/// Converts a inverse acceleration value into a `Quantity`
+impl<T> From<InverseAcceleration<T>> for Quantity<T> where T: NumLike {
+	fn from(value: InverseAcceleration<T>) -> Self { Quantity::InverseAcceleration(value) }
+}
+
+/// Converts a `Quantity` into a inverse acceleration value, returning an error if it holds a different unit type
+impl<T> TryFrom<Quantity<T>> for InverseAcceleration<T> where T: NumLike {
+	type Error = DimensionError;
+	fn try_from(value: Quantity<T>) -> Result<Self, Self::Error> {
+		match value {
+			Quantity::InverseAcceleration(v) => Ok(v),
+			_ => Err(DimensionError{operation: "convert", left: value.unit_symbol(), right: Self::unit_symbol()})
+		}
+	}
+}
+
+/// Converts a inverse angular acceleration value into a `Quantity`
+impl<T> From<InverseAngularAcceleration<T>> for Quantity<T> where T: NumLike {
+	fn from(value: InverseAngularAcceleration<T>) -> Self { Quantity::InverseAngularAcceleration(value) }
+}
+
+/// Converts a `Quantity` into a inverse angular acceleration value, returning an error if it holds a different unit type
+impl<T> TryFrom<Quantity<T>> for InverseAngularAcceleration<T> where T: NumLike {
+	type Error = DimensionError;
+	fn try_from(value: Quantity<T>) -> Result<Self, Self::Error> {
+		match value {
+			Quantity::InverseAngularAcceleration(v) => Ok(v),
+			_ => Err(DimensionError{operation: "convert", left: value.unit_symbol(), right: Self::unit_symbol()})
+		}
+	}
+}
+
+/// Converts a inverse angular momentum value into a `Quantity`
+impl<T> From<InverseAngularMomentum<T>> for Quantity<T> where T: NumLike {
+	fn from(value: InverseAngularMomentum<T>) -> Self { Quantity::InverseAngularMomentum(value) }
+}
+
+/// Converts a `Quantity` into a inverse angular momentum value, returning an error if it holds a different unit type
+impl<T> TryFrom<Quantity<T>> for InverseAngularMomentum<T> where T: NumLike {
+	type Error = DimensionError;
+	fn try_from(value: Quantity<T>) -> Result<Self, Self::Error> {
+		match value {
+			Quantity::InverseAngularMomentum(v) => Ok(v),
+			_ => Err(DimensionError{operation: "convert", left: value.unit_symbol(), right: Self::unit_symbol()})
+		}
+	}
+}
+
+/// Converts a inverse angular velocity value into a `Quantity`
+impl<T> From<InverseAngularVelocity<T>> for Quantity<T> where T: NumLike {
+	fn from(value: InverseAngularVelocity<T>) -> Self { Quantity::InverseAngularVelocity(value) }
+}
+
+/// Converts a `Quantity` into a inverse angular velocity value, returning an error if it holds a different unit type
+impl<T> TryFrom<Quantity<T>> for InverseAngularVelocity<T> where T: NumLike {
+	type Error = DimensionError;
+	fn try_from(value: Quantity<T>) -> Result<Self, Self::Error> {
+		match value {
+			Quantity::InverseAngularVelocity(v) => Ok(v),
+			_ => Err(DimensionError{operation: "convert", left: value.unit_symbol(), right: Self::unit_symbol()})
+		}
+	}
+}
+
+/// Converts a inverse dynamic viscosity value into a `Quantity`
+impl<T> From<InverseDynamicViscosity<T>> for Quantity<T> where T: NumLike {
+	fn from(value: InverseDynamicViscosity<T>) -> Self { Quantity::InverseDynamicViscosity(value) }
+}
+
+/// Converts a `Quantity` into a inverse dynamic viscosity value, returning an error if it holds a different unit type
+impl<T> TryFrom<Quantity<T>> for InverseDynamicViscosity<T> where T: NumLike {
+	type Error = DimensionError;
+	fn try_from(value: Quantity<T>) -> Result<Self, Self::Error> {
+		match value {
+			Quantity::InverseDynamicViscosity(v) => Ok(v),
+			_ => Err(DimensionError{operation: "convert", left: value.unit_symbol(), right: Self::unit_symbol()})
+		}
+	}
+}
+
+/// Converts a inverse energy value into a `Quantity`
+impl<T> From<InverseEnergy<T>> for Quantity<T> where T: NumLike {
+	fn from(value: InverseEnergy<T>) -> Self { Quantity::InverseEnergy(value) }
+}
+
+/// Converts a `Quantity` into a inverse energy value, returning an error if it holds a different unit type
+impl<T> TryFrom<Quantity<T>> for InverseEnergy<T> where T: NumLike {
+	type Error = DimensionError;
+	fn try_from(value: Quantity<T>) -> Result<Self, Self::Error> {
+		match value {
+			Quantity::InverseEnergy(v) => Ok(v),
+			_ => Err(DimensionError{operation: "convert", left: value.unit_symbol(), right: Self::unit_symbol()})
+		}
+	}
+}
+
+/// Converts a inverse force value into a `Quantity`
+impl<T> From<InverseForce<T>> for Quantity<T> where T: NumLike {
+	fn from(value: InverseForce<T>) -> Self { Quantity::InverseForce(value) }
+}
+
+/// Converts a `Quantity` into a inverse force value, returning an error if it holds a different unit type
+impl<T> TryFrom<Quantity<T>> for InverseForce<T> where T: NumLike {
+	type Error = DimensionError;
+	fn try_from(value: Quantity<T>) -> Result<Self, Self::Error> {
+		match value {
+			Quantity::InverseForce(v) => Ok(v),
+			_ => Err(DimensionError{operation: "convert", left: value.unit_symbol(), right: Self::unit_symbol()})
+		}
+	}
+}
+
+/// Converts a inverse jerk value into a `Quantity`
+impl<T> From<InverseJerk<T>> for Quantity<T> where T: NumLike {
+	fn from(value: InverseJerk<T>) -> Self { Quantity::InverseJerk(value) }
+}
+
+/// Converts a `Quantity` into a inverse jerk value, returning an error if it holds a different unit type
+impl<T> TryFrom<Quantity<T>> for InverseJerk<T> where T: NumLike {
+	type Error = DimensionError;
+	fn try_from(value: Quantity<T>) -> Result<Self, Self::Error> {
+		match value {
+			Quantity::InverseJerk(v) => Ok(v),
+			_ => Err(DimensionError{operation: "convert", left: value.unit_symbol(), right: Self::unit_symbol()})
+		}
+	}
+}
+
+/// Converts a inverse kinematic viscosity value into a `Quantity`
+impl<T> From<InverseKinematicViscosity<T>> for Quantity<T> where T: NumLike {
+	fn from(value: InverseKinematicViscosity<T>) -> Self { Quantity::InverseKinematicViscosity(value) }
+}
+
+/// Converts a `Quantity` into a inverse kinematic viscosity value, returning an error if it holds a different unit type
+impl<T> TryFrom<Quantity<T>> for InverseKinematicViscosity<T> where T: NumLike {
+	type Error = DimensionError;
+	fn try_from(value: Quantity<T>) -> Result<Self, Self::Error> {
+		match value {
+			Quantity::InverseKinematicViscosity(v) => Ok(v),
+			_ => Err(DimensionError{operation: "convert", left: value.unit_symbol(), right: Self::unit_symbol()})
+		}
+	}
+}
+
+/// Converts a inverse mass flow rate value into a `Quantity`
+impl<T> From<InverseMassFlowRate<T>> for Quantity<T> where T: NumLike {
+	fn from(value: InverseMassFlowRate<T>) -> Self { Quantity::InverseMassFlowRate(value) }
+}
+
+/// Converts a `Quantity` into a inverse mass flow rate value, returning an error if it holds a different unit type
+impl<T> TryFrom<Quantity<T>> for InverseMassFlowRate<T> where T: NumLike {
+	type Error = DimensionError;
+	fn try_from(value: Quantity<T>) -> Result<Self, Self::Error> {
+		match value {
+			Quantity::InverseMassFlowRate(v) => Ok(v),
+			_ => Err(DimensionError{operation: "convert", left: value.unit_symbol(), right: Self::unit_symbol()})
+		}
+	}
+}
+
+/// Converts a inverse moment of inertia value into a `Quantity`
+impl<T> From<InverseMomentOfInertia<T>> for Quantity<T> where T: NumLike {
+	fn from(value: InverseMomentOfInertia<T>) -> Self { Quantity::InverseMomentOfInertia(value) }
+}
+
+/// Converts a `Quantity` into a inverse moment of inertia value, returning an error if it holds a different unit type
+impl<T> TryFrom<Quantity<T>> for InverseMomentOfInertia<T> where T: NumLike {
+	type Error = DimensionError;
+	fn try_from(value: Quantity<T>) -> Result<Self, Self::Error> {
+		match value {
+			Quantity::InverseMomentOfInertia(v) => Ok(v),
+			_ => Err(DimensionError{operation: "convert", left: value.unit_symbol(), right: Self::unit_symbol()})
+		}
+	}
+}
+
+/// Converts a inverse momentum value into a `Quantity`
+impl<T> From<InverseMomentum<T>> for Quantity<T> where T: NumLike {
+	fn from(value: InverseMomentum<T>) -> Self { Quantity::InverseMomentum(value) }
+}
+
+/// Converts a `Quantity` into a inverse momentum value, returning an error if it holds a different unit type
+impl<T> TryFrom<Quantity<T>> for InverseMomentum<T> where T: NumLike {
+	type Error = DimensionError;
+	fn try_from(value: Quantity<T>) -> Result<Self, Self::Error> {
+		match value {
+			Quantity::InverseMomentum(v) => Ok(v),
+			_ => Err(DimensionError{operation: "convert", left: value.unit_symbol(), right: Self::unit_symbol()})
+		}
+	}
+}
+
+/// Converts a inverse power value into a `Quantity`
+impl<T> From<InversePower<T>> for Quantity<T> where T: NumLike {
+	fn from(value: InversePower<T>) -> Self { Quantity::InversePower(value) }
+}
+
+/// Converts a `Quantity` into a inverse power value, returning an error if it holds a different unit type
+impl<T> TryFrom<Quantity<T>> for InversePower<T> where T: NumLike {
+	type Error = DimensionError;
+	fn try_from(value: Quantity<T>) -> Result<Self, Self::Error> {
+		match value {
+			Quantity::InversePower(v) => Ok(v),
+			_ => Err(DimensionError{operation: "convert", left: value.unit_symbol(), right: Self::unit_symbol()})
+		}
+	}
+}
+
+/// Converts a inverse pressure value into a `Quantity`
+impl<T> From<InversePressure<T>> for Quantity<T> where T: NumLike {
+	fn from(value: InversePressure<T>) -> Self { Quantity::InversePressure(value) }
+}
+
+/// Converts a `Quantity` into a inverse pressure value, returning an error if it holds a different unit type
+impl<T> TryFrom<Quantity<T>> for InversePressure<T> where T: NumLike {
+	type Error = DimensionError;
+	fn try_from(value: Quantity<T>) -> Result<Self, Self::Error> {
+		match value {
+			Quantity::InversePressure(v) => Ok(v),
+			_ => Err(DimensionError{operation: "convert", left: value.unit_symbol(), right: Self::unit_symbol()})
+		}
+	}
+}
+
+/// Converts a inverse torque value into a `Quantity`
+impl<T> From<InverseTorque<T>> for Quantity<T> where T: NumLike {
+	fn from(value: InverseTorque<T>) -> Self { Quantity::InverseTorque(value) }
+}
+
+/// Converts a `Quantity` into a inverse torque value, returning an error if it holds a different unit type
+impl<T> TryFrom<Quantity<T>> for InverseTorque<T> where T: NumLike {
+	type Error = DimensionError;
+	fn try_from(value: Quantity<T>) -> Result<Self, Self::Error> {
+		match value {
+			Quantity::InverseTorque(v) => Ok(v),
+			_ => Err(DimensionError{operation: "convert", left: value.unit_symbol(), right: Self::unit_symbol()})
+		}
+	}
+}
+
+/// Converts a inverse volumetric flow rate value into a `Quantity`
+impl<T> From<InverseVolumetricFlowRate<T>> for Quantity<T> where T: NumLike {
+	fn from(value: InverseVolumetricFlowRate<T>) -> Self { Quantity::InverseVolumetricFlowRate(value) }
+}
+
+/// Converts a `Quantity` into a inverse volumetric flow rate value, returning an error if it holds a different unit type
+impl<T> TryFrom<Quantity<T>> for InverseVolumetricFlowRate<T> where T: NumLike {
+	type Error = DimensionError;
+	fn try_from(value: Quantity<T>) -> Result<Self, Self::Error> {
+		match value {
+			Quantity::InverseVolumetricFlowRate(v) => Ok(v),
+			_ => Err(DimensionError{operation: "convert", left: value.unit_symbol(), right: Self::unit_symbol()})
+		}
+	}
+}
+
+/// Converts a jerk value into a `Quantity`
+impl<T> From<Jerk<T>> for Quantity<T> where T: NumLike {
+	fn from(value: Jerk<T>) -> Self { Quantity::Jerk(value) }
+}
+
+/// Converts a `Quantity` into a jerk value, returning an error if it holds a different unit type
+impl<T> TryFrom<Quantity<T>> for Jerk<T> where T: NumLike {
+	type Error = DimensionError;
+	fn try_from(value: Quantity<T>) -> Result<Self, Self::Error> {
+		match value {
+			Quantity::Jerk(v) => Ok(v),
+			_ => Err(DimensionError{operation: "convert", left: value.unit_symbol(), right: Self::unit_symbol()})
+		}
+	}
+}
+
+/// Converts a kinematic viscosity value into a `Quantity`
+impl<T> From<KinematicViscosity<T>> for Quantity<T> where T: NumLike {
+	fn from(value: KinematicViscosity<T>) -> Self { Quantity::KinematicViscosity(value) }
+}
+
+/// Converts a `Quantity` into a kinematic viscosity value, returning an error if it holds a different unit type
+impl<T> TryFrom<Quantity<T>> for KinematicViscosity<T> where T: NumLike {
+	type Error = DimensionError;
+	fn try_from(value: Quantity<T>) -> Result<Self, Self::Error> {
+		match value {
+			Quantity::KinematicViscosity(v) => Ok(v),
+			_ => Err(DimensionError{operation: "convert", left: value.unit_symbol(), right: Self::unit_symbol()})
+		}
+	}
+}
+
+/// Converts a mass flow rate value into a `Quantity`
+impl<T> From<MassFlowRate<T>> for Quantity<T> where T: NumLike {
+	fn from(value: MassFlowRate<T>) -> Self { Quantity::MassFlowRate(value) }
+}
+
+/// Converts a `Quantity` into a mass flow rate value, returning an error if it holds a different unit type
+impl<T> TryFrom<Quantity<T>> for MassFlowRate<T> where T: NumLike {
+	type Error = DimensionError;
+	fn try_from(value: Quantity<T>) -> Result<Self, Self::Error> {
+		match value {
+			Quantity::MassFlowRate(v) => Ok(v),
+			_ => Err(DimensionError{operation: "convert", left: value.unit_symbol(), right: Self::unit_symbol()})
+		}
+	}
+}
+
+/// Converts a moment of inertia value into a `Quantity`
+impl<T> From<MomentOfInertia<T>> for Quantity<T> where T: NumLike {
+	fn from(value: MomentOfInertia<T>) -> Self { Quantity::MomentOfInertia(value) }
+}
+
+/// Converts a `Quantity` into a moment of inertia value, returning an error if it holds a different unit type
+impl<T> TryFrom<Quantity<T>> for MomentOfInertia<T> where T: NumLike {
+	type Error = DimensionError;
+	fn try_from(value: Quantity<T>) -> Result<Self, Self::Error> {
+		match value {
+			Quantity::MomentOfInertia(v) => Ok(v),
+			_ => Err(DimensionError{operation: "convert", left: value.unit_symbol(), right: Self::unit_symbol()})
+		}
+	}
+}
+
+/// Converts a momentum value into a `Quantity`
+impl<T> From<Momentum<T>> for Quantity<T> where T: NumLike {
+	fn from(value: Momentum<T>) -> Self { Quantity::Momentum(value) }
+}
+
+/// Converts a `Quantity` into a momentum value, returning an error if it holds a different unit type
+impl<T> TryFrom<Quantity<T>> for Momentum<T> where T: NumLike {
+	type Error = DimensionError;
+	fn try_from(value: Quantity<T>) -> Result<Self, Self::Error> {
+		match value {
+			Quantity::Momentum(v) => Ok(v),
+			_ => Err(DimensionError{operation: "convert", left: value.unit_symbol(), right: Self::unit_symbol()})
+		}
+	}
+}
+
+/// Converts a power value into a `Quantity`
+impl<T> From<Power<T>> for Quantity<T> where T: NumLike {
+	fn from(value: Power<T>) -> Self { Quantity::Power(value) }
+}
+
+/// Converts a `Quantity` into a power value, returning an error if it holds a different unit type
+impl<T> TryFrom<Quantity<T>> for Power<T> where T: NumLike {
+	type Error = DimensionError;
+	fn try_from(value: Quantity<T>) -> Result<Self, Self::Error> {
+		match value {
+			Quantity::Power(v) => Ok(v),
+			_ => Err(DimensionError{operation: "convert", left: value.unit_symbol(), right: Self::unit_symbol()})
+		}
+	}
+}
+
+/// Converts a pressure value into a `Quantity`
+impl<T> From<Pressure<T>> for Quantity<T> where T: NumLike {
+	fn from(value: Pressure<T>) -> Self { Quantity::Pressure(value) }
+}
+
+/// Converts a `Quantity` into a pressure value, returning an error if it holds a different unit type
+impl<T> TryFrom<Quantity<T>> for Pressure<T> where T: NumLike {
+	type Error = DimensionError;
+	fn try_from(value: Quantity<T>) -> Result<Self, Self::Error> {
+		match value {
+			Quantity::Pressure(v) => Ok(v),
+			_ => Err(DimensionError{operation: "convert", left: value.unit_symbol(), right: Self::unit_symbol()})
+		}
+	}
+}
+
+/// Converts a time per distance value into a `Quantity`
+impl<T> From<TimePerDistance<T>> for Quantity<T> where T: NumLike {
+	fn from(value: TimePerDistance<T>) -> Self { Quantity::TimePerDistance(value) }
+}
+
+/// Converts a `Quantity` into a time per distance value, returning an error if it holds a different unit type
+impl<T> TryFrom<Quantity<T>> for TimePerDistance<T> where T: NumLike {
+	type Error = DimensionError;
+	fn try_from(value: Quantity<T>) -> Result<Self, Self::Error> {
+		match value {
+			Quantity::TimePerDistance(v) => Ok(v),
+			_ => Err(DimensionError{operation: "convert", left: value.unit_symbol(), right: Self::unit_symbol()})
+		}
+	}
+}
+
+/// Converts a torque value into a `Quantity`
+impl<T> From<Torque<T>> for Quantity<T> where T: NumLike {
+	fn from(value: Torque<T>) -> Self { Quantity::Torque(value) }
+}
+
+/// Converts a `Quantity` into a torque value, returning an error if it holds a different unit type
+impl<T> TryFrom<Quantity<T>> for Torque<T> where T: NumLike {
+	type Error = DimensionError;
+	fn try_from(value: Quantity<T>) -> Result<Self, Self::Error> {
+		match value {
+			Quantity::Torque(v) => Ok(v),
+			_ => Err(DimensionError{operation: "convert", left: value.unit_symbol(), right: Self::unit_symbol()})
+		}
+	}
+}
+
+/// Converts a velocity value into a `Quantity`
+impl<T> From<Velocity<T>> for Quantity<T> where T: NumLike {
+	fn from(value: Velocity<T>) -> Self { Quantity::Velocity(value) }
+}
+
+/// Converts a `Quantity` into a velocity value, returning an error if it holds a different unit type
+impl<T> TryFrom<Quantity<T>> for Velocity<T> where T: NumLike {
+	type Error = DimensionError;
+	fn try_from(value: Quantity<T>) -> Result<Self, Self::Error> {
+		match value {
+			Quantity::Velocity(v) => Ok(v),
+			_ => Err(DimensionError{operation: "convert", left: value.unit_symbol(), right: Self::unit_symbol()})
+		}
+	}
+}
+
+/// Converts a volume per mass value into a `Quantity`
+impl<T> From<VolumePerMass<T>> for Quantity<T> where T: NumLike {
+	fn from(value: VolumePerMass<T>) -> Self { Quantity::VolumePerMass(value) }
+}
+
+/// Converts a `Quantity` into a volume per mass value, returning an error if it holds a different unit type
+impl<T> TryFrom<Quantity<T>> for VolumePerMass<T> where T: NumLike {
+	type Error = DimensionError;
+	fn try_from(value: Quantity<T>) -> Result<Self, Self::Error> {
+		match value {
+			Quantity::VolumePerMass(v) => Ok(v),
+			_ => Err(DimensionError{operation: "convert", left: value.unit_symbol(), right: Self::unit_symbol()})
+		}
+	}
+}
+
+/// Converts a volumetric flow rate value into a `Quantity`
+impl<T> From<VolumetricFlowRate<T>> for Quantity<T> where T: NumLike {
+	fn from(value: VolumetricFlowRate<T>) -> Self { Quantity::VolumetricFlowRate(value) }
+}
+
+/// Converts a `Quantity` into a volumetric flow rate value, returning an error if it holds a different unit type
+impl<T> TryFrom<Quantity<T>> for VolumetricFlowRate<T> where T: NumLike {
+	type Error = DimensionError;
+	fn try_from(value: Quantity<T>) -> Result<Self, Self::Error> {
+		match value {
+			Quantity::VolumetricFlowRate(v) => Ok(v),
+			_ => Err(DimensionError{operation: "convert", left: value.unit_symbol(), right: Self::unit_symbol()})
+		}
+	}
+}
+
+/// Converts a absorbed dose value into a `Quantity`
+impl<T> From<AbsorbedDose<T>> for Quantity<T> where T: NumLike {
+	fn from(value: AbsorbedDose<T>) -> Self { Quantity::AbsorbedDose(value) }
+}
+
+/// Converts a `Quantity` into a absorbed dose value, returning an error if it holds a different unit type
+impl<T> TryFrom<Quantity<T>> for AbsorbedDose<T> where T: NumLike {
+	type Error = DimensionError;
+	fn try_from(value: Quantity<T>) -> Result<Self, Self::Error> {
+		match value {
+			Quantity::AbsorbedDose(v) => Ok(v),
+			_ => Err(DimensionError{operation: "convert", left: value.unit_symbol(), right: Self::unit_symbol()})
+		}
+	}
+}
+
+/// Converts a absorbed dose rate value into a `Quantity`
+impl<T> From<AbsorbedDoseRate<T>> for Quantity<T> where T: NumLike {
+	fn from(value: AbsorbedDoseRate<T>) -> Self { Quantity::AbsorbedDoseRate(value) }
+}
+
+/// Converts a `Quantity` into a absorbed dose rate value, returning an error if it holds a different unit type
+impl<T> TryFrom<Quantity<T>> for AbsorbedDoseRate<T> where T: NumLike {
+	type Error = DimensionError;
+	fn try_from(value: Quantity<T>) -> Result<Self, Self::Error> {
+		match value {
+			Quantity::AbsorbedDoseRate(v) => Ok(v),
+			_ => Err(DimensionError{operation: "convert", left: value.unit_symbol(), right: Self::unit_symbol()})
+		}
+	}
+}
+
+/// Converts a dose equivalent value into a `Quantity`
+impl<T> From<DoseEquivalent<T>> for Quantity<T> where T: NumLike {
+	fn from(value: DoseEquivalent<T>) -> Self { Quantity::DoseEquivalent(value) }
+}
+
+/// Converts a `Quantity` into a dose equivalent value, returning an error if it holds a different unit type
+impl<T> TryFrom<Quantity<T>> for DoseEquivalent<T> where T: NumLike {
+	type Error = DimensionError;
+	fn try_from(value: Quantity<T>) -> Result<Self, Self::Error> {
+		match value {
+			Quantity::DoseEquivalent(v) => Ok(v),
+			_ => Err(DimensionError{operation: "convert", left: value.unit_symbol(), right: Self::unit_symbol()})
+		}
+	}
+}
+
+/// Converts a dose equivalent rate value into a `Quantity`
+impl<T> From<DoseEquivalentRate<T>> for Quantity<T> where T: NumLike {
+	fn from(value: DoseEquivalentRate<T>) -> Self { Quantity::DoseEquivalentRate(value) }
+}
+
+/// Converts a `Quantity` into a dose equivalent rate value, returning an error if it holds a different unit type
+impl<T> TryFrom<Quantity<T>> for DoseEquivalentRate<T> where T: NumLike {
+	type Error = DimensionError;
+	fn try_from(value: Quantity<T>) -> Result<Self, Self::Error> {
+		match value {
+			Quantity::DoseEquivalentRate(v) => Ok(v),
+			_ => Err(DimensionError{operation: "convert", left: value.unit_symbol(), right: Self::unit_symbol()})
+		}
+	}
+}
+
+/// Converts a inverse absorbed dose value into a `Quantity`
+impl<T> From<InverseAbsorbedDose<T>> for Quantity<T> where T: NumLike {
+	fn from(value: InverseAbsorbedDose<T>) -> Self { Quantity::InverseAbsorbedDose(value) }
+}
+
+/// Converts a `Quantity` into a inverse absorbed dose value, returning an error if it holds a different unit type
+impl<T> TryFrom<Quantity<T>> for InverseAbsorbedDose<T> where T: NumLike {
+	type Error = DimensionError;
+	fn try_from(value: Quantity<T>) -> Result<Self, Self::Error> {
+		match value {
+			Quantity::InverseAbsorbedDose(v) => Ok(v),
+			_ => Err(DimensionError{operation: "convert", left: value.unit_symbol(), right: Self::unit_symbol()})
+		}
+	}
+}
+
+/// Converts a inverse absorbed dose rate value into a `Quantity`
+impl<T> From<InverseAbsorbedDoseRate<T>> for Quantity<T> where T: NumLike {
+	fn from(value: InverseAbsorbedDoseRate<T>) -> Self { Quantity::InverseAbsorbedDoseRate(value) }
+}
+
+/// Converts a `Quantity` into a inverse absorbed dose rate value, returning an error if it holds a different unit type
+impl<T> TryFrom<Quantity<T>> for InverseAbsorbedDoseRate<T> where T: NumLike {
+	type Error = DimensionError;
+	fn try_from(value: Quantity<T>) -> Result<Self, Self::Error> {
+		match value {
+			Quantity::InverseAbsorbedDoseRate(v) => Ok(v),
+			_ => Err(DimensionError{operation: "convert", left: value.unit_symbol(), right: Self::unit_symbol()})
+		}
+	}
+}
+
+/// Converts a inverse dose equivalent value into a `Quantity`
+impl<T> From<InverseDoseEquivalent<T>> for Quantity<T> where T: NumLike {
+	fn from(value: InverseDoseEquivalent<T>) -> Self { Quantity::InverseDoseEquivalent(value) }
+}
+
+/// Converts a `Quantity` into a inverse dose equivalent value, returning an error if it holds a different unit type
+impl<T> TryFrom<Quantity<T>> for InverseDoseEquivalent<T> where T: NumLike {
+	type Error = DimensionError;
+	fn try_from(value: Quantity<T>) -> Result<Self, Self::Error> {
+		match value {
+			Quantity::InverseDoseEquivalent(v) => Ok(v),
+			_ => Err(DimensionError{operation: "convert", left: value.unit_symbol(), right: Self::unit_symbol()})
+		}
+	}
+}
+
+/// Converts a inverse dose equivalent rate value into a `Quantity`
+impl<T> From<InverseDoseEquivalentRate<T>> for Quantity<T> where T: NumLike {
+	fn from(value: InverseDoseEquivalentRate<T>) -> Self { Quantity::InverseDoseEquivalentRate(value) }
+}
+
+/// Converts a `Quantity` into a inverse dose equivalent rate value, returning an error if it holds a different unit type
+impl<T> TryFrom<Quantity<T>> for InverseDoseEquivalentRate<T> where T: NumLike {
+	type Error = DimensionError;
+	fn try_from(value: Quantity<T>) -> Result<Self, Self::Error> {
+		match value {
+			Quantity::InverseDoseEquivalentRate(v) => Ok(v),
+			_ => Err(DimensionError{operation: "convert", left: value.unit_symbol(), right: Self::unit_symbol()})
+		}
+	}
+}
+
+/// Converts a radioactivity value into a `Quantity`
+impl<T> From<Radioactivity<T>> for Quantity<T> where T: NumLike {
+	fn from(value: Radioactivity<T>) -> Self { Quantity::Radioactivity(value) }
+}
+
+/// Converts a `Quantity` into a radioactivity value, returning an error if it holds a different unit type
+impl<T> TryFrom<Quantity<T>> for Radioactivity<T> where T: NumLike {
+	type Error = DimensionError;
+	fn try_from(value: Quantity<T>) -> Result<Self, Self::Error> {
+		match value {
+			Quantity::Radioactivity(v) => Ok(v),
+			_ => Err(DimensionError{operation: "convert", left: value.unit_symbol(), right: Self::unit_symbol()})
+		}
+	}
+}
+
@@ -0,0 +1,135 @@
+
+//! This module provides the `ALL_UNITS` table, which lists the name, symbol, 
+//! and dimension of every unit type, for introspection (eg to generate 
+//! documentation or to fill a drop-down menu in a user interface).
+use super::UnitInfo;
+
+/// Table of every unit type in this crate that is generated from the unit 
+/// definitions (the hand-written dimensionless types, such as `Dimensionless` 
+/// and `MachNumber`, are not included), in alphabetical order of module and 
+/// then in the order that they are defined. Note that dimensionally equivalent 
+/// unit types may share a symbol (eg `Entropy` and `HeatCapacity` are both J/K)
+pub const ALL_UNITS: &[UnitInfo] = &[
+	UnitInfo{type_name: "Amount", module: "base", unit_name: "moles", symbol: "mol", dimension: super::Dimension{length: 0, mass: 0, time: 0, current: 0, temperature: 0, amount: 1, luminosity: 0}},
+	UnitInfo{type_name: "Current", module: "base", unit_name: "amperes", symbol: "A", dimension: super::Dimension{length: 0, mass: 0, time: 0, current: 1, temperature: 0, amount: 0, luminosity: 0}},
+	UnitInfo{type_name: "Distance", module: "base", unit_name: "meters", symbol: "m", dimension: super::Dimension{length: 1, mass: 0, time: 0, current: 0, temperature: 0, amount: 0, luminosity: 0}},
+	UnitInfo{type_name: "InverseAmount", module: "base", unit_name: "inverse moles", symbol: "1/mol", dimension: super::Dimension{length: 0, mass: 0, time: 0, current: 0, temperature: 0, amount: -1, luminosity: 0}},
+	UnitInfo{type_name: "InverseCurrent", module: "base", unit_name: "inverse amperes", symbol: "1/A", dimension: super::Dimension{length: 0, mass: 0, time: 0, current: -1, temperature: 0, amount: 0, luminosity: 0}},
+	UnitInfo{type_name: "InverseDistance", module: "base", unit_name: "inverse meters", symbol: "1/m", dimension: super::Dimension{length: -1, mass: 0, time: 0, current: 0, temperature: 0, amount: 0, luminosity: 0}},
+	UnitInfo{type_name: "InverseLuminosity", module: "base", unit_name: "inverse candela", symbol: "1/cd", dimension: super::Dimension{length: 0, mass: 0, time: 0, current: 0, temperature: 0, amount: 0, luminosity: -1}},
+	UnitInfo{type_name: "InverseMass", module: "base", unit_name: "inverse kilograms", symbol: "1/kg", dimension: super::Dimension{length: 0, mass: -1, time: 0, current: 0, temperature: 0, amount: 0, luminosity: 0}},
+	UnitInfo{type_name: "InverseTemperature", module: "base", unit_name: "inverse degrees kelvin", symbol: "1/K", dimension: super::Dimension{length: 0, mass: 0, time: 0, current: 0, temperature: -1, amount: 0, luminosity: 0}},
+	UnitInfo{type_name: "Luminosity", module: "base", unit_name: "candela", symbol: "cd", dimension: super::Dimension{length: 0, mass: 0, time: 0, current: 0, temperature: 0, amount: 0, luminosity: 1}},
+	UnitInfo{type_name: "Mass", module: "base", unit_name: "kilograms", symbol: "kg", dimension: super::Dimension{length: 0, mass: 1, time: 0, current: 0, temperature: 0, amount: 0, luminosity: 0}},
+	UnitInfo{type_name: "Temperature", module: "base", unit_name: "degrees kelvin", symbol: "K", dimension: super::Dimension{length: 0, mass: 0, time: 0, current: 0, temperature: 1, amount: 0, luminosity: 0}},
+	UnitInfo{type_name: "TemperatureInterval", module: "base", unit_name: "degrees kelvin", symbol: "K", dimension: super::Dimension{length: 0, mass: 0, time: 0, current: 0, temperature: 1, amount: 0, luminosity: 0}},
+	UnitInfo{type_name: "Time", module: "base", unit_name: "seconds", symbol: "s", dimension: super::Dimension{length: 0, mass: 0, time: 1, current: 0, temperature: 0, amount: 0, luminosity: 0}},
+	UnitInfo{type_name: "CatalyticActivity", module: "chemical", unit_name: "moles per second", symbol: "mol/s", dimension: super::Dimension{length: 0, mass: 0, time: -1, current: 0, temperature: 0, amount: 1, luminosity: 0}},
+	UnitInfo{type_name: "Concentration", module: "chemical", unit_name: "moles per cubic meter", symbol: "mol/m³", dimension: super::Dimension{length: -3, mass: 0, time: 0, current: 0, temperature: 0, amount: 1, luminosity: 0}},
+	UnitInfo{type_name: "Entropy", module: "chemical", unit_name: "joules per kelvin", symbol: "J/K", dimension: super::Dimension{length: 2, mass: 1, time: -2, current: 0, temperature: -1, amount: 0, luminosity: 0}},
+	UnitInfo{type_name: "HeatCapacity", module: "chemical", unit_name: "joules per kelvin", symbol: "J/K", dimension: super::Dimension{length: 2, mass: 1, time: -2, current: 0, temperature: -1, amount: 0, luminosity: 0}},
+	UnitInfo{type_name: "InverseCatalyticActivity", module: "chemical", unit_name: "seconds per mole", symbol: "s/mol", dimension: super::Dimension{length: 0, mass: 0, time: 1, current: 0, temperature: 0, amount: -1, luminosity: 0}},
+	UnitInfo{type_name: "InverseEntropy", module: "chemical", unit_name: "kelvin per joule", symbol: "K/J", dimension: super::Dimension{length: -2, mass: -1, time: 2, current: 0, temperature: 1, amount: 0, luminosity: 0}},
+	UnitInfo{type_name: "InverseHeatCapacity", module: "chemical", unit_name: "kelvin per joule", symbol: "K/J", dimension: super::Dimension{length: -2, mass: -1, time: 2, current: 0, temperature: 1, amount: 0, luminosity: 0}},
+	UnitInfo{type_name: "InverseMolarEnergy", module: "chemical", unit_name: "moles per joule", symbol: "mol/J", dimension: super::Dimension{length: -2, mass: -1, time: 2, current: 0, temperature: 0, amount: 1, luminosity: 0}},
+	UnitInfo{type_name: "InverseMolarEntropy", module: "chemical", unit_name: "mole kelvins per joule", symbol: "mol·K/J", dimension: super::Dimension{length: -2, mass: -1, time: 2, current: 0, temperature: 1, amount: 1, luminosity: 0}},
+	UnitInfo{type_name: "InverseSpecificHeatCapacity", module: "chemical", unit_name: "kilogram per kelvin per joules", symbol: "kg·K/J", dimension: super::Dimension{length: -2, mass: 0, time: 2, current: 0, temperature: 1, amount: 0, luminosity: 0}},
+	UnitInfo{type_name: "Molality", module: "chemical", unit_name: "moles per kilogram", symbol: "mol/kg", dimension: super::Dimension{length: 0, mass: -1, time: 0, current: 0, temperature: 0, amount: 1, luminosity: 0}},
+	UnitInfo{type_name: "MolarEnergy", module: "chemical", unit_name: "joules per mole", symbol: "J/mol", dimension: super::Dimension{length: 2, mass: 1, time: -2, current: 0, temperature: 0, amount: -1, luminosity: 0}},
+	UnitInfo{type_name: "MolarEntropy", module: "chemical", unit_name: "joules per mole per kelvin", symbol: "J/mol·K", dimension: super::Dimension{length: 2, mass: 1, time: -2, current: 0, temperature: -1, amount: -1, luminosity: 0}},
+	UnitInfo{type_name: "MolarMass", module: "chemical", unit_name: "kilograms per mole", symbol: "kg/mol", dimension: super::Dimension{length: 0, mass: 1, time: 0, current: 0, temperature: 0, amount: -1, luminosity: 0}},
+	UnitInfo{type_name: "MolarVolume", module: "chemical", unit_name: "cubic meters per mole", symbol: "m³/mol", dimension: super::Dimension{length: 3, mass: 0, time: 0, current: 0, temperature: 0, amount: -1, luminosity: 0}},
+	UnitInfo{type_name: "SpecificHeatCapacity", module: "chemical", unit_name: "joules per kilogram per kelvin", symbol: "J/kg·K", dimension: super::Dimension{length: 2, mass: 0, time: -2, current: 0, temperature: -1, amount: 0, luminosity: 0}},
+	UnitInfo{type_name: "ThermalConductance", module: "chemical", unit_name: "watts per kelvin", symbol: "W/K", dimension: super::Dimension{length: 2, mass: 1, time: -3, current: 0, temperature: -1, amount: 0, luminosity: 0}},
+	UnitInfo{type_name: "ThermalConductivity", module: "chemical", unit_name: "watts per meter per kelvin", symbol: "W/m·K", dimension: super::Dimension{length: 1, mass: 1, time: -3, current: 0, temperature: -1, amount: 0, luminosity: 0}},
+	UnitInfo{type_name: "ThermalResistance", module: "chemical", unit_name: "kelvin per watt", symbol: "K/W", dimension: super::Dimension{length: -2, mass: -1, time: 3, current: 0, temperature: 1, amount: 0, luminosity: 0}},
+	UnitInfo{type_name: "ThermalResistivity", module: "chemical", unit_name: "meter kelvin per watt", symbol: "m·K/W", dimension: super::Dimension{length: -1, mass: -1, time: 3, current: 0, temperature: 1, amount: 0, luminosity: 0}},
+	UnitInfo{type_name: "AreaPerLumen", module: "electromagnetic", unit_name: "square meters per lumen", symbol: "m²/lm", dimension: super::Dimension{length: 2, mass: 0, time: 0, current: 0, temperature: 0, amount: 0, luminosity: -1}},
+	UnitInfo{type_name: "Capacitance", module: "electromagnetic", unit_name: "farads", symbol: "F", dimension: super::Dimension{length: -2, mass: -1, time: 4, current: 2, temperature: 0, amount: 0, luminosity: 0}},
+	UnitInfo{type_name: "Charge", module: "electromagnetic", unit_name: "coulombs", symbol: "C", dimension: super::Dimension{length: 0, mass: 0, time: 1, current: 1, temperature: 0, amount: 0, luminosity: 0}},
+	UnitInfo{type_name: "Conductance", module: "electromagnetic", unit_name: "siemens", symbol: "S", dimension: super::Dimension{length: -2, mass: -1, time: 3, current: 2, temperature: 0, amount: 0, luminosity: 0}},
+	UnitInfo{type_name: "Elastance", module: "electromagnetic", unit_name: "inverse farads", symbol: "1/F", dimension: super::Dimension{length: 2, mass: 1, time: -4, current: -2, temperature: 0, amount: 0, luminosity: 0}},
+	UnitInfo{type_name: "ElectricField", module: "electromagnetic", unit_name: "volts per meter", symbol: "V/m", dimension: super::Dimension{length: 1, mass: 1, time: -3, current: -1, temperature: 0, amount: 0, luminosity: 0}},
+	UnitInfo{type_name: "Illuminance", module: "electromagnetic", unit_name: "lux", symbol: "lux", dimension: super::Dimension{length: -2, mass: 0, time: 0, current: 0, temperature: 0, amount: 0, luminosity: 1}},
+	UnitInfo{type_name: "Inductance", module: "electromagnetic", unit_name: "henries", symbol: "H", dimension: super::Dimension{length: 2, mass: 1, time: -2, current: -2, temperature: 0, amount: 0, luminosity: 0}},
+	UnitInfo{type_name: "InverseCharge", module: "electromagnetic", unit_name: "inverse coulombs", symbol: "1/C", dimension: super::Dimension{length: 0, mass: 0, time: -1, current: -1, temperature: 0, amount: 0, luminosity: 0}},
+	UnitInfo{type_name: "InverseElectricField", module: "electromagnetic", unit_name: "meters per volt", symbol: "m/V", dimension: super::Dimension{length: -1, mass: -1, time: 3, current: 1, temperature: 0, amount: 0, luminosity: 0}},
+	UnitInfo{type_name: "InverseInductance", module: "electromagnetic", unit_name: "inverse henries", symbol: "1/H", dimension: super::Dimension{length: -2, mass: -1, time: 2, current: 2, temperature: 0, amount: 0, luminosity: 0}},
+	UnitInfo{type_name: "InverseIrradiance", module: "electromagnetic", unit_name: "square meters per watt", symbol: "m²/W", dimension: super::Dimension{length: 0, mass: -1, time: 3, current: 0, temperature: 0, amount: 0, luminosity: 0}},
+	UnitInfo{type_name: "InverseLinearChargeDensity", module: "electromagnetic", unit_name: "meters per coulomb", symbol: "m/C", dimension: super::Dimension{length: 1, mass: 0, time: -1, current: -1, temperature: 0, amount: 0, luminosity: 0}},
+	UnitInfo{type_name: "InverseLuminousFlux", module: "electromagnetic", unit_name: "inverse lumens", symbol: "1/lm", dimension: super::Dimension{length: 0, mass: 0, time: 0, current: 0, temperature: 0, amount: 0, luminosity: -1}},
+	UnitInfo{type_name: "InverseMagneticFieldStrength", module: "electromagnetic", unit_name: "meters per ampere", symbol: "m/A", dimension: super::Dimension{length: 1, mass: 0, time: 0, current: -1, temperature: 0, amount: 0, luminosity: 0}},
+	UnitInfo{type_name: "InverseMagneticFlux", module: "electromagnetic", unit_name: "inverse webers", symbol: "1/Wb", dimension: super::Dimension{length: -2, mass: -1, time: 2, current: 1, temperature: 0, amount: 0, luminosity: 0}},
+	UnitInfo{type_name: "InverseMagneticFluxDensity", module: "electromagnetic", unit_name: "square meters per weber", symbol: "m²/Wb", dimension: super::Dimension{length: 0, mass: -1, time: 2, current: 1, temperature: 0, amount: 0, luminosity: 0}},
+	UnitInfo{type_name: "InverseRadiance", module: "electromagnetic", unit_name: "square meter steradians per watt", symbol: "m²·sr/W", dimension: super::Dimension{length: 0, mass: -1, time: 3, current: 0, temperature: 0, amount: 0, luminosity: 0}},
+	UnitInfo{type_name: "InverseSurfaceChargeDensity", module: "electromagnetic", unit_name: "square meters per coulomb", symbol: "m²/C", dimension: super::Dimension{length: 2, mass: 0, time: -1, current: -1, temperature: 0, amount: 0, luminosity: 0}},
+	UnitInfo{type_name: "InverseVoltage", module: "electromagnetic", unit_name: "inverse volts", symbol: "1/V", dimension: super::Dimension{length: -2, mass: -1, time: 3, current: 1, temperature: 0, amount: 0, luminosity: 0}},
+	UnitInfo{type_name: "InverseVolumeChargeDensity", module: "electromagnetic", unit_name: "cubic meters per coulomb", symbol: "m³/C", dimension: super::Dimension{length: 3, mass: 0, time: -1, current: -1, temperature: 0, amount: 0, luminosity: 0}},
+	UnitInfo{type_name: "Irradiance", module: "electromagnetic", unit_name: "watts per square meter", symbol: "W/m²", dimension: super::Dimension{length: 0, mass: 1, time: -3, current: 0, temperature: 0, amount: 0, luminosity: 0}},
+	UnitInfo{type_name: "LinearChargeDensity", module: "electromagnetic", unit_name: "coulombs per meter", symbol: "C/m", dimension: super::Dimension{length: -1, mass: 0, time: 1, current: 1, temperature: 0, amount: 0, luminosity: 0}},
+	UnitInfo{type_name: "LuminousFlux", module: "electromagnetic", unit_name: "lumens", symbol: "lm", dimension: super::Dimension{length: 0, mass: 0, time: 0, current: 0, temperature: 0, amount: 0, luminosity: 1}},
+	UnitInfo{type_name: "MagneticFieldStrength", module: "electromagnetic", unit_name: "amperes per meter", symbol: "A/m", dimension: super::Dimension{length: -1, mass: 0, time: 0, current: 1, temperature: 0, amount: 0, luminosity: 0}},
+	UnitInfo{type_name: "MagneticFlux", module: "electromagnetic", unit_name: "webers", symbol: "Wb", dimension: super::Dimension{length: 2, mass: 1, time: -2, current: -1, temperature: 0, amount: 0, luminosity: 0}},
+	UnitInfo{type_name: "MagneticFluxDensity", module: "electromagnetic", unit_name: "teslas", symbol: "T", dimension: super::Dimension{length: 0, mass: 1, time: -2, current: -1, temperature: 0, amount: 0, luminosity: 0}},
+	UnitInfo{type_name: "Radiance", module: "electromagnetic", unit_name: "watts per square meter per steradian", symbol: "W/m²·sr", dimension: super::Dimension{length: 0, mass: 1, time: -3, current: 0, temperature: 0, amount: 0, luminosity: 0}},
+	UnitInfo{type_name: "Resistance", module: "electromagnetic", unit_name: "ohms", symbol: "Ohm", dimension: super::Dimension{length: 2, mass: 1, time: -3, current: -2, temperature: 0, amount: 0, luminosity: 0}},
+	UnitInfo{type_name: "SurfaceChargeDensity", module: "electromagnetic", unit_name: "coulombs per square meter", symbol: "C/m²", dimension: super::Dimension{length: -2, mass: 0, time: 1, current: 1, temperature: 0, amount: 0, luminosity: 0}},
+	UnitInfo{type_name: "Voltage", module: "electromagnetic", unit_name: "volts", symbol: "V", dimension: super::Dimension{length: 2, mass: 1, time: -3, current: -1, temperature: 0, amount: 0, luminosity: 0}},
+	UnitInfo{type_name: "VolumeChargeDensity", module: "electromagnetic", unit_name: "coulombs per cubic meter", symbol: "C/m³", dimension: super::Dimension{length: -3, mass: 0, time: 1, current: 1, temperature: 0, amount: 0, luminosity: 0}},
+	UnitInfo{type_name: "Angle", module: "geometry", unit_name: "radians", symbol: "rad", dimension: super::Dimension{length: 0, mass: 0, time: 0, current: 0, temperature: 0, amount: 0, luminosity: 0}},
+	UnitInfo{type_name: "Area", module: "geometry", unit_name: "square meters", symbol: "m²", dimension: super::Dimension{length: 2, mass: 0, time: 0, current: 0, temperature: 0, amount: 0, luminosity: 0}},
+	UnitInfo{type_name: "InverseAngle", module: "geometry", unit_name: "inverse radians", symbol: "1/rad", dimension: super::Dimension{length: 0, mass: 0, time: 0, current: 0, temperature: 0, amount: 0, luminosity: 0}},
+	UnitInfo{type_name: "InverseArea", module: "geometry", unit_name: "inverse square meters", symbol: "1/m²", dimension: super::Dimension{length: -2, mass: 0, time: 0, current: 0, temperature: 0, amount: 0, luminosity: 0}},
+	UnitInfo{type_name: "InverseSolidAngle", module: "geometry", unit_name: "inverse steradian", symbol: "1/sr", dimension: super::Dimension{length: 0, mass: 0, time: 0, current: 0, temperature: 0, amount: 0, luminosity: 0}},
+	UnitInfo{type_name: "InverseVolume", module: "geometry", unit_name: "inverse cubic meters", symbol: "1/m³", dimension: super::Dimension{length: -3, mass: 0, time: 0, current: 0, temperature: 0, amount: 0, luminosity: 0}},
+	UnitInfo{type_name: "SolidAngle", module: "geometry", unit_name: "steradian", symbol: "sr", dimension: super::Dimension{length: 0, mass: 0, time: 0, current: 0, temperature: 0, amount: 0, luminosity: 0}},
+	UnitInfo{type_name: "Volume", module: "geometry", unit_name: "cubic meters", symbol: "m³", dimension: super::Dimension{length: 3, mass: 0, time: 0, current: 0, temperature: 0, amount: 0, luminosity: 0}},
+	UnitInfo{type_name: "Acceleration", module: "mechanical", unit_name: "meters per second squared", symbol: "m/s²", dimension: super::Dimension{length: 1, mass: 0, time: -2, current: 0, temperature: 0, amount: 0, luminosity: 0}},
+	UnitInfo{type_name: "AngularAcceleration", module: "mechanical", unit_name: "radians per second squared", symbol: "rad/s²", dimension: super::Dimension{length: 0, mass: 0, time: -2, current: 0, temperature: 0, amount: 0, luminosity: 0}},
+	UnitInfo{type_name: "AngularMomentum", module: "mechanical", unit_name: "kilogram meters squared radians per second", symbol: "kg·m²·rad/s", dimension: super::Dimension{length: 2, mass: 1, time: -1, current: 0, temperature: 0, amount: 0, luminosity: 0}},
+	UnitInfo{type_name: "AngularVelocity", module: "mechanical", unit_name: "radians per second", symbol: "rad/s", dimension: super::Dimension{length: 0, mass: 0, time: -1, current: 0, temperature: 0, amount: 0, luminosity: 0}},
+	UnitInfo{type_name: "AreaDensity", module: "mechanical", unit_name: "kilograms per square meter", symbol: "kg/m²", dimension: super::Dimension{length: -2, mass: 1, time: 0, current: 0, temperature: 0, amount: 0, luminosity: 0}},
+	UnitInfo{type_name: "AreaPerMass", module: "mechanical", unit_name: "square meters per kilogram", symbol: "m²/kg", dimension: super::Dimension{length: 2, mass: -1, time: 0, current: 0, temperature: 0, amount: 0, luminosity: 0}},
+	UnitInfo{type_name: "Density", module: "mechanical", unit_name: "kilograms per cubic meter", symbol: "kg/m³", dimension: super::Dimension{length: -3, mass: 1, time: 0, current: 0, temperature: 0, amount: 0, luminosity: 0}},
+	UnitInfo{type_name: "DynamicViscosity", module: "mechanical", unit_name: "pascal seconds", symbol: "Pa·s", dimension: super::Dimension{length: -1, mass: 1, time: -1, current: 0, temperature: 0, amount: 0, luminosity: 0}},
+	UnitInfo{type_name: "Energy", module: "mechanical", unit_name: "joules", symbol: "J", dimension: super::Dimension{length: 2, mass: 1, time: -2, current: 0, temperature: 0, amount: 0, luminosity: 0}},
+	UnitInfo{type_name: "Force", module: "mechanical", unit_name: "newtons", symbol: "N", dimension: super::Dimension{length: 1, mass: 1, time: -2, current: 0, temperature: 0, amount: 0, luminosity: 0}},
+	UnitInfo{type_name: "Frequency", module: "mechanical", unit_name: "hertz", symbol: "Hz", dimension: super::Dimension{length: 0, mass: 0, time: -1, current: 0, temperature: 0, amount: 0, luminosity: 0}},
+	UnitInfo{type_name: "InverseAcceleration", module: "mechanical", unit_name: "seconds squared per meter", symbol: "s²/m", dimension: super::Dimension{length: -1, mass: 0, time: 2, current: 0, temperature: 0, amount: 0, luminosity: 0}},
+	UnitInfo{type_name: "InverseAngularAcceleration", module: "mechanical", unit_name: "seconds squared per radian", symbol: "s²/rad", dimension: super::Dimension{length: 0, mass: 0, time: 2, current: 0, temperature: 0, amount: 0, luminosity: 0}},
+	UnitInfo{type_name: "InverseAngularMomentum", module: "mechanical", unit_name: "seconds per kilogram meters squared radian", symbol: "s/kg·m²·rad", dimension: super::Dimension{length: -2, mass: -1, time: 1, current: 0, temperature: 0, amount: 0, luminosity: 0}},
+	UnitInfo{type_name: "InverseAngularVelocity", module: "mechanical", unit_name: "seconds per radian", symbol: "s/rad", dimension: super::Dimension{length: 0, mass: 0, time: 1, current: 0, temperature: 0, amount: 0, luminosity: 0}},
+	UnitInfo{type_name: "InverseDynamicViscosity", module: "mechanical", unit_name: "inverse pascal seconds", symbol: "1/Pa·s", dimension: super::Dimension{length: 1, mass: -1, time: 1, current: 0, temperature: 0, amount: 0, luminosity: 0}},
+	UnitInfo{type_name: "InverseEnergy", module: "mechanical", unit_name: "inverse joules", symbol: "1/J", dimension: super::Dimension{length: -2, mass: -1, time: 2, current: 0, temperature: 0, amount: 0, luminosity: 0}},
+	UnitInfo{type_name: "InverseForce", module: "mechanical", unit_name: "inverse newtons", symbol: "1/N", dimension: super::Dimension{length: -1, mass: -1, time: 2, current: 0, temperature: 0, amount: 0, luminosity: 0}},
+	UnitInfo{type_name: "InverseJerk", module: "mechanical", unit_name: "seconds cubed per meter", symbol: "s³/m", dimension: super::Dimension{length: -1, mass: 0, time: 3, current: 0, temperature: 0, amount: 0, luminosity: 0}},
+	UnitInfo{type_name: "InverseKinematicViscosity", module: "mechanical", unit_name: "seconds per square meter", symbol: "s/m²", dimension: super::Dimension{length: -2, mass: 0, time: 1, current: 0, temperature: 0, amount: 0, luminosity: 0}},
+	UnitInfo{type_name: "InverseMassFlowRate", module: "mechanical", unit_name: "seconds per kilogram", symbol: "s/kg", dimension: super::Dimension{length: 0, mass: -1, time: 1, current: 0, temperature: 0, amount: 0, luminosity: 0}},
+	UnitInfo{type_name: "InverseMomentOfInertia", module: "mechanical", unit_name: "inverse kilogram meters squared", symbol: "1/kg·m²", dimension: super::Dimension{length: -2, mass: -1, time: 0, current: 0, temperature: 0, amount: 0, luminosity: 0}},
+	UnitInfo{type_name: "InverseMomentum", module: "mechanical", unit_name: "seconds per kilogram meter", symbol: "s/kg·m", dimension: super::Dimension{length: -1, mass: -1, time: 1, current: 0, temperature: 0, amount: 0, luminosity: 0}},
+	UnitInfo{type_name: "InversePower", module: "mechanical", unit_name: "inverse watts", symbol: "1/W", dimension: super::Dimension{length: -2, mass: -1, time: 3, current: 0, temperature: 0, amount: 0, luminosity: 0}},
+	UnitInfo{type_name: "InversePressure", module: "mechanical", unit_name: "inverse pascals", symbol: "1/Pa", dimension: super::Dimension{length: 1, mass: -1, time: 2, current: 0, temperature: 0, amount: 0, luminosity: 0}},
+	UnitInfo{type_name: "InverseTorque", module: "mechanical", unit_name: "inverse newton meters", symbol: "1/Nm", dimension: super::Dimension{length: -2, mass: -1, time: 2, current: 0, temperature: 0, amount: 0, luminosity: 0}},
+	UnitInfo{type_name: "InverseVolumetricFlowRate", module: "mechanical", unit_name: "seconds per cubic meter", symbol: "s/m³", dimension: super::Dimension{length: -3, mass: 0, time: 1, current: 0, temperature: 0, amount: 0, luminosity: 0}},
+	UnitInfo{type_name: "Jerk", module: "mechanical", unit_name: "meters per second cubed", symbol: "m/s³", dimension: super::Dimension{length: 1, mass: 0, time: -3, current: 0, temperature: 0, amount: 0, luminosity: 0}},
+	UnitInfo{type_name: "KinematicViscosity", module: "mechanical", unit_name: "square meters per second", symbol: "m²/s", dimension: super::Dimension{length: 2, mass: 0, time: -1, current: 0, temperature: 0, amount: 0, luminosity: 0}},
+	UnitInfo{type_name: "MassFlowRate", module: "mechanical", unit_name: "kilograms per second", symbol: "kg/s", dimension: super::Dimension{length: 0, mass: 1, time: -1, current: 0, temperature: 0, amount: 0, luminosity: 0}},
+	UnitInfo{type_name: "MomentOfInertia", module: "mechanical", unit_name: "kilogram meters squared", symbol: "kg·m²", dimension: super::Dimension{length: 2, mass: 1, time: 0, current: 0, temperature: 0, amount: 0, luminosity: 0}},
+	UnitInfo{type_name: "Momentum", module: "mechanical", unit_name: "kilogram meters per second", symbol: "kg·m/s", dimension: super::Dimension{length: 1, mass: 1, time: -1, current: 0, temperature: 0, amount: 0, luminosity: 0}},
+	UnitInfo{type_name: "Power", module: "mechanical", unit_name: "watts", symbol: "W", dimension: super::Dimension{length: 2, mass: 1, time: -3, current: 0, temperature: 0, amount: 0, luminosity: 0}},
+	UnitInfo{type_name: "Pressure", module: "mechanical", unit_name: "pascals", symbol: "Pa", dimension: super::Dimension{length: -1, mass: 1, time: -2, current: 0, temperature: 0, amount: 0, luminosity: 0}},
+	UnitInfo{type_name: "TimePerDistance", module: "mechanical", unit_name: "seconds per meter", symbol: "s/m", dimension: super::Dimension{length: -1, mass: 0, time: 1, current: 0, temperature: 0, amount: 0, luminosity: 0}},
+	UnitInfo{type_name: "Torque", module: "mechanical", unit_name: "newton meters", symbol: "Nm", dimension: super::Dimension{length: 2, mass: 1, time: -2, current: 0, temperature: 0, amount: 0, luminosity: 0}},
+	UnitInfo{type_name: "Velocity", module: "mechanical", unit_name: "meters per second", symbol: "m/s", dimension: super::Dimension{length: 1, mass: 0, time: -1, current: 0, temperature: 0, amount: 0, luminosity: 0}},
+	UnitInfo{type_name: "VolumePerMass", module: "mechanical", unit_name: "cubic meters per kilogram", symbol: "m³/kg", dimension: super::Dimension{length: 3, mass: -1, time: 0, current: 0, temperature: 0, amount: 0, luminosity: 0}},
+	UnitInfo{type_name: "VolumetricFlowRate", module: "mechanical", unit_name: "cubic meters per second", symbol: "m³/s", dimension: super::Dimension{length: 3, mass: 0, time: -1, current: 0, temperature: 0, amount: 0, luminosity: 0}},
+	UnitInfo{type_name: "AbsorbedDose", module: "nuclear", unit_name: "grays", symbol: "Gy", dimension: super::Dimension{length: 2, mass: 0, time: -2, current: 0, temperature: 0, amount: 0, luminosity: 0}},
+	UnitInfo{type_name: "AbsorbedDoseRate", module: "nuclear", unit_name: "grays per second", symbol: "Gy/s", dimension: super::Dimension{length: 2, mass: 0, time: -3, current: 0, temperature: 0, amount: 0, luminosity: 0}},
+	UnitInfo{type_name: "DoseEquivalent", module: "nuclear", unit_name: "sieverts", symbol: "Sv", dimension: super::Dimension{length: 2, mass: 0, time: -2, current: 0, temperature: 0, amount: 0, luminosity: 0}},
+	UnitInfo{type_name: "DoseEquivalentRate", module: "nuclear", unit_name: "sieverts per second", symbol: "Sv/s", dimension: super::Dimension{length: 2, mass: 0, time: -3, current: 0, temperature: 0, amount: 0, luminosity: 0}},
+	UnitInfo{type_name: "InverseAbsorbedDose", module: "nuclear", unit_name: "inverse grays", symbol: "1/Gy", dimension: super::Dimension{length: -2, mass: 0, time: 2, current: 0, temperature: 0, amount: 0, luminosity: 0}},
+	UnitInfo{type_name: "InverseAbsorbedDoseRate", module: "nuclear", unit_name: "seconds per gray", symbol: "s/Gy", dimension: super::Dimension{length: -2, mass: 0, time: 3, current: 0, temperature: 0, amount: 0, luminosity: 0}},
+	UnitInfo{type_name: "InverseDoseEquivalent", module: "nuclear", unit_name: "inverse sieverts", symbol: "1/Sv", dimension: super::Dimension{length: -2, mass: 0, time: 2, current: 0, temperature: 0, amount: 0, luminosity: 0}},
+	UnitInfo{type_name: "InverseDoseEquivalentRate", module: "nuclear", unit_name: "seconds per sievert", symbol: "s/Sv", dimension: super::Dimension{length: -2, mass: 0, time: 3, current: 0, temperature: 0, amount: 0, luminosity: 0}},
+	UnitInfo{type_name: "Radioactivity", module: "nuclear", unit_name: "becquerels", symbol: "Bq", dimension: super::Dimension{length: 0, mass: 0, time: -1, current: 0, temperature: 0, amount: 0, luminosity: 0}},
+];
@@ -0,0 +1,17 @@
+// run with: cargo test --no-default-features --features libm --test libm_tests
+#![cfg(feature="libm")]
+use simple_si_units::base::Distance;
+use simple_si_units::geometry::{Angle, Area, Volume};
+
+#[test]
+fn libm_area_sqrt_test() {
+	assert_eq!(Area::from_m2(16.0).sqrt(), Distance::from_m(4.0));
+	assert_eq!(Area::from_m2(2.25_f32).sqrt(), Distance::from_m(1.5_f32));
+	assert!((Volume::from_m3(27.0_f64).cbrt().to_m() - 3.0).abs() < 1e-12);
+}
+
+#[test]
+fn libm_trig_test() {
+	assert!((Angle::from_degrees(30.0_f64).sin() - 0.5).abs() < 1e-12);
+	assert!((Distance::hypot(Distance::from_m(3.0_f64), Distance::from_m(4.0)).to_m() - 5.0).abs() < 1e-12);
+}
@@ -0,0 +1,26 @@
+use simple_si_units::prelude::*;
+
+// ===== everything should be importable from the prelude ===== //
+#[derive(UnitStruct, Debug, Clone)]
+struct HyperVelocity<T: NumLike>{
+	m2ps: T
+}
+
+fn kinetic_energy<T: NumLike+From<f64>>(mass: Mass<T>, speed: Velocity<T>) -> Energy<T> {
+	return (mass * speed.clone() * speed) * T::from(0.5);
+}
+
+#[test]
+fn prelude_test() {
+	let d = Distance::from_m(100.0);
+	let t = Time::from_s(20.0);
+	let v: Velocity<f64> = d / t;
+	assert_eq!(v, Velocity::from_mps(5.0));
+	assert_eq!(kinetic_energy(Mass::from_kg(2.0), v), Energy::from_J(25.0));
+	let _: (Amount<f64>, Charge<f64>, Area<f64>, Pressure<f64>, AbsorbedDose<f64>) = (
+		Amount::from_mol(1.0), Charge::from_C(1.0), Area::from_m2(1.0),
+		Pressure::from_Pa(1.0), AbsorbedDose::from_Gy(1.0)
+	);
+	let j = HyperVelocity{m2ps: 1.5} + HyperVelocity{m2ps: 1.5};
+	assert_eq!(j.m2ps, 3.0);
+}
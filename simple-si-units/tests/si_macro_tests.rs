@@ -0,0 +1,32 @@
+use simple_si_units::si;
+use simple_si_units::prelude::*;
+
+#[test]
+fn si_macro_test() {
+	assert_eq!(si!(5.0 m), Distance::from_m(5.0));
+	assert_eq!(si!(-5.0 m), Distance::from_m(-5.0));
+	assert_eq!(si!(9.81 m/s^2), Acceleration::from_mps2(9.81));
+	assert_eq!(si!(3.3 V), Voltage::from_V(3.3));
+	assert_eq!(si!(2.0 kg), Mass::from_kg(2.0));
+	assert_eq!(si!(2.0 kg.m^2), MomentOfInertia::from_kgm2(2.0));
+	assert_eq!(si!(1.0 mol/m^3), Concentration::from_molpm3(1.0));
+	assert_eq!(si!(4.0 1/m), InverseDistance::from_per_m(4.0));
+	assert_eq!(si!(300.0 K), Temperature::from_K(300.0));
+	assert_eq!(si!(7_i32 s), Time{s: 7_i32});
+	// other units by the name of their from_... function
+	assert_eq!(si!(1.5 km), Distance::from_m(1500.0));
+	assert_eq!(si!(9.81 mps2), Acceleration::from_mps2(9.81));
+	assert_eq!(si!(250.0 mV), Voltage::from_V(0.25));
+	// expressions in parentheses
+	let x = 2.5;
+	assert_eq!(si!((x * 2.0) m/s), Velocity::from_mps(5.0));
+}
+
+#[test]
+fn si_macro_compile_test() {
+	let t = trybuild::TestCases::new();
+	t.pass("tests/ui/si_macro_pass.rs");
+	t.compile_fail("tests/ui/si_macro_unknown_unit.rs");
+	t.compile_fail("tests/ui/si_macro_ambiguous_unit.rs");
+	t.compile_fail("tests/ui/assert_unit_eq_mismatched_units.rs");
+}
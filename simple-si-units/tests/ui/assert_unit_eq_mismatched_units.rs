@@ -0,0 +1,7 @@
+use simple_si_units::assert_unit_eq;
+use simple_si_units::base::{Distance, Time};
+
+fn main() {
+	// comparing values of different unit types is a compile error
+	assert_unit_eq!(Distance::from_m(1.0), Time::from_s(1.0), Distance::from_mm(1.0));
+}
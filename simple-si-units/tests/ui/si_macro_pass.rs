@@ -0,0 +1,9 @@
+use simple_si_units::si;
+use simple_si_units::prelude::*;
+
+fn main() {
+	let _: Distance<f64> = si!(5.0 m);
+	let _: Velocity<f64> = si!(3.0 m/s);
+	let _: Force<f64> = si!(1.0 N);
+	let _: Pressure<f64> = si!(101.325 kPa);
+}
@@ -0,0 +1,5 @@
+use simple_si_units::si;
+
+fn main() {
+	let _ = si!(5.0 furlongs);
+}
@@ -133,6 +133,42 @@ mod uom_integration {
 				   uom::si::f64::SpecificArea::new::<uom::si::specific_area::square_meter_per_kilogram>(x).value);
 		assert_eq!(simple_si_units::mechanical::VolumePerMass{m3_per_kg: x}.m3_per_kg,
 				   uom::si::f64::SpecificVolume::new::<uom::si::specific_volume::cubic_meter_per_kilogram>(x).value);
+		assert_eq!(simple_si_units::base::TemperatureInterval{K: x}.K,
+			uom::si::f64::TemperatureInterval::new::<uom::si::temperature_interval::kelvin>(x).value);
+		assert_eq!(simple_si_units::mechanical::Jerk{mps3: x}.mps3,
+			uom::si::f64::Jerk::new::<uom::si::jerk::meter_per_second_cubed>(x).value);
+		assert_eq!(simple_si_units::mechanical::DynamicViscosity{Pas: x}.Pas,
+			uom::si::f64::DynamicViscosity::new::<uom::si::dynamic_viscosity::pascal_second>(x).value);
+		assert_eq!(simple_si_units::mechanical::MassFlowRate{kgps: x}.kgps,
+			uom::si::f64::MassRate::new::<uom::si::mass_rate::kilogram_per_second>(x).value);
+		assert_eq!(simple_si_units::mechanical::VolumetricFlowRate{m3ps: x}.m3ps,
+			uom::si::f64::VolumeRate::new::<uom::si::volume_rate::cubic_meter_per_second>(x).value);
+		assert_eq!(simple_si_units::chemical::HeatCapacity{J_per_K: x}.J_per_K,
+			uom::si::f64::HeatCapacity::new::<uom::si::heat_capacity::joule_per_kelvin>(x).value);
+		assert_eq!(simple_si_units::chemical::ThermalConductivity{W_per_mK: x}.W_per_mK,
+			uom::si::f64::ThermalConductivity::new::<uom::si::thermal_conductivity::watt_per_meter_kelvin>(x).value);
+		assert_eq!(simple_si_units::electromagnetic::ElectricField{Vpm: x}.Vpm,
+			uom::si::f64::ElectricField::new::<uom::si::electric_field::volt_per_meter>(x).value);
+		assert_eq!(simple_si_units::electromagnetic::MagneticFieldStrength{Apm: x}.Apm,
+			uom::si::f64::MagneticFieldStrength::new::<uom::si::magnetic_field_strength::ampere_per_meter>(x).value);
+		assert_eq!(simple_si_units::electromagnetic::LinearChargeDensity{Cpm: x}.Cpm,
+			uom::si::f64::ElectricChargeLinearDensity::new::<uom::si::electric_charge_linear_density::coulomb_per_meter>(x).value);
+		assert_eq!(simple_si_units::electromagnetic::SurfaceChargeDensity{Cpm2: x}.Cpm2,
+			uom::si::f64::ElectricChargeArealDensity::new::<uom::si::electric_charge_areal_density::coulomb_per_square_meter>(x).value);
+		assert_eq!(simple_si_units::electromagnetic::VolumeChargeDensity{Cpm3: x}.Cpm3,
+			uom::si::f64::ElectricChargeVolumetricDensity::new::<uom::si::electric_charge_volumetric_density::coulomb_per_cubic_meter>(x).value);
+		assert_eq!(simple_si_units::chemical::Entropy{J_per_K: x}.J_per_K,
+			uom::si::f64::HeatCapacity::new::<uom::si::heat_capacity::joule_per_kelvin>(x).value);
+		assert_eq!(simple_si_units::chemical::ThermalConductance{W_per_K: x}.W_per_K,
+			uom::si::f64::ThermalConductance::new::<uom::si::thermal_conductance::watt_per_kelvin>(x).value);
+		assert_eq!(simple_si_units::electromagnetic::Irradiance{W_per_m2: x}.W_per_m2,
+			uom::si::f64::HeatFluxDensity::new::<uom::si::heat_flux_density::watt_per_square_meter>(x).value);
+		assert_eq!(simple_si_units::chemical::MolarEnergy{J_per_mol: x}.J_per_mol,
+			uom::si::f64::MolarEnergy::new::<uom::si::molar_energy::joule_per_mole>(x).value);
+		assert_eq!(simple_si_units::chemical::MolarEntropy{J_per_molK: x}.J_per_molK,
+			uom::si::f64::MolarHeatCapacity::new::<uom::si::molar_heat_capacity::joule_per_kelvin_mole>(x).value);
+		assert_eq!(simple_si_units::mechanical::KinematicViscosity{m2ps: x}.m2ps,
+			uom::si::f64::DiffusionCoefficient::new::<uom::si::diffusion_coefficient::square_meter_per_second>(x).value);
 	}
 
 	#[test]
@@ -232,6 +268,42 @@ mod uom_integration {
 			== simple_si_units::mechanical::AreaPerMass{m2_per_kg: x}.into());
 		assert!(uom::si::f64::SpecificVolume::new::<uom::si::specific_volume::cubic_meter_per_kilogram>(x)
 			== simple_si_units::mechanical::VolumePerMass{m3_per_kg: x}.into());
+		assert!(uom::si::f64::TemperatureInterval::new::<uom::si::temperature_interval::kelvin>(x)
+			== simple_si_units::base::TemperatureInterval{K: x}.into());
+		assert!(uom::si::f64::Jerk::new::<uom::si::jerk::meter_per_second_cubed>(x)
+			== simple_si_units::mechanical::Jerk{mps3: x}.into());
+		assert!(uom::si::f64::DynamicViscosity::new::<uom::si::dynamic_viscosity::pascal_second>(x)
+			== simple_si_units::mechanical::DynamicViscosity{Pas: x}.into());
+		assert!(uom::si::f64::MassRate::new::<uom::si::mass_rate::kilogram_per_second>(x)
+			== simple_si_units::mechanical::MassFlowRate{kgps: x}.into());
+		assert!(uom::si::f64::VolumeRate::new::<uom::si::volume_rate::cubic_meter_per_second>(x)
+			== simple_si_units::mechanical::VolumetricFlowRate{m3ps: x}.into());
+		assert!(uom::si::f64::HeatCapacity::new::<uom::si::heat_capacity::joule_per_kelvin>(x)
+			== simple_si_units::chemical::HeatCapacity{J_per_K: x}.into());
+		assert!(uom::si::f64::ThermalConductivity::new::<uom::si::thermal_conductivity::watt_per_meter_kelvin>(x)
+			== simple_si_units::chemical::ThermalConductivity{W_per_mK: x}.into());
+		assert!(uom::si::f64::ElectricField::new::<uom::si::electric_field::volt_per_meter>(x)
+			== simple_si_units::electromagnetic::ElectricField{Vpm: x}.into());
+		assert!(uom::si::f64::MagneticFieldStrength::new::<uom::si::magnetic_field_strength::ampere_per_meter>(x)
+			== simple_si_units::electromagnetic::MagneticFieldStrength{Apm: x}.into());
+		assert!(uom::si::f64::ElectricChargeLinearDensity::new::<uom::si::electric_charge_linear_density::coulomb_per_meter>(x)
+			== simple_si_units::electromagnetic::LinearChargeDensity{Cpm: x}.into());
+		assert!(uom::si::f64::ElectricChargeArealDensity::new::<uom::si::electric_charge_areal_density::coulomb_per_square_meter>(x)
+			== simple_si_units::electromagnetic::SurfaceChargeDensity{Cpm2: x}.into());
+		assert!(uom::si::f64::ElectricChargeVolumetricDensity::new::<uom::si::electric_charge_volumetric_density::coulomb_per_cubic_meter>(x)
+			== simple_si_units::electromagnetic::VolumeChargeDensity{Cpm3: x}.into());
+		assert!(uom::si::f64::HeatCapacity::new::<uom::si::heat_capacity::joule_per_kelvin>(x)
+			== simple_si_units::chemical::Entropy{J_per_K: x}.into());
+		assert!(uom::si::f64::ThermalConductance::new::<uom::si::thermal_conductance::watt_per_kelvin>(x)
+			== simple_si_units::chemical::ThermalConductance{W_per_K: x}.into());
+		assert!(uom::si::f64::HeatFluxDensity::new::<uom::si::heat_flux_density::watt_per_square_meter>(x)
+			== simple_si_units::electromagnetic::Irradiance{W_per_m2: x}.into());
+		assert!(uom::si::f64::MolarEnergy::new::<uom::si::molar_energy::joule_per_mole>(x)
+			== simple_si_units::chemical::MolarEnergy{J_per_mol: x}.into());
+		assert!(uom::si::f64::MolarHeatCapacity::new::<uom::si::molar_heat_capacity::joule_per_kelvin_mole>(x)
+			== simple_si_units::chemical::MolarEntropy{J_per_molK: x}.into());
+		assert!(uom::si::f64::DiffusionCoefficient::new::<uom::si::diffusion_coefficient::square_meter_per_second>(x)
+			== simple_si_units::mechanical::KinematicViscosity{m2ps: x}.into());
 	}
 
 	#[test]
@@ -331,6 +403,42 @@ mod uom_integration {
 			== simple_si_units::mechanical::AreaPerMass{m2_per_kg: x}.into());
 		assert!(uom::si::f32::SpecificVolume::new::<uom::si::specific_volume::cubic_meter_per_kilogram>(x)
 			== simple_si_units::mechanical::VolumePerMass{m3_per_kg: x}.into());
+		assert!(uom::si::f32::TemperatureInterval::new::<uom::si::temperature_interval::kelvin>(x)
+			== simple_si_units::base::TemperatureInterval{K: x}.into());
+		assert!(uom::si::f32::Jerk::new::<uom::si::jerk::meter_per_second_cubed>(x)
+			== simple_si_units::mechanical::Jerk{mps3: x}.into());
+		assert!(uom::si::f32::DynamicViscosity::new::<uom::si::dynamic_viscosity::pascal_second>(x)
+			== simple_si_units::mechanical::DynamicViscosity{Pas: x}.into());
+		assert!(uom::si::f32::MassRate::new::<uom::si::mass_rate::kilogram_per_second>(x)
+			== simple_si_units::mechanical::MassFlowRate{kgps: x}.into());
+		assert!(uom::si::f32::VolumeRate::new::<uom::si::volume_rate::cubic_meter_per_second>(x)
+			== simple_si_units::mechanical::VolumetricFlowRate{m3ps: x}.into());
+		assert!(uom::si::f32::HeatCapacity::new::<uom::si::heat_capacity::joule_per_kelvin>(x)
+			== simple_si_units::chemical::HeatCapacity{J_per_K: x}.into());
+		assert!(uom::si::f32::ThermalConductivity::new::<uom::si::thermal_conductivity::watt_per_meter_kelvin>(x)
+			== simple_si_units::chemical::ThermalConductivity{W_per_mK: x}.into());
+		assert!(uom::si::f32::ElectricField::new::<uom::si::electric_field::volt_per_meter>(x)
+			== simple_si_units::electromagnetic::ElectricField{Vpm: x}.into());
+		assert!(uom::si::f32::MagneticFieldStrength::new::<uom::si::magnetic_field_strength::ampere_per_meter>(x)
+			== simple_si_units::electromagnetic::MagneticFieldStrength{Apm: x}.into());
+		assert!(uom::si::f32::ElectricChargeLinearDensity::new::<uom::si::electric_charge_linear_density::coulomb_per_meter>(x)
+			== simple_si_units::electromagnetic::LinearChargeDensity{Cpm: x}.into());
+		assert!(uom::si::f32::ElectricChargeArealDensity::new::<uom::si::electric_charge_areal_density::coulomb_per_square_meter>(x)
+			== simple_si_units::electromagnetic::SurfaceChargeDensity{Cpm2: x}.into());
+		assert!(uom::si::f32::ElectricChargeVolumetricDensity::new::<uom::si::electric_charge_volumetric_density::coulomb_per_cubic_meter>(x)
+			== simple_si_units::electromagnetic::VolumeChargeDensity{Cpm3: x}.into());
+		assert!(uom::si::f32::HeatCapacity::new::<uom::si::heat_capacity::joule_per_kelvin>(x)
+			== simple_si_units::chemical::Entropy{J_per_K: x}.into());
+		assert!(uom::si::f32::ThermalConductance::new::<uom::si::thermal_conductance::watt_per_kelvin>(x)
+			== simple_si_units::chemical::ThermalConductance{W_per_K: x}.into());
+		assert!(uom::si::f32::HeatFluxDensity::new::<uom::si::heat_flux_density::watt_per_square_meter>(x)
+			== simple_si_units::electromagnetic::Irradiance{W_per_m2: x}.into());
+		assert!(uom::si::f32::MolarEnergy::new::<uom::si::molar_energy::joule_per_mole>(x)
+			== simple_si_units::chemical::MolarEnergy{J_per_mol: x}.into());
+		assert!(uom::si::f32::MolarHeatCapacity::new::<uom::si::molar_heat_capacity::joule_per_kelvin_mole>(x)
+			== simple_si_units::chemical::MolarEntropy{J_per_molK: x}.into());
+		assert!(uom::si::f32::DiffusionCoefficient::new::<uom::si::diffusion_coefficient::square_meter_per_second>(x)
+			== simple_si_units::mechanical::KinematicViscosity{m2ps: x}.into());
 	}
 	#[test]
 	fn from_uom_test_f64(){
@@ -476,6 +584,60 @@ mod uom_integration {
 		assert!(simple_si_units::mechanical::VolumePerMass::from(
 			uom::si::f64::SpecificVolume::new::<uom::si::specific_volume::cubic_meter_per_kilogram>(x)
 			) == simple_si_units::mechanical::VolumePerMass{m3_per_kg: x});
+		assert!(simple_si_units::base::TemperatureInterval::from(
+				uom::si::f64::TemperatureInterval::new::<uom::si::temperature_interval::kelvin>(x)
+			) == simple_si_units::base::TemperatureInterval{K: x});
+		assert!(simple_si_units::mechanical::Jerk::from(
+				uom::si::f64::Jerk::new::<uom::si::jerk::meter_per_second_cubed>(x)
+			) == simple_si_units::mechanical::Jerk{mps3: x});
+		assert!(simple_si_units::mechanical::DynamicViscosity::from(
+				uom::si::f64::DynamicViscosity::new::<uom::si::dynamic_viscosity::pascal_second>(x)
+			) == simple_si_units::mechanical::DynamicViscosity{Pas: x});
+		assert!(simple_si_units::mechanical::MassFlowRate::from(
+				uom::si::f64::MassRate::new::<uom::si::mass_rate::kilogram_per_second>(x)
+			) == simple_si_units::mechanical::MassFlowRate{kgps: x});
+		assert!(simple_si_units::mechanical::VolumetricFlowRate::from(
+				uom::si::f64::VolumeRate::new::<uom::si::volume_rate::cubic_meter_per_second>(x)
+			) == simple_si_units::mechanical::VolumetricFlowRate{m3ps: x});
+		assert!(simple_si_units::chemical::HeatCapacity::from(
+				uom::si::f64::HeatCapacity::new::<uom::si::heat_capacity::joule_per_kelvin>(x)
+			) == simple_si_units::chemical::HeatCapacity{J_per_K: x});
+		assert!(simple_si_units::chemical::ThermalConductivity::from(
+				uom::si::f64::ThermalConductivity::new::<uom::si::thermal_conductivity::watt_per_meter_kelvin>(x)
+			) == simple_si_units::chemical::ThermalConductivity{W_per_mK: x});
+		assert!(simple_si_units::electromagnetic::ElectricField::from(
+				uom::si::f64::ElectricField::new::<uom::si::electric_field::volt_per_meter>(x)
+			) == simple_si_units::electromagnetic::ElectricField{Vpm: x});
+		assert!(simple_si_units::electromagnetic::MagneticFieldStrength::from(
+				uom::si::f64::MagneticFieldStrength::new::<uom::si::magnetic_field_strength::ampere_per_meter>(x)
+			) == simple_si_units::electromagnetic::MagneticFieldStrength{Apm: x});
+		assert!(simple_si_units::electromagnetic::LinearChargeDensity::from(
+				uom::si::f64::ElectricChargeLinearDensity::new::<uom::si::electric_charge_linear_density::coulomb_per_meter>(x)
+			) == simple_si_units::electromagnetic::LinearChargeDensity{Cpm: x});
+		assert!(simple_si_units::electromagnetic::SurfaceChargeDensity::from(
+				uom::si::f64::ElectricChargeArealDensity::new::<uom::si::electric_charge_areal_density::coulomb_per_square_meter>(x)
+			) == simple_si_units::electromagnetic::SurfaceChargeDensity{Cpm2: x});
+		assert!(simple_si_units::electromagnetic::VolumeChargeDensity::from(
+				uom::si::f64::ElectricChargeVolumetricDensity::new::<uom::si::electric_charge_volumetric_density::coulomb_per_cubic_meter>(x)
+			) == simple_si_units::electromagnetic::VolumeChargeDensity{Cpm3: x});
+		assert!(simple_si_units::chemical::Entropy::from(
+				uom::si::f64::HeatCapacity::new::<uom::si::heat_capacity::joule_per_kelvin>(x)
+			) == simple_si_units::chemical::Entropy{J_per_K: x});
+		assert!(simple_si_units::chemical::ThermalConductance::from(
+				uom::si::f64::ThermalConductance::new::<uom::si::thermal_conductance::watt_per_kelvin>(x)
+			) == simple_si_units::chemical::ThermalConductance{W_per_K: x});
+		assert!(simple_si_units::electromagnetic::Irradiance::from(
+				uom::si::f64::HeatFluxDensity::new::<uom::si::heat_flux_density::watt_per_square_meter>(x)
+			) == simple_si_units::electromagnetic::Irradiance{W_per_m2: x});
+		assert!(simple_si_units::chemical::MolarEnergy::from(
+				uom::si::f64::MolarEnergy::new::<uom::si::molar_energy::joule_per_mole>(x)
+			) == simple_si_units::chemical::MolarEnergy{J_per_mol: x});
+		assert!(simple_si_units::chemical::MolarEntropy::from(
+				uom::si::f64::MolarHeatCapacity::new::<uom::si::molar_heat_capacity::joule_per_kelvin_mole>(x)
+			) == simple_si_units::chemical::MolarEntropy{J_per_molK: x});
+		assert!(simple_si_units::mechanical::KinematicViscosity::from(
+				uom::si::f64::DiffusionCoefficient::new::<uom::si::diffusion_coefficient::square_meter_per_second>(x)
+			) == simple_si_units::mechanical::KinematicViscosity{m2ps: x});
 	}
 
 	#[test]
@@ -622,6 +784,60 @@ mod uom_integration {
 		assert!(simple_si_units::mechanical::VolumePerMass::from(
 			uom::si::f32::SpecificVolume::new::<uom::si::specific_volume::cubic_meter_per_kilogram>(x)
 		) == simple_si_units::mechanical::VolumePerMass{m3_per_kg: x});
+		assert!(simple_si_units::base::TemperatureInterval::from(
+				uom::si::f32::TemperatureInterval::new::<uom::si::temperature_interval::kelvin>(x)
+			) == simple_si_units::base::TemperatureInterval{K: x});
+		assert!(simple_si_units::mechanical::Jerk::from(
+				uom::si::f32::Jerk::new::<uom::si::jerk::meter_per_second_cubed>(x)
+			) == simple_si_units::mechanical::Jerk{mps3: x});
+		assert!(simple_si_units::mechanical::DynamicViscosity::from(
+				uom::si::f32::DynamicViscosity::new::<uom::si::dynamic_viscosity::pascal_second>(x)
+			) == simple_si_units::mechanical::DynamicViscosity{Pas: x});
+		assert!(simple_si_units::mechanical::MassFlowRate::from(
+				uom::si::f32::MassRate::new::<uom::si::mass_rate::kilogram_per_second>(x)
+			) == simple_si_units::mechanical::MassFlowRate{kgps: x});
+		assert!(simple_si_units::mechanical::VolumetricFlowRate::from(
+				uom::si::f32::VolumeRate::new::<uom::si::volume_rate::cubic_meter_per_second>(x)
+			) == simple_si_units::mechanical::VolumetricFlowRate{m3ps: x});
+		assert!(simple_si_units::chemical::HeatCapacity::from(
+				uom::si::f32::HeatCapacity::new::<uom::si::heat_capacity::joule_per_kelvin>(x)
+			) == simple_si_units::chemical::HeatCapacity{J_per_K: x});
+		assert!(simple_si_units::chemical::ThermalConductivity::from(
+				uom::si::f32::ThermalConductivity::new::<uom::si::thermal_conductivity::watt_per_meter_kelvin>(x)
+			) == simple_si_units::chemical::ThermalConductivity{W_per_mK: x});
+		assert!(simple_si_units::electromagnetic::ElectricField::from(
+				uom::si::f32::ElectricField::new::<uom::si::electric_field::volt_per_meter>(x)
+			) == simple_si_units::electromagnetic::ElectricField{Vpm: x});
+		assert!(simple_si_units::electromagnetic::MagneticFieldStrength::from(
+				uom::si::f32::MagneticFieldStrength::new::<uom::si::magnetic_field_strength::ampere_per_meter>(x)
+			) == simple_si_units::electromagnetic::MagneticFieldStrength{Apm: x});
+		assert!(simple_si_units::electromagnetic::LinearChargeDensity::from(
+				uom::si::f32::ElectricChargeLinearDensity::new::<uom::si::electric_charge_linear_density::coulomb_per_meter>(x)
+			) == simple_si_units::electromagnetic::LinearChargeDensity{Cpm: x});
+		assert!(simple_si_units::electromagnetic::SurfaceChargeDensity::from(
+				uom::si::f32::ElectricChargeArealDensity::new::<uom::si::electric_charge_areal_density::coulomb_per_square_meter>(x)
+			) == simple_si_units::electromagnetic::SurfaceChargeDensity{Cpm2: x});
+		assert!(simple_si_units::electromagnetic::VolumeChargeDensity::from(
+				uom::si::f32::ElectricChargeVolumetricDensity::new::<uom::si::electric_charge_volumetric_density::coulomb_per_cubic_meter>(x)
+			) == simple_si_units::electromagnetic::VolumeChargeDensity{Cpm3: x});
+		assert!(simple_si_units::chemical::Entropy::from(
+				uom::si::f32::HeatCapacity::new::<uom::si::heat_capacity::joule_per_kelvin>(x)
+			) == simple_si_units::chemical::Entropy{J_per_K: x});
+		assert!(simple_si_units::chemical::ThermalConductance::from(
+				uom::si::f32::ThermalConductance::new::<uom::si::thermal_conductance::watt_per_kelvin>(x)
+			) == simple_si_units::chemical::ThermalConductance{W_per_K: x});
+		assert!(simple_si_units::electromagnetic::Irradiance::from(
+				uom::si::f32::HeatFluxDensity::new::<uom::si::heat_flux_density::watt_per_square_meter>(x)
+			) == simple_si_units::electromagnetic::Irradiance{W_per_m2: x});
+		assert!(simple_si_units::chemical::MolarEnergy::from(
+				uom::si::f32::MolarEnergy::new::<uom::si::molar_energy::joule_per_mole>(x)
+			) == simple_si_units::chemical::MolarEnergy{J_per_mol: x});
+		assert!(simple_si_units::chemical::MolarEntropy::from(
+				uom::si::f32::MolarHeatCapacity::new::<uom::si::molar_heat_capacity::joule_per_kelvin_mole>(x)
+			) == simple_si_units::chemical::MolarEntropy{J_per_molK: x});
+		assert!(simple_si_units::mechanical::KinematicViscosity::from(
+				uom::si::f32::DiffusionCoefficient::new::<uom::si::diffusion_coefficient::square_meter_per_second>(x)
+			) == simple_si_units::mechanical::KinematicViscosity{m2ps: x});
 	}
 
 }
\ No newline at end of file